@@ -5,12 +5,18 @@
 //! Module for working with multiple calendars at once
 
 use crate::buddhist::Buddhist;
+use crate::chinese::Chinese;
 use crate::coptic::Coptic;
+use crate::dangi::Dangi;
 use crate::ethiopian::{Ethiopian, EthiopianEraStyle};
 use crate::gregorian::Gregorian;
+use crate::hebrew::Hebrew;
 use crate::indian::Indian;
+use crate::islamic::{IslamicCivil, IslamicObservational, IslamicTabular, IslamicUmmAlQura};
 use crate::iso::Iso;
 use crate::japanese::{Japanese, JapaneseExtended};
+use crate::persian::Persian;
+use crate::roc::Roc;
 use crate::{
     types, AsCalendar, Calendar, CalendarError, Date, DateDuration, DateDurationUnit, DateTime, Ref,
 };
@@ -20,6 +26,7 @@ use icu_locid::{
     subtags_language as language, Locale,
 };
 use icu_provider::prelude::*;
+use tinystr::tinystr;
 
 use core::fmt;
 
@@ -84,6 +91,24 @@ pub enum AnyCalendar {
     Ethiopian(Ethiopian),
     /// An [`Indian`] calendar
     Indian(Indian),
+    /// A [`Chinese`] calendar
+    Chinese(Chinese),
+    /// A [`Dangi`] calendar
+    Dangi(Dangi),
+    /// A [`Hebrew`] calendar
+    Hebrew(Hebrew),
+    /// An [`IslamicObservational`] calendar
+    IslamicObservational(IslamicObservational),
+    /// An [`IslamicUmmAlQura`] calendar
+    IslamicUmmAlQura(IslamicUmmAlQura),
+    /// An [`IslamicCivil`] calendar
+    IslamicCivil(IslamicCivil),
+    /// An [`IslamicTabular`] calendar
+    IslamicTabular(IslamicTabular),
+    /// A [`Persian`] calendar
+    Persian(Persian),
+    /// A [`Roc`] calendar
+    Roc(Roc),
     /// A [`Coptic`] calendar
     Coptic(Coptic),
     /// An [`Iso`] calendar
@@ -106,6 +131,24 @@ pub enum AnyDateInner {
     Ethiopian(<Ethiopian as Calendar>::DateInner),
     /// A date for an [`Indian`] calendar
     Indian(<Indian as Calendar>::DateInner),
+    /// A date for a [`Chinese`] calendar
+    Chinese(<Chinese as Calendar>::DateInner),
+    /// A date for a [`Dangi`] calendar
+    Dangi(<Dangi as Calendar>::DateInner),
+    /// A date for a [`Hebrew`] calendar
+    Hebrew(<Hebrew as Calendar>::DateInner),
+    /// A date for an [`IslamicObservational`] calendar
+    IslamicObservational(<IslamicObservational as Calendar>::DateInner),
+    /// A date for an [`IslamicUmmAlQura`] calendar
+    IslamicUmmAlQura(<IslamicUmmAlQura as Calendar>::DateInner),
+    /// A date for an [`IslamicCivil`] calendar
+    IslamicCivil(<IslamicCivil as Calendar>::DateInner),
+    /// A date for an [`IslamicTabular`] calendar
+    IslamicTabular(<IslamicTabular as Calendar>::DateInner),
+    /// A date for a [`Persian`] calendar
+    Persian(<Persian as Calendar>::DateInner),
+    /// A date for a [`Roc`] calendar
+    Roc(<Roc as Calendar>::DateInner),
     /// A date for a [`Coptic`] calendar
     Coptic(<Coptic as Calendar>::DateInner),
     /// A date for an [`Iso`] calendar
@@ -124,6 +167,27 @@ macro_rules! match_cal_and_date {
             ) => $e,
             (&Self::Ethiopian(ref $cal_matched), &AnyDateInner::Ethiopian(ref $date_matched)) => $e,
             (&Self::Indian(ref $cal_matched), &AnyDateInner::Indian(ref $date_matched)) => $e,
+            (&Self::Chinese(ref $cal_matched), &AnyDateInner::Chinese(ref $date_matched)) => $e,
+            (&Self::Dangi(ref $cal_matched), &AnyDateInner::Dangi(ref $date_matched)) => $e,
+            (&Self::Hebrew(ref $cal_matched), &AnyDateInner::Hebrew(ref $date_matched)) => $e,
+            (
+                &Self::IslamicObservational(ref $cal_matched),
+                &AnyDateInner::IslamicObservational(ref $date_matched),
+            ) => $e,
+            (
+                &Self::IslamicUmmAlQura(ref $cal_matched),
+                &AnyDateInner::IslamicUmmAlQura(ref $date_matched),
+            ) => $e,
+            (
+                &Self::IslamicCivil(ref $cal_matched),
+                &AnyDateInner::IslamicCivil(ref $date_matched),
+            ) => $e,
+            (
+                &Self::IslamicTabular(ref $cal_matched),
+                &AnyDateInner::IslamicTabular(ref $date_matched),
+            ) => $e,
+            (&Self::Persian(ref $cal_matched), &AnyDateInner::Persian(ref $date_matched)) => $e,
+            (&Self::Roc(ref $cal_matched), &AnyDateInner::Roc(ref $date_matched)) => $e,
             (&Self::Coptic(ref $cal_matched), &AnyDateInner::Coptic(ref $date_matched)) => $e,
             (&Self::Iso(ref $cal_matched), &AnyDateInner::Iso(ref $date_matched)) => $e,
             _ => panic!(
@@ -163,6 +227,31 @@ impl Calendar for AnyCalendar {
             Self::Indian(ref c) => {
                 AnyDateInner::Indian(c.date_from_codes(era, year, month_code, day)?)
             }
+            Self::Chinese(ref c) => {
+                AnyDateInner::Chinese(c.date_from_codes(era, year, month_code, day)?)
+            }
+            Self::Dangi(ref c) => {
+                AnyDateInner::Dangi(c.date_from_codes(era, year, month_code, day)?)
+            }
+            Self::Hebrew(ref c) => {
+                AnyDateInner::Hebrew(c.date_from_codes(era, year, month_code, day)?)
+            }
+            Self::IslamicObservational(ref c) => {
+                AnyDateInner::IslamicObservational(c.date_from_codes(era, year, month_code, day)?)
+            }
+            Self::IslamicUmmAlQura(ref c) => {
+                AnyDateInner::IslamicUmmAlQura(c.date_from_codes(era, year, month_code, day)?)
+            }
+            Self::IslamicCivil(ref c) => {
+                AnyDateInner::IslamicCivil(c.date_from_codes(era, year, month_code, day)?)
+            }
+            Self::IslamicTabular(ref c) => {
+                AnyDateInner::IslamicTabular(c.date_from_codes(era, year, month_code, day)?)
+            }
+            Self::Persian(ref c) => {
+                AnyDateInner::Persian(c.date_from_codes(era, year, month_code, day)?)
+            }
+            Self::Roc(ref c) => AnyDateInner::Roc(c.date_from_codes(era, year, month_code, day)?),
             Self::Coptic(ref c) => {
                 AnyDateInner::Coptic(c.date_from_codes(era, year, month_code, day)?)
             }
@@ -178,6 +267,17 @@ impl Calendar for AnyCalendar {
             Self::JapaneseExtended(ref c) => AnyDateInner::JapaneseExtended(c.date_from_iso(iso)),
             Self::Ethiopian(ref c) => AnyDateInner::Ethiopian(c.date_from_iso(iso)),
             Self::Indian(ref c) => AnyDateInner::Indian(c.date_from_iso(iso)),
+            Self::Chinese(ref c) => AnyDateInner::Chinese(c.date_from_iso(iso)),
+            Self::Dangi(ref c) => AnyDateInner::Dangi(c.date_from_iso(iso)),
+            Self::Hebrew(ref c) => AnyDateInner::Hebrew(c.date_from_iso(iso)),
+            Self::IslamicObservational(ref c) => {
+                AnyDateInner::IslamicObservational(c.date_from_iso(iso))
+            }
+            Self::IslamicUmmAlQura(ref c) => AnyDateInner::IslamicUmmAlQura(c.date_from_iso(iso)),
+            Self::IslamicCivil(ref c) => AnyDateInner::IslamicCivil(c.date_from_iso(iso)),
+            Self::IslamicTabular(ref c) => AnyDateInner::IslamicTabular(c.date_from_iso(iso)),
+            Self::Persian(ref c) => AnyDateInner::Persian(c.date_from_iso(iso)),
+            Self::Roc(ref c) => AnyDateInner::Roc(c.date_from_iso(iso)),
             Self::Coptic(ref c) => AnyDateInner::Coptic(c.date_from_iso(iso)),
             Self::Iso(ref c) => AnyDateInner::Iso(c.date_from_iso(iso)),
         }
@@ -219,6 +319,34 @@ impl Calendar for AnyCalendar {
             (&Self::Indian(ref c), &mut AnyDateInner::Indian(ref mut d)) => {
                 c.offset_date(d, offset.cast_unit())
             }
+            (&Self::Chinese(ref c), &mut AnyDateInner::Chinese(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit())
+            }
+            (&Self::Dangi(ref c), &mut AnyDateInner::Dangi(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit())
+            }
+            (&Self::Hebrew(ref c), &mut AnyDateInner::Hebrew(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit())
+            }
+            (
+                &Self::IslamicObservational(ref c),
+                &mut AnyDateInner::IslamicObservational(ref mut d),
+            ) => c.offset_date(d, offset.cast_unit()),
+            (&Self::IslamicUmmAlQura(ref c), &mut AnyDateInner::IslamicUmmAlQura(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit())
+            }
+            (&Self::IslamicCivil(ref c), &mut AnyDateInner::IslamicCivil(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit())
+            }
+            (&Self::IslamicTabular(ref c), &mut AnyDateInner::IslamicTabular(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit())
+            }
+            (&Self::Persian(ref c), &mut AnyDateInner::Persian(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit())
+            }
+            (&Self::Roc(ref c), &mut AnyDateInner::Roc(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit())
+            }
             (&Self::Coptic(ref c), &mut AnyDateInner::Coptic(ref mut d)) => {
                 c.offset_date(d, offset.cast_unit())
             }
@@ -292,6 +420,78 @@ impl Calendar for AnyCalendar {
             ) => c1
                 .until(d1, d2, c2, largest_unit, smallest_unit)
                 .cast_unit(),
+            (
+                &Self::Chinese(ref c1),
+                &Self::Chinese(ref c2),
+                &AnyDateInner::Chinese(ref d1),
+                &AnyDateInner::Chinese(ref d2),
+            ) => c1
+                .until(d1, d2, c2, largest_unit, smallest_unit)
+                .cast_unit(),
+            (
+                &Self::Dangi(ref c1),
+                &Self::Dangi(ref c2),
+                &AnyDateInner::Dangi(ref d1),
+                &AnyDateInner::Dangi(ref d2),
+            ) => c1
+                .until(d1, d2, c2, largest_unit, smallest_unit)
+                .cast_unit(),
+            (
+                &Self::Hebrew(ref c1),
+                &Self::Hebrew(ref c2),
+                &AnyDateInner::Hebrew(ref d1),
+                &AnyDateInner::Hebrew(ref d2),
+            ) => c1
+                .until(d1, d2, c2, largest_unit, smallest_unit)
+                .cast_unit(),
+            (
+                &Self::IslamicObservational(ref c1),
+                &Self::IslamicObservational(ref c2),
+                &AnyDateInner::IslamicObservational(ref d1),
+                &AnyDateInner::IslamicObservational(ref d2),
+            ) => c1
+                .until(d1, d2, c2, largest_unit, smallest_unit)
+                .cast_unit(),
+            (
+                &Self::IslamicUmmAlQura(ref c1),
+                &Self::IslamicUmmAlQura(ref c2),
+                &AnyDateInner::IslamicUmmAlQura(ref d1),
+                &AnyDateInner::IslamicUmmAlQura(ref d2),
+            ) => c1
+                .until(d1, d2, c2, largest_unit, smallest_unit)
+                .cast_unit(),
+            (
+                &Self::IslamicCivil(ref c1),
+                &Self::IslamicCivil(ref c2),
+                &AnyDateInner::IslamicCivil(ref d1),
+                &AnyDateInner::IslamicCivil(ref d2),
+            ) => c1
+                .until(d1, d2, c2, largest_unit, smallest_unit)
+                .cast_unit(),
+            (
+                &Self::IslamicTabular(ref c1),
+                &Self::IslamicTabular(ref c2),
+                &AnyDateInner::IslamicTabular(ref d1),
+                &AnyDateInner::IslamicTabular(ref d2),
+            ) => c1
+                .until(d1, d2, c2, largest_unit, smallest_unit)
+                .cast_unit(),
+            (
+                &Self::Persian(ref c1),
+                &Self::Persian(ref c2),
+                &AnyDateInner::Persian(ref d1),
+                &AnyDateInner::Persian(ref d2),
+            ) => c1
+                .until(d1, d2, c2, largest_unit, smallest_unit)
+                .cast_unit(),
+            (
+                &Self::Roc(ref c1),
+                &Self::Roc(ref c2),
+                &AnyDateInner::Roc(ref d1),
+                &AnyDateInner::Roc(ref d2),
+            ) => c1
+                .until(d1, d2, c2, largest_unit, smallest_unit)
+                .cast_unit(),
             (
                 &Self::Coptic(ref c1),
                 &Self::Coptic(ref c2),
@@ -351,6 +551,15 @@ impl Calendar for AnyCalendar {
             Self::JapaneseExtended(_) => "AnyCalendar (Japanese, Historical Era Data)",
             Self::Ethiopian(_) => "AnyCalendar (Ethiopian)",
             Self::Indian(_) => "AnyCalendar (Indian)",
+            Self::Chinese(_) => "AnyCalendar (Chinese)",
+            Self::Dangi(_) => "AnyCalendar (Dangi)",
+            Self::Hebrew(_) => "AnyCalendar (Hebrew)",
+            Self::IslamicObservational(_) => "AnyCalendar (Islamic, observational)",
+            Self::IslamicUmmAlQura(_) => "AnyCalendar (Islamic, Umm al-Qura)",
+            Self::IslamicCivil(_) => "AnyCalendar (Islamic, civil)",
+            Self::IslamicTabular(_) => "AnyCalendar (Islamic, tabular)",
+            Self::Persian(_) => "AnyCalendar (Persian)",
+            Self::Roc(_) => "AnyCalendar (ROC)",
             Self::Coptic(_) => "AnyCalendar (Coptic)",
             Self::Iso(_) => "AnyCalendar (Iso)",
         }
@@ -369,7 +578,8 @@ impl AnyCalendar {
     ///
     /// For calendars that need data, will attempt to load the appropriate data from the source.
     ///
-    /// This API needs the `calendar/japanese@1` or `calendar/japanext@1` data key if working with Japanese calendars.
+    /// This API needs the `calendar/japanese@1` or `calendar/japanext@1` data key if working with Japanese calendars,
+    /// and the `calendar/islamicumalquracache@1` data key if working with the Umm al-Qura calendar.
     pub fn try_new_with_any_provider<P>(
         provider: &P,
         kind: AnyCalendarKind,
@@ -387,6 +597,19 @@ impl AnyCalendar {
                 JapaneseExtended::try_new_with_any_provider(provider)?,
             ),
             AnyCalendarKind::Indian => AnyCalendar::Indian(Indian),
+            AnyCalendarKind::Chinese => AnyCalendar::Chinese(Chinese),
+            AnyCalendarKind::Dangi => AnyCalendar::Dangi(Dangi),
+            AnyCalendarKind::Hebrew => AnyCalendar::Hebrew(Hebrew),
+            AnyCalendarKind::IslamicObservational => {
+                AnyCalendar::IslamicObservational(IslamicObservational)
+            }
+            AnyCalendarKind::IslamicUmmAlQura => AnyCalendar::IslamicUmmAlQura(
+                IslamicUmmAlQura::try_new_with_any_provider(provider)?,
+            ),
+            AnyCalendarKind::IslamicCivil => AnyCalendar::IslamicCivil(IslamicCivil),
+            AnyCalendarKind::IslamicTabular => AnyCalendar::IslamicTabular(IslamicTabular),
+            AnyCalendarKind::Persian => AnyCalendar::Persian(Persian),
+            AnyCalendarKind::Roc => AnyCalendar::Roc(Roc),
             AnyCalendarKind::Coptic => AnyCalendar::Coptic(Coptic),
             AnyCalendarKind::Iso => AnyCalendar::Iso(Iso),
             AnyCalendarKind::Ethiopian => AnyCalendar::Ethiopian(Ethiopian::new_with_era_style(
@@ -405,7 +628,8 @@ impl AnyCalendar {
     ///
     /// For calendars that need data, will attempt to load the appropriate data from the source.
     ///
-    /// This API needs the `calendar/japanese@1` or `calendar/japanext@1` data key if working with Japanese calendars.
+    /// This API needs the `calendar/japanese@1` or `calendar/japanext@1` data key if working with Japanese calendars,
+    /// and the `calendar/islamicumalquracache@1` data key if working with the Umm al-Qura calendar.
     ///
     /// This needs the `"serde"` feature to be enabled to be used
     #[cfg(feature = "serde")]
@@ -426,6 +650,19 @@ impl AnyCalendar {
                 JapaneseExtended::try_new_with_buffer_provider(provider)?,
             ),
             AnyCalendarKind::Indian => AnyCalendar::Indian(Indian),
+            AnyCalendarKind::Chinese => AnyCalendar::Chinese(Chinese),
+            AnyCalendarKind::Dangi => AnyCalendar::Dangi(Dangi),
+            AnyCalendarKind::Hebrew => AnyCalendar::Hebrew(Hebrew),
+            AnyCalendarKind::IslamicObservational => {
+                AnyCalendar::IslamicObservational(IslamicObservational)
+            }
+            AnyCalendarKind::IslamicUmmAlQura => AnyCalendar::IslamicUmmAlQura(
+                IslamicUmmAlQura::try_new_with_buffer_provider(provider)?,
+            ),
+            AnyCalendarKind::IslamicCivil => AnyCalendar::IslamicCivil(IslamicCivil),
+            AnyCalendarKind::IslamicTabular => AnyCalendar::IslamicTabular(IslamicTabular),
+            AnyCalendarKind::Persian => AnyCalendar::Persian(Persian),
+            AnyCalendarKind::Roc => AnyCalendar::Roc(Roc),
             AnyCalendarKind::Coptic => AnyCalendar::Coptic(Coptic),
             AnyCalendarKind::Iso => AnyCalendar::Iso(Iso),
             AnyCalendarKind::Ethiopian => AnyCalendar::Ethiopian(Ethiopian::new_with_era_style(
@@ -454,6 +691,7 @@ impl AnyCalendar {
     where
         P: DataProvider<crate::provider::JapaneseErasV1Marker>
             + DataProvider<crate::provider::JapaneseExtendedErasV1Marker>
+            + DataProvider<crate::provider::IslamicUmmAlQuraCacheV1Marker>
             + ?Sized,
     {
         Ok(match kind {
@@ -466,6 +704,19 @@ impl AnyCalendar {
                 AnyCalendar::JapaneseExtended(JapaneseExtended::try_new_unstable(provider)?)
             }
             AnyCalendarKind::Indian => AnyCalendar::Indian(Indian),
+            AnyCalendarKind::Chinese => AnyCalendar::Chinese(Chinese),
+            AnyCalendarKind::Dangi => AnyCalendar::Dangi(Dangi),
+            AnyCalendarKind::Hebrew => AnyCalendar::Hebrew(Hebrew),
+            AnyCalendarKind::IslamicObservational => {
+                AnyCalendar::IslamicObservational(IslamicObservational)
+            }
+            AnyCalendarKind::IslamicUmmAlQura => {
+                AnyCalendar::IslamicUmmAlQura(IslamicUmmAlQura::try_new_unstable(provider)?)
+            }
+            AnyCalendarKind::IslamicCivil => AnyCalendar::IslamicCivil(IslamicCivil),
+            AnyCalendarKind::IslamicTabular => AnyCalendar::IslamicTabular(IslamicTabular),
+            AnyCalendarKind::Persian => AnyCalendar::Persian(Persian),
+            AnyCalendarKind::Roc => AnyCalendar::Roc(Roc),
             AnyCalendarKind::Coptic => AnyCalendar::Coptic(Coptic),
             AnyCalendarKind::Iso => AnyCalendar::Iso(Iso),
             AnyCalendarKind::Ethiopian => AnyCalendar::Ethiopian(Ethiopian::new_with_era_style(
@@ -503,6 +754,7 @@ impl AnyCalendar {
     where
         P: DataProvider<crate::provider::JapaneseErasV1Marker>
             + DataProvider<crate::provider::JapaneseExtendedErasV1Marker>
+            + DataProvider<crate::provider::IslamicUmmAlQuraCacheV1Marker>
             + ?Sized,
     {
         let kind = AnyCalendarKind::from_data_locale_with_fallback(locale);
@@ -517,6 +769,15 @@ impl AnyCalendar {
             Self::JapaneseExtended(_) => "Japanese (Historical era data)",
             Self::Ethiopian(_) => "Ethiopian",
             Self::Indian(_) => "Indian",
+            Self::Chinese(_) => "Chinese",
+            Self::Dangi(_) => "Dangi",
+            Self::Hebrew(_) => "Hebrew",
+            Self::IslamicObservational(_) => "Islamic (observational)",
+            Self::IslamicUmmAlQura(_) => "Islamic (Umm al-Qura)",
+            Self::IslamicCivil(_) => "Islamic (civil)",
+            Self::IslamicTabular(_) => "Islamic (tabular)",
+            Self::Persian(_) => "Persian",
+            Self::Roc(_) => "ROC",
             Self::Coptic(_) => "Coptic",
             Self::Iso(_) => "Iso",
         }
@@ -534,6 +795,15 @@ impl AnyCalendar {
                 .any_calendar_kind()
                 .expect("Ethiopian calendar known to have an AnyCalendarKind"),
             Self::Indian(_) => AnyCalendarKind::Indian,
+            Self::Chinese(_) => AnyCalendarKind::Chinese,
+            Self::Dangi(_) => AnyCalendarKind::Dangi,
+            Self::Hebrew(_) => AnyCalendarKind::Hebrew,
+            Self::IslamicObservational(_) => AnyCalendarKind::IslamicObservational,
+            Self::IslamicUmmAlQura(_) => AnyCalendarKind::IslamicUmmAlQura,
+            Self::IslamicCivil(_) => AnyCalendarKind::IslamicCivil,
+            Self::IslamicTabular(_) => AnyCalendarKind::IslamicTabular,
+            Self::Persian(_) => AnyCalendarKind::Persian,
+            Self::Roc(_) => AnyCalendarKind::Roc,
             Self::Coptic(_) => AnyCalendarKind::Coptic,
             Self::Iso(_) => AnyCalendarKind::Iso,
         }
@@ -577,6 +847,15 @@ impl AnyDateInner {
             AnyDateInner::JapaneseExtended(_) => "Japanese (Historical era data)",
             AnyDateInner::Ethiopian(_) => "Ethiopian",
             AnyDateInner::Indian(_) => "Indian",
+            AnyDateInner::Chinese(_) => "Chinese",
+            AnyDateInner::Dangi(_) => "Dangi",
+            AnyDateInner::Hebrew(_) => "Hebrew",
+            AnyDateInner::IslamicObservational(_) => "Islamic (observational)",
+            AnyDateInner::IslamicUmmAlQura(_) => "Islamic (Umm al-Qura)",
+            AnyDateInner::IslamicCivil(_) => "Islamic (civil)",
+            AnyDateInner::IslamicTabular(_) => "Islamic (tabular)",
+            AnyDateInner::Persian(_) => "Persian",
+            AnyDateInner::Roc(_) => "ROC",
             AnyDateInner::Coptic(_) => "Coptic",
             AnyDateInner::Iso(_) => "Iso",
         }
@@ -601,6 +880,24 @@ pub enum AnyCalendarKind {
     EthiopianAmeteAlem,
     /// The kind of a [`Indian`] calendar
     Indian,
+    /// The kind of a [`Chinese`] calendar
+    Chinese,
+    /// The kind of a [`Dangi`] calendar
+    Dangi,
+    /// The kind of a [`Hebrew`] calendar
+    Hebrew,
+    /// The kind of an [`IslamicObservational`] calendar
+    IslamicObservational,
+    /// The kind of an [`IslamicUmmAlQura`] calendar
+    IslamicUmmAlQura,
+    /// The kind of an [`IslamicCivil`] calendar
+    IslamicCivil,
+    /// The kind of an [`IslamicTabular`] calendar
+    IslamicTabular,
+    /// The kind of a [`Persian`] calendar
+    Persian,
+    /// The kind of a [`Roc`] calendar
+    Roc,
     /// The kind of a [`Coptic`] calendar
     Coptic,
     /// The kind of an [`Iso`] calendar
@@ -626,6 +923,15 @@ impl AnyCalendarKind {
             b"japanese" => AnyCalendarKind::Japanese,
             b"japanext" => AnyCalendarKind::JapaneseExtended,
             b"indian" => AnyCalendarKind::Indian,
+            b"chinese" => AnyCalendarKind::Chinese,
+            b"dangi" => AnyCalendarKind::Dangi,
+            b"hebrew" => AnyCalendarKind::Hebrew,
+            b"islamic" => AnyCalendarKind::IslamicObservational,
+            b"islamic-umalqura" => AnyCalendarKind::IslamicUmmAlQura,
+            b"islamic-civil" => AnyCalendarKind::IslamicCivil,
+            b"islamic-tbla" => AnyCalendarKind::IslamicTabular,
+            b"persian" => AnyCalendarKind::Persian,
+            b"roc" => AnyCalendarKind::Roc,
             b"coptic" => AnyCalendarKind::Coptic,
             b"iso" => AnyCalendarKind::Iso,
             b"ethiopic" => AnyCalendarKind::Ethiopian,
@@ -648,6 +954,24 @@ impl AnyCalendarKind {
             AnyCalendarKind::JapaneseExtended
         } else if *x == value!("indian") {
             AnyCalendarKind::Indian
+        } else if *x == value!("chinese") {
+            AnyCalendarKind::Chinese
+        } else if *x == value!("dangi") {
+            AnyCalendarKind::Dangi
+        } else if *x == value!("hebrew") {
+            AnyCalendarKind::Hebrew
+        } else if *x == value!("islamic") {
+            AnyCalendarKind::IslamicObservational
+        } else if x.as_tinystr_slice() == [tinystr!(8, "islamic"), tinystr!(8, "umalqura")] {
+            AnyCalendarKind::IslamicUmmAlQura
+        } else if x.as_tinystr_slice() == [tinystr!(8, "islamic"), tinystr!(8, "civil")] {
+            AnyCalendarKind::IslamicCivil
+        } else if x.as_tinystr_slice() == [tinystr!(8, "islamic"), tinystr!(8, "tbla")] {
+            AnyCalendarKind::IslamicTabular
+        } else if *x == value!("persian") {
+            AnyCalendarKind::Persian
+        } else if *x == value!("roc") {
+            AnyCalendarKind::Roc
         } else if *x == value!("coptic") {
             AnyCalendarKind::Coptic
         } else if *x == value!("iso") {
@@ -669,6 +993,15 @@ impl AnyCalendarKind {
             AnyCalendarKind::Japanese => "japanese",
             AnyCalendarKind::JapaneseExtended => "japanext",
             AnyCalendarKind::Indian => "indian",
+            AnyCalendarKind::Chinese => "chinese",
+            AnyCalendarKind::Dangi => "dangi",
+            AnyCalendarKind::Hebrew => "hebrew",
+            AnyCalendarKind::IslamicObservational => "islamic",
+            AnyCalendarKind::IslamicUmmAlQura => "islamic-umalqura",
+            AnyCalendarKind::IslamicCivil => "islamic-civil",
+            AnyCalendarKind::IslamicTabular => "islamic-tbla",
+            AnyCalendarKind::Persian => "persian",
+            AnyCalendarKind::Roc => "roc",
             AnyCalendarKind::Coptic => "coptic",
             AnyCalendarKind::Iso => "iso",
             AnyCalendarKind::Ethiopian => "ethiopic",
@@ -684,6 +1017,15 @@ impl AnyCalendarKind {
             AnyCalendarKind::Japanese => value!("japanese"),
             AnyCalendarKind::JapaneseExtended => value!("japanext"),
             AnyCalendarKind::Indian => value!("indian"),
+            AnyCalendarKind::Chinese => value!("chinese"),
+            AnyCalendarKind::Dangi => value!("dangi"),
+            AnyCalendarKind::Hebrew => value!("hebrew"),
+            AnyCalendarKind::IslamicObservational => value!("islamic"),
+            AnyCalendarKind::IslamicUmmAlQura => multi_subtag_value(b"islamic-umalqura"),
+            AnyCalendarKind::IslamicCivil => multi_subtag_value(b"islamic-civil"),
+            AnyCalendarKind::IslamicTabular => multi_subtag_value(b"islamic-tbla"),
+            AnyCalendarKind::Persian => value!("persian"),
+            AnyCalendarKind::Roc => value!("roc"),
             AnyCalendarKind::Coptic => value!("coptic"),
             AnyCalendarKind::Iso => value!("iso"),
             AnyCalendarKind::Ethiopian => value!("ethiopic"),
@@ -733,6 +1075,13 @@ impl AnyCalendarKind {
     }
 }
 
+/// Parses a BCP-47 value known at compile time to consist of several subtags, which
+/// `extensions_unicode_value!` does not support
+fn multi_subtag_value(bytes: &[u8]) -> Value {
+    #[allow(clippy::expect_used)] // only called with valid literals
+    Value::try_from_bytes(bytes).expect("valid BCP-47 value")
+}
+
 impl fmt::Display for AnyCalendarKind {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         fmt::Debug::fmt(self, f)
@@ -835,6 +1184,114 @@ impl IntoAnyCalendar for Indian {
     }
 }
 
+impl IntoAnyCalendar for Chinese {
+    fn to_any(self) -> AnyCalendar {
+        AnyCalendar::Chinese(Chinese)
+    }
+    fn to_any_cloned(&self) -> AnyCalendar {
+        AnyCalendar::Chinese(Chinese)
+    }
+    fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner {
+        AnyDateInner::Chinese(*d)
+    }
+}
+
+impl IntoAnyCalendar for Dangi {
+    fn to_any(self) -> AnyCalendar {
+        AnyCalendar::Dangi(Dangi)
+    }
+    fn to_any_cloned(&self) -> AnyCalendar {
+        AnyCalendar::Dangi(Dangi)
+    }
+    fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner {
+        AnyDateInner::Dangi(*d)
+    }
+}
+
+impl IntoAnyCalendar for Hebrew {
+    fn to_any(self) -> AnyCalendar {
+        AnyCalendar::Hebrew(Hebrew)
+    }
+    fn to_any_cloned(&self) -> AnyCalendar {
+        AnyCalendar::Hebrew(Hebrew)
+    }
+    fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner {
+        AnyDateInner::Hebrew(*d)
+    }
+}
+
+impl IntoAnyCalendar for IslamicObservational {
+    fn to_any(self) -> AnyCalendar {
+        AnyCalendar::IslamicObservational(IslamicObservational)
+    }
+    fn to_any_cloned(&self) -> AnyCalendar {
+        AnyCalendar::IslamicObservational(IslamicObservational)
+    }
+    fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner {
+        AnyDateInner::IslamicObservational(*d)
+    }
+}
+
+impl IntoAnyCalendar for IslamicUmmAlQura {
+    fn to_any(self) -> AnyCalendar {
+        AnyCalendar::IslamicUmmAlQura(self)
+    }
+    fn to_any_cloned(&self) -> AnyCalendar {
+        AnyCalendar::IslamicUmmAlQura(self.clone())
+    }
+    fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner {
+        AnyDateInner::IslamicUmmAlQura(*d)
+    }
+}
+
+impl IntoAnyCalendar for IslamicCivil {
+    fn to_any(self) -> AnyCalendar {
+        AnyCalendar::IslamicCivil(IslamicCivil)
+    }
+    fn to_any_cloned(&self) -> AnyCalendar {
+        AnyCalendar::IslamicCivil(IslamicCivil)
+    }
+    fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner {
+        AnyDateInner::IslamicCivil(*d)
+    }
+}
+
+impl IntoAnyCalendar for IslamicTabular {
+    fn to_any(self) -> AnyCalendar {
+        AnyCalendar::IslamicTabular(IslamicTabular)
+    }
+    fn to_any_cloned(&self) -> AnyCalendar {
+        AnyCalendar::IslamicTabular(IslamicTabular)
+    }
+    fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner {
+        AnyDateInner::IslamicTabular(*d)
+    }
+}
+
+impl IntoAnyCalendar for Persian {
+    fn to_any(self) -> AnyCalendar {
+        AnyCalendar::Persian(Persian)
+    }
+    fn to_any_cloned(&self) -> AnyCalendar {
+        AnyCalendar::Persian(Persian)
+    }
+    fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner {
+        AnyDateInner::Persian(*d)
+    }
+}
+
+impl IntoAnyCalendar for Roc {
+    fn to_any(self) -> AnyCalendar {
+        AnyCalendar::Roc(Roc)
+    }
+    fn to_any_cloned(&self) -> AnyCalendar {
+        AnyCalendar::Roc(Roc)
+    }
+    fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner {
+        AnyDateInner::Roc(*d)
+    }
+}
+
 impl IntoAnyCalendar for Coptic {
     fn to_any(self) -> AnyCalendar {
         AnyCalendar::Coptic(Coptic)
@@ -965,6 +1422,51 @@ mod tests {
             AnyCalendarKind::Indian,
         )
         .expect("Calendar construction must succeed");
+        let hebrew = AnyCalendar::try_new_with_buffer_provider(
+            &icu_testdata::buffer(),
+            AnyCalendarKind::Hebrew,
+        )
+        .expect("Calendar construction must succeed");
+        let chinese = AnyCalendar::try_new_with_buffer_provider(
+            &icu_testdata::buffer(),
+            AnyCalendarKind::Chinese,
+        )
+        .expect("Calendar construction must succeed");
+        let dangi = AnyCalendar::try_new_with_buffer_provider(
+            &icu_testdata::buffer(),
+            AnyCalendarKind::Dangi,
+        )
+        .expect("Calendar construction must succeed");
+        let islamic = AnyCalendar::try_new_with_buffer_provider(
+            &icu_testdata::buffer(),
+            AnyCalendarKind::IslamicObservational,
+        )
+        .expect("Calendar construction must succeed");
+        let ummalqura = AnyCalendar::try_new_with_buffer_provider(
+            &icu_testdata::buffer(),
+            AnyCalendarKind::IslamicUmmAlQura,
+        )
+        .expect("Calendar construction must succeed");
+        let islamic_civil = AnyCalendar::try_new_with_buffer_provider(
+            &icu_testdata::buffer(),
+            AnyCalendarKind::IslamicCivil,
+        )
+        .expect("Calendar construction must succeed");
+        let islamic_tabular = AnyCalendar::try_new_with_buffer_provider(
+            &icu_testdata::buffer(),
+            AnyCalendarKind::IslamicTabular,
+        )
+        .expect("Calendar construction must succeed");
+        let persian = AnyCalendar::try_new_with_buffer_provider(
+            &icu_testdata::buffer(),
+            AnyCalendarKind::Persian,
+        )
+        .expect("Calendar construction must succeed");
+        let roc = AnyCalendar::try_new_with_buffer_provider(
+            &icu_testdata::buffer(),
+            AnyCalendarKind::Roc,
+        )
+        .expect("Calendar construction must succeed");
         let japanese = AnyCalendar::try_new_with_buffer_provider(
             &icu_testdata::buffer(),
             AnyCalendarKind::Japanese,
@@ -981,6 +1483,15 @@ mod tests {
         let ethioaa = Ref(&ethioaa);
         let gregorian = Ref(&gregorian);
         let indian = Ref(&indian);
+        let hebrew = Ref(&hebrew);
+        let chinese = Ref(&chinese);
+        let dangi = Ref(&dangi);
+        let islamic = Ref(&islamic);
+        let ummalqura = Ref(&ummalqura);
+        let islamic_civil = Ref(&islamic_civil);
+        let islamic_tabular = Ref(&islamic_tabular);
+        let persian = Ref(&persian);
+        let roc = Ref(&roc);
         let japanese = Ref(&japanese);
         let japanext = Ref(&japanext);
 
@@ -1075,6 +1586,142 @@ mod tests {
             1,
             CalendarError::UnknownMonthCode("M13".parse().unwrap(), "Indian"),
         );
+
+        single_test_roundtrip(hebrew, "am", 5782, "M05L", 1);
+        single_test_roundtrip(hebrew, "am", 5782, "M06", 29);
+        single_test_roundtrip(hebrew, "am", 5783, "M06", 29);
+        single_test_roundtrip(hebrew, "am", 4000, "M12", 1);
+        single_test_error(
+            hebrew,
+            "am",
+            5783,
+            "M05L",
+            1,
+            CalendarError::UnknownMonthCode("M05L".parse().unwrap(), "Hebrew"),
+        );
+        single_test_error(
+            hebrew,
+            "am",
+            5782,
+            "M13",
+            1,
+            CalendarError::UnknownMonthCode("M13".parse().unwrap(), "Hebrew"),
+        );
+        single_test_error(
+            hebrew,
+            "am",
+            5782,
+            "M06L",
+            1,
+            CalendarError::UnknownMonthCode("M06L".parse().unwrap(), "Hebrew"),
+        );
+        single_test_error(hebrew, "am", 5783, "M06", 30, CalendarError::OutOfRange);
+
+        single_test_roundtrip(chinese, "chinese", 4660, "M02L", 1);
+        single_test_roundtrip(chinese, "chinese", 4660, "M12", 30);
+        single_test_roundtrip(chinese, "chinese", 4670, "M11L", 1);
+        single_test_error(
+            chinese,
+            "chinese",
+            4661,
+            "M02L",
+            1,
+            CalendarError::UnknownMonthCode("M02L".parse().unwrap(), "Chinese"),
+        );
+        single_test_error(
+            chinese,
+            "chinese",
+            4660,
+            "M13",
+            1,
+            CalendarError::UnknownMonthCode("M13".parse().unwrap(), "Chinese"),
+        );
+
+        single_test_roundtrip(dangi, "dangi", 4356, "M02L", 1);
+        single_test_roundtrip(dangi, "dangi", 4357, "M01", 1);
+        single_test_error(
+            dangi,
+            "dangi",
+            4357,
+            "M02L",
+            1,
+            CalendarError::UnknownMonthCode("M02L".parse().unwrap(), "Dangi"),
+        );
+
+        single_test_roundtrip(islamic, "ah", 1, "M01", 1);
+        single_test_roundtrip(islamic, "ah", 1444, "M09", 1);
+        single_test_roundtrip(islamic, "ah", -100, "M12", 1);
+        single_test_error(
+            islamic,
+            "ah",
+            1444,
+            "M13",
+            1,
+            CalendarError::UnknownMonthCode("M13".parse().unwrap(), "Islamic (observational)"),
+        );
+
+        single_test_roundtrip(ummalqura, "ah", 1, "M01", 1);
+        single_test_roundtrip(ummalqura, "ah", 1444, "M09", 1);
+        single_test_roundtrip(ummalqura, "ah", -100, "M12", 1);
+        single_test_error(
+            ummalqura,
+            "ah",
+            1444,
+            "M13",
+            1,
+            CalendarError::UnknownMonthCode("M13".parse().unwrap(), "Islamic (Umm al-Qura)"),
+        );
+
+        single_test_roundtrip(islamic_civil, "ah", 1, "M01", 1);
+        single_test_roundtrip(islamic_civil, "ah", 1444, "M09", 1);
+        single_test_roundtrip(islamic_civil, "ah", -100, "M12", 1);
+        single_test_error(
+            islamic_civil,
+            "ah",
+            1444,
+            "M13",
+            1,
+            CalendarError::UnknownMonthCode("M13".parse().unwrap(), "Islamic (civil)"),
+        );
+
+        single_test_roundtrip(islamic_tabular, "ah", 1, "M01", 1);
+        single_test_roundtrip(islamic_tabular, "ah", 1444, "M09", 1);
+        single_test_roundtrip(islamic_tabular, "ah", -100, "M12", 1);
+        single_test_error(
+            islamic_tabular,
+            "ah",
+            1444,
+            "M13",
+            1,
+            CalendarError::UnknownMonthCode("M13".parse().unwrap(), "Islamic (tabular)"),
+        );
+
+        single_test_roundtrip(persian, "ah", 1, "M01", 1);
+        single_test_roundtrip(persian, "ah", 1403, "M12", 30);
+        single_test_roundtrip(persian, "ah", -100, "M07", 1);
+        single_test_error(persian, "ah", 1402, "M12", 30, CalendarError::OutOfRange);
+        single_test_error(
+            persian,
+            "ah",
+            1402,
+            "M13",
+            1,
+            CalendarError::UnknownMonthCode("M13".parse().unwrap(), "Persian"),
+        );
+
+        single_test_roundtrip(roc, "roc", 10, "M05", 3);
+        single_test_roundtrip(roc, "roc-inverse", 15, "M01", 10);
+        single_test_error(roc, "roc", 0, "M03", 1, CalendarError::OutOfRange);
+        single_test_error(roc, "roc-inverse", 0, "M03", 1, CalendarError::OutOfRange);
+        single_test_error(
+            roc,
+            "roc",
+            100,
+            "M13",
+            1,
+            CalendarError::UnknownMonthCode("M13".parse().unwrap(), "ROC"),
+        );
+
         single_test_roundtrip(japanese, "reiwa", 3, "M03", 1);
         single_test_roundtrip(japanese, "heisei", 6, "M12", 1);
         single_test_roundtrip(japanese, "meiji", 10, "M03", 1);
@@ -0,0 +1,938 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Astronomical calculations needed by the lunisolar and observational calendars.
+//!
+//! Moments are represented as fractional R.D. (fixed) dates in universal time, as in
+//! _Calendrical Calculations_ by Reingold & Dershowitz, which is the source of all of
+//! the algorithms in this module.
+
+use crate::iso::Iso;
+
+/// Average length of a synodic month, in days
+pub(crate) const MEAN_SYNODIC_MONTH: f64 = 29.530588861;
+
+/// Average length of a tropical year, in days
+pub(crate) const MEAN_TROPICAL_YEAR: f64 = 365.242189;
+
+/// Solar longitude of the winter solstice, in degrees
+pub(crate) const WINTER: f64 = 270.0;
+
+/// Noon on January 1, 2000 (Gregorian)
+const J2000: f64 = 730120.5;
+
+/// The moment of the first new moon after R.D. 0, on January 11, 1 CE
+const NEW_MOON_ZERO: f64 = 11.458922815770109;
+
+/// `x mod y` for floats, with the sign of `y`
+pub(crate) fn rem_euclid(x: f64, y: f64) -> f64 {
+    x - y * libm::floor(x / y)
+}
+
+fn sin_degrees(x: f64) -> f64 {
+    libm::sin(x.to_radians())
+}
+
+fn cos_degrees(x: f64) -> f64 {
+    libm::cos(x.to_radians())
+}
+
+/// Evaluates the polynomial with the given coefficients (lowest order first) at `x`
+fn poly(x: f64, coefficients: &[f64]) -> f64 {
+    coefficients.iter().rev().fold(0.0, |acc, c| acc * x + c)
+}
+
+/// The difference between dynamical time and universal time at a moment, in days
+///
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L3884-L3952>
+fn ephemeris_correction(moment: f64) -> f64 {
+    // The Gregorian year containing the moment, approximately
+    let year = libm::floor(moment / 365.2425) as i32 + 1;
+    #[allow(clippy::unwrap_used)] // valid day and month
+    let mid_year = Iso::fixed_from_iso_integers(year, 7, 1).unwrap();
+    let c = (mid_year as f64 - 693596.0) / 36525.0;
+    let y = year as f64;
+    if (2051..=2150).contains(&year) {
+        let x = (y - 1820.0) / 100.0;
+        (-20.0 + 32.0 * x * x + 0.5628 * (2150.0 - y)) / 86400.0
+    } else if (2006..=2050).contains(&year) {
+        poly(y - 2000.0, &[62.92, 0.32217, 0.005589]) / 86400.0
+    } else if (1987..=2005).contains(&year) {
+        poly(
+            y - 2000.0,
+            &[
+                63.86,
+                0.3345,
+                -0.060374,
+                0.0017275,
+                0.000651814,
+                0.00002373599,
+            ],
+        ) / 86400.0
+    } else if (1900..=1986).contains(&year) {
+        poly(
+            c,
+            &[
+                -0.00002, 0.000297, 0.025184, -0.181133, 0.553040, -0.861938, 0.677066, -0.212591,
+            ],
+        )
+    } else if (1800..=1899).contains(&year) {
+        poly(
+            c,
+            &[
+                -0.000009, 0.003844, 0.083563, 0.865736, 4.867575, 15.845535, 31.332267, 38.291999,
+                28.316289, 11.636204, 2.043794,
+            ],
+        )
+    } else if (1700..=1799).contains(&year) {
+        poly(
+            y - 1700.0,
+            &[8.118780842, -0.005092142, 0.003336121, -0.0000266484],
+        ) / 86400.0
+    } else if (1600..=1699).contains(&year) {
+        poly(y - 1600.0, &[120.0, -0.9808, -0.01532, 0.000140272128]) / 86400.0
+    } else if (500..=1599).contains(&year) {
+        poly(
+            (y - 1000.0) / 100.0,
+            &[
+                1574.2,
+                -556.01,
+                71.23472,
+                0.319781,
+                -0.8503463,
+                -0.005050998,
+                0.0083572073,
+            ],
+        ) / 86400.0
+    } else if (-499..=499).contains(&year) {
+        poly(
+            y / 100.0,
+            &[
+                10583.6,
+                -1014.41,
+                33.78311,
+                -5.952053,
+                -0.1798452,
+                0.022174192,
+                0.0090316521,
+            ],
+        ) / 86400.0
+    } else {
+        let x = (y - 1820.0) / 100.0;
+        (-20.0 + 32.0 * x * x) / 86400.0
+    }
+}
+
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L3850-L3853>
+fn dynamical_from_universal(moment: f64) -> f64 {
+    moment + ephemeris_correction(moment)
+}
+
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L3845-L3848>
+fn universal_from_dynamical(moment: f64) -> f64 {
+    moment - ephemeris_correction(moment)
+}
+
+/// Number of (dynamical) Julian centuries since J2000
+///
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L3551-L3555>
+fn julian_centuries(moment: f64) -> f64 {
+    (dynamical_from_universal(moment) - J2000) / 36525.0
+}
+
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L4037-L4047>
+fn nutation(c: f64) -> f64 {
+    let a = poly(c, &[124.90, -1934.134, 0.002063]);
+    let b = poly(c, &[201.11, 72001.5377, 0.00057]);
+    -0.004778 * sin_degrees(a) - 0.0003667 * sin_degrees(b)
+}
+
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L4049-L4057>
+fn aberration(c: f64) -> f64 {
+    0.0000974 * cos_degrees(177.63 + 35999.01848 * c) - 0.005575
+}
+
+// Coefficients, multipliers, and addends for the solar longitude series
+#[rustfmt::skip]
+const SOLAR_LONGITUDE_TERMS: [(f64, f64, f64); 49] = [
+    (403406.0, 270.54861, 0.9287892), (195207.0, 340.19128, 35999.1376958),
+    (119433.0, 63.91854, 35999.4089666), (112392.0, 331.26220, 35998.7287385),
+    (3891.0, 317.843, 71998.20261), (2819.0, 86.631, 71998.4403),
+    (1721.0, 240.052, 36000.35726), (660.0, 310.26, 71997.4812),
+    (350.0, 247.23, 32964.4678), (334.0, 260.87, -19.4410),
+    (314.0, 297.82, 445267.1117), (268.0, 343.14, 45036.8840),
+    (242.0, 166.79, 3.1008), (234.0, 81.53, 22518.4434),
+    (158.0, 3.50, -19.9739), (132.0, 132.75, 65928.9345),
+    (129.0, 182.95, 9038.0293), (114.0, 162.03, 3034.7684),
+    (99.0, 29.8, 33718.148), (93.0, 266.4, 3034.448),
+    (86.0, 249.2, -2280.773), (78.0, 157.6, 29929.992),
+    (72.0, 257.8, 31556.493), (68.0, 185.1, 149.588),
+    (64.0, 69.9, 9037.750), (46.0, 8.0, 107997.405),
+    (38.0, 197.1, -4444.176), (37.0, 250.4, 151.771),
+    (32.0, 65.3, 67555.316), (29.0, 162.7, 31556.080),
+    (28.0, 341.5, -4561.540), (27.0, 291.6, 107996.706),
+    (27.0, 98.5, 1221.655), (25.0, 146.7, 62894.167),
+    (24.0, 110.0, 31437.369), (21.0, 5.2, 14578.298),
+    (21.0, 342.6, -31931.757), (20.0, 230.9, 34777.243),
+    (18.0, 256.1, 1221.999), (17.0, 45.3, 62894.511),
+    (14.0, 242.9, -4442.039), (13.0, 115.2, 107997.909),
+    (13.0, 151.8, 119.066), (13.0, 285.3, 16859.071),
+    (12.0, 53.3, -4.578), (10.0, 126.6, 26895.292),
+    (10.0, 205.7, -39.127), (10.0, 85.9, 12297.536),
+    (10.0, 146.1, 90073.778),
+];
+
+/// The longitude of the sun at a moment, in degrees
+///
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L3985-L4035>
+pub(crate) fn solar_longitude(moment: f64) -> f64 {
+    let c = julian_centuries(moment);
+    let sum: f64 = SOLAR_LONGITUDE_TERMS
+        .iter()
+        .map(|&(x, y, z)| x * sin_degrees(y + z * c))
+        .sum();
+    let lambda = 282.7771834 + 36000.76953744 * c + 0.000005729577951308232 * sum;
+    rem_euclid(lambda + aberration(c) + nutation(c), 360.0)
+}
+
+/// An estimate of the moment at or before `moment` when the solar longitude
+/// was `angle` degrees
+///
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L4132-L4146>
+pub(crate) fn estimate_prior_solar_longitude(angle: f64, moment: f64) -> f64 {
+    let rate = MEAN_TROPICAL_YEAR / 360.0;
+    let tau = moment - rate * rem_euclid(solar_longitude(moment) - angle, 360.0);
+    let delta = rem_euclid(solar_longitude(tau) - angle + 180.0, 360.0) - 180.0;
+    let estimate = tau - rate * delta;
+    if moment < estimate {
+        moment
+    } else {
+        estimate
+    }
+}
+
+// (v, w, x, y, z) for the periodic terms of the new moon series:
+// v * e^w * sin(x * solar_anomaly + y * lunar_anomaly + z * moon_argument)
+#[rustfmt::skip]
+const NEW_MOON_SINE_TERMS: [(f64, i32, f64, f64, f64); 24] = [
+    (-0.40720, 0, 0.0, 1.0, 0.0), (0.17241, 1, 1.0, 0.0, 0.0),
+    (0.01608, 0, 0.0, 2.0, 0.0), (0.01039, 0, 0.0, 0.0, 2.0),
+    (0.00739, 1, -1.0, 1.0, 0.0), (-0.00514, 1, 1.0, 1.0, 0.0),
+    (0.00208, 2, 2.0, 0.0, 0.0), (-0.00111, 0, 0.0, 1.0, -2.0),
+    (-0.00057, 0, 0.0, 1.0, 2.0), (0.00056, 1, 1.0, 2.0, 0.0),
+    (-0.00042, 0, 0.0, 3.0, 0.0), (0.00042, 1, 1.0, 0.0, 2.0),
+    (0.00038, 1, 1.0, 0.0, -2.0), (-0.00024, 1, -1.0, 2.0, 0.0),
+    (-0.00007, 0, 2.0, 1.0, 0.0), (0.00004, 0, 0.0, 2.0, -2.0),
+    (0.00004, 0, 3.0, 0.0, 0.0), (0.00003, 0, 1.0, 1.0, -2.0),
+    (0.00003, 0, 0.0, 2.0, 2.0), (-0.00003, 0, 1.0, 1.0, 2.0),
+    (0.00003, 0, -1.0, 1.0, 2.0), (-0.00002, 0, -1.0, 1.0, -2.0),
+    (-0.00002, 0, 1.0, 3.0, 0.0), (0.00002, 0, 0.0, 4.0, 0.0),
+];
+
+// (i, j, l) for the additional terms of the new moon series: l * sin(i + j * k)
+#[rustfmt::skip]
+const NEW_MOON_ADDITIONAL_TERMS: [(f64, f64, f64); 13] = [
+    (251.88, 0.016321, 0.000165), (251.83, 26.651886, 0.000164),
+    (349.42, 36.412478, 0.000126), (84.66, 18.206239, 0.000110),
+    (141.74, 53.303771, 0.000062), (207.14, 2.453732, 0.000060),
+    (154.84, 7.306860, 0.000056), (34.52, 27.261239, 0.000047),
+    (207.19, 0.121824, 0.000042), (291.34, 1.844379, 0.000040),
+    (161.72, 24.198154, 0.000037), (239.56, 25.513099, 0.000035),
+    (331.55, 3.592518, 0.000023),
+];
+
+/// The moment (in universal time) of the `n`th new moon after the new moon
+/// of January 11, 1 CE (or before it, if `n` is negative)
+///
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L4288-L4377>
+pub(crate) fn nth_new_moon(n: i32) -> f64 {
+    let k = n as f64 - 24724.0;
+    let c = k / 1236.85;
+    let approx = J2000
+        + poly(
+            c,
+            &[
+                5.09766,
+                MEAN_SYNODIC_MONTH * 1236.85,
+                0.00015437,
+                -0.00000015,
+                0.00000000073,
+            ],
+        );
+    let e = poly(c, &[1.0, -0.002516, -0.0000074]);
+    let solar_anomaly = poly(c, &[2.5534, 1236.85 * 29.10535670, -0.0000014, -0.00000011]);
+    let lunar_anomaly = poly(
+        c,
+        &[
+            201.5643,
+            385.81693528 * 1236.85,
+            0.0107582,
+            0.00001238,
+            -0.000000058,
+        ],
+    );
+    let moon_argument = poly(
+        c,
+        &[
+            160.7108,
+            390.67050284 * 1236.85,
+            -0.0016118,
+            -0.00000227,
+            0.000000011,
+        ],
+    );
+    let omega = poly(c, &[124.7746, -1.56375588 * 1236.85, 0.0020672, 0.00000215]);
+    let correction = -0.00017 * sin_degrees(omega)
+        + NEW_MOON_SINE_TERMS
+            .iter()
+            .map(|&(v, w, x, y, z)| {
+                v * libm::pow(e, w as f64)
+                    * sin_degrees(x * solar_anomaly + y * lunar_anomaly + z * moon_argument)
+            })
+            .sum::<f64>();
+    let extra = 0.000325 * sin_degrees(poly(c, &[299.77, 132.8475848, -0.009173]));
+    let additional: f64 = NEW_MOON_ADDITIONAL_TERMS
+        .iter()
+        .map(|&(i, j, l)| l * sin_degrees(i + j * k))
+        .sum();
+    universal_from_dynamical(approx + correction + extra + additional)
+}
+
+/// The index of the first new moon at or after `moment`
+fn new_moon_index_at_or_after(moment: f64) -> i32 {
+    // Start from the mean lunation and correct for the difference to the true new moon,
+    // which is never more than a fraction of a month.
+    let mut n = libm::round((moment - NEW_MOON_ZERO) / MEAN_SYNODIC_MONTH) as i32;
+    while nth_new_moon(n) >= moment {
+        n -= 1;
+    }
+    while nth_new_moon(n) < moment {
+        n += 1;
+    }
+    n
+}
+
+/// The moment of the last new moon strictly before `moment`
+///
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L4379-L4386>
+pub(crate) fn new_moon_before(moment: f64) -> f64 {
+    nth_new_moon(new_moon_index_at_or_after(moment) - 1)
+}
+
+/// The moment of the first new moon at or after `moment`
+///
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L4388-L4395>
+pub(crate) fn new_moon_at_or_after(moment: f64) -> f64 {
+    nth_new_moon(new_moon_index_at_or_after(moment))
+}
+
+/// A location on Earth, for the calculations that depend on the position of an observer
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct Location {
+    /// Latitude in degrees north of the equator
+    pub(crate) latitude: f64,
+    /// Longitude in degrees east of Greenwich
+    pub(crate) longitude: f64,
+    /// Elevation above sea level, in meters
+    pub(crate) elevation: f64,
+    /// Offset of the local standard time from universal time, in days
+    pub(crate) zone: f64,
+}
+
+fn tan_degrees(x: f64) -> f64 {
+    libm::tan(x.to_radians())
+}
+
+fn asin_degrees(x: f64) -> f64 {
+    libm::asin(x).to_degrees()
+}
+
+fn acos_degrees(x: f64) -> f64 {
+    libm::acos(x).to_degrees()
+}
+
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L3496-L3499>
+fn universal_from_local(moment: f64, location: Location) -> f64 {
+    moment - location.longitude / 360.0
+}
+
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L3473-L3477>
+fn standard_from_universal(moment: f64, location: Location) -> f64 {
+    moment + location.zone
+}
+
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L3479-L3483>
+pub(crate) fn universal_from_standard(moment: f64, location: Location) -> f64 {
+    moment - location.zone
+}
+
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L3501-L3506>
+fn standard_from_local(moment: f64, location: Location) -> f64 {
+    standard_from_universal(universal_from_local(moment, location), location)
+}
+
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L3521-L3524>
+fn local_from_apparent(moment: f64, location: Location) -> f64 {
+    moment - equation_of_time(universal_from_local(moment, location))
+}
+
+/// The inclination of the Earth's axis to the ecliptic, in degrees
+///
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L3557-L3565>
+fn obliquity(moment: f64) -> f64 {
+    let c = julian_centuries(moment);
+    23.0 + 26.0 / 60.0
+        + 21.448 / 3600.0
+        + poly(
+            c,
+            &[0.0, -46.8150 / 3600.0, -0.00059 / 3600.0, 0.001813 / 3600.0],
+        )
+}
+
+/// The declination of a body at ecliptic latitude `beta` and longitude `lambda`
+///
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L3567-L3576>
+fn declination(moment: f64, beta: f64, lambda: f64) -> f64 {
+    let epsilon = obliquity(moment);
+    asin_degrees(
+        sin_degrees(beta) * cos_degrees(epsilon)
+            + cos_degrees(beta) * sin_degrees(epsilon) * sin_degrees(lambda),
+    )
+}
+
+/// The right ascension of a body at ecliptic latitude `beta` and longitude `lambda`
+///
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L3578-L3588>
+fn right_ascension(moment: f64, beta: f64, lambda: f64) -> f64 {
+    let epsilon = obliquity(moment);
+    let y = sin_degrees(lambda) * cos_degrees(epsilon) - tan_degrees(beta) * sin_degrees(epsilon);
+    let x = cos_degrees(lambda);
+    rem_euclid(libm::atan2(y, x).to_degrees(), 360.0)
+}
+
+/// The difference between apparent (sundial) time and mean time, in days
+///
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L3954-L3983>
+fn equation_of_time(moment: f64) -> f64 {
+    let c = julian_centuries(moment);
+    let lambda = poly(c, &[280.46645, 36000.76983, 0.0003032]);
+    let anomaly = poly(c, &[357.52910, 35999.05030, -0.0001559, -0.00000048]);
+    let eccentricity = poly(c, &[0.016708617, -0.000042037, -0.0000001236]);
+    let y = tan_degrees(obliquity(moment) / 2.0);
+    let y = y * y;
+    let equation = (y * sin_degrees(2.0 * lambda) - 2.0 * eccentricity * sin_degrees(anomaly)
+        + 4.0 * eccentricity * y * sin_degrees(anomaly) * cos_degrees(2.0 * lambda)
+        - 0.5 * y * y * sin_degrees(4.0 * lambda)
+        - 1.25 * eccentricity * eccentricity * sin_degrees(2.0 * anomaly))
+        / (2.0 * core::f64::consts::PI);
+    libm::copysign(libm::fabs(equation).min(0.5), equation)
+}
+
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L3590-L3605>
+fn sine_offset(moment: f64, location: Location, alpha: f64) -> f64 {
+    let phi = location.latitude;
+    let moment = universal_from_local(moment, location);
+    let delta = declination(moment, 0.0, solar_longitude(moment));
+    tan_degrees(phi) * tan_degrees(delta)
+        + sin_degrees(alpha) / (cos_degrees(delta) * cos_degrees(phi))
+}
+
+/// An approximation of the moment in local time near `moment` when the sun is `alpha`
+/// degrees below the horizon, in the morning if `early` is set and in the evening otherwise
+///
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L3607-L3631>
+fn approx_moment_of_depression(
+    moment: f64,
+    location: Location,
+    alpha: f64,
+    early: bool,
+) -> Option<f64> {
+    let date = libm::floor(moment);
+    let value = match sine_offset(moment, location, alpha) {
+        value if libm::fabs(value) <= 1.0 => value,
+        _ => {
+            let alternative = match (alpha >= 0.0, early) {
+                (true, true) => date,
+                (true, false) => date + 1.0,
+                (false, _) => date + 0.5,
+            };
+            sine_offset(alternative, location, alpha)
+        }
+    };
+    if libm::fabs(value) > 1.0 {
+        return None;
+    }
+    let offset = rem_euclid(asin_degrees(value) / 360.0 + 0.5, 1.0) - 0.5;
+    let moment = if early {
+        date + 0.25 - offset
+    } else {
+        date + 0.75 + offset
+    };
+    Some(local_from_apparent(moment, location))
+}
+
+/// The moment in local time near `approx` when the sun is `alpha` degrees below the horizon
+///
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L3633-L3647>
+fn moment_of_depression(approx: f64, location: Location, alpha: f64, early: bool) -> Option<f64> {
+    let mut approx = approx;
+    // Each iteration refines the estimate; it converges within a few steps
+    for _ in 0..10 {
+        let moment = approx_moment_of_depression(approx, location, alpha, early)?;
+        if libm::fabs(approx - moment) < 30.0 / 86400.0 {
+            return Some(moment);
+        }
+        approx = moment;
+    }
+    Some(approx)
+}
+
+/// The standard time in the evening of `date` when the sun is `alpha` degrees below the horizon
+///
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L3670-L3679>
+fn dusk(date: f64, location: Location, alpha: f64) -> Option<f64> {
+    let moment = moment_of_depression(date + 0.75, location, alpha, false)?;
+    Some(standard_from_local(moment, location))
+}
+
+/// The refraction of the horizon at a location, in degrees
+///
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L3681-L3690>
+fn refraction(location: Location) -> f64 {
+    let h = location.elevation.max(0.0);
+    let earth_radius = 6.372e6;
+    let dip = acos_degrees(earth_radius / (earth_radius + h));
+    34.0 / 60.0 + dip + 19.0 / 3600.0 * libm::sqrt(h)
+}
+
+/// The standard time of sunset on `date`, if the sun sets on that day
+///
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L3700-L3706>
+pub(crate) fn sunset(date: f64, location: Location) -> Option<f64> {
+    dusk(date, location, refraction(location) + 16.0 / 60.0)
+}
+
+/// The sidereal time at a moment, as an angle in degrees
+///
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L3860-L3870>
+fn sidereal_from_moment(moment: f64) -> f64 {
+    let c = (moment - J2000) / 36525.0;
+    rem_euclid(
+        poly(
+            c,
+            &[
+                280.46061837,
+                36525.0 * 360.98564736629,
+                0.000387933,
+                -1.0 / 38710000.0,
+            ],
+        ),
+        360.0,
+    )
+}
+
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L4148-L4158>
+fn mean_lunar_longitude(c: f64) -> f64 {
+    rem_euclid(
+        poly(
+            c,
+            &[
+                218.3164477,
+                481267.88123421,
+                -0.0015786,
+                1.0 / 538841.0,
+                -1.0 / 65194000.0,
+            ],
+        ),
+        360.0,
+    )
+}
+
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L4160-L4170>
+fn lunar_elongation(c: f64) -> f64 {
+    rem_euclid(
+        poly(
+            c,
+            &[
+                297.85019021,
+                445267.1114034,
+                -0.0018819,
+                1.0 / 545868.0,
+                -1.0 / 113065000.0,
+            ],
+        ),
+        360.0,
+    )
+}
+
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L4172-L4182>
+fn solar_anomaly(c: f64) -> f64 {
+    rem_euclid(
+        poly(
+            c,
+            &[357.5291092, 35999.0502909, -0.0001536, 1.0 / 24490000.0],
+        ),
+        360.0,
+    )
+}
+
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L4184-L4194>
+fn lunar_anomaly(c: f64) -> f64 {
+    rem_euclid(
+        poly(
+            c,
+            &[
+                134.9633964,
+                477198.8675055,
+                0.0087414,
+                1.0 / 69699.0,
+                -1.0 / 14712000.0,
+            ],
+        ),
+        360.0,
+    )
+}
+
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L4196-L4206>
+fn moon_node(c: f64) -> f64 {
+    rem_euclid(
+        poly(
+            c,
+            &[
+                93.2720950,
+                483202.0175233,
+                -0.0036539,
+                -1.0 / 3526000.0,
+                1.0 / 863310000.0,
+            ],
+        ),
+        360.0,
+    )
+}
+
+// (w, x, y, z, v) for the periodic terms of the lunar position series:
+// v * e^|x| * f(w * elongation + x * solar_anomaly + y * lunar_anomaly + z * moon_node),
+// where f is the sine for longitude and latitude, and the cosine for distance
+#[rustfmt::skip]
+const LUNAR_LONGITUDE_TERMS: [(f64, f64, f64, f64, f64); 59] = [
+    (0.0, 0.0, 1.0, 0.0, 6288774.0), (2.0, 0.0, -1.0, 0.0, 1274027.0),
+    (2.0, 0.0, 0.0, 0.0, 658314.0), (0.0, 0.0, 2.0, 0.0, 213618.0),
+    (0.0, 1.0, 0.0, 0.0, -185116.0), (0.0, 0.0, 0.0, 2.0, -114332.0),
+    (2.0, 0.0, -2.0, 0.0, 58793.0), (2.0, -1.0, -1.0, 0.0, 57066.0),
+    (2.0, 0.0, 1.0, 0.0, 53322.0), (2.0, -1.0, 0.0, 0.0, 45758.0),
+    (0.0, 1.0, -1.0, 0.0, -40923.0), (1.0, 0.0, 0.0, 0.0, -34720.0),
+    (0.0, 1.0, 1.0, 0.0, -30383.0), (2.0, 0.0, 0.0, -2.0, 15327.0),
+    (0.0, 0.0, 1.0, 2.0, -12528.0), (0.0, 0.0, 1.0, -2.0, 10980.0),
+    (4.0, 0.0, -1.0, 0.0, 10675.0), (0.0, 0.0, 3.0, 0.0, 10034.0),
+    (4.0, 0.0, -2.0, 0.0, 8548.0), (2.0, 1.0, -1.0, 0.0, -7888.0),
+    (2.0, 1.0, 0.0, 0.0, -6766.0), (1.0, 0.0, -1.0, 0.0, -5163.0),
+    (1.0, 1.0, 0.0, 0.0, 4987.0), (2.0, -1.0, 1.0, 0.0, 4036.0),
+    (2.0, 0.0, 2.0, 0.0, 3994.0), (4.0, 0.0, 0.0, 0.0, 3861.0),
+    (2.0, 0.0, -3.0, 0.0, 3665.0), (0.0, 1.0, -2.0, 0.0, -2689.0),
+    (2.0, 0.0, -1.0, 2.0, -2602.0), (2.0, -1.0, -2.0, 0.0, 2390.0),
+    (1.0, 0.0, 1.0, 0.0, -2348.0), (2.0, -2.0, 0.0, 0.0, 2236.0),
+    (0.0, 1.0, 2.0, 0.0, -2120.0), (0.0, 2.0, 0.0, 0.0, -2069.0),
+    (2.0, -2.0, -1.0, 0.0, 2048.0), (2.0, 0.0, 1.0, -2.0, -1773.0),
+    (2.0, 0.0, 0.0, 2.0, -1595.0), (4.0, -1.0, -1.0, 0.0, 1215.0),
+    (0.0, 0.0, 2.0, 2.0, -1110.0), (3.0, 0.0, -1.0, 0.0, -892.0),
+    (2.0, 1.0, 1.0, 0.0, -810.0), (4.0, -1.0, -2.0, 0.0, 759.0),
+    (0.0, 2.0, -1.0, 0.0, -713.0), (2.0, 2.0, -1.0, 0.0, -700.0),
+    (2.0, 1.0, -2.0, 0.0, 691.0), (2.0, -1.0, 0.0, -2.0, 596.0),
+    (4.0, 0.0, 1.0, 0.0, 549.0), (0.0, 0.0, 4.0, 0.0, 537.0),
+    (4.0, -1.0, 0.0, 0.0, 520.0), (1.0, 0.0, -2.0, 0.0, -487.0),
+    (2.0, 1.0, 0.0, -2.0, -399.0), (0.0, 0.0, 2.0, -2.0, -381.0),
+    (1.0, 1.0, 1.0, 0.0, 351.0), (3.0, 0.0, -2.0, 0.0, -340.0),
+    (4.0, 0.0, -3.0, 0.0, 330.0), (2.0, -1.0, 2.0, 0.0, 327.0),
+    (0.0, 2.0, 1.0, 0.0, -323.0), (1.0, 1.0, -1.0, 0.0, 299.0),
+    (2.0, 0.0, 3.0, 0.0, 294.0),
+];
+
+#[rustfmt::skip]
+const LUNAR_LATITUDE_TERMS: [(f64, f64, f64, f64, f64); 60] = [
+    (0.0, 0.0, 0.0, 1.0, 5128122.0), (0.0, 0.0, 1.0, 1.0, 280602.0),
+    (0.0, 0.0, 1.0, -1.0, 277693.0), (2.0, 0.0, 0.0, -1.0, 173237.0),
+    (2.0, 0.0, -1.0, 1.0, 55413.0), (2.0, 0.0, -1.0, -1.0, 46271.0),
+    (2.0, 0.0, 0.0, 1.0, 32573.0), (0.0, 0.0, 2.0, 1.0, 17198.0),
+    (2.0, 0.0, 1.0, -1.0, 9266.0), (0.0, 0.0, 2.0, -1.0, 8822.0),
+    (2.0, -1.0, 0.0, -1.0, 8216.0), (2.0, 0.0, -2.0, -1.0, 4324.0),
+    (2.0, 0.0, 1.0, 1.0, 4200.0), (2.0, 1.0, 0.0, -1.0, -3359.0),
+    (2.0, -1.0, -1.0, 1.0, 2463.0), (2.0, -1.0, 0.0, 1.0, 2211.0),
+    (2.0, -1.0, -1.0, -1.0, 2065.0), (0.0, 1.0, -1.0, -1.0, -1870.0),
+    (4.0, 0.0, -1.0, -1.0, 1828.0), (0.0, 1.0, 0.0, 1.0, -1794.0),
+    (0.0, 0.0, 0.0, 3.0, -1749.0), (0.0, 1.0, -1.0, 1.0, -1565.0),
+    (1.0, 0.0, 0.0, 1.0, -1491.0), (0.0, 1.0, 1.0, 1.0, -1475.0),
+    (0.0, 1.0, 1.0, -1.0, -1410.0), (0.0, 1.0, 0.0, -1.0, -1344.0),
+    (1.0, 0.0, 0.0, -1.0, -1335.0), (0.0, 0.0, 3.0, 1.0, 1107.0),
+    (4.0, 0.0, 0.0, -1.0, 1021.0), (4.0, 0.0, -1.0, 1.0, 833.0),
+    (0.0, 0.0, 1.0, -3.0, 777.0), (4.0, 0.0, -2.0, 1.0, 671.0),
+    (2.0, 0.0, 0.0, -3.0, 607.0), (2.0, 0.0, 2.0, -1.0, 596.0),
+    (2.0, -1.0, 1.0, -1.0, 491.0), (2.0, 0.0, -2.0, 1.0, -451.0),
+    (0.0, 0.0, 3.0, -1.0, 439.0), (2.0, 0.0, 2.0, 1.0, 422.0),
+    (2.0, 0.0, -3.0, -1.0, 421.0), (2.0, 1.0, -1.0, 1.0, -366.0),
+    (2.0, 1.0, 0.0, 1.0, -351.0), (4.0, 0.0, 0.0, 1.0, 331.0),
+    (2.0, -1.0, 1.0, 1.0, 315.0), (2.0, -2.0, 0.0, -1.0, 302.0),
+    (0.0, 0.0, 1.0, 3.0, -283.0), (2.0, 1.0, 1.0, -1.0, -229.0),
+    (1.0, 1.0, 0.0, -1.0, 223.0), (1.0, 1.0, 0.0, 1.0, 223.0),
+    (0.0, 1.0, -2.0, -1.0, -220.0), (2.0, 1.0, -1.0, -1.0, -220.0),
+    (1.0, 0.0, 1.0, 1.0, -185.0), (2.0, -1.0, -2.0, -1.0, 181.0),
+    (0.0, 1.0, 2.0, 1.0, -177.0), (4.0, 0.0, -2.0, -1.0, 176.0),
+    (4.0, -1.0, -1.0, -1.0, 166.0), (1.0, 0.0, 1.0, -1.0, -164.0),
+    (4.0, 0.0, 1.0, -1.0, 132.0), (1.0, 0.0, -1.0, -1.0, -119.0),
+    (4.0, -1.0, 0.0, -1.0, 115.0), (2.0, -2.0, 0.0, 1.0, 107.0),
+];
+
+#[rustfmt::skip]
+const LUNAR_DISTANCE_TERMS: [(f64, f64, f64, f64, f64); 60] = [
+    (0.0, 0.0, 1.0, 0.0, -20905355.0), (2.0, 0.0, -1.0, 0.0, -3699111.0),
+    (2.0, 0.0, 0.0, 0.0, -2955968.0), (0.0, 0.0, 2.0, 0.0, -569925.0),
+    (0.0, 1.0, 0.0, 0.0, 48888.0), (0.0, 0.0, 0.0, 2.0, -3149.0),
+    (2.0, 0.0, -2.0, 0.0, 246158.0), (2.0, -1.0, -1.0, 0.0, -152138.0),
+    (2.0, 0.0, 1.0, 0.0, -170733.0), (2.0, -1.0, 0.0, 0.0, -204586.0),
+    (0.0, 1.0, -1.0, 0.0, -129620.0), (1.0, 0.0, 0.0, 0.0, 108743.0),
+    (0.0, 1.0, 1.0, 0.0, 104755.0), (2.0, 0.0, 0.0, -2.0, 10321.0),
+    (0.0, 0.0, 1.0, 2.0, 0.0), (0.0, 0.0, 1.0, -2.0, 79661.0),
+    (4.0, 0.0, -1.0, 0.0, -34782.0), (0.0, 0.0, 3.0, 0.0, -23210.0),
+    (4.0, 0.0, -2.0, 0.0, -21636.0), (2.0, 1.0, -1.0, 0.0, 24208.0),
+    (2.0, 1.0, 0.0, 0.0, 30824.0), (1.0, 0.0, -1.0, 0.0, -8379.0),
+    (1.0, 1.0, 0.0, 0.0, -16675.0), (2.0, -1.0, 1.0, 0.0, -12831.0),
+    (2.0, 0.0, 2.0, 0.0, -10445.0), (4.0, 0.0, 0.0, 0.0, -11650.0),
+    (2.0, 0.0, -3.0, 0.0, 14403.0), (0.0, 1.0, -2.0, 0.0, -7003.0),
+    (2.0, 0.0, -1.0, 2.0, 0.0), (2.0, -1.0, -2.0, 0.0, 10056.0),
+    (1.0, 0.0, 1.0, 0.0, 6322.0), (2.0, -2.0, 0.0, 0.0, -9884.0),
+    (0.0, 1.0, 2.0, 0.0, 5751.0), (0.0, 2.0, 0.0, 0.0, 0.0),
+    (2.0, -2.0, -1.0, 0.0, -4950.0), (2.0, 0.0, 1.0, -2.0, 4130.0),
+    (2.0, 0.0, 0.0, 2.0, 0.0), (4.0, -1.0, -1.0, 0.0, -3958.0),
+    (0.0, 0.0, 2.0, 2.0, 0.0), (3.0, 0.0, -1.0, 0.0, 3258.0),
+    (2.0, 1.0, 1.0, 0.0, 2616.0), (4.0, -1.0, -2.0, 0.0, -1897.0),
+    (0.0, 2.0, -1.0, 0.0, -2117.0), (2.0, 2.0, -1.0, 0.0, 2354.0),
+    (2.0, 1.0, -2.0, 0.0, 0.0), (2.0, -1.0, 0.0, -2.0, 0.0),
+    (4.0, 0.0, 1.0, 0.0, -1423.0), (0.0, 0.0, 4.0, 0.0, -1117.0),
+    (4.0, -1.0, 0.0, 0.0, -1571.0), (1.0, 0.0, -2.0, 0.0, -1739.0),
+    (2.0, 1.0, 0.0, -2.0, 0.0), (0.0, 0.0, 2.0, -2.0, -4421.0),
+    (1.0, 1.0, 1.0, 0.0, 0.0), (3.0, 0.0, -2.0, 0.0, 0.0),
+    (4.0, 0.0, -3.0, 0.0, 0.0), (2.0, -1.0, 2.0, 0.0, 0.0),
+    (0.0, 2.0, 1.0, 0.0, 1165.0), (1.0, 1.0, -1.0, 0.0, 0.0),
+    (2.0, 0.0, 3.0, 0.0, 0.0), (2.0, 0.0, -1.0, -2.0, 8752.0),
+];
+
+/// Sums the periodic terms of a lunar position series at `c` Julian centuries
+fn lunar_series(c: f64, terms: &[(f64, f64, f64, f64, f64)], f: impl Fn(f64) -> f64) -> f64 {
+    let elongation = lunar_elongation(c);
+    let solar_anomaly = solar_anomaly(c);
+    let lunar_anomaly = lunar_anomaly(c);
+    let moon_node = moon_node(c);
+    let e = poly(c, &[1.0, -0.002516, -0.0000074]);
+    terms
+        .iter()
+        .map(|&(w, x, y, z, v)| {
+            v * libm::pow(e, libm::fabs(x))
+                * f(w * elongation + x * solar_anomaly + y * lunar_anomaly + z * moon_node)
+        })
+        .sum()
+}
+
+/// The longitude of the moon at a moment, in degrees
+///
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L4215-L4278>
+fn lunar_longitude(moment: f64) -> f64 {
+    let c = julian_centuries(moment);
+    let mean_longitude = mean_lunar_longitude(c);
+    let correction = lunar_series(c, &LUNAR_LONGITUDE_TERMS, sin_degrees) / 1_000_000.0;
+    let venus = 3958.0 / 1_000_000.0 * sin_degrees(119.75 + c * 131.849);
+    let jupiter = 318.0 / 1_000_000.0 * sin_degrees(53.09 + c * 479264.29);
+    let flat_earth = 1962.0 / 1_000_000.0 * sin_degrees(mean_longitude - moon_node(c));
+    rem_euclid(
+        mean_longitude + correction + venus + jupiter + flat_earth + nutation(c),
+        360.0,
+    )
+}
+
+/// The latitude of the moon at a moment, in degrees
+///
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L4466-L4526>
+fn lunar_latitude(moment: f64) -> f64 {
+    let c = julian_centuries(moment);
+    let mean_longitude = mean_lunar_longitude(c);
+    let lunar_anomaly = lunar_anomaly(c);
+    let moon_node = moon_node(c);
+    let correction = lunar_series(c, &LUNAR_LATITUDE_TERMS, sin_degrees) / 1_000_000.0;
+    let venus = 175.0 / 1_000_000.0
+        * (sin_degrees(119.75 + c * 131.849 + moon_node)
+            + sin_degrees(119.75 + c * 131.849 - moon_node));
+    let flat_earth = (-2235.0 * sin_degrees(mean_longitude)
+        + 127.0 * sin_degrees(mean_longitude - lunar_anomaly)
+        - 115.0 * sin_degrees(mean_longitude + lunar_anomaly))
+        / 1_000_000.0;
+    let extra = 382.0 / 1_000_000.0 * sin_degrees(313.45 + c * 481266.484);
+    correction + venus + flat_earth + extra
+}
+
+/// The distance from the center of the Earth to the moon at a moment, in meters
+///
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L4568-L4617>
+fn lunar_distance(moment: f64) -> f64 {
+    let c = julian_centuries(moment);
+    385000560.0 + lunar_series(c, &LUNAR_DISTANCE_TERMS, cos_degrees)
+}
+
+/// The geocentric altitude of the moon above the horizon at a moment, in degrees
+///
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L4537-L4566>
+fn lunar_altitude(moment: f64, location: Location) -> f64 {
+    let phi = location.latitude;
+    let lambda = lunar_longitude(moment);
+    let beta = lunar_latitude(moment);
+    let alpha = right_ascension(moment, beta, lambda);
+    let delta = declination(moment, beta, lambda);
+    let hour_angle = rem_euclid(
+        sidereal_from_moment(moment) + location.longitude - alpha,
+        360.0,
+    );
+    let altitude = asin_degrees(
+        sin_degrees(phi) * sin_degrees(delta)
+            + cos_degrees(phi) * cos_degrees(delta) * cos_degrees(hour_angle),
+    );
+    rem_euclid(altitude + 180.0, 360.0) - 180.0
+}
+
+/// The altitude of the upper limb of the moon as seen by an observer at `location`,
+/// corrected for parallax and refraction
+///
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L4619-L4653>
+fn observed_lunar_altitude(moment: f64, location: Location) -> f64 {
+    let altitude = lunar_altitude(moment, location);
+    let parallax = asin_degrees(6378140.0 / lunar_distance(moment) * cos_degrees(altitude));
+    altitude - parallax + refraction(location) + 16.0 / 60.0
+}
+
+/// The phase of the moon at a moment, as the difference between the lunar and solar
+/// longitudes in degrees: 0 at new moon, 90 at first quarter, and so on
+///
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L4397-L4414>
+fn lunar_phase(moment: f64) -> f64 {
+    let phi = rem_euclid(lunar_longitude(moment) - solar_longitude(moment), 360.0);
+    let n = libm::round((moment - NEW_MOON_ZERO) / MEAN_SYNODIC_MONTH) as i32;
+    // Guard against the occasional inaccuracy of the longitudes near a new moon
+    let phi_prime = 360.0 * rem_euclid((moment - nth_new_moon(n)) / MEAN_SYNODIC_MONTH, 1.0);
+    if libm::fabs(phi - phi_prime) > 180.0 {
+        phi_prime
+    } else {
+        phi
+    }
+}
+
+/// The last moment at or before `moment` when the lunar phase was `phase` degrees
+///
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L4416-L4427>
+pub(crate) fn lunar_phase_at_or_before(phase: f64, moment: f64) -> f64 {
+    let tau = moment - MEAN_SYNODIC_MONTH / 360.0 * rem_euclid(lunar_phase(moment) - phase, 360.0);
+    let mut low = tau - 2.0;
+    let mut high = moment.min(tau + 2.0);
+    while high - low >= 1e-5 {
+        let mid = (low + high) / 2.0;
+        if rem_euclid(lunar_phase(mid) - phase, 360.0) < 180.0 {
+            high = mid;
+        } else {
+            low = mid;
+        }
+    }
+    (low + high) / 2.0
+}
+
+/// The standard time of moonset on `date`, if the moon sets on that day
+///
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L4655-L4681>
+fn moonset(date: f64, location: Location) -> Option<f64> {
+    let moment = universal_from_standard(date, location);
+    let waxing = lunar_phase(moment) < 180.0;
+    let altitude = observed_lunar_altitude(moment, location);
+    let offset = altitude / (4.0 * (90.0 - libm::fabs(location.latitude)));
+    let approx = if !waxing {
+        moment - offset + 0.5
+    } else if offset > 0.0 {
+        moment + offset
+    } else {
+        moment + 1.0 + offset
+    };
+    let mut low = approx - 0.25;
+    let mut high = approx + 0.25;
+    while high - low >= 1.0 / 1440.0 {
+        let mid = (low + high) / 2.0;
+        if observed_lunar_altitude(mid, location) < 0.0 {
+            high = mid;
+        } else {
+            low = mid;
+        }
+    }
+    let set = (low + high) / 2.0;
+    if set < moment + 1.0 {
+        Some(standard_from_universal(set, location).max(date))
+    } else {
+        None
+    }
+}
+
+/// The time between sunset and moonset on `date`, in days
+///
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L6770-L6778>
+pub(crate) fn moonlag(date: f64, location: Location) -> Option<f64> {
+    let sun = sunset(date, location)?;
+    Some(match moonset(date, location) {
+        Some(moon) => moon - sun,
+        None => 1.0,
+    })
+}
+
+/// The phase of the moon at sunset on `date`, if the sun sets on that day
+pub(crate) fn lunar_phase_at_sunset(date: f64, location: Location) -> Option<f64> {
+    let sunset = sunset(date, location)?;
+    Some(lunar_phase(universal_from_standard(sunset, location)))
+}
+
+/// Whether the crescent moon is likely to be visible on the eve of `date` at `location`,
+/// using the criterion of S. K. Shaukat
+///
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L7284-L7346>
+pub(crate) fn visible_crescent(date: f64, location: Location) -> bool {
+    // The best time for viewing the young moon, when the sun is 4.5° below the horizon
+    let best_view =
+        universal_from_standard(dusk(date - 1.0, location, 4.5).unwrap_or(date), location);
+    let phase = lunar_phase(best_view);
+    let arc_of_light = acos_degrees(cos_degrees(lunar_latitude(best_view)) * cos_degrees(phase));
+    0.0 < phase
+        && phase < 90.0
+        && (10.6..=90.0).contains(&arc_of_light)
+        && lunar_altitude(best_view, location) > 4.1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_moons() {
+        // Reference values from Table 14.3 of Calendrical Calculations (4th edition)
+        // and the sample data distributed with the book
+        let fixed = |y, m, d| Iso::fixed_from_iso_integers(y, m, d).unwrap() as f64;
+        // The 0th new moon is on January 11, 1 CE
+        assert_eq!(libm::floor(nth_new_moon(0)), fixed(1, 1, 11));
+        // New moon of 2000-01-06 18:14 UTC
+        let moon = new_moon_at_or_after(fixed(2000, 1, 1));
+        assert!((moon - (fixed(2000, 1, 6) + (18.0 * 60.0 + 14.0) / 1440.0)).abs() < 0.01);
+        assert!(new_moon_before(moon) < moon);
+        assert_eq!(new_moon_at_or_after(moon), moon);
+        assert!((moon - new_moon_before(moon) - MEAN_SYNODIC_MONTH).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_solar_longitude() {
+        let fixed = |y, m, d| Iso::fixed_from_iso_integers(y, m, d).unwrap() as f64;
+        // December solstice of 2022 was at 21:48 UTC on December 21
+        let solstice = fixed(2022, 12, 21) + (21.0 * 60.0 + 48.0) / 1440.0;
+        assert!(solar_longitude(solstice - 0.01) < WINTER);
+        assert!(solar_longitude(solstice + 0.01) > WINTER);
+        let estimate = estimate_prior_solar_longitude(WINTER, fixed(2023, 1, 1));
+        assert!((estimate - solstice).abs() < 0.5);
+    }
+}
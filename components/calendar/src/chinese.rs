@@ -0,0 +1,349 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains types and implementations for the Chinese calendar.
+//!
+//! ```rust
+//! use icu::calendar::{chinese::Chinese, Date, DateTime};
+//!
+//! // `Date` type
+//! let date_iso = Date::try_new_iso_date(2023, 6, 23)
+//!     .expect("Failed to initialize ISO Date instance.");
+//! let date_chinese = Date::new_from_iso(date_iso, Chinese);
+//!
+//! // `DateTime` type
+//! let datetime_iso = DateTime::try_new_iso_datetime(2023, 6, 23, 13, 1, 0)
+//!     .expect("Failed to initialize ISO DateTime instance.");
+//! let datetime_chinese = DateTime::new_from_iso(datetime_iso, Chinese);
+//!
+//! // `Date` checks
+//! assert_eq!(date_chinese.year().number, 4660);
+//! assert_eq!(date_chinese.year().related_iso, Some(2023));
+//! assert_eq!(date_chinese.year().cyclic.unwrap().get(), 40);
+//! assert_eq!(date_chinese.month().ordinal, 6);
+//! assert_eq!(date_chinese.day_of_month().0, 6);
+//!
+//! // `DateTime` checks
+//! assert_eq!(datetime_chinese.date.year().number, 4660);
+//! assert_eq!(datetime_chinese.date.month().ordinal, 6);
+//! assert_eq!(datetime_chinese.date.day_of_month().0, 6);
+//! assert_eq!(datetime_chinese.time.hour.number(), 13);
+//! assert_eq!(datetime_chinese.time.minute.number(), 1);
+//! assert_eq!(datetime_chinese.time.second.number(), 0);
+//! ```
+
+use crate::any_calendar::AnyCalendarKind;
+use crate::chinese_based::{self, ChineseBased, ChineseBasedDateInner};
+use crate::iso::Iso;
+use crate::{types, Calendar, CalendarError, Date, DateDuration, DateDurationUnit, DateTime};
+use tinystr::{tinystr, TinyStr16};
+
+/// The [Chinese Calendar]
+///
+/// The [Chinese calendar] is a lunisolar calendar used traditionally in China as well as in other
+/// countries particularly in, but not limited to, East Asia. It is often used today to track important
+/// cultural events and holidays like the Chinese Lunar New Year.
+///
+/// Months start on the day of the new moon, and a leap month is added to years in which
+/// 13 new moons occur between consecutive winter solstices. Both are computed astronomically
+/// for the meridian of Beijing (UTC+8 since 1929), following _Calendrical Calculations_.
+///
+/// This type can be used with [`Date`] or [`DateTime`] to represent dates in this calendar.
+///
+/// [Chinese calendar]: https://en.wikipedia.org/wiki/Chinese_calendar
+///
+/// # Year numbering
+///
+/// Years are numbered as extended years, counting from the traditional epoch in 2637 BCE
+/// (so that 2023 CE starts the year 4660). The position of the year in the 60-year sexagenary
+/// cycle and the related ISO year are available in [`types::FormattableYear`].
+///
+/// # Era codes
+///
+/// This calendar supports a single era code, `"chinese"`.
+///
+/// # Month codes
+///
+/// This calendar supports the month codes `"M01"` through `"M12"`. A leap month takes the
+/// code of the month preceding it with an `"L"` suffix, for example `"M02L"` for the
+/// leap month following the second month.
+#[derive(Copy, Clone, Debug, Hash, Default, Eq, PartialEq)]
+#[allow(clippy::exhaustive_structs)] // this type is stable
+pub struct Chinese;
+
+/// The inner date type used for representing [`Date`]s of [`Chinese`]. See [`Date`] and [`Chinese`] for more details.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct ChineseDateInner(ChineseBasedDateInner<Chinese>);
+
+/// The fixed date of January 1, 1929 (ISO), when China adopted UTC+8 as its standard time
+const UTC_PLUS_8_ADOPTED: i32 = 704188;
+
+impl ChineseBased for Chinese {
+    const EXTENDED_YEAR_OFFSET: i32 = 2637;
+    const ERA: TinyStr16 = tinystr!(16, "chinese");
+
+    // Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L5316-L5331
+    fn utc_offset(fixed: i32) -> f64 {
+        if fixed < UTC_PLUS_8_ADOPTED {
+            // Local mean time of Beijing (116°25′ E)
+            1397.0 / 180.0 / 24.0
+        } else {
+            8.0 / 24.0
+        }
+    }
+}
+
+impl Calendar for Chinese {
+    type DateInner = ChineseDateInner;
+    fn date_from_codes(
+        &self,
+        era: types::Era,
+        year: i32,
+        month_code: types::MonthCode,
+        day: u8,
+    ) -> Result<Self::DateInner, CalendarError> {
+        ChineseBasedDateInner::from_codes(self, era, year, month_code, day).map(ChineseDateInner)
+    }
+
+    fn date_from_iso(&self, iso: Date<Iso>) -> Self::DateInner {
+        ChineseDateInner(ChineseBasedDateInner::from_iso(iso))
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        date.0.to_iso()
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        date.0.months_in_year()
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u32 {
+        date.0.days_in_year()
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        date.0.days_in_month()
+    }
+
+    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
+        date.0.offset_date(offset)
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        _calendar2: &Self,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        date1.0.until(date2.0, largest_unit, smallest_unit)
+    }
+
+    fn year(&self, date: &Self::DateInner) -> types::FormattableYear {
+        date.0.year()
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::FormattableMonth {
+        date.0.month()
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        date.0.day_of_month()
+    }
+
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        date.0.day_of_year_info()
+    }
+
+    fn debug_name(&self) -> &'static str {
+        "Chinese"
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        Some(AnyCalendarKind::Chinese)
+    }
+}
+
+impl Date<Chinese> {
+    /// Construct new Chinese Date.
+    ///
+    /// The year is the extended year, and the month is the ordinal month of the year: in
+    /// years with a leap month, the months following it are shifted by one.
+    ///
+    /// ```rust
+    /// use icu::calendar::Date;
+    ///
+    /// let date_chinese = Date::try_new_chinese_date(4660, 3, 1)
+    ///     .expect("Failed to initialize Chinese Date instance.");
+    ///
+    /// assert_eq!(date_chinese.year().number, 4660);
+    /// assert_eq!(date_chinese.month().ordinal, 3);
+    /// assert_eq!(date_chinese.month().code.0, "M02L");
+    /// assert_eq!(date_chinese.day_of_month().0, 1);
+    /// ```
+    pub fn try_new_chinese_date(
+        year: i32,
+        month: u8,
+        day: u8,
+    ) -> Result<Date<Chinese>, CalendarError> {
+        chinese_based::try_new_date(year, month, day)
+            .map(|inner| Date::from_raw(ChineseDateInner(inner), Chinese))
+    }
+}
+
+impl DateTime<Chinese> {
+    /// Construct a new Chinese datetime from integers.
+    ///
+    /// ```rust
+    /// use icu::calendar::DateTime;
+    ///
+    /// let datetime_chinese =
+    ///     DateTime::try_new_chinese_datetime(4660, 3, 1, 13, 1, 0)
+    ///         .expect("Failed to initialize Chinese DateTime instance.");
+    ///
+    /// assert_eq!(datetime_chinese.date.year().number, 4660);
+    /// assert_eq!(datetime_chinese.date.month().ordinal, 3);
+    /// assert_eq!(datetime_chinese.date.day_of_month().0, 1);
+    /// assert_eq!(datetime_chinese.time.hour.number(), 13);
+    /// assert_eq!(datetime_chinese.time.minute.number(), 1);
+    /// assert_eq!(datetime_chinese.time.second.number(), 0);
+    /// ```
+    pub fn try_new_chinese_datetime(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<DateTime<Chinese>, CalendarError> {
+        Ok(DateTime {
+            date: Date::try_new_chinese_date(year, month, day)?,
+            time: types::Time::try_new(hour, minute, second, 0)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DateDuration;
+    use types::MonthCode;
+
+    fn code(s: &str) -> MonthCode {
+        MonthCode(s.parse().unwrap())
+    }
+
+    #[test]
+    fn test_conversions() {
+        // (iso, (chinese year, month code, day, ordinal month))
+        #[allow(clippy::type_complexity)]
+        let cases: &[((i32, u8, u8), (i32, &str, u8, u32))] = &[
+            ((1000, 6, 1), (3637, "M04", 20, 4)),
+            ((1900, 1, 31), (4537, "M01", 1, 1)),
+            ((1984, 2, 2), (4621, "M01", 1, 1)),
+            ((2020, 5, 23), (4657, "M04L", 1, 5)),
+            ((2020, 6, 21), (4657, "M05", 1, 6)),
+            ((2023, 1, 22), (4660, "M01", 1, 1)),
+            ((2023, 3, 22), (4660, "M02L", 1, 3)),
+            ((2023, 4, 20), (4660, "M03", 1, 4)),
+            ((2024, 2, 9), (4660, "M12", 30, 13)),
+            ((2024, 2, 10), (4661, "M01", 1, 1)),
+            ((2033, 12, 22), (4670, "M11L", 1, 12)),
+        ];
+        for &((iso_y, iso_m, iso_d), (y, m, d, ordinal)) in cases {
+            let iso = Date::try_new_iso_date(iso_y, iso_m, iso_d).unwrap();
+            let chinese = Date::try_new_from_codes(
+                types::Era(tinystr!(16, "chinese")),
+                y,
+                code(m),
+                d,
+                Chinese,
+            )
+            .expect("Chinese date should construct successfully");
+
+            assert_eq!(chinese.to_iso(), iso, "{y}-{m}-{d}: ISO date did not match");
+            assert_eq!(
+                iso.to_calendar(Chinese),
+                chinese,
+                "{iso_y}-{iso_m}-{iso_d}: Chinese date did not match"
+            );
+            assert_eq!(chinese.month().ordinal, ordinal, "{y}-{m}-{d}: ordinal");
+        }
+    }
+
+    #[test]
+    fn test_years() {
+        // (iso year of the new year, extended year, cyclic year, days in year, months in year)
+        let cases = [
+            (1000, 3637, 37, 354, 12),
+            (1984, 4621, 1, 384, 13),
+            (2023, 4660, 40, 384, 13),
+            (2024, 4661, 41, 354, 12),
+        ];
+        for (iso, year, cyclic, days, months) in cases {
+            let date = Date::try_new_chinese_date(year, 1, 1).unwrap();
+            assert_eq!(date.year().related_iso, Some(iso), "{year}: related ISO");
+            assert_eq!(date.year().cyclic.unwrap().get(), cyclic, "{year}: cyclic");
+            assert_eq!(date.days_in_year(), days, "{year}: days in year");
+            assert_eq!(date.months_in_year(), months, "{year}: months in year");
+        }
+    }
+
+    #[test]
+    fn test_leap_month_codes() {
+        // The leap month only exists in the year that has it
+        assert!(Date::try_new_from_codes(
+            types::Era(tinystr!(16, "chinese")),
+            4661,
+            code("M02L"),
+            1,
+            Chinese
+        )
+        .is_err());
+
+        let leap = Date::try_new_chinese_date(4660, 3, 1).unwrap();
+        assert_eq!(leap.month().code, code("M02L"));
+        let mut next_year = leap;
+        next_year.add(DateDuration::new(1, 0, 0, 0));
+        assert_eq!(next_year.year().number, 4661);
+        assert_eq!(next_year.month().code, code("M02"));
+    }
+
+    #[test]
+    fn test_offset_years() {
+        // The eighth month has 30 days in 4657, and 29 days in 4658
+        let mut date = Date::try_new_chinese_date(4657, 9, 30).unwrap();
+        assert_eq!(date.month().code, code("M08"));
+        date.add(DateDuration::new(1, 0, 0, 0));
+        assert_eq!(date, Date::try_new_chinese_date(4658, 8, 29).unwrap());
+        assert_eq!(date.month().code, code("M08"));
+    }
+
+    #[test]
+    fn test_offset_months_and_days() {
+        // (start, (months, days), expected), dates as (extended year, ordinal month, day)
+        #[allow(clippy::type_complexity)]
+        let cases: &[((i32, u8, u8), (i32, i32), (i32, u8, u8))] = &[
+            // 4660 has thirteen months, including the leap month M02L
+            ((4660, 2, 1), (1, 0), (4660, 3, 1)),
+            ((4660, 12, 1), (2, 0), (4661, 1, 1)),
+            ((4661, 1, 1), (-1, 0), (4660, 13, 1)),
+            ((4661, 1, 1), (0, -1), (4660, 13, 30)),
+            ((4660, 13, 30), (0, 1), (4661, 1, 1)),
+            ((4660, 1, 1), (0, 384), (4661, 1, 1)),
+            ((4661, 1, 1), (0, -384), (4660, 1, 1)),
+            // Day 30 of a month overflows into a following month of 29 days
+            ((4657, 9, 30), (1, 0), (4657, 11, 1)),
+        ];
+        for &((y, m, d), (months, days), (ey, em, ed)) in cases {
+            let mut date = Date::try_new_chinese_date(y, m, d).unwrap();
+            date.add(DateDuration::new(0, months, 0, days));
+            let expected = Date::try_new_chinese_date(ey, em, ed).unwrap();
+            assert_eq!(date, expected, "{y}-{m}-{d} + {months}m {days}d");
+            // The cached year information matches the one of a converted date
+            assert_eq!(date.to_iso().to_calendar(Chinese), expected);
+        }
+    }
+}
@@ -0,0 +1,492 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Shared implementation of the astronomical Chinese-based lunisolar calendars
+//! ([`Chinese`](crate::chinese::Chinese) and [`Dangi`](crate::dangi::Dangi)).
+//!
+//! These calendars only differ in the location used for the observations: months start
+//! on the day of the new moon and the leap months are placed according to the major solar
+//! terms, both in local standard time.
+
+use crate::astronomy::{self, MEAN_SYNODIC_MONTH, WINTER};
+use crate::calendar_arithmetic::{ArithmeticDate, CalendarArithmetic};
+use crate::iso::Iso;
+use crate::{types, Calendar, CalendarError, Date, DateDuration, DateDurationUnit};
+use core::fmt;
+use core::hash::Hash;
+use core::num::NonZeroU8;
+use tinystr::{tinystr, TinyStr16};
+
+/// A lunisolar calendar following the rules of the Chinese calendar
+///
+/// The calendars implement [`Calendar`] themselves, delegating to the methods of
+/// [`ChineseBasedDateInner`], so that they only need to provide their identifiers and the
+/// location of their observations.
+pub(crate) trait ChineseBased: Calendar + Copy + fmt::Debug + Hash + Eq {
+    /// The difference between the extended year of this calendar and the ISO year
+    /// in which it starts
+    const EXTENDED_YEAR_OFFSET: i32;
+
+    /// The single era code of this calendar
+    const ERA: TinyStr16;
+
+    /// The offset from UTC of the local standard time used for observations, in days
+    fn utc_offset(fixed: i32) -> f64;
+}
+
+/// The date type shared by the Chinese-based calendars
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub(crate) struct ChineseBasedDateInner<C: ChineseBased>(
+    pub(crate) ArithmeticDate<C>,
+    pub(crate) ChineseBasedYearInfo,
+);
+
+/// The astronomical data of a year that is needed by most date operations, computed once
+/// per year since it requires finding solstices and new moons
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub(crate) struct ChineseBasedYearInfo {
+    /// The fixed date of the new year
+    new_year: i32,
+    /// The fixed date of the following new year
+    next_new_year: i32,
+    /// The ordinal of the leap month, if the year has one
+    leap_month: Option<u8>,
+}
+
+/// The fixed date of the start of the day in local standard time, as a moment in universal time
+///
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L5333-L5338>
+fn midnight<C: ChineseBased>(date: i32) -> f64 {
+    date as f64 - C::utc_offset(date)
+}
+
+/// The local date of a moment in universal time
+fn fixed_from_universal<C: ChineseBased>(moment: f64) -> i32 {
+    let offset = C::utc_offset(libm::floor(moment) as i32);
+    libm::floor(moment + offset) as i32
+}
+
+/// The major solar term (1 to 12) in effect at the start of `date`
+///
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L5273-L5280>
+fn major_solar_term<C: ChineseBased>(date: i32) -> i32 {
+    let s = astronomy::solar_longitude(midnight::<C>(date));
+    (2 + libm::floor(s / 30.0) as i32 - 1).rem_euclid(12) + 1
+}
+
+/// Whether no major solar term begins in the month starting on `date`
+///
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L5345-L5351>
+fn no_major_solar_term<C: ChineseBased>(date: i32) -> bool {
+    major_solar_term::<C>(date) == major_solar_term::<C>(new_moon_on_or_after::<C>(date + 1))
+}
+
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L5370-L5376>
+fn new_moon_on_or_after<C: ChineseBased>(date: i32) -> i32 {
+    fixed_from_universal::<C>(astronomy::new_moon_at_or_after(midnight::<C>(date)))
+}
+
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L5378-L5384>
+fn new_moon_before<C: ChineseBased>(date: i32) -> i32 {
+    fixed_from_universal::<C>(astronomy::new_moon_before(midnight::<C>(date)))
+}
+
+/// The date of the last winter solstice on or before `date`
+///
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L5353-L5368>
+fn winter_solstice_on_or_before<C: ChineseBased>(date: i32) -> i32 {
+    let approx = astronomy::estimate_prior_solar_longitude(WINTER, midnight::<C>(date + 1));
+    let mut day = libm::floor(approx) as i32 - 1;
+    while WINTER >= astronomy::solar_longitude(midnight::<C>(day + 1)) {
+        day += 1;
+    }
+    day
+}
+
+/// The new year of the suì (period from one winter solstice to the next) beginning
+/// with the winter solstice `solstice`
+///
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L5386-L5405>
+fn new_year_in_sui<C: ChineseBased>(solstice: i32) -> i32 {
+    let next_solstice = winter_solstice_on_or_before::<C>(solstice + 370);
+    let m12 = new_moon_on_or_after::<C>(solstice + 1);
+    let m13 = new_moon_on_or_after::<C>(m12 + 1);
+    let next_m11 = new_moon_before::<C>(next_solstice + 1);
+    if is_leap_sui(m12, next_m11)
+        && (no_major_solar_term::<C>(m12) || no_major_solar_term::<C>(m13))
+    {
+        new_moon_on_or_after::<C>(m13 + 1)
+    } else {
+        m13
+    }
+}
+
+/// Whether the suì whose 12th and following 11th months start on the given dates has
+/// a leap month
+fn is_leap_sui(m12: i32, next_m11: i32) -> bool {
+    libm::round((next_m11 - m12) as f64 / MEAN_SYNODIC_MONTH) as i32 == 12
+}
+
+/// The start of the leap month of the suì beginning with the winter solstice `solstice`, if any
+///
+/// The leap month is the first month of a leap suì without a major solar term.
+fn leap_month_in_sui<C: ChineseBased>(solstice: i32) -> Option<i32> {
+    let next_solstice = winter_solstice_on_or_before::<C>(solstice + 370);
+    let m12 = new_moon_on_or_after::<C>(solstice + 1);
+    let next_m11 = new_moon_before::<C>(next_solstice + 1);
+    if !is_leap_sui(m12, next_m11) {
+        return None;
+    }
+    let mut month = m12;
+    while month < next_m11 {
+        if no_major_solar_term::<C>(month) {
+            return Some(month);
+        }
+        month = new_moon_on_or_after::<C>(month + 1);
+    }
+    None
+}
+
+/// The date of the new year of the given extended year
+fn new_year<C: ChineseBased>(year: i32) -> i32 {
+    // The new year always falls between January 21 and February 21 (ISO)
+    #[allow(clippy::unwrap_used)] // valid day and month
+    let mid_year = Iso::fixed_from_iso_integers(year - C::EXTENDED_YEAR_OFFSET, 7, 1).unwrap();
+    new_year_in_sui::<C>(winter_solstice_on_or_before::<C>(mid_year))
+}
+
+/// The date of the last new year on or before `date`
+///
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L5407-L5416>
+fn new_year_on_or_before<C: ChineseBased>(date: i32) -> i32 {
+    let new_year = new_year_in_sui::<C>(winter_solstice_on_or_before::<C>(date));
+    if date >= new_year {
+        new_year
+    } else {
+        new_year_in_sui::<C>(winter_solstice_on_or_before::<C>(date - 180))
+    }
+}
+
+/// The number of months between two new moons
+fn months_between(start: i32, end: i32) -> u8 {
+    libm::round((end - start) as f64 / MEAN_SYNODIC_MONTH) as u8
+}
+
+/// The start of the given ordinal month of the year starting on `new_year`
+fn month_start<C: ChineseBased>(new_year: i32, month: u8) -> i32 {
+    // Months are 29 or 30 days long, so this is after the start of the previous month
+    new_moon_on_or_after::<C>(new_year + 29 * (month as i32 - 1))
+}
+
+impl ChineseBasedYearInfo {
+    /// Computes the information of the given extended year
+    pub(crate) fn new<C: ChineseBased>(year: i32) -> Self {
+        Self::from_new_year::<C>(new_year::<C>(year))
+    }
+
+    /// Computes the information of the year starting on `new_year`
+    fn from_new_year<C: ChineseBased>(new_year: i32) -> Self {
+        // The following solstice is always in the suì preceding the next new year
+        let next_new_year = new_year_in_sui::<C>(winter_solstice_on_or_before::<C>(new_year + 370));
+        let leap_month = if months_between(new_year, next_new_year) == 13 {
+            // The year overlaps with two suì, one of which contains the leap month
+            let solstice = winter_solstice_on_or_before::<C>(new_year);
+            [solstice, winter_solstice_on_or_before::<C>(solstice + 370)]
+                .iter()
+                .filter_map(|&s| leap_month_in_sui::<C>(s))
+                .find(|&month| new_year <= month && month < next_new_year)
+                .map(|month| months_between(new_year, month) + 1)
+        } else {
+            None
+        };
+        Self {
+            new_year,
+            next_new_year,
+            leap_month,
+        }
+    }
+
+    fn months_in_year(self) -> u8 {
+        months_between(self.new_year, self.next_new_year)
+    }
+
+    fn days_in_year(self) -> u32 {
+        (self.next_new_year - self.new_year) as u32
+    }
+
+    /// The start of the given ordinal month
+    fn month_start<C: ChineseBased>(self, month: u8) -> i32 {
+        if month == 1 {
+            self.new_year
+        } else if month > self.months_in_year() {
+            self.next_new_year
+        } else {
+            month_start::<C>(self.new_year, month)
+        }
+    }
+
+    fn month_days<C: ChineseBased>(self, month: u8) -> u8 {
+        (self.month_start::<C>(month + 1) - self.month_start::<C>(month)) as u8
+    }
+
+    fn fixed_from_date<C: ChineseBased>(self, date: ArithmeticDate<C>) -> i32 {
+        self.month_start::<C>(date.month) + date.day as i32 - 1
+    }
+
+    /// The month code of an ordinal month. The leap month has the code of the preceding
+    /// month with an `L` suffix.
+    fn month_code(self, month: u8) -> types::MonthCode {
+        let (number, leap) = match self.leap_month {
+            Some(leap) if month == leap => (month - 1, true),
+            Some(leap) if month > leap => (month - 1, false),
+            _ => (month, false),
+        };
+        let code = match (number, leap) {
+            (1, false) => tinystr!(4, "M01"),
+            (2, false) => tinystr!(4, "M02"),
+            (3, false) => tinystr!(4, "M03"),
+            (4, false) => tinystr!(4, "M04"),
+            (5, false) => tinystr!(4, "M05"),
+            (6, false) => tinystr!(4, "M06"),
+            (7, false) => tinystr!(4, "M07"),
+            (8, false) => tinystr!(4, "M08"),
+            (9, false) => tinystr!(4, "M09"),
+            (10, false) => tinystr!(4, "M10"),
+            (11, false) => tinystr!(4, "M11"),
+            (12, false) => tinystr!(4, "M12"),
+            (1, true) => tinystr!(4, "M01L"),
+            (2, true) => tinystr!(4, "M02L"),
+            (3, true) => tinystr!(4, "M03L"),
+            (4, true) => tinystr!(4, "M04L"),
+            (5, true) => tinystr!(4, "M05L"),
+            (6, true) => tinystr!(4, "M06L"),
+            (7, true) => tinystr!(4, "M07L"),
+            (8, true) => tinystr!(4, "M08L"),
+            (9, true) => tinystr!(4, "M09L"),
+            (10, true) => tinystr!(4, "M10L"),
+            (11, true) => tinystr!(4, "M11L"),
+            (12, true) => tinystr!(4, "M12L"),
+            _ => tinystr!(4, "und"),
+        };
+        types::MonthCode(code)
+    }
+
+    /// The ordinal month for a month code, if that month exists in the year
+    fn ordinal_month_from_code(self, code: types::MonthCode) -> Option<u8> {
+        let (tens, ones, leap) = match *code.0.as_bytes() {
+            [b'M', tens, ones] => (tens, ones, false),
+            [b'M', tens, ones, b'L'] => (tens, ones, true),
+            _ => return None,
+        };
+        let number = match (tens, ones) {
+            (b'0', b'1'..=b'9') => ones - b'0',
+            (b'1', b'0'..=b'2') => 10 + ones - b'0',
+            _ => return None,
+        };
+        match (self.leap_month, leap) {
+            (Some(leap_month), true) if leap_month == number + 1 => Some(leap_month),
+            (_, true) => None,
+            (Some(leap_month), false) if number >= leap_month => Some(number + 1),
+            _ => Some(number),
+        }
+    }
+}
+
+fn date_from_fixed<C: ChineseBased>(date: i32) -> ChineseBasedDateInner<C> {
+    let info = ChineseBasedYearInfo::from_new_year::<C>(new_year_on_or_before::<C>(date));
+    let year = Iso::iso_from_fixed(info.new_year).year().number + C::EXTENDED_YEAR_OFFSET;
+    let month_start = new_moon_before::<C>(date + 1);
+    let month = months_between(info.new_year, month_start) + 1;
+    let day = (date - month_start + 1) as u8; // <= 30
+    ChineseBasedDateInner(ArithmeticDate::new(year, month, day), info)
+}
+
+pub(crate) fn formattable_year<C: ChineseBased>(year: i32) -> types::FormattableYear {
+    let related_iso = year - C::EXTENDED_YEAR_OFFSET;
+    // 1984 (ISO) is the first year of a sexagenary cycle
+    let cyclic = (related_iso - 4).rem_euclid(60) as u8 + 1;
+    types::FormattableYear {
+        era: types::Era(C::ERA),
+        number: year,
+        cyclic: NonZeroU8::new(cyclic),
+        related_iso: Some(related_iso),
+    }
+}
+
+/// Constructs a date from the extended year and the ordinal month, checking that they exist
+pub(crate) fn try_new_date<C: ChineseBased>(
+    year: i32,
+    month: u8,
+    day: u8,
+) -> Result<ChineseBasedDateInner<C>, CalendarError> {
+    let info = ChineseBasedYearInfo::new::<C>(year);
+    if month < 1 || month > info.months_in_year() {
+        return Err(CalendarError::OutOfRange);
+    }
+    if day < 1 || day > info.month_days::<C>(month) {
+        return Err(CalendarError::OutOfRange);
+    }
+
+    Ok(ChineseBasedDateInner(
+        ArithmeticDate::new(year, month, day),
+        info,
+    ))
+}
+
+impl<C: ChineseBased> CalendarArithmetic for C {
+    fn month_days(year: i32, month: u8) -> u8 {
+        ChineseBasedYearInfo::new::<C>(year).month_days::<C>(month)
+    }
+
+    fn months_for_every_year(year: i32) -> u8 {
+        ChineseBasedYearInfo::new::<C>(year).months_in_year()
+    }
+
+    fn is_leap_year(year: i32) -> bool {
+        Self::months_for_every_year(year) == 13
+    }
+
+    fn days_in_provided_year(year: i32) -> u32 {
+        ChineseBasedYearInfo::new::<C>(year).days_in_year()
+    }
+}
+
+impl<C: ChineseBased> ChineseBasedDateInner<C> {
+    pub(crate) fn from_codes(
+        calendar: &C,
+        era: types::Era,
+        year: i32,
+        month_code: types::MonthCode,
+        day: u8,
+    ) -> Result<Self, CalendarError> {
+        if era.0 != C::ERA {
+            return Err(CalendarError::UnknownEra(era.0, calendar.debug_name()));
+        }
+
+        let info = ChineseBasedYearInfo::new::<C>(year);
+        let month = if let Some(month) = info.ordinal_month_from_code(month_code) {
+            month
+        } else {
+            return Err(CalendarError::UnknownMonthCode(
+                month_code.0,
+                calendar.debug_name(),
+            ));
+        };
+
+        if day < 1 || day > info.month_days::<C>(month) {
+            return Err(CalendarError::OutOfRange);
+        }
+
+        Ok(ChineseBasedDateInner(
+            ArithmeticDate::new(year, month, day),
+            info,
+        ))
+    }
+
+    pub(crate) fn from_iso(iso: Date<Iso>) -> Self {
+        let fixed_iso = Iso::fixed_from_iso(*iso.inner());
+        date_from_fixed(fixed_iso)
+    }
+
+    pub(crate) fn to_iso(self) -> Date<Iso> {
+        Iso::iso_from_fixed(self.1.fixed_from_date(self.0))
+    }
+
+    pub(crate) fn months_in_year(self) -> u8 {
+        self.1.months_in_year()
+    }
+
+    pub(crate) fn days_in_year(self) -> u32 {
+        self.1.days_in_year()
+    }
+
+    pub(crate) fn days_in_month(self) -> u8 {
+        self.1.month_days::<C>(self.0.month)
+    }
+
+    pub(crate) fn offset_date(&mut self, offset: DateDuration<C>) {
+        let ChineseBasedDateInner(ref mut date, ref mut info) = *self;
+        // Adding years keeps the month code rather than the ordinal month, since
+        // the leap month moves from year to year.
+        if offset.years != 0 {
+            let code = info.month_code(date.month);
+            date.year += offset.years;
+            *info = ChineseBasedYearInfo::new::<C>(date.year);
+            date.month = info
+                .ordinal_month_from_code(code)
+                // Fall back to the regular month if the leap month does not exist
+                .or_else(|| {
+                    let regular = code.0.get(..3).and_then(|c| c.parse().ok())?;
+                    info.ordinal_month_from_code(types::MonthCode(regular))
+                })
+                .unwrap_or(1);
+            // Months have 29 or 30 days depending on the year
+            date.day = date.day.min(info.month_days::<C>(date.month));
+        }
+
+        let mut month = date.month as i32 + offset.months;
+        while month > info.months_in_year() as i32 {
+            month -= info.months_in_year() as i32;
+            date.year += 1;
+            *info = ChineseBasedYearInfo::new::<C>(date.year);
+        }
+        while month < 1 {
+            date.year -= 1;
+            *info = ChineseBasedYearInfo::new::<C>(date.year);
+            month += info.months_in_year() as i32;
+        }
+        date.month = month as u8;
+
+        // Days that overflow the month carry over into the following months
+        let days = offset.days + offset.weeks * 7 + date.day as i32 - 1;
+        if days != 0 || date.day > info.month_days::<C>(date.month) {
+            let fixed = info.month_start::<C>(date.month) + days;
+            if info.new_year <= fixed && fixed < info.next_new_year {
+                let month_start = new_moon_before::<C>(fixed + 1);
+                date.month = months_between(info.new_year, month_start) + 1;
+                date.day = (fixed - month_start + 1) as u8;
+            } else {
+                let ChineseBasedDateInner(new_date, new_info) = date_from_fixed::<C>(fixed);
+                *date = new_date;
+                *info = new_info;
+            }
+        }
+    }
+
+    pub(crate) fn until(
+        self,
+        other: Self,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
+    ) -> DateDuration<C> {
+        self.0.until(other.0, largest_unit, smallest_unit)
+    }
+
+    pub(crate) fn year(self) -> types::FormattableYear {
+        formattable_year::<C>(self.0.year)
+    }
+
+    pub(crate) fn month(self) -> types::FormattableMonth {
+        types::FormattableMonth {
+            ordinal: self.0.month as u32,
+            code: self.1.month_code(self.0.month),
+        }
+    }
+
+    pub(crate) fn day_of_month(self) -> types::DayOfMonth {
+        self.0.day_of_month()
+    }
+
+    pub(crate) fn day_of_year_info(self) -> types::DayOfYearInfo {
+        let prev_year = self.0.year - 1;
+        let next_year = self.0.year + 1;
+        types::DayOfYearInfo {
+            day_of_year: (self.1.fixed_from_date(self.0) - self.1.new_year + 1) as u32,
+            days_in_year: self.1.days_in_year(),
+            prev_year: formattable_year::<C>(prev_year),
+            days_in_prev_year: (self.1.new_year - new_year::<C>(prev_year)) as u32,
+            next_year: formattable_year::<C>(next_year),
+        }
+    }
+}
@@ -0,0 +1,310 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains types and implementations for the Korean Dangi calendar.
+//!
+//! ```rust
+//! use icu::calendar::{dangi::Dangi, Date, DateTime};
+//!
+//! // `Date` type
+//! let date_iso = Date::try_new_iso_date(2023, 4, 20)
+//!     .expect("Failed to initialize ISO Date instance.");
+//! let date_dangi = Date::new_from_iso(date_iso, Dangi);
+//!
+//! // `DateTime` type
+//! let datetime_iso = DateTime::try_new_iso_datetime(2023, 4, 20, 13, 1, 0)
+//!     .expect("Failed to initialize ISO DateTime instance.");
+//! let datetime_dangi = DateTime::new_from_iso(datetime_iso, Dangi);
+//!
+//! // `Date` checks
+//! assert_eq!(date_dangi.year().number, 4356);
+//! assert_eq!(date_dangi.year().related_iso, Some(2023));
+//! assert_eq!(date_dangi.year().cyclic.unwrap().get(), 40);
+//! assert_eq!(date_dangi.month().ordinal, 4);
+//! assert_eq!(date_dangi.day_of_month().0, 1);
+//!
+//! // `DateTime` checks
+//! assert_eq!(datetime_dangi.date.year().number, 4356);
+//! assert_eq!(datetime_dangi.date.month().ordinal, 4);
+//! assert_eq!(datetime_dangi.date.day_of_month().0, 1);
+//! assert_eq!(datetime_dangi.time.hour.number(), 13);
+//! assert_eq!(datetime_dangi.time.minute.number(), 1);
+//! assert_eq!(datetime_dangi.time.second.number(), 0);
+//! ```
+
+use crate::any_calendar::AnyCalendarKind;
+use crate::chinese_based::{self, ChineseBased, ChineseBasedDateInner};
+use crate::iso::Iso;
+use crate::{types, Calendar, CalendarError, Date, DateDuration, DateDurationUnit, DateTime};
+use tinystr::{tinystr, TinyStr16};
+
+/// The [Dangi Calendar]
+///
+/// The [Dangi calendar] is the traditional Korean lunisolar calendar. It follows the same rules
+/// as the [`Chinese`](crate::chinese::Chinese) calendar, but the new moons and solar terms
+/// are computed for Seoul, so the two calendars occasionally place months and leap months
+/// differently. It is used today mostly for traditional holidays like Seollal and Chuseok.
+///
+/// This type can be used with [`Date`] or [`DateTime`] to represent dates in this calendar.
+///
+/// [Dangi calendar]: https://en.wikipedia.org/wiki/Korean_calendar
+///
+/// # Year numbering
+///
+/// Years are numbered as extended years, counting from the legendary founding of Gojoseon
+/// in 2333 BCE (so that 2023 CE starts the year 4356). The position of the year in the
+/// 60-year sexagenary cycle and the related ISO year are available in [`types::FormattableYear`].
+///
+/// # Era codes
+///
+/// This calendar supports a single era code, `"dangi"`.
+///
+/// # Month codes
+///
+/// This calendar supports the month codes `"M01"` through `"M12"`. A leap month takes the
+/// code of the month preceding it with an `"L"` suffix, for example `"M02L"` for the
+/// leap month following the second month.
+#[derive(Copy, Clone, Debug, Hash, Default, Eq, PartialEq)]
+#[allow(clippy::exhaustive_structs)] // this type is stable
+pub struct Dangi;
+
+/// The inner date type used for representing [`Date`]s of [`Dangi`]. See [`Date`] and [`Dangi`] for more details.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct DangiDateInner(ChineseBasedDateInner<Dangi>);
+
+impl ChineseBased for Dangi {
+    const EXTENDED_YEAR_OFFSET: i32 = 2333;
+    const ERA: TinyStr16 = tinystr!(16, "dangi");
+
+    // Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L5631-L5646
+    fn utc_offset(fixed: i32) -> f64 {
+        // The standard time of Seoul changed several times in the 20th century
+        // (fixed dates of 1908-04-01, 1912-01-01, 1954-03-21 and 1961-08-10)
+        if fixed < 696608 {
+            // Local mean time of Seoul (126°58′ E)
+            3809.0 / 450.0 / 24.0
+        } else if fixed < 697978 {
+            8.5 / 24.0
+        } else if fixed < 713398 {
+            9.0 / 24.0
+        } else if fixed < 716097 {
+            8.5 / 24.0
+        } else {
+            9.0 / 24.0
+        }
+    }
+}
+
+impl Calendar for Dangi {
+    type DateInner = DangiDateInner;
+    fn date_from_codes(
+        &self,
+        era: types::Era,
+        year: i32,
+        month_code: types::MonthCode,
+        day: u8,
+    ) -> Result<Self::DateInner, CalendarError> {
+        ChineseBasedDateInner::from_codes(self, era, year, month_code, day).map(DangiDateInner)
+    }
+
+    fn date_from_iso(&self, iso: Date<Iso>) -> Self::DateInner {
+        DangiDateInner(ChineseBasedDateInner::from_iso(iso))
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        date.0.to_iso()
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        date.0.months_in_year()
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u32 {
+        date.0.days_in_year()
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        date.0.days_in_month()
+    }
+
+    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
+        date.0.offset_date(offset)
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        _calendar2: &Self,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        date1.0.until(date2.0, largest_unit, smallest_unit)
+    }
+
+    fn year(&self, date: &Self::DateInner) -> types::FormattableYear {
+        date.0.year()
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::FormattableMonth {
+        date.0.month()
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        date.0.day_of_month()
+    }
+
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        date.0.day_of_year_info()
+    }
+
+    fn debug_name(&self) -> &'static str {
+        "Dangi"
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        Some(AnyCalendarKind::Dangi)
+    }
+}
+
+impl Date<Dangi> {
+    /// Construct new Dangi Date.
+    ///
+    /// The year is the extended year, and the month is the ordinal month of the year: in
+    /// years with a leap month, the months following it are shifted by one.
+    ///
+    /// ```rust
+    /// use icu::calendar::Date;
+    ///
+    /// let date_dangi = Date::try_new_dangi_date(4356, 3, 1)
+    ///     .expect("Failed to initialize Dangi Date instance.");
+    ///
+    /// assert_eq!(date_dangi.year().number, 4356);
+    /// assert_eq!(date_dangi.month().ordinal, 3);
+    /// assert_eq!(date_dangi.month().code.0, "M02L");
+    /// assert_eq!(date_dangi.day_of_month().0, 1);
+    /// ```
+    pub fn try_new_dangi_date(year: i32, month: u8, day: u8) -> Result<Date<Dangi>, CalendarError> {
+        chinese_based::try_new_date(year, month, day)
+            .map(|inner| Date::from_raw(DangiDateInner(inner), Dangi))
+    }
+}
+
+impl DateTime<Dangi> {
+    /// Construct a new Dangi datetime from integers.
+    ///
+    /// ```rust
+    /// use icu::calendar::DateTime;
+    ///
+    /// let datetime_dangi =
+    ///     DateTime::try_new_dangi_datetime(4356, 3, 1, 13, 1, 0)
+    ///         .expect("Failed to initialize Dangi DateTime instance.");
+    ///
+    /// assert_eq!(datetime_dangi.date.year().number, 4356);
+    /// assert_eq!(datetime_dangi.date.month().ordinal, 3);
+    /// assert_eq!(datetime_dangi.date.day_of_month().0, 1);
+    /// assert_eq!(datetime_dangi.time.hour.number(), 13);
+    /// assert_eq!(datetime_dangi.time.minute.number(), 1);
+    /// assert_eq!(datetime_dangi.time.second.number(), 0);
+    /// ```
+    pub fn try_new_dangi_datetime(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<DateTime<Dangi>, CalendarError> {
+        Ok(DateTime {
+            date: Date::try_new_dangi_date(year, month, day)?,
+            time: types::Time::try_new(hour, minute, second, 0)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DateDuration;
+    use types::MonthCode;
+
+    fn code(s: &str) -> MonthCode {
+        MonthCode(s.parse().unwrap())
+    }
+
+    #[test]
+    fn test_conversions() {
+        // (iso, (dangi year, month code, day, ordinal month))
+        #[allow(clippy::type_complexity)]
+        let cases: &[((i32, u8, u8), (i32, &str, u8, u32))] = &[
+            ((1000, 6, 1), (3333, "M04", 20, 4)),
+            ((1900, 1, 31), (4233, "M01", 1, 1)),
+            ((1984, 2, 2), (4317, "M01", 1, 1)),
+            ((2020, 5, 23), (4353, "M04L", 1, 5)),
+            ((2023, 1, 22), (4356, "M01", 1, 1)),
+            ((2023, 3, 22), (4356, "M02L", 1, 3)),
+            ((2023, 4, 20), (4356, "M03", 1, 4)),
+            ((2024, 2, 9), (4356, "M12", 30, 13)),
+            ((2024, 2, 10), (4357, "M01", 1, 1)),
+            ((2033, 12, 22), (4366, "M11L", 1, 12)),
+        ];
+        for &((iso_y, iso_m, iso_d), (y, m, d, ordinal)) in cases {
+            let iso = Date::try_new_iso_date(iso_y, iso_m, iso_d).unwrap();
+            let dangi =
+                Date::try_new_from_codes(types::Era(tinystr!(16, "dangi")), y, code(m), d, Dangi)
+                    .expect("Dangi date should construct successfully");
+
+            assert_eq!(dangi.to_iso(), iso, "{y}-{m}-{d}: ISO date did not match");
+            assert_eq!(
+                iso.to_calendar(Dangi),
+                dangi,
+                "{iso_y}-{iso_m}-{iso_d}: Dangi date did not match"
+            );
+            assert_eq!(dangi.month().ordinal, ordinal, "{y}-{m}-{d}: ordinal");
+        }
+    }
+
+    #[test]
+    fn test_years() {
+        // (iso year of the new year, extended year, cyclic year, days in year, months in year)
+        let cases = [(2023, 4356, 40, 384, 13), (2024, 4357, 41, 354, 12)];
+        for (iso, year, cyclic, days, months) in cases {
+            let date = Date::try_new_dangi_date(year, 1, 1).unwrap();
+            assert_eq!(date.year().related_iso, Some(iso), "{year}: related ISO");
+            assert_eq!(date.year().cyclic.unwrap().get(), cyclic, "{year}: cyclic");
+            assert_eq!(date.days_in_year(), days, "{year}: days in year");
+            assert_eq!(date.months_in_year(), months, "{year}: months in year");
+        }
+    }
+
+    #[test]
+    fn test_leap_month_codes() {
+        // The leap month only exists in the year that has it
+        assert!(Date::try_new_from_codes(
+            types::Era(tinystr!(16, "dangi")),
+            4357,
+            code("M02L"),
+            1,
+            Dangi
+        )
+        .is_err());
+
+        let leap = Date::try_new_dangi_date(4356, 3, 1).unwrap();
+        assert_eq!(leap.month().code, code("M02L"));
+        let mut next_year = leap;
+        next_year.add(DateDuration::new(1, 0, 0, 0));
+        assert_eq!(next_year.year().number, 4357);
+        assert_eq!(next_year.month().code, code("M02"));
+    }
+
+    #[test]
+    fn test_offset_years() {
+        // The eighth month has 30 days in 4353, and 29 days in 4354
+        let mut date = Date::try_new_dangi_date(4353, 9, 30).unwrap();
+        assert_eq!(date.month().code, code("M08"));
+        date.add(DateDuration::new(1, 0, 0, 0));
+        assert_eq!(date, Date::try_new_dangi_date(4354, 8, 29).unwrap());
+        assert_eq!(date.month().code, code("M08"));
+    }
+}
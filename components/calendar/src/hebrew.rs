@@ -0,0 +1,563 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains types and implementations for the Hebrew calendar.
+//!
+//! ```rust
+//! use icu::calendar::{hebrew::Hebrew, Date, DateTime};
+//!
+//! // `Date` type
+//! let date_iso = Date::try_new_iso_date(1970, 1, 2)
+//!     .expect("Failed to initialize ISO Date instance.");
+//! let date_hebrew = Date::new_from_iso(date_iso, Hebrew);
+//!
+//! // `DateTime` type
+//! let datetime_iso = DateTime::try_new_iso_datetime(1970, 1, 2, 13, 1, 0)
+//!     .expect("Failed to initialize ISO DateTime instance.");
+//! let datetime_hebrew = DateTime::new_from_iso(datetime_iso, Hebrew);
+//!
+//! // `Date` checks
+//! assert_eq!(date_hebrew.year().number, 5730);
+//! assert_eq!(date_hebrew.month().ordinal, 4);
+//! assert_eq!(date_hebrew.day_of_month().0, 24);
+//!
+//! // `DateTime` checks
+//! assert_eq!(datetime_hebrew.date.year().number, 5730);
+//! assert_eq!(datetime_hebrew.date.month().ordinal, 4);
+//! assert_eq!(datetime_hebrew.date.day_of_month().0, 24);
+//! assert_eq!(datetime_hebrew.time.hour.number(), 13);
+//! assert_eq!(datetime_hebrew.time.minute.number(), 1);
+//! assert_eq!(datetime_hebrew.time.second.number(), 0);
+//! ```
+
+use crate::any_calendar::AnyCalendarKind;
+use crate::calendar_arithmetic::{ArithmeticDate, CalendarArithmetic};
+use crate::iso::Iso;
+use crate::julian::Julian;
+use crate::{types, Calendar, CalendarError, Date, DateDuration, DateDurationUnit, DateTime};
+use tinystr::tinystr;
+
+/// The [Hebrew Calendar]
+///
+/// The [Hebrew calendar] is a lunisolar calendar used as the Jewish liturgical calendar
+/// as well as an official calendar in Israel. Years have 12 or 13 months, and the year
+/// lengths are derived from the mean conjunction (molad) of Tishri.
+///
+/// This is the _civil_ Hebrew calendar: the year starts with the month of Tishri, which
+/// has ordinal month 1.
+///
+/// This type can be used with [`Date`] or [`DateTime`] to represent dates in this calendar.
+///
+/// [Hebrew calendar]: https://en.wikipedia.org/wiki/Hebrew_calendar
+///
+/// # Era codes
+///
+/// This calendar supports a single era code, `"am"`, corresponding to the Anno Mundi era.
+///
+/// # Month codes
+///
+/// This calendar supports the month codes `"M01"` (Tishri) through `"M12"` (Elul). In leap
+/// years, the leap month Adar I is inserted after Shevat and has the code `"M05L"`.
+///
+/// In a leap year, the regular month Adar (`"M06"`) is called Adar II. It keeps the code `"M06"`;
+/// formatting picks its leap-year name.
+#[derive(Copy, Clone, Debug, Hash, Default, Eq, PartialEq)]
+#[allow(clippy::exhaustive_structs)] // this type is stable
+pub struct Hebrew;
+
+/// The inner date type used for representing [`Date`]s of [`Hebrew`]. See [`Date`] and [`Hebrew`] for more details.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct HebrewDateInner(ArithmeticDate<Hebrew>);
+
+impl CalendarArithmetic for Hebrew {
+    fn month_days(year: i32, month: u8) -> u8 {
+        let leap = Self::is_leap_year(year);
+        match (month, leap) {
+            // Tishri
+            (1, _) => 30,
+            // Heshvan
+            (2, _) => {
+                if Self::is_long_heshvan(year) {
+                    30
+                } else {
+                    29
+                }
+            }
+            // Kislev
+            (3, _) => {
+                if Self::is_short_kislev(year) {
+                    29
+                } else {
+                    30
+                }
+            }
+            // Tevet, Shevat
+            (4, _) => 29,
+            (5, _) => 30,
+            // Adar (common year) or Adar I, Adar II (leap year)
+            (6, false) => 29,
+            (6, true) => 30,
+            (7, true) => 29,
+            // Nisan through Elul alternate between 30 and 29 days
+            (m @ 7..=12, false) => 30 - (m - 7) % 2,
+            (m @ 8..=13, true) => 30 - (m - 8) % 2,
+            _ => 0,
+        }
+    }
+
+    fn months_for_every_year(year: i32) -> u8 {
+        if Self::is_leap_year(year) {
+            13
+        } else {
+            12
+        }
+    }
+
+    fn is_leap_year(year: i32) -> bool {
+        (7 * year + 1).rem_euclid(19) < 7
+    }
+
+    fn days_in_provided_year(year: i32) -> u32 {
+        (Self::new_year(year + 1) - Self::new_year(year)) as u32
+    }
+}
+
+impl Calendar for Hebrew {
+    type DateInner = HebrewDateInner;
+    fn date_from_codes(
+        &self,
+        era: types::Era,
+        year: i32,
+        month_code: types::MonthCode,
+        day: u8,
+    ) -> Result<Self::DateInner, CalendarError> {
+        if era.0 != tinystr!(16, "am") {
+            return Err(CalendarError::UnknownEra(era.0, self.debug_name()));
+        }
+
+        let month = if let Some(month) = Self::ordinal_month_from_code(year, month_code) {
+            month
+        } else {
+            return Err(CalendarError::UnknownMonthCode(
+                month_code.0,
+                self.debug_name(),
+            ));
+        };
+
+        if day < 1 || day > Self::month_days(year, month) {
+            return Err(CalendarError::OutOfRange);
+        }
+
+        Ok(HebrewDateInner(ArithmeticDate::new(year, month, day)))
+    }
+
+    fn date_from_iso(&self, iso: Date<Iso>) -> HebrewDateInner {
+        let fixed_iso = Iso::fixed_from_iso(*iso.inner());
+        Self::hebrew_from_fixed(fixed_iso)
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        let fixed_hebrew = Self::fixed_from_hebrew(date.0);
+        Iso::iso_from_fixed(fixed_hebrew)
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        date.0.months_in_year()
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u32 {
+        date.0.days_in_year()
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        date.0.days_in_month()
+    }
+
+    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
+        // Adding years keeps the month code rather than the ordinal month, since
+        // the ordinals of the months after Adar differ between leap and common years.
+        if offset.years != 0 {
+            let code = Self::month_code(date.0.year, date.0.month);
+            date.0.year += offset.years;
+            date.0.month = Self::ordinal_month_from_code(date.0.year, code)
+                // Adar I does not exist in common years, fall back to Adar
+                .unwrap_or_else(|| Self::adar(date.0.year));
+            // Heshvan, Kislev and Adar have 29 or 30 days depending on the year
+            date.0.day = date.0.day.min(Self::month_days(date.0.year, date.0.month));
+        }
+        date.0.offset_date(DateDuration::new(
+            0,
+            offset.months,
+            offset.weeks,
+            offset.days,
+        ));
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        _calendar2: &Self,
+        _largest_unit: DateDurationUnit,
+        _smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        date1.0.until(date2.0, _largest_unit, _smallest_unit)
+    }
+
+    fn year(&self, date: &Self::DateInner) -> types::FormattableYear {
+        year_as_hebrew(date.0.year)
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::FormattableMonth {
+        types::FormattableMonth {
+            ordinal: date.0.month as u32,
+            code: Self::month_code(date.0.year, date.0.month),
+        }
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        date.0.day_of_month()
+    }
+
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        let prev_year = date.0.year - 1;
+        let next_year = date.0.year + 1;
+        types::DayOfYearInfo {
+            day_of_year: date.0.day_of_year(),
+            days_in_year: date.0.days_in_year(),
+            prev_year: year_as_hebrew(prev_year),
+            days_in_prev_year: Hebrew::days_in_provided_year(prev_year),
+            next_year: year_as_hebrew(next_year),
+        }
+    }
+
+    fn debug_name(&self) -> &'static str {
+        "Hebrew"
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        Some(AnyCalendarKind::Hebrew)
+    }
+}
+
+/// The fixed date of 1 Tishri 1 A.M., which is October 7, 3761 B.C.E. (Julian).
+const HEBREW_EPOCH: i32 = Julian::fixed_from_julian_integers(-3761, 10, 7);
+
+impl Hebrew {
+    // "Fixed" is a day count representation of calendars staring from Jan 1st of year 1 of the Georgian Calendar.
+    // The fixed date algorithms are from
+    // Dershowitz, Nachum, and Edward M. Reingold. _Calendrical calculations_. Cambridge University Press, 2008.
+    //
+    // The Lisp code numbers months starting with Nisan; the civil numbering used here starts with
+    // Tishri, which lets a date be computed from the new year and the day of the year.
+    //
+    // Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L2217-L2232
+    fn elapsed_days(year: i32) -> i32 {
+        // Months from the epoch to the molad of Tishri of `year`
+        let months_elapsed = (235 * year as i64 - 234).div_euclid(19);
+        // A day is divided into 25920 parts (halakim)
+        let parts_elapsed = 12084 + 13753 * months_elapsed;
+        let days = 29 * months_elapsed + parts_elapsed.div_euclid(25920);
+        // Postpone the new year if it would fall on a Sunday, Wednesday or Friday
+        if (3 * (days + 1)).rem_euclid(7) < 3 {
+            (days + 1) as i32
+        } else {
+            days as i32
+        }
+    }
+
+    // Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L2234-L2247
+    fn year_length_correction(year: i32) -> i32 {
+        let ny0 = Self::elapsed_days(year - 1);
+        let ny1 = Self::elapsed_days(year);
+        let ny2 = Self::elapsed_days(year + 1);
+        if ny2 - ny1 == 356 {
+            2
+        } else if ny1 - ny0 == 382 {
+            1
+        } else {
+            0
+        }
+    }
+
+    // Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L2249-L2254
+    fn new_year(year: i32) -> i32 {
+        HEBREW_EPOCH + Self::elapsed_days(year) + Self::year_length_correction(year)
+    }
+
+    // Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L2262-L2267
+    fn is_long_heshvan(year: i32) -> bool {
+        matches!(Self::days_in_provided_year(year), 355 | 385)
+    }
+
+    // Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L2269-L2274
+    fn is_short_kislev(year: i32) -> bool {
+        matches!(Self::days_in_provided_year(year), 353 | 383)
+    }
+
+    fn fixed_from_hebrew(date: ArithmeticDate<Hebrew>) -> i32 {
+        Self::new_year(date.year) + date.day_of_year() as i32 - 1
+    }
+
+    // Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L2352-L2378
+    fn hebrew_from_fixed(date: i32) -> HebrewDateInner {
+        // The mean length of a year is 35975351/98496 days
+        let approx = ((date - HEBREW_EPOCH) as i64 * 98496).div_euclid(35975351) as i32 + 1;
+        let mut year = approx - 1;
+        while Self::new_year(year + 1) <= date {
+            year += 1;
+        }
+        let day_of_year = (date - Self::new_year(year) + 1) as u32; // <= 385
+        HebrewDateInner(ArithmeticDate::date_from_year_day(year, day_of_year))
+    }
+
+    /// The ordinal month of Adar (Adar II in leap years)
+    fn adar(year: i32) -> u8 {
+        if Self::is_leap_year(year) {
+            7
+        } else {
+            6
+        }
+    }
+
+    fn month_code(year: i32, month: u8) -> types::MonthCode {
+        let month = if Self::is_leap_year(year) {
+            match month {
+                6 => return types::MonthCode(tinystr!(4, "M05L")),
+                m if m > 6 => m - 1,
+                m => m,
+            }
+        } else {
+            month
+        };
+        let code = match month {
+            1 => tinystr!(4, "M01"),
+            2 => tinystr!(4, "M02"),
+            3 => tinystr!(4, "M03"),
+            4 => tinystr!(4, "M04"),
+            5 => tinystr!(4, "M05"),
+            6 => tinystr!(4, "M06"),
+            7 => tinystr!(4, "M07"),
+            8 => tinystr!(4, "M08"),
+            9 => tinystr!(4, "M09"),
+            10 => tinystr!(4, "M10"),
+            11 => tinystr!(4, "M11"),
+            12 => tinystr!(4, "M12"),
+            _ => tinystr!(4, "und"),
+        };
+        types::MonthCode(code)
+    }
+
+    fn ordinal_month_from_code(year: i32, code: types::MonthCode) -> Option<u8> {
+        let leap = Self::is_leap_year(year);
+        if code.0 == tinystr!(4, "M05L") {
+            return if leap { Some(6) } else { None };
+        }
+        let month = crate::calendar_arithmetic::ordinal_solar_month_from_code(code)?;
+        match month {
+            13 => None,
+            m if leap && m >= 6 => Some(m + 1),
+            m => Some(m),
+        }
+    }
+}
+
+impl Date<Hebrew> {
+    /// Construct new Hebrew Date.
+    ///
+    /// The month is the ordinal month of the civil year, starting with Tishri. In leap
+    /// years, month 6 is Adar I and month 7 is Adar II.
+    ///
+    /// ```rust
+    /// use icu::calendar::Date;
+    ///
+    /// let date_hebrew = Date::try_new_hebrew_date(5782, 6, 9)
+    ///     .expect("Failed to initialize Hebrew Date instance.");
+    ///
+    /// assert_eq!(date_hebrew.year().number, 5782);
+    /// assert_eq!(date_hebrew.month().ordinal, 6);
+    /// assert_eq!(date_hebrew.month().code.0, "M05L");
+    /// assert_eq!(date_hebrew.day_of_month().0, 9);
+    /// ```
+    pub fn try_new_hebrew_date(
+        year: i32,
+        month: u8,
+        day: u8,
+    ) -> Result<Date<Hebrew>, CalendarError> {
+        if month < 1 || month > Hebrew::months_for_every_year(year) {
+            return Err(CalendarError::OutOfRange);
+        }
+        if day < 1 || day > Hebrew::month_days(year, month) {
+            return Err(CalendarError::OutOfRange);
+        }
+
+        Ok(Date::from_raw(
+            HebrewDateInner(ArithmeticDate::new(year, month, day)),
+            Hebrew,
+        ))
+    }
+}
+
+impl DateTime<Hebrew> {
+    /// Construct a new Hebrew datetime from integers.
+    ///
+    /// ```rust
+    /// use icu::calendar::DateTime;
+    ///
+    /// let datetime_hebrew =
+    ///     DateTime::try_new_hebrew_datetime(5782, 6, 9, 13, 1, 0)
+    ///         .expect("Failed to initialize Hebrew DateTime instance.");
+    ///
+    /// assert_eq!(datetime_hebrew.date.year().number, 5782);
+    /// assert_eq!(datetime_hebrew.date.month().ordinal, 6);
+    /// assert_eq!(datetime_hebrew.date.day_of_month().0, 9);
+    /// assert_eq!(datetime_hebrew.time.hour.number(), 13);
+    /// assert_eq!(datetime_hebrew.time.minute.number(), 1);
+    /// assert_eq!(datetime_hebrew.time.second.number(), 0);
+    /// ```
+    pub fn try_new_hebrew_datetime(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<DateTime<Hebrew>, CalendarError> {
+        Ok(DateTime {
+            date: Date::try_new_hebrew_date(year, month, day)?,
+            time: types::Time::try_new(hour, minute, second, 0)?,
+        })
+    }
+}
+
+fn year_as_hebrew(year: i32) -> types::FormattableYear {
+    types::FormattableYear {
+        era: types::Era(tinystr!(16, "am")),
+        number: year,
+        cyclic: None,
+        related_iso: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use types::MonthCode;
+
+    fn code(s: &str) -> MonthCode {
+        MonthCode(s.parse().unwrap())
+    }
+
+    #[test]
+    fn test_conversions() {
+        // (iso, (hebrew year, month code, day))
+        #[allow(clippy::type_complexity)]
+        let cases: &[((i32, u8, u8), (i32, &str, u8))] = &[
+            ((2021, 1, 10), (5781, "M04", 26)),
+            ((2021, 2, 25), (5781, "M06", 13)),
+            ((2021, 3, 25), (5781, "M07", 12)),
+            ((2021, 6, 10), (5781, "M09", 30)),
+            ((2021, 8, 25), (5781, "M12", 17)),
+            ((2021, 9, 10), (5782, "M01", 4)),
+            ((2021, 10, 10), (5782, "M02", 4)),
+            ((2021, 11, 10), (5782, "M03", 6)),
+            ((2022, 1, 25), (5782, "M05", 23)),
+            ((2022, 2, 10), (5782, "M05L", 9)),
+            ((2022, 2, 25), (5782, "M05L", 24)),
+            ((2022, 3, 10), (5782, "M06", 7)),
+            ((2022, 3, 25), (5782, "M06", 22)),
+            ((2022, 4, 10), (5782, "M07", 9)),
+            ((2022, 9, 25), (5782, "M12", 29)),
+            ((2022, 10, 25), (5783, "M01", 30)),
+            ((2022, 11, 25), (5783, "M03", 1)),
+            ((2022, 12, 25), (5783, "M04", 1)),
+        ];
+        for &((iso_y, iso_m, iso_d), (y, m, d)) in cases {
+            let iso = Date::try_new_iso_date(iso_y, iso_m, iso_d).unwrap();
+            let hebrew =
+                Date::try_new_from_codes(types::Era(tinystr!(16, "am")), y, code(m), d, Hebrew)
+                    .expect("Hebrew date should construct successfully");
+
+            assert_eq!(hebrew.to_iso(), iso, "{y}-{m}-{d}: ISO date did not match");
+            assert_eq!(
+                iso.to_calendar(Hebrew),
+                hebrew,
+                "{iso_y}-{iso_m}-{iso_d}: Hebrew date did not match"
+            );
+        }
+    }
+
+    #[test]
+    fn test_year_lengths() {
+        // (year, days in year, months in year)
+        let cases = [
+            (5780, 355, 12),
+            (5781, 353, 12),
+            (5782, 384, 13),
+            (5783, 355, 12),
+            (5784, 383, 13),
+            (5785, 355, 12),
+        ];
+        for (year, days, months) in cases {
+            let date = Date::try_new_hebrew_date(year, 1, 1).unwrap();
+            assert_eq!(date.days_in_year(), days, "{year}: days in year");
+            assert_eq!(date.months_in_year(), months, "{year}: months in year");
+        }
+    }
+
+    #[test]
+    fn test_month_codes() {
+        let adar_1 = Date::try_new_hebrew_date(5782, 6, 1).unwrap();
+        assert_eq!(adar_1.month().code, code("M05L"));
+        let adar_2 = Date::try_new_hebrew_date(5782, 7, 1).unwrap();
+        assert_eq!(adar_2.month().code, code("M06"));
+        let nisan = Date::try_new_hebrew_date(5782, 8, 1).unwrap();
+        assert_eq!(nisan.month().code, code("M07"));
+        let adar = Date::try_new_hebrew_date(5783, 6, 1).unwrap();
+        assert_eq!(adar.month().code, code("M06"));
+
+        let era = types::Era(tinystr!(16, "am"));
+        assert!(Date::try_new_from_codes(era, 5783, code("M05L"), 1, Hebrew).is_err());
+        assert!(Date::try_new_from_codes(era, 5782, code("M06L"), 1, Hebrew).is_err());
+        assert_eq!(
+            Date::try_new_from_codes(era, 5782, code("M06"), 1, Hebrew).unwrap(),
+            adar_2
+        );
+    }
+
+    #[test]
+    fn test_offset_years() {
+        // Nisan keeps its month code across leap and common years
+        let mut date = Date::try_new_hebrew_date(5782, 8, 15).unwrap();
+        date.add(DateDuration::new(1, 0, 0, 0));
+        assert_eq!(date, Date::try_new_hebrew_date(5783, 7, 15).unwrap());
+
+        // Adar I becomes Adar in a common year
+        let mut date = Date::try_new_hebrew_date(5782, 6, 10).unwrap();
+        date.add(DateDuration::new(1, 0, 0, 0));
+        assert_eq!(date, Date::try_new_hebrew_date(5783, 6, 10).unwrap());
+
+        // Adar becomes Adar II in a leap year
+        let mut date = Date::try_new_hebrew_date(5783, 6, 10).unwrap();
+        date.add(DateDuration::new(1, 0, 0, 0));
+        assert_eq!(date, Date::try_new_hebrew_date(5784, 7, 10).unwrap());
+
+        // The last day of a month is clamped if the month is shorter in the new year
+        let mut date = Date::try_new_hebrew_date(5780, 2, 30).unwrap();
+        date.add(DateDuration::new(1, 0, 0, 0));
+        assert_eq!(date, Date::try_new_hebrew_date(5781, 2, 29).unwrap());
+
+        let mut date = Date::try_new_hebrew_date(5780, 3, 30).unwrap();
+        date.add(DateDuration::new(1, 0, 0, 0));
+        assert_eq!(date, Date::try_new_hebrew_date(5781, 3, 29).unwrap());
+
+        let mut date = Date::try_new_hebrew_date(5782, 6, 30).unwrap();
+        date.add(DateDuration::new(1, 0, 0, 0));
+        assert_eq!(date, Date::try_new_hebrew_date(5783, 6, 29).unwrap());
+
+        let mut date = Date::try_new_hebrew_date(5782, 13, 29).unwrap();
+        date.add(DateDuration::new(0, 0, 0, 1));
+        assert_eq!(date, Date::try_new_hebrew_date(5783, 1, 1).unwrap());
+    }
+}
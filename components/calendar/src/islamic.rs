@@ -0,0 +1,1288 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains types and implementations for the Islamic calendars.
+//!
+//! ```rust
+//! use icu::calendar::islamic::IslamicCivil;
+//! use icu::calendar::{Date, DateTime};
+//!
+//! // `Date` type
+//! let date_iso = Date::try_new_iso_date(1970, 1, 2)
+//!     .expect("Failed to initialize ISO Date instance.");
+//! let date_islamic = Date::new_from_iso(date_iso, IslamicCivil);
+//!
+//! // `DateTime` type
+//! let datetime_iso = DateTime::try_new_iso_datetime(1970, 1, 2, 13, 1, 0)
+//!     .expect("Failed to initialize ISO DateTime instance.");
+//! let datetime_islamic = DateTime::new_from_iso(datetime_iso, IslamicCivil);
+//!
+//! // `Date` checks
+//! assert_eq!(date_islamic.year().number, 1389);
+//! assert_eq!(date_islamic.month().ordinal, 10);
+//! assert_eq!(date_islamic.day_of_month().0, 23);
+//!
+//! // `DateTime` checks
+//! assert_eq!(datetime_islamic.date.year().number, 1389);
+//! assert_eq!(datetime_islamic.date.month().ordinal, 10);
+//! assert_eq!(datetime_islamic.date.day_of_month().0, 23);
+//! assert_eq!(datetime_islamic.time.hour.number(), 13);
+//! assert_eq!(datetime_islamic.time.minute.number(), 1);
+//! assert_eq!(datetime_islamic.time.second.number(), 0);
+//! ```
+
+use crate::any_calendar::AnyCalendarKind;
+use crate::astronomy::{self, Location, MEAN_SYNODIC_MONTH};
+use crate::calendar_arithmetic::{ArithmeticDate, CalendarArithmetic};
+use crate::iso::Iso;
+use crate::julian::Julian;
+use crate::provider::IslamicUmmAlQuraCacheV1Marker;
+use crate::{types, Calendar, CalendarError, Date, DateDuration, DateDurationUnit, DateTime};
+use core::convert::TryFrom;
+use icu_provider::prelude::*;
+use tinystr::tinystr;
+
+/// The fixed date of the start of the Islamic calendar, July 16, 622 CE (Julian)
+const FIXED_ISLAMIC_EPOCH_FRIDAY: i32 = Julian::fixed_from_julian_integers(622, 7, 16);
+
+/// The astronomical epoch of the Islamic calendar, July 15, 622 CE (Julian), used
+/// by the tabular calendar
+const FIXED_ISLAMIC_EPOCH_THURSDAY: i32 = Julian::fixed_from_julian_integers(622, 7, 15);
+
+/// The location used for the observational calendar, following _Calendrical Calculations_
+const CAIRO: Location = Location {
+    latitude: 30.1,
+    longitude: 31.3,
+    elevation: 200.0,
+    zone: 2.0 / 24.0,
+};
+
+/// The location of the Umm al-Qura visibility criterion
+const MECCA: Location = Location {
+    latitude: 21.0 + 25.0 / 60.0 + 24.0 / 3600.0,
+    longitude: 39.0 + 49.0 / 60.0 + 24.0 / 3600.0,
+    elevation: 298.0,
+    zone: 3.0 / 24.0,
+};
+
+/// The [observational Islamic calendar](https://en.wikipedia.org/wiki/Islamic_calendar)
+///
+/// Months start on the evening when the crescent moon first becomes visible after the
+/// new moon. Visibility is predicted astronomically for Cairo, following
+/// _Calendrical Calculations_, so dates may differ by a day or two from those announced
+/// by religious authorities.
+///
+/// This corresponds to the `"islamic"` [CLDR calendar](https://unicode.org/reports/tr35/#UnicodeCalendarIdentifier).
+///
+/// # Era codes
+///
+/// This calendar supports a single era code, `"ah"`, corresponding to the Anno Hegirae era.
+///
+/// # Month codes
+///
+/// This calendar is a pure lunar calendar with no leap months. It uses month codes
+/// `"M01"` to `"M12"`.
+#[derive(Copy, Clone, Debug, Hash, Default, Eq, PartialEq)]
+#[allow(clippy::exhaustive_structs)] // this type is stable
+pub struct IslamicObservational;
+
+/// The [Umm al-Qura Islamic calendar](https://en.wikipedia.org/wiki/Islamic_calendar#Saudi_Arabia's_Umm_al-Qura_calendar),
+/// used in Saudi Arabia
+///
+/// A month starts on a day if, on the previous evening in Mecca, the moon has passed the new
+/// moon and sets after the sun.
+///
+/// This corresponds to the `"islamic-umalqura"` [CLDR calendar](https://unicode.org/reports/tr35/#UnicodeCalendarIdentifier).
+///
+/// The month lengths are loaded from data, requiring a data provider capable of providing
+/// [`IslamicUmmAlQuraCacheV1Marker`] data (`calendar/islamicumalquracache@1`). Years that are
+/// not covered by the data are computed astronomically.
+///
+/// # Era codes
+///
+/// This calendar supports a single era code, `"ah"`, corresponding to the Anno Hegirae era.
+///
+/// # Month codes
+///
+/// This calendar is a pure lunar calendar with no leap months. It uses month codes
+/// `"M01"` to `"M12"`.
+#[derive(Clone, Debug, Default)]
+pub struct IslamicUmmAlQura {
+    cache: DataPayload<IslamicUmmAlQuraCacheV1Marker>,
+}
+
+/// The [civil Islamic calendar](https://en.wikipedia.org/wiki/Tabular_Islamic_calendar)
+///
+/// This is an arithmetical calendar with alternating 30 and 29 day months, in which 11 of every
+/// 30 years add a day to the last month. It is counted from the civil epoch of July 16, 622 CE.
+///
+/// This corresponds to the `"islamic-civil"` [CLDR calendar](https://unicode.org/reports/tr35/#UnicodeCalendarIdentifier).
+///
+/// # Era codes
+///
+/// This calendar supports a single era code, `"ah"`, corresponding to the Anno Hegirae era.
+///
+/// # Month codes
+///
+/// This calendar is a pure lunar calendar with no leap months. It uses month codes
+/// `"M01"` to `"M12"`.
+#[derive(Copy, Clone, Debug, Hash, Default, Eq, PartialEq)]
+#[allow(clippy::exhaustive_structs)] // this type is stable
+pub struct IslamicCivil;
+
+/// The [tabular Islamic calendar](https://en.wikipedia.org/wiki/Tabular_Islamic_calendar)
+///
+/// This is the same arithmetical calendar as [`IslamicCivil`], but counted from the
+/// astronomical epoch of July 15, 622 CE, one day earlier.
+///
+/// This corresponds to the `"islamic-tbla"` [CLDR calendar](https://unicode.org/reports/tr35/#UnicodeCalendarIdentifier).
+///
+/// # Era codes
+///
+/// This calendar supports a single era code, `"ah"`, corresponding to the Anno Hegirae era.
+///
+/// # Month codes
+///
+/// This calendar is a pure lunar calendar with no leap months. It uses month codes
+/// `"M01"` to `"M12"`.
+#[derive(Copy, Clone, Debug, Hash, Default, Eq, PartialEq)]
+#[allow(clippy::exhaustive_structs)] // this type is stable
+pub struct IslamicTabular;
+
+/// The inner date type used for representing [`Date`]s of [`IslamicObservational`]. See [`Date`] and [`IslamicObservational`] for more details.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct IslamicObservationalDateInner(ArithmeticDate<IslamicObservational>);
+
+/// The inner date type used for representing [`Date`]s of [`IslamicUmmAlQura`]. See [`Date`] and [`IslamicUmmAlQura`] for more details.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct IslamicUmmAlQuraDateInner {
+    year: i32,
+    month: u8,
+    day: u8,
+    info: UmmAlQuraYearInfo,
+}
+
+/// The inner date type used for representing [`Date`]s of [`IslamicCivil`]. See [`Date`] and [`IslamicCivil`] for more details.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct IslamicCivilDateInner(ArithmeticDate<IslamicCivil>);
+
+/// The inner date type used for representing [`Date`]s of [`IslamicTabular`]. See [`Date`] and [`IslamicTabular`] for more details.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct IslamicTabularDateInner(ArithmeticDate<IslamicTabular>);
+
+fn year_as_islamic(year: i32) -> types::FormattableYear {
+    types::FormattableYear {
+        era: types::Era(tinystr!(16, "ah")),
+        number: year,
+        cyclic: None,
+        related_iso: None,
+    }
+}
+
+fn check_era(era: types::Era, debug_name: &'static str) -> Result<(), CalendarError> {
+    if era.0 == tinystr!(16, "ah") {
+        Ok(())
+    } else {
+        Err(CalendarError::UnknownEra(era.0, debug_name))
+    }
+}
+
+// Tabular arithmetic, shared by the civil and tabular calendars
+
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L2064-L2067>
+fn is_tabular_leap_year(year: i32) -> bool {
+    (14 + 11 * year).rem_euclid(30) < 11
+}
+
+fn tabular_month_days(year: i32, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 9 | 11 => 30,
+        12 if is_tabular_leap_year(year) => 30,
+        _ => 29,
+    }
+}
+
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L2069-L2083>
+fn fixed_from_tabular(epoch: i32, year: i32, month: u8, day: u8) -> i32 {
+    let month = month as i32;
+    epoch - 1
+        + (year - 1) * 354
+        + (3 + 11 * year).div_euclid(30)
+        + 29 * (month - 1)
+        + month / 2
+        + day as i32
+}
+
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L2085-L2101>
+fn tabular_from_fixed(epoch: i32, date: i32) -> (i32, u8, u8) {
+    let year = (30 * (date - epoch) + 10646).div_euclid(30 * 354 + 11);
+    let prior_days = date - fixed_from_tabular(epoch, year, 1, 1);
+    // prior_days is at most 354, so the month is at most 12
+    let month = ((11 * prior_days + 330) / 325) as u8;
+    let day = (date - fixed_from_tabular(epoch, year, month, 1) + 1) as u8;
+    (year, month, day)
+}
+
+// Observational arithmetic, shared by the observational and Umm al-Qura calendars
+
+/// A day in the middle of the given month, used to find the start of the month
+fn midmonth(year: i32, month: u8) -> i32 {
+    FIXED_ISLAMIC_EPOCH_FRIDAY
+        + libm::floor(((year - 1) as f64 * 12.0 + month as f64 - 0.5) * MEAN_SYNODIC_MONTH) as i32
+}
+
+/// The last day on or before `date` that starts a month, given the criterion that decides whether
+/// a month starts on a day
+///
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L6868-L6881>
+fn new_month_on_or_before(date: i32, criterion: fn(i32) -> bool) -> i32 {
+    let moon = libm::floor(astronomy::lunar_phase_at_or_before(0.0, date as f64)) as i32;
+    // The crescent is never visible on the first day after the new moon, so the month
+    // containing `date` may have started after the previous new moon
+    let start = if date - moon <= 3 && !criterion(date) {
+        moon - 30
+    } else {
+        moon
+    };
+    let mut day = start;
+    while !criterion(day) {
+        day += 1;
+    }
+    day
+}
+
+/// Whether the crescent moon is visible in Cairo on the eve of `date`
+fn visible_crescent(date: i32) -> bool {
+    astronomy::visible_crescent(date as f64, CAIRO)
+}
+
+/// Whether the Umm al-Qura criterion holds on the eve of `date`: at sunset in Mecca, the
+/// moon is waxing and sets after the sun
+///
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L6957-L6964>
+fn saudi_criterion(date: i32) -> bool {
+    let eve = (date - 1) as f64;
+    matches!(astronomy::lunar_phase_at_sunset(eve, MECCA), Some(phase) if 0.0 < phase && phase < 90.0)
+        && matches!(astronomy::moonlag(eve, MECCA), Some(lag) if lag > 0.0)
+}
+
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L6904-L6914>
+fn observational_month_start(year: i32, month: u8) -> i32 {
+    new_month_on_or_before(midmonth(year, month), visible_crescent)
+}
+
+/// Lisp code reference: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L6916-L6929>
+fn observational_from_fixed(date: i32) -> ArithmeticDate<IslamicObservational> {
+    let crescent = new_month_on_or_before(date, visible_crescent);
+    let elapsed_months =
+        libm::round((crescent - FIXED_ISLAMIC_EPOCH_FRIDAY) as f64 / MEAN_SYNODIC_MONTH) as i32;
+    ArithmeticDate::new(
+        elapsed_months.div_euclid(12) + 1,
+        elapsed_months.rem_euclid(12) as u8 + 1,
+        (date - crescent + 1) as u8,
+    )
+}
+
+impl CalendarArithmetic for IslamicObservational {
+    fn month_days(year: i32, month: u8) -> u8 {
+        let next = if month == 12 {
+            observational_month_start(year + 1, 1)
+        } else {
+            observational_month_start(year, month + 1)
+        };
+        (next - observational_month_start(year, month)) as u8
+    }
+
+    fn months_for_every_year(_year: i32) -> u8 {
+        12
+    }
+
+    fn is_leap_year(year: i32) -> bool {
+        Self::days_in_provided_year(year) > 354
+    }
+
+    fn days_in_provided_year(year: i32) -> u32 {
+        (observational_month_start(year + 1, 1) - observational_month_start(year, 1)) as u32
+    }
+}
+
+impl Calendar for IslamicObservational {
+    type DateInner = IslamicObservationalDateInner;
+    fn date_from_codes(
+        &self,
+        era: types::Era,
+        year: i32,
+        month_code: types::MonthCode,
+        day: u8,
+    ) -> Result<Self::DateInner, CalendarError> {
+        check_era(era, self.debug_name())?;
+        ArithmeticDate::new_from_solar(self, year, month_code, day)
+            .map(IslamicObservationalDateInner)
+    }
+
+    fn date_from_iso(&self, iso: Date<Iso>) -> Self::DateInner {
+        let fixed_iso = Iso::fixed_from_iso(*iso.inner());
+        IslamicObservationalDateInner(observational_from_fixed(fixed_iso))
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        let fixed = observational_month_start(date.0.year, date.0.month) + date.0.day as i32 - 1;
+        Iso::iso_from_fixed(fixed)
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        date.0.months_in_year()
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u32 {
+        date.0.days_in_year()
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        date.0.days_in_month()
+    }
+
+    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
+        date.0.offset_date(offset)
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        _calendar2: &Self,
+        _largest_unit: DateDurationUnit,
+        _smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        date1.0.until(date2.0, _largest_unit, _smallest_unit)
+    }
+
+    fn year(&self, date: &Self::DateInner) -> types::FormattableYear {
+        year_as_islamic(date.0.year)
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::FormattableMonth {
+        date.0.solar_month()
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        date.0.day_of_month()
+    }
+
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        let prev_year = date.0.year - 1;
+        let next_year = date.0.year + 1;
+        types::DayOfYearInfo {
+            day_of_year: date.0.day_of_year(),
+            days_in_year: date.0.days_in_year(),
+            prev_year: year_as_islamic(prev_year),
+            days_in_prev_year: Self::days_in_provided_year(prev_year),
+            next_year: year_as_islamic(next_year),
+        }
+    }
+
+    fn debug_name(&self) -> &'static str {
+        "Islamic (observational)"
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        Some(AnyCalendarKind::IslamicObservational)
+    }
+}
+
+/// The start and month lengths of an Umm al-Qura year
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+struct UmmAlQuraYearInfo {
+    /// The fixed date of the first day of the year
+    new_year: i32,
+    /// Bit `n` is set if month `n + 1` has 30 days
+    long_months: u16,
+}
+
+impl UmmAlQuraYearInfo {
+    fn compute(year: i32) -> Self {
+        let mut starts = [0; 13];
+        for (month, start) in (1..=12).zip(starts.iter_mut()) {
+            *start = new_month_on_or_before(midmonth(year, month), saudi_criterion);
+        }
+        let new_year = starts[0];
+        starts[12] = new_month_on_or_before(midmonth(year + 1, 1), saudi_criterion);
+        let mut long_months = 0;
+        for (month, pair) in starts.windows(2).enumerate() {
+            if let [start, end] = *pair {
+                debug_assert!(
+                    matches!(end - start, 29 | 30),
+                    "Umm al-Qura months have 29 or 30 days"
+                );
+                if end - start > 29 {
+                    long_months |= 1 << month;
+                }
+            }
+        }
+        Self {
+            new_year,
+            long_months,
+        }
+    }
+
+    fn unpack(year: i32, packed: u16) -> Self {
+        // The offset is stored as a signed integer in the upper four bits
+        let offset = (packed as i16) >> 12;
+        Self {
+            new_year: fixed_from_tabular(FIXED_ISLAMIC_EPOCH_FRIDAY, year, 1, 1) + offset as i32,
+            long_months: packed & 0xFFF,
+        }
+    }
+
+    fn month_days(self, month: u8) -> u8 {
+        if self.long_months & (1 << (month - 1)) != 0 {
+            30
+        } else {
+            29
+        }
+    }
+
+    fn days_before_month(self, month: u8) -> u16 {
+        (1..month).map(|m| self.month_days(m) as u16).sum()
+    }
+
+    fn days_in_year(self) -> u32 {
+        354 + (self.long_months.count_ones() as u32) - 6
+    }
+}
+
+/// Packs the information about an Umm al-Qura year for [`IslamicUmmAlQuraCacheV1`](crate::provider::IslamicUmmAlQuraCacheV1)
+#[cfg(feature = "datagen")]
+pub(crate) fn pack_umm_al_qura_year(year: i32) -> u16 {
+    let info = UmmAlQuraYearInfo::compute(year);
+    let offset = info.new_year - fixed_from_tabular(FIXED_ISLAMIC_EPOCH_FRIDAY, year, 1, 1);
+    debug_assert!(
+        (-8..8).contains(&offset),
+        "Umm al-Qura years start within a week of the civil new year"
+    );
+    ((offset as u16) << 12) | info.long_months
+}
+
+impl IslamicUmmAlQura {
+    /// Creates a new [`IslamicUmmAlQura`] using the cached year data from the data provider.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_unstable<D: DataProvider<IslamicUmmAlQuraCacheV1Marker> + ?Sized>(
+        data_provider: &D,
+    ) -> Result<Self, CalendarError> {
+        let cache = data_provider
+            .load(DataRequest {
+                locale: Default::default(),
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+        Ok(Self { cache })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(locale: skip, options: skip, error: CalendarError);
+
+    /// Creates a new [`IslamicUmmAlQura`] without any cached data, computing every year
+    /// astronomically.
+    ///
+    /// This is significantly slower than using the cached data.
+    pub fn new_always_calculating() -> Self {
+        Self::default()
+    }
+
+    fn year_info(&self, year: i32) -> UmmAlQuraYearInfo {
+        let cache = self.cache.get();
+        year.checked_sub(cache.first_extended_year)
+            .and_then(|index| usize::try_from(index).ok())
+            .and_then(|index| cache.year_info.get(index))
+            .map(|packed| UmmAlQuraYearInfo::unpack(year, packed))
+            .unwrap_or_else(|| UmmAlQuraYearInfo::compute(year))
+    }
+
+    fn date_from_fixed(&self, date: i32) -> IslamicUmmAlQuraDateInner {
+        // Umm al-Qura years start within a few days of the civil ones
+        let (mut year, _, _) = tabular_from_fixed(FIXED_ISLAMIC_EPOCH_FRIDAY, date);
+        let mut info = self.year_info(year);
+        if date < info.new_year {
+            year -= 1;
+            info = self.year_info(year);
+        } else {
+            let next = self.year_info(year + 1);
+            if date >= next.new_year {
+                year += 1;
+                info = next;
+            }
+        }
+        let mut day = date - info.new_year + 1;
+        let mut month = 1;
+        while month < 12 && day > info.month_days(month) as i32 {
+            day -= info.month_days(month) as i32;
+            month += 1;
+        }
+        IslamicUmmAlQuraDateInner {
+            year,
+            month,
+            day: day as u8,
+            info,
+        }
+    }
+
+    fn fixed_from_date(date: &IslamicUmmAlQuraDateInner) -> i32 {
+        date.info.new_year + date.info.days_before_month(date.month) as i32 + date.day as i32 - 1
+    }
+}
+
+impl Calendar for IslamicUmmAlQura {
+    type DateInner = IslamicUmmAlQuraDateInner;
+    fn date_from_codes(
+        &self,
+        era: types::Era,
+        year: i32,
+        month_code: types::MonthCode,
+        day: u8,
+    ) -> Result<Self::DateInner, CalendarError> {
+        check_era(era, self.debug_name())?;
+        let month = match crate::calendar_arithmetic::ordinal_solar_month_from_code(month_code) {
+            Some(month) if month <= 12 => month,
+            _ => {
+                return Err(CalendarError::UnknownMonthCode(
+                    month_code.0,
+                    self.debug_name(),
+                ))
+            }
+        };
+        let info = self.year_info(year);
+        if day < 1 || day > info.month_days(month) {
+            return Err(CalendarError::OutOfRange);
+        }
+        Ok(IslamicUmmAlQuraDateInner {
+            year,
+            month,
+            day,
+            info,
+        })
+    }
+
+    fn date_from_iso(&self, iso: Date<Iso>) -> Self::DateInner {
+        self.date_from_fixed(Iso::fixed_from_iso(*iso.inner()))
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        Iso::iso_from_fixed(Self::fixed_from_date(date))
+    }
+
+    fn months_in_year(&self, _date: &Self::DateInner) -> u8 {
+        12
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u32 {
+        date.info.days_in_year()
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        date.info.month_days(date.month)
+    }
+
+    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
+        // Move by whole years and months first, then by days from the start of the month,
+        // like `ArithmeticDate::offset_date`
+        let months = date.month as i32 - 1 + offset.months;
+        let year = date.year + offset.years + months.div_euclid(12);
+        let month = months.rem_euclid(12) as u8 + 1;
+        let info = self.year_info(year);
+        let month_start = info.new_year + info.days_before_month(month) as i32;
+        *date = self
+            .date_from_fixed(month_start + date.day as i32 - 1 + offset.weeks * 7 + offset.days);
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        _calendar2: &Self,
+        _largest_unit: DateDurationUnit,
+        _smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        DateDuration::new(
+            date1.year - date2.year,
+            date1.month as i32 - date2.month as i32,
+            0,
+            date1.day as i32 - date2.day as i32,
+        )
+    }
+
+    fn year(&self, date: &Self::DateInner) -> types::FormattableYear {
+        year_as_islamic(date.year)
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::FormattableMonth {
+        let code = match date.month {
+            1 => tinystr!(4, "M01"),
+            2 => tinystr!(4, "M02"),
+            3 => tinystr!(4, "M03"),
+            4 => tinystr!(4, "M04"),
+            5 => tinystr!(4, "M05"),
+            6 => tinystr!(4, "M06"),
+            7 => tinystr!(4, "M07"),
+            8 => tinystr!(4, "M08"),
+            9 => tinystr!(4, "M09"),
+            10 => tinystr!(4, "M10"),
+            11 => tinystr!(4, "M11"),
+            12 => tinystr!(4, "M12"),
+            _ => tinystr!(4, "und"),
+        };
+        types::FormattableMonth {
+            ordinal: date.month as u32,
+            code: types::MonthCode(code),
+        }
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        types::DayOfMonth(date.day.into())
+    }
+
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        let prev_year = date.year - 1;
+        let next_year = date.year + 1;
+        types::DayOfYearInfo {
+            day_of_year: date.info.days_before_month(date.month) as u32 + date.day as u32,
+            days_in_year: date.info.days_in_year(),
+            prev_year: year_as_islamic(prev_year),
+            days_in_prev_year: self.year_info(prev_year).days_in_year(),
+            next_year: year_as_islamic(next_year),
+        }
+    }
+
+    fn debug_name(&self) -> &'static str {
+        "Islamic (Umm al-Qura)"
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        Some(AnyCalendarKind::IslamicUmmAlQura)
+    }
+}
+
+impl CalendarArithmetic for IslamicCivil {
+    fn month_days(year: i32, month: u8) -> u8 {
+        tabular_month_days(year, month)
+    }
+
+    fn months_for_every_year(_year: i32) -> u8 {
+        12
+    }
+
+    fn is_leap_year(year: i32) -> bool {
+        is_tabular_leap_year(year)
+    }
+
+    fn days_in_provided_year(year: i32) -> u32 {
+        if Self::is_leap_year(year) {
+            355
+        } else {
+            354
+        }
+    }
+}
+
+impl Calendar for IslamicCivil {
+    type DateInner = IslamicCivilDateInner;
+    fn date_from_codes(
+        &self,
+        era: types::Era,
+        year: i32,
+        month_code: types::MonthCode,
+        day: u8,
+    ) -> Result<Self::DateInner, CalendarError> {
+        check_era(era, self.debug_name())?;
+        ArithmeticDate::new_from_solar(self, year, month_code, day).map(IslamicCivilDateInner)
+    }
+
+    fn date_from_iso(&self, iso: Date<Iso>) -> Self::DateInner {
+        let fixed_iso = Iso::fixed_from_iso(*iso.inner());
+        let (year, month, day) = tabular_from_fixed(FIXED_ISLAMIC_EPOCH_FRIDAY, fixed_iso);
+        IslamicCivilDateInner(ArithmeticDate::new(year, month, day))
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        Iso::iso_from_fixed(fixed_from_tabular(
+            FIXED_ISLAMIC_EPOCH_FRIDAY,
+            date.0.year,
+            date.0.month,
+            date.0.day,
+        ))
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        date.0.months_in_year()
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u32 {
+        date.0.days_in_year()
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        date.0.days_in_month()
+    }
+
+    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
+        date.0.offset_date(offset)
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        _calendar2: &Self,
+        _largest_unit: DateDurationUnit,
+        _smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        date1.0.until(date2.0, _largest_unit, _smallest_unit)
+    }
+
+    fn year(&self, date: &Self::DateInner) -> types::FormattableYear {
+        year_as_islamic(date.0.year)
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::FormattableMonth {
+        date.0.solar_month()
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        date.0.day_of_month()
+    }
+
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        let prev_year = date.0.year - 1;
+        let next_year = date.0.year + 1;
+        types::DayOfYearInfo {
+            day_of_year: date.0.day_of_year(),
+            days_in_year: date.0.days_in_year(),
+            prev_year: year_as_islamic(prev_year),
+            days_in_prev_year: Self::days_in_provided_year(prev_year),
+            next_year: year_as_islamic(next_year),
+        }
+    }
+
+    fn debug_name(&self) -> &'static str {
+        "Islamic (civil)"
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        Some(AnyCalendarKind::IslamicCivil)
+    }
+}
+
+impl CalendarArithmetic for IslamicTabular {
+    fn month_days(year: i32, month: u8) -> u8 {
+        tabular_month_days(year, month)
+    }
+
+    fn months_for_every_year(_year: i32) -> u8 {
+        12
+    }
+
+    fn is_leap_year(year: i32) -> bool {
+        is_tabular_leap_year(year)
+    }
+
+    fn days_in_provided_year(year: i32) -> u32 {
+        if Self::is_leap_year(year) {
+            355
+        } else {
+            354
+        }
+    }
+}
+
+impl Calendar for IslamicTabular {
+    type DateInner = IslamicTabularDateInner;
+    fn date_from_codes(
+        &self,
+        era: types::Era,
+        year: i32,
+        month_code: types::MonthCode,
+        day: u8,
+    ) -> Result<Self::DateInner, CalendarError> {
+        check_era(era, self.debug_name())?;
+        ArithmeticDate::new_from_solar(self, year, month_code, day).map(IslamicTabularDateInner)
+    }
+
+    fn date_from_iso(&self, iso: Date<Iso>) -> Self::DateInner {
+        let fixed_iso = Iso::fixed_from_iso(*iso.inner());
+        let (year, month, day) = tabular_from_fixed(FIXED_ISLAMIC_EPOCH_THURSDAY, fixed_iso);
+        IslamicTabularDateInner(ArithmeticDate::new(year, month, day))
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        Iso::iso_from_fixed(fixed_from_tabular(
+            FIXED_ISLAMIC_EPOCH_THURSDAY,
+            date.0.year,
+            date.0.month,
+            date.0.day,
+        ))
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        date.0.months_in_year()
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u32 {
+        date.0.days_in_year()
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        date.0.days_in_month()
+    }
+
+    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
+        date.0.offset_date(offset)
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        _calendar2: &Self,
+        _largest_unit: DateDurationUnit,
+        _smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        date1.0.until(date2.0, _largest_unit, _smallest_unit)
+    }
+
+    fn year(&self, date: &Self::DateInner) -> types::FormattableYear {
+        year_as_islamic(date.0.year)
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::FormattableMonth {
+        date.0.solar_month()
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        date.0.day_of_month()
+    }
+
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        let prev_year = date.0.year - 1;
+        let next_year = date.0.year + 1;
+        types::DayOfYearInfo {
+            day_of_year: date.0.day_of_year(),
+            days_in_year: date.0.days_in_year(),
+            prev_year: year_as_islamic(prev_year),
+            days_in_prev_year: Self::days_in_provided_year(prev_year),
+            next_year: year_as_islamic(next_year),
+        }
+    }
+
+    fn debug_name(&self) -> &'static str {
+        "Islamic (tabular)"
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        Some(AnyCalendarKind::IslamicTabular)
+    }
+}
+
+impl Date<IslamicObservational> {
+    /// Construct new observational Islamic Date.
+    ///
+    /// ```rust
+    /// use icu::calendar::islamic::IslamicObservational;
+    /// use icu::calendar::Date;
+    ///
+    /// let date_islamic = Date::try_new_observational_islamic_date(1392, 4, 25)
+    ///     .expect("Failed to initialize Islamic Date instance.");
+    ///
+    /// assert_eq!(date_islamic.year().number, 1392);
+    /// assert_eq!(date_islamic.month().ordinal, 4);
+    /// assert_eq!(date_islamic.day_of_month().0, 25);
+    /// ```
+    pub fn try_new_observational_islamic_date(
+        year: i32,
+        month: u8,
+        day: u8,
+    ) -> Result<Date<IslamicObservational>, CalendarError> {
+        if !(1..=12).contains(&month)
+            || day < 1
+            || day > IslamicObservational::month_days(year, month)
+        {
+            return Err(CalendarError::OutOfRange);
+        }
+        Ok(Date::from_raw(
+            IslamicObservationalDateInner(ArithmeticDate::new(year, month, day)),
+            IslamicObservational,
+        ))
+    }
+}
+
+impl<A: crate::AsCalendar<Calendar = IslamicUmmAlQura>> Date<A> {
+    /// Construct new Umm al-Qura Islamic Date.
+    ///
+    /// ```rust
+    /// use icu::calendar::islamic::IslamicUmmAlQura;
+    /// use icu::calendar::Date;
+    ///
+    /// let islamic = IslamicUmmAlQura::try_new_unstable(&icu_testdata::unstable())
+    ///     .expect("Cannot load Umm al-Qura data");
+    ///
+    /// let date_islamic = Date::try_new_ummalqura_date(1392, 4, 25, islamic)
+    ///     .expect("Failed to initialize Islamic Date instance.");
+    ///
+    /// assert_eq!(date_islamic.year().number, 1392);
+    /// assert_eq!(date_islamic.month().ordinal, 4);
+    /// assert_eq!(date_islamic.day_of_month().0, 25);
+    /// ```
+    pub fn try_new_ummalqura_date(
+        year: i32,
+        month: u8,
+        day: u8,
+        calendar: A,
+    ) -> Result<Date<A>, CalendarError> {
+        if !(1..=12).contains(&month) {
+            return Err(CalendarError::OutOfRange);
+        }
+        let info = calendar.as_calendar().year_info(year);
+        if day < 1 || day > info.month_days(month) {
+            return Err(CalendarError::OutOfRange);
+        }
+        Ok(Date::from_raw(
+            IslamicUmmAlQuraDateInner {
+                year,
+                month,
+                day,
+                info,
+            },
+            calendar,
+        ))
+    }
+}
+
+impl Date<IslamicCivil> {
+    /// Construct new civil Islamic Date.
+    ///
+    /// ```rust
+    /// use icu::calendar::islamic::IslamicCivil;
+    /// use icu::calendar::Date;
+    ///
+    /// let date_islamic = Date::try_new_islamic_civil_date(1392, 4, 25)
+    ///     .expect("Failed to initialize Islamic Date instance.");
+    ///
+    /// assert_eq!(date_islamic.year().number, 1392);
+    /// assert_eq!(date_islamic.month().ordinal, 4);
+    /// assert_eq!(date_islamic.day_of_month().0, 25);
+    /// ```
+    pub fn try_new_islamic_civil_date(
+        year: i32,
+        month: u8,
+        day: u8,
+    ) -> Result<Date<IslamicCivil>, CalendarError> {
+        if !(1..=12).contains(&month) || day < 1 || day > tabular_month_days(year, month) {
+            return Err(CalendarError::OutOfRange);
+        }
+        Ok(Date::from_raw(
+            IslamicCivilDateInner(ArithmeticDate::new(year, month, day)),
+            IslamicCivil,
+        ))
+    }
+}
+
+impl Date<IslamicTabular> {
+    /// Construct new tabular Islamic Date.
+    ///
+    /// ```rust
+    /// use icu::calendar::islamic::IslamicTabular;
+    /// use icu::calendar::Date;
+    ///
+    /// let date_islamic = Date::try_new_islamic_tabular_date(1392, 4, 25)
+    ///     .expect("Failed to initialize Islamic Date instance.");
+    ///
+    /// assert_eq!(date_islamic.year().number, 1392);
+    /// assert_eq!(date_islamic.month().ordinal, 4);
+    /// assert_eq!(date_islamic.day_of_month().0, 25);
+    /// ```
+    pub fn try_new_islamic_tabular_date(
+        year: i32,
+        month: u8,
+        day: u8,
+    ) -> Result<Date<IslamicTabular>, CalendarError> {
+        if !(1..=12).contains(&month) || day < 1 || day > tabular_month_days(year, month) {
+            return Err(CalendarError::OutOfRange);
+        }
+        Ok(Date::from_raw(
+            IslamicTabularDateInner(ArithmeticDate::new(year, month, day)),
+            IslamicTabular,
+        ))
+    }
+}
+
+impl DateTime<IslamicObservational> {
+    /// Construct a new observational Islamic datetime from integers.
+    ///
+    /// ```rust
+    /// use icu::calendar::DateTime;
+    ///
+    /// let datetime_islamic =
+    ///     DateTime::try_new_observational_islamic_datetime(474, 10, 11, 13, 1, 0)
+    ///         .expect("Failed to initialize Islamic DateTime instance.");
+    ///
+    /// assert_eq!(datetime_islamic.date.year().number, 474);
+    /// assert_eq!(datetime_islamic.date.month().ordinal, 10);
+    /// assert_eq!(datetime_islamic.date.day_of_month().0, 11);
+    /// assert_eq!(datetime_islamic.time.hour.number(), 13);
+    /// assert_eq!(datetime_islamic.time.minute.number(), 1);
+    /// assert_eq!(datetime_islamic.time.second.number(), 0);
+    /// ```
+    pub fn try_new_observational_islamic_datetime(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<DateTime<IslamicObservational>, CalendarError> {
+        Ok(DateTime {
+            date: Date::try_new_observational_islamic_date(year, month, day)?,
+            time: types::Time::try_new(hour, minute, second, 0)?,
+        })
+    }
+}
+
+impl<A: crate::AsCalendar<Calendar = IslamicUmmAlQura>> DateTime<A> {
+    /// Construct a new Umm al-Qura Islamic datetime from integers.
+    ///
+    /// ```rust
+    /// use icu::calendar::islamic::IslamicUmmAlQura;
+    /// use icu::calendar::DateTime;
+    ///
+    /// let islamic = IslamicUmmAlQura::try_new_unstable(&icu_testdata::unstable())
+    ///     .expect("Cannot load Umm al-Qura data");
+    ///
+    /// let datetime_islamic =
+    ///     DateTime::try_new_ummalqura_datetime(474, 10, 11, 13, 1, 0, islamic)
+    ///         .expect("Failed to initialize Islamic DateTime instance.");
+    ///
+    /// assert_eq!(datetime_islamic.date.year().number, 474);
+    /// assert_eq!(datetime_islamic.date.month().ordinal, 10);
+    /// assert_eq!(datetime_islamic.date.day_of_month().0, 11);
+    /// assert_eq!(datetime_islamic.time.hour.number(), 13);
+    /// assert_eq!(datetime_islamic.time.minute.number(), 1);
+    /// assert_eq!(datetime_islamic.time.second.number(), 0);
+    /// ```
+    pub fn try_new_ummalqura_datetime(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        calendar: A,
+    ) -> Result<DateTime<A>, CalendarError> {
+        Ok(DateTime {
+            date: Date::try_new_ummalqura_date(year, month, day, calendar)?,
+            time: types::Time::try_new(hour, minute, second, 0)?,
+        })
+    }
+}
+
+impl DateTime<IslamicCivil> {
+    /// Construct a new civil Islamic datetime from integers.
+    ///
+    /// ```rust
+    /// use icu::calendar::DateTime;
+    ///
+    /// let datetime_islamic =
+    ///     DateTime::try_new_islamic_civil_datetime(474, 10, 11, 13, 1, 0)
+    ///         .expect("Failed to initialize Islamic DateTime instance.");
+    ///
+    /// assert_eq!(datetime_islamic.date.year().number, 474);
+    /// assert_eq!(datetime_islamic.date.month().ordinal, 10);
+    /// assert_eq!(datetime_islamic.date.day_of_month().0, 11);
+    /// assert_eq!(datetime_islamic.time.hour.number(), 13);
+    /// assert_eq!(datetime_islamic.time.minute.number(), 1);
+    /// assert_eq!(datetime_islamic.time.second.number(), 0);
+    /// ```
+    pub fn try_new_islamic_civil_datetime(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<DateTime<IslamicCivil>, CalendarError> {
+        Ok(DateTime {
+            date: Date::try_new_islamic_civil_date(year, month, day)?,
+            time: types::Time::try_new(hour, minute, second, 0)?,
+        })
+    }
+}
+
+impl DateTime<IslamicTabular> {
+    /// Construct a new tabular Islamic datetime from integers.
+    ///
+    /// ```rust
+    /// use icu::calendar::DateTime;
+    ///
+    /// let datetime_islamic =
+    ///     DateTime::try_new_islamic_tabular_datetime(474, 10, 11, 13, 1, 0)
+    ///         .expect("Failed to initialize Islamic DateTime instance.");
+    ///
+    /// assert_eq!(datetime_islamic.date.year().number, 474);
+    /// assert_eq!(datetime_islamic.date.month().ordinal, 10);
+    /// assert_eq!(datetime_islamic.date.day_of_month().0, 11);
+    /// assert_eq!(datetime_islamic.time.hour.number(), 13);
+    /// assert_eq!(datetime_islamic.time.minute.number(), 1);
+    /// assert_eq!(datetime_islamic.time.second.number(), 0);
+    /// ```
+    pub fn try_new_islamic_tabular_datetime(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<DateTime<IslamicTabular>, CalendarError> {
+        Ok(DateTime {
+            date: Date::try_new_islamic_tabular_date(year, month, day)?,
+            time: types::Time::try_new(hour, minute, second, 0)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Ref;
+
+    // (ISO date, arithmetic date, observational date) from the sample data of
+    // _Calendrical Calculations_
+    #[allow(clippy::type_complexity)]
+    const CASES: &[((i32, u8, u8), (i32, u8, u8), (i32, u8, u8))] = &[
+        ((70, 9, 24), (-568, 4, 1), (-568, 4, 2)),
+        ((576, 5, 20), (-47, 6, 3), (-47, 6, 3)),
+        ((1096, 5, 24), (489, 5, 22), (489, 5, 22)),
+        ((1288, 4, 2), (687, 2, 20), (687, 2, 21)),
+        ((1391, 6, 12), (793, 7, 1), (793, 6, 30)),
+        ((1553, 9, 19), (960, 9, 30), (960, 9, 30)),
+        ((1680, 6, 30), (1091, 6, 2), (1091, 6, 3)),
+        ((1768, 6, 19), (1182, 2, 3), (1182, 2, 4)),
+        ((1903, 4, 19), (1321, 1, 21), (1321, 1, 20)),
+        ((1941, 9, 29), (1360, 9, 8), (1360, 9, 7)),
+        ((1943, 4, 19), (1362, 4, 13), (1362, 4, 14)),
+        ((1992, 3, 17), (1412, 9, 13), (1412, 9, 12)),
+        ((2038, 11, 10), (1460, 10, 12), (1460, 10, 12)),
+        ((2094, 7, 18), (1518, 3, 5), (1518, 3, 5)),
+    ];
+
+    #[test]
+    fn test_civil_conversions() {
+        for &((iso_y, iso_m, iso_d), (y, m, d), _) in CASES {
+            let iso = Date::try_new_iso_date(iso_y, iso_m, iso_d).unwrap();
+            let civil = Date::try_new_islamic_civil_date(y, m, d).unwrap();
+            assert_eq!(civil.to_iso(), iso, "{y}-{m}-{d}: ISO date did not match");
+            assert_eq!(
+                iso.to_calendar(IslamicCivil),
+                civil,
+                "{iso:?}: civil date did not match"
+            );
+        }
+    }
+
+    #[test]
+    fn test_tabular_conversions() {
+        // The tabular calendar is one day ahead of the civil calendar
+        for &((iso_y, iso_m, iso_d), (y, m, d), _) in CASES {
+            let mut iso = Date::try_new_iso_date(iso_y, iso_m, iso_d).unwrap();
+            iso.add(DateDuration::new(0, 0, 0, -1));
+            let tabular = Date::try_new_islamic_tabular_date(y, m, d).unwrap();
+            assert_eq!(tabular.to_iso(), iso, "{y}-{m}-{d}: ISO date did not match");
+            assert_eq!(
+                iso.to_calendar(IslamicTabular),
+                tabular,
+                "{iso:?}: tabular date did not match"
+            );
+        }
+    }
+
+    #[test]
+    fn test_observational_conversions() {
+        for &((iso_y, iso_m, iso_d), _, (y, m, d)) in CASES {
+            let iso = Date::try_new_iso_date(iso_y, iso_m, iso_d).unwrap();
+            let observational = Date::try_new_observational_islamic_date(y, m, d).unwrap();
+            assert_eq!(
+                observational.to_iso(),
+                iso,
+                "{y}-{m}-{d}: ISO date did not match"
+            );
+            assert_eq!(
+                iso.to_calendar(IslamicObservational),
+                observational,
+                "{iso:?}: observational date did not match"
+            );
+        }
+    }
+
+    #[test]
+    fn test_umm_al_qura_conversions() {
+        let cached =
+            IslamicUmmAlQura::try_new_with_buffer_provider(&icu_testdata::buffer()).unwrap();
+        // (ISO date of the first of the month, Umm al-Qura year and month)
+        let cases = [
+            ((2023, 3, 23), (1444, 9)),
+            ((2023, 7, 19), (1445, 1)),
+            ((2024, 3, 11), (1445, 9)),
+            ((2025, 1, 31), (1446, 8)),
+        ];
+        for ((iso_y, iso_m, iso_d), (y, m)) in cases {
+            let iso = Date::try_new_iso_date(iso_y, iso_m, iso_d).unwrap();
+            let date = Date::try_new_ummalqura_date(y, m, 1, Ref(&cached)).unwrap();
+            assert_eq!(date.to_iso(), iso, "{y}-{m}-1: ISO date did not match");
+            assert_eq!(
+                iso.to_calendar(Ref(&cached)),
+                date,
+                "{iso:?}: date did not match"
+            );
+        }
+    }
+
+    #[test]
+    fn test_umm_al_qura_cache() {
+        let cached =
+            IslamicUmmAlQura::try_new_with_buffer_provider(&icu_testdata::buffer()).unwrap();
+        let calculating = IslamicUmmAlQura::new_always_calculating();
+        // Check the boundaries of the cached range as well as a few years inside it
+        for year in [1299, 1300, 1301, 1390, 1445, 1446, 1599, 1600, 1601] {
+            assert_eq!(
+                cached.year_info(year),
+                calculating.year_info(year),
+                "year {year}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_offset_date() {
+        let calendar = IslamicUmmAlQura::new_always_calculating();
+        let mut date = Date::try_new_ummalqura_date(1444, 12, 30, Ref(&calendar)).unwrap();
+        // Muharram 1445 has 29 days, so the day overflows into the next month
+        date.add(DateDuration::new(0, 1, 0, 0));
+        assert_eq!(
+            (
+                date.year().number,
+                date.month().ordinal,
+                date.day_of_month().0
+            ),
+            (1445, 2, 1)
+        );
+        date.add(DateDuration::new(-1, 0, 0, 3));
+        assert_eq!(
+            (
+                date.year().number,
+                date.month().ordinal,
+                date.day_of_month().0
+            ),
+            (1444, 2, 4)
+        );
+    }
+}
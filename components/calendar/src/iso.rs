@@ -386,7 +386,7 @@ impl Iso {
         fixed + (date.0.day as i32)
     }
 
-    fn fixed_from_iso_integers(year: i32, month: u8, day: u8) -> Option<i32> {
+    pub(crate) fn fixed_from_iso_integers(year: i32, month: u8, day: u8) -> Option<i32> {
         Date::try_new_iso_date(year, month, day)
             .ok()
             .map(|d| *d.inner())
@@ -414,9 +414,11 @@ impl Iso {
 
     // Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L1191-L1217
     fn iso_year_from_fixed(date: i32) -> i32 {
+        let date = date - EPOCH;
+
         // 400 year cycles have 146097 days
-        let n_400 = date / 146097;
-        let date = date % 146097;
+        let n_400 = date.div_euclid(146097);
+        let date = date.rem_euclid(146097);
 
         // 100 year cycles have 36524 days
         let n_100 = date / 36524;
@@ -481,6 +483,7 @@ impl Iso {
         types::FormattableYear {
             era: types::Era(tinystr!(16, "default")),
             number: year,
+            cyclic: None,
             related_iso: None,
         }
     }
@@ -529,6 +532,25 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_fixed_roundtrip() {
+        // The last days of leap years are the edge cases of the year computation
+        for (year, month, day) in [
+            (1, 1, 1),
+            (4, 12, 31),
+            (1600, 12, 31),
+            (1852, 12, 31),
+            (1900, 12, 31),
+            (2000, 12, 31),
+            (2020, 12, 31),
+            (2021, 1, 1),
+        ] {
+            let date = Date::try_new_iso_date(year, month, day).unwrap();
+            let fixed = Iso::fixed_from_iso(*date.inner());
+            assert_eq!(Iso::iso_from_fixed(fixed), date, "{year}-{month}-{day}");
+        }
+    }
+
     #[test]
     fn test_day_of_year() {
         // June 23, 2021 was day 174
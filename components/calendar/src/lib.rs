@@ -13,7 +13,8 @@
 //!
 //! The [`iso`] and [`gregorian`] modules contain implementations for the ISO and
 //! Gregorian calendars respectively. Further calendars can be found in modules like
-//! [`japanese`], [`julian`], [`coptic`], [`indian`], [`buddhist`], and [`ethiopian`].
+//! [`japanese`], [`julian`], [`coptic`], [`indian`], [`buddhist`], [`ethiopian`], [`hebrew`],
+//! [`chinese`], [`dangi`], [`islamic`], [`persian`], and [`roc`].
 //!
 //! Most interaction with this crate will be done via the [`Date`] and [`DateTime`] types.
 //!
@@ -116,19 +117,27 @@ mod date;
 mod datetime;
 
 pub mod any_calendar;
+mod astronomy;
 pub mod buddhist;
 mod calendar;
 mod calendar_arithmetic;
+pub mod chinese;
+mod chinese_based;
 pub mod coptic;
+pub mod dangi;
 mod duration;
 mod error;
 pub mod ethiopian;
 pub mod gregorian;
+pub mod hebrew;
 pub mod indian;
+pub mod islamic;
 pub mod iso;
 pub mod japanese;
 pub mod julian;
+pub mod persian;
 pub mod provider;
+pub mod roc;
 pub mod types;
 mod week_of;
 
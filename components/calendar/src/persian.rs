@@ -0,0 +1,402 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains types and implementations for the Persian calendar.
+//!
+//! ```rust
+//! use icu::calendar::{persian::Persian, Date, DateTime};
+//!
+//! // `Date` type
+//! let date_iso = Date::try_new_iso_date(1970, 1, 2)
+//!     .expect("Failed to initialize ISO Date instance.");
+//! let date_persian = Date::new_from_iso(date_iso, Persian);
+//!
+//! // `DateTime` type
+//! let datetime_iso = DateTime::try_new_iso_datetime(1970, 1, 2, 13, 1, 0)
+//!     .expect("Failed to initialize ISO DateTime instance.");
+//! let datetime_persian = DateTime::new_from_iso(datetime_iso, Persian);
+//!
+//! // `Date` checks
+//! assert_eq!(date_persian.year().number, 1348);
+//! assert_eq!(date_persian.month().ordinal, 10);
+//! assert_eq!(date_persian.day_of_month().0, 12);
+//!
+//! // `DateTime` checks
+//! assert_eq!(datetime_persian.date.year().number, 1348);
+//! assert_eq!(datetime_persian.date.month().ordinal, 10);
+//! assert_eq!(datetime_persian.date.day_of_month().0, 12);
+//! assert_eq!(datetime_persian.time.hour.number(), 13);
+//! assert_eq!(datetime_persian.time.minute.number(), 1);
+//! assert_eq!(datetime_persian.time.second.number(), 0);
+//! ```
+
+use crate::any_calendar::AnyCalendarKind;
+use crate::calendar_arithmetic::{ArithmeticDate, CalendarArithmetic};
+use crate::iso::Iso;
+use crate::julian::Julian;
+use crate::{types, Calendar, CalendarError, Date, DateDuration, DateDurationUnit, DateTime};
+use tinystr::tinystr;
+
+/// The fixed date of the start of the Persian calendar, March 19, 622 CE (Julian)
+const FIXED_PERSIAN_EPOCH: i32 = Julian::fixed_from_julian_integers(622, 3, 19);
+
+/// The [Persian Calendar](https://en.wikipedia.org/wiki/Solar_Hijri_calendar)
+///
+/// The Persian (Solar Hijri) calendar is the official calendar of Iran and Afghanistan. It is a
+/// solar calendar with twelve months: the first six have 31 days, the next five have 30 days,
+/// and the last has 29 days, or 30 in leap years.
+///
+/// The year formally starts at the vernal equinox as observed in Tehran. This implementation
+/// instead uses the 33-year arithmetic cycle, with 8 leap years per cycle, which agrees with
+/// the astronomical calendar for the years in current use.
+///
+/// This type can be used with [`Date`] or [`DateTime`] to represent dates in this calendar.
+///
+/// # Era codes
+///
+/// This calendar supports a single era code, `"ah"`, with 1 A.P. being 622 CE.
+/// Dates before this era use negative years.
+///
+/// # Month codes
+///
+/// This calendar is a solar calendar with no leap months. It uses month codes
+/// `"M01"` to `"M12"`.
+#[derive(Copy, Clone, Debug, Hash, Default, Eq, PartialEq)]
+#[allow(clippy::exhaustive_structs)] // this type is stable
+pub struct Persian;
+
+/// The inner date type used for representing [`Date`]s of [`Persian`]. See [`Date`] and [`Persian`] for more details.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct PersianDateInner(ArithmeticDate<Persian>);
+
+impl CalendarArithmetic for Persian {
+    fn month_days(year: i32, month: u8) -> u8 {
+        match month {
+            1..=6 => 31,
+            7..=11 => 30,
+            12 if Self::is_leap_year(year) => 30,
+            12 => 29,
+            _ => 0,
+        }
+    }
+
+    fn months_for_every_year(_year: i32) -> u8 {
+        12
+    }
+
+    fn is_leap_year(year: i32) -> bool {
+        (25 * year + 11).rem_euclid(33) < 8
+    }
+
+    fn days_in_provided_year(year: i32) -> u32 {
+        if Self::is_leap_year(year) {
+            366
+        } else {
+            365
+        }
+    }
+}
+
+impl Calendar for Persian {
+    type DateInner = PersianDateInner;
+    fn date_from_codes(
+        &self,
+        era: types::Era,
+        year: i32,
+        month_code: types::MonthCode,
+        day: u8,
+    ) -> Result<Self::DateInner, CalendarError> {
+        if era.0 != tinystr!(16, "ah") {
+            return Err(CalendarError::UnknownEra(era.0, self.debug_name()));
+        }
+
+        ArithmeticDate::new_from_solar(self, year, month_code, day).map(PersianDateInner)
+    }
+
+    fn date_from_iso(&self, iso: Date<Iso>) -> PersianDateInner {
+        let fixed_iso = Iso::fixed_from_iso(*iso.inner());
+        Self::persian_from_fixed(fixed_iso)
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        let fixed_persian = Self::fixed_from_persian(date.0);
+        Iso::iso_from_fixed(fixed_persian)
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        date.0.months_in_year()
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u32 {
+        date.0.days_in_year()
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        date.0.days_in_month()
+    }
+
+    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
+        date.0.offset_date(offset);
+    }
+
+    #[allow(clippy::field_reassign_with_default)]
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        _calendar2: &Self,
+        _largest_unit: DateDurationUnit,
+        _smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        date1.0.until(date2.0, _largest_unit, _smallest_unit)
+    }
+
+    fn year(&self, date: &Self::DateInner) -> types::FormattableYear {
+        Self::year_as_persian(date.0.year)
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::FormattableMonth {
+        date.0.solar_month()
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        date.0.day_of_month()
+    }
+
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        let prev_year = date.0.year - 1;
+        let next_year = date.0.year + 1;
+        types::DayOfYearInfo {
+            day_of_year: date.0.day_of_year(),
+            days_in_year: date.0.days_in_year(),
+            prev_year: Self::year_as_persian(prev_year),
+            days_in_prev_year: Self::days_in_provided_year(prev_year),
+            next_year: Self::year_as_persian(next_year),
+        }
+    }
+
+    fn debug_name(&self) -> &'static str {
+        "Persian"
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        Some(AnyCalendarKind::Persian)
+    }
+}
+
+impl Persian {
+    /// Construct a new Persian Calendar
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// The fixed date of Nowruz (1 Farvardin) of the given year
+    fn fixed_nowruz(year: i32) -> i32 {
+        FIXED_PERSIAN_EPOCH - 1 + 365 * (year - 1) + (8 * year + 21).div_euclid(33)
+    }
+
+    fn fixed_from_persian(date: ArithmeticDate<Persian>) -> i32 {
+        let month = date.month as i32;
+        let days_before_month = if month <= 7 {
+            31 * (month - 1)
+        } else {
+            30 * (month - 1) + 6
+        };
+        Self::fixed_nowruz(date.year) + days_before_month + date.day as i32 - 1
+    }
+
+    fn persian_from_fixed(date: i32) -> PersianDateInner {
+        let days_since_epoch = date - FIXED_PERSIAN_EPOCH + 1;
+        // Computed in i64 as 33 * days_since_epoch can overflow for far-away dates
+        let year = 1 + (33 * days_since_epoch as i64 + 3).div_euclid(12053) as i32;
+        // 1-indexed, at most 366
+        let day_of_year = (date - Self::fixed_nowruz(year) + 1) as u32;
+        PersianDateInner(ArithmeticDate::date_from_year_day(year, day_of_year))
+    }
+
+    fn year_as_persian(year: i32) -> types::FormattableYear {
+        types::FormattableYear {
+            era: types::Era(tinystr!(16, "ah")),
+            number: year,
+            cyclic: None,
+            related_iso: None,
+        }
+    }
+}
+
+impl Date<Persian> {
+    /// Construct new Persian Date.
+    ///
+    /// ```rust
+    /// use icu::calendar::Date;
+    ///
+    /// let date_persian = Date::try_new_persian_date(1348, 10, 11)
+    ///     .expect("Failed to initialize Persian Date instance.");
+    ///
+    /// assert_eq!(date_persian.year().number, 1348);
+    /// assert_eq!(date_persian.month().ordinal, 10);
+    /// assert_eq!(date_persian.day_of_month().0, 11);
+    /// ```
+    pub fn try_new_persian_date(
+        year: i32,
+        month: u8,
+        day: u8,
+    ) -> Result<Date<Persian>, CalendarError> {
+        if !(1..=12).contains(&month) || day < 1 || day > Persian::month_days(year, month) {
+            return Err(CalendarError::OutOfRange);
+        }
+        Ok(Date::from_raw(
+            PersianDateInner(ArithmeticDate::new(year, month, day)),
+            Persian,
+        ))
+    }
+}
+
+impl DateTime<Persian> {
+    /// Construct a new Persian datetime from integers.
+    ///
+    /// ```rust
+    /// use icu::calendar::DateTime;
+    ///
+    /// let datetime_persian =
+    ///     DateTime::try_new_persian_datetime(474, 10, 11, 13, 1, 0)
+    ///         .expect("Failed to initialize Persian DateTime instance.");
+    ///
+    /// assert_eq!(datetime_persian.date.year().number, 474);
+    /// assert_eq!(datetime_persian.date.month().ordinal, 10);
+    /// assert_eq!(datetime_persian.date.day_of_month().0, 11);
+    /// assert_eq!(datetime_persian.time.hour.number(), 13);
+    /// assert_eq!(datetime_persian.time.minute.number(), 1);
+    /// assert_eq!(datetime_persian.time.second.number(), 0);
+    /// ```
+    pub fn try_new_persian_datetime(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<DateTime<Persian>, CalendarError> {
+        Ok(DateTime {
+            date: Date::try_new_persian_date(year, month, day)?,
+            time: types::Time::try_new(hour, minute, second, 0)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // (ISO date, Persian date) pairs for the sample dates of _Calendrical Calculations_.
+    // Unlike the book, years are counted with a year zero.
+    #[allow(clippy::type_complexity)]
+    const CASES: &[((i32, u8, u8), (i32, u8, u8))] = &[
+        ((70, 9, 24), (-551, 7, 2)),
+        ((576, 5, 20), (-45, 2, 31)),
+        ((1096, 5, 24), (475, 3, 4)),
+        ((1288, 4, 2), (667, 1, 14)),
+        ((1391, 6, 12), (770, 3, 22)),
+        ((1553, 9, 19), (932, 6, 28)),
+        ((1680, 6, 30), (1059, 4, 10)),
+        ((1768, 6, 19), (1147, 3, 30)),
+        ((1903, 4, 19), (1282, 1, 29)),
+        ((1941, 9, 29), (1320, 7, 7)),
+        ((1943, 4, 19), (1322, 1, 29)),
+        ((1992, 3, 17), (1370, 12, 27)),
+        ((2038, 11, 10), (1417, 8, 19)),
+        ((2094, 7, 18), (1473, 4, 28)),
+    ];
+
+    #[test]
+    fn test_conversions() {
+        for &((iso_y, iso_m, iso_d), (y, m, d)) in CASES {
+            let iso = Date::try_new_iso_date(iso_y, iso_m, iso_d).unwrap();
+            let persian = Date::try_new_persian_date(y, m, d).unwrap();
+            assert_eq!(persian.to_iso(), iso, "{y}-{m}-{d}: ISO date did not match");
+            assert_eq!(
+                iso.to_calendar(Persian),
+                persian,
+                "{iso:?}: Persian date did not match"
+            );
+        }
+    }
+
+    #[test]
+    fn test_nowruz() {
+        // Nowruz (1 Farvardin) falls on the March equinox
+        for (year, iso_day) in [(1400, 21), (1401, 21), (1402, 21), (1403, 20), (1404, 21)] {
+            let persian = Date::try_new_persian_date(year, 1, 1).unwrap();
+            assert_eq!(
+                persian.to_iso(),
+                Date::try_new_iso_date(year + 621, 3, iso_day).unwrap(),
+                "Nowruz {year}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_leap_years() {
+        let leap_years: Vec<i32> = (1390..1410).filter(|&y| Persian::is_leap_year(y)).collect();
+        assert_eq!(leap_years, [1391, 1395, 1399, 1403, 1408]);
+        assert_eq!(
+            Date::try_new_persian_date(1403, 12, 30).map(|d| d.days_in_year()),
+            Ok(366)
+        );
+        assert_eq!(
+            Date::try_new_persian_date(1402, 12, 30),
+            Err(CalendarError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_week_of_year() {
+        use crate::types::IsoWeekday;
+        use crate::week::{RelativeUnit, WeekCalculator, WeekOf};
+
+        // Weeks in Iran start on Saturday
+        let calculator = WeekCalculator {
+            first_weekday: IsoWeekday::Saturday,
+            min_week_days: 1,
+        };
+
+        let week_of = |y, m, d| {
+            Date::try_new_persian_date(y, m, d)
+                .unwrap()
+                .week_of_year(&calculator)
+                .unwrap()
+        };
+        // 1 Farvardin 1403 is a Wednesday
+        assert_eq!(
+            week_of(1403, 1, 1),
+            WeekOf {
+                week: 1,
+                unit: RelativeUnit::Current
+            }
+        );
+        assert_eq!(
+            week_of(1403, 1, 4),
+            WeekOf {
+                week: 2,
+                unit: RelativeUnit::Current
+            }
+        );
+        assert_eq!(
+            week_of(1402, 12, 29),
+            WeekOf {
+                week: 53,
+                unit: RelativeUnit::Current
+            }
+        );
+    }
+
+    #[test]
+    fn test_offset_date() {
+        let mut date = Date::try_new_persian_date(1402, 6, 31).unwrap();
+        date.add(DateDuration::new(0, 1, 0, 0));
+        assert_eq!(date, Date::try_new_persian_date(1402, 8, 1).unwrap());
+        date.add(DateDuration::new(1, 4, 0, 29));
+        assert_eq!(date, Date::try_new_persian_date(1403, 12, 30).unwrap());
+    }
+}
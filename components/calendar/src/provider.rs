@@ -56,6 +56,47 @@ pub struct JapaneseErasV1<'data> {
     pub dates_to_eras: ZeroVec<'data, (EraStartDate, TinyStr16)>,
 }
 
+/// Precomputed information about the years of the
+/// [`IslamicUmmAlQura`](crate::islamic::IslamicUmmAlQura) calendar
+///
+/// Years outside of the cached range are computed astronomically.
+#[icu_provider::data_struct(marker(
+    IslamicUmmAlQuraCacheV1Marker,
+    "calendar/islamicumalquracache@1"
+))]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_calendar::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct IslamicUmmAlQuraCacheV1<'data> {
+    /// The extended year of the first entry in `year_info`
+    pub first_extended_year: i32,
+    /// The packed information for each year, starting with `first_extended_year`
+    ///
+    /// Bit `n` (for `n` from 0 to 11) is set if month `n + 1` has 30 days rather than 29. The
+    /// upper four bits hold the difference in days, as a signed integer, between the start of
+    /// the year and the start of the same year in the
+    /// [`IslamicCivil`](crate::islamic::IslamicCivil) calendar.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub year_info: ZeroVec<'data, u16>,
+}
+
+#[cfg(feature = "datagen")]
+impl IslamicUmmAlQuraCacheV1<'static> {
+    /// Computes the cache for the given range of extended years
+    pub fn compute_for(extended_years: core::ops::Range<i32>) -> Self {
+        Self {
+            first_extended_year: extended_years.start,
+            year_info: extended_years
+                .map(crate::islamic::pack_umm_al_qura_year)
+                .collect(),
+        }
+    }
+}
+
 impl FromStr for EraStartDate {
     type Err = ();
     fn from_str(mut s: &str) -> Result<Self, ()> {
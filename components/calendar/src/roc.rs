@@ -0,0 +1,337 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains types and implementations for the Republic of China calendar.
+//!
+//! ```rust
+//! use icu::calendar::{roc::Roc, Date, DateTime};
+//!
+//! // `Date` type
+//! let date_iso = Date::try_new_iso_date(1970, 1, 2)
+//!     .expect("Failed to initialize ISO Date instance.");
+//! let date_roc = Date::new_from_iso(date_iso, Roc);
+//!
+//! // `DateTime` type
+//! let datetime_iso = DateTime::try_new_iso_datetime(1970, 1, 2, 13, 1, 0)
+//!     .expect("Failed to initialize ISO DateTime instance.");
+//! let datetime_roc = DateTime::new_from_iso(datetime_iso, Roc);
+//!
+//! // `Date` checks
+//! assert_eq!(date_roc.year().number, 59);
+//! assert_eq!(date_roc.month().ordinal, 1);
+//! assert_eq!(date_roc.day_of_month().0, 2);
+//!
+//! // `DateTime` checks
+//! assert_eq!(datetime_roc.date.year().number, 59);
+//! assert_eq!(datetime_roc.date.month().ordinal, 1);
+//! assert_eq!(datetime_roc.date.day_of_month().0, 2);
+//! assert_eq!(datetime_roc.time.hour.number(), 13);
+//! assert_eq!(datetime_roc.time.minute.number(), 1);
+//! assert_eq!(datetime_roc.time.second.number(), 0);
+//! ```
+
+use crate::any_calendar::AnyCalendarKind;
+use crate::calendar_arithmetic::ArithmeticDate;
+use crate::iso::{Iso, IsoDateInner};
+use crate::{types, Calendar, CalendarError, Date, DateDuration, DateDurationUnit, DateTime};
+use tinystr::tinystr;
+
+/// The number of years the ROC era is behind C.E. by
+///
+/// (1 ROC = 1912 CE)
+const ROC_ERA_OFFSET: i32 = 1911;
+
+/// The Republic of China Calendar
+///
+/// The [Republic of China calendar] (also known as the Minguo calendar) is a solar calendar
+/// used in Taiwan, with twelve months. The months and days are identical to that of the
+/// Gregorian calendar, however the years are counted from the founding of the Republic of
+/// China in 1912.
+///
+/// This type can be used with [`Date`] or [`DateTime`] to represent dates in this calendar.
+///
+/// [Republic of China calendar]: https://en.wikipedia.org/wiki/Republic_of_China_calendar
+///
+/// # Era codes
+///
+/// This calendar supports two era codes: `"roc"`, with 1 ROC being 1912 CE, and
+/// `"roc-inverse"`, counting backwards from 1 Before ROC being 1911 CE.
+#[derive(Copy, Clone, Debug, Default)]
+#[allow(clippy::exhaustive_structs)] // this type is stable
+pub struct Roc;
+
+/// The inner date type used for representing [`Date`]s of [`Roc`]. See [`Date`] and [`Roc`] for more details.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct RocDateInner(IsoDateInner);
+
+impl Calendar for Roc {
+    type DateInner = RocDateInner;
+
+    fn date_from_codes(
+        &self,
+        era: types::Era,
+        year: i32,
+        month_code: types::MonthCode,
+        day: u8,
+    ) -> Result<Self::DateInner, CalendarError> {
+        let year = if era.0 == tinystr!(16, "roc") {
+            if year <= 0 {
+                return Err(CalendarError::OutOfRange);
+            }
+            year + ROC_ERA_OFFSET
+        } else if era.0 == tinystr!(16, "roc-inverse") {
+            if year <= 0 {
+                return Err(CalendarError::OutOfRange);
+            }
+            1 - year + ROC_ERA_OFFSET
+        } else {
+            return Err(CalendarError::UnknownEra(era.0, self.debug_name()));
+        };
+
+        ArithmeticDate::new_from_solar(self, year, month_code, day)
+            .map(IsoDateInner)
+            .map(RocDateInner)
+    }
+
+    fn date_from_iso(&self, iso: Date<Iso>) -> Self::DateInner {
+        RocDateInner(*iso.inner())
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        Date::from_raw(date.0, Iso)
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        Iso.months_in_year(&date.0)
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u32 {
+        Iso.days_in_year(&date.0)
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        Iso.days_in_month(&date.0)
+    }
+
+    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
+        Iso.offset_date(&mut date.0, offset.cast_unit())
+    }
+
+    #[allow(clippy::field_reassign_with_default)] // it's more clear this way
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        _calendar2: &Self,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        Iso.until(&date1.0, &date2.0, &Iso, largest_unit, smallest_unit)
+            .cast_unit()
+    }
+
+    /// The calendar-specific year represented by `date`
+    fn year(&self, date: &Self::DateInner) -> types::FormattableYear {
+        iso_year_as_roc(date.0 .0.year)
+    }
+
+    /// The calendar-specific month represented by `date`
+    fn month(&self, date: &Self::DateInner) -> types::FormattableMonth {
+        Iso.month(&date.0)
+    }
+
+    /// The calendar-specific day-of-month represented by `date`
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        Iso.day_of_month(&date.0)
+    }
+
+    /// Information of the day of the year
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        let prev_year = date.0 .0.year - 1;
+        let next_year = date.0 .0.year + 1;
+        types::DayOfYearInfo {
+            day_of_year: Iso::day_of_year(date.0),
+            days_in_year: Iso::days_in_year_direct(date.0 .0.year),
+            prev_year: iso_year_as_roc(prev_year),
+            days_in_prev_year: Iso::days_in_year_direct(prev_year),
+            next_year: iso_year_as_roc(next_year),
+        }
+    }
+
+    fn debug_name(&self) -> &'static str {
+        "ROC"
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        Some(AnyCalendarKind::Roc)
+    }
+}
+
+impl Date<Roc> {
+    /// Construct a new Republic of China Date.
+    ///
+    /// Years are specified in the "roc" era. This function accepts an extended year in that
+    /// era, so dates before the founding of the Republic of China in 1912 can be constructed
+    /// with a year of zero or below: 0 is 1 Before ROC, -1 is 2 Before ROC, and so on.
+    ///
+    /// ```rust
+    /// use icu::calendar::Date;
+    /// use tinystr::tinystr;
+    ///
+    /// let date_roc = Date::try_new_roc_date(1, 2, 3)
+    ///     .expect("Failed to initialize ROC Date instance.");
+    ///
+    /// assert_eq!(date_roc.year().era.0, tinystr!(16, "roc"));
+    /// assert_eq!(date_roc.year().number, 1);
+    /// assert_eq!(date_roc.month().ordinal, 2);
+    /// assert_eq!(date_roc.day_of_month().0, 3);
+    ///
+    /// let date_before_roc = Date::try_new_roc_date(0, 2, 3)
+    ///     .expect("Failed to initialize ROC Date instance.");
+    ///
+    /// assert_eq!(date_before_roc.year().era.0, tinystr!(16, "roc-inverse"));
+    /// assert_eq!(date_before_roc.year().number, 1);
+    /// ```
+    pub fn try_new_roc_date(year: i32, month: u8, day: u8) -> Result<Date<Roc>, CalendarError> {
+        Date::try_new_iso_date(year + ROC_ERA_OFFSET, month, day)
+            .map(|d| Date::new_from_iso(d, Roc))
+    }
+}
+
+impl DateTime<Roc> {
+    /// Construct a new Republic of China datetime from integers.
+    ///
+    /// Years are specified as extended years in the "roc" era, see [`Date::try_new_roc_date`].
+    ///
+    /// ```rust
+    /// use icu::calendar::DateTime;
+    ///
+    /// let datetime_roc = DateTime::try_new_roc_datetime(59, 1, 2, 13, 1, 0)
+    ///     .expect("Failed to initialize ROC DateTime instance.");
+    ///
+    /// assert_eq!(datetime_roc.date.year().number, 59);
+    /// assert_eq!(datetime_roc.date.month().ordinal, 1);
+    /// assert_eq!(datetime_roc.date.day_of_month().0, 2);
+    /// assert_eq!(datetime_roc.time.hour.number(), 13);
+    /// assert_eq!(datetime_roc.time.minute.number(), 1);
+    /// assert_eq!(datetime_roc.time.second.number(), 0);
+    /// ```
+    pub fn try_new_roc_datetime(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<DateTime<Roc>, CalendarError> {
+        Ok(DateTime {
+            date: Date::try_new_roc_date(year, month, day)?,
+            time: types::Time::try_new(hour, minute, second, 0)?,
+        })
+    }
+}
+
+fn iso_year_as_roc(year: i32) -> types::FormattableYear {
+    if year > ROC_ERA_OFFSET {
+        types::FormattableYear {
+            era: types::Era(tinystr!(16, "roc")),
+            number: year - ROC_ERA_OFFSET,
+            cyclic: None,
+            related_iso: None,
+        }
+    } else {
+        types::FormattableYear {
+            era: types::Era(tinystr!(16, "roc-inverse")),
+            number: ROC_ERA_OFFSET + 1 - year,
+            cyclic: None,
+            related_iso: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn era(s: &str) -> types::Era {
+        types::Era(s.parse().unwrap())
+    }
+
+    fn code(s: &str) -> types::MonthCode {
+        types::MonthCode(s.parse().unwrap())
+    }
+
+    #[test]
+    fn test_era_boundary() {
+        // (iso, (era, year))
+        let cases = [
+            ((1912, 1, 1), ("roc", 1)),
+            ((1911, 12, 31), ("roc-inverse", 1)),
+            ((1911, 1, 1), ("roc-inverse", 1)),
+            ((1910, 12, 31), ("roc-inverse", 2)),
+            ((1, 1, 1), ("roc-inverse", 1911)),
+            ((0, 1, 1), ("roc-inverse", 1912)),
+            ((2023, 6, 1), ("roc", 112)),
+        ];
+        for ((iso_y, iso_m, iso_d), (e, y)) in cases {
+            let iso = Date::try_new_iso_date(iso_y, iso_m, iso_d).unwrap();
+            let roc = iso.to_calendar(Roc);
+            assert_eq!(roc.year().era, era(e), "{iso_y}-{iso_m}-{iso_d}: era");
+            assert_eq!(roc.year().number, y, "{iso_y}-{iso_m}-{iso_d}: year");
+            assert_eq!(roc.month().ordinal, iso_m as u32);
+            assert_eq!(roc.day_of_month().0, iso_d as u32);
+
+            let from_codes = Date::try_new_from_codes(era(e), y, roc.month().code, iso_d, Roc)
+                .expect("ROC date should construct successfully");
+            assert_eq!(from_codes.to_iso(), iso, "{e} {y}: ISO date did not match");
+        }
+    }
+
+    #[test]
+    fn test_extended_year() {
+        // The extended year counts down through zero before the ROC era
+        let minguo_1 = Date::try_new_roc_date(1, 1, 1).unwrap();
+        assert_eq!(
+            minguo_1.to_iso(),
+            Date::try_new_iso_date(1912, 1, 1).unwrap()
+        );
+        let before_roc_1 = Date::try_new_roc_date(0, 1, 1).unwrap();
+        assert_eq!(
+            before_roc_1.to_iso(),
+            Date::try_new_iso_date(1911, 1, 1).unwrap()
+        );
+        let before_roc_2 = Date::try_new_roc_date(-1, 1, 1).unwrap();
+        assert_eq!(before_roc_2.year().era, era("roc-inverse"));
+        assert_eq!(before_roc_2.year().number, 2);
+    }
+
+    #[test]
+    fn test_invalid_years() {
+        // Neither era has a year zero
+        for e in ["roc", "roc-inverse"] {
+            assert_eq!(
+                Date::try_new_from_codes(era(e), 0, code("M01"), 1, Roc),
+                Err(CalendarError::OutOfRange)
+            );
+        }
+        assert!(matches!(
+            Date::try_new_from_codes(era("ce"), 1, code("M01"), 1, Roc),
+            Err(CalendarError::UnknownEra(..))
+        ));
+    }
+
+    #[test]
+    fn test_offset_across_era_boundary() {
+        let mut date = Date::try_new_roc_date(1, 1, 1).unwrap();
+        date.add(DateDuration::new(0, 0, 0, -1));
+        assert_eq!(date.year().era, era("roc-inverse"));
+        assert_eq!(date.year().number, 1);
+        assert_eq!(date.month().ordinal, 12);
+        assert_eq!(date.day_of_month().0, 31);
+
+        date.add(DateDuration::new(1, 0, 0, 1));
+        assert_eq!(date.year().era, era("roc"));
+        assert_eq!(date.year().number, 2);
+    }
+}
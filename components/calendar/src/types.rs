@@ -8,6 +8,7 @@ use crate::error::CalendarError;
 use core::convert::TryFrom;
 use core::convert::TryInto;
 use core::fmt;
+use core::num::NonZeroU8;
 use core::str::FromStr;
 use tinystr::{TinyStr16, TinyStr4};
 use zerovec::maps::ZeroMapKV;
@@ -37,7 +38,7 @@ impl FromStr for Era {
 /// Representation of a formattable year.
 ///
 /// More fields may be added in the future, for things like
-/// the cyclic or extended year
+/// the extended year
 #[derive(Copy, Clone, Debug, PartialEq)]
 #[non_exhaustive]
 pub struct FormattableYear {
@@ -47,6 +48,11 @@ pub struct FormattableYear {
     /// The year number in the current era (usually 1-based).
     pub number: i32,
 
+    /// The year in the current cycle for cyclic calendars (1-indexed)
+    ///
+    /// Can be None if the calendar does not use cycles of years
+    pub cyclic: Option<NonZeroU8>,
+
     /// The related ISO year. This is normally the ISO (proleptic Gregorian) year having the greatest
     /// overlap with the calendar year. It is used in certain date formatting patterns.
     ///
@@ -64,6 +70,7 @@ impl FormattableYear {
         Self {
             era,
             number,
+            cyclic: None,
             related_iso: None,
         }
     }
@@ -0,0 +1,1243 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// Various collation-related algorithms and constants in this file are
+// adapted from ICU4C and, therefore, are subject to the ICU license as
+// described in LICENSE.
+
+//! This module holds the `TailoringBuilder` struct, which turns the resets
+//! and relations of parsed collation rules into a `CollationDataV1` that
+//! the `CollationElements` iterator can use as a tailoring on top of the
+//! root collation.
+//!
+//! As in ICU4C's `CollationBuilder`, the tailored weights are not assigned
+//! while the rules are being added. Instead, each reset position is found
+//! or inserted as a node in a list of nodes per root primary, each relation
+//! inserts a tailored node into such a list, and the weights are assigned
+//! by walking the lists once all the rules have been added. Unlike ICU4C,
+//! this builder does not compress the weights or reuse gaps between root
+//! weights beyond fixed-size ranges, so very long chains of relations
+//! after the same root weight are rejected as unsupported.
+
+// The node ids and list positions used for indexing are created by this module,
+// and the tries are only indexed within the ranges that are being written.
+#![allow(clippy::indexing_slicing)]
+
+use crate::elements::{
+    trie_value_has_ccc, CollationElements, Tag, JAMO_COUNT, NO_CE_PRIMARY,
+    OPTIMIZED_DIACRITICS_MAX_COUNT,
+};
+use crate::error::CollatorError;
+use crate::options::CollatorOptionsBitField;
+use crate::provider::CollationDataV1;
+use crate::Strength;
+use alloc::collections::btree_map::Entry;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use icu_collections::codepointtrie::{CodePointTrie, CodePointTrieHeader, TrieType};
+use icu_normalizer::provider::{DecompositionDataV1, DecompositionTablesV1};
+use icu_normalizer::Decomposition;
+use zerovec::ule::AsULE;
+use zerovec::{ZeroSlice, ZeroVec};
+
+/// Trie value for characters that are not tailored.
+const FALLBACK_CE32: u32 = 0xC0;
+const LONG_PRIMARY_CE32_LOW_BYTE: u32 = 0xC1;
+const LONG_SECONDARY_CE32_LOW_BYTE: u32 = 0xC2;
+const EXPANSION_CE32_LOW_BYTE: u32 = 0xC6;
+const CONTRACTION_CE32_LOW_BYTE: u32 = 0xC9;
+const DIGIT_CE32_LOW_BYTE: u32 = 0xCA;
+
+/// See the constants of the same name in `elements.rs`.
+const CONTRACT_NEXT_CCC: u32 = 0x200;
+const CONTRACT_TRAILING_CCC: u32 = 0x400;
+const CONTRACT_HAS_STARTER: u32 = 0x800;
+
+/// The maximum length of an expansion.
+const MAX_EXPANSION_LENGTH: usize = 31;
+
+const COMMON_WEIGHT16: u32 = 0x0500;
+const MERGE_SEPARATOR_PRIMARY: u32 = 0x02000000;
+const UNASSIGNED_IMPLICIT_BYTE: u32 = 0xFE;
+
+const COMBINING_DIACRITICS_BASE: u32 = 0x0300;
+const HANGUL_JAMO_BASE: u32 = 0x1100;
+
+/// The collation elements of the contraction suffixes of a starter.
+type Suffixes = BTreeMap<Vec<char>, Vec<u64>>;
+
+/// A string of UTF-16 code units and its value in a `Char16Trie`.
+type TrieEntry = (Vec<u16>, u32);
+
+/// A collation element of a string added by the rules.
+#[derive(Copy, Clone, Debug)]
+enum Ce {
+    /// A collation element of the root collation.
+    Root(u64),
+    /// A reference to a node whose weights are only known once all the rules have been
+    /// added, the level at which the collation element differs from the preceding ones
+    /// and the case bits to combine with the tertiary weight.
+    Node {
+        node: usize,
+        strength: Strength,
+        case: u64,
+    },
+}
+
+impl Ce {
+    fn strength(&self) -> Strength {
+        match *self {
+            Ce::Root(ce) => root_ce_strength(ce),
+            Ce::Node { strength, .. } => strength,
+        }
+    }
+}
+
+/// Returns the strongest level at which a root collation element has a non-zero weight,
+/// or `Strength::Identical` for a completely ignorable collation element.
+fn root_ce_strength(ce: u64) -> Strength {
+    if ce >> 32 != 0 {
+        Strength::Primary
+    } else if ce & 0xFFFF0000 != 0 {
+        Strength::Secondary
+    } else if ce != 0 {
+        Strength::Tertiary
+    } else {
+        Strength::Identical
+    }
+}
+
+/// A position in the sort order, either anchored at a root (or a virtual) weight, or
+/// tailored at `strength` after the preceding node of the same list.
+#[derive(Debug)]
+struct Node {
+    /// The level at which this node differs from the preceding node.
+    strength: Strength,
+    /// For root and virtual nodes, the weight at the level of `strength` and the largest
+    /// weight that tailored nodes following this node may use.
+    root: Option<(u32, u32)>,
+    /// The key of the list that this node belongs to.
+    list: u32,
+    /// The offset of the rule that created this node for error reporting.
+    offset: usize,
+}
+
+/// The result of building a tailoring from rules.
+pub(crate) struct Tailoring {
+    pub data: CollationDataV1<'static>,
+    /// A shortened diacritic table if the rules tailor characters in the
+    /// optimized diacritic range.
+    pub diacritics: Option<ZeroVec<'static, u16>>,
+    pub options: CollatorOptionsBitField,
+}
+
+/// Collects the resets and relations of collation rules.
+pub(crate) struct TailoringBuilder<'data> {
+    root: &'data CollationDataV1<'data>,
+    jamo: &'data [<u32 as AsULE>::ULE; JAMO_COUNT],
+    diacritics: &'data ZeroSlice<u16>,
+    decompositions: &'data DecompositionDataV1<'data>,
+    tables: &'data DecompositionTablesV1<'data>,
+    /// The options set by the rules.
+    pub options: CollatorOptionsBitField,
+    nodes: Vec<Node>,
+    /// The node lists keyed by the primary weight of the head node.
+    lists: BTreeMap<u32, Vec<usize>>,
+    /// The tailored strings (in NFD) and their collation elements.
+    items: BTreeMap<Vec<char>, Vec<Ce>>,
+    /// The collation elements of the current position.
+    ces: Vec<Ce>,
+}
+
+impl<'data> TailoringBuilder<'data> {
+    pub fn new(
+        root: &'data CollationDataV1<'data>,
+        jamo: &'data [<u32 as AsULE>::ULE; JAMO_COUNT],
+        diacritics: &'data ZeroSlice<u16>,
+        decompositions: &'data DecompositionDataV1<'data>,
+        tables: &'data DecompositionTablesV1<'data>,
+        options: CollatorOptionsBitField,
+    ) -> Self {
+        TailoringBuilder {
+            root,
+            jamo,
+            diacritics,
+            decompositions,
+            tables,
+            options,
+            nodes: Vec::new(),
+            lists: BTreeMap::new(),
+            items: BTreeMap::new(),
+            ces: Vec::new(),
+        }
+    }
+
+    /// Whether the character is its own NFD and has the canonical combining class zero,
+    /// as required for characters listed in starred relations.
+    pub fn is_inert(&self, c: char) -> bool {
+        let nfd = self.nfd(c.encode_utf8(&mut [0u8; 4]));
+        nfd.len() == 1 && nfd[0] == c && !self.has_ccc(c)
+    }
+
+    /// Sets the current position to `string`, or to the position before it at the level
+    /// given by `before`.
+    pub fn add_reset(
+        &mut self,
+        offset: usize,
+        before: Option<Strength>,
+        string: &str,
+    ) -> Result<(), CollatorError> {
+        let nfd = self.nfd(string);
+        self.ces = self.ces_for(&nfd);
+        if self.ces.len() > MAX_EXPANSION_LENGTH {
+            return Err(CollatorError::InvalidRules(offset));
+        }
+        if let Some(strength) = before {
+            let node = self.find_or_insert_node_for_ces(strength, offset)?;
+            let node = self.find_node_before(node, strength, offset)?;
+            if let Some(last) = self.ces.last_mut() {
+                *last = Ce::Node {
+                    node,
+                    strength,
+                    case: 0,
+                };
+            }
+        }
+        Ok(())
+    }
+
+    /// Sorts `string` after the current position with a difference at the level of
+    /// `strength`, or makes it equal to the current position for `Strength::Identical`.
+    /// The collation elements of `extension` are appended to those of `string`.
+    pub fn add_relation(
+        &mut self,
+        offset: usize,
+        strength: Strength,
+        string: &str,
+        extension: &str,
+    ) -> Result<(), CollatorError> {
+        let nfd = self.nfd(string);
+        self.check_tailored_string(&nfd, offset)?;
+        if strength != Strength::Identical {
+            let anchor = self.find_or_insert_node_for_ces(strength, offset)?;
+            let last_strength = match self.ces.last() {
+                Some(ce) => ce.strength(),
+                None => return Err(CollatorError::InvalidRules(offset)),
+            };
+            if strength == Strength::Primary && last_strength != Strength::Primary {
+                // Tailoring a primary difference after a primary-ignorable
+                // position is not supported.
+                return Err(CollatorError::UnsupportedRules(offset));
+            }
+            let node = self.insert_tailored_node_after(anchor, strength, offset);
+            if let Some(last) = self.ces.last_mut() {
+                *last = Ce::Node {
+                    node,
+                    strength: core::cmp::min(strength, last_strength),
+                    case: 0,
+                };
+            }
+        }
+        self.set_case_bits(&nfd);
+        let mut ces = self.ces.clone();
+        if !extension.is_empty() {
+            let extension = self.nfd(extension);
+            ces.extend(self.ces_for(&extension));
+            if ces.len() > MAX_EXPANSION_LENGTH {
+                return Err(CollatorError::InvalidRules(offset));
+            }
+        }
+        self.items.insert(nfd, ces);
+        Ok(())
+    }
+
+    /// Assigns the weights and encodes the tailored strings.
+    pub fn build(self) -> Result<Tailoring, CollatorError> {
+        let weights = self.allocate_weights()?;
+        let resolve = |ces: &[Ce]| -> Vec<u64> {
+            ces.iter()
+                .map(|ce| match *ce {
+                    Ce::Root(ce) => ce,
+                    Ce::Node { node, case, .. } => weights[node] | case,
+                })
+                .collect()
+        };
+
+        // The tailored single characters and contraction suffixes by starter.
+        let mut starters: BTreeMap<char, (Option<Vec<u64>>, Suffixes)> = BTreeMap::new();
+        for (string, ces) in self.items.iter() {
+            let (&first, suffix) = match string.split_first() {
+                Some(split) => split,
+                None => continue,
+            };
+            let entry = starters.entry(first).or_default();
+            if suffix.is_empty() {
+                entry.0 = Some(resolve(ces));
+            } else {
+                entry.1.insert(suffix.to_vec(), resolve(ces));
+            }
+        }
+
+        let mut encoder = Encoder::default();
+        let mut trie_values: BTreeMap<u32, u32> = BTreeMap::new();
+        for (c, (single, mut suffixes)) in starters {
+            let root_ce32 = self.root.ce32_for_char(c);
+            let root_tag = if root_ce32.to_ce_self_contained().is_some() {
+                None
+            } else {
+                Some(root_ce32.tag())
+            };
+            if root_tag == Some(Tag::Contraction) {
+                // The tailoring replaces the root mapping of the starter, so it has to
+                // carry over the root contractions that the rules don't override.
+                let (_, trie) = self.root.get_default_and_trie_impl(root_ce32.index());
+                for units in enumerate_char16_trie(trie) {
+                    let suffix: Vec<char> = char::decode_utf16(units.iter().copied())
+                        .map(|r| r.unwrap_or(char::REPLACEMENT_CHARACTER))
+                        .collect();
+                    if let Entry::Vacant(entry) = suffixes.entry(suffix) {
+                        let mut string = Vec::with_capacity(entry.key().len() + 1);
+                        string.push(c);
+                        string.extend_from_slice(entry.key());
+                        entry.insert(self.root_ces(&string));
+                    }
+                }
+            }
+            let default = match single {
+                Some(ces) => ces,
+                None => self.root_ces(&[c]),
+            };
+            let mut ce32 = if suffixes.is_empty() {
+                encoder.encode(&default)?
+            } else {
+                let flags = self.contraction_flags(&suffixes);
+                encoder.encode_contraction(&default, &suffixes, flags)?
+            };
+            if root_tag == Some(Tag::Digit) {
+                // Keep numeric collation working for tailored digits.
+                let index = encoder.ce32s.len() as u32;
+                encoder.ce32s.push(ce32);
+                ce32 = (index << 13) | (u32::from(root_ce32.digit()) << 8) | DIGIT_CE32_LOW_BYTE;
+            }
+            trie_values.insert(u32::from(c), ce32);
+        }
+
+        // The iterator looks up the optimized diacritics in the diacritic table without
+        // consulting the tailoring trie, so if the rules tailor any of them, the table
+        // is shortened to end before the first tailored diacritic and the trie supplies
+        // the root values for the rest of the range.
+        let diacritics = if let Some(&first) = trie_values
+            .range(COMBINING_DIACRITICS_BASE..)
+            .next()
+            .map(|(cp, _)| cp)
+            .filter(|&&cp| cp < COMBINING_DIACRITICS_BASE + OPTIMIZED_DIACRITICS_MAX_COUNT as u32)
+        {
+            let len = (first - COMBINING_DIACRITICS_BASE) as usize;
+            for (i, secondary) in self.diacritics.iter().enumerate().skip(len) {
+                let cp = COMBINING_DIACRITICS_BASE + i as u32;
+                if secondary != 0 {
+                    trie_values.entry(cp).or_insert(
+                        (u32::from(secondary) << 16)
+                            | (COMMON_WEIGHT16 & 0xFF00)
+                            | LONG_SECONDARY_CE32_LOW_BYTE,
+                    );
+                }
+            }
+            let secondaries: Vec<u16> = self.diacritics.iter().take(len).collect();
+            Some(ZeroVec::alloc_from_slice(&secondaries))
+        } else {
+            None
+        };
+
+        Ok(Tailoring {
+            data: CollationDataV1 {
+                trie: build_code_point_trie(&trie_values)?,
+                ces: ZeroVec::alloc_from_slice(&encoder.ces),
+                ce32s: ZeroVec::alloc_from_slice(&encoder.ce32s),
+                contexts: ZeroVec::alloc_from_slice(&encoder.contexts),
+            },
+            diacritics,
+            options: self.options,
+        })
+    }
+
+    fn nfd(&self, string: &str) -> Vec<char> {
+        Decomposition::new(string.chars(), self.decompositions, self.tables).collect()
+    }
+
+    fn has_ccc(&self, c: char) -> bool {
+        trie_value_has_ccc(self.decompositions.trie.get32(u32::from(c)))
+    }
+
+    /// Rejects strings that the `CollationElements` iterator would not look up in the
+    /// tailoring trie.
+    fn check_tailored_string(&self, nfd: &[char], offset: usize) -> Result<(), CollatorError> {
+        // Conjoining jamo are always looked up in the jamo table.
+        if nfd
+            .iter()
+            .any(|&c| u32::from(c) >= HANGUL_JAMO_BASE && u32::from(c) < HANGUL_JAMO_BASE + 0x100)
+        {
+            return Err(CollatorError::UnsupportedRules(offset));
+        }
+        // Prefix mappings (in the root, only for U+00B7) are not supported.
+        if let Some(&first) = nfd.first() {
+            let ce32 = self.root.ce32_for_char(first);
+            if ce32.to_ce_self_contained().is_none() && ce32.tag() == Tag::Prefix {
+                return Err(CollatorError::UnsupportedRules(offset));
+            }
+        }
+        Ok(())
+    }
+
+    /// Computes the root collation elements of an NFD string.
+    fn root_ces(&self, nfd: &[char]) -> Vec<u64> {
+        let mut iter = CollationElements::new(
+            nfd.iter().copied(),
+            self.root,
+            self.root,
+            self.jamo,
+            self.diacritics,
+            self.decompositions,
+            self.tables,
+            None,
+            false,
+        );
+        let mut ces = Vec::new();
+        loop {
+            let ce = iter.next();
+            if ce.primary() == NO_CE_PRIMARY {
+                return ces;
+            }
+            ces.push((u64::from(ce.primary()) << 32) | u64::from(ce.non_primary().bits()));
+        }
+    }
+
+    /// Computes the collation elements of an NFD string, preferring the longest
+    /// strings tailored so far and using the root collation elements otherwise.
+    fn ces_for(&self, nfd: &[char]) -> Vec<Ce> {
+        let mut ces = Vec::new();
+        let mut untailored_start = 0;
+        let mut i = 0;
+        while i < nfd.len() {
+            let longest = (i + 1..=nfd.len())
+                .rev()
+                .find_map(|end| self.items.get(&nfd[i..end]).map(|item| (end, item)));
+            if let Some((end, item)) = longest {
+                if untailored_start < i {
+                    ces.extend(
+                        self.root_ces(&nfd[untailored_start..i])
+                            .into_iter()
+                            .map(Ce::Root),
+                    );
+                }
+                ces.extend_from_slice(item);
+                i = end;
+                untailored_start = end;
+            } else {
+                i += 1;
+            }
+        }
+        if untailored_start < nfd.len() {
+            ces.extend(
+                self.root_ces(&nfd[untailored_start..])
+                    .into_iter()
+                    .map(Ce::Root),
+            );
+        }
+        ces
+    }
+
+    /// Drops the trailing collation elements of the current position that are weaker
+    /// than `strength` and returns the node for the last remaining one.
+    fn find_or_insert_node_for_ces(
+        &mut self,
+        strength: Strength,
+        offset: usize,
+    ) -> Result<usize, CollatorError> {
+        while let Some(ce) = self.ces.last() {
+            if ce.strength() <= strength {
+                break;
+            }
+            self.ces.pop();
+        }
+        match self.ces.last() {
+            Some(&Ce::Node { node, .. }) => Ok(node),
+            Some(&Ce::Root(ce)) => self.find_or_insert_node_for_root_ce(ce, strength, offset),
+            None => {
+                self.ces.push(Ce::Root(0));
+                self.find_or_insert_node_for_root_ce(0, strength, offset)
+            }
+        }
+    }
+
+    fn find_or_insert_node_for_root_ce(
+        &mut self,
+        ce: u64,
+        strength: Strength,
+        offset: usize,
+    ) -> Result<usize, CollatorError> {
+        let primary = (ce >> 32) as u32;
+        if primary >> 24 == UNASSIGNED_IMPLICIT_BYTE {
+            return Err(CollatorError::UnsupportedRules(offset));
+        }
+        let mut node = self.find_or_insert_primary(primary, primary_limit_after(primary), offset);
+        if strength >= Strength::Secondary {
+            let secondary = (ce >> 16) as u16 as u32;
+            node = self.find_or_insert_weak(
+                node,
+                secondary,
+                secondary_limit_after(secondary),
+                Strength::Secondary,
+                offset,
+            );
+            if strength >= Strength::Tertiary {
+                let tertiary = (ce as u32) & 0x3F3F;
+                node = self.find_or_insert_weak(
+                    node,
+                    tertiary,
+                    tertiary + 0x1F,
+                    Strength::Tertiary,
+                    offset,
+                );
+            }
+        }
+        Ok(node)
+    }
+
+    fn find_or_insert_primary(&mut self, primary: u32, limit: u32, offset: usize) -> usize {
+        if let Some(list) = self.lists.get(&primary) {
+            return list[0];
+        }
+        let id = self.nodes.len();
+        self.nodes.push(Node {
+            strength: Strength::Primary,
+            root: Some((primary, limit)),
+            list: primary,
+            offset,
+        });
+        self.lists.insert(primary, alloc::vec![id]);
+        id
+    }
+
+    /// The weight at a weaker level that nodes below `node` have unless they are
+    /// tailored or anchored at a different root weight.
+    fn implied_weight(&self, node: usize) -> u32 {
+        match self.nodes[node].root {
+            Some((0, _)) => 0,
+            _ => COMMON_WEIGHT16,
+        }
+    }
+
+    /// Finds or inserts the node for a secondary or tertiary root (or virtual) weight
+    /// below the stronger node `parent`.
+    fn find_or_insert_weak(
+        &mut self,
+        parent: usize,
+        weight: u32,
+        limit: u32,
+        strength: Strength,
+        offset: usize,
+    ) -> usize {
+        if weight == self.implied_weight(parent) {
+            return parent;
+        }
+        let key = self.nodes[parent].list;
+        let mut i = self.position(parent);
+        let list = &self.lists[&key];
+        while let Some(&next) = list.get(i + 1) {
+            let node = &self.nodes[next];
+            if node.strength < strength {
+                break;
+            }
+            if node.strength == strength {
+                if let Some((w, _)) = node.root {
+                    if w == weight {
+                        return next;
+                    }
+                    if w > weight {
+                        break;
+                    }
+                }
+            }
+            i += 1;
+        }
+        self.insert_node(
+            key,
+            i + 1,
+            Node {
+                strength,
+                root: Some((weight, limit)),
+                list: key,
+                offset,
+            },
+        )
+    }
+
+    /// Inserts a tailored node after `anchor` and after the nodes that follow `anchor`
+    /// with weaker differences.
+    fn insert_tailored_node_after(
+        &mut self,
+        anchor: usize,
+        strength: Strength,
+        offset: usize,
+    ) -> usize {
+        let key = self.nodes[anchor].list;
+        let mut i = self.position(anchor);
+        let list = &self.lists[&key];
+        while let Some(&next) = list.get(i + 1) {
+            if self.nodes[next].strength <= strength {
+                break;
+            }
+            i += 1;
+        }
+        self.insert_node(
+            key,
+            i + 1,
+            Node {
+                strength,
+                root: None,
+                list: key,
+                offset,
+            },
+        )
+    }
+
+    /// Returns the node after which relations following `[before n]` are inserted.
+    fn find_node_before(
+        &mut self,
+        mut node: usize,
+        strength: Strength,
+        offset: usize,
+    ) -> Result<usize, CollatorError> {
+        while self.nodes[node].strength > strength {
+            node = self.previous(node);
+        }
+        let (node_strength, root) = (self.nodes[node].strength, self.nodes[node].root);
+        if root.is_none() && node_strength == strength {
+            // Insert right before the tailored node.
+            return Ok(self.previous(node));
+        }
+        if strength == Strength::Primary {
+            let primary = root.map_or(0, |(weight, _)| weight);
+            let (base, limit) =
+                primary_range_before(primary).ok_or(CollatorError::UnsupportedRules(offset))?;
+            let head = self.find_or_insert_primary(base, limit, offset);
+            let key = self.nodes[head].list;
+            return Ok(self.lists[&key][self.lists[&key].len() - 1]);
+        }
+        let (weight, parent) = if let (true, Some((weight, _))) = (node_strength == strength, root)
+        {
+            let mut parent = node;
+            while self.nodes[parent].strength >= strength {
+                parent = self.previous(parent);
+            }
+            (weight, parent)
+        } else {
+            (self.implied_weight(node), node)
+        };
+        let (base, limit) = if strength == Strength::Secondary {
+            secondary_range_before(weight)
+        } else {
+            tertiary_range_before(weight)
+        }
+        .ok_or(CollatorError::UnsupportedRules(offset))?;
+        let mut position = self.find_or_insert_weak(parent, base, limit, strength, offset);
+        // Continue after the relations already tailored before the same weight.
+        let key = self.nodes[position].list;
+        let list = &self.lists[&key];
+        let mut i = self.position(position);
+        while let Some(&next) = list.get(i + 1) {
+            let next_node = &self.nodes[next];
+            if next_node.strength < strength
+                || (next_node.strength == strength && next_node.root.is_some())
+            {
+                break;
+            }
+            position = next;
+            i += 1;
+        }
+        Ok(position)
+    }
+
+    fn insert_node(&mut self, key: u32, index: usize, node: Node) -> usize {
+        let id = self.nodes.len();
+        self.nodes.push(node);
+        if let Some(list) = self.lists.get_mut(&key) {
+            list.insert(index, id);
+        }
+        id
+    }
+
+    fn position(&self, node: usize) -> usize {
+        let list = &self.lists[&self.nodes[node].list];
+        list.iter().position(|&id| id == node).unwrap_or(0)
+    }
+
+    /// The node preceding `node` in its list. The head of a list is a primary node,
+    /// so this is only called for nodes that are not heads.
+    fn previous(&self, node: usize) -> usize {
+        let list = &self.lists[&self.nodes[node].list];
+        list[self.position(node).saturating_sub(1)]
+    }
+
+    /// Sets the case bits of the current collation elements from the root collation
+    /// elements of the string. As in ICU4C, the case of the primary collation elements
+    /// is distributed over the tailored primaries, and tertiary differences are
+    /// treated as uppercase.
+    fn set_case_bits(&mut self, nfd: &[char]) {
+        let tailored_primaries = self
+            .ces
+            .iter()
+            .filter(|ce| ce.strength() == Strength::Primary)
+            .count();
+        let mut cases: u64 = 0;
+        if tailored_primaries > 0 {
+            let mut last_case = 0;
+            let mut root_primaries = 0;
+            for ce in self.root_ces(nfd) {
+                if ce >> 32 == 0 {
+                    continue;
+                }
+                root_primaries += 1;
+                let case = (ce >> 14) & 3;
+                if root_primaries < tailored_primaries {
+                    cases |= case << ((root_primaries - 1) * 2);
+                } else if root_primaries == tailored_primaries {
+                    last_case = case;
+                } else if case != last_case {
+                    // Mixed case among the trailing primaries.
+                    last_case = 1;
+                    break;
+                }
+            }
+            if root_primaries >= tailored_primaries {
+                cases |= last_case << ((tailored_primaries - 1) * 2);
+            }
+        }
+        for ce in self.ces.iter_mut() {
+            let case = match ce.strength() {
+                Strength::Primary => {
+                    let case = (cases & 3) << 14;
+                    cases >>= 2;
+                    case
+                }
+                Strength::Tertiary => 0x8000,
+                _ => 0,
+            };
+            match ce {
+                Ce::Root(bits) => *bits = (*bits & !0xC000) | case,
+                Ce::Node { case: c, .. } => *c = case,
+            }
+        }
+    }
+
+    /// Assigns the weights of all nodes by walking each list in order.
+    fn allocate_weights(&self) -> Result<Vec<u64>, CollatorError> {
+        let mut weights = alloc::vec![0u64; self.nodes.len()];
+        for list in self.lists.values() {
+            let (mut p, mut p_limit) = (0, 0);
+            let (mut s, mut s_limit) = (0, 0);
+            let (mut t, mut t_limit) = (0, 0);
+            for &id in list {
+                let node = &self.nodes[id];
+                let error = CollatorError::UnsupportedRules(node.offset);
+                match (node.strength, node.root) {
+                    (Strength::Primary, Some((weight, limit))) => {
+                        p = weight;
+                        p_limit = limit;
+                        s = if p == 0 { 0 } else { COMMON_WEIGHT16 };
+                        s_limit = s + 0x7F;
+                        t = s;
+                        t_limit = t + 0x1F;
+                    }
+                    (Strength::Secondary, Some((weight, limit))) => {
+                        s = weight;
+                        s_limit = limit;
+                        t = if s == 0 { 0 } else { COMMON_WEIGHT16 };
+                        t_limit = t + 0x1F;
+                    }
+                    (_, Some((weight, limit))) => {
+                        t = weight;
+                        t_limit = limit;
+                    }
+                    (Strength::Primary, None) => {
+                        if p == 0 || p >= p_limit {
+                            return Err(error);
+                        }
+                        p += 1;
+                        s = COMMON_WEIGHT16;
+                        s_limit = s + 0x7F;
+                        t = COMMON_WEIGHT16;
+                        t_limit = t + 0x1F;
+                    }
+                    (Strength::Secondary, None) => {
+                        if s == 0 || s >= s_limit {
+                            return Err(error);
+                        }
+                        s += 1;
+                        t = COMMON_WEIGHT16;
+                        t_limit = t + 0x1F;
+                    }
+                    (_, None) => {
+                        if t == 0 || t >= t_limit {
+                            return Err(error);
+                        }
+                        t += 1;
+                    }
+                }
+                weights[id] = (u64::from(p) << 32) | (u64::from(s) << 16) | u64::from(t);
+            }
+        }
+        Ok(weights)
+    }
+
+    fn contraction_flags(&self, suffixes: &Suffixes) -> u32 {
+        let mut flags = CONTRACT_NEXT_CCC;
+        for suffix in suffixes.keys() {
+            if suffix.first().map_or(true, |&c| !self.has_ccc(c)) {
+                flags &= !CONTRACT_NEXT_CCC;
+            }
+            if suffix.last().map_or(false, |&c| self.has_ccc(c)) {
+                flags |= CONTRACT_TRAILING_CCC;
+            }
+            if suffix.iter().any(|&c| !self.has_ccc(c)) {
+                flags |= CONTRACT_HAS_STARTER;
+            }
+        }
+        flags
+    }
+}
+
+/// The largest primary weight that may be tailored after a root primary.
+fn primary_limit_after(primary: u32) -> u32 {
+    if primary & 0xFFFF == 0 {
+        primary + 0x7FFF
+    } else if primary & 0xFF == 0 {
+        primary + 0x7F
+    } else {
+        primary + 6
+    }
+}
+
+/// The largest secondary weight that may be tailored after a root secondary.
+fn secondary_limit_after(secondary: u32) -> u32 {
+    if secondary & 0xFF == 0 {
+        secondary + 0x7F
+    } else {
+        secondary + 0xF
+    }
+}
+
+/// The virtual primary weight after which `[before 1]` relations are tailored and the
+/// largest primary weight that they may use.
+fn primary_range_before(primary: u32) -> Option<(u32, u32)> {
+    let base = if primary & 0xFFFF == 0 {
+        primary.checked_sub(0x100)?
+    } else if primary & 0xFF == 0 {
+        primary.checked_sub(0x80)?
+    } else {
+        primary.checked_sub(7)?
+    };
+    if base <= MERGE_SEPARATOR_PRIMARY {
+        return None;
+    }
+    Some((base, primary - 1))
+}
+
+fn secondary_range_before(secondary: u32) -> Option<(u32, u32)> {
+    if secondary < 0x100 {
+        None
+    } else if secondary & 0xFF == 0 {
+        Some((secondary - 0x80, secondary - 1))
+    } else {
+        Some((secondary - 0x10, secondary - 1))
+    }
+}
+
+fn tertiary_range_before(tertiary: u32) -> Option<(u32, u32)> {
+    if tertiary < 0x100 {
+        None
+    } else {
+        Some((tertiary - 0xE0, tertiary - 0xC1))
+    }
+}
+
+/// Accumulates the `ces`, `ce32s` and `contexts` of the tailoring data.
+#[derive(Default)]
+struct Encoder {
+    ces: Vec<u64>,
+    ce32s: Vec<u32>,
+    contexts: Vec<u16>,
+}
+
+impl Encoder {
+    fn encode(&mut self, ces: &[u64]) -> Result<u32, CollatorError> {
+        if let [ce] = ces {
+            if let Some(ce32) = self_contained_ce32(*ce) {
+                return Ok(ce32);
+            }
+        }
+        if ces.is_empty() {
+            return Ok(0);
+        }
+        let index = self.ces.len() as u32;
+        if ces.len() > MAX_EXPANSION_LENGTH || index >= 1 << 19 {
+            return Err(CollatorError::UnsupportedRules(0));
+        }
+        self.ces.extend_from_slice(ces);
+        Ok((index << 13) | ((ces.len() as u32) << 8) | EXPANSION_CE32_LOW_BYTE)
+    }
+
+    fn encode_contraction(
+        &mut self,
+        default: &[u64],
+        suffixes: &Suffixes,
+        flags: u32,
+    ) -> Result<u32, CollatorError> {
+        let default = self.encode(default)?;
+        let mut entries = Vec::with_capacity(suffixes.len());
+        for (suffix, ces) in suffixes {
+            let mut units = Vec::with_capacity(suffix.len());
+            for c in suffix {
+                units.extend_from_slice(c.encode_utf16(&mut [0u16; 2]));
+            }
+            entries.push((units, self.encode(ces)?));
+        }
+        // The trie is ordered by code units, which differs from the order of
+        // `char` for supplementary characters.
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        let index = self.contexts.len() as u32;
+        if index >= 1 << 19 {
+            return Err(CollatorError::UnsupportedRules(0));
+        }
+        self.contexts.push((default >> 16) as u16);
+        self.contexts.push(default as u16);
+        self.contexts.extend(write_char16_trie_node(&entries, 0));
+        Ok((index << 13) | flags | CONTRACTION_CE32_LOW_BYTE)
+    }
+}
+
+/// Returns the 32-bit form of a collation element if it has one.
+fn self_contained_ce32(ce: u64) -> Option<u32> {
+    let primary = (ce >> 32) as u32;
+    let lower = ce as u32;
+    let secondary = lower >> 16;
+    let tertiary = lower & 0xFFFF;
+    if primary & 0xFFFF == 0 && secondary & 0xFF == 0 && tertiary & 0xFF == 0 {
+        let ce32 = primary | (secondary & 0xFF00) | (tertiary >> 8);
+        if ce32 & 0xFF < FALLBACK_CE32 {
+            return Some(ce32);
+        }
+    }
+    if primary & 0xFF == 0 && primary != 0 && lower == (COMMON_WEIGHT16 << 16) | COMMON_WEIGHT16 {
+        return Some(primary | LONG_PRIMARY_CE32_LOW_BYTE);
+    }
+    if primary == 0 && lower & 0xFF == 0 {
+        return Some(lower | LONG_SECONDARY_CE32_LOW_BYTE);
+    }
+    None
+}
+
+/// Builds a fast-type `CodePointTrie` that maps the given code points to their values
+/// and every other code point to `FALLBACK_CE32`.
+fn build_code_point_trie(
+    values: &BTreeMap<u32, u32>,
+) -> Result<CodePointTrie<'static, u32>, CollatorError> {
+    const BMP_BLOCK: u32 = 64;
+    const SMALL_BLOCK: u32 = 16;
+    const INDEX_BLOCK: u32 = 32;
+
+    let has_values = |start: u32, len: u32| values.range(start..start + len).next().is_some();
+
+    let high_start = values
+        .range(0x10000..)
+        .next_back()
+        .map_or(0x10000, |(&max, _)| ((max >> 14) + 1) << 14);
+
+    // The null data block.
+    let mut data: Vec<u32> = alloc::vec![FALLBACK_CE32; BMP_BLOCK as usize];
+    let push_block = |data: &mut Vec<u32>, start: u32, len: u32| -> usize {
+        let offset = data.len();
+        data.extend(
+            (start..start + len).map(|cp| values.get(&cp).copied().unwrap_or(FALLBACK_CE32)),
+        );
+        offset
+    };
+
+    let mut index: Vec<usize> = Vec::new();
+    for block in 0..0x10000 / BMP_BLOCK {
+        let start = block * BMP_BLOCK;
+        index.push(if has_values(start, BMP_BLOCK) {
+            push_block(&mut data, start, BMP_BLOCK)
+        } else {
+            0
+        });
+    }
+
+    // The index-1 table for supplementary code points follows the BMP index.
+    let index1_start = index.len();
+    let index1_len = ((high_start >> 14) - 4) as usize;
+    index.resize(index1_start + index1_len, 0);
+    let mut index3_null_offset = 0xFFFF;
+    if index1_len > 0 {
+        let index3_null = index.len();
+        index3_null_offset = index3_null;
+        index.resize(index3_null + INDEX_BLOCK as usize, 0);
+        let index2_null = index.len();
+        index.resize(index2_null + INDEX_BLOCK as usize, index3_null);
+        for i1 in 0..index1_len {
+            let start1 = (i1 as u32 + 4) << 14;
+            if !has_values(start1, 1 << 14) {
+                index[index1_start + i1] = index2_null;
+                continue;
+            }
+            let index2 = index.len();
+            index[index1_start + i1] = index2;
+            index.resize(index2 + INDEX_BLOCK as usize, index3_null);
+            for i2 in 0..INDEX_BLOCK {
+                let start2 = start1 + (i2 << 9);
+                if !has_values(start2, 1 << 9) {
+                    continue;
+                }
+                let index3 = index.len();
+                index[index2 + i2 as usize] = index3;
+                for i3 in 0..INDEX_BLOCK {
+                    let start3 = start2 + i3 * SMALL_BLOCK;
+                    index.push(if has_values(start3, SMALL_BLOCK) {
+                        push_block(&mut data, start3, SMALL_BLOCK)
+                    } else {
+                        0
+                    });
+                }
+            }
+        }
+    }
+
+    // The value for code points at and above `high_start`, and the error value.
+    data.push(FALLBACK_CE32);
+    data.push(FALLBACK_CE32);
+
+    if data.len() > 0xFFFF || index.len() > 0x7FFF {
+        return Err(CollatorError::UnsupportedRules(0));
+    }
+    let index: Vec<u16> = index.into_iter().map(|i| i as u16).collect();
+    let header = CodePointTrieHeader {
+        high_start,
+        shifted12_high_start: ((high_start + 0xFFF) >> 12) as u16,
+        index3_null_offset: index3_null_offset as u16,
+        data_null_offset: 0,
+        null_value: FALLBACK_CE32,
+        trie_type: TrieType::Fast,
+    };
+    CodePointTrie::try_new(
+        header,
+        ZeroVec::alloc_from_slice(&index),
+        ZeroVec::alloc_from_slice(&data),
+    )
+    .map_err(|_| CollatorError::UnsupportedRules(0))
+}
+
+/// Writes the `Char16Trie` node for the sorted `entries`, which share their first
+/// `depth` code units.
+///
+/// Values and jump deltas always use their three-unit forms.
+fn write_char16_trie_node(entries: &[TrieEntry], depth: usize) -> Vec<u16> {
+    let (value, rest) = match entries.split_first() {
+        Some((first, rest)) if first.0.len() == depth => (Some(first.1), rest),
+        _ => (None, entries),
+    };
+    let mut out = Vec::new();
+    if rest.is_empty() {
+        let value = value.unwrap_or(0);
+        out.extend_from_slice(&[0xFFFF, (value >> 16) as u16, value as u16]);
+        return out;
+    }
+    let (lead, body) = write_char16_trie_match_node(rest, depth);
+    match value {
+        Some(value) => out.extend_from_slice(&[0x7FC0 | lead, (value >> 16) as u16, value as u16]),
+        None => out.push(lead),
+    }
+    out.extend(body);
+    out
+}
+
+/// Writes a linear-match or branch node, returning its lead unit separately so that
+/// the caller can combine it with an intermediate value.
+fn write_char16_trie_match_node(entries: &[TrieEntry], depth: usize) -> (u16, Vec<u16>) {
+    let first = &entries[0].0;
+    let last = &entries[entries.len() - 1].0;
+    if first[depth] == last[depth] {
+        // The entries are sorted, so they all share this unit.
+        let mut len = 1;
+        while len < 16
+            && entries.iter().all(|(units, _)| units.len() > depth + len)
+            && first[depth + len] == last[depth + len]
+        {
+            len += 1;
+        }
+        let mut body = first[depth..depth + len].to_vec();
+        body.extend(write_char16_trie_node(entries, depth + len));
+        return (0x30 + len as u16 - 1, body);
+    }
+    let mut groups: Vec<(u16, &[TrieEntry])> = Vec::new();
+    let mut start = 0;
+    for i in 1..=entries.len() {
+        if i == entries.len() || entries[i].0[depth] != entries[start].0[depth] {
+            groups.push((entries[start].0[depth], &entries[start..i]));
+            start = i;
+        }
+    }
+    let mut body = Vec::new();
+    let length = groups.len() as u16 - 1;
+    let lead = if length < 0x30 {
+        length
+    } else {
+        body.push(length);
+        0
+    };
+    body.extend(write_char16_trie_branch(&groups, depth));
+    (lead, body)
+}
+
+fn write_char16_trie_branch(groups: &[(u16, &[TrieEntry])], depth: usize) -> Vec<u16> {
+    let mut out = Vec::new();
+    if groups.len() > 5 {
+        // Units below the split unit jump to the lower half, which follows the
+        // upper half.
+        let half = groups.len() / 2;
+        let upper = write_char16_trie_branch(&groups[half..], depth);
+        let lower = write_char16_trie_branch(&groups[..half], depth);
+        let delta = upper.len() as u32;
+        out.extend_from_slice(&[groups[half].0, 0xFFFF, (delta >> 16) as u16, delta as u16]);
+        out.extend(upper);
+        out.extend(lower);
+        return out;
+    }
+    let mut deferred = Vec::new();
+    for (i, &(unit, group)) in groups.iter().enumerate() {
+        out.push(unit);
+        if i == groups.len() - 1 {
+            out.extend(write_char16_trie_node(group, depth + 1));
+        } else if group.len() == 1 && group[0].0.len() == depth + 1 {
+            let value = group[0].1;
+            out.extend_from_slice(&[0xFFFF, (value >> 16) as u16, value as u16]);
+        } else {
+            deferred.push((out.len(), write_char16_trie_node(group, depth + 1)));
+            out.extend_from_slice(&[0x7FFF, 0, 0]);
+        }
+    }
+    for (position, node) in deferred {
+        let delta = (out.len() - (position + 3)) as u32;
+        out[position + 1] = (delta >> 16) as u16;
+        out[position + 2] = delta as u16;
+        out.extend(node);
+    }
+    out
+}
+
+/// Lists the strings of a `Char16Trie` that have values.
+fn enumerate_char16_trie(trie: &ZeroSlice<u16>) -> Vec<Vec<u16>> {
+    let mut strings = Vec::new();
+    let mut prefix = Vec::new();
+    // Malformed root data only results in fewer contractions being carried over.
+    let _ = enumerate_char16_trie_node(trie, 0, &mut prefix, &mut strings);
+    strings
+}
+
+fn enumerate_char16_trie_node(
+    trie: &ZeroSlice<u16>,
+    mut pos: usize,
+    prefix: &mut Vec<u16>,
+    strings: &mut Vec<Vec<u16>>,
+) -> Option<()> {
+    let mut node = trie.get(pos)?;
+    pos += 1;
+    if node >= 0x40 {
+        strings.push(prefix.clone());
+        if node & 0x8000 != 0 {
+            return Some(());
+        }
+        pos += if node < 0x4040 {
+            0
+        } else if node < 0x7FC0 {
+            1
+        } else {
+            2
+        };
+        node &= 0x3F;
+    }
+    if node < 0x30 {
+        let mut length = usize::from(node);
+        if length == 0 {
+            length = usize::from(trie.get(pos)?);
+            pos += 1;
+        }
+        return enumerate_char16_trie_branch(trie, pos, length + 1, prefix, strings);
+    }
+    let len = usize::from(node - 0x30 + 1);
+    let prefix_len = prefix.len();
+    for i in 0..len {
+        prefix.push(trie.get(pos + i)?);
+    }
+    let result = enumerate_char16_trie_node(trie, pos + len, prefix, strings);
+    prefix.truncate(prefix_len);
+    result
+}
+
+fn enumerate_char16_trie_branch(
+    trie: &ZeroSlice<u16>,
+    mut pos: usize,
+    mut length: usize,
+    prefix: &mut Vec<u16>,
+    strings: &mut Vec<Vec<u16>>,
+) -> Option<()> {
+    while length > 5 {
+        let delta = trie.get(pos + 1)?;
+        let (lower, upper) = if delta < 0xFC00 {
+            (pos + 2 + usize::from(delta), pos + 2)
+        } else if delta == 0xFFFF {
+            let delta = (usize::from(trie.get(pos + 2)?) << 16) | usize::from(trie.get(pos + 3)?);
+            (pos + 4 + delta, pos + 4)
+        } else {
+            let delta = (usize::from(delta - 0xFC00) << 16) | usize::from(trie.get(pos + 2)?);
+            (pos + 3 + delta, pos + 3)
+        };
+        enumerate_char16_trie_branch(trie, lower, length >> 1, prefix, strings)?;
+        length -= length >> 1;
+        pos = upper;
+    }
+    loop {
+        prefix.push(trie.get(pos)?);
+        pos += 1;
+        if length == 1 {
+            let result = enumerate_char16_trie_node(trie, pos, prefix, strings);
+            prefix.pop();
+            return result;
+        }
+        let lead = trie.get(pos)?;
+        pos += 1;
+        let value_lead = lead & 0x7FFF;
+        let (value, after) = if value_lead < 0x4000 {
+            (usize::from(value_lead), pos)
+        } else if value_lead < 0x7FFF {
+            (
+                (usize::from(value_lead - 0x4000) << 16) | usize::from(trie.get(pos)?),
+                pos + 1,
+            )
+        } else {
+            (
+                (usize::from(trie.get(pos)?) << 16) | usize::from(trie.get(pos + 1)?),
+                pos + 2,
+            )
+        };
+        if lead & 0x8000 != 0 {
+            strings.push(prefix.clone());
+        } else {
+            enumerate_char16_trie_node(trie, after + value, prefix, strings)?;
+        }
+        prefix.pop();
+        pos = after;
+        length -= 1;
+    }
+}
@@ -9,6 +9,8 @@
 //! This module holds the `Collator` struct whose `compare_impl()` contains
 //! the comparison of collation element sequences.
 
+#[cfg(feature = "experimental")]
+use crate::builder::TailoringBuilder;
 use crate::elements::{
     CollationElement, CollationElements, NonPrimary, JAMO_COUNT, NO_CE, NO_CE_PRIMARY,
     NO_CE_SECONDARY, NO_CE_TERTIARY, OPTIMIZED_DIACRITICS_MAX_COUNT, QUATERNARY_MASK,
@@ -16,12 +18,18 @@ use crate::elements::{
 use crate::error::CollatorError;
 use crate::options::CollatorOptionsBitField;
 use crate::provider::CollationDataV1Marker;
+#[cfg(feature = "experimental")]
+use crate::provider::CollationDiacriticsV1;
 use crate::provider::CollationDiacriticsV1Marker;
 use crate::provider::CollationJamoV1Marker;
+use crate::provider::CollationMetadataV1;
 use crate::provider::CollationMetadataV1Marker;
 use crate::provider::CollationReorderingV1Marker;
 use crate::provider::CollationSpecialPrimariesV1Marker;
+#[cfg(feature = "experimental")]
+use crate::rules;
 use crate::{AlternateHandling, CollatorOptions, MaxVariable, Strength};
+use alloc::vec::Vec;
 use core::cmp::Ordering;
 use core::convert::TryFrom;
 use icu_locid::extensions_unicode_value as value;
@@ -67,6 +75,43 @@ pub struct Collator {
     lithuanian_dot_above: bool,
 }
 
+/// Returns the options that the collation metadata changes from the defaults.
+fn altered_defaults(metadata: &CollationMetadataV1) -> CollatorOptionsBitField {
+    let mut altered_defaults = CollatorOptionsBitField::new();
+
+    if metadata.alternate_shifted() {
+        altered_defaults.set_alternate_handling(Some(AlternateHandling::Shifted));
+    }
+    if metadata.backward_second_level() {
+        altered_defaults.set_backward_second_level(Some(true));
+    }
+
+    altered_defaults.set_case_first(Some(metadata.case_first()));
+    altered_defaults.set_max_variable(Some(metadata.max_variable()));
+    altered_defaults
+}
+
+/// Loads the special primaries if the options need them.
+fn load_special_primaries<D>(
+    data_provider: &D,
+    options: CollatorOptionsBitField,
+) -> Result<Option<DataPayload<CollationSpecialPrimariesV1Marker>>, CollatorError>
+where
+    D: DataProvider<CollationSpecialPrimariesV1Marker> + ?Sized,
+{
+    if options.alternate_handling() != AlternateHandling::Shifted && !options.numeric() {
+        return Ok(None);
+    }
+    let special_primaries: DataPayload<CollationSpecialPrimariesV1Marker> =
+        data_provider.load(Default::default())?.take_payload()?;
+    // `variant_count` isn't stable yet:
+    // https://github.com/rust-lang/rust/issues/73662
+    if special_primaries.get().last_primaries.len() <= (MaxVariable::Currency as usize) {
+        return Err(CollatorError::MalformedData);
+    }
+    Ok(Some(special_primaries))
+}
+
 impl Collator {
     /// Instantiates a collator for a given locale with the given options
     ///
@@ -172,8 +217,21 @@ impl Collator {
             return Err(CollatorError::MalformedData);
         }
 
-        let jamo: DataPayload<CollationJamoV1Marker> = data_provider
-            .load(Default::default())? // TODO: redesign Korean search collation handling
+        // Tailorings that map jamo differently, such as the search collations, which make
+        // archaic jamo match their modern approximations, come with a jamo table of their
+        // own. Other collations use the root jamo table.
+        let jamo: DataPayload<CollationJamoV1Marker> =
+            match data_provider.load(if metadata.tailored() {
+                req
+            } else {
+                Default::default()
+            }) {
+                Err(DataError {
+                    kind: DataErrorKind::MissingLocale,
+                    ..
+                }) => data_provider.load(Default::default())?,
+                response => response?,
+            }
             .take_payload()?;
 
         if jamo.get().ce32s.len() != JAMO_COUNT {
@@ -186,35 +244,10 @@ impl Collator {
         let tables: DataPayload<CanonicalDecompositionTablesV1Marker> =
             data_provider.load(Default::default())?.take_payload()?;
 
-        let mut altered_defaults = CollatorOptionsBitField::new();
-
-        if metadata.alternate_shifted() {
-            altered_defaults.set_alternate_handling(Some(AlternateHandling::Shifted));
-        }
-        if metadata.backward_second_level() {
-            altered_defaults.set_backward_second_level(Some(true));
-        }
-
-        altered_defaults.set_case_first(Some(metadata.case_first()));
-        altered_defaults.set_max_variable(Some(metadata.max_variable()));
-
         let mut merged_options = CollatorOptionsBitField::from(options);
-        merged_options.set_defaults(altered_defaults);
-
-        let special_primaries = if merged_options.alternate_handling() == AlternateHandling::Shifted
-            || merged_options.numeric()
-        {
-            let special_primaries: DataPayload<CollationSpecialPrimariesV1Marker> =
-                data_provider.load(Default::default())?.take_payload()?;
-            // `variant_count` isn't stable yet:
-            // https://github.com/rust-lang/rust/issues/73662
-            if special_primaries.get().last_primaries.len() <= (MaxVariable::Currency as usize) {
-                return Err(CollatorError::MalformedData);
-            }
-            Some(special_primaries)
-        } else {
-            None
-        };
+        merged_options.set_defaults(altered_defaults(metadata));
+
+        let special_primaries = load_special_primaries(data_provider, merged_options)?;
 
         Ok(Collator {
             special_primaries,
@@ -236,6 +269,144 @@ impl Collator {
         error: CollatorError
     );
 
+    /// Instantiates a collator that tailors the root collation with the given rules
+    /// in the [ICU collation rule syntax](https://unicode-org.github.io/icu/userguide/collation/customization/).
+    ///
+    /// Settings in the rules, such as `[strength 2]`, act as defaults for the options that
+    /// are not set in `options`.
+    ///
+    /// Prefix rules (`|`), quaternary relations, special reset positions such as
+    /// `[first tertiary ignorable]`, the `[reorder]`, `[import]` and `[suppressContractions]`
+    /// settings, and tailorings of conjoining jamo are not supported and result in
+    /// [`CollatorError::UnsupportedRules`]. Malformed rules result in
+    /// [`CollatorError::InvalidRules`]. Both carry the byte offset of the offending rule.
+    ///
+    /// In particular, the resulting collator never reorders scripts: building the
+    /// [`CollationReorderingV1`](crate::provider::CollationReorderingV1) for a `[reorder]`
+    /// setting needs the script boundaries of the root collation, which are not part of the
+    /// data. Use [`Collator::try_new_unstable`] with a locale whose data has the reordering,
+    /// such as `bn`, instead.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+    /// including in SemVer minor releases. It can be enabled with the "experimental" feature
+    /// of the icu meta-crate. Use with caution.
+    /// </div>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::cmp::Ordering;
+    /// use icu_collator::*;
+    ///
+    /// let collator = Collator::try_new_with_rules_unstable(
+    ///     &icu_testdata::unstable(),
+    ///     "&c < ch <<< cH <<< Ch <<< CH",
+    ///     CollatorOptions::new(),
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(collator.compare("chocolate", "cuchara"), Ordering::Greater);
+    /// assert_eq!(collator.compare("chocolate", "dulce"), Ordering::Less);
+    /// ```
+    #[cfg(feature = "experimental")]
+    pub fn try_new_with_rules_unstable<D>(
+        data_provider: &D,
+        rules: &str,
+        options: CollatorOptions,
+    ) -> Result<Self, CollatorError>
+    where
+        D: DataProvider<CollationSpecialPrimariesV1Marker>
+            + DataProvider<CollationDataV1Marker>
+            + DataProvider<CollationDiacriticsV1Marker>
+            + DataProvider<CollationJamoV1Marker>
+            + DataProvider<CollationMetadataV1Marker>
+            + DataProvider<CanonicalDecompositionDataV1Marker>
+            + DataProvider<CanonicalDecompositionTablesV1Marker>
+            + ?Sized,
+    {
+        let metadata_payload: DataPayload<CollationMetadataV1Marker> =
+            data_provider.load(Default::default())?.take_payload()?;
+
+        let root: DataPayload<CollationDataV1Marker> =
+            data_provider.load(Default::default())?.take_payload()?;
+
+        let root_diacritics: DataPayload<CollationDiacriticsV1Marker> =
+            data_provider.load(Default::default())?.take_payload()?;
+
+        if root_diacritics.get().secondaries.len() != OPTIMIZED_DIACRITICS_MAX_COUNT {
+            return Err(CollatorError::MalformedData);
+        }
+
+        let jamo: DataPayload<CollationJamoV1Marker> =
+            data_provider.load(Default::default())?.take_payload()?;
+
+        let jamo_ce32s =
+            <&[<u32 as AsULE>::ULE; JAMO_COUNT]>::try_from(jamo.get().ce32s.as_ule_slice())
+                .map_err(|_| CollatorError::MalformedData)?;
+
+        let decompositions: DataPayload<CanonicalDecompositionDataV1Marker> =
+            data_provider.load(Default::default())?.take_payload()?;
+
+        let tables: DataPayload<CanonicalDecompositionTablesV1Marker> =
+            data_provider.load(Default::default())?.take_payload()?;
+
+        let mut builder = TailoringBuilder::new(
+            root.get(),
+            jamo_ce32s,
+            &root_diacritics.get().secondaries,
+            decompositions.get(),
+            tables.get(),
+            altered_defaults(metadata_payload.get()),
+        );
+        rules::parse(rules, &mut builder)?;
+        let tailoring = builder.build()?;
+
+        let diacritics = match tailoring.diacritics {
+            Some(secondaries) => DataPayload::from_owned(CollationDiacriticsV1 { secondaries }),
+            None => root_diacritics,
+        };
+
+        let mut merged_options = CollatorOptionsBitField::from(options);
+        merged_options.set_defaults(tailoring.options);
+
+        let special_primaries = load_special_primaries(data_provider, merged_options)?;
+
+        Ok(Collator {
+            special_primaries,
+            root,
+            tailoring: Some(DataPayload::from_owned(tailoring.data)),
+            jamo,
+            diacritics,
+            options: merged_options,
+            reordering: None,
+            decompositions,
+            tables,
+            lithuanian_dot_above: false,
+        })
+    }
+
+    #[cfg(feature = "experimental")]
+    #[doc = icu_provider::gen_any_buffer_docs!(ANY, icu_provider, Self::try_new_with_rules_unstable)]
+    pub fn try_new_with_rules_with_any_provider(
+        provider: &(impl AnyProvider + ?Sized),
+        rules: &str,
+        options: CollatorOptions,
+    ) -> Result<Self, CollatorError> {
+        Self::try_new_with_rules_unstable(&provider.as_downcasting(), rules, options)
+    }
+
+    #[cfg(all(feature = "experimental", feature = "serde"))]
+    #[doc = icu_provider::gen_any_buffer_docs!(BUFFER, icu_provider, Self::try_new_with_rules_unstable)]
+    pub fn try_new_with_rules_with_buffer_provider(
+        provider: &(impl BufferProvider + ?Sized),
+        rules: &str,
+        options: CollatorOptions,
+    ) -> Result<Self, CollatorError> {
+        Self::try_new_with_rules_unstable(&provider.as_deserializing(), rules, options)
+    }
+
     /// Compare potentially ill-formed UTF-16 slices. Unpaired surrogates
     /// are compared as if each one was a REPLACEMENT CHARACTER.
     pub fn compare_utf16(&self, left: &[u16], right: &[u16]) -> Ordering {
@@ -826,4 +997,290 @@ impl Collator {
 
         Ordering::Equal
     }
+
+    /// Computes the sort key of a guaranteed well-formed UTF-8 string.
+    ///
+    /// Comparing sort keys as byte strings (e.g. with `<[u8]>::cmp` or `memcmp`) gives the same
+    /// result as [`Collator::compare`] with this collator. Sort keys are useful when the same
+    /// strings are compared many times, such as when sorting large collections or storing
+    /// precomputed keys in a database index.
+    ///
+    /// The format of the sort key is an implementation detail and may change between ICU4X
+    /// versions and data versions, so stored sort keys must be recomputed when either changes.
+    /// Sort keys from collators with different locales or options must not be compared with
+    /// each other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_collator::*;
+    ///
+    /// let mut options = CollatorOptions::new();
+    /// options.strength = Some(Strength::Secondary);
+    /// let collator =
+    ///     Collator::try_new_unstable(&icu_testdata::unstable(), &Default::default(), options)
+    ///         .unwrap();
+    ///
+    /// let mut words = ["peach", "Péché", "pêche", "péché", "Peach"];
+    /// words.sort_by_cached_key(|word| collator.sort_key(word));
+    /// assert_eq!(words, ["peach", "Peach", "Péché", "péché", "pêche"]);
+    ///
+    /// assert_eq!(collator.sort_key("peach"), collator.sort_key("Peach"));
+    /// ```
+    pub fn sort_key(&self, s: &str) -> Vec<u8> {
+        self.sort_key_impl(s.chars())
+    }
+
+    /// Computes the sort key of potentially ill-formed UTF-8. Ill-formed input is treated as if
+    /// errors had been replaced with REPLACEMENT CHARACTERs according to the WHATWG Encoding
+    /// Standard, like in [`Collator::compare_utf8`].
+    ///
+    /// See [`Collator::sort_key`] for details.
+    pub fn sort_key_utf8(&self, s: &[u8]) -> Vec<u8> {
+        self.sort_key_impl(s.chars())
+    }
+
+    /// Computes the sort key of potentially ill-formed UTF-16. Unpaired surrogates are treated
+    /// as if each one was a REPLACEMENT CHARACTER, like in [`Collator::compare_utf16`].
+    ///
+    /// See [`Collator::sort_key`] for details.
+    pub fn sort_key_utf16(&self, s: &[u16]) -> Vec<u8> {
+        self.sort_key_impl(s.chars())
+    }
+
+    /// Writes the weights of each level that `compare_impl()` compares, in the same order,
+    /// followed by the NFD form of the string at the identical strength.
+    ///
+    /// Each weight is written with a fixed width in big-endian order, and each level ends with
+    /// the weight of `NO_CE`, which is lower than all other weights of the level. Therefore,
+    /// byte-wise comparison of the keys compares the weights of each level lexicographically,
+    /// with a shorter sequence of weights sorting first, just like `compare_impl()` does.
+    pub(crate) fn sort_key_impl<I: Iterator<Item = char> + Clone>(&self, chars: I) -> Vec<u8> {
+        let variable_top = self.variable_top();
+        let mut iter = self.collation_elements(chars.clone());
+
+        // Collect the CEs, shifting variable CEs to the quaternary level the same way as
+        // `compare_impl()`. The sentinel `NO_CE` ends the buffer.
+        let mut ces: SmallVec<[CollationElement; 32]> = SmallVec::new();
+        let mut shifting = false;
+        loop {
+            let ce = iter.next();
+            let primary = ce.primary();
+            if primary < variable_top && primary > MERGE_SEPARATOR_PRIMARY {
+                // Variable CE, shift it to quaternary level.
+                shifting = true;
+                ces.push(ce.clone_with_non_primary_zeroed());
+            } else if shifting && primary == 0 {
+                // Ignore primary ignorables following a variable CE.
+                ces.push(ce.clone_with_non_primary_zeroed());
+            } else {
+                shifting = false;
+                ces.push(ce);
+            }
+            if primary == NO_CE_PRIMARY {
+                break;
+            }
+        }
+
+        let mut key = Vec::new();
+
+        // Primary level
+        for ce in ces.iter() {
+            // Skip primary ignorables and variable CEs shifted to the quaternary level.
+            if ce.either_half_zero() {
+                continue;
+            }
+            let mut primary = ce.primary();
+            if let Some(reordering) = &self.reordering {
+                primary = reordering.get().reorder(primary);
+            }
+            key.extend_from_slice(&primary.to_be_bytes());
+        }
+
+        // Secondary level
+        if self.options.strength() >= Strength::Secondary {
+            if !self.options.backward_second_level() {
+                for ce in ces.iter() {
+                    let secondary = ce.secondary();
+                    if secondary != 0 {
+                        key.extend_from_slice(&secondary.to_be_bytes());
+                    }
+                }
+            } else {
+                // The secondary weights are reversed within each segment ending with a
+                // merge separator or `NO_CE`, and each segment ends with the weight of `NO_CE`.
+                let mut remaining = &ces[..];
+                while let Some(end) = remaining
+                    .iter()
+                    .position(|ce| ce.primary() != 0 && ce.primary() <= MERGE_SEPARATOR_PRIMARY)
+                {
+                    let (segment, rest) = remaining.split_at(end);
+                    for ce in segment.iter().rev() {
+                        let secondary = ce.secondary();
+                        if secondary != 0 {
+                            key.extend_from_slice(&secondary.to_be_bytes());
+                        }
+                    }
+                    key.extend_from_slice(&NO_CE_SECONDARY.to_be_bytes());
+                    remaining = rest.get(1..).unwrap_or_default();
+                }
+            }
+        }
+
+        // Case level
+        if self.options.case_level() {
+            for ce in ces.iter() {
+                let non_primary = ce.non_primary();
+                let included = if self.options.strength() == Strength::Primary {
+                    // Ignore case level weights of primary ignorables, see `compare_impl()`.
+                    !ce.either_half_zero()
+                } else {
+                    // Ignore case level weights of secondary ignorables, see `compare_impl()`.
+                    non_primary.secondary() != 0
+                };
+                if included {
+                    // The case bits are the high two bits.
+                    let case = (non_primary.case() >> 14) as u8;
+                    key.push(if self.options.upper_first() {
+                        !case
+                    } else {
+                        case
+                    });
+                }
+            }
+        }
+
+        // Tertiary level
+        if let Some(tertiary_mask) = self.options.tertiary_mask() {
+            for ce in ces.iter() {
+                let non_primary = ce.non_primary();
+                let mut tertiary = non_primary.tertiary_case_quarternary(tertiary_mask);
+                if tertiary == 0 {
+                    continue;
+                }
+                if self.options.upper_first() && tertiary > NO_CE_TERTIARY {
+                    // Magic numbers from ICU4C, see `compare_impl()`.
+                    if non_primary.secondary() != 0 {
+                        tertiary ^= 0xC000;
+                    } else {
+                        tertiary += 0x4000;
+                    }
+                }
+                key.extend_from_slice(&tertiary.to_be_bytes());
+            }
+        }
+
+        // Quaternary level
+        if self.options.strength() > Strength::Tertiary {
+            for ce in ces.iter() {
+                let mut quaternary = if ce.tertiary_ignorable() {
+                    ce.primary()
+                } else {
+                    ce.quaternary()
+                };
+                if quaternary == 0 {
+                    continue;
+                }
+                if let Some(reordering) = &self.reordering {
+                    quaternary = reordering.get().reorder(quaternary);
+                }
+                key.extend_from_slice(&quaternary.to_be_bytes());
+            }
+        }
+
+        // Identical level
+        if self.options.strength() == Strength::Identical {
+            for c in Decomposition::new(chars, self.decompositions.get(), self.tables.get()) {
+                key.extend_from_slice(&u32::from(c).to_be_bytes()[1..]);
+            }
+        }
+
+        key
+    }
+
+    /// Returns an iterator over the primary weights of the string that `compare_impl()`
+    /// compares, without reordering. The collation elements are only computed as far as the
+    /// iterator is advanced.
+    ///
+    /// Primary ignorables and variable CEs that are shifted to the quaternary level have no
+    /// primary weight.
+    #[cfg(feature = "experimental")]
+    pub(crate) fn primary_weights<'a, I: Iterator<Item = char> + 'a>(
+        &'a self,
+        chars: I,
+    ) -> impl Iterator<Item = u32> + 'a {
+        let variable_top = self.variable_top();
+        let mut iter = self.collation_elements(chars);
+        core::iter::from_fn(move || loop {
+            let primary = iter.next().primary();
+            if primary == NO_CE_PRIMARY {
+                return None;
+            }
+            // Skip primary ignorables and variable CEs shifted to the quaternary level.
+            if primary != 0 && !(primary < variable_top && primary > MERGE_SEPARATOR_PRIMARY) {
+                return Some(primary);
+            }
+        })
+        .fuse()
+    }
+
+    /// The primary weight above the variable CEs that are shifted to the quaternary level, or 0
+    /// if variable CEs are not shifted.
+    // Attribute belongs closer to `unwrap`, but
+    // https://github.com/rust-lang/rust/issues/15701
+    #[allow(clippy::unwrap_used)]
+    fn variable_top(&self) -> u32 {
+        if self.options.alternate_handling() == AlternateHandling::NonIgnorable {
+            0
+        } else {
+            // +1 so that we can use "<" and primary ignorables test out early.
+            self.special_primaries
+                .as_ref()
+                // `unwrap()` is OK, because we've ensured in the constructor that value
+                // is `Some` if we have alternate handling.
+                .unwrap()
+                .get()
+                .last_primary_for_group(self.options.max_variable())
+                + 1
+        }
+    }
+
+    /// Creates the iterator over the collation elements of a string.
+    // Attribute belongs on inner expressions, but
+    // https://github.com/rust-lang/rust/issues/15701
+    #[allow(clippy::unwrap_used)]
+    fn collation_elements<I: Iterator<Item = char>>(&self, chars: I) -> CollationElements<'_, I> {
+        let tailoring: &DataPayload<CollationDataV1Marker> =
+            if let Some(tailoring) = &self.tailoring {
+                tailoring
+            } else {
+                &self.root
+            };
+
+        let numeric_primary = if self.options.numeric() {
+            Some(
+                self.special_primaries
+                    .as_ref()
+                    // `unwrap` is OK, because we've ensured `Some` in the constructor
+                    .unwrap()
+                    .get()
+                    .numeric_primary,
+            )
+        } else {
+            None
+        };
+
+        CollationElements::new(
+            chars,
+            self.root.get(),
+            tailoring.get(),
+            <&[<u32 as AsULE>::ULE; JAMO_COUNT]>::try_from(self.jamo.get().ce32s.as_ule_slice())
+                .unwrap(), // `unwrap` OK, because length already validated
+            &self.diacritics.get().secondaries,
+            self.decompositions.get(),
+            self.tables.get(),
+            numeric_primary,
+            self.lithuanian_dot_above,
+        )
+    }
 }
@@ -54,7 +54,7 @@ const SPECIAL_NON_STARTER_DECOMPOSITION_MARKER_U16: u16 = 2;
 
 /// Checks if a trie value carries a (non-zero) canonical
 /// combining class.
-fn trie_value_has_ccc(trie_value: u32) -> bool {
+pub(crate) fn trie_value_has_ccc(trie_value: u32) -> bool {
     (trie_value & 0xFFFFFF00) == 0xD800
 }
 
@@ -1301,26 +1301,21 @@ where
                             ce32 = data.ce32_for_char(c);
                         }
                     } else {
-                        // The purpose of reading the CE32 from the jamo table instead
-                        // of the trie even in this case is to make it unnecessary
-                        // for all search collation tries to carry a copy of the Hangul
-                        // part of the search root. Instead, all non-Korean tailorings
-                        // can use a shared copy of the non-Korean search jamo table.
-                        //
-                        // TODO(#1941): This isn't actually true with the current jamo
-                        // search expansions!
-
-                        // TODO(#1941): Instead of having different jamo CE32 table for
-                        // "search" collations, we could instead decompose the archaic
-                        // jamo to the modern approximation sequences here and then map
-                        // those by looking up the modern jamo from the normal root.
-
-                        // We need to set data to root, because archaic jamo refer to
-                        // the root.
-                        data = self.root;
                         // Index in range by construction above. Not using `get` with
                         // `if let` in order to put the likely branch first.
                         ce32 = CollationElement32::new_from_ule(self.jamo[jamo_index]);
+                        // Self-contained CE32s don't refer to the data. The others come
+                        // from the tailoring if it maps the jamo, such as the expansions
+                        // of the search collations, and from the root otherwise.
+                        if ce32.to_ce_self_contained().is_none() && !core::ptr::eq(data, self.root)
+                        {
+                            let tailored = data.ce32_for_char(c);
+                            if tailored == FALLBACK_CE32 {
+                                data = self.root;
+                            } else {
+                                ce32 = tailored;
+                            }
+                        }
                     }
                     if self.is_next_decomposition_starts_with_starter() {
                         if let Some(ce) = ce32.to_ce_simple_or_long_primary() {
@@ -1555,12 +1550,53 @@ where
 
                 // No prefix matches on Hangul
                 self.mark_prefix_unmatchable();
+
+                // Jamo that are mapped to expansions, such as in the search collations,
+                // are collated as separate characters.
+                //
+                // Indexing OK, because indices in range by construction
+                #[allow(clippy::indexing_slicing)]
+                if CollationElement32::new_from_ule(self.jamo[l as usize])
+                    .to_ce_self_contained()
+                    .is_none()
+                    || CollationElement32::new_from_ule(
+                        self.jamo[(HANGUL_V_BASE - HANGUL_L_BASE + v) as usize],
+                    )
+                    .to_ce_self_contained()
+                    .is_none()
+                    || (t != 0
+                        && CollationElement32::new_from_ule(
+                            self.jamo[(HANGUL_T_BASE - HANGUL_L_BASE + t) as usize],
+                        )
+                        .to_ce_self_contained()
+                        .is_none())
+                {
+                    if t != 0 {
+                        self.upcoming.insert(
+                            0,
+                            CharacterAndClassAndTrieValue::new_with_non_decomposing_starter(
+                                char_from_u32(HANGUL_T_BASE + t),
+                            ),
+                        );
+                    }
+                    self.upcoming.insert(
+                        0,
+                        CharacterAndClassAndTrieValue::new_with_non_decomposing_starter(
+                            char_from_u32(HANGUL_V_BASE + v),
+                        ),
+                    );
+                    self.upcoming.insert(
+                        0,
+                        CharacterAndClassAndTrieValue::new_with_non_decomposing_starter(
+                            char_from_u32(HANGUL_L_BASE + l),
+                        ),
+                    );
+                    return self.next();
+                }
+
                 // Indexing OK, because indices in range by construction
                 #[allow(clippy::indexing_slicing)]
                 if self.is_next_decomposition_starts_with_starter() {
-                    // TODO(#1941): Assuming self-contained CE32s is OK for the root,
-                    // but not currently OK for search collation, which at this time
-                    // do not support tailored Hangul.
                     self.pending.push(
                         CollationElement32::new_from_ule(
                             self.jamo[(HANGUL_V_BASE - HANGUL_L_BASE + v) as usize],
@@ -21,6 +21,13 @@ pub enum CollatorError {
     /// An error originating inside of the data provider.
     #[displaydoc("{0}")]
     Data(DataError),
+    /// The collation rules are malformed at the given byte offset
+    #[displaydoc("Invalid collation rules at offset {0}")]
+    InvalidRules(usize),
+    /// The collation rules use syntax or tailor characters that the rule
+    /// builder does not support at the given byte offset
+    #[displaydoc("Unsupported collation rule at offset {0}")]
+    UnsupportedRules(usize),
 }
 
 #[cfg(feature = "std")]
@@ -40,3 +47,13 @@ impl From<PropertiesError> for CollatorError {
         }
     }
 }
+
+#[cfg(feature = "experimental")]
+impl From<icu_segmenter::SegmenterError> for CollatorError {
+    fn from(e: icu_segmenter::SegmenterError) -> Self {
+        match e {
+            icu_segmenter::SegmenterError::Data(d) => CollatorError::Data(d),
+            _ => unreachable!("Shouldn't have non-Data SegmenterError"),
+        }
+    }
+}
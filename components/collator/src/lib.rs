@@ -28,7 +28,14 @@
 //! and as part of the [`icu`](https://docs.rs/icu/latest/icu/) crate. See the latter for more details on the ICU4X project.
 //! `Collator` is the main structure of the component. It accepts a set of arguments
 //! which allow it to collect necessary data from the data provider, and once
-//! instantiated, can be used to compare strings.
+//! instantiated, can be used to compare strings or to compute their sort keys.
+//!
+//! With the "experimental" feature, `CollatorSearch` finds the matches of a string in a text
+//! with the same notion of equality, e.g. ignoring accents or case.
+//!
+//! With the "experimental" feature, a `Collator` can also be built at runtime from rules in the
+//! ICU collation rule syntax, e.g. `"&c < ch <<< Ch"`, with
+//! `Collator::try_new_with_rules_unstable`, besides the tailorings that come with the data.
 //!
 //! Refer to the ICU User Guide sections for Collation that give an
 //! [introduction](https://unicode-org.github.io/icu/userguide/collation/) and explain
@@ -301,6 +308,8 @@
 //! assert_eq!(collator_num_on.compare("a10b", "a2b"), Ordering::Greater);
 //! ```
 
+#[cfg(feature = "experimental")]
+mod builder;
 mod comparison;
 #[cfg(doc)]
 pub mod docs;
@@ -314,6 +323,10 @@ mod elements;
 mod error;
 mod options;
 pub mod provider;
+#[cfg(feature = "experimental")]
+mod rules;
+#[cfg(feature = "experimental")]
+mod search;
 
 extern crate alloc;
 
@@ -327,6 +340,8 @@ pub use options::CollatorOptions;
 pub use options::MaxVariable;
 pub use options::Numeric;
 pub use options::Strength;
+#[cfg(feature = "experimental")]
+pub use search::CollatorSearch;
 
 #[doc(inline)]
 pub use CollatorError as Error;
@@ -121,7 +121,7 @@ impl<'data> CollationDataV1<'data> {
         debug_assert!(false);
         SINGLE_U64
     }
-    fn get_default_and_trie_impl(
+    pub(crate) fn get_default_and_trie_impl(
         &'data self,
         index: usize,
     ) -> (CollationElement32, &'data ZeroSlice<u16>) {
@@ -190,7 +190,16 @@ pub struct CollationDiacriticsV1<'data> {
 }
 
 /// `CollationElement32`s for the Hangul Jamo Unicode Block
-#[icu_provider::data_struct(CollationJamoV1Marker = "collator/jamo@1")]
+///
+/// The root collation and the tailorings that map jamo differently, such as the search
+/// collations, have their own table.
+#[icu_provider::data_struct(marker(
+    CollationJamoV1Marker,
+    "collator/jamo@1",
+    extension_key = "co",
+    fallback_by = "collation",
+    fallback_supplement = "collation"
+))]
 #[derive(Debug, PartialEq, Clone)]
 #[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake), databake(path = icu_collator::provider))]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize))]
@@ -0,0 +1,456 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// Various collation-related algorithms and constants in this file are
+// adapted from ICU4C and, therefore, are subject to the ICU license as
+// described in LICENSE.
+
+//! This module holds the parser for the ICU collation rule syntax, which
+//! feeds the resets, relations and settings of the rules to a
+//! `TailoringBuilder`.
+//!
+//! See <https://unicode-org.github.io/icu/userguide/collation/customization/>
+//! for the syntax. Not supported are quaternary relations, prefixes
+//! (`|`), special reset positions such as `[first tertiary ignorable]`,
+//! and the `[reorder]`, `[import]` and `[suppressContractions]` settings.
+
+use crate::builder::TailoringBuilder;
+use crate::error::CollatorError;
+use crate::{AlternateHandling, CaseFirst, MaxVariable, Strength};
+use alloc::string::String;
+
+/// Parses `rules` and adds them to `builder`.
+pub(crate) fn parse(rules: &str, builder: &mut TailoringBuilder) -> Result<(), CollatorError> {
+    RuleParser {
+        rules,
+        pos: 0,
+        builder,
+    }
+    .parse()
+}
+
+/// Whether `c` is Pattern_White_Space.
+fn is_white_space(c: char) -> bool {
+    matches!(
+        c,
+        '\t' | '\n'
+            | '\u{000B}'
+            | '\u{000C}'
+            | '\r'
+            | ' '
+            | '\u{0085}'
+            | '\u{200E}'
+            | '\u{200F}'
+            | '\u{2028}'
+            | '\u{2029}'
+    )
+}
+
+/// Whether `c` is an ASCII character that needs quoting in a rule string.
+fn is_syntax_char(c: char) -> bool {
+    ('\u{21}'..='\u{7E}').contains(&c) && !c.is_ascii_alphanumeric()
+}
+
+struct RuleParser<'a, 'b, 'data> {
+    rules: &'a str,
+    /// The byte offset of the next character.
+    pos: usize,
+    builder: &'b mut TailoringBuilder<'data>,
+}
+
+impl<'a, 'b, 'data> RuleParser<'a, 'b, 'data> {
+    fn parse(&mut self) -> Result<(), CollatorError> {
+        loop {
+            self.skip_white_space();
+            match self.peek() {
+                None => return Ok(()),
+                Some('&') => self.parse_rule_chain()?,
+                Some('[') => self.parse_setting()?,
+                Some('#') => self.skip_comment(),
+                Some('@') => {
+                    // Same as [backwards 2]
+                    self.builder.options.set_backward_second_level(Some(true));
+                    self.pos += 1;
+                }
+                Some('!') => {
+                    // Legacy Thai/Lao reversal, handled by the root collation.
+                    self.pos += 1;
+                }
+                Some(_) => return Err(CollatorError::InvalidRules(self.pos)),
+            }
+        }
+    }
+
+    #[allow(clippy::indexing_slicing)] // `pos` is always at a character boundary
+    fn peek(&self) -> Option<char> {
+        self.rules[self.pos..].chars().next()
+    }
+
+    fn skip_white_space(&mut self) {
+        while let Some(c) = self.peek() {
+            if !is_white_space(c) {
+                break;
+            }
+            self.pos += c.len_utf8();
+        }
+    }
+
+    fn skip_comment(&mut self) {
+        while let Some(c) = self.peek() {
+            self.pos += c.len_utf8();
+            if matches!(
+                c,
+                '\n' | '\u{000C}' | '\r' | '\u{0085}' | '\u{2028}' | '\u{2029}'
+            ) {
+                break;
+            }
+        }
+    }
+
+    /// Parses a reset followed by one or more relations.
+    fn parse_rule_chain(&mut self) -> Result<(), CollatorError> {
+        let reset_offset = self.pos;
+        // Skip '&'
+        self.pos += 1;
+        self.skip_white_space();
+        let before = if self.peek() == Some('[') {
+            Some(self.parse_before()?)
+        } else {
+            None
+        };
+        self.skip_white_space();
+        let reset = self.parse_string()?;
+        if reset.is_empty() {
+            return Err(CollatorError::InvalidRules(self.pos));
+        }
+        self.builder.add_reset(reset_offset, before, &reset)?;
+
+        let mut first = true;
+        loop {
+            self.skip_white_space();
+            let offset = self.pos;
+            let (strength, starred) = match self.parse_relation_operator()? {
+                Some(operator) => operator,
+                None => break,
+            };
+            if let Some(before) = before {
+                // The relations after [before n] must start at level n and
+                // may not get stronger.
+                if (first && strength != before) || strength < before {
+                    return Err(CollatorError::InvalidRules(offset));
+                }
+            }
+            if starred {
+                self.parse_starred_relation(offset, strength)?;
+            } else {
+                self.parse_relation(offset, strength)?;
+            }
+            first = false;
+        }
+        if first {
+            // A reset without any relation
+            return Err(CollatorError::InvalidRules(reset_offset));
+        }
+        Ok(())
+    }
+
+    /// Parses `[before 1]`, `[before 2]` or `[before 3]` after the `&` of a reset.
+    fn parse_before(&mut self) -> Result<Strength, CollatorError> {
+        let start = self.pos;
+        #[allow(clippy::indexing_slicing)] // `pos` is always at a character boundary
+        let rest = &self.rules[start..];
+        if let Some(after) = rest.strip_prefix("[before") {
+            let digits = after.trim_start_matches(is_white_space);
+            let strength = match digits.as_bytes().first() {
+                Some(b'1') => Strength::Primary,
+                Some(b'2') => Strength::Secondary,
+                Some(b'3') => Strength::Tertiary,
+                _ => return Err(CollatorError::InvalidRules(start)),
+            };
+            if digits.as_bytes().get(1) != Some(&b']') {
+                return Err(CollatorError::InvalidRules(start));
+            }
+            self.pos = self.rules.len() - digits.len() + 2;
+            Ok(strength)
+        } else {
+            // Special reset positions such as [first tertiary ignorable]
+            Err(CollatorError::UnsupportedRules(start))
+        }
+    }
+
+    /// Parses a relation operator and returns its strength and whether it is starred,
+    /// or `None` if there is no relation operator at the current position.
+    fn parse_relation_operator(&mut self) -> Result<Option<(Strength, bool)>, CollatorError> {
+        let offset = self.pos;
+        let strength = match self.peek() {
+            Some('<') => {
+                #[allow(clippy::indexing_slicing)] // `pos` is always at a character boundary
+                let count = self.rules[offset..]
+                    .bytes()
+                    .take_while(|&b| b == b'<')
+                    .take(4)
+                    .count();
+                self.pos += count;
+                match count {
+                    1 => Strength::Primary,
+                    2 => Strength::Secondary,
+                    3 => Strength::Tertiary,
+                    _ => return Err(CollatorError::UnsupportedRules(offset)),
+                }
+            }
+            Some(';') => {
+                self.pos += 1;
+                return Ok(Some((Strength::Secondary, false)));
+            }
+            Some(',') => {
+                self.pos += 1;
+                return Ok(Some((Strength::Tertiary, false)));
+            }
+            Some('=') => {
+                self.pos += 1;
+                Strength::Identical
+            }
+            _ => return Ok(None),
+        };
+        let starred = self.peek() == Some('*');
+        if starred {
+            self.pos += 1;
+        }
+        Ok(Some((strength, starred)))
+    }
+
+    /// Parses the string (with an optional extension) of a relation.
+    fn parse_relation(&mut self, offset: usize, strength: Strength) -> Result<(), CollatorError> {
+        self.skip_white_space();
+        let string = self.parse_string()?;
+        if string.is_empty() {
+            return Err(CollatorError::InvalidRules(self.pos));
+        }
+        self.skip_white_space();
+        if self.peek() == Some('|') {
+            // Prefix
+            return Err(CollatorError::UnsupportedRules(self.pos));
+        }
+        let mut extension = String::new();
+        if self.peek() == Some('/') {
+            self.pos += 1;
+            self.skip_white_space();
+            extension = self.parse_string()?;
+            if extension.is_empty() {
+                return Err(CollatorError::InvalidRules(self.pos));
+            }
+        }
+        self.builder
+            .add_relation(offset, strength, &string, &extension)
+    }
+
+    /// Parses the characters and ranges of a starred relation, each of which is a
+    /// separate relation.
+    fn parse_starred_relation(
+        &mut self,
+        offset: usize,
+        strength: Strength,
+    ) -> Result<(), CollatorError> {
+        let mut previous: Option<char> = None;
+        loop {
+            self.skip_white_space();
+            let string = self.parse_string()?;
+            if string.is_empty() {
+                return Err(CollatorError::InvalidRules(self.pos));
+            }
+            let mut chars = string.chars();
+            if let Some(start) = previous.take() {
+                // The end of a range
+                let end = chars.next().unwrap_or(start);
+                if end < start {
+                    return Err(CollatorError::InvalidRules(offset));
+                }
+                for c in (u32::from(start) + 1..=u32::from(end)).filter_map(char::from_u32) {
+                    self.add_starred_char(offset, strength, c)?;
+                }
+            }
+            for c in chars {
+                self.add_starred_char(offset, strength, c)?;
+                previous = Some(c);
+            }
+            if self.peek() != Some('-') {
+                return Ok(());
+            }
+            if previous.is_none() {
+                // A range without a start
+                return Err(CollatorError::InvalidRules(self.pos));
+            }
+            self.pos += 1;
+        }
+    }
+
+    fn add_starred_char(
+        &mut self,
+        offset: usize,
+        strength: Strength,
+        c: char,
+    ) -> Result<(), CollatorError> {
+        if !self.builder.is_inert(c) {
+            return Err(CollatorError::InvalidRules(offset));
+        }
+        self.builder
+            .add_relation(offset, strength, c.encode_utf8(&mut [0u8; 4]), "")
+    }
+
+    /// Parses a string, which ends at unquoted white space or syntax characters.
+    fn parse_string(&mut self) -> Result<String, CollatorError> {
+        let mut string = String::new();
+        while let Some(c) = self.peek() {
+            if is_white_space(c) {
+                break;
+            }
+            if !is_syntax_char(c) {
+                string.push(c);
+                self.pos += c.len_utf8();
+                continue;
+            }
+            match c {
+                '\'' => {
+                    let start = self.pos;
+                    self.pos += 1;
+                    if self.peek() == Some('\'') {
+                        // '' is an apostrophe.
+                        string.push('\'');
+                        self.pos += 1;
+                        continue;
+                    }
+                    // Quoted literal text
+                    loop {
+                        let c = self.peek().ok_or(CollatorError::InvalidRules(start))?;
+                        self.pos += c.len_utf8();
+                        if c == '\'' {
+                            if self.peek() == Some('\'') {
+                                self.pos += 1;
+                            } else {
+                                break;
+                            }
+                        }
+                        string.push(c);
+                    }
+                }
+                '\\' => {
+                    let start = self.pos;
+                    self.pos += 1;
+                    let c = self.peek().ok_or(CollatorError::InvalidRules(start))?;
+                    string.push(c);
+                    self.pos += c.len_utf8();
+                }
+                _ => break,
+            }
+        }
+        Ok(string)
+    }
+
+    /// Parses a `[setting value]` at the top level.
+    fn parse_setting(&mut self) -> Result<(), CollatorError> {
+        let start = self.pos;
+        self.pos += 1;
+        let words = self.read_words();
+        let invalid = || CollatorError::InvalidRules(start);
+        let unsupported = || CollatorError::UnsupportedRules(start);
+        if self.peek() == Some('[') {
+            // A setting whose value is a set of characters
+            match words.as_str() {
+                "optimize" => {
+                    // Only a performance hint
+                    self.skip_set().ok_or_else(invalid)?;
+                }
+                "suppressContractions" => return Err(unsupported()),
+                _ => return Err(invalid()),
+            }
+        } else {
+            let (name, value) = words.split_once(' ').unwrap_or((&words, ""));
+            let options = &mut self.builder.options;
+            match (name, value) {
+                ("strength", "1") => options.set_strength(Some(Strength::Primary)),
+                ("strength", "2") => options.set_strength(Some(Strength::Secondary)),
+                ("strength", "3") => options.set_strength(Some(Strength::Tertiary)),
+                ("strength", "4") => options.set_strength(Some(Strength::Quaternary)),
+                ("strength", "I") => options.set_strength(Some(Strength::Identical)),
+                ("alternate", "non-ignorable") => {
+                    options.set_alternate_handling(Some(AlternateHandling::NonIgnorable))
+                }
+                ("alternate", "shifted") => {
+                    options.set_alternate_handling(Some(AlternateHandling::Shifted))
+                }
+                ("backwards", "2") => options.set_backward_second_level(Some(true)),
+                ("caseFirst", "off") => options.set_case_first(Some(CaseFirst::Off)),
+                ("caseFirst", "lower") => options.set_case_first(Some(CaseFirst::LowerFirst)),
+                ("caseFirst", "upper") => options.set_case_first(Some(CaseFirst::UpperFirst)),
+                ("caseLevel", "on") => options.set_case_level(Some(true)),
+                ("caseLevel", "off") => options.set_case_level(Some(false)),
+                ("numericOrdering", "on") => options.set_numeric(Some(true)),
+                ("numericOrdering", "off") => options.set_numeric(Some(false)),
+                ("maxVariable", "space") => options.set_max_variable(Some(MaxVariable::Space)),
+                ("maxVariable", "punct") => {
+                    options.set_max_variable(Some(MaxVariable::Punctuation))
+                }
+                ("maxVariable", "symbol") => options.set_max_variable(Some(MaxVariable::Symbol)),
+                ("maxVariable", "currency") => {
+                    options.set_max_variable(Some(MaxVariable::Currency))
+                }
+                // The collator always normalizes its input.
+                ("normalization", "on" | "off") | ("hiraganaQ", "off") => {}
+                ("hiraganaQ", "on") | ("reorder", _) | ("import", _) => return Err(unsupported()),
+                _ => return Err(invalid()),
+            }
+        }
+        if self.peek() != Some(']') {
+            return Err(invalid());
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    /// Reads white-space-separated words up to a syntax character other than `-` and `_`,
+    /// collapsing the white space between words into single spaces.
+    fn read_words(&mut self) -> String {
+        let mut words = String::new();
+        self.skip_white_space();
+        while let Some(c) = self.peek() {
+            if is_syntax_char(c) && c != '-' && c != '_' {
+                break;
+            }
+            if is_white_space(c) {
+                words.push(' ');
+                self.skip_white_space();
+                continue;
+            }
+            words.push(c);
+            self.pos += c.len_utf8();
+        }
+        if words.ends_with(' ') {
+            words.pop();
+        }
+        words
+    }
+
+    /// Skips a bracketed set such as `[a-z[:Hani:]]`.
+    fn skip_set(&mut self) -> Option<()> {
+        let mut depth = 0usize;
+        loop {
+            let c = self.peek()?;
+            self.pos += c.len_utf8();
+            match c {
+                '\\' => {
+                    let c = self.peek()?;
+                    self.pos += c.len_utf8();
+                }
+                '[' => depth += 1,
+                ']' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(());
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
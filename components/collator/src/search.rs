@@ -0,0 +1,223 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module holds the `CollatorSearch` struct, which finds the matches of a pattern
+//! in a text according to a `Collator`.
+
+use crate::comparison::Collator;
+use crate::error::CollatorError;
+use crate::provider::CollationDataV1Marker;
+use crate::provider::CollationDiacriticsV1Marker;
+use crate::provider::CollationJamoV1Marker;
+use crate::provider::CollationMetadataV1Marker;
+use crate::provider::CollationReorderingV1Marker;
+use crate::provider::CollationSpecialPrimariesV1Marker;
+use crate::CollatorOptions;
+use alloc::vec::Vec;
+use core::cell::Cell;
+use core::ops::Range;
+use icu_normalizer::provider::CanonicalDecompositionDataV1Marker;
+use icu_normalizer::provider::CanonicalDecompositionTablesV1Marker;
+use icu_provider::prelude::*;
+use icu_segmenter::provider::GraphemeClusterBreakDataV1Marker;
+use icu_segmenter::GraphemeClusterBreakSegmenter;
+use utf16_iter::Utf16CharsEx;
+use utf8_iter::Utf8CharsEx;
+
+/// Finds the matches of a pattern in a text according to culturally-relevant equivalence,
+/// e.g. for a "find on page" feature.
+///
+/// A range of the text matches the pattern if the [`Collator`] with the same locale and
+/// options considers them equal, so the strength of the options determines whether the
+/// search ignores differences in accents or case. Matches start and end at grapheme
+/// cluster boundaries, so that a pattern never matches part of an accented letter, and are
+/// as short as possible. The matches don't overlap.
+///
+/// Many languages have a dedicated search collation, which can be requested with the
+/// "-u-co-search" Unicode extension of the locale. For Korean, the search collation also
+/// makes archaic jamo match their modern approximations.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// </div>
+///
+/// # Examples
+///
+/// ```
+/// use icu_collator::*;
+/// use icu_locid::locale;
+///
+/// let mut options = CollatorOptions::new();
+/// options.strength = Some(Strength::Primary);
+/// let search = CollatorSearch::try_new_unstable(
+///     &icu_testdata::unstable(),
+///     &locale!("en").into(),
+///     options,
+/// )
+/// .unwrap();
+///
+/// let text = "Résumé: resume writing";
+/// let matches = search.find_all_str("resume", text);
+/// assert_eq!(matches, [0..8, 10..16]);
+/// assert_eq!(&text[matches[0].clone()], "Résumé");
+///
+/// // Spanish has the separate letter "ñ", which doesn't match "n".
+/// let search = CollatorSearch::try_new_unstable(
+///     &icu_testdata::unstable(),
+///     &locale!("es").into(),
+///     options,
+/// )
+/// .unwrap();
+/// assert_eq!(search.find_all_str("n", "Año nuevo"), [5..6]);
+/// ```
+pub struct CollatorSearch {
+    collator: Collator,
+    segmenter: GraphemeClusterBreakSegmenter,
+}
+
+impl CollatorSearch {
+    /// Instantiates a search for a given locale with the given collator options.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        options: CollatorOptions,
+    ) -> Result<Self, CollatorError>
+    where
+        D: DataProvider<CollationSpecialPrimariesV1Marker>
+            + DataProvider<CollationDataV1Marker>
+            + DataProvider<CollationDiacriticsV1Marker>
+            + DataProvider<CollationJamoV1Marker>
+            + DataProvider<CollationMetadataV1Marker>
+            + DataProvider<CollationReorderingV1Marker>
+            + DataProvider<CanonicalDecompositionDataV1Marker>
+            + DataProvider<CanonicalDecompositionTablesV1Marker>
+            + DataProvider<GraphemeClusterBreakDataV1Marker>
+            + ?Sized,
+    {
+        Ok(Self {
+            collator: Collator::try_new_unstable(data_provider, locale, options)?,
+            segmenter: GraphemeClusterBreakSegmenter::try_new_unstable(data_provider)?,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        options: CollatorOptions,
+        error: CollatorError
+    );
+
+    /// Finds the matches of a guaranteed well-formed UTF-8 pattern in a guaranteed
+    /// well-formed UTF-8 text, as byte ranges of the text.
+    pub fn find_all_str(&self, pattern: &str, text: &str) -> Vec<Range<usize>> {
+        let boundaries: Vec<usize> = self.segmenter.segment_str(text).collect();
+        self.find_all_impl(pattern.chars(), &boundaries, |range| {
+            text.get(range).unwrap_or_default().chars()
+        })
+    }
+
+    /// Finds the matches of a potentially ill-formed UTF-8 pattern in a potentially
+    /// ill-formed UTF-8 text, as byte ranges of the text. Ill-formed input is treated as if
+    /// errors had been replaced with REPLACEMENT CHARACTERs according to the WHATWG Encoding
+    /// Standard.
+    pub fn find_all_utf8(&self, pattern: &[u8], text: &[u8]) -> Vec<Range<usize>> {
+        let boundaries: Vec<usize> = self.segmenter.segment_utf8(text).collect();
+        self.find_all_impl(pattern.chars(), &boundaries, |range| {
+            text.get(range).unwrap_or_default().chars()
+        })
+    }
+
+    /// Finds the matches of a potentially ill-formed UTF-16 pattern in a potentially
+    /// ill-formed UTF-16 text, as code unit ranges of the text. Unpaired surrogates are
+    /// treated as if each one was a REPLACEMENT CHARACTER.
+    pub fn find_all_utf16(&self, pattern: &[u16], text: &[u16]) -> Vec<Range<usize>> {
+        let boundaries: Vec<usize> = self.segmenter.segment_utf16(text).collect();
+        self.find_all_impl(pattern.chars(), &boundaries, |range| {
+            text.get(range).unwrap_or_default().chars()
+        })
+    }
+
+    /// Finds the matches given the grapheme cluster boundaries of the text, including its
+    /// start and end, and a function that returns the characters of a range of the text.
+    ///
+    /// Candidate matches are first filtered by their primary collation elements, which are
+    /// equal for all strengths, and then compared in full by their sort keys. The collation
+    /// elements are computed once per start, and the characters read to find the primary
+    /// weight following the ones of the pattern bound the ends of the candidate matches.
+    fn find_all_impl<I, J, F>(&self, pattern: I, boundaries: &[usize], text: F) -> Vec<Range<usize>>
+    where
+        I: Iterator<Item = char> + Clone,
+        J: Iterator<Item = char> + Clone,
+        F: Fn(Range<usize>) -> J,
+    {
+        let mut matches = Vec::new();
+        let pattern_primaries: Vec<u32> = self.collator.primary_weights(pattern.clone()).collect();
+        if pattern_primaries.is_empty() {
+            // A pattern without primary weights would match anywhere.
+            return matches;
+        }
+        let pattern_key = self.collator.sort_key_impl(pattern);
+        let end = boundaries.last().copied().unwrap_or_default();
+
+        // The number of characters of the text before each boundary
+        let mut char_offsets = Vec::with_capacity(boundaries.len());
+        let mut chars = 0;
+        let mut previous = boundaries.first().copied().unwrap_or_default();
+        for &boundary in boundaries {
+            chars += text(previous..boundary).count();
+            char_offsets.push(chars);
+            previous = boundary;
+        }
+
+        let mut i = 0;
+        while let (Some(&start), Some(&start_chars)) = (boundaries.get(i), char_offsets.get(i)) {
+            i += 1;
+            let chars_read = Cell::new(start_chars);
+            let mut primaries = self.collator.primary_weights(
+                text(start..end).inspect(|_| chars_read.set(chars_read.get() + 1)),
+            );
+            if !pattern_primaries
+                .iter()
+                .all(|&primary| primaries.next() == Some(primary))
+            {
+                continue;
+            }
+            // Matches end before the character of the following primary weight.
+            primaries.next();
+            let max_chars = chars_read.get();
+            // Matches don't start with ignorable grapheme clusters.
+            if let Some(&next) = boundaries.get(i) {
+                if self
+                    .collator
+                    .primary_weights(text(start..next))
+                    .next()
+                    .is_none()
+                {
+                    continue;
+                }
+            }
+            // Find the shortest match.
+            for (j, (&limit, &limit_chars)) in
+                boundaries.iter().zip(&char_offsets).enumerate().skip(i)
+            {
+                if limit_chars > max_chars {
+                    break;
+                }
+                if self.collator.sort_key_impl(text(start..limit)) == pattern_key {
+                    matches.push(start..limit);
+                    // Matches don't overlap.
+                    i = j;
+                    break;
+                }
+            }
+        }
+        matches
+    }
+}
@@ -1404,6 +1404,481 @@ fn test_conformance_non_ignorable() {
     }
 }
 
+#[test]
+fn test_sort_key_conformance() {
+    let dicts: [&[u8]; 2] = [
+        include_bytes!("data/CollationTest_CLDR_SHIFTED.txt"),
+        include_bytes!("data/CollationTest_CLDR_NON_IGNORABLE.txt"),
+    ];
+    let strings: Vec<StackString> = dicts
+        .iter()
+        .flat_map(|dict| dict.split(|b| b == &b'\n'))
+        .filter(|line| !line.is_empty() && !line.starts_with(&[b'#']))
+        .filter_map(parse_hex)
+        .step_by(61)
+        .collect();
+
+    let mut option_sets = Vec::new();
+    for strength in [
+        Strength::Primary,
+        Strength::Secondary,
+        Strength::Tertiary,
+        Strength::Quaternary,
+        Strength::Identical,
+    ] {
+        for alternate_handling in [AlternateHandling::NonIgnorable, AlternateHandling::Shifted] {
+            let mut options = CollatorOptions::new();
+            options.strength = Some(strength);
+            options.alternate_handling = Some(alternate_handling);
+            option_sets.push(options);
+        }
+    }
+    for case_first in [CaseFirst::LowerFirst, CaseFirst::UpperFirst] {
+        for case_level in [CaseLevel::Off, CaseLevel::On] {
+            for strength in [Strength::Primary, Strength::Secondary, Strength::Tertiary] {
+                let mut options = CollatorOptions::new();
+                options.strength = Some(strength);
+                options.case_first = Some(case_first);
+                options.case_level = Some(case_level);
+                option_sets.push(options);
+            }
+        }
+    }
+    let mut options = CollatorOptions::new();
+    options.backward_second_level = Some(BackwardSecondLevel::On);
+    option_sets.push(options);
+    let mut options = CollatorOptions::new();
+    options.numeric = Some(Numeric::On);
+    option_sets.push(options);
+
+    for options in option_sets {
+        let collator: Collator =
+            Collator::try_new_unstable(&icu_testdata::unstable(), &Default::default(), options)
+                .unwrap();
+        let keys: Vec<Vec<u8>> = strings.iter().map(|s| collator.sort_key(s)).collect();
+        for (i, (left, left_key)) in strings.iter().zip(&keys).enumerate() {
+            // Compare each string with a few of the following ones, not only the adjacent one,
+            // so that unequal strings are compared at every level.
+            for (right, right_key) in strings.iter().zip(&keys).skip(i + 1).take(4) {
+                assert_eq!(
+                    left_key.cmp(right_key),
+                    collator.compare(left, right),
+                    "{:?} {:?} {:?}",
+                    options,
+                    left,
+                    right
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_sort_key_tailored() {
+    let left = [
+        "cote",
+        "coté",
+        "Côte",
+        "vo\u{0301}",
+        "a\u{FFFE}b",
+        "chica",
+        "ıi",
+        "かな",
+        "a",
+        "ক",
+        "เก",
+    ];
+    let right = [
+        "côte",
+        "côte",
+        "côté",
+        "v\u{00F3}",
+        "a\u{FFFE}c",
+        "cuna",
+        "İi",
+        "カナ",
+        "ক",
+        "a",
+        "แก",
+    ];
+
+    for locale in ["und", "es", "es-u-co-trad", "tr", "ja", "bn", "th"] {
+        let locale: Locale = locale.parse().unwrap();
+        for strength in [Strength::Tertiary, Strength::Identical] {
+            let mut options = CollatorOptions::new();
+            options.strength = Some(strength);
+            let collator: Collator = Collator::try_new_unstable(
+                &icu_testdata::unstable(),
+                &locale.clone().into(),
+                options,
+            )
+            .unwrap();
+            for (left, right) in left.iter().zip(right.iter()) {
+                assert_eq!(
+                    collator.sort_key(left).cmp(&collator.sort_key(right)),
+                    collator.compare(left, right),
+                    "{} {:?} {:?}",
+                    locale,
+                    left,
+                    right
+                );
+                let left_utf16: Vec<u16> = left.encode_utf16().collect();
+                assert_eq!(
+                    collator.sort_key_utf16(&left_utf16),
+                    collator.sort_key(left)
+                );
+                assert_eq!(
+                    collator.sort_key_utf8(left.as_bytes()),
+                    collator.sort_key(left)
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_sort_key_expectations() {
+    // Cases from `test_de` and `test_tr_tertiary`, compared by sort key.
+    let left = ["Größe", "abc", "Töne", "äbc", "äbc", "Straße"];
+    let right = [
+        "Grossist",
+        "a\u{0308}bc",
+        "Ton",
+        "A\u{0308}bc",
+        "a\u{0308}bc",
+        "Strasse",
+    ];
+    let expect_primary = [
+        Ordering::Less,
+        Ordering::Equal,
+        Ordering::Greater,
+        Ordering::Equal,
+        Ordering::Equal,
+        Ordering::Equal,
+    ];
+    let expect_tertiary = [
+        Ordering::Less,
+        Ordering::Less,
+        Ordering::Greater,
+        Ordering::Less,
+        Ordering::Equal,
+        Ordering::Greater,
+    ];
+
+    let left_tr = ["ş", "hĞalt", "voıd", "idea"];
+    let right_tr = ["ü", "halt", "void", "Idea"];
+    let expect_tr = [
+        Ordering::Less,
+        Ordering::Greater,
+        Ordering::Less,
+        Ordering::Greater,
+    ];
+
+    for (locale, strength, left, right, expectations) in [
+        (
+            "und",
+            Strength::Primary,
+            &left[..],
+            &right[..],
+            &expect_primary[..],
+        ),
+        ("und", Strength::Tertiary, &left, &right, &expect_tertiary),
+        ("tr", Strength::Tertiary, &left_tr, &right_tr, &expect_tr),
+    ] {
+        let locale: Locale = locale.parse().unwrap();
+        let mut options = CollatorOptions::new();
+        options.strength = Some(strength);
+        let collator: Collator =
+            Collator::try_new_unstable(&icu_testdata::unstable(), &locale.into(), options).unwrap();
+        for ((left, right), expectation) in left.iter().zip(right).zip(expectations) {
+            assert_eq!(
+                collator.sort_key(left).cmp(&collator.sort_key(right)),
+                *expectation,
+                "{:?} {:?}",
+                left,
+                right
+            );
+        }
+    }
+}
+
+/// Checks that a collator built from rules orders the strings like the
+/// collator for `locale`, whose tailoring was precomputed from the same rules.
+#[cfg(feature = "experimental")]
+fn check_rules_match_locale(rules: &str, locale: &str, strings: &[&str]) {
+    let locale: Locale = locale.parse().unwrap();
+    for strength in [Strength::Primary, Strength::Secondary, Strength::Tertiary] {
+        let mut options = CollatorOptions::new();
+        options.strength = Some(strength);
+        let expected: Collator =
+            Collator::try_new_unstable(&icu_testdata::unstable(), &locale.clone().into(), options)
+                .unwrap();
+        let collator: Collator =
+            Collator::try_new_with_rules_unstable(&icu_testdata::unstable(), rules, options)
+                .unwrap();
+        for left in strings {
+            for right in strings {
+                assert_eq!(
+                    collator.compare(left, right),
+                    expected.compare(left, right),
+                    "{} {:?} {:?} {:?}",
+                    locale,
+                    strength,
+                    left,
+                    right
+                );
+                assert_eq!(
+                    collator.sort_key(left).cmp(&collator.sort_key(right)),
+                    collator.compare(left, right)
+                );
+            }
+        }
+    }
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+fn test_rules_es() {
+    let strings = [
+        "nalgas", "ñalgas", "Ñalgas", "nz", "o", "canoa", "caña", "cañón", "cana", "caNa", "chico",
+        "cuna", "llama", "luz", "lz", "mz",
+    ];
+    check_rules_match_locale("&N<ñ<<<Ñ", "es", &strings);
+    check_rules_match_locale(
+        "&N<ñ<<<Ñ &C<ch<<<Ch<<<CH &l<ll<<<Ll<<<LL",
+        "es-u-co-trad",
+        &strings,
+    );
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+fn test_rules_tr() {
+    let strings = [
+        "ş", "ü", "vät", "vbt", "old", "Öay", "üoid", "void", "hĞalt", "halt", "stresŞ", "ŞtreŞs",
+        "voıd", "idea", "Idea", "İdea", "ıdea", "çalı", "calı", "dalı", "ğ", "h",
+    ];
+    check_rules_match_locale(
+        "&C<ç<<<Ç &G<ğ<<<Ğ &[before 1]i<ı<<<I &i<<<İ &O<ö<<<Ö &S<ş<<<Ş &U<ü<<<Ü",
+        "tr",
+        &strings,
+    );
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+fn test_rules_relations() {
+    let new = |rules: &str| {
+        Collator::try_new_with_rules_unstable(
+            &icu_testdata::unstable(),
+            rules,
+            CollatorOptions::new(),
+        )
+        .unwrap()
+    };
+
+    let collator = new("&z < a <<< A");
+    assert_eq!(collator.compare("a", "z"), Ordering::Greater);
+    assert_eq!(collator.compare("a", "A"), Ordering::Less);
+    assert_eq!(collator.compare("A", "b"), Ordering::Greater);
+    assert_eq!(collator.compare("ä", "az"), Ordering::Less);
+
+    // Secondary and tertiary relations, and equality
+    let collator = new("&e << ë ; é , É = ee");
+    assert_eq!(collator.compare("e", "ë"), Ordering::Less);
+    assert_eq!(collator.compare("ë", "é"), Ordering::Less);
+    assert_eq!(collator.compare("é", "É"), Ordering::Less);
+    assert_eq!(collator.compare("É", "ee"), Ordering::Equal);
+    assert_eq!(collator.compare("É", "f"), Ordering::Less);
+
+    // Contractions and expansions
+    let collator = new("&c < ch &ae << æ &x <<< q / u");
+    assert_eq!(collator.compare("cz", "ch"), Ordering::Less);
+    assert_eq!(collator.compare("ch", "d"), Ordering::Less);
+    assert_eq!(collator.compare("cH", "ch"), Ordering::Less);
+    assert_eq!(collator.compare("æ", "ae"), Ordering::Greater);
+    assert_eq!(collator.compare("æ", "af"), Ordering::Less);
+    assert_eq!(collator.compare("q", "xu"), Ordering::Greater);
+    assert_eq!(collator.compare("q", "xv"), Ordering::Less);
+
+    // Before
+    let collator = new("&[before 1]a < b &[before 2]e << f &[before 3]x <<< y");
+    assert_eq!(collator.compare("b", "a"), Ordering::Less);
+    assert_eq!(collator.compare("b", "9"), Ordering::Greater);
+    assert_eq!(collator.compare("f", "e"), Ordering::Less);
+    assert_eq!(collator.compare("f", "d"), Ordering::Greater);
+    assert_eq!(collator.compare("y", "x"), Ordering::Less);
+    assert_eq!(collator.compare("y", "w"), Ordering::Greater);
+
+    // Starred relations and ranges
+    let collator = new("&a <* d-fb");
+    assert_eq!(collator.compare("a", "d"), Ordering::Less);
+    assert_eq!(collator.compare("d", "e"), Ordering::Less);
+    assert_eq!(collator.compare("f", "b"), Ordering::Less);
+    assert_eq!(collator.compare("b", "c"), Ordering::Less);
+
+    // Quoting, escapes, comments and supplementary characters
+    let collator = new("# comment\n&'-' < '&' < \\\\ < \u{1D49C}");
+    assert_eq!(collator.compare("-", "&"), Ordering::Less);
+    assert_eq!(collator.compare("&", "\\"), Ordering::Less);
+    assert_eq!(collator.compare("\\", "\u{1D49C}"), Ordering::Less);
+    assert_eq!(collator.compare("\u{1D49C}", ","), Ordering::Less);
+
+    // Tailored combining marks
+    let collator = new("&\u{0301} << \u{0300}");
+    assert_eq!(collator.compare("á", "à"), Ordering::Less);
+    assert_eq!(collator.compare("à", "â"), Ordering::Less);
+    assert_eq!(collator.compare("à", "b"), Ordering::Less);
+
+    // Tailoring a starter keeps its root contractions.
+    let collator = new("&Е < И");
+    assert_eq!(collator.compare("Е", "И"), Ordering::Less);
+    assert_eq!(collator.compare("И", "Й"), Ordering::Less);
+    assert_eq!(collator.compare("Й", "К"), Ordering::Less);
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+fn test_rules_settings() {
+    let mut options = CollatorOptions::new();
+    let collator = Collator::try_new_with_rules_unstable(
+        &icu_testdata::unstable(),
+        "[strength 2][caseFirst upper]&b < a <<< A",
+        options,
+    )
+    .unwrap();
+    assert_eq!(collator.compare("a", "A"), Ordering::Equal);
+    assert_eq!(collator.compare("a", "b"), Ordering::Greater);
+
+    // Options override the settings in the rules.
+    options.strength = Some(Strength::Tertiary);
+    let collator = Collator::try_new_with_rules_unstable(
+        &icu_testdata::unstable(),
+        "[strength 2][caseFirst upper]&b < a <<< A",
+        options,
+    )
+    .unwrap();
+    assert_eq!(collator.compare("a", "A"), Ordering::Greater);
+
+    let collator = Collator::try_new_with_rules_unstable(
+        &icu_testdata::unstable(),
+        "[numericOrdering on][alternate shifted]",
+        CollatorOptions::new(),
+    )
+    .unwrap();
+    assert_eq!(collator.compare("a10b", "a2b"), Ordering::Greater);
+    assert_eq!(collator.compare("a-b", "ab"), Ordering::Equal);
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+fn test_rules_errors() {
+    let error = |rules: &str| match Collator::try_new_with_rules_unstable(
+        &icu_testdata::unstable(),
+        rules,
+        CollatorOptions::new(),
+    ) {
+        Ok(_) => panic!("{:?} should fail", rules),
+        Err(CollatorError::InvalidRules(offset)) => Ok(offset),
+        Err(CollatorError::UnsupportedRules(offset)) => Err(offset),
+        Err(e) => panic!("{:?}: {}", rules, e),
+    };
+    assert_eq!(error("&a"), Ok(0));
+    assert_eq!(error("&a < b x"), Ok(7));
+    assert_eq!(error("&a < 'b"), Ok(5));
+    assert_eq!(error("&a <* d-b"), Ok(3));
+    assert_eq!(error("&a < b [strength 5]"), Ok(7));
+    assert_eq!(error("&[before 2]a < b"), Ok(13));
+    assert_eq!(error("&a <<<< b"), Err(3));
+    assert_eq!(error("&a < c | b"), Err(7));
+    assert_eq!(error("&[first tertiary ignorable] < b"), Err(1));
+    assert_eq!(error("[reorder Grek]&a < b"), Err(0));
+    assert_eq!(error("&a < \u{1100}"), Err(3));
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+fn test_search() {
+    let search_with = |locale: &str, strength, alternate| {
+        let locale: Locale = locale.parse().unwrap();
+        let mut options = CollatorOptions::new();
+        options.strength = Some(strength);
+        options.alternate_handling = alternate;
+        CollatorSearch::try_new_unstable(&icu_testdata::unstable(), &locale.into(), options)
+            .unwrap()
+    };
+    let check =
+        |search: &CollatorSearch, pattern: &str, text: &str, expected: &[(usize, usize)]| {
+            let expected: Vec<_> = expected.iter().map(|&(start, end)| start..end).collect();
+            assert_eq!(
+                search.find_all_str(pattern, text),
+                expected,
+                "{:?} in {:?}",
+                pattern,
+                text
+            );
+            assert_eq!(
+                search.find_all_utf8(pattern.as_bytes(), text.as_bytes()),
+                expected
+            );
+            let pattern16: Vec<u16> = pattern.encode_utf16().collect();
+            let text16: Vec<u16> = text.encode_utf16().collect();
+            let expected16: Vec<_> = expected
+                .iter()
+                .map(|range| {
+                    text[..range.start].encode_utf16().count()
+                        ..text[..range.end].encode_utf16().count()
+                })
+                .collect();
+            assert_eq!(search.find_all_utf16(&pattern16, &text16), expected16);
+        };
+
+    let primary = search_with("en", Strength::Primary, None);
+    check(
+        &primary,
+        "cafe",
+        "Café, CAFE, cafeteria",
+        &[(0, 5), (7, 11), (13, 17)],
+    );
+    // Matches include whole grapheme clusters.
+    check(&primary, "cafe", "cafe\u{301}", &[(0, 6)]);
+    check(&primary, "e", "e\u{301}e", &[(0, 3), (3, 4)]);
+    // Matches don't overlap.
+    check(&primary, "aa", "aaaaa", &[(0, 2), (2, 4)]);
+    // Completely ignorable characters are skipped.
+    check(&primary, "ab", "\u{AD}a\u{AD}b", &[(2, 6)]);
+    check(&primary, "", "abc", &[]);
+    check(&primary, "abc", "ab", &[]);
+
+    let secondary = search_with("en", Strength::Secondary, None);
+    check(&secondary, "cafe", "Café, CAFE, cafe\u{301}", &[(7, 11)]);
+    check(&secondary, "café", "Cafe\u{301}, CAFÉ", &[(0, 6), (8, 13)]);
+
+    let tertiary = search_with("en", Strength::Tertiary, None);
+    check(&tertiary, "cafe", "Café, CAFE, cafe", &[(13, 17)]);
+
+    let shifted = search_with("en", Strength::Tertiary, Some(AlternateHandling::Shifted));
+    check(
+        &shifted,
+        "coop",
+        "co-op, co op, coop",
+        &[(0, 5), (7, 12), (14, 18)],
+    );
+
+    // Tailorings, including contractions.
+    let trad = search_with("es-u-co-trad", Strength::Primary, None);
+    check(&trad, "c", "chico", &[(3, 4)]);
+    check(&trad, "ch", "Chico", &[(0, 2)]);
+    let spanish = search_with("es", Strength::Primary, None);
+    check(&spanish, "n", "Año nuevo", &[(5, 6)]);
+    let turkish = search_with("tr", Strength::Primary, None);
+    check(&turkish, "i", "Işık İzmir", &[(7, 9), (11, 12)]);
+
+    // Hangul syllables match their jamo.
+    let korean = search_with("ko-u-co-search", Strength::Tertiary, None);
+    check(&korean, "\u{1100}\u{1161}", "\u{AC00}\u{AC01}", &[(0, 3)]);
+}
+
 // TODO: Test languages that map to the root.
 // The languages that map to root without script reordering are:
 // ca (at least for now)
@@ -8,7 +8,8 @@ use crate::{input::DateInput, DateTimeError, FormattedDateTime};
 use alloc::string::String;
 use icu_calendar::any_calendar::{AnyCalendar, AnyCalendarKind};
 use icu_calendar::provider::{
-    JapaneseErasV1Marker, JapaneseExtendedErasV1Marker, WeekDataV1Marker,
+    IslamicUmmAlQuraCacheV1Marker, JapaneseErasV1Marker, JapaneseExtendedErasV1Marker,
+    WeekDataV1Marker,
 };
 use icu_calendar::Date;
 use icu_decimal::provider::DecimalSymbolsV1Marker;
@@ -76,6 +77,7 @@ impl DateFormatter {
     /// Furthermore, based on the type of calendar used, one of the following data keys may be necessary:
     ///
     /// - `u-ca-japanese` (Japanese calendar): `calendar/japanese@1`
+    /// - `u-ca-islamic-umalqura` (Umm al-Qura calendar): `calendar/islamicumalquracache@1`
     #[inline]
     pub fn try_new_with_length_with_any_provider<P>(
         data_provider: &P,
@@ -101,6 +103,7 @@ impl DateFormatter {
     /// Furthermore, based on the type of calendar used, one of the following data keys may be necessary:
     ///
     /// - `u-ca-japanese` (Japanese calendar): `calendar/japanese@1`
+    /// - `u-ca-islamic-umalqura` (Umm al-Qura calendar): `calendar/islamicumalquracache@1`
     ///
     /// # Examples
     ///
@@ -203,6 +206,12 @@ impl DateFormatter {
             + DataProvider<CopticDateLengthsV1Marker>
             + DataProvider<IndianDateLengthsV1Marker>
             + DataProvider<EthiopianDateLengthsV1Marker>
+            + DataProvider<HebrewDateLengthsV1Marker>
+            + DataProvider<ChineseDateLengthsV1Marker>
+            + DataProvider<DangiDateLengthsV1Marker>
+            + DataProvider<IslamicDateLengthsV1Marker>
+            + DataProvider<PersianDateLengthsV1Marker>
+            + DataProvider<RocDateLengthsV1Marker>
             + DataProvider<GregorianDateSymbolsV1Marker>
             + DataProvider<BuddhistDateSymbolsV1Marker>
             + DataProvider<JapaneseDateSymbolsV1Marker>
@@ -210,8 +219,15 @@ impl DateFormatter {
             + DataProvider<CopticDateSymbolsV1Marker>
             + DataProvider<IndianDateSymbolsV1Marker>
             + DataProvider<EthiopianDateSymbolsV1Marker>
+            + DataProvider<HebrewDateSymbolsV1Marker>
+            + DataProvider<ChineseDateSymbolsV1Marker>
+            + DataProvider<DangiDateSymbolsV1Marker>
+            + DataProvider<IslamicDateSymbolsV1Marker>
+            + DataProvider<PersianDateSymbolsV1Marker>
+            + DataProvider<RocDateSymbolsV1Marker>
             + DataProvider<JapaneseErasV1Marker>
             + DataProvider<JapaneseExtendedErasV1Marker>
+            + DataProvider<IslamicUmmAlQuraCacheV1Marker>
             + ?Sized,
     {
         let calendar = AnyCalendar::try_new_for_locale_unstable(data_provider, locale)?;
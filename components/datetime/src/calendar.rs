@@ -5,8 +5,19 @@
 use crate::provider::calendar::*;
 use icu_calendar::any_calendar::AnyCalendarKind;
 use icu_calendar::{
-    buddhist::Buddhist, coptic::Coptic, ethiopian::Ethiopian, indian::Indian, japanese::Japanese,
-    japanese::JapaneseExtended, Gregorian,
+    buddhist::Buddhist,
+    chinese::Chinese,
+    coptic::Coptic,
+    dangi::Dangi,
+    ethiopian::Ethiopian,
+    hebrew::Hebrew,
+    indian::Indian,
+    islamic::{IslamicCivil, IslamicObservational, IslamicTabular, IslamicUmmAlQura},
+    japanese::Japanese,
+    japanese::JapaneseExtended,
+    persian::Persian,
+    roc::Roc,
+    Gregorian,
 };
 use icu_locid::extensions::unicode::Value;
 use icu_locid::extensions_unicode_value as value;
@@ -81,6 +92,82 @@ impl CldrCalendar for Ethiopian {
     }
 }
 
+impl CldrCalendar for Hebrew {
+    const DEFAULT_BCP_47_IDENTIFIER: Value = value!("hebrew");
+    type DateSymbolsV1Marker = HebrewDateSymbolsV1Marker;
+    type DateLengthsV1Marker = HebrewDateLengthsV1Marker;
+}
+
+impl CldrCalendar for Chinese {
+    const DEFAULT_BCP_47_IDENTIFIER: Value = value!("chinese");
+    type DateSymbolsV1Marker = ChineseDateSymbolsV1Marker;
+    type DateLengthsV1Marker = ChineseDateLengthsV1Marker;
+}
+
+impl CldrCalendar for Dangi {
+    const DEFAULT_BCP_47_IDENTIFIER: Value = value!("dangi");
+    type DateSymbolsV1Marker = DangiDateSymbolsV1Marker;
+    type DateLengthsV1Marker = DangiDateLengthsV1Marker;
+}
+
+/// The Islamic calendars share their symbols and patterns, which CLDR
+/// stores under `islamic`
+fn is_islamic_identifier(value: &Value) -> bool {
+    value
+        .as_tinystr_slice()
+        .first()
+        .map(|subtag| subtag == "islamic")
+        .unwrap_or(false)
+}
+
+impl CldrCalendar for IslamicObservational {
+    const DEFAULT_BCP_47_IDENTIFIER: Value = value!("islamic");
+    type DateSymbolsV1Marker = IslamicDateSymbolsV1Marker;
+    type DateLengthsV1Marker = IslamicDateLengthsV1Marker;
+    fn is_identifier_allowed_for_calendar(value: &Value) -> bool {
+        is_islamic_identifier(value)
+    }
+}
+
+impl CldrCalendar for IslamicUmmAlQura {
+    const DEFAULT_BCP_47_IDENTIFIER: Value = value!("islamic");
+    type DateSymbolsV1Marker = IslamicDateSymbolsV1Marker;
+    type DateLengthsV1Marker = IslamicDateLengthsV1Marker;
+    fn is_identifier_allowed_for_calendar(value: &Value) -> bool {
+        is_islamic_identifier(value)
+    }
+}
+
+impl CldrCalendar for IslamicCivil {
+    const DEFAULT_BCP_47_IDENTIFIER: Value = value!("islamic");
+    type DateSymbolsV1Marker = IslamicDateSymbolsV1Marker;
+    type DateLengthsV1Marker = IslamicDateLengthsV1Marker;
+    fn is_identifier_allowed_for_calendar(value: &Value) -> bool {
+        is_islamic_identifier(value)
+    }
+}
+
+impl CldrCalendar for IslamicTabular {
+    const DEFAULT_BCP_47_IDENTIFIER: Value = value!("islamic");
+    type DateSymbolsV1Marker = IslamicDateSymbolsV1Marker;
+    type DateLengthsV1Marker = IslamicDateLengthsV1Marker;
+    fn is_identifier_allowed_for_calendar(value: &Value) -> bool {
+        is_islamic_identifier(value)
+    }
+}
+
+impl CldrCalendar for Persian {
+    const DEFAULT_BCP_47_IDENTIFIER: Value = value!("persian");
+    type DateSymbolsV1Marker = PersianDateSymbolsV1Marker;
+    type DateLengthsV1Marker = PersianDateLengthsV1Marker;
+}
+
+impl CldrCalendar for Roc {
+    const DEFAULT_BCP_47_IDENTIFIER: Value = value!("roc");
+    type DateSymbolsV1Marker = RocDateSymbolsV1Marker;
+    type DateLengthsV1Marker = RocDateLengthsV1Marker;
+}
+
 pub(crate) fn load_lengths_for_cldr_calendar<C, P>(
     provider: &P,
     locale: &DataLocale,
@@ -128,6 +215,12 @@ where
         + DataProvider<CopticDateLengthsV1Marker>
         + DataProvider<IndianDateLengthsV1Marker>
         + DataProvider<EthiopianDateLengthsV1Marker>
+        + DataProvider<HebrewDateLengthsV1Marker>
+        + DataProvider<ChineseDateLengthsV1Marker>
+        + DataProvider<DangiDateLengthsV1Marker>
+        + DataProvider<IslamicDateLengthsV1Marker>
+        + DataProvider<PersianDateLengthsV1Marker>
+        + DataProvider<RocDateLengthsV1Marker>
         + ?Sized,
 {
     let req = DataRequest {
@@ -175,6 +268,39 @@ where
                 .take_payload()?
                 .cast()
         }
+        AnyCalendarKind::Hebrew => {
+            DataProvider::<<Hebrew as CldrCalendar>::DateLengthsV1Marker>::load(provider, req)?
+                .take_payload()?
+                .cast()
+        }
+        AnyCalendarKind::Chinese => {
+            DataProvider::<<Chinese as CldrCalendar>::DateLengthsV1Marker>::load(provider, req)?
+                .take_payload()?
+                .cast()
+        }
+        AnyCalendarKind::Dangi => {
+            DataProvider::<<Dangi as CldrCalendar>::DateLengthsV1Marker>::load(provider, req)?
+                .take_payload()?
+                .cast()
+        }
+        AnyCalendarKind::IslamicObservational
+        | AnyCalendarKind::IslamicUmmAlQura
+        | AnyCalendarKind::IslamicCivil
+        | AnyCalendarKind::IslamicTabular => {
+            DataProvider::<IslamicDateLengthsV1Marker>::load(provider, req)?
+                .take_payload()?
+                .cast()
+        }
+        AnyCalendarKind::Persian => {
+            DataProvider::<<Persian as CldrCalendar>::DateLengthsV1Marker>::load(provider, req)?
+                .take_payload()?
+                .cast()
+        }
+        AnyCalendarKind::Roc => {
+            DataProvider::<<Roc as CldrCalendar>::DateLengthsV1Marker>::load(provider, req)?
+                .take_payload()?
+                .cast()
+        }
         _ => {
             return Err(
                 DataError::custom("Don't know how to load data for specified calendar")
@@ -198,6 +324,12 @@ where
         + DataProvider<CopticDateSymbolsV1Marker>
         + DataProvider<IndianDateSymbolsV1Marker>
         + DataProvider<EthiopianDateSymbolsV1Marker>
+        + DataProvider<HebrewDateSymbolsV1Marker>
+        + DataProvider<ChineseDateSymbolsV1Marker>
+        + DataProvider<DangiDateSymbolsV1Marker>
+        + DataProvider<IslamicDateSymbolsV1Marker>
+        + DataProvider<PersianDateSymbolsV1Marker>
+        + DataProvider<RocDateSymbolsV1Marker>
         + ?Sized,
 {
     let req = DataRequest {
@@ -245,6 +377,39 @@ where
                 .take_payload()?
                 .cast()
         }
+        AnyCalendarKind::Hebrew => {
+            DataProvider::<<Hebrew as CldrCalendar>::DateSymbolsV1Marker>::load(provider, req)?
+                .take_payload()?
+                .cast()
+        }
+        AnyCalendarKind::Chinese => {
+            DataProvider::<<Chinese as CldrCalendar>::DateSymbolsV1Marker>::load(provider, req)?
+                .take_payload()?
+                .cast()
+        }
+        AnyCalendarKind::Dangi => {
+            DataProvider::<<Dangi as CldrCalendar>::DateSymbolsV1Marker>::load(provider, req)?
+                .take_payload()?
+                .cast()
+        }
+        AnyCalendarKind::IslamicObservational
+        | AnyCalendarKind::IslamicUmmAlQura
+        | AnyCalendarKind::IslamicCivil
+        | AnyCalendarKind::IslamicTabular => {
+            DataProvider::<IslamicDateSymbolsV1Marker>::load(provider, req)?
+                .take_payload()?
+                .cast()
+        }
+        AnyCalendarKind::Persian => {
+            DataProvider::<<Persian as CldrCalendar>::DateSymbolsV1Marker>::load(provider, req)?
+                .take_payload()?
+                .cast()
+        }
+        AnyCalendarKind::Roc => {
+            DataProvider::<<Roc as CldrCalendar>::DateSymbolsV1Marker>::load(provider, req)?
+                .take_payload()?
+                .cast()
+        }
         _ => {
             return Err(
                 DataError::custom("Don't know how to load data for specified calendar")
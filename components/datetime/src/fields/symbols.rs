@@ -233,34 +233,36 @@ impl FieldSymbol {
             Self::Era => 0,
             Self::Year(Year::Calendar) => 1,
             Self::Year(Year::WeekOf) => 2,
-            Self::Month(Month::Format) => 3,
-            Self::Month(Month::StandAlone) => 4,
-            Self::Week(Week::WeekOfYear) => 5,
-            Self::Week(Week::WeekOfMonth) => 6,
-            Self::Day(Day::DayOfMonth) => 7,
-            Self::Day(Day::DayOfYear) => 8,
-            Self::Day(Day::DayOfWeekInMonth) => 9,
-            Self::Day(Day::ModifiedJulianDay) => 10,
-            Self::Weekday(Weekday::Format) => 11,
-            Self::Weekday(Weekday::Local) => 12,
-            Self::Weekday(Weekday::StandAlone) => 13,
-            Self::DayPeriod(DayPeriod::AmPm) => 14,
-            Self::DayPeriod(DayPeriod::NoonMidnight) => 15,
-            Self::Hour(Hour::H11) => 16,
-            Self::Hour(Hour::H12) => 17,
-            Self::Hour(Hour::H23) => 18,
-            Self::Hour(Hour::H24) => 19,
-            Self::Minute => 20,
-            Self::Second(Second::Second) => 21,
-            Self::Second(Second::FractionalSecond) => 22,
-            Self::Second(Second::Millisecond) => 23,
-            Self::TimeZone(TimeZone::LowerZ) => 24,
-            Self::TimeZone(TimeZone::UpperZ) => 25,
-            Self::TimeZone(TimeZone::UpperO) => 26,
-            Self::TimeZone(TimeZone::LowerV) => 27,
-            Self::TimeZone(TimeZone::UpperV) => 28,
-            Self::TimeZone(TimeZone::LowerX) => 29,
-            Self::TimeZone(TimeZone::UpperX) => 30,
+            Self::Year(Year::Cyclic) => 3,
+            Self::Year(Year::RelatedIso) => 4,
+            Self::Month(Month::Format) => 5,
+            Self::Month(Month::StandAlone) => 6,
+            Self::Week(Week::WeekOfYear) => 7,
+            Self::Week(Week::WeekOfMonth) => 8,
+            Self::Day(Day::DayOfMonth) => 9,
+            Self::Day(Day::DayOfYear) => 10,
+            Self::Day(Day::DayOfWeekInMonth) => 11,
+            Self::Day(Day::ModifiedJulianDay) => 12,
+            Self::Weekday(Weekday::Format) => 13,
+            Self::Weekday(Weekday::Local) => 14,
+            Self::Weekday(Weekday::StandAlone) => 15,
+            Self::DayPeriod(DayPeriod::AmPm) => 16,
+            Self::DayPeriod(DayPeriod::NoonMidnight) => 17,
+            Self::Hour(Hour::H11) => 18,
+            Self::Hour(Hour::H12) => 19,
+            Self::Hour(Hour::H23) => 20,
+            Self::Hour(Hour::H24) => 21,
+            Self::Minute => 22,
+            Self::Second(Second::Second) => 23,
+            Self::Second(Second::FractionalSecond) => 24,
+            Self::Second(Second::Millisecond) => 25,
+            Self::TimeZone(TimeZone::LowerZ) => 26,
+            Self::TimeZone(TimeZone::UpperZ) => 27,
+            Self::TimeZone(TimeZone::UpperO) => 28,
+            Self::TimeZone(TimeZone::LowerV) => 29,
+            Self::TimeZone(TimeZone::UpperV) => 30,
+            Self::TimeZone(TimeZone::LowerX) => 31,
+            Self::TimeZone(TimeZone::UpperX) => 32,
         }
     }
 }
@@ -445,11 +447,28 @@ field_type! (
         ///
         /// This works for “week of year” based calendars in which the year transition occurs on a week boundary; may differ from calendar year [`Year::Calendar`] near a year transition. This numeric year designation is used in conjunction with [`Week::WeekOfYear`], but can be used in non-Gregorian based calendar systems where week date processing is desired. The field length is interpreted in the same way as for [`Year::Calendar`].
         'Y' => WeekOf = 1,
+        /// Field symbol for cyclic year; used in calendars where years are tracked in cycles, such as the Chinese or Dangi calendars.
+        ///
+        /// Cyclic years are formatted with the locale's names for the years of the cycle, such as `"癸卯"` for the 40th year
+        /// of the sexagenary cycle, or as the position in the cycle if the locale has no such names.
+        'U' => Cyclic = 2,
+        /// Field symbol for related ISO year; used in calendars which number their years differently from the ISO calendar.
+        ///
+        /// This is the ISO year in which the calendar year begins. The field length is interpreted in the same way as for [`Year::Calendar`].
+        'r' => RelatedIso = 3,
     };
-    Numeric;
     YearULE
 );
 
+impl LengthType for Year {
+    fn get_length_type(&self, _length: FieldLength) -> TextOrNumeric {
+        match self {
+            Year::Cyclic => TextOrNumeric::Text,
+            _ => TextOrNumeric::Numeric,
+        }
+    }
+}
+
 field_type!(
     /// An enum for the possible symbols of a month field in a date pattern.
     Month; {
@@ -18,9 +18,12 @@ use crate::provider::date_time::{DateSymbols, TimeSymbols};
 use core::fmt;
 use fixed_decimal::FixedDecimal;
 use icu_calendar::provider::WeekDataV1;
+use icu_calendar::types::{FormattableMonth, MonthCode};
+use icu_calendar::AnyCalendarKind;
 use icu_decimal::FixedDecimalFormatter;
 use icu_plurals::PluralRules;
 use icu_provider::DataPayload;
+use tinystr::tinystr;
 use writeable::Writeable;
 
 /// [`FormattedDateTime`] is a intermediate structure which can be retrieved as
@@ -181,6 +184,13 @@ where
     )
 }
 
+/// In a leap year, the Hebrew month Adar (`"M06"`) follows Adar I and is called Adar II.
+fn is_hebrew_adar_ii(kind: Option<AnyCalendarKind>, month: &FormattableMonth) -> bool {
+    kind == Some(AnyCalendarKind::Hebrew)
+        && month.code == MonthCode(tinystr!(4, "M06"))
+        && month.ordinal == 7
+}
+
 // This function assumes that the correct decision has been
 // made regarding availability of symbols in the caller.
 //
@@ -232,6 +242,51 @@ where
                 FixedDecimal::from(datetime.week_of_year()?.0.number),
                 field.length,
             )?,
+            Year::Cyclic => {
+                let year = datetime
+                    .datetime()
+                    .year()
+                    .ok_or(Error::MissingInputField(Some("year")))?;
+                match year.cyclic {
+                    Some(cyclic) => {
+                        let name = date_symbols
+                            .ok_or(Error::MissingDateSymbols)?
+                            .cyclic_years
+                            .as_ref()
+                            .and_then(|names| names.get(usize::from(cyclic.get()) - 1));
+                        match name {
+                            Some(name) => w.write_str(name)?,
+                            // Locales without names for the years of the cycle
+                            // use the position in the cycle
+                            None => format_number(
+                                w,
+                                fixed_decimal_format,
+                                FixedDecimal::from(cyclic.get()),
+                                FieldLength::One,
+                            )?,
+                        }
+                    }
+                    // Calendars without year cycles use the calendar year
+                    None => format_number(
+                        w,
+                        fixed_decimal_format,
+                        FixedDecimal::from(year.number),
+                        FieldLength::One,
+                    )?,
+                }
+            }
+            Year::RelatedIso => {
+                let year = datetime
+                    .datetime()
+                    .year()
+                    .ok_or(Error::MissingInputField(Some("year")))?;
+                format_number(
+                    w,
+                    fixed_decimal_format,
+                    FixedDecimal::from(year.related_iso.unwrap_or(year.number)),
+                    field.length,
+                )?
+            }
         },
         FieldSymbol::Month(month) => match field.length {
             FieldLength::One | FieldLength::TwoDigit => format_number(
@@ -247,17 +302,24 @@ where
                 field.length,
             )?,
             length => {
-                let symbol = date_symbols
-                    .ok_or(Error::MissingDateSymbols)?
-                    .get_symbol_for_month(
-                        month,
-                        length,
-                        datetime
-                            .datetime()
-                            .month()
-                            .ok_or(Error::MissingInputField(Some("month")))?
-                            .code,
-                    )?;
+                let date_symbols = date_symbols.ok_or(Error::MissingDateSymbols)?;
+                let formattable_month = datetime
+                    .datetime()
+                    .month()
+                    .ok_or(Error::MissingInputField(Some("month")))?;
+                let symbol = if is_hebrew_adar_ii(
+                    datetime.datetime().any_calendar_kind(),
+                    &formattable_month,
+                ) {
+                    // Adar II has its own name, stored under a formatting-only code
+                    date_symbols
+                        .get_symbol_for_month(month, length, MonthCode(tinystr!(4, "M06L")))
+                        .or_else(|_| {
+                            date_symbols.get_symbol_for_month(month, length, formattable_month.code)
+                        })?
+                } else {
+                    date_symbols.get_symbol_for_month(month, length, formattable_month.code)?
+                };
                 w.write_str(symbol)?
             }
         },
@@ -443,6 +505,7 @@ impl RequiredData {
             if !self.date_symbols_data {
                 self.date_symbols_data = match field.symbol {
                     FieldSymbol::Era => true,
+                    FieldSymbol::Year(Year::Cyclic) => true,
                     FieldSymbol::Month(_) => {
                         !matches!(field.length, FieldLength::One | FieldLength::TwoDigit)
                     }
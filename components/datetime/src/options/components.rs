@@ -167,8 +167,9 @@ impl Bag {
         if let Some(year) = self.year {
             // Unimplemented year fields:
             // u - Extended year
-            // U - Cyclic year name
-            // r - Related Gregorian year
+            //
+            // The cyclic (U) and related Gregorian (r) years are only produced by
+            // calendar-specific patterns, so the bag always requests the calendar year.
             fields.push(Field {
                 symbol: FieldSymbol::Year(match year {
                     Year::Numeric | Year::TwoDigit => fields::Year::Calendar,
@@ -634,6 +635,9 @@ impl<'data> From<&PatternPlurals<'data>> for Bag {
                             FieldLength::TwoDigit => Year::TwoDigit,
                             _ => Year::Numeric,
                         },
+                        // Both of these stand in for the calendar year in patterns of
+                        // lunisolar calendars.
+                        fields::Year::Cyclic | fields::Year::RelatedIso => Year::Numeric,
                         fields::Year::WeekOf => match field.length {
                             FieldLength::TwoDigit => Year::TwoDigitWeekOf,
                             _ => Year::NumericWeekOf,
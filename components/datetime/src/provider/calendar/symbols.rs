@@ -10,7 +10,7 @@ use icu_calendar::types::MonthCode;
 use icu_provider::prelude::*;
 use icu_provider::{yoke, zerofrom};
 use tinystr::{tinystr, TinyStr4};
-use zerovec::ZeroMap;
+use zerovec::{VarZeroVec, ZeroMap};
 
 /// Symbol data for the months, weekdays, and eras needed to format a date.
 ///
@@ -22,7 +22,13 @@ use zerovec::ZeroMap;
     marker(JapaneseExtendedDateSymbolsV1Marker, "datetime/japanext/datesymbols@1"),
     marker(CopticDateSymbolsV1Marker, "datetime/coptic/datesymbols@1"),
     marker(IndianDateSymbolsV1Marker, "datetime/indian/datesymbols@1"),
-    marker(EthiopianDateSymbolsV1Marker, "datetime/ethiopic/datesymbols@1")
+    marker(EthiopianDateSymbolsV1Marker, "datetime/ethiopic/datesymbols@1"),
+    marker(HebrewDateSymbolsV1Marker, "datetime/hebrew/datesymbols@1"),
+    marker(ChineseDateSymbolsV1Marker, "datetime/chinese/datesymbols@1"),
+    marker(DangiDateSymbolsV1Marker, "datetime/dangi/datesymbols@1"),
+    marker(IslamicDateSymbolsV1Marker, "datetime/islamic/datesymbols@1"),
+    marker(PersianDateSymbolsV1Marker, "datetime/persian/datesymbols@1"),
+    marker(RocDateSymbolsV1Marker, "datetime/roc/datesymbols@1")
 )]
 #[derive(Debug, PartialEq, Clone, Default)]
 #[cfg_attr(
@@ -42,6 +48,13 @@ pub struct DateSymbolsV1<'data> {
     /// Symbol data for eras.
     #[cfg_attr(feature = "serde", serde(borrow))]
     pub eras: Eras<'data>,
+    /// Symbol data for the names of the years of a cycle, for calendars like the Chinese
+    /// calendar that count years in cycles of sixty.
+    ///
+    /// The n-th name is the name of the n-th year of the cycle. This is `None` if the
+    /// calendar has no year cycles or the locale has no names for them.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub cyclic_years: Option<VarZeroVec<'data, str>>,
 }
 
 /// Symbol data for the months, weekdays, and eras needed to format a date.
@@ -228,6 +241,9 @@ symbols!(
             [Cow<'data, str>; 12],
         ),
         /// A calendar with an arbitrary number of months, potentially including leap months
+        ///
+        /// The Hebrew calendar also stores the name that Adar (`M06`) has in leap years, Adar II,
+        /// under the code `M06L`.
         #[cfg_attr(feature = "serde", serde(borrow))]
         Other(ZeroMap<'data, MonthCode, str>),
     }
@@ -394,6 +410,7 @@ mod test {
                 abbr: ZeroMap::new(),
                 narrow: ZeroMap::new(),
             },
+            cyclic_years: None,
         })
         .unwrap()
     }
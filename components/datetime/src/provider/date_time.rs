@@ -278,9 +278,12 @@ where
         let mut locale = self.locale.clone();
         #[allow(clippy::expect_used)] // experimental
         let cal_val = self.cal_val.expect("should be present for components bag");
-        // Skeleton data for ethioaa is stored under ethiopic
+        // Skeleton data for ethioaa is stored under ethiopic, and for all
+        // Islamic calendars under islamic
         if cal_val == &value!("ethioaa") {
             locale.set_unicode_ext(key!("ca"), value!("ethiopic"));
+        } else if cal_val.as_tinystr_slice().first() == Some(&tinystr::tinystr!(8, "islamic")) {
+            locale.set_unicode_ext(key!("ca"), value!("islamic"));
         } else {
             locale.set_unicode_ext(key!("ca"), cal_val.clone());
         };
@@ -8,11 +8,17 @@ mod patterns;
 use icu_calendar::{
     any_calendar::{AnyCalendarKind, IntoAnyCalendar},
     buddhist::Buddhist,
+    chinese::Chinese,
     coptic::Coptic,
+    dangi::Dangi,
     ethiopian::{Ethiopian, EthiopianEraStyle},
+    hebrew::Hebrew,
     indian::Indian,
+    islamic::{IslamicCivil, IslamicObservational, IslamicTabular, IslamicUmmAlQura},
     japanese::{Japanese, JapaneseExtended},
+    persian::Persian,
     provider::WeekDataV1Marker,
+    roc::Roc,
     AsCalendar, DateTime, Gregorian, Iso,
 };
 use icu_datetime::provider::time_zones::{
@@ -58,6 +64,8 @@ fn test_fixture(fixture_name: &str) {
             .expect("Cannot load japanese data");
         let japanext = JapaneseExtended::try_new_unstable(&icu_testdata::unstable())
             .expect("Cannot load japanese data");
+        let ummalqura = IslamicUmmAlQura::try_new_unstable(&icu_testdata::unstable())
+            .expect("Cannot load Umm al-Qura data");
         let options_base = match fixtures::get_options(&fx.input.options) {
             Some(o) => o,
             #[cfg(feature = "experimental")]
@@ -73,6 +81,15 @@ fn test_fixture(fixture_name: &str) {
         let input_coptic = input_value.to_calendar(Coptic);
         let input_indian = input_value.to_calendar(Indian);
         let input_ethiopian = input_value.to_calendar(Ethiopian::new());
+        let input_hebrew = input_value.to_calendar(Hebrew);
+        let input_chinese = input_value.to_calendar(Chinese);
+        let input_dangi = input_value.to_calendar(Dangi);
+        let input_islamic = input_value.to_calendar(IslamicObservational);
+        let input_ummalqura = input_value.to_calendar(ummalqura);
+        let input_islamic_civil = input_value.to_calendar(IslamicCivil);
+        let input_islamic_tabular = input_value.to_calendar(IslamicTabular);
+        let input_persian = input_value.to_calendar(Persian);
+        let input_roc = input_value.to_calendar(Roc);
 
         let input_ethioaa =
             input_value.to_calendar(Ethiopian::new_with_era_style(EthiopianEraStyle::AmeteAlem));
@@ -146,6 +163,78 @@ fn test_fixture(fixture_name: &str) {
                         options,
                         &description,
                     ),
+                    AnyCalendarKind::Hebrew => assert_fixture_element(
+                        &locale,
+                        &input_hebrew,
+                        &input_iso,
+                        &output_value,
+                        options,
+                        &description,
+                    ),
+                    AnyCalendarKind::Chinese => assert_fixture_element(
+                        &locale,
+                        &input_chinese,
+                        &input_iso,
+                        &output_value,
+                        options,
+                        &description,
+                    ),
+                    AnyCalendarKind::Dangi => assert_fixture_element(
+                        &locale,
+                        &input_dangi,
+                        &input_iso,
+                        &output_value,
+                        options,
+                        &description,
+                    ),
+                    AnyCalendarKind::IslamicObservational => assert_fixture_element(
+                        &locale,
+                        &input_islamic,
+                        &input_iso,
+                        &output_value,
+                        options,
+                        &description,
+                    ),
+                    AnyCalendarKind::IslamicUmmAlQura => assert_fixture_element(
+                        &locale,
+                        &input_ummalqura,
+                        &input_iso,
+                        &output_value,
+                        options,
+                        &description,
+                    ),
+                    AnyCalendarKind::IslamicCivil => assert_fixture_element(
+                        &locale,
+                        &input_islamic_civil,
+                        &input_iso,
+                        &output_value,
+                        options,
+                        &description,
+                    ),
+                    AnyCalendarKind::IslamicTabular => assert_fixture_element(
+                        &locale,
+                        &input_islamic_tabular,
+                        &input_iso,
+                        &output_value,
+                        options,
+                        &description,
+                    ),
+                    AnyCalendarKind::Persian => assert_fixture_element(
+                        &locale,
+                        &input_persian,
+                        &input_iso,
+                        &output_value,
+                        options,
+                        &description,
+                    ),
+                    AnyCalendarKind::Roc => assert_fixture_element(
+                        &locale,
+                        &input_roc,
+                        &input_iso,
+                        &output_value,
+                        options,
+                        &description,
+                    ),
                     _ => panic!("datetime test does not support locale {:?}", locale),
                 }
             } else {
@@ -730,6 +819,42 @@ fn test_japanese() {
     test_fixture("japanese");
 }
 
+#[test]
+fn test_hebrew() {
+    // components/datetime/tests/fixtures/tests/hebrew.json
+    test_fixture("hebrew");
+}
+
+#[test]
+fn test_chinese() {
+    // components/datetime/tests/fixtures/tests/chinese.json
+    test_fixture("chinese");
+}
+
+#[test]
+fn test_dangi() {
+    // components/datetime/tests/fixtures/tests/dangi.json
+    test_fixture("dangi");
+}
+
+#[test]
+fn test_islamic() {
+    // components/datetime/tests/fixtures/tests/islamic.json
+    test_fixture("islamic");
+}
+
+#[test]
+fn test_persian() {
+    // components/datetime/tests/fixtures/tests/persian.json
+    test_fixture("persian");
+}
+
+#[test]
+fn test_roc() {
+    // components/datetime/tests/fixtures/tests/roc.json
+    test_fixture("roc");
+}
+
 #[test]
 fn test_lengths_with_preferences() {
     // components/datetime/tests/fixtures/tests/lengths_with_preferences.json
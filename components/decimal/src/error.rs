@@ -2,7 +2,7 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-//! Error types for decimal formatting.
+//! Error types for decimal formatting and parsing.
 
 use displaydoc::Display;
 
@@ -15,6 +15,22 @@ pub enum DecimalError {
     /// An error originating inside of the [data provider](icu_provider).
     #[displaydoc("error loading data: {0}")]
     Data(icu_provider::DataError),
+    /// The input is not a number. Contains the byte position of the first character that is not
+    /// understood, or the length of the input if it ends too early.
+    #[displaydoc("invalid number at position {0}")]
+    Parse(usize),
+    /// The number has too many digits for a [`FixedDecimal`](fixed_decimal::FixedDecimal).
+    #[displaydoc("number out of range")]
+    Limit,
+    /// The locale asks for an algorithmic numbering system that has no rules in the data.
+    ///
+    /// This is returned for `hanidays`, `hans`, `hansfin`, `hant` and `hantfin`, whose rules
+    /// are only available if the data was generated from CLDR data that includes `zh` and
+    /// `zh-Hant`. See [`FixedDecimalFormatter::try_new_with_algorithmic_unstable`].
+    ///
+    /// [`FixedDecimalFormatter::try_new_with_algorithmic_unstable`]: crate::FixedDecimalFormatter::try_new_with_algorithmic_unstable
+    #[displaydoc("unsupported numbering system")]
+    UnsupportedNumberingSystem,
 }
 
 #[cfg(feature = "std")]
@@ -11,6 +11,17 @@ use fixed_decimal::FixedDecimal;
 use fixed_decimal::Sign;
 use writeable::Writeable;
 
+fn get_sign_affixes<'l>(
+    sign: Sign,
+    symbols: &'l DecimalSymbolsV1<'l>,
+) -> Option<&'l AffixesV1<'l>> {
+    match sign {
+        Sign::None => None,
+        Sign::Negative => Some(&symbols.minus_sign_affixes),
+        Sign::Positive => Some(&symbols.plus_sign_affixes),
+    }
+}
+
 /// An intermediate structure returned by [`FixedDecimalFormatter`](crate::FixedDecimalFormatter).
 /// Use [`Writeable`][Writeable] to render the formatted decimal to a string or buffer.
 #[derive(Debug, PartialEq, Clone)]
@@ -18,15 +29,13 @@ pub struct FormattedFixedDecimal<'l> {
     pub(crate) value: &'l FixedDecimal,
     pub(crate) options: &'l FixedDecimalFormatterOptions,
     pub(crate) symbols: &'l DecimalSymbolsV1<'l>,
+    /// The rules and the rule set of an algorithmic numbering system.
+    pub(crate) algorithmic: Option<(&'l RbnfRulesV1<'l>, RuleSetInfo)>,
 }
 
 impl<'l> FormattedFixedDecimal<'l> {
     fn get_affixes(&self) -> Option<&AffixesV1> {
-        match self.value.sign() {
-            Sign::None => None,
-            Sign::Negative => Some(&self.symbols.minus_sign_affixes),
-            Sign::Positive => Some(&self.symbols.plus_sign_affixes),
-        }
+        get_sign_affixes(self.value.sign(), self.symbols)
     }
 }
 
@@ -35,6 +44,17 @@ impl<'l> Writeable for FormattedFixedDecimal<'l> {
     where
         W: core::fmt::Write + ?Sized,
     {
+        if let Some((rules, rule_set)) = self.algorithmic {
+            return crate::rbnf::format(
+                self.value,
+                rules,
+                rule_set,
+                self.symbols,
+                self.options,
+                &crate::rbnf::OtherPluralSelector,
+                sink,
+            );
+        }
         let affixes = self.get_affixes();
         if let Some(affixes) = affixes {
             sink.write_str(&affixes.prefix)?;
@@ -64,3 +84,58 @@ impl<'l> Writeable for FormattedFixedDecimal<'l> {
 }
 
 writeable::impl_display_with_writeable!(FormattedFixedDecimal<'_>);
+
+/// An intermediate structure returned by [`NotationFormatter`](crate::NotationFormatter).
+/// Use [`Writeable`][Writeable] to render the formatted decimal to a string or buffer.
+#[derive(Debug, PartialEq, Clone)]
+pub struct FormattedNotation<'l> {
+    /// The digits to render, without sign; the mantissa in scientific notation.
+    pub(crate) value: FixedDecimal,
+    pub(crate) sign: Sign,
+    /// The power of ten in scientific notation.
+    pub(crate) exponent: Option<i16>,
+    pub(crate) affixes: &'l AffixesV1<'l>,
+    pub(crate) exponent_separator: &'l str,
+    pub(crate) options: &'l FixedDecimalFormatterOptions,
+    pub(crate) symbols: &'l DecimalSymbolsV1<'l>,
+}
+
+impl<'l> Writeable for FormattedNotation<'l> {
+    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        // UTS 35: the sign is placed around the whole pattern, such as in "-%45".
+        let sign_affixes = get_sign_affixes(self.sign, self.symbols);
+        if let Some(sign_affixes) = sign_affixes {
+            sink.write_str(&sign_affixes.prefix)?;
+        }
+        sink.write_str(&self.affixes.prefix)?;
+        FormattedFixedDecimal {
+            value: &self.value,
+            options: self.options,
+            symbols: self.symbols,
+            algorithmic: None,
+        }
+        .write_to(sink)?;
+        if let Some(exponent) = self.exponent {
+            sink.write_str(self.exponent_separator)?;
+            FormattedFixedDecimal {
+                value: &FixedDecimal::from(exponent),
+                options: &FixedDecimalFormatterOptions {
+                    grouping_strategy: GroupingStrategy::Never,
+                },
+                symbols: self.symbols,
+                algorithmic: None,
+            }
+            .write_to(sink)?;
+        }
+        sink.write_str(&self.affixes.suffix)?;
+        if let Some(sign_affixes) = sign_affixes {
+            sink.write_str(&sign_affixes.suffix)?;
+        }
+        Ok(())
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedNotation<'_>);
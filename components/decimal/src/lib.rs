@@ -9,6 +9,10 @@
 //! This module is published as its own crate ([`icu_decimal`](https://docs.rs/icu_decimal/latest/icu_decimal/))
 //! and as part of the [`icu`](https://docs.rs/icu/latest/icu/) crate. See the latter for more details on the ICU4X project.
 //!
+//! Numbers can also be formatted as percentages or in scientific notation with
+//! [`NotationFormatter`], and numbers written in a locale can be parsed with
+//! [`FixedDecimalParser`].
+//!
 //! Support for currencies, measurement units, and compact notation is planned. To track progress,
 //! follow [icu4x#275](https://github.com/unicode-org/icu4x/issues/275).
 //!
@@ -75,6 +79,68 @@
 //! assert_writeable_eq!(fdf.format(&fixed_decimal), "๑,๐๐๐,๐๐๗");
 //! ```
 //!
+//! Algorithmic numbering systems, such as Roman numerals, are supported by a separate constructor
+//! that loads their rules.
+//!
+//! ```
+//! use icu::decimal::FixedDecimalFormatter;
+//! use icu::locid::locale;
+//! use writeable::assert_writeable_eq;
+//!
+//! let fdf = FixedDecimalFormatter::try_new_with_algorithmic_unstable(
+//!     &icu_testdata::unstable(),
+//!     &locale!("en-u-nu-roman").into(),
+//!     Default::default(),
+//! )
+//! .expect("Data should load successfully");
+//!
+//! let fixed_decimal = 1987.into();
+//!
+//! assert_writeable_eq!(fdf.format(&fixed_decimal), "MCMLXXXVII");
+//! ```
+//!
+//! The rules of the `hanidays`, `hans`, `hansfin`, `hant` and `hantfin` numbering systems are part
+//! of the Chinese rbnf data, so they are only available if the CLDR data includes `zh` and
+//! `zh-Hant`. Without them, the constructor returns [`DecimalError::UnsupportedNumberingSystem`].
+//!
+//! ## Format a percentage
+//!
+//! ```
+//! use fixed_decimal::FixedDecimal;
+//! use icu::decimal::options::Notation;
+//! use icu::decimal::NotationFormatter;
+//! use icu::locid::locale;
+//! use writeable::assert_writeable_eq;
+//!
+//! let formatter = NotationFormatter::try_new_unstable(
+//!     &icu_testdata::unstable(),
+//!     &locale!("en").into(),
+//!     Notation::Percent.into(),
+//! )
+//! .expect("Data should load successfully");
+//!
+//! let fixed_decimal = FixedDecimal::from(45).multiplied_pow10(-2);
+//!
+//! assert_writeable_eq!(formatter.format(fixed_decimal), "45%");
+//! ```
+//!
+//! ## Parse a number with Bengali digits
+//!
+//! ```
+//! use fixed_decimal::FixedDecimal;
+//! use icu::decimal::FixedDecimalParser;
+//! use icu::locid::locale;
+//!
+//! let parser = FixedDecimalParser::try_new_unstable(
+//!     &icu_testdata::unstable(),
+//!     &locale!("bn").into(),
+//!     Default::default(),
+//! )
+//! .expect("Data should load successfully");
+//!
+//! assert_eq!(parser.parse("১০,০০,০০৭"), Ok(FixedDecimal::from(1000007)));
+//! ```
+//!
 //! [`FixedDecimalFormatter`]: FixedDecimalFormatter
 
 // https://github.com/unicode-org/icu4x/blob/main/docs/process/boilerplate.md#library-annotations
@@ -98,25 +164,39 @@ extern crate alloc;
 mod error;
 mod format;
 mod grouper;
+mod notation;
 pub mod options;
+mod parse;
 pub mod provider;
+#[cfg(feature = "experimental")]
+pub mod rbnf;
+#[cfg(not(feature = "experimental"))]
+pub(crate) mod rbnf;
 
 pub use error::DecimalError;
-pub use format::FormattedFixedDecimal;
+pub use format::{FormattedFixedDecimal, FormattedNotation};
+pub use notation::NotationFormatter;
+pub use parse::FixedDecimalParser;
 
 #[doc(inline)]
 pub use DecimalError as Error;
 
 use alloc::string::String;
 use fixed_decimal::FixedDecimal;
+use icu_locid::extensions_unicode_key as key;
 use icu_provider::prelude::*;
 use writeable::Writeable;
 
+/// The algorithmic numbering systems whose rules come from the Chinese rule-based number format
+/// data rather than from the root locale.
+const CHINESE_ALGORITHMIC_SYSTEMS: &[&str] = &["hanidays", "hans", "hansfin", "hant", "hantfin"];
+
 /// A formatter for [`FixedDecimal`], rendering decimal digits in an i18n-friendly way.
 ///
 /// [`FixedDecimalFormatter`] supports:
 ///
-/// 1. Rendering in the local numbering system
+/// 1. Rendering in the local numbering system, including algorithmic numbering systems such as
+///    Roman numerals
 /// 2. Locale-sensitive grouping separator positions
 /// 3. Locale-sensitive plus and minus signs
 ///
@@ -126,11 +206,16 @@ use writeable::Writeable;
 pub struct FixedDecimalFormatter {
     options: options::FixedDecimalFormatterOptions,
     symbols: DataPayload<provider::DecimalSymbolsV1Marker>,
+    algorithmic: Option<DataPayload<provider::AlgorithmicSymbolsV1Marker>>,
 }
 
 impl FixedDecimalFormatter {
     /// Creates a new [`FixedDecimalFormatter`] from locale data and an options bag.
     ///
+    /// Numbers are formatted with the digits of the numbering system of the locale. To format
+    /// algorithmic numbering systems, such as Roman numerals, use
+    /// [`FixedDecimalFormatter::try_new_with_algorithmic_unstable`].
+    ///
     /// [📚 Help choosing a constructor](icu_provider::constructors)
     /// <div class="stab unstable">
     /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
@@ -146,7 +231,11 @@ impl FixedDecimalFormatter {
                 metadata: Default::default(),
             })?
             .take_payload()?;
-        Ok(Self { options, symbols })
+        Ok(Self {
+            options,
+            symbols,
+            algorithmic: None,
+        })
     }
 
     icu_provider::gen_any_buffer_constructors!(
@@ -155,12 +244,95 @@ impl FixedDecimalFormatter {
         error: DecimalError
     );
 
+    /// Creates a new [`FixedDecimalFormatter`] that also formats the algorithmic numbering
+    /// system in the `-u-nu` extension of the locale, such as `roman` or `hebr`.
+    ///
+    /// Numbering systems without rules in the data are formatted with digits, like in
+    /// [`FixedDecimalFormatter::try_new_unstable`]. The exceptions are `hanidays`, `hans`,
+    /// `hansfin`, `hant` and `hantfin`, whose rules are part of the Chinese rule-based number
+    /// format data: if the data has no rules for them, this returns
+    /// [`DecimalError::UnsupportedNumberingSystem`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::decimal::FixedDecimalFormatter;
+    /// use icu::locid::locale;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let fdf = FixedDecimalFormatter::try_new_with_algorithmic_unstable(
+    ///     &icu_testdata::unstable(),
+    ///     &locale!("en-u-nu-roman").into(),
+    ///     Default::default(),
+    /// )
+    /// .expect("Data should load successfully");
+    ///
+    /// let fixed_decimal = 1987.into();
+    /// assert_writeable_eq!(fdf.format(&fixed_decimal), "MCMLXXXVII");
+    /// ```
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_with_algorithmic_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        options: options::FixedDecimalFormatterOptions,
+    ) -> Result<Self, DecimalError>
+    where
+        D: DataProvider<provider::DecimalSymbolsV1Marker>
+            + DataProvider<provider::AlgorithmicSymbolsV1Marker>
+            + ?Sized,
+    {
+        let mut formatter = Self::try_new_unstable(data_provider, locale, options)?;
+
+        // The rules of algorithmic numbering systems do not depend on the locale, so they are
+        // stored under `und` with the `-u-nu` extension.
+        if let Some(nu) = locale.get_unicode_ext(&key!("nu")) {
+            let unsupported = CHINESE_ALGORITHMIC_SYSTEMS.contains(&&*nu.write_to_string());
+            let mut numbering_system = DataLocale::default();
+            numbering_system.set_unicode_ext(key!("nu"), nu);
+            formatter.algorithmic = match data_provider.load(DataRequest {
+                locale: &numbering_system,
+                metadata: Default::default(),
+            }) {
+                Err(DataError {
+                    kind: DataErrorKind::MissingLocale | DataErrorKind::MissingDataKey,
+                    ..
+                }) if unsupported => return Err(DecimalError::UnsupportedNumberingSystem),
+                Err(DataError {
+                    kind: DataErrorKind::MissingLocale | DataErrorKind::MissingDataKey,
+                    ..
+                }) => None,
+                response => Some(response?.take_payload()?),
+            };
+        }
+
+        Ok(formatter)
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        options: options::FixedDecimalFormatterOptions,
+        error: DecimalError,
+        functions: [
+            Self::try_new_with_algorithmic_unstable,
+            try_new_with_algorithmic_with_any_provider,
+            try_new_with_algorithmic_with_buffer_provider
+        ]
+    );
+
     /// Formats a [`FixedDecimal`], returning a [`FormattedFixedDecimal`].
     pub fn format<'l>(&'l self, value: &'l FixedDecimal) -> FormattedFixedDecimal<'l> {
         FormattedFixedDecimal {
             value,
             options: &self.options,
             symbols: self.symbols.get(),
+            algorithmic: self.algorithmic.as_ref().and_then(|data| {
+                let rules = &data.get().rules;
+                Some((rules, rules.rule_sets.get_copied(&data.get().rule_set)?))
+            }),
         }
     }
 
@@ -0,0 +1,173 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::format::FormattedNotation;
+use crate::options::*;
+use crate::provider::*;
+use crate::{DecimalError, FixedDecimalFormatter};
+use alloc::string::String;
+use fixed_decimal::{FixedDecimal, Sign};
+use icu_provider::prelude::*;
+use writeable::Writeable;
+
+/// A formatter for [`FixedDecimal`] in percent, permille, scientific, or engineering notation.
+///
+/// The number is not rounded; round it with the methods on [`FixedDecimal`] before formatting.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_decimal::options::Notation;
+/// use icu_decimal::NotationFormatter;
+/// use icu_locid::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let formatter = NotationFormatter::try_new_unstable(
+///     &icu_testdata::unstable(),
+///     &locale!("fr").into(),
+///     Notation::Percent.into(),
+/// )
+/// .expect("Data should load successfully");
+///
+/// let value = FixedDecimal::from(-45).multiplied_pow10(-2);
+/// assert_writeable_eq!(formatter.format(value), "-45\u{a0}%");
+///
+/// let formatter = NotationFormatter::try_new_unstable(
+///     &icu_testdata::unstable(),
+///     &locale!("tr").into(),
+///     Notation::Percent.into(),
+/// )
+/// .expect("Data should load successfully");
+///
+/// let value = FixedDecimal::from(125).multiplied_pow10(-1);
+/// assert_writeable_eq!(formatter.format(value), "%1.250");
+/// ```
+pub struct NotationFormatter {
+    fixed_decimal_formatter: FixedDecimalFormatter,
+    symbols: DataPayload<NotationSymbolsV1Marker>,
+    notation: Notation,
+}
+
+impl NotationFormatter {
+    /// Creates a new [`NotationFormatter`] from locale data and an options bag.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        options: NotationFormatterOptions,
+    ) -> Result<Self, DecimalError>
+    where
+        D: DataProvider<DecimalSymbolsV1Marker> + DataProvider<NotationSymbolsV1Marker> + ?Sized,
+    {
+        let fixed_decimal_formatter = FixedDecimalFormatter::try_new_unstable(
+            data_provider,
+            locale,
+            options.fixed_decimal_formatter_options,
+        )?;
+        let symbols = data_provider
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+        Ok(Self {
+            fixed_decimal_formatter,
+            symbols,
+            notation: options.notation,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        options: NotationFormatterOptions,
+        error: DecimalError
+    );
+
+    /// Formats a [`FixedDecimal`], returning a [`FormattedNotation`].
+    ///
+    /// In scientific and engineering notation, the digits of the number are kept, except for
+    /// trailing zeros in the integer part of a number without fraction digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// use icu_decimal::options::Notation;
+    /// use icu_decimal::NotationFormatter;
+    /// use icu_locid::locale;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let formatter = NotationFormatter::try_new_unstable(
+    ///     &icu_testdata::unstable(),
+    ///     &locale!("en").into(),
+    ///     Notation::Scientific.into(),
+    /// )
+    /// .expect("Data should load successfully");
+    ///
+    /// assert_writeable_eq!(formatter.format(FixedDecimal::from(1200)), "1.2E3");
+    /// assert_writeable_eq!(
+    ///     formatter.format(FixedDecimal::from(120000).multiplied_pow10(-2)),
+    ///     "1.20000E3"
+    /// );
+    /// assert_writeable_eq!(
+    ///     formatter.format(FixedDecimal::from(-25).multiplied_pow10(-4)),
+    ///     "-2.5E-3"
+    /// );
+    /// assert_writeable_eq!(formatter.format(FixedDecimal::from(0)), "0E0");
+    /// ```
+    pub fn format(&self, mut value: FixedDecimal) -> FormattedNotation<'_> {
+        let sign = value.sign();
+        value.set_sign(Sign::None);
+        let exponent = match self.notation {
+            Notation::Percent => {
+                value.multiply_pow10(2);
+                value.trim_start();
+                None
+            }
+            Notation::Permille => {
+                value.multiply_pow10(3);
+                value.trim_start();
+                None
+            }
+            Notation::Scientific | Notation::Engineering => {
+                let has_fraction_digits = *value.magnitude_range().start() < 0;
+                let mut exponent = value.nonzero_magnitude_start();
+                if self.notation == Notation::Engineering {
+                    exponent = exponent.div_euclid(3) * 3;
+                }
+                value.multiply_pow10(-exponent);
+                value.trim_start();
+                if !has_fraction_digits {
+                    value.trim_end();
+                }
+                Some(exponent)
+            }
+        };
+        let symbols = self.symbols.get();
+        let affixes = match self.notation {
+            Notation::Percent => &symbols.percent_affixes,
+            Notation::Permille => &symbols.permille_affixes,
+            Notation::Scientific | Notation::Engineering => &symbols.scientific_affixes,
+        };
+        FormattedNotation {
+            value,
+            sign,
+            exponent,
+            affixes,
+            exponent_separator: &symbols.exponent_separator,
+            options: &self.fixed_decimal_formatter.options,
+            symbols: self.fixed_decimal_formatter.symbols.get(),
+        }
+    }
+
+    /// Formats a [`FixedDecimal`], returning a [`String`].
+    pub fn format_to_string(&self, value: FixedDecimal) -> String {
+        self.format(value).write_to_string().into_owned()
+    }
+}
@@ -2,7 +2,9 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-//! Options for [`FixedDecimalFormatter`](crate::FixedDecimalFormatter).
+//! Options for [`FixedDecimalFormatter`](crate::FixedDecimalFormatter),
+//! [`NotationFormatter`](crate::NotationFormatter), and
+//! [`FixedDecimalParser`](crate::FixedDecimalParser).
 
 /// A bag of options defining how numbers will be formatted by
 /// [`FixedDecimalFormatter`](crate::FixedDecimalFormatter).
@@ -66,3 +68,130 @@ impl Default for GroupingStrategy {
         Self::Auto
     }
 }
+
+/// A bag of options defining how numbers will be formatted by
+/// [`NotationFormatter`](crate::NotationFormatter).
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+#[non_exhaustive]
+pub struct NotationFormatterOptions {
+    /// The notation to render the number in.
+    pub notation: Notation,
+    /// The options for rendering the digits of the number.
+    pub fixed_decimal_formatter_options: FixedDecimalFormatterOptions,
+}
+
+impl From<Notation> for NotationFormatterOptions {
+    fn from(notation: Notation) -> Self {
+        Self {
+            notation,
+            ..Default::default()
+        }
+    }
+}
+
+/// The notation used by [`NotationFormatter`](crate::NotationFormatter).
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_decimal::options::Notation;
+/// use icu_decimal::NotationFormatter;
+/// use icu_locid::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let value = FixedDecimal::from(12345).multiplied_pow10(-6);
+///
+/// let formatter = NotationFormatter::try_new_unstable(
+///     &icu_testdata::unstable(),
+///     &locale!("en").into(),
+///     Notation::Permille.into(),
+/// )
+/// .expect("Data should load successfully");
+/// assert_writeable_eq!(formatter.format(value.clone()), "12.345‰");
+///
+/// let formatter = NotationFormatter::try_new_unstable(
+///     &icu_testdata::unstable(),
+///     &locale!("en").into(),
+///     Notation::Engineering.into(),
+/// )
+/// .expect("Data should load successfully");
+/// assert_writeable_eq!(formatter.format(value), "12.345E-3");
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Notation {
+    /// Render the number multiplied by 100 with a percent sign, such as "45%".
+    Percent,
+
+    /// Render the number multiplied by 1000 with a permille sign, such as "450‰".
+    Permille,
+
+    /// Render the number with a single integer digit and a power of ten, such as "1.2345E4".
+    Scientific,
+
+    /// Render the number with one to three integer digits and a power of ten that is a multiple
+    /// of three, such as "12.345E3".
+    Engineering,
+}
+
+impl Default for Notation {
+    fn default() -> Self {
+        Self::Percent
+    }
+}
+
+/// A bag of options defining how numbers will be parsed by
+/// [`FixedDecimalParser`](crate::FixedDecimalParser).
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+#[non_exhaustive]
+pub struct FixedDecimalParserOptions {
+    /// How closely the input has to follow the symbols of the locale.
+    pub strictness: ParseStrictness,
+}
+
+/// How closely the input of [`FixedDecimalParser`](crate::FixedDecimalParser) has to follow
+/// the symbols of the locale.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_decimal::options::{FixedDecimalParserOptions, ParseStrictness};
+/// use icu_decimal::FixedDecimalParser;
+/// use icu_locid::locale;
+///
+/// let mut options = FixedDecimalParserOptions::default();
+/// options.strictness = ParseStrictness::Lenient;
+/// let parser = FixedDecimalParser::try_new_unstable(
+///     &icu_testdata::unstable(),
+///     &locale!("fr").into(),
+///     options,
+/// )
+/// .expect("Data should load successfully");
+///
+/// // "fr" groups with a narrow no-break space, but any space is accepted.
+/// assert_eq!(parser.parse(" 1 234,5 "), Ok(FixedDecimal::from(12345).multiplied_pow10(-1)));
+/// // Fullwidth signs and ASCII digits are accepted as well.
+/// assert_eq!(parser.parse("－1000"), Ok(FixedDecimal::from(-1000)));
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ParseStrictness {
+    /// Accept variants of the symbols of the locale, ASCII digits, surrounding whitespace, and
+    /// grouping separators anywhere in the integer part.
+    ///
+    /// Variants of a symbol are characters in the same CLDR lenient parse set, such as "−" and
+    /// "－" for "-". Bidi marks in sign affixes are optional.
+    Lenient,
+
+    /// Accept only the symbols and digits of the locale, with grouping separators either
+    /// left out or where the locale puts them.
+    Strict,
+}
+
+impl Default for ParseStrictness {
+    fn default() -> Self {
+        Self::Lenient
+    }
+}
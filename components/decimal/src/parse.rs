@@ -0,0 +1,352 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::grouper;
+use crate::options::*;
+use crate::provider::*;
+use crate::DecimalError;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use fixed_decimal::FixedDecimal;
+use icu_provider::prelude::*;
+
+/// Characters that are treated as the same symbol in lenient mode.
+///
+/// These are the number `parseLenients` of the CLDR root locale for the minus sign, the plus
+/// sign, and the comma, and the general one for the period.
+const LENIENT_SETS: &[&[char]] = &[
+    &['-', '‒', '⁻', '₋', '−', '➖', '﹣', '－'],
+    &['+', '⁺', '₊', '➕', '﬩', '﹢', '＋'],
+    &[',', '،', '٫', '、', '︐', '︑', '﹐', '﹑', '，', '､'],
+    &['.', '․', '。', '︒', '﹒', '．', '｡'],
+];
+
+/// Spaces used as grouping separators, which are interchangeable in lenient mode.
+const SPACES: &[char] = &[' ', '\u{a0}', '\u{202f}'];
+
+/// Bidi marks in sign affixes, which are ignored in lenient mode.
+const BIDI_MARKS: &[char] = &['\u{200e}', '\u{200f}', '\u{61c}'];
+
+fn is_lenient_match(a: char, b: char) -> bool {
+    a == b
+        || LENIENT_SETS
+            .iter()
+            .any(|set| set.contains(&a) && set.contains(&b))
+        || SPACES.contains(&a) && SPACES.contains(&b)
+}
+
+/// A parser for [`FixedDecimal`], reading numbers written with the symbols of a locale.
+///
+/// [`FixedDecimalParser`] supports:
+///
+/// 1. Digits of the local numbering system
+/// 2. Locale-sensitive grouping and decimal separators
+/// 3. Locale-sensitive plus and minus signs
+///
+/// In [lenient](ParseStrictness::Lenient) mode, the default, variants of the symbols such as
+/// "−" for "-" are accepted as well. Algorithmic numbering systems, such as Roman numerals, are
+/// not supported.
+///
+/// Read more about the options in the [`options`](crate::options) module.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_decimal::FixedDecimalParser;
+/// use icu_locid::locale;
+///
+/// let parser = FixedDecimalParser::try_new_unstable(
+///     &icu_testdata::unstable(),
+///     &locale!("es").into(),
+///     Default::default(),
+/// )
+/// .expect("Data should load successfully");
+///
+/// assert_eq!(
+///     parser.parse("-1.234,5"),
+///     Ok(FixedDecimal::from(-12345).multiplied_pow10(-1))
+/// );
+/// ```
+pub struct FixedDecimalParser {
+    options: FixedDecimalParserOptions,
+    symbols: DataPayload<DecimalSymbolsV1Marker>,
+}
+
+impl FixedDecimalParser {
+    /// Creates a new [`FixedDecimalParser`] from locale data and an options bag.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        options: FixedDecimalParserOptions,
+    ) -> Result<Self, DecimalError>
+    where
+        D: DataProvider<DecimalSymbolsV1Marker> + ?Sized,
+    {
+        let symbols = data_provider
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+        Ok(Self { options, symbols })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        options: FixedDecimalParserOptions,
+        error: DecimalError
+    );
+
+    /// Parses a number into a [`FixedDecimal`].
+    ///
+    /// Leading zeros and trailing fraction zeros are kept as padding of the [`FixedDecimal`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DecimalError::Parse`] with the byte position of the first character that is
+    /// not understood, or of the end of the input if it ends too early.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// use icu_decimal::options::{FixedDecimalParserOptions, ParseStrictness};
+    /// use icu_decimal::{DecimalError, FixedDecimalParser};
+    /// use icu_locid::locale;
+    ///
+    /// let mut options = FixedDecimalParserOptions::default();
+    /// options.strictness = ParseStrictness::Strict;
+    /// let parser = FixedDecimalParser::try_new_unstable(
+    ///     &icu_testdata::unstable(),
+    ///     &locale!("en").into(),
+    ///     options,
+    /// )
+    /// .expect("Data should load successfully");
+    ///
+    /// assert_eq!(parser.parse("1,234,567"), Ok(FixedDecimal::from(1234567)));
+    /// // The grouping separators must be where the locale puts them.
+    /// assert_eq!(parser.parse("12,34,567"), Err(DecimalError::Parse(2)));
+    /// // The minus sign of the locale is "-".
+    /// assert_eq!(parser.parse("−5"), Err(DecimalError::Parse(0)));
+    /// ```
+    pub fn parse(&self, input: &str) -> Result<FixedDecimal, DecimalError> {
+        let symbols = self.symbols.get();
+        let lenient = self.options.strictness == ParseStrictness::Lenient;
+
+        let mut start = 0;
+        let mut end = input.len();
+        if lenient {
+            start = end - input.trim_start().len();
+            end = input.trim_end().len().max(start);
+        }
+
+        let mut ascii = Vec::with_capacity(end - start + 1);
+        for (affixes, sign) in [
+            (&symbols.minus_sign_affixes, b'-'),
+            (&symbols.plus_sign_affixes, b'+'),
+        ] {
+            if affixes.prefix.is_empty() && affixes.suffix.is_empty() {
+                continue;
+            }
+            let body = self
+                .match_prefix(input.get(start..end).unwrap_or_default(), &affixes.prefix)
+                .and_then(|prefix_len| {
+                    let suffix_len = self.match_suffix(
+                        input.get(start + prefix_len..end).unwrap_or_default(),
+                        &affixes.suffix,
+                    )?;
+                    Some((start + prefix_len, end - suffix_len))
+                });
+            if let Some((body_start, body_end)) = body {
+                ascii.push(sign);
+                start = body_start;
+                end = body_end;
+                break;
+            }
+        }
+        if lenient {
+            let body = input.get(start..end).unwrap_or_default();
+            start = end - body.trim_start().len();
+            end = start + body.trim().len();
+        }
+
+        // The number of integer digits before each grouping separator, and its position.
+        let mut separators = Vec::new();
+        let mut integer_digits = 0;
+        let mut fraction_digits = 0;
+        let mut decimal_position = None;
+        let body = input.get(start..end).unwrap_or_default();
+        let mut i = 0;
+        while let Some(c) = body.get(i..).and_then(|rest| rest.chars().next()) {
+            let position = start + i;
+            let rest = body.get(i..).unwrap_or_default();
+            let digit = symbols
+                .digits
+                .iter()
+                .position(|&d| d == c)
+                .or_else(|| c.to_digit(10).filter(|_| lenient).map(|d| d as usize));
+            if let Some(digit) = digit {
+                #[allow(clippy::indexing_slicing)] // digit < 10
+                ascii.push(b"0123456789"[digit]);
+                if decimal_position.is_some() {
+                    fraction_digits += 1;
+                } else {
+                    integer_digits += 1;
+                }
+                i += c.len_utf8();
+                continue;
+            }
+            // Exact matches take precedence over lenient ones.
+            let exact = |symbol: &str| {
+                Some(symbol.len()).filter(|_| !symbol.is_empty() && rest.starts_with(symbol))
+            };
+            let separators_len = match (
+                exact(&symbols.decimal_separator),
+                exact(&symbols.grouping_separator),
+            ) {
+                (None, None) if lenient => (
+                    self.match_separator(rest, &symbols.decimal_separator),
+                    self.match_separator(rest, &symbols.grouping_separator),
+                ),
+                exact => exact,
+            };
+            match separators_len {
+                (Some(len), _) if decimal_position.is_none() => {
+                    if integer_digits == 0 {
+                        if !lenient {
+                            return Err(DecimalError::Parse(position));
+                        }
+                        ascii.push(b'0');
+                    }
+                    ascii.push(b'.');
+                    decimal_position = Some(position);
+                    i += len;
+                }
+                (_, Some(len))
+                    if decimal_position.is_none()
+                        && integer_digits > 0
+                        && separators.last().map(|&(digits, _)| digits) != Some(integer_digits) =>
+                {
+                    separators.push((integer_digits, position));
+                    i += len;
+                }
+                _ => return Err(DecimalError::Parse(position)),
+            }
+        }
+
+        // A grouping separator must be followed by a digit.
+        if let Some(&(digits, position)) = separators.last() {
+            if digits == integer_digits {
+                return Err(DecimalError::Parse(position));
+            }
+        }
+        if integer_digits + fraction_digits == 0 {
+            return Err(DecimalError::Parse(end));
+        }
+        if decimal_position.is_some() && fraction_digits == 0 {
+            if !lenient {
+                return Err(DecimalError::Parse(end));
+            }
+            ascii.truncate(ascii.len() - 1);
+        }
+
+        if !lenient && !separators.is_empty() {
+            self.check_grouping(&separators, integer_digits, decimal_position.unwrap_or(end))?;
+        }
+
+        FixedDecimal::try_from(ascii.as_slice()).map_err(|e| match e {
+            fixed_decimal::Error::Limit => DecimalError::Limit,
+            _ => DecimalError::Parse(start),
+        })
+    }
+
+    /// Checks that the grouping separators are where the locale puts them, and returns the
+    /// position of the first misplaced one otherwise. If a separator is missing, the position
+    /// is the next separator, or the end of the integer part.
+    fn check_grouping(
+        &self,
+        separators: &[(usize, usize)],
+        integer_digits: usize,
+        integer_end: usize,
+    ) -> Result<(), DecimalError> {
+        let sizes = GroupingSizesV1 {
+            // Separators are accepted on numbers that would be formatted without them.
+            min_grouping: 1,
+            ..self.symbols.get().grouping_sizes
+        };
+        let upper_magnitude = integer_digits as i16 - 1;
+        let mut separators = separators.iter().peekable();
+        for digits in 1..integer_digits {
+            let expected = grouper::check(
+                upper_magnitude,
+                (integer_digits - digits) as i16,
+                GroupingStrategy::Always,
+                &sizes,
+            );
+            let next = separators.peek().copied();
+            match next {
+                Some(&(d, position)) if d == digits => {
+                    if !expected {
+                        return Err(DecimalError::Parse(position));
+                    }
+                    separators.next();
+                }
+                _ if expected => {
+                    return Err(DecimalError::Parse(
+                        next.map_or(integer_end, |&(_, position)| position),
+                    ));
+                }
+                _ => (),
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the length of the match of a separator at the start of `text`.
+    fn match_separator(&self, text: &str, symbol: &str) -> Option<usize> {
+        self.match_prefix(text, symbol).filter(|&len| len > 0)
+    }
+
+    /// Returns the length of the match of `symbol` at the start of `text`.
+    fn match_prefix(&self, text: &str, symbol: &str) -> Option<usize> {
+        if self.options.strictness == ParseStrictness::Strict {
+            return Some(symbol.len()).filter(|_| text.starts_with(symbol));
+        }
+        let mut len = 0;
+        let mut chars = text.chars().peekable();
+        for s in symbol.chars().filter(|c| !BIDI_MARKS.contains(c)) {
+            while let Some(&c) = chars.peek().filter(|c| BIDI_MARKS.contains(c)) {
+                len += c.len_utf8();
+                chars.next();
+            }
+            let c = chars.next().filter(|&c| is_lenient_match(c, s))?;
+            len += c.len_utf8();
+        }
+        Some(len)
+    }
+
+    /// Returns the length of the match of `symbol` at the end of `text`.
+    fn match_suffix(&self, text: &str, symbol: &str) -> Option<usize> {
+        if self.options.strictness == ParseStrictness::Strict {
+            return Some(symbol.len()).filter(|_| text.ends_with(symbol));
+        }
+        let mut len = 0;
+        let mut chars = text.chars().rev().peekable();
+        for s in symbol.chars().rev().filter(|c| !BIDI_MARKS.contains(c)) {
+            while let Some(&c) = chars.peek().filter(|c| BIDI_MARKS.contains(c)) {
+                len += c.len_utf8();
+                chars.next();
+            }
+            let c = chars.next().filter(|&c| is_lenient_match(c, s))?;
+            len += c.len_utf8();
+        }
+        Some(len)
+    }
+}
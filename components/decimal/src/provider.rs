@@ -12,6 +12,8 @@
 
 use alloc::borrow::Cow;
 use icu_provider::{yoke, zerofrom};
+use zerovec::vecs::Index32;
+use zerovec::{VarZeroVec, ZeroMap};
 
 /// A collection of strings to affix to a decimal number.
 #[derive(Debug, PartialEq, Clone, yoke::Yokeable, zerofrom::ZeroFrom)]
@@ -116,3 +118,199 @@ impl Default for DecimalSymbolsV1<'static> {
         }
     }
 }
+
+/// Symbols and patterns required for formatting a [`FixedDecimal`](crate::FixedDecimal) in
+/// percent, permille, or scientific notation.
+///
+/// The sign of the number is applied outside of these affixes.
+#[icu_provider::data_struct(marker(
+    NotationSymbolsV1Marker,
+    "decimal/notation@1",
+    extension_key = "nu"
+))]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_decimal::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct NotationSymbolsV1<'data> {
+    /// Prefix and suffix to apply to a percentage, including the percent sign.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub percent_affixes: AffixesV1<'data>,
+
+    /// Prefix and suffix to apply to a permillage, including the permille sign.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub permille_affixes: AffixesV1<'data>,
+
+    /// Prefix and suffix to apply to a number in scientific notation.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub scientific_affixes: AffixesV1<'data>,
+
+    /// String used to separate the mantissa and the exponent in scientific notation.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub exponent_separator: Cow<'data, str>,
+}
+
+impl Default for NotationSymbolsV1<'static> {
+    fn default() -> Self {
+        Self {
+            percent_affixes: AffixesV1 {
+                prefix: Cow::Borrowed(""),
+                suffix: Cow::Borrowed("%"),
+            },
+            permille_affixes: AffixesV1 {
+                prefix: Cow::Borrowed(""),
+                suffix: Cow::Borrowed("‰"),
+            },
+            scientific_affixes: AffixesV1 {
+                prefix: Cow::Borrowed(""),
+                suffix: Cow::Borrowed(""),
+            },
+            exponent_separator: "E".into(),
+        }
+    }
+}
+
+/// Rules for formatting numbers in an algorithmic numbering system, such as `roman` or `hebr`,
+/// instead of with the digits in [`DecimalSymbolsV1`].
+///
+/// This data is keyed by `und` with the `-u-nu` extension of the numbering system, since the
+/// rules of a numbering system are the same in every locale.
+#[icu_provider::data_struct(marker(
+    AlgorithmicSymbolsV1Marker,
+    "decimal/algorithmic@1",
+    extension_key = "nu"
+))]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_decimal::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct AlgorithmicSymbolsV1<'data> {
+    /// The name of the rule set that formats numbers, such as "roman-upper".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub rule_set: Cow<'data, str>,
+
+    /// The rule set that formats numbers and the rule sets that its rules refer to.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub rules: RbnfRulesV1<'data>,
+}
+
+/// The rule sets of one group of CLDR rule-based number format rules, such as `SpelloutRules`.
+///
+/// Rule sets only refer to other rule sets of the same group.
+///
+/// See <https://unicode-org.github.io/icu/userguide/format_parse/numbers/rbnf.html> for the
+/// syntax of the rules.
+#[icu_provider::data_struct]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_decimal::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct RbnfRulesV1<'data> {
+    /// The rule sets, keyed by their name without the leading `%` or `%%`, such as
+    /// "spellout-cardinal". Rule sets that are only used for parsing are not included.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub rule_sets: ZeroMap<'data, str, RuleSetInfo>,
+
+    /// The rules of all rule sets. The rules of some locales take more than 64 KiB, so this
+    /// uses 32-bit indices.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub rules: VarZeroVec<'data, RuleULE, Index32>,
+}
+
+/// The location of a rule set in [`RbnfRulesV1::rules`], and its properties.
+///
+/// The rules of a rule set are the special rules (those that are not of kind
+/// [`RuleKind::Normal`]), followed by the normal rules in ascending order of their base value.
+#[derive(Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Debug)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_decimal::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[zerovec::make_ule(RuleSetInfoULE)]
+pub struct RuleSetInfo {
+    /// The index of the first rule.
+    pub start: u16,
+    /// The index after the last rule.
+    pub end: u16,
+    /// Whether the rule set is private (`%%`), that is, only usable from other rules.
+    pub private: bool,
+    /// Whether the rule set formats fractional parts, because a fraction rule refers to it with
+    /// `>%name>`. The base values of its rules are denominators.
+    pub fraction: bool,
+}
+
+/// The kind of a [`Rule`], which is given by its descriptor.
+#[derive(Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Debug)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_decimal::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[repr(u8)]
+#[zerovec::make_ule(RuleKindULE)]
+pub enum RuleKind {
+    /// A rule for integers that are at least its base value, such as "100: << hundred[ >>];".
+    Normal = 0,
+    /// The rule for negative numbers, "-x".
+    Negative = 1,
+    /// The rule for numbers with a fractional part, "x.x".
+    ImproperFraction = 2,
+    /// The rule for numbers between 0 and 1, "0.x".
+    ProperFraction = 3,
+    /// The master rule, "x.0", which is used for numbers with a fractional part that no
+    /// fraction rule applies to.
+    Master = 4,
+}
+
+/// A single rule of a rule set.
+///
+/// Optional text in brackets is expanded at data generation time, the way ICU does it:
+/// "100: << hundred[ >>];" becomes the two rules "100: << hundred" and "101: << hundred >>",
+/// both with a divisor of 100, and "x.x: << point[ >>];" becomes the proper fraction rule
+/// "<< point" and the improper fraction rule "<< point >>". In rule sets that format
+/// fractional parts, both rules keep the base value, and the one without the optional text is
+/// used for numerators of 1.
+#[zerovec::make_varule(RuleULE)]
+#[zerovec::derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize),
+    zerovec::derive(Deserialize)
+)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    zerovec::derive(Serialize),
+    databake(path = icu_decimal::provider),
+)]
+pub struct Rule<'data> {
+    /// The kind of the rule.
+    pub kind: RuleKind,
+    /// The base value of a [`RuleKind::Normal`] rule, or 0.
+    pub base_value: u64,
+    /// The divisor of the rule, which is the highest power of the radix (10 unless given in the
+    /// descriptor, such as in "80/20:") that is at most the base value, or 1.
+    pub divisor: u64,
+    /// The rule text with the descriptor, a leading apostrophe, and the final `;` removed,
+    /// such as "←← hundred →→" or "=#,##0=$(ordinal,one{st}other{th})$".
+    ///
+    /// Substitutions are written with arrows as in the CLDR JSON data, but `<` and `>` are
+    /// accepted as well.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub text: Cow<'data, str>,
+}
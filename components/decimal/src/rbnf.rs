@@ -0,0 +1,592 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! 🚧 \[Experimental\] Formatting numbers with CLDR rule-based number format (RBNF) rules.
+//!
+//! <div class="stab unstable">
+//! 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+//! including in SemVer minor releases. It can be enabled with the "experimental" feature
+//! of the icu meta-crate. Use with caution.
+//! </div>
+//!
+//! This is the rule engine behind the algorithmic numbering systems of
+//! [`FixedDecimalFormatter`](crate::FixedDecimalFormatter), such as Roman numerals. The
+//! `icu_rbnf` crate uses it for spellout and ordinal formats, whose rules are selected by
+//! plural forms; it supplies those through a [`PluralSelector`].
+//!
+//! The rules are the [`RbnfRulesV1`] data struct.
+
+use crate::options::{FixedDecimalFormatterOptions, GroupingStrategy};
+use crate::provider::{DecimalSymbolsV1, RbnfRulesV1, Rule, RuleKind, RuleSetInfo};
+use crate::FormattedFixedDecimal;
+use core::convert::TryFrom;
+use core::fmt::{self, Write};
+use fixed_decimal::{FixedDecimal, Sign};
+use icu_provider::zerofrom::ZeroFrom;
+use writeable::Writeable;
+
+/// The deepest nesting of rule sets that is followed, which guards against cycles in the data.
+const MAX_DEPTH: u8 = 64;
+
+/// The number of fraction digits that are taken into account.
+const MAX_FRACTION_DIGITS: u32 = 18;
+
+/// Selects the plural forms of `$(cardinal,…)$` and `$(ordinal,…)$` in rule texts.
+pub trait PluralSelector {
+    /// Returns the CLDR keyword of the plural category of `n`, such as "one" or "other".
+    fn select(&self, ordinal: bool, n: u64) -> &'static str;
+}
+
+/// A [`PluralSelector`] for rules without plural forms, such as those of the algorithmic
+/// numbering systems, which always selects `other`.
+pub(crate) struct OtherPluralSelector;
+
+impl PluralSelector for OtherPluralSelector {
+    fn select(&self, _ordinal: bool, _n: u64) -> &'static str {
+        "other"
+    }
+}
+
+/// Formats `value` with the rule set `rule_set` of `rules`.
+///
+/// Decimal patterns in the rules, such as `=#,##0=`, are formatted with `symbols`. Numbers whose
+/// integer part does not fit into a `u64` are written with digits, with the grouping of
+/// `options`. At most 18 fraction digits are taken into account.
+pub fn format<'l, W: Write + ?Sized>(
+    value: &FixedDecimal,
+    rules: &'l RbnfRulesV1<'l>,
+    rule_set: RuleSetInfo,
+    symbols: &'l DecimalSymbolsV1<'l>,
+    options: &FixedDecimalFormatterOptions,
+    plurals: &'l dyn PluralSelector,
+    sink: &mut W,
+) -> fmt::Result {
+    match Number::try_from_fixed_decimal(value) {
+        Some(number) => Executor {
+            rules,
+            rule_set,
+            symbols,
+            plurals,
+        }
+        .format(rule_set, number, sink, 0),
+        None => FormattedFixedDecimal {
+            value,
+            options,
+            symbols,
+            algorithmic: None,
+        }
+        .write_to(sink),
+    }
+}
+
+/// A number as the rules see it: a sign, an integer part, and a fractional part of
+/// `fraction / 10^fraction_digits` without trailing zeros.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct Number {
+    negative: bool,
+    integer: u64,
+    fraction: u64,
+    fraction_digits: u32,
+}
+
+impl Number {
+    fn from_integer(integer: u64) -> Self {
+        Self {
+            negative: false,
+            integer,
+            fraction: 0,
+            fraction_digits: 0,
+        }
+    }
+
+    /// Returns `None` if the integer part does not fit into a `u64`.
+    fn try_from_fixed_decimal(value: &FixedDecimal) -> Option<Self> {
+        let range = value.magnitude_range();
+        let mut integer = 0u64;
+        for magnitude in (0..=*range.end()).rev() {
+            integer = integer
+                .checked_mul(10)?
+                .checked_add(value.digit_at(magnitude) as u64)?;
+        }
+        let mut fraction = 0u64;
+        let mut fraction_digits = 0;
+        for magnitude in (*range.start()..0).rev().take(MAX_FRACTION_DIGITS as usize) {
+            fraction = fraction * 10 + value.digit_at(magnitude) as u64;
+            fraction_digits += 1;
+        }
+        while fraction_digits > 0 && fraction % 10 == 0 {
+            fraction /= 10;
+            fraction_digits -= 1;
+        }
+        Some(Self {
+            negative: value.sign() == Sign::Negative && (integer != 0 || fraction != 0),
+            integer,
+            fraction,
+            fraction_digits,
+        })
+    }
+
+    fn to_fixed_decimal(self) -> FixedDecimal {
+        let mut decimal = FixedDecimal::from(
+            self.integer as u128 * 10u128.pow(self.fraction_digits) + self.fraction as u128,
+        );
+        decimal.multiply_pow10(-(self.fraction_digits as i16));
+        if self.negative {
+            decimal.set_sign(Sign::Negative);
+        }
+        decimal
+    }
+
+    fn has_fraction(self) -> bool {
+        self.fraction_digits > 0
+    }
+
+    fn abs(self) -> Self {
+        Self {
+            negative: false,
+            ..self
+        }
+    }
+
+    fn fractional_part(self) -> Self {
+        Self {
+            negative: false,
+            integer: 0,
+            ..self
+        }
+    }
+
+    /// The absolute value rounded half up to an integer.
+    fn rounded(self) -> u64 {
+        if self.has_fraction() && self.fraction / 10u64.pow(self.fraction_digits - 1) >= 5 {
+            self.integer.saturating_add(1)
+        } else {
+            self.integer
+        }
+    }
+
+    /// The fractional part multiplied by `denominator`, rounded half up.
+    fn numerator(self, denominator: u64) -> u64 {
+        let scale = 10u128.pow(self.fraction_digits);
+        let numerator = (self.fraction as u128 * denominator as u128 * 2 + scale) / (2 * scale);
+        u64::try_from(numerator).unwrap_or(u64::MAX)
+    }
+
+    /// The fraction digits from the most significant one.
+    fn fraction_digits(self) -> impl Iterator<Item = u64> {
+        (0..self.fraction_digits)
+            .rev()
+            .map(move |position| self.fraction / 10u64.pow(position) % 10)
+    }
+}
+
+/// A piece of rule text.
+#[derive(Debug, PartialEq, Eq)]
+enum Token<'a> {
+    /// Text that is written as it is.
+    Literal(&'a str),
+    /// A substitution, such as `←←` or `=%spellout-numbering=`.
+    Substitution(Substitution<'a>),
+    /// A plural selection, such as `$(ordinal,one{st}other{th})$`, without the `$(` and `)$`.
+    Plural(&'a str),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Substitution<'a> {
+    kind: SubstitutionKind,
+    /// The text between the tokens, which is empty, a rule set name with its leading `%`, or a
+    /// decimal pattern.
+    descriptor: &'a str,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum SubstitutionKind {
+    /// `←←`: the quotient, the integer part, or the numerator.
+    Less,
+    /// `→→`: the remainder or the fractional part.
+    Greater,
+    /// `→→→`: the remainder formatted with the preceding rule, or the fraction digits without
+    /// spaces.
+    GreaterTriple,
+    /// `==`: the same number.
+    Equal,
+}
+
+/// An iterator over the [`Token`]s of a rule text.
+struct Tokens<'a>(&'a str);
+
+impl<'a> Tokens<'a> {
+    fn substitution_kind(c: char) -> Option<SubstitutionKind> {
+        match c {
+            '←' | '<' => Some(SubstitutionKind::Less),
+            '→' | '>' => Some(SubstitutionKind::Greater),
+            '=' => Some(SubstitutionKind::Equal),
+            _ => None,
+        }
+    }
+
+    fn is_token_start(text: &str) -> bool {
+        text.starts_with("$(")
+            || text
+                .chars()
+                .next()
+                .and_then(Self::substitution_kind)
+                .is_some()
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Token<'a>> {
+        let text = self.0;
+        let first = text.chars().next()?;
+
+        if let Some(body) = text.strip_prefix("$(") {
+            if let Some((body, rest)) = body.split_once(")$") {
+                self.0 = rest;
+                return Some(Token::Plural(body));
+            }
+        } else if let Some(mut kind) = Self::substitution_kind(first) {
+            let after = text.get(first.len_utf8()..).unwrap_or_default();
+            if let Some((descriptor, mut rest)) = after.split_once(first) {
+                if kind == SubstitutionKind::Greater && descriptor.is_empty() {
+                    if let Some(triple) = rest.strip_prefix(first) {
+                        kind = SubstitutionKind::GreaterTriple;
+                        rest = triple;
+                    }
+                }
+                self.0 = rest;
+                return Some(Token::Substitution(Substitution { kind, descriptor }));
+            }
+        }
+
+        // Literal text up to the next token; unterminated tokens are literal as well.
+        let end = text
+            .char_indices()
+            .skip(1)
+            .map(|(index, _)| index)
+            .find(|&index| Self::is_token_start(text.get(index..).unwrap_or_default()))
+            .unwrap_or(text.len());
+        let (literal, rest) = text.split_at(end);
+        self.0 = rest;
+        Some(Token::Literal(literal))
+    }
+}
+
+/// Applies the rules of a rule set.
+struct Executor<'l> {
+    rules: &'l RbnfRulesV1<'l>,
+    /// The rule set that is formatted, which formats the numerators of fraction rule sets.
+    rule_set: RuleSetInfo,
+    symbols: &'l DecimalSymbolsV1<'l>,
+    plurals: &'l dyn PluralSelector,
+}
+
+impl<'l> Executor<'l> {
+    fn rule(&self, index: u16) -> Option<Rule<'l>> {
+        self.rules.rules.get(index as usize).map(Rule::zero_from)
+    }
+
+    fn rule_set(&self, name: &str) -> Option<RuleSetInfo> {
+        self.rules
+            .rule_sets
+            .get_copied(name.trim_start_matches('%'))
+    }
+
+    /// The index of the first normal rule of a rule set.
+    fn first_normal(&self, set: RuleSetInfo) -> u16 {
+        (set.start..set.end)
+            .find(|&index| {
+                self.rule(index)
+                    .map_or(true, |rule| rule.kind == RuleKind::Normal)
+            })
+            .unwrap_or(set.end)
+    }
+
+    fn special_rule(&self, set: RuleSetInfo, kind: RuleKind) -> Option<(u16, Rule<'l>)> {
+        (set.start..self.first_normal(set))
+            .filter_map(|index| self.rule(index).map(|rule| (index, rule)))
+            .find(|(_, rule)| rule.kind == kind)
+    }
+
+    /// The normal rule with the largest base value that is at most `n`, or the one before it if
+    /// its modulus substitution would not apply cleanly, the way ICU does it.
+    fn normal_rule(&self, set: RuleSetInfo, n: u64) -> Option<(u16, Rule<'l>)> {
+        let first = self.first_normal(set);
+        let (mut low, mut high) = (first, set.end);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            if self.rule(middle)?.base_value <= n {
+                low = middle + 1;
+            } else {
+                high = middle;
+            }
+        }
+        if low == first {
+            return None;
+        }
+        let index = low - 1;
+        let rule = self.rule(index)?;
+        let has_modulus = Tokens(&rule.text).any(|token| {
+            matches!(
+                token,
+                Token::Substitution(Substitution {
+                    kind: SubstitutionKind::Greater | SubstitutionKind::GreaterTriple,
+                    ..
+                })
+            )
+        });
+        if has_modulus
+            && rule.divisor != 0
+            && n % rule.divisor == 0
+            && rule.base_value % rule.divisor != 0
+            && index > first
+        {
+            return Some((index - 1, self.rule(index - 1)?));
+        }
+        Some((index, rule))
+    }
+
+    /// The rule of a fraction rule set whose denominator best approximates the fractional part
+    /// of `n`, the way ICU does it.
+    fn fraction_rule(&self, set: RuleSetInfo, n: Number) -> Option<(u16, Rule<'l>)> {
+        let first = self.first_normal(set);
+        let mut lcm = 1u64;
+        for index in first..set.end {
+            let base = self.rule(index)?.base_value.max(1);
+            lcm = match (lcm / gcd(lcm, base)).checked_mul(base) {
+                Some(lcm) => lcm,
+                None => break,
+            };
+        }
+        let numerator = n.numerator(lcm) as u128;
+
+        let mut winner = first;
+        let mut difference = u128::MAX;
+        for index in first..set.end {
+            let base = self.rule(index)?.base_value as u128;
+            let mut candidate = numerator * base % lcm as u128;
+            candidate = candidate.min(lcm as u128 - candidate);
+            if candidate < difference {
+                difference = candidate;
+                winner = index;
+                if difference == 0 {
+                    break;
+                }
+            }
+        }
+
+        let rule = self.rule(winner)?;
+        if winner + 1 < set.end {
+            let next = self.rule(winner + 1)?;
+            if next.base_value == rule.base_value && n.numerator(rule.base_value) != 1 {
+                return Some((winner + 1, next));
+            }
+        }
+        Some((winner, rule))
+    }
+
+    /// Formats `n` with a rule set.
+    fn format<W: Write + ?Sized>(
+        &self,
+        set: RuleSetInfo,
+        mut n: Number,
+        sink: &mut W,
+        depth: u8,
+    ) -> fmt::Result {
+        if depth > MAX_DEPTH {
+            debug_assert!(false, "RBNF rules nest too deeply");
+            return Ok(());
+        }
+
+        if set.fraction {
+            return match self.fraction_rule(set, n) {
+                Some((index, rule)) => self.apply(set, index, rule, n, sink, depth),
+                None => Ok(()),
+            };
+        }
+
+        if n.negative {
+            if let Some((index, rule)) = self.special_rule(set, RuleKind::Negative) {
+                return self.apply(set, index, rule, n, sink, depth);
+            }
+            n = n.abs();
+        }
+
+        if n.has_fraction() {
+            let special = (n.integer == 0)
+                .then(|| self.special_rule(set, RuleKind::ProperFraction))
+                .flatten()
+                .or_else(|| self.special_rule(set, RuleKind::ImproperFraction))
+                .or_else(|| self.special_rule(set, RuleKind::Master));
+            if let Some((index, rule)) = special {
+                return self.apply(set, index, rule, n, sink, depth);
+            }
+            n = Number::from_integer(n.rounded());
+        }
+
+        match self.normal_rule(set, n.integer) {
+            Some((index, rule)) => self.apply(set, index, rule, n, sink, depth),
+            None => Ok(()),
+        }
+    }
+
+    /// Writes the text of a rule of `set` for `n`.
+    fn apply<W: Write + ?Sized>(
+        &self,
+        set: RuleSetInfo,
+        index: u16,
+        rule: Rule<'l>,
+        n: Number,
+        sink: &mut W,
+        depth: u8,
+    ) -> fmt::Result {
+        for token in Tokens(&rule.text) {
+            match token {
+                Token::Literal(text) => sink.write_str(text)?,
+                Token::Plural(body) => {
+                    let operand = match rule.kind {
+                        RuleKind::Normal if set.fraction => n.numerator(rule.base_value),
+                        RuleKind::Normal => n.integer / rule.divisor.max(1),
+                        _ => n.integer,
+                    };
+                    let (ordinal, variants) = match body.split_once(',') {
+                        Some(("ordinal", variants)) => (true, variants),
+                        Some((_, variants)) => (false, variants),
+                        None => (false, body),
+                    };
+                    sink.write_str(plural_variant(
+                        variants,
+                        self.plurals.select(ordinal, operand),
+                    ))?;
+                }
+                Token::Substitution(substitution) => {
+                    self.substitute(set, index, &rule, substitution, n, sink, depth)?
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn substitute<W: Write + ?Sized>(
+        &self,
+        set: RuleSetInfo,
+        index: u16,
+        rule: &Rule<'l>,
+        substitution: Substitution,
+        n: Number,
+        sink: &mut W,
+        depth: u8,
+    ) -> fmt::Result {
+        use SubstitutionKind::*;
+
+        let mut target = set;
+        let value = match (substitution.kind, rule.kind) {
+            (Equal, _) => n,
+            (Less, RuleKind::Normal) if set.fraction => {
+                target = self.rule_set;
+                Number::from_integer(n.numerator(rule.base_value))
+            }
+            (Less, RuleKind::Normal) => Number::from_integer(n.integer / rule.divisor.max(1)),
+            (Less, RuleKind::Negative) => return Ok(()),
+            (Less, _) => Number::from_integer(n.integer),
+            (Greater | GreaterTriple, RuleKind::Negative) => n.abs(),
+            (Greater | GreaterTriple, RuleKind::Normal) if set.fraction => return Ok(()),
+            (Greater, RuleKind::Normal) => Number::from_integer(n.integer % rule.divisor.max(1)),
+            (GreaterTriple, RuleKind::Normal) => {
+                let value = Number::from_integer(n.integer % rule.divisor.max(1));
+                return match index
+                    .checked_sub(1)
+                    .filter(|&i| i >= self.first_normal(set))
+                {
+                    Some(previous) => match self.rule(previous) {
+                        Some(rule) => self.apply(set, previous, rule, value, sink, depth + 1),
+                        None => Ok(()),
+                    },
+                    None => Ok(()),
+                };
+            }
+            (Greater | GreaterTriple, _) => {
+                let fraction = n.fractional_part();
+                let by_digits = substitution.descriptor.is_empty()
+                    || self.rule_set(substitution.descriptor) == Some(set);
+                if by_digits {
+                    for (i, digit) in fraction.fraction_digits().enumerate() {
+                        if i > 0 && substitution.kind == Greater {
+                            sink.write_char(' ')?;
+                        }
+                        self.format(set, Number::from_integer(digit), sink, depth + 1)?;
+                    }
+                    return Ok(());
+                }
+                fraction
+            }
+        };
+
+        let descriptor = substitution.descriptor;
+        if descriptor.is_empty() {
+            self.format(target, value, sink, depth + 1)
+        } else if descriptor.starts_with('%') {
+            match self.rule_set(descriptor) {
+                Some(target) => self.format(target, value, sink, depth + 1),
+                None => Ok(()),
+            }
+        } else {
+            self.format_decimal(descriptor, value, sink)
+        }
+    }
+
+    /// Formats `n` with a decimal pattern such as `#,##0.#`.
+    fn format_decimal<W: Write + ?Sized>(
+        &self,
+        pattern: &str,
+        n: Number,
+        sink: &mut W,
+    ) -> fmt::Result {
+        let (integer, fraction) = pattern.split_once('.').unwrap_or((pattern, ""));
+        let mut decimal = n.to_fixed_decimal();
+        decimal.half_even(-(fraction.len() as i16));
+        decimal.pad_end(-(fraction.chars().filter(|&c| c == '0').count() as i16));
+        let grouping_strategy = if integer.contains(',') {
+            GroupingStrategy::Auto
+        } else {
+            GroupingStrategy::Never
+        };
+        FormattedFixedDecimal {
+            value: &decimal,
+            options: &FixedDecimalFormatterOptions { grouping_strategy },
+            symbols: self.symbols,
+            algorithmic: None,
+        }
+        .write_to(sink)
+    }
+}
+
+/// Selects the text for the plural `keyword` from plural variants such as
+/// `one{st}two{nd}other{th}`, falling back to `other`.
+fn plural_variant<'a>(variants: &'a str, keyword: &str) -> &'a str {
+    let mut other = "";
+    let mut rest = variants;
+    while let Some((variant, after)) = rest.split_once('{') {
+        let (text, after) = after.split_once('}').unwrap_or((after, ""));
+        let variant = variant.trim();
+        if variant == keyword {
+            return text;
+        }
+        if variant == "other" {
+            other = text;
+        }
+        rest = after;
+    }
+    other
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
@@ -0,0 +1,87 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::FixedDecimal;
+use icu_decimal::{DecimalError, FixedDecimalFormatter};
+use icu_locid::{locale, Locale};
+use writeable::assert_writeable_eq;
+
+#[test]
+fn test_algorithmic() {
+    let format = |locale: Locale, value: FixedDecimal| {
+        FixedDecimalFormatter::try_new_with_algorithmic_unstable(
+            &icu_testdata::unstable(),
+            &locale.into(),
+            Default::default(),
+        )
+        .unwrap()
+        .format_to_string(&value)
+    };
+
+    assert_writeable_eq!(format(locale!("en-u-nu-roman"), 0.into()), "N");
+    assert_writeable_eq!(format(locale!("en-u-nu-roman"), 14.into()), "XIV");
+    assert_writeable_eq!(format(locale!("en-u-nu-roman"), 3999.into()), "MMMCMXCIX");
+    assert_writeable_eq!(format(locale!("en-u-nu-romanlow"), 14.into()), "xiv");
+    assert_writeable_eq!(format(locale!("en-u-nu-roman"), (-5).into()), "−V");
+    // Fractions are written with the decimal pattern of the fraction rule, "=#,##0.00=".
+    assert_writeable_eq!(
+        format(
+            locale!("en-u-nu-roman"),
+            FixedDecimal::from(12345).multiplied_pow10(-1)
+        ),
+        "1,234.50"
+    );
+    assert_writeable_eq!(format(locale!("he-u-nu-hebr"), 15.into()), "ט״ו");
+    assert_writeable_eq!(format(locale!("he-u-nu-hebr"), 5783.into()), "ה׳תשפ״ג");
+    assert_writeable_eq!(
+        format(locale!("ja-u-nu-jpan"), 12345.into()),
+        "一万二千三百四十五"
+    );
+    assert_writeable_eq!(format(locale!("hy-u-nu-armn"), 1987.into()), "ՌՋՁԷ");
+    // Numbers that are too large for the rules are written with digits.
+    assert_writeable_eq!(
+        format(
+            locale!("en-u-nu-roman"),
+            FixedDecimal::from(1).multiplied_pow10(20)
+        ),
+        "100,000,000,000,000,000,000"
+    );
+    // Positional numbering systems have no rules.
+    assert_writeable_eq!(format(locale!("th-u-nu-thai"), 1987.into()), "๑,๙๘๗");
+    assert_writeable_eq!(format(locale!("en"), 1987.into()), "1,987");
+}
+
+#[test]
+fn test_algorithmic_needs_constructor() {
+    // The default constructor does not load the rules and formats with digits.
+    let fdf = FixedDecimalFormatter::try_new_unstable(
+        &icu_testdata::unstable(),
+        &locale!("en-u-nu-roman").into(),
+        Default::default(),
+    )
+    .unwrap();
+    assert_writeable_eq!(fdf.format_to_string(&1987.into()), "1,987");
+}
+
+#[test]
+fn test_unsupported_numbering_system() {
+    // The test data has no Chinese rbnf data.
+    for locale in [
+        locale!("zh-u-nu-hanidays"),
+        locale!("zh-u-nu-hans"),
+        locale!("zh-u-nu-hansfin"),
+        locale!("zh-u-nu-hant"),
+        locale!("zh-u-nu-hantfin"),
+    ] {
+        assert_eq!(
+            FixedDecimalFormatter::try_new_with_algorithmic_unstable(
+                &icu_testdata::unstable(),
+                &locale.into(),
+                Default::default(),
+            )
+            .err(),
+            Some(DecimalError::UnsupportedNumberingSystem)
+        );
+    }
+}
@@ -0,0 +1,135 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::FixedDecimal;
+use icu_decimal::options::{GroupingStrategy, Notation, NotationFormatterOptions};
+use icu_decimal::NotationFormatter;
+use icu_locid::{locale, Locale};
+use std::str::FromStr;
+use writeable::assert_writeable_eq;
+
+#[test]
+fn test_notation() {
+    #[derive(Debug)]
+    struct TestCase {
+        locale: Locale,
+        notation: Notation,
+        // Pairs of an input number and its expected formatting
+        expected: &'static [(&'static str, &'static str)],
+    }
+    let cases = [
+        TestCase {
+            locale: locale!("en"),
+            notation: Notation::Percent,
+            expected: &[
+                ("0.45", "45%"),
+                ("-0.45", "-45%"),
+                ("0.005", "0.5%"),
+                ("0.450", "45.0%"),
+                ("12.5", "1,250%"),
+                ("0", "0%"),
+            ],
+        },
+        TestCase {
+            locale: locale!("fr"),
+            notation: Notation::Percent,
+            expected: &[("0.125", "12,5\u{a0}%"), ("-0.125", "-12,5\u{a0}%")],
+        },
+        // The percent sign comes before the number in Turkish
+        TestCase {
+            locale: locale!("tr"),
+            notation: Notation::Percent,
+            expected: &[("0.45", "%45"), ("-0.45", "-%45")],
+        },
+        TestCase {
+            locale: locale!("ar-EG"),
+            notation: Notation::Percent,
+            expected: &[("0.45", "٤٥٪؜")],
+        },
+        TestCase {
+            locale: locale!("ar-EG-u-nu-latn"),
+            notation: Notation::Percent,
+            expected: &[("0.45", "45\u{200e}%\u{200e}")],
+        },
+        TestCase {
+            locale: locale!("en"),
+            notation: Notation::Permille,
+            expected: &[("0.045", "45‰"), ("2.5", "2,500‰"), ("-0.0005", "-0.5‰")],
+        },
+        TestCase {
+            locale: locale!("ru"),
+            notation: Notation::Permille,
+            expected: &[("0.0125", "12,5\u{a0}‰")],
+        },
+        TestCase {
+            locale: locale!("tr"),
+            notation: Notation::Permille,
+            expected: &[("0.045", "‰45")],
+        },
+        TestCase {
+            locale: locale!("en"),
+            notation: Notation::Scientific,
+            expected: &[
+                ("1234", "1.234E3"),
+                ("1000000", "1E6"),
+                ("1000.0", "1.0000E3"),
+                ("7", "7E0"),
+                ("0.00012", "1.2E-4"),
+                ("-56.7", "-5.67E1"),
+                ("-0.5", "-5E-1"),
+            ],
+        },
+        TestCase {
+            locale: locale!("fr"),
+            notation: Notation::Scientific,
+            expected: &[("1234", "1,234E3")],
+        },
+        TestCase {
+            locale: locale!("ar"),
+            notation: Notation::Scientific,
+            expected: &[("1234", "١٫٢٣٤اس٣"), ("0.0015", "١٫٥اس\u{61c}-٣")],
+        },
+        TestCase {
+            locale: locale!("en"),
+            notation: Notation::Engineering,
+            expected: &[
+                ("1234", "1.234E3"),
+                ("12345", "12.345E3"),
+                ("123456", "123.456E3"),
+                ("1234567", "1.234567E6"),
+                ("12", "12E0"),
+                ("0.012", "12E-3"),
+                ("0.0012", "1.2E-3"),
+                ("0.12", "120E-3"),
+                ("-120000", "-120E3"),
+            ],
+        },
+    ];
+    for cas in &cases {
+        let nfmt = NotationFormatter::try_new_unstable(
+            &icu_testdata::unstable(),
+            &(&cas.locale).into(),
+            cas.notation.into(),
+        )
+        .unwrap();
+        for (input, expected) in cas.expected {
+            let dec = FixedDecimal::from_str(input).unwrap();
+            assert_writeable_eq!(nfmt.format(dec), *expected, "{:?}", cas);
+        }
+    }
+}
+
+#[test]
+fn test_grouping_strategy() {
+    let mut options = NotationFormatterOptions::from(Notation::Percent);
+    options.fixed_decimal_formatter_options.grouping_strategy = GroupingStrategy::Never;
+    let nfmt = NotationFormatter::try_new_unstable(
+        &icu_testdata::unstable(),
+        &locale!("en").into(),
+        options,
+    )
+    .unwrap();
+    let dec = FixedDecimal::from_str("12.5").unwrap();
+    assert_writeable_eq!(nfmt.format(dec), "1250%");
+}
@@ -0,0 +1,118 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::FixedDecimal;
+use icu_decimal::options::{FixedDecimalParserOptions, ParseStrictness};
+use icu_decimal::{DecimalError, FixedDecimalFormatter, FixedDecimalParser};
+use icu_locid::{locale, Locale};
+use std::str::FromStr;
+
+fn parser(locale: Locale, strictness: ParseStrictness) -> FixedDecimalParser {
+    let mut options = FixedDecimalParserOptions::default();
+    options.strictness = strictness;
+    FixedDecimalParser::try_new_unstable(&icu_testdata::unstable(), &locale.into(), options)
+        .unwrap()
+}
+
+fn decimal(s: &str) -> Result<FixedDecimal, DecimalError> {
+    Ok(FixedDecimal::from_str(s).unwrap())
+}
+
+#[test]
+fn test_lenient() {
+    let en = parser(locale!("en"), ParseStrictness::Lenient);
+    assert_eq!(en.parse("1,234.5"), decimal("1234.5"));
+    assert_eq!(en.parse(" +12 "), decimal("+12"));
+    assert_eq!(en.parse("− 5"), decimal("-5"));
+    assert_eq!(en.parse("007.50"), decimal("007.50"));
+    assert_eq!(en.parse(".5"), decimal("0.5"));
+    assert_eq!(en.parse("5."), decimal("5"));
+    // Grouping separators are not checked.
+    assert_eq!(en.parse("1,2,3"), decimal("123"));
+    assert_eq!(en.parse("1，234．5"), decimal("1234.5"));
+
+    assert_eq!(en.parse(""), Err(DecimalError::Parse(0)));
+    assert_eq!(en.parse("-"), Err(DecimalError::Parse(1)));
+    assert_eq!(en.parse("abc"), Err(DecimalError::Parse(0)));
+    assert_eq!(en.parse("12a"), Err(DecimalError::Parse(2)));
+    assert_eq!(en.parse("1.2.3"), Err(DecimalError::Parse(3)));
+    assert_eq!(en.parse("1.2,3"), Err(DecimalError::Parse(3)));
+    assert_eq!(en.parse("1,,2"), Err(DecimalError::Parse(2)));
+    assert_eq!(en.parse(",1"), Err(DecimalError::Parse(0)));
+    assert_eq!(en.parse("1,"), Err(DecimalError::Parse(1)));
+    assert_eq!(en.parse("1 000"), Err(DecimalError::Parse(1)));
+
+    let es = parser(locale!("es"), ParseStrictness::Lenient);
+    assert_eq!(es.parse("-1.234,5"), decimal("-1234.5"));
+    assert_eq!(es.parse("1.234，5"), decimal("1234.5"));
+
+    let fr = parser(locale!("fr"), ParseStrictness::Lenient);
+    assert_eq!(fr.parse("1 234,5"), decimal("1234.5"));
+    assert_eq!(fr.parse("1\u{a0}234"), decimal("1234"));
+
+    // The minus sign of "ar" has a bidi mark, and "ar" uses Arabic-Indic digits.
+    let ar = parser(locale!("ar"), ParseStrictness::Lenient);
+    assert_eq!(ar.parse("-١٢٣٫٤"), decimal("-123.4"));
+    assert_eq!(ar.parse("\u{61c}-1٬234"), decimal("-1234"));
+}
+
+#[test]
+fn test_strict() {
+    let en = parser(locale!("en"), ParseStrictness::Strict);
+    assert_eq!(en.parse("1234"), decimal("1234"));
+    assert_eq!(en.parse("1,234,567.5"), decimal("1234567.5"));
+    assert_eq!(en.parse("-1,234"), decimal("-1234"));
+
+    assert_eq!(en.parse(" 1"), Err(DecimalError::Parse(0)));
+    assert_eq!(en.parse(".5"), Err(DecimalError::Parse(0)));
+    assert_eq!(en.parse("5."), Err(DecimalError::Parse(2)));
+    assert_eq!(en.parse("1234,567"), Err(DecimalError::Parse(4)));
+    assert_eq!(en.parse("1,234567"), Err(DecimalError::Parse(8)));
+    assert_eq!(en.parse("1,2345"), Err(DecimalError::Parse(1)));
+    assert_eq!(en.parse("1，234"), Err(DecimalError::Parse(1)));
+
+    // "bn" groups by two after the first group of three.
+    let bn = parser(locale!("bn"), ParseStrictness::Strict);
+    assert_eq!(bn.parse("১০,০০,০০৭"), decimal("1000007"));
+    assert_eq!(bn.parse("১,০০০,০০৭"), Err(DecimalError::Parse(3)));
+    assert_eq!(bn.parse("1000007"), Err(DecimalError::Parse(0)));
+
+    let ar = parser(locale!("ar"), ParseStrictness::Strict);
+    assert_eq!(ar.parse("\u{61c}-١٢٣"), decimal("-123"));
+    assert_eq!(ar.parse("-١٢٣"), Err(DecimalError::Parse(0)));
+}
+
+#[test]
+fn test_round_trip() {
+    let values = ["0", "-5", "1234.5", "-1234567.000", "0.0012", "1000000000"];
+    for locale in [
+        locale!("en"),
+        locale!("ar"),
+        locale!("bn"),
+        locale!("es"),
+        locale!("fr"),
+        locale!("th-u-nu-thai"),
+    ] {
+        let formatter = FixedDecimalFormatter::try_new_unstable(
+            &icu_testdata::unstable(),
+            &locale.clone().into(),
+            Default::default(),
+        )
+        .unwrap();
+        for strictness in [ParseStrictness::Strict, ParseStrictness::Lenient] {
+            let parser = parser(locale.clone(), strictness);
+            for value in values {
+                let value = FixedDecimal::from_str(value).unwrap();
+                let formatted = formatter.format_to_string(&value);
+                assert_eq!(
+                    parser.parse(&formatted),
+                    Ok(value),
+                    "{}: {}",
+                    locale,
+                    formatted
+                );
+            }
+        }
+    }
+}
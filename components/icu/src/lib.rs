@@ -125,6 +125,14 @@ pub use icu_casemapping as casemapping;
 #[doc(inline)]
 pub use icu_collator as collator;
 
+#[cfg(feature = "experimental")]
+#[doc(inline)]
+pub use icu_compactdecimal as compactdecimal;
+
+#[cfg(feature = "experimental")]
+#[doc(inline)]
+pub use icu_currency as currency;
+
 #[doc(inline)]
 pub use icu_datetime as datetime;
 
@@ -162,3 +170,15 @@ pub use icu_timezone as timezone;
 #[cfg(feature = "experimental")]
 #[doc(inline)]
 pub use icu_displaynames as displaynames;
+
+#[cfg(feature = "experimental")]
+#[doc(inline)]
+pub use icu_rbnf as rbnf;
+
+#[cfg(feature = "experimental")]
+#[doc(inline)]
+pub use icu_relativetime as relativetime;
+
+#[cfg(feature = "experimental")]
+#[doc(inline)]
+pub use icu_units as units;
@@ -0,0 +1,245 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Parsing of weighted language lists, such as the value of the HTTP `Accept-Language` header.
+//!
+//! A list consists of comma-separated language ranges, each optionally followed by a quality
+//! value (`q`) between `0` and `1`, as specified in [`RFC 9110`]. The parsed entries are sorted
+//! by descending quality, keeping the original order between entries of the same quality.
+//!
+//! Language ranges are parsed into either [`LanguageIdentifier`]s or [`Locale`]s, or into
+//! [`LanguageRange::Wildcard`] for `*`, which matches any language not otherwise listed.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu::locid::accept_language::{self, LanguageRange};
+//! use icu::locid::{langid, LanguageIdentifier};
+//!
+//! let list = accept_language::parse::<LanguageIdentifier>(
+//!     b"fr-CH, fr;q=0.9, en;q=0.8, *;q=0.5",
+//! )
+//! .expect("Parsing failed.");
+//!
+//! assert_eq!(list.len(), 4);
+//! assert_eq!(list[0].range, LanguageRange::Tag(langid!("fr-CH")));
+//! assert_eq!(list[0].quality, 1000);
+//! assert_eq!(list[2].range, LanguageRange::Tag(langid!("en")));
+//! assert_eq!(list[2].quality, 800);
+//! assert_eq!(list[3].range, LanguageRange::Wildcard);
+//! assert_eq!(list[3].quality, 500);
+//! ```
+//!
+//! [`RFC 9110`]: https://www.rfc-editor.org/rfc/rfc9110#name-accept-language
+//! [`LanguageIdentifier`]: crate::LanguageIdentifier
+//! [`Locale`]: crate::Locale
+
+use crate::ParserError;
+use alloc::vec::Vec;
+use core::str::FromStr;
+
+/// The highest quality value, `q=1`, in thousandths.
+pub const MAX_QUALITY: u16 = 1000;
+
+/// A language range in a weighted list.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+#[allow(clippy::exhaustive_enums)] // this enum is stable
+pub enum LanguageRange<T> {
+    /// A language tag, such as `fr-CH`.
+    Tag(T),
+    /// The wildcard `*`, matching any language.
+    Wildcard,
+}
+
+/// An entry of a weighted list, consisting of a language range and its quality.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+#[non_exhaustive]
+pub struct WeightedRange<T> {
+    /// The language range.
+    pub range: LanguageRange<T>,
+    /// The quality in thousandths, from `0` to [`MAX_QUALITY`]. `q=0.8` is stored as `800`.
+    ///
+    /// A quality of `0` marks the range as not acceptable.
+    pub quality: u16,
+}
+
+/// Parses a weighted language list, such as the value of an `Accept-Language` header.
+///
+/// Empty list elements are ignored. Any malformed entry makes the whole list fail to parse; see
+/// [`parse_lenient`] for a parser that skips such entries instead.
+///
+/// # Examples
+///
+/// ```
+/// use icu::locid::accept_language::{self, LanguageRange};
+/// use icu::locid::{locale, Locale, ParserError};
+///
+/// let list =
+///     accept_language::parse::<Locale>(b"de-u-co-phonebk;q=0.5, ja-JP")
+///         .expect("Parsing failed.");
+///
+/// assert_eq!(list[0].range, LanguageRange::Tag(locale!("ja-JP")));
+/// assert_eq!(list[1].range, LanguageRange::Tag(locale!("de-u-co-phonebk")));
+///
+/// assert_eq!(
+///     accept_language::parse::<Locale>(b"de;q=2"),
+///     Err(ParserError::InvalidWeight)
+/// );
+/// assert_eq!(
+///     accept_language::parse::<Locale>(b"de, x2"),
+///     Err(ParserError::InvalidLanguage)
+/// );
+/// ```
+pub fn parse<T>(input: &[u8]) -> Result<Vec<WeightedRange<T>>, ParserError>
+where
+    T: FromStr<Err = ParserError>,
+{
+    let mut list = Vec::new();
+    for (_, entry) in split_list(input) {
+        if let Some(entry) = parse_entry(entry)? {
+            list.push(entry);
+        }
+    }
+    sort(&mut list);
+    Ok(list)
+}
+
+/// Parses a weighted language list, skipping malformed entries.
+///
+/// Returns the well-formed entries, as well as an error for each skipped entry, together with
+/// the byte offset of the entry in the input.
+///
+/// # Examples
+///
+/// ```
+/// use icu::locid::accept_language::{self, LanguageRange};
+/// use icu::locid::{langid, LanguageIdentifier, ParserError};
+///
+/// let (list, errors) = accept_language::parse_lenient::<LanguageIdentifier>(
+///     b"en-US, x2;q=0.9, fr;q=one, de;q=0.7",
+/// );
+///
+/// assert_eq!(list.len(), 2);
+/// assert_eq!(list[0].range, LanguageRange::Tag(langid!("en-US")));
+/// assert_eq!(list[1].range, LanguageRange::Tag(langid!("de")));
+///
+/// assert_eq!(
+///     errors,
+///     [(7, ParserError::InvalidLanguage), (17, ParserError::InvalidWeight)]
+/// );
+/// ```
+pub fn parse_lenient<T>(input: &[u8]) -> (Vec<WeightedRange<T>>, Vec<(usize, ParserError)>)
+where
+    T: FromStr<Err = ParserError>,
+{
+    let mut list = Vec::new();
+    let mut errors = Vec::new();
+    for (offset, entry) in split_list(input) {
+        match parse_entry(entry) {
+            Ok(Some(entry)) => list.push(entry),
+            Ok(None) => {}
+            Err(e) => errors.push((offset, e)),
+        }
+    }
+    sort(&mut list);
+    (list, errors)
+}
+
+fn sort<T>(list: &mut [WeightedRange<T>]) {
+    // The sort is stable, so ranges with the same quality keep their order.
+    list.sort_by(|a, b| b.quality.cmp(&a.quality));
+}
+
+/// Splits the list at commas, yielding each element with optional whitespace removed,
+/// together with its offset.
+fn split_list(input: &[u8]) -> impl Iterator<Item = (usize, &[u8])> {
+    let mut offset = 0;
+    input.split(|b| *b == b',').map(move |element| {
+        let start = offset + element.len() - trim_start(element).len();
+        offset += element.len() + 1;
+        (start, trim(element))
+    })
+}
+
+fn parse_entry<T>(entry: &[u8]) -> Result<Option<WeightedRange<T>>, ParserError>
+where
+    T: FromStr<Err = ParserError>,
+{
+    let mut parts = entry.split(|b| *b == b';');
+    let range = parts.next().map(trim).unwrap_or_default();
+    if range.is_empty() {
+        return if entry.is_empty() {
+            Ok(None)
+        } else {
+            Err(ParserError::InvalidLanguage)
+        };
+    }
+
+    let range = if range == b"*" {
+        LanguageRange::Wildcard
+    } else {
+        LanguageRange::Tag(
+            core::str::from_utf8(range)
+                .map_err(|_| ParserError::InvalidLanguage)?
+                .parse()?,
+        )
+    };
+
+    let mut quality = None;
+    for parameter in parts {
+        quality = match trim(parameter) {
+            [b'q' | b'Q', b'=', value @ ..] if quality.is_none() => Some(parse_quality(value)?),
+            _ => return Err(ParserError::InvalidWeight),
+        };
+    }
+
+    Ok(Some(WeightedRange {
+        range,
+        quality: quality.unwrap_or(MAX_QUALITY),
+    }))
+}
+
+/// Parses a quality value: `0` or `1`, followed by up to three decimal digits,
+/// which may not exceed `1.000`.
+fn parse_quality(value: &[u8]) -> Result<u16, ParserError> {
+    let (integer, fraction) = match value {
+        [integer] => (integer, &[][..]),
+        [integer, b'.', fraction @ ..] if fraction.len() <= 3 => (integer, fraction),
+        _ => return Err(ParserError::InvalidWeight),
+    };
+    let mut quality = match integer {
+        b'0' => 0,
+        b'1' => MAX_QUALITY,
+        _ => return Err(ParserError::InvalidWeight),
+    };
+    let mut scale = 100;
+    for digit in fraction {
+        if !digit.is_ascii_digit() {
+            return Err(ParserError::InvalidWeight);
+        }
+        quality += u16::from(digit - b'0') * scale;
+        scale /= 10;
+    }
+    if quality > MAX_QUALITY {
+        return Err(ParserError::InvalidWeight);
+    }
+    Ok(quality)
+}
+
+fn trim_start(bytes: &[u8]) -> &[u8] {
+    let start = bytes
+        .iter()
+        .position(|b| *b != b' ' && *b != b'\t')
+        .unwrap_or(bytes.len());
+    bytes.get(start..).unwrap_or_default()
+}
+
+fn trim(bytes: &[u8]) -> &[u8] {
+    let bytes = trim_start(bytes);
+    let end = bytes
+        .iter()
+        .rposition(|b| *b != b' ' && *b != b'\t')
+        .map_or(0, |i| i + 1);
+    bytes.get(..end).unwrap_or_default()
+}
@@ -157,6 +157,26 @@ impl Keywords {
         self.0.get(key)
     }
 
+    /// Returns an iterator over the [`Key`]-[`Value`] pairs, in canonical order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::extensions_unicode_key as key;
+    /// use icu::locid::extensions_unicode_value as value;
+    /// use icu::locid::Locale;
+    ///
+    /// let loc: Locale = "und-u-nu-thai-ca-buddhist".parse().expect("Valid BCP-47");
+    /// let mut keywords = loc.extensions.unicode.keywords.iter();
+    ///
+    /// assert_eq!(keywords.next(), Some((&key!("ca"), &value!("buddhist"))));
+    /// assert_eq!(keywords.next(), Some((&key!("nu"), &value!("thai"))));
+    /// assert_eq!(keywords.next(), None);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&Key, &Value)> {
+        self.0.iter()
+    }
+
     /// Returns a mutable reference to the [`Value`] corresponding to the [`Key`].
     ///
     /// Returns `None` if the key doesn't exist or if the key has no value.
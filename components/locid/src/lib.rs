@@ -19,6 +19,9 @@
 //!
 //! If in doubt, use [`Locale`].
 //!
+//! Weighted lists of language ranges, such as the values of HTTP `Accept-Language` headers,
+//! can be parsed with the [`accept_language`] module.
+//!
 //! # Examples
 //!
 //! ```
@@ -93,6 +96,7 @@ pub use locale::Locale;
 pub use ordering::SubtagOrderingResult;
 pub use parser::errors::ParserError;
 
+pub mod accept_language;
 pub mod extensions;
 pub mod subtags;
 pub mod zerovec;
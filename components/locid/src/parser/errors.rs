@@ -6,7 +6,8 @@ use displaydoc::Display;
 
 /// List of parser errors that can be generated
 /// while parsing [`LanguageIdentifier`](crate::LanguageIdentifier), [`Locale`](crate::Locale),
-/// [`subtags`](crate::subtags) or [`extensions`](crate::extensions).
+/// [`subtags`](crate::subtags), [`extensions`](crate::extensions) or
+/// [weighted language lists](crate::accept_language).
 #[derive(Display, Debug, PartialEq, Copy, Clone)]
 #[non_exhaustive]
 pub enum ParserError {
@@ -48,6 +49,22 @@ pub enum ParserError {
     /// ```
     #[displaydoc("Invalid extension")]
     InvalidExtension,
+
+    /// Invalid weight in a weighted language list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::accept_language;
+    /// use icu::locid::{LanguageIdentifier, ParserError};
+    ///
+    /// assert_eq!(
+    ///     accept_language::parse::<LanguageIdentifier>(b"en;q=1.5"),
+    ///     Err(ParserError::InvalidWeight)
+    /// );
+    /// ```
+    #[displaydoc("Invalid weight")]
+    InvalidWeight,
 }
 
 #[cfg(feature = "std")]
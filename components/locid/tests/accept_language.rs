@@ -0,0 +1,117 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_locid::accept_language::{self, LanguageRange, WeightedRange};
+use icu_locid::{langid, locale, LanguageIdentifier, Locale, ParserError};
+
+fn ranges<T: Clone>(list: &[WeightedRange<T>]) -> Vec<(LanguageRange<T>, u16)> {
+    list.iter().map(|e| (e.range.clone(), e.quality)).collect()
+}
+
+#[test]
+fn test_sorting() {
+    let list = accept_language::parse::<LanguageIdentifier>(
+        b"en;q=0.8, *;q=0.5, fr-CH, fr;q=0.9, de;q=0.8, it;q=0",
+    )
+    .unwrap();
+    assert_eq!(
+        ranges(&list),
+        [
+            (LanguageRange::Tag(langid!("fr-CH")), 1000),
+            (LanguageRange::Tag(langid!("fr")), 900),
+            (LanguageRange::Tag(langid!("en")), 800),
+            (LanguageRange::Tag(langid!("de")), 800),
+            (LanguageRange::Wildcard, 500),
+            (LanguageRange::Tag(langid!("it")), 0),
+        ]
+    );
+}
+
+#[test]
+fn test_whitespace_and_empty_elements() {
+    let list =
+        accept_language::parse::<Locale>(b" ,en_us ; Q=0.5 ,\t,ja-JP-u-ca-japanese,").unwrap();
+    assert_eq!(
+        ranges(&list),
+        [
+            (LanguageRange::Tag(locale!("ja-JP-u-ca-japanese")), 1000),
+            (LanguageRange::Tag(locale!("en-US")), 500),
+        ]
+    );
+
+    assert_eq!(accept_language::parse::<Locale>(b""), Ok(vec![]));
+}
+
+#[test]
+fn test_quality_values() {
+    for (q, expected) in [
+        ("1", Ok(1000)),
+        ("1.", Ok(1000)),
+        ("1.000", Ok(1000)),
+        ("0", Ok(0)),
+        ("0.5", Ok(500)),
+        ("0.05", Ok(50)),
+        ("0.123", Ok(123)),
+        ("1.001", Err(ParserError::InvalidWeight)),
+        ("0.1234", Err(ParserError::InvalidWeight)),
+        ("2", Err(ParserError::InvalidWeight)),
+        (".5", Err(ParserError::InvalidWeight)),
+        ("0.5x", Err(ParserError::InvalidWeight)),
+        ("", Err(ParserError::InvalidWeight)),
+    ] {
+        let input = format!("en;q={}", q);
+        assert_eq!(
+            accept_language::parse::<LanguageIdentifier>(input.as_bytes())
+                .map(|list| list[0].quality),
+            expected,
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_errors() {
+    for (input, expected) in [
+        (&b"en;q=0.5;q=0.4"[..], ParserError::InvalidWeight),
+        (b"en;level=1", ParserError::InvalidWeight),
+        (b"en; q = 0.5", ParserError::InvalidWeight),
+        (b";q=0.5", ParserError::InvalidLanguage),
+        (b"en-*", ParserError::InvalidExtension),
+        (b"en US", ParserError::InvalidLanguage),
+        (b"\xE9n", ParserError::InvalidLanguage),
+    ] {
+        assert_eq!(
+            accept_language::parse::<Locale>(input),
+            Err(expected),
+            "{:?}",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_lenient() {
+    let input = b"fr-CH, fr;q=0.9, en-*;q=0.85,, en;q=0.8, *;q=x, de;q";
+    let (list, errors) = accept_language::parse_lenient::<LanguageIdentifier>(input);
+    assert_eq!(
+        ranges(&list),
+        [
+            (LanguageRange::Tag(langid!("fr-CH")), 1000),
+            (LanguageRange::Tag(langid!("fr")), 900),
+            (LanguageRange::Tag(langid!("en")), 800),
+        ]
+    );
+    assert_eq!(
+        errors,
+        [
+            (17, ParserError::InvalidSubtag),
+            (41, ParserError::InvalidWeight),
+            (48, ParserError::InvalidWeight),
+        ]
+    );
+    for (offset, _) in errors {
+        assert_ne!(input[offset], b' ');
+    }
+}
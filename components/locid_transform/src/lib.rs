@@ -19,6 +19,10 @@
 //! This minimize method returns a new Locale that is the result of running the
 //! 'Remove Likely Subtags' algorithm from [`UTS #35: Unicode LDML 3. Likely Subtags`].
 //!
+//! The [`LocaleMatcher`] negotiates between the locales desired by a user and the locales
+//! supported by an application, based on the algorithm from
+//! [`UTS #35: Unicode LDML 3. Enhanced Language Matching`].
+//!
 //! # Examples
 //!
 //! ```
@@ -67,9 +71,24 @@
 //! assert_eq!(locale.to_string(), "zh");
 //! ```
 //!
+//! ```
+//! use icu::locid::langid;
+//! use icu::locid_transform::LocaleMatcher;
+//!
+//! let lm = LocaleMatcher::try_new_unstable(&icu_testdata::unstable())
+//!     .expect("create failed");
+//!
+//! let supported = [langid!("de"), langid!("en"), langid!("pt-PT")];
+//! let desired = [langid!("pt-BR"), langid!("en-AU")];
+//!
+//! let best = lm.best_match(desired, &supported).expect("no match");
+//! assert_eq!(best.supported, &langid!("pt-PT"));
+//! ```
+//!
 //! [`ICU4X`]: ../icu/index.html
 //! [`CLDR`]: http://cldr.unicode.org/
 //! [`UTS #35: Unicode LDML 3. Likely Subtags`]: https://www.unicode.org/reports/tr35/#Likely_Subtags.
+//! [`UTS #35: Unicode LDML 3. Enhanced Language Matching`]: https://www.unicode.org/reports/tr35/#EnhancedLanguageMatching
 //! [`UTS #35: Unicode LDML 3. LocaleId Canonicalization`]: http://unicode.org/reports/tr35/#LocaleId_Canonicalization,
 
 // https://github.com/unicode-org/icu4x/blob/main/docs/process/boilerplate.md#library-annotations
@@ -93,11 +112,13 @@ extern crate alloc;
 mod canonicalizer;
 mod error;
 mod expander;
+mod matcher;
 pub mod provider;
 
 pub use canonicalizer::LocaleCanonicalizer;
 pub use error::LocaleTransformError;
 pub use expander::LocaleExpander;
+pub use matcher::{LocaleMatch, LocaleMatcher};
 
 /// Used to track the result of a transformation operation that potentially modifies its argument in place.
 #[derive(Debug, PartialEq)]
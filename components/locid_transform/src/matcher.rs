@@ -0,0 +1,396 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::{provider::*, LocaleExpander, LocaleTransformError};
+
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use icu_locid::subtags::{Language, Region, Script};
+use icu_locid::{langid, LanguageIdentifier};
+use icu_provider::prelude::*;
+use icu_provider::zerofrom::ZeroFrom;
+use zerovec::VarZeroVec;
+
+/// The distance used if the data does not contain a catch-all rule for a level.
+const NO_RULE_DISTANCE: u16 = 100;
+
+/// The result of [`LocaleMatcher::best_match`].
+#[derive(Debug, PartialEq)]
+#[non_exhaustive]
+pub struct LocaleMatch<'a, T> {
+    /// The best supported locale.
+    pub supported: &'a T,
+    /// The index of the best supported locale in the supported list.
+    pub supported_index: usize,
+    /// The index of the desired locale that was matched.
+    pub desired_index: usize,
+    /// The distance between the desired and supported locales, including the demotion
+    /// of later desired locales. `0` is a perfect match.
+    pub distance: u16,
+}
+
+// Not derived, as that would require `T: Copy`.
+impl<'a, T> Clone for LocaleMatch<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for LocaleMatch<'a, T> {}
+
+impl<'a, T> LocaleMatch<'a, T> {
+    /// The confidence of this match, between `0.0` (no match) and `1.0` (a perfect match).
+    pub fn confidence(&self) -> f64 {
+        f64::from(NO_RULE_DISTANCE.saturating_sub(self.distance)) / f64::from(NO_RULE_DISTANCE)
+    }
+}
+
+/// LocaleMatcher implements the language matching algorithm as described in
+/// [`UTS #35: Unicode LDML 3. Enhanced Language Matching`].
+///
+/// Given a list of desired locales, such as the user's preferred languages, and the list of
+/// locales supported by an application, it finds the supported locale that best serves the
+/// user. Both lists are compared after adding likely subtags, and the distance between two
+/// locales is the sum of the distances of their language, script, and region subtags as
+/// given by the CLDR `languageMatching` rules:
+///
+/// * Closely related languages match, such as Norwegian Nynorsk and Bokmål.
+/// * Regions are grouped, such that `en-IN` prefers `en-GB` over `en-US`, and `es-MX` prefers
+///   `es-419` over `es-ES`.
+/// * Each desired locale after the first one is demoted by the distance between `en` and
+///   `en-GB`, so that a regional variant of an earlier language wins over a later language.
+/// * Between matches at the same distance, an earlier desired locale wins. Between supported
+///   locales at the same distance, an exact match wins, then a paradigm locale such as `en-GB`
+///   or `es-419`, then the supported locale that comes first.
+///
+/// Locales whose distance is at least the default script distance do not match: a user of
+/// Traditional Chinese is not served Simplified Chinese, nor a user of German French.
+///
+/// # Examples
+///
+/// ```
+/// use icu_locid::langid;
+/// use icu_locid_transform::LocaleMatcher;
+///
+/// let lm = LocaleMatcher::try_new_unstable(&icu_testdata::unstable())
+///     .expect("create failed");
+///
+/// let supported = [langid!("en"), langid!("en-GB"), langid!("es"), langid!("es-419")];
+///
+/// let best = lm
+///     .best_match([langid!("en-IN")], &supported)
+///     .expect("no match");
+/// assert_eq!(best.supported, &langid!("en-GB"));
+///
+/// let best = lm
+///     .best_match([langid!("es-MX"), langid!("en")], &supported)
+///     .expect("no match");
+/// assert_eq!(best.supported, &langid!("es-419"));
+/// assert_eq!(best.desired_index, 0);
+///
+/// assert!(lm.best_match([langid!("zh-TW")], &supported).is_none());
+/// ```
+///
+/// [`UTS #35: Unicode LDML 3. Enhanced Language Matching`]: https://www.unicode.org/reports/tr35/#EnhancedLanguageMatching
+pub struct LocaleMatcher {
+    expander: LocaleExpander,
+    language_matching: DataPayload<LanguageMatchingV1Marker>,
+    /// The maximized paradigm locales.
+    paradigm_locales: Vec<LanguageIdentifier>,
+    /// Distances at or above this do not match.
+    threshold: u16,
+    /// The demotion per desired locale.
+    demotion: u16,
+}
+
+impl LocaleMatcher {
+    /// A constructor which takes a [`DataProvider`] and creates a [`LocaleMatcher`].
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_unstable<P>(provider: &P) -> Result<LocaleMatcher, LocaleTransformError>
+    where
+        P: DataProvider<LanguageMatchingV1Marker> + DataProvider<LikelySubtagsV1Marker> + ?Sized,
+    {
+        let expander = LocaleExpander::try_new_unstable(provider)?;
+        let language_matching: DataPayload<LanguageMatchingV1Marker> =
+            provider.load(Default::default())?.take_payload()?;
+
+        let mut matcher = LocaleMatcher {
+            expander,
+            language_matching,
+            paradigm_locales: Vec::new(),
+            threshold: NO_RULE_DISTANCE,
+            demotion: 0,
+        };
+
+        let data = matcher.language_matching.get();
+        let paradigm_locales = data
+            .paradigm_locales
+            .iter()
+            .filter_map(|s| s.parse().ok())
+            .map(|langid| matcher.maximized(&langid))
+            .collect();
+        let threshold = data
+            .script
+            .iter()
+            .map(LanguageMatchRule::zero_from)
+            .find(|rule| rule.desired == "*_*" && rule.supported == "*_*")
+            .map_or(NO_RULE_DISTANCE, |rule| rule.distance.into());
+        let demotion = matcher.distance(&langid!("en"), &langid!("en-GB"));
+
+        matcher.paradigm_locales = paradigm_locales;
+        matcher.threshold = threshold;
+        matcher.demotion = demotion;
+        Ok(matcher)
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: skip,
+        options: skip,
+        error: LocaleTransformError
+    );
+
+    /// Returns the distance between a desired and a supported locale, after adding likely
+    /// subtags to both. `0` means that they are equivalent; distances at or above the default
+    /// script distance of `50` mean that the supported locale is not a useful fallback.
+    ///
+    /// Note that the distance is not symmetric: a Swiss German speaker is likely to understand
+    /// German, but not the other way around.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_locid::langid;
+    /// use icu_locid_transform::LocaleMatcher;
+    ///
+    /// let lm = LocaleMatcher::try_new_unstable(&icu_testdata::unstable())
+    ///     .expect("create failed");
+    ///
+    /// assert_eq!(lm.distance(&langid!("en"), &langid!("en-Latn-US")), 0);
+    /// assert_eq!(lm.distance(&langid!("en-IN"), &langid!("en-GB")), 3);
+    /// assert_eq!(lm.distance(&langid!("en-IN"), &langid!("en-US")), 5);
+    /// assert_eq!(lm.distance(&langid!("nb"), &langid!("no")), 1);
+    /// assert_eq!(lm.distance(&langid!("gsw"), &langid!("de")), 8);
+    /// assert_eq!(lm.distance(&langid!("de"), &langid!("gsw")), 84);
+    /// ```
+    pub fn distance<D, S>(&self, desired: &D, supported: &S) -> u16
+    where
+        D: AsRef<LanguageIdentifier>,
+        S: AsRef<LanguageIdentifier>,
+    {
+        self.maximized_distance(
+            &self.maximized(desired.as_ref()),
+            &self.maximized(supported.as_ref()),
+        )
+    }
+
+    /// Finds the supported locale that best matches the desired locales, which are given in
+    /// order of preference. Returns `None` if no supported locale is close enough to any of
+    /// the desired locales.
+    ///
+    /// Only the language identifiers are matched, so extensions of [`Locale`]s are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_locid::{langid, locale};
+    /// use icu_locid_transform::LocaleMatcher;
+    ///
+    /// let lm = LocaleMatcher::try_new_unstable(&icu_testdata::unstable())
+    ///     .expect("create failed");
+    ///
+    /// let supported = [locale!("de"), locale!("fr"), locale!("it")];
+    ///
+    /// // Swiss German speakers can be served German.
+    /// let best = lm
+    ///     .best_match([langid!("gsw"), langid!("en")], &supported)
+    ///     .expect("no match");
+    /// assert_eq!(best.supported, &locale!("de"));
+    /// assert_eq!(best.supported_index, 0);
+    /// assert_eq!(best.desired_index, 0);
+    /// assert_eq!(best.distance, 8);
+    /// assert_eq!(best.confidence(), 0.92);
+    ///
+    /// // An exact match for the second desired locale is demoted, but still preferred.
+    /// let best = lm
+    ///     .best_match([langid!("gsw"), langid!("fr")], &supported)
+    ///     .expect("no match");
+    /// assert_eq!(best.supported, &locale!("fr"));
+    /// assert_eq!(best.desired_index, 1);
+    /// assert_eq!(best.distance, 5);
+    /// ```
+    ///
+    /// [`Locale`]: icu_locid::Locale
+    pub fn best_match<'s, I, S>(&self, desired: I, supported: &'s [S]) -> Option<LocaleMatch<'s, S>>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<LanguageIdentifier>,
+        S: AsRef<LanguageIdentifier>,
+    {
+        let supported_maximized = supported
+            .iter()
+            .map(|s| {
+                let maximized = self.maximized(s.as_ref());
+                let is_paradigm = self.paradigm_locales.contains(&maximized);
+                (maximized, is_paradigm)
+            })
+            .collect::<Vec<_>>();
+
+        // Matches are ordered by distance, then by the order of the desired locales, then
+        // exact matches and paradigm locales first.
+        let mut best = None;
+        let mut best_key = (self.threshold, 0, false, false);
+        for (desired_index, desired) in desired.into_iter().enumerate() {
+            let demotion = self
+                .demotion
+                .saturating_mul(u16::try_from(desired_index).unwrap_or(u16::MAX));
+            if demotion >= best_key.0 {
+                break;
+            }
+            let desired = desired.as_ref();
+            let desired_maximized = self.maximized(desired);
+            for (supported_index, (s, (supported_maximized, is_paradigm))) in
+                supported.iter().zip(supported_maximized.iter()).enumerate()
+            {
+                let is_exact = s.as_ref() == desired;
+                let distance = if is_exact {
+                    0
+                } else {
+                    self.maximized_distance(&desired_maximized, supported_maximized)
+                }
+                .saturating_add(demotion);
+                let key = (distance, desired_index, !is_exact, !is_paradigm);
+                if key < best_key {
+                    best_key = key;
+                    best = Some(LocaleMatch {
+                        supported: s,
+                        supported_index,
+                        desired_index,
+                        distance,
+                    });
+                }
+            }
+        }
+        best
+    }
+
+    fn maximized(&self, langid: &LanguageIdentifier) -> LanguageIdentifier {
+        let mut maximized = langid.clone();
+        self.expander.maximize(&mut maximized);
+        maximized
+    }
+
+    fn maximized_distance(
+        &self,
+        desired: &LanguageIdentifier,
+        supported: &LanguageIdentifier,
+    ) -> u16 {
+        let data = self.language_matching.get();
+        let mut distance = 0;
+
+        if desired.language != supported.language {
+            // Rules that name two different languages with their scripts, such as
+            // `am_Ethi` and `en_Latn`, replace the language and script distances.
+            if let Some(d) = self.lookup(&data.script, desired, supported, true) {
+                distance = d;
+            } else {
+                distance = self
+                    .lookup(&data.language, desired, supported, false)
+                    .unwrap_or(NO_RULE_DISTANCE);
+                if desired.script != supported.script {
+                    distance += self
+                        .lookup(&data.script, desired, supported, false)
+                        .unwrap_or(NO_RULE_DISTANCE);
+                }
+            }
+        } else if desired.script != supported.script {
+            distance = self
+                .lookup(&data.script, desired, supported, false)
+                .unwrap_or(NO_RULE_DISTANCE);
+        }
+
+        if desired.region != supported.region {
+            distance += self
+                .lookup(&data.region, desired, supported, false)
+                .unwrap_or(NO_RULE_DISTANCE);
+        }
+
+        distance
+    }
+
+    /// Returns the distance of the first rule that matches the two locales in either direction,
+    /// or only from `desired` to `supported` for one-way rules.
+    fn lookup(
+        &self,
+        rules: &VarZeroVec<LanguageMatchRuleULE>,
+        desired: &LanguageIdentifier,
+        supported: &LanguageIdentifier,
+        explicit_languages: bool,
+    ) -> Option<u16> {
+        rules
+            .iter()
+            .map(LanguageMatchRule::zero_from)
+            .filter(|rule| {
+                !explicit_languages
+                    || !(rule.desired.starts_with('*') || rule.supported.starts_with('*'))
+            })
+            .find(|rule| {
+                (self.matches(&rule.desired, desired) && self.matches(&rule.supported, supported))
+                    || (!rule.oneway
+                        && self.matches(&rule.desired, supported)
+                        && self.matches(&rule.supported, desired))
+            })
+            .map(|rule| rule.distance.into())
+    }
+
+    fn matches(&self, pattern: &str, langid: &LanguageIdentifier) -> bool {
+        let mut fields = pattern.split('_');
+        fields
+            .next()
+            .map_or(true, |l| matches_language(l, langid.language))
+            && fields
+                .next()
+                .map_or(true, |s| matches_script(s, langid.script))
+            && fields
+                .next()
+                .map_or(true, |r| self.matches_region(r, langid.region))
+    }
+
+    fn matches_region(&self, pattern: &str, region: Option<Region>) -> bool {
+        if pattern == "*" {
+            return true;
+        }
+        let region = match region {
+            Some(region) => region,
+            None => return false,
+        };
+        match pattern.strip_prefix('$') {
+            Some(variable) => {
+                let (negated, variable) = match variable.strip_prefix('!') {
+                    Some(variable) => (true, variable),
+                    None => (false, variable),
+                };
+                let contained = self
+                    .language_matching
+                    .get()
+                    .match_variables
+                    .get(variable)
+                    .map_or(false, |regions| regions.binary_search(&region).is_ok());
+                contained != negated
+            }
+            None => pattern == region.as_str(),
+        }
+    }
+}
+
+fn matches_language(pattern: &str, language: Language) -> bool {
+    pattern == "*" || pattern == language.as_str()
+}
+
+fn matches_script(pattern: &str, script: Option<Script>) -> bool {
+    pattern == "*" || script.map_or(false, |s| pattern == s.as_str())
+}
@@ -157,3 +157,70 @@ pub struct LikelySubtagsV1<'data> {
     /// Undefined.
     pub und: (Language, Script, Region),
 }
+
+#[zerovec::make_varule(LanguageMatchRuleULE)]
+#[zerovec::derive(Debug)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize),
+    zerovec::derive(Deserialize)
+)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    zerovec::derive(Serialize),
+    databake(path = icu_locid_transform::provider),
+)]
+/// A single CLDR language matching rule.
+///
+/// The `desired` and `supported` patterns use the CLDR syntax, consisting of one to three
+/// `_`-separated fields for language, script, and region. Each field is either a subtag, the
+/// wildcard `*`, or (for regions) a match variable reference such as `$enUS` or `$!enUS`.
+pub struct LanguageMatchRule<'data> {
+    /// The distance between locales matching this rule.
+    pub distance: u8,
+    /// Whether this rule only applies from `desired` to `supported`, and not the other way around.
+    pub oneway: bool,
+    /// The pattern for the desired locale, such as `en_*_$!enUS`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub desired: Cow<'data, str>,
+    /// The pattern for the supported locale, such as `en_*_GB`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub supported: Cow<'data, str>,
+}
+
+#[icu_provider::data_struct(LanguageMatchingV1Marker = "locid_transform/languagematching@1")]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_locid_transform::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+/// This language matching data is used by the [`LocaleMatcher`](crate::LocaleMatcher) to
+/// compute distances between locales, based upon the rules in
+/// <https://www.unicode.org/reports/tr35/#EnhancedLanguageMatching>.
+///
+/// The rules are broken down by the number of fields in their patterns. Within each level,
+/// the first matching rule determines the distance.
+#[yoke(prove_covariance_manually)]
+pub struct LanguageMatchingV1<'data> {
+    /// Locales that are preferred over other supported locales with the same distance,
+    /// such as `en-GB` or `es-419`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub paradigm_locales: VarZeroVec<'data, UnvalidatedLanguageIdentifier>,
+    /// `[variable] -> [region]+`, with the contained regions expanded.
+    /// The names do not include the leading `$`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub match_variables: ZeroMap<'data, str, ZeroSlice<Region>>,
+    /// Rules that apply to languages, such as `nb` and `no`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub language: VarZeroVec<'data, LanguageMatchRuleULE>,
+    /// Rules that apply to languages and scripts, such as `sr_Latn` and `sr_Cyrl`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub script: VarZeroVec<'data, LanguageMatchRuleULE>,
+    /// Rules that apply to languages, scripts, and regions, such as `en_*_$!enUS` and `en_*_GB`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub region: VarZeroVec<'data, LanguageMatchRuleULE>,
+}
@@ -10,3 +10,10 @@ pub struct CanonicalizationTest {
     pub output: String,
     pub disabled: Option<bool>,
 }
+
+#[derive(Deserialize)]
+pub struct MatcherTest {
+    pub desired: Vec<String>,
+    pub supported: Vec<String>,
+    pub output: Option<String>,
+}
@@ -6,7 +6,7 @@ mod fixtures;
 mod helpers;
 
 use icu_locid::Locale;
-use icu_locid_transform::{LocaleCanonicalizer, LocaleExpander, TransformResult};
+use icu_locid_transform::{LocaleCanonicalizer, LocaleExpander, LocaleMatcher, TransformResult};
 
 #[test]
 fn test_maximize() {
@@ -79,3 +79,29 @@ fn test_canonicalize() {
         }
     }
 }
+
+#[test]
+fn test_best_match() {
+    let lm = LocaleMatcher::try_new_unstable(&icu_testdata::unstable()).unwrap();
+
+    let path = "./tests/fixtures/matcher.json";
+    let testcases: Vec<fixtures::MatcherTest> =
+        helpers::read_fixture(path).expect("Failed to read a fixture");
+
+    for case in testcases {
+        let desired: Vec<Locale> = case.desired.iter().map(|l| l.parse().unwrap()).collect();
+        let supported: Vec<Locale> = case.supported.iter().map(|l| l.parse().unwrap()).collect();
+        let result = lm.best_match(&desired, &supported);
+        assert_eq!(
+            result.map(|m| m.supported.to_string()),
+            case.output,
+            "{:?} in {:?}",
+            case.desired,
+            case.supported
+        );
+        if let Some(m) = result {
+            assert_eq!(m.supported, &supported[m.supported_index]);
+            assert!(m.confidence() > 0.5);
+        }
+    }
+}
@@ -77,6 +77,12 @@ use crate::provider::CanonicalDecompositionDataV1Marker;
 use crate::provider::CompatibilityDecompositionSupplementV1Marker;
 use crate::provider::DecompositionDataV1;
 #[cfg(feature = "experimental")]
+use crate::provider::NfkcCasefoldDecompositionSupplementV1Marker;
+#[cfg(feature = "experimental")]
+use crate::provider::NfkcCasefoldDecompositionTablesV1Marker;
+#[cfg(feature = "experimental")]
+use crate::provider::NfkcSimpleCasefoldDecompositionSupplementV1Marker;
+#[cfg(feature = "experimental")]
 use crate::provider::Uts46DecompositionSupplementV1Marker;
 use alloc::string::String;
 use alloc::vec::Vec;
@@ -105,6 +111,10 @@ enum SupplementPayloadHolder {
     Compatibility(DataPayload<CompatibilityDecompositionSupplementV1Marker>),
     #[cfg(feature = "experimental")]
     Uts46(DataPayload<Uts46DecompositionSupplementV1Marker>),
+    #[cfg(feature = "experimental")]
+    NfkcCasefold(DataPayload<NfkcCasefoldDecompositionSupplementV1Marker>),
+    #[cfg(feature = "experimental")]
+    NfkcSimpleCasefold(DataPayload<NfkcSimpleCasefoldDecompositionSupplementV1Marker>),
 }
 
 impl SupplementPayloadHolder {
@@ -113,6 +123,26 @@ impl SupplementPayloadHolder {
             SupplementPayloadHolder::Compatibility(d) => d.get(),
             #[cfg(feature = "experimental")]
             SupplementPayloadHolder::Uts46(d) => d.get(),
+            #[cfg(feature = "experimental")]
+            SupplementPayloadHolder::NfkcCasefold(d) => d.get(),
+            #[cfg(feature = "experimental")]
+            SupplementPayloadHolder::NfkcSimpleCasefold(d) => d.get(),
+        }
+    }
+}
+
+enum SupplementTablesPayloadHolder {
+    Compatibility(DataPayload<CompatibilityDecompositionTablesV1Marker>),
+    #[cfg(feature = "experimental")]
+    NfkcCasefold(DataPayload<NfkcCasefoldDecompositionTablesV1Marker>),
+}
+
+impl SupplementTablesPayloadHolder {
+    fn get(&self) -> &DecompositionTablesV1 {
+        match self {
+            SupplementTablesPayloadHolder::Compatibility(d) => d.get(),
+            #[cfg(feature = "experimental")]
+            SupplementTablesPayloadHolder::NfkcCasefold(d) => d.get(),
         }
     }
 }
@@ -189,6 +219,10 @@ static FDFA_NFKD: [u16; 17] = [
 /// Marker value for U+FDFA in NFKD
 const FDFA_MARKER: u16 = 3;
 
+/// Marker value in the supplementary trie for characters that
+/// normalize to the empty string. (NFKC_Casefold flavors only.)
+const IGNORABLE_MARKER: u32 = 0xFFFFFFFF;
+
 // These constants originate from page 143 of Unicode 14.0
 /// Syllable base
 const HANGUL_S_BASE: u32 = 0xAC00;
@@ -634,7 +668,9 @@ where
                 i += 1;
                 // Half-width kana and iota subscript don't occur in the tails
                 // of these multicharacter decompositions.
-                if decomposition_starts_with_non_starter(trie_value) {
+                // Non-starters after the last starter in the tail need to be
+                // sorted together with the ones that follow in the input.
+                if !decomposition_starts_with_non_starter(trie_value) {
                     combining_start = i;
                 }
             }
@@ -673,7 +709,9 @@ where
                 i += 1;
                 // Half-width kana and iota subscript don't occur in the tails
                 // of these multicharacter decompositions.
-                if decomposition_starts_with_non_starter(trie_value) {
+                // Non-starters after the last starter in the tail need to be
+                // sorted together with the ones that follow in the input.
+                if !decomposition_starts_with_non_starter(trie_value) {
                     combining_start = i;
                 }
             }
@@ -716,18 +754,91 @@ where
         None
     }
 
+    /// Returns the canonical combining classes of the first and the last
+    /// character of the canonical decomposition of `c` (i.e. its lccc and
+    /// tccc in ICU4C terms). Supplementary data is not consulted.
+    fn fcd_classes(&self, c: char) -> (CanonicalCombiningClass, CanonicalCombiningClass) {
+        let decomposition = self.trie.get(c);
+        if trie_value_has_ccc(decomposition) {
+            let ccc = ccc_from_trie_value(decomposition);
+            return (ccc, ccc);
+        }
+        if decomposition <= BACKWARD_COMBINING_STARTER_MARKER {
+            // Decomposes to self or is a Hangul syllable, whose
+            // decomposition consists of starters.
+            return (
+                CanonicalCombiningClass::NotReordered,
+                CanonicalCombiningClass::NotReordered,
+            );
+        }
+        if trie_value_indicates_special_non_starter_decomposition(decomposition) {
+            // See `gather_and_sort_combining` for the decompositions.
+            return match c {
+                '\u{0F73}' | '\u{0F81}' => (
+                    CanonicalCombiningClass::CCC129,
+                    CanonicalCombiningClass::CCC130,
+                ),
+                '\u{0F75}' => (
+                    CanonicalCombiningClass::CCC129,
+                    CanonicalCombiningClass::CCC132,
+                ),
+                _ => (
+                    CanonicalCombiningClass::Above,
+                    CanonicalCombiningClass::Above,
+                ),
+            };
+        }
+        let trail_or_complex = (decomposition >> 16) as u16;
+        let lead = decomposition as u16;
+        let last = if lead > NON_ROUND_TRIP_MARKER {
+            if trail_or_complex == 0 {
+                // Decomposition into one BMP starter
+                return (
+                    CanonicalCombiningClass::NotReordered,
+                    CanonicalCombiningClass::NotReordered,
+                );
+            }
+            // Decomposition into a BMP starter and a BMP non-starter
+            char_from_u16(trail_or_complex)
+        } else {
+            // Complex decomposition; see `decomposing_next` for the format.
+            // The first character is always a starter.
+            let offset = usize::from(trail_or_complex & 0xFFF);
+            if offset < self.scalars16.len() {
+                let len = usize::from(trail_or_complex >> 13) + 2;
+                char_from_u16(unwrap_or_gigo(self.scalars16.get(offset + len - 1), 0xFFFD))
+            } else {
+                let len = usize::from(trail_or_complex >> 13) + 1;
+                unwrap_or_gigo(
+                    self.scalars24.get(offset - self.scalars16.len() + len - 1),
+                    REPLACEMENT_CHARACTER,
+                )
+            }
+        };
+        (
+            CanonicalCombiningClass::NotReordered,
+            ccc_from_trie_value(self.trie.get(last)),
+        )
+    }
+
     fn delegate_next_no_pending(&mut self) -> Option<CharacterAndTrieValue> {
         debug_assert!(self.pending.is_none());
-        let c = self.delegate.next()?;
+        loop {
+            let c = self.delegate.next()?;
 
-        // TODO(#2384): Measure if this check is actually an optimization even in the
-        // non-supplementary case of if this should go inside the supplementary
-        // `if` below.
-        if u32::from(c) < self.decomposition_passthrough_bound {
-            return Some(CharacterAndTrieValue::new(c, 0));
-        }
+            // TODO(#2384): Measure if this check is actually an optimization even in the
+            // non-supplementary case of if this should go inside the supplementary
+            // `if` below.
+            if u32::from(c) < self.decomposition_passthrough_bound {
+                return Some(CharacterAndTrieValue::new(c, 0));
+            }
 
-        Some(self.attach_trie_value(c))
+            let c_and_trie_val = self.attach_trie_value(c);
+            if c_and_trie_val.trie_val != IGNORABLE_MARKER {
+                return Some(c_and_trie_val);
+            }
+            // The character normalizes to nothing, so skip it.
+        }
     }
 
     fn delegate_next(&mut self) -> Option<CharacterAndTrieValue> {
@@ -736,10 +847,13 @@ where
             // the contiguous-buffer methods of `DecomposingNormalizer`.
             // I.e. does not happen as part of standalone iterator
             // usage of `Decomposition`.
-            Some(pending)
-        } else {
-            self.delegate_next_no_pending()
+            // The fast path of `ComposingNormalizer` may leave a character
+            // that normalizes to nothing here.
+            if pending.trie_val != IGNORABLE_MARKER {
+                return Some(pending);
+            }
         }
+        self.delegate_next_no_pending()
     }
 
     fn decomposing_next(&mut self, c_and_trie_val: CharacterAndTrieValue) -> char {
@@ -977,6 +1091,10 @@ where
     /// 2. Decomposition starts with a non-starter
     /// 3. Is not a backward-combining starter
     composition_passthrough_bound: u32,
+    /// If `true`, a non-starter can only compose with the starter
+    /// when nothing remains between them (FCC). If `false`,
+    /// discontiguous composition is performed as in NFC.
+    only_contiguous: bool,
 }
 
 impl<'data, I> Composition<'data, I>
@@ -987,12 +1105,14 @@ where
         decomposition: Decomposition<'data, I>,
         canonical_compositions: Char16Trie<'data>,
         composition_passthrough_bound: u16,
+        only_contiguous: bool,
     ) -> Self {
         Self {
             decomposition,
             canonical_compositions,
             unprocessed_starter: None,
             composition_passthrough_bound: u32::from(composition_passthrough_bound),
+            only_contiguous,
         }
     }
 
@@ -1146,7 +1266,7 @@ where
                         return Some(starter);
                     }
                     debug_assert!(ccc >= most_recent_skipped_ccc);
-                    if ccc != most_recent_skipped_ccc {
+                    if ccc != most_recent_skipped_ccc && !self.only_contiguous {
                         // Using the non-Hangul version as a micro-optimization, since
                         // we already rejected the case where `second` is a starter
                         // above, and conjoining jamo are starters.
@@ -1324,7 +1444,7 @@ macro_rules! composing_normalize_to {
                                 continue 'bufferloop;
                             }
                             debug_assert!(ccc >= most_recent_skipped_ccc);
-                            if ccc != most_recent_skipped_ccc {
+                            if ccc != most_recent_skipped_ccc && !$composition.only_contiguous {
                                 // Using the non-Hangul version as a micro-optimization, since
                                 // we already rejected the case where `second` is a starter
                                 // above, and conjoining jamo are starters.
@@ -1505,15 +1625,81 @@ macro_rules! normalizer_methods {
             }
             sink.finished()
         }
+
+        /// Check whether a string slice is normalized by looking at each
+        /// character and its neighbors without normalizing the text.
+        ///
+        /// Returns the result of the check together with the length in bytes
+        /// of the prefix of `text` that is known to be normalized. The rest of
+        /// the text can be normalized separately and appended to that prefix.
+        pub fn quick_check(&self, text: &str) -> (QuickCheckResult, usize) {
+            self.quick_check_impl(
+                text.char_indices().map(|(offset, c)| (offset, Some(c))),
+                text.len(),
+            )
+        }
+
+        /// Check whether a slice of potentially-invalid UTF-16 is normalized
+        /// by looking at each character and its neighbors without normalizing
+        /// the text.
+        ///
+        /// Returns the result of the check together with the length in code
+        /// units of the prefix of `text` that is known to be normalized. The
+        /// rest of the text can be normalized separately and appended to that
+        /// prefix.
+        ///
+        /// Unpaired surrogates are not normalized, since normalizing maps
+        /// them to the REPLACEMENT CHARACTER.
+        pub fn quick_check_utf16(&self, text: &[u16]) -> (QuickCheckResult, usize) {
+            let mut iter = text.chars();
+            self.quick_check_impl(
+                core::iter::from_fn(move || {
+                    let offset = text.len() - iter.as_slice().len();
+                    let c = iter.next()?;
+                    if c == REPLACEMENT_CHARACTER && text.get(offset) != Some(&0xFFFD) {
+                        return Some((offset, None));
+                    }
+                    Some((offset, Some(c)))
+                }),
+                text.len(),
+            )
+        }
     };
 }
 
+/// The result of a normalization quick check.
+///
+/// See [`DecomposingNormalizer::quick_check()`] and [`ComposingNormalizer::quick_check()`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[non_exhaustive]
+pub enum QuickCheckResult {
+    /// The text is normalized.
+    Yes,
+    /// The text is not normalized.
+    No,
+    /// The text contains characters that may or may not be normalized
+    /// depending on the characters around them. A full check with
+    /// `is_normalized()` is needed to tell.
+    Maybe,
+}
+
+/// The flavor of the quick check to perform.
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum QuickCheckMode {
+    /// NFD, NFKD, and the other decomposed forms
+    Decomposed,
+    /// NFC, NFKC, and the other composed forms
+    Composed,
+    /// FCC
+    ComposedContiguous,
+}
+
 /// A normalizer for performing decomposing normalization.
 pub struct DecomposingNormalizer {
     decompositions: DataPayload<CanonicalDecompositionDataV1Marker>,
     supplementary_decompositions: Option<SupplementPayloadHolder>,
     tables: DataPayload<CanonicalDecompositionTablesV1Marker>,
-    supplementary_tables: Option<DataPayload<CompatibilityDecompositionTablesV1Marker>>,
+    supplementary_tables: Option<SupplementTablesPayloadHolder>,
     decomposition_passthrough_bound: u8, // never above 0xC0
     composition_passthrough_bound: u16,  // never above 0x0300
 }
@@ -1619,7 +1805,9 @@ impl DecomposingNormalizer {
                 supplementary_decompositions,
             )),
             tables,
-            supplementary_tables: Some(supplementary_tables),
+            supplementary_tables: Some(SupplementTablesPayloadHolder::Compatibility(
+                supplementary_tables,
+            )),
             decomposition_passthrough_bound: decomposition_capped as u8,
             composition_passthrough_bound: composition_capped as u16,
         })
@@ -1705,7 +1893,61 @@ impl DecomposingNormalizer {
                 supplementary_decompositions,
             )),
             tables,
-            supplementary_tables: Some(supplementary_tables),
+            supplementary_tables: Some(SupplementTablesPayloadHolder::Compatibility(
+                supplementary_tables,
+            )),
+            decomposition_passthrough_bound: decomposition_capped as u8,
+            composition_passthrough_bound: composition_capped as u16,
+        })
+    }
+
+    /// Decomposed counterpart of the NFKC_Casefold flavors given the supplement for
+    /// one of them.
+    ///
+    /// Not public, because characters that normalize to the empty string are only
+    /// supported by the composing normalizer.
+    #[cfg(feature = "experimental")]
+    fn try_new_nfkc_casefold_decomposed<D>(
+        data_provider: &D,
+        supplementary_decompositions: SupplementPayloadHolder,
+    ) -> Result<Self, NormalizerError>
+    where
+        D: DataProvider<CanonicalDecompositionDataV1Marker>
+            + DataProvider<CanonicalDecompositionTablesV1Marker>
+            + DataProvider<NfkcCasefoldDecompositionTablesV1Marker>
+            + ?Sized,
+    {
+        let decompositions: DataPayload<CanonicalDecompositionDataV1Marker> =
+            data_provider.load(Default::default())?.take_payload()?;
+        let tables: DataPayload<CanonicalDecompositionTablesV1Marker> =
+            data_provider.load(Default::default())?.take_payload()?;
+        let supplementary_tables: DataPayload<NfkcCasefoldDecompositionTablesV1Marker> =
+            data_provider.load(Default::default())?.take_payload()?;
+
+        if tables.get().scalars16.len()
+            + tables.get().scalars24.len()
+            + supplementary_tables.get().scalars16.len()
+            + supplementary_tables.get().scalars24.len()
+            > 0xFFF
+        {
+            // See `try_new_nfkd_unstable`.
+            return Err(NormalizerError::FutureExtension);
+        }
+
+        let cap = supplementary_decompositions.get().passthrough_cap;
+        if cap > 0x0300 {
+            return Err(NormalizerError::ValidationError);
+        }
+        let decomposition_capped = cap.min(0xC0);
+        let composition_capped = cap.min(0x0300);
+
+        Ok(DecomposingNormalizer {
+            decompositions,
+            supplementary_decompositions: Some(supplementary_decompositions),
+            tables,
+            supplementary_tables: Some(SupplementTablesPayloadHolder::NfkcCasefold(
+                supplementary_tables,
+            )),
             decomposition_passthrough_bound: decomposition_capped as u8,
             composition_passthrough_bound: composition_capped as u16,
         })
@@ -1724,6 +1966,167 @@ impl DecomposingNormalizer {
         )
     }
 
+    /// Check whether a string slice is in the FCD form.
+    ///
+    /// Text is FCD ("Fast C or D", see [UTN #5](https://www.unicode.org/notes/tn5/)) if the
+    /// canonical decomposition of each character, taken on its own without reordering, yields
+    /// text in the canonical order. FCD text can be processed as if it were in NFD by
+    /// operations that decompose each character on the fly but don't reorder.
+    ///
+    /// Only the canonical decomposition data is consulted, so the result does not depend on
+    /// which decomposition this normalizer performs.
+    ///
+    /// ```
+    /// use icu_normalizer::DecomposingNormalizer;
+    ///
+    /// let normalizer = DecomposingNormalizer::try_new_nfd_unstable(&icu_testdata::unstable())
+    ///     .expect("Data exists");
+    ///
+    /// // Not NFD, but decomposing "é" gives canonically ordered text.
+    /// assert!(normalizer.is_fcd("é\u{301}"));
+    /// // The acute accent from "é" (ccc 230) would precede the dot below (ccc 220).
+    /// assert!(!normalizer.is_fcd("é\u{323}"));
+    /// ```
+    pub fn is_fcd(&self, text: &str) -> bool {
+        self.is_fcd_impl(text.chars())
+    }
+
+    /// Check whether a slice of potentially-invalid UTF-16 is in the FCD form.
+    ///
+    /// See [`DecomposingNormalizer::is_fcd()`].
+    ///
+    /// Unpaired surrogates are treated as the REPLACEMENT CHARACTER.
+    pub fn is_fcd_utf16(&self, text: &[u16]) -> bool {
+        self.is_fcd_impl(text.chars())
+    }
+
+    fn is_fcd_impl<I: Iterator<Item = char>>(&self, iter: I) -> bool {
+        let decomposition = self.normalize_iter(core::iter::empty::<char>());
+        let mut previous_ccc = CanonicalCombiningClass::NotReordered;
+        for c in iter {
+            if u32::from(c) < 0xC0 {
+                previous_ccc = CanonicalCombiningClass::NotReordered;
+                continue;
+            }
+            let (lead_ccc, trail_ccc) = decomposition.fcd_classes(c);
+            if lead_ccc != CanonicalCombiningClass::NotReordered && lead_ccc < previous_ccc {
+                return false;
+            }
+            previous_ccc = trail_ccc;
+        }
+        true
+    }
+
+    fn quick_check_impl<I: Iterator<Item = (usize, Option<char>)>>(
+        &self,
+        iter: I,
+        len: usize,
+    ) -> (QuickCheckResult, usize) {
+        self.quick_check_with_mode(iter, len, QuickCheckMode::Decomposed)
+    }
+
+    /// Shared implementation of the quick check. `iter` yields each character
+    /// with its offset in `len` code units, or `None` for malformed input.
+    fn quick_check_with_mode<I: Iterator<Item = (usize, Option<char>)>>(
+        &self,
+        iter: I,
+        len: usize,
+        mode: QuickCheckMode,
+    ) -> (QuickCheckResult, usize) {
+        let decomposition = self.normalize_iter(core::iter::empty::<char>());
+        let mut result = QuickCheckResult::Yes;
+        // The offset of the last character that has a normalization boundary
+        // before it.
+        let mut boundary = 0;
+        // Set to `boundary` upon the first `Maybe`.
+        let mut normalized_prefix = None;
+        // A following non-starter must not have a lower canonical combining class.
+        let mut previous_ccc = CanonicalCombiningClass::NotReordered;
+        for (offset, c) in iter {
+            let c = if let Some(c) = c {
+                c
+            } else {
+                // Malformed input maps to the REPLACEMENT CHARACTER, which
+                // has a boundary before it.
+                return (QuickCheckResult::No, normalized_prefix.unwrap_or(offset));
+            };
+            if u32::from(c) < u32::from(self.decomposition_passthrough_bound) {
+                if normalized_prefix.is_none() {
+                    boundary = offset;
+                }
+                previous_ccc = CanonicalCombiningClass::NotReordered;
+                continue;
+            }
+            let c_and_trie_val = decomposition.attach_trie_value(c);
+            let trie_val = c_and_trie_val.trie_val;
+            // Half-width voicing marks get replaced by their decomposition when looked up.
+            let mapped = c_and_trie_val.character != c;
+            let (check, boundary_before) = if mode == QuickCheckMode::Decomposed {
+                let check = if !mapped
+                    && (trie_value_has_ccc(trie_val)
+                        || c_and_trie_val.starter_and_decomposes_to_self())
+                {
+                    QuickCheckResult::Yes
+                } else {
+                    QuickCheckResult::No
+                };
+                (
+                    check,
+                    !mapped && !decomposition_starts_with_non_starter(trie_val),
+                )
+            } else {
+                let check = if mapped
+                    || trie_value_indicates_special_non_starter_decomposition(trie_val)
+                {
+                    QuickCheckResult::No
+                } else if trie_value_has_ccc(trie_val) || c_and_trie_val.can_combine_backwards() {
+                    QuickCheckResult::Maybe
+                } else if c_and_trie_val.potential_passthrough() {
+                    QuickCheckResult::Yes
+                } else if c_and_trie_val.from_supplement
+                    || (trie_val >> 16) == 0
+                    || (trie_val as u16) == NON_ROUND_TRIP_MARKER
+                {
+                    // Compatibility mapping, singleton, or non-round-trippable
+                    // complex decomposition
+                    QuickCheckResult::No
+                } else {
+                    // `potential_passthrough()` excludes some ranges wholesale
+                    // even though some characters in them round-trip.
+                    QuickCheckResult::Maybe
+                };
+                (check, !mapped && !c_and_trie_val.can_combine_backwards())
+            };
+            if boundary_before && normalized_prefix.is_none() {
+                boundary = offset;
+            }
+            if check == QuickCheckResult::No {
+                return (QuickCheckResult::No, normalized_prefix.unwrap_or(boundary));
+            }
+            // In FCC, a character whose decomposition ends with a non-starter
+            // must not be followed by a non-starter with a lower class, since
+            // the composition would then be discontiguous. Elsewhere, the
+            // canonical combining class of the character itself matters.
+            let (lead_ccc, trail_ccc) = if mode == QuickCheckMode::ComposedContiguous {
+                decomposition.fcd_classes(c)
+            } else {
+                let ccc = ccc_from_trie_value(trie_val);
+                (ccc, ccc)
+            };
+            if lead_ccc != CanonicalCombiningClass::NotReordered && lead_ccc < previous_ccc {
+                return (QuickCheckResult::No, normalized_prefix.unwrap_or(boundary));
+            }
+            previous_ccc = trail_ccc;
+            if check == QuickCheckResult::Maybe {
+                result = QuickCheckResult::Maybe;
+                if normalized_prefix.is_none() {
+                    normalized_prefix = Some(boundary);
+                }
+            }
+        }
+        (result, normalized_prefix.unwrap_or(len))
+    }
+
     normalizer_methods!();
 
     decomposing_normalize_to!(
@@ -1988,6 +2391,7 @@ impl DecomposingNormalizer {
 pub struct ComposingNormalizer {
     decomposing_normalizer: DecomposingNormalizer,
     canonical_compositions: DataPayload<CanonicalCompositionsV1Marker>,
+    only_contiguous: bool,
 }
 
 impl ComposingNormalizer {
@@ -2012,6 +2416,7 @@ impl ComposingNormalizer {
         Ok(ComposingNormalizer {
             decomposing_normalizer,
             canonical_compositions,
+            only_contiguous: false,
         })
     }
 
@@ -2049,6 +2454,7 @@ impl ComposingNormalizer {
         Ok(ComposingNormalizer {
             decomposing_normalizer,
             canonical_compositions,
+            only_contiguous: false,
         })
     }
 
@@ -2063,6 +2469,61 @@ impl ComposingNormalizer {
         ]
     );
 
+    /// FCC constructor.
+    ///
+    /// FCC ("Fast C Contiguous", see [UTN #5](https://www.unicode.org/notes/tn5/)) is like NFC
+    /// except that a combining character only composes with the preceding starter if no other
+    /// character remains between them after canonical reordering. The output is always in the
+    /// FCD form.
+    ///
+    /// ```
+    /// use icu_normalizer::ComposingNormalizer;
+    ///
+    /// let fcc = ComposingNormalizer::try_new_fcc_unstable(&icu_testdata::unstable())
+    ///     .expect("Data exists");
+    /// let nfc = ComposingNormalizer::try_new_nfc_unstable(&icu_testdata::unstable())
+    ///     .expect("Data exists");
+    ///
+    /// assert_eq!(fcc.normalize("e\u{301}"), "é");
+    /// // U+0327 COMBINING CEDILLA doesn't compose with "a" and sits between it and the acute.
+    /// assert_eq!(nfc.normalize("a\u{327}\u{301}"), "á\u{327}");
+    /// assert_eq!(fcc.normalize("a\u{327}\u{301}"), "a\u{327}\u{301}");
+    /// ```
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_fcc_unstable<D>(data_provider: &D) -> Result<Self, NormalizerError>
+    where
+        D: DataProvider<CanonicalDecompositionDataV1Marker>
+            + DataProvider<CanonicalDecompositionTablesV1Marker>
+            + DataProvider<CanonicalCompositionsV1Marker>
+            + ?Sized,
+    {
+        let decomposing_normalizer = DecomposingNormalizer::try_new_nfd_unstable(data_provider)?;
+
+        let canonical_compositions: DataPayload<CanonicalCompositionsV1Marker> =
+            data_provider.load(Default::default())?.take_payload()?;
+
+        Ok(ComposingNormalizer {
+            decomposing_normalizer,
+            canonical_compositions,
+            only_contiguous: true,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: skip,
+        options: skip,
+        error: NormalizerError,
+        functions: [
+            Self::try_new_fcc_unstable,
+            try_new_fcc_with_any_provider,
+            try_new_fcc_with_buffer_provider
+        ]
+    );
+
     /// 🚧 \[Experimental\] UTS 46 constructor
     ///
     /// This is a special building block normalization for IDNA that implements parts of the Map
@@ -2117,9 +2578,138 @@ impl ComposingNormalizer {
         Ok(ComposingNormalizer {
             decomposing_normalizer,
             canonical_compositions,
+            only_contiguous: false,
+        })
+    }
+
+    /// 🚧 \[Experimental\] NFKC_Casefold constructor
+    ///
+    /// NFKC_Casefold (NFKC_CF) is NFKC with case folding and the removal of
+    /// Default_Ignorable_Code_Point characters. It is meant for caseless matching of
+    /// identifiers, see [UAX #31](https://www.unicode.org/reports/tr31/).
+    ///
+    /// ```
+    /// use icu_normalizer::ComposingNormalizer;
+    ///
+    /// let normalizer =
+    ///     ComposingNormalizer::try_new_nfkc_casefold_unstable(&icu_testdata::unstable())
+    ///         .expect("Data exists");
+    ///
+    /// assert_eq!(normalizer.normalize("Straße"), "strasse");
+    /// // U+00AD SOFT HYPHEN is removed.
+    /// assert_eq!(normalizer.normalize("ﬁ\u{AD}le"), "file");
+    /// ```
+    ///
+    /// <div class="stab unstable">
+    /// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+    /// including in SemVer minor releases. It can be enabled with the "experimental" feature
+    /// of the icu meta-crate. Use with caution.
+    /// </div>
+    #[cfg(feature = "experimental")]
+    pub fn try_new_nfkc_casefold_unstable<D>(data_provider: &D) -> Result<Self, NormalizerError>
+    where
+        D: DataProvider<CanonicalDecompositionDataV1Marker>
+            + DataProvider<NfkcCasefoldDecompositionSupplementV1Marker>
+            + DataProvider<CanonicalDecompositionTablesV1Marker>
+            + DataProvider<NfkcCasefoldDecompositionTablesV1Marker>
+            + DataProvider<CanonicalCompositionsV1Marker>
+            + ?Sized,
+    {
+        let supplementary_decompositions: DataPayload<NfkcCasefoldDecompositionSupplementV1Marker> =
+            data_provider.load(Default::default())?.take_payload()?;
+        let decomposing_normalizer = DecomposingNormalizer::try_new_nfkc_casefold_decomposed(
+            data_provider,
+            SupplementPayloadHolder::NfkcCasefold(supplementary_decompositions),
+        )?;
+
+        let canonical_compositions: DataPayload<CanonicalCompositionsV1Marker> =
+            data_provider.load(Default::default())?.take_payload()?;
+
+        Ok(ComposingNormalizer {
+            decomposing_normalizer,
+            canonical_compositions,
+            only_contiguous: false,
+        })
+    }
+
+    #[cfg(feature = "experimental")]
+    icu_provider::gen_any_buffer_constructors!(
+        locale: skip,
+        options: skip,
+        error: NormalizerError,
+        functions: [
+            Self::try_new_nfkc_casefold_unstable,
+            try_new_nfkc_casefold_with_any_provider,
+            try_new_nfkc_casefold_with_buffer_provider
+        ]
+    );
+
+    /// 🚧 \[Experimental\] NFKC_Simple_Casefold constructor
+    ///
+    /// NFKC_Simple_Casefold (NFKC_SCF) is like NFKC_Casefold except that it uses simple case
+    /// folding, which never changes the length of the text in characters, instead of full case
+    /// folding.
+    ///
+    /// ```
+    /// use icu_normalizer::ComposingNormalizer;
+    ///
+    /// let normalizer = ComposingNormalizer::try_new_nfkc_simple_casefold_unstable(
+    ///     &icu_testdata::unstable(),
+    /// )
+    /// .expect("Data exists");
+    ///
+    /// assert_eq!(normalizer.normalize("Straße"), "straße");
+    /// assert_eq!(normalizer.normalize("ẞ"), "ß");
+    /// ```
+    ///
+    /// <div class="stab unstable">
+    /// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+    /// including in SemVer minor releases. It can be enabled with the "experimental" feature
+    /// of the icu meta-crate. Use with caution.
+    /// </div>
+    #[cfg(feature = "experimental")]
+    pub fn try_new_nfkc_simple_casefold_unstable<D>(
+        data_provider: &D,
+    ) -> Result<Self, NormalizerError>
+    where
+        D: DataProvider<CanonicalDecompositionDataV1Marker>
+            + DataProvider<NfkcSimpleCasefoldDecompositionSupplementV1Marker>
+            + DataProvider<CanonicalDecompositionTablesV1Marker>
+            + DataProvider<NfkcCasefoldDecompositionTablesV1Marker>
+            // NFKC_Simple_Casefold tables merged into NfkcCasefoldDecompositionTablesV1Marker
+            + DataProvider<CanonicalCompositionsV1Marker>
+            + ?Sized,
+    {
+        let supplementary_decompositions: DataPayload<
+            NfkcSimpleCasefoldDecompositionSupplementV1Marker,
+        > = data_provider.load(Default::default())?.take_payload()?;
+        let decomposing_normalizer = DecomposingNormalizer::try_new_nfkc_casefold_decomposed(
+            data_provider,
+            SupplementPayloadHolder::NfkcSimpleCasefold(supplementary_decompositions),
+        )?;
+
+        let canonical_compositions: DataPayload<CanonicalCompositionsV1Marker> =
+            data_provider.load(Default::default())?.take_payload()?;
+
+        Ok(ComposingNormalizer {
+            decomposing_normalizer,
+            canonical_compositions,
+            only_contiguous: false,
         })
     }
 
+    #[cfg(feature = "experimental")]
+    icu_provider::gen_any_buffer_constructors!(
+        locale: skip,
+        options: skip,
+        error: NormalizerError,
+        functions: [
+            Self::try_new_nfkc_simple_casefold_unstable,
+            try_new_nfkc_simple_casefold_with_any_provider,
+            try_new_nfkc_simple_casefold_with_buffer_provider
+        ]
+    );
+
     /// Wraps a delegate iterator into a composing iterator
     /// adapter by using the data already held by this normalizer.
     pub fn normalize_iter<I: Iterator<Item = char>>(&self, iter: I) -> Composition<I> {
@@ -2140,6 +2730,23 @@ impl ComposingNormalizer {
             ),
             ZeroFrom::zero_from(&self.canonical_compositions.get().canonical_compositions),
             self.decomposing_normalizer.composition_passthrough_bound,
+            self.only_contiguous,
+        )
+    }
+
+    fn quick_check_impl<I: Iterator<Item = (usize, Option<char>)>>(
+        &self,
+        iter: I,
+        len: usize,
+    ) -> (QuickCheckResult, usize) {
+        self.decomposing_normalizer.quick_check_with_mode(
+            iter,
+            len,
+            if self.only_contiguous {
+                QuickCheckMode::ComposedContiguous
+            } else {
+                QuickCheckMode::Composed
+            },
         )
     }
 
@@ -23,11 +23,14 @@ pub struct DecompositionDataV1<'data> {
     pub trie: CodePointTrie<'data, u32>,
 }
 
-/// Data that either NFKD or the decomposed form of UTS 46 needs
-/// _in addition to_ the NFD data.
+/// Data that either NFKD, the decomposed form of UTS 46, or the decomposed
+/// form of NFKC_Casefold or NFKC_Simple_Casefold needs _in addition to_ the
+/// NFD data.
 #[icu_provider::data_struct(
     CompatibilityDecompositionSupplementV1Marker = "normalizer/nfkd@1",
-    Uts46DecompositionSupplementV1Marker = "normalizer/uts46d@1"
+    Uts46DecompositionSupplementV1Marker = "normalizer/uts46d@1",
+    NfkcCasefoldDecompositionSupplementV1Marker = "normalizer/nfkccfd@1",
+    NfkcSimpleCasefoldDecompositionSupplementV1Marker = "normalizer/nfkcscfd@1"
 )]
 #[derive(Debug, PartialEq, Clone)]
 #[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake), databake(path = icu_normalizer::provider))]
@@ -36,6 +39,8 @@ pub struct DecompositionSupplementV1<'data> {
     /// Trie for the decompositions that differ from NFD.
     /// Getting a zero from this trie means that you need
     /// to make another lookup from `DecompositionDataV1::trie`.
+    /// Getting `0xFFFFFFFF` means that the character is removed,
+    /// which only happens in the NFKC_Casefold flavors.
     #[cfg_attr(feature = "serde", serde(borrow))]
     pub trie: CodePointTrie<'data, u32>,
     /// Flags that indicate how the set of characters whose
@@ -70,7 +75,8 @@ impl DecompositionSupplementV1<'_> {
 /// contained in the trie value
 #[icu_provider::data_struct(
     CanonicalDecompositionTablesV1Marker = "normalizer/nfdex@1",
-    CompatibilityDecompositionTablesV1Marker = "normalizer/nfkdex@1"
+    CompatibilityDecompositionTablesV1Marker = "normalizer/nfkdex@1",
+    NfkcCasefoldDecompositionTablesV1Marker = "normalizer/nfkccfex@1"
 )]
 #[derive(Debug, PartialEq, Clone)]
 #[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake), databake(path = icu_normalizer::provider))]
@@ -8,6 +8,7 @@ use icu_normalizer::properties::CanonicalDecomposition;
 use icu_normalizer::properties::Decomposed;
 use icu_normalizer::ComposingNormalizer;
 use icu_normalizer::DecomposingNormalizer;
+use icu_normalizer::QuickCheckResult;
 
 #[test]
 fn test_nfd_basic() {
@@ -116,6 +117,181 @@ fn test_nfkc_basic() {
     assert_eq!(normalizer.normalize("\u{0345}"), "\u{0345}"); // Iota subscript
 }
 
+#[test]
+fn test_fcc_basic() {
+    let normalizer: ComposingNormalizer =
+        ComposingNormalizer::try_new_fcc_unstable(&icu_testdata::unstable()).unwrap();
+    assert_eq!(normalizer.normalize("a\u{0308}"), "ä");
+    assert_eq!(normalizer.normalize("A\u{0308}"), "Ä");
+    assert_eq!(normalizer.normalize("e\u{0323}\u{0302}"), "ệ");
+    assert_eq!(normalizer.normalize("E\u{0302}\u{0323}"), "Ệ");
+    assert_eq!(normalizer.normalize("𝅗𝅥"), "𝅗\u{1D165}"); // Composition exclusion
+    assert_eq!(normalizer.normalize("\u{1100}\u{1161}\u{11A8}"), "각");
+
+    assert_eq!(normalizer.normalize("\u{2126}"), "Ω"); // ohm sign
+    assert_eq!(normalizer.normalize("ﾍﾞ"), "ﾍﾞ"); // half-width unchanged
+    assert_eq!(normalizer.normalize("ﬁ"), "ﬁ"); // ligature unchanged
+
+    // No discontiguous composition
+    assert_eq!(
+        normalizer.normalize("a\u{0327}\u{0301}"),
+        "a\u{0327}\u{0301}"
+    );
+    assert_eq!(normalizer.normalize("á\u{0327}"), "a\u{0327}\u{0301}");
+    assert_eq!(normalizer.normalize("é\u{0316}"), "e\u{0316}\u{0301}");
+    assert_eq!(
+        normalizer.normalize("\u{0DDD}\u{1CE5}"),
+        "\u{0DDC}\u{1CE5}\u{0DCA}"
+    );
+    assert!(normalizer.is_normalized("a\u{0327}\u{0301}"));
+    assert!(!normalizer.is_normalized("á\u{0327}"));
+}
+
+#[test]
+fn test_complex_decomposition_reordering() {
+    let nfd: DecomposingNormalizer =
+        DecomposingNormalizer::try_new_nfd_unstable(&icu_testdata::unstable()).unwrap();
+    let nfc: ComposingNormalizer =
+        ComposingNormalizer::try_new_nfc_unstable(&icu_testdata::unstable()).unwrap();
+    // U+0DDD decomposes to a starter, a backward-combining starter, and a non-starter
+    // with ccc 9, which must be reordered after the following ccc 1 character.
+    assert_eq!(
+        nfd.normalize("\u{0DDD}\u{1CE5}"),
+        "\u{0DD9}\u{0DCF}\u{1CE5}\u{0DCA}"
+    );
+    assert_eq!(
+        nfd.normalize_iter("\u{0DDD}\u{1CE5}".chars())
+            .collect::<String>(),
+        "\u{0DD9}\u{0DCF}\u{1CE5}\u{0DCA}"
+    );
+    assert_eq!(nfc.normalize("\u{0DDD}\u{1CE5}"), "\u{0DDD}\u{1CE5}");
+}
+
+#[test]
+fn test_is_fcd() {
+    let normalizer: DecomposingNormalizer =
+        DecomposingNormalizer::try_new_nfd_unstable(&icu_testdata::unstable()).unwrap();
+    let fcd = [
+        "",
+        "abc",
+        "ä",
+        "a\u{0308}",
+        "é\u{0301}",
+        "ệ",
+        "e\u{0323}\u{0302}",
+        "a\u{0327}\u{0301}",
+        "\u{0301}",
+        "각",
+        "\u{0F73}\u{0F74}", // Decomposes to ccc 129 and ccc 130
+        "𝅗𝅥\u{1D165}",
+    ];
+    let not_fcd = [
+        "a\u{0301}\u{0323}",
+        "é\u{0323}",
+        "á\u{0327}",
+        "\u{0344}\u{0323}", // Decomposes to two characters with ccc 230
+        "\u{0F73}\u{0F71}",
+        "\u{0DDD}\u{1CE5}",
+    ];
+    for text in fcd {
+        assert!(normalizer.is_fcd(text), "{:?}", text);
+        let utf16: Vec<u16> = text.encode_utf16().collect();
+        assert!(normalizer.is_fcd_utf16(&utf16), "{:?}", text);
+    }
+    for text in not_fcd {
+        assert!(!normalizer.is_fcd(text), "{:?}", text);
+        let utf16: Vec<u16> = text.encode_utf16().collect();
+        assert!(!normalizer.is_fcd_utf16(&utf16), "{:?}", text);
+    }
+    // Unpaired surrogate is treated as U+FFFD
+    assert!(normalizer.is_fcd_utf16(&[0x0061, 0xD800, 0x0301]));
+
+    // The FCC form is always FCD.
+    let fcc: ComposingNormalizer =
+        ComposingNormalizer::try_new_fcc_unstable(&icu_testdata::unstable()).unwrap();
+    for text in not_fcd {
+        assert!(normalizer.is_fcd(&fcc.normalize(text)), "{:?}", text);
+    }
+}
+
+#[test]
+fn test_quick_check() {
+    let nfd: DecomposingNormalizer =
+        DecomposingNormalizer::try_new_nfd_unstable(&icu_testdata::unstable()).unwrap();
+    let nfc: ComposingNormalizer =
+        ComposingNormalizer::try_new_nfc_unstable(&icu_testdata::unstable()).unwrap();
+    let nfkc: ComposingNormalizer =
+        ComposingNormalizer::try_new_nfkc_unstable(&icu_testdata::unstable()).unwrap();
+    let fcc: ComposingNormalizer =
+        ComposingNormalizer::try_new_fcc_unstable(&icu_testdata::unstable()).unwrap();
+
+    assert_eq!(nfd.quick_check(""), (QuickCheckResult::Yes, 0));
+    assert_eq!(nfd.quick_check("abc"), (QuickCheckResult::Yes, 3));
+    assert_eq!(nfd.quick_check("e\u{0301}"), (QuickCheckResult::Yes, 3));
+    assert_eq!(nfd.quick_check("abé"), (QuickCheckResult::No, 2));
+    assert_eq!(
+        nfd.quick_check("ab\u{0301}\u{0323}"),
+        (QuickCheckResult::No, 1)
+    );
+    assert_eq!(nfd.quick_check("ab각"), (QuickCheckResult::No, 2));
+
+    assert_eq!(nfc.quick_check("abc"), (QuickCheckResult::Yes, 3));
+    assert_eq!(nfc.quick_check("abé"), (QuickCheckResult::Yes, 4));
+    assert_eq!(nfc.quick_check("é\u{0316}"), (QuickCheckResult::Maybe, 0));
+    assert_eq!(nfc.quick_check("abe\u{0301}"), (QuickCheckResult::Maybe, 2));
+    assert_eq!(
+        nfc.quick_check("abe\u{0301}\u{2126}"),
+        (QuickCheckResult::No, 2)
+    );
+    assert_eq!(
+        nfc.quick_check("ab\u{2126}e\u{0301}"),
+        (QuickCheckResult::No, 2)
+    );
+    assert_eq!(
+        nfc.quick_check("ab\u{0301}\u{0323}"),
+        (QuickCheckResult::No, 1)
+    );
+    assert_eq!(nfc.quick_check("ﬁ"), (QuickCheckResult::Yes, 3));
+    assert_eq!(nfc.quick_check("가\u{11A8}"), (QuickCheckResult::Maybe, 0));
+
+    assert_eq!(nfkc.quick_check("abé"), (QuickCheckResult::Yes, 4));
+    assert_eq!(nfkc.quick_check("aﬁ"), (QuickCheckResult::No, 1));
+    assert_eq!(nfkc.quick_check("aﾍﾞ"), (QuickCheckResult::No, 1));
+
+    assert_eq!(fcc.quick_check("abé"), (QuickCheckResult::Yes, 4));
+    assert_eq!(fcc.quick_check("é\u{0316}"), (QuickCheckResult::No, 0));
+    assert_eq!(fcc.quick_check("xé\u{0301}"), (QuickCheckResult::Maybe, 1));
+
+    let utf16: Vec<u16> = "a\u{1D15E}\u{0301}".encode_utf16().collect();
+    assert_eq!(nfc.quick_check_utf16(&utf16), (QuickCheckResult::No, 1));
+    assert_eq!(nfd.quick_check_utf16(&utf16), (QuickCheckResult::No, 1));
+    let utf16: Vec<u16> = "𝅗\u{1D165}e\u{0301}".encode_utf16().collect();
+    assert_eq!(nfc.quick_check_utf16(&utf16), (QuickCheckResult::Maybe, 0));
+    assert_eq!(nfd.quick_check_utf16(&utf16), (QuickCheckResult::Yes, 6));
+    // Unpaired surrogate
+    assert_eq!(
+        nfc.quick_check_utf16(&[0x0061, 0x0062, 0xDC00, 0x0063]),
+        (QuickCheckResult::No, 2)
+    );
+    assert_eq!(
+        nfd.quick_check_utf16(&[0x0061, 0xFFFD, 0x0063]),
+        (QuickCheckResult::Yes, 3)
+    );
+
+    // The prefix can be kept and the rest normalized separately.
+    for text in ["abe\u{0301}x", "ab\u{2126}e\u{0301}", "ab\u{0301}\u{0323}c"] {
+        for normalizer in [&nfc, &fcc] {
+            let (_, prefix_len) = normalizer.quick_check(text);
+            let (prefix, rest) = text.split_at(prefix_len);
+            assert!(normalizer.is_normalized(prefix));
+            assert_eq!(
+                format!("{}{}", prefix, normalizer.normalize(rest)),
+                normalizer.normalize(text)
+            );
+        }
+    }
+}
+
 #[test]
 #[cfg(feature = "experimental")]
 fn test_uts46_basic() {
@@ -148,6 +324,103 @@ fn test_uts46_basic() {
     assert_eq!(normalizer.normalize("\u{0345}"), "ι");
 }
 
+#[test]
+#[cfg(feature = "experimental")]
+fn test_nfkc_casefold_basic() {
+    let normalizer: ComposingNormalizer =
+        ComposingNormalizer::try_new_nfkc_casefold_unstable(&icu_testdata::unstable()).unwrap();
+    assert_eq!(normalizer.normalize("a\u{0308}"), "ä");
+    assert_eq!(normalizer.normalize("A\u{0308}"), "ä");
+    assert_eq!(normalizer.normalize("e\u{0323}\u{0302}"), "ệ");
+    assert_eq!(normalizer.normalize("E\u{0323}\u{0302}"), "ệ");
+    assert_eq!(normalizer.normalize("𝅗𝅥"), "𝅗\u{1D165}"); // Composition exclusion
+
+    assert_eq!(normalizer.normalize("\u{2126}"), "ω"); // ohm sign
+    assert_eq!(normalizer.normalize("ﾍﾞ"), "ベ"); // half-width to full-width, the compose
+    assert_eq!(normalizer.normalize("ﾍﾟ"), "ペ"); // half-width to full-width, the compose
+    assert_eq!(normalizer.normalize("ﬁ"), "fi"); // ligature expanded
+    assert_eq!(normalizer.normalize("\u{FDFA}"), "\u{0635}\u{0644}\u{0649} \u{0627}\u{0644}\u{0644}\u{0647} \u{0639}\u{0644}\u{064A}\u{0647} \u{0648}\u{0633}\u{0644}\u{0645}");
+    // ligature expanded
+    assert_eq!(normalizer.normalize("㈎"), "(가)"); // parenthetical expanded and partially recomposed
+    assert_eq!(normalizer.normalize("㏇"), "co."); // square abbreviation expanded and folded
+    assert_eq!(normalizer.normalize("𝐀"), "a"); // mathematical letter
+    assert_eq!(normalizer.normalize("𐐀"), "𐐨"); // case pair outside the BMP
+
+    // Full case folding
+    assert_eq!(normalizer.normalize("Straße"), "strasse");
+    assert_eq!(normalizer.normalize("ẞ"), "ss");
+    assert_eq!(normalizer.normalize("ΣΑΣ"), "σασ");
+    assert_eq!(normalizer.normalize("ᾳ"), "αι");
+    assert_eq!(normalizer.normalize("İ"), "i\u{0307}");
+
+    // Iota subscript
+    assert_eq!(normalizer.normalize("\u{0345}"), "ι");
+
+    // Default ignorables are removed
+    assert_eq!(normalizer.normalize("\u{00AD}"), "");
+    assert_eq!(normalizer.normalize("\u{200B}\u{200B}"), "");
+    assert_eq!(normalizer.normalize("soft\u{00AD}Hyphen"), "softhyphen");
+    assert_eq!(normalizer.normalize("\u{FEFF}bom"), "bom");
+    assert_eq!(normalizer.normalize("end\u{E0001}"), "end");
+    // Removal happens before composition and reordering
+    assert_eq!(normalizer.normalize("e\u{200D}\u{0301}"), "é");
+    assert_eq!(normalizer.normalize("E\u{0302}\u{FE0F}\u{0323}"), "ệ");
+    assert_eq!(normalizer.normalize("\u{1100}\u{034F}\u{1161}"), "가");
+}
+
+#[test]
+#[cfg(feature = "experimental")]
+fn test_nfkc_casefold_utf8_utf16() {
+    let normalizer: ComposingNormalizer =
+        ComposingNormalizer::try_new_nfkc_casefold_unstable(&icu_testdata::unstable()).unwrap();
+    let cases = [
+        ("", ""),
+        ("abc", "abc"),
+        ("ABC", "abc"),
+        ("\u{00AD}", ""),
+        ("a\u{00AD}b", "ab"),
+        ("ab\u{00AD}", "ab"),
+        ("aé\u{00AD}", "aé"),
+        ("e\u{00AD}\u{0301}", "é"),
+        ("ä\u{200B}Ö\u{200B}", "äö"),
+        ("\u{1D400}\u{E0020}\u{1D401}", "ab"),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(normalizer.normalize(input), expected);
+        assert_eq!(normalizer.normalize_utf8(input.as_bytes()), expected);
+        let utf16: Vec<u16> = input.encode_utf16().collect();
+        let expected16: Vec<u16> = expected.encode_utf16().collect();
+        assert_eq!(normalizer.normalize_utf16(&utf16), expected16);
+        assert_eq!(
+            normalizer.normalize_iter(input.chars()).collect::<String>(),
+            expected
+        );
+        assert_eq!(normalizer.is_normalized(input), input == expected);
+    }
+}
+
+#[test]
+#[cfg(feature = "experimental")]
+fn test_nfkc_simple_casefold_basic() {
+    let normalizer: ComposingNormalizer =
+        ComposingNormalizer::try_new_nfkc_simple_casefold_unstable(&icu_testdata::unstable())
+            .unwrap();
+    assert_eq!(normalizer.normalize("A\u{0308}"), "ä");
+    assert_eq!(normalizer.normalize("\u{2126}"), "ω"); // ohm sign
+    assert_eq!(normalizer.normalize("ﾍﾞ"), "ベ"); // half-width to full-width, the compose
+    assert_eq!(normalizer.normalize("ﬁ"), "fi"); // ligature expanded
+    assert_eq!(normalizer.normalize("㏇"), "co."); // square abbreviation expanded and folded
+
+    // Simple case folding
+    assert_eq!(normalizer.normalize("Straße"), "straße");
+    assert_eq!(normalizer.normalize("ẞ"), "ß");
+    assert_eq!(normalizer.normalize("ΣΑΣ"), "σασ");
+
+    // Default ignorables are removed
+    assert_eq!(normalizer.normalize("soft\u{00AD}Hyphen"), "softhyphen");
+    assert_eq!(normalizer.normalize("e\u{200D}\u{0301}"), "é");
+}
+
 type StackString = arraystring::ArrayString<arraystring::typenum::U48>;
 
 #[test]
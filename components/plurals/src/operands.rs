@@ -6,7 +6,7 @@ use core::isize;
 use core::num::ParseIntError;
 use core::str::FromStr;
 use displaydoc::Display;
-use fixed_decimal::FixedDecimal;
+use fixed_decimal::{CompactDecimal, FixedDecimal};
 
 /// A full plural operands representation of a number. See [CLDR Plural Rules](http://unicode.org/reports/tr35/tr35-numbers.html#Language_Plural_Rules) for complete operands description.
 /// Plural operands in compliance with [CLDR Plural Rules](http://unicode.org/reports/tr35/tr35-numbers.html#Language_Plural_Rules).
@@ -272,3 +272,30 @@ impl From<&FixedDecimal> for PluralOperands {
         }
     }
 }
+
+impl From<&CompactDecimal> for PluralOperands {
+    /// Converts a [`fixed_decimal::CompactDecimal`] to [`PluralOperands`]. Retains at most 18
+    /// digits each from the integer and fraction parts of the significand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{CompactDecimal, FixedDecimal};
+    /// use icu::plurals::PluralOperands;
+    ///
+    /// let compact = CompactDecimal::from_significand_and_exponent(
+    ///     FixedDecimal::from(12).multiplied_pow10(-1),
+    ///     6,
+    /// );
+    ///
+    /// assert_eq!(
+    ///     PluralOperands::from(&compact),
+    ///     "1.2e6".parse().expect("valid operands")
+    /// );
+    /// ```
+    fn from(compact: &CompactDecimal) -> Self {
+        let mut result = PluralOperands::from(compact.significand());
+        result.c = compact.exponent() as usize;
+        result
+    }
+}
@@ -8,10 +8,14 @@ use crate::internals::*;
 #[cfg(feature = "datagen")]
 use crate::provider::CaseMappingV1;
 use crate::provider::CaseMappingV1Marker;
+#[cfg(feature = "titlecase")]
+use crate::titlecase::TitlecaseOptions;
 #[cfg(feature = "datagen")]
 use icu_collections::codepointtrie::CodePointTrieHeader;
 use icu_locid::Locale;
 use icu_provider::prelude::*;
+#[cfg(feature = "titlecase")]
+use icu_segmenter::WordBreakSegmenter;
 
 /// A struct with the ability to convert characters and strings to uppercase or lowercase,
 /// or fold them to a normalized form for case-insensitive comparison.
@@ -132,6 +136,24 @@ impl CaseMapping {
 
     /// Returns the full uppercase mapping of the given string.
     /// This function is context and locale sensitive.
+    ///
+    /// In Greek, accents and breathing marks are removed from uppercased letters,
+    /// as is customary in Greek uppercase text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_casemapping::CaseMapping;
+    /// use icu_locid::locale;
+    ///
+    /// let cm = CaseMapping::try_new(&icu_testdata::unstable()).expect("Data exists");
+    /// assert_eq!(cm.to_full_uppercase("Καλημέρα"), "ΚΑΛΗΜΈΡΑ");
+    ///
+    /// let cm = CaseMapping::try_new_with_locale(&icu_testdata::unstable(), &locale!("el"))
+    ///     .expect("Data exists");
+    /// assert_eq!(cm.to_full_uppercase("Καλημέρα"), "ΚΑΛΗΜΕΡΑ");
+    /// assert_eq!(cm.to_full_uppercase("Μάιος"), "ΜΑΪΟΣ");
+    /// ```
     pub fn to_full_uppercase(&self, src: &str) -> String {
         self.internals
             .get()
@@ -139,6 +161,63 @@ impl CaseMapping {
             .full_uppercase(src, self.locale)
     }
 
+    /// Returns the full titlecase mapping of the given string, using the default
+    /// [`TitlecaseOptions`].
+    ///
+    /// Word boundaries are determined by the given [`WordBreakSegmenter`]. In each word,
+    /// the first cased letter is titlecased and the rest of the word is lowercased.
+    /// This function is context and locale sensitive.
+    ///
+    /// ✨ **Enabled with the `"titlecase"` feature.**
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_casemapping::CaseMapping;
+    /// use icu_locid::locale;
+    /// use icu_segmenter::WordBreakSegmenter;
+    ///
+    /// let segmenter = WordBreakSegmenter::try_new_unstable(&icu_testdata::unstable())
+    ///     .expect("Data exists");
+    ///
+    /// let cm = CaseMapping::try_new(&icu_testdata::unstable()).expect("Data exists");
+    /// assert_eq!(cm.to_full_titlecase("hello WORLD", &segmenter), "Hello World");
+    /// // Special full mappings are applied to the titlecased letter.
+    /// assert_eq!(cm.to_full_titlecase("ǆungla ﬁre", &segmenter), "ǅungla Fire");
+    /// // Final sigma is detected when lowercasing the rest of a word.
+    /// assert_eq!(cm.to_full_titlecase("ΟΔΟΣ ΣΟΦΙΑΣ", &segmenter), "Οδος Σοφιας");
+    ///
+    /// let cm = CaseMapping::try_new_with_locale(&icu_testdata::unstable(), &locale!("nl"))
+    ///     .expect("Data exists");
+    /// assert_eq!(cm.to_full_titlecase("ijsland", &segmenter), "IJsland");
+    /// ```
+    #[cfg(feature = "titlecase")]
+    pub fn to_full_titlecase(&self, src: &str, segmenter: &WordBreakSegmenter) -> String {
+        self.to_full_titlecase_with_options(src, segmenter, TitlecaseOptions::default())
+    }
+
+    /// Returns the full titlecase mapping of the given string, using the given
+    /// [`TitlecaseOptions`].
+    ///
+    /// Word boundaries are determined by the given [`WordBreakSegmenter`].
+    /// This function is context and locale sensitive.
+    ///
+    /// ✨ **Enabled with the `"titlecase"` feature.**
+    #[cfg(feature = "titlecase")]
+    pub fn to_full_titlecase_with_options(
+        &self,
+        src: &str,
+        segmenter: &WordBreakSegmenter,
+        options: TitlecaseOptions,
+    ) -> String {
+        self.internals.get().casemap.full_titlecase(
+            src,
+            segmenter.segment_str(src),
+            self.locale,
+            options,
+        )
+    }
+
     /// Case-folds the characters in the given string.
     /// This function is locale-independent and context-insensitive.
     pub fn full_fold(&self, src: &str) -> String {
@@ -156,4 +235,85 @@ impl CaseMapping {
             .casemap
             .full_folding(src, CaseMapLocale::Turkish)
     }
+
+    /// Returns true if the two strings are equal after full case folding, that is,
+    /// if they are equal when case is ignored.
+    ///
+    /// This is equivalent to comparing the results of [`CaseMapping::full_fold`],
+    /// but does not allocate. This function is locale-independent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_casemapping::CaseMapping;
+    ///
+    /// let cm = CaseMapping::try_new(&icu_testdata::unstable()).expect("Data exists");
+    /// assert!(cm.eq_ignore_case("Straße", "STRASSE"));
+    /// assert!(cm.eq_ignore_case("ΣΊΣΥΦΟΣ", "σίσυφος"));
+    /// assert!(!cm.eq_ignore_case("Straße", "Strasse!"));
+    /// ```
+    pub fn eq_ignore_case(&self, a: &str, b: &str) -> bool {
+        self.internals
+            .get()
+            .casemap
+            .full_folding_eq(a, b, CaseMapLocale::Root)
+    }
+
+    /// Adds all simple and full case mappings of the given `char` to `set`, as well
+    /// as the characters and strings that case fold to the same thing, such as the
+    /// Kelvin sign for `k`, or "ss" for `ß`. The character itself is not added.
+    ///
+    /// Together with the character itself, these form its case closure, which can be
+    /// used to implement case-insensitive matching of character classes.
+    ///
+    /// Strings are only added if `set` supports them; see [`ClosureSet`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_casemapping::CaseMapping;
+    /// use icu_collections::codepointinvlist::CodePointInversionListBuilder;
+    ///
+    /// let cm = CaseMapping::try_new(&icu_testdata::unstable()).expect("Data exists");
+    ///
+    /// let mut builder = CodePointInversionListBuilder::new();
+    /// cm.add_case_closure('k', &mut builder);
+    /// let set = builder.build();
+    ///
+    /// assert!(set.contains('K'));
+    /// assert!(set.contains('\u{212A}')); // Kelvin sign
+    /// assert!(!set.contains('k'));
+    /// ```
+    pub fn add_case_closure<S: ClosureSet>(&self, c: char, set: &mut S) {
+        self.internals.get().casemap.add_case_closure(c, set)
+    }
+
+    /// Finds all characters that case fold to the given string, and adds them to
+    /// `set` together with their case closures, as with [`CaseMapping::add_case_closure`].
+    /// The string itself is also added, if `set` supports strings.
+    ///
+    /// Returns true if any characters fold to the string. Strings of fewer than two
+    /// characters are never found; use [`CaseMapping::add_case_closure`] for them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_casemapping::CaseMapping;
+    /// use icu_collections::codepointinvlist::CodePointInversionListBuilder;
+    ///
+    /// let cm = CaseMapping::try_new(&icu_testdata::unstable()).expect("Data exists");
+    ///
+    /// let mut builder = CodePointInversionListBuilder::new();
+    /// assert!(cm.add_string_case_closure("ss", &mut builder));
+    /// let set = builder.build();
+    ///
+    /// assert!(set.contains('ß'));
+    /// assert!(set.contains('ẞ'));
+    ///
+    /// let mut builder = CodePointInversionListBuilder::new();
+    /// assert!(!cm.add_string_case_closure("xyz", &mut builder));
+    /// ```
+    pub fn add_string_case_closure<S: ClosureSet>(&self, s: &str, set: &mut S) -> bool {
+        self.internals.get().casemap.add_string_case_closure(s, set)
+    }
 }
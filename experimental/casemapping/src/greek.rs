@@ -0,0 +1,127 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// Data for the Greek-specific uppercasing algorithm, which removes accents and
+// breathing marks, and converts iota subscripts into capital iotas.
+// This is a port of the `GreekUpper` data in ICU4C's `ucasemap.cpp`, see
+// <https://icu.unicode.org/design/case/greek-upper>.
+//
+// Each letter maps to a u16 containing its uppercase base letter (without any
+// diacritics) in the low bits, together with flags for the diacritics that the
+// precomposed letter carries. The tables are derived from the canonical
+// decompositions of the letters in the two Greek blocks.
+
+// The uppercase base letter. All Greek capital base letters are below U+0400.
+pub(crate) const UPPER_MASK: u32 = 0x3ff;
+// The base letter is a vowel.
+pub(crate) const HAS_VOWEL: u32 = 0x1000;
+// The letter carries a ypogegrammeni (iota subscript).
+pub(crate) const HAS_YPOGEGRAMMENI: u32 = 0x2000;
+// The letter carries an accent (tonos, varia, perispomeni, or a lookalike).
+pub(crate) const HAS_ACCENT: u32 = 0x4000;
+// The letter carries a precomposed dialytika (diaeresis).
+pub(crate) const HAS_DIALYTIKA: u32 = 0x8000;
+// Only used for combining diacritics: a combining dialytika follows the letter.
+pub(crate) const HAS_COMBINING_DIALYTIKA: u32 = 0x10000;
+// Only used for combining diacritics: a breathing or length mark follows the letter.
+pub(crate) const HAS_OTHER_GREEK_DIACRITIC: u32 = 0x20000;
+
+pub(crate) const HAS_VOWEL_AND_ACCENT: u32 = HAS_VOWEL | HAS_ACCENT;
+pub(crate) const HAS_VOWEL_AND_ACCENT_AND_DIALYTIKA: u32 = HAS_VOWEL_AND_ACCENT | HAS_DIALYTIKA;
+pub(crate) const HAS_EITHER_DIALYTIKA: u32 = HAS_DIALYTIKA | HAS_COMBINING_DIALYTIKA;
+
+// Letter data for U+0370..U+03FF (Greek and Coptic).
+#[rustfmt::skip]
+const DATA_0370: [u16; 0x90] = [
+    0x0370, 0x0370, 0x0372, 0x0372, 0x0000, 0x0000, 0x0376, 0x0376, // U+0370
+    0x0000, 0x0000, 0x037a, 0x03fd, 0x03fe, 0x03ff, 0x0000, 0x037f, // U+0378
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x5391, 0x0000, // U+0380
+    0x5395, 0x5397, 0x5399, 0x0000, 0x539f, 0x0000, 0x53a5, 0x53a9, // U+0388
+    0xd399, 0x1391, 0x0392, 0x0393, 0x0394, 0x1395, 0x0396, 0x1397, // U+0390
+    0x0398, 0x1399, 0x039a, 0x039b, 0x039c, 0x039d, 0x039e, 0x139f, // U+0398
+    0x03a0, 0x03a1, 0x0000, 0x03a3, 0x03a4, 0x13a5, 0x03a6, 0x03a7, // U+03A0
+    0x03a8, 0x13a9, 0x9399, 0x93a5, 0x5391, 0x5395, 0x5397, 0x5399, // U+03A8
+    0xd3a5, 0x1391, 0x0392, 0x0393, 0x0394, 0x1395, 0x0396, 0x1397, // U+03B0
+    0x0398, 0x1399, 0x039a, 0x039b, 0x039c, 0x039d, 0x039e, 0x139f, // U+03B8
+    0x03a0, 0x03a1, 0x03a3, 0x03a3, 0x03a4, 0x13a5, 0x03a6, 0x03a7, // U+03C0
+    0x03a8, 0x13a9, 0x9399, 0x93a5, 0x539f, 0x53a5, 0x53a9, 0x03cf, // U+03C8
+    0x0392, 0x0398, 0x13d2, 0x53d2, 0x93d2, 0x03a6, 0x03a0, 0x03cf, // U+03D0
+    0x03d8, 0x03d8, 0x03da, 0x03da, 0x03dc, 0x03dc, 0x03de, 0x03de, // U+03D8
+    0x03e0, 0x03e0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // U+03E0
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // U+03E8
+    0x039a, 0x03a1, 0x03f9, 0x037f, 0x03f4, 0x1395, 0x0000, 0x03f7, // U+03F0
+    0x03f7, 0x03f9, 0x03fa, 0x03fa, 0x03fc, 0x03fd, 0x03fe, 0x03ff, // U+03F8
+];
+
+// Letter data for U+1F00..U+1FFF (Greek Extended).
+#[rustfmt::skip]
+const DATA_1F00: [u16; 0x100] = [
+    0x1391, 0x1391, 0x5391, 0x5391, 0x5391, 0x5391, 0x5391, 0x5391, // U+1F00
+    0x1391, 0x1391, 0x5391, 0x5391, 0x5391, 0x5391, 0x5391, 0x5391, // U+1F08
+    0x1395, 0x1395, 0x5395, 0x5395, 0x5395, 0x5395, 0x0000, 0x0000, // U+1F10
+    0x1395, 0x1395, 0x5395, 0x5395, 0x5395, 0x5395, 0x0000, 0x0000, // U+1F18
+    0x1397, 0x1397, 0x5397, 0x5397, 0x5397, 0x5397, 0x5397, 0x5397, // U+1F20
+    0x1397, 0x1397, 0x5397, 0x5397, 0x5397, 0x5397, 0x5397, 0x5397, // U+1F28
+    0x1399, 0x1399, 0x5399, 0x5399, 0x5399, 0x5399, 0x5399, 0x5399, // U+1F30
+    0x1399, 0x1399, 0x5399, 0x5399, 0x5399, 0x5399, 0x5399, 0x5399, // U+1F38
+    0x139f, 0x139f, 0x539f, 0x539f, 0x539f, 0x539f, 0x0000, 0x0000, // U+1F40
+    0x139f, 0x139f, 0x539f, 0x539f, 0x539f, 0x539f, 0x0000, 0x0000, // U+1F48
+    0x13a5, 0x13a5, 0x53a5, 0x53a5, 0x53a5, 0x53a5, 0x53a5, 0x53a5, // U+1F50
+    0x0000, 0x13a5, 0x0000, 0x53a5, 0x0000, 0x53a5, 0x0000, 0x53a5, // U+1F58
+    0x13a9, 0x13a9, 0x53a9, 0x53a9, 0x53a9, 0x53a9, 0x53a9, 0x53a9, // U+1F60
+    0x13a9, 0x13a9, 0x53a9, 0x53a9, 0x53a9, 0x53a9, 0x53a9, 0x53a9, // U+1F68
+    0x5391, 0x5391, 0x5395, 0x5395, 0x5397, 0x5397, 0x5399, 0x5399, // U+1F70
+    0x539f, 0x539f, 0x53a5, 0x53a5, 0x53a9, 0x53a9, 0x0000, 0x0000, // U+1F78
+    0x3391, 0x3391, 0x7391, 0x7391, 0x7391, 0x7391, 0x7391, 0x7391, // U+1F80
+    0x3391, 0x3391, 0x7391, 0x7391, 0x7391, 0x7391, 0x7391, 0x7391, // U+1F88
+    0x3397, 0x3397, 0x7397, 0x7397, 0x7397, 0x7397, 0x7397, 0x7397, // U+1F90
+    0x3397, 0x3397, 0x7397, 0x7397, 0x7397, 0x7397, 0x7397, 0x7397, // U+1F98
+    0x33a9, 0x33a9, 0x73a9, 0x73a9, 0x73a9, 0x73a9, 0x73a9, 0x73a9, // U+1FA0
+    0x33a9, 0x33a9, 0x73a9, 0x73a9, 0x73a9, 0x73a9, 0x73a9, 0x73a9, // U+1FA8
+    0x1391, 0x1391, 0x7391, 0x3391, 0x7391, 0x0000, 0x5391, 0x7391, // U+1FB0
+    0x1391, 0x1391, 0x5391, 0x5391, 0x3391, 0x0000, 0x1399, 0x0000, // U+1FB8
+    0x0000, 0x0000, 0x7397, 0x3397, 0x7397, 0x0000, 0x5397, 0x7397, // U+1FC0
+    0x5395, 0x5395, 0x5397, 0x5397, 0x3397, 0x0000, 0x0000, 0x0000, // U+1FC8
+    0x1399, 0x1399, 0xd399, 0xd399, 0x0000, 0x0000, 0x5399, 0xd399, // U+1FD0
+    0x1399, 0x1399, 0x5399, 0x5399, 0x0000, 0x0000, 0x0000, 0x0000, // U+1FD8
+    0x13a5, 0x13a5, 0xd3a5, 0xd3a5, 0x03a1, 0x03a1, 0x53a5, 0xd3a5, // U+1FE0
+    0x13a5, 0x13a5, 0x53a5, 0x53a5, 0x03a1, 0x0000, 0x0000, 0x0000, // U+1FE8
+    0x0000, 0x0000, 0x73a9, 0x33a9, 0x73a9, 0x0000, 0x53a9, 0x73a9, // U+1FF0
+    0x539f, 0x539f, 0x53a9, 0x53a9, 0x33a9, 0x0000, 0x0000, 0x0000, // U+1FF8
+];
+
+// Letter data for U+2126 OHM SIGN, which uppercases like omega.
+const DATA_2126: u16 = 0x13a9;
+
+// Returns the data for a Greek letter, or 0 if `c` is not a Greek letter.
+pub(crate) fn letter_data(c: char) -> u32 {
+    let c = c as usize;
+    let data = match c {
+        0x370..=0x3ff => DATA_0370[c - 0x370],
+        0x1f00..=0x1fff => DATA_1F00[c - 0x1f00],
+        0x2126 => DATA_2126,
+        _ => 0,
+    };
+    data as u32
+}
+
+// Returns the flags for a combining diacritic that is expected to be used
+// with Greek letters, or 0 if `c` is not one of them.
+pub(crate) fn diacritic_data(c: char) -> u32 {
+    match c {
+        // Varia, tonos (= oxia), and perispomeni, as well as the circumflex,
+        // tilde, and inverted breve, which can look like perispomeni.
+        '\u{300}' | '\u{301}' | '\u{342}' | '\u{302}' | '\u{303}' | '\u{311}' => HAS_ACCENT,
+        // Dialytika (= diaeresis).
+        '\u{308}' => HAS_COMBINING_DIALYTIKA,
+        // Dialytika tonos.
+        '\u{344}' => HAS_COMBINING_DIALYTIKA | HAS_ACCENT,
+        // Ypogegrammeni (= iota subscript).
+        '\u{345}' => HAS_YPOGEGRAMMENI,
+        // Macron, breve, comma above (smooth breathing), reversed comma above
+        // (rough breathing), and koronis.
+        '\u{304}' | '\u{306}' | '\u{313}' | '\u{314}' | '\u{343}' => HAS_OTHER_GREEK_DIACRITIC,
+        _ => 0,
+    }
+}
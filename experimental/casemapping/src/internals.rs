@@ -4,6 +4,7 @@
 
 use core::convert::TryFrom;
 use core::num::TryFromIntError;
+use core::str::{CharIndices, Chars};
 use icu_collections::codepointinvlist::CodePointInversionListBuilder;
 #[cfg(feature = "datagen")]
 use icu_collections::codepointtrie::CodePointTrieHeader;
@@ -21,6 +22,9 @@ use crate::error::Error;
 use crate::exceptions::{CaseMappingExceptions, ExceptionSlot};
 #[cfg(feature = "datagen")]
 use crate::exceptions_builder::CaseMappingExceptionsBuilder;
+use crate::greek;
+#[cfg(feature = "titlecase")]
+use crate::titlecase::{LeadingAdjustment, TitlecaseOptions, TrailingCase};
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum MappingKind {
@@ -581,14 +585,16 @@ impl<'data> CaseMappingInternals<'data> {
                     return FullMappingResult::String(mapped_string);
                 }
             }
+            // This has to be checked before the delta, which is the lowercase mapping of
+            // characters that do not fold to lowercase, such as uppercase Cherokee.
+            if kind == MappingKind::Fold && self.exceptions.no_simple_case_folding(idx) {
+                return FullMappingResult::CodePoint(c);
+            }
             if data.is_relevant_to(kind) && self.exceptions.has_slot(idx, ExceptionSlot::Delta) {
                 let mapped = c as i32 + self.exceptions.delta(idx);
                 let mapped = char::from_u32(mapped as u32).expect("Checked in validate()");
                 return FullMappingResult::CodePoint(mapped);
             }
-            if kind == MappingKind::Fold && self.exceptions.no_simple_case_folding(idx) {
-                return FullMappingResult::CodePoint(c);
-            }
 
             if let Some(slot_char) = self.exceptions.slot_char_for_kind(idx, kind) {
                 FullMappingResult::CodePoint(slot_char)
@@ -616,8 +622,7 @@ impl<'data> CaseMappingInternals<'data> {
         self.full_helper(c, context, locale, MappingKind::Upper)
     }
 
-    // Titlecase mapping is not yet exposed
-    #[allow(dead_code)]
+    #[cfg(feature = "titlecase")]
     pub(crate) fn to_full_title(
         &self,
         c: char,
@@ -739,7 +744,7 @@ impl<'data> CaseMappingInternals<'data> {
         c: char,
         context: ContextIterator,
         locale: CaseMapLocale,
-        _is_title: bool,
+        is_title: bool,
     ) -> Option<FullMappingResult> {
         if locale == CaseMapLocale::Turkish && c == 'i' {
             // In Turkic languages, i turns into a dotted capital I.
@@ -753,16 +758,17 @@ impl<'data> CaseMappingInternals<'data> {
             // Remove dot_above after i with upper or titlecase.
             return Some(FullMappingResult::Remove);
         }
-        // TODO: ICU4C has a non-standard extension for Armenian ligature ech-yiwn.
-        // Should we also implement it?
-        // if c == '\u{587}' {
-        //     return match (locale, is_title) {
-        // 	(CaseMapLocale::Armenian, false) => Some(FullMappingResult::String("ԵՎ")),
-        // 	(CaseMapLocale::Armenian, true) => Some(FullMappingResult::String("Եվ")),
-        // 	(_, false) => Some(FullMappingResult::String("ԵՒ")),
-        // 	(_, true) => Some(FullMappingResult::String("Եւ")),
-        //     }
-        // }
+        if c == '\u{587}' {
+            // The Armenian ligature ech-yiwn has no uppercase form, and maps to
+            // ech + yiwn by default. ICU4C uses ech + vew in Eastern Armenian instead,
+            // following the spelling reform; see ICU-13416.
+            return match (locale, is_title) {
+                (CaseMapLocale::Armenian, false) => Some(FullMappingResult::String("ԵՎ")),
+                (CaseMapLocale::Armenian, true) => Some(FullMappingResult::String("Եվ")),
+                (_, false) => Some(FullMappingResult::String("ԵՒ")),
+                (_, true) => Some(FullMappingResult::String("Եւ")),
+            };
+        }
         None
     }
 
@@ -821,6 +827,9 @@ impl<'data> CaseMappingInternals<'data> {
     }
 
     pub(crate) fn full_uppercase(&self, src: &str, locale: CaseMapLocale) -> String {
+        if locale == CaseMapLocale::Greek {
+            return self.full_uppercase_greek(src);
+        }
         let mut result = String::with_capacity(src.len());
 
         // To speed up the copying of long runs where nothing changes, we keep track
@@ -855,6 +864,131 @@ impl<'data> CaseMappingInternals<'data> {
         result
     }
 
+    // Uppercases Greek text the way it is written in Greek: accents and breathing
+    // marks are removed, except on the disjunctive eta ("Ή"), a dialytika is added
+    // where removing an accent would otherwise turn a diphthong into two vowels,
+    // and iota subscripts become capital iotas.
+    // Characters other than Greek letters are mapped as for other locales.
+    // This is a port of `GreekUpper::toUpper` in ICU4C.
+    fn full_uppercase_greek(&self, src: &str) -> String {
+        use greek::*;
+
+        // The previous character is a cased letter, possibly followed by
+        // case-ignorable characters.
+        const AFTER_CASED: u8 = 1;
+        // The previous character is a vowel whose precomposed accent was removed.
+        const AFTER_VOWEL_WITH_PRECOMPOSED_ACCENT: u8 = 2;
+        // The previous character is a vowel whose combining accent was removed.
+        const AFTER_VOWEL_WITH_COMBINING_ACCENT: u8 = 4;
+
+        let mut result = String::with_capacity(src.len());
+        let mut state = 0;
+        let mut chars = src.char_indices().peekable();
+        while let Some((i, c)) = chars.next() {
+            let mut next_state = 0;
+            let case_data = self.lookup_data(c);
+            if case_data.is_ignorable() {
+                next_state |= state & AFTER_CASED;
+            } else if case_data.case_type() != CaseType::None {
+                next_state |= AFTER_CASED;
+            }
+
+            let mut data = letter_data(c);
+            if data == 0 {
+                // Not a Greek letter.
+                let context = ContextIterator::new(src, i);
+                self.to_full_upper(c, context, CaseMapLocale::Greek)
+                    .append_to(&mut result);
+                state = next_state;
+                continue;
+            }
+
+            let mut upper = char::from_u32(data & UPPER_MASK).unwrap_or(c);
+            if data & HAS_VOWEL != 0
+                && state & (AFTER_VOWEL_WITH_PRECOMPOSED_ACCENT | AFTER_VOWEL_WITH_COMBINING_ACCENT)
+                    != 0
+                && (upper == '\u{399}' || upper == '\u{3a5}')
+            {
+                // Add a dialytika to this iota or ypsilon vowel if we removed a
+                // tonos from the previous vowel, and that previous vowel did not
+                // also have (or gain) a dialytika.
+                data |= if state & AFTER_VOWEL_WITH_PRECOMPOSED_ACCENT != 0 {
+                    HAS_DIALYTIKA
+                } else {
+                    HAS_COMBINING_DIALYTIKA
+                };
+            }
+            // Each ypogegrammeni is mapped to a trailing capital iota.
+            let mut num_ypogegrammeni = (data & HAS_YPOGEGRAMMENI != 0) as usize;
+            let has_precomposed_accent = data & HAS_ACCENT != 0;
+
+            // Skip combining diacritics after this Greek letter.
+            let mut next_idx = i + c.len_utf8();
+            while let Some(&(j, d)) = chars.peek() {
+                let diacritic = diacritic_data(d);
+                if diacritic == 0 {
+                    break;
+                }
+                data |= diacritic;
+                if diacritic & HAS_YPOGEGRAMMENI != 0 {
+                    num_ypogegrammeni += 1;
+                }
+                next_idx = j + d.len_utf8();
+                chars.next();
+            }
+            if data & HAS_VOWEL_AND_ACCENT_AND_DIALYTIKA == HAS_VOWEL_AND_ACCENT {
+                next_state |= if has_precomposed_accent {
+                    AFTER_VOWEL_WITH_PRECOMPOSED_ACCENT
+                } else {
+                    AFTER_VOWEL_WITH_COMBINING_ACCENT
+                };
+            }
+
+            let mut add_tonos = false;
+            let context = ContextIterator {
+                before: &src[..next_idx],
+                after: &src[next_idx..],
+            };
+            if upper == '\u{397}'
+                && data & HAS_ACCENT != 0
+                && num_ypogegrammeni == 0
+                && state & AFTER_CASED == 0
+                && !context.followed_by_cased_letter(self)
+            {
+                // Keep the disjunctive "or" with (only) a tonos. This uses the same
+                // word boundary conditions as the Final_Sigma test.
+                if has_precomposed_accent {
+                    // Preserve the precomposed form.
+                    upper = '\u{389}';
+                } else {
+                    add_tonos = true;
+                }
+            } else if data & HAS_DIALYTIKA != 0 {
+                // Preserve a vowel with a precomposed dialytika.
+                if upper == '\u{399}' {
+                    upper = '\u{3aa}';
+                    data &= !HAS_EITHER_DIALYTIKA;
+                } else if upper == '\u{3a5}' {
+                    upper = '\u{3ab}';
+                    data &= !HAS_EITHER_DIALYTIKA;
+                }
+            }
+
+            result.push(upper);
+            if data & HAS_EITHER_DIALYTIKA != 0 {
+                result.push('\u{308}');
+            }
+            if add_tonos {
+                result.push('\u{301}');
+            }
+            for _ in 0..num_ypogegrammeni {
+                result.push('\u{399}');
+            }
+            state = next_state;
+        }
+        result
+    }
+
     pub(crate) fn full_folding(&self, src: &str, locale: CaseMapLocale) -> String {
         let mut result = String::with_capacity(src.len());
 
@@ -890,6 +1024,130 @@ impl<'data> CaseMappingInternals<'data> {
         result
     }
 
+    // Titlecases each segment of `src` delimited by `boundaries`, which are
+    // the byte indices of word boundaries in ascending order.
+    #[cfg(feature = "titlecase")]
+    pub(crate) fn full_titlecase<I: Iterator<Item = usize>>(
+        &self,
+        src: &str,
+        boundaries: I,
+        locale: CaseMapLocale,
+        options: TitlecaseOptions,
+    ) -> String {
+        let mut result = String::with_capacity(src.len());
+        let mut start = 0;
+        for end in boundaries {
+            if end <= start {
+                continue;
+            }
+            self.titlecase_segment(&mut result, src, start, end, locale, options);
+            start = end;
+        }
+        if start < src.len() {
+            self.titlecase_segment(&mut result, src, start, src.len(), locale, options);
+        }
+        result
+    }
+
+    // Titlecases `src[start..end]` and appends it to `result`. The whole of
+    // `src` is used as context, so that e.g. Greek final sigma is detected
+    // correctly.
+    #[cfg(feature = "titlecase")]
+    fn titlecase_segment(
+        &self,
+        result: &mut String,
+        src: &str,
+        start: usize,
+        end: usize,
+        locale: CaseMapLocale,
+        options: TitlecaseOptions,
+    ) {
+        let segment = &src[start..end];
+        let title_idx = match options.leading_adjustment {
+            LeadingAdjustment::ToCased => segment
+                .char_indices()
+                .find(|&(_, c)| self.lookup_data(c).case_type() != CaseType::None),
+            LeadingAdjustment::None => segment.char_indices().next(),
+        };
+        let (title_idx, c) = match title_idx {
+            Some((i, c)) => (start + i, c),
+            None => {
+                // Nothing to titlecase in this segment.
+                result.push_str(segment);
+                return;
+            }
+        };
+        result.push_str(&src[start..title_idx]);
+        self.to_full_title(c, ContextIterator::new(src, title_idx), locale)
+            .append_to(result);
+        let rest_idx = title_idx + c.len_utf8();
+
+        if locale == CaseMapLocale::Dutch {
+            // In Dutch, the digraph "ij" is titlecased as a unit: "ijssel" becomes "IJssel".
+            if let Some(j_idx) = Self::dutch_ij_index(c, &src[rest_idx..end]) {
+                let j_idx = rest_idx + j_idx;
+                self.append_trailing(result, src, rest_idx, j_idx, locale, options);
+                result.push('J');
+                self.append_trailing(result, src, j_idx + 1, end, locale, options);
+                return;
+            }
+        }
+        self.append_trailing(result, src, rest_idx, end, locale, options);
+    }
+
+    // Given the first letter `c` of a Dutch word and the remainder of the word,
+    // returns the index in `rest` of a "j" that forms the digraph "ij" with `c`.
+    // Acute accents are allowed on the digraph only if both letters carry one.
+    #[cfg(feature = "titlecase")]
+    fn dutch_ij_index(c: char, rest: &str) -> Option<usize> {
+        const ACUTE: char = '\u{301}';
+        let mut chars = rest.char_indices().peekable();
+        let with_acute = match c {
+            'I' | 'i' => chars.next_if(|&(_, c)| c == ACUTE).is_some(),
+            '\u{cd}' | '\u{ed}' => true,
+            _ => return None,
+        };
+        let (j_idx, j) = chars.next()?;
+        if j != 'j' && j != 'J' {
+            return None;
+        }
+        let j_has_acute = matches!(chars.next(), Some((_, ACUTE)));
+        if with_acute != j_has_acute {
+            return None;
+        }
+        Some(j_idx)
+    }
+
+    // Appends `src[start..end]` to `result`, mapped according to the
+    // trailing case option.
+    #[cfg(feature = "titlecase")]
+    fn append_trailing(
+        &self,
+        result: &mut String,
+        src: &str,
+        start: usize,
+        end: usize,
+        locale: CaseMapLocale,
+        options: TitlecaseOptions,
+    ) {
+        match options.trailing_case {
+            TrailingCase::Lower => {
+                for (i, c) in src[start..end].char_indices() {
+                    let context = ContextIterator::new(src, start + i);
+                    self.to_full_lower(c, context, locale).append_to(result);
+                }
+            }
+            TrailingCase::Unchanged => result.push_str(&src[start..end]),
+        }
+    }
+
+    // Returns true if the full case foldings of the two strings are equal.
+    // This is equivalent to comparing the results of `full_folding`, but does
+    // not allocate.
+    pub(crate) fn full_folding_eq(&self, a: &str, b: &str, locale: CaseMapLocale) -> bool {
+        a == b || FullFoldingIter::new(self, a, locale).eq(FullFoldingIter::new(self, b, locale))
+    }
+
     // Adds all simple case mappings and the full case folding for `c` to `set`.
     // Also adds special case closure mappings.
     // The character itself is not added.
@@ -897,7 +1155,7 @@ impl<'data> CaseMappingInternals<'data> {
     // - for s include long s
     // - for sharp s include ss
     // - for k include the Kelvin sign
-    fn add_case_closure<S: ClosureSet>(&self, c: char, set: &mut S) {
+    pub(crate) fn add_case_closure<S: ClosureSet>(&self, c: char, set: &mut S) {
         // Hardcode the case closure of i and its relatives and ignore the
         // data file data for these characters.
         // The Turkic dotless i and dotted I with their case mapping conditions
@@ -974,8 +1232,7 @@ impl<'data> CaseMappingInternals<'data> {
     // the string itself is added as well as part of its code points' closure.
     //
     // Returns true if the string was found
-    #[allow(dead_code)]
-    fn add_string_case_closure<S: ClosureSet>(&self, s: &str, set: &mut S) -> bool {
+    pub(crate) fn add_string_case_closure<S: ClosureSet>(&self, s: &str, set: &mut S) -> bool {
         if s.chars().count() <= 1 {
             // The string is too short to find any match.
             return false;
@@ -997,7 +1254,6 @@ impl<'data> CaseMappingInternals<'data> {
 // enumeration imply that hard-coded special cases exist for this
 // language.
 #[derive(Copy, Clone, Eq, PartialEq)]
-#[allow(dead_code)]
 pub enum CaseMapLocale {
     Root,
     Turkish,
@@ -1027,6 +1283,14 @@ pub enum FullMappingResult<'a> {
 }
 
 impl<'a> FullMappingResult<'a> {
+    fn append_to(&self, result: &mut String) {
+        match self {
+            FullMappingResult::CodePoint(c) => result.push(*c),
+            FullMappingResult::String(s) => result.push_str(s),
+            FullMappingResult::Remove => {}
+        }
+    }
+
     #[allow(dead_code)]
     fn add_to_set<S: ClosureSet>(&self, set: &mut S) {
         match self {
@@ -1037,7 +1301,59 @@ impl<'a> FullMappingResult<'a> {
     }
 }
 
-// Interface for adding items to a closure set.
+// An iterator over the characters of the full case folding of a string.
+struct FullFoldingIter<'a, 'data> {
+    mapping: &'a CaseMappingInternals<'data>,
+    src: &'a str,
+    chars: CharIndices<'a>,
+    // The remaining characters of a string mapping.
+    pending: Chars<'a>,
+    locale: CaseMapLocale,
+}
+
+impl<'a, 'data> FullFoldingIter<'a, 'data> {
+    fn new(mapping: &'a CaseMappingInternals<'data>, src: &'a str, locale: CaseMapLocale) -> Self {
+        Self {
+            mapping,
+            src,
+            chars: src.char_indices(),
+            pending: "".chars(),
+            locale,
+        }
+    }
+}
+
+impl<'a, 'data> Iterator for FullFoldingIter<'a, 'data> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        loop {
+            if let Some(c) = self.pending.next() {
+                return Some(c);
+            }
+            let (i, c) = self.chars.next()?;
+            let context = ContextIterator::new(self.src, i);
+            match self.mapping.to_full_folding(c, context, self.locale) {
+                FullMappingResult::CodePoint(c) => return Some(c),
+                FullMappingResult::String(s) => self.pending = s.chars(),
+                FullMappingResult::Remove => {}
+            }
+        }
+    }
+}
+
+/// An interface for adding items to a case closure set, implemented by
+/// [`CodePointInversionListBuilder`].
+///
+/// Implement this trait for a set type that supports strings in order to also
+/// collect the strings in a case closure, such as "ss" for "ß".
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// <a href="https://github.com/unicode-org/icu4x/issues/2535">#2535</a>
+/// </div>
 pub trait ClosureSet {
     /// Add a character to the set
     fn add_char(&mut self, c: char);
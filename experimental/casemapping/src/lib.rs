@@ -25,9 +25,13 @@ mod error;
 mod exceptions;
 #[cfg(feature = "datagen")]
 mod exceptions_builder;
+mod greek;
 mod internals;
+#[cfg(feature = "titlecase")]
+pub mod titlecase;
 
 pub use casemapping::CaseMapping;
 pub use error::Error as CaseMappingError;
 #[cfg(feature = "datagen")]
 pub use internals::CaseMappingInternals;
+pub use internals::ClosureSet;
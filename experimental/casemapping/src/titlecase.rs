@@ -0,0 +1,106 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for titlecasing strings with [`CaseMapping`](crate::CaseMapping).
+//!
+//! ✨ **Enabled with the `"titlecase"` feature.**
+
+/// A bag of options defining how a string is titlecased by
+/// [`CaseMapping::to_full_titlecase_with_options()`](crate::CaseMapping::to_full_titlecase_with_options).
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// <a href="https://github.com/unicode-org/icu4x/issues/2535">#2535</a>
+/// </div>
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct TitlecaseOptions {
+    /// Which character of each word gets titlecased.
+    pub leading_adjustment: LeadingAdjustment,
+    /// How the characters following the titlecased character are mapped.
+    pub trailing_case: TrailingCase,
+}
+
+/// Where titlecasing starts within a word.
+///
+/// # Examples
+///
+/// ```
+/// use icu_casemapping::titlecase::{LeadingAdjustment, TitlecaseOptions};
+/// use icu_casemapping::CaseMapping;
+/// use icu_segmenter::WordBreakSegmenter;
+///
+/// let cm = CaseMapping::try_new(&icu_testdata::unstable()).expect("Data exists");
+/// let segmenter = WordBreakSegmenter::try_new_unstable(&icu_testdata::unstable())
+///     .expect("Data exists");
+///
+/// let mut options = TitlecaseOptions::default();
+/// assert_eq!(
+///     cm.to_full_titlecase_with_options("the 3rd", &segmenter, options),
+///     "The 3Rd"
+/// );
+///
+/// options.leading_adjustment = LeadingAdjustment::None;
+/// assert_eq!(
+///     cm.to_full_titlecase_with_options("the 3rd", &segmenter, options),
+///     "The 3rd"
+/// );
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum LeadingAdjustment {
+    /// Titlecase the first cased letter of each word, copying any uncased
+    /// characters before it unchanged.
+    ToCased,
+    /// Titlecase the first character of each word, even if it is not a cased letter.
+    None,
+}
+
+impl Default for LeadingAdjustment {
+    fn default() -> Self {
+        Self::ToCased
+    }
+}
+
+/// How the rest of a word is mapped after its first cased letter has been titlecased.
+///
+/// # Examples
+///
+/// ```
+/// use icu_casemapping::titlecase::{TitlecaseOptions, TrailingCase};
+/// use icu_casemapping::CaseMapping;
+/// use icu_segmenter::WordBreakSegmenter;
+///
+/// let cm = CaseMapping::try_new(&icu_testdata::unstable()).expect("Data exists");
+/// let segmenter = WordBreakSegmenter::try_new_unstable(&icu_testdata::unstable())
+///     .expect("Data exists");
+///
+/// let mut options = TitlecaseOptions::default();
+/// assert_eq!(
+///     cm.to_full_titlecase_with_options("visit NASA", &segmenter, options),
+///     "Visit Nasa"
+/// );
+///
+/// options.trailing_case = TrailingCase::Unchanged;
+/// assert_eq!(
+///     cm.to_full_titlecase_with_options("visit NASA", &segmenter, options),
+///     "Visit NASA"
+/// );
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum TrailingCase {
+    /// Lowercase the rest of each word.
+    Lower,
+    /// Leave the rest of each word unchanged.
+    Unchanged,
+}
+
+impl Default for TrailingCase {
+    fn default() -> Self {
+        Self::Lower
+    }
+}
@@ -0,0 +1,67 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_casemapping::{CaseMapping, ClosureSet};
+use std::collections::BTreeSet;
+
+#[derive(Default)]
+struct StringSet(BTreeSet<String>);
+
+impl ClosureSet for StringSet {
+    fn add_char(&mut self, c: char) {
+        self.0.insert(c.into());
+    }
+    fn add_string(&mut self, string: &str) {
+        self.0.insert(string.into());
+    }
+}
+
+fn closure_of(cm: &CaseMapping, c: char) -> Vec<String> {
+    let mut set = StringSet::default();
+    cm.add_case_closure(c, &mut set);
+    set.0.into_iter().collect()
+}
+
+#[test]
+fn test_case_closure() {
+    let cm = CaseMapping::try_new(&icu_testdata::unstable()).expect("Data exists");
+
+    assert_eq!(closure_of(&cm, 'a'), ["A"]);
+    assert_eq!(closure_of(&cm, 'k'), ["K", "\u{212A}"]);
+    assert_eq!(closure_of(&cm, 'ß'), ["ss", "ẞ"]);
+    assert_eq!(closure_of(&cm, 'σ'), ["Σ", "ς"]);
+    assert_eq!(closure_of(&cm, 'İ'), ["i\u{307}"]);
+    assert!(closure_of(&cm, 'ı').is_empty());
+    assert!(closure_of(&cm, '1').is_empty());
+
+    let mut set = StringSet::default();
+    assert!(cm.add_string_case_closure("ss", &mut set));
+    assert_eq!(set.0.into_iter().collect::<Vec<_>>(), ["ss", "ß", "ẞ"]);
+}
+
+#[test]
+fn test_eq_ignore_case_matches_full_fold() {
+    let cm = CaseMapping::try_new(&icu_testdata::unstable()).expect("Data exists");
+
+    for c in ('\0'..='\u{FFFF}').chain('\u{10000}'..='\u{1FFFF}') {
+        let s = c.to_string();
+        let folded = cm.full_fold(&s);
+        assert!(cm.eq_ignore_case(&s, &folded), "{:?}", c);
+    }
+
+    assert!(cm.eq_ignore_case("", ""));
+    assert!(cm.eq_ignore_case("ﬃ", "FFI"));
+    assert!(!cm.eq_ignore_case("ﬃ", "FF"));
+    assert!(!cm.eq_ignore_case("I", "ı"));
+}
+
+#[test]
+fn test_fold_no_simple_case_folding() {
+    let cm = CaseMapping::try_new(&icu_testdata::unstable()).expect("Data exists");
+
+    // Cherokee folds to uppercase, even though uppercase Cherokee has a lowercase delta.
+    assert_eq!(cm.full_fold("Ꭰꭰ"), "ᎠᎠ");
+    assert_eq!(cm.full_fold("ᏸᏰ"), "ᏰᏰ");
+    assert!(cm.eq_ignore_case("Ꭰ", "ꭰ"));
+}
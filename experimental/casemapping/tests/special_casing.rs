@@ -0,0 +1,208 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#[cfg(feature = "titlecase")]
+use icu_casemapping::titlecase::{LeadingAdjustment, TitlecaseOptions, TrailingCase};
+use icu_casemapping::CaseMapping;
+use icu_locid::Locale;
+#[cfg(feature = "titlecase")]
+use icu_segmenter::WordBreakSegmenter;
+
+fn parse_code_points(field: &str) -> String {
+    field
+        .split_whitespace()
+        .map(|cp| {
+            let cp = u32::from_str_radix(cp, 16).expect("Valid hex");
+            char::from_u32(cp).expect("Valid code point")
+        })
+        .collect()
+}
+
+/// The context in which a conditional mapping applies, as
+/// `(prefix, lowercased prefix, uppercased prefix, suffix)`.
+///
+/// The prefix is mapped differently in the given context than on its own, so the
+/// expected mappings are spelled out. The suffix is not affected by case mapping.
+fn context_for(condition: &str) -> (&'static str, &'static str, &'static str, &'static str) {
+    match condition {
+        "" | "Not_Before_Dot" => ("", "", "", ""),
+        "Final_Sigma" => ("Α", "α", "Α", ""),
+        "More_Above" => ("", "", "", "\u{301}"),
+        "After_Soft_Dotted" => ("i", "i", "I", ""),
+        "After_I" => ("I", "i", "I", ""),
+        _ => panic!("Unknown condition {}", condition),
+    }
+}
+
+#[test]
+fn test_special_casing() {
+    let provider = icu_testdata::unstable();
+    #[cfg(feature = "titlecase")]
+    let segmenter = WordBreakSegmenter::try_new_unstable(&provider).expect("Data exists");
+    #[cfg(feature = "titlecase")]
+    let title_options = {
+        let mut options = TitlecaseOptions::default();
+        options.leading_adjustment = LeadingAdjustment::None;
+        options.trailing_case = TrailingCase::Unchanged;
+        options
+    };
+
+    let data = include_str!("data/SpecialCasing.txt");
+    for line in data.lines() {
+        let line = line
+            .split('#')
+            .next()
+            .expect("split always returns an element");
+        if line.trim().is_empty() {
+            continue;
+        }
+        let fields: Vec<&str> = line.split(';').map(str::trim).collect();
+        let c = parse_code_points(fields[0]);
+        let lower = parse_code_points(fields[1]);
+        #[cfg(feature = "titlecase")]
+        let title = parse_code_points(fields[2]);
+        let upper = parse_code_points(fields[3]);
+
+        let mut conditions = fields[4].split_whitespace().peekable();
+        let locale: Locale = match conditions.next_if(|c| c.chars().all(|c| c.is_lowercase())) {
+            Some(language) => language.parse().expect("Valid language"),
+            None => Locale::UND,
+        };
+        let condition = conditions.next().unwrap_or("");
+        assert_eq!(conditions.next(), None, "Multiple conditions in {}", line);
+
+        let cm = CaseMapping::try_new_with_locale(&provider, &locale).expect("Data exists");
+        let (prefix, prefix_lower, prefix_upper, suffix) = context_for(condition);
+        let input = format!("{}{}{}", prefix, c, suffix);
+
+        assert_eq!(
+            cm.to_full_lowercase(&input),
+            format!("{}{}{}", prefix_lower, lower, suffix),
+            "Lowercase of {:?} in {}",
+            input,
+            locale
+        );
+        assert_eq!(
+            cm.to_full_uppercase(&input),
+            format!("{}{}{}", prefix_upper, upper, suffix),
+            "Uppercase of {:?} in {}",
+            input,
+            locale
+        );
+        // Only the first character of a word is titlecased, so the titlecase
+        // mapping can only be tested without a preceding context.
+        #[cfg(feature = "titlecase")]
+        if prefix.is_empty() {
+            assert_eq!(
+                cm.to_full_titlecase_with_options(&input, &segmenter, title_options),
+                format!("{}{}", title, suffix),
+                "Titlecase of {:?} in {}",
+                input,
+                locale
+            );
+        }
+    }
+}
+
+#[test]
+fn test_conditions_not_met() {
+    let provider = icu_testdata::unstable();
+    let root = CaseMapping::try_new(&provider).expect("Data exists");
+    let lt = CaseMapping::try_new_with_locale(&provider, &"lt".parse().expect("Valid locale"))
+        .expect("Data exists");
+    let tr = CaseMapping::try_new_with_locale(&provider, &"tr".parse().expect("Valid locale"))
+        .expect("Data exists");
+
+    // Final_Sigma: the sigma must be preceded by, and not followed by, a cased letter.
+    assert_eq!(root.to_full_lowercase("Σ"), "σ");
+    assert_eq!(root.to_full_lowercase("ΑΣΑ"), "ασα");
+    assert_eq!(root.to_full_lowercase("ΑΣ'Α"), "ασ'α");
+    assert_eq!(root.to_full_lowercase("ΑΣ'"), "ας'");
+    assert_eq!(root.to_full_lowercase("ΑΣ Α"), "ας α");
+
+    // More_Above: an accent above must follow, possibly after other accents.
+    assert_eq!(lt.to_full_lowercase("I"), "i");
+    assert_eq!(lt.to_full_lowercase("I\u{323}"), "i\u{323}");
+    assert_eq!(
+        lt.to_full_lowercase("I\u{323}\u{301}"),
+        "i\u{307}\u{323}\u{301}"
+    );
+    assert_eq!(lt.to_full_lowercase("IA\u{301}"), "ia\u{301}");
+    assert_eq!(root.to_full_lowercase("I\u{301}"), "i\u{301}");
+
+    // After_Soft_Dotted: the dot is only removed after a soft-dotted letter.
+    assert_eq!(lt.to_full_uppercase("a\u{307}"), "A\u{307}");
+    assert_eq!(lt.to_full_uppercase("i\u{323}\u{307}"), "I\u{323}");
+    assert_eq!(lt.to_full_uppercase("j\u{307}"), "J");
+    assert_eq!(root.to_full_uppercase("i\u{307}"), "I\u{307}");
+
+    // After_I and Not_Before_Dot.
+    assert_eq!(tr.to_full_lowercase("I\u{323}\u{307}"), "i\u{323}");
+    assert_eq!(tr.to_full_lowercase("I\u{323}"), "ı\u{323}");
+    assert_eq!(tr.to_full_lowercase("A\u{307}"), "a\u{307}");
+    assert_eq!(root.to_full_lowercase("I\u{307}"), "i\u{307}");
+}
+
+#[test]
+fn test_greek_upper() {
+    let provider = icu_testdata::unstable();
+    let el = CaseMapping::try_new_with_locale(&provider, &"el".parse().expect("Valid locale"))
+        .expect("Data exists");
+    let root = CaseMapping::try_new(&provider).expect("Data exists");
+
+    let cases = [
+        // Accents are removed.
+        ("άδικος, κείμενο, ίριδα", "ΑΔΙΚΟΣ, ΚΕΙΜΕΝΟ, ΙΡΙΔΑ"),
+        ("Πατάτα", "ΠΑΤΑΤΑ"),
+        ("Αέρας, Μυστήριο, Ωραίο", "ΑΕΡΑΣ, ΜΥΣΤΗΡΙΟ, ΩΡΑΙΟ"),
+        // A dialytika is kept, or added after a vowel whose accent was removed.
+        ("Μαΐου, Πόρος, Ρύθμιση", "ΜΑΪΟΥ, ΠΟΡΟΣ, ΡΥΘΜΙΣΗ"),
+        ("ΰ, Τηρώ, Μάιος", "Ϋ, ΤΗΡΩ, ΜΑΪΟΣ"),
+        ("άυλος", "ΑΫΛΟΣ"),
+        ("ΑΫΛΟΣ", "ΑΫΛΟΣ"),
+        ("α\u{301}ι", "ΑΙ\u{308}"),
+        // The disjunctive eta keeps its tonos.
+        (
+            "Άκλιτα ρήματα ή άκλιτες μετοχές",
+            "ΑΚΛΙΤΑ ΡΗΜΑΤΑ Ή ΑΚΛΙΤΕΣ ΜΕΤΟΧΕΣ",
+        ),
+        (
+            "Επειδή η αναγνώριση της αξιοπρέπειας",
+            "ΕΠΕΙΔΗ Η ΑΝΑΓΝΩΡΙΣΗ ΤΗΣ ΑΞΙΟΠΡΕΠΕΙΑΣ",
+        ),
+        ("νομικού ή διεθνούς", "ΝΟΜΙΚΟΥ Ή ΔΙΕΘΝΟΥΣ"),
+        ("η\u{301} ή", "Η\u{301} Ή"),
+        // Polytonic text loses breathings, and iota subscripts become capital iotas.
+        ("Ἐπειδὴ ἡ ἀναγνώριση", "ΕΠΕΙΔΗ Η ΑΝΑΓΝΩΡΙΣΗ"),
+        ("νομικοῦ ἢ διεθνοῦς", "ΝΟΜΙΚΟΥ Ή ΔΙΕΘΝΟΥΣ"),
+        ("ᾠδή", "ΩΙΔΗ"),
+        ("ᾼ", "ΑΙ"),
+        ("ᾳ\u{301}", "ΑΙ"),
+        // Non-Greek text is uppercased as usual.
+        ("ﬁ straße", "FI STRASSE"),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(
+            el.to_full_uppercase(input),
+            expected,
+            "Uppercase of {}",
+            input
+        );
+    }
+
+    // Other locales keep the accents.
+    assert_eq!(root.to_full_uppercase("άδικος"), "ΆΔΙΚΟΣ");
+    assert_eq!(root.to_full_uppercase("ᾠδή"), "ὨΙΔΉ");
+}
+
+#[test]
+fn test_armenian_ech_yiwn() {
+    let provider = icu_testdata::unstable();
+    let root = CaseMapping::try_new(&provider).expect("Data exists");
+    let hy = CaseMapping::try_new_with_locale(&provider, &"hy".parse().expect("Valid locale"))
+        .expect("Data exists");
+
+    assert_eq!(root.to_full_uppercase("և"), "ԵՒ");
+    assert_eq!(hy.to_full_uppercase("և"), "ԵՎ");
+}
@@ -0,0 +1,124 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_casemapping::titlecase::{LeadingAdjustment, TitlecaseOptions, TrailingCase};
+use icu_casemapping::CaseMapping;
+use icu_locid::locale;
+use icu_segmenter::WordBreakSegmenter;
+
+fn segmenter() -> WordBreakSegmenter {
+    WordBreakSegmenter::try_new_unstable(&icu_testdata::unstable()).expect("Data exists")
+}
+
+#[test]
+fn test_dutch_ij() {
+    let segmenter = segmenter();
+    let nl = CaseMapping::try_new_with_locale(&icu_testdata::unstable(), &locale!("nl"))
+        .expect("Data exists");
+    let root = CaseMapping::try_new(&icu_testdata::unstable()).expect("Data exists");
+
+    assert_eq!(nl.to_full_titlecase("ijssel", &segmenter), "IJssel");
+    assert_eq!(nl.to_full_titlecase("IJSSEL", &segmenter), "IJssel");
+    assert_eq!(nl.to_full_titlecase("iJssel", &segmenter), "IJssel");
+    assert_eq!(
+        nl.to_full_titlecase("het ijsselmeer", &segmenter),
+        "Het IJsselmeer"
+    );
+    // The digraph is only titlecased as a unit at the start of a word.
+    assert_eq!(nl.to_full_titlecase("fijn", &segmenter), "Fijn");
+    assert_eq!(nl.to_full_titlecase("i", &segmenter), "I");
+    assert_eq!(nl.to_full_titlecase("ik", &segmenter), "Ik");
+    // Accents are allowed only if both letters carry one.
+    assert_eq!(
+        nl.to_full_titlecase("i\u{301}j\u{301}s", &segmenter),
+        "I\u{301}J\u{301}s"
+    );
+    assert_eq!(nl.to_full_titlecase("íj́s", &segmenter), "ÍJ́s");
+    assert_eq!(nl.to_full_titlecase("i\u{301}js", &segmenter), "I\u{301}js");
+    assert_eq!(nl.to_full_titlecase("ij\u{301}s", &segmenter), "Ij\u{301}s");
+    // Other languages titlecase only the first letter.
+    assert_eq!(root.to_full_titlecase("ijssel", &segmenter), "Ijssel");
+
+    let mut options = TitlecaseOptions::default();
+    options.trailing_case = TrailingCase::Unchanged;
+    assert_eq!(
+        nl.to_full_titlecase_with_options("ijSSEL", &segmenter, options),
+        "IJSSEL"
+    );
+    options.leading_adjustment = LeadingAdjustment::None;
+    assert_eq!(
+        nl.to_full_titlecase_with_options("1ijssel", &segmenter, options),
+        "1ijssel"
+    );
+}
+
+#[test]
+fn test_leading_adjustment() {
+    let segmenter = segmenter();
+    let cm = CaseMapping::try_new(&icu_testdata::unstable()).expect("Data exists");
+
+    let mut options = TitlecaseOptions::default();
+    assert_eq!(options.leading_adjustment, LeadingAdjustment::ToCased);
+    assert_eq!(
+        cm.to_full_titlecase_with_options("'twas 42nd (hello)", &segmenter, options),
+        "'Twas 42Nd (Hello)"
+    );
+    assert_eq!(
+        cm.to_full_titlecase_with_options("123 ...", &segmenter, options),
+        "123 ..."
+    );
+
+    // Leading punctuation is a separate word, so only "42nd" is affected.
+    options.leading_adjustment = LeadingAdjustment::None;
+    assert_eq!(
+        cm.to_full_titlecase_with_options("'twas 42nd (hello)", &segmenter, options),
+        "'Twas 42nd (Hello)"
+    );
+    // The first character is titlecased even if it is a special mapping.
+    assert_eq!(
+        cm.to_full_titlecase_with_options("ǆ ﬂy", &segmenter, options),
+        "ǅ Fly"
+    );
+}
+
+#[test]
+fn test_trailing_case() {
+    let segmenter = segmenter();
+    let cm = CaseMapping::try_new(&icu_testdata::unstable()).expect("Data exists");
+
+    let mut options = TitlecaseOptions::default();
+    assert_eq!(options.trailing_case, TrailingCase::Lower);
+    assert_eq!(
+        cm.to_full_titlecase_with_options("mcDONALD's IPHONE", &segmenter, options),
+        "Mcdonald's Iphone"
+    );
+    // Lowercasing the rest of a word uses the context of the whole string.
+    assert_eq!(
+        cm.to_full_titlecase_with_options("ΟΔΟΣ", &segmenter, options),
+        "Οδος"
+    );
+
+    options.trailing_case = TrailingCase::Unchanged;
+    assert_eq!(
+        cm.to_full_titlecase_with_options("mcDONALD's IPHONE", &segmenter, options),
+        "McDONALD's IPHONE"
+    );
+    assert_eq!(
+        cm.to_full_titlecase_with_options("ΟΔΟΣ", &segmenter, options),
+        "ΟΔΟΣ"
+    );
+}
+
+#[test]
+fn test_locale_sensitive() {
+    let segmenter = segmenter();
+    let tr = CaseMapping::try_new_with_locale(&icu_testdata::unstable(), &locale!("tr"))
+        .expect("Data exists");
+
+    assert_eq!(
+        tr.to_full_titlecase("istanbul IZMIR", &segmenter),
+        "İstanbul Izmır"
+    );
+    assert_eq!(tr.to_full_titlecase("ıi", &segmenter), "Ii");
+}
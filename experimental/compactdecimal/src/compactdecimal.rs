@@ -0,0 +1,254 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::format::FormattedCompactDecimal;
+use crate::options::CompactDecimalFormatterOptions;
+use crate::provider::*;
+use crate::CompactDecimalError;
+use core::cmp;
+use fixed_decimal::{CompactDecimal, FixedDecimal};
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_decimal::FixedDecimalFormatter;
+use icu_plurals::provider::CardinalV1Marker;
+use icu_plurals::PluralRules;
+use icu_provider::prelude::*;
+use icu_provider::zerofrom::ZeroFrom;
+
+/// A formatter that renders locale-sensitive compact numbers, such as "1.2K" or
+/// "1.2 thousand". See the [crate-level documentation](crate) for more details.
+pub struct CompactDecimalFormatter {
+    pub(crate) fixed_decimal_formatter: FixedDecimalFormatter,
+    pub(crate) plural_rules: PluralRules,
+    compact_data: DataPayload<ErasedCompactDecimalFormatDataV1Marker>,
+}
+
+impl CompactDecimalFormatter {
+    /// Creates a new [`CompactDecimalFormatter`] using the short compact decimal format,
+    /// such as "1.2M".
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// use icu_compactdecimal::CompactDecimalFormatter;
+    /// use icu_locid::locale;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let short_english = CompactDecimalFormatter::try_new_short_unstable(
+    ///     &icu_testdata::unstable(),
+    ///     &locale!("en").into(),
+    ///     Default::default(),
+    /// )
+    /// .expect("Data should load successfully");
+    ///
+    /// assert_writeable_eq!(
+    ///     short_english.format_fixed_decimal(FixedDecimal::from(2207722)),
+    ///     "2.2M"
+    /// );
+    /// ```
+    pub fn try_new_short_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        options: CompactDecimalFormatterOptions,
+    ) -> Result<Self, CompactDecimalError>
+    where
+        D: DataProvider<ShortCompactDecimalFormatDataV1Marker>
+            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<CardinalV1Marker>
+            + ?Sized,
+    {
+        Ok(Self {
+            fixed_decimal_formatter: FixedDecimalFormatter::try_new_unstable(
+                data_provider,
+                locale,
+                options.fixed_decimal_formatter_options,
+            )?,
+            plural_rules: PluralRules::try_new_cardinal_unstable(data_provider, locale)?,
+            compact_data: DataProvider::<ShortCompactDecimalFormatDataV1Marker>::load(
+                data_provider,
+                DataRequest {
+                    locale,
+                    metadata: Default::default(),
+                },
+            )?
+            .take_payload()?
+            .cast(),
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        options: CompactDecimalFormatterOptions,
+        error: CompactDecimalError,
+        functions: [
+            Self::try_new_short_unstable,
+            try_new_short_with_any_provider,
+            try_new_short_with_buffer_provider
+        ]
+    );
+
+    /// Creates a new [`CompactDecimalFormatter`] using the long compact decimal format,
+    /// such as "1.2 million".
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// use icu_compactdecimal::CompactDecimalFormatter;
+    /// use icu_locid::locale;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let long_english = CompactDecimalFormatter::try_new_long_unstable(
+    ///     &icu_testdata::unstable(),
+    ///     &locale!("en").into(),
+    ///     Default::default(),
+    /// )
+    /// .expect("Data should load successfully");
+    ///
+    /// assert_writeable_eq!(
+    ///     long_english.format_fixed_decimal(FixedDecimal::from(2207722)),
+    ///     "2.2 million"
+    /// );
+    /// ```
+    pub fn try_new_long_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        options: CompactDecimalFormatterOptions,
+    ) -> Result<Self, CompactDecimalError>
+    where
+        D: DataProvider<LongCompactDecimalFormatDataV1Marker>
+            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<CardinalV1Marker>
+            + ?Sized,
+    {
+        Ok(Self {
+            fixed_decimal_formatter: FixedDecimalFormatter::try_new_unstable(
+                data_provider,
+                locale,
+                options.fixed_decimal_formatter_options,
+            )?,
+            plural_rules: PluralRules::try_new_cardinal_unstable(data_provider, locale)?,
+            compact_data: DataProvider::<LongCompactDecimalFormatDataV1Marker>::load(
+                data_provider,
+                DataRequest {
+                    locale,
+                    metadata: Default::default(),
+                },
+            )?
+            .take_payload()?
+            .cast(),
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        options: CompactDecimalFormatterOptions,
+        error: CompactDecimalError,
+        functions: [
+            Self::try_new_long_unstable,
+            try_new_long_with_any_provider,
+            try_new_long_with_buffer_provider
+        ]
+    );
+
+    /// Formats a [`FixedDecimal`] in compact notation, rounding it as needed.
+    ///
+    /// Numbers are rounded to an integer, except that at least two significant digits are
+    /// retained, following the CLDR recommendation: 1234 is formatted as "1.2K", and 12345 as
+    /// "12K". Trailing zeros in the fraction are removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// use icu_compactdecimal::CompactDecimalFormatter;
+    /// use icu_locid::locale;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let short_english = CompactDecimalFormatter::try_new_short_unstable(
+    ///     &icu_testdata::unstable(),
+    ///     &locale!("en").into(),
+    ///     Default::default(),
+    /// )
+    /// .expect("Data should load successfully");
+    ///
+    /// assert_writeable_eq!(short_english.format_fixed_decimal(FixedDecimal::from(0)), "0");
+    /// assert_writeable_eq!(short_english.format_fixed_decimal(FixedDecimal::from(2)), "2");
+    /// assert_writeable_eq!(short_english.format_fixed_decimal(FixedDecimal::from(843)), "843");
+    /// assert_writeable_eq!(short_english.format_fixed_decimal(FixedDecimal::from(2207)), "2.2K");
+    /// assert_writeable_eq!(short_english.format_fixed_decimal(FixedDecimal::from(15127)), "15K");
+    /// assert_writeable_eq!(short_english.format_fixed_decimal(FixedDecimal::from(999999)), "1M");
+    /// assert_writeable_eq!(
+    ///     short_english.format_fixed_decimal(FixedDecimal::from(-3_500_000)),
+    ///     "-3.5M"
+    /// );
+    /// assert_writeable_eq!(
+    ///     short_english.format_fixed_decimal(FixedDecimal::from(12345).multiplied_pow10(-3)),
+    ///     "12"
+    /// );
+    /// ```
+    pub fn format_fixed_decimal(&self, value: FixedDecimal) -> FormattedCompactDecimal<'_> {
+        let log10_type = value.nonzero_magnitude_start();
+        let (mut key, mut exponent) = self.key_and_exponent_for_magnitude(log10_type);
+        let mut significand = value.multiplied_pow10(-exponent);
+        // Keep at least two significant digits, and round away any other fraction digits.
+        significand.half_even(cmp::min(0, significand.nonzero_magnitude_start() - 1));
+        significand.trim_end();
+        let rounded_log10_type = significand.nonzero_magnitude_start() + exponent;
+        if rounded_log10_type != log10_type {
+            // Rounding carried into a higher magnitude, such as 999999 to 1000K; the higher
+            // magnitude may use a different pattern, such as 1M.
+            let (rounded_key, rounded_exponent) =
+                self.key_and_exponent_for_magnitude(rounded_log10_type);
+            significand.multiply_pow10(exponent - rounded_exponent);
+            significand.trim_end();
+            key = rounded_key;
+            exponent = rounded_exponent;
+        }
+        let value = CompactDecimal::from_significand_and_exponent(significand, exponent as u8);
+        let pattern = key.and_then(|key| {
+            let patterns = &self.compact_data.get().patterns;
+            let explicit_one = if value.significand() == &FixedDecimal::from(1) {
+                patterns.get_2d(&key, &Count::Explicit1)
+            } else {
+                None
+            };
+            explicit_one
+                .or_else(|| patterns.get_2d(&key, &self.plural_rules.category_for(&value).into()))
+                .or_else(|| patterns.get_2d(&key, &Count::Other))
+                .map(Pattern::zero_from)
+        });
+        FormattedCompactDecimal {
+            formatter: self,
+            value,
+            pattern,
+        }
+    }
+
+    /// Returns the key of the patterns for the given magnitude, if any, and the exponent of those
+    /// patterns, which is 0 if the magnitude is not formatted in compact notation.
+    fn key_and_exponent_for_magnitude(&self, magnitude: i16) -> (Option<i8>, i16) {
+        let patterns = &self.compact_data.get().patterns;
+        let key = patterns
+            .iter0()
+            .map(|cursor| *cursor.key0())
+            .filter(|&key| i16::from(key) <= magnitude)
+            .last();
+        let exponent = key
+            .and_then(|key| patterns.get_2d(&key, &Count::Other))
+            .map(|pattern| i16::from(pattern.exponent))
+            .unwrap_or(0);
+        (key, exponent)
+    }
+}
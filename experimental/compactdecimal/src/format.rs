@@ -0,0 +1,94 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::provider::Pattern;
+use crate::CompactDecimalFormatter;
+use core::fmt::{self, Write};
+use fixed_decimal::CompactDecimal;
+use writeable::{PartsWrite, Writeable};
+
+/// The [`Part`](writeable::Part)s used by [`CompactDecimalFormatter`].
+pub mod parts {
+    use writeable::Part;
+
+    /// The [`Part`] used by [`FormattedCompactDecimal`](super::FormattedCompactDecimal) to mark
+    /// the text of the compact pattern surrounding the number, such as "K" in "1.2K".
+    pub const LITERAL: Part = Part {
+        category: "compactdecimal",
+        value: "literal",
+    };
+}
+
+/// The [`Writeable`] implementation that is returned by
+/// [`CompactDecimalFormatter::format_fixed_decimal`]. See the [`writeable`] crate for how to
+/// consume this.
+pub struct FormattedCompactDecimal<'l> {
+    pub(crate) formatter: &'l CompactDecimalFormatter,
+    pub(crate) value: CompactDecimal,
+    pub(crate) pattern: Option<Pattern<'l>>,
+}
+
+impl<'l> FormattedCompactDecimal<'l> {
+    /// Returns the rounded number that is formatted, with its compact exponent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// use icu_compactdecimal::CompactDecimalFormatter;
+    /// use icu_locid::locale;
+    ///
+    /// let short_english = CompactDecimalFormatter::try_new_short_unstable(
+    ///     &icu_testdata::unstable(),
+    ///     &locale!("en").into(),
+    ///     Default::default(),
+    /// )
+    /// .expect("Data should load successfully");
+    ///
+    /// let formatted = short_english.format_fixed_decimal(FixedDecimal::from(2207722));
+    /// assert_eq!(formatted.get_compact_decimal().to_string(), "2.2c6");
+    /// ```
+    pub fn get_compact_decimal(&self) -> &CompactDecimal {
+        &self.value
+    }
+}
+
+impl<'l> Writeable for FormattedCompactDecimal<'l> {
+    fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        let number = self
+            .formatter
+            .fixed_decimal_formatter
+            .format(self.value.significand());
+        match &self.pattern {
+            None => number.write_to_parts(sink),
+            Some(pattern) if pattern.index == Pattern::NO_PLACEHOLDER => {
+                sink.with_part(parts::LITERAL, |s| s.write_str(&pattern.literal_text))
+            }
+            Some(pattern) => {
+                let index = pattern.index as usize;
+                match pattern
+                    .literal_text
+                    .get(..index)
+                    .zip(pattern.literal_text.get(index..))
+                {
+                    Some((before, after)) => {
+                        if !before.is_empty() {
+                            sink.with_part(parts::LITERAL, |s| s.write_str(before))?;
+                        }
+                        number.write_to_parts(sink)?;
+                        if !after.is_empty() {
+                            sink.with_part(parts::LITERAL, |s| s.write_str(after))?;
+                        }
+                        Ok(())
+                    }
+                    // The index is not on a character boundary; this only happens with invalid
+                    // data, so fall back to the plain number.
+                    None => number.write_to_parts(sink),
+                }
+            }
+        }
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedCompactDecimal<'_>);
@@ -0,0 +1,120 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! 🚧 \[Experimental\] Formatting numbers in compact notation, such as "1.2K" or "1.2 thousand".
+//!
+//! This module is published as its own crate ([`icu_compactdecimal`](https://docs.rs/icu_compactdecimal/latest/icu_compactdecimal/))
+//! and as part of the [`icu`](https://docs.rs/icu/latest/icu/) crate. See the latter for more details on the ICU4X project.
+//!
+//! <div class="stab unstable">
+//! 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+//! including in SemVer minor releases. It can be enabled with the "experimental" feature
+//! of the icu meta-crate. Use with caution.
+//! </div>
+//!
+//! # Examples
+//!
+//! ## Short compact notation in English
+//!
+//! ```
+//! use fixed_decimal::FixedDecimal;
+//! use icu_compactdecimal::CompactDecimalFormatter;
+//! use icu_locid::locale;
+//! use writeable::assert_writeable_eq;
+//!
+//! let short_english = CompactDecimalFormatter::try_new_short_unstable(
+//!     &icu_testdata::unstable(),
+//!     &locale!("en").into(),
+//!     Default::default(),
+//! )
+//! .expect("Data should load successfully");
+//!
+//! assert_writeable_eq!(short_english.format_fixed_decimal(FixedDecimal::from(1234)), "1.2K");
+//! assert_writeable_eq!(short_english.format_fixed_decimal(FixedDecimal::from(-98765)), "-99K");
+//! assert_writeable_eq!(
+//!     short_english.format_fixed_decimal(FixedDecimal::from(5_000_000_000_i64)),
+//!     "5B"
+//! );
+//! ```
+//!
+//! ## Long compact notation in French
+//!
+//! The pattern depends on the plural category of the number, and some languages have a
+//! dedicated form for exactly one of a unit.
+//!
+//! ```
+//! use fixed_decimal::FixedDecimal;
+//! use icu_compactdecimal::CompactDecimalFormatter;
+//! use icu_locid::locale;
+//! use writeable::assert_writeable_eq;
+//!
+//! let long_french = CompactDecimalFormatter::try_new_long_unstable(
+//!     &icu_testdata::unstable(),
+//!     &locale!("fr").into(),
+//!     Default::default(),
+//! )
+//! .expect("Data should load successfully");
+//!
+//! assert_writeable_eq!(long_french.format_fixed_decimal(FixedDecimal::from(1000)), "mille");
+//! assert_writeable_eq!(long_french.format_fixed_decimal(FixedDecimal::from(1500)), "1,5 millier");
+//! assert_writeable_eq!(long_french.format_fixed_decimal(FixedDecimal::from(2000)), "2 mille");
+//! assert_writeable_eq!(
+//!     long_french.format_fixed_decimal(FixedDecimal::from(3_000_000)),
+//!     "3 millions"
+//! );
+//! ```
+//!
+//! ## Compact notation with a different magnitude
+//!
+//! Not all locales abbreviate every power of 1000. Japanese abbreviates powers of 10⁴.
+//!
+//! ```
+//! use fixed_decimal::FixedDecimal;
+//! use icu_compactdecimal::CompactDecimalFormatter;
+//! use icu_locid::locale;
+//! use writeable::assert_writeable_eq;
+//!
+//! let short_japanese = CompactDecimalFormatter::try_new_short_unstable(
+//!     &icu_testdata::unstable(),
+//!     &locale!("ja").into(),
+//!     Default::default(),
+//! )
+//! .expect("Data should load successfully");
+//!
+//! assert_writeable_eq!(short_japanese.format_fixed_decimal(FixedDecimal::from(1234)), "1234");
+//! assert_writeable_eq!(short_japanese.format_fixed_decimal(FixedDecimal::from(12345)), "1.2万");
+//! assert_writeable_eq!(
+//!     short_japanese.format_fixed_decimal(FixedDecimal::from(123_456_789)),
+//!     "1.2億"
+//! );
+//! ```
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(
+    not(test),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::exhaustive_structs,
+        clippy::exhaustive_enums,
+    )
+)]
+#![warn(missing_docs)]
+
+extern crate alloc;
+
+mod compactdecimal;
+mod error;
+mod format;
+pub mod options;
+pub mod provider;
+
+pub use compactdecimal::CompactDecimalFormatter;
+pub use error::CompactDecimalError;
+pub use format::{parts, FormattedCompactDecimal};
+
+#[doc(inline)]
+pub use CompactDecimalError as Error;
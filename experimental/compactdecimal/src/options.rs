@@ -0,0 +1,39 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for [`CompactDecimalFormatter`](crate::CompactDecimalFormatter).
+
+use icu_decimal::options::{FixedDecimalFormatterOptions, GroupingStrategy};
+
+/// A bag of options defining how numbers will be formatted by
+/// [`CompactDecimalFormatter`](crate::CompactDecimalFormatter).
+///
+/// Unlike for [`FixedDecimalFormatter`](icu_decimal::FixedDecimalFormatter), the default
+/// grouping strategy is [`GroupingStrategy::Min2`], so that numbers that are not abbreviated,
+/// such as 1234 in Japanese, are not grouped.
+#[derive(Debug, Eq, PartialEq, Clone)]
+#[non_exhaustive]
+pub struct CompactDecimalFormatterOptions {
+    /// Options to configure the inner [`FixedDecimalFormatter`](icu_decimal::FixedDecimalFormatter),
+    /// which renders the significand of the compact number, such as "1.2" in "1.2K".
+    pub fixed_decimal_formatter_options: FixedDecimalFormatterOptions,
+}
+
+impl Default for CompactDecimalFormatterOptions {
+    fn default() -> Self {
+        let mut fixed_decimal_formatter_options = FixedDecimalFormatterOptions::default();
+        fixed_decimal_formatter_options.grouping_strategy = GroupingStrategy::Min2;
+        Self {
+            fixed_decimal_formatter_options,
+        }
+    }
+}
+
+impl From<FixedDecimalFormatterOptions> for CompactDecimalFormatterOptions {
+    fn from(fixed_decimal_formatter_options: FixedDecimalFormatterOptions) -> Self {
+        Self {
+            fixed_decimal_formatter_options,
+        }
+    }
+}
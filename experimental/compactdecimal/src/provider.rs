@@ -0,0 +1,133 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// Provider structs must be stable
+#![allow(clippy::exhaustive_structs, clippy::exhaustive_enums)]
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use alloc::borrow::Cow;
+use icu_plurals::PluralCategory;
+use icu_provider::prelude::*;
+use zerovec::ZeroMap2d;
+
+/// Compact decimal patterns for a single length, such as "1.2K" or "1.2 thousand".
+///
+/// The patterns are keyed by the magnitude of the number (the logarithm of the CLDR `type`
+/// attribute, such as 3 for "1000") and by the plural [`Count`]. A pattern applies to all
+/// numbers whose magnitude is greater than or equal to its key, up to the next key.
+///
+/// Plural forms other than [`Count::Other`] are omitted when they are identical to
+/// [`Count::Other`], and magnitudes are omitted when all their patterns are identical to those of
+/// the next smaller magnitude.
+#[icu_provider::data_struct(
+    marker(
+        ShortCompactDecimalFormatDataV1Marker,
+        "compactdecimal/short@1",
+        extension_key = "nu"
+    ),
+    marker(
+        LongCompactDecimalFormatDataV1Marker,
+        "compactdecimal/long@1",
+        extension_key = "nu"
+    )
+)]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_compactdecimal::provider),
+)]
+#[yoke(prove_covariance_manually)]
+pub struct CompactDecimalPatternDataV1<'data> {
+    /// A map keyed on the magnitude and on the plural [`Count`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub patterns: ZeroMap2d<'data, i8, Count, PatternULE>,
+}
+
+pub(crate) struct ErasedCompactDecimalFormatDataV1Marker;
+
+impl DataMarker for ErasedCompactDecimalFormatDataV1Marker {
+    type Yokeable = CompactDecimalPatternDataV1<'static>;
+}
+
+/// A CLDR plural keyword, or the explicit value 1.
+///
+/// See <https://www.unicode.org/reports/tr35/tr35-numbers.html#Compact_Number_Formats>.
+#[derive(Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_compactdecimal::provider),
+)]
+#[repr(u8)]
+#[zerovec::make_ule(CountULE)]
+pub enum Count {
+    /// The CLDR keyword `zero`.
+    Zero = 0,
+    /// The CLDR keyword `one`.
+    One = 1,
+    /// The CLDR keyword `two`.
+    Two = 2,
+    /// The CLDR keyword `few`.
+    Few = 3,
+    /// The CLDR keyword `many`.
+    Many = 4,
+    /// The CLDR keyword `other`.
+    Other = 5,
+    /// The explicit 1 case, used when the number is exactly 1, such as "mille" in French.
+    Explicit1 = 6,
+}
+
+impl From<PluralCategory> for Count {
+    fn from(category: PluralCategory) -> Self {
+        match category {
+            PluralCategory::Zero => Count::Zero,
+            PluralCategory::One => Count::One,
+            PluralCategory::Two => Count::Two,
+            PluralCategory::Few => Count::Few,
+            PluralCategory::Many => Count::Many,
+            PluralCategory::Other => Count::Other,
+        }
+    }
+}
+
+/// A compact decimal pattern, representing some literal text with an optional placeholder, and
+/// the power of 10 expressed by the text.
+#[zerovec::make_varule(PatternULE)]
+#[zerovec::derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize),
+    zerovec::derive(Deserialize)
+)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    zerovec::derive(Serialize),
+    databake(path = icu_compactdecimal::provider),
+)]
+pub struct Pattern<'data> {
+    /// The compact decimal exponent, such as 6 for "million".
+    ///
+    /// The value 0 indicates that compact notation is not applied; in that case `literal_text`
+    /// is empty.
+    pub exponent: i8,
+    /// The byte index in `literal_text` at which the number is inserted, or
+    /// [`Pattern::NO_PLACEHOLDER`] if the pattern does not contain the number, such as "mille".
+    pub index: u8,
+    /// The pattern with the placeholder removed, such as " thousand" for "0 thousand".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub literal_text: Cow<'data, str>,
+}
+
+impl Pattern<'_> {
+    /// The `index` of a pattern without a placeholder.
+    pub const NO_PLACEHOLDER: u8 = u8::MAX;
+}
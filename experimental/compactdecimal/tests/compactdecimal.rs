@@ -0,0 +1,114 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::FixedDecimal;
+use icu_compactdecimal::CompactDecimalFormatter;
+use icu_locid::locale;
+use std::str::FromStr;
+use writeable::assert_writeable_eq;
+
+#[test]
+fn test_short() {
+    let cases = [
+        (locale!("en"), "0", "0"),
+        (locale!("en"), "999", "999"),
+        (locale!("en"), "1000", "1K"),
+        (locale!("en"), "1049", "1K"),
+        (locale!("en"), "1050", "1K"),
+        (locale!("en"), "1150", "1.2K"),
+        (locale!("en"), "1499", "1.5K"),
+        (locale!("en"), "9999", "10K"),
+        (locale!("en"), "99999", "100K"),
+        (locale!("en"), "999499", "999K"),
+        (locale!("en"), "999500", "1M"),
+        (locale!("en"), "1000000000", "1B"),
+        (locale!("en"), "123456789012", "123B"),
+        (locale!("en"), "1000000000000", "1T"),
+        // There are no patterns beyond trillions
+        (locale!("en"), "1000000000000000", "1000T"),
+        (locale!("en"), "-1234.5", "-1.2K"),
+        (locale!("en"), "-999", "-999"),
+        // Small numbers keep two significant digits
+        (locale!("en"), "0.5", "0.5"),
+        (locale!("en"), "1.25", "1.2"),
+        (locale!("en"), "1.35", "1.4"),
+        (locale!("en"), "12.5", "12"),
+        (locale!("en"), "1.50", "1.5"),
+        (locale!("en"), "-0.012", "-0.012"),
+        // Japanese groups by myriads
+        (locale!("ja"), "9999", "9999"),
+        (locale!("ja"), "12345", "1.2万"),
+        (locale!("ja"), "123456789", "1.2億"),
+        (locale!("es"), "1234", "1,2\u{a0}mil"),
+        (locale!("es"), "1234567", "1,2\u{a0}M"),
+        (locale!("ar"), "1234", "١٫٢\u{a0}ألف"),
+        (locale!("ar-u-nu-latn"), "1234", "1.2\u{a0}ألف"),
+    ];
+    for (locale, input, expected) in cases {
+        let formatter = CompactDecimalFormatter::try_new_short_unstable(
+            &icu_testdata::unstable(),
+            &(&locale).into(),
+            Default::default(),
+        )
+        .unwrap();
+        assert_writeable_eq!(
+            formatter.format_fixed_decimal(FixedDecimal::from_str(input).unwrap()),
+            expected,
+            "{}: {}",
+            locale,
+            input
+        );
+    }
+}
+
+#[test]
+fn test_long() {
+    let cases = [
+        (locale!("en"), "1000", "1 thousand"),
+        (locale!("en"), "1500000", "1.5 million"),
+        (locale!("en"), "999950", "1 million"),
+        (locale!("ru"), "1000", "1 тысяча"),
+        (locale!("ru"), "2000", "2 тысячи"),
+        (locale!("ru"), "5000", "5 тысяч"),
+        (locale!("ru"), "1500", "1,5 тысячи"),
+        (locale!("ru"), "21000000", "21 миллион"),
+        // French has a pattern for exactly 1
+        (locale!("fr"), "1000", "mille"),
+        (locale!("fr"), "1100", "1,1 millier"),
+        (locale!("fr"), "3000000", "3 millions"),
+    ];
+    for (locale, input, expected) in cases {
+        let formatter = CompactDecimalFormatter::try_new_long_unstable(
+            &icu_testdata::unstable(),
+            &(&locale).into(),
+            Default::default(),
+        )
+        .unwrap();
+        assert_writeable_eq!(
+            formatter.format_fixed_decimal(FixedDecimal::from_str(input).unwrap()),
+            expected,
+            "{}: {}",
+            locale,
+            input
+        );
+    }
+}
+
+#[test]
+fn test_compact_decimal() {
+    let formatter = CompactDecimalFormatter::try_new_short_unstable(
+        &icu_testdata::unstable(),
+        &locale!("en").into(),
+        Default::default(),
+    )
+    .unwrap();
+
+    let formatted = formatter.format_fixed_decimal(FixedDecimal::from(1234567));
+    assert_eq!(formatted.get_compact_decimal().to_string(), "1.2c6");
+    let formatted = formatter.format_fixed_decimal(FixedDecimal::from(999));
+    assert_eq!(formatted.get_compact_decimal().to_string(), "999");
+    // 999,999 rounds to 1000K, which uses the pattern of the millions
+    let formatted = formatter.format_fixed_decimal(FixedDecimal::from(999999));
+    assert_eq!(formatted.get_compact_decimal().to_string(), "1c6");
+}
@@ -0,0 +1,284 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::format::FormattedCurrency;
+use crate::options::*;
+use crate::provider::*;
+use crate::CurrencyError;
+use fixed_decimal::{FixedDecimal, Sign};
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_decimal::FixedDecimalFormatter;
+use icu_plurals::provider::CardinalV1Marker;
+use icu_plurals::PluralRules;
+use icu_provider::prelude::*;
+use tinystr::TinyAsciiStr;
+
+/// A formatter that renders amounts of money in a locale-sensitive way, such as "$3.50" or
+/// "3,50 €". See the [crate-level documentation](crate) for more details.
+pub struct CurrencyFormatter {
+    pub(crate) fixed_decimal_formatter: FixedDecimalFormatter,
+    plural_rules: PluralRules,
+    pub(crate) essentials: DataPayload<CurrencyEssentialsV1Marker>,
+    names: Option<DataPayload<CurrencyNamesV1Marker>>,
+    fractions: DataPayload<CurrencyFractionsV1Marker>,
+    options: CurrencyFormatterOptions,
+}
+
+impl CurrencyFormatter {
+    /// Creates a new [`CurrencyFormatter`] from locale data and an options bag.
+    ///
+    /// The currency names are only loaded if [`CurrencyDisplay::Name`] is requested.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// use icu_currency::options::CurrencyDisplay;
+    /// use icu_currency::CurrencyFormatter;
+    /// use icu_locid::locale;
+    /// use tinystr::tinystr;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let formatter = CurrencyFormatter::try_new_unstable(
+    ///     &icu_testdata::unstable(),
+    ///     &locale!("en").into(),
+    ///     CurrencyDisplay::Name.into(),
+    /// )
+    /// .expect("Data should load successfully");
+    ///
+    /// assert_writeable_eq!(
+    ///     formatter.format(FixedDecimal::from(1), tinystr!(3, "JPY")),
+    ///     "1 Japanese yen"
+    /// );
+    /// assert_writeable_eq!(
+    ///     formatter.format(FixedDecimal::from(1), tinystr!(3, "USD")),
+    ///     "1.00 US dollars"
+    /// );
+    /// ```
+    pub fn try_new_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        options: CurrencyFormatterOptions,
+    ) -> Result<Self, CurrencyError>
+    where
+        D: DataProvider<CurrencyEssentialsV1Marker>
+            + DataProvider<CurrencyNamesV1Marker>
+            + DataProvider<CurrencyFractionsV1Marker>
+            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<CardinalV1Marker>
+            + ?Sized,
+    {
+        let fixed_decimal_formatter = FixedDecimalFormatter::try_new_unstable(
+            data_provider,
+            locale,
+            options.fixed_decimal_formatter_options.clone(),
+        )?;
+        let plural_rules = PluralRules::try_new_cardinal_unstable(data_provider, locale)?;
+        let essentials = data_provider
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+        let names = if options.display == CurrencyDisplay::Name {
+            Some(
+                data_provider
+                    .load(DataRequest {
+                        locale,
+                        metadata: Default::default(),
+                    })?
+                    .take_payload()?,
+            )
+        } else {
+            None
+        };
+        let fractions = data_provider
+            .load(DataRequest {
+                locale: &Default::default(),
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+        Ok(Self {
+            fixed_decimal_formatter,
+            plural_rules,
+            essentials,
+            names,
+            fractions,
+            options,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        options: CurrencyFormatterOptions,
+        error: CurrencyError
+    );
+
+    /// Formats an amount of the currency with the given ISO 4217 code.
+    ///
+    /// The amount is rounded to the number of fraction digits of the currency, using the
+    /// rounding increment for cash transactions if [`CurrencyUsage::Cash`] is requested.
+    /// Currencies without data in the locale are displayed with their code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// use icu_currency::options::{CurrencyFormatterOptions, CurrencySign, CurrencyUsage};
+    /// use icu_currency::CurrencyFormatter;
+    /// use icu_locid::locale;
+    /// use tinystr::tinystr;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let formatter = CurrencyFormatter::try_new_unstable(
+    ///     &icu_testdata::unstable(),
+    ///     &locale!("en").into(),
+    ///     Default::default(),
+    /// )
+    /// .expect("Data should load successfully");
+    ///
+    /// let amount = FixedDecimal::from(-123456).multiplied_pow10(-3);
+    /// assert_writeable_eq!(formatter.format(amount.clone(), tinystr!(3, "USD")), "-$123.46");
+    /// assert_writeable_eq!(formatter.format(amount.clone(), tinystr!(3, "JPY")), "-¥123");
+    /// assert_writeable_eq!(
+    ///     formatter.format(amount.clone(), tinystr!(3, "KWD")),
+    ///     "-KWD\u{a0}123.456"
+    /// );
+    /// assert_writeable_eq!(
+    ///     formatter.format(amount.clone(), tinystr!(3, "CHF")),
+    ///     "-CHF\u{a0}123.46"
+    /// );
+    ///
+    /// let mut options = CurrencyFormatterOptions::default();
+    /// options.sign = CurrencySign::Accounting;
+    /// options.usage = CurrencyUsage::Cash;
+    /// let formatter = CurrencyFormatter::try_new_unstable(
+    ///     &icu_testdata::unstable(),
+    ///     &locale!("en").into(),
+    ///     options,
+    /// )
+    /// .expect("Data should load successfully");
+    ///
+    /// assert_writeable_eq!(
+    ///     formatter.format(amount, tinystr!(3, "CHF")),
+    ///     "(CHF\u{a0}123.45)"
+    /// );
+    /// assert_writeable_eq!(
+    ///     formatter.format(FixedDecimal::from(975).multiplied_pow10(-3), tinystr!(3, "CHF")),
+    ///     "CHF\u{a0}1.00"
+    /// );
+    /// ```
+    pub fn format(
+        &self,
+        mut value: FixedDecimal,
+        currency: TinyAsciiStr<3>,
+    ) -> FormattedCurrency<'_> {
+        let info = self.fractions.get().get(currency);
+        let (digits, increment) = match self.options.usage {
+            CurrencyUsage::Cash => (info.cash_digits, info.cash_rounding),
+            CurrencyUsage::Standard => (info.digits, info.rounding),
+        };
+        round_to_increment(&mut value, -i16::from(digits), increment);
+        value.pad_end(-i16::from(digits));
+
+        if self.options.display == CurrencyDisplay::Name {
+            let category = self.plural_rules.category_for(&value);
+            if let Some((pattern, name)) = self
+                .names
+                .as_ref()
+                .and_then(|names| names.get().get(category, currency))
+            {
+                return FormattedCurrency {
+                    formatter: self,
+                    value,
+                    pattern,
+                    currency,
+                    currency_text: Some(name),
+                    spacing: false,
+                };
+            }
+        }
+
+        let essentials = self.essentials.get();
+        let patterns = match self.options.sign {
+            CurrencySign::Accounting if self.options.display != CurrencyDisplay::Name => {
+                &essentials.accounting
+            }
+            _ => &essentials.standard,
+        };
+        let currency_text = match self.options.display {
+            CurrencyDisplay::Symbol => essentials.symbols.get(&currency),
+            CurrencyDisplay::NarrowSymbol => essentials
+                .narrow_symbols
+                .get(&currency)
+                .or_else(|| essentials.symbols.get(&currency)),
+            // Currencies without a name are displayed with their code.
+            CurrencyDisplay::IsoCode | CurrencyDisplay::Name => None,
+        };
+        let pattern = if value.sign() == Sign::Negative {
+            value.set_sign(Sign::None);
+            &patterns.negative
+        } else {
+            &patterns.positive
+        };
+        FormattedCurrency {
+            formatter: self,
+            value,
+            pattern,
+            currency,
+            currency_text,
+            spacing: true,
+        }
+    }
+}
+
+/// Rounds `value` half-even to a multiple of `increment` at the given position.
+///
+/// Increments of 0 and 1 round to the position; increments with other digits than a single 1 or
+/// 5 followed by zeros, which CLDR does not use, are also treated as 1.
+fn round_to_increment(value: &mut FixedDecimal, mut position: i16, mut increment: u8) {
+    while increment >= 10 && increment % 10 == 0 {
+        increment /= 10;
+        position += 1;
+    }
+    if increment != 5 {
+        value.half_even(position);
+        return;
+    }
+
+    let digit = value.digit_at(position);
+    let offset = digit % 5;
+    let round_up = match offset {
+        0 | 1 => false,
+        3 | 4 => true,
+        _ => match value.digit_at(position - 1) {
+            0..=4 => false,
+            6..=9 => true,
+            // More than halfway to the next multiple.
+            _ if value.nonzero_magnitude_end() < position - 1 => true,
+            // Exactly halfway; round to the even multiple of 5 at this position.
+            _ => digit >= 5,
+        },
+    };
+
+    // The digits above the position, with the digit at the position set to 0 or 5. The
+    // concatenations cannot fail, as `rounded` has no digits at or below the position.
+    let mut rounded = value.clone();
+    rounded.trunc(position + 1);
+    let base = digit - offset;
+    if round_up && base == 5 {
+        // Add 1 at the position, then carry it into the next position.
+        let _ = rounded.concatenate_end(FixedDecimal::from(1).multiplied_pow10(position));
+        rounded.expand(position + 1);
+    } else {
+        let multiple = if round_up { 5 } else { base };
+        let _ = rounded.concatenate_end(FixedDecimal::from(multiple).multiplied_pow10(position));
+    }
+    *value = rounded;
+}
@@ -0,0 +1,101 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::provider::CurrencyPattern;
+use crate::CurrencyFormatter;
+use core::fmt::{self, Write};
+use fixed_decimal::FixedDecimal;
+use tinystr::TinyAsciiStr;
+use writeable::{PartsWrite, Writeable};
+
+/// The [`Part`](writeable::Part)s used by [`CurrencyFormatter`].
+pub mod parts {
+    use writeable::Part;
+
+    /// The [`Part`] used by [`FormattedCurrency`](super::FormattedCurrency) to mark the
+    /// currency symbol, code, or name, such as "$" in "$3.00".
+    pub const CURRENCY: Part = Part {
+        category: "currency",
+        value: "currency",
+    };
+
+    /// The [`Part`] used by [`FormattedCurrency`](super::FormattedCurrency) to mark the text of
+    /// the pattern surrounding the number and the currency, such as the parentheses in "($3.00)".
+    pub const LITERAL: Part = Part {
+        category: "currency",
+        value: "literal",
+    };
+}
+
+/// The [`Writeable`] implementation that is returned by [`CurrencyFormatter::format`]. See the
+/// [`writeable`] crate for how to consume this.
+pub struct FormattedCurrency<'l> {
+    pub(crate) formatter: &'l CurrencyFormatter,
+    pub(crate) value: FixedDecimal,
+    pub(crate) pattern: &'l CurrencyPattern<'l>,
+    pub(crate) currency: TinyAsciiStr<3>,
+    /// The symbol or name of the currency, or `None` to display the code.
+    pub(crate) currency_text: Option<&'l str>,
+    /// Whether to insert spacing between an alphanumeric currency and the number.
+    pub(crate) spacing: bool,
+}
+
+impl<'l> FormattedCurrency<'l> {
+    fn currency_text(&self) -> &str {
+        self.currency_text.unwrap_or_else(|| self.currency.as_str())
+    }
+
+    fn write_currency<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        sink.with_part(parts::CURRENCY, |s| s.write_str(self.currency_text()))
+    }
+}
+
+impl<'l> Writeable for FormattedCurrency<'l> {
+    fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        let number = self.formatter.fixed_decimal_formatter.format(&self.value);
+        let essentials = self.formatter.essentials.get();
+        let pattern = self.pattern;
+
+        let mut infix = &*pattern.infix;
+        if self.spacing && infix.is_empty() {
+            // CLDR currency spacing: an alphanumeric currency adjacent to the number is
+            // separated from it, such as in "CHF 3.00".
+            let currency = self.currency_text();
+            if pattern.currency_first {
+                if currency
+                    .chars()
+                    .next_back()
+                    .map_or(false, char::is_alphanumeric)
+                {
+                    infix = &essentials.spacing_before_number;
+                }
+            } else if currency.chars().next().map_or(false, char::is_alphanumeric) {
+                infix = &essentials.spacing_after_number;
+            }
+        }
+
+        if !pattern.prefix.is_empty() {
+            sink.with_part(parts::LITERAL, |s| s.write_str(&pattern.prefix))?;
+        }
+        if pattern.currency_first {
+            self.write_currency(sink)?;
+        } else {
+            number.write_to_parts(sink)?;
+        }
+        if !infix.is_empty() {
+            sink.with_part(parts::LITERAL, |s| s.write_str(infix))?;
+        }
+        if pattern.currency_first {
+            number.write_to_parts(sink)?;
+        } else {
+            self.write_currency(sink)?;
+        }
+        if !pattern.suffix.is_empty() {
+            sink.with_part(parts::LITERAL, |s| s.write_str(&pattern.suffix))?;
+        }
+        Ok(())
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedCurrency<'_>);
@@ -0,0 +1,128 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! 🚧 \[Experimental\] Formatting amounts of money in a locale-sensitive way.
+//!
+//! This module is published as its own crate ([`icu_currency`](https://docs.rs/icu_currency/latest/icu_currency/))
+//! and as part of the [`icu`](https://docs.rs/icu/latest/icu/) crate. See the latter for more details on the ICU4X project.
+//!
+//! <div class="stab unstable">
+//! 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+//! including in SemVer minor releases. It can be enabled with the "experimental" feature
+//! of the icu meta-crate. Use with caution.
+//! </div>
+//!
+//! Currencies are identified by their ISO 4217 code. The amount is rounded according to the
+//! CLDR supplemental currency data, and placed in the currency pattern of the locale.
+//!
+//! # Examples
+//!
+//! ## Formatting with currency symbols
+//!
+//! ```
+//! use fixed_decimal::FixedDecimal;
+//! use icu_currency::CurrencyFormatter;
+//! use icu_locid::locale;
+//! use tinystr::tinystr;
+//! use writeable::assert_writeable_eq;
+//!
+//! let amount = FixedDecimal::from(12345).multiplied_pow10(-1);
+//!
+//! let formatter = CurrencyFormatter::try_new_unstable(
+//!     &icu_testdata::unstable(),
+//!     &locale!("en").into(),
+//!     Default::default(),
+//! )
+//! .expect("Data should load successfully");
+//!
+//! assert_writeable_eq!(formatter.format(amount.clone(), tinystr!(3, "USD")), "$1,234.50");
+//! assert_writeable_eq!(formatter.format(amount.clone(), tinystr!(3, "CAD")), "CA$1,234.50");
+//!
+//! let formatter = CurrencyFormatter::try_new_unstable(
+//!     &icu_testdata::unstable(),
+//!     &locale!("fr").into(),
+//!     Default::default(),
+//! )
+//! .expect("Data should load successfully");
+//!
+//! assert_writeable_eq!(
+//!     formatter.format(amount.clone(), tinystr!(3, "EUR")),
+//!     "1\u{202f}234,50\u{a0}€"
+//! );
+//! assert_writeable_eq!(
+//!     formatter.format(amount, tinystr!(3, "USD")),
+//!     "1\u{202f}234,50\u{a0}$US"
+//! );
+//! ```
+//!
+//! ## Formatting with other currency displays
+//!
+//! ```
+//! use fixed_decimal::FixedDecimal;
+//! use icu_currency::options::{CurrencyDisplay, CurrencyFormatterOptions, CurrencySign};
+//! use icu_currency::CurrencyFormatter;
+//! use icu_locid::locale;
+//! use tinystr::tinystr;
+//! use writeable::assert_writeable_eq;
+//!
+//! let mut options = CurrencyFormatterOptions::default();
+//! options.display = CurrencyDisplay::IsoCode;
+//! options.sign = CurrencySign::Accounting;
+//! let formatter = CurrencyFormatter::try_new_unstable(
+//!     &icu_testdata::unstable(),
+//!     &locale!("en").into(),
+//!     options,
+//! )
+//! .expect("Data should load successfully");
+//!
+//! assert_writeable_eq!(
+//!     formatter.format(FixedDecimal::from(-42), tinystr!(3, "EUR")),
+//!     "(EUR\u{a0}42.00)"
+//! );
+//!
+//! let formatter = CurrencyFormatter::try_new_unstable(
+//!     &icu_testdata::unstable(),
+//!     &locale!("ru").into(),
+//!     CurrencyDisplay::Name.into(),
+//! )
+//! .expect("Data should load successfully");
+//!
+//! assert_writeable_eq!(
+//!     formatter.format(FixedDecimal::from(21), tinystr!(3, "JPY")),
+//!     "21 японская иена"
+//! );
+//! assert_writeable_eq!(
+//!     formatter.format(FixedDecimal::from(25), tinystr!(3, "JPY")),
+//!     "25 японских иен"
+//! );
+//! ```
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(
+    not(test),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::exhaustive_structs,
+        clippy::exhaustive_enums,
+    )
+)]
+#![warn(missing_docs)]
+
+extern crate alloc;
+
+mod currency;
+mod error;
+mod format;
+pub mod options;
+pub mod provider;
+
+pub use currency::CurrencyFormatter;
+pub use error::CurrencyError;
+pub use format::{parts, FormattedCurrency};
+
+#[doc(inline)]
+pub use CurrencyError as Error;
@@ -0,0 +1,85 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for [`CurrencyFormatter`](crate::CurrencyFormatter).
+
+use icu_decimal::options::FixedDecimalFormatterOptions;
+
+/// A bag of options defining how amounts of money will be formatted by
+/// [`CurrencyFormatter`](crate::CurrencyFormatter).
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+#[non_exhaustive]
+pub struct CurrencyFormatterOptions {
+    /// How the currency is displayed.
+    pub display: CurrencyDisplay,
+    /// How negative amounts are displayed.
+    pub sign: CurrencySign,
+    /// Which rounding rules of the currency apply.
+    pub usage: CurrencyUsage,
+    /// Options to configure the inner [`FixedDecimalFormatter`](icu_decimal::FixedDecimalFormatter),
+    /// which renders the amount.
+    pub fixed_decimal_formatter_options: FixedDecimalFormatterOptions,
+}
+
+impl From<CurrencyDisplay> for CurrencyFormatterOptions {
+    fn from(display: CurrencyDisplay) -> Self {
+        Self {
+            display,
+            ..Default::default()
+        }
+    }
+}
+
+/// How the currency is displayed.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+#[non_exhaustive]
+pub enum CurrencyDisplay {
+    /// The localized symbol, such as "US$" or "$" depending on the locale.
+    Symbol,
+    /// The narrow symbol, such as "$". Narrow symbols may be ambiguous.
+    NarrowSymbol,
+    /// The ISO 4217 code, such as "USD".
+    IsoCode,
+    /// The localized long name, inflected for the amount, such as "US dollars".
+    Name,
+}
+
+impl Default for CurrencyDisplay {
+    fn default() -> Self {
+        Self::Symbol
+    }
+}
+
+/// How negative amounts are displayed.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+#[non_exhaustive]
+pub enum CurrencySign {
+    /// Negative amounts are displayed with a minus sign, such as "-$3.00".
+    Standard,
+    /// Negative amounts are displayed using the accounting format of the locale, such as
+    /// "($3.00)". This has no effect with [`CurrencyDisplay::Name`].
+    Accounting,
+}
+
+impl Default for CurrencySign {
+    fn default() -> Self {
+        Self::Standard
+    }
+}
+
+/// Which rounding rules of the currency apply.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+#[non_exhaustive]
+pub enum CurrencyUsage {
+    /// The rounding used for most purposes, such as two fraction digits for the Swiss franc.
+    Standard,
+    /// The rounding used for cash transactions, such as to the nearest 0.05 for the Swiss franc.
+    Cash,
+}
+
+impl Default for CurrencyUsage {
+    fn default() -> Self {
+        Self::Standard
+    }
+}
@@ -0,0 +1,227 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// Provider structs must be stable
+#![allow(clippy::exhaustive_structs, clippy::exhaustive_enums)]
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use alloc::borrow::Cow;
+use icu_plurals::PluralCategory;
+use icu_provider::{yoke, zerofrom};
+use tinystr::TinyAsciiStr;
+use zerovec::ZeroMap;
+
+/// Currency patterns and symbols for a locale and numbering system.
+#[icu_provider::data_struct(marker(
+    CurrencyEssentialsV1Marker,
+    "currency/essentials@1",
+    extension_key = "nu"
+))]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_currency::provider),
+)]
+#[yoke(prove_covariance_manually)]
+pub struct CurrencyEssentialsV1<'data> {
+    /// The standard patterns, such as "¤#,##0.00".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub standard: CurrencyPatterns<'data>,
+    /// The accounting patterns, such as "¤#,##0.00;(¤#,##0.00)".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub accounting: CurrencyPatterns<'data>,
+    /// The text inserted between the currency and the number if the currency comes first and
+    /// ends with a letter or digit, such as the space in "CHF 3.00".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub spacing_before_number: Cow<'data, str>,
+    /// The text inserted between the number and the currency if the currency comes last and
+    /// starts with a letter or digit.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub spacing_after_number: Cow<'data, str>,
+    /// The symbols of currencies whose symbol is not their ISO 4217 code, such as "$" for USD.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub symbols: ZeroMap<'data, TinyAsciiStr<3>, str>,
+    /// The narrow symbols of currencies whose narrow symbol differs from their symbol, such as
+    /// "$" for CAD.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub narrow_symbols: ZeroMap<'data, TinyAsciiStr<3>, str>,
+}
+
+/// The patterns for positive and negative amounts.
+#[derive(Debug, Clone, PartialEq, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_currency::provider),
+)]
+pub struct CurrencyPatterns<'data> {
+    /// The pattern for positive amounts and zero.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub positive: CurrencyPattern<'data>,
+    /// The pattern for negative amounts, which is applied to the absolute value of the amount.
+    /// The minus sign is part of the literal text of this pattern.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub negative: CurrencyPattern<'data>,
+}
+
+/// A pattern that places a number and a currency, such as "-¤#,##0.00" or "{0} {1}".
+///
+/// The pattern consists of `prefix`, the number or the currency (depending on
+/// `currency_first`), `infix`, the other one, and `suffix`.
+#[derive(Debug, Clone, PartialEq, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_currency::provider),
+)]
+pub struct CurrencyPattern<'data> {
+    /// The literal text before the first placeholder.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub prefix: Cow<'data, str>,
+    /// The literal text between the two placeholders.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub infix: Cow<'data, str>,
+    /// The literal text after the second placeholder.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub suffix: Cow<'data, str>,
+    /// Whether the currency comes before the number.
+    pub currency_first: bool,
+}
+
+/// The long names of currencies, such as "US dollars", with the patterns that combine them
+/// with a number.
+///
+/// Entries are stored per plural category; the entries for categories other than `other` are
+/// present only if the locale uses the category, and their `names` only contain the names
+/// that differ from those for `other`.
+#[icu_provider::data_struct(CurrencyNamesV1Marker = "currency/names@1")]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_currency::provider),
+)]
+#[yoke(prove_covariance_manually)]
+pub struct CurrencyNamesV1<'data> {
+    /// The names for [`PluralCategory::Zero`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub zero: Option<CurrencyPluralNames<'data>>,
+    /// The names for [`PluralCategory::One`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub one: Option<CurrencyPluralNames<'data>>,
+    /// The names for [`PluralCategory::Two`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub two: Option<CurrencyPluralNames<'data>>,
+    /// The names for [`PluralCategory::Few`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub few: Option<CurrencyPluralNames<'data>>,
+    /// The names for [`PluralCategory::Many`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub many: Option<CurrencyPluralNames<'data>>,
+    /// The names for [`PluralCategory::Other`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub other: CurrencyPluralNames<'data>,
+}
+
+impl<'data> CurrencyNamesV1<'data> {
+    /// Returns the pattern and the name of the currency for the given plural category, or `None`
+    /// if the locale has no name for the currency.
+    pub(crate) fn get(
+        &self,
+        category: PluralCategory,
+        currency: TinyAsciiStr<3>,
+    ) -> Option<(&CurrencyPattern<'data>, &str)> {
+        let names = match category {
+            PluralCategory::Zero => self.zero.as_ref(),
+            PluralCategory::One => self.one.as_ref(),
+            PluralCategory::Two => self.two.as_ref(),
+            PluralCategory::Few => self.few.as_ref(),
+            PluralCategory::Many => self.many.as_ref(),
+            PluralCategory::Other => None,
+        }
+        .unwrap_or(&self.other);
+        let name = names
+            .names
+            .get(&currency)
+            .or_else(|| self.other.names.get(&currency))?;
+        Some((&names.pattern, name))
+    }
+}
+
+/// The currency names for a single plural category.
+#[derive(Debug, Clone, PartialEq, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_currency::provider),
+)]
+#[yoke(prove_covariance_manually)]
+pub struct CurrencyPluralNames<'data> {
+    /// The pattern that combines the number with the name, such as "{0} {1}".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub pattern: CurrencyPattern<'data>,
+    /// The names, keyed by ISO 4217 code.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub names: ZeroMap<'data, TinyAsciiStr<3>, str>,
+}
+
+/// The number of fraction digits and the rounding increments of each currency, from the CLDR
+/// supplemental `currencyData`.
+///
+/// This data is not locale-specific.
+#[icu_provider::data_struct(CurrencyFractionsV1Marker = "currency/fractions@1")]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_currency::provider),
+)]
+#[yoke(prove_covariance_manually)]
+pub struct CurrencyFractionsV1<'data> {
+    /// The fraction information of currencies that do not use the default.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub fractions: ZeroMap<'data, TinyAsciiStr<3>, FractionInfo>,
+    /// The fraction information of all other currencies.
+    pub default: FractionInfo,
+}
+
+impl<'data> CurrencyFractionsV1<'data> {
+    pub(crate) fn get(&self, currency: TinyAsciiStr<3>) -> FractionInfo {
+        self.fractions.get_copied(&currency).unwrap_or(self.default)
+    }
+}
+
+/// The number of fraction digits and the rounding increment of a currency.
+///
+/// A rounding increment of 0 or 1 means that amounts are rounded to the number of digits;
+/// otherwise amounts are rounded to a multiple of the increment in units of the last digit.
+/// For example, 2 digits with an increment of 5 round amounts to multiples of 0.05.
+#[derive(Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_currency::provider),
+)]
+#[zerovec::make_ule(FractionInfoULE)]
+pub struct FractionInfo {
+    /// The number of fraction digits.
+    pub digits: u8,
+    /// The rounding increment.
+    pub rounding: u8,
+    /// The number of fraction digits for cash transactions.
+    pub cash_digits: u8,
+    /// The rounding increment for cash transactions.
+    pub cash_rounding: u8,
+}
@@ -0,0 +1,274 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::FixedDecimal;
+use icu_currency::options::{
+    CurrencyDisplay, CurrencyFormatterOptions, CurrencySign, CurrencyUsage,
+};
+use icu_currency::CurrencyFormatter;
+use icu_locid::locale;
+use tinystr::tinystr;
+use writeable::assert_writeable_eq;
+
+#[test]
+fn test_fraction_digits() {
+    let formatter = CurrencyFormatter::try_new_unstable(
+        &icu_testdata::unstable(),
+        &locale!("en").into(),
+        Default::default(),
+    )
+    .unwrap();
+
+    // Amounts are padded and rounded half-even to the digits of the currency
+    let usd = tinystr!(3, "USD");
+    assert_writeable_eq!(formatter.format(FixedDecimal::from(5), usd), "$5.00");
+    let value: FixedDecimal = "1.005".parse().unwrap();
+    assert_writeable_eq!(formatter.format(value, usd), "$1.00");
+    let value: FixedDecimal = "1.015".parse().unwrap();
+    assert_writeable_eq!(formatter.format(value, usd), "$1.02");
+
+    let jpy = tinystr!(3, "JPY");
+    let value: FixedDecimal = "1234.5".parse().unwrap();
+    assert_writeable_eq!(formatter.format(value, jpy), "¥1,234");
+    let value: FixedDecimal = "1235.5".parse().unwrap();
+    assert_writeable_eq!(formatter.format(value, jpy), "¥1,236");
+
+    let value: FixedDecimal = "0.0005".parse().unwrap();
+    assert_writeable_eq!(
+        formatter.format(value, tinystr!(3, "KWD")),
+        "KWD\u{a0}0.000"
+    );
+
+    // Currencies without data use two fraction digits and are displayed with their code
+    let value: FixedDecimal = "1.5".parse().unwrap();
+    assert_writeable_eq!(formatter.format(value, tinystr!(3, "XXY")), "XXY\u{a0}1.50");
+}
+
+#[test]
+fn test_cash_rounding() {
+    let mut options = CurrencyFormatterOptions::default();
+    options.usage = CurrencyUsage::Cash;
+    let formatter = CurrencyFormatter::try_new_unstable(
+        &icu_testdata::unstable(),
+        &locale!("en").into(),
+        options,
+    )
+    .unwrap();
+
+    // Swiss franc cash amounts are rounded to 0.05, halfway cases to the even multiple
+    for (input, expected) in [
+        ("1", "CHF\u{a0}1.00"),
+        ("1.01", "CHF\u{a0}1.00"),
+        ("1.02", "CHF\u{a0}1.00"),
+        ("1.03", "CHF\u{a0}1.05"),
+        ("1.07", "CHF\u{a0}1.05"),
+        ("1.08", "CHF\u{a0}1.10"),
+        ("1.025", "CHF\u{a0}1.00"),
+        ("1.075", "CHF\u{a0}1.10"),
+        ("1.125", "CHF\u{a0}1.10"),
+        ("1.0251", "CHF\u{a0}1.05"),
+        ("1.02500001", "CHF\u{a0}1.05"),
+        ("1.0749", "CHF\u{a0}1.05"),
+        ("9.98", "CHF\u{a0}10.00"),
+        ("999.99", "CHF\u{a0}1,000.00"),
+        ("0.02", "CHF\u{a0}0.00"),
+        ("-1.03", "-CHF\u{a0}1.05"),
+        ("-9.98", "-CHF\u{a0}10.00"),
+    ] {
+        let value: FixedDecimal = input.parse().unwrap();
+        assert_writeable_eq!(
+            formatter.format(value, tinystr!(3, "CHF")),
+            expected,
+            "{}",
+            input
+        );
+    }
+
+    // Danish krone cash amounts are rounded to 0.50
+    for (input, expected) in [
+        ("1.24", "DKK\u{a0}1.00"),
+        ("1.25", "DKK\u{a0}1.00"),
+        ("1.26", "DKK\u{a0}1.50"),
+        ("1.74", "DKK\u{a0}1.50"),
+        ("1.75", "DKK\u{a0}2.00"),
+        ("2.75", "DKK\u{a0}3.00"),
+        ("2.25", "DKK\u{a0}2.00"),
+    ] {
+        let value: FixedDecimal = input.parse().unwrap();
+        assert_writeable_eq!(
+            formatter.format(value, tinystr!(3, "DKK")),
+            expected,
+            "{}",
+            input
+        );
+    }
+
+    // Swedish krona cash amounts have no fraction digits
+    let value: FixedDecimal = "12.5".parse().unwrap();
+    assert_writeable_eq!(formatter.format(value, tinystr!(3, "SEK")), "SEK\u{a0}12");
+    let value: FixedDecimal = "13.5".parse().unwrap();
+    assert_writeable_eq!(
+        formatter.format(value.clone(), tinystr!(3, "SEK")),
+        "SEK\u{a0}14"
+    );
+    assert_writeable_eq!(formatter.format(value, tinystr!(3, "USD")), "$13.50");
+}
+
+#[test]
+fn test_standard_rounding() {
+    let formatter = CurrencyFormatter::try_new_unstable(
+        &icu_testdata::unstable(),
+        &locale!("en").into(),
+        Default::default(),
+    )
+    .unwrap();
+
+    // The standard rounding has no increment
+    let value: FixedDecimal = "1.03".parse().unwrap();
+    assert_writeable_eq!(formatter.format(value, tinystr!(3, "CHF")), "CHF\u{a0}1.03");
+}
+
+#[test]
+fn test_displays() {
+    let value: FixedDecimal = "1234.5".parse().unwrap();
+    for (display, expected) in [
+        (CurrencyDisplay::Symbol, "CA$1,234.50"),
+        (CurrencyDisplay::NarrowSymbol, "$1,234.50"),
+        (CurrencyDisplay::IsoCode, "CAD\u{a0}1,234.50"),
+        (CurrencyDisplay::Name, "1,234.50 Canadian dollars"),
+    ] {
+        let formatter = CurrencyFormatter::try_new_unstable(
+            &icu_testdata::unstable(),
+            &locale!("en").into(),
+            display.into(),
+        )
+        .unwrap();
+        assert_writeable_eq!(
+            formatter.format(value.clone(), tinystr!(3, "CAD")),
+            expected,
+            "{:?}",
+            display
+        );
+    }
+}
+
+#[test]
+fn test_names_plurals() {
+    let formatter = CurrencyFormatter::try_new_unstable(
+        &icu_testdata::unstable(),
+        &locale!("en").into(),
+        CurrencyDisplay::Name.into(),
+    )
+    .unwrap();
+    assert_writeable_eq!(
+        formatter.format(FixedDecimal::from(1), tinystr!(3, "EUR")),
+        "1.00 euros"
+    );
+    assert_writeable_eq!(
+        formatter.format(FixedDecimal::from(1), tinystr!(3, "JPY")),
+        "1 Japanese yen"
+    );
+    assert_writeable_eq!(
+        formatter.format(FixedDecimal::from(-2), tinystr!(3, "EUR")),
+        "-2.00 euros"
+    );
+
+    // Russian distinguishes one, few and many
+    let formatter = CurrencyFormatter::try_new_unstable(
+        &icu_testdata::unstable(),
+        &locale!("ru").into(),
+        CurrencyDisplay::Name.into(),
+    )
+    .unwrap();
+    assert_writeable_eq!(
+        formatter.format(FixedDecimal::from(1), tinystr!(3, "RUB")),
+        "1,00 российского рубля"
+    );
+    assert_writeable_eq!(
+        formatter.format(FixedDecimal::from(21), tinystr!(3, "JPY")),
+        "21 японская иена"
+    );
+    assert_writeable_eq!(
+        formatter.format(FixedDecimal::from(5), tinystr!(3, "JPY")),
+        "5 японских иен"
+    );
+}
+
+#[test]
+fn test_accounting() {
+    let mut options = CurrencyFormatterOptions::default();
+    options.sign = CurrencySign::Accounting;
+
+    let formatter = CurrencyFormatter::try_new_unstable(
+        &icu_testdata::unstable(),
+        &locale!("en").into(),
+        options.clone(),
+    )
+    .unwrap();
+    assert_writeable_eq!(
+        formatter.format(FixedDecimal::from(-3), tinystr!(3, "USD")),
+        "($3.00)"
+    );
+    assert_writeable_eq!(
+        formatter.format(FixedDecimal::from(3), tinystr!(3, "USD")),
+        "$3.00"
+    );
+
+    let formatter = CurrencyFormatter::try_new_unstable(
+        &icu_testdata::unstable(),
+        &locale!("fr").into(),
+        options,
+    )
+    .unwrap();
+    assert_writeable_eq!(
+        formatter.format(FixedDecimal::from(-3), tinystr!(3, "EUR")),
+        "(3,00\u{a0}€)"
+    );
+}
+
+#[test]
+fn test_locales() {
+    let formatter = CurrencyFormatter::try_new_unstable(
+        &icu_testdata::unstable(),
+        &locale!("ja").into(),
+        Default::default(),
+    )
+    .unwrap();
+    assert_writeable_eq!(
+        formatter.format(FixedDecimal::from(1234), tinystr!(3, "JPY")),
+        "￥1,234"
+    );
+    let value: FixedDecimal = "12.3".parse().unwrap();
+    assert_writeable_eq!(formatter.format(value, tinystr!(3, "USD")), "$12.30");
+
+    // Spanish only groups numbers with five or more integer digits
+    let formatter = CurrencyFormatter::try_new_unstable(
+        &icu_testdata::unstable(),
+        &locale!("es").into(),
+        Default::default(),
+    )
+    .unwrap();
+    let value: FixedDecimal = "1234.5".parse().unwrap();
+    assert_writeable_eq!(
+        formatter.format(value, tinystr!(3, "EUR")),
+        "1234,50\u{a0}€"
+    );
+    let value: FixedDecimal = "12345.5".parse().unwrap();
+    assert_writeable_eq!(
+        formatter.format(value, tinystr!(3, "EUR")),
+        "12.345,50\u{a0}€"
+    );
+
+    let formatter = CurrencyFormatter::try_new_unstable(
+        &icu_testdata::unstable(),
+        &locale!("ar").into(),
+        Default::default(),
+    )
+    .unwrap();
+    let value: FixedDecimal = "-12.5".parse().unwrap();
+    assert_writeable_eq!(
+        formatter.format(value, tinystr!(3, "EGP")),
+        "\u{61c}-١٢٫٥٠\u{a0}ج.م."
+    );
+}
@@ -0,0 +1,784 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::options::{DisplayNamesOptions, LanguageDisplay, Style};
+use crate::provider::*;
+use crate::DisplayNamesError;
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use icu_locid::extensions::unicode::{Key, Value};
+use icu_locid::subtags::{Language, Region, Script, Variant};
+use icu_locid::Locale;
+use icu_plurals::provider::CardinalV1Marker;
+use icu_plurals::{PluralOperands, PluralRules};
+use icu_provider::prelude::*;
+use tinystr::TinyAsciiStr;
+
+fn load<M, D>(data_provider: &D, locale: &DataLocale) -> Result<DataPayload<M>, DataError>
+where
+    M: KeyedDataMarker,
+    D: DataProvider<M> + ?Sized,
+{
+    data_provider
+        .load(DataRequest {
+            locale,
+            metadata: Default::default(),
+        })?
+        .take_payload()
+}
+
+/// Lookup of display names of regions, such as "United Kingdom" for `GB`.
+///
+/// # Examples
+///
+/// ```
+/// use icu_displaynames::options::DisplayNamesOptions;
+/// use icu_displaynames::RegionDisplayNames;
+/// use icu_locid::{locale, subtags_region as region};
+///
+/// let display_name = RegionDisplayNames::try_new_unstable(
+///     &icu_testdata::unstable(),
+///     &locale!("en-001").into(),
+///     DisplayNamesOptions::default(),
+/// )
+/// .expect("Data should load successfully");
+///
+/// assert_eq!(display_name.of(region!("AE")), Some("United Arab Emirates"));
+/// ```
+pub struct RegionDisplayNames {
+    region_data: DataPayload<TerritoryDisplayNamesV1Marker>,
+}
+
+impl RegionDisplayNames {
+    /// Creates a new [`RegionDisplayNames`] for the given locale.
+    ///
+    /// Region names have no alternative styles, so the options currently have no effect.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        _options: DisplayNamesOptions,
+    ) -> Result<Self, DisplayNamesError>
+    where
+        D: DataProvider<TerritoryDisplayNamesV1Marker> + ?Sized,
+    {
+        Ok(Self {
+            region_data: load(data_provider, locale)?,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        options: DisplayNamesOptions,
+        error: DisplayNamesError
+    );
+
+    /// Returns the display name of a region, if available.
+    pub fn of(&self, region: Region) -> Option<&str> {
+        self.region_data.get().names.get(&region.into())
+    }
+}
+
+/// Lookup of display names of languages, such as "German" for `de`.
+///
+/// # Examples
+///
+/// ```
+/// use icu_displaynames::options::{DisplayNamesOptions, Style};
+/// use icu_displaynames::LanguageDisplayNames;
+/// use icu_locid::{locale, subtags_language as language};
+///
+/// let mut options = DisplayNamesOptions::default();
+/// options.style = Some(Style::Short);
+/// let display_name = LanguageDisplayNames::try_new_unstable(
+///     &icu_testdata::unstable(),
+///     &locale!("en-001").into(),
+///     options,
+/// )
+/// .expect("Data should load successfully");
+///
+/// assert_eq!(display_name.of(language!("de")), Some("German"));
+/// assert_eq!(display_name.of(language!("az")), Some("Azeri"));
+/// ```
+pub struct LanguageDisplayNames {
+    options: DisplayNamesOptions,
+    language_data: DataPayload<LanguageDisplayNamesV1Marker>,
+}
+
+impl LanguageDisplayNames {
+    /// Creates a new [`LanguageDisplayNames`] for the given locale.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        options: DisplayNamesOptions,
+    ) -> Result<Self, DisplayNamesError>
+    where
+        D: DataProvider<LanguageDisplayNamesV1Marker> + ?Sized,
+    {
+        Ok(Self {
+            options,
+            language_data: load(data_provider, locale)?,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        options: DisplayNamesOptions,
+        error: DisplayNamesError
+    );
+
+    /// Returns the display name of a language, if available.
+    pub fn of(&self, language: Language) -> Option<&str> {
+        let data = self.language_data.get();
+        let key = language.into();
+        match self.options.style {
+            Some(Style::Short) => data.short_names.get(&key),
+            Some(Style::Long) => data.long_names.get(&key),
+            None => None,
+        }
+        .or_else(|| data.names.get(&key))
+    }
+}
+
+/// Lookup of display names of scripts, such as "Cyrillic" for `Cyrl`.
+///
+/// # Examples
+///
+/// ```
+/// use icu_displaynames::options::DisplayNamesOptions;
+/// use icu_displaynames::ScriptDisplayNames;
+/// use icu_locid::{locale, subtags_script as script};
+///
+/// let display_name = ScriptDisplayNames::try_new_unstable(
+///     &icu_testdata::unstable(),
+///     &locale!("en-001").into(),
+///     DisplayNamesOptions::default(),
+/// )
+/// .expect("Data should load successfully");
+///
+/// assert_eq!(display_name.of(script!("Cyrl")), Some("Cyrillic"));
+/// ```
+pub struct ScriptDisplayNames {
+    options: DisplayNamesOptions,
+    script_data: DataPayload<ScriptDisplayNamesV1Marker>,
+}
+
+impl ScriptDisplayNames {
+    /// Creates a new [`ScriptDisplayNames`] for the given locale.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        options: DisplayNamesOptions,
+    ) -> Result<Self, DisplayNamesError>
+    where
+        D: DataProvider<ScriptDisplayNamesV1Marker> + ?Sized,
+    {
+        Ok(Self {
+            options,
+            script_data: load(data_provider, locale)?,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        options: DisplayNamesOptions,
+        error: DisplayNamesError
+    );
+
+    /// Returns the display name of a script, if available.
+    pub fn of(&self, script: Script) -> Option<&str> {
+        let data = self.script_data.get();
+        let key = script.into();
+        match self.options.style {
+            Some(Style::Short) => data.short_names.get(&key),
+            _ => None,
+        }
+        .or_else(|| data.names.get(&key))
+    }
+}
+
+/// Lookup of display names of variants, such as "IPA Phonetics" for `fonipa`.
+///
+/// # Examples
+///
+/// ```
+/// use icu_displaynames::options::DisplayNamesOptions;
+/// use icu_displaynames::VariantDisplayNames;
+/// use icu_locid::{locale, subtags_variant as variant};
+///
+/// let display_name = VariantDisplayNames::try_new_unstable(
+///     &icu_testdata::unstable(),
+///     &locale!("en-001").into(),
+///     DisplayNamesOptions::default(),
+/// )
+/// .expect("Data should load successfully");
+///
+/// assert_eq!(display_name.of(variant!("fonipa")), Some("IPA Phonetics"));
+/// ```
+pub struct VariantDisplayNames {
+    variant_data: DataPayload<VariantDisplayNamesV1Marker>,
+}
+
+impl VariantDisplayNames {
+    /// Creates a new [`VariantDisplayNames`] for the given locale.
+    ///
+    /// Variant names have no alternative styles, so the options currently have no effect.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        _options: DisplayNamesOptions,
+    ) -> Result<Self, DisplayNamesError>
+    where
+        D: DataProvider<VariantDisplayNamesV1Marker> + ?Sized,
+    {
+        Ok(Self {
+            variant_data: load(data_provider, locale)?,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        options: DisplayNamesOptions,
+        error: DisplayNamesError
+    );
+
+    /// Returns the display name of a variant, if available.
+    pub fn of(&self, variant: Variant) -> Option<&str> {
+        self.variant_data.get().names.get(&variant.into())
+    }
+}
+
+/// Lookup of display names of Unicode extension keys and their values, such as "Calendar"
+/// for `ca` and "Gregorian Calendar" for `ca-gregory`.
+///
+/// # Examples
+///
+/// ```
+/// use icu_displaynames::options::DisplayNamesOptions;
+/// use icu_displaynames::KeyDisplayNames;
+/// use icu_locid::extensions_unicode_key as key;
+/// use icu_locid::extensions_unicode_value as value;
+/// use icu_locid::locale;
+///
+/// let display_name = KeyDisplayNames::try_new_unstable(
+///     &icu_testdata::unstable(),
+///     &locale!("en-001").into(),
+///     DisplayNamesOptions::default(),
+/// )
+/// .expect("Data should load successfully");
+///
+/// assert_eq!(display_name.of(key!("ca")), Some("Calendar"));
+/// assert_eq!(
+///     display_name.of_type(key!("ca"), &value!("gregory")),
+///     Some("Gregorian Calendar")
+/// );
+/// ```
+pub struct KeyDisplayNames {
+    key_data: DataPayload<KeyDisplayNamesV1Marker>,
+}
+
+impl KeyDisplayNames {
+    /// Creates a new [`KeyDisplayNames`] for the given locale.
+    ///
+    /// Key names have no alternative styles, so the options currently have no effect.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        _options: DisplayNamesOptions,
+    ) -> Result<Self, DisplayNamesError>
+    where
+        D: DataProvider<KeyDisplayNamesV1Marker> + ?Sized,
+    {
+        Ok(Self {
+            key_data: load(data_provider, locale)?,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        options: DisplayNamesOptions,
+        error: DisplayNamesError
+    );
+
+    /// Returns the display name of a key, if available.
+    pub fn of(&self, key: Key) -> Option<&str> {
+        self.key_data.get().keys.get(&key.into())
+    }
+
+    /// Returns the display name of a value of a key, if available.
+    ///
+    /// An empty value is treated as `true`, as in `-u-kn`.
+    pub fn of_type(&self, key: Key, value: &Value) -> Option<&str> {
+        let value = value.to_string();
+        let value = if value.is_empty() { "true" } else { &value };
+        self.key_data.get().types.get(&format!("{}-{}", key, value))
+    }
+}
+
+/// Lookup of display names of currencies, such as "Euro" for `EUR`, including the names
+/// used with a number, such as "3 euros".
+///
+/// # Examples
+///
+/// ```
+/// use icu_displaynames::options::DisplayNamesOptions;
+/// use icu_displaynames::CurrencyDisplayNames;
+/// use icu_locid::locale;
+/// use tinystr::tinystr;
+///
+/// let display_name = CurrencyDisplayNames::try_new_unstable(
+///     &icu_testdata::unstable(),
+///     &locale!("en").into(),
+///     DisplayNamesOptions::default(),
+/// )
+/// .expect("Data should load successfully");
+///
+/// assert_eq!(display_name.of(tinystr!(3, "EUR")), Some("Euro"));
+/// assert_eq!(display_name.of_count(tinystr!(3, "EUR"), 1), Some("euro"));
+/// assert_eq!(display_name.of_count(tinystr!(3, "EUR"), 3), Some("euros"));
+/// ```
+pub struct CurrencyDisplayNames {
+    currency_data: DataPayload<CurrencyDisplayNamesV1Marker>,
+    plural_rules: PluralRules,
+}
+
+impl CurrencyDisplayNames {
+    /// Creates a new [`CurrencyDisplayNames`] for the given locale.
+    ///
+    /// Currency names have no alternative styles, so the options currently have no effect.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        _options: DisplayNamesOptions,
+    ) -> Result<Self, DisplayNamesError>
+    where
+        D: DataProvider<CurrencyDisplayNamesV1Marker> + DataProvider<CardinalV1Marker> + ?Sized,
+    {
+        Ok(Self {
+            currency_data: load(data_provider, locale)?,
+            plural_rules: PluralRules::try_new_cardinal_unstable(data_provider, locale)?,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        options: DisplayNamesOptions,
+        error: DisplayNamesError
+    );
+
+    /// Returns the display name of a currency, given by its ISO 4217 code, if available.
+    pub fn of(&self, currency: TinyAsciiStr<3>) -> Option<&str> {
+        self.currency_data.get().names.get(&currency)
+    }
+
+    /// Returns the display name of a currency used with the given number, if available.
+    ///
+    /// The name is selected by the plural category of the number, as in "1 euro" and "3 euros".
+    pub fn of_count<I: Into<PluralOperands>>(
+        &self,
+        currency: TinyAsciiStr<3>,
+        count: I,
+    ) -> Option<&str> {
+        self.currency_data
+            .get()
+            .plural_names
+            .get(self.plural_rules.category_for(count), &currency)
+    }
+}
+
+/// Lookup of display names of calendars, such as "Buddhist Calendar" for `buddhist`.
+///
+/// # Examples
+///
+/// ```
+/// use icu_displaynames::options::DisplayNamesOptions;
+/// use icu_displaynames::CalendarDisplayNames;
+/// use icu_locid::extensions_unicode_value as value;
+/// use icu_locid::locale;
+///
+/// let display_name = CalendarDisplayNames::try_new_unstable(
+///     &icu_testdata::unstable(),
+///     &locale!("en").into(),
+///     DisplayNamesOptions::default(),
+/// )
+/// .expect("Data should load successfully");
+///
+/// assert_eq!(
+///     display_name.of(&value!("buddhist")),
+///     Some("Buddhist Calendar")
+/// );
+/// ```
+pub struct CalendarDisplayNames {
+    calendar_data: DataPayload<CalendarDisplayNamesV1Marker>,
+}
+
+impl CalendarDisplayNames {
+    /// Creates a new [`CalendarDisplayNames`] for the given locale.
+    ///
+    /// Calendar names have no alternative styles, so the options currently have no effect.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        _options: DisplayNamesOptions,
+    ) -> Result<Self, DisplayNamesError>
+    where
+        D: DataProvider<CalendarDisplayNamesV1Marker> + ?Sized,
+    {
+        Ok(Self {
+            calendar_data: load(data_provider, locale)?,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        options: DisplayNamesOptions,
+        error: DisplayNamesError
+    );
+
+    /// Returns the display name of a calendar, given by the value of its `-u-ca` keyword,
+    /// if available.
+    pub fn of(&self, calendar: &Value) -> Option<&str> {
+        self.calendar_data.get().names.get(&calendar.to_string())
+    }
+}
+
+/// Lookup of display names of units of measurement, such as "kilometers" for `kilometer`.
+///
+/// # Examples
+///
+/// ```
+/// use icu_displaynames::options::{DisplayNamesOptions, Style};
+/// use icu_displaynames::UnitDisplayNames;
+/// use icu_locid::locale;
+///
+/// let display_name = UnitDisplayNames::try_new_unstable(
+///     &icu_testdata::unstable(),
+///     &locale!("en").into(),
+///     DisplayNamesOptions::default(),
+/// )
+/// .expect("Data should load successfully");
+///
+/// assert_eq!(display_name.of("kilometer"), Some("kilometers"));
+///
+/// let mut options = DisplayNamesOptions::default();
+/// options.style = Some(Style::Short);
+/// let display_name = UnitDisplayNames::try_new_unstable(
+///     &icu_testdata::unstable(),
+///     &locale!("en").into(),
+///     options,
+/// )
+/// .expect("Data should load successfully");
+///
+/// assert_eq!(display_name.of("kilometer"), Some("km"));
+/// ```
+pub struct UnitDisplayNames {
+    options: DisplayNamesOptions,
+    unit_data: DataPayload<UnitDisplayNamesV1Marker>,
+}
+
+impl UnitDisplayNames {
+    /// Creates a new [`UnitDisplayNames`] for the given locale.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        options: DisplayNamesOptions,
+    ) -> Result<Self, DisplayNamesError>
+    where
+        D: DataProvider<UnitDisplayNamesV1Marker> + ?Sized,
+    {
+        Ok(Self {
+            options,
+            unit_data: load(data_provider, locale)?,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        options: DisplayNamesOptions,
+        error: DisplayNamesError
+    );
+
+    /// Returns the display name of a unit, given by its CLDR core unit identifier such as
+    /// `kilometer` or `liter-per-kilometer`, if available.
+    pub fn of(&self, unit: &str) -> Option<&str> {
+        let data = self.unit_data.get();
+        match self.options.style {
+            Some(Style::Short) => data.short_names.get(unit),
+            _ => None,
+        }
+        .or_else(|| data.names.get(unit))
+    }
+}
+
+/// A formatter for display names of locales, such as "English (United Kingdom)" for `en-GB`.
+///
+/// The name consists of the name of the language, followed by names for the remaining
+/// subtags and the Unicode extension keywords, combined using the locale's
+/// `localeDisplayPattern`. With [`LanguageDisplay::Dialect`], dialect names such as
+/// "British English" are used where available.
+///
+/// Subtags without a display name are shown as their code.
+///
+/// # Examples
+///
+/// ```
+/// use icu_displaynames::options::DisplayNamesOptions;
+/// use icu_displaynames::LocaleDisplayNamesFormatter;
+/// use icu_locid::{locale, Locale};
+///
+/// let formatter = LocaleDisplayNamesFormatter::try_new_unstable(
+///     &icu_testdata::unstable(),
+///     &locale!("en-001").into(),
+///     DisplayNamesOptions::default(),
+/// )
+/// .expect("Data should load successfully");
+///
+/// assert_eq!(formatter.of(&locale!("de")), "German");
+/// assert_eq!(formatter.of(&locale!("de-CH")), "Swiss High German");
+/// assert_eq!(formatter.of(&locale!("uz-Latn-UZ")), "Uzbek (Latin, Uzbekistan)");
+/// assert_eq!(formatter.of(&locale!("sr-Latn-ME")), "Montenegrin (Latin)");
+///
+/// let locale: Locale = "de-DE-u-ca-buddhist-kn".parse().expect("Valid BCP-47");
+/// assert_eq!(
+///     formatter.of(&locale),
+///     "German (Germany, Buddhist Calendar, Sort Digits Numerically)"
+/// );
+///
+/// // Values without a display name are combined with the name of the key.
+/// assert_eq!(formatter.of(&locale!("en-u-ca-abc")), "English (Calendar: abc)");
+/// ```
+pub struct LocaleDisplayNamesFormatter {
+    options: DisplayNamesOptions,
+    patterns: DataPayload<LocaleDisplayPatternsV1Marker>,
+    locale_data: DataPayload<LocaleDisplayNamesV1Marker>,
+    language_names: LanguageDisplayNames,
+    script_names: ScriptDisplayNames,
+    region_names: RegionDisplayNames,
+    variant_names: VariantDisplayNames,
+    key_names: KeyDisplayNames,
+}
+
+impl LocaleDisplayNamesFormatter {
+    /// Creates a new [`LocaleDisplayNamesFormatter`] for the given locale.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        options: DisplayNamesOptions,
+    ) -> Result<Self, DisplayNamesError>
+    where
+        D: DataProvider<LocaleDisplayPatternsV1Marker>
+            + DataProvider<LocaleDisplayNamesV1Marker>
+            + DataProvider<LanguageDisplayNamesV1Marker>
+            + DataProvider<ScriptDisplayNamesV1Marker>
+            + DataProvider<TerritoryDisplayNamesV1Marker>
+            + DataProvider<VariantDisplayNamesV1Marker>
+            + DataProvider<KeyDisplayNamesV1Marker>
+            + ?Sized,
+    {
+        Ok(Self {
+            options,
+            patterns: load(data_provider, locale)?,
+            locale_data: load(data_provider, locale)?,
+            language_names: LanguageDisplayNames::try_new_unstable(data_provider, locale, options)?,
+            script_names: ScriptDisplayNames::try_new_unstable(data_provider, locale, options)?,
+            region_names: RegionDisplayNames::try_new_unstable(data_provider, locale, options)?,
+            variant_names: VariantDisplayNames::try_new_unstable(data_provider, locale, options)?,
+            key_names: KeyDisplayNames::try_new_unstable(data_provider, locale, options)?,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        options: DisplayNamesOptions,
+        error: DisplayNamesError
+    );
+
+    /// Returns the display name of a locale.
+    pub fn of<'a>(&'a self, locale: &'a Locale) -> Cow<'a, str> {
+        let id = &locale.id;
+        let mut script = id.script;
+        let mut region = id.region;
+
+        let mut name = None;
+        if self.options.language_display == LanguageDisplay::Dialect {
+            // Try the most specific dialect name first, as in ICU.
+            let candidates = [
+                (script.is_some() && region.is_some(), true, true),
+                (script.is_some(), true, false),
+                (region.is_some(), false, true),
+            ];
+            for (available, with_script, with_region) in candidates {
+                if !available {
+                    continue;
+                }
+                let mut key = String::from(id.language.as_str());
+                if let Some(script) = script.filter(|_| with_script) {
+                    key.push('-');
+                    key.push_str(script.as_str());
+                }
+                if let Some(region) = region.filter(|_| with_region) {
+                    key.push('-');
+                    key.push_str(region.as_str());
+                }
+                if let Some(dialect_name) = self.dialect_name(&key) {
+                    name = Some(dialect_name);
+                    if with_script {
+                        script = None;
+                    }
+                    if with_region {
+                        region = None;
+                    }
+                    break;
+                }
+            }
+        }
+        let name = name
+            .or_else(|| self.language_names.of(id.language))
+            .unwrap_or_else(|| id.language.as_str());
+
+        let mut qualifiers = Vec::<Cow<str>>::new();
+        if let Some(script) = &script {
+            qualifiers.push(
+                self.script_names
+                    .of(*script)
+                    .unwrap_or_else(|| script.as_str())
+                    .into(),
+            );
+        }
+        if let Some(region) = &region {
+            qualifiers.push(
+                self.region_names
+                    .of(*region)
+                    .unwrap_or_else(|| region.as_str())
+                    .into(),
+            );
+        }
+        for variant in id.variants.iter() {
+            qualifiers.push(
+                self.variant_names
+                    .of(*variant)
+                    .unwrap_or_else(|| variant.as_str())
+                    .into(),
+            );
+        }
+        for (key, value) in locale.extensions.unicode.keywords.iter() {
+            qualifiers.push(match self.key_names.of_type(*key, value) {
+                Some(type_name) => type_name.into(),
+                None => apply_pattern(
+                    &self.patterns.get().key_type_pattern,
+                    self.key_names.of(*key).unwrap_or_else(|| key.as_str()),
+                    &value.to_string(),
+                )
+                .into(),
+            });
+        }
+
+        let mut qualifiers = qualifiers.into_iter().map(escape_parentheses);
+        let first = match qualifiers.next() {
+            Some(first) => first,
+            None => return name.into(),
+        };
+        let patterns = self.patterns.get();
+        let qualifiers = qualifiers.fold(first, |joined, qualifier| {
+            apply_pattern(&patterns.separator, &joined, &qualifier).into()
+        });
+        apply_pattern(&patterns.pattern, name, &qualifiers).into()
+    }
+
+    fn dialect_name(&self, key: &str) -> Option<&str> {
+        let data = self.locale_data.get();
+        match self.options.style {
+            Some(Style::Short) => data.short_names.get(key),
+            Some(Style::Long) => data.long_names.get(key),
+            None => None,
+        }
+        .or_else(|| data.names.get(key))
+    }
+}
+
+/// Replaces parentheses in a qualifier by brackets, as the qualifiers are themselves
+/// usually displayed in parentheses.
+fn escape_parentheses(qualifier: Cow<str>) -> Cow<str> {
+    if !qualifier.contains(&['(', ')', '（', '）'][..]) {
+        return qualifier;
+    }
+    qualifier
+        .chars()
+        .map(|c| match c {
+            '(' => '[',
+            ')' => ']',
+            '（' => '［',
+            '）' => '］',
+            c => c,
+        })
+        .collect::<String>()
+        .into()
+}
+
+/// Substitutes the placeholders `{0}` and `{1}` in a pattern.
+fn apply_pattern(pattern: &str, first: &str, second: &str) -> String {
+    let mut result = String::with_capacity(pattern.len() + first.len() + second.len());
+    let mut rest = pattern;
+    while let Some((literal, placeholder)) = rest.split_once('{') {
+        result.push_str(literal);
+        rest = if let Some(after) = placeholder.strip_prefix("0}") {
+            result.push_str(first);
+            after
+        } else if let Some(after) = placeholder.strip_prefix("1}") {
+            result.push_str(second);
+            after
+        } else {
+            result.push('{');
+            placeholder
+        };
+    }
+    result.push_str(rest);
+    result
+}
@@ -2,7 +2,7 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-//! 🚧 \[Experimental\] Display names for regions
+//! 🚧 \[Experimental\] Display names for locales, currencies, calendars and units
 //!
 //! This module is published as its own crate ([`icu_displaynames`](https://docs.rs/icu_displaynames/latest/icu_displaynames/))
 //! and as part of the [`icu`](https://docs.rs/icu/latest/icu/) crate. See the latter for more details on the ICU4X project.
@@ -13,10 +13,58 @@
 //! of the icu meta-crate. Use with caution.
 //! </div>
 //!
+//! This crate provides lookups of display names for the individual subtags of a locale,
+//! such as [`LanguageDisplayNames`] and [`RegionDisplayNames`], as well as
+//! [`LocaleDisplayNamesFormatter`], which combines them into a name for a whole locale.
+//!
+//! Names of other entities that are commonly shown in settings, such as currencies, calendars
+//! and units, are provided by [`CurrencyDisplayNames`], [`CalendarDisplayNames`] and
+//! [`UnitDisplayNames`].
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_displaynames::options::DisplayNamesOptions;
+//! use icu_displaynames::LocaleDisplayNamesFormatter;
+//! use icu_locid::locale;
+//!
+//! let formatter = LocaleDisplayNamesFormatter::try_new_unstable(
+//!     &icu_testdata::unstable(),
+//!     &locale!("en-001").into(),
+//!     DisplayNamesOptions::default(),
+//! )
+//! .expect("Data should load successfully");
+//!
+//! assert_eq!(formatter.of(&locale!("en-GB")), "British English");
+//! assert_eq!(formatter.of(&locale!("fr-CA")), "Canadian French");
+//! assert_eq!(formatter.of(&locale!("fr-BE")), "French (Belgium)");
+//! ```
+//!
 //! [`ICU4X`]: ../icu/index.html
 
-// TODO: expand documentation
-
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(
+    not(test),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::exhaustive_structs,
+        clippy::exhaustive_enums,
+    )
+)]
 #![warn(missing_docs)]
 
+extern crate alloc;
+
+mod displaynames;
+mod error;
+pub mod options;
 pub mod provider;
+
+pub use displaynames::*;
+pub use error::DisplayNamesError;
+
+#[doc(inline)]
+pub use DisplayNamesError as Error;
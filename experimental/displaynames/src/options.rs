@@ -0,0 +1,70 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for the display name formatters in this crate.
+
+/// A bag of options defining how display names are selected.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct DisplayNamesOptions {
+    /// The length of the display names, if other than the default length.
+    pub style: Option<Style>,
+    /// Whether to use dialect names, such as "British English", for locales.
+    pub language_display: LanguageDisplay,
+}
+
+/// The length of a display name.
+///
+/// If no name of the requested length is available, the default name is used.
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Style {
+    /// A short name, as in "UK English" for `en-GB`.
+    Short,
+    /// A long name, as in "Simplified Chinese (China)" for `zh-Hans-CN`.
+    Long,
+}
+
+/// Configuration for how the language of a locale is displayed by
+/// [`LocaleDisplayNamesFormatter`](crate::LocaleDisplayNamesFormatter).
+///
+/// # Examples
+///
+/// ```
+/// use icu_displaynames::options::{DisplayNamesOptions, LanguageDisplay};
+/// use icu_displaynames::LocaleDisplayNamesFormatter;
+/// use icu_locid::locale;
+///
+/// let mut options = DisplayNamesOptions::default();
+/// let formatter = LocaleDisplayNamesFormatter::try_new_unstable(
+///     &icu_testdata::unstable(),
+///     &locale!("en-001").into(),
+///     options,
+/// )
+/// .expect("Data should load successfully");
+/// assert_eq!(formatter.of(&locale!("en-GB")), "British English");
+///
+/// options.language_display = LanguageDisplay::Standard;
+/// let formatter = LocaleDisplayNamesFormatter::try_new_unstable(
+///     &icu_testdata::unstable(),
+///     &locale!("en-001").into(),
+///     options,
+/// )
+/// .expect("Data should load successfully");
+/// assert_eq!(formatter.of(&locale!("en-GB")), "English (United Kingdom)");
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum LanguageDisplay {
+    /// Use dialect names where available, as in "British English".
+    Dialect,
+    /// Always combine the language name with qualifiers, as in "English (United Kingdom)".
+    Standard,
+}
+
+impl Default for LanguageDisplay {
+    fn default() -> Self {
+        Self::Dialect
+    }
+}
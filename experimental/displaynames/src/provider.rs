@@ -9,6 +9,8 @@
 //!
 //! Read more about data providers: [`icu_provider`]
 
+use alloc::borrow::Cow;
+use icu_plurals::PluralCategory;
 use icu_provider::prelude::*;
 use tinystr::TinyAsciiStr;
 use zerovec::ZeroMap;
@@ -18,6 +20,12 @@ use zerovec::ZeroMap;
 // done even if they are not valid tags (an invalid key will just
 // become inaccessible).
 type UnvalidatedRegion = TinyAsciiStr<3>;
+type UnvalidatedLanguage = TinyAsciiStr<3>;
+type UnvalidatedScript = TinyAsciiStr<4>;
+type UnvalidatedVariant = TinyAsciiStr<8>;
+type UnvalidatedKey = TinyAsciiStr<2>;
+type UnvalidatedLanguageIdentifier = str;
+type UnvalidatedCurrency = TinyAsciiStr<3>;
 
 #[icu_provider::data_struct(TerritoryDisplayNamesV1Marker = "displaynames/territories@1")]
 #[derive(Debug, PartialEq, Clone)]
@@ -34,3 +42,229 @@ pub struct TerritoryDisplayNamesV1<'data> {
     #[cfg_attr(feature = "serde", serde(borrow))]
     pub names: ZeroMap<'data, UnvalidatedRegion, str>,
 }
+
+#[icu_provider::data_struct(LanguageDisplayNamesV1Marker = "displaynames/languages@1")]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_displaynames::provider),
+)]
+#[yoke(prove_covariance_manually)]
+/// LanguageDisplayNames provides mapping between languages and display names.
+pub struct LanguageDisplayNamesV1<'data> {
+    /// Mapping for language to display name.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub names: ZeroMap<'data, UnvalidatedLanguage, str>,
+    /// Mapping for language to short display name, if different from the display name.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub short_names: ZeroMap<'data, UnvalidatedLanguage, str>,
+    /// Mapping for language to long display name, if different from the display name.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub long_names: ZeroMap<'data, UnvalidatedLanguage, str>,
+}
+
+#[icu_provider::data_struct(LocaleDisplayNamesV1Marker = "displaynames/locales@1")]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_displaynames::provider),
+)]
+#[yoke(prove_covariance_manually)]
+/// LocaleDisplayNames provides mapping between language identifiers with more than a
+/// language subtag, such as `en-GB` or `zh-Hans`, and dialect display names.
+pub struct LocaleDisplayNamesV1<'data> {
+    /// Mapping for language identifier to display name.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub names: ZeroMap<'data, UnvalidatedLanguageIdentifier, str>,
+    /// Mapping for language identifier to short display name, if different from the display name.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub short_names: ZeroMap<'data, UnvalidatedLanguageIdentifier, str>,
+    /// Mapping for language identifier to long display name, if different from the display name.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub long_names: ZeroMap<'data, UnvalidatedLanguageIdentifier, str>,
+}
+
+#[icu_provider::data_struct(ScriptDisplayNamesV1Marker = "displaynames/scripts@1")]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_displaynames::provider),
+)]
+#[yoke(prove_covariance_manually)]
+/// ScriptDisplayNames provides mapping between scripts and display names.
+pub struct ScriptDisplayNamesV1<'data> {
+    /// Mapping for script to display name.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub names: ZeroMap<'data, UnvalidatedScript, str>,
+    /// Mapping for script to short display name, if different from the display name.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub short_names: ZeroMap<'data, UnvalidatedScript, str>,
+}
+
+#[icu_provider::data_struct(VariantDisplayNamesV1Marker = "displaynames/variants@1")]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_displaynames::provider),
+)]
+#[yoke(prove_covariance_manually)]
+/// VariantDisplayNames provides mapping between variants and display names.
+pub struct VariantDisplayNamesV1<'data> {
+    /// Mapping for variant to display name.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub names: ZeroMap<'data, UnvalidatedVariant, str>,
+}
+
+#[icu_provider::data_struct(KeyDisplayNamesV1Marker = "displaynames/keys@1")]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_displaynames::provider),
+)]
+#[yoke(prove_covariance_manually)]
+/// KeyDisplayNames provides mapping between Unicode extension keys and values, and display names.
+pub struct KeyDisplayNamesV1<'data> {
+    /// Mapping for key, such as `ca`, to display name.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub keys: ZeroMap<'data, UnvalidatedKey, str>,
+    /// Mapping for key and value, joined by `-` such as `ca-buddhist`, to display name.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub types: ZeroMap<'data, str, str>,
+}
+
+#[icu_provider::data_struct(LocaleDisplayPatternsV1Marker = "displaynames/patterns@1")]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_displaynames::provider),
+)]
+#[yoke(prove_covariance_manually)]
+/// LocaleDisplayPatterns provides the patterns used to compose locale display names,
+/// from the CLDR `localeDisplayPattern` data.
+pub struct LocaleDisplayPatternsV1<'data> {
+    /// The pattern combining a language name (`{0}`) with qualifiers (`{1}`), such as `{0} ({1})`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub pattern: Cow<'data, str>,
+    /// The pattern joining two qualifiers, such as `{0}, {1}`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub separator: Cow<'data, str>,
+    /// The pattern combining a key name (`{0}`) with a value (`{1}`) for which there
+    /// is no display name, such as `{0}: {1}`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub key_type_pattern: Cow<'data, str>,
+}
+
+#[icu_provider::data_struct(CurrencyDisplayNamesV1Marker = "displaynames/currencies@1")]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_displaynames::provider),
+)]
+#[yoke(prove_covariance_manually)]
+/// CurrencyDisplayNames provides mapping between ISO 4217 currency codes and display names.
+pub struct CurrencyDisplayNamesV1<'data> {
+    /// Mapping for currency to display name, such as "Euro" for `EUR`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub names: ZeroMap<'data, UnvalidatedCurrency, str>,
+    /// Mapping for currency to the display names used with a number, such as "euros".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub plural_names: CurrencyPluralDisplayNames<'data>,
+}
+
+/// The display names of currencies used with a number, for each plural category.
+#[derive(Debug, PartialEq, Clone, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_displaynames::provider),
+)]
+#[yoke(prove_covariance_manually)]
+pub struct CurrencyPluralDisplayNames<'data> {
+    /// The names for [`PluralCategory::Zero`], if they differ from `other`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub zero: ZeroMap<'data, UnvalidatedCurrency, str>,
+    /// The names for [`PluralCategory::One`], if they differ from `other`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub one: ZeroMap<'data, UnvalidatedCurrency, str>,
+    /// The names for [`PluralCategory::Two`], if they differ from `other`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub two: ZeroMap<'data, UnvalidatedCurrency, str>,
+    /// The names for [`PluralCategory::Few`], if they differ from `other`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub few: ZeroMap<'data, UnvalidatedCurrency, str>,
+    /// The names for [`PluralCategory::Many`], if they differ from `other`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub many: ZeroMap<'data, UnvalidatedCurrency, str>,
+    /// The names for [`PluralCategory::Other`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub other: ZeroMap<'data, UnvalidatedCurrency, str>,
+}
+
+impl<'data> CurrencyPluralDisplayNames<'data> {
+    /// Returns the name of the currency for the given plural category, falling back to
+    /// [`PluralCategory::Other`].
+    pub(crate) fn get(&self, category: PluralCategory, currency: &TinyAsciiStr<3>) -> Option<&str> {
+        match category {
+            PluralCategory::Zero => self.zero.get(currency),
+            PluralCategory::One => self.one.get(currency),
+            PluralCategory::Two => self.two.get(currency),
+            PluralCategory::Few => self.few.get(currency),
+            PluralCategory::Many => self.many.get(currency),
+            PluralCategory::Other => None,
+        }
+        .or_else(|| self.other.get(currency))
+    }
+}
+
+#[icu_provider::data_struct(CalendarDisplayNamesV1Marker = "displaynames/calendars@1")]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_displaynames::provider),
+)]
+#[yoke(prove_covariance_manually)]
+/// CalendarDisplayNames provides mapping between calendars and display names.
+pub struct CalendarDisplayNamesV1<'data> {
+    /// Mapping for the value of the `-u-ca` keyword, such as `buddhist` or `islamic-civil`,
+    /// to display name.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub names: ZeroMap<'data, str, str>,
+}
+
+#[icu_provider::data_struct(UnitDisplayNamesV1Marker = "displaynames/units@1")]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_displaynames::provider),
+)]
+#[yoke(prove_covariance_manually)]
+/// UnitDisplayNames provides mapping between units of measurement and display names.
+pub struct UnitDisplayNamesV1<'data> {
+    /// Mapping for CLDR core unit identifier, such as `kilometer`, to display name,
+    /// such as "kilometers".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub names: ZeroMap<'data, str, str>,
+    /// Mapping for CLDR core unit identifier to short display name, such as "km",
+    /// if different from the display name.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub short_names: ZeroMap<'data, str, str>,
+}
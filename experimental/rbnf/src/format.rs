@@ -0,0 +1,57 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::RuleBasedNumberFormatter;
+use core::fmt::{self, Write};
+use fixed_decimal::FixedDecimal;
+use icu_decimal::rbnf::PluralSelector;
+use icu_plurals::{PluralCategory, PluralRules};
+use writeable::Writeable;
+
+/// The [`Writeable`] implementation that is returned by [`RuleBasedNumberFormatter::format`].
+/// See the [`writeable`] crate for how to consume this.
+pub struct FormattedRuleBasedNumber<'l> {
+    pub(crate) formatter: &'l RuleBasedNumberFormatter,
+    pub(crate) value: &'l FixedDecimal,
+}
+
+impl<'l> Writeable for FormattedRuleBasedNumber<'l> {
+    fn write_to<W: Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        icu_decimal::rbnf::format(
+            self.value,
+            self.formatter.data.get(),
+            self.formatter.rule_set,
+            self.formatter.symbols.get(),
+            &Default::default(),
+            &self.formatter.plural_rules,
+            sink,
+        )
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedRuleBasedNumber<'_>);
+
+/// The plural rules for the `$(cardinal,…)$` and `$(ordinal,…)$` forms in rule texts.
+pub(crate) struct RbnfPluralRules {
+    pub(crate) cardinal: PluralRules,
+    pub(crate) ordinal: PluralRules,
+}
+
+impl PluralSelector for RbnfPluralRules {
+    fn select(&self, ordinal: bool, n: u64) -> &'static str {
+        let rules = if ordinal {
+            &self.ordinal
+        } else {
+            &self.cardinal
+        };
+        match rules.category_for(n) {
+            PluralCategory::Zero => "zero",
+            PluralCategory::One => "one",
+            PluralCategory::Two => "two",
+            PluralCategory::Few => "few",
+            PluralCategory::Many => "many",
+            PluralCategory::Other => "other",
+        }
+    }
+}
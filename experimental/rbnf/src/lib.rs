@@ -0,0 +1,150 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! 🚧 \[Experimental\] Formatting numbers with rule-based number formats, such as spelled-out
+//! numbers and ordinals.
+//!
+//! This module is published as its own crate ([`icu_rbnf`](https://docs.rs/icu_rbnf/latest/icu_rbnf/))
+//! and as part of the [`icu`](https://docs.rs/icu/latest/icu/) crate. See the latter for more details on the ICU4X project.
+//!
+//! The rules come from the CLDR `rbnf` data; see the
+//! [ICU user guide](https://unicode-org.github.io/icu/userguide/format_parse/numbers/rbnf.html)
+//! for how they work.
+//!
+//! <div class="stab unstable">
+//! 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+//! including in SemVer minor releases. It can be enabled with the "experimental" feature
+//! of the icu meta-crate. Use with caution.
+//! </div>
+//!
+//! # Examples
+//!
+//! ## Spelling out numbers in English
+//!
+//! ```
+//! use fixed_decimal::FixedDecimal;
+//! use icu_locid::locale;
+//! use icu_rbnf::options::RuleSetName;
+//! use icu_rbnf::RuleBasedNumberFormatter;
+//! use writeable::assert_writeable_eq;
+//!
+//! let formatter = RuleBasedNumberFormatter::try_new_unstable(
+//!     &icu_testdata::unstable(),
+//!     &locale!("en").into(),
+//!     RuleSetName::SpelloutCardinal,
+//! )
+//! .expect("Data should load successfully");
+//!
+//! let value = FixedDecimal::from(123);
+//! assert_writeable_eq!(formatter.format(&value), "one hundred twenty-three");
+//!
+//! let value = FixedDecimal::from(-7);
+//! assert_writeable_eq!(formatter.format(&value), "minus seven");
+//!
+//! let value: FixedDecimal = "3.25".parse().unwrap();
+//! assert_writeable_eq!(formatter.format(&value), "three point two five");
+//! ```
+//!
+//! ## Ordinals in words and in digits
+//!
+//! ```
+//! use fixed_decimal::FixedDecimal;
+//! use icu_locid::locale;
+//! use icu_rbnf::options::RuleSetName;
+//! use icu_rbnf::RuleBasedNumberFormatter;
+//! use writeable::assert_writeable_eq;
+//!
+//! let spellout = RuleBasedNumberFormatter::try_new_unstable(
+//!     &icu_testdata::unstable(),
+//!     &locale!("en").into(),
+//!     RuleSetName::SpelloutOrdinal,
+//! )
+//! .expect("Data should load successfully");
+//! let digits = RuleBasedNumberFormatter::try_new_unstable(
+//!     &icu_testdata::unstable(),
+//!     &locale!("en").into(),
+//!     RuleSetName::DigitsOrdinal,
+//! )
+//! .expect("Data should load successfully");
+//!
+//! let value = FixedDecimal::from(123);
+//! assert_writeable_eq!(spellout.format(&value), "one hundred twenty-third");
+//! assert_writeable_eq!(digits.format(&value), "123rd");
+//!
+//! let spanish = RuleBasedNumberFormatter::try_new_unstable(
+//!     &icu_testdata::unstable(),
+//!     &locale!("es").into(),
+//!     RuleSetName::DigitsOrdinal,
+//! )
+//! .expect("Data should load successfully");
+//! assert_writeable_eq!(spanish.format(&value), "123.º");
+//! ```
+//!
+//! ## Choosing the plural form of a noun
+//!
+//! The number that is spelled out selects the plural form of the noun that follows it:
+//!
+//! ```
+//! use fixed_decimal::FixedDecimal;
+//! use icu_locid::locale;
+//! use icu_plurals::{PluralCategory, PluralRules};
+//! use icu_rbnf::options::RuleSetName;
+//! use icu_rbnf::RuleBasedNumberFormatter;
+//!
+//! let formatter = RuleBasedNumberFormatter::try_new_unstable(
+//!     &icu_testdata::unstable(),
+//!     &locale!("ru").into(),
+//!     RuleSetName::SpelloutCardinal,
+//! )
+//! .expect("Data should load successfully");
+//! let plural_rules =
+//!     PluralRules::try_new_cardinal_unstable(&icu_testdata::unstable(), &locale!("ru").into())
+//!         .expect("Data should load successfully");
+//!
+//! let value = FixedDecimal::from(22);
+//! let noun = match plural_rules.category_for(&value) {
+//!     PluralCategory::One => "рубль",
+//!     PluralCategory::Few => "рубля",
+//!     _ => "рублей",
+//! };
+//! assert_eq!(
+//!     format!("{} {}", formatter.format(&value), noun),
+//!     "двадцать два рубля"
+//! );
+//! ```
+//!
+//! ## Algorithmic numbering systems
+//!
+//! Numbering systems such as `roman` or `hebr` write numbers with rules as well. They are
+//! formatted by [`FixedDecimalFormatter`](icu_decimal::FixedDecimalFormatter) for locales with
+//! such a `-u-nu` extension.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(
+    not(test),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::exhaustive_structs,
+        clippy::exhaustive_enums,
+    )
+)]
+#![warn(missing_docs)]
+
+extern crate alloc;
+
+mod error;
+mod format;
+pub mod options;
+pub mod provider;
+mod rbnf;
+
+pub use error::RbnfError;
+pub use format::FormattedRuleBasedNumber;
+pub use rbnf::RuleBasedNumberFormatter;
+
+#[doc(inline)]
+pub use RbnfError as Error;
@@ -0,0 +1,37 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for [`RuleBasedNumberFormatter`](crate::RuleBasedNumberFormatter).
+
+use alloc::borrow::Cow;
+
+/// The rule set that a [`RuleBasedNumberFormatter`](crate::RuleBasedNumberFormatter) formats
+/// numbers with.
+///
+/// In languages whose numbers inflect for gender, such as French, the rule sets
+/// [`SpelloutCardinal`](RuleSetName::SpelloutCardinal) and
+/// [`SpelloutOrdinal`](RuleSetName::SpelloutOrdinal) resolve to the masculine forms; the
+/// other forms can be selected by name.
+#[derive(Debug, Eq, PartialEq, Clone)]
+#[non_exhaustive]
+pub enum RuleSetName {
+    /// Cardinal numbers in words, such as "one hundred twenty-three".
+    SpelloutCardinal,
+    /// Ordinal numbers in words, such as "one hundred twenty-third".
+    SpelloutOrdinal,
+    /// Ordinal numbers in digits, such as "123rd".
+    DigitsOrdinal,
+    /// A public rule set of the CLDR `SpelloutRules` by its name without the leading `%`, such
+    /// as "spellout-cardinal-feminine" or "spellout-numbering-year".
+    Spellout(Cow<'static, str>),
+    /// A public rule set of the CLDR `OrdinalRules` by its name without the leading `%`, such as
+    /// "digits-ordinal-feminine".
+    Ordinal(Cow<'static, str>),
+}
+
+impl Default for RuleSetName {
+    fn default() -> Self {
+        Self::SpelloutCardinal
+    }
+}
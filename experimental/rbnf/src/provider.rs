@@ -0,0 +1,59 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// Provider structs must be stable
+#![allow(clippy::exhaustive_structs)]
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! The rules are the same data structs that `icu_decimal` uses for algorithmic numbering
+//! systems, so they are defined there.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use icu_provider::prelude::*;
+
+pub use icu_decimal::provider::{
+    RbnfRulesV1, Rule, RuleKind, RuleKindULE, RuleSetInfo, RuleSetInfoULE, RuleULE,
+};
+
+// The markers are implemented manually, since the data struct is defined in `icu_decimal`.
+macro_rules! marker {
+    ($marker:ident, $path:literal) => {
+        #[doc = core::concat!("Marker type for [`RbnfRulesV1`]: \"", $path, "\"")]
+        pub struct $marker;
+
+        impl DataMarker for $marker {
+            type Yokeable = RbnfRulesV1<'static>;
+        }
+
+        impl KeyedDataMarker for $marker {
+            const KEY: DataKey = icu_provider::data_key!($path);
+        }
+
+        #[cfg(feature = "datagen")]
+        impl Default for $marker {
+            fn default() -> Self {
+                Self
+            }
+        }
+
+        #[cfg(feature = "datagen")]
+        impl databake::Bake for $marker {
+            fn bake(&self, env: &databake::CrateEnv) -> databake::TokenStream {
+                env.insert("icu_rbnf");
+                databake::quote! { ::icu_rbnf::provider::$marker }
+            }
+        }
+    };
+}
+
+marker!(SpelloutRulesV1Marker, "rbnf/spellout@1");
+marker!(OrdinalRulesV1Marker, "rbnf/ordinal@1");
+
+pub(crate) struct ErasedRbnfRulesV1Marker;
+
+impl DataMarker for ErasedRbnfRulesV1Marker {
+    type Yokeable = RbnfRulesV1<'static>;
+}
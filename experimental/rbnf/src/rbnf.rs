@@ -0,0 +1,172 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::format::{FormattedRuleBasedNumber, RbnfPluralRules};
+use crate::options::RuleSetName;
+use crate::provider::*;
+use crate::RbnfError;
+use fixed_decimal::FixedDecimal;
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_plurals::provider::{CardinalV1Marker, OrdinalV1Marker};
+use icu_plurals::PluralRules;
+use icu_provider::prelude::*;
+
+/// A formatter that renders numbers with a CLDR rule-based number format, such as
+/// "one hundred twenty-three" or "123rd". See the [crate-level documentation](crate) for more
+/// details.
+pub struct RuleBasedNumberFormatter {
+    pub(crate) data: DataPayload<ErasedRbnfRulesV1Marker>,
+    pub(crate) rule_set: RuleSetInfo,
+    pub(crate) plural_rules: RbnfPluralRules,
+    /// The symbols for decimal patterns, such as `=#,##0=`.
+    pub(crate) symbols: DataPayload<DecimalSymbolsV1Marker>,
+}
+
+fn load<M, D>(
+    data_provider: &D,
+    locale: &DataLocale,
+) -> Result<DataPayload<ErasedRbnfRulesV1Marker>, DataError>
+where
+    M: KeyedDataMarker<Yokeable = RbnfRulesV1<'static>>,
+    D: DataProvider<M> + ?Sized,
+{
+    Ok(data_provider
+        .load(DataRequest {
+            locale,
+            metadata: Default::default(),
+        })?
+        .take_payload()?
+        .cast())
+}
+
+impl RuleBasedNumberFormatter {
+    /// Creates a new [`RuleBasedNumberFormatter`] for the given rule set.
+    ///
+    /// Returns [`RbnfError::UnknownRuleSet`] if the locale has no public rule set of that name.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// use icu_locid::locale;
+    /// use icu_rbnf::options::RuleSetName;
+    /// use icu_rbnf::RuleBasedNumberFormatter;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let formatter = RuleBasedNumberFormatter::try_new_unstable(
+    ///     &icu_testdata::unstable(),
+    ///     &locale!("fr").into(),
+    ///     RuleSetName::Spellout("spellout-cardinal-feminine".into()),
+    /// )
+    /// .expect("Data should load successfully");
+    ///
+    /// let value = FixedDecimal::from(21);
+    /// assert_writeable_eq!(formatter.format(&value), "vingt-et-une");
+    ///
+    /// assert!(RuleBasedNumberFormatter::try_new_unstable(
+    ///     &icu_testdata::unstable(),
+    ///     &locale!("fr").into(),
+    ///     RuleSetName::Spellout("no-such-rule-set".into()),
+    /// )
+    /// .is_err());
+    /// ```
+    pub fn try_new_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        rule_set: RuleSetName,
+    ) -> Result<Self, RbnfError>
+    where
+        D: DataProvider<SpelloutRulesV1Marker>
+            + DataProvider<OrdinalRulesV1Marker>
+            + DataProvider<CardinalV1Marker>
+            + DataProvider<OrdinalV1Marker>
+            + DataProvider<DecimalSymbolsV1Marker>
+            + ?Sized,
+    {
+        let (data, names) = match &rule_set {
+            RuleSetName::SpelloutCardinal => (
+                load::<SpelloutRulesV1Marker, _>(data_provider, locale)?,
+                ["spellout-cardinal", "spellout-cardinal-masculine"],
+            ),
+            RuleSetName::SpelloutOrdinal => (
+                load::<SpelloutRulesV1Marker, _>(data_provider, locale)?,
+                ["spellout-ordinal", "spellout-ordinal-masculine"],
+            ),
+            RuleSetName::DigitsOrdinal => (
+                load::<OrdinalRulesV1Marker, _>(data_provider, locale)?,
+                ["digits-ordinal", "digits-ordinal-masculine"],
+            ),
+            RuleSetName::Spellout(name) => (
+                load::<SpelloutRulesV1Marker, _>(data_provider, locale)?,
+                [&**name, &**name],
+            ),
+            RuleSetName::Ordinal(name) => (
+                load::<OrdinalRulesV1Marker, _>(data_provider, locale)?,
+                [&**name, &**name],
+            ),
+        };
+
+        let rule_set = names
+            .iter()
+            .filter_map(|name| data.get().rule_sets.get_copied(name))
+            .find(|info| !info.private)
+            .ok_or(RbnfError::UnknownRuleSet)?;
+
+        Self::try_new_with_rules(data_provider, locale, data, rule_set)
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        rule_set: RuleSetName,
+        error: RbnfError
+    );
+
+    fn try_new_with_rules<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        data: DataPayload<ErasedRbnfRulesV1Marker>,
+        rule_set: RuleSetInfo,
+    ) -> Result<Self, RbnfError>
+    where
+        D: DataProvider<CardinalV1Marker>
+            + DataProvider<OrdinalV1Marker>
+            + DataProvider<DecimalSymbolsV1Marker>
+            + ?Sized,
+    {
+        let plural_rules = RbnfPluralRules {
+            cardinal: PluralRules::try_new_cardinal_unstable(data_provider, locale)?,
+            ordinal: PluralRules::try_new_ordinal_unstable(data_provider, locale)?,
+        };
+        let symbols = data_provider
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+
+        Ok(Self {
+            data,
+            rule_set,
+            plural_rules,
+            symbols,
+        })
+    }
+
+    /// Formats a number with the rule set of this formatter.
+    ///
+    /// Numbers whose integer part does not fit into a `u64` are written with digits, which is
+    /// also what the CLDR rules do for numbers of 10<sup>18</sup> and more. At most 18 fraction
+    /// digits are taken into account.
+    pub fn format<'l>(&'l self, value: &'l FixedDecimal) -> FormattedRuleBasedNumber<'l> {
+        FormattedRuleBasedNumber {
+            formatter: self,
+            value,
+        }
+    }
+}
@@ -0,0 +1,277 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::FixedDecimal;
+use icu_locid::{locale, Locale};
+use icu_provider::prelude::*;
+use icu_rbnf::options::RuleSetName;
+use icu_rbnf::provider::{RbnfRulesV1, Rule, RuleKind, RuleSetInfo, SpelloutRulesV1Marker};
+use icu_rbnf::RuleBasedNumberFormatter;
+use std::borrow::Cow;
+use std::str::FromStr;
+use writeable::assert_writeable_eq;
+use zerovec::{VarZeroVec, ZeroMap};
+
+fn formatter(locale: Locale, rule_set: RuleSetName) -> RuleBasedNumberFormatter {
+    RuleBasedNumberFormatter::try_new_unstable(&icu_testdata::unstable(), &locale.into(), rule_set)
+        .expect("Data should load successfully")
+}
+
+fn assert_format(formatter: &RuleBasedNumberFormatter, input: &str, expected: &str) {
+    let value = FixedDecimal::from_str(input).unwrap();
+    assert_writeable_eq!(formatter.format(&value), expected, "{}", input);
+}
+
+#[test]
+fn test_spellout_cardinal() {
+    let en = formatter(locale!("en"), RuleSetName::SpelloutCardinal);
+    assert_format(&en, "0", "zero");
+    assert_format(&en, "15", "fifteen");
+    assert_format(&en, "42", "forty-two");
+    assert_format(&en, "100", "one hundred");
+    assert_format(&en, "1001", "one thousand one");
+    assert_format(
+        &en,
+        "1234567",
+        "one million two hundred thirty-four thousand five hundred sixty-seven",
+    );
+
+    let fr = formatter(locale!("fr"), RuleSetName::SpelloutCardinal);
+    assert_format(&fr, "21", "vingt-et-un");
+    assert_format(&fr, "71", "soixante-et-onze");
+    assert_format(&fr, "80", "quatre-vingts");
+    assert_format(&fr, "81", "quatre-vingt-un");
+    assert_format(&fr, "99", "quatre-vingt-dix-neuf");
+    assert_format(&fr, "200", "deux cents");
+    assert_format(&fr, "1000", "mille");
+
+    // The masculine rule set is the default, and the others can be selected by name.
+    let es = formatter(locale!("es"), RuleSetName::SpelloutCardinal);
+    assert_format(&es, "21", "veintiún");
+    assert_format(&es, "100", "cien");
+    assert_format(&es, "101", "ciento un");
+    assert_format(&es, "500", "quinientos");
+    assert_format(&es, "1000000", "un millón");
+    let es_feminine = formatter(
+        locale!("es"),
+        RuleSetName::Spellout("spellout-cardinal-feminine".into()),
+    );
+    assert_format(&es_feminine, "21", "veintiuna");
+
+    // The plural form of "thousand" depends on the number of thousands.
+    let ru = formatter(locale!("ru"), RuleSetName::SpelloutCardinal);
+    assert_format(&ru, "21", "двадцать один");
+    assert_format(&ru, "1000", "одна тысяча");
+    assert_format(&ru, "2000", "две тысячи");
+    assert_format(&ru, "5000", "пять тысяч");
+
+    let ja = formatter(locale!("ja"), RuleSetName::SpelloutCardinal);
+    assert_format(&ja, "1234", "千二百三十四");
+    assert_format(&ja, "10000", "一万");
+
+    let tr = formatter(locale!("tr"), RuleSetName::SpelloutCardinal);
+    assert_format(&tr, "15", "on beş");
+    assert_format(&tr, "1000", "bin");
+}
+
+#[test]
+fn test_spellout_ordinal() {
+    let en = formatter(locale!("en"), RuleSetName::SpelloutOrdinal);
+    assert_format(&en, "1", "first");
+    assert_format(&en, "2", "second");
+    assert_format(&en, "3", "third");
+    assert_format(&en, "12", "twelfth");
+    assert_format(&en, "21", "twenty-first");
+    assert_format(&en, "100", "one hundredth");
+    assert_format(&en, "1000000", "one millionth");
+
+    let fr = formatter(locale!("fr"), RuleSetName::SpelloutOrdinal);
+    assert_format(&fr, "1", "premier");
+    assert_format(&fr, "2", "deuxième");
+    assert_format(&fr, "21", "vingt-et-unième");
+    assert_format(&fr, "80", "quatre-vingtième");
+    assert_format(&fr, "100", "centième");
+
+    let es = formatter(locale!("es"), RuleSetName::SpelloutOrdinal);
+    assert_format(&es, "1", "primero");
+    assert_format(&es, "3", "tercero");
+    assert_format(&es, "21", "vigésimo primero");
+
+    let ru = formatter(locale!("ru"), RuleSetName::SpelloutOrdinal);
+    assert_format(&ru, "1", "первый");
+    assert_format(&ru, "40", "сороковой");
+    assert_format(&ru, "1000", "тысячный");
+}
+
+#[test]
+fn test_digits_ordinal() {
+    let en = formatter(locale!("en"), RuleSetName::DigitsOrdinal);
+    assert_format(&en, "0", "0th");
+    assert_format(&en, "1", "1st");
+    assert_format(&en, "2", "2nd");
+    assert_format(&en, "3", "3rd");
+    assert_format(&en, "4", "4th");
+    assert_format(&en, "11", "11th");
+    assert_format(&en, "12", "12th");
+    assert_format(&en, "13", "13th");
+    assert_format(&en, "21", "21st");
+    assert_format(&en, "22", "22nd");
+    assert_format(&en, "111", "111th");
+    assert_format(&en, "1001", "1,001st");
+
+    let fr = formatter(locale!("fr"), RuleSetName::DigitsOrdinal);
+    assert_format(&fr, "1", "1er");
+    assert_format(&fr, "2", "2e");
+
+    // Spanish only groups numbers with five or more digits.
+    let es = formatter(locale!("es"), RuleSetName::DigitsOrdinal);
+    assert_format(&es, "1", "1.º");
+    assert_format(&es, "1000", "1000.º");
+
+    let ru = formatter(locale!("ru"), RuleSetName::DigitsOrdinal);
+    assert_format(&ru, "3", "3");
+    let ru_masculine = formatter(
+        locale!("ru"),
+        RuleSetName::Ordinal("digits-ordinal-masculine".into()),
+    );
+    assert_format(&ru_masculine, "3", "3-й");
+
+    let ja = formatter(locale!("ja"), RuleSetName::DigitsOrdinal);
+    assert_format(&ja, "5", "第5");
+
+    let fil = formatter(locale!("fil"), RuleSetName::DigitsOrdinal);
+    assert_format(&fil, "5", "ika5");
+}
+
+#[test]
+fn test_fractions() {
+    let en = formatter(locale!("en"), RuleSetName::SpelloutCardinal);
+    assert_format(&en, "3.25", "three point two five");
+    assert_format(&en, "0.5", "zero point five");
+    assert_format(&en, "1.125", "one point one two five");
+    // Trailing zeros are not spelled out.
+    assert_format(&en, "2.50", "two point five");
+
+    let fr = formatter(locale!("fr"), RuleSetName::SpelloutCardinal);
+    assert_format(&fr, "1.5", "un virgule cinq");
+
+    // Russian names the denominator, whose plural form depends on the numerator.
+    let ru = formatter(locale!("ru"), RuleSetName::SpelloutCardinal);
+    assert_format(&ru, "1.5", "одна целый пять десятых");
+
+    let ja = formatter(locale!("ja"), RuleSetName::SpelloutCardinal);
+    assert_format(&ja, "0.5", "〇・五");
+
+    // English spelled-out ordinals write fractions with digits.
+    let en_ordinal = formatter(locale!("en"), RuleSetName::SpelloutOrdinal);
+    assert_format(&en_ordinal, "2.5", "2.5");
+
+    // Rule sets without fraction rules round to an integer.
+    let en_digits = formatter(locale!("en"), RuleSetName::DigitsOrdinal);
+    assert_format(&en_digits, "2.5", "3rd");
+}
+
+#[test]
+fn test_negative() {
+    let en = formatter(locale!("en"), RuleSetName::SpelloutCardinal);
+    assert_format(&en, "-7", "minus seven");
+    assert_format(&en, "-0.5", "minus zero point five");
+    assert_format(&en, "-0", "zero");
+
+    let fr = formatter(locale!("fr"), RuleSetName::SpelloutCardinal);
+    assert_format(&fr, "-3", "moins trois");
+
+    let es = formatter(locale!("es"), RuleSetName::SpelloutCardinal);
+    assert_format(&es, "-2.5", "menos dos coma cinco");
+
+    let ja = formatter(locale!("ja"), RuleSetName::SpelloutCardinal);
+    assert_format(&ja, "-5", "マイナス五");
+
+    let en_ordinal = formatter(locale!("en"), RuleSetName::SpelloutOrdinal);
+    assert_format(&en_ordinal, "-1", "minus first");
+
+    let en_digits = formatter(locale!("en"), RuleSetName::DigitsOrdinal);
+    assert_format(&en_digits, "-1", "−1st");
+}
+
+#[test]
+fn test_large_numbers() {
+    let en = formatter(locale!("en"), RuleSetName::SpelloutCardinal);
+    assert_format(&en, "1000000000000000000", "1,000,000,000,000,000,000");
+    // Integers that do not fit into a u64 are written with digits as well.
+    assert_format(&en, "123456789012345678901", "123,456,789,012,345,678,901");
+}
+
+#[test]
+fn test_unknown_rule_set() {
+    assert!(RuleBasedNumberFormatter::try_new_unstable(
+        &icu_testdata::unstable(),
+        &locale!("en").into(),
+        RuleSetName::Spellout("no-such-rule-set".into()),
+    )
+    .is_err());
+    // Private rule sets cannot be selected.
+    assert!(RuleBasedNumberFormatter::try_new_unstable(
+        &icu_testdata::unstable(),
+        &locale!("fr").into(),
+        RuleSetName::Spellout("cents-m".into()),
+    )
+    .is_err());
+}
+
+/// Returns the given rules for [`SpelloutRulesV1Marker`] and the test data for other keys.
+struct RulesProvider(RbnfRulesV1<'static>);
+
+impl AnyProvider for RulesProvider {
+    fn load_any(&self, key: DataKey, req: DataRequest) -> Result<AnyResponse, DataError> {
+        if key == SpelloutRulesV1Marker::KEY {
+            Ok(AnyResponse {
+                metadata: Default::default(),
+                payload: Some(
+                    DataPayload::<SpelloutRulesV1Marker>::from_owned(self.0.clone())
+                        .wrap_into_any_payload(),
+                ),
+            })
+        } else {
+            icu_testdata::any().load_any(key, req)
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(
+    debug_assertions,
+    should_panic(expected = "RBNF rules nest too deeply")
+)]
+fn test_max_depth() {
+    // A rule set that refers to itself for every number.
+    let rule = Rule {
+        kind: RuleKind::Normal,
+        base_value: 0,
+        divisor: 1,
+        text: Cow::Borrowed("a=%spellout-cardinal="),
+    };
+    let info = RuleSetInfo {
+        start: 0,
+        end: 1,
+        private: false,
+        fraction: false,
+    };
+    let provider = RulesProvider(RbnfRulesV1 {
+        rule_sets: vec![("spellout-cardinal", info)]
+            .into_iter()
+            .collect::<ZeroMap<_, _>>(),
+        rules: VarZeroVec::from(&[rule]),
+    });
+
+    let formatter = RuleBasedNumberFormatter::try_new_with_any_provider(
+        &provider,
+        &locale!("en").into(),
+        RuleSetName::SpelloutCardinal,
+    )
+    .expect("Data should load successfully");
+
+    // Without debug assertions, formatting stops after 64 nested rule sets.
+    assert_format(&formatter, "1", &"a".repeat(65));
+}
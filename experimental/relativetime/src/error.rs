@@ -0,0 +1,46 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use displaydoc::Display;
+use icu_decimal::DecimalError;
+use icu_plurals::PluralsError;
+use icu_provider::prelude::DataError;
+
+#[cfg(feature = "std")]
+impl std::error::Error for RelativeTimeError {}
+
+/// A list of error outcomes for various operations in the `icu_relativetime` crate.
+///
+/// Re-exported as [`Error`](crate::Error).
+#[derive(Display, Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum RelativeTimeError {
+    /// An error originating inside of the [data provider](icu_provider).
+    #[displaydoc("{0}")]
+    Data(DataError),
+    /// An error originating from [`PluralRules`](icu_plurals::PluralRules).
+    #[displaydoc("{0}")]
+    PluralRules(PluralsError),
+    /// An error originating from [`FixedDecimalFormatter`](icu_decimal::FixedDecimalFormatter).
+    #[displaydoc("{0}")]
+    FixedDecimalFormatter(DecimalError),
+}
+
+impl From<DataError> for RelativeTimeError {
+    fn from(e: DataError) -> Self {
+        Self::Data(e)
+    }
+}
+
+impl From<PluralsError> for RelativeTimeError {
+    fn from(e: PluralsError) -> Self {
+        Self::PluralRules(e)
+    }
+}
+
+impl From<DecimalError> for RelativeTimeError {
+    fn from(e: DecimalError) -> Self {
+        Self::FixedDecimalFormatter(e)
+    }
+}
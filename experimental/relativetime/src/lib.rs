@@ -0,0 +1,138 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! 🚧 \[Experimental\] Formatting relative times in a locale-sensitive way.
+//!
+//! This module is published as its own crate ([`icu_relativetime`](https://docs.rs/icu_relativetime/latest/icu_relativetime/))
+//! and as part of the [`icu`](https://docs.rs/icu/latest/icu/) crate. See the latter for more details on the ICU4X project.
+//!
+//! <div class="stab unstable">
+//! 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+//! including in SemVer minor releases. It can be enabled with the "experimental" feature
+//! of the icu meta-crate. Use with caution.
+//! </div>
+//!
+//! # Examples
+//!
+//! ## Formatting days in English
+//!
+//! ```
+//! use fixed_decimal::FixedDecimal;
+//! use icu_locid::locale;
+//! use icu_relativetime::{RelativeTimeFormatter, RelativeTimeUnit};
+//! use writeable::assert_writeable_eq;
+//!
+//! let formatter = RelativeTimeFormatter::try_new_unstable(
+//!     &icu_testdata::unstable(),
+//!     &locale!("en").into(),
+//!     RelativeTimeUnit::Day,
+//!     Default::default(),
+//! )
+//! .expect("Data should load successfully");
+//!
+//! assert_writeable_eq!(formatter.format(FixedDecimal::from(-3)), "3 days ago");
+//! assert_writeable_eq!(formatter.format(FixedDecimal::from(1)), "in 1 day");
+//! assert_writeable_eq!(formatter.format(FixedDecimal::from(1500)), "in 1,500 days");
+//! ```
+//!
+//! ## Formatting days in Spanish using dedicated phrases
+//!
+//! ```
+//! use fixed_decimal::FixedDecimal;
+//! use icu_locid::locale;
+//! use icu_relativetime::options::{Numeric, RelativeTimeFormatterOptions};
+//! use icu_relativetime::{RelativeTimeFormatter, RelativeTimeUnit};
+//! use writeable::assert_writeable_eq;
+//!
+//! let mut options = RelativeTimeFormatterOptions::default();
+//! options.numeric = Numeric::Auto;
+//! let formatter = RelativeTimeFormatter::try_new_unstable(
+//!     &icu_testdata::unstable(),
+//!     &locale!("es").into(),
+//!     RelativeTimeUnit::Day,
+//!     options,
+//! )
+//! .expect("Data should load successfully");
+//!
+//! assert_writeable_eq!(formatter.format(FixedDecimal::from(-2)), "anteayer");
+//! assert_writeable_eq!(formatter.format(FixedDecimal::from(0)), "hoy");
+//! assert_writeable_eq!(formatter.format(FixedDecimal::from(5)), "dentro de 5 días");
+//! ```
+//!
+//! ## Formatting weekdays in English
+//!
+//! ```
+//! use fixed_decimal::FixedDecimal;
+//! use icu_calendar::types::IsoWeekday;
+//! use icu_locid::locale;
+//! use icu_relativetime::options::{Numeric, RelativeTimeFormatterOptions};
+//! use icu_relativetime::{RelativeTimeFormatter, RelativeTimeUnit};
+//! use writeable::assert_writeable_eq;
+//!
+//! let mut options = RelativeTimeFormatterOptions::default();
+//! options.numeric = Numeric::Auto;
+//! let formatter = RelativeTimeFormatter::try_new_unstable(
+//!     &icu_testdata::unstable(),
+//!     &locale!("en").into(),
+//!     RelativeTimeUnit::Weekday(IsoWeekday::Monday),
+//!     options,
+//! )
+//! .expect("Data should load successfully");
+//!
+//! assert_writeable_eq!(formatter.format(FixedDecimal::from(1)), "next Monday");
+//! assert_writeable_eq!(formatter.format(FixedDecimal::from(-3)), "3 Mondays ago");
+//! ```
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(
+    not(test),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::exhaustive_structs,
+        clippy::exhaustive_enums,
+    )
+)]
+#![warn(missing_docs)]
+
+extern crate alloc;
+
+mod error;
+pub mod options;
+pub mod provider;
+mod relativetime;
+
+pub use error::RelativeTimeError;
+pub use relativetime::*;
+
+#[doc(inline)]
+pub use RelativeTimeError as Error;
+
+use icu_calendar::types::IsoWeekday;
+
+/// The unit of a relative time, corresponding to a CLDR date field.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[non_exhaustive]
+pub enum RelativeTimeUnit {
+    /// Seconds, as in "in 30 seconds"
+    Second,
+    /// Minutes, as in "5 minutes ago"
+    Minute,
+    /// Hours, as in "in 2 hours"
+    Hour,
+    /// Days, as in "3 days ago" or "yesterday"
+    Day,
+    /// Weeks, as in "in 2 weeks" or "last week"
+    Week,
+    /// Months, as in "6 months ago" or "next month"
+    Month,
+    /// Quarters, as in "in 1 quarter" or "this quarter"
+    Quarter,
+    /// Years, as in "in 10 years" or "last year"
+    Year,
+    /// Occurrences of a particular day of the week, as in "in 2 Mondays" or "next Monday"
+    Weekday(IsoWeekday),
+}
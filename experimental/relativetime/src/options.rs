@@ -0,0 +1,77 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for [`RelativeTimeFormatter`](crate::RelativeTimeFormatter).
+
+/// A bag of options defining how relative times will be formatted by
+/// [`RelativeTimeFormatter`](crate::RelativeTimeFormatter).
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct RelativeTimeFormatterOptions {
+    /// The width of the unit names.
+    pub style: Style,
+    /// Whether to use phrases such as "yesterday" instead of a number where available.
+    pub numeric: Numeric,
+}
+
+/// The width of the unit names in a formatted relative time.
+///
+/// The exact strings depend on the locale; in English, the three styles produce
+/// "in 3 months", "in 3 mo.", and "in 3mo" respectively.
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Style {
+    /// Spelled-out unit names.
+    Long,
+    /// Abbreviated unit names.
+    Short,
+    /// The shortest available unit names.
+    Narrow,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self::Long
+    }
+}
+
+/// Configuration for whether a relative time is always formatted with a number.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_locid::locale;
+/// use icu_relativetime::options::{Numeric, RelativeTimeFormatterOptions};
+/// use icu_relativetime::{RelativeTimeFormatter, RelativeTimeUnit};
+/// use writeable::assert_writeable_eq;
+///
+/// let mut options = RelativeTimeFormatterOptions::default();
+/// options.numeric = Numeric::Auto;
+/// let formatter = RelativeTimeFormatter::try_new_unstable(
+///     &icu_testdata::unstable(),
+///     &locale!("en").into(),
+///     RelativeTimeUnit::Day,
+///     options,
+/// )
+/// .expect("Data should load successfully");
+///
+/// assert_writeable_eq!(formatter.format(FixedDecimal::from(-1)), "yesterday");
+/// assert_writeable_eq!(formatter.format(FixedDecimal::from(-2)), "2 days ago");
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Numeric {
+    /// Always format the number, as in "1 day ago".
+    Always,
+    /// Use a dedicated phrase if the locale has one for the value, as in "yesterday", and
+    /// format the number otherwise.
+    Auto,
+}
+
+impl Default for Numeric {
+    fn default() -> Self {
+        Self::Always
+    }
+}
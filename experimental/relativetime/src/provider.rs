@@ -0,0 +1,186 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// Provider structs must be stable
+#![allow(clippy::exhaustive_structs, clippy::exhaustive_enums)]
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use crate::options::Style;
+use alloc::borrow::Cow;
+use icu_plurals::PluralCategory;
+use icu_provider::prelude::*;
+use icu_provider::{yoke, zerofrom};
+use zerovec::ZeroMap;
+
+/// Relative time patterns for a single CLDR date field in every [`Style`].
+#[icu_provider::data_struct(
+    SecondRelativeTimeFormatDataV1Marker = "relativetime/second@1",
+    MinuteRelativeTimeFormatDataV1Marker = "relativetime/minute@1",
+    HourRelativeTimeFormatDataV1Marker = "relativetime/hour@1",
+    DayRelativeTimeFormatDataV1Marker = "relativetime/day@1",
+    WeekRelativeTimeFormatDataV1Marker = "relativetime/week@1",
+    MonthRelativeTimeFormatDataV1Marker = "relativetime/month@1",
+    QuarterRelativeTimeFormatDataV1Marker = "relativetime/quarter@1",
+    YearRelativeTimeFormatDataV1Marker = "relativetime/year@1",
+    SundayRelativeTimeFormatDataV1Marker = "relativetime/sun@1",
+    MondayRelativeTimeFormatDataV1Marker = "relativetime/mon@1",
+    TuesdayRelativeTimeFormatDataV1Marker = "relativetime/tue@1",
+    WednesdayRelativeTimeFormatDataV1Marker = "relativetime/wed@1",
+    ThursdayRelativeTimeFormatDataV1Marker = "relativetime/thu@1",
+    FridayRelativeTimeFormatDataV1Marker = "relativetime/fri@1",
+    SaturdayRelativeTimeFormatDataV1Marker = "relativetime/sat@1"
+)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_relativetime::provider),
+)]
+#[yoke(prove_covariance_manually)]
+pub struct RelativeTimeFormatDataV1<'data> {
+    /// Patterns for [`Style::Long`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub long: RelativeTimePatternDataV1<'data>,
+    /// Patterns for [`Style::Short`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub short: RelativeTimePatternDataV1<'data>,
+    /// Patterns for [`Style::Narrow`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub narrow: RelativeTimePatternDataV1<'data>,
+}
+
+pub(crate) struct ErasedRelativeTimeFormatDataV1Marker;
+
+impl DataMarker for ErasedRelativeTimeFormatDataV1Marker {
+    type Yokeable = RelativeTimeFormatDataV1<'static>;
+}
+
+impl<'data> RelativeTimeFormatDataV1<'data> {
+    pub(crate) fn get(&self, style: Style) -> &RelativeTimePatternDataV1<'data> {
+        match style {
+            Style::Long => &self.long,
+            Style::Short => &self.short,
+            Style::Narrow => &self.narrow,
+        }
+    }
+}
+
+/// Relative time patterns for a single CLDR date field in a single [`Style`].
+#[derive(Debug, Clone, PartialEq, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_relativetime::provider),
+)]
+#[yoke(prove_covariance_manually)]
+pub struct RelativeTimePatternDataV1<'data> {
+    /// Names for offsets that have a dedicated form, such as -1 ("yesterday") or 0 ("today").
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub relatives: ZeroMap<'data, i8, str>,
+    /// Patterns for offsets in the past.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub past: PluralPatterns<'data>,
+    /// Patterns for offsets in the future.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub future: PluralPatterns<'data>,
+}
+
+/// A [`SingularSubPattern`] for each plural category.
+#[derive(Debug, Clone, PartialEq, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_relativetime::provider),
+)]
+pub struct PluralPatterns<'data> {
+    /// The pattern for [`PluralCategory::Zero`], if it differs from `other`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub zero: Option<SingularSubPattern<'data>>,
+    /// The pattern for [`PluralCategory::One`], if it differs from `other`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub one: Option<SingularSubPattern<'data>>,
+    /// The pattern for [`PluralCategory::Two`], if it differs from `other`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub two: Option<SingularSubPattern<'data>>,
+    /// The pattern for [`PluralCategory::Few`], if it differs from `other`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub few: Option<SingularSubPattern<'data>>,
+    /// The pattern for [`PluralCategory::Many`], if it differs from `other`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub many: Option<SingularSubPattern<'data>>,
+    /// The pattern for [`PluralCategory::Other`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub other: SingularSubPattern<'data>,
+}
+
+impl<'data> PluralPatterns<'data> {
+    pub(crate) fn get(&self, category: PluralCategory) -> &SingularSubPattern<'data> {
+        match category {
+            PluralCategory::Zero => self.zero.as_ref(),
+            PluralCategory::One => self.one.as_ref(),
+            PluralCategory::Two => self.two.as_ref(),
+            PluralCategory::Few => self.few.as_ref(),
+            PluralCategory::Many => self.many.as_ref(),
+            PluralCategory::Other => None,
+        }
+        .unwrap_or(&self.other)
+    }
+}
+
+/// A pattern with at most one placeholder for the number, such as "in {0} days".
+#[derive(Debug, Clone, PartialEq, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_relativetime::provider),
+)]
+pub struct SingularSubPattern<'data> {
+    /// The pattern with the placeholder removed.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub pattern: Cow<'data, str>,
+    /// The byte index in `pattern` at which the number is inserted, or
+    /// [`SingularSubPattern::NO_PLACEHOLDER`] if the pattern does not contain one.
+    pub index: u8,
+}
+
+impl<'data> SingularSubPattern<'data> {
+    /// The `index` of a pattern without a placeholder.
+    pub const NO_PLACEHOLDER: u8 = u8::MAX;
+
+    /// Parses a CLDR pattern that contains at most one `{0}` placeholder.
+    #[cfg(feature = "datagen")]
+    pub fn try_from_str(pattern: &str) -> Result<Self, DataError> {
+        use core::convert::TryFrom;
+        let index = match pattern.find("{0}") {
+            Some(index) => u8::try_from(index)
+                .ok()
+                .filter(|&i| i != Self::NO_PLACEHOLDER)
+                .ok_or_else(|| {
+                    DataError::custom("Placeholder index too large").with_display_context(pattern)
+                })?,
+            None => Self::NO_PLACEHOLDER,
+        };
+        Ok(Self {
+            pattern: Cow::Owned(pattern.replacen("{0}", "", 1)),
+            index,
+        })
+    }
+
+    /// Returns the text before and after the placeholder, or `None` if there is no placeholder.
+    pub(crate) fn split(&self) -> Option<(&str, &str)> {
+        if self.index == Self::NO_PLACEHOLDER {
+            None
+        } else {
+            let index = self.index as usize;
+            self.pattern.get(..index).zip(self.pattern.get(index..))
+        }
+    }
+}
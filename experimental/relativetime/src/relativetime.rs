@@ -0,0 +1,250 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::options::{Numeric, RelativeTimeFormatterOptions};
+use crate::provider::*;
+use crate::{RelativeTimeError, RelativeTimeUnit};
+use core::fmt::{self, Write};
+use fixed_decimal::{FixedDecimal, Sign};
+use icu_calendar::types::IsoWeekday;
+use icu_decimal::options::FixedDecimalFormatterOptions;
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_decimal::FixedDecimalFormatter;
+use icu_plurals::provider::CardinalV1Marker;
+use icu_plurals::PluralRules;
+use icu_provider::prelude::*;
+use writeable::{PartsWrite, Writeable};
+
+/// A formatter that renders a number of units relative to now, such as "3 days ago" or
+/// "in 2 hours". See the [crate-level documentation](crate) for more details.
+pub struct RelativeTimeFormatter {
+    data: DataPayload<ErasedRelativeTimeFormatDataV1Marker>,
+    plural_rules: PluralRules,
+    fixed_decimal_formatter: FixedDecimalFormatter,
+    options: RelativeTimeFormatterOptions,
+}
+
+fn load<M, D>(
+    data_provider: &D,
+    locale: &DataLocale,
+) -> Result<DataPayload<ErasedRelativeTimeFormatDataV1Marker>, DataError>
+where
+    M: KeyedDataMarker<Yokeable = RelativeTimeFormatDataV1<'static>>,
+    D: DataProvider<M> + ?Sized,
+{
+    Ok(data_provider
+        .load(DataRequest {
+            locale,
+            metadata: Default::default(),
+        })?
+        .take_payload()?
+        .cast())
+}
+
+impl RelativeTimeFormatter {
+    /// Creates a new [`RelativeTimeFormatter`] for the given unit.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// use icu_locid::locale;
+    /// use icu_relativetime::options::{RelativeTimeFormatterOptions, Style};
+    /// use icu_relativetime::{RelativeTimeFormatter, RelativeTimeUnit};
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let mut options = RelativeTimeFormatterOptions::default();
+    /// options.style = Style::Short;
+    /// let formatter = RelativeTimeFormatter::try_new_unstable(
+    ///     &icu_testdata::unstable(),
+    ///     &locale!("en").into(),
+    ///     RelativeTimeUnit::Month,
+    ///     options,
+    /// )
+    /// .expect("Data should load successfully");
+    ///
+    /// assert_writeable_eq!(formatter.format(FixedDecimal::from(3)), "in 3 mo.");
+    /// ```
+    pub fn try_new_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        unit: RelativeTimeUnit,
+        options: RelativeTimeFormatterOptions,
+    ) -> Result<Self, RelativeTimeError>
+    where
+        D: DataProvider<SecondRelativeTimeFormatDataV1Marker>
+            + DataProvider<MinuteRelativeTimeFormatDataV1Marker>
+            + DataProvider<HourRelativeTimeFormatDataV1Marker>
+            + DataProvider<DayRelativeTimeFormatDataV1Marker>
+            + DataProvider<WeekRelativeTimeFormatDataV1Marker>
+            + DataProvider<MonthRelativeTimeFormatDataV1Marker>
+            + DataProvider<QuarterRelativeTimeFormatDataV1Marker>
+            + DataProvider<YearRelativeTimeFormatDataV1Marker>
+            + DataProvider<SundayRelativeTimeFormatDataV1Marker>
+            + DataProvider<MondayRelativeTimeFormatDataV1Marker>
+            + DataProvider<TuesdayRelativeTimeFormatDataV1Marker>
+            + DataProvider<WednesdayRelativeTimeFormatDataV1Marker>
+            + DataProvider<ThursdayRelativeTimeFormatDataV1Marker>
+            + DataProvider<FridayRelativeTimeFormatDataV1Marker>
+            + DataProvider<SaturdayRelativeTimeFormatDataV1Marker>
+            + DataProvider<CardinalV1Marker>
+            + DataProvider<DecimalSymbolsV1Marker>
+            + ?Sized,
+    {
+        let data = match unit {
+            RelativeTimeUnit::Second => {
+                load::<SecondRelativeTimeFormatDataV1Marker, _>(data_provider, locale)
+            }
+            RelativeTimeUnit::Minute => {
+                load::<MinuteRelativeTimeFormatDataV1Marker, _>(data_provider, locale)
+            }
+            RelativeTimeUnit::Hour => {
+                load::<HourRelativeTimeFormatDataV1Marker, _>(data_provider, locale)
+            }
+            RelativeTimeUnit::Day => {
+                load::<DayRelativeTimeFormatDataV1Marker, _>(data_provider, locale)
+            }
+            RelativeTimeUnit::Week => {
+                load::<WeekRelativeTimeFormatDataV1Marker, _>(data_provider, locale)
+            }
+            RelativeTimeUnit::Month => {
+                load::<MonthRelativeTimeFormatDataV1Marker, _>(data_provider, locale)
+            }
+            RelativeTimeUnit::Quarter => {
+                load::<QuarterRelativeTimeFormatDataV1Marker, _>(data_provider, locale)
+            }
+            RelativeTimeUnit::Year => {
+                load::<YearRelativeTimeFormatDataV1Marker, _>(data_provider, locale)
+            }
+            RelativeTimeUnit::Weekday(IsoWeekday::Sunday) => {
+                load::<SundayRelativeTimeFormatDataV1Marker, _>(data_provider, locale)
+            }
+            RelativeTimeUnit::Weekday(IsoWeekday::Monday) => {
+                load::<MondayRelativeTimeFormatDataV1Marker, _>(data_provider, locale)
+            }
+            RelativeTimeUnit::Weekday(IsoWeekday::Tuesday) => {
+                load::<TuesdayRelativeTimeFormatDataV1Marker, _>(data_provider, locale)
+            }
+            RelativeTimeUnit::Weekday(IsoWeekday::Wednesday) => {
+                load::<WednesdayRelativeTimeFormatDataV1Marker, _>(data_provider, locale)
+            }
+            RelativeTimeUnit::Weekday(IsoWeekday::Thursday) => {
+                load::<ThursdayRelativeTimeFormatDataV1Marker, _>(data_provider, locale)
+            }
+            RelativeTimeUnit::Weekday(IsoWeekday::Friday) => {
+                load::<FridayRelativeTimeFormatDataV1Marker, _>(data_provider, locale)
+            }
+            RelativeTimeUnit::Weekday(IsoWeekday::Saturday) => {
+                load::<SaturdayRelativeTimeFormatDataV1Marker, _>(data_provider, locale)
+            }
+        }?;
+
+        let plural_rules = PluralRules::try_new_cardinal_unstable(data_provider, locale)?;
+        let fixed_decimal_formatter = FixedDecimalFormatter::try_new_unstable(
+            data_provider,
+            locale,
+            FixedDecimalFormatterOptions::default(),
+        )?;
+
+        Ok(Self {
+            data,
+            plural_rules,
+            fixed_decimal_formatter,
+            options,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        unit: RelativeTimeUnit,
+        options: RelativeTimeFormatterOptions,
+        error: RelativeTimeError
+    );
+
+    /// Formats a number of units relative to now. Negative values are in the past, all other
+    /// values are in the future.
+    ///
+    /// Returns a [`Writeable`] whose parts are [`parts::LITERAL`] for the text of the pattern,
+    /// and the parts of [`FixedDecimalFormatter`] for the number, if any.
+    pub fn format(&self, value: FixedDecimal) -> FormattedRelativeTime<'_> {
+        let is_negative = value.sign() == Sign::Negative;
+        FormattedRelativeTime {
+            formatter: self,
+            value: value.with_sign(Sign::None),
+            is_negative,
+        }
+    }
+}
+
+/// The [`Part`](writeable::Part)s used by [`RelativeTimeFormatter`].
+pub mod parts {
+    use writeable::Part;
+
+    /// The [`Part`] used by [`FormattedRelativeTime`](super::FormattedRelativeTime) to mark the
+    /// text surrounding the number, or the whole output if it has no number.
+    pub const LITERAL: Part = Part {
+        category: "relativetime",
+        value: "literal",
+    };
+}
+
+/// The [`Writeable`] implementation that is returned by [`RelativeTimeFormatter::format`]. See
+/// the [`writeable`] crate for how to consume this.
+pub struct FormattedRelativeTime<'a> {
+    formatter: &'a RelativeTimeFormatter,
+    value: FixedDecimal,
+    is_negative: bool,
+}
+
+impl<'a> FormattedRelativeTime<'a> {
+    /// The offset as an `i8`, if `value` is a small integer without displayed fraction digits.
+    fn small_offset(&self) -> Option<i8> {
+        if *self.value.magnitude_range().start() != 0 || *self.value.magnitude_range().end() != 0 {
+            return None;
+        }
+        let digit = self.value.digit_at(0) as i8;
+        Some(if self.is_negative { -digit } else { digit })
+    }
+}
+
+impl<'a> Writeable for FormattedRelativeTime<'a> {
+    fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        let data = self.formatter.data.get().get(self.formatter.options.style);
+
+        if self.formatter.options.numeric == Numeric::Auto {
+            if let Some(relative) = self
+                .small_offset()
+                .and_then(|offset| data.relatives.get(&offset))
+            {
+                return sink.with_part(parts::LITERAL, |s| s.write_str(relative));
+            }
+        }
+
+        let patterns = if self.is_negative {
+            &data.past
+        } else {
+            &data.future
+        };
+        let pattern = patterns.get(self.formatter.plural_rules.category_for(&self.value));
+
+        match pattern.split() {
+            Some((before, after)) => {
+                sink.with_part(parts::LITERAL, |s| s.write_str(before))?;
+                self.formatter
+                    .fixed_decimal_formatter
+                    .format(&self.value)
+                    .write_to_parts(sink)?;
+                sink.with_part(parts::LITERAL, |s| s.write_str(after))
+            }
+            None => sink.with_part(parts::LITERAL, |s| s.write_str(&pattern.pattern)),
+        }
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedRelativeTime<'_>);
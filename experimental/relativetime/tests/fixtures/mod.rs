@@ -0,0 +1,99 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_calendar::types::IsoWeekday;
+use icu_relativetime::options::{Numeric, Style};
+use icu_relativetime::RelativeTimeUnit;
+use serde::Deserialize;
+
+/// A formatter configuration and the values to format with it.
+#[derive(Deserialize)]
+pub struct RelativeTimeTest {
+    pub locale: String,
+    pub unit: UnitInput,
+    pub style: StyleInput,
+    pub numeric: NumericInput,
+    pub cases: Vec<RelativeTimeCase>,
+}
+
+/// A decimal number and its expected formatting.
+#[derive(Deserialize)]
+pub struct RelativeTimeCase {
+    pub input: String,
+    pub output: String,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UnitInput {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl From<UnitInput> for RelativeTimeUnit {
+    fn from(other: UnitInput) -> Self {
+        match other {
+            UnitInput::Second => RelativeTimeUnit::Second,
+            UnitInput::Minute => RelativeTimeUnit::Minute,
+            UnitInput::Hour => RelativeTimeUnit::Hour,
+            UnitInput::Day => RelativeTimeUnit::Day,
+            UnitInput::Week => RelativeTimeUnit::Week,
+            UnitInput::Month => RelativeTimeUnit::Month,
+            UnitInput::Quarter => RelativeTimeUnit::Quarter,
+            UnitInput::Year => RelativeTimeUnit::Year,
+            UnitInput::Monday => RelativeTimeUnit::Weekday(IsoWeekday::Monday),
+            UnitInput::Tuesday => RelativeTimeUnit::Weekday(IsoWeekday::Tuesday),
+            UnitInput::Wednesday => RelativeTimeUnit::Weekday(IsoWeekday::Wednesday),
+            UnitInput::Thursday => RelativeTimeUnit::Weekday(IsoWeekday::Thursday),
+            UnitInput::Friday => RelativeTimeUnit::Weekday(IsoWeekday::Friday),
+            UnitInput::Saturday => RelativeTimeUnit::Weekday(IsoWeekday::Saturday),
+            UnitInput::Sunday => RelativeTimeUnit::Weekday(IsoWeekday::Sunday),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub enum StyleInput {
+    Long,
+    Short,
+    Narrow,
+}
+
+impl From<StyleInput> for Style {
+    fn from(other: StyleInput) -> Self {
+        match other {
+            StyleInput::Long => Style::Long,
+            StyleInput::Short => Style::Short,
+            StyleInput::Narrow => Style::Narrow,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub enum NumericInput {
+    Always,
+    Auto,
+}
+
+impl From<NumericInput> for Numeric {
+    fn from(other: NumericInput) -> Self {
+        match other {
+            NumericInput::Always => Numeric::Always,
+            NumericInput::Auto => Numeric::Auto,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+mod fixtures;
+mod helpers;
+
+use fixed_decimal::FixedDecimal;
+use icu_locid::Locale;
+use icu_relativetime::options::RelativeTimeFormatterOptions;
+use icu_relativetime::RelativeTimeFormatter;
+use std::str::FromStr;
+use writeable::Writeable;
+
+#[test]
+fn test_relativetime() {
+    let path = "./tests/fixtures/relativetime.json";
+    let test_set: Vec<fixtures::RelativeTimeTest> =
+        helpers::read_fixture(path).expect("Failed to read a fixture");
+
+    for test in test_set {
+        let mut options = RelativeTimeFormatterOptions::default();
+        options.style = test.style.into();
+        options.numeric = test.numeric.into();
+        let formatter = RelativeTimeFormatter::try_new_unstable(
+            &icu_testdata::unstable(),
+            &Locale::from_str(&test.locale).unwrap().into(),
+            test.unit.into(),
+            options,
+        )
+        .unwrap();
+
+        for case in test.cases {
+            let value = FixedDecimal::from_str(&case.input).unwrap();
+            assert_eq!(
+                formatter.format(value).write_to_string(),
+                case.output,
+                "\n\
+                {}({:?}, {:?}, {:?}): {}\n\
+                ",
+                test.locale,
+                test.unit,
+                test.style,
+                test.numeric,
+                case.input,
+            );
+        }
+    }
+}
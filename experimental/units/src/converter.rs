@@ -0,0 +1,302 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::measure_unit::{parse_product, Prefix};
+use crate::provider::*;
+use crate::{MeasureUnit, Ratio, UnitsError};
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use icu_provider::prelude::*;
+use icu_provider::zerofrom::ZeroFrom;
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{One, Zero};
+
+/// A factory for [`UnitsConverter`]s, backed by the CLDR supplemental unit data.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_units::{ConverterFactory, Ratio, UnitsError};
+///
+/// let factory = ConverterFactory::try_new_unstable(&icu_testdata::unstable())
+///     .expect("Data should load successfully");
+///
+/// let converter = factory
+///     .converter(
+///         &"mile-per-hour".parse().unwrap(),
+///         &"kilometer-per-hour".parse().unwrap(),
+///     )
+///     .expect("the units are convertible");
+/// let value = converter.convert(&Ratio::from(60));
+/// assert_eq!(value.to_fixed_decimal(-3).to_string(), "96.561");
+///
+/// let converter = factory
+///     .converter(
+///         &"fahrenheit".parse().unwrap(),
+///         &"celsius".parse().unwrap(),
+///     )
+///     .expect("the units are convertible");
+/// let value = converter.convert(&Ratio::from(-40));
+/// assert_eq!(value.to_fixed_decimal(0).to_string(), "-40");
+///
+/// assert_eq!(
+///     factory
+///         .converter(&"meter".parse().unwrap(), &"second".parse().unwrap())
+///         .err(),
+///     Some(UnitsError::NotConvertible)
+/// );
+/// ```
+pub struct ConverterFactory {
+    pub(crate) info: DataPayload<UnitsInfoV1Marker>,
+}
+
+/// A unit expressed in base units: `value_in_base_units = value * factor + offset`.
+struct BaseUnits {
+    factor: BigRational,
+    offset: BigRational,
+    /// The exponent of each base unit in the numerator of the unit, such as `{meter: 3}` for
+    /// "liter-per-100-kilometer".
+    numerator: BTreeMap<String, i16>,
+    /// The exponent of each base unit in the denominator of the unit, such as `{meter: 1}`
+    /// for "liter-per-100-kilometer".
+    denominator: BTreeMap<String, i16>,
+}
+
+impl BaseUnits {
+    /// The exponent of each base unit, with the numerator and denominator cancelled out.
+    fn dimensions(&self) -> BTreeMap<String, i16> {
+        let mut dimensions = self.numerator.clone();
+        for (unit, &power) in &self.denominator {
+            *dimensions.entry(unit.clone()).or_default() -= power;
+        }
+        dimensions.retain(|_, power| *power != 0);
+        dimensions
+    }
+}
+
+impl ConverterFactory {
+    /// Creates a new [`ConverterFactory`] from the CLDR supplemental unit data.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_unstable<D>(data_provider: &D) -> Result<Self, UnitsError>
+    where
+        D: DataProvider<UnitsInfoV1Marker> + ?Sized,
+    {
+        let info = data_provider.load(Default::default())?.take_payload()?;
+        Ok(Self { info })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(locale: skip, options: skip, error: UnitsError);
+
+    /// Creates a [`UnitsConverter`] from `input` to `output`.
+    ///
+    /// Units of reciprocal quantities, such as "liter-per-100-kilometer" and "mile-per-gallon",
+    /// are also convertible.
+    pub fn converter(
+        &self,
+        input: &MeasureUnit,
+        output: &MeasureUnit,
+    ) -> Result<UnitsConverter, UnitsError> {
+        let input = self.base_units(input.as_str())?;
+        let output = self.base_units(output.as_str())?;
+        let input_dimensions = input.dimensions();
+        let output_dimensions = output.dimensions();
+
+        if input_dimensions == output_dimensions {
+            Ok(UnitsConverter {
+                factor: &input.factor / &output.factor,
+                offset: (&input.offset - &output.offset) / &output.factor,
+                reciprocal: false,
+            })
+        } else if input_dimensions
+            .iter()
+            .map(|(unit, &power)| (unit, -power))
+            .eq(output_dimensions.iter().map(|(unit, &power)| (unit, power)))
+        {
+            Ok(UnitsConverter {
+                factor: &input.factor * &output.factor,
+                offset: BigRational::zero(),
+                reciprocal: true,
+            })
+        } else {
+            Err(UnitsError::NotConvertible)
+        }
+    }
+
+    /// Returns the quantity measured by a unit, such as "length" for "foot".
+    pub(crate) fn quantity(&self, unit: &str) -> Result<&str, UnitsError> {
+        let info = self.info.get();
+        let unit = self.base_units(unit)?;
+        let dimensions = unit.dimensions();
+        let mut quantities = info
+            .quantities
+            .iter()
+            .filter_map(|(base_unit, quantity)| Some((self.base_units(base_unit).ok()?, quantity)));
+        // Quantities such as area ("square-meter") and consumption ("cubic-meter-per-meter")
+        // have the same dimensions, so they are first matched without cancelling out the
+        // numerator and the denominator.
+        let mut by_dimensions = None;
+        for (base_units, quantity) in &mut quantities {
+            if base_units.numerator == unit.numerator && base_units.denominator == unit.denominator
+            {
+                return Ok(quantity);
+            }
+            if by_dimensions.is_none() && base_units.dimensions() == dimensions {
+                by_dimensions = Some(quantity);
+            }
+        }
+        by_dimensions.ok_or(UnitsError::UnknownUnit)
+    }
+
+    /// Expresses a unit, which may be a product of units divided by another product of units,
+    /// in base units.
+    fn base_units(&self, unit: &str) -> Result<BaseUnits, UnitsError> {
+        let mut result = BaseUnits {
+            factor: BigRational::one(),
+            offset: BigRational::zero(),
+            numerator: BTreeMap::new(),
+            denominator: BTreeMap::new(),
+        };
+        let (numerator, denominator) = match unit.split_once("-per-") {
+            Some((numerator, denominator)) => (numerator, Some(denominator)),
+            None => (unit, None),
+        };
+        let numerator = self.single_units(numerator)?;
+        let denominator = match denominator {
+            Some(denominator) => self.single_units(denominator)?,
+            None => Default::default(),
+        };
+
+        // Offsets, such as for "celsius", only apply to units that are not compound.
+        if let [(info, Prefix::None, 1)] = numerator.as_slice() {
+            if denominator.is_empty() {
+                result.offset = ratio(
+                    info.offset_numerator.as_ule_slice(),
+                    info.offset_denominator.as_ule_slice(),
+                )?;
+            }
+        }
+
+        for (units, inverse) in [(numerator, false), (denominator, true)] {
+            for (info, prefix, power) in units {
+                let mut factor = ratio(
+                    info.factor_numerator.as_ule_slice(),
+                    info.factor_denominator.as_ule_slice(),
+                )?;
+                match prefix {
+                    Prefix::None => {}
+                    Prefix::Decimal(exponent) => {
+                        factor *= BigRational::from_integer(BigInt::from(10)).pow(exponent.into())
+                    }
+                    Prefix::Binary(exponent) => {
+                        factor *= BigRational::from_integer(BigInt::from(1024)).pow(exponent.into())
+                    }
+                }
+                let power = i32::from(power);
+                if inverse {
+                    result.factor /= factor.pow(power);
+                } else {
+                    result.factor *= factor.pow(power);
+                }
+                self.add_base_unit(&mut result, &info.base_unit, power, inverse)?;
+            }
+        }
+        Ok(result)
+    }
+
+    /// Adds the base units of a simple unit to the numerator and denominator of `result`.
+    fn add_base_unit(
+        &self,
+        result: &mut BaseUnits,
+        base_unit: &str,
+        power: i32,
+        inverse: bool,
+    ) -> Result<(), UnitsError> {
+        let is_known = |unit: &str| self.info.get().conversions.get(unit).is_some();
+        let (numerator, denominator) = match base_unit.split_once("-per-") {
+            Some((numerator, denominator)) => (numerator, Some(denominator)),
+            None => (base_unit, None),
+        };
+        for (part, part_inverse) in [(Some(numerator), inverse), (denominator, !inverse)] {
+            for single in parse_product(part.unwrap_or_default(), is_known)
+                .into_iter()
+                .flatten()
+            {
+                let map = if part_inverse {
+                    &mut result.denominator
+                } else {
+                    &mut result.numerator
+                };
+                let power = i16::try_from(power * i32::from(single.power))
+                    .map_err(|_| UnitsError::InvalidUnit)?;
+                *map.entry(single.base.into()).or_default() += power;
+            }
+        }
+        Ok(())
+    }
+
+    /// Splits a product of units into simple units with their conversion data.
+    fn single_units(
+        &self,
+        unit: &str,
+    ) -> Result<Vec<(ConversionInfo<'_>, Prefix, u8)>, UnitsError> {
+        let conversions = &self.info.get().conversions;
+        parse_product(unit, |unit| conversions.get(unit).is_some())
+            .ok_or(UnitsError::UnknownUnit)?
+            .into_iter()
+            .map(|single| {
+                conversions
+                    .get(single.base)
+                    .map(|info| (ConversionInfo::zero_from(info), single.prefix, single.power))
+                    .ok_or(UnitsError::UnknownUnit)
+            })
+            .collect()
+    }
+}
+
+fn ratio(numerator: &[u8], denominator: &[u8]) -> Result<BigRational, UnitsError> {
+    if numerator.is_empty() {
+        return Ok(BigRational::zero());
+    }
+    Ratio::from_be_bytes(numerator, denominator)
+        .map(|ratio| ratio.0)
+        .ok_or(UnitsError::Data(DataError::custom(
+            "Invalid unit conversion factor",
+        )))
+}
+
+/// A converter from one unit to another, created by [`ConverterFactory::converter`].
+///
+/// The conversion is exact.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnitsConverter {
+    factor: BigRational,
+    offset: BigRational,
+    /// Whether the units measure reciprocal quantities, such as fuel consumption in
+    /// "liter-per-100-kilometer" and fuel economy in "mile-per-gallon".
+    reciprocal: bool,
+}
+
+impl UnitsConverter {
+    /// Converts a value in the input unit into the output unit.
+    ///
+    /// For reciprocal units, zero is converted to zero.
+    pub fn convert(&self, value: &Ratio) -> Ratio {
+        if self.reciprocal {
+            if value.0.is_zero() {
+                return Ratio(BigRational::zero());
+            }
+            Ratio((&value.0 * &self.factor).recip())
+        } else {
+            Ratio(&value.0 * &self.factor + &self.offset)
+        }
+    }
+}
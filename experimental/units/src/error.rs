@@ -0,0 +1,68 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use displaydoc::Display;
+use icu_decimal::DecimalError;
+use icu_locid_transform::LocaleTransformError;
+use icu_plurals::PluralsError;
+use icu_provider::prelude::DataError;
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnitsError {}
+
+/// A list of error outcomes for various operations in the `icu_units` crate.
+///
+/// Re-exported as [`Error`](crate::Error).
+#[derive(Display, Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum UnitsError {
+    /// An error originating inside of the [data provider](icu_provider).
+    #[displaydoc("{0}")]
+    Data(DataError),
+    /// An error originating from [`PluralRules`](icu_plurals::PluralRules).
+    #[displaydoc("{0}")]
+    PluralRules(PluralsError),
+    /// An error originating from [`FixedDecimalFormatter`](icu_decimal::FixedDecimalFormatter).
+    #[displaydoc("{0}")]
+    FixedDecimalFormatter(DecimalError),
+    /// An error originating from [`LocaleExpander`](icu_locid_transform::LocaleExpander).
+    #[displaydoc("{0}")]
+    LocaleTransform(LocaleTransformError),
+    /// The string is not a syntactically valid unit identifier.
+    #[displaydoc("Invalid unit identifier")]
+    InvalidUnit,
+    /// The unit identifier contains a unit that is not known to the data.
+    #[displaydoc("Unknown unit")]
+    UnknownUnit,
+    /// The units measure different quantities, such as length and time.
+    #[displaydoc("The units are not convertible")]
+    NotConvertible,
+    /// The data has no unit preferences for the quantity measured by the unit.
+    #[displaydoc("No unit preferences for the quantity")]
+    NoPreferences,
+}
+
+impl From<DataError> for UnitsError {
+    fn from(e: DataError) -> Self {
+        Self::Data(e)
+    }
+}
+
+impl From<PluralsError> for UnitsError {
+    fn from(e: PluralsError) -> Self {
+        Self::PluralRules(e)
+    }
+}
+
+impl From<DecimalError> for UnitsError {
+    fn from(e: DecimalError) -> Self {
+        Self::FixedDecimalFormatter(e)
+    }
+}
+
+impl From<LocaleTransformError> for UnitsError {
+    fn from(e: LocaleTransformError) -> Self {
+        Self::LocaleTransform(e)
+    }
+}
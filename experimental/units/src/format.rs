@@ -0,0 +1,51 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::UnitsFormatter;
+use core::fmt::{self, Write};
+use fixed_decimal::FixedDecimal;
+use writeable::{PartsWrite, Writeable};
+
+/// The [`Part`](writeable::Part)s used by [`UnitsFormatter`].
+pub mod parts {
+    use writeable::Part;
+
+    /// The [`Part`] used by [`FormattedUnit`](super::FormattedUnit) to mark the text of the
+    /// pattern surrounding the number, such as " km" in "3 km".
+    pub const UNIT: Part = Part {
+        category: "units",
+        value: "unit",
+    };
+}
+
+/// The [`Writeable`] implementation that is returned by [`UnitsFormatter::format`]. See the
+/// [`writeable`] crate for how to consume this.
+pub struct FormattedUnit<'l> {
+    pub(crate) formatter: &'l UnitsFormatter,
+    pub(crate) value: FixedDecimal,
+    pub(crate) pattern: &'l str,
+}
+
+impl<'l> Writeable for FormattedUnit<'l> {
+    fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        let (prefix, suffix) = match self.pattern.split_once("{0}") {
+            Some(split) => split,
+            // Some patterns, such as "half an hour", don't display the number.
+            None => return sink.with_part(parts::UNIT, |s| s.write_str(self.pattern)),
+        };
+        if !prefix.is_empty() {
+            sink.with_part(parts::UNIT, |s| s.write_str(prefix))?;
+        }
+        self.formatter
+            .fixed_decimal_formatter
+            .format(&self.value)
+            .write_to_parts(sink)?;
+        if !suffix.is_empty() {
+            sink.with_part(parts::UNIT, |s| s.write_str(suffix))?;
+        }
+        Ok(())
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedUnit<'_>);
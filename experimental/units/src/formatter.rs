@@ -0,0 +1,281 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::format::FormattedUnit;
+use crate::measure_unit::{parse_product, Prefix, SingleUnit};
+use crate::options::*;
+use crate::provider::*;
+use crate::{MeasureUnit, UnitsError};
+use alloc::string::String;
+use fixed_decimal::FixedDecimal;
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_decimal::FixedDecimalFormatter;
+use icu_plurals::provider::CardinalV1Marker;
+use icu_plurals::{PluralCategory, PluralRules};
+use icu_provider::prelude::*;
+
+/// A formatter that renders measurements in a locale-sensitive way, such as "3 km" or
+/// "5 kilometers per hour". See the [crate-level documentation](crate) for more details.
+pub struct UnitsFormatter {
+    pub(crate) fixed_decimal_formatter: FixedDecimalFormatter,
+    plural_rules: PluralRules,
+    patterns: UnitPatterns,
+}
+
+/// The patterns of the unit of a [`UnitsFormatter`] for each plural category.
+struct UnitPatterns {
+    zero: String,
+    one: String,
+    two: String,
+    few: String,
+    many: String,
+    other: String,
+}
+
+impl UnitPatterns {
+    fn get(&self, category: PluralCategory) -> &str {
+        match category {
+            PluralCategory::Zero => &self.zero,
+            PluralCategory::One => &self.one,
+            PluralCategory::Two => &self.two,
+            PluralCategory::Few => &self.few,
+            PluralCategory::Many => &self.many,
+            PluralCategory::Other => &self.other,
+        }
+    }
+}
+
+fn load<M, D>(
+    data_provider: &D,
+    locale: &DataLocale,
+) -> Result<DataPayload<ErasedUnitsFormatDataV1Marker>, DataError>
+where
+    M: KeyedDataMarker<Yokeable = UnitsFormatDataV1<'static>>,
+    D: DataProvider<M> + ?Sized,
+{
+    Ok(data_provider
+        .load(DataRequest {
+            locale,
+            metadata: Default::default(),
+        })?
+        .take_payload()?
+        .cast())
+}
+
+impl UnitsFormatter {
+    /// Creates a new [`UnitsFormatter`] for a unit from locale data and an options bag.
+    ///
+    /// Units without their own pattern, such as "gigabyte-per-second" or "cubic-millimeter",
+    /// are built from the patterns of their parts.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// use icu_locid::locale;
+    /// use icu_units::options::Width;
+    /// use icu_units::{UnitsError, UnitsFormatter};
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let formatter = UnitsFormatter::try_new_unstable(
+    ///     &icu_testdata::unstable(),
+    ///     &locale!("en").into(),
+    ///     "cubic-millimeter".parse().unwrap(),
+    ///     Width::Long.into(),
+    /// )
+    /// .expect("Data should load successfully");
+    ///
+    /// assert_writeable_eq!(
+    ///     formatter.format(FixedDecimal::from(2)),
+    ///     "2 cubic millimeters"
+    /// );
+    ///
+    /// assert_eq!(
+    ///     UnitsFormatter::try_new_unstable(
+    ///         &icu_testdata::unstable(),
+    ///         &locale!("en").into(),
+    ///         "furlong-per-fortnight".parse().unwrap(),
+    ///         Default::default(),
+    ///     )
+    ///     .err(),
+    ///     Some(UnitsError::UnknownUnit)
+    /// );
+    /// ```
+    pub fn try_new_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        unit: MeasureUnit,
+        options: UnitsFormatterOptions,
+    ) -> Result<Self, UnitsError>
+    where
+        D: DataProvider<LongUnitsFormatDataV1Marker>
+            + DataProvider<ShortUnitsFormatDataV1Marker>
+            + DataProvider<NarrowUnitsFormatDataV1Marker>
+            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<CardinalV1Marker>
+            + ?Sized,
+    {
+        let data = match options.width {
+            Width::Long => load::<LongUnitsFormatDataV1Marker, _>(data_provider, locale),
+            Width::Short => load::<ShortUnitsFormatDataV1Marker, _>(data_provider, locale),
+            Width::Narrow => load::<NarrowUnitsFormatDataV1Marker, _>(data_provider, locale),
+        }?;
+        let data = data.get();
+        let resolve = |category| {
+            resolve_pattern(data, unit.as_str(), category).ok_or(UnitsError::UnknownUnit)
+        };
+        let patterns = UnitPatterns {
+            zero: resolve(PluralCategory::Zero)?,
+            one: resolve(PluralCategory::One)?,
+            two: resolve(PluralCategory::Two)?,
+            few: resolve(PluralCategory::Few)?,
+            many: resolve(PluralCategory::Many)?,
+            other: resolve(PluralCategory::Other)?,
+        };
+
+        let plural_rules = PluralRules::try_new_cardinal_unstable(data_provider, locale)?;
+        let fixed_decimal_formatter = FixedDecimalFormatter::try_new_unstable(
+            data_provider,
+            locale,
+            options.fixed_decimal_formatter_options,
+        )?;
+
+        Ok(Self {
+            fixed_decimal_formatter,
+            plural_rules,
+            patterns,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        unit: MeasureUnit,
+        options: UnitsFormatterOptions,
+        error: UnitsError
+    );
+
+    /// Formats a number in the unit of this formatter.
+    ///
+    /// The number is not rounded; round it with the methods on [`FixedDecimal`] before
+    /// formatting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// use icu_locid::locale;
+    /// use icu_units::UnitsFormatter;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let formatter = UnitsFormatter::try_new_unstable(
+    ///     &icu_testdata::unstable(),
+    ///     &locale!("ru").into(),
+    ///     "kilometer-per-hour".parse().unwrap(),
+    ///     Default::default(),
+    /// )
+    /// .expect("Data should load successfully");
+    ///
+    /// assert_writeable_eq!(
+    ///     formatter.format(FixedDecimal::from(55)),
+    ///     "55 км/ч"
+    /// );
+    /// ```
+    pub fn format(&self, value: FixedDecimal) -> FormattedUnit<'_> {
+        FormattedUnit {
+            formatter: self,
+            pattern: self.patterns.get(self.plural_rules.category_for(&value)),
+            value,
+        }
+    }
+}
+
+/// Returns the pattern of a unit for a plural category, built from the patterns of its parts
+/// if the unit has no pattern of its own, or `None` if the unit is unknown.
+fn resolve_pattern(
+    data: &UnitsFormatDataV1,
+    unit: &str,
+    category: PluralCategory,
+) -> Option<String> {
+    if let Some(pattern) = data.patterns.get(category, unit) {
+        return Some(pattern.into());
+    }
+    let (numerator, denominator) = match unit.split_once("-per-") {
+        Some((numerator, denominator)) => (numerator, Some(denominator)),
+        None => (unit, None),
+    };
+    let is_known = |unit: &str| data.patterns.contains(unit);
+
+    let numerator_units = parse_product(numerator, is_known)?;
+    let mut numerator_units = numerator_units.iter();
+    let mut pattern = single_unit_pattern(data, numerator_units.next()?, category)?;
+    for unit in numerator_units {
+        let name = single_unit_name(data, unit)?;
+        pattern = substitute(&substitute(&data.times, "{1}", &name), "{0}", &pattern);
+    }
+
+    if let Some(denominator) = denominator {
+        if let Some(per_unit_pattern) = data.per_unit_patterns.get(denominator) {
+            pattern = substitute(per_unit_pattern, "{0}", &pattern);
+        } else {
+            let mut name = String::new();
+            for unit in parse_product(denominator, is_known)? {
+                let unit_name = single_unit_name(data, &unit)?;
+                name = if name.is_empty() {
+                    unit_name
+                } else {
+                    substitute(&substitute(&data.times, "{1}", &unit_name), "{0}", &name)
+                };
+            }
+            pattern = substitute(&substitute(&data.per, "{1}", &name), "{0}", &pattern);
+        }
+    }
+    Some(pattern)
+}
+
+/// Returns the pattern of a single unit, such as "{0} square kilometers".
+fn single_unit_pattern(
+    data: &UnitsFormatDataV1,
+    unit: &SingleUnit,
+    category: PluralCategory,
+) -> Option<String> {
+    let mut pattern: String = data.patterns.get(category, unit.base)?.into();
+    match unit.prefix {
+        Prefix::None => {}
+        Prefix::Decimal(power) => pattern = inflect(&pattern, data.decimal_prefixes.get(&power)?),
+        Prefix::Binary(power) => pattern = inflect(&pattern, data.binary_prefixes.get(&power)?),
+    }
+    match unit.power {
+        1 => {}
+        2 => pattern = inflect(&pattern, data.power2.get(category)),
+        3 => pattern = inflect(&pattern, data.power3.get(category)),
+        _ => return None,
+    }
+    Some(pattern)
+}
+
+/// Returns the name of a single unit for use in a compound unit, such as "square kilometer".
+fn single_unit_name(data: &UnitsFormatDataV1, unit: &SingleUnit) -> Option<String> {
+    let pattern = single_unit_pattern(data, unit, PluralCategory::One)?;
+    Some(pattern.replacen("{0}", "", 1).trim().into())
+}
+
+/// Applies a prefix or power pattern such as "kilo{0}" to the unit name in a unit pattern,
+/// such as "{0} meters".
+fn inflect(pattern: &str, inflection: &str) -> String {
+    let without_placeholder = pattern.replacen("{0}", "", 1);
+    let name = without_placeholder.trim();
+    if name.is_empty() {
+        return pattern.into();
+    }
+    pattern.replacen(name, &substitute(inflection, "{0}", name), 1)
+}
+
+fn substitute(pattern: &str, placeholder: &str, value: &str) -> String {
+    pattern.replacen(placeholder, value, 1)
+}
@@ -0,0 +1,167 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! 🚧 \[Experimental\] Formatting measurements, such as "3 km" or "5 kilometers per hour", in a
+//! locale-sensitive way, and converting them into the units preferred by a locale.
+//!
+//! This module is published as its own crate ([`icu_units`](https://docs.rs/icu_units/latest/icu_units/))
+//! and as part of the [`icu`](https://docs.rs/icu/latest/icu/) crate. See the latter for more details on the ICU4X project.
+//!
+//! <div class="stab unstable">
+//! 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+//! including in SemVer minor releases. It can be enabled with the "experimental" feature
+//! of the icu meta-crate. Use with caution.
+//! </div>
+//!
+//! Units are identified by their CLDR core unit identifier, such as "kilometer" or
+//! "kilometer-per-hour". Units for which the locale has no pattern of its own, such as
+//! "gigabyte-per-second" or "cubic-millimeter", are built from the patterns of their parts.
+//! The pattern is chosen by the plural category of the number.
+//!
+//! # Examples
+//!
+//! ## Formatting simple units
+//!
+//! ```
+//! use fixed_decimal::FixedDecimal;
+//! use icu_locid::locale;
+//! use icu_units::options::Width;
+//! use icu_units::UnitsFormatter;
+//! use writeable::assert_writeable_eq;
+//!
+//! let formatter = UnitsFormatter::try_new_unstable(
+//!     &icu_testdata::unstable(),
+//!     &locale!("en").into(),
+//!     "kilometer".parse().unwrap(),
+//!     Default::default(),
+//! )
+//! .expect("Data should load successfully");
+//!
+//! assert_writeable_eq!(formatter.format(FixedDecimal::from(3)), "3 km");
+//!
+//! let formatter = UnitsFormatter::try_new_unstable(
+//!     &icu_testdata::unstable(),
+//!     &locale!("en").into(),
+//!     "kilometer".parse().unwrap(),
+//!     Width::Long.into(),
+//! )
+//! .expect("Data should load successfully");
+//!
+//! assert_writeable_eq!(formatter.format(FixedDecimal::from(1)), "1 kilometer");
+//! assert_writeable_eq!(formatter.format(FixedDecimal::from(3)), "3 kilometers");
+//! ```
+//!
+//! ## Formatting compound units
+//!
+//! ```
+//! use fixed_decimal::FixedDecimal;
+//! use icu_locid::locale;
+//! use icu_units::options::Width;
+//! use icu_units::UnitsFormatter;
+//! use writeable::assert_writeable_eq;
+//!
+//! let formatter = UnitsFormatter::try_new_unstable(
+//!     &icu_testdata::unstable(),
+//!     &locale!("en").into(),
+//!     "gigabyte-per-second".parse().unwrap(),
+//!     Default::default(),
+//! )
+//! .expect("Data should load successfully");
+//!
+//! assert_writeable_eq!(formatter.format(FixedDecimal::from(5)), "5 GB/s");
+//!
+//! let formatter = UnitsFormatter::try_new_unstable(
+//!     &icu_testdata::unstable(),
+//!     &locale!("en").into(),
+//!     "gigabyte-per-second".parse().unwrap(),
+//!     Width::Long.into(),
+//! )
+//! .expect("Data should load successfully");
+//!
+//! assert_writeable_eq!(
+//!     formatter.format(FixedDecimal::from(5)),
+//!     "5 gigabytes per second"
+//! );
+//!
+//! let formatter = UnitsFormatter::try_new_unstable(
+//!     &icu_testdata::unstable(),
+//!     &locale!("en").into(),
+//!     "cubic-millimeter".parse().unwrap(),
+//!     Default::default(),
+//! )
+//! .expect("Data should load successfully");
+//!
+//! assert_writeable_eq!(formatter.format(FixedDecimal::from(8)), "8 mm³");
+//! ```
+//!
+//! ## Converting into the preferred units of a locale
+//!
+//! [`ConverterFactory`] converts exactly between any two units of the same quantity, and
+//! [`UnitsRouter`] chooses the units that a locale prefers for a usage, following the CLDR
+//! `unitPreferenceData`.
+//!
+//! ```
+//! use icu_locid::locale;
+//! use icu_units::{Ratio, UnitsFormatter, UnitsRouter};
+//! use writeable::assert_writeable_eq;
+//!
+//! let router = UnitsRouter::try_new_unstable(
+//!     &icu_testdata::unstable(),
+//!     &locale!("en-GB").into(),
+//!     "kilometer".parse().unwrap(),
+//!     "road".into(),
+//! )
+//! .expect("Data should load successfully");
+//!
+//! let routed = router.route(&Ratio::from(10));
+//! let (unit, value) = &routed.measures[0];
+//! assert_eq!(unit.as_str(), "mile");
+//!
+//! let formatter = UnitsFormatter::try_new_unstable(
+//!     &icu_testdata::unstable(),
+//!     &locale!("en-GB").into(),
+//!     (*unit).clone(),
+//!     Default::default(),
+//! )
+//! .expect("Data should load successfully");
+//!
+//! assert_writeable_eq!(formatter.format(value.to_fixed_decimal(-1)), "6.2 mi");
+//! ```
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(
+    not(test),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::exhaustive_structs,
+        clippy::exhaustive_enums,
+    )
+)]
+#![warn(missing_docs)]
+
+extern crate alloc;
+
+mod converter;
+mod error;
+mod format;
+mod formatter;
+mod measure_unit;
+pub mod options;
+pub mod provider;
+mod ratio;
+mod router;
+
+pub use converter::{ConverterFactory, UnitsConverter};
+pub use error::UnitsError;
+pub use format::{parts, FormattedUnit};
+pub use formatter::UnitsFormatter;
+pub use measure_unit::MeasureUnit;
+pub use ratio::Ratio;
+pub use router::{RoutedMeasure, UnitsRouter};
+
+#[doc(inline)]
+pub use UnitsError as Error;
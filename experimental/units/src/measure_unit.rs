@@ -0,0 +1,275 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::UnitsError;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::str::FromStr;
+
+/// A CLDR core unit identifier, such as "kilometer", "kilometer-per-hour", or "square-meter".
+///
+/// Identifiers consist of simple units such as "meter", optionally with an SI or binary prefix
+/// such as "kilo" or "kibi" and a power such as "square-" or "cubic-", joined by "-" and
+/// divided by "-per-". This type only checks the syntax of the identifier; whether the simple
+/// units are known is checked when the unit is used.
+///
+/// # Examples
+///
+/// ```
+/// use icu_units::MeasureUnit;
+///
+/// let unit: MeasureUnit = "kilometer-per-hour".parse().expect("valid syntax");
+/// assert_eq!(unit.as_str(), "kilometer-per-hour");
+///
+/// assert!(MeasureUnit::try_from_str("Kilometer").is_err());
+/// assert!(MeasureUnit::try_from_str("kilometer--hour").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct MeasureUnit(String);
+
+impl MeasureUnit {
+    /// Parses a CLDR core unit identifier.
+    pub fn try_from_str(s: &str) -> Result<Self, UnitsError> {
+        if s.split('-').all(|part| {
+            !part.is_empty()
+                && part
+                    .bytes()
+                    .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit())
+        }) {
+            Ok(Self(s.into()))
+        } else {
+            Err(UnitsError::InvalidUnit)
+        }
+    }
+
+    /// Returns the identifier as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for MeasureUnit {
+    type Err = UnitsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_str(s)
+    }
+}
+
+/// The CLDR identifiers of the SI prefixes, with their power of ten.
+pub(crate) const DECIMAL_PREFIXES: &[(&str, i8)] = &[
+    ("yotta", 24),
+    ("zetta", 21),
+    ("exa", 18),
+    ("peta", 15),
+    ("tera", 12),
+    ("giga", 9),
+    ("mega", 6),
+    ("kilo", 3),
+    ("hecto", 2),
+    ("deka", 1),
+    ("deci", -1),
+    ("centi", -2),
+    ("milli", -3),
+    ("micro", -6),
+    ("nano", -9),
+    ("pico", -12),
+    ("femto", -15),
+    ("atto", -18),
+    ("zepto", -21),
+    ("yocto", -24),
+];
+
+/// The CLDR identifiers of the binary prefixes, with their power of 1024.
+pub(crate) const BINARY_PREFIXES: &[(&str, u8)] = &[
+    ("kibi", 1),
+    ("mebi", 2),
+    ("gibi", 3),
+    ("tebi", 4),
+    ("pebi", 5),
+    ("exbi", 6),
+    ("zebi", 7),
+    ("yobi", 8),
+];
+
+/// Splits a power prefix such as "square-", "cubic-", or "pow4-" from a unit.
+fn strip_power(s: &str) -> Option<(u8, &str)> {
+    if let Some(rest) = s.strip_prefix("square-") {
+        return Some((2, rest));
+    }
+    if let Some(rest) = s.strip_prefix("cubic-") {
+        return Some((3, rest));
+    }
+    let (power, rest) = s.strip_prefix("pow")?.split_once('-')?;
+    match power.parse() {
+        Ok(power @ 2..=15) => Some((power, rest)),
+        _ => None,
+    }
+}
+
+/// A prefix of a simple unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Prefix {
+    None,
+    /// An SI prefix, with its power of ten.
+    Decimal(i8),
+    /// A binary prefix, with its power of 1024.
+    Binary(u8),
+}
+
+/// A simple unit with an optional prefix and power, such as "square-kilometer".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct SingleUnit<'a> {
+    pub(crate) power: u8,
+    pub(crate) prefix: Prefix,
+    pub(crate) base: &'a str,
+}
+
+/// Splits a product of units, such as "kilowatt-hour", into single units.
+///
+/// `is_known` decides which identifiers are simple units. Where several splits are possible,
+/// the longest known identifier is preferred, so that for example "square-meter" is a simple
+/// unit if it is known, and otherwise "meter" with the power 2.
+pub(crate) fn parse_product(
+    mut s: &str,
+    is_known: impl Fn(&str) -> bool,
+) -> Option<Vec<SingleUnit<'_>>> {
+    let mut units = Vec::new();
+    while !s.is_empty() {
+        let (unit, rest) = parse_single(s, &is_known)?;
+        units.push(unit);
+        s = match rest.strip_prefix('-') {
+            Some(rest) if !rest.is_empty() => rest,
+            None if rest.is_empty() => rest,
+            _ => return None,
+        };
+    }
+    if units.is_empty() {
+        None
+    } else {
+        Some(units)
+    }
+}
+
+fn parse_single<'a>(
+    s: &'a str,
+    is_known: &impl Fn(&str) -> bool,
+) -> Option<(SingleUnit<'a>, &'a str)> {
+    // Tries the candidate ends of the unit, longest first.
+    let split = |power: u8, s: &'a str| {
+        core::iter::once(s.len())
+            .chain(s.rmatch_indices('-').map(|(i, _)| i))
+            .find_map(|end| {
+                let candidate = s.get(..end)?;
+                let rest = s.get(end..)?;
+                if is_known(candidate) {
+                    return Some((
+                        SingleUnit {
+                            power,
+                            prefix: Prefix::None,
+                            base: candidate,
+                        },
+                        rest,
+                    ));
+                }
+                DECIMAL_PREFIXES
+                    .iter()
+                    .map(|&(name, exponent)| (name, Prefix::Decimal(exponent)))
+                    .chain(
+                        BINARY_PREFIXES
+                            .iter()
+                            .map(|&(name, exponent)| (name, Prefix::Binary(exponent))),
+                    )
+                    .find_map(|(name, prefix)| {
+                        let base = candidate.strip_prefix(name)?;
+                        is_known(base).then(|| {
+                            (
+                                SingleUnit {
+                                    power,
+                                    prefix,
+                                    base,
+                                },
+                                rest,
+                            )
+                        })
+                    })
+            })
+    };
+
+    split(1, s).or_else(|| {
+        let (power, s) = strip_power(s)?;
+        split(power, s)
+    })
+}
+
+#[test]
+fn test_parse_product() {
+    let known = [
+        "meter",
+        "square-meter",
+        "fluid-ounce",
+        "hour",
+        "byte",
+        "watt",
+    ];
+    let is_known = |s: &str| known.contains(&s);
+
+    assert_eq!(
+        parse_product("kilowatt-hour", is_known),
+        Some(alloc::vec![
+            SingleUnit {
+                power: 1,
+                prefix: Prefix::Decimal(3),
+                base: "watt"
+            },
+            SingleUnit {
+                power: 1,
+                prefix: Prefix::None,
+                base: "hour"
+            }
+        ])
+    );
+    assert_eq!(
+        parse_product("square-meter", is_known),
+        Some(alloc::vec![SingleUnit {
+            power: 1,
+            prefix: Prefix::None,
+            base: "square-meter"
+        }])
+    );
+    assert_eq!(
+        parse_product("cubic-centimeter", is_known),
+        Some(alloc::vec![SingleUnit {
+            power: 3,
+            prefix: Prefix::Decimal(-2),
+            base: "meter"
+        }])
+    );
+    assert_eq!(
+        parse_product("fluid-ounce", is_known),
+        Some(alloc::vec![SingleUnit {
+            power: 1,
+            prefix: Prefix::None,
+            base: "fluid-ounce"
+        }])
+    );
+    assert_eq!(
+        parse_product("gibibyte", is_known),
+        Some(alloc::vec![SingleUnit {
+            power: 1,
+            prefix: Prefix::Binary(3),
+            base: "byte"
+        }])
+    );
+    assert_eq!(
+        parse_product("pow4-meter", is_known),
+        Some(alloc::vec![SingleUnit {
+            power: 4,
+            prefix: Prefix::None,
+            base: "meter"
+        }])
+    );
+    assert_eq!(parse_product("furlong", is_known), None);
+    assert_eq!(parse_product("meter-", is_known), None);
+}
@@ -0,0 +1,68 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for [`UnitsFormatter`](crate::UnitsFormatter) and [`UnitsRouter`](crate::UnitsRouter).
+
+use alloc::borrow::Cow;
+use icu_decimal::options::FixedDecimalFormatterOptions;
+
+/// A bag of options defining how measurements will be formatted by
+/// [`UnitsFormatter`](crate::UnitsFormatter).
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+#[non_exhaustive]
+pub struct UnitsFormatterOptions {
+    /// The width of the unit names.
+    pub width: Width,
+    /// Options to configure the inner [`FixedDecimalFormatter`](icu_decimal::FixedDecimalFormatter),
+    /// which renders the number.
+    pub fixed_decimal_formatter_options: FixedDecimalFormatterOptions,
+}
+
+impl From<Width> for UnitsFormatterOptions {
+    fn from(width: Width) -> Self {
+        Self {
+            width,
+            ..Default::default()
+        }
+    }
+}
+
+/// The width of the unit names.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+#[non_exhaustive]
+pub enum Width {
+    /// Spelled-out unit names, such as "3 kilometers".
+    Long,
+    /// Abbreviated unit names, such as "3 km".
+    Short,
+    /// The shortest available unit names, such as "3km".
+    Narrow,
+}
+
+impl Default for Width {
+    fn default() -> Self {
+        Self::Short
+    }
+}
+
+/// A bag of options defining how [`UnitsRouter`](crate::UnitsRouter) chooses the preferred
+/// units.
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+#[non_exhaustive]
+pub struct UnitsRouterOptions {
+    /// The CLDR usage of the measurement, such as "road" or "person-height".
+    ///
+    /// Usages without preferences of their own fall back by removing their last subtag, such
+    /// as "person-height" to "person", and finally to "default", which is also used if this
+    /// is `None`.
+    pub usage: Option<Cow<'static, str>>,
+}
+
+impl From<&'static str> for UnitsRouterOptions {
+    fn from(usage: &'static str) -> Self {
+        Self {
+            usage: Some(Cow::Borrowed(usage)),
+        }
+    }
+}
@@ -0,0 +1,294 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// Provider structs must be stable
+#![allow(clippy::exhaustive_structs, clippy::exhaustive_enums)]
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use alloc::borrow::Cow;
+use icu_plurals::PluralCategory;
+use icu_provider::prelude::*;
+use icu_provider::{yoke, zerofrom};
+use zerovec::{VarZeroVec, ZeroMap, ZeroVec};
+
+/// Unit patterns in a single width, and the patterns that build compound units from them.
+///
+/// Patterns contain a `{0}` placeholder for the number, or for the unit in the compound
+/// patterns; the `per` and `times` patterns also contain a `{1}` placeholder.
+#[icu_provider::data_struct(
+    LongUnitsFormatDataV1Marker = "units/long@1",
+    ShortUnitsFormatDataV1Marker = "units/short@1",
+    NarrowUnitsFormatDataV1Marker = "units/narrow@1"
+)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_units::provider),
+)]
+#[yoke(prove_covariance_manually)]
+pub struct UnitsFormatDataV1<'data> {
+    /// The patterns of the units, such as "{0} kilometers", keyed by CLDR core unit identifier
+    /// such as "kilometer".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub patterns: UnitPluralPatterns<'data>,
+    /// The patterns for dividing by a unit, such as "{0} per kilometer", for the units that have
+    /// one.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub per_unit_patterns: ZeroMap<'data, str, str>,
+    /// The pattern for dividing a unit by another unit, such as "{0} per {1}".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub per: Cow<'data, str>,
+    /// The pattern for multiplying a unit by another unit, such as "{0}-{1}".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub times: Cow<'data, str>,
+    /// The patterns for squaring a unit, such as "square {0}".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub power2: PluralPattern<'data>,
+    /// The patterns for cubing a unit, such as "cubic {0}".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub power3: PluralPattern<'data>,
+    /// The patterns for the SI prefixes, such as "kilo{0}", keyed by the power of ten.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub decimal_prefixes: ZeroMap<'data, i8, str>,
+    /// The patterns for the binary prefixes, such as "kibi{0}", keyed by the power of 1024.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub binary_prefixes: ZeroMap<'data, u8, str>,
+}
+
+pub(crate) struct ErasedUnitsFormatDataV1Marker;
+
+impl DataMarker for ErasedUnitsFormatDataV1Marker {
+    type Yokeable = UnitsFormatDataV1<'static>;
+}
+
+/// The patterns of all units for each plural category.
+///
+/// The maps for categories other than `other` only contain the patterns that differ from
+/// those for `other`.
+#[derive(Debug, Clone, PartialEq, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_units::provider),
+)]
+#[yoke(prove_covariance_manually)]
+pub struct UnitPluralPatterns<'data> {
+    /// The patterns for [`PluralCategory::Zero`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub zero: ZeroMap<'data, str, str>,
+    /// The patterns for [`PluralCategory::One`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub one: ZeroMap<'data, str, str>,
+    /// The patterns for [`PluralCategory::Two`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub two: ZeroMap<'data, str, str>,
+    /// The patterns for [`PluralCategory::Few`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub few: ZeroMap<'data, str, str>,
+    /// The patterns for [`PluralCategory::Many`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub many: ZeroMap<'data, str, str>,
+    /// The patterns for [`PluralCategory::Other`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub other: ZeroMap<'data, str, str>,
+}
+
+impl<'data> UnitPluralPatterns<'data> {
+    /// Returns the pattern of the unit for the given plural category, or `None` if the locale
+    /// has no pattern for the unit.
+    pub(crate) fn get(&self, category: PluralCategory, unit: &str) -> Option<&str> {
+        match category {
+            PluralCategory::Zero => self.zero.get(unit),
+            PluralCategory::One => self.one.get(unit),
+            PluralCategory::Two => self.two.get(unit),
+            PluralCategory::Few => self.few.get(unit),
+            PluralCategory::Many => self.many.get(unit),
+            PluralCategory::Other => None,
+        }
+        .or_else(|| self.other.get(unit))
+    }
+
+    /// Returns whether the locale has a pattern for the unit.
+    pub(crate) fn contains(&self, unit: &str) -> bool {
+        self.other.get(unit).is_some()
+    }
+}
+
+/// A pattern for each plural category.
+#[derive(Debug, Clone, PartialEq, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_units::provider),
+)]
+pub struct PluralPattern<'data> {
+    /// The pattern for [`PluralCategory::Zero`], if it differs from `other`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            deserialize_with = "icu_provider::serde::borrow_de_utils::option_of_cow"
+        )
+    )]
+    pub zero: Option<Cow<'data, str>>,
+    /// The pattern for [`PluralCategory::One`], if it differs from `other`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            deserialize_with = "icu_provider::serde::borrow_de_utils::option_of_cow"
+        )
+    )]
+    pub one: Option<Cow<'data, str>>,
+    /// The pattern for [`PluralCategory::Two`], if it differs from `other`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            deserialize_with = "icu_provider::serde::borrow_de_utils::option_of_cow"
+        )
+    )]
+    pub two: Option<Cow<'data, str>>,
+    /// The pattern for [`PluralCategory::Few`], if it differs from `other`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            deserialize_with = "icu_provider::serde::borrow_de_utils::option_of_cow"
+        )
+    )]
+    pub few: Option<Cow<'data, str>>,
+    /// The pattern for [`PluralCategory::Many`], if it differs from `other`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            deserialize_with = "icu_provider::serde::borrow_de_utils::option_of_cow"
+        )
+    )]
+    pub many: Option<Cow<'data, str>>,
+    /// The pattern for [`PluralCategory::Other`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub other: Cow<'data, str>,
+}
+
+impl<'data> PluralPattern<'data> {
+    pub(crate) fn get(&self, category: PluralCategory) -> &str {
+        match category {
+            PluralCategory::Zero => self.zero.as_deref(),
+            PluralCategory::One => self.one.as_deref(),
+            PluralCategory::Two => self.two.as_deref(),
+            PluralCategory::Few => self.few.as_deref(),
+            PluralCategory::Many => self.many.as_deref(),
+            PluralCategory::Other => None,
+        }
+        .unwrap_or(&self.other)
+    }
+}
+
+/// The CLDR supplemental unit data: how to convert units into each other, which quantity each
+/// unit measures, and which units each region prefers for each usage.
+#[icu_provider::data_struct(UnitsInfoV1Marker = "units/info@1")]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_units::provider),
+)]
+#[yoke(prove_covariance_manually)]
+pub struct UnitsInfoV1<'data> {
+    /// The conversion of each simple unit into base units, keyed by CLDR core unit identifier
+    /// such as "foot".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub conversions: ZeroMap<'data, str, ConversionInfoULE>,
+    /// The quantity measured by each base unit, such as "length" for "meter".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub quantities: ZeroMap<'data, str, str>,
+    /// The preferred units, in the order of the CLDR `unitPreferenceData`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub preferences: VarZeroVec<'data, UnitPreferenceULE>,
+}
+
+/// The conversion of a simple unit into base units, as
+/// `value_in_base_unit = value * factor + offset`.
+///
+/// Rational numbers are stored as an unsigned big-endian numerator and denominator.
+#[zerovec::make_varule(ConversionInfoULE)]
+#[zerovec::derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize),
+    zerovec::derive(Deserialize)
+)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    zerovec::derive(Serialize),
+    databake(path = icu_units::provider),
+)]
+pub struct ConversionInfo<'data> {
+    /// The base unit, such as "meter" for "foot", or "kilogram-per-meter-square-second" for
+    /// "bar".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub base_unit: Cow<'data, str>,
+    /// The numerator of the factor.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub factor_numerator: ZeroVec<'data, u8>,
+    /// The denominator of the factor.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub factor_denominator: ZeroVec<'data, u8>,
+    /// The numerator of the offset, which is non-negative and empty for most units.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub offset_numerator: ZeroVec<'data, u8>,
+    /// The denominator of the offset.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub offset_denominator: ZeroVec<'data, u8>,
+}
+
+/// A unit preferred for measurements of a quantity with a given usage in a region.
+#[zerovec::make_varule(UnitPreferenceULE)]
+#[zerovec::derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize),
+    zerovec::derive(Deserialize)
+)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    zerovec::derive(Serialize),
+    databake(path = icu_units::provider),
+)]
+pub struct UnitPreference<'data> {
+    /// The numerator of the smallest value, in the preferred unit, for which the unit is used.
+    pub geq_numerator: u32,
+    /// The denominator of the smallest value for which the unit is used.
+    pub geq_denominator: u32,
+    /// The quantity, such as "length".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub quantity: Cow<'data, str>,
+    /// The usage, such as "road" or "default".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub usage: Cow<'data, str>,
+    /// The region, such as "US", or "001" for the regions without their own preferences.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub region: Cow<'data, str>,
+    /// The preferred unit, such as "kilometer" or the mixed unit "foot-and-inch".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub unit: Cow<'data, str>,
+    /// The CLDR number skeleton for formatting the value, such as "precision-increment/50", or
+    /// empty.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub skeleton: Cow<'data, str>,
+}
@@ -0,0 +1,112 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::string::ToString;
+use core::cmp::Ordering;
+use core::str::FromStr;
+use fixed_decimal::{FixedDecimal, Sign};
+use num_bigint::{BigInt, BigUint};
+use num_rational::BigRational;
+use num_traits::{One, Signed, Zero};
+use writeable::Writeable;
+
+/// An exact rational number, used as the input and output of unit conversions.
+///
+/// Conversions are computed without loss of precision; convert the result into a
+/// [`FixedDecimal`] with [`Ratio::to_fixed_decimal`] to format it.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_units::Ratio;
+///
+/// let value = Ratio::from(&FixedDecimal::from(1250).multiplied_pow10(-3));
+///
+/// assert_eq!(value.to_fixed_decimal(-2).to_string(), "1.25");
+/// assert_eq!(value.to_fixed_decimal(-1).to_string(), "1.2");
+/// assert_eq!(value.to_fixed_decimal(0).to_string(), "1");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ratio(pub(crate) BigRational);
+
+impl Ratio {
+    /// Returns the value rounded to the given magnitude, with ties rounded to even.
+    ///
+    /// For example, a `position` of -2 rounds to hundredths.
+    pub fn to_fixed_decimal(&self, position: i16) -> FixedDecimal {
+        let scaled = if position < 0 {
+            &self.0 * pow10(position.unsigned_abs())
+        } else {
+            &self.0 / pow10(position.unsigned_abs())
+        };
+        let mut rounded = scaled.floor();
+        match (&scaled - &rounded).cmp(&BigRational::new(BigInt::one(), BigInt::from(2))) {
+            Ordering::Greater => rounded += BigInt::one(),
+            Ordering::Equal if rounded.to_integer().bit(0) => rounded += BigInt::one(),
+            _ => {}
+        }
+        let integer = rounded.to_integer();
+        // Numbers that do not fit in a FixedDecimal have more than 32767 digits and are not
+        // representable in any unit system.
+        let mut result = FixedDecimal::from_str(&integer.to_string()).unwrap_or_default();
+        result.multiply_pow10(position);
+        result
+    }
+
+    /// Returns whether the value is zero.
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    /// Returns the absolute value.
+    pub(crate) fn abs(&self) -> Self {
+        Self(self.0.abs())
+    }
+
+    /// Returns the integer part, rounded toward zero, and the remaining fraction.
+    pub(crate) fn split_integer(&self) -> (Self, Self) {
+        let integer = self.0.trunc();
+        let fraction = &self.0 - &integer;
+        (Self(integer), Self(fraction))
+    }
+
+    /// Creates a ratio from an unsigned big-endian numerator and denominator, such as those
+    /// stored in the data.
+    pub(crate) fn from_be_bytes(numerator: &[u8], denominator: &[u8]) -> Option<Self> {
+        let numerator = BigInt::from(BigUint::from_bytes_be(numerator));
+        let denominator = BigInt::from(BigUint::from_bytes_be(denominator));
+        if denominator.is_zero() {
+            return None;
+        }
+        Some(Self(BigRational::new(numerator, denominator)))
+    }
+}
+
+fn pow10(exponent: u16) -> BigRational {
+    BigRational::from_integer(num_traits::pow(BigInt::from(10), exponent.into()))
+}
+
+impl From<i64> for Ratio {
+    fn from(value: i64) -> Self {
+        Self(BigRational::from_integer(value.into()))
+    }
+}
+
+impl From<&FixedDecimal> for Ratio {
+    fn from(value: &FixedDecimal) -> Self {
+        let digits = value.write_to_string();
+        let digits = digits.trim_start_matches(['-', '+'].as_ref());
+        let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+        let mut numerator = BigInt::zero();
+        for digit in integer.bytes().chain(fraction.bytes()) {
+            numerator = numerator * 10u32 + u32::from(digit - b'0');
+        }
+        if value.sign() == Sign::Negative {
+            numerator = -numerator;
+        }
+        let denominator = num_traits::pow(BigInt::from(10), fraction.len());
+        Self(BigRational::new(numerator, denominator))
+    }
+}
@@ -0,0 +1,276 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::converter::{ConverterFactory, UnitsConverter};
+use crate::options::UnitsRouterOptions;
+use crate::provider::*;
+use crate::{MeasureUnit, Ratio, UnitsError};
+use alloc::string::String;
+use alloc::vec::Vec;
+use icu_locid::{extensions_unicode_key as key, extensions_unicode_value as value};
+use icu_locid_transform::provider::LikelySubtagsV1Marker;
+use icu_locid_transform::LocaleExpander;
+use icu_provider::prelude::*;
+use icu_provider::zerofrom::ZeroFrom;
+use num_bigint::BigInt;
+use num_rational::BigRational;
+
+/// Converts measurements into the units preferred in a locale for a usage, such as miles for
+/// road distances in the United States, or feet and inches for the height of a person.
+///
+/// The region of the locale determines the preferences. It is overridden by the "ms"
+/// (measurement system) Unicode extension keyword, and inferred from the likely subtags if the
+/// locale has no region.
+///
+/// # Examples
+///
+/// ```
+/// use icu_locid::locale;
+/// use icu_units::{Ratio, UnitsRouter};
+///
+/// let router = UnitsRouter::try_new_unstable(
+///     &icu_testdata::unstable(),
+///     &locale!("en-US").into(),
+///     "meter".parse().unwrap(),
+///     "road".into(),
+/// )
+/// .expect("Data should load successfully");
+///
+/// let routed = router.route(&Ratio::from(1609));
+/// assert_eq!(routed.measures[0].0.as_str(), "mile");
+/// assert_eq!(routed.measures[0].1.to_fixed_decimal(-1).to_string(), "1.0");
+///
+/// let routed = router.route(&Ratio::from(100));
+/// assert_eq!(routed.measures[0].0.as_str(), "foot");
+/// assert_eq!(routed.measures[0].1.to_fixed_decimal(0).to_string(), "328");
+/// assert_eq!(routed.skeleton, "precision-increment/50");
+///
+/// let router = UnitsRouter::try_new_unstable(
+///     &icu_testdata::unstable(),
+///     &locale!("en-US").into(),
+///     "centimeter".parse().unwrap(),
+///     "person-height".into(),
+/// )
+/// .expect("Data should load successfully");
+///
+/// let routed = router.route(&Ratio::from(180));
+/// assert_eq!(routed.measures[0].0.as_str(), "foot");
+/// assert_eq!(routed.measures[0].1.to_fixed_decimal(0).to_string(), "5");
+/// assert_eq!(routed.measures[1].0.as_str(), "inch");
+/// assert_eq!(routed.measures[1].1.to_fixed_decimal(-1).to_string(), "10.9");
+/// ```
+#[derive(Debug)]
+pub struct UnitsRouter {
+    outputs: Vec<RouterOutput>,
+}
+
+/// One of the preferred units of a [`UnitsRouter`].
+#[derive(Debug)]
+struct RouterOutput {
+    /// The units of the output, such as "foot" and "inch" for the mixed unit "foot-and-inch".
+    units: Vec<MeasureUnit>,
+    /// The converter from the input unit to the first of `units`.
+    converter: UnitsConverter,
+    /// The converters from each of `units` to the next one.
+    mixed_converters: Vec<UnitsConverter>,
+    /// The smallest value, in the first of `units`, for which this output is used.
+    geq: Ratio,
+    skeleton: String,
+}
+
+/// The result of [`UnitsRouter::route`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct RoutedMeasure<'a> {
+    /// The value in each of the preferred units.
+    ///
+    /// There are several units for mixed units such as "foot-and-inch"; the values of all but
+    /// the last unit are integers, and only the first value can be negative.
+    pub measures: Vec<(&'a MeasureUnit, Ratio)>,
+    /// The CLDR number skeleton for formatting the value of the last unit, such as
+    /// "precision-increment/50", or an empty string.
+    pub skeleton: &'a str,
+}
+
+impl UnitsRouter {
+    /// Creates a new [`UnitsRouter`] for values in `unit`, from the CLDR supplemental unit
+    /// data and an options bag.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_locid::locale;
+    /// use icu_units::{Ratio, UnitsRouter};
+    ///
+    /// let router = UnitsRouter::try_new_unstable(
+    ///     &icu_testdata::unstable(),
+    ///     &locale!("en-US-u-ms-metric").into(),
+    ///     "fahrenheit".parse().unwrap(),
+    ///     "weather".into(),
+    /// )
+    /// .expect("Data should load successfully");
+    ///
+    /// let routed = router.route(&Ratio::from(212));
+    /// assert_eq!(routed.measures[0].0.as_str(), "celsius");
+    /// assert_eq!(routed.measures[0].1.to_fixed_decimal(0).to_string(), "100");
+    /// ```
+    pub fn try_new_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        unit: MeasureUnit,
+        options: UnitsRouterOptions,
+    ) -> Result<Self, UnitsError>
+    where
+        D: DataProvider<UnitsInfoV1Marker> + DataProvider<LikelySubtagsV1Marker> + ?Sized,
+    {
+        let factory = ConverterFactory::try_new_unstable(data_provider)?;
+        let region = region(data_provider, locale)?;
+        let quantity = factory.quantity(unit.as_str())?;
+
+        let mut usage = options.usage.as_deref().unwrap_or("default");
+        let preferences = loop {
+            let preferences = factory
+                .info
+                .get()
+                .preferences
+                .iter()
+                .map(UnitPreference::zero_from)
+                .filter(|preference| preference.quantity == quantity && preference.usage == usage)
+                .collect::<Vec<_>>();
+            if !preferences.is_empty() {
+                break preferences;
+            }
+            usage = match usage.rsplit_once('-') {
+                Some((parent, _)) => parent,
+                None if usage != "default" => "default",
+                None => return Err(UnitsError::NoPreferences),
+            };
+        };
+        // Regions without preferences of their own use those of the world.
+        let region = if preferences.iter().any(|p| p.region == region) {
+            region.as_str()
+        } else {
+            "001"
+        };
+        let preferences = preferences.into_iter().filter(|p| p.region == region);
+
+        let mut outputs = Vec::new();
+        for preference in preferences {
+            let units = preference
+                .unit
+                .split("-and-")
+                .map(MeasureUnit::try_from_str)
+                .collect::<Result<Vec<_>, _>>()?;
+            let first = units.first().ok_or(UnitsError::InvalidUnit)?;
+            let converter = factory.converter(&unit, first)?;
+            let mixed_converters = units
+                .windows(2)
+                .map(|pair| match pair {
+                    [from, to] => factory.converter(from, to),
+                    _ => Err(UnitsError::InvalidUnit),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let geq = if preference.geq_denominator == 0 {
+                Ratio::from(1)
+            } else {
+                Ratio(BigRational::new(
+                    BigInt::from(preference.geq_numerator),
+                    BigInt::from(preference.geq_denominator),
+                ))
+            };
+            outputs.push(RouterOutput {
+                units,
+                converter,
+                mixed_converters,
+                geq,
+                skeleton: preference.skeleton.into_owned(),
+            });
+        }
+        if outputs.is_empty() {
+            return Err(UnitsError::NoPreferences);
+        }
+        Ok(Self { outputs })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        unit: MeasureUnit,
+        options: UnitsRouterOptions,
+        error: UnitsError
+    );
+
+    /// Converts a value in the unit of this router into the preferred units.
+    ///
+    /// The first preferred unit in which the magnitude of the value is at least the threshold
+    /// of that unit is used, or otherwise the last preferred unit.
+    pub fn route(&self, value: &Ratio) -> RoutedMeasure<'_> {
+        let mut outputs = self.outputs.iter();
+        let (output, mut converted) = loop {
+            let output = match outputs.next() {
+                Some(output) => output,
+                // The constructor ensures that there is at least one output.
+                None => {
+                    return RoutedMeasure {
+                        measures: Vec::new(),
+                        skeleton: "",
+                    }
+                }
+            };
+            let converted = output.converter.convert(value);
+            if outputs.len() == 0 || converted.abs() >= output.geq {
+                break (output, converted);
+            }
+        };
+
+        let negative = converted.0 < BigRational::from_integer(BigInt::from(0));
+        if negative {
+            converted = converted.abs();
+        }
+        let mut measures = Vec::with_capacity(output.units.len());
+        for (unit, converter) in output.units.iter().zip(&output.mixed_converters) {
+            let (integer, fraction) = converted.split_integer();
+            measures.push((unit, integer));
+            converted = converter.convert(&fraction);
+        }
+        if let Some(unit) = output.units.last() {
+            measures.push((unit, converted));
+        }
+        if negative {
+            if let Some((_, first)) = measures.first_mut() {
+                *first = Ratio(-&first.0);
+            }
+        }
+        RoutedMeasure {
+            measures,
+            skeleton: &output.skeleton,
+        }
+    }
+}
+
+/// Returns the region whose preferences apply to a locale.
+fn region<D>(data_provider: &D, locale: &DataLocale) -> Result<String, UnitsError>
+where
+    D: DataProvider<LikelySubtagsV1Marker> + ?Sized,
+{
+    match locale.get_unicode_ext(&key!("ms")) {
+        Some(system) if system == value!("metric") => return Ok("001".into()),
+        Some(system) if system == value!("ussystem") => return Ok("US".into()),
+        Some(system) if system == value!("uksystem") => return Ok("GB".into()),
+        _ => {}
+    }
+    if let Some(region) = locale.region() {
+        return Ok(region.as_str().into());
+    }
+    let mut langid = locale.get_langid();
+    LocaleExpander::try_new_unstable(data_provider)?.maximize(&mut langid);
+    Ok(langid
+        .region
+        .map(|region| region.as_str().into())
+        .unwrap_or_else(|| "001".into()))
+}
@@ -0,0 +1,218 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::FixedDecimal;
+use icu_locid::locale;
+use icu_units::{ConverterFactory, MeasureUnit, Ratio, UnitsError, UnitsRouter};
+
+#[test]
+fn test_converter() {
+    let factory = ConverterFactory::try_new_unstable(&icu_testdata::unstable()).unwrap();
+
+    // Input unit, output unit, input value, rounding position, output value
+    let cases = [
+        // Offset units
+        ("celsius", "fahrenheit", "100", 0, "212"),
+        ("celsius", "fahrenheit", "-40", 0, "-40"),
+        ("celsius", "fahrenheit", "37", -1, "98.6"),
+        ("fahrenheit", "celsius", "32", 0, "0"),
+        ("fahrenheit", "celsius", "0", -2, "-17.78"),
+        ("celsius", "kelvin", "0", -2, "273.15"),
+        ("kelvin", "fahrenheit", "0", -2, "-459.67"),
+        // Reciprocal units; zero is converted to zero rather than dividing by it
+        (
+            "liter-per-100-kilometer",
+            "mile-per-gallon",
+            "5",
+            -2,
+            "47.04",
+        ),
+        (
+            "mile-per-gallon",
+            "liter-per-100-kilometer",
+            "30",
+            -2,
+            "7.84",
+        ),
+        (
+            "liter-per-kilometer",
+            "kilometer-per-liter",
+            "0.05",
+            0,
+            "20",
+        ),
+        ("mile-per-gallon", "liter-per-100-kilometer", "0", 0, "0"),
+        // Prefixes and powers
+        ("kilometer", "meter", "1.5", 0, "1500"),
+        ("millimeter", "inch", "25.4", 0, "1"),
+        ("square-kilometer", "square-meter", "2", 0, "2000000"),
+        ("cubic-centimeter", "liter", "1000", 0, "1"),
+        ("square-foot", "square-meter", "100", -3, "9.290"),
+        ("kilobyte", "byte", "1", 0, "1000"),
+        ("kibibyte", "byte", "1", 0, "1024"),
+        (
+            "meter-per-square-second",
+            "kilometer-per-square-second",
+            "9",
+            -3,
+            "0.009",
+        ),
+        ("kilometer-per-hour", "meter-per-second", "36", 0, "10"),
+    ];
+    for (input, output, value, position, expected) in cases {
+        let converter = factory
+            .converter(&input.parse().unwrap(), &output.parse().unwrap())
+            .unwrap();
+        let value: FixedDecimal = value.parse().unwrap();
+        assert_eq!(
+            converter
+                .convert(&Ratio::from(&value))
+                .to_fixed_decimal(position)
+                .to_string(),
+            expected,
+            "{} {} to {}",
+            value,
+            input,
+            output
+        );
+    }
+
+    for (input, output) in [("meter", "square-meter"), ("celsius", "liter")] {
+        let input: MeasureUnit = input.parse().unwrap();
+        let output: MeasureUnit = output.parse().unwrap();
+        assert_eq!(
+            factory.converter(&input, &output).err(),
+            Some(UnitsError::NotConvertible)
+        );
+    }
+}
+
+#[test]
+fn test_router() {
+    // Locale, input unit, usage, input value, output measures rounded to one fraction digit
+    let cases: [(_, _, _, _, &[(&str, &str)]); 14] = [
+        (
+            locale!("en-US"),
+            "kilometer",
+            "road",
+            10,
+            &[("mile", "6.2")],
+        ),
+        (
+            locale!("en-GB"),
+            "kilometer",
+            "road",
+            10,
+            &[("mile", "6.2")],
+        ),
+        (
+            locale!("fr-FR"),
+            "mile",
+            "road",
+            10,
+            &[("kilometer", "16.1")],
+        ),
+        (
+            locale!("en-US"),
+            "celsius",
+            "weather",
+            20,
+            &[("fahrenheit", "68.0")],
+        ),
+        (
+            locale!("de-DE"),
+            "fahrenheit",
+            "weather",
+            68,
+            &[("celsius", "20.0")],
+        ),
+        // Shorter road distances use smaller units
+        (
+            locale!("fr-FR"),
+            "meter",
+            "road",
+            2000,
+            &[("kilometer", "2.0")],
+        ),
+        (
+            locale!("fr-FR"),
+            "meter",
+            "road",
+            500,
+            &[("meter", "500.0")],
+        ),
+        // Mixed units, with inches only for heights under three feet
+        (
+            locale!("en-US"),
+            "centimeter",
+            "person-height",
+            180,
+            &[("foot", "5.0"), ("inch", "10.9")],
+        ),
+        (
+            locale!("en-US"),
+            "centimeter",
+            "person-height",
+            80,
+            &[("inch", "31.5")],
+        ),
+        (
+            locale!("fr-FR"),
+            "inch",
+            "person-height",
+            70,
+            &[("meter", "1.0"), ("centimeter", "77.8")],
+        ),
+        (
+            locale!("de-DE"),
+            "inch",
+            "person-height",
+            70,
+            &[("centimeter", "177.8")],
+        ),
+        // Without a region, the likely region of the language is used
+        (locale!("en"), "kilometer", "road", 10, &[("mile", "6.2")]),
+        // The measurement system keyword overrides the region
+        (
+            locale!("en-US-u-ms-metric"),
+            "mile",
+            "road",
+            10,
+            &[("kilometer", "16.1")],
+        ),
+        // Unknown usages fall back to the default usage of the quantity
+        (
+            locale!("en-US"),
+            "kilogram",
+            "no-such-usage",
+            1,
+            &[("pound", "2.2")],
+        ),
+    ];
+    for (locale, unit, usage, value, expected) in cases {
+        let router = UnitsRouter::try_new_unstable(
+            &icu_testdata::unstable(),
+            &(&locale).into(),
+            unit.parse().unwrap(),
+            usage.into(),
+        )
+        .unwrap();
+        let measures = router.route(&Ratio::from(value)).measures;
+        assert_eq!(
+            measures
+                .iter()
+                .map(|(unit, value)| (unit.as_str(), value.to_fixed_decimal(-1).to_string()))
+                .collect::<Vec<_>>(),
+            expected
+                .iter()
+                .map(|&(unit, value)| (unit, value.to_string()))
+                .collect::<Vec<_>>(),
+            "{}: {} {} for {}",
+            locale,
+            value,
+            unit,
+            usage
+        );
+    }
+}
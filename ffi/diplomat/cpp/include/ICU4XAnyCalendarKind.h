@@ -20,6 +20,15 @@ typedef enum ICU4XAnyCalendarKind {
   ICU4XAnyCalendarKind_EthiopianAmeteAlem = 6,
   ICU4XAnyCalendarKind_Indian = 7,
   ICU4XAnyCalendarKind_Coptic = 8,
+  ICU4XAnyCalendarKind_Hebrew = 9,
+  ICU4XAnyCalendarKind_Chinese = 10,
+  ICU4XAnyCalendarKind_Dangi = 11,
+  ICU4XAnyCalendarKind_IslamicObservational = 12,
+  ICU4XAnyCalendarKind_IslamicUmmAlQura = 13,
+  ICU4XAnyCalendarKind_IslamicCivil = 14,
+  ICU4XAnyCalendarKind_IslamicTabular = 15,
+  ICU4XAnyCalendarKind_Persian = 16,
+  ICU4XAnyCalendarKind_Roc = 17,
 } ICU4XAnyCalendarKind;
 #ifdef __cplusplus
 } // namespace capi
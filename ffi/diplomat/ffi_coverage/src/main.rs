@@ -190,11 +190,17 @@ lazy_static::lazy_static! {
 
         // Individual calendars: Currently the main entry point is AnyCalendar
         "icu::calendar::buddhist",
+        "icu::calendar::chinese",
         "icu::calendar::coptic",
+        "icu::calendar::dangi",
         "icu::calendar::ethiopian",
+        "icu::calendar::hebrew",
         "icu::calendar::indian",
+        "icu::calendar::islamic",
         "icu::calendar::japanese",
         "icu::calendar::julian",
+        "icu::calendar::persian",
+        "icu::calendar::roc",
         "icu::calendar::any_calendar::IntoAnyCalendar",
         "icu::calendar::Date::try_new_gregorian_date",
         "icu::calendar::Date::try_new_buddhist_date",
@@ -204,6 +210,15 @@ lazy_static::lazy_static! {
         "icu::calendar::Date::try_new_japanese_date",
         "icu::calendar::Date::try_new_japanese_extended_date",
         "icu::calendar::Date::try_new_julian_date",
+        "icu::calendar::Date::try_new_hebrew_date",
+        "icu::calendar::Date::try_new_chinese_date",
+        "icu::calendar::Date::try_new_dangi_date",
+        "icu::calendar::Date::try_new_observational_islamic_date",
+        "icu::calendar::Date::try_new_ummalqura_date",
+        "icu::calendar::Date::try_new_islamic_civil_date",
+        "icu::calendar::Date::try_new_islamic_tabular_date",
+        "icu::calendar::Date::try_new_persian_date",
+        "icu::calendar::Date::try_new_roc_date",
         "icu::calendar::DateTime::try_new_gregorian_datetime",
         "icu::calendar::DateTime::try_new_buddhist_datetime",
         "icu::calendar::DateTime::try_new_coptic_datetime",
@@ -212,6 +227,15 @@ lazy_static::lazy_static! {
         "icu::calendar::DateTime::try_new_japanese_datetime",
         "icu::calendar::DateTime::try_new_japanese_extended_datetime",
         "icu::calendar::DateTime::try_new_julian_datetime",
+        "icu::calendar::DateTime::try_new_hebrew_datetime",
+        "icu::calendar::DateTime::try_new_chinese_datetime",
+        "icu::calendar::DateTime::try_new_dangi_datetime",
+        "icu::calendar::DateTime::try_new_observational_islamic_datetime",
+        "icu::calendar::DateTime::try_new_ummalqura_datetime",
+        "icu::calendar::DateTime::try_new_islamic_civil_datetime",
+        "icu::calendar::DateTime::try_new_islamic_tabular_datetime",
+        "icu::calendar::DateTime::try_new_persian_datetime",
+        "icu::calendar::DateTime::try_new_roc_datetime",
 
         // Arithmetic APIs are still experimental/hidden for 1.0
         "icu::calendar::DateDuration",
@@ -240,6 +264,17 @@ lazy_static::lazy_static! {
         // The FFI constructor takes a single option instead of a struct
         "icu::decimal::options::FixedDecimalFormatterOptions",
 
+        // Notation formatting, may be supported in the future
+        "icu::decimal::NotationFormatter",
+        "icu::decimal::FormattedNotation",
+        "icu::decimal::options::Notation",
+        "icu::decimal::options::NotationFormatterOptions",
+
+        // Number parsing, may be supported in the future
+        "icu::decimal::FixedDecimalParser",
+        "icu::decimal::options::FixedDecimalParserOptions",
+        "icu::decimal::options::ParseStrictness",
+
         // Rust-specific power user API for rules ASTS and such
         // could be exposed in the future but it's complicated
         "icu::plurals::rules",
@@ -285,6 +320,16 @@ lazy_static::lazy_static! {
         // experimental
         "icu::normalizer::ComposingNormalizer::try_new_uts46_without_ignored_and_disallowed_unstable",
 
+        // Not planned until someone needs them
+        "icu::normalizer::ComposingNormalizer::try_new_fcc_unstable",
+        "icu::normalizer::ComposingNormalizer::quick_check",
+        "icu::normalizer::ComposingNormalizer::quick_check_utf16",
+        "icu::normalizer::DecomposingNormalizer::quick_check",
+        "icu::normalizer::DecomposingNormalizer::quick_check_utf16",
+        "icu::normalizer::DecomposingNormalizer::is_fcd",
+        "icu::normalizer::DecomposingNormalizer::is_fcd_utf16",
+        "icu::normalizer::QuickCheckResult",
+
         // can't be exposed till Diplomat has Write16
         "icu::normalizer::ComposingNormalizer::normalize_utf16",
         "icu::normalizer::ComposingNormalizer::normalize_utf16_to",
@@ -327,6 +372,11 @@ lazy_static::lazy_static! {
 
         // Experimental
         "icu::casemapping",
+        "icu::compactdecimal",
+        "icu::currency",
+        "icu::rbnf",
+        "icu::relativetime",
+        "icu::units",
 
         // Stuff that does not need to be exposed over FFI
         // Especially for stuff that are Rust specific like conversion traits
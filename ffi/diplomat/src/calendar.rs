@@ -37,6 +37,24 @@ pub mod ffi {
         Indian = 7,
         /// The kind of a Coptic calendar
         Coptic = 8,
+        /// The kind of a Hebrew calendar
+        Hebrew = 9,
+        /// The kind of a Chinese calendar
+        Chinese = 10,
+        /// The kind of a Dangi calendar
+        Dangi = 11,
+        /// The kind of an observational Islamic calendar
+        IslamicObservational = 12,
+        /// The kind of an Umm al-Qura Islamic calendar
+        IslamicUmmAlQura = 13,
+        /// The kind of a civil Islamic calendar
+        IslamicCivil = 14,
+        /// The kind of a tabular Islamic calendar
+        IslamicTabular = 15,
+        /// The kind of a Persian calendar
+        Persian = 16,
+        /// The kind of a Roc calendar
+        Roc = 17,
     }
 
     impl ICU4XAnyCalendarKind {
@@ -288,6 +288,8 @@ impl From<DecimalError> for ICU4XError {
     fn from(e: DecimalError) -> Self {
         let ret = match e {
             DecimalError::Data(e) => e.into(),
+            DecimalError::Parse(_) => ICU4XError::FixedDecimalSyntaxError,
+            DecimalError::Limit => ICU4XError::FixedDecimalLimitError,
             _ => ICU4XError::UnknownError,
         };
         log_conversion(&e, ret);
@@ -54,4 +54,49 @@ export enum ICU4XAnyCalendarKind {
    * The kind of a Coptic calendar
    */
   Coptic = 'Coptic',
+  /**
+
+   * The kind of a Hebrew calendar
+   */
+  Hebrew = 'Hebrew',
+  /**
+
+   * The kind of a Chinese calendar
+   */
+  Chinese = 'Chinese',
+  /**
+
+   * The kind of a Dangi calendar
+   */
+  Dangi = 'Dangi',
+  /**
+
+   * The kind of an observational Islamic calendar
+   */
+  IslamicObservational = 'IslamicObservational',
+  /**
+
+   * The kind of an Umm al-Qura Islamic calendar
+   */
+  IslamicUmmAlQura = 'IslamicUmmAlQura',
+  /**
+
+   * The kind of a civil Islamic calendar
+   */
+  IslamicCivil = 'IslamicCivil',
+  /**
+
+   * The kind of a tabular Islamic calendar
+   */
+  IslamicTabular = 'IslamicTabular',
+  /**
+
+   * The kind of a Persian calendar
+   */
+  Persian = 'Persian',
+  /**
+
+   * The kind of a Roc calendar
+   */
+  Roc = 'Roc',
 }
\ No newline at end of file
@@ -12,6 +12,15 @@ export const ICU4XAnyCalendarKind_js_to_rust = {
   "EthiopianAmeteAlem": 6,
   "Indian": 7,
   "Coptic": 8,
+  "Hebrew": 9,
+  "Chinese": 10,
+  "Dangi": 11,
+  "IslamicObservational": 12,
+  "IslamicUmmAlQura": 13,
+  "IslamicCivil": 14,
+  "IslamicTabular": 15,
+  "Persian": 16,
+  "Roc": 17,
 };
 
 export const ICU4XAnyCalendarKind_rust_to_js = {
@@ -24,6 +33,15 @@ export const ICU4XAnyCalendarKind_rust_to_js = {
   [6]: "EthiopianAmeteAlem",
   [7]: "Indian",
   [8]: "Coptic",
+  [9]: "Hebrew",
+  [10]: "Chinese",
+  [11]: "Dangi",
+  [12]: "IslamicObservational",
+  [13]: "IslamicUmmAlQura",
+  [14]: "IslamicCivil",
+  [15]: "IslamicTabular",
+  [16]: "Persian",
+  [17]: "Roc",
 };
 
 export const ICU4XAnyCalendarKind = {
@@ -36,4 +54,13 @@ export const ICU4XAnyCalendarKind = {
   "EthiopianAmeteAlem": "EthiopianAmeteAlem",
   "Indian": "Indian",
   "Coptic": "Coptic",
+  "Hebrew": "Hebrew",
+  "Chinese": "Chinese",
+  "Dangi": "Dangi",
+  "IslamicObservational": "IslamicObservational",
+  "IslamicUmmAlQura": "IslamicUmmAlQura",
+  "IslamicCivil": "IslamicCivil",
+  "IslamicTabular": "IslamicTabular",
+  "Persian": "Persian",
+  "Roc": "Roc",
 };
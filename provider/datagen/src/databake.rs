@@ -165,6 +165,16 @@ impl DataExporter for BakedDataExporter {
             if key == icu_datetime::provider::calendar::DateSkeletonPatternsV1Marker::KEY {
                 feature = quote! { #![cfg(feature = "icu_datetime_experimental")] }
             }
+            #[cfg(feature = "experimental")]
+            if [
+                icu_normalizer::provider::NfkcCasefoldDecompositionSupplementV1Marker::KEY,
+                icu_normalizer::provider::NfkcSimpleCasefoldDecompositionSupplementV1Marker::KEY,
+                icu_normalizer::provider::NfkcCasefoldDecompositionTablesV1Marker::KEY,
+            ]
+            .contains(&key)
+            {
+                feature = quote! { #![cfg(feature = "icu_normalizer_experimental")] }
+            }
             feature
         } else {
             quote!()
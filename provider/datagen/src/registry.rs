@@ -21,9 +21,19 @@ use icu_timezone::provider::*;
 #[cfg(feature = "experimental")]
 use icu_casemapping::provider::*;
 #[cfg(feature = "experimental")]
+use icu_compactdecimal::provider::*;
+#[cfg(feature = "experimental")]
+use icu_currency::provider::*;
+#[cfg(feature = "experimental")]
 use icu_displaynames::provider::*;
 #[cfg(feature = "experimental")]
+use icu_rbnf::provider::*;
+#[cfg(feature = "experimental")]
+use icu_relativetime::provider::*;
+#[cfg(feature = "experimental")]
 use icu_segmenter::provider::*;
+#[cfg(feature = "experimental")]
+use icu_units::provider::*;
 
 macro_rules! registry {
     ($($marker:ident,)+ #[cfg(feature = "experimental")] { $($exp_marker:ident,)+ }) => {
@@ -83,6 +93,7 @@ macro_rules! registry {
 }
 
 registry!(
+    AlgorithmicSymbolsV1Marker,
     AliasesV1Marker,
     AlnumV1Marker,
     AlphabeticV1Marker,
@@ -108,6 +119,8 @@ registry!(
     ChangesWhenNfkcCasefoldedV1Marker,
     ChangesWhenTitlecasedV1Marker,
     ChangesWhenUppercasedV1Marker,
+    ChineseDateLengthsV1Marker,
+    ChineseDateSymbolsV1Marker,
     CollationDataV1Marker,
     CollationDiacriticsV1Marker,
     CollationFallbackSupplementV1Marker,
@@ -119,6 +132,8 @@ registry!(
     CompatibilityDecompositionTablesV1Marker,
     CopticDateLengthsV1Marker,
     CopticDateSymbolsV1Marker,
+    DangiDateLengthsV1Marker,
+    DangiDateSymbolsV1Marker,
     DashV1Marker,
     DecimalSymbolsV1Marker,
     DefaultIgnorableCodePointV1Marker,
@@ -144,6 +159,8 @@ registry!(
     GraphV1Marker,
     GregorianDateLengthsV1Marker,
     GregorianDateSymbolsV1Marker,
+    HebrewDateLengthsV1Marker,
+    HebrewDateSymbolsV1Marker,
     HexDigitV1Marker,
     HyphenV1Marker,
     IdContinueV1Marker,
@@ -153,6 +170,9 @@ registry!(
     IdsTrinaryOperatorV1Marker,
     IndianDateLengthsV1Marker,
     IndianDateSymbolsV1Marker,
+    IslamicDateLengthsV1Marker,
+    IslamicDateSymbolsV1Marker,
+    IslamicUmmAlQuraCacheV1Marker,
     JapaneseDateLengthsV1Marker,
     JapaneseDateSymbolsV1Marker,
     JapaneseErasV1Marker,
@@ -160,6 +180,7 @@ registry!(
     JapaneseExtendedDateSymbolsV1Marker,
     JapaneseExtendedErasV1Marker,
     JoinControlV1Marker,
+    LanguageMatchingV1Marker,
     LikelySubtagsV1Marker,
     LineBreakV1Marker,
     LocaleFallbackLikelySubtagsV1Marker,
@@ -178,15 +199,20 @@ registry!(
     NfkdInertV1Marker,
     NoncharacterCodePointV1Marker,
     NonRecursiveDecompositionSupplementV1Marker,
+    NotationSymbolsV1Marker,
     OrdinalV1Marker,
     OrListV1Marker,
     PatternSyntaxV1Marker,
     PatternWhiteSpaceV1Marker,
+    PersianDateLengthsV1Marker,
+    PersianDateSymbolsV1Marker,
     PrependedConcatenationMarkV1Marker,
     PrintV1Marker,
     QuotationMarkV1Marker,
     RadicalV1Marker,
     RegionalIndicatorV1Marker,
+    RocDateLengthsV1Marker,
+    RocDateSymbolsV1Marker,
     ScriptV1Marker,
     ScriptWithExtensionsPropertyV1Marker,
     SegmentStarterV1Marker,
@@ -211,8 +237,46 @@ registry!(
     #[cfg(feature = "experimental")]
     {
         CaseMappingV1Marker,
+        NfkcCasefoldDecompositionSupplementV1Marker,
+        NfkcCasefoldDecompositionTablesV1Marker,
+        NfkcSimpleCasefoldDecompositionSupplementV1Marker,
+        ShortCompactDecimalFormatDataV1Marker,
+        LongCompactDecimalFormatDataV1Marker,
+        CurrencyEssentialsV1Marker,
+        CurrencyNamesV1Marker,
+        CurrencyFractionsV1Marker,
         DateSkeletonPatternsV1Marker,
         TerritoryDisplayNamesV1Marker,
+        LanguageDisplayNamesV1Marker,
+        LocaleDisplayNamesV1Marker,
+        ScriptDisplayNamesV1Marker,
+        VariantDisplayNamesV1Marker,
+        KeyDisplayNamesV1Marker,
+        LocaleDisplayPatternsV1Marker,
+        CurrencyDisplayNamesV1Marker,
+        CalendarDisplayNamesV1Marker,
+        UnitDisplayNamesV1Marker,
+        SecondRelativeTimeFormatDataV1Marker,
+        MinuteRelativeTimeFormatDataV1Marker,
+        HourRelativeTimeFormatDataV1Marker,
+        DayRelativeTimeFormatDataV1Marker,
+        WeekRelativeTimeFormatDataV1Marker,
+        MonthRelativeTimeFormatDataV1Marker,
+        QuarterRelativeTimeFormatDataV1Marker,
+        YearRelativeTimeFormatDataV1Marker,
+        SundayRelativeTimeFormatDataV1Marker,
+        MondayRelativeTimeFormatDataV1Marker,
+        TuesdayRelativeTimeFormatDataV1Marker,
+        WednesdayRelativeTimeFormatDataV1Marker,
+        ThursdayRelativeTimeFormatDataV1Marker,
+        FridayRelativeTimeFormatDataV1Marker,
+        SaturdayRelativeTimeFormatDataV1Marker,
+        LongUnitsFormatDataV1Marker,
+        ShortUnitsFormatDataV1Marker,
+        NarrowUnitsFormatDataV1Marker,
+        UnitsInfoV1Marker,
+        SpelloutRulesV1Marker,
+        OrdinalRulesV1Marker,
         GraphemeClusterBreakDataV1Marker,
         LineBreakDataV1Marker,
         LstmDataV1Marker,
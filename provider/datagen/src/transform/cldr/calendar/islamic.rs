@@ -0,0 +1,32 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_calendar::provider::*;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+
+/// The range of extended years for which Umm al-Qura month lengths are precomputed,
+/// covering roughly 1883 to 2174 CE
+const UMM_AL_QURA_CACHED_YEARS: core::ops::Range<i32> = 1300..1601;
+
+impl DataProvider<IslamicUmmAlQuraCacheV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        _req: DataRequest,
+    ) -> Result<DataResponse<IslamicUmmAlQuraCacheV1Marker>, DataError> {
+        // The cache is computed astronomically and does not depend on any source data
+        Ok(DataResponse {
+            metadata: DataResponseMetadata::default(),
+            payload: Some(DataPayload::from_owned(
+                IslamicUmmAlQuraCacheV1::compute_for(UMM_AL_QURA_CACHED_YEARS),
+            )),
+        })
+    }
+}
+
+impl IterableDataProvider<IslamicUmmAlQuraCacheV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![Default::default()])
+    }
+}
@@ -2,5 +2,7 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+/// Data for calendar arithmetic
+pub mod islamic;
 /// Data for calendar arithmetic
 pub mod japanese;
@@ -0,0 +1,56 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing the Unicode extension keywords in CLDR JSON BCP-47 files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-bcp47/bcp47/calendar.json>
+
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct TypeData {
+    #[serde(rename = "_description")]
+    pub description: String,
+    /// Space-separated legacy names of the type, such as `gregorian` for `gregory`.
+    #[serde(rename = "_alias")]
+    pub alias: Option<String>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum KeyEntry {
+    Type(TypeData),
+    Attribute(String),
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct KeyData {
+    /// Space-separated legacy names of the key, such as `calendar` for `ca`.
+    #[serde(rename = "_alias")]
+    pub alias: Option<String>,
+    #[serde(flatten)]
+    pub entries: HashMap<String, KeyEntry>,
+}
+
+impl KeyData {
+    /// Iterates over the types of this key, with their data.
+    pub fn types(&self) -> impl Iterator<Item = (&str, &TypeData)> {
+        self.entries.iter().filter_map(|(name, entry)| match entry {
+            KeyEntry::Type(data) => Some((name.as_str(), data)),
+            KeyEntry::Attribute(_) => None,
+        })
+    }
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Keyword {
+    pub u: HashMap<String, KeyData>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Resource {
+    pub keyword: Keyword,
+}
@@ -100,7 +100,7 @@ impl LengthPattern {
     }
 }
 
-#[derive(PartialEq, Debug, Deserialize, Clone)]
+#[derive(PartialEq, Debug, Deserialize, Clone, Default)]
 pub struct Eras {
     #[serde(rename = "eraNames")]
     pub names: HashMap<String, String>,
@@ -110,6 +110,48 @@ pub struct Eras {
     pub narrow: HashMap<String, String>,
 }
 
+/// A pattern like `"{0}bis"` for the name of a leap month, where `{0}` is the name
+/// of the corresponding regular month.
+#[derive(PartialEq, Debug, Deserialize, Clone)]
+pub struct MonthPattern {
+    pub leap: String,
+}
+
+#[derive(PartialEq, Debug, Deserialize, Clone)]
+pub struct MonthPatternWidths {
+    pub abbreviated: Option<MonthPattern>,
+    pub narrow: Option<MonthPattern>,
+    pub wide: Option<MonthPattern>,
+}
+
+#[derive(PartialEq, Debug, Deserialize, Clone)]
+pub struct MonthPatterns {
+    pub format: MonthPatternWidths,
+    #[serde(rename = "stand-alone")]
+    pub stand_alone: Option<MonthPatternWidths>,
+}
+
+/// The names of the years of a cycle, keyed by their 1-based position in the cycle.
+#[derive(PartialEq, Debug, Deserialize, Clone)]
+pub struct CyclicNames(pub HashMap<String, String>);
+
+#[derive(PartialEq, Debug, Deserialize, Clone)]
+pub struct CyclicNameWidths {
+    pub abbreviated: Option<CyclicNames>,
+    pub narrow: Option<CyclicNames>,
+    pub wide: Option<CyclicNames>,
+}
+
+#[derive(PartialEq, Debug, Deserialize, Clone)]
+pub struct CyclicNameContexts {
+    pub format: CyclicNameWidths,
+}
+
+#[derive(PartialEq, Debug, Deserialize, Clone)]
+pub struct CyclicNameSets {
+    pub years: Option<CyclicNameContexts>,
+}
+
 #[derive(PartialEq, Debug, Deserialize, Clone)]
 pub struct LengthPatterns {
     pub full: LengthPattern,
@@ -139,7 +181,13 @@ pub struct AvailableFormats(pub HashMap<String, String>);
 #[derive(PartialEq, Debug, Deserialize, Clone)]
 pub struct Dates {
     pub months: months::Contexts,
+    #[serde(rename = "monthPatterns")]
+    pub month_patterns: Option<MonthPatterns>,
+    #[serde(rename = "cyclicNameSets")]
+    pub cyclic_name_sets: Option<CyclicNameSets>,
     pub days: days::Contexts,
+    // Lunisolar calendars like the Chinese calendar have no eras
+    #[serde(default)]
     pub eras: Eras,
     #[serde(rename = "dayPeriods")]
     pub day_periods: day_periods::Contexts,
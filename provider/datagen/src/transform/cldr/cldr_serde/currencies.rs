@@ -0,0 +1,52 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON currencies.json files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-numbers-full/main/en/currencies.json>
+
+use icu_locid::LanguageIdentifier;
+use serde::Deserialize;
+use std::collections::HashMap;
+use tinystr::TinyAsciiStr;
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Currency {
+    #[serde(rename = "displayName")]
+    pub display_name: Option<String>,
+    #[serde(rename = "displayName-count-zero")]
+    pub display_name_zero: Option<String>,
+    #[serde(rename = "displayName-count-one")]
+    pub display_name_one: Option<String>,
+    #[serde(rename = "displayName-count-two")]
+    pub display_name_two: Option<String>,
+    #[serde(rename = "displayName-count-few")]
+    pub display_name_few: Option<String>,
+    #[serde(rename = "displayName-count-many")]
+    pub display_name_many: Option<String>,
+    #[serde(rename = "displayName-count-other")]
+    pub display_name_other: Option<String>,
+    pub symbol: Option<String>,
+    #[serde(rename = "symbol-alt-narrow")]
+    pub symbol_narrow: Option<String>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Currencies {
+    pub currencies: HashMap<TinyAsciiStr<3>, Currency>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct LangCurrencies {
+    pub numbers: Currencies,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct LangData(pub HashMap<LanguageIdentifier, LangCurrencies>);
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Resource {
+    pub main: LangData,
+}
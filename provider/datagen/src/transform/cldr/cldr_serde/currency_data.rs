@@ -0,0 +1,40 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON currencyData.json files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-core/supplemental/currencyData.json>
+
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Fraction {
+    #[serde(rename = "_digits")]
+    pub digits: String,
+    #[serde(rename = "_rounding")]
+    pub rounding: String,
+    #[serde(rename = "_cashDigits")]
+    pub cash_digits: Option<String>,
+    #[serde(rename = "_cashRounding")]
+    pub cash_rounding: Option<String>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct CurrencyData {
+    /// Map from an ISO 4217 code, or "DEFAULT", to the fraction information
+    pub fractions: HashMap<String, Fraction>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Supplemental {
+    #[serde(rename = "currencyData")]
+    pub currency_data: CurrencyData,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Resource {
+    pub supplemental: Supplemental,
+}
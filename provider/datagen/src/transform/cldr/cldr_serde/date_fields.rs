@@ -0,0 +1,67 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON dateFields.json files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-dates-full/main/en/dateFields.json>
+
+use icu_locid::LanguageIdentifier;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct PluralRulesPattern {
+    #[serde(rename = "relativeTimePattern-count-zero")]
+    pub zero: Option<String>,
+    #[serde(rename = "relativeTimePattern-count-one")]
+    pub one: Option<String>,
+    #[serde(rename = "relativeTimePattern-count-two")]
+    pub two: Option<String>,
+    #[serde(rename = "relativeTimePattern-count-few")]
+    pub few: Option<String>,
+    #[serde(rename = "relativeTimePattern-count-many")]
+    pub many: Option<String>,
+    #[serde(rename = "relativeTimePattern-count-other")]
+    pub other: String,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Field {
+    #[serde(rename = "relativeTime-type-past")]
+    pub past: Option<PluralRulesPattern>,
+    #[serde(rename = "relativeTime-type-future")]
+    pub future: Option<PluralRulesPattern>,
+    /// The remaining string-valued entries, such as "displayName" and "relative-type--1".
+    #[serde(flatten)]
+    pub strings: HashMap<String, String>,
+}
+
+impl Field {
+    /// The names for specific offsets, from the "relative-type-{offset}" entries.
+    pub fn relatives(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.strings.iter().filter_map(|(key, value)| {
+            key.strip_prefix("relative-type-")
+                .map(|offset| (offset, value.as_str()))
+        })
+    }
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Dates {
+    pub fields: HashMap<String, Field>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct LangDates {
+    pub dates: Dates,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct LangData(pub HashMap<LanguageIdentifier, LangDates>);
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Resource {
+    pub main: LangData,
+}
@@ -4,22 +4,48 @@
 
 //! Serde structs representing CLDR JSON display name files.
 //!
-//! Sample file:
+//! Sample files:
 //! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-localenames-full/main/en/territories.json>
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-localenames-full/main/en/languages.json>
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-localenames-full/main/en/localeDisplayNames.json>
 
 use icu_locid::LanguageIdentifier;
 use serde::Deserialize;
 use std::collections::HashMap;
 
 #[derive(PartialEq, Debug, Deserialize)]
-pub struct Territories {
+pub struct LocaleDisplayPattern {
+    #[serde(rename = "localePattern")]
+    pub locale_pattern: String,
+    #[serde(rename = "localeSeparator")]
+    pub locale_separator: String,
+    #[serde(rename = "localeKeyTypePattern")]
+    pub locale_key_type_pattern: String,
+}
+
+/// The `localeDisplayNames` object. Each file only contains some of the fields.
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct LocaleDisplayNames {
+    #[serde(default)]
     pub territories: HashMap<String, String>,
+    #[serde(default)]
+    pub languages: HashMap<String, String>,
+    #[serde(default)]
+    pub scripts: HashMap<String, String>,
+    #[serde(default)]
+    pub variants: HashMap<String, String>,
+    #[serde(rename = "localeDisplayPattern")]
+    pub locale_display_pattern: Option<LocaleDisplayPattern>,
+    #[serde(default)]
+    pub keys: HashMap<String, String>,
+    #[serde(default)]
+    pub types: HashMap<String, HashMap<String, String>>,
 }
 
 #[derive(PartialEq, Debug, Deserialize)]
 pub struct LangDisplayNames {
     #[serde(rename = "localeDisplayNames")]
-    pub localedisplaynames: Territories,
+    pub localedisplaynames: LocaleDisplayNames,
 }
 
 #[derive(PartialEq, Debug, Deserialize)]
@@ -0,0 +1,44 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON languageMatching.json files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-core/supplemental/languageMatching.json>
+
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// An entry of the `written-new` list. Depending on its key, it is the list of paradigm
+/// locales, a match variable, or a rule whose key is the supported locale pattern.
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Entry {
+    #[serde(rename = "_locales")]
+    pub locales: Option<String>,
+    #[serde(rename = "_value")]
+    pub value: Option<String>,
+    #[serde(rename = "_desired")]
+    pub desired: Option<String>,
+    #[serde(rename = "_distance")]
+    pub distance: Option<String>,
+    #[serde(rename = "_oneway")]
+    pub oneway: Option<String>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct LanguageMatching {
+    #[serde(rename = "written-new")]
+    pub written_new: Vec<HashMap<String, Entry>>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Supplemental {
+    #[serde(rename = "languageMatching")]
+    pub language_matching: LanguageMatching,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Resource {
+    pub supplemental: Supplemental,
+}
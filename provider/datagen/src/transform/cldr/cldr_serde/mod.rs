@@ -8,14 +8,23 @@
 //! deserialization support. These structures can be used in the transformers.
 
 pub mod aliases;
+pub mod bcp47_keywords;
 pub mod ca;
+pub mod currencies;
+pub mod currency_data;
+pub mod date_fields;
 pub mod displaynames;
 pub mod japanese;
+pub mod language_matching;
 pub mod likely_subtags;
 pub mod list_patterns;
 pub mod numbering_systems;
 pub mod numbers;
 pub mod parent_locales;
 pub mod plurals;
+pub mod rbnf;
+pub mod territory_containment;
 pub mod time_zones;
+pub mod units;
+pub mod units_data;
 pub mod week_data;
@@ -23,11 +23,69 @@ pub struct Symbols {
     pub minus_sign: String,
     #[serde(rename = "plusSign")]
     pub plus_sign: String,
+    #[serde(rename = "percentSign")]
+    pub percent_sign: String,
+    #[serde(rename = "perMille")]
+    pub per_mille: String,
+    pub exponential: String,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct DecimalFormatLength {
+    /// Map from a CLDR key, such as "1000-count-one", to a compact decimal pattern
+    #[serde(rename = "decimalFormat")]
+    pub decimal_format: HashMap<String, String>,
 }
 
 #[derive(PartialEq, Debug, Deserialize)]
 pub struct DecimalFormats {
     pub standard: String,
+    pub short: DecimalFormatLength,
+    pub long: DecimalFormatLength,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct PercentFormats {
+    pub standard: String,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct ScientificFormats {
+    pub standard: String,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct CurrencySpacingEntry {
+    #[serde(rename = "insertBetween")]
+    pub insert_between: String,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct CurrencySpacing {
+    #[serde(rename = "beforeCurrency")]
+    pub before_currency: CurrencySpacingEntry,
+    #[serde(rename = "afterCurrency")]
+    pub after_currency: CurrencySpacingEntry,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct CurrencyFormats {
+    #[serde(rename = "currencySpacing")]
+    pub currency_spacing: CurrencySpacing,
+    pub standard: String,
+    pub accounting: String,
+    #[serde(rename = "unitPattern-count-zero")]
+    pub unit_pattern_zero: Option<String>,
+    #[serde(rename = "unitPattern-count-one")]
+    pub unit_pattern_one: Option<String>,
+    #[serde(rename = "unitPattern-count-two")]
+    pub unit_pattern_two: Option<String>,
+    #[serde(rename = "unitPattern-count-few")]
+    pub unit_pattern_few: Option<String>,
+    #[serde(rename = "unitPattern-count-many")]
+    pub unit_pattern_many: Option<String>,
+    #[serde(rename = "unitPattern-count-other")]
+    pub unit_pattern_other: String,
 }
 
 #[derive(PartialEq, Debug, Default)]
@@ -36,6 +94,12 @@ pub struct NumberingSystemData {
     pub symbols: HashMap<TinyStr8, Symbols>,
     /// Map from numbering system to decimal formats
     pub formats: HashMap<TinyStr8, DecimalFormats>,
+    /// Map from numbering system to currency formats
+    pub currency_formats: HashMap<TinyStr8, CurrencyFormats>,
+    /// Map from numbering system to percent formats
+    pub percent_formats: HashMap<TinyStr8, PercentFormats>,
+    /// Map from numbering system to scientific formats
+    pub scientific_formats: HashMap<TinyStr8, ScientificFormats>,
 }
 
 pub struct NumberingSystemDataVisitor;
@@ -71,8 +135,20 @@ impl<'de> Visitor<'de> for NumberingSystemDataVisitor {
                     let value: DecimalFormats = access.next_value()?;
                     result.formats.insert(numsys, value);
                 }
+                "currencyFormats" => {
+                    let value: CurrencyFormats = access.next_value()?;
+                    result.currency_formats.insert(numsys, value);
+                }
+                "percentFormats" => {
+                    let value: PercentFormats = access.next_value()?;
+                    result.percent_formats.insert(numsys, value);
+                }
+                "scientificFormats" => {
+                    let value: ScientificFormats = access.next_value()?;
+                    result.scientific_formats.insert(numsys, value);
+                }
                 _ => {
-                    // When needed, consume "scientificFormats", "percentFormats", ...
+                    // When needed, consume "miscPatterns", ...
                     // For now, ignore them.
                 }
             }
@@ -0,0 +1,29 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON rbnf files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-rbnf/rbnf/en.json>
+
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// The rules of a rule set as pairs of descriptor and rule text, such as
+/// `["100", "←← hundred[ →→];"]`, in the order of the source.
+pub type RuleSet = Vec<(String, String)>;
+
+/// A group of rule sets, such as "SpelloutRules", keyed by rule set name including the leading
+/// `%` or `%%`.
+pub type RuleSetGroup = HashMap<String, RuleSet>;
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Rbnf {
+    pub rbnf: HashMap<String, RuleSetGroup>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Resource {
+    pub rbnf: Rbnf,
+}
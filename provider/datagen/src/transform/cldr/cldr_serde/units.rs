@@ -0,0 +1,72 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON units.json files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-units-full/main/en/units.json>
+
+use icu_locid::LanguageIdentifier;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// An entry of a units width, which is either a unit such as "length-kilometer", a prefix such
+/// as "10p3", or a compound pattern such as "per" or "power2".
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Entry {
+    #[serde(rename = "displayName")]
+    pub display_name: Option<String>,
+    #[serde(rename = "unitPattern-count-zero")]
+    pub unit_pattern_zero: Option<String>,
+    #[serde(rename = "unitPattern-count-one")]
+    pub unit_pattern_one: Option<String>,
+    #[serde(rename = "unitPattern-count-two")]
+    pub unit_pattern_two: Option<String>,
+    #[serde(rename = "unitPattern-count-few")]
+    pub unit_pattern_few: Option<String>,
+    #[serde(rename = "unitPattern-count-many")]
+    pub unit_pattern_many: Option<String>,
+    #[serde(rename = "unitPattern-count-other")]
+    pub unit_pattern_other: Option<String>,
+    #[serde(rename = "perUnitPattern")]
+    pub per_unit_pattern: Option<String>,
+    #[serde(rename = "unitPrefixPattern")]
+    pub unit_prefix_pattern: Option<String>,
+    #[serde(rename = "compoundUnitPattern")]
+    pub compound_unit_pattern: Option<String>,
+    #[serde(rename = "compoundUnitPattern1")]
+    pub compound_unit_pattern1: Option<String>,
+    #[serde(rename = "compoundUnitPattern1-count-zero")]
+    pub compound_unit_pattern1_zero: Option<String>,
+    #[serde(rename = "compoundUnitPattern1-count-one")]
+    pub compound_unit_pattern1_one: Option<String>,
+    #[serde(rename = "compoundUnitPattern1-count-two")]
+    pub compound_unit_pattern1_two: Option<String>,
+    #[serde(rename = "compoundUnitPattern1-count-few")]
+    pub compound_unit_pattern1_few: Option<String>,
+    #[serde(rename = "compoundUnitPattern1-count-many")]
+    pub compound_unit_pattern1_many: Option<String>,
+    #[serde(rename = "compoundUnitPattern1-count-other")]
+    pub compound_unit_pattern1_other: Option<String>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Units {
+    pub long: HashMap<String, Entry>,
+    pub short: HashMap<String, Entry>,
+    pub narrow: HashMap<String, Entry>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct LangUnits {
+    pub units: Units,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct LangData(pub HashMap<LanguageIdentifier, LangUnits>);
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Resource {
+    pub main: LangData,
+}
@@ -0,0 +1,59 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON supplemental units.json files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-core/supplemental/units.json>
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Constant {
+    #[serde(rename = "_value")]
+    pub value: String,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Quantity {
+    #[serde(rename = "_quantity")]
+    pub quantity: String,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Conversion {
+    #[serde(rename = "_baseUnit")]
+    pub base_unit: String,
+    #[serde(rename = "_factor")]
+    pub factor: Option<String>,
+    #[serde(rename = "_offset")]
+    pub offset: Option<String>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Preference {
+    pub unit: String,
+    pub geq: Option<String>,
+    pub skeleton: Option<String>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Supplemental {
+    #[serde(rename = "unitConstants")]
+    pub unit_constants: BTreeMap<String, Constant>,
+    /// Map from a base unit to the quantity it measures
+    #[serde(rename = "unitQuantities")]
+    pub unit_quantities: BTreeMap<String, Quantity>,
+    #[serde(rename = "convertUnits")]
+    pub convert_units: BTreeMap<String, Conversion>,
+    /// Map from a quantity, a usage and a region to the preferred units, in order
+    #[serde(rename = "unitPreferenceData")]
+    pub unit_preference_data: BTreeMap<String, BTreeMap<String, BTreeMap<String, Vec<Preference>>>>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Resource {
+    pub supplemental: Supplemental,
+}
@@ -0,0 +1,452 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#![cfg(feature = "experimental")]
+
+use crate::transform::cldr::cldr_serde;
+use icu_currency::provider::*;
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_locid::extensions_unicode_key as key;
+use icu_locid::LanguageIdentifier;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::str::FromStr;
+use tinystr::TinyAsciiStr;
+use zerovec::ZeroMap;
+
+impl crate::DatagenProvider {
+    fn get_currencies(
+        &self,
+        langid: &LanguageIdentifier,
+    ) -> Result<&HashMap<TinyAsciiStr<3>, cldr_serde::currencies::Currency>, DataError> {
+        let resource: &cldr_serde::currencies::Resource = self
+            .source
+            .cldr()?
+            .numbers()
+            .read_and_parse(langid, "currencies.json")?;
+
+        Ok(&resource
+            .main
+            .0
+            .get(langid)
+            .expect("CLDR file contains the expected language")
+            .numbers
+            .currencies)
+    }
+
+    fn get_numbers(
+        &self,
+        langid: &LanguageIdentifier,
+    ) -> Result<&cldr_serde::numbers::Numbers, DataError> {
+        let resource: &cldr_serde::numbers::Resource = self
+            .source
+            .cldr()?
+            .numbers()
+            .read_and_parse(langid, "numbers.json")?;
+
+        Ok(&resource
+            .main
+            .0
+            .get(langid)
+            .expect("CLDR file contains the expected language")
+            .numbers)
+    }
+}
+
+impl DataProvider<CurrencyEssentialsV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<CurrencyEssentialsV1Marker>, DataError> {
+        let langid = req.locale.get_langid();
+        let numbers = self.get_numbers(&langid)?;
+
+        let nsname = match req.locale.get_unicode_ext(&key!("nu")) {
+            Some(v) => *v
+                .as_tinystr_slice()
+                .first()
+                .expect("expecting subtag if key is present"),
+            None => numbers.default_numbering_system,
+        };
+
+        let formats = numbers
+            .numsys_data
+            .currency_formats
+            .get(&nsname)
+            .ok_or_else(|| {
+                DataError::custom("Could not find currency formats for numbering system")
+                    .with_display_context(&nsname)
+            })?;
+        let minus_sign = &numbers
+            .numsys_data
+            .symbols
+            .get(&nsname)
+            .ok_or_else(|| {
+                DataError::custom("Could not find symbols for numbering system")
+                    .with_display_context(&nsname)
+            })?
+            .minus_sign;
+
+        let mut symbols = ZeroMap::new();
+        let mut narrow_symbols = ZeroMap::new();
+        for (code, currency) in self.get_currencies(&langid)? {
+            let symbol = currency.symbol.as_deref().unwrap_or_else(|| code.as_str());
+            if symbol != code.as_str() {
+                symbols.insert(code, symbol);
+            }
+            if let Some(narrow) = currency.symbol_narrow.as_deref() {
+                if narrow != symbol {
+                    narrow_symbols.insert(code, narrow);
+                }
+            }
+        }
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(CurrencyEssentialsV1 {
+                standard: parse_currency_patterns(&formats.standard, minus_sign)?,
+                accounting: parse_currency_patterns(&formats.accounting, minus_sign)?,
+                spacing_before_number: Cow::Owned(
+                    formats
+                        .currency_spacing
+                        .before_currency
+                        .insert_between
+                        .clone(),
+                ),
+                spacing_after_number: Cow::Owned(
+                    formats
+                        .currency_spacing
+                        .after_currency
+                        .insert_between
+                        .clone(),
+                ),
+                symbols,
+                narrow_symbols,
+            })),
+        })
+    }
+}
+
+impl IterableDataProvider<CurrencyEssentialsV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        IterableDataProvider::<DecimalSymbolsV1Marker>::supported_locales(self)
+    }
+}
+
+impl DataProvider<CurrencyNamesV1Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<CurrencyNamesV1Marker>, DataError> {
+        let langid = req.locale.get_langid();
+        let numbers = self.get_numbers(&langid)?;
+        let formats = numbers
+            .numsys_data
+            .currency_formats
+            .get(&numbers.default_numbering_system)
+            .ok_or_else(|| {
+                DataError::custom("Could not find currency formats for numbering system")
+                    .with_display_context(&numbers.default_numbering_system)
+            })?;
+        let currencies = self.get_currencies(&langid)?;
+
+        let other = CurrencyPluralNames {
+            pattern: parse_unit_pattern(&formats.unit_pattern_other)?,
+            names: currencies
+                .iter()
+                .filter_map(|(code, currency)| {
+                    Some((code, currency.display_name_other.as_deref()?))
+                })
+                .collect(),
+        };
+
+        let plural_names = |pattern: &Option<String>,
+                            name: fn(&cldr_serde::currencies::Currency) -> &Option<String>|
+         -> Result<Option<CurrencyPluralNames<'static>>, DataError> {
+            let pattern = match pattern {
+                Some(pattern) => parse_unit_pattern(pattern)?,
+                None => return Ok(None),
+            };
+            Ok(Some(CurrencyPluralNames {
+                pattern,
+                // Only the names that differ from those for `other` are stored.
+                names: currencies
+                    .iter()
+                    .filter_map(|(code, currency)| {
+                        let name = name(currency).as_deref()?;
+                        if other.names.get(code) == Some(name) {
+                            None
+                        } else {
+                            Some((code, name))
+                        }
+                    })
+                    .collect(),
+            }))
+        };
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(CurrencyNamesV1 {
+                zero: plural_names(&formats.unit_pattern_zero, |c| &c.display_name_zero)?,
+                one: plural_names(&formats.unit_pattern_one, |c| &c.display_name_one)?,
+                two: plural_names(&formats.unit_pattern_two, |c| &c.display_name_two)?,
+                few: plural_names(&formats.unit_pattern_few, |c| &c.display_name_few)?,
+                many: plural_names(&formats.unit_pattern_many, |c| &c.display_name_many)?,
+                other,
+            })),
+        })
+    }
+}
+
+impl IterableDataProvider<CurrencyNamesV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(self
+            .source
+            .cldr()?
+            .numbers()
+            .list_langs()?
+            .map(DataLocale::from)
+            .collect())
+    }
+}
+
+impl DataProvider<CurrencyFractionsV1Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<CurrencyFractionsV1Marker>, DataError> {
+        if !req.locale.is_empty() {
+            return Err(DataErrorKind::ExtraneousLocale.into_error());
+        }
+
+        let resource: &cldr_serde::currency_data::Resource = self
+            .source
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/currencyData.json")?;
+
+        let fractions = &resource.supplemental.currency_data.fractions;
+        let default = parse_fraction(
+            fractions
+                .get("DEFAULT")
+                .ok_or_else(|| DataError::custom("Missing DEFAULT entry in currencyData"))?,
+        )?;
+        let mut result = ZeroMap::new();
+        for (code, fraction) in fractions.iter() {
+            if code == "DEFAULT" {
+                continue;
+            }
+            let info = parse_fraction(fraction).map_err(|e| e.with_display_context(code))?;
+            // Only the currencies that differ from the default are stored.
+            if info != default {
+                let code = TinyAsciiStr::<3>::from_str(code).map_err(|_| {
+                    DataError::custom("Invalid currency code").with_display_context(code)
+                })?;
+                result.insert(&code, &info);
+            }
+        }
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(CurrencyFractionsV1 {
+                fractions: result,
+                default,
+            })),
+        })
+    }
+}
+
+impl IterableDataProvider<CurrencyFractionsV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![Default::default()])
+    }
+}
+
+fn parse_fraction(
+    fraction: &cldr_serde::currency_data::Fraction,
+) -> Result<FractionInfo, DataError> {
+    let parse = |s: &str| {
+        u8::from_str(s).map_err(|_| DataError::custom("Invalid fraction").with_display_context(s))
+    };
+    let digits = parse(&fraction.digits)?;
+    let rounding = parse(&fraction.rounding)?;
+    Ok(FractionInfo {
+        digits,
+        rounding,
+        // The cash values default to the non-cash values.
+        cash_digits: fraction
+            .cash_digits
+            .as_deref()
+            .map(parse)
+            .transpose()?
+            .unwrap_or(digits),
+        cash_rounding: fraction
+            .cash_rounding
+            .as_deref()
+            .map(parse)
+            .transpose()?
+            .unwrap_or(rounding),
+    })
+}
+
+/// Parses a CLDR currency pattern such as "¤#,##0.00;(¤#,##0.00)", replacing "-" with the
+/// minus sign of the locale.
+fn parse_currency_patterns(
+    pattern: &str,
+    minus_sign: &str,
+) -> Result<CurrencyPatterns<'static>, DataError> {
+    let (positive, negative) = match pattern.split_once(';') {
+        Some((positive, negative)) => (positive, Cow::Borrowed(negative)),
+        // Without an explicit negative subpattern, the negative amount is the positive one
+        // with a minus sign prefixed.
+        None => (pattern, Cow::Owned(format!("-{}", pattern))),
+    };
+    Ok(CurrencyPatterns {
+        positive: parse_currency_subpattern(positive, minus_sign)?,
+        negative: parse_currency_subpattern(&negative, minus_sign)?,
+    })
+}
+
+fn parse_currency_subpattern(
+    pattern: &str,
+    minus_sign: &str,
+) -> Result<CurrencyPattern<'static>, DataError> {
+    let error = || DataError::custom("Invalid currency pattern").with_display_context(pattern);
+
+    // The literal text before, between, and after the placeholders.
+    let mut literals = vec![String::new()];
+    let mut currency_first = None;
+    let mut in_number = false;
+    let mut quoted = false;
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        let is_number = !quoted && matches!(c, '#' | '0'..='9' | ',' | '.' | '@');
+        if is_number && !in_number {
+            currency_first.get_or_insert(false);
+            literals.push(String::new());
+        }
+        in_number = is_number;
+        let literal = literals.last_mut().ok_or_else(error)?;
+        match c {
+            _ if is_number => (),
+            '\'' if chars.peek() == Some(&'\'') => {
+                chars.next();
+                literal.push('\'');
+            }
+            '\'' => quoted = !quoted,
+            '¤' if !quoted => {
+                currency_first.get_or_insert(true);
+                literals.push(String::new());
+            }
+            '-' if !quoted => literal.push_str(minus_sign),
+            c => literal.push(c),
+        }
+    }
+
+    match (literals.as_slice(), currency_first) {
+        ([prefix, infix, suffix], Some(currency_first)) => Ok(CurrencyPattern {
+            prefix: Cow::Owned(prefix.clone()),
+            infix: Cow::Owned(infix.clone()),
+            suffix: Cow::Owned(suffix.clone()),
+            currency_first,
+        }),
+        _ => Err(error()),
+    }
+}
+
+/// Parses a CLDR unit pattern such as "{0} {1}", where {0} is the number and {1} the currency.
+fn parse_unit_pattern(pattern: &str) -> Result<CurrencyPattern<'static>, DataError> {
+    let error = || DataError::custom("Invalid unit pattern").with_display_context(pattern);
+    let number = pattern.find("{0}").ok_or_else(error)?;
+    let currency = pattern.find("{1}").ok_or_else(error)?;
+    let (first, second) = (number.min(currency), number.max(currency));
+    Ok(CurrencyPattern {
+        prefix: Cow::Owned(pattern.get(..first).ok_or_else(error)?.to_string()),
+        infix: Cow::Owned(
+            pattern
+                .get(first + 3..second)
+                .ok_or_else(error)?
+                .to_string(),
+        ),
+        suffix: Cow::Owned(pattern.get(second + 3..).ok_or_else(error)?.to_string()),
+        currency_first: currency < number,
+    })
+}
+
+#[test]
+fn test_parse_currency_patterns() {
+    let patterns = parse_currency_patterns("¤#,##0.00;(¤#,##0.00)", "-").unwrap();
+    assert_eq!(
+        patterns.negative,
+        CurrencyPattern {
+            prefix: Cow::Borrowed("("),
+            infix: Cow::Borrowed(""),
+            suffix: Cow::Borrowed(")"),
+            currency_first: true,
+        }
+    );
+
+    let patterns = parse_currency_patterns("#,##0.00\u{a0}¤", "\u{2212}").unwrap();
+    assert_eq!(
+        patterns.positive,
+        CurrencyPattern {
+            prefix: Cow::Borrowed(""),
+            infix: Cow::Borrowed("\u{a0}"),
+            suffix: Cow::Borrowed(""),
+            currency_first: false,
+        }
+    );
+    assert_eq!(
+        patterns.negative,
+        CurrencyPattern {
+            prefix: Cow::Borrowed("\u{2212}"),
+            infix: Cow::Borrowed("\u{a0}"),
+            suffix: Cow::Borrowed(""),
+            currency_first: false,
+        }
+    );
+
+    assert!(parse_currency_patterns("#,##0.00", "-").is_err());
+}
+
+#[test]
+fn test_currency_names() {
+    use icu_locid::locale;
+    use tinystr::tinystr;
+
+    let provider = crate::DatagenProvider::for_test();
+
+    let ru: DataPayload<CurrencyNamesV1Marker> = provider
+        .load(DataRequest {
+            locale: &locale!("ru").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    let usd = tinystr!(3, "USD");
+    assert_eq!(ru.get().other.names.get(&usd), Some("доллара США"));
+    assert_eq!(
+        ru.get().many.as_ref().unwrap().names.get(&usd),
+        Some("долларов США")
+    );
+    // The name for `few` is the same as for `other`.
+    assert_eq!(ru.get().few.as_ref().unwrap().names.get(&usd), None);
+    assert!(ru.get().zero.is_none());
+
+    let fractions: DataPayload<CurrencyFractionsV1Marker> = provider
+        .load(Default::default())
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    assert_eq!(
+        fractions.get().fractions.get_copied(&tinystr!(3, "CHF")),
+        Some(FractionInfo {
+            digits: 2,
+            rounding: 0,
+            cash_digits: 2,
+            cash_rounding: 5,
+        })
+    );
+    assert_eq!(fractions.get().fractions.get_copied(&usd), None);
+    assert_eq!(fractions.get().default.digits, 2);
+}
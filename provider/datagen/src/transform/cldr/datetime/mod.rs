@@ -28,6 +28,12 @@ lazy_static! {
         (value!("coptic"), "coptic"),
         (value!("indian"), "indian"),
         (value!("ethiopic"), "ethiopic"),
+        (value!("hebrew"), "hebrew"),
+        (value!("chinese"), "chinese"),
+        (value!("dangi"), "dangi"),
+        (value!("islamic"), "islamic"),
+        (value!("persian"), "persian"),
+        (value!("roc"), "roc"),
     ]
     .into_iter()
     .collect();
@@ -308,6 +314,36 @@ impl_data_provider!(
     symbols::convert_dates,
     calendared = "ethiopic"
 );
+impl_data_provider!(
+    HebrewDateSymbolsV1Marker,
+    symbols::convert_dates,
+    calendared = "hebrew"
+);
+impl_data_provider!(
+    ChineseDateSymbolsV1Marker,
+    symbols::convert_dates,
+    calendared = "chinese"
+);
+impl_data_provider!(
+    DangiDateSymbolsV1Marker,
+    symbols::convert_dates,
+    calendared = "dangi"
+);
+impl_data_provider!(
+    IslamicDateSymbolsV1Marker,
+    symbols::convert_dates,
+    calendared = "islamic"
+);
+impl_data_provider!(
+    PersianDateSymbolsV1Marker,
+    symbols::convert_dates,
+    calendared = "persian"
+);
+impl_data_provider!(
+    RocDateSymbolsV1Marker,
+    symbols::convert_dates,
+    calendared = "roc"
+);
 impl_data_provider!(
     TimeSymbolsV1Marker,
     |dates, _| { symbols::convert_times(dates) },
@@ -354,6 +390,36 @@ impl_data_provider!(
     |dates, _| DateLengthsV1::from(dates),
     calendared = "ethiopic"
 );
+impl_data_provider!(
+    HebrewDateLengthsV1Marker,
+    |dates, _| DateLengthsV1::from(dates),
+    calendared = "hebrew"
+);
+impl_data_provider!(
+    ChineseDateLengthsV1Marker,
+    |dates, _| DateLengthsV1::from(dates),
+    calendared = "chinese"
+);
+impl_data_provider!(
+    DangiDateLengthsV1Marker,
+    |dates, _| DateLengthsV1::from(dates),
+    calendared = "dangi"
+);
+impl_data_provider!(
+    IslamicDateLengthsV1Marker,
+    |dates, _| DateLengthsV1::from(dates),
+    calendared = "islamic"
+);
+impl_data_provider!(
+    PersianDateLengthsV1Marker,
+    |dates, _| DateLengthsV1::from(dates),
+    calendared = "persian"
+);
+impl_data_provider!(
+    RocDateLengthsV1Marker,
+    |dates, _| DateLengthsV1::from(dates),
+    calendared = "roc"
+);
 impl_data_provider!(
     TimeLengthsV1Marker,
     |dates, _| TimeLengthsV1::from(dates),
@@ -8,13 +8,22 @@ use icu_datetime::provider::calendar::*;
 use std::borrow::Cow;
 use std::collections::BTreeMap;
 use tinystr::{tinystr, TinyStr16, TinyStr4};
-use zerovec::ZeroMap;
+use zerovec::{VarZeroVec, ZeroMap};
 
 pub fn convert_dates(other: &cldr_serde::ca::Dates, calendar: &str) -> DateSymbolsV1<'static> {
+    let months = match other.month_patterns {
+        Some(ref patterns) => Cow::Owned(with_leap_months(&other.months, patterns)),
+        None => Cow::Borrowed(&other.months),
+    };
     DateSymbolsV1 {
-        months: other.months.get(&get_month_code_map(calendar)),
+        months: months.get(&get_month_code_map(calendar)),
         weekdays: other.days.get(&()),
         eras: convert_eras(&other.eras, calendar),
+        cyclic_years: other
+            .cyclic_name_sets
+            .as_ref()
+            .and_then(|sets| sets.years.as_ref())
+            .and_then(convert_cyclic_years),
     }
 }
 
@@ -24,6 +33,64 @@ pub fn convert_times(other: &cldr_serde::ca::Dates) -> TimeSymbolsV1<'static> {
     }
 }
 
+/// Adds the names of the leap months of lunisolar calendars, which CLDR only provides
+/// as patterns applied to the names of the regular months. The leap months get keys
+/// like `"2-leap"`.
+fn with_leap_months(
+    months: &cldr_serde::ca::months::Contexts,
+    patterns: &cldr_serde::ca::MonthPatterns,
+) -> cldr_serde::ca::months::Contexts {
+    fn add_leap_months(
+        symbols: &mut cldr_serde::ca::months::Symbols,
+        pattern: &Option<cldr_serde::ca::MonthPattern>,
+    ) {
+        if let Some(pattern) = pattern {
+            let leap_months: Vec<(String, String)> = symbols
+                .0
+                .iter()
+                .map(|(k, v)| (format!("{k}-leap"), pattern.leap.replace("{0}", v)))
+                .collect();
+            symbols.0.extend(leap_months);
+        }
+    }
+
+    let mut months = months.clone();
+    let format = &patterns.format;
+    add_leap_months(&mut months.format.abbreviated, &format.abbreviated);
+    add_leap_months(&mut months.format.narrow, &format.narrow);
+    add_leap_months(&mut months.format.wide, &format.wide);
+    if let Some(ref mut stand_alone) = months.stand_alone {
+        let patterns = patterns.stand_alone.as_ref().unwrap_or(format);
+        for (symbols, pattern) in [
+            (&mut stand_alone.abbreviated, &patterns.abbreviated),
+            (&mut stand_alone.narrow, &patterns.narrow),
+            (&mut stand_alone.wide, &patterns.wide),
+        ] {
+            if let Some(symbols) = symbols {
+                add_leap_months(symbols, pattern);
+            }
+        }
+    }
+    months
+}
+
+/// Lists the names of the years of a cycle in cycle order. The `U` field is formatted with
+/// the abbreviated names; the other widths are only used if a locale lacks those.
+fn convert_cyclic_years(
+    years: &cldr_serde::ca::CyclicNameContexts,
+) -> Option<VarZeroVec<'static, str>> {
+    let names = years
+        .format
+        .abbreviated
+        .as_ref()
+        .or(years.format.wide.as_ref())
+        .or(years.format.narrow.as_ref())?;
+    let names = (1..=names.0.len())
+        .map(|i| names.0.get(&i.to_string()).map(String::as_str))
+        .collect::<Option<Vec<&str>>>()?;
+    Some(VarZeroVec::from(&names))
+}
+
 fn convert_eras(eras: &cldr_serde::ca::Eras, calendar: &str) -> Eras<'static> {
     let map = get_era_code_map(calendar);
     let mut out_eras = Eras::default();
@@ -60,10 +127,28 @@ fn get_month_code_map(calendar: &str) -> &'static [TinyStr4] {
         tinystr!(4, "M12"),
         tinystr!(4, "M13"),
     ];
+    // CLDR numbers the Hebrew months 1-13, with Adar I as month 6
+    static HEBREW_MONTH_CODES: &[TinyStr4] = &[
+        tinystr!(4, "M01"),
+        tinystr!(4, "M02"),
+        tinystr!(4, "M03"),
+        tinystr!(4, "M04"),
+        tinystr!(4, "M05"),
+        tinystr!(4, "M05L"),
+        tinystr!(4, "M06"),
+        tinystr!(4, "M07"),
+        tinystr!(4, "M08"),
+        tinystr!(4, "M09"),
+        tinystr!(4, "M10"),
+        tinystr!(4, "M11"),
+        tinystr!(4, "M12"),
+    ];
 
     match calendar {
-        "gregory" | "buddhist" | "japanese" | "japanext" | "indian" => &SOLAR_MONTH_CODES[0..12],
+        "gregory" | "buddhist" | "japanese" | "japanext" | "indian" | "chinese" | "dangi"
+        | "islamic" | "persian" | "roc" => &SOLAR_MONTH_CODES[0..12],
         "coptic" | "ethiopic" => SOLAR_MONTH_CODES,
+        "hebrew" => HEBREW_MONTH_CODES,
         _ => panic!("Month map unknown for {}", calendar),
     }
 }
@@ -91,6 +176,19 @@ fn get_era_code_map(calendar: &str) -> BTreeMap<String, TinyStr16> {
         "indian" => vec![("0".to_string(), tinystr!(16, "saka"))]
             .into_iter()
             .collect(),
+        "hebrew" => vec![("0".to_string(), tinystr!(16, "am"))]
+            .into_iter()
+            .collect(),
+        "islamic" | "persian" => vec![("0".to_string(), tinystr!(16, "ah"))]
+            .into_iter()
+            .collect(),
+        "roc" => vec![
+            ("0".to_string(), tinystr!(16, "roc-inverse")),
+            ("1".to_string(), tinystr!(16, "roc")),
+        ]
+        .into_iter()
+        .collect(),
+        "chinese" | "dangi" => BTreeMap::new(),
         "ethiopic" => vec![
             ("0".to_string(), tinystr!(16, "incar")),
             ("1".to_string(), tinystr!(16, "pre-incar")),
@@ -224,17 +322,34 @@ impl cldr_serde::ca::months::Symbols {
         } else {
             let mut map: ZeroMap<MonthCode, str> = ZeroMap::default();
             for (k, v) in self.0.iter() {
+                // Months that are named differently in leap years (like Adar II in the Hebrew
+                // calendar) have keys like "7-yeartype-leap", and the leap months of lunisolar
+                // calendars have keys like "7-leap"; they get the leap version of the month's code.
+                // For Adar II this code is only used to look up the name: the month itself keeps
+                // the code "M06".
+                let (k, is_leap_variant) = match k
+                    .strip_suffix("-yeartype-leap")
+                    .or_else(|| k.strip_suffix("-leap"))
+                {
+                    Some(k) => (k, true),
+                    None => (&**k, false),
+                };
                 let index: usize = k
                     .parse()
                     .expect("CLDR month indices must parse as numbers!");
                 if index == 0 {
                     panic!("CLDR month indices cannot be zero");
                 }
-                let code = ctx
+                let mut code = *ctx
                     .get(index - 1)
                     .expect("Found out of bounds month index for calendar");
+                if is_leap_variant {
+                    code = format!("{code}L")
+                        .parse()
+                        .expect("Leap month codes fit in four bytes");
+                }
 
-                map.insert(&MonthCode(*code), v);
+                map.insert(&MonthCode(code), v);
             }
             months::SymbolsV1::Other(map)
         }
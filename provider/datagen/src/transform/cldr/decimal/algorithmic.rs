@@ -0,0 +1,210 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::transform::cldr::cldr_serde;
+use crate::transform::cldr::cldr_serde::numbering_systems::NumberingSystemType;
+use icu_decimal::provider::*;
+use icu_locid::extensions::unicode::Value;
+use icu_locid::extensions_unicode_key as key;
+use icu_locid::LanguageIdentifier;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashSet};
+use std::str::FromStr;
+use tinystr::TinyAsciiStr;
+
+/// Where the rules of an algorithmic numbering system are: the language of the rbnf file, the
+/// rule set group, and the rule set.
+///
+/// The `_rules` of a numbering system are either the name of a rule set in the
+/// `NumberingSystemRules` of root, such as "roman-upper", or a path such as
+/// "ja/SpelloutRules/spellout-cardinal".
+fn rules_location(rules: &str) -> Result<(LanguageIdentifier, &str, &str), DataError> {
+    let mut parts = rules.rsplitn(3, '/');
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(rule_set), Some(group), Some(lang)) => Ok((
+            LanguageIdentifier::from_str(lang).map_err(|e| {
+                DataError::custom("Invalid numbering system rules")
+                    .with_display_context(&e)
+                    .with_display_context(rules)
+            })?,
+            group,
+            rule_set,
+        )),
+        _ => Ok((LanguageIdentifier::UND, "NumberingSystemRules", rules)),
+    }
+}
+
+/// The rule set names that a rule text refers to, such as "hebrew-item" in
+/// "י״→%hebrew-item→", without the leading `%` or `%%`.
+fn referenced_rule_sets(text: &str) -> impl Iterator<Item = &str> {
+    text.split('%')
+        .skip(1)
+        .filter_map(|rest| {
+            rest.split(|c: char| !c.is_alphanumeric() && c != '-')
+                .next()
+        })
+        .filter(|name| !name.is_empty())
+}
+
+/// The rule set `name` and the rule sets that its rules refer to, transitively.
+fn with_referenced_rule_sets<'a>(
+    rule_sets: &'a cldr_serde::rbnf::RuleSetGroup,
+    name: &str,
+) -> Result<Vec<&'a String>, DataError> {
+    let find = |name: &str| {
+        rule_sets
+            .get_key_value(&format!("%{}", name))
+            .or_else(|| rule_sets.get_key_value(&format!("%%{}", name)))
+    };
+
+    let mut names = vec![find(name)
+        .ok_or_else(|| DataError::custom("Missing rbnf rule set").with_display_context(name))?];
+    let mut i = 0;
+    while let Some(&(_, rules)) = names.get(i) {
+        for (_, text) in rules {
+            for referenced in referenced_rule_sets(text).filter_map(find) {
+                if !names.contains(&referenced) {
+                    names.push(referenced);
+                }
+            }
+        }
+        i += 1;
+    }
+    Ok(names.into_iter().map(|(name, _)| name).collect())
+}
+
+impl crate::DatagenProvider {
+    /// Returns the `_rules` of the algorithmic numbering systems.
+    fn algorithmic_numbering_systems(&self) -> Result<BTreeMap<TinyAsciiStr<8>, &str>, DataError> {
+        let resource: &cldr_serde::numbering_systems::Resource = self
+            .source
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/numberingSystems.json")?;
+
+        Ok(resource
+            .supplemental
+            .numbering_systems
+            .iter()
+            .filter(|(_, ns)| ns.nstype == NumberingSystemType::Algorithmic)
+            .filter_map(|(nsname, ns)| Some((*nsname, ns.rules.as_deref()?)))
+            .collect())
+    }
+}
+
+impl DataProvider<AlgorithmicSymbolsV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<AlgorithmicSymbolsV1Marker>, DataError> {
+        let nsname = req
+            .locale
+            .get_unicode_ext(&key!("nu"))
+            .filter(|_| req.locale.is_langid_und())
+            .and_then(|nu| nu.as_tinystr_slice().first().copied())
+            .ok_or_else(|| {
+                DataErrorKind::MissingLocale.with_req(AlgorithmicSymbolsV1Marker::KEY, req)
+            })?;
+
+        let systems = self.algorithmic_numbering_systems()?;
+        let rules = systems.get(&nsname).ok_or_else(|| {
+            DataErrorKind::MissingLocale.with_req(AlgorithmicSymbolsV1Marker::KEY, req)
+        })?;
+
+        let (langid, group, rule_set) = rules_location(rules)?;
+
+        let resource: &cldr_serde::rbnf::Resource = self
+            .source
+            .cldr()?
+            .rbnf()
+            .read_and_parse(&super::rbnf::file_name(&langid))?;
+
+        let rule_sets = resource.rbnf.rbnf.get(group).ok_or_else(|| {
+            DataError::custom("Missing rbnf rule set group").with_display_context(rules)
+        })?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(AlgorithmicSymbolsV1 {
+                rule_set: Cow::Owned(rule_set.to_string()),
+                rules: super::rbnf::transform(
+                    rule_sets,
+                    with_referenced_rule_sets(rule_sets, rule_set)?,
+                    super::rbnf::decimal_separator(self, &langid),
+                )?,
+            })),
+        })
+    }
+}
+
+impl IterableDataProvider<AlgorithmicSymbolsV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        let files = self.source.cldr()?.rbnf().list()?.collect::<HashSet<_>>();
+        let mut locales = Vec::new();
+        for (nsname, rules) in self.algorithmic_numbering_systems()? {
+            // The rules of some numbering systems, such as `hanidays` with the Chinese rules,
+            // are only available if the CLDR data includes their language.
+            if !files.contains(&super::rbnf::file_name(&rules_location(rules)?.0)) {
+                continue;
+            }
+            let mut data_locale = DataLocale::default();
+            data_locale.set_unicode_ext(
+                key!("nu"),
+                Value::try_from_single_subtag(nsname.as_bytes())
+                    .expect("CLDR should have valid numbering system names"),
+            );
+            locales.push(data_locale);
+        }
+        Ok(locales)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locid::locale;
+
+    fn load(locale: icu_locid::Locale) -> DataPayload<AlgorithmicSymbolsV1Marker> {
+        crate::DatagenProvider::for_test()
+            .load(DataRequest {
+                locale: &locale.into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_basic() {
+        let roman = load(locale!("und-u-nu-roman"));
+        assert_eq!(roman.get().rule_set, "roman-upper");
+        assert_eq!(
+            roman.get().rules.rule_sets.iter_keys().collect::<Vec<_>>(),
+            ["roman-upper"]
+        );
+
+        // The rule sets that the rules refer to are included.
+        let hebrew = load(locale!("und-u-nu-hebr"));
+        assert_eq!(hebrew.get().rule_set, "hebrew");
+        assert!(hebrew.get().rules.rule_sets.get("hebrew-item").is_some());
+        assert!(hebrew.get().rules.rule_sets.get("roman-upper").is_none());
+
+        // Rules from the spellout rules of a language, without the rule sets of the group that
+        // it does not refer to.
+        let japanese = load(locale!("und-u-nu-jpanyear"));
+        assert_eq!(japanese.get().rule_set, "spellout-numbering-year-latn");
+        assert_eq!(
+            japanese
+                .get()
+                .rules
+                .rule_sets
+                .iter_keys()
+                .collect::<Vec<_>>(),
+            ["spellout-numbering-year-latn"]
+        );
+    }
+}
@@ -0,0 +1,358 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::transform::cldr::cldr_serde;
+use icu_compactdecimal::provider::*;
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_locid::extensions_unicode_key as key;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use zerovec::ule::encode_varule_to_box;
+use zerovec::ZeroMap2d;
+
+macro_rules! implement {
+    ($marker:ident, $length:ident) => {
+        impl DataProvider<$marker> for crate::DatagenProvider {
+            fn load(&self, req: DataRequest) -> Result<DataResponse<$marker>, DataError> {
+                let langid = req.locale.get_langid();
+
+                let resource: &cldr_serde::numbers::Resource = self
+                    .source
+                    .cldr()?
+                    .numbers()
+                    .read_and_parse(&langid, "numbers.json")?;
+
+                let numbers = &resource
+                    .main
+                    .0
+                    .get(&langid)
+                    .expect("CLDR file contains the expected language")
+                    .numbers;
+
+                let nsname = match req.locale.get_unicode_ext(&key!("nu")) {
+                    Some(v) => *v
+                        .as_tinystr_slice()
+                        .first()
+                        .expect("expecting subtag if key is present"),
+                    None => numbers.default_numbering_system,
+                };
+
+                let result = CompactDecimalPatternDataV1::try_from(
+                    &numbers
+                        .numsys_data
+                        .formats
+                        .get(&nsname)
+                        .ok_or_else(|| {
+                            DataError::custom("Could not find formats for numbering system")
+                                .with_display_context(&nsname)
+                        })?
+                        .$length,
+                )
+                .map_err(|s| {
+                    DataError::custom("Could not create compact decimal patterns")
+                        .with_display_context(&s)
+                        .with_display_context(&nsname)
+                })?;
+
+                Ok(DataResponse {
+                    metadata: Default::default(),
+                    payload: Some(DataPayload::from_owned(result)),
+                })
+            }
+        }
+
+        impl IterableDataProvider<$marker> for crate::DatagenProvider {
+            fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+                IterableDataProvider::<DecimalSymbolsV1Marker>::supported_locales(self)
+            }
+        }
+    };
+}
+
+implement!(ShortCompactDecimalFormatDataV1Marker, short);
+implement!(LongCompactDecimalFormatDataV1Marker, long);
+
+/// Parses a CLDR compact decimal pattern such as "00 K" for the type 10000 (magnitude 4).
+fn parse_pattern(pattern: &str, magnitude: i8) -> Result<Pattern<'static>, Cow<'static, str>> {
+    // The special pattern "0" indicates that numbers of this magnitude are not abbreviated.
+    if pattern == "0" {
+        return Ok(Pattern {
+            exponent: 0,
+            index: 0,
+            literal_text: Cow::Borrowed(""),
+        });
+    }
+
+    let mut literal_text = String::new();
+    let mut index = None;
+    let mut zeros: i8 = 0;
+    let mut quoted = false;
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' if chars.peek() == Some(&'\'') => {
+                chars.next();
+                literal_text.push('\'');
+            }
+            '\'' => quoted = !quoted,
+            '0' if !quoted => {
+                match index {
+                    None => index = Some(literal_text.len()),
+                    Some(i) if i != literal_text.len() => {
+                        return Err(format!("Non-contiguous placeholder in {:?}", pattern).into())
+                    }
+                    _ => (),
+                }
+                zeros += 1;
+            }
+            _ => literal_text.push(c),
+        }
+    }
+    if quoted {
+        return Err(format!("Unterminated quote in {:?}", pattern).into());
+    }
+
+    let (index, exponent) = match index {
+        Some(index) => (
+            u8::try_from(index)
+                .ok()
+                .filter(|&i| i != Pattern::NO_PLACEHOLDER)
+                .ok_or_else(|| format!("Placeholder index too large in {:?}", pattern))?,
+            magnitude - (zeros - 1),
+        ),
+        None => (Pattern::NO_PLACEHOLDER, magnitude),
+    };
+    if exponent <= 0 {
+        return Err(format!("Too many placeholder digits in {:?}", pattern).into());
+    }
+
+    Ok(Pattern {
+        exponent,
+        index,
+        literal_text: Cow::Owned(literal_text),
+    })
+}
+
+impl TryFrom<&cldr_serde::numbers::DecimalFormatLength> for CompactDecimalPatternDataV1<'static> {
+    type Error = Cow<'static, str>;
+
+    fn try_from(other: &cldr_serde::numbers::DecimalFormatLength) -> Result<Self, Self::Error> {
+        let mut patterns: BTreeMap<i8, BTreeMap<Count, Pattern>> = BTreeMap::new();
+        for (key, pattern) in other.decimal_format.iter() {
+            // Keys are of the form "1000-count-one".
+            let (type_, count) = key
+                .split_once("-count-")
+                .ok_or_else(|| format!("Unexpected key {:?}", key))?;
+            let magnitude = type_
+                .strip_prefix('1')
+                .filter(|zeros| zeros.bytes().all(|b| b == b'0'))
+                .and_then(|zeros| i8::try_from(zeros.len()).ok())
+                .ok_or_else(|| format!("Unexpected type {:?}", type_))?;
+            let count = match count {
+                "zero" => Count::Zero,
+                "one" => Count::One,
+                "two" => Count::Two,
+                "few" => Count::Few,
+                "many" => Count::Many,
+                "other" => Count::Other,
+                "1" => Count::Explicit1,
+                _ => return Err(format!("Unexpected count {:?}", count).into()),
+            };
+            patterns
+                .entry(magnitude)
+                .or_default()
+                .insert(count, parse_pattern(pattern, magnitude)?);
+        }
+
+        // Numbers below the smallest type are not abbreviated.
+        let mut previous = BTreeMap::from([(
+            Count::Other,
+            Pattern {
+                exponent: 0,
+                index: 0,
+                literal_text: Cow::Borrowed(""),
+            },
+        )]);
+        let mut result: ZeroMap2d<i8, Count, PatternULE> = ZeroMap2d::new();
+        for (magnitude, mut plural_map) in patterns {
+            let other = plural_map
+                .get(&Count::Other)
+                .ok_or_else(|| format!("Missing other pattern for magnitude {}", magnitude))?
+                .clone();
+            // Plural categories that use the same pattern as `other` are not stored; the
+            // explicit 1 case is kept, as it takes precedence over the plural category.
+            plural_map.retain(|count, pattern| {
+                matches!(count, Count::Other | Count::Explicit1) || *pattern != other
+            });
+            // Magnitudes that use the same patterns as the next smaller magnitude are not stored.
+            if plural_map == previous {
+                continue;
+            }
+            for (count, pattern) in plural_map.iter() {
+                result.insert(&magnitude, count, &*encode_varule_to_box(pattern));
+            }
+            previous = plural_map;
+        }
+
+        Ok(Self { patterns: result })
+    }
+}
+
+#[test]
+fn test_compact_long() {
+    use icu_locid::locale;
+    use icu_provider::zerofrom::ZeroFrom;
+    use zerovec::ule::AsULE;
+
+    let provider = crate::DatagenProvider::for_test();
+
+    let fr_compact_long: DataPayload<LongCompactDecimalFormatDataV1Marker> = provider
+        .load(DataRequest {
+            locale: &locale!("fr").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    let nonzero_copy: Box<[(i8, Count, Pattern)]> = fr_compact_long
+        .get()
+        .patterns
+        .iter0()
+        .flat_map(|kkv| {
+            let key0 = *kkv.key0();
+            kkv.into_iter1()
+                .map(move |(k, v)| (key0, Count::from_unaligned(*k), Pattern::zero_from(v)))
+        })
+        .collect();
+    assert_eq!(
+        nonzero_copy.as_ref(),
+        [
+            (
+                3,
+                Count::One,
+                Pattern {
+                    exponent: 3,
+                    index: 0,
+                    literal_text: Cow::Borrowed(" millier")
+                }
+            ),
+            (
+                3,
+                Count::Other,
+                Pattern {
+                    exponent: 3,
+                    index: 0,
+                    literal_text: Cow::Borrowed(" mille")
+                }
+            ),
+            (
+                3,
+                Count::Explicit1,
+                Pattern {
+                    exponent: 3,
+                    index: Pattern::NO_PLACEHOLDER,
+                    literal_text: Cow::Borrowed("mille")
+                }
+            ),
+            (
+                4,
+                Count::Other,
+                Pattern {
+                    exponent: 3,
+                    index: 0,
+                    literal_text: Cow::Borrowed(" mille")
+                }
+            ),
+            (
+                6,
+                Count::One,
+                Pattern {
+                    exponent: 6,
+                    index: 0,
+                    literal_text: Cow::Borrowed(" million")
+                }
+            ),
+            (
+                6,
+                Count::Other,
+                Pattern {
+                    exponent: 6,
+                    index: 0,
+                    literal_text: Cow::Borrowed(" millions")
+                }
+            ),
+            (
+                9,
+                Count::One,
+                Pattern {
+                    exponent: 9,
+                    index: 0,
+                    literal_text: Cow::Borrowed(" milliard")
+                }
+            ),
+            (
+                9,
+                Count::Other,
+                Pattern {
+                    exponent: 9,
+                    index: 0,
+                    literal_text: Cow::Borrowed(" milliards")
+                }
+            ),
+            (
+                12,
+                Count::One,
+                Pattern {
+                    exponent: 12,
+                    index: 0,
+                    literal_text: Cow::Borrowed(" billion")
+                }
+            ),
+            (
+                12,
+                Count::Other,
+                Pattern {
+                    exponent: 12,
+                    index: 0,
+                    literal_text: Cow::Borrowed(" billions")
+                }
+            )
+        ]
+    );
+}
+
+#[test]
+fn test_compact_short() {
+    use icu_locid::locale;
+    use icu_provider::zerofrom::ZeroFrom;
+
+    let provider = crate::DatagenProvider::for_test();
+
+    let ja_compact_short: DataPayload<ShortCompactDecimalFormatDataV1Marker> = provider
+        .load(DataRequest {
+            locale: &locale!("ja").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    let patterns = &ja_compact_short.get().patterns;
+    // The "0" pattern for 1000 is not stored, as it is the same as no pattern.
+    assert!(!patterns.contains_key0(&3));
+    assert_eq!(
+        patterns.get_2d(&4, &Count::Other).map(Pattern::zero_from),
+        Some(Pattern {
+            exponent: 4,
+            index: 0,
+            literal_text: Cow::Borrowed("万"),
+        })
+    );
+    // 100000 uses the same pattern as 10000.
+    assert!(!patterns.contains_key0(&5));
+}
@@ -60,6 +60,8 @@ impl FromStr for DecimalSubPattern {
         let (a, b, c, d) = match body {
             "#,##0.###" => (3, 3, 0, 3),
             "#,##,##0.###" => (3, 2, 0, 3),
+            "#,##0" => (3, 3, 0, 0),
+            "#,##,##0" => (3, 2, 0, 0),
             "0.######" => (0, 0, 0, 6),
             _ => return Err(Error::UnknownPatternBody(body.to_string())),
         };
@@ -13,7 +13,12 @@ use std::borrow::Cow;
 use std::convert::TryFrom;
 use tinystr::TinyAsciiStr;
 
+mod algorithmic;
+#[cfg(feature = "experimental")]
+mod compact;
 mod decimal_pattern;
+mod notation;
+pub mod rbnf;
 
 impl crate::DatagenProvider {
     /// Returns the digits for the given numbering system name.
@@ -0,0 +1,175 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::decimal_pattern::DecimalSubPattern;
+use crate::transform::cldr::cldr_serde;
+use icu_decimal::provider::*;
+use icu_locid::extensions_unicode_key as key;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use tinystr::TinyAsciiStr;
+
+impl DataProvider<NotationSymbolsV1Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<NotationSymbolsV1Marker>, DataError> {
+        let langid = req.locale.get_langid();
+
+        let resource: &cldr_serde::numbers::Resource = self
+            .source
+            .cldr()?
+            .numbers()
+            .read_and_parse(&langid, "numbers.json")?;
+
+        let numbers = &resource
+            .main
+            .0
+            .get(&langid)
+            .expect("CLDR file contains the expected language")
+            .numbers;
+
+        let nsname = match req.locale.get_unicode_ext(&key!("nu")) {
+            Some(v) => *v
+                .as_tinystr_slice()
+                .first()
+                .expect("expecting subtag if key is present"),
+            None => numbers.default_numbering_system,
+        };
+
+        let result =
+            NotationSymbolsV1::try_from(NumbersWithNumsys(numbers, nsname)).map_err(|s| {
+                DataError::custom("Could not create notation symbols")
+                    .with_display_context(&s)
+                    .with_display_context(&nsname)
+            })?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(result)),
+        })
+    }
+}
+
+impl IterableDataProvider<NotationSymbolsV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        IterableDataProvider::<DecimalSymbolsV1Marker>::supported_locales(self)
+    }
+}
+
+struct NumbersWithNumsys<'a>(pub &'a cldr_serde::numbers::Numbers, pub TinyAsciiStr<8>);
+
+impl TryFrom<NumbersWithNumsys<'_>> for NotationSymbolsV1<'static> {
+    type Error = Cow<'static, str>;
+
+    fn try_from(other: NumbersWithNumsys<'_>) -> Result<Self, Self::Error> {
+        let NumbersWithNumsys(numbers, nsname) = other;
+        let symbols = numbers
+            .numsys_data
+            .symbols
+            .get(&nsname)
+            .ok_or("Could not find symbols for numbering system")?;
+        let percent_formats = numbers
+            .numsys_data
+            .percent_formats
+            .get(&nsname)
+            .ok_or("Could not find percent formats for numbering system")?;
+        let scientific_formats = numbers
+            .numsys_data
+            .scientific_formats
+            .get(&nsname)
+            .ok_or("Could not find scientific formats for numbering system")?;
+
+        // The sign is applied outside of the pattern, so only the positive subpattern is used.
+        let percent_pattern: DecimalSubPattern = percent_formats
+            .standard
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .parse()
+            .map_err(|e: super::decimal_pattern::Error| e.to_string())?;
+        let localize = |sign: &str| AffixesV1 {
+            prefix: Cow::Owned(percent_pattern.prefix.replace('%', sign)),
+            suffix: Cow::Owned(percent_pattern.suffix.replace('%', sign)),
+        };
+
+        Ok(Self {
+            percent_affixes: localize(&symbols.percent_sign),
+            permille_affixes: localize(&symbols.per_mille),
+            scientific_affixes: parse_scientific_pattern(&scientific_formats.standard)?,
+            exponent_separator: Cow::Owned(symbols.exponential.clone()),
+        })
+    }
+}
+
+/// Parses the affixes of a CLDR scientific pattern such as "#E0".
+fn parse_scientific_pattern(pattern: &str) -> Result<AffixesV1<'static>, Cow<'static, str>> {
+    let pattern = pattern.split(';').next().unwrap_or_default();
+    let start = pattern
+        .find(|c: char| matches!(c, '#' | '0' | ',' | '.'))
+        .ok_or("No body in scientific pattern")?;
+    let exponent = pattern[start..]
+        .find('E')
+        .ok_or("No exponent in scientific pattern")?
+        + start
+        + 1;
+    let end = pattern[exponent..]
+        .find(|c: char| !matches!(c, '0' | '+'))
+        .map_or(pattern.len(), |i| i + exponent);
+    Ok(AffixesV1 {
+        prefix: Cow::Owned(pattern[..start].into()),
+        suffix: Cow::Owned(pattern[end..].into()),
+    })
+}
+
+#[test]
+fn test_parse_scientific_pattern() {
+    assert_eq!(
+        parse_scientific_pattern("#E0"),
+        Ok(AffixesV1 {
+            prefix: "".into(),
+            suffix: "".into(),
+        })
+    );
+    assert_eq!(
+        parse_scientific_pattern("a0.###E+00b"),
+        Ok(AffixesV1 {
+            prefix: "a".into(),
+            suffix: "b".into(),
+        })
+    );
+    assert!(parse_scientific_pattern("E0").is_err());
+    assert!(parse_scientific_pattern("#,##0").is_err());
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid::locale;
+
+    let provider = crate::DatagenProvider::for_test();
+
+    let tr: DataPayload<NotationSymbolsV1Marker> = provider
+        .load(DataRequest {
+            locale: &locale!("tr").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    assert_eq!(tr.get().percent_affixes.prefix, "%");
+    assert_eq!(tr.get().permille_affixes.prefix, "‰");
+    assert_eq!(tr.get().exponent_separator, "E");
+
+    let ar_eg: DataPayload<NotationSymbolsV1Marker> = provider
+        .load(DataRequest {
+            locale: &locale!("ar-EG").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    assert_eq!(ar_eg.get().percent_affixes.suffix, "٪\u{61c}");
+    assert_eq!(ar_eg.get().exponent_separator, "اس");
+}
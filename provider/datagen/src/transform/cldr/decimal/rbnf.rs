@@ -0,0 +1,246 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The conversion of CLDR rule-based number format rules, which are used by algorithmic
+//! numbering systems and by `icu_rbnf`.
+
+use crate::transform::cldr::cldr_serde;
+use icu_decimal::provider::*;
+use icu_locid::LanguageIdentifier;
+use icu_provider::prelude::*;
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::str::FromStr;
+use zerovec::{VarZeroVec, ZeroMap};
+
+/// The rules of `und` are in root.json.
+pub fn file_name(langid: &LanguageIdentifier) -> String {
+    if *langid == LanguageIdentifier::UND {
+        "root.json".to_string()
+    } else {
+        format!("{}.json", langid)
+    }
+}
+
+/// The decimal separator of the locale, which selects between rules such as "x.x" and "x,x".
+pub fn decimal_separator(selff: &crate::DatagenProvider, langid: &LanguageIdentifier) -> char {
+    selff
+        .source
+        .cldr()
+        .ok()
+        .and_then(|cldr| {
+            cldr.numbers()
+                .read_and_parse::<cldr_serde::numbers::Resource>(langid, "numbers.json")
+                .ok()
+        })
+        .and_then(|resource| resource.main.0.get(langid))
+        .and_then(|lang| {
+            lang.numbers
+                .numsys_data
+                .symbols
+                .get(&lang.numbers.default_numbering_system)
+        })
+        .and_then(|symbols| symbols.decimal.chars().next())
+        .unwrap_or('.')
+}
+
+/// The rule set names in the modulus substitutions of a rule text, such as "%%frac" in
+/// "←← point →%%frac→".
+fn modulus_rule_sets(text: &str) -> impl Iterator<Item = &str> {
+    text.split(|c| c == '→' || c == '>')
+        .skip(1)
+        .step_by(2)
+        .filter(|descriptor| descriptor.starts_with('%'))
+        .map(|name| name.trim_start_matches('%'))
+}
+
+/// The kind of a rule that applies to numbers with a fractional part, given the descriptor
+/// with the decimal separator replaced by `.`.
+fn fraction_kind(descriptor: &str) -> Option<RuleKind> {
+    match descriptor {
+        "x.x" => Some(RuleKind::ImproperFraction),
+        "0.x" => Some(RuleKind::ProperFraction),
+        "x.0" => Some(RuleKind::Master),
+        _ => None,
+    }
+}
+
+/// Converts the rule sets `names` of a group, which must include the rule sets that their rules
+/// refer to.
+pub fn transform(
+    rule_sets: &cldr_serde::rbnf::RuleSetGroup,
+    mut names: Vec<&String>,
+    decimal_separator: char,
+) -> Result<RbnfRulesV1<'static>, DataError> {
+    // Rule sets that a fraction rule of another rule set refers to with `>%name>` format
+    // fractional parts, and their base values are denominators.
+    let mut fraction_sets = HashSet::new();
+    for (name, rules) in rule_sets {
+        for (descriptor, text) in rules {
+            if fraction_kind(&descriptor.replace(',', ".")).is_some() {
+                fraction_sets.extend(
+                    modulus_rule_sets(text).filter(|set| *set != name.trim_start_matches('%')),
+                );
+            }
+        }
+    }
+
+    names.sort();
+
+    let mut infos = ZeroMap::new();
+    let mut all_rules = Vec::new();
+    for name in names {
+        let fraction = fraction_sets.contains(name.trim_start_matches('%'));
+        let source = rule_sets.get(name).map(Vec::as_slice).unwrap_or_default();
+        let (mut special, mut normal) = (Vec::new(), Vec::new());
+
+        for (descriptor, text) in source {
+            let error = || {
+                DataError::custom("Invalid rbnf rule")
+                    .with_display_context(name)
+                    .with_display_context(descriptor)
+            };
+            let text = text.strip_suffix(';').unwrap_or(text);
+            let text = text.strip_prefix('\'').unwrap_or(text);
+
+            let (kind, base_value, divisor) = if descriptor == "-x" {
+                (RuleKind::Negative, 0, 1)
+            } else if descriptor == "Inf" || descriptor == "NaN" {
+                // A `FixedDecimal` cannot be infinite or NaN.
+                continue;
+            } else if let Some(kind) = fraction_kind(&descriptor.replace(',', ".")) {
+                // If a rule set has a rule for both decimal separators, such as "x.x" and
+                // "x,x", only the one for the locale's separator is used.
+                let has_alternative = source.iter().any(|(d, _)| {
+                    d != descriptor && d.replace(',', ".") == descriptor.replace(',', ".")
+                });
+                let preferred = if decimal_separator == ',' { ',' } else { '.' };
+                if has_alternative && !descriptor.contains(preferred) {
+                    continue;
+                }
+                (kind, 0, 1)
+            } else {
+                let (base, radix) = descriptor.split_once('/').unwrap_or((descriptor, "10"));
+                let base = u64::from_str(base.trim()).map_err(|_| error())?;
+                let radix = u64::from_str(radix.trim()).map_err(|_| error())?;
+                if radix < 2 {
+                    return Err(error());
+                }
+                let mut divisor = 1u64;
+                while let Some(next) = divisor.checked_mul(radix).filter(|&d| d <= base) {
+                    divisor = next;
+                }
+                (RuleKind::Normal, base, divisor)
+            };
+
+            let target = if kind == RuleKind::Normal {
+                &mut normal
+            } else {
+                &mut special
+            };
+
+            // Optional text in brackets, the way ICU expands it: the rule without the optional
+            // text, followed by the rule with it.
+            let brackets = text
+                .find('[')
+                .and_then(|open| Some((open, open + text.get(open..)?.find(']')?)));
+            let (open, close) = match brackets {
+                Some(brackets) => brackets,
+                None => {
+                    target.push(Rule {
+                        kind,
+                        base_value,
+                        divisor,
+                        text: Cow::Owned(text.to_string()),
+                    });
+                    continue;
+                }
+            };
+            let (before, rest) = text.split_at(open);
+            let (optional, after) = rest.split_at(close - open);
+            let optional = optional.trim_start_matches('[');
+            let after = after.trim_start_matches(']');
+            let with = format!("{}{}{}", before, optional, after);
+            let without = format!("{}{}", before, after);
+
+            match kind {
+                RuleKind::Normal if base_value > 0 && base_value % divisor == 0 => {
+                    target.push(Rule {
+                        kind,
+                        base_value,
+                        divisor,
+                        text: Cow::Owned(without),
+                    });
+                    target.push(Rule {
+                        kind,
+                        base_value: if fraction { base_value } else { base_value + 1 },
+                        divisor,
+                        text: Cow::Owned(with),
+                    });
+                }
+                RuleKind::ImproperFraction => {
+                    target.push(Rule {
+                        kind: RuleKind::ProperFraction,
+                        base_value,
+                        divisor,
+                        text: Cow::Owned(without),
+                    });
+                    target.push(Rule {
+                        kind,
+                        base_value,
+                        divisor,
+                        text: Cow::Owned(with),
+                    });
+                }
+                RuleKind::Master => {
+                    target.push(Rule {
+                        kind,
+                        base_value,
+                        divisor,
+                        text: Cow::Owned(without),
+                    });
+                    target.push(Rule {
+                        kind: RuleKind::ImproperFraction,
+                        base_value,
+                        divisor,
+                        text: Cow::Owned(with),
+                    });
+                }
+                _ => target.push(Rule {
+                    kind,
+                    base_value,
+                    divisor,
+                    text: Cow::Owned(with),
+                }),
+            }
+        }
+
+        if normal
+            .windows(2)
+            .any(|pair| pair[0].base_value > pair[1].base_value)
+        {
+            return Err(DataError::custom("Unordered rbnf rules").with_display_context(name));
+        }
+
+        let index =
+            |len: usize| u16::try_from(len).map_err(|_| DataError::custom("Too many rbnf rules"));
+        let start = index(all_rules.len())?;
+        all_rules.extend(special);
+        all_rules.extend(normal);
+        infos.insert(
+            name.trim_start_matches('%'),
+            &RuleSetInfo {
+                start,
+                end: index(all_rules.len())?,
+                private: name.starts_with("%%"),
+                fraction,
+            },
+        );
+    }
+
+    Ok(RbnfRulesV1 {
+        rule_sets: infos,
+        rules: VarZeroVec::from(&all_rules),
+    })
+}
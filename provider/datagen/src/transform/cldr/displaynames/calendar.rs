@@ -0,0 +1,72 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_displaynames::provider::*;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use zerovec::ZeroMap;
+
+/// The prefix of the calendar entries in [`KeyDisplayNamesV1::types`].
+const CALENDAR_PREFIX: &str = "ca-";
+
+impl DataProvider<CalendarDisplayNamesV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<CalendarDisplayNamesV1Marker>, DataError> {
+        // The calendar names are the display names of the values of the `ca` key.
+        let keys: DataPayload<KeyDisplayNamesV1Marker> = self.load(req)?.take_payload()?;
+
+        let mut names = ZeroMap::new();
+        for (ty, name) in keys.get().types.iter() {
+            if let Some(calendar) = ty.strip_prefix(CALENDAR_PREFIX) {
+                names.insert(calendar, name);
+            }
+        }
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(CalendarDisplayNamesV1 { names })),
+        })
+    }
+}
+
+impl IterableDataProvider<CalendarDisplayNamesV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        self.supported_displaynames_locales()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locid::locale;
+
+    #[test]
+    fn test_basic() {
+        let provider = crate::DatagenProvider::for_test();
+
+        let data: DataPayload<CalendarDisplayNamesV1Marker> = provider
+            .load(DataRequest {
+                locale: &locale!("en-001").into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+
+        assert_eq!(
+            data.get().names.get("buddhist").unwrap(),
+            "Buddhist Calendar"
+        );
+        assert_eq!(
+            data.get().names.get("gregory").unwrap(),
+            "Gregorian Calendar"
+        );
+        assert_eq!(
+            data.get().names.get("islamic-civil").unwrap(),
+            "Islamic Calendar (tabular, civil epoch)"
+        );
+    }
+}
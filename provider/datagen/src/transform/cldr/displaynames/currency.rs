@@ -0,0 +1,143 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::transform::cldr::cldr_serde;
+use icu_displaynames::provider::*;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use zerovec::ZeroMap;
+
+impl DataProvider<CurrencyDisplayNamesV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<CurrencyDisplayNamesV1Marker>, DataError> {
+        let langid = req.locale.get_langid();
+
+        let data: &cldr_serde::currencies::Resource = self
+            .source
+            .cldr()?
+            .numbers()
+            .read_and_parse(&langid, "currencies.json")?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(CurrencyDisplayNamesV1::from(data))),
+        })
+    }
+}
+
+impl IterableDataProvider<CurrencyDisplayNamesV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(self
+            .source
+            .cldr()?
+            .numbers()
+            .list_langs()?
+            .map(DataLocale::from)
+            .collect())
+    }
+}
+
+impl From<&cldr_serde::currencies::Resource> for CurrencyDisplayNamesV1<'static> {
+    fn from(other: &cldr_serde::currencies::Resource) -> Self {
+        let mut names = ZeroMap::new();
+        let mut plural_names = CurrencyPluralDisplayNames {
+            zero: ZeroMap::new(),
+            one: ZeroMap::new(),
+            two: ZeroMap::new(),
+            few: ZeroMap::new(),
+            many: ZeroMap::new(),
+            other: ZeroMap::new(),
+        };
+        for lang_data_entry in other.main.0.iter() {
+            for (currency, entry) in lang_data_entry.1.numbers.currencies.iter() {
+                if let Some(name) = &entry.display_name {
+                    names.insert(currency, name.as_str());
+                }
+                let other = match &entry.display_name_other {
+                    Some(other) => other,
+                    None => continue,
+                };
+                for (map, name) in [
+                    (&mut plural_names.zero, &entry.display_name_zero),
+                    (&mut plural_names.one, &entry.display_name_one),
+                    (&mut plural_names.two, &entry.display_name_two),
+                    (&mut plural_names.few, &entry.display_name_few),
+                    (&mut plural_names.many, &entry.display_name_many),
+                ] {
+                    if let Some(name) = name.as_deref().filter(|n| n != other) {
+                        map.insert(currency, name);
+                    }
+                }
+                plural_names.other.insert(currency, other.as_str());
+            }
+        }
+        Self {
+            names,
+            plural_names,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locid::locale;
+    use tinystr::tinystr;
+
+    #[test]
+    fn test_basic() {
+        let provider = crate::DatagenProvider::for_test();
+
+        let data: DataPayload<CurrencyDisplayNamesV1Marker> = provider
+            .load(DataRequest {
+                locale: &locale!("en").into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+
+        assert_eq!(data.get().names.get(&tinystr!(3, "EUR")).unwrap(), "Euro");
+        assert_eq!(
+            data.get()
+                .plural_names
+                .one
+                .get(&tinystr!(3, "EUR"))
+                .unwrap(),
+            "euro"
+        );
+        assert_eq!(
+            data.get()
+                .plural_names
+                .other
+                .get(&tinystr!(3, "EUR"))
+                .unwrap(),
+            "euros"
+        );
+    }
+
+    #[test]
+    fn test_plural_categories() {
+        let provider = crate::DatagenProvider::for_test();
+
+        let data: DataPayload<CurrencyDisplayNamesV1Marker> = provider
+            .load(DataRequest {
+                locale: &locale!("ru").into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+
+        let plural_names = &data.get().plural_names;
+        let usd = tinystr!(3, "USD");
+        assert_eq!(plural_names.one.get(&usd).unwrap(), "доллар США");
+        assert_eq!(plural_names.many.get(&usd).unwrap(), "долларов США");
+        // The name for `few` is the same as the one for `other`.
+        assert_eq!(plural_names.few.get(&usd), None);
+        assert_eq!(plural_names.other.get(&usd).unwrap(), "доллара США");
+    }
+}
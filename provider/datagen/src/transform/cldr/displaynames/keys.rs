@@ -0,0 +1,164 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::Alt;
+use crate::transform::cldr::cldr_serde;
+use icu_displaynames::provider::*;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use std::collections::{BTreeMap, HashMap};
+use tinystr::TinyAsciiStr;
+use zerovec::ZeroMap;
+
+/// The BCP-47 files defining Unicode extension keys.
+const BCP47_FILES: &[&str] = &[
+    "calendar.json",
+    "collation.json",
+    "currency.json",
+    "measure.json",
+    "number.json",
+    "segmentation.json",
+    "variant.json",
+];
+
+impl DataProvider<KeyDisplayNamesV1Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<KeyDisplayNamesV1Marker>, DataError> {
+        let langid = req.locale.get_langid();
+
+        let data: &cldr_serde::displaynames::Resource = self
+            .source
+            .cldr()?
+            .displaynames()
+            .read_and_parse(&langid, "localeDisplayNames.json")?;
+
+        let bcp47 = BCP47_FILES
+            .iter()
+            .map(|file| self.source.cldr()?.bcp47().read_and_parse(file))
+            .collect::<Result<Vec<&cldr_serde::bcp47_keywords::Resource>, DataError>>()?;
+
+        // The time zone file is read with its own structs. Only the key is needed,
+        // as time zones have no type display names here.
+        let time_zones: &cldr_serde::time_zones::bcp47_tzid::Resource = self
+            .source
+            .cldr()?
+            .bcp47()
+            .read_and_parse("timezone.json")?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(transform(
+                data, &bcp47, time_zones,
+            )?)),
+        })
+    }
+}
+
+impl IterableDataProvider<KeyDisplayNamesV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        self.supported_displaynames_locales()
+    }
+}
+
+/// Converts the display names, which CLDR keys by the legacy key and type names such as
+/// "calendar" and "gregorian", to BCP-47 keys and types such as "ca" and "gregory".
+/// Entries for keys that are not Unicode extension keys are dropped.
+fn transform(
+    other: &cldr_serde::displaynames::Resource,
+    bcp47: &[&cldr_serde::bcp47_keywords::Resource],
+    time_zones: &cldr_serde::time_zones::bcp47_tzid::Resource,
+) -> Result<KeyDisplayNamesV1<'static>, DataError> {
+    let mut key_aliases = HashMap::<&str, &str>::new();
+    key_aliases.insert("tz", "tz");
+    for alias in time_zones.keyword.u.time_zones._alias.split_whitespace() {
+        key_aliases.insert(alias, "tz");
+    }
+    let mut type_aliases = HashMap::<(&str, &str), &str>::new();
+    for (key, key_data) in bcp47.iter().flat_map(|r| r.keyword.u.iter()) {
+        key_aliases.insert(key, key);
+        for alias in key_data.alias.iter().flat_map(|a| a.split_whitespace()) {
+            key_aliases.insert(alias, key);
+        }
+        for (ty, type_data) in key_data.types() {
+            type_aliases.insert((key, ty), ty);
+            for alias in type_data.alias.iter().flat_map(|a| a.split_whitespace()) {
+                type_aliases.insert((key, alias), ty);
+            }
+        }
+    }
+
+    let mut keys = ZeroMap::new();
+    let mut types = ZeroMap::new();
+    for lang_data_entry in other.main.0.iter() {
+        let names = &lang_data_entry.1.localedisplaynames;
+        // Sort the entries, so that the output does not depend on the order of the
+        // hash maps if several CLDR names map to the same BCP-47 name.
+        for (key, name) in names.keys.iter().collect::<BTreeMap<_, _>>() {
+            if let Some(key) = key_aliases.get(key.as_str()) {
+                keys.insert(&to_key(key)?, name.as_str());
+            }
+        }
+        for (key, type_names) in names.types.iter().collect::<BTreeMap<_, _>>() {
+            let key = match key_aliases.get(key.as_str()) {
+                Some(key) => *key,
+                None => continue,
+            };
+            for (ty, name) in type_names.iter().collect::<BTreeMap<_, _>>() {
+                if let Alt::None(ty) = Alt::from(ty.as_str()) {
+                    if let Some(ty) = type_aliases.get(&(key, ty)) {
+                        types.insert(format!("{}-{}", key, ty).as_str(), name.as_str());
+                    }
+                }
+            }
+        }
+    }
+    Ok(KeyDisplayNamesV1 { keys, types })
+}
+
+fn to_key(key: &str) -> Result<TinyAsciiStr<2>, DataError> {
+    TinyAsciiStr::from_str(key).map_err(|e| {
+        DataError::custom("data for KeyDisplayNames")
+            .with_display_context(&e)
+            .with_debug_context(key)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locid::locale;
+    use tinystr::tinystr;
+
+    #[test]
+    fn test_basic() {
+        let provider = crate::DatagenProvider::for_test();
+
+        let data: DataPayload<KeyDisplayNamesV1Marker> = provider
+            .load(DataRequest {
+                locale: &locale!("en-001").into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+
+        assert_eq!(data.get().keys.get(&tinystr!(2, "ca")).unwrap(), "Calendar");
+        assert_eq!(
+            data.get().keys.get(&tinystr!(2, "tz")).unwrap(),
+            "Time Zone"
+        );
+        assert_eq!(
+            data.get().keys.get(&tinystr!(2, "kn")).unwrap(),
+            "Numeric Sorting"
+        );
+        assert_eq!(
+            data.get().types.get("ca-gregory").unwrap(),
+            "Gregorian Calendar"
+        );
+        assert_eq!(
+            data.get().types.get("co-phonebk").unwrap(),
+            "Phonebook Sort Order"
+        );
+        assert_eq!(data.get().types.get("ca-gregorian"), None);
+    }
+}
@@ -0,0 +1,171 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::Alt;
+use crate::transform::cldr::cldr_serde;
+use core::convert::TryFrom;
+use icu_displaynames::provider::*;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use tinystr::TinyAsciiStr;
+use tinystr::TinyStrError;
+use zerovec::ZeroMap;
+
+impl DataProvider<LanguageDisplayNamesV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<LanguageDisplayNamesV1Marker>, DataError> {
+        let langid = req.locale.get_langid();
+
+        let data: &cldr_serde::displaynames::Resource = self
+            .source
+            .cldr()?
+            .displaynames()
+            .read_and_parse(&langid, "languages.json")?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(
+                LanguageDisplayNamesV1::try_from(data).map_err(|e| {
+                    DataError::custom("data for LanguageDisplayNames").with_display_context(&e)
+                })?,
+            )),
+        })
+    }
+}
+
+impl IterableDataProvider<LanguageDisplayNamesV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        self.supported_displaynames_locales()
+    }
+}
+
+impl DataProvider<LocaleDisplayNamesV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<LocaleDisplayNamesV1Marker>, DataError> {
+        let langid = req.locale.get_langid();
+
+        let data: &cldr_serde::displaynames::Resource = self
+            .source
+            .cldr()?
+            .displaynames()
+            .read_and_parse(&langid, "languages.json")?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(LocaleDisplayNamesV1::from(data))),
+        })
+    }
+}
+
+impl IterableDataProvider<LocaleDisplayNamesV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        self.supported_displaynames_locales()
+    }
+}
+
+/// Separator between the subtags of a language identifier. Entries containing it,
+/// such as "en-GB", are dialect names of full language identifiers.
+const SUBTAG_SEPARATOR: char = '-';
+
+impl TryFrom<&cldr_serde::displaynames::Resource> for LanguageDisplayNamesV1<'static> {
+    type Error = TinyStrError;
+    fn try_from(other: &cldr_serde::displaynames::Resource) -> Result<Self, Self::Error> {
+        let mut names = ZeroMap::new();
+        let mut short_names = ZeroMap::new();
+        let mut long_names = ZeroMap::new();
+        for lang_data_entry in other.main.0.iter() {
+            for (key, name) in lang_data_entry.1.localedisplaynames.languages.iter() {
+                let (map, language) = match Alt::from(key.as_str()) {
+                    Alt::None(language) => (&mut names, language),
+                    Alt::Short(language) => (&mut short_names, language),
+                    Alt::Long(language) => (&mut long_names, language),
+                    Alt::Other => continue,
+                };
+                if !language.contains(SUBTAG_SEPARATOR) {
+                    map.insert(&<TinyAsciiStr<3>>::from_str(language)?, name.as_ref());
+                }
+            }
+        }
+        Ok(Self {
+            names,
+            short_names,
+            long_names,
+        })
+    }
+}
+
+impl From<&cldr_serde::displaynames::Resource> for LocaleDisplayNamesV1<'static> {
+    fn from(other: &cldr_serde::displaynames::Resource) -> Self {
+        let mut names = ZeroMap::new();
+        let mut short_names = ZeroMap::new();
+        let mut long_names = ZeroMap::new();
+        for lang_data_entry in other.main.0.iter() {
+            for (key, name) in lang_data_entry.1.localedisplaynames.languages.iter() {
+                let (map, locale) = match Alt::from(key.as_str()) {
+                    Alt::None(locale) => (&mut names, locale),
+                    Alt::Short(locale) => (&mut short_names, locale),
+                    Alt::Long(locale) => (&mut long_names, locale),
+                    Alt::Other => continue,
+                };
+                if locale.contains(SUBTAG_SEPARATOR) {
+                    map.insert(locale, name.as_ref());
+                }
+            }
+        }
+        Self {
+            names,
+            short_names,
+            long_names,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locid::locale;
+    use tinystr::tinystr;
+
+    #[test]
+    fn test_basic() {
+        let provider = crate::DatagenProvider::for_test();
+
+        let data: DataPayload<LanguageDisplayNamesV1Marker> = provider
+            .load(DataRequest {
+                locale: &locale!("en-001").into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+
+        assert_eq!(data.get().names.get(&tinystr!(3, "de")).unwrap(), "German");
+        assert_eq!(
+            data.get().short_names.get(&tinystr!(3, "az")).unwrap(),
+            "Azeri"
+        );
+    }
+
+    #[test]
+    fn test_locales() {
+        let provider = crate::DatagenProvider::for_test();
+
+        let data: DataPayload<LocaleDisplayNamesV1Marker> = provider
+            .load(DataRequest {
+                locale: &locale!("en-001").into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+
+        assert_eq!(data.get().names.get("en-GB").unwrap(), "British English");
+        assert_eq!(data.get().short_names.get("en-GB").unwrap(), "UK English");
+        assert_eq!(data.get().names.get("de"), None);
+    }
+}
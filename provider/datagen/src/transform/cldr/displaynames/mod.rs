@@ -13,6 +13,15 @@ use tinystr::TinyAsciiStr;
 use tinystr::TinyStrError;
 use zerovec::ZeroMap;
 
+mod calendar;
+mod currency;
+mod keys;
+mod language;
+mod patterns;
+mod script;
+mod unit;
+mod variant;
+
 impl DataProvider<TerritoryDisplayNamesV1Marker> for crate::DatagenProvider {
     fn load(
         &self,
@@ -39,6 +48,12 @@ impl DataProvider<TerritoryDisplayNamesV1Marker> for crate::DatagenProvider {
 
 impl IterableDataProvider<TerritoryDisplayNamesV1Marker> for crate::DatagenProvider {
     fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        self.supported_displaynames_locales()
+    }
+}
+
+impl crate::DatagenProvider {
+    fn supported_displaynames_locales(&self) -> Result<Vec<DataLocale>, DataError> {
         Ok(self
             .source
             .cldr()?
@@ -51,6 +66,32 @@ impl IterableDataProvider<TerritoryDisplayNamesV1Marker> for crate::DatagenProvi
 
 /// Substring used to denote alternative region names data variants for a given territory. For example: "BA-alt-short", "TL-alt-variant".
 const ALT_SUBSTRING: &str = "-alt-";
+/// Suffix used to denote short alternative names, such as "en-GB-alt-short".
+const ALT_SHORT_SUFFIX: &str = "-alt-short";
+/// Suffix used to denote long alternative names, such as "zh-Hans-alt-long".
+const ALT_LONG_SUFFIX: &str = "-alt-long";
+
+/// The variant of a display name entry, determined by its `-alt-` suffix.
+enum Alt<'a> {
+    None(&'a str),
+    Short(&'a str),
+    Long(&'a str),
+    Other,
+}
+
+impl<'a> From<&'a str> for Alt<'a> {
+    fn from(key: &'a str) -> Self {
+        if let Some(key) = key.strip_suffix(ALT_SHORT_SUFFIX) {
+            Alt::Short(key)
+        } else if let Some(key) = key.strip_suffix(ALT_LONG_SUFFIX) {
+            Alt::Long(key)
+        } else if key.contains(ALT_SUBSTRING) {
+            Alt::Other
+        } else {
+            Alt::None(key)
+        }
+    }
+}
 
 impl TryFrom<&cldr_serde::displaynames::Resource> for TerritoryDisplayNamesV1<'static> {
     type Error = TinyStrError;
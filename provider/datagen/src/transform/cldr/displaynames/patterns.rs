@@ -0,0 +1,71 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::transform::cldr::cldr_serde;
+use icu_displaynames::provider::*;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+
+impl DataProvider<LocaleDisplayPatternsV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<LocaleDisplayPatternsV1Marker>, DataError> {
+        let langid = req.locale.get_langid();
+
+        let data: &cldr_serde::displaynames::Resource = self
+            .source
+            .cldr()?
+            .displaynames()
+            .read_and_parse(&langid, "localeDisplayNames.json")?;
+
+        let pattern = data
+            .main
+            .0
+            .values()
+            .find_map(|names| names.localedisplaynames.locale_display_pattern.as_ref())
+            .ok_or_else(|| {
+                DataError::custom("Missing localeDisplayPattern").with_debug_context(&langid)
+            })?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(LocaleDisplayPatternsV1 {
+                pattern: pattern.locale_pattern.clone().into(),
+                separator: pattern.locale_separator.clone().into(),
+                key_type_pattern: pattern.locale_key_type_pattern.clone().into(),
+            })),
+        })
+    }
+}
+
+impl IterableDataProvider<LocaleDisplayPatternsV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        self.supported_displaynames_locales()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locid::locale;
+
+    #[test]
+    fn test_basic() {
+        let provider = crate::DatagenProvider::for_test();
+
+        let data: DataPayload<LocaleDisplayPatternsV1Marker> = provider
+            .load(DataRequest {
+                locale: &locale!("en-001").into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+
+        assert_eq!(data.get().pattern, "{0} ({1})");
+        assert_eq!(data.get().separator, "{0}, {1}");
+        assert_eq!(data.get().key_type_pattern, "{0}: {1}");
+    }
+}
@@ -0,0 +1,88 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::Alt;
+use crate::transform::cldr::cldr_serde;
+use core::convert::TryFrom;
+use icu_displaynames::provider::*;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use tinystr::TinyAsciiStr;
+use tinystr::TinyStrError;
+use zerovec::ZeroMap;
+
+impl DataProvider<ScriptDisplayNamesV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<ScriptDisplayNamesV1Marker>, DataError> {
+        let langid = req.locale.get_langid();
+
+        let data: &cldr_serde::displaynames::Resource = self
+            .source
+            .cldr()?
+            .displaynames()
+            .read_and_parse(&langid, "scripts.json")?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(
+                ScriptDisplayNamesV1::try_from(data).map_err(|e| {
+                    DataError::custom("data for ScriptDisplayNames").with_display_context(&e)
+                })?,
+            )),
+        })
+    }
+}
+
+impl IterableDataProvider<ScriptDisplayNamesV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        self.supported_displaynames_locales()
+    }
+}
+
+impl TryFrom<&cldr_serde::displaynames::Resource> for ScriptDisplayNamesV1<'static> {
+    type Error = TinyStrError;
+    fn try_from(other: &cldr_serde::displaynames::Resource) -> Result<Self, Self::Error> {
+        let mut names = ZeroMap::new();
+        let mut short_names = ZeroMap::new();
+        for lang_data_entry in other.main.0.iter() {
+            for (key, name) in lang_data_entry.1.localedisplaynames.scripts.iter() {
+                let (map, script) = match Alt::from(key.as_str()) {
+                    Alt::None(script) => (&mut names, script),
+                    Alt::Short(script) => (&mut short_names, script),
+                    Alt::Long(_) | Alt::Other => continue,
+                };
+                map.insert(&<TinyAsciiStr<4>>::from_str(script)?, name.as_ref());
+            }
+        }
+        Ok(Self { names, short_names })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locid::locale;
+    use tinystr::tinystr;
+
+    #[test]
+    fn test_basic() {
+        let provider = crate::DatagenProvider::for_test();
+
+        let data: DataPayload<ScriptDisplayNamesV1Marker> = provider
+            .load(DataRequest {
+                locale: &locale!("en-001").into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+
+        assert_eq!(
+            data.get().names.get(&tinystr!(4, "Cyrl")).unwrap(),
+            "Cyrillic"
+        );
+    }
+}
@@ -0,0 +1,96 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::transform::cldr::cldr_serde;
+use icu_displaynames::provider::*;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use std::collections::HashMap;
+use zerovec::ZeroMap;
+
+impl DataProvider<UnitDisplayNamesV1Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<UnitDisplayNamesV1Marker>, DataError> {
+        let langid = req.locale.get_langid();
+
+        let data: &cldr_serde::units::Resource = self
+            .source
+            .cldr()?
+            .units()
+            .read_and_parse(&langid, "units.json")?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(UnitDisplayNamesV1::from(data))),
+        })
+    }
+}
+
+impl IterableDataProvider<UnitDisplayNamesV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(self
+            .source
+            .cldr()?
+            .units()
+            .list_langs()?
+            .map(DataLocale::from)
+            .collect())
+    }
+}
+
+/// Collects the display names of the units, keyed by CLDR core unit identifier.
+fn display_names(entries: &HashMap<String, cldr_serde::units::Entry>) -> HashMap<&str, &str> {
+    entries
+        .iter()
+        .filter_map(|(key, entry)| {
+            // Unit keys are prefixed by their category, such as "length-" in "length-kilometer".
+            let (_category, unit) = key.split_once('-')?;
+            // Prefixes and compound patterns have no display name.
+            Some((unit, entry.display_name.as_deref()?))
+        })
+        .collect()
+}
+
+impl From<&cldr_serde::units::Resource> for UnitDisplayNamesV1<'static> {
+    fn from(other: &cldr_serde::units::Resource) -> Self {
+        let mut names = ZeroMap::new();
+        let mut short_names = ZeroMap::new();
+        for lang_data_entry in other.main.0.iter() {
+            let units = &lang_data_entry.1.units;
+            let long = display_names(&units.long);
+            for (unit, name) in display_names(&units.short) {
+                if long.get(unit) != Some(&name) {
+                    short_names.insert(unit, name);
+                }
+            }
+            for (unit, name) in long {
+                names.insert(unit, name);
+            }
+        }
+        Self { names, short_names }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locid::locale;
+
+    #[test]
+    fn test_basic() {
+        let provider = crate::DatagenProvider::for_test();
+
+        let data: DataPayload<UnitDisplayNamesV1Marker> = provider
+            .load(DataRequest {
+                locale: &locale!("en").into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+
+        assert_eq!(data.get().names.get("kilometer").unwrap(), "kilometers");
+        assert_eq!(data.get().short_names.get("kilometer").unwrap(), "km");
+        assert_eq!(data.get().names.get("1024p1"), None);
+    }
+}
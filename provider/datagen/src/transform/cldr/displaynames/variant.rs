@@ -0,0 +1,89 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::Alt;
+use crate::transform::cldr::cldr_serde;
+use core::convert::TryFrom;
+use icu_displaynames::provider::*;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use tinystr::TinyAsciiStr;
+use tinystr::TinyStrError;
+use zerovec::ZeroMap;
+
+impl DataProvider<VariantDisplayNamesV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<VariantDisplayNamesV1Marker>, DataError> {
+        let langid = req.locale.get_langid();
+
+        let data: &cldr_serde::displaynames::Resource = self
+            .source
+            .cldr()?
+            .displaynames()
+            .read_and_parse(&langid, "variants.json")?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(
+                VariantDisplayNamesV1::try_from(data).map_err(|e| {
+                    DataError::custom("data for VariantDisplayNames").with_display_context(&e)
+                })?,
+            )),
+        })
+    }
+}
+
+impl IterableDataProvider<VariantDisplayNamesV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        self.supported_displaynames_locales()
+    }
+}
+
+impl TryFrom<&cldr_serde::displaynames::Resource> for VariantDisplayNamesV1<'static> {
+    type Error = TinyStrError;
+    fn try_from(other: &cldr_serde::displaynames::Resource) -> Result<Self, Self::Error> {
+        let mut names = ZeroMap::new();
+        for lang_data_entry in other.main.0.iter() {
+            for (key, name) in lang_data_entry.1.localedisplaynames.variants.iter() {
+                if let Alt::None(variant) = Alt::from(key.as_str()) {
+                    // CLDR lists variants in uppercase, but they are stored lowercase
+                    // in a language identifier.
+                    names.insert(
+                        &<TinyAsciiStr<8>>::from_str(variant)?.to_ascii_lowercase(),
+                        name.as_ref(),
+                    );
+                }
+            }
+        }
+        Ok(Self { names })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locid::locale;
+    use tinystr::tinystr;
+
+    #[test]
+    fn test_basic() {
+        let provider = crate::DatagenProvider::for_test();
+
+        let data: DataPayload<VariantDisplayNamesV1Marker> = provider
+            .load(DataRequest {
+                locale: &locale!("en-001").into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+
+        assert_eq!(
+            data.get().names.get(&tinystr!(8, "fonipa")).unwrap(),
+            "IPA Phonetics"
+        );
+    }
+}
@@ -0,0 +1,164 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::transform::cldr::cldr_serde;
+use icu_locid::subtags::Region;
+use icu_locid::LanguageIdentifier;
+use icu_locid_transform::provider::*;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use std::collections::{BTreeSet, HashMap};
+use zerovec::{VarZeroVec, ZeroMap, ZeroSlice};
+
+impl DataProvider<LanguageMatchingV1Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<LanguageMatchingV1Marker>, DataError> {
+        // We treat searching for und as a request for all data. Other requests
+        // are not currently supported.
+        if !req.locale.is_empty() {
+            return Err(DataErrorKind::ExtraneousLocale.into_error());
+        }
+
+        let language_matching: &cldr_serde::language_matching::Resource = self
+            .source
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/languageMatching.json")?;
+
+        let containment: &cldr_serde::territory_containment::Resource = self
+            .source
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/territoryContainment.json")?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(transform(
+                language_matching,
+                containment,
+            )?)),
+        })
+    }
+}
+
+impl IterableDataProvider<LanguageMatchingV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![Default::default()])
+    }
+}
+
+fn transform(
+    language_matching: &cldr_serde::language_matching::Resource,
+    containment: &cldr_serde::territory_containment::Resource,
+) -> Result<LanguageMatchingV1<'static>, DataError> {
+    // Groupings such as 419 (Latin America) are listed separately from the
+    // regular containment, but are also matched by the region variables.
+    let mut contains = HashMap::<&str, Vec<&str>>::new();
+    for (region, containment) in containment.supplemental.territory_containment.iter() {
+        if region.ends_with("-status-deprecated") {
+            continue;
+        }
+        contains
+            .entry(region.trim_end_matches("-status-grouping"))
+            .or_default()
+            .extend(containment.contains.iter().map(String::as_str));
+    }
+
+    let mut paradigm_locales = Vec::new();
+    let mut match_variables = ZeroMap::new();
+    let mut language = Vec::new();
+    let mut script = Vec::new();
+    let mut region = Vec::new();
+
+    for entry in language_matching
+        .supplemental
+        .language_matching
+        .written_new
+        .iter()
+    {
+        for (key, entry) in entry.iter() {
+            if let Some(locales) = &entry.locales {
+                for locale in locales.split_whitespace() {
+                    let langid: LanguageIdentifier = locale.parse().map_err(|_| {
+                        DataError::custom("Invalid paradigm locale").with_debug_context(locale)
+                    })?;
+                    paradigm_locales.push(langid.to_string());
+                }
+            } else if let (Some(variable), Some(value)) = (key.strip_prefix('$'), &entry.value) {
+                let mut regions = BTreeSet::new();
+                let mut queue = value.split('+').collect::<Vec<_>>();
+                while let Some(r) = queue.pop() {
+                    let parsed = r.parse::<Region>().map_err(|_| {
+                        DataError::custom("Invalid match variable region").with_debug_context(r)
+                    })?;
+                    if regions.insert(parsed) {
+                        queue.extend(contains.get(r).into_iter().flatten());
+                    }
+                }
+                match_variables.insert(
+                    variable,
+                    ZeroSlice::from_boxed_slice(regions.into_iter().collect::<Box<[_]>>()).as_ref(),
+                );
+            } else if let (Some(desired), Some(distance)) = (&entry.desired, &entry.distance) {
+                let rule = LanguageMatchRule {
+                    distance: distance.parse().map_err(|_| {
+                        DataError::custom("Invalid language matching distance")
+                            .with_debug_context(distance)
+                    })?,
+                    oneway: entry.oneway.as_deref() == Some("true"),
+                    desired: desired.clone().into(),
+                    supported: key.clone().into(),
+                };
+                match desired.split('_').count() {
+                    1 => language.push(rule),
+                    2 => script.push(rule),
+                    _ => region.push(rule),
+                }
+            }
+        }
+    }
+
+    Ok(LanguageMatchingV1 {
+        paradigm_locales: VarZeroVec::from(&paradigm_locales),
+        match_variables,
+        language: VarZeroVec::from(&language),
+        script: VarZeroVec::from(&script),
+        region: VarZeroVec::from(&region),
+    })
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid::subtags_region as region;
+    use icu_provider::zerofrom::ZeroFrom;
+
+    let provider = crate::DatagenProvider::for_test();
+    let result: DataPayload<LanguageMatchingV1Marker> = provider
+        .load(Default::default())
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    let data = result.get();
+
+    assert!(data.paradigm_locales.iter().any(|l| l == "es-419"));
+
+    let americas = data.match_variables.get("americas").unwrap();
+    for r in [region!("019"), region!("419"), region!("MX"), region!("BR")] {
+        assert!(americas.binary_search(&r).is_ok(), "{:?}", r);
+    }
+    assert!(americas.binary_search(&region!("ES")).is_err());
+    assert_eq!(data.match_variables.get("cnsar").unwrap().len(), 2);
+
+    let nb = LanguageMatchRule::zero_from(data.language.get(0).unwrap());
+    assert_eq!((&*nb.desired, &*nb.supported, nb.distance), ("nb", "no", 1));
+    assert!(data
+        .script
+        .iter()
+        .map(LanguageMatchRule::zero_from)
+        .any(|r| r.supported == "*_*"));
+    assert!(data
+        .region
+        .iter()
+        .map(LanguageMatchRule::zero_from)
+        .any(|r| r.supported == "en_*_GB" && r.desired == "en_*_$!enUS"));
+}
@@ -3,4 +3,5 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 pub mod aliases;
+pub mod language_matching;
 pub mod likely_subtags;
@@ -6,6 +6,7 @@
 
 pub mod calendar;
 pub mod cldr_serde;
+pub mod currency;
 pub mod datetime;
 pub mod decimal;
 pub mod displaynames;
@@ -13,5 +14,8 @@ pub mod fallback;
 pub mod list;
 pub mod locale_canonicalizer;
 pub mod plurals;
+pub mod rbnf;
+pub mod relativetime;
 pub mod source;
 pub mod time_zones;
+pub mod units;
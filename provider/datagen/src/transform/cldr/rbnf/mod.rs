@@ -0,0 +1,183 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#![cfg(feature = "experimental")]
+
+use crate::transform::cldr::cldr_serde;
+use crate::transform::cldr::decimal::rbnf::{decimal_separator, file_name, transform};
+use icu_locid::LanguageIdentifier;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use icu_rbnf::provider::*;
+use std::str::FromStr;
+
+fn load<M: KeyedDataMarker<Yokeable = RbnfRulesV1<'static>>>(
+    selff: &crate::DatagenProvider,
+    req: DataRequest,
+    group: &str,
+) -> Result<DataResponse<M>, DataError> {
+    let langid = req.locale.get_langid();
+
+    let resource: &cldr_serde::rbnf::Resource = selff
+        .source
+        .cldr()?
+        .rbnf()
+        .read_and_parse(&file_name(&langid))?;
+
+    let rule_sets = resource
+        .rbnf
+        .rbnf
+        .get(group)
+        .ok_or_else(|| DataErrorKind::MissingLocale.with_req(M::KEY, req))?;
+
+    let names = rule_sets
+        .keys()
+        .filter(|name| !name.ends_with("lenient-parse"))
+        .collect();
+
+    Ok(DataResponse {
+        metadata: Default::default(),
+        payload: Some(DataPayload::from_owned(transform(
+            rule_sets,
+            names,
+            decimal_separator(selff, &langid),
+        )?)),
+    })
+}
+
+fn supported_locales(
+    selff: &crate::DatagenProvider,
+    group: &str,
+) -> Result<Vec<DataLocale>, DataError> {
+    let rbnf = selff.source.cldr()?.rbnf();
+    let mut locales = Vec::new();
+    for file_name in rbnf.list()? {
+        let resource: &cldr_serde::rbnf::Resource = rbnf.read_and_parse(&file_name)?;
+        if resource.rbnf.rbnf.contains_key(group) {
+            let langid = match file_name.trim_end_matches(".json") {
+                "root" => LanguageIdentifier::UND,
+                name => LanguageIdentifier::from_str(name).map_err(|e| {
+                    DataError::custom("Invalid rbnf file name")
+                        .with_display_context(&e)
+                        .with_display_context(&file_name)
+                })?,
+            };
+            locales.push(DataLocale::from(langid));
+        }
+    }
+    Ok(locales)
+}
+
+macro_rules! implement {
+    ($marker:ident, $group:literal) => {
+        impl DataProvider<$marker> for crate::DatagenProvider {
+            fn load(&self, req: DataRequest) -> Result<DataResponse<$marker>, DataError> {
+                load(self, req, $group)
+            }
+        }
+
+        impl IterableDataProvider<$marker> for crate::DatagenProvider {
+            fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+                supported_locales(self, $group)
+            }
+        }
+    };
+}
+
+implement!(SpelloutRulesV1Marker, "SpelloutRules");
+implement!(OrdinalRulesV1Marker, "OrdinalRules");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locid::locale;
+    use icu_provider::zerofrom::ZeroFrom;
+
+    #[test]
+    fn test_basic() {
+        let provider = crate::DatagenProvider::for_test();
+
+        let data: DataPayload<SpelloutRulesV1Marker> = provider
+            .load(DataRequest {
+                locale: &locale!("en").into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+
+        let rules = |name: &str| {
+            let info = data.get().rule_sets.get_copied(name).unwrap();
+            (info.start..info.end)
+                .map(|i| Rule::zero_from(data.get().rules.get(i as usize).unwrap()))
+                .collect::<Vec<_>>()
+        };
+
+        assert!(data.get().rule_sets.get_copied("lenient-parse").is_none());
+        assert!(data.get().rule_sets.get_copied("and").unwrap().private);
+
+        let cardinal = rules("spellout-cardinal");
+        assert_eq!(
+            cardinal[0],
+            Rule {
+                kind: RuleKind::Negative,
+                base_value: 0,
+                divisor: 1,
+                text: "minus →→".into(),
+            }
+        );
+        // "100: ←← hundred[ →→];" is expanded into two rules.
+        let hundreds = cardinal
+            .iter()
+            .filter(|rule| rule.divisor == 100)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            hundreds,
+            [
+                &Rule {
+                    kind: RuleKind::Normal,
+                    base_value: 100,
+                    divisor: 100,
+                    text: "←← hundred".into(),
+                },
+                &Rule {
+                    kind: RuleKind::Normal,
+                    base_value: 101,
+                    divisor: 100,
+                    text: "←← hundred →→".into(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fraction_rule_sets() {
+        let provider = crate::DatagenProvider::for_test();
+
+        let data: DataPayload<SpelloutRulesV1Marker> = provider
+            .load(DataRequest {
+                locale: &locale!("ru").into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+
+        assert!(
+            data.get()
+                .rule_sets
+                .get_copied("fractions-feminine")
+                .unwrap()
+                .fraction
+        );
+        assert!(
+            !data
+                .get()
+                .rule_sets
+                .get_copied("spellout-cardinal-feminine")
+                .unwrap()
+                .fraction
+        );
+    }
+}
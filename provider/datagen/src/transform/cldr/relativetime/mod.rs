@@ -0,0 +1,226 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#![cfg(feature = "experimental")]
+
+use crate::transform::cldr::cldr_serde;
+use core::convert::TryFrom;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use icu_relativetime::provider::*;
+use std::str::FromStr;
+use zerovec::ZeroMap;
+
+fn load<M: KeyedDataMarker<Yokeable = RelativeTimeFormatDataV1<'static>>>(
+    selff: &crate::DatagenProvider,
+    req: DataRequest,
+    field: &str,
+) -> Result<DataResponse<M>, DataError> {
+    let langid = req.locale.get_langid();
+
+    let resource: &cldr_serde::date_fields::Resource = selff
+        .source
+        .cldr()?
+        .dates("gregorian")
+        .read_and_parse(&langid, "dateFields.json")?;
+
+    let fields = &resource
+        .main
+        .0
+        .get(&langid)
+        .expect("CLDR file contains the expected language")
+        .dates
+        .fields;
+
+    let get = |suffix: &str| {
+        let name = format!("{}{}", field, suffix);
+        fields
+            .get(&name)
+            .ok_or_else(|| DataErrorKind::MissingLocale.with_req(M::KEY, req))
+            .and_then(|field| {
+                RelativeTimePatternDataV1::try_from(field)
+                    .map_err(|e| e.with_display_context(&name))
+            })
+    };
+
+    Ok(DataResponse {
+        metadata: Default::default(),
+        payload: Some(DataPayload::from_owned(RelativeTimeFormatDataV1 {
+            long: get("")?,
+            short: get("-short")?,
+            narrow: get("-narrow")?,
+        })),
+    })
+}
+
+fn supported_locales(
+    selff: &crate::DatagenProvider,
+    field: &str,
+) -> Result<Vec<DataLocale>, DataError> {
+    let dates = selff.source.cldr()?.dates("gregorian");
+    let mut locales = Vec::new();
+    for langid in dates.list_langs()? {
+        let resource: &cldr_serde::date_fields::Resource =
+            dates.read_and_parse(&langid, "dateFields.json")?;
+        if resource
+            .main
+            .0
+            .get(&langid)
+            .expect("CLDR file contains the expected language")
+            .dates
+            .fields
+            .contains_key(field)
+        {
+            locales.push(DataLocale::from(langid));
+        }
+    }
+    Ok(locales)
+}
+
+impl TryFrom<&cldr_serde::date_fields::Field> for RelativeTimePatternDataV1<'static> {
+    type Error = DataError;
+    fn try_from(field: &cldr_serde::date_fields::Field) -> Result<Self, Self::Error> {
+        let mut relatives = ZeroMap::new();
+        for (offset, name) in field.relatives() {
+            let offset = i8::from_str(offset).map_err(|_| {
+                DataError::custom("Invalid relative offset").with_display_context(offset)
+            })?;
+            relatives.insert(&offset, name);
+        }
+        Ok(Self {
+            relatives,
+            past: PluralPatterns::try_from(
+                field
+                    .past
+                    .as_ref()
+                    .ok_or_else(|| DataError::custom("Missing past patterns"))?,
+            )?,
+            future: PluralPatterns::try_from(
+                field
+                    .future
+                    .as_ref()
+                    .ok_or_else(|| DataError::custom("Missing future patterns"))?,
+            )?,
+        })
+    }
+}
+
+impl TryFrom<&cldr_serde::date_fields::PluralRulesPattern> for PluralPatterns<'static> {
+    type Error = DataError;
+    fn try_from(
+        patterns: &cldr_serde::date_fields::PluralRulesPattern,
+    ) -> Result<Self, Self::Error> {
+        // Categories that use the same pattern as `other` are not stored.
+        let optional = |pattern: &Option<String>| {
+            pattern
+                .as_deref()
+                .filter(|&p| p != patterns.other)
+                .map(SingularSubPattern::try_from_str)
+                .transpose()
+        };
+        Ok(Self {
+            zero: optional(&patterns.zero)?,
+            one: optional(&patterns.one)?,
+            two: optional(&patterns.two)?,
+            few: optional(&patterns.few)?,
+            many: optional(&patterns.many)?,
+            other: SingularSubPattern::try_from_str(&patterns.other)?,
+        })
+    }
+}
+
+macro_rules! implement {
+    ($marker:ident, $field:literal) => {
+        impl DataProvider<$marker> for crate::DatagenProvider {
+            fn load(&self, req: DataRequest) -> Result<DataResponse<$marker>, DataError> {
+                load(self, req, $field)
+            }
+        }
+
+        impl IterableDataProvider<$marker> for crate::DatagenProvider {
+            fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+                supported_locales(self, $field)
+            }
+        }
+    };
+}
+
+implement!(SecondRelativeTimeFormatDataV1Marker, "second");
+implement!(MinuteRelativeTimeFormatDataV1Marker, "minute");
+implement!(HourRelativeTimeFormatDataV1Marker, "hour");
+implement!(DayRelativeTimeFormatDataV1Marker, "day");
+implement!(WeekRelativeTimeFormatDataV1Marker, "week");
+implement!(MonthRelativeTimeFormatDataV1Marker, "month");
+implement!(QuarterRelativeTimeFormatDataV1Marker, "quarter");
+implement!(YearRelativeTimeFormatDataV1Marker, "year");
+implement!(SundayRelativeTimeFormatDataV1Marker, "sun");
+implement!(MondayRelativeTimeFormatDataV1Marker, "mon");
+implement!(TuesdayRelativeTimeFormatDataV1Marker, "tue");
+implement!(WednesdayRelativeTimeFormatDataV1Marker, "wed");
+implement!(ThursdayRelativeTimeFormatDataV1Marker, "thu");
+implement!(FridayRelativeTimeFormatDataV1Marker, "fri");
+implement!(SaturdayRelativeTimeFormatDataV1Marker, "sat");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locid::locale;
+
+    #[test]
+    fn test_basic() {
+        let provider = crate::DatagenProvider::for_test();
+
+        let data: DataPayload<DayRelativeTimeFormatDataV1Marker> = provider
+            .load(DataRequest {
+                locale: &locale!("en").into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+
+        let long = &data.get().long;
+        assert_eq!(long.relatives.get(&-1).unwrap(), "yesterday");
+        assert_eq!(long.relatives.get(&1).unwrap(), "tomorrow");
+        assert_eq!(
+            long.future.one,
+            Some(SingularSubPattern {
+                pattern: "in  day".into(),
+                index: 3,
+            })
+        );
+        assert_eq!(
+            long.past.other,
+            SingularSubPattern {
+                pattern: " days ago".into(),
+                index: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_plural_categories() {
+        let provider = crate::DatagenProvider::for_test();
+
+        let data: DataPayload<HourRelativeTimeFormatDataV1Marker> = provider
+            .load(DataRequest {
+                locale: &locale!("ar").into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+
+        let long = &data.get().long;
+        // Arabic uses the same pattern for `zero` and `other`, so `zero` is not stored.
+        assert_eq!(long.past.zero, None);
+        assert_eq!(
+            long.past.two,
+            Some(SingularSubPattern {
+                pattern: "قبل ساعتين".into(),
+                index: SingularSubPattern::NO_PLACEHOLDER,
+            })
+        );
+    }
+}
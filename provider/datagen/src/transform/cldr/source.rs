@@ -66,6 +66,17 @@ impl CldrCache {
         )
     }
 
+    pub fn units(&self) -> CldrDirLang<'_> {
+        CldrDirLang(
+            &self.cache,
+            format!("cldr-units-{}/main", self.locale_subset),
+        )
+    }
+
+    pub fn rbnf(&self) -> CldrDirNoLang<'_> {
+        CldrDirNoLang(&self.cache, "cldr-rbnf/rbnf".to_string())
+    }
+
     pub fn bcp47(&self) -> CldrDirNoLang<'_> {
         CldrDirNoLang(&self.cache, "cldr-bcp47/bcp47".to_string())
     }
@@ -99,6 +110,12 @@ impl<'a> CldrDirNoLang<'a> {
         self.0
             .read_and_parse_json(&format!("{}/{}", self.1, file_name))
     }
+
+    pub fn list(&self) -> Result<impl Iterator<Item = String>, DataError> {
+        Ok(self.0.list(&self.1)?.into_iter().map(|path| {
+            path.file_name().unwrap().to_string_lossy().into_owned()
+        }))
+    }
 }
 
 pub(crate) struct CldrDirLang<'a>(&'a SerdeCache, String);
@@ -0,0 +1,261 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::transform::cldr::cldr_serde;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use icu_units::provider::*;
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{One, Signed, ToPrimitive, Zero};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use zerovec::{VarZeroVec, ZeroMap, ZeroVec};
+
+impl DataProvider<UnitsInfoV1Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<UnitsInfoV1Marker>, DataError> {
+        // The unit data is not locale-specific.
+        if !req.locale.is_empty() {
+            return Err(DataErrorKind::ExtraneousLocale.into_error());
+        }
+
+        let resource: &cldr_serde::units_data::Resource = self
+            .source
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/units.json")?;
+        let supplemental = &resource.supplemental;
+
+        let mut conversions = ZeroMap::new();
+        for (unit, conversion) in &supplemental.convert_units {
+            let factor = match &conversion.factor {
+                Some(factor) => evaluate(factor, &supplemental.unit_constants, 0)?,
+                None => BigRational::one(),
+            };
+            let offset = match &conversion.offset {
+                Some(offset) => evaluate(offset, &supplemental.unit_constants, 0)?,
+                None => BigRational::zero(),
+            };
+            let (factor_numerator, factor_denominator) = encode(&factor, unit)?;
+            let (offset_numerator, offset_denominator) = if offset.is_zero() {
+                (ZeroVec::new(), ZeroVec::new())
+            } else {
+                encode(&offset, unit)?
+            };
+            conversions.insert_var_v(
+                unit.as_str(),
+                &ConversionInfo {
+                    base_unit: Cow::Borrowed(conversion.base_unit.as_str()),
+                    factor_numerator,
+                    factor_denominator,
+                    offset_numerator,
+                    offset_denominator,
+                },
+            );
+        }
+
+        let quantities = supplemental
+            .unit_quantities
+            .iter()
+            .map(|(base_unit, quantity)| (base_unit.as_str(), quantity.quantity.as_str()))
+            .collect();
+
+        let mut preferences = Vec::new();
+        for (quantity, usages) in &supplemental.unit_preference_data {
+            for (usage, regions) in usages {
+                for (region, rows) in regions {
+                    for row in rows {
+                        let (geq_numerator, geq_denominator) = match &row.geq {
+                            Some(geq) => {
+                                let geq = evaluate(geq, &BTreeMap::new(), 0)?;
+                                match (geq.numer().to_u32(), geq.denom().to_u32()) {
+                                    (Some(numerator), Some(denominator)) => {
+                                        (numerator, denominator)
+                                    }
+                                    _ => {
+                                        return Err(DataError::custom(
+                                            "Unit preference threshold out of range",
+                                        )
+                                        .with_debug_context(row))
+                                    }
+                                }
+                            }
+                            None => (1, 1),
+                        };
+                        preferences.push(UnitPreference {
+                            geq_numerator,
+                            geq_denominator,
+                            quantity: Cow::Borrowed(quantity.as_str()),
+                            usage: Cow::Borrowed(usage.as_str()),
+                            region: Cow::Borrowed(region.as_str()),
+                            unit: Cow::Borrowed(row.unit.as_str()),
+                            skeleton: Cow::Borrowed(row.skeleton.as_deref().unwrap_or_default()),
+                        });
+                    }
+                }
+            }
+        }
+
+        let data = UnitsInfoV1 {
+            conversions,
+            quantities,
+            preferences: VarZeroVec::from(&preferences),
+        };
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(data)),
+        })
+    }
+}
+
+impl IterableDataProvider<UnitsInfoV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![Default::default()])
+    }
+}
+
+/// Evaluates a CLDR conversion expression exactly, such as "ft3_to_m3/12*12*12".
+///
+/// All terms after the "/" are in the denominator. Terms are decimal numbers, which may have
+/// an exponent such as "6.67408E-11", or the names of `constants`.
+fn evaluate(
+    expression: &str,
+    constants: &BTreeMap<String, cldr_serde::units_data::Constant>,
+    depth: u8,
+) -> Result<BigRational, DataError> {
+    // Constants are defined in terms of each other, but not recursively.
+    if depth > 8 {
+        return Err(DataError::custom("Recursive unit constant").with_display_context(expression));
+    }
+    let (numerator, denominator) = expression.split_once('/').unwrap_or((expression, ""));
+    let mut result = BigRational::one();
+    for (terms, inverse) in [(numerator, false), (denominator, true)] {
+        for term in terms
+            .split('*')
+            .map(str::trim)
+            .filter(|term| !term.is_empty())
+        {
+            let value = match constants.get(term) {
+                Some(constant) => evaluate(&constant.value, constants, depth + 1)?,
+                None => parse_decimal(term).ok_or_else(|| {
+                    DataError::custom("Could not parse unit conversion term")
+                        .with_display_context(term)
+                })?,
+            };
+            if inverse {
+                if value.is_zero() {
+                    return Err(DataError::custom("Division by zero in unit conversion")
+                        .with_display_context(expression));
+                }
+                result /= value;
+            } else {
+                result *= value;
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// Parses a decimal number such as "-0.3048" or "6.02214076E+23".
+fn parse_decimal(s: &str) -> Option<BigRational> {
+    let (mantissa, exponent) = match s.split_once(['E', 'e'].as_ref()) {
+        Some((mantissa, exponent)) => (mantissa, exponent.parse::<i32>().ok()?),
+        None => (s, 0),
+    };
+    let (negative, mantissa) = match mantissa.strip_prefix('-') {
+        Some(mantissa) => (true, mantissa),
+        None => (false, mantissa),
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if integer.is_empty() && fraction.is_empty() {
+        return None;
+    }
+    let digits: BigInt = format!("{}{}", integer, fraction).parse().ok()?;
+    let exponent = exponent - i32::try_from(fraction.len()).ok()?;
+    let ten = BigRational::from_integer(BigInt::from(10));
+    let mut value = BigRational::from_integer(digits) * ten.pow(exponent);
+    if negative {
+        value = -value;
+    }
+    Some(value)
+}
+
+/// Encodes a non-negative rational number as an unsigned big-endian numerator and denominator.
+#[allow(clippy::type_complexity)]
+fn encode(
+    value: &BigRational,
+    unit: &str,
+) -> Result<(ZeroVec<'static, u8>, ZeroVec<'static, u8>), DataError> {
+    if value.is_negative() {
+        return Err(DataError::custom("Negative unit conversion factor").with_display_context(unit));
+    }
+    let encode = |n: &BigInt| ZeroVec::alloc_from_slice(&n.to_bytes_be().1);
+    Ok((encode(value.numer()), encode(value.denom())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_provider::zerofrom::ZeroFrom;
+
+    #[test]
+    fn test_evaluate() {
+        let provider = crate::DatagenProvider::for_test();
+        let resource: &cldr_serde::units_data::Resource = provider
+            .source
+            .cldr()
+            .unwrap()
+            .core()
+            .read_and_parse("supplemental/units.json")
+            .unwrap();
+        let constants = &resource.supplemental.unit_constants;
+
+        let ratio = |n: i64, d: i64| BigRational::new(n.into(), d.into());
+        assert_eq!(evaluate("0.3048", constants, 0).unwrap(), ratio(381, 1250));
+        assert_eq!(evaluate("5/9", constants, 0).unwrap(), ratio(5, 9));
+        assert_eq!(
+            evaluate("ft_to_m/12", constants, 0).unwrap(),
+            ratio(127, 5000)
+        );
+        assert_eq!(
+            evaluate("6.02214076E+23", constants, 0).unwrap(),
+            ratio(602214076, 1) * ratio(10, 1).pow(15)
+        );
+        // Every term after the "/" is in the denominator.
+        assert_eq!(
+            evaluate("in3_to_m3", constants, 0).unwrap(),
+            evaluate("ft_to_m*ft_to_m*ft_to_m/1728", constants, 0).unwrap()
+        );
+        assert!(evaluate("furlong", constants, 0).is_err());
+    }
+
+    #[test]
+    fn test_basic() {
+        let provider = crate::DatagenProvider::for_test();
+
+        let data: DataPayload<UnitsInfoV1Marker> = provider
+            .load(Default::default())
+            .unwrap()
+            .take_payload()
+            .unwrap();
+        let data = data.get();
+
+        assert_eq!(data.quantities.get("meter"), Some("length"));
+        let foot: ConversionInfo = ZeroFrom::zero_from(data.conversions.get("foot").unwrap());
+        assert_eq!(
+            foot,
+            ConversionInfo {
+                base_unit: Cow::Borrowed("meter"),
+                factor_numerator: ZeroVec::alloc_from_slice(&[1, 125]),
+                factor_denominator: ZeroVec::alloc_from_slice(&[4, 226]),
+                offset_numerator: ZeroVec::new(),
+                offset_denominator: ZeroVec::new(),
+            }
+        );
+        assert!(data.preferences.iter().any(|preference| {
+            let preference: UnitPreference = ZeroFrom::zero_from(preference);
+            preference.usage == "road" && preference.region == "US" && preference.unit == "mile"
+        }));
+    }
+}
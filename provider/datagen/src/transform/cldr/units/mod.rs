@@ -0,0 +1,218 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#![cfg(feature = "experimental")]
+
+use crate::transform::cldr::cldr_serde;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use icu_units::provider::*;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use zerovec::ZeroMap;
+
+mod info;
+
+fn load<M: KeyedDataMarker<Yokeable = UnitsFormatDataV1<'static>>>(
+    selff: &crate::DatagenProvider,
+    req: DataRequest,
+    width: fn(&cldr_serde::units::Units) -> &HashMap<String, cldr_serde::units::Entry>,
+) -> Result<DataResponse<M>, DataError> {
+    let langid = req.locale.get_langid();
+
+    let resource: &cldr_serde::units::Resource = selff
+        .source
+        .cldr()?
+        .units()
+        .read_and_parse(&langid, "units.json")?;
+
+    let entries = width(
+        &resource
+            .main
+            .0
+            .get(&langid)
+            .expect("CLDR file contains the expected language")
+            .units,
+    );
+
+    let mut patterns = UnitPluralPatterns {
+        zero: ZeroMap::new(),
+        one: ZeroMap::new(),
+        two: ZeroMap::new(),
+        few: ZeroMap::new(),
+        many: ZeroMap::new(),
+        other: ZeroMap::new(),
+    };
+    let mut per_unit_patterns = ZeroMap::new();
+    let mut decimal_prefixes = ZeroMap::new();
+    let mut binary_prefixes = ZeroMap::new();
+
+    for (key, entry) in entries {
+        if let Some(prefix) = &entry.unit_prefix_pattern {
+            if let Some(power) = key.strip_prefix("1024p") {
+                binary_prefixes.insert(&parse_power(power, key)?, prefix.as_str());
+            } else if let Some(power) = key.strip_prefix("10p") {
+                decimal_prefixes.insert(&parse_power(power, key)?, prefix.as_str());
+            }
+            continue;
+        }
+        // Unit keys are prefixed by their category, such as "length-" in "length-kilometer".
+        let unit = match key.split_once('-') {
+            Some((_category, unit)) => unit,
+            None => continue,
+        };
+        let other = match &entry.unit_pattern_other {
+            Some(other) => other,
+            None => continue,
+        };
+        for (map, pattern) in [
+            (&mut patterns.zero, &entry.unit_pattern_zero),
+            (&mut patterns.one, &entry.unit_pattern_one),
+            (&mut patterns.two, &entry.unit_pattern_two),
+            (&mut patterns.few, &entry.unit_pattern_few),
+            (&mut patterns.many, &entry.unit_pattern_many),
+        ] {
+            if let Some(pattern) = pattern.as_deref().filter(|p| p != other) {
+                map.insert(unit, pattern);
+            }
+        }
+        patterns.other.insert(unit, other.as_str());
+        if let Some(per_unit_pattern) = &entry.per_unit_pattern {
+            per_unit_patterns.insert(unit, per_unit_pattern.as_str());
+        }
+    }
+
+    let compound = |key: &str| {
+        entries
+            .get(key)
+            .and_then(|entry| entry.compound_unit_pattern.clone())
+            .ok_or_else(|| {
+                DataError::custom("Could not find compound unit pattern").with_display_context(key)
+            })
+    };
+
+    Ok(DataResponse {
+        metadata: Default::default(),
+        payload: Some(DataPayload::from_owned(UnitsFormatDataV1 {
+            patterns,
+            per_unit_patterns,
+            per: Cow::Owned(compound("per")?),
+            times: Cow::Owned(compound("times")?),
+            power2: plural_pattern(entries, "power2")?,
+            power3: plural_pattern(entries, "power3")?,
+            decimal_prefixes,
+            binary_prefixes,
+        })),
+    })
+}
+
+fn parse_power<T: std::str::FromStr>(power: &str, key: &str) -> Result<T, DataError> {
+    power.parse().map_err(|_| {
+        DataError::custom("Could not parse unit prefix power").with_display_context(key)
+    })
+}
+
+fn plural_pattern(
+    entries: &HashMap<String, cldr_serde::units::Entry>,
+    key: &str,
+) -> Result<PluralPattern<'static>, DataError> {
+    let entry = entries.get(key).ok_or_else(|| {
+        DataError::custom("Could not find compound unit pattern").with_display_context(key)
+    })?;
+    let other = entry
+        .compound_unit_pattern1_other
+        .as_ref()
+        .or(entry.compound_unit_pattern1.as_ref())
+        .ok_or_else(|| {
+            DataError::custom("Could not find compound unit pattern").with_display_context(key)
+        })?;
+    let sparse = |pattern: &Option<String>| {
+        pattern
+            .as_ref()
+            .filter(|p| *p != other)
+            .map(|p| Cow::Owned(p.clone()))
+    };
+    Ok(PluralPattern {
+        zero: sparse(&entry.compound_unit_pattern1_zero),
+        one: sparse(&entry.compound_unit_pattern1_one),
+        two: sparse(&entry.compound_unit_pattern1_two),
+        few: sparse(&entry.compound_unit_pattern1_few),
+        many: sparse(&entry.compound_unit_pattern1_many),
+        other: Cow::Owned(other.clone()),
+    })
+}
+
+macro_rules! implement {
+    ($marker:ident, $width:ident) => {
+        impl DataProvider<$marker> for crate::DatagenProvider {
+            fn load(&self, req: DataRequest) -> Result<DataResponse<$marker>, DataError> {
+                load(self, req, |units| &units.$width)
+            }
+        }
+
+        impl IterableDataProvider<$marker> for crate::DatagenProvider {
+            fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+                Ok(self
+                    .source
+                    .cldr()?
+                    .units()
+                    .list_langs()?
+                    .map(DataLocale::from)
+                    .collect())
+            }
+        }
+    };
+}
+
+implement!(LongUnitsFormatDataV1Marker, long);
+implement!(ShortUnitsFormatDataV1Marker, short);
+implement!(NarrowUnitsFormatDataV1Marker, narrow);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locid::locale;
+
+    #[test]
+    fn test_basic() {
+        let provider = crate::DatagenProvider::for_test();
+
+        let data: DataPayload<LongUnitsFormatDataV1Marker> = provider
+            .load(DataRequest {
+                locale: &locale!("en").into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+
+        let data = data.get();
+        assert_eq!(data.patterns.one.get("kilometer"), Some("{0} kilometer"));
+        assert_eq!(data.patterns.other.get("kilometer"), Some("{0} kilometers"));
+        assert_eq!(data.per_unit_patterns.get("hour"), Some("{0} per hour"));
+        assert_eq!(data.per, "{0} per {1}");
+        assert_eq!(data.power2.other, "square {0}");
+        assert_eq!(data.decimal_prefixes.get(&3), Some("kilo{0}"));
+        assert_eq!(data.binary_prefixes.get(&1), Some("kibi{0}"));
+    }
+
+    #[test]
+    fn test_plural_categories() {
+        let provider = crate::DatagenProvider::for_test();
+
+        let data: DataPayload<LongUnitsFormatDataV1Marker> = provider
+            .load(DataRequest {
+                locale: &locale!("ru").into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+
+        let data = data.get();
+        assert_eq!(data.patterns.few.get("hour"), None);
+        assert_eq!(data.patterns.many.get("hour"), Some("{0} часов"));
+        assert_eq!(data.power2.one.as_deref(), Some("квадратный {0}"));
+    }
+}
@@ -201,14 +201,6 @@ collation_provider!(
             secondaries: ZeroVec::alloc_from_slice(&toml_data.secondaries),
         }
     ),
-    (
-        CollationJamoV1Marker,
-        CollationJamo,
-        "_jamo",
-        icu_collator::provider::CollationJamoV1 {
-            ce32s: ZeroVec::alloc_from_slice(&toml_data.ce32s),
-        }
-    ),
     (
         CollationMetadataV1Marker,
         CollationMetadata,
@@ -238,3 +230,103 @@ collation_provider!(
     ),
     toml_data
 );
+
+/// The `CollationElement32` of characters that a tailoring leaves to the root collation.
+const FALLBACK_CE32: u32 = 0xC0;
+
+impl crate::DatagenProvider {
+    /// Reads a collation TOML file of the given locale and suffix, reporting a missing file
+    /// as a missing locale.
+    fn read_collation_toml<M: KeyedDataMarker, S>(
+        &self,
+        req: DataRequest,
+        suffix: &str,
+    ) -> Result<&S, DataError>
+    where
+        for<'de> S: serde::Deserialize<'de> + 'static + Send + Sync,
+    {
+        let icuexport = self.source.icuexport()?;
+        let collation_han_database = self.source.collation_han_database();
+        icuexport
+            .read_and_parse_toml(&format!(
+                "collation/{}/{}{}.toml",
+                collation_han_database,
+                locale_to_file_name(icuexport, collation_han_database, req.locale),
+                suffix
+            ))
+            .map_err(|e| match e.kind {
+                DataErrorKind::Io(std::io::ErrorKind::NotFound) => {
+                    DataErrorKind::MissingLocale.with_req(M::KEY, req)
+                }
+                _ => e,
+            })
+    }
+
+    /// The jamo table of a tailoring, or `None` if it doesn't tailor any jamo.
+    ///
+    /// ICU only exports the jamo table of the root collation, but tailorings such as the
+    /// search collations map jamo differently. The entries for the jamo that a tailoring
+    /// maps are read from its trie, and refer to its data rather than the root.
+    fn tailored_jamo(&self, req: DataRequest) -> Result<Option<Vec<u32>>, DataError> {
+        let root: &collator_serde::CollationJamo =
+            self.read_collation_toml::<CollationJamoV1Marker, _>(Default::default(), "_jamo")?;
+        let data: &collator_serde::CollationData =
+            self.read_collation_toml::<CollationJamoV1Marker, _>(req, "_data")?;
+        let trie = CodePointTrie::<u32>::try_from(&data.trie)
+            .map_err(|e| DataError::custom("trie conversion").with_display_context(&e))?;
+        if (0x1100..0x1200).all(|c| trie.get32(c) == FALLBACK_CE32) {
+            return Ok(None);
+        }
+        Ok(Some(
+            root.ce32s
+                .iter()
+                .zip(0x1100..)
+                .map(|(&root, c)| match trie.get32(c) {
+                    FALLBACK_CE32 => root,
+                    tailored => tailored,
+                })
+                .collect(),
+        ))
+    }
+}
+
+impl DataProvider<CollationJamoV1Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<CollationJamoV1Marker>, DataError> {
+        let ce32s = if req.locale.is_empty() {
+            let root: &collator_serde::CollationJamo =
+                self.read_collation_toml::<CollationJamoV1Marker, _>(req, "_jamo")?;
+            root.ce32s.clone()
+        } else {
+            self.tailored_jamo(req)?.ok_or_else(|| {
+                DataErrorKind::MissingLocale.with_req(CollationJamoV1Marker::KEY, req)
+            })?
+        };
+        Ok(DataResponse {
+            metadata: DataResponseMetadata::default(),
+            payload: Some(DataPayload::from_owned(
+                icu_collator::provider::CollationJamoV1 {
+                    ce32s: ZeroVec::alloc_from_slice(&ce32s),
+                },
+            )),
+        })
+    }
+}
+
+impl IterableDataProvider<CollationJamoV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        let mut locales = vec![DataLocale::default()];
+        for locale in IterableDataProvider::<CollationDataV1Marker>::supported_locales(self)? {
+            if locale.is_empty() {
+                continue;
+            }
+            let req = DataRequest {
+                locale: &locale,
+                metadata: Default::default(),
+            };
+            if self.tailored_jamo(req)?.is_some() {
+                locales.push(locale);
+            }
+        }
+        Ok(locales)
+    }
+}
@@ -0,0 +1,332 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains provider implementations for the NFKC_Casefold and
+//! NFKC_Simple_Casefold normalizations. ICU doesn't export these, so they are
+//! derived from the NFKC, case folding, and Default_Ignorable_Code_Point data.
+
+#![cfg(feature = "experimental")]
+
+use icu_casemapping::CaseMapping;
+use icu_codepointtrie_builder::{CodePointTrieBuilder, CodePointTrieBuilderData};
+use icu_normalizer::properties::CanonicalCombiningClassMap;
+use icu_normalizer::provider::*;
+use icu_normalizer::{ComposingNormalizer, DecomposingNormalizer};
+use icu_properties::{sets, CanonicalCombiningClass};
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use zerovec::ZeroVec;
+
+/// Trie value for characters that normalize to the empty string.
+const IGNORABLE_MARKER: u32 = 0xFFFFFFFF;
+
+/// Trie value for U+FDFA, whose decomposition is hard-coded in the normalizer.
+const FDFA_MARKER: u32 = 3;
+
+/// Trie value bit for complex decompositions whose trailing characters are all non-starters.
+const ALL_NON_STARTERS: u32 = 0x1000;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Folding {
+    Full,
+    Simple,
+}
+
+/// The decomposed NFKC_Casefold or NFKC_Simple_Casefold mappings of the characters for
+/// which they differ from the canonical decomposition.
+struct Mappings {
+    mappings: Vec<(char, Vec<char>)>,
+    ccc: CanonicalCombiningClassMap,
+}
+
+impl Mappings {
+    fn is_starter(&self, c: char) -> bool {
+        self.ccc.get(c) == CanonicalCombiningClass::NotReordered
+    }
+
+    /// Whether the mapping doesn't fit in the trie value and has to go in the tables.
+    fn is_complex(&self, c: char, mapping: &[char]) -> bool {
+        if c == '\u{FDFA}' {
+            return false;
+        }
+        match mapping {
+            [] => false,
+            [single] => u32::from(*single) > 0xFFFF,
+            [first, second] => {
+                u32::from(*first) > 0xFFFF
+                    || u32::from(*second) > 0xFFFF
+                    || self.is_starter(*second)
+            }
+            _ => true,
+        }
+    }
+}
+
+impl crate::DatagenProvider {
+    fn nfkc_casefold_mappings(&self, folding: Folding) -> Result<Mappings, DataError> {
+        let normalizer_error = |e: icu_normalizer::NormalizerError| {
+            DataError::custom("normalizer").with_display_context(&e)
+        };
+        let properties_error = |e: icu_properties::PropertiesError| {
+            DataError::custom("properties").with_display_context(&e)
+        };
+        let nfd = DecomposingNormalizer::try_new_nfd_unstable(self).map_err(normalizer_error)?;
+        let nfkd = DecomposingNormalizer::try_new_nfkd_unstable(self).map_err(normalizer_error)?;
+        let nfkc = ComposingNormalizer::try_new_nfkc_unstable(self).map_err(normalizer_error)?;
+        let ccc = CanonicalCombiningClassMap::try_new_unstable(self).map_err(normalizer_error)?;
+        let case_mapping = CaseMapping::try_new(self)?;
+        let default_ignorable =
+            sets::load_default_ignorable_code_point(self).map_err(properties_error)?;
+        let default_ignorable = default_ignorable.as_borrowed();
+        // Characters outside this set map to their NFC form. Since simple case folding only
+        // changes characters that full case folding changes, this also covers NFKC_Simple_Casefold.
+        let changes = sets::load_changes_when_nfkc_casefolded(self).map_err(properties_error)?;
+        let changes = changes.as_borrowed();
+
+        let mut mappings = Vec::new();
+        for c in (0..=0x10FFFF).filter_map(char::from_u32) {
+            if !changes.contains(c) {
+                continue;
+            }
+            // The mapping is the closure of NFKC, case folding, removing default ignorables,
+            // and NFKC again, as in Unicode's DerivedNormalizationProps.txt.
+            let mut mapping = String::from(c);
+            loop {
+                let nfkc_form = nfkc.normalize(&mapping);
+                let folded: String = match folding {
+                    Folding::Full => case_mapping.full_fold(&nfkc_form),
+                    Folding::Simple => nfkc_form.chars().map(|c| case_mapping.fold(c)).collect(),
+                };
+                let next = nfkc.normalize(
+                    &folded
+                        .chars()
+                        .filter(|&c| !default_ignorable.contains(c))
+                        .collect::<String>(),
+                );
+                if next == mapping {
+                    break;
+                }
+                mapping = next;
+            }
+            let decomposition = nfd.normalize(&mapping);
+            if decomposition == nfd.normalize(&String::from(c)) {
+                continue;
+            }
+            if c == '\u{FDFA}' && decomposition != nfkd.normalize("\u{FDFA}") {
+                return Err(DataError::custom("U+FDFA doesn't map to its NFKD form"));
+            }
+            mappings.push((c, decomposition.chars().collect()));
+        }
+        Ok(Mappings { mappings, ccc })
+    }
+
+    /// Lays out the mappings of both flavors that don't fit in the trie values.
+    fn nfkc_casefold_tables(
+        &self,
+        full: &Mappings,
+        simple: &Mappings,
+    ) -> Result<DecompositionTablesV1<'static>, DataError> {
+        let mut scalars16 = Vec::<u16>::new();
+        let mut scalars24 = Vec::<char>::new();
+        let nfd_tables: DataPayload<CanonicalDecompositionTablesV1Marker> =
+            self.load(Default::default())?.take_payload()?;
+        let nfd_scalars16 = nfd_tables.get().scalars16.to_vec();
+        let nfd_scalars24 = nfd_tables.get().scalars24.to_vec();
+        for mappings in [full, simple] {
+            for (c, mapping) in &mappings.mappings {
+                if !mappings.is_complex(*c, mapping) {
+                    continue;
+                }
+                if mapping.iter().all(|&c| u32::from(c) <= 0xFFFF) {
+                    let mapping = mapping.iter().map(|&c| c as u16).collect::<Vec<_>>();
+                    if find(&nfd_scalars16, &mapping).is_none()
+                        && find(&scalars16, &mapping).is_none()
+                    {
+                        scalars16.extend(mapping);
+                    }
+                } else if find(&nfd_scalars24, mapping).is_none()
+                    && find(&scalars24, mapping).is_none()
+                {
+                    scalars24.extend(mapping);
+                }
+            }
+        }
+        Ok(DecompositionTablesV1 {
+            scalars16: ZeroVec::alloc_from_slice(&scalars16),
+            scalars24: ZeroVec::alloc_from_slice(&scalars24),
+        })
+    }
+
+    fn nfkc_casefold_supplement(
+        &self,
+        folding: Folding,
+    ) -> Result<DecompositionSupplementV1<'static>, DataError> {
+        let full = self.nfkc_casefold_mappings(Folding::Full)?;
+        let simple = self.nfkc_casefold_mappings(Folding::Simple)?;
+        let tables = self.nfkc_casefold_tables(&full, &simple)?;
+        let nfd_tables: DataPayload<CanonicalDecompositionTablesV1Marker> =
+            self.load(Default::default())?.take_payload()?;
+        // Offsets are into the concatenation of the NFD tables and the NFKC_Casefold tables.
+        let scalars = [
+            nfd_tables.get().scalars16.iter().map(u32::from).collect(),
+            nfd_tables.get().scalars24.iter().map(u32::from).collect(),
+            tables.scalars16.iter().map(u32::from).collect(),
+            tables.scalars24.iter().map(u32::from).collect::<Vec<_>>(),
+        ];
+        let mappings = if folding == Folding::Full {
+            full
+        } else {
+            simple
+        };
+
+        let mut values = vec![0u32; 0x110000];
+        let mut flags = 0;
+        for (c, mapping) in &mappings.mappings {
+            let value = match mapping.as_slice() {
+                [] => IGNORABLE_MARKER,
+                _ if *c == '\u{FDFA}' => FDFA_MARKER,
+                ['\u{3099}' | '\u{309A}'] if matches!(c, '\u{FF9E}' | '\u{FF9F}') => {
+                    // The normalizer handles the half-width voicing marks without
+                    // looking them up.
+                    flags |= 1;
+                    continue;
+                }
+                [first, ..] if !mappings.is_starter(*first) => {
+                    return Err(DataError::custom("Decomposition starts with a non-starter")
+                        .with_display_context(&c.escape_unicode()));
+                }
+                _ if mappings.is_complex(*c, mapping) => {
+                    let mapping = mapping.iter().map(|&c| u32::from(c)).collect::<Vec<_>>();
+                    let (wide, len) = if mapping.iter().all(|&u| u <= 0xFFFF) {
+                        (false, mapping.len() - 2)
+                    } else {
+                        (true, mapping.len() - 1)
+                    };
+                    let offset = scalars
+                        .iter()
+                        .enumerate()
+                        .filter(|(i, _)| (*i % 2 == 1) == wide)
+                        .find_map(|(i, table)| {
+                            find(table, &mapping).map(|offset| {
+                                offset + scalars[..i].iter().map(Vec::len).sum::<usize>()
+                            })
+                        })
+                        .ok_or_else(|| DataError::custom("Decomposition missing from tables"))?;
+                    if len > 7 || offset > 0xFFF {
+                        return Err(DataError::custom("Decomposition doesn't fit in trie value")
+                            .with_display_context(&c.escape_unicode()));
+                    }
+                    let all_non_starters = if mapping[1..]
+                        .iter()
+                        .filter_map(|&u| char::from_u32(u))
+                        .all(|c| !mappings.is_starter(c))
+                    {
+                        ALL_NON_STARTERS
+                    } else {
+                        0
+                    };
+                    (((len as u32) << 13) | all_non_starters | offset as u32) << 16
+                }
+                [single] => u32::from(*single),
+                // Mappings of more than two characters are complex.
+                [starter, non_starter, ..] => u32::from(*starter) | (u32::from(*non_starter) << 16),
+            };
+            if *c == '\u{0345}' {
+                // Reaching this point means that the mapping starts with a starter.
+                flags |= 2;
+            }
+            values[u32::from(*c) as usize] = value;
+        }
+        let cap = values
+            .iter()
+            .position(|&v| v != 0)
+            .unwrap_or(0x0300)
+            .min(0x0300) as u16;
+
+        let trie = CodePointTrieBuilder {
+            data: CodePointTrieBuilderData::ValuesByCodePoint(&values),
+            default_value: 0,
+            error_value: 0,
+            trie_type: self.source.trie_type().to_internal(),
+        }
+        .build();
+
+        Ok(DecompositionSupplementV1 {
+            trie,
+            flags,
+            passthrough_cap: cap,
+        })
+    }
+}
+
+/// Finds the offset of `needle` in `haystack`.
+fn find<T: PartialEq>(haystack: &[T], needle: &[T]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+impl DataProvider<NfkcCasefoldDecompositionSupplementV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        _req: DataRequest,
+    ) -> Result<DataResponse<NfkcCasefoldDecompositionSupplementV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: DataResponseMetadata::default(),
+            payload: Some(DataPayload::from_owned(
+                self.nfkc_casefold_supplement(Folding::Full)?,
+            )),
+        })
+    }
+}
+
+impl DataProvider<NfkcSimpleCasefoldDecompositionSupplementV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        _req: DataRequest,
+    ) -> Result<DataResponse<NfkcSimpleCasefoldDecompositionSupplementV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: DataResponseMetadata::default(),
+            payload: Some(DataPayload::from_owned(
+                self.nfkc_casefold_supplement(Folding::Simple)?,
+            )),
+        })
+    }
+}
+
+impl DataProvider<NfkcCasefoldDecompositionTablesV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        _req: DataRequest,
+    ) -> Result<DataResponse<NfkcCasefoldDecompositionTablesV1Marker>, DataError> {
+        let full = self.nfkc_casefold_mappings(Folding::Full)?;
+        let simple = self.nfkc_casefold_mappings(Folding::Simple)?;
+        Ok(DataResponse {
+            metadata: DataResponseMetadata::default(),
+            payload: Some(DataPayload::from_owned(
+                self.nfkc_casefold_tables(&full, &simple)?,
+            )),
+        })
+    }
+}
+
+impl IterableDataProvider<NfkcCasefoldDecompositionSupplementV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![DataLocale::default()])
+    }
+}
+
+impl IterableDataProvider<NfkcSimpleCasefoldDecompositionSupplementV1Marker>
+    for crate::DatagenProvider
+{
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![DataLocale::default()])
+    }
+}
+
+impl IterableDataProvider<NfkcCasefoldDecompositionTablesV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![DataLocale::default()])
+    }
+}
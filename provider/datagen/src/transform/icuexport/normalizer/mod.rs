@@ -13,6 +13,7 @@ use icu_provider::prelude::*;
 use std::convert::TryFrom;
 use zerovec::ZeroVec;
 
+mod casefold;
 mod normalizer_serde;
 
 macro_rules! normalization_provider {
@@ -2,6 +2,9 @@
 impl AnyProvider for BakedDataProvider {
     fn load_any(&self, key: DataKey, req: DataRequest) -> Result<AnyResponse, DataError> {
         #[cfg(feature = "icu_calendar")]
+        const ISLAMICUMMALQURACACHEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_calendar::provider::IslamicUmmAlQuraCacheV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_calendar")]
         const JAPANESEERASV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_calendar::provider::JapaneseErasV1Marker::KEY.hashed();
         #[cfg(feature = "icu_calendar")]
@@ -31,6 +34,21 @@ impl AnyProvider for BakedDataProvider {
         #[cfg(feature = "icu_collator")]
         const COLLATIONSPECIALPRIMARIESV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_collator::provider::CollationSpecialPrimariesV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_compactdecimal")]
+        const LONGCOMPACTDECIMALFORMATDATAV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_compactdecimal::provider::LongCompactDecimalFormatDataV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_compactdecimal")]
+        const SHORTCOMPACTDECIMALFORMATDATAV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_compactdecimal::provider::ShortCompactDecimalFormatDataV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_currency")]
+        const CURRENCYESSENTIALSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_currency::provider::CurrencyEssentialsV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_currency")]
+        const CURRENCYFRACTIONSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_currency::provider::CurrencyFractionsV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_currency")]
+        const CURRENCYNAMESV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_currency::provider::CurrencyNamesV1Marker::KEY.hashed();
         #[cfg(feature = "icu_datetime")]
         const BUDDHISTDATELENGTHSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_datetime::provider::calendar::BuddhistDateLengthsV1Marker::KEY.hashed();
@@ -38,11 +56,23 @@ impl AnyProvider for BakedDataProvider {
         const BUDDHISTDATESYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_datetime::provider::calendar::BuddhistDateSymbolsV1Marker::KEY.hashed();
         #[cfg(feature = "icu_datetime")]
+        const CHINESEDATELENGTHSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::calendar::ChineseDateLengthsV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_datetime")]
+        const CHINESEDATESYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::calendar::ChineseDateSymbolsV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_datetime")]
         const COPTICDATELENGTHSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_datetime::provider::calendar::CopticDateLengthsV1Marker::KEY.hashed();
         #[cfg(feature = "icu_datetime")]
         const COPTICDATESYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_datetime::provider::calendar::CopticDateSymbolsV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_datetime")]
+        const DANGIDATELENGTHSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::calendar::DangiDateLengthsV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_datetime")]
+        const DANGIDATESYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::calendar::DangiDateSymbolsV1Marker::KEY.hashed();
         #[cfg(feature = "icu_datetime_experimental")]
         const DATESKELETONPATTERNSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_datetime::provider::calendar::DateSkeletonPatternsV1Marker::KEY.hashed();
@@ -59,12 +89,24 @@ impl AnyProvider for BakedDataProvider {
         const GREGORIANDATESYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_datetime::provider::calendar::GregorianDateSymbolsV1Marker::KEY.hashed();
         #[cfg(feature = "icu_datetime")]
+        const HEBREWDATELENGTHSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::calendar::HebrewDateLengthsV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_datetime")]
+        const HEBREWDATESYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::calendar::HebrewDateSymbolsV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_datetime")]
         const INDIANDATELENGTHSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_datetime::provider::calendar::IndianDateLengthsV1Marker::KEY.hashed();
         #[cfg(feature = "icu_datetime")]
         const INDIANDATESYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_datetime::provider::calendar::IndianDateSymbolsV1Marker::KEY.hashed();
         #[cfg(feature = "icu_datetime")]
+        const ISLAMICDATELENGTHSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::calendar::IslamicDateLengthsV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_datetime")]
+        const ISLAMICDATESYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::calendar::IslamicDateSymbolsV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_datetime")]
         const JAPANESEDATELENGTHSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_datetime::provider::calendar::JapaneseDateLengthsV1Marker::KEY.hashed();
         #[cfg(feature = "icu_datetime")]
@@ -77,6 +119,18 @@ impl AnyProvider for BakedDataProvider {
         const JAPANESEEXTENDEDDATESYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_datetime::provider::calendar::JapaneseExtendedDateSymbolsV1Marker::KEY.hashed();
         #[cfg(feature = "icu_datetime")]
+        const PERSIANDATELENGTHSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::calendar::PersianDateLengthsV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_datetime")]
+        const PERSIANDATESYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::calendar::PersianDateSymbolsV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_datetime")]
+        const ROCDATELENGTHSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::calendar::RocDateLengthsV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_datetime")]
+        const ROCDATESYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::calendar::RocDateSymbolsV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_datetime")]
         const TIMELENGTHSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_datetime::provider::calendar::TimeLengthsV1Marker::KEY.hashed();
         #[cfg(feature = "icu_datetime")]
@@ -101,11 +155,44 @@ impl AnyProvider for BakedDataProvider {
         const TIMEZONEFORMATSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_datetime::provider::time_zones::TimeZoneFormatsV1Marker::KEY.hashed();
         #[cfg(feature = "icu_decimal")]
+        const ALGORITHMICSYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_decimal::provider::AlgorithmicSymbolsV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_decimal")]
         const DECIMALSYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_decimal::provider::DecimalSymbolsV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_decimal")]
+        const NOTATIONSYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_decimal::provider::NotationSymbolsV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_displaynames")]
+        const CALENDARDISPLAYNAMESV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_displaynames::provider::CalendarDisplayNamesV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_displaynames")]
+        const CURRENCYDISPLAYNAMESV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_displaynames::provider::CurrencyDisplayNamesV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_displaynames")]
+        const KEYDISPLAYNAMESV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_displaynames::provider::KeyDisplayNamesV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_displaynames")]
+        const LANGUAGEDISPLAYNAMESV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_displaynames::provider::LanguageDisplayNamesV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_displaynames")]
+        const LOCALEDISPLAYNAMESV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_displaynames::provider::LocaleDisplayNamesV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_displaynames")]
+        const LOCALEDISPLAYPATTERNSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_displaynames::provider::LocaleDisplayPatternsV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_displaynames")]
+        const SCRIPTDISPLAYNAMESV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_displaynames::provider::ScriptDisplayNamesV1Marker::KEY.hashed();
         #[cfg(feature = "icu_displaynames")]
         const TERRITORYDISPLAYNAMESV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_displaynames::provider::TerritoryDisplayNamesV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_displaynames")]
+        const UNITDISPLAYNAMESV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_displaynames::provider::UnitDisplayNamesV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_displaynames")]
+        const VARIANTDISPLAYNAMESV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_displaynames::provider::VariantDisplayNamesV1Marker::KEY.hashed();
         #[cfg(feature = "icu_list")]
         const ANDLISTV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_list::provider::AndListV1Marker::KEY.hashed();
@@ -119,6 +206,9 @@ impl AnyProvider for BakedDataProvider {
         const ALIASESV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_locid_transform::provider::AliasesV1Marker::KEY.hashed();
         #[cfg(feature = "icu_locid_transform")]
+        const LANGUAGEMATCHINGV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_locid_transform::provider::LanguageMatchingV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_locid_transform")]
         const LIKELYSUBTAGSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_locid_transform::provider::LikelySubtagsV1Marker::KEY.hashed();
         #[cfg(feature = "icu_normalizer")]
@@ -136,6 +226,16 @@ impl AnyProvider for BakedDataProvider {
         #[cfg(feature = "icu_normalizer")]
         const COMPATIBILITYDECOMPOSITIONTABLESV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_normalizer::provider::CompatibilityDecompositionTablesV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_normalizer_experimental")]
+        const NFKCCASEFOLDDECOMPOSITIONSUPPLEMENTV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_normalizer::provider::NfkcCasefoldDecompositionSupplementV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_normalizer_experimental")]
+        const NFKCCASEFOLDDECOMPOSITIONTABLESV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_normalizer::provider::NfkcCasefoldDecompositionTablesV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_normalizer_experimental")]
+        const NFKCSIMPLECASEFOLDDECOMPOSITIONSUPPLEMENTV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_normalizer::provider::NfkcSimpleCasefoldDecompositionSupplementV1Marker::KEY
+                .hashed();
         #[cfg(feature = "icu_normalizer")]
         const NONRECURSIVEDECOMPOSITIONSUPPLEMENTV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_normalizer::provider::NonRecursiveDecompositionSupplementV1Marker::KEY.hashed();
@@ -336,6 +436,57 @@ impl AnyProvider for BakedDataProvider {
         const LOCALEFALLBACKPARENTSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_provider_adapters::fallback::provider::LocaleFallbackParentsV1Marker::KEY
                 .hashed();
+        #[cfg(feature = "icu_rbnf")]
+        const ORDINALRULESV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_rbnf::provider::OrdinalRulesV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_rbnf")]
+        const SPELLOUTRULESV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_rbnf::provider::SpelloutRulesV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_relativetime")]
+        const DAYRELATIVETIMEFORMATDATAV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_relativetime::provider::DayRelativeTimeFormatDataV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_relativetime")]
+        const FRIDAYRELATIVETIMEFORMATDATAV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_relativetime::provider::FridayRelativeTimeFormatDataV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_relativetime")]
+        const HOURRELATIVETIMEFORMATDATAV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_relativetime::provider::HourRelativeTimeFormatDataV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_relativetime")]
+        const MINUTERELATIVETIMEFORMATDATAV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_relativetime::provider::MinuteRelativeTimeFormatDataV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_relativetime")]
+        const MONDAYRELATIVETIMEFORMATDATAV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_relativetime::provider::MondayRelativeTimeFormatDataV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_relativetime")]
+        const MONTHRELATIVETIMEFORMATDATAV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_relativetime::provider::MonthRelativeTimeFormatDataV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_relativetime")]
+        const QUARTERRELATIVETIMEFORMATDATAV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_relativetime::provider::QuarterRelativeTimeFormatDataV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_relativetime")]
+        const SATURDAYRELATIVETIMEFORMATDATAV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_relativetime::provider::SaturdayRelativeTimeFormatDataV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_relativetime")]
+        const SECONDRELATIVETIMEFORMATDATAV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_relativetime::provider::SecondRelativeTimeFormatDataV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_relativetime")]
+        const SUNDAYRELATIVETIMEFORMATDATAV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_relativetime::provider::SundayRelativeTimeFormatDataV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_relativetime")]
+        const THURSDAYRELATIVETIMEFORMATDATAV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_relativetime::provider::ThursdayRelativeTimeFormatDataV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_relativetime")]
+        const TUESDAYRELATIVETIMEFORMATDATAV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_relativetime::provider::TuesdayRelativeTimeFormatDataV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_relativetime")]
+        const WEDNESDAYRELATIVETIMEFORMATDATAV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_relativetime::provider::WednesdayRelativeTimeFormatDataV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_relativetime")]
+        const WEEKRELATIVETIMEFORMATDATAV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_relativetime::provider::WeekRelativeTimeFormatDataV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_relativetime")]
+        const YEARRELATIVETIMEFORMATDATAV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_relativetime::provider::YearRelativeTimeFormatDataV1Marker::KEY.hashed();
         #[cfg(feature = "icu_segmenter")]
         const GRAPHEMECLUSTERBREAKDATAV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_segmenter::provider::GraphemeClusterBreakDataV1Marker::KEY.hashed();
@@ -357,9 +508,27 @@ impl AnyProvider for BakedDataProvider {
         #[cfg(feature = "icu_timezone")]
         const METAZONEPERIODV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_timezone::provider::MetazonePeriodV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_units")]
+        const LONGUNITSFORMATDATAV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_units::provider::LongUnitsFormatDataV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_units")]
+        const NARROWUNITSFORMATDATAV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_units::provider::NarrowUnitsFormatDataV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_units")]
+        const SHORTUNITSFORMATDATAV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_units::provider::ShortUnitsFormatDataV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_units")]
+        const UNITSINFOV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_units::provider::UnitsInfoV1Marker::KEY.hashed();
         #[allow(clippy::match_single_binding)]
         match key.hashed() {
             #[cfg(feature = "icu_calendar")]
+            ISLAMICUMMALQURACACHEV1MARKER => calendar::islamicumalquracache_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_calendar")]
             JAPANESEERASV1MARKER => calendar::japanese_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                 .copied()
@@ -419,6 +588,36 @@ impl AnyProvider for BakedDataProvider {
                 .copied()
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_compactdecimal")]
+            LONGCOMPACTDECIMALFORMATDATAV1MARKER => compactdecimal::long_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_compactdecimal")]
+            SHORTCOMPACTDECIMALFORMATDATAV1MARKER => compactdecimal::short_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_currency")]
+            CURRENCYESSENTIALSV1MARKER => currency::essentials_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_currency")]
+            CURRENCYFRACTIONSV1MARKER => currency::fractions_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_currency")]
+            CURRENCYNAMESV1MARKER => currency::names_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_datetime")]
             BUDDHISTDATELENGTHSV1MARKER => datetime::buddhist::datelengths_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
@@ -432,6 +631,18 @@ impl AnyProvider for BakedDataProvider {
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_datetime")]
+            CHINESEDATELENGTHSV1MARKER => datetime::chinese::datelengths_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_datetime")]
+            CHINESEDATESYMBOLSV1MARKER => datetime::chinese::datesymbols_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_datetime")]
             COPTICDATELENGTHSV1MARKER => datetime::coptic::datelengths_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                 .copied()
@@ -443,6 +654,18 @@ impl AnyProvider for BakedDataProvider {
                 .copied()
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_datetime")]
+            DANGIDATELENGTHSV1MARKER => datetime::dangi::datelengths_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_datetime")]
+            DANGIDATESYMBOLSV1MARKER => datetime::dangi::datesymbols_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_datetime_experimental")]
             DATESKELETONPATTERNSV1MARKER => {
                 datetime::skeletons_v1::DATA
@@ -482,6 +705,18 @@ impl AnyProvider for BakedDataProvider {
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_datetime")]
+            HEBREWDATELENGTHSV1MARKER => datetime::hebrew::datelengths_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_datetime")]
+            HEBREWDATESYMBOLSV1MARKER => datetime::hebrew::datesymbols_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_datetime")]
             INDIANDATELENGTHSV1MARKER => datetime::indian::datelengths_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                 .copied()
@@ -494,6 +729,18 @@ impl AnyProvider for BakedDataProvider {
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_datetime")]
+            ISLAMICDATELENGTHSV1MARKER => datetime::islamic::datelengths_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_datetime")]
+            ISLAMICDATESYMBOLSV1MARKER => datetime::islamic::datesymbols_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_datetime")]
             JAPANESEDATELENGTHSV1MARKER => datetime::japanese::datelengths_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                 .copied()
@@ -518,6 +765,30 @@ impl AnyProvider for BakedDataProvider {
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_datetime")]
+            PERSIANDATELENGTHSV1MARKER => datetime::persian::datelengths_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_datetime")]
+            PERSIANDATESYMBOLSV1MARKER => datetime::persian::datesymbols_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_datetime")]
+            ROCDATELENGTHSV1MARKER => datetime::roc::datelengths_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_datetime")]
+            ROCDATESYMBOLSV1MARKER => datetime::roc::datesymbols_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_datetime")]
             TIMELENGTHSV1MARKER => datetime::timelengths_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                 .copied()
@@ -566,17 +837,83 @@ impl AnyProvider for BakedDataProvider {
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_decimal")]
+            ALGORITHMICSYMBOLSV1MARKER => decimal::algorithmic_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_decimal")]
             DECIMALSYMBOLSV1MARKER => decimal::symbols_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                 .copied()
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_decimal")]
+            NOTATIONSYMBOLSV1MARKER => decimal::notation_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_displaynames")]
+            CALENDARDISPLAYNAMESV1MARKER => displaynames::calendars_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_displaynames")]
+            CURRENCYDISPLAYNAMESV1MARKER => displaynames::currencies_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_displaynames")]
+            KEYDISPLAYNAMESV1MARKER => displaynames::keys_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_displaynames")]
+            LANGUAGEDISPLAYNAMESV1MARKER => displaynames::languages_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_displaynames")]
+            LOCALEDISPLAYNAMESV1MARKER => displaynames::locales_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_displaynames")]
+            LOCALEDISPLAYPATTERNSV1MARKER => displaynames::patterns_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_displaynames")]
+            SCRIPTDISPLAYNAMESV1MARKER => displaynames::scripts_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_displaynames")]
             TERRITORYDISPLAYNAMESV1MARKER => displaynames::territories_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                 .copied()
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_displaynames")]
+            UNITDISPLAYNAMESV1MARKER => displaynames::units_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_displaynames")]
+            VARIANTDISPLAYNAMESV1MARKER => displaynames::variants_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_list")]
             ANDLISTV1MARKER => list::and_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
@@ -602,6 +939,12 @@ impl AnyProvider for BakedDataProvider {
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_locid_transform")]
+            LANGUAGEMATCHINGV1MARKER => locid_transform::languagematching_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_locid_transform")]
             LIKELYSUBTAGSV1MARKER => locid_transform::likelysubtags_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                 .copied()
@@ -637,6 +980,24 @@ impl AnyProvider for BakedDataProvider {
                 .copied()
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_normalizer_experimental")]
+            NFKCCASEFOLDDECOMPOSITIONSUPPLEMENTV1MARKER => normalizer::nfkccfd_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_normalizer_experimental")]
+            NFKCCASEFOLDDECOMPOSITIONTABLESV1MARKER => normalizer::nfkccfex_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_normalizer_experimental")]
+            NFKCSIMPLECASEFOLDDECOMPOSITIONSUPPLEMENTV1MARKER => normalizer::nfkcscfd_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_normalizer")]
             NONRECURSIVEDECOMPOSITIONSUPPLEMENTV1MARKER => normalizer::decomp_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
@@ -1035,6 +1396,108 @@ impl AnyProvider for BakedDataProvider {
                 .copied()
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_rbnf")]
+            ORDINALRULESV1MARKER => rbnf::ordinal_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_rbnf")]
+            SPELLOUTRULESV1MARKER => rbnf::spellout_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_relativetime")]
+            DAYRELATIVETIMEFORMATDATAV1MARKER => relativetime::day_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_relativetime")]
+            FRIDAYRELATIVETIMEFORMATDATAV1MARKER => relativetime::fri_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_relativetime")]
+            HOURRELATIVETIMEFORMATDATAV1MARKER => relativetime::hour_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_relativetime")]
+            MINUTERELATIVETIMEFORMATDATAV1MARKER => relativetime::minute_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_relativetime")]
+            MONDAYRELATIVETIMEFORMATDATAV1MARKER => relativetime::mon_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_relativetime")]
+            MONTHRELATIVETIMEFORMATDATAV1MARKER => relativetime::month_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_relativetime")]
+            QUARTERRELATIVETIMEFORMATDATAV1MARKER => relativetime::quarter_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_relativetime")]
+            SATURDAYRELATIVETIMEFORMATDATAV1MARKER => relativetime::sat_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_relativetime")]
+            SECONDRELATIVETIMEFORMATDATAV1MARKER => relativetime::second_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_relativetime")]
+            SUNDAYRELATIVETIMEFORMATDATAV1MARKER => relativetime::sun_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_relativetime")]
+            THURSDAYRELATIVETIMEFORMATDATAV1MARKER => relativetime::thu_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_relativetime")]
+            TUESDAYRELATIVETIMEFORMATDATAV1MARKER => relativetime::tue_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_relativetime")]
+            WEDNESDAYRELATIVETIMEFORMATDATAV1MARKER => relativetime::wed_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_relativetime")]
+            WEEKRELATIVETIMEFORMATDATAV1MARKER => relativetime::week_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_relativetime")]
+            YEARRELATIVETIMEFORMATDATAV1MARKER => relativetime::year_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_segmenter")]
             GRAPHEMECLUSTERBREAKDATAV1MARKER => segmenter::grapheme_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
@@ -1077,6 +1540,30 @@ impl AnyProvider for BakedDataProvider {
                 .copied()
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_units")]
+            LONGUNITSFORMATDATAV1MARKER => units::long_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_units")]
+            NARROWUNITSFORMATDATAV1MARKER => units::narrow_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_units")]
+            SHORTUNITSFORMATDATAV1MARKER => units::short_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_units")]
+            UNITSINFOV1MARKER => units::info_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
             _ => Err(DataErrorKind::MissingDataKey),
         }
         .map_err(|e| e.with_req(key, req))
@@ -0,0 +1,58 @@
+// @generated
+#![cfg(feature = "icu_calendar")]
+type DataStruct = < :: icu_calendar :: provider :: IslamicUmmAlQuraCacheV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_calendar::provider::IslamicUmmAlQuraCacheV1 {
+    first_extended_year: 1300i32,
+    year_info: unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+            165u8, 10u8, 45u8, 249u8, 93u8, 242u8, 189u8, 248u8, 186u8, 241u8, 181u8, 245u8, 170u8,
+            5u8, 85u8, 253u8, 154u8, 10u8, 46u8, 249u8, 110u8, 241u8, 221u8, 244u8, 218u8, 250u8,
+            212u8, 6u8, 149u8, 6u8, 43u8, 245u8, 87u8, 250u8, 46u8, 245u8, 173u8, 250u8, 170u8,
+            5u8, 165u8, 251u8, 74u8, 11u8, 149u8, 10u8, 75u8, 245u8, 155u8, 250u8, 90u8, 5u8, 85u8,
+            251u8, 74u8, 15u8, 164u8, 14u8, 74u8, 14u8, 149u8, 10u8, 45u8, 245u8, 173u8, 246u8,
+            106u8, 11u8, 84u8, 7u8, 73u8, 7u8, 149u8, 14u8, 42u8, 13u8, 90u8, 9u8, 186u8, 242u8,
+            185u8, 245u8, 180u8, 11u8, 100u8, 11u8, 170u8, 10u8, 86u8, 10u8, 182u8, 244u8, 109u8,
+            249u8, 236u8, 242u8, 233u8, 246u8, 178u8, 14u8, 84u8, 13u8, 170u8, 12u8, 58u8, 9u8,
+            182u8, 242u8, 117u8, 245u8, 106u8, 11u8, 84u8, 11u8, 37u8, 11u8, 75u8, 250u8, 27u8,
+            245u8, 91u8, 250u8, 182u8, 242u8, 181u8, 246u8, 169u8, 6u8, 147u8, 254u8, 37u8, 13u8,
+            77u8, 10u8, 173u8, 244u8, 91u8, 249u8, 90u8, 251u8, 210u8, 10u8, 165u8, 14u8, 74u8,
+            14u8, 150u8, 12u8, 54u8, 5u8, 117u8, 250u8, 116u8, 5u8, 105u8, 251u8, 82u8, 7u8, 169u8,
+            6u8, 85u8, 245u8, 173u8, 250u8, 236u8, 4u8, 234u8, 250u8, 212u8, 5u8, 201u8, 13u8,
+            82u8, 13u8, 165u8, 10u8, 213u8, 244u8, 117u8, 249u8, 244u8, 2u8, 233u8, 250u8, 210u8,
+            6u8, 165u8, 6u8, 43u8, 245u8, 87u8, 242u8, 183u8, 244u8, 118u8, 250u8, 106u8, 5u8,
+            101u8, 253u8, 74u8, 13u8, 150u8, 12u8, 46u8, 249u8, 93u8, 242u8, 221u8, 244u8, 214u8,
+            250u8, 170u8, 6u8, 149u8, 246u8, 43u8, 245u8, 87u8, 250u8, 174u8, 244u8, 109u8, 249u8,
+            234u8, 2u8, 101u8, 251u8, 201u8, 6u8, 147u8, 6u8, 43u8, 245u8, 103u8, 249u8, 214u8,
+            242u8, 213u8, 245u8, 210u8, 11u8, 164u8, 11u8, 73u8, 11u8, 149u8, 10u8, 45u8, 245u8,
+            173u8, 245u8, 106u8, 11u8, 228u8, 6u8, 201u8, 13u8, 146u8, 13u8, 166u8, 10u8, 86u8,
+            9u8, 174u8, 242u8, 109u8, 245u8, 106u8, 3u8, 85u8, 251u8, 170u8, 10u8, 77u8, 249u8,
+            157u8, 244u8, 93u8, 249u8, 186u8, 242u8, 181u8, 245u8, 170u8, 5u8, 85u8, 253u8, 154u8,
+            10u8, 46u8, 9u8, 94u8, 242u8, 93u8, 245u8, 218u8, 250u8, 212u8, 6u8, 165u8, 6u8, 75u8,
+            245u8, 151u8, 250u8, 78u8, 5u8, 174u8, 250u8, 172u8, 5u8, 169u8, 11u8, 146u8, 13u8,
+            37u8, 11u8, 75u8, 246u8, 171u8, 252u8, 90u8, 5u8, 85u8, 251u8, 210u8, 6u8, 165u8, 14u8,
+            74u8, 14u8, 149u8, 10u8, 45u8, 245u8, 173u8, 250u8, 108u8, 3u8, 89u8, 247u8, 210u8,
+            6u8, 149u8, 6u8, 45u8, 245u8, 91u8, 250u8, 186u8, 4u8, 186u8, 249u8, 180u8, 3u8, 105u8,
+            251u8, 82u8, 11u8, 166u8, 10u8, 182u8, 244u8, 109u8, 249u8, 236u8, 2u8, 217u8, 246u8,
+            178u8, 14u8, 84u8, 29u8, 42u8, 13u8, 86u8, 10u8, 174u8, 244u8, 109u8, 249u8, 106u8,
+            13u8, 84u8, 11u8, 41u8, 11u8, 147u8, 10u8, 43u8, 245u8, 87u8, 250u8, 54u8, 245u8,
+            181u8, 250u8, 170u8, 6u8, 147u8, 254u8, 38u8, 13u8, 85u8, 10u8, 173u8, 244u8, 93u8,
+            249u8, 218u8, 12u8, 212u8, 6u8, 169u8, 14u8, 82u8, 14u8, 170u8, 12u8, 86u8, 9u8, 181u8,
+            250u8, 116u8, 5u8, 113u8, 11u8, 100u8, 7u8, 201u8, 6u8, 85u8, 5u8, 173u8, 242u8, 109u8,
+            245u8, 234u8, 250u8, 228u8, 5u8, 209u8, 13u8, 82u8, 13u8, 165u8, 10u8, 85u8, 9u8,
+            117u8, 242u8, 237u8, 244u8, 233u8, 250u8, 210u8, 6u8, 165u8, 10u8, 75u8, 249u8, 87u8,
+            244u8, 183u8, 248u8, 118u8, 242u8, 117u8, 245u8, 106u8, 13u8, 74u8, 13u8, 150u8, 12u8,
+            46u8, 249u8, 94u8, 242u8, 221u8, 244u8, 218u8, 250u8, 210u8, 6u8, 165u8, 5u8, 75u8,
+            245u8, 151u8, 250u8, 174u8, 4u8, 109u8, 249u8, 106u8, 3u8, 101u8, 251u8, 82u8, 7u8,
+            165u8, 6u8, 75u8, 245u8, 171u8, 250u8, 90u8, 5u8, 213u8, 246u8, 210u8, 13u8, 164u8,
+            11u8, 74u8, 11u8, 149u8, 10u8, 77u8, 245u8, 173u8, 249u8, 106u8, 3u8, 213u8, 245u8,
+            202u8, 5u8, 149u8, 13u8, 42u8, 5u8, 87u8, 249u8, 174u8, 242u8, 110u8, 249u8, 108u8,
+            3u8, 85u8, 251u8, 170u8, 10u8, 85u8, 10u8, 173u8, 244u8, 93u8, 241u8, 189u8, 242u8,
+            186u8, 245u8, 170u8, 5u8, 85u8, 253u8, 170u8, 10u8, 78u8, 9u8, 174u8, 242u8, 93u8,
+            245u8, 218u8, 10u8, 212u8, 6u8, 169u8, 14u8, 138u8, 14u8, 22u8, 13u8, 86u8, 10u8,
+            182u8, 242u8, 181u8, 245u8, 169u8, 13u8, 146u8, 13u8, 69u8, 13u8, 139u8, 10u8, 43u8,
+            245u8, 91u8, 245u8, 90u8, 251u8, 212u8, 6u8, 169u8, 14u8,
+        ])
+    },
+};
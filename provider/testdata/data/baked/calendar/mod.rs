@@ -1,3 +1,4 @@
 // @generated
+pub mod islamicumalquracache_v1;
 pub mod japanese_v1;
 pub mod japanext_v1;
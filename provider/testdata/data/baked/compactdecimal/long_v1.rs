@@ -0,0 +1,464 @@
+// @generated
+#![cfg(feature = "icu_compactdecimal")]
+type DataStruct = < :: icu_compactdecimal :: provider :: LongCompactDecimalFormatDataV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[
+        ("ar", AR_AR_EG_AR_EG_U_NU_LATN_AR_U_NU_LATN),
+        ("ar-EG", AR_AR_EG_AR_EG_U_NU_LATN_AR_U_NU_LATN),
+        ("ar-EG-u-nu-latn", AR_AR_EG_AR_EG_U_NU_LATN_AR_U_NU_LATN),
+        ("ar-u-nu-latn", AR_AR_EG_AR_EG_U_NU_LATN_AR_U_NU_LATN),
+        ("bn", BN_BN_U_NU_LATN),
+        ("bn-u-nu-latn", BN_BN_U_NU_LATN),
+        ("ccp", CCP_CCP_U_NU_LATN_UND),
+        ("ccp-u-nu-latn", CCP_CCP_U_NU_LATN_UND),
+        ("en", EN_EN_001_EN_ZA),
+        ("en-001", EN_EN_001_EN_ZA),
+        ("en-ZA", EN_EN_001_EN_ZA),
+        ("es", ES),
+        ("es-AR", ES_AR),
+        ("fil", FIL),
+        ("fr", FR),
+        ("ja", JA),
+        ("ru", RU),
+        ("sr", SR_SR_CYRL),
+        ("sr-Cyrl", SR_SR_CYRL),
+        ("sr-Latn", SR_LATN),
+        ("th", TH_TH_U_NU_THAI),
+        ("th-u-nu-thai", TH_TH_U_NU_THAI),
+        ("tr", TR),
+        ("und", CCP_CCP_U_NU_LATN_UND),
+    ]);
+static AR_AR_EG_AR_EG_U_NU_LATN_AR_U_NU_LATN: &DataStruct =
+    &::icu_compactdecimal::provider::CompactDecimalPatternDataV1 {
+        patterns: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap2d::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[3u8, 4u8, 6u8, 8u8, 9u8, 12u8])
+                },
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        2u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8,
+                        0u8, 7u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        3u8, 5u8, 5u8, 3u8, 5u8, 5u8, 5u8, 5u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        8u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 20u8, 0u8, 29u8, 0u8, 44u8, 0u8,
+                        57u8, 0u8, 70u8, 0u8, 83u8, 0u8, 3u8, 0u8, 32u8, 216u8, 162u8, 217u8,
+                        132u8, 216u8, 167u8, 217u8, 129u8, 3u8, 0u8, 32u8, 216u8, 163u8, 217u8,
+                        132u8, 217u8, 129u8, 3u8, 0u8, 32u8, 216u8, 163u8, 217u8, 132u8, 217u8,
+                        129u8, 6u8, 0u8, 32u8, 217u8, 133u8, 217u8, 132u8, 216u8, 167u8, 217u8,
+                        138u8, 217u8, 138u8, 217u8, 134u8, 6u8, 0u8, 32u8, 217u8, 133u8, 217u8,
+                        132u8, 217u8, 138u8, 217u8, 136u8, 217u8, 134u8, 6u8, 0u8, 32u8, 217u8,
+                        133u8, 217u8, 132u8, 217u8, 138u8, 217u8, 136u8, 217u8, 134u8, 9u8, 0u8,
+                        32u8, 217u8, 133u8, 217u8, 132u8, 217u8, 138u8, 216u8, 167u8, 216u8, 177u8,
+                        12u8, 0u8, 32u8, 216u8, 170u8, 216u8, 177u8, 217u8, 132u8, 217u8, 138u8,
+                        217u8, 136u8, 217u8, 134u8,
+                    ])
+                },
+            )
+        },
+    };
+static BN_BN_U_NU_LATN: &DataStruct =
+    &::icu_compactdecimal::provider::CompactDecimalPatternDataV1 {
+        patterns: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap2d::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[3u8, 5u8, 7u8, 12u8]) },
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8,
+                        0u8,
+                    ])
+                },
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8, 5u8, 5u8, 5u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 18u8, 0u8, 30u8, 0u8, 45u8, 0u8, 3u8, 0u8,
+                        32u8, 224u8, 166u8, 185u8, 224u8, 166u8, 190u8, 224u8, 166u8, 156u8, 224u8,
+                        166u8, 190u8, 224u8, 166u8, 176u8, 5u8, 0u8, 32u8, 224u8, 166u8, 178u8,
+                        224u8, 166u8, 190u8, 224u8, 166u8, 150u8, 7u8, 0u8, 32u8, 224u8, 166u8,
+                        149u8, 224u8, 167u8, 139u8, 224u8, 166u8, 159u8, 224u8, 166u8, 191u8, 12u8,
+                        0u8, 32u8, 224u8, 166u8, 178u8, 224u8, 166u8, 190u8, 224u8, 166u8, 150u8,
+                        32u8, 224u8, 166u8, 149u8, 224u8, 167u8, 139u8, 224u8, 166u8, 159u8, 224u8,
+                        166u8, 191u8,
+                    ])
+                },
+            )
+        },
+    };
+static CCP_CCP_U_NU_LATN_UND: &DataStruct =
+    &::icu_compactdecimal::provider::CompactDecimalPatternDataV1 {
+        patterns: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap2d::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[3u8, 6u8, 9u8, 12u8]) },
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8,
+                        0u8,
+                    ])
+                },
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8, 5u8, 5u8, 5u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 6u8, 0u8, 9u8, 0u8, 3u8, 0u8, 75u8,
+                        6u8, 0u8, 77u8, 9u8, 0u8, 71u8, 12u8, 0u8, 84u8,
+                    ])
+                },
+            )
+        },
+    };
+static EN_EN_001_EN_ZA: &DataStruct =
+    &::icu_compactdecimal::provider::CompactDecimalPatternDataV1 {
+        patterns: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap2d::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[3u8, 6u8, 9u8, 12u8]) },
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8,
+                        0u8,
+                    ])
+                },
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8, 5u8, 5u8, 5u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 21u8, 0u8, 31u8, 0u8, 3u8, 0u8,
+                        32u8, 116u8, 104u8, 111u8, 117u8, 115u8, 97u8, 110u8, 100u8, 6u8, 0u8,
+                        32u8, 109u8, 105u8, 108u8, 108u8, 105u8, 111u8, 110u8, 9u8, 0u8, 32u8,
+                        98u8, 105u8, 108u8, 108u8, 105u8, 111u8, 110u8, 12u8, 0u8, 32u8, 116u8,
+                        114u8, 105u8, 108u8, 108u8, 105u8, 111u8, 110u8,
+                    ])
+                },
+            )
+        },
+    };
+static ES: &DataStruct = &::icu_compactdecimal::provider::CompactDecimalPatternDataV1 {
+    patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[3u8, 6u8, 7u8, 9u8, 12u8, 13u8]) },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8,
+                    7u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8, 1u8, 5u8, 5u8, 5u8, 1u8, 5u8, 5u8])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    8u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 16u8, 0u8, 27u8, 0u8, 38u8, 0u8, 53u8,
+                    0u8, 63u8, 0u8, 74u8, 0u8, 3u8, 0u8, 32u8, 109u8, 105u8, 108u8, 6u8, 0u8, 32u8,
+                    109u8, 105u8, 108u8, 108u8, 195u8, 179u8, 110u8, 6u8, 0u8, 32u8, 109u8, 105u8,
+                    108u8, 108u8, 111u8, 110u8, 101u8, 115u8, 6u8, 0u8, 32u8, 109u8, 105u8, 108u8,
+                    108u8, 111u8, 110u8, 101u8, 115u8, 9u8, 0u8, 32u8, 109u8, 105u8, 108u8, 32u8,
+                    109u8, 105u8, 108u8, 108u8, 111u8, 110u8, 101u8, 115u8, 12u8, 0u8, 32u8, 98u8,
+                    105u8, 108u8, 108u8, 195u8, 179u8, 110u8, 12u8, 0u8, 32u8, 98u8, 105u8, 108u8,
+                    108u8, 111u8, 110u8, 101u8, 115u8, 12u8, 0u8, 32u8, 98u8, 105u8, 108u8, 108u8,
+                    111u8, 110u8, 101u8, 115u8,
+                ])
+            },
+        )
+    },
+};
+static ES_AR: &DataStruct = &::icu_compactdecimal::provider::CompactDecimalPatternDataV1 {
+    patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[3u8, 6u8, 7u8, 9u8, 12u8, 13u8]) },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8,
+                    6u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8, 1u8, 5u8, 5u8, 5u8, 5u8, 5u8])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 16u8, 0u8, 27u8, 0u8, 38u8, 0u8, 53u8,
+                    0u8, 63u8, 0u8, 3u8, 0u8, 32u8, 109u8, 105u8, 108u8, 6u8, 0u8, 32u8, 109u8,
+                    105u8, 108u8, 108u8, 195u8, 179u8, 110u8, 6u8, 0u8, 32u8, 109u8, 105u8, 108u8,
+                    108u8, 111u8, 110u8, 101u8, 115u8, 6u8, 0u8, 32u8, 109u8, 105u8, 108u8, 108u8,
+                    111u8, 110u8, 101u8, 115u8, 9u8, 0u8, 32u8, 109u8, 105u8, 108u8, 32u8, 109u8,
+                    105u8, 108u8, 108u8, 111u8, 110u8, 101u8, 115u8, 12u8, 0u8, 32u8, 98u8, 105u8,
+                    108u8, 108u8, 195u8, 179u8, 110u8, 12u8, 0u8, 32u8, 98u8, 105u8, 108u8, 108u8,
+                    111u8, 110u8, 101u8, 115u8,
+                ])
+            },
+        )
+    },
+};
+static FIL: &DataStruct = &::icu_compactdecimal::provider::CompactDecimalPatternDataV1 {
+    patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[3u8, 6u8, 9u8, 12u8]) },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    8u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 17u8, 0u8, 26u8, 0u8, 38u8, 0u8, 47u8,
+                    0u8, 59u8, 0u8, 69u8, 0u8, 3u8, 0u8, 32u8, 108u8, 105u8, 98u8, 111u8, 3u8, 0u8,
+                    32u8, 110u8, 97u8, 32u8, 108u8, 105u8, 98u8, 111u8, 6u8, 0u8, 32u8, 109u8,
+                    105u8, 108u8, 121u8, 111u8, 110u8, 6u8, 0u8, 32u8, 110u8, 97u8, 32u8, 109u8,
+                    105u8, 108u8, 121u8, 111u8, 110u8, 9u8, 0u8, 32u8, 98u8, 105u8, 108u8, 121u8,
+                    111u8, 110u8, 9u8, 0u8, 32u8, 110u8, 97u8, 32u8, 98u8, 105u8, 108u8, 121u8,
+                    111u8, 110u8, 12u8, 0u8, 32u8, 116u8, 114u8, 105u8, 108u8, 121u8, 111u8, 110u8,
+                    12u8, 0u8, 32u8, 110u8, 97u8, 32u8, 116u8, 114u8, 105u8, 108u8, 121u8, 111u8,
+                    110u8,
+                ])
+            },
+        )
+    },
+};
+static FR: &DataStruct = &::icu_compactdecimal::provider::CompactDecimalPatternDataV1 {
+    patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[3u8, 4u8, 6u8, 9u8, 12u8]) },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    3u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8,
+                    10u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 5u8, 6u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 18u8, 0u8, 25u8, 0u8, 33u8, 0u8,
+                    43u8, 0u8, 54u8, 0u8, 65u8, 0u8, 77u8, 0u8, 87u8, 0u8, 3u8, 0u8, 32u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 101u8, 114u8, 3u8, 0u8, 32u8, 109u8, 105u8, 108u8,
+                    108u8, 101u8, 3u8, 255u8, 109u8, 105u8, 108u8, 108u8, 101u8, 3u8, 0u8, 32u8,
+                    109u8, 105u8, 108u8, 108u8, 101u8, 6u8, 0u8, 32u8, 109u8, 105u8, 108u8, 108u8,
+                    105u8, 111u8, 110u8, 6u8, 0u8, 32u8, 109u8, 105u8, 108u8, 108u8, 105u8, 111u8,
+                    110u8, 115u8, 9u8, 0u8, 32u8, 109u8, 105u8, 108u8, 108u8, 105u8, 97u8, 114u8,
+                    100u8, 9u8, 0u8, 32u8, 109u8, 105u8, 108u8, 108u8, 105u8, 97u8, 114u8, 100u8,
+                    115u8, 12u8, 0u8, 32u8, 98u8, 105u8, 108u8, 108u8, 105u8, 111u8, 110u8, 12u8,
+                    0u8, 32u8, 98u8, 105u8, 108u8, 108u8, 105u8, 111u8, 110u8, 115u8,
+                ])
+            },
+        )
+    },
+};
+static JA: &DataStruct = &::icu_compactdecimal::provider::CompactDecimalPatternDataV1 {
+    patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[4u8, 8u8, 12u8]) },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8, 5u8, 5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 10u8, 0u8, 4u8, 0u8, 228u8, 184u8,
+                    135u8, 8u8, 0u8, 229u8, 132u8, 132u8, 12u8, 0u8, 229u8, 133u8, 134u8,
+                ])
+            },
+        )
+    },
+};
+static RU: &DataStruct = &::icu_compactdecimal::provider::CompactDecimalPatternDataV1 {
+    patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[3u8, 6u8, 9u8, 12u8]) },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    3u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 4u8, 5u8, 1u8, 4u8, 5u8, 1u8, 4u8, 5u8, 1u8, 4u8, 5u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    12u8, 0u8, 0u8, 0u8, 0u8, 0u8, 15u8, 0u8, 28u8, 0u8, 43u8, 0u8, 60u8, 0u8,
+                    81u8, 0u8, 100u8, 0u8, 119u8, 0u8, 142u8, 0u8, 163u8, 0u8, 182u8, 0u8, 205u8,
+                    0u8, 3u8, 0u8, 32u8, 209u8, 130u8, 209u8, 139u8, 209u8, 129u8, 209u8, 143u8,
+                    209u8, 135u8, 208u8, 176u8, 3u8, 0u8, 32u8, 209u8, 130u8, 209u8, 139u8, 209u8,
+                    129u8, 209u8, 143u8, 209u8, 135u8, 3u8, 0u8, 32u8, 209u8, 130u8, 209u8, 139u8,
+                    209u8, 129u8, 209u8, 143u8, 209u8, 135u8, 208u8, 184u8, 6u8, 0u8, 32u8, 208u8,
+                    188u8, 208u8, 184u8, 208u8, 187u8, 208u8, 187u8, 208u8, 184u8, 208u8, 190u8,
+                    208u8, 189u8, 6u8, 0u8, 32u8, 208u8, 188u8, 208u8, 184u8, 208u8, 187u8, 208u8,
+                    187u8, 208u8, 184u8, 208u8, 190u8, 208u8, 189u8, 208u8, 190u8, 208u8, 178u8,
+                    6u8, 0u8, 32u8, 208u8, 188u8, 208u8, 184u8, 208u8, 187u8, 208u8, 187u8, 208u8,
+                    184u8, 208u8, 190u8, 208u8, 189u8, 208u8, 176u8, 9u8, 0u8, 32u8, 208u8, 188u8,
+                    208u8, 184u8, 208u8, 187u8, 208u8, 187u8, 208u8, 184u8, 208u8, 176u8, 209u8,
+                    128u8, 208u8, 180u8, 9u8, 0u8, 32u8, 208u8, 188u8, 208u8, 184u8, 208u8, 187u8,
+                    208u8, 187u8, 208u8, 184u8, 208u8, 176u8, 209u8, 128u8, 208u8, 180u8, 208u8,
+                    190u8, 208u8, 178u8, 9u8, 0u8, 32u8, 208u8, 188u8, 208u8, 184u8, 208u8, 187u8,
+                    208u8, 187u8, 208u8, 184u8, 208u8, 176u8, 209u8, 128u8, 208u8, 180u8, 208u8,
+                    176u8, 12u8, 0u8, 32u8, 209u8, 130u8, 209u8, 128u8, 208u8, 184u8, 208u8, 187u8,
+                    208u8, 187u8, 208u8, 184u8, 208u8, 190u8, 208u8, 189u8, 12u8, 0u8, 32u8, 209u8,
+                    130u8, 209u8, 128u8, 208u8, 184u8, 208u8, 187u8, 208u8, 187u8, 208u8, 184u8,
+                    208u8, 190u8, 208u8, 189u8, 208u8, 190u8, 208u8, 178u8, 12u8, 0u8, 32u8, 209u8,
+                    130u8, 209u8, 128u8, 208u8, 184u8, 208u8, 187u8, 208u8, 187u8, 208u8, 184u8,
+                    208u8, 190u8, 208u8, 189u8, 208u8, 176u8,
+                ])
+            },
+        )
+    },
+};
+static SR_LATN: &DataStruct = &::icu_compactdecimal::provider::CompactDecimalPatternDataV1 {
+    patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[3u8, 6u8, 9u8, 12u8]) },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    3u8, 5u8, 1u8, 5u8, 1u8, 3u8, 5u8, 1u8, 5u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    9u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 20u8, 0u8, 29u8, 0u8, 39u8, 0u8, 51u8,
+                    0u8, 63u8, 0u8, 75u8, 0u8, 84u8, 0u8, 3u8, 0u8, 32u8, 104u8, 105u8, 108u8,
+                    106u8, 97u8, 100u8, 101u8, 3u8, 0u8, 32u8, 104u8, 105u8, 108u8, 106u8, 97u8,
+                    100u8, 97u8, 6u8, 0u8, 32u8, 109u8, 105u8, 108u8, 105u8, 111u8, 110u8, 6u8,
+                    0u8, 32u8, 109u8, 105u8, 108u8, 105u8, 111u8, 110u8, 97u8, 9u8, 0u8, 32u8,
+                    109u8, 105u8, 108u8, 105u8, 106u8, 97u8, 114u8, 100u8, 97u8, 9u8, 0u8, 32u8,
+                    109u8, 105u8, 108u8, 105u8, 106u8, 97u8, 114u8, 100u8, 101u8, 9u8, 0u8, 32u8,
+                    109u8, 105u8, 108u8, 105u8, 106u8, 97u8, 114u8, 100u8, 105u8, 12u8, 0u8, 32u8,
+                    98u8, 105u8, 108u8, 105u8, 111u8, 110u8, 12u8, 0u8, 32u8, 98u8, 105u8, 108u8,
+                    105u8, 111u8, 110u8, 97u8,
+                ])
+            },
+        )
+    },
+};
+static SR_SR_CYRL: &DataStruct = &::icu_compactdecimal::provider::CompactDecimalPatternDataV1 {
+    patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[3u8, 6u8, 9u8, 12u8]) },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    3u8, 5u8, 1u8, 5u8, 1u8, 3u8, 5u8, 1u8, 5u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    9u8, 0u8, 0u8, 0u8, 0u8, 0u8, 15u8, 0u8, 30u8, 0u8, 45u8, 0u8, 62u8, 0u8, 83u8,
+                    0u8, 104u8, 0u8, 125u8, 0u8, 140u8, 0u8, 3u8, 0u8, 32u8, 209u8, 133u8, 208u8,
+                    184u8, 209u8, 153u8, 208u8, 176u8, 208u8, 180u8, 208u8, 181u8, 3u8, 0u8, 32u8,
+                    209u8, 133u8, 208u8, 184u8, 209u8, 153u8, 208u8, 176u8, 208u8, 180u8, 208u8,
+                    176u8, 6u8, 0u8, 32u8, 208u8, 188u8, 208u8, 184u8, 208u8, 187u8, 208u8, 184u8,
+                    208u8, 190u8, 208u8, 189u8, 6u8, 0u8, 32u8, 208u8, 188u8, 208u8, 184u8, 208u8,
+                    187u8, 208u8, 184u8, 208u8, 190u8, 208u8, 189u8, 208u8, 176u8, 9u8, 0u8, 32u8,
+                    208u8, 188u8, 208u8, 184u8, 208u8, 187u8, 208u8, 184u8, 209u8, 152u8, 208u8,
+                    176u8, 209u8, 128u8, 208u8, 180u8, 208u8, 176u8, 9u8, 0u8, 32u8, 208u8, 188u8,
+                    208u8, 184u8, 208u8, 187u8, 208u8, 184u8, 209u8, 152u8, 208u8, 176u8, 209u8,
+                    128u8, 208u8, 180u8, 208u8, 181u8, 9u8, 0u8, 32u8, 208u8, 188u8, 208u8, 184u8,
+                    208u8, 187u8, 208u8, 184u8, 209u8, 152u8, 208u8, 176u8, 209u8, 128u8, 208u8,
+                    180u8, 208u8, 184u8, 12u8, 0u8, 32u8, 208u8, 177u8, 208u8, 184u8, 208u8, 187u8,
+                    208u8, 184u8, 208u8, 190u8, 208u8, 189u8, 12u8, 0u8, 32u8, 208u8, 177u8, 208u8,
+                    184u8, 208u8, 187u8, 208u8, 184u8, 208u8, 190u8, 208u8, 189u8, 208u8, 176u8,
+                ])
+            },
+        )
+    },
+};
+static TH_TH_U_NU_THAI: &DataStruct =
+    &::icu_compactdecimal::provider::CompactDecimalPatternDataV1 {
+        patterns: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap2d::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        3u8, 4u8, 5u8, 6u8, 9u8, 10u8, 11u8, 12u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8,
+                        0u8, 5u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 8u8, 0u8,
+                        0u8, 0u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        8u8, 0u8, 0u8, 0u8, 0u8, 0u8, 12u8, 0u8, 30u8, 0u8, 42u8, 0u8, 57u8, 0u8,
+                        81u8, 0u8, 111u8, 0u8, 135u8, 0u8, 3u8, 0u8, 32u8, 224u8, 184u8, 158u8,
+                        224u8, 184u8, 177u8, 224u8, 184u8, 153u8, 4u8, 0u8, 32u8, 224u8, 184u8,
+                        171u8, 224u8, 184u8, 161u8, 224u8, 184u8, 183u8, 224u8, 185u8, 136u8,
+                        224u8, 184u8, 153u8, 5u8, 0u8, 32u8, 224u8, 185u8, 129u8, 224u8, 184u8,
+                        170u8, 224u8, 184u8, 153u8, 6u8, 0u8, 32u8, 224u8, 184u8, 165u8, 224u8,
+                        185u8, 137u8, 224u8, 184u8, 178u8, 224u8, 184u8, 153u8, 9u8, 0u8, 32u8,
+                        224u8, 184u8, 158u8, 224u8, 184u8, 177u8, 224u8, 184u8, 153u8, 224u8,
+                        184u8, 165u8, 224u8, 185u8, 137u8, 224u8, 184u8, 178u8, 224u8, 184u8,
+                        153u8, 10u8, 0u8, 32u8, 224u8, 184u8, 171u8, 224u8, 184u8, 161u8, 224u8,
+                        184u8, 183u8, 224u8, 185u8, 136u8, 224u8, 184u8, 153u8, 224u8, 184u8,
+                        165u8, 224u8, 185u8, 137u8, 224u8, 184u8, 178u8, 224u8, 184u8, 153u8, 11u8,
+                        0u8, 32u8, 224u8, 185u8, 129u8, 224u8, 184u8, 170u8, 224u8, 184u8, 153u8,
+                        224u8, 184u8, 165u8, 224u8, 185u8, 137u8, 224u8, 184u8, 178u8, 224u8,
+                        184u8, 153u8, 12u8, 0u8, 32u8, 224u8, 184u8, 165u8, 224u8, 185u8, 137u8,
+                        224u8, 184u8, 178u8, 224u8, 184u8, 153u8, 224u8, 184u8, 165u8, 224u8,
+                        185u8, 137u8, 224u8, 184u8, 178u8, 224u8, 184u8, 153u8,
+                    ])
+                },
+            )
+        },
+    };
+static TR: &DataStruct = &::icu_compactdecimal::provider::CompactDecimalPatternDataV1 {
+    patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap2d::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[3u8, 6u8, 9u8, 12u8]) },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8,
+                ])
+            },
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[5u8, 5u8, 5u8, 5u8]) },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 15u8, 0u8, 24u8, 0u8, 3u8, 0u8, 32u8,
+                    98u8, 105u8, 110u8, 6u8, 0u8, 32u8, 109u8, 105u8, 108u8, 121u8, 111u8, 110u8,
+                    9u8, 0u8, 32u8, 109u8, 105u8, 108u8, 121u8, 97u8, 114u8, 12u8, 0u8, 32u8,
+                    116u8, 114u8, 105u8, 108u8, 121u8, 111u8, 110u8,
+                ])
+            },
+        )
+    },
+};
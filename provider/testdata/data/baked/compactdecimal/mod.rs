@@ -0,0 +1,3 @@
+// @generated
+pub mod long_v1;
+pub mod short_v1;
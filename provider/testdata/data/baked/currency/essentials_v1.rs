@@ -0,0 +1,1205 @@
+// @generated
+#![cfg(feature = "icu_currency")]
+type DataStruct =
+    <::icu_currency::provider::CurrencyEssentialsV1Marker as ::icu_provider::DataMarker>::Yokeable;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[
+        ("ar", AR_AR_EG),
+        ("ar-EG", AR_AR_EG),
+        ("ar-EG-u-nu-latn", AR_EG_U_NU_LATN_AR_U_NU_LATN),
+        ("ar-u-nu-latn", AR_EG_U_NU_LATN_AR_U_NU_LATN),
+        ("bn", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN),
+        ("bn-u-nu-latn", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN),
+        ("ccp", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN),
+        ("ccp-u-nu-latn", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN),
+        ("en", EN),
+        ("en-001", EN_001),
+        ("en-ZA", EN_ZA),
+        ("es", ES),
+        ("es-AR", ES_AR),
+        ("fil", FIL),
+        ("fr", FR),
+        ("ja", JA),
+        ("ru", RU),
+        ("sr", SR_SR_CYRL_SR_LATN),
+        ("sr-Cyrl", SR_SR_CYRL_SR_LATN),
+        ("sr-Latn", SR_SR_CYRL_SR_LATN),
+        ("th", TH_TH_U_NU_THAI),
+        ("th-u-nu-thai", TH_TH_U_NU_THAI),
+        ("tr", TR),
+        ("und", UND),
+    ]);
+static AR_AR_EG: &DataStruct = &::icu_currency::provider::CurrencyEssentialsV1 {
+    standard: ::icu_currency::provider::CurrencyPatterns {
+        positive: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed("\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        negative: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed("\u{61c}-"),
+            infix: alloc::borrow::Cow::Borrowed("\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+    },
+    accounting: ::icu_currency::provider::CurrencyPatterns {
+        positive: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed("\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        negative: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed("\u{61c}-"),
+            infix: alloc::borrow::Cow::Borrowed("\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+    },
+    spacing_before_number: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    spacing_after_number: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 85u8, 68u8, 66u8, 82u8, 76u8, 67u8, 65u8, 68u8, 67u8, 78u8, 89u8, 69u8,
+                    71u8, 80u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8, 73u8, 78u8, 82u8, 74u8, 80u8,
+                    89u8, 75u8, 82u8, 87u8, 75u8, 87u8, 68u8, 77u8, 88u8, 78u8, 84u8, 72u8, 66u8,
+                    85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    14u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 5u8, 0u8, 8u8, 0u8, 12u8, 0u8, 18u8,
+                    0u8, 21u8, 0u8, 25u8, 0u8, 28u8, 0u8, 32u8, 0u8, 35u8, 0u8, 41u8, 0u8, 44u8,
+                    0u8, 47u8, 0u8, 65u8, 85u8, 36u8, 82u8, 36u8, 67u8, 65u8, 36u8, 67u8, 78u8,
+                    194u8, 165u8, 216u8, 172u8, 46u8, 217u8, 133u8, 46u8, 226u8, 130u8, 172u8,
+                    85u8, 75u8, 194u8, 163u8, 226u8, 130u8, 185u8, 74u8, 80u8, 194u8, 165u8, 226u8,
+                    130u8, 169u8, 216u8, 175u8, 46u8, 217u8, 131u8, 46u8, 77u8, 88u8, 36u8, 224u8,
+                    184u8, 191u8, 85u8, 83u8, 36u8,
+                ])
+            },
+        )
+    },
+    narrow_symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 82u8, 83u8, 66u8, 68u8, 84u8, 67u8, 76u8, 80u8, 68u8, 75u8, 75u8, 69u8,
+                    71u8, 80u8, 80u8, 72u8, 80u8, 82u8, 85u8, 66u8, 83u8, 69u8, 75u8, 84u8, 82u8,
+                    89u8, 90u8, 65u8, 82u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 6u8, 0u8, 9u8, 0u8, 11u8, 0u8, 14u8,
+                    0u8, 17u8, 0u8, 20u8, 0u8, 22u8, 0u8, 25u8, 0u8, 65u8, 82u8, 36u8, 224u8,
+                    167u8, 179u8, 67u8, 76u8, 36u8, 107u8, 114u8, 69u8, 194u8, 163u8, 226u8, 130u8,
+                    177u8, 226u8, 130u8, 189u8, 107u8, 114u8, 226u8, 130u8, 186u8, 82u8,
+                ])
+            },
+        )
+    },
+};
+static AR_EG_U_NU_LATN_AR_U_NU_LATN: &DataStruct =
+    &::icu_currency::provider::CurrencyEssentialsV1 {
+        standard: ::icu_currency::provider::CurrencyPatterns {
+            positive: ::icu_currency::provider::CurrencyPattern {
+                prefix: alloc::borrow::Cow::Borrowed(""),
+                infix: alloc::borrow::Cow::Borrowed("\u{a0}"),
+                suffix: alloc::borrow::Cow::Borrowed(""),
+                currency_first: true,
+            },
+            negative: ::icu_currency::provider::CurrencyPattern {
+                prefix: alloc::borrow::Cow::Borrowed("\u{200e}-"),
+                infix: alloc::borrow::Cow::Borrowed("\u{a0}"),
+                suffix: alloc::borrow::Cow::Borrowed(""),
+                currency_first: true,
+            },
+        },
+        accounting: ::icu_currency::provider::CurrencyPatterns {
+            positive: ::icu_currency::provider::CurrencyPattern {
+                prefix: alloc::borrow::Cow::Borrowed(""),
+                infix: alloc::borrow::Cow::Borrowed(""),
+                suffix: alloc::borrow::Cow::Borrowed(""),
+                currency_first: true,
+            },
+            negative: ::icu_currency::provider::CurrencyPattern {
+                prefix: alloc::borrow::Cow::Borrowed("("),
+                infix: alloc::borrow::Cow::Borrowed(""),
+                suffix: alloc::borrow::Cow::Borrowed(")"),
+                currency_first: true,
+            },
+        },
+        spacing_before_number: alloc::borrow::Cow::Borrowed("\u{a0}"),
+        spacing_after_number: alloc::borrow::Cow::Borrowed("\u{a0}"),
+        symbols: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        65u8, 85u8, 68u8, 66u8, 82u8, 76u8, 67u8, 65u8, 68u8, 67u8, 78u8, 89u8,
+                        69u8, 71u8, 80u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8, 73u8, 78u8, 82u8,
+                        74u8, 80u8, 89u8, 75u8, 82u8, 87u8, 75u8, 87u8, 68u8, 77u8, 88u8, 78u8,
+                        84u8, 72u8, 66u8, 85u8, 83u8, 68u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        14u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 5u8, 0u8, 8u8, 0u8, 12u8, 0u8,
+                        18u8, 0u8, 21u8, 0u8, 25u8, 0u8, 28u8, 0u8, 32u8, 0u8, 35u8, 0u8, 41u8,
+                        0u8, 44u8, 0u8, 47u8, 0u8, 65u8, 85u8, 36u8, 82u8, 36u8, 67u8, 65u8, 36u8,
+                        67u8, 78u8, 194u8, 165u8, 216u8, 172u8, 46u8, 217u8, 133u8, 46u8, 226u8,
+                        130u8, 172u8, 85u8, 75u8, 194u8, 163u8, 226u8, 130u8, 185u8, 74u8, 80u8,
+                        194u8, 165u8, 226u8, 130u8, 169u8, 216u8, 175u8, 46u8, 217u8, 131u8, 46u8,
+                        77u8, 88u8, 36u8, 224u8, 184u8, 191u8, 85u8, 83u8, 36u8,
+                    ])
+                },
+            )
+        },
+        narrow_symbols: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        65u8, 82u8, 83u8, 66u8, 68u8, 84u8, 67u8, 76u8, 80u8, 68u8, 75u8, 75u8,
+                        69u8, 71u8, 80u8, 80u8, 72u8, 80u8, 82u8, 85u8, 66u8, 83u8, 69u8, 75u8,
+                        84u8, 82u8, 89u8, 90u8, 65u8, 82u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 6u8, 0u8, 9u8, 0u8, 11u8, 0u8,
+                        14u8, 0u8, 17u8, 0u8, 20u8, 0u8, 22u8, 0u8, 25u8, 0u8, 65u8, 82u8, 36u8,
+                        224u8, 167u8, 179u8, 67u8, 76u8, 36u8, 107u8, 114u8, 69u8, 194u8, 163u8,
+                        226u8, 130u8, 177u8, 226u8, 130u8, 189u8, 107u8, 114u8, 226u8, 130u8,
+                        186u8, 82u8,
+                    ])
+                },
+            )
+        },
+    };
+static BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN: &DataStruct =
+    &::icu_currency::provider::CurrencyEssentialsV1 {
+        standard: ::icu_currency::provider::CurrencyPatterns {
+            positive: ::icu_currency::provider::CurrencyPattern {
+                prefix: alloc::borrow::Cow::Borrowed(""),
+                infix: alloc::borrow::Cow::Borrowed(""),
+                suffix: alloc::borrow::Cow::Borrowed(""),
+                currency_first: false,
+            },
+            negative: ::icu_currency::provider::CurrencyPattern {
+                prefix: alloc::borrow::Cow::Borrowed("-"),
+                infix: alloc::borrow::Cow::Borrowed(""),
+                suffix: alloc::borrow::Cow::Borrowed(""),
+                currency_first: false,
+            },
+        },
+        accounting: ::icu_currency::provider::CurrencyPatterns {
+            positive: ::icu_currency::provider::CurrencyPattern {
+                prefix: alloc::borrow::Cow::Borrowed(""),
+                infix: alloc::borrow::Cow::Borrowed(""),
+                suffix: alloc::borrow::Cow::Borrowed(""),
+                currency_first: false,
+            },
+            negative: ::icu_currency::provider::CurrencyPattern {
+                prefix: alloc::borrow::Cow::Borrowed("("),
+                infix: alloc::borrow::Cow::Borrowed(""),
+                suffix: alloc::borrow::Cow::Borrowed(")"),
+                currency_first: false,
+            },
+        },
+        spacing_before_number: alloc::borrow::Cow::Borrowed("\u{a0}"),
+        spacing_after_number: alloc::borrow::Cow::Borrowed("\u{a0}"),
+        symbols: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 66u8, 82u8, 76u8, 67u8, 65u8, 68u8,
+                        67u8, 78u8, 89u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8, 73u8, 78u8, 82u8,
+                        74u8, 80u8, 89u8, 75u8, 82u8, 87u8, 77u8, 88u8, 78u8, 84u8, 72u8, 66u8,
+                        85u8, 83u8, 68u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 5u8, 0u8, 7u8, 0u8, 10u8, 0u8,
+                        14u8, 0u8, 17u8, 0u8, 19u8, 0u8, 22u8, 0u8, 26u8, 0u8, 29u8, 0u8, 32u8,
+                        0u8, 35u8, 0u8, 65u8, 36u8, 224u8, 167u8, 179u8, 82u8, 36u8, 67u8, 65u8,
+                        36u8, 67u8, 78u8, 194u8, 165u8, 226u8, 130u8, 172u8, 194u8, 163u8, 226u8,
+                        130u8, 185u8, 74u8, 80u8, 194u8, 165u8, 226u8, 130u8, 169u8, 77u8, 88u8,
+                        36u8, 224u8, 184u8, 191u8, 85u8, 83u8, 36u8,
+                    ])
+                },
+            )
+        },
+        narrow_symbols: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 67u8, 65u8, 68u8, 67u8, 76u8, 80u8,
+                        67u8, 78u8, 89u8, 68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 74u8, 80u8, 89u8,
+                        77u8, 88u8, 78u8, 80u8, 72u8, 80u8, 82u8, 85u8, 66u8, 83u8, 69u8, 75u8,
+                        84u8, 82u8, 89u8, 85u8, 83u8, 68u8, 90u8, 65u8, 82u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 3u8, 0u8, 4u8, 0u8, 6u8,
+                        0u8, 8u8, 0u8, 11u8, 0u8, 13u8, 0u8, 14u8, 0u8, 17u8, 0u8, 20u8, 0u8, 22u8,
+                        0u8, 25u8, 0u8, 26u8, 0u8, 36u8, 36u8, 36u8, 36u8, 194u8, 165u8, 107u8,
+                        114u8, 69u8, 194u8, 163u8, 194u8, 165u8, 36u8, 226u8, 130u8, 177u8, 226u8,
+                        130u8, 189u8, 107u8, 114u8, 226u8, 130u8, 186u8, 36u8, 82u8,
+                    ])
+                },
+            )
+        },
+    };
+static EN: &DataStruct = &::icu_currency::provider::CurrencyEssentialsV1 {
+    standard: ::icu_currency::provider::CurrencyPatterns {
+        positive: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: true,
+        },
+        negative: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed("-"),
+            infix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: true,
+        },
+    },
+    accounting: ::icu_currency::provider::CurrencyPatterns {
+        positive: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: true,
+        },
+        negative: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed("("),
+            infix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed(")"),
+            currency_first: true,
+        },
+    },
+    spacing_before_number: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    spacing_after_number: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 85u8, 68u8, 66u8, 82u8, 76u8, 67u8, 65u8, 68u8, 67u8, 78u8, 89u8, 69u8,
+                    85u8, 82u8, 71u8, 66u8, 80u8, 73u8, 78u8, 82u8, 74u8, 80u8, 89u8, 75u8, 82u8,
+                    87u8, 77u8, 88u8, 78u8, 80u8, 72u8, 80u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    12u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 4u8, 0u8, 7u8, 0u8, 11u8, 0u8, 14u8,
+                    0u8, 16u8, 0u8, 19u8, 0u8, 21u8, 0u8, 24u8, 0u8, 27u8, 0u8, 30u8, 0u8, 65u8,
+                    36u8, 82u8, 36u8, 67u8, 65u8, 36u8, 67u8, 78u8, 194u8, 165u8, 226u8, 130u8,
+                    172u8, 194u8, 163u8, 226u8, 130u8, 185u8, 194u8, 165u8, 226u8, 130u8, 169u8,
+                    77u8, 88u8, 36u8, 226u8, 130u8, 177u8, 36u8,
+                ])
+            },
+        )
+    },
+    narrow_symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 67u8, 65u8, 68u8, 67u8,
+                    76u8, 80u8, 67u8, 78u8, 89u8, 68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 77u8, 88u8,
+                    78u8, 82u8, 85u8, 66u8, 83u8, 69u8, 75u8, 84u8, 72u8, 66u8, 84u8, 82u8, 89u8,
+                    90u8, 65u8, 82u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    14u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 5u8, 0u8, 6u8, 0u8, 7u8,
+                    0u8, 9u8, 0u8, 11u8, 0u8, 14u8, 0u8, 15u8, 0u8, 18u8, 0u8, 20u8, 0u8, 23u8,
+                    0u8, 26u8, 0u8, 36u8, 36u8, 224u8, 167u8, 179u8, 36u8, 36u8, 194u8, 165u8,
+                    107u8, 114u8, 69u8, 194u8, 163u8, 36u8, 226u8, 130u8, 189u8, 107u8, 114u8,
+                    224u8, 184u8, 191u8, 226u8, 130u8, 186u8, 82u8,
+                ])
+            },
+        )
+    },
+};
+static EN_001: &DataStruct = &::icu_currency::provider::CurrencyEssentialsV1 {
+    standard: ::icu_currency::provider::CurrencyPatterns {
+        positive: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: true,
+        },
+        negative: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed("-"),
+            infix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: true,
+        },
+    },
+    accounting: ::icu_currency::provider::CurrencyPatterns {
+        positive: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: true,
+        },
+        negative: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed("("),
+            infix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed(")"),
+            currency_first: true,
+        },
+    },
+    spacing_before_number: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    spacing_after_number: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 85u8, 68u8, 66u8, 82u8, 76u8, 67u8, 65u8, 68u8, 67u8, 78u8, 89u8, 69u8,
+                    85u8, 82u8, 71u8, 66u8, 80u8, 73u8, 78u8, 82u8, 74u8, 80u8, 89u8, 75u8, 82u8,
+                    87u8, 77u8, 88u8, 78u8, 80u8, 72u8, 80u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    12u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 4u8, 0u8, 7u8, 0u8, 11u8, 0u8, 14u8,
+                    0u8, 16u8, 0u8, 19u8, 0u8, 23u8, 0u8, 26u8, 0u8, 29u8, 0u8, 32u8, 0u8, 65u8,
+                    36u8, 82u8, 36u8, 67u8, 65u8, 36u8, 67u8, 78u8, 194u8, 165u8, 226u8, 130u8,
+                    172u8, 194u8, 163u8, 226u8, 130u8, 185u8, 74u8, 80u8, 194u8, 165u8, 226u8,
+                    130u8, 169u8, 77u8, 88u8, 36u8, 226u8, 130u8, 177u8, 85u8, 83u8, 36u8,
+                ])
+            },
+        )
+    },
+    narrow_symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 67u8, 65u8, 68u8, 67u8,
+                    76u8, 80u8, 67u8, 78u8, 89u8, 68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 74u8, 80u8,
+                    89u8, 77u8, 88u8, 78u8, 82u8, 85u8, 66u8, 83u8, 69u8, 75u8, 84u8, 72u8, 66u8,
+                    84u8, 82u8, 89u8, 85u8, 83u8, 68u8, 90u8, 65u8, 82u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    16u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 5u8, 0u8, 6u8, 0u8, 7u8,
+                    0u8, 9u8, 0u8, 11u8, 0u8, 14u8, 0u8, 16u8, 0u8, 17u8, 0u8, 20u8, 0u8, 22u8,
+                    0u8, 25u8, 0u8, 28u8, 0u8, 29u8, 0u8, 36u8, 36u8, 224u8, 167u8, 179u8, 36u8,
+                    36u8, 194u8, 165u8, 107u8, 114u8, 69u8, 194u8, 163u8, 194u8, 165u8, 36u8,
+                    226u8, 130u8, 189u8, 107u8, 114u8, 224u8, 184u8, 191u8, 226u8, 130u8, 186u8,
+                    36u8, 82u8,
+                ])
+            },
+        )
+    },
+};
+static EN_ZA: &DataStruct = &::icu_currency::provider::CurrencyEssentialsV1 {
+    standard: ::icu_currency::provider::CurrencyPatterns {
+        positive: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: true,
+        },
+        negative: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed("-"),
+            infix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: true,
+        },
+    },
+    accounting: ::icu_currency::provider::CurrencyPatterns {
+        positive: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: true,
+        },
+        negative: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed("("),
+            infix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed(")"),
+            currency_first: true,
+        },
+    },
+    spacing_before_number: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    spacing_after_number: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 85u8, 68u8, 66u8, 82u8, 76u8, 67u8, 65u8, 68u8, 67u8, 78u8, 89u8, 69u8,
+                    85u8, 82u8, 71u8, 66u8, 80u8, 73u8, 78u8, 82u8, 74u8, 80u8, 89u8, 75u8, 82u8,
+                    87u8, 77u8, 88u8, 78u8, 80u8, 72u8, 80u8, 85u8, 83u8, 68u8, 90u8, 65u8, 82u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 4u8, 0u8, 7u8, 0u8, 11u8, 0u8, 14u8,
+                    0u8, 16u8, 0u8, 19u8, 0u8, 23u8, 0u8, 26u8, 0u8, 29u8, 0u8, 32u8, 0u8, 35u8,
+                    0u8, 65u8, 36u8, 82u8, 36u8, 67u8, 65u8, 36u8, 67u8, 78u8, 194u8, 165u8, 226u8,
+                    130u8, 172u8, 194u8, 163u8, 226u8, 130u8, 185u8, 74u8, 80u8, 194u8, 165u8,
+                    226u8, 130u8, 169u8, 77u8, 88u8, 36u8, 226u8, 130u8, 177u8, 85u8, 83u8, 36u8,
+                    82u8,
+                ])
+            },
+        )
+    },
+    narrow_symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 67u8, 65u8, 68u8, 67u8,
+                    76u8, 80u8, 67u8, 78u8, 89u8, 68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 74u8, 80u8,
+                    89u8, 77u8, 88u8, 78u8, 82u8, 85u8, 66u8, 83u8, 69u8, 75u8, 84u8, 72u8, 66u8,
+                    84u8, 82u8, 89u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 5u8, 0u8, 6u8, 0u8, 7u8,
+                    0u8, 9u8, 0u8, 11u8, 0u8, 14u8, 0u8, 16u8, 0u8, 17u8, 0u8, 20u8, 0u8, 22u8,
+                    0u8, 25u8, 0u8, 28u8, 0u8, 36u8, 36u8, 224u8, 167u8, 179u8, 36u8, 36u8, 194u8,
+                    165u8, 107u8, 114u8, 69u8, 194u8, 163u8, 194u8, 165u8, 36u8, 226u8, 130u8,
+                    189u8, 107u8, 114u8, 224u8, 184u8, 191u8, 226u8, 130u8, 186u8, 36u8,
+                ])
+            },
+        )
+    },
+};
+static ES: &DataStruct = &::icu_currency::provider::CurrencyEssentialsV1 {
+    standard: ::icu_currency::provider::CurrencyPatterns {
+        positive: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed("\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        negative: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed("-"),
+            infix: alloc::borrow::Cow::Borrowed("\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+    },
+    accounting: ::icu_currency::provider::CurrencyPatterns {
+        positive: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed("\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        negative: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed("-"),
+            infix: alloc::borrow::Cow::Borrowed("\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+    },
+    spacing_before_number: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    spacing_after_number: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    69u8, 85u8, 82u8, 84u8, 72u8, 66u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 6u8, 0u8, 226u8, 130u8, 172u8, 224u8,
+                    184u8, 191u8, 85u8, 83u8, 36u8,
+                ])
+            },
+        )
+    },
+    narrow_symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 66u8, 82u8, 76u8, 67u8,
+                    65u8, 68u8, 67u8, 76u8, 80u8, 67u8, 78u8, 89u8, 68u8, 75u8, 75u8, 71u8, 66u8,
+                    80u8, 73u8, 78u8, 82u8, 74u8, 80u8, 89u8, 75u8, 82u8, 87u8, 77u8, 88u8, 78u8,
+                    80u8, 72u8, 80u8, 82u8, 85u8, 66u8, 83u8, 69u8, 75u8, 84u8, 82u8, 89u8, 85u8,
+                    83u8, 68u8, 90u8, 65u8, 82u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    19u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 5u8, 0u8, 7u8, 0u8, 8u8,
+                    0u8, 9u8, 0u8, 11u8, 0u8, 13u8, 0u8, 15u8, 0u8, 18u8, 0u8, 20u8, 0u8, 23u8,
+                    0u8, 24u8, 0u8, 27u8, 0u8, 30u8, 0u8, 32u8, 0u8, 35u8, 0u8, 36u8, 0u8, 36u8,
+                    36u8, 224u8, 167u8, 179u8, 82u8, 36u8, 36u8, 36u8, 194u8, 165u8, 107u8, 114u8,
+                    194u8, 163u8, 226u8, 130u8, 185u8, 194u8, 165u8, 226u8, 130u8, 169u8, 36u8,
+                    226u8, 130u8, 177u8, 226u8, 130u8, 189u8, 107u8, 114u8, 226u8, 130u8, 186u8,
+                    36u8, 82u8,
+                ])
+            },
+        )
+    },
+};
+static ES_AR: &DataStruct = &::icu_currency::provider::CurrencyEssentialsV1 {
+    standard: ::icu_currency::provider::CurrencyPatterns {
+        positive: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed("\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: true,
+        },
+        negative: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed("-"),
+            infix: alloc::borrow::Cow::Borrowed("\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: true,
+        },
+    },
+    accounting: ::icu_currency::provider::CurrencyPatterns {
+        positive: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed("\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: true,
+        },
+        negative: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed("("),
+            infix: alloc::borrow::Cow::Borrowed("\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(")"),
+            currency_first: true,
+        },
+    },
+    spacing_before_number: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    spacing_after_number: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[65u8, 82u8, 83u8, 85u8, 83u8, 68u8])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 36u8, 85u8, 83u8, 36u8,
+                ])
+            },
+        )
+    },
+    narrow_symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 66u8, 82u8, 76u8, 67u8, 65u8, 68u8, 67u8,
+                    76u8, 80u8, 67u8, 78u8, 89u8, 68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 69u8, 85u8,
+                    82u8, 71u8, 66u8, 80u8, 73u8, 78u8, 82u8, 74u8, 80u8, 89u8, 75u8, 82u8, 87u8,
+                    77u8, 88u8, 78u8, 80u8, 72u8, 80u8, 82u8, 85u8, 66u8, 83u8, 69u8, 75u8, 84u8,
+                    72u8, 66u8, 84u8, 82u8, 89u8, 85u8, 83u8, 68u8, 90u8, 65u8, 82u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    21u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 4u8, 0u8, 6u8, 0u8, 7u8, 0u8, 8u8,
+                    0u8, 10u8, 0u8, 12u8, 0u8, 15u8, 0u8, 18u8, 0u8, 20u8, 0u8, 23u8, 0u8, 25u8,
+                    0u8, 28u8, 0u8, 29u8, 0u8, 32u8, 0u8, 35u8, 0u8, 37u8, 0u8, 40u8, 0u8, 43u8,
+                    0u8, 44u8, 0u8, 36u8, 224u8, 167u8, 179u8, 82u8, 36u8, 36u8, 36u8, 194u8,
+                    165u8, 107u8, 114u8, 69u8, 194u8, 163u8, 226u8, 130u8, 172u8, 194u8, 163u8,
+                    226u8, 130u8, 185u8, 194u8, 165u8, 226u8, 130u8, 169u8, 36u8, 226u8, 130u8,
+                    177u8, 226u8, 130u8, 189u8, 107u8, 114u8, 224u8, 184u8, 191u8, 226u8, 130u8,
+                    186u8, 36u8, 82u8,
+                ])
+            },
+        )
+    },
+};
+static FIL: &DataStruct = &::icu_currency::provider::CurrencyEssentialsV1 {
+    standard: ::icu_currency::provider::CurrencyPatterns {
+        positive: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: true,
+        },
+        negative: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed("-"),
+            infix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: true,
+        },
+    },
+    accounting: ::icu_currency::provider::CurrencyPatterns {
+        positive: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: true,
+        },
+        negative: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed("("),
+            infix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed(")"),
+            currency_first: true,
+        },
+    },
+    spacing_before_number: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    spacing_after_number: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 85u8, 68u8, 66u8, 82u8, 76u8, 67u8, 65u8, 68u8, 67u8, 78u8, 89u8, 69u8,
+                    85u8, 82u8, 71u8, 66u8, 80u8, 73u8, 78u8, 82u8, 74u8, 80u8, 89u8, 75u8, 82u8,
+                    87u8, 77u8, 88u8, 78u8, 80u8, 72u8, 80u8, 84u8, 72u8, 66u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 4u8, 0u8, 7u8, 0u8, 11u8, 0u8, 14u8,
+                    0u8, 16u8, 0u8, 19u8, 0u8, 21u8, 0u8, 24u8, 0u8, 27u8, 0u8, 30u8, 0u8, 33u8,
+                    0u8, 65u8, 36u8, 82u8, 36u8, 67u8, 65u8, 36u8, 67u8, 78u8, 194u8, 165u8, 226u8,
+                    130u8, 172u8, 194u8, 163u8, 226u8, 130u8, 185u8, 194u8, 165u8, 226u8, 130u8,
+                    169u8, 77u8, 88u8, 36u8, 226u8, 130u8, 177u8, 224u8, 184u8, 191u8, 36u8,
+                ])
+            },
+        )
+    },
+    narrow_symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 67u8, 65u8, 68u8, 67u8,
+                    76u8, 80u8, 67u8, 78u8, 89u8, 68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 77u8, 88u8,
+                    78u8, 82u8, 85u8, 66u8, 83u8, 69u8, 75u8, 84u8, 82u8, 89u8, 90u8, 65u8, 82u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 5u8, 0u8, 6u8, 0u8, 7u8,
+                    0u8, 9u8, 0u8, 11u8, 0u8, 14u8, 0u8, 15u8, 0u8, 18u8, 0u8, 20u8, 0u8, 23u8,
+                    0u8, 36u8, 36u8, 224u8, 167u8, 179u8, 36u8, 36u8, 194u8, 165u8, 107u8, 114u8,
+                    69u8, 194u8, 163u8, 36u8, 226u8, 130u8, 189u8, 107u8, 114u8, 226u8, 130u8,
+                    186u8, 82u8,
+                ])
+            },
+        )
+    },
+};
+static FR: &DataStruct = &::icu_currency::provider::CurrencyEssentialsV1 {
+    standard: ::icu_currency::provider::CurrencyPatterns {
+        positive: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed("\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        negative: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed("-"),
+            infix: alloc::borrow::Cow::Borrowed("\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+    },
+    accounting: ::icu_currency::provider::CurrencyPatterns {
+        positive: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed("\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        negative: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed("("),
+            infix: alloc::borrow::Cow::Borrowed("\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(")"),
+            currency_first: false,
+        },
+    },
+    spacing_before_number: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    spacing_after_number: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 82u8, 76u8, 67u8, 65u8, 68u8, 67u8,
+                    76u8, 80u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8, 73u8, 78u8, 82u8, 75u8, 82u8,
+                    87u8, 77u8, 88u8, 78u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 6u8, 0u8, 8u8, 0u8, 11u8, 0u8, 14u8,
+                    0u8, 17u8, 0u8, 21u8, 0u8, 24u8, 0u8, 27u8, 0u8, 30u8, 0u8, 36u8, 65u8, 82u8,
+                    36u8, 65u8, 85u8, 82u8, 36u8, 36u8, 67u8, 65u8, 36u8, 67u8, 76u8, 226u8, 130u8,
+                    172u8, 194u8, 163u8, 71u8, 66u8, 226u8, 130u8, 185u8, 226u8, 130u8, 169u8,
+                    36u8, 77u8, 88u8, 36u8, 85u8, 83u8,
+                ])
+            },
+        )
+    },
+    narrow_symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 67u8, 65u8, 68u8, 67u8,
+                    76u8, 80u8, 67u8, 78u8, 89u8, 68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 71u8, 66u8,
+                    80u8, 74u8, 80u8, 89u8, 77u8, 88u8, 78u8, 80u8, 72u8, 80u8, 82u8, 85u8, 66u8,
+                    83u8, 69u8, 75u8, 84u8, 72u8, 66u8, 84u8, 82u8, 89u8, 85u8, 83u8, 68u8, 90u8,
+                    65u8, 82u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    18u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 5u8, 0u8, 6u8, 0u8, 7u8,
+                    0u8, 9u8, 0u8, 11u8, 0u8, 14u8, 0u8, 16u8, 0u8, 18u8, 0u8, 19u8, 0u8, 22u8,
+                    0u8, 25u8, 0u8, 27u8, 0u8, 30u8, 0u8, 33u8, 0u8, 34u8, 0u8, 36u8, 36u8, 224u8,
+                    167u8, 179u8, 36u8, 36u8, 194u8, 165u8, 107u8, 114u8, 194u8, 163u8, 69u8,
+                    194u8, 163u8, 194u8, 165u8, 36u8, 226u8, 130u8, 177u8, 226u8, 130u8, 189u8,
+                    107u8, 114u8, 224u8, 184u8, 191u8, 226u8, 130u8, 186u8, 36u8, 82u8,
+                ])
+            },
+        )
+    },
+};
+static JA: &DataStruct = &::icu_currency::provider::CurrencyEssentialsV1 {
+    standard: ::icu_currency::provider::CurrencyPatterns {
+        positive: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: true,
+        },
+        negative: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed("-"),
+            infix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: true,
+        },
+    },
+    accounting: ::icu_currency::provider::CurrencyPatterns {
+        positive: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: true,
+        },
+        negative: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed("("),
+            infix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed(")"),
+            currency_first: true,
+        },
+    },
+    spacing_before_number: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    spacing_after_number: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 85u8, 68u8, 66u8, 82u8, 76u8, 67u8, 65u8, 68u8, 67u8, 78u8, 89u8, 69u8,
+                    85u8, 82u8, 71u8, 66u8, 80u8, 73u8, 78u8, 82u8, 74u8, 80u8, 89u8, 75u8, 82u8,
+                    87u8, 77u8, 88u8, 78u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 4u8, 0u8, 7u8, 0u8, 10u8, 0u8, 13u8,
+                    0u8, 15u8, 0u8, 18u8, 0u8, 21u8, 0u8, 24u8, 0u8, 27u8, 0u8, 65u8, 36u8, 82u8,
+                    36u8, 67u8, 65u8, 36u8, 229u8, 133u8, 131u8, 226u8, 130u8, 172u8, 194u8, 163u8,
+                    226u8, 130u8, 185u8, 239u8, 191u8, 165u8, 226u8, 130u8, 169u8, 77u8, 88u8,
+                    36u8, 36u8,
+                ])
+            },
+        )
+    },
+    narrow_symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 67u8, 65u8, 68u8, 67u8,
+                    76u8, 80u8, 67u8, 78u8, 89u8, 68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 77u8, 88u8,
+                    78u8, 80u8, 72u8, 80u8, 82u8, 85u8, 66u8, 83u8, 69u8, 75u8, 84u8, 72u8, 66u8,
+                    84u8, 82u8, 89u8, 90u8, 65u8, 82u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 5u8, 0u8, 6u8, 0u8, 7u8,
+                    0u8, 10u8, 0u8, 12u8, 0u8, 15u8, 0u8, 16u8, 0u8, 19u8, 0u8, 22u8, 0u8, 24u8,
+                    0u8, 27u8, 0u8, 30u8, 0u8, 36u8, 36u8, 224u8, 167u8, 179u8, 36u8, 36u8, 239u8,
+                    191u8, 165u8, 107u8, 114u8, 69u8, 194u8, 163u8, 36u8, 226u8, 130u8, 177u8,
+                    226u8, 130u8, 189u8, 107u8, 114u8, 224u8, 184u8, 191u8, 226u8, 130u8, 186u8,
+                    82u8,
+                ])
+            },
+        )
+    },
+};
+static RU: &DataStruct = &::icu_currency::provider::CurrencyEssentialsV1 {
+    standard: ::icu_currency::provider::CurrencyPatterns {
+        positive: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed("\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        negative: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed("-"),
+            infix: alloc::borrow::Cow::Borrowed("\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+    },
+    accounting: ::icu_currency::provider::CurrencyPatterns {
+        positive: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed("\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        negative: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed("-"),
+            infix: alloc::borrow::Cow::Borrowed("\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+    },
+    spacing_before_number: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    spacing_after_number: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 85u8, 68u8, 66u8, 82u8, 76u8, 67u8, 65u8, 68u8, 67u8, 78u8, 89u8, 69u8,
+                    85u8, 82u8, 71u8, 66u8, 80u8, 73u8, 78u8, 82u8, 74u8, 80u8, 89u8, 75u8, 82u8,
+                    87u8, 77u8, 88u8, 78u8, 82u8, 85u8, 66u8, 84u8, 72u8, 66u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 4u8, 0u8, 7u8, 0u8, 11u8, 0u8, 14u8,
+                    0u8, 16u8, 0u8, 19u8, 0u8, 21u8, 0u8, 24u8, 0u8, 27u8, 0u8, 30u8, 0u8, 33u8,
+                    0u8, 65u8, 36u8, 82u8, 36u8, 67u8, 65u8, 36u8, 67u8, 78u8, 194u8, 165u8, 226u8,
+                    130u8, 172u8, 194u8, 163u8, 226u8, 130u8, 185u8, 194u8, 165u8, 226u8, 130u8,
+                    169u8, 77u8, 88u8, 36u8, 226u8, 130u8, 189u8, 224u8, 184u8, 191u8, 36u8,
+                ])
+            },
+        )
+    },
+    narrow_symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 67u8, 65u8, 68u8, 67u8,
+                    76u8, 80u8, 67u8, 78u8, 89u8, 68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 77u8, 88u8,
+                    78u8, 80u8, 72u8, 80u8, 83u8, 69u8, 75u8, 84u8, 82u8, 89u8, 90u8, 65u8, 82u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 5u8, 0u8, 6u8, 0u8, 7u8,
+                    0u8, 9u8, 0u8, 11u8, 0u8, 14u8, 0u8, 15u8, 0u8, 18u8, 0u8, 20u8, 0u8, 23u8,
+                    0u8, 36u8, 36u8, 224u8, 167u8, 179u8, 36u8, 36u8, 194u8, 165u8, 107u8, 114u8,
+                    69u8, 194u8, 163u8, 36u8, 226u8, 130u8, 177u8, 107u8, 114u8, 226u8, 130u8,
+                    186u8, 82u8,
+                ])
+            },
+        )
+    },
+};
+static SR_SR_CYRL_SR_LATN: &DataStruct = &::icu_currency::provider::CurrencyEssentialsV1 {
+    standard: ::icu_currency::provider::CurrencyPatterns {
+        positive: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed("\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        negative: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed("-"),
+            infix: alloc::borrow::Cow::Borrowed("\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+    },
+    accounting: ::icu_currency::provider::CurrencyPatterns {
+        positive: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed("\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        negative: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed("("),
+            infix: alloc::borrow::Cow::Borrowed("\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(")"),
+            currency_first: false,
+        },
+    },
+    spacing_before_number: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    spacing_after_number: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    66u8, 82u8, 76u8, 67u8, 65u8, 68u8, 67u8, 78u8, 89u8, 69u8, 85u8, 82u8, 71u8,
+                    66u8, 80u8, 73u8, 78u8, 82u8, 74u8, 80u8, 89u8, 77u8, 88u8, 78u8, 85u8, 83u8,
+                    68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    9u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 5u8, 0u8, 9u8, 0u8, 12u8, 0u8, 14u8,
+                    0u8, 17u8, 0u8, 19u8, 0u8, 22u8, 0u8, 82u8, 36u8, 67u8, 65u8, 36u8, 67u8, 78u8,
+                    194u8, 165u8, 226u8, 130u8, 172u8, 194u8, 163u8, 226u8, 130u8, 185u8, 194u8,
+                    165u8, 77u8, 88u8, 36u8, 85u8, 83u8, 36u8,
+                ])
+            },
+        )
+    },
+    narrow_symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 67u8, 65u8, 68u8, 67u8,
+                    76u8, 80u8, 67u8, 78u8, 89u8, 68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 75u8, 82u8,
+                    87u8, 77u8, 88u8, 78u8, 80u8, 72u8, 80u8, 82u8, 85u8, 66u8, 83u8, 69u8, 75u8,
+                    84u8, 72u8, 66u8, 84u8, 82u8, 89u8, 85u8, 83u8, 68u8, 90u8, 65u8, 82u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    17u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 5u8, 0u8, 6u8, 0u8, 7u8,
+                    0u8, 9u8, 0u8, 11u8, 0u8, 14u8, 0u8, 17u8, 0u8, 18u8, 0u8, 21u8, 0u8, 24u8,
+                    0u8, 26u8, 0u8, 29u8, 0u8, 32u8, 0u8, 33u8, 0u8, 36u8, 36u8, 224u8, 167u8,
+                    179u8, 36u8, 36u8, 194u8, 165u8, 107u8, 114u8, 69u8, 194u8, 163u8, 226u8,
+                    130u8, 169u8, 36u8, 226u8, 130u8, 177u8, 226u8, 130u8, 189u8, 107u8, 114u8,
+                    224u8, 184u8, 191u8, 226u8, 130u8, 186u8, 36u8, 82u8,
+                ])
+            },
+        )
+    },
+};
+static TH_TH_U_NU_THAI: &DataStruct = &::icu_currency::provider::CurrencyEssentialsV1 {
+    standard: ::icu_currency::provider::CurrencyPatterns {
+        positive: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: true,
+        },
+        negative: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed("-"),
+            infix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: true,
+        },
+    },
+    accounting: ::icu_currency::provider::CurrencyPatterns {
+        positive: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: true,
+        },
+        negative: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed("("),
+            infix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed(")"),
+            currency_first: true,
+        },
+    },
+    spacing_before_number: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    spacing_after_number: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 85u8, 68u8, 66u8, 82u8, 76u8, 67u8, 65u8, 68u8, 67u8, 78u8, 89u8, 69u8,
+                    85u8, 82u8, 71u8, 66u8, 80u8, 73u8, 78u8, 82u8, 74u8, 80u8, 89u8, 75u8, 82u8,
+                    87u8, 77u8, 88u8, 78u8, 84u8, 72u8, 66u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    12u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 5u8, 0u8, 8u8, 0u8, 12u8, 0u8, 15u8,
+                    0u8, 17u8, 0u8, 20u8, 0u8, 22u8, 0u8, 25u8, 0u8, 28u8, 0u8, 31u8, 0u8, 65u8,
+                    85u8, 36u8, 82u8, 36u8, 67u8, 65u8, 36u8, 67u8, 78u8, 194u8, 165u8, 226u8,
+                    130u8, 172u8, 194u8, 163u8, 226u8, 130u8, 185u8, 194u8, 165u8, 226u8, 130u8,
+                    169u8, 77u8, 88u8, 36u8, 224u8, 184u8, 191u8, 85u8, 83u8, 36u8,
+                ])
+            },
+        )
+    },
+    narrow_symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 67u8, 65u8, 68u8, 67u8,
+                    76u8, 80u8, 67u8, 78u8, 89u8, 68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 77u8, 88u8,
+                    78u8, 80u8, 72u8, 80u8, 82u8, 85u8, 66u8, 83u8, 69u8, 75u8, 84u8, 82u8, 89u8,
+                    85u8, 83u8, 68u8, 90u8, 65u8, 82u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 5u8, 0u8, 6u8, 0u8, 7u8,
+                    0u8, 9u8, 0u8, 11u8, 0u8, 14u8, 0u8, 15u8, 0u8, 18u8, 0u8, 21u8, 0u8, 23u8,
+                    0u8, 26u8, 0u8, 27u8, 0u8, 36u8, 36u8, 224u8, 167u8, 179u8, 36u8, 36u8, 194u8,
+                    165u8, 107u8, 114u8, 69u8, 194u8, 163u8, 36u8, 226u8, 130u8, 177u8, 226u8,
+                    130u8, 189u8, 107u8, 114u8, 226u8, 130u8, 186u8, 36u8, 82u8,
+                ])
+            },
+        )
+    },
+};
+static TR: &DataStruct = &::icu_currency::provider::CurrencyEssentialsV1 {
+    standard: ::icu_currency::provider::CurrencyPatterns {
+        positive: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: true,
+        },
+        negative: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed("-"),
+            infix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: true,
+        },
+    },
+    accounting: ::icu_currency::provider::CurrencyPatterns {
+        positive: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: true,
+        },
+        negative: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed("("),
+            infix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed(")"),
+            currency_first: true,
+        },
+    },
+    spacing_before_number: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    spacing_after_number: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 85u8, 68u8, 66u8, 82u8, 76u8, 67u8, 65u8, 68u8, 67u8, 78u8, 89u8, 69u8,
+                    85u8, 82u8, 71u8, 66u8, 80u8, 73u8, 78u8, 82u8, 74u8, 80u8, 89u8, 75u8, 82u8,
+                    87u8, 77u8, 88u8, 78u8, 84u8, 72u8, 66u8, 84u8, 82u8, 89u8, 85u8, 83u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 5u8, 0u8, 8u8, 0u8, 12u8, 0u8, 15u8,
+                    0u8, 17u8, 0u8, 20u8, 0u8, 22u8, 0u8, 25u8, 0u8, 28u8, 0u8, 31u8, 0u8, 34u8,
+                    0u8, 65u8, 85u8, 36u8, 82u8, 36u8, 67u8, 65u8, 36u8, 67u8, 78u8, 194u8, 165u8,
+                    226u8, 130u8, 172u8, 194u8, 163u8, 226u8, 130u8, 185u8, 194u8, 165u8, 226u8,
+                    130u8, 169u8, 77u8, 88u8, 36u8, 224u8, 184u8, 191u8, 226u8, 130u8, 186u8, 36u8,
+                ])
+            },
+        )
+    },
+    narrow_symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 67u8, 65u8, 68u8, 67u8,
+                    76u8, 80u8, 67u8, 78u8, 89u8, 68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 77u8, 88u8,
+                    78u8, 80u8, 72u8, 80u8, 82u8, 85u8, 66u8, 83u8, 69u8, 75u8, 90u8, 65u8, 82u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 5u8, 0u8, 6u8, 0u8, 7u8,
+                    0u8, 9u8, 0u8, 11u8, 0u8, 14u8, 0u8, 15u8, 0u8, 18u8, 0u8, 21u8, 0u8, 23u8,
+                    0u8, 36u8, 36u8, 224u8, 167u8, 179u8, 36u8, 36u8, 194u8, 165u8, 107u8, 114u8,
+                    69u8, 194u8, 163u8, 36u8, 226u8, 130u8, 177u8, 226u8, 130u8, 189u8, 107u8,
+                    114u8, 82u8,
+                ])
+            },
+        )
+    },
+};
+static UND: &DataStruct = &::icu_currency::provider::CurrencyEssentialsV1 {
+    standard: ::icu_currency::provider::CurrencyPatterns {
+        positive: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed("\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: true,
+        },
+        negative: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed("-"),
+            infix: alloc::borrow::Cow::Borrowed("\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: true,
+        },
+    },
+    accounting: ::icu_currency::provider::CurrencyPatterns {
+        positive: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed("\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: true,
+        },
+        negative: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed("-"),
+            infix: alloc::borrow::Cow::Borrowed("\u{a0}"),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: true,
+        },
+    },
+    spacing_before_number: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    spacing_after_number: alloc::borrow::Cow::Borrowed("\u{a0}"),
+    symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    narrow_symbols: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+};
@@ -0,0 +1,2594 @@
+// @generated
+#![cfg(feature = "icu_currency")]
+type DataStruct =
+    <::icu_currency::provider::CurrencyNamesV1Marker as ::icu_provider::DataMarker>::Yokeable;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[
+        ("ar", AR_AR_EG),
+        ("ar-EG", AR_AR_EG),
+        ("bn", BN),
+        ("ccp", CCP),
+        ("en", EN),
+        ("en-001", EN_001_EN_ZA),
+        ("en-ZA", EN_001_EN_ZA),
+        ("es", ES),
+        ("es-AR", ES_AR),
+        ("fil", FIL),
+        ("fr", FR),
+        ("ja", JA),
+        ("ru", RU),
+        ("sr", SR_SR_CYRL),
+        ("sr-Cyrl", SR_SR_CYRL),
+        ("sr-Latn", SR_LATN),
+        ("th", TH),
+        ("tr", TR),
+        ("und", UND),
+    ]);
+static AR_AR_EG: &DataStruct = &::icu_currency::provider::CurrencyNamesV1 {
+    zero: Some(::icu_currency::provider::CurrencyPluralNames {
+        pattern: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(" "),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+    }),
+    one: Some(::icu_currency::provider::CurrencyPluralNames {
+        pattern: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(" "),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+    }),
+    two: Some(::icu_currency::provider::CurrencyPluralNames {
+        pattern: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(" "),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[69u8, 71u8, 80u8, 82u8, 83u8, 68u8])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 25u8, 0u8, 216u8, 172u8, 217u8, 134u8, 217u8,
+                        138u8, 217u8, 135u8, 216u8, 167u8, 217u8, 134u8, 32u8, 217u8, 133u8, 216u8,
+                        181u8, 216u8, 177u8, 217u8, 138u8, 216u8, 167u8, 217u8, 134u8, 216u8,
+                        175u8, 217u8, 138u8, 217u8, 134u8, 216u8, 167u8, 216u8, 177u8, 216u8,
+                        167u8, 217u8, 134u8, 32u8, 216u8, 181u8, 216u8, 177u8, 216u8, 168u8, 217u8,
+                        138u8, 216u8, 167u8, 217u8, 134u8,
+                    ])
+                },
+            )
+        },
+    }),
+    few: Some(::icu_currency::provider::CurrencyPluralNames {
+        pattern: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(" "),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[69u8, 71u8, 80u8, 82u8, 83u8, 68u8])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 23u8, 0u8, 216u8, 172u8, 217u8, 134u8, 217u8,
+                        138u8, 217u8, 135u8, 216u8, 167u8, 216u8, 170u8, 32u8, 217u8, 133u8, 216u8,
+                        181u8, 216u8, 177u8, 217u8, 138u8, 216u8, 169u8, 216u8, 175u8, 217u8,
+                        138u8, 217u8, 134u8, 216u8, 167u8, 216u8, 177u8, 216u8, 167u8, 216u8,
+                        170u8, 32u8, 216u8, 181u8, 216u8, 177u8, 216u8, 168u8, 217u8, 138u8, 216u8,
+                        169u8,
+                    ])
+                },
+            )
+        },
+    }),
+    many: Some(::icu_currency::provider::CurrencyPluralNames {
+        pattern: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(" "),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[69u8, 71u8, 80u8, 82u8, 83u8, 68u8])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 25u8, 0u8, 216u8, 172u8, 217u8, 134u8, 217u8,
+                        138u8, 217u8, 135u8, 217u8, 139u8, 216u8, 167u8, 32u8, 217u8, 133u8, 216u8,
+                        181u8, 216u8, 177u8, 217u8, 138u8, 217u8, 139u8, 216u8, 167u8, 216u8,
+                        175u8, 217u8, 138u8, 217u8, 134u8, 216u8, 167u8, 216u8, 177u8, 217u8,
+                        139u8, 216u8, 167u8, 32u8, 216u8, 181u8, 216u8, 177u8, 216u8, 168u8, 217u8,
+                        138u8, 217u8, 139u8, 216u8, 167u8,
+                    ])
+                },
+            )
+        },
+    }),
+    other: ::icu_currency::provider::CurrencyPluralNames {
+        pattern: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(" "),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 66u8, 82u8, 76u8,
+                        67u8, 65u8, 68u8, 67u8, 72u8, 70u8, 67u8, 76u8, 80u8, 67u8, 78u8, 89u8,
+                        68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8,
+                        73u8, 78u8, 82u8, 74u8, 80u8, 89u8, 75u8, 82u8, 87u8, 75u8, 87u8, 68u8,
+                        77u8, 88u8, 78u8, 80u8, 72u8, 80u8, 82u8, 83u8, 68u8, 82u8, 85u8, 66u8,
+                        83u8, 69u8, 75u8, 84u8, 72u8, 66u8, 84u8, 82u8, 89u8, 85u8, 83u8, 68u8,
+                        90u8, 65u8, 82u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        25u8, 0u8, 0u8, 0u8, 0u8, 0u8, 25u8, 0u8, 50u8, 0u8, 77u8, 0u8, 100u8, 0u8,
+                        119u8, 0u8, 140u8, 0u8, 159u8, 0u8, 176u8, 0u8, 203u8, 0u8, 220u8, 0u8,
+                        228u8, 0u8, 253u8, 0u8, 16u8, 1u8, 33u8, 1u8, 67u8, 1u8, 88u8, 1u8, 109u8,
+                        1u8, 130u8, 1u8, 149u8, 1u8, 166u8, 1u8, 189u8, 1u8, 214u8, 1u8, 233u8,
+                        1u8, 0u8, 2u8, 216u8, 168u8, 217u8, 138u8, 216u8, 178u8, 217u8, 136u8,
+                        32u8, 216u8, 163u8, 216u8, 177u8, 216u8, 172u8, 217u8, 134u8, 216u8, 170u8,
+                        217u8, 138u8, 217u8, 134u8, 217u8, 138u8, 216u8, 175u8, 217u8, 136u8,
+                        217u8, 132u8, 216u8, 167u8, 216u8, 177u8, 32u8, 216u8, 163u8, 216u8, 179u8,
+                        216u8, 170u8, 216u8, 177u8, 216u8, 167u8, 217u8, 132u8, 217u8, 138u8,
+                        216u8, 170u8, 216u8, 167u8, 217u8, 131u8, 216u8, 167u8, 32u8, 216u8, 168u8,
+                        217u8, 134u8, 216u8, 186u8, 217u8, 132u8, 216u8, 167u8, 216u8, 175u8,
+                        217u8, 138u8, 216u8, 180u8, 217u8, 138u8, 216u8, 177u8, 217u8, 138u8,
+                        216u8, 167u8, 217u8, 132u8, 32u8, 216u8, 168u8, 216u8, 177u8, 216u8, 167u8,
+                        216u8, 178u8, 217u8, 138u8, 217u8, 132u8, 217u8, 138u8, 216u8, 175u8,
+                        217u8, 136u8, 217u8, 132u8, 216u8, 167u8, 216u8, 177u8, 32u8, 217u8, 131u8,
+                        217u8, 134u8, 216u8, 175u8, 217u8, 138u8, 217u8, 129u8, 216u8, 177u8,
+                        217u8, 134u8, 217u8, 131u8, 32u8, 216u8, 179u8, 217u8, 136u8, 217u8, 138u8,
+                        216u8, 179u8, 216u8, 177u8, 217u8, 138u8, 216u8, 168u8, 217u8, 138u8,
+                        216u8, 178u8, 217u8, 136u8, 32u8, 216u8, 170u8, 216u8, 180u8, 217u8, 138u8,
+                        217u8, 132u8, 217u8, 138u8, 217u8, 138u8, 217u8, 136u8, 216u8, 167u8,
+                        217u8, 134u8, 32u8, 216u8, 181u8, 217u8, 138u8, 217u8, 134u8, 217u8, 138u8,
+                        217u8, 131u8, 216u8, 177u8, 217u8, 136u8, 217u8, 134u8, 216u8, 169u8, 32u8,
+                        216u8, 175u8, 217u8, 134u8, 217u8, 133u8, 216u8, 167u8, 216u8, 177u8,
+                        217u8, 131u8, 217u8, 138u8, 216u8, 169u8, 216u8, 172u8, 217u8, 134u8,
+                        217u8, 138u8, 217u8, 135u8, 32u8, 217u8, 133u8, 216u8, 181u8, 216u8, 177u8,
+                        217u8, 138u8, 217u8, 138u8, 217u8, 136u8, 216u8, 177u8, 217u8, 136u8,
+                        216u8, 172u8, 217u8, 134u8, 217u8, 138u8, 217u8, 135u8, 32u8, 216u8, 165u8,
+                        216u8, 179u8, 216u8, 170u8, 216u8, 177u8, 217u8, 132u8, 217u8, 138u8,
+                        217u8, 134u8, 217u8, 138u8, 216u8, 177u8, 217u8, 136u8, 216u8, 168u8,
+                        217u8, 138u8, 216u8, 169u8, 32u8, 217u8, 135u8, 217u8, 134u8, 216u8, 175u8,
+                        217u8, 138u8, 217u8, 138u8, 217u8, 134u8, 32u8, 217u8, 138u8, 216u8, 167u8,
+                        216u8, 168u8, 216u8, 167u8, 217u8, 134u8, 217u8, 138u8, 217u8, 136u8,
+                        217u8, 136u8, 217u8, 134u8, 32u8, 217u8, 131u8, 217u8, 136u8, 216u8, 177u8,
+                        217u8, 138u8, 216u8, 167u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 172u8,
+                        217u8, 134u8, 217u8, 136u8, 216u8, 168u8, 217u8, 138u8, 216u8, 169u8,
+                        216u8, 175u8, 217u8, 138u8, 217u8, 134u8, 216u8, 167u8, 216u8, 177u8, 32u8,
+                        217u8, 131u8, 217u8, 136u8, 217u8, 138u8, 216u8, 170u8, 217u8, 138u8,
+                        216u8, 168u8, 217u8, 138u8, 216u8, 178u8, 217u8, 136u8, 32u8, 217u8, 133u8,
+                        217u8, 131u8, 216u8, 179u8, 217u8, 138u8, 217u8, 131u8, 217u8, 138u8,
+                        216u8, 168u8, 217u8, 138u8, 216u8, 178u8, 217u8, 136u8, 32u8, 217u8, 129u8,
+                        217u8, 132u8, 216u8, 168u8, 217u8, 138u8, 217u8, 134u8, 217u8, 138u8,
+                        216u8, 175u8, 217u8, 138u8, 217u8, 134u8, 216u8, 167u8, 216u8, 177u8, 32u8,
+                        216u8, 181u8, 216u8, 177u8, 216u8, 168u8, 217u8, 138u8, 216u8, 177u8,
+                        217u8, 136u8, 216u8, 168u8, 217u8, 132u8, 32u8, 216u8, 177u8, 217u8, 136u8,
+                        216u8, 179u8, 217u8, 138u8, 217u8, 131u8, 216u8, 177u8, 217u8, 136u8,
+                        217u8, 134u8, 216u8, 169u8, 32u8, 216u8, 179u8, 217u8, 136u8, 217u8, 138u8,
+                        216u8, 175u8, 217u8, 138u8, 216u8, 169u8, 216u8, 168u8, 216u8, 167u8,
+                        216u8, 174u8, 216u8, 170u8, 32u8, 216u8, 170u8, 216u8, 167u8, 217u8, 138u8,
+                        217u8, 132u8, 216u8, 167u8, 217u8, 134u8, 216u8, 175u8, 217u8, 138u8,
+                        217u8, 132u8, 217u8, 138u8, 216u8, 177u8, 216u8, 169u8, 32u8, 216u8, 170u8,
+                        216u8, 177u8, 217u8, 131u8, 217u8, 138u8, 216u8, 169u8, 216u8, 175u8,
+                        217u8, 136u8, 217u8, 132u8, 216u8, 167u8, 216u8, 177u8, 32u8, 216u8, 163u8,
+                        217u8, 133u8, 216u8, 177u8, 217u8, 138u8, 217u8, 131u8, 217u8, 138u8,
+                        216u8, 177u8, 216u8, 167u8, 217u8, 134u8, 216u8, 175u8, 32u8, 216u8, 172u8,
+                        217u8, 134u8, 217u8, 136u8, 216u8, 168u8, 32u8, 216u8, 163u8, 217u8, 129u8,
+                        216u8, 177u8, 217u8, 138u8, 217u8, 130u8, 217u8, 138u8, 216u8, 167u8,
+                    ])
+                },
+            )
+        },
+    },
+};
+static BN: &DataStruct = &::icu_currency::provider::CurrencyNamesV1 {
+    zero: None,
+    one: Some(::icu_currency::provider::CurrencyPluralNames {
+        pattern: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(" "),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[68u8, 75u8, 75u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 224u8, 166u8, 161u8, 224u8, 167u8, 141u8,
+                        224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 224u8,
+                        166u8, 191u8, 224u8, 166u8, 182u8, 32u8, 224u8, 166u8, 149u8, 224u8, 167u8,
+                        141u8, 224u8, 166u8, 176u8, 224u8, 167u8, 139u8, 224u8, 166u8, 168u8,
+                    ])
+                },
+            )
+        },
+    }),
+    two: None,
+    few: None,
+    many: None,
+    other: ::icu_currency::provider::CurrencyPluralNames {
+        pattern: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(" "),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 66u8, 82u8, 76u8,
+                        67u8, 65u8, 68u8, 67u8, 72u8, 70u8, 67u8, 76u8, 80u8, 67u8, 78u8, 89u8,
+                        68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8,
+                        73u8, 78u8, 82u8, 74u8, 80u8, 89u8, 75u8, 82u8, 87u8, 75u8, 87u8, 68u8,
+                        77u8, 88u8, 78u8, 80u8, 72u8, 80u8, 82u8, 83u8, 68u8, 82u8, 85u8, 66u8,
+                        83u8, 69u8, 75u8, 84u8, 72u8, 66u8, 84u8, 82u8, 89u8, 85u8, 83u8, 68u8,
+                        90u8, 65u8, 82u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        25u8, 0u8, 0u8, 0u8, 0u8, 0u8, 46u8, 0u8, 98u8, 0u8, 138u8, 0u8, 193u8,
+                        0u8, 236u8, 0u8, 8u8, 1u8, 33u8, 1u8, 67u8, 1u8, 104u8, 1u8, 144u8, 1u8,
+                        156u8, 1u8, 196u8, 1u8, 230u8, 1u8, 8u8, 2u8, 58u8, 2u8, 95u8, 2u8, 141u8,
+                        2u8, 178u8, 2u8, 221u8, 2u8, 5u8, 3u8, 42u8, 3u8, 61u8, 3u8, 92u8, 3u8,
+                        126u8, 3u8, 224u8, 166u8, 134u8, 224u8, 166u8, 176u8, 224u8, 167u8, 141u8,
+                        224u8, 166u8, 156u8, 224u8, 167u8, 135u8, 224u8, 166u8, 168u8, 224u8,
+                        167u8, 141u8, 224u8, 166u8, 159u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                        168u8, 224u8, 166u8, 190u8, 32u8, 224u8, 166u8, 170u8, 224u8, 167u8, 135u8,
+                        224u8, 166u8, 184u8, 224u8, 167u8, 139u8, 224u8, 166u8, 133u8, 224u8,
+                        166u8, 184u8, 224u8, 167u8, 141u8, 224u8, 166u8, 159u8, 224u8, 167u8,
+                        141u8, 224u8, 166u8, 176u8, 224u8, 167u8, 135u8, 224u8, 166u8, 178u8,
+                        224u8, 166u8, 191u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 224u8,
+                        166u8, 190u8, 224u8, 166u8, 168u8, 32u8, 224u8, 166u8, 161u8, 224u8, 166u8,
+                        178u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 166u8, 172u8,
+                        224u8, 166u8, 190u8, 224u8, 166u8, 130u8, 224u8, 166u8, 178u8, 224u8,
+                        166u8, 190u8, 224u8, 166u8, 166u8, 224u8, 167u8, 135u8, 224u8, 166u8,
+                        182u8, 224u8, 167u8, 128u8, 32u8, 224u8, 166u8, 159u8, 224u8, 166u8, 190u8,
+                        224u8, 166u8, 149u8, 224u8, 166u8, 190u8, 224u8, 166u8, 172u8, 224u8,
+                        167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                        156u8, 224u8, 166u8, 191u8, 224u8, 166u8, 178u8, 224u8, 166u8, 191u8,
+                        224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 224u8, 166u8, 190u8, 224u8,
+                        166u8, 168u8, 32u8, 224u8, 166u8, 176u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                        175u8, 224u8, 166u8, 188u8, 224u8, 167u8, 135u8, 224u8, 166u8, 178u8,
+                        224u8, 166u8, 149u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 224u8,
+                        166u8, 190u8, 224u8, 166u8, 161u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                        175u8, 224u8, 166u8, 188u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 32u8,
+                        224u8, 166u8, 161u8, 224u8, 166u8, 178u8, 224u8, 166u8, 190u8, 224u8,
+                        166u8, 176u8, 224u8, 166u8, 184u8, 224u8, 167u8, 129u8, 224u8, 166u8,
+                        135u8, 224u8, 166u8, 184u8, 32u8, 224u8, 166u8, 171u8, 224u8, 167u8, 141u8,
+                        224u8, 166u8, 176u8, 224u8, 166u8, 190u8, 224u8, 166u8, 129u8, 224u8,
+                        166u8, 154u8, 224u8, 166u8, 191u8, 224u8, 166u8, 178u8, 224u8, 166u8,
+                        191u8, 32u8, 224u8, 166u8, 170u8, 224u8, 167u8, 135u8, 224u8, 166u8, 184u8,
+                        224u8, 167u8, 139u8, 224u8, 166u8, 154u8, 224u8, 167u8, 128u8, 224u8,
+                        166u8, 168u8, 224u8, 166u8, 190u8, 32u8, 224u8, 166u8, 175u8, 224u8, 166u8,
+                        188u8, 224u8, 167u8, 129u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8,
+                        224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 224u8, 166u8, 161u8, 224u8,
+                        167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                        168u8, 224u8, 166u8, 191u8, 224u8, 166u8, 182u8, 32u8, 224u8, 166u8, 149u8,
+                        224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 167u8, 140u8, 224u8,
+                        166u8, 168u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                        182u8, 224u8, 166u8, 176u8, 224u8, 167u8, 128u8, 224u8, 166u8, 175u8,
+                        224u8, 166u8, 188u8, 32u8, 224u8, 166u8, 170u8, 224u8, 166u8, 190u8, 224u8,
+                        166u8, 137u8, 224u8, 166u8, 168u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                        161u8, 224u8, 166u8, 135u8, 224u8, 166u8, 137u8, 224u8, 166u8, 176u8,
+                        224u8, 167u8, 139u8, 224u8, 166u8, 172u8, 224u8, 167u8, 141u8, 224u8,
+                        166u8, 176u8, 224u8, 166u8, 191u8, 224u8, 166u8, 159u8, 224u8, 166u8,
+                        191u8, 224u8, 166u8, 182u8, 32u8, 224u8, 166u8, 170u8, 224u8, 166u8, 190u8,
+                        224u8, 166u8, 137u8, 224u8, 166u8, 168u8, 224u8, 167u8, 141u8, 224u8,
+                        166u8, 161u8, 224u8, 166u8, 173u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                        176u8, 224u8, 166u8, 164u8, 224u8, 167u8, 128u8, 224u8, 166u8, 175u8,
+                        224u8, 166u8, 188u8, 32u8, 224u8, 166u8, 159u8, 224u8, 166u8, 190u8, 224u8,
+                        166u8, 149u8, 224u8, 166u8, 190u8, 224u8, 166u8, 156u8, 224u8, 166u8,
+                        190u8, 224u8, 166u8, 170u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8,
+                        224u8, 166u8, 191u8, 32u8, 224u8, 166u8, 135u8, 224u8, 166u8, 175u8, 224u8,
+                        166u8, 188u8, 224u8, 167u8, 135u8, 224u8, 166u8, 168u8, 224u8, 166u8,
+                        166u8, 224u8, 166u8, 149u8, 224u8, 167u8, 141u8, 224u8, 166u8, 183u8,
+                        224u8, 166u8, 191u8, 224u8, 166u8, 163u8, 32u8, 224u8, 166u8, 149u8, 224u8,
+                        167u8, 139u8, 224u8, 166u8, 176u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                        175u8, 224u8, 166u8, 188u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 32u8,
+                        224u8, 166u8, 147u8, 224u8, 166u8, 168u8, 224u8, 166u8, 149u8, 224u8,
+                        167u8, 129u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 224u8, 167u8,
+                        135u8, 224u8, 166u8, 164u8, 224u8, 166u8, 191u8, 32u8, 224u8, 166u8, 166u8,
+                        224u8, 166u8, 191u8, 224u8, 166u8, 168u8, 224u8, 166u8, 190u8, 224u8,
+                        166u8, 176u8, 224u8, 166u8, 174u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                        175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 149u8, 224u8, 167u8, 141u8,
+                        224u8, 166u8, 184u8, 224u8, 166u8, 191u8, 224u8, 166u8, 149u8, 224u8,
+                        166u8, 190u8, 224u8, 166u8, 168u8, 32u8, 224u8, 166u8, 170u8, 224u8, 167u8,
+                        135u8, 224u8, 166u8, 184u8, 224u8, 167u8, 139u8, 224u8, 166u8, 171u8,
+                        224u8, 166u8, 191u8, 224u8, 166u8, 178u8, 224u8, 166u8, 191u8, 224u8,
+                        166u8, 170u8, 224u8, 166u8, 190u8, 224u8, 166u8, 135u8, 224u8, 166u8,
+                        168u8, 32u8, 224u8, 166u8, 170u8, 224u8, 167u8, 135u8, 224u8, 166u8, 184u8,
+                        224u8, 167u8, 139u8, 224u8, 166u8, 184u8, 224u8, 166u8, 190u8, 224u8,
+                        166u8, 176u8, 224u8, 166u8, 172u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                        175u8, 224u8, 166u8, 188u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 32u8,
+                        224u8, 166u8, 166u8, 224u8, 166u8, 191u8, 224u8, 166u8, 168u8, 224u8,
+                        166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 166u8, 176u8, 224u8, 166u8,
+                        190u8, 224u8, 166u8, 182u8, 224u8, 166u8, 191u8, 224u8, 166u8, 175u8,
+                        224u8, 166u8, 188u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 32u8, 224u8,
+                        166u8, 176u8, 224u8, 167u8, 129u8, 224u8, 166u8, 172u8, 224u8, 167u8,
+                        135u8, 224u8, 166u8, 178u8, 224u8, 166u8, 184u8, 224u8, 167u8, 129u8,
+                        224u8, 166u8, 135u8, 224u8, 166u8, 161u8, 224u8, 166u8, 191u8, 224u8,
+                        166u8, 182u8, 32u8, 224u8, 166u8, 149u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                        176u8, 224u8, 167u8, 139u8, 224u8, 166u8, 168u8, 224u8, 166u8, 190u8,
+                        224u8, 166u8, 165u8, 224u8, 166u8, 190u8, 224u8, 166u8, 135u8, 32u8, 224u8,
+                        166u8, 172u8, 224u8, 166u8, 190u8, 224u8, 166u8, 164u8, 224u8, 166u8,
+                        164u8, 224u8, 167u8, 129u8, 224u8, 166u8, 176u8, 224u8, 167u8, 141u8,
+                        224u8, 166u8, 149u8, 224u8, 166u8, 191u8, 32u8, 224u8, 166u8, 178u8, 224u8,
+                        166u8, 191u8, 224u8, 166u8, 176u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                        174u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 167u8, 141u8,
+                        224u8, 166u8, 149u8, 224u8, 166u8, 191u8, 224u8, 166u8, 168u8, 32u8, 224u8,
+                        166u8, 161u8, 224u8, 166u8, 178u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                        176u8, 224u8, 166u8, 166u8, 224u8, 166u8, 149u8, 224u8, 167u8, 141u8,
+                        224u8, 166u8, 183u8, 224u8, 166u8, 191u8, 224u8, 166u8, 163u8, 32u8, 224u8,
+                        166u8, 134u8, 224u8, 166u8, 171u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                        176u8, 224u8, 166u8, 191u8, 224u8, 166u8, 149u8, 224u8, 166u8, 190u8,
+                        224u8, 166u8, 168u8, 32u8, 224u8, 166u8, 176u8, 224u8, 167u8, 135u8, 224u8,
+                        166u8, 168u8, 224u8, 167u8, 141u8, 224u8, 166u8, 161u8,
+                    ])
+                },
+            )
+        },
+    },
+};
+static CCP: &DataStruct = &::icu_currency::provider::CurrencyNamesV1 {
+    zero: None,
+    one: Some(::icu_currency::provider::CurrencyPluralNames {
+        pattern: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(" "),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+    }),
+    two: None,
+    few: None,
+    many: None,
+    other: ::icu_currency::provider::CurrencyPluralNames {
+        pattern: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(" "),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 66u8, 82u8, 76u8,
+                        67u8, 65u8, 68u8, 67u8, 72u8, 70u8, 67u8, 76u8, 80u8, 67u8, 78u8, 89u8,
+                        68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8,
+                        73u8, 78u8, 82u8, 74u8, 80u8, 89u8, 75u8, 82u8, 87u8, 75u8, 87u8, 68u8,
+                        77u8, 88u8, 78u8, 80u8, 72u8, 80u8, 82u8, 83u8, 68u8, 82u8, 85u8, 66u8,
+                        83u8, 69u8, 75u8, 84u8, 72u8, 66u8, 84u8, 82u8, 89u8, 85u8, 83u8, 68u8,
+                        90u8, 65u8, 82u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        25u8, 0u8, 0u8, 0u8, 0u8, 0u8, 57u8, 0u8, 130u8, 0u8, 171u8, 0u8, 236u8,
+                        0u8, 29u8, 1u8, 66u8, 1u8, 99u8, 1u8, 132u8, 1u8, 185u8, 1u8, 246u8, 1u8,
+                        14u8, 2u8, 67u8, 2u8, 120u8, 2u8, 161u8, 2u8, 231u8, 2u8, 20u8, 3u8, 77u8,
+                        3u8, 126u8, 3u8, 179u8, 3u8, 228u8, 3u8, 21u8, 4u8, 42u8, 4u8, 79u8, 4u8,
+                        128u8, 4u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 162u8, 240u8,
+                        145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 142u8, 240u8, 145u8, 132u8,
+                        172u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                        145u8, 132u8, 145u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                        154u8, 32u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 172u8, 240u8,
+                        145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8,
+                        131u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 140u8, 240u8,
+                        145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8, 132u8,
+                        179u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 172u8, 240u8,
+                        145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                        160u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8,
+                        145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8,
+                        163u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                        145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 129u8, 240u8, 145u8, 132u8,
+                        163u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 172u8, 240u8,
+                        145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 168u8, 32u8, 240u8, 145u8, 132u8,
+                        145u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 139u8, 240u8,
+                        145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8,
+                        162u8, 240u8, 145u8, 132u8, 142u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                        145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                        160u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8,
+                        145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                        160u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 163u8, 240u8,
+                        145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8,
+                        154u8, 240u8, 145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                        145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8,
+                        180u8, 32u8, 240u8, 145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 167u8, 240u8,
+                        145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8,
+                        180u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 173u8, 240u8,
+                        145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8,
+                        180u8, 32u8, 240u8, 145u8, 132u8, 156u8, 240u8, 145u8, 132u8, 179u8, 240u8,
+                        145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 128u8, 240u8, 145u8, 132u8,
+                        140u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 163u8, 240u8,
+                        145u8, 132u8, 168u8, 32u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8,
+                        172u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 174u8, 240u8,
+                        145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 169u8, 240u8, 145u8, 132u8,
+                        154u8, 32u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 170u8, 240u8,
+                        145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8,
+                        180u8, 240u8, 145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 179u8, 240u8,
+                        145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8,
+                        168u8, 240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8,
+                        145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8,
+                        162u8, 240u8, 145u8, 132u8, 175u8, 240u8, 145u8, 132u8, 154u8, 240u8,
+                        145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8,
+                        168u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 167u8, 240u8,
+                        145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 169u8, 240u8, 145u8, 132u8,
+                        160u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 155u8, 240u8,
+                        145u8, 132u8, 133u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8,
+                        154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 147u8, 240u8,
+                        145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8,
+                        168u8, 240u8, 145u8, 132u8, 133u8, 240u8, 145u8, 132u8, 170u8, 240u8,
+                        145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8,
+                        157u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 162u8, 240u8,
+                        145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8, 132u8,
+                        168u8, 240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8,
+                        145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8,
+                        170u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                        145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                        154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 152u8, 240u8,
+                        145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8,
+                        154u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 162u8, 240u8,
+                        145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8,
+                        168u8, 240u8, 145u8, 132u8, 142u8, 240u8, 145u8, 132u8, 155u8, 240u8,
+                        145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 168u8, 32u8, 240u8, 145u8, 132u8,
+                        131u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 160u8, 240u8,
+                        145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8,
+                        180u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 167u8, 240u8,
+                        145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                        154u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 135u8, 240u8,
+                        145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8,
+                        168u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 162u8, 240u8,
+                        145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8,
+                        174u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                        145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8,
+                        160u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 150u8, 240u8,
+                        145u8, 132u8, 168u8, 32u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8,
+                        168u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 162u8, 240u8,
+                        145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8,
+                        179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 135u8, 240u8,
+                        145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8,
+                        168u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 154u8, 240u8,
+                        145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8,
+                        172u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 174u8, 240u8,
+                        145u8, 132u8, 156u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                        163u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 155u8, 240u8,
+                        145u8, 132u8, 173u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8,
+                        180u8, 32u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 172u8, 240u8,
+                        145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8,
+                        165u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                        145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                        160u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8,
+                        145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                        154u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                        145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8,
+                        168u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 154u8, 240u8,
+                        145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8,
+                        170u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 172u8, 240u8,
+                        145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8,
+                        165u8, 240u8, 145u8, 132u8, 173u8, 240u8, 145u8, 132u8, 170u8, 240u8,
+                        145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                        165u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 135u8, 240u8,
+                        145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8,
+                        174u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 151u8, 240u8,
+                        145u8, 132u8, 173u8, 32u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8,
+                        150u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 150u8, 240u8,
+                        145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8,
+                        180u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 168u8, 32u8, 240u8,
+                        145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                        162u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 162u8, 240u8,
+                        145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8,
+                        168u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8,
+                        145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8,
+                        163u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                        145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8,
+                        137u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 154u8, 240u8,
+                        145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8,
+                        156u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 162u8, 240u8,
+                        145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8,
+                        154u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 162u8, 240u8,
+                        145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8,
+                        154u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 147u8, 240u8,
+                        145u8, 132u8, 180u8,
+                    ])
+                },
+            )
+        },
+    },
+};
+static EN: &DataStruct = &::icu_currency::provider::CurrencyNamesV1 {
+    zero: None,
+    one: Some(::icu_currency::provider::CurrencyPluralNames {
+        pattern: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(" "),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 66u8, 82u8, 76u8,
+                        67u8, 65u8, 68u8, 67u8, 72u8, 70u8, 67u8, 76u8, 80u8, 68u8, 75u8, 75u8,
+                        69u8, 71u8, 80u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8, 73u8, 78u8, 82u8,
+                        75u8, 87u8, 68u8, 77u8, 88u8, 78u8, 80u8, 72u8, 80u8, 82u8, 83u8, 68u8,
+                        82u8, 85u8, 66u8, 83u8, 69u8, 75u8, 84u8, 82u8, 89u8, 85u8, 83u8, 68u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        20u8, 0u8, 0u8, 0u8, 0u8, 0u8, 14u8, 0u8, 31u8, 0u8, 47u8, 0u8, 61u8, 0u8,
+                        76u8, 0u8, 87u8, 0u8, 99u8, 0u8, 111u8, 0u8, 125u8, 0u8, 129u8, 0u8, 142u8,
+                        0u8, 154u8, 0u8, 167u8, 0u8, 179u8, 0u8, 194u8, 0u8, 207u8, 0u8, 220u8,
+                        0u8, 233u8, 0u8, 245u8, 0u8, 65u8, 114u8, 103u8, 101u8, 110u8, 116u8,
+                        105u8, 110u8, 101u8, 32u8, 112u8, 101u8, 115u8, 111u8, 65u8, 117u8, 115u8,
+                        116u8, 114u8, 97u8, 108u8, 105u8, 97u8, 110u8, 32u8, 100u8, 111u8, 108u8,
+                        108u8, 97u8, 114u8, 66u8, 97u8, 110u8, 103u8, 108u8, 97u8, 100u8, 101u8,
+                        115u8, 104u8, 105u8, 32u8, 116u8, 97u8, 107u8, 97u8, 66u8, 114u8, 97u8,
+                        122u8, 105u8, 108u8, 105u8, 97u8, 110u8, 32u8, 114u8, 101u8, 97u8, 108u8,
+                        67u8, 97u8, 110u8, 97u8, 100u8, 105u8, 97u8, 110u8, 32u8, 100u8, 111u8,
+                        108u8, 108u8, 97u8, 114u8, 83u8, 119u8, 105u8, 115u8, 115u8, 32u8, 102u8,
+                        114u8, 97u8, 110u8, 99u8, 67u8, 104u8, 105u8, 108u8, 101u8, 97u8, 110u8,
+                        32u8, 112u8, 101u8, 115u8, 111u8, 68u8, 97u8, 110u8, 105u8, 115u8, 104u8,
+                        32u8, 107u8, 114u8, 111u8, 110u8, 101u8, 69u8, 103u8, 121u8, 112u8, 116u8,
+                        105u8, 97u8, 110u8, 32u8, 112u8, 111u8, 117u8, 110u8, 100u8, 101u8, 117u8,
+                        114u8, 111u8, 66u8, 114u8, 105u8, 116u8, 105u8, 115u8, 104u8, 32u8, 112u8,
+                        111u8, 117u8, 110u8, 100u8, 73u8, 110u8, 100u8, 105u8, 97u8, 110u8, 32u8,
+                        114u8, 117u8, 112u8, 101u8, 101u8, 75u8, 117u8, 119u8, 97u8, 105u8, 116u8,
+                        105u8, 32u8, 100u8, 105u8, 110u8, 97u8, 114u8, 77u8, 101u8, 120u8, 105u8,
+                        99u8, 97u8, 110u8, 32u8, 112u8, 101u8, 115u8, 111u8, 80u8, 104u8, 105u8,
+                        108u8, 105u8, 112u8, 112u8, 105u8, 110u8, 101u8, 32u8, 112u8, 101u8, 115u8,
+                        111u8, 83u8, 101u8, 114u8, 98u8, 105u8, 97u8, 110u8, 32u8, 100u8, 105u8,
+                        110u8, 97u8, 114u8, 82u8, 117u8, 115u8, 115u8, 105u8, 97u8, 110u8, 32u8,
+                        114u8, 117u8, 98u8, 108u8, 101u8, 83u8, 119u8, 101u8, 100u8, 105u8, 115u8,
+                        104u8, 32u8, 107u8, 114u8, 111u8, 110u8, 97u8, 84u8, 117u8, 114u8, 107u8,
+                        105u8, 115u8, 104u8, 32u8, 108u8, 105u8, 114u8, 97u8, 85u8, 83u8, 32u8,
+                        100u8, 111u8, 108u8, 108u8, 97u8, 114u8,
+                    ])
+                },
+            )
+        },
+    }),
+    two: None,
+    few: None,
+    many: None,
+    other: ::icu_currency::provider::CurrencyPluralNames {
+        pattern: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(" "),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 66u8, 82u8, 76u8,
+                        67u8, 65u8, 68u8, 67u8, 72u8, 70u8, 67u8, 76u8, 80u8, 67u8, 78u8, 89u8,
+                        68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8,
+                        73u8, 78u8, 82u8, 74u8, 80u8, 89u8, 75u8, 82u8, 87u8, 75u8, 87u8, 68u8,
+                        77u8, 88u8, 78u8, 80u8, 72u8, 80u8, 82u8, 83u8, 68u8, 82u8, 85u8, 66u8,
+                        83u8, 69u8, 75u8, 84u8, 72u8, 66u8, 84u8, 82u8, 89u8, 85u8, 83u8, 68u8,
+                        90u8, 65u8, 82u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        25u8, 0u8, 0u8, 0u8, 0u8, 0u8, 15u8, 0u8, 33u8, 0u8, 50u8, 0u8, 65u8, 0u8,
+                        81u8, 0u8, 93u8, 0u8, 106u8, 0u8, 118u8, 0u8, 131u8, 0u8, 146u8, 0u8,
+                        151u8, 0u8, 165u8, 0u8, 178u8, 0u8, 190u8, 0u8, 206u8, 0u8, 220u8, 0u8,
+                        233u8, 0u8, 249u8, 0u8, 7u8, 1u8, 21u8, 1u8, 35u8, 1u8, 44u8, 1u8, 56u8,
+                        1u8, 66u8, 1u8, 65u8, 114u8, 103u8, 101u8, 110u8, 116u8, 105u8, 110u8,
+                        101u8, 32u8, 112u8, 101u8, 115u8, 111u8, 115u8, 65u8, 117u8, 115u8, 116u8,
+                        114u8, 97u8, 108u8, 105u8, 97u8, 110u8, 32u8, 100u8, 111u8, 108u8, 108u8,
+                        97u8, 114u8, 115u8, 66u8, 97u8, 110u8, 103u8, 108u8, 97u8, 100u8, 101u8,
+                        115u8, 104u8, 105u8, 32u8, 116u8, 97u8, 107u8, 97u8, 115u8, 66u8, 114u8,
+                        97u8, 122u8, 105u8, 108u8, 105u8, 97u8, 110u8, 32u8, 114u8, 101u8, 97u8,
+                        108u8, 115u8, 67u8, 97u8, 110u8, 97u8, 100u8, 105u8, 97u8, 110u8, 32u8,
+                        100u8, 111u8, 108u8, 108u8, 97u8, 114u8, 115u8, 83u8, 119u8, 105u8, 115u8,
+                        115u8, 32u8, 102u8, 114u8, 97u8, 110u8, 99u8, 115u8, 67u8, 104u8, 105u8,
+                        108u8, 101u8, 97u8, 110u8, 32u8, 112u8, 101u8, 115u8, 111u8, 115u8, 67u8,
+                        104u8, 105u8, 110u8, 101u8, 115u8, 101u8, 32u8, 121u8, 117u8, 97u8, 110u8,
+                        68u8, 97u8, 110u8, 105u8, 115u8, 104u8, 32u8, 107u8, 114u8, 111u8, 110u8,
+                        101u8, 114u8, 69u8, 103u8, 121u8, 112u8, 116u8, 105u8, 97u8, 110u8, 32u8,
+                        112u8, 111u8, 117u8, 110u8, 100u8, 115u8, 101u8, 117u8, 114u8, 111u8,
+                        115u8, 66u8, 114u8, 105u8, 116u8, 105u8, 115u8, 104u8, 32u8, 112u8, 111u8,
+                        117u8, 110u8, 100u8, 115u8, 73u8, 110u8, 100u8, 105u8, 97u8, 110u8, 32u8,
+                        114u8, 117u8, 112u8, 101u8, 101u8, 115u8, 74u8, 97u8, 112u8, 97u8, 110u8,
+                        101u8, 115u8, 101u8, 32u8, 121u8, 101u8, 110u8, 83u8, 111u8, 117u8, 116u8,
+                        104u8, 32u8, 75u8, 111u8, 114u8, 101u8, 97u8, 110u8, 32u8, 119u8, 111u8,
+                        110u8, 75u8, 117u8, 119u8, 97u8, 105u8, 116u8, 105u8, 32u8, 100u8, 105u8,
+                        110u8, 97u8, 114u8, 115u8, 77u8, 101u8, 120u8, 105u8, 99u8, 97u8, 110u8,
+                        32u8, 112u8, 101u8, 115u8, 111u8, 115u8, 80u8, 104u8, 105u8, 108u8, 105u8,
+                        112u8, 112u8, 105u8, 110u8, 101u8, 32u8, 112u8, 101u8, 115u8, 111u8, 115u8,
+                        83u8, 101u8, 114u8, 98u8, 105u8, 97u8, 110u8, 32u8, 100u8, 105u8, 110u8,
+                        97u8, 114u8, 115u8, 82u8, 117u8, 115u8, 115u8, 105u8, 97u8, 110u8, 32u8,
+                        114u8, 117u8, 98u8, 108u8, 101u8, 115u8, 83u8, 119u8, 101u8, 100u8, 105u8,
+                        115u8, 104u8, 32u8, 107u8, 114u8, 111u8, 110u8, 111u8, 114u8, 84u8, 104u8,
+                        97u8, 105u8, 32u8, 98u8, 97u8, 104u8, 116u8, 84u8, 117u8, 114u8, 107u8,
+                        105u8, 115u8, 104u8, 32u8, 76u8, 105u8, 114u8, 97u8, 85u8, 83u8, 32u8,
+                        100u8, 111u8, 108u8, 108u8, 97u8, 114u8, 115u8, 83u8, 111u8, 117u8, 116u8,
+                        104u8, 32u8, 65u8, 102u8, 114u8, 105u8, 99u8, 97u8, 110u8, 32u8, 114u8,
+                        97u8, 110u8, 100u8,
+                    ])
+                },
+            )
+        },
+    },
+};
+static EN_001_EN_ZA: &DataStruct = &::icu_currency::provider::CurrencyNamesV1 {
+    zero: None,
+    one: Some(::icu_currency::provider::CurrencyPluralNames {
+        pattern: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(" "),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 66u8, 82u8, 76u8,
+                        67u8, 65u8, 68u8, 67u8, 72u8, 70u8, 67u8, 76u8, 80u8, 68u8, 75u8, 75u8,
+                        69u8, 71u8, 80u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8, 73u8, 78u8, 82u8,
+                        75u8, 87u8, 68u8, 77u8, 88u8, 78u8, 80u8, 72u8, 80u8, 82u8, 83u8, 68u8,
+                        82u8, 85u8, 66u8, 83u8, 69u8, 75u8, 84u8, 82u8, 89u8, 85u8, 83u8, 68u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        20u8, 0u8, 0u8, 0u8, 0u8, 0u8, 14u8, 0u8, 31u8, 0u8, 47u8, 0u8, 61u8, 0u8,
+                        76u8, 0u8, 87u8, 0u8, 99u8, 0u8, 111u8, 0u8, 125u8, 0u8, 129u8, 0u8, 142u8,
+                        0u8, 154u8, 0u8, 167u8, 0u8, 179u8, 0u8, 194u8, 0u8, 207u8, 0u8, 221u8,
+                        0u8, 234u8, 0u8, 246u8, 0u8, 65u8, 114u8, 103u8, 101u8, 110u8, 116u8,
+                        105u8, 110u8, 101u8, 32u8, 112u8, 101u8, 115u8, 111u8, 65u8, 117u8, 115u8,
+                        116u8, 114u8, 97u8, 108u8, 105u8, 97u8, 110u8, 32u8, 100u8, 111u8, 108u8,
+                        108u8, 97u8, 114u8, 66u8, 97u8, 110u8, 103u8, 108u8, 97u8, 100u8, 101u8,
+                        115u8, 104u8, 105u8, 32u8, 116u8, 97u8, 107u8, 97u8, 66u8, 114u8, 97u8,
+                        122u8, 105u8, 108u8, 105u8, 97u8, 110u8, 32u8, 114u8, 101u8, 97u8, 108u8,
+                        67u8, 97u8, 110u8, 97u8, 100u8, 105u8, 97u8, 110u8, 32u8, 100u8, 111u8,
+                        108u8, 108u8, 97u8, 114u8, 83u8, 119u8, 105u8, 115u8, 115u8, 32u8, 102u8,
+                        114u8, 97u8, 110u8, 99u8, 67u8, 104u8, 105u8, 108u8, 101u8, 97u8, 110u8,
+                        32u8, 112u8, 101u8, 115u8, 111u8, 68u8, 97u8, 110u8, 105u8, 115u8, 104u8,
+                        32u8, 107u8, 114u8, 111u8, 110u8, 101u8, 69u8, 103u8, 121u8, 112u8, 116u8,
+                        105u8, 97u8, 110u8, 32u8, 112u8, 111u8, 117u8, 110u8, 100u8, 101u8, 117u8,
+                        114u8, 111u8, 66u8, 114u8, 105u8, 116u8, 105u8, 115u8, 104u8, 32u8, 112u8,
+                        111u8, 117u8, 110u8, 100u8, 73u8, 110u8, 100u8, 105u8, 97u8, 110u8, 32u8,
+                        114u8, 117u8, 112u8, 101u8, 101u8, 75u8, 117u8, 119u8, 97u8, 105u8, 116u8,
+                        105u8, 32u8, 100u8, 105u8, 110u8, 97u8, 114u8, 77u8, 101u8, 120u8, 105u8,
+                        99u8, 97u8, 110u8, 32u8, 112u8, 101u8, 115u8, 111u8, 80u8, 104u8, 105u8,
+                        108u8, 105u8, 112u8, 112u8, 105u8, 110u8, 101u8, 32u8, 112u8, 101u8, 115u8,
+                        111u8, 83u8, 101u8, 114u8, 98u8, 105u8, 97u8, 110u8, 32u8, 100u8, 105u8,
+                        110u8, 97u8, 114u8, 82u8, 117u8, 115u8, 115u8, 105u8, 97u8, 110u8, 32u8,
+                        114u8, 111u8, 117u8, 98u8, 108u8, 101u8, 83u8, 119u8, 101u8, 100u8, 105u8,
+                        115u8, 104u8, 32u8, 107u8, 114u8, 111u8, 110u8, 97u8, 84u8, 117u8, 114u8,
+                        107u8, 105u8, 115u8, 104u8, 32u8, 108u8, 105u8, 114u8, 97u8, 85u8, 83u8,
+                        32u8, 100u8, 111u8, 108u8, 108u8, 97u8, 114u8,
+                    ])
+                },
+            )
+        },
+    }),
+    two: None,
+    few: None,
+    many: None,
+    other: ::icu_currency::provider::CurrencyPluralNames {
+        pattern: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(" "),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 66u8, 82u8, 76u8,
+                        67u8, 65u8, 68u8, 67u8, 72u8, 70u8, 67u8, 76u8, 80u8, 67u8, 78u8, 89u8,
+                        68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8,
+                        73u8, 78u8, 82u8, 74u8, 80u8, 89u8, 75u8, 82u8, 87u8, 75u8, 87u8, 68u8,
+                        77u8, 88u8, 78u8, 80u8, 72u8, 80u8, 82u8, 83u8, 68u8, 82u8, 85u8, 66u8,
+                        83u8, 69u8, 75u8, 84u8, 72u8, 66u8, 84u8, 82u8, 89u8, 85u8, 83u8, 68u8,
+                        90u8, 65u8, 82u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        25u8, 0u8, 0u8, 0u8, 0u8, 0u8, 15u8, 0u8, 33u8, 0u8, 50u8, 0u8, 65u8, 0u8,
+                        81u8, 0u8, 93u8, 0u8, 106u8, 0u8, 118u8, 0u8, 131u8, 0u8, 146u8, 0u8,
+                        151u8, 0u8, 165u8, 0u8, 178u8, 0u8, 190u8, 0u8, 206u8, 0u8, 220u8, 0u8,
+                        233u8, 0u8, 249u8, 0u8, 7u8, 1u8, 22u8, 1u8, 36u8, 1u8, 45u8, 1u8, 57u8,
+                        1u8, 67u8, 1u8, 65u8, 114u8, 103u8, 101u8, 110u8, 116u8, 105u8, 110u8,
+                        101u8, 32u8, 112u8, 101u8, 115u8, 111u8, 115u8, 65u8, 117u8, 115u8, 116u8,
+                        114u8, 97u8, 108u8, 105u8, 97u8, 110u8, 32u8, 100u8, 111u8, 108u8, 108u8,
+                        97u8, 114u8, 115u8, 66u8, 97u8, 110u8, 103u8, 108u8, 97u8, 100u8, 101u8,
+                        115u8, 104u8, 105u8, 32u8, 116u8, 97u8, 107u8, 97u8, 115u8, 66u8, 114u8,
+                        97u8, 122u8, 105u8, 108u8, 105u8, 97u8, 110u8, 32u8, 114u8, 101u8, 97u8,
+                        108u8, 115u8, 67u8, 97u8, 110u8, 97u8, 100u8, 105u8, 97u8, 110u8, 32u8,
+                        100u8, 111u8, 108u8, 108u8, 97u8, 114u8, 115u8, 83u8, 119u8, 105u8, 115u8,
+                        115u8, 32u8, 102u8, 114u8, 97u8, 110u8, 99u8, 115u8, 67u8, 104u8, 105u8,
+                        108u8, 101u8, 97u8, 110u8, 32u8, 112u8, 101u8, 115u8, 111u8, 115u8, 67u8,
+                        104u8, 105u8, 110u8, 101u8, 115u8, 101u8, 32u8, 121u8, 117u8, 97u8, 110u8,
+                        68u8, 97u8, 110u8, 105u8, 115u8, 104u8, 32u8, 107u8, 114u8, 111u8, 110u8,
+                        101u8, 114u8, 69u8, 103u8, 121u8, 112u8, 116u8, 105u8, 97u8, 110u8, 32u8,
+                        112u8, 111u8, 117u8, 110u8, 100u8, 115u8, 101u8, 117u8, 114u8, 111u8,
+                        115u8, 66u8, 114u8, 105u8, 116u8, 105u8, 115u8, 104u8, 32u8, 112u8, 111u8,
+                        117u8, 110u8, 100u8, 115u8, 73u8, 110u8, 100u8, 105u8, 97u8, 110u8, 32u8,
+                        114u8, 117u8, 112u8, 101u8, 101u8, 115u8, 74u8, 97u8, 112u8, 97u8, 110u8,
+                        101u8, 115u8, 101u8, 32u8, 121u8, 101u8, 110u8, 83u8, 111u8, 117u8, 116u8,
+                        104u8, 32u8, 75u8, 111u8, 114u8, 101u8, 97u8, 110u8, 32u8, 119u8, 111u8,
+                        110u8, 75u8, 117u8, 119u8, 97u8, 105u8, 116u8, 105u8, 32u8, 100u8, 105u8,
+                        110u8, 97u8, 114u8, 115u8, 77u8, 101u8, 120u8, 105u8, 99u8, 97u8, 110u8,
+                        32u8, 112u8, 101u8, 115u8, 111u8, 115u8, 80u8, 104u8, 105u8, 108u8, 105u8,
+                        112u8, 112u8, 105u8, 110u8, 101u8, 32u8, 112u8, 101u8, 115u8, 111u8, 115u8,
+                        83u8, 101u8, 114u8, 98u8, 105u8, 97u8, 110u8, 32u8, 100u8, 105u8, 110u8,
+                        97u8, 114u8, 115u8, 82u8, 117u8, 115u8, 115u8, 105u8, 97u8, 110u8, 32u8,
+                        114u8, 111u8, 117u8, 98u8, 108u8, 101u8, 115u8, 83u8, 119u8, 101u8, 100u8,
+                        105u8, 115u8, 104u8, 32u8, 107u8, 114u8, 111u8, 110u8, 111u8, 114u8, 84u8,
+                        104u8, 97u8, 105u8, 32u8, 98u8, 97u8, 104u8, 116u8, 84u8, 117u8, 114u8,
+                        107u8, 105u8, 115u8, 104u8, 32u8, 76u8, 105u8, 114u8, 97u8, 85u8, 83u8,
+                        32u8, 100u8, 111u8, 108u8, 108u8, 97u8, 114u8, 115u8, 83u8, 111u8, 117u8,
+                        116u8, 104u8, 32u8, 65u8, 102u8, 114u8, 105u8, 99u8, 97u8, 110u8, 32u8,
+                        114u8, 97u8, 110u8, 100u8,
+                    ])
+                },
+            )
+        },
+    },
+};
+static ES: &DataStruct = &::icu_currency::provider::CurrencyNamesV1 {
+    zero: None,
+    one: Some(::icu_currency::provider::CurrencyPluralNames {
+        pattern: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(" "),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 66u8, 82u8, 76u8,
+                        67u8, 65u8, 68u8, 67u8, 72u8, 70u8, 67u8, 76u8, 80u8, 67u8, 78u8, 89u8,
+                        68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8,
+                        73u8, 78u8, 82u8, 74u8, 80u8, 89u8, 75u8, 82u8, 87u8, 75u8, 87u8, 68u8,
+                        77u8, 88u8, 78u8, 80u8, 72u8, 80u8, 82u8, 83u8, 68u8, 82u8, 85u8, 66u8,
+                        83u8, 69u8, 75u8, 84u8, 72u8, 66u8, 84u8, 82u8, 89u8, 85u8, 83u8, 68u8,
+                        90u8, 65u8, 82u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        25u8, 0u8, 0u8, 0u8, 0u8, 0u8, 14u8, 0u8, 32u8, 0u8, 48u8, 0u8, 63u8, 0u8,
+                        80u8, 0u8, 92u8, 0u8, 104u8, 0u8, 117u8, 0u8, 130u8, 0u8, 143u8, 0u8,
+                        147u8, 0u8, 162u8, 0u8, 173u8, 0u8, 185u8, 0u8, 199u8, 0u8, 213u8, 0u8,
+                        226u8, 0u8, 239u8, 0u8, 251u8, 0u8, 5u8, 1u8, 17u8, 1u8, 31u8, 1u8, 41u8,
+                        1u8, 62u8, 1u8, 112u8, 101u8, 115u8, 111u8, 32u8, 97u8, 114u8, 103u8,
+                        101u8, 110u8, 116u8, 105u8, 110u8, 111u8, 100u8, 195u8, 179u8, 108u8, 97u8,
+                        114u8, 32u8, 97u8, 117u8, 115u8, 116u8, 114u8, 97u8, 108u8, 105u8, 97u8,
+                        110u8, 111u8, 116u8, 97u8, 107u8, 97u8, 32u8, 98u8, 97u8, 110u8, 103u8,
+                        108u8, 97u8, 100u8, 101u8, 115u8, 195u8, 173u8, 114u8, 101u8, 97u8, 108u8,
+                        32u8, 98u8, 114u8, 97u8, 115u8, 105u8, 108u8, 101u8, 195u8, 177u8, 111u8,
+                        100u8, 195u8, 179u8, 108u8, 97u8, 114u8, 32u8, 99u8, 97u8, 110u8, 97u8,
+                        100u8, 105u8, 101u8, 110u8, 115u8, 101u8, 102u8, 114u8, 97u8, 110u8, 99u8,
+                        111u8, 32u8, 115u8, 117u8, 105u8, 122u8, 111u8, 112u8, 101u8, 115u8, 111u8,
+                        32u8, 99u8, 104u8, 105u8, 108u8, 101u8, 110u8, 111u8, 121u8, 117u8, 97u8,
+                        110u8, 32u8, 114u8, 101u8, 110u8, 109u8, 105u8, 110u8, 98u8, 105u8, 99u8,
+                        111u8, 114u8, 111u8, 110u8, 97u8, 32u8, 100u8, 97u8, 110u8, 101u8, 115u8,
+                        97u8, 108u8, 105u8, 98u8, 114u8, 97u8, 32u8, 101u8, 103u8, 105u8, 112u8,
+                        99u8, 105u8, 97u8, 101u8, 117u8, 114u8, 111u8, 108u8, 105u8, 98u8, 114u8,
+                        97u8, 32u8, 101u8, 115u8, 116u8, 101u8, 114u8, 108u8, 105u8, 110u8, 97u8,
+                        114u8, 117u8, 112u8, 105u8, 97u8, 32u8, 105u8, 110u8, 100u8, 105u8, 97u8,
+                        121u8, 101u8, 110u8, 32u8, 106u8, 97u8, 112u8, 111u8, 110u8, 195u8, 169u8,
+                        115u8, 119u8, 111u8, 110u8, 32u8, 115u8, 117u8, 114u8, 99u8, 111u8, 114u8,
+                        101u8, 97u8, 110u8, 111u8, 100u8, 105u8, 110u8, 97u8, 114u8, 32u8, 107u8,
+                        117u8, 119u8, 97u8, 105u8, 116u8, 195u8, 173u8, 112u8, 101u8, 115u8, 111u8,
+                        32u8, 109u8, 101u8, 120u8, 105u8, 99u8, 97u8, 110u8, 111u8, 112u8, 101u8,
+                        115u8, 111u8, 32u8, 102u8, 105u8, 108u8, 105u8, 112u8, 105u8, 110u8, 111u8,
+                        100u8, 105u8, 110u8, 97u8, 114u8, 32u8, 115u8, 101u8, 114u8, 98u8, 105u8,
+                        111u8, 114u8, 117u8, 98u8, 108u8, 111u8, 32u8, 114u8, 117u8, 115u8, 111u8,
+                        99u8, 111u8, 114u8, 111u8, 110u8, 97u8, 32u8, 115u8, 117u8, 101u8, 99u8,
+                        97u8, 98u8, 97u8, 116u8, 32u8, 116u8, 97u8, 105u8, 108u8, 97u8, 110u8,
+                        100u8, 195u8, 169u8, 115u8, 108u8, 105u8, 114u8, 97u8, 32u8, 116u8, 117u8,
+                        114u8, 99u8, 97u8, 100u8, 195u8, 179u8, 108u8, 97u8, 114u8, 32u8, 101u8,
+                        115u8, 116u8, 97u8, 100u8, 111u8, 117u8, 110u8, 105u8, 100u8, 101u8, 110u8,
+                        115u8, 101u8, 114u8, 97u8, 110u8, 100u8, 32u8, 115u8, 117u8, 100u8, 97u8,
+                        102u8, 114u8, 105u8, 99u8, 97u8, 110u8, 111u8,
+                    ])
+                },
+            )
+        },
+    }),
+    two: None,
+    few: None,
+    many: None,
+    other: ::icu_currency::provider::CurrencyPluralNames {
+        pattern: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(" "),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 66u8, 82u8, 76u8,
+                        67u8, 65u8, 68u8, 67u8, 72u8, 70u8, 67u8, 76u8, 80u8, 67u8, 78u8, 89u8,
+                        68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8,
+                        73u8, 78u8, 82u8, 74u8, 80u8, 89u8, 75u8, 82u8, 87u8, 75u8, 87u8, 68u8,
+                        77u8, 88u8, 78u8, 80u8, 72u8, 80u8, 82u8, 83u8, 68u8, 82u8, 85u8, 66u8,
+                        83u8, 69u8, 75u8, 84u8, 72u8, 66u8, 84u8, 82u8, 89u8, 85u8, 83u8, 68u8,
+                        90u8, 65u8, 82u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        25u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 0u8, 37u8, 0u8, 56u8, 0u8, 74u8, 0u8,
+                        94u8, 0u8, 108u8, 0u8, 122u8, 0u8, 137u8, 0u8, 152u8, 0u8, 167u8, 0u8,
+                        172u8, 0u8, 189u8, 0u8, 202u8, 0u8, 217u8, 0u8, 233u8, 0u8, 251u8, 0u8,
+                        10u8, 1u8, 25u8, 1u8, 40u8, 1u8, 52u8, 1u8, 66u8, 1u8, 82u8, 1u8, 94u8,
+                        1u8, 118u8, 1u8, 112u8, 101u8, 115u8, 111u8, 115u8, 32u8, 97u8, 114u8,
+                        103u8, 101u8, 110u8, 116u8, 105u8, 110u8, 111u8, 115u8, 100u8, 195u8,
+                        179u8, 108u8, 97u8, 114u8, 101u8, 115u8, 32u8, 97u8, 117u8, 115u8, 116u8,
+                        114u8, 97u8, 108u8, 105u8, 97u8, 110u8, 111u8, 115u8, 116u8, 97u8, 107u8,
+                        97u8, 115u8, 32u8, 98u8, 97u8, 110u8, 103u8, 108u8, 97u8, 100u8, 101u8,
+                        115u8, 195u8, 173u8, 101u8, 115u8, 114u8, 101u8, 97u8, 108u8, 101u8, 115u8,
+                        32u8, 98u8, 114u8, 97u8, 115u8, 105u8, 108u8, 101u8, 195u8, 177u8, 111u8,
+                        115u8, 100u8, 195u8, 179u8, 108u8, 97u8, 114u8, 101u8, 115u8, 32u8, 99u8,
+                        97u8, 110u8, 97u8, 100u8, 105u8, 101u8, 110u8, 115u8, 101u8, 115u8, 102u8,
+                        114u8, 97u8, 110u8, 99u8, 111u8, 115u8, 32u8, 115u8, 117u8, 105u8, 122u8,
+                        111u8, 115u8, 112u8, 101u8, 115u8, 111u8, 115u8, 32u8, 99u8, 104u8, 105u8,
+                        108u8, 101u8, 110u8, 111u8, 115u8, 121u8, 117u8, 97u8, 110u8, 101u8, 115u8,
+                        32u8, 114u8, 101u8, 110u8, 109u8, 105u8, 110u8, 98u8, 105u8, 99u8, 111u8,
+                        114u8, 111u8, 110u8, 97u8, 115u8, 32u8, 100u8, 97u8, 110u8, 101u8, 115u8,
+                        97u8, 115u8, 108u8, 105u8, 98u8, 114u8, 97u8, 115u8, 32u8, 101u8, 103u8,
+                        105u8, 112u8, 99u8, 105u8, 97u8, 115u8, 101u8, 117u8, 114u8, 111u8, 115u8,
+                        108u8, 105u8, 98u8, 114u8, 97u8, 115u8, 32u8, 101u8, 115u8, 116u8, 101u8,
+                        114u8, 108u8, 105u8, 110u8, 97u8, 115u8, 114u8, 117u8, 112u8, 105u8, 97u8,
+                        115u8, 32u8, 105u8, 110u8, 100u8, 105u8, 97u8, 115u8, 121u8, 101u8, 110u8,
+                        101u8, 115u8, 32u8, 106u8, 97u8, 112u8, 111u8, 110u8, 101u8, 115u8, 101u8,
+                        115u8, 119u8, 111u8, 110u8, 115u8, 32u8, 115u8, 117u8, 114u8, 99u8, 111u8,
+                        114u8, 101u8, 97u8, 110u8, 111u8, 115u8, 100u8, 105u8, 110u8, 97u8, 114u8,
+                        101u8, 115u8, 32u8, 107u8, 117u8, 119u8, 97u8, 105u8, 116u8, 195u8, 173u8,
+                        101u8, 115u8, 112u8, 101u8, 115u8, 111u8, 115u8, 32u8, 109u8, 101u8, 120u8,
+                        105u8, 99u8, 97u8, 110u8, 111u8, 115u8, 112u8, 101u8, 115u8, 111u8, 115u8,
+                        32u8, 102u8, 105u8, 108u8, 105u8, 112u8, 105u8, 110u8, 111u8, 115u8, 100u8,
+                        105u8, 110u8, 97u8, 114u8, 101u8, 115u8, 32u8, 115u8, 101u8, 114u8, 98u8,
+                        105u8, 111u8, 115u8, 114u8, 117u8, 98u8, 108u8, 111u8, 115u8, 32u8, 114u8,
+                        117u8, 115u8, 111u8, 115u8, 99u8, 111u8, 114u8, 111u8, 110u8, 97u8, 115u8,
+                        32u8, 115u8, 117u8, 101u8, 99u8, 97u8, 115u8, 98u8, 97u8, 116u8, 115u8,
+                        32u8, 116u8, 97u8, 105u8, 108u8, 97u8, 110u8, 100u8, 101u8, 115u8, 101u8,
+                        115u8, 108u8, 105u8, 114u8, 97u8, 115u8, 32u8, 116u8, 117u8, 114u8, 99u8,
+                        97u8, 115u8, 100u8, 195u8, 179u8, 108u8, 97u8, 114u8, 101u8, 115u8, 32u8,
+                        101u8, 115u8, 116u8, 97u8, 100u8, 111u8, 117u8, 110u8, 105u8, 100u8, 101u8,
+                        110u8, 115u8, 101u8, 115u8, 114u8, 97u8, 110u8, 100u8, 115u8, 32u8, 115u8,
+                        117u8, 100u8, 97u8, 102u8, 114u8, 105u8, 99u8, 97u8, 110u8, 111u8, 115u8,
+                    ])
+                },
+            )
+        },
+    },
+};
+static ES_AR: &DataStruct = &::icu_currency::provider::CurrencyNamesV1 {
+    zero: None,
+    one: Some(::icu_currency::provider::CurrencyPluralNames {
+        pattern: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(" "),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 66u8, 82u8, 76u8,
+                        67u8, 65u8, 68u8, 67u8, 72u8, 70u8, 67u8, 76u8, 80u8, 67u8, 78u8, 89u8,
+                        68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8,
+                        73u8, 78u8, 82u8, 74u8, 80u8, 89u8, 75u8, 82u8, 87u8, 75u8, 87u8, 68u8,
+                        77u8, 88u8, 78u8, 80u8, 72u8, 80u8, 82u8, 83u8, 68u8, 82u8, 85u8, 66u8,
+                        83u8, 69u8, 75u8, 84u8, 72u8, 66u8, 84u8, 82u8, 89u8, 85u8, 83u8, 68u8,
+                        90u8, 65u8, 82u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        25u8, 0u8, 0u8, 0u8, 0u8, 0u8, 14u8, 0u8, 32u8, 0u8, 48u8, 0u8, 63u8, 0u8,
+                        80u8, 0u8, 92u8, 0u8, 104u8, 0u8, 117u8, 0u8, 130u8, 0u8, 143u8, 0u8,
+                        147u8, 0u8, 162u8, 0u8, 173u8, 0u8, 185u8, 0u8, 199u8, 0u8, 213u8, 0u8,
+                        226u8, 0u8, 239u8, 0u8, 251u8, 0u8, 5u8, 1u8, 17u8, 1u8, 31u8, 1u8, 41u8,
+                        1u8, 62u8, 1u8, 112u8, 101u8, 115u8, 111u8, 32u8, 97u8, 114u8, 103u8,
+                        101u8, 110u8, 116u8, 105u8, 110u8, 111u8, 100u8, 195u8, 179u8, 108u8, 97u8,
+                        114u8, 32u8, 97u8, 117u8, 115u8, 116u8, 114u8, 97u8, 108u8, 105u8, 97u8,
+                        110u8, 111u8, 116u8, 97u8, 107u8, 97u8, 32u8, 98u8, 97u8, 110u8, 103u8,
+                        108u8, 97u8, 100u8, 101u8, 115u8, 195u8, 173u8, 114u8, 101u8, 97u8, 108u8,
+                        32u8, 98u8, 114u8, 97u8, 115u8, 105u8, 108u8, 101u8, 195u8, 177u8, 111u8,
+                        100u8, 195u8, 179u8, 108u8, 97u8, 114u8, 32u8, 99u8, 97u8, 110u8, 97u8,
+                        100u8, 105u8, 101u8, 110u8, 115u8, 101u8, 102u8, 114u8, 97u8, 110u8, 99u8,
+                        111u8, 32u8, 115u8, 117u8, 105u8, 122u8, 111u8, 112u8, 101u8, 115u8, 111u8,
+                        32u8, 99u8, 104u8, 105u8, 108u8, 101u8, 110u8, 111u8, 121u8, 117u8, 97u8,
+                        110u8, 32u8, 114u8, 101u8, 110u8, 109u8, 105u8, 110u8, 98u8, 105u8, 99u8,
+                        111u8, 114u8, 111u8, 110u8, 97u8, 32u8, 100u8, 97u8, 110u8, 101u8, 115u8,
+                        97u8, 108u8, 105u8, 98u8, 114u8, 97u8, 32u8, 101u8, 103u8, 105u8, 112u8,
+                        99u8, 105u8, 97u8, 101u8, 117u8, 114u8, 111u8, 108u8, 105u8, 98u8, 114u8,
+                        97u8, 32u8, 101u8, 115u8, 116u8, 101u8, 114u8, 108u8, 105u8, 110u8, 97u8,
+                        114u8, 117u8, 112u8, 105u8, 97u8, 32u8, 105u8, 110u8, 100u8, 105u8, 97u8,
+                        121u8, 101u8, 110u8, 32u8, 106u8, 97u8, 112u8, 111u8, 110u8, 195u8, 169u8,
+                        115u8, 119u8, 111u8, 110u8, 32u8, 115u8, 117u8, 114u8, 99u8, 111u8, 114u8,
+                        101u8, 97u8, 110u8, 111u8, 100u8, 105u8, 110u8, 97u8, 114u8, 32u8, 107u8,
+                        117u8, 119u8, 97u8, 105u8, 116u8, 195u8, 173u8, 112u8, 101u8, 115u8, 111u8,
+                        32u8, 109u8, 101u8, 120u8, 105u8, 99u8, 97u8, 110u8, 111u8, 112u8, 101u8,
+                        115u8, 111u8, 32u8, 102u8, 105u8, 108u8, 105u8, 112u8, 105u8, 110u8, 111u8,
+                        100u8, 105u8, 110u8, 97u8, 114u8, 32u8, 115u8, 101u8, 114u8, 98u8, 105u8,
+                        111u8, 114u8, 117u8, 98u8, 108u8, 111u8, 32u8, 114u8, 117u8, 115u8, 111u8,
+                        99u8, 111u8, 114u8, 111u8, 110u8, 97u8, 32u8, 115u8, 117u8, 101u8, 99u8,
+                        97u8, 98u8, 97u8, 104u8, 116u8, 32u8, 116u8, 97u8, 105u8, 108u8, 97u8,
+                        110u8, 100u8, 101u8, 115u8, 108u8, 105u8, 114u8, 97u8, 32u8, 116u8, 117u8,
+                        114u8, 99u8, 97u8, 100u8, 195u8, 179u8, 108u8, 97u8, 114u8, 32u8, 101u8,
+                        115u8, 116u8, 97u8, 100u8, 111u8, 117u8, 110u8, 105u8, 100u8, 101u8, 110u8,
+                        115u8, 101u8, 114u8, 97u8, 110u8, 100u8, 32u8, 115u8, 117u8, 100u8, 97u8,
+                        102u8, 114u8, 105u8, 99u8, 97u8, 110u8, 111u8,
+                    ])
+                },
+            )
+        },
+    }),
+    two: None,
+    few: None,
+    many: None,
+    other: ::icu_currency::provider::CurrencyPluralNames {
+        pattern: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(" "),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 66u8, 82u8, 76u8,
+                        67u8, 65u8, 68u8, 67u8, 72u8, 70u8, 67u8, 76u8, 80u8, 67u8, 78u8, 89u8,
+                        68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8,
+                        73u8, 78u8, 82u8, 74u8, 80u8, 89u8, 75u8, 82u8, 87u8, 75u8, 87u8, 68u8,
+                        77u8, 88u8, 78u8, 80u8, 72u8, 80u8, 82u8, 83u8, 68u8, 82u8, 85u8, 66u8,
+                        83u8, 69u8, 75u8, 84u8, 72u8, 66u8, 84u8, 82u8, 89u8, 85u8, 83u8, 68u8,
+                        90u8, 65u8, 82u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        25u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 0u8, 37u8, 0u8, 56u8, 0u8, 74u8, 0u8,
+                        94u8, 0u8, 108u8, 0u8, 122u8, 0u8, 137u8, 0u8, 152u8, 0u8, 167u8, 0u8,
+                        172u8, 0u8, 189u8, 0u8, 202u8, 0u8, 217u8, 0u8, 233u8, 0u8, 251u8, 0u8,
+                        10u8, 1u8, 25u8, 1u8, 40u8, 1u8, 52u8, 1u8, 66u8, 1u8, 83u8, 1u8, 95u8,
+                        1u8, 119u8, 1u8, 112u8, 101u8, 115u8, 111u8, 115u8, 32u8, 97u8, 114u8,
+                        103u8, 101u8, 110u8, 116u8, 105u8, 110u8, 111u8, 115u8, 100u8, 195u8,
+                        179u8, 108u8, 97u8, 114u8, 101u8, 115u8, 32u8, 97u8, 117u8, 115u8, 116u8,
+                        114u8, 97u8, 108u8, 105u8, 97u8, 110u8, 111u8, 115u8, 116u8, 97u8, 107u8,
+                        97u8, 115u8, 32u8, 98u8, 97u8, 110u8, 103u8, 108u8, 97u8, 100u8, 101u8,
+                        115u8, 195u8, 173u8, 101u8, 115u8, 114u8, 101u8, 97u8, 108u8, 101u8, 115u8,
+                        32u8, 98u8, 114u8, 97u8, 115u8, 105u8, 108u8, 101u8, 195u8, 177u8, 111u8,
+                        115u8, 100u8, 195u8, 179u8, 108u8, 97u8, 114u8, 101u8, 115u8, 32u8, 99u8,
+                        97u8, 110u8, 97u8, 100u8, 105u8, 101u8, 110u8, 115u8, 101u8, 115u8, 102u8,
+                        114u8, 97u8, 110u8, 99u8, 111u8, 115u8, 32u8, 115u8, 117u8, 105u8, 122u8,
+                        111u8, 115u8, 112u8, 101u8, 115u8, 111u8, 115u8, 32u8, 99u8, 104u8, 105u8,
+                        108u8, 101u8, 110u8, 111u8, 115u8, 121u8, 117u8, 97u8, 110u8, 101u8, 115u8,
+                        32u8, 114u8, 101u8, 110u8, 109u8, 105u8, 110u8, 98u8, 105u8, 99u8, 111u8,
+                        114u8, 111u8, 110u8, 97u8, 115u8, 32u8, 100u8, 97u8, 110u8, 101u8, 115u8,
+                        97u8, 115u8, 108u8, 105u8, 98u8, 114u8, 97u8, 115u8, 32u8, 101u8, 103u8,
+                        105u8, 112u8, 99u8, 105u8, 97u8, 115u8, 101u8, 117u8, 114u8, 111u8, 115u8,
+                        108u8, 105u8, 98u8, 114u8, 97u8, 115u8, 32u8, 101u8, 115u8, 116u8, 101u8,
+                        114u8, 108u8, 105u8, 110u8, 97u8, 115u8, 114u8, 117u8, 112u8, 105u8, 97u8,
+                        115u8, 32u8, 105u8, 110u8, 100u8, 105u8, 97u8, 115u8, 121u8, 101u8, 110u8,
+                        101u8, 115u8, 32u8, 106u8, 97u8, 112u8, 111u8, 110u8, 101u8, 115u8, 101u8,
+                        115u8, 119u8, 111u8, 110u8, 115u8, 32u8, 115u8, 117u8, 114u8, 99u8, 111u8,
+                        114u8, 101u8, 97u8, 110u8, 111u8, 115u8, 100u8, 105u8, 110u8, 97u8, 114u8,
+                        101u8, 115u8, 32u8, 107u8, 117u8, 119u8, 97u8, 105u8, 116u8, 195u8, 173u8,
+                        101u8, 115u8, 112u8, 101u8, 115u8, 111u8, 115u8, 32u8, 109u8, 101u8, 120u8,
+                        105u8, 99u8, 97u8, 110u8, 111u8, 115u8, 112u8, 101u8, 115u8, 111u8, 115u8,
+                        32u8, 102u8, 105u8, 108u8, 105u8, 112u8, 105u8, 110u8, 111u8, 115u8, 100u8,
+                        105u8, 110u8, 97u8, 114u8, 101u8, 115u8, 32u8, 115u8, 101u8, 114u8, 98u8,
+                        105u8, 111u8, 115u8, 114u8, 117u8, 98u8, 108u8, 111u8, 115u8, 32u8, 114u8,
+                        117u8, 115u8, 111u8, 115u8, 99u8, 111u8, 114u8, 111u8, 110u8, 97u8, 115u8,
+                        32u8, 115u8, 117u8, 101u8, 99u8, 97u8, 115u8, 98u8, 97u8, 104u8, 116u8,
+                        115u8, 32u8, 116u8, 97u8, 105u8, 108u8, 97u8, 110u8, 100u8, 101u8, 115u8,
+                        101u8, 115u8, 108u8, 105u8, 114u8, 97u8, 115u8, 32u8, 116u8, 117u8, 114u8,
+                        99u8, 97u8, 115u8, 100u8, 195u8, 179u8, 108u8, 97u8, 114u8, 101u8, 115u8,
+                        32u8, 101u8, 115u8, 116u8, 97u8, 100u8, 111u8, 117u8, 110u8, 105u8, 100u8,
+                        101u8, 110u8, 115u8, 101u8, 115u8, 114u8, 97u8, 110u8, 100u8, 115u8, 32u8,
+                        115u8, 117u8, 100u8, 97u8, 102u8, 114u8, 105u8, 99u8, 97u8, 110u8, 111u8,
+                        115u8,
+                    ])
+                },
+            )
+        },
+    },
+};
+static FIL: &DataStruct = &::icu_currency::provider::CurrencyNamesV1 {
+    zero: None,
+    one: Some(::icu_currency::provider::CurrencyPluralNames {
+        pattern: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(" "),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 66u8, 82u8, 76u8, 67u8, 65u8, 68u8,
+                        67u8, 72u8, 70u8, 68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 69u8, 85u8, 82u8,
+                        71u8, 66u8, 80u8, 73u8, 78u8, 82u8, 75u8, 87u8, 68u8, 82u8, 83u8, 68u8,
+                        82u8, 85u8, 66u8, 83u8, 69u8, 75u8, 84u8, 82u8, 89u8, 85u8, 83u8, 68u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        16u8, 0u8, 0u8, 0u8, 0u8, 0u8, 19u8, 0u8, 35u8, 0u8, 49u8, 0u8, 65u8, 0u8,
+                        76u8, 0u8, 88u8, 0u8, 102u8, 0u8, 106u8, 0u8, 119u8, 0u8, 131u8, 0u8,
+                        144u8, 0u8, 159u8, 0u8, 174u8, 0u8, 187u8, 0u8, 199u8, 0u8, 100u8, 111u8,
+                        108u8, 121u8, 97u8, 114u8, 32u8, 110u8, 103u8, 32u8, 65u8, 117u8, 115u8,
+                        116u8, 114u8, 97u8, 108u8, 121u8, 97u8, 66u8, 97u8, 110u8, 103u8, 108u8,
+                        97u8, 100u8, 101u8, 115u8, 104u8, 105u8, 32u8, 116u8, 97u8, 107u8, 97u8,
+                        114u8, 101u8, 97u8, 108u8, 32u8, 110u8, 103u8, 32u8, 66u8, 114u8, 97u8,
+                        122u8, 105u8, 108u8, 100u8, 111u8, 108u8, 121u8, 97u8, 114u8, 32u8, 110u8,
+                        103u8, 32u8, 67u8, 97u8, 110u8, 97u8, 100u8, 97u8, 83u8, 119u8, 105u8,
+                        115u8, 115u8, 32u8, 102u8, 114u8, 97u8, 110u8, 99u8, 68u8, 97u8, 110u8,
+                        105u8, 115u8, 104u8, 32u8, 107u8, 114u8, 111u8, 110u8, 101u8, 112u8, 111u8,
+                        117u8, 110u8, 100u8, 32u8, 110u8, 103u8, 32u8, 69u8, 103u8, 121u8, 112u8,
+                        116u8, 101u8, 117u8, 114u8, 111u8, 66u8, 114u8, 105u8, 116u8, 105u8, 115u8,
+                        104u8, 32u8, 112u8, 111u8, 117u8, 110u8, 100u8, 73u8, 110u8, 100u8, 105u8,
+                        97u8, 110u8, 32u8, 114u8, 117u8, 112u8, 101u8, 101u8, 75u8, 117u8, 119u8,
+                        97u8, 105u8, 116u8, 105u8, 32u8, 100u8, 105u8, 110u8, 97u8, 114u8, 100u8,
+                        105u8, 110u8, 97u8, 114u8, 32u8, 110u8, 103u8, 32u8, 83u8, 101u8, 114u8,
+                        98u8, 105u8, 97u8, 114u8, 117u8, 98u8, 108u8, 101u8, 32u8, 110u8, 103u8,
+                        32u8, 82u8, 117u8, 115u8, 115u8, 105u8, 97u8, 83u8, 119u8, 101u8, 100u8,
+                        105u8, 115u8, 104u8, 32u8, 107u8, 114u8, 111u8, 110u8, 97u8, 84u8, 117u8,
+                        114u8, 107u8, 105u8, 115u8, 104u8, 32u8, 108u8, 105u8, 114u8, 97u8, 100u8,
+                        111u8, 108u8, 121u8, 97u8, 114u8, 32u8, 110u8, 103u8, 32u8, 85u8, 83u8,
+                    ])
+                },
+            )
+        },
+    }),
+    two: None,
+    few: None,
+    many: None,
+    other: ::icu_currency::provider::CurrencyPluralNames {
+        pattern: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(" "),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 66u8, 82u8, 76u8,
+                        67u8, 65u8, 68u8, 67u8, 72u8, 70u8, 67u8, 76u8, 80u8, 67u8, 78u8, 89u8,
+                        68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8,
+                        73u8, 78u8, 82u8, 74u8, 80u8, 89u8, 75u8, 82u8, 87u8, 75u8, 87u8, 68u8,
+                        77u8, 88u8, 78u8, 80u8, 72u8, 80u8, 82u8, 83u8, 68u8, 82u8, 85u8, 66u8,
+                        83u8, 69u8, 75u8, 84u8, 72u8, 66u8, 84u8, 82u8, 89u8, 85u8, 83u8, 68u8,
+                        90u8, 65u8, 82u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        25u8, 0u8, 0u8, 0u8, 0u8, 0u8, 17u8, 0u8, 38u8, 0u8, 55u8, 0u8, 70u8, 0u8,
+                        88u8, 0u8, 100u8, 0u8, 113u8, 0u8, 125u8, 0u8, 138u8, 0u8, 153u8, 0u8,
+                        158u8, 0u8, 172u8, 0u8, 185u8, 0u8, 197u8, 0u8, 215u8, 0u8, 229u8, 0u8,
+                        243u8, 0u8, 4u8, 1u8, 18u8, 1u8, 32u8, 1u8, 46u8, 1u8, 55u8, 1u8, 67u8,
+                        1u8, 81u8, 1u8, 112u8, 105u8, 115u8, 111u8, 32u8, 110u8, 103u8, 32u8, 65u8,
+                        114u8, 103u8, 101u8, 110u8, 116u8, 105u8, 110u8, 97u8, 100u8, 111u8, 108u8,
+                        121u8, 97u8, 114u8, 101u8, 115u8, 32u8, 110u8, 103u8, 32u8, 65u8, 117u8,
+                        115u8, 116u8, 114u8, 97u8, 108u8, 121u8, 97u8, 66u8, 97u8, 110u8, 103u8,
+                        108u8, 97u8, 100u8, 101u8, 115u8, 104u8, 105u8, 32u8, 116u8, 97u8, 107u8,
+                        97u8, 115u8, 114u8, 101u8, 97u8, 108u8, 115u8, 32u8, 110u8, 103u8, 32u8,
+                        66u8, 114u8, 97u8, 122u8, 105u8, 108u8, 68u8, 111u8, 108u8, 121u8, 97u8,
+                        114u8, 101u8, 115u8, 32u8, 110u8, 103u8, 32u8, 67u8, 97u8, 110u8, 97u8,
+                        100u8, 97u8, 83u8, 119u8, 105u8, 115u8, 115u8, 32u8, 102u8, 114u8, 97u8,
+                        110u8, 99u8, 115u8, 112u8, 105u8, 115u8, 111u8, 32u8, 110u8, 103u8, 32u8,
+                        67u8, 104u8, 105u8, 108u8, 101u8, 67u8, 104u8, 105u8, 110u8, 101u8, 115u8,
+                        101u8, 32u8, 121u8, 117u8, 97u8, 110u8, 68u8, 97u8, 110u8, 105u8, 115u8,
+                        104u8, 32u8, 107u8, 114u8, 111u8, 110u8, 101u8, 114u8, 69u8, 103u8, 121u8,
+                        112u8, 116u8, 105u8, 97u8, 110u8, 32u8, 112u8, 111u8, 117u8, 110u8, 100u8,
+                        115u8, 101u8, 117u8, 114u8, 111u8, 115u8, 66u8, 114u8, 105u8, 116u8, 105u8,
+                        115u8, 104u8, 32u8, 112u8, 111u8, 117u8, 110u8, 100u8, 115u8, 73u8, 110u8,
+                        100u8, 105u8, 97u8, 110u8, 32u8, 114u8, 117u8, 112u8, 101u8, 101u8, 115u8,
+                        74u8, 97u8, 112u8, 97u8, 110u8, 101u8, 115u8, 101u8, 32u8, 121u8, 101u8,
+                        110u8, 119u8, 111u8, 110u8, 32u8, 110u8, 103u8, 32u8, 84u8, 105u8, 109u8,
+                        111u8, 103u8, 32u8, 75u8, 111u8, 114u8, 101u8, 97u8, 75u8, 117u8, 119u8,
+                        97u8, 105u8, 116u8, 105u8, 32u8, 100u8, 105u8, 110u8, 97u8, 114u8, 115u8,
+                        112u8, 105u8, 115u8, 111u8, 32u8, 110u8, 103u8, 32u8, 77u8, 101u8, 120u8,
+                        105u8, 99u8, 111u8, 112u8, 105u8, 115u8, 111u8, 32u8, 110u8, 103u8, 32u8,
+                        80u8, 105u8, 108u8, 105u8, 112u8, 105u8, 110u8, 97u8, 115u8, 83u8, 101u8,
+                        114u8, 98u8, 105u8, 97u8, 110u8, 32u8, 100u8, 105u8, 110u8, 97u8, 114u8,
+                        115u8, 82u8, 117u8, 115u8, 115u8, 105u8, 97u8, 110u8, 32u8, 114u8, 117u8,
+                        98u8, 108u8, 101u8, 115u8, 83u8, 119u8, 101u8, 100u8, 105u8, 115u8, 104u8,
+                        32u8, 107u8, 114u8, 111u8, 110u8, 111u8, 114u8, 84u8, 104u8, 97u8, 105u8,
+                        32u8, 98u8, 97u8, 104u8, 116u8, 84u8, 117u8, 114u8, 107u8, 105u8, 115u8,
+                        104u8, 32u8, 76u8, 105u8, 114u8, 97u8, 100u8, 111u8, 108u8, 121u8, 97u8,
+                        114u8, 101u8, 115u8, 32u8, 110u8, 103u8, 32u8, 85u8, 83u8, 114u8, 97u8,
+                        110u8, 100u8, 32u8, 110u8, 103u8, 32u8, 84u8, 105u8, 109u8, 111u8, 103u8,
+                        32u8, 65u8, 102u8, 114u8, 105u8, 99u8, 97u8,
+                    ])
+                },
+            )
+        },
+    },
+};
+static FR: &DataStruct = &::icu_currency::provider::CurrencyNamesV1 {
+    zero: None,
+    one: Some(::icu_currency::provider::CurrencyPluralNames {
+        pattern: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(" "),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 66u8, 82u8, 76u8,
+                        67u8, 65u8, 68u8, 67u8, 72u8, 70u8, 67u8, 76u8, 80u8, 67u8, 78u8, 89u8,
+                        68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8,
+                        73u8, 78u8, 82u8, 74u8, 80u8, 89u8, 75u8, 82u8, 87u8, 75u8, 87u8, 68u8,
+                        77u8, 88u8, 78u8, 80u8, 72u8, 80u8, 82u8, 83u8, 68u8, 82u8, 85u8, 66u8,
+                        83u8, 69u8, 75u8, 84u8, 72u8, 66u8, 84u8, 82u8, 89u8, 85u8, 83u8, 68u8,
+                        90u8, 65u8, 82u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        25u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 30u8, 0u8, 46u8, 0u8, 62u8, 0u8,
+                        77u8, 0u8, 89u8, 0u8, 101u8, 0u8, 122u8, 0u8, 138u8, 0u8, 155u8, 0u8,
+                        159u8, 0u8, 173u8, 0u8, 188u8, 0u8, 200u8, 0u8, 215u8, 0u8, 231u8, 0u8,
+                        244u8, 0u8, 2u8, 1u8, 13u8, 1u8, 25u8, 1u8, 43u8, 1u8, 60u8, 1u8, 72u8,
+                        1u8, 94u8, 1u8, 112u8, 101u8, 115u8, 111u8, 32u8, 97u8, 114u8, 103u8,
+                        101u8, 110u8, 116u8, 105u8, 110u8, 100u8, 111u8, 108u8, 108u8, 97u8, 114u8,
+                        32u8, 97u8, 117u8, 115u8, 116u8, 114u8, 97u8, 108u8, 105u8, 101u8, 110u8,
+                        116u8, 97u8, 107u8, 97u8, 32u8, 98u8, 97u8, 110u8, 103u8, 108u8, 97u8,
+                        100u8, 101u8, 115u8, 104u8, 105u8, 114u8, 195u8, 169u8, 97u8, 108u8, 32u8,
+                        98u8, 114u8, 195u8, 169u8, 115u8, 105u8, 108u8, 105u8, 101u8, 110u8, 100u8,
+                        111u8, 108u8, 108u8, 97u8, 114u8, 32u8, 99u8, 97u8, 110u8, 97u8, 100u8,
+                        105u8, 101u8, 110u8, 102u8, 114u8, 97u8, 110u8, 99u8, 32u8, 115u8, 117u8,
+                        105u8, 115u8, 115u8, 101u8, 112u8, 101u8, 115u8, 111u8, 32u8, 99u8, 104u8,
+                        105u8, 108u8, 105u8, 101u8, 110u8, 121u8, 117u8, 97u8, 110u8, 32u8, 114u8,
+                        101u8, 110u8, 109u8, 105u8, 110u8, 98u8, 105u8, 32u8, 99u8, 104u8, 105u8,
+                        110u8, 111u8, 105u8, 115u8, 99u8, 111u8, 117u8, 114u8, 111u8, 110u8, 110u8,
+                        101u8, 32u8, 100u8, 97u8, 110u8, 111u8, 105u8, 115u8, 101u8, 108u8, 105u8,
+                        118u8, 114u8, 101u8, 32u8, 195u8, 169u8, 103u8, 121u8, 112u8, 116u8, 105u8,
+                        101u8, 110u8, 110u8, 101u8, 101u8, 117u8, 114u8, 111u8, 108u8, 105u8,
+                        118u8, 114u8, 101u8, 32u8, 115u8, 116u8, 101u8, 114u8, 108u8, 105u8, 110u8,
+                        103u8, 114u8, 111u8, 117u8, 112u8, 105u8, 101u8, 32u8, 105u8, 110u8, 100u8,
+                        105u8, 101u8, 110u8, 110u8, 101u8, 121u8, 101u8, 110u8, 32u8, 106u8, 97u8,
+                        112u8, 111u8, 110u8, 97u8, 105u8, 115u8, 119u8, 111u8, 110u8, 32u8, 115u8,
+                        117u8, 100u8, 45u8, 99u8, 111u8, 114u8, 195u8, 169u8, 101u8, 110u8, 100u8,
+                        105u8, 110u8, 97u8, 114u8, 32u8, 107u8, 111u8, 119u8, 101u8, 195u8, 175u8,
+                        116u8, 105u8, 101u8, 110u8, 112u8, 101u8, 115u8, 111u8, 32u8, 109u8, 101u8,
+                        120u8, 105u8, 99u8, 97u8, 105u8, 110u8, 112u8, 101u8, 115u8, 111u8, 32u8,
+                        112u8, 104u8, 105u8, 108u8, 105u8, 112u8, 112u8, 105u8, 110u8, 100u8,
+                        105u8, 110u8, 97u8, 114u8, 32u8, 115u8, 101u8, 114u8, 98u8, 101u8, 114u8,
+                        111u8, 117u8, 98u8, 108u8, 101u8, 32u8, 114u8, 117u8, 115u8, 115u8, 101u8,
+                        99u8, 111u8, 117u8, 114u8, 111u8, 110u8, 110u8, 101u8, 32u8, 115u8, 117u8,
+                        195u8, 169u8, 100u8, 111u8, 105u8, 115u8, 101u8, 98u8, 97u8, 104u8, 116u8,
+                        32u8, 116u8, 104u8, 97u8, 195u8, 175u8, 108u8, 97u8, 110u8, 100u8, 97u8,
+                        105u8, 115u8, 108u8, 105u8, 118u8, 114u8, 101u8, 32u8, 116u8, 117u8, 114u8,
+                        113u8, 117u8, 101u8, 100u8, 111u8, 108u8, 108u8, 97u8, 114u8, 32u8, 100u8,
+                        101u8, 115u8, 32u8, 195u8, 137u8, 116u8, 97u8, 116u8, 115u8, 45u8, 85u8,
+                        110u8, 105u8, 115u8, 114u8, 97u8, 110u8, 100u8, 32u8, 115u8, 117u8, 100u8,
+                        45u8, 97u8, 102u8, 114u8, 105u8, 99u8, 97u8, 105u8, 110u8,
+                    ])
+                },
+            )
+        },
+    }),
+    two: None,
+    few: None,
+    many: None,
+    other: ::icu_currency::provider::CurrencyPluralNames {
+        pattern: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(" "),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 66u8, 82u8, 76u8,
+                        67u8, 65u8, 68u8, 67u8, 72u8, 70u8, 67u8, 76u8, 80u8, 67u8, 78u8, 89u8,
+                        68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8,
+                        73u8, 78u8, 82u8, 74u8, 80u8, 89u8, 75u8, 82u8, 87u8, 75u8, 87u8, 68u8,
+                        77u8, 88u8, 78u8, 80u8, 72u8, 80u8, 82u8, 83u8, 68u8, 82u8, 85u8, 66u8,
+                        83u8, 69u8, 75u8, 84u8, 72u8, 66u8, 84u8, 82u8, 89u8, 85u8, 83u8, 68u8,
+                        90u8, 65u8, 82u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        25u8, 0u8, 0u8, 0u8, 0u8, 0u8, 15u8, 0u8, 34u8, 0u8, 52u8, 0u8, 70u8, 0u8,
+                        87u8, 0u8, 101u8, 0u8, 115u8, 0u8, 137u8, 0u8, 155u8, 0u8, 174u8, 0u8,
+                        179u8, 0u8, 194u8, 0u8, 211u8, 0u8, 224u8, 0u8, 241u8, 0u8, 2u8, 1u8, 17u8,
+                        1u8, 33u8, 1u8, 46u8, 1u8, 60u8, 1u8, 80u8, 1u8, 98u8, 1u8, 112u8, 1u8,
+                        135u8, 1u8, 112u8, 101u8, 115u8, 111u8, 115u8, 32u8, 97u8, 114u8, 103u8,
+                        101u8, 110u8, 116u8, 105u8, 110u8, 115u8, 100u8, 111u8, 108u8, 108u8, 97u8,
+                        114u8, 115u8, 32u8, 97u8, 117u8, 115u8, 116u8, 114u8, 97u8, 108u8, 105u8,
+                        101u8, 110u8, 115u8, 116u8, 97u8, 107u8, 97u8, 115u8, 32u8, 98u8, 97u8,
+                        110u8, 103u8, 108u8, 97u8, 100u8, 101u8, 115u8, 104u8, 105u8, 115u8, 114u8,
+                        195u8, 169u8, 97u8, 108u8, 115u8, 32u8, 98u8, 114u8, 195u8, 169u8, 115u8,
+                        105u8, 108u8, 105u8, 101u8, 110u8, 115u8, 100u8, 111u8, 108u8, 108u8, 97u8,
+                        114u8, 115u8, 32u8, 99u8, 97u8, 110u8, 97u8, 100u8, 105u8, 101u8, 110u8,
+                        115u8, 102u8, 114u8, 97u8, 110u8, 99u8, 115u8, 32u8, 115u8, 117u8, 105u8,
+                        115u8, 115u8, 101u8, 115u8, 112u8, 101u8, 115u8, 111u8, 115u8, 32u8, 99u8,
+                        104u8, 105u8, 108u8, 105u8, 101u8, 110u8, 115u8, 121u8, 117u8, 97u8, 110u8,
+                        115u8, 32u8, 114u8, 101u8, 110u8, 109u8, 105u8, 110u8, 98u8, 105u8, 32u8,
+                        99u8, 104u8, 105u8, 110u8, 111u8, 105u8, 115u8, 99u8, 111u8, 117u8, 114u8,
+                        111u8, 110u8, 110u8, 101u8, 115u8, 32u8, 100u8, 97u8, 110u8, 111u8, 105u8,
+                        115u8, 101u8, 115u8, 108u8, 105u8, 118u8, 114u8, 101u8, 115u8, 32u8, 195u8,
+                        169u8, 103u8, 121u8, 112u8, 116u8, 105u8, 101u8, 110u8, 110u8, 101u8,
+                        115u8, 101u8, 117u8, 114u8, 111u8, 115u8, 108u8, 105u8, 118u8, 114u8,
+                        101u8, 115u8, 32u8, 115u8, 116u8, 101u8, 114u8, 108u8, 105u8, 110u8, 103u8,
+                        114u8, 111u8, 117u8, 112u8, 105u8, 101u8, 115u8, 32u8, 105u8, 110u8, 100u8,
+                        105u8, 101u8, 110u8, 110u8, 101u8, 115u8, 121u8, 101u8, 110u8, 115u8, 32u8,
+                        106u8, 97u8, 112u8, 111u8, 110u8, 97u8, 105u8, 115u8, 119u8, 111u8, 110u8,
+                        115u8, 32u8, 115u8, 117u8, 100u8, 45u8, 99u8, 111u8, 114u8, 195u8, 169u8,
+                        101u8, 110u8, 115u8, 100u8, 105u8, 110u8, 97u8, 114u8, 32u8, 107u8, 111u8,
+                        119u8, 101u8, 195u8, 175u8, 116u8, 105u8, 101u8, 110u8, 115u8, 112u8,
+                        101u8, 115u8, 111u8, 115u8, 32u8, 109u8, 101u8, 120u8, 105u8, 99u8, 97u8,
+                        105u8, 110u8, 115u8, 112u8, 101u8, 115u8, 111u8, 115u8, 32u8, 112u8, 104u8,
+                        105u8, 108u8, 105u8, 112u8, 112u8, 105u8, 110u8, 115u8, 100u8, 105u8,
+                        110u8, 97u8, 114u8, 115u8, 32u8, 115u8, 101u8, 114u8, 98u8, 101u8, 115u8,
+                        114u8, 111u8, 117u8, 98u8, 108u8, 101u8, 115u8, 32u8, 114u8, 117u8, 115u8,
+                        115u8, 101u8, 115u8, 99u8, 111u8, 117u8, 114u8, 111u8, 110u8, 110u8, 101u8,
+                        115u8, 32u8, 115u8, 117u8, 195u8, 169u8, 100u8, 111u8, 105u8, 115u8, 101u8,
+                        115u8, 98u8, 97u8, 104u8, 116u8, 115u8, 32u8, 116u8, 104u8, 97u8, 195u8,
+                        175u8, 108u8, 97u8, 110u8, 100u8, 97u8, 105u8, 115u8, 108u8, 105u8, 118u8,
+                        114u8, 101u8, 115u8, 32u8, 116u8, 117u8, 114u8, 113u8, 117u8, 101u8, 115u8,
+                        100u8, 111u8, 108u8, 108u8, 97u8, 114u8, 115u8, 32u8, 100u8, 101u8, 115u8,
+                        32u8, 195u8, 137u8, 116u8, 97u8, 116u8, 115u8, 45u8, 85u8, 110u8, 105u8,
+                        115u8, 114u8, 97u8, 110u8, 100u8, 115u8, 32u8, 115u8, 117u8, 100u8, 45u8,
+                        97u8, 102u8, 114u8, 105u8, 99u8, 97u8, 105u8, 110u8, 115u8,
+                    ])
+                },
+            )
+        },
+    },
+};
+static JA: &DataStruct = &::icu_currency::provider::CurrencyNamesV1 {
+    zero: None,
+    one: None,
+    two: None,
+    few: None,
+    many: None,
+    other: ::icu_currency::provider::CurrencyPluralNames {
+        pattern: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 66u8, 82u8, 76u8,
+                        67u8, 65u8, 68u8, 67u8, 72u8, 70u8, 67u8, 76u8, 80u8, 67u8, 78u8, 89u8,
+                        68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8,
+                        73u8, 78u8, 82u8, 74u8, 80u8, 89u8, 75u8, 82u8, 87u8, 75u8, 87u8, 68u8,
+                        77u8, 88u8, 78u8, 80u8, 72u8, 80u8, 82u8, 83u8, 68u8, 82u8, 85u8, 66u8,
+                        83u8, 69u8, 75u8, 84u8, 72u8, 66u8, 84u8, 82u8, 89u8, 85u8, 83u8, 68u8,
+                        90u8, 65u8, 82u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        25u8, 0u8, 0u8, 0u8, 0u8, 0u8, 25u8, 0u8, 53u8, 0u8, 81u8, 0u8, 103u8, 0u8,
+                        119u8, 0u8, 138u8, 0u8, 151u8, 0u8, 166u8, 0u8, 194u8, 0u8, 216u8, 0u8,
+                        225u8, 0u8, 240u8, 0u8, 3u8, 1u8, 6u8, 1u8, 21u8, 1u8, 52u8, 1u8, 71u8,
+                        1u8, 93u8, 1u8, 121u8, 1u8, 143u8, 1u8, 174u8, 1u8, 190u8, 1u8, 206u8, 1u8,
+                        215u8, 1u8, 227u8, 130u8, 162u8, 227u8, 131u8, 171u8, 227u8, 130u8, 188u8,
+                        227u8, 131u8, 179u8, 227u8, 131u8, 129u8, 227u8, 131u8, 179u8, 32u8, 227u8,
+                        131u8, 154u8, 227u8, 130u8, 189u8, 227u8, 130u8, 170u8, 227u8, 131u8,
+                        188u8, 227u8, 130u8, 185u8, 227u8, 131u8, 136u8, 227u8, 131u8, 169u8,
+                        227u8, 131u8, 170u8, 227u8, 130u8, 162u8, 32u8, 227u8, 131u8, 137u8, 227u8,
+                        131u8, 171u8, 227u8, 131u8, 144u8, 227u8, 131u8, 179u8, 227u8, 130u8,
+                        176u8, 227u8, 131u8, 169u8, 227u8, 131u8, 135u8, 227u8, 130u8, 183u8,
+                        227u8, 131u8, 165u8, 32u8, 227u8, 130u8, 191u8, 227u8, 130u8, 171u8, 227u8,
+                        131u8, 150u8, 227u8, 131u8, 169u8, 227u8, 130u8, 184u8, 227u8, 131u8,
+                        171u8, 32u8, 227u8, 131u8, 172u8, 227u8, 130u8, 162u8, 227u8, 131u8, 171u8,
+                        227u8, 130u8, 171u8, 227u8, 131u8, 138u8, 227u8, 131u8, 128u8, 32u8, 227u8,
+                        131u8, 137u8, 227u8, 131u8, 171u8, 227u8, 130u8, 185u8, 227u8, 130u8,
+                        164u8, 227u8, 130u8, 185u8, 32u8, 227u8, 131u8, 149u8, 227u8, 131u8, 169u8,
+                        227u8, 131u8, 179u8, 227u8, 131u8, 129u8, 227u8, 131u8, 170u8, 32u8, 227u8,
+                        131u8, 154u8, 227u8, 130u8, 189u8, 228u8, 184u8, 173u8, 229u8, 155u8,
+                        189u8, 228u8, 186u8, 186u8, 230u8, 176u8, 145u8, 229u8, 133u8, 131u8,
+                        227u8, 131u8, 135u8, 227u8, 131u8, 179u8, 227u8, 131u8, 158u8, 227u8,
+                        131u8, 188u8, 227u8, 130u8, 175u8, 32u8, 227u8, 130u8, 175u8, 227u8, 131u8,
+                        173u8, 227u8, 131u8, 188u8, 227u8, 131u8, 141u8, 227u8, 130u8, 168u8,
+                        227u8, 130u8, 184u8, 227u8, 131u8, 151u8, 227u8, 131u8, 136u8, 32u8, 227u8,
+                        131u8, 157u8, 227u8, 131u8, 179u8, 227u8, 131u8, 137u8, 227u8, 131u8,
+                        166u8, 227u8, 131u8, 188u8, 227u8, 131u8, 173u8, 232u8, 139u8, 177u8,
+                        229u8, 155u8, 189u8, 227u8, 131u8, 157u8, 227u8, 131u8, 179u8, 227u8,
+                        131u8, 137u8, 227u8, 130u8, 164u8, 227u8, 131u8, 179u8, 227u8, 131u8,
+                        137u8, 32u8, 227u8, 131u8, 171u8, 227u8, 131u8, 148u8, 227u8, 131u8, 188u8,
+                        229u8, 134u8, 134u8, 233u8, 159u8, 147u8, 229u8, 155u8, 189u8, 227u8,
+                        130u8, 166u8, 227u8, 130u8, 169u8, 227u8, 131u8, 179u8, 227u8, 130u8,
+                        175u8, 227u8, 130u8, 166u8, 227u8, 130u8, 167u8, 227u8, 131u8, 188u8,
+                        227u8, 131u8, 136u8, 32u8, 227u8, 131u8, 135u8, 227u8, 130u8, 163u8, 227u8,
+                        131u8, 138u8, 227u8, 131u8, 188u8, 227u8, 131u8, 171u8, 227u8, 131u8,
+                        161u8, 227u8, 130u8, 173u8, 227u8, 130u8, 183u8, 227u8, 130u8, 179u8, 32u8,
+                        227u8, 131u8, 154u8, 227u8, 130u8, 189u8, 227u8, 131u8, 149u8, 227u8,
+                        130u8, 163u8, 227u8, 131u8, 170u8, 227u8, 131u8, 148u8, 227u8, 131u8,
+                        179u8, 32u8, 227u8, 131u8, 154u8, 227u8, 130u8, 189u8, 227u8, 130u8, 187u8,
+                        227u8, 131u8, 171u8, 227u8, 131u8, 147u8, 227u8, 130u8, 162u8, 32u8, 227u8,
+                        131u8, 135u8, 227u8, 130u8, 163u8, 227u8, 131u8, 138u8, 227u8, 131u8,
+                        188u8, 227u8, 131u8, 171u8, 227u8, 131u8, 173u8, 227u8, 130u8, 183u8,
+                        227u8, 130u8, 162u8, 32u8, 227u8, 131u8, 171u8, 227u8, 131u8, 188u8, 227u8,
+                        131u8, 150u8, 227u8, 131u8, 171u8, 227u8, 130u8, 185u8, 227u8, 130u8,
+                        166u8, 227u8, 130u8, 167u8, 227u8, 131u8, 188u8, 227u8, 131u8, 135u8,
+                        227u8, 131u8, 179u8, 32u8, 227u8, 130u8, 175u8, 227u8, 131u8, 173u8, 227u8,
+                        131u8, 188u8, 227u8, 131u8, 138u8, 227u8, 130u8, 191u8, 227u8, 130u8,
+                        164u8, 32u8, 227u8, 131u8, 144u8, 227u8, 131u8, 188u8, 227u8, 131u8, 132u8,
+                        227u8, 131u8, 136u8, 227u8, 131u8, 171u8, 227u8, 130u8, 179u8, 32u8, 227u8,
+                        131u8, 170u8, 227u8, 131u8, 169u8, 231u8, 177u8, 179u8, 227u8, 131u8,
+                        137u8, 227u8, 131u8, 171u8, 229u8, 141u8, 151u8, 227u8, 130u8, 162u8,
+                        227u8, 131u8, 149u8, 227u8, 131u8, 170u8, 227u8, 130u8, 171u8, 32u8, 227u8,
+                        131u8, 169u8, 227u8, 131u8, 179u8, 227u8, 131u8, 137u8,
+                    ])
+                },
+            )
+        },
+    },
+};
+static RU: &DataStruct = &::icu_currency::provider::CurrencyNamesV1 {
+    zero: None,
+    one: Some(::icu_currency::provider::CurrencyPluralNames {
+        pattern: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(" "),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 66u8, 82u8, 76u8,
+                        67u8, 65u8, 68u8, 67u8, 72u8, 70u8, 67u8, 76u8, 80u8, 67u8, 78u8, 89u8,
+                        68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 71u8, 66u8, 80u8, 73u8, 78u8, 82u8,
+                        74u8, 80u8, 89u8, 75u8, 82u8, 87u8, 75u8, 87u8, 68u8, 77u8, 88u8, 78u8,
+                        80u8, 72u8, 80u8, 82u8, 83u8, 68u8, 82u8, 85u8, 66u8, 83u8, 69u8, 75u8,
+                        84u8, 72u8, 66u8, 84u8, 82u8, 89u8, 85u8, 83u8, 68u8, 90u8, 65u8, 82u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 33u8, 0u8, 72u8, 0u8, 107u8, 0u8, 138u8,
+                        0u8, 169u8, 0u8, 202u8, 0u8, 229u8, 0u8, 0u8, 1u8, 25u8, 1u8, 54u8, 1u8,
+                        104u8, 1u8, 133u8, 1u8, 158u8, 1u8, 193u8, 1u8, 224u8, 1u8, 1u8, 2u8, 34u8,
+                        2u8, 61u8, 2u8, 92u8, 2u8, 119u8, 2u8, 148u8, 2u8, 173u8, 2u8, 192u8, 2u8,
+                        208u8, 176u8, 209u8, 128u8, 208u8, 179u8, 208u8, 181u8, 208u8, 189u8,
+                        209u8, 130u8, 208u8, 184u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8,
+                        208u8, 184u8, 208u8, 185u8, 32u8, 208u8, 191u8, 208u8, 181u8, 209u8, 129u8,
+                        208u8, 190u8, 208u8, 176u8, 208u8, 178u8, 209u8, 129u8, 209u8, 130u8,
+                        209u8, 128u8, 208u8, 176u8, 208u8, 187u8, 208u8, 184u8, 208u8, 185u8,
+                        209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 32u8, 208u8, 180u8,
+                        208u8, 190u8, 208u8, 187u8, 208u8, 187u8, 208u8, 176u8, 209u8, 128u8,
+                        208u8, 177u8, 208u8, 176u8, 208u8, 189u8, 208u8, 179u8, 208u8, 187u8,
+                        208u8, 176u8, 208u8, 180u8, 208u8, 181u8, 209u8, 136u8, 209u8, 129u8,
+                        208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 32u8, 209u8, 130u8, 208u8, 176u8,
+                        208u8, 186u8, 208u8, 176u8, 208u8, 177u8, 209u8, 128u8, 208u8, 176u8,
+                        208u8, 183u8, 208u8, 184u8, 208u8, 187u8, 209u8, 140u8, 209u8, 129u8,
+                        208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 32u8, 209u8, 128u8, 208u8, 181u8,
+                        208u8, 176u8, 208u8, 187u8, 208u8, 186u8, 208u8, 176u8, 208u8, 189u8,
+                        208u8, 176u8, 208u8, 180u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                        208u8, 185u8, 32u8, 208u8, 180u8, 208u8, 190u8, 208u8, 187u8, 208u8, 187u8,
+                        208u8, 176u8, 209u8, 128u8, 209u8, 136u8, 208u8, 178u8, 208u8, 181u8,
+                        208u8, 185u8, 209u8, 134u8, 208u8, 176u8, 209u8, 128u8, 209u8, 129u8,
+                        208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 32u8, 209u8, 132u8, 209u8, 128u8,
+                        208u8, 176u8, 208u8, 189u8, 208u8, 186u8, 209u8, 135u8, 208u8, 184u8,
+                        208u8, 187u8, 208u8, 184u8, 208u8, 185u8, 209u8, 129u8, 208u8, 186u8,
+                        208u8, 184u8, 208u8, 185u8, 32u8, 208u8, 191u8, 208u8, 181u8, 209u8, 129u8,
+                        208u8, 190u8, 208u8, 186u8, 208u8, 184u8, 209u8, 130u8, 208u8, 176u8,
+                        208u8, 185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 32u8,
+                        209u8, 142u8, 208u8, 176u8, 208u8, 189u8, 209u8, 140u8, 208u8, 180u8,
+                        208u8, 176u8, 209u8, 130u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8,
+                        209u8, 143u8, 32u8, 208u8, 186u8, 209u8, 128u8, 208u8, 190u8, 208u8, 189u8,
+                        208u8, 176u8, 208u8, 181u8, 208u8, 179u8, 208u8, 184u8, 208u8, 191u8,
+                        208u8, 181u8, 209u8, 130u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                        208u8, 185u8, 32u8, 209u8, 132u8, 209u8, 131u8, 208u8, 189u8, 209u8, 130u8,
+                        208u8, 177u8, 209u8, 128u8, 208u8, 184u8, 209u8, 130u8, 208u8, 176u8,
+                        208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 32u8,
+                        209u8, 132u8, 209u8, 131u8, 208u8, 189u8, 209u8, 130u8, 32u8, 209u8, 129u8,
+                        209u8, 130u8, 208u8, 181u8, 209u8, 128u8, 208u8, 187u8, 208u8, 184u8,
+                        208u8, 189u8, 208u8, 179u8, 208u8, 190u8, 208u8, 178u8, 208u8, 184u8,
+                        208u8, 189u8, 208u8, 180u8, 208u8, 184u8, 208u8, 185u8, 209u8, 129u8,
+                        208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 32u8, 209u8, 128u8, 209u8, 131u8,
+                        208u8, 191u8, 208u8, 184u8, 209u8, 143u8, 209u8, 143u8, 208u8, 191u8,
+                        208u8, 190u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8,
+                        209u8, 143u8, 32u8, 208u8, 184u8, 208u8, 181u8, 208u8, 189u8, 208u8, 176u8,
+                        209u8, 142u8, 208u8, 182u8, 208u8, 189u8, 208u8, 190u8, 208u8, 186u8,
+                        208u8, 190u8, 209u8, 128u8, 208u8, 181u8, 208u8, 185u8, 209u8, 129u8,
+                        208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 32u8, 208u8, 178u8, 208u8, 190u8,
+                        208u8, 189u8, 208u8, 176u8, 208u8, 186u8, 209u8, 131u8, 208u8, 178u8,
+                        208u8, 181u8, 208u8, 185u8, 209u8, 130u8, 209u8, 129u8, 208u8, 186u8,
+                        208u8, 184u8, 208u8, 185u8, 32u8, 208u8, 180u8, 208u8, 184u8, 208u8, 189u8,
+                        208u8, 176u8, 209u8, 128u8, 208u8, 188u8, 208u8, 181u8, 208u8, 186u8,
+                        209u8, 129u8, 208u8, 184u8, 208u8, 186u8, 208u8, 176u8, 208u8, 189u8,
+                        209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 32u8, 208u8, 191u8,
+                        208u8, 181u8, 209u8, 129u8, 208u8, 190u8, 209u8, 132u8, 208u8, 184u8,
+                        208u8, 187u8, 208u8, 184u8, 208u8, 191u8, 208u8, 191u8, 208u8, 184u8,
+                        208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 32u8,
+                        208u8, 191u8, 208u8, 181u8, 209u8, 129u8, 208u8, 190u8, 209u8, 129u8,
+                        208u8, 181u8, 209u8, 128u8, 208u8, 177u8, 209u8, 129u8, 208u8, 186u8,
+                        208u8, 184u8, 208u8, 185u8, 32u8, 208u8, 180u8, 208u8, 184u8, 208u8, 189u8,
+                        208u8, 176u8, 209u8, 128u8, 209u8, 128u8, 208u8, 190u8, 209u8, 129u8,
+                        209u8, 129u8, 208u8, 184u8, 208u8, 185u8, 209u8, 129u8, 208u8, 186u8,
+                        208u8, 184u8, 208u8, 185u8, 32u8, 209u8, 128u8, 209u8, 131u8, 208u8, 177u8,
+                        208u8, 187u8, 209u8, 140u8, 209u8, 136u8, 208u8, 178u8, 208u8, 181u8,
+                        208u8, 180u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 32u8,
+                        208u8, 186u8, 209u8, 128u8, 208u8, 190u8, 208u8, 189u8, 208u8, 176u8,
+                        209u8, 130u8, 208u8, 176u8, 208u8, 184u8, 208u8, 187u8, 208u8, 176u8,
+                        208u8, 189u8, 208u8, 180u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                        208u8, 185u8, 32u8, 208u8, 177u8, 208u8, 176u8, 209u8, 130u8, 209u8, 130u8,
+                        209u8, 131u8, 209u8, 128u8, 208u8, 181u8, 209u8, 134u8, 208u8, 186u8,
+                        208u8, 176u8, 209u8, 143u8, 32u8, 208u8, 187u8, 208u8, 184u8, 209u8, 128u8,
+                        208u8, 176u8, 208u8, 180u8, 208u8, 190u8, 208u8, 187u8, 208u8, 187u8,
+                        208u8, 176u8, 209u8, 128u8, 32u8, 208u8, 161u8, 208u8, 168u8, 208u8, 144u8,
+                        209u8, 142u8, 208u8, 182u8, 208u8, 189u8, 208u8, 190u8, 208u8, 176u8,
+                        209u8, 132u8, 209u8, 128u8, 208u8, 184u8, 208u8, 186u8, 208u8, 176u8,
+                        208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 32u8,
+                        209u8, 128u8, 209u8, 141u8, 208u8, 189u8, 208u8, 180u8,
+                    ])
+                },
+            )
+        },
+    }),
+    two: None,
+    few: Some(::icu_currency::provider::CurrencyPluralNames {
+        pattern: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(" "),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 66u8, 82u8, 76u8,
+                        67u8, 65u8, 68u8, 67u8, 72u8, 70u8, 67u8, 76u8, 80u8, 67u8, 78u8, 89u8,
+                        68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 71u8, 66u8, 80u8, 73u8, 78u8, 82u8,
+                        74u8, 80u8, 89u8, 75u8, 82u8, 87u8, 75u8, 87u8, 68u8, 77u8, 88u8, 78u8,
+                        80u8, 72u8, 80u8, 82u8, 83u8, 68u8, 82u8, 85u8, 66u8, 83u8, 69u8, 75u8,
+                        84u8, 72u8, 66u8, 84u8, 82u8, 89u8, 90u8, 65u8, 82u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        23u8, 0u8, 0u8, 0u8, 0u8, 0u8, 33u8, 0u8, 74u8, 0u8, 109u8, 0u8, 142u8,
+                        0u8, 175u8, 0u8, 210u8, 0u8, 237u8, 0u8, 8u8, 1u8, 33u8, 1u8, 64u8, 1u8,
+                        116u8, 1u8, 145u8, 1u8, 170u8, 1u8, 205u8, 1u8, 238u8, 1u8, 15u8, 2u8,
+                        48u8, 2u8, 77u8, 2u8, 108u8, 2u8, 135u8, 2u8, 166u8, 2u8, 191u8, 2u8,
+                        208u8, 176u8, 209u8, 128u8, 208u8, 179u8, 208u8, 181u8, 208u8, 189u8,
+                        209u8, 130u8, 208u8, 184u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8,
+                        208u8, 184u8, 209u8, 133u8, 32u8, 208u8, 191u8, 208u8, 181u8, 209u8, 129u8,
+                        208u8, 190u8, 208u8, 176u8, 208u8, 178u8, 209u8, 129u8, 209u8, 130u8,
+                        209u8, 128u8, 208u8, 176u8, 208u8, 187u8, 208u8, 184u8, 208u8, 185u8,
+                        209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 208u8, 180u8,
+                        208u8, 190u8, 208u8, 187u8, 208u8, 187u8, 208u8, 176u8, 209u8, 128u8,
+                        208u8, 176u8, 208u8, 177u8, 208u8, 176u8, 208u8, 189u8, 208u8, 179u8,
+                        208u8, 187u8, 208u8, 176u8, 208u8, 180u8, 208u8, 181u8, 209u8, 136u8,
+                        209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 181u8, 32u8, 209u8, 130u8,
+                        208u8, 176u8, 208u8, 186u8, 208u8, 184u8, 208u8, 177u8, 209u8, 128u8,
+                        208u8, 176u8, 208u8, 183u8, 208u8, 184u8, 208u8, 187u8, 209u8, 140u8,
+                        209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 209u8, 128u8,
+                        208u8, 181u8, 208u8, 176u8, 208u8, 187u8, 208u8, 176u8, 208u8, 186u8,
+                        208u8, 176u8, 208u8, 189u8, 208u8, 176u8, 208u8, 180u8, 209u8, 129u8,
+                        208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 208u8, 180u8, 208u8, 190u8,
+                        208u8, 187u8, 208u8, 187u8, 208u8, 176u8, 209u8, 128u8, 208u8, 176u8,
+                        209u8, 136u8, 208u8, 178u8, 208u8, 181u8, 208u8, 185u8, 209u8, 134u8,
+                        208u8, 176u8, 209u8, 128u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                        209u8, 133u8, 32u8, 209u8, 132u8, 209u8, 128u8, 208u8, 176u8, 208u8, 189u8,
+                        208u8, 186u8, 208u8, 176u8, 209u8, 135u8, 208u8, 184u8, 208u8, 187u8,
+                        208u8, 184u8, 208u8, 185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                        209u8, 133u8, 32u8, 208u8, 191u8, 208u8, 181u8, 209u8, 129u8, 208u8, 190u8,
+                        208u8, 186u8, 208u8, 184u8, 209u8, 130u8, 208u8, 176u8, 208u8, 185u8,
+                        209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 209u8, 142u8,
+                        208u8, 176u8, 208u8, 189u8, 209u8, 143u8, 208u8, 180u8, 208u8, 176u8,
+                        209u8, 130u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 181u8, 32u8,
+                        208u8, 186u8, 209u8, 128u8, 208u8, 190u8, 208u8, 189u8, 209u8, 139u8,
+                        208u8, 181u8, 208u8, 179u8, 208u8, 184u8, 208u8, 191u8, 208u8, 181u8,
+                        209u8, 130u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8,
+                        209u8, 132u8, 209u8, 131u8, 208u8, 189u8, 209u8, 130u8, 208u8, 176u8,
+                        208u8, 177u8, 209u8, 128u8, 208u8, 184u8, 209u8, 130u8, 208u8, 176u8,
+                        208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8,
+                        209u8, 132u8, 209u8, 131u8, 208u8, 189u8, 209u8, 130u8, 208u8, 176u8, 32u8,
+                        209u8, 129u8, 209u8, 130u8, 208u8, 181u8, 209u8, 128u8, 208u8, 187u8,
+                        208u8, 184u8, 208u8, 189u8, 208u8, 179u8, 208u8, 190u8, 208u8, 178u8,
+                        208u8, 184u8, 208u8, 189u8, 208u8, 180u8, 208u8, 184u8, 208u8, 185u8,
+                        209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 181u8, 32u8, 209u8, 128u8,
+                        209u8, 131u8, 208u8, 191u8, 208u8, 184u8, 208u8, 184u8, 209u8, 143u8,
+                        208u8, 191u8, 208u8, 190u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8,
+                        208u8, 184u8, 208u8, 181u8, 32u8, 208u8, 184u8, 208u8, 181u8, 208u8, 189u8,
+                        209u8, 139u8, 209u8, 142u8, 208u8, 182u8, 208u8, 189u8, 208u8, 190u8,
+                        208u8, 186u8, 208u8, 190u8, 209u8, 128u8, 208u8, 181u8, 208u8, 185u8,
+                        209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 181u8, 32u8, 208u8, 178u8,
+                        208u8, 190u8, 208u8, 189u8, 209u8, 139u8, 208u8, 186u8, 209u8, 131u8,
+                        208u8, 178u8, 208u8, 181u8, 208u8, 185u8, 209u8, 130u8, 209u8, 129u8,
+                        208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 208u8, 180u8, 208u8, 184u8,
+                        208u8, 189u8, 208u8, 176u8, 209u8, 128u8, 208u8, 176u8, 208u8, 188u8,
+                        208u8, 181u8, 208u8, 186u8, 209u8, 129u8, 208u8, 184u8, 208u8, 186u8,
+                        208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                        209u8, 133u8, 32u8, 208u8, 191u8, 208u8, 181u8, 209u8, 129u8, 208u8, 190u8,
+                        209u8, 132u8, 208u8, 184u8, 208u8, 187u8, 208u8, 184u8, 208u8, 191u8,
+                        208u8, 191u8, 208u8, 184u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8,
+                        208u8, 184u8, 209u8, 133u8, 32u8, 208u8, 191u8, 208u8, 181u8, 209u8, 129u8,
+                        208u8, 190u8, 209u8, 129u8, 208u8, 181u8, 209u8, 128u8, 208u8, 177u8,
+                        209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 208u8, 180u8,
+                        208u8, 184u8, 208u8, 189u8, 208u8, 176u8, 209u8, 128u8, 208u8, 176u8,
+                        209u8, 128u8, 208u8, 190u8, 209u8, 129u8, 209u8, 129u8, 208u8, 184u8,
+                        208u8, 185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8,
+                        209u8, 128u8, 209u8, 131u8, 208u8, 177u8, 208u8, 187u8, 209u8, 143u8,
+                        209u8, 136u8, 208u8, 178u8, 208u8, 181u8, 208u8, 180u8, 209u8, 129u8,
+                        208u8, 186u8, 208u8, 184u8, 208u8, 181u8, 32u8, 208u8, 186u8, 209u8, 128u8,
+                        208u8, 190u8, 208u8, 189u8, 209u8, 139u8, 209u8, 130u8, 208u8, 176u8,
+                        208u8, 184u8, 208u8, 187u8, 208u8, 176u8, 208u8, 189u8, 208u8, 180u8,
+                        209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 208u8, 177u8,
+                        208u8, 176u8, 209u8, 130u8, 208u8, 176u8, 209u8, 130u8, 209u8, 131u8,
+                        209u8, 128u8, 208u8, 181u8, 209u8, 134u8, 208u8, 186u8, 208u8, 184u8,
+                        208u8, 181u8, 32u8, 208u8, 187u8, 208u8, 184u8, 209u8, 128u8, 209u8, 139u8,
+                        209u8, 142u8, 208u8, 182u8, 208u8, 189u8, 208u8, 190u8, 208u8, 176u8,
+                        209u8, 132u8, 209u8, 128u8, 208u8, 184u8, 208u8, 186u8, 208u8, 176u8,
+                        208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8,
+                        209u8, 128u8, 209u8, 141u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8,
+                    ])
+                },
+            )
+        },
+    }),
+    many: Some(::icu_currency::provider::CurrencyPluralNames {
+        pattern: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(" "),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 66u8, 82u8, 76u8,
+                        67u8, 65u8, 68u8, 67u8, 72u8, 70u8, 67u8, 76u8, 80u8, 67u8, 78u8, 89u8,
+                        68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 71u8, 66u8, 80u8, 73u8, 78u8, 82u8,
+                        74u8, 80u8, 89u8, 75u8, 82u8, 87u8, 75u8, 87u8, 68u8, 77u8, 88u8, 78u8,
+                        80u8, 72u8, 80u8, 82u8, 83u8, 68u8, 82u8, 85u8, 66u8, 83u8, 69u8, 75u8,
+                        84u8, 72u8, 66u8, 84u8, 82u8, 89u8, 85u8, 83u8, 68u8, 90u8, 65u8, 82u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 33u8, 0u8, 76u8, 0u8, 109u8, 0u8, 144u8,
+                        0u8, 179u8, 0u8, 216u8, 0u8, 243u8, 0u8, 16u8, 1u8, 39u8, 1u8, 72u8, 1u8,
+                        126u8, 1u8, 155u8, 1u8, 178u8, 1u8, 211u8, 1u8, 246u8, 1u8, 23u8, 2u8,
+                        56u8, 2u8, 87u8, 2u8, 120u8, 2u8, 145u8, 2u8, 178u8, 2u8, 201u8, 2u8,
+                        224u8, 2u8, 208u8, 176u8, 209u8, 128u8, 208u8, 179u8, 208u8, 181u8, 208u8,
+                        189u8, 209u8, 130u8, 208u8, 184u8, 208u8, 189u8, 209u8, 129u8, 208u8,
+                        186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 208u8, 191u8, 208u8, 181u8, 209u8,
+                        129u8, 208u8, 190u8, 208u8, 176u8, 208u8, 178u8, 209u8, 129u8, 209u8,
+                        130u8, 209u8, 128u8, 208u8, 176u8, 208u8, 187u8, 208u8, 184u8, 208u8,
+                        185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 208u8,
+                        180u8, 208u8, 190u8, 208u8, 187u8, 208u8, 187u8, 208u8, 176u8, 209u8,
+                        128u8, 208u8, 190u8, 208u8, 178u8, 208u8, 177u8, 208u8, 176u8, 208u8,
+                        189u8, 208u8, 179u8, 208u8, 187u8, 208u8, 176u8, 208u8, 180u8, 208u8,
+                        181u8, 209u8, 136u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 209u8,
+                        133u8, 32u8, 209u8, 130u8, 208u8, 176u8, 208u8, 186u8, 208u8, 177u8, 209u8,
+                        128u8, 208u8, 176u8, 208u8, 183u8, 208u8, 184u8, 208u8, 187u8, 209u8,
+                        140u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 209u8,
+                        128u8, 208u8, 181u8, 208u8, 176u8, 208u8, 187u8, 208u8, 190u8, 208u8,
+                        178u8, 208u8, 186u8, 208u8, 176u8, 208u8, 189u8, 208u8, 176u8, 208u8,
+                        180u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 208u8,
+                        180u8, 208u8, 190u8, 208u8, 187u8, 208u8, 187u8, 208u8, 176u8, 209u8,
+                        128u8, 208u8, 190u8, 208u8, 178u8, 209u8, 136u8, 208u8, 178u8, 208u8,
+                        181u8, 208u8, 185u8, 209u8, 134u8, 208u8, 176u8, 209u8, 128u8, 209u8,
+                        129u8, 208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 209u8, 132u8, 209u8,
+                        128u8, 208u8, 176u8, 208u8, 189u8, 208u8, 186u8, 208u8, 190u8, 208u8,
+                        178u8, 209u8, 135u8, 208u8, 184u8, 208u8, 187u8, 208u8, 184u8, 208u8,
+                        185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 208u8,
+                        191u8, 208u8, 181u8, 209u8, 129u8, 208u8, 190u8, 208u8, 186u8, 208u8,
+                        184u8, 209u8, 130u8, 208u8, 176u8, 208u8, 185u8, 209u8, 129u8, 208u8,
+                        186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 209u8, 142u8, 208u8, 176u8, 208u8,
+                        189u8, 208u8, 181u8, 208u8, 185u8, 208u8, 180u8, 208u8, 176u8, 209u8,
+                        130u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 208u8,
+                        186u8, 209u8, 128u8, 208u8, 190u8, 208u8, 189u8, 208u8, 181u8, 208u8,
+                        179u8, 208u8, 184u8, 208u8, 191u8, 208u8, 181u8, 209u8, 130u8, 209u8,
+                        129u8, 208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 209u8, 132u8, 209u8,
+                        131u8, 208u8, 189u8, 209u8, 130u8, 208u8, 190u8, 208u8, 178u8, 208u8,
+                        177u8, 209u8, 128u8, 208u8, 184u8, 209u8, 130u8, 208u8, 176u8, 208u8,
+                        189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 209u8,
+                        132u8, 209u8, 131u8, 208u8, 189u8, 209u8, 130u8, 208u8, 190u8, 208u8,
+                        178u8, 32u8, 209u8, 129u8, 209u8, 130u8, 208u8, 181u8, 209u8, 128u8, 208u8,
+                        187u8, 208u8, 184u8, 208u8, 189u8, 208u8, 179u8, 208u8, 190u8, 208u8,
+                        178u8, 208u8, 184u8, 208u8, 189u8, 208u8, 180u8, 208u8, 184u8, 208u8,
+                        185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 209u8,
+                        128u8, 209u8, 131u8, 208u8, 191u8, 208u8, 184u8, 208u8, 185u8, 209u8,
+                        143u8, 208u8, 191u8, 208u8, 190u8, 208u8, 189u8, 209u8, 129u8, 208u8,
+                        186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 208u8, 184u8, 208u8, 181u8, 208u8,
+                        189u8, 209u8, 142u8, 208u8, 182u8, 208u8, 189u8, 208u8, 190u8, 208u8,
+                        186u8, 208u8, 190u8, 209u8, 128u8, 208u8, 181u8, 208u8, 185u8, 209u8,
+                        129u8, 208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 208u8, 178u8, 208u8,
+                        190u8, 208u8, 189u8, 208u8, 186u8, 209u8, 131u8, 208u8, 178u8, 208u8,
+                        181u8, 208u8, 185u8, 209u8, 130u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                        184u8, 209u8, 133u8, 32u8, 208u8, 180u8, 208u8, 184u8, 208u8, 189u8, 208u8,
+                        176u8, 209u8, 128u8, 208u8, 190u8, 208u8, 178u8, 208u8, 188u8, 208u8,
+                        181u8, 208u8, 186u8, 209u8, 129u8, 208u8, 184u8, 208u8, 186u8, 208u8,
+                        176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 209u8,
+                        133u8, 32u8, 208u8, 191u8, 208u8, 181u8, 209u8, 129u8, 208u8, 190u8, 209u8,
+                        132u8, 208u8, 184u8, 208u8, 187u8, 208u8, 184u8, 208u8, 191u8, 208u8,
+                        191u8, 208u8, 184u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                        184u8, 209u8, 133u8, 32u8, 208u8, 191u8, 208u8, 181u8, 209u8, 129u8, 208u8,
+                        190u8, 209u8, 129u8, 208u8, 181u8, 209u8, 128u8, 208u8, 177u8, 209u8,
+                        129u8, 208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 208u8, 180u8, 208u8,
+                        184u8, 208u8, 189u8, 208u8, 176u8, 209u8, 128u8, 208u8, 190u8, 208u8,
+                        178u8, 209u8, 128u8, 208u8, 190u8, 209u8, 129u8, 209u8, 129u8, 208u8,
+                        184u8, 208u8, 185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 209u8,
+                        133u8, 32u8, 209u8, 128u8, 209u8, 131u8, 208u8, 177u8, 208u8, 187u8, 208u8,
+                        181u8, 208u8, 185u8, 209u8, 136u8, 208u8, 178u8, 208u8, 181u8, 208u8,
+                        180u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 208u8,
+                        186u8, 209u8, 128u8, 208u8, 190u8, 208u8, 189u8, 209u8, 130u8, 208u8,
+                        176u8, 208u8, 184u8, 208u8, 187u8, 208u8, 176u8, 208u8, 189u8, 208u8,
+                        180u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 208u8,
+                        177u8, 208u8, 176u8, 209u8, 130u8, 208u8, 190u8, 208u8, 178u8, 209u8,
+                        130u8, 209u8, 131u8, 209u8, 128u8, 208u8, 181u8, 209u8, 134u8, 208u8,
+                        186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 208u8, 187u8, 208u8, 184u8, 209u8,
+                        128u8, 208u8, 180u8, 208u8, 190u8, 208u8, 187u8, 208u8, 187u8, 208u8,
+                        176u8, 209u8, 128u8, 208u8, 190u8, 208u8, 178u8, 32u8, 208u8, 161u8, 208u8,
+                        168u8, 208u8, 144u8, 209u8, 142u8, 208u8, 182u8, 208u8, 189u8, 208u8,
+                        190u8, 208u8, 176u8, 209u8, 132u8, 209u8, 128u8, 208u8, 184u8, 208u8,
+                        186u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                        184u8, 209u8, 133u8, 32u8, 209u8, 128u8, 209u8, 141u8, 208u8, 189u8, 208u8,
+                        180u8, 208u8, 190u8, 208u8, 178u8,
+                    ])
+                },
+            )
+        },
+    }),
+    other: ::icu_currency::provider::CurrencyPluralNames {
+        pattern: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(" "),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 66u8, 82u8, 76u8,
+                        67u8, 65u8, 68u8, 67u8, 72u8, 70u8, 67u8, 76u8, 80u8, 67u8, 78u8, 89u8,
+                        68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8,
+                        73u8, 78u8, 82u8, 74u8, 80u8, 89u8, 75u8, 82u8, 87u8, 75u8, 87u8, 68u8,
+                        77u8, 88u8, 78u8, 80u8, 72u8, 80u8, 82u8, 83u8, 68u8, 82u8, 85u8, 66u8,
+                        83u8, 69u8, 75u8, 84u8, 72u8, 66u8, 84u8, 82u8, 89u8, 85u8, 83u8, 68u8,
+                        90u8, 65u8, 82u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        25u8, 0u8, 0u8, 0u8, 0u8, 0u8, 35u8, 0u8, 78u8, 0u8, 113u8, 0u8, 148u8,
+                        0u8, 183u8, 0u8, 220u8, 0u8, 249u8, 0u8, 22u8, 1u8, 47u8, 1u8, 80u8, 1u8,
+                        88u8, 1u8, 142u8, 1u8, 171u8, 1u8, 196u8, 1u8, 231u8, 1u8, 10u8, 2u8, 45u8,
+                        2u8, 80u8, 2u8, 111u8, 2u8, 144u8, 2u8, 171u8, 2u8, 204u8, 2u8, 229u8, 2u8,
+                        250u8, 2u8, 208u8, 176u8, 209u8, 128u8, 208u8, 179u8, 208u8, 181u8, 208u8,
+                        189u8, 209u8, 130u8, 208u8, 184u8, 208u8, 189u8, 209u8, 129u8, 208u8,
+                        186u8, 208u8, 190u8, 208u8, 179u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8,
+                        181u8, 209u8, 129u8, 208u8, 190u8, 208u8, 176u8, 208u8, 178u8, 209u8,
+                        129u8, 209u8, 130u8, 209u8, 128u8, 208u8, 176u8, 208u8, 187u8, 208u8,
+                        184u8, 208u8, 185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 208u8,
+                        179u8, 208u8, 190u8, 32u8, 208u8, 180u8, 208u8, 190u8, 208u8, 187u8, 208u8,
+                        187u8, 208u8, 176u8, 209u8, 128u8, 208u8, 176u8, 208u8, 177u8, 208u8,
+                        176u8, 208u8, 189u8, 208u8, 179u8, 208u8, 187u8, 208u8, 176u8, 208u8,
+                        180u8, 208u8, 181u8, 209u8, 136u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                        190u8, 208u8, 185u8, 32u8, 209u8, 130u8, 208u8, 176u8, 208u8, 186u8, 208u8,
+                        184u8, 208u8, 177u8, 209u8, 128u8, 208u8, 176u8, 208u8, 183u8, 208u8,
+                        184u8, 208u8, 187u8, 209u8, 140u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                        190u8, 208u8, 179u8, 208u8, 190u8, 32u8, 209u8, 128u8, 208u8, 181u8, 208u8,
+                        176u8, 208u8, 187u8, 208u8, 176u8, 208u8, 186u8, 208u8, 176u8, 208u8,
+                        189u8, 208u8, 176u8, 208u8, 180u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                        190u8, 208u8, 179u8, 208u8, 190u8, 32u8, 208u8, 180u8, 208u8, 190u8, 208u8,
+                        187u8, 208u8, 187u8, 208u8, 176u8, 209u8, 128u8, 208u8, 176u8, 209u8,
+                        136u8, 208u8, 178u8, 208u8, 181u8, 208u8, 185u8, 209u8, 134u8, 208u8,
+                        176u8, 209u8, 128u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 208u8,
+                        179u8, 208u8, 190u8, 32u8, 209u8, 132u8, 209u8, 128u8, 208u8, 176u8, 208u8,
+                        189u8, 208u8, 186u8, 208u8, 176u8, 209u8, 135u8, 208u8, 184u8, 208u8,
+                        187u8, 208u8, 184u8, 208u8, 185u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                        190u8, 208u8, 179u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8, 181u8, 209u8,
+                        129u8, 208u8, 190u8, 208u8, 186u8, 208u8, 184u8, 209u8, 130u8, 208u8,
+                        176u8, 208u8, 185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 208u8,
+                        179u8, 208u8, 190u8, 32u8, 209u8, 142u8, 208u8, 176u8, 208u8, 189u8, 209u8,
+                        143u8, 208u8, 180u8, 208u8, 176u8, 209u8, 130u8, 209u8, 129u8, 208u8,
+                        186u8, 208u8, 190u8, 208u8, 185u8, 32u8, 208u8, 186u8, 209u8, 128u8, 208u8,
+                        190u8, 208u8, 189u8, 209u8, 139u8, 208u8, 181u8, 208u8, 179u8, 208u8,
+                        184u8, 208u8, 191u8, 208u8, 181u8, 209u8, 130u8, 209u8, 129u8, 208u8,
+                        186u8, 208u8, 190u8, 208u8, 179u8, 208u8, 190u8, 32u8, 209u8, 132u8, 209u8,
+                        131u8, 208u8, 189u8, 209u8, 130u8, 208u8, 176u8, 208u8, 181u8, 208u8,
+                        178u8, 209u8, 128u8, 208u8, 190u8, 208u8, 177u8, 209u8, 128u8, 208u8,
+                        184u8, 209u8, 130u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8,
+                        186u8, 208u8, 190u8, 208u8, 179u8, 208u8, 190u8, 32u8, 209u8, 132u8, 209u8,
+                        131u8, 208u8, 189u8, 209u8, 130u8, 208u8, 176u8, 32u8, 209u8, 129u8, 209u8,
+                        130u8, 208u8, 181u8, 209u8, 128u8, 208u8, 187u8, 208u8, 184u8, 208u8,
+                        189u8, 208u8, 179u8, 208u8, 190u8, 208u8, 178u8, 208u8, 184u8, 208u8,
+                        189u8, 208u8, 180u8, 208u8, 184u8, 208u8, 185u8, 209u8, 129u8, 208u8,
+                        186u8, 208u8, 190u8, 208u8, 185u8, 32u8, 209u8, 128u8, 209u8, 131u8, 208u8,
+                        191u8, 208u8, 184u8, 208u8, 184u8, 209u8, 143u8, 208u8, 191u8, 208u8,
+                        190u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 208u8,
+                        185u8, 32u8, 208u8, 184u8, 208u8, 181u8, 208u8, 189u8, 209u8, 139u8, 209u8,
+                        142u8, 208u8, 182u8, 208u8, 189u8, 208u8, 190u8, 208u8, 186u8, 208u8,
+                        190u8, 209u8, 128u8, 208u8, 181u8, 208u8, 185u8, 209u8, 129u8, 208u8,
+                        186u8, 208u8, 190u8, 208u8, 185u8, 32u8, 208u8, 178u8, 208u8, 190u8, 208u8,
+                        189u8, 209u8, 139u8, 208u8, 186u8, 209u8, 131u8, 208u8, 178u8, 208u8,
+                        181u8, 208u8, 185u8, 209u8, 130u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                        190u8, 208u8, 179u8, 208u8, 190u8, 32u8, 208u8, 180u8, 208u8, 184u8, 208u8,
+                        189u8, 208u8, 176u8, 209u8, 128u8, 208u8, 176u8, 208u8, 188u8, 208u8,
+                        181u8, 208u8, 186u8, 209u8, 129u8, 208u8, 184u8, 208u8, 186u8, 208u8,
+                        176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 208u8,
+                        179u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8, 181u8, 209u8, 129u8, 208u8,
+                        190u8, 209u8, 132u8, 208u8, 184u8, 208u8, 187u8, 208u8, 184u8, 208u8,
+                        191u8, 208u8, 191u8, 208u8, 184u8, 208u8, 189u8, 209u8, 129u8, 208u8,
+                        186u8, 208u8, 190u8, 208u8, 179u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8,
+                        181u8, 209u8, 129u8, 208u8, 190u8, 209u8, 129u8, 208u8, 181u8, 209u8,
+                        128u8, 208u8, 177u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 208u8,
+                        179u8, 208u8, 190u8, 32u8, 208u8, 180u8, 208u8, 184u8, 208u8, 189u8, 208u8,
+                        176u8, 209u8, 128u8, 208u8, 176u8, 209u8, 128u8, 208u8, 190u8, 209u8,
+                        129u8, 209u8, 129u8, 208u8, 184u8, 208u8, 185u8, 209u8, 129u8, 208u8,
+                        186u8, 208u8, 190u8, 208u8, 179u8, 208u8, 190u8, 32u8, 209u8, 128u8, 209u8,
+                        131u8, 208u8, 177u8, 208u8, 187u8, 209u8, 143u8, 209u8, 136u8, 208u8,
+                        178u8, 208u8, 181u8, 208u8, 180u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                        190u8, 208u8, 185u8, 32u8, 208u8, 186u8, 209u8, 128u8, 208u8, 190u8, 208u8,
+                        189u8, 209u8, 139u8, 209u8, 130u8, 208u8, 176u8, 208u8, 184u8, 208u8,
+                        187u8, 208u8, 176u8, 208u8, 189u8, 208u8, 180u8, 209u8, 129u8, 208u8,
+                        186u8, 208u8, 190u8, 208u8, 179u8, 208u8, 190u8, 32u8, 208u8, 177u8, 208u8,
+                        176u8, 209u8, 130u8, 208u8, 176u8, 209u8, 130u8, 209u8, 131u8, 209u8,
+                        128u8, 208u8, 181u8, 209u8, 134u8, 208u8, 186u8, 208u8, 190u8, 208u8,
+                        185u8, 32u8, 208u8, 187u8, 208u8, 184u8, 209u8, 128u8, 209u8, 139u8, 208u8,
+                        180u8, 208u8, 190u8, 208u8, 187u8, 208u8, 187u8, 208u8, 176u8, 209u8,
+                        128u8, 208u8, 176u8, 32u8, 208u8, 161u8, 208u8, 168u8, 208u8, 144u8, 209u8,
+                        142u8, 208u8, 182u8, 208u8, 189u8, 208u8, 190u8, 208u8, 176u8, 209u8,
+                        132u8, 209u8, 128u8, 208u8, 184u8, 208u8, 186u8, 208u8, 176u8, 208u8,
+                        189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 208u8, 179u8, 208u8,
+                        190u8, 32u8, 209u8, 128u8, 209u8, 141u8, 208u8, 189u8, 208u8, 180u8, 208u8,
+                        176u8,
+                    ])
+                },
+            )
+        },
+    },
+};
+static SR_LATN: &DataStruct = &::icu_currency::provider::CurrencyNamesV1 {
+    zero: None,
+    one: Some(::icu_currency::provider::CurrencyPluralNames {
+        pattern: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(" "),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 66u8, 82u8, 76u8,
+                        67u8, 65u8, 68u8, 67u8, 72u8, 70u8, 67u8, 76u8, 80u8, 67u8, 78u8, 89u8,
+                        68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8,
+                        73u8, 78u8, 82u8, 74u8, 80u8, 89u8, 75u8, 82u8, 87u8, 75u8, 87u8, 68u8,
+                        77u8, 88u8, 78u8, 80u8, 72u8, 80u8, 82u8, 83u8, 68u8, 82u8, 85u8, 66u8,
+                        83u8, 69u8, 75u8, 84u8, 72u8, 66u8, 84u8, 82u8, 89u8, 85u8, 83u8, 68u8,
+                        90u8, 65u8, 82u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        25u8, 0u8, 0u8, 0u8, 0u8, 0u8, 17u8, 0u8, 35u8, 0u8, 52u8, 0u8, 66u8, 0u8,
+                        80u8, 0u8, 98u8, 0u8, 114u8, 0u8, 126u8, 0u8, 138u8, 0u8, 153u8, 0u8,
+                        157u8, 0u8, 172u8, 0u8, 187u8, 0u8, 199u8, 0u8, 217u8, 0u8, 232u8, 0u8,
+                        247u8, 0u8, 7u8, 1u8, 19u8, 1u8, 31u8, 1u8, 45u8, 1u8, 59u8, 1u8, 70u8,
+                        1u8, 85u8, 1u8, 97u8, 114u8, 103u8, 101u8, 110u8, 116u8, 105u8, 110u8,
+                        115u8, 107u8, 105u8, 32u8, 112u8, 101u8, 122u8, 111u8, 115u8, 97u8, 117u8,
+                        115u8, 116u8, 114u8, 97u8, 108u8, 105u8, 106u8, 115u8, 107u8, 105u8, 32u8,
+                        100u8, 111u8, 108u8, 97u8, 114u8, 98u8, 97u8, 110u8, 103u8, 108u8, 97u8,
+                        100u8, 101u8, 197u8, 161u8, 107u8, 97u8, 32u8, 116u8, 97u8, 107u8, 97u8,
+                        98u8, 114u8, 97u8, 122u8, 105u8, 108u8, 115u8, 107u8, 105u8, 32u8, 114u8,
+                        101u8, 97u8, 108u8, 107u8, 97u8, 110u8, 97u8, 100u8, 115u8, 107u8, 105u8,
+                        32u8, 100u8, 111u8, 108u8, 97u8, 114u8, 197u8, 161u8, 118u8, 97u8, 106u8,
+                        99u8, 97u8, 114u8, 115u8, 107u8, 105u8, 32u8, 102u8, 114u8, 97u8, 110u8,
+                        97u8, 107u8, 196u8, 141u8, 105u8, 108u8, 101u8, 97u8, 110u8, 115u8, 107u8,
+                        105u8, 32u8, 112u8, 101u8, 122u8, 111u8, 115u8, 107u8, 105u8, 110u8, 101u8,
+                        115u8, 107u8, 105u8, 32u8, 106u8, 117u8, 97u8, 110u8, 100u8, 97u8, 110u8,
+                        115u8, 107u8, 97u8, 32u8, 107u8, 114u8, 117u8, 110u8, 97u8, 101u8, 103u8,
+                        105u8, 112u8, 97u8, 116u8, 115u8, 107u8, 97u8, 32u8, 102u8, 117u8, 110u8,
+                        116u8, 97u8, 101u8, 118u8, 114u8, 111u8, 98u8, 114u8, 105u8, 116u8, 97u8,
+                        110u8, 115u8, 107u8, 97u8, 32u8, 102u8, 117u8, 110u8, 116u8, 97u8, 105u8,
+                        110u8, 100u8, 105u8, 106u8, 115u8, 107u8, 97u8, 32u8, 114u8, 117u8, 112u8,
+                        105u8, 106u8, 97u8, 106u8, 97u8, 112u8, 97u8, 110u8, 115u8, 107u8, 105u8,
+                        32u8, 106u8, 101u8, 110u8, 106u8, 117u8, 197u8, 190u8, 110u8, 111u8, 107u8,
+                        111u8, 114u8, 101u8, 106u8, 115u8, 107u8, 105u8, 32u8, 118u8, 111u8, 110u8,
+                        107u8, 117u8, 118u8, 97u8, 106u8, 116u8, 115u8, 107u8, 105u8, 32u8, 100u8,
+                        105u8, 110u8, 97u8, 114u8, 109u8, 101u8, 107u8, 115u8, 105u8, 196u8, 141u8,
+                        107u8, 105u8, 32u8, 112u8, 101u8, 122u8, 111u8, 115u8, 102u8, 105u8, 108u8,
+                        105u8, 112u8, 105u8, 110u8, 115u8, 107u8, 105u8, 32u8, 112u8, 101u8, 122u8,
+                        111u8, 115u8, 115u8, 114u8, 112u8, 115u8, 107u8, 105u8, 32u8, 100u8, 105u8,
+                        110u8, 97u8, 114u8, 114u8, 117u8, 115u8, 107u8, 97u8, 32u8, 114u8, 117u8,
+                        98u8, 108u8, 106u8, 97u8, 197u8, 161u8, 118u8, 101u8, 100u8, 115u8, 107u8,
+                        97u8, 32u8, 107u8, 114u8, 117u8, 110u8, 97u8, 116u8, 97u8, 106u8, 108u8,
+                        97u8, 110u8, 100u8, 115u8, 107u8, 105u8, 32u8, 98u8, 97u8, 116u8, 116u8,
+                        117u8, 114u8, 115u8, 107u8, 97u8, 32u8, 108u8, 105u8, 114u8, 97u8, 97u8,
+                        109u8, 101u8, 114u8, 105u8, 196u8, 141u8, 107u8, 105u8, 32u8, 100u8, 111u8,
+                        108u8, 97u8, 114u8, 106u8, 117u8, 197u8, 190u8, 110u8, 111u8, 97u8, 102u8,
+                        114u8, 105u8, 196u8, 141u8, 107u8, 105u8, 32u8, 114u8, 97u8, 110u8, 100u8,
+                    ])
+                },
+            )
+        },
+    }),
+    two: None,
+    few: Some(::icu_currency::provider::CurrencyPluralNames {
+        pattern: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(" "),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 66u8, 82u8, 76u8,
+                        67u8, 65u8, 68u8, 67u8, 72u8, 70u8, 67u8, 76u8, 80u8, 67u8, 78u8, 89u8,
+                        68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 71u8, 66u8, 80u8, 73u8, 78u8, 82u8,
+                        74u8, 80u8, 89u8, 75u8, 82u8, 87u8, 75u8, 87u8, 68u8, 77u8, 88u8, 78u8,
+                        80u8, 72u8, 80u8, 82u8, 83u8, 68u8, 82u8, 85u8, 66u8, 83u8, 69u8, 75u8,
+                        84u8, 72u8, 66u8, 84u8, 82u8, 89u8, 85u8, 83u8, 68u8, 90u8, 65u8, 82u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 18u8, 0u8, 37u8, 0u8, 54u8, 0u8, 69u8, 0u8,
+                        84u8, 0u8, 102u8, 0u8, 119u8, 0u8, 132u8, 0u8, 144u8, 0u8, 159u8, 0u8,
+                        174u8, 0u8, 189u8, 0u8, 202u8, 0u8, 221u8, 0u8, 237u8, 0u8, 253u8, 0u8,
+                        14u8, 1u8, 27u8, 1u8, 39u8, 1u8, 53u8, 1u8, 68u8, 1u8, 79u8, 1u8, 95u8,
+                        1u8, 97u8, 114u8, 103u8, 101u8, 110u8, 116u8, 105u8, 110u8, 115u8, 107u8,
+                        97u8, 32u8, 112u8, 101u8, 122u8, 111u8, 115u8, 97u8, 97u8, 117u8, 115u8,
+                        116u8, 114u8, 97u8, 108u8, 105u8, 106u8, 115u8, 107u8, 97u8, 32u8, 100u8,
+                        111u8, 108u8, 97u8, 114u8, 97u8, 98u8, 97u8, 110u8, 103u8, 108u8, 97u8,
+                        100u8, 101u8, 197u8, 161u8, 107u8, 101u8, 32u8, 116u8, 97u8, 107u8, 101u8,
+                        98u8, 114u8, 97u8, 122u8, 105u8, 108u8, 115u8, 107u8, 97u8, 32u8, 114u8,
+                        101u8, 97u8, 108u8, 97u8, 107u8, 97u8, 110u8, 97u8, 100u8, 115u8, 107u8,
+                        97u8, 32u8, 100u8, 111u8, 108u8, 97u8, 114u8, 97u8, 197u8, 161u8, 118u8,
+                        97u8, 106u8, 99u8, 97u8, 114u8, 115u8, 107u8, 97u8, 32u8, 102u8, 114u8,
+                        97u8, 110u8, 107u8, 97u8, 196u8, 141u8, 105u8, 108u8, 101u8, 97u8, 110u8,
+                        115u8, 107u8, 97u8, 32u8, 112u8, 101u8, 122u8, 111u8, 115u8, 97u8, 107u8,
+                        105u8, 110u8, 101u8, 115u8, 107u8, 97u8, 32u8, 106u8, 117u8, 97u8, 110u8,
+                        97u8, 100u8, 97u8, 110u8, 115u8, 107u8, 101u8, 32u8, 107u8, 114u8, 117u8,
+                        110u8, 101u8, 101u8, 103u8, 105u8, 112u8, 97u8, 116u8, 115u8, 107u8, 101u8,
+                        32u8, 102u8, 117u8, 110u8, 116u8, 101u8, 98u8, 114u8, 105u8, 116u8, 97u8,
+                        110u8, 115u8, 107u8, 101u8, 32u8, 102u8, 117u8, 110u8, 116u8, 101u8, 105u8,
+                        110u8, 100u8, 105u8, 106u8, 115u8, 107u8, 101u8, 32u8, 114u8, 117u8, 112u8,
+                        105u8, 106u8, 101u8, 106u8, 97u8, 112u8, 97u8, 110u8, 115u8, 107u8, 97u8,
+                        32u8, 106u8, 101u8, 110u8, 97u8, 106u8, 117u8, 197u8, 190u8, 110u8, 111u8,
+                        107u8, 111u8, 114u8, 101u8, 106u8, 115u8, 107u8, 97u8, 32u8, 118u8, 111u8,
+                        110u8, 97u8, 107u8, 117u8, 118u8, 97u8, 106u8, 116u8, 115u8, 107u8, 97u8,
+                        32u8, 100u8, 105u8, 110u8, 97u8, 114u8, 97u8, 109u8, 101u8, 107u8, 115u8,
+                        105u8, 196u8, 141u8, 107u8, 97u8, 32u8, 112u8, 101u8, 122u8, 111u8, 115u8,
+                        97u8, 102u8, 105u8, 108u8, 105u8, 112u8, 105u8, 110u8, 115u8, 107u8, 97u8,
+                        32u8, 112u8, 101u8, 122u8, 111u8, 115u8, 97u8, 115u8, 114u8, 112u8, 115u8,
+                        107u8, 97u8, 32u8, 100u8, 105u8, 110u8, 97u8, 114u8, 97u8, 114u8, 117u8,
+                        115u8, 107u8, 101u8, 32u8, 114u8, 117u8, 98u8, 108u8, 106u8, 101u8, 197u8,
+                        161u8, 118u8, 101u8, 100u8, 115u8, 107u8, 101u8, 32u8, 107u8, 114u8, 117u8,
+                        110u8, 101u8, 116u8, 97u8, 106u8, 108u8, 97u8, 110u8, 100u8, 115u8, 107u8,
+                        97u8, 32u8, 98u8, 97u8, 116u8, 97u8, 116u8, 117u8, 114u8, 115u8, 107u8,
+                        101u8, 32u8, 108u8, 105u8, 114u8, 101u8, 97u8, 109u8, 101u8, 114u8, 105u8,
+                        196u8, 141u8, 107u8, 97u8, 32u8, 100u8, 111u8, 108u8, 97u8, 114u8, 97u8,
+                        106u8, 117u8, 197u8, 190u8, 110u8, 111u8, 97u8, 102u8, 114u8, 105u8, 196u8,
+                        141u8, 107u8, 97u8, 32u8, 114u8, 97u8, 110u8, 100u8, 97u8,
+                    ])
+                },
+            )
+        },
+    }),
+    many: None,
+    other: ::icu_currency::provider::CurrencyPluralNames {
+        pattern: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(" "),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 66u8, 82u8, 76u8,
+                        67u8, 65u8, 68u8, 67u8, 72u8, 70u8, 67u8, 76u8, 80u8, 67u8, 78u8, 89u8,
+                        68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8,
+                        73u8, 78u8, 82u8, 74u8, 80u8, 89u8, 75u8, 82u8, 87u8, 75u8, 87u8, 68u8,
+                        77u8, 88u8, 78u8, 80u8, 72u8, 80u8, 82u8, 83u8, 68u8, 82u8, 85u8, 66u8,
+                        83u8, 69u8, 75u8, 84u8, 72u8, 66u8, 84u8, 82u8, 89u8, 85u8, 83u8, 68u8,
+                        90u8, 65u8, 82u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        25u8, 0u8, 0u8, 0u8, 0u8, 0u8, 19u8, 0u8, 39u8, 0u8, 57u8, 0u8, 73u8, 0u8,
+                        89u8, 0u8, 109u8, 0u8, 127u8, 0u8, 141u8, 0u8, 154u8, 0u8, 170u8, 0u8,
+                        174u8, 0u8, 190u8, 0u8, 206u8, 0u8, 220u8, 0u8, 240u8, 0u8, 1u8, 1u8, 18u8,
+                        1u8, 36u8, 1u8, 50u8, 1u8, 63u8, 1u8, 78u8, 1u8, 94u8, 1u8, 106u8, 1u8,
+                        123u8, 1u8, 97u8, 114u8, 103u8, 101u8, 110u8, 116u8, 105u8, 110u8, 115u8,
+                        107u8, 105u8, 104u8, 32u8, 112u8, 101u8, 122u8, 111u8, 115u8, 97u8, 97u8,
+                        117u8, 115u8, 116u8, 114u8, 97u8, 108u8, 105u8, 106u8, 115u8, 107u8, 105u8,
+                        104u8, 32u8, 100u8, 111u8, 108u8, 97u8, 114u8, 97u8, 98u8, 97u8, 110u8,
+                        103u8, 108u8, 97u8, 100u8, 101u8, 197u8, 161u8, 107u8, 105u8, 104u8, 32u8,
+                        116u8, 97u8, 107u8, 97u8, 98u8, 114u8, 97u8, 122u8, 105u8, 108u8, 115u8,
+                        107u8, 105u8, 104u8, 32u8, 114u8, 101u8, 97u8, 108u8, 97u8, 107u8, 97u8,
+                        110u8, 97u8, 100u8, 115u8, 107u8, 105u8, 104u8, 32u8, 100u8, 111u8, 108u8,
+                        97u8, 114u8, 97u8, 197u8, 161u8, 118u8, 97u8, 106u8, 99u8, 97u8, 114u8,
+                        115u8, 107u8, 105u8, 104u8, 32u8, 102u8, 114u8, 97u8, 110u8, 97u8, 107u8,
+                        97u8, 196u8, 141u8, 105u8, 108u8, 101u8, 97u8, 110u8, 115u8, 107u8, 105u8,
+                        104u8, 32u8, 112u8, 101u8, 122u8, 111u8, 115u8, 97u8, 107u8, 105u8, 110u8,
+                        101u8, 115u8, 107u8, 105u8, 104u8, 32u8, 106u8, 117u8, 97u8, 110u8, 97u8,
+                        100u8, 97u8, 110u8, 115u8, 107u8, 105u8, 104u8, 32u8, 107u8, 114u8, 117u8,
+                        110u8, 97u8, 101u8, 103u8, 105u8, 112u8, 97u8, 116u8, 115u8, 107u8, 105u8,
+                        104u8, 32u8, 102u8, 117u8, 110u8, 116u8, 105u8, 101u8, 118u8, 114u8, 97u8,
+                        98u8, 114u8, 105u8, 116u8, 97u8, 110u8, 115u8, 107u8, 105u8, 104u8, 32u8,
+                        102u8, 117u8, 110u8, 116u8, 105u8, 105u8, 110u8, 100u8, 105u8, 106u8,
+                        115u8, 107u8, 105u8, 104u8, 32u8, 114u8, 117u8, 112u8, 105u8, 106u8, 97u8,
+                        106u8, 97u8, 112u8, 97u8, 110u8, 115u8, 107u8, 105u8, 104u8, 32u8, 106u8,
+                        101u8, 110u8, 97u8, 106u8, 117u8, 197u8, 190u8, 110u8, 111u8, 107u8, 111u8,
+                        114u8, 101u8, 106u8, 115u8, 107u8, 105u8, 104u8, 32u8, 118u8, 111u8, 110u8,
+                        97u8, 107u8, 117u8, 118u8, 97u8, 106u8, 116u8, 115u8, 107u8, 105u8, 104u8,
+                        32u8, 100u8, 105u8, 110u8, 97u8, 114u8, 97u8, 109u8, 101u8, 107u8, 115u8,
+                        105u8, 196u8, 141u8, 107u8, 105u8, 104u8, 32u8, 112u8, 101u8, 122u8, 111u8,
+                        115u8, 97u8, 102u8, 105u8, 108u8, 105u8, 112u8, 105u8, 110u8, 115u8, 107u8,
+                        105u8, 104u8, 32u8, 112u8, 101u8, 122u8, 111u8, 115u8, 97u8, 115u8, 114u8,
+                        112u8, 115u8, 107u8, 105u8, 104u8, 32u8, 100u8, 105u8, 110u8, 97u8, 114u8,
+                        97u8, 114u8, 117u8, 115u8, 107u8, 105u8, 104u8, 32u8, 114u8, 117u8, 98u8,
+                        108u8, 106u8, 105u8, 197u8, 161u8, 118u8, 101u8, 100u8, 115u8, 107u8,
+                        105u8, 104u8, 32u8, 107u8, 114u8, 117u8, 110u8, 97u8, 116u8, 97u8, 106u8,
+                        108u8, 97u8, 110u8, 100u8, 115u8, 107u8, 105u8, 104u8, 32u8, 98u8, 97u8,
+                        116u8, 97u8, 116u8, 117u8, 114u8, 115u8, 107u8, 105u8, 104u8, 32u8, 108u8,
+                        105u8, 114u8, 97u8, 97u8, 109u8, 101u8, 114u8, 105u8, 196u8, 141u8, 107u8,
+                        105u8, 104u8, 32u8, 100u8, 111u8, 108u8, 97u8, 114u8, 97u8, 106u8, 117u8,
+                        197u8, 190u8, 110u8, 111u8, 97u8, 102u8, 114u8, 105u8, 196u8, 141u8, 107u8,
+                        105u8, 104u8, 32u8, 114u8, 97u8, 110u8, 100u8, 97u8,
+                    ])
+                },
+            )
+        },
+    },
+};
+static SR_SR_CYRL: &DataStruct = &::icu_currency::provider::CurrencyNamesV1 {
+    zero: None,
+    one: Some(::icu_currency::provider::CurrencyPluralNames {
+        pattern: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(" "),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 66u8, 82u8, 76u8,
+                        67u8, 65u8, 68u8, 67u8, 72u8, 70u8, 67u8, 76u8, 80u8, 67u8, 78u8, 89u8,
+                        68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8,
+                        73u8, 78u8, 82u8, 74u8, 80u8, 89u8, 75u8, 82u8, 87u8, 75u8, 87u8, 68u8,
+                        77u8, 88u8, 78u8, 80u8, 72u8, 80u8, 82u8, 83u8, 68u8, 82u8, 85u8, 66u8,
+                        83u8, 69u8, 75u8, 84u8, 72u8, 66u8, 84u8, 82u8, 89u8, 85u8, 83u8, 68u8,
+                        90u8, 65u8, 82u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        25u8, 0u8, 0u8, 0u8, 0u8, 0u8, 33u8, 0u8, 68u8, 0u8, 99u8, 0u8, 126u8, 0u8,
+                        153u8, 0u8, 186u8, 0u8, 215u8, 0u8, 238u8, 0u8, 5u8, 1u8, 34u8, 1u8, 42u8,
+                        1u8, 71u8, 1u8, 100u8, 1u8, 123u8, 1u8, 156u8, 1u8, 185u8, 1u8, 212u8, 1u8,
+                        243u8, 1u8, 10u8, 2u8, 31u8, 2u8, 56u8, 2u8, 83u8, 2u8, 104u8, 2u8, 131u8,
+                        2u8, 208u8, 176u8, 209u8, 128u8, 208u8, 179u8, 208u8, 181u8, 208u8, 189u8,
+                        209u8, 130u8, 208u8, 184u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8,
+                        208u8, 184u8, 32u8, 208u8, 191u8, 208u8, 181u8, 208u8, 183u8, 208u8, 190u8,
+                        209u8, 129u8, 208u8, 176u8, 209u8, 131u8, 209u8, 129u8, 209u8, 130u8,
+                        209u8, 128u8, 208u8, 176u8, 208u8, 187u8, 208u8, 184u8, 209u8, 152u8,
+                        209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 208u8, 180u8, 208u8, 190u8,
+                        208u8, 187u8, 208u8, 176u8, 209u8, 128u8, 208u8, 177u8, 208u8, 176u8,
+                        208u8, 189u8, 208u8, 179u8, 208u8, 187u8, 208u8, 176u8, 208u8, 180u8,
+                        208u8, 181u8, 209u8, 136u8, 208u8, 186u8, 208u8, 176u8, 32u8, 209u8, 130u8,
+                        208u8, 176u8, 208u8, 186u8, 208u8, 176u8, 208u8, 177u8, 209u8, 128u8,
+                        208u8, 176u8, 208u8, 183u8, 208u8, 184u8, 208u8, 187u8, 209u8, 129u8,
+                        208u8, 186u8, 208u8, 184u8, 32u8, 209u8, 128u8, 208u8, 181u8, 208u8, 176u8,
+                        208u8, 187u8, 208u8, 186u8, 208u8, 176u8, 208u8, 189u8, 208u8, 176u8,
+                        208u8, 180u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 208u8, 180u8,
+                        208u8, 190u8, 208u8, 187u8, 208u8, 176u8, 209u8, 128u8, 209u8, 136u8,
+                        208u8, 178u8, 208u8, 176u8, 209u8, 152u8, 209u8, 134u8, 208u8, 176u8,
+                        209u8, 128u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 209u8, 132u8,
+                        209u8, 128u8, 208u8, 176u8, 208u8, 189u8, 208u8, 176u8, 208u8, 186u8,
+                        209u8, 135u8, 208u8, 184u8, 208u8, 187u8, 208u8, 181u8, 208u8, 176u8,
+                        208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 208u8, 191u8,
+                        208u8, 181u8, 208u8, 183u8, 208u8, 190u8, 209u8, 129u8, 208u8, 186u8,
+                        208u8, 184u8, 208u8, 189u8, 208u8, 181u8, 209u8, 129u8, 208u8, 186u8,
+                        208u8, 184u8, 32u8, 209u8, 152u8, 209u8, 131u8, 208u8, 176u8, 208u8, 189u8,
+                        208u8, 180u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8,
+                        208u8, 176u8, 32u8, 208u8, 186u8, 209u8, 128u8, 209u8, 131u8, 208u8, 189u8,
+                        208u8, 176u8, 208u8, 181u8, 208u8, 179u8, 208u8, 184u8, 208u8, 191u8,
+                        208u8, 176u8, 209u8, 130u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 32u8,
+                        209u8, 132u8, 209u8, 131u8, 208u8, 189u8, 209u8, 130u8, 208u8, 176u8,
+                        208u8, 181u8, 208u8, 178u8, 209u8, 128u8, 208u8, 190u8, 208u8, 177u8,
+                        209u8, 128u8, 208u8, 184u8, 209u8, 130u8, 208u8, 176u8, 208u8, 189u8,
+                        209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 32u8, 209u8, 132u8, 209u8, 131u8,
+                        208u8, 189u8, 209u8, 130u8, 208u8, 176u8, 208u8, 184u8, 208u8, 189u8,
+                        208u8, 180u8, 208u8, 184u8, 209u8, 152u8, 209u8, 129u8, 208u8, 186u8,
+                        208u8, 176u8, 32u8, 209u8, 128u8, 209u8, 131u8, 208u8, 191u8, 208u8, 184u8,
+                        209u8, 152u8, 208u8, 176u8, 209u8, 152u8, 208u8, 176u8, 208u8, 191u8,
+                        208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 32u8,
+                        209u8, 152u8, 208u8, 181u8, 208u8, 189u8, 209u8, 152u8, 209u8, 131u8,
+                        208u8, 182u8, 208u8, 189u8, 208u8, 190u8, 208u8, 186u8, 208u8, 190u8,
+                        209u8, 128u8, 208u8, 181u8, 209u8, 152u8, 209u8, 129u8, 208u8, 186u8,
+                        208u8, 184u8, 32u8, 208u8, 178u8, 208u8, 190u8, 208u8, 189u8, 208u8, 186u8,
+                        209u8, 131u8, 208u8, 178u8, 208u8, 176u8, 209u8, 152u8, 209u8, 130u8,
+                        209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 208u8, 180u8, 208u8, 184u8,
+                        208u8, 189u8, 208u8, 176u8, 209u8, 128u8, 208u8, 188u8, 208u8, 181u8,
+                        208u8, 186u8, 209u8, 129u8, 208u8, 184u8, 209u8, 135u8, 208u8, 186u8,
+                        208u8, 184u8, 32u8, 208u8, 191u8, 208u8, 181u8, 208u8, 183u8, 208u8, 190u8,
+                        209u8, 129u8, 209u8, 132u8, 208u8, 184u8, 208u8, 187u8, 208u8, 184u8,
+                        208u8, 191u8, 208u8, 184u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8,
+                        208u8, 184u8, 32u8, 208u8, 191u8, 208u8, 181u8, 208u8, 183u8, 208u8, 190u8,
+                        209u8, 129u8, 209u8, 129u8, 209u8, 128u8, 208u8, 191u8, 209u8, 129u8,
+                        208u8, 186u8, 208u8, 184u8, 32u8, 208u8, 180u8, 208u8, 184u8, 208u8, 189u8,
+                        208u8, 176u8, 209u8, 128u8, 209u8, 128u8, 209u8, 131u8, 209u8, 129u8,
+                        208u8, 186u8, 208u8, 176u8, 32u8, 209u8, 128u8, 209u8, 131u8, 208u8, 177u8,
+                        209u8, 153u8, 208u8, 176u8, 209u8, 136u8, 208u8, 178u8, 208u8, 181u8,
+                        208u8, 180u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 32u8, 208u8, 186u8,
+                        209u8, 128u8, 209u8, 131u8, 208u8, 189u8, 208u8, 176u8, 209u8, 130u8,
+                        208u8, 176u8, 209u8, 152u8, 208u8, 187u8, 208u8, 176u8, 208u8, 189u8,
+                        208u8, 180u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 208u8, 177u8,
+                        208u8, 176u8, 209u8, 130u8, 209u8, 130u8, 209u8, 131u8, 209u8, 128u8,
+                        209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 32u8, 208u8, 187u8, 208u8, 184u8,
+                        209u8, 128u8, 208u8, 176u8, 208u8, 176u8, 208u8, 188u8, 208u8, 181u8,
+                        209u8, 128u8, 208u8, 184u8, 209u8, 135u8, 208u8, 186u8, 208u8, 184u8, 32u8,
+                        208u8, 180u8, 208u8, 190u8, 208u8, 187u8, 208u8, 176u8, 209u8, 128u8,
+                        209u8, 152u8, 209u8, 131u8, 208u8, 182u8, 208u8, 189u8, 208u8, 190u8,
+                        208u8, 176u8, 209u8, 132u8, 209u8, 128u8, 208u8, 184u8, 209u8, 135u8,
+                        208u8, 186u8, 208u8, 184u8, 32u8, 209u8, 128u8, 208u8, 176u8, 208u8, 189u8,
+                        208u8, 180u8,
+                    ])
+                },
+            )
+        },
+    }),
+    two: None,
+    few: Some(::icu_currency::provider::CurrencyPluralNames {
+        pattern: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(" "),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 66u8, 82u8, 76u8,
+                        67u8, 65u8, 68u8, 67u8, 72u8, 70u8, 67u8, 76u8, 80u8, 67u8, 78u8, 89u8,
+                        68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 71u8, 66u8, 80u8, 73u8, 78u8, 82u8,
+                        74u8, 80u8, 89u8, 75u8, 82u8, 87u8, 75u8, 87u8, 68u8, 77u8, 88u8, 78u8,
+                        80u8, 72u8, 80u8, 82u8, 83u8, 68u8, 82u8, 85u8, 66u8, 83u8, 69u8, 75u8,
+                        84u8, 72u8, 66u8, 84u8, 82u8, 89u8, 85u8, 83u8, 68u8, 90u8, 65u8, 82u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 35u8, 0u8, 72u8, 0u8, 103u8, 0u8, 132u8,
+                        0u8, 161u8, 0u8, 194u8, 0u8, 225u8, 0u8, 250u8, 0u8, 17u8, 1u8, 46u8, 1u8,
+                        75u8, 1u8, 104u8, 1u8, 129u8, 1u8, 164u8, 1u8, 194u8, 1u8, 223u8, 1u8, 0u8,
+                        2u8, 25u8, 2u8, 46u8, 2u8, 71u8, 2u8, 100u8, 2u8, 121u8, 2u8, 150u8, 2u8,
+                        208u8, 176u8, 209u8, 128u8, 208u8, 179u8, 208u8, 181u8, 208u8, 189u8,
+                        209u8, 130u8, 208u8, 184u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8,
+                        208u8, 176u8, 32u8, 208u8, 191u8, 208u8, 181u8, 208u8, 183u8, 208u8, 190u8,
+                        209u8, 129u8, 208u8, 176u8, 208u8, 176u8, 209u8, 131u8, 209u8, 129u8,
+                        209u8, 130u8, 209u8, 128u8, 208u8, 176u8, 208u8, 187u8, 208u8, 184u8,
+                        209u8, 152u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 32u8, 208u8, 180u8,
+                        208u8, 190u8, 208u8, 187u8, 208u8, 176u8, 209u8, 128u8, 208u8, 176u8,
+                        208u8, 177u8, 208u8, 176u8, 208u8, 189u8, 208u8, 179u8, 208u8, 187u8,
+                        208u8, 176u8, 208u8, 180u8, 208u8, 181u8, 209u8, 136u8, 208u8, 186u8,
+                        208u8, 181u8, 32u8, 209u8, 130u8, 208u8, 176u8, 208u8, 186u8, 208u8, 181u8,
+                        208u8, 177u8, 209u8, 128u8, 208u8, 176u8, 208u8, 183u8, 208u8, 184u8,
+                        208u8, 187u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 32u8, 209u8, 128u8,
+                        208u8, 181u8, 208u8, 176u8, 208u8, 187u8, 208u8, 176u8, 208u8, 186u8,
+                        208u8, 176u8, 208u8, 189u8, 208u8, 176u8, 208u8, 180u8, 209u8, 129u8,
+                        208u8, 186u8, 208u8, 176u8, 32u8, 208u8, 180u8, 208u8, 190u8, 208u8, 187u8,
+                        208u8, 176u8, 209u8, 128u8, 208u8, 176u8, 209u8, 136u8, 208u8, 178u8,
+                        208u8, 176u8, 209u8, 152u8, 209u8, 134u8, 208u8, 176u8, 209u8, 128u8,
+                        209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 32u8, 209u8, 132u8, 209u8, 128u8,
+                        208u8, 176u8, 208u8, 189u8, 208u8, 186u8, 208u8, 176u8, 209u8, 135u8,
+                        208u8, 184u8, 208u8, 187u8, 208u8, 181u8, 208u8, 176u8, 208u8, 189u8,
+                        209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 32u8, 208u8, 191u8, 208u8, 181u8,
+                        208u8, 183u8, 208u8, 190u8, 209u8, 129u8, 208u8, 176u8, 208u8, 186u8,
+                        208u8, 184u8, 208u8, 189u8, 208u8, 181u8, 209u8, 129u8, 208u8, 186u8,
+                        208u8, 176u8, 32u8, 209u8, 152u8, 209u8, 131u8, 208u8, 176u8, 208u8, 189u8,
+                        208u8, 176u8, 208u8, 180u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8,
+                        208u8, 186u8, 208u8, 181u8, 32u8, 208u8, 186u8, 209u8, 128u8, 209u8, 131u8,
+                        208u8, 189u8, 208u8, 181u8, 208u8, 181u8, 208u8, 179u8, 208u8, 184u8,
+                        208u8, 191u8, 208u8, 176u8, 209u8, 130u8, 209u8, 129u8, 208u8, 186u8,
+                        208u8, 181u8, 32u8, 209u8, 132u8, 209u8, 131u8, 208u8, 189u8, 209u8, 130u8,
+                        208u8, 181u8, 208u8, 177u8, 209u8, 128u8, 208u8, 184u8, 209u8, 130u8,
+                        208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 181u8, 32u8,
+                        209u8, 132u8, 209u8, 131u8, 208u8, 189u8, 209u8, 130u8, 208u8, 181u8,
+                        208u8, 184u8, 208u8, 189u8, 208u8, 180u8, 208u8, 184u8, 209u8, 152u8,
+                        209u8, 129u8, 208u8, 186u8, 208u8, 181u8, 32u8, 209u8, 128u8, 209u8, 131u8,
+                        208u8, 191u8, 208u8, 184u8, 209u8, 152u8, 208u8, 181u8, 209u8, 152u8,
+                        208u8, 176u8, 208u8, 191u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8,
+                        208u8, 186u8, 208u8, 176u8, 32u8, 209u8, 152u8, 208u8, 181u8, 208u8, 189u8,
+                        208u8, 176u8, 209u8, 152u8, 209u8, 131u8, 208u8, 182u8, 208u8, 189u8,
+                        208u8, 190u8, 208u8, 186u8, 208u8, 190u8, 209u8, 128u8, 208u8, 181u8,
+                        209u8, 152u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 32u8, 208u8, 178u8,
+                        208u8, 190u8, 208u8, 189u8, 208u8, 176u8, 208u8, 186u8, 209u8, 131u8,
+                        208u8, 178u8, 208u8, 176u8, 209u8, 152u8, 209u8, 130u8, 209u8, 129u8,
+                        208u8, 186u8, 97u8, 32u8, 208u8, 180u8, 208u8, 184u8, 208u8, 189u8, 208u8,
+                        176u8, 209u8, 128u8, 208u8, 176u8, 208u8, 188u8, 208u8, 181u8, 208u8,
+                        186u8, 209u8, 129u8, 208u8, 184u8, 209u8, 135u8, 208u8, 186u8, 208u8,
+                        176u8, 32u8, 208u8, 191u8, 208u8, 181u8, 208u8, 183u8, 208u8, 190u8, 209u8,
+                        129u8, 208u8, 176u8, 209u8, 132u8, 208u8, 184u8, 208u8, 187u8, 208u8,
+                        184u8, 208u8, 191u8, 208u8, 184u8, 208u8, 189u8, 209u8, 129u8, 208u8,
+                        186u8, 208u8, 176u8, 32u8, 208u8, 191u8, 208u8, 181u8, 208u8, 183u8, 208u8,
+                        190u8, 209u8, 129u8, 208u8, 176u8, 209u8, 129u8, 209u8, 128u8, 208u8,
+                        191u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 32u8, 208u8, 180u8, 208u8,
+                        184u8, 208u8, 189u8, 208u8, 176u8, 209u8, 128u8, 208u8, 176u8, 209u8,
+                        128u8, 209u8, 131u8, 209u8, 129u8, 208u8, 186u8, 208u8, 181u8, 32u8, 209u8,
+                        128u8, 209u8, 131u8, 208u8, 177u8, 209u8, 153u8, 208u8, 181u8, 209u8,
+                        136u8, 208u8, 178u8, 208u8, 181u8, 208u8, 180u8, 209u8, 129u8, 208u8,
+                        186u8, 208u8, 181u8, 32u8, 208u8, 186u8, 209u8, 128u8, 209u8, 131u8, 208u8,
+                        189u8, 208u8, 181u8, 209u8, 130u8, 208u8, 176u8, 209u8, 152u8, 208u8,
+                        187u8, 208u8, 176u8, 208u8, 189u8, 208u8, 180u8, 209u8, 129u8, 208u8,
+                        186u8, 208u8, 176u8, 32u8, 208u8, 177u8, 208u8, 176u8, 209u8, 130u8, 208u8,
+                        176u8, 209u8, 130u8, 209u8, 131u8, 209u8, 128u8, 209u8, 129u8, 208u8,
+                        186u8, 208u8, 181u8, 32u8, 208u8, 187u8, 208u8, 184u8, 209u8, 128u8, 208u8,
+                        181u8, 208u8, 176u8, 208u8, 188u8, 208u8, 181u8, 209u8, 128u8, 208u8,
+                        184u8, 209u8, 135u8, 208u8, 186u8, 208u8, 176u8, 32u8, 208u8, 180u8, 208u8,
+                        190u8, 208u8, 187u8, 208u8, 176u8, 209u8, 128u8, 208u8, 176u8, 209u8,
+                        152u8, 209u8, 131u8, 208u8, 182u8, 208u8, 189u8, 208u8, 190u8, 208u8,
+                        176u8, 209u8, 132u8, 209u8, 128u8, 208u8, 184u8, 209u8, 135u8, 208u8,
+                        186u8, 208u8, 176u8, 32u8, 209u8, 128u8, 208u8, 176u8, 208u8, 189u8, 208u8,
+                        180u8, 208u8, 176u8,
+                    ])
+                },
+            )
+        },
+    }),
+    many: None,
+    other: ::icu_currency::provider::CurrencyPluralNames {
+        pattern: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(" "),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 66u8, 82u8, 76u8,
+                        67u8, 65u8, 68u8, 67u8, 72u8, 70u8, 67u8, 76u8, 80u8, 67u8, 78u8, 89u8,
+                        68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8,
+                        73u8, 78u8, 82u8, 74u8, 80u8, 89u8, 75u8, 82u8, 87u8, 75u8, 87u8, 68u8,
+                        77u8, 88u8, 78u8, 80u8, 72u8, 80u8, 82u8, 83u8, 68u8, 82u8, 85u8, 66u8,
+                        83u8, 69u8, 75u8, 84u8, 72u8, 66u8, 84u8, 82u8, 89u8, 85u8, 83u8, 68u8,
+                        90u8, 65u8, 82u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        25u8, 0u8, 0u8, 0u8, 0u8, 0u8, 37u8, 0u8, 76u8, 0u8, 109u8, 0u8, 140u8,
+                        0u8, 171u8, 0u8, 208u8, 0u8, 241u8, 0u8, 12u8, 1u8, 37u8, 1u8, 68u8, 1u8,
+                        76u8, 1u8, 107u8, 1u8, 138u8, 1u8, 165u8, 1u8, 202u8, 1u8, 235u8, 1u8,
+                        10u8, 2u8, 45u8, 2u8, 72u8, 2u8, 95u8, 2u8, 122u8, 2u8, 153u8, 2u8, 176u8,
+                        2u8, 207u8, 2u8, 208u8, 176u8, 209u8, 128u8, 208u8, 179u8, 208u8, 181u8,
+                        208u8, 189u8, 209u8, 130u8, 208u8, 184u8, 208u8, 189u8, 209u8, 129u8,
+                        208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 208u8, 191u8, 208u8, 181u8,
+                        208u8, 183u8, 208u8, 190u8, 209u8, 129u8, 208u8, 176u8, 208u8, 176u8,
+                        209u8, 131u8, 209u8, 129u8, 209u8, 130u8, 209u8, 128u8, 208u8, 176u8,
+                        208u8, 187u8, 208u8, 184u8, 209u8, 152u8, 209u8, 129u8, 208u8, 186u8,
+                        208u8, 184u8, 209u8, 133u8, 32u8, 208u8, 180u8, 208u8, 190u8, 208u8, 187u8,
+                        208u8, 176u8, 209u8, 128u8, 208u8, 176u8, 208u8, 177u8, 208u8, 176u8,
+                        208u8, 189u8, 208u8, 179u8, 208u8, 187u8, 208u8, 176u8, 208u8, 180u8,
+                        208u8, 181u8, 209u8, 136u8, 208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8,
+                        209u8, 130u8, 208u8, 176u8, 208u8, 186u8, 208u8, 176u8, 208u8, 177u8,
+                        209u8, 128u8, 208u8, 176u8, 208u8, 183u8, 208u8, 184u8, 208u8, 187u8,
+                        209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 209u8, 128u8,
+                        208u8, 181u8, 208u8, 176u8, 208u8, 187u8, 208u8, 176u8, 208u8, 186u8,
+                        208u8, 176u8, 208u8, 189u8, 208u8, 176u8, 208u8, 180u8, 209u8, 129u8,
+                        208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 208u8, 180u8, 208u8, 190u8,
+                        208u8, 187u8, 208u8, 176u8, 209u8, 128u8, 208u8, 176u8, 209u8, 136u8,
+                        208u8, 178u8, 208u8, 176u8, 209u8, 152u8, 209u8, 134u8, 208u8, 176u8,
+                        209u8, 128u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8,
+                        209u8, 132u8, 209u8, 128u8, 208u8, 176u8, 208u8, 189u8, 208u8, 176u8,
+                        208u8, 186u8, 208u8, 176u8, 209u8, 135u8, 208u8, 184u8, 208u8, 187u8,
+                        208u8, 181u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8,
+                        208u8, 184u8, 209u8, 133u8, 32u8, 208u8, 191u8, 208u8, 181u8, 208u8, 183u8,
+                        208u8, 190u8, 209u8, 129u8, 208u8, 176u8, 208u8, 186u8, 208u8, 184u8,
+                        208u8, 189u8, 208u8, 181u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                        209u8, 133u8, 32u8, 209u8, 152u8, 209u8, 131u8, 208u8, 176u8, 208u8, 189u8,
+                        208u8, 176u8, 208u8, 180u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8,
+                        208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 208u8, 186u8, 209u8, 128u8,
+                        209u8, 131u8, 208u8, 189u8, 208u8, 176u8, 208u8, 181u8, 208u8, 179u8,
+                        208u8, 184u8, 208u8, 191u8, 208u8, 176u8, 209u8, 130u8, 209u8, 129u8,
+                        208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 209u8, 132u8, 209u8, 131u8,
+                        208u8, 189u8, 209u8, 130u8, 208u8, 184u8, 208u8, 181u8, 208u8, 178u8,
+                        209u8, 128u8, 208u8, 176u8, 208u8, 177u8, 209u8, 128u8, 208u8, 184u8,
+                        209u8, 130u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8,
+                        208u8, 184u8, 209u8, 133u8, 32u8, 209u8, 132u8, 209u8, 131u8, 208u8, 189u8,
+                        209u8, 130u8, 208u8, 184u8, 208u8, 184u8, 208u8, 189u8, 208u8, 180u8,
+                        208u8, 184u8, 209u8, 152u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                        209u8, 133u8, 32u8, 209u8, 128u8, 209u8, 131u8, 208u8, 191u8, 208u8, 184u8,
+                        209u8, 152u8, 208u8, 176u8, 209u8, 152u8, 208u8, 176u8, 208u8, 191u8,
+                        208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                        209u8, 133u8, 32u8, 209u8, 152u8, 208u8, 181u8, 208u8, 189u8, 208u8, 176u8,
+                        209u8, 152u8, 209u8, 131u8, 208u8, 182u8, 208u8, 189u8, 208u8, 190u8,
+                        208u8, 186u8, 208u8, 190u8, 209u8, 128u8, 208u8, 181u8, 209u8, 152u8,
+                        209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 208u8, 178u8,
+                        208u8, 190u8, 208u8, 189u8, 208u8, 176u8, 208u8, 186u8, 209u8, 131u8,
+                        208u8, 178u8, 208u8, 176u8, 209u8, 152u8, 209u8, 130u8, 209u8, 129u8,
+                        208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 208u8, 180u8, 208u8, 184u8,
+                        208u8, 189u8, 208u8, 176u8, 209u8, 128u8, 208u8, 176u8, 208u8, 188u8,
+                        208u8, 181u8, 208u8, 186u8, 209u8, 129u8, 208u8, 184u8, 209u8, 135u8,
+                        208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 208u8, 191u8, 208u8, 181u8,
+                        208u8, 183u8, 208u8, 190u8, 209u8, 129u8, 208u8, 176u8, 209u8, 132u8,
+                        208u8, 184u8, 208u8, 187u8, 208u8, 184u8, 208u8, 191u8, 208u8, 184u8,
+                        208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8,
+                        208u8, 191u8, 208u8, 181u8, 208u8, 183u8, 208u8, 190u8, 209u8, 129u8,
+                        208u8, 176u8, 209u8, 129u8, 209u8, 128u8, 208u8, 191u8, 209u8, 129u8,
+                        208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 208u8, 180u8, 208u8, 184u8,
+                        208u8, 189u8, 208u8, 176u8, 209u8, 128u8, 208u8, 176u8, 209u8, 128u8,
+                        209u8, 131u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8,
+                        209u8, 128u8, 209u8, 131u8, 208u8, 177u8, 209u8, 153u8, 208u8, 184u8,
+                        209u8, 136u8, 208u8, 178u8, 208u8, 181u8, 208u8, 180u8, 209u8, 129u8,
+                        208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 208u8, 186u8, 209u8, 128u8,
+                        209u8, 131u8, 208u8, 189u8, 208u8, 176u8, 209u8, 130u8, 208u8, 176u8,
+                        209u8, 152u8, 208u8, 187u8, 208u8, 176u8, 208u8, 189u8, 208u8, 180u8,
+                        209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 208u8, 177u8,
+                        208u8, 176u8, 209u8, 130u8, 208u8, 176u8, 209u8, 130u8, 209u8, 131u8,
+                        209u8, 128u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8,
+                        208u8, 187u8, 208u8, 184u8, 209u8, 128u8, 208u8, 176u8, 208u8, 176u8,
+                        208u8, 188u8, 208u8, 181u8, 209u8, 128u8, 208u8, 184u8, 209u8, 135u8,
+                        208u8, 186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 208u8, 180u8, 208u8, 190u8,
+                        208u8, 187u8, 208u8, 176u8, 209u8, 128u8, 208u8, 176u8, 209u8, 152u8,
+                        209u8, 131u8, 208u8, 182u8, 208u8, 189u8, 208u8, 190u8, 208u8, 176u8,
+                        209u8, 132u8, 209u8, 128u8, 208u8, 184u8, 209u8, 135u8, 208u8, 186u8,
+                        208u8, 184u8, 209u8, 133u8, 32u8, 209u8, 128u8, 208u8, 176u8, 208u8, 189u8,
+                        208u8, 180u8, 208u8, 176u8,
+                    ])
+                },
+            )
+        },
+    },
+};
+static TH: &DataStruct = &::icu_currency::provider::CurrencyNamesV1 {
+    zero: None,
+    one: None,
+    two: None,
+    few: None,
+    many: None,
+    other: ::icu_currency::provider::CurrencyPluralNames {
+        pattern: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(" "),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 66u8, 82u8, 76u8,
+                        67u8, 65u8, 68u8, 67u8, 72u8, 70u8, 67u8, 76u8, 80u8, 67u8, 78u8, 89u8,
+                        68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8,
+                        73u8, 78u8, 82u8, 74u8, 80u8, 89u8, 75u8, 82u8, 87u8, 75u8, 87u8, 68u8,
+                        77u8, 88u8, 78u8, 80u8, 72u8, 80u8, 82u8, 83u8, 68u8, 82u8, 85u8, 66u8,
+                        83u8, 69u8, 75u8, 84u8, 72u8, 66u8, 84u8, 82u8, 89u8, 85u8, 83u8, 68u8,
+                        90u8, 65u8, 82u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        25u8, 0u8, 0u8, 0u8, 0u8, 0u8, 45u8, 0u8, 96u8, 0u8, 135u8, 0u8, 168u8,
+                        0u8, 207u8, 0u8, 237u8, 0u8, 5u8, 1u8, 26u8, 1u8, 62u8, 1u8, 98u8, 1u8,
+                        110u8, 1u8, 194u8, 1u8, 227u8, 1u8, 1u8, 2u8, 37u8, 2u8, 70u8, 2u8, 106u8,
+                        2u8, 148u8, 2u8, 193u8, 2u8, 232u8, 2u8, 9u8, 3u8, 27u8, 3u8, 54u8, 3u8,
+                        90u8, 3u8, 224u8, 185u8, 128u8, 224u8, 184u8, 155u8, 224u8, 185u8, 130u8,
+                        224u8, 184u8, 139u8, 224u8, 184u8, 173u8, 224u8, 184u8, 178u8, 224u8,
+                        184u8, 163u8, 224u8, 185u8, 140u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                        136u8, 224u8, 184u8, 153u8, 224u8, 184u8, 149u8, 224u8, 184u8, 180u8,
+                        224u8, 184u8, 153u8, 224u8, 184u8, 178u8, 224u8, 184u8, 148u8, 224u8,
+                        184u8, 173u8, 224u8, 184u8, 165u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                        178u8, 224u8, 184u8, 163u8, 224u8, 185u8, 140u8, 224u8, 184u8, 173u8,
+                        224u8, 184u8, 173u8, 224u8, 184u8, 170u8, 224u8, 185u8, 128u8, 224u8,
+                        184u8, 149u8, 224u8, 184u8, 163u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                        165u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 149u8,
+                        224u8, 184u8, 178u8, 224u8, 184u8, 129u8, 224u8, 184u8, 178u8, 224u8,
+                        184u8, 154u8, 224u8, 184u8, 177u8, 224u8, 184u8, 135u8, 224u8, 184u8,
+                        129u8, 224u8, 184u8, 165u8, 224u8, 184u8, 178u8, 224u8, 185u8, 128u8,
+                        224u8, 184u8, 151u8, 224u8, 184u8, 168u8, 224u8, 185u8, 128u8, 224u8,
+                        184u8, 163u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8,
+                        165u8, 224u8, 184u8, 154u8, 224u8, 184u8, 163u8, 224u8, 184u8, 178u8,
+                        224u8, 184u8, 139u8, 224u8, 184u8, 180u8, 224u8, 184u8, 165u8, 224u8,
+                        184u8, 148u8, 224u8, 184u8, 173u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                        165u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 185u8, 140u8,
+                        224u8, 185u8, 129u8, 224u8, 184u8, 132u8, 224u8, 184u8, 153u8, 224u8,
+                        184u8, 178u8, 224u8, 184u8, 148u8, 224u8, 184u8, 178u8, 224u8, 184u8,
+                        159u8, 224u8, 184u8, 163u8, 224u8, 184u8, 177u8, 224u8, 184u8, 135u8,
+                        224u8, 184u8, 129u8, 224u8, 185u8, 140u8, 224u8, 184u8, 170u8, 224u8,
+                        184u8, 167u8, 224u8, 184u8, 180u8, 224u8, 184u8, 170u8, 224u8, 185u8,
+                        128u8, 224u8, 184u8, 155u8, 224u8, 185u8, 130u8, 224u8, 184u8, 139u8,
+                        224u8, 184u8, 138u8, 224u8, 184u8, 180u8, 224u8, 184u8, 165u8, 224u8,
+                        184u8, 181u8, 224u8, 184u8, 171u8, 224u8, 184u8, 162u8, 224u8, 184u8,
+                        167u8, 224u8, 184u8, 153u8, 224u8, 184u8, 136u8, 224u8, 184u8, 181u8,
+                        224u8, 184u8, 153u8, 224u8, 185u8, 130u8, 224u8, 184u8, 132u8, 224u8,
+                        184u8, 163u8, 224u8, 184u8, 153u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                        148u8, 224u8, 184u8, 153u8, 224u8, 184u8, 161u8, 224u8, 184u8, 178u8,
+                        224u8, 184u8, 163u8, 224u8, 185u8, 140u8, 224u8, 184u8, 129u8, 224u8,
+                        184u8, 155u8, 224u8, 184u8, 173u8, 224u8, 184u8, 153u8, 224u8, 184u8,
+                        148u8, 224u8, 185u8, 140u8, 224u8, 184u8, 173u8, 224u8, 184u8, 181u8,
+                        224u8, 184u8, 162u8, 224u8, 184u8, 180u8, 224u8, 184u8, 155u8, 224u8,
+                        184u8, 149u8, 224u8, 185u8, 140u8, 224u8, 184u8, 162u8, 224u8, 184u8,
+                        185u8, 224u8, 185u8, 130u8, 224u8, 184u8, 163u8, 224u8, 184u8, 155u8,
+                        224u8, 184u8, 173u8, 224u8, 184u8, 153u8, 224u8, 184u8, 148u8, 224u8,
+                        185u8, 140u8, 224u8, 184u8, 170u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                        149u8, 224u8, 184u8, 173u8, 224u8, 184u8, 163u8, 224u8, 185u8, 140u8,
+                        224u8, 184u8, 165u8, 224u8, 184u8, 180u8, 224u8, 184u8, 135u8, 32u8, 40u8,
+                        224u8, 184u8, 170u8, 224u8, 184u8, 171u8, 224u8, 184u8, 163u8, 224u8,
+                        184u8, 178u8, 224u8, 184u8, 138u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                        178u8, 224u8, 184u8, 147u8, 224u8, 184u8, 178u8, 224u8, 184u8, 136u8,
+                        224u8, 184u8, 177u8, 224u8, 184u8, 129u8, 224u8, 184u8, 163u8, 41u8, 224u8,
+                        184u8, 163u8, 224u8, 184u8, 185u8, 224u8, 184u8, 155u8, 224u8, 184u8,
+                        181u8, 224u8, 184u8, 173u8, 224u8, 184u8, 180u8, 224u8, 184u8, 153u8,
+                        224u8, 185u8, 128u8, 224u8, 184u8, 148u8, 224u8, 184u8, 181u8, 224u8,
+                        184u8, 162u8, 224u8, 185u8, 128u8, 224u8, 184u8, 162u8, 224u8, 184u8,
+                        153u8, 224u8, 184u8, 141u8, 224u8, 184u8, 181u8, 224u8, 185u8, 136u8,
+                        224u8, 184u8, 155u8, 224u8, 184u8, 184u8, 224u8, 185u8, 136u8, 224u8,
+                        184u8, 153u8, 224u8, 184u8, 167u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                        153u8, 224u8, 185u8, 128u8, 224u8, 184u8, 129u8, 224u8, 184u8, 178u8,
+                        224u8, 184u8, 171u8, 224u8, 184u8, 165u8, 224u8, 184u8, 181u8, 224u8,
+                        185u8, 131u8, 224u8, 184u8, 149u8, 224u8, 185u8, 137u8, 224u8, 184u8,
+                        148u8, 224u8, 184u8, 181u8, 224u8, 184u8, 153u8, 224u8, 184u8, 178u8,
+                        224u8, 184u8, 163u8, 224u8, 185u8, 140u8, 224u8, 184u8, 132u8, 224u8,
+                        184u8, 185u8, 224u8, 185u8, 128u8, 224u8, 184u8, 167u8, 224u8, 184u8,
+                        149u8, 224u8, 185u8, 128u8, 224u8, 184u8, 155u8, 224u8, 185u8, 130u8,
+                        224u8, 184u8, 139u8, 224u8, 185u8, 128u8, 224u8, 184u8, 161u8, 224u8,
+                        185u8, 135u8, 224u8, 184u8, 129u8, 224u8, 184u8, 139u8, 224u8, 184u8,
+                        180u8, 224u8, 185u8, 130u8, 224u8, 184u8, 129u8, 224u8, 185u8, 128u8,
+                        224u8, 184u8, 155u8, 224u8, 185u8, 130u8, 224u8, 184u8, 139u8, 224u8,
+                        184u8, 159u8, 224u8, 184u8, 180u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                        180u8, 224u8, 184u8, 155u8, 224u8, 184u8, 155u8, 224u8, 184u8, 180u8,
+                        224u8, 184u8, 153u8, 224u8, 184u8, 170u8, 224u8, 185u8, 140u8, 224u8,
+                        184u8, 148u8, 224u8, 184u8, 181u8, 224u8, 184u8, 153u8, 224u8, 184u8,
+                        178u8, 224u8, 184u8, 163u8, 224u8, 185u8, 140u8, 224u8, 185u8, 128u8,
+                        224u8, 184u8, 139u8, 224u8, 184u8, 173u8, 224u8, 184u8, 163u8, 224u8,
+                        185u8, 140u8, 224u8, 185u8, 128u8, 224u8, 184u8, 154u8, 224u8, 184u8,
+                        181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 163u8, 224u8, 184u8, 185u8,
+                        224u8, 185u8, 128u8, 224u8, 184u8, 154u8, 224u8, 184u8, 180u8, 224u8,
+                        184u8, 165u8, 224u8, 184u8, 163u8, 224u8, 184u8, 177u8, 224u8, 184u8,
+                        170u8, 224u8, 185u8, 128u8, 224u8, 184u8, 139u8, 224u8, 184u8, 181u8,
+                        224u8, 184u8, 162u8, 224u8, 185u8, 130u8, 224u8, 184u8, 132u8, 224u8,
+                        184u8, 163u8, 224u8, 184u8, 153u8, 224u8, 184u8, 178u8, 224u8, 184u8,
+                        170u8, 224u8, 184u8, 167u8, 224u8, 184u8, 181u8, 224u8, 185u8, 128u8,
+                        224u8, 184u8, 148u8, 224u8, 184u8, 153u8, 224u8, 184u8, 154u8, 224u8,
+                        184u8, 178u8, 224u8, 184u8, 151u8, 224u8, 185u8, 132u8, 224u8, 184u8,
+                        151u8, 224u8, 184u8, 162u8, 224u8, 184u8, 165u8, 224u8, 184u8, 181u8,
+                        224u8, 184u8, 163u8, 224u8, 184u8, 178u8, 224u8, 184u8, 149u8, 224u8,
+                        184u8, 184u8, 224u8, 184u8, 163u8, 224u8, 184u8, 129u8, 224u8, 184u8,
+                        181u8, 224u8, 184u8, 148u8, 224u8, 184u8, 173u8, 224u8, 184u8, 165u8,
+                        224u8, 184u8, 165u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 224u8,
+                        185u8, 140u8, 224u8, 184u8, 170u8, 224u8, 184u8, 171u8, 224u8, 184u8,
+                        163u8, 224u8, 184u8, 177u8, 224u8, 184u8, 144u8, 224u8, 185u8, 129u8,
+                        224u8, 184u8, 163u8, 224u8, 184u8, 153u8, 224u8, 184u8, 148u8, 224u8,
+                        185u8, 140u8, 224u8, 185u8, 129u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                        159u8, 224u8, 184u8, 163u8, 224u8, 184u8, 180u8, 224u8, 184u8, 129u8,
+                        224u8, 184u8, 178u8, 224u8, 185u8, 131u8, 224u8, 184u8, 149u8, 224u8,
+                        185u8, 137u8,
+                    ])
+                },
+            )
+        },
+    },
+};
+static TR: &DataStruct = &::icu_currency::provider::CurrencyNamesV1 {
+    zero: None,
+    one: Some(::icu_currency::provider::CurrencyPluralNames {
+        pattern: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(" "),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+    }),
+    two: None,
+    few: None,
+    many: None,
+    other: ::icu_currency::provider::CurrencyPluralNames {
+        pattern: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(" "),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        65u8, 82u8, 83u8, 65u8, 85u8, 68u8, 66u8, 68u8, 84u8, 66u8, 82u8, 76u8,
+                        67u8, 65u8, 68u8, 67u8, 72u8, 70u8, 67u8, 76u8, 80u8, 67u8, 78u8, 89u8,
+                        68u8, 75u8, 75u8, 69u8, 71u8, 80u8, 69u8, 85u8, 82u8, 71u8, 66u8, 80u8,
+                        73u8, 78u8, 82u8, 74u8, 80u8, 89u8, 75u8, 82u8, 87u8, 75u8, 87u8, 68u8,
+                        77u8, 88u8, 78u8, 80u8, 72u8, 80u8, 82u8, 83u8, 68u8, 82u8, 85u8, 66u8,
+                        83u8, 69u8, 75u8, 84u8, 72u8, 66u8, 84u8, 82u8, 89u8, 85u8, 83u8, 68u8,
+                        90u8, 65u8, 82u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        25u8, 0u8, 0u8, 0u8, 0u8, 0u8, 15u8, 0u8, 33u8, 0u8, 51u8, 0u8, 65u8, 0u8,
+                        79u8, 0u8, 96u8, 0u8, 108u8, 0u8, 119u8, 0u8, 134u8, 0u8, 149u8, 0u8,
+                        153u8, 0u8, 170u8, 0u8, 186u8, 0u8, 196u8, 0u8, 212u8, 0u8, 226u8, 0u8,
+                        240u8, 0u8, 1u8, 1u8, 14u8, 1u8, 25u8, 1u8, 38u8, 1u8, 52u8, 1u8, 68u8,
+                        1u8, 79u8, 1u8, 65u8, 114u8, 106u8, 97u8, 110u8, 116u8, 105u8, 110u8, 32u8,
+                        112u8, 101u8, 115u8, 111u8, 115u8, 117u8, 65u8, 118u8, 117u8, 115u8, 116u8,
+                        114u8, 97u8, 108u8, 121u8, 97u8, 32u8, 100u8, 111u8, 108u8, 97u8, 114u8,
+                        196u8, 177u8, 66u8, 97u8, 110u8, 103u8, 108u8, 97u8, 100u8, 101u8, 197u8,
+                        159u8, 32u8, 116u8, 97u8, 107u8, 97u8, 115u8, 196u8, 177u8, 66u8, 114u8,
+                        101u8, 122u8, 105u8, 108u8, 121u8, 97u8, 32u8, 114u8, 101u8, 97u8, 108u8,
+                        105u8, 75u8, 97u8, 110u8, 97u8, 100u8, 97u8, 32u8, 100u8, 111u8, 108u8,
+                        97u8, 114u8, 196u8, 177u8, 196u8, 176u8, 115u8, 118u8, 105u8, 195u8, 167u8,
+                        114u8, 101u8, 32u8, 102u8, 114u8, 97u8, 110u8, 103u8, 196u8, 177u8, 197u8,
+                        158u8, 105u8, 108u8, 105u8, 32u8, 112u8, 101u8, 115u8, 111u8, 115u8, 117u8,
+                        195u8, 135u8, 105u8, 110u8, 32u8, 121u8, 117u8, 97u8, 110u8, 196u8, 177u8,
+                        68u8, 97u8, 110u8, 105u8, 109u8, 97u8, 114u8, 107u8, 97u8, 32u8, 107u8,
+                        114u8, 111u8, 110u8, 117u8, 77u8, 196u8, 177u8, 115u8, 196u8, 177u8, 114u8,
+                        32u8, 108u8, 105u8, 114u8, 97u8, 115u8, 196u8, 177u8, 69u8, 117u8, 114u8,
+                        111u8, 196u8, 176u8, 110u8, 103u8, 105u8, 108u8, 105u8, 122u8, 32u8, 115u8,
+                        116u8, 101u8, 114u8, 108u8, 105u8, 110u8, 105u8, 72u8, 105u8, 110u8, 100u8,
+                        105u8, 115u8, 116u8, 97u8, 110u8, 32u8, 114u8, 117u8, 112u8, 105u8, 115u8,
+                        105u8, 74u8, 97u8, 112u8, 111u8, 110u8, 32u8, 121u8, 101u8, 110u8, 105u8,
+                        71u8, 195u8, 188u8, 110u8, 101u8, 121u8, 32u8, 75u8, 111u8, 114u8, 101u8,
+                        32u8, 119u8, 111u8, 110u8, 117u8, 75u8, 117u8, 118u8, 101u8, 121u8, 116u8,
+                        32u8, 100u8, 105u8, 110u8, 97u8, 114u8, 196u8, 177u8, 77u8, 101u8, 107u8,
+                        115u8, 105u8, 107u8, 97u8, 32u8, 112u8, 101u8, 115u8, 111u8, 115u8, 117u8,
+                        70u8, 105u8, 108u8, 105u8, 112u8, 105u8, 110u8, 108u8, 101u8, 114u8, 32u8,
+                        112u8, 101u8, 115u8, 111u8, 115u8, 117u8, 83u8, 196u8, 177u8, 114u8, 112u8,
+                        32u8, 100u8, 105u8, 110u8, 97u8, 114u8, 196u8, 177u8, 82u8, 117u8, 115u8,
+                        32u8, 114u8, 117u8, 98u8, 108u8, 101u8, 115u8, 105u8, 196u8, 176u8, 115u8,
+                        118u8, 101u8, 195u8, 167u8, 32u8, 107u8, 114u8, 111u8, 110u8, 117u8, 84u8,
+                        97u8, 121u8, 108u8, 97u8, 110u8, 100u8, 32u8, 98u8, 97u8, 104u8, 116u8,
+                        196u8, 177u8, 226u8, 130u8, 186u8, 84u8, 195u8, 188u8, 114u8, 107u8, 32u8,
+                        108u8, 105u8, 114u8, 97u8, 115u8, 196u8, 177u8, 65u8, 66u8, 68u8, 32u8,
+                        100u8, 111u8, 108u8, 97u8, 114u8, 196u8, 177u8, 71u8, 195u8, 188u8, 110u8,
+                        101u8, 121u8, 32u8, 65u8, 102u8, 114u8, 105u8, 107u8, 97u8, 32u8, 114u8,
+                        97u8, 110u8, 100u8, 196u8, 177u8,
+                    ])
+                },
+            )
+        },
+    },
+};
+static UND: &DataStruct = &::icu_currency::provider::CurrencyNamesV1 {
+    zero: None,
+    one: None,
+    two: None,
+    few: None,
+    many: None,
+    other: ::icu_currency::provider::CurrencyPluralNames {
+        pattern: ::icu_currency::provider::CurrencyPattern {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            infix: alloc::borrow::Cow::Borrowed(" "),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+            currency_first: false,
+        },
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+    },
+};
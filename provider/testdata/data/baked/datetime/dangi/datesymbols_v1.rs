@@ -0,0 +1,2711 @@
+// @generated
+#![cfg(feature = "icu_datetime")]
+type DataStruct = < :: icu_datetime :: provider :: calendar :: DangiDateSymbolsV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[
+        ("ar", AR_AR_EG),
+        ("ar-EG", AR_AR_EG),
+        ("bn", BN),
+        ("ccp", CCP),
+        ("en", EN_EN_001_EN_ZA),
+        ("en-001", EN_EN_001_EN_ZA),
+        ("en-ZA", EN_EN_001_EN_ZA),
+        ("es", ES),
+        ("es-AR", ES_AR),
+        ("fil", FIL),
+        ("fr", FR),
+        ("ja", JA),
+        ("ru", RU),
+        ("sr", SR_SR_CYRL),
+        ("sr-Cyrl", SR_SR_CYRL),
+        ("sr-Latn", SR_LATN),
+        ("th", TH),
+        ("tr", TR),
+        ("und", UND),
+    ]);
+static AR_AR_EG: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
+    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 9u8, 0u8, 12u8, 0u8, 18u8,
+                            0u8, 21u8, 0u8, 27u8, 0u8, 30u8, 0u8, 36u8, 0u8, 39u8, 0u8, 45u8, 0u8,
+                            48u8, 0u8, 54u8, 0u8, 57u8, 0u8, 63u8, 0u8, 66u8, 0u8, 72u8, 0u8, 75u8,
+                            0u8, 81u8, 0u8, 84u8, 0u8, 90u8, 0u8, 93u8, 0u8, 99u8, 0u8, 102u8, 0u8,
+                            77u8, 48u8, 49u8, 77u8, 48u8, 49u8, 98u8, 105u8, 115u8, 77u8, 48u8,
+                            50u8, 77u8, 48u8, 50u8, 98u8, 105u8, 115u8, 77u8, 48u8, 51u8, 77u8,
+                            48u8, 51u8, 98u8, 105u8, 115u8, 77u8, 48u8, 52u8, 77u8, 48u8, 52u8,
+                            98u8, 105u8, 115u8, 77u8, 48u8, 53u8, 77u8, 48u8, 53u8, 98u8, 105u8,
+                            115u8, 77u8, 48u8, 54u8, 77u8, 48u8, 54u8, 98u8, 105u8, 115u8, 77u8,
+                            48u8, 55u8, 77u8, 48u8, 55u8, 98u8, 105u8, 115u8, 77u8, 48u8, 56u8,
+                            77u8, 48u8, 56u8, 98u8, 105u8, 115u8, 77u8, 48u8, 57u8, 77u8, 48u8,
+                            57u8, 98u8, 105u8, 115u8, 77u8, 49u8, 48u8, 77u8, 49u8, 48u8, 98u8,
+                            105u8, 115u8, 77u8, 49u8, 49u8, 77u8, 49u8, 49u8, 98u8, 105u8, 115u8,
+                            77u8, 49u8, 50u8, 77u8, 49u8, 50u8, 98u8, 105u8, 115u8,
+                        ])
+                    },
+                )
+            }),
+            narrow: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 3u8, 0u8, 4u8, 0u8, 6u8, 0u8,
+                            7u8, 0u8, 9u8, 0u8, 10u8, 0u8, 12u8, 0u8, 13u8, 0u8, 15u8, 0u8, 16u8,
+                            0u8, 18u8, 0u8, 19u8, 0u8, 21u8, 0u8, 22u8, 0u8, 24u8, 0u8, 25u8, 0u8,
+                            27u8, 0u8, 29u8, 0u8, 32u8, 0u8, 34u8, 0u8, 37u8, 0u8, 39u8, 0u8, 49u8,
+                            49u8, 98u8, 50u8, 50u8, 98u8, 51u8, 51u8, 98u8, 52u8, 52u8, 98u8, 53u8,
+                            53u8, 98u8, 54u8, 54u8, 98u8, 55u8, 55u8, 98u8, 56u8, 56u8, 98u8, 57u8,
+                            57u8, 98u8, 49u8, 48u8, 49u8, 48u8, 98u8, 49u8, 49u8, 49u8, 49u8, 98u8,
+                            49u8, 50u8, 49u8, 50u8, 98u8,
+                        ])
+                    },
+                )
+            }),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 9u8, 0u8, 12u8, 0u8, 18u8,
+                            0u8, 21u8, 0u8, 27u8, 0u8, 30u8, 0u8, 36u8, 0u8, 39u8, 0u8, 45u8, 0u8,
+                            48u8, 0u8, 54u8, 0u8, 57u8, 0u8, 63u8, 0u8, 66u8, 0u8, 72u8, 0u8, 75u8,
+                            0u8, 81u8, 0u8, 84u8, 0u8, 90u8, 0u8, 93u8, 0u8, 99u8, 0u8, 102u8, 0u8,
+                            77u8, 48u8, 49u8, 77u8, 48u8, 49u8, 98u8, 105u8, 115u8, 77u8, 48u8,
+                            50u8, 77u8, 48u8, 50u8, 98u8, 105u8, 115u8, 77u8, 48u8, 51u8, 77u8,
+                            48u8, 51u8, 98u8, 105u8, 115u8, 77u8, 48u8, 52u8, 77u8, 48u8, 52u8,
+                            98u8, 105u8, 115u8, 77u8, 48u8, 53u8, 77u8, 48u8, 53u8, 98u8, 105u8,
+                            115u8, 77u8, 48u8, 54u8, 77u8, 48u8, 54u8, 98u8, 105u8, 115u8, 77u8,
+                            48u8, 55u8, 77u8, 48u8, 55u8, 98u8, 105u8, 115u8, 77u8, 48u8, 56u8,
+                            77u8, 48u8, 56u8, 98u8, 105u8, 115u8, 77u8, 48u8, 57u8, 77u8, 48u8,
+                            57u8, 98u8, 105u8, 115u8, 77u8, 49u8, 48u8, 77u8, 49u8, 48u8, 98u8,
+                            105u8, 115u8, 77u8, 49u8, 49u8, 77u8, 49u8, 49u8, 98u8, 105u8, 115u8,
+                            77u8, 49u8, 50u8, 77u8, 49u8, 50u8, 98u8, 105u8, 115u8,
+                        ])
+                    },
+                )
+            }),
+        },
+        stand_alone: None,
+    },
+    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("الأحد"),
+                alloc::borrow::Cow::Borrowed("الاثنين"),
+                alloc::borrow::Cow::Borrowed("الثلاثاء"),
+                alloc::borrow::Cow::Borrowed("الأربعاء"),
+                alloc::borrow::Cow::Borrowed("الخميس"),
+                alloc::borrow::Cow::Borrowed("الجمعة"),
+                alloc::borrow::Cow::Borrowed("السبت"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("ح"),
+                alloc::borrow::Cow::Borrowed("ن"),
+                alloc::borrow::Cow::Borrowed("ث"),
+                alloc::borrow::Cow::Borrowed("ر"),
+                alloc::borrow::Cow::Borrowed("خ"),
+                alloc::borrow::Cow::Borrowed("ج"),
+                alloc::borrow::Cow::Borrowed("س"),
+            ]),
+            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("أحد"),
+                alloc::borrow::Cow::Borrowed("إثنين"),
+                alloc::borrow::Cow::Borrowed("ثلاثاء"),
+                alloc::borrow::Cow::Borrowed("أربعاء"),
+                alloc::borrow::Cow::Borrowed("خميس"),
+                alloc::borrow::Cow::Borrowed("جمعة"),
+                alloc::borrow::Cow::Borrowed("سبت"),
+            ])),
+            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("الأحد"),
+                alloc::borrow::Cow::Borrowed("الاثنين"),
+                alloc::borrow::Cow::Borrowed("الثلاثاء"),
+                alloc::borrow::Cow::Borrowed("الأربعاء"),
+                alloc::borrow::Cow::Borrowed("الخميس"),
+                alloc::borrow::Cow::Borrowed("الجمعة"),
+                alloc::borrow::Cow::Borrowed("السبت"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    eras: ::icu_datetime::provider::calendar::Eras {
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+        abbr: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+        narrow: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+    },
+    cyclic_years: None,
+};
+static BN: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
+    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 9u8, 0u8, 12u8, 0u8, 18u8,
+                            0u8, 21u8, 0u8, 27u8, 0u8, 30u8, 0u8, 36u8, 0u8, 39u8, 0u8, 45u8, 0u8,
+                            48u8, 0u8, 54u8, 0u8, 57u8, 0u8, 63u8, 0u8, 66u8, 0u8, 72u8, 0u8, 75u8,
+                            0u8, 81u8, 0u8, 84u8, 0u8, 90u8, 0u8, 93u8, 0u8, 99u8, 0u8, 102u8, 0u8,
+                            77u8, 48u8, 49u8, 77u8, 48u8, 49u8, 98u8, 105u8, 115u8, 77u8, 48u8,
+                            50u8, 77u8, 48u8, 50u8, 98u8, 105u8, 115u8, 77u8, 48u8, 51u8, 77u8,
+                            48u8, 51u8, 98u8, 105u8, 115u8, 77u8, 48u8, 52u8, 77u8, 48u8, 52u8,
+                            98u8, 105u8, 115u8, 77u8, 48u8, 53u8, 77u8, 48u8, 53u8, 98u8, 105u8,
+                            115u8, 77u8, 48u8, 54u8, 77u8, 48u8, 54u8, 98u8, 105u8, 115u8, 77u8,
+                            48u8, 55u8, 77u8, 48u8, 55u8, 98u8, 105u8, 115u8, 77u8, 48u8, 56u8,
+                            77u8, 48u8, 56u8, 98u8, 105u8, 115u8, 77u8, 48u8, 57u8, 77u8, 48u8,
+                            57u8, 98u8, 105u8, 115u8, 77u8, 49u8, 48u8, 77u8, 49u8, 48u8, 98u8,
+                            105u8, 115u8, 77u8, 49u8, 49u8, 77u8, 49u8, 49u8, 98u8, 105u8, 115u8,
+                            77u8, 49u8, 50u8, 77u8, 49u8, 50u8, 98u8, 105u8, 115u8,
+                        ])
+                    },
+                )
+            }),
+            narrow: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 3u8, 0u8, 4u8, 0u8, 6u8, 0u8,
+                            7u8, 0u8, 9u8, 0u8, 10u8, 0u8, 12u8, 0u8, 13u8, 0u8, 15u8, 0u8, 16u8,
+                            0u8, 18u8, 0u8, 19u8, 0u8, 21u8, 0u8, 22u8, 0u8, 24u8, 0u8, 25u8, 0u8,
+                            27u8, 0u8, 29u8, 0u8, 32u8, 0u8, 34u8, 0u8, 37u8, 0u8, 39u8, 0u8, 49u8,
+                            49u8, 98u8, 50u8, 50u8, 98u8, 51u8, 51u8, 98u8, 52u8, 52u8, 98u8, 53u8,
+                            53u8, 98u8, 54u8, 54u8, 98u8, 55u8, 55u8, 98u8, 56u8, 56u8, 98u8, 57u8,
+                            57u8, 98u8, 49u8, 48u8, 49u8, 48u8, 98u8, 49u8, 49u8, 49u8, 49u8, 98u8,
+                            49u8, 50u8, 49u8, 50u8, 98u8,
+                        ])
+                    },
+                )
+            }),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 9u8, 0u8, 12u8, 0u8, 18u8,
+                            0u8, 21u8, 0u8, 27u8, 0u8, 30u8, 0u8, 36u8, 0u8, 39u8, 0u8, 45u8, 0u8,
+                            48u8, 0u8, 54u8, 0u8, 57u8, 0u8, 63u8, 0u8, 66u8, 0u8, 72u8, 0u8, 75u8,
+                            0u8, 81u8, 0u8, 84u8, 0u8, 90u8, 0u8, 93u8, 0u8, 99u8, 0u8, 102u8, 0u8,
+                            77u8, 48u8, 49u8, 77u8, 48u8, 49u8, 98u8, 105u8, 115u8, 77u8, 48u8,
+                            50u8, 77u8, 48u8, 50u8, 98u8, 105u8, 115u8, 77u8, 48u8, 51u8, 77u8,
+                            48u8, 51u8, 98u8, 105u8, 115u8, 77u8, 48u8, 52u8, 77u8, 48u8, 52u8,
+                            98u8, 105u8, 115u8, 77u8, 48u8, 53u8, 77u8, 48u8, 53u8, 98u8, 105u8,
+                            115u8, 77u8, 48u8, 54u8, 77u8, 48u8, 54u8, 98u8, 105u8, 115u8, 77u8,
+                            48u8, 55u8, 77u8, 48u8, 55u8, 98u8, 105u8, 115u8, 77u8, 48u8, 56u8,
+                            77u8, 48u8, 56u8, 98u8, 105u8, 115u8, 77u8, 48u8, 57u8, 77u8, 48u8,
+                            57u8, 98u8, 105u8, 115u8, 77u8, 49u8, 48u8, 77u8, 49u8, 48u8, 98u8,
+                            105u8, 115u8, 77u8, 49u8, 49u8, 77u8, 49u8, 49u8, 98u8, 105u8, 115u8,
+                            77u8, 49u8, 50u8, 77u8, 49u8, 50u8, 98u8, 105u8, 115u8,
+                        ])
+                    },
+                )
+            }),
+        },
+        stand_alone: None,
+    },
+    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("রবি"),
+                alloc::borrow::Cow::Borrowed("সোম"),
+                alloc::borrow::Cow::Borrowed("মঙ\u{9cd}গল"),
+                alloc::borrow::Cow::Borrowed("ব\u{9c1}ধ"),
+                alloc::borrow::Cow::Borrowed("ব\u{9c3}হস\u{9cd}পতি"),
+                alloc::borrow::Cow::Borrowed("শ\u{9c1}ক\u{9cd}র"),
+                alloc::borrow::Cow::Borrowed("শনি"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("র"),
+                alloc::borrow::Cow::Borrowed("সো"),
+                alloc::borrow::Cow::Borrowed("ম"),
+                alloc::borrow::Cow::Borrowed("ব\u{9c1}"),
+                alloc::borrow::Cow::Borrowed("ব\u{9c3}"),
+                alloc::borrow::Cow::Borrowed("শ\u{9c1}"),
+                alloc::borrow::Cow::Borrowed("শ"),
+            ]),
+            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("রঃ"),
+                alloc::borrow::Cow::Borrowed("সোঃ"),
+                alloc::borrow::Cow::Borrowed("মঃ"),
+                alloc::borrow::Cow::Borrowed("ব\u{9c1}ঃ"),
+                alloc::borrow::Cow::Borrowed("ব\u{9c3}ঃ"),
+                alloc::borrow::Cow::Borrowed("শ\u{9c1}ঃ"),
+                alloc::borrow::Cow::Borrowed("শনি"),
+            ])),
+            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("রবিব\u{9be}র"),
+                alloc::borrow::Cow::Borrowed("সোমব\u{9be}র"),
+                alloc::borrow::Cow::Borrowed("মঙ\u{9cd}গলব\u{9be}র"),
+                alloc::borrow::Cow::Borrowed("ব\u{9c1}ধব\u{9be}র"),
+                alloc::borrow::Cow::Borrowed("ব\u{9c3}হস\u{9cd}পতিব\u{9be}র"),
+                alloc::borrow::Cow::Borrowed("শ\u{9c1}ক\u{9cd}রব\u{9be}র"),
+                alloc::borrow::Cow::Borrowed("শনিব\u{9be}র"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    eras: ::icu_datetime::provider::calendar::Eras {
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+        abbr: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+        narrow: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+    },
+    cyclic_years: None,
+};
+static CCP: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
+    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 9u8, 0u8, 12u8, 0u8, 18u8,
+                            0u8, 21u8, 0u8, 27u8, 0u8, 30u8, 0u8, 36u8, 0u8, 39u8, 0u8, 45u8, 0u8,
+                            48u8, 0u8, 54u8, 0u8, 57u8, 0u8, 63u8, 0u8, 66u8, 0u8, 72u8, 0u8, 75u8,
+                            0u8, 81u8, 0u8, 84u8, 0u8, 90u8, 0u8, 93u8, 0u8, 99u8, 0u8, 102u8, 0u8,
+                            77u8, 48u8, 49u8, 77u8, 48u8, 49u8, 98u8, 105u8, 115u8, 77u8, 48u8,
+                            50u8, 77u8, 48u8, 50u8, 98u8, 105u8, 115u8, 77u8, 48u8, 51u8, 77u8,
+                            48u8, 51u8, 98u8, 105u8, 115u8, 77u8, 48u8, 52u8, 77u8, 48u8, 52u8,
+                            98u8, 105u8, 115u8, 77u8, 48u8, 53u8, 77u8, 48u8, 53u8, 98u8, 105u8,
+                            115u8, 77u8, 48u8, 54u8, 77u8, 48u8, 54u8, 98u8, 105u8, 115u8, 77u8,
+                            48u8, 55u8, 77u8, 48u8, 55u8, 98u8, 105u8, 115u8, 77u8, 48u8, 56u8,
+                            77u8, 48u8, 56u8, 98u8, 105u8, 115u8, 77u8, 48u8, 57u8, 77u8, 48u8,
+                            57u8, 98u8, 105u8, 115u8, 77u8, 49u8, 48u8, 77u8, 49u8, 48u8, 98u8,
+                            105u8, 115u8, 77u8, 49u8, 49u8, 77u8, 49u8, 49u8, 98u8, 105u8, 115u8,
+                            77u8, 49u8, 50u8, 77u8, 49u8, 50u8, 98u8, 105u8, 115u8,
+                        ])
+                    },
+                )
+            }),
+            narrow: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 3u8, 0u8, 4u8, 0u8, 6u8, 0u8,
+                            7u8, 0u8, 9u8, 0u8, 10u8, 0u8, 12u8, 0u8, 13u8, 0u8, 15u8, 0u8, 16u8,
+                            0u8, 18u8, 0u8, 19u8, 0u8, 21u8, 0u8, 22u8, 0u8, 24u8, 0u8, 25u8, 0u8,
+                            27u8, 0u8, 29u8, 0u8, 32u8, 0u8, 34u8, 0u8, 37u8, 0u8, 39u8, 0u8, 49u8,
+                            49u8, 98u8, 50u8, 50u8, 98u8, 51u8, 51u8, 98u8, 52u8, 52u8, 98u8, 53u8,
+                            53u8, 98u8, 54u8, 54u8, 98u8, 55u8, 55u8, 98u8, 56u8, 56u8, 98u8, 57u8,
+                            57u8, 98u8, 49u8, 48u8, 49u8, 48u8, 98u8, 49u8, 49u8, 49u8, 49u8, 98u8,
+                            49u8, 50u8, 49u8, 50u8, 98u8,
+                        ])
+                    },
+                )
+            }),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 9u8, 0u8, 12u8, 0u8, 18u8,
+                            0u8, 21u8, 0u8, 27u8, 0u8, 30u8, 0u8, 36u8, 0u8, 39u8, 0u8, 45u8, 0u8,
+                            48u8, 0u8, 54u8, 0u8, 57u8, 0u8, 63u8, 0u8, 66u8, 0u8, 72u8, 0u8, 75u8,
+                            0u8, 81u8, 0u8, 84u8, 0u8, 90u8, 0u8, 93u8, 0u8, 99u8, 0u8, 102u8, 0u8,
+                            77u8, 48u8, 49u8, 77u8, 48u8, 49u8, 98u8, 105u8, 115u8, 77u8, 48u8,
+                            50u8, 77u8, 48u8, 50u8, 98u8, 105u8, 115u8, 77u8, 48u8, 51u8, 77u8,
+                            48u8, 51u8, 98u8, 105u8, 115u8, 77u8, 48u8, 52u8, 77u8, 48u8, 52u8,
+                            98u8, 105u8, 115u8, 77u8, 48u8, 53u8, 77u8, 48u8, 53u8, 98u8, 105u8,
+                            115u8, 77u8, 48u8, 54u8, 77u8, 48u8, 54u8, 98u8, 105u8, 115u8, 77u8,
+                            48u8, 55u8, 77u8, 48u8, 55u8, 98u8, 105u8, 115u8, 77u8, 48u8, 56u8,
+                            77u8, 48u8, 56u8, 98u8, 105u8, 115u8, 77u8, 48u8, 57u8, 77u8, 48u8,
+                            57u8, 98u8, 105u8, 115u8, 77u8, 49u8, 48u8, 77u8, 49u8, 48u8, 98u8,
+                            105u8, 115u8, 77u8, 49u8, 49u8, 77u8, 49u8, 49u8, 98u8, 105u8, 115u8,
+                            77u8, 49u8, 50u8, 77u8, 49u8, 50u8, 98u8, 105u8, 115u8,
+                        ])
+                    },
+                )
+            }),
+        },
+        stand_alone: None,
+    },
+    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("𑄢\u{11127}𑄝\u{11128}"),
+                alloc::borrow::Cow::Borrowed("𑄥\u{11127}𑄟\u{11134}"),
+                alloc::borrow::Cow::Borrowed("𑄟\u{11127}\u{11101}𑄉\u{11127}𑄣\u{11134}"),
+                alloc::borrow::Cow::Borrowed("𑄝\u{1112a}𑄖\u{11134}"),
+                alloc::borrow::Cow::Borrowed("𑄝\u{11133}𑄢\u{11128}𑄥\u{1112a}𑄛\u{11134}"),
+                alloc::borrow::Cow::Borrowed("𑄥\u{1112a}𑄇\u{11134}𑄇\u{1112e}𑄢\u{11134}"),
+                alloc::borrow::Cow::Borrowed("𑄥\u{11127}𑄚\u{11128}"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("𑄢\u{11127}"),
+                alloc::borrow::Cow::Borrowed("𑄥\u{11127}"),
+                alloc::borrow::Cow::Borrowed("𑄟\u{11127}"),
+                alloc::borrow::Cow::Borrowed("𑄝\u{1112a}"),
+                alloc::borrow::Cow::Borrowed("𑄝\u{11133}𑄢\u{11128}"),
+                alloc::borrow::Cow::Borrowed("𑄥\u{1112a}"),
+                alloc::borrow::Cow::Borrowed("𑄥\u{11127}"),
+            ]),
+            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("𑄢\u{11127}𑄝\u{11128}"),
+                alloc::borrow::Cow::Borrowed("𑄥\u{11127}𑄟\u{11134}"),
+                alloc::borrow::Cow::Borrowed("𑄟\u{11127}\u{11101}𑄉\u{11127}𑄣\u{11134}"),
+                alloc::borrow::Cow::Borrowed("𑄝\u{1112a}𑄖\u{11134}"),
+                alloc::borrow::Cow::Borrowed("𑄝\u{11133}𑄢\u{11128}𑄥\u{1112a}𑄛\u{11134}"),
+                alloc::borrow::Cow::Borrowed("𑄥\u{1112a}𑄇\u{11134}𑄇\u{1112e}𑄢\u{11134}"),
+                alloc::borrow::Cow::Borrowed("𑄥\u{11127}𑄚\u{11128}"),
+            ])),
+            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("𑄢\u{11127}𑄝\u{11128}𑄝𑄢\u{11134}"),
+                alloc::borrow::Cow::Borrowed("𑄥\u{11127}𑄟\u{11134}𑄝𑄢\u{11134}"),
+                alloc::borrow::Cow::Borrowed("𑄟\u{11127}\u{11101}𑄉\u{11127}𑄣\u{11134}𑄝𑄢\u{11134}"),
+                alloc::borrow::Cow::Borrowed("𑄝\u{1112a}𑄖\u{11134}𑄝𑄢\u{11134}"),
+                alloc::borrow::Cow::Borrowed("𑄝\u{11133}𑄢\u{11128}𑄥\u{1112a}𑄛\u{11134}𑄝𑄢\u{11134}"),
+                alloc::borrow::Cow::Borrowed("𑄥\u{1112a}𑄇\u{11134}𑄇\u{1112e}𑄢\u{11134}𑄝𑄢\u{11134}"),
+                alloc::borrow::Cow::Borrowed("𑄥\u{11127}𑄚\u{11128}𑄝𑄢\u{11134}"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    eras: ::icu_datetime::provider::calendar::Eras {
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+        abbr: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+        narrow: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+    },
+    cyclic_years: None,
+};
+static EN_EN_001_EN_ZA: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
+    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 9u8, 0u8, 12u8, 0u8, 18u8,
+                            0u8, 21u8, 0u8, 27u8, 0u8, 30u8, 0u8, 36u8, 0u8, 39u8, 0u8, 45u8, 0u8,
+                            48u8, 0u8, 54u8, 0u8, 57u8, 0u8, 63u8, 0u8, 66u8, 0u8, 72u8, 0u8, 75u8,
+                            0u8, 81u8, 0u8, 85u8, 0u8, 92u8, 0u8, 96u8, 0u8, 103u8, 0u8, 107u8,
+                            0u8, 77u8, 111u8, 49u8, 77u8, 111u8, 49u8, 98u8, 105u8, 115u8, 77u8,
+                            111u8, 50u8, 77u8, 111u8, 50u8, 98u8, 105u8, 115u8, 77u8, 111u8, 51u8,
+                            77u8, 111u8, 51u8, 98u8, 105u8, 115u8, 77u8, 111u8, 52u8, 77u8, 111u8,
+                            52u8, 98u8, 105u8, 115u8, 77u8, 111u8, 53u8, 77u8, 111u8, 53u8, 98u8,
+                            105u8, 115u8, 77u8, 111u8, 54u8, 77u8, 111u8, 54u8, 98u8, 105u8, 115u8,
+                            77u8, 111u8, 55u8, 77u8, 111u8, 55u8, 98u8, 105u8, 115u8, 77u8, 111u8,
+                            56u8, 77u8, 111u8, 56u8, 98u8, 105u8, 115u8, 77u8, 111u8, 57u8, 77u8,
+                            111u8, 57u8, 98u8, 105u8, 115u8, 77u8, 111u8, 49u8, 48u8, 77u8, 111u8,
+                            49u8, 48u8, 98u8, 105u8, 115u8, 77u8, 111u8, 49u8, 49u8, 77u8, 111u8,
+                            49u8, 49u8, 98u8, 105u8, 115u8, 77u8, 111u8, 49u8, 50u8, 77u8, 111u8,
+                            49u8, 50u8, 98u8, 105u8, 115u8,
+                        ])
+                    },
+                )
+            }),
+            narrow: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 3u8, 0u8, 4u8, 0u8, 6u8, 0u8,
+                            7u8, 0u8, 9u8, 0u8, 10u8, 0u8, 12u8, 0u8, 13u8, 0u8, 15u8, 0u8, 16u8,
+                            0u8, 18u8, 0u8, 19u8, 0u8, 21u8, 0u8, 22u8, 0u8, 24u8, 0u8, 25u8, 0u8,
+                            27u8, 0u8, 29u8, 0u8, 32u8, 0u8, 34u8, 0u8, 37u8, 0u8, 39u8, 0u8, 49u8,
+                            49u8, 98u8, 50u8, 50u8, 98u8, 51u8, 51u8, 98u8, 52u8, 52u8, 98u8, 53u8,
+                            53u8, 98u8, 54u8, 54u8, 98u8, 55u8, 55u8, 98u8, 56u8, 56u8, 98u8, 57u8,
+                            57u8, 98u8, 49u8, 48u8, 49u8, 48u8, 98u8, 49u8, 49u8, 49u8, 49u8, 98u8,
+                            49u8, 50u8, 49u8, 50u8, 98u8,
+                        ])
+                    },
+                )
+            }),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 25u8, 0u8, 37u8, 0u8, 52u8,
+                            0u8, 63u8, 0u8, 77u8, 0u8, 89u8, 0u8, 104u8, 0u8, 115u8, 0u8, 129u8,
+                            0u8, 140u8, 0u8, 154u8, 0u8, 167u8, 0u8, 183u8, 0u8, 195u8, 0u8, 210u8,
+                            0u8, 221u8, 0u8, 235u8, 0u8, 246u8, 0u8, 4u8, 1u8, 18u8, 1u8, 35u8,
+                            1u8, 48u8, 1u8, 70u8, 105u8, 114u8, 115u8, 116u8, 32u8, 77u8, 111u8,
+                            110u8, 116u8, 104u8, 70u8, 105u8, 114u8, 115u8, 116u8, 32u8, 77u8,
+                            111u8, 110u8, 116u8, 104u8, 98u8, 105u8, 115u8, 83u8, 101u8, 99u8,
+                            111u8, 110u8, 100u8, 32u8, 77u8, 111u8, 110u8, 116u8, 104u8, 83u8,
+                            101u8, 99u8, 111u8, 110u8, 100u8, 32u8, 77u8, 111u8, 110u8, 116u8,
+                            104u8, 98u8, 105u8, 115u8, 84u8, 104u8, 105u8, 114u8, 100u8, 32u8,
+                            77u8, 111u8, 110u8, 116u8, 104u8, 84u8, 104u8, 105u8, 114u8, 100u8,
+                            32u8, 77u8, 111u8, 110u8, 116u8, 104u8, 98u8, 105u8, 115u8, 70u8,
+                            111u8, 117u8, 114u8, 116u8, 104u8, 32u8, 77u8, 111u8, 110u8, 116u8,
+                            104u8, 70u8, 111u8, 117u8, 114u8, 116u8, 104u8, 32u8, 77u8, 111u8,
+                            110u8, 116u8, 104u8, 98u8, 105u8, 115u8, 70u8, 105u8, 102u8, 116u8,
+                            104u8, 32u8, 77u8, 111u8, 110u8, 116u8, 104u8, 70u8, 105u8, 102u8,
+                            116u8, 104u8, 32u8, 77u8, 111u8, 110u8, 116u8, 104u8, 98u8, 105u8,
+                            115u8, 83u8, 105u8, 120u8, 116u8, 104u8, 32u8, 77u8, 111u8, 110u8,
+                            116u8, 104u8, 83u8, 105u8, 120u8, 116u8, 104u8, 32u8, 77u8, 111u8,
+                            110u8, 116u8, 104u8, 98u8, 105u8, 115u8, 83u8, 101u8, 118u8, 101u8,
+                            110u8, 116u8, 104u8, 32u8, 77u8, 111u8, 110u8, 116u8, 104u8, 83u8,
+                            101u8, 118u8, 101u8, 110u8, 116u8, 104u8, 32u8, 77u8, 111u8, 110u8,
+                            116u8, 104u8, 98u8, 105u8, 115u8, 69u8, 105u8, 103u8, 104u8, 116u8,
+                            104u8, 32u8, 77u8, 111u8, 110u8, 116u8, 104u8, 69u8, 105u8, 103u8,
+                            104u8, 116u8, 104u8, 32u8, 77u8, 111u8, 110u8, 116u8, 104u8, 98u8,
+                            105u8, 115u8, 78u8, 105u8, 110u8, 116u8, 104u8, 32u8, 77u8, 111u8,
+                            110u8, 116u8, 104u8, 78u8, 105u8, 110u8, 116u8, 104u8, 32u8, 77u8,
+                            111u8, 110u8, 116u8, 104u8, 98u8, 105u8, 115u8, 84u8, 101u8, 110u8,
+                            116u8, 104u8, 32u8, 77u8, 111u8, 110u8, 116u8, 104u8, 84u8, 101u8,
+                            110u8, 116u8, 104u8, 32u8, 77u8, 111u8, 110u8, 116u8, 104u8, 98u8,
+                            105u8, 115u8, 69u8, 108u8, 101u8, 118u8, 101u8, 110u8, 116u8, 104u8,
+                            32u8, 77u8, 111u8, 110u8, 116u8, 104u8, 69u8, 108u8, 101u8, 118u8,
+                            101u8, 110u8, 116u8, 104u8, 32u8, 77u8, 111u8, 110u8, 116u8, 104u8,
+                            98u8, 105u8, 115u8, 84u8, 119u8, 101u8, 108u8, 102u8, 116u8, 104u8,
+                            32u8, 77u8, 111u8, 110u8, 116u8, 104u8, 84u8, 119u8, 101u8, 108u8,
+                            102u8, 116u8, 104u8, 32u8, 77u8, 111u8, 110u8, 116u8, 104u8, 98u8,
+                            105u8, 115u8,
+                        ])
+                    },
+                )
+            }),
+        },
+        stand_alone: None,
+    },
+    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Sun"),
+                alloc::borrow::Cow::Borrowed("Mon"),
+                alloc::borrow::Cow::Borrowed("Tue"),
+                alloc::borrow::Cow::Borrowed("Wed"),
+                alloc::borrow::Cow::Borrowed("Thu"),
+                alloc::borrow::Cow::Borrowed("Fri"),
+                alloc::borrow::Cow::Borrowed("Sat"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("S"),
+                alloc::borrow::Cow::Borrowed("M"),
+                alloc::borrow::Cow::Borrowed("T"),
+                alloc::borrow::Cow::Borrowed("W"),
+                alloc::borrow::Cow::Borrowed("T"),
+                alloc::borrow::Cow::Borrowed("F"),
+                alloc::borrow::Cow::Borrowed("S"),
+            ]),
+            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Su"),
+                alloc::borrow::Cow::Borrowed("Mo"),
+                alloc::borrow::Cow::Borrowed("Tu"),
+                alloc::borrow::Cow::Borrowed("We"),
+                alloc::borrow::Cow::Borrowed("Th"),
+                alloc::borrow::Cow::Borrowed("Fr"),
+                alloc::borrow::Cow::Borrowed("Sa"),
+            ])),
+            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Sunday"),
+                alloc::borrow::Cow::Borrowed("Monday"),
+                alloc::borrow::Cow::Borrowed("Tuesday"),
+                alloc::borrow::Cow::Borrowed("Wednesday"),
+                alloc::borrow::Cow::Borrowed("Thursday"),
+                alloc::borrow::Cow::Borrowed("Friday"),
+                alloc::borrow::Cow::Borrowed("Saturday"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    eras: ::icu_datetime::provider::calendar::Eras {
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+        abbr: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+        narrow: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+    },
+    cyclic_years: None,
+};
+static ES: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
+    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 9u8, 0u8, 12u8, 0u8, 18u8,
+                            0u8, 21u8, 0u8, 27u8, 0u8, 30u8, 0u8, 36u8, 0u8, 39u8, 0u8, 45u8, 0u8,
+                            48u8, 0u8, 54u8, 0u8, 57u8, 0u8, 63u8, 0u8, 66u8, 0u8, 72u8, 0u8, 75u8,
+                            0u8, 81u8, 0u8, 84u8, 0u8, 90u8, 0u8, 93u8, 0u8, 99u8, 0u8, 102u8, 0u8,
+                            77u8, 48u8, 49u8, 77u8, 48u8, 49u8, 98u8, 105u8, 115u8, 77u8, 48u8,
+                            50u8, 77u8, 48u8, 50u8, 98u8, 105u8, 115u8, 77u8, 48u8, 51u8, 77u8,
+                            48u8, 51u8, 98u8, 105u8, 115u8, 77u8, 48u8, 52u8, 77u8, 48u8, 52u8,
+                            98u8, 105u8, 115u8, 77u8, 48u8, 53u8, 77u8, 48u8, 53u8, 98u8, 105u8,
+                            115u8, 77u8, 48u8, 54u8, 77u8, 48u8, 54u8, 98u8, 105u8, 115u8, 77u8,
+                            48u8, 55u8, 77u8, 48u8, 55u8, 98u8, 105u8, 115u8, 77u8, 48u8, 56u8,
+                            77u8, 48u8, 56u8, 98u8, 105u8, 115u8, 77u8, 48u8, 57u8, 77u8, 48u8,
+                            57u8, 98u8, 105u8, 115u8, 77u8, 49u8, 48u8, 77u8, 49u8, 48u8, 98u8,
+                            105u8, 115u8, 77u8, 49u8, 49u8, 77u8, 49u8, 49u8, 98u8, 105u8, 115u8,
+                            77u8, 49u8, 50u8, 77u8, 49u8, 50u8, 98u8, 105u8, 115u8,
+                        ])
+                    },
+                )
+            }),
+            narrow: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 3u8, 0u8, 4u8, 0u8, 6u8, 0u8,
+                            7u8, 0u8, 9u8, 0u8, 10u8, 0u8, 12u8, 0u8, 13u8, 0u8, 15u8, 0u8, 16u8,
+                            0u8, 18u8, 0u8, 19u8, 0u8, 21u8, 0u8, 22u8, 0u8, 24u8, 0u8, 25u8, 0u8,
+                            27u8, 0u8, 29u8, 0u8, 32u8, 0u8, 34u8, 0u8, 37u8, 0u8, 39u8, 0u8, 49u8,
+                            49u8, 98u8, 50u8, 50u8, 98u8, 51u8, 51u8, 98u8, 52u8, 52u8, 98u8, 53u8,
+                            53u8, 98u8, 54u8, 54u8, 98u8, 55u8, 55u8, 98u8, 56u8, 56u8, 98u8, 57u8,
+                            57u8, 98u8, 49u8, 48u8, 49u8, 48u8, 98u8, 49u8, 49u8, 49u8, 49u8, 98u8,
+                            49u8, 50u8, 49u8, 50u8, 98u8,
+                        ])
+                    },
+                )
+            }),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 9u8, 0u8, 12u8, 0u8, 18u8,
+                            0u8, 21u8, 0u8, 27u8, 0u8, 30u8, 0u8, 36u8, 0u8, 39u8, 0u8, 45u8, 0u8,
+                            48u8, 0u8, 54u8, 0u8, 57u8, 0u8, 63u8, 0u8, 66u8, 0u8, 72u8, 0u8, 75u8,
+                            0u8, 81u8, 0u8, 84u8, 0u8, 90u8, 0u8, 93u8, 0u8, 99u8, 0u8, 102u8, 0u8,
+                            77u8, 48u8, 49u8, 77u8, 48u8, 49u8, 98u8, 105u8, 115u8, 77u8, 48u8,
+                            50u8, 77u8, 48u8, 50u8, 98u8, 105u8, 115u8, 77u8, 48u8, 51u8, 77u8,
+                            48u8, 51u8, 98u8, 105u8, 115u8, 77u8, 48u8, 52u8, 77u8, 48u8, 52u8,
+                            98u8, 105u8, 115u8, 77u8, 48u8, 53u8, 77u8, 48u8, 53u8, 98u8, 105u8,
+                            115u8, 77u8, 48u8, 54u8, 77u8, 48u8, 54u8, 98u8, 105u8, 115u8, 77u8,
+                            48u8, 55u8, 77u8, 48u8, 55u8, 98u8, 105u8, 115u8, 77u8, 48u8, 56u8,
+                            77u8, 48u8, 56u8, 98u8, 105u8, 115u8, 77u8, 48u8, 57u8, 77u8, 48u8,
+                            57u8, 98u8, 105u8, 115u8, 77u8, 49u8, 48u8, 77u8, 49u8, 48u8, 98u8,
+                            105u8, 115u8, 77u8, 49u8, 49u8, 77u8, 49u8, 49u8, 98u8, 105u8, 115u8,
+                            77u8, 49u8, 50u8, 77u8, 49u8, 50u8, 98u8, 105u8, 115u8,
+                        ])
+                    },
+                )
+            }),
+        },
+        stand_alone: None,
+    },
+    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("dom"),
+                alloc::borrow::Cow::Borrowed("lun"),
+                alloc::borrow::Cow::Borrowed("mar"),
+                alloc::borrow::Cow::Borrowed("mié"),
+                alloc::borrow::Cow::Borrowed("jue"),
+                alloc::borrow::Cow::Borrowed("vie"),
+                alloc::borrow::Cow::Borrowed("sáb"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("D"),
+                alloc::borrow::Cow::Borrowed("L"),
+                alloc::borrow::Cow::Borrowed("M"),
+                alloc::borrow::Cow::Borrowed("X"),
+                alloc::borrow::Cow::Borrowed("J"),
+                alloc::borrow::Cow::Borrowed("V"),
+                alloc::borrow::Cow::Borrowed("S"),
+            ]),
+            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("DO"),
+                alloc::borrow::Cow::Borrowed("LU"),
+                alloc::borrow::Cow::Borrowed("MA"),
+                alloc::borrow::Cow::Borrowed("MI"),
+                alloc::borrow::Cow::Borrowed("JU"),
+                alloc::borrow::Cow::Borrowed("VI"),
+                alloc::borrow::Cow::Borrowed("SA"),
+            ])),
+            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("domingo"),
+                alloc::borrow::Cow::Borrowed("lunes"),
+                alloc::borrow::Cow::Borrowed("martes"),
+                alloc::borrow::Cow::Borrowed("miércoles"),
+                alloc::borrow::Cow::Borrowed("jueves"),
+                alloc::borrow::Cow::Borrowed("viernes"),
+                alloc::borrow::Cow::Borrowed("sábado"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    eras: ::icu_datetime::provider::calendar::Eras {
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+        abbr: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+        narrow: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+    },
+    cyclic_years: None,
+};
+static ES_AR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
+    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 9u8, 0u8, 12u8, 0u8, 18u8,
+                            0u8, 21u8, 0u8, 27u8, 0u8, 30u8, 0u8, 36u8, 0u8, 39u8, 0u8, 45u8, 0u8,
+                            48u8, 0u8, 54u8, 0u8, 57u8, 0u8, 63u8, 0u8, 66u8, 0u8, 72u8, 0u8, 75u8,
+                            0u8, 81u8, 0u8, 84u8, 0u8, 90u8, 0u8, 93u8, 0u8, 99u8, 0u8, 102u8, 0u8,
+                            77u8, 48u8, 49u8, 77u8, 48u8, 49u8, 98u8, 105u8, 115u8, 77u8, 48u8,
+                            50u8, 77u8, 48u8, 50u8, 98u8, 105u8, 115u8, 77u8, 48u8, 51u8, 77u8,
+                            48u8, 51u8, 98u8, 105u8, 115u8, 77u8, 48u8, 52u8, 77u8, 48u8, 52u8,
+                            98u8, 105u8, 115u8, 77u8, 48u8, 53u8, 77u8, 48u8, 53u8, 98u8, 105u8,
+                            115u8, 77u8, 48u8, 54u8, 77u8, 48u8, 54u8, 98u8, 105u8, 115u8, 77u8,
+                            48u8, 55u8, 77u8, 48u8, 55u8, 98u8, 105u8, 115u8, 77u8, 48u8, 56u8,
+                            77u8, 48u8, 56u8, 98u8, 105u8, 115u8, 77u8, 48u8, 57u8, 77u8, 48u8,
+                            57u8, 98u8, 105u8, 115u8, 77u8, 49u8, 48u8, 77u8, 49u8, 48u8, 98u8,
+                            105u8, 115u8, 77u8, 49u8, 49u8, 77u8, 49u8, 49u8, 98u8, 105u8, 115u8,
+                            77u8, 49u8, 50u8, 77u8, 49u8, 50u8, 98u8, 105u8, 115u8,
+                        ])
+                    },
+                )
+            }),
+            narrow: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 3u8, 0u8, 4u8, 0u8, 6u8, 0u8,
+                            7u8, 0u8, 9u8, 0u8, 10u8, 0u8, 12u8, 0u8, 13u8, 0u8, 15u8, 0u8, 16u8,
+                            0u8, 18u8, 0u8, 19u8, 0u8, 21u8, 0u8, 22u8, 0u8, 24u8, 0u8, 25u8, 0u8,
+                            27u8, 0u8, 29u8, 0u8, 32u8, 0u8, 34u8, 0u8, 37u8, 0u8, 39u8, 0u8, 49u8,
+                            49u8, 98u8, 50u8, 50u8, 98u8, 51u8, 51u8, 98u8, 52u8, 52u8, 98u8, 53u8,
+                            53u8, 98u8, 54u8, 54u8, 98u8, 55u8, 55u8, 98u8, 56u8, 56u8, 98u8, 57u8,
+                            57u8, 98u8, 49u8, 48u8, 49u8, 48u8, 98u8, 49u8, 49u8, 49u8, 49u8, 98u8,
+                            49u8, 50u8, 49u8, 50u8, 98u8,
+                        ])
+                    },
+                )
+            }),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 9u8, 0u8, 12u8, 0u8, 18u8,
+                            0u8, 21u8, 0u8, 27u8, 0u8, 30u8, 0u8, 36u8, 0u8, 39u8, 0u8, 45u8, 0u8,
+                            48u8, 0u8, 54u8, 0u8, 57u8, 0u8, 63u8, 0u8, 66u8, 0u8, 72u8, 0u8, 75u8,
+                            0u8, 81u8, 0u8, 84u8, 0u8, 90u8, 0u8, 93u8, 0u8, 99u8, 0u8, 102u8, 0u8,
+                            77u8, 48u8, 49u8, 77u8, 48u8, 49u8, 98u8, 105u8, 115u8, 77u8, 48u8,
+                            50u8, 77u8, 48u8, 50u8, 98u8, 105u8, 115u8, 77u8, 48u8, 51u8, 77u8,
+                            48u8, 51u8, 98u8, 105u8, 115u8, 77u8, 48u8, 52u8, 77u8, 48u8, 52u8,
+                            98u8, 105u8, 115u8, 77u8, 48u8, 53u8, 77u8, 48u8, 53u8, 98u8, 105u8,
+                            115u8, 77u8, 48u8, 54u8, 77u8, 48u8, 54u8, 98u8, 105u8, 115u8, 77u8,
+                            48u8, 55u8, 77u8, 48u8, 55u8, 98u8, 105u8, 115u8, 77u8, 48u8, 56u8,
+                            77u8, 48u8, 56u8, 98u8, 105u8, 115u8, 77u8, 48u8, 57u8, 77u8, 48u8,
+                            57u8, 98u8, 105u8, 115u8, 77u8, 49u8, 48u8, 77u8, 49u8, 48u8, 98u8,
+                            105u8, 115u8, 77u8, 49u8, 49u8, 77u8, 49u8, 49u8, 98u8, 105u8, 115u8,
+                            77u8, 49u8, 50u8, 77u8, 49u8, 50u8, 98u8, 105u8, 115u8,
+                        ])
+                    },
+                )
+            }),
+        },
+        stand_alone: None,
+    },
+    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("dom"),
+                alloc::borrow::Cow::Borrowed("lun"),
+                alloc::borrow::Cow::Borrowed("mar"),
+                alloc::borrow::Cow::Borrowed("mié"),
+                alloc::borrow::Cow::Borrowed("jue"),
+                alloc::borrow::Cow::Borrowed("vie"),
+                alloc::borrow::Cow::Borrowed("sáb"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("D"),
+                alloc::borrow::Cow::Borrowed("L"),
+                alloc::borrow::Cow::Borrowed("M"),
+                alloc::borrow::Cow::Borrowed("M"),
+                alloc::borrow::Cow::Borrowed("J"),
+                alloc::borrow::Cow::Borrowed("V"),
+                alloc::borrow::Cow::Borrowed("S"),
+            ]),
+            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("DO"),
+                alloc::borrow::Cow::Borrowed("LU"),
+                alloc::borrow::Cow::Borrowed("MA"),
+                alloc::borrow::Cow::Borrowed("MI"),
+                alloc::borrow::Cow::Borrowed("JU"),
+                alloc::borrow::Cow::Borrowed("VI"),
+                alloc::borrow::Cow::Borrowed("SA"),
+            ])),
+            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("domingo"),
+                alloc::borrow::Cow::Borrowed("lunes"),
+                alloc::borrow::Cow::Borrowed("martes"),
+                alloc::borrow::Cow::Borrowed("miércoles"),
+                alloc::borrow::Cow::Borrowed("jueves"),
+                alloc::borrow::Cow::Borrowed("viernes"),
+                alloc::borrow::Cow::Borrowed("sábado"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    eras: ::icu_datetime::provider::calendar::Eras {
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+        abbr: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+        narrow: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+    },
+    cyclic_years: None,
+};
+static FIL: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
+    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 9u8, 0u8, 12u8, 0u8, 18u8,
+                            0u8, 21u8, 0u8, 27u8, 0u8, 30u8, 0u8, 36u8, 0u8, 39u8, 0u8, 45u8, 0u8,
+                            48u8, 0u8, 54u8, 0u8, 57u8, 0u8, 63u8, 0u8, 66u8, 0u8, 72u8, 0u8, 75u8,
+                            0u8, 81u8, 0u8, 84u8, 0u8, 90u8, 0u8, 93u8, 0u8, 99u8, 0u8, 102u8, 0u8,
+                            77u8, 48u8, 49u8, 77u8, 48u8, 49u8, 98u8, 105u8, 115u8, 77u8, 48u8,
+                            50u8, 77u8, 48u8, 50u8, 98u8, 105u8, 115u8, 77u8, 48u8, 51u8, 77u8,
+                            48u8, 51u8, 98u8, 105u8, 115u8, 77u8, 48u8, 52u8, 77u8, 48u8, 52u8,
+                            98u8, 105u8, 115u8, 77u8, 48u8, 53u8, 77u8, 48u8, 53u8, 98u8, 105u8,
+                            115u8, 77u8, 48u8, 54u8, 77u8, 48u8, 54u8, 98u8, 105u8, 115u8, 77u8,
+                            48u8, 55u8, 77u8, 48u8, 55u8, 98u8, 105u8, 115u8, 77u8, 48u8, 56u8,
+                            77u8, 48u8, 56u8, 98u8, 105u8, 115u8, 77u8, 48u8, 57u8, 77u8, 48u8,
+                            57u8, 98u8, 105u8, 115u8, 77u8, 49u8, 48u8, 77u8, 49u8, 48u8, 98u8,
+                            105u8, 115u8, 77u8, 49u8, 49u8, 77u8, 49u8, 49u8, 98u8, 105u8, 115u8,
+                            77u8, 49u8, 50u8, 77u8, 49u8, 50u8, 98u8, 105u8, 115u8,
+                        ])
+                    },
+                )
+            }),
+            narrow: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 3u8, 0u8, 4u8, 0u8, 6u8, 0u8,
+                            7u8, 0u8, 9u8, 0u8, 10u8, 0u8, 12u8, 0u8, 13u8, 0u8, 15u8, 0u8, 16u8,
+                            0u8, 18u8, 0u8, 19u8, 0u8, 21u8, 0u8, 22u8, 0u8, 24u8, 0u8, 25u8, 0u8,
+                            27u8, 0u8, 29u8, 0u8, 32u8, 0u8, 34u8, 0u8, 37u8, 0u8, 39u8, 0u8, 49u8,
+                            49u8, 98u8, 50u8, 50u8, 98u8, 51u8, 51u8, 98u8, 52u8, 52u8, 98u8, 53u8,
+                            53u8, 98u8, 54u8, 54u8, 98u8, 55u8, 55u8, 98u8, 56u8, 56u8, 98u8, 57u8,
+                            57u8, 98u8, 49u8, 48u8, 49u8, 48u8, 98u8, 49u8, 49u8, 49u8, 49u8, 98u8,
+                            49u8, 50u8, 49u8, 50u8, 98u8,
+                        ])
+                    },
+                )
+            }),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 9u8, 0u8, 12u8, 0u8, 18u8,
+                            0u8, 21u8, 0u8, 27u8, 0u8, 30u8, 0u8, 36u8, 0u8, 39u8, 0u8, 45u8, 0u8,
+                            48u8, 0u8, 54u8, 0u8, 57u8, 0u8, 63u8, 0u8, 66u8, 0u8, 72u8, 0u8, 75u8,
+                            0u8, 81u8, 0u8, 84u8, 0u8, 90u8, 0u8, 93u8, 0u8, 99u8, 0u8, 102u8, 0u8,
+                            77u8, 48u8, 49u8, 77u8, 48u8, 49u8, 98u8, 105u8, 115u8, 77u8, 48u8,
+                            50u8, 77u8, 48u8, 50u8, 98u8, 105u8, 115u8, 77u8, 48u8, 51u8, 77u8,
+                            48u8, 51u8, 98u8, 105u8, 115u8, 77u8, 48u8, 52u8, 77u8, 48u8, 52u8,
+                            98u8, 105u8, 115u8, 77u8, 48u8, 53u8, 77u8, 48u8, 53u8, 98u8, 105u8,
+                            115u8, 77u8, 48u8, 54u8, 77u8, 48u8, 54u8, 98u8, 105u8, 115u8, 77u8,
+                            48u8, 55u8, 77u8, 48u8, 55u8, 98u8, 105u8, 115u8, 77u8, 48u8, 56u8,
+                            77u8, 48u8, 56u8, 98u8, 105u8, 115u8, 77u8, 48u8, 57u8, 77u8, 48u8,
+                            57u8, 98u8, 105u8, 115u8, 77u8, 49u8, 48u8, 77u8, 49u8, 48u8, 98u8,
+                            105u8, 115u8, 77u8, 49u8, 49u8, 77u8, 49u8, 49u8, 98u8, 105u8, 115u8,
+                            77u8, 49u8, 50u8, 77u8, 49u8, 50u8, 98u8, 105u8, 115u8,
+                        ])
+                    },
+                )
+            }),
+        },
+        stand_alone: None,
+    },
+    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Lin"),
+                alloc::borrow::Cow::Borrowed("Lun"),
+                alloc::borrow::Cow::Borrowed("Mar"),
+                alloc::borrow::Cow::Borrowed("Miy"),
+                alloc::borrow::Cow::Borrowed("Huw"),
+                alloc::borrow::Cow::Borrowed("Biy"),
+                alloc::borrow::Cow::Borrowed("Sab"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Lin"),
+                alloc::borrow::Cow::Borrowed("Lun"),
+                alloc::borrow::Cow::Borrowed("Mar"),
+                alloc::borrow::Cow::Borrowed("Miy"),
+                alloc::borrow::Cow::Borrowed("Huw"),
+                alloc::borrow::Cow::Borrowed("Biy"),
+                alloc::borrow::Cow::Borrowed("Sab"),
+            ]),
+            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Li"),
+                alloc::borrow::Cow::Borrowed("Lu"),
+                alloc::borrow::Cow::Borrowed("Ma"),
+                alloc::borrow::Cow::Borrowed("Mi"),
+                alloc::borrow::Cow::Borrowed("Hu"),
+                alloc::borrow::Cow::Borrowed("Bi"),
+                alloc::borrow::Cow::Borrowed("Sa"),
+            ])),
+            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Linggo"),
+                alloc::borrow::Cow::Borrowed("Lunes"),
+                alloc::borrow::Cow::Borrowed("Martes"),
+                alloc::borrow::Cow::Borrowed("Miyerkules"),
+                alloc::borrow::Cow::Borrowed("Huwebes"),
+                alloc::borrow::Cow::Borrowed("Biyernes"),
+                alloc::borrow::Cow::Borrowed("Sabado"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    eras: ::icu_datetime::provider::calendar::Eras {
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+        abbr: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+        narrow: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+    },
+    cyclic_years: None,
+};
+static FR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
+    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 13u8, 0u8, 18u8, 0u8, 26u8,
+                            0u8, 31u8, 0u8, 39u8, 0u8, 44u8, 0u8, 52u8, 0u8, 57u8, 0u8, 65u8, 0u8,
+                            70u8, 0u8, 78u8, 0u8, 83u8, 0u8, 91u8, 0u8, 96u8, 0u8, 104u8, 0u8,
+                            109u8, 0u8, 117u8, 0u8, 123u8, 0u8, 132u8, 0u8, 138u8, 0u8, 147u8, 0u8,
+                            153u8, 0u8, 49u8, 121u8, 117u8, 195u8, 168u8, 49u8, 121u8, 117u8,
+                            195u8, 168u8, 98u8, 105u8, 115u8, 50u8, 121u8, 117u8, 195u8, 168u8,
+                            50u8, 121u8, 117u8, 195u8, 168u8, 98u8, 105u8, 115u8, 51u8, 121u8,
+                            117u8, 195u8, 168u8, 51u8, 121u8, 117u8, 195u8, 168u8, 98u8, 105u8,
+                            115u8, 52u8, 121u8, 117u8, 195u8, 168u8, 52u8, 121u8, 117u8, 195u8,
+                            168u8, 98u8, 105u8, 115u8, 53u8, 121u8, 117u8, 195u8, 168u8, 53u8,
+                            121u8, 117u8, 195u8, 168u8, 98u8, 105u8, 115u8, 54u8, 121u8, 117u8,
+                            195u8, 168u8, 54u8, 121u8, 117u8, 195u8, 168u8, 98u8, 105u8, 115u8,
+                            55u8, 121u8, 117u8, 195u8, 168u8, 55u8, 121u8, 117u8, 195u8, 168u8,
+                            98u8, 105u8, 115u8, 56u8, 121u8, 117u8, 195u8, 168u8, 56u8, 121u8,
+                            117u8, 195u8, 168u8, 98u8, 105u8, 115u8, 57u8, 121u8, 117u8, 195u8,
+                            168u8, 57u8, 121u8, 117u8, 195u8, 168u8, 98u8, 105u8, 115u8, 49u8,
+                            48u8, 121u8, 117u8, 195u8, 168u8, 49u8, 48u8, 121u8, 117u8, 195u8,
+                            168u8, 98u8, 105u8, 115u8, 49u8, 49u8, 121u8, 117u8, 195u8, 168u8,
+                            49u8, 49u8, 121u8, 117u8, 195u8, 168u8, 98u8, 105u8, 115u8, 49u8, 50u8,
+                            121u8, 117u8, 195u8, 168u8, 49u8, 50u8, 121u8, 117u8, 195u8, 168u8,
+                            98u8, 105u8, 115u8,
+                        ])
+                    },
+                )
+            }),
+            narrow: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 3u8, 0u8, 4u8, 0u8, 6u8, 0u8,
+                            7u8, 0u8, 9u8, 0u8, 10u8, 0u8, 12u8, 0u8, 13u8, 0u8, 15u8, 0u8, 16u8,
+                            0u8, 18u8, 0u8, 19u8, 0u8, 21u8, 0u8, 22u8, 0u8, 24u8, 0u8, 25u8, 0u8,
+                            27u8, 0u8, 29u8, 0u8, 32u8, 0u8, 34u8, 0u8, 37u8, 0u8, 39u8, 0u8, 49u8,
+                            49u8, 98u8, 50u8, 50u8, 98u8, 51u8, 51u8, 98u8, 52u8, 52u8, 98u8, 53u8,
+                            53u8, 98u8, 54u8, 54u8, 98u8, 55u8, 55u8, 98u8, 56u8, 56u8, 98u8, 57u8,
+                            57u8, 98u8, 49u8, 48u8, 49u8, 48u8, 98u8, 49u8, 49u8, 49u8, 49u8, 98u8,
+                            49u8, 50u8, 49u8, 50u8, 98u8,
+                        ])
+                    },
+                )
+            }),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 23u8, 0u8, 30u8, 0u8, 40u8,
+                            0u8, 48u8, 0u8, 59u8, 0u8, 66u8, 0u8, 76u8, 0u8, 83u8, 0u8, 93u8, 0u8,
+                            101u8, 0u8, 112u8, 0u8, 119u8, 0u8, 129u8, 0u8, 136u8, 0u8, 146u8, 0u8,
+                            154u8, 0u8, 165u8, 0u8, 173u8, 0u8, 184u8, 0u8, 195u8, 0u8, 209u8, 0u8,
+                            223u8, 0u8, 122u8, 104u8, 196u8, 147u8, 110u8, 103u8, 121u8, 117u8,
+                            195u8, 168u8, 122u8, 104u8, 196u8, 147u8, 110u8, 103u8, 121u8, 117u8,
+                            195u8, 168u8, 98u8, 105u8, 115u8, 195u8, 168u8, 114u8, 121u8, 117u8,
+                            195u8, 168u8, 195u8, 168u8, 114u8, 121u8, 117u8, 195u8, 168u8, 98u8,
+                            105u8, 115u8, 115u8, 196u8, 129u8, 110u8, 121u8, 117u8, 195u8, 168u8,
+                            115u8, 196u8, 129u8, 110u8, 121u8, 117u8, 195u8, 168u8, 98u8, 105u8,
+                            115u8, 115u8, 195u8, 172u8, 121u8, 117u8, 195u8, 168u8, 115u8, 195u8,
+                            172u8, 121u8, 117u8, 195u8, 168u8, 98u8, 105u8, 115u8, 119u8, 199u8,
+                            148u8, 121u8, 117u8, 195u8, 168u8, 119u8, 199u8, 148u8, 121u8, 117u8,
+                            195u8, 168u8, 98u8, 105u8, 115u8, 108u8, 105u8, 195u8, 185u8, 121u8,
+                            117u8, 195u8, 168u8, 108u8, 105u8, 195u8, 185u8, 121u8, 117u8, 195u8,
+                            168u8, 98u8, 105u8, 115u8, 113u8, 196u8, 171u8, 121u8, 117u8, 195u8,
+                            168u8, 113u8, 196u8, 171u8, 121u8, 117u8, 195u8, 168u8, 98u8, 105u8,
+                            115u8, 98u8, 196u8, 129u8, 121u8, 117u8, 195u8, 168u8, 98u8, 196u8,
+                            129u8, 121u8, 117u8, 195u8, 168u8, 98u8, 105u8, 115u8, 106u8, 105u8,
+                            199u8, 148u8, 121u8, 117u8, 195u8, 168u8, 106u8, 105u8, 199u8, 148u8,
+                            121u8, 117u8, 195u8, 168u8, 98u8, 105u8, 115u8, 115u8, 104u8, 195u8,
+                            173u8, 121u8, 117u8, 195u8, 168u8, 115u8, 104u8, 195u8, 173u8, 121u8,
+                            117u8, 195u8, 168u8, 98u8, 105u8, 115u8, 115u8, 104u8, 195u8, 173u8,
+                            121u8, 196u8, 171u8, 121u8, 117u8, 195u8, 168u8, 115u8, 104u8, 195u8,
+                            173u8, 121u8, 196u8, 171u8, 121u8, 117u8, 195u8, 168u8, 98u8, 105u8,
+                            115u8, 115u8, 104u8, 195u8, 173u8, 226u8, 128u8, 153u8, 195u8, 168u8,
+                            114u8, 121u8, 117u8, 195u8, 168u8, 115u8, 104u8, 195u8, 173u8, 226u8,
+                            128u8, 153u8, 195u8, 168u8, 114u8, 121u8, 117u8, 195u8, 168u8, 98u8,
+                            105u8, 115u8,
+                        ])
+                    },
+                )
+            }),
+        },
+        stand_alone: None,
+    },
+    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("dim."),
+                alloc::borrow::Cow::Borrowed("lun."),
+                alloc::borrow::Cow::Borrowed("mar."),
+                alloc::borrow::Cow::Borrowed("mer."),
+                alloc::borrow::Cow::Borrowed("jeu."),
+                alloc::borrow::Cow::Borrowed("ven."),
+                alloc::borrow::Cow::Borrowed("sam."),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("D"),
+                alloc::borrow::Cow::Borrowed("L"),
+                alloc::borrow::Cow::Borrowed("M"),
+                alloc::borrow::Cow::Borrowed("M"),
+                alloc::borrow::Cow::Borrowed("J"),
+                alloc::borrow::Cow::Borrowed("V"),
+                alloc::borrow::Cow::Borrowed("S"),
+            ]),
+            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("di"),
+                alloc::borrow::Cow::Borrowed("lu"),
+                alloc::borrow::Cow::Borrowed("ma"),
+                alloc::borrow::Cow::Borrowed("me"),
+                alloc::borrow::Cow::Borrowed("je"),
+                alloc::borrow::Cow::Borrowed("ve"),
+                alloc::borrow::Cow::Borrowed("sa"),
+            ])),
+            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("dimanche"),
+                alloc::borrow::Cow::Borrowed("lundi"),
+                alloc::borrow::Cow::Borrowed("mardi"),
+                alloc::borrow::Cow::Borrowed("mercredi"),
+                alloc::borrow::Cow::Borrowed("jeudi"),
+                alloc::borrow::Cow::Borrowed("vendredi"),
+                alloc::borrow::Cow::Borrowed("samedi"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    eras: ::icu_datetime::provider::calendar::Eras {
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+        abbr: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+        narrow: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+    },
+    cyclic_years: None,
+};
+static JA: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
+    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 15u8, 0u8, 21u8, 0u8, 30u8,
+                            0u8, 36u8, 0u8, 45u8, 0u8, 51u8, 0u8, 60u8, 0u8, 66u8, 0u8, 75u8, 0u8,
+                            81u8, 0u8, 90u8, 0u8, 96u8, 0u8, 105u8, 0u8, 111u8, 0u8, 120u8, 0u8,
+                            126u8, 0u8, 135u8, 0u8, 141u8, 0u8, 150u8, 0u8, 159u8, 0u8, 171u8, 0u8,
+                            180u8, 0u8, 230u8, 173u8, 163u8, 230u8, 156u8, 136u8, 233u8, 150u8,
+                            143u8, 230u8, 173u8, 163u8, 230u8, 156u8, 136u8, 228u8, 186u8, 140u8,
+                            230u8, 156u8, 136u8, 233u8, 150u8, 143u8, 228u8, 186u8, 140u8, 230u8,
+                            156u8, 136u8, 228u8, 184u8, 137u8, 230u8, 156u8, 136u8, 233u8, 150u8,
+                            143u8, 228u8, 184u8, 137u8, 230u8, 156u8, 136u8, 229u8, 155u8, 155u8,
+                            230u8, 156u8, 136u8, 233u8, 150u8, 143u8, 229u8, 155u8, 155u8, 230u8,
+                            156u8, 136u8, 228u8, 186u8, 148u8, 230u8, 156u8, 136u8, 233u8, 150u8,
+                            143u8, 228u8, 186u8, 148u8, 230u8, 156u8, 136u8, 229u8, 133u8, 173u8,
+                            230u8, 156u8, 136u8, 233u8, 150u8, 143u8, 229u8, 133u8, 173u8, 230u8,
+                            156u8, 136u8, 228u8, 184u8, 131u8, 230u8, 156u8, 136u8, 233u8, 150u8,
+                            143u8, 228u8, 184u8, 131u8, 230u8, 156u8, 136u8, 229u8, 133u8, 171u8,
+                            230u8, 156u8, 136u8, 233u8, 150u8, 143u8, 229u8, 133u8, 171u8, 230u8,
+                            156u8, 136u8, 228u8, 185u8, 157u8, 230u8, 156u8, 136u8, 233u8, 150u8,
+                            143u8, 228u8, 185u8, 157u8, 230u8, 156u8, 136u8, 229u8, 141u8, 129u8,
+                            230u8, 156u8, 136u8, 233u8, 150u8, 143u8, 229u8, 141u8, 129u8, 230u8,
+                            156u8, 136u8, 229u8, 141u8, 129u8, 228u8, 184u8, 128u8, 230u8, 156u8,
+                            136u8, 233u8, 150u8, 143u8, 229u8, 141u8, 129u8, 228u8, 184u8, 128u8,
+                            230u8, 156u8, 136u8, 229u8, 141u8, 129u8, 228u8, 186u8, 140u8, 230u8,
+                            156u8, 136u8, 233u8, 150u8, 143u8, 229u8, 141u8, 129u8, 228u8, 186u8,
+                            140u8, 230u8, 156u8, 136u8,
+                        ])
+                    },
+                )
+            }),
+            narrow: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 9u8, 0u8, 12u8, 0u8, 18u8,
+                            0u8, 21u8, 0u8, 27u8, 0u8, 30u8, 0u8, 36u8, 0u8, 39u8, 0u8, 45u8, 0u8,
+                            48u8, 0u8, 54u8, 0u8, 57u8, 0u8, 63u8, 0u8, 66u8, 0u8, 72u8, 0u8, 75u8,
+                            0u8, 81u8, 0u8, 84u8, 0u8, 90u8, 0u8, 96u8, 0u8, 105u8, 0u8, 111u8,
+                            0u8, 230u8, 173u8, 163u8, 233u8, 150u8, 143u8, 230u8, 173u8, 163u8,
+                            228u8, 186u8, 140u8, 233u8, 150u8, 143u8, 228u8, 186u8, 140u8, 228u8,
+                            184u8, 137u8, 233u8, 150u8, 143u8, 228u8, 184u8, 137u8, 229u8, 155u8,
+                            155u8, 233u8, 150u8, 143u8, 229u8, 155u8, 155u8, 228u8, 186u8, 148u8,
+                            233u8, 150u8, 143u8, 228u8, 186u8, 148u8, 229u8, 133u8, 173u8, 233u8,
+                            150u8, 143u8, 229u8, 133u8, 173u8, 228u8, 184u8, 131u8, 233u8, 150u8,
+                            143u8, 228u8, 184u8, 131u8, 229u8, 133u8, 171u8, 233u8, 150u8, 143u8,
+                            229u8, 133u8, 171u8, 228u8, 185u8, 157u8, 233u8, 150u8, 143u8, 228u8,
+                            185u8, 157u8, 229u8, 141u8, 129u8, 233u8, 150u8, 143u8, 229u8, 141u8,
+                            129u8, 229u8, 141u8, 129u8, 228u8, 184u8, 128u8, 233u8, 150u8, 143u8,
+                            229u8, 141u8, 129u8, 228u8, 184u8, 128u8, 229u8, 141u8, 129u8, 228u8,
+                            186u8, 140u8, 233u8, 150u8, 143u8, 229u8, 141u8, 129u8, 228u8, 186u8,
+                            140u8,
+                        ])
+                    },
+                )
+            }),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 15u8, 0u8, 21u8, 0u8, 30u8,
+                            0u8, 36u8, 0u8, 45u8, 0u8, 51u8, 0u8, 60u8, 0u8, 66u8, 0u8, 75u8, 0u8,
+                            81u8, 0u8, 90u8, 0u8, 96u8, 0u8, 105u8, 0u8, 111u8, 0u8, 120u8, 0u8,
+                            126u8, 0u8, 135u8, 0u8, 141u8, 0u8, 150u8, 0u8, 159u8, 0u8, 171u8, 0u8,
+                            180u8, 0u8, 230u8, 173u8, 163u8, 230u8, 156u8, 136u8, 233u8, 150u8,
+                            143u8, 230u8, 173u8, 163u8, 230u8, 156u8, 136u8, 228u8, 186u8, 140u8,
+                            230u8, 156u8, 136u8, 233u8, 150u8, 143u8, 228u8, 186u8, 140u8, 230u8,
+                            156u8, 136u8, 228u8, 184u8, 137u8, 230u8, 156u8, 136u8, 233u8, 150u8,
+                            143u8, 228u8, 184u8, 137u8, 230u8, 156u8, 136u8, 229u8, 155u8, 155u8,
+                            230u8, 156u8, 136u8, 233u8, 150u8, 143u8, 229u8, 155u8, 155u8, 230u8,
+                            156u8, 136u8, 228u8, 186u8, 148u8, 230u8, 156u8, 136u8, 233u8, 150u8,
+                            143u8, 228u8, 186u8, 148u8, 230u8, 156u8, 136u8, 229u8, 133u8, 173u8,
+                            230u8, 156u8, 136u8, 233u8, 150u8, 143u8, 229u8, 133u8, 173u8, 230u8,
+                            156u8, 136u8, 228u8, 184u8, 131u8, 230u8, 156u8, 136u8, 233u8, 150u8,
+                            143u8, 228u8, 184u8, 131u8, 230u8, 156u8, 136u8, 229u8, 133u8, 171u8,
+                            230u8, 156u8, 136u8, 233u8, 150u8, 143u8, 229u8, 133u8, 171u8, 230u8,
+                            156u8, 136u8, 228u8, 185u8, 157u8, 230u8, 156u8, 136u8, 233u8, 150u8,
+                            143u8, 228u8, 185u8, 157u8, 230u8, 156u8, 136u8, 229u8, 141u8, 129u8,
+                            230u8, 156u8, 136u8, 233u8, 150u8, 143u8, 229u8, 141u8, 129u8, 230u8,
+                            156u8, 136u8, 229u8, 141u8, 129u8, 228u8, 184u8, 128u8, 230u8, 156u8,
+                            136u8, 233u8, 150u8, 143u8, 229u8, 141u8, 129u8, 228u8, 184u8, 128u8,
+                            230u8, 156u8, 136u8, 229u8, 141u8, 129u8, 228u8, 186u8, 140u8, 230u8,
+                            156u8, 136u8, 233u8, 150u8, 143u8, 229u8, 141u8, 129u8, 228u8, 186u8,
+                            140u8, 230u8, 156u8, 136u8,
+                        ])
+                    },
+                )
+            }),
+        },
+        stand_alone: None,
+    },
+    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("日"),
+                alloc::borrow::Cow::Borrowed("月"),
+                alloc::borrow::Cow::Borrowed("火"),
+                alloc::borrow::Cow::Borrowed("水"),
+                alloc::borrow::Cow::Borrowed("木"),
+                alloc::borrow::Cow::Borrowed("金"),
+                alloc::borrow::Cow::Borrowed("土"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("日"),
+                alloc::borrow::Cow::Borrowed("月"),
+                alloc::borrow::Cow::Borrowed("火"),
+                alloc::borrow::Cow::Borrowed("水"),
+                alloc::borrow::Cow::Borrowed("木"),
+                alloc::borrow::Cow::Borrowed("金"),
+                alloc::borrow::Cow::Borrowed("土"),
+            ]),
+            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("日"),
+                alloc::borrow::Cow::Borrowed("月"),
+                alloc::borrow::Cow::Borrowed("火"),
+                alloc::borrow::Cow::Borrowed("水"),
+                alloc::borrow::Cow::Borrowed("木"),
+                alloc::borrow::Cow::Borrowed("金"),
+                alloc::borrow::Cow::Borrowed("土"),
+            ])),
+            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("日曜日"),
+                alloc::borrow::Cow::Borrowed("月曜日"),
+                alloc::borrow::Cow::Borrowed("火曜日"),
+                alloc::borrow::Cow::Borrowed("水曜日"),
+                alloc::borrow::Cow::Borrowed("木曜日"),
+                alloc::borrow::Cow::Borrowed("金曜日"),
+                alloc::borrow::Cow::Borrowed("土曜日"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    eras: ::icu_datetime::provider::calendar::Eras {
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+        abbr: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+        narrow: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+    },
+    cyclic_years: None,
+};
+static RU: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
+    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 5u8, 0u8, 6u8, 0u8, 10u8, 0u8,
+                            11u8, 0u8, 15u8, 0u8, 16u8, 0u8, 20u8, 0u8, 21u8, 0u8, 25u8, 0u8, 26u8,
+                            0u8, 30u8, 0u8, 31u8, 0u8, 35u8, 0u8, 36u8, 0u8, 40u8, 0u8, 41u8, 0u8,
+                            45u8, 0u8, 47u8, 0u8, 52u8, 0u8, 54u8, 0u8, 59u8, 0u8, 61u8, 0u8, 49u8,
+                            49u8, 98u8, 105u8, 115u8, 50u8, 50u8, 98u8, 105u8, 115u8, 51u8, 51u8,
+                            98u8, 105u8, 115u8, 52u8, 52u8, 98u8, 105u8, 115u8, 53u8, 53u8, 98u8,
+                            105u8, 115u8, 54u8, 54u8, 98u8, 105u8, 115u8, 55u8, 55u8, 98u8, 105u8,
+                            115u8, 56u8, 56u8, 98u8, 105u8, 115u8, 57u8, 57u8, 98u8, 105u8, 115u8,
+                            49u8, 48u8, 49u8, 48u8, 98u8, 105u8, 115u8, 49u8, 49u8, 49u8, 49u8,
+                            98u8, 105u8, 115u8, 49u8, 50u8, 49u8, 50u8, 98u8, 105u8, 115u8,
+                        ])
+                    },
+                )
+            }),
+            narrow: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 3u8, 0u8, 4u8, 0u8, 6u8, 0u8,
+                            7u8, 0u8, 9u8, 0u8, 10u8, 0u8, 12u8, 0u8, 13u8, 0u8, 15u8, 0u8, 16u8,
+                            0u8, 18u8, 0u8, 19u8, 0u8, 21u8, 0u8, 22u8, 0u8, 24u8, 0u8, 25u8, 0u8,
+                            27u8, 0u8, 29u8, 0u8, 32u8, 0u8, 34u8, 0u8, 37u8, 0u8, 39u8, 0u8, 49u8,
+                            49u8, 98u8, 50u8, 50u8, 98u8, 51u8, 51u8, 98u8, 52u8, 52u8, 98u8, 53u8,
+                            53u8, 98u8, 54u8, 54u8, 98u8, 55u8, 55u8, 98u8, 56u8, 56u8, 98u8, 57u8,
+                            57u8, 98u8, 49u8, 48u8, 49u8, 48u8, 98u8, 49u8, 49u8, 49u8, 49u8, 98u8,
+                            49u8, 50u8, 49u8, 50u8, 98u8,
+                        ])
+                    },
+                )
+            }),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 9u8, 0u8, 12u8, 0u8, 18u8,
+                            0u8, 21u8, 0u8, 27u8, 0u8, 30u8, 0u8, 36u8, 0u8, 39u8, 0u8, 45u8, 0u8,
+                            48u8, 0u8, 54u8, 0u8, 57u8, 0u8, 63u8, 0u8, 66u8, 0u8, 72u8, 0u8, 75u8,
+                            0u8, 81u8, 0u8, 84u8, 0u8, 90u8, 0u8, 93u8, 0u8, 99u8, 0u8, 102u8, 0u8,
+                            77u8, 48u8, 49u8, 77u8, 48u8, 49u8, 98u8, 105u8, 115u8, 77u8, 48u8,
+                            50u8, 77u8, 48u8, 50u8, 98u8, 105u8, 115u8, 77u8, 48u8, 51u8, 77u8,
+                            48u8, 51u8, 98u8, 105u8, 115u8, 77u8, 48u8, 52u8, 77u8, 48u8, 52u8,
+                            98u8, 105u8, 115u8, 77u8, 48u8, 53u8, 77u8, 48u8, 53u8, 98u8, 105u8,
+                            115u8, 77u8, 48u8, 54u8, 77u8, 48u8, 54u8, 98u8, 105u8, 115u8, 77u8,
+                            48u8, 55u8, 77u8, 48u8, 55u8, 98u8, 105u8, 115u8, 77u8, 48u8, 56u8,
+                            77u8, 48u8, 56u8, 98u8, 105u8, 115u8, 77u8, 48u8, 57u8, 77u8, 48u8,
+                            57u8, 98u8, 105u8, 115u8, 77u8, 49u8, 48u8, 77u8, 49u8, 48u8, 98u8,
+                            105u8, 115u8, 77u8, 49u8, 49u8, 77u8, 49u8, 49u8, 98u8, 105u8, 115u8,
+                            77u8, 49u8, 50u8, 77u8, 49u8, 50u8, 98u8, 105u8, 115u8,
+                        ])
+                    },
+                )
+            }),
+        },
+        stand_alone: Some(
+            ::icu_datetime::provider::calendar::months::StandAloneWidthsV1 {
+                abbreviated: None,
+                narrow: None,
+                short: None,
+                wide: Some(
+                    ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                        #[allow(unused_unsafe)]
+                        ::zerovec::ZeroMap::from_parts_unchecked(
+                            unsafe {
+                                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                    77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8,
+                                    50u8, 0u8, 77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8,
+                                    48u8, 51u8, 76u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8,
+                                    76u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8, 53u8, 76u8, 77u8,
+                                    48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8, 77u8, 48u8, 55u8, 0u8,
+                                    77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8, 77u8, 48u8,
+                                    56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                                    77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8,
+                                    49u8, 0u8, 77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8,
+                                    49u8, 50u8, 76u8,
+                                ])
+                            },
+                            unsafe {
+                                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                    24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 5u8, 0u8, 6u8, 0u8,
+                                    10u8, 0u8, 11u8, 0u8, 15u8, 0u8, 16u8, 0u8, 20u8, 0u8, 21u8,
+                                    0u8, 25u8, 0u8, 26u8, 0u8, 30u8, 0u8, 31u8, 0u8, 35u8, 0u8,
+                                    36u8, 0u8, 40u8, 0u8, 41u8, 0u8, 45u8, 0u8, 47u8, 0u8, 52u8,
+                                    0u8, 54u8, 0u8, 59u8, 0u8, 61u8, 0u8, 49u8, 49u8, 98u8, 105u8,
+                                    115u8, 50u8, 50u8, 98u8, 105u8, 115u8, 51u8, 51u8, 98u8, 105u8,
+                                    115u8, 52u8, 52u8, 98u8, 105u8, 115u8, 53u8, 53u8, 98u8, 105u8,
+                                    115u8, 54u8, 54u8, 98u8, 105u8, 115u8, 55u8, 55u8, 98u8, 105u8,
+                                    115u8, 56u8, 56u8, 98u8, 105u8, 115u8, 57u8, 57u8, 98u8, 105u8,
+                                    115u8, 49u8, 48u8, 49u8, 48u8, 98u8, 105u8, 115u8, 49u8, 49u8,
+                                    49u8, 49u8, 98u8, 105u8, 115u8, 49u8, 50u8, 49u8, 50u8, 98u8,
+                                    105u8, 115u8,
+                                ])
+                            },
+                        )
+                    }),
+                ),
+            },
+        ),
+    },
+    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("вс"),
+                alloc::borrow::Cow::Borrowed("пн"),
+                alloc::borrow::Cow::Borrowed("вт"),
+                alloc::borrow::Cow::Borrowed("ср"),
+                alloc::borrow::Cow::Borrowed("чт"),
+                alloc::borrow::Cow::Borrowed("пт"),
+                alloc::borrow::Cow::Borrowed("сб"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("В"),
+                alloc::borrow::Cow::Borrowed("П"),
+                alloc::borrow::Cow::Borrowed("В"),
+                alloc::borrow::Cow::Borrowed("С"),
+                alloc::borrow::Cow::Borrowed("Ч"),
+                alloc::borrow::Cow::Borrowed("П"),
+                alloc::borrow::Cow::Borrowed("С"),
+            ]),
+            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("вс"),
+                alloc::borrow::Cow::Borrowed("пн"),
+                alloc::borrow::Cow::Borrowed("вт"),
+                alloc::borrow::Cow::Borrowed("ср"),
+                alloc::borrow::Cow::Borrowed("чт"),
+                alloc::borrow::Cow::Borrowed("пт"),
+                alloc::borrow::Cow::Borrowed("сб"),
+            ])),
+            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("воскресенье"),
+                alloc::borrow::Cow::Borrowed("понедельник"),
+                alloc::borrow::Cow::Borrowed("вторник"),
+                alloc::borrow::Cow::Borrowed("среда"),
+                alloc::borrow::Cow::Borrowed("четверг"),
+                alloc::borrow::Cow::Borrowed("пятница"),
+                alloc::borrow::Cow::Borrowed("суббота"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    eras: ::icu_datetime::provider::calendar::Eras {
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+        abbr: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+        narrow: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+    },
+    cyclic_years: None,
+};
+static SR_LATN: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
+    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 9u8, 0u8, 12u8, 0u8, 18u8,
+                            0u8, 21u8, 0u8, 27u8, 0u8, 30u8, 0u8, 36u8, 0u8, 39u8, 0u8, 45u8, 0u8,
+                            48u8, 0u8, 54u8, 0u8, 57u8, 0u8, 63u8, 0u8, 66u8, 0u8, 72u8, 0u8, 75u8,
+                            0u8, 81u8, 0u8, 84u8, 0u8, 90u8, 0u8, 93u8, 0u8, 99u8, 0u8, 102u8, 0u8,
+                            77u8, 48u8, 49u8, 77u8, 48u8, 49u8, 98u8, 105u8, 115u8, 77u8, 48u8,
+                            50u8, 77u8, 48u8, 50u8, 98u8, 105u8, 115u8, 77u8, 48u8, 51u8, 77u8,
+                            48u8, 51u8, 98u8, 105u8, 115u8, 77u8, 48u8, 52u8, 77u8, 48u8, 52u8,
+                            98u8, 105u8, 115u8, 77u8, 48u8, 53u8, 77u8, 48u8, 53u8, 98u8, 105u8,
+                            115u8, 77u8, 48u8, 54u8, 77u8, 48u8, 54u8, 98u8, 105u8, 115u8, 77u8,
+                            48u8, 55u8, 77u8, 48u8, 55u8, 98u8, 105u8, 115u8, 77u8, 48u8, 56u8,
+                            77u8, 48u8, 56u8, 98u8, 105u8, 115u8, 77u8, 48u8, 57u8, 77u8, 48u8,
+                            57u8, 98u8, 105u8, 115u8, 77u8, 49u8, 48u8, 77u8, 49u8, 48u8, 98u8,
+                            105u8, 115u8, 77u8, 49u8, 49u8, 77u8, 49u8, 49u8, 98u8, 105u8, 115u8,
+                            77u8, 49u8, 50u8, 77u8, 49u8, 50u8, 98u8, 105u8, 115u8,
+                        ])
+                    },
+                )
+            }),
+            narrow: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 3u8, 0u8, 4u8, 0u8, 6u8, 0u8,
+                            7u8, 0u8, 9u8, 0u8, 10u8, 0u8, 12u8, 0u8, 13u8, 0u8, 15u8, 0u8, 16u8,
+                            0u8, 18u8, 0u8, 19u8, 0u8, 21u8, 0u8, 22u8, 0u8, 24u8, 0u8, 25u8, 0u8,
+                            27u8, 0u8, 29u8, 0u8, 32u8, 0u8, 34u8, 0u8, 37u8, 0u8, 39u8, 0u8, 49u8,
+                            49u8, 98u8, 50u8, 50u8, 98u8, 51u8, 51u8, 98u8, 52u8, 52u8, 98u8, 53u8,
+                            53u8, 98u8, 54u8, 54u8, 98u8, 55u8, 55u8, 98u8, 56u8, 56u8, 98u8, 57u8,
+                            57u8, 98u8, 49u8, 48u8, 49u8, 48u8, 98u8, 49u8, 49u8, 49u8, 49u8, 98u8,
+                            49u8, 50u8, 49u8, 50u8, 98u8,
+                        ])
+                    },
+                )
+            }),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 9u8, 0u8, 12u8, 0u8, 18u8,
+                            0u8, 21u8, 0u8, 27u8, 0u8, 30u8, 0u8, 36u8, 0u8, 39u8, 0u8, 45u8, 0u8,
+                            48u8, 0u8, 54u8, 0u8, 57u8, 0u8, 63u8, 0u8, 66u8, 0u8, 72u8, 0u8, 75u8,
+                            0u8, 81u8, 0u8, 84u8, 0u8, 90u8, 0u8, 93u8, 0u8, 99u8, 0u8, 102u8, 0u8,
+                            77u8, 48u8, 49u8, 77u8, 48u8, 49u8, 98u8, 105u8, 115u8, 77u8, 48u8,
+                            50u8, 77u8, 48u8, 50u8, 98u8, 105u8, 115u8, 77u8, 48u8, 51u8, 77u8,
+                            48u8, 51u8, 98u8, 105u8, 115u8, 77u8, 48u8, 52u8, 77u8, 48u8, 52u8,
+                            98u8, 105u8, 115u8, 77u8, 48u8, 53u8, 77u8, 48u8, 53u8, 98u8, 105u8,
+                            115u8, 77u8, 48u8, 54u8, 77u8, 48u8, 54u8, 98u8, 105u8, 115u8, 77u8,
+                            48u8, 55u8, 77u8, 48u8, 55u8, 98u8, 105u8, 115u8, 77u8, 48u8, 56u8,
+                            77u8, 48u8, 56u8, 98u8, 105u8, 115u8, 77u8, 48u8, 57u8, 77u8, 48u8,
+                            57u8, 98u8, 105u8, 115u8, 77u8, 49u8, 48u8, 77u8, 49u8, 48u8, 98u8,
+                            105u8, 115u8, 77u8, 49u8, 49u8, 77u8, 49u8, 49u8, 98u8, 105u8, 115u8,
+                            77u8, 49u8, 50u8, 77u8, 49u8, 50u8, 98u8, 105u8, 115u8,
+                        ])
+                    },
+                )
+            }),
+        },
+        stand_alone: None,
+    },
+    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("ned"),
+                alloc::borrow::Cow::Borrowed("pon"),
+                alloc::borrow::Cow::Borrowed("uto"),
+                alloc::borrow::Cow::Borrowed("sre"),
+                alloc::borrow::Cow::Borrowed("čet"),
+                alloc::borrow::Cow::Borrowed("pet"),
+                alloc::borrow::Cow::Borrowed("sub"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("n"),
+                alloc::borrow::Cow::Borrowed("p"),
+                alloc::borrow::Cow::Borrowed("u"),
+                alloc::borrow::Cow::Borrowed("s"),
+                alloc::borrow::Cow::Borrowed("č"),
+                alloc::borrow::Cow::Borrowed("p"),
+                alloc::borrow::Cow::Borrowed("s"),
+            ]),
+            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("ne"),
+                alloc::borrow::Cow::Borrowed("po"),
+                alloc::borrow::Cow::Borrowed("ut"),
+                alloc::borrow::Cow::Borrowed("sr"),
+                alloc::borrow::Cow::Borrowed("če"),
+                alloc::borrow::Cow::Borrowed("pe"),
+                alloc::borrow::Cow::Borrowed("su"),
+            ])),
+            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("nedelja"),
+                alloc::borrow::Cow::Borrowed("ponedeljak"),
+                alloc::borrow::Cow::Borrowed("utorak"),
+                alloc::borrow::Cow::Borrowed("sreda"),
+                alloc::borrow::Cow::Borrowed("četvrtak"),
+                alloc::borrow::Cow::Borrowed("petak"),
+                alloc::borrow::Cow::Borrowed("subota"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    eras: ::icu_datetime::provider::calendar::Eras {
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+        abbr: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+        narrow: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+    },
+    cyclic_years: None,
+};
+static SR_SR_CYRL: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
+    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 9u8, 0u8, 12u8, 0u8, 18u8,
+                            0u8, 21u8, 0u8, 27u8, 0u8, 30u8, 0u8, 36u8, 0u8, 39u8, 0u8, 45u8, 0u8,
+                            48u8, 0u8, 54u8, 0u8, 57u8, 0u8, 63u8, 0u8, 66u8, 0u8, 72u8, 0u8, 75u8,
+                            0u8, 81u8, 0u8, 84u8, 0u8, 90u8, 0u8, 93u8, 0u8, 99u8, 0u8, 102u8, 0u8,
+                            77u8, 48u8, 49u8, 77u8, 48u8, 49u8, 98u8, 105u8, 115u8, 77u8, 48u8,
+                            50u8, 77u8, 48u8, 50u8, 98u8, 105u8, 115u8, 77u8, 48u8, 51u8, 77u8,
+                            48u8, 51u8, 98u8, 105u8, 115u8, 77u8, 48u8, 52u8, 77u8, 48u8, 52u8,
+                            98u8, 105u8, 115u8, 77u8, 48u8, 53u8, 77u8, 48u8, 53u8, 98u8, 105u8,
+                            115u8, 77u8, 48u8, 54u8, 77u8, 48u8, 54u8, 98u8, 105u8, 115u8, 77u8,
+                            48u8, 55u8, 77u8, 48u8, 55u8, 98u8, 105u8, 115u8, 77u8, 48u8, 56u8,
+                            77u8, 48u8, 56u8, 98u8, 105u8, 115u8, 77u8, 48u8, 57u8, 77u8, 48u8,
+                            57u8, 98u8, 105u8, 115u8, 77u8, 49u8, 48u8, 77u8, 49u8, 48u8, 98u8,
+                            105u8, 115u8, 77u8, 49u8, 49u8, 77u8, 49u8, 49u8, 98u8, 105u8, 115u8,
+                            77u8, 49u8, 50u8, 77u8, 49u8, 50u8, 98u8, 105u8, 115u8,
+                        ])
+                    },
+                )
+            }),
+            narrow: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 3u8, 0u8, 4u8, 0u8, 6u8, 0u8,
+                            7u8, 0u8, 9u8, 0u8, 10u8, 0u8, 12u8, 0u8, 13u8, 0u8, 15u8, 0u8, 16u8,
+                            0u8, 18u8, 0u8, 19u8, 0u8, 21u8, 0u8, 22u8, 0u8, 24u8, 0u8, 25u8, 0u8,
+                            27u8, 0u8, 29u8, 0u8, 32u8, 0u8, 34u8, 0u8, 37u8, 0u8, 39u8, 0u8, 49u8,
+                            49u8, 98u8, 50u8, 50u8, 98u8, 51u8, 51u8, 98u8, 52u8, 52u8, 98u8, 53u8,
+                            53u8, 98u8, 54u8, 54u8, 98u8, 55u8, 55u8, 98u8, 56u8, 56u8, 98u8, 57u8,
+                            57u8, 98u8, 49u8, 48u8, 49u8, 48u8, 98u8, 49u8, 49u8, 49u8, 49u8, 98u8,
+                            49u8, 50u8, 49u8, 50u8, 98u8,
+                        ])
+                    },
+                )
+            }),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 9u8, 0u8, 12u8, 0u8, 18u8,
+                            0u8, 21u8, 0u8, 27u8, 0u8, 30u8, 0u8, 36u8, 0u8, 39u8, 0u8, 45u8, 0u8,
+                            48u8, 0u8, 54u8, 0u8, 57u8, 0u8, 63u8, 0u8, 66u8, 0u8, 72u8, 0u8, 75u8,
+                            0u8, 81u8, 0u8, 84u8, 0u8, 90u8, 0u8, 93u8, 0u8, 99u8, 0u8, 102u8, 0u8,
+                            77u8, 48u8, 49u8, 77u8, 48u8, 49u8, 98u8, 105u8, 115u8, 77u8, 48u8,
+                            50u8, 77u8, 48u8, 50u8, 98u8, 105u8, 115u8, 77u8, 48u8, 51u8, 77u8,
+                            48u8, 51u8, 98u8, 105u8, 115u8, 77u8, 48u8, 52u8, 77u8, 48u8, 52u8,
+                            98u8, 105u8, 115u8, 77u8, 48u8, 53u8, 77u8, 48u8, 53u8, 98u8, 105u8,
+                            115u8, 77u8, 48u8, 54u8, 77u8, 48u8, 54u8, 98u8, 105u8, 115u8, 77u8,
+                            48u8, 55u8, 77u8, 48u8, 55u8, 98u8, 105u8, 115u8, 77u8, 48u8, 56u8,
+                            77u8, 48u8, 56u8, 98u8, 105u8, 115u8, 77u8, 48u8, 57u8, 77u8, 48u8,
+                            57u8, 98u8, 105u8, 115u8, 77u8, 49u8, 48u8, 77u8, 49u8, 48u8, 98u8,
+                            105u8, 115u8, 77u8, 49u8, 49u8, 77u8, 49u8, 49u8, 98u8, 105u8, 115u8,
+                            77u8, 49u8, 50u8, 77u8, 49u8, 50u8, 98u8, 105u8, 115u8,
+                        ])
+                    },
+                )
+            }),
+        },
+        stand_alone: None,
+    },
+    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("нед"),
+                alloc::borrow::Cow::Borrowed("пон"),
+                alloc::borrow::Cow::Borrowed("уто"),
+                alloc::borrow::Cow::Borrowed("сре"),
+                alloc::borrow::Cow::Borrowed("чет"),
+                alloc::borrow::Cow::Borrowed("пет"),
+                alloc::borrow::Cow::Borrowed("суб"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("н"),
+                alloc::borrow::Cow::Borrowed("п"),
+                alloc::borrow::Cow::Borrowed("у"),
+                alloc::borrow::Cow::Borrowed("с"),
+                alloc::borrow::Cow::Borrowed("ч"),
+                alloc::borrow::Cow::Borrowed("п"),
+                alloc::borrow::Cow::Borrowed("с"),
+            ]),
+            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("не"),
+                alloc::borrow::Cow::Borrowed("по"),
+                alloc::borrow::Cow::Borrowed("ут"),
+                alloc::borrow::Cow::Borrowed("ср"),
+                alloc::borrow::Cow::Borrowed("че"),
+                alloc::borrow::Cow::Borrowed("пе"),
+                alloc::borrow::Cow::Borrowed("су"),
+            ])),
+            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("недеља"),
+                alloc::borrow::Cow::Borrowed("понедељак"),
+                alloc::borrow::Cow::Borrowed("уторак"),
+                alloc::borrow::Cow::Borrowed("среда"),
+                alloc::borrow::Cow::Borrowed("четвртак"),
+                alloc::borrow::Cow::Borrowed("петак"),
+                alloc::borrow::Cow::Borrowed("субота"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    eras: ::icu_datetime::provider::calendar::Eras {
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+        abbr: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+        narrow: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+    },
+    cyclic_years: None,
+};
+static TH: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
+    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 5u8, 0u8, 6u8, 0u8, 10u8, 0u8,
+                            11u8, 0u8, 15u8, 0u8, 16u8, 0u8, 20u8, 0u8, 21u8, 0u8, 25u8, 0u8, 26u8,
+                            0u8, 30u8, 0u8, 31u8, 0u8, 35u8, 0u8, 36u8, 0u8, 40u8, 0u8, 41u8, 0u8,
+                            45u8, 0u8, 47u8, 0u8, 52u8, 0u8, 54u8, 0u8, 59u8, 0u8, 61u8, 0u8, 49u8,
+                            49u8, 98u8, 105u8, 115u8, 50u8, 50u8, 98u8, 105u8, 115u8, 51u8, 51u8,
+                            98u8, 105u8, 115u8, 52u8, 52u8, 98u8, 105u8, 115u8, 53u8, 53u8, 98u8,
+                            105u8, 115u8, 54u8, 54u8, 98u8, 105u8, 115u8, 55u8, 55u8, 98u8, 105u8,
+                            115u8, 56u8, 56u8, 98u8, 105u8, 115u8, 57u8, 57u8, 98u8, 105u8, 115u8,
+                            49u8, 48u8, 49u8, 48u8, 98u8, 105u8, 115u8, 49u8, 49u8, 49u8, 49u8,
+                            98u8, 105u8, 115u8, 49u8, 50u8, 49u8, 50u8, 98u8, 105u8, 115u8,
+                        ])
+                    },
+                )
+            }),
+            narrow: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 3u8, 0u8, 4u8, 0u8, 6u8, 0u8,
+                            7u8, 0u8, 9u8, 0u8, 10u8, 0u8, 12u8, 0u8, 13u8, 0u8, 15u8, 0u8, 16u8,
+                            0u8, 18u8, 0u8, 19u8, 0u8, 21u8, 0u8, 22u8, 0u8, 24u8, 0u8, 25u8, 0u8,
+                            27u8, 0u8, 29u8, 0u8, 32u8, 0u8, 34u8, 0u8, 37u8, 0u8, 39u8, 0u8, 49u8,
+                            49u8, 98u8, 50u8, 50u8, 98u8, 51u8, 51u8, 98u8, 52u8, 52u8, 98u8, 53u8,
+                            53u8, 98u8, 54u8, 54u8, 98u8, 55u8, 55u8, 98u8, 56u8, 56u8, 98u8, 57u8,
+                            57u8, 98u8, 49u8, 48u8, 49u8, 48u8, 98u8, 49u8, 49u8, 49u8, 49u8, 98u8,
+                            49u8, 50u8, 49u8, 50u8, 98u8,
+                        ])
+                    },
+                )
+            }),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 5u8, 0u8, 6u8, 0u8, 10u8, 0u8,
+                            11u8, 0u8, 15u8, 0u8, 16u8, 0u8, 20u8, 0u8, 21u8, 0u8, 25u8, 0u8, 26u8,
+                            0u8, 30u8, 0u8, 31u8, 0u8, 35u8, 0u8, 36u8, 0u8, 40u8, 0u8, 41u8, 0u8,
+                            45u8, 0u8, 47u8, 0u8, 52u8, 0u8, 54u8, 0u8, 59u8, 0u8, 61u8, 0u8, 49u8,
+                            49u8, 98u8, 105u8, 115u8, 50u8, 50u8, 98u8, 105u8, 115u8, 51u8, 51u8,
+                            98u8, 105u8, 115u8, 52u8, 52u8, 98u8, 105u8, 115u8, 53u8, 53u8, 98u8,
+                            105u8, 115u8, 54u8, 54u8, 98u8, 105u8, 115u8, 55u8, 55u8, 98u8, 105u8,
+                            115u8, 56u8, 56u8, 98u8, 105u8, 115u8, 57u8, 57u8, 98u8, 105u8, 115u8,
+                            49u8, 48u8, 49u8, 48u8, 98u8, 105u8, 115u8, 49u8, 49u8, 49u8, 49u8,
+                            98u8, 105u8, 115u8, 49u8, 50u8, 49u8, 50u8, 98u8, 105u8, 115u8,
+                        ])
+                    },
+                )
+            }),
+        },
+        stand_alone: None,
+    },
+    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("อา."),
+                alloc::borrow::Cow::Borrowed("จ."),
+                alloc::borrow::Cow::Borrowed("อ."),
+                alloc::borrow::Cow::Borrowed("พ."),
+                alloc::borrow::Cow::Borrowed("พฤ."),
+                alloc::borrow::Cow::Borrowed("ศ."),
+                alloc::borrow::Cow::Borrowed("ส."),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("อา"),
+                alloc::borrow::Cow::Borrowed("จ"),
+                alloc::borrow::Cow::Borrowed("อ"),
+                alloc::borrow::Cow::Borrowed("พ"),
+                alloc::borrow::Cow::Borrowed("พฤ"),
+                alloc::borrow::Cow::Borrowed("ศ"),
+                alloc::borrow::Cow::Borrowed("ส"),
+            ]),
+            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("อา."),
+                alloc::borrow::Cow::Borrowed("จ."),
+                alloc::borrow::Cow::Borrowed("อ."),
+                alloc::borrow::Cow::Borrowed("พ."),
+                alloc::borrow::Cow::Borrowed("พฤ."),
+                alloc::borrow::Cow::Borrowed("ศ."),
+                alloc::borrow::Cow::Borrowed("ส."),
+            ])),
+            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("ว\u{e31}นอาท\u{e34}ตย\u{e4c}"),
+                alloc::borrow::Cow::Borrowed("ว\u{e31}นจ\u{e31}นทร\u{e4c}"),
+                alloc::borrow::Cow::Borrowed("ว\u{e31}นอ\u{e31}งคาร"),
+                alloc::borrow::Cow::Borrowed("ว\u{e31}นพ\u{e38}ธ"),
+                alloc::borrow::Cow::Borrowed("ว\u{e31}นพฤห\u{e31}สบด\u{e35}"),
+                alloc::borrow::Cow::Borrowed("ว\u{e31}นศ\u{e38}กร\u{e4c}"),
+                alloc::borrow::Cow::Borrowed("ว\u{e31}นเสาร\u{e4c}"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    eras: ::icu_datetime::provider::calendar::Eras {
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+        abbr: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+        narrow: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+    },
+    cyclic_years: None,
+};
+static TR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
+    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 9u8, 0u8, 12u8, 0u8, 18u8,
+                            0u8, 21u8, 0u8, 27u8, 0u8, 30u8, 0u8, 36u8, 0u8, 39u8, 0u8, 45u8, 0u8,
+                            48u8, 0u8, 54u8, 0u8, 57u8, 0u8, 63u8, 0u8, 66u8, 0u8, 72u8, 0u8, 75u8,
+                            0u8, 81u8, 0u8, 84u8, 0u8, 90u8, 0u8, 93u8, 0u8, 99u8, 0u8, 102u8, 0u8,
+                            77u8, 48u8, 49u8, 77u8, 48u8, 49u8, 98u8, 105u8, 115u8, 77u8, 48u8,
+                            50u8, 77u8, 48u8, 50u8, 98u8, 105u8, 115u8, 77u8, 48u8, 51u8, 77u8,
+                            48u8, 51u8, 98u8, 105u8, 115u8, 77u8, 48u8, 52u8, 77u8, 48u8, 52u8,
+                            98u8, 105u8, 115u8, 77u8, 48u8, 53u8, 77u8, 48u8, 53u8, 98u8, 105u8,
+                            115u8, 77u8, 48u8, 54u8, 77u8, 48u8, 54u8, 98u8, 105u8, 115u8, 77u8,
+                            48u8, 55u8, 77u8, 48u8, 55u8, 98u8, 105u8, 115u8, 77u8, 48u8, 56u8,
+                            77u8, 48u8, 56u8, 98u8, 105u8, 115u8, 77u8, 48u8, 57u8, 77u8, 48u8,
+                            57u8, 98u8, 105u8, 115u8, 77u8, 49u8, 48u8, 77u8, 49u8, 48u8, 98u8,
+                            105u8, 115u8, 77u8, 49u8, 49u8, 77u8, 49u8, 49u8, 98u8, 105u8, 115u8,
+                            77u8, 49u8, 50u8, 77u8, 49u8, 50u8, 98u8, 105u8, 115u8,
+                        ])
+                    },
+                )
+            }),
+            narrow: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 3u8, 0u8, 4u8, 0u8, 6u8, 0u8,
+                            7u8, 0u8, 9u8, 0u8, 10u8, 0u8, 12u8, 0u8, 13u8, 0u8, 15u8, 0u8, 16u8,
+                            0u8, 18u8, 0u8, 19u8, 0u8, 21u8, 0u8, 22u8, 0u8, 24u8, 0u8, 25u8, 0u8,
+                            27u8, 0u8, 29u8, 0u8, 32u8, 0u8, 34u8, 0u8, 37u8, 0u8, 39u8, 0u8, 49u8,
+                            49u8, 98u8, 50u8, 50u8, 98u8, 51u8, 51u8, 98u8, 52u8, 52u8, 98u8, 53u8,
+                            53u8, 98u8, 54u8, 54u8, 98u8, 55u8, 55u8, 98u8, 56u8, 56u8, 98u8, 57u8,
+                            57u8, 98u8, 49u8, 48u8, 49u8, 48u8, 98u8, 49u8, 49u8, 49u8, 49u8, 98u8,
+                            49u8, 50u8, 49u8, 50u8, 98u8,
+                        ])
+                    },
+                )
+            }),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 9u8, 0u8, 12u8, 0u8, 18u8,
+                            0u8, 21u8, 0u8, 27u8, 0u8, 30u8, 0u8, 36u8, 0u8, 39u8, 0u8, 45u8, 0u8,
+                            48u8, 0u8, 54u8, 0u8, 57u8, 0u8, 63u8, 0u8, 66u8, 0u8, 72u8, 0u8, 75u8,
+                            0u8, 81u8, 0u8, 84u8, 0u8, 90u8, 0u8, 93u8, 0u8, 99u8, 0u8, 102u8, 0u8,
+                            77u8, 48u8, 49u8, 77u8, 48u8, 49u8, 98u8, 105u8, 115u8, 77u8, 48u8,
+                            50u8, 77u8, 48u8, 50u8, 98u8, 105u8, 115u8, 77u8, 48u8, 51u8, 77u8,
+                            48u8, 51u8, 98u8, 105u8, 115u8, 77u8, 48u8, 52u8, 77u8, 48u8, 52u8,
+                            98u8, 105u8, 115u8, 77u8, 48u8, 53u8, 77u8, 48u8, 53u8, 98u8, 105u8,
+                            115u8, 77u8, 48u8, 54u8, 77u8, 48u8, 54u8, 98u8, 105u8, 115u8, 77u8,
+                            48u8, 55u8, 77u8, 48u8, 55u8, 98u8, 105u8, 115u8, 77u8, 48u8, 56u8,
+                            77u8, 48u8, 56u8, 98u8, 105u8, 115u8, 77u8, 48u8, 57u8, 77u8, 48u8,
+                            57u8, 98u8, 105u8, 115u8, 77u8, 49u8, 48u8, 77u8, 49u8, 48u8, 98u8,
+                            105u8, 115u8, 77u8, 49u8, 49u8, 77u8, 49u8, 49u8, 98u8, 105u8, 115u8,
+                            77u8, 49u8, 50u8, 77u8, 49u8, 50u8, 98u8, 105u8, 115u8,
+                        ])
+                    },
+                )
+            }),
+        },
+        stand_alone: None,
+    },
+    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Paz"),
+                alloc::borrow::Cow::Borrowed("Pzt"),
+                alloc::borrow::Cow::Borrowed("Sal"),
+                alloc::borrow::Cow::Borrowed("Çar"),
+                alloc::borrow::Cow::Borrowed("Per"),
+                alloc::borrow::Cow::Borrowed("Cum"),
+                alloc::borrow::Cow::Borrowed("Cmt"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("P"),
+                alloc::borrow::Cow::Borrowed("P"),
+                alloc::borrow::Cow::Borrowed("S"),
+                alloc::borrow::Cow::Borrowed("Ç"),
+                alloc::borrow::Cow::Borrowed("P"),
+                alloc::borrow::Cow::Borrowed("C"),
+                alloc::borrow::Cow::Borrowed("C"),
+            ]),
+            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Pa"),
+                alloc::borrow::Cow::Borrowed("Pt"),
+                alloc::borrow::Cow::Borrowed("Sa"),
+                alloc::borrow::Cow::Borrowed("Ça"),
+                alloc::borrow::Cow::Borrowed("Pe"),
+                alloc::borrow::Cow::Borrowed("Cu"),
+                alloc::borrow::Cow::Borrowed("Ct"),
+            ])),
+            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Pazar"),
+                alloc::borrow::Cow::Borrowed("Pazartesi"),
+                alloc::borrow::Cow::Borrowed("Salı"),
+                alloc::borrow::Cow::Borrowed("Çarşamba"),
+                alloc::borrow::Cow::Borrowed("Perşembe"),
+                alloc::borrow::Cow::Borrowed("Cuma"),
+                alloc::borrow::Cow::Borrowed("Cumartesi"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    eras: ::icu_datetime::provider::calendar::Eras {
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+        abbr: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+        narrow: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+    },
+    cyclic_years: None,
+};
+static UND: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
+    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 9u8, 0u8, 12u8, 0u8, 18u8,
+                            0u8, 21u8, 0u8, 27u8, 0u8, 30u8, 0u8, 36u8, 0u8, 39u8, 0u8, 45u8, 0u8,
+                            48u8, 0u8, 54u8, 0u8, 57u8, 0u8, 63u8, 0u8, 66u8, 0u8, 72u8, 0u8, 75u8,
+                            0u8, 81u8, 0u8, 84u8, 0u8, 90u8, 0u8, 93u8, 0u8, 99u8, 0u8, 102u8, 0u8,
+                            77u8, 48u8, 49u8, 77u8, 48u8, 49u8, 98u8, 105u8, 115u8, 77u8, 48u8,
+                            50u8, 77u8, 48u8, 50u8, 98u8, 105u8, 115u8, 77u8, 48u8, 51u8, 77u8,
+                            48u8, 51u8, 98u8, 105u8, 115u8, 77u8, 48u8, 52u8, 77u8, 48u8, 52u8,
+                            98u8, 105u8, 115u8, 77u8, 48u8, 53u8, 77u8, 48u8, 53u8, 98u8, 105u8,
+                            115u8, 77u8, 48u8, 54u8, 77u8, 48u8, 54u8, 98u8, 105u8, 115u8, 77u8,
+                            48u8, 55u8, 77u8, 48u8, 55u8, 98u8, 105u8, 115u8, 77u8, 48u8, 56u8,
+                            77u8, 48u8, 56u8, 98u8, 105u8, 115u8, 77u8, 48u8, 57u8, 77u8, 48u8,
+                            57u8, 98u8, 105u8, 115u8, 77u8, 49u8, 48u8, 77u8, 49u8, 48u8, 98u8,
+                            105u8, 115u8, 77u8, 49u8, 49u8, 77u8, 49u8, 49u8, 98u8, 105u8, 115u8,
+                            77u8, 49u8, 50u8, 77u8, 49u8, 50u8, 98u8, 105u8, 115u8,
+                        ])
+                    },
+                )
+            }),
+            narrow: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 3u8, 0u8, 4u8, 0u8, 6u8, 0u8,
+                            7u8, 0u8, 9u8, 0u8, 10u8, 0u8, 12u8, 0u8, 13u8, 0u8, 15u8, 0u8, 16u8,
+                            0u8, 18u8, 0u8, 19u8, 0u8, 21u8, 0u8, 22u8, 0u8, 24u8, 0u8, 25u8, 0u8,
+                            27u8, 0u8, 29u8, 0u8, 32u8, 0u8, 34u8, 0u8, 37u8, 0u8, 39u8, 0u8, 49u8,
+                            49u8, 98u8, 50u8, 50u8, 98u8, 51u8, 51u8, 98u8, 52u8, 52u8, 98u8, 53u8,
+                            53u8, 98u8, 54u8, 54u8, 98u8, 55u8, 55u8, 98u8, 56u8, 56u8, 98u8, 57u8,
+                            57u8, 98u8, 49u8, 48u8, 49u8, 48u8, 98u8, 49u8, 49u8, 49u8, 49u8, 98u8,
+                            49u8, 50u8, 49u8, 50u8, 98u8,
+                        ])
+                    },
+                )
+            }),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 9u8, 0u8, 12u8, 0u8, 18u8,
+                            0u8, 21u8, 0u8, 27u8, 0u8, 30u8, 0u8, 36u8, 0u8, 39u8, 0u8, 45u8, 0u8,
+                            48u8, 0u8, 54u8, 0u8, 57u8, 0u8, 63u8, 0u8, 66u8, 0u8, 72u8, 0u8, 75u8,
+                            0u8, 81u8, 0u8, 84u8, 0u8, 90u8, 0u8, 93u8, 0u8, 99u8, 0u8, 102u8, 0u8,
+                            77u8, 48u8, 49u8, 77u8, 48u8, 49u8, 98u8, 105u8, 115u8, 77u8, 48u8,
+                            50u8, 77u8, 48u8, 50u8, 98u8, 105u8, 115u8, 77u8, 48u8, 51u8, 77u8,
+                            48u8, 51u8, 98u8, 105u8, 115u8, 77u8, 48u8, 52u8, 77u8, 48u8, 52u8,
+                            98u8, 105u8, 115u8, 77u8, 48u8, 53u8, 77u8, 48u8, 53u8, 98u8, 105u8,
+                            115u8, 77u8, 48u8, 54u8, 77u8, 48u8, 54u8, 98u8, 105u8, 115u8, 77u8,
+                            48u8, 55u8, 77u8, 48u8, 55u8, 98u8, 105u8, 115u8, 77u8, 48u8, 56u8,
+                            77u8, 48u8, 56u8, 98u8, 105u8, 115u8, 77u8, 48u8, 57u8, 77u8, 48u8,
+                            57u8, 98u8, 105u8, 115u8, 77u8, 49u8, 48u8, 77u8, 49u8, 48u8, 98u8,
+                            105u8, 115u8, 77u8, 49u8, 49u8, 77u8, 49u8, 49u8, 98u8, 105u8, 115u8,
+                            77u8, 49u8, 50u8, 77u8, 49u8, 50u8, 98u8, 105u8, 115u8,
+                        ])
+                    },
+                )
+            }),
+        },
+        stand_alone: None,
+    },
+    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Sun"),
+                alloc::borrow::Cow::Borrowed("Mon"),
+                alloc::borrow::Cow::Borrowed("Tue"),
+                alloc::borrow::Cow::Borrowed("Wed"),
+                alloc::borrow::Cow::Borrowed("Thu"),
+                alloc::borrow::Cow::Borrowed("Fri"),
+                alloc::borrow::Cow::Borrowed("Sat"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("S"),
+                alloc::borrow::Cow::Borrowed("M"),
+                alloc::borrow::Cow::Borrowed("T"),
+                alloc::borrow::Cow::Borrowed("W"),
+                alloc::borrow::Cow::Borrowed("T"),
+                alloc::borrow::Cow::Borrowed("F"),
+                alloc::borrow::Cow::Borrowed("S"),
+            ]),
+            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Sun"),
+                alloc::borrow::Cow::Borrowed("Mon"),
+                alloc::borrow::Cow::Borrowed("Tue"),
+                alloc::borrow::Cow::Borrowed("Wed"),
+                alloc::borrow::Cow::Borrowed("Thu"),
+                alloc::borrow::Cow::Borrowed("Fri"),
+                alloc::borrow::Cow::Borrowed("Sat"),
+            ])),
+            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Sun"),
+                alloc::borrow::Cow::Borrowed("Mon"),
+                alloc::borrow::Cow::Borrowed("Tue"),
+                alloc::borrow::Cow::Borrowed("Wed"),
+                alloc::borrow::Cow::Borrowed("Thu"),
+                alloc::borrow::Cow::Borrowed("Fri"),
+                alloc::borrow::Cow::Borrowed("Sat"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    eras: ::icu_datetime::provider::calendar::Eras {
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+        abbr: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+        narrow: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+    },
+    cyclic_years: None,
+};
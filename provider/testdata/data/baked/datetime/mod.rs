@@ -1,11 +1,17 @@
 // @generated
 pub mod buddhist;
+pub mod chinese;
 pub mod coptic;
+pub mod dangi;
 pub mod ethiopic;
 pub mod gregory;
+pub mod hebrew;
 pub mod indian;
+pub mod islamic;
 pub mod japanese;
 pub mod japanext;
+pub mod persian;
+pub mod roc;
 pub mod skeletons_v1;
 pub mod timelengths_v1;
 pub mod timesymbols_v1;
@@ -7,36 +7,67 @@ type DataStruct = [(
 pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
     litemap::LiteMap::from_sorted_store_unchecked(&[
         ("ar-EG-u-ca-buddhist", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ar-EG-u-ca-chinese", AR_EG_U_CA_CHINESE_AR_EG_U_CA_DANGI),
         ("ar-EG-u-ca-coptic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ar-EG-u-ca-dangi", AR_EG_U_CA_CHINESE_AR_EG_U_CA_DANGI),
         ("ar-EG-u-ca-ethiopic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
-        ("ar-EG-u-ca-gregory", AR_EG_U_CA_GREGORY_AR_U_CA_GREGORY),
+        ("ar-EG-u-ca-gregory", AR_EG_U_CA_CHINESE_AR_EG_U_CA_DANGI),
+        ("ar-EG-u-ca-hebrew", AR_EG_U_CA_CHINESE_AR_EG_U_CA_DANGI),
         ("ar-EG-u-ca-indian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ar-EG-u-ca-islamic", AR_EG_U_CA_CHINESE_AR_EG_U_CA_DANGI),
         ("ar-EG-u-ca-japanese", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
         ("ar-EG-u-ca-japanext", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ar-EG-u-ca-persian", AR_EG_U_CA_CHINESE_AR_EG_U_CA_DANGI),
+        ("ar-EG-u-ca-roc", AR_EG_U_CA_CHINESE_AR_EG_U_CA_DANGI),
         ("ar-u-ca-buddhist", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ar-u-ca-chinese", AR_EG_U_CA_CHINESE_AR_EG_U_CA_DANGI),
         ("ar-u-ca-coptic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ar-u-ca-dangi", AR_EG_U_CA_CHINESE_AR_EG_U_CA_DANGI),
         ("ar-u-ca-ethiopic", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
-        ("ar-u-ca-gregory", AR_EG_U_CA_GREGORY_AR_U_CA_GREGORY),
+        ("ar-u-ca-gregory", AR_EG_U_CA_CHINESE_AR_EG_U_CA_DANGI),
+        ("ar-u-ca-hebrew", AR_EG_U_CA_CHINESE_AR_EG_U_CA_DANGI),
         ("ar-u-ca-indian", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ar-u-ca-islamic", AR_EG_U_CA_CHINESE_AR_EG_U_CA_DANGI),
         ("ar-u-ca-japanese", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
         ("ar-u-ca-japanext", AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC),
+        ("ar-u-ca-persian", AR_EG_U_CA_CHINESE_AR_EG_U_CA_DANGI),
+        ("ar-u-ca-roc", AR_EG_U_CA_CHINESE_AR_EG_U_CA_DANGI),
         (
             "bn-u-ca-buddhist",
             BN_U_CA_BUDDHIST_BN_U_CA_COPTIC_BN_U_CA_ETHIOPIC,
         ),
+        (
+            "bn-u-ca-chinese",
+            BN_U_CA_CHINESE_BN_U_CA_DANGI_BN_U_CA_GREGORY,
+        ),
         (
             "bn-u-ca-coptic",
             BN_U_CA_BUDDHIST_BN_U_CA_COPTIC_BN_U_CA_ETHIOPIC,
         ),
+        (
+            "bn-u-ca-dangi",
+            BN_U_CA_CHINESE_BN_U_CA_DANGI_BN_U_CA_GREGORY,
+        ),
         (
             "bn-u-ca-ethiopic",
             BN_U_CA_BUDDHIST_BN_U_CA_COPTIC_BN_U_CA_ETHIOPIC,
         ),
-        ("bn-u-ca-gregory", BN_U_CA_GREGORY),
+        (
+            "bn-u-ca-gregory",
+            BN_U_CA_CHINESE_BN_U_CA_DANGI_BN_U_CA_GREGORY,
+        ),
+        (
+            "bn-u-ca-hebrew",
+            BN_U_CA_CHINESE_BN_U_CA_DANGI_BN_U_CA_GREGORY,
+        ),
         (
             "bn-u-ca-indian",
             BN_U_CA_BUDDHIST_BN_U_CA_COPTIC_BN_U_CA_ETHIOPIC,
         ),
+        (
+            "bn-u-ca-islamic",
+            BN_U_CA_CHINESE_BN_U_CA_DANGI_BN_U_CA_GREGORY,
+        ),
         (
             "bn-u-ca-japanese",
             BN_U_CA_BUDDHIST_BN_U_CA_COPTIC_BN_U_CA_ETHIOPIC,
@@ -45,23 +76,47 @@ pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
             "bn-u-ca-japanext",
             BN_U_CA_BUDDHIST_BN_U_CA_COPTIC_BN_U_CA_ETHIOPIC,
         ),
+        (
+            "bn-u-ca-persian",
+            BN_U_CA_CHINESE_BN_U_CA_DANGI_BN_U_CA_GREGORY,
+        ),
+        ("bn-u-ca-roc", BN_U_CA_CHINESE_BN_U_CA_DANGI_BN_U_CA_GREGORY),
         (
             "ccp-u-ca-buddhist",
             CCP_U_CA_BUDDHIST_CCP_U_CA_COPTIC_CCP_U_CA_ETHIOPIC,
         ),
+        (
+            "ccp-u-ca-chinese",
+            CCP_U_CA_CHINESE_CCP_U_CA_DANGI_CCP_U_CA_GREGORY,
+        ),
         (
             "ccp-u-ca-coptic",
             CCP_U_CA_BUDDHIST_CCP_U_CA_COPTIC_CCP_U_CA_ETHIOPIC,
         ),
+        (
+            "ccp-u-ca-dangi",
+            CCP_U_CA_CHINESE_CCP_U_CA_DANGI_CCP_U_CA_GREGORY,
+        ),
         (
             "ccp-u-ca-ethiopic",
             CCP_U_CA_BUDDHIST_CCP_U_CA_COPTIC_CCP_U_CA_ETHIOPIC,
         ),
-        ("ccp-u-ca-gregory", CCP_U_CA_GREGORY),
+        (
+            "ccp-u-ca-gregory",
+            CCP_U_CA_CHINESE_CCP_U_CA_DANGI_CCP_U_CA_GREGORY,
+        ),
+        (
+            "ccp-u-ca-hebrew",
+            CCP_U_CA_CHINESE_CCP_U_CA_DANGI_CCP_U_CA_GREGORY,
+        ),
         (
             "ccp-u-ca-indian",
             CCP_U_CA_BUDDHIST_CCP_U_CA_COPTIC_CCP_U_CA_ETHIOPIC,
         ),
+        (
+            "ccp-u-ca-islamic",
+            CCP_U_CA_CHINESE_CCP_U_CA_DANGI_CCP_U_CA_GREGORY,
+        ),
         (
             "ccp-u-ca-japanese",
             CCP_U_CA_BUDDHIST_CCP_U_CA_COPTIC_CCP_U_CA_ETHIOPIC,
@@ -70,23 +125,35 @@ pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
             "ccp-u-ca-japanext",
             CCP_U_CA_BUDDHIST_CCP_U_CA_COPTIC_CCP_U_CA_ETHIOPIC,
         ),
+        (
+            "ccp-u-ca-persian",
+            CCP_U_CA_CHINESE_CCP_U_CA_DANGI_CCP_U_CA_GREGORY,
+        ),
+        (
+            "ccp-u-ca-roc",
+            CCP_U_CA_CHINESE_CCP_U_CA_DANGI_CCP_U_CA_GREGORY,
+        ),
         (
             "en-001-u-ca-buddhist",
             EN_001_U_CA_BUDDHIST_EN_001_U_CA_COPTIC,
         ),
+        ("en-001-u-ca-chinese", EN_001_U_CA_CHINESE_EN_001_U_CA_DANGI),
         (
             "en-001-u-ca-coptic",
             EN_001_U_CA_BUDDHIST_EN_001_U_CA_COPTIC,
         ),
+        ("en-001-u-ca-dangi", EN_001_U_CA_CHINESE_EN_001_U_CA_DANGI),
         (
             "en-001-u-ca-ethiopic",
             EN_001_U_CA_BUDDHIST_EN_001_U_CA_COPTIC,
         ),
-        ("en-001-u-ca-gregory", EN_001_U_CA_GREGORY),
+        ("en-001-u-ca-gregory", EN_001_U_CA_CHINESE_EN_001_U_CA_DANGI),
+        ("en-001-u-ca-hebrew", EN_001_U_CA_CHINESE_EN_001_U_CA_DANGI),
         (
             "en-001-u-ca-indian",
             EN_001_U_CA_BUDDHIST_EN_001_U_CA_COPTIC,
         ),
+        ("en-001-u-ca-islamic", EN_001_U_CA_CHINESE_EN_001_U_CA_DANGI),
         (
             "en-001-u-ca-japanese",
             EN_001_U_CA_BUDDHIST_EN_001_U_CA_COPTIC,
@@ -95,30 +162,57 @@ pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
             "en-001-u-ca-japanext",
             EN_001_U_CA_BUDDHIST_EN_001_U_CA_COPTIC,
         ),
+        ("en-001-u-ca-persian", EN_001_U_CA_CHINESE_EN_001_U_CA_DANGI),
+        ("en-001-u-ca-roc", EN_001_U_CA_CHINESE_EN_001_U_CA_DANGI),
         ("en-ZA-u-ca-buddhist", EN_ZA_U_CA_BUDDHIST_EN_ZA_U_CA_COPTIC),
+        ("en-ZA-u-ca-chinese", EN_ZA_U_CA_CHINESE_EN_ZA_U_CA_DANGI),
         ("en-ZA-u-ca-coptic", EN_ZA_U_CA_BUDDHIST_EN_ZA_U_CA_COPTIC),
+        ("en-ZA-u-ca-dangi", EN_ZA_U_CA_CHINESE_EN_ZA_U_CA_DANGI),
         ("en-ZA-u-ca-ethiopic", EN_ZA_U_CA_BUDDHIST_EN_ZA_U_CA_COPTIC),
-        ("en-ZA-u-ca-gregory", EN_ZA_U_CA_GREGORY),
+        ("en-ZA-u-ca-gregory", EN_ZA_U_CA_CHINESE_EN_ZA_U_CA_DANGI),
+        ("en-ZA-u-ca-hebrew", EN_ZA_U_CA_CHINESE_EN_ZA_U_CA_DANGI),
         ("en-ZA-u-ca-indian", EN_ZA_U_CA_BUDDHIST_EN_ZA_U_CA_COPTIC),
+        ("en-ZA-u-ca-islamic", EN_ZA_U_CA_CHINESE_EN_ZA_U_CA_DANGI),
         ("en-ZA-u-ca-japanese", EN_ZA_U_CA_BUDDHIST_EN_ZA_U_CA_COPTIC),
         ("en-ZA-u-ca-japanext", EN_ZA_U_CA_BUDDHIST_EN_ZA_U_CA_COPTIC),
+        ("en-ZA-u-ca-persian", EN_ZA_U_CA_CHINESE_EN_ZA_U_CA_DANGI),
+        ("en-ZA-u-ca-roc", EN_ZA_U_CA_CHINESE_EN_ZA_U_CA_DANGI),
         (
             "en-u-ca-buddhist",
             EN_U_CA_BUDDHIST_EN_U_CA_COPTIC_EN_U_CA_ETHIOPIC,
         ),
+        (
+            "en-u-ca-chinese",
+            EN_U_CA_CHINESE_EN_U_CA_DANGI_EN_U_CA_GREGORY,
+        ),
         (
             "en-u-ca-coptic",
             EN_U_CA_BUDDHIST_EN_U_CA_COPTIC_EN_U_CA_ETHIOPIC,
         ),
+        (
+            "en-u-ca-dangi",
+            EN_U_CA_CHINESE_EN_U_CA_DANGI_EN_U_CA_GREGORY,
+        ),
         (
             "en-u-ca-ethiopic",
             EN_U_CA_BUDDHIST_EN_U_CA_COPTIC_EN_U_CA_ETHIOPIC,
         ),
-        ("en-u-ca-gregory", EN_U_CA_GREGORY),
+        (
+            "en-u-ca-gregory",
+            EN_U_CA_CHINESE_EN_U_CA_DANGI_EN_U_CA_GREGORY,
+        ),
+        (
+            "en-u-ca-hebrew",
+            EN_U_CA_CHINESE_EN_U_CA_DANGI_EN_U_CA_GREGORY,
+        ),
         (
             "en-u-ca-indian",
             EN_U_CA_BUDDHIST_EN_U_CA_COPTIC_EN_U_CA_ETHIOPIC,
         ),
+        (
+            "en-u-ca-islamic",
+            EN_U_CA_CHINESE_EN_U_CA_DANGI_EN_U_CA_GREGORY,
+        ),
         (
             "en-u-ca-japanese",
             EN_U_CA_BUDDHIST_EN_U_CA_COPTIC_EN_U_CA_ETHIOPIC,
@@ -127,30 +221,60 @@ pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
             "en-u-ca-japanext",
             EN_U_CA_BUDDHIST_EN_U_CA_COPTIC_EN_U_CA_ETHIOPIC,
         ),
+        (
+            "en-u-ca-persian",
+            EN_U_CA_CHINESE_EN_U_CA_DANGI_EN_U_CA_GREGORY,
+        ),
+        ("en-u-ca-roc", EN_U_CA_CHINESE_EN_U_CA_DANGI_EN_U_CA_GREGORY),
         ("es-AR-u-ca-buddhist", ES_AR_U_CA_BUDDHIST_ES_AR_U_CA_COPTIC),
+        ("es-AR-u-ca-chinese", ES_AR_U_CA_CHINESE_ES_AR_U_CA_DANGI),
         ("es-AR-u-ca-coptic", ES_AR_U_CA_BUDDHIST_ES_AR_U_CA_COPTIC),
+        ("es-AR-u-ca-dangi", ES_AR_U_CA_CHINESE_ES_AR_U_CA_DANGI),
         ("es-AR-u-ca-ethiopic", ES_AR_U_CA_BUDDHIST_ES_AR_U_CA_COPTIC),
-        ("es-AR-u-ca-gregory", ES_AR_U_CA_GREGORY),
+        ("es-AR-u-ca-gregory", ES_AR_U_CA_CHINESE_ES_AR_U_CA_DANGI),
+        ("es-AR-u-ca-hebrew", ES_AR_U_CA_CHINESE_ES_AR_U_CA_DANGI),
         ("es-AR-u-ca-indian", ES_AR_U_CA_BUDDHIST_ES_AR_U_CA_COPTIC),
+        ("es-AR-u-ca-islamic", ES_AR_U_CA_CHINESE_ES_AR_U_CA_DANGI),
         ("es-AR-u-ca-japanese", ES_AR_U_CA_BUDDHIST_ES_AR_U_CA_COPTIC),
         ("es-AR-u-ca-japanext", ES_AR_U_CA_BUDDHIST_ES_AR_U_CA_COPTIC),
+        ("es-AR-u-ca-persian", ES_AR_U_CA_CHINESE_ES_AR_U_CA_DANGI),
+        ("es-AR-u-ca-roc", ES_AR_U_CA_CHINESE_ES_AR_U_CA_DANGI),
         (
             "es-u-ca-buddhist",
             ES_U_CA_BUDDHIST_ES_U_CA_COPTIC_ES_U_CA_ETHIOPIC,
         ),
+        (
+            "es-u-ca-chinese",
+            ES_U_CA_CHINESE_ES_U_CA_DANGI_ES_U_CA_GREGORY,
+        ),
         (
             "es-u-ca-coptic",
             ES_U_CA_BUDDHIST_ES_U_CA_COPTIC_ES_U_CA_ETHIOPIC,
         ),
+        (
+            "es-u-ca-dangi",
+            ES_U_CA_CHINESE_ES_U_CA_DANGI_ES_U_CA_GREGORY,
+        ),
         (
             "es-u-ca-ethiopic",
             ES_U_CA_BUDDHIST_ES_U_CA_COPTIC_ES_U_CA_ETHIOPIC,
         ),
-        ("es-u-ca-gregory", ES_U_CA_GREGORY),
+        (
+            "es-u-ca-gregory",
+            ES_U_CA_CHINESE_ES_U_CA_DANGI_ES_U_CA_GREGORY,
+        ),
+        (
+            "es-u-ca-hebrew",
+            ES_U_CA_CHINESE_ES_U_CA_DANGI_ES_U_CA_GREGORY,
+        ),
         (
             "es-u-ca-indian",
             ES_U_CA_BUDDHIST_ES_U_CA_COPTIC_ES_U_CA_ETHIOPIC,
         ),
+        (
+            "es-u-ca-islamic",
+            ES_U_CA_CHINESE_ES_U_CA_DANGI_ES_U_CA_GREGORY,
+        ),
         (
             "es-u-ca-japanese",
             ES_U_CA_BUDDHIST_ES_U_CA_COPTIC_ES_U_CA_ETHIOPIC,
@@ -159,23 +283,47 @@ pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
             "es-u-ca-japanext",
             ES_U_CA_BUDDHIST_ES_U_CA_COPTIC_ES_U_CA_ETHIOPIC,
         ),
+        (
+            "es-u-ca-persian",
+            ES_U_CA_CHINESE_ES_U_CA_DANGI_ES_U_CA_GREGORY,
+        ),
+        ("es-u-ca-roc", ES_U_CA_CHINESE_ES_U_CA_DANGI_ES_U_CA_GREGORY),
         (
             "fil-u-ca-buddhist",
             FIL_U_CA_BUDDHIST_FIL_U_CA_COPTIC_FIL_U_CA_ETHIOPIC,
         ),
+        (
+            "fil-u-ca-chinese",
+            FIL_U_CA_CHINESE_FIL_U_CA_DANGI_FIL_U_CA_GREGORY,
+        ),
         (
             "fil-u-ca-coptic",
             FIL_U_CA_BUDDHIST_FIL_U_CA_COPTIC_FIL_U_CA_ETHIOPIC,
         ),
+        (
+            "fil-u-ca-dangi",
+            FIL_U_CA_CHINESE_FIL_U_CA_DANGI_FIL_U_CA_GREGORY,
+        ),
         (
             "fil-u-ca-ethiopic",
             FIL_U_CA_BUDDHIST_FIL_U_CA_COPTIC_FIL_U_CA_ETHIOPIC,
         ),
-        ("fil-u-ca-gregory", FIL_U_CA_GREGORY),
+        (
+            "fil-u-ca-gregory",
+            FIL_U_CA_CHINESE_FIL_U_CA_DANGI_FIL_U_CA_GREGORY,
+        ),
+        (
+            "fil-u-ca-hebrew",
+            FIL_U_CA_CHINESE_FIL_U_CA_DANGI_FIL_U_CA_GREGORY,
+        ),
         (
             "fil-u-ca-indian",
             FIL_U_CA_BUDDHIST_FIL_U_CA_COPTIC_FIL_U_CA_ETHIOPIC,
         ),
+        (
+            "fil-u-ca-islamic",
+            FIL_U_CA_CHINESE_FIL_U_CA_DANGI_FIL_U_CA_GREGORY,
+        ),
         (
             "fil-u-ca-japanese",
             FIL_U_CA_BUDDHIST_FIL_U_CA_COPTIC_FIL_U_CA_ETHIOPIC,
@@ -184,55 +332,130 @@ pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
             "fil-u-ca-japanext",
             FIL_U_CA_BUDDHIST_FIL_U_CA_COPTIC_FIL_U_CA_ETHIOPIC,
         ),
+        (
+            "fil-u-ca-persian",
+            FIL_U_CA_CHINESE_FIL_U_CA_DANGI_FIL_U_CA_GREGORY,
+        ),
+        (
+            "fil-u-ca-roc",
+            FIL_U_CA_CHINESE_FIL_U_CA_DANGI_FIL_U_CA_GREGORY,
+        ),
         ("fr-u-ca-buddhist", FR_U_CA_BUDDHIST),
+        (
+            "fr-u-ca-chinese",
+            FR_U_CA_CHINESE_FR_U_CA_DANGI_FR_U_CA_GREGORY,
+        ),
         (
             "fr-u-ca-coptic",
             FR_U_CA_COPTIC_FR_U_CA_ETHIOPIC_FR_U_CA_INDIAN,
         ),
+        (
+            "fr-u-ca-dangi",
+            FR_U_CA_CHINESE_FR_U_CA_DANGI_FR_U_CA_GREGORY,
+        ),
         (
             "fr-u-ca-ethiopic",
             FR_U_CA_COPTIC_FR_U_CA_ETHIOPIC_FR_U_CA_INDIAN,
         ),
-        ("fr-u-ca-gregory", FR_U_CA_GREGORY),
+        (
+            "fr-u-ca-gregory",
+            FR_U_CA_CHINESE_FR_U_CA_DANGI_FR_U_CA_GREGORY,
+        ),
+        (
+            "fr-u-ca-hebrew",
+            FR_U_CA_CHINESE_FR_U_CA_DANGI_FR_U_CA_GREGORY,
+        ),
         (
             "fr-u-ca-indian",
             FR_U_CA_COPTIC_FR_U_CA_ETHIOPIC_FR_U_CA_INDIAN,
         ),
+        (
+            "fr-u-ca-islamic",
+            FR_U_CA_CHINESE_FR_U_CA_DANGI_FR_U_CA_GREGORY,
+        ),
         ("fr-u-ca-japanese", FR_U_CA_JAPANESE_FR_U_CA_JAPANEXT),
         ("fr-u-ca-japanext", FR_U_CA_JAPANESE_FR_U_CA_JAPANEXT),
+        (
+            "fr-u-ca-persian",
+            FR_U_CA_CHINESE_FR_U_CA_DANGI_FR_U_CA_GREGORY,
+        ),
+        ("fr-u-ca-roc", FR_U_CA_CHINESE_FR_U_CA_DANGI_FR_U_CA_GREGORY),
         ("ja-u-ca-buddhist", JA_U_CA_BUDDHIST),
+        (
+            "ja-u-ca-chinese",
+            JA_U_CA_CHINESE_JA_U_CA_DANGI_JA_U_CA_GREGORY,
+        ),
         (
             "ja-u-ca-coptic",
             JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN,
         ),
+        (
+            "ja-u-ca-dangi",
+            JA_U_CA_CHINESE_JA_U_CA_DANGI_JA_U_CA_GREGORY,
+        ),
         (
             "ja-u-ca-ethiopic",
             JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN,
         ),
-        ("ja-u-ca-gregory", JA_U_CA_GREGORY),
+        (
+            "ja-u-ca-gregory",
+            JA_U_CA_CHINESE_JA_U_CA_DANGI_JA_U_CA_GREGORY,
+        ),
+        (
+            "ja-u-ca-hebrew",
+            JA_U_CA_CHINESE_JA_U_CA_DANGI_JA_U_CA_GREGORY,
+        ),
         (
             "ja-u-ca-indian",
             JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN,
         ),
+        (
+            "ja-u-ca-islamic",
+            JA_U_CA_CHINESE_JA_U_CA_DANGI_JA_U_CA_GREGORY,
+        ),
         ("ja-u-ca-japanese", JA_U_CA_JAPANESE_JA_U_CA_JAPANEXT),
         ("ja-u-ca-japanext", JA_U_CA_JAPANESE_JA_U_CA_JAPANEXT),
+        (
+            "ja-u-ca-persian",
+            JA_U_CA_CHINESE_JA_U_CA_DANGI_JA_U_CA_GREGORY,
+        ),
+        ("ja-u-ca-roc", JA_U_CA_CHINESE_JA_U_CA_DANGI_JA_U_CA_GREGORY),
         (
             "ru-u-ca-buddhist",
             RU_U_CA_BUDDHIST_RU_U_CA_COPTIC_RU_U_CA_ETHIOPIC,
         ),
+        (
+            "ru-u-ca-chinese",
+            RU_U_CA_CHINESE_RU_U_CA_DANGI_RU_U_CA_GREGORY,
+        ),
         (
             "ru-u-ca-coptic",
             RU_U_CA_BUDDHIST_RU_U_CA_COPTIC_RU_U_CA_ETHIOPIC,
         ),
+        (
+            "ru-u-ca-dangi",
+            RU_U_CA_CHINESE_RU_U_CA_DANGI_RU_U_CA_GREGORY,
+        ),
         (
             "ru-u-ca-ethiopic",
             RU_U_CA_BUDDHIST_RU_U_CA_COPTIC_RU_U_CA_ETHIOPIC,
         ),
-        ("ru-u-ca-gregory", RU_U_CA_GREGORY),
+        (
+            "ru-u-ca-gregory",
+            RU_U_CA_CHINESE_RU_U_CA_DANGI_RU_U_CA_GREGORY,
+        ),
+        (
+            "ru-u-ca-hebrew",
+            RU_U_CA_CHINESE_RU_U_CA_DANGI_RU_U_CA_GREGORY,
+        ),
         (
             "ru-u-ca-indian",
             RU_U_CA_BUDDHIST_RU_U_CA_COPTIC_RU_U_CA_ETHIOPIC,
         ),
+        (
+            "ru-u-ca-islamic",
+            RU_U_CA_CHINESE_RU_U_CA_DANGI_RU_U_CA_GREGORY,
+        ),
         (
             "ru-u-ca-japanese",
             RU_U_CA_BUDDHIST_RU_U_CA_COPTIC_RU_U_CA_ETHIOPIC,
@@ -241,23 +464,47 @@ pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
             "ru-u-ca-japanext",
             RU_U_CA_BUDDHIST_RU_U_CA_COPTIC_RU_U_CA_ETHIOPIC,
         ),
+        (
+            "ru-u-ca-persian",
+            RU_U_CA_CHINESE_RU_U_CA_DANGI_RU_U_CA_GREGORY,
+        ),
+        ("ru-u-ca-roc", RU_U_CA_CHINESE_RU_U_CA_DANGI_RU_U_CA_GREGORY),
         (
             "sr-Cyrl-u-ca-buddhist",
             SR_CYRL_U_CA_BUDDHIST_SR_CYRL_U_CA_COPTIC,
         ),
+        (
+            "sr-Cyrl-u-ca-chinese",
+            SR_CYRL_U_CA_CHINESE_SR_CYRL_U_CA_DANGI,
+        ),
         (
             "sr-Cyrl-u-ca-coptic",
             SR_CYRL_U_CA_BUDDHIST_SR_CYRL_U_CA_COPTIC,
         ),
+        (
+            "sr-Cyrl-u-ca-dangi",
+            SR_CYRL_U_CA_CHINESE_SR_CYRL_U_CA_DANGI,
+        ),
         (
             "sr-Cyrl-u-ca-ethiopic",
             SR_CYRL_U_CA_BUDDHIST_SR_CYRL_U_CA_COPTIC,
         ),
-        ("sr-Cyrl-u-ca-gregory", SR_CYRL_U_CA_GREGORY_SR_U_CA_GREGORY),
+        (
+            "sr-Cyrl-u-ca-gregory",
+            SR_CYRL_U_CA_CHINESE_SR_CYRL_U_CA_DANGI,
+        ),
+        (
+            "sr-Cyrl-u-ca-hebrew",
+            SR_CYRL_U_CA_CHINESE_SR_CYRL_U_CA_DANGI,
+        ),
         (
             "sr-Cyrl-u-ca-indian",
             SR_CYRL_U_CA_BUDDHIST_SR_CYRL_U_CA_COPTIC,
         ),
+        (
+            "sr-Cyrl-u-ca-islamic",
+            SR_CYRL_U_CA_CHINESE_SR_CYRL_U_CA_DANGI,
+        ),
         (
             "sr-Cyrl-u-ca-japanese",
             SR_CYRL_U_CA_BUDDHIST_SR_CYRL_U_CA_COPTIC,
@@ -266,23 +513,47 @@ pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
             "sr-Cyrl-u-ca-japanext",
             SR_CYRL_U_CA_BUDDHIST_SR_CYRL_U_CA_COPTIC,
         ),
+        (
+            "sr-Cyrl-u-ca-persian",
+            SR_CYRL_U_CA_CHINESE_SR_CYRL_U_CA_DANGI,
+        ),
+        ("sr-Cyrl-u-ca-roc", SR_CYRL_U_CA_CHINESE_SR_CYRL_U_CA_DANGI),
         (
             "sr-Latn-u-ca-buddhist",
             SR_CYRL_U_CA_BUDDHIST_SR_CYRL_U_CA_COPTIC,
         ),
+        (
+            "sr-Latn-u-ca-chinese",
+            SR_LATN_U_CA_CHINESE_SR_LATN_U_CA_DANGI,
+        ),
         (
             "sr-Latn-u-ca-coptic",
             SR_CYRL_U_CA_BUDDHIST_SR_CYRL_U_CA_COPTIC,
         ),
+        (
+            "sr-Latn-u-ca-dangi",
+            SR_LATN_U_CA_CHINESE_SR_LATN_U_CA_DANGI,
+        ),
         (
             "sr-Latn-u-ca-ethiopic",
             SR_CYRL_U_CA_BUDDHIST_SR_CYRL_U_CA_COPTIC,
         ),
-        ("sr-Latn-u-ca-gregory", SR_LATN_U_CA_GREGORY),
+        (
+            "sr-Latn-u-ca-gregory",
+            SR_LATN_U_CA_CHINESE_SR_LATN_U_CA_DANGI,
+        ),
+        (
+            "sr-Latn-u-ca-hebrew",
+            SR_LATN_U_CA_CHINESE_SR_LATN_U_CA_DANGI,
+        ),
         (
             "sr-Latn-u-ca-indian",
             SR_CYRL_U_CA_BUDDHIST_SR_CYRL_U_CA_COPTIC,
         ),
+        (
+            "sr-Latn-u-ca-islamic",
+            SR_LATN_U_CA_CHINESE_SR_LATN_U_CA_DANGI,
+        ),
         (
             "sr-Latn-u-ca-japanese",
             SR_CYRL_U_CA_BUDDHIST_SR_CYRL_U_CA_COPTIC,
@@ -291,17 +562,26 @@ pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
             "sr-Latn-u-ca-japanext",
             SR_CYRL_U_CA_BUDDHIST_SR_CYRL_U_CA_COPTIC,
         ),
+        (
+            "sr-Latn-u-ca-persian",
+            SR_LATN_U_CA_CHINESE_SR_LATN_U_CA_DANGI,
+        ),
+        ("sr-Latn-u-ca-roc", SR_LATN_U_CA_CHINESE_SR_LATN_U_CA_DANGI),
         (
             "sr-u-ca-buddhist",
             SR_CYRL_U_CA_BUDDHIST_SR_CYRL_U_CA_COPTIC,
         ),
+        ("sr-u-ca-chinese", SR_CYRL_U_CA_CHINESE_SR_CYRL_U_CA_DANGI),
         ("sr-u-ca-coptic", SR_CYRL_U_CA_BUDDHIST_SR_CYRL_U_CA_COPTIC),
+        ("sr-u-ca-dangi", SR_CYRL_U_CA_CHINESE_SR_CYRL_U_CA_DANGI),
         (
             "sr-u-ca-ethiopic",
             SR_CYRL_U_CA_BUDDHIST_SR_CYRL_U_CA_COPTIC,
         ),
-        ("sr-u-ca-gregory", SR_CYRL_U_CA_GREGORY_SR_U_CA_GREGORY),
+        ("sr-u-ca-gregory", SR_CYRL_U_CA_CHINESE_SR_CYRL_U_CA_DANGI),
+        ("sr-u-ca-hebrew", SR_CYRL_U_CA_CHINESE_SR_CYRL_U_CA_DANGI),
         ("sr-u-ca-indian", SR_CYRL_U_CA_BUDDHIST_SR_CYRL_U_CA_COPTIC),
+        ("sr-u-ca-islamic", SR_CYRL_U_CA_CHINESE_SR_CYRL_U_CA_DANGI),
         (
             "sr-u-ca-japanese",
             SR_CYRL_U_CA_BUDDHIST_SR_CYRL_U_CA_COPTIC,
@@ -310,39 +590,84 @@ pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
             "sr-u-ca-japanext",
             SR_CYRL_U_CA_BUDDHIST_SR_CYRL_U_CA_COPTIC,
         ),
+        ("sr-u-ca-persian", SR_CYRL_U_CA_CHINESE_SR_CYRL_U_CA_DANGI),
+        ("sr-u-ca-roc", SR_CYRL_U_CA_CHINESE_SR_CYRL_U_CA_DANGI),
         ("th-u-ca-buddhist", TH_U_CA_BUDDHIST),
+        (
+            "th-u-ca-chinese",
+            TH_U_CA_CHINESE_TH_U_CA_DANGI_TH_U_CA_GREGORY,
+        ),
         (
             "th-u-ca-coptic",
             TH_U_CA_COPTIC_TH_U_CA_ETHIOPIC_TH_U_CA_INDIAN,
         ),
+        (
+            "th-u-ca-dangi",
+            TH_U_CA_CHINESE_TH_U_CA_DANGI_TH_U_CA_GREGORY,
+        ),
         (
             "th-u-ca-ethiopic",
             TH_U_CA_COPTIC_TH_U_CA_ETHIOPIC_TH_U_CA_INDIAN,
         ),
-        ("th-u-ca-gregory", TH_U_CA_GREGORY),
+        (
+            "th-u-ca-gregory",
+            TH_U_CA_CHINESE_TH_U_CA_DANGI_TH_U_CA_GREGORY,
+        ),
+        (
+            "th-u-ca-hebrew",
+            TH_U_CA_CHINESE_TH_U_CA_DANGI_TH_U_CA_GREGORY,
+        ),
         (
             "th-u-ca-indian",
             TH_U_CA_COPTIC_TH_U_CA_ETHIOPIC_TH_U_CA_INDIAN,
         ),
+        (
+            "th-u-ca-islamic",
+            TH_U_CA_CHINESE_TH_U_CA_DANGI_TH_U_CA_GREGORY,
+        ),
         ("th-u-ca-japanese", TH_U_CA_JAPANESE_TH_U_CA_JAPANEXT),
         ("th-u-ca-japanext", TH_U_CA_JAPANESE_TH_U_CA_JAPANEXT),
+        (
+            "th-u-ca-persian",
+            TH_U_CA_CHINESE_TH_U_CA_DANGI_TH_U_CA_GREGORY,
+        ),
+        ("th-u-ca-roc", TH_U_CA_CHINESE_TH_U_CA_DANGI_TH_U_CA_GREGORY),
         (
             "tr-u-ca-buddhist",
             TR_U_CA_BUDDHIST_TR_U_CA_COPTIC_TR_U_CA_ETHIOPIC,
         ),
+        (
+            "tr-u-ca-chinese",
+            TR_U_CA_CHINESE_TR_U_CA_DANGI_TR_U_CA_GREGORY,
+        ),
         (
             "tr-u-ca-coptic",
             TR_U_CA_BUDDHIST_TR_U_CA_COPTIC_TR_U_CA_ETHIOPIC,
         ),
+        (
+            "tr-u-ca-dangi",
+            TR_U_CA_CHINESE_TR_U_CA_DANGI_TR_U_CA_GREGORY,
+        ),
         (
             "tr-u-ca-ethiopic",
             TR_U_CA_BUDDHIST_TR_U_CA_COPTIC_TR_U_CA_ETHIOPIC,
         ),
-        ("tr-u-ca-gregory", TR_U_CA_GREGORY),
+        (
+            "tr-u-ca-gregory",
+            TR_U_CA_CHINESE_TR_U_CA_DANGI_TR_U_CA_GREGORY,
+        ),
+        (
+            "tr-u-ca-hebrew",
+            TR_U_CA_CHINESE_TR_U_CA_DANGI_TR_U_CA_GREGORY,
+        ),
         (
             "tr-u-ca-indian",
             TR_U_CA_BUDDHIST_TR_U_CA_COPTIC_TR_U_CA_ETHIOPIC,
         ),
+        (
+            "tr-u-ca-islamic",
+            TR_U_CA_CHINESE_TR_U_CA_DANGI_TR_U_CA_GREGORY,
+        ),
         (
             "tr-u-ca-japanese",
             TR_U_CA_BUDDHIST_TR_U_CA_COPTIC_TR_U_CA_ETHIOPIC,
@@ -351,23 +676,47 @@ pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
             "tr-u-ca-japanext",
             TR_U_CA_BUDDHIST_TR_U_CA_COPTIC_TR_U_CA_ETHIOPIC,
         ),
+        (
+            "tr-u-ca-persian",
+            TR_U_CA_CHINESE_TR_U_CA_DANGI_TR_U_CA_GREGORY,
+        ),
+        ("tr-u-ca-roc", TR_U_CA_CHINESE_TR_U_CA_DANGI_TR_U_CA_GREGORY),
         (
             "und-u-ca-buddhist",
             UND_U_CA_BUDDHIST_UND_U_CA_COPTIC_UND_U_CA_ETHIOPIC,
         ),
+        (
+            "und-u-ca-chinese",
+            UND_U_CA_CHINESE_UND_U_CA_DANGI_UND_U_CA_GREGORY,
+        ),
         (
             "und-u-ca-coptic",
             UND_U_CA_BUDDHIST_UND_U_CA_COPTIC_UND_U_CA_ETHIOPIC,
         ),
+        (
+            "und-u-ca-dangi",
+            UND_U_CA_CHINESE_UND_U_CA_DANGI_UND_U_CA_GREGORY,
+        ),
         (
             "und-u-ca-ethiopic",
             UND_U_CA_BUDDHIST_UND_U_CA_COPTIC_UND_U_CA_ETHIOPIC,
         ),
-        ("und-u-ca-gregory", UND_U_CA_GREGORY),
+        (
+            "und-u-ca-gregory",
+            UND_U_CA_CHINESE_UND_U_CA_DANGI_UND_U_CA_GREGORY,
+        ),
+        (
+            "und-u-ca-hebrew",
+            UND_U_CA_CHINESE_UND_U_CA_DANGI_UND_U_CA_GREGORY,
+        ),
         (
             "und-u-ca-indian",
             UND_U_CA_BUDDHIST_UND_U_CA_COPTIC_UND_U_CA_ETHIOPIC,
         ),
+        (
+            "und-u-ca-islamic",
+            UND_U_CA_CHINESE_UND_U_CA_DANGI_UND_U_CA_GREGORY,
+        ),
         (
             "und-u-ca-japanese",
             UND_U_CA_BUDDHIST_UND_U_CA_COPTIC_UND_U_CA_ETHIOPIC,
@@ -376,6 +725,14 @@ pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
             "und-u-ca-japanext",
             UND_U_CA_BUDDHIST_UND_U_CA_COPTIC_UND_U_CA_ETHIOPIC,
         ),
+        (
+            "und-u-ca-persian",
+            UND_U_CA_CHINESE_UND_U_CA_DANGI_UND_U_CA_GREGORY,
+        ),
+        (
+            "und-u-ca-roc",
+            UND_U_CA_CHINESE_UND_U_CA_DANGI_UND_U_CA_GREGORY,
+        ),
     ]);
 static AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC: &DataStruct = &[
     (
@@ -1343,7 +1700,7 @@ static AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC: &DataStruct = &[
         ),
     ),
 ];
-static AR_EG_U_CA_GREGORY_AR_U_CA_GREGORY: &DataStruct = &[
+static AR_EG_U_CA_CHINESE_AR_EG_U_CA_DANGI: &DataStruct = &[
     (
         &[
             ::icu_datetime::fields::Field {
@@ -3611,7 +3968,7 @@ static BN_U_CA_BUDDHIST_BN_U_CA_COPTIC_BN_U_CA_ETHIOPIC: &DataStruct = &[
         ),
     ),
 ];
-static BN_U_CA_GREGORY: &DataStruct = &[
+static BN_U_CA_CHINESE_BN_U_CA_DANGI_BN_U_CA_GREGORY: &DataStruct = &[
     (
         &[
             ::icu_datetime::fields::Field {
@@ -5879,7 +6236,7 @@ static CCP_U_CA_BUDDHIST_CCP_U_CA_COPTIC_CCP_U_CA_ETHIOPIC: &DataStruct = &[
         ),
     ),
 ];
-static CCP_U_CA_GREGORY: &DataStruct = &[
+static CCP_U_CA_CHINESE_CCP_U_CA_DANGI_CCP_U_CA_GREGORY: &DataStruct = &[
     (
         &[
             ::icu_datetime::fields::Field {
@@ -8062,7 +8419,7 @@ static EN_001_U_CA_BUDDHIST_EN_001_U_CA_COPTIC: &DataStruct = &[
         ),
     ),
 ];
-static EN_001_U_CA_GREGORY: &DataStruct = &[
+static EN_001_U_CA_CHINESE_EN_001_U_CA_DANGI: &DataStruct = &[
     (
         &[
             ::icu_datetime::fields::Field {
@@ -10182,7 +10539,7 @@ static EN_U_CA_BUDDHIST_EN_U_CA_COPTIC_EN_U_CA_ETHIOPIC: &DataStruct = &[
         ),
     ),
 ];
-static EN_U_CA_GREGORY: &DataStruct = &[
+static EN_U_CA_CHINESE_EN_U_CA_DANGI_EN_U_CA_GREGORY: &DataStruct = &[
     (
         &[
             ::icu_datetime::fields::Field {
@@ -12277,7 +12634,7 @@ static EN_ZA_U_CA_BUDDHIST_EN_ZA_U_CA_COPTIC: &DataStruct = &[
         ),
     ),
 ];
-static EN_ZA_U_CA_GREGORY: &DataStruct = &[
+static EN_ZA_U_CA_CHINESE_EN_ZA_U_CA_DANGI: &DataStruct = &[
     (
         &[
             ::icu_datetime::fields::Field {
@@ -14667,7 +15024,7 @@ static ES_AR_U_CA_BUDDHIST_ES_AR_U_CA_COPTIC: &DataStruct = &[
         ),
     ),
 ];
-static ES_AR_U_CA_GREGORY: &DataStruct = &[
+static ES_AR_U_CA_CHINESE_ES_AR_U_CA_DANGI: &DataStruct = &[
     (
         &[
             ::icu_datetime::fields::Field {
@@ -17392,7 +17749,7 @@ static ES_U_CA_BUDDHIST_ES_U_CA_COPTIC_ES_U_CA_ETHIOPIC: &DataStruct = &[
         ),
     ),
 ];
-static ES_U_CA_GREGORY: &DataStruct = &[
+static ES_U_CA_CHINESE_ES_U_CA_DANGI_ES_U_CA_GREGORY: &DataStruct = &[
     (
         &[
             ::icu_datetime::fields::Field {
@@ -19925,7 +20282,7 @@ static FIL_U_CA_BUDDHIST_FIL_U_CA_COPTIC_FIL_U_CA_ETHIOPIC: &DataStruct = &[
         ),
     ),
 ];
-static FIL_U_CA_GREGORY: &DataStruct = &[
+static FIL_U_CA_CHINESE_FIL_U_CA_DANGI_FIL_U_CA_GREGORY: &DataStruct = &[
     (
         &[
             ::icu_datetime::fields::Field {
@@ -22119,7 +22476,7 @@ static FR_U_CA_BUDDHIST: &DataStruct = &[
         ),
     ),
 ];
-static FR_U_CA_COPTIC_FR_U_CA_ETHIOPIC_FR_U_CA_INDIAN: &DataStruct = &[
+static FR_U_CA_CHINESE_FR_U_CA_DANGI_FR_U_CA_GREGORY: &DataStruct = &[
     (
         &[
             ::icu_datetime::fields::Field {
@@ -22302,29 +22659,7 @@ static FR_U_CA_COPTIC_FR_U_CA_ETHIOPIC_FR_U_CA_INDIAN: &DataStruct = &[
         }],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe {
-                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 1u8,
-                    ])
-                },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
-            },
-        ),
-    ),
-    (
-        &[::icu_datetime::fields::Field {
-            symbol: ::icu_datetime::fields::FieldSymbol::Year(
-                ::icu_datetime::fields::Year::Calendar,
-            ),
-            length: ::icu_datetime::fields::FieldLength::Wide,
-        }],
-        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
-            ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe {
-                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 1u8,
-                    ])
-                },
+                items: unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[128u8, 16u8, 1u8]) },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
             },
         ),
@@ -22335,7 +22670,7 @@ static FR_U_CA_COPTIC_FR_U_CA_ETHIOPIC_FR_U_CA_INDIAN: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
+                length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -22348,8 +22683,7 @@ static FR_U_CA_COPTIC_FR_U_CA_ETHIOPIC_FR_U_CA_INDIAN: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 32u8, 2u8, 0u8, 0u8, 47u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
-                        0u8, 5u8,
+                        128u8, 32u8, 2u8, 0u8, 0u8, 47u8, 128u8, 16u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -22362,7 +22696,7 @@ static FR_U_CA_COPTIC_FR_U_CA_ETHIOPIC_FR_U_CA_INDIAN: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
+                length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -22382,7 +22716,7 @@ static FR_U_CA_COPTIC_FR_U_CA_ETHIOPIC_FR_U_CA_INDIAN: &DataStruct = &[
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
                         128u8, 64u8, 2u8, 0u8, 0u8, 47u8, 128u8, 32u8, 2u8, 0u8, 0u8, 47u8, 128u8,
-                        16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 5u8,
+                        16u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -22395,7 +22729,7 @@ static FR_U_CA_COPTIC_FR_U_CA_ETHIOPIC_FR_U_CA_INDIAN: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
+                length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -22421,8 +22755,7 @@ static FR_U_CA_COPTIC_FR_U_CA_ETHIOPIC_FR_U_CA_INDIAN: &DataStruct = &[
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
                         128u8, 80u8, 1u8, 0u8, 0u8, 32u8, 128u8, 64u8, 2u8, 0u8, 0u8, 47u8, 128u8,
-                        32u8, 2u8, 0u8, 0u8, 47u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8,
-                        5u8,
+                        32u8, 2u8, 0u8, 0u8, 47u8, 128u8, 16u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -22435,7 +22768,7 @@ static FR_U_CA_COPTIC_FR_U_CA_ETHIOPIC_FR_U_CA_INDIAN: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
+                length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -22448,8 +22781,7 @@ static FR_U_CA_COPTIC_FR_U_CA_ETHIOPIC_FR_U_CA_INDIAN: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 32u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
-                        0u8, 1u8,
+                        128u8, 32u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -22462,7 +22794,7 @@ static FR_U_CA_COPTIC_FR_U_CA_ETHIOPIC_FR_U_CA_INDIAN: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
+                length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -22482,7 +22814,7 @@ static FR_U_CA_COPTIC_FR_U_CA_ETHIOPIC_FR_U_CA_INDIAN: &DataStruct = &[
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
                         128u8, 64u8, 1u8, 0u8, 0u8, 32u8, 128u8, 32u8, 3u8, 0u8, 0u8, 32u8, 128u8,
-                        16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 1u8,
+                        16u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -22495,7 +22827,7 @@ static FR_U_CA_COPTIC_FR_U_CA_ETHIOPIC_FR_U_CA_INDIAN: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
+                length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -22521,8 +22853,7 @@ static FR_U_CA_COPTIC_FR_U_CA_ETHIOPIC_FR_U_CA_INDIAN: &DataStruct = &[
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
                         128u8, 80u8, 1u8, 0u8, 0u8, 32u8, 128u8, 64u8, 1u8, 0u8, 0u8, 32u8, 128u8,
-                        32u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8,
-                        1u8,
+                        32u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -22535,7 +22866,7 @@ static FR_U_CA_COPTIC_FR_U_CA_ETHIOPIC_FR_U_CA_INDIAN: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
+                length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -22548,8 +22879,36 @@ static FR_U_CA_COPTIC_FR_U_CA_ETHIOPIC_FR_U_CA_INDIAN: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 32u8, 4u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
-                        0u8, 1u8,
+                        128u8, 32u8, 4u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Week(
+                    ::icu_datetime::fields::Week::WeekOfYear,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        0u8, 0u8, 115u8, 0u8, 0u8, 101u8, 0u8, 0u8, 109u8, 0u8, 0u8, 97u8, 0u8,
+                        0u8, 105u8, 0u8, 0u8, 110u8, 0u8, 0u8, 101u8, 0u8, 0u8, 32u8, 128u8, 48u8,
+                        1u8, 0u8, 0u8, 32u8, 0u8, 0u8, 100u8, 0u8, 0u8, 101u8, 0u8, 0u8, 32u8,
+                        128u8, 17u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -22702,6 +23061,34 @@ static FR_U_CA_COPTIC_FR_U_CA_ETHIOPIC_FR_U_CA_INDIAN: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Month(
+                    ::icu_datetime::fields::Month::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Week(
+                    ::icu_datetime::fields::Week::WeekOfMonth,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        0u8, 0u8, 115u8, 0u8, 0u8, 101u8, 0u8, 0u8, 109u8, 0u8, 0u8, 97u8, 0u8,
+                        0u8, 105u8, 0u8, 0u8, 110u8, 0u8, 0u8, 101u8, 0u8, 0u8, 32u8, 128u8, 49u8,
+                        1u8, 0u8, 0u8, 32u8, 0u8, 0u8, 40u8, 128u8, 32u8, 4u8, 0u8, 0u8, 41u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -22777,7 +23164,7 @@ static FR_U_CA_COPTIC_FR_U_CA_ETHIOPIC_FR_U_CA_INDIAN: &DataStruct = &[
         }],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[128u8, 82u8, 3u8]) },
+                items: unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[128u8, 80u8, 1u8]) },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
             },
         ),
@@ -22991,6 +23378,75 @@ static FR_U_CA_COPTIC_FR_U_CA_ETHIOPIC_FR_U_CA_INDIAN: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Hour(
+                    ::icu_datetime::fields::Hour::H12,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Minute,
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Second(
+                    ::icu_datetime::fields::Second::Second,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::TimeZone(
+                    ::icu_datetime::fields::TimeZone::LowerV,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 113u8, 1u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8, 0u8, 0u8, 58u8,
+                        128u8, 144u8, 2u8, 0u8, 0u8, 32u8, 128u8, 96u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        163u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::Seconds,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Hour(
+                    ::icu_datetime::fields::Hour::H12,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Minute,
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::TimeZone(
+                    ::icu_datetime::fields::TimeZone::LowerV,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 113u8, 1u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8, 0u8, 0u8, 32u8,
+                        128u8, 96u8, 1u8, 0u8, 0u8, 32u8, 128u8, 163u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::Minutes,
+            },
+        ),
+    ),
     (
         &[::icu_datetime::fields::Field {
             symbol: ::icu_datetime::fields::FieldSymbol::Hour(::icu_datetime::fields::Hour::H23),
@@ -22998,7 +23454,11 @@ static FR_U_CA_COPTIC_FR_U_CA_ETHIOPIC_FR_U_CA_INDIAN: &DataStruct = &[
         }],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[128u8, 114u8, 2u8]) },
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 114u8, 2u8, 0u8, 0u8, 32u8, 0u8, 0u8, 104u8,
+                    ])
+                },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::Hours,
             },
         ),
@@ -23058,6 +23518,74 @@ static FR_U_CA_COPTIC_FR_U_CA_ETHIOPIC_FR_U_CA_INDIAN: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Hour(
+                    ::icu_datetime::fields::Hour::H23,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Minute,
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Second(
+                    ::icu_datetime::fields::Second::Second,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::TimeZone(
+                    ::icu_datetime::fields::TimeZone::LowerV,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 114u8, 2u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8, 0u8, 0u8, 58u8,
+                        128u8, 144u8, 2u8, 0u8, 0u8, 32u8, 128u8, 163u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::Seconds,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Hour(
+                    ::icu_datetime::fields::Hour::H23,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Minute,
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::TimeZone(
+                    ::icu_datetime::fields::TimeZone::LowerV,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 114u8, 2u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8, 0u8, 0u8, 32u8,
+                        128u8, 163u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::Minutes,
+            },
+        ),
+    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -23083,7 +23611,7 @@ static FR_U_CA_COPTIC_FR_U_CA_ETHIOPIC_FR_U_CA_INDIAN: &DataStruct = &[
         ),
     ),
 ];
-static FR_U_CA_GREGORY: &DataStruct = &[
+static FR_U_CA_COPTIC_FR_U_CA_ETHIOPIC_FR_U_CA_INDIAN: &DataStruct = &[
     (
         &[
             ::icu_datetime::fields::Field {
@@ -23266,7 +23794,29 @@ static FR_U_CA_GREGORY: &DataStruct = &[
         }],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[128u8, 16u8, 1u8]) },
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[::icu_datetime::fields::Field {
+            symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                ::icu_datetime::fields::Year::Calendar,
+            ),
+            length: ::icu_datetime::fields::FieldLength::Wide,
+        }],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 1u8,
+                    ])
+                },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
             },
         ),
@@ -23277,7 +23827,7 @@ static FR_U_CA_GREGORY: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -23290,7 +23840,8 @@ static FR_U_CA_GREGORY: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 32u8, 2u8, 0u8, 0u8, 47u8, 128u8, 16u8, 1u8,
+                        128u8, 32u8, 2u8, 0u8, 0u8, 47u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        0u8, 5u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -23303,7 +23854,7 @@ static FR_U_CA_GREGORY: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -23323,7 +23874,7 @@ static FR_U_CA_GREGORY: &DataStruct = &[
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
                         128u8, 64u8, 2u8, 0u8, 0u8, 47u8, 128u8, 32u8, 2u8, 0u8, 0u8, 47u8, 128u8,
-                        16u8, 1u8,
+                        16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 5u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -23336,7 +23887,7 @@ static FR_U_CA_GREGORY: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -23362,7 +23913,8 @@ static FR_U_CA_GREGORY: &DataStruct = &[
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
                         128u8, 80u8, 1u8, 0u8, 0u8, 32u8, 128u8, 64u8, 2u8, 0u8, 0u8, 47u8, 128u8,
-                        32u8, 2u8, 0u8, 0u8, 47u8, 128u8, 16u8, 1u8,
+                        32u8, 2u8, 0u8, 0u8, 47u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8,
+                        5u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -23375,7 +23927,7 @@ static FR_U_CA_GREGORY: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -23388,7 +23940,8 @@ static FR_U_CA_GREGORY: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 32u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
+                        128u8, 32u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        0u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -23401,7 +23954,7 @@ static FR_U_CA_GREGORY: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -23421,7 +23974,7 @@ static FR_U_CA_GREGORY: &DataStruct = &[
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
                         128u8, 64u8, 1u8, 0u8, 0u8, 32u8, 128u8, 32u8, 3u8, 0u8, 0u8, 32u8, 128u8,
-                        16u8, 1u8,
+                        16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -23434,7 +23987,7 @@ static FR_U_CA_GREGORY: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -23460,7 +24013,8 @@ static FR_U_CA_GREGORY: &DataStruct = &[
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
                         128u8, 80u8, 1u8, 0u8, 0u8, 32u8, 128u8, 64u8, 1u8, 0u8, 0u8, 32u8, 128u8,
-                        32u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
+                        32u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8,
+                        1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -23473,7 +24027,7 @@ static FR_U_CA_GREGORY: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -23486,36 +24040,8 @@ static FR_U_CA_GREGORY: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 32u8, 4u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
-                    ])
-                },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
-            },
-        ),
-    ),
-    (
-        &[
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Year(
-                    ::icu_datetime::fields::Year::Calendar,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Week(
-                    ::icu_datetime::fields::Week::WeekOfYear,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-        ],
-        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
-            ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe {
-                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        0u8, 0u8, 115u8, 0u8, 0u8, 101u8, 0u8, 0u8, 109u8, 0u8, 0u8, 97u8, 0u8,
-                        0u8, 105u8, 0u8, 0u8, 110u8, 0u8, 0u8, 101u8, 0u8, 0u8, 32u8, 128u8, 48u8,
-                        1u8, 0u8, 0u8, 32u8, 0u8, 0u8, 100u8, 0u8, 0u8, 101u8, 0u8, 0u8, 32u8,
-                        128u8, 17u8, 1u8,
+                        128u8, 32u8, 4u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        0u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -23668,34 +24194,6 @@ static FR_U_CA_GREGORY: &DataStruct = &[
             },
         ),
     ),
-    (
-        &[
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Month(
-                    ::icu_datetime::fields::Month::Format,
-                ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Week(
-                    ::icu_datetime::fields::Week::WeekOfMonth,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-        ],
-        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
-            ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe {
-                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        0u8, 0u8, 115u8, 0u8, 0u8, 101u8, 0u8, 0u8, 109u8, 0u8, 0u8, 97u8, 0u8,
-                        0u8, 105u8, 0u8, 0u8, 110u8, 0u8, 0u8, 101u8, 0u8, 0u8, 32u8, 128u8, 49u8,
-                        1u8, 0u8, 0u8, 32u8, 0u8, 0u8, 40u8, 128u8, 32u8, 4u8, 0u8, 0u8, 41u8,
-                    ])
-                },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
-            },
-        ),
-    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -23771,7 +24269,7 @@ static FR_U_CA_GREGORY: &DataStruct = &[
         }],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[128u8, 80u8, 1u8]) },
+                items: unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[128u8, 82u8, 3u8]) },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
             },
         ),
@@ -23985,11 +24483,23 @@ static FR_U_CA_GREGORY: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[::icu_datetime::fields::Field {
+            symbol: ::icu_datetime::fields::FieldSymbol::Hour(::icu_datetime::fields::Hour::H23),
+            length: ::icu_datetime::fields::FieldLength::One,
+        }],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[128u8, 114u8, 2u8]) },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::Hours,
+            },
+        ),
+    ),
     (
         &[
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Hour(
-                    ::icu_datetime::fields::Hour::H12,
+                    ::icu_datetime::fields::Hour::H23,
                 ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
@@ -23997,15 +24507,33 @@ static FR_U_CA_GREGORY: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Minute,
                 length: ::icu_datetime::fields::FieldLength::One,
             },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 114u8, 2u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::Minutes,
+            },
+        ),
+    ),
+    (
+        &[
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Second(
-                    ::icu_datetime::fields::Second::Second,
+                symbol: ::icu_datetime::fields::FieldSymbol::Hour(
+                    ::icu_datetime::fields::Hour::H23,
                 ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::TimeZone(
-                    ::icu_datetime::fields::TimeZone::LowerV,
+                symbol: ::icu_datetime::fields::FieldSymbol::Minute,
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Second(
+                    ::icu_datetime::fields::Second::Second,
                 ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
@@ -24014,9 +24542,8 @@ static FR_U_CA_GREGORY: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 113u8, 1u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8, 0u8, 0u8, 58u8,
-                        128u8, 144u8, 2u8, 0u8, 0u8, 32u8, 128u8, 96u8, 1u8, 0u8, 0u8, 32u8, 128u8,
-                        163u8, 1u8,
+                        128u8, 114u8, 2u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8, 0u8, 0u8, 58u8,
+                        128u8, 144u8, 2u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::Seconds,
@@ -24025,19 +24552,13 @@ static FR_U_CA_GREGORY: &DataStruct = &[
     ),
     (
         &[
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Hour(
-                    ::icu_datetime::fields::Hour::H12,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Minute,
                 length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::TimeZone(
-                    ::icu_datetime::fields::TimeZone::LowerV,
+                symbol: ::icu_datetime::fields::FieldSymbol::Second(
+                    ::icu_datetime::fields::Second::Second,
                 ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
@@ -24046,40 +24567,61 @@ static FR_U_CA_GREGORY: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 113u8, 1u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8, 0u8, 0u8, 32u8,
-                        128u8, 96u8, 1u8, 0u8, 0u8, 32u8, 128u8, 163u8, 1u8,
+                        128u8, 128u8, 2u8, 0u8, 0u8, 58u8, 128u8, 144u8, 2u8,
                     ])
                 },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::Minutes,
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::Seconds,
             },
         ),
     ),
+];
+static FR_U_CA_JAPANESE_FR_U_CA_JAPANEXT: &DataStruct = &[
     (
-        &[::icu_datetime::fields::Field {
-            symbol: ::icu_datetime::fields::FieldSymbol::Hour(::icu_datetime::fields::Hour::H23),
-            length: ::icu_datetime::fields::FieldLength::One,
-        }],
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Era,
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+        ],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 114u8, 2u8, 0u8, 0u8, 32u8, 0u8, 0u8, 104u8,
+                        128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 1u8,
                     ])
                 },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::Hours,
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
             },
         ),
     ),
     (
         &[
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Hour(
-                    ::icu_datetime::fields::Hour::H23,
+                symbol: ::icu_datetime::fields::FieldSymbol::Era,
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
                 ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Minute,
+                symbol: ::icu_datetime::fields::FieldSymbol::Month(
+                    ::icu_datetime::fields::Month::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Day(
+                    ::icu_datetime::fields::Day::DayOfMonth,
+                ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
         ],
@@ -24087,65 +24629,66 @@ static FR_U_CA_GREGORY: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 114u8, 2u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8,
+                        128u8, 64u8, 2u8, 0u8, 0u8, 47u8, 128u8, 32u8, 2u8, 0u8, 0u8, 47u8, 128u8,
+                        16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 5u8,
                     ])
                 },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::Minutes,
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
             },
         ),
     ),
     (
         &[
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Hour(
-                    ::icu_datetime::fields::Hour::H23,
-                ),
+                symbol: ::icu_datetime::fields::FieldSymbol::Era,
                 length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Minute,
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Second(
-                    ::icu_datetime::fields::Second::Second,
+                symbol: ::icu_datetime::fields::FieldSymbol::Month(
+                    ::icu_datetime::fields::Month::Format,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
             },
         ],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 114u8, 2u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8, 0u8, 0u8, 58u8,
-                        128u8, 144u8, 2u8,
+                        128u8, 32u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        0u8, 1u8,
                     ])
                 },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::Seconds,
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
             },
         ),
     ),
     (
         &[
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Hour(
-                    ::icu_datetime::fields::Hour::H23,
-                ),
+                symbol: ::icu_datetime::fields::FieldSymbol::Era,
                 length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Minute,
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Second(
-                    ::icu_datetime::fields::Second::Second,
+                symbol: ::icu_datetime::fields::FieldSymbol::Month(
+                    ::icu_datetime::fields::Month::Format,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
             },
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::TimeZone(
-                    ::icu_datetime::fields::TimeZone::LowerV,
+                symbol: ::icu_datetime::fields::FieldSymbol::Day(
+                    ::icu_datetime::fields::Day::DayOfMonth,
                 ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
@@ -24154,29 +24697,41 @@ static FR_U_CA_GREGORY: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 114u8, 2u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8, 0u8, 0u8, 58u8,
-                        128u8, 144u8, 2u8, 0u8, 0u8, 32u8, 128u8, 163u8, 1u8,
+                        128u8, 64u8, 1u8, 0u8, 0u8, 32u8, 128u8, 32u8, 3u8, 0u8, 0u8, 32u8, 128u8,
+                        16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 1u8,
                     ])
                 },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::Seconds,
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
             },
         ),
     ),
     (
         &[
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Hour(
-                    ::icu_datetime::fields::Hour::H23,
+                symbol: ::icu_datetime::fields::FieldSymbol::Era,
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
                 ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Minute,
+                symbol: ::icu_datetime::fields::FieldSymbol::Month(
+                    ::icu_datetime::fields::Month::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Day(
+                    ::icu_datetime::fields::Day::DayOfMonth,
+                ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::TimeZone(
-                    ::icu_datetime::fields::TimeZone::LowerV,
+                symbol: ::icu_datetime::fields::FieldSymbol::Weekday(
+                    ::icu_datetime::fields::Weekday::Format,
                 ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
@@ -24185,23 +24740,62 @@ static FR_U_CA_GREGORY: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 114u8, 2u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8, 0u8, 0u8, 32u8,
-                        128u8, 163u8, 1u8,
+                        128u8, 80u8, 1u8, 0u8, 0u8, 32u8, 128u8, 64u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        32u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8,
+                        1u8,
                     ])
                 },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::Minutes,
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[::icu_datetime::fields::Field {
+            symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                ::icu_datetime::fields::Year::Calendar,
+            ),
+            length: ::icu_datetime::fields::FieldLength::One,
+        }],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[::icu_datetime::fields::Field {
+            symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                ::icu_datetime::fields::Year::Calendar,
+            ),
+            length: ::icu_datetime::fields::FieldLength::Wide,
+        }],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
             },
         ),
     ),
     (
         &[
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Minute,
-                length: ::icu_datetime::fields::FieldLength::One,
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Second(
-                    ::icu_datetime::fields::Second::Second,
+                symbol: ::icu_datetime::fields::FieldSymbol::Month(
+                    ::icu_datetime::fields::Month::Format,
                 ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
@@ -24210,24 +24804,31 @@ static FR_U_CA_GREGORY: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 128u8, 2u8, 0u8, 0u8, 58u8, 128u8, 144u8, 2u8,
+                        128u8, 32u8, 1u8, 0u8, 0u8, 47u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        0u8, 5u8,
                     ])
                 },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::Seconds,
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
             },
         ),
     ),
-];
-static FR_U_CA_JAPANESE_FR_U_CA_JAPANEXT: &DataStruct = &[
     (
         &[
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Era,
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Month(
+                    ::icu_datetime::fields::Month::Format,
+                ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Year(
-                    ::icu_datetime::fields::Year::Calendar,
+                symbol: ::icu_datetime::fields::FieldSymbol::Day(
+                    ::icu_datetime::fields::Day::DayOfMonth,
                 ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
@@ -24236,7 +24837,8 @@ static FR_U_CA_JAPANESE_FR_U_CA_JAPANEXT: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 1u8,
+                        128u8, 64u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8, 0u8, 0u8, 47u8, 128u8,
+                        16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 5u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -24245,15 +24847,11 @@ static FR_U_CA_JAPANESE_FR_U_CA_JAPANEXT: &DataStruct = &[
     ),
     (
         &[
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Era,
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -24267,13 +24865,20 @@ static FR_U_CA_JAPANESE_FR_U_CA_JAPANEXT: &DataStruct = &[
                 ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Weekday(
+                    ::icu_datetime::fields::Weekday::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
         ],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 64u8, 2u8, 0u8, 0u8, 47u8, 128u8, 32u8, 2u8, 0u8, 0u8, 47u8, 128u8,
-                        16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 5u8,
+                        128u8, 80u8, 1u8, 0u8, 0u8, 32u8, 128u8, 64u8, 1u8, 0u8, 0u8, 47u8, 128u8,
+                        32u8, 1u8, 0u8, 0u8, 47u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8,
+                        5u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -24282,15 +24887,11 @@ static FR_U_CA_JAPANESE_FR_U_CA_JAPANEXT: &DataStruct = &[
     ),
     (
         &[
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Era,
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -24313,255 +24914,11 @@ static FR_U_CA_JAPANESE_FR_U_CA_JAPANEXT: &DataStruct = &[
     ),
     (
         &[
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Era,
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Month(
-                    ::icu_datetime::fields::Month::Format,
-                ),
-                length: ::icu_datetime::fields::FieldLength::Abbreviated,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Day(
-                    ::icu_datetime::fields::Day::DayOfMonth,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-        ],
-        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
-            ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe {
-                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 64u8, 1u8, 0u8, 0u8, 32u8, 128u8, 32u8, 3u8, 0u8, 0u8, 32u8, 128u8,
-                        16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 1u8,
-                    ])
-                },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
-            },
-        ),
-    ),
-    (
-        &[
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Era,
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Year(
-                    ::icu_datetime::fields::Year::Calendar,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Month(
-                    ::icu_datetime::fields::Month::Format,
-                ),
-                length: ::icu_datetime::fields::FieldLength::Abbreviated,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Day(
-                    ::icu_datetime::fields::Day::DayOfMonth,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Weekday(
-                    ::icu_datetime::fields::Weekday::Format,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-        ],
-        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
-            ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe {
-                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 80u8, 1u8, 0u8, 0u8, 32u8, 128u8, 64u8, 1u8, 0u8, 0u8, 32u8, 128u8,
-                        32u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8,
-                        1u8,
-                    ])
-                },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
-            },
-        ),
-    ),
-    (
-        &[::icu_datetime::fields::Field {
-            symbol: ::icu_datetime::fields::FieldSymbol::Year(
-                ::icu_datetime::fields::Year::Calendar,
-            ),
-            length: ::icu_datetime::fields::FieldLength::One,
-        }],
-        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
-            ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe {
-                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 1u8,
-                    ])
-                },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
-            },
-        ),
-    ),
-    (
-        &[::icu_datetime::fields::Field {
-            symbol: ::icu_datetime::fields::FieldSymbol::Year(
-                ::icu_datetime::fields::Year::Calendar,
-            ),
-            length: ::icu_datetime::fields::FieldLength::Wide,
-        }],
-        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
-            ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe {
-                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 1u8,
-                    ])
-                },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
-            },
-        ),
-    ),
-    (
-        &[
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Year(
-                    ::icu_datetime::fields::Year::Calendar,
-                ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Month(
-                    ::icu_datetime::fields::Month::Format,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-        ],
-        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
-            ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe {
-                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 32u8, 1u8, 0u8, 0u8, 47u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
-                        0u8, 5u8,
-                    ])
-                },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
-            },
-        ),
-    ),
-    (
-        &[
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Year(
-                    ::icu_datetime::fields::Year::Calendar,
-                ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Month(
-                    ::icu_datetime::fields::Month::Format,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Day(
-                    ::icu_datetime::fields::Day::DayOfMonth,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-        ],
-        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
-            ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe {
-                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 64u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8, 0u8, 0u8, 47u8, 128u8,
-                        16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 5u8,
-                    ])
-                },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
-            },
-        ),
-    ),
-    (
-        &[
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Year(
-                    ::icu_datetime::fields::Year::Calendar,
-                ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Month(
-                    ::icu_datetime::fields::Month::Format,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Day(
-                    ::icu_datetime::fields::Day::DayOfMonth,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Weekday(
-                    ::icu_datetime::fields::Weekday::Format,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-        ],
-        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
-            ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe {
-                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 80u8, 1u8, 0u8, 0u8, 32u8, 128u8, 64u8, 1u8, 0u8, 0u8, 47u8, 128u8,
-                        32u8, 1u8, 0u8, 0u8, 47u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8,
-                        5u8,
-                    ])
-                },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
-            },
-        ),
-    ),
-    (
-        &[
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Year(
-                    ::icu_datetime::fields::Year::Calendar,
-                ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Month(
-                    ::icu_datetime::fields::Month::Format,
-                ),
-                length: ::icu_datetime::fields::FieldLength::Abbreviated,
-            },
-        ],
-        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
-            ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe {
-                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 32u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
-                        0u8, 1u8,
-                    ])
-                },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
-            },
-        ),
-    ),
-    (
-        &[
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Year(
-                    ::icu_datetime::fields::Year::Calendar,
-                ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
+                length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -26393,7 +26750,7 @@ static JA_U_CA_BUDDHIST: &DataStruct = &[
         ),
     ),
 ];
-static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
+static JA_U_CA_CHINESE_JA_U_CA_DANGI_JA_U_CA_GREGORY: &DataStruct = &[
     (
         &[
             ::icu_datetime::fields::Field {
@@ -26447,7 +26804,7 @@ static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 0u8, 5u8, 128u8, 16u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8, 0u8,
+                        128u8, 0u8, 1u8, 128u8, 16u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8, 0u8,
                         0u8, 47u8, 128u8, 64u8, 1u8,
                     ])
                 },
@@ -26603,8 +26960,7 @@ static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
                         128u8, 0u8, 1u8, 128u8, 16u8, 1u8, 0u8, 94u8, 116u8, 128u8, 32u8, 1u8, 0u8,
-                        103u8, 8u8, 128u8, 64u8, 1u8, 0u8, 101u8, 229u8, 0u8, 0u8, 40u8, 128u8,
-                        80u8, 4u8, 0u8, 0u8, 41u8,
+                        103u8, 8u8, 128u8, 64u8, 1u8, 0u8, 101u8, 229u8, 128u8, 80u8, 4u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -26621,27 +26977,7 @@ static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
-                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 0u8, 1u8, 128u8, 16u8, 1u8, 0u8, 94u8, 116u8,
-                    ])
-                },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
-            },
-        ),
-    ),
-    (
-        &[::icu_datetime::fields::Field {
-            symbol: ::icu_datetime::fields::FieldSymbol::Year(
-                ::icu_datetime::fields::Year::Calendar,
-            ),
-            length: ::icu_datetime::fields::FieldLength::Wide,
-        }],
-        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
-            ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe {
-                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 0u8, 1u8, 128u8, 16u8, 1u8, 0u8, 94u8, 116u8,
-                    ])
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[128u8, 16u8, 1u8, 0u8, 94u8, 116u8])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
             },
@@ -26653,7 +26989,7 @@ static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
+                length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -26666,7 +27002,7 @@ static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 0u8, 5u8, 128u8, 16u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8,
+                        128u8, 16u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -26679,7 +27015,7 @@ static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
+                length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -26698,8 +27034,8 @@ static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 0u8, 5u8, 128u8, 16u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8, 0u8,
-                        0u8, 47u8, 128u8, 64u8, 1u8,
+                        128u8, 16u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8, 0u8, 0u8, 47u8, 128u8,
+                        64u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -26712,7 +27048,7 @@ static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
+                length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -26737,9 +27073,8 @@ static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 0u8, 5u8, 128u8, 16u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8, 0u8,
-                        0u8, 47u8, 128u8, 64u8, 1u8, 0u8, 0u8, 40u8, 128u8, 80u8, 1u8, 0u8, 0u8,
-                        41u8,
+                        128u8, 16u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8, 0u8, 0u8, 47u8, 128u8,
+                        64u8, 1u8, 0u8, 0u8, 40u8, 128u8, 80u8, 1u8, 0u8, 0u8, 41u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -26752,7 +27087,7 @@ static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
+                length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -26777,9 +27112,8 @@ static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 0u8, 5u8, 128u8, 16u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8, 0u8,
-                        0u8, 47u8, 128u8, 64u8, 1u8, 0u8, 0u8, 40u8, 128u8, 80u8, 4u8, 0u8, 0u8,
-                        41u8,
+                        128u8, 16u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8, 0u8, 0u8, 47u8, 128u8,
+                        64u8, 1u8, 128u8, 80u8, 4u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -26792,7 +27126,33 @@ static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Month(
+                    ::icu_datetime::fields::Month::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::TwoDigit,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 16u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 2u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -26805,8 +27165,7 @@ static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 0u8, 1u8, 128u8, 16u8, 1u8, 0u8, 94u8, 116u8, 128u8, 32u8, 1u8, 0u8,
-                        103u8, 8u8,
+                        128u8, 16u8, 1u8, 0u8, 94u8, 116u8, 128u8, 32u8, 1u8, 0u8, 103u8, 8u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -26819,7 +27178,7 @@ static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
+                length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -26838,8 +27197,8 @@ static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 0u8, 1u8, 128u8, 16u8, 1u8, 0u8, 94u8, 116u8, 128u8, 32u8, 1u8, 0u8,
-                        103u8, 8u8, 128u8, 64u8, 1u8, 0u8, 101u8, 229u8,
+                        128u8, 16u8, 1u8, 0u8, 94u8, 116u8, 128u8, 32u8, 1u8, 0u8, 103u8, 8u8,
+                        128u8, 64u8, 1u8, 0u8, 101u8, 229u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -26852,7 +27211,7 @@ static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
+                length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -26877,9 +27236,9 @@ static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 0u8, 1u8, 128u8, 16u8, 1u8, 0u8, 94u8, 116u8, 128u8, 32u8, 1u8, 0u8,
-                        103u8, 8u8, 128u8, 64u8, 1u8, 0u8, 101u8, 229u8, 0u8, 0u8, 40u8, 128u8,
-                        80u8, 1u8, 0u8, 0u8, 41u8,
+                        128u8, 16u8, 1u8, 0u8, 94u8, 116u8, 128u8, 32u8, 1u8, 0u8, 103u8, 8u8,
+                        128u8, 64u8, 1u8, 0u8, 101u8, 229u8, 0u8, 0u8, 40u8, 128u8, 80u8, 1u8, 0u8,
+                        0u8, 41u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -26892,7 +27251,7 @@ static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
+                length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -26917,9 +27276,8 @@ static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 0u8, 1u8, 128u8, 16u8, 1u8, 0u8, 94u8, 116u8, 128u8, 32u8, 1u8, 0u8,
-                        103u8, 8u8, 128u8, 64u8, 1u8, 0u8, 101u8, 229u8, 0u8, 0u8, 40u8, 128u8,
-                        80u8, 4u8, 0u8, 0u8, 41u8,
+                        128u8, 16u8, 1u8, 0u8, 94u8, 116u8, 128u8, 32u8, 1u8, 0u8, 103u8, 8u8,
+                        128u8, 64u8, 1u8, 0u8, 101u8, 229u8, 128u8, 80u8, 4u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -26932,7 +27290,7 @@ static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
+                length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -26945,8 +27303,34 @@ static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 0u8, 1u8, 128u8, 16u8, 1u8, 0u8, 94u8, 116u8, 128u8, 32u8, 1u8, 0u8,
-                        103u8, 8u8,
+                        128u8, 16u8, 1u8, 0u8, 94u8, 116u8, 128u8, 32u8, 1u8, 0u8, 103u8, 8u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Week(
+                    ::icu_datetime::fields::Week::WeekOfYear,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 17u8, 1u8, 0u8, 94u8, 116u8, 0u8, 123u8, 44u8, 128u8, 48u8, 1u8,
+                        0u8, 144u8, 49u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -27053,8 +27437,7 @@ static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 32u8, 1u8, 0u8, 0u8, 47u8, 128u8, 64u8, 1u8, 0u8, 0u8, 40u8, 128u8,
-                        80u8, 4u8, 0u8, 0u8, 41u8,
+                        128u8, 32u8, 1u8, 0u8, 0u8, 47u8, 128u8, 64u8, 1u8, 128u8, 80u8, 4u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -27162,7 +27545,34 @@ static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
                         128u8, 32u8, 1u8, 0u8, 103u8, 8u8, 128u8, 64u8, 1u8, 0u8, 101u8, 229u8,
-                        0u8, 0u8, 40u8, 128u8, 80u8, 4u8, 0u8, 0u8, 41u8,
+                        128u8, 80u8, 4u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Month(
+                    ::icu_datetime::fields::Month::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Week(
+                    ::icu_datetime::fields::Week::WeekOfMonth,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 32u8, 1u8, 0u8, 103u8, 8u8, 0u8, 123u8, 44u8, 128u8, 49u8, 1u8, 0u8,
+                        144u8, 49u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -27257,8 +27667,7 @@ static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 64u8, 1u8, 0u8, 101u8, 229u8, 0u8, 0u8, 40u8, 128u8, 80u8, 4u8, 0u8,
-                        0u8, 41u8,
+                        128u8, 64u8, 1u8, 0u8, 101u8, 229u8, 128u8, 80u8, 4u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -27488,6 +27897,74 @@ static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Hour(
+                    ::icu_datetime::fields::Hour::H12,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Minute,
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Second(
+                    ::icu_datetime::fields::Second::Second,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::TimeZone(
+                    ::icu_datetime::fields::TimeZone::LowerV,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 96u8, 1u8, 128u8, 112u8, 1u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8,
+                        0u8, 0u8, 58u8, 128u8, 144u8, 2u8, 0u8, 0u8, 32u8, 128u8, 163u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::Seconds,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Hour(
+                    ::icu_datetime::fields::Hour::H12,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Minute,
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::TimeZone(
+                    ::icu_datetime::fields::TimeZone::LowerV,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 96u8, 1u8, 128u8, 112u8, 1u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8,
+                        0u8, 0u8, 32u8, 128u8, 163u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::Minutes,
+            },
+        ),
+    ),
     (
         &[::icu_datetime::fields::Field {
             symbol: ::icu_datetime::fields::FieldSymbol::Hour(::icu_datetime::fields::Hour::H23),
@@ -27559,6 +28036,12 @@ static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
     ),
     (
         &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Hour(
+                    ::icu_datetime::fields::Hour::H23,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Minute,
                 length: ::icu_datetime::fields::FieldLength::One,
@@ -27569,29 +28052,40 @@ static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
                 ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::TimeZone(
+                    ::icu_datetime::fields::TimeZone::LowerV,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
         ],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 128u8, 2u8, 0u8, 0u8, 58u8, 128u8, 144u8, 2u8,
+                        128u8, 114u8, 1u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8, 0u8, 0u8, 58u8,
+                        128u8, 144u8, 2u8, 0u8, 0u8, 32u8, 128u8, 163u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::Seconds,
             },
         ),
     ),
-];
-static JA_U_CA_GREGORY: &DataStruct = &[
     (
         &[
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Era,
+                symbol: ::icu_datetime::fields::FieldSymbol::Hour(
+                    ::icu_datetime::fields::Hour::H23,
+                ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Year(
-                    ::icu_datetime::fields::Year::Calendar,
+                symbol: ::icu_datetime::fields::FieldSymbol::Minute,
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::TimeZone(
+                    ::icu_datetime::fields::TimeZone::LowerV,
                 ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
@@ -27600,34 +28094,49 @@ static JA_U_CA_GREGORY: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 0u8, 1u8, 128u8, 16u8, 1u8, 0u8, 94u8, 116u8,
+                        128u8, 114u8, 1u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8, 0u8, 0u8, 32u8,
+                        128u8, 163u8, 1u8,
                     ])
                 },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::Minutes,
             },
         ),
     ),
     (
         &[
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Era,
+                symbol: ::icu_datetime::fields::FieldSymbol::Minute,
                 length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Year(
-                    ::icu_datetime::fields::Year::Calendar,
+                symbol: ::icu_datetime::fields::FieldSymbol::Second(
+                    ::icu_datetime::fields::Second::Second,
                 ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 128u8, 2u8, 0u8, 0u8, 58u8, 128u8, 144u8, 2u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::Seconds,
+            },
+        ),
+    ),
+];
+static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
+    (
+        &[
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Month(
-                    ::icu_datetime::fields::Month::Format,
-                ),
+                symbol: ::icu_datetime::fields::FieldSymbol::Era,
                 length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Day(
-                    ::icu_datetime::fields::Day::DayOfMonth,
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
                 ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
@@ -27636,8 +28145,44 @@ static JA_U_CA_GREGORY: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 0u8, 1u8, 128u8, 16u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8, 0u8,
-                        0u8, 47u8, 128u8, 64u8, 1u8,
+                        128u8, 0u8, 1u8, 128u8, 16u8, 1u8, 0u8, 94u8, 116u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Era,
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Month(
+                    ::icu_datetime::fields::Month::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Day(
+                    ::icu_datetime::fields::Day::DayOfMonth,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 0u8, 5u8, 128u8, 16u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8, 0u8,
+                        0u8, 47u8, 128u8, 64u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -27792,7 +28337,8 @@ static JA_U_CA_GREGORY: &DataStruct = &[
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
                         128u8, 0u8, 1u8, 128u8, 16u8, 1u8, 0u8, 94u8, 116u8, 128u8, 32u8, 1u8, 0u8,
-                        103u8, 8u8, 128u8, 64u8, 1u8, 0u8, 101u8, 229u8, 128u8, 80u8, 4u8,
+                        103u8, 8u8, 128u8, 64u8, 1u8, 0u8, 101u8, 229u8, 0u8, 0u8, 40u8, 128u8,
+                        80u8, 4u8, 0u8, 0u8, 41u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -27809,7 +28355,27 @@ static JA_U_CA_GREGORY: &DataStruct = &[
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
-                    ::zerovec::ZeroVec::from_bytes_unchecked(&[128u8, 16u8, 1u8, 0u8, 94u8, 116u8])
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 0u8, 1u8, 128u8, 16u8, 1u8, 0u8, 94u8, 116u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[::icu_datetime::fields::Field {
+            symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                ::icu_datetime::fields::Year::Calendar,
+            ),
+            length: ::icu_datetime::fields::FieldLength::Wide,
+        }],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 0u8, 1u8, 128u8, 16u8, 1u8, 0u8, 94u8, 116u8,
+                    ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
             },
@@ -27821,7 +28387,7 @@ static JA_U_CA_GREGORY: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -27834,7 +28400,7 @@ static JA_U_CA_GREGORY: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 16u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8,
+                        128u8, 0u8, 5u8, 128u8, 16u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -27847,7 +28413,7 @@ static JA_U_CA_GREGORY: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -27866,8 +28432,8 @@ static JA_U_CA_GREGORY: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 16u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8, 0u8, 0u8, 47u8, 128u8,
-                        64u8, 1u8,
+                        128u8, 0u8, 5u8, 128u8, 16u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8, 0u8,
+                        0u8, 47u8, 128u8, 64u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -27880,7 +28446,7 @@ static JA_U_CA_GREGORY: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -27905,8 +28471,9 @@ static JA_U_CA_GREGORY: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 16u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8, 0u8, 0u8, 47u8, 128u8,
-                        64u8, 1u8, 0u8, 0u8, 40u8, 128u8, 80u8, 1u8, 0u8, 0u8, 41u8,
+                        128u8, 0u8, 5u8, 128u8, 16u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8, 0u8,
+                        0u8, 47u8, 128u8, 64u8, 1u8, 0u8, 0u8, 40u8, 128u8, 80u8, 1u8, 0u8, 0u8,
+                        41u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -27919,7 +28486,7 @@ static JA_U_CA_GREGORY: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -27944,34 +28511,9 @@ static JA_U_CA_GREGORY: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 16u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8, 0u8, 0u8, 47u8, 128u8,
-                        64u8, 1u8, 128u8, 80u8, 4u8,
-                    ])
-                },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
-            },
-        ),
-    ),
-    (
-        &[
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Year(
-                    ::icu_datetime::fields::Year::Calendar,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Month(
-                    ::icu_datetime::fields::Month::Format,
-                ),
-                length: ::icu_datetime::fields::FieldLength::TwoDigit,
-            },
-        ],
-        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
-            ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe {
-                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 16u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 2u8,
+                        128u8, 0u8, 5u8, 128u8, 16u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8, 0u8,
+                        0u8, 47u8, 128u8, 64u8, 1u8, 0u8, 0u8, 40u8, 128u8, 80u8, 4u8, 0u8, 0u8,
+                        41u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -27984,7 +28526,7 @@ static JA_U_CA_GREGORY: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -27997,7 +28539,8 @@ static JA_U_CA_GREGORY: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 16u8, 1u8, 0u8, 94u8, 116u8, 128u8, 32u8, 1u8, 0u8, 103u8, 8u8,
+                        128u8, 0u8, 1u8, 128u8, 16u8, 1u8, 0u8, 94u8, 116u8, 128u8, 32u8, 1u8, 0u8,
+                        103u8, 8u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -28010,7 +28553,7 @@ static JA_U_CA_GREGORY: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -28029,8 +28572,8 @@ static JA_U_CA_GREGORY: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 16u8, 1u8, 0u8, 94u8, 116u8, 128u8, 32u8, 1u8, 0u8, 103u8, 8u8,
-                        128u8, 64u8, 1u8, 0u8, 101u8, 229u8,
+                        128u8, 0u8, 1u8, 128u8, 16u8, 1u8, 0u8, 94u8, 116u8, 128u8, 32u8, 1u8, 0u8,
+                        103u8, 8u8, 128u8, 64u8, 1u8, 0u8, 101u8, 229u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -28043,7 +28586,7 @@ static JA_U_CA_GREGORY: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -28068,9 +28611,9 @@ static JA_U_CA_GREGORY: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 16u8, 1u8, 0u8, 94u8, 116u8, 128u8, 32u8, 1u8, 0u8, 103u8, 8u8,
-                        128u8, 64u8, 1u8, 0u8, 101u8, 229u8, 0u8, 0u8, 40u8, 128u8, 80u8, 1u8, 0u8,
-                        0u8, 41u8,
+                        128u8, 0u8, 1u8, 128u8, 16u8, 1u8, 0u8, 94u8, 116u8, 128u8, 32u8, 1u8, 0u8,
+                        103u8, 8u8, 128u8, 64u8, 1u8, 0u8, 101u8, 229u8, 0u8, 0u8, 40u8, 128u8,
+                        80u8, 1u8, 0u8, 0u8, 41u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -28083,7 +28626,7 @@ static JA_U_CA_GREGORY: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -28108,8 +28651,9 @@ static JA_U_CA_GREGORY: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 16u8, 1u8, 0u8, 94u8, 116u8, 128u8, 32u8, 1u8, 0u8, 103u8, 8u8,
-                        128u8, 64u8, 1u8, 0u8, 101u8, 229u8, 128u8, 80u8, 4u8,
+                        128u8, 0u8, 1u8, 128u8, 16u8, 1u8, 0u8, 94u8, 116u8, 128u8, 32u8, 1u8, 0u8,
+                        103u8, 8u8, 128u8, 64u8, 1u8, 0u8, 101u8, 229u8, 0u8, 0u8, 40u8, 128u8,
+                        80u8, 4u8, 0u8, 0u8, 41u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -28122,7 +28666,7 @@ static JA_U_CA_GREGORY: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -28135,34 +28679,8 @@ static JA_U_CA_GREGORY: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 16u8, 1u8, 0u8, 94u8, 116u8, 128u8, 32u8, 1u8, 0u8, 103u8, 8u8,
-                    ])
-                },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
-            },
-        ),
-    ),
-    (
-        &[
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Year(
-                    ::icu_datetime::fields::Year::Calendar,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Week(
-                    ::icu_datetime::fields::Week::WeekOfYear,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-        ],
-        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
-            ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe {
-                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 17u8, 1u8, 0u8, 94u8, 116u8, 0u8, 123u8, 44u8, 128u8, 48u8, 1u8,
-                        0u8, 144u8, 49u8,
+                        128u8, 0u8, 1u8, 128u8, 16u8, 1u8, 0u8, 94u8, 116u8, 128u8, 32u8, 1u8, 0u8,
+                        103u8, 8u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -28269,7 +28787,8 @@ static JA_U_CA_GREGORY: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 32u8, 1u8, 0u8, 0u8, 47u8, 128u8, 64u8, 1u8, 128u8, 80u8, 4u8,
+                        128u8, 32u8, 1u8, 0u8, 0u8, 47u8, 128u8, 64u8, 1u8, 0u8, 0u8, 40u8, 128u8,
+                        80u8, 4u8, 0u8, 0u8, 41u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -28377,34 +28896,7 @@ static JA_U_CA_GREGORY: &DataStruct = &[
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
                         128u8, 32u8, 1u8, 0u8, 103u8, 8u8, 128u8, 64u8, 1u8, 0u8, 101u8, 229u8,
-                        128u8, 80u8, 4u8,
-                    ])
-                },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
-            },
-        ),
-    ),
-    (
-        &[
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Month(
-                    ::icu_datetime::fields::Month::Format,
-                ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Week(
-                    ::icu_datetime::fields::Week::WeekOfMonth,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-        ],
-        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
-            ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe {
-                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 32u8, 1u8, 0u8, 103u8, 8u8, 0u8, 123u8, 44u8, 128u8, 49u8, 1u8, 0u8,
-                        144u8, 49u8,
+                        0u8, 0u8, 40u8, 128u8, 80u8, 4u8, 0u8, 0u8, 41u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -28499,7 +28991,8 @@ static JA_U_CA_GREGORY: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 64u8, 1u8, 0u8, 101u8, 229u8, 128u8, 80u8, 4u8,
+                        128u8, 64u8, 1u8, 0u8, 101u8, 229u8, 0u8, 0u8, 40u8, 128u8, 80u8, 4u8, 0u8,
+                        0u8, 41u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -28729,74 +29222,6 @@ static JA_U_CA_GREGORY: &DataStruct = &[
             },
         ),
     ),
-    (
-        &[
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Hour(
-                    ::icu_datetime::fields::Hour::H12,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Minute,
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Second(
-                    ::icu_datetime::fields::Second::Second,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::TimeZone(
-                    ::icu_datetime::fields::TimeZone::LowerV,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-        ],
-        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
-            ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe {
-                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 96u8, 1u8, 128u8, 112u8, 1u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8,
-                        0u8, 0u8, 58u8, 128u8, 144u8, 2u8, 0u8, 0u8, 32u8, 128u8, 163u8, 1u8,
-                    ])
-                },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::Seconds,
-            },
-        ),
-    ),
-    (
-        &[
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Hour(
-                    ::icu_datetime::fields::Hour::H12,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Minute,
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::TimeZone(
-                    ::icu_datetime::fields::TimeZone::LowerV,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-        ],
-        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
-            ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe {
-                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 96u8, 1u8, 128u8, 112u8, 1u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8,
-                        0u8, 0u8, 32u8, 128u8, 163u8, 1u8,
-                    ])
-                },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::Minutes,
-            },
-        ),
-    ),
     (
         &[::icu_datetime::fields::Field {
             symbol: ::icu_datetime::fields::FieldSymbol::Hour(::icu_datetime::fields::Hour::H23),
@@ -28866,74 +29291,6 @@ static JA_U_CA_GREGORY: &DataStruct = &[
             },
         ),
     ),
-    (
-        &[
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Hour(
-                    ::icu_datetime::fields::Hour::H23,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Minute,
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Second(
-                    ::icu_datetime::fields::Second::Second,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::TimeZone(
-                    ::icu_datetime::fields::TimeZone::LowerV,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-        ],
-        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
-            ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe {
-                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 114u8, 1u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8, 0u8, 0u8, 58u8,
-                        128u8, 144u8, 2u8, 0u8, 0u8, 32u8, 128u8, 163u8, 1u8,
-                    ])
-                },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::Seconds,
-            },
-        ),
-    ),
-    (
-        &[
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Hour(
-                    ::icu_datetime::fields::Hour::H23,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Minute,
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::TimeZone(
-                    ::icu_datetime::fields::TimeZone::LowerV,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-        ],
-        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
-            ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe {
-                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 114u8, 1u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8, 0u8, 0u8, 32u8,
-                        128u8, 163u8, 1u8,
-                    ])
-                },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::Minutes,
-            },
-        ),
-    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -31138,7 +31495,7 @@ static RU_U_CA_BUDDHIST_RU_U_CA_COPTIC_RU_U_CA_ETHIOPIC: &DataStruct = &[
         ),
     ),
 ];
-static RU_U_CA_GREGORY: &DataStruct = &[
+static RU_U_CA_CHINESE_RU_U_CA_DANGI_RU_U_CA_GREGORY: &DataStruct = &[
     (
         &[
             ::icu_datetime::fields::Field {
@@ -33438,7 +33795,7 @@ static SR_CYRL_U_CA_BUDDHIST_SR_CYRL_U_CA_COPTIC: &DataStruct = &[
         ),
     ),
 ];
-static SR_CYRL_U_CA_GREGORY_SR_U_CA_GREGORY: &DataStruct = &[
+static SR_CYRL_U_CA_CHINESE_SR_CYRL_U_CA_DANGI: &DataStruct = &[
     (
         &[
             ::icu_datetime::fields::Field {
@@ -34719,7 +35076,7 @@ static SR_CYRL_U_CA_GREGORY_SR_U_CA_GREGORY: &DataStruct = &[
         ),
     ),
 ];
-static SR_LATN_U_CA_GREGORY: &DataStruct = &[
+static SR_LATN_U_CA_CHINESE_SR_LATN_U_CA_DANGI: &DataStruct = &[
     (
         &[
             ::icu_datetime::fields::Field {
@@ -37142,7 +37499,7 @@ static TH_U_CA_BUDDHIST: &DataStruct = &[
         ),
     ),
 ];
-static TH_U_CA_COPTIC_TH_U_CA_ETHIOPIC_TH_U_CA_INDIAN: &DataStruct = &[
+static TH_U_CA_CHINESE_TH_U_CA_DANGI_TH_U_CA_GREGORY: &DataStruct = &[
     (
         &[
             ::icu_datetime::fields::Field {
@@ -37197,7 +37554,7 @@ static TH_U_CA_COPTIC_TH_U_CA_ETHIOPIC_TH_U_CA_INDIAN: &DataStruct = &[
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
                         128u8, 64u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8, 0u8, 0u8, 47u8, 128u8,
-                        16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 5u8,
+                        0u8, 5u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -37367,11 +37724,33 @@ static TH_U_CA_COPTIC_TH_U_CA_ETHIOPIC_TH_U_CA_INDIAN: &DataStruct = &[
             ),
             length: ::icu_datetime::fields::FieldLength::One,
         }],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[128u8, 16u8, 1u8]) },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Month(
+                    ::icu_datetime::fields::Month::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+        ],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 0u8, 1u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
+                        128u8, 32u8, 1u8, 0u8, 0u8, 47u8, 128u8, 16u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -37379,17 +37758,32 @@ static TH_U_CA_COPTIC_TH_U_CA_ETHIOPIC_TH_U_CA_INDIAN: &DataStruct = &[
         ),
     ),
     (
-        &[::icu_datetime::fields::Field {
-            symbol: ::icu_datetime::fields::FieldSymbol::Year(
-                ::icu_datetime::fields::Year::Calendar,
-            ),
-            length: ::icu_datetime::fields::FieldLength::Wide,
-        }],
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Month(
+                    ::icu_datetime::fields::Month::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Day(
+                    ::icu_datetime::fields::Day::DayOfMonth,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+        ],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 0u8, 1u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
+                        128u8, 64u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8, 0u8, 0u8, 47u8, 128u8,
+                        16u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -37402,7 +37796,7 @@ static TH_U_CA_COPTIC_TH_U_CA_ETHIOPIC_TH_U_CA_INDIAN: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
+                length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -37410,13 +37804,25 @@ static TH_U_CA_COPTIC_TH_U_CA_ETHIOPIC_TH_U_CA_INDIAN: &DataStruct = &[
                 ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Day(
+                    ::icu_datetime::fields::Day::DayOfMonth,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Weekday(
+                    ::icu_datetime::fields::Weekday::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
         ],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 32u8, 1u8, 0u8, 0u8, 47u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
-                        0u8, 1u8,
+                        128u8, 80u8, 1u8, 0u8, 0u8, 32u8, 128u8, 64u8, 1u8, 0u8, 0u8, 47u8, 128u8,
+                        32u8, 1u8, 0u8, 0u8, 47u8, 128u8, 16u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -37429,14 +37835,40 @@ static TH_U_CA_COPTIC_TH_U_CA_ETHIOPIC_TH_U_CA_INDIAN: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
+                length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
                     ::icu_datetime::fields::Month::Format,
                 ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 32u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Month(
+                    ::icu_datetime::fields::Month::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Day(
                     ::icu_datetime::fields::Day::DayOfMonth,
@@ -37448,8 +37880,8 @@ static TH_U_CA_COPTIC_TH_U_CA_ETHIOPIC_TH_U_CA_INDIAN: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 64u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8, 0u8, 0u8, 47u8, 128u8,
-                        16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 5u8,
+                        128u8, 64u8, 1u8, 0u8, 0u8, 32u8, 128u8, 32u8, 3u8, 0u8, 0u8, 32u8, 128u8,
+                        16u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -37462,13 +37894,13 @@ static TH_U_CA_COPTIC_TH_U_CA_ETHIOPIC_TH_U_CA_INDIAN: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
+                length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
                     ::icu_datetime::fields::Month::Format,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Day(
@@ -37487,9 +37919,8 @@ static TH_U_CA_COPTIC_TH_U_CA_ETHIOPIC_TH_U_CA_INDIAN: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 80u8, 1u8, 0u8, 0u8, 32u8, 128u8, 64u8, 1u8, 0u8, 0u8, 47u8, 128u8,
-                        32u8, 1u8, 0u8, 0u8, 47u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8,
-                        5u8,
+                        128u8, 80u8, 1u8, 0u8, 0u8, 32u8, 128u8, 64u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        32u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -37502,7 +37933,7 @@ static TH_U_CA_COPTIC_TH_U_CA_ETHIOPIC_TH_U_CA_INDIAN: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
+                length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -37510,13 +37941,26 @@ static TH_U_CA_COPTIC_TH_U_CA_ETHIOPIC_TH_U_CA_INDIAN: &DataStruct = &[
                 ),
                 length: ::icu_datetime::fields::FieldLength::Abbreviated,
             },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Day(
+                    ::icu_datetime::fields::Day::DayOfMonth,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Weekday(
+                    ::icu_datetime::fields::Weekday::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
         ],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 32u8, 3u8, 0u8, 0u8, 32u8, 128u8, 0u8, 1u8, 0u8, 0u8, 32u8, 128u8,
-                        16u8, 1u8,
+                        128u8, 80u8, 4u8, 0u8, 14u8, 23u8, 0u8, 14u8, 53u8, 0u8, 14u8, 72u8, 0u8,
+                        0u8, 32u8, 128u8, 64u8, 1u8, 0u8, 0u8, 32u8, 128u8, 32u8, 3u8, 0u8, 0u8,
+                        32u8, 128u8, 16u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -37529,13 +37973,40 @@ static TH_U_CA_COPTIC_TH_U_CA_ETHIOPIC_TH_U_CA_INDIAN: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Month(
+                    ::icu_datetime::fields::Month::Format,
+                ),
                 length: ::icu_datetime::fields::FieldLength::Wide,
             },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 32u8, 4u8, 0u8, 0u8, 32u8, 128u8, 0u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        16u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
                     ::icu_datetime::fields::Month::Format,
                 ),
-                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+                length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Day(
@@ -37548,7 +38019,7 @@ static TH_U_CA_COPTIC_TH_U_CA_ETHIOPIC_TH_U_CA_INDIAN: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 64u8, 1u8, 0u8, 0u8, 32u8, 128u8, 32u8, 3u8, 0u8, 0u8, 32u8, 128u8,
+                        128u8, 64u8, 1u8, 0u8, 0u8, 32u8, 128u8, 32u8, 4u8, 0u8, 0u8, 32u8, 128u8,
                         0u8, 1u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
                     ])
                 },
@@ -37562,13 +38033,13 @@ static TH_U_CA_COPTIC_TH_U_CA_ETHIOPIC_TH_U_CA_INDIAN: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
+                length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
                     ::icu_datetime::fields::Month::Format,
                 ),
-                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+                length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Day(
@@ -37588,7 +38059,7 @@ static TH_U_CA_COPTIC_TH_U_CA_ETHIOPIC_TH_U_CA_INDIAN: &DataStruct = &[
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
                         128u8, 80u8, 1u8, 0u8, 0u8, 32u8, 128u8, 64u8, 1u8, 0u8, 0u8, 32u8, 128u8,
-                        32u8, 3u8, 0u8, 0u8, 32u8, 128u8, 0u8, 1u8, 0u8, 0u8, 32u8, 128u8, 16u8,
+                        32u8, 4u8, 0u8, 0u8, 32u8, 128u8, 0u8, 1u8, 0u8, 0u8, 32u8, 128u8, 16u8,
                         1u8,
                     ])
                 },
@@ -37602,13 +38073,13 @@ static TH_U_CA_COPTIC_TH_U_CA_ETHIOPIC_TH_U_CA_INDIAN: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
+                length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
                     ::icu_datetime::fields::Month::Format,
                 ),
-                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+                length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Day(
@@ -37628,7 +38099,7 @@ static TH_U_CA_COPTIC_TH_U_CA_ETHIOPIC_TH_U_CA_INDIAN: &DataStruct = &[
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
                         128u8, 80u8, 4u8, 0u8, 14u8, 23u8, 0u8, 14u8, 53u8, 0u8, 14u8, 72u8, 0u8,
-                        0u8, 32u8, 128u8, 64u8, 1u8, 0u8, 0u8, 32u8, 128u8, 32u8, 3u8, 0u8, 0u8,
+                        0u8, 32u8, 128u8, 64u8, 1u8, 0u8, 0u8, 32u8, 128u8, 32u8, 4u8, 0u8, 0u8,
                         32u8, 128u8, 0u8, 1u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
                     ])
                 },
@@ -37642,21 +38113,24 @@ static TH_U_CA_COPTIC_TH_U_CA_ETHIOPIC_TH_U_CA_INDIAN: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
+                length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Month(
-                    ::icu_datetime::fields::Month::Format,
+                symbol: ::icu_datetime::fields::FieldSymbol::Week(
+                    ::icu_datetime::fields::Week::WeekOfYear,
                 ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
+                length: ::icu_datetime::fields::FieldLength::One,
             },
         ],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 32u8, 4u8, 0u8, 0u8, 32u8, 128u8, 0u8, 1u8, 0u8, 0u8, 32u8, 128u8,
-                        16u8, 1u8,
+                        0u8, 14u8, 42u8, 0u8, 14u8, 49u8, 0u8, 14u8, 27u8, 0u8, 14u8, 20u8, 0u8,
+                        14u8, 50u8, 0u8, 14u8, 43u8, 0u8, 14u8, 76u8, 0u8, 14u8, 23u8, 0u8, 14u8,
+                        53u8, 0u8, 14u8, 72u8, 0u8, 0u8, 32u8, 128u8, 48u8, 1u8, 0u8, 0u8, 32u8,
+                        0u8, 14u8, 2u8, 0u8, 14u8, 45u8, 0u8, 14u8, 7u8, 0u8, 14u8, 27u8, 0u8,
+                        14u8, 53u8, 0u8, 0u8, 32u8, 128u8, 17u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -37842,6 +38316,37 @@ static TH_U_CA_COPTIC_TH_U_CA_ETHIOPIC_TH_U_CA_INDIAN: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Month(
+                    ::icu_datetime::fields::Month::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Week(
+                    ::icu_datetime::fields::Week::WeekOfMonth,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        0u8, 14u8, 42u8, 0u8, 14u8, 49u8, 0u8, 14u8, 27u8, 0u8, 14u8, 20u8, 0u8,
+                        14u8, 50u8, 0u8, 14u8, 43u8, 0u8, 14u8, 76u8, 0u8, 14u8, 23u8, 0u8, 14u8,
+                        53u8, 0u8, 14u8, 72u8, 0u8, 0u8, 32u8, 128u8, 49u8, 1u8, 0u8, 0u8, 32u8,
+                        0u8, 14u8, 2u8, 0u8, 14u8, 45u8, 0u8, 14u8, 7u8, 0u8, 14u8, 64u8, 0u8,
+                        14u8, 20u8, 0u8, 14u8, 55u8, 0u8, 14u8, 45u8, 0u8, 14u8, 25u8, 128u8, 32u8,
+                        4u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -37868,6 +38373,72 @@ static TH_U_CA_COPTIC_TH_U_CA_ETHIOPIC_TH_U_CA_INDIAN: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Month(
+                    ::icu_datetime::fields::Month::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Day(
+                    ::icu_datetime::fields::Day::DayOfMonth,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Weekday(
+                    ::icu_datetime::fields::Weekday::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 80u8, 1u8, 0u8, 0u8, 32u8, 128u8, 64u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        32u8, 4u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Month(
+                    ::icu_datetime::fields::Month::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Day(
+                    ::icu_datetime::fields::Day::DayOfMonth,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Weekday(
+                    ::icu_datetime::fields::Weekday::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 80u8, 4u8, 0u8, 14u8, 23u8, 0u8, 14u8, 53u8, 0u8, 14u8, 72u8, 0u8,
+                        0u8, 32u8, 128u8, 64u8, 1u8, 0u8, 0u8, 32u8, 128u8, 32u8, 4u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
     (
         &[::icu_datetime::fields::Field {
             symbol: ::icu_datetime::fields::FieldSymbol::Day(
@@ -38015,7 +38586,7 @@ static TH_U_CA_COPTIC_TH_U_CA_ETHIOPIC_TH_U_CA_INDIAN: &DataStruct = &[
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
                         128u8, 80u8, 1u8, 0u8, 0u8, 32u8, 128u8, 114u8, 2u8, 0u8, 0u8, 58u8, 128u8,
-                        128u8, 2u8,
+                        128u8, 2u8, 0u8, 0u8, 32u8, 0u8, 14u8, 25u8, 0u8, 0u8, 46u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::Minutes,
@@ -38051,35 +38622,229 @@ static TH_U_CA_COPTIC_TH_U_CA_ETHIOPIC_TH_U_CA_INDIAN: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 80u8, 1u8, 0u8, 0u8, 32u8, 128u8, 114u8, 2u8, 0u8, 0u8, 58u8, 128u8,
-                        128u8, 2u8, 0u8, 0u8, 58u8, 128u8, 144u8, 2u8,
+                        128u8, 80u8, 1u8, 0u8, 0u8, 32u8, 128u8, 114u8, 2u8, 0u8, 0u8, 58u8, 128u8,
+                        128u8, 2u8, 0u8, 0u8, 58u8, 128u8, 144u8, 2u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::Seconds,
+            },
+        ),
+    ),
+    (
+        &[::icu_datetime::fields::Field {
+            symbol: ::icu_datetime::fields::FieldSymbol::Hour(::icu_datetime::fields::Hour::H12),
+            length: ::icu_datetime::fields::FieldLength::One,
+        }],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 113u8, 1u8, 0u8, 0u8, 32u8, 128u8, 96u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::Hours,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Hour(
+                    ::icu_datetime::fields::Hour::H12,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Minute,
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 113u8, 1u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8, 0u8, 0u8, 32u8,
+                        128u8, 96u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::Minutes,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Hour(
+                    ::icu_datetime::fields::Hour::H12,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Minute,
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Second(
+                    ::icu_datetime::fields::Second::Second,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 113u8, 1u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8, 0u8, 0u8, 58u8,
+                        128u8, 144u8, 2u8, 0u8, 0u8, 32u8, 128u8, 96u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::Seconds,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Hour(
+                    ::icu_datetime::fields::Hour::H12,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Minute,
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Second(
+                    ::icu_datetime::fields::Second::Second,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::TimeZone(
+                    ::icu_datetime::fields::TimeZone::LowerV,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 113u8, 1u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8, 0u8, 0u8, 58u8,
+                        128u8, 144u8, 2u8, 0u8, 0u8, 32u8, 128u8, 96u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        163u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::Seconds,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Hour(
+                    ::icu_datetime::fields::Hour::H12,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Minute,
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::TimeZone(
+                    ::icu_datetime::fields::TimeZone::LowerV,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 113u8, 1u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8, 0u8, 0u8, 32u8, 0u8,
+                        14u8, 25u8, 0u8, 0u8, 46u8, 0u8, 0u8, 32u8, 128u8, 96u8, 1u8, 0u8, 0u8,
+                        32u8, 128u8, 163u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::Minutes,
+            },
+        ),
+    ),
+    (
+        &[::icu_datetime::fields::Field {
+            symbol: ::icu_datetime::fields::FieldSymbol::Hour(::icu_datetime::fields::Hour::H23),
+            length: ::icu_datetime::fields::FieldLength::One,
+        }],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[128u8, 114u8, 2u8]) },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::Hours,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Hour(
+                    ::icu_datetime::fields::Hour::H23,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Minute,
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 114u8, 2u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8, 0u8, 0u8, 32u8, 0u8,
+                        14u8, 25u8, 0u8, 0u8, 46u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::Minutes,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Hour(
+                    ::icu_datetime::fields::Hour::H23,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Minute,
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Second(
+                    ::icu_datetime::fields::Second::Second,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 114u8, 2u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8, 0u8, 0u8, 58u8,
+                        128u8, 144u8, 2u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::Seconds,
             },
         ),
     ),
-    (
-        &[::icu_datetime::fields::Field {
-            symbol: ::icu_datetime::fields::FieldSymbol::Hour(::icu_datetime::fields::Hour::H12),
-            length: ::icu_datetime::fields::FieldLength::One,
-        }],
-        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
-            ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe {
-                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 113u8, 1u8, 0u8, 0u8, 32u8, 128u8, 96u8, 1u8,
-                    ])
-                },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::Hours,
-            },
-        ),
-    ),
     (
         &[
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Hour(
-                    ::icu_datetime::fields::Hour::H12,
+                    ::icu_datetime::fields::Hour::H23,
                 ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
@@ -38087,34 +38852,15 @@ static TH_U_CA_COPTIC_TH_U_CA_ETHIOPIC_TH_U_CA_INDIAN: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Minute,
                 length: ::icu_datetime::fields::FieldLength::One,
             },
-        ],
-        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
-            ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe {
-                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 113u8, 1u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8, 0u8, 0u8, 32u8,
-                        128u8, 96u8, 1u8,
-                    ])
-                },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::Minutes,
-            },
-        ),
-    ),
-    (
-        &[
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Hour(
-                    ::icu_datetime::fields::Hour::H12,
+                symbol: ::icu_datetime::fields::FieldSymbol::Second(
+                    ::icu_datetime::fields::Second::Second,
                 ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Minute,
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Second(
-                    ::icu_datetime::fields::Second::Second,
+                symbol: ::icu_datetime::fields::FieldSymbol::TimeZone(
+                    ::icu_datetime::fields::TimeZone::LowerV,
                 ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
@@ -38123,26 +38869,14 @@ static TH_U_CA_COPTIC_TH_U_CA_ETHIOPIC_TH_U_CA_INDIAN: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 113u8, 1u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8, 0u8, 0u8, 58u8,
-                        128u8, 144u8, 2u8, 0u8, 0u8, 32u8, 128u8, 96u8, 1u8,
+                        128u8, 114u8, 2u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8, 0u8, 0u8, 58u8,
+                        128u8, 144u8, 2u8, 0u8, 0u8, 32u8, 128u8, 163u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::Seconds,
             },
         ),
     ),
-    (
-        &[::icu_datetime::fields::Field {
-            symbol: ::icu_datetime::fields::FieldSymbol::Hour(::icu_datetime::fields::Hour::H23),
-            length: ::icu_datetime::fields::FieldLength::One,
-        }],
-        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
-            ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[128u8, 114u8, 2u8]) },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::Hours,
-            },
-        ),
-    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -38155,12 +38889,19 @@ static TH_U_CA_COPTIC_TH_U_CA_ETHIOPIC_TH_U_CA_INDIAN: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Minute,
                 length: ::icu_datetime::fields::FieldLength::One,
             },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::TimeZone(
+                    ::icu_datetime::fields::TimeZone::LowerV,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
         ],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 114u8, 2u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8,
+                        128u8, 114u8, 2u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8, 0u8, 0u8, 32u8,
+                        128u8, 163u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::Minutes,
@@ -38169,12 +38910,6 @@ static TH_U_CA_COPTIC_TH_U_CA_ETHIOPIC_TH_U_CA_INDIAN: &DataStruct = &[
     ),
     (
         &[
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Hour(
-                    ::icu_datetime::fields::Hour::H23,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Minute,
                 length: ::icu_datetime::fields::FieldLength::One,
@@ -38190,8 +38925,7 @@ static TH_U_CA_COPTIC_TH_U_CA_ETHIOPIC_TH_U_CA_INDIAN: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 114u8, 2u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8, 0u8, 0u8, 58u8,
-                        128u8, 144u8, 2u8,
+                        128u8, 128u8, 2u8, 0u8, 0u8, 58u8, 128u8, 144u8, 2u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::Seconds,
@@ -38202,13 +38936,13 @@ static TH_U_CA_COPTIC_TH_U_CA_ETHIOPIC_TH_U_CA_INDIAN: &DataStruct = &[
         &[
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Minute,
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::TwoDigit,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Second(
                     ::icu_datetime::fields::Second::Second,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::TwoDigit,
             },
         ],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
@@ -38223,7 +38957,7 @@ static TH_U_CA_COPTIC_TH_U_CA_ETHIOPIC_TH_U_CA_INDIAN: &DataStruct = &[
         ),
     ),
 ];
-static TH_U_CA_GREGORY: &DataStruct = &[
+static TH_U_CA_COPTIC_TH_U_CA_ETHIOPIC_TH_U_CA_INDIAN: &DataStruct = &[
     (
         &[
             ::icu_datetime::fields::Field {
@@ -38278,7 +39012,7 @@ static TH_U_CA_GREGORY: &DataStruct = &[
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
                         128u8, 64u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8, 0u8, 0u8, 47u8, 128u8,
-                        0u8, 5u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
+                        16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 5u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -38448,33 +39182,11 @@ static TH_U_CA_GREGORY: &DataStruct = &[
             ),
             length: ::icu_datetime::fields::FieldLength::One,
         }],
-        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
-            ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[128u8, 16u8, 1u8]) },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
-            },
-        ),
-    ),
-    (
-        &[
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Year(
-                    ::icu_datetime::fields::Year::Calendar,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Month(
-                    ::icu_datetime::fields::Month::Format,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-        ],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 32u8, 1u8, 0u8, 0u8, 47u8, 128u8, 16u8, 1u8,
+                        128u8, 0u8, 1u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -38482,32 +39194,17 @@ static TH_U_CA_GREGORY: &DataStruct = &[
         ),
     ),
     (
-        &[
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Year(
-                    ::icu_datetime::fields::Year::Calendar,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Month(
-                    ::icu_datetime::fields::Month::Format,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Day(
-                    ::icu_datetime::fields::Day::DayOfMonth,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-        ],
+        &[::icu_datetime::fields::Field {
+            symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                ::icu_datetime::fields::Year::Calendar,
+            ),
+            length: ::icu_datetime::fields::FieldLength::Wide,
+        }],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 64u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8, 0u8, 0u8, 47u8, 128u8,
-                        16u8, 1u8,
+                        128u8, 0u8, 1u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -38520,7 +39217,7 @@ static TH_U_CA_GREGORY: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
@@ -38528,25 +39225,13 @@ static TH_U_CA_GREGORY: &DataStruct = &[
                 ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Day(
-                    ::icu_datetime::fields::Day::DayOfMonth,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Weekday(
-                    ::icu_datetime::fields::Weekday::Format,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
         ],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 80u8, 1u8, 0u8, 0u8, 32u8, 128u8, 64u8, 1u8, 0u8, 0u8, 47u8, 128u8,
-                        32u8, 1u8, 0u8, 0u8, 47u8, 128u8, 16u8, 1u8,
+                        128u8, 32u8, 1u8, 0u8, 0u8, 47u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        0u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -38559,40 +39244,14 @@ static TH_U_CA_GREGORY: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
                     ::icu_datetime::fields::Month::Format,
                 ),
-                length: ::icu_datetime::fields::FieldLength::Abbreviated,
-            },
-        ],
-        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
-            ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe {
-                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 32u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
-                    ])
-                },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
-            },
-        ),
-    ),
-    (
-        &[
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Year(
-                    ::icu_datetime::fields::Year::Calendar,
-                ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Month(
-                    ::icu_datetime::fields::Month::Format,
-                ),
-                length: ::icu_datetime::fields::FieldLength::Abbreviated,
-            },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Day(
                     ::icu_datetime::fields::Day::DayOfMonth,
@@ -38604,8 +39263,8 @@ static TH_U_CA_GREGORY: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 64u8, 1u8, 0u8, 0u8, 32u8, 128u8, 32u8, 3u8, 0u8, 0u8, 32u8, 128u8,
-                        16u8, 1u8,
+                        128u8, 64u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8, 0u8, 0u8, 47u8, 128u8,
+                        16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 5u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -38618,13 +39277,13 @@ static TH_U_CA_GREGORY: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
                     ::icu_datetime::fields::Month::Format,
                 ),
-                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+                length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Day(
@@ -38643,8 +39302,9 @@ static TH_U_CA_GREGORY: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 80u8, 1u8, 0u8, 0u8, 32u8, 128u8, 64u8, 1u8, 0u8, 0u8, 32u8, 128u8,
-                        32u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
+                        128u8, 80u8, 1u8, 0u8, 0u8, 32u8, 128u8, 64u8, 1u8, 0u8, 0u8, 47u8, 128u8,
+                        32u8, 1u8, 0u8, 0u8, 47u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8,
+                        5u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -38657,60 +39317,20 @@ static TH_U_CA_GREGORY: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Month(
-                    ::icu_datetime::fields::Month::Format,
-                ),
-                length: ::icu_datetime::fields::FieldLength::Abbreviated,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Day(
-                    ::icu_datetime::fields::Day::DayOfMonth,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Weekday(
-                    ::icu_datetime::fields::Weekday::Format,
-                ),
                 length: ::icu_datetime::fields::FieldLength::Wide,
             },
-        ],
-        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
-            ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe {
-                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 80u8, 4u8, 0u8, 14u8, 23u8, 0u8, 14u8, 53u8, 0u8, 14u8, 72u8, 0u8,
-                        0u8, 32u8, 128u8, 64u8, 1u8, 0u8, 0u8, 32u8, 128u8, 32u8, 3u8, 0u8, 0u8,
-                        32u8, 128u8, 16u8, 1u8,
-                    ])
-                },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
-            },
-        ),
-    ),
-    (
-        &[
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Year(
-                    ::icu_datetime::fields::Year::Calendar,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
                     ::icu_datetime::fields::Month::Format,
                 ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
             },
         ],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 32u8, 4u8, 0u8, 0u8, 32u8, 128u8, 0u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        128u8, 32u8, 3u8, 0u8, 0u8, 32u8, 128u8, 0u8, 1u8, 0u8, 0u8, 32u8, 128u8,
                         16u8, 1u8,
                     ])
                 },
@@ -38724,13 +39344,13 @@ static TH_U_CA_GREGORY: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
                     ::icu_datetime::fields::Month::Format,
                 ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Day(
@@ -38743,7 +39363,7 @@ static TH_U_CA_GREGORY: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 64u8, 1u8, 0u8, 0u8, 32u8, 128u8, 32u8, 4u8, 0u8, 0u8, 32u8, 128u8,
+                        128u8, 64u8, 1u8, 0u8, 0u8, 32u8, 128u8, 32u8, 3u8, 0u8, 0u8, 32u8, 128u8,
                         0u8, 1u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
                     ])
                 },
@@ -38757,13 +39377,13 @@ static TH_U_CA_GREGORY: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
                     ::icu_datetime::fields::Month::Format,
                 ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Day(
@@ -38783,7 +39403,7 @@ static TH_U_CA_GREGORY: &DataStruct = &[
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
                         128u8, 80u8, 1u8, 0u8, 0u8, 32u8, 128u8, 64u8, 1u8, 0u8, 0u8, 32u8, 128u8,
-                        32u8, 4u8, 0u8, 0u8, 32u8, 128u8, 0u8, 1u8, 0u8, 0u8, 32u8, 128u8, 16u8,
+                        32u8, 3u8, 0u8, 0u8, 32u8, 128u8, 0u8, 1u8, 0u8, 0u8, 32u8, 128u8, 16u8,
                         1u8,
                     ])
                 },
@@ -38797,13 +39417,13 @@ static TH_U_CA_GREGORY: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
                     ::icu_datetime::fields::Month::Format,
                 ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
             },
             ::icu_datetime::fields::Field {
                 symbol: ::icu_datetime::fields::FieldSymbol::Day(
@@ -38823,7 +39443,7 @@ static TH_U_CA_GREGORY: &DataStruct = &[
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
                         128u8, 80u8, 4u8, 0u8, 14u8, 23u8, 0u8, 14u8, 53u8, 0u8, 14u8, 72u8, 0u8,
-                        0u8, 32u8, 128u8, 64u8, 1u8, 0u8, 0u8, 32u8, 128u8, 32u8, 4u8, 0u8, 0u8,
+                        0u8, 32u8, 128u8, 64u8, 1u8, 0u8, 0u8, 32u8, 128u8, 32u8, 3u8, 0u8, 0u8,
                         32u8, 128u8, 0u8, 1u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
                     ])
                 },
@@ -38837,24 +39457,21 @@ static TH_U_CA_GREGORY: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Year(
                     ::icu_datetime::fields::Year::Calendar,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Week(
-                    ::icu_datetime::fields::Week::WeekOfYear,
+                symbol: ::icu_datetime::fields::FieldSymbol::Month(
+                    ::icu_datetime::fields::Month::Format,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Wide,
             },
         ],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        0u8, 14u8, 42u8, 0u8, 14u8, 49u8, 0u8, 14u8, 27u8, 0u8, 14u8, 20u8, 0u8,
-                        14u8, 50u8, 0u8, 14u8, 43u8, 0u8, 14u8, 76u8, 0u8, 14u8, 23u8, 0u8, 14u8,
-                        53u8, 0u8, 14u8, 72u8, 0u8, 0u8, 32u8, 128u8, 48u8, 1u8, 0u8, 0u8, 32u8,
-                        0u8, 14u8, 2u8, 0u8, 14u8, 45u8, 0u8, 14u8, 7u8, 0u8, 14u8, 27u8, 0u8,
-                        14u8, 53u8, 0u8, 0u8, 32u8, 128u8, 17u8, 1u8,
+                        128u8, 32u8, 4u8, 0u8, 0u8, 32u8, 128u8, 0u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        16u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -39040,37 +39657,6 @@ static TH_U_CA_GREGORY: &DataStruct = &[
             },
         ),
     ),
-    (
-        &[
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Month(
-                    ::icu_datetime::fields::Month::Format,
-                ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Week(
-                    ::icu_datetime::fields::Week::WeekOfMonth,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-        ],
-        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
-            ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe {
-                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        0u8, 14u8, 42u8, 0u8, 14u8, 49u8, 0u8, 14u8, 27u8, 0u8, 14u8, 20u8, 0u8,
-                        14u8, 50u8, 0u8, 14u8, 43u8, 0u8, 14u8, 76u8, 0u8, 14u8, 23u8, 0u8, 14u8,
-                        53u8, 0u8, 14u8, 72u8, 0u8, 0u8, 32u8, 128u8, 49u8, 1u8, 0u8, 0u8, 32u8,
-                        0u8, 14u8, 2u8, 0u8, 14u8, 45u8, 0u8, 14u8, 7u8, 0u8, 14u8, 64u8, 0u8,
-                        14u8, 20u8, 0u8, 14u8, 55u8, 0u8, 14u8, 45u8, 0u8, 14u8, 25u8, 128u8, 32u8,
-                        4u8,
-                    ])
-                },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
-            },
-        ),
-    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -39097,72 +39683,6 @@ static TH_U_CA_GREGORY: &DataStruct = &[
             },
         ),
     ),
-    (
-        &[
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Month(
-                    ::icu_datetime::fields::Month::Format,
-                ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Day(
-                    ::icu_datetime::fields::Day::DayOfMonth,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Weekday(
-                    ::icu_datetime::fields::Weekday::Format,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-        ],
-        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
-            ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe {
-                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 80u8, 1u8, 0u8, 0u8, 32u8, 128u8, 64u8, 1u8, 0u8, 0u8, 32u8, 128u8,
-                        32u8, 4u8,
-                    ])
-                },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
-            },
-        ),
-    ),
-    (
-        &[
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Month(
-                    ::icu_datetime::fields::Month::Format,
-                ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Day(
-                    ::icu_datetime::fields::Day::DayOfMonth,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Weekday(
-                    ::icu_datetime::fields::Weekday::Format,
-                ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
-            },
-        ],
-        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
-            ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe {
-                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 80u8, 4u8, 0u8, 14u8, 23u8, 0u8, 14u8, 53u8, 0u8, 14u8, 72u8, 0u8,
-                        0u8, 32u8, 128u8, 64u8, 1u8, 0u8, 0u8, 32u8, 128u8, 32u8, 4u8,
-                    ])
-                },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
-            },
-        ),
-    ),
     (
         &[::icu_datetime::fields::Field {
             symbol: ::icu_datetime::fields::FieldSymbol::Day(
@@ -39310,7 +39830,7 @@ static TH_U_CA_GREGORY: &DataStruct = &[
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
                         128u8, 80u8, 1u8, 0u8, 0u8, 32u8, 128u8, 114u8, 2u8, 0u8, 0u8, 58u8, 128u8,
-                        128u8, 2u8, 0u8, 0u8, 32u8, 0u8, 14u8, 25u8, 0u8, 0u8, 46u8,
+                        128u8, 2u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::Minutes,
@@ -39426,76 +39946,6 @@ static TH_U_CA_GREGORY: &DataStruct = &[
             },
         ),
     ),
-    (
-        &[
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Hour(
-                    ::icu_datetime::fields::Hour::H12,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Minute,
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Second(
-                    ::icu_datetime::fields::Second::Second,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::TimeZone(
-                    ::icu_datetime::fields::TimeZone::LowerV,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-        ],
-        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
-            ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe {
-                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 113u8, 1u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8, 0u8, 0u8, 58u8,
-                        128u8, 144u8, 2u8, 0u8, 0u8, 32u8, 128u8, 96u8, 1u8, 0u8, 0u8, 32u8, 128u8,
-                        163u8, 1u8,
-                    ])
-                },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::Seconds,
-            },
-        ),
-    ),
-    (
-        &[
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Hour(
-                    ::icu_datetime::fields::Hour::H12,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Minute,
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::TimeZone(
-                    ::icu_datetime::fields::TimeZone::LowerV,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-        ],
-        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
-            ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe {
-                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 113u8, 1u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8, 0u8, 0u8, 32u8, 0u8,
-                        14u8, 25u8, 0u8, 0u8, 46u8, 0u8, 0u8, 32u8, 128u8, 96u8, 1u8, 0u8, 0u8,
-                        32u8, 128u8, 163u8, 1u8,
-                    ])
-                },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::Minutes,
-            },
-        ),
-    ),
     (
         &[::icu_datetime::fields::Field {
             symbol: ::icu_datetime::fields::FieldSymbol::Hour(::icu_datetime::fields::Hour::H23),
@@ -39525,8 +39975,7 @@ static TH_U_CA_GREGORY: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 114u8, 2u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8, 0u8, 0u8, 32u8, 0u8,
-                        14u8, 25u8, 0u8, 0u8, 46u8,
+                        128u8, 114u8, 2u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::Minutes,
@@ -39564,74 +40013,6 @@ static TH_U_CA_GREGORY: &DataStruct = &[
             },
         ),
     ),
-    (
-        &[
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Hour(
-                    ::icu_datetime::fields::Hour::H23,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Minute,
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Second(
-                    ::icu_datetime::fields::Second::Second,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::TimeZone(
-                    ::icu_datetime::fields::TimeZone::LowerV,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-        ],
-        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
-            ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe {
-                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 114u8, 2u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8, 0u8, 0u8, 58u8,
-                        128u8, 144u8, 2u8, 0u8, 0u8, 32u8, 128u8, 163u8, 1u8,
-                    ])
-                },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::Seconds,
-            },
-        ),
-    ),
-    (
-        &[
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Hour(
-                    ::icu_datetime::fields::Hour::H23,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Minute,
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::TimeZone(
-                    ::icu_datetime::fields::TimeZone::LowerV,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
-        ],
-        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
-            ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe {
-                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 114u8, 2u8, 0u8, 0u8, 58u8, 128u8, 128u8, 2u8, 0u8, 0u8, 32u8,
-                        128u8, 163u8, 1u8,
-                    ])
-                },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::Minutes,
-            },
-        ),
-    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -39656,30 +40037,6 @@ static TH_U_CA_GREGORY: &DataStruct = &[
             },
         ),
     ),
-    (
-        &[
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Minute,
-                length: ::icu_datetime::fields::FieldLength::TwoDigit,
-            },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Second(
-                    ::icu_datetime::fields::Second::Second,
-                ),
-                length: ::icu_datetime::fields::FieldLength::TwoDigit,
-            },
-        ],
-        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
-            ::icu_datetime::pattern::runtime::Pattern {
-                items: unsafe {
-                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 128u8, 2u8, 0u8, 0u8, 58u8, 128u8, 144u8, 2u8,
-                    ])
-                },
-                time_granularity: ::icu_datetime::pattern::TimeGranularity::Seconds,
-            },
-        ),
-    ),
 ];
 static TH_U_CA_JAPANESE_TH_U_CA_JAPANEXT: &DataStruct = &[
     (
@@ -41810,7 +42167,7 @@ static TR_U_CA_BUDDHIST_TR_U_CA_COPTIC_TR_U_CA_ETHIOPIC: &DataStruct = &[
         ),
     ),
 ];
-static TR_U_CA_GREGORY: &DataStruct = &[
+static TR_U_CA_CHINESE_TR_U_CA_DANGI_TR_U_CA_GREGORY: &DataStruct = &[
     (
         &[
             ::icu_datetime::fields::Field {
@@ -43991,7 +44348,7 @@ static UND_U_CA_BUDDHIST_UND_U_CA_COPTIC_UND_U_CA_ETHIOPIC: &DataStruct = &[
         ),
     ),
 ];
-static UND_U_CA_GREGORY: &DataStruct = &[
+static UND_U_CA_CHINESE_UND_U_CA_DANGI_UND_U_CA_GREGORY: &DataStruct = &[
     (
         &[
             ::icu_datetime::fields::Field {
@@ -0,0 +1,2303 @@
+// @generated
+#![cfg(feature = "icu_decimal")]
+type DataStruct =
+    <::icu_decimal::provider::AlgorithmicSymbolsV1Marker as ::icu_provider::DataMarker>::Yokeable;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[
+        ("und-u-nu-armn", UND_U_NU_ARMN),
+        ("und-u-nu-armnlow", UND_U_NU_ARMNLOW),
+        ("und-u-nu-cyrl", UND_U_NU_CYRL),
+        ("und-u-nu-ethi", UND_U_NU_ETHI),
+        ("und-u-nu-geor", UND_U_NU_GEOR),
+        ("und-u-nu-grek", UND_U_NU_GREK),
+        ("und-u-nu-greklow", UND_U_NU_GREKLOW),
+        ("und-u-nu-hebr", UND_U_NU_HEBR),
+        ("und-u-nu-jpan", UND_U_NU_JPAN),
+        ("und-u-nu-jpanfin", UND_U_NU_JPANFIN),
+        ("und-u-nu-jpanyear", UND_U_NU_JPANYEAR),
+        ("und-u-nu-roman", UND_U_NU_ROMAN),
+        ("und-u-nu-romanlow", UND_U_NU_ROMANLOW),
+        ("und-u-nu-taml", UND_U_NU_TAML),
+    ]);
+static UND_U_NU_ARMN: &DataStruct = &::icu_decimal::provider::AlgorithmicSymbolsV1 {
+    rule_set: alloc::borrow::Cow::Borrowed("armenian-upper"),
+    rules: ::icu_decimal::provider::RbnfRulesV1 {
+        rule_sets: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 114u8, 109u8, 101u8, 110u8, 105u8,
+                        97u8, 110u8, 45u8, 117u8, 112u8, 112u8, 101u8, 114u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8, 67u8, 0u8, 0u8, 0u8])
+                },
+            )
+        },
+        rules: unsafe {
+            ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                67u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 53u8, 0u8, 0u8, 0u8,
+                71u8, 0u8, 0u8, 0u8, 90u8, 0u8, 0u8, 0u8, 109u8, 0u8, 0u8, 0u8, 128u8, 0u8, 0u8,
+                0u8, 147u8, 0u8, 0u8, 0u8, 166u8, 0u8, 0u8, 0u8, 185u8, 0u8, 0u8, 0u8, 204u8, 0u8,
+                0u8, 0u8, 223u8, 0u8, 0u8, 0u8, 242u8, 0u8, 0u8, 0u8, 5u8, 1u8, 0u8, 0u8, 30u8,
+                1u8, 0u8, 0u8, 49u8, 1u8, 0u8, 0u8, 74u8, 1u8, 0u8, 0u8, 93u8, 1u8, 0u8, 0u8,
+                118u8, 1u8, 0u8, 0u8, 137u8, 1u8, 0u8, 0u8, 162u8, 1u8, 0u8, 0u8, 181u8, 1u8, 0u8,
+                0u8, 206u8, 1u8, 0u8, 0u8, 225u8, 1u8, 0u8, 0u8, 250u8, 1u8, 0u8, 0u8, 13u8, 2u8,
+                0u8, 0u8, 38u8, 2u8, 0u8, 0u8, 57u8, 2u8, 0u8, 0u8, 82u8, 2u8, 0u8, 0u8, 101u8,
+                2u8, 0u8, 0u8, 126u8, 2u8, 0u8, 0u8, 145u8, 2u8, 0u8, 0u8, 170u8, 2u8, 0u8, 0u8,
+                189u8, 2u8, 0u8, 0u8, 214u8, 2u8, 0u8, 0u8, 233u8, 2u8, 0u8, 0u8, 2u8, 3u8, 0u8,
+                0u8, 21u8, 3u8, 0u8, 0u8, 46u8, 3u8, 0u8, 0u8, 65u8, 3u8, 0u8, 0u8, 90u8, 3u8, 0u8,
+                0u8, 109u8, 3u8, 0u8, 0u8, 134u8, 3u8, 0u8, 0u8, 153u8, 3u8, 0u8, 0u8, 178u8, 3u8,
+                0u8, 0u8, 197u8, 3u8, 0u8, 0u8, 222u8, 3u8, 0u8, 0u8, 241u8, 3u8, 0u8, 0u8, 10u8,
+                4u8, 0u8, 0u8, 29u8, 4u8, 0u8, 0u8, 54u8, 4u8, 0u8, 0u8, 73u8, 4u8, 0u8, 0u8, 98u8,
+                4u8, 0u8, 0u8, 117u8, 4u8, 0u8, 0u8, 142u8, 4u8, 0u8, 0u8, 161u8, 4u8, 0u8, 0u8,
+                186u8, 4u8, 0u8, 0u8, 205u8, 4u8, 0u8, 0u8, 230u8, 4u8, 0u8, 0u8, 249u8, 4u8, 0u8,
+                0u8, 18u8, 5u8, 0u8, 0u8, 37u8, 5u8, 0u8, 0u8, 62u8, 5u8, 0u8, 0u8, 81u8, 5u8, 0u8,
+                0u8, 106u8, 5u8, 0u8, 0u8, 125u8, 5u8, 0u8, 0u8, 150u8, 5u8, 0u8, 0u8, 1u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8,
+                136u8, 146u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8,
+                35u8, 48u8, 46u8, 48u8, 48u8, 61u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 48u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 212u8, 177u8, 0u8, 2u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 212u8, 178u8, 0u8,
+                3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                212u8, 179u8, 0u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 212u8, 180u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 212u8, 181u8, 0u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 212u8, 182u8, 0u8, 7u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 212u8, 183u8, 0u8, 8u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 212u8,
+                184u8, 0u8, 9u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 212u8, 185u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 212u8, 186u8, 0u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 212u8, 186u8, 226u8, 134u8,
+                146u8, 226u8, 134u8, 146u8, 0u8, 20u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 212u8, 187u8, 0u8, 21u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 212u8, 187u8, 226u8, 134u8,
+                146u8, 226u8, 134u8, 146u8, 0u8, 30u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 212u8, 188u8, 0u8, 31u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 212u8, 188u8, 226u8, 134u8,
+                146u8, 226u8, 134u8, 146u8, 0u8, 40u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 212u8, 189u8, 0u8, 41u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 212u8, 189u8, 226u8, 134u8,
+                146u8, 226u8, 134u8, 146u8, 0u8, 50u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 212u8, 190u8, 0u8, 51u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 212u8, 190u8, 226u8, 134u8,
+                146u8, 226u8, 134u8, 146u8, 0u8, 60u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 212u8, 191u8, 0u8, 61u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 212u8, 191u8, 226u8, 134u8,
+                146u8, 226u8, 134u8, 146u8, 0u8, 70u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 128u8, 0u8, 71u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 128u8, 226u8, 134u8,
+                146u8, 226u8, 134u8, 146u8, 0u8, 80u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 129u8, 0u8, 81u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 129u8, 226u8, 134u8,
+                146u8, 226u8, 134u8, 146u8, 0u8, 90u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 130u8, 0u8, 91u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 130u8, 226u8, 134u8,
+                146u8, 226u8, 134u8, 146u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 131u8, 0u8, 101u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 131u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 200u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 132u8, 0u8, 201u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 132u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 44u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 133u8, 0u8, 45u8, 1u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 133u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 144u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 134u8, 0u8, 145u8, 1u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 134u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 244u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 135u8, 0u8, 245u8, 1u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 135u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 88u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 136u8, 0u8, 89u8, 2u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 136u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 188u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 137u8, 0u8, 189u8, 2u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 137u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 32u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 138u8, 0u8, 33u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 138u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 132u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 139u8, 0u8, 133u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 139u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 140u8, 0u8, 233u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 140u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 208u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 141u8, 0u8, 209u8, 7u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 141u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 184u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 142u8, 0u8, 185u8, 11u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 142u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 160u8, 15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 143u8, 0u8, 161u8, 15u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 143u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 136u8, 19u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 144u8, 0u8, 137u8, 19u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 144u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 112u8, 23u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 145u8, 0u8, 113u8, 23u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 145u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 88u8, 27u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 146u8, 0u8, 89u8, 27u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 146u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 64u8, 31u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 147u8, 0u8, 65u8, 31u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 147u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 40u8, 35u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 148u8, 0u8, 41u8, 35u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 148u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 16u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                16u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 61u8,
+            ])
+        },
+    },
+};
+static UND_U_NU_ARMNLOW: &DataStruct = &::icu_decimal::provider::AlgorithmicSymbolsV1 {
+    rule_set: alloc::borrow::Cow::Borrowed("armenian-lower"),
+    rules: ::icu_decimal::provider::RbnfRulesV1 {
+        rule_sets: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 114u8, 109u8, 101u8, 110u8, 105u8,
+                        97u8, 110u8, 45u8, 108u8, 111u8, 119u8, 101u8, 114u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8, 67u8, 0u8, 0u8, 0u8])
+                },
+            )
+        },
+        rules: unsafe {
+            ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                67u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 53u8, 0u8, 0u8, 0u8,
+                71u8, 0u8, 0u8, 0u8, 90u8, 0u8, 0u8, 0u8, 109u8, 0u8, 0u8, 0u8, 128u8, 0u8, 0u8,
+                0u8, 147u8, 0u8, 0u8, 0u8, 166u8, 0u8, 0u8, 0u8, 185u8, 0u8, 0u8, 0u8, 204u8, 0u8,
+                0u8, 0u8, 223u8, 0u8, 0u8, 0u8, 242u8, 0u8, 0u8, 0u8, 5u8, 1u8, 0u8, 0u8, 30u8,
+                1u8, 0u8, 0u8, 49u8, 1u8, 0u8, 0u8, 74u8, 1u8, 0u8, 0u8, 93u8, 1u8, 0u8, 0u8,
+                118u8, 1u8, 0u8, 0u8, 137u8, 1u8, 0u8, 0u8, 162u8, 1u8, 0u8, 0u8, 181u8, 1u8, 0u8,
+                0u8, 206u8, 1u8, 0u8, 0u8, 225u8, 1u8, 0u8, 0u8, 250u8, 1u8, 0u8, 0u8, 13u8, 2u8,
+                0u8, 0u8, 38u8, 2u8, 0u8, 0u8, 57u8, 2u8, 0u8, 0u8, 82u8, 2u8, 0u8, 0u8, 101u8,
+                2u8, 0u8, 0u8, 126u8, 2u8, 0u8, 0u8, 145u8, 2u8, 0u8, 0u8, 170u8, 2u8, 0u8, 0u8,
+                189u8, 2u8, 0u8, 0u8, 214u8, 2u8, 0u8, 0u8, 233u8, 2u8, 0u8, 0u8, 2u8, 3u8, 0u8,
+                0u8, 21u8, 3u8, 0u8, 0u8, 46u8, 3u8, 0u8, 0u8, 65u8, 3u8, 0u8, 0u8, 90u8, 3u8, 0u8,
+                0u8, 109u8, 3u8, 0u8, 0u8, 134u8, 3u8, 0u8, 0u8, 153u8, 3u8, 0u8, 0u8, 178u8, 3u8,
+                0u8, 0u8, 197u8, 3u8, 0u8, 0u8, 222u8, 3u8, 0u8, 0u8, 241u8, 3u8, 0u8, 0u8, 10u8,
+                4u8, 0u8, 0u8, 29u8, 4u8, 0u8, 0u8, 54u8, 4u8, 0u8, 0u8, 73u8, 4u8, 0u8, 0u8, 98u8,
+                4u8, 0u8, 0u8, 117u8, 4u8, 0u8, 0u8, 142u8, 4u8, 0u8, 0u8, 161u8, 4u8, 0u8, 0u8,
+                186u8, 4u8, 0u8, 0u8, 205u8, 4u8, 0u8, 0u8, 230u8, 4u8, 0u8, 0u8, 249u8, 4u8, 0u8,
+                0u8, 18u8, 5u8, 0u8, 0u8, 37u8, 5u8, 0u8, 0u8, 62u8, 5u8, 0u8, 0u8, 81u8, 5u8, 0u8,
+                0u8, 106u8, 5u8, 0u8, 0u8, 125u8, 5u8, 0u8, 0u8, 150u8, 5u8, 0u8, 0u8, 1u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8,
+                136u8, 146u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8,
+                35u8, 48u8, 46u8, 48u8, 48u8, 61u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 48u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 161u8, 0u8, 2u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 162u8, 0u8,
+                3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                213u8, 163u8, 0u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 213u8, 164u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 165u8, 0u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 166u8, 0u8, 7u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 167u8, 0u8, 8u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8,
+                168u8, 0u8, 9u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 213u8, 169u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 170u8, 0u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 170u8, 226u8, 134u8,
+                146u8, 226u8, 134u8, 146u8, 0u8, 20u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 171u8, 0u8, 21u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 171u8, 226u8, 134u8,
+                146u8, 226u8, 134u8, 146u8, 0u8, 30u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 172u8, 0u8, 31u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 172u8, 226u8, 134u8,
+                146u8, 226u8, 134u8, 146u8, 0u8, 40u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 173u8, 0u8, 41u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 173u8, 226u8, 134u8,
+                146u8, 226u8, 134u8, 146u8, 0u8, 50u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 174u8, 0u8, 51u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 174u8, 226u8, 134u8,
+                146u8, 226u8, 134u8, 146u8, 0u8, 60u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 175u8, 0u8, 61u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 175u8, 226u8, 134u8,
+                146u8, 226u8, 134u8, 146u8, 0u8, 70u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 176u8, 0u8, 71u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 176u8, 226u8, 134u8,
+                146u8, 226u8, 134u8, 146u8, 0u8, 80u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 177u8, 0u8, 81u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 177u8, 226u8, 134u8,
+                146u8, 226u8, 134u8, 146u8, 0u8, 90u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 178u8, 0u8, 91u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 178u8, 226u8, 134u8,
+                146u8, 226u8, 134u8, 146u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 179u8, 0u8, 101u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 179u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 200u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 180u8, 0u8, 201u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 180u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 44u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 181u8, 0u8, 45u8, 1u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 181u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 144u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 182u8, 0u8, 145u8, 1u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 182u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 244u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 183u8, 0u8, 245u8, 1u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 183u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 88u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 184u8, 0u8, 89u8, 2u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 184u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 188u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 185u8, 0u8, 189u8, 2u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 185u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 32u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 186u8, 0u8, 33u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 186u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 132u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 187u8, 0u8, 133u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 187u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 188u8, 0u8, 233u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 188u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 208u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 189u8, 0u8, 209u8, 7u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 189u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 184u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 190u8, 0u8, 185u8, 11u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 190u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 160u8, 15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 191u8, 0u8, 161u8, 15u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 191u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 136u8, 19u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 214u8, 128u8, 0u8, 137u8, 19u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 214u8, 128u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 112u8, 23u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 214u8, 129u8, 0u8, 113u8, 23u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 214u8, 129u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 88u8, 27u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 214u8, 130u8, 0u8, 89u8, 27u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 214u8, 130u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 64u8, 31u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 214u8, 131u8, 0u8, 65u8, 31u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 214u8, 131u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 40u8, 35u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 214u8, 132u8, 0u8, 41u8, 35u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 214u8, 132u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 16u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                16u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 61u8,
+            ])
+        },
+    },
+};
+static UND_U_NU_CYRL: &DataStruct = &::icu_decimal::provider::AlgorithmicSymbolsV1 {
+    rule_set: alloc::borrow::Cow::Borrowed("cyrillic-lower"),
+    rules: ::icu_decimal::provider::RbnfRulesV1 {
+        rule_sets: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 14u8, 0u8, 33u8, 0u8, 53u8, 0u8, 72u8, 0u8,
+                        99u8, 121u8, 114u8, 105u8, 108u8, 108u8, 105u8, 99u8, 45u8, 108u8, 111u8,
+                        119u8, 101u8, 114u8, 99u8, 121u8, 114u8, 105u8, 108u8, 108u8, 105u8, 99u8,
+                        45u8, 108u8, 111u8, 119u8, 101u8, 114u8, 45u8, 49u8, 45u8, 49u8, 48u8,
+                        99u8, 121u8, 114u8, 105u8, 108u8, 108u8, 105u8, 99u8, 45u8, 108u8, 111u8,
+                        119u8, 101u8, 114u8, 45u8, 102u8, 105u8, 110u8, 97u8, 108u8, 99u8, 121u8,
+                        114u8, 105u8, 108u8, 108u8, 105u8, 99u8, 45u8, 108u8, 111u8, 119u8, 101u8,
+                        114u8, 45u8, 112u8, 111u8, 115u8, 116u8, 99u8, 121u8, 114u8, 105u8, 108u8,
+                        108u8, 105u8, 99u8, 45u8, 108u8, 111u8, 119u8, 101u8, 114u8, 45u8, 116u8,
+                        104u8, 111u8, 117u8, 115u8, 97u8, 110u8, 100u8, 115u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        76u8, 0u8, 121u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 1u8, 0u8, 10u8, 0u8,
+                        37u8, 0u8, 1u8, 0u8, 37u8, 0u8, 39u8, 0u8, 1u8, 0u8, 39u8, 0u8, 76u8, 0u8,
+                        1u8, 0u8,
+                    ])
+                },
+            )
+        },
+        rules: unsafe {
+            ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                121u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 38u8, 0u8, 0u8, 0u8,
+                57u8, 0u8, 0u8, 0u8, 76u8, 0u8, 0u8, 0u8, 95u8, 0u8, 0u8, 0u8, 114u8, 0u8, 0u8,
+                0u8, 133u8, 0u8, 0u8, 0u8, 152u8, 0u8, 0u8, 0u8, 171u8, 0u8, 0u8, 0u8, 190u8, 0u8,
+                0u8, 0u8, 209u8, 0u8, 0u8, 0u8, 251u8, 0u8, 0u8, 0u8, 18u8, 1u8, 0u8, 0u8, 41u8,
+                1u8, 0u8, 0u8, 64u8, 1u8, 0u8, 0u8, 87u8, 1u8, 0u8, 0u8, 110u8, 1u8, 0u8, 0u8,
+                133u8, 1u8, 0u8, 0u8, 156u8, 1u8, 0u8, 0u8, 179u8, 1u8, 0u8, 0u8, 202u8, 1u8, 0u8,
+                0u8, 223u8, 1u8, 0u8, 0u8, 248u8, 1u8, 0u8, 0u8, 13u8, 2u8, 0u8, 0u8, 38u8, 2u8,
+                0u8, 0u8, 59u8, 2u8, 0u8, 0u8, 84u8, 2u8, 0u8, 0u8, 105u8, 2u8, 0u8, 0u8, 130u8,
+                2u8, 0u8, 0u8, 151u8, 2u8, 0u8, 0u8, 176u8, 2u8, 0u8, 0u8, 197u8, 2u8, 0u8, 0u8,
+                222u8, 2u8, 0u8, 0u8, 243u8, 2u8, 0u8, 0u8, 12u8, 3u8, 0u8, 0u8, 33u8, 3u8, 0u8,
+                0u8, 58u8, 3u8, 0u8, 0u8, 77u8, 3u8, 0u8, 0u8, 111u8, 3u8, 0u8, 0u8, 130u8, 3u8,
+                0u8, 0u8, 153u8, 3u8, 0u8, 0u8, 176u8, 3u8, 0u8, 0u8, 199u8, 3u8, 0u8, 0u8, 222u8,
+                3u8, 0u8, 0u8, 245u8, 3u8, 0u8, 0u8, 12u8, 4u8, 0u8, 0u8, 35u8, 4u8, 0u8, 0u8,
+                58u8, 4u8, 0u8, 0u8, 81u8, 4u8, 0u8, 0u8, 104u8, 4u8, 0u8, 0u8, 131u8, 4u8, 0u8,
+                0u8, 158u8, 4u8, 0u8, 0u8, 185u8, 4u8, 0u8, 0u8, 212u8, 4u8, 0u8, 0u8, 239u8, 4u8,
+                0u8, 0u8, 10u8, 5u8, 0u8, 0u8, 37u8, 5u8, 0u8, 0u8, 64u8, 5u8, 0u8, 0u8, 91u8, 5u8,
+                0u8, 0u8, 118u8, 5u8, 0u8, 0u8, 145u8, 5u8, 0u8, 0u8, 172u8, 5u8, 0u8, 0u8, 199u8,
+                5u8, 0u8, 0u8, 226u8, 5u8, 0u8, 0u8, 253u8, 5u8, 0u8, 0u8, 24u8, 6u8, 0u8, 0u8,
+                51u8, 6u8, 0u8, 0u8, 78u8, 6u8, 0u8, 0u8, 105u8, 6u8, 0u8, 0u8, 132u8, 6u8, 0u8,
+                0u8, 159u8, 6u8, 0u8, 0u8, 186u8, 6u8, 0u8, 0u8, 213u8, 6u8, 0u8, 0u8, 240u8, 6u8,
+                0u8, 0u8, 11u8, 7u8, 0u8, 0u8, 38u8, 7u8, 0u8, 0u8, 64u8, 7u8, 0u8, 0u8, 97u8, 7u8,
+                0u8, 0u8, 117u8, 7u8, 0u8, 0u8, 159u8, 7u8, 0u8, 0u8, 182u8, 7u8, 0u8, 0u8, 205u8,
+                7u8, 0u8, 0u8, 228u8, 7u8, 0u8, 0u8, 251u8, 7u8, 0u8, 0u8, 18u8, 8u8, 0u8, 0u8,
+                41u8, 8u8, 0u8, 0u8, 64u8, 8u8, 0u8, 0u8, 87u8, 8u8, 0u8, 0u8, 110u8, 8u8, 0u8,
+                0u8, 157u8, 8u8, 0u8, 0u8, 204u8, 8u8, 0u8, 0u8, 251u8, 8u8, 0u8, 0u8, 42u8, 9u8,
+                0u8, 0u8, 89u8, 9u8, 0u8, 0u8, 136u8, 9u8, 0u8, 0u8, 183u8, 9u8, 0u8, 0u8, 230u8,
+                9u8, 0u8, 0u8, 21u8, 10u8, 0u8, 0u8, 68u8, 10u8, 0u8, 0u8, 115u8, 10u8, 0u8, 0u8,
+                162u8, 10u8, 0u8, 0u8, 209u8, 10u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 47u8, 11u8, 0u8,
+                0u8, 68u8, 11u8, 0u8, 0u8, 93u8, 11u8, 0u8, 0u8, 140u8, 11u8, 0u8, 0u8, 213u8,
+                11u8, 0u8, 0u8, 238u8, 11u8, 0u8, 0u8, 14u8, 12u8, 0u8, 0u8, 63u8, 12u8, 0u8, 0u8,
+                119u8, 12u8, 0u8, 0u8, 146u8, 12u8, 0u8, 0u8, 180u8, 12u8, 0u8, 0u8, 209u8, 12u8,
+                0u8, 0u8, 245u8, 12u8, 0u8, 0u8, 20u8, 13u8, 0u8, 0u8, 58u8, 13u8, 0u8, 0u8, 91u8,
+                13u8, 0u8, 0u8, 131u8, 13u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 176u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 178u8, 0u8, 3u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 179u8,
+                0u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 208u8, 180u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 209u8, 148u8, 0u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 209u8, 149u8, 0u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 183u8, 0u8, 8u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 184u8, 0u8,
+                9u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                209u8, 179u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 209u8, 150u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 210u8, 131u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 210u8, 131u8, 61u8, 37u8, 37u8,
+                99u8, 121u8, 114u8, 105u8, 108u8, 108u8, 105u8, 99u8, 45u8, 108u8, 111u8, 119u8,
+                101u8, 114u8, 45u8, 49u8, 45u8, 49u8, 48u8, 61u8, 0u8, 11u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 176u8, 210u8, 131u8,
+                209u8, 150u8, 0u8, 12u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 208u8, 178u8, 210u8, 131u8, 209u8, 150u8, 0u8, 13u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 179u8,
+                210u8, 131u8, 209u8, 150u8, 0u8, 14u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 180u8, 210u8, 131u8, 209u8, 150u8, 0u8,
+                15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                209u8, 148u8, 210u8, 131u8, 209u8, 150u8, 0u8, 16u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 209u8, 149u8, 210u8, 131u8, 209u8,
+                150u8, 0u8, 17u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 208u8, 183u8, 210u8, 131u8, 209u8, 150u8, 0u8, 18u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 184u8, 210u8, 131u8,
+                209u8, 150u8, 0u8, 19u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 209u8, 179u8, 210u8, 131u8, 209u8, 150u8, 0u8, 20u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 210u8, 131u8,
+                208u8, 186u8, 0u8, 21u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 208u8, 186u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8,
+                30u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                210u8, 131u8, 208u8, 187u8, 0u8, 31u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 187u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 40u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 210u8, 131u8, 208u8, 188u8, 0u8, 41u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 188u8, 226u8, 134u8, 146u8, 226u8,
+                134u8, 146u8, 0u8, 50u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 210u8, 131u8, 208u8, 189u8, 0u8, 51u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 189u8, 226u8, 134u8,
+                146u8, 226u8, 134u8, 146u8, 0u8, 60u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 210u8, 131u8, 209u8, 175u8, 0u8, 61u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 209u8, 175u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 70u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 210u8, 131u8, 209u8, 187u8, 0u8,
+                71u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                209u8, 187u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 80u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 210u8, 131u8, 208u8,
+                191u8, 0u8, 81u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 208u8, 191u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 90u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 210u8,
+                131u8, 209u8, 135u8, 0u8, 91u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 209u8, 135u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 210u8, 131u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 61u8, 37u8, 99u8, 121u8, 114u8, 105u8, 108u8, 108u8, 105u8,
+                99u8, 45u8, 108u8, 111u8, 119u8, 101u8, 114u8, 61u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 210u8, 131u8, 0u8, 1u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 210u8, 131u8,
+                210u8, 130u8, 208u8, 176u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 210u8, 131u8, 210u8, 130u8, 208u8, 178u8, 0u8, 3u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 210u8,
+                131u8, 210u8, 130u8, 208u8, 179u8, 0u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 210u8, 131u8, 210u8, 130u8, 208u8, 180u8,
+                0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 210u8, 131u8, 210u8, 130u8, 209u8, 148u8, 0u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 210u8, 131u8, 210u8, 130u8,
+                209u8, 149u8, 0u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 210u8, 131u8, 210u8, 130u8, 208u8, 183u8, 0u8, 8u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 210u8, 131u8, 210u8,
+                130u8, 208u8, 184u8, 0u8, 9u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 210u8, 131u8, 210u8, 130u8, 209u8, 179u8, 0u8, 10u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 210u8,
+                131u8, 210u8, 130u8, 209u8, 150u8, 0u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 210u8, 130u8, 208u8, 176u8, 210u8, 131u8,
+                210u8, 130u8, 209u8, 150u8, 0u8, 12u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 210u8, 130u8, 208u8, 178u8, 210u8, 131u8, 210u8,
+                130u8, 209u8, 150u8, 0u8, 13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 210u8, 130u8, 208u8, 179u8, 210u8, 131u8, 210u8, 130u8,
+                209u8, 150u8, 0u8, 14u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 210u8, 130u8, 208u8, 180u8, 210u8, 131u8, 210u8, 130u8, 209u8,
+                150u8, 0u8, 15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 210u8, 130u8, 209u8, 148u8, 210u8, 131u8, 210u8, 130u8, 209u8, 150u8,
+                0u8, 16u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 210u8, 130u8, 209u8, 149u8, 210u8, 131u8, 210u8, 130u8, 209u8, 150u8, 0u8,
+                17u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                210u8, 130u8, 208u8, 183u8, 210u8, 131u8, 210u8, 130u8, 209u8, 150u8, 0u8, 18u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 210u8,
+                130u8, 208u8, 184u8, 210u8, 131u8, 210u8, 130u8, 209u8, 150u8, 0u8, 19u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 210u8, 130u8,
+                209u8, 179u8, 210u8, 131u8, 210u8, 130u8, 209u8, 150u8, 0u8, 20u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 210u8, 130u8, 208u8,
+                186u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 30u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 210u8, 130u8, 208u8, 187u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 40u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 210u8, 130u8, 208u8, 188u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 50u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 210u8, 130u8, 208u8, 189u8, 226u8, 134u8,
+                146u8, 226u8, 134u8, 146u8, 0u8, 60u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 210u8, 130u8, 209u8, 175u8, 226u8, 134u8, 146u8,
+                226u8, 134u8, 146u8, 0u8, 70u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 210u8, 130u8, 209u8, 187u8, 226u8, 134u8, 146u8, 226u8,
+                134u8, 146u8, 0u8, 80u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 210u8, 130u8, 208u8, 191u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 90u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 210u8, 130u8, 209u8, 135u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8,
+                0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 210u8, 130u8, 209u8, 128u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8,
+                200u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                210u8, 130u8, 209u8, 129u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 44u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 210u8,
+                130u8, 209u8, 130u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 144u8, 1u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 210u8,
+                130u8, 209u8, 131u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 244u8, 1u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 210u8,
+                130u8, 209u8, 132u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 88u8, 2u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 210u8, 130u8,
+                209u8, 133u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 188u8, 2u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 210u8, 130u8, 209u8,
+                177u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 32u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 210u8, 130u8, 209u8,
+                191u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 132u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 210u8, 130u8, 209u8,
+                134u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 136u8, 146u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8,
+                46u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 48u8, 210u8,
+                131u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 61u8, 37u8, 37u8, 99u8, 121u8, 114u8, 105u8, 108u8, 108u8, 105u8, 99u8,
+                45u8, 108u8, 111u8, 119u8, 101u8, 114u8, 45u8, 49u8, 45u8, 49u8, 48u8, 61u8, 210u8,
+                131u8, 0u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 208u8, 176u8, 210u8, 131u8, 209u8, 150u8, 0u8, 12u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 178u8, 210u8, 131u8,
+                209u8, 150u8, 0u8, 13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 208u8, 179u8, 210u8, 131u8, 209u8, 150u8, 0u8, 14u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 180u8,
+                210u8, 131u8, 209u8, 150u8, 0u8, 15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 209u8, 148u8, 210u8, 131u8, 209u8, 150u8, 0u8,
+                16u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                209u8, 149u8, 210u8, 131u8, 209u8, 150u8, 0u8, 17u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 183u8, 210u8, 131u8, 209u8,
+                150u8, 0u8, 18u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 208u8, 184u8, 210u8, 131u8, 209u8, 150u8, 0u8, 19u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 209u8, 179u8, 210u8, 131u8,
+                209u8, 150u8, 0u8, 20u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 208u8, 186u8, 226u8, 134u8, 146u8, 37u8, 37u8, 99u8, 121u8,
+                114u8, 105u8, 108u8, 108u8, 105u8, 99u8, 45u8, 108u8, 111u8, 119u8, 101u8, 114u8,
+                45u8, 102u8, 105u8, 110u8, 97u8, 108u8, 226u8, 134u8, 146u8, 0u8, 30u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 187u8,
+                226u8, 134u8, 146u8, 37u8, 37u8, 99u8, 121u8, 114u8, 105u8, 108u8, 108u8, 105u8,
+                99u8, 45u8, 108u8, 111u8, 119u8, 101u8, 114u8, 45u8, 102u8, 105u8, 110u8, 97u8,
+                108u8, 226u8, 134u8, 146u8, 0u8, 40u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 188u8, 226u8, 134u8, 146u8, 37u8, 37u8,
+                99u8, 121u8, 114u8, 105u8, 108u8, 108u8, 105u8, 99u8, 45u8, 108u8, 111u8, 119u8,
+                101u8, 114u8, 45u8, 102u8, 105u8, 110u8, 97u8, 108u8, 226u8, 134u8, 146u8, 0u8,
+                50u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                208u8, 189u8, 226u8, 134u8, 146u8, 37u8, 37u8, 99u8, 121u8, 114u8, 105u8, 108u8,
+                108u8, 105u8, 99u8, 45u8, 108u8, 111u8, 119u8, 101u8, 114u8, 45u8, 102u8, 105u8,
+                110u8, 97u8, 108u8, 226u8, 134u8, 146u8, 0u8, 60u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 209u8, 175u8, 226u8, 134u8, 146u8,
+                37u8, 37u8, 99u8, 121u8, 114u8, 105u8, 108u8, 108u8, 105u8, 99u8, 45u8, 108u8,
+                111u8, 119u8, 101u8, 114u8, 45u8, 102u8, 105u8, 110u8, 97u8, 108u8, 226u8, 134u8,
+                146u8, 0u8, 70u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 209u8, 187u8, 226u8, 134u8, 146u8, 37u8, 37u8, 99u8, 121u8, 114u8, 105u8,
+                108u8, 108u8, 105u8, 99u8, 45u8, 108u8, 111u8, 119u8, 101u8, 114u8, 45u8, 102u8,
+                105u8, 110u8, 97u8, 108u8, 226u8, 134u8, 146u8, 0u8, 80u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 191u8, 226u8, 134u8,
+                146u8, 37u8, 37u8, 99u8, 121u8, 114u8, 105u8, 108u8, 108u8, 105u8, 99u8, 45u8,
+                108u8, 111u8, 119u8, 101u8, 114u8, 45u8, 102u8, 105u8, 110u8, 97u8, 108u8, 226u8,
+                134u8, 146u8, 0u8, 90u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 209u8, 135u8, 226u8, 134u8, 146u8, 37u8, 37u8, 99u8, 121u8,
+                114u8, 105u8, 108u8, 108u8, 105u8, 99u8, 45u8, 108u8, 111u8, 119u8, 101u8, 114u8,
+                45u8, 102u8, 105u8, 110u8, 97u8, 108u8, 226u8, 134u8, 146u8, 0u8, 100u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 209u8, 128u8,
+                226u8, 134u8, 146u8, 37u8, 37u8, 99u8, 121u8, 114u8, 105u8, 108u8, 108u8, 105u8,
+                99u8, 45u8, 108u8, 111u8, 119u8, 101u8, 114u8, 45u8, 102u8, 105u8, 110u8, 97u8,
+                108u8, 226u8, 134u8, 146u8, 0u8, 200u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 209u8, 129u8, 226u8, 134u8, 146u8, 37u8, 37u8,
+                99u8, 121u8, 114u8, 105u8, 108u8, 108u8, 105u8, 99u8, 45u8, 108u8, 111u8, 119u8,
+                101u8, 114u8, 45u8, 102u8, 105u8, 110u8, 97u8, 108u8, 226u8, 134u8, 146u8, 0u8,
+                44u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                209u8, 130u8, 226u8, 134u8, 146u8, 37u8, 37u8, 99u8, 121u8, 114u8, 105u8, 108u8,
+                108u8, 105u8, 99u8, 45u8, 108u8, 111u8, 119u8, 101u8, 114u8, 45u8, 102u8, 105u8,
+                110u8, 97u8, 108u8, 226u8, 134u8, 146u8, 0u8, 144u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 209u8, 131u8, 226u8, 134u8, 146u8,
+                37u8, 37u8, 99u8, 121u8, 114u8, 105u8, 108u8, 108u8, 105u8, 99u8, 45u8, 108u8,
+                111u8, 119u8, 101u8, 114u8, 45u8, 102u8, 105u8, 110u8, 97u8, 108u8, 226u8, 134u8,
+                146u8, 0u8, 244u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 209u8, 132u8, 226u8, 134u8, 146u8, 37u8, 37u8, 99u8, 121u8, 114u8,
+                105u8, 108u8, 108u8, 105u8, 99u8, 45u8, 108u8, 111u8, 119u8, 101u8, 114u8, 45u8,
+                102u8, 105u8, 110u8, 97u8, 108u8, 226u8, 134u8, 146u8, 0u8, 88u8, 2u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 209u8, 133u8, 226u8,
+                134u8, 146u8, 37u8, 37u8, 99u8, 121u8, 114u8, 105u8, 108u8, 108u8, 105u8, 99u8,
+                45u8, 108u8, 111u8, 119u8, 101u8, 114u8, 45u8, 102u8, 105u8, 110u8, 97u8, 108u8,
+                226u8, 134u8, 146u8, 0u8, 188u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 209u8, 177u8, 226u8, 134u8, 146u8, 37u8, 37u8, 99u8,
+                121u8, 114u8, 105u8, 108u8, 108u8, 105u8, 99u8, 45u8, 108u8, 111u8, 119u8, 101u8,
+                114u8, 45u8, 102u8, 105u8, 110u8, 97u8, 108u8, 226u8, 134u8, 146u8, 0u8, 32u8, 3u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 209u8,
+                191u8, 210u8, 131u8, 0u8, 33u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 209u8, 191u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8,
+                0u8, 132u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 209u8, 134u8, 226u8, 134u8, 146u8, 37u8, 37u8, 99u8, 121u8, 114u8, 105u8,
+                108u8, 108u8, 105u8, 99u8, 45u8, 108u8, 111u8, 119u8, 101u8, 114u8, 45u8, 102u8,
+                105u8, 110u8, 97u8, 108u8, 226u8, 134u8, 146u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 210u8, 130u8, 226u8,
+                134u8, 144u8, 37u8, 37u8, 99u8, 121u8, 114u8, 105u8, 108u8, 108u8, 105u8, 99u8,
+                45u8, 108u8, 111u8, 119u8, 101u8, 114u8, 45u8, 49u8, 45u8, 49u8, 48u8, 226u8,
+                134u8, 144u8, 226u8, 134u8, 146u8, 37u8, 37u8, 99u8, 121u8, 114u8, 105u8, 108u8,
+                108u8, 105u8, 99u8, 45u8, 108u8, 111u8, 119u8, 101u8, 114u8, 45u8, 112u8, 111u8,
+                115u8, 116u8, 226u8, 134u8, 146u8, 0u8, 16u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 210u8, 130u8, 226u8, 134u8, 144u8, 226u8,
+                134u8, 144u8, 0u8, 17u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 210u8, 130u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 32u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 248u8, 42u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 37u8,
+                37u8, 99u8, 121u8, 114u8, 105u8, 108u8, 108u8, 105u8, 99u8, 45u8, 108u8, 111u8,
+                119u8, 101u8, 114u8, 45u8, 116u8, 104u8, 111u8, 117u8, 115u8, 97u8, 110u8, 100u8,
+                115u8, 226u8, 134u8, 144u8, 0u8, 249u8, 42u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8,
+                3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 37u8, 37u8, 99u8, 121u8,
+                114u8, 105u8, 108u8, 108u8, 105u8, 99u8, 45u8, 108u8, 111u8, 119u8, 101u8, 114u8,
+                45u8, 116u8, 104u8, 111u8, 117u8, 115u8, 97u8, 110u8, 100u8, 115u8, 226u8, 134u8,
+                144u8, 32u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 64u8, 66u8, 15u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 64u8, 66u8, 15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 210u8, 130u8, 210u8,
+                130u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 0u8, 65u8, 66u8, 15u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 64u8, 66u8, 15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 210u8, 130u8, 210u8,
+                130u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 32u8, 226u8, 134u8, 146u8, 226u8,
+                134u8, 146u8, 0u8, 0u8, 202u8, 154u8, 59u8, 0u8, 0u8, 0u8, 0u8, 0u8, 202u8, 154u8,
+                59u8, 0u8, 0u8, 0u8, 0u8, 210u8, 130u8, 210u8, 130u8, 210u8, 130u8, 226u8, 134u8,
+                144u8, 226u8, 134u8, 144u8, 0u8, 1u8, 202u8, 154u8, 59u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                202u8, 154u8, 59u8, 0u8, 0u8, 0u8, 0u8, 210u8, 130u8, 210u8, 130u8, 210u8, 130u8,
+                226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 32u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 0u8, 16u8, 165u8, 212u8, 232u8, 0u8, 0u8, 0u8, 0u8, 16u8, 165u8, 212u8,
+                232u8, 0u8, 0u8, 0u8, 210u8, 130u8, 210u8, 130u8, 210u8, 130u8, 210u8, 130u8,
+                226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 0u8, 1u8, 16u8, 165u8, 212u8, 232u8, 0u8,
+                0u8, 0u8, 0u8, 16u8, 165u8, 212u8, 232u8, 0u8, 0u8, 0u8, 210u8, 130u8, 210u8,
+                130u8, 210u8, 130u8, 210u8, 130u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 32u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8, 128u8, 198u8, 164u8, 126u8,
+                141u8, 3u8, 0u8, 0u8, 128u8, 198u8, 164u8, 126u8, 141u8, 3u8, 0u8, 210u8, 130u8,
+                210u8, 130u8, 210u8, 130u8, 210u8, 130u8, 210u8, 130u8, 226u8, 134u8, 144u8, 226u8,
+                134u8, 144u8, 0u8, 1u8, 128u8, 198u8, 164u8, 126u8, 141u8, 3u8, 0u8, 0u8, 128u8,
+                198u8, 164u8, 126u8, 141u8, 3u8, 0u8, 210u8, 130u8, 210u8, 130u8, 210u8, 130u8,
+                210u8, 130u8, 210u8, 130u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 32u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 100u8, 167u8, 179u8, 182u8,
+                224u8, 13u8, 0u8, 0u8, 100u8, 167u8, 179u8, 182u8, 224u8, 13u8, 61u8, 35u8, 44u8,
+                35u8, 35u8, 48u8, 61u8,
+            ])
+        },
+    },
+};
+static UND_U_NU_ETHI: &DataStruct = &::icu_decimal::provider::AlgorithmicSymbolsV1 {
+    rule_set: alloc::borrow::Cow::Borrowed("ethiopic"),
+    rules: ::icu_decimal::provider::RbnfRulesV1 {
+        rule_sets: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 18u8, 0u8, 29u8, 0u8, 40u8, 0u8,
+                        101u8, 116u8, 104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 101u8, 116u8, 104u8,
+                        105u8, 111u8, 112u8, 105u8, 99u8, 45u8, 112u8, 101u8, 116u8, 104u8, 105u8,
+                        111u8, 112u8, 105u8, 99u8, 45u8, 112u8, 49u8, 101u8, 116u8, 104u8, 105u8,
+                        111u8, 112u8, 105u8, 99u8, 45u8, 112u8, 50u8, 101u8, 116u8, 104u8, 105u8,
+                        111u8, 112u8, 105u8, 99u8, 45u8, 112u8, 51u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        16u8, 0u8, 61u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 1u8, 0u8, 6u8, 0u8,
+                        10u8, 0u8, 1u8, 0u8, 10u8, 0u8, 13u8, 0u8, 1u8, 0u8, 13u8, 0u8, 16u8, 0u8,
+                        1u8, 0u8,
+                    ])
+                },
+            )
+        },
+        rules: unsafe {
+            ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                61u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 54u8, 0u8, 0u8, 0u8,
+                86u8, 0u8, 0u8, 0u8, 131u8, 0u8, 0u8, 0u8, 176u8, 0u8, 0u8, 0u8, 221u8, 0u8, 0u8,
+                0u8, 241u8, 0u8, 0u8, 0u8, 19u8, 1u8, 0u8, 0u8, 54u8, 1u8, 0u8, 0u8, 104u8, 1u8,
+                0u8, 0u8, 127u8, 1u8, 0u8, 0u8, 164u8, 1u8, 0u8, 0u8, 218u8, 1u8, 0u8, 0u8, 244u8,
+                1u8, 0u8, 0u8, 28u8, 2u8, 0u8, 0u8, 82u8, 2u8, 0u8, 0u8, 108u8, 2u8, 0u8, 0u8,
+                140u8, 2u8, 0u8, 0u8, 163u8, 2u8, 0u8, 0u8, 183u8, 2u8, 0u8, 0u8, 203u8, 2u8, 0u8,
+                0u8, 223u8, 2u8, 0u8, 0u8, 243u8, 2u8, 0u8, 0u8, 7u8, 3u8, 0u8, 0u8, 27u8, 3u8,
+                0u8, 0u8, 47u8, 3u8, 0u8, 0u8, 67u8, 3u8, 0u8, 0u8, 87u8, 3u8, 0u8, 0u8, 107u8,
+                3u8, 0u8, 0u8, 133u8, 3u8, 0u8, 0u8, 153u8, 3u8, 0u8, 0u8, 179u8, 3u8, 0u8, 0u8,
+                199u8, 3u8, 0u8, 0u8, 225u8, 3u8, 0u8, 0u8, 245u8, 3u8, 0u8, 0u8, 15u8, 4u8, 0u8,
+                0u8, 35u8, 4u8, 0u8, 0u8, 61u8, 4u8, 0u8, 0u8, 81u8, 4u8, 0u8, 0u8, 107u8, 4u8,
+                0u8, 0u8, 127u8, 4u8, 0u8, 0u8, 153u8, 4u8, 0u8, 0u8, 173u8, 4u8, 0u8, 0u8, 199u8,
+                4u8, 0u8, 0u8, 219u8, 4u8, 0u8, 0u8, 245u8, 4u8, 0u8, 0u8, 9u8, 5u8, 0u8, 0u8,
+                35u8, 5u8, 0u8, 0u8, 61u8, 5u8, 0u8, 0u8, 93u8, 5u8, 0u8, 0u8, 113u8, 5u8, 0u8,
+                0u8, 139u8, 5u8, 0u8, 0u8, 165u8, 5u8, 0u8, 0u8, 197u8, 5u8, 0u8, 0u8, 236u8, 5u8,
+                0u8, 0u8, 25u8, 6u8, 0u8, 0u8, 64u8, 6u8, 0u8, 0u8, 109u8, 6u8, 0u8, 0u8, 148u8,
+                6u8, 0u8, 0u8, 193u8, 6u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 37u8, 101u8, 116u8, 104u8, 105u8,
+                111u8, 112u8, 105u8, 99u8, 61u8, 0u8, 16u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                16u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8,
+                225u8, 141u8, 188u8, 0u8, 17u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 39u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 225u8,
+                141u8, 188u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8, 225u8, 245u8,
+                5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 245u8, 5u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8,
+                144u8, 226u8, 134u8, 144u8, 225u8, 141u8, 188u8, 226u8, 134u8, 146u8, 37u8, 37u8,
+                101u8, 116u8, 104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 45u8, 112u8, 49u8, 226u8,
+                134u8, 146u8, 0u8, 0u8, 16u8, 165u8, 212u8, 232u8, 0u8, 0u8, 0u8, 0u8, 16u8, 165u8,
+                212u8, 232u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 225u8,
+                141u8, 188u8, 226u8, 134u8, 146u8, 37u8, 37u8, 101u8, 116u8, 104u8, 105u8, 111u8,
+                112u8, 105u8, 99u8, 45u8, 112u8, 50u8, 226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 193u8,
+                111u8, 242u8, 134u8, 35u8, 0u8, 0u8, 0u8, 193u8, 111u8, 242u8, 134u8, 35u8, 0u8,
+                226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 225u8, 141u8, 188u8, 226u8, 134u8, 146u8,
+                37u8, 37u8, 101u8, 116u8, 104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 45u8, 112u8,
+                51u8, 226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 141u8, 188u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 141u8, 188u8, 61u8,
+                37u8, 37u8, 101u8, 116u8, 104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 45u8, 112u8,
+                61u8, 0u8, 16u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 39u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 37u8, 101u8, 116u8, 104u8, 105u8, 111u8, 112u8,
+                105u8, 99u8, 226u8, 134u8, 144u8, 225u8, 141u8, 188u8, 0u8, 17u8, 39u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 16u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8,
+                37u8, 101u8, 116u8, 104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 226u8, 134u8, 144u8,
+                225u8, 141u8, 188u8, 226u8, 134u8, 146u8, 37u8, 101u8, 116u8, 104u8, 105u8, 111u8,
+                112u8, 105u8, 99u8, 226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 141u8, 188u8, 225u8, 141u8,
+                188u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 225u8, 141u8, 188u8, 225u8, 141u8, 188u8, 61u8, 37u8, 37u8, 101u8, 116u8,
+                104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 45u8, 112u8, 61u8, 0u8, 0u8, 225u8, 245u8,
+                5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 245u8, 5u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8,
+                144u8, 37u8, 101u8, 116u8, 104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 226u8, 134u8,
+                144u8, 225u8, 141u8, 188u8, 226u8, 134u8, 146u8, 37u8, 37u8, 101u8, 116u8, 104u8,
+                105u8, 111u8, 112u8, 105u8, 99u8, 45u8, 112u8, 49u8, 226u8, 134u8, 146u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8,
+                141u8, 188u8, 225u8, 141u8, 188u8, 225u8, 141u8, 188u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 141u8, 188u8,
+                225u8, 141u8, 188u8, 225u8, 141u8, 188u8, 61u8, 37u8, 37u8, 101u8, 116u8, 104u8,
+                105u8, 111u8, 112u8, 105u8, 99u8, 45u8, 112u8, 61u8, 0u8, 0u8, 16u8, 165u8, 212u8,
+                232u8, 0u8, 0u8, 0u8, 0u8, 16u8, 165u8, 212u8, 232u8, 0u8, 0u8, 0u8, 226u8, 134u8,
+                144u8, 37u8, 101u8, 116u8, 104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 226u8, 134u8,
+                144u8, 225u8, 141u8, 188u8, 226u8, 134u8, 146u8, 37u8, 37u8, 101u8, 116u8, 104u8,
+                105u8, 111u8, 112u8, 105u8, 99u8, 45u8, 112u8, 50u8, 226u8, 134u8, 146u8, 1u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8,
+                136u8, 146u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8,
+                226u8, 134u8, 144u8, 225u8, 141u8, 161u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 225u8, 137u8, 163u8, 225u8, 139u8, 182u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 141u8, 169u8, 0u8, 2u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8,
+                141u8, 170u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 225u8, 141u8, 171u8, 0u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 141u8, 172u8, 0u8, 5u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 141u8,
+                173u8, 0u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 225u8, 141u8, 174u8, 0u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 141u8, 175u8, 0u8, 8u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 141u8, 176u8,
+                0u8, 9u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 225u8, 141u8, 177u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 141u8, 178u8, 0u8, 11u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 141u8, 178u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 20u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 141u8, 179u8, 0u8, 21u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 141u8,
+                179u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 30u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 141u8, 180u8, 0u8,
+                31u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                225u8, 141u8, 180u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 40u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 141u8,
+                181u8, 0u8, 41u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 225u8, 141u8, 181u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 50u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8,
+                141u8, 182u8, 0u8, 51u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 225u8, 141u8, 182u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8,
+                0u8, 60u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 225u8, 141u8, 183u8, 0u8, 61u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 141u8, 183u8, 226u8, 134u8, 146u8, 226u8,
+                134u8, 146u8, 0u8, 70u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 225u8, 141u8, 184u8, 0u8, 71u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 141u8, 184u8, 226u8, 134u8,
+                146u8, 226u8, 134u8, 146u8, 0u8, 80u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 141u8, 185u8, 0u8, 81u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 141u8, 185u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 90u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 141u8, 186u8, 0u8, 91u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8,
+                141u8, 186u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 100u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 141u8, 187u8,
+                0u8, 101u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 225u8, 141u8, 187u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 200u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8,
+                134u8, 144u8, 226u8, 134u8, 144u8, 225u8, 141u8, 187u8, 0u8, 201u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8,
+                226u8, 134u8, 144u8, 225u8, 141u8, 187u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8,
+                0u8, 16u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 225u8, 141u8, 188u8, 0u8, 17u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8,
+                39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 141u8, 188u8, 226u8, 134u8, 146u8,
+                226u8, 134u8, 146u8, 0u8, 32u8, 78u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 39u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 225u8,
+                141u8, 188u8, 0u8, 33u8, 78u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 39u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 225u8, 141u8, 188u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8, 225u8, 245u8, 5u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 225u8, 245u8, 5u8, 0u8, 0u8, 0u8, 0u8, 225u8, 141u8, 188u8, 226u8,
+                134u8, 146u8, 37u8, 37u8, 101u8, 116u8, 104u8, 105u8, 111u8, 112u8, 105u8, 99u8,
+                45u8, 112u8, 49u8, 226u8, 134u8, 146u8, 0u8, 0u8, 194u8, 235u8, 11u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 225u8, 245u8, 5u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 226u8,
+                134u8, 144u8, 225u8, 141u8, 188u8, 226u8, 134u8, 146u8, 37u8, 37u8, 101u8, 116u8,
+                104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 45u8, 112u8, 49u8, 226u8, 134u8, 146u8,
+                0u8, 0u8, 16u8, 165u8, 212u8, 232u8, 0u8, 0u8, 0u8, 0u8, 16u8, 165u8, 212u8, 232u8,
+                0u8, 0u8, 0u8, 225u8, 141u8, 188u8, 226u8, 134u8, 146u8, 37u8, 37u8, 101u8, 116u8,
+                104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 45u8, 112u8, 50u8, 226u8, 134u8, 146u8,
+                0u8, 0u8, 32u8, 74u8, 169u8, 209u8, 1u8, 0u8, 0u8, 0u8, 16u8, 165u8, 212u8, 232u8,
+                0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 225u8, 141u8, 188u8,
+                226u8, 134u8, 146u8, 37u8, 37u8, 101u8, 116u8, 104u8, 105u8, 111u8, 112u8, 105u8,
+                99u8, 45u8, 112u8, 50u8, 226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 193u8, 111u8, 242u8,
+                134u8, 35u8, 0u8, 0u8, 0u8, 193u8, 111u8, 242u8, 134u8, 35u8, 0u8, 225u8, 141u8,
+                188u8, 226u8, 134u8, 146u8, 37u8, 37u8, 101u8, 116u8, 104u8, 105u8, 111u8, 112u8,
+                105u8, 99u8, 45u8, 112u8, 51u8, 226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 130u8, 223u8,
+                228u8, 13u8, 71u8, 0u8, 0u8, 0u8, 193u8, 111u8, 242u8, 134u8, 35u8, 0u8, 226u8,
+                134u8, 144u8, 226u8, 134u8, 144u8, 225u8, 141u8, 188u8, 226u8, 134u8, 146u8, 37u8,
+                37u8, 101u8, 116u8, 104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 45u8, 112u8, 51u8,
+                226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 100u8, 167u8, 179u8, 182u8, 224u8, 13u8, 0u8,
+                0u8, 100u8, 167u8, 179u8, 182u8, 224u8, 13u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8,
+                61u8,
+            ])
+        },
+    },
+};
+static UND_U_NU_GEOR: &DataStruct = &::icu_decimal::provider::AlgorithmicSymbolsV1 {
+    rule_set: alloc::borrow::Cow::Borrowed("georgian"),
+    rules: ::icu_decimal::provider::RbnfRulesV1 {
+        rule_sets: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 103u8, 101u8, 111u8, 114u8, 103u8, 105u8,
+                        97u8, 110u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8, 69u8, 0u8, 0u8, 0u8])
+                },
+            )
+        },
+        rules: unsafe {
+            ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                69u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 53u8, 0u8, 0u8, 0u8,
+                77u8, 0u8, 0u8, 0u8, 97u8, 0u8, 0u8, 0u8, 117u8, 0u8, 0u8, 0u8, 137u8, 0u8, 0u8,
+                0u8, 157u8, 0u8, 0u8, 0u8, 177u8, 0u8, 0u8, 0u8, 197u8, 0u8, 0u8, 0u8, 217u8, 0u8,
+                0u8, 0u8, 237u8, 0u8, 0u8, 0u8, 1u8, 1u8, 0u8, 0u8, 21u8, 1u8, 0u8, 0u8, 47u8, 1u8,
+                0u8, 0u8, 67u8, 1u8, 0u8, 0u8, 93u8, 1u8, 0u8, 0u8, 113u8, 1u8, 0u8, 0u8, 139u8,
+                1u8, 0u8, 0u8, 159u8, 1u8, 0u8, 0u8, 185u8, 1u8, 0u8, 0u8, 205u8, 1u8, 0u8, 0u8,
+                231u8, 1u8, 0u8, 0u8, 251u8, 1u8, 0u8, 0u8, 21u8, 2u8, 0u8, 0u8, 41u8, 2u8, 0u8,
+                0u8, 67u8, 2u8, 0u8, 0u8, 87u8, 2u8, 0u8, 0u8, 113u8, 2u8, 0u8, 0u8, 133u8, 2u8,
+                0u8, 0u8, 159u8, 2u8, 0u8, 0u8, 179u8, 2u8, 0u8, 0u8, 205u8, 2u8, 0u8, 0u8, 225u8,
+                2u8, 0u8, 0u8, 251u8, 2u8, 0u8, 0u8, 15u8, 3u8, 0u8, 0u8, 41u8, 3u8, 0u8, 0u8,
+                61u8, 3u8, 0u8, 0u8, 87u8, 3u8, 0u8, 0u8, 107u8, 3u8, 0u8, 0u8, 133u8, 3u8, 0u8,
+                0u8, 153u8, 3u8, 0u8, 0u8, 179u8, 3u8, 0u8, 0u8, 199u8, 3u8, 0u8, 0u8, 225u8, 3u8,
+                0u8, 0u8, 245u8, 3u8, 0u8, 0u8, 15u8, 4u8, 0u8, 0u8, 35u8, 4u8, 0u8, 0u8, 61u8,
+                4u8, 0u8, 0u8, 81u8, 4u8, 0u8, 0u8, 107u8, 4u8, 0u8, 0u8, 127u8, 4u8, 0u8, 0u8,
+                153u8, 4u8, 0u8, 0u8, 173u8, 4u8, 0u8, 0u8, 199u8, 4u8, 0u8, 0u8, 219u8, 4u8, 0u8,
+                0u8, 245u8, 4u8, 0u8, 0u8, 9u8, 5u8, 0u8, 0u8, 35u8, 5u8, 0u8, 0u8, 55u8, 5u8, 0u8,
+                0u8, 81u8, 5u8, 0u8, 0u8, 101u8, 5u8, 0u8, 0u8, 127u8, 5u8, 0u8, 0u8, 147u8, 5u8,
+                0u8, 0u8, 173u8, 5u8, 0u8, 0u8, 193u8, 5u8, 0u8, 0u8, 219u8, 5u8, 0u8, 0u8, 239u8,
+                5u8, 0u8, 0u8, 9u8, 6u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 136u8, 146u8, 226u8, 134u8, 146u8,
+                226u8, 134u8, 146u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 46u8, 48u8, 48u8,
+                61u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 61u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 144u8, 0u8,
+                2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                225u8, 131u8, 145u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 146u8, 0u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 147u8, 0u8, 5u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8,
+                131u8, 148u8, 0u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 225u8, 131u8, 149u8, 0u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 150u8, 0u8, 8u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 131u8,
+                177u8, 0u8, 9u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 225u8, 131u8, 151u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 152u8, 0u8, 11u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 152u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 20u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 153u8, 0u8, 21u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8,
+                131u8, 153u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 30u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 154u8,
+                0u8, 31u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 225u8, 131u8, 154u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 40u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8,
+                131u8, 155u8, 0u8, 41u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 155u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8,
+                0u8, 50u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 225u8, 131u8, 156u8, 0u8, 51u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 156u8, 226u8, 134u8, 146u8, 226u8,
+                134u8, 146u8, 0u8, 60u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 178u8, 0u8, 61u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 178u8, 226u8, 134u8,
+                146u8, 226u8, 134u8, 146u8, 0u8, 70u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 157u8, 0u8, 71u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 157u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 80u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 158u8, 0u8, 81u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8,
+                131u8, 158u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 90u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 159u8,
+                0u8, 91u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 225u8, 131u8, 159u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 100u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8,
+                131u8, 160u8, 0u8, 101u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 160u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8,
+                0u8, 200u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 225u8, 131u8, 161u8, 0u8, 201u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 161u8, 226u8, 134u8, 146u8, 226u8,
+                134u8, 146u8, 0u8, 44u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 162u8, 0u8, 45u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 162u8, 226u8, 134u8,
+                146u8, 226u8, 134u8, 146u8, 0u8, 144u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 163u8, 0u8, 145u8, 1u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 163u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 244u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 179u8, 0u8, 245u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8,
+                131u8, 179u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 88u8, 2u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 164u8,
+                0u8, 89u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 225u8, 131u8, 164u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 188u8,
+                2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8,
+                131u8, 165u8, 0u8, 189u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 165u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8,
+                0u8, 32u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 225u8, 131u8, 166u8, 0u8, 33u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 166u8, 226u8, 134u8, 146u8, 226u8,
+                134u8, 146u8, 0u8, 132u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 167u8, 0u8, 133u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 167u8, 226u8, 134u8,
+                146u8, 226u8, 134u8, 146u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8,
+                3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 168u8, 0u8, 233u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 168u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 208u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 169u8, 0u8, 209u8,
+                7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8,
+                131u8, 169u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 184u8, 11u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 170u8,
+                0u8, 185u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 225u8, 131u8, 170u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8,
+                160u8, 15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 225u8, 131u8, 171u8, 0u8, 161u8, 15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8,
+                3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 171u8, 226u8, 134u8, 146u8, 226u8,
+                134u8, 146u8, 0u8, 136u8, 19u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 172u8, 0u8, 137u8, 19u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 172u8, 226u8, 134u8,
+                146u8, 226u8, 134u8, 146u8, 0u8, 112u8, 23u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8,
+                3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 173u8, 0u8, 113u8, 23u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 173u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 88u8, 27u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 174u8, 0u8, 89u8,
+                27u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                225u8, 131u8, 174u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 64u8, 31u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8,
+                131u8, 180u8, 0u8, 65u8, 31u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 180u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8,
+                0u8, 40u8, 35u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 225u8, 131u8, 181u8, 0u8, 41u8, 35u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8,
+                3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 181u8, 226u8, 134u8, 146u8, 226u8,
+                134u8, 146u8, 0u8, 16u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 39u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 175u8, 0u8, 17u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 16u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 131u8, 175u8, 226u8, 134u8,
+                146u8, 226u8, 134u8, 146u8, 0u8, 32u8, 78u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8,
+                39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 61u8,
+            ])
+        },
+    },
+};
+static UND_U_NU_GREK: &DataStruct = &::icu_decimal::provider::AlgorithmicSymbolsV1 {
+    rule_set: alloc::borrow::Cow::Borrowed("greek-upper"),
+    rules: ::icu_decimal::provider::RbnfRulesV1 {
+        rule_sets: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 24u8, 0u8, 103u8, 114u8, 101u8, 101u8, 107u8,
+                        45u8, 110u8, 117u8, 109u8, 101u8, 114u8, 97u8, 108u8, 45u8, 109u8, 97u8,
+                        106u8, 117u8, 115u8, 99u8, 117u8, 108u8, 101u8, 115u8, 103u8, 114u8, 101u8,
+                        101u8, 107u8, 45u8, 117u8, 112u8, 112u8, 101u8, 114u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        0u8, 0u8, 57u8, 0u8, 1u8, 0u8, 57u8, 0u8, 60u8, 0u8, 0u8, 0u8,
+                    ])
+                },
+            )
+        },
+        rules: unsafe {
+            ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                60u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 40u8, 0u8, 0u8, 0u8,
+                59u8, 0u8, 0u8, 0u8, 78u8, 0u8, 0u8, 0u8, 97u8, 0u8, 0u8, 0u8, 116u8, 0u8, 0u8,
+                0u8, 135u8, 0u8, 0u8, 0u8, 154u8, 0u8, 0u8, 0u8, 173u8, 0u8, 0u8, 0u8, 192u8, 0u8,
+                0u8, 0u8, 211u8, 0u8, 0u8, 0u8, 236u8, 0u8, 0u8, 0u8, 255u8, 0u8, 0u8, 0u8, 24u8,
+                1u8, 0u8, 0u8, 43u8, 1u8, 0u8, 0u8, 68u8, 1u8, 0u8, 0u8, 87u8, 1u8, 0u8, 0u8,
+                112u8, 1u8, 0u8, 0u8, 131u8, 1u8, 0u8, 0u8, 156u8, 1u8, 0u8, 0u8, 175u8, 1u8, 0u8,
+                0u8, 200u8, 1u8, 0u8, 0u8, 219u8, 1u8, 0u8, 0u8, 244u8, 1u8, 0u8, 0u8, 7u8, 2u8,
+                0u8, 0u8, 32u8, 2u8, 0u8, 0u8, 51u8, 2u8, 0u8, 0u8, 76u8, 2u8, 0u8, 0u8, 95u8, 2u8,
+                0u8, 0u8, 120u8, 2u8, 0u8, 0u8, 139u8, 2u8, 0u8, 0u8, 164u8, 2u8, 0u8, 0u8, 183u8,
+                2u8, 0u8, 0u8, 208u8, 2u8, 0u8, 0u8, 227u8, 2u8, 0u8, 0u8, 252u8, 2u8, 0u8, 0u8,
+                15u8, 3u8, 0u8, 0u8, 40u8, 3u8, 0u8, 0u8, 59u8, 3u8, 0u8, 0u8, 84u8, 3u8, 0u8, 0u8,
+                103u8, 3u8, 0u8, 0u8, 128u8, 3u8, 0u8, 0u8, 147u8, 3u8, 0u8, 0u8, 172u8, 3u8, 0u8,
+                0u8, 191u8, 3u8, 0u8, 0u8, 216u8, 3u8, 0u8, 0u8, 241u8, 3u8, 0u8, 0u8, 16u8, 4u8,
+                0u8, 0u8, 41u8, 4u8, 0u8, 0u8, 73u8, 4u8, 0u8, 0u8, 100u8, 4u8, 0u8, 0u8, 134u8,
+                4u8, 0u8, 0u8, 163u8, 4u8, 0u8, 0u8, 199u8, 4u8, 0u8, 0u8, 230u8, 4u8, 0u8, 0u8,
+                12u8, 5u8, 0u8, 0u8, 36u8, 5u8, 0u8, 0u8, 62u8, 5u8, 0u8, 0u8, 95u8, 5u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 240u8, 144u8, 134u8, 138u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 206u8, 145u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 206u8, 146u8, 0u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 206u8, 147u8, 0u8,
+                4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                206u8, 148u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 206u8, 149u8, 0u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 207u8, 156u8, 0u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 206u8, 150u8, 0u8, 8u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 206u8, 151u8, 0u8, 9u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 206u8,
+                152u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 206u8, 153u8, 0u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 206u8, 153u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 20u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 206u8, 154u8, 0u8, 21u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 206u8, 154u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 30u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 206u8, 155u8, 0u8, 31u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 206u8, 155u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 40u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 206u8, 156u8, 0u8, 41u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 206u8, 156u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 50u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 206u8, 157u8, 0u8, 51u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 206u8, 157u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 60u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 206u8, 158u8, 0u8, 61u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 206u8, 158u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 70u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 206u8, 159u8, 0u8, 71u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 206u8, 159u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 80u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 206u8, 160u8, 0u8, 81u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 206u8, 160u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 90u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 207u8, 158u8, 0u8, 91u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 207u8, 158u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 206u8, 161u8, 0u8, 101u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 206u8, 161u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 200u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 206u8, 163u8, 0u8, 201u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 206u8, 163u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 44u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 206u8, 164u8, 0u8, 45u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 206u8, 164u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 144u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 206u8, 165u8, 0u8, 145u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 206u8, 165u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 244u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 206u8, 166u8, 0u8, 245u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 206u8, 166u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 88u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 206u8, 167u8, 0u8, 89u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 206u8, 167u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 188u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 206u8, 168u8, 0u8, 189u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 206u8, 168u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 32u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 206u8, 169u8, 0u8, 33u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 206u8, 169u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 132u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 207u8, 160u8, 0u8, 133u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 207u8, 160u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 205u8, 181u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 0u8, 233u8,
+                3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 205u8,
+                181u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 16u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 39u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 206u8, 156u8, 0u8, 17u8,
+                39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 206u8, 156u8, 32u8, 226u8, 134u8, 146u8,
+                226u8, 134u8, 146u8, 0u8, 0u8, 225u8, 245u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8,
+                245u8, 5u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 206u8,
+                156u8, 206u8, 156u8, 0u8, 1u8, 225u8, 245u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8,
+                245u8, 5u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 206u8,
+                156u8, 206u8, 156u8, 32u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8,
+                16u8, 165u8, 212u8, 232u8, 0u8, 0u8, 0u8, 0u8, 16u8, 165u8, 212u8, 232u8, 0u8, 0u8,
+                0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 206u8, 156u8, 206u8, 156u8, 206u8,
+                156u8, 0u8, 1u8, 16u8, 165u8, 212u8, 232u8, 0u8, 0u8, 0u8, 0u8, 16u8, 165u8, 212u8,
+                232u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 206u8, 156u8,
+                206u8, 156u8, 206u8, 156u8, 32u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8,
+                0u8, 0u8, 193u8, 111u8, 242u8, 134u8, 35u8, 0u8, 0u8, 0u8, 193u8, 111u8, 242u8,
+                134u8, 35u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 206u8, 156u8, 206u8,
+                156u8, 206u8, 156u8, 206u8, 156u8, 0u8, 1u8, 0u8, 193u8, 111u8, 242u8, 134u8, 35u8,
+                0u8, 0u8, 0u8, 193u8, 111u8, 242u8, 134u8, 35u8, 0u8, 226u8, 134u8, 144u8, 226u8,
+                134u8, 144u8, 206u8, 156u8, 206u8, 156u8, 206u8, 156u8, 206u8, 156u8, 32u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 100u8, 167u8, 179u8, 182u8,
+                224u8, 13u8, 0u8, 0u8, 100u8, 167u8, 179u8, 182u8, 224u8, 13u8, 61u8, 35u8, 44u8,
+                35u8, 35u8, 48u8, 61u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 136u8, 146u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 46u8, 226u8, 134u8, 146u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 37u8, 37u8, 103u8, 114u8, 101u8,
+                101u8, 107u8, 45u8, 110u8, 117u8, 109u8, 101u8, 114u8, 97u8, 108u8, 45u8, 109u8,
+                97u8, 106u8, 117u8, 115u8, 99u8, 117u8, 108u8, 101u8, 115u8, 61u8, 194u8, 180u8,
+            ])
+        },
+    },
+};
+static UND_U_NU_GREKLOW: &DataStruct = &::icu_decimal::provider::AlgorithmicSymbolsV1 {
+    rule_set: alloc::borrow::Cow::Borrowed("greek-lower"),
+    rules: ::icu_decimal::provider::RbnfRulesV1 {
+        rule_sets: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 103u8, 114u8, 101u8, 101u8, 107u8,
+                        45u8, 108u8, 111u8, 119u8, 101u8, 114u8, 103u8, 114u8, 101u8, 101u8, 107u8,
+                        45u8, 110u8, 117u8, 109u8, 101u8, 114u8, 97u8, 108u8, 45u8, 109u8, 105u8,
+                        110u8, 117u8, 115u8, 99u8, 117u8, 108u8, 101u8, 115u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        57u8, 0u8, 60u8, 0u8, 0u8, 0u8, 0u8, 0u8, 57u8, 0u8, 1u8, 0u8,
+                    ])
+                },
+            )
+        },
+        rules: unsafe {
+            ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                60u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 40u8, 0u8, 0u8, 0u8,
+                59u8, 0u8, 0u8, 0u8, 78u8, 0u8, 0u8, 0u8, 97u8, 0u8, 0u8, 0u8, 116u8, 0u8, 0u8,
+                0u8, 135u8, 0u8, 0u8, 0u8, 154u8, 0u8, 0u8, 0u8, 173u8, 0u8, 0u8, 0u8, 192u8, 0u8,
+                0u8, 0u8, 211u8, 0u8, 0u8, 0u8, 236u8, 0u8, 0u8, 0u8, 255u8, 0u8, 0u8, 0u8, 24u8,
+                1u8, 0u8, 0u8, 43u8, 1u8, 0u8, 0u8, 68u8, 1u8, 0u8, 0u8, 87u8, 1u8, 0u8, 0u8,
+                112u8, 1u8, 0u8, 0u8, 131u8, 1u8, 0u8, 0u8, 156u8, 1u8, 0u8, 0u8, 175u8, 1u8, 0u8,
+                0u8, 200u8, 1u8, 0u8, 0u8, 219u8, 1u8, 0u8, 0u8, 244u8, 1u8, 0u8, 0u8, 7u8, 2u8,
+                0u8, 0u8, 32u8, 2u8, 0u8, 0u8, 51u8, 2u8, 0u8, 0u8, 76u8, 2u8, 0u8, 0u8, 95u8, 2u8,
+                0u8, 0u8, 120u8, 2u8, 0u8, 0u8, 139u8, 2u8, 0u8, 0u8, 164u8, 2u8, 0u8, 0u8, 183u8,
+                2u8, 0u8, 0u8, 208u8, 2u8, 0u8, 0u8, 227u8, 2u8, 0u8, 0u8, 252u8, 2u8, 0u8, 0u8,
+                15u8, 3u8, 0u8, 0u8, 40u8, 3u8, 0u8, 0u8, 59u8, 3u8, 0u8, 0u8, 84u8, 3u8, 0u8, 0u8,
+                103u8, 3u8, 0u8, 0u8, 128u8, 3u8, 0u8, 0u8, 147u8, 3u8, 0u8, 0u8, 172u8, 3u8, 0u8,
+                0u8, 191u8, 3u8, 0u8, 0u8, 216u8, 3u8, 0u8, 0u8, 241u8, 3u8, 0u8, 0u8, 16u8, 4u8,
+                0u8, 0u8, 41u8, 4u8, 0u8, 0u8, 73u8, 4u8, 0u8, 0u8, 100u8, 4u8, 0u8, 0u8, 134u8,
+                4u8, 0u8, 0u8, 163u8, 4u8, 0u8, 0u8, 199u8, 4u8, 0u8, 0u8, 230u8, 4u8, 0u8, 0u8,
+                12u8, 5u8, 0u8, 0u8, 36u8, 5u8, 0u8, 0u8, 62u8, 5u8, 0u8, 0u8, 95u8, 5u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 240u8, 144u8, 134u8, 138u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 206u8, 177u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 206u8, 178u8, 0u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 206u8, 179u8, 0u8,
+                4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                206u8, 180u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 206u8, 181u8, 0u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 207u8, 157u8, 0u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 206u8, 182u8, 0u8, 8u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 206u8, 183u8, 0u8, 9u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 206u8,
+                184u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 206u8, 185u8, 0u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 206u8, 185u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 20u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 206u8, 186u8, 0u8, 21u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 206u8, 186u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 30u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 206u8, 187u8, 0u8, 31u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 206u8, 187u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 40u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 206u8, 188u8, 0u8, 41u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 206u8, 188u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 50u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 206u8, 189u8, 0u8, 51u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 206u8, 189u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 60u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 206u8, 190u8, 0u8, 61u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 206u8, 190u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 70u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 206u8, 191u8, 0u8, 71u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 206u8, 191u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 80u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 207u8, 128u8, 0u8, 81u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 207u8, 128u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 90u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 207u8, 159u8, 0u8, 91u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 207u8, 159u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 207u8, 129u8, 0u8, 101u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 207u8, 129u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 200u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 207u8, 131u8, 0u8, 201u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 207u8, 131u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 44u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 207u8, 132u8, 0u8, 45u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 207u8, 132u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 144u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 207u8, 133u8, 0u8, 145u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 207u8, 133u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 244u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 207u8, 134u8, 0u8, 245u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 207u8, 134u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 88u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 207u8, 135u8, 0u8, 89u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 207u8, 135u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 188u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 207u8, 136u8, 0u8, 189u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 207u8, 136u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 32u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 207u8, 137u8, 0u8, 33u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 207u8, 137u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 132u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 207u8, 161u8, 0u8, 133u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 207u8, 161u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 205u8, 181u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 0u8, 233u8,
+                3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 205u8,
+                181u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 16u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 39u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 206u8, 188u8, 0u8, 17u8,
+                39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 206u8, 188u8, 32u8, 226u8, 134u8, 146u8,
+                226u8, 134u8, 146u8, 0u8, 0u8, 225u8, 245u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8,
+                245u8, 5u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 206u8,
+                188u8, 206u8, 188u8, 0u8, 1u8, 225u8, 245u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8,
+                245u8, 5u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 206u8,
+                188u8, 206u8, 188u8, 32u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8,
+                16u8, 165u8, 212u8, 232u8, 0u8, 0u8, 0u8, 0u8, 16u8, 165u8, 212u8, 232u8, 0u8, 0u8,
+                0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 206u8, 188u8, 206u8, 188u8, 206u8,
+                188u8, 0u8, 1u8, 16u8, 165u8, 212u8, 232u8, 0u8, 0u8, 0u8, 0u8, 16u8, 165u8, 212u8,
+                232u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 206u8, 188u8,
+                206u8, 188u8, 206u8, 188u8, 32u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8,
+                0u8, 0u8, 193u8, 111u8, 242u8, 134u8, 35u8, 0u8, 0u8, 0u8, 193u8, 111u8, 242u8,
+                134u8, 35u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 206u8, 188u8, 206u8,
+                188u8, 206u8, 188u8, 206u8, 188u8, 0u8, 1u8, 0u8, 193u8, 111u8, 242u8, 134u8, 35u8,
+                0u8, 0u8, 0u8, 193u8, 111u8, 242u8, 134u8, 35u8, 0u8, 226u8, 134u8, 144u8, 226u8,
+                134u8, 144u8, 206u8, 188u8, 206u8, 188u8, 206u8, 188u8, 206u8, 188u8, 32u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 100u8, 167u8, 179u8, 182u8,
+                224u8, 13u8, 0u8, 0u8, 100u8, 167u8, 179u8, 182u8, 224u8, 13u8, 61u8, 35u8, 44u8,
+                35u8, 35u8, 48u8, 61u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 136u8, 146u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 46u8, 226u8, 134u8, 146u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 37u8, 37u8, 103u8, 114u8, 101u8,
+                101u8, 107u8, 45u8, 110u8, 117u8, 109u8, 101u8, 114u8, 97u8, 108u8, 45u8, 109u8,
+                105u8, 110u8, 117u8, 115u8, 99u8, 117u8, 108u8, 101u8, 115u8, 61u8, 194u8, 180u8,
+            ])
+        },
+    },
+};
+static UND_U_NU_HEBR: &DataStruct = &::icu_decimal::provider::AlgorithmicSymbolsV1 {
+    rule_set: alloc::borrow::Cow::Borrowed("hebrew"),
+    rules: ::icu_decimal::provider::RbnfRulesV1 {
+        rule_sets: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 17u8, 0u8, 28u8, 0u8, 48u8, 0u8,
+                        104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 104u8, 101u8, 98u8, 114u8, 101u8,
+                        119u8, 45u8, 48u8, 45u8, 57u8, 57u8, 104u8, 101u8, 98u8, 114u8, 101u8,
+                        119u8, 45u8, 105u8, 116u8, 101u8, 109u8, 104u8, 101u8, 98u8, 114u8, 101u8,
+                        119u8, 45u8, 105u8, 116u8, 101u8, 109u8, 45u8, 104u8, 117u8, 110u8, 100u8,
+                        114u8, 101u8, 100u8, 115u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 45u8,
+                        116u8, 104u8, 111u8, 117u8, 115u8, 97u8, 110u8, 100u8, 115u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        112u8, 0u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 22u8, 0u8, 1u8, 0u8, 167u8,
+                        0u8, 201u8, 0u8, 0u8, 0u8, 22u8, 0u8, 106u8, 0u8, 1u8, 0u8, 106u8, 0u8,
+                        112u8, 0u8, 1u8, 0u8,
+                    ])
+                },
+            )
+        },
+        rules: unsafe {
+            ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                201u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 52u8, 0u8, 0u8, 0u8,
+                91u8, 0u8, 0u8, 0u8, 114u8, 0u8, 0u8, 0u8, 137u8, 0u8, 0u8, 0u8, 176u8, 0u8, 0u8,
+                0u8, 197u8, 0u8, 0u8, 0u8, 236u8, 0u8, 0u8, 0u8, 1u8, 1u8, 0u8, 0u8, 40u8, 1u8,
+                0u8, 0u8, 61u8, 1u8, 0u8, 0u8, 100u8, 1u8, 0u8, 0u8, 121u8, 1u8, 0u8, 0u8, 160u8,
+                1u8, 0u8, 0u8, 181u8, 1u8, 0u8, 0u8, 220u8, 1u8, 0u8, 0u8, 241u8, 1u8, 0u8, 0u8,
+                24u8, 2u8, 0u8, 0u8, 45u8, 2u8, 0u8, 0u8, 84u8, 2u8, 0u8, 0u8, 105u8, 2u8, 0u8,
+                0u8, 144u8, 2u8, 0u8, 0u8, 170u8, 2u8, 0u8, 0u8, 197u8, 2u8, 0u8, 0u8, 216u8, 2u8,
+                0u8, 0u8, 235u8, 2u8, 0u8, 0u8, 254u8, 2u8, 0u8, 0u8, 17u8, 3u8, 0u8, 0u8, 36u8,
+                3u8, 0u8, 0u8, 55u8, 3u8, 0u8, 0u8, 74u8, 3u8, 0u8, 0u8, 93u8, 3u8, 0u8, 0u8,
+                112u8, 3u8, 0u8, 0u8, 131u8, 3u8, 0u8, 0u8, 150u8, 3u8, 0u8, 0u8, 175u8, 3u8, 0u8,
+                0u8, 196u8, 3u8, 0u8, 0u8, 217u8, 3u8, 0u8, 0u8, 242u8, 3u8, 0u8, 0u8, 5u8, 4u8,
+                0u8, 0u8, 30u8, 4u8, 0u8, 0u8, 49u8, 4u8, 0u8, 0u8, 74u8, 4u8, 0u8, 0u8, 93u8, 4u8,
+                0u8, 0u8, 118u8, 4u8, 0u8, 0u8, 137u8, 4u8, 0u8, 0u8, 162u8, 4u8, 0u8, 0u8, 181u8,
+                4u8, 0u8, 0u8, 206u8, 4u8, 0u8, 0u8, 225u8, 4u8, 0u8, 0u8, 250u8, 4u8, 0u8, 0u8,
+                13u8, 5u8, 0u8, 0u8, 38u8, 5u8, 0u8, 0u8, 57u8, 5u8, 0u8, 0u8, 82u8, 5u8, 0u8, 0u8,
+                101u8, 5u8, 0u8, 0u8, 126u8, 5u8, 0u8, 0u8, 145u8, 5u8, 0u8, 0u8, 170u8, 5u8, 0u8,
+                0u8, 193u8, 5u8, 0u8, 0u8, 218u8, 5u8, 0u8, 0u8, 237u8, 5u8, 0u8, 0u8, 6u8, 6u8,
+                0u8, 0u8, 27u8, 6u8, 0u8, 0u8, 52u8, 6u8, 0u8, 0u8, 75u8, 6u8, 0u8, 0u8, 100u8,
+                6u8, 0u8, 0u8, 119u8, 6u8, 0u8, 0u8, 144u8, 6u8, 0u8, 0u8, 165u8, 6u8, 0u8, 0u8,
+                192u8, 6u8, 0u8, 0u8, 213u8, 6u8, 0u8, 0u8, 240u8, 6u8, 0u8, 0u8, 9u8, 7u8, 0u8,
+                0u8, 36u8, 7u8, 0u8, 0u8, 57u8, 7u8, 0u8, 0u8, 84u8, 7u8, 0u8, 0u8, 109u8, 7u8,
+                0u8, 0u8, 136u8, 7u8, 0u8, 0u8, 157u8, 7u8, 0u8, 0u8, 184u8, 7u8, 0u8, 0u8, 207u8,
+                7u8, 0u8, 0u8, 236u8, 7u8, 0u8, 0u8, 3u8, 8u8, 0u8, 0u8, 32u8, 8u8, 0u8, 0u8, 55u8,
+                8u8, 0u8, 0u8, 84u8, 8u8, 0u8, 0u8, 107u8, 8u8, 0u8, 0u8, 136u8, 8u8, 0u8, 0u8,
+                161u8, 8u8, 0u8, 0u8, 192u8, 8u8, 0u8, 0u8, 217u8, 8u8, 0u8, 0u8, 248u8, 8u8, 0u8,
+                0u8, 17u8, 9u8, 0u8, 0u8, 48u8, 9u8, 0u8, 0u8, 73u8, 9u8, 0u8, 0u8, 104u8, 9u8,
+                0u8, 0u8, 131u8, 9u8, 0u8, 0u8, 164u8, 9u8, 0u8, 0u8, 191u8, 9u8, 0u8, 0u8, 224u8,
+                9u8, 0u8, 0u8, 251u8, 9u8, 0u8, 0u8, 28u8, 10u8, 0u8, 0u8, 55u8, 10u8, 0u8, 0u8,
+                88u8, 10u8, 0u8, 0u8, 112u8, 10u8, 0u8, 0u8, 138u8, 10u8, 0u8, 0u8, 164u8, 10u8,
+                0u8, 0u8, 192u8, 10u8, 0u8, 0u8, 218u8, 10u8, 0u8, 0u8, 246u8, 10u8, 0u8, 0u8,
+                18u8, 11u8, 0u8, 0u8, 44u8, 11u8, 0u8, 0u8, 71u8, 11u8, 0u8, 0u8, 104u8, 11u8, 0u8,
+                0u8, 143u8, 11u8, 0u8, 0u8, 166u8, 11u8, 0u8, 0u8, 189u8, 11u8, 0u8, 0u8, 228u8,
+                11u8, 0u8, 0u8, 249u8, 11u8, 0u8, 0u8, 32u8, 12u8, 0u8, 0u8, 53u8, 12u8, 0u8, 0u8,
+                92u8, 12u8, 0u8, 0u8, 113u8, 12u8, 0u8, 0u8, 152u8, 12u8, 0u8, 0u8, 173u8, 12u8,
+                0u8, 0u8, 212u8, 12u8, 0u8, 0u8, 233u8, 12u8, 0u8, 0u8, 16u8, 13u8, 0u8, 0u8, 37u8,
+                13u8, 0u8, 0u8, 76u8, 13u8, 0u8, 0u8, 97u8, 13u8, 0u8, 0u8, 136u8, 13u8, 0u8, 0u8,
+                157u8, 13u8, 0u8, 0u8, 196u8, 13u8, 0u8, 0u8, 234u8, 13u8, 0u8, 0u8, 16u8, 14u8,
+                0u8, 0u8, 41u8, 14u8, 0u8, 0u8, 79u8, 14u8, 0u8, 0u8, 117u8, 14u8, 0u8, 0u8, 140u8,
+                14u8, 0u8, 0u8, 178u8, 14u8, 0u8, 0u8, 203u8, 14u8, 0u8, 0u8, 241u8, 14u8, 0u8,
+                0u8, 23u8, 15u8, 0u8, 0u8, 46u8, 15u8, 0u8, 0u8, 86u8, 15u8, 0u8, 0u8, 109u8, 15u8,
+                0u8, 0u8, 149u8, 15u8, 0u8, 0u8, 176u8, 15u8, 0u8, 0u8, 216u8, 15u8, 0u8, 0u8,
+                239u8, 15u8, 0u8, 0u8, 23u8, 16u8, 0u8, 0u8, 50u8, 16u8, 0u8, 0u8, 90u8, 16u8, 0u8,
+                0u8, 113u8, 16u8, 0u8, 0u8, 153u8, 16u8, 0u8, 0u8, 178u8, 16u8, 0u8, 0u8, 220u8,
+                16u8, 0u8, 0u8, 243u8, 16u8, 0u8, 0u8, 34u8, 17u8, 0u8, 0u8, 63u8, 17u8, 0u8, 0u8,
+                110u8, 17u8, 0u8, 0u8, 144u8, 17u8, 0u8, 0u8, 191u8, 17u8, 0u8, 0u8, 225u8, 17u8,
+                0u8, 0u8, 249u8, 17u8, 0u8, 0u8, 19u8, 18u8, 0u8, 0u8, 46u8, 18u8, 0u8, 0u8, 65u8,
+                18u8, 0u8, 0u8, 84u8, 18u8, 0u8, 0u8, 103u8, 18u8, 0u8, 0u8, 122u8, 18u8, 0u8, 0u8,
+                141u8, 18u8, 0u8, 0u8, 160u8, 18u8, 0u8, 0u8, 179u8, 18u8, 0u8, 0u8, 198u8, 18u8,
+                0u8, 0u8, 217u8, 18u8, 0u8, 0u8, 236u8, 18u8, 0u8, 0u8, 255u8, 18u8, 0u8, 0u8,
+                24u8, 19u8, 0u8, 0u8, 45u8, 19u8, 0u8, 0u8, 66u8, 19u8, 0u8, 0u8, 91u8, 19u8, 0u8,
+                0u8, 110u8, 19u8, 0u8, 0u8, 135u8, 19u8, 0u8, 0u8, 154u8, 19u8, 0u8, 0u8, 179u8,
+                19u8, 0u8, 0u8, 198u8, 19u8, 0u8, 0u8, 223u8, 19u8, 0u8, 0u8, 242u8, 19u8, 0u8,
+                0u8, 11u8, 20u8, 0u8, 0u8, 30u8, 20u8, 0u8, 0u8, 55u8, 20u8, 0u8, 0u8, 74u8, 20u8,
+                0u8, 0u8, 99u8, 20u8, 0u8, 0u8, 118u8, 20u8, 0u8, 0u8, 143u8, 20u8, 0u8, 0u8,
+                162u8, 20u8, 0u8, 0u8, 187u8, 20u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 179u8, 0u8, 1u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 180u8,
+                61u8, 37u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 45u8, 105u8, 116u8, 101u8,
+                109u8, 61u8, 0u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 215u8, 153u8, 215u8, 180u8, 226u8, 134u8, 146u8, 37u8, 104u8,
+                101u8, 98u8, 114u8, 101u8, 119u8, 45u8, 105u8, 116u8, 101u8, 109u8, 226u8, 134u8,
+                146u8, 0u8, 15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 215u8, 152u8, 215u8, 180u8, 215u8, 149u8, 0u8, 16u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 152u8, 215u8, 180u8,
+                215u8, 150u8, 0u8, 17u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 215u8, 153u8, 215u8, 180u8, 226u8, 134u8, 146u8, 37u8, 104u8,
+                101u8, 98u8, 114u8, 101u8, 119u8, 45u8, 105u8, 116u8, 101u8, 109u8, 226u8, 134u8,
+                146u8, 0u8, 20u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 215u8, 180u8, 215u8, 155u8, 0u8, 21u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 155u8, 215u8, 180u8, 226u8, 134u8,
+                146u8, 37u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 45u8, 105u8, 116u8, 101u8,
+                109u8, 226u8, 134u8, 146u8, 0u8, 30u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 180u8, 215u8, 156u8, 0u8, 31u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 156u8,
+                215u8, 180u8, 226u8, 134u8, 146u8, 37u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8,
+                45u8, 105u8, 116u8, 101u8, 109u8, 226u8, 134u8, 146u8, 0u8, 40u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 180u8, 215u8,
+                158u8, 0u8, 41u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 215u8, 158u8, 215u8, 180u8, 226u8, 134u8, 146u8, 37u8, 104u8, 101u8,
+                98u8, 114u8, 101u8, 119u8, 45u8, 105u8, 116u8, 101u8, 109u8, 226u8, 134u8, 146u8,
+                0u8, 50u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 215u8, 180u8, 215u8, 160u8, 0u8, 51u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 160u8, 215u8, 180u8, 226u8, 134u8,
+                146u8, 37u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 45u8, 105u8, 116u8, 101u8,
+                109u8, 226u8, 134u8, 146u8, 0u8, 60u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 180u8, 215u8, 161u8, 0u8, 61u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 161u8,
+                215u8, 180u8, 226u8, 134u8, 146u8, 37u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8,
+                45u8, 105u8, 116u8, 101u8, 109u8, 226u8, 134u8, 146u8, 0u8, 70u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 180u8, 215u8,
+                162u8, 0u8, 71u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 215u8, 162u8, 215u8, 180u8, 226u8, 134u8, 146u8, 37u8, 104u8, 101u8,
+                98u8, 114u8, 101u8, 119u8, 45u8, 105u8, 116u8, 101u8, 109u8, 226u8, 134u8, 146u8,
+                0u8, 80u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 215u8, 180u8, 215u8, 163u8, 0u8, 81u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 164u8, 215u8, 180u8, 226u8, 134u8,
+                146u8, 37u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 45u8, 105u8, 116u8, 101u8,
+                109u8, 226u8, 134u8, 146u8, 0u8, 90u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 180u8, 215u8, 166u8, 0u8, 91u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 166u8,
+                215u8, 180u8, 226u8, 134u8, 146u8, 37u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8,
+                45u8, 105u8, 116u8, 101u8, 109u8, 226u8, 134u8, 146u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 136u8, 146u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8,
+                46u8, 48u8, 48u8, 61u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 180u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 144u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 145u8, 0u8, 3u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 146u8,
+                0u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 215u8, 147u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 215u8, 148u8, 0u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 149u8, 0u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 150u8, 0u8, 8u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 151u8, 0u8,
+                9u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                215u8, 152u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 215u8, 153u8, 0u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 153u8, 226u8, 134u8, 146u8, 226u8,
+                134u8, 146u8, 0u8, 15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 215u8, 152u8, 215u8, 149u8, 0u8, 16u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 152u8, 215u8, 150u8, 0u8,
+                17u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                215u8, 153u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 20u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 155u8, 0u8,
+                21u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                215u8, 155u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 30u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 156u8, 0u8,
+                31u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                215u8, 156u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 40u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 158u8, 0u8,
+                41u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                215u8, 158u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 50u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 160u8, 0u8,
+                51u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                215u8, 160u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 60u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 161u8, 0u8,
+                61u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                215u8, 161u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 70u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 162u8, 0u8,
+                71u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                215u8, 162u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 80u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 163u8, 0u8,
+                81u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                215u8, 164u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 90u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 166u8, 0u8,
+                91u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                215u8, 166u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 100u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 167u8, 0u8,
+                101u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                215u8, 167u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 200u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 168u8, 0u8,
+                201u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                215u8, 168u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 42u8, 1u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 168u8, 215u8,
+                151u8, 215u8, 166u8, 0u8, 43u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 168u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8,
+                0u8, 44u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 215u8, 169u8, 0u8, 45u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 169u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8,
+                0u8, 48u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 215u8, 147u8, 215u8, 169u8, 0u8, 49u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 169u8, 226u8, 134u8, 146u8, 226u8,
+                134u8, 146u8, 0u8, 88u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 215u8, 169u8, 215u8, 147u8, 215u8, 158u8, 0u8, 89u8, 1u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 169u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 144u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 170u8, 0u8, 145u8, 1u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 170u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 244u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 170u8, 215u8, 167u8, 0u8,
+                245u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                215u8, 170u8, 215u8, 167u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 88u8,
+                2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8,
+                170u8, 215u8, 168u8, 0u8, 89u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 170u8, 215u8, 168u8, 226u8, 134u8, 146u8, 226u8,
+                134u8, 146u8, 0u8, 186u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 215u8, 170u8, 215u8, 168u8, 215u8, 151u8, 215u8, 166u8, 0u8,
+                187u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                215u8, 170u8, 215u8, 168u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 188u8,
+                2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8,
+                170u8, 215u8, 169u8, 0u8, 189u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 170u8, 215u8, 169u8, 226u8, 134u8, 146u8,
+                226u8, 134u8, 146u8, 0u8, 232u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 170u8, 215u8, 169u8, 215u8, 147u8, 215u8,
+                158u8, 0u8, 233u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 215u8, 170u8, 215u8, 169u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 32u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 215u8, 170u8, 215u8, 170u8, 0u8, 33u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 170u8, 215u8, 170u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 132u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 170u8, 215u8, 170u8, 215u8, 167u8,
+                0u8, 133u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 215u8, 170u8, 215u8, 170u8, 215u8, 167u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 215u8, 170u8, 215u8, 170u8, 215u8, 168u8, 0u8, 233u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 170u8, 215u8,
+                170u8, 215u8, 168u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 76u8, 4u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 170u8,
+                215u8, 170u8, 215u8, 169u8, 0u8, 77u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 170u8, 215u8, 170u8, 215u8, 169u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 176u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 170u8, 215u8, 170u8, 215u8, 170u8,
+                0u8, 177u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 215u8, 170u8, 215u8, 170u8, 215u8, 170u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 20u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 215u8, 170u8, 215u8, 170u8, 215u8, 170u8, 215u8, 167u8, 0u8, 21u8,
+                5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8,
+                170u8, 215u8, 170u8, 215u8, 170u8, 215u8, 167u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 120u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 215u8, 170u8, 215u8, 170u8, 215u8, 170u8, 215u8, 168u8, 0u8, 121u8,
+                5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8,
+                170u8, 215u8, 170u8, 215u8, 170u8, 215u8, 168u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 220u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 215u8, 170u8, 215u8, 170u8, 215u8, 170u8, 215u8, 169u8, 0u8, 221u8,
+                5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8,
+                170u8, 215u8, 170u8, 215u8, 170u8, 215u8, 169u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 64u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 215u8, 170u8, 215u8, 170u8, 215u8, 170u8, 215u8, 170u8, 0u8, 65u8,
+                6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8,
+                170u8, 215u8, 170u8, 215u8, 170u8, 215u8, 170u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 164u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 215u8, 170u8, 215u8, 170u8, 215u8, 170u8, 215u8, 170u8, 215u8,
+                167u8, 0u8, 165u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 215u8, 170u8, 215u8, 170u8, 215u8, 170u8, 215u8, 170u8, 215u8,
+                167u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 8u8, 7u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 170u8, 215u8, 170u8,
+                215u8, 170u8, 215u8, 170u8, 215u8, 168u8, 0u8, 9u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 170u8, 215u8, 170u8, 215u8,
+                170u8, 215u8, 170u8, 215u8, 168u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8,
+                108u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                215u8, 170u8, 215u8, 170u8, 215u8, 170u8, 215u8, 170u8, 215u8, 169u8, 0u8, 109u8,
+                7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8,
+                170u8, 215u8, 170u8, 215u8, 170u8, 215u8, 170u8, 215u8, 169u8, 226u8, 134u8, 146u8,
+                226u8, 134u8, 146u8, 0u8, 208u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 170u8, 215u8, 170u8, 215u8, 170u8, 215u8,
+                170u8, 215u8, 170u8, 0u8, 209u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 170u8, 215u8, 170u8, 215u8, 170u8, 215u8,
+                170u8, 215u8, 170u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 52u8, 8u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8,
+                44u8, 35u8, 35u8, 48u8, 61u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 37u8, 104u8, 101u8, 98u8, 114u8, 101u8,
+                119u8, 61u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 61u8, 37u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 61u8, 0u8,
+                11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                61u8, 37u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 61u8, 215u8, 179u8, 0u8,
+                100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                61u8, 37u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 61u8, 0u8, 101u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 37u8,
+                104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 61u8, 215u8, 179u8, 0u8, 145u8, 1u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 37u8,
+                104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 61u8, 215u8, 179u8, 1u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 136u8,
+                146u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8,
+                48u8, 46u8, 48u8, 48u8, 61u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 37u8, 104u8, 101u8, 98u8, 114u8, 101u8,
+                119u8, 45u8, 105u8, 116u8, 101u8, 109u8, 61u8, 215u8, 179u8, 0u8, 11u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 153u8,
+                215u8, 180u8, 226u8, 134u8, 146u8, 37u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8,
+                45u8, 105u8, 116u8, 101u8, 109u8, 226u8, 134u8, 146u8, 0u8, 15u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 152u8, 215u8,
+                180u8, 215u8, 149u8, 0u8, 16u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 152u8, 215u8, 180u8, 215u8, 150u8, 0u8, 17u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8,
+                153u8, 215u8, 180u8, 226u8, 134u8, 146u8, 37u8, 104u8, 101u8, 98u8, 114u8, 101u8,
+                119u8, 45u8, 105u8, 116u8, 101u8, 109u8, 226u8, 134u8, 146u8, 0u8, 20u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 155u8,
+                215u8, 179u8, 0u8, 21u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 215u8, 155u8, 215u8, 180u8, 226u8, 134u8, 146u8, 37u8, 104u8,
+                101u8, 98u8, 114u8, 101u8, 119u8, 45u8, 105u8, 116u8, 101u8, 109u8, 226u8, 134u8,
+                146u8, 0u8, 30u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 215u8, 156u8, 215u8, 179u8, 0u8, 31u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 156u8, 215u8, 180u8, 226u8, 134u8,
+                146u8, 37u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 45u8, 105u8, 116u8, 101u8,
+                109u8, 226u8, 134u8, 146u8, 0u8, 40u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 158u8, 215u8, 179u8, 0u8, 41u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 158u8,
+                215u8, 180u8, 226u8, 134u8, 146u8, 37u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8,
+                45u8, 105u8, 116u8, 101u8, 109u8, 226u8, 134u8, 146u8, 0u8, 50u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 160u8, 215u8,
+                179u8, 0u8, 51u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 215u8, 160u8, 215u8, 180u8, 226u8, 134u8, 146u8, 37u8, 104u8, 101u8,
+                98u8, 114u8, 101u8, 119u8, 45u8, 105u8, 116u8, 101u8, 109u8, 226u8, 134u8, 146u8,
+                0u8, 60u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 215u8, 161u8, 215u8, 179u8, 0u8, 61u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 161u8, 215u8, 180u8, 226u8, 134u8,
+                146u8, 37u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 45u8, 105u8, 116u8, 101u8,
+                109u8, 226u8, 134u8, 146u8, 0u8, 70u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 162u8, 215u8, 179u8, 0u8, 71u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 162u8,
+                215u8, 180u8, 226u8, 134u8, 146u8, 37u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8,
+                45u8, 105u8, 116u8, 101u8, 109u8, 226u8, 134u8, 146u8, 0u8, 80u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 164u8, 215u8,
+                179u8, 0u8, 81u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 215u8, 164u8, 215u8, 180u8, 226u8, 134u8, 146u8, 37u8, 104u8, 101u8,
+                98u8, 114u8, 101u8, 119u8, 45u8, 105u8, 116u8, 101u8, 109u8, 226u8, 134u8, 146u8,
+                0u8, 90u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 215u8, 166u8, 215u8, 179u8, 0u8, 91u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 166u8, 215u8, 180u8, 226u8, 134u8,
+                146u8, 37u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 45u8, 105u8, 116u8, 101u8,
+                109u8, 226u8, 134u8, 146u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 167u8, 226u8, 134u8, 146u8, 37u8, 37u8,
+                104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 45u8, 48u8, 45u8, 57u8, 57u8, 226u8,
+                134u8, 146u8, 0u8, 200u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 215u8, 168u8, 226u8, 134u8, 146u8, 37u8, 37u8, 104u8, 101u8,
+                98u8, 114u8, 101u8, 119u8, 45u8, 48u8, 45u8, 57u8, 57u8, 226u8, 134u8, 146u8, 0u8,
+                42u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                215u8, 168u8, 215u8, 151u8, 215u8, 180u8, 215u8, 166u8, 0u8, 43u8, 1u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 168u8, 226u8,
+                134u8, 146u8, 37u8, 37u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 45u8, 48u8,
+                45u8, 57u8, 57u8, 226u8, 134u8, 146u8, 0u8, 44u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 169u8, 226u8, 134u8, 146u8,
+                37u8, 37u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 45u8, 48u8, 45u8, 57u8, 57u8,
+                226u8, 134u8, 146u8, 0u8, 48u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 147u8, 215u8, 180u8, 215u8, 169u8, 0u8, 49u8, 1u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8,
+                169u8, 226u8, 134u8, 146u8, 37u8, 37u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8,
+                45u8, 48u8, 45u8, 57u8, 57u8, 226u8, 134u8, 146u8, 0u8, 88u8, 1u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 169u8, 215u8,
+                147u8, 215u8, 180u8, 215u8, 158u8, 0u8, 89u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 169u8, 226u8, 134u8, 146u8, 37u8,
+                37u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 45u8, 48u8, 45u8, 57u8, 57u8, 226u8,
+                134u8, 146u8, 0u8, 144u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 215u8, 170u8, 226u8, 134u8, 146u8, 37u8, 37u8, 104u8, 101u8,
+                98u8, 114u8, 101u8, 119u8, 45u8, 48u8, 45u8, 57u8, 57u8, 226u8, 134u8, 146u8, 0u8,
+                244u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                215u8, 170u8, 215u8, 180u8, 215u8, 167u8, 0u8, 245u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 170u8, 215u8, 167u8, 226u8,
+                134u8, 146u8, 37u8, 37u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 45u8, 48u8,
+                45u8, 57u8, 57u8, 226u8, 134u8, 146u8, 0u8, 88u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 170u8, 215u8, 180u8, 215u8,
+                168u8, 0u8, 89u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 215u8, 170u8, 215u8, 168u8, 226u8, 134u8, 146u8, 37u8, 37u8, 104u8,
+                101u8, 98u8, 114u8, 101u8, 119u8, 45u8, 48u8, 45u8, 57u8, 57u8, 226u8, 134u8,
+                146u8, 0u8, 186u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 215u8, 170u8, 215u8, 168u8, 215u8, 151u8, 215u8, 180u8, 215u8,
+                166u8, 0u8, 187u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 215u8, 170u8, 215u8, 168u8, 226u8, 134u8, 146u8, 37u8, 37u8, 104u8,
+                101u8, 98u8, 114u8, 101u8, 119u8, 45u8, 48u8, 45u8, 57u8, 57u8, 226u8, 134u8,
+                146u8, 0u8, 188u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 215u8, 170u8, 215u8, 180u8, 215u8, 169u8, 0u8, 189u8, 2u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 170u8, 215u8,
+                169u8, 226u8, 134u8, 146u8, 37u8, 37u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8,
+                45u8, 48u8, 45u8, 57u8, 57u8, 226u8, 134u8, 146u8, 0u8, 232u8, 2u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 170u8, 215u8,
+                169u8, 215u8, 147u8, 215u8, 180u8, 215u8, 158u8, 0u8, 233u8, 2u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 170u8, 215u8,
+                169u8, 226u8, 134u8, 146u8, 37u8, 37u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8,
+                45u8, 48u8, 45u8, 57u8, 57u8, 226u8, 134u8, 146u8, 0u8, 32u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 170u8, 215u8,
+                180u8, 215u8, 170u8, 0u8, 33u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 170u8, 215u8, 170u8, 226u8, 134u8, 146u8, 37u8,
+                37u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 45u8, 48u8, 45u8, 57u8, 57u8, 226u8,
+                134u8, 146u8, 0u8, 132u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 215u8, 170u8, 215u8, 170u8, 215u8, 180u8, 215u8, 167u8, 0u8,
+                133u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                215u8, 170u8, 215u8, 170u8, 215u8, 167u8, 226u8, 134u8, 146u8, 37u8, 37u8, 104u8,
+                101u8, 98u8, 114u8, 101u8, 119u8, 45u8, 48u8, 45u8, 57u8, 57u8, 226u8, 134u8,
+                146u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 215u8, 144u8, 215u8, 156u8, 215u8, 163u8, 0u8, 233u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8,
+                37u8, 37u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 45u8, 116u8, 104u8, 111u8,
+                117u8, 115u8, 97u8, 110u8, 100u8, 115u8, 226u8, 134u8, 144u8, 226u8, 134u8, 146u8,
+                226u8, 134u8, 146u8, 0u8, 208u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 144u8, 215u8, 156u8, 215u8, 164u8, 215u8,
+                153u8, 215u8, 153u8, 215u8, 157u8, 0u8, 209u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 37u8, 37u8, 104u8,
+                101u8, 98u8, 114u8, 101u8, 119u8, 45u8, 116u8, 104u8, 111u8, 117u8, 115u8, 97u8,
+                110u8, 100u8, 115u8, 226u8, 134u8, 144u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8,
+                0u8, 184u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 32u8, 215u8, 144u8, 215u8,
+                156u8, 215u8, 164u8, 215u8, 153u8, 215u8, 157u8, 0u8, 185u8, 11u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 37u8,
+                37u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 45u8, 116u8, 104u8, 111u8, 117u8,
+                115u8, 97u8, 110u8, 100u8, 115u8, 226u8, 134u8, 144u8, 226u8, 134u8, 146u8, 226u8,
+                134u8, 146u8, 0u8, 64u8, 66u8, 15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 64u8, 66u8, 15u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 144u8, 215u8, 156u8, 215u8, 163u8, 32u8, 215u8,
+                144u8, 215u8, 156u8, 215u8, 164u8, 215u8, 153u8, 215u8, 157u8, 0u8, 65u8, 66u8,
+                15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 64u8, 66u8, 15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8,
+                35u8, 44u8, 35u8, 35u8, 48u8, 61u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 136u8, 146u8, 226u8, 134u8, 146u8,
+                226u8, 134u8, 146u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 46u8, 48u8, 48u8,
+                61u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 215u8, 180u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 144u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 145u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 146u8, 0u8, 4u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 147u8,
+                0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 215u8, 148u8, 0u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 215u8, 149u8, 0u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 150u8, 0u8, 8u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 151u8, 0u8, 9u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 152u8, 0u8,
+                10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                215u8, 153u8, 0u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 215u8, 153u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8,
+                15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                215u8, 152u8, 215u8, 149u8, 0u8, 16u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 152u8, 215u8, 150u8, 0u8, 17u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 153u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 20u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 155u8, 0u8, 21u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 155u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 30u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 156u8, 0u8, 31u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 156u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 40u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 158u8, 0u8, 41u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 158u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 50u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 160u8, 0u8, 51u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 160u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 60u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 161u8, 0u8, 61u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 161u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 70u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 162u8, 0u8, 71u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 162u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 80u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 164u8, 0u8, 81u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 164u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 90u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 166u8, 0u8, 91u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 166u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 37u8, 37u8, 104u8, 101u8,
+                98u8, 114u8, 101u8, 119u8, 45u8, 105u8, 116u8, 101u8, 109u8, 45u8, 104u8, 117u8,
+                110u8, 100u8, 114u8, 101u8, 100u8, 115u8, 61u8,
+            ])
+        },
+    },
+};
+static UND_U_NU_JPAN: &DataStruct = &::icu_decimal::provider::AlgorithmicSymbolsV1 {
+    rule_set: alloc::borrow::Cow::Borrowed("spellout-cardinal"),
+    rules: ::icu_decimal::provider::RbnfRulesV1 {
+        rule_sets: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 115u8, 112u8, 101u8, 108u8, 108u8, 111u8,
+                        117u8, 116u8, 45u8, 99u8, 97u8, 114u8, 100u8, 105u8, 110u8, 97u8, 108u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8, 33u8, 0u8, 0u8, 0u8])
+                },
+            )
+        },
+        rules: unsafe {
+            ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                33u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 35u8, 0u8, 0u8, 0u8, 70u8, 0u8, 0u8, 0u8,
+                90u8, 0u8, 0u8, 0u8, 110u8, 0u8, 0u8, 0u8, 130u8, 0u8, 0u8, 0u8, 150u8, 0u8, 0u8,
+                0u8, 170u8, 0u8, 0u8, 0u8, 190u8, 0u8, 0u8, 0u8, 210u8, 0u8, 0u8, 0u8, 230u8, 0u8,
+                0u8, 0u8, 250u8, 0u8, 0u8, 0u8, 14u8, 1u8, 0u8, 0u8, 34u8, 1u8, 0u8, 0u8, 60u8,
+                1u8, 0u8, 0u8, 86u8, 1u8, 0u8, 0u8, 118u8, 1u8, 0u8, 0u8, 138u8, 1u8, 0u8, 0u8,
+                164u8, 1u8, 0u8, 0u8, 190u8, 1u8, 0u8, 0u8, 222u8, 1u8, 0u8, 0u8, 242u8, 1u8, 0u8,
+                0u8, 12u8, 2u8, 0u8, 0u8, 38u8, 2u8, 0u8, 0u8, 70u8, 2u8, 0u8, 0u8, 96u8, 2u8, 0u8,
+                0u8, 128u8, 2u8, 0u8, 0u8, 154u8, 2u8, 0u8, 0u8, 186u8, 2u8, 0u8, 0u8, 212u8, 2u8,
+                0u8, 0u8, 244u8, 2u8, 0u8, 0u8, 14u8, 3u8, 0u8, 0u8, 46u8, 3u8, 0u8, 0u8, 1u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 227u8,
+                131u8, 158u8, 227u8, 130u8, 164u8, 227u8, 131u8, 138u8, 227u8, 130u8, 185u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8,
+                227u8, 131u8, 187u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 227u8, 128u8, 135u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 228u8, 184u8, 128u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 228u8, 186u8, 140u8, 0u8,
+                3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                228u8, 184u8, 137u8, 0u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 229u8, 155u8, 155u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 228u8, 186u8, 148u8, 0u8, 6u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 229u8,
+                133u8, 173u8, 0u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 228u8, 184u8, 131u8, 0u8, 8u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 229u8, 133u8, 171u8, 0u8, 9u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 228u8, 185u8,
+                157u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 229u8, 141u8, 129u8, 0u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 229u8, 141u8, 129u8, 226u8, 134u8, 146u8, 226u8,
+                134u8, 146u8, 0u8, 20u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 229u8, 141u8, 129u8,
+                0u8, 21u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 229u8, 141u8, 129u8, 226u8, 134u8,
+                146u8, 226u8, 134u8, 146u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 231u8, 153u8, 190u8, 0u8, 101u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 231u8, 153u8, 190u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 200u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8,
+                144u8, 231u8, 153u8, 190u8, 0u8, 201u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 231u8,
+                153u8, 190u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 232u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 229u8, 141u8, 131u8,
+                0u8, 233u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 229u8, 141u8, 131u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 208u8,
+                7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8,
+                134u8, 144u8, 226u8, 134u8, 144u8, 229u8, 141u8, 131u8, 0u8, 209u8, 7u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8,
+                226u8, 134u8, 144u8, 229u8, 141u8, 131u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8,
+                0u8, 16u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 228u8, 184u8, 135u8, 0u8, 17u8,
+                39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 228u8, 184u8, 135u8, 226u8, 134u8, 146u8,
+                226u8, 134u8, 146u8, 0u8, 0u8, 225u8, 245u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8,
+                245u8, 5u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 229u8,
+                132u8, 132u8, 0u8, 1u8, 225u8, 245u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 245u8,
+                5u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 229u8, 132u8,
+                132u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8, 16u8, 165u8, 212u8,
+                232u8, 0u8, 0u8, 0u8, 0u8, 16u8, 165u8, 212u8, 232u8, 0u8, 0u8, 0u8, 226u8, 134u8,
+                144u8, 226u8, 134u8, 144u8, 229u8, 133u8, 134u8, 0u8, 1u8, 16u8, 165u8, 212u8,
+                232u8, 0u8, 0u8, 0u8, 0u8, 16u8, 165u8, 212u8, 232u8, 0u8, 0u8, 0u8, 226u8, 134u8,
+                144u8, 226u8, 134u8, 144u8, 229u8, 133u8, 134u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 0u8, 0u8, 193u8, 111u8, 242u8, 134u8, 35u8, 0u8, 0u8, 0u8, 193u8,
+                111u8, 242u8, 134u8, 35u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 228u8,
+                186u8, 172u8, 0u8, 1u8, 0u8, 193u8, 111u8, 242u8, 134u8, 35u8, 0u8, 0u8, 0u8,
+                193u8, 111u8, 242u8, 134u8, 35u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8,
+                228u8, 186u8, 172u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8, 0u8,
+                100u8, 167u8, 179u8, 182u8, 224u8, 13u8, 0u8, 0u8, 100u8, 167u8, 179u8, 182u8,
+                224u8, 13u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 61u8,
+            ])
+        },
+    },
+};
+static UND_U_NU_JPANFIN: &DataStruct = &::icu_decimal::provider::AlgorithmicSymbolsV1 {
+    rule_set: alloc::borrow::Cow::Borrowed("spellout-cardinal-financial"),
+    rules: ::icu_decimal::provider::RbnfRulesV1 {
+        rule_sets: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 115u8, 112u8, 101u8, 108u8, 108u8, 111u8,
+                        117u8, 116u8, 45u8, 99u8, 97u8, 114u8, 100u8, 105u8, 110u8, 97u8, 108u8,
+                        45u8, 102u8, 105u8, 110u8, 97u8, 110u8, 99u8, 105u8, 97u8, 108u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8, 29u8, 0u8, 0u8, 0u8])
+                },
+            )
+        },
+        rules: unsafe {
+            ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                29u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 35u8, 0u8, 0u8, 0u8, 70u8, 0u8, 0u8, 0u8,
+                90u8, 0u8, 0u8, 0u8, 110u8, 0u8, 0u8, 0u8, 130u8, 0u8, 0u8, 0u8, 150u8, 0u8, 0u8,
+                0u8, 170u8, 0u8, 0u8, 0u8, 190u8, 0u8, 0u8, 0u8, 210u8, 0u8, 0u8, 0u8, 230u8, 0u8,
+                0u8, 0u8, 250u8, 0u8, 0u8, 0u8, 14u8, 1u8, 0u8, 0u8, 34u8, 1u8, 0u8, 0u8, 60u8,
+                1u8, 0u8, 0u8, 86u8, 1u8, 0u8, 0u8, 118u8, 1u8, 0u8, 0u8, 144u8, 1u8, 0u8, 0u8,
+                176u8, 1u8, 0u8, 0u8, 202u8, 1u8, 0u8, 0u8, 234u8, 1u8, 0u8, 0u8, 4u8, 2u8, 0u8,
+                0u8, 36u8, 2u8, 0u8, 0u8, 62u8, 2u8, 0u8, 0u8, 94u8, 2u8, 0u8, 0u8, 120u8, 2u8,
+                0u8, 0u8, 152u8, 2u8, 0u8, 0u8, 178u8, 2u8, 0u8, 0u8, 210u8, 2u8, 0u8, 0u8, 1u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                227u8, 131u8, 158u8, 227u8, 130u8, 164u8, 227u8, 131u8, 138u8, 227u8, 130u8, 185u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8,
+                144u8, 231u8, 130u8, 185u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 233u8, 155u8, 182u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 229u8, 163u8, 177u8, 0u8, 2u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 229u8, 188u8, 144u8,
+                0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 229u8, 143u8, 130u8, 0u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 229u8, 155u8, 155u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 228u8, 188u8, 141u8, 0u8,
+                6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                229u8, 133u8, 173u8, 0u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 228u8, 184u8, 131u8, 0u8, 8u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 229u8, 133u8, 171u8, 0u8, 9u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 228u8,
+                185u8, 157u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 230u8, 139u8, 190u8, 0u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 230u8, 139u8, 190u8, 226u8, 134u8,
+                146u8, 226u8, 134u8, 146u8, 0u8, 20u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 230u8,
+                139u8, 190u8, 0u8, 21u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 230u8, 139u8, 190u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8,
+                144u8, 231u8, 153u8, 190u8, 0u8, 101u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 231u8,
+                153u8, 190u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 232u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8,
+                226u8, 134u8, 144u8, 229u8, 141u8, 131u8, 0u8, 233u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8,
+                144u8, 229u8, 141u8, 131u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 16u8,
+                39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 232u8, 144u8, 172u8, 0u8, 17u8, 39u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8,
+                134u8, 144u8, 226u8, 134u8, 144u8, 232u8, 144u8, 172u8, 226u8, 134u8, 146u8, 226u8,
+                134u8, 146u8, 0u8, 0u8, 225u8, 245u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 245u8,
+                5u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 229u8, 132u8,
+                132u8, 0u8, 1u8, 225u8, 245u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 245u8, 5u8,
+                0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 229u8, 132u8, 132u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8, 16u8, 165u8, 212u8, 232u8, 0u8,
+                0u8, 0u8, 0u8, 16u8, 165u8, 212u8, 232u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8,
+                226u8, 134u8, 144u8, 229u8, 133u8, 134u8, 0u8, 1u8, 16u8, 165u8, 212u8, 232u8, 0u8,
+                0u8, 0u8, 0u8, 16u8, 165u8, 212u8, 232u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8,
+                226u8, 134u8, 144u8, 229u8, 133u8, 134u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8,
+                0u8, 0u8, 0u8, 193u8, 111u8, 242u8, 134u8, 35u8, 0u8, 0u8, 0u8, 193u8, 111u8,
+                242u8, 134u8, 35u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 228u8, 186u8,
+                172u8, 0u8, 1u8, 0u8, 193u8, 111u8, 242u8, 134u8, 35u8, 0u8, 0u8, 0u8, 193u8,
+                111u8, 242u8, 134u8, 35u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 228u8,
+                186u8, 172u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 100u8,
+                167u8, 179u8, 182u8, 224u8, 13u8, 0u8, 0u8, 100u8, 167u8, 179u8, 182u8, 224u8,
+                13u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 61u8,
+            ])
+        },
+    },
+};
+static UND_U_NU_JPANYEAR: &DataStruct = &::icu_decimal::provider::AlgorithmicSymbolsV1 {
+    rule_set: alloc::borrow::Cow::Borrowed("spellout-numbering-year-latn"),
+    rules: ::icu_decimal::provider::RbnfRulesV1 {
+        rule_sets: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 115u8, 112u8, 101u8, 108u8, 108u8, 111u8,
+                        117u8, 116u8, 45u8, 110u8, 117u8, 109u8, 98u8, 101u8, 114u8, 105u8, 110u8,
+                        103u8, 45u8, 121u8, 101u8, 97u8, 114u8, 45u8, 108u8, 97u8, 116u8, 110u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8, 4u8, 0u8, 0u8, 0u8])
+                },
+            )
+        },
+        rules: unsafe {
+            ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 22u8, 0u8, 0u8, 0u8, 42u8, 0u8, 0u8, 0u8,
+                62u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 48u8, 46u8, 48u8, 61u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 48u8, 61u8, 0u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                229u8, 133u8, 131u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 48u8, 61u8,
+            ])
+        },
+    },
+};
+static UND_U_NU_ROMAN: &DataStruct = &::icu_decimal::provider::AlgorithmicSymbolsV1 {
+    rule_set: alloc::borrow::Cow::Borrowed("roman-upper"),
+    rules: ::icu_decimal::provider::RbnfRulesV1 {
+        rule_sets: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 114u8, 111u8, 109u8, 97u8, 110u8, 45u8,
+                        117u8, 112u8, 112u8, 101u8, 114u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8, 91u8, 0u8, 0u8, 0u8])
+                },
+            )
+        },
+        rules: unsafe {
+            ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                91u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 53u8, 0u8, 0u8, 0u8,
+                71u8, 0u8, 0u8, 0u8, 89u8, 0u8, 0u8, 0u8, 108u8, 0u8, 0u8, 0u8, 128u8, 0u8, 0u8,
+                0u8, 147u8, 0u8, 0u8, 0u8, 165u8, 0u8, 0u8, 0u8, 184u8, 0u8, 0u8, 0u8, 204u8, 0u8,
+                0u8, 0u8, 225u8, 0u8, 0u8, 0u8, 244u8, 0u8, 0u8, 0u8, 6u8, 1u8, 0u8, 0u8, 30u8,
+                1u8, 0u8, 0u8, 49u8, 1u8, 0u8, 0u8, 74u8, 1u8, 0u8, 0u8, 94u8, 1u8, 0u8, 0u8,
+                120u8, 1u8, 0u8, 0u8, 139u8, 1u8, 0u8, 0u8, 164u8, 1u8, 0u8, 0u8, 182u8, 1u8, 0u8,
+                0u8, 206u8, 1u8, 0u8, 0u8, 225u8, 1u8, 0u8, 0u8, 250u8, 1u8, 0u8, 0u8, 14u8, 2u8,
+                0u8, 0u8, 40u8, 2u8, 0u8, 0u8, 61u8, 2u8, 0u8, 0u8, 88u8, 2u8, 0u8, 0u8, 107u8,
+                2u8, 0u8, 0u8, 132u8, 2u8, 0u8, 0u8, 150u8, 2u8, 0u8, 0u8, 174u8, 2u8, 0u8, 0u8,
+                193u8, 2u8, 0u8, 0u8, 218u8, 2u8, 0u8, 0u8, 238u8, 2u8, 0u8, 0u8, 8u8, 3u8, 0u8,
+                0u8, 27u8, 3u8, 0u8, 0u8, 52u8, 3u8, 0u8, 0u8, 70u8, 3u8, 0u8, 0u8, 94u8, 3u8, 0u8,
+                0u8, 113u8, 3u8, 0u8, 0u8, 138u8, 3u8, 0u8, 0u8, 158u8, 3u8, 0u8, 0u8, 184u8, 3u8,
+                0u8, 0u8, 205u8, 3u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 251u8, 3u8, 0u8, 0u8, 20u8,
+                4u8, 0u8, 0u8, 38u8, 4u8, 0u8, 0u8, 62u8, 4u8, 0u8, 0u8, 81u8, 4u8, 0u8, 0u8,
+                106u8, 4u8, 0u8, 0u8, 126u8, 4u8, 0u8, 0u8, 152u8, 4u8, 0u8, 0u8, 173u8, 4u8, 0u8,
+                0u8, 200u8, 4u8, 0u8, 0u8, 220u8, 4u8, 0u8, 0u8, 246u8, 4u8, 0u8, 0u8, 11u8, 5u8,
+                0u8, 0u8, 38u8, 5u8, 0u8, 0u8, 60u8, 5u8, 0u8, 0u8, 88u8, 5u8, 0u8, 0u8, 111u8,
+                5u8, 0u8, 0u8, 140u8, 5u8, 0u8, 0u8, 161u8, 5u8, 0u8, 0u8, 188u8, 5u8, 0u8, 0u8,
+                208u8, 5u8, 0u8, 0u8, 234u8, 5u8, 0u8, 0u8, 1u8, 6u8, 0u8, 0u8, 30u8, 6u8, 0u8,
+                0u8, 56u8, 6u8, 0u8, 0u8, 88u8, 6u8, 0u8, 0u8, 111u8, 6u8, 0u8, 0u8, 140u8, 6u8,
+                0u8, 0u8, 160u8, 6u8, 0u8, 0u8, 186u8, 6u8, 0u8, 0u8, 209u8, 6u8, 0u8, 0u8, 238u8,
+                6u8, 0u8, 0u8, 8u8, 7u8, 0u8, 0u8, 40u8, 7u8, 0u8, 0u8, 69u8, 7u8, 0u8, 0u8, 104u8,
+                7u8, 0u8, 0u8, 127u8, 7u8, 0u8, 0u8, 156u8, 7u8, 0u8, 0u8, 176u8, 7u8, 0u8, 0u8,
+                202u8, 7u8, 0u8, 0u8, 225u8, 7u8, 0u8, 0u8, 254u8, 7u8, 0u8, 0u8, 24u8, 8u8, 0u8,
+                0u8, 56u8, 8u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 136u8, 146u8, 226u8, 134u8, 146u8, 226u8,
+                134u8, 146u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 46u8, 48u8, 48u8, 61u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                78u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 73u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 73u8, 73u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 73u8, 73u8, 73u8, 0u8, 4u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 73u8, 86u8, 0u8, 5u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 86u8, 0u8,
+                6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                86u8, 73u8, 0u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 86u8, 73u8, 73u8, 0u8, 8u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 86u8, 73u8, 73u8, 73u8, 0u8, 9u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 73u8, 88u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 88u8,
+                0u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 88u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 20u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 88u8, 88u8, 0u8, 21u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 88u8, 88u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 30u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 88u8, 88u8, 88u8, 0u8, 31u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 88u8, 88u8,
+                88u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 40u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 88u8, 76u8, 0u8, 41u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 88u8, 76u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 50u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 76u8, 0u8, 51u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 76u8, 226u8, 134u8, 146u8,
+                226u8, 134u8, 146u8, 0u8, 60u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 76u8, 88u8, 0u8, 61u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 76u8, 88u8, 226u8, 134u8, 146u8, 226u8,
+                134u8, 146u8, 0u8, 70u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 76u8, 88u8, 88u8, 0u8, 71u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 76u8, 88u8, 88u8, 226u8, 134u8, 146u8,
+                226u8, 134u8, 146u8, 0u8, 80u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 76u8, 88u8, 88u8, 88u8, 0u8, 81u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 76u8, 88u8, 88u8, 88u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 90u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 88u8, 67u8, 0u8, 91u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 88u8, 67u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 67u8, 0u8, 101u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 67u8, 226u8, 134u8, 146u8,
+                226u8, 134u8, 146u8, 0u8, 200u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 67u8, 67u8, 0u8, 201u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 67u8, 67u8, 226u8, 134u8, 146u8,
+                226u8, 134u8, 146u8, 0u8, 44u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 67u8, 67u8, 67u8, 0u8, 45u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 67u8, 67u8, 67u8, 226u8, 134u8,
+                146u8, 226u8, 134u8, 146u8, 0u8, 144u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 67u8, 68u8, 0u8, 145u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 67u8, 68u8, 226u8, 134u8,
+                146u8, 226u8, 134u8, 146u8, 0u8, 244u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 68u8, 0u8, 245u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 68u8, 226u8, 134u8, 146u8, 226u8,
+                134u8, 146u8, 0u8, 88u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 68u8, 67u8, 0u8, 89u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 68u8, 67u8, 226u8, 134u8, 146u8, 226u8,
+                134u8, 146u8, 0u8, 188u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 68u8, 67u8, 67u8, 0u8, 189u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 68u8, 67u8, 67u8, 226u8, 134u8,
+                146u8, 226u8, 134u8, 146u8, 0u8, 32u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 68u8, 67u8, 67u8, 67u8, 0u8, 33u8, 3u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 68u8, 67u8,
+                67u8, 67u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 132u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 67u8, 77u8, 0u8,
+                133u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                67u8, 77u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 232u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 77u8, 0u8, 233u8,
+                3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 77u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 208u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 77u8, 77u8, 0u8, 209u8, 7u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 77u8, 77u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 184u8, 11u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 77u8, 77u8, 77u8, 0u8, 185u8,
+                11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 77u8,
+                77u8, 77u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 160u8, 15u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 77u8, 226u8, 134u8,
+                129u8, 0u8, 161u8, 15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 77u8, 226u8, 134u8, 129u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8,
+                0u8, 136u8, 19u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 226u8, 134u8, 129u8, 0u8, 137u8, 19u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 129u8, 226u8, 134u8, 146u8,
+                226u8, 134u8, 146u8, 0u8, 112u8, 23u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 129u8, 77u8, 0u8, 113u8, 23u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8,
+                129u8, 77u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 88u8, 27u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 129u8,
+                77u8, 77u8, 0u8, 89u8, 27u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 129u8, 77u8, 77u8, 226u8, 134u8, 146u8, 226u8,
+                134u8, 146u8, 0u8, 64u8, 31u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 129u8, 77u8, 77u8, 77u8, 0u8, 65u8, 31u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8,
+                129u8, 77u8, 77u8, 77u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 40u8, 35u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 77u8,
+                226u8, 134u8, 130u8, 0u8, 41u8, 35u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 77u8, 226u8, 134u8, 130u8, 226u8, 134u8, 146u8,
+                226u8, 134u8, 146u8, 0u8, 16u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 39u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 130u8, 0u8, 17u8, 39u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 16u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 130u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 32u8, 78u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 16u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 130u8, 226u8, 134u8,
+                130u8, 0u8, 33u8, 78u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 39u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 226u8, 134u8, 130u8, 226u8, 134u8, 130u8, 226u8, 134u8, 146u8,
+                226u8, 134u8, 146u8, 0u8, 48u8, 117u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 39u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 130u8, 226u8, 134u8, 130u8, 226u8,
+                134u8, 130u8, 0u8, 49u8, 117u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 39u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 130u8, 226u8, 134u8, 130u8, 226u8, 134u8, 130u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 64u8, 156u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 16u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 130u8, 226u8,
+                134u8, 135u8, 0u8, 65u8, 156u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 39u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 130u8, 226u8, 134u8, 135u8, 226u8, 134u8, 146u8,
+                226u8, 134u8, 146u8, 0u8, 80u8, 195u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 39u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 135u8, 0u8, 81u8, 195u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 16u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 135u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 96u8, 234u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 16u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 135u8, 226u8,
+                134u8, 130u8, 0u8, 97u8, 234u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 39u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 135u8, 226u8, 134u8, 130u8, 226u8, 134u8, 146u8,
+                226u8, 134u8, 146u8, 0u8, 112u8, 17u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 39u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 135u8, 226u8, 134u8, 130u8, 226u8,
+                134u8, 130u8, 0u8, 113u8, 17u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 39u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 135u8, 226u8, 134u8, 130u8, 226u8, 134u8, 130u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 128u8, 56u8, 1u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 16u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 135u8, 226u8,
+                134u8, 130u8, 226u8, 134u8, 130u8, 226u8, 134u8, 130u8, 0u8, 129u8, 56u8, 1u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 16u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 135u8,
+                226u8, 134u8, 130u8, 226u8, 134u8, 130u8, 226u8, 134u8, 130u8, 226u8, 134u8, 146u8,
+                226u8, 134u8, 146u8, 0u8, 144u8, 95u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 39u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 130u8, 226u8, 134u8, 136u8, 0u8, 145u8,
+                95u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                226u8, 134u8, 130u8, 226u8, 134u8, 136u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8,
+                0u8, 160u8, 134u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 160u8, 134u8, 1u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 226u8, 134u8, 136u8, 0u8, 161u8, 134u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                160u8, 134u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 136u8, 226u8, 134u8,
+                146u8, 226u8, 134u8, 146u8, 0u8, 64u8, 13u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 160u8,
+                134u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 136u8, 226u8, 134u8, 136u8, 0u8,
+                65u8, 13u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 160u8, 134u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 226u8, 134u8, 136u8, 226u8, 134u8, 136u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+                146u8, 0u8, 224u8, 147u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 160u8, 134u8, 1u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 136u8, 226u8, 134u8, 136u8, 226u8, 134u8, 136u8,
+                0u8, 225u8, 147u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 160u8, 134u8, 1u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 226u8, 134u8, 136u8, 226u8, 134u8, 136u8, 226u8, 134u8, 136u8, 226u8,
+                134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 128u8, 26u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                160u8, 134u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8,
+                61u8,
+            ])
+        },
+    },
+};
+static UND_U_NU_ROMANLOW: &DataStruct = &::icu_decimal::provider::AlgorithmicSymbolsV1 {
+    rule_set: alloc::borrow::Cow::Borrowed("roman-lower"),
+    rules: ::icu_decimal::provider::RbnfRulesV1 {
+        rule_sets: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 114u8, 111u8, 109u8, 97u8, 110u8, 45u8,
+                        108u8, 111u8, 119u8, 101u8, 114u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8, 57u8, 0u8, 0u8, 0u8])
+                },
+            )
+        },
+        rules: unsafe {
+            ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                57u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 53u8, 0u8, 0u8, 0u8,
+                71u8, 0u8, 0u8, 0u8, 89u8, 0u8, 0u8, 0u8, 108u8, 0u8, 0u8, 0u8, 128u8, 0u8, 0u8,
+                0u8, 147u8, 0u8, 0u8, 0u8, 165u8, 0u8, 0u8, 0u8, 184u8, 0u8, 0u8, 0u8, 204u8, 0u8,
+                0u8, 0u8, 225u8, 0u8, 0u8, 0u8, 244u8, 0u8, 0u8, 0u8, 6u8, 1u8, 0u8, 0u8, 30u8,
+                1u8, 0u8, 0u8, 49u8, 1u8, 0u8, 0u8, 74u8, 1u8, 0u8, 0u8, 94u8, 1u8, 0u8, 0u8,
+                120u8, 1u8, 0u8, 0u8, 139u8, 1u8, 0u8, 0u8, 164u8, 1u8, 0u8, 0u8, 182u8, 1u8, 0u8,
+                0u8, 206u8, 1u8, 0u8, 0u8, 225u8, 1u8, 0u8, 0u8, 250u8, 1u8, 0u8, 0u8, 14u8, 2u8,
+                0u8, 0u8, 40u8, 2u8, 0u8, 0u8, 61u8, 2u8, 0u8, 0u8, 88u8, 2u8, 0u8, 0u8, 107u8,
+                2u8, 0u8, 0u8, 132u8, 2u8, 0u8, 0u8, 150u8, 2u8, 0u8, 0u8, 174u8, 2u8, 0u8, 0u8,
+                193u8, 2u8, 0u8, 0u8, 218u8, 2u8, 0u8, 0u8, 238u8, 2u8, 0u8, 0u8, 8u8, 3u8, 0u8,
+                0u8, 27u8, 3u8, 0u8, 0u8, 52u8, 3u8, 0u8, 0u8, 70u8, 3u8, 0u8, 0u8, 94u8, 3u8, 0u8,
+                0u8, 113u8, 3u8, 0u8, 0u8, 138u8, 3u8, 0u8, 0u8, 158u8, 3u8, 0u8, 0u8, 184u8, 3u8,
+                0u8, 0u8, 205u8, 3u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 251u8, 3u8, 0u8, 0u8, 20u8,
+                4u8, 0u8, 0u8, 38u8, 4u8, 0u8, 0u8, 62u8, 4u8, 0u8, 0u8, 81u8, 4u8, 0u8, 0u8,
+                106u8, 4u8, 0u8, 0u8, 126u8, 4u8, 0u8, 0u8, 152u8, 4u8, 0u8, 0u8, 173u8, 4u8, 0u8,
+                0u8, 200u8, 4u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 136u8, 146u8, 226u8, 134u8, 146u8, 226u8,
+                134u8, 146u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 46u8, 48u8, 48u8, 61u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                110u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 105u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 105u8, 105u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 105u8, 105u8, 105u8, 0u8, 4u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 105u8, 118u8, 0u8, 5u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 118u8,
+                0u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 118u8, 105u8, 0u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 118u8, 105u8, 105u8, 0u8, 8u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 118u8, 105u8, 105u8, 105u8, 0u8, 9u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 105u8,
+                120u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 120u8, 0u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 120u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 20u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 120u8,
+                120u8, 0u8, 21u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 120u8, 120u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 30u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 120u8,
+                120u8, 120u8, 0u8, 31u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 120u8, 120u8, 120u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8,
+                0u8, 40u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 120u8, 108u8, 0u8, 41u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 120u8, 108u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8,
+                0u8, 50u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 108u8, 0u8, 51u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 108u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 60u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 108u8,
+                120u8, 0u8, 61u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 108u8, 120u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 70u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 108u8,
+                120u8, 120u8, 0u8, 71u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 108u8, 120u8, 120u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8,
+                0u8, 80u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 108u8, 120u8, 120u8, 120u8, 0u8, 81u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 108u8, 120u8, 120u8, 120u8, 226u8, 134u8,
+                146u8, 226u8, 134u8, 146u8, 0u8, 90u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 120u8, 99u8, 0u8, 91u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 120u8, 99u8, 226u8, 134u8,
+                146u8, 226u8, 134u8, 146u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 99u8, 0u8, 101u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 99u8, 226u8, 134u8, 146u8, 226u8,
+                134u8, 146u8, 0u8, 200u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 99u8, 99u8, 0u8, 201u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 99u8, 99u8, 226u8, 134u8, 146u8, 226u8,
+                134u8, 146u8, 0u8, 44u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 99u8, 99u8, 99u8, 0u8, 45u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 99u8, 99u8, 99u8, 226u8, 134u8, 146u8,
+                226u8, 134u8, 146u8, 0u8, 144u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 99u8, 100u8, 0u8, 145u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 99u8, 100u8, 226u8, 134u8,
+                146u8, 226u8, 134u8, 146u8, 0u8, 244u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 245u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 226u8, 134u8, 146u8, 226u8,
+                134u8, 146u8, 0u8, 88u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 100u8, 99u8, 0u8, 89u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 99u8, 226u8, 134u8, 146u8, 226u8,
+                134u8, 146u8, 0u8, 188u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 100u8, 99u8, 99u8, 0u8, 189u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 99u8, 99u8, 226u8, 134u8,
+                146u8, 226u8, 134u8, 146u8, 0u8, 32u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 99u8, 99u8, 99u8, 0u8, 33u8, 3u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 99u8,
+                99u8, 99u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 132u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 99u8, 109u8, 0u8,
+                133u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                99u8, 109u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 232u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 109u8, 0u8, 233u8,
+                3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 109u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 208u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 109u8, 109u8, 0u8, 209u8, 7u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 109u8, 109u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 184u8, 11u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 109u8, 109u8, 109u8, 0u8,
+                185u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 109u8, 109u8, 109u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 160u8,
+                15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                109u8, 109u8, 109u8, 109u8, 0u8, 161u8, 15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8,
+                3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 109u8, 109u8, 109u8, 109u8, 226u8, 134u8, 146u8,
+                226u8, 134u8, 146u8, 0u8, 136u8, 19u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 61u8,
+            ])
+        },
+    },
+};
+static UND_U_NU_TAML: &DataStruct = &::icu_decimal::provider::AlgorithmicSymbolsV1 {
+    rule_set: alloc::borrow::Cow::Borrowed("tamil"),
+    rules: ::icu_decimal::provider::RbnfRulesV1 {
+        rule_sets: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 116u8, 97u8, 109u8, 105u8, 108u8,
+                        116u8, 97u8, 109u8, 105u8, 108u8, 45u8, 116u8, 104u8, 111u8, 117u8, 115u8,
+                        97u8, 110u8, 100u8, 115u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        3u8, 0u8, 30u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 1u8, 0u8,
+                    ])
+                },
+            )
+        },
+        rules: unsafe {
+            ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                30u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 25u8, 0u8, 0u8, 0u8, 51u8, 0u8, 0u8, 0u8,
+                83u8, 0u8, 0u8, 0u8, 109u8, 0u8, 0u8, 0u8, 136u8, 0u8, 0u8, 0u8, 156u8, 0u8, 0u8,
+                0u8, 176u8, 0u8, 0u8, 0u8, 196u8, 0u8, 0u8, 0u8, 216u8, 0u8, 0u8, 0u8, 236u8, 0u8,
+                0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 20u8, 1u8, 0u8, 0u8, 40u8, 1u8, 0u8, 0u8, 60u8, 1u8,
+                0u8, 0u8, 80u8, 1u8, 0u8, 0u8, 100u8, 1u8, 0u8, 0u8, 126u8, 1u8, 0u8, 0u8, 152u8,
+                1u8, 0u8, 0u8, 184u8, 1u8, 0u8, 0u8, 204u8, 1u8, 0u8, 0u8, 230u8, 1u8, 0u8, 0u8,
+                0u8, 2u8, 0u8, 0u8, 32u8, 2u8, 0u8, 0u8, 52u8, 2u8, 0u8, 0u8, 78u8, 2u8, 0u8, 0u8,
+                104u8, 2u8, 0u8, 0u8, 136u8, 2u8, 0u8, 0u8, 165u8, 2u8, 0u8, 0u8, 217u8, 2u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 61u8, 37u8, 116u8, 97u8, 109u8, 105u8, 108u8, 61u8, 0u8, 232u8, 3u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8,
+                144u8, 226u8, 134u8, 144u8, 224u8, 175u8, 178u8, 0u8, 233u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8,
+                226u8, 134u8, 144u8, 224u8, 175u8, 178u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 226u8, 136u8, 146u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 2u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8,
+                44u8, 35u8, 35u8, 48u8, 46u8, 48u8, 48u8, 61u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 224u8, 175u8, 166u8, 0u8, 1u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 224u8,
+                175u8, 167u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 224u8, 175u8, 168u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 224u8, 175u8, 169u8, 0u8, 4u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 224u8, 175u8,
+                170u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 224u8, 175u8, 171u8, 0u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 224u8, 175u8, 172u8, 0u8, 7u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 224u8, 175u8, 173u8,
+                0u8, 8u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 224u8, 175u8, 174u8, 0u8, 9u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 224u8, 175u8, 175u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 224u8, 175u8, 176u8, 0u8,
+                11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                224u8, 175u8, 176u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 20u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8,
+                144u8, 226u8, 134u8, 144u8, 224u8, 175u8, 176u8, 0u8, 21u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 226u8,
+                134u8, 144u8, 224u8, 175u8, 176u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8,
+                100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                224u8, 175u8, 177u8, 0u8, 101u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 224u8, 175u8, 177u8, 226u8, 134u8, 146u8, 226u8,
+                134u8, 146u8, 0u8, 200u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 224u8, 175u8, 177u8,
+                0u8, 201u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 224u8, 175u8, 177u8, 226u8, 134u8,
+                146u8, 226u8, 134u8, 146u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8,
+                3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 224u8, 175u8, 178u8, 0u8, 233u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 224u8, 175u8, 178u8,
+                226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 208u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 232u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8,
+                144u8, 224u8, 175u8, 178u8, 0u8, 209u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8,
+                3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 224u8,
+                175u8, 178u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 64u8, 66u8, 15u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 160u8, 134u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8,
+                144u8, 226u8, 134u8, 144u8, 224u8, 175u8, 177u8, 224u8, 175u8, 178u8, 0u8, 65u8,
+                66u8, 15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 160u8, 134u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                226u8, 134u8, 144u8, 226u8, 134u8, 144u8, 224u8, 175u8, 177u8, 224u8, 175u8, 178u8,
+                226u8, 134u8, 146u8, 37u8, 37u8, 116u8, 97u8, 109u8, 105u8, 108u8, 45u8, 116u8,
+                104u8, 111u8, 117u8, 115u8, 97u8, 110u8, 100u8, 115u8, 226u8, 134u8, 146u8, 0u8,
+                0u8, 225u8, 245u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 245u8, 5u8, 0u8, 0u8, 0u8,
+                0u8, 61u8, 35u8, 44u8, 35u8, 35u8, 44u8, 35u8, 35u8, 48u8, 61u8,
+            ])
+        },
+    },
+};
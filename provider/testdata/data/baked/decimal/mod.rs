@@ -1,2 +1,4 @@
 // @generated
+pub mod algorithmic_v1;
+pub mod notation_v1;
 pub mod symbols_v1;
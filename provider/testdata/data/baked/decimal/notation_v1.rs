@@ -0,0 +1,107 @@
+// @generated
+#![cfg(feature = "icu_decimal")]
+type DataStruct =
+    <::icu_decimal::provider::NotationSymbolsV1Marker as ::icu_provider::DataMarker>::Yokeable;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[
+        ("ar", AR_AR_EG),
+        ("ar-EG", AR_AR_EG),
+        ("ar-EG-u-nu-latn", AR_EG_U_NU_LATN_AR_U_NU_LATN),
+        ("ar-u-nu-latn", AR_EG_U_NU_LATN_AR_U_NU_LATN),
+        ("bn", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+        ("bn-u-nu-latn", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+        ("ccp", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+        ("ccp-u-nu-latn", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+        ("en", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+        ("en-001", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+        ("en-ZA", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+        ("es", ES_ES_AR_FR_RU),
+        ("es-AR", ES_ES_AR_FR_RU),
+        ("fil", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+        ("fr", ES_ES_AR_FR_RU),
+        ("ja", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+        ("ru", ES_ES_AR_FR_RU),
+        ("sr", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+        ("sr-Cyrl", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+        ("sr-Latn", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+        ("th", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+        ("th-u-nu-thai", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+        ("tr", TR),
+        ("und", BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN),
+    ]);
+static AR_AR_EG: &DataStruct = &::icu_decimal::provider::NotationSymbolsV1 {
+    percent_affixes: ::icu_decimal::provider::AffixesV1 {
+        prefix: alloc::borrow::Cow::Borrowed(""),
+        suffix: alloc::borrow::Cow::Borrowed("٪\u{61c}"),
+    },
+    permille_affixes: ::icu_decimal::provider::AffixesV1 {
+        prefix: alloc::borrow::Cow::Borrowed(""),
+        suffix: alloc::borrow::Cow::Borrowed("؉"),
+    },
+    scientific_affixes: ::icu_decimal::provider::AffixesV1 {
+        prefix: alloc::borrow::Cow::Borrowed(""),
+        suffix: alloc::borrow::Cow::Borrowed(""),
+    },
+    exponent_separator: alloc::borrow::Cow::Borrowed("اس"),
+};
+static AR_EG_U_NU_LATN_AR_U_NU_LATN: &DataStruct = &::icu_decimal::provider::NotationSymbolsV1 {
+    percent_affixes: ::icu_decimal::provider::AffixesV1 {
+        prefix: alloc::borrow::Cow::Borrowed(""),
+        suffix: alloc::borrow::Cow::Borrowed("\u{200e}%\u{200e}"),
+    },
+    permille_affixes: ::icu_decimal::provider::AffixesV1 {
+        prefix: alloc::borrow::Cow::Borrowed(""),
+        suffix: alloc::borrow::Cow::Borrowed("‰"),
+    },
+    scientific_affixes: ::icu_decimal::provider::AffixesV1 {
+        prefix: alloc::borrow::Cow::Borrowed(""),
+        suffix: alloc::borrow::Cow::Borrowed(""),
+    },
+    exponent_separator: alloc::borrow::Cow::Borrowed("E"),
+};
+static BN_BN_U_NU_LATN_CCP_CCP_U_NU_LATN_EN: &DataStruct =
+    &::icu_decimal::provider::NotationSymbolsV1 {
+        percent_affixes: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed("%"),
+        },
+        permille_affixes: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed("‰"),
+        },
+        scientific_affixes: ::icu_decimal::provider::AffixesV1 {
+            prefix: alloc::borrow::Cow::Borrowed(""),
+            suffix: alloc::borrow::Cow::Borrowed(""),
+        },
+        exponent_separator: alloc::borrow::Cow::Borrowed("E"),
+    };
+static ES_ES_AR_FR_RU: &DataStruct = &::icu_decimal::provider::NotationSymbolsV1 {
+    percent_affixes: ::icu_decimal::provider::AffixesV1 {
+        prefix: alloc::borrow::Cow::Borrowed(""),
+        suffix: alloc::borrow::Cow::Borrowed("\u{a0}%"),
+    },
+    permille_affixes: ::icu_decimal::provider::AffixesV1 {
+        prefix: alloc::borrow::Cow::Borrowed(""),
+        suffix: alloc::borrow::Cow::Borrowed("\u{a0}‰"),
+    },
+    scientific_affixes: ::icu_decimal::provider::AffixesV1 {
+        prefix: alloc::borrow::Cow::Borrowed(""),
+        suffix: alloc::borrow::Cow::Borrowed(""),
+    },
+    exponent_separator: alloc::borrow::Cow::Borrowed("E"),
+};
+static TR: &DataStruct = &::icu_decimal::provider::NotationSymbolsV1 {
+    percent_affixes: ::icu_decimal::provider::AffixesV1 {
+        prefix: alloc::borrow::Cow::Borrowed("%"),
+        suffix: alloc::borrow::Cow::Borrowed(""),
+    },
+    permille_affixes: ::icu_decimal::provider::AffixesV1 {
+        prefix: alloc::borrow::Cow::Borrowed("‰"),
+        suffix: alloc::borrow::Cow::Borrowed(""),
+    },
+    scientific_affixes: ::icu_decimal::provider::AffixesV1 {
+        prefix: alloc::borrow::Cow::Borrowed(""),
+        suffix: alloc::borrow::Cow::Borrowed(""),
+    },
+    exponent_separator: alloc::borrow::Cow::Borrowed("E"),
+};
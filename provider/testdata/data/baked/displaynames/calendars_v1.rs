@@ -0,0 +1,1262 @@
+// @generated
+#![cfg(feature = "icu_displaynames")]
+type DataStruct = < :: icu_displaynames :: provider :: CalendarDisplayNamesV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[
+        ("ar", AR_AR_EG),
+        ("ar-EG", AR_AR_EG),
+        ("bn", BN),
+        ("ccp", CCP),
+        ("en", EN_EN_001_EN_ZA),
+        ("en-001", EN_EN_001_EN_ZA),
+        ("en-ZA", EN_EN_001_EN_ZA),
+        ("es", ES),
+        ("es-AR", ES_AR),
+        ("fil", FIL),
+        ("fr", FR),
+        ("ja", JA),
+        ("ru", RU),
+        ("sr", SR_SR_CYRL),
+        ("sr-Cyrl", SR_SR_CYRL),
+        ("sr-Latn", SR_LATN),
+        ("th", TH),
+        ("tr", TR),
+        ("und", UND),
+    ]);
+static AR_AR_EG: &DataStruct = &::icu_displaynames::provider::CalendarDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    18u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 15u8, 0u8, 21u8, 0u8, 26u8, 0u8, 33u8,
+                    0u8, 41u8, 0u8, 48u8, 0u8, 54u8, 0u8, 60u8, 0u8, 67u8, 0u8, 80u8, 0u8, 92u8,
+                    0u8, 104u8, 0u8, 120u8, 0u8, 127u8, 0u8, 135u8, 0u8, 142u8, 0u8, 98u8, 117u8,
+                    100u8, 100u8, 104u8, 105u8, 115u8, 116u8, 99u8, 104u8, 105u8, 110u8, 101u8,
+                    115u8, 101u8, 99u8, 111u8, 112u8, 116u8, 105u8, 99u8, 100u8, 97u8, 110u8,
+                    103u8, 105u8, 101u8, 116u8, 104u8, 105u8, 111u8, 97u8, 97u8, 101u8, 116u8,
+                    104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 103u8, 114u8, 101u8, 103u8, 111u8,
+                    114u8, 121u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 105u8, 110u8, 100u8,
+                    105u8, 97u8, 110u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 105u8,
+                    115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 99u8, 105u8, 118u8, 105u8, 108u8,
+                    105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 114u8, 103u8, 115u8, 97u8,
+                    105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 116u8, 98u8, 108u8, 97u8,
+                    105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 117u8, 109u8, 97u8, 108u8,
+                    113u8, 117u8, 114u8, 97u8, 105u8, 115u8, 111u8, 56u8, 54u8, 48u8, 49u8, 106u8,
+                    97u8, 112u8, 97u8, 110u8, 101u8, 115u8, 101u8, 112u8, 101u8, 114u8, 115u8,
+                    105u8, 97u8, 110u8, 114u8, 111u8, 99u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    18u8, 0u8, 0u8, 0u8, 0u8, 0u8, 27u8, 0u8, 54u8, 0u8, 81u8, 0u8, 102u8, 0u8,
+                    149u8, 0u8, 180u8, 0u8, 211u8, 0u8, 238u8, 0u8, 22u8, 1u8, 49u8, 1u8, 93u8,
+                    1u8, 158u8, 1u8, 223u8, 1u8, 16u8, 2u8, 35u8, 2u8, 66u8, 2u8, 95u8, 2u8, 216u8,
+                    167u8, 217u8, 132u8, 216u8, 170u8, 217u8, 130u8, 217u8, 136u8, 217u8, 138u8,
+                    217u8, 133u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 168u8, 217u8, 136u8,
+                    216u8, 176u8, 217u8, 138u8, 216u8, 167u8, 217u8, 132u8, 216u8, 170u8, 217u8,
+                    130u8, 217u8, 136u8, 217u8, 138u8, 217u8, 133u8, 32u8, 216u8, 167u8, 217u8,
+                    132u8, 216u8, 181u8, 217u8, 138u8, 217u8, 134u8, 217u8, 138u8, 216u8, 167u8,
+                    217u8, 132u8, 216u8, 170u8, 217u8, 130u8, 217u8, 136u8, 217u8, 138u8, 217u8,
+                    133u8, 32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 130u8, 216u8, 168u8, 216u8,
+                    183u8, 217u8, 138u8, 216u8, 170u8, 217u8, 130u8, 217u8, 136u8, 217u8, 138u8,
+                    217u8, 133u8, 32u8, 216u8, 175u8, 216u8, 167u8, 217u8, 134u8, 216u8, 172u8,
+                    217u8, 138u8, 216u8, 170u8, 217u8, 130u8, 217u8, 136u8, 217u8, 138u8, 217u8,
+                    133u8, 32u8, 216u8, 163u8, 217u8, 133u8, 217u8, 138u8, 216u8, 170u8, 217u8,
+                    138u8, 32u8, 216u8, 163u8, 217u8, 132u8, 217u8, 138u8, 217u8, 133u8, 32u8,
+                    216u8, 167u8, 217u8, 132u8, 216u8, 165u8, 216u8, 171u8, 217u8, 138u8, 217u8,
+                    136u8, 216u8, 168u8, 217u8, 138u8, 216u8, 167u8, 217u8, 132u8, 216u8, 170u8,
+                    217u8, 130u8, 217u8, 136u8, 217u8, 138u8, 217u8, 133u8, 32u8, 216u8, 167u8,
+                    217u8, 132u8, 216u8, 165u8, 216u8, 171u8, 217u8, 138u8, 217u8, 136u8, 216u8,
+                    168u8, 217u8, 138u8, 216u8, 167u8, 217u8, 132u8, 216u8, 170u8, 217u8, 130u8,
+                    217u8, 136u8, 217u8, 138u8, 217u8, 133u8, 32u8, 216u8, 167u8, 217u8, 132u8,
+                    217u8, 133u8, 217u8, 138u8, 217u8, 132u8, 216u8, 167u8, 216u8, 175u8, 217u8,
+                    138u8, 216u8, 167u8, 217u8, 132u8, 216u8, 170u8, 217u8, 130u8, 217u8, 136u8,
+                    217u8, 138u8, 217u8, 133u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 185u8,
+                    216u8, 168u8, 216u8, 177u8, 217u8, 138u8, 216u8, 167u8, 217u8, 132u8, 216u8,
+                    170u8, 217u8, 130u8, 217u8, 136u8, 217u8, 138u8, 217u8, 133u8, 32u8, 216u8,
+                    167u8, 217u8, 132u8, 217u8, 130u8, 217u8, 136u8, 217u8, 133u8, 217u8, 138u8,
+                    32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 135u8, 217u8, 134u8, 216u8, 175u8,
+                    217u8, 138u8, 216u8, 167u8, 217u8, 132u8, 216u8, 170u8, 217u8, 130u8, 217u8,
+                    136u8, 217u8, 138u8, 217u8, 133u8, 32u8, 216u8, 167u8, 217u8, 132u8, 217u8,
+                    135u8, 216u8, 172u8, 216u8, 177u8, 217u8, 138u8, 216u8, 167u8, 217u8, 132u8,
+                    216u8, 170u8, 217u8, 130u8, 217u8, 136u8, 217u8, 138u8, 217u8, 133u8, 32u8,
+                    216u8, 167u8, 217u8, 132u8, 216u8, 165u8, 216u8, 179u8, 217u8, 132u8, 216u8,
+                    167u8, 217u8, 133u8, 217u8, 138u8, 32u8, 216u8, 167u8, 217u8, 132u8, 217u8,
+                    133u8, 216u8, 175u8, 217u8, 134u8, 217u8, 138u8, 216u8, 167u8, 217u8, 132u8,
+                    216u8, 170u8, 217u8, 130u8, 217u8, 136u8, 217u8, 138u8, 217u8, 133u8, 32u8,
+                    216u8, 167u8, 217u8, 132u8, 216u8, 165u8, 216u8, 179u8, 217u8, 132u8, 216u8,
+                    167u8, 217u8, 133u8, 217u8, 138u8, 32u8, 40u8, 216u8, 167u8, 217u8, 132u8,
+                    216u8, 179u8, 216u8, 185u8, 217u8, 136u8, 216u8, 175u8, 217u8, 138u8, 216u8,
+                    169u8, 32u8, 45u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 177u8, 216u8,
+                    164u8, 217u8, 138u8, 216u8, 169u8, 41u8, 216u8, 167u8, 217u8, 132u8, 216u8,
+                    170u8, 217u8, 130u8, 217u8, 136u8, 217u8, 138u8, 217u8, 133u8, 32u8, 216u8,
+                    167u8, 217u8, 132u8, 216u8, 165u8, 216u8, 179u8, 217u8, 132u8, 216u8, 167u8,
+                    217u8, 133u8, 217u8, 138u8, 32u8, 40u8, 216u8, 167u8, 217u8, 132u8, 216u8,
+                    173u8, 216u8, 179u8, 216u8, 167u8, 216u8, 168u8, 216u8, 167u8, 216u8, 170u8,
+                    32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 129u8, 217u8, 132u8, 217u8, 131u8,
+                    217u8, 138u8, 216u8, 169u8, 41u8, 216u8, 167u8, 217u8, 132u8, 216u8, 170u8,
+                    217u8, 130u8, 217u8, 136u8, 217u8, 138u8, 217u8, 133u8, 32u8, 216u8, 167u8,
+                    217u8, 132u8, 216u8, 165u8, 216u8, 179u8, 217u8, 132u8, 216u8, 167u8, 217u8,
+                    133u8, 217u8, 138u8, 32u8, 40u8, 216u8, 163u8, 217u8, 133u8, 32u8, 216u8,
+                    167u8, 217u8, 132u8, 217u8, 130u8, 216u8, 177u8, 217u8, 137u8, 41u8, 216u8,
+                    170u8, 217u8, 130u8, 217u8, 136u8, 217u8, 138u8, 217u8, 133u8, 32u8, 73u8,
+                    83u8, 79u8, 45u8, 56u8, 54u8, 48u8, 49u8, 216u8, 167u8, 217u8, 132u8, 216u8,
+                    170u8, 217u8, 130u8, 217u8, 136u8, 217u8, 138u8, 217u8, 133u8, 32u8, 216u8,
+                    167u8, 217u8, 132u8, 217u8, 138u8, 216u8, 167u8, 216u8, 168u8, 216u8, 167u8,
+                    217u8, 134u8, 217u8, 138u8, 216u8, 167u8, 217u8, 132u8, 216u8, 170u8, 217u8,
+                    130u8, 217u8, 136u8, 217u8, 138u8, 217u8, 133u8, 32u8, 216u8, 167u8, 217u8,
+                    132u8, 217u8, 129u8, 216u8, 167u8, 216u8, 177u8, 216u8, 179u8, 217u8, 138u8,
+                    216u8, 170u8, 217u8, 130u8, 217u8, 136u8, 217u8, 138u8, 217u8, 133u8, 32u8,
+                    217u8, 133u8, 217u8, 138u8, 217u8, 134u8, 216u8, 172u8, 217u8, 136u8,
+                ])
+            },
+        )
+    },
+};
+static BN: &DataStruct = &::icu_displaynames::provider::CalendarDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    18u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 15u8, 0u8, 21u8, 0u8, 26u8, 0u8, 33u8,
+                    0u8, 41u8, 0u8, 48u8, 0u8, 54u8, 0u8, 60u8, 0u8, 67u8, 0u8, 80u8, 0u8, 92u8,
+                    0u8, 104u8, 0u8, 120u8, 0u8, 127u8, 0u8, 135u8, 0u8, 142u8, 0u8, 98u8, 117u8,
+                    100u8, 100u8, 104u8, 105u8, 115u8, 116u8, 99u8, 104u8, 105u8, 110u8, 101u8,
+                    115u8, 101u8, 99u8, 111u8, 112u8, 116u8, 105u8, 99u8, 100u8, 97u8, 110u8,
+                    103u8, 105u8, 101u8, 116u8, 104u8, 105u8, 111u8, 97u8, 97u8, 101u8, 116u8,
+                    104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 103u8, 114u8, 101u8, 103u8, 111u8,
+                    114u8, 121u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 105u8, 110u8, 100u8,
+                    105u8, 97u8, 110u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 105u8,
+                    115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 99u8, 105u8, 118u8, 105u8, 108u8,
+                    105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 114u8, 103u8, 115u8, 97u8,
+                    105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 116u8, 98u8, 108u8, 97u8,
+                    105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 117u8, 109u8, 97u8, 108u8,
+                    113u8, 117u8, 114u8, 97u8, 105u8, 115u8, 111u8, 56u8, 54u8, 48u8, 49u8, 106u8,
+                    97u8, 112u8, 97u8, 110u8, 101u8, 115u8, 101u8, 112u8, 101u8, 114u8, 115u8,
+                    105u8, 97u8, 110u8, 114u8, 111u8, 99u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    18u8, 0u8, 0u8, 0u8, 0u8, 0u8, 49u8, 0u8, 95u8, 0u8, 144u8, 0u8, 196u8, 0u8,
+                    24u8, 1u8, 79u8, 1u8, 149u8, 1u8, 201u8, 1u8, 13u8, 2u8, 68u8, 2u8, 133u8, 2u8,
+                    233u8, 2u8, 107u8, 3u8, 194u8, 3u8, 235u8, 3u8, 31u8, 4u8, 80u8, 4u8, 224u8,
+                    166u8, 172u8, 224u8, 167u8, 140u8, 224u8, 166u8, 166u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 167u8, 32u8, 224u8, 166u8, 149u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 178u8, 224u8, 167u8, 135u8,
+                    224u8, 166u8, 168u8, 224u8, 167u8, 141u8, 224u8, 166u8, 161u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 176u8, 224u8, 166u8, 154u8, 224u8, 167u8, 128u8, 224u8,
+                    166u8, 168u8, 224u8, 166u8, 190u8, 32u8, 224u8, 166u8, 149u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 178u8, 224u8,
+                    167u8, 135u8, 224u8, 166u8, 168u8, 224u8, 167u8, 141u8, 224u8, 166u8, 161u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 166u8, 149u8, 224u8, 166u8,
+                    170u8, 224u8, 166u8, 159u8, 224u8, 166u8, 191u8, 224u8, 166u8, 149u8, 32u8,
+                    224u8, 166u8, 149u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 178u8, 224u8, 167u8, 135u8, 224u8, 166u8, 168u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 161u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8,
+                    224u8, 166u8, 166u8, 224u8, 166u8, 190u8, 224u8, 166u8, 153u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 151u8, 224u8, 167u8, 128u8, 32u8, 224u8, 166u8, 149u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    178u8, 224u8, 167u8, 135u8, 224u8, 166u8, 168u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 161u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 166u8, 135u8,
+                    224u8, 166u8, 165u8, 224u8, 166u8, 191u8, 224u8, 166u8, 147u8, 224u8, 166u8,
+                    170u8, 224u8, 166u8, 191u8, 224u8, 166u8, 147u8, 32u8, 224u8, 166u8, 134u8,
+                    224u8, 166u8, 174u8, 224u8, 167u8, 135u8, 224u8, 166u8, 164u8, 224u8, 167u8,
+                    135u8, 32u8, 224u8, 166u8, 134u8, 224u8, 166u8, 178u8, 224u8, 167u8, 135u8,
+                    224u8, 166u8, 174u8, 32u8, 224u8, 166u8, 149u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 178u8, 224u8, 167u8, 135u8,
+                    224u8, 166u8, 168u8, 224u8, 167u8, 141u8, 224u8, 166u8, 161u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 176u8, 224u8, 166u8, 135u8, 224u8, 166u8, 165u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 147u8, 224u8, 166u8, 170u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 149u8, 32u8, 224u8, 166u8, 149u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 178u8, 224u8, 167u8, 135u8,
+                    224u8, 166u8, 168u8, 224u8, 167u8, 141u8, 224u8, 166u8, 161u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 176u8, 224u8, 166u8, 151u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 176u8, 224u8, 166u8, 191u8, 224u8, 166u8, 151u8, 224u8, 167u8, 139u8,
+                    224u8, 166u8, 176u8, 224u8, 166u8, 191u8, 224u8, 166u8, 175u8, 224u8, 166u8,
+                    188u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 32u8, 224u8, 166u8, 149u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    178u8, 224u8, 167u8, 135u8, 224u8, 166u8, 168u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 161u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 166u8, 185u8,
+                    224u8, 166u8, 191u8, 224u8, 166u8, 172u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                    176u8, 224u8, 167u8, 129u8, 32u8, 224u8, 166u8, 149u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 178u8, 224u8, 167u8,
+                    135u8, 224u8, 166u8, 168u8, 224u8, 167u8, 141u8, 224u8, 166u8, 161u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 166u8, 173u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 176u8, 224u8, 166u8, 164u8, 224u8, 167u8, 128u8, 224u8, 166u8,
+                    175u8, 224u8, 166u8, 188u8, 32u8, 224u8, 166u8, 156u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 164u8, 224u8, 167u8, 128u8, 224u8, 166u8, 175u8, 224u8, 166u8,
+                    188u8, 32u8, 224u8, 166u8, 172u8, 224u8, 166u8, 176u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 183u8, 224u8, 166u8, 170u8, 224u8, 166u8, 158u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 156u8, 224u8, 167u8, 128u8, 224u8, 166u8, 135u8, 224u8,
+                    166u8, 184u8, 224u8, 166u8, 178u8, 224u8, 166u8, 190u8, 224u8, 166u8, 174u8,
+                    224u8, 166u8, 191u8, 224u8, 166u8, 149u8, 32u8, 224u8, 166u8, 149u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 178u8,
+                    224u8, 167u8, 135u8, 224u8, 166u8, 168u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                    161u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 166u8, 135u8, 224u8,
+                    166u8, 184u8, 224u8, 166u8, 178u8, 224u8, 166u8, 190u8, 224u8, 166u8, 174u8,
+                    224u8, 166u8, 191u8, 224u8, 166u8, 149u8, 45u8, 224u8, 166u8, 184u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 173u8, 224u8, 166u8, 191u8, 224u8, 166u8, 178u8,
+                    32u8, 224u8, 166u8, 172u8, 224u8, 166u8, 176u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 183u8, 224u8, 166u8, 170u8, 224u8, 166u8, 158u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 156u8, 224u8, 167u8, 128u8, 224u8, 166u8, 135u8, 224u8, 166u8,
+                    184u8, 224u8, 166u8, 178u8, 224u8, 166u8, 190u8, 224u8, 166u8, 174u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 149u8, 32u8, 224u8, 166u8, 172u8, 224u8, 166u8,
+                    176u8, 224u8, 167u8, 141u8, 224u8, 166u8, 183u8, 224u8, 166u8, 170u8, 224u8,
+                    166u8, 158u8, 224u8, 167u8, 141u8, 224u8, 166u8, 156u8, 224u8, 167u8, 128u8,
+                    32u8, 40u8, 224u8, 166u8, 184u8, 224u8, 167u8, 140u8, 224u8, 166u8, 166u8,
+                    224u8, 166u8, 191u8, 32u8, 224u8, 166u8, 134u8, 224u8, 166u8, 176u8, 224u8,
+                    166u8, 172u8, 44u8, 32u8, 224u8, 166u8, 166u8, 224u8, 167u8, 131u8, 224u8,
+                    166u8, 182u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 174u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 41u8, 224u8, 166u8, 135u8, 224u8,
+                    166u8, 184u8, 224u8, 166u8, 178u8, 224u8, 166u8, 190u8, 224u8, 166u8, 174u8,
+                    224u8, 166u8, 191u8, 224u8, 166u8, 149u8, 32u8, 224u8, 166u8, 172u8, 224u8,
+                    166u8, 176u8, 224u8, 167u8, 141u8, 224u8, 166u8, 183u8, 224u8, 166u8, 170u8,
+                    224u8, 166u8, 158u8, 224u8, 167u8, 141u8, 224u8, 166u8, 156u8, 224u8, 167u8,
+                    128u8, 32u8, 40u8, 224u8, 166u8, 155u8, 224u8, 166u8, 149u8, 224u8, 166u8,
+                    172u8, 224u8, 166u8, 166u8, 224u8, 167u8, 141u8, 224u8, 166u8, 167u8, 44u8,
+                    32u8, 224u8, 166u8, 156u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8,
+                    167u8, 139u8, 224u8, 166u8, 164u8, 224u8, 166u8, 191u8, 224u8, 166u8, 176u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 172u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    166u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 167u8, 128u8, 224u8,
+                    166u8, 175u8, 224u8, 166u8, 188u8, 32u8, 224u8, 166u8, 175u8, 224u8, 167u8,
+                    129u8, 224u8, 166u8, 151u8, 41u8, 224u8, 166u8, 135u8, 224u8, 166u8, 184u8,
+                    224u8, 166u8, 178u8, 224u8, 166u8, 190u8, 224u8, 166u8, 174u8, 224u8, 166u8,
+                    191u8, 224u8, 166u8, 149u8, 32u8, 224u8, 166u8, 172u8, 224u8, 166u8, 176u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 183u8, 224u8, 166u8, 170u8, 224u8, 166u8,
+                    158u8, 224u8, 167u8, 141u8, 224u8, 166u8, 156u8, 224u8, 167u8, 128u8, 32u8,
+                    40u8, 224u8, 166u8, 137u8, 224u8, 166u8, 174u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 174u8, 224u8, 166u8, 190u8, 32u8, 224u8, 166u8, 134u8, 224u8, 166u8,
+                    178u8, 45u8, 224u8, 166u8, 149u8, 224u8, 167u8, 129u8, 224u8, 166u8, 176u8,
+                    224u8, 166u8, 134u8, 41u8, 73u8, 83u8, 79u8, 45u8, 56u8, 54u8, 49u8, 32u8,
+                    224u8, 166u8, 149u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 178u8, 224u8, 167u8, 135u8, 224u8, 166u8, 168u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 161u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8,
+                    224u8, 166u8, 156u8, 224u8, 166u8, 190u8, 224u8, 166u8, 170u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 168u8, 224u8, 166u8, 191u8, 32u8, 224u8, 166u8, 149u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    178u8, 224u8, 167u8, 135u8, 224u8, 166u8, 168u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 161u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 166u8, 171u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 166u8, 184u8, 224u8, 166u8,
+                    191u8, 32u8, 224u8, 166u8, 149u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 178u8, 224u8, 167u8, 135u8, 224u8, 166u8,
+                    168u8, 224u8, 167u8, 141u8, 224u8, 166u8, 161u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 176u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8, 224u8, 166u8, 153u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 151u8, 224u8, 167u8, 129u8, 224u8, 166u8,
+                    147u8, 32u8, 224u8, 166u8, 149u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 178u8, 224u8, 167u8, 135u8, 224u8, 166u8,
+                    168u8, 224u8, 167u8, 141u8, 224u8, 166u8, 161u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 176u8,
+                ])
+            },
+        )
+    },
+};
+static CCP: &DataStruct = &::icu_displaynames::provider::CalendarDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 15u8, 0u8, 21u8, 0u8, 26u8, 0u8, 33u8,
+                    0u8, 41u8, 0u8, 48u8, 0u8, 54u8, 0u8, 60u8, 0u8, 67u8, 0u8, 80u8, 0u8, 87u8,
+                    0u8, 95u8, 0u8, 102u8, 0u8, 98u8, 117u8, 100u8, 100u8, 104u8, 105u8, 115u8,
+                    116u8, 99u8, 104u8, 105u8, 110u8, 101u8, 115u8, 101u8, 99u8, 111u8, 112u8,
+                    116u8, 105u8, 99u8, 100u8, 97u8, 110u8, 103u8, 105u8, 101u8, 116u8, 104u8,
+                    105u8, 111u8, 97u8, 97u8, 101u8, 116u8, 104u8, 105u8, 111u8, 112u8, 105u8,
+                    99u8, 103u8, 114u8, 101u8, 103u8, 111u8, 114u8, 121u8, 104u8, 101u8, 98u8,
+                    114u8, 101u8, 119u8, 105u8, 110u8, 100u8, 105u8, 97u8, 110u8, 105u8, 115u8,
+                    108u8, 97u8, 109u8, 105u8, 99u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8,
+                    45u8, 99u8, 105u8, 118u8, 105u8, 108u8, 105u8, 115u8, 111u8, 56u8, 54u8, 48u8,
+                    49u8, 106u8, 97u8, 112u8, 97u8, 110u8, 101u8, 115u8, 101u8, 112u8, 101u8,
+                    114u8, 115u8, 105u8, 97u8, 110u8, 114u8, 111u8, 99u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 0u8, 118u8, 0u8, 191u8, 0u8, 252u8, 0u8,
+                    119u8, 1u8, 200u8, 1u8, 25u8, 2u8, 90u8, 2u8, 188u8, 2u8, 9u8, 3u8, 119u8, 3u8,
+                    167u8, 3u8, 224u8, 3u8, 29u8, 4u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8,
+                    132u8, 170u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 153u8, 240u8, 145u8, 132u8, 174u8, 32u8, 240u8, 145u8, 132u8,
+                    135u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8,
+                    132u8, 163u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 154u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 162u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8,
+                    168u8, 240u8, 145u8, 132u8, 154u8, 32u8, 240u8, 145u8, 132u8, 135u8, 240u8,
+                    145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 163u8,
+                    240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8,
+                    180u8, 240u8, 145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8,
+                    132u8, 180u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 167u8, 240u8,
+                    145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 145u8,
+                    240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8,
+                    180u8, 32u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 179u8, 240u8,
+                    145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 172u8,
+                    240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8,
+                    147u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8,
+                    132u8, 147u8, 240u8, 145u8, 132u8, 139u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 168u8, 32u8, 240u8, 145u8, 132u8,
+                    135u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8,
+                    132u8, 163u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 154u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 162u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8,
+                    168u8, 240u8, 145u8, 132u8, 151u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8,
+                    132u8, 131u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 155u8, 240u8,
+                    145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 174u8,
+                    32u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8,
+                    132u8, 172u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 172u8, 32u8,
+                    240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8,
+                    172u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8,
+                    145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 160u8,
+                    240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8,
+                    154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 147u8, 240u8, 145u8,
+                    132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 131u8, 240u8,
+                    145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 151u8, 240u8, 145u8, 132u8, 168u8,
+                    240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8,
+                    155u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8,
+                    132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 179u8,
+                    240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8,
+                    172u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8,
+                    132u8, 147u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 162u8,
+                    240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8,
+                    162u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8,
+                    132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 135u8,
+                    240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8,
+                    163u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8,
+                    132u8, 180u8, 240u8, 145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 162u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 166u8, 240u8, 145u8, 132u8, 168u8,
+                    240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8,
+                    162u8, 240u8, 145u8, 132u8, 170u8, 32u8, 240u8, 145u8, 132u8, 135u8, 240u8,
+                    145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 163u8,
+                    240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8,
+                    180u8, 240u8, 145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8,
+                    132u8, 180u8, 240u8, 145u8, 132u8, 158u8, 240u8, 145u8, 132u8, 162u8, 240u8,
+                    145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 167u8,
+                    240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8,
+                    132u8, 142u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 169u8, 240u8,
+                    145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 167u8, 32u8, 240u8, 145u8, 132u8,
+                    157u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8,
+                    132u8, 180u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 167u8, 240u8,
+                    145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 154u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 143u8, 240u8, 145u8, 132u8,
+                    169u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8,
+                    132u8, 165u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 163u8, 240u8,
+                    145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 135u8,
+                    240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8,
+                    132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 163u8, 240u8,
+                    145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8,
+                    180u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8,
+                    132u8, 165u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 163u8, 240u8,
+                    145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 135u8,
+                    240u8, 145u8, 132u8, 180u8, 45u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8,
+                    132u8, 168u8, 240u8, 145u8, 132u8, 158u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                    145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8,
+                    157u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8,
+                    132u8, 180u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 167u8, 240u8,
+                    145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 154u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 143u8, 240u8, 145u8, 132u8,
+                    169u8, 73u8, 83u8, 79u8, 45u8, 56u8, 54u8, 49u8, 32u8, 240u8, 145u8, 132u8,
+                    135u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8,
+                    132u8, 163u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 154u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 162u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 142u8, 240u8, 145u8, 132u8,
+                    155u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 168u8, 32u8, 240u8,
+                    145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 160u8,
+                    240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8,
+                    154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 147u8, 240u8, 145u8,
+                    132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 156u8, 240u8,
+                    145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 165u8,
+                    240u8, 145u8, 132u8, 168u8, 32u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8,
+                    132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 163u8, 240u8,
+                    145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8,
+                    180u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8,
+                    132u8, 129u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 170u8, 240u8,
+                    145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 174u8, 32u8, 240u8, 145u8, 132u8,
+                    135u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8,
+                    132u8, 163u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 154u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 162u8,
+                    240u8, 145u8, 132u8, 180u8,
+                ])
+            },
+        )
+    },
+};
+static EN_EN_001_EN_ZA: &DataStruct = &::icu_displaynames::provider::CalendarDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    18u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 15u8, 0u8, 21u8, 0u8, 26u8, 0u8, 33u8,
+                    0u8, 41u8, 0u8, 48u8, 0u8, 54u8, 0u8, 60u8, 0u8, 67u8, 0u8, 80u8, 0u8, 92u8,
+                    0u8, 104u8, 0u8, 120u8, 0u8, 127u8, 0u8, 135u8, 0u8, 142u8, 0u8, 98u8, 117u8,
+                    100u8, 100u8, 104u8, 105u8, 115u8, 116u8, 99u8, 104u8, 105u8, 110u8, 101u8,
+                    115u8, 101u8, 99u8, 111u8, 112u8, 116u8, 105u8, 99u8, 100u8, 97u8, 110u8,
+                    103u8, 105u8, 101u8, 116u8, 104u8, 105u8, 111u8, 97u8, 97u8, 101u8, 116u8,
+                    104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 103u8, 114u8, 101u8, 103u8, 111u8,
+                    114u8, 121u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 105u8, 110u8, 100u8,
+                    105u8, 97u8, 110u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 105u8,
+                    115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 99u8, 105u8, 118u8, 105u8, 108u8,
+                    105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 114u8, 103u8, 115u8, 97u8,
+                    105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 116u8, 98u8, 108u8, 97u8,
+                    105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 117u8, 109u8, 97u8, 108u8,
+                    113u8, 117u8, 114u8, 97u8, 105u8, 115u8, 111u8, 56u8, 54u8, 48u8, 49u8, 106u8,
+                    97u8, 112u8, 97u8, 110u8, 101u8, 115u8, 101u8, 112u8, 101u8, 114u8, 115u8,
+                    105u8, 97u8, 110u8, 114u8, 111u8, 99u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    18u8, 0u8, 0u8, 0u8, 0u8, 0u8, 17u8, 0u8, 33u8, 0u8, 48u8, 0u8, 62u8, 0u8,
+                    90u8, 0u8, 107u8, 0u8, 125u8, 0u8, 140u8, 0u8, 164u8, 0u8, 180u8, 0u8, 219u8,
+                    0u8, 4u8, 1u8, 50u8, 1u8, 80u8, 1u8, 97u8, 1u8, 114u8, 1u8, 130u8, 1u8, 66u8,
+                    117u8, 100u8, 100u8, 104u8, 105u8, 115u8, 116u8, 32u8, 67u8, 97u8, 108u8,
+                    101u8, 110u8, 100u8, 97u8, 114u8, 67u8, 104u8, 105u8, 110u8, 101u8, 115u8,
+                    101u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 67u8, 111u8,
+                    112u8, 116u8, 105u8, 99u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8,
+                    114u8, 68u8, 97u8, 110u8, 103u8, 105u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8,
+                    100u8, 97u8, 114u8, 69u8, 116u8, 104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 32u8,
+                    65u8, 109u8, 101u8, 116u8, 101u8, 32u8, 65u8, 108u8, 101u8, 109u8, 32u8, 67u8,
+                    97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 69u8, 116u8, 104u8, 105u8,
+                    111u8, 112u8, 105u8, 99u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8,
+                    114u8, 71u8, 114u8, 101u8, 103u8, 111u8, 114u8, 105u8, 97u8, 110u8, 32u8, 67u8,
+                    97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 72u8, 101u8, 98u8, 114u8, 101u8,
+                    119u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 73u8, 110u8,
+                    100u8, 105u8, 97u8, 110u8, 32u8, 78u8, 97u8, 116u8, 105u8, 111u8, 110u8, 97u8,
+                    108u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 73u8, 115u8,
+                    108u8, 97u8, 109u8, 105u8, 99u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8,
+                    97u8, 114u8, 73u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 32u8, 67u8, 97u8,
+                    108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 32u8, 40u8, 116u8, 97u8, 98u8, 117u8,
+                    108u8, 97u8, 114u8, 44u8, 32u8, 99u8, 105u8, 118u8, 105u8, 108u8, 32u8, 101u8,
+                    112u8, 111u8, 99u8, 104u8, 41u8, 73u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8,
+                    32u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 32u8, 40u8, 83u8,
+                    97u8, 117u8, 100u8, 105u8, 32u8, 65u8, 114u8, 97u8, 98u8, 105u8, 97u8, 44u8,
+                    32u8, 115u8, 105u8, 103u8, 104u8, 116u8, 105u8, 110u8, 103u8, 41u8, 73u8,
+                    115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8,
+                    100u8, 97u8, 114u8, 32u8, 40u8, 116u8, 97u8, 98u8, 117u8, 108u8, 97u8, 114u8,
+                    44u8, 32u8, 97u8, 115u8, 116u8, 114u8, 111u8, 110u8, 111u8, 109u8, 105u8, 99u8,
+                    97u8, 108u8, 32u8, 101u8, 112u8, 111u8, 99u8, 104u8, 41u8, 73u8, 115u8, 108u8,
+                    97u8, 109u8, 105u8, 99u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8,
+                    114u8, 32u8, 40u8, 85u8, 109u8, 109u8, 32u8, 97u8, 108u8, 45u8, 81u8, 117u8,
+                    114u8, 97u8, 41u8, 73u8, 83u8, 79u8, 45u8, 56u8, 54u8, 48u8, 49u8, 32u8, 67u8,
+                    97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 74u8, 97u8, 112u8, 97u8, 110u8,
+                    101u8, 115u8, 101u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8,
+                    80u8, 101u8, 114u8, 115u8, 105u8, 97u8, 110u8, 32u8, 67u8, 97u8, 108u8, 101u8,
+                    110u8, 100u8, 97u8, 114u8, 77u8, 105u8, 110u8, 103u8, 117u8, 111u8, 32u8, 67u8,
+                    97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8,
+                ])
+            },
+        )
+    },
+};
+static ES: &DataStruct = &::icu_displaynames::provider::CalendarDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    16u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 15u8, 0u8, 21u8, 0u8, 26u8, 0u8, 33u8,
+                    0u8, 41u8, 0u8, 48u8, 0u8, 54u8, 0u8, 60u8, 0u8, 67u8, 0u8, 80u8, 0u8, 96u8,
+                    0u8, 103u8, 0u8, 111u8, 0u8, 118u8, 0u8, 98u8, 117u8, 100u8, 100u8, 104u8,
+                    105u8, 115u8, 116u8, 99u8, 104u8, 105u8, 110u8, 101u8, 115u8, 101u8, 99u8,
+                    111u8, 112u8, 116u8, 105u8, 99u8, 100u8, 97u8, 110u8, 103u8, 105u8, 101u8,
+                    116u8, 104u8, 105u8, 111u8, 97u8, 97u8, 101u8, 116u8, 104u8, 105u8, 111u8,
+                    112u8, 105u8, 99u8, 103u8, 114u8, 101u8, 103u8, 111u8, 114u8, 121u8, 104u8,
+                    101u8, 98u8, 114u8, 101u8, 119u8, 105u8, 110u8, 100u8, 105u8, 97u8, 110u8,
+                    105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 105u8, 115u8, 108u8, 97u8,
+                    109u8, 105u8, 99u8, 45u8, 99u8, 105u8, 118u8, 105u8, 108u8, 105u8, 115u8,
+                    108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 117u8, 109u8, 97u8, 108u8, 113u8, 117u8,
+                    114u8, 97u8, 105u8, 115u8, 111u8, 56u8, 54u8, 48u8, 49u8, 106u8, 97u8, 112u8,
+                    97u8, 110u8, 101u8, 115u8, 101u8, 112u8, 101u8, 114u8, 115u8, 105u8, 97u8,
+                    110u8, 114u8, 111u8, 99u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    16u8, 0u8, 0u8, 0u8, 0u8, 0u8, 18u8, 0u8, 34u8, 0u8, 53u8, 0u8, 69u8, 0u8,
+                    98u8, 0u8, 116u8, 0u8, 137u8, 0u8, 154u8, 0u8, 180u8, 0u8, 200u8, 0u8, 228u8,
+                    0u8, 4u8, 1u8, 23u8, 1u8, 42u8, 1u8, 58u8, 1u8, 99u8, 97u8, 108u8, 101u8,
+                    110u8, 100u8, 97u8, 114u8, 105u8, 111u8, 32u8, 98u8, 117u8, 100u8, 105u8,
+                    115u8, 116u8, 97u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 105u8,
+                    111u8, 32u8, 99u8, 104u8, 105u8, 110u8, 111u8, 99u8, 97u8, 108u8, 101u8, 110u8,
+                    100u8, 97u8, 114u8, 105u8, 111u8, 32u8, 99u8, 195u8, 179u8, 112u8, 116u8,
+                    105u8, 99u8, 111u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 105u8,
+                    111u8, 32u8, 100u8, 97u8, 110u8, 103u8, 105u8, 99u8, 97u8, 108u8, 101u8, 110u8,
+                    100u8, 97u8, 114u8, 105u8, 111u8, 32u8, 101u8, 116u8, 195u8, 173u8, 111u8,
+                    112u8, 101u8, 32u8, 65u8, 109u8, 101u8, 116u8, 101u8, 32u8, 65u8, 108u8, 101u8,
+                    109u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 105u8, 111u8, 32u8,
+                    101u8, 116u8, 195u8, 173u8, 111u8, 112u8, 101u8, 99u8, 97u8, 108u8, 101u8,
+                    110u8, 100u8, 97u8, 114u8, 105u8, 111u8, 32u8, 103u8, 114u8, 101u8, 103u8,
+                    111u8, 114u8, 105u8, 97u8, 110u8, 111u8, 99u8, 97u8, 108u8, 101u8, 110u8,
+                    100u8, 97u8, 114u8, 105u8, 111u8, 32u8, 104u8, 101u8, 98u8, 114u8, 101u8,
+                    111u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 105u8, 111u8, 32u8,
+                    110u8, 97u8, 99u8, 105u8, 111u8, 110u8, 97u8, 108u8, 32u8, 104u8, 105u8, 110u8,
+                    100u8, 195u8, 186u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8,
+                    105u8, 111u8, 32u8, 105u8, 115u8, 108u8, 195u8, 161u8, 109u8, 105u8, 99u8,
+                    111u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 105u8, 111u8, 32u8,
+                    105u8, 115u8, 108u8, 195u8, 161u8, 109u8, 105u8, 99u8, 111u8, 32u8, 116u8,
+                    97u8, 98u8, 117u8, 108u8, 97u8, 114u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8,
+                    97u8, 114u8, 105u8, 111u8, 32u8, 105u8, 115u8, 108u8, 195u8, 161u8, 109u8,
+                    105u8, 99u8, 111u8, 32u8, 85u8, 109u8, 109u8, 32u8, 97u8, 108u8, 45u8, 81u8,
+                    117u8, 114u8, 97u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 105u8,
+                    111u8, 32u8, 73u8, 83u8, 79u8, 45u8, 56u8, 54u8, 48u8, 49u8, 99u8, 97u8, 108u8,
+                    101u8, 110u8, 100u8, 97u8, 114u8, 105u8, 111u8, 32u8, 106u8, 97u8, 112u8,
+                    111u8, 110u8, 195u8, 169u8, 115u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8,
+                    97u8, 114u8, 105u8, 111u8, 32u8, 112u8, 101u8, 114u8, 115u8, 97u8, 99u8, 97u8,
+                    108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 105u8, 111u8, 32u8, 100u8, 101u8,
+                    32u8, 108u8, 97u8, 32u8, 82u8, 101u8, 112u8, 195u8, 186u8, 98u8, 108u8, 105u8,
+                    99u8, 97u8, 32u8, 100u8, 101u8, 32u8, 67u8, 104u8, 105u8, 110u8, 97u8,
+                ])
+            },
+        )
+    },
+};
+static ES_AR: &DataStruct = &::icu_displaynames::provider::CalendarDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    18u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 15u8, 0u8, 21u8, 0u8, 26u8, 0u8, 33u8,
+                    0u8, 41u8, 0u8, 48u8, 0u8, 54u8, 0u8, 60u8, 0u8, 67u8, 0u8, 80u8, 0u8, 92u8,
+                    0u8, 104u8, 0u8, 120u8, 0u8, 127u8, 0u8, 135u8, 0u8, 142u8, 0u8, 98u8, 117u8,
+                    100u8, 100u8, 104u8, 105u8, 115u8, 116u8, 99u8, 104u8, 105u8, 110u8, 101u8,
+                    115u8, 101u8, 99u8, 111u8, 112u8, 116u8, 105u8, 99u8, 100u8, 97u8, 110u8,
+                    103u8, 105u8, 101u8, 116u8, 104u8, 105u8, 111u8, 97u8, 97u8, 101u8, 116u8,
+                    104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 103u8, 114u8, 101u8, 103u8, 111u8,
+                    114u8, 121u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 105u8, 110u8, 100u8,
+                    105u8, 97u8, 110u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 105u8,
+                    115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 99u8, 105u8, 118u8, 105u8, 108u8,
+                    105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 114u8, 103u8, 115u8, 97u8,
+                    105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 116u8, 98u8, 108u8, 97u8,
+                    105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 117u8, 109u8, 97u8, 108u8,
+                    113u8, 117u8, 114u8, 97u8, 105u8, 115u8, 111u8, 56u8, 54u8, 48u8, 49u8, 106u8,
+                    97u8, 112u8, 97u8, 110u8, 101u8, 115u8, 101u8, 112u8, 101u8, 114u8, 115u8,
+                    105u8, 97u8, 110u8, 114u8, 111u8, 99u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    18u8, 0u8, 0u8, 0u8, 0u8, 0u8, 18u8, 0u8, 34u8, 0u8, 53u8, 0u8, 69u8, 0u8,
+                    98u8, 0u8, 116u8, 0u8, 137u8, 0u8, 154u8, 0u8, 180u8, 0u8, 200u8, 0u8, 228u8,
+                    0u8, 9u8, 1u8, 37u8, 1u8, 69u8, 1u8, 88u8, 1u8, 107u8, 1u8, 123u8, 1u8, 99u8,
+                    97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 105u8, 111u8, 32u8, 98u8, 117u8,
+                    100u8, 105u8, 115u8, 116u8, 97u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8,
+                    114u8, 105u8, 111u8, 32u8, 99u8, 104u8, 105u8, 110u8, 111u8, 99u8, 97u8, 108u8,
+                    101u8, 110u8, 100u8, 97u8, 114u8, 105u8, 111u8, 32u8, 99u8, 195u8, 179u8,
+                    112u8, 116u8, 105u8, 99u8, 111u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8,
+                    114u8, 105u8, 111u8, 32u8, 100u8, 97u8, 110u8, 103u8, 105u8, 99u8, 97u8, 108u8,
+                    101u8, 110u8, 100u8, 97u8, 114u8, 105u8, 111u8, 32u8, 101u8, 116u8, 195u8,
+                    173u8, 111u8, 112u8, 101u8, 32u8, 65u8, 109u8, 101u8, 116u8, 101u8, 32u8, 65u8,
+                    108u8, 101u8, 109u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8,
+                    105u8, 111u8, 32u8, 101u8, 116u8, 195u8, 173u8, 111u8, 112u8, 101u8, 99u8,
+                    97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 105u8, 111u8, 32u8, 103u8,
+                    114u8, 101u8, 103u8, 111u8, 114u8, 105u8, 97u8, 110u8, 111u8, 99u8, 97u8,
+                    108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 105u8, 111u8, 32u8, 104u8, 101u8,
+                    98u8, 114u8, 101u8, 111u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8,
+                    105u8, 111u8, 32u8, 110u8, 97u8, 99u8, 105u8, 111u8, 110u8, 97u8, 108u8, 32u8,
+                    104u8, 105u8, 110u8, 100u8, 195u8, 186u8, 99u8, 97u8, 108u8, 101u8, 110u8,
+                    100u8, 97u8, 114u8, 105u8, 111u8, 32u8, 105u8, 115u8, 108u8, 195u8, 161u8,
+                    109u8, 105u8, 99u8, 111u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8,
+                    105u8, 111u8, 32u8, 105u8, 115u8, 108u8, 195u8, 161u8, 109u8, 105u8, 99u8,
+                    111u8, 32u8, 116u8, 97u8, 98u8, 117u8, 108u8, 97u8, 114u8, 99u8, 97u8, 108u8,
+                    101u8, 110u8, 100u8, 97u8, 114u8, 105u8, 111u8, 32u8, 105u8, 115u8, 108u8,
+                    195u8, 161u8, 109u8, 105u8, 99u8, 111u8, 32u8, 40u8, 65u8, 114u8, 97u8, 98u8,
+                    105u8, 97u8, 32u8, 83u8, 97u8, 117u8, 100u8, 105u8, 116u8, 97u8, 41u8, 99u8,
+                    97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 105u8, 111u8, 32u8, 105u8,
+                    115u8, 108u8, 195u8, 161u8, 109u8, 105u8, 99u8, 111u8, 32u8, 116u8, 97u8, 98u8,
+                    117u8, 108u8, 97u8, 114u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8,
+                    105u8, 111u8, 32u8, 105u8, 115u8, 108u8, 195u8, 161u8, 109u8, 105u8, 99u8,
+                    111u8, 32u8, 85u8, 109u8, 109u8, 32u8, 97u8, 108u8, 45u8, 81u8, 117u8, 114u8,
+                    97u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 105u8, 111u8, 32u8,
+                    73u8, 83u8, 79u8, 45u8, 56u8, 54u8, 48u8, 49u8, 99u8, 97u8, 108u8, 101u8,
+                    110u8, 100u8, 97u8, 114u8, 105u8, 111u8, 32u8, 106u8, 97u8, 112u8, 111u8,
+                    110u8, 195u8, 169u8, 115u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8,
+                    114u8, 105u8, 111u8, 32u8, 112u8, 101u8, 114u8, 115u8, 97u8, 99u8, 97u8, 108u8,
+                    101u8, 110u8, 100u8, 97u8, 114u8, 105u8, 111u8, 32u8, 100u8, 101u8, 32u8,
+                    108u8, 97u8, 32u8, 82u8, 101u8, 112u8, 195u8, 186u8, 98u8, 108u8, 105u8, 99u8,
+                    97u8, 32u8, 100u8, 101u8, 32u8, 67u8, 104u8, 105u8, 110u8, 97u8,
+                ])
+            },
+        )
+    },
+};
+static FIL: &DataStruct = &::icu_displaynames::provider::CalendarDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    18u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 15u8, 0u8, 21u8, 0u8, 26u8, 0u8, 33u8,
+                    0u8, 41u8, 0u8, 48u8, 0u8, 54u8, 0u8, 60u8, 0u8, 67u8, 0u8, 80u8, 0u8, 92u8,
+                    0u8, 104u8, 0u8, 120u8, 0u8, 127u8, 0u8, 135u8, 0u8, 142u8, 0u8, 98u8, 117u8,
+                    100u8, 100u8, 104u8, 105u8, 115u8, 116u8, 99u8, 104u8, 105u8, 110u8, 101u8,
+                    115u8, 101u8, 99u8, 111u8, 112u8, 116u8, 105u8, 99u8, 100u8, 97u8, 110u8,
+                    103u8, 105u8, 101u8, 116u8, 104u8, 105u8, 111u8, 97u8, 97u8, 101u8, 116u8,
+                    104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 103u8, 114u8, 101u8, 103u8, 111u8,
+                    114u8, 121u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 105u8, 110u8, 100u8,
+                    105u8, 97u8, 110u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 105u8,
+                    115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 99u8, 105u8, 118u8, 105u8, 108u8,
+                    105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 114u8, 103u8, 115u8, 97u8,
+                    105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 116u8, 98u8, 108u8, 97u8,
+                    105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 117u8, 109u8, 97u8, 108u8,
+                    113u8, 117u8, 114u8, 97u8, 105u8, 115u8, 111u8, 56u8, 54u8, 48u8, 49u8, 106u8,
+                    97u8, 112u8, 97u8, 110u8, 101u8, 115u8, 101u8, 112u8, 101u8, 114u8, 115u8,
+                    105u8, 97u8, 110u8, 114u8, 111u8, 99u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    18u8, 0u8, 0u8, 0u8, 0u8, 0u8, 22u8, 0u8, 42u8, 0u8, 61u8, 0u8, 80u8, 0u8,
+                    112u8, 0u8, 134u8, 0u8, 157u8, 0u8, 177u8, 0u8, 207u8, 0u8, 227u8, 0u8, 253u8,
+                    0u8, 43u8, 1u8, 97u8, 1u8, 129u8, 1u8, 151u8, 1u8, 172u8, 1u8, 192u8, 1u8,
+                    75u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 121u8, 111u8, 32u8, 110u8,
+                    103u8, 32u8, 66u8, 117u8, 100u8, 100u8, 104u8, 105u8, 115u8, 116u8, 75u8, 97u8,
+                    108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 121u8, 111u8, 110u8, 103u8, 32u8,
+                    67u8, 104u8, 105u8, 110u8, 101u8, 115u8, 101u8, 75u8, 97u8, 108u8, 101u8,
+                    110u8, 100u8, 97u8, 114u8, 121u8, 111u8, 110u8, 103u8, 32u8, 67u8, 111u8,
+                    112u8, 116u8, 105u8, 99u8, 68u8, 97u8, 110u8, 103u8, 105u8, 32u8, 110u8, 97u8,
+                    32u8, 75u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 121u8, 111u8, 75u8,
+                    97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 121u8, 111u8, 110u8, 103u8,
+                    32u8, 69u8, 116u8, 104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 32u8, 65u8, 109u8,
+                    101u8, 116u8, 101u8, 32u8, 65u8, 108u8, 101u8, 109u8, 75u8, 97u8, 108u8, 101u8,
+                    110u8, 100u8, 97u8, 114u8, 121u8, 111u8, 32u8, 110u8, 103u8, 32u8, 69u8, 116u8,
+                    104u8, 105u8, 111u8, 112u8, 105u8, 97u8, 71u8, 114u8, 101u8, 103u8, 111u8,
+                    114u8, 105u8, 97u8, 110u8, 32u8, 110u8, 97u8, 32u8, 75u8, 97u8, 108u8, 101u8,
+                    110u8, 100u8, 97u8, 114u8, 121u8, 111u8, 72u8, 101u8, 98u8, 114u8, 101u8,
+                    119u8, 32u8, 110u8, 97u8, 32u8, 75u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8,
+                    114u8, 121u8, 111u8, 80u8, 97u8, 109u8, 98u8, 97u8, 110u8, 115u8, 97u8, 110u8,
+                    103u8, 32u8, 75u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 121u8, 111u8,
+                    110u8, 103u8, 32u8, 73u8, 110u8, 100u8, 105u8, 97u8, 110u8, 75u8, 97u8, 108u8,
+                    101u8, 110u8, 100u8, 97u8, 114u8, 121u8, 111u8, 110u8, 103u8, 32u8, 73u8,
+                    115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 75u8, 97u8, 108u8, 101u8, 110u8, 100u8,
+                    97u8, 114u8, 121u8, 111u8, 110u8, 103u8, 32u8, 73u8, 115u8, 108u8, 97u8, 109u8,
+                    105u8, 99u8, 45u8, 67u8, 105u8, 118u8, 105u8, 108u8, 75u8, 97u8, 108u8, 101u8,
+                    110u8, 100u8, 97u8, 114u8, 121u8, 111u8, 110u8, 103u8, 32u8, 73u8, 115u8,
+                    108u8, 97u8, 109u8, 105u8, 107u8, 111u8, 32u8, 40u8, 83u8, 97u8, 117u8, 100u8,
+                    105u8, 32u8, 65u8, 114u8, 97u8, 98u8, 105u8, 97u8, 44u8, 32u8, 115u8, 105u8,
+                    103u8, 104u8, 116u8, 105u8, 110u8, 103u8, 41u8, 75u8, 97u8, 108u8, 101u8,
+                    110u8, 100u8, 97u8, 114u8, 121u8, 111u8, 110u8, 103u8, 32u8, 73u8, 115u8,
+                    108u8, 97u8, 109u8, 105u8, 107u8, 111u8, 32u8, 40u8, 116u8, 97u8, 98u8, 117u8,
+                    108u8, 97u8, 114u8, 44u8, 32u8, 97u8, 115u8, 116u8, 114u8, 111u8, 110u8, 111u8,
+                    109u8, 105u8, 107u8, 97u8, 108u8, 32u8, 110u8, 97u8, 32u8, 101u8, 112u8, 111u8,
+                    99u8, 104u8, 41u8, 75u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 121u8,
+                    111u8, 110u8, 103u8, 32u8, 73u8, 115u8, 108u8, 97u8, 109u8, 32u8, 40u8, 85u8,
+                    109u8, 109u8, 32u8, 97u8, 108u8, 45u8, 81u8, 117u8, 114u8, 97u8, 41u8, 73u8,
+                    83u8, 79u8, 45u8, 56u8, 54u8, 48u8, 49u8, 32u8, 110u8, 97u8, 32u8, 75u8, 97u8,
+                    108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 121u8, 111u8, 75u8, 97u8, 108u8,
+                    101u8, 110u8, 100u8, 97u8, 114u8, 121u8, 111u8, 110u8, 103u8, 32u8, 74u8, 97u8,
+                    112u8, 97u8, 110u8, 101u8, 115u8, 101u8, 75u8, 97u8, 108u8, 101u8, 110u8,
+                    100u8, 97u8, 114u8, 121u8, 111u8, 110u8, 103u8, 32u8, 80u8, 101u8, 114u8,
+                    115u8, 105u8, 97u8, 110u8, 75u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8,
+                    121u8, 111u8, 110u8, 103u8, 32u8, 77u8, 105u8, 110u8, 103u8, 117u8, 111u8,
+                ])
+            },
+        )
+    },
+};
+static FR: &DataStruct = &::icu_displaynames::provider::CalendarDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    17u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 15u8, 0u8, 21u8, 0u8, 26u8, 0u8, 33u8,
+                    0u8, 41u8, 0u8, 48u8, 0u8, 54u8, 0u8, 60u8, 0u8, 67u8, 0u8, 80u8, 0u8, 92u8,
+                    0u8, 108u8, 0u8, 115u8, 0u8, 123u8, 0u8, 130u8, 0u8, 98u8, 117u8, 100u8, 100u8,
+                    104u8, 105u8, 115u8, 116u8, 99u8, 104u8, 105u8, 110u8, 101u8, 115u8, 101u8,
+                    99u8, 111u8, 112u8, 116u8, 105u8, 99u8, 100u8, 97u8, 110u8, 103u8, 105u8,
+                    101u8, 116u8, 104u8, 105u8, 111u8, 97u8, 97u8, 101u8, 116u8, 104u8, 105u8,
+                    111u8, 112u8, 105u8, 99u8, 103u8, 114u8, 101u8, 103u8, 111u8, 114u8, 121u8,
+                    104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 105u8, 110u8, 100u8, 105u8, 97u8,
+                    110u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 105u8, 115u8, 108u8,
+                    97u8, 109u8, 105u8, 99u8, 45u8, 99u8, 105u8, 118u8, 105u8, 108u8, 105u8, 115u8,
+                    108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 116u8, 98u8, 108u8, 97u8, 105u8, 115u8,
+                    108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 117u8, 109u8, 97u8, 108u8, 113u8, 117u8,
+                    114u8, 97u8, 105u8, 115u8, 111u8, 56u8, 54u8, 48u8, 49u8, 106u8, 97u8, 112u8,
+                    97u8, 110u8, 101u8, 115u8, 101u8, 112u8, 101u8, 114u8, 115u8, 105u8, 97u8,
+                    110u8, 114u8, 111u8, 99u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    17u8, 0u8, 0u8, 0u8, 0u8, 0u8, 21u8, 0u8, 39u8, 0u8, 55u8, 0u8, 71u8, 0u8,
+                    103u8, 0u8, 124u8, 0u8, 145u8, 0u8, 167u8, 0u8, 184u8, 0u8, 203u8, 0u8, 250u8,
+                    0u8, 47u8, 1u8, 80u8, 1u8, 99u8, 1u8, 118u8, 1u8, 135u8, 1u8, 99u8, 97u8,
+                    108u8, 101u8, 110u8, 100u8, 114u8, 105u8, 101u8, 114u8, 32u8, 98u8, 111u8,
+                    117u8, 100u8, 100u8, 104u8, 105u8, 115u8, 116u8, 101u8, 99u8, 97u8, 108u8,
+                    101u8, 110u8, 100u8, 114u8, 105u8, 101u8, 114u8, 32u8, 99u8, 104u8, 105u8,
+                    110u8, 111u8, 105u8, 115u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 114u8,
+                    105u8, 101u8, 114u8, 32u8, 99u8, 111u8, 112u8, 116u8, 101u8, 99u8, 97u8, 108u8,
+                    101u8, 110u8, 100u8, 114u8, 105u8, 101u8, 114u8, 32u8, 100u8, 97u8, 110u8,
+                    103u8, 105u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 114u8, 105u8, 101u8,
+                    114u8, 32u8, 195u8, 169u8, 116u8, 104u8, 105u8, 111u8, 112u8, 105u8, 101u8,
+                    110u8, 32u8, 65u8, 109u8, 101u8, 116u8, 101u8, 32u8, 65u8, 108u8, 101u8, 109u8,
+                    99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 114u8, 105u8, 101u8, 114u8, 32u8,
+                    195u8, 169u8, 116u8, 104u8, 105u8, 111u8, 112u8, 105u8, 101u8, 110u8, 99u8,
+                    97u8, 108u8, 101u8, 110u8, 100u8, 114u8, 105u8, 101u8, 114u8, 32u8, 103u8,
+                    114u8, 195u8, 169u8, 103u8, 111u8, 114u8, 105u8, 101u8, 110u8, 99u8, 97u8,
+                    108u8, 101u8, 110u8, 100u8, 114u8, 105u8, 101u8, 114u8, 32u8, 104u8, 195u8,
+                    169u8, 98u8, 114u8, 97u8, 195u8, 175u8, 113u8, 117u8, 101u8, 99u8, 97u8, 108u8,
+                    101u8, 110u8, 100u8, 114u8, 105u8, 101u8, 114u8, 32u8, 105u8, 110u8, 100u8,
+                    105u8, 101u8, 110u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 114u8, 105u8,
+                    101u8, 114u8, 32u8, 109u8, 117u8, 115u8, 117u8, 108u8, 109u8, 97u8, 110u8,
+                    99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 114u8, 105u8, 101u8, 114u8, 32u8,
+                    109u8, 117u8, 115u8, 117u8, 108u8, 109u8, 97u8, 110u8, 32u8, 40u8, 116u8, 97u8,
+                    98u8, 117u8, 108u8, 97u8, 105u8, 114u8, 101u8, 44u8, 32u8, 195u8, 169u8, 112u8,
+                    111u8, 113u8, 117u8, 101u8, 32u8, 99u8, 105u8, 118u8, 105u8, 108u8, 101u8,
+                    41u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 114u8, 105u8, 101u8, 114u8, 32u8,
+                    109u8, 117u8, 115u8, 117u8, 108u8, 109u8, 97u8, 110u8, 32u8, 40u8, 116u8, 97u8,
+                    98u8, 117u8, 108u8, 97u8, 105u8, 114u8, 101u8, 44u8, 32u8, 195u8, 169u8, 112u8,
+                    111u8, 113u8, 117u8, 101u8, 32u8, 97u8, 115u8, 116u8, 114u8, 111u8, 110u8,
+                    111u8, 109u8, 105u8, 113u8, 117u8, 101u8, 41u8, 99u8, 97u8, 108u8, 101u8,
+                    110u8, 100u8, 114u8, 105u8, 101u8, 114u8, 32u8, 109u8, 117u8, 115u8, 117u8,
+                    108u8, 109u8, 97u8, 110u8, 32u8, 40u8, 85u8, 109u8, 109u8, 32u8, 97u8, 108u8,
+                    32u8, 81u8, 117u8, 114u8, 97u8, 41u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8,
+                    114u8, 105u8, 101u8, 114u8, 32u8, 73u8, 83u8, 79u8, 32u8, 56u8, 54u8, 48u8,
+                    49u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 114u8, 105u8, 101u8, 114u8, 32u8,
+                    106u8, 97u8, 112u8, 111u8, 110u8, 97u8, 105u8, 115u8, 99u8, 97u8, 108u8, 101u8,
+                    110u8, 100u8, 114u8, 105u8, 101u8, 114u8, 32u8, 112u8, 101u8, 114u8, 115u8,
+                    97u8, 110u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 114u8, 105u8, 101u8,
+                    114u8, 32u8, 114u8, 195u8, 169u8, 112u8, 117u8, 98u8, 108u8, 105u8, 99u8, 97u8,
+                    105u8, 110u8, 32u8, 99u8, 104u8, 105u8, 110u8, 111u8, 105u8, 115u8,
+                ])
+            },
+        )
+    },
+};
+static JA: &DataStruct = &::icu_displaynames::provider::CalendarDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    18u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 15u8, 0u8, 21u8, 0u8, 26u8, 0u8, 33u8,
+                    0u8, 41u8, 0u8, 48u8, 0u8, 54u8, 0u8, 60u8, 0u8, 67u8, 0u8, 80u8, 0u8, 92u8,
+                    0u8, 104u8, 0u8, 120u8, 0u8, 127u8, 0u8, 135u8, 0u8, 142u8, 0u8, 98u8, 117u8,
+                    100u8, 100u8, 104u8, 105u8, 115u8, 116u8, 99u8, 104u8, 105u8, 110u8, 101u8,
+                    115u8, 101u8, 99u8, 111u8, 112u8, 116u8, 105u8, 99u8, 100u8, 97u8, 110u8,
+                    103u8, 105u8, 101u8, 116u8, 104u8, 105u8, 111u8, 97u8, 97u8, 101u8, 116u8,
+                    104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 103u8, 114u8, 101u8, 103u8, 111u8,
+                    114u8, 121u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 105u8, 110u8, 100u8,
+                    105u8, 97u8, 110u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 105u8,
+                    115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 99u8, 105u8, 118u8, 105u8, 108u8,
+                    105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 114u8, 103u8, 115u8, 97u8,
+                    105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 116u8, 98u8, 108u8, 97u8,
+                    105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 117u8, 109u8, 97u8, 108u8,
+                    113u8, 117u8, 114u8, 97u8, 105u8, 115u8, 111u8, 56u8, 54u8, 48u8, 49u8, 106u8,
+                    97u8, 112u8, 97u8, 110u8, 101u8, 115u8, 101u8, 112u8, 101u8, 114u8, 115u8,
+                    105u8, 97u8, 110u8, 114u8, 111u8, 99u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    18u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 15u8, 0u8, 27u8, 0u8, 39u8, 0u8, 69u8,
+                    0u8, 87u8, 0u8, 113u8, 0u8, 125u8, 0u8, 143u8, 0u8, 158u8, 0u8, 199u8, 0u8,
+                    249u8, 0u8, 34u8, 1u8, 78u8, 1u8, 86u8, 1u8, 92u8, 1u8, 107u8, 1u8, 228u8,
+                    187u8, 143u8, 230u8, 154u8, 166u8, 228u8, 184u8, 173u8, 229u8, 155u8, 189u8,
+                    230u8, 154u8, 166u8, 227u8, 130u8, 179u8, 227u8, 131u8, 151u8, 227u8, 131u8,
+                    136u8, 230u8, 154u8, 166u8, 227u8, 131u8, 128u8, 227u8, 131u8, 179u8, 227u8,
+                    130u8, 174u8, 230u8, 154u8, 166u8, 227u8, 130u8, 168u8, 227u8, 131u8, 129u8,
+                    227u8, 130u8, 170u8, 227u8, 131u8, 148u8, 227u8, 130u8, 162u8, 229u8, 137u8,
+                    181u8, 228u8, 184u8, 150u8, 231u8, 180u8, 128u8, 229u8, 133u8, 131u8, 230u8,
+                    154u8, 166u8, 227u8, 130u8, 168u8, 227u8, 131u8, 129u8, 227u8, 130u8, 170u8,
+                    227u8, 131u8, 148u8, 227u8, 130u8, 162u8, 230u8, 154u8, 166u8, 232u8, 165u8,
+                    191u8, 230u8, 154u8, 166u8, 40u8, 227u8, 130u8, 176u8, 227u8, 131u8, 172u8,
+                    227u8, 130u8, 180u8, 227u8, 131u8, 170u8, 227u8, 130u8, 170u8, 230u8, 154u8,
+                    166u8, 41u8, 227u8, 131u8, 166u8, 227u8, 131u8, 128u8, 227u8, 131u8, 164u8,
+                    230u8, 154u8, 166u8, 227u8, 130u8, 164u8, 227u8, 131u8, 179u8, 227u8, 131u8,
+                    137u8, 229u8, 155u8, 189u8, 229u8, 174u8, 154u8, 230u8, 154u8, 166u8, 227u8,
+                    130u8, 164u8, 227u8, 130u8, 185u8, 227u8, 131u8, 169u8, 227u8, 131u8, 160u8,
+                    230u8, 154u8, 166u8, 227u8, 130u8, 164u8, 227u8, 130u8, 185u8, 227u8, 131u8,
+                    169u8, 227u8, 131u8, 160u8, 230u8, 154u8, 166u8, 40u8, 229u8, 174u8, 154u8,
+                    229u8, 145u8, 168u8, 230u8, 156u8, 159u8, 227u8, 128u8, 129u8, 229u8, 133u8,
+                    172u8, 230u8, 176u8, 145u8, 231u8, 180u8, 128u8, 229u8, 133u8, 131u8, 41u8,
+                    227u8, 130u8, 164u8, 227u8, 130u8, 185u8, 227u8, 131u8, 169u8, 227u8, 131u8,
+                    160u8, 230u8, 154u8, 166u8, 40u8, 227u8, 130u8, 181u8, 227u8, 130u8, 166u8,
+                    227u8, 130u8, 184u8, 227u8, 130u8, 162u8, 227u8, 131u8, 169u8, 227u8, 131u8,
+                    147u8, 227u8, 130u8, 162u8, 227u8, 128u8, 129u8, 230u8, 156u8, 136u8, 232u8,
+                    166u8, 179u8, 230u8, 184u8, 172u8, 41u8, 227u8, 130u8, 164u8, 227u8, 130u8,
+                    185u8, 227u8, 131u8, 169u8, 227u8, 131u8, 160u8, 230u8, 173u8, 180u8, 40u8,
+                    229u8, 174u8, 154u8, 229u8, 145u8, 168u8, 230u8, 156u8, 159u8, 227u8, 128u8,
+                    129u8, 229u8, 164u8, 169u8, 230u8, 150u8, 135u8, 231u8, 180u8, 128u8, 229u8,
+                    133u8, 131u8, 41u8, 227u8, 130u8, 164u8, 227u8, 130u8, 185u8, 227u8, 131u8,
+                    169u8, 227u8, 131u8, 160u8, 230u8, 154u8, 166u8, 40u8, 227u8, 130u8, 166u8,
+                    227u8, 131u8, 179u8, 227u8, 131u8, 160u8, 227u8, 131u8, 187u8, 227u8, 130u8,
+                    162u8, 227u8, 131u8, 171u8, 227u8, 130u8, 175u8, 227u8, 131u8, 169u8, 227u8,
+                    131u8, 188u8, 41u8, 73u8, 83u8, 79u8, 45u8, 56u8, 54u8, 48u8, 49u8, 229u8,
+                    146u8, 140u8, 230u8, 154u8, 166u8, 227u8, 131u8, 154u8, 227u8, 131u8, 171u8,
+                    227u8, 130u8, 183u8, 227u8, 130u8, 162u8, 230u8, 154u8, 166u8, 228u8, 184u8,
+                    173u8, 232u8, 143u8, 175u8, 230u8, 176u8, 145u8, 229u8, 155u8, 189u8, 230u8,
+                    154u8, 166u8,
+                ])
+            },
+        )
+    },
+};
+static RU: &DataStruct = &::icu_displaynames::provider::CalendarDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    18u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 15u8, 0u8, 21u8, 0u8, 26u8, 0u8, 33u8,
+                    0u8, 41u8, 0u8, 48u8, 0u8, 54u8, 0u8, 60u8, 0u8, 67u8, 0u8, 80u8, 0u8, 92u8,
+                    0u8, 104u8, 0u8, 120u8, 0u8, 127u8, 0u8, 135u8, 0u8, 142u8, 0u8, 98u8, 117u8,
+                    100u8, 100u8, 104u8, 105u8, 115u8, 116u8, 99u8, 104u8, 105u8, 110u8, 101u8,
+                    115u8, 101u8, 99u8, 111u8, 112u8, 116u8, 105u8, 99u8, 100u8, 97u8, 110u8,
+                    103u8, 105u8, 101u8, 116u8, 104u8, 105u8, 111u8, 97u8, 97u8, 101u8, 116u8,
+                    104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 103u8, 114u8, 101u8, 103u8, 111u8,
+                    114u8, 121u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 105u8, 110u8, 100u8,
+                    105u8, 97u8, 110u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 105u8,
+                    115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 99u8, 105u8, 118u8, 105u8, 108u8,
+                    105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 114u8, 103u8, 115u8, 97u8,
+                    105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 116u8, 98u8, 108u8, 97u8,
+                    105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 117u8, 109u8, 97u8, 108u8,
+                    113u8, 117u8, 114u8, 97u8, 105u8, 115u8, 111u8, 56u8, 54u8, 48u8, 49u8, 106u8,
+                    97u8, 112u8, 97u8, 110u8, 101u8, 115u8, 101u8, 112u8, 101u8, 114u8, 115u8,
+                    105u8, 97u8, 110u8, 114u8, 111u8, 99u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    18u8, 0u8, 0u8, 0u8, 0u8, 0u8, 39u8, 0u8, 76u8, 0u8, 111u8, 0u8, 140u8, 0u8,
+                    197u8, 0u8, 234u8, 0u8, 23u8, 1u8, 60u8, 1u8, 114u8, 1u8, 151u8, 1u8, 232u8,
+                    1u8, 49u8, 2u8, 150u8, 2u8, 212u8, 2u8, 239u8, 2u8, 18u8, 3u8, 57u8, 3u8,
+                    208u8, 177u8, 209u8, 131u8, 208u8, 180u8, 208u8, 180u8, 208u8, 184u8, 208u8,
+                    185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 32u8, 208u8,
+                    186u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8, 189u8, 208u8, 180u8,
+                    208u8, 176u8, 209u8, 128u8, 209u8, 140u8, 208u8, 186u8, 208u8, 184u8, 209u8,
+                    130u8, 208u8, 176u8, 208u8, 185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                    208u8, 185u8, 32u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8,
+                    208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 209u8, 140u8, 208u8,
+                    186u8, 208u8, 190u8, 208u8, 191u8, 209u8, 130u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 184u8, 208u8, 185u8, 32u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8,
+                    208u8, 181u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 209u8,
+                    140u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8, 189u8,
+                    208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 209u8, 140u8, 32u8, 208u8, 180u8,
+                    208u8, 176u8, 208u8, 189u8, 208u8, 179u8, 208u8, 184u8, 209u8, 141u8, 209u8,
+                    132u8, 208u8, 184u8, 208u8, 190u8, 208u8, 191u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 184u8, 208u8, 185u8, 32u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8,
+                    208u8, 181u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 209u8,
+                    140u8, 32u8, 208u8, 176u8, 208u8, 188u8, 208u8, 181u8, 209u8, 130u8, 208u8,
+                    181u8, 45u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8, 188u8, 209u8,
+                    141u8, 209u8, 132u8, 208u8, 184u8, 208u8, 190u8, 208u8, 191u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 32u8, 208u8, 186u8, 208u8, 176u8,
+                    208u8, 187u8, 208u8, 181u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 209u8,
+                    128u8, 209u8, 140u8, 208u8, 179u8, 209u8, 128u8, 208u8, 184u8, 208u8, 179u8,
+                    208u8, 190u8, 209u8, 128u8, 208u8, 184u8, 208u8, 176u8, 208u8, 189u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 32u8, 208u8, 186u8, 208u8,
+                    176u8, 208u8, 187u8, 208u8, 181u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8,
+                    209u8, 128u8, 209u8, 140u8, 208u8, 181u8, 208u8, 178u8, 209u8, 128u8, 208u8,
+                    181u8, 208u8, 185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8,
+                    32u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8, 189u8,
+                    208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 209u8, 140u8, 208u8, 157u8, 208u8,
+                    176u8, 209u8, 134u8, 208u8, 184u8, 208u8, 190u8, 208u8, 189u8, 208u8, 176u8,
+                    208u8, 187u8, 209u8, 140u8, 208u8, 189u8, 209u8, 139u8, 208u8, 185u8, 32u8,
+                    208u8, 186u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8, 189u8, 208u8,
+                    180u8, 208u8, 176u8, 209u8, 128u8, 209u8, 140u8, 32u8, 208u8, 152u8, 208u8,
+                    189u8, 208u8, 180u8, 208u8, 184u8, 208u8, 184u8, 208u8, 184u8, 209u8, 129u8,
+                    208u8, 187u8, 208u8, 176u8, 208u8, 188u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                    184u8, 208u8, 185u8, 32u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8, 208u8,
+                    181u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 209u8, 140u8,
+                    208u8, 184u8, 209u8, 129u8, 208u8, 187u8, 208u8, 176u8, 208u8, 188u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 32u8, 208u8, 179u8, 209u8,
+                    128u8, 208u8, 176u8, 208u8, 182u8, 208u8, 180u8, 208u8, 176u8, 208u8, 189u8,
+                    209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 32u8, 208u8, 186u8,
+                    208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8, 189u8, 208u8, 180u8, 208u8,
+                    176u8, 209u8, 128u8, 209u8, 140u8, 32u8, 40u8, 209u8, 130u8, 208u8, 176u8,
+                    208u8, 177u8, 208u8, 187u8, 208u8, 184u8, 209u8, 135u8, 208u8, 189u8, 209u8,
+                    139u8, 208u8, 185u8, 41u8, 208u8, 184u8, 209u8, 129u8, 208u8, 187u8, 208u8,
+                    176u8, 208u8, 188u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8,
+                    32u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8, 189u8,
+                    208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 209u8, 140u8, 32u8, 40u8, 208u8,
+                    161u8, 208u8, 176u8, 209u8, 131u8, 208u8, 180u8, 208u8, 190u8, 208u8, 178u8,
+                    209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 32u8, 208u8, 144u8,
+                    209u8, 128u8, 208u8, 176u8, 208u8, 178u8, 208u8, 184u8, 209u8, 143u8, 41u8,
+                    208u8, 184u8, 209u8, 129u8, 208u8, 187u8, 208u8, 176u8, 208u8, 188u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 32u8, 208u8, 186u8, 208u8,
+                    176u8, 208u8, 187u8, 208u8, 181u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8,
+                    209u8, 128u8, 209u8, 140u8, 32u8, 40u8, 209u8, 130u8, 208u8, 176u8, 208u8,
+                    177u8, 208u8, 187u8, 208u8, 184u8, 209u8, 135u8, 208u8, 189u8, 209u8, 139u8,
+                    208u8, 185u8, 44u8, 32u8, 208u8, 176u8, 209u8, 129u8, 209u8, 130u8, 209u8,
+                    128u8, 208u8, 190u8, 208u8, 189u8, 208u8, 190u8, 208u8, 188u8, 208u8, 184u8,
+                    209u8, 135u8, 208u8, 181u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 209u8,
+                    143u8, 32u8, 209u8, 141u8, 208u8, 191u8, 208u8, 190u8, 209u8, 133u8, 208u8,
+                    176u8, 41u8, 208u8, 184u8, 209u8, 129u8, 208u8, 187u8, 208u8, 176u8, 208u8,
+                    188u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 32u8, 208u8,
+                    186u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8, 189u8, 208u8, 180u8,
+                    208u8, 176u8, 209u8, 128u8, 209u8, 140u8, 32u8, 40u8, 208u8, 163u8, 208u8,
+                    188u8, 208u8, 188u8, 32u8, 208u8, 176u8, 208u8, 187u8, 209u8, 140u8, 45u8,
+                    208u8, 154u8, 209u8, 131u8, 209u8, 128u8, 208u8, 176u8, 41u8, 208u8, 186u8,
+                    208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8, 189u8, 208u8, 180u8, 208u8,
+                    176u8, 209u8, 128u8, 209u8, 140u8, 32u8, 73u8, 83u8, 79u8, 45u8, 56u8, 54u8,
+                    48u8, 49u8, 209u8, 143u8, 208u8, 191u8, 208u8, 190u8, 208u8, 189u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 32u8, 208u8, 186u8, 208u8,
+                    176u8, 208u8, 187u8, 208u8, 181u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8,
+                    209u8, 128u8, 209u8, 140u8, 208u8, 191u8, 208u8, 181u8, 209u8, 128u8, 209u8,
+                    129u8, 208u8, 184u8, 208u8, 180u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                    208u8, 185u8, 32u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8,
+                    208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 209u8, 140u8, 208u8,
+                    186u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8, 189u8, 208u8, 180u8,
+                    208u8, 176u8, 209u8, 128u8, 209u8, 140u8, 32u8, 208u8, 156u8, 208u8, 184u8,
+                    208u8, 189u8, 209u8, 140u8, 208u8, 179u8, 208u8, 190u8,
+                ])
+            },
+        )
+    },
+};
+static SR_LATN: &DataStruct = &::icu_displaynames::provider::CalendarDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    17u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 15u8, 0u8, 21u8, 0u8, 26u8, 0u8, 33u8,
+                    0u8, 41u8, 0u8, 48u8, 0u8, 54u8, 0u8, 60u8, 0u8, 67u8, 0u8, 80u8, 0u8, 92u8,
+                    0u8, 108u8, 0u8, 115u8, 0u8, 123u8, 0u8, 130u8, 0u8, 98u8, 117u8, 100u8, 100u8,
+                    104u8, 105u8, 115u8, 116u8, 99u8, 104u8, 105u8, 110u8, 101u8, 115u8, 101u8,
+                    99u8, 111u8, 112u8, 116u8, 105u8, 99u8, 100u8, 97u8, 110u8, 103u8, 105u8,
+                    101u8, 116u8, 104u8, 105u8, 111u8, 97u8, 97u8, 101u8, 116u8, 104u8, 105u8,
+                    111u8, 112u8, 105u8, 99u8, 103u8, 114u8, 101u8, 103u8, 111u8, 114u8, 121u8,
+                    104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 105u8, 110u8, 100u8, 105u8, 97u8,
+                    110u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 105u8, 115u8, 108u8,
+                    97u8, 109u8, 105u8, 99u8, 45u8, 99u8, 105u8, 118u8, 105u8, 108u8, 105u8, 115u8,
+                    108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 116u8, 98u8, 108u8, 97u8, 105u8, 115u8,
+                    108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 117u8, 109u8, 97u8, 108u8, 113u8, 117u8,
+                    114u8, 97u8, 105u8, 115u8, 111u8, 56u8, 54u8, 48u8, 49u8, 106u8, 97u8, 112u8,
+                    97u8, 110u8, 101u8, 115u8, 101u8, 112u8, 101u8, 114u8, 115u8, 105u8, 97u8,
+                    110u8, 114u8, 111u8, 99u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    17u8, 0u8, 0u8, 0u8, 0u8, 0u8, 20u8, 0u8, 36u8, 0u8, 52u8, 0u8, 66u8, 0u8,
+                    93u8, 0u8, 110u8, 0u8, 132u8, 0u8, 150u8, 0u8, 178u8, 0u8, 195u8, 0u8, 220u8,
+                    0u8, 249u8, 0u8, 24u8, 1u8, 41u8, 1u8, 58u8, 1u8, 76u8, 1u8, 98u8, 117u8,
+                    100u8, 105u8, 115u8, 116u8, 105u8, 196u8, 141u8, 107u8, 105u8, 32u8, 107u8,
+                    97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 107u8, 105u8, 110u8, 101u8,
+                    115u8, 107u8, 105u8, 32u8, 107u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8,
+                    114u8, 107u8, 111u8, 112u8, 116u8, 115u8, 107u8, 105u8, 32u8, 107u8, 97u8,
+                    108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 100u8, 97u8, 110u8, 103u8, 105u8,
+                    32u8, 107u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 101u8, 116u8,
+                    105u8, 111u8, 112u8, 115u8, 107u8, 105u8, 32u8, 97u8, 109u8, 101u8, 116u8,
+                    32u8, 97u8, 108u8, 101u8, 109u8, 32u8, 107u8, 97u8, 108u8, 101u8, 110u8, 100u8,
+                    97u8, 114u8, 101u8, 116u8, 105u8, 111u8, 112u8, 115u8, 107u8, 105u8, 32u8,
+                    107u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 103u8, 114u8, 101u8,
+                    103u8, 111u8, 114u8, 105u8, 106u8, 97u8, 110u8, 115u8, 107u8, 105u8, 32u8,
+                    107u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 104u8, 101u8, 98u8,
+                    114u8, 101u8, 106u8, 115u8, 107u8, 105u8, 32u8, 107u8, 97u8, 108u8, 101u8,
+                    110u8, 100u8, 97u8, 114u8, 73u8, 110u8, 100u8, 105u8, 106u8, 115u8, 107u8,
+                    105u8, 32u8, 110u8, 97u8, 99u8, 105u8, 111u8, 110u8, 97u8, 108u8, 110u8, 105u8,
+                    32u8, 107u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 105u8, 115u8,
+                    108u8, 97u8, 109u8, 115u8, 107u8, 105u8, 32u8, 107u8, 97u8, 108u8, 101u8,
+                    110u8, 100u8, 97u8, 114u8, 105u8, 115u8, 108u8, 97u8, 109u8, 115u8, 107u8,
+                    105u8, 32u8, 99u8, 105u8, 118u8, 105u8, 108u8, 110u8, 105u8, 32u8, 107u8, 97u8,
+                    108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 105u8, 115u8, 108u8, 97u8, 109u8,
+                    115u8, 107u8, 105u8, 32u8, 97u8, 115u8, 116u8, 114u8, 111u8, 110u8, 111u8,
+                    109u8, 115u8, 107u8, 105u8, 32u8, 107u8, 97u8, 108u8, 101u8, 110u8, 100u8,
+                    97u8, 114u8, 105u8, 115u8, 108u8, 97u8, 109u8, 115u8, 107u8, 105u8, 32u8,
+                    107u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 32u8, 40u8, 85u8, 109u8,
+                    109u8, 32u8, 97u8, 108u8, 45u8, 81u8, 117u8, 114u8, 97u8, 41u8, 73u8, 83u8,
+                    79u8, 45u8, 56u8, 54u8, 48u8, 49u8, 32u8, 107u8, 97u8, 108u8, 101u8, 110u8,
+                    100u8, 97u8, 114u8, 106u8, 97u8, 112u8, 97u8, 110u8, 115u8, 107u8, 105u8, 32u8,
+                    107u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 112u8, 101u8, 114u8,
+                    115u8, 105u8, 106u8, 115u8, 107u8, 105u8, 32u8, 107u8, 97u8, 108u8, 101u8,
+                    110u8, 100u8, 97u8, 114u8, 107u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8,
+                    114u8, 32u8, 82u8, 101u8, 112u8, 117u8, 98u8, 108u8, 105u8, 107u8, 101u8, 32u8,
+                    75u8, 105u8, 110u8, 101u8,
+                ])
+            },
+        )
+    },
+};
+static SR_SR_CYRL: &DataStruct = &::icu_displaynames::provider::CalendarDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    17u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 15u8, 0u8, 21u8, 0u8, 26u8, 0u8, 33u8,
+                    0u8, 41u8, 0u8, 48u8, 0u8, 54u8, 0u8, 60u8, 0u8, 67u8, 0u8, 80u8, 0u8, 92u8,
+                    0u8, 108u8, 0u8, 115u8, 0u8, 123u8, 0u8, 130u8, 0u8, 98u8, 117u8, 100u8, 100u8,
+                    104u8, 105u8, 115u8, 116u8, 99u8, 104u8, 105u8, 110u8, 101u8, 115u8, 101u8,
+                    99u8, 111u8, 112u8, 116u8, 105u8, 99u8, 100u8, 97u8, 110u8, 103u8, 105u8,
+                    101u8, 116u8, 104u8, 105u8, 111u8, 97u8, 97u8, 101u8, 116u8, 104u8, 105u8,
+                    111u8, 112u8, 105u8, 99u8, 103u8, 114u8, 101u8, 103u8, 111u8, 114u8, 121u8,
+                    104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 105u8, 110u8, 100u8, 105u8, 97u8,
+                    110u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 105u8, 115u8, 108u8,
+                    97u8, 109u8, 105u8, 99u8, 45u8, 99u8, 105u8, 118u8, 105u8, 108u8, 105u8, 115u8,
+                    108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 116u8, 98u8, 108u8, 97u8, 105u8, 115u8,
+                    108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 117u8, 109u8, 97u8, 108u8, 113u8, 117u8,
+                    114u8, 97u8, 105u8, 115u8, 111u8, 56u8, 54u8, 48u8, 49u8, 106u8, 97u8, 112u8,
+                    97u8, 110u8, 101u8, 115u8, 101u8, 112u8, 101u8, 114u8, 115u8, 105u8, 97u8,
+                    110u8, 114u8, 111u8, 99u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    17u8, 0u8, 0u8, 0u8, 0u8, 0u8, 37u8, 0u8, 68u8, 0u8, 99u8, 0u8, 126u8, 0u8,
+                    177u8, 0u8, 210u8, 0u8, 253u8, 0u8, 32u8, 1u8, 86u8, 1u8, 119u8, 1u8, 167u8,
+                    1u8, 223u8, 1u8, 14u8, 2u8, 39u8, 2u8, 72u8, 2u8, 107u8, 2u8, 208u8, 177u8,
+                    209u8, 131u8, 208u8, 180u8, 208u8, 184u8, 209u8, 129u8, 209u8, 130u8, 208u8,
+                    184u8, 209u8, 135u8, 208u8, 186u8, 208u8, 184u8, 32u8, 208u8, 186u8, 208u8,
+                    176u8, 208u8, 187u8, 208u8, 181u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8,
+                    209u8, 128u8, 208u8, 186u8, 208u8, 184u8, 208u8, 189u8, 208u8, 181u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 208u8, 186u8, 208u8, 176u8, 208u8,
+                    187u8, 208u8, 181u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8,
+                    208u8, 186u8, 208u8, 190u8, 208u8, 191u8, 209u8, 130u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 184u8, 32u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8, 208u8,
+                    181u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 208u8, 180u8,
+                    208u8, 176u8, 208u8, 189u8, 208u8, 179u8, 208u8, 184u8, 32u8, 208u8, 186u8,
+                    208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8, 189u8, 208u8, 180u8, 208u8,
+                    176u8, 209u8, 128u8, 208u8, 181u8, 209u8, 130u8, 208u8, 184u8, 208u8, 190u8,
+                    208u8, 191u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 208u8, 176u8,
+                    208u8, 188u8, 208u8, 181u8, 209u8, 130u8, 32u8, 208u8, 176u8, 208u8, 187u8,
+                    208u8, 181u8, 208u8, 188u8, 32u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8,
+                    208u8, 181u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 208u8,
+                    181u8, 209u8, 130u8, 208u8, 184u8, 208u8, 190u8, 208u8, 191u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 184u8, 32u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8,
+                    208u8, 181u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 208u8,
+                    179u8, 209u8, 128u8, 208u8, 181u8, 208u8, 179u8, 208u8, 190u8, 209u8, 128u8,
+                    208u8, 184u8, 209u8, 152u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 184u8, 32u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8, 208u8,
+                    181u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 209u8, 133u8,
+                    208u8, 181u8, 208u8, 177u8, 209u8, 128u8, 208u8, 181u8, 209u8, 152u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 208u8, 186u8, 208u8, 176u8, 208u8,
+                    187u8, 208u8, 181u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8,
+                    208u8, 152u8, 208u8, 189u8, 208u8, 180u8, 208u8, 184u8, 209u8, 152u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 208u8, 189u8, 208u8, 176u8, 209u8,
+                    134u8, 208u8, 184u8, 208u8, 190u8, 208u8, 189u8, 208u8, 176u8, 208u8, 187u8,
+                    208u8, 189u8, 208u8, 184u8, 32u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8,
+                    208u8, 181u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 208u8,
+                    184u8, 209u8, 129u8, 208u8, 187u8, 208u8, 176u8, 208u8, 188u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 184u8, 32u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8,
+                    208u8, 181u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 208u8,
+                    184u8, 209u8, 129u8, 208u8, 187u8, 208u8, 176u8, 208u8, 188u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 184u8, 32u8, 209u8, 134u8, 208u8, 184u8, 208u8, 178u8,
+                    208u8, 184u8, 208u8, 187u8, 208u8, 189u8, 208u8, 184u8, 32u8, 208u8, 186u8,
+                    208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8, 189u8, 208u8, 180u8, 208u8,
+                    176u8, 209u8, 128u8, 208u8, 184u8, 209u8, 129u8, 208u8, 187u8, 208u8, 176u8,
+                    208u8, 188u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 208u8, 176u8,
+                    209u8, 129u8, 209u8, 130u8, 209u8, 128u8, 208u8, 190u8, 208u8, 189u8, 208u8,
+                    190u8, 208u8, 188u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 208u8,
+                    186u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8, 189u8, 208u8, 180u8,
+                    208u8, 176u8, 209u8, 128u8, 208u8, 184u8, 209u8, 129u8, 208u8, 187u8, 208u8,
+                    176u8, 208u8, 188u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 208u8,
+                    186u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8, 189u8, 208u8, 180u8,
+                    208u8, 176u8, 209u8, 128u8, 32u8, 40u8, 85u8, 109u8, 109u8, 32u8, 97u8, 108u8,
+                    45u8, 81u8, 117u8, 114u8, 97u8, 41u8, 73u8, 83u8, 79u8, 45u8, 56u8, 54u8, 48u8,
+                    49u8, 32u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8,
+                    189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 209u8, 152u8, 208u8, 176u8,
+                    208u8, 191u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                    184u8, 32u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8,
+                    189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 208u8, 191u8, 208u8, 181u8,
+                    209u8, 128u8, 209u8, 129u8, 208u8, 184u8, 209u8, 152u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 184u8, 32u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8, 208u8,
+                    181u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 208u8, 186u8,
+                    208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8, 189u8, 208u8, 180u8, 208u8,
+                    176u8, 209u8, 128u8, 32u8, 208u8, 160u8, 208u8, 181u8, 208u8, 191u8, 209u8,
+                    131u8, 208u8, 177u8, 208u8, 187u8, 208u8, 184u8, 208u8, 186u8, 208u8, 181u8,
+                    32u8, 208u8, 154u8, 208u8, 184u8, 208u8, 189u8, 208u8, 181u8,
+                ])
+            },
+        )
+    },
+};
+static TH: &DataStruct = &::icu_displaynames::provider::CalendarDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    18u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 15u8, 0u8, 21u8, 0u8, 26u8, 0u8, 33u8,
+                    0u8, 41u8, 0u8, 48u8, 0u8, 54u8, 0u8, 60u8, 0u8, 67u8, 0u8, 80u8, 0u8, 92u8,
+                    0u8, 104u8, 0u8, 120u8, 0u8, 127u8, 0u8, 135u8, 0u8, 142u8, 0u8, 98u8, 117u8,
+                    100u8, 100u8, 104u8, 105u8, 115u8, 116u8, 99u8, 104u8, 105u8, 110u8, 101u8,
+                    115u8, 101u8, 99u8, 111u8, 112u8, 116u8, 105u8, 99u8, 100u8, 97u8, 110u8,
+                    103u8, 105u8, 101u8, 116u8, 104u8, 105u8, 111u8, 97u8, 97u8, 101u8, 116u8,
+                    104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 103u8, 114u8, 101u8, 103u8, 111u8,
+                    114u8, 121u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 105u8, 110u8, 100u8,
+                    105u8, 97u8, 110u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 105u8,
+                    115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 99u8, 105u8, 118u8, 105u8, 108u8,
+                    105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 114u8, 103u8, 115u8, 97u8,
+                    105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 116u8, 98u8, 108u8, 97u8,
+                    105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 117u8, 109u8, 97u8, 108u8,
+                    113u8, 117u8, 114u8, 97u8, 105u8, 115u8, 111u8, 56u8, 54u8, 48u8, 49u8, 106u8,
+                    97u8, 112u8, 97u8, 110u8, 101u8, 115u8, 101u8, 112u8, 101u8, 114u8, 115u8,
+                    105u8, 97u8, 110u8, 114u8, 111u8, 99u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    18u8, 0u8, 0u8, 0u8, 0u8, 0u8, 30u8, 0u8, 57u8, 0u8, 93u8, 0u8, 129u8, 0u8,
+                    192u8, 0u8, 240u8, 0u8, 32u8, 1u8, 65u8, 1u8, 128u8, 1u8, 164u8, 1u8, 215u8,
+                    1u8, 95u8, 2u8, 201u8, 2u8, 20u8, 3u8, 47u8, 3u8, 86u8, 3u8, 131u8, 3u8, 224u8,
+                    184u8, 155u8, 224u8, 184u8, 143u8, 224u8, 184u8, 180u8, 224u8, 184u8, 151u8,
+                    224u8, 184u8, 180u8, 224u8, 184u8, 153u8, 224u8, 184u8, 158u8, 224u8, 184u8,
+                    184u8, 224u8, 184u8, 151u8, 224u8, 184u8, 152u8, 224u8, 184u8, 155u8, 224u8,
+                    184u8, 143u8, 224u8, 184u8, 180u8, 224u8, 184u8, 151u8, 224u8, 184u8, 180u8,
+                    224u8, 184u8, 153u8, 224u8, 184u8, 136u8, 224u8, 184u8, 181u8, 224u8, 184u8,
+                    153u8, 224u8, 184u8, 155u8, 224u8, 184u8, 143u8, 224u8, 184u8, 180u8, 224u8,
+                    184u8, 151u8, 224u8, 184u8, 180u8, 224u8, 184u8, 153u8, 224u8, 184u8, 132u8,
+                    224u8, 184u8, 173u8, 224u8, 184u8, 155u8, 224u8, 184u8, 149u8, 224u8, 184u8,
+                    180u8, 224u8, 184u8, 129u8, 224u8, 184u8, 155u8, 224u8, 184u8, 143u8, 224u8,
+                    184u8, 180u8, 224u8, 184u8, 151u8, 224u8, 184u8, 180u8, 224u8, 184u8, 153u8,
+                    224u8, 185u8, 128u8, 224u8, 184u8, 129u8, 224u8, 184u8, 178u8, 224u8, 184u8,
+                    171u8, 224u8, 184u8, 165u8, 224u8, 184u8, 181u8, 224u8, 184u8, 155u8, 224u8,
+                    184u8, 143u8, 224u8, 184u8, 180u8, 224u8, 184u8, 151u8, 224u8, 184u8, 180u8,
+                    224u8, 184u8, 153u8, 224u8, 184u8, 155u8, 224u8, 184u8, 181u8, 224u8, 185u8,
+                    130u8, 224u8, 184u8, 165u8, 224u8, 184u8, 129u8, 224u8, 185u8, 128u8, 224u8,
+                    184u8, 173u8, 224u8, 184u8, 152u8, 224u8, 184u8, 180u8, 224u8, 185u8, 130u8,
+                    224u8, 184u8, 173u8, 224u8, 185u8, 128u8, 224u8, 184u8, 155u8, 224u8, 184u8,
+                    181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 155u8, 224u8, 184u8, 143u8, 224u8,
+                    184u8, 180u8, 224u8, 184u8, 151u8, 224u8, 184u8, 180u8, 224u8, 184u8, 153u8,
+                    224u8, 185u8, 128u8, 224u8, 184u8, 173u8, 224u8, 184u8, 152u8, 224u8, 184u8,
+                    180u8, 224u8, 185u8, 130u8, 224u8, 184u8, 173u8, 224u8, 185u8, 128u8, 224u8,
+                    184u8, 155u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 155u8,
+                    224u8, 184u8, 143u8, 224u8, 184u8, 180u8, 224u8, 184u8, 151u8, 224u8, 184u8,
+                    180u8, 224u8, 184u8, 153u8, 224u8, 185u8, 128u8, 224u8, 184u8, 129u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 129u8, 224u8, 184u8, 173u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 163u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8,
+                    153u8, 224u8, 184u8, 155u8, 224u8, 184u8, 143u8, 224u8, 184u8, 180u8, 224u8,
+                    184u8, 151u8, 224u8, 184u8, 180u8, 224u8, 184u8, 153u8, 224u8, 184u8, 174u8,
+                    224u8, 184u8, 180u8, 224u8, 184u8, 154u8, 224u8, 184u8, 163u8, 224u8, 184u8,
+                    185u8, 224u8, 184u8, 155u8, 224u8, 184u8, 143u8, 224u8, 184u8, 180u8, 224u8,
+                    184u8, 151u8, 224u8, 184u8, 180u8, 224u8, 184u8, 153u8, 224u8, 185u8, 129u8,
+                    224u8, 184u8, 171u8, 224u8, 185u8, 136u8, 224u8, 184u8, 135u8, 224u8, 184u8,
+                    138u8, 224u8, 184u8, 178u8, 224u8, 184u8, 149u8, 224u8, 184u8, 180u8, 224u8,
+                    184u8, 173u8, 224u8, 184u8, 180u8, 224u8, 184u8, 153u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 148u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8,
+                    155u8, 224u8, 184u8, 143u8, 224u8, 184u8, 180u8, 224u8, 184u8, 151u8, 224u8,
+                    184u8, 180u8, 224u8, 184u8, 153u8, 224u8, 184u8, 173u8, 224u8, 184u8, 180u8,
+                    224u8, 184u8, 170u8, 224u8, 184u8, 165u8, 224u8, 184u8, 178u8, 224u8, 184u8,
+                    161u8, 224u8, 184u8, 155u8, 224u8, 184u8, 143u8, 224u8, 184u8, 180u8, 224u8,
+                    184u8, 151u8, 224u8, 184u8, 180u8, 224u8, 184u8, 153u8, 224u8, 184u8, 173u8,
+                    224u8, 184u8, 180u8, 224u8, 184u8, 170u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 161u8, 224u8, 184u8, 139u8, 224u8, 184u8, 181u8, 224u8,
+                    184u8, 167u8, 224u8, 184u8, 180u8, 224u8, 184u8, 165u8, 224u8, 184u8, 155u8,
+                    224u8, 184u8, 143u8, 224u8, 184u8, 180u8, 224u8, 184u8, 151u8, 224u8, 184u8,
+                    180u8, 224u8, 184u8, 153u8, 224u8, 184u8, 173u8, 224u8, 184u8, 180u8, 224u8,
+                    184u8, 170u8, 224u8, 184u8, 165u8, 224u8, 184u8, 178u8, 224u8, 184u8, 161u8,
+                    32u8, 40u8, 224u8, 184u8, 139u8, 224u8, 184u8, 178u8, 224u8, 184u8, 173u8,
+                    224u8, 184u8, 184u8, 224u8, 184u8, 148u8, 224u8, 184u8, 181u8, 224u8, 184u8,
+                    173u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 184u8, 176u8, 224u8,
+                    185u8, 128u8, 224u8, 184u8, 154u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8,
+                    32u8, 224u8, 185u8, 129u8, 224u8, 184u8, 154u8, 224u8, 184u8, 154u8, 224u8,
+                    184u8, 158u8, 224u8, 184u8, 163u8, 224u8, 184u8, 176u8, 224u8, 184u8, 136u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 153u8, 224u8, 184u8, 151u8, 224u8, 184u8,
+                    163u8, 224u8, 185u8, 140u8, 224u8, 185u8, 128u8, 224u8, 184u8, 170u8, 224u8,
+                    184u8, 181u8, 224u8, 185u8, 137u8, 224u8, 184u8, 162u8, 224u8, 184u8, 167u8,
+                    41u8, 224u8, 184u8, 155u8, 224u8, 184u8, 143u8, 224u8, 184u8, 180u8, 224u8,
+                    184u8, 151u8, 224u8, 184u8, 180u8, 224u8, 184u8, 153u8, 224u8, 184u8, 173u8,
+                    224u8, 184u8, 180u8, 224u8, 184u8, 170u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 161u8, 32u8, 40u8, 224u8, 185u8, 129u8, 224u8, 184u8,
+                    154u8, 224u8, 184u8, 154u8, 224u8, 184u8, 149u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 178u8, 224u8, 184u8, 135u8, 32u8, 224u8, 184u8,
+                    170u8, 224u8, 184u8, 161u8, 224u8, 184u8, 177u8, 224u8, 184u8, 162u8, 224u8,
+                    184u8, 148u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 168u8, 224u8, 184u8, 178u8, 224u8, 184u8, 170u8, 224u8, 184u8,
+                    149u8, 224u8, 184u8, 163u8, 224u8, 185u8, 140u8, 41u8, 224u8, 184u8, 155u8,
+                    224u8, 184u8, 143u8, 224u8, 184u8, 180u8, 224u8, 184u8, 151u8, 224u8, 184u8,
+                    180u8, 224u8, 184u8, 153u8, 224u8, 184u8, 173u8, 224u8, 184u8, 180u8, 224u8,
+                    184u8, 170u8, 224u8, 184u8, 165u8, 224u8, 184u8, 178u8, 224u8, 184u8, 161u8,
+                    32u8, 40u8, 224u8, 184u8, 173u8, 224u8, 184u8, 184u8, 224u8, 184u8, 161u8,
+                    224u8, 184u8, 161u8, 224u8, 185u8, 140u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                    177u8, 224u8, 184u8, 165u8, 224u8, 184u8, 129u8, 224u8, 184u8, 184u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 178u8, 41u8, 224u8, 184u8, 155u8, 224u8, 184u8,
+                    143u8, 224u8, 184u8, 180u8, 224u8, 184u8, 151u8, 224u8, 184u8, 180u8, 224u8,
+                    184u8, 153u8, 32u8, 73u8, 83u8, 79u8, 45u8, 56u8, 54u8, 48u8, 49u8, 224u8,
+                    184u8, 155u8, 224u8, 184u8, 143u8, 224u8, 184u8, 180u8, 224u8, 184u8, 151u8,
+                    224u8, 184u8, 180u8, 224u8, 184u8, 153u8, 224u8, 184u8, 141u8, 224u8, 184u8,
+                    181u8, 224u8, 185u8, 136u8, 224u8, 184u8, 155u8, 224u8, 184u8, 184u8, 224u8,
+                    185u8, 136u8, 224u8, 184u8, 153u8, 224u8, 184u8, 155u8, 224u8, 184u8, 143u8,
+                    224u8, 184u8, 180u8, 224u8, 184u8, 151u8, 224u8, 184u8, 180u8, 224u8, 184u8,
+                    153u8, 224u8, 185u8, 128u8, 224u8, 184u8, 155u8, 224u8, 184u8, 173u8, 224u8,
+                    184u8, 163u8, 224u8, 185u8, 140u8, 224u8, 185u8, 128u8, 224u8, 184u8, 138u8,
+                    224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 155u8, 224u8, 184u8,
+                    143u8, 224u8, 184u8, 180u8, 224u8, 184u8, 151u8, 224u8, 184u8, 180u8, 224u8,
+                    184u8, 153u8, 224u8, 185u8, 132u8, 224u8, 184u8, 149u8, 224u8, 185u8, 137u8,
+                    224u8, 184u8, 171u8, 224u8, 184u8, 167u8, 224u8, 184u8, 177u8, 224u8, 184u8,
+                    153u8,
+                ])
+            },
+        )
+    },
+};
+static TR: &DataStruct = &::icu_displaynames::provider::CalendarDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    18u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 15u8, 0u8, 21u8, 0u8, 26u8, 0u8, 33u8,
+                    0u8, 41u8, 0u8, 48u8, 0u8, 54u8, 0u8, 60u8, 0u8, 67u8, 0u8, 80u8, 0u8, 92u8,
+                    0u8, 104u8, 0u8, 120u8, 0u8, 127u8, 0u8, 135u8, 0u8, 142u8, 0u8, 98u8, 117u8,
+                    100u8, 100u8, 104u8, 105u8, 115u8, 116u8, 99u8, 104u8, 105u8, 110u8, 101u8,
+                    115u8, 101u8, 99u8, 111u8, 112u8, 116u8, 105u8, 99u8, 100u8, 97u8, 110u8,
+                    103u8, 105u8, 101u8, 116u8, 104u8, 105u8, 111u8, 97u8, 97u8, 101u8, 116u8,
+                    104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 103u8, 114u8, 101u8, 103u8, 111u8,
+                    114u8, 121u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 105u8, 110u8, 100u8,
+                    105u8, 97u8, 110u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 105u8,
+                    115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 99u8, 105u8, 118u8, 105u8, 108u8,
+                    105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 114u8, 103u8, 115u8, 97u8,
+                    105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 116u8, 98u8, 108u8, 97u8,
+                    105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 117u8, 109u8, 97u8, 108u8,
+                    113u8, 117u8, 114u8, 97u8, 105u8, 115u8, 111u8, 56u8, 54u8, 48u8, 49u8, 106u8,
+                    97u8, 112u8, 97u8, 110u8, 101u8, 115u8, 101u8, 112u8, 101u8, 114u8, 115u8,
+                    105u8, 97u8, 110u8, 114u8, 111u8, 99u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    18u8, 0u8, 0u8, 0u8, 0u8, 0u8, 14u8, 0u8, 26u8, 0u8, 39u8, 0u8, 52u8, 0u8,
+                    79u8, 0u8, 94u8, 0u8, 107u8, 0u8, 122u8, 0u8, 141u8, 0u8, 153u8, 0u8, 165u8,
+                    0u8, 185u8, 0u8, 218u8, 0u8, 255u8, 0u8, 15u8, 1u8, 28u8, 1u8, 41u8, 1u8, 66u8,
+                    117u8, 100u8, 105u8, 115u8, 116u8, 32u8, 84u8, 97u8, 107u8, 118u8, 105u8,
+                    109u8, 105u8, 195u8, 135u8, 105u8, 110u8, 32u8, 84u8, 97u8, 107u8, 118u8,
+                    105u8, 109u8, 105u8, 75u8, 196u8, 177u8, 112u8, 116u8, 105u8, 32u8, 84u8, 97u8,
+                    107u8, 118u8, 105u8, 109u8, 68u8, 97u8, 110u8, 103u8, 105u8, 32u8, 84u8, 97u8,
+                    107u8, 118u8, 105u8, 109u8, 105u8, 69u8, 116u8, 105u8, 121u8, 111u8, 112u8,
+                    105u8, 107u8, 32u8, 65u8, 109u8, 101u8, 116u8, 101u8, 32u8, 65u8, 108u8, 101u8,
+                    109u8, 32u8, 84u8, 97u8, 107u8, 118u8, 105u8, 109u8, 105u8, 69u8, 116u8, 105u8,
+                    121u8, 111u8, 112u8, 105u8, 107u8, 32u8, 84u8, 97u8, 107u8, 118u8, 105u8,
+                    109u8, 77u8, 105u8, 108u8, 97u8, 100u8, 105u8, 32u8, 84u8, 97u8, 107u8, 118u8,
+                    105u8, 109u8, 196u8, 176u8, 98u8, 114u8, 97u8, 110u8, 105u8, 32u8, 84u8, 97u8,
+                    107u8, 118u8, 105u8, 109u8, 105u8, 85u8, 108u8, 117u8, 115u8, 97u8, 108u8,
+                    32u8, 72u8, 105u8, 110u8, 116u8, 32u8, 84u8, 97u8, 107u8, 118u8, 105u8, 109u8,
+                    105u8, 72u8, 105u8, 99u8, 114u8, 105u8, 32u8, 84u8, 97u8, 107u8, 118u8, 105u8,
+                    109u8, 65u8, 114u8, 97u8, 112u8, 32u8, 84u8, 97u8, 107u8, 118u8, 105u8, 109u8,
+                    105u8, 72u8, 105u8, 99u8, 114u8, 105u8, 32u8, 84u8, 97u8, 107u8, 118u8, 105u8,
+                    109u8, 32u8, 40u8, 83u8, 117u8, 117u8, 100u8, 105u8, 41u8, 72u8, 105u8, 99u8,
+                    114u8, 105u8, 32u8, 84u8, 97u8, 107u8, 118u8, 105u8, 109u8, 32u8, 40u8, 65u8,
+                    115u8, 116u8, 114u8, 111u8, 110u8, 111u8, 109u8, 105u8, 107u8, 32u8, 69u8,
+                    118u8, 114u8, 101u8, 108u8, 101u8, 114u8, 41u8, 72u8, 105u8, 99u8, 114u8,
+                    105u8, 32u8, 84u8, 97u8, 107u8, 118u8, 105u8, 109u8, 32u8, 40u8, 195u8, 156u8,
+                    109u8, 109u8, 195u8, 188u8, 45u8, 108u8, 32u8, 75u8, 117u8, 114u8, 114u8, 97u8,
+                    32u8, 84u8, 97u8, 107u8, 118u8, 105u8, 109u8, 105u8, 41u8, 73u8, 83u8, 79u8,
+                    45u8, 56u8, 54u8, 48u8, 49u8, 32u8, 84u8, 97u8, 107u8, 118u8, 105u8, 109u8,
+                    105u8, 74u8, 97u8, 112u8, 111u8, 110u8, 32u8, 84u8, 97u8, 107u8, 118u8, 105u8,
+                    109u8, 105u8, 196u8, 176u8, 114u8, 97u8, 110u8, 32u8, 84u8, 97u8, 107u8, 118u8,
+                    105u8, 109u8, 105u8, 195u8, 135u8, 105u8, 110u8, 32u8, 67u8, 117u8, 109u8,
+                    104u8, 117u8, 114u8, 105u8, 121u8, 101u8, 116u8, 105u8, 32u8, 84u8, 97u8,
+                    107u8, 118u8, 105u8, 109u8, 105u8,
+                ])
+            },
+        )
+    },
+};
+static UND: &DataStruct = &::icu_displaynames::provider::CalendarDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+};
@@ -0,0 +1,9191 @@
+// @generated
+#![cfg(feature = "icu_displaynames")]
+type DataStruct =
+    <::icu_displaynames::provider::KeyDisplayNamesV1Marker as ::icu_provider::DataMarker>::Yokeable;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[
+        ("ar", AR_AR_EG),
+        ("ar-EG", AR_AR_EG),
+        ("bn", BN),
+        ("ccp", CCP),
+        ("en", EN),
+        ("en-001", EN_001_EN_ZA),
+        ("en-ZA", EN_001_EN_ZA),
+        ("es", ES),
+        ("es-AR", ES_AR),
+        ("fil", FIL),
+        ("fr", FR),
+        ("ja", JA),
+        ("ru", RU),
+        ("sr", SR_SR_CYRL),
+        ("sr-Cyrl", SR_SR_CYRL),
+        ("sr-Latn", SR_LATN),
+        ("th", TH),
+        ("tr", TR),
+        ("und", UND),
+    ]);
+static AR_AR_EG: &DataStruct = &::icu_displaynames::provider::KeyDisplayNamesV1 {
+    keys: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    99u8, 97u8, 99u8, 102u8, 99u8, 111u8, 99u8, 117u8, 104u8, 99u8, 107u8, 97u8,
+                    107u8, 98u8, 107u8, 99u8, 107u8, 102u8, 107u8, 107u8, 107u8, 110u8, 107u8,
+                    115u8, 108u8, 98u8, 109u8, 115u8, 110u8, 117u8, 116u8, 122u8, 118u8, 97u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    17u8, 0u8, 0u8, 0u8, 0u8, 0u8, 14u8, 0u8, 37u8, 0u8, 58u8, 0u8, 70u8, 0u8,
+                    112u8, 0u8, 155u8, 0u8, 204u8, 0u8, 2u8, 1u8, 68u8, 1u8, 91u8, 1u8, 114u8, 1u8,
+                    131u8, 1u8, 157u8, 1u8, 178u8, 1u8, 192u8, 1u8, 221u8, 1u8, 216u8, 167u8,
+                    217u8, 132u8, 216u8, 170u8, 217u8, 130u8, 217u8, 136u8, 217u8, 138u8, 217u8,
+                    133u8, 216u8, 170u8, 217u8, 134u8, 216u8, 179u8, 217u8, 138u8, 217u8, 130u8,
+                    32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 185u8, 217u8, 133u8, 217u8, 132u8,
+                    216u8, 169u8, 216u8, 170u8, 216u8, 177u8, 216u8, 170u8, 217u8, 138u8, 216u8,
+                    168u8, 32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 129u8, 216u8, 177u8, 216u8,
+                    178u8, 216u8, 167u8, 217u8, 132u8, 216u8, 185u8, 217u8, 133u8, 217u8, 132u8,
+                    216u8, 169u8, 217u8, 134u8, 216u8, 184u8, 216u8, 167u8, 217u8, 133u8, 32u8,
+                    216u8, 167u8, 217u8, 132u8, 216u8, 170u8, 217u8, 136u8, 217u8, 130u8, 217u8,
+                    138u8, 216u8, 170u8, 32u8, 40u8, 49u8, 50u8, 32u8, 217u8, 133u8, 217u8, 130u8,
+                    216u8, 167u8, 216u8, 168u8, 217u8, 132u8, 32u8, 50u8, 52u8, 41u8, 216u8, 167u8,
+                    217u8, 132u8, 217u8, 129u8, 216u8, 177u8, 216u8, 178u8, 32u8, 216u8, 168u8,
+                    216u8, 173u8, 216u8, 179u8, 216u8, 168u8, 32u8, 216u8, 170u8, 216u8, 172u8,
+                    216u8, 167u8, 217u8, 135u8, 217u8, 132u8, 32u8, 216u8, 167u8, 217u8, 132u8,
+                    216u8, 177u8, 217u8, 133u8, 217u8, 136u8, 216u8, 178u8, 216u8, 167u8, 217u8,
+                    132u8, 217u8, 129u8, 216u8, 177u8, 216u8, 178u8, 32u8, 216u8, 168u8, 216u8,
+                    173u8, 216u8, 179u8, 216u8, 168u8, 32u8, 216u8, 167u8, 217u8, 132u8, 217u8,
+                    132u8, 217u8, 131u8, 217u8, 134u8, 216u8, 169u8, 32u8, 216u8, 167u8, 217u8,
+                    132u8, 217u8, 133u8, 216u8, 185u8, 217u8, 131u8, 217u8, 136u8, 216u8, 179u8,
+                    216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 217u8, 129u8, 216u8, 177u8, 216u8,
+                    178u8, 32u8, 216u8, 168u8, 216u8, 173u8, 216u8, 179u8, 216u8, 168u8, 32u8,
+                    216u8, 173u8, 216u8, 179u8, 216u8, 167u8, 216u8, 179u8, 217u8, 138u8, 216u8,
+                    169u8, 32u8, 216u8, 173u8, 216u8, 167u8, 217u8, 132u8, 216u8, 169u8, 32u8,
+                    216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 216u8, 173u8, 216u8, 177u8, 217u8,
+                    129u8, 216u8, 167u8, 217u8, 132u8, 216u8, 170u8, 216u8, 177u8, 216u8, 170u8,
+                    217u8, 138u8, 216u8, 168u8, 32u8, 216u8, 168u8, 216u8, 173u8, 216u8, 179u8,
+                    216u8, 168u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 216u8, 173u8,
+                    216u8, 177u8, 217u8, 129u8, 32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 131u8,
+                    216u8, 168u8, 217u8, 138u8, 216u8, 177u8, 216u8, 169u8, 47u8, 216u8, 167u8,
+                    217u8, 132u8, 216u8, 181u8, 216u8, 186u8, 217u8, 138u8, 216u8, 177u8, 216u8,
+                    169u8, 216u8, 167u8, 217u8, 132u8, 217u8, 129u8, 216u8, 177u8, 216u8, 178u8,
+                    32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 133u8, 217u8, 136u8, 216u8, 173u8,
+                    216u8, 175u8, 216u8, 167u8, 217u8, 132u8, 217u8, 129u8, 216u8, 177u8, 216u8,
+                    178u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 177u8, 217u8, 130u8, 217u8,
+                    133u8, 217u8, 138u8, 217u8, 130u8, 217u8, 136u8, 216u8, 169u8, 32u8, 216u8,
+                    167u8, 217u8, 132u8, 217u8, 129u8, 216u8, 177u8, 216u8, 178u8, 217u8, 134u8,
+                    217u8, 133u8, 216u8, 183u8, 32u8, 217u8, 129u8, 216u8, 181u8, 217u8, 132u8,
+                    32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 179u8, 216u8, 183u8, 217u8, 136u8,
+                    216u8, 177u8, 217u8, 134u8, 216u8, 184u8, 216u8, 167u8, 217u8, 133u8, 32u8,
+                    216u8, 167u8, 217u8, 132u8, 217u8, 130u8, 217u8, 138u8, 216u8, 167u8, 216u8,
+                    179u8, 216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 216u8, 177u8, 217u8, 130u8,
+                    216u8, 167u8, 217u8, 133u8, 216u8, 167u8, 217u8, 132u8, 217u8, 133u8, 217u8,
+                    134u8, 216u8, 183u8, 217u8, 130u8, 216u8, 169u8, 32u8, 216u8, 167u8, 217u8,
+                    132u8, 216u8, 178u8, 217u8, 133u8, 217u8, 134u8, 217u8, 138u8, 216u8, 169u8,
+                    217u8, 133u8, 216u8, 170u8, 216u8, 186u8, 217u8, 138u8, 216u8, 177u8, 216u8,
+                    167u8, 216u8, 170u8, 32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 132u8, 216u8,
+                    186u8, 216u8, 169u8,
+                ])
+            },
+        )
+    },
+    types: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    108u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 21u8, 0u8, 30u8, 0u8, 38u8, 0u8,
+                    48u8, 0u8, 59u8, 0u8, 69u8, 0u8, 78u8, 0u8, 87u8, 0u8, 97u8, 0u8, 113u8, 0u8,
+                    128u8, 0u8, 143u8, 0u8, 162u8, 0u8, 172u8, 0u8, 183u8, 0u8, 193u8, 0u8, 199u8,
+                    0u8, 209u8, 0u8, 220u8, 0u8, 230u8, 0u8, 239u8, 0u8, 246u8, 0u8, 254u8, 0u8,
+                    7u8, 1u8, 17u8, 1u8, 28u8, 1u8, 37u8, 1u8, 48u8, 1u8, 57u8, 1u8, 68u8, 1u8,
+                    79u8, 1u8, 88u8, 1u8, 95u8, 1u8, 104u8, 1u8, 113u8, 1u8, 119u8, 1u8, 125u8,
+                    1u8, 131u8, 1u8, 137u8, 1u8, 148u8, 1u8, 158u8, 1u8, 166u8, 1u8, 173u8, 1u8,
+                    181u8, 1u8, 188u8, 1u8, 196u8, 1u8, 204u8, 1u8, 212u8, 1u8, 220u8, 1u8, 227u8,
+                    1u8, 235u8, 1u8, 242u8, 1u8, 252u8, 1u8, 5u8, 2u8, 14u8, 2u8, 23u8, 2u8, 32u8,
+                    2u8, 40u8, 2u8, 49u8, 2u8, 58u8, 2u8, 67u8, 2u8, 78u8, 2u8, 89u8, 2u8, 96u8,
+                    2u8, 106u8, 2u8, 113u8, 2u8, 123u8, 2u8, 130u8, 2u8, 137u8, 2u8, 144u8, 2u8,
+                    151u8, 2u8, 161u8, 2u8, 172u8, 2u8, 179u8, 2u8, 186u8, 2u8, 196u8, 2u8, 203u8,
+                    2u8, 210u8, 2u8, 220u8, 2u8, 227u8, 2u8, 237u8, 2u8, 244u8, 2u8, 254u8, 2u8,
+                    5u8, 3u8, 12u8, 3u8, 19u8, 3u8, 29u8, 3u8, 36u8, 3u8, 43u8, 3u8, 50u8, 3u8,
+                    57u8, 3u8, 64u8, 3u8, 71u8, 3u8, 78u8, 3u8, 85u8, 3u8, 94u8, 3u8, 101u8, 3u8,
+                    108u8, 3u8, 116u8, 3u8, 127u8, 3u8, 134u8, 3u8, 144u8, 3u8, 151u8, 3u8, 158u8,
+                    3u8, 165u8, 3u8, 176u8, 3u8, 99u8, 97u8, 45u8, 98u8, 117u8, 100u8, 100u8,
+                    104u8, 105u8, 115u8, 116u8, 99u8, 97u8, 45u8, 99u8, 104u8, 105u8, 110u8, 101u8,
+                    115u8, 101u8, 99u8, 97u8, 45u8, 99u8, 111u8, 112u8, 116u8, 105u8, 99u8, 99u8,
+                    97u8, 45u8, 100u8, 97u8, 110u8, 103u8, 105u8, 99u8, 97u8, 45u8, 101u8, 116u8,
+                    104u8, 105u8, 111u8, 97u8, 97u8, 99u8, 97u8, 45u8, 101u8, 116u8, 104u8, 105u8,
+                    111u8, 112u8, 105u8, 99u8, 99u8, 97u8, 45u8, 103u8, 114u8, 101u8, 103u8, 111u8,
+                    114u8, 121u8, 99u8, 97u8, 45u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 99u8,
+                    97u8, 45u8, 105u8, 110u8, 100u8, 105u8, 97u8, 110u8, 99u8, 97u8, 45u8, 105u8,
+                    115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 99u8, 97u8, 45u8, 105u8, 115u8, 108u8,
+                    97u8, 109u8, 105u8, 99u8, 45u8, 99u8, 105u8, 118u8, 105u8, 108u8, 99u8, 97u8,
+                    45u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 114u8, 103u8, 115u8,
+                    97u8, 99u8, 97u8, 45u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8,
+                    116u8, 98u8, 108u8, 97u8, 99u8, 97u8, 45u8, 105u8, 115u8, 108u8, 97u8, 109u8,
+                    105u8, 99u8, 45u8, 117u8, 109u8, 97u8, 108u8, 113u8, 117u8, 114u8, 97u8, 99u8,
+                    97u8, 45u8, 105u8, 115u8, 111u8, 56u8, 54u8, 48u8, 49u8, 99u8, 97u8, 45u8,
+                    106u8, 97u8, 112u8, 97u8, 110u8, 101u8, 115u8, 101u8, 99u8, 97u8, 45u8, 112u8,
+                    101u8, 114u8, 115u8, 105u8, 97u8, 110u8, 99u8, 97u8, 45u8, 114u8, 111u8, 99u8,
+                    99u8, 102u8, 45u8, 97u8, 99u8, 99u8, 111u8, 117u8, 110u8, 116u8, 99u8, 102u8,
+                    45u8, 115u8, 116u8, 97u8, 110u8, 100u8, 97u8, 114u8, 100u8, 99u8, 111u8, 45u8,
+                    98u8, 105u8, 103u8, 53u8, 104u8, 97u8, 110u8, 99u8, 111u8, 45u8, 99u8, 111u8,
+                    109u8, 112u8, 97u8, 116u8, 99u8, 111u8, 45u8, 100u8, 105u8, 99u8, 116u8, 99u8,
+                    111u8, 45u8, 100u8, 117u8, 99u8, 101u8, 116u8, 99u8, 111u8, 45u8, 103u8, 98u8,
+                    50u8, 51u8, 49u8, 50u8, 99u8, 111u8, 45u8, 112u8, 104u8, 111u8, 110u8, 101u8,
+                    98u8, 107u8, 99u8, 111u8, 45u8, 112u8, 104u8, 111u8, 110u8, 101u8, 116u8,
+                    105u8, 99u8, 99u8, 111u8, 45u8, 112u8, 105u8, 110u8, 121u8, 105u8, 110u8, 99u8,
+                    111u8, 45u8, 114u8, 101u8, 102u8, 111u8, 114u8, 109u8, 101u8, 100u8, 99u8,
+                    111u8, 45u8, 115u8, 101u8, 97u8, 114u8, 99u8, 104u8, 99u8, 111u8, 45u8, 115u8,
+                    101u8, 97u8, 114u8, 99u8, 104u8, 106u8, 108u8, 99u8, 111u8, 45u8, 115u8, 116u8,
+                    97u8, 110u8, 100u8, 97u8, 114u8, 100u8, 99u8, 111u8, 45u8, 115u8, 116u8, 114u8,
+                    111u8, 107u8, 101u8, 99u8, 111u8, 45u8, 116u8, 114u8, 97u8, 100u8, 99u8, 111u8,
+                    45u8, 117u8, 110u8, 105u8, 104u8, 97u8, 110u8, 99u8, 111u8, 45u8, 122u8, 104u8,
+                    117u8, 121u8, 105u8, 110u8, 104u8, 99u8, 45u8, 104u8, 49u8, 49u8, 104u8, 99u8,
+                    45u8, 104u8, 49u8, 50u8, 104u8, 99u8, 45u8, 104u8, 50u8, 51u8, 104u8, 99u8,
+                    45u8, 104u8, 50u8, 52u8, 107u8, 97u8, 45u8, 110u8, 111u8, 105u8, 103u8, 110u8,
+                    111u8, 114u8, 101u8, 107u8, 97u8, 45u8, 115u8, 104u8, 105u8, 102u8, 116u8,
+                    101u8, 100u8, 107u8, 98u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8, 107u8, 98u8,
+                    45u8, 116u8, 114u8, 117u8, 101u8, 107u8, 99u8, 45u8, 102u8, 97u8, 108u8, 115u8,
+                    101u8, 107u8, 99u8, 45u8, 116u8, 114u8, 117u8, 101u8, 107u8, 102u8, 45u8,
+                    102u8, 97u8, 108u8, 115u8, 101u8, 107u8, 102u8, 45u8, 108u8, 111u8, 119u8,
+                    101u8, 114u8, 107u8, 102u8, 45u8, 117u8, 112u8, 112u8, 101u8, 114u8, 107u8,
+                    107u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8, 107u8, 107u8, 45u8, 116u8,
+                    114u8, 117u8, 101u8, 107u8, 110u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8,
+                    107u8, 110u8, 45u8, 116u8, 114u8, 117u8, 101u8, 107u8, 115u8, 45u8, 105u8,
+                    100u8, 101u8, 110u8, 116u8, 105u8, 99u8, 107u8, 115u8, 45u8, 108u8, 101u8,
+                    118u8, 101u8, 108u8, 49u8, 107u8, 115u8, 45u8, 108u8, 101u8, 118u8, 101u8,
+                    108u8, 50u8, 107u8, 115u8, 45u8, 108u8, 101u8, 118u8, 101u8, 108u8, 51u8,
+                    107u8, 115u8, 45u8, 108u8, 101u8, 118u8, 101u8, 108u8, 52u8, 108u8, 98u8, 45u8,
+                    108u8, 111u8, 111u8, 115u8, 101u8, 108u8, 98u8, 45u8, 110u8, 111u8, 114u8,
+                    109u8, 97u8, 108u8, 108u8, 98u8, 45u8, 115u8, 116u8, 114u8, 105u8, 99u8, 116u8,
+                    109u8, 115u8, 45u8, 109u8, 101u8, 116u8, 114u8, 105u8, 99u8, 109u8, 115u8,
+                    45u8, 117u8, 107u8, 115u8, 121u8, 115u8, 116u8, 101u8, 109u8, 109u8, 115u8,
+                    45u8, 117u8, 115u8, 115u8, 121u8, 115u8, 116u8, 101u8, 109u8, 110u8, 117u8,
+                    45u8, 97u8, 114u8, 97u8, 98u8, 110u8, 117u8, 45u8, 97u8, 114u8, 97u8, 98u8,
+                    101u8, 120u8, 116u8, 110u8, 117u8, 45u8, 97u8, 114u8, 109u8, 110u8, 110u8,
+                    117u8, 45u8, 97u8, 114u8, 109u8, 110u8, 108u8, 111u8, 119u8, 110u8, 117u8,
+                    45u8, 98u8, 101u8, 110u8, 103u8, 110u8, 117u8, 45u8, 99u8, 97u8, 107u8, 109u8,
+                    110u8, 117u8, 45u8, 100u8, 101u8, 118u8, 97u8, 110u8, 117u8, 45u8, 101u8,
+                    116u8, 104u8, 105u8, 110u8, 117u8, 45u8, 102u8, 105u8, 110u8, 97u8, 110u8,
+                    99u8, 101u8, 110u8, 117u8, 45u8, 102u8, 117u8, 108u8, 108u8, 119u8, 105u8,
+                    100u8, 101u8, 110u8, 117u8, 45u8, 103u8, 101u8, 111u8, 114u8, 110u8, 117u8,
+                    45u8, 103u8, 114u8, 101u8, 107u8, 110u8, 117u8, 45u8, 103u8, 114u8, 101u8,
+                    107u8, 108u8, 111u8, 119u8, 110u8, 117u8, 45u8, 103u8, 117u8, 106u8, 114u8,
+                    110u8, 117u8, 45u8, 103u8, 117u8, 114u8, 117u8, 110u8, 117u8, 45u8, 104u8,
+                    97u8, 110u8, 105u8, 100u8, 101u8, 99u8, 110u8, 117u8, 45u8, 104u8, 97u8, 110u8,
+                    115u8, 110u8, 117u8, 45u8, 104u8, 97u8, 110u8, 115u8, 102u8, 105u8, 110u8,
+                    110u8, 117u8, 45u8, 104u8, 97u8, 110u8, 116u8, 110u8, 117u8, 45u8, 104u8, 97u8,
+                    110u8, 116u8, 102u8, 105u8, 110u8, 110u8, 117u8, 45u8, 104u8, 101u8, 98u8,
+                    114u8, 110u8, 117u8, 45u8, 106u8, 97u8, 118u8, 97u8, 110u8, 117u8, 45u8, 106u8,
+                    112u8, 97u8, 110u8, 110u8, 117u8, 45u8, 106u8, 112u8, 97u8, 110u8, 102u8,
+                    105u8, 110u8, 110u8, 117u8, 45u8, 107u8, 104u8, 109u8, 114u8, 110u8, 117u8,
+                    45u8, 107u8, 110u8, 100u8, 97u8, 110u8, 117u8, 45u8, 108u8, 97u8, 111u8, 111u8,
+                    110u8, 117u8, 45u8, 108u8, 97u8, 116u8, 110u8, 110u8, 117u8, 45u8, 109u8,
+                    108u8, 121u8, 109u8, 110u8, 117u8, 45u8, 109u8, 111u8, 110u8, 103u8, 110u8,
+                    117u8, 45u8, 109u8, 116u8, 101u8, 105u8, 110u8, 117u8, 45u8, 109u8, 121u8,
+                    109u8, 114u8, 110u8, 117u8, 45u8, 110u8, 97u8, 116u8, 105u8, 118u8, 101u8,
+                    110u8, 117u8, 45u8, 111u8, 108u8, 99u8, 107u8, 110u8, 117u8, 45u8, 111u8,
+                    114u8, 121u8, 97u8, 110u8, 117u8, 45u8, 114u8, 111u8, 109u8, 97u8, 110u8,
+                    110u8, 117u8, 45u8, 114u8, 111u8, 109u8, 97u8, 110u8, 108u8, 111u8, 119u8,
+                    110u8, 117u8, 45u8, 116u8, 97u8, 109u8, 108u8, 110u8, 117u8, 45u8, 116u8, 97u8,
+                    109u8, 108u8, 100u8, 101u8, 99u8, 110u8, 117u8, 45u8, 116u8, 101u8, 108u8,
+                    117u8, 110u8, 117u8, 45u8, 116u8, 104u8, 97u8, 105u8, 110u8, 117u8, 45u8,
+                    116u8, 105u8, 98u8, 116u8, 110u8, 117u8, 45u8, 116u8, 114u8, 97u8, 100u8,
+                    105u8, 116u8, 105u8, 111u8, 110u8, 117u8, 45u8, 118u8, 97u8, 105u8, 105u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    108u8, 0u8, 0u8, 0u8, 0u8, 0u8, 27u8, 0u8, 54u8, 0u8, 81u8, 0u8, 102u8, 0u8,
+                    149u8, 0u8, 180u8, 0u8, 211u8, 0u8, 238u8, 0u8, 22u8, 1u8, 49u8, 1u8, 93u8,
+                    1u8, 158u8, 1u8, 223u8, 1u8, 16u8, 2u8, 35u8, 2u8, 66u8, 2u8, 95u8, 2u8, 116u8,
+                    2u8, 156u8, 2u8, 194u8, 2u8, 11u8, 3u8, 61u8, 3u8, 97u8, 3u8, 141u8, 3u8,
+                    214u8, 3u8, 1u8, 4u8, 33u8, 4u8, 87u8, 4u8, 118u8, 4u8, 146u8, 4u8, 217u8, 4u8,
+                    253u8, 4u8, 66u8, 5u8, 89u8, 5u8, 164u8, 5u8, 211u8, 5u8, 240u8, 5u8, 13u8,
+                    6u8, 42u8, 6u8, 71u8, 6u8, 94u8, 6u8, 128u8, 6u8, 171u8, 6u8, 209u8, 6u8, 31u8,
+                    7u8, 76u8, 7u8, 136u8, 7u8, 185u8, 7u8, 234u8, 7u8, 12u8, 8u8, 41u8, 8u8, 80u8,
+                    8u8, 118u8, 8u8, 137u8, 8u8, 184u8, 8u8, 209u8, 8u8, 2u8, 9u8, 64u8, 9u8,
+                    104u8, 9u8, 140u8, 9u8, 180u8, 9u8, 205u8, 9u8, 245u8, 9u8, 27u8, 10u8, 71u8,
+                    10u8, 130u8, 10u8, 163u8, 10u8, 211u8, 10u8, 244u8, 10u8, 11u8, 11u8, 50u8,
+                    11u8, 83u8, 11u8, 112u8, 11u8, 144u8, 11u8, 175u8, 11u8, 208u8, 11u8, 0u8,
+                    12u8, 37u8, 12u8, 72u8, 12u8, 116u8, 12u8, 160u8, 12u8, 219u8, 12u8, 11u8,
+                    13u8, 74u8, 13u8, 103u8, 13u8, 132u8, 13u8, 165u8, 13u8, 213u8, 13u8, 244u8,
+                    13u8, 15u8, 14u8, 44u8, 14u8, 73u8, 14u8, 110u8, 14u8, 141u8, 14u8, 160u8,
+                    14u8, 185u8, 14u8, 214u8, 14u8, 244u8, 14u8, 13u8, 15u8, 46u8, 15u8, 94u8,
+                    15u8, 146u8, 15u8, 179u8, 15u8, 214u8, 15u8, 251u8, 15u8, 24u8, 16u8, 49u8,
+                    16u8, 216u8, 167u8, 217u8, 132u8, 216u8, 170u8, 217u8, 130u8, 217u8, 136u8,
+                    217u8, 138u8, 217u8, 133u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 168u8,
+                    217u8, 136u8, 216u8, 176u8, 217u8, 138u8, 216u8, 167u8, 217u8, 132u8, 216u8,
+                    170u8, 217u8, 130u8, 217u8, 136u8, 217u8, 138u8, 217u8, 133u8, 32u8, 216u8,
+                    167u8, 217u8, 132u8, 216u8, 181u8, 217u8, 138u8, 217u8, 134u8, 217u8, 138u8,
+                    216u8, 167u8, 217u8, 132u8, 216u8, 170u8, 217u8, 130u8, 217u8, 136u8, 217u8,
+                    138u8, 217u8, 133u8, 32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 130u8, 216u8,
+                    168u8, 216u8, 183u8, 217u8, 138u8, 216u8, 170u8, 217u8, 130u8, 217u8, 136u8,
+                    217u8, 138u8, 217u8, 133u8, 32u8, 216u8, 175u8, 216u8, 167u8, 217u8, 134u8,
+                    216u8, 172u8, 217u8, 138u8, 216u8, 170u8, 217u8, 130u8, 217u8, 136u8, 217u8,
+                    138u8, 217u8, 133u8, 32u8, 216u8, 163u8, 217u8, 133u8, 217u8, 138u8, 216u8,
+                    170u8, 217u8, 138u8, 32u8, 216u8, 163u8, 217u8, 132u8, 217u8, 138u8, 217u8,
+                    133u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 165u8, 216u8, 171u8, 217u8,
+                    138u8, 217u8, 136u8, 216u8, 168u8, 217u8, 138u8, 216u8, 167u8, 217u8, 132u8,
+                    216u8, 170u8, 217u8, 130u8, 217u8, 136u8, 217u8, 138u8, 217u8, 133u8, 32u8,
+                    216u8, 167u8, 217u8, 132u8, 216u8, 165u8, 216u8, 171u8, 217u8, 138u8, 217u8,
+                    136u8, 216u8, 168u8, 217u8, 138u8, 216u8, 167u8, 217u8, 132u8, 216u8, 170u8,
+                    217u8, 130u8, 217u8, 136u8, 217u8, 138u8, 217u8, 133u8, 32u8, 216u8, 167u8,
+                    217u8, 132u8, 217u8, 133u8, 217u8, 138u8, 217u8, 132u8, 216u8, 167u8, 216u8,
+                    175u8, 217u8, 138u8, 216u8, 167u8, 217u8, 132u8, 216u8, 170u8, 217u8, 130u8,
+                    217u8, 136u8, 217u8, 138u8, 217u8, 133u8, 32u8, 216u8, 167u8, 217u8, 132u8,
+                    216u8, 185u8, 216u8, 168u8, 216u8, 177u8, 217u8, 138u8, 216u8, 167u8, 217u8,
+                    132u8, 216u8, 170u8, 217u8, 130u8, 217u8, 136u8, 217u8, 138u8, 217u8, 133u8,
+                    32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 130u8, 217u8, 136u8, 217u8, 133u8,
+                    217u8, 138u8, 32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 135u8, 217u8, 134u8,
+                    216u8, 175u8, 217u8, 138u8, 216u8, 167u8, 217u8, 132u8, 216u8, 170u8, 217u8,
+                    130u8, 217u8, 136u8, 217u8, 138u8, 217u8, 133u8, 32u8, 216u8, 167u8, 217u8,
+                    132u8, 217u8, 135u8, 216u8, 172u8, 216u8, 177u8, 217u8, 138u8, 216u8, 167u8,
+                    217u8, 132u8, 216u8, 170u8, 217u8, 130u8, 217u8, 136u8, 217u8, 138u8, 217u8,
+                    133u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 165u8, 216u8, 179u8, 217u8,
+                    132u8, 216u8, 167u8, 217u8, 133u8, 217u8, 138u8, 32u8, 216u8, 167u8, 217u8,
+                    132u8, 217u8, 133u8, 216u8, 175u8, 217u8, 134u8, 217u8, 138u8, 216u8, 167u8,
+                    217u8, 132u8, 216u8, 170u8, 217u8, 130u8, 217u8, 136u8, 217u8, 138u8, 217u8,
+                    133u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 165u8, 216u8, 179u8, 217u8,
+                    132u8, 216u8, 167u8, 217u8, 133u8, 217u8, 138u8, 32u8, 40u8, 216u8, 167u8,
+                    217u8, 132u8, 216u8, 179u8, 216u8, 185u8, 217u8, 136u8, 216u8, 175u8, 217u8,
+                    138u8, 216u8, 169u8, 32u8, 45u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8,
+                    177u8, 216u8, 164u8, 217u8, 138u8, 216u8, 169u8, 41u8, 216u8, 167u8, 217u8,
+                    132u8, 216u8, 170u8, 217u8, 130u8, 217u8, 136u8, 217u8, 138u8, 217u8, 133u8,
+                    32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 165u8, 216u8, 179u8, 217u8, 132u8,
+                    216u8, 167u8, 217u8, 133u8, 217u8, 138u8, 32u8, 40u8, 216u8, 167u8, 217u8,
+                    132u8, 216u8, 173u8, 216u8, 179u8, 216u8, 167u8, 216u8, 168u8, 216u8, 167u8,
+                    216u8, 170u8, 32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 129u8, 217u8, 132u8,
+                    217u8, 131u8, 217u8, 138u8, 216u8, 169u8, 41u8, 216u8, 167u8, 217u8, 132u8,
+                    216u8, 170u8, 217u8, 130u8, 217u8, 136u8, 217u8, 138u8, 217u8, 133u8, 32u8,
+                    216u8, 167u8, 217u8, 132u8, 216u8, 165u8, 216u8, 179u8, 217u8, 132u8, 216u8,
+                    167u8, 217u8, 133u8, 217u8, 138u8, 32u8, 40u8, 216u8, 163u8, 217u8, 133u8,
+                    32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 130u8, 216u8, 177u8, 217u8, 137u8,
+                    41u8, 216u8, 170u8, 217u8, 130u8, 217u8, 136u8, 217u8, 138u8, 217u8, 133u8,
+                    32u8, 73u8, 83u8, 79u8, 45u8, 56u8, 54u8, 48u8, 49u8, 216u8, 167u8, 217u8,
+                    132u8, 216u8, 170u8, 217u8, 130u8, 217u8, 136u8, 217u8, 138u8, 217u8, 133u8,
+                    32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 138u8, 216u8, 167u8, 216u8, 168u8,
+                    216u8, 167u8, 217u8, 134u8, 217u8, 138u8, 216u8, 167u8, 217u8, 132u8, 216u8,
+                    170u8, 217u8, 130u8, 217u8, 136u8, 217u8, 138u8, 217u8, 133u8, 32u8, 216u8,
+                    167u8, 217u8, 132u8, 217u8, 129u8, 216u8, 167u8, 216u8, 177u8, 216u8, 179u8,
+                    217u8, 138u8, 216u8, 170u8, 217u8, 130u8, 217u8, 136u8, 217u8, 138u8, 217u8,
+                    133u8, 32u8, 217u8, 133u8, 217u8, 138u8, 217u8, 134u8, 216u8, 172u8, 217u8,
+                    136u8, 216u8, 170u8, 217u8, 134u8, 216u8, 179u8, 217u8, 138u8, 217u8, 130u8,
+                    32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 185u8, 217u8, 133u8, 217u8, 132u8,
+                    216u8, 169u8, 32u8, 217u8, 132u8, 217u8, 132u8, 216u8, 173u8, 216u8, 179u8,
+                    216u8, 167u8, 216u8, 168u8, 216u8, 167u8, 216u8, 170u8, 216u8, 170u8, 217u8,
+                    134u8, 216u8, 179u8, 217u8, 138u8, 217u8, 130u8, 32u8, 216u8, 167u8, 217u8,
+                    132u8, 216u8, 185u8, 217u8, 133u8, 217u8, 132u8, 216u8, 169u8, 32u8, 216u8,
+                    167u8, 217u8, 132u8, 217u8, 130u8, 217u8, 138u8, 216u8, 167u8, 216u8, 179u8,
+                    217u8, 138u8, 216u8, 167u8, 217u8, 132u8, 216u8, 170u8, 216u8, 177u8, 216u8,
+                    170u8, 217u8, 138u8, 216u8, 168u8, 32u8, 216u8, 173u8, 216u8, 179u8, 216u8,
+                    168u8, 32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 132u8, 216u8, 186u8, 216u8,
+                    169u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 181u8, 217u8, 138u8, 217u8,
+                    134u8, 217u8, 138u8, 216u8, 169u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8,
+                    170u8, 217u8, 130u8, 217u8, 132u8, 217u8, 138u8, 216u8, 175u8, 217u8, 138u8,
+                    216u8, 169u8, 32u8, 40u8, 66u8, 105u8, 103u8, 53u8, 41u8, 216u8, 170u8, 216u8,
+                    177u8, 216u8, 170u8, 217u8, 138u8, 216u8, 168u8, 32u8, 216u8, 167u8, 217u8,
+                    132u8, 217u8, 129u8, 216u8, 177u8, 216u8, 178u8, 32u8, 216u8, 167u8, 217u8,
+                    132u8, 216u8, 179u8, 216u8, 167u8, 216u8, 168u8, 217u8, 130u8, 58u8, 32u8,
+                    217u8, 132u8, 217u8, 132u8, 216u8, 170u8, 217u8, 136u8, 216u8, 167u8, 217u8,
+                    129u8, 217u8, 130u8, 216u8, 167u8, 217u8, 132u8, 216u8, 170u8, 216u8, 177u8,
+                    216u8, 170u8, 217u8, 138u8, 216u8, 168u8, 32u8, 216u8, 173u8, 216u8, 179u8,
+                    216u8, 168u8, 32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 130u8, 216u8, 167u8,
+                    217u8, 133u8, 217u8, 136u8, 216u8, 179u8, 216u8, 170u8, 216u8, 177u8, 216u8,
+                    170u8, 217u8, 138u8, 216u8, 168u8, 32u8, 217u8, 129u8, 216u8, 177u8, 216u8,
+                    178u8, 32u8, 85u8, 110u8, 105u8, 99u8, 111u8, 100u8, 101u8, 32u8, 216u8, 167u8,
+                    217u8, 132u8, 216u8, 167u8, 217u8, 129u8, 216u8, 170u8, 216u8, 177u8, 216u8,
+                    167u8, 216u8, 182u8, 217u8, 138u8, 216u8, 167u8, 217u8, 132u8, 216u8, 170u8,
+                    216u8, 177u8, 216u8, 170u8, 217u8, 138u8, 216u8, 168u8, 32u8, 216u8, 173u8,
+                    216u8, 179u8, 216u8, 168u8, 32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 132u8,
+                    216u8, 186u8, 216u8, 169u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 181u8,
+                    217u8, 138u8, 217u8, 134u8, 217u8, 138u8, 216u8, 169u8, 32u8, 216u8, 167u8,
+                    217u8, 132u8, 217u8, 133u8, 216u8, 168u8, 216u8, 179u8, 217u8, 145u8, 216u8,
+                    183u8, 216u8, 169u8, 32u8, 40u8, 71u8, 66u8, 50u8, 51u8, 49u8, 50u8, 41u8,
+                    216u8, 167u8, 217u8, 132u8, 216u8, 170u8, 216u8, 177u8, 216u8, 170u8, 217u8,
+                    138u8, 216u8, 168u8, 32u8, 216u8, 173u8, 216u8, 179u8, 216u8, 168u8, 32u8,
+                    216u8, 175u8, 217u8, 132u8, 217u8, 138u8, 217u8, 132u8, 32u8, 216u8, 167u8,
+                    217u8, 132u8, 217u8, 135u8, 216u8, 167u8, 216u8, 170u8, 217u8, 129u8, 216u8,
+                    167u8, 217u8, 132u8, 216u8, 170u8, 216u8, 177u8, 216u8, 170u8, 217u8, 138u8,
+                    216u8, 168u8, 32u8, 216u8, 173u8, 216u8, 179u8, 216u8, 168u8, 32u8, 216u8,
+                    167u8, 217u8, 132u8, 217u8, 132u8, 217u8, 129u8, 216u8, 184u8, 216u8, 167u8,
+                    217u8, 132u8, 216u8, 170u8, 216u8, 177u8, 216u8, 170u8, 217u8, 138u8, 216u8,
+                    168u8, 32u8, 216u8, 173u8, 216u8, 179u8, 216u8, 168u8, 32u8, 217u8, 134u8,
+                    216u8, 184u8, 216u8, 167u8, 217u8, 133u8, 32u8, 216u8, 168u8, 217u8, 134u8,
+                    217u8, 138u8, 217u8, 138u8, 217u8, 134u8, 32u8, 216u8, 167u8, 217u8, 132u8,
+                    216u8, 181u8, 217u8, 138u8, 217u8, 134u8, 217u8, 138u8, 216u8, 167u8, 217u8,
+                    132u8, 216u8, 170u8, 216u8, 177u8, 216u8, 170u8, 217u8, 138u8, 216u8, 168u8,
+                    32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 133u8, 216u8, 173u8, 216u8, 179u8,
+                    217u8, 142u8, 217u8, 145u8, 217u8, 134u8, 216u8, 168u8, 216u8, 173u8, 216u8,
+                    171u8, 32u8, 217u8, 132u8, 216u8, 163u8, 216u8, 186u8, 216u8, 177u8, 216u8,
+                    167u8, 216u8, 182u8, 32u8, 216u8, 185u8, 216u8, 167u8, 217u8, 133u8, 216u8,
+                    169u8, 216u8, 168u8, 216u8, 173u8, 216u8, 171u8, 32u8, 216u8, 168u8, 216u8,
+                    167u8, 216u8, 179u8, 216u8, 170u8, 216u8, 174u8, 216u8, 175u8, 216u8, 167u8,
+                    217u8, 133u8, 32u8, 216u8, 173u8, 216u8, 177u8, 217u8, 129u8, 32u8, 216u8,
+                    167u8, 217u8, 132u8, 217u8, 135u8, 216u8, 167u8, 217u8, 134u8, 216u8, 186u8,
+                    217u8, 136u8, 217u8, 132u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 179u8,
+                    216u8, 167u8, 217u8, 131u8, 217u8, 134u8, 32u8, 216u8, 167u8, 217u8, 132u8,
+                    216u8, 163u8, 217u8, 136u8, 217u8, 132u8, 216u8, 170u8, 216u8, 177u8, 216u8,
+                    170u8, 217u8, 138u8, 216u8, 168u8, 32u8, 216u8, 167u8, 217u8, 132u8, 217u8,
+                    129u8, 216u8, 177u8, 216u8, 178u8, 32u8, 216u8, 167u8, 217u8, 132u8, 217u8,
+                    130u8, 217u8, 138u8, 216u8, 167u8, 216u8, 179u8, 217u8, 138u8, 216u8, 167u8,
+                    217u8, 132u8, 216u8, 170u8, 216u8, 177u8, 216u8, 170u8, 217u8, 138u8, 216u8,
+                    168u8, 32u8, 216u8, 173u8, 216u8, 179u8, 216u8, 168u8, 32u8, 217u8, 134u8,
+                    216u8, 184u8, 216u8, 167u8, 217u8, 133u8, 32u8, 217u8, 131u8, 216u8, 170u8,
+                    216u8, 167u8, 216u8, 168u8, 216u8, 169u8, 32u8, 216u8, 167u8, 217u8, 132u8,
+                    217u8, 133u8, 216u8, 172u8, 217u8, 133u8, 217u8, 136u8, 216u8, 185u8, 32u8,
+                    216u8, 167u8, 217u8, 132u8, 216u8, 181u8, 217u8, 138u8, 217u8, 134u8, 217u8,
+                    138u8, 216u8, 170u8, 216u8, 177u8, 216u8, 170u8, 217u8, 138u8, 216u8, 168u8,
+                    32u8, 216u8, 170u8, 217u8, 130u8, 217u8, 132u8, 217u8, 138u8, 216u8, 175u8,
+                    217u8, 138u8, 216u8, 167u8, 217u8, 132u8, 216u8, 170u8, 216u8, 177u8, 216u8,
+                    170u8, 217u8, 138u8, 216u8, 168u8, 32u8, 216u8, 173u8, 216u8, 179u8, 216u8,
+                    168u8, 32u8, 217u8, 134u8, 216u8, 184u8, 216u8, 167u8, 217u8, 133u8, 32u8,
+                    216u8, 167u8, 217u8, 132u8, 217u8, 131u8, 216u8, 170u8, 216u8, 167u8, 216u8,
+                    168u8, 216u8, 169u8, 32u8, 216u8, 168u8, 216u8, 167u8, 217u8, 132u8, 216u8,
+                    172u8, 216u8, 176u8, 216u8, 177u8, 32u8, 217u8, 136u8, 216u8, 167u8, 217u8,
+                    132u8, 217u8, 133u8, 216u8, 172u8, 217u8, 133u8, 217u8, 136u8, 216u8, 185u8,
+                    216u8, 167u8, 217u8, 132u8, 216u8, 170u8, 216u8, 177u8, 216u8, 170u8, 217u8,
+                    138u8, 216u8, 168u8, 32u8, 216u8, 173u8, 216u8, 179u8, 216u8, 168u8, 32u8,
+                    217u8, 134u8, 216u8, 184u8, 216u8, 167u8, 217u8, 133u8, 32u8, 216u8, 168u8,
+                    217u8, 136u8, 216u8, 168u8, 217u8, 136u8, 217u8, 133u8, 217u8, 136u8, 217u8,
+                    129u8, 217u8, 136u8, 217u8, 134u8, 216u8, 184u8, 216u8, 167u8, 217u8, 133u8,
+                    32u8, 49u8, 50u8, 32u8, 216u8, 179u8, 216u8, 167u8, 216u8, 185u8, 216u8, 169u8,
+                    32u8, 40u8, 48u8, 226u8, 128u8, 147u8, 49u8, 49u8, 41u8, 217u8, 134u8, 216u8,
+                    184u8, 216u8, 167u8, 217u8, 133u8, 32u8, 49u8, 50u8, 32u8, 216u8, 179u8, 216u8,
+                    167u8, 216u8, 185u8, 216u8, 169u8, 32u8, 40u8, 49u8, 226u8, 128u8, 147u8, 49u8,
+                    50u8, 41u8, 217u8, 134u8, 216u8, 184u8, 216u8, 167u8, 217u8, 133u8, 32u8, 50u8,
+                    52u8, 32u8, 216u8, 179u8, 216u8, 167u8, 216u8, 185u8, 216u8, 169u8, 32u8, 40u8,
+                    48u8, 226u8, 128u8, 147u8, 50u8, 51u8, 41u8, 217u8, 134u8, 216u8, 184u8, 216u8,
+                    167u8, 217u8, 133u8, 32u8, 50u8, 52u8, 32u8, 216u8, 179u8, 216u8, 167u8, 216u8,
+                    185u8, 216u8, 169u8, 32u8, 40u8, 49u8, 226u8, 128u8, 147u8, 50u8, 52u8, 41u8,
+                    216u8, 170u8, 216u8, 181u8, 217u8, 134u8, 217u8, 138u8, 217u8, 129u8, 32u8,
+                    216u8, 167u8, 217u8, 132u8, 216u8, 177u8, 217u8, 133u8, 217u8, 136u8, 216u8,
+                    178u8, 216u8, 170u8, 216u8, 181u8, 217u8, 134u8, 217u8, 138u8, 217u8, 129u8,
+                    32u8, 216u8, 170u8, 216u8, 172u8, 216u8, 167u8, 217u8, 135u8, 217u8, 132u8,
+                    32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 177u8, 217u8, 133u8, 217u8, 136u8,
+                    216u8, 178u8, 216u8, 170u8, 216u8, 181u8, 217u8, 134u8, 217u8, 138u8, 217u8,
+                    129u8, 32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 132u8, 217u8, 131u8, 217u8,
+                    134u8, 216u8, 167u8, 216u8, 170u8, 32u8, 216u8, 168u8, 216u8, 180u8, 217u8,
+                    131u8, 217u8, 132u8, 32u8, 216u8, 185u8, 216u8, 167u8, 216u8, 175u8, 217u8,
+                    138u8, 216u8, 170u8, 216u8, 181u8, 217u8, 134u8, 217u8, 138u8, 217u8, 129u8,
+                    32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 132u8, 217u8, 131u8, 217u8, 134u8,
+                    216u8, 167u8, 216u8, 170u8, 32u8, 217u8, 133u8, 216u8, 185u8, 217u8, 131u8,
+                    217u8, 136u8, 216u8, 179u8, 216u8, 169u8, 216u8, 170u8, 216u8, 181u8, 217u8,
+                    134u8, 217u8, 138u8, 217u8, 129u8, 32u8, 216u8, 168u8, 216u8, 173u8, 216u8,
+                    179u8, 216u8, 168u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 216u8,
+                    173u8, 216u8, 177u8, 217u8, 129u8, 32u8, 216u8, 186u8, 217u8, 138u8, 216u8,
+                    177u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 173u8, 216u8, 179u8, 216u8,
+                    167u8, 216u8, 179u8, 216u8, 169u8, 32u8, 217u8, 132u8, 216u8, 173u8, 216u8,
+                    167u8, 217u8, 132u8, 216u8, 169u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8,
+                    163u8, 216u8, 173u8, 216u8, 177u8, 217u8, 129u8, 216u8, 170u8, 216u8, 181u8,
+                    217u8, 134u8, 217u8, 138u8, 217u8, 129u8, 32u8, 216u8, 168u8, 216u8, 173u8,
+                    216u8, 179u8, 216u8, 168u8, 32u8, 216u8, 173u8, 216u8, 179u8, 216u8, 167u8,
+                    216u8, 179u8, 217u8, 138u8, 216u8, 169u8, 32u8, 216u8, 167u8, 217u8, 132u8,
+                    216u8, 163u8, 216u8, 173u8, 216u8, 177u8, 217u8, 129u8, 216u8, 170u8, 216u8,
+                    177u8, 216u8, 170u8, 217u8, 138u8, 216u8, 168u8, 32u8, 216u8, 170u8, 216u8,
+                    181u8, 217u8, 134u8, 217u8, 138u8, 217u8, 129u8, 32u8, 216u8, 173u8, 216u8,
+                    167u8, 217u8, 132u8, 216u8, 169u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8,
+                    163u8, 216u8, 173u8, 216u8, 177u8, 217u8, 129u8, 32u8, 216u8, 167u8, 217u8,
+                    132u8, 216u8, 183u8, 216u8, 168u8, 217u8, 138u8, 216u8, 185u8, 217u8, 138u8,
+                    216u8, 169u8, 216u8, 170u8, 216u8, 181u8, 217u8, 134u8, 217u8, 138u8, 217u8,
+                    129u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 216u8, 173u8, 216u8,
+                    177u8, 217u8, 129u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 181u8, 216u8,
+                    186u8, 217u8, 138u8, 216u8, 177u8, 216u8, 169u8, 32u8, 216u8, 163u8, 217u8,
+                    136u8, 217u8, 132u8, 216u8, 167u8, 217u8, 139u8, 216u8, 170u8, 216u8, 181u8,
+                    217u8, 134u8, 217u8, 138u8, 217u8, 129u8, 32u8, 216u8, 167u8, 217u8, 132u8,
+                    216u8, 163u8, 216u8, 173u8, 216u8, 177u8, 217u8, 129u8, 32u8, 216u8, 167u8,
+                    217u8, 132u8, 217u8, 131u8, 216u8, 168u8, 217u8, 138u8, 216u8, 177u8, 216u8,
+                    169u8, 32u8, 216u8, 163u8, 217u8, 136u8, 217u8, 132u8, 216u8, 167u8, 217u8,
+                    139u8, 216u8, 167u8, 217u8, 132u8, 216u8, 170u8, 216u8, 181u8, 217u8, 129u8,
+                    217u8, 138u8, 216u8, 169u8, 32u8, 216u8, 168u8, 216u8, 175u8, 217u8, 136u8,
+                    217u8, 134u8, 32u8, 216u8, 170u8, 216u8, 179u8, 217u8, 136u8, 217u8, 138u8,
+                    216u8, 169u8, 216u8, 170u8, 216u8, 181u8, 217u8, 134u8, 217u8, 138u8, 217u8,
+                    129u8, 32u8, 85u8, 110u8, 105u8, 99u8, 111u8, 100u8, 101u8, 32u8, 216u8, 183u8,
+                    216u8, 168u8, 217u8, 138u8, 216u8, 185u8, 217u8, 138u8, 216u8, 170u8, 216u8,
+                    181u8, 217u8, 134u8, 217u8, 138u8, 217u8, 129u8, 32u8, 216u8, 167u8, 217u8,
+                    132u8, 216u8, 163u8, 216u8, 177u8, 217u8, 130u8, 216u8, 167u8, 217u8, 133u8,
+                    32u8, 216u8, 185u8, 217u8, 132u8, 217u8, 137u8, 32u8, 216u8, 173u8, 216u8,
+                    175u8, 216u8, 169u8, 216u8, 170u8, 216u8, 181u8, 217u8, 134u8, 217u8, 138u8,
+                    217u8, 129u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 216u8, 177u8,
+                    217u8, 130u8, 216u8, 167u8, 217u8, 133u8, 32u8, 216u8, 168u8, 216u8, 167u8,
+                    217u8, 132u8, 216u8, 185u8, 216u8, 175u8, 216u8, 175u8, 216u8, 170u8, 216u8,
+                    181u8, 217u8, 134u8, 217u8, 138u8, 217u8, 129u8, 32u8, 216u8, 167u8, 217u8,
+                    132u8, 217u8, 131u8, 217u8, 132u8, 216u8, 170u8, 216u8, 181u8, 217u8, 134u8,
+                    217u8, 138u8, 217u8, 129u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 173u8,
+                    216u8, 177u8, 217u8, 136u8, 217u8, 129u8, 32u8, 216u8, 167u8, 217u8, 132u8,
+                    216u8, 163u8, 216u8, 179u8, 216u8, 167u8, 216u8, 179u8, 217u8, 138u8, 216u8,
+                    169u8, 32u8, 217u8, 129u8, 217u8, 130u8, 216u8, 183u8, 216u8, 170u8, 216u8,
+                    181u8, 217u8, 134u8, 217u8, 138u8, 217u8, 129u8, 32u8, 216u8, 167u8, 217u8,
+                    132u8, 217u8, 132u8, 217u8, 131u8, 217u8, 134u8, 216u8, 167u8, 216u8, 170u8,
+                    216u8, 170u8, 216u8, 181u8, 217u8, 134u8, 217u8, 138u8, 217u8, 129u8, 32u8,
+                    216u8, 167u8, 217u8, 132u8, 217u8, 132u8, 217u8, 131u8, 217u8, 134u8, 216u8,
+                    167u8, 216u8, 170u8, 47u8, 216u8, 167u8, 217u8, 132u8, 216u8, 173u8, 216u8,
+                    167u8, 217u8, 132u8, 216u8, 169u8, 47u8, 216u8, 167u8, 217u8, 132u8, 216u8,
+                    185u8, 216u8, 177u8, 216u8, 182u8, 216u8, 170u8, 216u8, 181u8, 217u8, 134u8,
+                    217u8, 138u8, 217u8, 129u8, 32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 132u8,
+                    217u8, 131u8, 217u8, 134u8, 216u8, 167u8, 216u8, 170u8, 47u8, 216u8, 167u8,
+                    217u8, 132u8, 216u8, 173u8, 216u8, 167u8, 217u8, 132u8, 216u8, 169u8, 47u8,
+                    216u8, 167u8, 217u8, 132u8, 216u8, 185u8, 216u8, 177u8, 216u8, 182u8, 47u8,
+                    216u8, 167u8, 217u8, 132u8, 217u8, 131u8, 216u8, 167u8, 217u8, 134u8, 216u8,
+                    167u8, 217u8, 134u8, 217u8, 133u8, 216u8, 183u8, 32u8, 217u8, 129u8, 216u8,
+                    181u8, 217u8, 132u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 179u8, 216u8,
+                    183u8, 217u8, 136u8, 216u8, 177u8, 58u8, 32u8, 217u8, 133u8, 216u8, 170u8,
+                    216u8, 168u8, 216u8, 167u8, 216u8, 185u8, 216u8, 175u8, 217u8, 134u8, 217u8,
+                    133u8, 216u8, 183u8, 32u8, 217u8, 129u8, 216u8, 181u8, 217u8, 132u8, 32u8,
+                    216u8, 167u8, 217u8, 132u8, 216u8, 179u8, 216u8, 183u8, 217u8, 136u8, 216u8,
+                    177u8, 58u8, 32u8, 216u8, 185u8, 216u8, 167u8, 216u8, 175u8, 217u8, 138u8,
+                    217u8, 134u8, 217u8, 133u8, 216u8, 183u8, 32u8, 217u8, 129u8, 216u8, 181u8,
+                    217u8, 132u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 179u8, 216u8, 183u8,
+                    217u8, 136u8, 216u8, 177u8, 58u8, 32u8, 217u8, 133u8, 216u8, 170u8, 217u8,
+                    130u8, 216u8, 167u8, 216u8, 177u8, 216u8, 168u8, 216u8, 167u8, 217u8, 132u8,
+                    217u8, 134u8, 216u8, 184u8, 216u8, 167u8, 217u8, 133u8, 32u8, 216u8, 167u8,
+                    217u8, 132u8, 217u8, 133u8, 216u8, 170u8, 216u8, 177u8, 217u8, 138u8, 217u8,
+                    134u8, 216u8, 184u8, 216u8, 167u8, 217u8, 133u8, 32u8, 216u8, 167u8, 217u8,
+                    132u8, 217u8, 130u8, 217u8, 138u8, 216u8, 167u8, 216u8, 179u8, 32u8, 216u8,
+                    167u8, 217u8, 132u8, 216u8, 168u8, 216u8, 177u8, 217u8, 138u8, 216u8, 183u8,
+                    216u8, 167u8, 217u8, 134u8, 217u8, 138u8, 217u8, 134u8, 216u8, 184u8, 216u8,
+                    167u8, 217u8, 133u8, 32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 130u8, 217u8,
+                    138u8, 216u8, 167u8, 216u8, 179u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8,
+                    163u8, 217u8, 133u8, 216u8, 177u8, 217u8, 138u8, 217u8, 131u8, 217u8, 138u8,
+                    216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 216u8, 177u8, 217u8, 130u8, 216u8,
+                    167u8, 217u8, 133u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 185u8, 216u8,
+                    177u8, 216u8, 168u8, 217u8, 138u8, 216u8, 169u8, 32u8, 216u8, 167u8, 217u8,
+                    132u8, 217u8, 135u8, 217u8, 134u8, 216u8, 175u8, 217u8, 138u8, 216u8, 169u8,
+                    216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 216u8, 177u8, 217u8, 130u8, 216u8,
+                    167u8, 217u8, 133u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 185u8, 216u8,
+                    177u8, 216u8, 168u8, 217u8, 138u8, 216u8, 169u8, 32u8, 216u8, 167u8, 217u8,
+                    132u8, 217u8, 135u8, 217u8, 134u8, 216u8, 175u8, 217u8, 138u8, 216u8, 169u8,
+                    32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 133u8, 217u8, 133u8, 216u8, 170u8,
+                    216u8, 175u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 216u8,
+                    177u8, 217u8, 130u8, 216u8, 167u8, 217u8, 133u8, 32u8, 216u8, 167u8, 217u8,
+                    132u8, 216u8, 163u8, 216u8, 177u8, 217u8, 133u8, 217u8, 138u8, 217u8, 134u8,
+                    217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 216u8,
+                    177u8, 217u8, 130u8, 216u8, 167u8, 217u8, 133u8, 32u8, 216u8, 167u8, 217u8,
+                    132u8, 216u8, 163u8, 216u8, 177u8, 217u8, 133u8, 217u8, 138u8, 217u8, 134u8,
+                    217u8, 138u8, 216u8, 169u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 181u8,
+                    216u8, 186u8, 217u8, 138u8, 216u8, 177u8, 216u8, 169u8, 216u8, 167u8, 217u8,
+                    132u8, 216u8, 163u8, 216u8, 177u8, 217u8, 130u8, 216u8, 167u8, 217u8, 133u8,
+                    32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 168u8, 217u8, 134u8, 216u8, 186u8,
+                    216u8, 167u8, 217u8, 132u8, 217u8, 138u8, 216u8, 169u8, 216u8, 163u8, 216u8,
+                    177u8, 217u8, 130u8, 216u8, 167u8, 217u8, 133u8, 32u8, 216u8, 170u8, 216u8,
+                    180u8, 216u8, 167u8, 217u8, 131u8, 217u8, 133u8, 216u8, 167u8, 216u8, 167u8,
+                    217u8, 132u8, 216u8, 163u8, 216u8, 177u8, 217u8, 130u8, 216u8, 167u8, 217u8,
+                    133u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 175u8, 217u8, 138u8, 217u8,
+                    129u8, 216u8, 167u8, 217u8, 134u8, 216u8, 186u8, 216u8, 167u8, 216u8, 177u8,
+                    217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 216u8,
+                    177u8, 217u8, 130u8, 216u8, 167u8, 217u8, 133u8, 32u8, 216u8, 167u8, 217u8,
+                    132u8, 216u8, 165u8, 216u8, 171u8, 217u8, 138u8, 217u8, 136u8, 216u8, 168u8,
+                    217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 216u8,
+                    177u8, 217u8, 130u8, 216u8, 167u8, 217u8, 133u8, 32u8, 216u8, 167u8, 217u8,
+                    132u8, 217u8, 133u8, 216u8, 167u8, 217u8, 132u8, 217u8, 138u8, 216u8, 169u8,
+                    216u8, 163u8, 216u8, 177u8, 217u8, 130u8, 216u8, 167u8, 217u8, 133u8, 32u8,
+                    217u8, 131u8, 216u8, 167u8, 217u8, 133u8, 217u8, 132u8, 216u8, 169u8, 32u8,
+                    216u8, 167u8, 217u8, 132u8, 216u8, 185u8, 216u8, 177u8, 216u8, 182u8, 216u8,
+                    167u8, 217u8, 132u8, 216u8, 163u8, 216u8, 177u8, 217u8, 130u8, 216u8, 167u8,
+                    217u8, 133u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 172u8, 217u8, 136u8,
+                    216u8, 177u8, 216u8, 172u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8,
+                    132u8, 216u8, 163u8, 216u8, 177u8, 217u8, 130u8, 216u8, 167u8, 217u8, 133u8,
+                    32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 138u8, 217u8, 136u8, 217u8, 134u8,
+                    216u8, 167u8, 217u8, 134u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8,
+                    132u8, 216u8, 163u8, 216u8, 177u8, 217u8, 130u8, 216u8, 167u8, 217u8, 133u8,
+                    32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 138u8, 217u8, 136u8, 217u8, 134u8,
+                    216u8, 167u8, 217u8, 134u8, 217u8, 138u8, 216u8, 169u8, 32u8, 216u8, 167u8,
+                    217u8, 132u8, 216u8, 181u8, 216u8, 186u8, 217u8, 138u8, 216u8, 177u8, 216u8,
+                    169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 216u8, 177u8, 217u8, 130u8,
+                    216u8, 167u8, 217u8, 133u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 186u8,
+                    217u8, 136u8, 216u8, 172u8, 216u8, 167u8, 216u8, 177u8, 216u8, 167u8, 216u8,
+                    170u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 163u8,
+                    216u8, 177u8, 217u8, 130u8, 216u8, 167u8, 217u8, 133u8, 32u8, 216u8, 167u8,
+                    217u8, 132u8, 216u8, 186u8, 217u8, 136u8, 216u8, 177u8, 217u8, 133u8, 217u8,
+                    136u8, 216u8, 174u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8,
+                    216u8, 163u8, 216u8, 177u8, 217u8, 130u8, 216u8, 167u8, 217u8, 133u8, 32u8,
+                    216u8, 167u8, 217u8, 132u8, 216u8, 185u8, 216u8, 180u8, 216u8, 177u8, 217u8,
+                    138u8, 216u8, 169u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 181u8, 217u8,
+                    138u8, 217u8, 134u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8,
+                    216u8, 163u8, 216u8, 177u8, 217u8, 130u8, 216u8, 167u8, 217u8, 133u8, 32u8,
+                    216u8, 167u8, 217u8, 132u8, 216u8, 181u8, 217u8, 138u8, 217u8, 134u8, 217u8,
+                    138u8, 216u8, 169u8, 32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 133u8, 216u8,
+                    168u8, 216u8, 179u8, 216u8, 183u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8,
+                    216u8, 163u8, 216u8, 177u8, 217u8, 130u8, 216u8, 167u8, 217u8, 133u8, 32u8,
+                    216u8, 167u8, 217u8, 132u8, 217u8, 133u8, 216u8, 167u8, 217u8, 132u8, 217u8,
+                    138u8, 216u8, 169u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 181u8, 217u8,
+                    138u8, 217u8, 134u8, 217u8, 138u8, 216u8, 169u8, 32u8, 216u8, 167u8, 217u8,
+                    132u8, 217u8, 133u8, 216u8, 168u8, 216u8, 179u8, 216u8, 183u8, 216u8, 169u8,
+                    216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 216u8, 177u8, 217u8, 130u8, 216u8,
+                    167u8, 217u8, 133u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 181u8, 217u8,
+                    138u8, 217u8, 134u8, 217u8, 138u8, 216u8, 169u8, 32u8, 216u8, 167u8, 217u8,
+                    132u8, 216u8, 170u8, 217u8, 130u8, 217u8, 132u8, 217u8, 138u8, 216u8, 175u8,
+                    217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 216u8,
+                    177u8, 217u8, 130u8, 216u8, 167u8, 217u8, 133u8, 32u8, 216u8, 167u8, 217u8,
+                    132u8, 217u8, 133u8, 216u8, 167u8, 217u8, 132u8, 217u8, 138u8, 216u8, 169u8,
+                    32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 181u8, 217u8, 138u8, 217u8, 134u8,
+                    217u8, 138u8, 216u8, 169u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 170u8,
+                    217u8, 130u8, 217u8, 132u8, 217u8, 138u8, 216u8, 175u8, 217u8, 138u8, 216u8,
+                    169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 216u8, 177u8, 217u8, 130u8,
+                    216u8, 167u8, 217u8, 133u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 185u8,
+                    216u8, 168u8, 216u8, 177u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8,
+                    132u8, 216u8, 163u8, 216u8, 177u8, 217u8, 130u8, 216u8, 167u8, 217u8, 133u8,
+                    32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 172u8, 216u8, 167u8, 217u8, 136u8,
+                    217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 216u8,
+                    177u8, 217u8, 130u8, 216u8, 167u8, 217u8, 133u8, 32u8, 216u8, 167u8, 217u8,
+                    132u8, 217u8, 138u8, 216u8, 167u8, 216u8, 168u8, 216u8, 167u8, 217u8, 134u8,
+                    217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 216u8,
+                    177u8, 217u8, 130u8, 216u8, 167u8, 217u8, 133u8, 32u8, 216u8, 167u8, 217u8,
+                    132u8, 217u8, 133u8, 216u8, 167u8, 217u8, 132u8, 217u8, 138u8, 216u8, 169u8,
+                    32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 138u8, 216u8, 167u8, 216u8, 168u8,
+                    216u8, 167u8, 217u8, 134u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8,
+                    132u8, 216u8, 163u8, 216u8, 177u8, 217u8, 130u8, 216u8, 167u8, 217u8, 133u8,
+                    32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 174u8, 217u8, 138u8, 217u8, 133u8,
+                    216u8, 177u8, 217u8, 138u8, 216u8, 169u8, 216u8, 163u8, 216u8, 177u8, 217u8,
+                    130u8, 216u8, 167u8, 217u8, 133u8, 32u8, 216u8, 167u8, 217u8, 132u8, 217u8,
+                    131u8, 216u8, 167u8, 217u8, 134u8, 216u8, 167u8, 216u8, 175u8, 216u8, 167u8,
+                    216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 216u8, 177u8, 217u8, 130u8, 216u8,
+                    167u8, 217u8, 133u8, 32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 132u8, 216u8,
+                    167u8, 217u8, 136u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8,
+                    216u8, 163u8, 216u8, 177u8, 217u8, 130u8, 216u8, 167u8, 217u8, 133u8, 32u8,
+                    216u8, 167u8, 217u8, 132u8, 216u8, 186u8, 216u8, 177u8, 216u8, 168u8, 217u8,
+                    138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 216u8, 177u8,
+                    217u8, 130u8, 216u8, 167u8, 217u8, 133u8, 32u8, 216u8, 167u8, 217u8, 132u8,
+                    217u8, 133u8, 217u8, 132u8, 216u8, 167u8, 217u8, 138u8, 217u8, 132u8, 216u8,
+                    167u8, 217u8, 133u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8,
+                    216u8, 163u8, 216u8, 177u8, 217u8, 130u8, 216u8, 167u8, 217u8, 133u8, 32u8,
+                    216u8, 167u8, 217u8, 132u8, 217u8, 133u8, 216u8, 186u8, 217u8, 136u8, 217u8,
+                    132u8, 217u8, 138u8, 216u8, 169u8, 216u8, 163u8, 216u8, 177u8, 217u8, 130u8,
+                    216u8, 167u8, 217u8, 133u8, 32u8, 217u8, 133u8, 217u8, 138u8, 216u8, 170u8,
+                    217u8, 138u8, 216u8, 163u8, 216u8, 177u8, 217u8, 130u8, 216u8, 167u8, 217u8,
+                    133u8, 32u8, 217u8, 133u8, 217u8, 138u8, 216u8, 167u8, 217u8, 134u8, 217u8,
+                    133u8, 216u8, 167u8, 216u8, 177u8, 216u8, 167u8, 217u8, 132u8, 216u8, 163u8,
+                    216u8, 177u8, 217u8, 130u8, 216u8, 167u8, 217u8, 133u8, 32u8, 216u8, 167u8,
+                    217u8, 132u8, 216u8, 163u8, 216u8, 181u8, 217u8, 132u8, 217u8, 138u8, 216u8,
+                    169u8, 216u8, 163u8, 216u8, 177u8, 217u8, 130u8, 216u8, 167u8, 217u8, 133u8,
+                    32u8, 216u8, 163u8, 217u8, 143u8, 217u8, 136u8, 217u8, 132u8, 32u8, 216u8,
+                    170u8, 216u8, 180u8, 217u8, 138u8, 217u8, 131u8, 217u8, 138u8, 216u8, 163u8,
+                    216u8, 177u8, 217u8, 130u8, 216u8, 167u8, 217u8, 133u8, 32u8, 216u8, 167u8,
+                    217u8, 132u8, 216u8, 163u8, 217u8, 136u8, 216u8, 177u8, 217u8, 138u8, 216u8,
+                    167u8, 216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 216u8, 177u8, 217u8, 130u8,
+                    216u8, 167u8, 217u8, 133u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 177u8,
+                    217u8, 136u8, 217u8, 133u8, 216u8, 167u8, 217u8, 134u8, 217u8, 138u8, 216u8,
+                    169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 216u8, 177u8, 217u8, 130u8,
+                    216u8, 167u8, 217u8, 133u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 177u8,
+                    217u8, 136u8, 217u8, 133u8, 216u8, 167u8, 217u8, 134u8, 217u8, 138u8, 216u8,
+                    169u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 181u8, 216u8, 186u8, 217u8,
+                    138u8, 216u8, 177u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 163u8,
+                    216u8, 177u8, 217u8, 130u8, 216u8, 167u8, 217u8, 133u8, 32u8, 216u8, 167u8,
+                    217u8, 132u8, 216u8, 170u8, 216u8, 167u8, 217u8, 133u8, 217u8, 138u8, 217u8,
+                    132u8, 217u8, 138u8, 216u8, 169u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8,
+                    170u8, 217u8, 130u8, 217u8, 132u8, 217u8, 138u8, 216u8, 175u8, 217u8, 138u8,
+                    216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 216u8, 177u8, 217u8,
+                    130u8, 216u8, 167u8, 217u8, 133u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8,
+                    170u8, 216u8, 167u8, 217u8, 133u8, 217u8, 138u8, 217u8, 132u8, 217u8, 138u8,
+                    216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 216u8, 177u8, 217u8,
+                    130u8, 216u8, 167u8, 217u8, 133u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8,
+                    170u8, 217u8, 138u8, 217u8, 132u8, 217u8, 136u8, 216u8, 186u8, 217u8, 136u8,
+                    217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 216u8,
+                    177u8, 217u8, 130u8, 216u8, 167u8, 217u8, 133u8, 32u8, 216u8, 167u8, 217u8,
+                    132u8, 216u8, 170u8, 216u8, 167u8, 217u8, 138u8, 217u8, 132u8, 216u8, 167u8,
+                    217u8, 134u8, 216u8, 175u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8,
+                    132u8, 216u8, 163u8, 216u8, 177u8, 217u8, 130u8, 216u8, 167u8, 217u8, 133u8,
+                    32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 170u8, 216u8, 168u8, 216u8, 170u8,
+                    217u8, 138u8, 216u8, 169u8, 216u8, 163u8, 216u8, 177u8, 217u8, 130u8, 216u8,
+                    167u8, 217u8, 133u8, 32u8, 216u8, 170u8, 217u8, 130u8, 217u8, 132u8, 217u8,
+                    138u8, 216u8, 175u8, 217u8, 138u8, 216u8, 169u8, 216u8, 163u8, 216u8, 177u8,
+                    217u8, 130u8, 216u8, 167u8, 217u8, 133u8, 32u8, 217u8, 129u8, 216u8, 167u8,
+                    217u8, 138u8,
+                ])
+            },
+        )
+    },
+};
+static BN: &DataStruct = &::icu_displaynames::provider::KeyDisplayNamesV1 {
+    keys: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    99u8, 97u8, 99u8, 102u8, 99u8, 111u8, 99u8, 117u8, 104u8, 99u8, 107u8, 97u8,
+                    107u8, 98u8, 107u8, 99u8, 107u8, 102u8, 107u8, 107u8, 107u8, 110u8, 107u8,
+                    115u8, 108u8, 98u8, 109u8, 115u8, 110u8, 117u8, 116u8, 122u8, 118u8, 97u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    17u8, 0u8, 0u8, 0u8, 0u8, 0u8, 33u8, 0u8, 79u8, 0u8, 116u8, 0u8, 134u8, 0u8,
+                    191u8, 0u8, 13u8, 1u8, 91u8, 1u8, 144u8, 1u8, 222u8, 1u8, 27u8, 2u8, 82u8, 2u8,
+                    122u8, 2u8, 166u8, 2u8, 206u8, 2u8, 224u8, 2u8, 246u8, 2u8, 224u8, 166u8,
+                    149u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 178u8, 224u8, 167u8, 135u8, 224u8, 166u8, 168u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 161u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 166u8,
+                    174u8, 224u8, 167u8, 129u8, 224u8, 166u8, 166u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 176u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8,
+                    32u8, 224u8, 166u8, 171u8, 224u8, 166u8, 176u8, 224u8, 166u8, 174u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 159u8,
+                    224u8, 166u8, 172u8, 224u8, 166u8, 190u8, 224u8, 166u8, 155u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 135u8, 32u8, 224u8, 166u8, 172u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 168u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 184u8, 224u8, 166u8, 174u8, 224u8, 167u8, 129u8, 224u8,
+                    166u8, 166u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 184u8, 224u8, 166u8, 174u8, 224u8, 166u8, 175u8, 224u8, 166u8,
+                    188u8, 224u8, 167u8, 135u8, 224u8, 166u8, 176u8, 32u8, 224u8, 166u8, 185u8,
+                    224u8, 166u8, 191u8, 224u8, 166u8, 184u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    172u8, 32u8, 40u8, 224u8, 167u8, 167u8, 224u8, 167u8, 168u8, 32u8, 224u8,
+                    166u8, 172u8, 224u8, 166u8, 190u8, 32u8, 224u8, 167u8, 168u8, 224u8, 167u8,
+                    170u8, 41u8, 224u8, 166u8, 170u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8,
+                    224u8, 166u8, 164u8, 224u8, 167u8, 128u8, 224u8, 166u8, 149u8, 32u8, 224u8,
+                    166u8, 172u8, 224u8, 166u8, 190u8, 224u8, 166u8, 155u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 135u8, 224u8, 166u8, 149u8, 224u8, 166u8, 176u8, 224u8, 166u8,
+                    163u8, 32u8, 224u8, 166u8, 137u8, 224u8, 166u8, 170u8, 224u8, 167u8, 135u8,
+                    224u8, 166u8, 149u8, 224u8, 167u8, 141u8, 224u8, 166u8, 183u8, 224u8, 166u8,
+                    190u8, 32u8, 224u8, 166u8, 149u8, 224u8, 166u8, 176u8, 224u8, 167u8, 129u8,
+                    224u8, 166u8, 168u8, 224u8, 166u8, 172u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    170u8, 224u8, 166u8, 176u8, 224u8, 167u8, 128u8, 224u8, 166u8, 164u8, 32u8,
+                    224u8, 166u8, 149u8, 224u8, 166u8, 176u8, 224u8, 166u8, 190u8, 32u8, 224u8,
+                    166u8, 184u8, 224u8, 167u8, 141u8, 224u8, 166u8, 172u8, 224u8, 166u8, 176u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 152u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    164u8, 32u8, 224u8, 166u8, 172u8, 224u8, 166u8, 190u8, 224u8, 166u8, 155u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 135u8, 224u8, 166u8, 149u8, 224u8, 166u8,
+                    176u8, 224u8, 166u8, 163u8, 224u8, 166u8, 149u8, 224u8, 167u8, 135u8, 224u8,
+                    166u8, 184u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8, 166u8,
+                    172u8, 224u8, 167u8, 135u8, 224u8, 166u8, 166u8, 224u8, 167u8, 128u8, 32u8,
+                    224u8, 166u8, 172u8, 224u8, 166u8, 190u8, 224u8, 166u8, 155u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 135u8, 224u8, 166u8, 149u8, 224u8, 166u8, 176u8, 224u8,
+                    166u8, 163u8, 224u8, 166u8, 172u8, 224u8, 166u8, 161u8, 224u8, 166u8, 188u8,
+                    224u8, 166u8, 185u8, 224u8, 166u8, 190u8, 224u8, 166u8, 164u8, 224u8, 167u8,
+                    135u8, 224u8, 166u8, 176u8, 47u8, 224u8, 166u8, 155u8, 224u8, 167u8, 139u8,
+                    224u8, 166u8, 159u8, 224u8, 166u8, 185u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    164u8, 224u8, 167u8, 135u8, 224u8, 166u8, 176u8, 32u8, 224u8, 166u8, 133u8,
+                    224u8, 166u8, 149u8, 224u8, 167u8, 141u8, 224u8, 166u8, 183u8, 224u8, 166u8,
+                    176u8, 32u8, 224u8, 166u8, 149u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8,
+                    224u8, 166u8, 174u8, 224u8, 166u8, 184u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                    172u8, 224u8, 166u8, 190u8, 224u8, 166u8, 173u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 172u8, 224u8, 166u8, 191u8, 224u8, 166u8, 149u8, 224u8, 166u8, 149u8,
+                    224u8, 167u8, 131u8, 224u8, 166u8, 164u8, 32u8, 224u8, 166u8, 172u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 155u8, 224u8, 166u8, 190u8, 224u8, 166u8, 135u8,
+                    224u8, 166u8, 149u8, 224u8, 166u8, 176u8, 224u8, 166u8, 163u8, 224u8, 166u8,
+                    184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 184u8, 224u8, 167u8, 130u8,
+                    224u8, 166u8, 154u8, 224u8, 166u8, 149u8, 32u8, 224u8, 166u8, 172u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 155u8, 224u8, 166u8, 190u8, 224u8, 166u8, 135u8,
+                    224u8, 166u8, 149u8, 224u8, 166u8, 176u8, 224u8, 166u8, 163u8, 224u8, 166u8,
+                    172u8, 224u8, 166u8, 190u8, 224u8, 166u8, 155u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 135u8, 224u8, 166u8, 149u8, 224u8, 166u8, 176u8, 224u8, 166u8, 163u8,
+                    32u8, 224u8, 166u8, 182u8, 224u8, 166u8, 149u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 164u8, 224u8, 166u8, 191u8, 224u8, 166u8, 178u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 135u8, 224u8, 166u8, 168u8, 32u8, 224u8, 166u8, 172u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 173u8, 224u8, 166u8, 190u8, 224u8, 166u8, 156u8,
+                    224u8, 166u8, 149u8, 32u8, 224u8, 166u8, 182u8, 224u8, 167u8, 136u8, 224u8,
+                    166u8, 178u8, 224u8, 167u8, 128u8, 224u8, 166u8, 170u8, 224u8, 166u8, 176u8,
+                    224u8, 166u8, 191u8, 224u8, 166u8, 174u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    170u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 191u8, 224u8, 166u8, 184u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 159u8, 224u8, 167u8, 135u8, 224u8, 166u8,
+                    174u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 150u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 184u8,
+                    224u8, 166u8, 174u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 32u8, 224u8,
+                    166u8, 156u8, 224u8, 167u8, 139u8, 224u8, 166u8, 168u8, 224u8, 166u8, 184u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 165u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    168u8, 224u8, 167u8, 128u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 32u8,
+                    224u8, 166u8, 173u8, 224u8, 166u8, 191u8, 224u8, 166u8, 168u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 168u8, 224u8, 166u8, 164u8, 224u8, 166u8, 190u8,
+                ])
+            },
+        )
+    },
+    types: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    143u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 21u8, 0u8, 30u8, 0u8, 38u8, 0u8,
+                    48u8, 0u8, 59u8, 0u8, 69u8, 0u8, 78u8, 0u8, 87u8, 0u8, 97u8, 0u8, 113u8, 0u8,
+                    128u8, 0u8, 143u8, 0u8, 162u8, 0u8, 172u8, 0u8, 183u8, 0u8, 193u8, 0u8, 199u8,
+                    0u8, 209u8, 0u8, 220u8, 0u8, 230u8, 0u8, 239u8, 0u8, 246u8, 0u8, 254u8, 0u8,
+                    6u8, 1u8, 12u8, 1u8, 21u8, 1u8, 31u8, 1u8, 42u8, 1u8, 51u8, 1u8, 62u8, 1u8,
+                    71u8, 1u8, 82u8, 1u8, 93u8, 1u8, 102u8, 1u8, 109u8, 1u8, 118u8, 1u8, 127u8,
+                    1u8, 133u8, 1u8, 139u8, 1u8, 145u8, 1u8, 151u8, 1u8, 162u8, 1u8, 172u8, 1u8,
+                    180u8, 1u8, 187u8, 1u8, 195u8, 1u8, 202u8, 1u8, 210u8, 1u8, 218u8, 1u8, 226u8,
+                    1u8, 234u8, 1u8, 241u8, 1u8, 249u8, 1u8, 0u8, 2u8, 10u8, 2u8, 19u8, 2u8, 28u8,
+                    2u8, 37u8, 2u8, 46u8, 2u8, 54u8, 2u8, 63u8, 2u8, 72u8, 2u8, 81u8, 2u8, 92u8,
+                    2u8, 103u8, 2u8, 110u8, 2u8, 117u8, 2u8, 127u8, 2u8, 134u8, 2u8, 144u8, 2u8,
+                    151u8, 2u8, 158u8, 2u8, 165u8, 2u8, 172u8, 2u8, 179u8, 2u8, 186u8, 2u8, 193u8,
+                    2u8, 200u8, 2u8, 210u8, 2u8, 221u8, 2u8, 228u8, 2u8, 235u8, 2u8, 242u8, 2u8,
+                    252u8, 2u8, 3u8, 3u8, 10u8, 3u8, 20u8, 3u8, 27u8, 3u8, 37u8, 3u8, 44u8, 3u8,
+                    54u8, 3u8, 61u8, 3u8, 68u8, 3u8, 75u8, 3u8, 82u8, 3u8, 92u8, 3u8, 99u8, 3u8,
+                    106u8, 3u8, 113u8, 3u8, 120u8, 3u8, 131u8, 3u8, 138u8, 3u8, 145u8, 3u8, 152u8,
+                    3u8, 159u8, 3u8, 170u8, 3u8, 180u8, 3u8, 191u8, 3u8, 202u8, 3u8, 213u8, 3u8,
+                    220u8, 3u8, 227u8, 3u8, 234u8, 3u8, 241u8, 3u8, 248u8, 3u8, 255u8, 3u8, 10u8,
+                    4u8, 21u8, 4u8, 30u8, 4u8, 37u8, 4u8, 44u8, 4u8, 51u8, 4u8, 58u8, 4u8, 66u8,
+                    4u8, 77u8, 4u8, 84u8, 4u8, 91u8, 4u8, 98u8, 4u8, 105u8, 4u8, 112u8, 4u8, 119u8,
+                    4u8, 126u8, 4u8, 133u8, 4u8, 140u8, 4u8, 150u8, 4u8, 157u8, 4u8, 164u8, 4u8,
+                    171u8, 4u8, 178u8, 4u8, 189u8, 4u8, 196u8, 4u8, 99u8, 97u8, 45u8, 98u8, 117u8,
+                    100u8, 100u8, 104u8, 105u8, 115u8, 116u8, 99u8, 97u8, 45u8, 99u8, 104u8, 105u8,
+                    110u8, 101u8, 115u8, 101u8, 99u8, 97u8, 45u8, 99u8, 111u8, 112u8, 116u8, 105u8,
+                    99u8, 99u8, 97u8, 45u8, 100u8, 97u8, 110u8, 103u8, 105u8, 99u8, 97u8, 45u8,
+                    101u8, 116u8, 104u8, 105u8, 111u8, 97u8, 97u8, 99u8, 97u8, 45u8, 101u8, 116u8,
+                    104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 99u8, 97u8, 45u8, 103u8, 114u8, 101u8,
+                    103u8, 111u8, 114u8, 121u8, 99u8, 97u8, 45u8, 104u8, 101u8, 98u8, 114u8, 101u8,
+                    119u8, 99u8, 97u8, 45u8, 105u8, 110u8, 100u8, 105u8, 97u8, 110u8, 99u8, 97u8,
+                    45u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 99u8, 97u8, 45u8, 105u8,
+                    115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 99u8, 105u8, 118u8, 105u8, 108u8,
+                    99u8, 97u8, 45u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 114u8,
+                    103u8, 115u8, 97u8, 99u8, 97u8, 45u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8,
+                    99u8, 45u8, 116u8, 98u8, 108u8, 97u8, 99u8, 97u8, 45u8, 105u8, 115u8, 108u8,
+                    97u8, 109u8, 105u8, 99u8, 45u8, 117u8, 109u8, 97u8, 108u8, 113u8, 117u8, 114u8,
+                    97u8, 99u8, 97u8, 45u8, 105u8, 115u8, 111u8, 56u8, 54u8, 48u8, 49u8, 99u8,
+                    97u8, 45u8, 106u8, 97u8, 112u8, 97u8, 110u8, 101u8, 115u8, 101u8, 99u8, 97u8,
+                    45u8, 112u8, 101u8, 114u8, 115u8, 105u8, 97u8, 110u8, 99u8, 97u8, 45u8, 114u8,
+                    111u8, 99u8, 99u8, 102u8, 45u8, 97u8, 99u8, 99u8, 111u8, 117u8, 110u8, 116u8,
+                    99u8, 102u8, 45u8, 115u8, 116u8, 97u8, 110u8, 100u8, 97u8, 114u8, 100u8, 99u8,
+                    111u8, 45u8, 98u8, 105u8, 103u8, 53u8, 104u8, 97u8, 110u8, 99u8, 111u8, 45u8,
+                    99u8, 111u8, 109u8, 112u8, 97u8, 116u8, 99u8, 111u8, 45u8, 100u8, 105u8, 99u8,
+                    116u8, 99u8, 111u8, 45u8, 100u8, 117u8, 99u8, 101u8, 116u8, 99u8, 111u8, 45u8,
+                    101u8, 109u8, 111u8, 106u8, 105u8, 99u8, 111u8, 45u8, 101u8, 111u8, 114u8,
+                    99u8, 111u8, 45u8, 103u8, 98u8, 50u8, 51u8, 49u8, 50u8, 99u8, 111u8, 45u8,
+                    112u8, 104u8, 111u8, 110u8, 101u8, 98u8, 107u8, 99u8, 111u8, 45u8, 112u8,
+                    104u8, 111u8, 110u8, 101u8, 116u8, 105u8, 99u8, 99u8, 111u8, 45u8, 112u8,
+                    105u8, 110u8, 121u8, 105u8, 110u8, 99u8, 111u8, 45u8, 114u8, 101u8, 102u8,
+                    111u8, 114u8, 109u8, 101u8, 100u8, 99u8, 111u8, 45u8, 115u8, 101u8, 97u8,
+                    114u8, 99u8, 104u8, 99u8, 111u8, 45u8, 115u8, 101u8, 97u8, 114u8, 99u8, 104u8,
+                    106u8, 108u8, 99u8, 111u8, 45u8, 115u8, 116u8, 97u8, 110u8, 100u8, 97u8, 114u8,
+                    100u8, 99u8, 111u8, 45u8, 115u8, 116u8, 114u8, 111u8, 107u8, 101u8, 99u8,
+                    111u8, 45u8, 116u8, 114u8, 97u8, 100u8, 99u8, 111u8, 45u8, 117u8, 110u8, 105u8,
+                    104u8, 97u8, 110u8, 99u8, 111u8, 45u8, 122u8, 104u8, 117u8, 121u8, 105u8,
+                    110u8, 104u8, 99u8, 45u8, 104u8, 49u8, 49u8, 104u8, 99u8, 45u8, 104u8, 49u8,
+                    50u8, 104u8, 99u8, 45u8, 104u8, 50u8, 51u8, 104u8, 99u8, 45u8, 104u8, 50u8,
+                    52u8, 107u8, 97u8, 45u8, 110u8, 111u8, 105u8, 103u8, 110u8, 111u8, 114u8,
+                    101u8, 107u8, 97u8, 45u8, 115u8, 104u8, 105u8, 102u8, 116u8, 101u8, 100u8,
+                    107u8, 98u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8, 107u8, 98u8, 45u8, 116u8,
+                    114u8, 117u8, 101u8, 107u8, 99u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8,
+                    107u8, 99u8, 45u8, 116u8, 114u8, 117u8, 101u8, 107u8, 102u8, 45u8, 102u8, 97u8,
+                    108u8, 115u8, 101u8, 107u8, 102u8, 45u8, 108u8, 111u8, 119u8, 101u8, 114u8,
+                    107u8, 102u8, 45u8, 117u8, 112u8, 112u8, 101u8, 114u8, 107u8, 107u8, 45u8,
+                    102u8, 97u8, 108u8, 115u8, 101u8, 107u8, 107u8, 45u8, 116u8, 114u8, 117u8,
+                    101u8, 107u8, 110u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8, 107u8, 110u8,
+                    45u8, 116u8, 114u8, 117u8, 101u8, 107u8, 115u8, 45u8, 105u8, 100u8, 101u8,
+                    110u8, 116u8, 105u8, 99u8, 107u8, 115u8, 45u8, 108u8, 101u8, 118u8, 101u8,
+                    108u8, 49u8, 107u8, 115u8, 45u8, 108u8, 101u8, 118u8, 101u8, 108u8, 50u8,
+                    107u8, 115u8, 45u8, 108u8, 101u8, 118u8, 101u8, 108u8, 51u8, 107u8, 115u8,
+                    45u8, 108u8, 101u8, 118u8, 101u8, 108u8, 52u8, 108u8, 98u8, 45u8, 108u8, 111u8,
+                    111u8, 115u8, 101u8, 108u8, 98u8, 45u8, 110u8, 111u8, 114u8, 109u8, 97u8,
+                    108u8, 108u8, 98u8, 45u8, 115u8, 116u8, 114u8, 105u8, 99u8, 116u8, 109u8,
+                    115u8, 45u8, 109u8, 101u8, 116u8, 114u8, 105u8, 99u8, 109u8, 115u8, 45u8,
+                    117u8, 107u8, 115u8, 121u8, 115u8, 116u8, 101u8, 109u8, 109u8, 115u8, 45u8,
+                    117u8, 115u8, 115u8, 121u8, 115u8, 116u8, 101u8, 109u8, 110u8, 117u8, 45u8,
+                    97u8, 104u8, 111u8, 109u8, 110u8, 117u8, 45u8, 97u8, 114u8, 97u8, 98u8, 110u8,
+                    117u8, 45u8, 97u8, 114u8, 97u8, 98u8, 101u8, 120u8, 116u8, 110u8, 117u8, 45u8,
+                    97u8, 114u8, 109u8, 110u8, 110u8, 117u8, 45u8, 97u8, 114u8, 109u8, 110u8,
+                    108u8, 111u8, 119u8, 110u8, 117u8, 45u8, 98u8, 97u8, 108u8, 105u8, 110u8,
+                    117u8, 45u8, 98u8, 101u8, 110u8, 103u8, 110u8, 117u8, 45u8, 98u8, 114u8, 97u8,
+                    104u8, 110u8, 117u8, 45u8, 99u8, 97u8, 107u8, 109u8, 110u8, 117u8, 45u8, 99u8,
+                    104u8, 97u8, 109u8, 110u8, 117u8, 45u8, 99u8, 121u8, 114u8, 108u8, 110u8,
+                    117u8, 45u8, 100u8, 101u8, 118u8, 97u8, 110u8, 117u8, 45u8, 101u8, 116u8,
+                    104u8, 105u8, 110u8, 117u8, 45u8, 102u8, 105u8, 110u8, 97u8, 110u8, 99u8,
+                    101u8, 110u8, 117u8, 45u8, 102u8, 117u8, 108u8, 108u8, 119u8, 105u8, 100u8,
+                    101u8, 110u8, 117u8, 45u8, 103u8, 101u8, 111u8, 114u8, 110u8, 117u8, 45u8,
+                    103u8, 111u8, 110u8, 109u8, 110u8, 117u8, 45u8, 103u8, 114u8, 101u8, 107u8,
+                    110u8, 117u8, 45u8, 103u8, 114u8, 101u8, 107u8, 108u8, 111u8, 119u8, 110u8,
+                    117u8, 45u8, 103u8, 117u8, 106u8, 114u8, 110u8, 117u8, 45u8, 103u8, 117u8,
+                    114u8, 117u8, 110u8, 117u8, 45u8, 104u8, 97u8, 110u8, 105u8, 100u8, 101u8,
+                    99u8, 110u8, 117u8, 45u8, 104u8, 97u8, 110u8, 115u8, 110u8, 117u8, 45u8, 104u8,
+                    97u8, 110u8, 115u8, 102u8, 105u8, 110u8, 110u8, 117u8, 45u8, 104u8, 97u8,
+                    110u8, 116u8, 110u8, 117u8, 45u8, 104u8, 97u8, 110u8, 116u8, 102u8, 105u8,
+                    110u8, 110u8, 117u8, 45u8, 104u8, 101u8, 98u8, 114u8, 110u8, 117u8, 45u8,
+                    104u8, 109u8, 110u8, 103u8, 110u8, 117u8, 45u8, 106u8, 97u8, 118u8, 97u8,
+                    110u8, 117u8, 45u8, 106u8, 112u8, 97u8, 110u8, 110u8, 117u8, 45u8, 106u8,
+                    112u8, 97u8, 110u8, 102u8, 105u8, 110u8, 110u8, 117u8, 45u8, 107u8, 97u8,
+                    108u8, 105u8, 110u8, 117u8, 45u8, 107u8, 104u8, 109u8, 114u8, 110u8, 117u8,
+                    45u8, 107u8, 110u8, 100u8, 97u8, 110u8, 117u8, 45u8, 108u8, 97u8, 110u8, 97u8,
+                    110u8, 117u8, 45u8, 108u8, 97u8, 110u8, 97u8, 116u8, 104u8, 97u8, 109u8, 110u8,
+                    117u8, 45u8, 108u8, 97u8, 111u8, 111u8, 110u8, 117u8, 45u8, 108u8, 97u8, 116u8,
+                    110u8, 110u8, 117u8, 45u8, 108u8, 101u8, 112u8, 99u8, 110u8, 117u8, 45u8,
+                    108u8, 105u8, 109u8, 98u8, 110u8, 117u8, 45u8, 109u8, 97u8, 116u8, 104u8, 98u8,
+                    111u8, 108u8, 100u8, 110u8, 117u8, 45u8, 109u8, 97u8, 116u8, 104u8, 100u8,
+                    98u8, 108u8, 110u8, 117u8, 45u8, 109u8, 97u8, 116u8, 104u8, 109u8, 111u8,
+                    110u8, 111u8, 110u8, 117u8, 45u8, 109u8, 97u8, 116u8, 104u8, 115u8, 97u8,
+                    110u8, 98u8, 110u8, 117u8, 45u8, 109u8, 97u8, 116u8, 104u8, 115u8, 97u8, 110u8,
+                    115u8, 110u8, 117u8, 45u8, 109u8, 108u8, 121u8, 109u8, 110u8, 117u8, 45u8,
+                    109u8, 111u8, 100u8, 105u8, 110u8, 117u8, 45u8, 109u8, 111u8, 110u8, 103u8,
+                    110u8, 117u8, 45u8, 109u8, 114u8, 111u8, 111u8, 110u8, 117u8, 45u8, 109u8,
+                    116u8, 101u8, 105u8, 110u8, 117u8, 45u8, 109u8, 121u8, 109u8, 114u8, 110u8,
+                    117u8, 45u8, 109u8, 121u8, 109u8, 114u8, 115u8, 104u8, 97u8, 110u8, 110u8,
+                    117u8, 45u8, 109u8, 121u8, 109u8, 114u8, 116u8, 108u8, 110u8, 103u8, 110u8,
+                    117u8, 45u8, 110u8, 97u8, 116u8, 105u8, 118u8, 101u8, 110u8, 117u8, 45u8,
+                    110u8, 107u8, 111u8, 111u8, 110u8, 117u8, 45u8, 111u8, 108u8, 99u8, 107u8,
+                    110u8, 117u8, 45u8, 111u8, 114u8, 121u8, 97u8, 110u8, 117u8, 45u8, 111u8,
+                    115u8, 109u8, 97u8, 110u8, 117u8, 45u8, 114u8, 111u8, 109u8, 97u8, 110u8,
+                    110u8, 117u8, 45u8, 114u8, 111u8, 109u8, 97u8, 110u8, 108u8, 111u8, 119u8,
+                    110u8, 117u8, 45u8, 115u8, 97u8, 117u8, 114u8, 110u8, 117u8, 45u8, 115u8,
+                    104u8, 114u8, 100u8, 110u8, 117u8, 45u8, 115u8, 105u8, 110u8, 100u8, 110u8,
+                    117u8, 45u8, 115u8, 105u8, 110u8, 104u8, 110u8, 117u8, 45u8, 115u8, 111u8,
+                    114u8, 97u8, 110u8, 117u8, 45u8, 115u8, 117u8, 110u8, 100u8, 110u8, 117u8,
+                    45u8, 116u8, 97u8, 107u8, 114u8, 110u8, 117u8, 45u8, 116u8, 97u8, 108u8, 117u8,
+                    110u8, 117u8, 45u8, 116u8, 97u8, 109u8, 108u8, 110u8, 117u8, 45u8, 116u8, 97u8,
+                    109u8, 108u8, 100u8, 101u8, 99u8, 110u8, 117u8, 45u8, 116u8, 101u8, 108u8,
+                    117u8, 110u8, 117u8, 45u8, 116u8, 104u8, 97u8, 105u8, 110u8, 117u8, 45u8,
+                    116u8, 105u8, 98u8, 116u8, 110u8, 117u8, 45u8, 116u8, 105u8, 114u8, 104u8,
+                    110u8, 117u8, 45u8, 116u8, 114u8, 97u8, 100u8, 105u8, 116u8, 105u8, 111u8,
+                    110u8, 117u8, 45u8, 118u8, 97u8, 105u8, 105u8, 110u8, 117u8, 45u8, 119u8, 97u8,
+                    114u8, 97u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    143u8, 0u8, 0u8, 0u8, 0u8, 0u8, 49u8, 0u8, 95u8, 0u8, 144u8, 0u8, 196u8, 0u8,
+                    24u8, 1u8, 79u8, 1u8, 149u8, 1u8, 201u8, 1u8, 13u8, 2u8, 68u8, 2u8, 133u8, 2u8,
+                    233u8, 2u8, 107u8, 3u8, 194u8, 3u8, 235u8, 3u8, 31u8, 4u8, 80u8, 4u8, 135u8,
+                    4u8, 197u8, 4u8, 250u8, 4u8, 71u8, 5u8, 172u8, 5u8, 228u8, 5u8, 50u8, 6u8,
+                    94u8, 6u8, 187u8, 6u8, 17u8, 7u8, 63u8, 7u8, 132u8, 7u8, 175u8, 7u8, 234u8,
+                    7u8, 52u8, 8u8, 192u8, 8u8, 245u8, 8u8, 38u8, 9u8, 97u8, 9u8, 178u8, 9u8,
+                    218u8, 9u8, 21u8, 10u8, 80u8, 10u8, 139u8, 10u8, 198u8, 10u8, 245u8, 10u8,
+                    58u8, 11u8, 145u8, 11u8, 217u8, 11u8, 21u8, 12u8, 78u8, 12u8, 148u8, 12u8,
+                    237u8, 12u8, 69u8, 13u8, 141u8, 13u8, 225u8, 13u8, 41u8, 14u8, 131u8, 14u8,
+                    175u8, 14u8, 1u8, 15u8, 54u8, 15u8, 136u8, 15u8, 231u8, 15u8, 32u8, 16u8, 95u8,
+                    16u8, 152u8, 16u8, 195u8, 16u8, 13u8, 17u8, 118u8, 17u8, 146u8, 17u8, 199u8,
+                    17u8, 22u8, 18u8, 68u8, 18u8, 139u8, 18u8, 182u8, 18u8, 216u8, 18u8, 3u8, 19u8,
+                    37u8, 19u8, 65u8, 19u8, 105u8, 19u8, 148u8, 19u8, 194u8, 19u8, 252u8, 19u8,
+                    46u8, 20u8, 92u8, 20u8, 148u8, 20u8, 182u8, 20u8, 242u8, 20u8, 26u8, 21u8,
+                    69u8, 21u8, 119u8, 21u8, 175u8, 21u8, 250u8, 21u8, 62u8, 22u8, 146u8, 22u8,
+                    183u8, 22u8, 227u8, 22u8, 11u8, 23u8, 48u8, 23u8, 104u8, 23u8, 148u8, 23u8,
+                    182u8, 23u8, 219u8, 23u8, 14u8, 24u8, 62u8, 24u8, 90u8, 24u8, 139u8, 24u8,
+                    173u8, 24u8, 210u8, 24u8, 10u8, 25u8, 85u8, 25u8, 153u8, 25u8, 247u8, 25u8,
+                    69u8, 26u8, 112u8, 26u8, 143u8, 26u8, 189u8, 26u8, 220u8, 26u8, 17u8, 27u8,
+                    63u8, 27u8, 119u8, 27u8, 194u8, 27u8, 237u8, 27u8, 15u8, 28u8, 53u8, 28u8,
+                    93u8, 28u8, 136u8, 28u8, 170u8, 28u8, 229u8, 28u8, 25u8, 29u8, 59u8, 29u8,
+                    102u8, 29u8, 149u8, 29u8, 199u8, 29u8, 242u8, 29u8, 23u8, 30u8, 74u8, 30u8,
+                    139u8, 30u8, 173u8, 30u8, 210u8, 30u8, 238u8, 30u8, 22u8, 31u8, 62u8, 31u8,
+                    117u8, 31u8, 145u8, 31u8, 224u8, 166u8, 172u8, 224u8, 167u8, 140u8, 224u8,
+                    166u8, 166u8, 224u8, 167u8, 141u8, 224u8, 166u8, 167u8, 32u8, 224u8, 166u8,
+                    149u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 178u8, 224u8, 167u8, 135u8, 224u8, 166u8, 168u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 161u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 166u8,
+                    154u8, 224u8, 167u8, 128u8, 224u8, 166u8, 168u8, 224u8, 166u8, 190u8, 32u8,
+                    224u8, 166u8, 149u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 178u8, 224u8, 167u8, 135u8, 224u8, 166u8, 168u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 161u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8,
+                    224u8, 166u8, 149u8, 224u8, 166u8, 170u8, 224u8, 166u8, 159u8, 224u8, 166u8,
+                    191u8, 224u8, 166u8, 149u8, 32u8, 224u8, 166u8, 149u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 178u8, 224u8, 167u8,
+                    135u8, 224u8, 166u8, 168u8, 224u8, 167u8, 141u8, 224u8, 166u8, 161u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 166u8, 166u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 153u8, 224u8, 167u8, 141u8, 224u8, 166u8, 151u8, 224u8, 167u8,
+                    128u8, 32u8, 224u8, 166u8, 149u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 178u8, 224u8, 167u8, 135u8, 224u8, 166u8,
+                    168u8, 224u8, 167u8, 141u8, 224u8, 166u8, 161u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 176u8, 224u8, 166u8, 135u8, 224u8, 166u8, 165u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 147u8, 224u8, 166u8, 170u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    147u8, 32u8, 224u8, 166u8, 134u8, 224u8, 166u8, 174u8, 224u8, 167u8, 135u8,
+                    224u8, 166u8, 164u8, 224u8, 167u8, 135u8, 32u8, 224u8, 166u8, 134u8, 224u8,
+                    166u8, 178u8, 224u8, 167u8, 135u8, 224u8, 166u8, 174u8, 32u8, 224u8, 166u8,
+                    149u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 178u8, 224u8, 167u8, 135u8, 224u8, 166u8, 168u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 161u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 166u8,
+                    135u8, 224u8, 166u8, 165u8, 224u8, 166u8, 191u8, 224u8, 166u8, 147u8, 224u8,
+                    166u8, 170u8, 224u8, 166u8, 191u8, 224u8, 166u8, 149u8, 32u8, 224u8, 166u8,
+                    149u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 178u8, 224u8, 167u8, 135u8, 224u8, 166u8, 168u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 161u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 166u8,
+                    151u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 191u8, 224u8,
+                    166u8, 151u8, 224u8, 167u8, 139u8, 224u8, 166u8, 176u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    168u8, 32u8, 224u8, 166u8, 149u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 178u8, 224u8, 167u8, 135u8, 224u8, 166u8,
+                    168u8, 224u8, 167u8, 141u8, 224u8, 166u8, 161u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 176u8, 224u8, 166u8, 185u8, 224u8, 166u8, 191u8, 224u8, 166u8, 172u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 167u8, 129u8, 32u8, 224u8,
+                    166u8, 149u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 178u8, 224u8, 167u8, 135u8, 224u8, 166u8, 168u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 161u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8,
+                    166u8, 173u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 166u8, 164u8,
+                    224u8, 167u8, 128u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 32u8, 224u8,
+                    166u8, 156u8, 224u8, 166u8, 190u8, 224u8, 166u8, 164u8, 224u8, 167u8, 128u8,
+                    224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 32u8, 224u8, 166u8, 172u8, 224u8,
+                    166u8, 176u8, 224u8, 167u8, 141u8, 224u8, 166u8, 183u8, 224u8, 166u8, 170u8,
+                    224u8, 166u8, 158u8, 224u8, 167u8, 141u8, 224u8, 166u8, 156u8, 224u8, 167u8,
+                    128u8, 224u8, 166u8, 135u8, 224u8, 166u8, 184u8, 224u8, 166u8, 178u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8, 224u8, 166u8, 149u8,
+                    32u8, 224u8, 166u8, 149u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 178u8, 224u8, 167u8, 135u8, 224u8, 166u8, 168u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 161u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    176u8, 224u8, 166u8, 135u8, 224u8, 166u8, 184u8, 224u8, 166u8, 178u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8, 224u8, 166u8, 149u8,
+                    45u8, 224u8, 166u8, 184u8, 224u8, 166u8, 191u8, 224u8, 166u8, 173u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 178u8, 32u8, 224u8, 166u8, 172u8, 224u8, 166u8,
+                    176u8, 224u8, 167u8, 141u8, 224u8, 166u8, 183u8, 224u8, 166u8, 170u8, 224u8,
+                    166u8, 158u8, 224u8, 167u8, 141u8, 224u8, 166u8, 156u8, 224u8, 167u8, 128u8,
+                    224u8, 166u8, 135u8, 224u8, 166u8, 184u8, 224u8, 166u8, 178u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8, 224u8, 166u8, 149u8, 32u8,
+                    224u8, 166u8, 172u8, 224u8, 166u8, 176u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                    183u8, 224u8, 166u8, 170u8, 224u8, 166u8, 158u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 156u8, 224u8, 167u8, 128u8, 32u8, 40u8, 224u8, 166u8, 184u8, 224u8,
+                    167u8, 140u8, 224u8, 166u8, 166u8, 224u8, 166u8, 191u8, 32u8, 224u8, 166u8,
+                    134u8, 224u8, 166u8, 176u8, 224u8, 166u8, 172u8, 44u8, 32u8, 224u8, 166u8,
+                    166u8, 224u8, 167u8, 131u8, 224u8, 166u8, 182u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 175u8, 224u8, 166u8, 174u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8,
+                    41u8, 224u8, 166u8, 135u8, 224u8, 166u8, 184u8, 224u8, 166u8, 178u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8, 224u8, 166u8, 149u8,
+                    32u8, 224u8, 166u8, 172u8, 224u8, 166u8, 176u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 183u8, 224u8, 166u8, 170u8, 224u8, 166u8, 158u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 156u8, 224u8, 167u8, 128u8, 32u8, 40u8, 224u8, 166u8, 155u8,
+                    224u8, 166u8, 149u8, 224u8, 166u8, 172u8, 224u8, 166u8, 166u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 167u8, 44u8, 32u8, 224u8, 166u8, 156u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 175u8, 224u8, 167u8, 139u8, 224u8, 166u8, 164u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 176u8, 224u8, 167u8, 141u8, 224u8, 166u8, 172u8,
+                    224u8, 166u8, 191u8, 224u8, 166u8, 166u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                    175u8, 224u8, 167u8, 128u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 32u8,
+                    224u8, 166u8, 175u8, 224u8, 167u8, 129u8, 224u8, 166u8, 151u8, 41u8, 224u8,
+                    166u8, 135u8, 224u8, 166u8, 184u8, 224u8, 166u8, 178u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 174u8, 224u8, 166u8, 191u8, 224u8, 166u8, 149u8, 32u8, 224u8,
+                    166u8, 172u8, 224u8, 166u8, 176u8, 224u8, 167u8, 141u8, 224u8, 166u8, 183u8,
+                    224u8, 166u8, 170u8, 224u8, 166u8, 158u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                    156u8, 224u8, 167u8, 128u8, 32u8, 40u8, 224u8, 166u8, 137u8, 224u8, 166u8,
+                    174u8, 224u8, 167u8, 141u8, 224u8, 166u8, 174u8, 224u8, 166u8, 190u8, 32u8,
+                    224u8, 166u8, 134u8, 224u8, 166u8, 178u8, 45u8, 224u8, 166u8, 149u8, 224u8,
+                    167u8, 129u8, 224u8, 166u8, 176u8, 224u8, 166u8, 134u8, 41u8, 73u8, 83u8, 79u8,
+                    45u8, 56u8, 54u8, 49u8, 32u8, 224u8, 166u8, 149u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 178u8, 224u8, 167u8, 135u8,
+                    224u8, 166u8, 168u8, 224u8, 167u8, 141u8, 224u8, 166u8, 161u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 176u8, 224u8, 166u8, 156u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 170u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 224u8, 166u8, 191u8,
+                    32u8, 224u8, 166u8, 149u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 178u8, 224u8, 167u8, 135u8, 224u8, 166u8, 168u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 161u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    176u8, 224u8, 166u8, 171u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8,
+                    166u8, 184u8, 224u8, 166u8, 191u8, 32u8, 224u8, 166u8, 149u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 178u8, 224u8,
+                    167u8, 135u8, 224u8, 166u8, 168u8, 224u8, 167u8, 141u8, 224u8, 166u8, 161u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 166u8, 174u8, 224u8, 166u8,
+                    191u8, 224u8, 166u8, 153u8, 224u8, 167u8, 141u8, 224u8, 166u8, 151u8, 224u8,
+                    167u8, 129u8, 224u8, 166u8, 147u8, 32u8, 224u8, 166u8, 149u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 178u8, 224u8,
+                    167u8, 135u8, 224u8, 166u8, 168u8, 224u8, 167u8, 141u8, 224u8, 166u8, 161u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 166u8, 185u8, 224u8, 166u8,
+                    191u8, 224u8, 166u8, 184u8, 224u8, 166u8, 190u8, 224u8, 166u8, 172u8, 224u8,
+                    167u8, 135u8, 224u8, 166u8, 176u8, 32u8, 224u8, 166u8, 174u8, 224u8, 167u8,
+                    129u8, 224u8, 166u8, 166u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8,
+                    166u8, 190u8, 32u8, 224u8, 166u8, 172u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    168u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 184u8, 224u8, 166u8, 174u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8,
+                    224u8, 166u8, 149u8, 32u8, 224u8, 166u8, 174u8, 224u8, 167u8, 129u8, 224u8,
+                    166u8, 166u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 190u8,
+                    32u8, 224u8, 166u8, 172u8, 224u8, 166u8, 191u8, 224u8, 166u8, 168u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 184u8,
+                    224u8, 166u8, 170u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8,
+                    165u8, 224u8, 166u8, 190u8, 224u8, 166u8, 151u8, 224u8, 166u8, 164u8, 32u8,
+                    224u8, 166u8, 154u8, 224u8, 167u8, 128u8, 224u8, 166u8, 168u8, 224u8, 166u8,
+                    190u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 156u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 156u8, 224u8, 166u8, 190u8, 224u8, 166u8, 149u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 174u8, 32u8, 45u8, 32u8, 224u8,
+                    166u8, 172u8, 224u8, 166u8, 191u8, 224u8, 166u8, 151u8, 224u8, 167u8, 171u8,
+                    224u8, 166u8, 134u8, 224u8, 166u8, 151u8, 224u8, 167u8, 135u8, 224u8, 166u8,
+                    176u8, 32u8, 224u8, 166u8, 172u8, 224u8, 166u8, 190u8, 224u8, 166u8, 155u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 135u8, 224u8, 166u8, 175u8, 224u8, 166u8,
+                    188u8, 224u8, 167u8, 135u8, 224u8, 166u8, 176u8, 32u8, 224u8, 166u8, 149u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 174u8, 44u8, 32u8,
+                    224u8, 166u8, 184u8, 224u8, 166u8, 190u8, 224u8, 166u8, 174u8, 224u8, 166u8,
+                    158u8, 224u8, 167u8, 141u8, 224u8, 166u8, 156u8, 224u8, 166u8, 184u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 167u8, 135u8, 224u8, 166u8, 176u8,
+                    32u8, 224u8, 166u8, 156u8, 224u8, 166u8, 168u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 175u8, 224u8, 166u8, 133u8, 224u8, 166u8, 173u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 167u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 32u8, 224u8,
+                    166u8, 172u8, 224u8, 166u8, 190u8, 224u8, 166u8, 155u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 135u8, 32u8, 224u8, 166u8, 172u8, 224u8, 166u8, 191u8, 224u8,
+                    166u8, 168u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 184u8, 224u8, 166u8, 161u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    171u8, 224u8, 166u8, 178u8, 224u8, 167u8, 141u8, 224u8, 166u8, 159u8, 32u8,
+                    224u8, 166u8, 135u8, 224u8, 166u8, 137u8, 224u8, 166u8, 168u8, 224u8, 166u8,
+                    191u8, 224u8, 166u8, 149u8, 224u8, 167u8, 139u8, 224u8, 166u8, 161u8, 32u8,
+                    224u8, 166u8, 172u8, 224u8, 166u8, 190u8, 224u8, 166u8, 155u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 135u8, 32u8, 224u8, 166u8, 172u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 168u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 184u8, 224u8, 166u8, 135u8, 224u8, 166u8, 174u8, 224u8,
+                    167u8, 139u8, 224u8, 166u8, 156u8, 224u8, 166u8, 191u8, 32u8, 224u8, 166u8,
+                    172u8, 224u8, 166u8, 190u8, 224u8, 166u8, 155u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 135u8, 32u8, 224u8, 166u8, 149u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                    176u8, 224u8, 166u8, 174u8, 224u8, 166u8, 135u8, 224u8, 166u8, 137u8, 224u8,
+                    166u8, 176u8, 224u8, 167u8, 139u8, 224u8, 166u8, 170u8, 224u8, 167u8, 128u8,
+                    224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 32u8, 224u8, 166u8, 149u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 174u8, 32u8, 224u8, 166u8,
+                    172u8, 224u8, 166u8, 191u8, 224u8, 166u8, 168u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 184u8, 224u8, 167u8, 135u8,
+                    224u8, 166u8, 176u8, 32u8, 224u8, 166u8, 168u8, 224u8, 166u8, 191u8, 224u8,
+                    166u8, 175u8, 224u8, 166u8, 188u8, 224u8, 166u8, 174u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 172u8, 224u8, 166u8, 178u8, 224u8, 167u8, 128u8, 224u8, 166u8,
+                    184u8, 224u8, 166u8, 190u8, 224u8, 166u8, 167u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 176u8, 224u8, 166u8, 163u8, 32u8, 224u8, 166u8, 154u8, 224u8, 167u8,
+                    128u8, 224u8, 166u8, 168u8, 224u8, 166u8, 190u8, 32u8, 224u8, 166u8, 184u8,
+                    224u8, 166u8, 156u8, 224u8, 167u8, 141u8, 224u8, 166u8, 156u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 149u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8,
+                    166u8, 174u8, 32u8, 45u8, 32u8, 224u8, 166u8, 156u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 172u8, 224u8, 166u8, 191u8, 224u8, 167u8, 168u8, 224u8, 167u8,
+                    169u8, 224u8, 167u8, 167u8, 224u8, 167u8, 168u8, 224u8, 166u8, 171u8, 224u8,
+                    167u8, 139u8, 224u8, 166u8, 168u8, 224u8, 166u8, 172u8, 224u8, 167u8, 129u8,
+                    224u8, 166u8, 149u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 156u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 156u8, 224u8, 166u8, 190u8, 224u8, 166u8, 149u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 174u8, 224u8, 166u8,
+                    167u8, 224u8, 167u8, 141u8, 224u8, 166u8, 172u8, 224u8, 166u8, 168u8, 224u8,
+                    166u8, 191u8, 32u8, 224u8, 166u8, 168u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    176u8, 224u8, 167u8, 141u8, 224u8, 166u8, 166u8, 224u8, 167u8, 135u8, 224u8,
+                    166u8, 182u8, 224u8, 166u8, 149u8, 32u8, 224u8, 166u8, 172u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 155u8, 224u8, 166u8, 190u8, 224u8, 166u8, 135u8, 32u8,
+                    224u8, 166u8, 149u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8,
+                    174u8, 224u8, 166u8, 170u8, 224u8, 166u8, 191u8, 224u8, 166u8, 168u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 168u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8,
+                    156u8, 224u8, 167u8, 141u8, 224u8, 166u8, 156u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 149u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 174u8,
+                    224u8, 166u8, 176u8, 224u8, 166u8, 191u8, 224u8, 166u8, 171u8, 224u8, 166u8,
+                    176u8, 224u8, 167u8, 141u8, 224u8, 166u8, 174u8, 224u8, 166u8, 161u8, 32u8,
+                    224u8, 166u8, 172u8, 224u8, 166u8, 190u8, 224u8, 166u8, 155u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 135u8, 32u8, 224u8, 166u8, 172u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 168u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 184u8, 224u8, 166u8, 184u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 167u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 166u8, 163u8,
+                    45u8, 224u8, 166u8, 137u8, 224u8, 166u8, 166u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 166u8, 224u8, 167u8, 135u8, 224u8, 166u8, 182u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 175u8, 224u8, 167u8, 135u8, 32u8, 224u8, 166u8, 133u8, 224u8,
+                    166u8, 168u8, 224u8, 167u8, 129u8, 224u8, 166u8, 184u8, 224u8, 166u8, 168u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 167u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    168u8, 224u8, 166u8, 185u8, 224u8, 166u8, 190u8, 224u8, 166u8, 153u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 151u8, 224u8, 167u8, 129u8, 224u8, 166u8, 178u8,
+                    32u8, 224u8, 166u8, 170u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 165u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 149u8, 32u8, 224u8, 166u8, 172u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 175u8, 224u8, 166u8, 158u8, 224u8, 167u8, 141u8, 224u8, 166u8, 156u8,
+                    224u8, 166u8, 168u8, 224u8, 166u8, 172u8, 224u8, 166u8, 176u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 163u8, 32u8, 224u8, 166u8, 166u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 172u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 166u8,
+                    190u8, 32u8, 224u8, 166u8, 133u8, 224u8, 166u8, 168u8, 224u8, 167u8, 129u8,
+                    224u8, 166u8, 184u8, 224u8, 166u8, 168u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                    167u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 32u8, 224u8, 166u8, 149u8,
+                    224u8, 166u8, 176u8, 224u8, 167u8, 129u8, 224u8, 166u8, 168u8, 224u8, 166u8,
+                    134u8, 224u8, 166u8, 166u8, 224u8, 166u8, 176u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 182u8, 32u8, 224u8, 166u8, 172u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    155u8, 224u8, 166u8, 190u8, 224u8, 166u8, 135u8, 32u8, 224u8, 166u8, 172u8,
+                    224u8, 166u8, 191u8, 224u8, 166u8, 168u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                    175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 184u8, 224u8, 166u8, 134u8, 224u8,
+                    166u8, 172u8, 224u8, 166u8, 176u8, 224u8, 167u8, 141u8, 224u8, 166u8, 164u8,
+                    224u8, 166u8, 191u8, 224u8, 166u8, 164u8, 32u8, 224u8, 166u8, 184u8, 224u8,
+                    166u8, 156u8, 224u8, 167u8, 141u8, 224u8, 166u8, 156u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 149u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8,
+                    174u8, 224u8, 166u8, 170u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8,
+                    166u8, 165u8, 224u8, 166u8, 190u8, 224u8, 166u8, 151u8, 224u8, 166u8, 164u8,
+                    32u8, 224u8, 166u8, 172u8, 224u8, 166u8, 190u8, 224u8, 166u8, 155u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 135u8, 32u8, 224u8, 166u8, 172u8, 224u8, 166u8,
+                    191u8, 224u8, 166u8, 168u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 184u8, 224u8, 166u8, 176u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 161u8, 224u8, 166u8, 191u8, 224u8, 166u8, 149u8, 224u8, 167u8,
+                    135u8, 224u8, 166u8, 178u8, 32u8, 224u8, 166u8, 184u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 159u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 167u8,
+                    139u8, 224u8, 166u8, 149u8, 32u8, 224u8, 166u8, 172u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 155u8, 224u8, 166u8, 190u8, 224u8, 166u8, 135u8, 32u8, 224u8,
+                    166u8, 172u8, 224u8, 166u8, 191u8, 224u8, 166u8, 168u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 184u8, 224u8, 166u8,
+                    157u8, 224u8, 167u8, 129u8, 224u8, 166u8, 135u8, 224u8, 166u8, 168u8, 32u8,
+                    224u8, 166u8, 184u8, 224u8, 166u8, 156u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                    156u8, 224u8, 166u8, 190u8, 224u8, 166u8, 149u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 176u8, 224u8, 166u8, 174u8, 224u8, 167u8, 167u8, 224u8, 167u8, 168u8,
+                    32u8, 224u8, 166u8, 152u8, 224u8, 166u8, 163u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 159u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 32u8, 224u8, 166u8,
+                    185u8, 224u8, 166u8, 191u8, 224u8, 166u8, 184u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 172u8, 224u8, 167u8, 135u8, 32u8, 40u8, 224u8, 167u8, 166u8, 226u8,
+                    128u8, 147u8, 224u8, 167u8, 167u8, 224u8, 167u8, 167u8, 41u8, 224u8, 167u8,
+                    167u8, 224u8, 167u8, 168u8, 32u8, 224u8, 166u8, 152u8, 224u8, 166u8, 163u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 159u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    176u8, 32u8, 224u8, 166u8, 185u8, 224u8, 166u8, 191u8, 224u8, 166u8, 184u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 172u8, 224u8, 167u8, 135u8, 32u8, 40u8,
+                    224u8, 167u8, 167u8, 226u8, 128u8, 147u8, 224u8, 167u8, 167u8, 224u8, 167u8,
+                    168u8, 41u8, 224u8, 167u8, 168u8, 224u8, 167u8, 170u8, 32u8, 224u8, 166u8,
+                    152u8, 224u8, 166u8, 163u8, 224u8, 167u8, 141u8, 224u8, 166u8, 159u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 176u8, 32u8, 224u8, 166u8, 185u8, 224u8, 166u8,
+                    191u8, 224u8, 166u8, 184u8, 224u8, 166u8, 190u8, 224u8, 166u8, 172u8, 224u8,
+                    167u8, 135u8, 32u8, 40u8, 224u8, 167u8, 166u8, 226u8, 128u8, 147u8, 224u8,
+                    167u8, 168u8, 224u8, 167u8, 169u8, 41u8, 224u8, 167u8, 168u8, 224u8, 167u8,
+                    170u8, 32u8, 224u8, 166u8, 152u8, 224u8, 166u8, 163u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 159u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 32u8, 224u8,
+                    166u8, 185u8, 224u8, 166u8, 191u8, 224u8, 166u8, 184u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 172u8, 224u8, 167u8, 135u8, 32u8, 40u8, 224u8, 167u8, 167u8,
+                    226u8, 128u8, 147u8, 224u8, 167u8, 168u8, 224u8, 167u8, 170u8, 41u8, 224u8,
+                    166u8, 170u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 164u8,
+                    224u8, 167u8, 128u8, 224u8, 166u8, 149u8, 32u8, 224u8, 166u8, 172u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 155u8, 224u8, 166u8, 190u8, 224u8, 166u8, 135u8,
+                    32u8, 224u8, 166u8, 149u8, 224u8, 166u8, 176u8, 224u8, 167u8, 129u8, 224u8,
+                    166u8, 168u8, 224u8, 166u8, 137u8, 224u8, 166u8, 170u8, 224u8, 167u8, 135u8,
+                    224u8, 166u8, 149u8, 224u8, 167u8, 141u8, 224u8, 166u8, 183u8, 224u8, 166u8,
+                    190u8, 32u8, 224u8, 166u8, 170u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8,
+                    224u8, 166u8, 164u8, 224u8, 167u8, 128u8, 224u8, 166u8, 149u8, 32u8, 224u8,
+                    166u8, 172u8, 224u8, 166u8, 190u8, 224u8, 166u8, 155u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 135u8, 32u8, 224u8, 166u8, 149u8, 224u8, 166u8, 176u8, 224u8,
+                    167u8, 129u8, 224u8, 166u8, 168u8, 224u8, 166u8, 184u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 172u8, 224u8, 166u8, 176u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    152u8, 224u8, 166u8, 190u8, 224u8, 166u8, 164u8, 32u8, 224u8, 166u8, 184u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 167u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    176u8, 224u8, 166u8, 163u8, 224u8, 166u8, 164u8, 224u8, 166u8, 173u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 172u8, 224u8, 167u8, 135u8, 32u8, 224u8, 166u8,
+                    172u8, 224u8, 166u8, 190u8, 224u8, 166u8, 155u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 135u8, 32u8, 224u8, 166u8, 149u8, 224u8, 166u8, 176u8, 224u8, 167u8,
+                    129u8, 224u8, 166u8, 168u8, 224u8, 166u8, 184u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 172u8, 224u8, 166u8, 176u8, 224u8, 166u8, 190u8, 224u8, 166u8, 152u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 164u8, 32u8, 224u8, 166u8, 172u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 170u8, 224u8, 166u8, 176u8, 224u8, 167u8, 128u8,
+                    224u8, 166u8, 164u8, 32u8, 224u8, 166u8, 172u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 155u8, 224u8, 166u8, 190u8, 224u8, 166u8, 135u8, 32u8, 224u8, 166u8,
+                    149u8, 224u8, 166u8, 176u8, 224u8, 167u8, 129u8, 224u8, 166u8, 168u8, 224u8,
+                    166u8, 149u8, 224u8, 167u8, 135u8, 224u8, 166u8, 184u8, 32u8, 224u8, 166u8,
+                    133u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 172u8, 224u8,
+                    167u8, 135u8, 224u8, 166u8, 166u8, 224u8, 167u8, 128u8, 32u8, 224u8, 166u8,
+                    172u8, 224u8, 166u8, 190u8, 224u8, 166u8, 155u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 135u8, 32u8, 224u8, 166u8, 149u8, 224u8, 166u8, 176u8, 224u8, 167u8,
+                    129u8, 224u8, 166u8, 168u8, 224u8, 166u8, 149u8, 224u8, 167u8, 135u8, 224u8,
+                    166u8, 184u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8, 166u8,
+                    172u8, 224u8, 167u8, 135u8, 224u8, 166u8, 166u8, 224u8, 167u8, 128u8, 32u8,
+                    224u8, 166u8, 172u8, 224u8, 166u8, 190u8, 224u8, 166u8, 155u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 135u8, 32u8, 224u8, 166u8, 149u8, 224u8, 166u8, 176u8,
+                    224u8, 167u8, 129u8, 224u8, 166u8, 168u8, 224u8, 166u8, 184u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 167u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8,
+                    166u8, 168u8, 32u8, 224u8, 166u8, 149u8, 224u8, 167u8, 135u8, 224u8, 166u8,
+                    184u8, 32u8, 224u8, 166u8, 149u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8,
+                    224u8, 166u8, 174u8, 32u8, 224u8, 166u8, 172u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 155u8, 224u8, 166u8, 190u8, 224u8, 166u8, 135u8, 32u8, 224u8, 166u8,
+                    149u8, 224u8, 166u8, 176u8, 224u8, 167u8, 129u8, 224u8, 166u8, 168u8, 224u8,
+                    166u8, 170u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 165u8,
+                    224u8, 166u8, 174u8, 224u8, 167u8, 135u8, 32u8, 224u8, 166u8, 155u8, 224u8,
+                    167u8, 139u8, 224u8, 166u8, 159u8, 32u8, 224u8, 166u8, 185u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 164u8, 224u8, 167u8, 135u8, 224u8, 166u8, 176u8, 32u8,
+                    224u8, 166u8, 133u8, 224u8, 166u8, 149u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                    183u8, 224u8, 166u8, 176u8, 32u8, 224u8, 166u8, 172u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 155u8, 224u8, 166u8, 190u8, 224u8, 166u8, 135u8, 32u8, 224u8,
+                    166u8, 149u8, 224u8, 166u8, 176u8, 224u8, 167u8, 129u8, 224u8, 166u8, 168u8,
+                    224u8, 166u8, 170u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8,
+                    165u8, 224u8, 166u8, 174u8, 224u8, 167u8, 135u8, 32u8, 224u8, 166u8, 172u8,
+                    224u8, 166u8, 161u8, 224u8, 166u8, 188u8, 224u8, 166u8, 185u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 164u8, 224u8, 167u8, 135u8, 224u8, 166u8, 176u8, 32u8,
+                    224u8, 166u8, 133u8, 224u8, 166u8, 149u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                    183u8, 224u8, 166u8, 176u8, 32u8, 224u8, 166u8, 172u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 155u8, 224u8, 166u8, 190u8, 224u8, 166u8, 135u8, 32u8, 224u8,
+                    166u8, 149u8, 224u8, 166u8, 176u8, 224u8, 167u8, 129u8, 224u8, 166u8, 168u8,
+                    224u8, 166u8, 184u8, 224u8, 167u8, 141u8, 224u8, 166u8, 172u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 173u8, 224u8, 166u8, 190u8, 224u8, 166u8, 172u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 149u8, 32u8, 224u8, 166u8, 155u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 161u8, 224u8, 166u8, 188u8, 224u8, 166u8, 190u8, 32u8,
+                    224u8, 166u8, 172u8, 224u8, 166u8, 190u8, 224u8, 166u8, 155u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 135u8, 32u8, 224u8, 166u8, 149u8, 224u8, 166u8, 176u8,
+                    224u8, 167u8, 129u8, 224u8, 166u8, 168u8, 224u8, 166u8, 184u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 172u8, 224u8, 166u8, 190u8, 224u8, 166u8, 173u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 172u8, 224u8, 166u8, 191u8, 224u8, 166u8, 149u8,
+                    224u8, 167u8, 131u8, 224u8, 166u8, 164u8, 32u8, 224u8, 166u8, 135u8, 224u8,
+                    166u8, 137u8, 224u8, 166u8, 168u8, 224u8, 166u8, 191u8, 224u8, 166u8, 149u8,
+                    224u8, 167u8, 139u8, 224u8, 166u8, 161u8, 32u8, 224u8, 166u8, 172u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 155u8, 224u8, 166u8, 190u8, 224u8, 166u8, 135u8,
+                    32u8, 224u8, 166u8, 149u8, 224u8, 166u8, 176u8, 224u8, 167u8, 129u8, 224u8,
+                    166u8, 168u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 150u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 32u8, 224u8,
+                    166u8, 170u8, 224u8, 167u8, 131u8, 224u8, 166u8, 165u8, 224u8, 166u8, 149u8,
+                    224u8, 166u8, 173u8, 224u8, 166u8, 190u8, 224u8, 166u8, 172u8, 224u8, 167u8,
+                    135u8, 32u8, 224u8, 166u8, 172u8, 224u8, 166u8, 190u8, 224u8, 166u8, 155u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 135u8, 32u8, 224u8, 166u8, 149u8, 224u8,
+                    166u8, 176u8, 224u8, 167u8, 129u8, 224u8, 166u8, 168u8, 224u8, 166u8, 184u8,
+                    224u8, 166u8, 130u8, 224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                    175u8, 224u8, 166u8, 190u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8,
+                    224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 184u8, 224u8, 167u8, 130u8, 224u8, 166u8, 154u8, 224u8,
+                    166u8, 149u8, 224u8, 166u8, 173u8, 224u8, 166u8, 190u8, 224u8, 166u8, 172u8,
+                    224u8, 167u8, 135u8, 32u8, 224u8, 166u8, 172u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 155u8, 224u8, 166u8, 190u8, 224u8, 166u8, 135u8, 32u8, 224u8, 166u8,
+                    149u8, 224u8, 166u8, 176u8, 224u8, 167u8, 129u8, 224u8, 166u8, 168u8, 224u8,
+                    166u8, 184u8, 224u8, 166u8, 174u8, 224u8, 166u8, 184u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 164u8, 32u8, 224u8, 166u8, 172u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 155u8, 224u8, 166u8, 190u8, 224u8, 166u8, 135u8, 32u8, 224u8, 166u8,
+                    149u8, 224u8, 166u8, 176u8, 224u8, 167u8, 129u8, 224u8, 166u8, 168u8, 224u8,
+                    166u8, 149u8, 224u8, 167u8, 135u8, 224u8, 166u8, 172u8, 224u8, 166u8, 178u8,
+                    32u8, 224u8, 166u8, 172u8, 224u8, 167u8, 129u8, 224u8, 166u8, 168u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 166u8, 224u8, 166u8, 191u8, 32u8, 224u8, 166u8, 172u8, 224u8,
+                    166u8, 176u8, 224u8, 167u8, 141u8, 224u8, 166u8, 163u8, 32u8, 224u8, 166u8,
+                    172u8, 224u8, 166u8, 190u8, 224u8, 166u8, 155u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 135u8, 32u8, 224u8, 166u8, 149u8, 224u8, 166u8, 176u8, 224u8, 167u8,
+                    129u8, 224u8, 166u8, 168u8, 224u8, 166u8, 184u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 172u8, 224u8, 166u8, 176u8, 224u8, 166u8, 190u8, 224u8, 166u8, 152u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 164u8, 32u8, 224u8, 166u8, 172u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 155u8, 224u8, 166u8, 190u8, 224u8, 166u8, 135u8,
+                    32u8, 224u8, 166u8, 149u8, 224u8, 166u8, 176u8, 224u8, 167u8, 129u8, 224u8,
+                    166u8, 168u8, 224u8, 166u8, 184u8, 224u8, 167u8, 141u8, 224u8, 166u8, 172u8,
+                    224u8, 166u8, 176u8, 224u8, 166u8, 190u8, 224u8, 166u8, 152u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 164u8, 47u8, 224u8, 166u8, 149u8, 224u8, 167u8, 135u8,
+                    224u8, 166u8, 184u8, 47u8, 224u8, 166u8, 170u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 176u8, 224u8, 166u8, 184u8, 224u8, 167u8, 141u8, 224u8, 166u8, 165u8,
+                    32u8, 224u8, 166u8, 172u8, 224u8, 166u8, 190u8, 224u8, 166u8, 155u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 135u8, 32u8, 224u8, 166u8, 149u8, 224u8, 166u8,
+                    176u8, 224u8, 167u8, 129u8, 224u8, 166u8, 168u8, 224u8, 166u8, 184u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 172u8, 224u8, 166u8, 176u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 152u8, 224u8, 166u8, 190u8, 224u8, 166u8, 164u8, 47u8, 224u8,
+                    166u8, 149u8, 224u8, 167u8, 135u8, 224u8, 166u8, 184u8, 47u8, 224u8, 166u8,
+                    170u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 184u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 165u8, 47u8, 224u8, 166u8, 149u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 168u8, 224u8, 166u8, 190u8, 32u8, 224u8, 166u8, 172u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 155u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    135u8, 32u8, 224u8, 166u8, 149u8, 224u8, 166u8, 176u8, 224u8, 167u8, 129u8,
+                    224u8, 166u8, 168u8, 224u8, 166u8, 134u8, 224u8, 166u8, 178u8, 224u8, 166u8,
+                    151u8, 224u8, 166u8, 190u8, 32u8, 224u8, 166u8, 178u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 135u8, 224u8, 166u8, 168u8, 32u8, 224u8, 166u8, 172u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 173u8, 224u8, 166u8, 190u8, 224u8, 166u8, 156u8,
+                    224u8, 166u8, 149u8, 32u8, 224u8, 166u8, 182u8, 224u8, 167u8, 136u8, 224u8,
+                    166u8, 178u8, 224u8, 167u8, 128u8, 224u8, 166u8, 184u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 167u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 166u8,
+                    163u8, 32u8, 224u8, 166u8, 178u8, 224u8, 166u8, 190u8, 224u8, 166u8, 135u8,
+                    224u8, 166u8, 168u8, 32u8, 224u8, 166u8, 172u8, 224u8, 166u8, 191u8, 224u8,
+                    166u8, 173u8, 224u8, 166u8, 190u8, 224u8, 166u8, 156u8, 224u8, 166u8, 149u8,
+                    32u8, 224u8, 166u8, 182u8, 224u8, 167u8, 136u8, 224u8, 166u8, 178u8, 224u8,
+                    167u8, 128u8, 224u8, 166u8, 159u8, 224u8, 166u8, 190u8, 224u8, 166u8, 135u8,
+                    224u8, 166u8, 159u8, 32u8, 224u8, 166u8, 178u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 135u8, 224u8, 166u8, 168u8, 32u8, 224u8, 166u8, 172u8, 224u8, 166u8,
+                    191u8, 224u8, 166u8, 173u8, 224u8, 166u8, 190u8, 224u8, 166u8, 156u8, 224u8,
+                    166u8, 149u8, 32u8, 224u8, 166u8, 182u8, 224u8, 167u8, 136u8, 224u8, 166u8,
+                    178u8, 224u8, 167u8, 128u8, 224u8, 166u8, 174u8, 224u8, 167u8, 135u8, 224u8,
+                    166u8, 159u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 149u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 191u8, 224u8,
+                    166u8, 184u8, 224u8, 167u8, 141u8, 224u8, 166u8, 159u8, 224u8, 167u8, 135u8,
+                    224u8, 166u8, 174u8, 224u8, 166u8, 135u8, 224u8, 166u8, 174u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 170u8, 224u8, 167u8, 135u8, 224u8, 166u8, 176u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 178u8, 32u8, 224u8, 166u8, 170u8, 224u8, 166u8, 176u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 174u8, 224u8, 166u8, 190u8, 224u8, 166u8, 170u8,
+                    32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 191u8, 224u8, 166u8, 184u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 159u8, 224u8, 167u8, 135u8, 224u8, 166u8, 174u8,
+                    224u8, 166u8, 174u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 149u8, 224u8, 166u8, 191u8, 224u8, 166u8, 168u8, 32u8,
+                    224u8, 166u8, 175u8, 224u8, 167u8, 129u8, 224u8, 166u8, 149u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 164u8, 224u8, 166u8, 176u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 183u8, 224u8, 167u8, 141u8, 224u8, 166u8, 159u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 176u8, 224u8, 167u8, 135u8, 224u8, 166u8, 176u8, 32u8, 224u8,
+                    166u8, 170u8, 224u8, 166u8, 176u8, 224u8, 166u8, 191u8, 224u8, 166u8, 174u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 170u8, 32u8, 224u8, 166u8, 184u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 184u8, 224u8, 167u8, 141u8, 224u8, 166u8, 159u8,
+                    224u8, 167u8, 135u8, 224u8, 166u8, 174u8, 224u8, 166u8, 133u8, 224u8, 166u8,
+                    185u8, 224u8, 166u8, 174u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8,
+                    224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 134u8, 224u8, 166u8, 176u8, 224u8, 166u8, 172u8, 224u8,
+                    166u8, 191u8, 45u8, 224u8, 166u8, 173u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    176u8, 224u8, 166u8, 164u8, 224u8, 167u8, 128u8, 224u8, 166u8, 175u8, 224u8,
+                    166u8, 188u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8, 166u8,
+                    150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 170u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 184u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    164u8, 32u8, 224u8, 166u8, 134u8, 224u8, 166u8, 176u8, 224u8, 166u8, 172u8,
+                    224u8, 166u8, 191u8, 32u8, 45u8, 224u8, 166u8, 173u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 176u8, 224u8, 166u8, 164u8, 224u8, 167u8, 128u8, 224u8, 166u8,
+                    175u8, 224u8, 166u8, 188u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8,
+                    224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 134u8, 224u8, 166u8, 176u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 174u8, 224u8, 167u8, 135u8, 224u8, 166u8, 168u8, 224u8, 167u8, 128u8,
+                    224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 32u8, 224u8, 166u8, 184u8, 224u8,
+                    166u8, 130u8, 224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 134u8, 224u8, 166u8, 176u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 174u8, 224u8, 167u8, 135u8, 224u8, 166u8, 168u8, 224u8,
+                    167u8, 128u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 32u8, 224u8, 166u8,
+                    155u8, 224u8, 167u8, 139u8, 224u8, 166u8, 159u8, 224u8, 166u8, 185u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 164u8, 224u8, 167u8, 135u8, 224u8, 166u8, 176u8,
+                    32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 150u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 172u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 178u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    168u8, 224u8, 167u8, 128u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 32u8,
+                    224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 150u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 172u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 130u8, 224u8, 166u8, 178u8, 224u8, 166u8, 190u8,
+                    32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 150u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 172u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    185u8, 224u8, 167u8, 141u8, 224u8, 166u8, 174u8, 224u8, 167u8, 128u8, 32u8,
+                    224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 150u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 154u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 149u8, 224u8, 166u8, 174u8, 224u8, 166u8, 190u8,
+                    32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 150u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 154u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 174u8, 32u8, 224u8, 166u8, 184u8, 224u8,
+                    166u8, 130u8, 224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 184u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    176u8, 224u8, 166u8, 191u8, 224u8, 166u8, 178u8, 224u8, 166u8, 191u8, 224u8,
+                    166u8, 149u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8, 166u8,
+                    150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 166u8, 224u8, 167u8, 135u8, 224u8, 166u8, 172u8, 224u8, 166u8, 168u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 151u8, 224u8, 166u8, 176u8, 224u8, 166u8,
+                    191u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 150u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    135u8, 224u8, 166u8, 165u8, 224u8, 166u8, 191u8, 224u8, 166u8, 175u8, 224u8,
+                    166u8, 188u8, 224u8, 167u8, 139u8, 224u8, 166u8, 170u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 149u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8,
+                    166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 133u8, 224u8, 166u8, 176u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                    165u8, 224u8, 166u8, 168u8, 224u8, 167u8, 136u8, 224u8, 166u8, 164u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 149u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8,
+                    130u8, 224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 184u8, 224u8, 167u8, 130u8, 224u8, 166u8, 154u8,
+                    224u8, 166u8, 149u8, 224u8, 166u8, 170u8, 224u8, 167u8, 130u8, 224u8, 166u8,
+                    176u8, 224u8, 167u8, 141u8, 224u8, 166u8, 163u8, 32u8, 224u8, 166u8, 154u8,
+                    224u8, 166u8, 147u8, 224u8, 166u8, 161u8, 224u8, 166u8, 188u8, 224u8, 166u8,
+                    190u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 150u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    156u8, 224u8, 166u8, 176u8, 224u8, 167u8, 141u8, 224u8, 166u8, 156u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 168u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8,
+                    166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 174u8, 224u8, 166u8, 190u8, 224u8, 166u8, 184u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 176u8, 224u8, 166u8, 190u8, 224u8, 166u8, 174u8, 32u8,
+                    224u8, 166u8, 151u8, 224u8, 166u8, 168u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                    161u8, 224u8, 166u8, 191u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8,
+                    224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 151u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 149u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8,
+                    130u8, 224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 151u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8,
+                    224u8, 167u8, 128u8, 224u8, 166u8, 149u8, 32u8, 224u8, 166u8, 155u8, 224u8,
+                    167u8, 139u8, 224u8, 166u8, 159u8, 32u8, 224u8, 166u8, 185u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 164u8, 224u8, 167u8, 135u8, 224u8, 166u8, 176u8, 32u8,
+                    224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 150u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 151u8, 224u8,
+                    167u8, 129u8, 224u8, 166u8, 156u8, 224u8, 166u8, 176u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 164u8, 224u8, 166u8, 191u8, 32u8, 224u8, 166u8, 184u8, 224u8,
+                    166u8, 130u8, 224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 151u8, 224u8, 167u8, 129u8, 224u8, 166u8,
+                    176u8, 224u8, 167u8, 129u8, 224u8, 166u8, 174u8, 224u8, 167u8, 129u8, 224u8,
+                    166u8, 150u8, 224u8, 166u8, 191u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8,
+                    130u8, 224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 154u8, 224u8, 167u8, 136u8, 224u8, 166u8, 168u8,
+                    224u8, 166u8, 191u8, 224u8, 166u8, 149u8, 32u8, 224u8, 166u8, 166u8, 224u8,
+                    166u8, 182u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8, 224u8, 166u8, 149u8,
+                    32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 150u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 184u8,
+                    224u8, 166u8, 176u8, 224u8, 166u8, 178u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    149u8, 224u8, 167u8, 131u8, 224u8, 166u8, 164u8, 32u8, 224u8, 166u8, 154u8,
+                    224u8, 167u8, 136u8, 224u8, 166u8, 168u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    149u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 150u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    184u8, 224u8, 166u8, 176u8, 224u8, 166u8, 178u8, 224u8, 166u8, 191u8, 224u8,
+                    166u8, 149u8, 224u8, 167u8, 131u8, 224u8, 166u8, 164u8, 32u8, 224u8, 166u8,
+                    154u8, 224u8, 167u8, 136u8, 224u8, 166u8, 168u8, 224u8, 166u8, 191u8, 224u8,
+                    166u8, 149u8, 32u8, 224u8, 166u8, 134u8, 224u8, 166u8, 176u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 165u8, 224u8, 166u8, 191u8, 224u8, 166u8, 149u8, 32u8,
+                    224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 150u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 144u8, 224u8,
+                    166u8, 164u8, 224u8, 166u8, 191u8, 224u8, 166u8, 185u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 172u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 185u8, 224u8, 167u8, 128u8, 32u8, 224u8, 166u8, 154u8,
+                    224u8, 167u8, 136u8, 224u8, 166u8, 168u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    149u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 150u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    144u8, 224u8, 166u8, 164u8, 224u8, 166u8, 191u8, 224u8, 166u8, 185u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 172u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 185u8, 224u8, 167u8, 128u8, 32u8, 224u8, 166u8, 154u8, 224u8,
+                    167u8, 136u8, 224u8, 166u8, 168u8, 224u8, 166u8, 191u8, 224u8, 166u8, 149u8,
+                    32u8, 224u8, 166u8, 134u8, 224u8, 166u8, 176u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 165u8, 224u8, 166u8, 191u8, 224u8, 166u8, 149u8, 32u8, 224u8, 166u8,
+                    184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 185u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 172u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 167u8,
+                    129u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 150u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    170u8, 224u8, 166u8, 190u8, 224u8, 166u8, 185u8, 224u8, 166u8, 175u8, 224u8,
+                    166u8, 188u8, 224u8, 166u8, 190u8, 32u8, 224u8, 166u8, 174u8, 224u8, 166u8,
+                    130u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 150u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    156u8, 224u8, 166u8, 190u8, 224u8, 166u8, 173u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 168u8, 224u8, 166u8, 191u8, 224u8, 166u8, 156u8, 32u8, 224u8, 166u8,
+                    184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 156u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 170u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 224u8, 166u8,
+                    191u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 150u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    156u8, 224u8, 166u8, 190u8, 224u8, 166u8, 170u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 168u8, 224u8, 166u8, 191u8, 32u8, 224u8, 166u8, 134u8, 224u8, 166u8,
+                    176u8, 224u8, 167u8, 141u8, 224u8, 166u8, 165u8, 224u8, 166u8, 191u8, 224u8,
+                    166u8, 149u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8, 166u8,
+                    150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 149u8, 224u8, 166u8, 190u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 185u8, 32u8, 224u8, 166u8, 178u8, 224u8,
+                    166u8, 191u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8, 166u8,
+                    150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 150u8, 224u8, 167u8, 135u8, 224u8, 166u8, 174u8, 224u8, 167u8, 135u8,
+                    224u8, 166u8, 176u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8,
+                    166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 149u8, 224u8, 166u8, 168u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                    168u8, 224u8, 166u8, 161u8, 224u8, 166u8, 188u8, 32u8, 224u8, 166u8, 184u8,
+                    224u8, 166u8, 130u8, 224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                    175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 159u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 135u8, 32u8, 224u8, 166u8, 165u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    174u8, 32u8, 224u8, 166u8, 185u8, 224u8, 167u8, 139u8, 224u8, 166u8, 176u8,
+                    224u8, 166u8, 190u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8,
+                    166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 159u8, 224u8, 166u8, 190u8, 224u8, 166u8, 135u8, 32u8, 224u8,
+                    166u8, 165u8, 224u8, 166u8, 190u8, 224u8, 166u8, 174u8, 32u8, 224u8, 166u8,
+                    165u8, 224u8, 166u8, 190u8, 224u8, 166u8, 174u8, 32u8, 224u8, 166u8, 184u8,
+                    224u8, 166u8, 130u8, 224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                    175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 178u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 147u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8, 166u8,
+                    150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 147u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 224u8, 167u8, 135u8,
+                    224u8, 166u8, 184u8, 224u8, 167u8, 141u8, 224u8, 166u8, 159u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 176u8, 224u8, 167u8, 141u8, 224u8, 166u8, 168u8, 32u8,
+                    224u8, 166u8, 161u8, 224u8, 166u8, 191u8, 224u8, 166u8, 156u8, 224u8, 166u8,
+                    191u8, 224u8, 166u8, 159u8, 224u8, 166u8, 178u8, 224u8, 167u8, 135u8, 224u8,
+                    166u8, 170u8, 224u8, 166u8, 154u8, 224u8, 166u8, 190u8, 32u8, 224u8, 166u8,
+                    184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 178u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 174u8, 224u8, 167u8, 141u8, 224u8, 166u8, 172u8, 224u8, 167u8,
+                    129u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 150u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    151u8, 224u8, 166u8, 190u8, 224u8, 166u8, 163u8, 224u8, 166u8, 191u8, 224u8,
+                    166u8, 164u8, 224u8, 166u8, 191u8, 224u8, 166u8, 149u8, 32u8, 224u8, 166u8,
+                    172u8, 224u8, 167u8, 139u8, 224u8, 166u8, 178u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 161u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8, 166u8,
+                    150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 151u8, 224u8, 166u8, 190u8, 224u8, 166u8, 163u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 164u8, 224u8, 166u8, 191u8, 224u8, 166u8, 149u8, 32u8, 224u8,
+                    166u8, 161u8, 224u8, 166u8, 190u8, 224u8, 166u8, 172u8, 224u8, 166u8, 178u8,
+                    45u8, 224u8, 166u8, 184u8, 224u8, 167u8, 141u8, 224u8, 166u8, 159u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 190u8, 224u8, 166u8, 149u8,
+                    32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 150u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 151u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 163u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    164u8, 224u8, 166u8, 191u8, 224u8, 166u8, 149u8, 32u8, 224u8, 166u8, 174u8,
+                    224u8, 167u8, 139u8, 224u8, 166u8, 168u8, 224u8, 167u8, 139u8, 224u8, 166u8,
+                    184u8, 224u8, 167u8, 141u8, 224u8, 166u8, 170u8, 224u8, 167u8, 135u8, 224u8,
+                    166u8, 184u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8, 166u8,
+                    150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 151u8, 224u8, 166u8, 190u8, 224u8, 166u8, 163u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 164u8, 224u8, 166u8, 191u8, 224u8, 166u8, 149u8, 32u8, 224u8,
+                    166u8, 184u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 168u8, 224u8, 167u8, 141u8, 224u8, 166u8, 184u8, 45u8, 224u8,
+                    166u8, 182u8, 224u8, 167u8, 135u8, 224u8, 166u8, 176u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 171u8, 32u8, 224u8, 166u8, 172u8, 224u8, 167u8, 139u8, 224u8,
+                    166u8, 178u8, 224u8, 167u8, 141u8, 224u8, 166u8, 161u8, 32u8, 224u8, 166u8,
+                    184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 151u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 163u8, 224u8, 166u8, 191u8, 224u8, 166u8, 164u8, 224u8, 166u8,
+                    191u8, 224u8, 166u8, 149u8, 32u8, 224u8, 166u8, 184u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 184u8, 45u8, 224u8, 166u8, 182u8, 224u8, 167u8, 135u8,
+                    224u8, 166u8, 176u8, 224u8, 166u8, 191u8, 224u8, 166u8, 171u8, 32u8, 224u8,
+                    166u8, 184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 150u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 174u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 178u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 178u8, 224u8, 166u8, 174u8, 32u8, 224u8, 166u8,
+                    184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 174u8, 224u8, 167u8, 139u8,
+                    224u8, 166u8, 166u8, 224u8, 166u8, 191u8, 32u8, 224u8, 166u8, 184u8, 224u8,
+                    166u8, 130u8, 224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 174u8, 224u8, 166u8, 153u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 151u8, 224u8, 167u8, 139u8, 224u8, 166u8, 178u8, 224u8,
+                    167u8, 128u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 32u8, 224u8, 166u8,
+                    184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 174u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 176u8, 224u8, 167u8, 139u8, 32u8, 224u8, 166u8, 184u8, 224u8,
+                    166u8, 130u8, 224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    159u8, 224u8, 166u8, 191u8, 224u8, 166u8, 135u8, 32u8, 224u8, 166u8, 174u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 224u8, 167u8,
+                    135u8, 224u8, 166u8, 149u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8,
+                    224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 174u8, 224u8, 166u8, 190u8, 224u8, 166u8, 175u8, 224u8,
+                    166u8, 188u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 224u8, 166u8, 174u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 32u8, 224u8, 166u8, 184u8, 224u8,
+                    166u8, 130u8, 224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 174u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    175u8, 224u8, 166u8, 188u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 224u8,
+                    166u8, 174u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 32u8, 224u8, 166u8,
+                    182u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 32u8, 224u8, 166u8, 184u8,
+                    224u8, 166u8, 130u8, 224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                    175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 174u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 175u8, 224u8, 166u8, 188u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8,
+                    224u8, 166u8, 174u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 32u8, 224u8,
+                    166u8, 165u8, 224u8, 166u8, 190u8, 224u8, 166u8, 135u8, 32u8, 224u8, 166u8,
+                    178u8, 224u8, 167u8, 135u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 130u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8,
+                    130u8, 224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 184u8, 224u8, 167u8, 141u8, 224u8, 166u8, 165u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 224u8, 167u8, 128u8, 224u8, 166u8,
+                    175u8, 224u8, 166u8, 188u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8,
+                    224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 143u8, 224u8, 166u8, 168u8, 226u8, 128u8, 153u8, 224u8,
+                    166u8, 149u8, 224u8, 167u8, 139u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8,
+                    130u8, 224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 147u8, 224u8, 166u8, 178u8, 32u8, 224u8, 166u8,
+                    154u8, 224u8, 166u8, 191u8, 224u8, 166u8, 149u8, 224u8, 166u8, 191u8, 32u8,
+                    224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 150u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 147u8, 224u8,
+                    166u8, 161u8, 224u8, 166u8, 188u8, 224u8, 166u8, 191u8, 224u8, 166u8, 175u8,
+                    224u8, 166u8, 188u8, 224u8, 166u8, 190u8, 32u8, 224u8, 166u8, 184u8, 224u8,
+                    166u8, 130u8, 224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 147u8, 224u8, 166u8, 184u8, 224u8, 166u8,
+                    174u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 224u8, 166u8, 191u8, 224u8,
+                    166u8, 175u8, 224u8, 166u8, 188u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8,
+                    130u8, 224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 167u8, 139u8, 224u8, 166u8, 174u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 32u8, 224u8, 166u8, 184u8, 224u8,
+                    166u8, 130u8, 224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 167u8, 139u8, 224u8, 166u8,
+                    174u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 32u8, 224u8, 166u8, 155u8,
+                    224u8, 167u8, 139u8, 224u8, 166u8, 159u8, 224u8, 166u8, 185u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 164u8, 224u8, 167u8, 135u8, 224u8, 166u8, 176u8, 32u8,
+                    224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 150u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 184u8, 224u8,
+                    167u8, 140u8, 224u8, 166u8, 176u8, 224u8, 166u8, 190u8, 224u8, 166u8, 183u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 159u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                    176u8, 224u8, 167u8, 135u8, 224u8, 166u8, 176u8, 32u8, 224u8, 166u8, 184u8,
+                    224u8, 166u8, 130u8, 224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                    175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 184u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 176u8, 224u8, 166u8, 166u8, 224u8, 166u8, 190u8, 32u8, 224u8, 166u8,
+                    184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 149u8, 224u8, 167u8, 129u8,
+                    224u8, 166u8, 166u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 166u8, 224u8, 166u8, 191u8, 32u8, 224u8, 166u8, 184u8,
+                    224u8, 166u8, 130u8, 224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                    175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 184u8, 224u8, 166u8, 191u8, 224u8,
+                    166u8, 130u8, 224u8, 166u8, 185u8, 224u8, 166u8, 178u8, 224u8, 167u8, 128u8,
+                    32u8, 224u8, 166u8, 178u8, 224u8, 166u8, 191u8, 224u8, 166u8, 165u8, 32u8,
+                    224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 150u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 184u8, 224u8,
+                    167u8, 139u8, 224u8, 166u8, 176u8, 224u8, 166u8, 190u8, 32u8, 224u8, 166u8,
+                    184u8, 224u8, 166u8, 174u8, 224u8, 167u8, 141u8, 224u8, 166u8, 170u8, 224u8,
+                    167u8, 135u8, 224u8, 166u8, 130u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8,
+                    130u8, 224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 184u8, 224u8, 167u8, 129u8, 224u8, 166u8, 166u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 224u8, 167u8, 128u8, 224u8, 166u8,
+                    175u8, 224u8, 166u8, 188u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8,
+                    224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 164u8, 224u8, 166u8, 190u8, 224u8, 166u8, 149u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 191u8, 32u8, 224u8, 166u8,
+                    184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 224u8, 166u8, 164u8,
+                    224u8, 167u8, 129u8, 224u8, 166u8, 168u8, 32u8, 224u8, 166u8, 164u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 135u8, 32u8, 224u8, 166u8, 178u8, 224u8, 166u8,
+                    191u8, 224u8, 166u8, 137u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8,
+                    224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 144u8, 224u8, 166u8, 164u8, 224u8, 166u8, 191u8, 224u8,
+                    166u8, 185u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 172u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 185u8, 224u8, 167u8, 128u8, 32u8, 224u8,
+                    166u8, 164u8, 224u8, 166u8, 190u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 178u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8,
+                    166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 164u8, 224u8, 166u8, 190u8, 224u8, 166u8, 174u8, 224u8, 166u8,
+                    191u8, 224u8, 166u8, 178u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8,
+                    224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 164u8, 224u8, 167u8, 135u8, 224u8, 166u8, 178u8, 224u8,
+                    167u8, 129u8, 224u8, 166u8, 151u8, 224u8, 167u8, 129u8, 32u8, 224u8, 166u8,
+                    184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 165u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 135u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8,
+                    166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 164u8, 224u8, 166u8, 191u8, 224u8, 166u8, 172u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 172u8, 224u8, 166u8, 164u8, 224u8, 166u8, 191u8, 32u8,
+                    224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 150u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 164u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 176u8, 224u8, 166u8, 185u8, 224u8, 167u8, 129u8,
+                    224u8, 166u8, 164u8, 224u8, 166u8, 190u8, 32u8, 224u8, 166u8, 184u8, 224u8,
+                    166u8, 130u8, 224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 144u8, 224u8, 166u8, 164u8, 224u8, 166u8,
+                    191u8, 224u8, 166u8, 185u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8,
+                    166u8, 151u8, 224u8, 166u8, 164u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8,
+                    130u8, 224u8, 166u8, 150u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 184u8, 224u8, 167u8, 130u8, 224u8, 166u8, 154u8,
+                    224u8, 166u8, 149u8, 224u8, 166u8, 173u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    135u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 150u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    147u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 176u8, 224u8, 167u8, 135u8, 224u8, 166u8, 130u8, 32u8, 224u8, 166u8,
+                    184u8, 224u8, 166u8, 191u8, 224u8, 166u8, 159u8, 224u8, 166u8, 191u8, 32u8,
+                    224u8, 166u8, 184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 150u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8,
+                ])
+            },
+        )
+    },
+};
+static CCP: &DataStruct = &::icu_displaynames::provider::KeyDisplayNamesV1 {
+    keys: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    99u8, 97u8, 99u8, 102u8, 99u8, 111u8, 99u8, 117u8, 104u8, 99u8, 107u8, 97u8,
+                    107u8, 98u8, 107u8, 99u8, 107u8, 102u8, 107u8, 107u8, 107u8, 110u8, 107u8,
+                    115u8, 108u8, 98u8, 109u8, 115u8, 110u8, 117u8, 116u8, 122u8, 118u8, 97u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    17u8, 0u8, 0u8, 0u8, 0u8, 0u8, 40u8, 0u8, 105u8, 0u8, 162u8, 0u8, 203u8, 0u8,
+                    37u8, 1u8, 142u8, 1u8, 237u8, 1u8, 52u8, 2u8, 199u8, 2u8, 8u8, 3u8, 69u8, 3u8,
+                    118u8, 3u8, 208u8, 3u8, 253u8, 3u8, 21u8, 4u8, 62u8, 4u8, 240u8, 145u8, 132u8,
+                    135u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8,
+                    132u8, 163u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 154u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 162u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8,
+                    170u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8,
+                    132u8, 152u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 162u8, 32u8,
+                    240u8, 145u8, 132u8, 156u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8,
+                    162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8,
+                    132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 145u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 172u8,
+                    240u8, 145u8, 132u8, 173u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8,
+                    167u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8,
+                    132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 168u8,
+                    240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8,
+                    159u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8,
+                    132u8, 172u8, 240u8, 145u8, 132u8, 139u8, 45u8, 240u8, 145u8, 132u8, 155u8,
+                    240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8,
+                    180u8, 240u8, 145u8, 132u8, 142u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8,
+                    132u8, 160u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 167u8, 240u8,
+                    145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 150u8,
+                    240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8,
+                    180u8, 32u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                    145u8, 132u8, 143u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 155u8,
+                    240u8, 145u8, 132u8, 180u8, 32u8, 40u8, 240u8, 145u8, 132u8, 183u8, 240u8,
+                    145u8, 132u8, 184u8, 32u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8,
+                    179u8, 240u8, 145u8, 132u8, 131u8, 32u8, 240u8, 145u8, 132u8, 184u8, 240u8,
+                    145u8, 132u8, 186u8, 41u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8,
+                    140u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8,
+                    132u8, 172u8, 240u8, 145u8, 132u8, 173u8, 32u8, 240u8, 145u8, 132u8, 151u8,
+                    240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8,
+                    132u8, 141u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 157u8, 240u8,
+                    145u8, 132u8, 168u8, 32u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8,
+                    172u8, 240u8, 145u8, 132u8, 173u8, 32u8, 240u8, 145u8, 132u8, 163u8, 240u8,
+                    145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 158u8, 240u8, 145u8, 132u8, 162u8,
+                    240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8,
+                    132u8, 143u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 133u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 163u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8,
+                    174u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8,
+                    132u8, 179u8, 240u8, 145u8, 132u8, 166u8, 240u8, 145u8, 132u8, 180u8, 32u8,
+                    240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8,
+                    166u8, 240u8, 145u8, 132u8, 167u8, 32u8, 240u8, 145u8, 132u8, 150u8, 240u8,
+                    145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 172u8,
+                    240u8, 145u8, 132u8, 173u8, 32u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8,
+                    132u8, 167u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 135u8, 240u8,
+                    145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 180u8,
+                    32u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8,
+                    132u8, 129u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 172u8, 240u8,
+                    145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 169u8, 32u8, 240u8, 145u8, 132u8,
+                    157u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 173u8, 32u8, 240u8,
+                    145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 154u8,
+                    240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8,
+                    162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 166u8, 240u8, 145u8,
+                    132u8, 152u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8, 240u8,
+                    145u8, 132u8, 180u8, 47u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8,
+                    168u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8,
+                    132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 166u8, 240u8,
+                    145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8,
+                    240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 166u8, 240u8, 145u8,
+                    132u8, 167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 167u8, 240u8,
+                    145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 162u8,
+                    240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8,
+                    132u8, 170u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 173u8, 240u8, 145u8, 132u8, 154u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8,
+                    167u8, 240u8, 145u8, 132u8, 142u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8,
+                    132u8, 131u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 140u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 172u8,
+                    32u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8,
+                    132u8, 173u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 167u8, 240u8,
+                    145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 152u8,
+                    240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8,
+                    167u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8,
+                    132u8, 160u8, 240u8, 145u8, 132u8, 172u8, 32u8, 240u8, 145u8, 132u8, 157u8,
+                    240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 173u8, 240u8, 145u8, 132u8,
+                    163u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8,
+                    132u8, 157u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 173u8, 240u8,
+                    145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 154u8,
+                    240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8,
+                    132u8, 157u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 163u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 131u8,
+                    240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8,
+                    180u8, 32u8, 240u8, 145u8, 132u8, 158u8, 240u8, 145u8, 132u8, 137u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 167u8,
+                    240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8,
+                    162u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 131u8, 240u8,
+                    145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 145u8, 240u8, 145u8, 132u8, 173u8, 240u8, 145u8, 132u8,
+                    163u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8,
+                    132u8, 157u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 162u8, 240u8,
+                    145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8,
+                    168u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8,
+                    132u8, 159u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 154u8, 240u8,
+                    145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 133u8, 240u8, 145u8, 132u8, 170u8,
+                    240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8,
+                    131u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8,
+                    132u8, 180u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 167u8, 32u8,
+                    240u8, 145u8, 132u8, 142u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8,
+                    154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8,
+                    132u8, 168u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 170u8, 240u8,
+                    145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 162u8,
+                    240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8,
+                    132u8, 154u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 157u8, 240u8,
+                    145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 179u8,
+                    240u8, 145u8, 132u8, 160u8,
+                ])
+            },
+        )
+    },
+    types: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    99u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 21u8, 0u8, 30u8, 0u8, 38u8, 0u8,
+                    48u8, 0u8, 59u8, 0u8, 69u8, 0u8, 78u8, 0u8, 87u8, 0u8, 97u8, 0u8, 113u8, 0u8,
+                    123u8, 0u8, 134u8, 0u8, 144u8, 0u8, 150u8, 0u8, 160u8, 0u8, 171u8, 0u8, 181u8,
+                    0u8, 188u8, 0u8, 196u8, 0u8, 205u8, 0u8, 215u8, 0u8, 226u8, 0u8, 235u8, 0u8,
+                    246u8, 0u8, 255u8, 0u8, 10u8, 1u8, 21u8, 1u8, 30u8, 1u8, 37u8, 1u8, 46u8, 1u8,
+                    52u8, 1u8, 58u8, 1u8, 64u8, 1u8, 70u8, 1u8, 81u8, 1u8, 91u8, 1u8, 99u8, 1u8,
+                    106u8, 1u8, 114u8, 1u8, 121u8, 1u8, 129u8, 1u8, 137u8, 1u8, 145u8, 1u8, 153u8,
+                    1u8, 160u8, 1u8, 168u8, 1u8, 175u8, 1u8, 185u8, 1u8, 194u8, 1u8, 203u8, 1u8,
+                    212u8, 1u8, 221u8, 1u8, 229u8, 1u8, 238u8, 1u8, 247u8, 1u8, 0u8, 2u8, 11u8,
+                    2u8, 22u8, 2u8, 29u8, 2u8, 39u8, 2u8, 46u8, 2u8, 56u8, 2u8, 63u8, 2u8, 70u8,
+                    2u8, 77u8, 2u8, 87u8, 2u8, 98u8, 2u8, 105u8, 2u8, 112u8, 2u8, 122u8, 2u8,
+                    129u8, 2u8, 136u8, 2u8, 146u8, 2u8, 153u8, 2u8, 163u8, 2u8, 170u8, 2u8, 180u8,
+                    2u8, 187u8, 2u8, 194u8, 2u8, 204u8, 2u8, 211u8, 2u8, 218u8, 2u8, 225u8, 2u8,
+                    232u8, 2u8, 239u8, 2u8, 246u8, 2u8, 253u8, 2u8, 6u8, 3u8, 13u8, 3u8, 21u8, 3u8,
+                    32u8, 3u8, 39u8, 3u8, 49u8, 3u8, 56u8, 3u8, 63u8, 3u8, 70u8, 3u8, 81u8, 3u8,
+                    99u8, 97u8, 45u8, 98u8, 117u8, 100u8, 100u8, 104u8, 105u8, 115u8, 116u8, 99u8,
+                    97u8, 45u8, 99u8, 104u8, 105u8, 110u8, 101u8, 115u8, 101u8, 99u8, 97u8, 45u8,
+                    99u8, 111u8, 112u8, 116u8, 105u8, 99u8, 99u8, 97u8, 45u8, 100u8, 97u8, 110u8,
+                    103u8, 105u8, 99u8, 97u8, 45u8, 101u8, 116u8, 104u8, 105u8, 111u8, 97u8, 97u8,
+                    99u8, 97u8, 45u8, 101u8, 116u8, 104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 99u8,
+                    97u8, 45u8, 103u8, 114u8, 101u8, 103u8, 111u8, 114u8, 121u8, 99u8, 97u8, 45u8,
+                    104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 99u8, 97u8, 45u8, 105u8, 110u8, 100u8,
+                    105u8, 97u8, 110u8, 99u8, 97u8, 45u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8,
+                    99u8, 99u8, 97u8, 45u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8,
+                    99u8, 105u8, 118u8, 105u8, 108u8, 99u8, 97u8, 45u8, 105u8, 115u8, 111u8, 56u8,
+                    54u8, 48u8, 49u8, 99u8, 97u8, 45u8, 106u8, 97u8, 112u8, 97u8, 110u8, 101u8,
+                    115u8, 101u8, 99u8, 97u8, 45u8, 112u8, 101u8, 114u8, 115u8, 105u8, 97u8, 110u8,
+                    99u8, 97u8, 45u8, 114u8, 111u8, 99u8, 99u8, 102u8, 45u8, 97u8, 99u8, 99u8,
+                    111u8, 117u8, 110u8, 116u8, 99u8, 102u8, 45u8, 115u8, 116u8, 97u8, 110u8,
+                    100u8, 97u8, 114u8, 100u8, 99u8, 111u8, 45u8, 98u8, 105u8, 103u8, 53u8, 104u8,
+                    97u8, 110u8, 99u8, 111u8, 45u8, 100u8, 105u8, 99u8, 116u8, 99u8, 111u8, 45u8,
+                    100u8, 117u8, 99u8, 101u8, 116u8, 99u8, 111u8, 45u8, 103u8, 98u8, 50u8, 51u8,
+                    49u8, 50u8, 99u8, 111u8, 45u8, 112u8, 104u8, 111u8, 110u8, 101u8, 98u8, 107u8,
+                    99u8, 111u8, 45u8, 112u8, 104u8, 111u8, 110u8, 101u8, 116u8, 105u8, 99u8, 99u8,
+                    111u8, 45u8, 112u8, 105u8, 110u8, 121u8, 105u8, 110u8, 99u8, 111u8, 45u8,
+                    114u8, 101u8, 102u8, 111u8, 114u8, 109u8, 101u8, 100u8, 99u8, 111u8, 45u8,
+                    115u8, 101u8, 97u8, 114u8, 99u8, 104u8, 99u8, 111u8, 45u8, 115u8, 101u8, 97u8,
+                    114u8, 99u8, 104u8, 106u8, 108u8, 99u8, 111u8, 45u8, 115u8, 116u8, 97u8, 110u8,
+                    100u8, 97u8, 114u8, 100u8, 99u8, 111u8, 45u8, 115u8, 116u8, 114u8, 111u8,
+                    107u8, 101u8, 99u8, 111u8, 45u8, 116u8, 114u8, 97u8, 100u8, 99u8, 111u8, 45u8,
+                    117u8, 110u8, 105u8, 104u8, 97u8, 110u8, 104u8, 99u8, 45u8, 104u8, 49u8, 49u8,
+                    104u8, 99u8, 45u8, 104u8, 49u8, 50u8, 104u8, 99u8, 45u8, 104u8, 50u8, 51u8,
+                    104u8, 99u8, 45u8, 104u8, 50u8, 52u8, 107u8, 97u8, 45u8, 110u8, 111u8, 105u8,
+                    103u8, 110u8, 111u8, 114u8, 101u8, 107u8, 97u8, 45u8, 115u8, 104u8, 105u8,
+                    102u8, 116u8, 101u8, 100u8, 107u8, 98u8, 45u8, 102u8, 97u8, 108u8, 115u8,
+                    101u8, 107u8, 98u8, 45u8, 116u8, 114u8, 117u8, 101u8, 107u8, 99u8, 45u8, 102u8,
+                    97u8, 108u8, 115u8, 101u8, 107u8, 99u8, 45u8, 116u8, 114u8, 117u8, 101u8,
+                    107u8, 102u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8, 107u8, 102u8, 45u8,
+                    108u8, 111u8, 119u8, 101u8, 114u8, 107u8, 102u8, 45u8, 117u8, 112u8, 112u8,
+                    101u8, 114u8, 107u8, 107u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8, 107u8,
+                    107u8, 45u8, 116u8, 114u8, 117u8, 101u8, 107u8, 110u8, 45u8, 102u8, 97u8,
+                    108u8, 115u8, 101u8, 107u8, 110u8, 45u8, 116u8, 114u8, 117u8, 101u8, 107u8,
+                    115u8, 45u8, 105u8, 100u8, 101u8, 110u8, 116u8, 105u8, 99u8, 107u8, 115u8,
+                    45u8, 108u8, 101u8, 118u8, 101u8, 108u8, 49u8, 107u8, 115u8, 45u8, 108u8,
+                    101u8, 118u8, 101u8, 108u8, 50u8, 107u8, 115u8, 45u8, 108u8, 101u8, 118u8,
+                    101u8, 108u8, 51u8, 107u8, 115u8, 45u8, 108u8, 101u8, 118u8, 101u8, 108u8,
+                    52u8, 108u8, 98u8, 45u8, 108u8, 111u8, 111u8, 115u8, 101u8, 108u8, 98u8, 45u8,
+                    110u8, 111u8, 114u8, 109u8, 97u8, 108u8, 108u8, 98u8, 45u8, 115u8, 116u8,
+                    114u8, 105u8, 99u8, 116u8, 109u8, 115u8, 45u8, 109u8, 101u8, 116u8, 114u8,
+                    105u8, 99u8, 109u8, 115u8, 45u8, 117u8, 107u8, 115u8, 121u8, 115u8, 116u8,
+                    101u8, 109u8, 109u8, 115u8, 45u8, 117u8, 115u8, 115u8, 121u8, 115u8, 116u8,
+                    101u8, 109u8, 110u8, 117u8, 45u8, 97u8, 114u8, 97u8, 98u8, 110u8, 117u8, 45u8,
+                    97u8, 114u8, 97u8, 98u8, 101u8, 120u8, 116u8, 110u8, 117u8, 45u8, 97u8, 114u8,
+                    109u8, 110u8, 110u8, 117u8, 45u8, 97u8, 114u8, 109u8, 110u8, 108u8, 111u8,
+                    119u8, 110u8, 117u8, 45u8, 98u8, 101u8, 110u8, 103u8, 110u8, 117u8, 45u8,
+                    100u8, 101u8, 118u8, 97u8, 110u8, 117u8, 45u8, 101u8, 116u8, 104u8, 105u8,
+                    110u8, 117u8, 45u8, 102u8, 105u8, 110u8, 97u8, 110u8, 99u8, 101u8, 110u8,
+                    117u8, 45u8, 102u8, 117u8, 108u8, 108u8, 119u8, 105u8, 100u8, 101u8, 110u8,
+                    117u8, 45u8, 103u8, 101u8, 111u8, 114u8, 110u8, 117u8, 45u8, 103u8, 114u8,
+                    101u8, 107u8, 110u8, 117u8, 45u8, 103u8, 114u8, 101u8, 107u8, 108u8, 111u8,
+                    119u8, 110u8, 117u8, 45u8, 103u8, 117u8, 106u8, 114u8, 110u8, 117u8, 45u8,
+                    103u8, 117u8, 114u8, 117u8, 110u8, 117u8, 45u8, 104u8, 97u8, 110u8, 105u8,
+                    100u8, 101u8, 99u8, 110u8, 117u8, 45u8, 104u8, 97u8, 110u8, 115u8, 110u8,
+                    117u8, 45u8, 104u8, 97u8, 110u8, 115u8, 102u8, 105u8, 110u8, 110u8, 117u8,
+                    45u8, 104u8, 97u8, 110u8, 116u8, 110u8, 117u8, 45u8, 104u8, 97u8, 110u8, 116u8,
+                    102u8, 105u8, 110u8, 110u8, 117u8, 45u8, 104u8, 101u8, 98u8, 114u8, 110u8,
+                    117u8, 45u8, 106u8, 112u8, 97u8, 110u8, 110u8, 117u8, 45u8, 106u8, 112u8, 97u8,
+                    110u8, 102u8, 105u8, 110u8, 110u8, 117u8, 45u8, 107u8, 104u8, 109u8, 114u8,
+                    110u8, 117u8, 45u8, 107u8, 110u8, 100u8, 97u8, 110u8, 117u8, 45u8, 108u8, 97u8,
+                    111u8, 111u8, 110u8, 117u8, 45u8, 108u8, 97u8, 116u8, 110u8, 110u8, 117u8,
+                    45u8, 109u8, 108u8, 121u8, 109u8, 110u8, 117u8, 45u8, 109u8, 111u8, 110u8,
+                    103u8, 110u8, 117u8, 45u8, 109u8, 121u8, 109u8, 114u8, 110u8, 117u8, 45u8,
+                    110u8, 97u8, 116u8, 105u8, 118u8, 101u8, 110u8, 117u8, 45u8, 111u8, 114u8,
+                    121u8, 97u8, 110u8, 117u8, 45u8, 114u8, 111u8, 109u8, 97u8, 110u8, 110u8,
+                    117u8, 45u8, 114u8, 111u8, 109u8, 97u8, 110u8, 108u8, 111u8, 119u8, 110u8,
+                    117u8, 45u8, 116u8, 97u8, 109u8, 108u8, 110u8, 117u8, 45u8, 116u8, 97u8, 109u8,
+                    108u8, 100u8, 101u8, 99u8, 110u8, 117u8, 45u8, 116u8, 101u8, 108u8, 117u8,
+                    110u8, 117u8, 45u8, 116u8, 104u8, 97u8, 105u8, 110u8, 117u8, 45u8, 116u8,
+                    105u8, 98u8, 116u8, 110u8, 117u8, 45u8, 116u8, 114u8, 97u8, 100u8, 105u8,
+                    116u8, 105u8, 111u8, 110u8, 117u8, 45u8, 118u8, 97u8, 105u8, 105u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    99u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 0u8, 118u8, 0u8, 191u8, 0u8, 252u8, 0u8,
+                    119u8, 1u8, 200u8, 1u8, 25u8, 2u8, 90u8, 2u8, 188u8, 2u8, 9u8, 3u8, 119u8, 3u8,
+                    167u8, 3u8, 224u8, 3u8, 29u8, 4u8, 98u8, 4u8, 196u8, 4u8, 34u8, 5u8, 145u8,
+                    5u8, 223u8, 5u8, 65u8, 6u8, 156u8, 6u8, 201u8, 6u8, 242u8, 6u8, 23u8, 7u8,
+                    80u8, 7u8, 166u8, 7u8, 71u8, 8u8, 129u8, 8u8, 198u8, 8u8, 3u8, 9u8, 97u8, 9u8,
+                    175u8, 9u8, 253u8, 9u8, 74u8, 10u8, 152u8, 10u8, 193u8, 10u8, 11u8, 11u8,
+                    129u8, 11u8, 248u8, 11u8, 70u8, 12u8, 136u8, 12u8, 235u8, 12u8, 107u8, 13u8,
+                    226u8, 13u8, 36u8, 14u8, 134u8, 14u8, 204u8, 14u8, 10u8, 15u8, 63u8, 15u8,
+                    162u8, 15u8, 203u8, 15u8, 26u8, 16u8, 114u8, 16u8, 201u8, 16u8, 48u8, 17u8,
+                    135u8, 17u8, 200u8, 17u8, 42u8, 18u8, 169u8, 18u8, 227u8, 18u8, 83u8, 19u8,
+                    128u8, 19u8, 219u8, 19u8, 240u8, 19u8, 29u8, 20u8, 74u8, 20u8, 155u8, 20u8,
+                    205u8, 20u8, 250u8, 20u8, 31u8, 21u8, 110u8, 21u8, 147u8, 21u8, 188u8, 21u8,
+                    238u8, 21u8, 52u8, 22u8, 131u8, 22u8, 209u8, 22u8, 44u8, 23u8, 81u8, 23u8,
+                    106u8, 23u8, 144u8, 23u8, 177u8, 23u8, 218u8, 23u8, 239u8, 23u8, 52u8, 24u8,
+                    93u8, 24u8, 142u8, 24u8, 179u8, 24u8, 229u8, 24u8, 2u8, 25u8, 31u8, 25u8,
+                    106u8, 25u8, 176u8, 25u8, 201u8, 25u8, 234u8, 25u8, 251u8, 25u8, 36u8, 26u8,
+                    109u8, 26u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 170u8, 240u8,
+                    145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 153u8,
+                    240u8, 145u8, 132u8, 174u8, 32u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8,
+                    132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 163u8, 240u8,
+                    145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8,
+                    180u8, 240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8,
+                    132u8, 154u8, 32u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 179u8,
+                    240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8,
+                    172u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8,
+                    132u8, 147u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 155u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8, 132u8,
+                    168u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8,
+                    145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 160u8,
+                    240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8,
+                    154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 147u8, 240u8, 145u8,
+                    132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 147u8, 240u8,
+                    145u8, 132u8, 139u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 137u8,
+                    240u8, 145u8, 132u8, 168u8, 32u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8,
+                    132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 163u8, 240u8,
+                    145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8,
+                    180u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8,
+                    132u8, 151u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 131u8, 240u8,
+                    145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 168u8,
+                    240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 174u8, 32u8, 240u8, 145u8,
+                    132u8, 131u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 172u8, 240u8,
+                    145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 172u8, 32u8, 240u8, 145u8, 132u8,
+                    131u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8,
+                    132u8, 159u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 135u8,
+                    240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8,
+                    163u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8,
+                    132u8, 180u8, 240u8, 145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 162u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 168u8,
+                    240u8, 145u8, 132u8, 151u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    131u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8,
+                    132u8, 168u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 180u8, 32u8,
+                    240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8,
+                    160u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8,
+                    132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 147u8, 240u8,
+                    145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 137u8,
+                    240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8,
+                    137u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8,
+                    132u8, 168u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 154u8, 240u8,
+                    145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8,
+                    179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8,
+                    132u8, 172u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 166u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    157u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8,
+                    132u8, 170u8, 32u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 179u8,
+                    240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8,
+                    172u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8,
+                    132u8, 147u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 158u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 167u8,
+                    240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8,
+                    162u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 142u8, 240u8,
+                    145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 169u8, 240u8, 145u8, 132u8, 160u8,
+                    240u8, 145u8, 132u8, 167u8, 32u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8,
+                    132u8, 167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 155u8,
+                    240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8,
+                    180u8, 240u8, 145u8, 132u8, 143u8, 240u8, 145u8, 132u8, 169u8, 240u8, 145u8,
+                    132u8, 131u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 165u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 159u8,
+                    240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8,
+                    180u8, 32u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 179u8, 240u8,
+                    145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 172u8,
+                    240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8,
+                    147u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8,
+                    132u8, 131u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 165u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 159u8,
+                    240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8,
+                    180u8, 45u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                    145u8, 132u8, 158u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 163u8,
+                    240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8,
+                    132u8, 167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 155u8,
+                    240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8,
+                    180u8, 240u8, 145u8, 132u8, 143u8, 240u8, 145u8, 132u8, 169u8, 73u8, 83u8,
+                    79u8, 45u8, 56u8, 54u8, 49u8, 32u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8,
+                    132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 163u8, 240u8,
+                    145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8,
+                    180u8, 240u8, 145u8, 132u8, 142u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8,
+                    132u8, 154u8, 240u8, 145u8, 132u8, 168u8, 32u8, 240u8, 145u8, 132u8, 135u8,
+                    240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8,
+                    163u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8,
+                    132u8, 180u8, 240u8, 145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 162u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 156u8, 240u8, 145u8, 132u8, 162u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8,
+                    168u8, 32u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 179u8, 240u8,
+                    145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 172u8,
+                    240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8,
+                    147u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8,
+                    132u8, 159u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 129u8, 240u8,
+                    145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 131u8,
+                    240u8, 145u8, 132u8, 174u8, 32u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8,
+                    132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 163u8, 240u8,
+                    145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8,
+                    180u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8,
+                    132u8, 139u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 167u8, 240u8,
+                    145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 142u8,
+                    240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 32u8, 240u8, 145u8,
+                    132u8, 131u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 143u8, 240u8,
+                    145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 167u8,
+                    240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8,
+                    132u8, 154u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 160u8, 240u8,
+                    145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8,
+                    167u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8,
+                    145u8, 132u8, 145u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 139u8,
+                    240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8,
+                    160u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 142u8, 240u8, 145u8,
+                    132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 162u8, 240u8,
+                    145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8,
+                    168u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8,
+                    132u8, 159u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 155u8, 240u8,
+                    145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 167u8,
+                    240u8, 145u8, 132u8, 151u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8,
+                    167u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 167u8, 32u8, 240u8,
+                    145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 169u8, 240u8, 145u8, 132u8, 154u8,
+                    32u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8,
+                    132u8, 142u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 142u8, 240u8,
+                    145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 162u8,
+                    240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 180u8, 45u8, 240u8, 145u8,
+                    132u8, 157u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 137u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 187u8, 240u8, 145u8, 132u8, 135u8,
+                    240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 153u8, 240u8, 145u8, 132u8,
+                    150u8, 240u8, 145u8, 132u8, 162u8, 32u8, 240u8, 145u8, 132u8, 157u8, 240u8,
+                    145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 173u8, 240u8, 145u8, 132u8, 163u8,
+                    240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8,
+                    162u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 154u8, 240u8,
+                    145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 174u8,
+                    240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8,
+                    147u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 156u8, 240u8, 145u8,
+                    132u8, 167u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 145u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8,
+                    131u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8,
+                    132u8, 170u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                    145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 147u8,
+                    240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8,
+                    132u8, 172u8, 240u8, 145u8, 132u8, 173u8, 240u8, 145u8, 132u8, 163u8, 240u8,
+                    145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 131u8,
+                    240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 142u8, 240u8, 145u8, 132u8,
+                    172u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8,
+                    132u8, 140u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 140u8,
+                    240u8, 145u8, 132u8, 169u8, 240u8, 145u8, 132u8, 154u8, 32u8, 240u8, 145u8,
+                    132u8, 165u8, 240u8, 145u8, 132u8, 142u8, 240u8, 145u8, 132u8, 154u8, 45u8,
+                    240u8, 145u8, 132u8, 142u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    157u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 184u8, 240u8, 145u8,
+                    132u8, 185u8, 240u8, 145u8, 132u8, 183u8, 240u8, 145u8, 132u8, 184u8, 240u8,
+                    145u8, 132u8, 156u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 154u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8,
+                    170u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8,
+                    145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 142u8, 240u8, 145u8, 132u8, 154u8,
+                    240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8,
+                    166u8, 240u8, 145u8, 132u8, 167u8, 32u8, 240u8, 145u8, 132u8, 157u8, 240u8,
+                    145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 173u8, 240u8, 145u8, 132u8, 163u8,
+                    240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8,
+                    155u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8,
+                    132u8, 168u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 32u8,
+                    240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 142u8, 240u8, 145u8, 132u8,
+                    154u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8,
+                    132u8, 156u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 159u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 168u8,
+                    32u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8,
+                    132u8, 173u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 167u8, 240u8,
+                    145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 167u8,
+                    240u8, 145u8, 132u8, 142u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8,
+                    131u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 140u8, 240u8, 145u8,
+                    132u8, 180u8, 45u8, 240u8, 145u8, 132u8, 133u8, 240u8, 145u8, 132u8, 170u8,
+                    240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    140u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8,
+                    132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 32u8, 240u8, 145u8, 132u8, 150u8,
+                    240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8,
+                    154u8, 240u8, 145u8, 132u8, 166u8, 240u8, 145u8, 132u8, 139u8, 240u8, 145u8,
+                    132u8, 180u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 170u8, 240u8,
+                    145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8,
+                    155u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8,
+                    132u8, 180u8, 240u8, 145u8, 132u8, 151u8, 240u8, 145u8, 132u8, 167u8, 240u8,
+                    145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8,
+                    157u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8,
+                    132u8, 172u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 150u8, 240u8,
+                    145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 172u8,
+                    32u8, 240u8, 145u8, 132u8, 166u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8,
+                    132u8, 162u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 135u8, 240u8,
+                    145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8,
+                    168u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8,
+                    132u8, 173u8, 32u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 174u8,
+                    240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8,
+                    137u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8,
+                    132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 174u8,
+                    240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8,
+                    132u8, 157u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 173u8, 240u8,
+                    145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 154u8,
+                    240u8, 145u8, 132u8, 138u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8,
+                    142u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8,
+                    132u8, 155u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 143u8, 240u8,
+                    145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 32u8, 240u8, 145u8, 132u8,
+                    165u8, 240u8, 145u8, 132u8, 142u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8,
+                    132u8, 173u8, 240u8, 145u8, 132u8, 151u8, 240u8, 145u8, 132u8, 167u8, 240u8,
+                    145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 179u8,
+                    240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8,
+                    151u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8,
+                    132u8, 150u8, 240u8, 145u8, 132u8, 167u8, 32u8, 240u8, 145u8, 132u8, 157u8,
+                    240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 173u8, 240u8, 145u8, 132u8,
+                    163u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8,
+                    132u8, 162u8, 240u8, 145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                    145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 163u8,
+                    240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8,
+                    132u8, 168u8, 240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 145u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 162u8,
+                    240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8,
+                    180u8, 32u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 172u8, 240u8,
+                    145u8, 132u8, 173u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 167u8,
+                    240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 183u8, 240u8, 145u8, 132u8,
+                    184u8, 32u8, 240u8, 145u8, 132u8, 138u8, 240u8, 145u8, 132u8, 167u8, 240u8,
+                    145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 145u8,
+                    240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8,
+                    132u8, 166u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 143u8, 240u8,
+                    145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 180u8,
+                    32u8, 40u8, 240u8, 145u8, 132u8, 182u8, 45u8, 240u8, 145u8, 132u8, 183u8,
+                    240u8, 145u8, 132u8, 183u8, 41u8, 240u8, 145u8, 132u8, 183u8, 240u8, 145u8,
+                    132u8, 184u8, 32u8, 240u8, 145u8, 132u8, 138u8, 240u8, 145u8, 132u8, 167u8,
+                    240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8,
+                    145u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8,
+                    145u8, 132u8, 166u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 143u8,
+                    240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8,
+                    180u8, 32u8, 40u8, 240u8, 145u8, 132u8, 183u8, 45u8, 240u8, 145u8, 132u8,
+                    183u8, 240u8, 145u8, 132u8, 184u8, 41u8, 240u8, 145u8, 132u8, 184u8, 240u8,
+                    145u8, 132u8, 186u8, 32u8, 240u8, 145u8, 132u8, 138u8, 240u8, 145u8, 132u8,
+                    167u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8,
+                    132u8, 145u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 32u8,
+                    240u8, 145u8, 132u8, 166u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    143u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8,
+                    132u8, 180u8, 40u8, 240u8, 145u8, 132u8, 183u8, 45u8, 240u8, 145u8, 132u8,
+                    184u8, 240u8, 145u8, 132u8, 185u8, 41u8, 240u8, 145u8, 132u8, 184u8, 240u8,
+                    145u8, 132u8, 186u8, 32u8, 240u8, 145u8, 132u8, 138u8, 240u8, 145u8, 132u8,
+                    167u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8,
+                    132u8, 145u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 32u8,
+                    240u8, 145u8, 132u8, 166u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    143u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8,
+                    132u8, 180u8, 32u8, 40u8, 240u8, 145u8, 132u8, 183u8, 45u8, 240u8, 145u8,
+                    132u8, 184u8, 240u8, 145u8, 132u8, 186u8, 41u8, 240u8, 145u8, 132u8, 141u8,
+                    240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8,
+                    168u8, 32u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 172u8, 240u8,
+                    145u8, 132u8, 173u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 167u8,
+                    240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8,
+                    140u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8,
+                    132u8, 172u8, 240u8, 145u8, 132u8, 173u8, 240u8, 145u8, 132u8, 151u8, 240u8,
+                    145u8, 132u8, 154u8, 32u8, 240u8, 145u8, 132u8, 141u8, 240u8, 145u8, 132u8,
+                    170u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 168u8, 32u8, 240u8,
+                    145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 173u8,
+                    240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8,
+                    154u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8,
+                    132u8, 166u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 137u8, 240u8,
+                    145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8,
+                    32u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8,
+                    132u8, 142u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 131u8, 240u8,
+                    145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8,
+                    160u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8,
+                    132u8, 162u8, 240u8, 145u8, 132u8, 168u8, 32u8, 240u8, 145u8, 132u8, 157u8,
+                    240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 173u8, 240u8, 145u8, 132u8,
+                    163u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8,
+                    132u8, 162u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 166u8, 240u8,
+                    145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 170u8,
+                    240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8,
+                    132u8, 133u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 163u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 174u8,
+                    240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8,
+                    179u8, 240u8, 145u8, 132u8, 166u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8,
+                    145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8,
+                    240u8, 145u8, 132u8, 168u8, 32u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8,
+                    132u8, 172u8, 240u8, 145u8, 132u8, 173u8, 240u8, 145u8, 132u8, 163u8, 240u8,
+                    145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 135u8,
+                    240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8,
+                    180u8, 32u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 167u8, 240u8,
+                    145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 129u8,
+                    240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8,
+                    152u8, 240u8, 145u8, 132u8, 169u8, 32u8, 240u8, 145u8, 132u8, 157u8, 240u8,
+                    145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 173u8, 240u8, 145u8, 132u8, 163u8,
+                    240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8,
+                    135u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8,
+                    132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 167u8,
+                    240u8, 145u8, 132u8, 129u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8,
+                    152u8, 240u8, 145u8, 132u8, 169u8, 32u8, 240u8, 145u8, 132u8, 157u8, 240u8,
+                    145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 173u8, 240u8, 145u8, 132u8, 163u8,
+                    240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8,
+                    131u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 142u8, 240u8, 145u8,
+                    132u8, 172u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 167u8, 240u8,
+                    145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8,
+                    135u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8,
+                    132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 179u8,
+                    240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8,
+                    159u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 157u8, 240u8,
+                    145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 173u8, 240u8, 145u8, 132u8, 163u8,
+                    240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8,
+                    156u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8,
+                    132u8, 180u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 179u8, 240u8,
+                    145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 172u8, 32u8, 240u8, 145u8, 132u8,
+                    165u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8,
+                    132u8, 174u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 32u8,
+                    240u8, 145u8, 132u8, 166u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8,
+                    167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8,
+                    145u8, 132u8, 166u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8,
+                    240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8,
+                    180u8, 32u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 172u8, 240u8,
+                    145u8, 132u8, 173u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 167u8,
+                    240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 156u8, 240u8, 145u8, 132u8,
+                    170u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8,
+                    132u8, 163u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 240u8,
+                    145u8, 132u8, 172u8, 32u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8,
+                    167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8,
+                    132u8, 166u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 167u8, 240u8,
+                    145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8,
+                    166u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8,
+                    132u8, 167u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 180u8, 32u8,
+                    240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8,
+                    173u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8,
+                    132u8, 154u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 167u8, 240u8,
+                    145u8, 132u8, 142u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 131u8,
+                    240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8,
+                    180u8, 32u8, 240u8, 145u8, 132u8, 141u8, 240u8, 145u8, 132u8, 162u8, 32u8,
+                    240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8,
+                    173u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8,
+                    132u8, 154u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 167u8, 240u8,
+                    145u8, 132u8, 142u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 131u8,
+                    240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8,
+                    180u8, 32u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                    145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 154u8,
+                    240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8,
+                    174u8, 240u8, 145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8,
+                    145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 173u8,
+                    240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8,
+                    154u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 152u8, 32u8, 240u8,
+                    145u8, 132u8, 156u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 167u8,
+                    240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8,
+                    137u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8,
+                    132u8, 168u8, 32u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 172u8,
+                    240u8, 145u8, 132u8, 173u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8,
+                    167u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8,
+                    132u8, 152u8, 32u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 152u8,
+                    240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8,
+                    135u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 32u8, 240u8,
+                    145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 173u8,
+                    240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8,
+                    154u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8,
+                    132u8, 135u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 133u8, 240u8,
+                    145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8,
+                    157u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 173u8, 240u8, 145u8,
+                    132u8, 163u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 154u8, 240u8,
+                    145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 154u8, 32u8, 240u8, 145u8, 132u8,
+                    156u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8,
+                    132u8, 180u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 179u8, 240u8,
+                    145u8, 132u8, 160u8, 32u8, 240u8, 145u8, 132u8, 166u8, 240u8, 145u8, 132u8,
+                    167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8,
+                    132u8, 135u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 133u8, 240u8,
+                    145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8,
+                    157u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 173u8, 240u8, 145u8,
+                    132u8, 163u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 154u8, 240u8,
+                    145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 166u8,
+                    240u8, 145u8, 132u8, 167u8, 32u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8,
+                    132u8, 172u8, 240u8, 145u8, 132u8, 173u8, 240u8, 145u8, 132u8, 163u8, 240u8,
+                    145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 162u8,
+                    240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 166u8, 240u8, 145u8, 132u8,
+                    167u8, 47u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 172u8, 240u8,
+                    145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 180u8, 47u8, 240u8, 145u8, 132u8,
+                    131u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 143u8, 240u8, 145u8,
+                    132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 157u8,
+                    240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 173u8, 240u8, 145u8, 132u8,
+                    163u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8,
+                    132u8, 162u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 166u8, 240u8,
+                    145u8, 132u8, 167u8, 47u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8,
+                    172u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 180u8, 47u8, 240u8,
+                    145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 143u8,
+                    240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 47u8, 240u8, 145u8,
+                    132u8, 135u8, 240u8, 145u8, 132u8, 154u8, 32u8, 240u8, 145u8, 132u8, 157u8,
+                    240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 173u8, 240u8, 145u8, 132u8,
+                    163u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8,
+                    132u8, 131u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 137u8, 32u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8,
+                    173u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8,
+                    145u8, 132u8, 156u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 167u8,
+                    240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8,
+                    162u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 154u8, 240u8,
+                    145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 174u8,
+                    240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8,
+                    131u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 142u8, 240u8, 145u8,
+                    132u8, 172u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 167u8, 240u8,
+                    145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8,
+                    163u8, 240u8, 145u8, 132u8, 173u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8,
+                    132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 156u8, 240u8, 145u8, 132u8, 162u8,
+                    240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8,
+                    167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8,
+                    145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 160u8,
+                    240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8,
+                    180u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8, 132u8, 173u8, 240u8, 145u8,
+                    132u8, 145u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 163u8,
+                    240u8, 145u8, 132u8, 173u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8,
+                    180u8, 32u8, 240u8, 145u8, 132u8, 156u8, 240u8, 145u8, 132u8, 162u8, 240u8,
+                    145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 167u8,
+                    240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8,
+                    132u8, 154u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 160u8, 240u8,
+                    145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8,
+                    145u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8,
+                    132u8, 168u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 180u8, 32u8,
+                    240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    165u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8,
+                    132u8, 172u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 159u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8,
+                    172u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8,
+                    132u8, 160u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 180u8, 32u8,
+                    240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8,
+                    167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8,
+                    145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 165u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8, 132u8,
+                    172u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8,
+                    132u8, 159u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 154u8,
+                    240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 142u8, 240u8, 145u8,
+                    132u8, 167u8, 240u8, 145u8, 132u8, 153u8, 240u8, 145u8, 132u8, 162u8, 240u8,
+                    145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 142u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8,
+                    162u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 159u8, 240u8,
+                    145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8,
+                    240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8,
+                    132u8, 168u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 145u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 159u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8,
+                    162u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8,
+                    132u8, 168u8, 45u8, 240u8, 145u8, 132u8, 158u8, 240u8, 145u8, 132u8, 162u8,
+                    240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8,
+                    169u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 167u8, 32u8, 240u8,
+                    145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 141u8,
+                    240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8,
+                    168u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 172u8, 32u8, 240u8,
+                    145u8, 132u8, 141u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 150u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8,
+                    179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 172u8, 32u8, 240u8,
+                    145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 167u8,
+                    240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 168u8, 45u8, 240u8, 145u8,
+                    132u8, 158u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 167u8, 240u8,
+                    145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 169u8, 240u8, 145u8, 132u8, 160u8,
+                    32u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8,
+                    132u8, 131u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 154u8,
+                    240u8, 145u8, 132u8, 169u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8,
+                    167u8, 32u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 152u8, 240u8,
+                    145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8,
+                    154u8, 240u8, 145u8, 132u8, 169u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8,
+                    132u8, 167u8, 32u8, 240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 168u8,
+                    240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8,
+                    154u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 166u8, 240u8,
+                    145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8,
+                    240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8,
+                    132u8, 152u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 129u8, 240u8,
+                    145u8, 132u8, 163u8, 32u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8,
+                    152u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8,
+                    132u8, 157u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 154u8, 240u8,
+                    145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8,
+                    240u8, 145u8, 132u8, 168u8, 32u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8,
+                    132u8, 152u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                    145u8, 132u8, 151u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 160u8,
+                    240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8,
+                    135u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 154u8, 240u8,
+                    145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 167u8,
+                    240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8,
+                    151u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8,
+                    132u8, 168u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                    145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8,
+                    154u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8,
+                    132u8, 157u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 150u8, 240u8,
+                    145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 155u8,
+                    240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8,
+                    174u8, 32u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 167u8, 240u8,
+                    145u8, 132u8, 143u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8,
+                    32u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8,
+                    132u8, 168u8, 240u8, 145u8, 132u8, 142u8, 240u8, 145u8, 132u8, 167u8, 240u8,
+                    145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 142u8,
+                    240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8,
+                    154u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 154u8, 240u8,
+                    145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 179u8,
+                    240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    135u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 154u8, 240u8,
+                    145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 137u8,
+                    240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8,
+                    168u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8,
+                    145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 137u8,
+                    240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8,
+                    180u8, 32u8, 240u8, 145u8, 132u8, 166u8, 240u8, 145u8, 132u8, 152u8, 240u8,
+                    145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8,
+                    32u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8,
+                    132u8, 137u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 142u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 150u8,
+                    240u8, 145u8, 132u8, 168u8, 32u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8,
+                    132u8, 152u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 170u8, 240u8,
+                    145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 159u8,
+                    240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 136u8, 240u8, 145u8, 132u8,
+                    168u8, 32u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 152u8, 240u8,
+                    145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 134u8,
+                    240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    135u8, 32u8, 240u8, 145u8, 132u8, 156u8, 240u8, 145u8, 132u8, 170u8, 240u8,
+                    145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 174u8, 32u8, 240u8, 145u8, 132u8,
+                    154u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 133u8, 240u8, 145u8,
+                    132u8, 170u8, 240u8, 145u8, 132u8, 142u8, 240u8, 145u8, 132u8, 170u8, 240u8,
+                    145u8, 132u8, 133u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 142u8,
+                    240u8, 145u8, 132u8, 170u8, 32u8, 240u8, 145u8, 132u8, 140u8, 240u8, 145u8,
+                    132u8, 179u8, 240u8, 145u8, 132u8, 134u8, 240u8, 145u8, 132u8, 154u8, 240u8,
+                    145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 180u8,
+                    32u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8,
+                    132u8, 133u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 142u8, 240u8,
+                    145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 133u8, 240u8, 145u8, 132u8, 142u8,
+                    240u8, 145u8, 132u8, 170u8, 32u8, 240u8, 145u8, 132u8, 140u8, 240u8, 145u8,
+                    132u8, 179u8, 240u8, 145u8, 132u8, 134u8, 240u8, 145u8, 132u8, 154u8, 240u8,
+                    145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 180u8,
+                    32u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8,
+                    132u8, 139u8, 32u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 152u8,
+                    240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    152u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8,
+                    132u8, 170u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 174u8, 240u8,
+                    145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8,
+                    140u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 134u8, 240u8, 145u8,
+                    132u8, 154u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 135u8, 240u8,
+                    145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8,
+                    152u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8,
+                    132u8, 152u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 165u8, 240u8,
+                    145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 174u8,
+                    240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8,
+                    132u8, 140u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 134u8, 240u8,
+                    145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 135u8,
+                    240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8,
+                    132u8, 172u8, 240u8, 145u8, 132u8, 139u8, 32u8, 240u8, 145u8, 132u8, 154u8,
+                    240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 166u8, 240u8, 145u8, 132u8,
+                    168u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8,
+                    132u8, 162u8, 240u8, 145u8, 132u8, 170u8, 32u8, 240u8, 145u8, 132u8, 154u8,
+                    240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    142u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8,
+                    132u8, 168u8, 32u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 152u8,
+                    240u8, 145u8, 132u8, 142u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8,
+                    154u8, 240u8, 145u8, 132u8, 168u8, 32u8, 240u8, 145u8, 132u8, 145u8, 240u8,
+                    145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 139u8, 32u8, 240u8, 145u8, 132u8,
+                    154u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 136u8, 240u8, 145u8,
+                    132u8, 172u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 172u8, 240u8,
+                    145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8,
+                    154u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8,
+                    132u8, 167u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8,
+                    240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8,
+                    132u8, 152u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 131u8, 240u8,
+                    145u8, 132u8, 174u8, 32u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8,
+                    152u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8,
+                    132u8, 160u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 140u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8, 132u8, 162u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8,
+                    180u8, 32u8, 240u8, 145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                    145u8, 132u8, 142u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 145u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8,
+                    163u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8,
+                    132u8, 163u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 159u8, 240u8,
+                    145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8,
+                    152u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8,
+                    132u8, 139u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 137u8, 240u8,
+                    145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 169u8,
+                    240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 167u8, 32u8, 240u8, 145u8,
+                    132u8, 154u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 159u8, 240u8,
+                    145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8,
+                    180u8, 32u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 152u8, 240u8,
+                    145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 167u8,
+                    240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 167u8, 32u8, 240u8, 145u8,
+                    132u8, 142u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 162u8, 240u8,
+                    145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8,
+                    152u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8,
+                    132u8, 174u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                    145u8, 132u8, 160u8, 32u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8,
+                    152u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8,
+                    132u8, 159u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 32u8,
+                    240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8,
+                    162u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8,
+                    132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 140u8,
+                    240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8,
+                    174u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8,
+                    145u8, 132u8, 166u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 167u8,
+                    240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8,
+                    132u8, 154u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 162u8, 240u8,
+                    145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 168u8,
+                    240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8,
+                    152u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8,
+                    132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 159u8,
+                    240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8,
+                    180u8, 32u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 152u8, 240u8,
+                    145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 168u8,
+                    240u8, 145u8, 132u8, 163u8, 32u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8,
+                    132u8, 152u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 172u8, 240u8,
+                    145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 137u8,
+                    240u8, 145u8, 132u8, 170u8, 32u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8,
+                    132u8, 152u8, 240u8, 145u8, 132u8, 151u8, 240u8, 145u8, 132u8, 173u8, 32u8,
+                    240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8,
+                    150u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8,
+                    132u8, 180u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 167u8, 240u8,
+                    145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 168u8, 32u8, 240u8, 145u8, 132u8,
+                    154u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8,
+                    132u8, 168u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                    145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 152u8,
+                    240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8,
+                    180u8, 32u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 152u8, 240u8,
+                    145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 167u8,
+                    240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8,
+                    160u8, 240u8, 145u8, 132u8, 158u8, 240u8, 145u8, 132u8, 173u8, 32u8, 240u8,
+                    145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 152u8,
+                ])
+            },
+        )
+    },
+};
+static EN: &DataStruct = &::icu_displaynames::provider::KeyDisplayNamesV1 {
+    keys: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    99u8, 97u8, 99u8, 102u8, 99u8, 111u8, 99u8, 117u8, 100u8, 120u8, 101u8, 109u8,
+                    102u8, 119u8, 104u8, 99u8, 107u8, 97u8, 107u8, 98u8, 107u8, 99u8, 107u8, 102u8,
+                    107u8, 107u8, 107u8, 110u8, 107u8, 114u8, 107u8, 115u8, 107u8, 118u8, 108u8,
+                    98u8, 108u8, 119u8, 109u8, 115u8, 109u8, 117u8, 110u8, 117u8, 114u8, 103u8,
+                    115u8, 100u8, 115u8, 115u8, 116u8, 122u8, 118u8, 97u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    27u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 23u8, 0u8, 33u8, 0u8, 41u8, 0u8, 68u8,
+                    0u8, 92u8, 0u8, 109u8, 0u8, 130u8, 0u8, 152u8, 0u8, 175u8, 0u8, 197u8, 0u8,
+                    225u8, 0u8, 243u8, 0u8, 2u8, 1u8, 25u8, 1u8, 41u8, 1u8, 56u8, 1u8, 72u8, 1u8,
+                    100u8, 1u8, 118u8, 1u8, 134u8, 1u8, 141u8, 1u8, 169u8, 1u8, 187u8, 1u8, 219u8,
+                    1u8, 228u8, 1u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 67u8,
+                    117u8, 114u8, 114u8, 101u8, 110u8, 99u8, 121u8, 32u8, 70u8, 111u8, 114u8,
+                    109u8, 97u8, 116u8, 83u8, 111u8, 114u8, 116u8, 32u8, 79u8, 114u8, 100u8, 101u8,
+                    114u8, 67u8, 117u8, 114u8, 114u8, 101u8, 110u8, 99u8, 121u8, 68u8, 105u8, 99u8,
+                    116u8, 105u8, 111u8, 110u8, 97u8, 114u8, 121u8, 32u8, 66u8, 114u8, 101u8, 97u8,
+                    107u8, 32u8, 69u8, 120u8, 99u8, 108u8, 117u8, 115u8, 105u8, 111u8, 110u8,
+                    115u8, 69u8, 109u8, 111u8, 106u8, 105u8, 32u8, 80u8, 114u8, 101u8, 115u8,
+                    101u8, 110u8, 116u8, 97u8, 116u8, 105u8, 111u8, 110u8, 32u8, 83u8, 116u8,
+                    121u8, 108u8, 101u8, 70u8, 105u8, 114u8, 115u8, 116u8, 32u8, 100u8, 97u8,
+                    121u8, 32u8, 111u8, 102u8, 32u8, 119u8, 101u8, 101u8, 107u8, 72u8, 111u8,
+                    117u8, 114u8, 32u8, 67u8, 121u8, 99u8, 108u8, 101u8, 32u8, 40u8, 49u8, 50u8,
+                    32u8, 118u8, 115u8, 32u8, 50u8, 52u8, 41u8, 73u8, 103u8, 110u8, 111u8, 114u8,
+                    101u8, 32u8, 83u8, 121u8, 109u8, 98u8, 111u8, 108u8, 115u8, 32u8, 83u8, 111u8,
+                    114u8, 116u8, 105u8, 110u8, 103u8, 82u8, 101u8, 118u8, 101u8, 114u8, 115u8,
+                    101u8, 100u8, 32u8, 65u8, 99u8, 99u8, 101u8, 110u8, 116u8, 32u8, 83u8, 111u8,
+                    114u8, 116u8, 105u8, 110u8, 103u8, 67u8, 97u8, 115u8, 101u8, 32u8, 83u8, 101u8,
+                    110u8, 115u8, 105u8, 116u8, 105u8, 118u8, 101u8, 32u8, 83u8, 111u8, 114u8,
+                    116u8, 105u8, 110u8, 103u8, 85u8, 112u8, 112u8, 101u8, 114u8, 99u8, 97u8,
+                    115u8, 101u8, 47u8, 76u8, 111u8, 119u8, 101u8, 114u8, 99u8, 97u8, 115u8, 101u8,
+                    32u8, 79u8, 114u8, 100u8, 101u8, 114u8, 105u8, 110u8, 103u8, 78u8, 111u8,
+                    114u8, 109u8, 97u8, 108u8, 105u8, 122u8, 101u8, 100u8, 32u8, 83u8, 111u8,
+                    114u8, 116u8, 105u8, 110u8, 103u8, 78u8, 117u8, 109u8, 101u8, 114u8, 105u8,
+                    99u8, 32u8, 83u8, 111u8, 114u8, 116u8, 105u8, 110u8, 103u8, 83u8, 99u8, 114u8,
+                    105u8, 112u8, 116u8, 47u8, 66u8, 108u8, 111u8, 99u8, 107u8, 32u8, 82u8, 101u8,
+                    111u8, 114u8, 100u8, 101u8, 114u8, 105u8, 110u8, 103u8, 83u8, 111u8, 114u8,
+                    116u8, 105u8, 110u8, 103u8, 32u8, 83u8, 116u8, 114u8, 101u8, 110u8, 103u8,
+                    116u8, 104u8, 72u8, 105u8, 103u8, 104u8, 101u8, 115u8, 116u8, 32u8, 73u8,
+                    103u8, 110u8, 111u8, 114u8, 101u8, 100u8, 76u8, 105u8, 110u8, 101u8, 32u8,
+                    66u8, 114u8, 101u8, 97u8, 107u8, 32u8, 83u8, 116u8, 121u8, 108u8, 101u8, 76u8,
+                    105u8, 110u8, 101u8, 32u8, 66u8, 114u8, 101u8, 97u8, 107u8, 115u8, 32u8, 73u8,
+                    110u8, 32u8, 87u8, 111u8, 114u8, 100u8, 115u8, 32u8, 83u8, 101u8, 116u8, 116u8,
+                    105u8, 110u8, 103u8, 77u8, 101u8, 97u8, 115u8, 117u8, 114u8, 101u8, 109u8,
+                    101u8, 110u8, 116u8, 32u8, 83u8, 121u8, 115u8, 116u8, 101u8, 109u8, 77u8,
+                    101u8, 97u8, 115u8, 117u8, 114u8, 101u8, 109u8, 101u8, 110u8, 116u8, 32u8,
+                    85u8, 110u8, 105u8, 116u8, 78u8, 117u8, 109u8, 98u8, 101u8, 114u8, 115u8, 82u8,
+                    101u8, 103u8, 105u8, 111u8, 110u8, 32u8, 70u8, 111u8, 114u8, 32u8, 83u8, 117u8,
+                    112u8, 112u8, 108u8, 101u8, 109u8, 101u8, 110u8, 116u8, 97u8, 108u8, 32u8,
+                    68u8, 97u8, 116u8, 97u8, 82u8, 101u8, 103u8, 105u8, 111u8, 110u8, 32u8, 83u8,
+                    117u8, 98u8, 100u8, 105u8, 118u8, 105u8, 115u8, 105u8, 111u8, 110u8, 83u8,
+                    101u8, 110u8, 116u8, 101u8, 110u8, 99u8, 101u8, 32u8, 66u8, 114u8, 101u8, 97u8,
+                    107u8, 32u8, 83u8, 117u8, 112u8, 112u8, 114u8, 101u8, 115u8, 115u8, 105u8,
+                    111u8, 110u8, 115u8, 32u8, 84u8, 121u8, 112u8, 101u8, 84u8, 105u8, 109u8,
+                    101u8, 32u8, 90u8, 111u8, 110u8, 101u8, 76u8, 111u8, 99u8, 97u8, 108u8, 101u8,
+                    32u8, 86u8, 97u8, 114u8, 105u8, 97u8, 110u8, 116u8,
+                ])
+            },
+        )
+    },
+    types: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    186u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 21u8, 0u8, 30u8, 0u8, 38u8, 0u8,
+                    48u8, 0u8, 59u8, 0u8, 69u8, 0u8, 78u8, 0u8, 87u8, 0u8, 97u8, 0u8, 113u8, 0u8,
+                    128u8, 0u8, 143u8, 0u8, 162u8, 0u8, 172u8, 0u8, 183u8, 0u8, 193u8, 0u8, 199u8,
+                    0u8, 209u8, 0u8, 220u8, 0u8, 230u8, 0u8, 239u8, 0u8, 246u8, 0u8, 254u8, 0u8,
+                    6u8, 1u8, 12u8, 1u8, 21u8, 1u8, 31u8, 1u8, 42u8, 1u8, 51u8, 1u8, 62u8, 1u8,
+                    71u8, 1u8, 82u8, 1u8, 93u8, 1u8, 102u8, 1u8, 109u8, 1u8, 118u8, 1u8, 127u8,
+                    1u8, 137u8, 1u8, 145u8, 1u8, 152u8, 1u8, 158u8, 1u8, 164u8, 1u8, 170u8, 1u8,
+                    176u8, 1u8, 182u8, 1u8, 188u8, 1u8, 194u8, 1u8, 200u8, 1u8, 206u8, 1u8, 212u8,
+                    1u8, 218u8, 1u8, 229u8, 1u8, 239u8, 1u8, 247u8, 1u8, 254u8, 1u8, 6u8, 2u8,
+                    13u8, 2u8, 21u8, 2u8, 29u8, 2u8, 37u8, 2u8, 45u8, 2u8, 52u8, 2u8, 60u8, 2u8,
+                    67u8, 2u8, 78u8, 2u8, 86u8, 2u8, 94u8, 2u8, 102u8, 2u8, 111u8, 2u8, 121u8, 2u8,
+                    130u8, 2u8, 139u8, 2u8, 148u8, 2u8, 157u8, 2u8, 168u8, 2u8, 176u8, 2u8, 184u8,
+                    2u8, 193u8, 2u8, 201u8, 2u8, 210u8, 2u8, 219u8, 2u8, 230u8, 2u8, 240u8, 2u8,
+                    249u8, 2u8, 2u8, 3u8, 11u8, 3u8, 22u8, 3u8, 33u8, 3u8, 43u8, 3u8, 54u8, 3u8,
+                    63u8, 3u8, 70u8, 3u8, 77u8, 3u8, 84u8, 3u8, 94u8, 3u8, 101u8, 3u8, 111u8, 3u8,
+                    118u8, 3u8, 125u8, 3u8, 132u8, 3u8, 139u8, 3u8, 146u8, 3u8, 153u8, 3u8, 160u8,
+                    3u8, 167u8, 3u8, 174u8, 3u8, 181u8, 3u8, 191u8, 3u8, 202u8, 3u8, 209u8, 3u8,
+                    216u8, 3u8, 223u8, 3u8, 230u8, 3u8, 240u8, 3u8, 247u8, 3u8, 254u8, 3u8, 9u8,
+                    4u8, 19u8, 4u8, 26u8, 4u8, 36u8, 4u8, 43u8, 4u8, 53u8, 4u8, 60u8, 4u8, 67u8,
+                    4u8, 74u8, 4u8, 81u8, 4u8, 88u8, 4u8, 98u8, 4u8, 109u8, 4u8, 116u8, 4u8, 123u8,
+                    4u8, 130u8, 4u8, 137u8, 4u8, 144u8, 4u8, 155u8, 4u8, 162u8, 4u8, 169u8, 4u8,
+                    176u8, 4u8, 183u8, 4u8, 194u8, 4u8, 204u8, 4u8, 215u8, 4u8, 226u8, 4u8, 237u8,
+                    4u8, 244u8, 4u8, 251u8, 4u8, 2u8, 5u8, 9u8, 5u8, 16u8, 5u8, 23u8, 5u8, 34u8,
+                    5u8, 45u8, 5u8, 52u8, 5u8, 61u8, 5u8, 68u8, 5u8, 75u8, 5u8, 82u8, 5u8, 89u8,
+                    5u8, 96u8, 5u8, 103u8, 5u8, 111u8, 5u8, 122u8, 5u8, 129u8, 5u8, 139u8, 5u8,
+                    146u8, 5u8, 153u8, 5u8, 160u8, 5u8, 167u8, 5u8, 174u8, 5u8, 181u8, 5u8, 188u8,
+                    5u8, 195u8, 5u8, 205u8, 5u8, 212u8, 5u8, 219u8, 5u8, 226u8, 5u8, 233u8, 5u8,
+                    240u8, 5u8, 251u8, 5u8, 2u8, 6u8, 9u8, 6u8, 16u8, 6u8, 23u8, 6u8, 34u8, 6u8,
+                    99u8, 97u8, 45u8, 98u8, 117u8, 100u8, 100u8, 104u8, 105u8, 115u8, 116u8, 99u8,
+                    97u8, 45u8, 99u8, 104u8, 105u8, 110u8, 101u8, 115u8, 101u8, 99u8, 97u8, 45u8,
+                    99u8, 111u8, 112u8, 116u8, 105u8, 99u8, 99u8, 97u8, 45u8, 100u8, 97u8, 110u8,
+                    103u8, 105u8, 99u8, 97u8, 45u8, 101u8, 116u8, 104u8, 105u8, 111u8, 97u8, 97u8,
+                    99u8, 97u8, 45u8, 101u8, 116u8, 104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 99u8,
+                    97u8, 45u8, 103u8, 114u8, 101u8, 103u8, 111u8, 114u8, 121u8, 99u8, 97u8, 45u8,
+                    104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 99u8, 97u8, 45u8, 105u8, 110u8, 100u8,
+                    105u8, 97u8, 110u8, 99u8, 97u8, 45u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8,
+                    99u8, 99u8, 97u8, 45u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8,
+                    99u8, 105u8, 118u8, 105u8, 108u8, 99u8, 97u8, 45u8, 105u8, 115u8, 108u8, 97u8,
+                    109u8, 105u8, 99u8, 45u8, 114u8, 103u8, 115u8, 97u8, 99u8, 97u8, 45u8, 105u8,
+                    115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 116u8, 98u8, 108u8, 97u8, 99u8,
+                    97u8, 45u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 117u8, 109u8,
+                    97u8, 108u8, 113u8, 117u8, 114u8, 97u8, 99u8, 97u8, 45u8, 105u8, 115u8, 111u8,
+                    56u8, 54u8, 48u8, 49u8, 99u8, 97u8, 45u8, 106u8, 97u8, 112u8, 97u8, 110u8,
+                    101u8, 115u8, 101u8, 99u8, 97u8, 45u8, 112u8, 101u8, 114u8, 115u8, 105u8, 97u8,
+                    110u8, 99u8, 97u8, 45u8, 114u8, 111u8, 99u8, 99u8, 102u8, 45u8, 97u8, 99u8,
+                    99u8, 111u8, 117u8, 110u8, 116u8, 99u8, 102u8, 45u8, 115u8, 116u8, 97u8, 110u8,
+                    100u8, 97u8, 114u8, 100u8, 99u8, 111u8, 45u8, 98u8, 105u8, 103u8, 53u8, 104u8,
+                    97u8, 110u8, 99u8, 111u8, 45u8, 99u8, 111u8, 109u8, 112u8, 97u8, 116u8, 99u8,
+                    111u8, 45u8, 100u8, 105u8, 99u8, 116u8, 99u8, 111u8, 45u8, 100u8, 117u8, 99u8,
+                    101u8, 116u8, 99u8, 111u8, 45u8, 101u8, 109u8, 111u8, 106u8, 105u8, 99u8,
+                    111u8, 45u8, 101u8, 111u8, 114u8, 99u8, 111u8, 45u8, 103u8, 98u8, 50u8, 51u8,
+                    49u8, 50u8, 99u8, 111u8, 45u8, 112u8, 104u8, 111u8, 110u8, 101u8, 98u8, 107u8,
+                    99u8, 111u8, 45u8, 112u8, 104u8, 111u8, 110u8, 101u8, 116u8, 105u8, 99u8, 99u8,
+                    111u8, 45u8, 112u8, 105u8, 110u8, 121u8, 105u8, 110u8, 99u8, 111u8, 45u8,
+                    114u8, 101u8, 102u8, 111u8, 114u8, 109u8, 101u8, 100u8, 99u8, 111u8, 45u8,
+                    115u8, 101u8, 97u8, 114u8, 99u8, 104u8, 99u8, 111u8, 45u8, 115u8, 101u8, 97u8,
+                    114u8, 99u8, 104u8, 106u8, 108u8, 99u8, 111u8, 45u8, 115u8, 116u8, 97u8, 110u8,
+                    100u8, 97u8, 114u8, 100u8, 99u8, 111u8, 45u8, 115u8, 116u8, 114u8, 111u8,
+                    107u8, 101u8, 99u8, 111u8, 45u8, 116u8, 114u8, 97u8, 100u8, 99u8, 111u8, 45u8,
+                    117u8, 110u8, 105u8, 104u8, 97u8, 110u8, 99u8, 111u8, 45u8, 122u8, 104u8,
+                    117u8, 121u8, 105u8, 110u8, 101u8, 109u8, 45u8, 100u8, 101u8, 102u8, 97u8,
+                    117u8, 108u8, 116u8, 101u8, 109u8, 45u8, 101u8, 109u8, 111u8, 106u8, 105u8,
+                    101u8, 109u8, 45u8, 116u8, 101u8, 120u8, 116u8, 102u8, 119u8, 45u8, 102u8,
+                    114u8, 105u8, 102u8, 119u8, 45u8, 109u8, 111u8, 110u8, 102u8, 119u8, 45u8,
+                    115u8, 97u8, 116u8, 102u8, 119u8, 45u8, 115u8, 117u8, 110u8, 102u8, 119u8,
+                    45u8, 116u8, 104u8, 117u8, 102u8, 119u8, 45u8, 116u8, 117u8, 101u8, 102u8,
+                    119u8, 45u8, 119u8, 101u8, 100u8, 104u8, 99u8, 45u8, 104u8, 49u8, 49u8, 104u8,
+                    99u8, 45u8, 104u8, 49u8, 50u8, 104u8, 99u8, 45u8, 104u8, 50u8, 51u8, 104u8,
+                    99u8, 45u8, 104u8, 50u8, 52u8, 107u8, 97u8, 45u8, 110u8, 111u8, 105u8, 103u8,
+                    110u8, 111u8, 114u8, 101u8, 107u8, 97u8, 45u8, 115u8, 104u8, 105u8, 102u8,
+                    116u8, 101u8, 100u8, 107u8, 98u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8,
+                    107u8, 98u8, 45u8, 116u8, 114u8, 117u8, 101u8, 107u8, 99u8, 45u8, 102u8, 97u8,
+                    108u8, 115u8, 101u8, 107u8, 99u8, 45u8, 116u8, 114u8, 117u8, 101u8, 107u8,
+                    102u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8, 107u8, 102u8, 45u8, 108u8,
+                    111u8, 119u8, 101u8, 114u8, 107u8, 102u8, 45u8, 117u8, 112u8, 112u8, 101u8,
+                    114u8, 107u8, 107u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8, 107u8, 107u8,
+                    45u8, 116u8, 114u8, 117u8, 101u8, 107u8, 110u8, 45u8, 102u8, 97u8, 108u8,
+                    115u8, 101u8, 107u8, 110u8, 45u8, 116u8, 114u8, 117u8, 101u8, 107u8, 114u8,
+                    45u8, 99u8, 117u8, 114u8, 114u8, 101u8, 110u8, 99u8, 121u8, 107u8, 114u8, 45u8,
+                    100u8, 105u8, 103u8, 105u8, 116u8, 107u8, 114u8, 45u8, 112u8, 117u8, 110u8,
+                    99u8, 116u8, 107u8, 114u8, 45u8, 115u8, 112u8, 97u8, 99u8, 101u8, 107u8, 114u8,
+                    45u8, 115u8, 121u8, 109u8, 98u8, 111u8, 108u8, 107u8, 115u8, 45u8, 105u8,
+                    100u8, 101u8, 110u8, 116u8, 105u8, 99u8, 107u8, 115u8, 45u8, 108u8, 101u8,
+                    118u8, 101u8, 108u8, 49u8, 107u8, 115u8, 45u8, 108u8, 101u8, 118u8, 101u8,
+                    108u8, 50u8, 107u8, 115u8, 45u8, 108u8, 101u8, 118u8, 101u8, 108u8, 51u8,
+                    107u8, 115u8, 45u8, 108u8, 101u8, 118u8, 101u8, 108u8, 52u8, 107u8, 118u8,
+                    45u8, 99u8, 117u8, 114u8, 114u8, 101u8, 110u8, 99u8, 121u8, 107u8, 118u8, 45u8,
+                    112u8, 117u8, 110u8, 99u8, 116u8, 107u8, 118u8, 45u8, 115u8, 112u8, 97u8, 99u8,
+                    101u8, 107u8, 118u8, 45u8, 115u8, 121u8, 109u8, 98u8, 111u8, 108u8, 108u8,
+                    98u8, 45u8, 108u8, 111u8, 111u8, 115u8, 101u8, 108u8, 98u8, 45u8, 110u8, 111u8,
+                    114u8, 109u8, 97u8, 108u8, 108u8, 98u8, 45u8, 115u8, 116u8, 114u8, 105u8, 99u8,
+                    116u8, 108u8, 119u8, 45u8, 98u8, 114u8, 101u8, 97u8, 107u8, 97u8, 108u8, 108u8,
+                    108u8, 119u8, 45u8, 107u8, 101u8, 101u8, 112u8, 97u8, 108u8, 108u8, 108u8,
+                    119u8, 45u8, 110u8, 111u8, 114u8, 109u8, 97u8, 108u8, 108u8, 119u8, 45u8,
+                    112u8, 104u8, 114u8, 97u8, 115u8, 101u8, 109u8, 115u8, 45u8, 109u8, 101u8,
+                    116u8, 114u8, 105u8, 99u8, 109u8, 115u8, 45u8, 117u8, 107u8, 115u8, 121u8,
+                    115u8, 116u8, 101u8, 109u8, 109u8, 115u8, 45u8, 117u8, 115u8, 115u8, 121u8,
+                    115u8, 116u8, 101u8, 109u8, 109u8, 117u8, 45u8, 99u8, 101u8, 108u8, 115u8,
+                    105u8, 117u8, 115u8, 109u8, 117u8, 45u8, 102u8, 97u8, 104u8, 114u8, 101u8,
+                    110u8, 104u8, 101u8, 109u8, 117u8, 45u8, 107u8, 101u8, 108u8, 118u8, 105u8,
+                    110u8, 110u8, 117u8, 45u8, 97u8, 100u8, 108u8, 109u8, 110u8, 117u8, 45u8, 97u8,
+                    104u8, 111u8, 109u8, 110u8, 117u8, 45u8, 97u8, 114u8, 97u8, 98u8, 110u8, 117u8,
+                    45u8, 97u8, 114u8, 97u8, 98u8, 101u8, 120u8, 116u8, 110u8, 117u8, 45u8, 97u8,
+                    114u8, 109u8, 110u8, 110u8, 117u8, 45u8, 97u8, 114u8, 109u8, 110u8, 108u8,
+                    111u8, 119u8, 110u8, 117u8, 45u8, 98u8, 97u8, 108u8, 105u8, 110u8, 117u8, 45u8,
+                    98u8, 101u8, 110u8, 103u8, 110u8, 117u8, 45u8, 98u8, 104u8, 107u8, 115u8,
+                    110u8, 117u8, 45u8, 98u8, 114u8, 97u8, 104u8, 110u8, 117u8, 45u8, 99u8, 97u8,
+                    107u8, 109u8, 110u8, 117u8, 45u8, 99u8, 104u8, 97u8, 109u8, 110u8, 117u8, 45u8,
+                    99u8, 121u8, 114u8, 108u8, 110u8, 117u8, 45u8, 100u8, 101u8, 118u8, 97u8,
+                    110u8, 117u8, 45u8, 100u8, 105u8, 97u8, 107u8, 110u8, 117u8, 45u8, 101u8,
+                    116u8, 104u8, 105u8, 110u8, 117u8, 45u8, 102u8, 105u8, 110u8, 97u8, 110u8,
+                    99u8, 101u8, 110u8, 117u8, 45u8, 102u8, 117u8, 108u8, 108u8, 119u8, 105u8,
+                    100u8, 101u8, 110u8, 117u8, 45u8, 103u8, 101u8, 111u8, 114u8, 110u8, 117u8,
+                    45u8, 103u8, 111u8, 110u8, 103u8, 110u8, 117u8, 45u8, 103u8, 111u8, 110u8,
+                    109u8, 110u8, 117u8, 45u8, 103u8, 114u8, 101u8, 107u8, 110u8, 117u8, 45u8,
+                    103u8, 114u8, 101u8, 107u8, 108u8, 111u8, 119u8, 110u8, 117u8, 45u8, 103u8,
+                    117u8, 106u8, 114u8, 110u8, 117u8, 45u8, 103u8, 117u8, 114u8, 117u8, 110u8,
+                    117u8, 45u8, 104u8, 97u8, 110u8, 105u8, 100u8, 97u8, 121u8, 115u8, 110u8,
+                    117u8, 45u8, 104u8, 97u8, 110u8, 105u8, 100u8, 101u8, 99u8, 110u8, 117u8, 45u8,
+                    104u8, 97u8, 110u8, 115u8, 110u8, 117u8, 45u8, 104u8, 97u8, 110u8, 115u8,
+                    102u8, 105u8, 110u8, 110u8, 117u8, 45u8, 104u8, 97u8, 110u8, 116u8, 110u8,
+                    117u8, 45u8, 104u8, 97u8, 110u8, 116u8, 102u8, 105u8, 110u8, 110u8, 117u8,
+                    45u8, 104u8, 101u8, 98u8, 114u8, 110u8, 117u8, 45u8, 104u8, 109u8, 110u8,
+                    103u8, 110u8, 117u8, 45u8, 104u8, 109u8, 110u8, 112u8, 110u8, 117u8, 45u8,
+                    106u8, 97u8, 118u8, 97u8, 110u8, 117u8, 45u8, 106u8, 112u8, 97u8, 110u8, 110u8,
+                    117u8, 45u8, 106u8, 112u8, 97u8, 110u8, 102u8, 105u8, 110u8, 110u8, 117u8,
+                    45u8, 106u8, 112u8, 97u8, 110u8, 121u8, 101u8, 97u8, 114u8, 110u8, 117u8, 45u8,
+                    107u8, 97u8, 108u8, 105u8, 110u8, 117u8, 45u8, 107u8, 97u8, 119u8, 105u8,
+                    110u8, 117u8, 45u8, 107u8, 104u8, 109u8, 114u8, 110u8, 117u8, 45u8, 107u8,
+                    110u8, 100u8, 97u8, 110u8, 117u8, 45u8, 108u8, 97u8, 110u8, 97u8, 110u8, 117u8,
+                    45u8, 108u8, 97u8, 110u8, 97u8, 116u8, 104u8, 97u8, 109u8, 110u8, 117u8, 45u8,
+                    108u8, 97u8, 111u8, 111u8, 110u8, 117u8, 45u8, 108u8, 97u8, 116u8, 110u8,
+                    110u8, 117u8, 45u8, 108u8, 101u8, 112u8, 99u8, 110u8, 117u8, 45u8, 108u8,
+                    105u8, 109u8, 98u8, 110u8, 117u8, 45u8, 109u8, 97u8, 116u8, 104u8, 98u8, 111u8,
+                    108u8, 100u8, 110u8, 117u8, 45u8, 109u8, 97u8, 116u8, 104u8, 100u8, 98u8,
+                    108u8, 110u8, 117u8, 45u8, 109u8, 97u8, 116u8, 104u8, 109u8, 111u8, 110u8,
+                    111u8, 110u8, 117u8, 45u8, 109u8, 97u8, 116u8, 104u8, 115u8, 97u8, 110u8, 98u8,
+                    110u8, 117u8, 45u8, 109u8, 97u8, 116u8, 104u8, 115u8, 97u8, 110u8, 115u8,
+                    110u8, 117u8, 45u8, 109u8, 108u8, 121u8, 109u8, 110u8, 117u8, 45u8, 109u8,
+                    111u8, 100u8, 105u8, 110u8, 117u8, 45u8, 109u8, 111u8, 110u8, 103u8, 110u8,
+                    117u8, 45u8, 109u8, 114u8, 111u8, 111u8, 110u8, 117u8, 45u8, 109u8, 116u8,
+                    101u8, 105u8, 110u8, 117u8, 45u8, 109u8, 121u8, 109u8, 114u8, 110u8, 117u8,
+                    45u8, 109u8, 121u8, 109u8, 114u8, 115u8, 104u8, 97u8, 110u8, 110u8, 117u8,
+                    45u8, 109u8, 121u8, 109u8, 114u8, 116u8, 108u8, 110u8, 103u8, 110u8, 117u8,
+                    45u8, 110u8, 97u8, 103u8, 109u8, 110u8, 117u8, 45u8, 110u8, 97u8, 116u8, 105u8,
+                    118u8, 101u8, 110u8, 117u8, 45u8, 110u8, 101u8, 119u8, 97u8, 110u8, 117u8,
+                    45u8, 110u8, 107u8, 111u8, 111u8, 110u8, 117u8, 45u8, 111u8, 108u8, 99u8,
+                    107u8, 110u8, 117u8, 45u8, 111u8, 114u8, 121u8, 97u8, 110u8, 117u8, 45u8,
+                    111u8, 115u8, 109u8, 97u8, 110u8, 117u8, 45u8, 114u8, 111u8, 104u8, 103u8,
+                    110u8, 117u8, 45u8, 114u8, 111u8, 109u8, 97u8, 110u8, 110u8, 117u8, 45u8,
+                    114u8, 111u8, 109u8, 97u8, 110u8, 108u8, 111u8, 119u8, 110u8, 117u8, 45u8,
+                    115u8, 97u8, 117u8, 114u8, 110u8, 117u8, 45u8, 115u8, 101u8, 103u8, 109u8,
+                    101u8, 110u8, 116u8, 110u8, 117u8, 45u8, 115u8, 104u8, 114u8, 100u8, 110u8,
+                    117u8, 45u8, 115u8, 105u8, 110u8, 100u8, 110u8, 117u8, 45u8, 115u8, 105u8,
+                    110u8, 104u8, 110u8, 117u8, 45u8, 115u8, 111u8, 114u8, 97u8, 110u8, 117u8,
+                    45u8, 115u8, 117u8, 110u8, 100u8, 110u8, 117u8, 45u8, 116u8, 97u8, 107u8,
+                    114u8, 110u8, 117u8, 45u8, 116u8, 97u8, 108u8, 117u8, 110u8, 117u8, 45u8,
+                    116u8, 97u8, 109u8, 108u8, 110u8, 117u8, 45u8, 116u8, 97u8, 109u8, 108u8,
+                    100u8, 101u8, 99u8, 110u8, 117u8, 45u8, 116u8, 101u8, 108u8, 117u8, 110u8,
+                    117u8, 45u8, 116u8, 104u8, 97u8, 105u8, 110u8, 117u8, 45u8, 116u8, 105u8, 98u8,
+                    116u8, 110u8, 117u8, 45u8, 116u8, 105u8, 114u8, 104u8, 110u8, 117u8, 45u8,
+                    116u8, 110u8, 115u8, 97u8, 110u8, 117u8, 45u8, 116u8, 114u8, 97u8, 100u8,
+                    105u8, 116u8, 105u8, 111u8, 110u8, 117u8, 45u8, 118u8, 97u8, 105u8, 105u8,
+                    110u8, 117u8, 45u8, 119u8, 97u8, 114u8, 97u8, 110u8, 117u8, 45u8, 119u8, 99u8,
+                    104u8, 111u8, 115u8, 115u8, 45u8, 110u8, 111u8, 110u8, 101u8, 115u8, 115u8,
+                    45u8, 115u8, 116u8, 97u8, 110u8, 100u8, 97u8, 114u8, 100u8, 118u8, 97u8, 45u8,
+                    112u8, 111u8, 115u8, 105u8, 120u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    186u8, 0u8, 0u8, 0u8, 0u8, 0u8, 17u8, 0u8, 33u8, 0u8, 48u8, 0u8, 62u8, 0u8,
+                    90u8, 0u8, 107u8, 0u8, 125u8, 0u8, 140u8, 0u8, 164u8, 0u8, 180u8, 0u8, 219u8,
+                    0u8, 4u8, 1u8, 50u8, 1u8, 80u8, 1u8, 97u8, 1u8, 114u8, 1u8, 130u8, 1u8, 145u8,
+                    1u8, 171u8, 1u8, 195u8, 1u8, 232u8, 1u8, 14u8, 2u8, 35u8, 2u8, 61u8, 2u8, 77u8,
+                    2u8, 100u8, 2u8, 138u8, 2u8, 158u8, 2u8, 177u8, 2u8, 194u8, 2u8, 213u8, 2u8,
+                    235u8, 2u8, 13u8, 3u8, 32u8, 3u8, 49u8, 3u8, 71u8, 3u8, 96u8, 3u8, 113u8, 3u8,
+                    158u8, 3u8, 204u8, 3u8, 249u8, 3u8, 20u8, 4u8, 47u8, 4u8, 76u8, 4u8, 103u8,
+                    4u8, 132u8, 4u8, 160u8, 4u8, 190u8, 4u8, 213u8, 4u8, 236u8, 4u8, 3u8, 5u8,
+                    26u8, 5u8, 38u8, 5u8, 59u8, 5u8, 80u8, 5u8, 101u8, 5u8, 122u8, 5u8, 141u8, 5u8,
+                    163u8, 5u8, 183u8, 5u8, 203u8, 5u8, 229u8, 5u8, 252u8, 5u8, 20u8, 6u8, 43u8,
+                    6u8, 51u8, 6u8, 57u8, 6u8, 68u8, 6u8, 78u8, 6u8, 84u8, 6u8, 92u8, 6u8, 114u8,
+                    6u8, 126u8, 6u8, 149u8, 6u8, 177u8, 6u8, 232u8, 6u8, 26u8, 7u8, 60u8, 7u8,
+                    124u8, 7u8, 146u8, 7u8, 169u8, 7u8, 192u8, 7u8, 222u8, 7u8, 254u8, 7u8, 26u8,
+                    8u8, 56u8, 8u8, 69u8, 8u8, 96u8, 8u8, 117u8, 8u8, 124u8, 8u8, 134u8, 8u8,
+                    140u8, 8u8, 152u8, 8u8, 163u8, 8u8, 182u8, 8u8, 210u8, 8u8, 227u8, 8u8, 254u8,
+                    8u8, 13u8, 9u8, 26u8, 9u8, 42u8, 9u8, 55u8, 9u8, 68u8, 9u8, 79u8, 9u8, 96u8,
+                    9u8, 113u8, 9u8, 131u8, 9u8, 148u8, 9u8, 166u8, 9u8, 183u8, 9u8, 200u8, 9u8,
+                    220u8, 9u8, 240u8, 9u8, 254u8, 9u8, 22u8, 10u8, 37u8, 10u8, 52u8, 10u8, 90u8,
+                    10u8, 114u8, 10u8, 141u8, 10u8, 178u8, 10u8, 206u8, 10u8, 244u8, 10u8, 3u8,
+                    11u8, 22u8, 11u8, 51u8, 11u8, 66u8, 11u8, 83u8, 11u8, 110u8, 11u8, 148u8, 11u8,
+                    163u8, 11u8, 174u8, 11u8, 186u8, 11u8, 200u8, 11u8, 220u8, 11u8, 240u8, 11u8,
+                    250u8, 11u8, 8u8, 12u8, 21u8, 12u8, 33u8, 12u8, 57u8, 12u8, 90u8, 12u8, 119u8,
+                    12u8, 154u8, 12u8, 184u8, 12u8, 200u8, 12u8, 211u8, 12u8, 227u8, 12u8, 237u8,
+                    12u8, 0u8, 13u8, 14u8, 13u8, 33u8, 13u8, 57u8, 13u8, 75u8, 13u8, 88u8, 13u8,
+                    99u8, 13u8, 112u8, 13u8, 127u8, 13u8, 138u8, 13u8, 152u8, 13u8, 174u8, 13u8,
+                    188u8, 13u8, 212u8, 13u8, 229u8, 13u8, 245u8, 13u8, 3u8, 14u8, 19u8, 14u8,
+                    38u8, 14u8, 57u8, 14u8, 73u8, 14u8, 85u8, 14u8, 103u8, 14u8, 129u8, 14u8,
+                    141u8, 14u8, 154u8, 14u8, 165u8, 14u8, 179u8, 14u8, 193u8, 14u8, 206u8, 14u8,
+                    226u8, 14u8, 236u8, 14u8, 254u8, 14u8, 11u8, 15u8, 56u8, 15u8, 109u8, 15u8,
+                    66u8, 117u8, 100u8, 100u8, 104u8, 105u8, 115u8, 116u8, 32u8, 67u8, 97u8, 108u8,
+                    101u8, 110u8, 100u8, 97u8, 114u8, 67u8, 104u8, 105u8, 110u8, 101u8, 115u8,
+                    101u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 67u8, 111u8,
+                    112u8, 116u8, 105u8, 99u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8,
+                    114u8, 68u8, 97u8, 110u8, 103u8, 105u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8,
+                    100u8, 97u8, 114u8, 69u8, 116u8, 104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 32u8,
+                    65u8, 109u8, 101u8, 116u8, 101u8, 32u8, 65u8, 108u8, 101u8, 109u8, 32u8, 67u8,
+                    97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 69u8, 116u8, 104u8, 105u8,
+                    111u8, 112u8, 105u8, 99u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8,
+                    114u8, 71u8, 114u8, 101u8, 103u8, 111u8, 114u8, 105u8, 97u8, 110u8, 32u8, 67u8,
+                    97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 72u8, 101u8, 98u8, 114u8, 101u8,
+                    119u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 73u8, 110u8,
+                    100u8, 105u8, 97u8, 110u8, 32u8, 78u8, 97u8, 116u8, 105u8, 111u8, 110u8, 97u8,
+                    108u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 73u8, 115u8,
+                    108u8, 97u8, 109u8, 105u8, 99u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8,
+                    97u8, 114u8, 73u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 32u8, 67u8, 97u8,
+                    108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 32u8, 40u8, 116u8, 97u8, 98u8, 117u8,
+                    108u8, 97u8, 114u8, 44u8, 32u8, 99u8, 105u8, 118u8, 105u8, 108u8, 32u8, 101u8,
+                    112u8, 111u8, 99u8, 104u8, 41u8, 73u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8,
+                    32u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 32u8, 40u8, 83u8,
+                    97u8, 117u8, 100u8, 105u8, 32u8, 65u8, 114u8, 97u8, 98u8, 105u8, 97u8, 44u8,
+                    32u8, 115u8, 105u8, 103u8, 104u8, 116u8, 105u8, 110u8, 103u8, 41u8, 73u8,
+                    115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8,
+                    100u8, 97u8, 114u8, 32u8, 40u8, 116u8, 97u8, 98u8, 117u8, 108u8, 97u8, 114u8,
+                    44u8, 32u8, 97u8, 115u8, 116u8, 114u8, 111u8, 110u8, 111u8, 109u8, 105u8, 99u8,
+                    97u8, 108u8, 32u8, 101u8, 112u8, 111u8, 99u8, 104u8, 41u8, 73u8, 115u8, 108u8,
+                    97u8, 109u8, 105u8, 99u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8,
+                    114u8, 32u8, 40u8, 85u8, 109u8, 109u8, 32u8, 97u8, 108u8, 45u8, 81u8, 117u8,
+                    114u8, 97u8, 41u8, 73u8, 83u8, 79u8, 45u8, 56u8, 54u8, 48u8, 49u8, 32u8, 67u8,
+                    97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 74u8, 97u8, 112u8, 97u8, 110u8,
+                    101u8, 115u8, 101u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8,
+                    80u8, 101u8, 114u8, 115u8, 105u8, 97u8, 110u8, 32u8, 67u8, 97u8, 108u8, 101u8,
+                    110u8, 100u8, 97u8, 114u8, 77u8, 105u8, 110u8, 103u8, 117u8, 111u8, 32u8, 67u8,
+                    97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 65u8, 99u8, 99u8, 111u8, 117u8,
+                    110u8, 116u8, 105u8, 110u8, 103u8, 32u8, 67u8, 117u8, 114u8, 114u8, 101u8,
+                    110u8, 99u8, 121u8, 32u8, 70u8, 111u8, 114u8, 109u8, 97u8, 116u8, 83u8, 116u8,
+                    97u8, 110u8, 100u8, 97u8, 114u8, 100u8, 32u8, 67u8, 117u8, 114u8, 114u8, 101u8,
+                    110u8, 99u8, 121u8, 32u8, 70u8, 111u8, 114u8, 109u8, 97u8, 116u8, 84u8, 114u8,
+                    97u8, 100u8, 105u8, 116u8, 105u8, 111u8, 110u8, 97u8, 108u8, 32u8, 67u8, 104u8,
+                    105u8, 110u8, 101u8, 115u8, 101u8, 32u8, 83u8, 111u8, 114u8, 116u8, 32u8, 79u8,
+                    114u8, 100u8, 101u8, 114u8, 32u8, 45u8, 32u8, 66u8, 105u8, 103u8, 53u8, 80u8,
+                    114u8, 101u8, 118u8, 105u8, 111u8, 117u8, 115u8, 32u8, 83u8, 111u8, 114u8,
+                    116u8, 32u8, 79u8, 114u8, 100u8, 101u8, 114u8, 44u8, 32u8, 102u8, 111u8, 114u8,
+                    32u8, 99u8, 111u8, 109u8, 112u8, 97u8, 116u8, 105u8, 98u8, 105u8, 108u8, 105u8,
+                    116u8, 121u8, 68u8, 105u8, 99u8, 116u8, 105u8, 111u8, 110u8, 97u8, 114u8,
+                    121u8, 32u8, 83u8, 111u8, 114u8, 116u8, 32u8, 79u8, 114u8, 100u8, 101u8, 114u8,
+                    68u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 32u8, 85u8, 110u8, 105u8, 99u8,
+                    111u8, 100u8, 101u8, 32u8, 83u8, 111u8, 114u8, 116u8, 32u8, 79u8, 114u8, 100u8,
+                    101u8, 114u8, 69u8, 109u8, 111u8, 106u8, 105u8, 32u8, 83u8, 111u8, 114u8,
+                    116u8, 32u8, 79u8, 114u8, 100u8, 101u8, 114u8, 69u8, 117u8, 114u8, 111u8,
+                    112u8, 101u8, 97u8, 110u8, 32u8, 79u8, 114u8, 100u8, 101u8, 114u8, 105u8,
+                    110u8, 103u8, 32u8, 82u8, 117u8, 108u8, 101u8, 115u8, 83u8, 105u8, 109u8,
+                    112u8, 108u8, 105u8, 102u8, 105u8, 101u8, 100u8, 32u8, 67u8, 104u8, 105u8,
+                    110u8, 101u8, 115u8, 101u8, 32u8, 83u8, 111u8, 114u8, 116u8, 32u8, 79u8, 114u8,
+                    100u8, 101u8, 114u8, 32u8, 45u8, 32u8, 71u8, 66u8, 50u8, 51u8, 49u8, 50u8,
+                    80u8, 104u8, 111u8, 110u8, 101u8, 98u8, 111u8, 111u8, 107u8, 32u8, 83u8, 111u8,
+                    114u8, 116u8, 32u8, 79u8, 114u8, 100u8, 101u8, 114u8, 80u8, 104u8, 111u8,
+                    110u8, 101u8, 116u8, 105u8, 99u8, 32u8, 83u8, 111u8, 114u8, 116u8, 32u8, 79u8,
+                    114u8, 100u8, 101u8, 114u8, 80u8, 105u8, 110u8, 121u8, 105u8, 110u8, 32u8,
+                    83u8, 111u8, 114u8, 116u8, 32u8, 79u8, 114u8, 100u8, 101u8, 114u8, 82u8, 101u8,
+                    102u8, 111u8, 114u8, 109u8, 101u8, 100u8, 32u8, 83u8, 111u8, 114u8, 116u8,
+                    32u8, 79u8, 114u8, 100u8, 101u8, 114u8, 71u8, 101u8, 110u8, 101u8, 114u8, 97u8,
+                    108u8, 45u8, 80u8, 117u8, 114u8, 112u8, 111u8, 115u8, 101u8, 32u8, 83u8, 101u8,
+                    97u8, 114u8, 99u8, 104u8, 83u8, 101u8, 97u8, 114u8, 99u8, 104u8, 32u8, 66u8,
+                    121u8, 32u8, 72u8, 97u8, 110u8, 103u8, 117u8, 108u8, 32u8, 73u8, 110u8, 105u8,
+                    116u8, 105u8, 97u8, 108u8, 32u8, 67u8, 111u8, 110u8, 115u8, 111u8, 110u8, 97u8,
+                    110u8, 116u8, 83u8, 116u8, 97u8, 110u8, 100u8, 97u8, 114u8, 100u8, 32u8, 83u8,
+                    111u8, 114u8, 116u8, 32u8, 79u8, 114u8, 100u8, 101u8, 114u8, 83u8, 116u8,
+                    114u8, 111u8, 107u8, 101u8, 32u8, 83u8, 111u8, 114u8, 116u8, 32u8, 79u8, 114u8,
+                    100u8, 101u8, 114u8, 84u8, 114u8, 97u8, 100u8, 105u8, 116u8, 105u8, 111u8,
+                    110u8, 97u8, 108u8, 32u8, 83u8, 111u8, 114u8, 116u8, 32u8, 79u8, 114u8, 100u8,
+                    101u8, 114u8, 82u8, 97u8, 100u8, 105u8, 99u8, 97u8, 108u8, 45u8, 83u8, 116u8,
+                    114u8, 111u8, 107u8, 101u8, 32u8, 83u8, 111u8, 114u8, 116u8, 32u8, 79u8, 114u8,
+                    100u8, 101u8, 114u8, 90u8, 104u8, 117u8, 121u8, 105u8, 110u8, 32u8, 83u8,
+                    111u8, 114u8, 116u8, 32u8, 79u8, 114u8, 100u8, 101u8, 114u8, 85u8, 115u8,
+                    101u8, 32u8, 68u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 32u8, 80u8, 114u8,
+                    101u8, 115u8, 101u8, 110u8, 116u8, 97u8, 116u8, 105u8, 111u8, 110u8, 32u8,
+                    70u8, 111u8, 114u8, 32u8, 69u8, 109u8, 111u8, 106u8, 105u8, 32u8, 67u8, 104u8,
+                    97u8, 114u8, 97u8, 99u8, 116u8, 101u8, 114u8, 115u8, 80u8, 114u8, 101u8, 102u8,
+                    101u8, 114u8, 32u8, 69u8, 109u8, 111u8, 106u8, 105u8, 32u8, 80u8, 114u8, 101u8,
+                    115u8, 101u8, 110u8, 116u8, 97u8, 116u8, 105u8, 111u8, 110u8, 32u8, 70u8,
+                    111u8, 114u8, 32u8, 69u8, 109u8, 111u8, 106u8, 105u8, 32u8, 67u8, 104u8, 97u8,
+                    114u8, 97u8, 99u8, 116u8, 101u8, 114u8, 115u8, 80u8, 114u8, 101u8, 102u8,
+                    101u8, 114u8, 32u8, 84u8, 101u8, 120u8, 116u8, 32u8, 80u8, 114u8, 101u8, 115u8,
+                    101u8, 110u8, 116u8, 97u8, 116u8, 105u8, 111u8, 110u8, 32u8, 70u8, 111u8,
+                    114u8, 32u8, 69u8, 109u8, 111u8, 106u8, 105u8, 32u8, 67u8, 104u8, 97u8, 114u8,
+                    97u8, 99u8, 116u8, 101u8, 114u8, 115u8, 70u8, 105u8, 114u8, 115u8, 116u8, 32u8,
+                    68u8, 97u8, 121u8, 32u8, 111u8, 102u8, 32u8, 87u8, 101u8, 101u8, 107u8, 32u8,
+                    73u8, 115u8, 32u8, 70u8, 114u8, 105u8, 100u8, 97u8, 121u8, 70u8, 105u8, 114u8,
+                    115u8, 116u8, 32u8, 68u8, 97u8, 121u8, 32u8, 111u8, 102u8, 32u8, 87u8, 101u8,
+                    101u8, 107u8, 32u8, 73u8, 115u8, 32u8, 77u8, 111u8, 110u8, 100u8, 97u8, 121u8,
+                    70u8, 105u8, 114u8, 115u8, 116u8, 32u8, 68u8, 97u8, 121u8, 32u8, 111u8, 102u8,
+                    32u8, 87u8, 101u8, 101u8, 107u8, 32u8, 73u8, 115u8, 32u8, 83u8, 97u8, 116u8,
+                    117u8, 114u8, 100u8, 97u8, 121u8, 70u8, 105u8, 114u8, 115u8, 116u8, 32u8, 68u8,
+                    97u8, 121u8, 32u8, 111u8, 102u8, 32u8, 87u8, 101u8, 101u8, 107u8, 32u8, 73u8,
+                    115u8, 32u8, 83u8, 117u8, 110u8, 100u8, 97u8, 121u8, 70u8, 105u8, 114u8, 115u8,
+                    116u8, 32u8, 68u8, 97u8, 121u8, 32u8, 111u8, 102u8, 32u8, 87u8, 101u8, 101u8,
+                    107u8, 32u8, 73u8, 115u8, 32u8, 84u8, 104u8, 117u8, 114u8, 115u8, 100u8, 97u8,
+                    121u8, 70u8, 105u8, 114u8, 115u8, 116u8, 32u8, 68u8, 97u8, 121u8, 32u8, 111u8,
+                    102u8, 32u8, 87u8, 101u8, 101u8, 107u8, 32u8, 73u8, 115u8, 32u8, 84u8, 117u8,
+                    101u8, 115u8, 100u8, 97u8, 121u8, 70u8, 105u8, 114u8, 115u8, 116u8, 32u8, 68u8,
+                    97u8, 121u8, 32u8, 111u8, 102u8, 32u8, 87u8, 101u8, 101u8, 107u8, 32u8, 73u8,
+                    115u8, 32u8, 87u8, 101u8, 100u8, 110u8, 101u8, 115u8, 100u8, 97u8, 121u8, 49u8,
+                    50u8, 32u8, 72u8, 111u8, 117u8, 114u8, 32u8, 83u8, 121u8, 115u8, 116u8, 101u8,
+                    109u8, 32u8, 40u8, 48u8, 226u8, 128u8, 147u8, 49u8, 49u8, 41u8, 49u8, 50u8,
+                    32u8, 72u8, 111u8, 117u8, 114u8, 32u8, 83u8, 121u8, 115u8, 116u8, 101u8, 109u8,
+                    32u8, 40u8, 49u8, 226u8, 128u8, 147u8, 49u8, 50u8, 41u8, 50u8, 52u8, 32u8,
+                    72u8, 111u8, 117u8, 114u8, 32u8, 83u8, 121u8, 115u8, 116u8, 101u8, 109u8, 32u8,
+                    40u8, 48u8, 226u8, 128u8, 147u8, 50u8, 51u8, 41u8, 50u8, 52u8, 32u8, 72u8,
+                    111u8, 117u8, 114u8, 32u8, 83u8, 121u8, 115u8, 116u8, 101u8, 109u8, 32u8, 40u8,
+                    49u8, 226u8, 128u8, 147u8, 50u8, 52u8, 41u8, 83u8, 111u8, 114u8, 116u8, 32u8,
+                    83u8, 121u8, 109u8, 98u8, 111u8, 108u8, 115u8, 83u8, 111u8, 114u8, 116u8, 32u8,
+                    73u8, 103u8, 110u8, 111u8, 114u8, 105u8, 110u8, 103u8, 32u8, 83u8, 121u8,
+                    109u8, 98u8, 111u8, 108u8, 115u8, 83u8, 111u8, 114u8, 116u8, 32u8, 65u8, 99u8,
+                    99u8, 101u8, 110u8, 116u8, 115u8, 32u8, 78u8, 111u8, 114u8, 109u8, 97u8, 108u8,
+                    108u8, 121u8, 83u8, 111u8, 114u8, 116u8, 32u8, 65u8, 99u8, 99u8, 101u8, 110u8,
+                    116u8, 115u8, 32u8, 82u8, 101u8, 118u8, 101u8, 114u8, 115u8, 101u8, 100u8,
+                    83u8, 111u8, 114u8, 116u8, 32u8, 67u8, 97u8, 115u8, 101u8, 32u8, 73u8, 110u8,
+                    115u8, 101u8, 110u8, 115u8, 105u8, 116u8, 105u8, 118u8, 101u8, 83u8, 111u8,
+                    114u8, 116u8, 32u8, 67u8, 97u8, 115u8, 101u8, 32u8, 83u8, 101u8, 110u8, 115u8,
+                    105u8, 116u8, 105u8, 118u8, 101u8, 83u8, 111u8, 114u8, 116u8, 32u8, 78u8,
+                    111u8, 114u8, 109u8, 97u8, 108u8, 32u8, 67u8, 97u8, 115u8, 101u8, 32u8, 79u8,
+                    114u8, 100u8, 101u8, 114u8, 83u8, 111u8, 114u8, 116u8, 32u8, 76u8, 111u8,
+                    119u8, 101u8, 114u8, 99u8, 97u8, 115u8, 101u8, 32u8, 70u8, 105u8, 114u8, 115u8,
+                    116u8, 83u8, 111u8, 114u8, 116u8, 32u8, 85u8, 112u8, 112u8, 101u8, 114u8, 99u8,
+                    97u8, 115u8, 101u8, 32u8, 70u8, 105u8, 114u8, 115u8, 116u8, 83u8, 111u8, 114u8,
+                    116u8, 32u8, 87u8, 105u8, 116u8, 104u8, 111u8, 117u8, 116u8, 32u8, 78u8, 111u8,
+                    114u8, 109u8, 97u8, 108u8, 105u8, 122u8, 97u8, 116u8, 105u8, 111u8, 110u8,
+                    83u8, 111u8, 114u8, 116u8, 32u8, 85u8, 110u8, 105u8, 99u8, 111u8, 100u8, 101u8,
+                    32u8, 78u8, 111u8, 114u8, 109u8, 97u8, 108u8, 105u8, 122u8, 101u8, 100u8, 83u8,
+                    111u8, 114u8, 116u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 32u8, 73u8,
+                    110u8, 100u8, 105u8, 118u8, 105u8, 100u8, 117u8, 97u8, 108u8, 108u8, 121u8,
+                    83u8, 111u8, 114u8, 116u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 32u8,
+                    78u8, 117u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 108u8, 108u8, 121u8, 67u8,
+                    117u8, 114u8, 114u8, 101u8, 110u8, 99u8, 121u8, 68u8, 105u8, 103u8, 105u8,
+                    116u8, 115u8, 80u8, 117u8, 110u8, 99u8, 116u8, 117u8, 97u8, 116u8, 105u8,
+                    111u8, 110u8, 87u8, 104u8, 105u8, 116u8, 101u8, 115u8, 112u8, 97u8, 99u8,
+                    101u8, 83u8, 121u8, 109u8, 98u8, 111u8, 108u8, 83u8, 111u8, 114u8, 116u8, 32u8,
+                    65u8, 108u8, 108u8, 83u8, 111u8, 114u8, 116u8, 32u8, 66u8, 97u8, 115u8, 101u8,
+                    32u8, 76u8, 101u8, 116u8, 116u8, 101u8, 114u8, 115u8, 32u8, 79u8, 110u8, 108u8,
+                    121u8, 83u8, 111u8, 114u8, 116u8, 32u8, 65u8, 99u8, 99u8, 101u8, 110u8, 116u8,
+                    115u8, 83u8, 111u8, 114u8, 116u8, 32u8, 65u8, 99u8, 99u8, 101u8, 110u8, 116u8,
+                    115u8, 47u8, 67u8, 97u8, 115u8, 101u8, 47u8, 87u8, 105u8, 100u8, 116u8, 104u8,
+                    83u8, 111u8, 114u8, 116u8, 32u8, 65u8, 99u8, 99u8, 101u8, 110u8, 116u8, 115u8,
+                    47u8, 67u8, 97u8, 115u8, 101u8, 47u8, 87u8, 105u8, 100u8, 116u8, 104u8, 47u8,
+                    75u8, 97u8, 110u8, 97u8, 73u8, 103u8, 110u8, 111u8, 114u8, 101u8, 32u8, 83u8,
+                    121u8, 109u8, 98u8, 111u8, 108u8, 115u8, 32u8, 97u8, 102u8, 102u8, 101u8, 99u8,
+                    116u8, 115u8, 32u8, 115u8, 112u8, 97u8, 99u8, 101u8, 115u8, 44u8, 32u8, 112u8,
+                    117u8, 110u8, 99u8, 116u8, 117u8, 97u8, 116u8, 105u8, 111u8, 110u8, 44u8, 32u8,
+                    97u8, 108u8, 108u8, 32u8, 115u8, 121u8, 109u8, 98u8, 111u8, 108u8, 115u8, 73u8,
+                    103u8, 110u8, 111u8, 114u8, 101u8, 32u8, 83u8, 121u8, 109u8, 98u8, 111u8,
+                    108u8, 115u8, 32u8, 97u8, 102u8, 102u8, 101u8, 99u8, 116u8, 115u8, 32u8, 115u8,
+                    112u8, 97u8, 99u8, 101u8, 115u8, 32u8, 97u8, 110u8, 100u8, 32u8, 112u8, 117u8,
+                    110u8, 99u8, 116u8, 117u8, 97u8, 116u8, 105u8, 111u8, 110u8, 32u8, 111u8,
+                    110u8, 108u8, 121u8, 73u8, 103u8, 110u8, 111u8, 114u8, 101u8, 32u8, 83u8,
+                    121u8, 109u8, 98u8, 111u8, 108u8, 115u8, 32u8, 97u8, 102u8, 102u8, 101u8, 99u8,
+                    116u8, 115u8, 32u8, 115u8, 112u8, 97u8, 99u8, 101u8, 115u8, 32u8, 111u8, 110u8,
+                    108u8, 121u8, 73u8, 103u8, 110u8, 111u8, 114u8, 101u8, 32u8, 83u8, 121u8,
+                    109u8, 98u8, 111u8, 108u8, 115u8, 32u8, 97u8, 102u8, 102u8, 101u8, 99u8, 116u8,
+                    115u8, 32u8, 115u8, 112u8, 97u8, 99u8, 101u8, 115u8, 44u8, 32u8, 112u8, 117u8,
+                    110u8, 99u8, 116u8, 117u8, 97u8, 116u8, 105u8, 111u8, 110u8, 44u8, 32u8, 110u8,
+                    111u8, 110u8, 45u8, 99u8, 117u8, 114u8, 114u8, 101u8, 110u8, 99u8, 121u8, 32u8,
+                    115u8, 121u8, 109u8, 98u8, 111u8, 108u8, 115u8, 76u8, 111u8, 111u8, 115u8,
+                    101u8, 32u8, 76u8, 105u8, 110u8, 101u8, 32u8, 66u8, 114u8, 101u8, 97u8, 107u8,
+                    32u8, 83u8, 116u8, 121u8, 108u8, 101u8, 78u8, 111u8, 114u8, 109u8, 97u8, 108u8,
+                    32u8, 76u8, 105u8, 110u8, 101u8, 32u8, 66u8, 114u8, 101u8, 97u8, 107u8, 32u8,
+                    83u8, 116u8, 121u8, 108u8, 101u8, 83u8, 116u8, 114u8, 105u8, 99u8, 116u8, 32u8,
+                    76u8, 105u8, 110u8, 101u8, 32u8, 66u8, 114u8, 101u8, 97u8, 107u8, 32u8, 83u8,
+                    116u8, 121u8, 108u8, 101u8, 65u8, 108u8, 108u8, 111u8, 119u8, 32u8, 76u8,
+                    105u8, 110u8, 101u8, 32u8, 66u8, 114u8, 101u8, 97u8, 107u8, 115u8, 32u8, 73u8,
+                    110u8, 32u8, 65u8, 108u8, 108u8, 32u8, 87u8, 111u8, 114u8, 100u8, 115u8, 80u8,
+                    114u8, 101u8, 118u8, 101u8, 110u8, 116u8, 32u8, 76u8, 105u8, 110u8, 101u8,
+                    32u8, 66u8, 114u8, 101u8, 97u8, 107u8, 115u8, 32u8, 73u8, 110u8, 32u8, 65u8,
+                    108u8, 108u8, 32u8, 87u8, 111u8, 114u8, 100u8, 115u8, 78u8, 111u8, 114u8,
+                    109u8, 97u8, 108u8, 32u8, 76u8, 105u8, 110u8, 101u8, 32u8, 66u8, 114u8, 101u8,
+                    97u8, 107u8, 115u8, 32u8, 70u8, 111u8, 114u8, 32u8, 87u8, 111u8, 114u8, 100u8,
+                    115u8, 80u8, 114u8, 101u8, 118u8, 101u8, 110u8, 116u8, 32u8, 76u8, 105u8,
+                    110u8, 101u8, 32u8, 66u8, 114u8, 101u8, 97u8, 107u8, 115u8, 32u8, 73u8, 110u8,
+                    32u8, 80u8, 104u8, 114u8, 97u8, 115u8, 101u8, 115u8, 77u8, 101u8, 116u8, 114u8,
+                    105u8, 99u8, 32u8, 83u8, 121u8, 115u8, 116u8, 101u8, 109u8, 73u8, 109u8, 112u8,
+                    101u8, 114u8, 105u8, 97u8, 108u8, 32u8, 77u8, 101u8, 97u8, 115u8, 117u8, 114u8,
+                    101u8, 109u8, 101u8, 110u8, 116u8, 32u8, 83u8, 121u8, 115u8, 116u8, 101u8,
+                    109u8, 85u8, 83u8, 32u8, 77u8, 101u8, 97u8, 115u8, 117u8, 114u8, 101u8, 109u8,
+                    101u8, 110u8, 116u8, 32u8, 83u8, 121u8, 115u8, 116u8, 101u8, 109u8, 67u8,
+                    101u8, 108u8, 115u8, 105u8, 117u8, 115u8, 70u8, 97u8, 104u8, 114u8, 101u8,
+                    110u8, 104u8, 101u8, 105u8, 116u8, 75u8, 101u8, 108u8, 118u8, 105u8, 110u8,
+                    65u8, 100u8, 108u8, 97u8, 109u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8,
+                    65u8, 104u8, 111u8, 109u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 65u8,
+                    114u8, 97u8, 98u8, 105u8, 99u8, 45u8, 73u8, 110u8, 100u8, 105u8, 99u8, 32u8,
+                    68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 69u8, 120u8, 116u8, 101u8, 110u8,
+                    100u8, 101u8, 100u8, 32u8, 65u8, 114u8, 97u8, 98u8, 105u8, 99u8, 45u8, 73u8,
+                    110u8, 100u8, 105u8, 99u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 65u8,
+                    114u8, 109u8, 101u8, 110u8, 105u8, 97u8, 110u8, 32u8, 78u8, 117u8, 109u8,
+                    101u8, 114u8, 97u8, 108u8, 115u8, 65u8, 114u8, 109u8, 101u8, 110u8, 105u8,
+                    97u8, 110u8, 32u8, 76u8, 111u8, 119u8, 101u8, 114u8, 99u8, 97u8, 115u8, 101u8,
+                    32u8, 78u8, 117u8, 109u8, 101u8, 114u8, 97u8, 108u8, 115u8, 66u8, 97u8, 108u8,
+                    105u8, 110u8, 101u8, 115u8, 101u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8,
+                    115u8, 66u8, 97u8, 110u8, 103u8, 108u8, 97u8, 32u8, 68u8, 105u8, 103u8, 105u8,
+                    116u8, 115u8, 66u8, 104u8, 97u8, 105u8, 107u8, 115u8, 117u8, 107u8, 105u8,
+                    32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 66u8, 114u8, 97u8, 104u8, 109u8,
+                    105u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 67u8, 104u8, 97u8, 107u8,
+                    109u8, 97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 67u8, 104u8, 97u8,
+                    109u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 67u8, 121u8, 114u8,
+                    105u8, 108u8, 108u8, 105u8, 99u8, 32u8, 78u8, 117u8, 109u8, 101u8, 114u8, 97u8,
+                    108u8, 115u8, 68u8, 101u8, 118u8, 97u8, 110u8, 97u8, 103u8, 97u8, 114u8, 105u8,
+                    32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 68u8, 105u8, 118u8, 101u8,
+                    115u8, 32u8, 65u8, 107u8, 117u8, 114u8, 117u8, 32u8, 68u8, 105u8, 103u8, 105u8,
+                    116u8, 115u8, 69u8, 116u8, 104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 32u8, 78u8,
+                    117u8, 109u8, 101u8, 114u8, 97u8, 108u8, 115u8, 70u8, 105u8, 110u8, 97u8,
+                    110u8, 99u8, 105u8, 97u8, 108u8, 32u8, 78u8, 117u8, 109u8, 101u8, 114u8, 97u8,
+                    108u8, 115u8, 70u8, 117u8, 108u8, 108u8, 45u8, 87u8, 105u8, 100u8, 116u8,
+                    104u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 71u8, 101u8, 111u8,
+                    114u8, 103u8, 105u8, 97u8, 110u8, 32u8, 78u8, 117u8, 109u8, 101u8, 114u8, 97u8,
+                    108u8, 115u8, 71u8, 117u8, 110u8, 106u8, 97u8, 108u8, 97u8, 32u8, 71u8, 111u8,
+                    110u8, 100u8, 105u8, 32u8, 100u8, 105u8, 103u8, 105u8, 116u8, 115u8, 77u8,
+                    97u8, 115u8, 97u8, 114u8, 97u8, 109u8, 32u8, 71u8, 111u8, 110u8, 100u8, 105u8,
+                    32u8, 100u8, 105u8, 103u8, 105u8, 116u8, 115u8, 71u8, 114u8, 101u8, 101u8,
+                    107u8, 32u8, 78u8, 117u8, 109u8, 101u8, 114u8, 97u8, 108u8, 115u8, 71u8, 114u8,
+                    101u8, 101u8, 107u8, 32u8, 76u8, 111u8, 119u8, 101u8, 114u8, 99u8, 97u8, 115u8,
+                    101u8, 32u8, 78u8, 117u8, 109u8, 101u8, 114u8, 97u8, 108u8, 115u8, 71u8, 117u8,
+                    106u8, 97u8, 114u8, 97u8, 116u8, 105u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8,
+                    115u8, 71u8, 117u8, 114u8, 109u8, 117u8, 107u8, 104u8, 105u8, 32u8, 68u8,
+                    105u8, 103u8, 105u8, 116u8, 115u8, 67u8, 104u8, 105u8, 110u8, 101u8, 115u8,
+                    101u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 32u8, 68u8,
+                    97u8, 121u8, 45u8, 111u8, 102u8, 45u8, 77u8, 111u8, 110u8, 116u8, 104u8, 32u8,
+                    78u8, 117u8, 109u8, 101u8, 114u8, 97u8, 108u8, 115u8, 67u8, 104u8, 105u8,
+                    110u8, 101u8, 115u8, 101u8, 32u8, 68u8, 101u8, 99u8, 105u8, 109u8, 97u8, 108u8,
+                    32u8, 78u8, 117u8, 109u8, 101u8, 114u8, 97u8, 108u8, 115u8, 83u8, 105u8, 109u8,
+                    112u8, 108u8, 105u8, 102u8, 105u8, 101u8, 100u8, 32u8, 67u8, 104u8, 105u8,
+                    110u8, 101u8, 115u8, 101u8, 32u8, 78u8, 117u8, 109u8, 101u8, 114u8, 97u8,
+                    108u8, 115u8, 83u8, 105u8, 109u8, 112u8, 108u8, 105u8, 102u8, 105u8, 101u8,
+                    100u8, 32u8, 67u8, 104u8, 105u8, 110u8, 101u8, 115u8, 101u8, 32u8, 70u8, 105u8,
+                    110u8, 97u8, 110u8, 99u8, 105u8, 97u8, 108u8, 32u8, 78u8, 117u8, 109u8, 101u8,
+                    114u8, 97u8, 108u8, 115u8, 84u8, 114u8, 97u8, 100u8, 105u8, 116u8, 105u8,
+                    111u8, 110u8, 97u8, 108u8, 32u8, 67u8, 104u8, 105u8, 110u8, 101u8, 115u8,
+                    101u8, 32u8, 78u8, 117u8, 109u8, 101u8, 114u8, 97u8, 108u8, 115u8, 84u8, 114u8,
+                    97u8, 100u8, 105u8, 116u8, 105u8, 111u8, 110u8, 97u8, 108u8, 32u8, 67u8, 104u8,
+                    105u8, 110u8, 101u8, 115u8, 101u8, 32u8, 70u8, 105u8, 110u8, 97u8, 110u8, 99u8,
+                    105u8, 97u8, 108u8, 32u8, 78u8, 117u8, 109u8, 101u8, 114u8, 97u8, 108u8, 115u8,
+                    72u8, 101u8, 98u8, 114u8, 101u8, 119u8, 32u8, 78u8, 117u8, 109u8, 101u8, 114u8,
+                    97u8, 108u8, 115u8, 80u8, 97u8, 104u8, 97u8, 119u8, 104u8, 32u8, 72u8, 109u8,
+                    111u8, 110u8, 103u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 78u8,
+                    121u8, 105u8, 97u8, 107u8, 101u8, 110u8, 103u8, 32u8, 80u8, 117u8, 97u8, 99u8,
+                    104u8, 117u8, 101u8, 32u8, 72u8, 109u8, 111u8, 110u8, 103u8, 32u8, 68u8, 105u8,
+                    103u8, 105u8, 116u8, 115u8, 74u8, 97u8, 118u8, 97u8, 110u8, 101u8, 115u8,
+                    101u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 74u8, 97u8, 112u8, 97u8,
+                    110u8, 101u8, 115u8, 101u8, 32u8, 78u8, 117u8, 109u8, 101u8, 114u8, 97u8,
+                    108u8, 115u8, 74u8, 97u8, 112u8, 97u8, 110u8, 101u8, 115u8, 101u8, 32u8, 70u8,
+                    105u8, 110u8, 97u8, 110u8, 99u8, 105u8, 97u8, 108u8, 32u8, 78u8, 117u8, 109u8,
+                    101u8, 114u8, 97u8, 108u8, 115u8, 74u8, 97u8, 112u8, 97u8, 110u8, 101u8, 115u8,
+                    101u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 32u8, 71u8,
+                    97u8, 110u8, 110u8, 101u8, 110u8, 32u8, 89u8, 101u8, 97u8, 114u8, 32u8, 78u8,
+                    117u8, 109u8, 101u8, 114u8, 97u8, 108u8, 115u8, 75u8, 97u8, 121u8, 97u8, 104u8,
+                    32u8, 76u8, 105u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 75u8, 97u8,
+                    119u8, 105u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 75u8, 104u8,
+                    109u8, 101u8, 114u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 75u8, 97u8,
+                    110u8, 110u8, 97u8, 100u8, 97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8,
+                    84u8, 97u8, 105u8, 32u8, 84u8, 104u8, 97u8, 109u8, 32u8, 72u8, 111u8, 114u8,
+                    97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 84u8, 97u8, 105u8, 32u8,
+                    84u8, 104u8, 97u8, 109u8, 32u8, 84u8, 104u8, 97u8, 109u8, 32u8, 68u8, 105u8,
+                    103u8, 105u8, 116u8, 115u8, 76u8, 97u8, 111u8, 32u8, 68u8, 105u8, 103u8, 105u8,
+                    116u8, 115u8, 87u8, 101u8, 115u8, 116u8, 101u8, 114u8, 110u8, 32u8, 68u8,
+                    105u8, 103u8, 105u8, 116u8, 115u8, 76u8, 101u8, 112u8, 99u8, 104u8, 97u8, 32u8,
+                    68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 76u8, 105u8, 109u8, 98u8, 117u8, 32u8,
+                    68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 77u8, 97u8, 116u8, 104u8, 101u8,
+                    109u8, 97u8, 116u8, 105u8, 99u8, 97u8, 108u8, 32u8, 66u8, 111u8, 108u8, 100u8,
+                    32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 77u8, 97u8, 116u8, 104u8, 101u8,
+                    109u8, 97u8, 116u8, 105u8, 99u8, 97u8, 108u8, 32u8, 68u8, 111u8, 117u8, 98u8,
+                    108u8, 101u8, 45u8, 83u8, 116u8, 114u8, 117u8, 99u8, 107u8, 32u8, 68u8, 105u8,
+                    103u8, 105u8, 116u8, 115u8, 77u8, 97u8, 116u8, 104u8, 101u8, 109u8, 97u8,
+                    116u8, 105u8, 99u8, 97u8, 108u8, 32u8, 77u8, 111u8, 110u8, 111u8, 115u8, 112u8,
+                    97u8, 99u8, 101u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 77u8, 97u8,
+                    116u8, 104u8, 101u8, 109u8, 97u8, 116u8, 105u8, 99u8, 97u8, 108u8, 32u8, 83u8,
+                    97u8, 110u8, 115u8, 45u8, 83u8, 101u8, 114u8, 105u8, 102u8, 32u8, 66u8, 111u8,
+                    108u8, 100u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 77u8, 97u8, 116u8,
+                    104u8, 101u8, 109u8, 97u8, 116u8, 105u8, 99u8, 97u8, 108u8, 32u8, 83u8, 97u8,
+                    110u8, 115u8, 45u8, 83u8, 101u8, 114u8, 105u8, 102u8, 32u8, 68u8, 105u8, 103u8,
+                    105u8, 116u8, 115u8, 77u8, 97u8, 108u8, 97u8, 121u8, 97u8, 108u8, 97u8, 109u8,
+                    32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 77u8, 111u8, 100u8, 105u8, 32u8,
+                    68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 77u8, 111u8, 110u8, 103u8, 111u8,
+                    108u8, 105u8, 97u8, 110u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 77u8,
+                    114u8, 111u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 77u8, 101u8,
+                    101u8, 116u8, 101u8, 105u8, 32u8, 77u8, 97u8, 121u8, 101u8, 107u8, 32u8, 68u8,
+                    105u8, 103u8, 105u8, 116u8, 115u8, 77u8, 121u8, 97u8, 110u8, 109u8, 97u8,
+                    114u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 77u8, 121u8, 97u8, 110u8,
+                    109u8, 97u8, 114u8, 32u8, 83u8, 104u8, 97u8, 110u8, 32u8, 68u8, 105u8, 103u8,
+                    105u8, 116u8, 115u8, 77u8, 121u8, 97u8, 110u8, 109u8, 97u8, 114u8, 32u8, 84u8,
+                    97u8, 105u8, 32u8, 76u8, 97u8, 105u8, 110u8, 103u8, 32u8, 68u8, 105u8, 103u8,
+                    105u8, 116u8, 115u8, 78u8, 97u8, 103u8, 32u8, 77u8, 117u8, 110u8, 100u8, 97u8,
+                    114u8, 105u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 78u8, 97u8, 116u8,
+                    105u8, 118u8, 101u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 78u8,
+                    101u8, 119u8, 97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 78u8, 226u8,
+                    128u8, 153u8, 75u8, 111u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 79u8,
+                    108u8, 32u8, 67u8, 104u8, 105u8, 107u8, 105u8, 32u8, 68u8, 105u8, 103u8, 105u8,
+                    116u8, 115u8, 79u8, 100u8, 105u8, 97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8,
+                    115u8, 79u8, 115u8, 109u8, 97u8, 110u8, 121u8, 97u8, 32u8, 68u8, 105u8, 103u8,
+                    105u8, 116u8, 115u8, 72u8, 97u8, 110u8, 105u8, 102u8, 105u8, 32u8, 82u8, 111u8,
+                    104u8, 105u8, 110u8, 103u8, 121u8, 97u8, 32u8, 100u8, 105u8, 103u8, 105u8,
+                    116u8, 115u8, 82u8, 111u8, 109u8, 97u8, 110u8, 32u8, 78u8, 117u8, 109u8, 101u8,
+                    114u8, 97u8, 108u8, 115u8, 82u8, 111u8, 109u8, 97u8, 110u8, 32u8, 76u8, 111u8,
+                    119u8, 101u8, 114u8, 99u8, 97u8, 115u8, 101u8, 32u8, 78u8, 117u8, 109u8, 101u8,
+                    114u8, 97u8, 108u8, 115u8, 83u8, 97u8, 117u8, 114u8, 97u8, 115u8, 104u8, 116u8,
+                    114u8, 97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 83u8, 101u8, 103u8,
+                    109u8, 101u8, 110u8, 116u8, 101u8, 100u8, 32u8, 68u8, 105u8, 103u8, 105u8,
+                    116u8, 115u8, 83u8, 104u8, 97u8, 114u8, 97u8, 100u8, 97u8, 32u8, 68u8, 105u8,
+                    103u8, 105u8, 116u8, 115u8, 75u8, 104u8, 117u8, 100u8, 97u8, 119u8, 97u8,
+                    100u8, 105u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 83u8, 105u8,
+                    110u8, 104u8, 97u8, 108u8, 97u8, 32u8, 76u8, 105u8, 116u8, 104u8, 32u8, 68u8,
+                    105u8, 103u8, 105u8, 116u8, 115u8, 83u8, 111u8, 114u8, 97u8, 32u8, 83u8, 111u8,
+                    109u8, 112u8, 101u8, 110u8, 103u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8,
+                    115u8, 83u8, 117u8, 110u8, 100u8, 97u8, 110u8, 101u8, 115u8, 101u8, 32u8, 68u8,
+                    105u8, 103u8, 105u8, 116u8, 115u8, 84u8, 97u8, 107u8, 114u8, 105u8, 32u8, 68u8,
+                    105u8, 103u8, 105u8, 116u8, 115u8, 78u8, 101u8, 119u8, 32u8, 84u8, 97u8, 105u8,
+                    32u8, 76u8, 117u8, 101u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 84u8,
+                    114u8, 97u8, 100u8, 105u8, 116u8, 105u8, 111u8, 110u8, 97u8, 108u8, 32u8, 84u8,
+                    97u8, 109u8, 105u8, 108u8, 32u8, 78u8, 117u8, 109u8, 101u8, 114u8, 97u8, 108u8,
+                    115u8, 84u8, 97u8, 109u8, 105u8, 108u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8,
+                    115u8, 84u8, 101u8, 108u8, 117u8, 103u8, 117u8, 32u8, 68u8, 105u8, 103u8,
+                    105u8, 116u8, 115u8, 84u8, 104u8, 97u8, 105u8, 32u8, 68u8, 105u8, 103u8, 105u8,
+                    116u8, 115u8, 84u8, 105u8, 98u8, 101u8, 116u8, 97u8, 110u8, 32u8, 68u8, 105u8,
+                    103u8, 105u8, 116u8, 115u8, 84u8, 105u8, 114u8, 104u8, 117u8, 116u8, 97u8,
+                    32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 84u8, 97u8, 110u8, 103u8, 115u8,
+                    97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 84u8, 114u8, 97u8, 100u8,
+                    105u8, 116u8, 105u8, 111u8, 110u8, 97u8, 108u8, 32u8, 78u8, 117u8, 109u8,
+                    101u8, 114u8, 97u8, 108u8, 115u8, 86u8, 97u8, 105u8, 32u8, 68u8, 105u8, 103u8,
+                    105u8, 116u8, 115u8, 87u8, 97u8, 114u8, 97u8, 110u8, 103u8, 32u8, 67u8, 105u8,
+                    116u8, 105u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 87u8, 97u8, 110u8,
+                    99u8, 104u8, 111u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 83u8, 101u8,
+                    110u8, 116u8, 101u8, 110u8, 99u8, 101u8, 32u8, 66u8, 114u8, 101u8, 97u8, 107u8,
+                    115u8, 32u8, 87u8, 105u8, 116u8, 104u8, 111u8, 117u8, 116u8, 32u8, 65u8, 98u8,
+                    98u8, 114u8, 101u8, 118u8, 105u8, 97u8, 116u8, 105u8, 111u8, 110u8, 32u8, 72u8,
+                    97u8, 110u8, 100u8, 108u8, 105u8, 110u8, 103u8, 83u8, 117u8, 112u8, 112u8,
+                    114u8, 101u8, 115u8, 115u8, 32u8, 83u8, 101u8, 110u8, 116u8, 101u8, 110u8,
+                    99u8, 101u8, 32u8, 66u8, 114u8, 101u8, 97u8, 107u8, 115u8, 32u8, 65u8, 102u8,
+                    116u8, 101u8, 114u8, 32u8, 83u8, 116u8, 97u8, 110u8, 100u8, 97u8, 114u8, 100u8,
+                    32u8, 65u8, 98u8, 98u8, 114u8, 101u8, 118u8, 105u8, 97u8, 116u8, 105u8, 111u8,
+                    110u8, 115u8, 80u8, 79u8, 83u8, 73u8, 88u8, 32u8, 67u8, 111u8, 109u8, 112u8,
+                    108u8, 105u8, 97u8, 110u8, 116u8, 32u8, 76u8, 111u8, 99u8, 97u8, 108u8, 101u8,
+                ])
+            },
+        )
+    },
+};
+static EN_001_EN_ZA: &DataStruct = &::icu_displaynames::provider::KeyDisplayNamesV1 {
+    keys: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    99u8, 97u8, 99u8, 102u8, 99u8, 111u8, 99u8, 117u8, 100u8, 120u8, 101u8, 109u8,
+                    102u8, 119u8, 104u8, 99u8, 107u8, 97u8, 107u8, 98u8, 107u8, 99u8, 107u8, 102u8,
+                    107u8, 107u8, 107u8, 110u8, 107u8, 114u8, 107u8, 115u8, 107u8, 118u8, 108u8,
+                    98u8, 108u8, 119u8, 109u8, 115u8, 109u8, 117u8, 110u8, 117u8, 114u8, 103u8,
+                    115u8, 100u8, 115u8, 115u8, 116u8, 122u8, 118u8, 97u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    27u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 23u8, 0u8, 33u8, 0u8, 41u8, 0u8, 68u8,
+                    0u8, 92u8, 0u8, 109u8, 0u8, 130u8, 0u8, 152u8, 0u8, 175u8, 0u8, 197u8, 0u8,
+                    225u8, 0u8, 243u8, 0u8, 2u8, 1u8, 25u8, 1u8, 41u8, 1u8, 56u8, 1u8, 72u8, 1u8,
+                    100u8, 1u8, 118u8, 1u8, 134u8, 1u8, 141u8, 1u8, 169u8, 1u8, 187u8, 1u8, 219u8,
+                    1u8, 228u8, 1u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 67u8,
+                    117u8, 114u8, 114u8, 101u8, 110u8, 99u8, 121u8, 32u8, 70u8, 111u8, 114u8,
+                    109u8, 97u8, 116u8, 83u8, 111u8, 114u8, 116u8, 32u8, 79u8, 114u8, 100u8, 101u8,
+                    114u8, 67u8, 117u8, 114u8, 114u8, 101u8, 110u8, 99u8, 121u8, 68u8, 105u8, 99u8,
+                    116u8, 105u8, 111u8, 110u8, 97u8, 114u8, 121u8, 32u8, 66u8, 114u8, 101u8, 97u8,
+                    107u8, 32u8, 69u8, 120u8, 99u8, 108u8, 117u8, 115u8, 105u8, 111u8, 110u8,
+                    115u8, 69u8, 109u8, 111u8, 106u8, 105u8, 32u8, 80u8, 114u8, 101u8, 115u8,
+                    101u8, 110u8, 116u8, 97u8, 116u8, 105u8, 111u8, 110u8, 32u8, 83u8, 116u8,
+                    121u8, 108u8, 101u8, 70u8, 105u8, 114u8, 115u8, 116u8, 32u8, 100u8, 97u8,
+                    121u8, 32u8, 111u8, 102u8, 32u8, 119u8, 101u8, 101u8, 107u8, 72u8, 111u8,
+                    117u8, 114u8, 32u8, 67u8, 121u8, 99u8, 108u8, 101u8, 32u8, 40u8, 49u8, 50u8,
+                    32u8, 118u8, 115u8, 32u8, 50u8, 52u8, 41u8, 73u8, 103u8, 110u8, 111u8, 114u8,
+                    101u8, 32u8, 83u8, 121u8, 109u8, 98u8, 111u8, 108u8, 115u8, 32u8, 83u8, 111u8,
+                    114u8, 116u8, 105u8, 110u8, 103u8, 82u8, 101u8, 118u8, 101u8, 114u8, 115u8,
+                    101u8, 100u8, 32u8, 65u8, 99u8, 99u8, 101u8, 110u8, 116u8, 32u8, 83u8, 111u8,
+                    114u8, 116u8, 105u8, 110u8, 103u8, 67u8, 97u8, 115u8, 101u8, 45u8, 83u8, 101u8,
+                    110u8, 115u8, 105u8, 116u8, 105u8, 118u8, 101u8, 32u8, 83u8, 111u8, 114u8,
+                    116u8, 105u8, 110u8, 103u8, 85u8, 112u8, 112u8, 101u8, 114u8, 99u8, 97u8,
+                    115u8, 101u8, 47u8, 76u8, 111u8, 119u8, 101u8, 114u8, 99u8, 97u8, 115u8, 101u8,
+                    32u8, 79u8, 114u8, 100u8, 101u8, 114u8, 105u8, 110u8, 103u8, 78u8, 111u8,
+                    114u8, 109u8, 97u8, 108u8, 105u8, 115u8, 101u8, 100u8, 32u8, 83u8, 111u8,
+                    114u8, 116u8, 105u8, 110u8, 103u8, 78u8, 117u8, 109u8, 101u8, 114u8, 105u8,
+                    99u8, 32u8, 83u8, 111u8, 114u8, 116u8, 105u8, 110u8, 103u8, 83u8, 99u8, 114u8,
+                    105u8, 112u8, 116u8, 47u8, 66u8, 108u8, 111u8, 99u8, 107u8, 32u8, 82u8, 101u8,
+                    111u8, 114u8, 100u8, 101u8, 114u8, 105u8, 110u8, 103u8, 83u8, 111u8, 114u8,
+                    116u8, 105u8, 110u8, 103u8, 32u8, 83u8, 116u8, 114u8, 101u8, 110u8, 103u8,
+                    116u8, 104u8, 72u8, 105u8, 103u8, 104u8, 101u8, 115u8, 116u8, 32u8, 73u8,
+                    103u8, 110u8, 111u8, 114u8, 101u8, 100u8, 76u8, 105u8, 110u8, 101u8, 32u8,
+                    66u8, 114u8, 101u8, 97u8, 107u8, 32u8, 83u8, 116u8, 121u8, 108u8, 101u8, 76u8,
+                    105u8, 110u8, 101u8, 32u8, 66u8, 114u8, 101u8, 97u8, 107u8, 115u8, 32u8, 73u8,
+                    110u8, 32u8, 87u8, 111u8, 114u8, 100u8, 115u8, 32u8, 83u8, 101u8, 116u8, 116u8,
+                    105u8, 110u8, 103u8, 77u8, 101u8, 97u8, 115u8, 117u8, 114u8, 101u8, 109u8,
+                    101u8, 110u8, 116u8, 32u8, 83u8, 121u8, 115u8, 116u8, 101u8, 109u8, 77u8,
+                    101u8, 97u8, 115u8, 117u8, 114u8, 101u8, 109u8, 101u8, 110u8, 116u8, 32u8,
+                    85u8, 110u8, 105u8, 116u8, 78u8, 117u8, 109u8, 98u8, 101u8, 114u8, 115u8, 82u8,
+                    101u8, 103u8, 105u8, 111u8, 110u8, 32u8, 70u8, 111u8, 114u8, 32u8, 83u8, 117u8,
+                    112u8, 112u8, 108u8, 101u8, 109u8, 101u8, 110u8, 116u8, 97u8, 108u8, 32u8,
+                    68u8, 97u8, 116u8, 97u8, 82u8, 101u8, 103u8, 105u8, 111u8, 110u8, 32u8, 83u8,
+                    117u8, 98u8, 100u8, 105u8, 118u8, 105u8, 115u8, 105u8, 111u8, 110u8, 83u8,
+                    101u8, 110u8, 116u8, 101u8, 110u8, 99u8, 101u8, 32u8, 66u8, 114u8, 101u8, 97u8,
+                    107u8, 32u8, 83u8, 117u8, 112u8, 112u8, 114u8, 101u8, 115u8, 115u8, 105u8,
+                    111u8, 110u8, 115u8, 32u8, 84u8, 121u8, 112u8, 101u8, 84u8, 105u8, 109u8,
+                    101u8, 32u8, 90u8, 111u8, 110u8, 101u8, 76u8, 111u8, 99u8, 97u8, 108u8, 101u8,
+                    32u8, 86u8, 97u8, 114u8, 105u8, 97u8, 110u8, 116u8,
+                ])
+            },
+        )
+    },
+    types: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    186u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 21u8, 0u8, 30u8, 0u8, 38u8, 0u8,
+                    48u8, 0u8, 59u8, 0u8, 69u8, 0u8, 78u8, 0u8, 87u8, 0u8, 97u8, 0u8, 113u8, 0u8,
+                    128u8, 0u8, 143u8, 0u8, 162u8, 0u8, 172u8, 0u8, 183u8, 0u8, 193u8, 0u8, 199u8,
+                    0u8, 209u8, 0u8, 220u8, 0u8, 230u8, 0u8, 239u8, 0u8, 246u8, 0u8, 254u8, 0u8,
+                    6u8, 1u8, 12u8, 1u8, 21u8, 1u8, 31u8, 1u8, 42u8, 1u8, 51u8, 1u8, 62u8, 1u8,
+                    71u8, 1u8, 82u8, 1u8, 93u8, 1u8, 102u8, 1u8, 109u8, 1u8, 118u8, 1u8, 127u8,
+                    1u8, 137u8, 1u8, 145u8, 1u8, 152u8, 1u8, 158u8, 1u8, 164u8, 1u8, 170u8, 1u8,
+                    176u8, 1u8, 182u8, 1u8, 188u8, 1u8, 194u8, 1u8, 200u8, 1u8, 206u8, 1u8, 212u8,
+                    1u8, 218u8, 1u8, 229u8, 1u8, 239u8, 1u8, 247u8, 1u8, 254u8, 1u8, 6u8, 2u8,
+                    13u8, 2u8, 21u8, 2u8, 29u8, 2u8, 37u8, 2u8, 45u8, 2u8, 52u8, 2u8, 60u8, 2u8,
+                    67u8, 2u8, 78u8, 2u8, 86u8, 2u8, 94u8, 2u8, 102u8, 2u8, 111u8, 2u8, 121u8, 2u8,
+                    130u8, 2u8, 139u8, 2u8, 148u8, 2u8, 157u8, 2u8, 168u8, 2u8, 176u8, 2u8, 184u8,
+                    2u8, 193u8, 2u8, 201u8, 2u8, 210u8, 2u8, 219u8, 2u8, 230u8, 2u8, 240u8, 2u8,
+                    249u8, 2u8, 2u8, 3u8, 11u8, 3u8, 22u8, 3u8, 33u8, 3u8, 43u8, 3u8, 54u8, 3u8,
+                    63u8, 3u8, 70u8, 3u8, 77u8, 3u8, 84u8, 3u8, 94u8, 3u8, 101u8, 3u8, 111u8, 3u8,
+                    118u8, 3u8, 125u8, 3u8, 132u8, 3u8, 139u8, 3u8, 146u8, 3u8, 153u8, 3u8, 160u8,
+                    3u8, 167u8, 3u8, 174u8, 3u8, 181u8, 3u8, 191u8, 3u8, 202u8, 3u8, 209u8, 3u8,
+                    216u8, 3u8, 223u8, 3u8, 230u8, 3u8, 240u8, 3u8, 247u8, 3u8, 254u8, 3u8, 9u8,
+                    4u8, 19u8, 4u8, 26u8, 4u8, 36u8, 4u8, 43u8, 4u8, 53u8, 4u8, 60u8, 4u8, 67u8,
+                    4u8, 74u8, 4u8, 81u8, 4u8, 88u8, 4u8, 98u8, 4u8, 109u8, 4u8, 116u8, 4u8, 123u8,
+                    4u8, 130u8, 4u8, 137u8, 4u8, 144u8, 4u8, 155u8, 4u8, 162u8, 4u8, 169u8, 4u8,
+                    176u8, 4u8, 183u8, 4u8, 194u8, 4u8, 204u8, 4u8, 215u8, 4u8, 226u8, 4u8, 237u8,
+                    4u8, 244u8, 4u8, 251u8, 4u8, 2u8, 5u8, 9u8, 5u8, 16u8, 5u8, 23u8, 5u8, 34u8,
+                    5u8, 45u8, 5u8, 52u8, 5u8, 61u8, 5u8, 68u8, 5u8, 75u8, 5u8, 82u8, 5u8, 89u8,
+                    5u8, 96u8, 5u8, 103u8, 5u8, 111u8, 5u8, 122u8, 5u8, 129u8, 5u8, 139u8, 5u8,
+                    146u8, 5u8, 153u8, 5u8, 160u8, 5u8, 167u8, 5u8, 174u8, 5u8, 181u8, 5u8, 188u8,
+                    5u8, 195u8, 5u8, 205u8, 5u8, 212u8, 5u8, 219u8, 5u8, 226u8, 5u8, 233u8, 5u8,
+                    240u8, 5u8, 251u8, 5u8, 2u8, 6u8, 9u8, 6u8, 16u8, 6u8, 23u8, 6u8, 34u8, 6u8,
+                    99u8, 97u8, 45u8, 98u8, 117u8, 100u8, 100u8, 104u8, 105u8, 115u8, 116u8, 99u8,
+                    97u8, 45u8, 99u8, 104u8, 105u8, 110u8, 101u8, 115u8, 101u8, 99u8, 97u8, 45u8,
+                    99u8, 111u8, 112u8, 116u8, 105u8, 99u8, 99u8, 97u8, 45u8, 100u8, 97u8, 110u8,
+                    103u8, 105u8, 99u8, 97u8, 45u8, 101u8, 116u8, 104u8, 105u8, 111u8, 97u8, 97u8,
+                    99u8, 97u8, 45u8, 101u8, 116u8, 104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 99u8,
+                    97u8, 45u8, 103u8, 114u8, 101u8, 103u8, 111u8, 114u8, 121u8, 99u8, 97u8, 45u8,
+                    104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 99u8, 97u8, 45u8, 105u8, 110u8, 100u8,
+                    105u8, 97u8, 110u8, 99u8, 97u8, 45u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8,
+                    99u8, 99u8, 97u8, 45u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8,
+                    99u8, 105u8, 118u8, 105u8, 108u8, 99u8, 97u8, 45u8, 105u8, 115u8, 108u8, 97u8,
+                    109u8, 105u8, 99u8, 45u8, 114u8, 103u8, 115u8, 97u8, 99u8, 97u8, 45u8, 105u8,
+                    115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 116u8, 98u8, 108u8, 97u8, 99u8,
+                    97u8, 45u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 117u8, 109u8,
+                    97u8, 108u8, 113u8, 117u8, 114u8, 97u8, 99u8, 97u8, 45u8, 105u8, 115u8, 111u8,
+                    56u8, 54u8, 48u8, 49u8, 99u8, 97u8, 45u8, 106u8, 97u8, 112u8, 97u8, 110u8,
+                    101u8, 115u8, 101u8, 99u8, 97u8, 45u8, 112u8, 101u8, 114u8, 115u8, 105u8, 97u8,
+                    110u8, 99u8, 97u8, 45u8, 114u8, 111u8, 99u8, 99u8, 102u8, 45u8, 97u8, 99u8,
+                    99u8, 111u8, 117u8, 110u8, 116u8, 99u8, 102u8, 45u8, 115u8, 116u8, 97u8, 110u8,
+                    100u8, 97u8, 114u8, 100u8, 99u8, 111u8, 45u8, 98u8, 105u8, 103u8, 53u8, 104u8,
+                    97u8, 110u8, 99u8, 111u8, 45u8, 99u8, 111u8, 109u8, 112u8, 97u8, 116u8, 99u8,
+                    111u8, 45u8, 100u8, 105u8, 99u8, 116u8, 99u8, 111u8, 45u8, 100u8, 117u8, 99u8,
+                    101u8, 116u8, 99u8, 111u8, 45u8, 101u8, 109u8, 111u8, 106u8, 105u8, 99u8,
+                    111u8, 45u8, 101u8, 111u8, 114u8, 99u8, 111u8, 45u8, 103u8, 98u8, 50u8, 51u8,
+                    49u8, 50u8, 99u8, 111u8, 45u8, 112u8, 104u8, 111u8, 110u8, 101u8, 98u8, 107u8,
+                    99u8, 111u8, 45u8, 112u8, 104u8, 111u8, 110u8, 101u8, 116u8, 105u8, 99u8, 99u8,
+                    111u8, 45u8, 112u8, 105u8, 110u8, 121u8, 105u8, 110u8, 99u8, 111u8, 45u8,
+                    114u8, 101u8, 102u8, 111u8, 114u8, 109u8, 101u8, 100u8, 99u8, 111u8, 45u8,
+                    115u8, 101u8, 97u8, 114u8, 99u8, 104u8, 99u8, 111u8, 45u8, 115u8, 101u8, 97u8,
+                    114u8, 99u8, 104u8, 106u8, 108u8, 99u8, 111u8, 45u8, 115u8, 116u8, 97u8, 110u8,
+                    100u8, 97u8, 114u8, 100u8, 99u8, 111u8, 45u8, 115u8, 116u8, 114u8, 111u8,
+                    107u8, 101u8, 99u8, 111u8, 45u8, 116u8, 114u8, 97u8, 100u8, 99u8, 111u8, 45u8,
+                    117u8, 110u8, 105u8, 104u8, 97u8, 110u8, 99u8, 111u8, 45u8, 122u8, 104u8,
+                    117u8, 121u8, 105u8, 110u8, 101u8, 109u8, 45u8, 100u8, 101u8, 102u8, 97u8,
+                    117u8, 108u8, 116u8, 101u8, 109u8, 45u8, 101u8, 109u8, 111u8, 106u8, 105u8,
+                    101u8, 109u8, 45u8, 116u8, 101u8, 120u8, 116u8, 102u8, 119u8, 45u8, 102u8,
+                    114u8, 105u8, 102u8, 119u8, 45u8, 109u8, 111u8, 110u8, 102u8, 119u8, 45u8,
+                    115u8, 97u8, 116u8, 102u8, 119u8, 45u8, 115u8, 117u8, 110u8, 102u8, 119u8,
+                    45u8, 116u8, 104u8, 117u8, 102u8, 119u8, 45u8, 116u8, 117u8, 101u8, 102u8,
+                    119u8, 45u8, 119u8, 101u8, 100u8, 104u8, 99u8, 45u8, 104u8, 49u8, 49u8, 104u8,
+                    99u8, 45u8, 104u8, 49u8, 50u8, 104u8, 99u8, 45u8, 104u8, 50u8, 51u8, 104u8,
+                    99u8, 45u8, 104u8, 50u8, 52u8, 107u8, 97u8, 45u8, 110u8, 111u8, 105u8, 103u8,
+                    110u8, 111u8, 114u8, 101u8, 107u8, 97u8, 45u8, 115u8, 104u8, 105u8, 102u8,
+                    116u8, 101u8, 100u8, 107u8, 98u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8,
+                    107u8, 98u8, 45u8, 116u8, 114u8, 117u8, 101u8, 107u8, 99u8, 45u8, 102u8, 97u8,
+                    108u8, 115u8, 101u8, 107u8, 99u8, 45u8, 116u8, 114u8, 117u8, 101u8, 107u8,
+                    102u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8, 107u8, 102u8, 45u8, 108u8,
+                    111u8, 119u8, 101u8, 114u8, 107u8, 102u8, 45u8, 117u8, 112u8, 112u8, 101u8,
+                    114u8, 107u8, 107u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8, 107u8, 107u8,
+                    45u8, 116u8, 114u8, 117u8, 101u8, 107u8, 110u8, 45u8, 102u8, 97u8, 108u8,
+                    115u8, 101u8, 107u8, 110u8, 45u8, 116u8, 114u8, 117u8, 101u8, 107u8, 114u8,
+                    45u8, 99u8, 117u8, 114u8, 114u8, 101u8, 110u8, 99u8, 121u8, 107u8, 114u8, 45u8,
+                    100u8, 105u8, 103u8, 105u8, 116u8, 107u8, 114u8, 45u8, 112u8, 117u8, 110u8,
+                    99u8, 116u8, 107u8, 114u8, 45u8, 115u8, 112u8, 97u8, 99u8, 101u8, 107u8, 114u8,
+                    45u8, 115u8, 121u8, 109u8, 98u8, 111u8, 108u8, 107u8, 115u8, 45u8, 105u8,
+                    100u8, 101u8, 110u8, 116u8, 105u8, 99u8, 107u8, 115u8, 45u8, 108u8, 101u8,
+                    118u8, 101u8, 108u8, 49u8, 107u8, 115u8, 45u8, 108u8, 101u8, 118u8, 101u8,
+                    108u8, 50u8, 107u8, 115u8, 45u8, 108u8, 101u8, 118u8, 101u8, 108u8, 51u8,
+                    107u8, 115u8, 45u8, 108u8, 101u8, 118u8, 101u8, 108u8, 52u8, 107u8, 118u8,
+                    45u8, 99u8, 117u8, 114u8, 114u8, 101u8, 110u8, 99u8, 121u8, 107u8, 118u8, 45u8,
+                    112u8, 117u8, 110u8, 99u8, 116u8, 107u8, 118u8, 45u8, 115u8, 112u8, 97u8, 99u8,
+                    101u8, 107u8, 118u8, 45u8, 115u8, 121u8, 109u8, 98u8, 111u8, 108u8, 108u8,
+                    98u8, 45u8, 108u8, 111u8, 111u8, 115u8, 101u8, 108u8, 98u8, 45u8, 110u8, 111u8,
+                    114u8, 109u8, 97u8, 108u8, 108u8, 98u8, 45u8, 115u8, 116u8, 114u8, 105u8, 99u8,
+                    116u8, 108u8, 119u8, 45u8, 98u8, 114u8, 101u8, 97u8, 107u8, 97u8, 108u8, 108u8,
+                    108u8, 119u8, 45u8, 107u8, 101u8, 101u8, 112u8, 97u8, 108u8, 108u8, 108u8,
+                    119u8, 45u8, 110u8, 111u8, 114u8, 109u8, 97u8, 108u8, 108u8, 119u8, 45u8,
+                    112u8, 104u8, 114u8, 97u8, 115u8, 101u8, 109u8, 115u8, 45u8, 109u8, 101u8,
+                    116u8, 114u8, 105u8, 99u8, 109u8, 115u8, 45u8, 117u8, 107u8, 115u8, 121u8,
+                    115u8, 116u8, 101u8, 109u8, 109u8, 115u8, 45u8, 117u8, 115u8, 115u8, 121u8,
+                    115u8, 116u8, 101u8, 109u8, 109u8, 117u8, 45u8, 99u8, 101u8, 108u8, 115u8,
+                    105u8, 117u8, 115u8, 109u8, 117u8, 45u8, 102u8, 97u8, 104u8, 114u8, 101u8,
+                    110u8, 104u8, 101u8, 109u8, 117u8, 45u8, 107u8, 101u8, 108u8, 118u8, 105u8,
+                    110u8, 110u8, 117u8, 45u8, 97u8, 100u8, 108u8, 109u8, 110u8, 117u8, 45u8, 97u8,
+                    104u8, 111u8, 109u8, 110u8, 117u8, 45u8, 97u8, 114u8, 97u8, 98u8, 110u8, 117u8,
+                    45u8, 97u8, 114u8, 97u8, 98u8, 101u8, 120u8, 116u8, 110u8, 117u8, 45u8, 97u8,
+                    114u8, 109u8, 110u8, 110u8, 117u8, 45u8, 97u8, 114u8, 109u8, 110u8, 108u8,
+                    111u8, 119u8, 110u8, 117u8, 45u8, 98u8, 97u8, 108u8, 105u8, 110u8, 117u8, 45u8,
+                    98u8, 101u8, 110u8, 103u8, 110u8, 117u8, 45u8, 98u8, 104u8, 107u8, 115u8,
+                    110u8, 117u8, 45u8, 98u8, 114u8, 97u8, 104u8, 110u8, 117u8, 45u8, 99u8, 97u8,
+                    107u8, 109u8, 110u8, 117u8, 45u8, 99u8, 104u8, 97u8, 109u8, 110u8, 117u8, 45u8,
+                    99u8, 121u8, 114u8, 108u8, 110u8, 117u8, 45u8, 100u8, 101u8, 118u8, 97u8,
+                    110u8, 117u8, 45u8, 100u8, 105u8, 97u8, 107u8, 110u8, 117u8, 45u8, 101u8,
+                    116u8, 104u8, 105u8, 110u8, 117u8, 45u8, 102u8, 105u8, 110u8, 97u8, 110u8,
+                    99u8, 101u8, 110u8, 117u8, 45u8, 102u8, 117u8, 108u8, 108u8, 119u8, 105u8,
+                    100u8, 101u8, 110u8, 117u8, 45u8, 103u8, 101u8, 111u8, 114u8, 110u8, 117u8,
+                    45u8, 103u8, 111u8, 110u8, 103u8, 110u8, 117u8, 45u8, 103u8, 111u8, 110u8,
+                    109u8, 110u8, 117u8, 45u8, 103u8, 114u8, 101u8, 107u8, 110u8, 117u8, 45u8,
+                    103u8, 114u8, 101u8, 107u8, 108u8, 111u8, 119u8, 110u8, 117u8, 45u8, 103u8,
+                    117u8, 106u8, 114u8, 110u8, 117u8, 45u8, 103u8, 117u8, 114u8, 117u8, 110u8,
+                    117u8, 45u8, 104u8, 97u8, 110u8, 105u8, 100u8, 97u8, 121u8, 115u8, 110u8,
+                    117u8, 45u8, 104u8, 97u8, 110u8, 105u8, 100u8, 101u8, 99u8, 110u8, 117u8, 45u8,
+                    104u8, 97u8, 110u8, 115u8, 110u8, 117u8, 45u8, 104u8, 97u8, 110u8, 115u8,
+                    102u8, 105u8, 110u8, 110u8, 117u8, 45u8, 104u8, 97u8, 110u8, 116u8, 110u8,
+                    117u8, 45u8, 104u8, 97u8, 110u8, 116u8, 102u8, 105u8, 110u8, 110u8, 117u8,
+                    45u8, 104u8, 101u8, 98u8, 114u8, 110u8, 117u8, 45u8, 104u8, 109u8, 110u8,
+                    103u8, 110u8, 117u8, 45u8, 104u8, 109u8, 110u8, 112u8, 110u8, 117u8, 45u8,
+                    106u8, 97u8, 118u8, 97u8, 110u8, 117u8, 45u8, 106u8, 112u8, 97u8, 110u8, 110u8,
+                    117u8, 45u8, 106u8, 112u8, 97u8, 110u8, 102u8, 105u8, 110u8, 110u8, 117u8,
+                    45u8, 106u8, 112u8, 97u8, 110u8, 121u8, 101u8, 97u8, 114u8, 110u8, 117u8, 45u8,
+                    107u8, 97u8, 108u8, 105u8, 110u8, 117u8, 45u8, 107u8, 97u8, 119u8, 105u8,
+                    110u8, 117u8, 45u8, 107u8, 104u8, 109u8, 114u8, 110u8, 117u8, 45u8, 107u8,
+                    110u8, 100u8, 97u8, 110u8, 117u8, 45u8, 108u8, 97u8, 110u8, 97u8, 110u8, 117u8,
+                    45u8, 108u8, 97u8, 110u8, 97u8, 116u8, 104u8, 97u8, 109u8, 110u8, 117u8, 45u8,
+                    108u8, 97u8, 111u8, 111u8, 110u8, 117u8, 45u8, 108u8, 97u8, 116u8, 110u8,
+                    110u8, 117u8, 45u8, 108u8, 101u8, 112u8, 99u8, 110u8, 117u8, 45u8, 108u8,
+                    105u8, 109u8, 98u8, 110u8, 117u8, 45u8, 109u8, 97u8, 116u8, 104u8, 98u8, 111u8,
+                    108u8, 100u8, 110u8, 117u8, 45u8, 109u8, 97u8, 116u8, 104u8, 100u8, 98u8,
+                    108u8, 110u8, 117u8, 45u8, 109u8, 97u8, 116u8, 104u8, 109u8, 111u8, 110u8,
+                    111u8, 110u8, 117u8, 45u8, 109u8, 97u8, 116u8, 104u8, 115u8, 97u8, 110u8, 98u8,
+                    110u8, 117u8, 45u8, 109u8, 97u8, 116u8, 104u8, 115u8, 97u8, 110u8, 115u8,
+                    110u8, 117u8, 45u8, 109u8, 108u8, 121u8, 109u8, 110u8, 117u8, 45u8, 109u8,
+                    111u8, 100u8, 105u8, 110u8, 117u8, 45u8, 109u8, 111u8, 110u8, 103u8, 110u8,
+                    117u8, 45u8, 109u8, 114u8, 111u8, 111u8, 110u8, 117u8, 45u8, 109u8, 116u8,
+                    101u8, 105u8, 110u8, 117u8, 45u8, 109u8, 121u8, 109u8, 114u8, 110u8, 117u8,
+                    45u8, 109u8, 121u8, 109u8, 114u8, 115u8, 104u8, 97u8, 110u8, 110u8, 117u8,
+                    45u8, 109u8, 121u8, 109u8, 114u8, 116u8, 108u8, 110u8, 103u8, 110u8, 117u8,
+                    45u8, 110u8, 97u8, 103u8, 109u8, 110u8, 117u8, 45u8, 110u8, 97u8, 116u8, 105u8,
+                    118u8, 101u8, 110u8, 117u8, 45u8, 110u8, 101u8, 119u8, 97u8, 110u8, 117u8,
+                    45u8, 110u8, 107u8, 111u8, 111u8, 110u8, 117u8, 45u8, 111u8, 108u8, 99u8,
+                    107u8, 110u8, 117u8, 45u8, 111u8, 114u8, 121u8, 97u8, 110u8, 117u8, 45u8,
+                    111u8, 115u8, 109u8, 97u8, 110u8, 117u8, 45u8, 114u8, 111u8, 104u8, 103u8,
+                    110u8, 117u8, 45u8, 114u8, 111u8, 109u8, 97u8, 110u8, 110u8, 117u8, 45u8,
+                    114u8, 111u8, 109u8, 97u8, 110u8, 108u8, 111u8, 119u8, 110u8, 117u8, 45u8,
+                    115u8, 97u8, 117u8, 114u8, 110u8, 117u8, 45u8, 115u8, 101u8, 103u8, 109u8,
+                    101u8, 110u8, 116u8, 110u8, 117u8, 45u8, 115u8, 104u8, 114u8, 100u8, 110u8,
+                    117u8, 45u8, 115u8, 105u8, 110u8, 100u8, 110u8, 117u8, 45u8, 115u8, 105u8,
+                    110u8, 104u8, 110u8, 117u8, 45u8, 115u8, 111u8, 114u8, 97u8, 110u8, 117u8,
+                    45u8, 115u8, 117u8, 110u8, 100u8, 110u8, 117u8, 45u8, 116u8, 97u8, 107u8,
+                    114u8, 110u8, 117u8, 45u8, 116u8, 97u8, 108u8, 117u8, 110u8, 117u8, 45u8,
+                    116u8, 97u8, 109u8, 108u8, 110u8, 117u8, 45u8, 116u8, 97u8, 109u8, 108u8,
+                    100u8, 101u8, 99u8, 110u8, 117u8, 45u8, 116u8, 101u8, 108u8, 117u8, 110u8,
+                    117u8, 45u8, 116u8, 104u8, 97u8, 105u8, 110u8, 117u8, 45u8, 116u8, 105u8, 98u8,
+                    116u8, 110u8, 117u8, 45u8, 116u8, 105u8, 114u8, 104u8, 110u8, 117u8, 45u8,
+                    116u8, 110u8, 115u8, 97u8, 110u8, 117u8, 45u8, 116u8, 114u8, 97u8, 100u8,
+                    105u8, 116u8, 105u8, 111u8, 110u8, 117u8, 45u8, 118u8, 97u8, 105u8, 105u8,
+                    110u8, 117u8, 45u8, 119u8, 97u8, 114u8, 97u8, 110u8, 117u8, 45u8, 119u8, 99u8,
+                    104u8, 111u8, 115u8, 115u8, 45u8, 110u8, 111u8, 110u8, 101u8, 115u8, 115u8,
+                    45u8, 115u8, 116u8, 97u8, 110u8, 100u8, 97u8, 114u8, 100u8, 118u8, 97u8, 45u8,
+                    112u8, 111u8, 115u8, 105u8, 120u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    186u8, 0u8, 0u8, 0u8, 0u8, 0u8, 17u8, 0u8, 33u8, 0u8, 48u8, 0u8, 62u8, 0u8,
+                    90u8, 0u8, 107u8, 0u8, 125u8, 0u8, 140u8, 0u8, 164u8, 0u8, 180u8, 0u8, 219u8,
+                    0u8, 4u8, 1u8, 50u8, 1u8, 80u8, 1u8, 97u8, 1u8, 114u8, 1u8, 130u8, 1u8, 145u8,
+                    1u8, 171u8, 1u8, 195u8, 1u8, 232u8, 1u8, 14u8, 2u8, 35u8, 2u8, 61u8, 2u8, 77u8,
+                    2u8, 100u8, 2u8, 138u8, 2u8, 158u8, 2u8, 177u8, 2u8, 194u8, 2u8, 213u8, 2u8,
+                    235u8, 2u8, 13u8, 3u8, 32u8, 3u8, 49u8, 3u8, 71u8, 3u8, 96u8, 3u8, 113u8, 3u8,
+                    158u8, 3u8, 204u8, 3u8, 249u8, 3u8, 20u8, 4u8, 47u8, 4u8, 76u8, 4u8, 103u8,
+                    4u8, 132u8, 4u8, 160u8, 4u8, 190u8, 4u8, 213u8, 4u8, 236u8, 4u8, 3u8, 5u8,
+                    26u8, 5u8, 38u8, 5u8, 59u8, 5u8, 80u8, 5u8, 101u8, 5u8, 122u8, 5u8, 141u8, 5u8,
+                    163u8, 5u8, 183u8, 5u8, 203u8, 5u8, 229u8, 5u8, 252u8, 5u8, 20u8, 6u8, 43u8,
+                    6u8, 51u8, 6u8, 57u8, 6u8, 68u8, 6u8, 78u8, 6u8, 84u8, 6u8, 92u8, 6u8, 114u8,
+                    6u8, 126u8, 6u8, 149u8, 6u8, 177u8, 6u8, 232u8, 6u8, 26u8, 7u8, 60u8, 7u8,
+                    124u8, 7u8, 146u8, 7u8, 169u8, 7u8, 192u8, 7u8, 222u8, 7u8, 254u8, 7u8, 26u8,
+                    8u8, 56u8, 8u8, 69u8, 8u8, 96u8, 8u8, 117u8, 8u8, 124u8, 8u8, 134u8, 8u8,
+                    140u8, 8u8, 152u8, 8u8, 163u8, 8u8, 182u8, 8u8, 210u8, 8u8, 227u8, 8u8, 254u8,
+                    8u8, 13u8, 9u8, 26u8, 9u8, 42u8, 9u8, 55u8, 9u8, 68u8, 9u8, 79u8, 9u8, 96u8,
+                    9u8, 113u8, 9u8, 131u8, 9u8, 148u8, 9u8, 166u8, 9u8, 183u8, 9u8, 200u8, 9u8,
+                    220u8, 9u8, 240u8, 9u8, 254u8, 9u8, 22u8, 10u8, 37u8, 10u8, 52u8, 10u8, 90u8,
+                    10u8, 114u8, 10u8, 141u8, 10u8, 178u8, 10u8, 206u8, 10u8, 244u8, 10u8, 3u8,
+                    11u8, 22u8, 11u8, 51u8, 11u8, 66u8, 11u8, 83u8, 11u8, 110u8, 11u8, 148u8, 11u8,
+                    163u8, 11u8, 174u8, 11u8, 186u8, 11u8, 200u8, 11u8, 220u8, 11u8, 240u8, 11u8,
+                    250u8, 11u8, 8u8, 12u8, 21u8, 12u8, 33u8, 12u8, 57u8, 12u8, 90u8, 12u8, 119u8,
+                    12u8, 154u8, 12u8, 184u8, 12u8, 200u8, 12u8, 211u8, 12u8, 227u8, 12u8, 237u8,
+                    12u8, 0u8, 13u8, 14u8, 13u8, 33u8, 13u8, 57u8, 13u8, 75u8, 13u8, 88u8, 13u8,
+                    99u8, 13u8, 112u8, 13u8, 127u8, 13u8, 138u8, 13u8, 152u8, 13u8, 174u8, 13u8,
+                    188u8, 13u8, 212u8, 13u8, 229u8, 13u8, 245u8, 13u8, 3u8, 14u8, 19u8, 14u8,
+                    38u8, 14u8, 57u8, 14u8, 73u8, 14u8, 85u8, 14u8, 103u8, 14u8, 129u8, 14u8,
+                    141u8, 14u8, 154u8, 14u8, 165u8, 14u8, 179u8, 14u8, 193u8, 14u8, 206u8, 14u8,
+                    226u8, 14u8, 236u8, 14u8, 254u8, 14u8, 11u8, 15u8, 56u8, 15u8, 109u8, 15u8,
+                    66u8, 117u8, 100u8, 100u8, 104u8, 105u8, 115u8, 116u8, 32u8, 67u8, 97u8, 108u8,
+                    101u8, 110u8, 100u8, 97u8, 114u8, 67u8, 104u8, 105u8, 110u8, 101u8, 115u8,
+                    101u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 67u8, 111u8,
+                    112u8, 116u8, 105u8, 99u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8,
+                    114u8, 68u8, 97u8, 110u8, 103u8, 105u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8,
+                    100u8, 97u8, 114u8, 69u8, 116u8, 104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 32u8,
+                    65u8, 109u8, 101u8, 116u8, 101u8, 32u8, 65u8, 108u8, 101u8, 109u8, 32u8, 67u8,
+                    97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 69u8, 116u8, 104u8, 105u8,
+                    111u8, 112u8, 105u8, 99u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8,
+                    114u8, 71u8, 114u8, 101u8, 103u8, 111u8, 114u8, 105u8, 97u8, 110u8, 32u8, 67u8,
+                    97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 72u8, 101u8, 98u8, 114u8, 101u8,
+                    119u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 73u8, 110u8,
+                    100u8, 105u8, 97u8, 110u8, 32u8, 78u8, 97u8, 116u8, 105u8, 111u8, 110u8, 97u8,
+                    108u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 73u8, 115u8,
+                    108u8, 97u8, 109u8, 105u8, 99u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8,
+                    97u8, 114u8, 73u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 32u8, 67u8, 97u8,
+                    108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 32u8, 40u8, 116u8, 97u8, 98u8, 117u8,
+                    108u8, 97u8, 114u8, 44u8, 32u8, 99u8, 105u8, 118u8, 105u8, 108u8, 32u8, 101u8,
+                    112u8, 111u8, 99u8, 104u8, 41u8, 73u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8,
+                    32u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 32u8, 40u8, 83u8,
+                    97u8, 117u8, 100u8, 105u8, 32u8, 65u8, 114u8, 97u8, 98u8, 105u8, 97u8, 44u8,
+                    32u8, 115u8, 105u8, 103u8, 104u8, 116u8, 105u8, 110u8, 103u8, 41u8, 73u8,
+                    115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8,
+                    100u8, 97u8, 114u8, 32u8, 40u8, 116u8, 97u8, 98u8, 117u8, 108u8, 97u8, 114u8,
+                    44u8, 32u8, 97u8, 115u8, 116u8, 114u8, 111u8, 110u8, 111u8, 109u8, 105u8, 99u8,
+                    97u8, 108u8, 32u8, 101u8, 112u8, 111u8, 99u8, 104u8, 41u8, 73u8, 115u8, 108u8,
+                    97u8, 109u8, 105u8, 99u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8,
+                    114u8, 32u8, 40u8, 85u8, 109u8, 109u8, 32u8, 97u8, 108u8, 45u8, 81u8, 117u8,
+                    114u8, 97u8, 41u8, 73u8, 83u8, 79u8, 45u8, 56u8, 54u8, 48u8, 49u8, 32u8, 67u8,
+                    97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 74u8, 97u8, 112u8, 97u8, 110u8,
+                    101u8, 115u8, 101u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8,
+                    80u8, 101u8, 114u8, 115u8, 105u8, 97u8, 110u8, 32u8, 67u8, 97u8, 108u8, 101u8,
+                    110u8, 100u8, 97u8, 114u8, 77u8, 105u8, 110u8, 103u8, 117u8, 111u8, 32u8, 67u8,
+                    97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 65u8, 99u8, 99u8, 111u8, 117u8,
+                    110u8, 116u8, 105u8, 110u8, 103u8, 32u8, 67u8, 117u8, 114u8, 114u8, 101u8,
+                    110u8, 99u8, 121u8, 32u8, 70u8, 111u8, 114u8, 109u8, 97u8, 116u8, 83u8, 116u8,
+                    97u8, 110u8, 100u8, 97u8, 114u8, 100u8, 32u8, 67u8, 117u8, 114u8, 114u8, 101u8,
+                    110u8, 99u8, 121u8, 32u8, 70u8, 111u8, 114u8, 109u8, 97u8, 116u8, 84u8, 114u8,
+                    97u8, 100u8, 105u8, 116u8, 105u8, 111u8, 110u8, 97u8, 108u8, 32u8, 67u8, 104u8,
+                    105u8, 110u8, 101u8, 115u8, 101u8, 32u8, 83u8, 111u8, 114u8, 116u8, 32u8, 79u8,
+                    114u8, 100u8, 101u8, 114u8, 32u8, 45u8, 32u8, 66u8, 105u8, 103u8, 53u8, 80u8,
+                    114u8, 101u8, 118u8, 105u8, 111u8, 117u8, 115u8, 32u8, 83u8, 111u8, 114u8,
+                    116u8, 32u8, 79u8, 114u8, 100u8, 101u8, 114u8, 44u8, 32u8, 102u8, 111u8, 114u8,
+                    32u8, 99u8, 111u8, 109u8, 112u8, 97u8, 116u8, 105u8, 98u8, 105u8, 108u8, 105u8,
+                    116u8, 121u8, 68u8, 105u8, 99u8, 116u8, 105u8, 111u8, 110u8, 97u8, 114u8,
+                    121u8, 32u8, 83u8, 111u8, 114u8, 116u8, 32u8, 79u8, 114u8, 100u8, 101u8, 114u8,
+                    68u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 32u8, 85u8, 110u8, 105u8, 99u8,
+                    111u8, 100u8, 101u8, 32u8, 83u8, 111u8, 114u8, 116u8, 32u8, 79u8, 114u8, 100u8,
+                    101u8, 114u8, 69u8, 109u8, 111u8, 106u8, 105u8, 32u8, 83u8, 111u8, 114u8,
+                    116u8, 32u8, 79u8, 114u8, 100u8, 101u8, 114u8, 69u8, 117u8, 114u8, 111u8,
+                    112u8, 101u8, 97u8, 110u8, 32u8, 79u8, 114u8, 100u8, 101u8, 114u8, 105u8,
+                    110u8, 103u8, 32u8, 82u8, 117u8, 108u8, 101u8, 115u8, 83u8, 105u8, 109u8,
+                    112u8, 108u8, 105u8, 102u8, 105u8, 101u8, 100u8, 32u8, 67u8, 104u8, 105u8,
+                    110u8, 101u8, 115u8, 101u8, 32u8, 83u8, 111u8, 114u8, 116u8, 32u8, 79u8, 114u8,
+                    100u8, 101u8, 114u8, 32u8, 45u8, 32u8, 71u8, 66u8, 50u8, 51u8, 49u8, 50u8,
+                    80u8, 104u8, 111u8, 110u8, 101u8, 98u8, 111u8, 111u8, 107u8, 32u8, 83u8, 111u8,
+                    114u8, 116u8, 32u8, 79u8, 114u8, 100u8, 101u8, 114u8, 80u8, 104u8, 111u8,
+                    110u8, 101u8, 116u8, 105u8, 99u8, 32u8, 83u8, 111u8, 114u8, 116u8, 32u8, 79u8,
+                    114u8, 100u8, 101u8, 114u8, 80u8, 105u8, 110u8, 121u8, 105u8, 110u8, 32u8,
+                    83u8, 111u8, 114u8, 116u8, 32u8, 79u8, 114u8, 100u8, 101u8, 114u8, 82u8, 101u8,
+                    102u8, 111u8, 114u8, 109u8, 101u8, 100u8, 32u8, 83u8, 111u8, 114u8, 116u8,
+                    32u8, 79u8, 114u8, 100u8, 101u8, 114u8, 71u8, 101u8, 110u8, 101u8, 114u8, 97u8,
+                    108u8, 45u8, 80u8, 117u8, 114u8, 112u8, 111u8, 115u8, 101u8, 32u8, 83u8, 101u8,
+                    97u8, 114u8, 99u8, 104u8, 83u8, 101u8, 97u8, 114u8, 99u8, 104u8, 32u8, 66u8,
+                    121u8, 32u8, 72u8, 97u8, 110u8, 103u8, 117u8, 108u8, 32u8, 73u8, 110u8, 105u8,
+                    116u8, 105u8, 97u8, 108u8, 32u8, 67u8, 111u8, 110u8, 115u8, 111u8, 110u8, 97u8,
+                    110u8, 116u8, 83u8, 116u8, 97u8, 110u8, 100u8, 97u8, 114u8, 100u8, 32u8, 83u8,
+                    111u8, 114u8, 116u8, 32u8, 79u8, 114u8, 100u8, 101u8, 114u8, 83u8, 116u8,
+                    114u8, 111u8, 107u8, 101u8, 32u8, 83u8, 111u8, 114u8, 116u8, 32u8, 79u8, 114u8,
+                    100u8, 101u8, 114u8, 84u8, 114u8, 97u8, 100u8, 105u8, 116u8, 105u8, 111u8,
+                    110u8, 97u8, 108u8, 32u8, 83u8, 111u8, 114u8, 116u8, 32u8, 79u8, 114u8, 100u8,
+                    101u8, 114u8, 82u8, 97u8, 100u8, 105u8, 99u8, 97u8, 108u8, 45u8, 83u8, 116u8,
+                    114u8, 111u8, 107u8, 101u8, 32u8, 83u8, 111u8, 114u8, 116u8, 32u8, 79u8, 114u8,
+                    100u8, 101u8, 114u8, 90u8, 104u8, 117u8, 121u8, 105u8, 110u8, 32u8, 83u8,
+                    111u8, 114u8, 116u8, 32u8, 79u8, 114u8, 100u8, 101u8, 114u8, 85u8, 115u8,
+                    101u8, 32u8, 68u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 32u8, 80u8, 114u8,
+                    101u8, 115u8, 101u8, 110u8, 116u8, 97u8, 116u8, 105u8, 111u8, 110u8, 32u8,
+                    70u8, 111u8, 114u8, 32u8, 69u8, 109u8, 111u8, 106u8, 105u8, 32u8, 67u8, 104u8,
+                    97u8, 114u8, 97u8, 99u8, 116u8, 101u8, 114u8, 115u8, 80u8, 114u8, 101u8, 102u8,
+                    101u8, 114u8, 32u8, 69u8, 109u8, 111u8, 106u8, 105u8, 32u8, 80u8, 114u8, 101u8,
+                    115u8, 101u8, 110u8, 116u8, 97u8, 116u8, 105u8, 111u8, 110u8, 32u8, 70u8,
+                    111u8, 114u8, 32u8, 69u8, 109u8, 111u8, 106u8, 105u8, 32u8, 67u8, 104u8, 97u8,
+                    114u8, 97u8, 99u8, 116u8, 101u8, 114u8, 115u8, 80u8, 114u8, 101u8, 102u8,
+                    101u8, 114u8, 32u8, 84u8, 101u8, 120u8, 116u8, 32u8, 80u8, 114u8, 101u8, 115u8,
+                    101u8, 110u8, 116u8, 97u8, 116u8, 105u8, 111u8, 110u8, 32u8, 70u8, 111u8,
+                    114u8, 32u8, 69u8, 109u8, 111u8, 106u8, 105u8, 32u8, 67u8, 104u8, 97u8, 114u8,
+                    97u8, 99u8, 116u8, 101u8, 114u8, 115u8, 70u8, 105u8, 114u8, 115u8, 116u8, 32u8,
+                    68u8, 97u8, 121u8, 32u8, 111u8, 102u8, 32u8, 87u8, 101u8, 101u8, 107u8, 32u8,
+                    73u8, 115u8, 32u8, 70u8, 114u8, 105u8, 100u8, 97u8, 121u8, 70u8, 105u8, 114u8,
+                    115u8, 116u8, 32u8, 68u8, 97u8, 121u8, 32u8, 111u8, 102u8, 32u8, 87u8, 101u8,
+                    101u8, 107u8, 32u8, 73u8, 115u8, 32u8, 77u8, 111u8, 110u8, 100u8, 97u8, 121u8,
+                    70u8, 105u8, 114u8, 115u8, 116u8, 32u8, 68u8, 97u8, 121u8, 32u8, 111u8, 102u8,
+                    32u8, 87u8, 101u8, 101u8, 107u8, 32u8, 73u8, 115u8, 32u8, 83u8, 97u8, 116u8,
+                    117u8, 114u8, 100u8, 97u8, 121u8, 70u8, 105u8, 114u8, 115u8, 116u8, 32u8, 68u8,
+                    97u8, 121u8, 32u8, 111u8, 102u8, 32u8, 87u8, 101u8, 101u8, 107u8, 32u8, 73u8,
+                    115u8, 32u8, 83u8, 117u8, 110u8, 100u8, 97u8, 121u8, 70u8, 105u8, 114u8, 115u8,
+                    116u8, 32u8, 68u8, 97u8, 121u8, 32u8, 111u8, 102u8, 32u8, 87u8, 101u8, 101u8,
+                    107u8, 32u8, 73u8, 115u8, 32u8, 84u8, 104u8, 117u8, 114u8, 115u8, 100u8, 97u8,
+                    121u8, 70u8, 105u8, 114u8, 115u8, 116u8, 32u8, 68u8, 97u8, 121u8, 32u8, 111u8,
+                    102u8, 32u8, 87u8, 101u8, 101u8, 107u8, 32u8, 73u8, 115u8, 32u8, 84u8, 117u8,
+                    101u8, 115u8, 100u8, 97u8, 121u8, 70u8, 105u8, 114u8, 115u8, 116u8, 32u8, 68u8,
+                    97u8, 121u8, 32u8, 111u8, 102u8, 32u8, 87u8, 101u8, 101u8, 107u8, 32u8, 73u8,
+                    115u8, 32u8, 87u8, 101u8, 100u8, 110u8, 101u8, 115u8, 100u8, 97u8, 121u8, 49u8,
+                    50u8, 45u8, 72u8, 111u8, 117u8, 114u8, 32u8, 83u8, 121u8, 115u8, 116u8, 101u8,
+                    109u8, 32u8, 40u8, 48u8, 226u8, 128u8, 147u8, 49u8, 49u8, 41u8, 49u8, 50u8,
+                    45u8, 72u8, 111u8, 117u8, 114u8, 32u8, 83u8, 121u8, 115u8, 116u8, 101u8, 109u8,
+                    32u8, 40u8, 49u8, 226u8, 128u8, 147u8, 49u8, 50u8, 41u8, 50u8, 52u8, 45u8,
+                    72u8, 111u8, 117u8, 114u8, 32u8, 83u8, 121u8, 115u8, 116u8, 101u8, 109u8, 32u8,
+                    40u8, 48u8, 226u8, 128u8, 147u8, 50u8, 51u8, 41u8, 50u8, 52u8, 45u8, 72u8,
+                    111u8, 117u8, 114u8, 32u8, 83u8, 121u8, 115u8, 116u8, 101u8, 109u8, 32u8, 40u8,
+                    49u8, 226u8, 128u8, 147u8, 50u8, 52u8, 41u8, 83u8, 111u8, 114u8, 116u8, 32u8,
+                    83u8, 121u8, 109u8, 98u8, 111u8, 108u8, 115u8, 83u8, 111u8, 114u8, 116u8, 32u8,
+                    73u8, 103u8, 110u8, 111u8, 114u8, 105u8, 110u8, 103u8, 32u8, 83u8, 121u8,
+                    109u8, 98u8, 111u8, 108u8, 115u8, 83u8, 111u8, 114u8, 116u8, 32u8, 65u8, 99u8,
+                    99u8, 101u8, 110u8, 116u8, 115u8, 32u8, 78u8, 111u8, 114u8, 109u8, 97u8, 108u8,
+                    108u8, 121u8, 83u8, 111u8, 114u8, 116u8, 32u8, 65u8, 99u8, 99u8, 101u8, 110u8,
+                    116u8, 115u8, 32u8, 82u8, 101u8, 118u8, 101u8, 114u8, 115u8, 101u8, 100u8,
+                    83u8, 111u8, 114u8, 116u8, 32u8, 67u8, 97u8, 115u8, 101u8, 32u8, 73u8, 110u8,
+                    115u8, 101u8, 110u8, 115u8, 105u8, 116u8, 105u8, 118u8, 101u8, 83u8, 111u8,
+                    114u8, 116u8, 32u8, 67u8, 97u8, 115u8, 101u8, 32u8, 83u8, 101u8, 110u8, 115u8,
+                    105u8, 116u8, 105u8, 118u8, 101u8, 83u8, 111u8, 114u8, 116u8, 32u8, 78u8,
+                    111u8, 114u8, 109u8, 97u8, 108u8, 32u8, 67u8, 97u8, 115u8, 101u8, 32u8, 79u8,
+                    114u8, 100u8, 101u8, 114u8, 83u8, 111u8, 114u8, 116u8, 32u8, 76u8, 111u8,
+                    119u8, 101u8, 114u8, 99u8, 97u8, 115u8, 101u8, 32u8, 70u8, 105u8, 114u8, 115u8,
+                    116u8, 83u8, 111u8, 114u8, 116u8, 32u8, 85u8, 112u8, 112u8, 101u8, 114u8, 99u8,
+                    97u8, 115u8, 101u8, 32u8, 70u8, 105u8, 114u8, 115u8, 116u8, 83u8, 111u8, 114u8,
+                    116u8, 32u8, 87u8, 105u8, 116u8, 104u8, 111u8, 117u8, 116u8, 32u8, 78u8, 111u8,
+                    114u8, 109u8, 97u8, 108u8, 105u8, 115u8, 97u8, 116u8, 105u8, 111u8, 110u8,
+                    83u8, 111u8, 114u8, 116u8, 32u8, 85u8, 110u8, 105u8, 99u8, 111u8, 100u8, 101u8,
+                    32u8, 78u8, 111u8, 114u8, 109u8, 97u8, 108u8, 105u8, 115u8, 101u8, 100u8, 83u8,
+                    111u8, 114u8, 116u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 32u8, 73u8,
+                    110u8, 100u8, 105u8, 118u8, 105u8, 100u8, 117u8, 97u8, 108u8, 108u8, 121u8,
+                    83u8, 111u8, 114u8, 116u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 32u8,
+                    78u8, 117u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 108u8, 108u8, 121u8, 67u8,
+                    117u8, 114u8, 114u8, 101u8, 110u8, 99u8, 121u8, 68u8, 105u8, 103u8, 105u8,
+                    116u8, 115u8, 80u8, 117u8, 110u8, 99u8, 116u8, 117u8, 97u8, 116u8, 105u8,
+                    111u8, 110u8, 87u8, 104u8, 105u8, 116u8, 101u8, 115u8, 112u8, 97u8, 99u8,
+                    101u8, 83u8, 121u8, 109u8, 98u8, 111u8, 108u8, 83u8, 111u8, 114u8, 116u8, 32u8,
+                    65u8, 108u8, 108u8, 83u8, 111u8, 114u8, 116u8, 32u8, 66u8, 97u8, 115u8, 101u8,
+                    32u8, 76u8, 101u8, 116u8, 116u8, 101u8, 114u8, 115u8, 32u8, 79u8, 110u8, 108u8,
+                    121u8, 83u8, 111u8, 114u8, 116u8, 32u8, 65u8, 99u8, 99u8, 101u8, 110u8, 116u8,
+                    115u8, 83u8, 111u8, 114u8, 116u8, 32u8, 65u8, 99u8, 99u8, 101u8, 110u8, 116u8,
+                    115u8, 47u8, 67u8, 97u8, 115u8, 101u8, 47u8, 87u8, 105u8, 100u8, 116u8, 104u8,
+                    83u8, 111u8, 114u8, 116u8, 32u8, 65u8, 99u8, 99u8, 101u8, 110u8, 116u8, 115u8,
+                    47u8, 67u8, 97u8, 115u8, 101u8, 47u8, 87u8, 105u8, 100u8, 116u8, 104u8, 47u8,
+                    75u8, 97u8, 110u8, 97u8, 73u8, 103u8, 110u8, 111u8, 114u8, 101u8, 32u8, 83u8,
+                    121u8, 109u8, 98u8, 111u8, 108u8, 115u8, 32u8, 97u8, 102u8, 102u8, 101u8, 99u8,
+                    116u8, 115u8, 32u8, 115u8, 112u8, 97u8, 99u8, 101u8, 115u8, 44u8, 32u8, 112u8,
+                    117u8, 110u8, 99u8, 116u8, 117u8, 97u8, 116u8, 105u8, 111u8, 110u8, 44u8, 32u8,
+                    97u8, 108u8, 108u8, 32u8, 115u8, 121u8, 109u8, 98u8, 111u8, 108u8, 115u8, 73u8,
+                    103u8, 110u8, 111u8, 114u8, 101u8, 32u8, 83u8, 121u8, 109u8, 98u8, 111u8,
+                    108u8, 115u8, 32u8, 97u8, 102u8, 102u8, 101u8, 99u8, 116u8, 115u8, 32u8, 115u8,
+                    112u8, 97u8, 99u8, 101u8, 115u8, 32u8, 97u8, 110u8, 100u8, 32u8, 112u8, 117u8,
+                    110u8, 99u8, 116u8, 117u8, 97u8, 116u8, 105u8, 111u8, 110u8, 32u8, 111u8,
+                    110u8, 108u8, 121u8, 73u8, 103u8, 110u8, 111u8, 114u8, 101u8, 32u8, 83u8,
+                    121u8, 109u8, 98u8, 111u8, 108u8, 115u8, 32u8, 97u8, 102u8, 102u8, 101u8, 99u8,
+                    116u8, 115u8, 32u8, 115u8, 112u8, 97u8, 99u8, 101u8, 115u8, 32u8, 111u8, 110u8,
+                    108u8, 121u8, 73u8, 103u8, 110u8, 111u8, 114u8, 101u8, 32u8, 83u8, 121u8,
+                    109u8, 98u8, 111u8, 108u8, 115u8, 32u8, 97u8, 102u8, 102u8, 101u8, 99u8, 116u8,
+                    115u8, 32u8, 115u8, 112u8, 97u8, 99u8, 101u8, 115u8, 44u8, 32u8, 112u8, 117u8,
+                    110u8, 99u8, 116u8, 117u8, 97u8, 116u8, 105u8, 111u8, 110u8, 44u8, 32u8, 110u8,
+                    111u8, 110u8, 45u8, 99u8, 117u8, 114u8, 114u8, 101u8, 110u8, 99u8, 121u8, 32u8,
+                    115u8, 121u8, 109u8, 98u8, 111u8, 108u8, 115u8, 76u8, 111u8, 111u8, 115u8,
+                    101u8, 32u8, 76u8, 105u8, 110u8, 101u8, 32u8, 66u8, 114u8, 101u8, 97u8, 107u8,
+                    32u8, 83u8, 116u8, 121u8, 108u8, 101u8, 78u8, 111u8, 114u8, 109u8, 97u8, 108u8,
+                    32u8, 76u8, 105u8, 110u8, 101u8, 32u8, 66u8, 114u8, 101u8, 97u8, 107u8, 32u8,
+                    83u8, 116u8, 121u8, 108u8, 101u8, 83u8, 116u8, 114u8, 105u8, 99u8, 116u8, 32u8,
+                    76u8, 105u8, 110u8, 101u8, 32u8, 66u8, 114u8, 101u8, 97u8, 107u8, 32u8, 83u8,
+                    116u8, 121u8, 108u8, 101u8, 65u8, 108u8, 108u8, 111u8, 119u8, 32u8, 76u8,
+                    105u8, 110u8, 101u8, 32u8, 66u8, 114u8, 101u8, 97u8, 107u8, 115u8, 32u8, 73u8,
+                    110u8, 32u8, 65u8, 108u8, 108u8, 32u8, 87u8, 111u8, 114u8, 100u8, 115u8, 80u8,
+                    114u8, 101u8, 118u8, 101u8, 110u8, 116u8, 32u8, 76u8, 105u8, 110u8, 101u8,
+                    32u8, 66u8, 114u8, 101u8, 97u8, 107u8, 115u8, 32u8, 73u8, 110u8, 32u8, 65u8,
+                    108u8, 108u8, 32u8, 87u8, 111u8, 114u8, 100u8, 115u8, 78u8, 111u8, 114u8,
+                    109u8, 97u8, 108u8, 32u8, 76u8, 105u8, 110u8, 101u8, 32u8, 66u8, 114u8, 101u8,
+                    97u8, 107u8, 115u8, 32u8, 70u8, 111u8, 114u8, 32u8, 87u8, 111u8, 114u8, 100u8,
+                    115u8, 80u8, 114u8, 101u8, 118u8, 101u8, 110u8, 116u8, 32u8, 76u8, 105u8,
+                    110u8, 101u8, 32u8, 66u8, 114u8, 101u8, 97u8, 107u8, 115u8, 32u8, 73u8, 110u8,
+                    32u8, 80u8, 104u8, 114u8, 97u8, 115u8, 101u8, 115u8, 77u8, 101u8, 116u8, 114u8,
+                    105u8, 99u8, 32u8, 83u8, 121u8, 115u8, 116u8, 101u8, 109u8, 73u8, 109u8, 112u8,
+                    101u8, 114u8, 105u8, 97u8, 108u8, 32u8, 77u8, 101u8, 97u8, 115u8, 117u8, 114u8,
+                    101u8, 109u8, 101u8, 110u8, 116u8, 32u8, 83u8, 121u8, 115u8, 116u8, 101u8,
+                    109u8, 85u8, 83u8, 32u8, 77u8, 101u8, 97u8, 115u8, 117u8, 114u8, 101u8, 109u8,
+                    101u8, 110u8, 116u8, 32u8, 83u8, 121u8, 115u8, 116u8, 101u8, 109u8, 67u8,
+                    101u8, 108u8, 115u8, 105u8, 117u8, 115u8, 70u8, 97u8, 104u8, 114u8, 101u8,
+                    110u8, 104u8, 101u8, 105u8, 116u8, 75u8, 101u8, 108u8, 118u8, 105u8, 110u8,
+                    65u8, 100u8, 108u8, 97u8, 109u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8,
+                    65u8, 104u8, 111u8, 109u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 65u8,
+                    114u8, 97u8, 98u8, 105u8, 99u8, 45u8, 73u8, 110u8, 100u8, 105u8, 99u8, 32u8,
+                    68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 69u8, 120u8, 116u8, 101u8, 110u8,
+                    100u8, 101u8, 100u8, 32u8, 65u8, 114u8, 97u8, 98u8, 105u8, 99u8, 45u8, 73u8,
+                    110u8, 100u8, 105u8, 99u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 65u8,
+                    114u8, 109u8, 101u8, 110u8, 105u8, 97u8, 110u8, 32u8, 78u8, 117u8, 109u8,
+                    101u8, 114u8, 97u8, 108u8, 115u8, 65u8, 114u8, 109u8, 101u8, 110u8, 105u8,
+                    97u8, 110u8, 32u8, 76u8, 111u8, 119u8, 101u8, 114u8, 99u8, 97u8, 115u8, 101u8,
+                    32u8, 78u8, 117u8, 109u8, 101u8, 114u8, 97u8, 108u8, 115u8, 66u8, 97u8, 108u8,
+                    105u8, 110u8, 101u8, 115u8, 101u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8,
+                    115u8, 66u8, 97u8, 110u8, 103u8, 108u8, 97u8, 32u8, 68u8, 105u8, 103u8, 105u8,
+                    116u8, 115u8, 66u8, 104u8, 97u8, 105u8, 107u8, 115u8, 117u8, 107u8, 105u8,
+                    32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 66u8, 114u8, 97u8, 104u8, 109u8,
+                    105u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 67u8, 104u8, 97u8, 107u8,
+                    109u8, 97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 67u8, 104u8, 97u8,
+                    109u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 67u8, 121u8, 114u8,
+                    105u8, 108u8, 108u8, 105u8, 99u8, 32u8, 78u8, 117u8, 109u8, 101u8, 114u8, 97u8,
+                    108u8, 115u8, 68u8, 101u8, 118u8, 97u8, 110u8, 97u8, 103u8, 97u8, 114u8, 105u8,
+                    32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 68u8, 105u8, 118u8, 101u8,
+                    115u8, 32u8, 65u8, 107u8, 117u8, 114u8, 117u8, 32u8, 68u8, 105u8, 103u8, 105u8,
+                    116u8, 115u8, 69u8, 116u8, 104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 32u8, 78u8,
+                    117u8, 109u8, 101u8, 114u8, 97u8, 108u8, 115u8, 70u8, 105u8, 110u8, 97u8,
+                    110u8, 99u8, 105u8, 97u8, 108u8, 32u8, 78u8, 117u8, 109u8, 101u8, 114u8, 97u8,
+                    108u8, 115u8, 70u8, 117u8, 108u8, 108u8, 45u8, 87u8, 105u8, 100u8, 116u8,
+                    104u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 71u8, 101u8, 111u8,
+                    114u8, 103u8, 105u8, 97u8, 110u8, 32u8, 78u8, 117u8, 109u8, 101u8, 114u8, 97u8,
+                    108u8, 115u8, 71u8, 117u8, 110u8, 106u8, 97u8, 108u8, 97u8, 32u8, 71u8, 111u8,
+                    110u8, 100u8, 105u8, 32u8, 100u8, 105u8, 103u8, 105u8, 116u8, 115u8, 77u8,
+                    97u8, 115u8, 97u8, 114u8, 97u8, 109u8, 32u8, 71u8, 111u8, 110u8, 100u8, 105u8,
+                    32u8, 100u8, 105u8, 103u8, 105u8, 116u8, 115u8, 71u8, 114u8, 101u8, 101u8,
+                    107u8, 32u8, 78u8, 117u8, 109u8, 101u8, 114u8, 97u8, 108u8, 115u8, 71u8, 114u8,
+                    101u8, 101u8, 107u8, 32u8, 76u8, 111u8, 119u8, 101u8, 114u8, 99u8, 97u8, 115u8,
+                    101u8, 32u8, 78u8, 117u8, 109u8, 101u8, 114u8, 97u8, 108u8, 115u8, 71u8, 117u8,
+                    106u8, 97u8, 114u8, 97u8, 116u8, 105u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8,
+                    115u8, 71u8, 117u8, 114u8, 109u8, 117u8, 107u8, 104u8, 105u8, 32u8, 68u8,
+                    105u8, 103u8, 105u8, 116u8, 115u8, 67u8, 104u8, 105u8, 110u8, 101u8, 115u8,
+                    101u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 32u8, 68u8,
+                    97u8, 121u8, 45u8, 111u8, 102u8, 45u8, 77u8, 111u8, 110u8, 116u8, 104u8, 32u8,
+                    78u8, 117u8, 109u8, 101u8, 114u8, 97u8, 108u8, 115u8, 67u8, 104u8, 105u8,
+                    110u8, 101u8, 115u8, 101u8, 32u8, 68u8, 101u8, 99u8, 105u8, 109u8, 97u8, 108u8,
+                    32u8, 78u8, 117u8, 109u8, 101u8, 114u8, 97u8, 108u8, 115u8, 83u8, 105u8, 109u8,
+                    112u8, 108u8, 105u8, 102u8, 105u8, 101u8, 100u8, 32u8, 67u8, 104u8, 105u8,
+                    110u8, 101u8, 115u8, 101u8, 32u8, 78u8, 117u8, 109u8, 101u8, 114u8, 97u8,
+                    108u8, 115u8, 83u8, 105u8, 109u8, 112u8, 108u8, 105u8, 102u8, 105u8, 101u8,
+                    100u8, 32u8, 67u8, 104u8, 105u8, 110u8, 101u8, 115u8, 101u8, 32u8, 70u8, 105u8,
+                    110u8, 97u8, 110u8, 99u8, 105u8, 97u8, 108u8, 32u8, 78u8, 117u8, 109u8, 101u8,
+                    114u8, 97u8, 108u8, 115u8, 84u8, 114u8, 97u8, 100u8, 105u8, 116u8, 105u8,
+                    111u8, 110u8, 97u8, 108u8, 32u8, 67u8, 104u8, 105u8, 110u8, 101u8, 115u8,
+                    101u8, 32u8, 78u8, 117u8, 109u8, 101u8, 114u8, 97u8, 108u8, 115u8, 84u8, 114u8,
+                    97u8, 100u8, 105u8, 116u8, 105u8, 111u8, 110u8, 97u8, 108u8, 32u8, 67u8, 104u8,
+                    105u8, 110u8, 101u8, 115u8, 101u8, 32u8, 70u8, 105u8, 110u8, 97u8, 110u8, 99u8,
+                    105u8, 97u8, 108u8, 32u8, 78u8, 117u8, 109u8, 101u8, 114u8, 97u8, 108u8, 115u8,
+                    72u8, 101u8, 98u8, 114u8, 101u8, 119u8, 32u8, 78u8, 117u8, 109u8, 101u8, 114u8,
+                    97u8, 108u8, 115u8, 80u8, 97u8, 104u8, 97u8, 119u8, 104u8, 32u8, 72u8, 109u8,
+                    111u8, 110u8, 103u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 78u8,
+                    121u8, 105u8, 97u8, 107u8, 101u8, 110u8, 103u8, 32u8, 80u8, 117u8, 97u8, 99u8,
+                    104u8, 117u8, 101u8, 32u8, 72u8, 109u8, 111u8, 110u8, 103u8, 32u8, 68u8, 105u8,
+                    103u8, 105u8, 116u8, 115u8, 74u8, 97u8, 118u8, 97u8, 110u8, 101u8, 115u8,
+                    101u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 74u8, 97u8, 112u8, 97u8,
+                    110u8, 101u8, 115u8, 101u8, 32u8, 78u8, 117u8, 109u8, 101u8, 114u8, 97u8,
+                    108u8, 115u8, 74u8, 97u8, 112u8, 97u8, 110u8, 101u8, 115u8, 101u8, 32u8, 70u8,
+                    105u8, 110u8, 97u8, 110u8, 99u8, 105u8, 97u8, 108u8, 32u8, 78u8, 117u8, 109u8,
+                    101u8, 114u8, 97u8, 108u8, 115u8, 74u8, 97u8, 112u8, 97u8, 110u8, 101u8, 115u8,
+                    101u8, 32u8, 67u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 32u8, 71u8,
+                    97u8, 110u8, 110u8, 101u8, 110u8, 32u8, 89u8, 101u8, 97u8, 114u8, 32u8, 78u8,
+                    117u8, 109u8, 101u8, 114u8, 97u8, 108u8, 115u8, 75u8, 97u8, 121u8, 97u8, 104u8,
+                    32u8, 76u8, 105u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 75u8, 97u8,
+                    119u8, 105u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 75u8, 104u8,
+                    109u8, 101u8, 114u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 75u8, 97u8,
+                    110u8, 110u8, 97u8, 100u8, 97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8,
+                    84u8, 97u8, 105u8, 32u8, 84u8, 104u8, 97u8, 109u8, 32u8, 72u8, 111u8, 114u8,
+                    97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 84u8, 97u8, 105u8, 32u8,
+                    84u8, 104u8, 97u8, 109u8, 32u8, 84u8, 104u8, 97u8, 109u8, 32u8, 68u8, 105u8,
+                    103u8, 105u8, 116u8, 115u8, 76u8, 97u8, 111u8, 32u8, 68u8, 105u8, 103u8, 105u8,
+                    116u8, 115u8, 87u8, 101u8, 115u8, 116u8, 101u8, 114u8, 110u8, 32u8, 68u8,
+                    105u8, 103u8, 105u8, 116u8, 115u8, 76u8, 101u8, 112u8, 99u8, 104u8, 97u8, 32u8,
+                    68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 76u8, 105u8, 109u8, 98u8, 117u8, 32u8,
+                    68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 77u8, 97u8, 116u8, 104u8, 101u8,
+                    109u8, 97u8, 116u8, 105u8, 99u8, 97u8, 108u8, 32u8, 66u8, 111u8, 108u8, 100u8,
+                    32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 77u8, 97u8, 116u8, 104u8, 101u8,
+                    109u8, 97u8, 116u8, 105u8, 99u8, 97u8, 108u8, 32u8, 68u8, 111u8, 117u8, 98u8,
+                    108u8, 101u8, 45u8, 83u8, 116u8, 114u8, 117u8, 99u8, 107u8, 32u8, 68u8, 105u8,
+                    103u8, 105u8, 116u8, 115u8, 77u8, 97u8, 116u8, 104u8, 101u8, 109u8, 97u8,
+                    116u8, 105u8, 99u8, 97u8, 108u8, 32u8, 77u8, 111u8, 110u8, 111u8, 115u8, 112u8,
+                    97u8, 99u8, 101u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 77u8, 97u8,
+                    116u8, 104u8, 101u8, 109u8, 97u8, 116u8, 105u8, 99u8, 97u8, 108u8, 32u8, 83u8,
+                    97u8, 110u8, 115u8, 45u8, 83u8, 101u8, 114u8, 105u8, 102u8, 32u8, 66u8, 111u8,
+                    108u8, 100u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 77u8, 97u8, 116u8,
+                    104u8, 101u8, 109u8, 97u8, 116u8, 105u8, 99u8, 97u8, 108u8, 32u8, 83u8, 97u8,
+                    110u8, 115u8, 45u8, 83u8, 101u8, 114u8, 105u8, 102u8, 32u8, 68u8, 105u8, 103u8,
+                    105u8, 116u8, 115u8, 77u8, 97u8, 108u8, 97u8, 121u8, 97u8, 108u8, 97u8, 109u8,
+                    32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 77u8, 111u8, 100u8, 105u8, 32u8,
+                    68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 77u8, 111u8, 110u8, 103u8, 111u8,
+                    108u8, 105u8, 97u8, 110u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 77u8,
+                    114u8, 111u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 77u8, 101u8,
+                    101u8, 116u8, 101u8, 105u8, 32u8, 77u8, 97u8, 121u8, 101u8, 107u8, 32u8, 68u8,
+                    105u8, 103u8, 105u8, 116u8, 115u8, 77u8, 121u8, 97u8, 110u8, 109u8, 97u8,
+                    114u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 77u8, 121u8, 97u8, 110u8,
+                    109u8, 97u8, 114u8, 32u8, 83u8, 104u8, 97u8, 110u8, 32u8, 68u8, 105u8, 103u8,
+                    105u8, 116u8, 115u8, 77u8, 121u8, 97u8, 110u8, 109u8, 97u8, 114u8, 32u8, 84u8,
+                    97u8, 105u8, 32u8, 76u8, 97u8, 105u8, 110u8, 103u8, 32u8, 68u8, 105u8, 103u8,
+                    105u8, 116u8, 115u8, 78u8, 97u8, 103u8, 32u8, 77u8, 117u8, 110u8, 100u8, 97u8,
+                    114u8, 105u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 78u8, 97u8, 116u8,
+                    105u8, 118u8, 101u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 78u8,
+                    101u8, 119u8, 97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 78u8, 226u8,
+                    128u8, 153u8, 75u8, 111u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 79u8,
+                    108u8, 32u8, 67u8, 104u8, 105u8, 107u8, 105u8, 32u8, 68u8, 105u8, 103u8, 105u8,
+                    116u8, 115u8, 79u8, 100u8, 105u8, 97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8,
+                    115u8, 79u8, 115u8, 109u8, 97u8, 110u8, 121u8, 97u8, 32u8, 68u8, 105u8, 103u8,
+                    105u8, 116u8, 115u8, 72u8, 97u8, 110u8, 105u8, 102u8, 105u8, 32u8, 82u8, 111u8,
+                    104u8, 105u8, 110u8, 103u8, 121u8, 97u8, 32u8, 100u8, 105u8, 103u8, 105u8,
+                    116u8, 115u8, 82u8, 111u8, 109u8, 97u8, 110u8, 32u8, 78u8, 117u8, 109u8, 101u8,
+                    114u8, 97u8, 108u8, 115u8, 82u8, 111u8, 109u8, 97u8, 110u8, 32u8, 76u8, 111u8,
+                    119u8, 101u8, 114u8, 99u8, 97u8, 115u8, 101u8, 32u8, 78u8, 117u8, 109u8, 101u8,
+                    114u8, 97u8, 108u8, 115u8, 83u8, 97u8, 117u8, 114u8, 97u8, 115u8, 104u8, 116u8,
+                    114u8, 97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 83u8, 101u8, 103u8,
+                    109u8, 101u8, 110u8, 116u8, 101u8, 100u8, 32u8, 68u8, 105u8, 103u8, 105u8,
+                    116u8, 115u8, 83u8, 104u8, 97u8, 114u8, 97u8, 100u8, 97u8, 32u8, 68u8, 105u8,
+                    103u8, 105u8, 116u8, 115u8, 75u8, 104u8, 117u8, 100u8, 97u8, 119u8, 97u8,
+                    100u8, 105u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 83u8, 105u8,
+                    110u8, 104u8, 97u8, 108u8, 97u8, 32u8, 76u8, 105u8, 116u8, 104u8, 32u8, 68u8,
+                    105u8, 103u8, 105u8, 116u8, 115u8, 83u8, 111u8, 114u8, 97u8, 32u8, 83u8, 111u8,
+                    109u8, 112u8, 101u8, 110u8, 103u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8,
+                    115u8, 83u8, 117u8, 110u8, 100u8, 97u8, 110u8, 101u8, 115u8, 101u8, 32u8, 68u8,
+                    105u8, 103u8, 105u8, 116u8, 115u8, 84u8, 97u8, 107u8, 114u8, 105u8, 32u8, 68u8,
+                    105u8, 103u8, 105u8, 116u8, 115u8, 78u8, 101u8, 119u8, 32u8, 84u8, 97u8, 105u8,
+                    32u8, 76u8, 117u8, 101u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 84u8,
+                    114u8, 97u8, 100u8, 105u8, 116u8, 105u8, 111u8, 110u8, 97u8, 108u8, 32u8, 84u8,
+                    97u8, 109u8, 105u8, 108u8, 32u8, 78u8, 117u8, 109u8, 101u8, 114u8, 97u8, 108u8,
+                    115u8, 84u8, 97u8, 109u8, 105u8, 108u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8,
+                    115u8, 84u8, 101u8, 108u8, 117u8, 103u8, 117u8, 32u8, 68u8, 105u8, 103u8,
+                    105u8, 116u8, 115u8, 84u8, 104u8, 97u8, 105u8, 32u8, 68u8, 105u8, 103u8, 105u8,
+                    116u8, 115u8, 84u8, 105u8, 98u8, 101u8, 116u8, 97u8, 110u8, 32u8, 68u8, 105u8,
+                    103u8, 105u8, 116u8, 115u8, 84u8, 105u8, 114u8, 104u8, 117u8, 116u8, 97u8,
+                    32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 84u8, 97u8, 110u8, 103u8, 115u8,
+                    97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 84u8, 114u8, 97u8, 100u8,
+                    105u8, 116u8, 105u8, 111u8, 110u8, 97u8, 108u8, 32u8, 78u8, 117u8, 109u8,
+                    101u8, 114u8, 97u8, 108u8, 115u8, 86u8, 97u8, 105u8, 32u8, 68u8, 105u8, 103u8,
+                    105u8, 116u8, 115u8, 87u8, 97u8, 114u8, 97u8, 110u8, 103u8, 32u8, 67u8, 105u8,
+                    116u8, 105u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 87u8, 97u8, 110u8,
+                    99u8, 104u8, 111u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 83u8, 101u8,
+                    110u8, 116u8, 101u8, 110u8, 99u8, 101u8, 32u8, 66u8, 114u8, 101u8, 97u8, 107u8,
+                    115u8, 32u8, 87u8, 105u8, 116u8, 104u8, 111u8, 117u8, 116u8, 32u8, 65u8, 98u8,
+                    98u8, 114u8, 101u8, 118u8, 105u8, 97u8, 116u8, 105u8, 111u8, 110u8, 32u8, 72u8,
+                    97u8, 110u8, 100u8, 108u8, 105u8, 110u8, 103u8, 83u8, 117u8, 112u8, 112u8,
+                    114u8, 101u8, 115u8, 115u8, 32u8, 83u8, 101u8, 110u8, 116u8, 101u8, 110u8,
+                    99u8, 101u8, 32u8, 66u8, 114u8, 101u8, 97u8, 107u8, 115u8, 32u8, 65u8, 102u8,
+                    116u8, 101u8, 114u8, 32u8, 83u8, 116u8, 97u8, 110u8, 100u8, 97u8, 114u8, 100u8,
+                    32u8, 65u8, 98u8, 98u8, 114u8, 101u8, 118u8, 105u8, 97u8, 116u8, 105u8, 111u8,
+                    110u8, 115u8, 80u8, 79u8, 83u8, 73u8, 88u8, 32u8, 67u8, 111u8, 109u8, 112u8,
+                    108u8, 105u8, 97u8, 110u8, 116u8, 32u8, 76u8, 111u8, 99u8, 97u8, 108u8, 101u8,
+                ])
+            },
+        )
+    },
+};
+static ES: &DataStruct = &::icu_displaynames::provider::KeyDisplayNamesV1 {
+    keys: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    99u8, 97u8, 99u8, 102u8, 99u8, 111u8, 99u8, 117u8, 104u8, 99u8, 107u8, 97u8,
+                    107u8, 98u8, 107u8, 99u8, 107u8, 102u8, 107u8, 107u8, 107u8, 110u8, 107u8,
+                    115u8, 108u8, 98u8, 109u8, 115u8, 110u8, 117u8, 116u8, 122u8, 118u8, 97u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    17u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 27u8, 0u8, 32u8, 0u8, 38u8, 0u8,
+                    67u8, 0u8, 92u8, 0u8, 123u8, 0u8, 176u8, 0u8, 208u8, 0u8, 232u8, 0u8, 247u8,
+                    0u8, 10u8, 1u8, 35u8, 1u8, 55u8, 1u8, 63u8, 1u8, 75u8, 1u8, 99u8, 97u8, 108u8,
+                    101u8, 110u8, 100u8, 97u8, 114u8, 105u8, 111u8, 102u8, 111u8, 114u8, 109u8,
+                    97u8, 116u8, 111u8, 32u8, 100u8, 101u8, 32u8, 109u8, 111u8, 110u8, 101u8,
+                    100u8, 97u8, 111u8, 114u8, 100u8, 101u8, 110u8, 109u8, 111u8, 110u8, 101u8,
+                    100u8, 97u8, 99u8, 105u8, 99u8, 108u8, 111u8, 32u8, 104u8, 111u8, 114u8, 97u8,
+                    114u8, 105u8, 111u8, 32u8, 40u8, 49u8, 50u8, 32u8, 111u8, 32u8, 50u8, 52u8,
+                    32u8, 104u8, 111u8, 114u8, 97u8, 115u8, 41u8, 111u8, 114u8, 100u8, 101u8,
+                    110u8, 32u8, 105u8, 103u8, 110u8, 111u8, 114u8, 97u8, 110u8, 100u8, 111u8,
+                    32u8, 115u8, 195u8, 173u8, 109u8, 98u8, 111u8, 108u8, 111u8, 115u8, 111u8,
+                    114u8, 100u8, 101u8, 110u8, 32u8, 100u8, 101u8, 32u8, 97u8, 99u8, 101u8, 110u8,
+                    116u8, 111u8, 115u8, 32u8, 99u8, 111u8, 110u8, 32u8, 105u8, 110u8, 118u8,
+                    101u8, 114u8, 115u8, 105u8, 195u8, 179u8, 110u8, 111u8, 114u8, 100u8, 101u8,
+                    110u8, 32u8, 99u8, 111u8, 110u8, 32u8, 100u8, 105u8, 115u8, 116u8, 105u8,
+                    110u8, 99u8, 105u8, 195u8, 179u8, 110u8, 32u8, 101u8, 110u8, 116u8, 114u8,
+                    101u8, 32u8, 109u8, 97u8, 121u8, 195u8, 186u8, 115u8, 99u8, 117u8, 108u8, 97u8,
+                    115u8, 32u8, 121u8, 32u8, 109u8, 105u8, 110u8, 195u8, 186u8, 115u8, 99u8,
+                    117u8, 108u8, 97u8, 115u8, 111u8, 114u8, 100u8, 101u8, 110u8, 32u8, 100u8,
+                    101u8, 32u8, 109u8, 97u8, 121u8, 195u8, 186u8, 115u8, 99u8, 117u8, 108u8, 97u8,
+                    115u8, 47u8, 109u8, 105u8, 110u8, 195u8, 186u8, 115u8, 99u8, 117u8, 108u8,
+                    97u8, 115u8, 111u8, 114u8, 100u8, 101u8, 110u8, 32u8, 99u8, 111u8, 110u8, 32u8,
+                    110u8, 111u8, 114u8, 109u8, 97u8, 108u8, 105u8, 122u8, 97u8, 99u8, 105u8,
+                    195u8, 179u8, 110u8, 111u8, 114u8, 100u8, 101u8, 110u8, 32u8, 110u8, 117u8,
+                    109u8, 195u8, 169u8, 114u8, 105u8, 99u8, 111u8, 105u8, 110u8, 116u8, 101u8,
+                    110u8, 115u8, 105u8, 100u8, 97u8, 100u8, 32u8, 100u8, 101u8, 32u8, 111u8,
+                    114u8, 100u8, 101u8, 110u8, 101u8, 115u8, 116u8, 105u8, 108u8, 111u8, 32u8,
+                    100u8, 101u8, 32u8, 115u8, 97u8, 108u8, 116u8, 111u8, 32u8, 100u8, 101u8, 32u8,
+                    108u8, 195u8, 173u8, 110u8, 101u8, 97u8, 115u8, 105u8, 115u8, 116u8, 101u8,
+                    109u8, 97u8, 32u8, 100u8, 101u8, 32u8, 109u8, 101u8, 100u8, 105u8, 99u8, 105u8,
+                    195u8, 179u8, 110u8, 110u8, 195u8, 186u8, 109u8, 101u8, 114u8, 111u8, 115u8,
+                    122u8, 111u8, 110u8, 97u8, 32u8, 104u8, 111u8, 114u8, 97u8, 114u8, 105u8, 97u8,
+                    118u8, 97u8, 114u8, 105u8, 97u8, 110u8, 116u8, 101u8, 32u8, 108u8, 111u8, 99u8,
+                    97u8, 108u8,
+                ])
+            },
+        )
+    },
+    types: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    107u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 21u8, 0u8, 30u8, 0u8, 38u8, 0u8,
+                    48u8, 0u8, 59u8, 0u8, 69u8, 0u8, 78u8, 0u8, 87u8, 0u8, 97u8, 0u8, 113u8, 0u8,
+                    132u8, 0u8, 142u8, 0u8, 153u8, 0u8, 163u8, 0u8, 169u8, 0u8, 179u8, 0u8, 190u8,
+                    0u8, 200u8, 0u8, 209u8, 0u8, 216u8, 0u8, 224u8, 0u8, 230u8, 0u8, 239u8, 0u8,
+                    249u8, 0u8, 4u8, 1u8, 13u8, 1u8, 24u8, 1u8, 33u8, 1u8, 44u8, 1u8, 55u8, 1u8,
+                    64u8, 1u8, 71u8, 1u8, 80u8, 1u8, 89u8, 1u8, 95u8, 1u8, 101u8, 1u8, 107u8, 1u8,
+                    113u8, 1u8, 124u8, 1u8, 134u8, 1u8, 142u8, 1u8, 149u8, 1u8, 157u8, 1u8, 164u8,
+                    1u8, 172u8, 1u8, 180u8, 1u8, 188u8, 1u8, 196u8, 1u8, 203u8, 1u8, 211u8, 1u8,
+                    218u8, 1u8, 228u8, 1u8, 237u8, 1u8, 246u8, 1u8, 255u8, 1u8, 8u8, 2u8, 16u8,
+                    2u8, 25u8, 2u8, 34u8, 2u8, 43u8, 2u8, 54u8, 2u8, 65u8, 2u8, 72u8, 2u8, 82u8,
+                    2u8, 89u8, 2u8, 99u8, 2u8, 106u8, 2u8, 113u8, 2u8, 120u8, 2u8, 127u8, 2u8,
+                    137u8, 2u8, 148u8, 2u8, 155u8, 2u8, 162u8, 2u8, 172u8, 2u8, 179u8, 2u8, 186u8,
+                    2u8, 196u8, 2u8, 203u8, 2u8, 213u8, 2u8, 220u8, 2u8, 230u8, 2u8, 237u8, 2u8,
+                    244u8, 2u8, 251u8, 2u8, 5u8, 3u8, 12u8, 3u8, 19u8, 3u8, 26u8, 3u8, 33u8, 3u8,
+                    40u8, 3u8, 47u8, 3u8, 54u8, 3u8, 61u8, 3u8, 70u8, 3u8, 77u8, 3u8, 84u8, 3u8,
+                    92u8, 3u8, 103u8, 3u8, 110u8, 3u8, 120u8, 3u8, 127u8, 3u8, 134u8, 3u8, 141u8,
+                    3u8, 152u8, 3u8, 99u8, 97u8, 45u8, 98u8, 117u8, 100u8, 100u8, 104u8, 105u8,
+                    115u8, 116u8, 99u8, 97u8, 45u8, 99u8, 104u8, 105u8, 110u8, 101u8, 115u8, 101u8,
+                    99u8, 97u8, 45u8, 99u8, 111u8, 112u8, 116u8, 105u8, 99u8, 99u8, 97u8, 45u8,
+                    100u8, 97u8, 110u8, 103u8, 105u8, 99u8, 97u8, 45u8, 101u8, 116u8, 104u8, 105u8,
+                    111u8, 97u8, 97u8, 99u8, 97u8, 45u8, 101u8, 116u8, 104u8, 105u8, 111u8, 112u8,
+                    105u8, 99u8, 99u8, 97u8, 45u8, 103u8, 114u8, 101u8, 103u8, 111u8, 114u8, 121u8,
+                    99u8, 97u8, 45u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 99u8, 97u8, 45u8,
+                    105u8, 110u8, 100u8, 105u8, 97u8, 110u8, 99u8, 97u8, 45u8, 105u8, 115u8, 108u8,
+                    97u8, 109u8, 105u8, 99u8, 99u8, 97u8, 45u8, 105u8, 115u8, 108u8, 97u8, 109u8,
+                    105u8, 99u8, 45u8, 99u8, 105u8, 118u8, 105u8, 108u8, 99u8, 97u8, 45u8, 105u8,
+                    115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 117u8, 109u8, 97u8, 108u8, 113u8,
+                    117u8, 114u8, 97u8, 99u8, 97u8, 45u8, 105u8, 115u8, 111u8, 56u8, 54u8, 48u8,
+                    49u8, 99u8, 97u8, 45u8, 106u8, 97u8, 112u8, 97u8, 110u8, 101u8, 115u8, 101u8,
+                    99u8, 97u8, 45u8, 112u8, 101u8, 114u8, 115u8, 105u8, 97u8, 110u8, 99u8, 97u8,
+                    45u8, 114u8, 111u8, 99u8, 99u8, 102u8, 45u8, 97u8, 99u8, 99u8, 111u8, 117u8,
+                    110u8, 116u8, 99u8, 102u8, 45u8, 115u8, 116u8, 97u8, 110u8, 100u8, 97u8, 114u8,
+                    100u8, 99u8, 111u8, 45u8, 98u8, 105u8, 103u8, 53u8, 104u8, 97u8, 110u8, 99u8,
+                    111u8, 45u8, 99u8, 111u8, 109u8, 112u8, 97u8, 116u8, 99u8, 111u8, 45u8, 100u8,
+                    105u8, 99u8, 116u8, 99u8, 111u8, 45u8, 100u8, 117u8, 99u8, 101u8, 116u8, 99u8,
+                    111u8, 45u8, 101u8, 111u8, 114u8, 99u8, 111u8, 45u8, 103u8, 98u8, 50u8, 51u8,
+                    49u8, 50u8, 99u8, 111u8, 45u8, 112u8, 104u8, 111u8, 110u8, 101u8, 98u8, 107u8,
+                    99u8, 111u8, 45u8, 112u8, 104u8, 111u8, 110u8, 101u8, 116u8, 105u8, 99u8, 99u8,
+                    111u8, 45u8, 112u8, 105u8, 110u8, 121u8, 105u8, 110u8, 99u8, 111u8, 45u8,
+                    114u8, 101u8, 102u8, 111u8, 114u8, 109u8, 101u8, 100u8, 99u8, 111u8, 45u8,
+                    115u8, 101u8, 97u8, 114u8, 99u8, 104u8, 99u8, 111u8, 45u8, 115u8, 101u8, 97u8,
+                    114u8, 99u8, 104u8, 106u8, 108u8, 99u8, 111u8, 45u8, 115u8, 116u8, 97u8, 110u8,
+                    100u8, 97u8, 114u8, 100u8, 99u8, 111u8, 45u8, 115u8, 116u8, 114u8, 111u8,
+                    107u8, 101u8, 99u8, 111u8, 45u8, 116u8, 114u8, 97u8, 100u8, 99u8, 111u8, 45u8,
+                    117u8, 110u8, 105u8, 104u8, 97u8, 110u8, 99u8, 111u8, 45u8, 122u8, 104u8,
+                    117u8, 121u8, 105u8, 110u8, 104u8, 99u8, 45u8, 104u8, 49u8, 49u8, 104u8, 99u8,
+                    45u8, 104u8, 49u8, 50u8, 104u8, 99u8, 45u8, 104u8, 50u8, 51u8, 104u8, 99u8,
+                    45u8, 104u8, 50u8, 52u8, 107u8, 97u8, 45u8, 110u8, 111u8, 105u8, 103u8, 110u8,
+                    111u8, 114u8, 101u8, 107u8, 97u8, 45u8, 115u8, 104u8, 105u8, 102u8, 116u8,
+                    101u8, 100u8, 107u8, 98u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8, 107u8, 98u8,
+                    45u8, 116u8, 114u8, 117u8, 101u8, 107u8, 99u8, 45u8, 102u8, 97u8, 108u8, 115u8,
+                    101u8, 107u8, 99u8, 45u8, 116u8, 114u8, 117u8, 101u8, 107u8, 102u8, 45u8,
+                    102u8, 97u8, 108u8, 115u8, 101u8, 107u8, 102u8, 45u8, 108u8, 111u8, 119u8,
+                    101u8, 114u8, 107u8, 102u8, 45u8, 117u8, 112u8, 112u8, 101u8, 114u8, 107u8,
+                    107u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8, 107u8, 107u8, 45u8, 116u8,
+                    114u8, 117u8, 101u8, 107u8, 110u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8,
+                    107u8, 110u8, 45u8, 116u8, 114u8, 117u8, 101u8, 107u8, 115u8, 45u8, 105u8,
+                    100u8, 101u8, 110u8, 116u8, 105u8, 99u8, 107u8, 115u8, 45u8, 108u8, 101u8,
+                    118u8, 101u8, 108u8, 49u8, 107u8, 115u8, 45u8, 108u8, 101u8, 118u8, 101u8,
+                    108u8, 50u8, 107u8, 115u8, 45u8, 108u8, 101u8, 118u8, 101u8, 108u8, 51u8,
+                    107u8, 115u8, 45u8, 108u8, 101u8, 118u8, 101u8, 108u8, 52u8, 108u8, 98u8, 45u8,
+                    108u8, 111u8, 111u8, 115u8, 101u8, 108u8, 98u8, 45u8, 110u8, 111u8, 114u8,
+                    109u8, 97u8, 108u8, 108u8, 98u8, 45u8, 115u8, 116u8, 114u8, 105u8, 99u8, 116u8,
+                    109u8, 115u8, 45u8, 109u8, 101u8, 116u8, 114u8, 105u8, 99u8, 109u8, 115u8,
+                    45u8, 117u8, 107u8, 115u8, 121u8, 115u8, 116u8, 101u8, 109u8, 109u8, 115u8,
+                    45u8, 117u8, 115u8, 115u8, 121u8, 115u8, 116u8, 101u8, 109u8, 110u8, 117u8,
+                    45u8, 97u8, 114u8, 97u8, 98u8, 110u8, 117u8, 45u8, 97u8, 114u8, 97u8, 98u8,
+                    101u8, 120u8, 116u8, 110u8, 117u8, 45u8, 97u8, 114u8, 109u8, 110u8, 110u8,
+                    117u8, 45u8, 97u8, 114u8, 109u8, 110u8, 108u8, 111u8, 119u8, 110u8, 117u8,
+                    45u8, 98u8, 101u8, 110u8, 103u8, 110u8, 117u8, 45u8, 99u8, 97u8, 107u8, 109u8,
+                    110u8, 117u8, 45u8, 100u8, 101u8, 118u8, 97u8, 110u8, 117u8, 45u8, 101u8,
+                    116u8, 104u8, 105u8, 110u8, 117u8, 45u8, 102u8, 105u8, 110u8, 97u8, 110u8,
+                    99u8, 101u8, 110u8, 117u8, 45u8, 102u8, 117u8, 108u8, 108u8, 119u8, 105u8,
+                    100u8, 101u8, 110u8, 117u8, 45u8, 103u8, 101u8, 111u8, 114u8, 110u8, 117u8,
+                    45u8, 103u8, 114u8, 101u8, 107u8, 110u8, 117u8, 45u8, 103u8, 114u8, 101u8,
+                    107u8, 108u8, 111u8, 119u8, 110u8, 117u8, 45u8, 103u8, 117u8, 106u8, 114u8,
+                    110u8, 117u8, 45u8, 103u8, 117u8, 114u8, 117u8, 110u8, 117u8, 45u8, 104u8,
+                    97u8, 110u8, 105u8, 100u8, 101u8, 99u8, 110u8, 117u8, 45u8, 104u8, 97u8, 110u8,
+                    115u8, 110u8, 117u8, 45u8, 104u8, 97u8, 110u8, 115u8, 102u8, 105u8, 110u8,
+                    110u8, 117u8, 45u8, 104u8, 97u8, 110u8, 116u8, 110u8, 117u8, 45u8, 104u8, 97u8,
+                    110u8, 116u8, 102u8, 105u8, 110u8, 110u8, 117u8, 45u8, 104u8, 101u8, 98u8,
+                    114u8, 110u8, 117u8, 45u8, 106u8, 97u8, 118u8, 97u8, 110u8, 117u8, 45u8, 106u8,
+                    112u8, 97u8, 110u8, 110u8, 117u8, 45u8, 106u8, 112u8, 97u8, 110u8, 102u8,
+                    105u8, 110u8, 110u8, 117u8, 45u8, 107u8, 104u8, 109u8, 114u8, 110u8, 117u8,
+                    45u8, 107u8, 110u8, 100u8, 97u8, 110u8, 117u8, 45u8, 108u8, 97u8, 111u8, 111u8,
+                    110u8, 117u8, 45u8, 108u8, 97u8, 116u8, 110u8, 110u8, 117u8, 45u8, 109u8,
+                    108u8, 121u8, 109u8, 110u8, 117u8, 45u8, 109u8, 111u8, 110u8, 103u8, 110u8,
+                    117u8, 45u8, 109u8, 116u8, 101u8, 105u8, 110u8, 117u8, 45u8, 109u8, 121u8,
+                    109u8, 114u8, 110u8, 117u8, 45u8, 110u8, 97u8, 116u8, 105u8, 118u8, 101u8,
+                    110u8, 117u8, 45u8, 111u8, 108u8, 99u8, 107u8, 110u8, 117u8, 45u8, 111u8,
+                    114u8, 121u8, 97u8, 110u8, 117u8, 45u8, 114u8, 111u8, 109u8, 97u8, 110u8,
+                    110u8, 117u8, 45u8, 114u8, 111u8, 109u8, 97u8, 110u8, 108u8, 111u8, 119u8,
+                    110u8, 117u8, 45u8, 116u8, 97u8, 109u8, 108u8, 110u8, 117u8, 45u8, 116u8, 97u8,
+                    109u8, 108u8, 100u8, 101u8, 99u8, 110u8, 117u8, 45u8, 116u8, 101u8, 108u8,
+                    117u8, 110u8, 117u8, 45u8, 116u8, 104u8, 97u8, 105u8, 110u8, 117u8, 45u8,
+                    116u8, 105u8, 98u8, 116u8, 110u8, 117u8, 45u8, 116u8, 114u8, 97u8, 100u8,
+                    105u8, 116u8, 105u8, 111u8, 110u8, 117u8, 45u8, 118u8, 97u8, 105u8, 105u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    107u8, 0u8, 0u8, 0u8, 0u8, 0u8, 18u8, 0u8, 34u8, 0u8, 53u8, 0u8, 69u8, 0u8,
+                    98u8, 0u8, 116u8, 0u8, 137u8, 0u8, 154u8, 0u8, 180u8, 0u8, 200u8, 0u8, 228u8,
+                    0u8, 4u8, 1u8, 23u8, 1u8, 42u8, 1u8, 58u8, 1u8, 94u8, 1u8, 127u8, 1u8, 154u8,
+                    1u8, 188u8, 1u8, 223u8, 1u8, 243u8, 1u8, 18u8, 2u8, 48u8, 2u8, 85u8, 2u8,
+                    113u8, 2u8, 128u8, 2u8, 140u8, 2u8, 155u8, 2u8, 179u8, 2u8, 218u8, 2u8, 233u8,
+                    2u8, 252u8, 2u8, 13u8, 3u8, 38u8, 3u8, 50u8, 3u8, 78u8, 3u8, 106u8, 3u8, 134u8,
+                    3u8, 162u8, 3u8, 179u8, 3u8, 206u8, 3u8, 233u8, 3u8, 7u8, 4u8, 61u8, 4u8,
+                    114u8, 4u8, 173u8, 4u8, 206u8, 4u8, 239u8, 4u8, 9u8, 5u8, 43u8, 5u8, 75u8, 5u8,
+                    106u8, 5u8, 118u8, 5u8, 142u8, 5u8, 157u8, 5u8, 204u8, 5u8, 0u8, 6u8, 34u8,
+                    6u8, 66u8, 6u8, 100u8, 6u8, 116u8, 6u8, 132u8, 6u8, 154u8, 6u8, 176u8, 6u8,
+                    209u8, 6u8, 228u8, 6u8, 5u8, 7u8, 25u8, 7u8, 43u8, 7u8, 65u8, 7u8, 84u8, 7u8,
+                    104u8, 7u8, 130u8, 7u8, 151u8, 7u8, 169u8, 7u8, 201u8, 7u8, 222u8, 7u8, 241u8,
+                    7u8, 12u8, 8u8, 42u8, 8u8, 84u8, 8u8, 113u8, 8u8, 154u8, 8u8, 172u8, 8u8,
+                    192u8, 8u8, 212u8, 8u8, 244u8, 8u8, 5u8, 9u8, 25u8, 9u8, 40u8, 9u8, 61u8, 9u8,
+                    83u8, 9u8, 101u8, 9u8, 119u8, 9u8, 138u8, 9u8, 154u8, 9u8, 174u8, 9u8, 191u8,
+                    9u8, 207u8, 9u8, 237u8, 9u8, 10u8, 10u8, 27u8, 10u8, 45u8, 10u8, 67u8, 10u8,
+                    87u8, 10u8, 109u8, 10u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8,
+                    105u8, 111u8, 32u8, 98u8, 117u8, 100u8, 105u8, 115u8, 116u8, 97u8, 99u8, 97u8,
+                    108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 105u8, 111u8, 32u8, 99u8, 104u8,
+                    105u8, 110u8, 111u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8,
+                    105u8, 111u8, 32u8, 99u8, 195u8, 179u8, 112u8, 116u8, 105u8, 99u8, 111u8, 99u8,
+                    97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 105u8, 111u8, 32u8, 100u8, 97u8,
+                    110u8, 103u8, 105u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8,
+                    105u8, 111u8, 32u8, 101u8, 116u8, 195u8, 173u8, 111u8, 112u8, 101u8, 32u8,
+                    65u8, 109u8, 101u8, 116u8, 101u8, 32u8, 65u8, 108u8, 101u8, 109u8, 99u8, 97u8,
+                    108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 105u8, 111u8, 32u8, 101u8, 116u8,
+                    195u8, 173u8, 111u8, 112u8, 101u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8,
+                    97u8, 114u8, 105u8, 111u8, 32u8, 103u8, 114u8, 101u8, 103u8, 111u8, 114u8,
+                    105u8, 97u8, 110u8, 111u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8,
+                    105u8, 111u8, 32u8, 104u8, 101u8, 98u8, 114u8, 101u8, 111u8, 99u8, 97u8, 108u8,
+                    101u8, 110u8, 100u8, 97u8, 114u8, 105u8, 111u8, 32u8, 110u8, 97u8, 99u8, 105u8,
+                    111u8, 110u8, 97u8, 108u8, 32u8, 104u8, 105u8, 110u8, 100u8, 195u8, 186u8,
+                    99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 105u8, 111u8, 32u8, 105u8,
+                    115u8, 108u8, 195u8, 161u8, 109u8, 105u8, 99u8, 111u8, 99u8, 97u8, 108u8,
+                    101u8, 110u8, 100u8, 97u8, 114u8, 105u8, 111u8, 32u8, 105u8, 115u8, 108u8,
+                    195u8, 161u8, 109u8, 105u8, 99u8, 111u8, 32u8, 116u8, 97u8, 98u8, 117u8, 108u8,
+                    97u8, 114u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 105u8, 111u8,
+                    32u8, 105u8, 115u8, 108u8, 195u8, 161u8, 109u8, 105u8, 99u8, 111u8, 32u8, 85u8,
+                    109u8, 109u8, 32u8, 97u8, 108u8, 45u8, 81u8, 117u8, 114u8, 97u8, 99u8, 97u8,
+                    108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 105u8, 111u8, 32u8, 73u8, 83u8, 79u8,
+                    45u8, 56u8, 54u8, 48u8, 49u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8,
+                    114u8, 105u8, 111u8, 32u8, 106u8, 97u8, 112u8, 111u8, 110u8, 195u8, 169u8,
+                    115u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 105u8, 111u8, 32u8,
+                    112u8, 101u8, 114u8, 115u8, 97u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8,
+                    114u8, 105u8, 111u8, 32u8, 100u8, 101u8, 32u8, 108u8, 97u8, 32u8, 82u8, 101u8,
+                    112u8, 195u8, 186u8, 98u8, 108u8, 105u8, 99u8, 97u8, 32u8, 100u8, 101u8, 32u8,
+                    67u8, 104u8, 105u8, 110u8, 97u8, 102u8, 111u8, 114u8, 109u8, 97u8, 116u8,
+                    111u8, 32u8, 100u8, 101u8, 32u8, 109u8, 111u8, 110u8, 101u8, 100u8, 97u8, 32u8,
+                    100u8, 101u8, 32u8, 99u8, 111u8, 110u8, 116u8, 97u8, 98u8, 105u8, 108u8, 105u8,
+                    100u8, 97u8, 100u8, 102u8, 111u8, 114u8, 109u8, 97u8, 116u8, 111u8, 32u8,
+                    100u8, 101u8, 32u8, 109u8, 111u8, 110u8, 101u8, 100u8, 97u8, 32u8, 101u8,
+                    115u8, 116u8, 195u8, 161u8, 110u8, 100u8, 97u8, 114u8, 111u8, 114u8, 100u8,
+                    101u8, 110u8, 32u8, 100u8, 101u8, 108u8, 32u8, 99u8, 104u8, 105u8, 110u8,
+                    111u8, 32u8, 116u8, 114u8, 97u8, 100u8, 105u8, 99u8, 105u8, 111u8, 110u8, 97u8,
+                    108u8, 32u8, 45u8, 32u8, 66u8, 105u8, 103u8, 53u8, 111u8, 114u8, 100u8, 101u8,
+                    110u8, 32u8, 97u8, 110u8, 116u8, 101u8, 114u8, 105u8, 111u8, 114u8, 44u8, 32u8,
+                    112u8, 97u8, 114u8, 97u8, 32u8, 99u8, 111u8, 109u8, 112u8, 97u8, 116u8, 105u8,
+                    98u8, 105u8, 108u8, 105u8, 100u8, 97u8, 100u8, 111u8, 114u8, 100u8, 101u8,
+                    110u8, 32u8, 100u8, 101u8, 32u8, 100u8, 105u8, 99u8, 99u8, 105u8, 111u8, 110u8,
+                    97u8, 114u8, 105u8, 111u8, 111u8, 114u8, 100u8, 101u8, 110u8, 32u8, 112u8,
+                    114u8, 101u8, 100u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8, 110u8, 97u8,
+                    100u8, 111u8, 32u8, 100u8, 101u8, 32u8, 85u8, 110u8, 105u8, 99u8, 111u8, 100u8,
+                    101u8, 114u8, 101u8, 103u8, 108u8, 97u8, 115u8, 32u8, 100u8, 101u8, 32u8,
+                    111u8, 114u8, 100u8, 101u8, 110u8, 97u8, 99u8, 105u8, 195u8, 179u8, 110u8,
+                    32u8, 101u8, 117u8, 114u8, 111u8, 112u8, 101u8, 97u8, 115u8, 111u8, 114u8,
+                    100u8, 101u8, 110u8, 32u8, 100u8, 101u8, 108u8, 32u8, 99u8, 104u8, 105u8,
+                    110u8, 111u8, 32u8, 115u8, 105u8, 109u8, 112u8, 108u8, 105u8, 102u8, 105u8,
+                    99u8, 97u8, 100u8, 111u8, 32u8, 45u8, 32u8, 71u8, 66u8, 50u8, 51u8, 49u8, 50u8,
+                    111u8, 114u8, 100u8, 101u8, 110u8, 32u8, 100u8, 101u8, 32u8, 108u8, 105u8,
+                    115u8, 116u8, 195u8, 173u8, 110u8, 32u8, 116u8, 101u8, 108u8, 101u8, 102u8,
+                    195u8, 179u8, 110u8, 105u8, 99u8, 111u8, 111u8, 114u8, 100u8, 101u8, 110u8,
+                    32u8, 102u8, 111u8, 110u8, 195u8, 169u8, 116u8, 105u8, 99u8, 111u8, 111u8,
+                    114u8, 100u8, 101u8, 110u8, 32u8, 112u8, 105u8, 110u8, 121u8, 105u8, 110u8,
+                    111u8, 114u8, 100u8, 101u8, 110u8, 32u8, 114u8, 101u8, 102u8, 111u8, 114u8,
+                    109u8, 97u8, 100u8, 111u8, 98u8, 195u8, 186u8, 115u8, 113u8, 117u8, 101u8,
+                    100u8, 97u8, 32u8, 100u8, 101u8, 32u8, 117u8, 115u8, 111u8, 32u8, 103u8, 101u8,
+                    110u8, 101u8, 114u8, 97u8, 108u8, 66u8, 117u8, 115u8, 99u8, 97u8, 114u8, 32u8,
+                    112u8, 111u8, 114u8, 32u8, 99u8, 111u8, 110u8, 115u8, 111u8, 110u8, 97u8,
+                    110u8, 116u8, 101u8, 32u8, 105u8, 110u8, 105u8, 99u8, 105u8, 97u8, 108u8, 32u8,
+                    100u8, 101u8, 32u8, 104u8, 97u8, 110u8, 103u8, 117u8, 108u8, 111u8, 114u8,
+                    100u8, 101u8, 110u8, 32u8, 101u8, 115u8, 116u8, 195u8, 161u8, 110u8, 100u8,
+                    97u8, 114u8, 111u8, 114u8, 100u8, 101u8, 110u8, 32u8, 100u8, 101u8, 32u8,
+                    108u8, 111u8, 115u8, 32u8, 116u8, 114u8, 97u8, 122u8, 111u8, 115u8, 111u8,
+                    114u8, 100u8, 101u8, 110u8, 32u8, 116u8, 114u8, 97u8, 100u8, 105u8, 99u8,
+                    105u8, 111u8, 110u8, 97u8, 108u8, 111u8, 114u8, 100u8, 101u8, 110u8, 32u8,
+                    100u8, 101u8, 32u8, 116u8, 114u8, 97u8, 122u8, 111u8, 115u8, 32u8, 114u8, 97u8,
+                    100u8, 105u8, 99u8, 97u8, 108u8, 101u8, 115u8, 111u8, 114u8, 100u8, 101u8,
+                    110u8, 32u8, 122u8, 104u8, 117u8, 121u8, 105u8, 110u8, 115u8, 105u8, 115u8,
+                    116u8, 101u8, 109u8, 97u8, 32u8, 100u8, 101u8, 32u8, 49u8, 50u8, 32u8, 104u8,
+                    111u8, 114u8, 97u8, 115u8, 32u8, 40u8, 48u8, 226u8, 128u8, 147u8, 49u8, 49u8,
+                    41u8, 115u8, 105u8, 115u8, 116u8, 101u8, 109u8, 97u8, 32u8, 100u8, 101u8, 32u8,
+                    49u8, 50u8, 32u8, 104u8, 111u8, 114u8, 97u8, 115u8, 32u8, 40u8, 49u8, 226u8,
+                    128u8, 147u8, 49u8, 50u8, 41u8, 115u8, 105u8, 115u8, 116u8, 101u8, 109u8, 97u8,
+                    32u8, 100u8, 101u8, 32u8, 50u8, 52u8, 32u8, 104u8, 111u8, 114u8, 97u8, 115u8,
+                    32u8, 40u8, 48u8, 226u8, 128u8, 147u8, 50u8, 51u8, 41u8, 115u8, 105u8, 115u8,
+                    116u8, 101u8, 109u8, 97u8, 32u8, 100u8, 101u8, 32u8, 50u8, 52u8, 32u8, 104u8,
+                    111u8, 114u8, 97u8, 115u8, 32u8, 40u8, 49u8, 226u8, 128u8, 147u8, 50u8, 52u8,
+                    41u8, 79u8, 114u8, 100u8, 101u8, 110u8, 97u8, 114u8, 32u8, 115u8, 195u8, 173u8,
+                    109u8, 98u8, 111u8, 108u8, 111u8, 115u8, 79u8, 114u8, 100u8, 101u8, 110u8,
+                    97u8, 114u8, 32u8, 105u8, 103u8, 110u8, 111u8, 114u8, 97u8, 110u8, 100u8,
+                    111u8, 32u8, 115u8, 195u8, 173u8, 109u8, 98u8, 111u8, 108u8, 111u8, 115u8,
+                    79u8, 114u8, 100u8, 101u8, 110u8, 97u8, 114u8, 32u8, 97u8, 99u8, 101u8, 110u8,
+                    116u8, 111u8, 115u8, 32u8, 110u8, 111u8, 114u8, 109u8, 97u8, 108u8, 109u8,
+                    101u8, 110u8, 116u8, 101u8, 79u8, 114u8, 100u8, 101u8, 110u8, 97u8, 114u8,
+                    32u8, 97u8, 99u8, 101u8, 110u8, 116u8, 111u8, 115u8, 32u8, 99u8, 111u8, 110u8,
+                    32u8, 105u8, 110u8, 118u8, 101u8, 114u8, 115u8, 105u8, 195u8, 179u8, 110u8,
+                    79u8, 114u8, 100u8, 101u8, 110u8, 97u8, 114u8, 32u8, 115u8, 105u8, 110u8, 32u8,
+                    100u8, 105u8, 115u8, 116u8, 105u8, 110u8, 103u8, 117u8, 105u8, 114u8, 32u8,
+                    101u8, 110u8, 116u8, 114u8, 101u8, 32u8, 109u8, 97u8, 121u8, 195u8, 186u8,
+                    115u8, 99u8, 117u8, 108u8, 97u8, 115u8, 32u8, 121u8, 32u8, 109u8, 105u8, 110u8,
+                    195u8, 186u8, 115u8, 99u8, 117u8, 108u8, 97u8, 115u8, 79u8, 114u8, 100u8,
+                    101u8, 110u8, 97u8, 114u8, 32u8, 100u8, 105u8, 115u8, 116u8, 105u8, 110u8,
+                    103u8, 117u8, 105u8, 101u8, 110u8, 100u8, 111u8, 32u8, 101u8, 110u8, 116u8,
+                    114u8, 101u8, 32u8, 109u8, 97u8, 121u8, 195u8, 186u8, 115u8, 99u8, 117u8,
+                    108u8, 97u8, 115u8, 32u8, 121u8, 32u8, 109u8, 105u8, 110u8, 195u8, 186u8,
+                    115u8, 99u8, 117u8, 108u8, 97u8, 115u8, 79u8, 114u8, 100u8, 101u8, 110u8, 97u8,
+                    114u8, 32u8, 115u8, 105u8, 103u8, 117u8, 105u8, 101u8, 110u8, 100u8, 111u8,
+                    32u8, 111u8, 114u8, 100u8, 101u8, 110u8, 32u8, 110u8, 111u8, 114u8, 109u8,
+                    97u8, 108u8, 32u8, 100u8, 101u8, 32u8, 109u8, 97u8, 121u8, 195u8, 186u8, 115u8,
+                    99u8, 117u8, 108u8, 97u8, 115u8, 32u8, 121u8, 32u8, 109u8, 105u8, 110u8, 195u8,
+                    186u8, 115u8, 99u8, 117u8, 108u8, 97u8, 115u8, 79u8, 114u8, 100u8, 101u8,
+                    110u8, 97u8, 114u8, 32u8, 101u8, 109u8, 112u8, 101u8, 122u8, 97u8, 110u8,
+                    100u8, 111u8, 32u8, 112u8, 111u8, 114u8, 32u8, 109u8, 105u8, 110u8, 195u8,
+                    186u8, 115u8, 99u8, 117u8, 108u8, 97u8, 115u8, 79u8, 114u8, 100u8, 101u8,
+                    110u8, 97u8, 114u8, 32u8, 101u8, 109u8, 112u8, 101u8, 122u8, 97u8, 110u8,
+                    100u8, 111u8, 32u8, 112u8, 111u8, 114u8, 32u8, 109u8, 97u8, 121u8, 195u8,
+                    186u8, 115u8, 99u8, 117u8, 108u8, 97u8, 115u8, 79u8, 114u8, 100u8, 101u8,
+                    110u8, 97u8, 114u8, 32u8, 115u8, 105u8, 110u8, 32u8, 110u8, 111u8, 114u8,
+                    109u8, 97u8, 108u8, 105u8, 122u8, 97u8, 99u8, 105u8, 195u8, 179u8, 110u8, 79u8,
+                    114u8, 100u8, 101u8, 110u8, 97u8, 114u8, 32u8, 99u8, 111u8, 110u8, 32u8, 110u8,
+                    111u8, 114u8, 109u8, 97u8, 108u8, 105u8, 122u8, 97u8, 99u8, 105u8, 195u8,
+                    179u8, 110u8, 32u8, 85u8, 110u8, 105u8, 99u8, 111u8, 100u8, 101u8, 79u8, 114u8,
+                    100u8, 101u8, 110u8, 97u8, 114u8, 32u8, 100u8, 195u8, 173u8, 103u8, 105u8,
+                    116u8, 111u8, 115u8, 32u8, 105u8, 110u8, 100u8, 105u8, 118u8, 105u8, 100u8,
+                    117u8, 97u8, 108u8, 109u8, 101u8, 110u8, 116u8, 101u8, 79u8, 114u8, 100u8,
+                    101u8, 110u8, 97u8, 114u8, 32u8, 100u8, 195u8, 173u8, 103u8, 105u8, 116u8,
+                    111u8, 115u8, 32u8, 110u8, 117u8, 109u8, 195u8, 169u8, 114u8, 105u8, 99u8,
+                    97u8, 109u8, 101u8, 110u8, 116u8, 101u8, 79u8, 114u8, 100u8, 101u8, 110u8,
+                    97u8, 114u8, 32u8, 116u8, 111u8, 100u8, 111u8, 79u8, 114u8, 100u8, 101u8,
+                    110u8, 97u8, 114u8, 32u8, 115u8, 111u8, 108u8, 111u8, 32u8, 108u8, 101u8,
+                    116u8, 114u8, 97u8, 115u8, 32u8, 98u8, 97u8, 115u8, 101u8, 79u8, 114u8, 100u8,
+                    101u8, 110u8, 97u8, 114u8, 32u8, 97u8, 99u8, 101u8, 110u8, 116u8, 111u8, 115u8,
+                    79u8, 114u8, 100u8, 101u8, 110u8, 97u8, 114u8, 32u8, 97u8, 99u8, 101u8, 110u8,
+                    116u8, 111u8, 115u8, 47u8, 109u8, 97u8, 121u8, 195u8, 186u8, 115u8, 99u8,
+                    117u8, 108u8, 97u8, 115u8, 32u8, 121u8, 32u8, 109u8, 105u8, 110u8, 195u8,
+                    186u8, 115u8, 99u8, 117u8, 108u8, 97u8, 115u8, 47u8, 97u8, 110u8, 99u8, 104u8,
+                    111u8, 79u8, 114u8, 100u8, 101u8, 110u8, 97u8, 114u8, 32u8, 97u8, 99u8, 101u8,
+                    110u8, 116u8, 111u8, 115u8, 47u8, 109u8, 97u8, 121u8, 195u8, 186u8, 115u8,
+                    99u8, 117u8, 108u8, 97u8, 115u8, 32u8, 121u8, 32u8, 109u8, 105u8, 110u8, 195u8,
+                    186u8, 115u8, 99u8, 117u8, 108u8, 97u8, 115u8, 47u8, 97u8, 110u8, 99u8, 104u8,
+                    111u8, 47u8, 107u8, 97u8, 110u8, 97u8, 101u8, 115u8, 116u8, 105u8, 108u8,
+                    111u8, 32u8, 100u8, 101u8, 32u8, 115u8, 97u8, 108u8, 116u8, 111u8, 32u8, 100u8,
+                    101u8, 32u8, 108u8, 195u8, 173u8, 110u8, 101u8, 97u8, 32u8, 102u8, 108u8,
+                    101u8, 120u8, 105u8, 98u8, 108u8, 101u8, 101u8, 115u8, 116u8, 105u8, 108u8,
+                    111u8, 32u8, 100u8, 101u8, 32u8, 115u8, 97u8, 108u8, 116u8, 111u8, 32u8, 100u8,
+                    101u8, 32u8, 108u8, 195u8, 173u8, 110u8, 101u8, 97u8, 32u8, 110u8, 111u8,
+                    114u8, 109u8, 97u8, 108u8, 101u8, 115u8, 116u8, 105u8, 108u8, 111u8, 32u8,
+                    100u8, 101u8, 32u8, 115u8, 97u8, 108u8, 116u8, 111u8, 32u8, 100u8, 101u8, 32u8,
+                    108u8, 195u8, 173u8, 110u8, 101u8, 97u8, 32u8, 101u8, 115u8, 116u8, 114u8,
+                    105u8, 99u8, 116u8, 111u8, 115u8, 105u8, 115u8, 116u8, 101u8, 109u8, 97u8,
+                    32u8, 109u8, 195u8, 169u8, 116u8, 114u8, 105u8, 99u8, 111u8, 115u8, 105u8,
+                    115u8, 116u8, 101u8, 109u8, 97u8, 32u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 115u8, 105u8, 115u8, 116u8, 101u8, 109u8, 97u8, 32u8,
+                    101u8, 115u8, 116u8, 97u8, 100u8, 111u8, 117u8, 110u8, 105u8, 100u8, 101u8,
+                    110u8, 115u8, 101u8, 100u8, 195u8, 173u8, 103u8, 105u8, 116u8, 111u8, 115u8,
+                    32u8, 105u8, 110u8, 100u8, 111u8, 97u8, 114u8, 195u8, 161u8, 98u8, 105u8,
+                    103u8, 111u8, 115u8, 100u8, 195u8, 173u8, 103u8, 105u8, 116u8, 111u8, 115u8,
+                    32u8, 105u8, 110u8, 100u8, 111u8, 97u8, 114u8, 195u8, 161u8, 98u8, 105u8,
+                    103u8, 111u8, 115u8, 32u8, 101u8, 120u8, 116u8, 101u8, 110u8, 100u8, 105u8,
+                    100u8, 111u8, 115u8, 110u8, 195u8, 186u8, 109u8, 101u8, 114u8, 111u8, 115u8,
+                    32u8, 101u8, 110u8, 32u8, 97u8, 114u8, 109u8, 101u8, 110u8, 105u8, 111u8,
+                    110u8, 195u8, 186u8, 109u8, 101u8, 114u8, 111u8, 115u8, 32u8, 101u8, 110u8,
+                    32u8, 97u8, 114u8, 109u8, 101u8, 110u8, 105u8, 111u8, 32u8, 101u8, 110u8, 32u8,
+                    109u8, 105u8, 110u8, 195u8, 186u8, 115u8, 99u8, 117u8, 108u8, 97u8, 100u8,
+                    195u8, 173u8, 103u8, 105u8, 116u8, 111u8, 115u8, 32u8, 101u8, 110u8, 32u8,
+                    98u8, 101u8, 110u8, 103u8, 97u8, 108u8, 195u8, 173u8, 100u8, 195u8, 173u8,
+                    103u8, 105u8, 116u8, 111u8, 115u8, 32u8, 101u8, 110u8, 32u8, 99u8, 104u8, 97u8,
+                    107u8, 109u8, 97u8, 100u8, 195u8, 173u8, 103u8, 105u8, 116u8, 111u8, 115u8,
+                    32u8, 101u8, 110u8, 32u8, 100u8, 101u8, 118u8, 97u8, 110u8, 97u8, 103u8, 97u8,
+                    114u8, 105u8, 110u8, 195u8, 186u8, 109u8, 101u8, 114u8, 111u8, 115u8, 32u8,
+                    101u8, 110u8, 32u8, 101u8, 116u8, 195u8, 173u8, 111u8, 112u8, 101u8, 78u8,
+                    195u8, 186u8, 109u8, 101u8, 114u8, 111u8, 115u8, 32u8, 102u8, 105u8, 110u8,
+                    97u8, 110u8, 99u8, 105u8, 101u8, 114u8, 111u8, 115u8, 100u8, 195u8, 173u8,
+                    103u8, 105u8, 116u8, 111u8, 115u8, 32u8, 100u8, 101u8, 32u8, 97u8, 110u8, 99u8,
+                    104u8, 111u8, 32u8, 99u8, 111u8, 109u8, 112u8, 108u8, 101u8, 116u8, 111u8,
+                    110u8, 195u8, 186u8, 109u8, 101u8, 114u8, 111u8, 115u8, 32u8, 101u8, 110u8,
+                    32u8, 103u8, 101u8, 111u8, 114u8, 103u8, 105u8, 97u8, 110u8, 111u8, 110u8,
+                    195u8, 186u8, 109u8, 101u8, 114u8, 111u8, 115u8, 32u8, 101u8, 110u8, 32u8,
+                    103u8, 114u8, 105u8, 101u8, 103u8, 111u8, 110u8, 195u8, 186u8, 109u8, 101u8,
+                    114u8, 111u8, 115u8, 32u8, 101u8, 110u8, 32u8, 103u8, 114u8, 105u8, 101u8,
+                    103u8, 111u8, 32u8, 101u8, 110u8, 32u8, 109u8, 105u8, 110u8, 195u8, 186u8,
+                    115u8, 99u8, 117u8, 108u8, 97u8, 100u8, 195u8, 173u8, 103u8, 105u8, 116u8,
+                    111u8, 115u8, 32u8, 101u8, 110u8, 32u8, 103u8, 117u8, 121u8, 97u8, 114u8, 97u8,
+                    116u8, 195u8, 173u8, 100u8, 195u8, 173u8, 103u8, 105u8, 116u8, 111u8, 115u8,
+                    32u8, 101u8, 110u8, 32u8, 103u8, 117u8, 114u8, 109u8, 117u8, 106u8, 105u8,
+                    110u8, 195u8, 186u8, 109u8, 101u8, 114u8, 111u8, 115u8, 32u8, 100u8, 101u8,
+                    99u8, 105u8, 109u8, 97u8, 108u8, 101u8, 115u8, 32u8, 101u8, 110u8, 32u8, 99u8,
+                    104u8, 105u8, 110u8, 111u8, 110u8, 195u8, 186u8, 109u8, 101u8, 114u8, 111u8,
+                    115u8, 32u8, 101u8, 110u8, 32u8, 99u8, 104u8, 105u8, 110u8, 111u8, 32u8, 115u8,
+                    105u8, 109u8, 112u8, 108u8, 105u8, 102u8, 105u8, 99u8, 97u8, 100u8, 111u8,
+                    110u8, 195u8, 186u8, 109u8, 101u8, 114u8, 111u8, 115u8, 32u8, 102u8, 105u8,
+                    110u8, 97u8, 110u8, 99u8, 105u8, 101u8, 114u8, 111u8, 115u8, 32u8, 101u8,
+                    110u8, 32u8, 99u8, 104u8, 105u8, 110u8, 111u8, 32u8, 115u8, 105u8, 109u8,
+                    112u8, 108u8, 105u8, 102u8, 105u8, 99u8, 97u8, 100u8, 111u8, 110u8, 195u8,
+                    186u8, 109u8, 101u8, 114u8, 111u8, 115u8, 32u8, 101u8, 110u8, 32u8, 99u8,
+                    104u8, 105u8, 110u8, 111u8, 32u8, 116u8, 114u8, 97u8, 100u8, 105u8, 99u8,
+                    105u8, 111u8, 110u8, 97u8, 108u8, 110u8, 195u8, 186u8, 109u8, 101u8, 114u8,
+                    111u8, 115u8, 32u8, 102u8, 105u8, 110u8, 97u8, 110u8, 99u8, 105u8, 101u8,
+                    114u8, 111u8, 115u8, 32u8, 101u8, 110u8, 32u8, 99u8, 104u8, 105u8, 110u8,
+                    111u8, 32u8, 116u8, 114u8, 97u8, 100u8, 105u8, 99u8, 105u8, 111u8, 110u8, 97u8,
+                    108u8, 110u8, 195u8, 186u8, 109u8, 101u8, 114u8, 111u8, 115u8, 32u8, 101u8,
+                    110u8, 32u8, 104u8, 101u8, 98u8, 114u8, 101u8, 111u8, 100u8, 195u8, 173u8,
+                    103u8, 105u8, 116u8, 111u8, 115u8, 32u8, 101u8, 110u8, 32u8, 106u8, 97u8,
+                    118u8, 97u8, 110u8, 195u8, 169u8, 115u8, 110u8, 195u8, 186u8, 109u8, 101u8,
+                    114u8, 111u8, 115u8, 32u8, 101u8, 110u8, 32u8, 106u8, 97u8, 112u8, 111u8,
+                    110u8, 195u8, 169u8, 115u8, 110u8, 195u8, 186u8, 109u8, 101u8, 114u8, 111u8,
+                    115u8, 32u8, 102u8, 105u8, 110u8, 97u8, 110u8, 99u8, 105u8, 101u8, 114u8,
+                    111u8, 115u8, 32u8, 101u8, 110u8, 32u8, 106u8, 97u8, 112u8, 111u8, 110u8,
+                    195u8, 169u8, 115u8, 100u8, 195u8, 173u8, 103u8, 105u8, 116u8, 111u8, 115u8,
+                    32u8, 101u8, 110u8, 32u8, 106u8, 101u8, 109u8, 101u8, 114u8, 100u8, 195u8,
+                    173u8, 103u8, 105u8, 116u8, 111u8, 115u8, 32u8, 101u8, 110u8, 32u8, 99u8, 97u8,
+                    110u8, 97u8, 114u8, 195u8, 169u8, 115u8, 100u8, 195u8, 173u8, 103u8, 105u8,
+                    116u8, 111u8, 115u8, 32u8, 101u8, 110u8, 32u8, 108u8, 97u8, 111u8, 100u8,
+                    195u8, 173u8, 103u8, 105u8, 116u8, 111u8, 115u8, 32u8, 111u8, 99u8, 99u8,
+                    105u8, 100u8, 101u8, 110u8, 116u8, 97u8, 108u8, 101u8, 115u8, 100u8, 195u8,
+                    173u8, 103u8, 105u8, 116u8, 111u8, 115u8, 32u8, 101u8, 110u8, 32u8, 109u8,
+                    97u8, 108u8, 97u8, 121u8, 195u8, 161u8, 108u8, 97u8, 109u8, 100u8, 195u8,
+                    173u8, 103u8, 105u8, 116u8, 111u8, 115u8, 32u8, 101u8, 110u8, 32u8, 109u8,
+                    111u8, 110u8, 103u8, 111u8, 108u8, 100u8, 195u8, 173u8, 103u8, 105u8, 116u8,
+                    111u8, 115u8, 32u8, 101u8, 110u8, 32u8, 109u8, 101u8, 105u8, 116u8, 101u8,
+                    105u8, 100u8, 195u8, 173u8, 103u8, 105u8, 116u8, 111u8, 115u8, 32u8, 101u8,
+                    110u8, 32u8, 98u8, 105u8, 114u8, 109u8, 97u8, 110u8, 111u8, 100u8, 195u8,
+                    173u8, 103u8, 105u8, 116u8, 111u8, 115u8, 32u8, 110u8, 97u8, 116u8, 105u8,
+                    118u8, 111u8, 115u8, 100u8, 195u8, 173u8, 103u8, 105u8, 116u8, 111u8, 115u8,
+                    32u8, 101u8, 110u8, 32u8, 111u8, 108u8, 32u8, 99u8, 104u8, 105u8, 107u8, 105u8,
+                    100u8, 195u8, 173u8, 103u8, 105u8, 116u8, 111u8, 115u8, 32u8, 101u8, 110u8,
+                    32u8, 111u8, 114u8, 105u8, 121u8, 97u8, 110u8, 195u8, 186u8, 109u8, 101u8,
+                    114u8, 111u8, 115u8, 32u8, 114u8, 111u8, 109u8, 97u8, 110u8, 111u8, 115u8,
+                    110u8, 195u8, 186u8, 109u8, 101u8, 114u8, 111u8, 115u8, 32u8, 114u8, 111u8,
+                    109u8, 97u8, 110u8, 111u8, 115u8, 32u8, 101u8, 110u8, 32u8, 109u8, 105u8,
+                    110u8, 195u8, 186u8, 115u8, 99u8, 117u8, 108u8, 97u8, 110u8, 195u8, 186u8,
+                    109u8, 101u8, 114u8, 111u8, 115u8, 32u8, 101u8, 110u8, 32u8, 116u8, 97u8,
+                    109u8, 105u8, 108u8, 32u8, 116u8, 114u8, 97u8, 100u8, 105u8, 99u8, 105u8,
+                    111u8, 110u8, 97u8, 108u8, 100u8, 195u8, 173u8, 103u8, 105u8, 116u8, 111u8,
+                    115u8, 32u8, 101u8, 110u8, 32u8, 116u8, 97u8, 109u8, 105u8, 108u8, 100u8,
+                    195u8, 173u8, 103u8, 105u8, 116u8, 111u8, 115u8, 32u8, 101u8, 110u8, 32u8,
+                    116u8, 101u8, 108u8, 117u8, 103u8, 117u8, 100u8, 195u8, 173u8, 103u8, 105u8,
+                    116u8, 111u8, 115u8, 32u8, 101u8, 110u8, 32u8, 116u8, 97u8, 105u8, 108u8, 97u8,
+                    110u8, 100u8, 195u8, 169u8, 115u8, 100u8, 195u8, 173u8, 103u8, 105u8, 116u8,
+                    111u8, 115u8, 32u8, 101u8, 110u8, 32u8, 116u8, 105u8, 98u8, 101u8, 116u8, 97u8,
+                    110u8, 111u8, 78u8, 195u8, 186u8, 109u8, 101u8, 114u8, 111u8, 115u8, 32u8,
+                    116u8, 114u8, 97u8, 100u8, 105u8, 99u8, 105u8, 111u8, 110u8, 97u8, 108u8,
+                    101u8, 115u8, 100u8, 195u8, 173u8, 103u8, 105u8, 116u8, 111u8, 115u8, 32u8,
+                    101u8, 110u8, 32u8, 118u8, 97u8, 105u8,
+                ])
+            },
+        )
+    },
+};
+static ES_AR: &DataStruct = &::icu_displaynames::provider::KeyDisplayNamesV1 {
+    keys: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    99u8, 97u8, 99u8, 102u8, 99u8, 111u8, 99u8, 117u8, 104u8, 99u8, 107u8, 97u8,
+                    107u8, 98u8, 107u8, 99u8, 107u8, 102u8, 107u8, 107u8, 107u8, 110u8, 107u8,
+                    115u8, 108u8, 98u8, 109u8, 115u8, 110u8, 117u8, 116u8, 122u8, 118u8, 97u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    17u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 27u8, 0u8, 32u8, 0u8, 38u8, 0u8,
+                    67u8, 0u8, 92u8, 0u8, 123u8, 0u8, 176u8, 0u8, 208u8, 0u8, 225u8, 0u8, 240u8,
+                    0u8, 3u8, 1u8, 28u8, 1u8, 48u8, 1u8, 56u8, 1u8, 68u8, 1u8, 99u8, 97u8, 108u8,
+                    101u8, 110u8, 100u8, 97u8, 114u8, 105u8, 111u8, 102u8, 111u8, 114u8, 109u8,
+                    97u8, 116u8, 111u8, 32u8, 100u8, 101u8, 32u8, 109u8, 111u8, 110u8, 101u8,
+                    100u8, 97u8, 111u8, 114u8, 100u8, 101u8, 110u8, 109u8, 111u8, 110u8, 101u8,
+                    100u8, 97u8, 99u8, 105u8, 99u8, 108u8, 111u8, 32u8, 104u8, 111u8, 114u8, 97u8,
+                    114u8, 105u8, 111u8, 32u8, 40u8, 49u8, 50u8, 32u8, 111u8, 32u8, 50u8, 52u8,
+                    32u8, 104u8, 111u8, 114u8, 97u8, 115u8, 41u8, 111u8, 114u8, 100u8, 101u8,
+                    110u8, 32u8, 105u8, 103u8, 110u8, 111u8, 114u8, 97u8, 110u8, 100u8, 111u8,
+                    32u8, 115u8, 195u8, 173u8, 109u8, 98u8, 111u8, 108u8, 111u8, 115u8, 111u8,
+                    114u8, 100u8, 101u8, 110u8, 32u8, 100u8, 101u8, 32u8, 97u8, 99u8, 101u8, 110u8,
+                    116u8, 111u8, 115u8, 32u8, 99u8, 111u8, 110u8, 32u8, 105u8, 110u8, 118u8,
+                    101u8, 114u8, 115u8, 105u8, 195u8, 179u8, 110u8, 111u8, 114u8, 100u8, 101u8,
+                    110u8, 32u8, 99u8, 111u8, 110u8, 32u8, 100u8, 105u8, 115u8, 116u8, 105u8,
+                    110u8, 99u8, 105u8, 195u8, 179u8, 110u8, 32u8, 101u8, 110u8, 116u8, 114u8,
+                    101u8, 32u8, 109u8, 97u8, 121u8, 195u8, 186u8, 115u8, 99u8, 117u8, 108u8, 97u8,
+                    115u8, 32u8, 121u8, 32u8, 109u8, 105u8, 110u8, 195u8, 186u8, 115u8, 99u8,
+                    117u8, 108u8, 97u8, 115u8, 111u8, 114u8, 100u8, 101u8, 110u8, 32u8, 100u8,
+                    101u8, 32u8, 109u8, 97u8, 121u8, 195u8, 186u8, 115u8, 99u8, 117u8, 108u8, 97u8,
+                    115u8, 47u8, 109u8, 105u8, 110u8, 195u8, 186u8, 115u8, 99u8, 117u8, 108u8,
+                    97u8, 115u8, 111u8, 114u8, 100u8, 101u8, 110u8, 32u8, 110u8, 111u8, 114u8,
+                    109u8, 97u8, 108u8, 105u8, 122u8, 97u8, 100u8, 111u8, 111u8, 114u8, 100u8,
+                    101u8, 110u8, 32u8, 110u8, 117u8, 109u8, 195u8, 169u8, 114u8, 105u8, 99u8,
+                    111u8, 105u8, 110u8, 116u8, 101u8, 110u8, 115u8, 105u8, 100u8, 97u8, 100u8,
+                    32u8, 100u8, 101u8, 32u8, 111u8, 114u8, 100u8, 101u8, 110u8, 101u8, 115u8,
+                    116u8, 105u8, 108u8, 111u8, 32u8, 100u8, 101u8, 32u8, 115u8, 97u8, 108u8,
+                    116u8, 111u8, 32u8, 100u8, 101u8, 32u8, 108u8, 195u8, 173u8, 110u8, 101u8,
+                    97u8, 115u8, 105u8, 115u8, 116u8, 101u8, 109u8, 97u8, 32u8, 100u8, 101u8, 32u8,
+                    109u8, 101u8, 100u8, 105u8, 99u8, 105u8, 195u8, 179u8, 110u8, 110u8, 195u8,
+                    186u8, 109u8, 101u8, 114u8, 111u8, 115u8, 122u8, 111u8, 110u8, 97u8, 32u8,
+                    104u8, 111u8, 114u8, 97u8, 114u8, 105u8, 97u8, 118u8, 97u8, 114u8, 105u8, 97u8,
+                    110u8, 116u8, 101u8, 32u8, 108u8, 111u8, 99u8, 97u8, 108u8,
+                ])
+            },
+        )
+    },
+    types: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    111u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 21u8, 0u8, 30u8, 0u8, 38u8, 0u8,
+                    48u8, 0u8, 59u8, 0u8, 69u8, 0u8, 78u8, 0u8, 87u8, 0u8, 97u8, 0u8, 113u8, 0u8,
+                    128u8, 0u8, 143u8, 0u8, 162u8, 0u8, 172u8, 0u8, 183u8, 0u8, 193u8, 0u8, 199u8,
+                    0u8, 209u8, 0u8, 220u8, 0u8, 230u8, 0u8, 239u8, 0u8, 246u8, 0u8, 254u8, 0u8,
+                    4u8, 1u8, 13u8, 1u8, 23u8, 1u8, 34u8, 1u8, 43u8, 1u8, 54u8, 1u8, 63u8, 1u8,
+                    74u8, 1u8, 85u8, 1u8, 94u8, 1u8, 101u8, 1u8, 110u8, 1u8, 119u8, 1u8, 125u8,
+                    1u8, 131u8, 1u8, 137u8, 1u8, 143u8, 1u8, 154u8, 1u8, 164u8, 1u8, 172u8, 1u8,
+                    179u8, 1u8, 187u8, 1u8, 194u8, 1u8, 202u8, 1u8, 210u8, 1u8, 218u8, 1u8, 226u8,
+                    1u8, 233u8, 1u8, 241u8, 1u8, 248u8, 1u8, 2u8, 2u8, 11u8, 2u8, 20u8, 2u8, 29u8,
+                    2u8, 38u8, 2u8, 46u8, 2u8, 55u8, 2u8, 64u8, 2u8, 73u8, 2u8, 84u8, 2u8, 95u8,
+                    2u8, 102u8, 2u8, 112u8, 2u8, 119u8, 2u8, 129u8, 2u8, 136u8, 2u8, 143u8, 2u8,
+                    150u8, 2u8, 157u8, 2u8, 167u8, 2u8, 178u8, 2u8, 185u8, 2u8, 192u8, 2u8, 202u8,
+                    2u8, 209u8, 2u8, 216u8, 2u8, 226u8, 2u8, 233u8, 2u8, 243u8, 2u8, 250u8, 2u8,
+                    4u8, 3u8, 11u8, 3u8, 18u8, 3u8, 25u8, 3u8, 35u8, 3u8, 42u8, 3u8, 49u8, 3u8,
+                    56u8, 3u8, 63u8, 3u8, 70u8, 3u8, 77u8, 3u8, 84u8, 3u8, 91u8, 3u8, 100u8, 3u8,
+                    107u8, 3u8, 114u8, 3u8, 122u8, 3u8, 133u8, 3u8, 140u8, 3u8, 150u8, 3u8, 157u8,
+                    3u8, 164u8, 3u8, 171u8, 3u8, 178u8, 3u8, 189u8, 3u8, 196u8, 3u8, 99u8, 97u8,
+                    45u8, 98u8, 117u8, 100u8, 100u8, 104u8, 105u8, 115u8, 116u8, 99u8, 97u8, 45u8,
+                    99u8, 104u8, 105u8, 110u8, 101u8, 115u8, 101u8, 99u8, 97u8, 45u8, 99u8, 111u8,
+                    112u8, 116u8, 105u8, 99u8, 99u8, 97u8, 45u8, 100u8, 97u8, 110u8, 103u8, 105u8,
+                    99u8, 97u8, 45u8, 101u8, 116u8, 104u8, 105u8, 111u8, 97u8, 97u8, 99u8, 97u8,
+                    45u8, 101u8, 116u8, 104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 99u8, 97u8, 45u8,
+                    103u8, 114u8, 101u8, 103u8, 111u8, 114u8, 121u8, 99u8, 97u8, 45u8, 104u8,
+                    101u8, 98u8, 114u8, 101u8, 119u8, 99u8, 97u8, 45u8, 105u8, 110u8, 100u8, 105u8,
+                    97u8, 110u8, 99u8, 97u8, 45u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8,
+                    99u8, 97u8, 45u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 99u8,
+                    105u8, 118u8, 105u8, 108u8, 99u8, 97u8, 45u8, 105u8, 115u8, 108u8, 97u8, 109u8,
+                    105u8, 99u8, 45u8, 114u8, 103u8, 115u8, 97u8, 99u8, 97u8, 45u8, 105u8, 115u8,
+                    108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 116u8, 98u8, 108u8, 97u8, 99u8, 97u8,
+                    45u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 117u8, 109u8, 97u8,
+                    108u8, 113u8, 117u8, 114u8, 97u8, 99u8, 97u8, 45u8, 105u8, 115u8, 111u8, 56u8,
+                    54u8, 48u8, 49u8, 99u8, 97u8, 45u8, 106u8, 97u8, 112u8, 97u8, 110u8, 101u8,
+                    115u8, 101u8, 99u8, 97u8, 45u8, 112u8, 101u8, 114u8, 115u8, 105u8, 97u8, 110u8,
+                    99u8, 97u8, 45u8, 114u8, 111u8, 99u8, 99u8, 102u8, 45u8, 97u8, 99u8, 99u8,
+                    111u8, 117u8, 110u8, 116u8, 99u8, 102u8, 45u8, 115u8, 116u8, 97u8, 110u8,
+                    100u8, 97u8, 114u8, 100u8, 99u8, 111u8, 45u8, 98u8, 105u8, 103u8, 53u8, 104u8,
+                    97u8, 110u8, 99u8, 111u8, 45u8, 99u8, 111u8, 109u8, 112u8, 97u8, 116u8, 99u8,
+                    111u8, 45u8, 100u8, 105u8, 99u8, 116u8, 99u8, 111u8, 45u8, 100u8, 117u8, 99u8,
+                    101u8, 116u8, 99u8, 111u8, 45u8, 101u8, 111u8, 114u8, 99u8, 111u8, 45u8, 103u8,
+                    98u8, 50u8, 51u8, 49u8, 50u8, 99u8, 111u8, 45u8, 112u8, 104u8, 111u8, 110u8,
+                    101u8, 98u8, 107u8, 99u8, 111u8, 45u8, 112u8, 104u8, 111u8, 110u8, 101u8,
+                    116u8, 105u8, 99u8, 99u8, 111u8, 45u8, 112u8, 105u8, 110u8, 121u8, 105u8,
+                    110u8, 99u8, 111u8, 45u8, 114u8, 101u8, 102u8, 111u8, 114u8, 109u8, 101u8,
+                    100u8, 99u8, 111u8, 45u8, 115u8, 101u8, 97u8, 114u8, 99u8, 104u8, 99u8, 111u8,
+                    45u8, 115u8, 101u8, 97u8, 114u8, 99u8, 104u8, 106u8, 108u8, 99u8, 111u8, 45u8,
+                    115u8, 116u8, 97u8, 110u8, 100u8, 97u8, 114u8, 100u8, 99u8, 111u8, 45u8, 115u8,
+                    116u8, 114u8, 111u8, 107u8, 101u8, 99u8, 111u8, 45u8, 116u8, 114u8, 97u8,
+                    100u8, 99u8, 111u8, 45u8, 117u8, 110u8, 105u8, 104u8, 97u8, 110u8, 99u8, 111u8,
+                    45u8, 122u8, 104u8, 117u8, 121u8, 105u8, 110u8, 104u8, 99u8, 45u8, 104u8, 49u8,
+                    49u8, 104u8, 99u8, 45u8, 104u8, 49u8, 50u8, 104u8, 99u8, 45u8, 104u8, 50u8,
+                    51u8, 104u8, 99u8, 45u8, 104u8, 50u8, 52u8, 107u8, 97u8, 45u8, 110u8, 111u8,
+                    105u8, 103u8, 110u8, 111u8, 114u8, 101u8, 107u8, 97u8, 45u8, 115u8, 104u8,
+                    105u8, 102u8, 116u8, 101u8, 100u8, 107u8, 98u8, 45u8, 102u8, 97u8, 108u8,
+                    115u8, 101u8, 107u8, 98u8, 45u8, 116u8, 114u8, 117u8, 101u8, 107u8, 99u8, 45u8,
+                    102u8, 97u8, 108u8, 115u8, 101u8, 107u8, 99u8, 45u8, 116u8, 114u8, 117u8,
+                    101u8, 107u8, 102u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8, 107u8, 102u8,
+                    45u8, 108u8, 111u8, 119u8, 101u8, 114u8, 107u8, 102u8, 45u8, 117u8, 112u8,
+                    112u8, 101u8, 114u8, 107u8, 107u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8,
+                    107u8, 107u8, 45u8, 116u8, 114u8, 117u8, 101u8, 107u8, 110u8, 45u8, 102u8,
+                    97u8, 108u8, 115u8, 101u8, 107u8, 110u8, 45u8, 116u8, 114u8, 117u8, 101u8,
+                    107u8, 115u8, 45u8, 105u8, 100u8, 101u8, 110u8, 116u8, 105u8, 99u8, 107u8,
+                    115u8, 45u8, 108u8, 101u8, 118u8, 101u8, 108u8, 49u8, 107u8, 115u8, 45u8,
+                    108u8, 101u8, 118u8, 101u8, 108u8, 50u8, 107u8, 115u8, 45u8, 108u8, 101u8,
+                    118u8, 101u8, 108u8, 51u8, 107u8, 115u8, 45u8, 108u8, 101u8, 118u8, 101u8,
+                    108u8, 52u8, 108u8, 98u8, 45u8, 108u8, 111u8, 111u8, 115u8, 101u8, 108u8, 98u8,
+                    45u8, 110u8, 111u8, 114u8, 109u8, 97u8, 108u8, 108u8, 98u8, 45u8, 115u8, 116u8,
+                    114u8, 105u8, 99u8, 116u8, 109u8, 115u8, 45u8, 109u8, 101u8, 116u8, 114u8,
+                    105u8, 99u8, 109u8, 115u8, 45u8, 117u8, 107u8, 115u8, 121u8, 115u8, 116u8,
+                    101u8, 109u8, 109u8, 115u8, 45u8, 117u8, 115u8, 115u8, 121u8, 115u8, 116u8,
+                    101u8, 109u8, 110u8, 117u8, 45u8, 97u8, 114u8, 97u8, 98u8, 110u8, 117u8, 45u8,
+                    97u8, 114u8, 97u8, 98u8, 101u8, 120u8, 116u8, 110u8, 117u8, 45u8, 97u8, 114u8,
+                    109u8, 110u8, 110u8, 117u8, 45u8, 97u8, 114u8, 109u8, 110u8, 108u8, 111u8,
+                    119u8, 110u8, 117u8, 45u8, 98u8, 101u8, 110u8, 103u8, 110u8, 117u8, 45u8, 99u8,
+                    97u8, 107u8, 109u8, 110u8, 117u8, 45u8, 100u8, 101u8, 118u8, 97u8, 110u8,
+                    117u8, 45u8, 101u8, 116u8, 104u8, 105u8, 110u8, 117u8, 45u8, 102u8, 105u8,
+                    110u8, 97u8, 110u8, 99u8, 101u8, 110u8, 117u8, 45u8, 102u8, 117u8, 108u8,
+                    108u8, 119u8, 105u8, 100u8, 101u8, 110u8, 117u8, 45u8, 103u8, 101u8, 111u8,
+                    114u8, 110u8, 117u8, 45u8, 103u8, 114u8, 101u8, 107u8, 110u8, 117u8, 45u8,
+                    103u8, 114u8, 101u8, 107u8, 108u8, 111u8, 119u8, 110u8, 117u8, 45u8, 103u8,
+                    117u8, 106u8, 114u8, 110u8, 117u8, 45u8, 103u8, 117u8, 114u8, 117u8, 110u8,
+                    117u8, 45u8, 104u8, 97u8, 110u8, 105u8, 100u8, 101u8, 99u8, 110u8, 117u8, 45u8,
+                    104u8, 97u8, 110u8, 115u8, 110u8, 117u8, 45u8, 104u8, 97u8, 110u8, 115u8,
+                    102u8, 105u8, 110u8, 110u8, 117u8, 45u8, 104u8, 97u8, 110u8, 116u8, 110u8,
+                    117u8, 45u8, 104u8, 97u8, 110u8, 116u8, 102u8, 105u8, 110u8, 110u8, 117u8,
+                    45u8, 104u8, 101u8, 98u8, 114u8, 110u8, 117u8, 45u8, 106u8, 97u8, 118u8, 97u8,
+                    110u8, 117u8, 45u8, 106u8, 112u8, 97u8, 110u8, 110u8, 117u8, 45u8, 106u8,
+                    112u8, 97u8, 110u8, 102u8, 105u8, 110u8, 110u8, 117u8, 45u8, 107u8, 104u8,
+                    109u8, 114u8, 110u8, 117u8, 45u8, 107u8, 110u8, 100u8, 97u8, 110u8, 117u8,
+                    45u8, 108u8, 97u8, 111u8, 111u8, 110u8, 117u8, 45u8, 108u8, 97u8, 116u8, 110u8,
+                    110u8, 117u8, 45u8, 109u8, 108u8, 121u8, 109u8, 110u8, 117u8, 45u8, 109u8,
+                    111u8, 110u8, 103u8, 110u8, 117u8, 45u8, 109u8, 116u8, 101u8, 105u8, 110u8,
+                    117u8, 45u8, 109u8, 121u8, 109u8, 114u8, 110u8, 117u8, 45u8, 110u8, 97u8,
+                    116u8, 105u8, 118u8, 101u8, 110u8, 117u8, 45u8, 111u8, 108u8, 99u8, 107u8,
+                    110u8, 117u8, 45u8, 111u8, 114u8, 121u8, 97u8, 110u8, 117u8, 45u8, 114u8,
+                    111u8, 109u8, 97u8, 110u8, 110u8, 117u8, 45u8, 114u8, 111u8, 109u8, 97u8,
+                    110u8, 108u8, 111u8, 119u8, 110u8, 117u8, 45u8, 116u8, 97u8, 109u8, 108u8,
+                    110u8, 117u8, 45u8, 116u8, 97u8, 109u8, 108u8, 100u8, 101u8, 99u8, 110u8,
+                    117u8, 45u8, 116u8, 101u8, 108u8, 117u8, 110u8, 117u8, 45u8, 116u8, 104u8,
+                    97u8, 105u8, 110u8, 117u8, 45u8, 116u8, 105u8, 98u8, 116u8, 110u8, 117u8, 45u8,
+                    116u8, 105u8, 114u8, 104u8, 110u8, 117u8, 45u8, 116u8, 114u8, 97u8, 100u8,
+                    105u8, 116u8, 105u8, 111u8, 110u8, 117u8, 45u8, 118u8, 97u8, 105u8, 105u8,
+                    110u8, 117u8, 45u8, 119u8, 97u8, 114u8, 97u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    111u8, 0u8, 0u8, 0u8, 0u8, 0u8, 18u8, 0u8, 34u8, 0u8, 53u8, 0u8, 69u8, 0u8,
+                    98u8, 0u8, 116u8, 0u8, 137u8, 0u8, 154u8, 0u8, 180u8, 0u8, 200u8, 0u8, 228u8,
+                    0u8, 9u8, 1u8, 37u8, 1u8, 69u8, 1u8, 88u8, 1u8, 107u8, 1u8, 123u8, 1u8, 159u8,
+                    1u8, 192u8, 1u8, 219u8, 1u8, 253u8, 1u8, 32u8, 2u8, 52u8, 2u8, 83u8, 2u8,
+                    107u8, 2u8, 144u8, 2u8, 171u8, 2u8, 186u8, 2u8, 198u8, 2u8, 213u8, 2u8, 237u8,
+                    2u8, 20u8, 3u8, 35u8, 3u8, 54u8, 3u8, 71u8, 3u8, 96u8, 3u8, 108u8, 3u8, 136u8,
+                    3u8, 164u8, 3u8, 192u8, 3u8, 220u8, 3u8, 237u8, 3u8, 8u8, 4u8, 35u8, 4u8, 65u8,
+                    4u8, 119u8, 4u8, 172u8, 4u8, 231u8, 4u8, 8u8, 5u8, 41u8, 5u8, 67u8, 5u8, 101u8,
+                    5u8, 133u8, 5u8, 164u8, 5u8, 176u8, 5u8, 200u8, 5u8, 215u8, 5u8, 6u8, 6u8,
+                    58u8, 6u8, 92u8, 6u8, 124u8, 6u8, 158u8, 6u8, 174u8, 6u8, 190u8, 6u8, 212u8,
+                    6u8, 234u8, 6u8, 11u8, 7u8, 30u8, 7u8, 63u8, 7u8, 83u8, 7u8, 101u8, 7u8, 123u8,
+                    7u8, 142u8, 7u8, 162u8, 7u8, 188u8, 7u8, 209u8, 7u8, 227u8, 7u8, 3u8, 8u8,
+                    24u8, 8u8, 43u8, 8u8, 70u8, 8u8, 100u8, 8u8, 142u8, 8u8, 171u8, 8u8, 212u8,
+                    8u8, 230u8, 8u8, 250u8, 8u8, 14u8, 9u8, 46u8, 9u8, 63u8, 9u8, 83u8, 9u8, 98u8,
+                    9u8, 119u8, 9u8, 141u8, 9u8, 159u8, 9u8, 177u8, 9u8, 196u8, 9u8, 212u8, 9u8,
+                    232u8, 9u8, 249u8, 9u8, 9u8, 10u8, 39u8, 10u8, 68u8, 10u8, 85u8, 10u8, 103u8,
+                    10u8, 125u8, 10u8, 145u8, 10u8, 161u8, 10u8, 183u8, 10u8, 198u8, 10u8, 99u8,
+                    97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 105u8, 111u8, 32u8, 98u8, 117u8,
+                    100u8, 105u8, 115u8, 116u8, 97u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8,
+                    114u8, 105u8, 111u8, 32u8, 99u8, 104u8, 105u8, 110u8, 111u8, 99u8, 97u8, 108u8,
+                    101u8, 110u8, 100u8, 97u8, 114u8, 105u8, 111u8, 32u8, 99u8, 195u8, 179u8,
+                    112u8, 116u8, 105u8, 99u8, 111u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8,
+                    114u8, 105u8, 111u8, 32u8, 100u8, 97u8, 110u8, 103u8, 105u8, 99u8, 97u8, 108u8,
+                    101u8, 110u8, 100u8, 97u8, 114u8, 105u8, 111u8, 32u8, 101u8, 116u8, 195u8,
+                    173u8, 111u8, 112u8, 101u8, 32u8, 65u8, 109u8, 101u8, 116u8, 101u8, 32u8, 65u8,
+                    108u8, 101u8, 109u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8,
+                    105u8, 111u8, 32u8, 101u8, 116u8, 195u8, 173u8, 111u8, 112u8, 101u8, 99u8,
+                    97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 105u8, 111u8, 32u8, 103u8,
+                    114u8, 101u8, 103u8, 111u8, 114u8, 105u8, 97u8, 110u8, 111u8, 99u8, 97u8,
+                    108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 105u8, 111u8, 32u8, 104u8, 101u8,
+                    98u8, 114u8, 101u8, 111u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8,
+                    105u8, 111u8, 32u8, 110u8, 97u8, 99u8, 105u8, 111u8, 110u8, 97u8, 108u8, 32u8,
+                    104u8, 105u8, 110u8, 100u8, 195u8, 186u8, 99u8, 97u8, 108u8, 101u8, 110u8,
+                    100u8, 97u8, 114u8, 105u8, 111u8, 32u8, 105u8, 115u8, 108u8, 195u8, 161u8,
+                    109u8, 105u8, 99u8, 111u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8,
+                    105u8, 111u8, 32u8, 105u8, 115u8, 108u8, 195u8, 161u8, 109u8, 105u8, 99u8,
+                    111u8, 32u8, 116u8, 97u8, 98u8, 117u8, 108u8, 97u8, 114u8, 99u8, 97u8, 108u8,
+                    101u8, 110u8, 100u8, 97u8, 114u8, 105u8, 111u8, 32u8, 105u8, 115u8, 108u8,
+                    195u8, 161u8, 109u8, 105u8, 99u8, 111u8, 32u8, 40u8, 65u8, 114u8, 97u8, 98u8,
+                    105u8, 97u8, 32u8, 83u8, 97u8, 117u8, 100u8, 105u8, 116u8, 97u8, 41u8, 99u8,
+                    97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 105u8, 111u8, 32u8, 105u8,
+                    115u8, 108u8, 195u8, 161u8, 109u8, 105u8, 99u8, 111u8, 32u8, 116u8, 97u8, 98u8,
+                    117u8, 108u8, 97u8, 114u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8,
+                    105u8, 111u8, 32u8, 105u8, 115u8, 108u8, 195u8, 161u8, 109u8, 105u8, 99u8,
+                    111u8, 32u8, 85u8, 109u8, 109u8, 32u8, 97u8, 108u8, 45u8, 81u8, 117u8, 114u8,
+                    97u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 105u8, 111u8, 32u8,
+                    73u8, 83u8, 79u8, 45u8, 56u8, 54u8, 48u8, 49u8, 99u8, 97u8, 108u8, 101u8,
+                    110u8, 100u8, 97u8, 114u8, 105u8, 111u8, 32u8, 106u8, 97u8, 112u8, 111u8,
+                    110u8, 195u8, 169u8, 115u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8,
+                    114u8, 105u8, 111u8, 32u8, 112u8, 101u8, 114u8, 115u8, 97u8, 99u8, 97u8, 108u8,
+                    101u8, 110u8, 100u8, 97u8, 114u8, 105u8, 111u8, 32u8, 100u8, 101u8, 32u8,
+                    108u8, 97u8, 32u8, 82u8, 101u8, 112u8, 195u8, 186u8, 98u8, 108u8, 105u8, 99u8,
+                    97u8, 32u8, 100u8, 101u8, 32u8, 67u8, 104u8, 105u8, 110u8, 97u8, 102u8, 111u8,
+                    114u8, 109u8, 97u8, 116u8, 111u8, 32u8, 100u8, 101u8, 32u8, 109u8, 111u8,
+                    110u8, 101u8, 100u8, 97u8, 32u8, 100u8, 101u8, 32u8, 99u8, 111u8, 110u8, 116u8,
+                    97u8, 98u8, 105u8, 108u8, 105u8, 100u8, 97u8, 100u8, 102u8, 111u8, 114u8,
+                    109u8, 97u8, 116u8, 111u8, 32u8, 100u8, 101u8, 32u8, 109u8, 111u8, 110u8,
+                    101u8, 100u8, 97u8, 32u8, 101u8, 115u8, 116u8, 195u8, 161u8, 110u8, 100u8,
+                    97u8, 114u8, 111u8, 114u8, 100u8, 101u8, 110u8, 32u8, 100u8, 101u8, 108u8,
+                    32u8, 99u8, 104u8, 105u8, 110u8, 111u8, 32u8, 116u8, 114u8, 97u8, 100u8, 105u8,
+                    99u8, 105u8, 111u8, 110u8, 97u8, 108u8, 32u8, 45u8, 32u8, 66u8, 105u8, 103u8,
+                    53u8, 111u8, 114u8, 100u8, 101u8, 110u8, 32u8, 97u8, 110u8, 116u8, 101u8,
+                    114u8, 105u8, 111u8, 114u8, 44u8, 32u8, 112u8, 97u8, 114u8, 97u8, 32u8, 99u8,
+                    111u8, 109u8, 112u8, 97u8, 116u8, 105u8, 98u8, 105u8, 108u8, 105u8, 100u8,
+                    97u8, 100u8, 111u8, 114u8, 100u8, 101u8, 110u8, 32u8, 100u8, 101u8, 32u8,
+                    100u8, 105u8, 99u8, 99u8, 105u8, 111u8, 110u8, 97u8, 114u8, 105u8, 111u8,
+                    111u8, 114u8, 100u8, 101u8, 110u8, 32u8, 112u8, 114u8, 101u8, 100u8, 101u8,
+                    116u8, 101u8, 114u8, 109u8, 105u8, 110u8, 97u8, 100u8, 111u8, 32u8, 100u8,
+                    101u8, 32u8, 85u8, 110u8, 105u8, 99u8, 111u8, 100u8, 101u8, 114u8, 101u8,
+                    103u8, 108u8, 97u8, 115u8, 32u8, 100u8, 101u8, 32u8, 111u8, 114u8, 100u8,
+                    101u8, 110u8, 32u8, 101u8, 117u8, 114u8, 111u8, 112u8, 101u8, 97u8, 115u8,
+                    111u8, 114u8, 100u8, 101u8, 110u8, 32u8, 100u8, 101u8, 108u8, 32u8, 99u8,
+                    104u8, 105u8, 110u8, 111u8, 32u8, 115u8, 105u8, 109u8, 112u8, 108u8, 105u8,
+                    102u8, 105u8, 99u8, 97u8, 100u8, 111u8, 32u8, 45u8, 32u8, 71u8, 66u8, 50u8,
+                    51u8, 49u8, 50u8, 111u8, 114u8, 100u8, 101u8, 110u8, 32u8, 100u8, 101u8, 32u8,
+                    97u8, 103u8, 101u8, 110u8, 100u8, 97u8, 32u8, 116u8, 101u8, 108u8, 101u8,
+                    102u8, 195u8, 179u8, 110u8, 105u8, 99u8, 97u8, 111u8, 114u8, 100u8, 101u8,
+                    110u8, 32u8, 102u8, 111u8, 110u8, 195u8, 169u8, 116u8, 105u8, 99u8, 111u8,
+                    111u8, 114u8, 100u8, 101u8, 110u8, 32u8, 112u8, 105u8, 110u8, 121u8, 105u8,
+                    110u8, 111u8, 114u8, 100u8, 101u8, 110u8, 32u8, 114u8, 101u8, 102u8, 111u8,
+                    114u8, 109u8, 97u8, 100u8, 111u8, 98u8, 195u8, 186u8, 115u8, 113u8, 117u8,
+                    101u8, 100u8, 97u8, 32u8, 100u8, 101u8, 32u8, 117u8, 115u8, 111u8, 32u8, 103u8,
+                    101u8, 110u8, 101u8, 114u8, 97u8, 108u8, 66u8, 117u8, 115u8, 99u8, 97u8, 114u8,
+                    32u8, 112u8, 111u8, 114u8, 32u8, 99u8, 111u8, 110u8, 115u8, 111u8, 110u8, 97u8,
+                    110u8, 116u8, 101u8, 32u8, 105u8, 110u8, 105u8, 99u8, 105u8, 97u8, 108u8, 32u8,
+                    100u8, 101u8, 32u8, 104u8, 97u8, 110u8, 103u8, 117u8, 108u8, 111u8, 114u8,
+                    100u8, 101u8, 110u8, 32u8, 101u8, 115u8, 116u8, 195u8, 161u8, 110u8, 100u8,
+                    97u8, 114u8, 111u8, 114u8, 100u8, 101u8, 110u8, 32u8, 100u8, 101u8, 32u8,
+                    108u8, 111u8, 115u8, 32u8, 116u8, 114u8, 97u8, 122u8, 111u8, 115u8, 111u8,
+                    114u8, 100u8, 101u8, 110u8, 32u8, 116u8, 114u8, 97u8, 100u8, 105u8, 99u8,
+                    105u8, 111u8, 110u8, 97u8, 108u8, 111u8, 114u8, 100u8, 101u8, 110u8, 32u8,
+                    100u8, 101u8, 32u8, 116u8, 114u8, 97u8, 122u8, 111u8, 115u8, 32u8, 114u8, 97u8,
+                    100u8, 105u8, 99u8, 97u8, 108u8, 101u8, 115u8, 111u8, 114u8, 100u8, 101u8,
+                    110u8, 32u8, 122u8, 104u8, 117u8, 121u8, 105u8, 110u8, 115u8, 105u8, 115u8,
+                    116u8, 101u8, 109u8, 97u8, 32u8, 100u8, 101u8, 32u8, 49u8, 50u8, 32u8, 104u8,
+                    111u8, 114u8, 97u8, 115u8, 32u8, 40u8, 48u8, 226u8, 128u8, 147u8, 49u8, 49u8,
+                    41u8, 115u8, 105u8, 115u8, 116u8, 101u8, 109u8, 97u8, 32u8, 100u8, 101u8, 32u8,
+                    49u8, 50u8, 32u8, 104u8, 111u8, 114u8, 97u8, 115u8, 32u8, 40u8, 49u8, 226u8,
+                    128u8, 147u8, 49u8, 50u8, 41u8, 115u8, 105u8, 115u8, 116u8, 101u8, 109u8, 97u8,
+                    32u8, 100u8, 101u8, 32u8, 50u8, 52u8, 32u8, 104u8, 111u8, 114u8, 97u8, 115u8,
+                    32u8, 40u8, 48u8, 226u8, 128u8, 147u8, 50u8, 51u8, 41u8, 115u8, 105u8, 115u8,
+                    116u8, 101u8, 109u8, 97u8, 32u8, 100u8, 101u8, 32u8, 50u8, 52u8, 32u8, 104u8,
+                    111u8, 114u8, 97u8, 115u8, 32u8, 40u8, 49u8, 226u8, 128u8, 147u8, 50u8, 52u8,
+                    41u8, 111u8, 114u8, 100u8, 101u8, 110u8, 97u8, 114u8, 32u8, 115u8, 195u8,
+                    173u8, 109u8, 98u8, 111u8, 108u8, 111u8, 115u8, 111u8, 114u8, 100u8, 101u8,
+                    110u8, 97u8, 114u8, 32u8, 105u8, 103u8, 110u8, 111u8, 114u8, 97u8, 110u8,
+                    100u8, 111u8, 32u8, 115u8, 195u8, 173u8, 109u8, 98u8, 111u8, 108u8, 111u8,
+                    115u8, 111u8, 114u8, 100u8, 101u8, 110u8, 97u8, 114u8, 32u8, 97u8, 99u8, 101u8,
+                    110u8, 116u8, 111u8, 115u8, 32u8, 110u8, 111u8, 114u8, 109u8, 97u8, 108u8,
+                    109u8, 101u8, 110u8, 116u8, 101u8, 111u8, 114u8, 100u8, 101u8, 110u8, 97u8,
+                    114u8, 32u8, 97u8, 99u8, 101u8, 110u8, 116u8, 111u8, 115u8, 32u8, 99u8, 111u8,
+                    110u8, 32u8, 105u8, 110u8, 118u8, 101u8, 114u8, 115u8, 105u8, 195u8, 179u8,
+                    110u8, 111u8, 114u8, 100u8, 101u8, 110u8, 97u8, 114u8, 32u8, 115u8, 105u8,
+                    110u8, 32u8, 100u8, 105u8, 115u8, 116u8, 105u8, 110u8, 103u8, 117u8, 105u8,
+                    114u8, 32u8, 101u8, 110u8, 116u8, 114u8, 101u8, 32u8, 109u8, 97u8, 121u8,
+                    195u8, 186u8, 115u8, 99u8, 117u8, 108u8, 97u8, 115u8, 32u8, 121u8, 32u8, 109u8,
+                    105u8, 110u8, 195u8, 186u8, 115u8, 99u8, 117u8, 108u8, 97u8, 115u8, 111u8,
+                    114u8, 100u8, 101u8, 110u8, 97u8, 114u8, 32u8, 100u8, 105u8, 115u8, 116u8,
+                    105u8, 110u8, 103u8, 117u8, 105u8, 101u8, 110u8, 100u8, 111u8, 32u8, 101u8,
+                    110u8, 116u8, 114u8, 101u8, 32u8, 109u8, 97u8, 121u8, 195u8, 186u8, 115u8,
+                    99u8, 117u8, 108u8, 97u8, 115u8, 32u8, 121u8, 32u8, 109u8, 105u8, 110u8, 195u8,
+                    186u8, 115u8, 99u8, 117u8, 108u8, 97u8, 115u8, 111u8, 114u8, 100u8, 101u8,
+                    110u8, 97u8, 114u8, 32u8, 115u8, 105u8, 103u8, 117u8, 105u8, 101u8, 110u8,
+                    100u8, 111u8, 32u8, 111u8, 114u8, 100u8, 101u8, 110u8, 32u8, 110u8, 111u8,
+                    114u8, 109u8, 97u8, 108u8, 32u8, 100u8, 101u8, 32u8, 109u8, 97u8, 121u8, 195u8,
+                    186u8, 115u8, 99u8, 117u8, 108u8, 97u8, 115u8, 32u8, 121u8, 32u8, 109u8, 105u8,
+                    110u8, 195u8, 186u8, 115u8, 99u8, 117u8, 108u8, 97u8, 115u8, 111u8, 114u8,
+                    100u8, 101u8, 110u8, 97u8, 114u8, 32u8, 101u8, 109u8, 112u8, 101u8, 122u8,
+                    97u8, 110u8, 100u8, 111u8, 32u8, 112u8, 111u8, 114u8, 32u8, 109u8, 105u8,
+                    110u8, 195u8, 186u8, 115u8, 99u8, 117u8, 108u8, 97u8, 115u8, 111u8, 114u8,
+                    100u8, 101u8, 110u8, 97u8, 114u8, 32u8, 101u8, 109u8, 112u8, 101u8, 122u8,
+                    97u8, 110u8, 100u8, 111u8, 32u8, 112u8, 111u8, 114u8, 32u8, 109u8, 97u8, 121u8,
+                    195u8, 186u8, 115u8, 99u8, 117u8, 108u8, 97u8, 115u8, 79u8, 114u8, 100u8,
+                    101u8, 110u8, 97u8, 114u8, 32u8, 115u8, 105u8, 110u8, 32u8, 110u8, 111u8,
+                    114u8, 109u8, 97u8, 108u8, 105u8, 122u8, 97u8, 99u8, 105u8, 195u8, 179u8,
+                    110u8, 79u8, 114u8, 100u8, 101u8, 110u8, 97u8, 114u8, 32u8, 99u8, 111u8, 110u8,
+                    32u8, 110u8, 111u8, 114u8, 109u8, 97u8, 108u8, 105u8, 122u8, 97u8, 99u8, 105u8,
+                    195u8, 179u8, 110u8, 32u8, 85u8, 110u8, 105u8, 99u8, 111u8, 100u8, 101u8, 79u8,
+                    114u8, 100u8, 101u8, 110u8, 97u8, 114u8, 32u8, 100u8, 195u8, 173u8, 103u8,
+                    105u8, 116u8, 111u8, 115u8, 32u8, 105u8, 110u8, 100u8, 105u8, 118u8, 105u8,
+                    100u8, 117u8, 97u8, 108u8, 109u8, 101u8, 110u8, 116u8, 101u8, 79u8, 114u8,
+                    100u8, 101u8, 110u8, 97u8, 114u8, 32u8, 100u8, 195u8, 173u8, 103u8, 105u8,
+                    116u8, 111u8, 115u8, 32u8, 110u8, 117u8, 109u8, 195u8, 169u8, 114u8, 105u8,
+                    99u8, 97u8, 109u8, 101u8, 110u8, 116u8, 101u8, 79u8, 114u8, 100u8, 101u8,
+                    110u8, 97u8, 114u8, 32u8, 116u8, 111u8, 100u8, 111u8, 79u8, 114u8, 100u8,
+                    101u8, 110u8, 97u8, 114u8, 32u8, 115u8, 111u8, 108u8, 111u8, 32u8, 108u8,
+                    101u8, 116u8, 114u8, 97u8, 115u8, 32u8, 98u8, 97u8, 115u8, 101u8, 79u8, 114u8,
+                    100u8, 101u8, 110u8, 97u8, 114u8, 32u8, 97u8, 99u8, 101u8, 110u8, 116u8, 111u8,
+                    115u8, 79u8, 114u8, 100u8, 101u8, 110u8, 97u8, 114u8, 32u8, 97u8, 99u8, 101u8,
+                    110u8, 116u8, 111u8, 115u8, 47u8, 109u8, 97u8, 121u8, 195u8, 186u8, 115u8,
+                    99u8, 117u8, 108u8, 97u8, 115u8, 32u8, 121u8, 32u8, 109u8, 105u8, 110u8, 195u8,
+                    186u8, 115u8, 99u8, 117u8, 108u8, 97u8, 115u8, 47u8, 97u8, 110u8, 99u8, 104u8,
+                    111u8, 79u8, 114u8, 100u8, 101u8, 110u8, 97u8, 114u8, 32u8, 97u8, 99u8, 101u8,
+                    110u8, 116u8, 111u8, 115u8, 47u8, 109u8, 97u8, 121u8, 195u8, 186u8, 115u8,
+                    99u8, 117u8, 108u8, 97u8, 115u8, 32u8, 121u8, 32u8, 109u8, 105u8, 110u8, 195u8,
+                    186u8, 115u8, 99u8, 117u8, 108u8, 97u8, 115u8, 47u8, 97u8, 110u8, 99u8, 104u8,
+                    111u8, 47u8, 107u8, 97u8, 110u8, 97u8, 101u8, 115u8, 116u8, 105u8, 108u8,
+                    111u8, 32u8, 100u8, 101u8, 32u8, 115u8, 97u8, 108u8, 116u8, 111u8, 32u8, 100u8,
+                    101u8, 32u8, 108u8, 195u8, 173u8, 110u8, 101u8, 97u8, 32u8, 102u8, 108u8,
+                    101u8, 120u8, 105u8, 98u8, 108u8, 101u8, 101u8, 115u8, 116u8, 105u8, 108u8,
+                    111u8, 32u8, 100u8, 101u8, 32u8, 115u8, 97u8, 108u8, 116u8, 111u8, 32u8, 100u8,
+                    101u8, 32u8, 108u8, 195u8, 173u8, 110u8, 101u8, 97u8, 32u8, 110u8, 111u8,
+                    114u8, 109u8, 97u8, 108u8, 101u8, 115u8, 116u8, 105u8, 108u8, 111u8, 32u8,
+                    100u8, 101u8, 32u8, 115u8, 97u8, 108u8, 116u8, 111u8, 32u8, 100u8, 101u8, 32u8,
+                    108u8, 195u8, 173u8, 110u8, 101u8, 97u8, 32u8, 101u8, 115u8, 116u8, 114u8,
+                    105u8, 99u8, 116u8, 111u8, 115u8, 105u8, 115u8, 116u8, 101u8, 109u8, 97u8,
+                    32u8, 109u8, 195u8, 169u8, 116u8, 114u8, 105u8, 99u8, 111u8, 115u8, 105u8,
+                    115u8, 116u8, 101u8, 109u8, 97u8, 32u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 115u8, 105u8, 115u8, 116u8, 101u8, 109u8, 97u8, 32u8,
+                    101u8, 115u8, 116u8, 97u8, 100u8, 111u8, 117u8, 110u8, 105u8, 100u8, 101u8,
+                    110u8, 115u8, 101u8, 100u8, 195u8, 173u8, 103u8, 105u8, 116u8, 111u8, 115u8,
+                    32u8, 105u8, 110u8, 100u8, 111u8, 97u8, 114u8, 195u8, 161u8, 98u8, 105u8,
+                    103u8, 111u8, 115u8, 100u8, 195u8, 173u8, 103u8, 105u8, 116u8, 111u8, 115u8,
+                    32u8, 105u8, 110u8, 100u8, 111u8, 97u8, 114u8, 195u8, 161u8, 98u8, 105u8,
+                    103u8, 111u8, 115u8, 32u8, 101u8, 120u8, 116u8, 101u8, 110u8, 100u8, 105u8,
+                    100u8, 111u8, 115u8, 110u8, 195u8, 186u8, 109u8, 101u8, 114u8, 111u8, 115u8,
+                    32u8, 101u8, 110u8, 32u8, 97u8, 114u8, 109u8, 101u8, 110u8, 105u8, 111u8,
+                    110u8, 195u8, 186u8, 109u8, 101u8, 114u8, 111u8, 115u8, 32u8, 101u8, 110u8,
+                    32u8, 97u8, 114u8, 109u8, 101u8, 110u8, 105u8, 111u8, 32u8, 101u8, 110u8, 32u8,
+                    109u8, 105u8, 110u8, 195u8, 186u8, 115u8, 99u8, 117u8, 108u8, 97u8, 100u8,
+                    195u8, 173u8, 103u8, 105u8, 116u8, 111u8, 115u8, 32u8, 101u8, 110u8, 32u8,
+                    98u8, 101u8, 110u8, 103u8, 97u8, 108u8, 195u8, 173u8, 100u8, 195u8, 173u8,
+                    103u8, 105u8, 116u8, 111u8, 115u8, 32u8, 101u8, 110u8, 32u8, 99u8, 104u8, 97u8,
+                    107u8, 109u8, 97u8, 100u8, 195u8, 173u8, 103u8, 105u8, 116u8, 111u8, 115u8,
+                    32u8, 101u8, 110u8, 32u8, 100u8, 101u8, 118u8, 97u8, 110u8, 97u8, 103u8, 97u8,
+                    114u8, 105u8, 110u8, 195u8, 186u8, 109u8, 101u8, 114u8, 111u8, 115u8, 32u8,
+                    101u8, 110u8, 32u8, 101u8, 116u8, 195u8, 173u8, 111u8, 112u8, 101u8, 78u8,
+                    195u8, 186u8, 109u8, 101u8, 114u8, 111u8, 115u8, 32u8, 102u8, 105u8, 110u8,
+                    97u8, 110u8, 99u8, 105u8, 101u8, 114u8, 111u8, 115u8, 100u8, 195u8, 173u8,
+                    103u8, 105u8, 116u8, 111u8, 115u8, 32u8, 100u8, 101u8, 32u8, 97u8, 110u8, 99u8,
+                    104u8, 111u8, 32u8, 99u8, 111u8, 109u8, 112u8, 108u8, 101u8, 116u8, 111u8,
+                    110u8, 195u8, 186u8, 109u8, 101u8, 114u8, 111u8, 115u8, 32u8, 101u8, 110u8,
+                    32u8, 103u8, 101u8, 111u8, 114u8, 103u8, 105u8, 97u8, 110u8, 111u8, 110u8,
+                    195u8, 186u8, 109u8, 101u8, 114u8, 111u8, 115u8, 32u8, 101u8, 110u8, 32u8,
+                    103u8, 114u8, 105u8, 101u8, 103u8, 111u8, 110u8, 195u8, 186u8, 109u8, 101u8,
+                    114u8, 111u8, 115u8, 32u8, 101u8, 110u8, 32u8, 103u8, 114u8, 105u8, 101u8,
+                    103u8, 111u8, 32u8, 101u8, 110u8, 32u8, 109u8, 105u8, 110u8, 195u8, 186u8,
+                    115u8, 99u8, 117u8, 108u8, 97u8, 100u8, 195u8, 173u8, 103u8, 105u8, 116u8,
+                    111u8, 115u8, 32u8, 101u8, 110u8, 32u8, 103u8, 117u8, 121u8, 97u8, 114u8, 97u8,
+                    116u8, 195u8, 173u8, 100u8, 195u8, 173u8, 103u8, 105u8, 116u8, 111u8, 115u8,
+                    32u8, 101u8, 110u8, 32u8, 103u8, 117u8, 114u8, 109u8, 117u8, 106u8, 105u8,
+                    110u8, 195u8, 186u8, 109u8, 101u8, 114u8, 111u8, 115u8, 32u8, 100u8, 101u8,
+                    99u8, 105u8, 109u8, 97u8, 108u8, 101u8, 115u8, 32u8, 101u8, 110u8, 32u8, 99u8,
+                    104u8, 105u8, 110u8, 111u8, 110u8, 195u8, 186u8, 109u8, 101u8, 114u8, 111u8,
+                    115u8, 32u8, 101u8, 110u8, 32u8, 99u8, 104u8, 105u8, 110u8, 111u8, 32u8, 115u8,
+                    105u8, 109u8, 112u8, 108u8, 105u8, 102u8, 105u8, 99u8, 97u8, 100u8, 111u8,
+                    110u8, 195u8, 186u8, 109u8, 101u8, 114u8, 111u8, 115u8, 32u8, 102u8, 105u8,
+                    110u8, 97u8, 110u8, 99u8, 105u8, 101u8, 114u8, 111u8, 115u8, 32u8, 101u8,
+                    110u8, 32u8, 99u8, 104u8, 105u8, 110u8, 111u8, 32u8, 115u8, 105u8, 109u8,
+                    112u8, 108u8, 105u8, 102u8, 105u8, 99u8, 97u8, 100u8, 111u8, 110u8, 195u8,
+                    186u8, 109u8, 101u8, 114u8, 111u8, 115u8, 32u8, 101u8, 110u8, 32u8, 99u8,
+                    104u8, 105u8, 110u8, 111u8, 32u8, 116u8, 114u8, 97u8, 100u8, 105u8, 99u8,
+                    105u8, 111u8, 110u8, 97u8, 108u8, 110u8, 195u8, 186u8, 109u8, 101u8, 114u8,
+                    111u8, 115u8, 32u8, 102u8, 105u8, 110u8, 97u8, 110u8, 99u8, 105u8, 101u8,
+                    114u8, 111u8, 115u8, 32u8, 101u8, 110u8, 32u8, 99u8, 104u8, 105u8, 110u8,
+                    111u8, 32u8, 116u8, 114u8, 97u8, 100u8, 105u8, 99u8, 105u8, 111u8, 110u8, 97u8,
+                    108u8, 110u8, 195u8, 186u8, 109u8, 101u8, 114u8, 111u8, 115u8, 32u8, 101u8,
+                    110u8, 32u8, 104u8, 101u8, 98u8, 114u8, 101u8, 111u8, 100u8, 195u8, 173u8,
+                    103u8, 105u8, 116u8, 111u8, 115u8, 32u8, 101u8, 110u8, 32u8, 106u8, 97u8,
+                    118u8, 97u8, 110u8, 195u8, 169u8, 115u8, 110u8, 195u8, 186u8, 109u8, 101u8,
+                    114u8, 111u8, 115u8, 32u8, 101u8, 110u8, 32u8, 106u8, 97u8, 112u8, 111u8,
+                    110u8, 195u8, 169u8, 115u8, 110u8, 195u8, 186u8, 109u8, 101u8, 114u8, 111u8,
+                    115u8, 32u8, 102u8, 105u8, 110u8, 97u8, 110u8, 99u8, 105u8, 101u8, 114u8,
+                    111u8, 115u8, 32u8, 101u8, 110u8, 32u8, 106u8, 97u8, 112u8, 111u8, 110u8,
+                    195u8, 169u8, 115u8, 100u8, 195u8, 173u8, 103u8, 105u8, 116u8, 111u8, 115u8,
+                    32u8, 101u8, 110u8, 32u8, 106u8, 101u8, 109u8, 101u8, 114u8, 100u8, 195u8,
+                    173u8, 103u8, 105u8, 116u8, 111u8, 115u8, 32u8, 101u8, 110u8, 32u8, 99u8, 97u8,
+                    110u8, 97u8, 114u8, 195u8, 169u8, 115u8, 100u8, 195u8, 173u8, 103u8, 105u8,
+                    116u8, 111u8, 115u8, 32u8, 101u8, 110u8, 32u8, 108u8, 97u8, 111u8, 100u8,
+                    195u8, 173u8, 103u8, 105u8, 116u8, 111u8, 115u8, 32u8, 111u8, 99u8, 99u8,
+                    105u8, 100u8, 101u8, 110u8, 116u8, 97u8, 108u8, 101u8, 115u8, 100u8, 195u8,
+                    173u8, 103u8, 105u8, 116u8, 111u8, 115u8, 32u8, 101u8, 110u8, 32u8, 109u8,
+                    97u8, 108u8, 97u8, 121u8, 195u8, 161u8, 108u8, 97u8, 109u8, 100u8, 195u8,
+                    173u8, 103u8, 105u8, 116u8, 111u8, 115u8, 32u8, 101u8, 110u8, 32u8, 109u8,
+                    111u8, 110u8, 103u8, 111u8, 108u8, 100u8, 195u8, 173u8, 103u8, 105u8, 116u8,
+                    111u8, 115u8, 32u8, 101u8, 110u8, 32u8, 109u8, 101u8, 105u8, 116u8, 101u8,
+                    105u8, 100u8, 195u8, 173u8, 103u8, 105u8, 116u8, 111u8, 115u8, 32u8, 101u8,
+                    110u8, 32u8, 98u8, 105u8, 114u8, 109u8, 97u8, 110u8, 111u8, 100u8, 195u8,
+                    173u8, 103u8, 105u8, 116u8, 111u8, 115u8, 32u8, 110u8, 97u8, 116u8, 105u8,
+                    118u8, 111u8, 115u8, 100u8, 195u8, 173u8, 103u8, 105u8, 116u8, 111u8, 115u8,
+                    32u8, 101u8, 110u8, 32u8, 111u8, 108u8, 32u8, 99u8, 104u8, 105u8, 107u8, 105u8,
+                    100u8, 195u8, 173u8, 103u8, 105u8, 116u8, 111u8, 115u8, 32u8, 101u8, 110u8,
+                    32u8, 111u8, 114u8, 105u8, 121u8, 97u8, 110u8, 195u8, 186u8, 109u8, 101u8,
+                    114u8, 111u8, 115u8, 32u8, 114u8, 111u8, 109u8, 97u8, 110u8, 111u8, 115u8,
+                    110u8, 195u8, 186u8, 109u8, 101u8, 114u8, 111u8, 115u8, 32u8, 114u8, 111u8,
+                    109u8, 97u8, 110u8, 111u8, 115u8, 32u8, 101u8, 110u8, 32u8, 109u8, 105u8,
+                    110u8, 195u8, 186u8, 115u8, 99u8, 117u8, 108u8, 97u8, 110u8, 195u8, 186u8,
+                    109u8, 101u8, 114u8, 111u8, 115u8, 32u8, 101u8, 110u8, 32u8, 116u8, 97u8,
+                    109u8, 105u8, 108u8, 32u8, 116u8, 114u8, 97u8, 100u8, 105u8, 99u8, 105u8,
+                    111u8, 110u8, 97u8, 108u8, 100u8, 195u8, 173u8, 103u8, 105u8, 116u8, 111u8,
+                    115u8, 32u8, 101u8, 110u8, 32u8, 116u8, 97u8, 109u8, 105u8, 108u8, 100u8,
+                    195u8, 173u8, 103u8, 105u8, 116u8, 111u8, 115u8, 32u8, 101u8, 110u8, 32u8,
+                    116u8, 101u8, 108u8, 117u8, 103u8, 117u8, 100u8, 195u8, 173u8, 103u8, 105u8,
+                    116u8, 111u8, 115u8, 32u8, 101u8, 110u8, 32u8, 116u8, 97u8, 105u8, 108u8, 97u8,
+                    110u8, 100u8, 195u8, 169u8, 115u8, 100u8, 195u8, 173u8, 103u8, 105u8, 116u8,
+                    111u8, 115u8, 32u8, 101u8, 110u8, 32u8, 116u8, 105u8, 98u8, 101u8, 116u8, 97u8,
+                    110u8, 111u8, 100u8, 195u8, 173u8, 103u8, 105u8, 116u8, 111u8, 115u8, 32u8,
+                    101u8, 110u8, 32u8, 116u8, 105u8, 114u8, 104u8, 110u8, 195u8, 186u8, 109u8,
+                    101u8, 114u8, 111u8, 115u8, 32u8, 116u8, 114u8, 97u8, 100u8, 117u8, 99u8,
+                    105u8, 111u8, 110u8, 97u8, 108u8, 101u8, 115u8, 100u8, 195u8, 173u8, 103u8,
+                    105u8, 116u8, 111u8, 115u8, 32u8, 101u8, 110u8, 32u8, 118u8, 97u8, 105u8,
+                    100u8, 195u8, 173u8, 103u8, 105u8, 116u8, 111u8, 115u8, 32u8, 101u8, 110u8,
+                    32u8, 87u8, 97u8, 114u8, 97u8, 110u8, 103u8, 32u8, 67u8, 105u8, 116u8, 105u8,
+                ])
+            },
+        )
+    },
+};
+static FIL: &DataStruct = &::icu_displaynames::provider::KeyDisplayNamesV1 {
+    keys: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    99u8, 97u8, 99u8, 102u8, 99u8, 111u8, 99u8, 117u8, 104u8, 99u8, 107u8, 97u8,
+                    107u8, 98u8, 107u8, 99u8, 107u8, 102u8, 107u8, 107u8, 107u8, 110u8, 107u8,
+                    115u8, 108u8, 98u8, 109u8, 115u8, 110u8, 117u8, 116u8, 122u8, 118u8, 97u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    17u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 24u8, 0u8, 50u8, 0u8, 54u8, 0u8,
+                    84u8, 0u8, 117u8, 0u8, 151u8, 0u8, 181u8, 0u8, 220u8, 0u8, 246u8, 0u8, 13u8,
+                    1u8, 34u8, 1u8, 58u8, 1u8, 77u8, 1u8, 87u8, 1u8, 96u8, 1u8, 75u8, 97u8, 108u8,
+                    101u8, 110u8, 100u8, 97u8, 114u8, 121u8, 111u8, 70u8, 111u8, 114u8, 109u8,
+                    97u8, 116u8, 32u8, 110u8, 103u8, 32u8, 80u8, 101u8, 114u8, 97u8, 80u8, 97u8,
+                    103u8, 107u8, 97u8, 107u8, 97u8, 115u8, 117u8, 110u8, 117u8, 100u8, 45u8,
+                    115u8, 117u8, 110u8, 111u8, 100u8, 32u8, 110u8, 103u8, 32u8, 65u8, 121u8,
+                    111u8, 115u8, 80u8, 101u8, 114u8, 97u8, 83u8, 105u8, 107u8, 108u8, 111u8, 32u8,
+                    110u8, 103u8, 32u8, 79u8, 114u8, 97u8, 115u8, 32u8, 40u8, 49u8, 50u8, 32u8,
+                    108u8, 97u8, 98u8, 97u8, 110u8, 32u8, 115u8, 97u8, 32u8, 50u8, 52u8, 41u8,
+                    80u8, 97u8, 103u8, 45u8, 117u8, 117u8, 114u8, 105u8, 45u8, 117u8, 114u8, 105u8,
+                    32u8, 110u8, 103u8, 32u8, 77u8, 103u8, 97u8, 32u8, 73u8, 103u8, 110u8, 111u8,
+                    114u8, 101u8, 32u8, 83u8, 121u8, 109u8, 98u8, 111u8, 108u8, 80u8, 97u8, 103u8,
+                    45u8, 117u8, 117u8, 114u8, 105u8, 45u8, 117u8, 114u8, 105u8, 32u8, 110u8,
+                    103u8, 32u8, 66u8, 97u8, 108u8, 105u8, 107u8, 116u8, 97u8, 100u8, 32u8, 110u8,
+                    97u8, 32u8, 65u8, 99u8, 99u8, 101u8, 110u8, 116u8, 67u8, 97u8, 115u8, 101u8,
+                    32u8, 83u8, 101u8, 110u8, 115u8, 105u8, 116u8, 105u8, 118u8, 101u8, 32u8,
+                    110u8, 97u8, 32u8, 80u8, 97u8, 103u8, 45u8, 117u8, 117u8, 114u8, 105u8, 45u8,
+                    117u8, 114u8, 105u8, 85u8, 112u8, 112u8, 101u8, 114u8, 99u8, 97u8, 115u8,
+                    101u8, 47u8, 76u8, 111u8, 119u8, 101u8, 114u8, 99u8, 97u8, 115u8, 101u8, 32u8,
+                    110u8, 97u8, 32u8, 80u8, 97u8, 103u8, 115u8, 117u8, 115u8, 117u8, 110u8, 117u8,
+                    100u8, 45u8, 115u8, 117u8, 110u8, 111u8, 100u8, 78u8, 111u8, 114u8, 109u8,
+                    97u8, 108u8, 105u8, 122u8, 101u8, 100u8, 32u8, 110u8, 97u8, 32u8, 80u8, 97u8,
+                    103u8, 45u8, 117u8, 117u8, 114u8, 105u8, 45u8, 117u8, 114u8, 105u8, 78u8,
+                    117u8, 109u8, 101u8, 114u8, 105u8, 99u8, 32u8, 110u8, 97u8, 32u8, 80u8, 97u8,
+                    103u8, 45u8, 117u8, 117u8, 114u8, 105u8, 45u8, 117u8, 114u8, 105u8, 76u8, 97u8,
+                    107u8, 97u8, 115u8, 32u8, 110u8, 103u8, 32u8, 80u8, 97u8, 103u8, 45u8, 117u8,
+                    117u8, 114u8, 105u8, 45u8, 117u8, 114u8, 105u8, 73u8, 115u8, 116u8, 105u8,
+                    108u8, 111u8, 32u8, 110u8, 103u8, 32u8, 80u8, 117u8, 116u8, 111u8, 108u8, 32u8,
+                    110u8, 103u8, 32u8, 76u8, 105u8, 110u8, 121u8, 97u8, 83u8, 105u8, 115u8, 116u8,
+                    101u8, 109u8, 97u8, 32u8, 110u8, 103u8, 32u8, 80u8, 97u8, 103u8, 115u8, 117u8,
+                    107u8, 97u8, 116u8, 77u8, 103u8, 97u8, 32u8, 78u8, 117u8, 109u8, 101u8, 114u8,
+                    111u8, 84u8, 105u8, 109u8, 101u8, 32u8, 90u8, 111u8, 110u8, 101u8, 76u8, 111u8,
+                    107u8, 97u8, 108u8, 32u8, 110u8, 97u8, 32u8, 86u8, 97u8, 114u8, 105u8, 97u8,
+                    110u8, 116u8,
+                ])
+            },
+        )
+    },
+    types: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    132u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 21u8, 0u8, 30u8, 0u8, 38u8, 0u8,
+                    48u8, 0u8, 59u8, 0u8, 69u8, 0u8, 78u8, 0u8, 87u8, 0u8, 97u8, 0u8, 113u8, 0u8,
+                    128u8, 0u8, 143u8, 0u8, 162u8, 0u8, 172u8, 0u8, 183u8, 0u8, 193u8, 0u8, 199u8,
+                    0u8, 209u8, 0u8, 220u8, 0u8, 230u8, 0u8, 239u8, 0u8, 246u8, 0u8, 254u8, 0u8,
+                    6u8, 1u8, 12u8, 1u8, 21u8, 1u8, 31u8, 1u8, 42u8, 1u8, 51u8, 1u8, 62u8, 1u8,
+                    71u8, 1u8, 82u8, 1u8, 93u8, 1u8, 102u8, 1u8, 109u8, 1u8, 118u8, 1u8, 127u8,
+                    1u8, 133u8, 1u8, 139u8, 1u8, 145u8, 1u8, 151u8, 1u8, 162u8, 1u8, 172u8, 1u8,
+                    180u8, 1u8, 187u8, 1u8, 195u8, 1u8, 202u8, 1u8, 210u8, 1u8, 218u8, 1u8, 226u8,
+                    1u8, 234u8, 1u8, 241u8, 1u8, 249u8, 1u8, 0u8, 2u8, 10u8, 2u8, 19u8, 2u8, 28u8,
+                    2u8, 37u8, 2u8, 46u8, 2u8, 54u8, 2u8, 63u8, 2u8, 72u8, 2u8, 81u8, 2u8, 92u8,
+                    2u8, 103u8, 2u8, 110u8, 2u8, 117u8, 2u8, 127u8, 2u8, 134u8, 2u8, 144u8, 2u8,
+                    151u8, 2u8, 158u8, 2u8, 165u8, 2u8, 172u8, 2u8, 179u8, 2u8, 186u8, 2u8, 193u8,
+                    2u8, 200u8, 2u8, 210u8, 2u8, 221u8, 2u8, 228u8, 2u8, 235u8, 2u8, 242u8, 2u8,
+                    252u8, 2u8, 3u8, 3u8, 10u8, 3u8, 20u8, 3u8, 27u8, 3u8, 37u8, 3u8, 44u8, 3u8,
+                    54u8, 3u8, 61u8, 3u8, 68u8, 3u8, 75u8, 3u8, 82u8, 3u8, 92u8, 3u8, 99u8, 3u8,
+                    106u8, 3u8, 113u8, 3u8, 120u8, 3u8, 131u8, 3u8, 138u8, 3u8, 145u8, 3u8, 152u8,
+                    3u8, 159u8, 3u8, 170u8, 3u8, 180u8, 3u8, 191u8, 3u8, 202u8, 3u8, 213u8, 3u8,
+                    220u8, 3u8, 227u8, 3u8, 234u8, 3u8, 241u8, 3u8, 248u8, 3u8, 255u8, 3u8, 10u8,
+                    4u8, 21u8, 4u8, 30u8, 4u8, 37u8, 4u8, 44u8, 4u8, 51u8, 4u8, 59u8, 4u8, 70u8,
+                    4u8, 77u8, 4u8, 87u8, 4u8, 94u8, 4u8, 101u8, 4u8, 108u8, 4u8, 119u8, 4u8, 99u8,
+                    97u8, 45u8, 98u8, 117u8, 100u8, 100u8, 104u8, 105u8, 115u8, 116u8, 99u8, 97u8,
+                    45u8, 99u8, 104u8, 105u8, 110u8, 101u8, 115u8, 101u8, 99u8, 97u8, 45u8, 99u8,
+                    111u8, 112u8, 116u8, 105u8, 99u8, 99u8, 97u8, 45u8, 100u8, 97u8, 110u8, 103u8,
+                    105u8, 99u8, 97u8, 45u8, 101u8, 116u8, 104u8, 105u8, 111u8, 97u8, 97u8, 99u8,
+                    97u8, 45u8, 101u8, 116u8, 104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 99u8, 97u8,
+                    45u8, 103u8, 114u8, 101u8, 103u8, 111u8, 114u8, 121u8, 99u8, 97u8, 45u8, 104u8,
+                    101u8, 98u8, 114u8, 101u8, 119u8, 99u8, 97u8, 45u8, 105u8, 110u8, 100u8, 105u8,
+                    97u8, 110u8, 99u8, 97u8, 45u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8,
+                    99u8, 97u8, 45u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 99u8,
+                    105u8, 118u8, 105u8, 108u8, 99u8, 97u8, 45u8, 105u8, 115u8, 108u8, 97u8, 109u8,
+                    105u8, 99u8, 45u8, 114u8, 103u8, 115u8, 97u8, 99u8, 97u8, 45u8, 105u8, 115u8,
+                    108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 116u8, 98u8, 108u8, 97u8, 99u8, 97u8,
+                    45u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 117u8, 109u8, 97u8,
+                    108u8, 113u8, 117u8, 114u8, 97u8, 99u8, 97u8, 45u8, 105u8, 115u8, 111u8, 56u8,
+                    54u8, 48u8, 49u8, 99u8, 97u8, 45u8, 106u8, 97u8, 112u8, 97u8, 110u8, 101u8,
+                    115u8, 101u8, 99u8, 97u8, 45u8, 112u8, 101u8, 114u8, 115u8, 105u8, 97u8, 110u8,
+                    99u8, 97u8, 45u8, 114u8, 111u8, 99u8, 99u8, 102u8, 45u8, 97u8, 99u8, 99u8,
+                    111u8, 117u8, 110u8, 116u8, 99u8, 102u8, 45u8, 115u8, 116u8, 97u8, 110u8,
+                    100u8, 97u8, 114u8, 100u8, 99u8, 111u8, 45u8, 98u8, 105u8, 103u8, 53u8, 104u8,
+                    97u8, 110u8, 99u8, 111u8, 45u8, 99u8, 111u8, 109u8, 112u8, 97u8, 116u8, 99u8,
+                    111u8, 45u8, 100u8, 105u8, 99u8, 116u8, 99u8, 111u8, 45u8, 100u8, 117u8, 99u8,
+                    101u8, 116u8, 99u8, 111u8, 45u8, 101u8, 109u8, 111u8, 106u8, 105u8, 99u8,
+                    111u8, 45u8, 101u8, 111u8, 114u8, 99u8, 111u8, 45u8, 103u8, 98u8, 50u8, 51u8,
+                    49u8, 50u8, 99u8, 111u8, 45u8, 112u8, 104u8, 111u8, 110u8, 101u8, 98u8, 107u8,
+                    99u8, 111u8, 45u8, 112u8, 104u8, 111u8, 110u8, 101u8, 116u8, 105u8, 99u8, 99u8,
+                    111u8, 45u8, 112u8, 105u8, 110u8, 121u8, 105u8, 110u8, 99u8, 111u8, 45u8,
+                    114u8, 101u8, 102u8, 111u8, 114u8, 109u8, 101u8, 100u8, 99u8, 111u8, 45u8,
+                    115u8, 101u8, 97u8, 114u8, 99u8, 104u8, 99u8, 111u8, 45u8, 115u8, 101u8, 97u8,
+                    114u8, 99u8, 104u8, 106u8, 108u8, 99u8, 111u8, 45u8, 115u8, 116u8, 97u8, 110u8,
+                    100u8, 97u8, 114u8, 100u8, 99u8, 111u8, 45u8, 115u8, 116u8, 114u8, 111u8,
+                    107u8, 101u8, 99u8, 111u8, 45u8, 116u8, 114u8, 97u8, 100u8, 99u8, 111u8, 45u8,
+                    117u8, 110u8, 105u8, 104u8, 97u8, 110u8, 99u8, 111u8, 45u8, 122u8, 104u8,
+                    117u8, 121u8, 105u8, 110u8, 104u8, 99u8, 45u8, 104u8, 49u8, 49u8, 104u8, 99u8,
+                    45u8, 104u8, 49u8, 50u8, 104u8, 99u8, 45u8, 104u8, 50u8, 51u8, 104u8, 99u8,
+                    45u8, 104u8, 50u8, 52u8, 107u8, 97u8, 45u8, 110u8, 111u8, 105u8, 103u8, 110u8,
+                    111u8, 114u8, 101u8, 107u8, 97u8, 45u8, 115u8, 104u8, 105u8, 102u8, 116u8,
+                    101u8, 100u8, 107u8, 98u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8, 107u8, 98u8,
+                    45u8, 116u8, 114u8, 117u8, 101u8, 107u8, 99u8, 45u8, 102u8, 97u8, 108u8, 115u8,
+                    101u8, 107u8, 99u8, 45u8, 116u8, 114u8, 117u8, 101u8, 107u8, 102u8, 45u8,
+                    102u8, 97u8, 108u8, 115u8, 101u8, 107u8, 102u8, 45u8, 108u8, 111u8, 119u8,
+                    101u8, 114u8, 107u8, 102u8, 45u8, 117u8, 112u8, 112u8, 101u8, 114u8, 107u8,
+                    107u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8, 107u8, 107u8, 45u8, 116u8,
+                    114u8, 117u8, 101u8, 107u8, 110u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8,
+                    107u8, 110u8, 45u8, 116u8, 114u8, 117u8, 101u8, 107u8, 115u8, 45u8, 105u8,
+                    100u8, 101u8, 110u8, 116u8, 105u8, 99u8, 107u8, 115u8, 45u8, 108u8, 101u8,
+                    118u8, 101u8, 108u8, 49u8, 107u8, 115u8, 45u8, 108u8, 101u8, 118u8, 101u8,
+                    108u8, 50u8, 107u8, 115u8, 45u8, 108u8, 101u8, 118u8, 101u8, 108u8, 51u8,
+                    107u8, 115u8, 45u8, 108u8, 101u8, 118u8, 101u8, 108u8, 52u8, 108u8, 98u8, 45u8,
+                    108u8, 111u8, 111u8, 115u8, 101u8, 108u8, 98u8, 45u8, 110u8, 111u8, 114u8,
+                    109u8, 97u8, 108u8, 108u8, 98u8, 45u8, 115u8, 116u8, 114u8, 105u8, 99u8, 116u8,
+                    109u8, 115u8, 45u8, 109u8, 101u8, 116u8, 114u8, 105u8, 99u8, 109u8, 115u8,
+                    45u8, 117u8, 107u8, 115u8, 121u8, 115u8, 116u8, 101u8, 109u8, 109u8, 115u8,
+                    45u8, 117u8, 115u8, 115u8, 121u8, 115u8, 116u8, 101u8, 109u8, 110u8, 117u8,
+                    45u8, 97u8, 104u8, 111u8, 109u8, 110u8, 117u8, 45u8, 97u8, 114u8, 97u8, 98u8,
+                    110u8, 117u8, 45u8, 97u8, 114u8, 97u8, 98u8, 101u8, 120u8, 116u8, 110u8, 117u8,
+                    45u8, 97u8, 114u8, 109u8, 110u8, 110u8, 117u8, 45u8, 97u8, 114u8, 109u8, 110u8,
+                    108u8, 111u8, 119u8, 110u8, 117u8, 45u8, 98u8, 97u8, 108u8, 105u8, 110u8,
+                    117u8, 45u8, 98u8, 101u8, 110u8, 103u8, 110u8, 117u8, 45u8, 98u8, 114u8, 97u8,
+                    104u8, 110u8, 117u8, 45u8, 99u8, 97u8, 107u8, 109u8, 110u8, 117u8, 45u8, 99u8,
+                    104u8, 97u8, 109u8, 110u8, 117u8, 45u8, 99u8, 121u8, 114u8, 108u8, 110u8,
+                    117u8, 45u8, 100u8, 101u8, 118u8, 97u8, 110u8, 117u8, 45u8, 101u8, 116u8,
+                    104u8, 105u8, 110u8, 117u8, 45u8, 102u8, 105u8, 110u8, 97u8, 110u8, 99u8,
+                    101u8, 110u8, 117u8, 45u8, 102u8, 117u8, 108u8, 108u8, 119u8, 105u8, 100u8,
+                    101u8, 110u8, 117u8, 45u8, 103u8, 101u8, 111u8, 114u8, 110u8, 117u8, 45u8,
+                    103u8, 111u8, 110u8, 109u8, 110u8, 117u8, 45u8, 103u8, 114u8, 101u8, 107u8,
+                    110u8, 117u8, 45u8, 103u8, 114u8, 101u8, 107u8, 108u8, 111u8, 119u8, 110u8,
+                    117u8, 45u8, 103u8, 117u8, 106u8, 114u8, 110u8, 117u8, 45u8, 103u8, 117u8,
+                    114u8, 117u8, 110u8, 117u8, 45u8, 104u8, 97u8, 110u8, 105u8, 100u8, 101u8,
+                    99u8, 110u8, 117u8, 45u8, 104u8, 97u8, 110u8, 115u8, 110u8, 117u8, 45u8, 104u8,
+                    97u8, 110u8, 115u8, 102u8, 105u8, 110u8, 110u8, 117u8, 45u8, 104u8, 97u8,
+                    110u8, 116u8, 110u8, 117u8, 45u8, 104u8, 97u8, 110u8, 116u8, 102u8, 105u8,
+                    110u8, 110u8, 117u8, 45u8, 104u8, 101u8, 98u8, 114u8, 110u8, 117u8, 45u8,
+                    104u8, 109u8, 110u8, 103u8, 110u8, 117u8, 45u8, 106u8, 97u8, 118u8, 97u8,
+                    110u8, 117u8, 45u8, 106u8, 112u8, 97u8, 110u8, 110u8, 117u8, 45u8, 106u8,
+                    112u8, 97u8, 110u8, 102u8, 105u8, 110u8, 110u8, 117u8, 45u8, 107u8, 97u8,
+                    108u8, 105u8, 110u8, 117u8, 45u8, 107u8, 104u8, 109u8, 114u8, 110u8, 117u8,
+                    45u8, 107u8, 110u8, 100u8, 97u8, 110u8, 117u8, 45u8, 108u8, 97u8, 110u8, 97u8,
+                    110u8, 117u8, 45u8, 108u8, 97u8, 110u8, 97u8, 116u8, 104u8, 97u8, 109u8, 110u8,
+                    117u8, 45u8, 108u8, 97u8, 111u8, 111u8, 110u8, 117u8, 45u8, 108u8, 97u8, 116u8,
+                    110u8, 110u8, 117u8, 45u8, 108u8, 101u8, 112u8, 99u8, 110u8, 117u8, 45u8,
+                    108u8, 105u8, 109u8, 98u8, 110u8, 117u8, 45u8, 109u8, 97u8, 116u8, 104u8, 98u8,
+                    111u8, 108u8, 100u8, 110u8, 117u8, 45u8, 109u8, 97u8, 116u8, 104u8, 100u8,
+                    98u8, 108u8, 110u8, 117u8, 45u8, 109u8, 97u8, 116u8, 104u8, 109u8, 111u8,
+                    110u8, 111u8, 110u8, 117u8, 45u8, 109u8, 97u8, 116u8, 104u8, 115u8, 97u8,
+                    110u8, 98u8, 110u8, 117u8, 45u8, 109u8, 97u8, 116u8, 104u8, 115u8, 97u8, 110u8,
+                    115u8, 110u8, 117u8, 45u8, 109u8, 108u8, 121u8, 109u8, 110u8, 117u8, 45u8,
+                    109u8, 111u8, 100u8, 105u8, 110u8, 117u8, 45u8, 109u8, 111u8, 110u8, 103u8,
+                    110u8, 117u8, 45u8, 109u8, 114u8, 111u8, 111u8, 110u8, 117u8, 45u8, 109u8,
+                    116u8, 101u8, 105u8, 110u8, 117u8, 45u8, 109u8, 121u8, 109u8, 114u8, 110u8,
+                    117u8, 45u8, 109u8, 121u8, 109u8, 114u8, 115u8, 104u8, 97u8, 110u8, 110u8,
+                    117u8, 45u8, 109u8, 121u8, 109u8, 114u8, 116u8, 108u8, 110u8, 103u8, 110u8,
+                    117u8, 45u8, 110u8, 97u8, 116u8, 105u8, 118u8, 101u8, 110u8, 117u8, 45u8,
+                    110u8, 107u8, 111u8, 111u8, 110u8, 117u8, 45u8, 111u8, 108u8, 99u8, 107u8,
+                    110u8, 117u8, 45u8, 111u8, 114u8, 121u8, 97u8, 110u8, 117u8, 45u8, 114u8,
+                    111u8, 109u8, 97u8, 110u8, 110u8, 117u8, 45u8, 114u8, 111u8, 109u8, 97u8,
+                    110u8, 108u8, 111u8, 119u8, 110u8, 117u8, 45u8, 116u8, 97u8, 109u8, 108u8,
+                    110u8, 117u8, 45u8, 116u8, 97u8, 109u8, 108u8, 100u8, 101u8, 99u8, 110u8,
+                    117u8, 45u8, 116u8, 101u8, 108u8, 117u8, 110u8, 117u8, 45u8, 116u8, 104u8,
+                    97u8, 105u8, 110u8, 117u8, 45u8, 116u8, 105u8, 98u8, 116u8, 110u8, 117u8, 45u8,
+                    116u8, 114u8, 97u8, 100u8, 105u8, 116u8, 105u8, 111u8, 110u8, 117u8, 45u8,
+                    118u8, 97u8, 105u8, 105u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    132u8, 0u8, 0u8, 0u8, 0u8, 0u8, 22u8, 0u8, 42u8, 0u8, 61u8, 0u8, 80u8, 0u8,
+                    112u8, 0u8, 134u8, 0u8, 157u8, 0u8, 177u8, 0u8, 207u8, 0u8, 227u8, 0u8, 253u8,
+                    0u8, 43u8, 1u8, 97u8, 1u8, 129u8, 1u8, 151u8, 1u8, 172u8, 1u8, 192u8, 1u8,
+                    211u8, 1u8, 239u8, 1u8, 8u8, 2u8, 71u8, 2u8, 134u8, 2u8, 179u8, 2u8, 227u8,
+                    2u8, 10u8, 3u8, 56u8, 3u8, 119u8, 3u8, 162u8, 3u8, 194u8, 3u8, 234u8, 3u8,
+                    21u8, 4u8, 46u8, 4u8, 86u8, 4u8, 123u8, 4u8, 163u8, 4u8, 208u8, 4u8, 0u8, 5u8,
+                    40u8, 5u8, 67u8, 5u8, 94u8, 5u8, 124u8, 5u8, 154u8, 5u8, 183u8, 5u8, 220u8,
+                    5u8, 4u8, 6u8, 46u8, 6u8, 84u8, 6u8, 116u8, 6u8, 156u8, 6u8, 188u8, 6u8, 220u8,
+                    6u8, 3u8, 7u8, 44u8, 7u8, 87u8, 7u8, 129u8, 7u8, 148u8, 7u8, 188u8, 7u8, 216u8,
+                    7u8, 255u8, 7u8, 43u8, 8u8, 72u8, 8u8, 102u8, 8u8, 134u8, 8u8, 151u8, 8u8,
+                    181u8, 8u8, 206u8, 8u8, 223u8, 8u8, 248u8, 8u8, 27u8, 9u8, 47u8, 9u8, 74u8,
+                    9u8, 95u8, 9u8, 112u8, 9u8, 131u8, 9u8, 150u8, 9u8, 167u8, 9u8, 189u8, 9u8,
+                    209u8, 9u8, 229u8, 9u8, 254u8, 9u8, 18u8, 10u8, 41u8, 10u8, 67u8, 10u8, 87u8,
+                    10u8, 117u8, 10u8, 135u8, 10u8, 153u8, 10u8, 183u8, 10u8, 216u8, 10u8, 12u8,
+                    11u8, 46u8, 11u8, 99u8, 11u8, 117u8, 11u8, 142u8, 11u8, 163u8, 11u8, 183u8,
+                    11u8, 217u8, 11u8, 238u8, 11u8, 0u8, 12u8, 20u8, 12u8, 46u8, 12u8, 72u8, 12u8,
+                    88u8, 12u8, 109u8, 12u8, 125u8, 12u8, 143u8, 12u8, 175u8, 12u8, 217u8, 12u8,
+                    5u8, 13u8, 48u8, 13u8, 86u8, 13u8, 108u8, 13u8, 125u8, 13u8, 141u8, 13u8,
+                    157u8, 13u8, 182u8, 13u8, 202u8, 13u8, 227u8, 13u8, 1u8, 14u8, 20u8, 14u8,
+                    39u8, 14u8, 60u8, 14u8, 78u8, 14u8, 95u8, 14u8, 125u8, 14u8, 145u8, 14u8,
+                    163u8, 14u8, 182u8, 14u8, 199u8, 14u8, 219u8, 14u8, 245u8, 14u8, 75u8, 97u8,
+                    108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 121u8, 111u8, 32u8, 110u8, 103u8,
+                    32u8, 66u8, 117u8, 100u8, 100u8, 104u8, 105u8, 115u8, 116u8, 75u8, 97u8, 108u8,
+                    101u8, 110u8, 100u8, 97u8, 114u8, 121u8, 111u8, 110u8, 103u8, 32u8, 67u8,
+                    104u8, 105u8, 110u8, 101u8, 115u8, 101u8, 75u8, 97u8, 108u8, 101u8, 110u8,
+                    100u8, 97u8, 114u8, 121u8, 111u8, 110u8, 103u8, 32u8, 67u8, 111u8, 112u8,
+                    116u8, 105u8, 99u8, 68u8, 97u8, 110u8, 103u8, 105u8, 32u8, 110u8, 97u8, 32u8,
+                    75u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 121u8, 111u8, 75u8, 97u8,
+                    108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 121u8, 111u8, 110u8, 103u8, 32u8,
+                    69u8, 116u8, 104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 32u8, 65u8, 109u8, 101u8,
+                    116u8, 101u8, 32u8, 65u8, 108u8, 101u8, 109u8, 75u8, 97u8, 108u8, 101u8, 110u8,
+                    100u8, 97u8, 114u8, 121u8, 111u8, 32u8, 110u8, 103u8, 32u8, 69u8, 116u8, 104u8,
+                    105u8, 111u8, 112u8, 105u8, 97u8, 71u8, 114u8, 101u8, 103u8, 111u8, 114u8,
+                    105u8, 97u8, 110u8, 32u8, 110u8, 97u8, 32u8, 75u8, 97u8, 108u8, 101u8, 110u8,
+                    100u8, 97u8, 114u8, 121u8, 111u8, 72u8, 101u8, 98u8, 114u8, 101u8, 119u8, 32u8,
+                    110u8, 97u8, 32u8, 75u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 121u8,
+                    111u8, 80u8, 97u8, 109u8, 98u8, 97u8, 110u8, 115u8, 97u8, 110u8, 103u8, 32u8,
+                    75u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 121u8, 111u8, 110u8,
+                    103u8, 32u8, 73u8, 110u8, 100u8, 105u8, 97u8, 110u8, 75u8, 97u8, 108u8, 101u8,
+                    110u8, 100u8, 97u8, 114u8, 121u8, 111u8, 110u8, 103u8, 32u8, 73u8, 115u8,
+                    108u8, 97u8, 109u8, 105u8, 99u8, 75u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8,
+                    114u8, 121u8, 111u8, 110u8, 103u8, 32u8, 73u8, 115u8, 108u8, 97u8, 109u8,
+                    105u8, 99u8, 45u8, 67u8, 105u8, 118u8, 105u8, 108u8, 75u8, 97u8, 108u8, 101u8,
+                    110u8, 100u8, 97u8, 114u8, 121u8, 111u8, 110u8, 103u8, 32u8, 73u8, 115u8,
+                    108u8, 97u8, 109u8, 105u8, 107u8, 111u8, 32u8, 40u8, 83u8, 97u8, 117u8, 100u8,
+                    105u8, 32u8, 65u8, 114u8, 97u8, 98u8, 105u8, 97u8, 44u8, 32u8, 115u8, 105u8,
+                    103u8, 104u8, 116u8, 105u8, 110u8, 103u8, 41u8, 75u8, 97u8, 108u8, 101u8,
+                    110u8, 100u8, 97u8, 114u8, 121u8, 111u8, 110u8, 103u8, 32u8, 73u8, 115u8,
+                    108u8, 97u8, 109u8, 105u8, 107u8, 111u8, 32u8, 40u8, 116u8, 97u8, 98u8, 117u8,
+                    108u8, 97u8, 114u8, 44u8, 32u8, 97u8, 115u8, 116u8, 114u8, 111u8, 110u8, 111u8,
+                    109u8, 105u8, 107u8, 97u8, 108u8, 32u8, 110u8, 97u8, 32u8, 101u8, 112u8, 111u8,
+                    99u8, 104u8, 41u8, 75u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 121u8,
+                    111u8, 110u8, 103u8, 32u8, 73u8, 115u8, 108u8, 97u8, 109u8, 32u8, 40u8, 85u8,
+                    109u8, 109u8, 32u8, 97u8, 108u8, 45u8, 81u8, 117u8, 114u8, 97u8, 41u8, 73u8,
+                    83u8, 79u8, 45u8, 56u8, 54u8, 48u8, 49u8, 32u8, 110u8, 97u8, 32u8, 75u8, 97u8,
+                    108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 121u8, 111u8, 75u8, 97u8, 108u8,
+                    101u8, 110u8, 100u8, 97u8, 114u8, 121u8, 111u8, 110u8, 103u8, 32u8, 74u8, 97u8,
+                    112u8, 97u8, 110u8, 101u8, 115u8, 101u8, 75u8, 97u8, 108u8, 101u8, 110u8,
+                    100u8, 97u8, 114u8, 121u8, 111u8, 110u8, 103u8, 32u8, 80u8, 101u8, 114u8,
+                    115u8, 105u8, 97u8, 110u8, 75u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8,
+                    121u8, 111u8, 110u8, 103u8, 32u8, 77u8, 105u8, 110u8, 103u8, 117u8, 111u8,
+                    70u8, 111u8, 114u8, 109u8, 97u8, 116u8, 32u8, 110u8, 103u8, 32u8, 80u8, 101u8,
+                    114u8, 97u8, 32u8, 115u8, 97u8, 32u8, 65u8, 99u8, 99u8, 111u8, 117u8, 110u8,
+                    116u8, 105u8, 110u8, 103u8, 75u8, 97u8, 114u8, 97u8, 110u8, 105u8, 119u8, 97u8,
+                    110u8, 103u8, 32u8, 70u8, 111u8, 114u8, 109u8, 97u8, 116u8, 32u8, 110u8, 103u8,
+                    32u8, 80u8, 101u8, 114u8, 97u8, 80u8, 97u8, 103u8, 107u8, 97u8, 107u8, 97u8,
+                    115u8, 117u8, 110u8, 111u8, 100u8, 45u8, 115u8, 117u8, 110u8, 111u8, 100u8,
+                    32u8, 110u8, 103u8, 32u8, 80u8, 97u8, 103u8, 45u8, 117u8, 117u8, 114u8, 105u8,
+                    32u8, 110u8, 103u8, 32u8, 84u8, 114u8, 97u8, 100u8, 105u8, 115u8, 121u8, 117u8,
+                    110u8, 97u8, 108u8, 32u8, 110u8, 97u8, 32u8, 67u8, 104u8, 105u8, 110u8, 101u8,
+                    115u8, 101u8, 32u8, 45u8, 32u8, 66u8, 105u8, 103u8, 53u8, 78u8, 97u8, 107u8,
+                    97u8, 114u8, 97u8, 97u8, 110u8, 103u8, 32u8, 80u8, 97u8, 103u8, 107u8, 97u8,
+                    107u8, 97u8, 115u8, 117u8, 110u8, 117u8, 100u8, 45u8, 115u8, 117u8, 110u8,
+                    111u8, 100u8, 32u8, 110u8, 103u8, 32u8, 80u8, 97u8, 103u8, 45u8, 117u8, 117u8,
+                    114u8, 105u8, 44u8, 32u8, 112u8, 97u8, 114u8, 97u8, 32u8, 115u8, 97u8, 32u8,
+                    99u8, 111u8, 109u8, 112u8, 97u8, 116u8, 105u8, 98u8, 105u8, 108u8, 105u8,
+                    116u8, 121u8, 80u8, 97u8, 103u8, 107u8, 97u8, 107u8, 97u8, 115u8, 117u8, 110u8,
+                    117u8, 100u8, 45u8, 115u8, 117u8, 110u8, 111u8, 100u8, 32u8, 110u8, 103u8,
+                    32u8, 80u8, 97u8, 103u8, 45u8, 117u8, 117u8, 114u8, 105u8, 32u8, 110u8, 103u8,
+                    32u8, 68u8, 105u8, 107u8, 115u8, 121u8, 117u8, 110u8, 97u8, 114u8, 121u8,
+                    111u8, 68u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 32u8, 110u8, 97u8, 32u8,
+                    80u8, 97u8, 103u8, 107u8, 97u8, 107u8, 97u8, 115u8, 117u8, 110u8, 117u8, 100u8,
+                    45u8, 115u8, 117u8, 110u8, 111u8, 100u8, 32u8, 110u8, 103u8, 32u8, 65u8, 121u8,
+                    111u8, 115u8, 32u8, 110u8, 103u8, 32u8, 85u8, 110u8, 105u8, 99u8, 111u8, 100u8,
+                    101u8, 80u8, 97u8, 103u8, 107u8, 97u8, 107u8, 97u8, 115u8, 117u8, 110u8, 117u8,
+                    100u8, 45u8, 115u8, 117u8, 110u8, 111u8, 100u8, 32u8, 110u8, 103u8, 32u8, 80u8,
+                    97u8, 103u8, 45u8, 117u8, 117u8, 114u8, 105u8, 32u8, 110u8, 103u8, 32u8, 69u8,
+                    109u8, 111u8, 106u8, 105u8, 77u8, 103u8, 97u8, 32u8, 84u8, 117u8, 110u8, 116u8,
+                    117u8, 110u8, 105u8, 110u8, 32u8, 110u8, 103u8, 32u8, 69u8, 117u8, 114u8,
+                    111u8, 112u8, 101u8, 97u8, 110u8, 32u8, 110u8, 97u8, 32u8, 80u8, 97u8, 103u8,
+                    107u8, 97u8, 107u8, 97u8, 115u8, 117u8, 110u8, 117u8, 100u8, 45u8, 115u8,
+                    117u8, 110u8, 111u8, 100u8, 80u8, 97u8, 103u8, 107u8, 97u8, 107u8, 97u8, 115u8,
+                    117u8, 110u8, 117u8, 100u8, 45u8, 115u8, 117u8, 110u8, 111u8, 100u8, 32u8,
+                    110u8, 103u8, 32u8, 80u8, 97u8, 103u8, 45u8, 117u8, 117u8, 114u8, 105u8, 32u8,
+                    110u8, 103u8, 32u8, 80u8, 105u8, 110u8, 97u8, 115u8, 105u8, 109u8, 112u8,
+                    108u8, 101u8, 110u8, 103u8, 32u8, 67u8, 104u8, 105u8, 110u8, 101u8, 115u8,
+                    101u8, 32u8, 45u8, 32u8, 71u8, 66u8, 50u8, 51u8, 49u8, 50u8, 80u8, 97u8, 103u8,
+                    107u8, 97u8, 107u8, 97u8, 115u8, 117u8, 110u8, 117u8, 100u8, 45u8, 115u8,
+                    117u8, 110u8, 111u8, 100u8, 32u8, 110u8, 103u8, 32u8, 80u8, 97u8, 103u8, 45u8,
+                    117u8, 117u8, 114u8, 105u8, 32u8, 110u8, 103u8, 32u8, 80u8, 104u8, 111u8,
+                    110u8, 101u8, 98u8, 111u8, 111u8, 107u8, 80u8, 104u8, 111u8, 110u8, 101u8,
+                    116u8, 105u8, 99u8, 32u8, 110u8, 97u8, 32u8, 65u8, 121u8, 111u8, 115u8, 32u8,
+                    110u8, 103u8, 32u8, 80u8, 97u8, 103u8, 45u8, 117u8, 117u8, 114u8, 105u8, 45u8,
+                    117u8, 114u8, 105u8, 80u8, 97u8, 103u8, 107u8, 97u8, 107u8, 97u8, 115u8, 117u8,
+                    110u8, 117u8, 100u8, 45u8, 115u8, 117u8, 110u8, 111u8, 100u8, 32u8, 110u8,
+                    103u8, 32u8, 80u8, 97u8, 103u8, 45u8, 117u8, 117u8, 114u8, 105u8, 32u8, 110u8,
+                    103u8, 32u8, 80u8, 105u8, 110u8, 121u8, 105u8, 110u8, 80u8, 97u8, 103u8, 107u8,
+                    97u8, 107u8, 97u8, 115u8, 117u8, 110u8, 117u8, 100u8, 45u8, 115u8, 117u8,
+                    110u8, 111u8, 100u8, 32u8, 110u8, 103u8, 32u8, 80u8, 97u8, 103u8, 45u8, 117u8,
+                    117u8, 114u8, 105u8, 32u8, 110u8, 103u8, 32u8, 78u8, 97u8, 45u8, 114u8, 101u8,
+                    102u8, 111u8, 114u8, 109u8, 80u8, 97u8, 110u8, 103u8, 107u8, 97u8, 108u8, 97u8,
+                    104u8, 97u8, 116u8, 97u8, 110u8, 103u8, 32u8, 80u8, 97u8, 103u8, 104u8, 97u8,
+                    104u8, 97u8, 110u8, 97u8, 112u8, 77u8, 97u8, 103u8, 104u8, 97u8, 110u8, 97u8,
+                    112u8, 32u8, 65u8, 121u8, 111u8, 110u8, 32u8, 115u8, 97u8, 32u8, 85u8, 110u8,
+                    97u8, 110u8, 103u8, 32u8, 75u8, 97u8, 116u8, 105u8, 110u8, 105u8, 103u8, 32u8,
+                    110u8, 103u8, 32u8, 72u8, 97u8, 110u8, 103u8, 117u8, 108u8, 75u8, 97u8, 114u8,
+                    97u8, 110u8, 105u8, 119u8, 97u8, 110u8, 103u8, 32u8, 80u8, 97u8, 103u8, 107u8,
+                    97u8, 107u8, 97u8, 115u8, 117u8, 110u8, 117u8, 100u8, 45u8, 115u8, 117u8,
+                    110u8, 111u8, 100u8, 32u8, 110u8, 103u8, 32u8, 65u8, 121u8, 111u8, 115u8, 80u8,
+                    97u8, 103u8, 107u8, 97u8, 107u8, 97u8, 115u8, 117u8, 110u8, 117u8, 100u8, 45u8,
+                    115u8, 117u8, 110u8, 111u8, 100u8, 32u8, 110u8, 103u8, 32u8, 80u8, 97u8, 103u8,
+                    45u8, 117u8, 117u8, 114u8, 105u8, 32u8, 110u8, 103u8, 32u8, 83u8, 116u8, 114u8,
+                    111u8, 107u8, 101u8, 84u8, 114u8, 97u8, 100u8, 105u8, 115u8, 121u8, 117u8,
+                    110u8, 97u8, 108u8, 32u8, 110u8, 97u8, 32u8, 80u8, 97u8, 103u8, 107u8, 97u8,
+                    107u8, 97u8, 115u8, 117u8, 110u8, 117u8, 100u8, 45u8, 115u8, 117u8, 110u8,
+                    111u8, 100u8, 32u8, 110u8, 103u8, 32u8, 80u8, 97u8, 103u8, 45u8, 117u8, 117u8,
+                    114u8, 105u8, 80u8, 97u8, 103u8, 107u8, 97u8, 107u8, 97u8, 115u8, 117u8, 110u8,
+                    117u8, 100u8, 45u8, 115u8, 117u8, 110u8, 111u8, 100u8, 32u8, 110u8, 103u8,
+                    32u8, 80u8, 97u8, 103u8, 45u8, 117u8, 117u8, 114u8, 105u8, 32u8, 110u8, 103u8,
+                    32u8, 82u8, 97u8, 100u8, 105u8, 99u8, 97u8, 108u8, 45u8, 83u8, 116u8, 114u8,
+                    111u8, 107u8, 101u8, 90u8, 104u8, 117u8, 121u8, 105u8, 110u8, 32u8, 110u8,
+                    97u8, 32u8, 80u8, 97u8, 103u8, 107u8, 97u8, 107u8, 97u8, 115u8, 117u8, 110u8,
+                    117u8, 100u8, 45u8, 115u8, 117u8, 110u8, 111u8, 100u8, 32u8, 110u8, 103u8,
+                    32u8, 80u8, 97u8, 103u8, 45u8, 117u8, 117u8, 114u8, 105u8, 49u8, 50u8, 32u8,
+                    79u8, 114u8, 97u8, 115u8, 32u8, 110u8, 97u8, 32u8, 83u8, 105u8, 115u8, 116u8,
+                    101u8, 109u8, 97u8, 32u8, 40u8, 48u8, 226u8, 128u8, 147u8, 49u8, 49u8, 41u8,
+                    49u8, 50u8, 32u8, 79u8, 114u8, 97u8, 115u8, 32u8, 110u8, 97u8, 32u8, 83u8,
+                    105u8, 115u8, 116u8, 101u8, 109u8, 97u8, 32u8, 40u8, 49u8, 226u8, 128u8, 147u8,
+                    49u8, 50u8, 41u8, 50u8, 52u8, 32u8, 110u8, 97u8, 32u8, 79u8, 114u8, 97u8,
+                    115u8, 32u8, 110u8, 97u8, 32u8, 83u8, 105u8, 115u8, 116u8, 101u8, 109u8, 97u8,
+                    32u8, 40u8, 48u8, 226u8, 128u8, 147u8, 50u8, 51u8, 41u8, 50u8, 52u8, 32u8,
+                    110u8, 97u8, 32u8, 79u8, 114u8, 97u8, 115u8, 32u8, 110u8, 97u8, 32u8, 83u8,
+                    105u8, 115u8, 116u8, 101u8, 109u8, 97u8, 32u8, 40u8, 49u8, 226u8, 128u8, 147u8,
+                    50u8, 52u8, 41u8, 80u8, 97u8, 103u8, 45u8, 117u8, 114u8, 105u8, 45u8, 117u8,
+                    114u8, 105u8, 105u8, 110u8, 32u8, 97u8, 110u8, 103u8, 32u8, 77u8, 103u8, 97u8,
+                    32u8, 83u8, 105u8, 109u8, 98u8, 111u8, 108u8, 111u8, 80u8, 97u8, 103u8, 45u8,
+                    117u8, 114u8, 105u8, 45u8, 117u8, 114u8, 105u8, 105u8, 110u8, 32u8, 97u8,
+                    110u8, 103u8, 32u8, 77u8, 103u8, 97u8, 32u8, 73u8, 103u8, 110u8, 111u8, 114u8,
+                    105u8, 110u8, 103u8, 32u8, 83u8, 121u8, 109u8, 98u8, 111u8, 108u8, 80u8, 97u8,
+                    103u8, 45u8, 117u8, 114u8, 105u8, 45u8, 117u8, 114u8, 105u8, 105u8, 110u8,
+                    32u8, 97u8, 110u8, 103u8, 32u8, 77u8, 103u8, 97u8, 32u8, 65u8, 99u8, 99u8,
+                    101u8, 110u8, 116u8, 32u8, 110u8, 97u8, 110u8, 103u8, 32u8, 78u8, 111u8, 114u8,
+                    109u8, 97u8, 108u8, 80u8, 97u8, 103u8, 45u8, 117u8, 114u8, 105u8, 45u8, 117u8,
+                    114u8, 105u8, 105u8, 110u8, 32u8, 97u8, 110u8, 103u8, 32u8, 77u8, 103u8, 97u8,
+                    32u8, 65u8, 99u8, 99u8, 101u8, 110u8, 116u8, 32u8, 110u8, 97u8, 110u8, 103u8,
+                    32u8, 66u8, 97u8, 108u8, 105u8, 107u8, 116u8, 97u8, 100u8, 80u8, 97u8, 103u8,
+                    45u8, 117u8, 114u8, 105u8, 45u8, 117u8, 114u8, 105u8, 105u8, 110u8, 32u8, 97u8,
+                    110u8, 103u8, 32u8, 72u8, 105u8, 110u8, 100u8, 105u8, 32u8, 67u8, 97u8, 115u8,
+                    101u8, 32u8, 83u8, 101u8, 110u8, 115u8, 105u8, 116u8, 105u8, 118u8, 101u8,
+                    80u8, 97u8, 103u8, 45u8, 117u8, 114u8, 105u8, 45u8, 117u8, 114u8, 105u8, 105u8,
+                    110u8, 32u8, 97u8, 110u8, 103u8, 32u8, 67u8, 97u8, 115u8, 101u8, 32u8, 83u8,
+                    101u8, 110u8, 115u8, 105u8, 116u8, 105u8, 118u8, 101u8, 80u8, 97u8, 103u8,
+                    45u8, 117u8, 114u8, 105u8, 45u8, 117u8, 114u8, 105u8, 105u8, 110u8, 32u8, 97u8,
+                    110u8, 103u8, 32u8, 65u8, 121u8, 111u8, 115u8, 32u8, 110u8, 103u8, 32u8, 78u8,
+                    111u8, 114u8, 109u8, 97u8, 108u8, 32u8, 110u8, 97u8, 32u8, 67u8, 97u8, 115u8,
+                    101u8, 76u8, 111u8, 119u8, 101u8, 114u8, 99u8, 97u8, 115u8, 101u8, 32u8, 77u8,
+                    117u8, 110u8, 97u8, 32u8, 97u8, 110u8, 103u8, 32u8, 80u8, 97u8, 103u8, 45u8,
+                    117u8, 114u8, 105u8, 45u8, 117u8, 114u8, 105u8, 105u8, 110u8, 85u8, 112u8,
+                    112u8, 101u8, 114u8, 99u8, 97u8, 115u8, 101u8, 32u8, 77u8, 117u8, 110u8, 97u8,
+                    32u8, 97u8, 110u8, 103u8, 32u8, 80u8, 97u8, 103u8, 45u8, 117u8, 114u8, 105u8,
+                    45u8, 117u8, 114u8, 105u8, 105u8, 110u8, 80u8, 97u8, 103u8, 45u8, 117u8, 114u8,
+                    105u8, 45u8, 117u8, 114u8, 105u8, 105u8, 110u8, 32u8, 110u8, 97u8, 110u8,
+                    103u8, 32u8, 87u8, 97u8, 108u8, 97u8, 110u8, 103u8, 32u8, 80u8, 97u8, 103u8,
+                    45u8, 110u8, 111u8, 114u8, 109u8, 97u8, 108u8, 105u8, 122u8, 101u8, 80u8, 97u8,
+                    103u8, 45u8, 117u8, 114u8, 105u8, 45u8, 117u8, 114u8, 105u8, 105u8, 110u8,
+                    32u8, 97u8, 110u8, 103u8, 32u8, 85u8, 110u8, 105u8, 99u8, 111u8, 100u8, 101u8,
+                    32u8, 110u8, 97u8, 110u8, 103u8, 32u8, 78u8, 111u8, 114u8, 109u8, 97u8, 108u8,
+                    105u8, 122u8, 101u8, 100u8, 80u8, 97u8, 103u8, 45u8, 117u8, 114u8, 105u8, 45u8,
+                    117u8, 114u8, 105u8, 105u8, 110u8, 32u8, 97u8, 110u8, 103u8, 32u8, 77u8, 103u8,
+                    97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 32u8, 110u8, 97u8, 110u8, 103u8,
+                    32u8, 73u8, 110u8, 100u8, 105u8, 98u8, 105u8, 100u8, 119u8, 97u8, 108u8, 80u8,
+                    97u8, 103u8, 45u8, 117u8, 114u8, 105u8, 45u8, 117u8, 114u8, 105u8, 105u8,
+                    110u8, 32u8, 97u8, 110u8, 103u8, 32u8, 77u8, 103u8, 97u8, 32u8, 68u8, 105u8,
+                    103u8, 105u8, 116u8, 32u8, 110u8, 97u8, 110u8, 103u8, 32u8, 78u8, 117u8, 109u8,
+                    101u8, 114u8, 105u8, 99u8, 97u8, 108u8, 80u8, 97u8, 103u8, 45u8, 117u8, 114u8,
+                    105u8, 45u8, 117u8, 114u8, 105u8, 105u8, 110u8, 32u8, 76u8, 97u8, 104u8, 97u8,
+                    116u8, 77u8, 103u8, 97u8, 32u8, 66u8, 97u8, 115u8, 101u8, 32u8, 110u8, 97u8,
+                    32u8, 84u8, 105u8, 116u8, 105u8, 107u8, 32u8, 76u8, 97u8, 110u8, 103u8, 32u8,
+                    97u8, 110u8, 103u8, 32u8, 80u8, 97u8, 103u8, 45u8, 117u8, 114u8, 105u8, 45u8,
+                    117u8, 114u8, 105u8, 105u8, 110u8, 80u8, 97u8, 103u8, 45u8, 117u8, 114u8,
+                    105u8, 45u8, 117u8, 114u8, 105u8, 105u8, 110u8, 32u8, 97u8, 110u8, 103u8, 32u8,
+                    77u8, 103u8, 97u8, 32u8, 65u8, 99u8, 99u8, 101u8, 110u8, 116u8, 80u8, 97u8,
+                    103u8, 45u8, 117u8, 114u8, 105u8, 45u8, 117u8, 114u8, 105u8, 105u8, 110u8,
+                    32u8, 97u8, 110u8, 103u8, 32u8, 77u8, 103u8, 97u8, 32u8, 65u8, 99u8, 99u8,
+                    101u8, 110u8, 116u8, 47u8, 67u8, 97u8, 115u8, 101u8, 47u8, 76u8, 97u8, 112u8,
+                    97u8, 100u8, 80u8, 97u8, 103u8, 45u8, 117u8, 114u8, 105u8, 45u8, 117u8, 114u8,
+                    105u8, 105u8, 110u8, 32u8, 97u8, 110u8, 103u8, 32u8, 77u8, 103u8, 97u8, 32u8,
+                    65u8, 99u8, 99u8, 101u8, 110u8, 116u8, 47u8, 67u8, 97u8, 115u8, 101u8, 47u8,
+                    76u8, 97u8, 112u8, 97u8, 100u8, 47u8, 75u8, 97u8, 110u8, 97u8, 76u8, 111u8,
+                    111u8, 115u8, 101u8, 32u8, 110u8, 97u8, 32u8, 73u8, 115u8, 116u8, 105u8, 108u8,
+                    111u8, 32u8, 110u8, 103u8, 32u8, 76u8, 105u8, 110u8, 101u8, 32u8, 66u8, 114u8,
+                    101u8, 97u8, 107u8, 78u8, 111u8, 114u8, 109u8, 97u8, 108u8, 32u8, 110u8, 97u8,
+                    32u8, 73u8, 115u8, 116u8, 105u8, 108u8, 111u8, 32u8, 110u8, 103u8, 32u8, 76u8,
+                    105u8, 110u8, 101u8, 32u8, 66u8, 114u8, 101u8, 97u8, 107u8, 77u8, 97u8, 104u8,
+                    105u8, 103u8, 112u8, 105u8, 116u8, 32u8, 110u8, 97u8, 32u8, 73u8, 115u8, 116u8,
+                    105u8, 108u8, 111u8, 32u8, 110u8, 103u8, 32u8, 76u8, 105u8, 110u8, 101u8, 32u8,
+                    66u8, 114u8, 101u8, 97u8, 107u8, 77u8, 101u8, 116u8, 114u8, 105u8, 107u8,
+                    111u8, 110u8, 103u8, 32u8, 83u8, 105u8, 115u8, 116u8, 101u8, 109u8, 97u8, 83u8,
+                    105u8, 115u8, 116u8, 101u8, 109u8, 97u8, 110u8, 103u8, 32u8, 73u8, 109u8,
+                    112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 32u8, 110u8, 97u8, 32u8, 80u8, 97u8,
+                    103u8, 115u8, 117u8, 107u8, 97u8, 116u8, 83u8, 105u8, 115u8, 116u8, 101u8,
+                    109u8, 97u8, 32u8, 110u8, 103u8, 32u8, 80u8, 97u8, 103u8, 115u8, 117u8, 107u8,
+                    97u8, 116u8, 32u8, 115u8, 97u8, 32u8, 85u8, 83u8, 65u8, 104u8, 111u8, 109u8,
+                    32u8, 110u8, 97u8, 32u8, 109u8, 103u8, 97u8, 32u8, 68u8, 105u8, 103u8, 105u8,
+                    116u8, 65u8, 114u8, 97u8, 98u8, 105u8, 99u8, 45u8, 73u8, 110u8, 100u8, 105u8,
+                    99u8, 32u8, 110u8, 97u8, 32u8, 77u8, 103u8, 97u8, 32u8, 68u8, 105u8, 103u8,
+                    105u8, 116u8, 69u8, 120u8, 116u8, 101u8, 110u8, 100u8, 101u8, 110u8, 100u8,
+                    32u8, 65u8, 114u8, 97u8, 98u8, 105u8, 99u8, 45u8, 73u8, 110u8, 100u8, 105u8,
+                    99u8, 32u8, 110u8, 97u8, 32u8, 77u8, 103u8, 97u8, 32u8, 68u8, 105u8, 103u8,
+                    105u8, 116u8, 77u8, 103u8, 97u8, 32u8, 65u8, 114u8, 109u8, 101u8, 110u8, 105u8,
+                    97u8, 110u8, 32u8, 78u8, 117u8, 109u8, 101u8, 114u8, 97u8, 108u8, 65u8, 114u8,
+                    109u8, 101u8, 110u8, 105u8, 97u8, 110u8, 32u8, 76u8, 111u8, 119u8, 101u8,
+                    114u8, 99u8, 97u8, 115u8, 101u8, 32u8, 78u8, 117u8, 109u8, 101u8, 114u8, 97u8,
+                    108u8, 115u8, 66u8, 97u8, 108u8, 105u8, 110u8, 101u8, 115u8, 101u8, 32u8,
+                    110u8, 97u8, 32u8, 77u8, 103u8, 97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8,
+                    77u8, 103u8, 97u8, 32u8, 66u8, 101u8, 110u8, 103u8, 97u8, 108u8, 105u8, 32u8,
+                    68u8, 105u8, 103u8, 105u8, 116u8, 66u8, 114u8, 97u8, 104u8, 109u8, 105u8, 32u8,
+                    110u8, 97u8, 32u8, 77u8, 103u8, 97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8,
+                    67u8, 104u8, 97u8, 107u8, 109u8, 97u8, 32u8, 110u8, 97u8, 32u8, 77u8, 103u8,
+                    97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 67u8, 104u8, 97u8, 109u8, 32u8,
+                    110u8, 97u8, 32u8, 77u8, 103u8, 97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8,
+                    67u8, 121u8, 114u8, 105u8, 108u8, 108u8, 105u8, 99u8, 32u8, 110u8, 97u8, 32u8,
+                    77u8, 103u8, 97u8, 32u8, 78u8, 117u8, 109u8, 101u8, 114u8, 111u8, 77u8, 103u8,
+                    97u8, 32u8, 68u8, 101u8, 118u8, 97u8, 110u8, 97u8, 103u8, 97u8, 114u8, 105u8,
+                    32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 77u8, 103u8, 97u8, 32u8, 69u8, 116u8,
+                    104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 32u8, 78u8, 117u8, 109u8, 101u8,
+                    114u8, 97u8, 108u8, 77u8, 103u8, 97u8, 32u8, 80u8, 97u8, 109u8, 112u8, 105u8,
+                    110u8, 97u8, 110u8, 115u8, 121u8, 97u8, 110u8, 103u8, 32u8, 78u8, 117u8, 109u8,
+                    101u8, 114u8, 97u8, 108u8, 77u8, 103u8, 97u8, 32u8, 70u8, 117u8, 108u8, 108u8,
+                    45u8, 87u8, 105u8, 100u8, 116u8, 104u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8,
+                    71u8, 101u8, 111u8, 114u8, 103u8, 105u8, 97u8, 110u8, 32u8, 110u8, 97u8, 32u8,
+                    77u8, 103u8, 97u8, 32u8, 78u8, 117u8, 109u8, 101u8, 114u8, 97u8, 108u8, 77u8,
+                    97u8, 115u8, 97u8, 114u8, 97u8, 109u8, 32u8, 71u8, 111u8, 110u8, 100u8, 105u8,
+                    32u8, 110u8, 97u8, 32u8, 109u8, 103u8, 97u8, 32u8, 100u8, 105u8, 103u8, 105u8,
+                    116u8, 71u8, 114u8, 101u8, 101u8, 107u8, 32u8, 110u8, 97u8, 32u8, 77u8, 103u8,
+                    97u8, 32u8, 78u8, 117u8, 109u8, 101u8, 114u8, 97u8, 108u8, 71u8, 114u8, 101u8,
+                    101u8, 107u8, 32u8, 76u8, 111u8, 119u8, 101u8, 114u8, 99u8, 97u8, 115u8, 101u8,
+                    32u8, 110u8, 97u8, 32u8, 77u8, 103u8, 97u8, 32u8, 78u8, 117u8, 109u8, 101u8,
+                    114u8, 97u8, 108u8, 77u8, 103u8, 97u8, 32u8, 71u8, 117u8, 106u8, 97u8, 114u8,
+                    97u8, 116u8, 105u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 77u8, 103u8, 97u8,
+                    32u8, 71u8, 117u8, 114u8, 109u8, 117u8, 107u8, 104u8, 105u8, 32u8, 68u8, 105u8,
+                    103u8, 105u8, 116u8, 77u8, 103u8, 97u8, 32u8, 67u8, 104u8, 105u8, 110u8, 101u8,
+                    115u8, 101u8, 32u8, 68u8, 101u8, 99u8, 105u8, 109u8, 97u8, 108u8, 32u8, 110u8,
+                    97u8, 32u8, 78u8, 117u8, 109u8, 101u8, 114u8, 97u8, 108u8, 83u8, 105u8, 109u8,
+                    112u8, 108u8, 105u8, 102u8, 105u8, 101u8, 100u8, 32u8, 67u8, 104u8, 105u8,
+                    110u8, 101u8, 115u8, 101u8, 32u8, 110u8, 97u8, 32u8, 77u8, 103u8, 97u8, 32u8,
+                    78u8, 117u8, 109u8, 101u8, 114u8, 97u8, 108u8, 83u8, 105u8, 109u8, 112u8,
+                    108u8, 105u8, 102u8, 105u8, 101u8, 100u8, 32u8, 67u8, 104u8, 105u8, 110u8,
+                    101u8, 115u8, 101u8, 32u8, 110u8, 97u8, 32u8, 77u8, 103u8, 97u8, 32u8, 78u8,
+                    117u8, 109u8, 101u8, 114u8, 97u8, 108u8, 32u8, 112u8, 97u8, 114u8, 97u8, 32u8,
+                    115u8, 97u8, 32u8, 80u8, 97u8, 110u8, 97u8, 110u8, 97u8, 108u8, 97u8, 112u8,
+                    105u8, 84u8, 114u8, 97u8, 100u8, 105u8, 116u8, 105u8, 111u8, 110u8, 97u8,
+                    108u8, 32u8, 67u8, 104u8, 105u8, 110u8, 101u8, 115u8, 101u8, 32u8, 110u8, 97u8,
+                    32u8, 77u8, 103u8, 97u8, 32u8, 78u8, 117u8, 109u8, 101u8, 114u8, 97u8, 108u8,
+                    84u8, 114u8, 97u8, 100u8, 105u8, 116u8, 105u8, 111u8, 110u8, 97u8, 108u8, 32u8,
+                    67u8, 104u8, 105u8, 110u8, 101u8, 115u8, 101u8, 32u8, 110u8, 97u8, 32u8, 77u8,
+                    103u8, 97u8, 32u8, 78u8, 117u8, 109u8, 101u8, 114u8, 97u8, 108u8, 32u8, 112u8,
+                    97u8, 114u8, 97u8, 32u8, 115u8, 97u8, 32u8, 80u8, 97u8, 110u8, 97u8, 110u8,
+                    97u8, 108u8, 97u8, 112u8, 105u8, 77u8, 103u8, 97u8, 32u8, 72u8, 101u8, 98u8,
+                    114u8, 101u8, 119u8, 32u8, 78u8, 117u8, 109u8, 101u8, 114u8, 97u8, 108u8, 80u8,
+                    97u8, 104u8, 97u8, 119u8, 104u8, 32u8, 72u8, 109u8, 111u8, 110u8, 103u8, 32u8,
+                    110u8, 97u8, 32u8, 77u8, 103u8, 97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8,
+                    74u8, 97u8, 118u8, 97u8, 110u8, 101u8, 115u8, 101u8, 32u8, 110u8, 97u8, 32u8,
+                    77u8, 103u8, 97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 77u8, 103u8, 97u8,
+                    32u8, 74u8, 97u8, 112u8, 97u8, 110u8, 101u8, 115u8, 101u8, 32u8, 78u8, 117u8,
+                    109u8, 101u8, 114u8, 97u8, 108u8, 77u8, 103u8, 97u8, 32u8, 74u8, 97u8, 112u8,
+                    97u8, 110u8, 101u8, 115u8, 101u8, 32u8, 78u8, 117u8, 109u8, 101u8, 114u8, 97u8,
+                    108u8, 32u8, 115u8, 97u8, 32u8, 80u8, 97u8, 110u8, 97u8, 110u8, 97u8, 108u8,
+                    97u8, 112u8, 105u8, 75u8, 97u8, 121u8, 97u8, 104u8, 32u8, 76u8, 105u8, 32u8,
+                    110u8, 97u8, 32u8, 77u8, 103u8, 97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8,
+                    77u8, 103u8, 97u8, 32u8, 75u8, 104u8, 109u8, 101u8, 114u8, 32u8, 110u8, 97u8,
+                    32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 77u8, 103u8, 97u8, 32u8, 75u8, 97u8,
+                    110u8, 110u8, 97u8, 100u8, 97u8, 32u8, 110u8, 97u8, 32u8, 68u8, 105u8, 103u8,
+                    105u8, 116u8, 84u8, 97u8, 105u8, 32u8, 84u8, 104u8, 97u8, 109u8, 32u8, 72u8,
+                    111u8, 114u8, 97u8, 32u8, 110u8, 97u8, 32u8, 77u8, 103u8, 97u8, 32u8, 68u8,
+                    105u8, 103u8, 105u8, 116u8, 84u8, 97u8, 105u8, 32u8, 84u8, 104u8, 97u8, 109u8,
+                    32u8, 84u8, 104u8, 97u8, 109u8, 32u8, 110u8, 97u8, 32u8, 77u8, 103u8, 97u8,
+                    32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 77u8, 103u8, 97u8, 32u8, 76u8, 97u8,
+                    111u8, 32u8, 110u8, 97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 77u8, 103u8,
+                    97u8, 32u8, 75u8, 97u8, 110u8, 108u8, 117u8, 114u8, 97u8, 110u8, 105u8, 110u8,
+                    103u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 76u8, 101u8, 112u8, 99u8, 104u8,
+                    97u8, 32u8, 77u8, 103u8, 97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 76u8,
+                    105u8, 109u8, 98u8, 117u8, 32u8, 110u8, 97u8, 32u8, 77u8, 103u8, 97u8, 32u8,
+                    68u8, 105u8, 103u8, 105u8, 116u8, 77u8, 97u8, 116u8, 101u8, 109u8, 97u8, 116u8,
+                    105u8, 107u8, 97u8, 108u8, 32u8, 110u8, 97u8, 32u8, 66u8, 111u8, 108u8, 100u8,
+                    32u8, 110u8, 97u8, 32u8, 77u8, 103u8, 97u8, 32u8, 68u8, 105u8, 103u8, 105u8,
+                    116u8, 77u8, 97u8, 116u8, 101u8, 109u8, 97u8, 116u8, 105u8, 107u8, 97u8, 108u8,
+                    32u8, 110u8, 97u8, 32u8, 68u8, 111u8, 98u8, 108u8, 101u8, 110u8, 103u8, 45u8,
+                    83u8, 116u8, 114u8, 117u8, 99u8, 107u8, 32u8, 110u8, 97u8, 32u8, 77u8, 103u8,
+                    97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 77u8, 97u8, 116u8, 101u8, 109u8,
+                    97u8, 116u8, 105u8, 107u8, 97u8, 108u8, 32u8, 110u8, 97u8, 32u8, 77u8, 103u8,
+                    97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 32u8, 110u8, 97u8, 32u8, 77u8,
+                    97u8, 121u8, 32u8, 73u8, 115u8, 97u8, 110u8, 103u8, 32u8, 80u8, 117u8, 119u8,
+                    97u8, 110u8, 103u8, 77u8, 97u8, 116u8, 101u8, 109u8, 97u8, 116u8, 105u8, 107u8,
+                    97u8, 108u8, 32u8, 110u8, 97u8, 32u8, 83u8, 97u8, 110u8, 115u8, 45u8, 83u8,
+                    101u8, 114u8, 105u8, 102u8, 32u8, 66u8, 111u8, 108u8, 100u8, 32u8, 110u8, 97u8,
+                    32u8, 77u8, 103u8, 97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 77u8, 97u8,
+                    116u8, 101u8, 109u8, 97u8, 116u8, 105u8, 107u8, 97u8, 108u8, 32u8, 110u8, 97u8,
+                    32u8, 83u8, 97u8, 110u8, 115u8, 45u8, 83u8, 101u8, 114u8, 105u8, 102u8, 32u8,
+                    110u8, 97u8, 32u8, 77u8, 103u8, 97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8,
+                    77u8, 103u8, 97u8, 32u8, 77u8, 97u8, 108u8, 97u8, 121u8, 97u8, 108u8, 97u8,
+                    109u8, 32u8, 110u8, 97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 77u8, 111u8,
+                    100u8, 105u8, 32u8, 110u8, 97u8, 32u8, 77u8, 103u8, 97u8, 32u8, 68u8, 105u8,
+                    103u8, 105u8, 116u8, 77u8, 111u8, 110u8, 103u8, 111u8, 108u8, 105u8, 97u8,
+                    110u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 115u8, 77u8, 114u8, 111u8, 32u8,
+                    110u8, 97u8, 32u8, 77u8, 103u8, 97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8,
+                    77u8, 101u8, 101u8, 116u8, 101u8, 105u8, 32u8, 77u8, 97u8, 121u8, 101u8, 107u8,
+                    32u8, 110u8, 97u8, 32u8, 77u8, 103u8, 97u8, 32u8, 68u8, 105u8, 103u8, 105u8,
+                    116u8, 77u8, 103u8, 97u8, 32u8, 77u8, 121u8, 97u8, 110u8, 109u8, 97u8, 114u8,
+                    32u8, 110u8, 97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 77u8, 97u8, 121u8,
+                    110u8, 109u8, 97u8, 114u8, 32u8, 83u8, 104u8, 97u8, 110u8, 32u8, 110u8, 97u8,
+                    32u8, 77u8, 103u8, 97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 77u8, 121u8,
+                    97u8, 110u8, 109u8, 97u8, 114u8, 32u8, 84u8, 97u8, 105u8, 32u8, 76u8, 97u8,
+                    105u8, 110u8, 103u8, 32u8, 110u8, 97u8, 32u8, 77u8, 103u8, 97u8, 32u8, 68u8,
+                    105u8, 103u8, 105u8, 116u8, 77u8, 103u8, 97u8, 32u8, 78u8, 97u8, 116u8, 105u8,
+                    118u8, 101u8, 32u8, 110u8, 97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 78u8,
+                    226u8, 128u8, 153u8, 75u8, 111u8, 32u8, 110u8, 97u8, 32u8, 77u8, 103u8, 97u8,
+                    32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 77u8, 103u8, 97u8, 32u8, 68u8, 105u8,
+                    103u8, 105u8, 116u8, 32u8, 110u8, 103u8, 32u8, 79u8, 108u8, 32u8, 67u8, 104u8,
+                    105u8, 107u8, 105u8, 77u8, 103u8, 97u8, 32u8, 79u8, 114u8, 105u8, 121u8, 97u8,
+                    32u8, 110u8, 97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 77u8, 103u8, 97u8,
+                    32u8, 82u8, 111u8, 109u8, 97u8, 110u8, 32u8, 78u8, 117u8, 109u8, 101u8, 114u8,
+                    97u8, 108u8, 82u8, 111u8, 109u8, 97u8, 110u8, 32u8, 76u8, 111u8, 119u8, 101u8,
+                    114u8, 99u8, 97u8, 115u8, 101u8, 32u8, 110u8, 97u8, 32u8, 77u8, 103u8, 97u8,
+                    32u8, 78u8, 117u8, 109u8, 101u8, 114u8, 97u8, 108u8, 84u8, 97u8, 109u8, 105u8,
+                    108u8, 32u8, 110u8, 97u8, 32u8, 77u8, 103u8, 97u8, 32u8, 78u8, 117u8, 109u8,
+                    101u8, 114u8, 97u8, 108u8, 77u8, 103u8, 97u8, 32u8, 84u8, 97u8, 109u8, 105u8,
+                    108u8, 32u8, 110u8, 97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 77u8, 103u8,
+                    97u8, 32u8, 84u8, 101u8, 108u8, 117u8, 103u8, 117u8, 32u8, 110u8, 97u8, 32u8,
+                    68u8, 105u8, 103u8, 105u8, 116u8, 77u8, 103u8, 97u8, 32u8, 84u8, 104u8, 97u8,
+                    105u8, 32u8, 110u8, 97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 77u8, 103u8,
+                    97u8, 32u8, 84u8, 105u8, 98u8, 101u8, 116u8, 97u8, 110u8, 32u8, 110u8, 97u8,
+                    32u8, 68u8, 105u8, 103u8, 105u8, 116u8, 77u8, 103u8, 97u8, 32u8, 84u8, 114u8,
+                    97u8, 100u8, 105u8, 115u8, 121u8, 117u8, 110u8, 97u8, 108u8, 32u8, 110u8, 97u8,
+                    32u8, 78u8, 117u8, 109u8, 101u8, 114u8, 97u8, 108u8, 77u8, 103u8, 97u8, 32u8,
+                    86u8, 97u8, 105u8, 32u8, 110u8, 97u8, 32u8, 68u8, 105u8, 103u8, 105u8, 116u8,
+                ])
+            },
+        )
+    },
+};
+static FR: &DataStruct = &::icu_displaynames::provider::KeyDisplayNamesV1 {
+    keys: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    99u8, 97u8, 99u8, 102u8, 99u8, 111u8, 99u8, 117u8, 104u8, 99u8, 107u8, 97u8,
+                    107u8, 98u8, 107u8, 99u8, 107u8, 102u8, 107u8, 107u8, 107u8, 110u8, 107u8,
+                    115u8, 108u8, 98u8, 109u8, 115u8, 110u8, 117u8, 116u8, 122u8, 118u8, 97u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    17u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 26u8, 0u8, 38u8, 0u8, 44u8, 0u8,
+                    78u8, 0u8, 115u8, 0u8, 154u8, 0u8, 178u8, 0u8, 231u8, 0u8, 245u8, 0u8, 3u8,
+                    1u8, 19u8, 1u8, 41u8, 1u8, 59u8, 1u8, 66u8, 1u8, 80u8, 1u8, 99u8, 97u8, 108u8,
+                    101u8, 110u8, 100u8, 114u8, 105u8, 101u8, 114u8, 102u8, 111u8, 114u8, 109u8,
+                    97u8, 116u8, 32u8, 100u8, 101u8, 32u8, 100u8, 101u8, 118u8, 105u8, 115u8,
+                    101u8, 111u8, 114u8, 100u8, 114u8, 101u8, 32u8, 100u8, 101u8, 32u8, 116u8,
+                    114u8, 105u8, 100u8, 101u8, 118u8, 105u8, 115u8, 101u8, 115u8, 121u8, 115u8,
+                    116u8, 195u8, 168u8, 109u8, 101u8, 32u8, 104u8, 111u8, 114u8, 97u8, 105u8,
+                    114u8, 101u8, 32u8, 40u8, 49u8, 50u8, 32u8, 111u8, 117u8, 32u8, 50u8, 52u8,
+                    32u8, 104u8, 101u8, 117u8, 114u8, 101u8, 115u8, 41u8, 116u8, 114u8, 105u8,
+                    32u8, 110u8, 101u8, 32u8, 116u8, 101u8, 110u8, 97u8, 110u8, 116u8, 32u8, 112u8,
+                    97u8, 115u8, 32u8, 99u8, 111u8, 109u8, 112u8, 116u8, 101u8, 32u8, 100u8, 101u8,
+                    115u8, 32u8, 115u8, 121u8, 109u8, 98u8, 111u8, 108u8, 101u8, 115u8, 116u8,
+                    114u8, 105u8, 32u8, 105u8, 110u8, 118u8, 101u8, 114u8, 115u8, 195u8, 169u8,
+                    32u8, 100u8, 101u8, 115u8, 32u8, 99u8, 97u8, 114u8, 97u8, 99u8, 116u8, 195u8,
+                    168u8, 114u8, 101u8, 115u8, 32u8, 97u8, 99u8, 99u8, 101u8, 110u8, 116u8, 117u8,
+                    195u8, 169u8, 115u8, 116u8, 114u8, 105u8, 32u8, 115u8, 101u8, 110u8, 115u8,
+                    105u8, 98u8, 108u8, 101u8, 32u8, 195u8, 160u8, 32u8, 108u8, 97u8, 32u8, 99u8,
+                    97u8, 115u8, 115u8, 101u8, 99u8, 108u8, 97u8, 115u8, 115u8, 101u8, 109u8,
+                    101u8, 110u8, 116u8, 32u8, 98u8, 97u8, 115u8, 195u8, 169u8, 32u8, 115u8, 117u8,
+                    114u8, 32u8, 108u8, 101u8, 115u8, 32u8, 109u8, 97u8, 106u8, 117u8, 115u8, 99u8,
+                    117u8, 108u8, 101u8, 115u8, 32u8, 101u8, 116u8, 32u8, 108u8, 101u8, 115u8,
+                    32u8, 109u8, 105u8, 110u8, 117u8, 115u8, 99u8, 117u8, 108u8, 101u8, 115u8,
+                    116u8, 114u8, 105u8, 32u8, 110u8, 111u8, 114u8, 109u8, 97u8, 108u8, 105u8,
+                    115u8, 195u8, 169u8, 116u8, 114u8, 105u8, 32u8, 110u8, 117u8, 109u8, 195u8,
+                    169u8, 114u8, 105u8, 113u8, 117u8, 101u8, 112u8, 114u8, 105u8, 111u8, 114u8,
+                    105u8, 116u8, 195u8, 169u8, 32u8, 100u8, 117u8, 32u8, 116u8, 114u8, 105u8,
+                    115u8, 116u8, 121u8, 108u8, 101u8, 32u8, 100u8, 101u8, 32u8, 115u8, 97u8,
+                    117u8, 116u8, 32u8, 100u8, 101u8, 32u8, 108u8, 105u8, 103u8, 110u8, 101u8,
+                    115u8, 121u8, 115u8, 116u8, 195u8, 168u8, 109u8, 101u8, 32u8, 100u8, 101u8,
+                    32u8, 109u8, 101u8, 115u8, 117u8, 114u8, 101u8, 110u8, 111u8, 109u8, 98u8,
+                    114u8, 101u8, 115u8, 102u8, 117u8, 115u8, 101u8, 97u8, 117u8, 32u8, 104u8,
+                    111u8, 114u8, 97u8, 105u8, 114u8, 101u8, 118u8, 97u8, 114u8, 105u8, 97u8,
+                    110u8, 116u8, 101u8, 32u8, 108u8, 111u8, 99u8, 97u8, 108u8, 101u8,
+                ])
+            },
+        )
+    },
+    types: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    110u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 21u8, 0u8, 30u8, 0u8, 38u8, 0u8,
+                    48u8, 0u8, 59u8, 0u8, 69u8, 0u8, 78u8, 0u8, 87u8, 0u8, 97u8, 0u8, 113u8, 0u8,
+                    128u8, 0u8, 147u8, 0u8, 157u8, 0u8, 168u8, 0u8, 178u8, 0u8, 184u8, 0u8, 194u8,
+                    0u8, 205u8, 0u8, 215u8, 0u8, 224u8, 0u8, 231u8, 0u8, 239u8, 0u8, 245u8, 0u8,
+                    254u8, 0u8, 8u8, 1u8, 19u8, 1u8, 28u8, 1u8, 39u8, 1u8, 48u8, 1u8, 59u8, 1u8,
+                    70u8, 1u8, 79u8, 1u8, 86u8, 1u8, 95u8, 1u8, 104u8, 1u8, 110u8, 1u8, 116u8, 1u8,
+                    122u8, 1u8, 128u8, 1u8, 139u8, 1u8, 149u8, 1u8, 157u8, 1u8, 164u8, 1u8, 172u8,
+                    1u8, 179u8, 1u8, 187u8, 1u8, 195u8, 1u8, 203u8, 1u8, 211u8, 1u8, 218u8, 1u8,
+                    226u8, 1u8, 233u8, 1u8, 243u8, 1u8, 252u8, 1u8, 5u8, 2u8, 14u8, 2u8, 23u8, 2u8,
+                    31u8, 2u8, 40u8, 2u8, 49u8, 2u8, 58u8, 2u8, 69u8, 2u8, 80u8, 2u8, 87u8, 2u8,
+                    97u8, 2u8, 104u8, 2u8, 114u8, 2u8, 121u8, 2u8, 128u8, 2u8, 135u8, 2u8, 142u8,
+                    2u8, 152u8, 2u8, 163u8, 2u8, 170u8, 2u8, 177u8, 2u8, 187u8, 2u8, 194u8, 2u8,
+                    201u8, 2u8, 211u8, 2u8, 218u8, 2u8, 228u8, 2u8, 235u8, 2u8, 245u8, 2u8, 252u8,
+                    2u8, 3u8, 3u8, 10u8, 3u8, 17u8, 3u8, 27u8, 3u8, 34u8, 3u8, 41u8, 3u8, 48u8,
+                    3u8, 55u8, 3u8, 62u8, 3u8, 69u8, 3u8, 76u8, 3u8, 83u8, 3u8, 92u8, 3u8, 99u8,
+                    3u8, 106u8, 3u8, 114u8, 3u8, 125u8, 3u8, 132u8, 3u8, 142u8, 3u8, 149u8, 3u8,
+                    156u8, 3u8, 163u8, 3u8, 174u8, 3u8, 181u8, 3u8, 99u8, 97u8, 45u8, 98u8, 117u8,
+                    100u8, 100u8, 104u8, 105u8, 115u8, 116u8, 99u8, 97u8, 45u8, 99u8, 104u8, 105u8,
+                    110u8, 101u8, 115u8, 101u8, 99u8, 97u8, 45u8, 99u8, 111u8, 112u8, 116u8, 105u8,
+                    99u8, 99u8, 97u8, 45u8, 100u8, 97u8, 110u8, 103u8, 105u8, 99u8, 97u8, 45u8,
+                    101u8, 116u8, 104u8, 105u8, 111u8, 97u8, 97u8, 99u8, 97u8, 45u8, 101u8, 116u8,
+                    104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 99u8, 97u8, 45u8, 103u8, 114u8, 101u8,
+                    103u8, 111u8, 114u8, 121u8, 99u8, 97u8, 45u8, 104u8, 101u8, 98u8, 114u8, 101u8,
+                    119u8, 99u8, 97u8, 45u8, 105u8, 110u8, 100u8, 105u8, 97u8, 110u8, 99u8, 97u8,
+                    45u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 99u8, 97u8, 45u8, 105u8,
+                    115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 99u8, 105u8, 118u8, 105u8, 108u8,
+                    99u8, 97u8, 45u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 116u8,
+                    98u8, 108u8, 97u8, 99u8, 97u8, 45u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8,
+                    99u8, 45u8, 117u8, 109u8, 97u8, 108u8, 113u8, 117u8, 114u8, 97u8, 99u8, 97u8,
+                    45u8, 105u8, 115u8, 111u8, 56u8, 54u8, 48u8, 49u8, 99u8, 97u8, 45u8, 106u8,
+                    97u8, 112u8, 97u8, 110u8, 101u8, 115u8, 101u8, 99u8, 97u8, 45u8, 112u8, 101u8,
+                    114u8, 115u8, 105u8, 97u8, 110u8, 99u8, 97u8, 45u8, 114u8, 111u8, 99u8, 99u8,
+                    102u8, 45u8, 97u8, 99u8, 99u8, 111u8, 117u8, 110u8, 116u8, 99u8, 102u8, 45u8,
+                    115u8, 116u8, 97u8, 110u8, 100u8, 97u8, 114u8, 100u8, 99u8, 111u8, 45u8, 98u8,
+                    105u8, 103u8, 53u8, 104u8, 97u8, 110u8, 99u8, 111u8, 45u8, 99u8, 111u8, 109u8,
+                    112u8, 97u8, 116u8, 99u8, 111u8, 45u8, 100u8, 105u8, 99u8, 116u8, 99u8, 111u8,
+                    45u8, 100u8, 117u8, 99u8, 101u8, 116u8, 99u8, 111u8, 45u8, 101u8, 111u8, 114u8,
+                    99u8, 111u8, 45u8, 103u8, 98u8, 50u8, 51u8, 49u8, 50u8, 99u8, 111u8, 45u8,
+                    112u8, 104u8, 111u8, 110u8, 101u8, 98u8, 107u8, 99u8, 111u8, 45u8, 112u8,
+                    104u8, 111u8, 110u8, 101u8, 116u8, 105u8, 99u8, 99u8, 111u8, 45u8, 112u8,
+                    105u8, 110u8, 121u8, 105u8, 110u8, 99u8, 111u8, 45u8, 114u8, 101u8, 102u8,
+                    111u8, 114u8, 109u8, 101u8, 100u8, 99u8, 111u8, 45u8, 115u8, 101u8, 97u8,
+                    114u8, 99u8, 104u8, 99u8, 111u8, 45u8, 115u8, 101u8, 97u8, 114u8, 99u8, 104u8,
+                    106u8, 108u8, 99u8, 111u8, 45u8, 115u8, 116u8, 97u8, 110u8, 100u8, 97u8, 114u8,
+                    100u8, 99u8, 111u8, 45u8, 115u8, 116u8, 114u8, 111u8, 107u8, 101u8, 99u8,
+                    111u8, 45u8, 116u8, 114u8, 97u8, 100u8, 99u8, 111u8, 45u8, 117u8, 110u8, 105u8,
+                    104u8, 97u8, 110u8, 99u8, 111u8, 45u8, 122u8, 104u8, 117u8, 121u8, 105u8,
+                    110u8, 104u8, 99u8, 45u8, 104u8, 49u8, 49u8, 104u8, 99u8, 45u8, 104u8, 49u8,
+                    50u8, 104u8, 99u8, 45u8, 104u8, 50u8, 51u8, 104u8, 99u8, 45u8, 104u8, 50u8,
+                    52u8, 107u8, 97u8, 45u8, 110u8, 111u8, 105u8, 103u8, 110u8, 111u8, 114u8,
+                    101u8, 107u8, 97u8, 45u8, 115u8, 104u8, 105u8, 102u8, 116u8, 101u8, 100u8,
+                    107u8, 98u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8, 107u8, 98u8, 45u8, 116u8,
+                    114u8, 117u8, 101u8, 107u8, 99u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8,
+                    107u8, 99u8, 45u8, 116u8, 114u8, 117u8, 101u8, 107u8, 102u8, 45u8, 102u8, 97u8,
+                    108u8, 115u8, 101u8, 107u8, 102u8, 45u8, 108u8, 111u8, 119u8, 101u8, 114u8,
+                    107u8, 102u8, 45u8, 117u8, 112u8, 112u8, 101u8, 114u8, 107u8, 107u8, 45u8,
+                    102u8, 97u8, 108u8, 115u8, 101u8, 107u8, 107u8, 45u8, 116u8, 114u8, 117u8,
+                    101u8, 107u8, 110u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8, 107u8, 110u8,
+                    45u8, 116u8, 114u8, 117u8, 101u8, 107u8, 115u8, 45u8, 105u8, 100u8, 101u8,
+                    110u8, 116u8, 105u8, 99u8, 107u8, 115u8, 45u8, 108u8, 101u8, 118u8, 101u8,
+                    108u8, 49u8, 107u8, 115u8, 45u8, 108u8, 101u8, 118u8, 101u8, 108u8, 50u8,
+                    107u8, 115u8, 45u8, 108u8, 101u8, 118u8, 101u8, 108u8, 51u8, 107u8, 115u8,
+                    45u8, 108u8, 101u8, 118u8, 101u8, 108u8, 52u8, 108u8, 98u8, 45u8, 108u8, 111u8,
+                    111u8, 115u8, 101u8, 108u8, 98u8, 45u8, 110u8, 111u8, 114u8, 109u8, 97u8,
+                    108u8, 108u8, 98u8, 45u8, 115u8, 116u8, 114u8, 105u8, 99u8, 116u8, 109u8,
+                    115u8, 45u8, 109u8, 101u8, 116u8, 114u8, 105u8, 99u8, 109u8, 115u8, 45u8,
+                    117u8, 107u8, 115u8, 121u8, 115u8, 116u8, 101u8, 109u8, 109u8, 115u8, 45u8,
+                    117u8, 115u8, 115u8, 121u8, 115u8, 116u8, 101u8, 109u8, 110u8, 117u8, 45u8,
+                    97u8, 114u8, 97u8, 98u8, 110u8, 117u8, 45u8, 97u8, 114u8, 97u8, 98u8, 101u8,
+                    120u8, 116u8, 110u8, 117u8, 45u8, 97u8, 114u8, 109u8, 110u8, 110u8, 117u8,
+                    45u8, 97u8, 114u8, 109u8, 110u8, 108u8, 111u8, 119u8, 110u8, 117u8, 45u8, 98u8,
+                    101u8, 110u8, 103u8, 110u8, 117u8, 45u8, 99u8, 97u8, 107u8, 109u8, 110u8,
+                    117u8, 45u8, 100u8, 101u8, 118u8, 97u8, 110u8, 117u8, 45u8, 101u8, 116u8,
+                    104u8, 105u8, 110u8, 117u8, 45u8, 102u8, 105u8, 110u8, 97u8, 110u8, 99u8,
+                    101u8, 110u8, 117u8, 45u8, 102u8, 117u8, 108u8, 108u8, 119u8, 105u8, 100u8,
+                    101u8, 110u8, 117u8, 45u8, 103u8, 101u8, 111u8, 114u8, 110u8, 117u8, 45u8,
+                    103u8, 114u8, 101u8, 107u8, 110u8, 117u8, 45u8, 103u8, 114u8, 101u8, 107u8,
+                    108u8, 111u8, 119u8, 110u8, 117u8, 45u8, 103u8, 117u8, 106u8, 114u8, 110u8,
+                    117u8, 45u8, 103u8, 117u8, 114u8, 117u8, 110u8, 117u8, 45u8, 104u8, 97u8,
+                    110u8, 105u8, 100u8, 101u8, 99u8, 110u8, 117u8, 45u8, 104u8, 97u8, 110u8,
+                    115u8, 110u8, 117u8, 45u8, 104u8, 97u8, 110u8, 115u8, 102u8, 105u8, 110u8,
+                    110u8, 117u8, 45u8, 104u8, 97u8, 110u8, 116u8, 110u8, 117u8, 45u8, 104u8, 97u8,
+                    110u8, 116u8, 102u8, 105u8, 110u8, 110u8, 117u8, 45u8, 104u8, 101u8, 98u8,
+                    114u8, 110u8, 117u8, 45u8, 104u8, 109u8, 110u8, 112u8, 110u8, 117u8, 45u8,
+                    106u8, 97u8, 118u8, 97u8, 110u8, 117u8, 45u8, 106u8, 112u8, 97u8, 110u8, 110u8,
+                    117u8, 45u8, 106u8, 112u8, 97u8, 110u8, 102u8, 105u8, 110u8, 110u8, 117u8,
+                    45u8, 107u8, 104u8, 109u8, 114u8, 110u8, 117u8, 45u8, 107u8, 110u8, 100u8,
+                    97u8, 110u8, 117u8, 45u8, 108u8, 97u8, 111u8, 111u8, 110u8, 117u8, 45u8, 108u8,
+                    97u8, 116u8, 110u8, 110u8, 117u8, 45u8, 109u8, 108u8, 121u8, 109u8, 110u8,
+                    117u8, 45u8, 109u8, 111u8, 110u8, 103u8, 110u8, 117u8, 45u8, 109u8, 116u8,
+                    101u8, 105u8, 110u8, 117u8, 45u8, 109u8, 121u8, 109u8, 114u8, 110u8, 117u8,
+                    45u8, 110u8, 97u8, 116u8, 105u8, 118u8, 101u8, 110u8, 117u8, 45u8, 111u8,
+                    108u8, 99u8, 107u8, 110u8, 117u8, 45u8, 111u8, 114u8, 121u8, 97u8, 110u8,
+                    117u8, 45u8, 114u8, 111u8, 109u8, 97u8, 110u8, 110u8, 117u8, 45u8, 114u8,
+                    111u8, 109u8, 97u8, 110u8, 108u8, 111u8, 119u8, 110u8, 117u8, 45u8, 116u8,
+                    97u8, 109u8, 108u8, 110u8, 117u8, 45u8, 116u8, 97u8, 109u8, 108u8, 100u8,
+                    101u8, 99u8, 110u8, 117u8, 45u8, 116u8, 101u8, 108u8, 117u8, 110u8, 117u8,
+                    45u8, 116u8, 104u8, 97u8, 105u8, 110u8, 117u8, 45u8, 116u8, 105u8, 98u8, 116u8,
+                    110u8, 117u8, 45u8, 116u8, 114u8, 97u8, 100u8, 105u8, 116u8, 105u8, 111u8,
+                    110u8, 117u8, 45u8, 118u8, 97u8, 105u8, 105u8, 110u8, 117u8, 45u8, 119u8, 99u8,
+                    104u8, 111u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    110u8, 0u8, 0u8, 0u8, 0u8, 0u8, 21u8, 0u8, 39u8, 0u8, 55u8, 0u8, 71u8, 0u8,
+                    103u8, 0u8, 124u8, 0u8, 145u8, 0u8, 167u8, 0u8, 184u8, 0u8, 203u8, 0u8, 250u8,
+                    0u8, 47u8, 1u8, 80u8, 1u8, 99u8, 1u8, 118u8, 1u8, 135u8, 1u8, 166u8, 1u8,
+                    192u8, 1u8, 217u8, 1u8, 250u8, 1u8, 33u8, 2u8, 54u8, 2u8, 86u8, 2u8, 117u8,
+                    2u8, 150u8, 2u8, 171u8, 2u8, 195u8, 2u8, 207u8, 2u8, 222u8, 2u8, 243u8, 2u8,
+                    30u8, 3u8, 51u8, 3u8, 67u8, 3u8, 85u8, 3u8, 112u8, 3u8, 124u8, 3u8, 162u8, 3u8,
+                    200u8, 3u8, 238u8, 3u8, 20u8, 4u8, 38u8, 4u8, 68u8, 4u8, 112u8, 4u8, 167u8,
+                    4u8, 202u8, 4u8, 236u8, 4u8, 13u8, 5u8, 48u8, 5u8, 83u8, 5u8, 107u8, 5u8,
+                    139u8, 5u8, 174u8, 5u8, 213u8, 5u8, 223u8, 5u8, 255u8, 5u8, 48u8, 6u8, 127u8,
+                    6u8, 228u8, 6u8, 4u8, 7u8, 33u8, 7u8, 62u8, 7u8, 80u8, 7u8, 98u8, 7u8, 117u8,
+                    7u8, 132u8, 7u8, 156u8, 7u8, 175u8, 7u8, 205u8, 7u8, 222u8, 7u8, 238u8, 7u8,
+                    3u8, 8u8, 23u8, 8u8, 42u8, 8u8, 64u8, 8u8, 83u8, 8u8, 97u8, 8u8, 122u8, 8u8,
+                    144u8, 8u8, 165u8, 8u8, 190u8, 8u8, 220u8, 8u8, 5u8, 9u8, 37u8, 9u8, 80u8, 9u8,
+                    97u8, 9u8, 128u8, 9u8, 145u8, 9u8, 162u8, 9u8, 190u8, 9u8, 205u8, 9u8, 224u8,
+                    9u8, 241u8, 9u8, 5u8, 10u8, 25u8, 10u8, 41u8, 10u8, 63u8, 10u8, 79u8, 10u8,
+                    94u8, 10u8, 112u8, 10u8, 127u8, 10u8, 143u8, 10u8, 170u8, 10u8, 200u8, 10u8,
+                    216u8, 10u8, 235u8, 10u8, 250u8, 10u8, 13u8, 11u8, 35u8, 11u8, 51u8, 11u8,
+                    99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 114u8, 105u8, 101u8, 114u8, 32u8, 98u8,
+                    111u8, 117u8, 100u8, 100u8, 104u8, 105u8, 115u8, 116u8, 101u8, 99u8, 97u8,
+                    108u8, 101u8, 110u8, 100u8, 114u8, 105u8, 101u8, 114u8, 32u8, 99u8, 104u8,
+                    105u8, 110u8, 111u8, 105u8, 115u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8,
+                    114u8, 105u8, 101u8, 114u8, 32u8, 99u8, 111u8, 112u8, 116u8, 101u8, 99u8, 97u8,
+                    108u8, 101u8, 110u8, 100u8, 114u8, 105u8, 101u8, 114u8, 32u8, 100u8, 97u8,
+                    110u8, 103u8, 105u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 114u8, 105u8,
+                    101u8, 114u8, 32u8, 195u8, 169u8, 116u8, 104u8, 105u8, 111u8, 112u8, 105u8,
+                    101u8, 110u8, 32u8, 65u8, 109u8, 101u8, 116u8, 101u8, 32u8, 65u8, 108u8, 101u8,
+                    109u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 114u8, 105u8, 101u8, 114u8,
+                    32u8, 195u8, 169u8, 116u8, 104u8, 105u8, 111u8, 112u8, 105u8, 101u8, 110u8,
+                    99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 114u8, 105u8, 101u8, 114u8, 32u8,
+                    103u8, 114u8, 195u8, 169u8, 103u8, 111u8, 114u8, 105u8, 101u8, 110u8, 99u8,
+                    97u8, 108u8, 101u8, 110u8, 100u8, 114u8, 105u8, 101u8, 114u8, 32u8, 104u8,
+                    195u8, 169u8, 98u8, 114u8, 97u8, 195u8, 175u8, 113u8, 117u8, 101u8, 99u8, 97u8,
+                    108u8, 101u8, 110u8, 100u8, 114u8, 105u8, 101u8, 114u8, 32u8, 105u8, 110u8,
+                    100u8, 105u8, 101u8, 110u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 114u8,
+                    105u8, 101u8, 114u8, 32u8, 109u8, 117u8, 115u8, 117u8, 108u8, 109u8, 97u8,
+                    110u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 114u8, 105u8, 101u8, 114u8,
+                    32u8, 109u8, 117u8, 115u8, 117u8, 108u8, 109u8, 97u8, 110u8, 32u8, 40u8, 116u8,
+                    97u8, 98u8, 117u8, 108u8, 97u8, 105u8, 114u8, 101u8, 44u8, 32u8, 195u8, 169u8,
+                    112u8, 111u8, 113u8, 117u8, 101u8, 32u8, 99u8, 105u8, 118u8, 105u8, 108u8,
+                    101u8, 41u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 114u8, 105u8, 101u8,
+                    114u8, 32u8, 109u8, 117u8, 115u8, 117u8, 108u8, 109u8, 97u8, 110u8, 32u8, 40u8,
+                    116u8, 97u8, 98u8, 117u8, 108u8, 97u8, 105u8, 114u8, 101u8, 44u8, 32u8, 195u8,
+                    169u8, 112u8, 111u8, 113u8, 117u8, 101u8, 32u8, 97u8, 115u8, 116u8, 114u8,
+                    111u8, 110u8, 111u8, 109u8, 105u8, 113u8, 117u8, 101u8, 41u8, 99u8, 97u8,
+                    108u8, 101u8, 110u8, 100u8, 114u8, 105u8, 101u8, 114u8, 32u8, 109u8, 117u8,
+                    115u8, 117u8, 108u8, 109u8, 97u8, 110u8, 32u8, 40u8, 85u8, 109u8, 109u8, 32u8,
+                    97u8, 108u8, 32u8, 81u8, 117u8, 114u8, 97u8, 41u8, 99u8, 97u8, 108u8, 101u8,
+                    110u8, 100u8, 114u8, 105u8, 101u8, 114u8, 32u8, 73u8, 83u8, 79u8, 32u8, 56u8,
+                    54u8, 48u8, 49u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 114u8, 105u8, 101u8,
+                    114u8, 32u8, 106u8, 97u8, 112u8, 111u8, 110u8, 97u8, 105u8, 115u8, 99u8, 97u8,
+                    108u8, 101u8, 110u8, 100u8, 114u8, 105u8, 101u8, 114u8, 32u8, 112u8, 101u8,
+                    114u8, 115u8, 97u8, 110u8, 99u8, 97u8, 108u8, 101u8, 110u8, 100u8, 114u8,
+                    105u8, 101u8, 114u8, 32u8, 114u8, 195u8, 169u8, 112u8, 117u8, 98u8, 108u8,
+                    105u8, 99u8, 97u8, 105u8, 110u8, 32u8, 99u8, 104u8, 105u8, 110u8, 111u8, 105u8,
+                    115u8, 102u8, 111u8, 114u8, 109u8, 97u8, 116u8, 32u8, 100u8, 101u8, 32u8,
+                    100u8, 101u8, 118u8, 105u8, 115u8, 101u8, 32u8, 99u8, 111u8, 109u8, 112u8,
+                    116u8, 97u8, 98u8, 108u8, 101u8, 102u8, 111u8, 114u8, 109u8, 97u8, 116u8, 32u8,
+                    100u8, 101u8, 32u8, 100u8, 101u8, 118u8, 105u8, 115u8, 101u8, 32u8, 115u8,
+                    116u8, 97u8, 110u8, 100u8, 97u8, 114u8, 100u8, 111u8, 114u8, 100u8, 114u8,
+                    101u8, 32u8, 99u8, 104u8, 105u8, 110u8, 111u8, 105u8, 115u8, 32u8, 116u8,
+                    114u8, 97u8, 100u8, 105u8, 116u8, 105u8, 111u8, 110u8, 110u8, 101u8, 108u8,
+                    32u8, 45u8, 32u8, 66u8, 105u8, 103u8, 53u8, 97u8, 110u8, 99u8, 105u8, 101u8,
+                    110u8, 32u8, 111u8, 114u8, 100u8, 114u8, 101u8, 32u8, 100u8, 101u8, 32u8,
+                    116u8, 114u8, 105u8, 32u8, 112u8, 111u8, 117u8, 114u8, 32u8, 99u8, 111u8,
+                    109u8, 112u8, 97u8, 116u8, 105u8, 98u8, 105u8, 108u8, 105u8, 116u8, 195u8,
+                    169u8, 111u8, 114u8, 100u8, 114u8, 101u8, 32u8, 100u8, 117u8, 32u8, 100u8,
+                    105u8, 99u8, 116u8, 105u8, 111u8, 110u8, 110u8, 97u8, 105u8, 114u8, 101u8,
+                    111u8, 114u8, 100u8, 114u8, 101u8, 32u8, 100u8, 101u8, 32u8, 116u8, 114u8,
+                    105u8, 32u8, 85u8, 110u8, 105u8, 99u8, 111u8, 100u8, 101u8, 32u8, 112u8, 97u8,
+                    114u8, 32u8, 100u8, 195u8, 169u8, 102u8, 97u8, 117u8, 116u8, 114u8, 195u8,
+                    168u8, 103u8, 108u8, 101u8, 115u8, 32u8, 100u8, 101u8, 32u8, 99u8, 108u8, 97u8,
+                    115u8, 115u8, 101u8, 109u8, 101u8, 110u8, 116u8, 32u8, 101u8, 117u8, 114u8,
+                    111u8, 112u8, 195u8, 169u8, 101u8, 110u8, 111u8, 114u8, 100u8, 114u8, 101u8,
+                    32u8, 99u8, 104u8, 105u8, 110u8, 111u8, 105u8, 115u8, 32u8, 115u8, 105u8,
+                    109u8, 112u8, 108u8, 105u8, 102u8, 105u8, 195u8, 169u8, 32u8, 45u8, 32u8, 71u8,
+                    66u8, 50u8, 51u8, 49u8, 50u8, 111u8, 114u8, 100u8, 114u8, 101u8, 32u8, 100u8,
+                    101u8, 32u8, 108u8, 226u8, 128u8, 153u8, 97u8, 110u8, 110u8, 117u8, 97u8,
+                    105u8, 114u8, 101u8, 111u8, 114u8, 100u8, 114u8, 101u8, 32u8, 100u8, 101u8,
+                    32u8, 116u8, 114u8, 105u8, 32u8, 112u8, 104u8, 111u8, 110u8, 195u8, 169u8,
+                    116u8, 105u8, 113u8, 117u8, 101u8, 111u8, 114u8, 100u8, 114u8, 101u8, 32u8,
+                    112u8, 105u8, 110u8, 121u8, 105u8, 110u8, 111u8, 114u8, 100u8, 114u8, 101u8,
+                    32u8, 114u8, 195u8, 169u8, 102u8, 111u8, 114u8, 109u8, 195u8, 169u8, 114u8,
+                    101u8, 99u8, 104u8, 101u8, 114u8, 99u8, 104u8, 101u8, 32u8, 103u8, 195u8,
+                    169u8, 110u8, 195u8, 169u8, 114u8, 105u8, 113u8, 117u8, 101u8, 114u8, 101u8,
+                    99u8, 104u8, 101u8, 114u8, 99u8, 104u8, 101u8, 114u8, 32u8, 112u8, 97u8, 114u8,
+                    32u8, 99u8, 111u8, 110u8, 115u8, 111u8, 110u8, 110u8, 101u8, 32u8, 105u8,
+                    110u8, 105u8, 116u8, 105u8, 97u8, 108u8, 101u8, 32u8, 101u8, 110u8, 32u8,
+                    104u8, 97u8, 110u8, 103u8, 101u8, 117u8, 108u8, 111u8, 114u8, 100u8, 114u8,
+                    101u8, 32u8, 100u8, 101u8, 32u8, 116u8, 114u8, 105u8, 32u8, 115u8, 116u8, 97u8,
+                    110u8, 100u8, 97u8, 114u8, 100u8, 111u8, 114u8, 100u8, 114u8, 101u8, 32u8,
+                    100u8, 101u8, 115u8, 32u8, 116u8, 114u8, 97u8, 105u8, 116u8, 115u8, 111u8,
+                    114u8, 100u8, 114u8, 101u8, 32u8, 116u8, 114u8, 97u8, 100u8, 105u8, 116u8,
+                    105u8, 111u8, 110u8, 110u8, 101u8, 108u8, 111u8, 114u8, 100u8, 114u8, 101u8,
+                    32u8, 100u8, 101u8, 32u8, 116u8, 114u8, 105u8, 32u8, 114u8, 97u8, 100u8, 105u8,
+                    99u8, 97u8, 108u8, 45u8, 116u8, 114u8, 97u8, 105u8, 116u8, 115u8, 111u8, 114u8,
+                    100u8, 114u8, 101u8, 32u8, 122u8, 104u8, 117u8, 121u8, 105u8, 110u8, 115u8,
+                    121u8, 115u8, 116u8, 195u8, 168u8, 109u8, 101u8, 32u8, 104u8, 111u8, 114u8,
+                    97u8, 105u8, 114u8, 101u8, 32u8, 100u8, 101u8, 32u8, 49u8, 50u8, 32u8, 104u8,
+                    101u8, 117u8, 114u8, 101u8, 115u8, 32u8, 40u8, 48u8, 226u8, 128u8, 147u8, 49u8,
+                    49u8, 41u8, 115u8, 121u8, 115u8, 116u8, 195u8, 168u8, 109u8, 101u8, 32u8,
+                    104u8, 111u8, 114u8, 97u8, 105u8, 114u8, 101u8, 32u8, 100u8, 101u8, 32u8, 49u8,
+                    50u8, 32u8, 104u8, 101u8, 117u8, 114u8, 101u8, 115u8, 32u8, 40u8, 49u8, 226u8,
+                    128u8, 147u8, 49u8, 50u8, 41u8, 115u8, 121u8, 115u8, 116u8, 195u8, 168u8,
+                    109u8, 101u8, 32u8, 104u8, 111u8, 114u8, 97u8, 105u8, 114u8, 101u8, 32u8,
+                    100u8, 101u8, 32u8, 50u8, 52u8, 32u8, 104u8, 101u8, 117u8, 114u8, 101u8, 115u8,
+                    32u8, 40u8, 48u8, 226u8, 128u8, 147u8, 50u8, 51u8, 41u8, 115u8, 121u8, 115u8,
+                    116u8, 195u8, 168u8, 109u8, 101u8, 32u8, 104u8, 111u8, 114u8, 97u8, 105u8,
+                    114u8, 101u8, 32u8, 100u8, 101u8, 32u8, 50u8, 52u8, 32u8, 104u8, 101u8, 117u8,
+                    114u8, 101u8, 115u8, 32u8, 40u8, 49u8, 226u8, 128u8, 147u8, 50u8, 52u8, 41u8,
+                    84u8, 114u8, 105u8, 101u8, 114u8, 32u8, 108u8, 101u8, 115u8, 32u8, 115u8,
+                    121u8, 109u8, 98u8, 111u8, 108u8, 101u8, 115u8, 84u8, 114u8, 105u8, 101u8,
+                    114u8, 32u8, 101u8, 110u8, 32u8, 105u8, 103u8, 110u8, 111u8, 114u8, 97u8,
+                    110u8, 116u8, 32u8, 108u8, 101u8, 115u8, 32u8, 115u8, 121u8, 109u8, 98u8,
+                    111u8, 108u8, 101u8, 115u8, 84u8, 114u8, 105u8, 101u8, 114u8, 32u8, 108u8,
+                    101u8, 115u8, 32u8, 99u8, 97u8, 114u8, 97u8, 99u8, 116u8, 195u8, 168u8, 114u8,
+                    101u8, 115u8, 32u8, 97u8, 99u8, 99u8, 101u8, 110u8, 116u8, 117u8, 195u8, 169u8,
+                    115u8, 32u8, 110u8, 111u8, 114u8, 109u8, 97u8, 108u8, 101u8, 109u8, 101u8,
+                    110u8, 116u8, 84u8, 114u8, 105u8, 101u8, 114u8, 32u8, 108u8, 101u8, 115u8,
+                    32u8, 99u8, 97u8, 114u8, 97u8, 99u8, 116u8, 195u8, 168u8, 114u8, 101u8, 115u8,
+                    32u8, 97u8, 99u8, 99u8, 101u8, 110u8, 116u8, 117u8, 195u8, 169u8, 115u8, 32u8,
+                    100u8, 97u8, 110u8, 115u8, 32u8, 108u8, 226u8, 128u8, 153u8, 111u8, 114u8,
+                    100u8, 114u8, 101u8, 32u8, 105u8, 110u8, 118u8, 101u8, 114u8, 115u8, 101u8,
+                    84u8, 114u8, 105u8, 101u8, 114u8, 32u8, 115u8, 97u8, 110u8, 115u8, 32u8, 116u8,
+                    101u8, 110u8, 105u8, 114u8, 32u8, 99u8, 111u8, 109u8, 112u8, 116u8, 101u8,
+                    32u8, 100u8, 101u8, 32u8, 108u8, 97u8, 32u8, 99u8, 97u8, 115u8, 115u8, 101u8,
+                    84u8, 114u8, 105u8, 101u8, 114u8, 32u8, 101u8, 110u8, 32u8, 116u8, 101u8,
+                    110u8, 97u8, 110u8, 116u8, 32u8, 99u8, 111u8, 109u8, 112u8, 116u8, 101u8, 32u8,
+                    100u8, 101u8, 32u8, 108u8, 97u8, 32u8, 99u8, 97u8, 115u8, 115u8, 101u8, 84u8,
+                    114u8, 105u8, 101u8, 114u8, 32u8, 115u8, 97u8, 110u8, 115u8, 32u8, 111u8,
+                    114u8, 100u8, 114u8, 101u8, 32u8, 108u8, 105u8, 195u8, 169u8, 32u8, 195u8,
+                    160u8, 32u8, 108u8, 97u8, 32u8, 99u8, 97u8, 115u8, 115u8, 101u8, 84u8, 114u8,
+                    105u8, 101u8, 114u8, 32u8, 97u8, 118u8, 101u8, 99u8, 32u8, 108u8, 101u8, 115u8,
+                    32u8, 109u8, 105u8, 110u8, 117u8, 115u8, 99u8, 117u8, 108u8, 101u8, 115u8,
+                    32u8, 100u8, 226u8, 128u8, 153u8, 97u8, 98u8, 111u8, 114u8, 100u8, 84u8, 114u8,
+                    105u8, 101u8, 114u8, 32u8, 97u8, 118u8, 101u8, 99u8, 32u8, 108u8, 101u8, 115u8,
+                    32u8, 109u8, 97u8, 106u8, 117u8, 115u8, 99u8, 117u8, 108u8, 101u8, 115u8, 32u8,
+                    100u8, 226u8, 128u8, 153u8, 97u8, 98u8, 111u8, 114u8, 100u8, 84u8, 114u8,
+                    105u8, 101u8, 114u8, 32u8, 115u8, 97u8, 110u8, 115u8, 32u8, 110u8, 111u8,
+                    114u8, 109u8, 97u8, 108u8, 105u8, 115u8, 97u8, 116u8, 105u8, 111u8, 110u8,
+                    84u8, 114u8, 105u8, 101u8, 114u8, 32u8, 97u8, 118u8, 101u8, 99u8, 32u8, 110u8,
+                    111u8, 114u8, 109u8, 97u8, 108u8, 105u8, 115u8, 97u8, 116u8, 105u8, 111u8,
+                    110u8, 32u8, 85u8, 110u8, 105u8, 99u8, 111u8, 100u8, 101u8, 84u8, 114u8, 105u8,
+                    101u8, 114u8, 32u8, 108u8, 101u8, 115u8, 32u8, 99u8, 104u8, 105u8, 102u8,
+                    102u8, 114u8, 101u8, 115u8, 32u8, 105u8, 110u8, 100u8, 105u8, 118u8, 105u8,
+                    100u8, 117u8, 101u8, 108u8, 108u8, 101u8, 109u8, 101u8, 110u8, 116u8, 84u8,
+                    114u8, 105u8, 101u8, 114u8, 32u8, 108u8, 101u8, 115u8, 32u8, 99u8, 104u8,
+                    105u8, 102u8, 102u8, 114u8, 101u8, 115u8, 32u8, 112u8, 97u8, 114u8, 32u8,
+                    111u8, 114u8, 100u8, 114u8, 101u8, 32u8, 110u8, 117u8, 109u8, 195u8, 169u8,
+                    114u8, 105u8, 113u8, 117u8, 101u8, 84u8, 111u8, 117u8, 116u8, 32u8, 116u8,
+                    114u8, 105u8, 101u8, 114u8, 78u8, 101u8, 32u8, 116u8, 114u8, 105u8, 101u8,
+                    114u8, 32u8, 113u8, 117u8, 101u8, 32u8, 108u8, 101u8, 115u8, 32u8, 108u8,
+                    101u8, 116u8, 116u8, 114u8, 101u8, 115u8, 32u8, 100u8, 101u8, 32u8, 98u8, 97u8,
+                    115u8, 101u8, 84u8, 114u8, 105u8, 101u8, 114u8, 32u8, 101u8, 110u8, 32u8,
+                    116u8, 101u8, 110u8, 97u8, 110u8, 116u8, 32u8, 99u8, 111u8, 109u8, 112u8,
+                    116u8, 101u8, 32u8, 100u8, 101u8, 115u8, 32u8, 99u8, 97u8, 114u8, 97u8, 99u8,
+                    116u8, 195u8, 168u8, 114u8, 101u8, 115u8, 32u8, 97u8, 99u8, 99u8, 101u8, 110u8,
+                    116u8, 117u8, 195u8, 169u8, 115u8, 84u8, 114u8, 105u8, 101u8, 114u8, 32u8,
+                    101u8, 110u8, 32u8, 116u8, 101u8, 110u8, 97u8, 110u8, 116u8, 32u8, 99u8, 111u8,
+                    109u8, 112u8, 116u8, 101u8, 32u8, 100u8, 101u8, 115u8, 32u8, 99u8, 97u8, 114u8,
+                    97u8, 99u8, 116u8, 195u8, 168u8, 114u8, 101u8, 115u8, 32u8, 97u8, 99u8, 99u8,
+                    101u8, 110u8, 116u8, 117u8, 195u8, 169u8, 115u8, 44u8, 32u8, 100u8, 101u8,
+                    32u8, 108u8, 97u8, 32u8, 99u8, 97u8, 115u8, 115u8, 101u8, 32u8, 101u8, 116u8,
+                    32u8, 100u8, 101u8, 32u8, 108u8, 97u8, 32u8, 108u8, 97u8, 114u8, 103u8, 101u8,
+                    117u8, 114u8, 84u8, 114u8, 105u8, 101u8, 114u8, 32u8, 101u8, 110u8, 32u8,
+                    116u8, 101u8, 110u8, 97u8, 110u8, 116u8, 32u8, 99u8, 111u8, 109u8, 112u8,
+                    116u8, 101u8, 32u8, 100u8, 101u8, 115u8, 32u8, 99u8, 97u8, 114u8, 97u8, 99u8,
+                    116u8, 195u8, 168u8, 114u8, 101u8, 115u8, 32u8, 97u8, 99u8, 99u8, 101u8, 110u8,
+                    116u8, 117u8, 195u8, 169u8, 115u8, 44u8, 32u8, 100u8, 101u8, 32u8, 108u8, 97u8,
+                    32u8, 99u8, 97u8, 115u8, 115u8, 101u8, 44u8, 32u8, 100u8, 101u8, 32u8, 108u8,
+                    97u8, 32u8, 108u8, 97u8, 114u8, 103u8, 101u8, 117u8, 114u8, 32u8, 101u8, 116u8,
+                    32u8, 100u8, 101u8, 115u8, 32u8, 99u8, 97u8, 114u8, 97u8, 99u8, 116u8, 195u8,
+                    168u8, 114u8, 101u8, 115u8, 32u8, 75u8, 97u8, 110u8, 97u8, 115u8, 116u8, 121u8,
+                    108u8, 101u8, 32u8, 100u8, 101u8, 32u8, 115u8, 97u8, 117u8, 116u8, 32u8, 100u8,
+                    101u8, 32u8, 108u8, 105u8, 103u8, 110u8, 101u8, 32u8, 112u8, 101u8, 114u8,
+                    109u8, 105u8, 115u8, 115u8, 105u8, 102u8, 115u8, 116u8, 121u8, 108u8, 101u8,
+                    32u8, 100u8, 101u8, 32u8, 115u8, 97u8, 117u8, 116u8, 32u8, 100u8, 101u8, 32u8,
+                    108u8, 105u8, 103u8, 110u8, 101u8, 32u8, 110u8, 111u8, 114u8, 109u8, 97u8,
+                    108u8, 115u8, 116u8, 121u8, 108u8, 101u8, 32u8, 100u8, 101u8, 32u8, 115u8,
+                    97u8, 117u8, 116u8, 32u8, 100u8, 101u8, 32u8, 108u8, 105u8, 103u8, 110u8,
+                    101u8, 32u8, 115u8, 116u8, 114u8, 105u8, 99u8, 116u8, 115u8, 121u8, 115u8,
+                    116u8, 195u8, 168u8, 109u8, 101u8, 32u8, 109u8, 195u8, 169u8, 116u8, 114u8,
+                    105u8, 113u8, 117u8, 101u8, 115u8, 121u8, 115u8, 116u8, 195u8, 168u8, 109u8,
+                    101u8, 32u8, 105u8, 109u8, 112u8, 195u8, 169u8, 114u8, 105u8, 97u8, 108u8,
+                    115u8, 121u8, 115u8, 116u8, 195u8, 168u8, 109u8, 101u8, 32u8, 97u8, 109u8,
+                    195u8, 169u8, 114u8, 105u8, 99u8, 97u8, 105u8, 110u8, 99u8, 104u8, 105u8,
+                    102u8, 102u8, 114u8, 101u8, 115u8, 32u8, 97u8, 114u8, 97u8, 98u8, 101u8, 115u8,
+                    99u8, 104u8, 105u8, 102u8, 102u8, 114u8, 101u8, 115u8, 32u8, 97u8, 114u8, 97u8,
+                    98u8, 101u8, 115u8, 32u8, 195u8, 169u8, 116u8, 101u8, 110u8, 100u8, 117u8,
+                    115u8, 99u8, 104u8, 105u8, 102u8, 102u8, 114u8, 101u8, 115u8, 32u8, 97u8,
+                    114u8, 109u8, 195u8, 169u8, 110u8, 105u8, 101u8, 110u8, 115u8, 99u8, 104u8,
+                    105u8, 102u8, 102u8, 114u8, 101u8, 115u8, 32u8, 97u8, 114u8, 109u8, 195u8,
+                    169u8, 110u8, 105u8, 101u8, 110u8, 115u8, 32u8, 109u8, 105u8, 110u8, 117u8,
+                    115u8, 99u8, 117u8, 108u8, 101u8, 115u8, 99u8, 104u8, 105u8, 102u8, 102u8,
+                    114u8, 101u8, 115u8, 32u8, 98u8, 101u8, 110u8, 103u8, 97u8, 108u8, 105u8,
+                    115u8, 99u8, 104u8, 105u8, 102u8, 102u8, 114u8, 101u8, 115u8, 32u8, 99u8,
+                    104u8, 97u8, 107u8, 109u8, 97u8, 115u8, 99u8, 104u8, 105u8, 102u8, 102u8,
+                    114u8, 101u8, 115u8, 32u8, 100u8, 195u8, 169u8, 118u8, 97u8, 110u8, 97u8,
+                    103u8, 97u8, 114u8, 105u8, 115u8, 99u8, 104u8, 105u8, 102u8, 102u8, 114u8,
+                    101u8, 115u8, 32u8, 195u8, 169u8, 116u8, 104u8, 105u8, 111u8, 112u8, 105u8,
+                    101u8, 110u8, 115u8, 67u8, 104u8, 105u8, 102u8, 102u8, 114u8, 101u8, 115u8,
+                    32u8, 102u8, 105u8, 110u8, 97u8, 110u8, 99u8, 105u8, 101u8, 114u8, 115u8, 99u8,
+                    104u8, 105u8, 102u8, 102u8, 114u8, 101u8, 115u8, 32u8, 112u8, 108u8, 101u8,
+                    105u8, 110u8, 101u8, 32u8, 99u8, 104u8, 97u8, 115u8, 115u8, 101u8, 99u8, 104u8,
+                    105u8, 102u8, 102u8, 114u8, 101u8, 115u8, 32u8, 103u8, 195u8, 169u8, 111u8,
+                    114u8, 103u8, 105u8, 101u8, 110u8, 115u8, 99u8, 104u8, 105u8, 102u8, 102u8,
+                    114u8, 101u8, 115u8, 32u8, 103u8, 114u8, 101u8, 99u8, 115u8, 99u8, 104u8,
+                    105u8, 102u8, 102u8, 114u8, 101u8, 115u8, 32u8, 103u8, 114u8, 101u8, 99u8,
+                    115u8, 32u8, 109u8, 105u8, 110u8, 117u8, 115u8, 99u8, 117u8, 108u8, 101u8,
+                    115u8, 99u8, 104u8, 105u8, 102u8, 102u8, 114u8, 101u8, 115u8, 32u8, 103u8,
+                    111u8, 117u8, 100u8, 106u8, 97u8, 114u8, 195u8, 162u8, 116u8, 195u8, 174u8,
+                    115u8, 99u8, 104u8, 105u8, 102u8, 102u8, 114u8, 101u8, 115u8, 32u8, 103u8,
+                    111u8, 117u8, 114u8, 109u8, 111u8, 117u8, 107u8, 104u8, 195u8, 174u8, 115u8,
+                    110u8, 111u8, 109u8, 98u8, 114u8, 101u8, 115u8, 32u8, 100u8, 195u8, 169u8,
+                    99u8, 105u8, 109u8, 97u8, 117u8, 120u8, 32u8, 99u8, 104u8, 105u8, 110u8, 111u8,
+                    105u8, 115u8, 99u8, 104u8, 105u8, 102u8, 102u8, 114u8, 101u8, 115u8, 32u8,
+                    101u8, 110u8, 32u8, 99u8, 104u8, 105u8, 110u8, 111u8, 105u8, 115u8, 32u8,
+                    115u8, 105u8, 109u8, 112u8, 108u8, 105u8, 102u8, 105u8, 195u8, 169u8, 99u8,
+                    104u8, 105u8, 102u8, 102u8, 114u8, 101u8, 115u8, 32u8, 102u8, 105u8, 110u8,
+                    97u8, 110u8, 99u8, 105u8, 101u8, 114u8, 115u8, 32u8, 101u8, 110u8, 32u8, 99u8,
+                    104u8, 105u8, 110u8, 111u8, 105u8, 115u8, 32u8, 115u8, 105u8, 109u8, 112u8,
+                    108u8, 105u8, 102u8, 105u8, 195u8, 169u8, 99u8, 104u8, 105u8, 102u8, 102u8,
+                    114u8, 101u8, 115u8, 32u8, 101u8, 110u8, 32u8, 99u8, 104u8, 105u8, 110u8,
+                    111u8, 105u8, 115u8, 32u8, 116u8, 114u8, 97u8, 100u8, 105u8, 116u8, 105u8,
+                    111u8, 110u8, 110u8, 101u8, 108u8, 99u8, 104u8, 105u8, 102u8, 102u8, 114u8,
+                    101u8, 115u8, 32u8, 102u8, 105u8, 110u8, 97u8, 110u8, 99u8, 105u8, 101u8,
+                    114u8, 115u8, 32u8, 101u8, 110u8, 32u8, 99u8, 104u8, 105u8, 110u8, 111u8,
+                    105u8, 115u8, 32u8, 116u8, 114u8, 97u8, 100u8, 105u8, 116u8, 105u8, 111u8,
+                    110u8, 110u8, 101u8, 108u8, 99u8, 104u8, 105u8, 102u8, 102u8, 114u8, 101u8,
+                    115u8, 32u8, 104u8, 195u8, 169u8, 98u8, 114u8, 101u8, 117u8, 120u8, 99u8,
+                    104u8, 105u8, 102u8, 102u8, 114u8, 101u8, 115u8, 32u8, 110u8, 121u8, 105u8,
+                    97u8, 107u8, 101u8, 110u8, 103u8, 32u8, 112u8, 117u8, 97u8, 99u8, 104u8, 117u8,
+                    101u8, 32u8, 104u8, 109u8, 111u8, 110u8, 103u8, 99u8, 104u8, 105u8, 102u8,
+                    102u8, 114u8, 101u8, 115u8, 32u8, 106u8, 97u8, 118u8, 97u8, 110u8, 97u8, 105u8,
+                    115u8, 99u8, 104u8, 105u8, 102u8, 102u8, 114u8, 101u8, 115u8, 32u8, 106u8,
+                    97u8, 112u8, 111u8, 110u8, 97u8, 105u8, 115u8, 99u8, 104u8, 105u8, 102u8,
+                    102u8, 114u8, 101u8, 115u8, 32u8, 106u8, 97u8, 112u8, 111u8, 110u8, 97u8,
+                    105u8, 115u8, 32u8, 102u8, 105u8, 110u8, 97u8, 110u8, 99u8, 105u8, 101u8,
+                    114u8, 115u8, 99u8, 104u8, 105u8, 102u8, 102u8, 114u8, 101u8, 115u8, 32u8,
+                    107u8, 104u8, 109u8, 101u8, 114u8, 115u8, 99u8, 104u8, 105u8, 102u8, 102u8,
+                    114u8, 101u8, 115u8, 32u8, 101u8, 110u8, 32u8, 107u8, 97u8, 110u8, 110u8, 97u8,
+                    100u8, 97u8, 99u8, 104u8, 105u8, 102u8, 102u8, 114u8, 101u8, 115u8, 32u8,
+                    108u8, 97u8, 111u8, 116u8, 105u8, 101u8, 110u8, 115u8, 99u8, 104u8, 105u8,
+                    102u8, 102u8, 114u8, 101u8, 115u8, 32u8, 111u8, 99u8, 99u8, 105u8, 100u8,
+                    101u8, 110u8, 116u8, 97u8, 117u8, 120u8, 99u8, 104u8, 105u8, 102u8, 102u8,
+                    114u8, 101u8, 115u8, 32u8, 109u8, 97u8, 108u8, 97u8, 121u8, 195u8, 162u8,
+                    108u8, 97u8, 109u8, 115u8, 99u8, 104u8, 105u8, 102u8, 102u8, 114u8, 101u8,
+                    115u8, 32u8, 109u8, 111u8, 110u8, 103u8, 111u8, 108u8, 115u8, 99u8, 104u8,
+                    105u8, 102u8, 102u8, 114u8, 101u8, 115u8, 32u8, 109u8, 101u8, 105u8, 116u8,
+                    101u8, 105u8, 45u8, 109u8, 97u8, 121u8, 101u8, 107u8, 115u8, 99u8, 104u8,
+                    105u8, 102u8, 102u8, 114u8, 101u8, 115u8, 32u8, 98u8, 105u8, 114u8, 109u8,
+                    97u8, 110u8, 115u8, 99u8, 104u8, 105u8, 102u8, 102u8, 114u8, 101u8, 115u8,
+                    32u8, 110u8, 97u8, 116u8, 105u8, 102u8, 115u8, 99u8, 104u8, 105u8, 102u8,
+                    102u8, 114u8, 101u8, 115u8, 32u8, 111u8, 108u8, 45u8, 99u8, 104u8, 105u8,
+                    107u8, 105u8, 115u8, 99u8, 104u8, 105u8, 102u8, 102u8, 114u8, 101u8, 115u8,
+                    32u8, 111u8, 114u8, 105u8, 121u8, 97u8, 115u8, 99u8, 104u8, 105u8, 102u8,
+                    102u8, 114u8, 101u8, 115u8, 32u8, 114u8, 111u8, 109u8, 97u8, 105u8, 110u8,
+                    115u8, 99u8, 104u8, 105u8, 102u8, 102u8, 114u8, 101u8, 115u8, 32u8, 114u8,
+                    111u8, 109u8, 97u8, 105u8, 110u8, 115u8, 32u8, 109u8, 105u8, 110u8, 117u8,
+                    115u8, 99u8, 117u8, 108u8, 101u8, 115u8, 99u8, 104u8, 105u8, 102u8, 102u8,
+                    114u8, 101u8, 115u8, 32u8, 116u8, 97u8, 109u8, 111u8, 117u8, 108u8, 115u8,
+                    32u8, 116u8, 114u8, 97u8, 100u8, 105u8, 116u8, 105u8, 111u8, 110u8, 110u8,
+                    101u8, 108u8, 115u8, 99u8, 104u8, 105u8, 102u8, 102u8, 114u8, 101u8, 115u8,
+                    32u8, 116u8, 97u8, 109u8, 111u8, 117u8, 108u8, 115u8, 99u8, 104u8, 105u8,
+                    102u8, 102u8, 114u8, 101u8, 115u8, 32u8, 116u8, 195u8, 169u8, 108u8, 111u8,
+                    117u8, 103u8, 111u8, 117u8, 115u8, 99u8, 104u8, 105u8, 102u8, 102u8, 114u8,
+                    101u8, 115u8, 32u8, 116u8, 104u8, 97u8, 195u8, 175u8, 115u8, 99u8, 104u8,
+                    105u8, 102u8, 102u8, 114u8, 101u8, 115u8, 32u8, 116u8, 105u8, 98u8, 195u8,
+                    169u8, 116u8, 97u8, 105u8, 110u8, 115u8, 67u8, 104u8, 105u8, 102u8, 102u8,
+                    114u8, 101u8, 115u8, 32u8, 116u8, 114u8, 97u8, 100u8, 105u8, 116u8, 105u8,
+                    111u8, 110u8, 110u8, 101u8, 108u8, 115u8, 99u8, 104u8, 105u8, 102u8, 102u8,
+                    114u8, 101u8, 115u8, 32u8, 101u8, 110u8, 32u8, 118u8, 97u8, 195u8, 175u8, 99u8,
+                    104u8, 105u8, 102u8, 102u8, 114u8, 101u8, 115u8, 32u8, 119u8, 97u8, 110u8,
+                    116u8, 99u8, 104u8, 111u8,
+                ])
+            },
+        )
+    },
+};
+static JA: &DataStruct = &::icu_displaynames::provider::KeyDisplayNamesV1 {
+    keys: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    99u8, 97u8, 99u8, 102u8, 99u8, 111u8, 99u8, 117u8, 104u8, 99u8, 107u8, 97u8,
+                    107u8, 98u8, 107u8, 99u8, 107u8, 102u8, 107u8, 107u8, 107u8, 110u8, 107u8,
+                    115u8, 108u8, 98u8, 109u8, 115u8, 110u8, 117u8, 116u8, 122u8, 118u8, 97u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    17u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 30u8, 0u8, 48u8, 0u8, 54u8, 0u8, 72u8,
+                    0u8, 105u8, 0u8, 156u8, 0u8, 201u8, 0u8, 247u8, 0u8, 21u8, 1u8, 48u8, 1u8,
+                    75u8, 1u8, 87u8, 1u8, 96u8, 1u8, 108u8, 1u8, 126u8, 1u8, 230u8, 154u8, 166u8,
+                    230u8, 179u8, 149u8, 233u8, 128u8, 154u8, 232u8, 178u8, 168u8, 227u8, 131u8,
+                    149u8, 227u8, 130u8, 169u8, 227u8, 131u8, 188u8, 227u8, 131u8, 158u8, 227u8,
+                    131u8, 131u8, 227u8, 131u8, 136u8, 228u8, 184u8, 166u8, 227u8, 129u8, 185u8,
+                    230u8, 155u8, 191u8, 227u8, 129u8, 136u8, 233u8, 160u8, 134u8, 229u8, 186u8,
+                    143u8, 233u8, 128u8, 154u8, 232u8, 178u8, 168u8, 230u8, 153u8, 130u8, 233u8,
+                    150u8, 147u8, 229u8, 136u8, 182u8, 40u8, 49u8, 50u8, 32u8, 47u8, 32u8, 50u8,
+                    52u8, 41u8, 232u8, 168u8, 152u8, 229u8, 143u8, 183u8, 227u8, 130u8, 146u8,
+                    231u8, 132u8, 161u8, 232u8, 166u8, 150u8, 227u8, 129u8, 151u8, 227u8, 129u8,
+                    159u8, 228u8, 184u8, 166u8, 227u8, 129u8, 185u8, 230u8, 155u8, 191u8, 227u8,
+                    129u8, 136u8, 227u8, 130u8, 162u8, 227u8, 130u8, 175u8, 227u8, 130u8, 187u8,
+                    227u8, 131u8, 179u8, 227u8, 131u8, 136u8, 239u8, 188u8, 136u8, 233u8, 128u8,
+                    134u8, 230u8, 150u8, 185u8, 229u8, 144u8, 145u8, 239u8, 188u8, 137u8, 227u8,
+                    129u8, 171u8, 227u8, 130u8, 136u8, 227u8, 130u8, 139u8, 228u8, 184u8, 166u8,
+                    227u8, 129u8, 185u8, 230u8, 155u8, 191u8, 227u8, 129u8, 136u8, 229u8, 164u8,
+                    167u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 229u8, 176u8, 143u8, 230u8,
+                    150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 130u8, 146u8, 229u8, 140u8, 186u8,
+                    229u8, 136u8, 165u8, 227u8, 129u8, 151u8, 227u8, 129u8, 159u8, 228u8, 184u8,
+                    166u8, 227u8, 129u8, 185u8, 230u8, 155u8, 191u8, 227u8, 129u8, 136u8, 229u8,
+                    164u8, 167u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 233u8, 160u8, 134u8,
+                    47u8, 229u8, 176u8, 143u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 233u8,
+                    160u8, 134u8, 227u8, 129u8, 171u8, 227u8, 130u8, 136u8, 227u8, 130u8, 139u8,
+                    228u8, 184u8, 166u8, 227u8, 129u8, 185u8, 230u8, 155u8, 191u8, 227u8, 129u8,
+                    136u8, 230u8, 173u8, 163u8, 232u8, 166u8, 143u8, 229u8, 140u8, 150u8, 227u8,
+                    129u8, 171u8, 227u8, 130u8, 136u8, 227u8, 130u8, 139u8, 228u8, 184u8, 166u8,
+                    227u8, 129u8, 185u8, 230u8, 155u8, 191u8, 227u8, 129u8, 136u8, 230u8, 149u8,
+                    176u8, 229u8, 128u8, 164u8, 227u8, 129u8, 171u8, 227u8, 130u8, 136u8, 227u8,
+                    130u8, 139u8, 228u8, 184u8, 166u8, 227u8, 129u8, 185u8, 230u8, 155u8, 191u8,
+                    227u8, 129u8, 136u8, 229u8, 188u8, 183u8, 229u8, 186u8, 166u8, 227u8, 129u8,
+                    171u8, 227u8, 130u8, 136u8, 227u8, 130u8, 139u8, 228u8, 184u8, 166u8, 227u8,
+                    129u8, 185u8, 230u8, 155u8, 191u8, 227u8, 129u8, 136u8, 231u8, 166u8, 129u8,
+                    229u8, 137u8, 135u8, 229u8, 135u8, 166u8, 231u8, 144u8, 134u8, 229u8, 141u8,
+                    152u8, 228u8, 189u8, 141u8, 231u8, 179u8, 187u8, 230u8, 149u8, 176u8, 229u8,
+                    128u8, 164u8, 230u8, 155u8, 184u8, 229u8, 188u8, 143u8, 227u8, 130u8, 191u8,
+                    227u8, 130u8, 164u8, 227u8, 131u8, 160u8, 227u8, 130u8, 190u8, 227u8, 131u8,
+                    188u8, 227u8, 131u8, 179u8, 227u8, 131u8, 173u8, 227u8, 130u8, 177u8, 227u8,
+                    131u8, 188u8, 227u8, 131u8, 171u8, 227u8, 129u8, 174u8, 227u8, 131u8, 144u8,
+                    227u8, 131u8, 170u8, 227u8, 130u8, 162u8, 227u8, 131u8, 179u8, 227u8, 131u8,
+                    136u8,
+                ])
+            },
+        )
+    },
+    types: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    126u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 21u8, 0u8, 30u8, 0u8, 38u8, 0u8,
+                    48u8, 0u8, 59u8, 0u8, 69u8, 0u8, 78u8, 0u8, 87u8, 0u8, 97u8, 0u8, 113u8, 0u8,
+                    128u8, 0u8, 143u8, 0u8, 162u8, 0u8, 172u8, 0u8, 183u8, 0u8, 193u8, 0u8, 199u8,
+                    0u8, 209u8, 0u8, 220u8, 0u8, 230u8, 0u8, 239u8, 0u8, 246u8, 0u8, 254u8, 0u8,
+                    4u8, 1u8, 13u8, 1u8, 23u8, 1u8, 34u8, 1u8, 43u8, 1u8, 54u8, 1u8, 63u8, 1u8,
+                    74u8, 1u8, 85u8, 1u8, 94u8, 1u8, 101u8, 1u8, 110u8, 1u8, 119u8, 1u8, 125u8,
+                    1u8, 131u8, 1u8, 137u8, 1u8, 143u8, 1u8, 154u8, 1u8, 164u8, 1u8, 172u8, 1u8,
+                    179u8, 1u8, 187u8, 1u8, 194u8, 1u8, 202u8, 1u8, 210u8, 1u8, 218u8, 1u8, 226u8,
+                    1u8, 233u8, 1u8, 241u8, 1u8, 248u8, 1u8, 2u8, 2u8, 11u8, 2u8, 20u8, 2u8, 29u8,
+                    2u8, 38u8, 2u8, 46u8, 2u8, 55u8, 2u8, 64u8, 2u8, 73u8, 2u8, 84u8, 2u8, 95u8,
+                    2u8, 102u8, 2u8, 112u8, 2u8, 119u8, 2u8, 129u8, 2u8, 136u8, 2u8, 143u8, 2u8,
+                    150u8, 2u8, 157u8, 2u8, 164u8, 2u8, 171u8, 2u8, 178u8, 2u8, 188u8, 2u8, 199u8,
+                    2u8, 206u8, 2u8, 213u8, 2u8, 223u8, 2u8, 230u8, 2u8, 237u8, 2u8, 247u8, 2u8,
+                    254u8, 2u8, 8u8, 3u8, 15u8, 3u8, 25u8, 3u8, 32u8, 3u8, 39u8, 3u8, 46u8, 3u8,
+                    56u8, 3u8, 63u8, 3u8, 70u8, 3u8, 77u8, 3u8, 84u8, 3u8, 95u8, 3u8, 102u8, 3u8,
+                    109u8, 3u8, 116u8, 3u8, 123u8, 3u8, 130u8, 3u8, 137u8, 3u8, 144u8, 3u8, 151u8,
+                    3u8, 162u8, 3u8, 171u8, 3u8, 178u8, 3u8, 185u8, 3u8, 192u8, 3u8, 199u8, 3u8,
+                    207u8, 3u8, 218u8, 3u8, 225u8, 3u8, 232u8, 3u8, 239u8, 3u8, 246u8, 3u8, 253u8,
+                    3u8, 4u8, 4u8, 11u8, 4u8, 21u8, 4u8, 28u8, 4u8, 35u8, 4u8, 42u8, 4u8, 53u8,
+                    4u8, 99u8, 97u8, 45u8, 98u8, 117u8, 100u8, 100u8, 104u8, 105u8, 115u8, 116u8,
+                    99u8, 97u8, 45u8, 99u8, 104u8, 105u8, 110u8, 101u8, 115u8, 101u8, 99u8, 97u8,
+                    45u8, 99u8, 111u8, 112u8, 116u8, 105u8, 99u8, 99u8, 97u8, 45u8, 100u8, 97u8,
+                    110u8, 103u8, 105u8, 99u8, 97u8, 45u8, 101u8, 116u8, 104u8, 105u8, 111u8, 97u8,
+                    97u8, 99u8, 97u8, 45u8, 101u8, 116u8, 104u8, 105u8, 111u8, 112u8, 105u8, 99u8,
+                    99u8, 97u8, 45u8, 103u8, 114u8, 101u8, 103u8, 111u8, 114u8, 121u8, 99u8, 97u8,
+                    45u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 99u8, 97u8, 45u8, 105u8, 110u8,
+                    100u8, 105u8, 97u8, 110u8, 99u8, 97u8, 45u8, 105u8, 115u8, 108u8, 97u8, 109u8,
+                    105u8, 99u8, 99u8, 97u8, 45u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8,
+                    45u8, 99u8, 105u8, 118u8, 105u8, 108u8, 99u8, 97u8, 45u8, 105u8, 115u8, 108u8,
+                    97u8, 109u8, 105u8, 99u8, 45u8, 114u8, 103u8, 115u8, 97u8, 99u8, 97u8, 45u8,
+                    105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 116u8, 98u8, 108u8, 97u8,
+                    99u8, 97u8, 45u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 117u8,
+                    109u8, 97u8, 108u8, 113u8, 117u8, 114u8, 97u8, 99u8, 97u8, 45u8, 105u8, 115u8,
+                    111u8, 56u8, 54u8, 48u8, 49u8, 99u8, 97u8, 45u8, 106u8, 97u8, 112u8, 97u8,
+                    110u8, 101u8, 115u8, 101u8, 99u8, 97u8, 45u8, 112u8, 101u8, 114u8, 115u8,
+                    105u8, 97u8, 110u8, 99u8, 97u8, 45u8, 114u8, 111u8, 99u8, 99u8, 102u8, 45u8,
+                    97u8, 99u8, 99u8, 111u8, 117u8, 110u8, 116u8, 99u8, 102u8, 45u8, 115u8, 116u8,
+                    97u8, 110u8, 100u8, 97u8, 114u8, 100u8, 99u8, 111u8, 45u8, 98u8, 105u8, 103u8,
+                    53u8, 104u8, 97u8, 110u8, 99u8, 111u8, 45u8, 99u8, 111u8, 109u8, 112u8, 97u8,
+                    116u8, 99u8, 111u8, 45u8, 100u8, 105u8, 99u8, 116u8, 99u8, 111u8, 45u8, 100u8,
+                    117u8, 99u8, 101u8, 116u8, 99u8, 111u8, 45u8, 101u8, 111u8, 114u8, 99u8, 111u8,
+                    45u8, 103u8, 98u8, 50u8, 51u8, 49u8, 50u8, 99u8, 111u8, 45u8, 112u8, 104u8,
+                    111u8, 110u8, 101u8, 98u8, 107u8, 99u8, 111u8, 45u8, 112u8, 104u8, 111u8,
+                    110u8, 101u8, 116u8, 105u8, 99u8, 99u8, 111u8, 45u8, 112u8, 105u8, 110u8,
+                    121u8, 105u8, 110u8, 99u8, 111u8, 45u8, 114u8, 101u8, 102u8, 111u8, 114u8,
+                    109u8, 101u8, 100u8, 99u8, 111u8, 45u8, 115u8, 101u8, 97u8, 114u8, 99u8, 104u8,
+                    99u8, 111u8, 45u8, 115u8, 101u8, 97u8, 114u8, 99u8, 104u8, 106u8, 108u8, 99u8,
+                    111u8, 45u8, 115u8, 116u8, 97u8, 110u8, 100u8, 97u8, 114u8, 100u8, 99u8, 111u8,
+                    45u8, 115u8, 116u8, 114u8, 111u8, 107u8, 101u8, 99u8, 111u8, 45u8, 116u8,
+                    114u8, 97u8, 100u8, 99u8, 111u8, 45u8, 117u8, 110u8, 105u8, 104u8, 97u8, 110u8,
+                    99u8, 111u8, 45u8, 122u8, 104u8, 117u8, 121u8, 105u8, 110u8, 104u8, 99u8, 45u8,
+                    104u8, 49u8, 49u8, 104u8, 99u8, 45u8, 104u8, 49u8, 50u8, 104u8, 99u8, 45u8,
+                    104u8, 50u8, 51u8, 104u8, 99u8, 45u8, 104u8, 50u8, 52u8, 107u8, 97u8, 45u8,
+                    110u8, 111u8, 105u8, 103u8, 110u8, 111u8, 114u8, 101u8, 107u8, 97u8, 45u8,
+                    115u8, 104u8, 105u8, 102u8, 116u8, 101u8, 100u8, 107u8, 98u8, 45u8, 102u8,
+                    97u8, 108u8, 115u8, 101u8, 107u8, 98u8, 45u8, 116u8, 114u8, 117u8, 101u8,
+                    107u8, 99u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8, 107u8, 99u8, 45u8, 116u8,
+                    114u8, 117u8, 101u8, 107u8, 102u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8,
+                    107u8, 102u8, 45u8, 108u8, 111u8, 119u8, 101u8, 114u8, 107u8, 102u8, 45u8,
+                    117u8, 112u8, 112u8, 101u8, 114u8, 107u8, 107u8, 45u8, 102u8, 97u8, 108u8,
+                    115u8, 101u8, 107u8, 107u8, 45u8, 116u8, 114u8, 117u8, 101u8, 107u8, 110u8,
+                    45u8, 102u8, 97u8, 108u8, 115u8, 101u8, 107u8, 110u8, 45u8, 116u8, 114u8,
+                    117u8, 101u8, 107u8, 115u8, 45u8, 105u8, 100u8, 101u8, 110u8, 116u8, 105u8,
+                    99u8, 107u8, 115u8, 45u8, 108u8, 101u8, 118u8, 101u8, 108u8, 49u8, 107u8,
+                    115u8, 45u8, 108u8, 101u8, 118u8, 101u8, 108u8, 50u8, 107u8, 115u8, 45u8,
+                    108u8, 101u8, 118u8, 101u8, 108u8, 51u8, 107u8, 115u8, 45u8, 108u8, 101u8,
+                    118u8, 101u8, 108u8, 52u8, 108u8, 98u8, 45u8, 108u8, 111u8, 111u8, 115u8,
+                    101u8, 108u8, 98u8, 45u8, 110u8, 111u8, 114u8, 109u8, 97u8, 108u8, 108u8, 98u8,
+                    45u8, 115u8, 116u8, 114u8, 105u8, 99u8, 116u8, 109u8, 115u8, 45u8, 109u8,
+                    101u8, 116u8, 114u8, 105u8, 99u8, 109u8, 115u8, 45u8, 117u8, 107u8, 115u8,
+                    121u8, 115u8, 116u8, 101u8, 109u8, 109u8, 115u8, 45u8, 117u8, 115u8, 115u8,
+                    121u8, 115u8, 116u8, 101u8, 109u8, 110u8, 117u8, 45u8, 97u8, 114u8, 97u8, 98u8,
+                    110u8, 117u8, 45u8, 97u8, 114u8, 97u8, 98u8, 101u8, 120u8, 116u8, 110u8, 117u8,
+                    45u8, 97u8, 114u8, 109u8, 110u8, 110u8, 117u8, 45u8, 97u8, 114u8, 109u8, 110u8,
+                    108u8, 111u8, 119u8, 110u8, 117u8, 45u8, 98u8, 97u8, 108u8, 105u8, 110u8,
+                    117u8, 45u8, 98u8, 101u8, 110u8, 103u8, 110u8, 117u8, 45u8, 98u8, 114u8, 97u8,
+                    104u8, 110u8, 117u8, 45u8, 99u8, 97u8, 107u8, 109u8, 110u8, 117u8, 45u8, 99u8,
+                    104u8, 97u8, 109u8, 110u8, 117u8, 45u8, 100u8, 101u8, 118u8, 97u8, 110u8,
+                    117u8, 45u8, 101u8, 116u8, 104u8, 105u8, 110u8, 117u8, 45u8, 102u8, 105u8,
+                    110u8, 97u8, 110u8, 99u8, 101u8, 110u8, 117u8, 45u8, 102u8, 117u8, 108u8,
+                    108u8, 119u8, 105u8, 100u8, 101u8, 110u8, 117u8, 45u8, 103u8, 101u8, 111u8,
+                    114u8, 110u8, 117u8, 45u8, 103u8, 114u8, 101u8, 107u8, 110u8, 117u8, 45u8,
+                    103u8, 114u8, 101u8, 107u8, 108u8, 111u8, 119u8, 110u8, 117u8, 45u8, 103u8,
+                    117u8, 106u8, 114u8, 110u8, 117u8, 45u8, 103u8, 117u8, 114u8, 117u8, 110u8,
+                    117u8, 45u8, 104u8, 97u8, 110u8, 105u8, 100u8, 101u8, 99u8, 110u8, 117u8, 45u8,
+                    104u8, 97u8, 110u8, 115u8, 110u8, 117u8, 45u8, 104u8, 97u8, 110u8, 115u8,
+                    102u8, 105u8, 110u8, 110u8, 117u8, 45u8, 104u8, 97u8, 110u8, 116u8, 110u8,
+                    117u8, 45u8, 104u8, 97u8, 110u8, 116u8, 102u8, 105u8, 110u8, 110u8, 117u8,
+                    45u8, 104u8, 101u8, 98u8, 114u8, 110u8, 117u8, 45u8, 106u8, 97u8, 118u8, 97u8,
+                    110u8, 117u8, 45u8, 106u8, 112u8, 97u8, 110u8, 110u8, 117u8, 45u8, 106u8,
+                    112u8, 97u8, 110u8, 102u8, 105u8, 110u8, 110u8, 117u8, 45u8, 107u8, 97u8,
+                    108u8, 105u8, 110u8, 117u8, 45u8, 107u8, 104u8, 109u8, 114u8, 110u8, 117u8,
+                    45u8, 107u8, 110u8, 100u8, 97u8, 110u8, 117u8, 45u8, 108u8, 97u8, 110u8, 97u8,
+                    110u8, 117u8, 45u8, 108u8, 97u8, 110u8, 97u8, 116u8, 104u8, 97u8, 109u8, 110u8,
+                    117u8, 45u8, 108u8, 97u8, 111u8, 111u8, 110u8, 117u8, 45u8, 108u8, 97u8, 116u8,
+                    110u8, 110u8, 117u8, 45u8, 108u8, 101u8, 112u8, 99u8, 110u8, 117u8, 45u8,
+                    108u8, 105u8, 109u8, 98u8, 110u8, 117u8, 45u8, 109u8, 108u8, 121u8, 109u8,
+                    110u8, 117u8, 45u8, 109u8, 111u8, 110u8, 103u8, 110u8, 117u8, 45u8, 109u8,
+                    116u8, 101u8, 105u8, 110u8, 117u8, 45u8, 109u8, 121u8, 109u8, 114u8, 110u8,
+                    117u8, 45u8, 109u8, 121u8, 109u8, 114u8, 115u8, 104u8, 97u8, 110u8, 110u8,
+                    117u8, 45u8, 110u8, 97u8, 116u8, 105u8, 118u8, 101u8, 110u8, 117u8, 45u8,
+                    110u8, 107u8, 111u8, 111u8, 110u8, 117u8, 45u8, 111u8, 108u8, 99u8, 107u8,
+                    110u8, 117u8, 45u8, 111u8, 114u8, 121u8, 97u8, 110u8, 117u8, 45u8, 111u8,
+                    115u8, 109u8, 97u8, 110u8, 117u8, 45u8, 114u8, 111u8, 109u8, 97u8, 110u8,
+                    110u8, 117u8, 45u8, 114u8, 111u8, 109u8, 97u8, 110u8, 108u8, 111u8, 119u8,
+                    110u8, 117u8, 45u8, 115u8, 97u8, 117u8, 114u8, 110u8, 117u8, 45u8, 115u8,
+                    104u8, 114u8, 100u8, 110u8, 117u8, 45u8, 115u8, 111u8, 114u8, 97u8, 110u8,
+                    117u8, 45u8, 115u8, 117u8, 110u8, 100u8, 110u8, 117u8, 45u8, 116u8, 97u8,
+                    107u8, 114u8, 110u8, 117u8, 45u8, 116u8, 97u8, 108u8, 117u8, 110u8, 117u8,
+                    45u8, 116u8, 97u8, 109u8, 108u8, 110u8, 117u8, 45u8, 116u8, 97u8, 109u8, 108u8,
+                    100u8, 101u8, 99u8, 110u8, 117u8, 45u8, 116u8, 101u8, 108u8, 117u8, 110u8,
+                    117u8, 45u8, 116u8, 104u8, 97u8, 105u8, 110u8, 117u8, 45u8, 116u8, 105u8, 98u8,
+                    116u8, 110u8, 117u8, 45u8, 116u8, 114u8, 97u8, 100u8, 105u8, 116u8, 105u8,
+                    111u8, 110u8, 117u8, 45u8, 118u8, 97u8, 105u8, 105u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    126u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 15u8, 0u8, 27u8, 0u8, 39u8, 0u8,
+                    69u8, 0u8, 87u8, 0u8, 113u8, 0u8, 125u8, 0u8, 143u8, 0u8, 158u8, 0u8, 199u8,
+                    0u8, 249u8, 0u8, 34u8, 1u8, 78u8, 1u8, 86u8, 1u8, 92u8, 1u8, 107u8, 1u8, 122u8,
+                    1u8, 152u8, 1u8, 182u8, 1u8, 209u8, 1u8, 239u8, 1u8, 248u8, 1u8, 16u8, 2u8,
+                    64u8, 2u8, 93u8, 2u8, 105u8, 2u8, 135u8, 2u8, 150u8, 2u8, 165u8, 2u8, 177u8,
+                    2u8, 222u8, 2u8, 246u8, 2u8, 255u8, 2u8, 26u8, 3u8, 35u8, 3u8, 44u8, 3u8, 63u8,
+                    3u8, 82u8, 3u8, 101u8, 3u8, 120u8, 3u8, 141u8, 3u8, 174u8, 3u8, 219u8, 3u8,
+                    8u8, 4u8, 59u8, 4u8, 104u8, 4u8, 155u8, 4u8, 185u8, 4u8, 215u8, 4u8, 248u8,
+                    4u8, 24u8, 5u8, 57u8, 5u8, 93u8, 5u8, 129u8, 5u8, 162u8, 5u8, 192u8, 5u8,
+                    254u8, 5u8, 67u8, 6u8, 84u8, 6u8, 104u8, 6u8, 121u8, 6u8, 136u8, 6u8, 160u8,
+                    6u8, 175u8, 6u8, 205u8, 6u8, 223u8, 6u8, 244u8, 6u8, 20u8, 7u8, 32u8, 7u8,
+                    50u8, 7u8, 74u8, 7u8, 92u8, 7u8, 107u8, 7u8, 137u8, 7u8, 158u8, 7u8, 176u8,
+                    7u8, 188u8, 7u8, 209u8, 7u8, 227u8, 7u8, 0u8, 8u8, 24u8, 8u8, 45u8, 8u8, 74u8,
+                    8u8, 89u8, 8u8, 101u8, 8u8, 116u8, 8u8, 128u8, 8u8, 146u8, 8u8, 161u8, 8u8,
+                    170u8, 8u8, 176u8, 8u8, 191u8, 8u8, 209u8, 8u8, 227u8, 8u8, 248u8, 8u8, 22u8,
+                    9u8, 34u8, 9u8, 46u8, 9u8, 64u8, 9u8, 79u8, 9u8, 103u8, 9u8, 121u8, 9u8, 139u8,
+                    9u8, 160u8, 9u8, 193u8, 9u8, 211u8, 9u8, 223u8, 9u8, 241u8, 9u8, 3u8, 10u8,
+                    24u8, 10u8, 39u8, 10u8, 65u8, 10u8, 95u8, 10u8, 119u8, 10u8, 146u8, 10u8,
+                    161u8, 10u8, 182u8, 10u8, 206u8, 10u8, 230u8, 10u8, 245u8, 10u8, 4u8, 11u8,
+                    16u8, 11u8, 34u8, 11u8, 52u8, 11u8, 228u8, 187u8, 143u8, 230u8, 154u8, 166u8,
+                    228u8, 184u8, 173u8, 229u8, 155u8, 189u8, 230u8, 154u8, 166u8, 227u8, 130u8,
+                    179u8, 227u8, 131u8, 151u8, 227u8, 131u8, 136u8, 230u8, 154u8, 166u8, 227u8,
+                    131u8, 128u8, 227u8, 131u8, 179u8, 227u8, 130u8, 174u8, 230u8, 154u8, 166u8,
+                    227u8, 130u8, 168u8, 227u8, 131u8, 129u8, 227u8, 130u8, 170u8, 227u8, 131u8,
+                    148u8, 227u8, 130u8, 162u8, 229u8, 137u8, 181u8, 228u8, 184u8, 150u8, 231u8,
+                    180u8, 128u8, 229u8, 133u8, 131u8, 230u8, 154u8, 166u8, 227u8, 130u8, 168u8,
+                    227u8, 131u8, 129u8, 227u8, 130u8, 170u8, 227u8, 131u8, 148u8, 227u8, 130u8,
+                    162u8, 230u8, 154u8, 166u8, 232u8, 165u8, 191u8, 230u8, 154u8, 166u8, 40u8,
+                    227u8, 130u8, 176u8, 227u8, 131u8, 172u8, 227u8, 130u8, 180u8, 227u8, 131u8,
+                    170u8, 227u8, 130u8, 170u8, 230u8, 154u8, 166u8, 41u8, 227u8, 131u8, 166u8,
+                    227u8, 131u8, 128u8, 227u8, 131u8, 164u8, 230u8, 154u8, 166u8, 227u8, 130u8,
+                    164u8, 227u8, 131u8, 179u8, 227u8, 131u8, 137u8, 229u8, 155u8, 189u8, 229u8,
+                    174u8, 154u8, 230u8, 154u8, 166u8, 227u8, 130u8, 164u8, 227u8, 130u8, 185u8,
+                    227u8, 131u8, 169u8, 227u8, 131u8, 160u8, 230u8, 154u8, 166u8, 227u8, 130u8,
+                    164u8, 227u8, 130u8, 185u8, 227u8, 131u8, 169u8, 227u8, 131u8, 160u8, 230u8,
+                    154u8, 166u8, 40u8, 229u8, 174u8, 154u8, 229u8, 145u8, 168u8, 230u8, 156u8,
+                    159u8, 227u8, 128u8, 129u8, 229u8, 133u8, 172u8, 230u8, 176u8, 145u8, 231u8,
+                    180u8, 128u8, 229u8, 133u8, 131u8, 41u8, 227u8, 130u8, 164u8, 227u8, 130u8,
+                    185u8, 227u8, 131u8, 169u8, 227u8, 131u8, 160u8, 230u8, 154u8, 166u8, 40u8,
+                    227u8, 130u8, 181u8, 227u8, 130u8, 166u8, 227u8, 130u8, 184u8, 227u8, 130u8,
+                    162u8, 227u8, 131u8, 169u8, 227u8, 131u8, 147u8, 227u8, 130u8, 162u8, 227u8,
+                    128u8, 129u8, 230u8, 156u8, 136u8, 232u8, 166u8, 179u8, 230u8, 184u8, 172u8,
+                    41u8, 227u8, 130u8, 164u8, 227u8, 130u8, 185u8, 227u8, 131u8, 169u8, 227u8,
+                    131u8, 160u8, 230u8, 173u8, 180u8, 40u8, 229u8, 174u8, 154u8, 229u8, 145u8,
+                    168u8, 230u8, 156u8, 159u8, 227u8, 128u8, 129u8, 229u8, 164u8, 169u8, 230u8,
+                    150u8, 135u8, 231u8, 180u8, 128u8, 229u8, 133u8, 131u8, 41u8, 227u8, 130u8,
+                    164u8, 227u8, 130u8, 185u8, 227u8, 131u8, 169u8, 227u8, 131u8, 160u8, 230u8,
+                    154u8, 166u8, 40u8, 227u8, 130u8, 166u8, 227u8, 131u8, 179u8, 227u8, 131u8,
+                    160u8, 227u8, 131u8, 187u8, 227u8, 130u8, 162u8, 227u8, 131u8, 171u8, 227u8,
+                    130u8, 175u8, 227u8, 131u8, 169u8, 227u8, 131u8, 188u8, 41u8, 73u8, 83u8, 79u8,
+                    45u8, 56u8, 54u8, 48u8, 49u8, 229u8, 146u8, 140u8, 230u8, 154u8, 166u8, 227u8,
+                    131u8, 154u8, 227u8, 131u8, 171u8, 227u8, 130u8, 183u8, 227u8, 130u8, 162u8,
+                    230u8, 154u8, 166u8, 228u8, 184u8, 173u8, 232u8, 143u8, 175u8, 230u8, 176u8,
+                    145u8, 229u8, 155u8, 189u8, 230u8, 154u8, 166u8, 228u8, 188u8, 154u8, 232u8,
+                    168u8, 136u8, 233u8, 128u8, 154u8, 232u8, 178u8, 168u8, 227u8, 131u8, 149u8,
+                    227u8, 130u8, 169u8, 227u8, 131u8, 188u8, 227u8, 131u8, 158u8, 227u8, 131u8,
+                    131u8, 227u8, 131u8, 136u8, 230u8, 168u8, 153u8, 230u8, 186u8, 150u8, 233u8,
+                    128u8, 154u8, 232u8, 178u8, 168u8, 227u8, 131u8, 149u8, 227u8, 130u8, 169u8,
+                    227u8, 131u8, 188u8, 227u8, 131u8, 158u8, 227u8, 131u8, 131u8, 227u8, 131u8,
+                    136u8, 231u8, 185u8, 129u8, 228u8, 189u8, 147u8, 229u8, 173u8, 151u8, 228u8,
+                    184u8, 173u8, 229u8, 155u8, 189u8, 232u8, 170u8, 158u8, 233u8, 160u8, 134u8,
+                    40u8, 66u8, 105u8, 103u8, 53u8, 41u8, 228u8, 187u8, 165u8, 229u8, 137u8, 141u8,
+                    227u8, 129u8, 174u8, 233u8, 160u8, 134u8, 229u8, 186u8, 143u8, 239u8, 188u8,
+                    136u8, 228u8, 186u8, 146u8, 230u8, 143u8, 155u8, 230u8, 128u8, 167u8, 239u8,
+                    188u8, 137u8, 232u8, 190u8, 158u8, 230u8, 155u8, 184u8, 233u8, 160u8, 134u8,
+                    227u8, 131u8, 166u8, 227u8, 131u8, 139u8, 227u8, 130u8, 179u8, 227u8, 131u8,
+                    188u8, 227u8, 131u8, 137u8, 231u8, 133u8, 167u8, 229u8, 144u8, 136u8, 233u8,
+                    160u8, 134u8, 227u8, 131u8, 168u8, 227u8, 131u8, 188u8, 227u8, 131u8, 173u8,
+                    227u8, 131u8, 131u8, 227u8, 131u8, 145u8, 232u8, 168u8, 128u8, 232u8, 170u8,
+                    158u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 129u8, 174u8, 228u8,
+                    184u8, 166u8, 227u8, 129u8, 185u8, 230u8, 155u8, 191u8, 227u8, 129u8, 136u8,
+                    232u8, 166u8, 143u8, 229u8, 137u8, 135u8, 231u8, 176u8, 161u8, 228u8, 189u8,
+                    147u8, 229u8, 173u8, 151u8, 228u8, 184u8, 173u8, 229u8, 155u8, 189u8, 232u8,
+                    170u8, 158u8, 233u8, 160u8, 134u8, 40u8, 71u8, 66u8, 50u8, 51u8, 49u8, 50u8,
+                    41u8, 233u8, 155u8, 187u8, 232u8, 169u8, 177u8, 229u8, 184u8, 179u8, 233u8,
+                    160u8, 134u8, 233u8, 159u8, 179u8, 229u8, 163u8, 176u8, 233u8, 160u8, 134u8,
+                    227u8, 129u8, 171u8, 227u8, 130u8, 136u8, 227u8, 130u8, 139u8, 228u8, 184u8,
+                    166u8, 227u8, 129u8, 185u8, 230u8, 155u8, 191u8, 227u8, 129u8, 136u8, 227u8,
+                    131u8, 148u8, 227u8, 131u8, 179u8, 227u8, 130u8, 164u8, 227u8, 131u8, 179u8,
+                    233u8, 160u8, 134u8, 227u8, 131u8, 170u8, 227u8, 131u8, 149u8, 227u8, 130u8,
+                    169u8, 227u8, 131u8, 188u8, 227u8, 131u8, 160u8, 230u8, 177u8, 142u8, 231u8,
+                    148u8, 168u8, 230u8, 164u8, 156u8, 231u8, 180u8, 162u8, 227u8, 131u8, 143u8,
+                    227u8, 131u8, 179u8, 227u8, 130u8, 176u8, 227u8, 131u8, 171u8, 232u8, 170u8,
+                    158u8, 233u8, 160u8, 173u8, 229u8, 173u8, 144u8, 233u8, 159u8, 179u8, 227u8,
+                    129u8, 171u8, 227u8, 130u8, 136u8, 227u8, 130u8, 139u8, 228u8, 184u8, 166u8,
+                    227u8, 129u8, 185u8, 230u8, 155u8, 191u8, 227u8, 129u8, 136u8, 230u8, 168u8,
+                    153u8, 230u8, 186u8, 150u8, 228u8, 184u8, 166u8, 227u8, 129u8, 185u8, 230u8,
+                    155u8, 191u8, 227u8, 129u8, 136u8, 233u8, 160u8, 134u8, 229u8, 186u8, 143u8,
+                    231u8, 148u8, 187u8, 230u8, 149u8, 176u8, 233u8, 160u8, 134u8, 227u8, 131u8,
+                    136u8, 227u8, 131u8, 169u8, 227u8, 131u8, 135u8, 227u8, 130u8, 163u8, 227u8,
+                    131u8, 131u8, 227u8, 130u8, 183u8, 227u8, 131u8, 167u8, 227u8, 131u8, 138u8,
+                    227u8, 131u8, 171u8, 233u8, 131u8, 168u8, 233u8, 166u8, 150u8, 233u8, 160u8,
+                    134u8, 230u8, 179u8, 168u8, 233u8, 159u8, 179u8, 233u8, 160u8, 134u8, 49u8,
+                    50u8, 230u8, 153u8, 130u8, 233u8, 150u8, 147u8, 229u8, 136u8, 182u8, 40u8,
+                    48u8, 227u8, 128u8, 156u8, 49u8, 49u8, 41u8, 49u8, 50u8, 230u8, 153u8, 130u8,
+                    233u8, 150u8, 147u8, 229u8, 136u8, 182u8, 40u8, 49u8, 227u8, 128u8, 156u8,
+                    49u8, 50u8, 41u8, 50u8, 52u8, 230u8, 153u8, 130u8, 233u8, 150u8, 147u8, 229u8,
+                    136u8, 182u8, 40u8, 48u8, 227u8, 128u8, 156u8, 50u8, 51u8, 41u8, 50u8, 52u8,
+                    230u8, 153u8, 130u8, 233u8, 150u8, 147u8, 229u8, 136u8, 182u8, 40u8, 49u8,
+                    227u8, 128u8, 156u8, 50u8, 52u8, 41u8, 232u8, 168u8, 152u8, 229u8, 143u8,
+                    183u8, 227u8, 129u8, 167u8, 228u8, 184u8, 166u8, 227u8, 129u8, 185u8, 230u8,
+                    155u8, 191u8, 227u8, 129u8, 136u8, 232u8, 168u8, 152u8, 229u8, 143u8, 183u8,
+                    227u8, 130u8, 146u8, 231u8, 132u8, 161u8, 232u8, 166u8, 150u8, 227u8, 129u8,
+                    151u8, 227u8, 129u8, 166u8, 228u8, 184u8, 166u8, 227u8, 129u8, 185u8, 230u8,
+                    155u8, 191u8, 227u8, 129u8, 136u8, 227u8, 130u8, 162u8, 227u8, 130u8, 175u8,
+                    227u8, 130u8, 187u8, 227u8, 131u8, 179u8, 227u8, 131u8, 136u8, 239u8, 188u8,
+                    136u8, 233u8, 160u8, 134u8, 230u8, 150u8, 185u8, 229u8, 144u8, 145u8, 239u8,
+                    188u8, 137u8, 227u8, 129u8, 167u8, 228u8, 184u8, 166u8, 227u8, 129u8, 185u8,
+                    230u8, 155u8, 191u8, 227u8, 129u8, 136u8, 227u8, 130u8, 162u8, 227u8, 130u8,
+                    175u8, 227u8, 130u8, 187u8, 227u8, 131u8, 179u8, 227u8, 131u8, 136u8, 239u8,
+                    188u8, 136u8, 233u8, 128u8, 134u8, 230u8, 150u8, 185u8, 229u8, 144u8, 145u8,
+                    239u8, 188u8, 137u8, 227u8, 129u8, 167u8, 228u8, 184u8, 166u8, 227u8, 129u8,
+                    185u8, 230u8, 155u8, 191u8, 227u8, 129u8, 136u8, 229u8, 164u8, 167u8, 230u8,
+                    150u8, 135u8, 229u8, 173u8, 151u8, 229u8, 176u8, 143u8, 230u8, 150u8, 135u8,
+                    229u8, 173u8, 151u8, 227u8, 130u8, 146u8, 229u8, 140u8, 186u8, 229u8, 136u8,
+                    165u8, 227u8, 129u8, 151u8, 227u8, 129u8, 170u8, 227u8, 129u8, 132u8, 227u8,
+                    129u8, 167u8, 228u8, 184u8, 166u8, 227u8, 129u8, 185u8, 230u8, 155u8, 191u8,
+                    227u8, 129u8, 136u8, 229u8, 164u8, 167u8, 230u8, 150u8, 135u8, 229u8, 173u8,
+                    151u8, 229u8, 176u8, 143u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8,
+                    130u8, 146u8, 229u8, 140u8, 186u8, 229u8, 136u8, 165u8, 227u8, 129u8, 151u8,
+                    227u8, 129u8, 166u8, 228u8, 184u8, 166u8, 227u8, 129u8, 185u8, 230u8, 155u8,
+                    191u8, 227u8, 129u8, 136u8, 229u8, 164u8, 167u8, 230u8, 150u8, 135u8, 229u8,
+                    173u8, 151u8, 229u8, 176u8, 143u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8,
+                    227u8, 130u8, 146u8, 233u8, 128u8, 154u8, 229u8, 184u8, 184u8, 227u8, 129u8,
+                    174u8, 233u8, 160u8, 134u8, 229u8, 186u8, 143u8, 227u8, 129u8, 167u8, 228u8,
+                    184u8, 166u8, 227u8, 129u8, 185u8, 230u8, 155u8, 191u8, 227u8, 129u8, 136u8,
+                    229u8, 176u8, 143u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 229u8, 132u8,
+                    170u8, 229u8, 133u8, 136u8, 227u8, 129u8, 167u8, 228u8, 184u8, 166u8, 227u8,
+                    129u8, 185u8, 230u8, 155u8, 191u8, 227u8, 129u8, 136u8, 229u8, 164u8, 167u8,
+                    230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 229u8, 132u8, 170u8, 229u8, 133u8,
+                    136u8, 227u8, 129u8, 167u8, 228u8, 184u8, 166u8, 227u8, 129u8, 185u8, 230u8,
+                    155u8, 191u8, 227u8, 129u8, 136u8, 230u8, 173u8, 163u8, 232u8, 166u8, 143u8,
+                    229u8, 140u8, 150u8, 227u8, 129u8, 151u8, 227u8, 129u8, 170u8, 227u8, 129u8,
+                    132u8, 227u8, 129u8, 167u8, 228u8, 184u8, 166u8, 227u8, 129u8, 185u8, 230u8,
+                    155u8, 191u8, 227u8, 129u8, 136u8, 85u8, 110u8, 105u8, 99u8, 111u8, 100u8,
+                    101u8, 32u8, 230u8, 173u8, 163u8, 232u8, 166u8, 143u8, 229u8, 140u8, 150u8,
+                    227u8, 129u8, 167u8, 228u8, 184u8, 166u8, 227u8, 129u8, 185u8, 230u8, 155u8,
+                    191u8, 227u8, 129u8, 136u8, 230u8, 149u8, 176u8, 229u8, 128u8, 164u8, 227u8,
+                    130u8, 146u8, 231u8, 139u8, 172u8, 231u8, 171u8, 139u8, 227u8, 129u8, 151u8,
+                    227u8, 129u8, 166u8, 228u8, 184u8, 166u8, 227u8, 129u8, 185u8, 230u8, 155u8,
+                    191u8, 227u8, 129u8, 136u8, 230u8, 149u8, 176u8, 229u8, 128u8, 164u8, 227u8,
+                    130u8, 146u8, 230u8, 149u8, 176u8, 229u8, 128u8, 164u8, 227u8, 129u8, 168u8,
+                    227u8, 129u8, 151u8, 227u8, 129u8, 166u8, 228u8, 184u8, 166u8, 227u8, 129u8,
+                    185u8, 230u8, 155u8, 191u8, 227u8, 129u8, 136u8, 227u8, 129u8, 153u8, 227u8,
+                    129u8, 185u8, 227u8, 129u8, 166u8, 227u8, 130u8, 146u8, 229u8, 140u8, 186u8,
+                    229u8, 136u8, 165u8, 227u8, 129u8, 151u8, 227u8, 129u8, 166u8, 228u8, 184u8,
+                    166u8, 227u8, 129u8, 185u8, 230u8, 155u8, 191u8, 227u8, 129u8, 136u8, 229u8,
+                    159u8, 186u8, 230u8, 156u8, 172u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8,
+                    227u8, 129u8, 174u8, 227u8, 129u8, 191u8, 227u8, 129u8, 167u8, 228u8, 184u8,
+                    166u8, 227u8, 129u8, 185u8, 230u8, 155u8, 191u8, 227u8, 129u8, 136u8, 227u8,
+                    130u8, 162u8, 227u8, 130u8, 175u8, 227u8, 130u8, 187u8, 227u8, 131u8, 179u8,
+                    227u8, 131u8, 136u8, 227u8, 129u8, 167u8, 228u8, 184u8, 166u8, 227u8, 129u8,
+                    185u8, 230u8, 155u8, 191u8, 227u8, 129u8, 136u8, 227u8, 130u8, 162u8, 227u8,
+                    130u8, 175u8, 227u8, 130u8, 187u8, 227u8, 131u8, 179u8, 227u8, 131u8, 136u8,
+                    47u8, 229u8, 164u8, 167u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 229u8,
+                    176u8, 143u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 47u8, 229u8, 133u8,
+                    168u8, 232u8, 167u8, 146u8, 229u8, 141u8, 138u8, 232u8, 167u8, 146u8, 227u8,
+                    129u8, 167u8, 228u8, 184u8, 166u8, 227u8, 129u8, 185u8, 230u8, 155u8, 191u8,
+                    227u8, 129u8, 136u8, 227u8, 130u8, 162u8, 227u8, 130u8, 175u8, 227u8, 130u8,
+                    187u8, 227u8, 131u8, 179u8, 227u8, 131u8, 136u8, 47u8, 229u8, 164u8, 167u8,
+                    230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 229u8, 176u8, 143u8, 230u8, 150u8,
+                    135u8, 229u8, 173u8, 151u8, 47u8, 229u8, 133u8, 168u8, 232u8, 167u8, 146u8,
+                    229u8, 141u8, 138u8, 232u8, 167u8, 146u8, 47u8, 228u8, 187u8, 174u8, 229u8,
+                    144u8, 141u8, 227u8, 129u8, 167u8, 228u8, 184u8, 166u8, 227u8, 129u8, 185u8,
+                    230u8, 155u8, 191u8, 227u8, 129u8, 136u8, 231u8, 166u8, 129u8, 229u8, 137u8,
+                    135u8, 229u8, 135u8, 166u8, 231u8, 144u8, 134u8, 40u8, 229u8, 188u8, 177u8,
+                    41u8, 231u8, 166u8, 129u8, 229u8, 137u8, 135u8, 229u8, 135u8, 166u8, 231u8,
+                    144u8, 134u8, 40u8, 230u8, 168u8, 153u8, 230u8, 186u8, 150u8, 41u8, 231u8,
+                    166u8, 129u8, 229u8, 137u8, 135u8, 229u8, 135u8, 166u8, 231u8, 144u8, 134u8,
+                    40u8, 229u8, 188u8, 183u8, 41u8, 227u8, 131u8, 161u8, 227u8, 131u8, 188u8,
+                    227u8, 131u8, 136u8, 227u8, 131u8, 171u8, 230u8, 179u8, 149u8, 227u8, 131u8,
+                    164u8, 227u8, 131u8, 188u8, 227u8, 131u8, 137u8, 227u8, 131u8, 187u8, 227u8,
+                    131u8, 157u8, 227u8, 131u8, 179u8, 227u8, 131u8, 137u8, 230u8, 179u8, 149u8,
+                    231u8, 177u8, 179u8, 230u8, 133u8, 163u8, 231u8, 191u8, 146u8, 229u8, 141u8,
+                    152u8, 228u8, 189u8, 141u8, 227u8, 130u8, 162u8, 227u8, 131u8, 169u8, 227u8,
+                    131u8, 147u8, 227u8, 130u8, 162u8, 227u8, 131u8, 187u8, 227u8, 130u8, 164u8,
+                    227u8, 131u8, 179u8, 227u8, 131u8, 137u8, 230u8, 149u8, 176u8, 229u8, 173u8,
+                    151u8, 227u8, 131u8, 154u8, 227u8, 131u8, 171u8, 227u8, 130u8, 183u8, 227u8,
+                    130u8, 162u8, 230u8, 149u8, 176u8, 229u8, 173u8, 151u8, 227u8, 130u8, 162u8,
+                    227u8, 131u8, 171u8, 227u8, 131u8, 161u8, 227u8, 131u8, 139u8, 227u8, 130u8,
+                    162u8, 230u8, 149u8, 176u8, 229u8, 173u8, 151u8, 227u8, 130u8, 162u8, 227u8,
+                    131u8, 171u8, 227u8, 131u8, 161u8, 227u8, 131u8, 139u8, 227u8, 130u8, 162u8,
+                    230u8, 149u8, 176u8, 229u8, 173u8, 151u8, 40u8, 229u8, 176u8, 143u8, 230u8,
+                    150u8, 135u8, 229u8, 173u8, 151u8, 41u8, 227u8, 131u8, 144u8, 227u8, 131u8,
+                    170u8, 230u8, 149u8, 176u8, 229u8, 173u8, 151u8, 227u8, 131u8, 153u8, 227u8,
+                    131u8, 179u8, 227u8, 130u8, 172u8, 227u8, 131u8, 171u8, 230u8, 149u8, 176u8,
+                    229u8, 173u8, 151u8, 227u8, 131u8, 150u8, 227u8, 131u8, 169u8, 227u8, 131u8,
+                    188u8, 227u8, 131u8, 149u8, 227u8, 131u8, 159u8, 227u8, 131u8, 188u8, 230u8,
+                    149u8, 176u8, 229u8, 173u8, 151u8, 227u8, 131u8, 129u8, 227u8, 131u8, 163u8,
+                    227u8, 130u8, 175u8, 227u8, 131u8, 158u8, 230u8, 149u8, 176u8, 229u8, 173u8,
+                    151u8, 227u8, 131u8, 129u8, 227u8, 131u8, 163u8, 227u8, 131u8, 160u8, 230u8,
+                    149u8, 176u8, 229u8, 173u8, 151u8, 227u8, 131u8, 135u8, 227u8, 131u8, 188u8,
+                    227u8, 131u8, 144u8, 227u8, 131u8, 138u8, 227u8, 131u8, 188u8, 227u8, 130u8,
+                    172u8, 227u8, 131u8, 170u8, 227u8, 131u8, 188u8, 230u8, 149u8, 176u8, 229u8,
+                    173u8, 151u8, 227u8, 130u8, 168u8, 227u8, 131u8, 129u8, 227u8, 130u8, 170u8,
+                    227u8, 131u8, 148u8, 227u8, 130u8, 162u8, 230u8, 149u8, 176u8, 229u8, 173u8,
+                    151u8, 232u8, 178u8, 161u8, 229u8, 139u8, 153u8, 231u8, 148u8, 168u8, 230u8,
+                    188u8, 162u8, 230u8, 149u8, 176u8, 229u8, 173u8, 151u8, 229u8, 133u8, 168u8,
+                    232u8, 167u8, 146u8, 230u8, 149u8, 176u8, 229u8, 173u8, 151u8, 227u8, 130u8,
+                    184u8, 227u8, 131u8, 167u8, 227u8, 131u8, 188u8, 227u8, 130u8, 184u8, 227u8,
+                    130u8, 162u8, 230u8, 149u8, 176u8, 229u8, 173u8, 151u8, 227u8, 130u8, 174u8,
+                    227u8, 131u8, 170u8, 227u8, 130u8, 183u8, 227u8, 131u8, 163u8, 230u8, 149u8,
+                    176u8, 229u8, 173u8, 151u8, 227u8, 130u8, 174u8, 227u8, 131u8, 170u8, 227u8,
+                    130u8, 183u8, 227u8, 131u8, 163u8, 230u8, 149u8, 176u8, 229u8, 173u8, 151u8,
+                    40u8, 229u8, 176u8, 143u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 41u8,
+                    227u8, 130u8, 176u8, 227u8, 130u8, 184u8, 227u8, 131u8, 163u8, 227u8, 131u8,
+                    169u8, 227u8, 131u8, 188u8, 227u8, 131u8, 136u8, 230u8, 149u8, 176u8, 229u8,
+                    173u8, 151u8, 227u8, 130u8, 176u8, 227u8, 131u8, 171u8, 227u8, 131u8, 160u8,
+                    227u8, 130u8, 173u8, 227u8, 131u8, 188u8, 230u8, 149u8, 176u8, 229u8, 173u8,
+                    151u8, 230u8, 188u8, 162u8, 230u8, 149u8, 176u8, 229u8, 173u8, 151u8, 40u8,
+                    228u8, 189u8, 141u8, 229u8, 143u8, 150u8, 227u8, 130u8, 138u8, 232u8, 168u8,
+                    152u8, 230u8, 149u8, 176u8, 230u8, 179u8, 149u8, 41u8, 231u8, 176u8, 161u8,
+                    228u8, 189u8, 147u8, 230u8, 188u8, 162u8, 230u8, 149u8, 176u8, 229u8, 173u8,
+                    151u8, 231u8, 176u8, 161u8, 228u8, 189u8, 147u8, 229u8, 164u8, 167u8, 229u8,
+                    173u8, 151u8, 231u8, 185u8, 129u8, 228u8, 189u8, 147u8, 230u8, 188u8, 162u8,
+                    230u8, 149u8, 176u8, 229u8, 173u8, 151u8, 231u8, 185u8, 129u8, 228u8, 189u8,
+                    147u8, 229u8, 164u8, 167u8, 229u8, 173u8, 151u8, 227u8, 131u8, 152u8, 227u8,
+                    131u8, 150u8, 227u8, 131u8, 169u8, 227u8, 130u8, 164u8, 230u8, 149u8, 176u8,
+                    229u8, 173u8, 151u8, 227u8, 130u8, 184u8, 227u8, 131u8, 163u8, 227u8, 131u8,
+                    175u8, 230u8, 149u8, 176u8, 229u8, 173u8, 151u8, 230u8, 188u8, 162u8, 230u8,
+                    149u8, 176u8, 229u8, 173u8, 151u8, 229u8, 164u8, 167u8, 229u8, 173u8, 151u8,
+                    227u8, 130u8, 171u8, 227u8, 131u8, 164u8, 227u8, 131u8, 188u8, 230u8, 149u8,
+                    176u8, 229u8, 173u8, 151u8, 227u8, 130u8, 175u8, 227u8, 131u8, 161u8, 227u8,
+                    131u8, 188u8, 227u8, 131u8, 171u8, 230u8, 149u8, 176u8, 229u8, 173u8, 151u8,
+                    227u8, 130u8, 171u8, 227u8, 131u8, 179u8, 227u8, 131u8, 138u8, 227u8, 131u8,
+                    128u8, 230u8, 149u8, 176u8, 229u8, 173u8, 151u8, 227u8, 131u8, 169u8, 227u8,
+                    131u8, 188u8, 227u8, 131u8, 179u8, 227u8, 131u8, 138u8, 227u8, 131u8, 188u8,
+                    230u8, 149u8, 176u8, 229u8, 173u8, 151u8, 227u8, 131u8, 169u8, 227u8, 131u8,
+                    188u8, 227u8, 131u8, 179u8, 227u8, 131u8, 138u8, 227u8, 131u8, 188u8, 227u8,
+                    131u8, 187u8, 227u8, 130u8, 191u8, 227u8, 131u8, 160u8, 230u8, 149u8, 176u8,
+                    229u8, 173u8, 151u8, 227u8, 131u8, 169u8, 227u8, 130u8, 170u8, 230u8, 149u8,
+                    176u8, 229u8, 173u8, 151u8, 231u8, 174u8, 151u8, 231u8, 148u8, 168u8, 230u8,
+                    149u8, 176u8, 229u8, 173u8, 151u8, 227u8, 131u8, 172u8, 227u8, 131u8, 151u8,
+                    227u8, 131u8, 129u8, 227u8, 131u8, 163u8, 230u8, 149u8, 176u8, 229u8, 173u8,
+                    151u8, 227u8, 131u8, 170u8, 227u8, 131u8, 179u8, 227u8, 131u8, 150u8, 230u8,
+                    149u8, 176u8, 229u8, 173u8, 151u8, 227u8, 131u8, 158u8, 227u8, 131u8, 169u8,
+                    227u8, 131u8, 164u8, 227u8, 131u8, 188u8, 227u8, 131u8, 169u8, 227u8, 131u8,
+                    160u8, 230u8, 149u8, 176u8, 229u8, 173u8, 151u8, 227u8, 131u8, 162u8, 227u8,
+                    131u8, 179u8, 227u8, 130u8, 180u8, 227u8, 131u8, 171u8, 230u8, 149u8, 176u8,
+                    229u8, 173u8, 151u8, 227u8, 131u8, 158u8, 227u8, 131u8, 139u8, 227u8, 131u8,
+                    151u8, 227u8, 131u8, 170u8, 230u8, 149u8, 176u8, 229u8, 173u8, 151u8, 227u8,
+                    131u8, 159u8, 227u8, 131u8, 163u8, 227u8, 131u8, 179u8, 227u8, 131u8, 158u8,
+                    227u8, 131u8, 188u8, 230u8, 149u8, 176u8, 229u8, 173u8, 151u8, 227u8, 131u8,
+                    159u8, 227u8, 131u8, 163u8, 227u8, 131u8, 179u8, 227u8, 131u8, 158u8, 227u8,
+                    131u8, 188u8, 227u8, 131u8, 187u8, 227u8, 130u8, 183u8, 227u8, 131u8, 163u8,
+                    227u8, 131u8, 179u8, 230u8, 149u8, 176u8, 229u8, 173u8, 151u8, 231u8, 139u8,
+                    172u8, 232u8, 135u8, 170u8, 227u8, 129u8, 174u8, 232u8, 168u8, 152u8, 230u8,
+                    149u8, 176u8, 230u8, 179u8, 149u8, 227u8, 131u8, 179u8, 227u8, 130u8, 179u8,
+                    230u8, 149u8, 176u8, 229u8, 173u8, 151u8, 227u8, 130u8, 170u8, 227u8, 131u8,
+                    171u8, 227u8, 131u8, 129u8, 227u8, 130u8, 173u8, 230u8, 149u8, 176u8, 229u8,
+                    173u8, 151u8, 227u8, 130u8, 170u8, 227u8, 131u8, 135u8, 227u8, 130u8, 163u8,
+                    227u8, 130u8, 162u8, 230u8, 149u8, 176u8, 229u8, 173u8, 151u8, 227u8, 130u8,
+                    170u8, 227u8, 130u8, 185u8, 227u8, 131u8, 158u8, 227u8, 131u8, 139u8, 227u8,
+                    130u8, 162u8, 230u8, 149u8, 176u8, 229u8, 173u8, 151u8, 227u8, 131u8, 173u8,
+                    227u8, 131u8, 188u8, 227u8, 131u8, 158u8, 230u8, 149u8, 176u8, 229u8, 173u8,
+                    151u8, 227u8, 131u8, 173u8, 227u8, 131u8, 188u8, 227u8, 131u8, 158u8, 230u8,
+                    149u8, 176u8, 229u8, 173u8, 151u8, 40u8, 229u8, 176u8, 143u8, 230u8, 150u8,
+                    135u8, 229u8, 173u8, 151u8, 41u8, 227u8, 130u8, 181u8, 227u8, 130u8, 166u8,
+                    227u8, 131u8, 169u8, 227u8, 131u8, 188u8, 227u8, 130u8, 183u8, 227u8, 131u8,
+                    165u8, 227u8, 131u8, 136u8, 227u8, 131u8, 169u8, 230u8, 149u8, 176u8, 229u8,
+                    173u8, 151u8, 227u8, 130u8, 183u8, 227u8, 131u8, 163u8, 227u8, 131u8, 188u8,
+                    227u8, 131u8, 169u8, 227u8, 131u8, 128u8, 227u8, 131u8, 188u8, 230u8, 149u8,
+                    176u8, 229u8, 173u8, 151u8, 227u8, 130u8, 189u8, 227u8, 131u8, 169u8, 227u8,
+                    131u8, 187u8, 227u8, 130u8, 189u8, 227u8, 131u8, 179u8, 227u8, 131u8, 154u8,
+                    227u8, 131u8, 179u8, 230u8, 149u8, 176u8, 229u8, 173u8, 151u8, 227u8, 130u8,
+                    185u8, 227u8, 131u8, 179u8, 227u8, 131u8, 128u8, 230u8, 149u8, 176u8, 229u8,
+                    173u8, 151u8, 227u8, 130u8, 191u8, 227u8, 131u8, 188u8, 227u8, 130u8, 175u8,
+                    227u8, 131u8, 170u8, 227u8, 131u8, 188u8, 230u8, 149u8, 176u8, 229u8, 173u8,
+                    151u8, 230u8, 150u8, 176u8, 227u8, 130u8, 191u8, 227u8, 130u8, 164u8, 227u8,
+                    131u8, 187u8, 227u8, 131u8, 171u8, 227u8, 131u8, 188u8, 230u8, 149u8, 176u8,
+                    229u8, 173u8, 151u8, 228u8, 188u8, 157u8, 231u8, 181u8, 177u8, 231u8, 154u8,
+                    132u8, 227u8, 130u8, 191u8, 227u8, 131u8, 159u8, 227u8, 131u8, 171u8, 230u8,
+                    149u8, 176u8, 229u8, 173u8, 151u8, 227u8, 130u8, 191u8, 227u8, 131u8, 159u8,
+                    227u8, 131u8, 171u8, 230u8, 149u8, 176u8, 229u8, 173u8, 151u8, 227u8, 131u8,
+                    134u8, 227u8, 131u8, 171u8, 227u8, 130u8, 176u8, 230u8, 149u8, 176u8, 229u8,
+                    173u8, 151u8, 227u8, 130u8, 191u8, 227u8, 130u8, 164u8, 230u8, 149u8, 176u8,
+                    229u8, 173u8, 151u8, 227u8, 131u8, 129u8, 227u8, 131u8, 153u8, 227u8, 131u8,
+                    131u8, 227u8, 131u8, 136u8, 230u8, 149u8, 176u8, 229u8, 173u8, 151u8, 229u8,
+                    190u8, 147u8, 230u8, 157u8, 165u8, 227u8, 129u8, 174u8, 232u8, 168u8, 152u8,
+                    230u8, 149u8, 176u8, 230u8, 179u8, 149u8, 227u8, 131u8, 180u8, 227u8, 130u8,
+                    161u8, 227u8, 130u8, 164u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8,
+                    129u8, 174u8, 232u8, 168u8, 152u8, 230u8, 149u8, 176u8, 230u8, 179u8, 149u8,
+                ])
+            },
+        )
+    },
+};
+static RU: &DataStruct = &::icu_displaynames::provider::KeyDisplayNamesV1 {
+    keys: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    99u8, 97u8, 99u8, 102u8, 99u8, 111u8, 99u8, 117u8, 104u8, 99u8, 107u8, 97u8,
+                    107u8, 98u8, 107u8, 99u8, 107u8, 102u8, 107u8, 107u8, 107u8, 110u8, 107u8,
+                    115u8, 108u8, 98u8, 109u8, 115u8, 110u8, 117u8, 116u8, 122u8, 118u8, 97u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    17u8, 0u8, 0u8, 0u8, 0u8, 0u8, 18u8, 0u8, 43u8, 0u8, 78u8, 0u8, 90u8, 0u8,
+                    148u8, 0u8, 203u8, 0u8, 4u8, 1u8, 57u8, 1u8, 138u8, 1u8, 189u8, 1u8, 226u8,
+                    1u8, 17u8, 2u8, 57u8, 2u8, 78u8, 2u8, 88u8, 2u8, 111u8, 2u8, 208u8, 186u8,
+                    208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8, 189u8, 208u8, 180u8, 208u8,
+                    176u8, 209u8, 128u8, 209u8, 140u8, 209u8, 132u8, 208u8, 190u8, 209u8, 128u8,
+                    208u8, 188u8, 208u8, 176u8, 209u8, 130u8, 32u8, 208u8, 178u8, 208u8, 176u8,
+                    208u8, 187u8, 209u8, 142u8, 209u8, 130u8, 209u8, 139u8, 208u8, 191u8, 208u8,
+                    190u8, 209u8, 128u8, 209u8, 143u8, 208u8, 180u8, 208u8, 190u8, 208u8, 186u8,
+                    32u8, 209u8, 129u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 208u8, 184u8,
+                    209u8, 128u8, 208u8, 190u8, 208u8, 178u8, 208u8, 186u8, 208u8, 184u8, 208u8,
+                    178u8, 208u8, 176u8, 208u8, 187u8, 209u8, 142u8, 209u8, 130u8, 208u8, 176u8,
+                    209u8, 132u8, 208u8, 190u8, 209u8, 128u8, 208u8, 188u8, 208u8, 176u8, 209u8,
+                    130u8, 32u8, 208u8, 178u8, 209u8, 128u8, 208u8, 181u8, 208u8, 188u8, 208u8,
+                    181u8, 208u8, 189u8, 208u8, 184u8, 32u8, 40u8, 49u8, 50u8, 45u8, 32u8, 208u8,
+                    184u8, 208u8, 187u8, 208u8, 184u8, 32u8, 50u8, 52u8, 45u8, 209u8, 135u8, 208u8,
+                    176u8, 209u8, 129u8, 208u8, 190u8, 208u8, 178u8, 208u8, 190u8, 208u8, 185u8,
+                    41u8, 209u8, 129u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 208u8, 184u8,
+                    209u8, 128u8, 208u8, 190u8, 208u8, 178u8, 208u8, 186u8, 208u8, 176u8, 32u8,
+                    208u8, 177u8, 208u8, 181u8, 208u8, 183u8, 32u8, 209u8, 131u8, 209u8, 135u8,
+                    208u8, 181u8, 209u8, 130u8, 208u8, 176u8, 32u8, 209u8, 129u8, 208u8, 184u8,
+                    208u8, 188u8, 208u8, 178u8, 208u8, 190u8, 208u8, 187u8, 208u8, 190u8, 208u8,
+                    178u8, 208u8, 190u8, 208u8, 177u8, 209u8, 128u8, 208u8, 176u8, 209u8, 130u8,
+                    208u8, 189u8, 208u8, 176u8, 209u8, 143u8, 32u8, 209u8, 129u8, 208u8, 190u8,
+                    209u8, 128u8, 209u8, 130u8, 208u8, 184u8, 209u8, 128u8, 208u8, 190u8, 208u8,
+                    178u8, 208u8, 186u8, 208u8, 176u8, 32u8, 208u8, 191u8, 208u8, 190u8, 32u8,
+                    208u8, 176u8, 208u8, 186u8, 209u8, 134u8, 208u8, 181u8, 208u8, 189u8, 209u8,
+                    130u8, 209u8, 131u8, 209u8, 129u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8,
+                    208u8, 184u8, 209u8, 128u8, 208u8, 190u8, 208u8, 178u8, 208u8, 186u8, 208u8,
+                    176u8, 32u8, 209u8, 129u8, 32u8, 209u8, 131u8, 209u8, 135u8, 208u8, 181u8,
+                    209u8, 130u8, 208u8, 190u8, 208u8, 188u8, 32u8, 209u8, 128u8, 208u8, 181u8,
+                    208u8, 179u8, 208u8, 184u8, 209u8, 129u8, 209u8, 130u8, 209u8, 128u8, 208u8,
+                    176u8, 209u8, 129u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 208u8, 184u8,
+                    209u8, 128u8, 208u8, 190u8, 208u8, 178u8, 208u8, 186u8, 208u8, 176u8, 32u8,
+                    208u8, 191u8, 208u8, 190u8, 32u8, 208u8, 178u8, 208u8, 181u8, 209u8, 128u8,
+                    209u8, 133u8, 208u8, 189u8, 208u8, 181u8, 208u8, 188u8, 209u8, 131u8, 32u8,
+                    208u8, 184u8, 208u8, 187u8, 208u8, 184u8, 32u8, 208u8, 189u8, 208u8, 184u8,
+                    208u8, 182u8, 208u8, 189u8, 208u8, 181u8, 208u8, 188u8, 209u8, 131u8, 32u8,
+                    209u8, 128u8, 208u8, 181u8, 208u8, 179u8, 208u8, 184u8, 209u8, 129u8, 209u8,
+                    130u8, 209u8, 128u8, 209u8, 131u8, 208u8, 189u8, 208u8, 190u8, 209u8, 128u8,
+                    208u8, 188u8, 208u8, 176u8, 208u8, 187u8, 208u8, 184u8, 208u8, 183u8, 208u8,
+                    190u8, 208u8, 178u8, 208u8, 176u8, 208u8, 189u8, 208u8, 189u8, 208u8, 176u8,
+                    209u8, 143u8, 32u8, 209u8, 129u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8,
+                    208u8, 184u8, 209u8, 128u8, 208u8, 190u8, 208u8, 178u8, 208u8, 186u8, 208u8,
+                    176u8, 209u8, 135u8, 208u8, 184u8, 209u8, 129u8, 208u8, 187u8, 208u8, 190u8,
+                    208u8, 178u8, 208u8, 176u8, 209u8, 143u8, 32u8, 209u8, 129u8, 208u8, 190u8,
+                    209u8, 128u8, 209u8, 130u8, 208u8, 184u8, 209u8, 128u8, 208u8, 190u8, 208u8,
+                    178u8, 208u8, 186u8, 208u8, 176u8, 209u8, 141u8, 209u8, 132u8, 209u8, 132u8,
+                    208u8, 181u8, 208u8, 186u8, 209u8, 130u8, 208u8, 184u8, 208u8, 178u8, 208u8,
+                    189u8, 208u8, 190u8, 209u8, 129u8, 209u8, 130u8, 209u8, 140u8, 32u8, 209u8,
+                    129u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 208u8, 184u8, 209u8, 128u8,
+                    208u8, 190u8, 208u8, 178u8, 208u8, 186u8, 208u8, 184u8, 209u8, 129u8, 209u8,
+                    130u8, 208u8, 184u8, 208u8, 187u8, 209u8, 140u8, 32u8, 208u8, 191u8, 208u8,
+                    181u8, 209u8, 128u8, 208u8, 181u8, 208u8, 178u8, 208u8, 190u8, 208u8, 180u8,
+                    208u8, 176u8, 32u8, 209u8, 129u8, 209u8, 130u8, 209u8, 128u8, 208u8, 190u8,
+                    208u8, 186u8, 208u8, 184u8, 209u8, 129u8, 208u8, 184u8, 209u8, 129u8, 209u8,
+                    130u8, 208u8, 181u8, 208u8, 188u8, 208u8, 176u8, 32u8, 208u8, 188u8, 208u8,
+                    181u8, 209u8, 128u8, 209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8,
+                    209u8, 139u8, 209u8, 135u8, 208u8, 176u8, 209u8, 129u8, 208u8, 190u8, 208u8,
+                    178u8, 208u8, 190u8, 208u8, 185u8, 32u8, 208u8, 191u8, 208u8, 190u8, 209u8,
+                    143u8, 209u8, 129u8, 208u8, 178u8, 208u8, 176u8, 209u8, 128u8, 208u8, 184u8,
+                    208u8, 176u8, 208u8, 189u8, 209u8, 130u8, 32u8, 209u8, 128u8, 208u8, 181u8,
+                    208u8, 179u8, 208u8, 184u8, 208u8, 190u8, 208u8, 189u8, 208u8, 176u8, 208u8,
+                    187u8, 209u8, 140u8, 208u8, 189u8, 209u8, 139u8, 209u8, 133u8, 32u8, 208u8,
+                    189u8, 208u8, 176u8, 209u8, 129u8, 209u8, 130u8, 209u8, 128u8, 208u8, 190u8,
+                    208u8, 181u8, 208u8, 186u8,
+                ])
+            },
+        )
+    },
+    types: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    127u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 21u8, 0u8, 30u8, 0u8, 38u8, 0u8,
+                    48u8, 0u8, 59u8, 0u8, 69u8, 0u8, 78u8, 0u8, 87u8, 0u8, 97u8, 0u8, 113u8, 0u8,
+                    128u8, 0u8, 143u8, 0u8, 162u8, 0u8, 172u8, 0u8, 183u8, 0u8, 193u8, 0u8, 199u8,
+                    0u8, 209u8, 0u8, 220u8, 0u8, 230u8, 0u8, 239u8, 0u8, 246u8, 0u8, 254u8, 0u8,
+                    6u8, 1u8, 12u8, 1u8, 21u8, 1u8, 31u8, 1u8, 42u8, 1u8, 51u8, 1u8, 62u8, 1u8,
+                    71u8, 1u8, 82u8, 1u8, 93u8, 1u8, 102u8, 1u8, 109u8, 1u8, 118u8, 1u8, 127u8,
+                    1u8, 133u8, 1u8, 139u8, 1u8, 145u8, 1u8, 151u8, 1u8, 162u8, 1u8, 172u8, 1u8,
+                    180u8, 1u8, 187u8, 1u8, 195u8, 1u8, 202u8, 1u8, 210u8, 1u8, 218u8, 1u8, 226u8,
+                    1u8, 234u8, 1u8, 241u8, 1u8, 249u8, 1u8, 0u8, 2u8, 10u8, 2u8, 19u8, 2u8, 28u8,
+                    2u8, 37u8, 2u8, 46u8, 2u8, 54u8, 2u8, 63u8, 2u8, 72u8, 2u8, 81u8, 2u8, 92u8,
+                    2u8, 103u8, 2u8, 110u8, 2u8, 120u8, 2u8, 127u8, 2u8, 137u8, 2u8, 144u8, 2u8,
+                    151u8, 2u8, 158u8, 2u8, 165u8, 2u8, 172u8, 2u8, 179u8, 2u8, 186u8, 2u8, 196u8,
+                    2u8, 207u8, 2u8, 214u8, 2u8, 221u8, 2u8, 231u8, 2u8, 238u8, 2u8, 245u8, 2u8,
+                    255u8, 2u8, 6u8, 3u8, 16u8, 3u8, 23u8, 3u8, 33u8, 3u8, 40u8, 3u8, 47u8, 3u8,
+                    54u8, 3u8, 64u8, 3u8, 71u8, 3u8, 78u8, 3u8, 85u8, 3u8, 92u8, 3u8, 103u8, 3u8,
+                    110u8, 3u8, 117u8, 3u8, 124u8, 3u8, 131u8, 3u8, 138u8, 3u8, 145u8, 3u8, 152u8,
+                    3u8, 159u8, 3u8, 170u8, 3u8, 179u8, 3u8, 186u8, 3u8, 193u8, 3u8, 200u8, 3u8,
+                    207u8, 3u8, 215u8, 3u8, 226u8, 3u8, 233u8, 3u8, 240u8, 3u8, 247u8, 3u8, 254u8,
+                    3u8, 5u8, 4u8, 12u8, 4u8, 19u8, 4u8, 29u8, 4u8, 36u8, 4u8, 43u8, 4u8, 50u8,
+                    4u8, 61u8, 4u8, 99u8, 97u8, 45u8, 98u8, 117u8, 100u8, 100u8, 104u8, 105u8,
+                    115u8, 116u8, 99u8, 97u8, 45u8, 99u8, 104u8, 105u8, 110u8, 101u8, 115u8, 101u8,
+                    99u8, 97u8, 45u8, 99u8, 111u8, 112u8, 116u8, 105u8, 99u8, 99u8, 97u8, 45u8,
+                    100u8, 97u8, 110u8, 103u8, 105u8, 99u8, 97u8, 45u8, 101u8, 116u8, 104u8, 105u8,
+                    111u8, 97u8, 97u8, 99u8, 97u8, 45u8, 101u8, 116u8, 104u8, 105u8, 111u8, 112u8,
+                    105u8, 99u8, 99u8, 97u8, 45u8, 103u8, 114u8, 101u8, 103u8, 111u8, 114u8, 121u8,
+                    99u8, 97u8, 45u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 99u8, 97u8, 45u8,
+                    105u8, 110u8, 100u8, 105u8, 97u8, 110u8, 99u8, 97u8, 45u8, 105u8, 115u8, 108u8,
+                    97u8, 109u8, 105u8, 99u8, 99u8, 97u8, 45u8, 105u8, 115u8, 108u8, 97u8, 109u8,
+                    105u8, 99u8, 45u8, 99u8, 105u8, 118u8, 105u8, 108u8, 99u8, 97u8, 45u8, 105u8,
+                    115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 114u8, 103u8, 115u8, 97u8, 99u8,
+                    97u8, 45u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 116u8, 98u8,
+                    108u8, 97u8, 99u8, 97u8, 45u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8,
+                    45u8, 117u8, 109u8, 97u8, 108u8, 113u8, 117u8, 114u8, 97u8, 99u8, 97u8, 45u8,
+                    105u8, 115u8, 111u8, 56u8, 54u8, 48u8, 49u8, 99u8, 97u8, 45u8, 106u8, 97u8,
+                    112u8, 97u8, 110u8, 101u8, 115u8, 101u8, 99u8, 97u8, 45u8, 112u8, 101u8, 114u8,
+                    115u8, 105u8, 97u8, 110u8, 99u8, 97u8, 45u8, 114u8, 111u8, 99u8, 99u8, 102u8,
+                    45u8, 97u8, 99u8, 99u8, 111u8, 117u8, 110u8, 116u8, 99u8, 102u8, 45u8, 115u8,
+                    116u8, 97u8, 110u8, 100u8, 97u8, 114u8, 100u8, 99u8, 111u8, 45u8, 98u8, 105u8,
+                    103u8, 53u8, 104u8, 97u8, 110u8, 99u8, 111u8, 45u8, 99u8, 111u8, 109u8, 112u8,
+                    97u8, 116u8, 99u8, 111u8, 45u8, 100u8, 105u8, 99u8, 116u8, 99u8, 111u8, 45u8,
+                    100u8, 117u8, 99u8, 101u8, 116u8, 99u8, 111u8, 45u8, 101u8, 109u8, 111u8,
+                    106u8, 105u8, 99u8, 111u8, 45u8, 101u8, 111u8, 114u8, 99u8, 111u8, 45u8, 103u8,
+                    98u8, 50u8, 51u8, 49u8, 50u8, 99u8, 111u8, 45u8, 112u8, 104u8, 111u8, 110u8,
+                    101u8, 98u8, 107u8, 99u8, 111u8, 45u8, 112u8, 104u8, 111u8, 110u8, 101u8,
+                    116u8, 105u8, 99u8, 99u8, 111u8, 45u8, 112u8, 105u8, 110u8, 121u8, 105u8,
+                    110u8, 99u8, 111u8, 45u8, 114u8, 101u8, 102u8, 111u8, 114u8, 109u8, 101u8,
+                    100u8, 99u8, 111u8, 45u8, 115u8, 101u8, 97u8, 114u8, 99u8, 104u8, 99u8, 111u8,
+                    45u8, 115u8, 101u8, 97u8, 114u8, 99u8, 104u8, 106u8, 108u8, 99u8, 111u8, 45u8,
+                    115u8, 116u8, 97u8, 110u8, 100u8, 97u8, 114u8, 100u8, 99u8, 111u8, 45u8, 115u8,
+                    116u8, 114u8, 111u8, 107u8, 101u8, 99u8, 111u8, 45u8, 116u8, 114u8, 97u8,
+                    100u8, 99u8, 111u8, 45u8, 117u8, 110u8, 105u8, 104u8, 97u8, 110u8, 99u8, 111u8,
+                    45u8, 122u8, 104u8, 117u8, 121u8, 105u8, 110u8, 104u8, 99u8, 45u8, 104u8, 49u8,
+                    49u8, 104u8, 99u8, 45u8, 104u8, 49u8, 50u8, 104u8, 99u8, 45u8, 104u8, 50u8,
+                    51u8, 104u8, 99u8, 45u8, 104u8, 50u8, 52u8, 107u8, 97u8, 45u8, 110u8, 111u8,
+                    105u8, 103u8, 110u8, 111u8, 114u8, 101u8, 107u8, 97u8, 45u8, 115u8, 104u8,
+                    105u8, 102u8, 116u8, 101u8, 100u8, 107u8, 98u8, 45u8, 102u8, 97u8, 108u8,
+                    115u8, 101u8, 107u8, 98u8, 45u8, 116u8, 114u8, 117u8, 101u8, 107u8, 99u8, 45u8,
+                    102u8, 97u8, 108u8, 115u8, 101u8, 107u8, 99u8, 45u8, 116u8, 114u8, 117u8,
+                    101u8, 107u8, 102u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8, 107u8, 102u8,
+                    45u8, 108u8, 111u8, 119u8, 101u8, 114u8, 107u8, 102u8, 45u8, 117u8, 112u8,
+                    112u8, 101u8, 114u8, 107u8, 107u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8,
+                    107u8, 107u8, 45u8, 116u8, 114u8, 117u8, 101u8, 107u8, 110u8, 45u8, 102u8,
+                    97u8, 108u8, 115u8, 101u8, 107u8, 110u8, 45u8, 116u8, 114u8, 117u8, 101u8,
+                    107u8, 115u8, 45u8, 105u8, 100u8, 101u8, 110u8, 116u8, 105u8, 99u8, 107u8,
+                    115u8, 45u8, 108u8, 101u8, 118u8, 101u8, 108u8, 49u8, 107u8, 115u8, 45u8,
+                    108u8, 101u8, 118u8, 101u8, 108u8, 50u8, 107u8, 115u8, 45u8, 108u8, 101u8,
+                    118u8, 101u8, 108u8, 51u8, 107u8, 115u8, 45u8, 108u8, 101u8, 118u8, 101u8,
+                    108u8, 52u8, 108u8, 98u8, 45u8, 108u8, 111u8, 111u8, 115u8, 101u8, 108u8, 98u8,
+                    45u8, 110u8, 111u8, 114u8, 109u8, 97u8, 108u8, 108u8, 98u8, 45u8, 115u8, 116u8,
+                    114u8, 105u8, 99u8, 116u8, 109u8, 115u8, 45u8, 109u8, 101u8, 116u8, 114u8,
+                    105u8, 99u8, 109u8, 115u8, 45u8, 117u8, 107u8, 115u8, 121u8, 115u8, 116u8,
+                    101u8, 109u8, 109u8, 115u8, 45u8, 117u8, 115u8, 115u8, 121u8, 115u8, 116u8,
+                    101u8, 109u8, 110u8, 117u8, 45u8, 97u8, 114u8, 97u8, 98u8, 110u8, 117u8, 45u8,
+                    97u8, 114u8, 97u8, 98u8, 101u8, 120u8, 116u8, 110u8, 117u8, 45u8, 97u8, 114u8,
+                    109u8, 110u8, 110u8, 117u8, 45u8, 97u8, 114u8, 109u8, 110u8, 108u8, 111u8,
+                    119u8, 110u8, 117u8, 45u8, 98u8, 97u8, 108u8, 105u8, 110u8, 117u8, 45u8, 98u8,
+                    101u8, 110u8, 103u8, 110u8, 117u8, 45u8, 98u8, 114u8, 97u8, 104u8, 110u8,
+                    117u8, 45u8, 99u8, 97u8, 107u8, 109u8, 110u8, 117u8, 45u8, 99u8, 104u8, 97u8,
+                    109u8, 110u8, 117u8, 45u8, 100u8, 101u8, 118u8, 97u8, 110u8, 117u8, 45u8,
+                    101u8, 116u8, 104u8, 105u8, 110u8, 117u8, 45u8, 102u8, 105u8, 110u8, 97u8,
+                    110u8, 99u8, 101u8, 110u8, 117u8, 45u8, 102u8, 117u8, 108u8, 108u8, 119u8,
+                    105u8, 100u8, 101u8, 110u8, 117u8, 45u8, 103u8, 101u8, 111u8, 114u8, 110u8,
+                    117u8, 45u8, 103u8, 114u8, 101u8, 107u8, 110u8, 117u8, 45u8, 103u8, 114u8,
+                    101u8, 107u8, 108u8, 111u8, 119u8, 110u8, 117u8, 45u8, 103u8, 117u8, 106u8,
+                    114u8, 110u8, 117u8, 45u8, 103u8, 117u8, 114u8, 117u8, 110u8, 117u8, 45u8,
+                    104u8, 97u8, 110u8, 105u8, 100u8, 101u8, 99u8, 110u8, 117u8, 45u8, 104u8, 97u8,
+                    110u8, 115u8, 110u8, 117u8, 45u8, 104u8, 97u8, 110u8, 115u8, 102u8, 105u8,
+                    110u8, 110u8, 117u8, 45u8, 104u8, 97u8, 110u8, 116u8, 110u8, 117u8, 45u8,
+                    104u8, 97u8, 110u8, 116u8, 102u8, 105u8, 110u8, 110u8, 117u8, 45u8, 104u8,
+                    101u8, 98u8, 114u8, 110u8, 117u8, 45u8, 106u8, 97u8, 118u8, 97u8, 110u8, 117u8,
+                    45u8, 106u8, 112u8, 97u8, 110u8, 110u8, 117u8, 45u8, 106u8, 112u8, 97u8, 110u8,
+                    102u8, 105u8, 110u8, 110u8, 117u8, 45u8, 107u8, 97u8, 108u8, 105u8, 110u8,
+                    117u8, 45u8, 107u8, 104u8, 109u8, 114u8, 110u8, 117u8, 45u8, 107u8, 110u8,
+                    100u8, 97u8, 110u8, 117u8, 45u8, 108u8, 97u8, 110u8, 97u8, 110u8, 117u8, 45u8,
+                    108u8, 97u8, 110u8, 97u8, 116u8, 104u8, 97u8, 109u8, 110u8, 117u8, 45u8, 108u8,
+                    97u8, 111u8, 111u8, 110u8, 117u8, 45u8, 108u8, 97u8, 116u8, 110u8, 110u8,
+                    117u8, 45u8, 108u8, 101u8, 112u8, 99u8, 110u8, 117u8, 45u8, 108u8, 105u8,
+                    109u8, 98u8, 110u8, 117u8, 45u8, 109u8, 108u8, 121u8, 109u8, 110u8, 117u8,
+                    45u8, 109u8, 111u8, 110u8, 103u8, 110u8, 117u8, 45u8, 109u8, 116u8, 101u8,
+                    105u8, 110u8, 117u8, 45u8, 109u8, 121u8, 109u8, 114u8, 110u8, 117u8, 45u8,
+                    109u8, 121u8, 109u8, 114u8, 115u8, 104u8, 97u8, 110u8, 110u8, 117u8, 45u8,
+                    110u8, 97u8, 116u8, 105u8, 118u8, 101u8, 110u8, 117u8, 45u8, 110u8, 107u8,
+                    111u8, 111u8, 110u8, 117u8, 45u8, 111u8, 108u8, 99u8, 107u8, 110u8, 117u8,
+                    45u8, 111u8, 114u8, 121u8, 97u8, 110u8, 117u8, 45u8, 111u8, 115u8, 109u8, 97u8,
+                    110u8, 117u8, 45u8, 114u8, 111u8, 109u8, 97u8, 110u8, 110u8, 117u8, 45u8,
+                    114u8, 111u8, 109u8, 97u8, 110u8, 108u8, 111u8, 119u8, 110u8, 117u8, 45u8,
+                    115u8, 97u8, 117u8, 114u8, 110u8, 117u8, 45u8, 115u8, 104u8, 114u8, 100u8,
+                    110u8, 117u8, 45u8, 115u8, 111u8, 114u8, 97u8, 110u8, 117u8, 45u8, 115u8,
+                    117u8, 110u8, 100u8, 110u8, 117u8, 45u8, 116u8, 97u8, 107u8, 114u8, 110u8,
+                    117u8, 45u8, 116u8, 97u8, 108u8, 117u8, 110u8, 117u8, 45u8, 116u8, 97u8, 109u8,
+                    108u8, 110u8, 117u8, 45u8, 116u8, 97u8, 109u8, 108u8, 100u8, 101u8, 99u8,
+                    110u8, 117u8, 45u8, 116u8, 101u8, 108u8, 117u8, 110u8, 117u8, 45u8, 116u8,
+                    104u8, 97u8, 105u8, 110u8, 117u8, 45u8, 116u8, 105u8, 98u8, 116u8, 110u8,
+                    117u8, 45u8, 116u8, 114u8, 97u8, 100u8, 105u8, 116u8, 105u8, 111u8, 110u8,
+                    117u8, 45u8, 118u8, 97u8, 105u8, 105u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    127u8, 0u8, 0u8, 0u8, 0u8, 0u8, 39u8, 0u8, 76u8, 0u8, 111u8, 0u8, 140u8, 0u8,
+                    197u8, 0u8, 234u8, 0u8, 23u8, 1u8, 60u8, 1u8, 114u8, 1u8, 151u8, 1u8, 232u8,
+                    1u8, 49u8, 2u8, 150u8, 2u8, 212u8, 2u8, 239u8, 2u8, 18u8, 3u8, 57u8, 3u8, 88u8,
+                    3u8, 121u8, 3u8, 156u8, 3u8, 206u8, 3u8, 8u8, 4u8, 62u8, 4u8, 112u8, 4u8,
+                    124u8, 4u8, 182u8, 4u8, 230u8, 4u8, 20u8, 5u8, 80u8, 5u8, 94u8, 5u8, 160u8,
+                    5u8, 170u8, 5u8, 232u8, 5u8, 19u8, 6u8, 36u8, 6u8, 75u8, 6u8, 143u8, 6u8,
+                    155u8, 6u8, 209u8, 6u8, 7u8, 7u8, 61u8, 7u8, 115u8, 7u8, 152u8, 7u8, 207u8,
+                    7u8, 26u8, 8u8, 103u8, 8u8, 175u8, 8u8, 228u8, 8u8, 37u8, 9u8, 124u8, 9u8,
+                    213u8, 9u8, 9u8, 10u8, 85u8, 10u8, 158u8, 10u8, 214u8, 10u8, 247u8, 10u8, 95u8,
+                    11u8, 137u8, 11u8, 220u8, 11u8, 58u8, 12u8, 98u8, 12u8, 140u8, 12u8, 182u8,
+                    12u8, 219u8, 12u8, 5u8, 13u8, 51u8, 13u8, 95u8, 13u8, 175u8, 13u8, 204u8, 13u8,
+                    10u8, 14u8, 39u8, 14u8, 72u8, 14u8, 95u8, 14u8, 116u8, 14u8, 141u8, 14u8,
+                    172u8, 14u8, 201u8, 14u8, 26u8, 15u8, 63u8, 15u8, 94u8, 15u8, 123u8, 15u8,
+                    185u8, 15u8, 214u8, 15u8, 241u8, 15u8, 35u8, 16u8, 85u8, 16u8, 152u8, 16u8,
+                    206u8, 16u8, 21u8, 17u8, 49u8, 17u8, 76u8, 17u8, 103u8, 17u8, 147u8, 17u8,
+                    171u8, 17u8, 200u8, 17u8, 225u8, 17u8, 2u8, 18u8, 33u8, 18u8, 60u8, 18u8,
+                    110u8, 18u8, 131u8, 18u8, 152u8, 18u8, 179u8, 18u8, 212u8, 18u8, 239u8, 18u8,
+                    14u8, 19u8, 60u8, 19u8, 102u8, 19u8, 119u8, 19u8, 143u8, 19u8, 162u8, 19u8,
+                    187u8, 19u8, 212u8, 19u8, 14u8, 20u8, 43u8, 20u8, 66u8, 20u8, 100u8, 20u8,
+                    129u8, 20u8, 150u8, 20u8, 183u8, 20u8, 239u8, 20u8, 14u8, 21u8, 37u8, 21u8,
+                    62u8, 21u8, 91u8, 21u8, 149u8, 21u8, 208u8, 177u8, 209u8, 131u8, 208u8, 180u8,
+                    208u8, 180u8, 208u8, 184u8, 208u8, 185u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                    184u8, 208u8, 185u8, 32u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8, 208u8,
+                    181u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 209u8, 140u8,
+                    208u8, 186u8, 208u8, 184u8, 209u8, 130u8, 208u8, 176u8, 208u8, 185u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 32u8, 208u8, 186u8, 208u8,
+                    176u8, 208u8, 187u8, 208u8, 181u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8,
+                    209u8, 128u8, 209u8, 140u8, 208u8, 186u8, 208u8, 190u8, 208u8, 191u8, 209u8,
+                    130u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 32u8, 208u8,
+                    186u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8, 189u8, 208u8, 180u8,
+                    208u8, 176u8, 209u8, 128u8, 209u8, 140u8, 208u8, 186u8, 208u8, 176u8, 208u8,
+                    187u8, 208u8, 181u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8,
+                    209u8, 140u8, 32u8, 208u8, 180u8, 208u8, 176u8, 208u8, 189u8, 208u8, 179u8,
+                    208u8, 184u8, 209u8, 141u8, 209u8, 132u8, 208u8, 184u8, 208u8, 190u8, 208u8,
+                    191u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 32u8, 208u8,
+                    186u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8, 189u8, 208u8, 180u8,
+                    208u8, 176u8, 209u8, 128u8, 209u8, 140u8, 32u8, 208u8, 176u8, 208u8, 188u8,
+                    208u8, 181u8, 209u8, 130u8, 208u8, 181u8, 45u8, 208u8, 176u8, 208u8, 187u8,
+                    208u8, 181u8, 208u8, 188u8, 209u8, 141u8, 209u8, 132u8, 208u8, 184u8, 208u8,
+                    190u8, 208u8, 191u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8,
+                    32u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8, 189u8,
+                    208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 209u8, 140u8, 208u8, 179u8, 209u8,
+                    128u8, 208u8, 184u8, 208u8, 179u8, 208u8, 190u8, 209u8, 128u8, 208u8, 184u8,
+                    208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8,
+                    185u8, 32u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8,
+                    189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 209u8, 140u8, 208u8, 181u8,
+                    208u8, 178u8, 209u8, 128u8, 208u8, 181u8, 208u8, 185u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 184u8, 208u8, 185u8, 32u8, 208u8, 186u8, 208u8, 176u8, 208u8,
+                    187u8, 208u8, 181u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8,
+                    209u8, 140u8, 208u8, 157u8, 208u8, 176u8, 209u8, 134u8, 208u8, 184u8, 208u8,
+                    190u8, 208u8, 189u8, 208u8, 176u8, 208u8, 187u8, 209u8, 140u8, 208u8, 189u8,
+                    209u8, 139u8, 208u8, 185u8, 32u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8,
+                    208u8, 181u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 209u8,
+                    140u8, 32u8, 208u8, 152u8, 208u8, 189u8, 208u8, 180u8, 208u8, 184u8, 208u8,
+                    184u8, 208u8, 184u8, 209u8, 129u8, 208u8, 187u8, 208u8, 176u8, 208u8, 188u8,
+                    209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 32u8, 208u8, 186u8,
+                    208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8, 189u8, 208u8, 180u8, 208u8,
+                    176u8, 209u8, 128u8, 209u8, 140u8, 208u8, 184u8, 209u8, 129u8, 208u8, 187u8,
+                    208u8, 176u8, 208u8, 188u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8,
+                    185u8, 32u8, 208u8, 179u8, 209u8, 128u8, 208u8, 176u8, 208u8, 182u8, 208u8,
+                    180u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                    208u8, 185u8, 32u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8,
+                    208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 209u8, 140u8, 32u8,
+                    40u8, 209u8, 130u8, 208u8, 176u8, 208u8, 177u8, 208u8, 187u8, 208u8, 184u8,
+                    209u8, 135u8, 208u8, 189u8, 209u8, 139u8, 208u8, 185u8, 41u8, 208u8, 184u8,
+                    209u8, 129u8, 208u8, 187u8, 208u8, 176u8, 208u8, 188u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 184u8, 208u8, 185u8, 32u8, 208u8, 186u8, 208u8, 176u8, 208u8,
+                    187u8, 208u8, 181u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8,
+                    209u8, 140u8, 32u8, 40u8, 208u8, 161u8, 208u8, 176u8, 209u8, 131u8, 208u8,
+                    180u8, 208u8, 190u8, 208u8, 178u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8,
+                    209u8, 143u8, 32u8, 208u8, 144u8, 209u8, 128u8, 208u8, 176u8, 208u8, 178u8,
+                    208u8, 184u8, 209u8, 143u8, 41u8, 208u8, 184u8, 209u8, 129u8, 208u8, 187u8,
+                    208u8, 176u8, 208u8, 188u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8,
+                    185u8, 32u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8,
+                    189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 209u8, 140u8, 32u8, 40u8,
+                    209u8, 130u8, 208u8, 176u8, 208u8, 177u8, 208u8, 187u8, 208u8, 184u8, 209u8,
+                    135u8, 208u8, 189u8, 209u8, 139u8, 208u8, 185u8, 44u8, 32u8, 208u8, 176u8,
+                    209u8, 129u8, 209u8, 130u8, 209u8, 128u8, 208u8, 190u8, 208u8, 189u8, 208u8,
+                    190u8, 208u8, 188u8, 208u8, 184u8, 209u8, 135u8, 208u8, 181u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 32u8, 209u8, 141u8, 208u8, 191u8,
+                    208u8, 190u8, 209u8, 133u8, 208u8, 176u8, 41u8, 208u8, 184u8, 209u8, 129u8,
+                    208u8, 187u8, 208u8, 176u8, 208u8, 188u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                    184u8, 208u8, 185u8, 32u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8, 208u8,
+                    181u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 209u8, 140u8,
+                    32u8, 40u8, 208u8, 163u8, 208u8, 188u8, 208u8, 188u8, 32u8, 208u8, 176u8,
+                    208u8, 187u8, 209u8, 140u8, 45u8, 208u8, 154u8, 209u8, 131u8, 209u8, 128u8,
+                    208u8, 176u8, 41u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8,
+                    208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 209u8, 140u8, 32u8,
+                    73u8, 83u8, 79u8, 45u8, 56u8, 54u8, 48u8, 49u8, 209u8, 143u8, 208u8, 191u8,
+                    208u8, 190u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8,
+                    185u8, 32u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8,
+                    189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 209u8, 140u8, 208u8, 191u8,
+                    208u8, 181u8, 209u8, 128u8, 209u8, 129u8, 208u8, 184u8, 208u8, 180u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 32u8, 208u8, 186u8, 208u8,
+                    176u8, 208u8, 187u8, 208u8, 181u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8,
+                    209u8, 128u8, 209u8, 140u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8, 208u8,
+                    181u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 209u8, 140u8,
+                    32u8, 208u8, 156u8, 208u8, 184u8, 208u8, 189u8, 209u8, 140u8, 208u8, 179u8,
+                    208u8, 190u8, 209u8, 132u8, 208u8, 184u8, 208u8, 189u8, 208u8, 176u8, 208u8,
+                    189u8, 209u8, 129u8, 208u8, 190u8, 208u8, 178u8, 209u8, 139u8, 208u8, 185u8,
+                    32u8, 209u8, 132u8, 208u8, 190u8, 209u8, 128u8, 208u8, 188u8, 208u8, 176u8,
+                    209u8, 130u8, 209u8, 129u8, 209u8, 130u8, 208u8, 176u8, 208u8, 189u8, 208u8,
+                    180u8, 208u8, 176u8, 209u8, 128u8, 209u8, 130u8, 208u8, 189u8, 209u8, 139u8,
+                    208u8, 185u8, 32u8, 209u8, 132u8, 208u8, 190u8, 209u8, 128u8, 208u8, 188u8,
+                    208u8, 176u8, 209u8, 130u8, 209u8, 130u8, 209u8, 128u8, 208u8, 176u8, 208u8,
+                    180u8, 208u8, 184u8, 209u8, 134u8, 208u8, 184u8, 208u8, 190u8, 208u8, 189u8,
+                    208u8, 189u8, 209u8, 139u8, 208u8, 185u8, 32u8, 208u8, 186u8, 208u8, 184u8,
+                    209u8, 130u8, 208u8, 176u8, 208u8, 185u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                    184u8, 208u8, 185u8, 32u8, 45u8, 32u8, 66u8, 105u8, 103u8, 53u8, 209u8, 129u8,
+                    208u8, 190u8, 208u8, 178u8, 208u8, 188u8, 208u8, 181u8, 209u8, 129u8, 209u8,
+                    130u8, 208u8, 184u8, 208u8, 188u8, 209u8, 139u8, 208u8, 185u8, 32u8, 208u8,
+                    191u8, 208u8, 190u8, 209u8, 128u8, 209u8, 143u8, 208u8, 180u8, 208u8, 190u8,
+                    208u8, 186u8, 32u8, 209u8, 129u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8,
+                    208u8, 184u8, 209u8, 128u8, 208u8, 190u8, 208u8, 178u8, 208u8, 186u8, 208u8,
+                    184u8, 209u8, 129u8, 208u8, 187u8, 208u8, 190u8, 208u8, 178u8, 208u8, 176u8,
+                    209u8, 128u8, 208u8, 189u8, 209u8, 139u8, 208u8, 185u8, 32u8, 208u8, 191u8,
+                    208u8, 190u8, 209u8, 128u8, 209u8, 143u8, 208u8, 180u8, 208u8, 190u8, 208u8,
+                    186u8, 32u8, 209u8, 129u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 208u8,
+                    184u8, 209u8, 128u8, 208u8, 190u8, 208u8, 178u8, 208u8, 186u8, 208u8, 184u8,
+                    99u8, 209u8, 130u8, 208u8, 176u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8,
+                    209u8, 128u8, 209u8, 130u8, 208u8, 189u8, 208u8, 176u8, 209u8, 143u8, 32u8,
+                    209u8, 129u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 208u8, 184u8, 209u8,
+                    128u8, 208u8, 190u8, 208u8, 178u8, 208u8, 186u8, 208u8, 176u8, 32u8, 85u8,
+                    110u8, 105u8, 99u8, 111u8, 100u8, 101u8, 209u8, 141u8, 208u8, 188u8, 208u8,
+                    190u8, 208u8, 180u8, 208u8, 183u8, 208u8, 184u8, 208u8, 181u8, 208u8, 178u8,
+                    209u8, 128u8, 208u8, 190u8, 208u8, 191u8, 208u8, 181u8, 208u8, 185u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 181u8, 32u8, 208u8, 191u8, 209u8,
+                    128u8, 208u8, 176u8, 208u8, 178u8, 208u8, 184u8, 208u8, 187u8, 208u8, 176u8,
+                    32u8, 209u8, 129u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 208u8, 184u8,
+                    209u8, 128u8, 208u8, 190u8, 208u8, 178u8, 208u8, 186u8, 208u8, 184u8, 209u8,
+                    131u8, 208u8, 191u8, 209u8, 128u8, 208u8, 190u8, 209u8, 137u8, 208u8, 181u8,
+                    208u8, 189u8, 208u8, 189u8, 209u8, 139u8, 208u8, 185u8, 32u8, 208u8, 186u8,
+                    208u8, 184u8, 209u8, 130u8, 208u8, 176u8, 208u8, 185u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 184u8, 208u8, 185u8, 32u8, 45u8, 32u8, 71u8, 66u8, 50u8, 51u8,
+                    49u8, 50u8, 208u8, 191u8, 208u8, 190u8, 209u8, 128u8, 209u8, 143u8, 208u8,
+                    180u8, 208u8, 190u8, 208u8, 186u8, 32u8, 209u8, 130u8, 208u8, 181u8, 208u8,
+                    187u8, 208u8, 181u8, 209u8, 132u8, 208u8, 190u8, 208u8, 189u8, 208u8, 189u8,
+                    208u8, 190u8, 208u8, 185u8, 32u8, 208u8, 186u8, 208u8, 189u8, 208u8, 184u8,
+                    208u8, 179u8, 208u8, 184u8, 209u8, 132u8, 208u8, 190u8, 208u8, 189u8, 208u8,
+                    181u8, 209u8, 130u8, 208u8, 184u8, 209u8, 135u8, 208u8, 181u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 32u8, 208u8, 191u8, 208u8, 190u8,
+                    209u8, 128u8, 209u8, 143u8, 208u8, 180u8, 208u8, 190u8, 208u8, 186u8, 32u8,
+                    209u8, 129u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 208u8, 184u8, 209u8,
+                    128u8, 208u8, 190u8, 208u8, 178u8, 208u8, 186u8, 208u8, 184u8, 208u8, 191u8,
+                    208u8, 184u8, 208u8, 189u8, 209u8, 140u8, 208u8, 184u8, 208u8, 189u8, 209u8,
+                    140u8, 209u8, 128u8, 208u8, 181u8, 209u8, 132u8, 208u8, 190u8, 209u8, 128u8,
+                    208u8, 188u8, 208u8, 184u8, 209u8, 128u8, 208u8, 190u8, 208u8, 178u8, 208u8,
+                    176u8, 208u8, 189u8, 208u8, 189u8, 209u8, 139u8, 208u8, 185u8, 32u8, 208u8,
+                    191u8, 208u8, 190u8, 209u8, 128u8, 209u8, 143u8, 208u8, 180u8, 208u8, 190u8,
+                    208u8, 186u8, 32u8, 209u8, 129u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8,
+                    208u8, 184u8, 209u8, 128u8, 208u8, 190u8, 208u8, 178u8, 208u8, 186u8, 208u8,
+                    184u8, 208u8, 191u8, 208u8, 190u8, 208u8, 184u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 159u8, 208u8, 190u8, 208u8, 184u8, 209u8, 129u8, 208u8, 186u8, 32u8,
+                    208u8, 191u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8, 181u8, 209u8, 128u8,
+                    208u8, 178u8, 208u8, 190u8, 208u8, 185u8, 32u8, 209u8, 129u8, 208u8, 190u8,
+                    208u8, 179u8, 208u8, 187u8, 208u8, 176u8, 209u8, 129u8, 208u8, 189u8, 208u8,
+                    190u8, 208u8, 185u8, 32u8, 209u8, 133u8, 208u8, 176u8, 208u8, 189u8, 208u8,
+                    179u8, 209u8, 139u8, 208u8, 187u8, 209u8, 140u8, 209u8, 129u8, 209u8, 130u8,
+                    208u8, 176u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 209u8,
+                    130u8, 208u8, 189u8, 208u8, 176u8, 209u8, 143u8, 32u8, 209u8, 129u8, 208u8,
+                    190u8, 209u8, 128u8, 209u8, 130u8, 208u8, 184u8, 209u8, 128u8, 208u8, 190u8,
+                    208u8, 178u8, 208u8, 186u8, 208u8, 176u8, 208u8, 191u8, 208u8, 190u8, 32u8,
+                    209u8, 135u8, 208u8, 181u8, 209u8, 128u8, 209u8, 130u8, 208u8, 176u8, 208u8,
+                    188u8, 209u8, 130u8, 209u8, 128u8, 208u8, 176u8, 208u8, 180u8, 208u8, 184u8,
+                    209u8, 134u8, 208u8, 184u8, 208u8, 190u8, 208u8, 189u8, 208u8, 189u8, 209u8,
+                    139u8, 208u8, 185u8, 32u8, 208u8, 191u8, 208u8, 190u8, 209u8, 128u8, 209u8,
+                    143u8, 208u8, 180u8, 208u8, 190u8, 208u8, 186u8, 209u8, 129u8, 208u8, 190u8,
+                    209u8, 128u8, 209u8, 130u8, 208u8, 184u8, 209u8, 128u8, 208u8, 190u8, 208u8,
+                    178u8, 208u8, 186u8, 208u8, 176u8, 32u8, 208u8, 191u8, 208u8, 190u8, 32u8,
+                    208u8, 186u8, 208u8, 187u8, 209u8, 142u8, 209u8, 135u8, 208u8, 176u8, 208u8,
+                    188u8, 44u8, 32u8, 208u8, 183u8, 208u8, 176u8, 209u8, 130u8, 208u8, 181u8,
+                    208u8, 188u8, 32u8, 208u8, 191u8, 208u8, 190u8, 32u8, 209u8, 135u8, 208u8,
+                    181u8, 209u8, 128u8, 209u8, 130u8, 208u8, 176u8, 208u8, 188u8, 209u8, 135u8,
+                    208u8, 182u8, 209u8, 131u8, 208u8, 184u8, 208u8, 189u8, 209u8, 140u8, 49u8,
+                    50u8, 45u8, 209u8, 135u8, 208u8, 176u8, 209u8, 129u8, 208u8, 190u8, 208u8,
+                    178u8, 208u8, 190u8, 208u8, 185u8, 32u8, 209u8, 132u8, 208u8, 190u8, 209u8,
+                    128u8, 208u8, 188u8, 208u8, 176u8, 209u8, 130u8, 32u8, 208u8, 178u8, 209u8,
+                    128u8, 208u8, 181u8, 208u8, 188u8, 208u8, 181u8, 208u8, 189u8, 208u8, 184u8,
+                    32u8, 40u8, 48u8, 226u8, 128u8, 147u8, 49u8, 49u8, 41u8, 49u8, 50u8, 45u8,
+                    209u8, 135u8, 208u8, 176u8, 209u8, 129u8, 208u8, 190u8, 208u8, 178u8, 208u8,
+                    190u8, 208u8, 185u8, 32u8, 209u8, 132u8, 208u8, 190u8, 209u8, 128u8, 208u8,
+                    188u8, 208u8, 176u8, 209u8, 130u8, 32u8, 208u8, 178u8, 209u8, 128u8, 208u8,
+                    181u8, 208u8, 188u8, 208u8, 181u8, 208u8, 189u8, 208u8, 184u8, 32u8, 40u8,
+                    49u8, 226u8, 128u8, 147u8, 49u8, 50u8, 41u8, 50u8, 52u8, 45u8, 209u8, 135u8,
+                    208u8, 176u8, 209u8, 129u8, 208u8, 190u8, 208u8, 178u8, 208u8, 190u8, 208u8,
+                    185u8, 32u8, 209u8, 132u8, 208u8, 190u8, 209u8, 128u8, 208u8, 188u8, 208u8,
+                    176u8, 209u8, 130u8, 32u8, 208u8, 178u8, 209u8, 128u8, 208u8, 181u8, 208u8,
+                    188u8, 208u8, 181u8, 208u8, 189u8, 208u8, 184u8, 32u8, 40u8, 48u8, 226u8,
+                    128u8, 147u8, 50u8, 51u8, 41u8, 50u8, 52u8, 45u8, 209u8, 135u8, 208u8, 176u8,
+                    209u8, 129u8, 208u8, 190u8, 208u8, 178u8, 208u8, 190u8, 208u8, 185u8, 32u8,
+                    209u8, 132u8, 208u8, 190u8, 209u8, 128u8, 208u8, 188u8, 208u8, 176u8, 209u8,
+                    130u8, 32u8, 208u8, 178u8, 209u8, 128u8, 208u8, 181u8, 208u8, 188u8, 208u8,
+                    181u8, 208u8, 189u8, 208u8, 184u8, 32u8, 40u8, 49u8, 226u8, 128u8, 147u8, 50u8,
+                    52u8, 41u8, 208u8, 161u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 208u8,
+                    184u8, 209u8, 128u8, 208u8, 190u8, 208u8, 178u8, 208u8, 186u8, 208u8, 176u8,
+                    32u8, 209u8, 129u8, 208u8, 184u8, 208u8, 188u8, 208u8, 178u8, 208u8, 190u8,
+                    208u8, 187u8, 208u8, 190u8, 208u8, 178u8, 208u8, 161u8, 208u8, 190u8, 209u8,
+                    128u8, 209u8, 130u8, 208u8, 184u8, 209u8, 128u8, 208u8, 190u8, 208u8, 178u8,
+                    208u8, 186u8, 208u8, 176u8, 32u8, 208u8, 177u8, 208u8, 181u8, 208u8, 183u8,
+                    32u8, 209u8, 131u8, 209u8, 135u8, 208u8, 181u8, 209u8, 130u8, 208u8, 176u8,
+                    32u8, 209u8, 129u8, 208u8, 184u8, 208u8, 188u8, 208u8, 178u8, 208u8, 190u8,
+                    208u8, 187u8, 208u8, 190u8, 208u8, 178u8, 208u8, 161u8, 208u8, 190u8, 209u8,
+                    128u8, 209u8, 130u8, 208u8, 184u8, 209u8, 128u8, 208u8, 190u8, 208u8, 178u8,
+                    208u8, 186u8, 208u8, 176u8, 32u8, 208u8, 191u8, 208u8, 190u8, 32u8, 208u8,
+                    176u8, 208u8, 186u8, 209u8, 134u8, 208u8, 181u8, 208u8, 189u8, 209u8, 130u8,
+                    208u8, 176u8, 208u8, 188u8, 32u8, 208u8, 178u8, 32u8, 208u8, 190u8, 208u8,
+                    177u8, 209u8, 139u8, 209u8, 135u8, 208u8, 189u8, 208u8, 190u8, 208u8, 188u8,
+                    32u8, 208u8, 191u8, 208u8, 190u8, 209u8, 128u8, 209u8, 143u8, 208u8, 180u8,
+                    208u8, 186u8, 208u8, 181u8, 208u8, 161u8, 208u8, 190u8, 209u8, 128u8, 209u8,
+                    130u8, 208u8, 184u8, 209u8, 128u8, 208u8, 190u8, 208u8, 178u8, 208u8, 186u8,
+                    208u8, 176u8, 32u8, 208u8, 191u8, 208u8, 190u8, 32u8, 208u8, 176u8, 208u8,
+                    186u8, 209u8, 134u8, 208u8, 181u8, 208u8, 189u8, 209u8, 130u8, 208u8, 176u8,
+                    208u8, 188u8, 32u8, 208u8, 178u8, 32u8, 208u8, 190u8, 208u8, 177u8, 209u8,
+                    128u8, 208u8, 176u8, 209u8, 130u8, 208u8, 189u8, 208u8, 190u8, 208u8, 188u8,
+                    32u8, 208u8, 191u8, 208u8, 190u8, 209u8, 128u8, 209u8, 143u8, 208u8, 180u8,
+                    208u8, 186u8, 208u8, 181u8, 208u8, 161u8, 208u8, 190u8, 209u8, 128u8, 209u8,
+                    130u8, 208u8, 184u8, 209u8, 128u8, 208u8, 190u8, 208u8, 178u8, 208u8, 186u8,
+                    208u8, 176u8, 32u8, 208u8, 178u8, 208u8, 189u8, 208u8, 181u8, 32u8, 208u8,
+                    183u8, 208u8, 176u8, 208u8, 178u8, 208u8, 184u8, 209u8, 129u8, 208u8, 184u8,
+                    208u8, 188u8, 208u8, 190u8, 209u8, 129u8, 209u8, 130u8, 208u8, 184u8, 32u8,
+                    208u8, 190u8, 209u8, 130u8, 32u8, 209u8, 128u8, 208u8, 181u8, 208u8, 179u8,
+                    208u8, 184u8, 209u8, 129u8, 209u8, 130u8, 209u8, 128u8, 208u8, 176u8, 208u8,
+                    161u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 208u8, 184u8, 209u8, 128u8,
+                    208u8, 190u8, 208u8, 178u8, 208u8, 186u8, 208u8, 176u8, 32u8, 209u8, 129u8,
+                    32u8, 209u8, 131u8, 209u8, 135u8, 208u8, 181u8, 209u8, 130u8, 208u8, 190u8,
+                    208u8, 188u8, 32u8, 209u8, 128u8, 208u8, 181u8, 208u8, 179u8, 208u8, 184u8,
+                    209u8, 129u8, 209u8, 130u8, 209u8, 128u8, 208u8, 176u8, 208u8, 161u8, 208u8,
+                    190u8, 209u8, 128u8, 209u8, 130u8, 208u8, 184u8, 209u8, 128u8, 208u8, 190u8,
+                    208u8, 178u8, 208u8, 186u8, 208u8, 176u8, 32u8, 208u8, 191u8, 208u8, 190u8,
+                    32u8, 209u8, 129u8, 209u8, 130u8, 208u8, 176u8, 208u8, 189u8, 208u8, 180u8,
+                    208u8, 176u8, 209u8, 128u8, 209u8, 130u8, 208u8, 189u8, 209u8, 139u8, 208u8,
+                    188u8, 32u8, 208u8, 191u8, 209u8, 128u8, 208u8, 176u8, 208u8, 178u8, 208u8,
+                    184u8, 208u8, 187u8, 208u8, 176u8, 208u8, 188u8, 208u8, 159u8, 209u8, 128u8,
+                    208u8, 184u8, 208u8, 190u8, 209u8, 128u8, 208u8, 184u8, 209u8, 130u8, 208u8,
+                    181u8, 209u8, 130u8, 208u8, 189u8, 208u8, 176u8, 209u8, 143u8, 32u8, 209u8,
+                    129u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 208u8, 184u8, 209u8, 128u8,
+                    208u8, 190u8, 208u8, 178u8, 208u8, 186u8, 208u8, 176u8, 32u8, 209u8, 129u8,
+                    208u8, 187u8, 208u8, 190u8, 208u8, 178u8, 32u8, 208u8, 178u8, 32u8, 208u8,
+                    189u8, 208u8, 184u8, 208u8, 182u8, 208u8, 189u8, 208u8, 181u8, 208u8, 188u8,
+                    32u8, 209u8, 128u8, 208u8, 181u8, 208u8, 179u8, 208u8, 184u8, 209u8, 129u8,
+                    209u8, 130u8, 209u8, 128u8, 208u8, 181u8, 208u8, 159u8, 209u8, 128u8, 208u8,
+                    184u8, 208u8, 190u8, 209u8, 128u8, 208u8, 184u8, 209u8, 130u8, 208u8, 181u8,
+                    209u8, 130u8, 208u8, 189u8, 208u8, 176u8, 209u8, 143u8, 32u8, 209u8, 129u8,
+                    208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 208u8, 184u8, 209u8, 128u8, 208u8,
+                    190u8, 208u8, 178u8, 208u8, 186u8, 208u8, 176u8, 32u8, 209u8, 129u8, 208u8,
+                    187u8, 208u8, 190u8, 208u8, 178u8, 32u8, 208u8, 178u8, 32u8, 208u8, 178u8,
+                    208u8, 181u8, 209u8, 128u8, 209u8, 133u8, 208u8, 189u8, 208u8, 181u8, 208u8,
+                    188u8, 32u8, 209u8, 128u8, 208u8, 181u8, 208u8, 179u8, 208u8, 184u8, 209u8,
+                    129u8, 209u8, 130u8, 209u8, 128u8, 208u8, 181u8, 208u8, 161u8, 208u8, 190u8,
+                    209u8, 128u8, 209u8, 130u8, 208u8, 184u8, 209u8, 128u8, 208u8, 190u8, 208u8,
+                    178u8, 208u8, 186u8, 208u8, 176u8, 32u8, 208u8, 177u8, 208u8, 181u8, 208u8,
+                    183u8, 32u8, 208u8, 189u8, 208u8, 190u8, 209u8, 128u8, 208u8, 188u8, 208u8,
+                    176u8, 208u8, 187u8, 208u8, 184u8, 208u8, 183u8, 208u8, 176u8, 209u8, 134u8,
+                    208u8, 184u8, 208u8, 184u8, 208u8, 161u8, 208u8, 190u8, 209u8, 128u8, 209u8,
+                    130u8, 208u8, 184u8, 209u8, 128u8, 208u8, 190u8, 208u8, 178u8, 208u8, 186u8,
+                    208u8, 176u8, 32u8, 208u8, 189u8, 208u8, 190u8, 209u8, 128u8, 208u8, 188u8,
+                    208u8, 176u8, 208u8, 187u8, 208u8, 184u8, 208u8, 183u8, 208u8, 190u8, 208u8,
+                    178u8, 208u8, 176u8, 208u8, 189u8, 208u8, 189u8, 209u8, 139u8, 209u8, 133u8,
+                    32u8, 209u8, 129u8, 208u8, 184u8, 208u8, 188u8, 208u8, 178u8, 208u8, 190u8,
+                    208u8, 187u8, 208u8, 190u8, 208u8, 178u8, 32u8, 85u8, 110u8, 105u8, 99u8,
+                    111u8, 100u8, 101u8, 208u8, 158u8, 209u8, 130u8, 208u8, 180u8, 208u8, 181u8,
+                    208u8, 187u8, 209u8, 140u8, 208u8, 189u8, 208u8, 176u8, 209u8, 143u8, 32u8,
+                    209u8, 129u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 208u8, 184u8, 209u8,
+                    128u8, 208u8, 190u8, 208u8, 178u8, 208u8, 186u8, 208u8, 176u8, 32u8, 209u8,
+                    135u8, 208u8, 184u8, 209u8, 129u8, 208u8, 187u8, 208u8, 190u8, 208u8, 178u8,
+                    209u8, 139u8, 209u8, 133u8, 32u8, 208u8, 183u8, 208u8, 189u8, 208u8, 176u8,
+                    209u8, 135u8, 208u8, 181u8, 208u8, 189u8, 208u8, 184u8, 208u8, 185u8, 208u8,
+                    161u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 208u8, 184u8, 209u8, 128u8,
+                    208u8, 190u8, 208u8, 178u8, 208u8, 186u8, 208u8, 176u8, 32u8, 209u8, 135u8,
+                    208u8, 184u8, 209u8, 129u8, 208u8, 187u8, 208u8, 181u8, 208u8, 189u8, 208u8,
+                    189u8, 209u8, 139u8, 209u8, 133u8, 32u8, 208u8, 183u8, 208u8, 189u8, 208u8,
+                    176u8, 209u8, 135u8, 208u8, 181u8, 208u8, 189u8, 208u8, 184u8, 208u8, 185u8,
+                    208u8, 159u8, 208u8, 190u8, 208u8, 187u8, 208u8, 189u8, 208u8, 176u8, 209u8,
+                    143u8, 32u8, 209u8, 129u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 208u8,
+                    184u8, 209u8, 128u8, 208u8, 190u8, 208u8, 178u8, 208u8, 186u8, 208u8, 176u8,
+                    208u8, 161u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 208u8, 184u8, 209u8,
+                    128u8, 208u8, 190u8, 208u8, 178u8, 208u8, 186u8, 208u8, 176u8, 32u8, 209u8,
+                    130u8, 208u8, 190u8, 208u8, 187u8, 209u8, 140u8, 208u8, 186u8, 208u8, 190u8,
+                    32u8, 208u8, 191u8, 208u8, 190u8, 32u8, 209u8, 129u8, 208u8, 184u8, 208u8,
+                    188u8, 208u8, 178u8, 208u8, 190u8, 208u8, 187u8, 208u8, 176u8, 208u8, 188u8,
+                    44u8, 32u8, 208u8, 190u8, 208u8, 177u8, 208u8, 190u8, 208u8, 183u8, 208u8,
+                    189u8, 208u8, 176u8, 209u8, 135u8, 208u8, 176u8, 209u8, 142u8, 209u8, 137u8,
+                    208u8, 184u8, 208u8, 188u8, 32u8, 209u8, 128u8, 208u8, 176u8, 208u8, 183u8,
+                    209u8, 128u8, 209u8, 143u8, 208u8, 180u8, 208u8, 189u8, 208u8, 190u8, 209u8,
+                    129u8, 209u8, 130u8, 209u8, 140u8, 208u8, 161u8, 208u8, 190u8, 209u8, 128u8,
+                    209u8, 130u8, 208u8, 184u8, 209u8, 128u8, 208u8, 190u8, 208u8, 178u8, 208u8,
+                    186u8, 208u8, 176u8, 32u8, 208u8, 191u8, 208u8, 190u8, 32u8, 208u8, 176u8,
+                    208u8, 186u8, 209u8, 134u8, 208u8, 181u8, 208u8, 189u8, 209u8, 130u8, 208u8,
+                    176u8, 208u8, 188u8, 208u8, 161u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8,
+                    208u8, 184u8, 209u8, 128u8, 208u8, 190u8, 208u8, 178u8, 208u8, 186u8, 208u8,
+                    176u8, 32u8, 208u8, 191u8, 208u8, 190u8, 32u8, 208u8, 176u8, 208u8, 186u8,
+                    209u8, 134u8, 208u8, 181u8, 208u8, 189u8, 209u8, 130u8, 208u8, 176u8, 208u8,
+                    188u8, 47u8, 209u8, 128u8, 208u8, 181u8, 208u8, 179u8, 208u8, 184u8, 209u8,
+                    129u8, 209u8, 130u8, 209u8, 128u8, 209u8, 131u8, 47u8, 208u8, 180u8, 208u8,
+                    187u8, 208u8, 184u8, 208u8, 189u8, 208u8, 181u8, 32u8, 209u8, 129u8, 209u8,
+                    130u8, 209u8, 128u8, 208u8, 190u8, 208u8, 186u8, 208u8, 184u8, 208u8, 161u8,
+                    208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 208u8, 184u8, 209u8, 128u8, 208u8,
+                    190u8, 208u8, 178u8, 208u8, 186u8, 208u8, 176u8, 32u8, 208u8, 191u8, 208u8,
+                    190u8, 32u8, 208u8, 176u8, 208u8, 186u8, 209u8, 134u8, 208u8, 181u8, 208u8,
+                    189u8, 209u8, 130u8, 208u8, 176u8, 208u8, 188u8, 47u8, 209u8, 128u8, 208u8,
+                    181u8, 208u8, 179u8, 208u8, 184u8, 209u8, 129u8, 209u8, 130u8, 209u8, 128u8,
+                    208u8, 176u8, 208u8, 188u8, 47u8, 208u8, 180u8, 208u8, 187u8, 208u8, 184u8,
+                    208u8, 189u8, 208u8, 181u8, 32u8, 209u8, 129u8, 209u8, 130u8, 209u8, 128u8,
+                    208u8, 190u8, 208u8, 186u8, 208u8, 184u8, 47u8, 208u8, 186u8, 208u8, 176u8,
+                    208u8, 189u8, 208u8, 181u8, 208u8, 188u8, 209u8, 143u8, 208u8, 179u8, 208u8,
+                    186u8, 208u8, 184u8, 208u8, 185u8, 32u8, 208u8, 191u8, 208u8, 181u8, 209u8,
+                    128u8, 208u8, 181u8, 208u8, 178u8, 208u8, 190u8, 208u8, 180u8, 32u8, 209u8,
+                    129u8, 209u8, 130u8, 209u8, 128u8, 208u8, 190u8, 208u8, 186u8, 208u8, 184u8,
+                    208u8, 190u8, 208u8, 177u8, 209u8, 139u8, 209u8, 135u8, 208u8, 189u8, 209u8,
+                    139u8, 208u8, 185u8, 32u8, 208u8, 191u8, 208u8, 181u8, 209u8, 128u8, 208u8,
+                    181u8, 208u8, 178u8, 208u8, 190u8, 208u8, 180u8, 32u8, 209u8, 129u8, 209u8,
+                    130u8, 209u8, 128u8, 208u8, 190u8, 208u8, 186u8, 208u8, 184u8, 208u8, 182u8,
+                    208u8, 181u8, 209u8, 129u8, 209u8, 130u8, 208u8, 186u8, 208u8, 184u8, 208u8,
+                    185u8, 32u8, 208u8, 191u8, 208u8, 181u8, 209u8, 128u8, 208u8, 181u8, 208u8,
+                    178u8, 208u8, 190u8, 208u8, 180u8, 32u8, 209u8, 129u8, 209u8, 130u8, 209u8,
+                    128u8, 208u8, 190u8, 208u8, 186u8, 208u8, 184u8, 208u8, 188u8, 208u8, 181u8,
+                    209u8, 130u8, 209u8, 128u8, 208u8, 184u8, 209u8, 135u8, 208u8, 181u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 32u8, 209u8, 129u8, 208u8,
+                    184u8, 209u8, 129u8, 209u8, 130u8, 208u8, 181u8, 208u8, 188u8, 208u8, 176u8,
+                    208u8, 177u8, 209u8, 128u8, 208u8, 184u8, 209u8, 130u8, 208u8, 176u8, 208u8,
+                    189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 32u8, 209u8,
+                    129u8, 208u8, 184u8, 209u8, 129u8, 209u8, 130u8, 208u8, 181u8, 208u8, 188u8,
+                    208u8, 176u8, 32u8, 208u8, 188u8, 208u8, 181u8, 209u8, 128u8, 208u8, 176u8,
+                    208u8, 188u8, 208u8, 181u8, 209u8, 128u8, 208u8, 184u8, 208u8, 186u8, 208u8,
+                    176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 209u8, 143u8,
+                    32u8, 209u8, 129u8, 208u8, 184u8, 209u8, 129u8, 209u8, 130u8, 208u8, 181u8,
+                    208u8, 188u8, 208u8, 176u8, 32u8, 208u8, 188u8, 208u8, 181u8, 209u8, 128u8,
+                    208u8, 176u8, 209u8, 128u8, 208u8, 176u8, 208u8, 177u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 190u8, 45u8, 208u8, 184u8, 208u8, 189u8, 208u8, 180u8, 208u8,
+                    184u8, 208u8, 185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 181u8,
+                    32u8, 209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8, 209u8, 139u8,
+                    209u8, 128u8, 208u8, 176u8, 209u8, 129u8, 209u8, 136u8, 208u8, 184u8, 209u8,
+                    128u8, 208u8, 181u8, 208u8, 189u8, 208u8, 189u8, 208u8, 176u8, 209u8, 143u8,
+                    32u8, 209u8, 129u8, 208u8, 184u8, 209u8, 129u8, 209u8, 130u8, 208u8, 181u8,
+                    208u8, 188u8, 208u8, 176u8, 32u8, 208u8, 176u8, 209u8, 128u8, 208u8, 176u8,
+                    208u8, 177u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 45u8, 208u8, 184u8,
+                    208u8, 189u8, 208u8, 180u8, 208u8, 184u8, 208u8, 185u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 184u8, 209u8, 133u8, 32u8, 209u8, 134u8, 208u8, 184u8, 209u8,
+                    132u8, 209u8, 128u8, 208u8, 176u8, 209u8, 128u8, 208u8, 188u8, 209u8, 143u8,
+                    208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 181u8, 32u8,
+                    209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8, 209u8, 139u8, 208u8,
+                    176u8, 209u8, 128u8, 208u8, 188u8, 209u8, 143u8, 208u8, 189u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 184u8, 208u8, 181u8, 32u8, 209u8, 134u8, 208u8, 184u8,
+                    209u8, 132u8, 209u8, 128u8, 209u8, 139u8, 32u8, 208u8, 178u8, 32u8, 208u8,
+                    189u8, 208u8, 184u8, 208u8, 182u8, 208u8, 189u8, 208u8, 181u8, 208u8, 188u8,
+                    32u8, 209u8, 128u8, 208u8, 181u8, 208u8, 179u8, 208u8, 184u8, 209u8, 129u8,
+                    209u8, 130u8, 209u8, 128u8, 208u8, 181u8, 208u8, 177u8, 208u8, 176u8, 208u8,
+                    187u8, 208u8, 184u8, 208u8, 185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                    208u8, 181u8, 32u8, 209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8,
+                    209u8, 139u8, 208u8, 177u8, 208u8, 181u8, 208u8, 189u8, 208u8, 179u8, 208u8,
+                    176u8, 208u8, 187u8, 209u8, 140u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                    208u8, 181u8, 32u8, 209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8,
+                    209u8, 139u8, 209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8, 209u8,
+                    139u8, 32u8, 208u8, 177u8, 209u8, 128u8, 208u8, 176u8, 209u8, 133u8, 208u8,
+                    188u8, 208u8, 184u8, 209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8,
+                    209u8, 139u8, 32u8, 209u8, 135u8, 208u8, 176u8, 208u8, 186u8, 208u8, 188u8,
+                    208u8, 176u8, 209u8, 135u8, 208u8, 176u8, 208u8, 188u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 184u8, 208u8, 181u8, 32u8, 209u8, 134u8, 208u8, 184u8, 209u8,
+                    132u8, 209u8, 128u8, 209u8, 139u8, 209u8, 134u8, 208u8, 184u8, 209u8, 132u8,
+                    209u8, 128u8, 209u8, 139u8, 32u8, 208u8, 180u8, 208u8, 181u8, 208u8, 178u8,
+                    208u8, 176u8, 208u8, 189u8, 208u8, 176u8, 208u8, 179u8, 208u8, 176u8, 209u8,
+                    128u8, 208u8, 184u8, 209u8, 141u8, 209u8, 132u8, 208u8, 184u8, 208u8, 190u8,
+                    208u8, 191u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 181u8, 32u8,
+                    209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8, 209u8, 139u8, 208u8,
+                    161u8, 208u8, 184u8, 208u8, 188u8, 208u8, 178u8, 208u8, 190u8, 208u8, 187u8,
+                    209u8, 139u8, 32u8, 208u8, 190u8, 208u8, 177u8, 208u8, 190u8, 208u8, 183u8,
+                    208u8, 189u8, 208u8, 176u8, 209u8, 135u8, 208u8, 181u8, 208u8, 189u8, 208u8,
+                    184u8, 209u8, 143u8, 32u8, 209u8, 132u8, 208u8, 184u8, 208u8, 189u8, 208u8,
+                    176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 190u8, 208u8, 178u8, 209u8, 139u8,
+                    209u8, 133u8, 32u8, 208u8, 191u8, 208u8, 190u8, 208u8, 186u8, 208u8, 176u8,
+                    208u8, 183u8, 208u8, 176u8, 209u8, 130u8, 208u8, 181u8, 208u8, 187u8, 208u8,
+                    181u8, 208u8, 185u8, 208u8, 191u8, 208u8, 190u8, 208u8, 187u8, 208u8, 189u8,
+                    208u8, 190u8, 209u8, 136u8, 208u8, 184u8, 209u8, 128u8, 208u8, 184u8, 208u8,
+                    189u8, 208u8, 189u8, 209u8, 139u8, 208u8, 181u8, 32u8, 209u8, 134u8, 208u8,
+                    184u8, 209u8, 132u8, 209u8, 128u8, 209u8, 139u8, 208u8, 179u8, 209u8, 128u8,
+                    209u8, 131u8, 208u8, 183u8, 208u8, 184u8, 208u8, 189u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 184u8, 208u8, 181u8, 32u8, 209u8, 134u8, 208u8, 184u8, 209u8,
+                    132u8, 209u8, 128u8, 209u8, 139u8, 208u8, 179u8, 209u8, 128u8, 208u8, 181u8,
+                    209u8, 135u8, 208u8, 181u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8,
+                    181u8, 32u8, 209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8, 209u8,
+                    139u8, 208u8, 179u8, 209u8, 128u8, 208u8, 181u8, 209u8, 135u8, 208u8, 181u8,
+                    209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 181u8, 32u8, 209u8, 134u8,
+                    208u8, 184u8, 209u8, 132u8, 209u8, 128u8, 209u8, 139u8, 32u8, 208u8, 178u8,
+                    32u8, 208u8, 189u8, 208u8, 184u8, 208u8, 182u8, 208u8, 189u8, 208u8, 181u8,
+                    208u8, 188u8, 32u8, 209u8, 128u8, 208u8, 181u8, 208u8, 179u8, 208u8, 184u8,
+                    209u8, 129u8, 209u8, 130u8, 209u8, 128u8, 208u8, 181u8, 209u8, 134u8, 208u8,
+                    184u8, 209u8, 132u8, 209u8, 128u8, 209u8, 139u8, 32u8, 208u8, 179u8, 209u8,
+                    131u8, 208u8, 180u8, 208u8, 182u8, 208u8, 176u8, 209u8, 128u8, 208u8, 176u8,
+                    209u8, 130u8, 208u8, 184u8, 209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8,
+                    128u8, 209u8, 139u8, 32u8, 208u8, 179u8, 209u8, 131u8, 209u8, 128u8, 208u8,
+                    188u8, 209u8, 131u8, 208u8, 186u8, 209u8, 133u8, 208u8, 184u8, 208u8, 186u8,
+                    208u8, 184u8, 209u8, 130u8, 208u8, 176u8, 208u8, 185u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 184u8, 208u8, 181u8, 32u8, 208u8, 180u8, 208u8, 181u8, 209u8,
+                    129u8, 209u8, 143u8, 209u8, 130u8, 208u8, 184u8, 209u8, 135u8, 208u8, 189u8,
+                    209u8, 139u8, 208u8, 181u8, 32u8, 209u8, 134u8, 208u8, 184u8, 209u8, 132u8,
+                    209u8, 128u8, 209u8, 139u8, 208u8, 186u8, 208u8, 184u8, 209u8, 130u8, 208u8,
+                    176u8, 208u8, 185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 181u8,
+                    32u8, 209u8, 131u8, 208u8, 191u8, 209u8, 128u8, 208u8, 190u8, 209u8, 137u8,
+                    208u8, 181u8, 208u8, 189u8, 208u8, 189u8, 209u8, 139u8, 208u8, 181u8, 32u8,
+                    209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8, 209u8, 139u8, 208u8,
+                    186u8, 208u8, 184u8, 209u8, 130u8, 208u8, 176u8, 208u8, 185u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 184u8, 208u8, 181u8, 32u8, 209u8, 131u8, 208u8, 191u8,
+                    209u8, 128u8, 208u8, 190u8, 209u8, 137u8, 208u8, 181u8, 208u8, 189u8, 208u8,
+                    189u8, 209u8, 139u8, 208u8, 181u8, 32u8, 209u8, 134u8, 208u8, 184u8, 209u8,
+                    132u8, 209u8, 128u8, 209u8, 139u8, 32u8, 40u8, 209u8, 132u8, 208u8, 184u8,
+                    208u8, 189u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 209u8, 139u8, 41u8,
+                    208u8, 186u8, 208u8, 184u8, 209u8, 130u8, 208u8, 176u8, 208u8, 185u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 181u8, 32u8, 209u8, 130u8, 209u8,
+                    128u8, 208u8, 176u8, 208u8, 180u8, 208u8, 184u8, 209u8, 134u8, 208u8, 184u8,
+                    208u8, 190u8, 208u8, 189u8, 208u8, 189u8, 209u8, 139u8, 208u8, 181u8, 32u8,
+                    209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8, 209u8, 139u8, 208u8,
+                    186u8, 208u8, 184u8, 209u8, 130u8, 208u8, 176u8, 208u8, 185u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 184u8, 208u8, 181u8, 32u8, 209u8, 130u8, 209u8, 128u8,
+                    208u8, 176u8, 208u8, 180u8, 208u8, 184u8, 209u8, 134u8, 208u8, 184u8, 208u8,
+                    190u8, 208u8, 189u8, 208u8, 189u8, 209u8, 139u8, 208u8, 181u8, 32u8, 209u8,
+                    134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8, 209u8, 139u8, 32u8, 40u8,
+                    209u8, 132u8, 208u8, 184u8, 208u8, 189u8, 208u8, 176u8, 208u8, 189u8, 209u8,
+                    129u8, 209u8, 139u8, 41u8, 209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8,
+                    128u8, 209u8, 139u8, 32u8, 208u8, 189u8, 208u8, 176u8, 32u8, 208u8, 184u8,
+                    208u8, 178u8, 209u8, 128u8, 208u8, 184u8, 209u8, 130u8, 208u8, 181u8, 209u8,
+                    143u8, 208u8, 178u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 184u8, 208u8, 181u8, 32u8, 209u8, 134u8, 208u8, 184u8, 209u8, 132u8,
+                    209u8, 128u8, 209u8, 139u8, 209u8, 143u8, 208u8, 191u8, 208u8, 190u8, 208u8,
+                    189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 181u8, 32u8, 209u8,
+                    134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8, 209u8, 139u8, 209u8, 143u8,
+                    208u8, 191u8, 208u8, 190u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                    184u8, 208u8, 181u8, 32u8, 209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8,
+                    128u8, 209u8, 139u8, 32u8, 40u8, 209u8, 132u8, 208u8, 184u8, 208u8, 189u8,
+                    208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 209u8, 139u8, 41u8, 209u8, 134u8,
+                    208u8, 184u8, 209u8, 132u8, 209u8, 128u8, 209u8, 139u8, 32u8, 208u8, 186u8,
+                    208u8, 176u8, 208u8, 185u8, 209u8, 143u8, 32u8, 208u8, 187u8, 208u8, 184u8,
+                    208u8, 186u8, 209u8, 133u8, 208u8, 188u8, 208u8, 181u8, 209u8, 128u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 181u8, 32u8, 209u8, 134u8, 208u8,
+                    184u8, 209u8, 132u8, 209u8, 128u8, 209u8, 139u8, 209u8, 134u8, 208u8, 184u8,
+                    209u8, 132u8, 209u8, 128u8, 209u8, 139u8, 32u8, 208u8, 186u8, 208u8, 176u8,
+                    208u8, 189u8, 208u8, 189u8, 208u8, 176u8, 208u8, 180u8, 208u8, 176u8, 209u8,
+                    134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8, 209u8, 139u8, 32u8, 209u8,
+                    130u8, 208u8, 176u8, 208u8, 185u8, 32u8, 209u8, 130u8, 208u8, 176u8, 208u8,
+                    188u8, 32u8, 209u8, 133u8, 208u8, 190u8, 209u8, 128u8, 208u8, 176u8, 209u8,
+                    134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8, 209u8, 139u8, 32u8, 209u8,
+                    130u8, 208u8, 176u8, 208u8, 185u8, 32u8, 209u8, 130u8, 208u8, 176u8, 208u8,
+                    188u8, 32u8, 209u8, 130u8, 208u8, 176u8, 208u8, 188u8, 208u8, 187u8, 208u8,
+                    176u8, 208u8, 190u8, 209u8, 129u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                    208u8, 181u8, 32u8, 209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8,
+                    209u8, 139u8, 209u8, 129u8, 208u8, 190u8, 208u8, 178u8, 209u8, 128u8, 208u8,
+                    181u8, 208u8, 188u8, 208u8, 181u8, 208u8, 189u8, 208u8, 189u8, 209u8, 139u8,
+                    208u8, 181u8, 32u8, 208u8, 176u8, 209u8, 128u8, 208u8, 176u8, 208u8, 177u8,
+                    209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 181u8, 32u8, 209u8, 134u8,
+                    208u8, 184u8, 209u8, 132u8, 209u8, 128u8, 209u8, 139u8, 209u8, 134u8, 208u8,
+                    184u8, 209u8, 132u8, 209u8, 128u8, 209u8, 139u8, 32u8, 208u8, 187u8, 208u8,
+                    181u8, 208u8, 191u8, 209u8, 135u8, 208u8, 176u8, 209u8, 134u8, 208u8, 184u8,
+                    209u8, 132u8, 209u8, 128u8, 209u8, 139u8, 32u8, 208u8, 187u8, 208u8, 184u8,
+                    208u8, 188u8, 208u8, 177u8, 209u8, 131u8, 209u8, 134u8, 208u8, 184u8, 209u8,
+                    132u8, 209u8, 128u8, 209u8, 139u8, 32u8, 208u8, 188u8, 208u8, 176u8, 208u8,
+                    187u8, 208u8, 176u8, 209u8, 143u8, 208u8, 187u8, 208u8, 176u8, 208u8, 188u8,
+                    208u8, 156u8, 208u8, 190u8, 208u8, 189u8, 208u8, 179u8, 208u8, 190u8, 208u8,
+                    187u8, 209u8, 140u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 181u8,
+                    32u8, 209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8, 209u8, 139u8,
+                    209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8, 209u8, 139u8, 32u8,
+                    208u8, 188u8, 208u8, 176u8, 208u8, 189u8, 208u8, 184u8, 208u8, 191u8, 209u8,
+                    131u8, 209u8, 128u8, 208u8, 184u8, 208u8, 177u8, 208u8, 184u8, 209u8, 128u8,
+                    208u8, 188u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                    184u8, 208u8, 181u8, 32u8, 209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8,
+                    128u8, 209u8, 139u8, 208u8, 177u8, 208u8, 184u8, 209u8, 128u8, 208u8, 188u8,
+                    208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8,
+                    181u8, 32u8, 209u8, 136u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 184u8, 208u8, 181u8, 32u8, 209u8, 134u8, 208u8, 184u8, 209u8,
+                    132u8, 209u8, 128u8, 209u8, 139u8, 209u8, 134u8, 208u8, 184u8, 209u8, 132u8,
+                    209u8, 128u8, 209u8, 139u8, 32u8, 208u8, 186u8, 208u8, 190u8, 209u8, 128u8,
+                    208u8, 181u8, 208u8, 189u8, 208u8, 189u8, 209u8, 139u8, 209u8, 133u8, 32u8,
+                    208u8, 189u8, 208u8, 176u8, 209u8, 128u8, 208u8, 190u8, 208u8, 180u8, 208u8,
+                    190u8, 208u8, 178u8, 209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8,
+                    209u8, 139u8, 32u8, 208u8, 189u8, 208u8, 186u8, 208u8, 190u8, 209u8, 134u8,
+                    208u8, 184u8, 209u8, 132u8, 209u8, 128u8, 209u8, 139u8, 32u8, 208u8, 190u8,
+                    208u8, 187u8, 45u8, 209u8, 135u8, 208u8, 184u8, 208u8, 186u8, 208u8, 184u8,
+                    209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8, 209u8, 139u8, 32u8,
+                    208u8, 190u8, 209u8, 128u8, 208u8, 184u8, 209u8, 143u8, 209u8, 134u8, 208u8,
+                    184u8, 209u8, 132u8, 209u8, 128u8, 209u8, 139u8, 32u8, 208u8, 190u8, 209u8,
+                    129u8, 208u8, 188u8, 208u8, 176u8, 208u8, 189u8, 209u8, 140u8, 209u8, 143u8,
+                    209u8, 128u8, 208u8, 184u8, 208u8, 188u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                    184u8, 208u8, 181u8, 32u8, 209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8,
+                    128u8, 209u8, 139u8, 209u8, 128u8, 208u8, 184u8, 208u8, 188u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 184u8, 208u8, 181u8, 32u8, 209u8, 134u8, 208u8, 184u8,
+                    209u8, 132u8, 209u8, 128u8, 209u8, 139u8, 32u8, 208u8, 178u8, 32u8, 208u8,
+                    189u8, 208u8, 184u8, 208u8, 182u8, 208u8, 189u8, 208u8, 181u8, 208u8, 188u8,
+                    32u8, 209u8, 128u8, 208u8, 181u8, 208u8, 179u8, 208u8, 184u8, 209u8, 129u8,
+                    209u8, 130u8, 209u8, 128u8, 208u8, 181u8, 209u8, 134u8, 208u8, 184u8, 209u8,
+                    132u8, 209u8, 128u8, 209u8, 139u8, 32u8, 209u8, 129u8, 208u8, 176u8, 209u8,
+                    131u8, 209u8, 128u8, 208u8, 176u8, 209u8, 136u8, 209u8, 130u8, 209u8, 128u8,
+                    208u8, 176u8, 209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8, 209u8,
+                    139u8, 32u8, 209u8, 136u8, 208u8, 176u8, 209u8, 128u8, 208u8, 176u8, 208u8,
+                    180u8, 208u8, 176u8, 209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8,
+                    209u8, 139u8, 32u8, 209u8, 129u8, 208u8, 190u8, 209u8, 128u8, 208u8, 176u8,
+                    45u8, 209u8, 129u8, 208u8, 190u8, 208u8, 188u8, 208u8, 191u8, 208u8, 181u8,
+                    208u8, 189u8, 208u8, 179u8, 209u8, 129u8, 209u8, 131u8, 208u8, 180u8, 208u8,
+                    176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 181u8,
+                    32u8, 209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8, 209u8, 139u8,
+                    209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8, 209u8, 139u8, 32u8,
+                    209u8, 130u8, 208u8, 176u8, 208u8, 186u8, 209u8, 128u8, 208u8, 184u8, 209u8,
+                    134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8, 209u8, 139u8, 32u8, 208u8,
+                    189u8, 208u8, 190u8, 208u8, 178u8, 208u8, 190u8, 208u8, 185u8, 32u8, 209u8,
+                    130u8, 208u8, 176u8, 208u8, 185u8, 45u8, 208u8, 187u8, 209u8, 142u8, 209u8,
+                    130u8, 208u8, 176u8, 208u8, 188u8, 208u8, 184u8, 208u8, 187u8, 209u8, 140u8,
+                    209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 181u8, 32u8, 209u8, 130u8,
+                    209u8, 128u8, 208u8, 176u8, 208u8, 180u8, 208u8, 184u8, 209u8, 134u8, 208u8,
+                    184u8, 208u8, 190u8, 208u8, 189u8, 208u8, 189u8, 209u8, 139u8, 208u8, 181u8,
+                    32u8, 209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8, 209u8, 139u8,
+                    209u8, 130u8, 208u8, 176u8, 208u8, 188u8, 208u8, 184u8, 208u8, 187u8, 209u8,
+                    140u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 181u8, 32u8, 209u8,
+                    134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8, 209u8, 139u8, 209u8, 134u8,
+                    208u8, 184u8, 209u8, 132u8, 209u8, 128u8, 209u8, 139u8, 32u8, 209u8, 130u8,
+                    208u8, 181u8, 208u8, 187u8, 209u8, 131u8, 208u8, 179u8, 209u8, 131u8, 209u8,
+                    130u8, 208u8, 176u8, 208u8, 185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                    208u8, 181u8, 32u8, 209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8,
+                    209u8, 139u8, 209u8, 130u8, 208u8, 184u8, 208u8, 177u8, 208u8, 181u8, 209u8,
+                    130u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 181u8, 32u8, 209u8,
+                    134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8, 209u8, 139u8, 208u8, 162u8,
+                    209u8, 128u8, 208u8, 176u8, 208u8, 180u8, 208u8, 184u8, 209u8, 134u8, 208u8,
+                    184u8, 208u8, 190u8, 208u8, 189u8, 208u8, 189u8, 208u8, 176u8, 209u8, 143u8,
+                    32u8, 209u8, 129u8, 208u8, 184u8, 209u8, 129u8, 209u8, 130u8, 208u8, 181u8,
+                    208u8, 188u8, 208u8, 176u8, 32u8, 208u8, 189u8, 209u8, 131u8, 208u8, 188u8,
+                    208u8, 181u8, 209u8, 128u8, 208u8, 176u8, 209u8, 134u8, 208u8, 184u8, 208u8,
+                    184u8, 209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8, 209u8, 139u8,
+                    32u8, 208u8, 178u8, 208u8, 176u8, 208u8, 184u8,
+                ])
+            },
+        )
+    },
+};
+static SR_LATN: &DataStruct = &::icu_displaynames::provider::KeyDisplayNamesV1 {
+    keys: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    99u8, 97u8, 99u8, 102u8, 99u8, 111u8, 99u8, 117u8, 104u8, 99u8, 107u8, 97u8,
+                    107u8, 98u8, 107u8, 99u8, 107u8, 102u8, 107u8, 107u8, 107u8, 110u8, 107u8,
+                    115u8, 108u8, 98u8, 109u8, 115u8, 110u8, 117u8, 116u8, 122u8, 118u8, 97u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    17u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 21u8, 0u8, 40u8, 0u8, 46u8, 0u8, 88u8,
+                    0u8, 121u8, 0u8, 156u8, 0u8, 192u8, 0u8, 226u8, 0u8, 250u8, 0u8, 15u8, 1u8,
+                    39u8, 1u8, 56u8, 1u8, 78u8, 1u8, 85u8, 1u8, 99u8, 1u8, 107u8, 97u8, 108u8,
+                    101u8, 110u8, 100u8, 97u8, 114u8, 102u8, 111u8, 114u8, 109u8, 97u8, 116u8,
+                    32u8, 118u8, 97u8, 108u8, 117u8, 116u8, 101u8, 114u8, 101u8, 100u8, 111u8,
+                    115u8, 108u8, 101u8, 100u8, 32u8, 115u8, 111u8, 114u8, 116u8, 105u8, 114u8,
+                    97u8, 110u8, 106u8, 97u8, 118u8, 97u8, 108u8, 117u8, 116u8, 97u8, 112u8, 114u8,
+                    105u8, 107u8, 97u8, 122u8, 105u8, 118u8, 97u8, 110u8, 106u8, 101u8, 32u8,
+                    118u8, 114u8, 101u8, 109u8, 101u8, 110u8, 97u8, 32u8, 40u8, 49u8, 50u8, 45u8,
+                    32u8, 105u8, 108u8, 105u8, 32u8, 50u8, 52u8, 45u8, 196u8, 141u8, 97u8, 115u8,
+                    111u8, 118u8, 110u8, 111u8, 41u8, 115u8, 111u8, 114u8, 116u8, 105u8, 114u8,
+                    97u8, 110u8, 106u8, 101u8, 32u8, 117u8, 122u8, 32u8, 105u8, 103u8, 110u8,
+                    111u8, 114u8, 105u8, 115u8, 97u8, 110u8, 106u8, 101u8, 32u8, 115u8, 105u8,
+                    109u8, 98u8, 111u8, 108u8, 97u8, 115u8, 111u8, 114u8, 116u8, 105u8, 114u8,
+                    97u8, 110u8, 106u8, 101u8, 32u8, 112u8, 114u8, 101u8, 109u8, 97u8, 32u8, 111u8,
+                    98u8, 114u8, 110u8, 117u8, 116u8, 105u8, 109u8, 32u8, 97u8, 107u8, 99u8, 101u8,
+                    110u8, 116u8, 105u8, 109u8, 97u8, 115u8, 111u8, 114u8, 116u8, 105u8, 114u8,
+                    97u8, 110u8, 106u8, 101u8, 32u8, 112u8, 114u8, 101u8, 109u8, 97u8, 32u8, 109u8,
+                    97u8, 108u8, 111u8, 109u8, 47u8, 118u8, 101u8, 108u8, 105u8, 107u8, 111u8,
+                    109u8, 32u8, 115u8, 108u8, 111u8, 118u8, 117u8, 114u8, 101u8, 196u8, 145u8,
+                    97u8, 110u8, 106u8, 101u8, 32u8, 112u8, 114u8, 101u8, 109u8, 97u8, 32u8, 109u8,
+                    97u8, 108u8, 111u8, 109u8, 47u8, 118u8, 101u8, 108u8, 105u8, 107u8, 111u8,
+                    109u8, 32u8, 115u8, 108u8, 111u8, 118u8, 117u8, 110u8, 111u8, 114u8, 109u8,
+                    97u8, 108u8, 105u8, 122u8, 111u8, 118u8, 97u8, 110u8, 111u8, 32u8, 115u8,
+                    111u8, 114u8, 116u8, 105u8, 114u8, 97u8, 110u8, 106u8, 101u8, 110u8, 117u8,
+                    109u8, 101u8, 114u8, 105u8, 196u8, 141u8, 107u8, 111u8, 32u8, 115u8, 111u8,
+                    114u8, 116u8, 105u8, 114u8, 97u8, 110u8, 106u8, 101u8, 115u8, 111u8, 114u8,
+                    116u8, 105u8, 114u8, 97u8, 110u8, 106u8, 101u8, 32u8, 112u8, 114u8, 101u8,
+                    109u8, 97u8, 32u8, 106u8, 97u8, 196u8, 141u8, 105u8, 110u8, 105u8, 115u8,
+                    116u8, 105u8, 108u8, 32u8, 112u8, 114u8, 101u8, 108u8, 111u8, 109u8, 97u8,
+                    32u8, 114u8, 101u8, 100u8, 97u8, 115u8, 105u8, 115u8, 116u8, 101u8, 109u8,
+                    32u8, 109u8, 101u8, 114u8, 110u8, 105u8, 104u8, 32u8, 106u8, 101u8, 100u8,
+                    105u8, 110u8, 105u8, 99u8, 97u8, 98u8, 114u8, 111u8, 106u8, 101u8, 118u8,
+                    105u8, 86u8, 114u8, 101u8, 109u8, 101u8, 110u8, 115u8, 107u8, 97u8, 32u8,
+                    122u8, 111u8, 110u8, 97u8, 86u8, 97u8, 114u8, 105u8, 106u8, 97u8, 110u8, 116u8,
+                    97u8, 32u8, 108u8, 111u8, 107u8, 97u8, 108u8, 105u8, 116u8, 101u8, 116u8, 97u8,
+                ])
+            },
+        )
+    },
+    types: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    108u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 21u8, 0u8, 30u8, 0u8, 38u8, 0u8,
+                    48u8, 0u8, 59u8, 0u8, 69u8, 0u8, 78u8, 0u8, 87u8, 0u8, 97u8, 0u8, 113u8, 0u8,
+                    128u8, 0u8, 147u8, 0u8, 157u8, 0u8, 168u8, 0u8, 178u8, 0u8, 184u8, 0u8, 194u8,
+                    0u8, 205u8, 0u8, 215u8, 0u8, 224u8, 0u8, 231u8, 0u8, 239u8, 0u8, 245u8, 0u8,
+                    254u8, 0u8, 8u8, 1u8, 19u8, 1u8, 28u8, 1u8, 39u8, 1u8, 48u8, 1u8, 59u8, 1u8,
+                    70u8, 1u8, 79u8, 1u8, 86u8, 1u8, 95u8, 1u8, 104u8, 1u8, 110u8, 1u8, 116u8, 1u8,
+                    122u8, 1u8, 128u8, 1u8, 139u8, 1u8, 149u8, 1u8, 157u8, 1u8, 164u8, 1u8, 172u8,
+                    1u8, 179u8, 1u8, 187u8, 1u8, 195u8, 1u8, 203u8, 1u8, 211u8, 1u8, 218u8, 1u8,
+                    226u8, 1u8, 233u8, 1u8, 243u8, 1u8, 252u8, 1u8, 5u8, 2u8, 14u8, 2u8, 23u8, 2u8,
+                    31u8, 2u8, 40u8, 2u8, 49u8, 2u8, 58u8, 2u8, 69u8, 2u8, 80u8, 2u8, 87u8, 2u8,
+                    97u8, 2u8, 104u8, 2u8, 114u8, 2u8, 121u8, 2u8, 128u8, 2u8, 135u8, 2u8, 142u8,
+                    2u8, 152u8, 2u8, 163u8, 2u8, 170u8, 2u8, 177u8, 2u8, 187u8, 2u8, 194u8, 2u8,
+                    201u8, 2u8, 211u8, 2u8, 218u8, 2u8, 228u8, 2u8, 235u8, 2u8, 245u8, 2u8, 252u8,
+                    2u8, 3u8, 3u8, 10u8, 3u8, 20u8, 3u8, 27u8, 3u8, 34u8, 3u8, 41u8, 3u8, 48u8,
+                    3u8, 55u8, 3u8, 62u8, 3u8, 69u8, 3u8, 76u8, 3u8, 85u8, 3u8, 92u8, 3u8, 99u8,
+                    3u8, 107u8, 3u8, 118u8, 3u8, 125u8, 3u8, 135u8, 3u8, 142u8, 3u8, 149u8, 3u8,
+                    156u8, 3u8, 167u8, 3u8, 99u8, 97u8, 45u8, 98u8, 117u8, 100u8, 100u8, 104u8,
+                    105u8, 115u8, 116u8, 99u8, 97u8, 45u8, 99u8, 104u8, 105u8, 110u8, 101u8, 115u8,
+                    101u8, 99u8, 97u8, 45u8, 99u8, 111u8, 112u8, 116u8, 105u8, 99u8, 99u8, 97u8,
+                    45u8, 100u8, 97u8, 110u8, 103u8, 105u8, 99u8, 97u8, 45u8, 101u8, 116u8, 104u8,
+                    105u8, 111u8, 97u8, 97u8, 99u8, 97u8, 45u8, 101u8, 116u8, 104u8, 105u8, 111u8,
+                    112u8, 105u8, 99u8, 99u8, 97u8, 45u8, 103u8, 114u8, 101u8, 103u8, 111u8, 114u8,
+                    121u8, 99u8, 97u8, 45u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 99u8, 97u8,
+                    45u8, 105u8, 110u8, 100u8, 105u8, 97u8, 110u8, 99u8, 97u8, 45u8, 105u8, 115u8,
+                    108u8, 97u8, 109u8, 105u8, 99u8, 99u8, 97u8, 45u8, 105u8, 115u8, 108u8, 97u8,
+                    109u8, 105u8, 99u8, 45u8, 99u8, 105u8, 118u8, 105u8, 108u8, 99u8, 97u8, 45u8,
+                    105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 116u8, 98u8, 108u8, 97u8,
+                    99u8, 97u8, 45u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 117u8,
+                    109u8, 97u8, 108u8, 113u8, 117u8, 114u8, 97u8, 99u8, 97u8, 45u8, 105u8, 115u8,
+                    111u8, 56u8, 54u8, 48u8, 49u8, 99u8, 97u8, 45u8, 106u8, 97u8, 112u8, 97u8,
+                    110u8, 101u8, 115u8, 101u8, 99u8, 97u8, 45u8, 112u8, 101u8, 114u8, 115u8,
+                    105u8, 97u8, 110u8, 99u8, 97u8, 45u8, 114u8, 111u8, 99u8, 99u8, 102u8, 45u8,
+                    97u8, 99u8, 99u8, 111u8, 117u8, 110u8, 116u8, 99u8, 102u8, 45u8, 115u8, 116u8,
+                    97u8, 110u8, 100u8, 97u8, 114u8, 100u8, 99u8, 111u8, 45u8, 98u8, 105u8, 103u8,
+                    53u8, 104u8, 97u8, 110u8, 99u8, 111u8, 45u8, 99u8, 111u8, 109u8, 112u8, 97u8,
+                    116u8, 99u8, 111u8, 45u8, 100u8, 105u8, 99u8, 116u8, 99u8, 111u8, 45u8, 100u8,
+                    117u8, 99u8, 101u8, 116u8, 99u8, 111u8, 45u8, 101u8, 111u8, 114u8, 99u8, 111u8,
+                    45u8, 103u8, 98u8, 50u8, 51u8, 49u8, 50u8, 99u8, 111u8, 45u8, 112u8, 104u8,
+                    111u8, 110u8, 101u8, 98u8, 107u8, 99u8, 111u8, 45u8, 112u8, 104u8, 111u8,
+                    110u8, 101u8, 116u8, 105u8, 99u8, 99u8, 111u8, 45u8, 112u8, 105u8, 110u8,
+                    121u8, 105u8, 110u8, 99u8, 111u8, 45u8, 114u8, 101u8, 102u8, 111u8, 114u8,
+                    109u8, 101u8, 100u8, 99u8, 111u8, 45u8, 115u8, 101u8, 97u8, 114u8, 99u8, 104u8,
+                    99u8, 111u8, 45u8, 115u8, 101u8, 97u8, 114u8, 99u8, 104u8, 106u8, 108u8, 99u8,
+                    111u8, 45u8, 115u8, 116u8, 97u8, 110u8, 100u8, 97u8, 114u8, 100u8, 99u8, 111u8,
+                    45u8, 115u8, 116u8, 114u8, 111u8, 107u8, 101u8, 99u8, 111u8, 45u8, 116u8,
+                    114u8, 97u8, 100u8, 99u8, 111u8, 45u8, 117u8, 110u8, 105u8, 104u8, 97u8, 110u8,
+                    99u8, 111u8, 45u8, 122u8, 104u8, 117u8, 121u8, 105u8, 110u8, 104u8, 99u8, 45u8,
+                    104u8, 49u8, 49u8, 104u8, 99u8, 45u8, 104u8, 49u8, 50u8, 104u8, 99u8, 45u8,
+                    104u8, 50u8, 51u8, 104u8, 99u8, 45u8, 104u8, 50u8, 52u8, 107u8, 97u8, 45u8,
+                    110u8, 111u8, 105u8, 103u8, 110u8, 111u8, 114u8, 101u8, 107u8, 97u8, 45u8,
+                    115u8, 104u8, 105u8, 102u8, 116u8, 101u8, 100u8, 107u8, 98u8, 45u8, 102u8,
+                    97u8, 108u8, 115u8, 101u8, 107u8, 98u8, 45u8, 116u8, 114u8, 117u8, 101u8,
+                    107u8, 99u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8, 107u8, 99u8, 45u8, 116u8,
+                    114u8, 117u8, 101u8, 107u8, 102u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8,
+                    107u8, 102u8, 45u8, 108u8, 111u8, 119u8, 101u8, 114u8, 107u8, 102u8, 45u8,
+                    117u8, 112u8, 112u8, 101u8, 114u8, 107u8, 107u8, 45u8, 102u8, 97u8, 108u8,
+                    115u8, 101u8, 107u8, 107u8, 45u8, 116u8, 114u8, 117u8, 101u8, 107u8, 110u8,
+                    45u8, 102u8, 97u8, 108u8, 115u8, 101u8, 107u8, 110u8, 45u8, 116u8, 114u8,
+                    117u8, 101u8, 107u8, 115u8, 45u8, 105u8, 100u8, 101u8, 110u8, 116u8, 105u8,
+                    99u8, 107u8, 115u8, 45u8, 108u8, 101u8, 118u8, 101u8, 108u8, 49u8, 107u8,
+                    115u8, 45u8, 108u8, 101u8, 118u8, 101u8, 108u8, 50u8, 107u8, 115u8, 45u8,
+                    108u8, 101u8, 118u8, 101u8, 108u8, 51u8, 107u8, 115u8, 45u8, 108u8, 101u8,
+                    118u8, 101u8, 108u8, 52u8, 108u8, 98u8, 45u8, 108u8, 111u8, 111u8, 115u8,
+                    101u8, 108u8, 98u8, 45u8, 110u8, 111u8, 114u8, 109u8, 97u8, 108u8, 108u8, 98u8,
+                    45u8, 115u8, 116u8, 114u8, 105u8, 99u8, 116u8, 109u8, 115u8, 45u8, 109u8,
+                    101u8, 116u8, 114u8, 105u8, 99u8, 109u8, 115u8, 45u8, 117u8, 107u8, 115u8,
+                    121u8, 115u8, 116u8, 101u8, 109u8, 109u8, 115u8, 45u8, 117u8, 115u8, 115u8,
+                    121u8, 115u8, 116u8, 101u8, 109u8, 110u8, 117u8, 45u8, 97u8, 114u8, 97u8, 98u8,
+                    110u8, 117u8, 45u8, 97u8, 114u8, 97u8, 98u8, 101u8, 120u8, 116u8, 110u8, 117u8,
+                    45u8, 97u8, 114u8, 109u8, 110u8, 110u8, 117u8, 45u8, 97u8, 114u8, 109u8, 110u8,
+                    108u8, 111u8, 119u8, 110u8, 117u8, 45u8, 98u8, 101u8, 110u8, 103u8, 110u8,
+                    117u8, 45u8, 99u8, 97u8, 107u8, 109u8, 110u8, 117u8, 45u8, 100u8, 101u8, 118u8,
+                    97u8, 110u8, 117u8, 45u8, 101u8, 116u8, 104u8, 105u8, 110u8, 117u8, 45u8,
+                    102u8, 105u8, 110u8, 97u8, 110u8, 99u8, 101u8, 110u8, 117u8, 45u8, 102u8,
+                    117u8, 108u8, 108u8, 119u8, 105u8, 100u8, 101u8, 110u8, 117u8, 45u8, 103u8,
+                    101u8, 111u8, 114u8, 110u8, 117u8, 45u8, 103u8, 114u8, 101u8, 107u8, 110u8,
+                    117u8, 45u8, 103u8, 114u8, 101u8, 107u8, 108u8, 111u8, 119u8, 110u8, 117u8,
+                    45u8, 103u8, 117u8, 106u8, 114u8, 110u8, 117u8, 45u8, 103u8, 117u8, 114u8,
+                    117u8, 110u8, 117u8, 45u8, 104u8, 97u8, 110u8, 105u8, 100u8, 101u8, 99u8,
+                    110u8, 117u8, 45u8, 104u8, 97u8, 110u8, 115u8, 110u8, 117u8, 45u8, 104u8, 97u8,
+                    110u8, 115u8, 102u8, 105u8, 110u8, 110u8, 117u8, 45u8, 104u8, 97u8, 110u8,
+                    116u8, 110u8, 117u8, 45u8, 104u8, 97u8, 110u8, 116u8, 102u8, 105u8, 110u8,
+                    110u8, 117u8, 45u8, 104u8, 101u8, 98u8, 114u8, 110u8, 117u8, 45u8, 106u8, 97u8,
+                    118u8, 97u8, 110u8, 117u8, 45u8, 106u8, 112u8, 97u8, 110u8, 110u8, 117u8, 45u8,
+                    106u8, 112u8, 97u8, 110u8, 102u8, 105u8, 110u8, 110u8, 117u8, 45u8, 107u8,
+                    104u8, 109u8, 114u8, 110u8, 117u8, 45u8, 107u8, 110u8, 100u8, 97u8, 110u8,
+                    117u8, 45u8, 108u8, 97u8, 111u8, 111u8, 110u8, 117u8, 45u8, 108u8, 97u8, 116u8,
+                    110u8, 110u8, 117u8, 45u8, 109u8, 108u8, 121u8, 109u8, 110u8, 117u8, 45u8,
+                    109u8, 111u8, 110u8, 103u8, 110u8, 117u8, 45u8, 109u8, 116u8, 101u8, 105u8,
+                    110u8, 117u8, 45u8, 109u8, 121u8, 109u8, 114u8, 110u8, 117u8, 45u8, 110u8,
+                    97u8, 116u8, 105u8, 118u8, 101u8, 110u8, 117u8, 45u8, 111u8, 108u8, 99u8,
+                    107u8, 110u8, 117u8, 45u8, 111u8, 114u8, 121u8, 97u8, 110u8, 117u8, 45u8,
+                    114u8, 111u8, 109u8, 97u8, 110u8, 110u8, 117u8, 45u8, 114u8, 111u8, 109u8,
+                    97u8, 110u8, 108u8, 111u8, 119u8, 110u8, 117u8, 45u8, 116u8, 97u8, 109u8,
+                    108u8, 110u8, 117u8, 45u8, 116u8, 97u8, 109u8, 108u8, 100u8, 101u8, 99u8,
+                    110u8, 117u8, 45u8, 116u8, 101u8, 108u8, 117u8, 110u8, 117u8, 45u8, 116u8,
+                    104u8, 97u8, 105u8, 110u8, 117u8, 45u8, 116u8, 105u8, 98u8, 116u8, 110u8,
+                    117u8, 45u8, 116u8, 114u8, 97u8, 100u8, 105u8, 116u8, 105u8, 111u8, 110u8,
+                    117u8, 45u8, 118u8, 97u8, 105u8, 105u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    108u8, 0u8, 0u8, 0u8, 0u8, 0u8, 20u8, 0u8, 36u8, 0u8, 52u8, 0u8, 66u8, 0u8,
+                    93u8, 0u8, 110u8, 0u8, 132u8, 0u8, 150u8, 0u8, 178u8, 0u8, 195u8, 0u8, 220u8,
+                    0u8, 249u8, 0u8, 24u8, 1u8, 41u8, 1u8, 58u8, 1u8, 76u8, 1u8, 99u8, 1u8, 129u8,
+                    1u8, 153u8, 1u8, 185u8, 1u8, 236u8, 1u8, 10u8, 2u8, 51u8, 2u8, 77u8, 2u8,
+                    112u8, 2u8, 144u8, 2u8, 172u8, 2u8, 189u8, 2u8, 220u8, 2u8, 242u8, 2u8, 28u8,
+                    3u8, 58u8, 3u8, 84u8, 3u8, 108u8, 3u8, 145u8, 3u8, 151u8, 3u8, 176u8, 3u8,
+                    201u8, 3u8, 226u8, 3u8, 251u8, 3u8, 11u8, 4u8, 44u8, 4u8, 69u8, 4u8, 93u8, 4u8,
+                    135u8, 4u8, 163u8, 4u8, 211u8, 4u8, 235u8, 4u8, 5u8, 5u8, 31u8, 5u8, 61u8, 5u8,
+                    88u8, 5u8, 113u8, 5u8, 125u8, 5u8, 152u8, 5u8, 168u8, 5u8, 212u8, 5u8, 13u8,
+                    6u8, 41u8, 6u8, 67u8, 6u8, 91u8, 6u8, 100u8, 6u8, 111u8, 6u8, 120u8, 6u8,
+                    142u8, 6u8, 175u8, 6u8, 192u8, 6u8, 214u8, 6u8, 229u8, 6u8, 241u8, 6u8, 0u8,
+                    7u8, 16u8, 7u8, 35u8, 7u8, 53u8, 7u8, 70u8, 7u8, 84u8, 7u8, 103u8, 7u8, 121u8,
+                    7u8, 134u8, 7u8, 159u8, 7u8, 191u8, 7u8, 235u8, 7u8, 8u8, 8u8, 49u8, 8u8, 66u8,
+                    8u8, 80u8, 8u8, 96u8, 8u8, 124u8, 8u8, 137u8, 8u8, 149u8, 8u8, 162u8, 8u8,
+                    175u8, 8u8, 190u8, 8u8, 205u8, 8u8, 222u8, 8u8, 239u8, 8u8, 252u8, 8u8, 10u8,
+                    9u8, 21u8, 9u8, 35u8, 9u8, 54u8, 9u8, 70u8, 9u8, 84u8, 9u8, 96u8, 9u8, 108u8,
+                    9u8, 124u8, 9u8, 145u8, 9u8, 98u8, 117u8, 100u8, 105u8, 115u8, 116u8, 105u8,
+                    196u8, 141u8, 107u8, 105u8, 32u8, 107u8, 97u8, 108u8, 101u8, 110u8, 100u8,
+                    97u8, 114u8, 107u8, 105u8, 110u8, 101u8, 115u8, 107u8, 105u8, 32u8, 107u8,
+                    97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 107u8, 111u8, 112u8, 116u8,
+                    115u8, 107u8, 105u8, 32u8, 107u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8,
+                    114u8, 100u8, 97u8, 110u8, 103u8, 105u8, 32u8, 107u8, 97u8, 108u8, 101u8,
+                    110u8, 100u8, 97u8, 114u8, 101u8, 116u8, 105u8, 111u8, 112u8, 115u8, 107u8,
+                    105u8, 32u8, 97u8, 109u8, 101u8, 116u8, 32u8, 97u8, 108u8, 101u8, 109u8, 32u8,
+                    107u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 101u8, 116u8, 105u8,
+                    111u8, 112u8, 115u8, 107u8, 105u8, 32u8, 107u8, 97u8, 108u8, 101u8, 110u8,
+                    100u8, 97u8, 114u8, 103u8, 114u8, 101u8, 103u8, 111u8, 114u8, 105u8, 106u8,
+                    97u8, 110u8, 115u8, 107u8, 105u8, 32u8, 107u8, 97u8, 108u8, 101u8, 110u8,
+                    100u8, 97u8, 114u8, 104u8, 101u8, 98u8, 114u8, 101u8, 106u8, 115u8, 107u8,
+                    105u8, 32u8, 107u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 73u8, 110u8,
+                    100u8, 105u8, 106u8, 115u8, 107u8, 105u8, 32u8, 110u8, 97u8, 99u8, 105u8,
+                    111u8, 110u8, 97u8, 108u8, 110u8, 105u8, 32u8, 107u8, 97u8, 108u8, 101u8,
+                    110u8, 100u8, 97u8, 114u8, 105u8, 115u8, 108u8, 97u8, 109u8, 115u8, 107u8,
+                    105u8, 32u8, 107u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 105u8,
+                    115u8, 108u8, 97u8, 109u8, 115u8, 107u8, 105u8, 32u8, 99u8, 105u8, 118u8,
+                    105u8, 108u8, 110u8, 105u8, 32u8, 107u8, 97u8, 108u8, 101u8, 110u8, 100u8,
+                    97u8, 114u8, 105u8, 115u8, 108u8, 97u8, 109u8, 115u8, 107u8, 105u8, 32u8, 97u8,
+                    115u8, 116u8, 114u8, 111u8, 110u8, 111u8, 109u8, 115u8, 107u8, 105u8, 32u8,
+                    107u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 105u8, 115u8, 108u8,
+                    97u8, 109u8, 115u8, 107u8, 105u8, 32u8, 107u8, 97u8, 108u8, 101u8, 110u8,
+                    100u8, 97u8, 114u8, 32u8, 40u8, 85u8, 109u8, 109u8, 32u8, 97u8, 108u8, 45u8,
+                    81u8, 117u8, 114u8, 97u8, 41u8, 73u8, 83u8, 79u8, 45u8, 56u8, 54u8, 48u8, 49u8,
+                    32u8, 107u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 106u8, 97u8, 112u8,
+                    97u8, 110u8, 115u8, 107u8, 105u8, 32u8, 107u8, 97u8, 108u8, 101u8, 110u8,
+                    100u8, 97u8, 114u8, 112u8, 101u8, 114u8, 115u8, 105u8, 106u8, 115u8, 107u8,
+                    105u8, 32u8, 107u8, 97u8, 108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 107u8, 97u8,
+                    108u8, 101u8, 110u8, 100u8, 97u8, 114u8, 32u8, 82u8, 101u8, 112u8, 117u8, 98u8,
+                    108u8, 105u8, 107u8, 101u8, 32u8, 75u8, 105u8, 110u8, 101u8, 114u8, 97u8,
+                    196u8, 141u8, 117u8, 110u8, 111u8, 118u8, 111u8, 100u8, 115u8, 116u8, 118u8,
+                    101u8, 110u8, 105u8, 32u8, 102u8, 111u8, 114u8, 109u8, 97u8, 116u8, 32u8,
+                    118u8, 97u8, 108u8, 117u8, 116u8, 101u8, 115u8, 116u8, 97u8, 110u8, 100u8,
+                    97u8, 114u8, 100u8, 110u8, 105u8, 32u8, 102u8, 111u8, 114u8, 109u8, 97u8,
+                    116u8, 32u8, 118u8, 97u8, 108u8, 117u8, 116u8, 101u8, 116u8, 114u8, 97u8,
+                    100u8, 105u8, 99u8, 105u8, 111u8, 110u8, 97u8, 108u8, 110u8, 111u8, 32u8,
+                    107u8, 105u8, 110u8, 101u8, 115u8, 107u8, 111u8, 32u8, 115u8, 111u8, 114u8,
+                    116u8, 105u8, 114u8, 97u8, 110u8, 106u8, 101u8, 112u8, 114u8, 101u8, 116u8,
+                    104u8, 111u8, 100u8, 110u8, 105u8, 32u8, 114u8, 101u8, 100u8, 111u8, 115u8,
+                    108u8, 101u8, 100u8, 32u8, 115u8, 111u8, 114u8, 116u8, 105u8, 114u8, 97u8,
+                    110u8, 106u8, 97u8, 44u8, 32u8, 122u8, 98u8, 111u8, 103u8, 32u8, 107u8, 111u8,
+                    109u8, 112u8, 97u8, 116u8, 105u8, 98u8, 105u8, 108u8, 110u8, 111u8, 115u8,
+                    116u8, 105u8, 114u8, 101u8, 100u8, 111u8, 115u8, 108u8, 101u8, 100u8, 32u8,
+                    115u8, 111u8, 114u8, 116u8, 105u8, 114u8, 97u8, 110u8, 106u8, 97u8, 32u8,
+                    117u8, 32u8, 114u8, 101u8, 196u8, 141u8, 110u8, 105u8, 107u8, 117u8, 112u8,
+                    111u8, 100u8, 114u8, 97u8, 122u8, 117u8, 109u8, 101u8, 118u8, 97u8, 110u8,
+                    105u8, 32u8, 85u8, 110u8, 105u8, 99u8, 111u8, 100u8, 101u8, 32u8, 114u8, 101u8,
+                    100u8, 111u8, 115u8, 108u8, 101u8, 100u8, 32u8, 115u8, 111u8, 114u8, 116u8,
+                    105u8, 114u8, 97u8, 110u8, 106u8, 97u8, 101u8, 118u8, 114u8, 111u8, 112u8,
+                    115u8, 107u8, 97u8, 32u8, 112u8, 114u8, 97u8, 118u8, 105u8, 108u8, 97u8, 32u8,
+                    114u8, 101u8, 100u8, 111u8, 115u8, 108u8, 101u8, 100u8, 97u8, 112u8, 111u8,
+                    106u8, 101u8, 100u8, 110u8, 111u8, 115u8, 116u8, 97u8, 118u8, 108u8, 106u8,
+                    101u8, 110u8, 111u8, 32u8, 107u8, 105u8, 110u8, 101u8, 115u8, 107u8, 111u8,
+                    32u8, 115u8, 111u8, 114u8, 116u8, 105u8, 114u8, 97u8, 110u8, 106u8, 101u8,
+                    115u8, 111u8, 114u8, 116u8, 105u8, 114u8, 97u8, 110u8, 106u8, 101u8, 32u8,
+                    107u8, 97u8, 111u8, 32u8, 116u8, 101u8, 108u8, 101u8, 102u8, 111u8, 110u8,
+                    115u8, 107u8, 105u8, 32u8, 105u8, 109u8, 101u8, 110u8, 105u8, 107u8, 102u8,
+                    111u8, 110u8, 101u8, 116u8, 115u8, 107u8, 105u8, 32u8, 114u8, 101u8, 100u8,
+                    111u8, 115u8, 108u8, 101u8, 100u8, 32u8, 115u8, 111u8, 114u8, 116u8, 105u8,
+                    114u8, 97u8, 110u8, 106u8, 97u8, 112u8, 105u8, 110u8, 106u8, 105u8, 110u8,
+                    32u8, 115u8, 111u8, 114u8, 116u8, 105u8, 114u8, 97u8, 110u8, 106u8, 101u8,
+                    114u8, 101u8, 102u8, 111u8, 114u8, 109u8, 105u8, 115u8, 97u8, 110u8, 105u8,
+                    32u8, 114u8, 101u8, 100u8, 111u8, 115u8, 108u8, 101u8, 100u8, 32u8, 115u8,
+                    111u8, 114u8, 116u8, 105u8, 114u8, 97u8, 110u8, 106u8, 97u8, 112u8, 114u8,
+                    101u8, 116u8, 114u8, 97u8, 103u8, 97u8, 32u8, 111u8, 112u8, 197u8, 161u8,
+                    116u8, 101u8, 32u8, 110u8, 97u8, 109u8, 101u8, 110u8, 101u8, 80u8, 114u8,
+                    101u8, 116u8, 114u8, 97u8, 103u8, 97u8, 32u8, 112u8, 114u8, 101u8, 109u8, 97u8,
+                    32u8, 104u8, 97u8, 110u8, 103u8, 117u8, 108u8, 32u8, 112u8, 111u8, 196u8,
+                    141u8, 101u8, 116u8, 110u8, 111u8, 109u8, 32u8, 115u8, 117u8, 103u8, 108u8,
+                    97u8, 115u8, 110u8, 105u8, 107u8, 117u8, 115u8, 116u8, 97u8, 110u8, 100u8,
+                    97u8, 114u8, 100u8, 110u8, 105u8, 32u8, 114u8, 101u8, 100u8, 111u8, 115u8,
+                    108u8, 101u8, 100u8, 32u8, 115u8, 111u8, 114u8, 116u8, 105u8, 114u8, 97u8,
+                    110u8, 106u8, 97u8, 115u8, 111u8, 114u8, 116u8, 105u8, 114u8, 97u8, 110u8,
+                    106u8, 101u8, 32u8, 112u8, 111u8, 32u8, 98u8, 114u8, 111u8, 106u8, 117u8, 32u8,
+                    112u8, 111u8, 116u8, 101u8, 122u8, 97u8, 116u8, 114u8, 97u8, 100u8, 105u8,
+                    99u8, 105u8, 111u8, 110u8, 97u8, 108u8, 110u8, 111u8, 32u8, 115u8, 111u8,
+                    114u8, 116u8, 105u8, 114u8, 97u8, 110u8, 106u8, 101u8, 114u8, 101u8, 100u8,
+                    111u8, 115u8, 108u8, 101u8, 100u8, 32u8, 115u8, 111u8, 114u8, 116u8, 105u8,
+                    114u8, 97u8, 110u8, 106u8, 97u8, 32u8, 114u8, 97u8, 100u8, 105u8, 107u8, 97u8,
+                    108u8, 110u8, 105u8, 104u8, 32u8, 112u8, 111u8, 116u8, 101u8, 122u8, 97u8,
+                    197u8, 190u8, 117u8, 106u8, 105u8, 110u8, 49u8, 50u8, 45u8, 196u8, 141u8, 97u8,
+                    115u8, 111u8, 118u8, 110u8, 105u8, 32u8, 115u8, 105u8, 115u8, 116u8, 101u8,
+                    109u8, 32u8, 40u8, 48u8, 45u8, 49u8, 49u8, 41u8, 49u8, 50u8, 45u8, 196u8,
+                    141u8, 97u8, 115u8, 111u8, 118u8, 110u8, 105u8, 32u8, 115u8, 105u8, 115u8,
+                    116u8, 101u8, 109u8, 32u8, 40u8, 49u8, 45u8, 49u8, 50u8, 41u8, 50u8, 52u8,
+                    45u8, 196u8, 141u8, 97u8, 115u8, 111u8, 118u8, 110u8, 105u8, 32u8, 115u8,
+                    105u8, 115u8, 116u8, 101u8, 109u8, 32u8, 40u8, 48u8, 45u8, 50u8, 51u8, 41u8,
+                    50u8, 52u8, 45u8, 196u8, 141u8, 97u8, 115u8, 111u8, 118u8, 110u8, 105u8, 32u8,
+                    115u8, 105u8, 115u8, 116u8, 101u8, 109u8, 32u8, 40u8, 49u8, 45u8, 50u8, 52u8,
+                    41u8, 83u8, 111u8, 114u8, 116u8, 105u8, 114u8, 97u8, 106u8, 32u8, 115u8, 105u8,
+                    109u8, 98u8, 111u8, 108u8, 101u8, 83u8, 111u8, 114u8, 116u8, 105u8, 114u8,
+                    97u8, 110u8, 106u8, 101u8, 32u8, 117u8, 122u8, 32u8, 105u8, 103u8, 110u8,
+                    111u8, 114u8, 105u8, 115u8, 97u8, 110u8, 106u8, 101u8, 32u8, 115u8, 105u8,
+                    109u8, 98u8, 111u8, 108u8, 97u8, 83u8, 111u8, 114u8, 116u8, 105u8, 114u8, 97u8,
+                    106u8, 32u8, 97u8, 107u8, 99u8, 101u8, 110u8, 116u8, 101u8, 32u8, 110u8, 111u8,
+                    114u8, 109u8, 97u8, 108u8, 110u8, 111u8, 83u8, 111u8, 114u8, 116u8, 105u8,
+                    114u8, 97u8, 106u8, 32u8, 97u8, 107u8, 99u8, 101u8, 110u8, 116u8, 101u8, 32u8,
+                    111u8, 98u8, 114u8, 110u8, 117u8, 116u8, 111u8, 83u8, 111u8, 114u8, 116u8,
+                    105u8, 114u8, 97u8, 106u8, 32u8, 98u8, 101u8, 122u8, 32u8, 111u8, 98u8, 122u8,
+                    105u8, 114u8, 97u8, 32u8, 110u8, 97u8, 32u8, 118u8, 101u8, 108u8, 105u8, 107u8,
+                    97u8, 32u8, 105u8, 32u8, 109u8, 97u8, 108u8, 97u8, 32u8, 115u8, 108u8, 111u8,
+                    118u8, 97u8, 83u8, 111u8, 114u8, 116u8, 105u8, 114u8, 97u8, 106u8, 32u8, 109u8,
+                    97u8, 108u8, 97u8, 32u8, 105u8, 32u8, 118u8, 101u8, 108u8, 105u8, 107u8, 97u8,
+                    32u8, 115u8, 108u8, 111u8, 118u8, 97u8, 83u8, 111u8, 114u8, 116u8, 105u8,
+                    114u8, 97u8, 106u8, 32u8, 110u8, 111u8, 114u8, 109u8, 97u8, 108u8, 97u8, 110u8,
+                    32u8, 114u8, 101u8, 100u8, 111u8, 115u8, 108u8, 101u8, 100u8, 32u8, 118u8,
+                    101u8, 108u8, 105u8, 107u8, 105u8, 104u8, 32u8, 105u8, 32u8, 109u8, 97u8,
+                    108u8, 105u8, 104u8, 32u8, 115u8, 108u8, 111u8, 118u8, 97u8, 83u8, 111u8,
+                    114u8, 116u8, 105u8, 114u8, 97u8, 106u8, 32u8, 112u8, 114u8, 118u8, 111u8,
+                    32u8, 109u8, 97u8, 108u8, 97u8, 32u8, 115u8, 108u8, 111u8, 118u8, 97u8, 83u8,
+                    111u8, 114u8, 116u8, 105u8, 114u8, 97u8, 106u8, 32u8, 112u8, 114u8, 118u8,
+                    111u8, 32u8, 118u8, 101u8, 108u8, 105u8, 107u8, 97u8, 32u8, 115u8, 108u8,
+                    111u8, 118u8, 97u8, 83u8, 111u8, 114u8, 116u8, 105u8, 114u8, 97u8, 106u8, 32u8,
+                    98u8, 101u8, 122u8, 32u8, 110u8, 111u8, 114u8, 109u8, 97u8, 108u8, 105u8,
+                    122u8, 97u8, 99u8, 105u8, 106u8, 101u8, 83u8, 111u8, 114u8, 116u8, 105u8,
+                    114u8, 97u8, 106u8, 32u8, 85u8, 110u8, 105u8, 99u8, 111u8, 100u8, 101u8, 32u8,
+                    110u8, 111u8, 114u8, 109u8, 97u8, 108u8, 105u8, 122u8, 111u8, 118u8, 97u8,
+                    110u8, 111u8, 83u8, 111u8, 114u8, 116u8, 105u8, 114u8, 97u8, 106u8, 32u8, 99u8,
+                    105u8, 102u8, 114u8, 101u8, 32u8, 112u8, 111u8, 106u8, 101u8, 100u8, 105u8,
+                    110u8, 97u8, 196u8, 141u8, 110u8, 111u8, 83u8, 111u8, 114u8, 116u8, 105u8,
+                    114u8, 97u8, 106u8, 32u8, 99u8, 105u8, 102u8, 114u8, 101u8, 32u8, 110u8, 117u8,
+                    109u8, 101u8, 114u8, 105u8, 196u8, 141u8, 107u8, 105u8, 83u8, 111u8, 114u8,
+                    116u8, 105u8, 114u8, 97u8, 106u8, 32u8, 115u8, 118u8, 101u8, 83u8, 111u8,
+                    114u8, 116u8, 105u8, 114u8, 97u8, 106u8, 32u8, 115u8, 97u8, 109u8, 111u8, 32u8,
+                    111u8, 115u8, 110u8, 111u8, 118u8, 110u8, 97u8, 32u8, 115u8, 108u8, 111u8,
+                    118u8, 97u8, 83u8, 111u8, 114u8, 116u8, 105u8, 114u8, 97u8, 106u8, 32u8, 97u8,
+                    107u8, 99u8, 101u8, 110u8, 116u8, 101u8, 83u8, 111u8, 114u8, 116u8, 105u8,
+                    114u8, 97u8, 106u8, 32u8, 97u8, 107u8, 99u8, 101u8, 110u8, 116u8, 101u8, 47u8,
+                    109u8, 97u8, 108u8, 97u8, 32u8, 105u8, 32u8, 118u8, 101u8, 108u8, 105u8, 107u8,
+                    97u8, 32u8, 115u8, 108u8, 111u8, 118u8, 97u8, 47u8, 197u8, 161u8, 105u8, 114u8,
+                    105u8, 110u8, 117u8, 83u8, 111u8, 114u8, 116u8, 105u8, 114u8, 97u8, 106u8,
+                    32u8, 97u8, 107u8, 99u8, 101u8, 110u8, 116u8, 101u8, 47u8, 109u8, 97u8, 108u8,
+                    97u8, 32u8, 105u8, 32u8, 118u8, 101u8, 108u8, 105u8, 107u8, 97u8, 32u8, 115u8,
+                    108u8, 111u8, 118u8, 97u8, 47u8, 197u8, 161u8, 105u8, 114u8, 105u8, 110u8,
+                    117u8, 47u8, 107u8, 97u8, 110u8, 97u8, 32u8, 115u8, 105u8, 109u8, 98u8, 111u8,
+                    108u8, 101u8, 114u8, 97u8, 122u8, 109u8, 97u8, 107u8, 110u8, 117u8, 116u8,
+                    105u8, 32u8, 115u8, 116u8, 105u8, 108u8, 32u8, 112u8, 114u8, 101u8, 108u8,
+                    111u8, 109u8, 97u8, 32u8, 114u8, 101u8, 100u8, 97u8, 110u8, 111u8, 114u8,
+                    109u8, 97u8, 108u8, 110u8, 105u8, 32u8, 115u8, 116u8, 105u8, 108u8, 32u8,
+                    112u8, 114u8, 101u8, 108u8, 111u8, 109u8, 97u8, 32u8, 114u8, 101u8, 100u8,
+                    97u8, 115u8, 116u8, 114u8, 111u8, 103u8, 105u8, 32u8, 115u8, 116u8, 105u8,
+                    108u8, 32u8, 112u8, 114u8, 101u8, 108u8, 111u8, 109u8, 97u8, 32u8, 114u8,
+                    101u8, 100u8, 97u8, 109u8, 101u8, 116u8, 114u8, 105u8, 196u8, 141u8, 107u8,
+                    105u8, 105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 106u8, 97u8, 108u8, 110u8,
+                    105u8, 97u8, 109u8, 101u8, 114u8, 105u8, 196u8, 141u8, 107u8, 105u8, 97u8,
+                    114u8, 97u8, 112u8, 115u8, 107u8, 111u8, 45u8, 105u8, 110u8, 100u8, 105u8,
+                    106u8, 115u8, 107u8, 101u8, 32u8, 99u8, 105u8, 102u8, 114u8, 101u8, 112u8,
+                    114u8, 111u8, 100u8, 117u8, 197u8, 190u8, 101u8, 110u8, 101u8, 32u8, 97u8,
+                    114u8, 97u8, 112u8, 115u8, 107u8, 111u8, 45u8, 105u8, 110u8, 100u8, 105u8,
+                    106u8, 115u8, 107u8, 101u8, 32u8, 99u8, 105u8, 102u8, 114u8, 101u8, 106u8,
+                    101u8, 114u8, 109u8, 101u8, 110u8, 115u8, 107u8, 105u8, 32u8, 98u8, 114u8,
+                    111u8, 106u8, 101u8, 118u8, 105u8, 109u8, 97u8, 108u8, 105u8, 32u8, 106u8,
+                    101u8, 114u8, 109u8, 101u8, 110u8, 115u8, 107u8, 105u8, 32u8, 98u8, 114u8,
+                    111u8, 106u8, 101u8, 118u8, 105u8, 98u8, 101u8, 110u8, 103u8, 97u8, 108u8,
+                    115u8, 107u8, 101u8, 32u8, 99u8, 105u8, 102u8, 114u8, 101u8, 196u8, 141u8,
+                    97u8, 107u8, 109u8, 97u8, 32u8, 99u8, 105u8, 102u8, 114u8, 101u8, 100u8, 101u8,
+                    118u8, 97u8, 110u8, 103u8, 97u8, 114u8, 105u8, 32u8, 99u8, 105u8, 102u8, 114u8,
+                    101u8, 101u8, 116u8, 105u8, 111u8, 112u8, 115u8, 107u8, 105u8, 32u8, 98u8,
+                    114u8, 111u8, 106u8, 101u8, 118u8, 105u8, 70u8, 105u8, 110u8, 97u8, 110u8,
+                    115u8, 105u8, 106u8, 115u8, 107u8, 105u8, 32u8, 98u8, 114u8, 111u8, 106u8,
+                    101u8, 118u8, 105u8, 99u8, 105u8, 102u8, 114u8, 101u8, 32u8, 112u8, 117u8,
+                    110u8, 101u8, 32u8, 197u8, 161u8, 105u8, 114u8, 105u8, 110u8, 101u8, 103u8,
+                    114u8, 117u8, 122u8, 105u8, 106u8, 115u8, 107u8, 105u8, 32u8, 98u8, 114u8,
+                    111u8, 106u8, 101u8, 118u8, 105u8, 103u8, 114u8, 196u8, 141u8, 107u8, 105u8,
+                    32u8, 98u8, 114u8, 111u8, 106u8, 101u8, 118u8, 105u8, 109u8, 97u8, 108u8,
+                    105u8, 32u8, 103u8, 114u8, 196u8, 141u8, 107u8, 105u8, 32u8, 98u8, 114u8,
+                    111u8, 106u8, 101u8, 118u8, 105u8, 103u8, 117u8, 100u8, 197u8, 190u8, 97u8,
+                    114u8, 97u8, 116u8, 115u8, 107u8, 101u8, 32u8, 99u8, 105u8, 102u8, 114u8,
+                    101u8, 103u8, 117u8, 114u8, 109u8, 117u8, 107u8, 105u8, 32u8, 99u8, 105u8,
+                    102u8, 114u8, 101u8, 107u8, 105u8, 110u8, 101u8, 115u8, 107u8, 105u8, 32u8,
+                    100u8, 101u8, 99u8, 105u8, 109u8, 97u8, 108u8, 110u8, 105u8, 32u8, 98u8, 114u8,
+                    111u8, 106u8, 101u8, 118u8, 105u8, 112u8, 111u8, 106u8, 101u8, 100u8, 110u8,
+                    111u8, 115u8, 116u8, 97u8, 118u8, 108u8, 106u8, 101u8, 110u8, 105u8, 32u8,
+                    107u8, 105u8, 110u8, 101u8, 115u8, 107u8, 105u8, 32u8, 98u8, 114u8, 111u8,
+                    106u8, 101u8, 118u8, 105u8, 112u8, 111u8, 106u8, 101u8, 100u8, 110u8, 111u8,
+                    115u8, 116u8, 97u8, 118u8, 108u8, 106u8, 101u8, 110u8, 105u8, 32u8, 107u8,
+                    105u8, 110u8, 101u8, 115u8, 107u8, 105u8, 32u8, 102u8, 105u8, 110u8, 97u8,
+                    110u8, 115u8, 105u8, 106u8, 115u8, 107u8, 105u8, 32u8, 98u8, 114u8, 111u8,
+                    106u8, 101u8, 118u8, 105u8, 116u8, 114u8, 97u8, 100u8, 105u8, 99u8, 105u8,
+                    111u8, 110u8, 97u8, 108u8, 110u8, 105u8, 32u8, 107u8, 105u8, 110u8, 101u8,
+                    115u8, 107u8, 105u8, 32u8, 98u8, 114u8, 111u8, 106u8, 101u8, 118u8, 105u8,
+                    116u8, 114u8, 97u8, 100u8, 105u8, 99u8, 105u8, 111u8, 110u8, 97u8, 108u8,
+                    110u8, 105u8, 32u8, 107u8, 105u8, 110u8, 101u8, 115u8, 107u8, 105u8, 32u8,
+                    102u8, 105u8, 110u8, 97u8, 110u8, 115u8, 105u8, 106u8, 115u8, 107u8, 105u8,
+                    32u8, 98u8, 114u8, 111u8, 106u8, 101u8, 118u8, 105u8, 104u8, 101u8, 98u8,
+                    114u8, 101u8, 106u8, 115u8, 107u8, 105u8, 32u8, 98u8, 114u8, 111u8, 106u8,
+                    101u8, 118u8, 105u8, 106u8, 97u8, 118u8, 97u8, 110u8, 115u8, 107u8, 101u8,
+                    32u8, 99u8, 105u8, 102u8, 114u8, 101u8, 106u8, 97u8, 112u8, 97u8, 110u8, 115u8,
+                    107u8, 105u8, 32u8, 98u8, 114u8, 111u8, 106u8, 101u8, 118u8, 105u8, 106u8,
+                    97u8, 112u8, 97u8, 110u8, 115u8, 107u8, 105u8, 32u8, 102u8, 105u8, 110u8, 97u8,
+                    110u8, 115u8, 105u8, 106u8, 115u8, 107u8, 105u8, 32u8, 98u8, 114u8, 111u8,
+                    106u8, 101u8, 118u8, 105u8, 107u8, 109u8, 101u8, 114u8, 115u8, 107u8, 101u8,
+                    32u8, 99u8, 105u8, 102u8, 114u8, 101u8, 107u8, 97u8, 110u8, 97u8, 100u8, 97u8,
+                    32u8, 99u8, 105u8, 102u8, 114u8, 101u8, 108u8, 97u8, 111u8, 197u8, 161u8,
+                    107u8, 101u8, 32u8, 99u8, 105u8, 102u8, 114u8, 101u8, 122u8, 97u8, 112u8, 97u8,
+                    100u8, 110u8, 101u8, 32u8, 99u8, 105u8, 102u8, 114u8, 101u8, 109u8, 97u8,
+                    108u8, 97u8, 106u8, 97u8, 108u8, 97u8, 109u8, 32u8, 99u8, 105u8, 102u8, 114u8,
+                    101u8, 109u8, 111u8, 110u8, 103u8, 111u8, 108u8, 115u8, 107u8, 101u8, 32u8,
+                    99u8, 105u8, 102u8, 114u8, 101u8, 109u8, 105u8, 116u8, 101u8, 105u8, 32u8,
+                    109u8, 97u8, 106u8, 101u8, 107u8, 32u8, 99u8, 105u8, 102u8, 114u8, 101u8,
+                    109u8, 105u8, 106u8, 97u8, 110u8, 109u8, 97u8, 114u8, 115u8, 107u8, 101u8,
+                    32u8, 99u8, 105u8, 102u8, 114u8, 101u8, 108u8, 111u8, 107u8, 97u8, 108u8,
+                    110u8, 101u8, 32u8, 99u8, 105u8, 102u8, 114u8, 101u8, 111u8, 108u8, 32u8,
+                    196u8, 141u8, 105u8, 107u8, 105u8, 32u8, 99u8, 105u8, 102u8, 114u8, 101u8,
+                    111u8, 114u8, 105u8, 106u8, 97u8, 32u8, 99u8, 105u8, 102u8, 114u8, 101u8,
+                    114u8, 105u8, 109u8, 115u8, 107u8, 105u8, 32u8, 98u8, 114u8, 111u8, 106u8,
+                    101u8, 118u8, 105u8, 109u8, 97u8, 108u8, 105u8, 32u8, 114u8, 105u8, 109u8,
+                    115u8, 107u8, 105u8, 32u8, 98u8, 114u8, 111u8, 106u8, 101u8, 118u8, 105u8,
+                    116u8, 97u8, 109u8, 105u8, 108u8, 115u8, 107u8, 105u8, 32u8, 98u8, 114u8,
+                    111u8, 106u8, 101u8, 118u8, 105u8, 116u8, 97u8, 109u8, 105u8, 108u8, 115u8,
+                    107u8, 101u8, 32u8, 99u8, 105u8, 102u8, 114u8, 101u8, 116u8, 101u8, 108u8,
+                    117u8, 103u8, 117u8, 32u8, 99u8, 105u8, 102u8, 114u8, 101u8, 116u8, 97u8,
+                    106u8, 115u8, 107u8, 101u8, 32u8, 99u8, 105u8, 102u8, 114u8, 101u8, 116u8,
+                    105u8, 98u8, 101u8, 116u8, 97u8, 110u8, 115u8, 107u8, 101u8, 32u8, 99u8, 105u8,
+                    102u8, 114u8, 101u8, 84u8, 114u8, 97u8, 100u8, 105u8, 99u8, 105u8, 111u8,
+                    110u8, 97u8, 108u8, 110u8, 105u8, 32u8, 98u8, 114u8, 111u8, 106u8, 101u8,
+                    118u8, 105u8, 118u8, 97u8, 105u8, 32u8, 99u8, 105u8, 102u8, 114u8, 101u8,
+                ])
+            },
+        )
+    },
+};
+static SR_SR_CYRL: &DataStruct = &::icu_displaynames::provider::KeyDisplayNamesV1 {
+    keys: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    99u8, 97u8, 99u8, 102u8, 99u8, 111u8, 99u8, 117u8, 104u8, 99u8, 107u8, 97u8,
+                    107u8, 98u8, 107u8, 99u8, 107u8, 102u8, 107u8, 107u8, 107u8, 110u8, 107u8,
+                    115u8, 108u8, 98u8, 109u8, 115u8, 110u8, 117u8, 116u8, 122u8, 118u8, 97u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    17u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 0u8, 41u8, 0u8, 76u8, 0u8, 88u8, 0u8,
+                    156u8, 0u8, 215u8, 0u8, 24u8, 1u8, 90u8, 1u8, 150u8, 1u8, 195u8, 1u8, 232u8,
+                    1u8, 18u8, 2u8, 50u8, 2u8, 92u8, 2u8, 106u8, 2u8, 133u8, 2u8, 208u8, 186u8,
+                    208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8, 189u8, 208u8, 180u8, 208u8,
+                    176u8, 209u8, 128u8, 209u8, 132u8, 208u8, 190u8, 209u8, 128u8, 208u8, 188u8,
+                    208u8, 176u8, 209u8, 130u8, 32u8, 208u8, 178u8, 208u8, 176u8, 208u8, 187u8,
+                    209u8, 131u8, 209u8, 130u8, 208u8, 181u8, 209u8, 128u8, 208u8, 181u8, 208u8,
+                    180u8, 208u8, 190u8, 209u8, 129u8, 208u8, 187u8, 208u8, 181u8, 208u8, 180u8,
+                    32u8, 209u8, 129u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 208u8, 184u8,
+                    209u8, 128u8, 208u8, 176u8, 209u8, 154u8, 208u8, 176u8, 208u8, 178u8, 208u8,
+                    176u8, 208u8, 187u8, 209u8, 131u8, 209u8, 130u8, 208u8, 176u8, 208u8, 191u8,
+                    209u8, 128u8, 208u8, 184u8, 208u8, 186u8, 208u8, 176u8, 208u8, 183u8, 208u8,
+                    184u8, 208u8, 178u8, 208u8, 176u8, 209u8, 154u8, 208u8, 181u8, 32u8, 208u8,
+                    178u8, 209u8, 128u8, 208u8, 181u8, 208u8, 188u8, 208u8, 181u8, 208u8, 189u8,
+                    208u8, 176u8, 32u8, 40u8, 49u8, 50u8, 45u8, 32u8, 208u8, 184u8, 208u8, 187u8,
+                    208u8, 184u8, 32u8, 50u8, 52u8, 45u8, 209u8, 135u8, 208u8, 176u8, 209u8, 129u8,
+                    208u8, 190u8, 208u8, 178u8, 208u8, 189u8, 208u8, 190u8, 41u8, 209u8, 129u8,
+                    208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 208u8, 184u8, 209u8, 128u8, 208u8,
+                    176u8, 209u8, 154u8, 208u8, 181u8, 32u8, 209u8, 131u8, 208u8, 183u8, 32u8,
+                    208u8, 184u8, 208u8, 179u8, 208u8, 189u8, 208u8, 190u8, 209u8, 128u8, 208u8,
+                    184u8, 209u8, 129u8, 208u8, 176u8, 209u8, 154u8, 208u8, 181u8, 32u8, 209u8,
+                    129u8, 208u8, 184u8, 208u8, 188u8, 208u8, 177u8, 208u8, 190u8, 208u8, 187u8,
+                    208u8, 176u8, 209u8, 129u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 208u8,
+                    184u8, 209u8, 128u8, 208u8, 176u8, 209u8, 154u8, 208u8, 181u8, 32u8, 208u8,
+                    191u8, 209u8, 128u8, 208u8, 181u8, 208u8, 188u8, 208u8, 176u8, 32u8, 208u8,
+                    190u8, 208u8, 177u8, 209u8, 128u8, 208u8, 189u8, 209u8, 131u8, 209u8, 130u8,
+                    208u8, 184u8, 208u8, 188u8, 32u8, 208u8, 176u8, 208u8, 186u8, 209u8, 134u8,
+                    208u8, 181u8, 208u8, 189u8, 209u8, 130u8, 208u8, 184u8, 208u8, 188u8, 208u8,
+                    176u8, 209u8, 129u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 208u8, 184u8,
+                    209u8, 128u8, 208u8, 176u8, 209u8, 154u8, 208u8, 181u8, 32u8, 208u8, 191u8,
+                    209u8, 128u8, 208u8, 181u8, 208u8, 188u8, 208u8, 176u8, 32u8, 208u8, 188u8,
+                    208u8, 176u8, 208u8, 187u8, 208u8, 190u8, 208u8, 188u8, 47u8, 208u8, 178u8,
+                    208u8, 181u8, 208u8, 187u8, 208u8, 184u8, 208u8, 186u8, 208u8, 190u8, 208u8,
+                    188u8, 32u8, 209u8, 129u8, 208u8, 187u8, 208u8, 190u8, 208u8, 178u8, 209u8,
+                    131u8, 209u8, 128u8, 208u8, 181u8, 209u8, 146u8, 208u8, 176u8, 209u8, 154u8,
+                    208u8, 181u8, 32u8, 208u8, 191u8, 209u8, 128u8, 208u8, 181u8, 208u8, 188u8,
+                    208u8, 176u8, 32u8, 208u8, 188u8, 208u8, 176u8, 208u8, 187u8, 208u8, 190u8,
+                    208u8, 188u8, 47u8, 208u8, 178u8, 208u8, 181u8, 208u8, 187u8, 208u8, 184u8,
+                    208u8, 186u8, 208u8, 190u8, 208u8, 188u8, 32u8, 209u8, 129u8, 208u8, 187u8,
+                    208u8, 190u8, 208u8, 178u8, 209u8, 131u8, 208u8, 189u8, 208u8, 190u8, 209u8,
+                    128u8, 208u8, 188u8, 208u8, 176u8, 208u8, 187u8, 208u8, 184u8, 208u8, 183u8,
+                    208u8, 190u8, 208u8, 178u8, 208u8, 176u8, 208u8, 189u8, 208u8, 190u8, 32u8,
+                    209u8, 129u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 208u8, 184u8, 209u8,
+                    128u8, 208u8, 176u8, 209u8, 154u8, 208u8, 181u8, 208u8, 189u8, 209u8, 131u8,
+                    208u8, 188u8, 208u8, 181u8, 209u8, 128u8, 208u8, 184u8, 209u8, 135u8, 208u8,
+                    186u8, 208u8, 190u8, 32u8, 209u8, 129u8, 208u8, 190u8, 209u8, 128u8, 209u8,
+                    130u8, 208u8, 184u8, 209u8, 128u8, 208u8, 176u8, 209u8, 154u8, 208u8, 181u8,
+                    209u8, 129u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 208u8, 184u8, 209u8,
+                    128u8, 208u8, 176u8, 209u8, 154u8, 208u8, 181u8, 32u8, 208u8, 191u8, 209u8,
+                    128u8, 208u8, 181u8, 208u8, 188u8, 208u8, 176u8, 32u8, 209u8, 152u8, 208u8,
+                    176u8, 209u8, 135u8, 208u8, 184u8, 208u8, 189u8, 208u8, 184u8, 209u8, 129u8,
+                    209u8, 130u8, 208u8, 184u8, 208u8, 187u8, 32u8, 208u8, 191u8, 209u8, 128u8,
+                    208u8, 181u8, 208u8, 187u8, 208u8, 190u8, 208u8, 188u8, 208u8, 176u8, 32u8,
+                    209u8, 128u8, 208u8, 181u8, 208u8, 180u8, 208u8, 176u8, 209u8, 129u8, 208u8,
+                    184u8, 209u8, 129u8, 209u8, 130u8, 208u8, 181u8, 208u8, 188u8, 32u8, 208u8,
+                    188u8, 208u8, 181u8, 209u8, 128u8, 208u8, 189u8, 208u8, 184u8, 209u8, 133u8,
+                    32u8, 209u8, 152u8, 208u8, 181u8, 208u8, 180u8, 208u8, 184u8, 208u8, 189u8,
+                    208u8, 184u8, 209u8, 134u8, 208u8, 176u8, 208u8, 177u8, 209u8, 128u8, 208u8,
+                    190u8, 209u8, 152u8, 208u8, 181u8, 208u8, 178u8, 208u8, 184u8, 208u8, 146u8,
+                    209u8, 128u8, 208u8, 181u8, 208u8, 188u8, 208u8, 181u8, 208u8, 189u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 176u8, 32u8, 208u8, 183u8, 208u8, 190u8, 208u8,
+                    189u8, 208u8, 176u8, 208u8, 146u8, 208u8, 176u8, 209u8, 128u8, 208u8, 184u8,
+                    209u8, 152u8, 208u8, 176u8, 208u8, 189u8, 209u8, 130u8, 208u8, 176u8, 32u8,
+                    208u8, 187u8, 208u8, 190u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8, 208u8,
+                    184u8, 209u8, 130u8, 208u8, 181u8, 209u8, 130u8, 208u8, 176u8,
+                ])
+            },
+        )
+    },
+    types: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    108u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 21u8, 0u8, 30u8, 0u8, 38u8, 0u8,
+                    48u8, 0u8, 59u8, 0u8, 69u8, 0u8, 78u8, 0u8, 87u8, 0u8, 97u8, 0u8, 113u8, 0u8,
+                    128u8, 0u8, 147u8, 0u8, 157u8, 0u8, 168u8, 0u8, 178u8, 0u8, 184u8, 0u8, 194u8,
+                    0u8, 205u8, 0u8, 215u8, 0u8, 224u8, 0u8, 231u8, 0u8, 239u8, 0u8, 245u8, 0u8,
+                    254u8, 0u8, 8u8, 1u8, 19u8, 1u8, 28u8, 1u8, 39u8, 1u8, 48u8, 1u8, 59u8, 1u8,
+                    70u8, 1u8, 79u8, 1u8, 86u8, 1u8, 95u8, 1u8, 104u8, 1u8, 110u8, 1u8, 116u8, 1u8,
+                    122u8, 1u8, 128u8, 1u8, 139u8, 1u8, 149u8, 1u8, 157u8, 1u8, 164u8, 1u8, 172u8,
+                    1u8, 179u8, 1u8, 187u8, 1u8, 195u8, 1u8, 203u8, 1u8, 211u8, 1u8, 218u8, 1u8,
+                    226u8, 1u8, 233u8, 1u8, 243u8, 1u8, 252u8, 1u8, 5u8, 2u8, 14u8, 2u8, 23u8, 2u8,
+                    31u8, 2u8, 40u8, 2u8, 49u8, 2u8, 58u8, 2u8, 69u8, 2u8, 80u8, 2u8, 87u8, 2u8,
+                    97u8, 2u8, 104u8, 2u8, 114u8, 2u8, 121u8, 2u8, 128u8, 2u8, 135u8, 2u8, 142u8,
+                    2u8, 152u8, 2u8, 163u8, 2u8, 170u8, 2u8, 177u8, 2u8, 187u8, 2u8, 194u8, 2u8,
+                    201u8, 2u8, 211u8, 2u8, 218u8, 2u8, 228u8, 2u8, 235u8, 2u8, 245u8, 2u8, 252u8,
+                    2u8, 3u8, 3u8, 10u8, 3u8, 20u8, 3u8, 27u8, 3u8, 34u8, 3u8, 41u8, 3u8, 48u8,
+                    3u8, 55u8, 3u8, 62u8, 3u8, 69u8, 3u8, 76u8, 3u8, 85u8, 3u8, 92u8, 3u8, 99u8,
+                    3u8, 107u8, 3u8, 118u8, 3u8, 125u8, 3u8, 135u8, 3u8, 142u8, 3u8, 149u8, 3u8,
+                    156u8, 3u8, 167u8, 3u8, 99u8, 97u8, 45u8, 98u8, 117u8, 100u8, 100u8, 104u8,
+                    105u8, 115u8, 116u8, 99u8, 97u8, 45u8, 99u8, 104u8, 105u8, 110u8, 101u8, 115u8,
+                    101u8, 99u8, 97u8, 45u8, 99u8, 111u8, 112u8, 116u8, 105u8, 99u8, 99u8, 97u8,
+                    45u8, 100u8, 97u8, 110u8, 103u8, 105u8, 99u8, 97u8, 45u8, 101u8, 116u8, 104u8,
+                    105u8, 111u8, 97u8, 97u8, 99u8, 97u8, 45u8, 101u8, 116u8, 104u8, 105u8, 111u8,
+                    112u8, 105u8, 99u8, 99u8, 97u8, 45u8, 103u8, 114u8, 101u8, 103u8, 111u8, 114u8,
+                    121u8, 99u8, 97u8, 45u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 99u8, 97u8,
+                    45u8, 105u8, 110u8, 100u8, 105u8, 97u8, 110u8, 99u8, 97u8, 45u8, 105u8, 115u8,
+                    108u8, 97u8, 109u8, 105u8, 99u8, 99u8, 97u8, 45u8, 105u8, 115u8, 108u8, 97u8,
+                    109u8, 105u8, 99u8, 45u8, 99u8, 105u8, 118u8, 105u8, 108u8, 99u8, 97u8, 45u8,
+                    105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 116u8, 98u8, 108u8, 97u8,
+                    99u8, 97u8, 45u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 117u8,
+                    109u8, 97u8, 108u8, 113u8, 117u8, 114u8, 97u8, 99u8, 97u8, 45u8, 105u8, 115u8,
+                    111u8, 56u8, 54u8, 48u8, 49u8, 99u8, 97u8, 45u8, 106u8, 97u8, 112u8, 97u8,
+                    110u8, 101u8, 115u8, 101u8, 99u8, 97u8, 45u8, 112u8, 101u8, 114u8, 115u8,
+                    105u8, 97u8, 110u8, 99u8, 97u8, 45u8, 114u8, 111u8, 99u8, 99u8, 102u8, 45u8,
+                    97u8, 99u8, 99u8, 111u8, 117u8, 110u8, 116u8, 99u8, 102u8, 45u8, 115u8, 116u8,
+                    97u8, 110u8, 100u8, 97u8, 114u8, 100u8, 99u8, 111u8, 45u8, 98u8, 105u8, 103u8,
+                    53u8, 104u8, 97u8, 110u8, 99u8, 111u8, 45u8, 99u8, 111u8, 109u8, 112u8, 97u8,
+                    116u8, 99u8, 111u8, 45u8, 100u8, 105u8, 99u8, 116u8, 99u8, 111u8, 45u8, 100u8,
+                    117u8, 99u8, 101u8, 116u8, 99u8, 111u8, 45u8, 101u8, 111u8, 114u8, 99u8, 111u8,
+                    45u8, 103u8, 98u8, 50u8, 51u8, 49u8, 50u8, 99u8, 111u8, 45u8, 112u8, 104u8,
+                    111u8, 110u8, 101u8, 98u8, 107u8, 99u8, 111u8, 45u8, 112u8, 104u8, 111u8,
+                    110u8, 101u8, 116u8, 105u8, 99u8, 99u8, 111u8, 45u8, 112u8, 105u8, 110u8,
+                    121u8, 105u8, 110u8, 99u8, 111u8, 45u8, 114u8, 101u8, 102u8, 111u8, 114u8,
+                    109u8, 101u8, 100u8, 99u8, 111u8, 45u8, 115u8, 101u8, 97u8, 114u8, 99u8, 104u8,
+                    99u8, 111u8, 45u8, 115u8, 101u8, 97u8, 114u8, 99u8, 104u8, 106u8, 108u8, 99u8,
+                    111u8, 45u8, 115u8, 116u8, 97u8, 110u8, 100u8, 97u8, 114u8, 100u8, 99u8, 111u8,
+                    45u8, 115u8, 116u8, 114u8, 111u8, 107u8, 101u8, 99u8, 111u8, 45u8, 116u8,
+                    114u8, 97u8, 100u8, 99u8, 111u8, 45u8, 117u8, 110u8, 105u8, 104u8, 97u8, 110u8,
+                    99u8, 111u8, 45u8, 122u8, 104u8, 117u8, 121u8, 105u8, 110u8, 104u8, 99u8, 45u8,
+                    104u8, 49u8, 49u8, 104u8, 99u8, 45u8, 104u8, 49u8, 50u8, 104u8, 99u8, 45u8,
+                    104u8, 50u8, 51u8, 104u8, 99u8, 45u8, 104u8, 50u8, 52u8, 107u8, 97u8, 45u8,
+                    110u8, 111u8, 105u8, 103u8, 110u8, 111u8, 114u8, 101u8, 107u8, 97u8, 45u8,
+                    115u8, 104u8, 105u8, 102u8, 116u8, 101u8, 100u8, 107u8, 98u8, 45u8, 102u8,
+                    97u8, 108u8, 115u8, 101u8, 107u8, 98u8, 45u8, 116u8, 114u8, 117u8, 101u8,
+                    107u8, 99u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8, 107u8, 99u8, 45u8, 116u8,
+                    114u8, 117u8, 101u8, 107u8, 102u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8,
+                    107u8, 102u8, 45u8, 108u8, 111u8, 119u8, 101u8, 114u8, 107u8, 102u8, 45u8,
+                    117u8, 112u8, 112u8, 101u8, 114u8, 107u8, 107u8, 45u8, 102u8, 97u8, 108u8,
+                    115u8, 101u8, 107u8, 107u8, 45u8, 116u8, 114u8, 117u8, 101u8, 107u8, 110u8,
+                    45u8, 102u8, 97u8, 108u8, 115u8, 101u8, 107u8, 110u8, 45u8, 116u8, 114u8,
+                    117u8, 101u8, 107u8, 115u8, 45u8, 105u8, 100u8, 101u8, 110u8, 116u8, 105u8,
+                    99u8, 107u8, 115u8, 45u8, 108u8, 101u8, 118u8, 101u8, 108u8, 49u8, 107u8,
+                    115u8, 45u8, 108u8, 101u8, 118u8, 101u8, 108u8, 50u8, 107u8, 115u8, 45u8,
+                    108u8, 101u8, 118u8, 101u8, 108u8, 51u8, 107u8, 115u8, 45u8, 108u8, 101u8,
+                    118u8, 101u8, 108u8, 52u8, 108u8, 98u8, 45u8, 108u8, 111u8, 111u8, 115u8,
+                    101u8, 108u8, 98u8, 45u8, 110u8, 111u8, 114u8, 109u8, 97u8, 108u8, 108u8, 98u8,
+                    45u8, 115u8, 116u8, 114u8, 105u8, 99u8, 116u8, 109u8, 115u8, 45u8, 109u8,
+                    101u8, 116u8, 114u8, 105u8, 99u8, 109u8, 115u8, 45u8, 117u8, 107u8, 115u8,
+                    121u8, 115u8, 116u8, 101u8, 109u8, 109u8, 115u8, 45u8, 117u8, 115u8, 115u8,
+                    121u8, 115u8, 116u8, 101u8, 109u8, 110u8, 117u8, 45u8, 97u8, 114u8, 97u8, 98u8,
+                    110u8, 117u8, 45u8, 97u8, 114u8, 97u8, 98u8, 101u8, 120u8, 116u8, 110u8, 117u8,
+                    45u8, 97u8, 114u8, 109u8, 110u8, 110u8, 117u8, 45u8, 97u8, 114u8, 109u8, 110u8,
+                    108u8, 111u8, 119u8, 110u8, 117u8, 45u8, 98u8, 101u8, 110u8, 103u8, 110u8,
+                    117u8, 45u8, 99u8, 97u8, 107u8, 109u8, 110u8, 117u8, 45u8, 100u8, 101u8, 118u8,
+                    97u8, 110u8, 117u8, 45u8, 101u8, 116u8, 104u8, 105u8, 110u8, 117u8, 45u8,
+                    102u8, 105u8, 110u8, 97u8, 110u8, 99u8, 101u8, 110u8, 117u8, 45u8, 102u8,
+                    117u8, 108u8, 108u8, 119u8, 105u8, 100u8, 101u8, 110u8, 117u8, 45u8, 103u8,
+                    101u8, 111u8, 114u8, 110u8, 117u8, 45u8, 103u8, 114u8, 101u8, 107u8, 110u8,
+                    117u8, 45u8, 103u8, 114u8, 101u8, 107u8, 108u8, 111u8, 119u8, 110u8, 117u8,
+                    45u8, 103u8, 117u8, 106u8, 114u8, 110u8, 117u8, 45u8, 103u8, 117u8, 114u8,
+                    117u8, 110u8, 117u8, 45u8, 104u8, 97u8, 110u8, 105u8, 100u8, 101u8, 99u8,
+                    110u8, 117u8, 45u8, 104u8, 97u8, 110u8, 115u8, 110u8, 117u8, 45u8, 104u8, 97u8,
+                    110u8, 115u8, 102u8, 105u8, 110u8, 110u8, 117u8, 45u8, 104u8, 97u8, 110u8,
+                    116u8, 110u8, 117u8, 45u8, 104u8, 97u8, 110u8, 116u8, 102u8, 105u8, 110u8,
+                    110u8, 117u8, 45u8, 104u8, 101u8, 98u8, 114u8, 110u8, 117u8, 45u8, 106u8, 97u8,
+                    118u8, 97u8, 110u8, 117u8, 45u8, 106u8, 112u8, 97u8, 110u8, 110u8, 117u8, 45u8,
+                    106u8, 112u8, 97u8, 110u8, 102u8, 105u8, 110u8, 110u8, 117u8, 45u8, 107u8,
+                    104u8, 109u8, 114u8, 110u8, 117u8, 45u8, 107u8, 110u8, 100u8, 97u8, 110u8,
+                    117u8, 45u8, 108u8, 97u8, 111u8, 111u8, 110u8, 117u8, 45u8, 108u8, 97u8, 116u8,
+                    110u8, 110u8, 117u8, 45u8, 109u8, 108u8, 121u8, 109u8, 110u8, 117u8, 45u8,
+                    109u8, 111u8, 110u8, 103u8, 110u8, 117u8, 45u8, 109u8, 116u8, 101u8, 105u8,
+                    110u8, 117u8, 45u8, 109u8, 121u8, 109u8, 114u8, 110u8, 117u8, 45u8, 110u8,
+                    97u8, 116u8, 105u8, 118u8, 101u8, 110u8, 117u8, 45u8, 111u8, 108u8, 99u8,
+                    107u8, 110u8, 117u8, 45u8, 111u8, 114u8, 121u8, 97u8, 110u8, 117u8, 45u8,
+                    114u8, 111u8, 109u8, 97u8, 110u8, 110u8, 117u8, 45u8, 114u8, 111u8, 109u8,
+                    97u8, 110u8, 108u8, 111u8, 119u8, 110u8, 117u8, 45u8, 116u8, 97u8, 109u8,
+                    108u8, 110u8, 117u8, 45u8, 116u8, 97u8, 109u8, 108u8, 100u8, 101u8, 99u8,
+                    110u8, 117u8, 45u8, 116u8, 101u8, 108u8, 117u8, 110u8, 117u8, 45u8, 116u8,
+                    104u8, 97u8, 105u8, 110u8, 117u8, 45u8, 116u8, 105u8, 98u8, 116u8, 110u8,
+                    117u8, 45u8, 116u8, 114u8, 97u8, 100u8, 105u8, 116u8, 105u8, 111u8, 110u8,
+                    117u8, 45u8, 118u8, 97u8, 105u8, 105u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    108u8, 0u8, 0u8, 0u8, 0u8, 0u8, 37u8, 0u8, 68u8, 0u8, 99u8, 0u8, 126u8, 0u8,
+                    177u8, 0u8, 210u8, 0u8, 253u8, 0u8, 32u8, 1u8, 86u8, 1u8, 119u8, 1u8, 167u8,
+                    1u8, 223u8, 1u8, 14u8, 2u8, 39u8, 2u8, 72u8, 2u8, 107u8, 2u8, 151u8, 2u8,
+                    207u8, 2u8, 253u8, 2u8, 57u8, 3u8, 152u8, 3u8, 205u8, 3u8, 19u8, 4u8, 69u8,
+                    4u8, 133u8, 4u8, 192u8, 4u8, 244u8, 4u8, 19u8, 5u8, 77u8, 5u8, 117u8, 5u8,
+                    195u8, 5u8, 251u8, 5u8, 42u8, 6u8, 87u8, 6u8, 156u8, 6u8, 166u8, 6u8, 203u8,
+                    6u8, 240u8, 6u8, 21u8, 7u8, 58u8, 7u8, 89u8, 7u8, 148u8, 7u8, 196u8, 7u8,
+                    242u8, 7u8, 63u8, 8u8, 115u8, 8u8, 205u8, 8u8, 250u8, 8u8, 43u8, 9u8, 93u8,
+                    9u8, 144u8, 9u8, 194u8, 9u8, 240u8, 9u8, 7u8, 10u8, 58u8, 10u8, 89u8, 10u8,
+                    169u8, 10u8, 17u8, 11u8, 70u8, 11u8, 119u8, 11u8, 164u8, 11u8, 180u8, 11u8,
+                    202u8, 11u8, 218u8, 11u8, 4u8, 12u8, 65u8, 12u8, 98u8, 12u8, 140u8, 12u8,
+                    169u8, 12u8, 190u8, 12u8, 219u8, 12u8, 250u8, 12u8, 31u8, 13u8, 63u8, 13u8,
+                    96u8, 13u8, 121u8, 13u8, 155u8, 13u8, 186u8, 13u8, 211u8, 13u8, 3u8, 14u8,
+                    63u8, 14u8, 146u8, 14u8, 202u8, 14u8, 25u8, 15u8, 58u8, 15u8, 85u8, 15u8,
+                    116u8, 15u8, 170u8, 15u8, 195u8, 15u8, 218u8, 15u8, 241u8, 15u8, 10u8, 16u8,
+                    39u8, 16u8, 68u8, 16u8, 100u8, 16u8, 133u8, 16u8, 158u8, 16u8, 182u8, 16u8,
+                    203u8, 16u8, 230u8, 16u8, 10u8, 17u8, 41u8, 17u8, 68u8, 17u8, 91u8, 17u8,
+                    114u8, 17u8, 145u8, 17u8, 186u8, 17u8, 208u8, 177u8, 209u8, 131u8, 208u8,
+                    180u8, 208u8, 184u8, 209u8, 129u8, 209u8, 130u8, 208u8, 184u8, 209u8, 135u8,
+                    208u8, 186u8, 208u8, 184u8, 32u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8,
+                    208u8, 181u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 208u8,
+                    186u8, 208u8, 184u8, 208u8, 189u8, 208u8, 181u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 184u8, 32u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8,
+                    208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 208u8, 186u8, 208u8,
+                    190u8, 208u8, 191u8, 209u8, 130u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                    32u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8, 189u8,
+                    208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 208u8, 180u8, 208u8, 176u8, 208u8,
+                    189u8, 208u8, 179u8, 208u8, 184u8, 32u8, 208u8, 186u8, 208u8, 176u8, 208u8,
+                    187u8, 208u8, 181u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8,
+                    208u8, 181u8, 209u8, 130u8, 208u8, 184u8, 208u8, 190u8, 208u8, 191u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 208u8, 176u8, 208u8, 188u8, 208u8,
+                    181u8, 209u8, 130u8, 32u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8,
+                    188u8, 32u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8,
+                    189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 208u8, 181u8, 209u8, 130u8,
+                    208u8, 184u8, 208u8, 190u8, 208u8, 191u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                    184u8, 32u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8,
+                    189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 208u8, 179u8, 209u8, 128u8,
+                    208u8, 181u8, 208u8, 179u8, 208u8, 190u8, 209u8, 128u8, 208u8, 184u8, 209u8,
+                    152u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                    32u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8, 189u8,
+                    208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 209u8, 133u8, 208u8, 181u8, 208u8,
+                    177u8, 209u8, 128u8, 208u8, 181u8, 209u8, 152u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 184u8, 32u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8,
+                    208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 208u8, 152u8, 208u8,
+                    189u8, 208u8, 180u8, 208u8, 184u8, 209u8, 152u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 184u8, 32u8, 208u8, 189u8, 208u8, 176u8, 209u8, 134u8, 208u8, 184u8,
+                    208u8, 190u8, 208u8, 189u8, 208u8, 176u8, 208u8, 187u8, 208u8, 189u8, 208u8,
+                    184u8, 32u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8,
+                    189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 208u8, 184u8, 209u8, 129u8,
+                    208u8, 187u8, 208u8, 176u8, 208u8, 188u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                    184u8, 32u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8,
+                    189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 208u8, 184u8, 209u8, 129u8,
+                    208u8, 187u8, 208u8, 176u8, 208u8, 188u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                    184u8, 32u8, 209u8, 134u8, 208u8, 184u8, 208u8, 178u8, 208u8, 184u8, 208u8,
+                    187u8, 208u8, 189u8, 208u8, 184u8, 32u8, 208u8, 186u8, 208u8, 176u8, 208u8,
+                    187u8, 208u8, 181u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8,
+                    208u8, 184u8, 209u8, 129u8, 208u8, 187u8, 208u8, 176u8, 208u8, 188u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 208u8, 176u8, 209u8, 129u8, 209u8,
+                    130u8, 209u8, 128u8, 208u8, 190u8, 208u8, 189u8, 208u8, 190u8, 208u8, 188u8,
+                    209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 208u8, 186u8, 208u8, 176u8,
+                    208u8, 187u8, 208u8, 181u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 209u8,
+                    128u8, 208u8, 184u8, 209u8, 129u8, 208u8, 187u8, 208u8, 176u8, 208u8, 188u8,
+                    209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 208u8, 186u8, 208u8, 176u8,
+                    208u8, 187u8, 208u8, 181u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 209u8,
+                    128u8, 32u8, 40u8, 85u8, 109u8, 109u8, 32u8, 97u8, 108u8, 45u8, 81u8, 117u8,
+                    114u8, 97u8, 41u8, 73u8, 83u8, 79u8, 45u8, 56u8, 54u8, 48u8, 49u8, 32u8, 208u8,
+                    186u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8, 189u8, 208u8, 180u8,
+                    208u8, 176u8, 209u8, 128u8, 209u8, 152u8, 208u8, 176u8, 208u8, 191u8, 208u8,
+                    176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 208u8,
+                    186u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8, 189u8, 208u8, 180u8,
+                    208u8, 176u8, 209u8, 128u8, 208u8, 191u8, 208u8, 181u8, 209u8, 128u8, 209u8,
+                    129u8, 208u8, 184u8, 209u8, 152u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                    32u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8, 189u8,
+                    208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 208u8, 186u8, 208u8, 176u8, 208u8,
+                    187u8, 208u8, 181u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8,
+                    32u8, 208u8, 160u8, 208u8, 181u8, 208u8, 191u8, 209u8, 131u8, 208u8, 177u8,
+                    208u8, 187u8, 208u8, 184u8, 208u8, 186u8, 208u8, 181u8, 32u8, 208u8, 154u8,
+                    208u8, 184u8, 208u8, 189u8, 208u8, 181u8, 209u8, 128u8, 208u8, 176u8, 209u8,
+                    135u8, 209u8, 131u8, 208u8, 189u8, 208u8, 190u8, 208u8, 178u8, 208u8, 190u8,
+                    208u8, 180u8, 209u8, 129u8, 209u8, 130u8, 208u8, 178u8, 208u8, 181u8, 208u8,
+                    189u8, 208u8, 184u8, 32u8, 209u8, 132u8, 208u8, 190u8, 209u8, 128u8, 208u8,
+                    188u8, 208u8, 176u8, 209u8, 130u8, 32u8, 208u8, 178u8, 208u8, 176u8, 208u8,
+                    187u8, 209u8, 131u8, 209u8, 130u8, 208u8, 181u8, 209u8, 129u8, 209u8, 130u8,
+                    208u8, 176u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 208u8,
+                    180u8, 208u8, 189u8, 208u8, 184u8, 32u8, 209u8, 132u8, 208u8, 190u8, 209u8,
+                    128u8, 208u8, 188u8, 208u8, 176u8, 209u8, 130u8, 32u8, 208u8, 178u8, 208u8,
+                    176u8, 208u8, 187u8, 209u8, 131u8, 209u8, 130u8, 208u8, 181u8, 209u8, 130u8,
+                    209u8, 128u8, 208u8, 176u8, 208u8, 180u8, 208u8, 184u8, 209u8, 134u8, 208u8,
+                    184u8, 208u8, 190u8, 208u8, 189u8, 208u8, 176u8, 208u8, 187u8, 208u8, 189u8,
+                    208u8, 190u8, 32u8, 208u8, 186u8, 208u8, 184u8, 208u8, 189u8, 208u8, 181u8,
+                    209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 32u8, 209u8, 129u8, 208u8, 190u8,
+                    209u8, 128u8, 209u8, 130u8, 208u8, 184u8, 209u8, 128u8, 208u8, 176u8, 209u8,
+                    154u8, 208u8, 181u8, 208u8, 191u8, 209u8, 128u8, 208u8, 181u8, 209u8, 130u8,
+                    209u8, 133u8, 208u8, 190u8, 208u8, 180u8, 208u8, 189u8, 208u8, 184u8, 32u8,
+                    209u8, 128u8, 208u8, 181u8, 208u8, 180u8, 208u8, 190u8, 209u8, 129u8, 208u8,
+                    187u8, 208u8, 181u8, 208u8, 180u8, 32u8, 209u8, 129u8, 208u8, 190u8, 209u8,
+                    128u8, 209u8, 130u8, 208u8, 184u8, 209u8, 128u8, 208u8, 176u8, 209u8, 154u8,
+                    208u8, 176u8, 44u8, 32u8, 208u8, 183u8, 208u8, 177u8, 208u8, 190u8, 208u8,
+                    179u8, 32u8, 208u8, 186u8, 208u8, 190u8, 208u8, 188u8, 208u8, 191u8, 208u8,
+                    176u8, 209u8, 130u8, 208u8, 184u8, 208u8, 177u8, 208u8, 184u8, 208u8, 187u8,
+                    208u8, 189u8, 208u8, 190u8, 209u8, 129u8, 209u8, 130u8, 208u8, 184u8, 209u8,
+                    128u8, 208u8, 181u8, 208u8, 180u8, 208u8, 190u8, 209u8, 129u8, 208u8, 187u8,
+                    208u8, 181u8, 208u8, 180u8, 32u8, 209u8, 129u8, 208u8, 190u8, 209u8, 128u8,
+                    209u8, 130u8, 208u8, 184u8, 209u8, 128u8, 208u8, 176u8, 209u8, 154u8, 208u8,
+                    176u8, 32u8, 209u8, 131u8, 32u8, 209u8, 128u8, 208u8, 181u8, 209u8, 135u8,
+                    208u8, 189u8, 208u8, 184u8, 208u8, 186u8, 209u8, 131u8, 208u8, 191u8, 208u8,
+                    190u8, 208u8, 180u8, 209u8, 128u8, 208u8, 176u8, 208u8, 183u8, 209u8, 131u8,
+                    208u8, 188u8, 208u8, 181u8, 208u8, 178u8, 208u8, 176u8, 208u8, 189u8, 208u8,
+                    184u8, 32u8, 85u8, 110u8, 105u8, 99u8, 111u8, 100u8, 101u8, 32u8, 209u8, 128u8,
+                    208u8, 181u8, 208u8, 180u8, 208u8, 190u8, 209u8, 129u8, 208u8, 187u8, 208u8,
+                    181u8, 208u8, 180u8, 32u8, 209u8, 129u8, 208u8, 190u8, 209u8, 128u8, 209u8,
+                    130u8, 208u8, 184u8, 209u8, 128u8, 208u8, 176u8, 209u8, 154u8, 208u8, 176u8,
+                    208u8, 181u8, 208u8, 178u8, 209u8, 128u8, 208u8, 190u8, 208u8, 191u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 176u8, 32u8, 208u8, 191u8, 209u8, 128u8, 208u8,
+                    176u8, 208u8, 178u8, 208u8, 184u8, 208u8, 187u8, 208u8, 176u8, 32u8, 209u8,
+                    128u8, 208u8, 181u8, 208u8, 180u8, 208u8, 190u8, 209u8, 129u8, 208u8, 187u8,
+                    208u8, 181u8, 208u8, 180u8, 208u8, 176u8, 208u8, 191u8, 208u8, 190u8, 209u8,
+                    152u8, 208u8, 181u8, 208u8, 180u8, 208u8, 189u8, 208u8, 190u8, 209u8, 129u8,
+                    209u8, 130u8, 208u8, 176u8, 208u8, 178u8, 209u8, 153u8, 208u8, 181u8, 208u8,
+                    189u8, 208u8, 190u8, 32u8, 208u8, 186u8, 208u8, 184u8, 208u8, 189u8, 208u8,
+                    181u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 32u8, 209u8, 129u8, 208u8,
+                    190u8, 209u8, 128u8, 209u8, 130u8, 208u8, 184u8, 209u8, 128u8, 208u8, 176u8,
+                    209u8, 154u8, 208u8, 181u8, 209u8, 129u8, 208u8, 190u8, 209u8, 128u8, 209u8,
+                    130u8, 208u8, 184u8, 209u8, 128u8, 208u8, 176u8, 209u8, 154u8, 208u8, 181u8,
+                    32u8, 208u8, 186u8, 208u8, 176u8, 208u8, 190u8, 32u8, 209u8, 130u8, 208u8,
+                    181u8, 208u8, 187u8, 208u8, 181u8, 209u8, 132u8, 208u8, 190u8, 208u8, 189u8,
+                    209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 208u8, 184u8, 208u8, 188u8,
+                    208u8, 181u8, 208u8, 189u8, 208u8, 184u8, 208u8, 186u8, 209u8, 132u8, 208u8,
+                    190u8, 208u8, 189u8, 208u8, 181u8, 209u8, 130u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 184u8, 32u8, 209u8, 128u8, 208u8, 181u8, 208u8, 180u8, 208u8, 190u8,
+                    209u8, 129u8, 208u8, 187u8, 208u8, 181u8, 208u8, 180u8, 32u8, 209u8, 129u8,
+                    208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 208u8, 184u8, 209u8, 128u8, 208u8,
+                    176u8, 209u8, 154u8, 208u8, 176u8, 208u8, 191u8, 208u8, 184u8, 208u8, 189u8,
+                    209u8, 152u8, 208u8, 184u8, 208u8, 189u8, 32u8, 209u8, 129u8, 208u8, 190u8,
+                    209u8, 128u8, 209u8, 130u8, 208u8, 184u8, 209u8, 128u8, 208u8, 176u8, 209u8,
+                    154u8, 208u8, 181u8, 209u8, 128u8, 208u8, 181u8, 209u8, 132u8, 208u8, 190u8,
+                    209u8, 128u8, 208u8, 188u8, 208u8, 184u8, 209u8, 129u8, 208u8, 176u8, 208u8,
+                    189u8, 208u8, 184u8, 32u8, 209u8, 128u8, 208u8, 181u8, 208u8, 180u8, 208u8,
+                    190u8, 209u8, 129u8, 208u8, 187u8, 208u8, 181u8, 208u8, 180u8, 32u8, 209u8,
+                    129u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 208u8, 184u8, 209u8, 128u8,
+                    208u8, 176u8, 209u8, 154u8, 208u8, 176u8, 208u8, 191u8, 209u8, 128u8, 208u8,
+                    181u8, 209u8, 130u8, 209u8, 128u8, 208u8, 176u8, 208u8, 179u8, 208u8, 176u8,
+                    32u8, 208u8, 190u8, 208u8, 191u8, 209u8, 136u8, 209u8, 130u8, 208u8, 181u8,
+                    32u8, 208u8, 189u8, 208u8, 176u8, 208u8, 188u8, 208u8, 181u8, 208u8, 189u8,
+                    208u8, 181u8, 208u8, 159u8, 209u8, 128u8, 208u8, 181u8, 209u8, 130u8, 209u8,
+                    128u8, 208u8, 176u8, 208u8, 179u8, 208u8, 176u8, 32u8, 208u8, 191u8, 209u8,
+                    128u8, 208u8, 181u8, 208u8, 188u8, 208u8, 176u8, 32u8, 209u8, 133u8, 208u8,
+                    176u8, 208u8, 189u8, 208u8, 179u8, 209u8, 131u8, 208u8, 187u8, 32u8, 208u8,
+                    191u8, 208u8, 190u8, 209u8, 135u8, 208u8, 181u8, 209u8, 130u8, 208u8, 189u8,
+                    208u8, 190u8, 208u8, 188u8, 32u8, 209u8, 129u8, 209u8, 131u8, 208u8, 179u8,
+                    208u8, 187u8, 208u8, 176u8, 209u8, 129u8, 208u8, 189u8, 208u8, 184u8, 208u8,
+                    186u8, 209u8, 131u8, 209u8, 129u8, 209u8, 130u8, 208u8, 176u8, 208u8, 189u8,
+                    208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 208u8, 180u8, 208u8, 189u8, 208u8,
+                    184u8, 32u8, 209u8, 128u8, 208u8, 181u8, 208u8, 180u8, 208u8, 190u8, 209u8,
+                    129u8, 208u8, 187u8, 208u8, 181u8, 208u8, 180u8, 32u8, 209u8, 129u8, 208u8,
+                    190u8, 209u8, 128u8, 209u8, 130u8, 208u8, 184u8, 209u8, 128u8, 208u8, 176u8,
+                    209u8, 154u8, 208u8, 176u8, 209u8, 129u8, 208u8, 190u8, 209u8, 128u8, 209u8,
+                    130u8, 208u8, 184u8, 209u8, 128u8, 208u8, 176u8, 209u8, 154u8, 208u8, 181u8,
+                    32u8, 208u8, 191u8, 208u8, 190u8, 32u8, 208u8, 177u8, 209u8, 128u8, 208u8,
+                    190u8, 209u8, 152u8, 209u8, 131u8, 32u8, 208u8, 191u8, 208u8, 190u8, 209u8,
+                    130u8, 208u8, 181u8, 208u8, 183u8, 208u8, 176u8, 209u8, 130u8, 209u8, 128u8,
+                    208u8, 176u8, 208u8, 180u8, 208u8, 184u8, 209u8, 134u8, 208u8, 184u8, 208u8,
+                    190u8, 208u8, 189u8, 208u8, 176u8, 208u8, 187u8, 208u8, 189u8, 208u8, 190u8,
+                    32u8, 209u8, 129u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 208u8, 184u8,
+                    209u8, 128u8, 208u8, 176u8, 209u8, 154u8, 208u8, 181u8, 209u8, 128u8, 208u8,
+                    181u8, 208u8, 180u8, 208u8, 190u8, 209u8, 129u8, 208u8, 187u8, 208u8, 181u8,
+                    208u8, 180u8, 32u8, 209u8, 129u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8,
+                    208u8, 184u8, 209u8, 128u8, 208u8, 176u8, 209u8, 154u8, 208u8, 176u8, 32u8,
+                    209u8, 128u8, 208u8, 176u8, 208u8, 180u8, 208u8, 184u8, 208u8, 186u8, 208u8,
+                    176u8, 208u8, 187u8, 208u8, 189u8, 208u8, 184u8, 209u8, 133u8, 32u8, 208u8,
+                    191u8, 208u8, 190u8, 209u8, 130u8, 208u8, 181u8, 208u8, 183u8, 208u8, 176u8,
+                    208u8, 182u8, 209u8, 131u8, 209u8, 152u8, 208u8, 184u8, 208u8, 189u8, 49u8,
+                    50u8, 45u8, 209u8, 135u8, 208u8, 176u8, 209u8, 129u8, 208u8, 190u8, 208u8,
+                    178u8, 208u8, 189u8, 208u8, 184u8, 32u8, 209u8, 129u8, 208u8, 184u8, 209u8,
+                    129u8, 209u8, 130u8, 208u8, 181u8, 208u8, 188u8, 32u8, 40u8, 48u8, 45u8, 49u8,
+                    49u8, 41u8, 49u8, 50u8, 45u8, 209u8, 135u8, 208u8, 176u8, 209u8, 129u8, 208u8,
+                    190u8, 208u8, 178u8, 208u8, 189u8, 208u8, 184u8, 32u8, 209u8, 129u8, 208u8,
+                    184u8, 209u8, 129u8, 209u8, 130u8, 208u8, 181u8, 208u8, 188u8, 32u8, 40u8,
+                    49u8, 45u8, 49u8, 50u8, 41u8, 50u8, 52u8, 45u8, 209u8, 135u8, 208u8, 176u8,
+                    209u8, 129u8, 208u8, 190u8, 208u8, 178u8, 208u8, 189u8, 208u8, 184u8, 32u8,
+                    209u8, 129u8, 208u8, 184u8, 209u8, 129u8, 209u8, 130u8, 208u8, 181u8, 208u8,
+                    188u8, 32u8, 40u8, 48u8, 45u8, 50u8, 51u8, 41u8, 50u8, 52u8, 45u8, 209u8,
+                    135u8, 208u8, 176u8, 209u8, 129u8, 208u8, 190u8, 208u8, 178u8, 208u8, 189u8,
+                    208u8, 184u8, 32u8, 209u8, 129u8, 208u8, 184u8, 209u8, 129u8, 209u8, 130u8,
+                    208u8, 181u8, 208u8, 188u8, 32u8, 40u8, 49u8, 45u8, 50u8, 52u8, 41u8, 208u8,
+                    161u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 208u8, 184u8, 209u8, 128u8,
+                    208u8, 176u8, 209u8, 152u8, 32u8, 209u8, 129u8, 208u8, 184u8, 208u8, 188u8,
+                    208u8, 177u8, 208u8, 190u8, 208u8, 187u8, 208u8, 181u8, 208u8, 161u8, 208u8,
+                    190u8, 209u8, 128u8, 209u8, 130u8, 208u8, 184u8, 209u8, 128u8, 208u8, 176u8,
+                    209u8, 154u8, 208u8, 181u8, 32u8, 209u8, 131u8, 208u8, 183u8, 32u8, 208u8,
+                    184u8, 208u8, 179u8, 208u8, 189u8, 208u8, 190u8, 209u8, 128u8, 208u8, 184u8,
+                    209u8, 129u8, 208u8, 176u8, 209u8, 154u8, 208u8, 181u8, 32u8, 209u8, 129u8,
+                    208u8, 184u8, 208u8, 188u8, 208u8, 177u8, 208u8, 190u8, 208u8, 187u8, 208u8,
+                    176u8, 208u8, 161u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 208u8, 184u8,
+                    209u8, 128u8, 208u8, 176u8, 209u8, 152u8, 32u8, 208u8, 176u8, 208u8, 186u8,
+                    209u8, 134u8, 208u8, 181u8, 208u8, 189u8, 209u8, 130u8, 208u8, 181u8, 32u8,
+                    208u8, 189u8, 208u8, 190u8, 209u8, 128u8, 208u8, 188u8, 208u8, 176u8, 208u8,
+                    187u8, 208u8, 189u8, 208u8, 190u8, 208u8, 161u8, 208u8, 190u8, 209u8, 128u8,
+                    209u8, 130u8, 208u8, 184u8, 209u8, 128u8, 208u8, 176u8, 209u8, 152u8, 32u8,
+                    208u8, 176u8, 208u8, 186u8, 209u8, 134u8, 208u8, 181u8, 208u8, 189u8, 209u8,
+                    130u8, 208u8, 181u8, 32u8, 208u8, 190u8, 208u8, 177u8, 209u8, 128u8, 208u8,
+                    189u8, 209u8, 131u8, 209u8, 130u8, 208u8, 190u8, 208u8, 161u8, 208u8, 190u8,
+                    209u8, 128u8, 209u8, 130u8, 208u8, 184u8, 209u8, 128u8, 208u8, 176u8, 209u8,
+                    152u8, 32u8, 208u8, 177u8, 208u8, 181u8, 208u8, 183u8, 32u8, 208u8, 190u8,
+                    208u8, 177u8, 208u8, 183u8, 208u8, 184u8, 209u8, 128u8, 208u8, 176u8, 32u8,
+                    208u8, 189u8, 208u8, 176u8, 32u8, 208u8, 178u8, 208u8, 181u8, 208u8, 187u8,
+                    208u8, 184u8, 208u8, 186u8, 208u8, 176u8, 32u8, 208u8, 184u8, 32u8, 208u8,
+                    188u8, 208u8, 176u8, 208u8, 187u8, 208u8, 176u8, 32u8, 209u8, 129u8, 208u8,
+                    187u8, 208u8, 190u8, 208u8, 178u8, 208u8, 176u8, 208u8, 161u8, 208u8, 190u8,
+                    209u8, 128u8, 209u8, 130u8, 208u8, 184u8, 209u8, 128u8, 208u8, 176u8, 209u8,
+                    152u8, 32u8, 208u8, 188u8, 208u8, 176u8, 208u8, 187u8, 208u8, 176u8, 32u8,
+                    208u8, 184u8, 32u8, 208u8, 178u8, 208u8, 181u8, 208u8, 187u8, 208u8, 184u8,
+                    208u8, 186u8, 208u8, 176u8, 32u8, 209u8, 129u8, 208u8, 187u8, 208u8, 190u8,
+                    208u8, 178u8, 208u8, 176u8, 208u8, 161u8, 208u8, 190u8, 209u8, 128u8, 209u8,
+                    130u8, 208u8, 184u8, 209u8, 128u8, 208u8, 176u8, 209u8, 152u8, 32u8, 208u8,
+                    189u8, 208u8, 190u8, 209u8, 128u8, 208u8, 188u8, 208u8, 176u8, 208u8, 187u8,
+                    208u8, 176u8, 208u8, 189u8, 32u8, 209u8, 128u8, 208u8, 181u8, 208u8, 180u8,
+                    208u8, 190u8, 209u8, 129u8, 208u8, 187u8, 208u8, 181u8, 208u8, 180u8, 32u8,
+                    208u8, 178u8, 208u8, 181u8, 208u8, 187u8, 208u8, 184u8, 208u8, 186u8, 208u8,
+                    184u8, 209u8, 133u8, 32u8, 208u8, 184u8, 32u8, 208u8, 188u8, 208u8, 176u8,
+                    208u8, 187u8, 208u8, 184u8, 209u8, 133u8, 32u8, 209u8, 129u8, 208u8, 187u8,
+                    208u8, 190u8, 208u8, 178u8, 208u8, 176u8, 208u8, 161u8, 208u8, 190u8, 209u8,
+                    128u8, 209u8, 130u8, 208u8, 184u8, 209u8, 128u8, 208u8, 176u8, 209u8, 152u8,
+                    32u8, 208u8, 191u8, 209u8, 128u8, 208u8, 178u8, 208u8, 190u8, 32u8, 208u8,
+                    188u8, 208u8, 176u8, 208u8, 187u8, 208u8, 176u8, 32u8, 209u8, 129u8, 208u8,
+                    187u8, 208u8, 190u8, 208u8, 178u8, 208u8, 176u8, 208u8, 161u8, 208u8, 190u8,
+                    209u8, 128u8, 209u8, 130u8, 208u8, 184u8, 209u8, 128u8, 208u8, 176u8, 209u8,
+                    152u8, 32u8, 208u8, 191u8, 209u8, 128u8, 208u8, 178u8, 208u8, 190u8, 32u8,
+                    208u8, 178u8, 208u8, 181u8, 208u8, 187u8, 208u8, 184u8, 208u8, 186u8, 208u8,
+                    176u8, 32u8, 209u8, 129u8, 208u8, 187u8, 208u8, 190u8, 208u8, 178u8, 208u8,
+                    176u8, 208u8, 161u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 208u8, 184u8,
+                    209u8, 128u8, 208u8, 176u8, 209u8, 152u8, 32u8, 208u8, 177u8, 208u8, 181u8,
+                    208u8, 183u8, 32u8, 208u8, 189u8, 208u8, 190u8, 209u8, 128u8, 208u8, 188u8,
+                    208u8, 176u8, 208u8, 187u8, 208u8, 184u8, 208u8, 183u8, 208u8, 176u8, 209u8,
+                    134u8, 208u8, 184u8, 209u8, 152u8, 208u8, 181u8, 208u8, 161u8, 208u8, 190u8,
+                    209u8, 128u8, 209u8, 130u8, 208u8, 184u8, 209u8, 128u8, 208u8, 176u8, 209u8,
+                    152u8, 32u8, 85u8, 110u8, 105u8, 99u8, 111u8, 100u8, 101u8, 32u8, 208u8, 189u8,
+                    208u8, 190u8, 209u8, 128u8, 208u8, 188u8, 208u8, 176u8, 208u8, 187u8, 208u8,
+                    184u8, 208u8, 183u8, 208u8, 190u8, 208u8, 178u8, 208u8, 176u8, 208u8, 189u8,
+                    208u8, 190u8, 208u8, 161u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 208u8,
+                    184u8, 209u8, 128u8, 208u8, 176u8, 209u8, 152u8, 32u8, 209u8, 134u8, 208u8,
+                    184u8, 209u8, 132u8, 209u8, 128u8, 208u8, 181u8, 32u8, 208u8, 191u8, 208u8,
+                    190u8, 209u8, 152u8, 208u8, 181u8, 208u8, 180u8, 208u8, 184u8, 208u8, 189u8,
+                    208u8, 176u8, 209u8, 135u8, 208u8, 189u8, 208u8, 190u8, 208u8, 161u8, 208u8,
+                    190u8, 209u8, 128u8, 209u8, 130u8, 208u8, 184u8, 209u8, 128u8, 208u8, 176u8,
+                    209u8, 152u8, 32u8, 209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8,
+                    208u8, 181u8, 32u8, 208u8, 189u8, 209u8, 131u8, 208u8, 188u8, 208u8, 181u8,
+                    209u8, 128u8, 208u8, 184u8, 209u8, 135u8, 208u8, 186u8, 208u8, 184u8, 208u8,
+                    161u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 208u8, 184u8, 209u8, 128u8,
+                    208u8, 176u8, 209u8, 152u8, 32u8, 209u8, 129u8, 208u8, 178u8, 208u8, 181u8,
+                    208u8, 161u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 208u8, 184u8, 209u8,
+                    128u8, 208u8, 176u8, 209u8, 152u8, 32u8, 209u8, 129u8, 208u8, 176u8, 208u8,
+                    188u8, 208u8, 190u8, 32u8, 208u8, 190u8, 209u8, 129u8, 208u8, 189u8, 208u8,
+                    190u8, 208u8, 178u8, 208u8, 189u8, 208u8, 176u8, 32u8, 209u8, 129u8, 208u8,
+                    187u8, 208u8, 190u8, 208u8, 178u8, 208u8, 176u8, 208u8, 161u8, 208u8, 190u8,
+                    209u8, 128u8, 209u8, 130u8, 208u8, 184u8, 209u8, 128u8, 208u8, 176u8, 209u8,
+                    152u8, 32u8, 208u8, 176u8, 208u8, 186u8, 209u8, 134u8, 208u8, 181u8, 208u8,
+                    189u8, 209u8, 130u8, 208u8, 181u8, 208u8, 161u8, 208u8, 190u8, 209u8, 128u8,
+                    209u8, 130u8, 208u8, 184u8, 209u8, 128u8, 208u8, 176u8, 209u8, 152u8, 32u8,
+                    208u8, 176u8, 208u8, 186u8, 209u8, 134u8, 208u8, 181u8, 208u8, 189u8, 209u8,
+                    130u8, 208u8, 181u8, 47u8, 208u8, 188u8, 208u8, 176u8, 208u8, 187u8, 208u8,
+                    176u8, 32u8, 208u8, 184u8, 32u8, 208u8, 178u8, 208u8, 181u8, 208u8, 187u8,
+                    208u8, 184u8, 208u8, 186u8, 208u8, 176u8, 32u8, 209u8, 129u8, 208u8, 187u8,
+                    208u8, 190u8, 208u8, 178u8, 208u8, 176u8, 47u8, 209u8, 136u8, 208u8, 184u8,
+                    209u8, 128u8, 208u8, 184u8, 208u8, 189u8, 209u8, 131u8, 208u8, 161u8, 208u8,
+                    190u8, 209u8, 128u8, 209u8, 130u8, 208u8, 184u8, 209u8, 128u8, 208u8, 176u8,
+                    209u8, 152u8, 32u8, 208u8, 176u8, 208u8, 186u8, 209u8, 134u8, 208u8, 181u8,
+                    208u8, 189u8, 209u8, 130u8, 208u8, 181u8, 47u8, 208u8, 188u8, 208u8, 176u8,
+                    208u8, 187u8, 208u8, 176u8, 32u8, 208u8, 184u8, 32u8, 208u8, 178u8, 208u8,
+                    181u8, 208u8, 187u8, 208u8, 184u8, 208u8, 186u8, 208u8, 176u8, 32u8, 209u8,
+                    129u8, 208u8, 187u8, 208u8, 190u8, 208u8, 178u8, 208u8, 176u8, 47u8, 209u8,
+                    136u8, 208u8, 184u8, 209u8, 128u8, 208u8, 184u8, 208u8, 189u8, 209u8, 131u8,
+                    47u8, 208u8, 186u8, 208u8, 176u8, 208u8, 189u8, 208u8, 176u8, 32u8, 209u8,
+                    129u8, 208u8, 184u8, 208u8, 188u8, 208u8, 177u8, 208u8, 190u8, 208u8, 187u8,
+                    208u8, 181u8, 209u8, 128u8, 208u8, 176u8, 208u8, 183u8, 208u8, 188u8, 208u8,
+                    176u8, 208u8, 186u8, 208u8, 189u8, 209u8, 131u8, 209u8, 130u8, 208u8, 184u8,
+                    32u8, 209u8, 129u8, 209u8, 130u8, 208u8, 184u8, 208u8, 187u8, 32u8, 208u8,
+                    191u8, 209u8, 128u8, 208u8, 181u8, 208u8, 187u8, 208u8, 190u8, 208u8, 188u8,
+                    208u8, 176u8, 32u8, 209u8, 128u8, 208u8, 181u8, 208u8, 180u8, 208u8, 176u8,
+                    208u8, 189u8, 208u8, 190u8, 209u8, 128u8, 208u8, 188u8, 208u8, 176u8, 208u8,
+                    187u8, 208u8, 189u8, 208u8, 184u8, 32u8, 209u8, 129u8, 209u8, 130u8, 208u8,
+                    184u8, 208u8, 187u8, 32u8, 208u8, 191u8, 209u8, 128u8, 208u8, 181u8, 208u8,
+                    187u8, 208u8, 190u8, 208u8, 188u8, 208u8, 176u8, 32u8, 209u8, 128u8, 208u8,
+                    181u8, 208u8, 180u8, 208u8, 176u8, 209u8, 129u8, 209u8, 130u8, 209u8, 128u8,
+                    208u8, 190u8, 208u8, 179u8, 208u8, 184u8, 32u8, 209u8, 129u8, 209u8, 130u8,
+                    208u8, 184u8, 208u8, 187u8, 32u8, 208u8, 191u8, 209u8, 128u8, 208u8, 181u8,
+                    208u8, 187u8, 208u8, 190u8, 208u8, 188u8, 208u8, 176u8, 32u8, 209u8, 128u8,
+                    208u8, 181u8, 208u8, 180u8, 208u8, 176u8, 208u8, 188u8, 208u8, 181u8, 209u8,
+                    130u8, 209u8, 128u8, 208u8, 184u8, 209u8, 135u8, 208u8, 186u8, 208u8, 184u8,
+                    208u8, 184u8, 208u8, 188u8, 208u8, 191u8, 208u8, 181u8, 209u8, 128u8, 208u8,
+                    184u8, 209u8, 152u8, 208u8, 176u8, 208u8, 187u8, 208u8, 189u8, 208u8, 184u8,
+                    208u8, 176u8, 208u8, 188u8, 208u8, 181u8, 209u8, 128u8, 208u8, 184u8, 209u8,
+                    135u8, 208u8, 186u8, 208u8, 184u8, 208u8, 176u8, 209u8, 128u8, 208u8, 176u8,
+                    208u8, 191u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 45u8, 208u8, 184u8,
+                    208u8, 189u8, 208u8, 180u8, 208u8, 184u8, 209u8, 152u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 181u8, 32u8, 209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8,
+                    128u8, 208u8, 181u8, 208u8, 191u8, 209u8, 128u8, 208u8, 190u8, 208u8, 180u8,
+                    209u8, 131u8, 208u8, 182u8, 208u8, 181u8, 208u8, 189u8, 208u8, 181u8, 32u8,
+                    208u8, 176u8, 209u8, 128u8, 208u8, 176u8, 208u8, 191u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 190u8, 45u8, 208u8, 184u8, 208u8, 189u8, 208u8, 180u8, 208u8,
+                    184u8, 209u8, 152u8, 209u8, 129u8, 208u8, 186u8, 208u8, 181u8, 32u8, 209u8,
+                    134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8, 208u8, 181u8, 209u8, 152u8,
+                    208u8, 181u8, 209u8, 128u8, 208u8, 188u8, 208u8, 181u8, 208u8, 189u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 208u8, 177u8, 209u8, 128u8, 208u8,
+                    190u8, 209u8, 152u8, 208u8, 181u8, 208u8, 178u8, 208u8, 184u8, 208u8, 188u8,
+                    208u8, 176u8, 208u8, 187u8, 208u8, 184u8, 32u8, 209u8, 152u8, 208u8, 181u8,
+                    209u8, 128u8, 208u8, 188u8, 208u8, 181u8, 208u8, 189u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 184u8, 32u8, 208u8, 177u8, 209u8, 128u8, 208u8, 190u8, 209u8,
+                    152u8, 208u8, 181u8, 208u8, 178u8, 208u8, 184u8, 208u8, 177u8, 208u8, 181u8,
+                    208u8, 189u8, 208u8, 179u8, 208u8, 176u8, 208u8, 187u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 181u8, 32u8, 209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8,
+                    128u8, 208u8, 181u8, 209u8, 135u8, 208u8, 176u8, 208u8, 186u8, 208u8, 188u8,
+                    208u8, 176u8, 32u8, 209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8,
+                    208u8, 181u8, 208u8, 180u8, 208u8, 181u8, 208u8, 178u8, 208u8, 176u8, 208u8,
+                    189u8, 208u8, 179u8, 208u8, 176u8, 209u8, 128u8, 208u8, 184u8, 32u8, 209u8,
+                    134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8, 208u8, 181u8, 208u8, 181u8,
+                    209u8, 130u8, 208u8, 184u8, 208u8, 190u8, 208u8, 191u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 184u8, 32u8, 208u8, 177u8, 209u8, 128u8, 208u8, 190u8, 209u8,
+                    152u8, 208u8, 181u8, 208u8, 178u8, 208u8, 184u8, 208u8, 164u8, 208u8, 184u8,
+                    208u8, 189u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 184u8, 209u8,
+                    152u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 208u8, 177u8, 209u8,
+                    128u8, 208u8, 190u8, 209u8, 152u8, 208u8, 181u8, 208u8, 178u8, 208u8, 184u8,
+                    209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8, 208u8, 181u8, 32u8,
+                    208u8, 191u8, 209u8, 131u8, 208u8, 189u8, 208u8, 181u8, 32u8, 209u8, 136u8,
+                    208u8, 184u8, 209u8, 128u8, 208u8, 184u8, 208u8, 189u8, 208u8, 181u8, 208u8,
+                    179u8, 209u8, 128u8, 209u8, 131u8, 208u8, 183u8, 208u8, 184u8, 209u8, 152u8,
+                    209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 208u8, 177u8, 209u8, 128u8,
+                    208u8, 190u8, 209u8, 152u8, 208u8, 181u8, 208u8, 178u8, 208u8, 184u8, 208u8,
+                    179u8, 209u8, 128u8, 209u8, 135u8, 208u8, 186u8, 208u8, 184u8, 32u8, 208u8,
+                    177u8, 209u8, 128u8, 208u8, 190u8, 209u8, 152u8, 208u8, 181u8, 208u8, 178u8,
+                    208u8, 184u8, 208u8, 188u8, 208u8, 176u8, 208u8, 187u8, 208u8, 184u8, 32u8,
+                    208u8, 179u8, 209u8, 128u8, 209u8, 135u8, 208u8, 186u8, 208u8, 184u8, 32u8,
+                    208u8, 177u8, 209u8, 128u8, 208u8, 190u8, 209u8, 152u8, 208u8, 181u8, 208u8,
+                    178u8, 208u8, 184u8, 208u8, 179u8, 209u8, 131u8, 209u8, 159u8, 208u8, 176u8,
+                    209u8, 128u8, 208u8, 176u8, 209u8, 130u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                    181u8, 32u8, 209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8, 208u8,
+                    181u8, 208u8, 179u8, 209u8, 131u8, 209u8, 128u8, 208u8, 188u8, 209u8, 131u8,
+                    208u8, 186u8, 208u8, 184u8, 32u8, 209u8, 134u8, 208u8, 184u8, 209u8, 132u8,
+                    209u8, 128u8, 208u8, 181u8, 208u8, 186u8, 208u8, 184u8, 208u8, 189u8, 208u8,
+                    181u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 208u8, 180u8, 208u8,
+                    181u8, 209u8, 134u8, 208u8, 184u8, 208u8, 188u8, 208u8, 176u8, 208u8, 187u8,
+                    208u8, 189u8, 208u8, 184u8, 32u8, 208u8, 177u8, 209u8, 128u8, 208u8, 190u8,
+                    209u8, 152u8, 208u8, 181u8, 208u8, 178u8, 208u8, 184u8, 208u8, 191u8, 208u8,
+                    190u8, 209u8, 152u8, 208u8, 181u8, 208u8, 180u8, 208u8, 189u8, 208u8, 190u8,
+                    209u8, 129u8, 209u8, 130u8, 208u8, 176u8, 208u8, 178u8, 209u8, 153u8, 208u8,
+                    181u8, 208u8, 189u8, 208u8, 184u8, 32u8, 208u8, 186u8, 208u8, 184u8, 208u8,
+                    189u8, 208u8, 181u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 208u8,
+                    177u8, 209u8, 128u8, 208u8, 190u8, 209u8, 152u8, 208u8, 181u8, 208u8, 178u8,
+                    208u8, 184u8, 208u8, 191u8, 208u8, 190u8, 209u8, 152u8, 208u8, 181u8, 208u8,
+                    180u8, 208u8, 189u8, 208u8, 190u8, 209u8, 129u8, 209u8, 130u8, 208u8, 176u8,
+                    208u8, 178u8, 209u8, 153u8, 208u8, 181u8, 208u8, 189u8, 208u8, 184u8, 32u8,
+                    208u8, 186u8, 208u8, 184u8, 208u8, 189u8, 208u8, 181u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 184u8, 32u8, 209u8, 132u8, 208u8, 184u8, 208u8, 189u8, 208u8,
+                    176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 184u8, 209u8, 152u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 184u8, 32u8, 208u8, 177u8, 209u8, 128u8, 208u8, 190u8,
+                    209u8, 152u8, 208u8, 181u8, 208u8, 178u8, 208u8, 184u8, 209u8, 130u8, 209u8,
+                    128u8, 208u8, 176u8, 208u8, 180u8, 208u8, 184u8, 209u8, 134u8, 208u8, 184u8,
+                    208u8, 190u8, 208u8, 189u8, 208u8, 176u8, 208u8, 187u8, 208u8, 189u8, 208u8,
+                    184u8, 32u8, 208u8, 186u8, 208u8, 184u8, 208u8, 189u8, 208u8, 181u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 208u8, 177u8, 209u8, 128u8, 208u8,
+                    190u8, 209u8, 152u8, 208u8, 181u8, 208u8, 178u8, 208u8, 184u8, 209u8, 130u8,
+                    209u8, 128u8, 208u8, 176u8, 208u8, 180u8, 208u8, 184u8, 209u8, 134u8, 208u8,
+                    184u8, 208u8, 190u8, 208u8, 189u8, 208u8, 176u8, 208u8, 187u8, 208u8, 189u8,
+                    208u8, 184u8, 32u8, 208u8, 186u8, 208u8, 184u8, 208u8, 189u8, 208u8, 181u8,
+                    209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 209u8, 132u8, 208u8, 184u8,
+                    208u8, 189u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 184u8, 209u8,
+                    152u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 208u8, 177u8, 209u8,
+                    128u8, 208u8, 190u8, 209u8, 152u8, 208u8, 181u8, 208u8, 178u8, 208u8, 184u8,
+                    209u8, 133u8, 208u8, 181u8, 208u8, 177u8, 209u8, 128u8, 208u8, 181u8, 209u8,
+                    152u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 208u8, 177u8, 209u8,
+                    128u8, 208u8, 190u8, 209u8, 152u8, 208u8, 181u8, 208u8, 178u8, 208u8, 184u8,
+                    209u8, 152u8, 208u8, 176u8, 208u8, 178u8, 208u8, 176u8, 208u8, 189u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 181u8, 32u8, 209u8, 134u8, 208u8, 184u8, 209u8,
+                    132u8, 209u8, 128u8, 208u8, 181u8, 209u8, 152u8, 208u8, 176u8, 208u8, 191u8,
+                    208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 32u8,
+                    208u8, 177u8, 209u8, 128u8, 208u8, 190u8, 209u8, 152u8, 208u8, 181u8, 208u8,
+                    178u8, 208u8, 184u8, 209u8, 152u8, 208u8, 176u8, 208u8, 191u8, 208u8, 176u8,
+                    208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 209u8, 132u8,
+                    208u8, 184u8, 208u8, 189u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8,
+                    184u8, 209u8, 152u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 208u8,
+                    177u8, 209u8, 128u8, 208u8, 190u8, 209u8, 152u8, 208u8, 181u8, 208u8, 178u8,
+                    208u8, 184u8, 208u8, 186u8, 208u8, 188u8, 208u8, 181u8, 209u8, 128u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 181u8, 32u8, 209u8, 134u8, 208u8, 184u8, 209u8,
+                    132u8, 209u8, 128u8, 208u8, 181u8, 208u8, 186u8, 208u8, 176u8, 208u8, 189u8,
+                    208u8, 176u8, 208u8, 180u8, 208u8, 176u8, 32u8, 209u8, 134u8, 208u8, 184u8,
+                    209u8, 132u8, 209u8, 128u8, 208u8, 181u8, 208u8, 187u8, 208u8, 176u8, 208u8,
+                    190u8, 209u8, 136u8, 208u8, 186u8, 208u8, 181u8, 32u8, 209u8, 134u8, 208u8,
+                    184u8, 209u8, 132u8, 209u8, 128u8, 208u8, 181u8, 208u8, 183u8, 208u8, 176u8,
+                    208u8, 191u8, 208u8, 176u8, 208u8, 180u8, 208u8, 189u8, 208u8, 181u8, 32u8,
+                    209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8, 208u8, 181u8, 208u8,
+                    188u8, 208u8, 176u8, 208u8, 187u8, 208u8, 176u8, 209u8, 152u8, 208u8, 176u8,
+                    208u8, 187u8, 208u8, 176u8, 208u8, 188u8, 32u8, 209u8, 134u8, 208u8, 184u8,
+                    209u8, 132u8, 209u8, 128u8, 208u8, 181u8, 208u8, 188u8, 208u8, 190u8, 208u8,
+                    189u8, 208u8, 179u8, 208u8, 190u8, 208u8, 187u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 181u8, 32u8, 209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8,
+                    208u8, 181u8, 208u8, 188u8, 208u8, 184u8, 209u8, 130u8, 208u8, 181u8, 208u8,
+                    184u8, 32u8, 208u8, 188u8, 208u8, 176u8, 209u8, 152u8, 208u8, 181u8, 208u8,
+                    186u8, 32u8, 209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8, 208u8,
+                    181u8, 208u8, 188u8, 208u8, 184u8, 209u8, 152u8, 208u8, 176u8, 208u8, 189u8,
+                    208u8, 188u8, 208u8, 176u8, 209u8, 128u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                    181u8, 32u8, 209u8, 134u8, 208u8, 184u8, 209u8, 132u8, 209u8, 128u8, 208u8,
+                    181u8, 208u8, 187u8, 208u8, 190u8, 208u8, 186u8, 208u8, 176u8, 208u8, 187u8,
+                    208u8, 189u8, 208u8, 181u8, 32u8, 209u8, 134u8, 208u8, 184u8, 209u8, 132u8,
+                    209u8, 128u8, 208u8, 181u8, 208u8, 190u8, 208u8, 187u8, 32u8, 209u8, 135u8,
+                    208u8, 184u8, 208u8, 186u8, 208u8, 184u8, 32u8, 209u8, 134u8, 208u8, 184u8,
+                    209u8, 132u8, 209u8, 128u8, 208u8, 181u8, 208u8, 190u8, 209u8, 128u8, 208u8,
+                    184u8, 209u8, 152u8, 208u8, 176u8, 32u8, 209u8, 134u8, 208u8, 184u8, 209u8,
+                    132u8, 209u8, 128u8, 208u8, 181u8, 209u8, 128u8, 208u8, 184u8, 208u8, 188u8,
+                    209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 208u8, 177u8, 209u8, 128u8,
+                    208u8, 190u8, 209u8, 152u8, 208u8, 181u8, 208u8, 178u8, 208u8, 184u8, 208u8,
+                    188u8, 208u8, 176u8, 208u8, 187u8, 208u8, 184u8, 32u8, 209u8, 128u8, 208u8,
+                    184u8, 208u8, 188u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 208u8,
+                    177u8, 209u8, 128u8, 208u8, 190u8, 209u8, 152u8, 208u8, 181u8, 208u8, 178u8,
+                    208u8, 184u8, 209u8, 130u8, 208u8, 176u8, 208u8, 188u8, 208u8, 184u8, 208u8,
+                    187u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 208u8, 177u8, 209u8,
+                    128u8, 208u8, 190u8, 209u8, 152u8, 208u8, 181u8, 208u8, 178u8, 208u8, 184u8,
+                    209u8, 130u8, 208u8, 176u8, 208u8, 188u8, 208u8, 184u8, 208u8, 187u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 181u8, 32u8, 209u8, 134u8, 208u8, 184u8, 209u8,
+                    132u8, 209u8, 128u8, 208u8, 181u8, 209u8, 130u8, 208u8, 181u8, 208u8, 187u8,
+                    209u8, 131u8, 208u8, 179u8, 209u8, 131u8, 32u8, 209u8, 134u8, 208u8, 184u8,
+                    209u8, 132u8, 209u8, 128u8, 208u8, 181u8, 209u8, 130u8, 208u8, 176u8, 209u8,
+                    152u8, 209u8, 129u8, 208u8, 186u8, 208u8, 181u8, 32u8, 209u8, 134u8, 208u8,
+                    184u8, 209u8, 132u8, 209u8, 128u8, 208u8, 181u8, 209u8, 130u8, 208u8, 184u8,
+                    208u8, 177u8, 208u8, 181u8, 209u8, 130u8, 208u8, 176u8, 208u8, 189u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 181u8, 32u8, 209u8, 134u8, 208u8, 184u8, 209u8,
+                    132u8, 209u8, 128u8, 208u8, 181u8, 208u8, 162u8, 209u8, 128u8, 208u8, 176u8,
+                    208u8, 180u8, 208u8, 184u8, 209u8, 134u8, 208u8, 184u8, 208u8, 190u8, 208u8,
+                    189u8, 208u8, 176u8, 208u8, 187u8, 208u8, 189u8, 208u8, 184u8, 32u8, 208u8,
+                    177u8, 209u8, 128u8, 208u8, 190u8, 209u8, 152u8, 208u8, 181u8, 208u8, 178u8,
+                    208u8, 184u8, 208u8, 178u8, 208u8, 176u8, 208u8, 184u8, 32u8, 209u8, 134u8,
+                    208u8, 184u8, 209u8, 132u8, 209u8, 128u8, 208u8, 181u8,
+                ])
+            },
+        )
+    },
+};
+static TH: &DataStruct = &::icu_displaynames::provider::KeyDisplayNamesV1 {
+    keys: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    99u8, 97u8, 99u8, 102u8, 99u8, 111u8, 99u8, 117u8, 104u8, 99u8, 107u8, 97u8,
+                    107u8, 98u8, 107u8, 99u8, 107u8, 102u8, 107u8, 107u8, 107u8, 110u8, 107u8,
+                    115u8, 108u8, 98u8, 109u8, 115u8, 110u8, 117u8, 116u8, 122u8, 118u8, 97u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    17u8, 0u8, 0u8, 0u8, 0u8, 0u8, 18u8, 0u8, 60u8, 0u8, 108u8, 0u8, 132u8, 0u8,
+                    199u8, 0u8, 21u8, 1u8, 132u8, 1u8, 241u8, 1u8, 97u8, 2u8, 172u8, 2u8, 223u8,
+                    2u8, 36u8, 3u8, 105u8, 3u8, 135u8, 3u8, 153u8, 3u8, 174u8, 3u8, 224u8, 184u8,
+                    155u8, 224u8, 184u8, 143u8, 224u8, 184u8, 180u8, 224u8, 184u8, 151u8, 224u8,
+                    184u8, 180u8, 224u8, 184u8, 153u8, 224u8, 184u8, 163u8, 224u8, 184u8, 185u8,
+                    224u8, 184u8, 155u8, 224u8, 185u8, 129u8, 224u8, 184u8, 154u8, 224u8, 184u8,
+                    154u8, 224u8, 184u8, 170u8, 224u8, 184u8, 129u8, 224u8, 184u8, 184u8, 224u8,
+                    184u8, 165u8, 224u8, 185u8, 128u8, 224u8, 184u8, 135u8, 224u8, 184u8, 180u8,
+                    224u8, 184u8, 153u8, 224u8, 184u8, 165u8, 224u8, 184u8, 179u8, 224u8, 184u8,
+                    148u8, 224u8, 184u8, 177u8, 224u8, 184u8, 154u8, 224u8, 184u8, 129u8, 224u8,
+                    184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 184u8, 136u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 148u8, 224u8, 185u8, 128u8, 224u8, 184u8, 163u8, 224u8, 184u8,
+                    181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 135u8, 224u8, 184u8, 170u8, 224u8,
+                    184u8, 129u8, 224u8, 184u8, 184u8, 224u8, 184u8, 165u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 135u8, 224u8, 184u8, 180u8, 224u8, 184u8, 153u8, 224u8, 184u8,
+                    167u8, 224u8, 184u8, 135u8, 224u8, 184u8, 136u8, 224u8, 184u8, 163u8, 224u8,
+                    184u8, 138u8, 224u8, 184u8, 177u8, 224u8, 185u8, 136u8, 224u8, 184u8, 167u8,
+                    224u8, 185u8, 130u8, 224u8, 184u8, 161u8, 224u8, 184u8, 135u8, 32u8, 40u8,
+                    224u8, 185u8, 128u8, 224u8, 184u8, 151u8, 224u8, 184u8, 181u8, 224u8, 184u8,
+                    162u8, 224u8, 184u8, 154u8, 32u8, 49u8, 50u8, 32u8, 224u8, 185u8, 129u8, 224u8,
+                    184u8, 165u8, 224u8, 184u8, 176u8, 32u8, 50u8, 52u8, 41u8, 224u8, 184u8, 165u8,
+                    224u8, 184u8, 176u8, 224u8, 185u8, 128u8, 224u8, 184u8, 167u8, 224u8, 185u8,
+                    137u8, 224u8, 184u8, 153u8, 224u8, 184u8, 129u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 136u8, 224u8, 184u8, 177u8, 224u8, 184u8, 148u8,
+                    224u8, 185u8, 128u8, 224u8, 184u8, 163u8, 224u8, 184u8, 181u8, 224u8, 184u8,
+                    162u8, 224u8, 184u8, 135u8, 224u8, 184u8, 170u8, 224u8, 184u8, 177u8, 224u8,
+                    184u8, 141u8, 224u8, 184u8, 165u8, 224u8, 184u8, 177u8, 224u8, 184u8, 129u8,
+                    224u8, 184u8, 169u8, 224u8, 184u8, 147u8, 224u8, 185u8, 140u8, 224u8, 184u8,
+                    129u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 184u8, 136u8, 224u8,
+                    184u8, 177u8, 224u8, 184u8, 148u8, 224u8, 185u8, 128u8, 224u8, 184u8, 163u8,
+                    224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 135u8, 224u8, 185u8,
+                    128u8, 224u8, 184u8, 170u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8,
+                    184u8, 135u8, 224u8, 184u8, 171u8, 224u8, 184u8, 153u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 129u8, 224u8, 185u8, 128u8, 224u8, 184u8, 154u8, 224u8, 184u8,
+                    178u8, 224u8, 185u8, 131u8, 224u8, 184u8, 153u8, 224u8, 184u8, 165u8, 224u8,
+                    184u8, 179u8, 224u8, 184u8, 148u8, 224u8, 184u8, 177u8, 224u8, 184u8, 154u8,
+                    224u8, 184u8, 150u8, 224u8, 184u8, 173u8, 224u8, 184u8, 162u8, 224u8, 184u8,
+                    171u8, 224u8, 184u8, 165u8, 224u8, 184u8, 177u8, 224u8, 184u8, 135u8, 224u8,
+                    184u8, 129u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 184u8, 136u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 148u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    163u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 135u8, 224u8,
+                    185u8, 129u8, 224u8, 184u8, 154u8, 224u8, 184u8, 154u8, 224u8, 184u8, 149u8,
+                    224u8, 184u8, 163u8, 224u8, 184u8, 135u8, 224u8, 184u8, 149u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 161u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8, 224u8,
+                    184u8, 167u8, 224u8, 184u8, 158u8, 224u8, 184u8, 180u8, 224u8, 184u8, 161u8,
+                    224u8, 184u8, 158u8, 224u8, 185u8, 140u8, 224u8, 185u8, 131u8, 224u8, 184u8,
+                    171u8, 224u8, 184u8, 141u8, 224u8, 185u8, 136u8, 45u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 165u8, 224u8, 185u8, 135u8, 224u8, 184u8, 129u8, 224u8, 184u8,
+                    129u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 185u8, 128u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 135u8,
+                    224u8, 184u8, 165u8, 224u8, 184u8, 179u8, 224u8, 184u8, 148u8, 224u8, 184u8,
+                    177u8, 224u8, 184u8, 154u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8, 224u8,
+                    184u8, 167u8, 224u8, 184u8, 158u8, 224u8, 184u8, 180u8, 224u8, 184u8, 161u8,
+                    224u8, 184u8, 158u8, 224u8, 185u8, 140u8, 224u8, 185u8, 131u8, 224u8, 184u8,
+                    171u8, 224u8, 184u8, 141u8, 224u8, 185u8, 136u8, 47u8, 224u8, 184u8, 149u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 184u8, 158u8, 224u8, 184u8,
+                    180u8, 224u8, 184u8, 161u8, 224u8, 184u8, 158u8, 224u8, 185u8, 140u8, 224u8,
+                    185u8, 128u8, 224u8, 184u8, 165u8, 224u8, 185u8, 135u8, 224u8, 184u8, 129u8,
+                    224u8, 184u8, 129u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 184u8,
+                    136u8, 224u8, 184u8, 177u8, 224u8, 184u8, 148u8, 224u8, 185u8, 128u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 135u8,
+                    224u8, 185u8, 129u8, 224u8, 184u8, 154u8, 224u8, 184u8, 154u8, 224u8, 184u8,
+                    151u8, 224u8, 184u8, 181u8, 224u8, 185u8, 136u8, 224u8, 185u8, 128u8, 224u8,
+                    184u8, 155u8, 224u8, 185u8, 135u8, 224u8, 184u8, 153u8, 224u8, 184u8, 155u8,
+                    224u8, 184u8, 129u8, 224u8, 184u8, 149u8, 224u8, 184u8, 180u8, 224u8, 184u8,
+                    129u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 184u8, 136u8, 224u8,
+                    184u8, 177u8, 224u8, 184u8, 148u8, 224u8, 185u8, 128u8, 224u8, 184u8, 163u8,
+                    224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 135u8, 224u8, 184u8,
+                    149u8, 224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8,
+                    184u8, 165u8, 224u8, 184u8, 130u8, 224u8, 184u8, 132u8, 224u8, 184u8, 167u8,
+                    224u8, 184u8, 178u8, 224u8, 184u8, 161u8, 224u8, 185u8, 129u8, 224u8, 184u8,
+                    161u8, 224u8, 185u8, 136u8, 224u8, 184u8, 153u8, 224u8, 184u8, 162u8, 224u8,
+                    184u8, 179u8, 224u8, 185u8, 131u8, 224u8, 184u8, 153u8, 224u8, 184u8, 129u8,
+                    224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 184u8, 136u8, 224u8, 184u8,
+                    177u8, 224u8, 184u8, 148u8, 224u8, 185u8, 128u8, 224u8, 184u8, 163u8, 224u8,
+                    184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 135u8, 224u8, 184u8, 163u8,
+                    224u8, 184u8, 185u8, 224u8, 184u8, 155u8, 224u8, 185u8, 129u8, 224u8, 184u8,
+                    154u8, 224u8, 184u8, 154u8, 224u8, 184u8, 129u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 130u8, 224u8, 184u8, 182u8, 224u8, 185u8, 137u8,
+                    224u8, 184u8, 153u8, 224u8, 184u8, 154u8, 224u8, 184u8, 163u8, 224u8, 184u8,
+                    163u8, 224u8, 184u8, 151u8, 224u8, 184u8, 177u8, 224u8, 184u8, 148u8, 224u8,
+                    185u8, 131u8, 224u8, 184u8, 171u8, 224u8, 184u8, 161u8, 224u8, 185u8, 136u8,
+                    224u8, 184u8, 163u8, 224u8, 184u8, 176u8, 224u8, 184u8, 154u8, 224u8, 184u8,
+                    154u8, 224u8, 184u8, 129u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 224u8,
+                    184u8, 167u8, 224u8, 184u8, 177u8, 224u8, 184u8, 148u8, 224u8, 184u8, 149u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    165u8, 224u8, 184u8, 130u8, 224u8, 185u8, 128u8, 224u8, 184u8, 130u8, 224u8,
+                    184u8, 149u8, 224u8, 185u8, 128u8, 224u8, 184u8, 167u8, 224u8, 184u8, 165u8,
+                    224u8, 184u8, 178u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8, 224u8, 184u8,
+                    167u8, 224u8, 185u8, 129u8, 224u8, 184u8, 155u8, 224u8, 184u8, 163u8, 224u8,
+                    184u8, 160u8, 224u8, 184u8, 178u8, 224u8, 184u8, 169u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 150u8, 224u8, 184u8, 180u8, 224u8, 185u8, 136u8, 224u8, 184u8,
+                    153u8,
+                ])
+            },
+        )
+    },
+    types: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    126u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 21u8, 0u8, 30u8, 0u8, 38u8, 0u8,
+                    48u8, 0u8, 59u8, 0u8, 69u8, 0u8, 78u8, 0u8, 87u8, 0u8, 97u8, 0u8, 113u8, 0u8,
+                    128u8, 0u8, 143u8, 0u8, 162u8, 0u8, 172u8, 0u8, 183u8, 0u8, 193u8, 0u8, 199u8,
+                    0u8, 209u8, 0u8, 220u8, 0u8, 230u8, 0u8, 239u8, 0u8, 246u8, 0u8, 254u8, 0u8,
+                    4u8, 1u8, 13u8, 1u8, 23u8, 1u8, 34u8, 1u8, 43u8, 1u8, 54u8, 1u8, 63u8, 1u8,
+                    74u8, 1u8, 85u8, 1u8, 94u8, 1u8, 101u8, 1u8, 110u8, 1u8, 119u8, 1u8, 125u8,
+                    1u8, 131u8, 1u8, 137u8, 1u8, 143u8, 1u8, 154u8, 1u8, 164u8, 1u8, 172u8, 1u8,
+                    179u8, 1u8, 187u8, 1u8, 194u8, 1u8, 202u8, 1u8, 210u8, 1u8, 218u8, 1u8, 226u8,
+                    1u8, 233u8, 1u8, 241u8, 1u8, 248u8, 1u8, 2u8, 2u8, 11u8, 2u8, 20u8, 2u8, 29u8,
+                    2u8, 38u8, 2u8, 46u8, 2u8, 55u8, 2u8, 64u8, 2u8, 73u8, 2u8, 84u8, 2u8, 95u8,
+                    2u8, 102u8, 2u8, 112u8, 2u8, 119u8, 2u8, 129u8, 2u8, 136u8, 2u8, 143u8, 2u8,
+                    150u8, 2u8, 157u8, 2u8, 164u8, 2u8, 171u8, 2u8, 178u8, 2u8, 188u8, 2u8, 199u8,
+                    2u8, 206u8, 2u8, 213u8, 2u8, 223u8, 2u8, 230u8, 2u8, 237u8, 2u8, 247u8, 2u8,
+                    254u8, 2u8, 8u8, 3u8, 15u8, 3u8, 25u8, 3u8, 32u8, 3u8, 39u8, 3u8, 46u8, 3u8,
+                    56u8, 3u8, 63u8, 3u8, 70u8, 3u8, 77u8, 3u8, 84u8, 3u8, 95u8, 3u8, 102u8, 3u8,
+                    109u8, 3u8, 116u8, 3u8, 123u8, 3u8, 130u8, 3u8, 137u8, 3u8, 144u8, 3u8, 151u8,
+                    3u8, 162u8, 3u8, 171u8, 3u8, 178u8, 3u8, 185u8, 3u8, 192u8, 3u8, 199u8, 3u8,
+                    207u8, 3u8, 218u8, 3u8, 225u8, 3u8, 232u8, 3u8, 239u8, 3u8, 246u8, 3u8, 253u8,
+                    3u8, 4u8, 4u8, 11u8, 4u8, 21u8, 4u8, 28u8, 4u8, 35u8, 4u8, 42u8, 4u8, 53u8,
+                    4u8, 99u8, 97u8, 45u8, 98u8, 117u8, 100u8, 100u8, 104u8, 105u8, 115u8, 116u8,
+                    99u8, 97u8, 45u8, 99u8, 104u8, 105u8, 110u8, 101u8, 115u8, 101u8, 99u8, 97u8,
+                    45u8, 99u8, 111u8, 112u8, 116u8, 105u8, 99u8, 99u8, 97u8, 45u8, 100u8, 97u8,
+                    110u8, 103u8, 105u8, 99u8, 97u8, 45u8, 101u8, 116u8, 104u8, 105u8, 111u8, 97u8,
+                    97u8, 99u8, 97u8, 45u8, 101u8, 116u8, 104u8, 105u8, 111u8, 112u8, 105u8, 99u8,
+                    99u8, 97u8, 45u8, 103u8, 114u8, 101u8, 103u8, 111u8, 114u8, 121u8, 99u8, 97u8,
+                    45u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 99u8, 97u8, 45u8, 105u8, 110u8,
+                    100u8, 105u8, 97u8, 110u8, 99u8, 97u8, 45u8, 105u8, 115u8, 108u8, 97u8, 109u8,
+                    105u8, 99u8, 99u8, 97u8, 45u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8,
+                    45u8, 99u8, 105u8, 118u8, 105u8, 108u8, 99u8, 97u8, 45u8, 105u8, 115u8, 108u8,
+                    97u8, 109u8, 105u8, 99u8, 45u8, 114u8, 103u8, 115u8, 97u8, 99u8, 97u8, 45u8,
+                    105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 116u8, 98u8, 108u8, 97u8,
+                    99u8, 97u8, 45u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 117u8,
+                    109u8, 97u8, 108u8, 113u8, 117u8, 114u8, 97u8, 99u8, 97u8, 45u8, 105u8, 115u8,
+                    111u8, 56u8, 54u8, 48u8, 49u8, 99u8, 97u8, 45u8, 106u8, 97u8, 112u8, 97u8,
+                    110u8, 101u8, 115u8, 101u8, 99u8, 97u8, 45u8, 112u8, 101u8, 114u8, 115u8,
+                    105u8, 97u8, 110u8, 99u8, 97u8, 45u8, 114u8, 111u8, 99u8, 99u8, 102u8, 45u8,
+                    97u8, 99u8, 99u8, 111u8, 117u8, 110u8, 116u8, 99u8, 102u8, 45u8, 115u8, 116u8,
+                    97u8, 110u8, 100u8, 97u8, 114u8, 100u8, 99u8, 111u8, 45u8, 98u8, 105u8, 103u8,
+                    53u8, 104u8, 97u8, 110u8, 99u8, 111u8, 45u8, 99u8, 111u8, 109u8, 112u8, 97u8,
+                    116u8, 99u8, 111u8, 45u8, 100u8, 105u8, 99u8, 116u8, 99u8, 111u8, 45u8, 100u8,
+                    117u8, 99u8, 101u8, 116u8, 99u8, 111u8, 45u8, 101u8, 111u8, 114u8, 99u8, 111u8,
+                    45u8, 103u8, 98u8, 50u8, 51u8, 49u8, 50u8, 99u8, 111u8, 45u8, 112u8, 104u8,
+                    111u8, 110u8, 101u8, 98u8, 107u8, 99u8, 111u8, 45u8, 112u8, 104u8, 111u8,
+                    110u8, 101u8, 116u8, 105u8, 99u8, 99u8, 111u8, 45u8, 112u8, 105u8, 110u8,
+                    121u8, 105u8, 110u8, 99u8, 111u8, 45u8, 114u8, 101u8, 102u8, 111u8, 114u8,
+                    109u8, 101u8, 100u8, 99u8, 111u8, 45u8, 115u8, 101u8, 97u8, 114u8, 99u8, 104u8,
+                    99u8, 111u8, 45u8, 115u8, 101u8, 97u8, 114u8, 99u8, 104u8, 106u8, 108u8, 99u8,
+                    111u8, 45u8, 115u8, 116u8, 97u8, 110u8, 100u8, 97u8, 114u8, 100u8, 99u8, 111u8,
+                    45u8, 115u8, 116u8, 114u8, 111u8, 107u8, 101u8, 99u8, 111u8, 45u8, 116u8,
+                    114u8, 97u8, 100u8, 99u8, 111u8, 45u8, 117u8, 110u8, 105u8, 104u8, 97u8, 110u8,
+                    99u8, 111u8, 45u8, 122u8, 104u8, 117u8, 121u8, 105u8, 110u8, 104u8, 99u8, 45u8,
+                    104u8, 49u8, 49u8, 104u8, 99u8, 45u8, 104u8, 49u8, 50u8, 104u8, 99u8, 45u8,
+                    104u8, 50u8, 51u8, 104u8, 99u8, 45u8, 104u8, 50u8, 52u8, 107u8, 97u8, 45u8,
+                    110u8, 111u8, 105u8, 103u8, 110u8, 111u8, 114u8, 101u8, 107u8, 97u8, 45u8,
+                    115u8, 104u8, 105u8, 102u8, 116u8, 101u8, 100u8, 107u8, 98u8, 45u8, 102u8,
+                    97u8, 108u8, 115u8, 101u8, 107u8, 98u8, 45u8, 116u8, 114u8, 117u8, 101u8,
+                    107u8, 99u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8, 107u8, 99u8, 45u8, 116u8,
+                    114u8, 117u8, 101u8, 107u8, 102u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8,
+                    107u8, 102u8, 45u8, 108u8, 111u8, 119u8, 101u8, 114u8, 107u8, 102u8, 45u8,
+                    117u8, 112u8, 112u8, 101u8, 114u8, 107u8, 107u8, 45u8, 102u8, 97u8, 108u8,
+                    115u8, 101u8, 107u8, 107u8, 45u8, 116u8, 114u8, 117u8, 101u8, 107u8, 110u8,
+                    45u8, 102u8, 97u8, 108u8, 115u8, 101u8, 107u8, 110u8, 45u8, 116u8, 114u8,
+                    117u8, 101u8, 107u8, 115u8, 45u8, 105u8, 100u8, 101u8, 110u8, 116u8, 105u8,
+                    99u8, 107u8, 115u8, 45u8, 108u8, 101u8, 118u8, 101u8, 108u8, 49u8, 107u8,
+                    115u8, 45u8, 108u8, 101u8, 118u8, 101u8, 108u8, 50u8, 107u8, 115u8, 45u8,
+                    108u8, 101u8, 118u8, 101u8, 108u8, 51u8, 107u8, 115u8, 45u8, 108u8, 101u8,
+                    118u8, 101u8, 108u8, 52u8, 108u8, 98u8, 45u8, 108u8, 111u8, 111u8, 115u8,
+                    101u8, 108u8, 98u8, 45u8, 110u8, 111u8, 114u8, 109u8, 97u8, 108u8, 108u8, 98u8,
+                    45u8, 115u8, 116u8, 114u8, 105u8, 99u8, 116u8, 109u8, 115u8, 45u8, 109u8,
+                    101u8, 116u8, 114u8, 105u8, 99u8, 109u8, 115u8, 45u8, 117u8, 107u8, 115u8,
+                    121u8, 115u8, 116u8, 101u8, 109u8, 109u8, 115u8, 45u8, 117u8, 115u8, 115u8,
+                    121u8, 115u8, 116u8, 101u8, 109u8, 110u8, 117u8, 45u8, 97u8, 114u8, 97u8, 98u8,
+                    110u8, 117u8, 45u8, 97u8, 114u8, 97u8, 98u8, 101u8, 120u8, 116u8, 110u8, 117u8,
+                    45u8, 97u8, 114u8, 109u8, 110u8, 110u8, 117u8, 45u8, 97u8, 114u8, 109u8, 110u8,
+                    108u8, 111u8, 119u8, 110u8, 117u8, 45u8, 98u8, 97u8, 108u8, 105u8, 110u8,
+                    117u8, 45u8, 98u8, 101u8, 110u8, 103u8, 110u8, 117u8, 45u8, 98u8, 114u8, 97u8,
+                    104u8, 110u8, 117u8, 45u8, 99u8, 97u8, 107u8, 109u8, 110u8, 117u8, 45u8, 99u8,
+                    104u8, 97u8, 109u8, 110u8, 117u8, 45u8, 100u8, 101u8, 118u8, 97u8, 110u8,
+                    117u8, 45u8, 101u8, 116u8, 104u8, 105u8, 110u8, 117u8, 45u8, 102u8, 105u8,
+                    110u8, 97u8, 110u8, 99u8, 101u8, 110u8, 117u8, 45u8, 102u8, 117u8, 108u8,
+                    108u8, 119u8, 105u8, 100u8, 101u8, 110u8, 117u8, 45u8, 103u8, 101u8, 111u8,
+                    114u8, 110u8, 117u8, 45u8, 103u8, 114u8, 101u8, 107u8, 110u8, 117u8, 45u8,
+                    103u8, 114u8, 101u8, 107u8, 108u8, 111u8, 119u8, 110u8, 117u8, 45u8, 103u8,
+                    117u8, 106u8, 114u8, 110u8, 117u8, 45u8, 103u8, 117u8, 114u8, 117u8, 110u8,
+                    117u8, 45u8, 104u8, 97u8, 110u8, 105u8, 100u8, 101u8, 99u8, 110u8, 117u8, 45u8,
+                    104u8, 97u8, 110u8, 115u8, 110u8, 117u8, 45u8, 104u8, 97u8, 110u8, 115u8,
+                    102u8, 105u8, 110u8, 110u8, 117u8, 45u8, 104u8, 97u8, 110u8, 116u8, 110u8,
+                    117u8, 45u8, 104u8, 97u8, 110u8, 116u8, 102u8, 105u8, 110u8, 110u8, 117u8,
+                    45u8, 104u8, 101u8, 98u8, 114u8, 110u8, 117u8, 45u8, 106u8, 97u8, 118u8, 97u8,
+                    110u8, 117u8, 45u8, 106u8, 112u8, 97u8, 110u8, 110u8, 117u8, 45u8, 106u8,
+                    112u8, 97u8, 110u8, 102u8, 105u8, 110u8, 110u8, 117u8, 45u8, 107u8, 97u8,
+                    108u8, 105u8, 110u8, 117u8, 45u8, 107u8, 104u8, 109u8, 114u8, 110u8, 117u8,
+                    45u8, 107u8, 110u8, 100u8, 97u8, 110u8, 117u8, 45u8, 108u8, 97u8, 110u8, 97u8,
+                    110u8, 117u8, 45u8, 108u8, 97u8, 110u8, 97u8, 116u8, 104u8, 97u8, 109u8, 110u8,
+                    117u8, 45u8, 108u8, 97u8, 111u8, 111u8, 110u8, 117u8, 45u8, 108u8, 97u8, 116u8,
+                    110u8, 110u8, 117u8, 45u8, 108u8, 101u8, 112u8, 99u8, 110u8, 117u8, 45u8,
+                    108u8, 105u8, 109u8, 98u8, 110u8, 117u8, 45u8, 109u8, 108u8, 121u8, 109u8,
+                    110u8, 117u8, 45u8, 109u8, 111u8, 110u8, 103u8, 110u8, 117u8, 45u8, 109u8,
+                    116u8, 101u8, 105u8, 110u8, 117u8, 45u8, 109u8, 121u8, 109u8, 114u8, 110u8,
+                    117u8, 45u8, 109u8, 121u8, 109u8, 114u8, 115u8, 104u8, 97u8, 110u8, 110u8,
+                    117u8, 45u8, 110u8, 97u8, 116u8, 105u8, 118u8, 101u8, 110u8, 117u8, 45u8,
+                    110u8, 107u8, 111u8, 111u8, 110u8, 117u8, 45u8, 111u8, 108u8, 99u8, 107u8,
+                    110u8, 117u8, 45u8, 111u8, 114u8, 121u8, 97u8, 110u8, 117u8, 45u8, 111u8,
+                    115u8, 109u8, 97u8, 110u8, 117u8, 45u8, 114u8, 111u8, 109u8, 97u8, 110u8,
+                    110u8, 117u8, 45u8, 114u8, 111u8, 109u8, 97u8, 110u8, 108u8, 111u8, 119u8,
+                    110u8, 117u8, 45u8, 115u8, 97u8, 117u8, 114u8, 110u8, 117u8, 45u8, 115u8,
+                    104u8, 114u8, 100u8, 110u8, 117u8, 45u8, 115u8, 111u8, 114u8, 97u8, 110u8,
+                    117u8, 45u8, 115u8, 117u8, 110u8, 100u8, 110u8, 117u8, 45u8, 116u8, 97u8,
+                    107u8, 114u8, 110u8, 117u8, 45u8, 116u8, 97u8, 108u8, 117u8, 110u8, 117u8,
+                    45u8, 116u8, 97u8, 109u8, 108u8, 110u8, 117u8, 45u8, 116u8, 97u8, 109u8, 108u8,
+                    100u8, 101u8, 99u8, 110u8, 117u8, 45u8, 116u8, 101u8, 108u8, 117u8, 110u8,
+                    117u8, 45u8, 116u8, 104u8, 97u8, 105u8, 110u8, 117u8, 45u8, 116u8, 105u8, 98u8,
+                    116u8, 110u8, 117u8, 45u8, 116u8, 114u8, 97u8, 100u8, 105u8, 116u8, 105u8,
+                    111u8, 110u8, 117u8, 45u8, 118u8, 97u8, 105u8, 105u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    126u8, 0u8, 0u8, 0u8, 0u8, 0u8, 30u8, 0u8, 57u8, 0u8, 93u8, 0u8, 129u8, 0u8,
+                    192u8, 0u8, 240u8, 0u8, 32u8, 1u8, 65u8, 1u8, 128u8, 1u8, 164u8, 1u8, 215u8,
+                    1u8, 95u8, 2u8, 201u8, 2u8, 20u8, 3u8, 47u8, 3u8, 86u8, 3u8, 131u8, 3u8, 170u8,
+                    3u8, 227u8, 3u8, 34u8, 4u8, 139u8, 4u8, 7u8, 5u8, 91u8, 5u8, 181u8, 5u8, 3u8,
+                    6u8, 108u8, 6u8, 201u8, 6u8, 35u8, 7u8, 146u8, 7u8, 236u8, 7u8, 22u8, 8u8,
+                    127u8, 8u8, 205u8, 8u8, 36u8, 9u8, 126u8, 9u8, 207u8, 9u8, 56u8, 10u8, 102u8,
+                    10u8, 148u8, 10u8, 194u8, 10u8, 240u8, 10u8, 35u8, 11u8, 110u8, 11u8, 191u8,
+                    11u8, 37u8, 12u8, 130u8, 12u8, 214u8, 12u8, 42u8, 13u8, 129u8, 13u8, 201u8,
+                    13u8, 47u8, 14u8, 137u8, 14u8, 188u8, 14u8, 16u8, 15u8, 61u8, 15u8, 142u8,
+                    15u8, 202u8, 15u8, 59u8, 16u8, 218u8, 16u8, 73u8, 17u8, 163u8, 17u8, 21u8,
+                    18u8, 51u8, 18u8, 111u8, 18u8, 165u8, 18u8, 220u8, 18u8, 40u8, 19u8, 88u8,
+                    19u8, 163u8, 19u8, 196u8, 19u8, 241u8, 19u8, 21u8, 20u8, 57u8, 20u8, 84u8,
+                    20u8, 126u8, 20u8, 174u8, 20u8, 222u8, 20u8, 17u8, 21u8, 59u8, 21u8, 89u8,
+                    21u8, 146u8, 21u8, 182u8, 21u8, 224u8, 21u8, 13u8, 22u8, 58u8, 22u8, 151u8,
+                    22u8, 199u8, 22u8, 39u8, 23u8, 72u8, 23u8, 99u8, 23u8, 138u8, 23u8, 216u8,
+                    23u8, 249u8, 23u8, 23u8, 24u8, 62u8, 24u8, 107u8, 24u8, 149u8, 24u8, 176u8,
+                    24u8, 212u8, 24u8, 245u8, 24u8, 22u8, 25u8, 67u8, 25u8, 112u8, 25u8, 157u8,
+                    25u8, 187u8, 25u8, 226u8, 25u8, 15u8, 26u8, 51u8, 26u8, 90u8, 26u8, 126u8,
+                    26u8, 168u8, 26u8, 201u8, 26u8, 5u8, 27u8, 50u8, 27u8, 86u8, 27u8, 134u8, 27u8,
+                    167u8, 27u8, 200u8, 27u8, 248u8, 27u8, 46u8, 28u8, 76u8, 28u8, 112u8, 28u8,
+                    139u8, 28u8, 172u8, 28u8, 223u8, 28u8, 224u8, 184u8, 155u8, 224u8, 184u8,
+                    143u8, 224u8, 184u8, 180u8, 224u8, 184u8, 151u8, 224u8, 184u8, 180u8, 224u8,
+                    184u8, 153u8, 224u8, 184u8, 158u8, 224u8, 184u8, 184u8, 224u8, 184u8, 151u8,
+                    224u8, 184u8, 152u8, 224u8, 184u8, 155u8, 224u8, 184u8, 143u8, 224u8, 184u8,
+                    180u8, 224u8, 184u8, 151u8, 224u8, 184u8, 180u8, 224u8, 184u8, 153u8, 224u8,
+                    184u8, 136u8, 224u8, 184u8, 181u8, 224u8, 184u8, 153u8, 224u8, 184u8, 155u8,
+                    224u8, 184u8, 143u8, 224u8, 184u8, 180u8, 224u8, 184u8, 151u8, 224u8, 184u8,
+                    180u8, 224u8, 184u8, 153u8, 224u8, 184u8, 132u8, 224u8, 184u8, 173u8, 224u8,
+                    184u8, 155u8, 224u8, 184u8, 149u8, 224u8, 184u8, 180u8, 224u8, 184u8, 129u8,
+                    224u8, 184u8, 155u8, 224u8, 184u8, 143u8, 224u8, 184u8, 180u8, 224u8, 184u8,
+                    151u8, 224u8, 184u8, 180u8, 224u8, 184u8, 153u8, 224u8, 185u8, 128u8, 224u8,
+                    184u8, 129u8, 224u8, 184u8, 178u8, 224u8, 184u8, 171u8, 224u8, 184u8, 165u8,
+                    224u8, 184u8, 181u8, 224u8, 184u8, 155u8, 224u8, 184u8, 143u8, 224u8, 184u8,
+                    180u8, 224u8, 184u8, 151u8, 224u8, 184u8, 180u8, 224u8, 184u8, 153u8, 224u8,
+                    184u8, 155u8, 224u8, 184u8, 181u8, 224u8, 185u8, 130u8, 224u8, 184u8, 165u8,
+                    224u8, 184u8, 129u8, 224u8, 185u8, 128u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                    152u8, 224u8, 184u8, 180u8, 224u8, 185u8, 130u8, 224u8, 184u8, 173u8, 224u8,
+                    185u8, 128u8, 224u8, 184u8, 155u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8,
+                    224u8, 184u8, 155u8, 224u8, 184u8, 143u8, 224u8, 184u8, 180u8, 224u8, 184u8,
+                    151u8, 224u8, 184u8, 180u8, 224u8, 184u8, 153u8, 224u8, 185u8, 128u8, 224u8,
+                    184u8, 173u8, 224u8, 184u8, 152u8, 224u8, 184u8, 180u8, 224u8, 185u8, 130u8,
+                    224u8, 184u8, 173u8, 224u8, 185u8, 128u8, 224u8, 184u8, 155u8, 224u8, 184u8,
+                    181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 155u8, 224u8, 184u8, 143u8, 224u8,
+                    184u8, 180u8, 224u8, 184u8, 151u8, 224u8, 184u8, 180u8, 224u8, 184u8, 153u8,
+                    224u8, 185u8, 128u8, 224u8, 184u8, 129u8, 224u8, 184u8, 163u8, 224u8, 184u8,
+                    129u8, 224u8, 184u8, 173u8, 224u8, 185u8, 128u8, 224u8, 184u8, 163u8, 224u8,
+                    184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 153u8, 224u8, 184u8, 155u8,
+                    224u8, 184u8, 143u8, 224u8, 184u8, 180u8, 224u8, 184u8, 151u8, 224u8, 184u8,
+                    180u8, 224u8, 184u8, 153u8, 224u8, 184u8, 174u8, 224u8, 184u8, 180u8, 224u8,
+                    184u8, 154u8, 224u8, 184u8, 163u8, 224u8, 184u8, 185u8, 224u8, 184u8, 155u8,
+                    224u8, 184u8, 143u8, 224u8, 184u8, 180u8, 224u8, 184u8, 151u8, 224u8, 184u8,
+                    180u8, 224u8, 184u8, 153u8, 224u8, 185u8, 129u8, 224u8, 184u8, 171u8, 224u8,
+                    185u8, 136u8, 224u8, 184u8, 135u8, 224u8, 184u8, 138u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 149u8, 224u8, 184u8, 180u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                    180u8, 224u8, 184u8, 153u8, 224u8, 185u8, 128u8, 224u8, 184u8, 148u8, 224u8,
+                    184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 155u8, 224u8, 184u8, 143u8,
+                    224u8, 184u8, 180u8, 224u8, 184u8, 151u8, 224u8, 184u8, 180u8, 224u8, 184u8,
+                    153u8, 224u8, 184u8, 173u8, 224u8, 184u8, 180u8, 224u8, 184u8, 170u8, 224u8,
+                    184u8, 165u8, 224u8, 184u8, 178u8, 224u8, 184u8, 161u8, 224u8, 184u8, 155u8,
+                    224u8, 184u8, 143u8, 224u8, 184u8, 180u8, 224u8, 184u8, 151u8, 224u8, 184u8,
+                    180u8, 224u8, 184u8, 153u8, 224u8, 184u8, 173u8, 224u8, 184u8, 180u8, 224u8,
+                    184u8, 170u8, 224u8, 184u8, 165u8, 224u8, 184u8, 178u8, 224u8, 184u8, 161u8,
+                    224u8, 184u8, 139u8, 224u8, 184u8, 181u8, 224u8, 184u8, 167u8, 224u8, 184u8,
+                    180u8, 224u8, 184u8, 165u8, 224u8, 184u8, 155u8, 224u8, 184u8, 143u8, 224u8,
+                    184u8, 180u8, 224u8, 184u8, 151u8, 224u8, 184u8, 180u8, 224u8, 184u8, 153u8,
+                    224u8, 184u8, 173u8, 224u8, 184u8, 180u8, 224u8, 184u8, 170u8, 224u8, 184u8,
+                    165u8, 224u8, 184u8, 178u8, 224u8, 184u8, 161u8, 32u8, 40u8, 224u8, 184u8,
+                    139u8, 224u8, 184u8, 178u8, 224u8, 184u8, 173u8, 224u8, 184u8, 184u8, 224u8,
+                    184u8, 148u8, 224u8, 184u8, 181u8, 224u8, 184u8, 173u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 163u8, 224u8, 184u8, 176u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    154u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 32u8, 224u8, 185u8, 129u8,
+                    224u8, 184u8, 154u8, 224u8, 184u8, 154u8, 224u8, 184u8, 158u8, 224u8, 184u8,
+                    163u8, 224u8, 184u8, 176u8, 224u8, 184u8, 136u8, 224u8, 184u8, 177u8, 224u8,
+                    184u8, 153u8, 224u8, 184u8, 151u8, 224u8, 184u8, 163u8, 224u8, 185u8, 140u8,
+                    224u8, 185u8, 128u8, 224u8, 184u8, 170u8, 224u8, 184u8, 181u8, 224u8, 185u8,
+                    137u8, 224u8, 184u8, 162u8, 224u8, 184u8, 167u8, 41u8, 224u8, 184u8, 155u8,
+                    224u8, 184u8, 143u8, 224u8, 184u8, 180u8, 224u8, 184u8, 151u8, 224u8, 184u8,
+                    180u8, 224u8, 184u8, 153u8, 224u8, 184u8, 173u8, 224u8, 184u8, 180u8, 224u8,
+                    184u8, 170u8, 224u8, 184u8, 165u8, 224u8, 184u8, 178u8, 224u8, 184u8, 161u8,
+                    32u8, 40u8, 224u8, 185u8, 129u8, 224u8, 184u8, 154u8, 224u8, 184u8, 154u8,
+                    224u8, 184u8, 149u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 135u8, 32u8, 224u8, 184u8, 170u8, 224u8, 184u8, 161u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 162u8, 224u8, 184u8, 148u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 163u8, 224u8, 184u8, 178u8, 224u8, 184u8, 168u8, 224u8,
+                    184u8, 178u8, 224u8, 184u8, 170u8, 224u8, 184u8, 149u8, 224u8, 184u8, 163u8,
+                    224u8, 185u8, 140u8, 41u8, 224u8, 184u8, 155u8, 224u8, 184u8, 143u8, 224u8,
+                    184u8, 180u8, 224u8, 184u8, 151u8, 224u8, 184u8, 180u8, 224u8, 184u8, 153u8,
+                    224u8, 184u8, 173u8, 224u8, 184u8, 180u8, 224u8, 184u8, 170u8, 224u8, 184u8,
+                    165u8, 224u8, 184u8, 178u8, 224u8, 184u8, 161u8, 32u8, 40u8, 224u8, 184u8,
+                    173u8, 224u8, 184u8, 184u8, 224u8, 184u8, 161u8, 224u8, 184u8, 161u8, 224u8,
+                    185u8, 140u8, 224u8, 184u8, 173u8, 224u8, 184u8, 177u8, 224u8, 184u8, 165u8,
+                    224u8, 184u8, 129u8, 224u8, 184u8, 184u8, 224u8, 184u8, 163u8, 224u8, 184u8,
+                    178u8, 41u8, 224u8, 184u8, 155u8, 224u8, 184u8, 143u8, 224u8, 184u8, 180u8,
+                    224u8, 184u8, 151u8, 224u8, 184u8, 180u8, 224u8, 184u8, 153u8, 32u8, 73u8,
+                    83u8, 79u8, 45u8, 56u8, 54u8, 48u8, 49u8, 224u8, 184u8, 155u8, 224u8, 184u8,
+                    143u8, 224u8, 184u8, 180u8, 224u8, 184u8, 151u8, 224u8, 184u8, 180u8, 224u8,
+                    184u8, 153u8, 224u8, 184u8, 141u8, 224u8, 184u8, 181u8, 224u8, 185u8, 136u8,
+                    224u8, 184u8, 155u8, 224u8, 184u8, 184u8, 224u8, 185u8, 136u8, 224u8, 184u8,
+                    153u8, 224u8, 184u8, 155u8, 224u8, 184u8, 143u8, 224u8, 184u8, 180u8, 224u8,
+                    184u8, 151u8, 224u8, 184u8, 180u8, 224u8, 184u8, 153u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 155u8, 224u8, 184u8, 173u8, 224u8, 184u8, 163u8, 224u8, 185u8,
+                    140u8, 224u8, 185u8, 128u8, 224u8, 184u8, 138u8, 224u8, 184u8, 181u8, 224u8,
+                    184u8, 162u8, 224u8, 184u8, 155u8, 224u8, 184u8, 143u8, 224u8, 184u8, 180u8,
+                    224u8, 184u8, 151u8, 224u8, 184u8, 180u8, 224u8, 184u8, 153u8, 224u8, 185u8,
+                    132u8, 224u8, 184u8, 149u8, 224u8, 185u8, 137u8, 224u8, 184u8, 171u8, 224u8,
+                    184u8, 167u8, 224u8, 184u8, 177u8, 224u8, 184u8, 153u8, 224u8, 184u8, 163u8,
+                    224u8, 184u8, 185u8, 224u8, 184u8, 155u8, 224u8, 185u8, 129u8, 224u8, 184u8,
+                    154u8, 224u8, 184u8, 154u8, 224u8, 184u8, 170u8, 224u8, 184u8, 129u8, 224u8,
+                    184u8, 184u8, 224u8, 184u8, 165u8, 224u8, 185u8, 128u8, 224u8, 184u8, 135u8,
+                    224u8, 184u8, 180u8, 224u8, 184u8, 153u8, 224u8, 184u8, 154u8, 224u8, 184u8,
+                    177u8, 224u8, 184u8, 141u8, 224u8, 184u8, 138u8, 224u8, 184u8, 181u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 185u8, 224u8, 184u8, 155u8, 224u8, 185u8, 129u8,
+                    224u8, 184u8, 154u8, 224u8, 184u8, 154u8, 224u8, 184u8, 170u8, 224u8, 184u8,
+                    129u8, 224u8, 184u8, 184u8, 224u8, 184u8, 165u8, 224u8, 185u8, 128u8, 224u8,
+                    184u8, 135u8, 224u8, 184u8, 180u8, 224u8, 184u8, 153u8, 224u8, 184u8, 161u8,
+                    224u8, 184u8, 178u8, 224u8, 184u8, 149u8, 224u8, 184u8, 163u8, 224u8, 184u8,
+                    144u8, 224u8, 184u8, 178u8, 224u8, 184u8, 153u8, 224u8, 184u8, 165u8, 224u8,
+                    184u8, 179u8, 224u8, 184u8, 148u8, 224u8, 184u8, 177u8, 224u8, 184u8, 154u8,
+                    224u8, 184u8, 129u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 184u8,
+                    136u8, 224u8, 184u8, 177u8, 224u8, 184u8, 148u8, 224u8, 185u8, 128u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 135u8,
+                    224u8, 184u8, 149u8, 224u8, 184u8, 178u8, 224u8, 184u8, 161u8, 224u8, 184u8,
+                    173u8, 224u8, 184u8, 177u8, 224u8, 184u8, 129u8, 224u8, 184u8, 169u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 136u8, 224u8, 184u8, 181u8, 224u8, 184u8, 153u8,
+                    224u8, 184u8, 148u8, 224u8, 184u8, 177u8, 224u8, 185u8, 137u8, 224u8, 184u8,
+                    135u8, 224u8, 185u8, 128u8, 224u8, 184u8, 148u8, 224u8, 184u8, 180u8, 224u8,
+                    184u8, 161u8, 224u8, 184u8, 165u8, 224u8, 184u8, 179u8, 224u8, 184u8, 148u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 154u8, 224u8, 184u8, 129u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 163u8, 224u8, 184u8, 136u8, 224u8, 184u8, 177u8, 224u8,
+                    184u8, 148u8, 224u8, 185u8, 128u8, 224u8, 184u8, 163u8, 224u8, 184u8, 181u8,
+                    224u8, 184u8, 162u8, 224u8, 184u8, 135u8, 224u8, 184u8, 129u8, 224u8, 185u8,
+                    136u8, 224u8, 184u8, 173u8, 224u8, 184u8, 153u8, 224u8, 184u8, 171u8, 224u8,
+                    184u8, 153u8, 224u8, 185u8, 137u8, 224u8, 184u8, 178u8, 224u8, 184u8, 153u8,
+                    224u8, 184u8, 181u8, 224u8, 185u8, 137u8, 32u8, 224u8, 184u8, 149u8, 224u8,
+                    184u8, 178u8, 224u8, 184u8, 161u8, 224u8, 184u8, 132u8, 224u8, 184u8, 167u8,
+                    224u8, 184u8, 178u8, 224u8, 184u8, 161u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    171u8, 224u8, 184u8, 161u8, 224u8, 184u8, 178u8, 224u8, 184u8, 176u8, 224u8,
+                    184u8, 170u8, 224u8, 184u8, 161u8, 224u8, 184u8, 165u8, 224u8, 184u8, 179u8,
+                    224u8, 184u8, 148u8, 224u8, 184u8, 177u8, 224u8, 184u8, 154u8, 224u8, 184u8,
+                    129u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 184u8, 136u8, 224u8,
+                    184u8, 177u8, 224u8, 184u8, 148u8, 224u8, 185u8, 128u8, 224u8, 184u8, 163u8,
+                    224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 135u8, 224u8, 184u8,
+                    149u8, 224u8, 184u8, 178u8, 224u8, 184u8, 161u8, 224u8, 184u8, 158u8, 224u8,
+                    184u8, 136u8, 224u8, 184u8, 153u8, 224u8, 184u8, 178u8, 224u8, 184u8, 153u8,
+                    224u8, 184u8, 184u8, 224u8, 184u8, 129u8, 224u8, 184u8, 163u8, 224u8, 184u8,
+                    161u8, 224u8, 184u8, 165u8, 224u8, 184u8, 179u8, 224u8, 184u8, 148u8, 224u8,
+                    184u8, 177u8, 224u8, 184u8, 154u8, 224u8, 184u8, 129u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 163u8, 224u8, 184u8, 136u8, 224u8, 184u8, 177u8, 224u8, 184u8,
+                    148u8, 224u8, 185u8, 128u8, 224u8, 184u8, 163u8, 224u8, 184u8, 181u8, 224u8,
+                    184u8, 162u8, 224u8, 184u8, 135u8, 224u8, 184u8, 149u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 161u8, 32u8, 85u8, 110u8, 105u8, 99u8, 111u8, 100u8, 101u8, 32u8,
+                    224u8, 185u8, 128u8, 224u8, 184u8, 163u8, 224u8, 184u8, 180u8, 224u8, 185u8,
+                    136u8, 224u8, 184u8, 161u8, 224u8, 184u8, 149u8, 224u8, 185u8, 137u8, 224u8,
+                    184u8, 153u8, 224u8, 184u8, 129u8, 224u8, 184u8, 142u8, 224u8, 184u8, 129u8,
+                    224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    163u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 135u8, 224u8,
+                    184u8, 165u8, 224u8, 184u8, 179u8, 224u8, 184u8, 148u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 154u8, 224u8, 184u8, 149u8, 224u8, 184u8, 178u8, 224u8, 184u8,
+                    161u8, 224u8, 185u8, 129u8, 224u8, 184u8, 154u8, 224u8, 184u8, 154u8, 224u8,
+                    184u8, 162u8, 224u8, 184u8, 184u8, 224u8, 185u8, 130u8, 224u8, 184u8, 163u8,
+                    224u8, 184u8, 155u8, 224u8, 184u8, 165u8, 224u8, 184u8, 179u8, 224u8, 184u8,
+                    148u8, 224u8, 184u8, 177u8, 224u8, 184u8, 154u8, 224u8, 184u8, 129u8, 224u8,
+                    184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 184u8, 136u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 148u8, 224u8, 185u8, 128u8, 224u8, 184u8, 163u8, 224u8, 184u8,
+                    181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 135u8, 224u8, 184u8, 149u8, 224u8,
+                    184u8, 178u8, 224u8, 184u8, 161u8, 224u8, 184u8, 173u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 129u8, 224u8, 184u8, 169u8, 224u8, 184u8, 163u8, 224u8, 184u8,
+                    136u8, 224u8, 184u8, 181u8, 224u8, 184u8, 153u8, 224u8, 184u8, 155u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 176u8, 224u8, 184u8, 162u8, 224u8, 184u8, 184u8,
+                    224u8, 184u8, 129u8, 224u8, 184u8, 149u8, 224u8, 185u8, 140u8, 224u8, 184u8,
+                    165u8, 224u8, 184u8, 179u8, 224u8, 184u8, 148u8, 224u8, 184u8, 177u8, 224u8,
+                    184u8, 154u8, 224u8, 184u8, 129u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8,
+                    224u8, 184u8, 136u8, 224u8, 184u8, 177u8, 224u8, 184u8, 148u8, 224u8, 185u8,
+                    128u8, 224u8, 184u8, 163u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8,
+                    184u8, 135u8, 224u8, 184u8, 149u8, 224u8, 184u8, 178u8, 224u8, 184u8, 161u8,
+                    224u8, 184u8, 170u8, 224u8, 184u8, 161u8, 224u8, 184u8, 184u8, 224u8, 184u8,
+                    148u8, 224u8, 185u8, 130u8, 224u8, 184u8, 151u8, 224u8, 184u8, 163u8, 224u8,
+                    184u8, 168u8, 224u8, 184u8, 177u8, 224u8, 184u8, 158u8, 224u8, 184u8, 151u8,
+                    224u8, 185u8, 140u8, 224u8, 184u8, 165u8, 224u8, 184u8, 179u8, 224u8, 184u8,
+                    148u8, 224u8, 184u8, 177u8, 224u8, 184u8, 154u8, 224u8, 184u8, 129u8, 224u8,
+                    184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 184u8, 136u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 148u8, 224u8, 185u8, 128u8, 224u8, 184u8, 163u8, 224u8, 184u8,
+                    181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 135u8, 224u8, 184u8, 149u8, 224u8,
+                    184u8, 178u8, 224u8, 184u8, 161u8, 224u8, 184u8, 129u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 163u8, 224u8, 184u8, 173u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                    129u8, 224u8, 185u8, 128u8, 224u8, 184u8, 170u8, 224u8, 184u8, 181u8, 224u8,
+                    184u8, 162u8, 224u8, 184u8, 135u8, 224u8, 184u8, 165u8, 224u8, 184u8, 179u8,
+                    224u8, 184u8, 148u8, 224u8, 184u8, 177u8, 224u8, 184u8, 154u8, 224u8, 184u8,
+                    129u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 184u8, 136u8, 224u8,
+                    184u8, 177u8, 224u8, 184u8, 148u8, 224u8, 185u8, 128u8, 224u8, 184u8, 163u8,
+                    224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 135u8, 224u8, 184u8,
+                    149u8, 224u8, 184u8, 178u8, 224u8, 184u8, 161u8, 224u8, 184u8, 129u8, 224u8,
+                    184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 184u8, 150u8, 224u8, 184u8, 173u8,
+                    224u8, 184u8, 148u8, 224u8, 185u8, 128u8, 224u8, 184u8, 170u8, 224u8, 184u8,
+                    181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 135u8, 224u8, 184u8, 160u8, 224u8,
+                    184u8, 178u8, 224u8, 184u8, 169u8, 224u8, 184u8, 178u8, 224u8, 184u8, 136u8,
+                    224u8, 184u8, 181u8, 224u8, 184u8, 153u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    179u8, 224u8, 184u8, 148u8, 224u8, 184u8, 177u8, 224u8, 184u8, 154u8, 224u8,
+                    184u8, 129u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 184u8, 136u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 148u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    163u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 135u8, 224u8,
+                    184u8, 149u8, 224u8, 184u8, 178u8, 224u8, 184u8, 161u8, 224u8, 184u8, 165u8,
+                    224u8, 184u8, 179u8, 224u8, 184u8, 148u8, 224u8, 184u8, 177u8, 224u8, 184u8,
+                    154u8, 224u8, 184u8, 165u8, 224u8, 185u8, 136u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 170u8, 224u8, 184u8, 184u8, 224u8, 184u8, 148u8, 224u8, 184u8, 129u8,
+                    224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 184u8, 132u8, 224u8, 185u8,
+                    137u8, 224u8, 184u8, 153u8, 224u8, 184u8, 171u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 151u8, 224u8, 184u8, 177u8, 224u8, 185u8, 136u8, 224u8, 184u8, 167u8,
+                    224u8, 185u8, 132u8, 224u8, 184u8, 155u8, 224u8, 184u8, 132u8, 224u8, 185u8,
+                    137u8, 224u8, 184u8, 153u8, 224u8, 184u8, 171u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 149u8, 224u8, 184u8, 178u8, 224u8, 184u8, 161u8, 224u8, 184u8, 158u8,
+                    224u8, 184u8, 162u8, 224u8, 184u8, 177u8, 224u8, 184u8, 141u8, 224u8, 184u8,
+                    138u8, 224u8, 184u8, 153u8, 224u8, 184u8, 176u8, 224u8, 184u8, 130u8, 224u8,
+                    184u8, 182u8, 224u8, 185u8, 137u8, 224u8, 184u8, 153u8, 224u8, 184u8, 149u8,
+                    224u8, 185u8, 137u8, 224u8, 184u8, 153u8, 224u8, 184u8, 151u8, 224u8, 184u8,
+                    181u8, 224u8, 185u8, 136u8, 224u8, 185u8, 128u8, 224u8, 184u8, 155u8, 224u8,
+                    185u8, 135u8, 224u8, 184u8, 153u8, 224u8, 184u8, 174u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 153u8, 224u8, 184u8, 129u8, 224u8, 184u8, 182u8, 224u8, 184u8,
+                    165u8, 224u8, 184u8, 165u8, 224u8, 184u8, 179u8, 224u8, 184u8, 148u8, 224u8,
+                    184u8, 177u8, 224u8, 184u8, 154u8, 224u8, 184u8, 129u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 163u8, 224u8, 184u8, 136u8, 224u8, 184u8, 177u8, 224u8, 184u8,
+                    148u8, 224u8, 185u8, 128u8, 224u8, 184u8, 163u8, 224u8, 184u8, 181u8, 224u8,
+                    184u8, 162u8, 224u8, 184u8, 135u8, 224u8, 185u8, 129u8, 224u8, 184u8, 154u8,
+                    224u8, 184u8, 154u8, 224u8, 184u8, 161u8, 224u8, 184u8, 178u8, 224u8, 184u8,
+                    149u8, 224u8, 184u8, 163u8, 224u8, 184u8, 144u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 153u8, 224u8, 184u8, 165u8, 224u8, 184u8, 179u8, 224u8, 184u8, 148u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 154u8, 224u8, 184u8, 129u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 163u8, 224u8, 184u8, 136u8, 224u8, 184u8, 177u8, 224u8,
+                    184u8, 148u8, 224u8, 185u8, 128u8, 224u8, 184u8, 163u8, 224u8, 184u8, 181u8,
+                    224u8, 184u8, 162u8, 224u8, 184u8, 135u8, 224u8, 184u8, 149u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 161u8, 224u8, 184u8, 129u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 165u8, 224u8, 184u8, 178u8, 224u8, 184u8, 129u8,
+                    224u8, 185u8, 128u8, 224u8, 184u8, 170u8, 224u8, 185u8, 137u8, 224u8, 184u8,
+                    153u8, 224u8, 184u8, 165u8, 224u8, 184u8, 179u8, 224u8, 184u8, 148u8, 224u8,
+                    184u8, 177u8, 224u8, 184u8, 154u8, 224u8, 184u8, 129u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 163u8, 224u8, 184u8, 136u8, 224u8, 184u8, 177u8, 224u8, 184u8,
+                    148u8, 224u8, 185u8, 128u8, 224u8, 184u8, 163u8, 224u8, 184u8, 181u8, 224u8,
+                    184u8, 162u8, 224u8, 184u8, 135u8, 224u8, 184u8, 149u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 161u8, 224u8, 185u8, 129u8, 224u8, 184u8, 154u8, 224u8, 184u8,
+                    154u8, 224u8, 184u8, 148u8, 224u8, 184u8, 177u8, 224u8, 185u8, 137u8, 224u8,
+                    184u8, 135u8, 224u8, 185u8, 128u8, 224u8, 184u8, 148u8, 224u8, 184u8, 180u8,
+                    224u8, 184u8, 161u8, 224u8, 184u8, 165u8, 224u8, 184u8, 179u8, 224u8, 184u8,
+                    148u8, 224u8, 184u8, 177u8, 224u8, 184u8, 154u8, 224u8, 184u8, 129u8, 224u8,
+                    184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 184u8, 136u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 148u8, 224u8, 185u8, 128u8, 224u8, 184u8, 163u8, 224u8, 184u8,
+                    181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 135u8, 224u8, 184u8, 149u8, 224u8,
+                    184u8, 178u8, 224u8, 184u8, 161u8, 224u8, 184u8, 136u8, 224u8, 184u8, 179u8,
+                    224u8, 184u8, 153u8, 224u8, 184u8, 167u8, 224u8, 184u8, 153u8, 224u8, 184u8,
+                    130u8, 224u8, 184u8, 181u8, 224u8, 184u8, 148u8, 224u8, 184u8, 165u8, 224u8,
+                    184u8, 179u8, 224u8, 184u8, 148u8, 224u8, 184u8, 177u8, 224u8, 184u8, 154u8,
+                    224u8, 184u8, 129u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 184u8,
+                    136u8, 224u8, 184u8, 177u8, 224u8, 184u8, 148u8, 224u8, 185u8, 128u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 135u8,
+                    224u8, 184u8, 149u8, 224u8, 184u8, 178u8, 224u8, 184u8, 161u8, 224u8, 184u8,
+                    129u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 184u8, 170u8, 224u8,
+                    184u8, 176u8, 224u8, 184u8, 129u8, 224u8, 184u8, 148u8, 224u8, 185u8, 129u8,
+                    224u8, 184u8, 154u8, 224u8, 184u8, 154u8, 224u8, 184u8, 136u8, 224u8, 184u8,
+                    185u8, 224u8, 185u8, 137u8, 224u8, 184u8, 173u8, 224u8, 184u8, 180u8, 224u8,
+                    184u8, 153u8, 224u8, 184u8, 163u8, 224u8, 184u8, 176u8, 224u8, 184u8, 154u8,
+                    224u8, 184u8, 154u8, 32u8, 49u8, 50u8, 32u8, 224u8, 184u8, 138u8, 224u8, 184u8,
+                    177u8, 224u8, 185u8, 136u8, 224u8, 184u8, 167u8, 224u8, 185u8, 130u8, 224u8,
+                    184u8, 161u8, 224u8, 184u8, 135u8, 32u8, 40u8, 48u8, 226u8, 128u8, 147u8, 49u8,
+                    49u8, 41u8, 224u8, 184u8, 163u8, 224u8, 184u8, 176u8, 224u8, 184u8, 154u8,
+                    224u8, 184u8, 154u8, 32u8, 49u8, 50u8, 32u8, 224u8, 184u8, 138u8, 224u8, 184u8,
+                    177u8, 224u8, 185u8, 136u8, 224u8, 184u8, 167u8, 224u8, 185u8, 130u8, 224u8,
+                    184u8, 161u8, 224u8, 184u8, 135u8, 32u8, 40u8, 49u8, 226u8, 128u8, 147u8, 49u8,
+                    50u8, 41u8, 224u8, 184u8, 163u8, 224u8, 184u8, 176u8, 224u8, 184u8, 154u8,
+                    224u8, 184u8, 154u8, 32u8, 50u8, 52u8, 32u8, 224u8, 184u8, 138u8, 224u8, 184u8,
+                    177u8, 224u8, 185u8, 136u8, 224u8, 184u8, 167u8, 224u8, 185u8, 130u8, 224u8,
+                    184u8, 161u8, 224u8, 184u8, 135u8, 32u8, 40u8, 48u8, 226u8, 128u8, 147u8, 50u8,
+                    51u8, 41u8, 224u8, 184u8, 163u8, 224u8, 184u8, 176u8, 224u8, 184u8, 154u8,
+                    224u8, 184u8, 154u8, 32u8, 50u8, 52u8, 32u8, 224u8, 184u8, 138u8, 224u8, 184u8,
+                    177u8, 224u8, 185u8, 136u8, 224u8, 184u8, 167u8, 224u8, 185u8, 130u8, 224u8,
+                    184u8, 161u8, 224u8, 184u8, 135u8, 32u8, 40u8, 49u8, 226u8, 128u8, 147u8, 50u8,
+                    52u8, 41u8, 224u8, 184u8, 136u8, 224u8, 184u8, 177u8, 224u8, 184u8, 148u8,
+                    224u8, 185u8, 128u8, 224u8, 184u8, 163u8, 224u8, 184u8, 181u8, 224u8, 184u8,
+                    162u8, 224u8, 184u8, 135u8, 224u8, 184u8, 170u8, 224u8, 184u8, 177u8, 224u8,
+                    184u8, 141u8, 224u8, 184u8, 165u8, 224u8, 184u8, 177u8, 224u8, 184u8, 129u8,
+                    224u8, 184u8, 169u8, 224u8, 184u8, 147u8, 224u8, 185u8, 140u8, 224u8, 184u8,
+                    136u8, 224u8, 184u8, 177u8, 224u8, 184u8, 148u8, 224u8, 185u8, 128u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 135u8,
+                    224u8, 184u8, 170u8, 224u8, 184u8, 177u8, 224u8, 184u8, 141u8, 224u8, 184u8,
+                    165u8, 224u8, 184u8, 177u8, 224u8, 184u8, 129u8, 224u8, 184u8, 169u8, 224u8,
+                    184u8, 147u8, 224u8, 185u8, 140u8, 224u8, 184u8, 151u8, 224u8, 184u8, 181u8,
+                    224u8, 185u8, 136u8, 224u8, 184u8, 165u8, 224u8, 184u8, 176u8, 224u8, 185u8,
+                    132u8, 224u8, 184u8, 167u8, 224u8, 185u8, 137u8, 224u8, 184u8, 136u8, 224u8,
+                    184u8, 177u8, 224u8, 184u8, 148u8, 224u8, 185u8, 128u8, 224u8, 184u8, 163u8,
+                    224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 135u8, 224u8, 185u8,
+                    128u8, 224u8, 184u8, 170u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8,
+                    184u8, 135u8, 224u8, 184u8, 171u8, 224u8, 184u8, 153u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 129u8, 224u8, 185u8, 128u8, 224u8, 184u8, 154u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 149u8, 224u8, 184u8, 178u8, 224u8, 184u8, 161u8, 224u8,
+                    184u8, 155u8, 224u8, 184u8, 129u8, 224u8, 184u8, 149u8, 224u8, 184u8, 180u8,
+                    224u8, 184u8, 136u8, 224u8, 184u8, 177u8, 224u8, 184u8, 148u8, 224u8, 185u8,
+                    128u8, 224u8, 184u8, 163u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8,
+                    184u8, 135u8, 224u8, 185u8, 128u8, 224u8, 184u8, 170u8, 224u8, 184u8, 181u8,
+                    224u8, 184u8, 162u8, 224u8, 184u8, 135u8, 224u8, 184u8, 171u8, 224u8, 184u8,
+                    153u8, 224u8, 184u8, 177u8, 224u8, 184u8, 129u8, 224u8, 185u8, 128u8, 224u8,
+                    184u8, 154u8, 224u8, 184u8, 178u8, 224u8, 185u8, 131u8, 224u8, 184u8, 153u8,
+                    224u8, 184u8, 165u8, 224u8, 184u8, 179u8, 224u8, 184u8, 148u8, 224u8, 184u8,
+                    177u8, 224u8, 184u8, 154u8, 224u8, 184u8, 150u8, 224u8, 184u8, 173u8, 224u8,
+                    184u8, 162u8, 224u8, 184u8, 171u8, 224u8, 184u8, 165u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 135u8, 224u8, 184u8, 136u8, 224u8, 184u8, 177u8, 224u8, 184u8,
+                    148u8, 224u8, 185u8, 128u8, 224u8, 184u8, 163u8, 224u8, 184u8, 181u8, 224u8,
+                    184u8, 162u8, 224u8, 184u8, 135u8, 224u8, 184u8, 149u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 161u8, 224u8, 184u8, 132u8, 224u8, 184u8, 167u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 161u8, 224u8, 184u8, 170u8, 224u8, 184u8, 179u8, 224u8,
+                    184u8, 132u8, 224u8, 184u8, 177u8, 224u8, 184u8, 141u8, 224u8, 184u8, 130u8,
+                    224u8, 184u8, 173u8, 224u8, 184u8, 135u8, 224u8, 184u8, 149u8, 224u8, 184u8,
+                    177u8, 224u8, 184u8, 167u8, 224u8, 184u8, 158u8, 224u8, 184u8, 180u8, 224u8,
+                    184u8, 161u8, 224u8, 184u8, 158u8, 224u8, 185u8, 140u8, 224u8, 184u8, 136u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 148u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    163u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 135u8, 224u8,
+                    184u8, 132u8, 224u8, 184u8, 167u8, 224u8, 184u8, 178u8, 224u8, 184u8, 161u8,
+                    224u8, 184u8, 170u8, 224u8, 184u8, 179u8, 224u8, 184u8, 132u8, 224u8, 184u8,
+                    177u8, 224u8, 184u8, 141u8, 224u8, 184u8, 130u8, 224u8, 184u8, 173u8, 224u8,
+                    184u8, 135u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8, 224u8, 184u8, 167u8,
+                    224u8, 184u8, 158u8, 224u8, 184u8, 180u8, 224u8, 184u8, 161u8, 224u8, 184u8,
+                    158u8, 224u8, 185u8, 140u8, 224u8, 184u8, 136u8, 224u8, 184u8, 177u8, 224u8,
+                    184u8, 148u8, 224u8, 185u8, 128u8, 224u8, 184u8, 163u8, 224u8, 184u8, 181u8,
+                    224u8, 184u8, 162u8, 224u8, 184u8, 135u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    179u8, 224u8, 184u8, 148u8, 224u8, 184u8, 177u8, 224u8, 184u8, 154u8, 224u8,
+                    184u8, 149u8, 224u8, 184u8, 178u8, 224u8, 184u8, 161u8, 224u8, 184u8, 149u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 184u8, 158u8, 224u8, 184u8,
+                    180u8, 224u8, 184u8, 161u8, 224u8, 184u8, 158u8, 224u8, 185u8, 140u8, 224u8,
+                    184u8, 155u8, 224u8, 184u8, 129u8, 224u8, 184u8, 149u8, 224u8, 184u8, 180u8,
+                    224u8, 184u8, 136u8, 224u8, 184u8, 177u8, 224u8, 184u8, 148u8, 224u8, 185u8,
+                    128u8, 224u8, 184u8, 163u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8,
+                    184u8, 135u8, 224u8, 184u8, 165u8, 224u8, 184u8, 179u8, 224u8, 184u8, 148u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 154u8, 224u8, 184u8, 149u8, 224u8, 184u8,
+                    177u8, 224u8, 184u8, 167u8, 224u8, 184u8, 158u8, 224u8, 184u8, 180u8, 224u8,
+                    184u8, 161u8, 224u8, 184u8, 158u8, 224u8, 185u8, 140u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 165u8, 224u8, 185u8, 135u8, 224u8, 184u8, 129u8, 224u8, 184u8,
+                    129u8, 224u8, 185u8, 136u8, 224u8, 184u8, 173u8, 224u8, 184u8, 153u8, 224u8,
+                    184u8, 136u8, 224u8, 184u8, 177u8, 224u8, 184u8, 148u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 163u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8,
+                    135u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8,
+                    184u8, 158u8, 224u8, 184u8, 180u8, 224u8, 184u8, 161u8, 224u8, 184u8, 158u8,
+                    224u8, 185u8, 140u8, 224u8, 185u8, 131u8, 224u8, 184u8, 171u8, 224u8, 184u8,
+                    141u8, 224u8, 185u8, 136u8, 224u8, 184u8, 129u8, 224u8, 185u8, 136u8, 224u8,
+                    184u8, 173u8, 224u8, 184u8, 153u8, 224u8, 184u8, 136u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 148u8, 224u8, 185u8, 128u8, 224u8, 184u8, 163u8, 224u8, 184u8,
+                    181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 135u8, 224u8, 185u8, 130u8, 224u8,
+                    184u8, 148u8, 224u8, 184u8, 162u8, 224u8, 185u8, 132u8, 224u8, 184u8, 161u8,
+                    224u8, 185u8, 136u8, 224u8, 184u8, 149u8, 224u8, 185u8, 137u8, 224u8, 184u8,
+                    173u8, 224u8, 184u8, 135u8, 224u8, 184u8, 151u8, 224u8, 184u8, 179u8, 224u8,
+                    185u8, 131u8, 224u8, 184u8, 171u8, 224u8, 185u8, 137u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 155u8, 224u8, 185u8, 135u8, 224u8, 184u8, 153u8, 224u8, 185u8,
+                    129u8, 224u8, 184u8, 154u8, 224u8, 184u8, 154u8, 224u8, 184u8, 155u8, 224u8,
+                    184u8, 129u8, 224u8, 184u8, 149u8, 224u8, 184u8, 180u8, 224u8, 184u8, 136u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 148u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    163u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 135u8, 224u8,
+                    184u8, 162u8, 224u8, 184u8, 185u8, 224u8, 184u8, 153u8, 224u8, 184u8, 180u8,
+                    224u8, 185u8, 130u8, 224u8, 184u8, 132u8, 224u8, 185u8, 137u8, 224u8, 184u8,
+                    148u8, 224u8, 185u8, 129u8, 224u8, 184u8, 154u8, 224u8, 184u8, 154u8, 224u8,
+                    184u8, 151u8, 224u8, 184u8, 181u8, 224u8, 185u8, 136u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 155u8, 224u8, 185u8, 135u8, 224u8, 184u8, 153u8, 224u8, 184u8,
+                    155u8, 224u8, 184u8, 129u8, 224u8, 184u8, 149u8, 224u8, 184u8, 180u8, 224u8,
+                    184u8, 136u8, 224u8, 184u8, 177u8, 224u8, 184u8, 148u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 163u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8,
+                    135u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8,
+                    185u8, 128u8, 224u8, 184u8, 165u8, 224u8, 184u8, 130u8, 224u8, 185u8, 129u8,
+                    224u8, 184u8, 162u8, 224u8, 184u8, 129u8, 224u8, 184u8, 136u8, 224u8, 184u8,
+                    177u8, 224u8, 184u8, 148u8, 224u8, 185u8, 128u8, 224u8, 184u8, 163u8, 224u8,
+                    184u8, 177u8, 224u8, 184u8, 162u8, 224u8, 184u8, 135u8, 224u8, 184u8, 149u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    165u8, 224u8, 184u8, 130u8, 224u8, 184u8, 149u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 161u8, 224u8, 184u8, 165u8, 224u8, 184u8, 179u8, 224u8, 184u8, 148u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 154u8, 224u8, 184u8, 149u8, 224u8, 184u8,
+                    177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8,
+                    184u8, 130u8, 224u8, 184u8, 136u8, 224u8, 184u8, 177u8, 224u8, 184u8, 148u8,
+                    224u8, 185u8, 128u8, 224u8, 184u8, 163u8, 224u8, 184u8, 181u8, 224u8, 184u8,
+                    162u8, 224u8, 184u8, 135u8, 224u8, 184u8, 151u8, 224u8, 184u8, 177u8, 224u8,
+                    185u8, 137u8, 224u8, 184u8, 135u8, 224u8, 184u8, 171u8, 224u8, 184u8, 161u8,
+                    224u8, 184u8, 148u8, 224u8, 184u8, 136u8, 224u8, 184u8, 177u8, 224u8, 184u8,
+                    148u8, 224u8, 185u8, 128u8, 224u8, 184u8, 163u8, 224u8, 184u8, 181u8, 224u8,
+                    184u8, 162u8, 224u8, 184u8, 135u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 167u8, 224u8, 184u8, 173u8, 224u8, 184u8, 177u8, 224u8, 184u8,
+                    129u8, 224u8, 184u8, 169u8, 224u8, 184u8, 163u8, 224u8, 184u8, 144u8, 224u8,
+                    184u8, 178u8, 224u8, 184u8, 153u8, 224u8, 185u8, 128u8, 224u8, 184u8, 151u8,
+                    224u8, 185u8, 136u8, 224u8, 184u8, 178u8, 224u8, 184u8, 153u8, 224u8, 184u8,
+                    177u8, 224u8, 185u8, 137u8, 224u8, 184u8, 153u8, 224u8, 184u8, 136u8, 224u8,
+                    184u8, 177u8, 224u8, 184u8, 148u8, 224u8, 185u8, 128u8, 224u8, 184u8, 163u8,
+                    224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 135u8, 224u8, 185u8,
+                    128u8, 224u8, 184u8, 170u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8,
+                    184u8, 135u8, 224u8, 184u8, 171u8, 224u8, 184u8, 153u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 129u8, 224u8, 185u8, 128u8, 224u8, 184u8, 154u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 136u8, 224u8, 184u8, 177u8, 224u8, 184u8, 148u8, 224u8,
+                    185u8, 128u8, 224u8, 184u8, 163u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8,
+                    224u8, 184u8, 135u8, 224u8, 185u8, 128u8, 224u8, 184u8, 170u8, 224u8, 184u8,
+                    181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 135u8, 224u8, 184u8, 171u8, 224u8,
+                    184u8, 153u8, 224u8, 184u8, 177u8, 224u8, 184u8, 129u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 154u8, 224u8, 184u8, 178u8, 47u8, 224u8, 184u8, 149u8, 224u8,
+                    184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 184u8, 158u8, 224u8, 184u8, 180u8,
+                    224u8, 184u8, 161u8, 224u8, 184u8, 158u8, 224u8, 185u8, 140u8, 47u8, 224u8,
+                    184u8, 132u8, 224u8, 184u8, 167u8, 224u8, 184u8, 178u8, 224u8, 184u8, 161u8,
+                    224u8, 184u8, 129u8, 224u8, 184u8, 167u8, 224u8, 185u8, 137u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 135u8, 224u8, 184u8, 136u8, 224u8, 184u8, 177u8, 224u8,
+                    184u8, 148u8, 224u8, 185u8, 128u8, 224u8, 184u8, 163u8, 224u8, 184u8, 181u8,
+                    224u8, 184u8, 162u8, 224u8, 184u8, 135u8, 224u8, 184u8, 149u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 161u8, 224u8, 185u8, 128u8, 224u8, 184u8, 170u8, 224u8,
+                    184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 135u8, 224u8, 184u8, 171u8,
+                    224u8, 184u8, 153u8, 224u8, 184u8, 177u8, 224u8, 184u8, 129u8, 224u8, 185u8,
+                    128u8, 224u8, 184u8, 154u8, 224u8, 184u8, 178u8, 47u8, 224u8, 184u8, 149u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 184u8, 158u8, 224u8, 184u8,
+                    180u8, 224u8, 184u8, 161u8, 224u8, 184u8, 158u8, 224u8, 185u8, 140u8, 47u8,
+                    224u8, 184u8, 132u8, 224u8, 184u8, 167u8, 224u8, 184u8, 178u8, 224u8, 184u8,
+                    161u8, 224u8, 184u8, 129u8, 224u8, 184u8, 167u8, 224u8, 185u8, 137u8, 224u8,
+                    184u8, 178u8, 224u8, 184u8, 135u8, 47u8, 224u8, 184u8, 149u8, 224u8, 184u8,
+                    177u8, 224u8, 184u8, 167u8, 224u8, 184u8, 173u8, 224u8, 184u8, 177u8, 224u8,
+                    184u8, 129u8, 224u8, 184u8, 169u8, 224u8, 184u8, 163u8, 224u8, 184u8, 132u8,
+                    224u8, 184u8, 176u8, 224u8, 184u8, 153u8, 224u8, 184u8, 176u8, 224u8, 184u8,
+                    163u8, 224u8, 184u8, 185u8, 224u8, 184u8, 155u8, 224u8, 185u8, 129u8, 224u8,
+                    184u8, 154u8, 224u8, 184u8, 154u8, 224u8, 184u8, 151u8, 224u8, 184u8, 181u8,
+                    224u8, 185u8, 136u8, 224u8, 184u8, 162u8, 224u8, 184u8, 183u8, 224u8, 184u8,
+                    148u8, 224u8, 184u8, 171u8, 224u8, 184u8, 162u8, 224u8, 184u8, 184u8, 224u8,
+                    185u8, 136u8, 224u8, 184u8, 153u8, 224u8, 184u8, 130u8, 224u8, 184u8, 173u8,
+                    224u8, 184u8, 135u8, 224u8, 184u8, 129u8, 224u8, 184u8, 178u8, 224u8, 184u8,
+                    163u8, 224u8, 184u8, 130u8, 224u8, 184u8, 182u8, 224u8, 185u8, 137u8, 224u8,
+                    184u8, 153u8, 224u8, 184u8, 154u8, 224u8, 184u8, 163u8, 224u8, 184u8, 163u8,
+                    224u8, 184u8, 151u8, 224u8, 184u8, 177u8, 224u8, 184u8, 148u8, 224u8, 185u8,
+                    131u8, 224u8, 184u8, 171u8, 224u8, 184u8, 161u8, 224u8, 185u8, 136u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 185u8, 224u8, 184u8, 155u8, 224u8, 185u8, 129u8,
+                    224u8, 184u8, 154u8, 224u8, 184u8, 154u8, 224u8, 184u8, 155u8, 224u8, 184u8,
+                    129u8, 224u8, 184u8, 149u8, 224u8, 184u8, 180u8, 224u8, 184u8, 130u8, 224u8,
+                    184u8, 173u8, 224u8, 184u8, 135u8, 224u8, 184u8, 129u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 163u8, 224u8, 184u8, 130u8, 224u8, 184u8, 182u8, 224u8, 185u8,
+                    137u8, 224u8, 184u8, 153u8, 224u8, 184u8, 154u8, 224u8, 184u8, 163u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 151u8, 224u8, 184u8, 177u8, 224u8, 184u8, 148u8,
+                    224u8, 185u8, 131u8, 224u8, 184u8, 171u8, 224u8, 184u8, 161u8, 224u8, 185u8,
+                    136u8, 224u8, 184u8, 163u8, 224u8, 184u8, 185u8, 224u8, 184u8, 155u8, 224u8,
+                    185u8, 129u8, 224u8, 184u8, 154u8, 224u8, 184u8, 154u8, 224u8, 184u8, 151u8,
+                    224u8, 184u8, 181u8, 224u8, 185u8, 136u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    132u8, 224u8, 184u8, 163u8, 224u8, 185u8, 136u8, 224u8, 184u8, 135u8, 224u8,
+                    184u8, 132u8, 224u8, 184u8, 163u8, 224u8, 184u8, 177u8, 224u8, 184u8, 148u8,
+                    224u8, 184u8, 130u8, 224u8, 184u8, 173u8, 224u8, 184u8, 135u8, 224u8, 184u8,
+                    129u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 184u8, 130u8, 224u8,
+                    184u8, 182u8, 224u8, 185u8, 137u8, 224u8, 184u8, 153u8, 224u8, 184u8, 154u8,
+                    224u8, 184u8, 163u8, 224u8, 184u8, 163u8, 224u8, 184u8, 151u8, 224u8, 184u8,
+                    177u8, 224u8, 184u8, 148u8, 224u8, 185u8, 131u8, 224u8, 184u8, 171u8, 224u8,
+                    184u8, 161u8, 224u8, 185u8, 136u8, 224u8, 184u8, 163u8, 224u8, 184u8, 176u8,
+                    224u8, 184u8, 154u8, 224u8, 184u8, 154u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    161u8, 224u8, 184u8, 149u8, 224u8, 184u8, 163u8, 224u8, 184u8, 180u8, 224u8,
+                    184u8, 129u8, 224u8, 184u8, 163u8, 224u8, 184u8, 176u8, 224u8, 184u8, 154u8,
+                    224u8, 184u8, 154u8, 224u8, 184u8, 129u8, 224u8, 184u8, 178u8, 224u8, 184u8,
+                    163u8, 224u8, 184u8, 167u8, 224u8, 184u8, 177u8, 224u8, 184u8, 148u8, 224u8,
+                    184u8, 173u8, 224u8, 184u8, 180u8, 224u8, 184u8, 161u8, 224u8, 184u8, 158u8,
+                    224u8, 184u8, 181u8, 224u8, 185u8, 128u8, 224u8, 184u8, 163u8, 224u8, 184u8,
+                    181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 165u8, 224u8, 184u8, 163u8, 224u8,
+                    184u8, 176u8, 224u8, 184u8, 154u8, 224u8, 184u8, 154u8, 224u8, 184u8, 129u8,
+                    224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 184u8, 167u8, 224u8, 184u8,
+                    177u8, 224u8, 184u8, 148u8, 224u8, 184u8, 173u8, 224u8, 185u8, 128u8, 224u8,
+                    184u8, 161u8, 224u8, 184u8, 163u8, 224u8, 184u8, 180u8, 224u8, 184u8, 129u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 153u8, 224u8, 184u8, 149u8, 224u8, 184u8,
+                    177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8,
+                    184u8, 130u8, 224u8, 184u8, 173u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8,
+                    224u8, 184u8, 154u8, 224u8, 184u8, 180u8, 224u8, 184u8, 129u8, 45u8, 224u8,
+                    184u8, 173u8, 224u8, 184u8, 180u8, 224u8, 184u8, 153u8, 224u8, 184u8, 148u8,
+                    224u8, 184u8, 180u8, 224u8, 184u8, 129u8, 224u8, 184u8, 149u8, 224u8, 184u8,
+                    177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8,
+                    184u8, 130u8, 224u8, 184u8, 173u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8,
+                    224u8, 184u8, 154u8, 224u8, 184u8, 180u8, 224u8, 184u8, 129u8, 45u8, 224u8,
+                    184u8, 173u8, 224u8, 184u8, 180u8, 224u8, 184u8, 153u8, 224u8, 184u8, 148u8,
+                    224u8, 184u8, 180u8, 224u8, 184u8, 129u8, 224u8, 185u8, 129u8, 224u8, 184u8,
+                    154u8, 224u8, 184u8, 154u8, 224u8, 184u8, 130u8, 224u8, 184u8, 162u8, 224u8,
+                    184u8, 178u8, 224u8, 184u8, 162u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    130u8, 224u8, 184u8, 173u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 224u8,
+                    185u8, 140u8, 224u8, 185u8, 128u8, 224u8, 184u8, 161u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 153u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8,
+                    149u8, 224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8,
+                    184u8, 165u8, 224u8, 184u8, 130u8, 224u8, 184u8, 173u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 163u8, 224u8, 185u8, 140u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    161u8, 224u8, 185u8, 128u8, 224u8, 184u8, 153u8, 224u8, 184u8, 181u8, 224u8,
+                    184u8, 162u8, 224u8, 184u8, 158u8, 224u8, 184u8, 180u8, 224u8, 184u8, 161u8,
+                    224u8, 184u8, 158u8, 224u8, 185u8, 140u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    165u8, 224u8, 185u8, 135u8, 224u8, 184u8, 129u8, 224u8, 184u8, 149u8, 224u8,
+                    184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8,
+                    224u8, 184u8, 130u8, 224u8, 184u8, 154u8, 224u8, 184u8, 178u8, 224u8, 184u8,
+                    171u8, 224u8, 184u8, 165u8, 224u8, 184u8, 181u8, 224u8, 184u8, 149u8, 224u8,
+                    184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8,
+                    224u8, 184u8, 130u8, 224u8, 184u8, 154u8, 224u8, 184u8, 177u8, 224u8, 184u8,
+                    135u8, 224u8, 184u8, 129u8, 224u8, 184u8, 165u8, 224u8, 184u8, 178u8, 224u8,
+                    185u8, 128u8, 224u8, 184u8, 151u8, 224u8, 184u8, 168u8, 224u8, 184u8, 149u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    165u8, 224u8, 184u8, 130u8, 224u8, 184u8, 158u8, 224u8, 184u8, 163u8, 224u8,
+                    184u8, 178u8, 224u8, 184u8, 171u8, 224u8, 184u8, 161u8, 224u8, 184u8, 181u8,
+                    224u8, 184u8, 149u8, 224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 185u8,
+                    128u8, 224u8, 184u8, 165u8, 224u8, 184u8, 130u8, 224u8, 184u8, 136u8, 224u8,
+                    184u8, 177u8, 224u8, 184u8, 129u8, 224u8, 184u8, 161u8, 224u8, 185u8, 136u8,
+                    224u8, 184u8, 178u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8, 224u8, 184u8,
+                    167u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8, 184u8, 130u8, 224u8,
+                    184u8, 138u8, 224u8, 184u8, 178u8, 224u8, 184u8, 161u8, 224u8, 184u8, 149u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    165u8, 224u8, 184u8, 130u8, 224u8, 185u8, 128u8, 224u8, 184u8, 151u8, 224u8,
+                    184u8, 167u8, 224u8, 184u8, 153u8, 224u8, 184u8, 178u8, 224u8, 184u8, 132u8,
+                    224u8, 184u8, 163u8, 224u8, 184u8, 181u8, 224u8, 184u8, 149u8, 224u8, 184u8,
+                    177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8,
+                    184u8, 130u8, 224u8, 185u8, 128u8, 224u8, 184u8, 173u8, 224u8, 184u8, 152u8,
+                    224u8, 184u8, 180u8, 224u8, 185u8, 130u8, 224u8, 184u8, 173u8, 224u8, 185u8,
+                    128u8, 224u8, 184u8, 155u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8,
+                    184u8, 149u8, 224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 165u8, 224u8, 184u8, 130u8, 224u8, 184u8, 151u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 135u8, 224u8, 184u8, 129u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 163u8, 224u8, 185u8, 128u8, 224u8, 184u8, 135u8, 224u8, 184u8, 180u8,
+                    224u8, 184u8, 153u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8, 224u8, 184u8,
+                    167u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8, 184u8, 130u8, 224u8,
+                    185u8, 129u8, 224u8, 184u8, 154u8, 224u8, 184u8, 154u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 149u8, 224u8, 185u8, 135u8, 224u8, 184u8, 161u8, 224u8, 184u8,
+                    130u8, 224u8, 184u8, 153u8, 224u8, 184u8, 178u8, 224u8, 184u8, 148u8, 224u8,
+                    184u8, 149u8, 224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 165u8, 224u8, 184u8, 130u8, 224u8, 184u8, 136u8, 224u8, 184u8,
+                    173u8, 224u8, 184u8, 163u8, 224u8, 185u8, 140u8, 224u8, 185u8, 128u8, 224u8,
+                    184u8, 136u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 149u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    165u8, 224u8, 184u8, 130u8, 224u8, 184u8, 129u8, 224u8, 184u8, 163u8, 224u8,
+                    184u8, 181u8, 224u8, 184u8, 129u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    130u8, 224u8, 184u8, 129u8, 224u8, 184u8, 163u8, 224u8, 184u8, 181u8, 224u8,
+                    184u8, 129u8, 224u8, 184u8, 158u8, 224u8, 184u8, 180u8, 224u8, 184u8, 161u8,
+                    224u8, 184u8, 158u8, 224u8, 185u8, 140u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    165u8, 224u8, 185u8, 135u8, 224u8, 184u8, 129u8, 224u8, 184u8, 149u8, 224u8,
+                    184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8,
+                    224u8, 184u8, 130u8, 224u8, 184u8, 132u8, 224u8, 184u8, 184u8, 224u8, 184u8,
+                    138u8, 224u8, 184u8, 163u8, 224u8, 184u8, 178u8, 224u8, 184u8, 149u8, 224u8,
+                    184u8, 149u8, 224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 165u8, 224u8, 184u8, 130u8, 224u8, 184u8, 129u8, 224u8, 184u8,
+                    185u8, 224u8, 184u8, 163u8, 224u8, 185u8, 140u8, 224u8, 184u8, 161u8, 224u8,
+                    184u8, 185u8, 224u8, 184u8, 132u8, 224u8, 184u8, 181u8, 224u8, 184u8, 149u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    165u8, 224u8, 184u8, 130u8, 224u8, 184u8, 151u8, 224u8, 184u8, 168u8, 224u8,
+                    184u8, 153u8, 224u8, 184u8, 180u8, 224u8, 184u8, 162u8, 224u8, 184u8, 161u8,
+                    224u8, 184u8, 136u8, 224u8, 184u8, 181u8, 224u8, 184u8, 153u8, 224u8, 184u8,
+                    149u8, 224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8,
+                    184u8, 165u8, 224u8, 184u8, 130u8, 224u8, 184u8, 136u8, 224u8, 184u8, 181u8,
+                    224u8, 184u8, 153u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8, 224u8, 184u8,
+                    167u8, 224u8, 184u8, 162u8, 224u8, 185u8, 136u8, 224u8, 184u8, 173u8, 224u8,
+                    184u8, 149u8, 224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 165u8, 224u8, 184u8, 130u8, 224u8, 184u8, 151u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 135u8, 224u8, 184u8, 129u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 163u8, 224u8, 185u8, 128u8, 224u8, 184u8, 135u8, 224u8, 184u8, 180u8,
+                    224u8, 184u8, 153u8, 224u8, 185u8, 129u8, 224u8, 184u8, 154u8, 224u8, 184u8,
+                    154u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8,
+                    184u8, 162u8, 224u8, 185u8, 136u8, 224u8, 184u8, 173u8, 224u8, 184u8, 130u8,
+                    224u8, 184u8, 173u8, 224u8, 184u8, 135u8, 224u8, 184u8, 136u8, 224u8, 184u8,
+                    181u8, 224u8, 184u8, 153u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8, 224u8,
+                    184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8, 184u8, 130u8,
+                    224u8, 184u8, 136u8, 224u8, 184u8, 181u8, 224u8, 184u8, 153u8, 224u8, 184u8,
+                    149u8, 224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8,
+                    184u8, 149u8, 224u8, 185u8, 135u8, 224u8, 184u8, 161u8, 224u8, 184u8, 149u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    165u8, 224u8, 184u8, 130u8, 224u8, 184u8, 151u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 135u8, 224u8, 184u8, 129u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8,
+                    224u8, 185u8, 128u8, 224u8, 184u8, 135u8, 224u8, 184u8, 180u8, 224u8, 184u8,
+                    153u8, 224u8, 185u8, 129u8, 224u8, 184u8, 154u8, 224u8, 184u8, 154u8, 224u8,
+                    184u8, 149u8, 224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 149u8, 224u8, 185u8, 135u8, 224u8, 184u8, 161u8, 224u8, 184u8,
+                    130u8, 224u8, 184u8, 173u8, 224u8, 184u8, 135u8, 224u8, 184u8, 136u8, 224u8,
+                    184u8, 181u8, 224u8, 184u8, 153u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    130u8, 224u8, 184u8, 174u8, 224u8, 184u8, 180u8, 224u8, 184u8, 154u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 185u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    130u8, 224u8, 184u8, 138u8, 224u8, 184u8, 167u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 149u8, 224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 165u8, 224u8, 184u8, 130u8, 224u8, 184u8, 141u8, 224u8, 184u8,
+                    181u8, 224u8, 185u8, 136u8, 224u8, 184u8, 155u8, 224u8, 184u8, 184u8, 224u8,
+                    185u8, 136u8, 224u8, 184u8, 153u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    130u8, 224u8, 184u8, 151u8, 224u8, 184u8, 178u8, 224u8, 184u8, 135u8, 224u8,
+                    184u8, 129u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 135u8, 224u8, 184u8, 180u8, 224u8, 184u8, 153u8, 224u8, 184u8,
+                    130u8, 224u8, 184u8, 173u8, 224u8, 184u8, 135u8, 224u8, 184u8, 141u8, 224u8,
+                    184u8, 181u8, 224u8, 185u8, 136u8, 224u8, 184u8, 155u8, 224u8, 184u8, 184u8,
+                    224u8, 185u8, 136u8, 224u8, 184u8, 153u8, 224u8, 184u8, 149u8, 224u8, 184u8,
+                    177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8,
+                    184u8, 130u8, 224u8, 184u8, 132u8, 224u8, 184u8, 162u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 171u8, 224u8, 185u8, 140u8, 224u8, 184u8, 149u8, 224u8, 184u8,
+                    177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8,
+                    184u8, 130u8, 224u8, 185u8, 128u8, 224u8, 184u8, 130u8, 224u8, 184u8, 161u8,
+                    224u8, 184u8, 163u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8, 224u8, 184u8,
+                    167u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8, 184u8, 130u8, 224u8,
+                    184u8, 129u8, 224u8, 184u8, 177u8, 224u8, 184u8, 153u8, 224u8, 184u8, 153u8,
+                    224u8, 184u8, 178u8, 224u8, 184u8, 148u8, 224u8, 184u8, 178u8, 224u8, 184u8,
+                    149u8, 224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8,
+                    184u8, 165u8, 224u8, 184u8, 130u8, 224u8, 185u8, 132u8, 224u8, 184u8, 151u8,
+                    224u8, 184u8, 151u8, 224u8, 184u8, 177u8, 224u8, 184u8, 161u8, 224u8, 185u8,
+                    130u8, 224u8, 184u8, 174u8, 224u8, 184u8, 163u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 149u8, 224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 165u8, 224u8, 184u8, 130u8, 224u8, 185u8, 132u8, 224u8, 184u8,
+                    151u8, 224u8, 184u8, 151u8, 224u8, 184u8, 177u8, 224u8, 184u8, 161u8, 224u8,
+                    184u8, 151u8, 224u8, 184u8, 177u8, 224u8, 184u8, 161u8, 224u8, 184u8, 149u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    165u8, 224u8, 184u8, 130u8, 224u8, 184u8, 165u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 167u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8, 224u8, 184u8, 167u8,
+                    224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8, 184u8, 130u8, 224u8, 184u8,
+                    173u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 184u8, 154u8, 224u8,
+                    184u8, 180u8, 224u8, 184u8, 129u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    130u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8, 184u8, 155u8, 224u8,
+                    184u8, 138u8, 224u8, 184u8, 178u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    130u8, 224u8, 184u8, 165u8, 224u8, 184u8, 180u8, 224u8, 184u8, 161u8, 224u8,
+                    184u8, 154u8, 224u8, 184u8, 185u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    130u8, 224u8, 184u8, 161u8, 224u8, 184u8, 178u8, 224u8, 184u8, 165u8, 224u8,
+                    184u8, 178u8, 224u8, 184u8, 162u8, 224u8, 184u8, 178u8, 224u8, 184u8, 165u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 161u8, 224u8, 184u8, 149u8, 224u8, 184u8,
+                    177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8,
+                    184u8, 130u8, 224u8, 184u8, 161u8, 224u8, 184u8, 173u8, 224u8, 184u8, 135u8,
+                    224u8, 185u8, 130u8, 224u8, 184u8, 129u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    165u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 149u8, 224u8,
+                    184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8,
+                    224u8, 184u8, 130u8, 224u8, 185u8, 128u8, 224u8, 184u8, 161u8, 224u8, 185u8,
+                    132u8, 224u8, 184u8, 149u8, 224u8, 184u8, 161u8, 224u8, 184u8, 178u8, 224u8,
+                    185u8, 128u8, 224u8, 184u8, 162u8, 224u8, 184u8, 129u8, 224u8, 184u8, 149u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    165u8, 224u8, 184u8, 130u8, 224u8, 184u8, 158u8, 224u8, 184u8, 161u8, 224u8,
+                    185u8, 136u8, 224u8, 184u8, 178u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    130u8, 224u8, 184u8, 138u8, 224u8, 184u8, 178u8, 224u8, 184u8, 153u8, 224u8,
+                    184u8, 158u8, 224u8, 184u8, 161u8, 224u8, 185u8, 136u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 149u8, 224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 185u8,
+                    128u8, 224u8, 184u8, 165u8, 224u8, 184u8, 130u8, 224u8, 184u8, 158u8, 224u8,
+                    184u8, 183u8, 224u8, 185u8, 137u8, 224u8, 184u8, 153u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 161u8, 224u8, 184u8, 183u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                    135u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8,
+                    185u8, 128u8, 224u8, 184u8, 165u8, 224u8, 184u8, 130u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 173u8, 224u8, 185u8, 135u8, 224u8, 184u8, 153u8, 224u8, 185u8,
+                    130u8, 224u8, 184u8, 129u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8, 224u8,
+                    184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8, 184u8, 130u8,
+                    224u8, 185u8, 130u8, 224u8, 184u8, 173u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    136u8, 224u8, 184u8, 180u8, 224u8, 184u8, 129u8, 224u8, 184u8, 180u8, 224u8,
+                    184u8, 149u8, 224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 165u8, 224u8, 184u8, 130u8, 224u8, 185u8, 130u8, 224u8, 184u8,
+                    173u8, 224u8, 184u8, 163u8, 224u8, 184u8, 180u8, 224u8, 184u8, 162u8, 224u8,
+                    184u8, 178u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8, 224u8, 184u8, 167u8,
+                    224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8, 184u8, 130u8, 224u8, 184u8,
+                    173u8, 224u8, 184u8, 173u8, 224u8, 184u8, 170u8, 224u8, 184u8, 161u8, 224u8,
+                    184u8, 177u8, 224u8, 184u8, 153u8, 224u8, 184u8, 162u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 149u8, 224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 185u8,
+                    128u8, 224u8, 184u8, 165u8, 224u8, 184u8, 130u8, 224u8, 185u8, 130u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 161u8, 224u8, 184u8, 177u8, 224u8, 184u8, 153u8,
+                    224u8, 184u8, 149u8, 224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 185u8,
+                    128u8, 224u8, 184u8, 165u8, 224u8, 184u8, 130u8, 224u8, 185u8, 130u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 161u8, 224u8, 184u8, 177u8, 224u8, 184u8, 153u8,
+                    224u8, 184u8, 158u8, 224u8, 184u8, 180u8, 224u8, 184u8, 161u8, 224u8, 184u8,
+                    158u8, 224u8, 185u8, 140u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8,
+                    185u8, 135u8, 224u8, 184u8, 129u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    130u8, 224u8, 185u8, 128u8, 224u8, 184u8, 168u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 177u8, 224u8, 184u8, 138u8, 224u8, 184u8, 152u8,
+                    224u8, 184u8, 163u8, 224u8, 184u8, 178u8, 224u8, 184u8, 149u8, 224u8, 184u8,
+                    177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8,
+                    184u8, 130u8, 224u8, 184u8, 138u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8,
+                    224u8, 184u8, 178u8, 224u8, 184u8, 148u8, 224u8, 184u8, 178u8, 224u8, 184u8,
+                    149u8, 224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8,
+                    184u8, 165u8, 224u8, 184u8, 130u8, 224u8, 185u8, 130u8, 224u8, 184u8, 170u8,
+                    224u8, 184u8, 163u8, 224u8, 184u8, 178u8, 224u8, 184u8, 170u8, 224u8, 184u8,
+                    161u8, 224u8, 185u8, 128u8, 224u8, 184u8, 155u8, 224u8, 185u8, 135u8, 224u8,
+                    184u8, 135u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8, 224u8, 184u8, 167u8,
+                    224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8, 184u8, 130u8, 224u8, 184u8,
+                    139u8, 224u8, 184u8, 184u8, 224u8, 184u8, 153u8, 224u8, 184u8, 148u8, 224u8,
+                    184u8, 178u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8, 224u8, 184u8, 167u8,
+                    224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8, 184u8, 130u8, 224u8, 184u8,
+                    151u8, 224u8, 184u8, 178u8, 224u8, 184u8, 132u8, 224u8, 184u8, 163u8, 224u8,
+                    184u8, 181u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8, 224u8, 184u8, 167u8,
+                    224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8, 184u8, 130u8, 224u8, 185u8,
+                    132u8, 224u8, 184u8, 151u8, 224u8, 184u8, 165u8, 224u8, 184u8, 183u8, 224u8,
+                    185u8, 137u8, 224u8, 184u8, 173u8, 224u8, 185u8, 131u8, 224u8, 184u8, 171u8,
+                    224u8, 184u8, 161u8, 224u8, 185u8, 136u8, 224u8, 184u8, 149u8, 224u8, 184u8,
+                    177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8,
+                    184u8, 130u8, 224u8, 184u8, 151u8, 224u8, 184u8, 161u8, 224u8, 184u8, 180u8,
+                    224u8, 184u8, 172u8, 224u8, 184u8, 148u8, 224u8, 184u8, 177u8, 224u8, 185u8,
+                    137u8, 224u8, 184u8, 135u8, 224u8, 185u8, 128u8, 224u8, 184u8, 148u8, 224u8,
+                    184u8, 180u8, 224u8, 184u8, 161u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    130u8, 224u8, 184u8, 151u8, 224u8, 184u8, 161u8, 224u8, 184u8, 180u8, 224u8,
+                    184u8, 172u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8, 224u8, 184u8, 167u8,
+                    224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8, 184u8, 130u8, 224u8, 185u8,
+                    128u8, 224u8, 184u8, 149u8, 224u8, 184u8, 165u8, 224u8, 184u8, 185u8, 224u8,
+                    184u8, 129u8, 224u8, 184u8, 185u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    130u8, 224u8, 185u8, 132u8, 224u8, 184u8, 151u8, 224u8, 184u8, 162u8, 224u8,
+                    184u8, 149u8, 224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 165u8, 224u8, 184u8, 130u8, 224u8, 184u8, 151u8, 224u8, 184u8,
+                    180u8, 224u8, 185u8, 128u8, 224u8, 184u8, 154u8, 224u8, 184u8, 149u8, 224u8,
+                    184u8, 149u8, 224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 165u8, 224u8, 184u8, 130u8, 224u8, 185u8, 129u8, 224u8, 184u8,
+                    154u8, 224u8, 184u8, 154u8, 224u8, 184u8, 148u8, 224u8, 184u8, 177u8, 224u8,
+                    185u8, 137u8, 224u8, 184u8, 135u8, 224u8, 185u8, 128u8, 224u8, 184u8, 148u8,
+                    224u8, 184u8, 180u8, 224u8, 184u8, 161u8, 224u8, 184u8, 149u8, 224u8, 184u8,
+                    177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8,
+                    184u8, 130u8, 224u8, 185u8, 132u8, 224u8, 184u8, 167u8,
+                ])
+            },
+        )
+    },
+};
+static TR: &DataStruct = &::icu_displaynames::provider::KeyDisplayNamesV1 {
+    keys: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    99u8, 97u8, 99u8, 102u8, 99u8, 111u8, 99u8, 117u8, 104u8, 99u8, 107u8, 97u8,
+                    107u8, 98u8, 107u8, 99u8, 107u8, 102u8, 107u8, 107u8, 107u8, 110u8, 107u8,
+                    115u8, 108u8, 98u8, 109u8, 115u8, 110u8, 117u8, 116u8, 122u8, 118u8, 97u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    17u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 25u8, 0u8, 42u8, 0u8, 53u8, 0u8, 75u8,
+                    0u8, 107u8, 0u8, 127u8, 0u8, 168u8, 0u8, 199u8, 0u8, 228u8, 0u8, 246u8, 0u8,
+                    6u8, 1u8, 23u8, 1u8, 38u8, 1u8, 46u8, 1u8, 57u8, 1u8, 84u8, 97u8, 107u8, 118u8,
+                    105u8, 109u8, 80u8, 97u8, 114u8, 97u8, 32u8, 66u8, 105u8, 114u8, 105u8, 109u8,
+                    105u8, 32u8, 66u8, 105u8, 195u8, 167u8, 105u8, 109u8, 105u8, 83u8, 196u8,
+                    177u8, 114u8, 97u8, 108u8, 97u8, 109u8, 97u8, 32u8, 68u8, 195u8, 188u8, 122u8,
+                    101u8, 110u8, 105u8, 80u8, 97u8, 114u8, 97u8, 32u8, 66u8, 105u8, 114u8, 105u8,
+                    109u8, 105u8, 83u8, 97u8, 97u8, 116u8, 32u8, 83u8, 105u8, 115u8, 116u8, 101u8,
+                    109u8, 105u8, 32u8, 40u8, 49u8, 50u8, 32u8, 45u8, 32u8, 50u8, 52u8, 41u8, 83u8,
+                    101u8, 109u8, 98u8, 111u8, 108u8, 108u8, 101u8, 114u8, 105u8, 32u8, 83u8,
+                    196u8, 177u8, 114u8, 97u8, 108u8, 97u8, 109u8, 97u8, 121u8, 196u8, 177u8, 32u8,
+                    89u8, 111u8, 107u8, 115u8, 97u8, 121u8, 109u8, 97u8, 84u8, 101u8, 114u8, 115u8,
+                    32u8, 65u8, 107u8, 115u8, 97u8, 110u8, 32u8, 83u8, 196u8, 177u8, 114u8, 97u8,
+                    108u8, 97u8, 109u8, 97u8, 66u8, 195u8, 188u8, 121u8, 195u8, 188u8, 107u8, 47u8,
+                    75u8, 195u8, 188u8, 195u8, 167u8, 195u8, 188u8, 107u8, 32u8, 72u8, 97u8, 114u8,
+                    102u8, 101u8, 32u8, 68u8, 117u8, 121u8, 97u8, 114u8, 108u8, 196u8, 177u8, 32u8,
+                    83u8, 196u8, 177u8, 114u8, 97u8, 108u8, 97u8, 109u8, 97u8, 66u8, 195u8, 188u8,
+                    121u8, 195u8, 188u8, 107u8, 47u8, 75u8, 195u8, 188u8, 195u8, 167u8, 195u8,
+                    188u8, 107u8, 32u8, 72u8, 97u8, 114u8, 102u8, 32u8, 83u8, 196u8, 177u8, 114u8,
+                    97u8, 108u8, 97u8, 109u8, 97u8, 78u8, 111u8, 114u8, 109u8, 97u8, 108u8, 108u8,
+                    101u8, 197u8, 159u8, 116u8, 105u8, 114u8, 105u8, 108u8, 109u8, 105u8, 197u8,
+                    159u8, 32u8, 83u8, 196u8, 177u8, 114u8, 97u8, 108u8, 97u8, 109u8, 97u8, 83u8,
+                    97u8, 121u8, 196u8, 177u8, 115u8, 97u8, 108u8, 32u8, 83u8, 196u8, 177u8, 114u8,
+                    97u8, 108u8, 97u8, 109u8, 97u8, 83u8, 196u8, 177u8, 114u8, 97u8, 108u8, 97u8,
+                    109u8, 97u8, 32u8, 71u8, 195u8, 188u8, 99u8, 195u8, 188u8, 83u8, 97u8, 116u8,
+                    196u8, 177u8, 114u8, 32u8, 83u8, 111u8, 110u8, 117u8, 32u8, 83u8, 116u8, 105u8,
+                    108u8, 105u8, 195u8, 150u8, 108u8, 195u8, 167u8, 195u8, 188u8, 32u8, 83u8,
+                    105u8, 115u8, 116u8, 101u8, 109u8, 105u8, 82u8, 97u8, 107u8, 97u8, 109u8,
+                    108u8, 97u8, 114u8, 83u8, 97u8, 97u8, 116u8, 32u8, 68u8, 105u8, 108u8, 105u8,
+                    109u8, 105u8, 89u8, 101u8, 114u8, 101u8, 108u8, 32u8, 86u8, 97u8, 114u8, 121u8,
+                    97u8, 110u8, 116u8,
+                ])
+            },
+        )
+    },
+    types: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    143u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 21u8, 0u8, 30u8, 0u8, 38u8, 0u8,
+                    48u8, 0u8, 59u8, 0u8, 69u8, 0u8, 78u8, 0u8, 87u8, 0u8, 97u8, 0u8, 113u8, 0u8,
+                    128u8, 0u8, 143u8, 0u8, 162u8, 0u8, 172u8, 0u8, 183u8, 0u8, 193u8, 0u8, 199u8,
+                    0u8, 209u8, 0u8, 220u8, 0u8, 230u8, 0u8, 239u8, 0u8, 246u8, 0u8, 254u8, 0u8,
+                    6u8, 1u8, 12u8, 1u8, 21u8, 1u8, 31u8, 1u8, 42u8, 1u8, 51u8, 1u8, 62u8, 1u8,
+                    71u8, 1u8, 82u8, 1u8, 93u8, 1u8, 102u8, 1u8, 109u8, 1u8, 118u8, 1u8, 127u8,
+                    1u8, 133u8, 1u8, 139u8, 1u8, 145u8, 1u8, 151u8, 1u8, 162u8, 1u8, 172u8, 1u8,
+                    180u8, 1u8, 187u8, 1u8, 195u8, 1u8, 202u8, 1u8, 210u8, 1u8, 218u8, 1u8, 226u8,
+                    1u8, 234u8, 1u8, 241u8, 1u8, 249u8, 1u8, 0u8, 2u8, 10u8, 2u8, 19u8, 2u8, 28u8,
+                    2u8, 37u8, 2u8, 46u8, 2u8, 54u8, 2u8, 63u8, 2u8, 72u8, 2u8, 81u8, 2u8, 92u8,
+                    2u8, 103u8, 2u8, 110u8, 2u8, 117u8, 2u8, 127u8, 2u8, 134u8, 2u8, 144u8, 2u8,
+                    151u8, 2u8, 158u8, 2u8, 165u8, 2u8, 172u8, 2u8, 179u8, 2u8, 186u8, 2u8, 193u8,
+                    2u8, 200u8, 2u8, 210u8, 2u8, 221u8, 2u8, 228u8, 2u8, 235u8, 2u8, 242u8, 2u8,
+                    252u8, 2u8, 3u8, 3u8, 10u8, 3u8, 20u8, 3u8, 27u8, 3u8, 37u8, 3u8, 44u8, 3u8,
+                    54u8, 3u8, 61u8, 3u8, 68u8, 3u8, 75u8, 3u8, 82u8, 3u8, 92u8, 3u8, 99u8, 3u8,
+                    106u8, 3u8, 113u8, 3u8, 120u8, 3u8, 131u8, 3u8, 138u8, 3u8, 145u8, 3u8, 152u8,
+                    3u8, 159u8, 3u8, 170u8, 3u8, 180u8, 3u8, 191u8, 3u8, 202u8, 3u8, 213u8, 3u8,
+                    220u8, 3u8, 227u8, 3u8, 234u8, 3u8, 241u8, 3u8, 248u8, 3u8, 255u8, 3u8, 10u8,
+                    4u8, 21u8, 4u8, 30u8, 4u8, 37u8, 4u8, 44u8, 4u8, 51u8, 4u8, 58u8, 4u8, 66u8,
+                    4u8, 77u8, 4u8, 84u8, 4u8, 91u8, 4u8, 98u8, 4u8, 105u8, 4u8, 112u8, 4u8, 119u8,
+                    4u8, 126u8, 4u8, 133u8, 4u8, 140u8, 4u8, 150u8, 4u8, 157u8, 4u8, 164u8, 4u8,
+                    171u8, 4u8, 178u8, 4u8, 189u8, 4u8, 196u8, 4u8, 99u8, 97u8, 45u8, 98u8, 117u8,
+                    100u8, 100u8, 104u8, 105u8, 115u8, 116u8, 99u8, 97u8, 45u8, 99u8, 104u8, 105u8,
+                    110u8, 101u8, 115u8, 101u8, 99u8, 97u8, 45u8, 99u8, 111u8, 112u8, 116u8, 105u8,
+                    99u8, 99u8, 97u8, 45u8, 100u8, 97u8, 110u8, 103u8, 105u8, 99u8, 97u8, 45u8,
+                    101u8, 116u8, 104u8, 105u8, 111u8, 97u8, 97u8, 99u8, 97u8, 45u8, 101u8, 116u8,
+                    104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 99u8, 97u8, 45u8, 103u8, 114u8, 101u8,
+                    103u8, 111u8, 114u8, 121u8, 99u8, 97u8, 45u8, 104u8, 101u8, 98u8, 114u8, 101u8,
+                    119u8, 99u8, 97u8, 45u8, 105u8, 110u8, 100u8, 105u8, 97u8, 110u8, 99u8, 97u8,
+                    45u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 99u8, 97u8, 45u8, 105u8,
+                    115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 99u8, 105u8, 118u8, 105u8, 108u8,
+                    99u8, 97u8, 45u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 114u8,
+                    103u8, 115u8, 97u8, 99u8, 97u8, 45u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8,
+                    99u8, 45u8, 116u8, 98u8, 108u8, 97u8, 99u8, 97u8, 45u8, 105u8, 115u8, 108u8,
+                    97u8, 109u8, 105u8, 99u8, 45u8, 117u8, 109u8, 97u8, 108u8, 113u8, 117u8, 114u8,
+                    97u8, 99u8, 97u8, 45u8, 105u8, 115u8, 111u8, 56u8, 54u8, 48u8, 49u8, 99u8,
+                    97u8, 45u8, 106u8, 97u8, 112u8, 97u8, 110u8, 101u8, 115u8, 101u8, 99u8, 97u8,
+                    45u8, 112u8, 101u8, 114u8, 115u8, 105u8, 97u8, 110u8, 99u8, 97u8, 45u8, 114u8,
+                    111u8, 99u8, 99u8, 102u8, 45u8, 97u8, 99u8, 99u8, 111u8, 117u8, 110u8, 116u8,
+                    99u8, 102u8, 45u8, 115u8, 116u8, 97u8, 110u8, 100u8, 97u8, 114u8, 100u8, 99u8,
+                    111u8, 45u8, 98u8, 105u8, 103u8, 53u8, 104u8, 97u8, 110u8, 99u8, 111u8, 45u8,
+                    99u8, 111u8, 109u8, 112u8, 97u8, 116u8, 99u8, 111u8, 45u8, 100u8, 105u8, 99u8,
+                    116u8, 99u8, 111u8, 45u8, 100u8, 117u8, 99u8, 101u8, 116u8, 99u8, 111u8, 45u8,
+                    101u8, 109u8, 111u8, 106u8, 105u8, 99u8, 111u8, 45u8, 101u8, 111u8, 114u8,
+                    99u8, 111u8, 45u8, 103u8, 98u8, 50u8, 51u8, 49u8, 50u8, 99u8, 111u8, 45u8,
+                    112u8, 104u8, 111u8, 110u8, 101u8, 98u8, 107u8, 99u8, 111u8, 45u8, 112u8,
+                    104u8, 111u8, 110u8, 101u8, 116u8, 105u8, 99u8, 99u8, 111u8, 45u8, 112u8,
+                    105u8, 110u8, 121u8, 105u8, 110u8, 99u8, 111u8, 45u8, 114u8, 101u8, 102u8,
+                    111u8, 114u8, 109u8, 101u8, 100u8, 99u8, 111u8, 45u8, 115u8, 101u8, 97u8,
+                    114u8, 99u8, 104u8, 99u8, 111u8, 45u8, 115u8, 101u8, 97u8, 114u8, 99u8, 104u8,
+                    106u8, 108u8, 99u8, 111u8, 45u8, 115u8, 116u8, 97u8, 110u8, 100u8, 97u8, 114u8,
+                    100u8, 99u8, 111u8, 45u8, 115u8, 116u8, 114u8, 111u8, 107u8, 101u8, 99u8,
+                    111u8, 45u8, 116u8, 114u8, 97u8, 100u8, 99u8, 111u8, 45u8, 117u8, 110u8, 105u8,
+                    104u8, 97u8, 110u8, 99u8, 111u8, 45u8, 122u8, 104u8, 117u8, 121u8, 105u8,
+                    110u8, 104u8, 99u8, 45u8, 104u8, 49u8, 49u8, 104u8, 99u8, 45u8, 104u8, 49u8,
+                    50u8, 104u8, 99u8, 45u8, 104u8, 50u8, 51u8, 104u8, 99u8, 45u8, 104u8, 50u8,
+                    52u8, 107u8, 97u8, 45u8, 110u8, 111u8, 105u8, 103u8, 110u8, 111u8, 114u8,
+                    101u8, 107u8, 97u8, 45u8, 115u8, 104u8, 105u8, 102u8, 116u8, 101u8, 100u8,
+                    107u8, 98u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8, 107u8, 98u8, 45u8, 116u8,
+                    114u8, 117u8, 101u8, 107u8, 99u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8,
+                    107u8, 99u8, 45u8, 116u8, 114u8, 117u8, 101u8, 107u8, 102u8, 45u8, 102u8, 97u8,
+                    108u8, 115u8, 101u8, 107u8, 102u8, 45u8, 108u8, 111u8, 119u8, 101u8, 114u8,
+                    107u8, 102u8, 45u8, 117u8, 112u8, 112u8, 101u8, 114u8, 107u8, 107u8, 45u8,
+                    102u8, 97u8, 108u8, 115u8, 101u8, 107u8, 107u8, 45u8, 116u8, 114u8, 117u8,
+                    101u8, 107u8, 110u8, 45u8, 102u8, 97u8, 108u8, 115u8, 101u8, 107u8, 110u8,
+                    45u8, 116u8, 114u8, 117u8, 101u8, 107u8, 115u8, 45u8, 105u8, 100u8, 101u8,
+                    110u8, 116u8, 105u8, 99u8, 107u8, 115u8, 45u8, 108u8, 101u8, 118u8, 101u8,
+                    108u8, 49u8, 107u8, 115u8, 45u8, 108u8, 101u8, 118u8, 101u8, 108u8, 50u8,
+                    107u8, 115u8, 45u8, 108u8, 101u8, 118u8, 101u8, 108u8, 51u8, 107u8, 115u8,
+                    45u8, 108u8, 101u8, 118u8, 101u8, 108u8, 52u8, 108u8, 98u8, 45u8, 108u8, 111u8,
+                    111u8, 115u8, 101u8, 108u8, 98u8, 45u8, 110u8, 111u8, 114u8, 109u8, 97u8,
+                    108u8, 108u8, 98u8, 45u8, 115u8, 116u8, 114u8, 105u8, 99u8, 116u8, 109u8,
+                    115u8, 45u8, 109u8, 101u8, 116u8, 114u8, 105u8, 99u8, 109u8, 115u8, 45u8,
+                    117u8, 107u8, 115u8, 121u8, 115u8, 116u8, 101u8, 109u8, 109u8, 115u8, 45u8,
+                    117u8, 115u8, 115u8, 121u8, 115u8, 116u8, 101u8, 109u8, 110u8, 117u8, 45u8,
+                    97u8, 104u8, 111u8, 109u8, 110u8, 117u8, 45u8, 97u8, 114u8, 97u8, 98u8, 110u8,
+                    117u8, 45u8, 97u8, 114u8, 97u8, 98u8, 101u8, 120u8, 116u8, 110u8, 117u8, 45u8,
+                    97u8, 114u8, 109u8, 110u8, 110u8, 117u8, 45u8, 97u8, 114u8, 109u8, 110u8,
+                    108u8, 111u8, 119u8, 110u8, 117u8, 45u8, 98u8, 97u8, 108u8, 105u8, 110u8,
+                    117u8, 45u8, 98u8, 101u8, 110u8, 103u8, 110u8, 117u8, 45u8, 98u8, 114u8, 97u8,
+                    104u8, 110u8, 117u8, 45u8, 99u8, 97u8, 107u8, 109u8, 110u8, 117u8, 45u8, 99u8,
+                    104u8, 97u8, 109u8, 110u8, 117u8, 45u8, 99u8, 121u8, 114u8, 108u8, 110u8,
+                    117u8, 45u8, 100u8, 101u8, 118u8, 97u8, 110u8, 117u8, 45u8, 101u8, 116u8,
+                    104u8, 105u8, 110u8, 117u8, 45u8, 102u8, 105u8, 110u8, 97u8, 110u8, 99u8,
+                    101u8, 110u8, 117u8, 45u8, 102u8, 117u8, 108u8, 108u8, 119u8, 105u8, 100u8,
+                    101u8, 110u8, 117u8, 45u8, 103u8, 101u8, 111u8, 114u8, 110u8, 117u8, 45u8,
+                    103u8, 111u8, 110u8, 109u8, 110u8, 117u8, 45u8, 103u8, 114u8, 101u8, 107u8,
+                    110u8, 117u8, 45u8, 103u8, 114u8, 101u8, 107u8, 108u8, 111u8, 119u8, 110u8,
+                    117u8, 45u8, 103u8, 117u8, 106u8, 114u8, 110u8, 117u8, 45u8, 103u8, 117u8,
+                    114u8, 117u8, 110u8, 117u8, 45u8, 104u8, 97u8, 110u8, 105u8, 100u8, 101u8,
+                    99u8, 110u8, 117u8, 45u8, 104u8, 97u8, 110u8, 115u8, 110u8, 117u8, 45u8, 104u8,
+                    97u8, 110u8, 115u8, 102u8, 105u8, 110u8, 110u8, 117u8, 45u8, 104u8, 97u8,
+                    110u8, 116u8, 110u8, 117u8, 45u8, 104u8, 97u8, 110u8, 116u8, 102u8, 105u8,
+                    110u8, 110u8, 117u8, 45u8, 104u8, 101u8, 98u8, 114u8, 110u8, 117u8, 45u8,
+                    104u8, 109u8, 110u8, 103u8, 110u8, 117u8, 45u8, 106u8, 97u8, 118u8, 97u8,
+                    110u8, 117u8, 45u8, 106u8, 112u8, 97u8, 110u8, 110u8, 117u8, 45u8, 106u8,
+                    112u8, 97u8, 110u8, 102u8, 105u8, 110u8, 110u8, 117u8, 45u8, 107u8, 97u8,
+                    108u8, 105u8, 110u8, 117u8, 45u8, 107u8, 104u8, 109u8, 114u8, 110u8, 117u8,
+                    45u8, 107u8, 110u8, 100u8, 97u8, 110u8, 117u8, 45u8, 108u8, 97u8, 110u8, 97u8,
+                    110u8, 117u8, 45u8, 108u8, 97u8, 110u8, 97u8, 116u8, 104u8, 97u8, 109u8, 110u8,
+                    117u8, 45u8, 108u8, 97u8, 111u8, 111u8, 110u8, 117u8, 45u8, 108u8, 97u8, 116u8,
+                    110u8, 110u8, 117u8, 45u8, 108u8, 101u8, 112u8, 99u8, 110u8, 117u8, 45u8,
+                    108u8, 105u8, 109u8, 98u8, 110u8, 117u8, 45u8, 109u8, 97u8, 116u8, 104u8, 98u8,
+                    111u8, 108u8, 100u8, 110u8, 117u8, 45u8, 109u8, 97u8, 116u8, 104u8, 100u8,
+                    98u8, 108u8, 110u8, 117u8, 45u8, 109u8, 97u8, 116u8, 104u8, 109u8, 111u8,
+                    110u8, 111u8, 110u8, 117u8, 45u8, 109u8, 97u8, 116u8, 104u8, 115u8, 97u8,
+                    110u8, 98u8, 110u8, 117u8, 45u8, 109u8, 97u8, 116u8, 104u8, 115u8, 97u8, 110u8,
+                    115u8, 110u8, 117u8, 45u8, 109u8, 108u8, 121u8, 109u8, 110u8, 117u8, 45u8,
+                    109u8, 111u8, 100u8, 105u8, 110u8, 117u8, 45u8, 109u8, 111u8, 110u8, 103u8,
+                    110u8, 117u8, 45u8, 109u8, 114u8, 111u8, 111u8, 110u8, 117u8, 45u8, 109u8,
+                    116u8, 101u8, 105u8, 110u8, 117u8, 45u8, 109u8, 121u8, 109u8, 114u8, 110u8,
+                    117u8, 45u8, 109u8, 121u8, 109u8, 114u8, 115u8, 104u8, 97u8, 110u8, 110u8,
+                    117u8, 45u8, 109u8, 121u8, 109u8, 114u8, 116u8, 108u8, 110u8, 103u8, 110u8,
+                    117u8, 45u8, 110u8, 97u8, 116u8, 105u8, 118u8, 101u8, 110u8, 117u8, 45u8,
+                    110u8, 107u8, 111u8, 111u8, 110u8, 117u8, 45u8, 111u8, 108u8, 99u8, 107u8,
+                    110u8, 117u8, 45u8, 111u8, 114u8, 121u8, 97u8, 110u8, 117u8, 45u8, 111u8,
+                    115u8, 109u8, 97u8, 110u8, 117u8, 45u8, 114u8, 111u8, 109u8, 97u8, 110u8,
+                    110u8, 117u8, 45u8, 114u8, 111u8, 109u8, 97u8, 110u8, 108u8, 111u8, 119u8,
+                    110u8, 117u8, 45u8, 115u8, 97u8, 117u8, 114u8, 110u8, 117u8, 45u8, 115u8,
+                    104u8, 114u8, 100u8, 110u8, 117u8, 45u8, 115u8, 105u8, 110u8, 100u8, 110u8,
+                    117u8, 45u8, 115u8, 105u8, 110u8, 104u8, 110u8, 117u8, 45u8, 115u8, 111u8,
+                    114u8, 97u8, 110u8, 117u8, 45u8, 115u8, 117u8, 110u8, 100u8, 110u8, 117u8,
+                    45u8, 116u8, 97u8, 107u8, 114u8, 110u8, 117u8, 45u8, 116u8, 97u8, 108u8, 117u8,
+                    110u8, 117u8, 45u8, 116u8, 97u8, 109u8, 108u8, 110u8, 117u8, 45u8, 116u8, 97u8,
+                    109u8, 108u8, 100u8, 101u8, 99u8, 110u8, 117u8, 45u8, 116u8, 101u8, 108u8,
+                    117u8, 110u8, 117u8, 45u8, 116u8, 104u8, 97u8, 105u8, 110u8, 117u8, 45u8,
+                    116u8, 105u8, 98u8, 116u8, 110u8, 117u8, 45u8, 116u8, 105u8, 114u8, 104u8,
+                    110u8, 117u8, 45u8, 116u8, 114u8, 97u8, 100u8, 105u8, 116u8, 105u8, 111u8,
+                    110u8, 117u8, 45u8, 118u8, 97u8, 105u8, 105u8, 110u8, 117u8, 45u8, 119u8, 97u8,
+                    114u8, 97u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    143u8, 0u8, 0u8, 0u8, 0u8, 0u8, 14u8, 0u8, 26u8, 0u8, 39u8, 0u8, 52u8, 0u8,
+                    79u8, 0u8, 94u8, 0u8, 107u8, 0u8, 122u8, 0u8, 141u8, 0u8, 153u8, 0u8, 165u8,
+                    0u8, 185u8, 0u8, 218u8, 0u8, 255u8, 0u8, 15u8, 1u8, 28u8, 1u8, 41u8, 1u8, 65u8,
+                    1u8, 86u8, 1u8, 107u8, 1u8, 149u8, 1u8, 192u8, 1u8, 218u8, 1u8, 255u8, 1u8,
+                    22u8, 2u8, 49u8, 2u8, 101u8, 2u8, 134u8, 2u8, 159u8, 2u8, 183u8, 2u8, 205u8,
+                    2u8, 225u8, 2u8, 5u8, 3u8, 31u8, 3u8, 55u8, 3u8, 83u8, 3u8, 115u8, 3u8, 139u8,
+                    3u8, 163u8, 3u8, 187u8, 3u8, 211u8, 3u8, 235u8, 3u8, 255u8, 3u8, 30u8, 4u8,
+                    64u8, 4u8, 89u8, 4u8, 138u8, 4u8, 178u8, 4u8, 227u8, 4u8, 4u8, 5u8, 36u8, 5u8,
+                    69u8, 5u8, 113u8, 5u8, 139u8, 5u8, 175u8, 5u8, 194u8, 5u8, 230u8, 5u8, 250u8,
+                    5u8, 52u8, 6u8, 121u8, 6u8, 146u8, 6u8, 170u8, 6u8, 193u8, 6u8, 206u8, 6u8,
+                    230u8, 6u8, 249u8, 6u8, 8u8, 7u8, 28u8, 7u8, 64u8, 7u8, 81u8, 7u8, 112u8, 7u8,
+                    127u8, 7u8, 144u8, 7u8, 161u8, 7u8, 178u8, 7u8, 193u8, 7u8, 209u8, 7u8, 230u8,
+                    7u8, 248u8, 7u8, 9u8, 8u8, 33u8, 8u8, 51u8, 8u8, 75u8, 8u8, 91u8, 8u8, 121u8,
+                    8u8, 140u8, 8u8, 159u8, 8u8, 183u8, 8u8, 217u8, 8u8, 4u8, 9u8, 30u8, 9u8, 65u8,
+                    9u8, 83u8, 9u8, 106u8, 9u8, 121u8, 9u8, 137u8, 9u8, 162u8, 9u8, 181u8, 9u8,
+                    196u8, 9u8, 214u8, 9u8, 238u8, 9u8, 6u8, 10u8, 20u8, 10u8, 36u8, 10u8, 53u8,
+                    10u8, 69u8, 10u8, 97u8, 10u8, 133u8, 10u8, 171u8, 10u8, 210u8, 10u8, 242u8,
+                    10u8, 6u8, 11u8, 21u8, 11u8, 38u8, 11u8, 52u8, 11u8, 75u8, 11u8, 93u8, 11u8,
+                    116u8, 11u8, 144u8, 11u8, 158u8, 11u8, 175u8, 11u8, 194u8, 11u8, 210u8, 11u8,
+                    228u8, 11u8, 243u8, 11u8, 16u8, 12u8, 37u8, 12u8, 55u8, 12u8, 75u8, 12u8, 98u8,
+                    12u8, 121u8, 12u8, 137u8, 12u8, 155u8, 12u8, 177u8, 12u8, 204u8, 12u8, 220u8,
+                    12u8, 237u8, 12u8, 251u8, 12u8, 11u8, 13u8, 29u8, 13u8, 48u8, 13u8, 62u8, 13u8,
+                    66u8, 117u8, 100u8, 105u8, 115u8, 116u8, 32u8, 84u8, 97u8, 107u8, 118u8, 105u8,
+                    109u8, 105u8, 195u8, 135u8, 105u8, 110u8, 32u8, 84u8, 97u8, 107u8, 118u8,
+                    105u8, 109u8, 105u8, 75u8, 196u8, 177u8, 112u8, 116u8, 105u8, 32u8, 84u8, 97u8,
+                    107u8, 118u8, 105u8, 109u8, 68u8, 97u8, 110u8, 103u8, 105u8, 32u8, 84u8, 97u8,
+                    107u8, 118u8, 105u8, 109u8, 105u8, 69u8, 116u8, 105u8, 121u8, 111u8, 112u8,
+                    105u8, 107u8, 32u8, 65u8, 109u8, 101u8, 116u8, 101u8, 32u8, 65u8, 108u8, 101u8,
+                    109u8, 32u8, 84u8, 97u8, 107u8, 118u8, 105u8, 109u8, 105u8, 69u8, 116u8, 105u8,
+                    121u8, 111u8, 112u8, 105u8, 107u8, 32u8, 84u8, 97u8, 107u8, 118u8, 105u8,
+                    109u8, 77u8, 105u8, 108u8, 97u8, 100u8, 105u8, 32u8, 84u8, 97u8, 107u8, 118u8,
+                    105u8, 109u8, 196u8, 176u8, 98u8, 114u8, 97u8, 110u8, 105u8, 32u8, 84u8, 97u8,
+                    107u8, 118u8, 105u8, 109u8, 105u8, 85u8, 108u8, 117u8, 115u8, 97u8, 108u8,
+                    32u8, 72u8, 105u8, 110u8, 116u8, 32u8, 84u8, 97u8, 107u8, 118u8, 105u8, 109u8,
+                    105u8, 72u8, 105u8, 99u8, 114u8, 105u8, 32u8, 84u8, 97u8, 107u8, 118u8, 105u8,
+                    109u8, 65u8, 114u8, 97u8, 112u8, 32u8, 84u8, 97u8, 107u8, 118u8, 105u8, 109u8,
+                    105u8, 72u8, 105u8, 99u8, 114u8, 105u8, 32u8, 84u8, 97u8, 107u8, 118u8, 105u8,
+                    109u8, 32u8, 40u8, 83u8, 117u8, 117u8, 100u8, 105u8, 41u8, 72u8, 105u8, 99u8,
+                    114u8, 105u8, 32u8, 84u8, 97u8, 107u8, 118u8, 105u8, 109u8, 32u8, 40u8, 65u8,
+                    115u8, 116u8, 114u8, 111u8, 110u8, 111u8, 109u8, 105u8, 107u8, 32u8, 69u8,
+                    118u8, 114u8, 101u8, 108u8, 101u8, 114u8, 41u8, 72u8, 105u8, 99u8, 114u8,
+                    105u8, 32u8, 84u8, 97u8, 107u8, 118u8, 105u8, 109u8, 32u8, 40u8, 195u8, 156u8,
+                    109u8, 109u8, 195u8, 188u8, 45u8, 108u8, 32u8, 75u8, 117u8, 114u8, 114u8, 97u8,
+                    32u8, 84u8, 97u8, 107u8, 118u8, 105u8, 109u8, 105u8, 41u8, 73u8, 83u8, 79u8,
+                    45u8, 56u8, 54u8, 48u8, 49u8, 32u8, 84u8, 97u8, 107u8, 118u8, 105u8, 109u8,
+                    105u8, 74u8, 97u8, 112u8, 111u8, 110u8, 32u8, 84u8, 97u8, 107u8, 118u8, 105u8,
+                    109u8, 105u8, 196u8, 176u8, 114u8, 97u8, 110u8, 32u8, 84u8, 97u8, 107u8, 118u8,
+                    105u8, 109u8, 105u8, 195u8, 135u8, 105u8, 110u8, 32u8, 67u8, 117u8, 109u8,
+                    104u8, 117u8, 114u8, 105u8, 121u8, 101u8, 116u8, 105u8, 32u8, 84u8, 97u8,
+                    107u8, 118u8, 105u8, 109u8, 105u8, 77u8, 117u8, 104u8, 97u8, 115u8, 101u8,
+                    98u8, 101u8, 32u8, 80u8, 97u8, 114u8, 97u8, 32u8, 66u8, 105u8, 195u8, 167u8,
+                    105u8, 109u8, 105u8, 83u8, 116u8, 97u8, 110u8, 100u8, 97u8, 114u8, 116u8, 32u8,
+                    80u8, 97u8, 114u8, 97u8, 32u8, 66u8, 105u8, 195u8, 167u8, 105u8, 109u8, 105u8,
+                    71u8, 101u8, 108u8, 101u8, 110u8, 101u8, 107u8, 115u8, 101u8, 108u8, 32u8,
+                    195u8, 135u8, 105u8, 110u8, 99u8, 101u8, 32u8, 83u8, 196u8, 177u8, 114u8, 97u8,
+                    108u8, 97u8, 109u8, 97u8, 32u8, 68u8, 195u8, 188u8, 122u8, 101u8, 110u8, 105u8,
+                    32u8, 45u8, 32u8, 66u8, 105u8, 103u8, 53u8, 195u8, 150u8, 110u8, 99u8, 101u8,
+                    107u8, 105u8, 32u8, 83u8, 196u8, 177u8, 114u8, 97u8, 108u8, 97u8, 109u8, 97u8,
+                    32u8, 68u8, 195u8, 188u8, 122u8, 101u8, 110u8, 105u8, 32u8, 40u8, 117u8, 121u8,
+                    117u8, 109u8, 108u8, 117u8, 108u8, 117u8, 107u8, 32u8, 105u8, 195u8, 167u8,
+                    105u8, 110u8, 41u8, 83u8, 195u8, 182u8, 122u8, 108u8, 195u8, 188u8, 107u8,
+                    32u8, 83u8, 196u8, 177u8, 114u8, 97u8, 108u8, 97u8, 109u8, 97u8, 32u8, 68u8,
+                    195u8, 188u8, 122u8, 101u8, 110u8, 105u8, 83u8, 97u8, 112u8, 116u8, 97u8,
+                    110u8, 109u8, 196u8, 177u8, 197u8, 159u8, 32u8, 85u8, 110u8, 105u8, 99u8,
+                    111u8, 100u8, 101u8, 32u8, 83u8, 196u8, 177u8, 114u8, 97u8, 108u8, 97u8, 109u8,
+                    97u8, 32u8, 68u8, 195u8, 188u8, 122u8, 101u8, 110u8, 105u8, 69u8, 109u8, 111u8,
+                    106u8, 105u8, 32u8, 83u8, 196u8, 177u8, 114u8, 97u8, 108u8, 97u8, 109u8, 97u8,
+                    32u8, 68u8, 195u8, 188u8, 122u8, 101u8, 110u8, 105u8, 65u8, 118u8, 114u8,
+                    117u8, 112u8, 97u8, 32u8, 83u8, 196u8, 177u8, 114u8, 97u8, 108u8, 97u8, 109u8,
+                    97u8, 32u8, 75u8, 117u8, 114u8, 97u8, 108u8, 108u8, 97u8, 114u8, 196u8, 177u8,
+                    66u8, 97u8, 115u8, 105u8, 116u8, 108u8, 101u8, 197u8, 159u8, 116u8, 105u8,
+                    114u8, 105u8, 108u8, 109u8, 105u8, 197u8, 159u8, 32u8, 195u8, 135u8, 105u8,
+                    110u8, 99u8, 101u8, 32u8, 83u8, 196u8, 177u8, 114u8, 97u8, 108u8, 97u8, 109u8,
+                    97u8, 32u8, 68u8, 195u8, 188u8, 122u8, 101u8, 110u8, 105u8, 32u8, 45u8, 32u8,
+                    71u8, 66u8, 50u8, 51u8, 49u8, 50u8, 84u8, 101u8, 108u8, 101u8, 102u8, 111u8,
+                    110u8, 32u8, 68u8, 101u8, 102u8, 116u8, 101u8, 114u8, 105u8, 32u8, 83u8, 196u8,
+                    177u8, 114u8, 97u8, 108u8, 97u8, 109u8, 97u8, 32u8, 68u8, 195u8, 188u8, 122u8,
+                    101u8, 110u8, 105u8, 70u8, 111u8, 110u8, 101u8, 116u8, 105u8, 107u8, 32u8,
+                    83u8, 196u8, 177u8, 114u8, 97u8, 108u8, 97u8, 109u8, 97u8, 32u8, 68u8, 195u8,
+                    188u8, 122u8, 101u8, 110u8, 105u8, 80u8, 105u8, 110u8, 121u8, 105u8, 110u8,
+                    32u8, 83u8, 196u8, 177u8, 114u8, 97u8, 108u8, 97u8, 109u8, 97u8, 32u8, 68u8,
+                    195u8, 188u8, 122u8, 101u8, 110u8, 105u8, 89u8, 101u8, 110u8, 105u8, 32u8,
+                    83u8, 196u8, 177u8, 114u8, 97u8, 108u8, 97u8, 109u8, 97u8, 32u8, 68u8, 195u8,
+                    188u8, 122u8, 101u8, 110u8, 105u8, 71u8, 101u8, 110u8, 101u8, 108u8, 32u8,
+                    65u8, 109u8, 97u8, 195u8, 167u8, 108u8, 196u8, 177u8, 32u8, 65u8, 114u8, 97u8,
+                    109u8, 97u8, 72u8, 97u8, 110u8, 103u8, 117u8, 108u8, 32u8, 196u8, 176u8, 108u8,
+                    107u8, 32u8, 83u8, 101u8, 115u8, 115u8, 105u8, 122u8, 32u8, 72u8, 97u8, 114u8,
+                    102u8, 101u8, 32u8, 71u8, 195u8, 182u8, 114u8, 101u8, 32u8, 65u8, 114u8, 97u8,
+                    109u8, 97u8, 83u8, 116u8, 97u8, 110u8, 100u8, 97u8, 114u8, 116u8, 32u8, 83u8,
+                    196u8, 177u8, 114u8, 97u8, 108u8, 97u8, 109u8, 97u8, 32u8, 68u8, 195u8, 188u8,
+                    122u8, 101u8, 110u8, 105u8, 86u8, 117u8, 114u8, 117u8, 197u8, 159u8, 32u8,
+                    83u8, 196u8, 177u8, 114u8, 97u8, 108u8, 97u8, 109u8, 97u8, 32u8, 68u8, 195u8,
+                    188u8, 122u8, 101u8, 110u8, 105u8, 71u8, 101u8, 108u8, 101u8, 110u8, 101u8,
+                    107u8, 115u8, 101u8, 108u8, 32u8, 83u8, 196u8, 177u8, 114u8, 97u8, 108u8, 97u8,
+                    109u8, 97u8, 32u8, 68u8, 195u8, 188u8, 122u8, 101u8, 110u8, 105u8, 82u8, 97u8,
+                    100u8, 105u8, 107u8, 97u8, 108u8, 45u8, 86u8, 117u8, 114u8, 117u8, 197u8,
+                    159u8, 32u8, 83u8, 196u8, 177u8, 114u8, 97u8, 108u8, 97u8, 109u8, 97u8, 32u8,
+                    68u8, 195u8, 188u8, 122u8, 101u8, 110u8, 105u8, 90u8, 104u8, 117u8, 121u8,
+                    105u8, 110u8, 32u8, 83u8, 196u8, 177u8, 114u8, 97u8, 108u8, 97u8, 109u8, 97u8,
+                    32u8, 68u8, 195u8, 188u8, 122u8, 101u8, 110u8, 105u8, 49u8, 50u8, 32u8, 83u8,
+                    97u8, 97u8, 116u8, 32u8, 83u8, 105u8, 115u8, 116u8, 101u8, 109u8, 105u8, 32u8,
+                    40u8, 48u8, 226u8, 128u8, 147u8, 49u8, 49u8, 41u8, 49u8, 50u8, 32u8, 83u8,
+                    97u8, 97u8, 116u8, 32u8, 83u8, 105u8, 115u8, 116u8, 101u8, 109u8, 105u8, 32u8,
+                    40u8, 49u8, 226u8, 128u8, 147u8, 49u8, 50u8, 41u8, 50u8, 52u8, 32u8, 83u8,
+                    97u8, 97u8, 116u8, 32u8, 83u8, 105u8, 115u8, 116u8, 101u8, 109u8, 105u8, 32u8,
+                    40u8, 48u8, 226u8, 128u8, 147u8, 50u8, 51u8, 41u8, 50u8, 52u8, 32u8, 83u8,
+                    97u8, 97u8, 116u8, 32u8, 83u8, 105u8, 115u8, 116u8, 101u8, 109u8, 105u8, 32u8,
+                    40u8, 49u8, 226u8, 128u8, 147u8, 50u8, 52u8, 41u8, 83u8, 101u8, 109u8, 98u8,
+                    111u8, 108u8, 108u8, 101u8, 114u8, 105u8, 32u8, 83u8, 196u8, 177u8, 114u8,
+                    97u8, 108u8, 97u8, 109u8, 97u8, 83u8, 101u8, 109u8, 98u8, 111u8, 108u8, 108u8,
+                    101u8, 114u8, 105u8, 32u8, 89u8, 111u8, 107u8, 115u8, 97u8, 121u8, 97u8, 114u8,
+                    97u8, 107u8, 32u8, 83u8, 196u8, 177u8, 114u8, 97u8, 108u8, 97u8, 109u8, 97u8,
+                    65u8, 107u8, 115u8, 97u8, 110u8, 108u8, 97u8, 114u8, 196u8, 177u8, 32u8, 78u8,
+                    111u8, 114u8, 109u8, 97u8, 108u8, 32u8, 79u8, 108u8, 97u8, 114u8, 97u8, 107u8,
+                    32u8, 83u8, 196u8, 177u8, 114u8, 97u8, 108u8, 97u8, 109u8, 97u8, 65u8, 107u8,
+                    115u8, 97u8, 110u8, 108u8, 97u8, 114u8, 196u8, 177u8, 32u8, 84u8, 101u8, 114u8,
+                    115u8, 32u8, 83u8, 196u8, 177u8, 114u8, 97u8, 108u8, 97u8, 109u8, 97u8, 66u8,
+                    195u8, 188u8, 121u8, 195u8, 188u8, 107u8, 47u8, 75u8, 195u8, 188u8, 195u8,
+                    167u8, 195u8, 188u8, 107u8, 32u8, 72u8, 97u8, 114u8, 102u8, 101u8, 32u8, 68u8,
+                    117u8, 121u8, 97u8, 114u8, 108u8, 196u8, 177u8, 32u8, 79u8, 108u8, 109u8, 97u8,
+                    100u8, 97u8, 110u8, 32u8, 83u8, 196u8, 177u8, 114u8, 97u8, 108u8, 97u8, 109u8,
+                    97u8, 66u8, 195u8, 188u8, 121u8, 195u8, 188u8, 107u8, 47u8, 75u8, 195u8, 188u8,
+                    195u8, 167u8, 195u8, 188u8, 107u8, 32u8, 72u8, 97u8, 114u8, 102u8, 101u8, 32u8,
+                    68u8, 117u8, 121u8, 97u8, 114u8, 108u8, 97u8, 32u8, 83u8, 196u8, 177u8, 114u8,
+                    97u8, 108u8, 97u8, 109u8, 97u8, 78u8, 111u8, 114u8, 109u8, 97u8, 108u8, 32u8,
+                    66u8, 195u8, 188u8, 121u8, 195u8, 188u8, 107u8, 47u8, 75u8, 195u8, 188u8,
+                    195u8, 167u8, 195u8, 188u8, 107u8, 32u8, 72u8, 97u8, 114u8, 102u8, 32u8, 68u8,
+                    195u8, 188u8, 122u8, 101u8, 110u8, 105u8, 110u8, 100u8, 101u8, 32u8, 83u8,
+                    196u8, 177u8, 114u8, 97u8, 108u8, 97u8, 109u8, 97u8, 195u8, 150u8, 110u8, 99u8,
+                    101u8, 32u8, 75u8, 195u8, 188u8, 195u8, 167u8, 195u8, 188u8, 107u8, 32u8, 72u8,
+                    97u8, 114u8, 102u8, 108u8, 101u8, 114u8, 105u8, 32u8, 83u8, 196u8, 177u8,
+                    114u8, 97u8, 108u8, 97u8, 109u8, 97u8, 195u8, 150u8, 110u8, 99u8, 101u8, 32u8,
+                    66u8, 195u8, 188u8, 121u8, 195u8, 188u8, 107u8, 32u8, 72u8, 97u8, 114u8, 102u8,
+                    108u8, 101u8, 114u8, 105u8, 32u8, 83u8, 196u8, 177u8, 114u8, 97u8, 108u8, 97u8,
+                    109u8, 97u8, 78u8, 111u8, 114u8, 109u8, 97u8, 108u8, 108u8, 101u8, 197u8,
+                    159u8, 116u8, 105u8, 114u8, 109u8, 101u8, 32u8, 79u8, 108u8, 109u8, 97u8,
+                    100u8, 97u8, 110u8, 32u8, 83u8, 196u8, 177u8, 114u8, 97u8, 108u8, 97u8, 109u8,
+                    97u8, 85u8, 110u8, 105u8, 99u8, 111u8, 100u8, 101u8, 32u8, 78u8, 111u8, 114u8,
+                    109u8, 97u8, 108u8, 108u8, 101u8, 197u8, 159u8, 116u8, 105u8, 114u8, 105u8,
+                    108u8, 109u8, 105u8, 197u8, 159u8, 32u8, 79u8, 108u8, 97u8, 114u8, 97u8, 107u8,
+                    32u8, 83u8, 196u8, 177u8, 114u8, 97u8, 108u8, 97u8, 109u8, 97u8, 82u8, 97u8,
+                    107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 196u8, 177u8, 32u8, 65u8, 121u8, 114u8,
+                    196u8, 177u8, 32u8, 83u8, 196u8, 177u8, 114u8, 97u8, 108u8, 97u8, 109u8, 97u8,
+                    82u8, 97u8, 107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 196u8, 177u8, 32u8, 83u8,
+                    97u8, 121u8, 196u8, 177u8, 115u8, 97u8, 108u8, 32u8, 79u8, 108u8, 97u8, 114u8,
+                    97u8, 107u8, 32u8, 83u8, 196u8, 177u8, 114u8, 97u8, 108u8, 97u8, 109u8, 97u8,
+                    84u8, 195u8, 188u8, 109u8, 195u8, 188u8, 110u8, 195u8, 188u8, 32u8, 83u8,
+                    196u8, 177u8, 114u8, 97u8, 108u8, 97u8, 109u8, 97u8, 89u8, 97u8, 108u8, 110u8,
+                    196u8, 177u8, 122u8, 99u8, 97u8, 32u8, 84u8, 97u8, 98u8, 97u8, 110u8, 32u8,
+                    72u8, 97u8, 114u8, 102u8, 108u8, 101u8, 114u8, 105u8, 110u8, 105u8, 32u8, 83u8,
+                    196u8, 177u8, 114u8, 97u8, 108u8, 97u8, 109u8, 97u8, 65u8, 107u8, 115u8, 97u8,
+                    110u8, 108u8, 97u8, 114u8, 196u8, 177u8, 32u8, 83u8, 196u8, 177u8, 114u8, 97u8,
+                    108u8, 97u8, 109u8, 97u8, 65u8, 107u8, 115u8, 97u8, 110u8, 108u8, 97u8, 114u8,
+                    196u8, 177u8, 47u8, 66u8, 195u8, 188u8, 121u8, 195u8, 188u8, 107u8, 45u8, 75u8,
+                    195u8, 188u8, 195u8, 167u8, 195u8, 188u8, 107u8, 32u8, 72u8, 97u8, 114u8,
+                    102u8, 108u8, 101u8, 114u8, 105u8, 47u8, 71u8, 101u8, 110u8, 105u8, 197u8,
+                    159u8, 108u8, 105u8, 196u8, 159u8, 105u8, 32u8, 83u8, 196u8, 177u8, 114u8,
+                    97u8, 108u8, 97u8, 109u8, 97u8, 65u8, 107u8, 115u8, 97u8, 110u8, 108u8, 97u8,
+                    114u8, 196u8, 177u8, 47u8, 66u8, 195u8, 188u8, 121u8, 195u8, 188u8, 107u8,
+                    45u8, 75u8, 195u8, 188u8, 195u8, 167u8, 195u8, 188u8, 107u8, 32u8, 72u8, 97u8,
+                    114u8, 102u8, 108u8, 101u8, 114u8, 105u8, 47u8, 71u8, 101u8, 110u8, 105u8,
+                    197u8, 159u8, 108u8, 105u8, 196u8, 159u8, 105u8, 47u8, 75u8, 97u8, 110u8, 97u8,
+                    226u8, 128u8, 153u8, 121u8, 196u8, 177u8, 32u8, 83u8, 196u8, 177u8, 114u8,
+                    97u8, 108u8, 97u8, 109u8, 97u8, 83u8, 101u8, 114u8, 98u8, 101u8, 115u8, 116u8,
+                    32u8, 83u8, 97u8, 116u8, 196u8, 177u8, 114u8, 32u8, 83u8, 111u8, 110u8, 117u8,
+                    32u8, 83u8, 116u8, 105u8, 108u8, 105u8, 78u8, 111u8, 114u8, 109u8, 97u8, 108u8,
+                    32u8, 83u8, 97u8, 116u8, 196u8, 177u8, 114u8, 32u8, 83u8, 111u8, 110u8, 117u8,
+                    32u8, 83u8, 116u8, 105u8, 108u8, 105u8, 75u8, 97u8, 116u8, 196u8, 177u8, 32u8,
+                    83u8, 97u8, 116u8, 196u8, 177u8, 114u8, 32u8, 83u8, 111u8, 110u8, 117u8, 32u8,
+                    83u8, 116u8, 105u8, 108u8, 105u8, 77u8, 101u8, 116u8, 114u8, 105u8, 107u8,
+                    32u8, 83u8, 105u8, 115u8, 116u8, 101u8, 109u8, 196u8, 176u8, 110u8, 103u8,
+                    105u8, 108u8, 105u8, 122u8, 32u8, 195u8, 150u8, 108u8, 195u8, 167u8, 195u8,
+                    188u8, 32u8, 83u8, 105u8, 115u8, 116u8, 101u8, 109u8, 105u8, 65u8, 66u8, 68u8,
+                    32u8, 195u8, 150u8, 108u8, 195u8, 167u8, 195u8, 188u8, 32u8, 83u8, 105u8,
+                    115u8, 116u8, 101u8, 109u8, 105u8, 65u8, 104u8, 111u8, 109u8, 32u8, 82u8, 97u8,
+                    107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 196u8, 177u8, 72u8, 105u8, 110u8,
+                    116u8, 45u8, 65u8, 114u8, 97u8, 112u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8,
+                    108u8, 97u8, 114u8, 196u8, 177u8, 71u8, 101u8, 110u8, 105u8, 197u8, 159u8,
+                    108u8, 101u8, 116u8, 105u8, 108u8, 109u8, 105u8, 197u8, 159u8, 32u8, 72u8,
+                    105u8, 110u8, 116u8, 45u8, 65u8, 114u8, 97u8, 112u8, 32u8, 82u8, 97u8, 107u8,
+                    97u8, 109u8, 108u8, 97u8, 114u8, 196u8, 177u8, 69u8, 114u8, 109u8, 101u8,
+                    110u8, 105u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 196u8,
+                    177u8, 75u8, 195u8, 188u8, 195u8, 167u8, 195u8, 188u8, 107u8, 32u8, 72u8, 97u8,
+                    114u8, 102u8, 32u8, 69u8, 114u8, 109u8, 101u8, 110u8, 105u8, 32u8, 82u8, 97u8,
+                    107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 196u8, 177u8, 66u8, 97u8, 108u8, 105u8,
+                    32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 196u8, 177u8, 66u8,
+                    101u8, 110u8, 103u8, 97u8, 108u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8,
+                    97u8, 114u8, 196u8, 177u8, 66u8, 114u8, 97u8, 104u8, 109u8, 105u8, 32u8, 82u8,
+                    97u8, 107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 196u8, 177u8, 67u8, 104u8, 97u8,
+                    107u8, 109u8, 97u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8, 97u8, 114u8,
+                    196u8, 177u8, 67u8, 104u8, 97u8, 109u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8,
+                    108u8, 97u8, 114u8, 196u8, 177u8, 75u8, 105u8, 114u8, 105u8, 108u8, 32u8, 82u8,
+                    97u8, 107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 196u8, 177u8, 68u8, 101u8, 118u8,
+                    97u8, 110u8, 97u8, 103u8, 97u8, 114u8, 105u8, 32u8, 82u8, 97u8, 107u8, 97u8,
+                    109u8, 108u8, 97u8, 114u8, 196u8, 177u8, 71u8, 101u8, 226u8, 128u8, 153u8,
+                    101u8, 122u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 196u8,
+                    177u8, 70u8, 105u8, 110u8, 97u8, 110u8, 115u8, 97u8, 108u8, 32u8, 83u8, 97u8,
+                    121u8, 196u8, 177u8, 108u8, 97u8, 114u8, 84u8, 97u8, 109u8, 32u8, 71u8, 101u8,
+                    110u8, 105u8, 197u8, 159u8, 108u8, 105u8, 107u8, 116u8, 101u8, 32u8, 82u8,
+                    97u8, 107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 71u8, 195u8, 188u8, 114u8, 99u8,
+                    195u8, 188u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 196u8,
+                    177u8, 77u8, 97u8, 115u8, 97u8, 114u8, 97u8, 109u8, 32u8, 71u8, 111u8, 110u8,
+                    100u8, 105u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 196u8,
+                    177u8, 89u8, 117u8, 110u8, 97u8, 110u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8,
+                    108u8, 97u8, 114u8, 196u8, 177u8, 75u8, 195u8, 188u8, 195u8, 167u8, 195u8,
+                    188u8, 107u8, 32u8, 72u8, 97u8, 114u8, 102u8, 32u8, 89u8, 117u8, 110u8, 97u8,
+                    110u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 196u8, 177u8,
+                    71u8, 195u8, 188u8, 99u8, 101u8, 114u8, 97u8, 116u8, 32u8, 82u8, 97u8, 107u8,
+                    97u8, 109u8, 108u8, 97u8, 114u8, 196u8, 177u8, 71u8, 117u8, 114u8, 109u8,
+                    117u8, 107u8, 104u8, 105u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8, 97u8,
+                    114u8, 196u8, 177u8, 195u8, 135u8, 105u8, 110u8, 32u8, 79u8, 110u8, 100u8,
+                    97u8, 108u8, 196u8, 177u8, 107u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8,
+                    97u8, 114u8, 196u8, 177u8, 66u8, 97u8, 115u8, 105u8, 116u8, 108u8, 101u8,
+                    197u8, 159u8, 116u8, 105u8, 114u8, 105u8, 108u8, 109u8, 105u8, 197u8, 159u8,
+                    32u8, 195u8, 135u8, 105u8, 110u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8,
+                    97u8, 114u8, 196u8, 177u8, 70u8, 105u8, 110u8, 97u8, 110u8, 115u8, 97u8, 108u8,
+                    32u8, 66u8, 97u8, 115u8, 105u8, 116u8, 108u8, 101u8, 197u8, 159u8, 116u8,
+                    105u8, 114u8, 105u8, 108u8, 109u8, 105u8, 197u8, 159u8, 32u8, 195u8, 135u8,
+                    105u8, 110u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 196u8,
+                    177u8, 71u8, 101u8, 108u8, 101u8, 110u8, 101u8, 107u8, 115u8, 101u8, 108u8,
+                    32u8, 195u8, 135u8, 105u8, 110u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8,
+                    97u8, 114u8, 196u8, 177u8, 70u8, 105u8, 110u8, 97u8, 110u8, 115u8, 97u8, 108u8,
+                    32u8, 71u8, 101u8, 108u8, 101u8, 110u8, 101u8, 107u8, 115u8, 101u8, 108u8,
+                    32u8, 195u8, 135u8, 105u8, 110u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8,
+                    97u8, 114u8, 196u8, 177u8, 196u8, 176u8, 98u8, 114u8, 97u8, 110u8, 105u8, 32u8,
+                    82u8, 97u8, 107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 196u8, 177u8, 80u8, 97u8,
+                    104u8, 97u8, 119u8, 104u8, 32u8, 72u8, 109u8, 111u8, 110u8, 103u8, 32u8, 82u8,
+                    97u8, 107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 196u8, 177u8, 67u8, 97u8, 118u8,
+                    97u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 196u8, 177u8,
+                    74u8, 97u8, 112u8, 111u8, 110u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8,
+                    97u8, 114u8, 196u8, 177u8, 70u8, 105u8, 110u8, 97u8, 110u8, 115u8, 97u8, 108u8,
+                    32u8, 74u8, 97u8, 112u8, 111u8, 110u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8,
+                    108u8, 97u8, 114u8, 196u8, 177u8, 75u8, 97u8, 121u8, 97u8, 104u8, 32u8, 76u8,
+                    105u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 196u8, 177u8,
+                    75u8, 109u8, 101u8, 114u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8, 97u8,
+                    114u8, 196u8, 177u8, 75u8, 97u8, 110u8, 110u8, 97u8, 100u8, 97u8, 32u8, 82u8,
+                    97u8, 107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 196u8, 177u8, 84u8, 97u8, 105u8,
+                    32u8, 84u8, 104u8, 97u8, 109u8, 32u8, 72u8, 111u8, 114u8, 97u8, 32u8, 82u8,
+                    97u8, 107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 196u8, 177u8, 84u8, 97u8, 105u8,
+                    32u8, 84u8, 104u8, 97u8, 109u8, 32u8, 84u8, 104u8, 97u8, 109u8, 32u8, 82u8,
+                    97u8, 107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 196u8, 177u8, 76u8, 97u8, 111u8,
+                    32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 196u8, 177u8, 66u8,
+                    97u8, 116u8, 196u8, 177u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8, 97u8,
+                    114u8, 196u8, 177u8, 76u8, 101u8, 112u8, 195u8, 167u8, 97u8, 32u8, 82u8, 97u8,
+                    107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 196u8, 177u8, 76u8, 105u8, 109u8, 98u8,
+                    117u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 196u8, 177u8,
+                    75u8, 97u8, 108u8, 196u8, 177u8, 110u8, 32u8, 77u8, 97u8, 116u8, 101u8, 109u8,
+                    97u8, 116u8, 105u8, 107u8, 115u8, 101u8, 108u8, 32u8, 82u8, 97u8, 107u8, 97u8,
+                    109u8, 108u8, 97u8, 114u8, 195u8, 135u8, 105u8, 102u8, 116u8, 32u8, 195u8,
+                    135u8, 105u8, 122u8, 103u8, 105u8, 108u8, 105u8, 32u8, 77u8, 97u8, 116u8,
+                    101u8, 109u8, 97u8, 116u8, 105u8, 107u8, 115u8, 101u8, 108u8, 32u8, 82u8, 97u8,
+                    107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 69u8, 197u8, 159u8, 105u8, 116u8, 32u8,
+                    65u8, 114u8, 97u8, 108u8, 196u8, 177u8, 107u8, 108u8, 196u8, 177u8, 32u8, 77u8,
+                    97u8, 116u8, 101u8, 109u8, 97u8, 116u8, 105u8, 107u8, 115u8, 101u8, 108u8,
+                    32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 75u8, 97u8, 108u8,
+                    196u8, 177u8, 110u8, 32u8, 83u8, 97u8, 110u8, 115u8, 32u8, 83u8, 101u8, 114u8,
+                    105u8, 102u8, 32u8, 77u8, 97u8, 116u8, 101u8, 109u8, 97u8, 116u8, 105u8, 107u8,
+                    115u8, 101u8, 108u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8, 97u8, 114u8,
+                    83u8, 97u8, 110u8, 115u8, 32u8, 83u8, 101u8, 114u8, 105u8, 102u8, 32u8, 77u8,
+                    97u8, 116u8, 101u8, 109u8, 97u8, 116u8, 105u8, 107u8, 115u8, 101u8, 108u8,
+                    32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 77u8, 97u8, 108u8,
+                    97u8, 121u8, 97u8, 108u8, 97u8, 109u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8,
+                    108u8, 97u8, 114u8, 196u8, 177u8, 77u8, 111u8, 100u8, 105u8, 32u8, 82u8, 97u8,
+                    107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 196u8, 177u8, 77u8, 111u8, 196u8,
+                    159u8, 111u8, 108u8, 99u8, 97u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8,
+                    97u8, 114u8, 77u8, 114u8, 111u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8,
+                    97u8, 114u8, 196u8, 177u8, 77u8, 101u8, 101u8, 116u8, 101u8, 105u8, 32u8, 77u8,
+                    97u8, 121u8, 101u8, 107u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8, 97u8,
+                    114u8, 196u8, 177u8, 77u8, 121u8, 97u8, 110u8, 109u8, 97u8, 114u8, 32u8, 82u8,
+                    97u8, 107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 196u8, 177u8, 77u8, 121u8, 97u8,
+                    110u8, 109u8, 97u8, 114u8, 32u8, 83u8, 104u8, 97u8, 110u8, 32u8, 82u8, 97u8,
+                    107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 196u8, 177u8, 77u8, 121u8, 97u8, 110u8,
+                    109u8, 97u8, 114u8, 32u8, 84u8, 97u8, 105u8, 32u8, 76u8, 97u8, 105u8, 110u8,
+                    103u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 196u8, 177u8,
+                    89u8, 101u8, 114u8, 101u8, 108u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8,
+                    97u8, 114u8, 78u8, 226u8, 128u8, 153u8, 75u8, 111u8, 32u8, 82u8, 97u8, 107u8,
+                    97u8, 109u8, 108u8, 97u8, 114u8, 196u8, 177u8, 79u8, 108u8, 32u8, 67u8, 104u8,
+                    105u8, 107u8, 105u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8, 97u8, 114u8,
+                    196u8, 177u8, 79u8, 114u8, 105u8, 121u8, 97u8, 32u8, 82u8, 97u8, 107u8, 97u8,
+                    109u8, 108u8, 97u8, 114u8, 196u8, 177u8, 79u8, 115u8, 109u8, 97u8, 110u8,
+                    121u8, 97u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 196u8,
+                    177u8, 82u8, 111u8, 109u8, 97u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8,
+                    97u8, 114u8, 196u8, 177u8, 75u8, 195u8, 188u8, 195u8, 167u8, 195u8, 188u8,
+                    107u8, 32u8, 72u8, 97u8, 114u8, 102u8, 32u8, 82u8, 111u8, 109u8, 97u8, 32u8,
+                    82u8, 97u8, 107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 196u8, 177u8, 83u8, 97u8,
+                    117u8, 114u8, 97u8, 115u8, 104u8, 116u8, 114u8, 97u8, 32u8, 82u8, 97u8, 107u8,
+                    97u8, 109u8, 108u8, 97u8, 114u8, 196u8, 177u8, 83u8, 104u8, 97u8, 114u8, 97u8,
+                    100u8, 97u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 196u8,
+                    177u8, 75u8, 104u8, 117u8, 100u8, 97u8, 119u8, 97u8, 100u8, 105u8, 32u8, 82u8,
+                    97u8, 107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 196u8, 177u8, 83u8, 105u8, 110u8,
+                    104u8, 97u8, 108u8, 97u8, 32u8, 76u8, 105u8, 116u8, 104u8, 32u8, 82u8, 97u8,
+                    107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 196u8, 177u8, 83u8, 111u8, 114u8, 97u8,
+                    32u8, 83u8, 111u8, 109u8, 112u8, 101u8, 110u8, 103u8, 32u8, 82u8, 97u8, 107u8,
+                    97u8, 109u8, 108u8, 97u8, 114u8, 196u8, 177u8, 83u8, 117u8, 110u8, 100u8, 97u8,
+                    32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 196u8, 177u8, 84u8,
+                    97u8, 107u8, 114u8, 105u8, 32u8, 66u8, 97u8, 115u8, 97u8, 109u8, 97u8, 107u8,
+                    108u8, 97u8, 114u8, 196u8, 177u8, 78u8, 101u8, 119u8, 32u8, 84u8, 97u8, 105u8,
+                    32u8, 76u8, 117u8, 101u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8, 97u8,
+                    114u8, 196u8, 177u8, 71u8, 101u8, 108u8, 101u8, 110u8, 101u8, 107u8, 115u8,
+                    101u8, 108u8, 32u8, 84u8, 97u8, 109u8, 105u8, 108u8, 32u8, 82u8, 97u8, 107u8,
+                    97u8, 109u8, 108u8, 97u8, 114u8, 196u8, 177u8, 84u8, 97u8, 109u8, 105u8, 108u8,
+                    32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 196u8, 177u8, 84u8,
+                    101u8, 108u8, 117u8, 103u8, 117u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8,
+                    97u8, 114u8, 196u8, 177u8, 84u8, 97u8, 121u8, 32u8, 82u8, 97u8, 107u8, 97u8,
+                    109u8, 108u8, 97u8, 114u8, 196u8, 177u8, 84u8, 105u8, 98u8, 101u8, 116u8, 32u8,
+                    82u8, 97u8, 107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 196u8, 177u8, 84u8, 105u8,
+                    114u8, 104u8, 117u8, 116u8, 97u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8,
+                    97u8, 114u8, 196u8, 177u8, 71u8, 101u8, 108u8, 101u8, 110u8, 101u8, 107u8,
+                    115u8, 101u8, 108u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8, 97u8, 114u8,
+                    86u8, 97u8, 105u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8, 97u8, 114u8,
+                    196u8, 177u8, 87u8, 97u8, 114u8, 97u8, 110u8, 103u8, 32u8, 67u8, 105u8, 116u8,
+                    105u8, 32u8, 82u8, 97u8, 107u8, 97u8, 109u8, 108u8, 97u8, 114u8, 196u8, 177u8,
+                ])
+            },
+        )
+    },
+};
+static UND: &DataStruct = &::icu_displaynames::provider::KeyDisplayNamesV1 {
+    keys: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    types: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+};
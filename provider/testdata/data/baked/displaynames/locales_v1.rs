@@ -0,0 +1,1668 @@
+// @generated
+#![cfg(feature = "icu_displaynames")]
+type DataStruct = < :: icu_displaynames :: provider :: LocaleDisplayNamesV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[
+        ("ar", AR_AR_EG),
+        ("ar-EG", AR_AR_EG),
+        ("bn", BN),
+        ("ccp", CCP),
+        ("en", EN),
+        ("en-001", EN_001_EN_ZA),
+        ("en-ZA", EN_001_EN_ZA),
+        ("es", ES_ES_AR),
+        ("es-AR", ES_ES_AR),
+        ("fil", FIL),
+        ("fr", FR),
+        ("ja", JA),
+        ("ru", RU),
+        ("sr", SR_SR_CYRL),
+        ("sr-Cyrl", SR_SR_CYRL),
+        ("sr-Latn", SR_LATN),
+        ("th", TH),
+        ("tr", TR),
+        ("und", UND),
+    ]);
+static AR_AR_EG: &DataStruct = &::icu_displaynames::provider::LocaleDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    21u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 11u8, 0u8, 16u8, 0u8, 21u8, 0u8, 26u8,
+                    0u8, 31u8, 0u8, 36u8, 0u8, 42u8, 0u8, 47u8, 0u8, 52u8, 0u8, 57u8, 0u8, 62u8,
+                    0u8, 67u8, 0u8, 73u8, 0u8, 78u8, 0u8, 83u8, 0u8, 88u8, 0u8, 93u8, 0u8, 98u8,
+                    0u8, 105u8, 0u8, 97u8, 114u8, 45u8, 48u8, 48u8, 49u8, 100u8, 101u8, 45u8, 65u8,
+                    84u8, 100u8, 101u8, 45u8, 67u8, 72u8, 101u8, 110u8, 45u8, 65u8, 85u8, 101u8,
+                    110u8, 45u8, 67u8, 65u8, 101u8, 110u8, 45u8, 71u8, 66u8, 101u8, 110u8, 45u8,
+                    85u8, 83u8, 101u8, 115u8, 45u8, 52u8, 49u8, 57u8, 101u8, 115u8, 45u8, 69u8,
+                    83u8, 101u8, 115u8, 45u8, 77u8, 88u8, 102u8, 97u8, 45u8, 65u8, 70u8, 102u8,
+                    114u8, 45u8, 67u8, 65u8, 102u8, 114u8, 45u8, 67u8, 72u8, 110u8, 100u8, 115u8,
+                    45u8, 78u8, 76u8, 110u8, 108u8, 45u8, 66u8, 69u8, 112u8, 116u8, 45u8, 66u8,
+                    82u8, 112u8, 116u8, 45u8, 80u8, 84u8, 114u8, 111u8, 45u8, 77u8, 68u8, 115u8,
+                    119u8, 45u8, 67u8, 68u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8, 115u8, 122u8,
+                    104u8, 45u8, 72u8, 97u8, 110u8, 116u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    21u8, 0u8, 0u8, 0u8, 0u8, 0u8, 42u8, 0u8, 79u8, 0u8, 129u8, 0u8, 170u8, 0u8,
+                    205u8, 0u8, 246u8, 0u8, 29u8, 1u8, 79u8, 1u8, 116u8, 1u8, 153u8, 1u8, 167u8,
+                    1u8, 198u8, 1u8, 233u8, 1u8, 8u8, 2u8, 26u8, 2u8, 67u8, 2u8, 106u8, 2u8, 126u8,
+                    2u8, 159u8, 2u8, 188u8, 2u8, 216u8, 167u8, 217u8, 132u8, 216u8, 185u8, 216u8,
+                    177u8, 216u8, 168u8, 217u8, 138u8, 216u8, 169u8, 32u8, 216u8, 167u8, 217u8,
+                    132u8, 217u8, 129u8, 216u8, 181u8, 216u8, 173u8, 217u8, 137u8, 32u8, 216u8,
+                    167u8, 217u8, 132u8, 216u8, 173u8, 216u8, 175u8, 217u8, 138u8, 216u8, 171u8,
+                    216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 217u8, 132u8, 217u8,
+                    133u8, 216u8, 167u8, 217u8, 134u8, 217u8, 138u8, 216u8, 169u8, 32u8, 216u8,
+                    167u8, 217u8, 132u8, 217u8, 134u8, 217u8, 133u8, 216u8, 179u8, 216u8, 167u8,
+                    217u8, 136u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8,
+                    163u8, 217u8, 132u8, 217u8, 133u8, 216u8, 167u8, 217u8, 134u8, 217u8, 138u8,
+                    216u8, 169u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 185u8, 217u8, 132u8,
+                    217u8, 138u8, 216u8, 167u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 179u8,
+                    217u8, 136u8, 217u8, 138u8, 216u8, 179u8, 216u8, 177u8, 217u8, 138u8, 216u8,
+                    169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 165u8, 217u8, 134u8, 216u8, 172u8,
+                    217u8, 132u8, 217u8, 138u8, 216u8, 178u8, 217u8, 138u8, 216u8, 169u8, 32u8,
+                    216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 216u8, 179u8, 216u8, 170u8, 216u8,
+                    177u8, 216u8, 167u8, 217u8, 132u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8,
+                    217u8, 132u8, 216u8, 165u8, 217u8, 134u8, 216u8, 172u8, 217u8, 132u8, 217u8,
+                    138u8, 216u8, 178u8, 217u8, 138u8, 216u8, 169u8, 32u8, 216u8, 167u8, 217u8,
+                    132u8, 217u8, 131u8, 217u8, 134u8, 216u8, 175u8, 217u8, 138u8, 216u8, 169u8,
+                    216u8, 167u8, 217u8, 132u8, 216u8, 165u8, 217u8, 134u8, 216u8, 172u8, 217u8,
+                    132u8, 217u8, 138u8, 216u8, 178u8, 217u8, 138u8, 216u8, 169u8, 32u8, 216u8,
+                    167u8, 217u8, 132u8, 216u8, 168u8, 216u8, 177u8, 217u8, 138u8, 216u8, 183u8,
+                    216u8, 167u8, 217u8, 134u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8,
+                    132u8, 216u8, 165u8, 217u8, 134u8, 216u8, 172u8, 217u8, 132u8, 217u8, 138u8,
+                    216u8, 178u8, 217u8, 138u8, 216u8, 169u8, 32u8, 216u8, 167u8, 217u8, 132u8,
+                    216u8, 163u8, 217u8, 133u8, 216u8, 177u8, 217u8, 138u8, 217u8, 131u8, 217u8,
+                    138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 165u8, 216u8, 179u8,
+                    216u8, 168u8, 216u8, 167u8, 217u8, 134u8, 217u8, 138u8, 216u8, 169u8, 32u8,
+                    216u8, 163u8, 217u8, 133u8, 216u8, 177u8, 217u8, 138u8, 217u8, 131u8, 216u8,
+                    167u8, 32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 132u8, 216u8, 167u8, 216u8,
+                    170u8, 217u8, 138u8, 217u8, 134u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8,
+                    217u8, 132u8, 216u8, 165u8, 216u8, 179u8, 216u8, 168u8, 216u8, 167u8, 217u8,
+                    134u8, 217u8, 138u8, 216u8, 169u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8,
+                    163u8, 217u8, 136u8, 216u8, 177u8, 217u8, 136u8, 216u8, 168u8, 217u8, 138u8,
+                    216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 165u8, 216u8, 179u8, 216u8,
+                    168u8, 216u8, 167u8, 217u8, 134u8, 217u8, 138u8, 216u8, 169u8, 32u8, 216u8,
+                    167u8, 217u8, 132u8, 217u8, 133u8, 217u8, 131u8, 216u8, 179u8, 217u8, 138u8,
+                    217u8, 131u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8,
+                    175u8, 216u8, 167u8, 216u8, 177u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8,
+                    217u8, 132u8, 217u8, 129u8, 216u8, 177u8, 217u8, 134u8, 216u8, 179u8, 217u8,
+                    138u8, 216u8, 169u8, 32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 131u8, 217u8,
+                    134u8, 216u8, 175u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8,
+                    217u8, 129u8, 216u8, 177u8, 217u8, 134u8, 216u8, 179u8, 217u8, 138u8, 216u8,
+                    169u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 179u8, 217u8, 136u8, 217u8,
+                    138u8, 216u8, 179u8, 216u8, 177u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8,
+                    217u8, 132u8, 216u8, 179u8, 217u8, 131u8, 216u8, 179u8, 217u8, 136u8, 217u8,
+                    134u8, 217u8, 138u8, 216u8, 169u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8,
+                    179u8, 217u8, 129u8, 217u8, 132u8, 217u8, 137u8, 216u8, 167u8, 217u8, 132u8,
+                    217u8, 129u8, 217u8, 132u8, 217u8, 133u8, 217u8, 134u8, 217u8, 131u8, 217u8,
+                    138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 168u8, 216u8, 177u8,
+                    216u8, 170u8, 216u8, 186u8, 216u8, 167u8, 217u8, 132u8, 217u8, 138u8, 216u8,
+                    169u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 168u8, 216u8, 177u8, 216u8,
+                    167u8, 216u8, 178u8, 217u8, 138u8, 217u8, 132u8, 217u8, 138u8, 216u8, 169u8,
+                    216u8, 167u8, 217u8, 132u8, 216u8, 168u8, 216u8, 177u8, 216u8, 170u8, 216u8,
+                    186u8, 216u8, 167u8, 217u8, 132u8, 217u8, 138u8, 216u8, 169u8, 32u8, 216u8,
+                    167u8, 217u8, 132u8, 216u8, 163u8, 217u8, 136u8, 216u8, 177u8, 217u8, 136u8,
+                    216u8, 168u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 217u8,
+                    133u8, 217u8, 136u8, 217u8, 132u8, 216u8, 175u8, 217u8, 136u8, 217u8, 129u8,
+                    217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 217u8, 131u8, 217u8,
+                    136u8, 217u8, 134u8, 216u8, 186u8, 217u8, 136u8, 32u8, 216u8, 167u8, 217u8,
+                    132u8, 216u8, 179u8, 217u8, 136u8, 216u8, 167u8, 216u8, 173u8, 217u8, 132u8,
+                    217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 181u8, 217u8,
+                    138u8, 217u8, 134u8, 217u8, 138u8, 216u8, 169u8, 32u8, 216u8, 167u8, 217u8,
+                    132u8, 217u8, 133u8, 216u8, 168u8, 216u8, 179u8, 216u8, 183u8, 216u8, 169u8,
+                    216u8, 167u8, 217u8, 132u8, 216u8, 181u8, 217u8, 138u8, 217u8, 134u8, 217u8,
+                    138u8, 216u8, 169u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 170u8, 217u8,
+                    130u8, 217u8, 132u8, 217u8, 138u8, 216u8, 175u8, 217u8, 138u8, 216u8, 169u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 101u8, 110u8, 45u8, 71u8, 66u8, 101u8,
+                    110u8, 45u8, 85u8, 83u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 50u8, 0u8, 216u8, 167u8, 217u8, 132u8, 216u8,
+                    165u8, 217u8, 134u8, 216u8, 172u8, 217u8, 132u8, 217u8, 138u8, 216u8, 178u8,
+                    217u8, 138u8, 216u8, 169u8, 32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 133u8,
+                    217u8, 133u8, 217u8, 132u8, 217u8, 131u8, 216u8, 169u8, 32u8, 216u8, 167u8,
+                    217u8, 132u8, 217u8, 133u8, 216u8, 170u8, 216u8, 173u8, 216u8, 175u8, 216u8,
+                    169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 165u8, 217u8, 134u8, 216u8, 172u8,
+                    217u8, 132u8, 217u8, 138u8, 216u8, 178u8, 217u8, 138u8, 216u8, 169u8, 32u8,
+                    216u8, 167u8, 217u8, 132u8, 217u8, 136u8, 217u8, 132u8, 216u8, 167u8, 217u8,
+                    138u8, 216u8, 167u8, 216u8, 170u8, 32u8, 216u8, 167u8, 217u8, 132u8, 217u8,
+                    133u8, 216u8, 170u8, 216u8, 173u8, 216u8, 175u8, 216u8, 169u8,
+                ])
+            },
+        )
+    },
+    long_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+};
+static BN: &DataStruct = &::icu_displaynames::provider::LocaleDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    9u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 11u8, 0u8, 16u8, 0u8, 21u8, 0u8, 27u8,
+                    0u8, 32u8, 0u8, 37u8, 0u8, 42u8, 0u8, 97u8, 114u8, 45u8, 48u8, 48u8, 49u8,
+                    101u8, 110u8, 45u8, 85u8, 83u8, 101u8, 115u8, 45u8, 69u8, 83u8, 102u8, 97u8,
+                    45u8, 65u8, 70u8, 110u8, 100u8, 115u8, 45u8, 78u8, 76u8, 110u8, 108u8, 45u8,
+                    66u8, 69u8, 112u8, 116u8, 45u8, 80u8, 84u8, 114u8, 111u8, 45u8, 77u8, 68u8,
+                    115u8, 119u8, 45u8, 67u8, 68u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    9u8, 0u8, 0u8, 0u8, 0u8, 0u8, 47u8, 0u8, 89u8, 0u8, 134u8, 0u8, 146u8, 0u8,
+                    177u8, 0u8, 198u8, 0u8, 240u8, 0u8, 8u8, 1u8, 224u8, 166u8, 134u8, 224u8,
+                    166u8, 167u8, 224u8, 167u8, 129u8, 224u8, 166u8, 168u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 149u8, 32u8, 224u8, 166u8, 134u8, 224u8, 166u8, 166u8, 224u8,
+                    166u8, 176u8, 224u8, 167u8, 141u8, 224u8, 166u8, 182u8, 32u8, 224u8, 166u8,
+                    134u8, 224u8, 166u8, 176u8, 224u8, 166u8, 172u8, 224u8, 167u8, 128u8, 224u8,
+                    166u8, 135u8, 224u8, 166u8, 130u8, 224u8, 166u8, 176u8, 224u8, 167u8, 135u8,
+                    224u8, 166u8, 156u8, 224u8, 166u8, 191u8, 32u8, 40u8, 224u8, 166u8, 134u8,
+                    224u8, 166u8, 174u8, 224u8, 167u8, 135u8, 224u8, 166u8, 176u8, 224u8, 166u8,
+                    191u8, 224u8, 166u8, 149u8, 224u8, 166u8, 190u8, 41u8, 224u8, 166u8, 184u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 170u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                    175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 224u8, 166u8, 191u8, 224u8,
+                    166u8, 182u8, 32u8, 40u8, 224u8, 166u8, 135u8, 224u8, 166u8, 137u8, 224u8,
+                    166u8, 176u8, 224u8, 167u8, 139u8, 224u8, 166u8, 170u8, 41u8, 224u8, 166u8,
+                    166u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 166u8, 191u8, 224u8,
+                    166u8, 178u8, 224u8, 167u8, 139u8, 32u8, 224u8, 166u8, 184u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 149u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 184u8, 224u8, 166u8, 168u8, 224u8, 166u8, 171u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 178u8, 224u8, 167u8, 135u8, 224u8, 166u8,
+                    174u8, 224u8, 166u8, 191u8, 224u8, 166u8, 182u8, 224u8, 166u8, 170u8, 224u8,
+                    166u8, 176u8, 224u8, 167u8, 141u8, 224u8, 166u8, 164u8, 224u8, 167u8, 129u8,
+                    224u8, 166u8, 151u8, 224u8, 167u8, 128u8, 224u8, 166u8, 156u8, 32u8, 40u8,
+                    224u8, 166u8, 135u8, 224u8, 166u8, 137u8, 224u8, 166u8, 176u8, 224u8, 167u8,
+                    139u8, 224u8, 166u8, 170u8, 41u8, 224u8, 166u8, 174u8, 224u8, 166u8, 178u8,
+                    224u8, 166u8, 166u8, 224u8, 166u8, 190u8, 224u8, 166u8, 173u8, 224u8, 166u8,
+                    191u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 224u8, 166u8, 149u8, 224u8,
+                    166u8, 153u8, 224u8, 167u8, 141u8, 224u8, 166u8, 151u8, 224u8, 167u8, 139u8,
+                    32u8, 224u8, 166u8, 184u8, 224u8, 167u8, 139u8, 224u8, 166u8, 175u8, 224u8,
+                    166u8, 188u8, 224u8, 166u8, 190u8, 224u8, 166u8, 185u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 178u8, 224u8, 166u8, 191u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 101u8, 110u8, 45u8, 71u8, 66u8, 101u8,
+                    110u8, 45u8, 85u8, 83u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 51u8, 0u8, 224u8, 166u8, 135u8, 224u8, 166u8,
+                    130u8, 224u8, 166u8, 176u8, 224u8, 167u8, 135u8, 224u8, 166u8, 156u8, 224u8,
+                    166u8, 191u8, 32u8, 40u8, 224u8, 166u8, 175u8, 224u8, 167u8, 129u8, 224u8,
+                    166u8, 149u8, 224u8, 167u8, 141u8, 224u8, 166u8, 164u8, 224u8, 166u8, 176u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 156u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                    175u8, 41u8, 224u8, 166u8, 135u8, 224u8, 166u8, 130u8, 224u8, 166u8, 176u8,
+                    224u8, 167u8, 135u8, 224u8, 166u8, 156u8, 224u8, 166u8, 191u8, 32u8, 40u8,
+                    224u8, 166u8, 175u8, 224u8, 167u8, 129u8, 224u8, 166u8, 149u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 164u8, 224u8, 166u8, 176u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 183u8, 224u8, 167u8, 141u8, 224u8, 166u8, 159u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 176u8, 41u8,
+                ])
+            },
+        )
+    },
+    long_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8,
+                    115u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8, 116u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 68u8, 0u8, 224u8, 166u8, 154u8, 224u8, 167u8,
+                    128u8, 224u8, 166u8, 168u8, 224u8, 166u8, 190u8, 32u8, 224u8, 166u8, 174u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    168u8, 224u8, 167u8, 141u8, 224u8, 166u8, 161u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 176u8, 224u8, 166u8, 191u8, 224u8, 166u8, 168u8, 32u8, 224u8, 166u8,
+                    184u8, 224u8, 166u8, 176u8, 224u8, 166u8, 178u8, 224u8, 167u8, 128u8, 224u8,
+                    166u8, 149u8, 224u8, 167u8, 131u8, 224u8, 166u8, 164u8, 224u8, 166u8, 174u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    168u8, 224u8, 167u8, 141u8, 224u8, 166u8, 161u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 176u8, 224u8, 166u8, 191u8, 224u8, 166u8, 168u8, 32u8, 224u8, 166u8,
+                    154u8, 224u8, 167u8, 128u8, 224u8, 166u8, 168u8, 224u8, 166u8, 190u8, 32u8,
+                    40u8, 224u8, 166u8, 144u8, 224u8, 166u8, 164u8, 224u8, 166u8, 191u8, 224u8,
+                    166u8, 185u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 172u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 185u8, 224u8, 167u8, 128u8, 41u8,
+                ])
+            },
+        )
+    },
+};
+static CCP: &DataStruct = &::icu_displaynames::provider::LocaleDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    20u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 11u8, 0u8, 16u8, 0u8, 21u8, 0u8, 26u8,
+                    0u8, 31u8, 0u8, 36u8, 0u8, 42u8, 0u8, 47u8, 0u8, 52u8, 0u8, 57u8, 0u8, 62u8,
+                    0u8, 68u8, 0u8, 73u8, 0u8, 78u8, 0u8, 83u8, 0u8, 88u8, 0u8, 93u8, 0u8, 100u8,
+                    0u8, 97u8, 114u8, 45u8, 48u8, 48u8, 49u8, 100u8, 101u8, 45u8, 65u8, 84u8,
+                    100u8, 101u8, 45u8, 67u8, 72u8, 101u8, 110u8, 45u8, 65u8, 85u8, 101u8, 110u8,
+                    45u8, 67u8, 65u8, 101u8, 110u8, 45u8, 71u8, 66u8, 101u8, 110u8, 45u8, 85u8,
+                    83u8, 101u8, 115u8, 45u8, 52u8, 49u8, 57u8, 101u8, 115u8, 45u8, 69u8, 83u8,
+                    101u8, 115u8, 45u8, 77u8, 88u8, 102u8, 114u8, 45u8, 67u8, 65u8, 102u8, 114u8,
+                    45u8, 67u8, 72u8, 110u8, 100u8, 115u8, 45u8, 78u8, 76u8, 110u8, 108u8, 45u8,
+                    66u8, 69u8, 112u8, 116u8, 45u8, 66u8, 82u8, 112u8, 116u8, 45u8, 80u8, 84u8,
+                    114u8, 111u8, 45u8, 77u8, 68u8, 115u8, 119u8, 45u8, 67u8, 68u8, 122u8, 104u8,
+                    45u8, 72u8, 97u8, 110u8, 115u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8, 116u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    20u8, 0u8, 0u8, 0u8, 0u8, 0u8, 46u8, 0u8, 115u8, 0u8, 173u8, 0u8, 250u8, 0u8,
+                    47u8, 1u8, 108u8, 1u8, 165u8, 1u8, 7u8, 2u8, 76u8, 2u8, 153u8, 2u8, 198u8, 2u8,
+                    243u8, 2u8, 31u8, 3u8, 63u8, 3u8, 140u8, 3u8, 221u8, 3u8, 1u8, 4u8, 50u8, 4u8,
+                    95u8, 4u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 177u8, 32u8, 240u8,
+                    145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 159u8,
+                    240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8,
+                    132u8, 162u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 157u8, 240u8,
+                    145u8, 132u8, 169u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 167u8,
+                    240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8,
+                    145u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8,
+                    132u8, 168u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 154u8, 240u8,
+                    145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 142u8, 240u8, 145u8, 132u8,
+                    162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8,
+                    132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 165u8, 240u8,
+                    145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 168u8,
+                    240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8,
+                    132u8, 166u8, 240u8, 145u8, 132u8, 173u8, 32u8, 240u8, 145u8, 132u8, 142u8,
+                    240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8,
+                    159u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8,
+                    132u8, 131u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 140u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8, 132u8, 179u8,
+                    240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8,
+                    163u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8,
+                    132u8, 167u8, 32u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 168u8,
+                    240u8, 145u8, 132u8, 129u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8,
+                    172u8, 240u8, 145u8, 132u8, 142u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8,
+                    132u8, 135u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 147u8, 240u8,
+                    145u8, 132u8, 169u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 167u8,
+                    32u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8,
+                    132u8, 129u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 172u8, 240u8,
+                    145u8, 132u8, 142u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 157u8,
+                    240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8,
+                    168u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8,
+                    132u8, 140u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 131u8,
+                    240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 129u8, 240u8, 145u8, 132u8,
+                    162u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 142u8, 240u8, 145u8,
+                    132u8, 168u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 159u8, 240u8,
+                    145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 168u8,
+                    240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8,
+                    180u8, 32u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                    145u8, 132u8, 129u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 142u8,
+                    240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8,
+                    179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8,
+                    132u8, 168u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 32u8,
+                    240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8,
+                    172u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8,
+                    132u8, 135u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 32u8,
+                    240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8,
+                    155u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8,
+                    132u8, 154u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 140u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 132u8, 240u8, 145u8, 132u8, 133u8,
+                    240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8,
+                    155u8, 240u8, 145u8, 132u8, 169u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8,
+                    132u8, 167u8, 32u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 179u8,
+                    240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8,
+                    160u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8,
+                    132u8, 140u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 159u8, 240u8,
+                    145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 135u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8,
+                    168u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8,
+                    132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 179u8,
+                    240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8,
+                    160u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8,
+                    132u8, 140u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 135u8, 240u8,
+                    145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 169u8,
+                    240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 167u8, 32u8, 240u8, 145u8,
+                    132u8, 156u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8, 240u8,
+                    145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 165u8,
+                    240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8,
+                    168u8, 240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8,
+                    145u8, 132u8, 156u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8,
+                    240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    163u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8,
+                    132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 135u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 167u8,
+                    240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8,
+                    156u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8,
+                    132u8, 172u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                    145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 157u8,
+                    240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8,
+                    142u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8,
+                    132u8, 172u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 32u8,
+                    240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8,
+                    162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8,
+                    132u8, 170u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                    145u8, 132u8, 142u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 131u8,
+                    240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8,
+                    170u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8,
+                    132u8, 155u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 162u8, 240u8,
+                    145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8,
+                    167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8,
+                    132u8, 150u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 137u8, 240u8,
+                    145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 142u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8,
+                    163u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8,
+                    132u8, 158u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 160u8, 240u8,
+                    145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 167u8,
+                    240u8, 145u8, 132u8, 139u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8,
+                    137u8, 240u8, 145u8, 132u8, 174u8, 32u8, 240u8, 145u8, 132u8, 165u8, 240u8,
+                    145u8, 132u8, 177u8, 240u8, 145u8, 132u8, 166u8, 240u8, 145u8, 132u8, 168u8,
+                    240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    133u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 142u8, 240u8, 145u8,
+                    132u8, 170u8, 240u8, 145u8, 132u8, 133u8, 240u8, 145u8, 132u8, 170u8, 240u8,
+                    145u8, 132u8, 143u8, 240u8, 145u8, 132u8, 171u8, 32u8, 240u8, 145u8, 132u8,
+                    140u8, 240u8, 145u8, 132u8, 169u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8,
+                    132u8, 162u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 152u8, 240u8,
+                    145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 170u8,
+                    240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8,
+                    159u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 140u8, 240u8,
+                    145u8, 132u8, 169u8, 240u8, 145u8, 132u8, 154u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 101u8, 110u8, 45u8, 71u8, 66u8, 101u8,
+                    110u8, 45u8, 85u8, 83u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 73u8, 0u8, 240u8, 145u8, 132u8, 142u8, 240u8,
+                    145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 153u8, 240u8, 145u8, 132u8, 162u8,
+                    240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8,
+                    180u8, 240u8, 145u8, 132u8, 142u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8,
+                    132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 131u8,
+                    240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 129u8, 240u8, 145u8, 132u8,
+                    162u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 142u8, 240u8, 145u8,
+                    132u8, 168u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                    145u8, 132u8, 133u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 131u8,
+                    240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8,
+                    180u8, 32u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                    145u8, 132u8, 129u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 172u8,
+                    240u8, 145u8, 132u8, 142u8, 240u8, 145u8, 132u8, 168u8,
+                ])
+            },
+        )
+    },
+    long_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+};
+static EN: &DataStruct = &::icu_displaynames::provider::LocaleDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    23u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 11u8, 0u8, 16u8, 0u8, 21u8, 0u8, 26u8,
+                    0u8, 31u8, 0u8, 36u8, 0u8, 42u8, 0u8, 47u8, 0u8, 52u8, 0u8, 57u8, 0u8, 62u8,
+                    0u8, 67u8, 0u8, 74u8, 0u8, 80u8, 0u8, 85u8, 0u8, 90u8, 0u8, 95u8, 0u8, 100u8,
+                    0u8, 105u8, 0u8, 110u8, 0u8, 117u8, 0u8, 97u8, 114u8, 45u8, 48u8, 48u8, 49u8,
+                    100u8, 101u8, 45u8, 65u8, 84u8, 100u8, 101u8, 45u8, 67u8, 72u8, 101u8, 110u8,
+                    45u8, 65u8, 85u8, 101u8, 110u8, 45u8, 67u8, 65u8, 101u8, 110u8, 45u8, 71u8,
+                    66u8, 101u8, 110u8, 45u8, 85u8, 83u8, 101u8, 115u8, 45u8, 52u8, 49u8, 57u8,
+                    101u8, 115u8, 45u8, 69u8, 83u8, 101u8, 115u8, 45u8, 77u8, 88u8, 102u8, 97u8,
+                    45u8, 65u8, 70u8, 102u8, 114u8, 45u8, 67u8, 65u8, 102u8, 114u8, 45u8, 67u8,
+                    72u8, 104u8, 105u8, 45u8, 76u8, 97u8, 116u8, 110u8, 110u8, 100u8, 115u8, 45u8,
+                    78u8, 76u8, 110u8, 108u8, 45u8, 66u8, 69u8, 112u8, 116u8, 45u8, 66u8, 82u8,
+                    112u8, 116u8, 45u8, 80u8, 84u8, 114u8, 111u8, 45u8, 77u8, 68u8, 115u8, 114u8,
+                    45u8, 77u8, 69u8, 115u8, 119u8, 45u8, 67u8, 68u8, 122u8, 104u8, 45u8, 72u8,
+                    97u8, 110u8, 115u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8, 116u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    23u8, 0u8, 0u8, 0u8, 0u8, 0u8, 22u8, 0u8, 37u8, 0u8, 54u8, 0u8, 72u8, 0u8,
+                    88u8, 0u8, 103u8, 0u8, 119u8, 0u8, 141u8, 0u8, 157u8, 0u8, 172u8, 0u8, 176u8,
+                    0u8, 191u8, 0u8, 203u8, 0u8, 216u8, 0u8, 225u8, 0u8, 232u8, 0u8, 252u8, 0u8,
+                    15u8, 1u8, 24u8, 1u8, 35u8, 1u8, 48u8, 1u8, 66u8, 1u8, 77u8, 111u8, 100u8,
+                    101u8, 114u8, 110u8, 32u8, 83u8, 116u8, 97u8, 110u8, 100u8, 97u8, 114u8, 100u8,
+                    32u8, 65u8, 114u8, 97u8, 98u8, 105u8, 99u8, 65u8, 117u8, 115u8, 116u8, 114u8,
+                    105u8, 97u8, 110u8, 32u8, 71u8, 101u8, 114u8, 109u8, 97u8, 110u8, 83u8, 119u8,
+                    105u8, 115u8, 115u8, 32u8, 72u8, 105u8, 103u8, 104u8, 32u8, 71u8, 101u8, 114u8,
+                    109u8, 97u8, 110u8, 65u8, 117u8, 115u8, 116u8, 114u8, 97u8, 108u8, 105u8, 97u8,
+                    110u8, 32u8, 69u8, 110u8, 103u8, 108u8, 105u8, 115u8, 104u8, 67u8, 97u8, 110u8,
+                    97u8, 100u8, 105u8, 97u8, 110u8, 32u8, 69u8, 110u8, 103u8, 108u8, 105u8, 115u8,
+                    104u8, 66u8, 114u8, 105u8, 116u8, 105u8, 115u8, 104u8, 32u8, 69u8, 110u8,
+                    103u8, 108u8, 105u8, 115u8, 104u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8,
+                    97u8, 110u8, 32u8, 69u8, 110u8, 103u8, 108u8, 105u8, 115u8, 104u8, 76u8, 97u8,
+                    116u8, 105u8, 110u8, 32u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 110u8,
+                    32u8, 83u8, 112u8, 97u8, 110u8, 105u8, 115u8, 104u8, 69u8, 117u8, 114u8, 111u8,
+                    112u8, 101u8, 97u8, 110u8, 32u8, 83u8, 112u8, 97u8, 110u8, 105u8, 115u8, 104u8,
+                    77u8, 101u8, 120u8, 105u8, 99u8, 97u8, 110u8, 32u8, 83u8, 112u8, 97u8, 110u8,
+                    105u8, 115u8, 104u8, 68u8, 97u8, 114u8, 105u8, 67u8, 97u8, 110u8, 97u8, 100u8,
+                    105u8, 97u8, 110u8, 32u8, 70u8, 114u8, 101u8, 110u8, 99u8, 104u8, 83u8, 119u8,
+                    105u8, 115u8, 115u8, 32u8, 70u8, 114u8, 101u8, 110u8, 99u8, 104u8, 72u8, 105u8,
+                    110u8, 100u8, 105u8, 32u8, 40u8, 76u8, 97u8, 116u8, 105u8, 110u8, 41u8, 76u8,
+                    111u8, 119u8, 32u8, 83u8, 97u8, 120u8, 111u8, 110u8, 70u8, 108u8, 101u8, 109u8,
+                    105u8, 115u8, 104u8, 66u8, 114u8, 97u8, 122u8, 105u8, 108u8, 105u8, 97u8,
+                    110u8, 32u8, 80u8, 111u8, 114u8, 116u8, 117u8, 103u8, 117u8, 101u8, 115u8,
+                    101u8, 69u8, 117u8, 114u8, 111u8, 112u8, 101u8, 97u8, 110u8, 32u8, 80u8, 111u8,
+                    114u8, 116u8, 117u8, 103u8, 117u8, 101u8, 115u8, 101u8, 77u8, 111u8, 108u8,
+                    100u8, 97u8, 118u8, 105u8, 97u8, 110u8, 77u8, 111u8, 110u8, 116u8, 101u8,
+                    110u8, 101u8, 103u8, 114u8, 105u8, 110u8, 67u8, 111u8, 110u8, 103u8, 111u8,
+                    32u8, 83u8, 119u8, 97u8, 104u8, 105u8, 108u8, 105u8, 83u8, 105u8, 109u8, 112u8,
+                    108u8, 105u8, 102u8, 105u8, 101u8, 100u8, 32u8, 67u8, 104u8, 105u8, 110u8,
+                    101u8, 115u8, 101u8, 84u8, 114u8, 97u8, 100u8, 105u8, 116u8, 105u8, 111u8,
+                    110u8, 97u8, 108u8, 32u8, 67u8, 104u8, 105u8, 110u8, 101u8, 115u8, 101u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 101u8, 110u8, 45u8, 71u8, 66u8, 101u8,
+                    110u8, 45u8, 85u8, 83u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 85u8, 75u8, 32u8, 69u8, 110u8, 103u8,
+                    108u8, 105u8, 115u8, 104u8, 85u8, 83u8, 32u8, 69u8, 110u8, 103u8, 108u8, 105u8,
+                    115u8, 104u8,
+                ])
+            },
+        )
+    },
+    long_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8,
+                    115u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8, 116u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 27u8, 0u8, 83u8, 105u8, 109u8, 112u8, 108u8,
+                    105u8, 102u8, 105u8, 101u8, 100u8, 32u8, 77u8, 97u8, 110u8, 100u8, 97u8, 114u8,
+                    105u8, 110u8, 32u8, 67u8, 104u8, 105u8, 110u8, 101u8, 115u8, 101u8, 84u8,
+                    114u8, 97u8, 100u8, 105u8, 116u8, 105u8, 111u8, 110u8, 97u8, 108u8, 32u8, 77u8,
+                    97u8, 110u8, 100u8, 97u8, 114u8, 105u8, 110u8, 32u8, 67u8, 104u8, 105u8, 110u8,
+                    101u8, 115u8, 101u8,
+                ])
+            },
+        )
+    },
+};
+static EN_001_EN_ZA: &DataStruct = &::icu_displaynames::provider::LocaleDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    23u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 11u8, 0u8, 16u8, 0u8, 21u8, 0u8, 26u8,
+                    0u8, 31u8, 0u8, 36u8, 0u8, 42u8, 0u8, 47u8, 0u8, 52u8, 0u8, 57u8, 0u8, 62u8,
+                    0u8, 67u8, 0u8, 74u8, 0u8, 80u8, 0u8, 85u8, 0u8, 90u8, 0u8, 95u8, 0u8, 100u8,
+                    0u8, 105u8, 0u8, 110u8, 0u8, 117u8, 0u8, 97u8, 114u8, 45u8, 48u8, 48u8, 49u8,
+                    100u8, 101u8, 45u8, 65u8, 84u8, 100u8, 101u8, 45u8, 67u8, 72u8, 101u8, 110u8,
+                    45u8, 65u8, 85u8, 101u8, 110u8, 45u8, 67u8, 65u8, 101u8, 110u8, 45u8, 71u8,
+                    66u8, 101u8, 110u8, 45u8, 85u8, 83u8, 101u8, 115u8, 45u8, 52u8, 49u8, 57u8,
+                    101u8, 115u8, 45u8, 69u8, 83u8, 101u8, 115u8, 45u8, 77u8, 88u8, 102u8, 97u8,
+                    45u8, 65u8, 70u8, 102u8, 114u8, 45u8, 67u8, 65u8, 102u8, 114u8, 45u8, 67u8,
+                    72u8, 104u8, 105u8, 45u8, 76u8, 97u8, 116u8, 110u8, 110u8, 100u8, 115u8, 45u8,
+                    78u8, 76u8, 110u8, 108u8, 45u8, 66u8, 69u8, 112u8, 116u8, 45u8, 66u8, 82u8,
+                    112u8, 116u8, 45u8, 80u8, 84u8, 114u8, 111u8, 45u8, 77u8, 68u8, 115u8, 114u8,
+                    45u8, 77u8, 69u8, 115u8, 119u8, 45u8, 67u8, 68u8, 122u8, 104u8, 45u8, 72u8,
+                    97u8, 110u8, 115u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8, 116u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    23u8, 0u8, 0u8, 0u8, 0u8, 0u8, 22u8, 0u8, 37u8, 0u8, 54u8, 0u8, 72u8, 0u8,
+                    88u8, 0u8, 103u8, 0u8, 119u8, 0u8, 141u8, 0u8, 157u8, 0u8, 172u8, 0u8, 176u8,
+                    0u8, 191u8, 0u8, 203u8, 0u8, 216u8, 0u8, 231u8, 0u8, 238u8, 0u8, 2u8, 1u8,
+                    21u8, 1u8, 30u8, 1u8, 41u8, 1u8, 54u8, 1u8, 72u8, 1u8, 77u8, 111u8, 100u8,
+                    101u8, 114u8, 110u8, 32u8, 83u8, 116u8, 97u8, 110u8, 100u8, 97u8, 114u8, 100u8,
+                    32u8, 65u8, 114u8, 97u8, 98u8, 105u8, 99u8, 65u8, 117u8, 115u8, 116u8, 114u8,
+                    105u8, 97u8, 110u8, 32u8, 71u8, 101u8, 114u8, 109u8, 97u8, 110u8, 83u8, 119u8,
+                    105u8, 115u8, 115u8, 32u8, 72u8, 105u8, 103u8, 104u8, 32u8, 71u8, 101u8, 114u8,
+                    109u8, 97u8, 110u8, 65u8, 117u8, 115u8, 116u8, 114u8, 97u8, 108u8, 105u8, 97u8,
+                    110u8, 32u8, 69u8, 110u8, 103u8, 108u8, 105u8, 115u8, 104u8, 67u8, 97u8, 110u8,
+                    97u8, 100u8, 105u8, 97u8, 110u8, 32u8, 69u8, 110u8, 103u8, 108u8, 105u8, 115u8,
+                    104u8, 66u8, 114u8, 105u8, 116u8, 105u8, 115u8, 104u8, 32u8, 69u8, 110u8,
+                    103u8, 108u8, 105u8, 115u8, 104u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8,
+                    97u8, 110u8, 32u8, 69u8, 110u8, 103u8, 108u8, 105u8, 115u8, 104u8, 76u8, 97u8,
+                    116u8, 105u8, 110u8, 32u8, 65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 110u8,
+                    32u8, 83u8, 112u8, 97u8, 110u8, 105u8, 115u8, 104u8, 69u8, 117u8, 114u8, 111u8,
+                    112u8, 101u8, 97u8, 110u8, 32u8, 83u8, 112u8, 97u8, 110u8, 105u8, 115u8, 104u8,
+                    77u8, 101u8, 120u8, 105u8, 99u8, 97u8, 110u8, 32u8, 83u8, 112u8, 97u8, 110u8,
+                    105u8, 115u8, 104u8, 68u8, 97u8, 114u8, 105u8, 67u8, 97u8, 110u8, 97u8, 100u8,
+                    105u8, 97u8, 110u8, 32u8, 70u8, 114u8, 101u8, 110u8, 99u8, 104u8, 83u8, 119u8,
+                    105u8, 115u8, 115u8, 32u8, 70u8, 114u8, 101u8, 110u8, 99u8, 104u8, 72u8, 105u8,
+                    110u8, 100u8, 105u8, 32u8, 40u8, 76u8, 97u8, 116u8, 105u8, 110u8, 41u8, 87u8,
+                    101u8, 115u8, 116u8, 32u8, 76u8, 111u8, 119u8, 32u8, 71u8, 101u8, 114u8, 109u8,
+                    97u8, 110u8, 70u8, 108u8, 101u8, 109u8, 105u8, 115u8, 104u8, 66u8, 114u8, 97u8,
+                    122u8, 105u8, 108u8, 105u8, 97u8, 110u8, 32u8, 80u8, 111u8, 114u8, 116u8,
+                    117u8, 103u8, 117u8, 101u8, 115u8, 101u8, 69u8, 117u8, 114u8, 111u8, 112u8,
+                    101u8, 97u8, 110u8, 32u8, 80u8, 111u8, 114u8, 116u8, 117u8, 103u8, 117u8,
+                    101u8, 115u8, 101u8, 77u8, 111u8, 108u8, 100u8, 97u8, 118u8, 105u8, 97u8,
+                    110u8, 77u8, 111u8, 110u8, 116u8, 101u8, 110u8, 101u8, 103u8, 114u8, 105u8,
+                    110u8, 67u8, 111u8, 110u8, 103u8, 111u8, 32u8, 83u8, 119u8, 97u8, 104u8, 105u8,
+                    108u8, 105u8, 83u8, 105u8, 109u8, 112u8, 108u8, 105u8, 102u8, 105u8, 101u8,
+                    100u8, 32u8, 67u8, 104u8, 105u8, 110u8, 101u8, 115u8, 101u8, 84u8, 114u8, 97u8,
+                    100u8, 105u8, 116u8, 105u8, 111u8, 110u8, 97u8, 108u8, 32u8, 67u8, 104u8,
+                    105u8, 110u8, 101u8, 115u8, 101u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 101u8, 110u8, 45u8, 71u8, 66u8, 101u8,
+                    110u8, 45u8, 85u8, 83u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 85u8, 75u8, 32u8, 69u8, 110u8, 103u8,
+                    108u8, 105u8, 115u8, 104u8, 85u8, 83u8, 32u8, 69u8, 110u8, 103u8, 108u8, 105u8,
+                    115u8, 104u8,
+                ])
+            },
+        )
+    },
+    long_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8,
+                    115u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8, 116u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 27u8, 0u8, 83u8, 105u8, 109u8, 112u8, 108u8,
+                    105u8, 102u8, 105u8, 101u8, 100u8, 32u8, 77u8, 97u8, 110u8, 100u8, 97u8, 114u8,
+                    105u8, 110u8, 32u8, 67u8, 104u8, 105u8, 110u8, 101u8, 115u8, 101u8, 84u8,
+                    114u8, 97u8, 100u8, 105u8, 116u8, 105u8, 111u8, 110u8, 97u8, 108u8, 32u8, 77u8,
+                    97u8, 110u8, 100u8, 97u8, 114u8, 105u8, 110u8, 32u8, 67u8, 104u8, 105u8, 110u8,
+                    101u8, 115u8, 101u8,
+                ])
+            },
+        )
+    },
+};
+static ES_ES_AR: &DataStruct = &::icu_displaynames::provider::LocaleDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    21u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 11u8, 0u8, 16u8, 0u8, 21u8, 0u8, 26u8,
+                    0u8, 31u8, 0u8, 36u8, 0u8, 42u8, 0u8, 47u8, 0u8, 52u8, 0u8, 57u8, 0u8, 62u8,
+                    0u8, 67u8, 0u8, 73u8, 0u8, 78u8, 0u8, 83u8, 0u8, 88u8, 0u8, 93u8, 0u8, 98u8,
+                    0u8, 105u8, 0u8, 97u8, 114u8, 45u8, 48u8, 48u8, 49u8, 100u8, 101u8, 45u8, 65u8,
+                    84u8, 100u8, 101u8, 45u8, 67u8, 72u8, 101u8, 110u8, 45u8, 65u8, 85u8, 101u8,
+                    110u8, 45u8, 67u8, 65u8, 101u8, 110u8, 45u8, 71u8, 66u8, 101u8, 110u8, 45u8,
+                    85u8, 83u8, 101u8, 115u8, 45u8, 52u8, 49u8, 57u8, 101u8, 115u8, 45u8, 69u8,
+                    83u8, 101u8, 115u8, 45u8, 77u8, 88u8, 102u8, 97u8, 45u8, 65u8, 70u8, 102u8,
+                    114u8, 45u8, 67u8, 65u8, 102u8, 114u8, 45u8, 67u8, 72u8, 110u8, 100u8, 115u8,
+                    45u8, 78u8, 76u8, 110u8, 108u8, 45u8, 66u8, 69u8, 112u8, 116u8, 45u8, 66u8,
+                    82u8, 112u8, 116u8, 45u8, 80u8, 84u8, 114u8, 111u8, 45u8, 77u8, 68u8, 115u8,
+                    119u8, 45u8, 67u8, 68u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8, 115u8, 122u8,
+                    104u8, 45u8, 72u8, 97u8, 110u8, 116u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    21u8, 0u8, 0u8, 0u8, 0u8, 0u8, 24u8, 0u8, 42u8, 0u8, 60u8, 0u8, 79u8, 0u8,
+                    97u8, 0u8, 115u8, 0u8, 137u8, 0u8, 161u8, 0u8, 180u8, 0u8, 199u8, 0u8, 204u8,
+                    0u8, 223u8, 0u8, 237u8, 0u8, 248u8, 0u8, 0u8, 1u8, 20u8, 1u8, 42u8, 1u8, 49u8,
+                    1u8, 66u8, 1u8, 84u8, 1u8, 195u8, 161u8, 114u8, 97u8, 98u8, 101u8, 32u8, 101u8,
+                    115u8, 116u8, 195u8, 161u8, 110u8, 100u8, 97u8, 114u8, 32u8, 109u8, 111u8,
+                    100u8, 101u8, 114u8, 110u8, 111u8, 97u8, 108u8, 101u8, 109u8, 195u8, 161u8,
+                    110u8, 32u8, 97u8, 117u8, 115u8, 116u8, 114u8, 195u8, 173u8, 97u8, 99u8, 111u8,
+                    97u8, 108u8, 116u8, 111u8, 32u8, 97u8, 108u8, 101u8, 109u8, 195u8, 161u8,
+                    110u8, 32u8, 115u8, 117u8, 105u8, 122u8, 111u8, 105u8, 110u8, 103u8, 108u8,
+                    195u8, 169u8, 115u8, 32u8, 97u8, 117u8, 115u8, 116u8, 114u8, 97u8, 108u8,
+                    105u8, 97u8, 110u8, 111u8, 105u8, 110u8, 103u8, 108u8, 195u8, 169u8, 115u8,
+                    32u8, 99u8, 97u8, 110u8, 97u8, 100u8, 105u8, 101u8, 110u8, 115u8, 101u8, 105u8,
+                    110u8, 103u8, 108u8, 195u8, 169u8, 115u8, 32u8, 98u8, 114u8, 105u8, 116u8,
+                    195u8, 161u8, 110u8, 105u8, 99u8, 111u8, 105u8, 110u8, 103u8, 108u8, 195u8,
+                    169u8, 115u8, 32u8, 101u8, 115u8, 116u8, 97u8, 100u8, 111u8, 117u8, 110u8,
+                    105u8, 100u8, 101u8, 110u8, 115u8, 101u8, 101u8, 115u8, 112u8, 97u8, 195u8,
+                    177u8, 111u8, 108u8, 32u8, 108u8, 97u8, 116u8, 105u8, 110u8, 111u8, 97u8,
+                    109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 110u8, 111u8, 101u8, 115u8, 112u8,
+                    97u8, 195u8, 177u8, 111u8, 108u8, 32u8, 100u8, 101u8, 32u8, 69u8, 115u8, 112u8,
+                    97u8, 195u8, 177u8, 97u8, 101u8, 115u8, 112u8, 97u8, 195u8, 177u8, 111u8,
+                    108u8, 32u8, 100u8, 101u8, 32u8, 77u8, 195u8, 169u8, 120u8, 105u8, 99u8, 111u8,
+                    100u8, 97u8, 114u8, 195u8, 173u8, 102u8, 114u8, 97u8, 110u8, 99u8, 195u8,
+                    169u8, 115u8, 32u8, 99u8, 97u8, 110u8, 97u8, 100u8, 105u8, 101u8, 110u8, 115u8,
+                    101u8, 102u8, 114u8, 97u8, 110u8, 99u8, 195u8, 169u8, 115u8, 32u8, 115u8,
+                    117u8, 105u8, 122u8, 111u8, 98u8, 97u8, 106u8, 111u8, 32u8, 115u8, 97u8, 106u8,
+                    195u8, 179u8, 110u8, 102u8, 108u8, 97u8, 109u8, 101u8, 110u8, 99u8, 111u8,
+                    112u8, 111u8, 114u8, 116u8, 117u8, 103u8, 117u8, 195u8, 169u8, 115u8, 32u8,
+                    100u8, 101u8, 32u8, 66u8, 114u8, 97u8, 115u8, 105u8, 108u8, 112u8, 111u8,
+                    114u8, 116u8, 117u8, 103u8, 117u8, 195u8, 169u8, 115u8, 32u8, 100u8, 101u8,
+                    32u8, 80u8, 111u8, 114u8, 116u8, 117u8, 103u8, 97u8, 108u8, 109u8, 111u8,
+                    108u8, 100u8, 97u8, 118u8, 111u8, 115u8, 117u8, 97u8, 106u8, 105u8, 108u8,
+                    105u8, 32u8, 100u8, 101u8, 108u8, 32u8, 67u8, 111u8, 110u8, 103u8, 111u8, 99u8,
+                    104u8, 105u8, 110u8, 111u8, 32u8, 115u8, 105u8, 109u8, 112u8, 108u8, 105u8,
+                    102u8, 105u8, 99u8, 97u8, 100u8, 111u8, 99u8, 104u8, 105u8, 110u8, 111u8, 32u8,
+                    116u8, 114u8, 97u8, 100u8, 105u8, 99u8, 105u8, 111u8, 110u8, 97u8, 108u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 101u8, 110u8, 45u8, 71u8, 66u8, 101u8,
+                    110u8, 45u8, 85u8, 83u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 12u8, 0u8, 105u8, 110u8, 103u8, 108u8, 195u8,
+                    169u8, 115u8, 32u8, 40u8, 82u8, 85u8, 41u8, 105u8, 110u8, 103u8, 108u8, 195u8,
+                    169u8, 115u8, 32u8, 40u8, 69u8, 69u8, 46u8, 32u8, 85u8, 85u8, 46u8, 41u8,
+                ])
+            },
+        )
+    },
+    long_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8,
+                    115u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8, 116u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 28u8, 0u8, 99u8, 104u8, 105u8, 110u8, 111u8,
+                    32u8, 109u8, 97u8, 110u8, 100u8, 97u8, 114u8, 195u8, 173u8, 110u8, 32u8, 115u8,
+                    105u8, 109u8, 112u8, 108u8, 105u8, 102u8, 105u8, 99u8, 97u8, 100u8, 111u8,
+                    99u8, 104u8, 105u8, 110u8, 111u8, 32u8, 109u8, 97u8, 110u8, 100u8, 97u8, 114u8,
+                    195u8, 173u8, 110u8, 32u8, 116u8, 114u8, 97u8, 100u8, 105u8, 99u8, 105u8,
+                    111u8, 110u8, 97u8, 108u8,
+                ])
+            },
+        )
+    },
+};
+static FIL: &DataStruct = &::icu_displaynames::provider::LocaleDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    17u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 11u8, 0u8, 16u8, 0u8, 21u8, 0u8, 27u8,
+                    0u8, 32u8, 0u8, 37u8, 0u8, 42u8, 0u8, 47u8, 0u8, 53u8, 0u8, 58u8, 0u8, 63u8,
+                    0u8, 68u8, 0u8, 73u8, 0u8, 78u8, 0u8, 85u8, 0u8, 97u8, 114u8, 45u8, 48u8, 48u8,
+                    49u8, 100u8, 101u8, 45u8, 67u8, 72u8, 101u8, 110u8, 45u8, 71u8, 66u8, 101u8,
+                    110u8, 45u8, 85u8, 83u8, 101u8, 115u8, 45u8, 52u8, 49u8, 57u8, 101u8, 115u8,
+                    45u8, 69u8, 83u8, 101u8, 115u8, 45u8, 77u8, 88u8, 102u8, 97u8, 45u8, 65u8,
+                    70u8, 102u8, 114u8, 45u8, 67u8, 72u8, 110u8, 100u8, 115u8, 45u8, 78u8, 76u8,
+                    110u8, 108u8, 45u8, 66u8, 69u8, 112u8, 116u8, 45u8, 66u8, 82u8, 112u8, 116u8,
+                    45u8, 80u8, 84u8, 114u8, 111u8, 45u8, 77u8, 68u8, 115u8, 119u8, 45u8, 67u8,
+                    68u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8, 115u8, 122u8, 104u8, 45u8, 72u8,
+                    97u8, 110u8, 116u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    17u8, 0u8, 0u8, 0u8, 0u8, 0u8, 27u8, 0u8, 44u8, 0u8, 61u8, 0u8, 79u8, 0u8,
+                    105u8, 0u8, 121u8, 0u8, 140u8, 0u8, 144u8, 0u8, 159u8, 0u8, 168u8, 0u8, 175u8,
+                    0u8, 193u8, 0u8, 212u8, 0u8, 221u8, 0u8, 234u8, 0u8, 254u8, 0u8, 77u8, 111u8,
+                    100u8, 101u8, 114u8, 110u8, 111u8, 110u8, 103u8, 32u8, 75u8, 97u8, 114u8, 97u8,
+                    110u8, 105u8, 119u8, 97u8, 110u8, 103u8, 32u8, 65u8, 114u8, 97u8, 98u8, 105u8,
+                    99u8, 83u8, 119u8, 105u8, 115u8, 115u8, 32u8, 72u8, 105u8, 103u8, 104u8, 32u8,
+                    71u8, 101u8, 114u8, 109u8, 97u8, 110u8, 73u8, 110u8, 103u8, 108u8, 101u8,
+                    115u8, 32u8, 110u8, 97u8, 32u8, 66u8, 114u8, 105u8, 116u8, 105u8, 115u8, 104u8,
+                    73u8, 110u8, 103u8, 108u8, 101u8, 115u8, 32u8, 110u8, 97u8, 32u8, 65u8, 109u8,
+                    101u8, 114u8, 105u8, 99u8, 97u8, 110u8, 76u8, 97u8, 116u8, 105u8, 110u8, 32u8,
+                    65u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 110u8, 32u8, 110u8, 97u8, 32u8,
+                    69u8, 115u8, 112u8, 97u8, 110u8, 121u8, 111u8, 108u8, 69u8, 117u8, 114u8,
+                    111u8, 112u8, 101u8, 97u8, 110u8, 32u8, 83u8, 112u8, 97u8, 110u8, 105u8, 115u8,
+                    104u8, 77u8, 101u8, 120u8, 105u8, 99u8, 97u8, 110u8, 32u8, 110u8, 97u8, 32u8,
+                    69u8, 115u8, 112u8, 97u8, 110u8, 121u8, 111u8, 108u8, 68u8, 97u8, 114u8, 105u8,
+                    83u8, 119u8, 105u8, 115u8, 115u8, 32u8, 110u8, 97u8, 32u8, 70u8, 114u8, 101u8,
+                    110u8, 99u8, 104u8, 76u8, 111u8, 119u8, 32u8, 83u8, 97u8, 120u8, 111u8, 110u8,
+                    70u8, 108u8, 101u8, 109u8, 105u8, 115u8, 104u8, 80u8, 111u8, 114u8, 116u8,
+                    117u8, 103u8, 101u8, 115u8, 32u8, 110u8, 103u8, 32u8, 66u8, 114u8, 97u8, 115u8,
+                    105u8, 108u8, 69u8, 117u8, 114u8, 111u8, 112u8, 101u8, 97u8, 110u8, 32u8, 80u8,
+                    111u8, 114u8, 116u8, 117u8, 103u8, 117u8, 101u8, 115u8, 101u8, 77u8, 111u8,
+                    108u8, 100u8, 97u8, 118u8, 105u8, 97u8, 110u8, 67u8, 111u8, 110u8, 103u8,
+                    111u8, 32u8, 83u8, 119u8, 97u8, 104u8, 105u8, 108u8, 105u8, 80u8, 105u8, 110u8,
+                    97u8, 115u8, 105u8, 109u8, 112u8, 108u8, 101u8, 110u8, 103u8, 32u8, 67u8,
+                    104u8, 105u8, 110u8, 101u8, 115u8, 101u8, 84u8, 114u8, 97u8, 100u8, 105u8,
+                    115u8, 121u8, 111u8, 110u8, 97u8, 108u8, 32u8, 110u8, 97u8, 32u8, 67u8, 104u8,
+                    105u8, 110u8, 101u8, 115u8, 101u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 101u8, 110u8, 45u8, 71u8, 66u8, 101u8,
+                    110u8, 45u8, 85u8, 83u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 12u8, 0u8, 73u8, 110u8, 103u8, 108u8, 101u8,
+                    115u8, 32u8, 115u8, 97u8, 32u8, 85u8, 75u8, 73u8, 110u8, 103u8, 108u8, 101u8,
+                    115u8, 32u8, 115u8, 97u8, 32u8, 85u8, 83u8,
+                ])
+            },
+        )
+    },
+    long_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8,
+                    115u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8, 116u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 29u8, 0u8, 80u8, 105u8, 110u8, 97u8, 115u8,
+                    105u8, 109u8, 112u8, 108u8, 101u8, 110u8, 103u8, 32u8, 77u8, 97u8, 110u8,
+                    100u8, 97u8, 114u8, 105u8, 110u8, 32u8, 67u8, 104u8, 105u8, 110u8, 101u8,
+                    115u8, 101u8, 84u8, 114u8, 97u8, 100u8, 105u8, 115u8, 121u8, 111u8, 110u8,
+                    97u8, 108u8, 32u8, 110u8, 97u8, 32u8, 77u8, 97u8, 110u8, 100u8, 97u8, 114u8,
+                    105u8, 110u8, 32u8, 67u8, 104u8, 105u8, 110u8, 101u8, 115u8, 101u8,
+                ])
+            },
+        )
+    },
+};
+static FR: &DataStruct = &::icu_displaynames::provider::LocaleDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    21u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 11u8, 0u8, 16u8, 0u8, 21u8, 0u8, 26u8,
+                    0u8, 31u8, 0u8, 36u8, 0u8, 42u8, 0u8, 47u8, 0u8, 52u8, 0u8, 57u8, 0u8, 62u8,
+                    0u8, 67u8, 0u8, 73u8, 0u8, 78u8, 0u8, 83u8, 0u8, 88u8, 0u8, 93u8, 0u8, 98u8,
+                    0u8, 105u8, 0u8, 97u8, 114u8, 45u8, 48u8, 48u8, 49u8, 100u8, 101u8, 45u8, 65u8,
+                    84u8, 100u8, 101u8, 45u8, 67u8, 72u8, 101u8, 110u8, 45u8, 65u8, 85u8, 101u8,
+                    110u8, 45u8, 67u8, 65u8, 101u8, 110u8, 45u8, 71u8, 66u8, 101u8, 110u8, 45u8,
+                    85u8, 83u8, 101u8, 115u8, 45u8, 52u8, 49u8, 57u8, 101u8, 115u8, 45u8, 69u8,
+                    83u8, 101u8, 115u8, 45u8, 77u8, 88u8, 102u8, 97u8, 45u8, 65u8, 70u8, 102u8,
+                    114u8, 45u8, 67u8, 65u8, 102u8, 114u8, 45u8, 67u8, 72u8, 110u8, 100u8, 115u8,
+                    45u8, 78u8, 76u8, 110u8, 108u8, 45u8, 66u8, 69u8, 112u8, 116u8, 45u8, 66u8,
+                    82u8, 112u8, 116u8, 45u8, 80u8, 84u8, 114u8, 111u8, 45u8, 77u8, 68u8, 115u8,
+                    119u8, 45u8, 67u8, 68u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8, 115u8, 122u8,
+                    104u8, 45u8, 72u8, 97u8, 110u8, 116u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    21u8, 0u8, 0u8, 0u8, 0u8, 0u8, 22u8, 0u8, 41u8, 0u8, 56u8, 0u8, 74u8, 0u8,
+                    90u8, 0u8, 109u8, 0u8, 127u8, 0u8, 156u8, 0u8, 176u8, 0u8, 195u8, 0u8, 199u8,
+                    0u8, 217u8, 0u8, 233u8, 0u8, 255u8, 0u8, 6u8, 1u8, 26u8, 1u8, 45u8, 1u8, 52u8,
+                    1u8, 68u8, 1u8, 86u8, 1u8, 97u8, 114u8, 97u8, 98u8, 101u8, 32u8, 115u8, 116u8,
+                    97u8, 110u8, 100u8, 97u8, 114u8, 100u8, 32u8, 109u8, 111u8, 100u8, 101u8,
+                    114u8, 110u8, 101u8, 97u8, 108u8, 108u8, 101u8, 109u8, 97u8, 110u8, 100u8,
+                    32u8, 97u8, 117u8, 116u8, 114u8, 105u8, 99u8, 104u8, 105u8, 101u8, 110u8, 97u8,
+                    108u8, 108u8, 101u8, 109u8, 97u8, 110u8, 100u8, 32u8, 115u8, 117u8, 105u8,
+                    115u8, 115u8, 101u8, 97u8, 110u8, 103u8, 108u8, 97u8, 105u8, 115u8, 32u8, 97u8,
+                    117u8, 115u8, 116u8, 114u8, 97u8, 108u8, 105u8, 101u8, 110u8, 97u8, 110u8,
+                    103u8, 108u8, 97u8, 105u8, 115u8, 32u8, 99u8, 97u8, 110u8, 97u8, 100u8, 105u8,
+                    101u8, 110u8, 97u8, 110u8, 103u8, 108u8, 97u8, 105u8, 115u8, 32u8, 98u8, 114u8,
+                    105u8, 116u8, 97u8, 110u8, 110u8, 105u8, 113u8, 117u8, 101u8, 97u8, 110u8,
+                    103u8, 108u8, 97u8, 105u8, 115u8, 32u8, 97u8, 109u8, 195u8, 169u8, 114u8,
+                    105u8, 99u8, 97u8, 105u8, 110u8, 101u8, 115u8, 112u8, 97u8, 103u8, 110u8,
+                    111u8, 108u8, 32u8, 100u8, 226u8, 128u8, 153u8, 65u8, 109u8, 195u8, 169u8,
+                    114u8, 105u8, 113u8, 117u8, 101u8, 32u8, 108u8, 97u8, 116u8, 105u8, 110u8,
+                    101u8, 101u8, 115u8, 112u8, 97u8, 103u8, 110u8, 111u8, 108u8, 32u8, 100u8,
+                    226u8, 128u8, 153u8, 69u8, 115u8, 112u8, 97u8, 103u8, 110u8, 101u8, 101u8,
+                    115u8, 112u8, 97u8, 103u8, 110u8, 111u8, 108u8, 32u8, 100u8, 117u8, 32u8, 77u8,
+                    101u8, 120u8, 105u8, 113u8, 117u8, 101u8, 100u8, 97u8, 114u8, 105u8, 102u8,
+                    114u8, 97u8, 110u8, 195u8, 167u8, 97u8, 105u8, 115u8, 32u8, 99u8, 97u8, 110u8,
+                    97u8, 100u8, 105u8, 101u8, 110u8, 102u8, 114u8, 97u8, 110u8, 195u8, 167u8,
+                    97u8, 105u8, 115u8, 32u8, 115u8, 117u8, 105u8, 115u8, 115u8, 101u8, 98u8, 97u8,
+                    115u8, 45u8, 115u8, 97u8, 120u8, 111u8, 110u8, 32u8, 110u8, 195u8, 169u8,
+                    101u8, 114u8, 108u8, 97u8, 110u8, 100u8, 97u8, 105u8, 115u8, 102u8, 108u8,
+                    97u8, 109u8, 97u8, 110u8, 100u8, 112u8, 111u8, 114u8, 116u8, 117u8, 103u8,
+                    97u8, 105u8, 115u8, 32u8, 98u8, 114u8, 195u8, 169u8, 115u8, 105u8, 108u8,
+                    105u8, 101u8, 110u8, 112u8, 111u8, 114u8, 116u8, 117u8, 103u8, 97u8, 105u8,
+                    115u8, 32u8, 101u8, 117u8, 114u8, 111u8, 112u8, 195u8, 169u8, 101u8, 110u8,
+                    109u8, 111u8, 108u8, 100u8, 97u8, 118u8, 101u8, 115u8, 119u8, 97u8, 104u8,
+                    105u8, 108u8, 105u8, 32u8, 100u8, 117u8, 32u8, 67u8, 111u8, 110u8, 103u8,
+                    111u8, 99u8, 104u8, 105u8, 110u8, 111u8, 105u8, 115u8, 32u8, 115u8, 105u8,
+                    109u8, 112u8, 108u8, 105u8, 102u8, 105u8, 195u8, 169u8, 99u8, 104u8, 105u8,
+                    110u8, 111u8, 105u8, 115u8, 32u8, 116u8, 114u8, 97u8, 100u8, 105u8, 116u8,
+                    105u8, 111u8, 110u8, 110u8, 101u8, 108u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    long_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8,
+                    115u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8, 116u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 19u8, 0u8, 109u8, 97u8, 110u8, 100u8, 97u8,
+                    114u8, 105u8, 110u8, 32u8, 115u8, 105u8, 109u8, 112u8, 108u8, 105u8, 102u8,
+                    105u8, 195u8, 169u8, 109u8, 97u8, 110u8, 100u8, 97u8, 114u8, 105u8, 110u8,
+                    32u8, 116u8, 114u8, 97u8, 100u8, 105u8, 116u8, 105u8, 111u8, 110u8, 110u8,
+                    101u8, 108u8,
+                ])
+            },
+        )
+    },
+};
+static JA: &DataStruct = &::icu_displaynames::provider::LocaleDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 11u8, 0u8, 16u8, 0u8, 21u8, 0u8, 26u8,
+                    0u8, 31u8, 0u8, 36u8, 0u8, 41u8, 0u8, 48u8, 0u8, 53u8, 0u8, 58u8, 0u8, 63u8,
+                    0u8, 68u8, 0u8, 75u8, 0u8, 97u8, 114u8, 45u8, 48u8, 48u8, 49u8, 100u8, 101u8,
+                    45u8, 67u8, 72u8, 101u8, 110u8, 45u8, 65u8, 85u8, 101u8, 110u8, 45u8, 67u8,
+                    65u8, 101u8, 110u8, 45u8, 71u8, 66u8, 101u8, 110u8, 45u8, 85u8, 83u8, 101u8,
+                    115u8, 45u8, 69u8, 83u8, 102u8, 97u8, 45u8, 65u8, 70u8, 104u8, 105u8, 45u8,
+                    76u8, 97u8, 116u8, 110u8, 110u8, 108u8, 45u8, 66u8, 69u8, 112u8, 116u8, 45u8,
+                    80u8, 84u8, 114u8, 111u8, 45u8, 77u8, 68u8, 115u8, 119u8, 45u8, 67u8, 68u8,
+                    122u8, 104u8, 45u8, 72u8, 97u8, 110u8, 115u8, 122u8, 104u8, 45u8, 72u8, 97u8,
+                    110u8, 116u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 27u8, 0u8, 57u8, 0u8, 84u8, 0u8, 99u8, 0u8,
+                    117u8, 0u8, 135u8, 0u8, 171u8, 0u8, 183u8, 0u8, 219u8, 0u8, 234u8, 0u8, 17u8,
+                    1u8, 35u8, 1u8, 62u8, 1u8, 77u8, 1u8, 231u8, 143u8, 190u8, 228u8, 187u8, 163u8,
+                    230u8, 168u8, 153u8, 230u8, 186u8, 150u8, 227u8, 130u8, 162u8, 227u8, 131u8,
+                    169u8, 227u8, 131u8, 147u8, 227u8, 130u8, 162u8, 232u8, 170u8, 158u8, 230u8,
+                    168u8, 153u8, 230u8, 186u8, 150u8, 227u8, 131u8, 137u8, 227u8, 130u8, 164u8,
+                    227u8, 131u8, 132u8, 232u8, 170u8, 158u8, 32u8, 40u8, 227u8, 130u8, 185u8,
+                    227u8, 130u8, 164u8, 227u8, 130u8, 185u8, 41u8, 227u8, 130u8, 170u8, 227u8,
+                    131u8, 188u8, 227u8, 130u8, 185u8, 227u8, 131u8, 136u8, 227u8, 131u8, 169u8,
+                    227u8, 131u8, 170u8, 227u8, 130u8, 162u8, 232u8, 139u8, 177u8, 232u8, 170u8,
+                    158u8, 227u8, 130u8, 171u8, 227u8, 131u8, 138u8, 227u8, 131u8, 128u8, 232u8,
+                    139u8, 177u8, 232u8, 170u8, 158u8, 227u8, 130u8, 164u8, 227u8, 130u8, 174u8,
+                    227u8, 131u8, 170u8, 227u8, 130u8, 185u8, 232u8, 139u8, 177u8, 232u8, 170u8,
+                    158u8, 227u8, 130u8, 162u8, 227u8, 131u8, 161u8, 227u8, 131u8, 170u8, 227u8,
+                    130u8, 171u8, 232u8, 139u8, 177u8, 232u8, 170u8, 158u8, 227u8, 130u8, 185u8,
+                    227u8, 131u8, 154u8, 227u8, 130u8, 164u8, 227u8, 131u8, 179u8, 232u8, 170u8,
+                    158u8, 32u8, 40u8, 227u8, 130u8, 164u8, 227u8, 131u8, 153u8, 227u8, 131u8,
+                    170u8, 227u8, 130u8, 162u8, 229u8, 141u8, 138u8, 229u8, 179u8, 182u8, 41u8,
+                    227u8, 131u8, 128u8, 227u8, 131u8, 170u8, 227u8, 131u8, 188u8, 232u8, 170u8,
+                    158u8, 227u8, 131u8, 146u8, 227u8, 131u8, 179u8, 227u8, 131u8, 135u8, 227u8,
+                    130u8, 163u8, 227u8, 131u8, 188u8, 232u8, 170u8, 158u8, 32u8, 40u8, 227u8,
+                    131u8, 169u8, 227u8, 131u8, 134u8, 227u8, 131u8, 179u8, 230u8, 150u8, 135u8,
+                    229u8, 173u8, 151u8, 41u8, 227u8, 131u8, 149u8, 227u8, 131u8, 169u8, 227u8,
+                    131u8, 158u8, 227u8, 131u8, 179u8, 232u8, 170u8, 158u8, 227u8, 131u8, 157u8,
+                    227u8, 131u8, 171u8, 227u8, 131u8, 136u8, 227u8, 130u8, 172u8, 227u8, 131u8,
+                    171u8, 232u8, 170u8, 158u8, 32u8, 40u8, 227u8, 130u8, 164u8, 227u8, 131u8,
+                    153u8, 227u8, 131u8, 170u8, 227u8, 130u8, 162u8, 229u8, 141u8, 138u8, 229u8,
+                    179u8, 182u8, 41u8, 227u8, 131u8, 162u8, 227u8, 131u8, 171u8, 227u8, 131u8,
+                    128u8, 227u8, 131u8, 147u8, 227u8, 130u8, 162u8, 232u8, 170u8, 158u8, 227u8,
+                    130u8, 179u8, 227u8, 131u8, 179u8, 227u8, 130u8, 180u8, 227u8, 131u8, 187u8,
+                    227u8, 130u8, 185u8, 227u8, 131u8, 175u8, 227u8, 131u8, 146u8, 227u8, 131u8,
+                    170u8, 232u8, 170u8, 158u8, 231u8, 176u8, 161u8, 228u8, 189u8, 147u8, 228u8,
+                    184u8, 173u8, 229u8, 155u8, 189u8, 232u8, 170u8, 158u8, 231u8, 185u8, 129u8,
+                    228u8, 189u8, 147u8, 228u8, 184u8, 173u8, 229u8, 155u8, 189u8, 232u8, 170u8,
+                    158u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 101u8, 110u8, 45u8, 71u8, 66u8, 101u8,
+                    110u8, 45u8, 85u8, 83u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 14u8, 0u8, 232u8, 139u8, 177u8, 232u8, 170u8,
+                    158u8, 40u8, 232u8, 139u8, 177u8, 229u8, 155u8, 189u8, 41u8, 232u8, 139u8,
+                    177u8, 232u8, 170u8, 158u8, 40u8, 231u8, 177u8, 179u8, 229u8, 155u8, 189u8,
+                    41u8,
+                ])
+            },
+        )
+    },
+    long_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8,
+                    115u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8, 116u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 27u8, 0u8, 230u8, 168u8, 153u8, 230u8, 186u8,
+                    150u8, 228u8, 184u8, 173u8, 229u8, 155u8, 189u8, 232u8, 170u8, 158u8, 32u8,
+                    40u8, 231u8, 176u8, 161u8, 228u8, 189u8, 147u8, 229u8, 173u8, 151u8, 41u8,
+                    230u8, 168u8, 153u8, 230u8, 186u8, 150u8, 228u8, 184u8, 173u8, 229u8, 155u8,
+                    189u8, 232u8, 170u8, 158u8, 32u8, 40u8, 231u8, 185u8, 129u8, 228u8, 189u8,
+                    147u8, 229u8, 173u8, 151u8, 41u8,
+                ])
+            },
+        )
+    },
+};
+static RU: &DataStruct = &::icu_displaynames::provider::LocaleDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    21u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 11u8, 0u8, 16u8, 0u8, 21u8, 0u8, 26u8,
+                    0u8, 31u8, 0u8, 36u8, 0u8, 42u8, 0u8, 47u8, 0u8, 52u8, 0u8, 57u8, 0u8, 62u8,
+                    0u8, 67u8, 0u8, 73u8, 0u8, 78u8, 0u8, 83u8, 0u8, 88u8, 0u8, 93u8, 0u8, 98u8,
+                    0u8, 105u8, 0u8, 97u8, 114u8, 45u8, 48u8, 48u8, 49u8, 100u8, 101u8, 45u8, 65u8,
+                    84u8, 100u8, 101u8, 45u8, 67u8, 72u8, 101u8, 110u8, 45u8, 65u8, 85u8, 101u8,
+                    110u8, 45u8, 67u8, 65u8, 101u8, 110u8, 45u8, 71u8, 66u8, 101u8, 110u8, 45u8,
+                    85u8, 83u8, 101u8, 115u8, 45u8, 52u8, 49u8, 57u8, 101u8, 115u8, 45u8, 69u8,
+                    83u8, 101u8, 115u8, 45u8, 77u8, 88u8, 102u8, 97u8, 45u8, 65u8, 70u8, 102u8,
+                    114u8, 45u8, 67u8, 65u8, 102u8, 114u8, 45u8, 67u8, 72u8, 110u8, 100u8, 115u8,
+                    45u8, 78u8, 76u8, 110u8, 108u8, 45u8, 66u8, 69u8, 112u8, 116u8, 45u8, 66u8,
+                    82u8, 112u8, 116u8, 45u8, 80u8, 84u8, 114u8, 111u8, 45u8, 77u8, 68u8, 115u8,
+                    119u8, 45u8, 67u8, 68u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8, 115u8, 122u8,
+                    104u8, 45u8, 72u8, 97u8, 110u8, 116u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    21u8, 0u8, 0u8, 0u8, 0u8, 0u8, 41u8, 0u8, 80u8, 0u8, 144u8, 0u8, 191u8, 0u8,
+                    230u8, 0u8, 15u8, 1u8, 60u8, 1u8, 115u8, 1u8, 156u8, 1u8, 199u8, 1u8, 207u8,
+                    1u8, 248u8, 1u8, 37u8, 2u8, 67u8, 2u8, 89u8, 2u8, 138u8, 2u8, 187u8, 2u8,
+                    207u8, 2u8, 246u8, 2u8, 43u8, 3u8, 208u8, 176u8, 209u8, 128u8, 208u8, 176u8,
+                    208u8, 177u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 32u8,
+                    208u8, 187u8, 208u8, 184u8, 209u8, 130u8, 208u8, 181u8, 209u8, 128u8, 208u8,
+                    176u8, 209u8, 130u8, 209u8, 131u8, 209u8, 128u8, 208u8, 189u8, 209u8, 139u8,
+                    208u8, 185u8, 208u8, 176u8, 208u8, 178u8, 209u8, 129u8, 209u8, 130u8, 209u8,
+                    128u8, 208u8, 184u8, 208u8, 185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                    208u8, 185u8, 32u8, 208u8, 189u8, 208u8, 181u8, 208u8, 188u8, 208u8, 181u8,
+                    209u8, 134u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 208u8, 187u8, 208u8,
+                    184u8, 209u8, 130u8, 208u8, 181u8, 209u8, 128u8, 208u8, 176u8, 209u8, 130u8,
+                    209u8, 131u8, 209u8, 128u8, 208u8, 189u8, 209u8, 139u8, 208u8, 185u8, 32u8,
+                    209u8, 136u8, 208u8, 178u8, 208u8, 181u8, 208u8, 185u8, 209u8, 134u8, 208u8,
+                    176u8, 209u8, 128u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8,
+                    32u8, 208u8, 189u8, 208u8, 181u8, 208u8, 188u8, 208u8, 181u8, 209u8, 134u8,
+                    208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 208u8, 176u8, 208u8, 178u8, 209u8,
+                    129u8, 209u8, 130u8, 209u8, 128u8, 208u8, 176u8, 208u8, 187u8, 208u8, 184u8,
+                    208u8, 185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 32u8,
+                    208u8, 176u8, 208u8, 189u8, 208u8, 179u8, 208u8, 187u8, 208u8, 184u8, 208u8,
+                    185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 208u8, 186u8,
+                    208u8, 176u8, 208u8, 189u8, 208u8, 176u8, 208u8, 180u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 184u8, 208u8, 185u8, 32u8, 208u8, 176u8, 208u8, 189u8, 208u8,
+                    179u8, 208u8, 187u8, 208u8, 184u8, 208u8, 185u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 184u8, 208u8, 185u8, 208u8, 177u8, 209u8, 128u8, 208u8, 184u8, 209u8,
+                    130u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                    208u8, 185u8, 32u8, 208u8, 176u8, 208u8, 189u8, 208u8, 179u8, 208u8, 187u8,
+                    208u8, 184u8, 208u8, 185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8,
+                    185u8, 208u8, 176u8, 208u8, 188u8, 208u8, 181u8, 209u8, 128u8, 208u8, 184u8,
+                    208u8, 186u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                    184u8, 208u8, 185u8, 32u8, 208u8, 176u8, 208u8, 189u8, 208u8, 179u8, 208u8,
+                    187u8, 208u8, 184u8, 208u8, 185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                    208u8, 185u8, 208u8, 187u8, 208u8, 176u8, 209u8, 130u8, 208u8, 184u8, 208u8,
+                    189u8, 208u8, 190u8, 208u8, 176u8, 208u8, 188u8, 208u8, 181u8, 209u8, 128u8,
+                    208u8, 184u8, 208u8, 186u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 184u8, 208u8, 185u8, 32u8, 208u8, 184u8, 209u8, 129u8, 208u8,
+                    191u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                    208u8, 185u8, 208u8, 181u8, 208u8, 178u8, 209u8, 128u8, 208u8, 190u8, 208u8,
+                    191u8, 208u8, 181u8, 208u8, 185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                    208u8, 185u8, 32u8, 208u8, 184u8, 209u8, 129u8, 208u8, 191u8, 208u8, 176u8,
+                    208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 208u8,
+                    188u8, 208u8, 181u8, 208u8, 186u8, 209u8, 129u8, 208u8, 184u8, 208u8, 186u8,
+                    208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8,
+                    185u8, 32u8, 208u8, 184u8, 209u8, 129u8, 208u8, 191u8, 208u8, 176u8, 208u8,
+                    189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 208u8, 180u8,
+                    208u8, 176u8, 209u8, 128u8, 208u8, 184u8, 208u8, 186u8, 208u8, 176u8, 208u8,
+                    189u8, 208u8, 176u8, 208u8, 180u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                    208u8, 185u8, 32u8, 209u8, 132u8, 209u8, 128u8, 208u8, 176u8, 208u8, 189u8,
+                    209u8, 134u8, 209u8, 131u8, 208u8, 183u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                    184u8, 208u8, 185u8, 209u8, 136u8, 208u8, 178u8, 208u8, 181u8, 208u8, 185u8,
+                    209u8, 134u8, 208u8, 176u8, 209u8, 128u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                    184u8, 208u8, 185u8, 32u8, 209u8, 132u8, 209u8, 128u8, 208u8, 176u8, 208u8,
+                    189u8, 209u8, 134u8, 209u8, 131u8, 208u8, 183u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 184u8, 208u8, 185u8, 208u8, 189u8, 208u8, 184u8, 208u8, 182u8, 208u8,
+                    189u8, 208u8, 181u8, 209u8, 129u8, 208u8, 176u8, 208u8, 186u8, 209u8, 129u8,
+                    208u8, 190u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8,
+                    185u8, 209u8, 132u8, 208u8, 187u8, 208u8, 176u8, 208u8, 188u8, 208u8, 176u8,
+                    208u8, 189u8, 208u8, 180u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8,
+                    185u8, 208u8, 177u8, 209u8, 128u8, 208u8, 176u8, 208u8, 183u8, 208u8, 184u8,
+                    208u8, 187u8, 209u8, 140u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8,
+                    185u8, 32u8, 208u8, 191u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 209u8,
+                    131u8, 208u8, 179u8, 208u8, 176u8, 208u8, 187u8, 209u8, 140u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 208u8, 181u8, 208u8, 178u8, 209u8,
+                    128u8, 208u8, 190u8, 208u8, 191u8, 208u8, 181u8, 208u8, 185u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 32u8, 208u8, 191u8, 208u8, 190u8,
+                    209u8, 128u8, 209u8, 130u8, 209u8, 131u8, 208u8, 179u8, 208u8, 176u8, 208u8,
+                    187u8, 209u8, 140u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8,
+                    208u8, 188u8, 208u8, 190u8, 208u8, 187u8, 208u8, 180u8, 208u8, 176u8, 208u8,
+                    178u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 208u8, 186u8,
+                    208u8, 190u8, 208u8, 189u8, 208u8, 179u8, 208u8, 190u8, 208u8, 187u8, 208u8,
+                    181u8, 208u8, 183u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8,
+                    32u8, 209u8, 129u8, 209u8, 131u8, 208u8, 176u8, 209u8, 133u8, 208u8, 184u8,
+                    208u8, 187u8, 208u8, 184u8, 208u8, 186u8, 208u8, 184u8, 209u8, 130u8, 208u8,
+                    176u8, 208u8, 185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8,
+                    44u8, 32u8, 209u8, 131u8, 208u8, 191u8, 209u8, 128u8, 208u8, 190u8, 209u8,
+                    137u8, 208u8, 181u8, 208u8, 189u8, 208u8, 189u8, 208u8, 190u8, 208u8, 181u8,
+                    32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 209u8, 140u8, 208u8, 188u8,
+                    208u8, 190u8, 208u8, 186u8, 208u8, 184u8, 209u8, 130u8, 208u8, 176u8, 208u8,
+                    185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 44u8, 32u8,
+                    209u8, 130u8, 209u8, 128u8, 208u8, 176u8, 208u8, 180u8, 208u8, 184u8, 209u8,
+                    134u8, 208u8, 184u8, 208u8, 190u8, 208u8, 189u8, 208u8, 189u8, 208u8, 190u8,
+                    208u8, 181u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 209u8, 140u8,
+                    208u8, 188u8, 208u8, 190u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    long_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8,
+                    115u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8, 116u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 67u8, 0u8, 209u8, 129u8, 208u8, 181u8, 208u8,
+                    178u8, 208u8, 181u8, 209u8, 128u8, 208u8, 189u8, 208u8, 190u8, 208u8, 186u8,
+                    208u8, 184u8, 209u8, 130u8, 208u8, 176u8, 208u8, 185u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 184u8, 208u8, 185u8, 44u8, 32u8, 209u8, 131u8, 208u8, 191u8,
+                    209u8, 128u8, 208u8, 190u8, 209u8, 137u8, 208u8, 181u8, 208u8, 189u8, 208u8,
+                    189u8, 208u8, 190u8, 208u8, 181u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8,
+                    129u8, 209u8, 140u8, 208u8, 188u8, 208u8, 190u8, 209u8, 129u8, 208u8, 181u8,
+                    208u8, 178u8, 208u8, 181u8, 209u8, 128u8, 208u8, 189u8, 208u8, 190u8, 208u8,
+                    186u8, 208u8, 184u8, 209u8, 130u8, 208u8, 176u8, 208u8, 185u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 44u8, 32u8, 209u8, 130u8, 209u8,
+                    128u8, 208u8, 176u8, 208u8, 180u8, 208u8, 184u8, 209u8, 134u8, 208u8, 184u8,
+                    208u8, 190u8, 208u8, 189u8, 208u8, 189u8, 208u8, 190u8, 208u8, 181u8, 32u8,
+                    208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 209u8, 140u8, 208u8, 188u8, 208u8,
+                    190u8,
+                ])
+            },
+        )
+    },
+};
+static SR_LATN: &DataStruct = &::icu_displaynames::provider::LocaleDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    14u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 11u8, 0u8, 16u8, 0u8, 21u8, 0u8, 26u8,
+                    0u8, 31u8, 0u8, 36u8, 0u8, 42u8, 0u8, 47u8, 0u8, 52u8, 0u8, 57u8, 0u8, 62u8,
+                    0u8, 69u8, 0u8, 97u8, 114u8, 45u8, 48u8, 48u8, 49u8, 100u8, 101u8, 45u8, 65u8,
+                    84u8, 100u8, 101u8, 45u8, 67u8, 72u8, 101u8, 110u8, 45u8, 71u8, 66u8, 101u8,
+                    110u8, 45u8, 85u8, 83u8, 101u8, 115u8, 45u8, 69u8, 83u8, 102u8, 97u8, 45u8,
+                    65u8, 70u8, 110u8, 100u8, 115u8, 45u8, 78u8, 76u8, 110u8, 108u8, 45u8, 66u8,
+                    69u8, 112u8, 116u8, 45u8, 80u8, 84u8, 114u8, 111u8, 45u8, 77u8, 68u8, 115u8,
+                    119u8, 45u8, 67u8, 68u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8, 115u8, 122u8,
+                    104u8, 45u8, 72u8, 97u8, 110u8, 116u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    14u8, 0u8, 0u8, 0u8, 0u8, 0u8, 28u8, 0u8, 47u8, 0u8, 74u8, 0u8, 101u8, 0u8,
+                    140u8, 0u8, 157u8, 0u8, 161u8, 0u8, 175u8, 0u8, 184u8, 0u8, 206u8, 0u8, 215u8,
+                    0u8, 224u8, 0u8, 248u8, 0u8, 115u8, 97u8, 118u8, 114u8, 101u8, 109u8, 101u8,
+                    110u8, 105u8, 32u8, 115u8, 116u8, 97u8, 110u8, 100u8, 97u8, 114u8, 100u8,
+                    110u8, 105u8, 32u8, 97u8, 114u8, 97u8, 112u8, 115u8, 107u8, 105u8, 97u8, 117u8,
+                    115u8, 116u8, 114u8, 105u8, 106u8, 115u8, 107u8, 105u8, 32u8, 110u8, 101u8,
+                    109u8, 97u8, 196u8, 141u8, 107u8, 105u8, 197u8, 161u8, 118u8, 97u8, 106u8,
+                    99u8, 97u8, 114u8, 115u8, 107u8, 105u8, 32u8, 118u8, 105u8, 115u8, 111u8,
+                    107u8, 105u8, 32u8, 110u8, 101u8, 109u8, 97u8, 196u8, 141u8, 107u8, 105u8,
+                    101u8, 110u8, 103u8, 108u8, 101u8, 115u8, 107u8, 105u8, 32u8, 40u8, 86u8,
+                    101u8, 108u8, 105u8, 107u8, 97u8, 32u8, 66u8, 114u8, 105u8, 116u8, 97u8, 110u8,
+                    105u8, 106u8, 97u8, 41u8, 101u8, 110u8, 103u8, 108u8, 101u8, 115u8, 107u8,
+                    105u8, 32u8, 40u8, 83u8, 106u8, 101u8, 100u8, 105u8, 110u8, 106u8, 101u8,
+                    110u8, 101u8, 32u8, 65u8, 109u8, 101u8, 114u8, 105u8, 196u8, 141u8, 107u8,
+                    101u8, 32u8, 68u8, 114u8, 197u8, 190u8, 97u8, 118u8, 101u8, 41u8, 197u8, 161u8,
+                    112u8, 97u8, 110u8, 115u8, 107u8, 105u8, 32u8, 40u8, 69u8, 118u8, 114u8, 111u8,
+                    112u8, 97u8, 41u8, 100u8, 97u8, 114u8, 105u8, 110u8, 105u8, 115u8, 107u8,
+                    111u8, 115u8, 97u8, 107u8, 115u8, 111u8, 110u8, 115u8, 107u8, 105u8, 102u8,
+                    108u8, 97u8, 109u8, 97u8, 110u8, 115u8, 107u8, 105u8, 112u8, 111u8, 114u8,
+                    116u8, 117u8, 103u8, 97u8, 108u8, 115u8, 107u8, 105u8, 32u8, 40u8, 80u8, 111u8,
+                    114u8, 116u8, 117u8, 103u8, 97u8, 108u8, 41u8, 109u8, 111u8, 108u8, 100u8,
+                    97u8, 118u8, 115u8, 107u8, 105u8, 107u8, 105u8, 115u8, 118u8, 97u8, 104u8,
+                    105u8, 108u8, 105u8, 112u8, 111u8, 106u8, 101u8, 100u8, 110u8, 111u8, 115u8,
+                    116u8, 97u8, 118u8, 108u8, 106u8, 101u8, 110u8, 105u8, 32u8, 107u8, 105u8,
+                    110u8, 101u8, 115u8, 107u8, 105u8, 116u8, 114u8, 97u8, 100u8, 105u8, 99u8,
+                    105u8, 111u8, 110u8, 97u8, 108u8, 110u8, 105u8, 32u8, 107u8, 105u8, 110u8,
+                    101u8, 115u8, 107u8, 105u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 101u8, 110u8, 45u8, 71u8, 66u8, 101u8,
+                    110u8, 45u8, 85u8, 83u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 101u8, 110u8, 103u8, 108u8, 101u8,
+                    115u8, 107u8, 105u8, 32u8, 40u8, 85u8, 75u8, 41u8, 101u8, 110u8, 103u8, 108u8,
+                    101u8, 115u8, 107u8, 105u8, 32u8, 40u8, 83u8, 65u8, 68u8, 41u8,
+                ])
+            },
+        )
+    },
+    long_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8,
+                    115u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8, 116u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 36u8, 0u8, 112u8, 111u8, 106u8, 101u8, 100u8,
+                    110u8, 111u8, 115u8, 116u8, 97u8, 118u8, 108u8, 106u8, 101u8, 110u8, 105u8,
+                    32u8, 109u8, 97u8, 110u8, 100u8, 97u8, 114u8, 105u8, 110u8, 115u8, 107u8,
+                    105u8, 32u8, 107u8, 105u8, 110u8, 101u8, 115u8, 107u8, 105u8, 116u8, 114u8,
+                    97u8, 100u8, 105u8, 99u8, 105u8, 111u8, 110u8, 97u8, 108u8, 110u8, 105u8, 32u8,
+                    109u8, 97u8, 110u8, 100u8, 97u8, 114u8, 105u8, 110u8, 115u8, 107u8, 105u8,
+                    32u8, 107u8, 105u8, 110u8, 101u8, 115u8, 107u8, 105u8,
+                ])
+            },
+        )
+    },
+};
+static SR_SR_CYRL: &DataStruct = &::icu_displaynames::provider::LocaleDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    14u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 11u8, 0u8, 16u8, 0u8, 21u8, 0u8, 26u8,
+                    0u8, 31u8, 0u8, 36u8, 0u8, 42u8, 0u8, 47u8, 0u8, 52u8, 0u8, 57u8, 0u8, 62u8,
+                    0u8, 69u8, 0u8, 97u8, 114u8, 45u8, 48u8, 48u8, 49u8, 100u8, 101u8, 45u8, 65u8,
+                    84u8, 100u8, 101u8, 45u8, 67u8, 72u8, 101u8, 110u8, 45u8, 71u8, 66u8, 101u8,
+                    110u8, 45u8, 85u8, 83u8, 101u8, 115u8, 45u8, 69u8, 83u8, 102u8, 97u8, 45u8,
+                    65u8, 70u8, 110u8, 100u8, 115u8, 45u8, 78u8, 76u8, 110u8, 108u8, 45u8, 66u8,
+                    69u8, 112u8, 116u8, 45u8, 80u8, 84u8, 114u8, 111u8, 45u8, 77u8, 68u8, 115u8,
+                    119u8, 45u8, 67u8, 68u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8, 115u8, 122u8,
+                    104u8, 45u8, 72u8, 97u8, 110u8, 116u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    14u8, 0u8, 0u8, 0u8, 0u8, 0u8, 54u8, 0u8, 89u8, 0u8, 137u8, 0u8, 187u8, 0u8,
+                    254u8, 0u8, 27u8, 1u8, 35u8, 1u8, 63u8, 1u8, 81u8, 1u8, 122u8, 1u8, 140u8, 1u8,
+                    158u8, 1u8, 203u8, 1u8, 209u8, 129u8, 208u8, 176u8, 208u8, 178u8, 209u8, 128u8,
+                    208u8, 181u8, 208u8, 188u8, 208u8, 181u8, 208u8, 189u8, 208u8, 184u8, 32u8,
+                    209u8, 129u8, 209u8, 130u8, 208u8, 176u8, 208u8, 189u8, 208u8, 180u8, 208u8,
+                    176u8, 209u8, 128u8, 208u8, 180u8, 208u8, 189u8, 208u8, 184u8, 32u8, 208u8,
+                    176u8, 209u8, 128u8, 208u8, 176u8, 208u8, 191u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 184u8, 208u8, 176u8, 209u8, 131u8, 209u8, 129u8, 209u8, 130u8, 209u8,
+                    128u8, 208u8, 184u8, 209u8, 152u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                    32u8, 208u8, 189u8, 208u8, 181u8, 208u8, 188u8, 208u8, 176u8, 209u8, 135u8,
+                    208u8, 186u8, 208u8, 184u8, 209u8, 136u8, 208u8, 178u8, 208u8, 176u8, 209u8,
+                    152u8, 209u8, 134u8, 208u8, 176u8, 209u8, 128u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 184u8, 32u8, 208u8, 178u8, 208u8, 184u8, 209u8, 129u8, 208u8, 190u8,
+                    208u8, 186u8, 208u8, 184u8, 32u8, 208u8, 189u8, 208u8, 181u8, 208u8, 188u8,
+                    208u8, 176u8, 209u8, 135u8, 208u8, 186u8, 208u8, 184u8, 208u8, 181u8, 208u8,
+                    189u8, 208u8, 179u8, 208u8, 187u8, 208u8, 181u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 184u8, 32u8, 40u8, 208u8, 146u8, 208u8, 181u8, 208u8, 187u8, 208u8,
+                    184u8, 208u8, 186u8, 208u8, 176u8, 32u8, 208u8, 145u8, 209u8, 128u8, 208u8,
+                    184u8, 209u8, 130u8, 208u8, 176u8, 208u8, 189u8, 208u8, 184u8, 209u8, 152u8,
+                    208u8, 176u8, 41u8, 208u8, 181u8, 208u8, 189u8, 208u8, 179u8, 208u8, 187u8,
+                    208u8, 181u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 40u8, 208u8,
+                    161u8, 209u8, 152u8, 208u8, 181u8, 208u8, 180u8, 208u8, 184u8, 209u8, 154u8,
+                    208u8, 181u8, 208u8, 189u8, 208u8, 181u8, 32u8, 208u8, 144u8, 208u8, 188u8,
+                    208u8, 181u8, 209u8, 128u8, 208u8, 184u8, 209u8, 135u8, 208u8, 186u8, 208u8,
+                    181u8, 32u8, 208u8, 148u8, 209u8, 128u8, 208u8, 182u8, 208u8, 176u8, 208u8,
+                    178u8, 208u8, 181u8, 41u8, 209u8, 136u8, 208u8, 191u8, 208u8, 176u8, 208u8,
+                    189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 40u8, 208u8, 149u8,
+                    208u8, 178u8, 209u8, 128u8, 208u8, 190u8, 208u8, 191u8, 208u8, 176u8, 41u8,
+                    208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 208u8, 184u8, 208u8, 189u8, 208u8,
+                    184u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 209u8, 129u8, 208u8, 176u8,
+                    208u8, 186u8, 209u8, 129u8, 208u8, 190u8, 208u8, 189u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 184u8, 209u8, 132u8, 208u8, 187u8, 208u8, 176u8, 208u8, 188u8,
+                    208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8,
+                    191u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 209u8, 131u8, 208u8, 179u8,
+                    208u8, 176u8, 208u8, 187u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 32u8,
+                    40u8, 208u8, 159u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 209u8, 131u8,
+                    208u8, 179u8, 208u8, 176u8, 208u8, 187u8, 41u8, 208u8, 188u8, 208u8, 190u8,
+                    208u8, 187u8, 208u8, 180u8, 208u8, 176u8, 208u8, 178u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 184u8, 208u8, 186u8, 208u8, 184u8, 209u8, 129u8, 208u8, 178u8,
+                    208u8, 176u8, 209u8, 133u8, 208u8, 184u8, 208u8, 187u8, 208u8, 184u8, 208u8,
+                    191u8, 208u8, 190u8, 209u8, 152u8, 208u8, 181u8, 208u8, 180u8, 208u8, 189u8,
+                    208u8, 190u8, 209u8, 129u8, 209u8, 130u8, 208u8, 176u8, 208u8, 178u8, 209u8,
+                    153u8, 208u8, 181u8, 208u8, 189u8, 208u8, 184u8, 32u8, 208u8, 186u8, 208u8,
+                    184u8, 208u8, 189u8, 208u8, 181u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                    209u8, 130u8, 209u8, 128u8, 208u8, 176u8, 208u8, 180u8, 208u8, 184u8, 209u8,
+                    134u8, 208u8, 184u8, 208u8, 190u8, 208u8, 189u8, 208u8, 176u8, 208u8, 187u8,
+                    208u8, 189u8, 208u8, 184u8, 32u8, 208u8, 186u8, 208u8, 184u8, 208u8, 189u8,
+                    208u8, 181u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 101u8, 110u8, 45u8, 71u8, 66u8, 101u8,
+                    110u8, 45u8, 85u8, 83u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 23u8, 0u8, 208u8, 181u8, 208u8, 189u8, 208u8,
+                    179u8, 208u8, 187u8, 208u8, 181u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                    32u8, 40u8, 208u8, 163u8, 208u8, 154u8, 41u8, 208u8, 181u8, 208u8, 189u8,
+                    208u8, 179u8, 208u8, 187u8, 208u8, 181u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                    184u8, 32u8, 40u8, 208u8, 161u8, 208u8, 144u8, 208u8, 148u8, 41u8,
+                ])
+            },
+        )
+    },
+    long_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8,
+                    115u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8, 116u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 68u8, 0u8, 208u8, 191u8, 208u8, 190u8, 209u8,
+                    152u8, 208u8, 181u8, 208u8, 180u8, 208u8, 189u8, 208u8, 190u8, 209u8, 129u8,
+                    209u8, 130u8, 208u8, 176u8, 208u8, 178u8, 209u8, 153u8, 208u8, 181u8, 208u8,
+                    189u8, 208u8, 184u8, 32u8, 208u8, 188u8, 208u8, 176u8, 208u8, 189u8, 208u8,
+                    180u8, 208u8, 176u8, 209u8, 128u8, 208u8, 184u8, 208u8, 189u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 184u8, 32u8, 208u8, 186u8, 208u8, 184u8, 208u8, 189u8,
+                    208u8, 181u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 209u8, 130u8, 209u8,
+                    128u8, 208u8, 176u8, 208u8, 180u8, 208u8, 184u8, 209u8, 134u8, 208u8, 184u8,
+                    208u8, 190u8, 208u8, 189u8, 208u8, 176u8, 208u8, 187u8, 208u8, 189u8, 208u8,
+                    184u8, 32u8, 208u8, 188u8, 208u8, 176u8, 208u8, 189u8, 208u8, 180u8, 208u8,
+                    176u8, 209u8, 128u8, 208u8, 184u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 184u8, 32u8, 208u8, 186u8, 208u8, 184u8, 208u8, 189u8, 208u8, 181u8,
+                    209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                ])
+            },
+        )
+    },
+};
+static TH: &DataStruct = &::icu_displaynames::provider::LocaleDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    21u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 11u8, 0u8, 16u8, 0u8, 21u8, 0u8, 26u8,
+                    0u8, 31u8, 0u8, 36u8, 0u8, 42u8, 0u8, 47u8, 0u8, 52u8, 0u8, 57u8, 0u8, 62u8,
+                    0u8, 67u8, 0u8, 73u8, 0u8, 78u8, 0u8, 83u8, 0u8, 88u8, 0u8, 93u8, 0u8, 98u8,
+                    0u8, 105u8, 0u8, 97u8, 114u8, 45u8, 48u8, 48u8, 49u8, 100u8, 101u8, 45u8, 65u8,
+                    84u8, 100u8, 101u8, 45u8, 67u8, 72u8, 101u8, 110u8, 45u8, 65u8, 85u8, 101u8,
+                    110u8, 45u8, 67u8, 65u8, 101u8, 110u8, 45u8, 71u8, 66u8, 101u8, 110u8, 45u8,
+                    85u8, 83u8, 101u8, 115u8, 45u8, 52u8, 49u8, 57u8, 101u8, 115u8, 45u8, 69u8,
+                    83u8, 101u8, 115u8, 45u8, 77u8, 88u8, 102u8, 97u8, 45u8, 65u8, 70u8, 102u8,
+                    114u8, 45u8, 67u8, 65u8, 102u8, 114u8, 45u8, 67u8, 72u8, 110u8, 100u8, 115u8,
+                    45u8, 78u8, 76u8, 110u8, 108u8, 45u8, 66u8, 69u8, 112u8, 116u8, 45u8, 66u8,
+                    82u8, 112u8, 116u8, 45u8, 80u8, 84u8, 114u8, 111u8, 45u8, 77u8, 68u8, 115u8,
+                    119u8, 45u8, 67u8, 68u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8, 115u8, 122u8,
+                    104u8, 45u8, 72u8, 97u8, 110u8, 116u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    21u8, 0u8, 0u8, 0u8, 0u8, 0u8, 63u8, 0u8, 111u8, 0u8, 156u8, 0u8, 207u8, 0u8,
+                    246u8, 0u8, 50u8, 1u8, 107u8, 1u8, 158u8, 1u8, 188u8, 1u8, 227u8, 1u8, 239u8,
+                    1u8, 28u8, 2u8, 67u8, 2u8, 94u8, 2u8, 112u8, 2u8, 157u8, 2u8, 199u8, 2u8,
+                    220u8, 2u8, 3u8, 3u8, 30u8, 3u8, 224u8, 184u8, 173u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 171u8, 224u8, 184u8, 163u8, 224u8, 184u8, 177u8, 224u8, 184u8,
+                    154u8, 224u8, 184u8, 161u8, 224u8, 184u8, 178u8, 224u8, 184u8, 149u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 144u8, 224u8, 184u8, 178u8, 224u8, 184u8, 153u8,
+                    224u8, 184u8, 170u8, 224u8, 184u8, 161u8, 224u8, 184u8, 177u8, 224u8, 184u8,
+                    162u8, 224u8, 185u8, 131u8, 224u8, 184u8, 171u8, 224u8, 184u8, 161u8, 224u8,
+                    185u8, 136u8, 224u8, 185u8, 128u8, 224u8, 184u8, 162u8, 224u8, 184u8, 173u8,
+                    224u8, 184u8, 163u8, 224u8, 184u8, 161u8, 224u8, 184u8, 177u8, 224u8, 184u8,
+                    153u8, 32u8, 45u8, 32u8, 224u8, 184u8, 173u8, 224u8, 184u8, 173u8, 224u8,
+                    184u8, 170u8, 224u8, 185u8, 128u8, 224u8, 184u8, 149u8, 224u8, 184u8, 163u8,
+                    224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    162u8, 224u8, 184u8, 173u8, 224u8, 184u8, 163u8, 224u8, 184u8, 161u8, 224u8,
+                    184u8, 177u8, 224u8, 184u8, 153u8, 224u8, 184u8, 170u8, 224u8, 184u8, 185u8,
+                    224u8, 184u8, 135u8, 32u8, 40u8, 224u8, 184u8, 170u8, 224u8, 184u8, 167u8,
+                    224u8, 184u8, 180u8, 224u8, 184u8, 170u8, 41u8, 224u8, 184u8, 173u8, 224u8,
+                    184u8, 177u8, 224u8, 184u8, 135u8, 224u8, 184u8, 129u8, 224u8, 184u8, 164u8,
+                    224u8, 184u8, 169u8, 32u8, 45u8, 32u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                    173u8, 224u8, 184u8, 170u8, 224u8, 185u8, 128u8, 224u8, 184u8, 149u8, 224u8,
+                    184u8, 163u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8, 184u8, 181u8,
+                    224u8, 184u8, 162u8, 224u8, 184u8, 173u8, 224u8, 184u8, 177u8, 224u8, 184u8,
+                    135u8, 224u8, 184u8, 129u8, 224u8, 184u8, 164u8, 224u8, 184u8, 169u8, 32u8,
+                    45u8, 32u8, 224u8, 185u8, 129u8, 224u8, 184u8, 132u8, 224u8, 184u8, 153u8,
+                    224u8, 184u8, 178u8, 224u8, 184u8, 148u8, 224u8, 184u8, 178u8, 224u8, 184u8,
+                    173u8, 224u8, 184u8, 177u8, 224u8, 184u8, 135u8, 224u8, 184u8, 129u8, 224u8,
+                    184u8, 164u8, 224u8, 184u8, 169u8, 32u8, 45u8, 32u8, 224u8, 184u8, 170u8,
+                    224u8, 184u8, 171u8, 224u8, 184u8, 163u8, 224u8, 184u8, 178u8, 224u8, 184u8,
+                    138u8, 224u8, 184u8, 173u8, 224u8, 184u8, 178u8, 224u8, 184u8, 147u8, 224u8,
+                    184u8, 178u8, 224u8, 184u8, 136u8, 224u8, 184u8, 177u8, 224u8, 184u8, 129u8,
+                    224u8, 184u8, 163u8, 224u8, 184u8, 173u8, 224u8, 184u8, 177u8, 224u8, 184u8,
+                    135u8, 224u8, 184u8, 129u8, 224u8, 184u8, 164u8, 224u8, 184u8, 169u8, 32u8,
+                    45u8, 32u8, 224u8, 184u8, 170u8, 224u8, 184u8, 171u8, 224u8, 184u8, 163u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 144u8, 224u8, 184u8, 173u8, 224u8, 185u8,
+                    128u8, 224u8, 184u8, 161u8, 224u8, 184u8, 163u8, 224u8, 184u8, 180u8, 224u8,
+                    184u8, 129u8, 224u8, 184u8, 178u8, 224u8, 184u8, 170u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 155u8, 224u8, 184u8, 153u8, 32u8, 45u8, 32u8, 224u8, 184u8,
+                    165u8, 224u8, 184u8, 176u8, 224u8, 184u8, 149u8, 224u8, 184u8, 180u8, 224u8,
+                    184u8, 153u8, 224u8, 184u8, 173u8, 224u8, 185u8, 128u8, 224u8, 184u8, 161u8,
+                    224u8, 184u8, 163u8, 224u8, 184u8, 180u8, 224u8, 184u8, 129u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 170u8, 224u8, 185u8, 128u8, 224u8, 184u8, 155u8, 224u8,
+                    184u8, 153u8, 32u8, 45u8, 32u8, 224u8, 184u8, 162u8, 224u8, 184u8, 184u8,
+                    224u8, 185u8, 130u8, 224u8, 184u8, 163u8, 224u8, 184u8, 155u8, 224u8, 184u8,
+                    170u8, 224u8, 185u8, 128u8, 224u8, 184u8, 155u8, 224u8, 184u8, 153u8, 32u8,
+                    45u8, 32u8, 224u8, 185u8, 128u8, 224u8, 184u8, 161u8, 224u8, 185u8, 135u8,
+                    224u8, 184u8, 129u8, 224u8, 184u8, 139u8, 224u8, 184u8, 180u8, 224u8, 185u8,
+                    130u8, 224u8, 184u8, 129u8, 224u8, 184u8, 148u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 181u8, 224u8, 184u8, 157u8, 224u8, 184u8, 163u8,
+                    224u8, 184u8, 177u8, 224u8, 185u8, 136u8, 224u8, 184u8, 135u8, 224u8, 185u8,
+                    128u8, 224u8, 184u8, 168u8, 224u8, 184u8, 170u8, 32u8, 45u8, 32u8, 224u8,
+                    185u8, 129u8, 224u8, 184u8, 132u8, 224u8, 184u8, 153u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 148u8, 224u8, 184u8, 178u8, 224u8, 184u8, 157u8, 224u8, 184u8,
+                    163u8, 224u8, 184u8, 177u8, 224u8, 185u8, 136u8, 224u8, 184u8, 135u8, 224u8,
+                    185u8, 128u8, 224u8, 184u8, 168u8, 224u8, 184u8, 170u8, 32u8, 45u8, 32u8,
+                    224u8, 184u8, 170u8, 224u8, 184u8, 167u8, 224u8, 184u8, 180u8, 224u8, 184u8,
+                    170u8, 224u8, 185u8, 129u8, 224u8, 184u8, 139u8, 224u8, 184u8, 129u8, 224u8,
+                    184u8, 139u8, 224u8, 184u8, 173u8, 224u8, 184u8, 153u8, 224u8, 185u8, 131u8,
+                    224u8, 184u8, 149u8, 224u8, 185u8, 137u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    159u8, 224u8, 184u8, 165u8, 224u8, 184u8, 161u8, 224u8, 184u8, 180u8, 224u8,
+                    184u8, 138u8, 224u8, 185u8, 130u8, 224u8, 184u8, 155u8, 224u8, 184u8, 163u8,
+                    224u8, 184u8, 149u8, 224u8, 184u8, 184u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    129u8, 224u8, 184u8, 170u8, 32u8, 45u8, 32u8, 224u8, 184u8, 154u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 178u8, 224u8, 184u8, 139u8, 224u8, 184u8, 180u8,
+                    224u8, 184u8, 165u8, 224u8, 185u8, 130u8, 224u8, 184u8, 155u8, 224u8, 184u8,
+                    163u8, 224u8, 184u8, 149u8, 224u8, 184u8, 184u8, 224u8, 185u8, 128u8, 224u8,
+                    184u8, 129u8, 224u8, 184u8, 170u8, 32u8, 45u8, 32u8, 224u8, 184u8, 162u8,
+                    224u8, 184u8, 184u8, 224u8, 185u8, 130u8, 224u8, 184u8, 163u8, 224u8, 184u8,
+                    155u8, 224u8, 184u8, 161u8, 224u8, 184u8, 173u8, 224u8, 184u8, 165u8, 224u8,
+                    185u8, 130u8, 224u8, 184u8, 148u8, 224u8, 184u8, 167u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 170u8, 224u8, 184u8, 167u8, 224u8, 184u8, 178u8, 224u8, 184u8,
+                    174u8, 224u8, 184u8, 181u8, 224u8, 184u8, 165u8, 224u8, 184u8, 181u8, 32u8,
+                    45u8, 32u8, 224u8, 184u8, 132u8, 224u8, 184u8, 173u8, 224u8, 184u8, 135u8,
+                    224u8, 185u8, 130u8, 224u8, 184u8, 129u8, 224u8, 184u8, 136u8, 224u8, 184u8,
+                    181u8, 224u8, 184u8, 153u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8, 224u8,
+                    184u8, 167u8, 224u8, 184u8, 162u8, 224u8, 185u8, 136u8, 224u8, 184u8, 173u8,
+                    224u8, 184u8, 136u8, 224u8, 184u8, 181u8, 224u8, 184u8, 153u8, 224u8, 184u8,
+                    149u8, 224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8,
+                    184u8, 149u8, 224u8, 185u8, 135u8, 224u8, 184u8, 161u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 101u8, 110u8, 45u8, 71u8, 66u8, 101u8,
+                    110u8, 45u8, 85u8, 83u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 39u8, 0u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                    177u8, 224u8, 184u8, 135u8, 224u8, 184u8, 129u8, 224u8, 184u8, 164u8, 224u8,
+                    184u8, 169u8, 32u8, 45u8, 32u8, 224u8, 184u8, 173u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 135u8, 224u8, 184u8, 129u8, 224u8, 184u8, 164u8, 224u8, 184u8,
+                    169u8, 224u8, 184u8, 173u8, 224u8, 184u8, 177u8, 224u8, 184u8, 135u8, 224u8,
+                    184u8, 129u8, 224u8, 184u8, 164u8, 224u8, 184u8, 169u8, 32u8, 45u8, 32u8,
+                    224u8, 184u8, 170u8, 224u8, 184u8, 171u8, 224u8, 184u8, 163u8, 224u8, 184u8,
+                    177u8, 224u8, 184u8, 144u8, 224u8, 184u8, 175u8,
+                ])
+            },
+        )
+    },
+    long_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8,
+                    115u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8, 116u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 42u8, 0u8, 224u8, 184u8, 136u8, 224u8, 184u8,
+                    181u8, 224u8, 184u8, 153u8, 224u8, 184u8, 129u8, 224u8, 184u8, 165u8, 224u8,
+                    184u8, 178u8, 224u8, 184u8, 135u8, 32u8, 40u8, 224u8, 184u8, 149u8, 224u8,
+                    184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 184u8, 162u8, 224u8, 185u8, 136u8,
+                    224u8, 184u8, 173u8, 41u8, 224u8, 184u8, 136u8, 224u8, 184u8, 181u8, 224u8,
+                    184u8, 153u8, 224u8, 184u8, 129u8, 224u8, 184u8, 165u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 135u8, 32u8, 40u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8, 149u8, 224u8, 185u8,
+                    135u8, 224u8, 184u8, 161u8, 41u8,
+                ])
+            },
+        )
+    },
+};
+static TR: &DataStruct = &::icu_displaynames::provider::LocaleDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    22u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 13u8, 0u8, 18u8, 0u8, 23u8, 0u8, 28u8,
+                    0u8, 33u8, 0u8, 38u8, 0u8, 43u8, 0u8, 49u8, 0u8, 54u8, 0u8, 59u8, 0u8, 64u8,
+                    0u8, 69u8, 0u8, 74u8, 0u8, 80u8, 0u8, 85u8, 0u8, 90u8, 0u8, 95u8, 0u8, 100u8,
+                    0u8, 105u8, 0u8, 112u8, 0u8, 97u8, 114u8, 45u8, 48u8, 48u8, 49u8, 97u8, 122u8,
+                    45u8, 65u8, 114u8, 97u8, 98u8, 100u8, 101u8, 45u8, 65u8, 84u8, 100u8, 101u8,
+                    45u8, 67u8, 72u8, 101u8, 110u8, 45u8, 65u8, 85u8, 101u8, 110u8, 45u8, 67u8,
+                    65u8, 101u8, 110u8, 45u8, 71u8, 66u8, 101u8, 110u8, 45u8, 85u8, 83u8, 101u8,
+                    115u8, 45u8, 52u8, 49u8, 57u8, 101u8, 115u8, 45u8, 69u8, 83u8, 101u8, 115u8,
+                    45u8, 77u8, 88u8, 102u8, 97u8, 45u8, 65u8, 70u8, 102u8, 114u8, 45u8, 67u8,
+                    65u8, 102u8, 114u8, 45u8, 67u8, 72u8, 110u8, 100u8, 115u8, 45u8, 78u8, 76u8,
+                    110u8, 108u8, 45u8, 66u8, 69u8, 112u8, 116u8, 45u8, 66u8, 82u8, 112u8, 116u8,
+                    45u8, 80u8, 84u8, 114u8, 111u8, 45u8, 77u8, 68u8, 115u8, 119u8, 45u8, 67u8,
+                    68u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8, 115u8, 122u8, 104u8, 45u8, 72u8,
+                    97u8, 110u8, 116u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    22u8, 0u8, 0u8, 0u8, 0u8, 0u8, 23u8, 0u8, 37u8, 0u8, 57u8, 0u8, 85u8, 0u8,
+                    108u8, 0u8, 127u8, 0u8, 148u8, 0u8, 169u8, 0u8, 197u8, 0u8, 218u8, 0u8, 240u8,
+                    0u8, 246u8, 0u8, 10u8, 1u8, 33u8, 1u8, 50u8, 1u8, 58u8, 1u8, 79u8, 1u8, 98u8,
+                    1u8, 107u8, 1u8, 120u8, 1u8, 145u8, 1u8, 77u8, 111u8, 100u8, 101u8, 114u8,
+                    110u8, 32u8, 83u8, 116u8, 97u8, 110u8, 100u8, 97u8, 114u8, 116u8, 32u8, 65u8,
+                    114u8, 97u8, 112u8, 195u8, 167u8, 97u8, 71u8, 195u8, 188u8, 110u8, 101u8,
+                    121u8, 32u8, 65u8, 122u8, 101u8, 114u8, 105u8, 99u8, 101u8, 65u8, 118u8, 117u8,
+                    115u8, 116u8, 117u8, 114u8, 121u8, 97u8, 32u8, 65u8, 108u8, 109u8, 97u8, 110u8,
+                    99u8, 97u8, 115u8, 196u8, 177u8, 196u8, 176u8, 115u8, 118u8, 105u8, 195u8,
+                    167u8, 114u8, 101u8, 32u8, 89u8, 195u8, 188u8, 107u8, 115u8, 101u8, 107u8,
+                    32u8, 65u8, 108u8, 109u8, 97u8, 110u8, 99u8, 97u8, 115u8, 196u8, 177u8, 65u8,
+                    118u8, 117u8, 115u8, 116u8, 114u8, 97u8, 108u8, 121u8, 97u8, 32u8, 196u8,
+                    176u8, 110u8, 103u8, 105u8, 108u8, 105u8, 122u8, 99u8, 101u8, 115u8, 105u8,
+                    75u8, 97u8, 110u8, 97u8, 100u8, 97u8, 32u8, 196u8, 176u8, 110u8, 103u8, 105u8,
+                    108u8, 105u8, 122u8, 99u8, 101u8, 115u8, 105u8, 196u8, 176u8, 110u8, 103u8,
+                    105u8, 108u8, 105u8, 122u8, 32u8, 196u8, 176u8, 110u8, 103u8, 105u8, 108u8,
+                    105u8, 122u8, 99u8, 101u8, 115u8, 105u8, 65u8, 109u8, 101u8, 114u8, 105u8,
+                    107u8, 97u8, 110u8, 32u8, 196u8, 176u8, 110u8, 103u8, 105u8, 108u8, 105u8,
+                    122u8, 99u8, 101u8, 115u8, 105u8, 76u8, 97u8, 116u8, 105u8, 110u8, 32u8, 65u8,
+                    109u8, 101u8, 114u8, 105u8, 107u8, 97u8, 32u8, 196u8, 176u8, 115u8, 112u8,
+                    97u8, 110u8, 121u8, 111u8, 108u8, 99u8, 97u8, 115u8, 196u8, 177u8, 65u8, 118u8,
+                    114u8, 117u8, 112u8, 97u8, 32u8, 196u8, 176u8, 115u8, 112u8, 97u8, 110u8,
+                    121u8, 111u8, 108u8, 99u8, 97u8, 115u8, 196u8, 177u8, 77u8, 101u8, 107u8,
+                    115u8, 105u8, 107u8, 97u8, 32u8, 196u8, 176u8, 115u8, 112u8, 97u8, 110u8,
+                    121u8, 111u8, 108u8, 99u8, 97u8, 115u8, 196u8, 177u8, 68u8, 97u8, 114u8, 105u8,
+                    99u8, 101u8, 75u8, 97u8, 110u8, 97u8, 100u8, 97u8, 32u8, 70u8, 114u8, 97u8,
+                    110u8, 115u8, 196u8, 177u8, 122u8, 99u8, 97u8, 115u8, 196u8, 177u8, 196u8,
+                    176u8, 115u8, 118u8, 105u8, 195u8, 167u8, 114u8, 101u8, 32u8, 70u8, 114u8,
+                    97u8, 110u8, 115u8, 196u8, 177u8, 122u8, 99u8, 97u8, 115u8, 196u8, 177u8, 65u8,
+                    197u8, 159u8, 97u8, 196u8, 159u8, 196u8, 177u8, 32u8, 83u8, 97u8, 107u8, 115u8,
+                    111u8, 110u8, 99u8, 97u8, 70u8, 108u8, 97u8, 109u8, 97u8, 110u8, 99u8, 97u8,
+                    66u8, 114u8, 101u8, 122u8, 105u8, 108u8, 121u8, 97u8, 32u8, 80u8, 111u8, 114u8,
+                    116u8, 101u8, 107u8, 105u8, 122u8, 99u8, 101u8, 115u8, 105u8, 65u8, 118u8,
+                    114u8, 117u8, 112u8, 97u8, 32u8, 80u8, 111u8, 114u8, 116u8, 101u8, 107u8,
+                    105u8, 122u8, 99u8, 101u8, 115u8, 105u8, 77u8, 111u8, 108u8, 100u8, 111u8,
+                    118u8, 97u8, 99u8, 97u8, 75u8, 111u8, 110u8, 103u8, 111u8, 32u8, 83u8, 118u8,
+                    97u8, 104u8, 105u8, 108u8, 105u8, 66u8, 97u8, 115u8, 105u8, 116u8, 108u8,
+                    101u8, 197u8, 159u8, 116u8, 105u8, 114u8, 105u8, 108u8, 109u8, 105u8, 197u8,
+                    159u8, 32u8, 195u8, 135u8, 105u8, 110u8, 99u8, 101u8, 71u8, 101u8, 108u8,
+                    101u8, 110u8, 101u8, 107u8, 115u8, 101u8, 108u8, 32u8, 195u8, 135u8, 105u8,
+                    110u8, 99u8, 101u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 101u8, 110u8, 45u8, 71u8, 66u8, 101u8,
+                    110u8, 45u8, 85u8, 83u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 31u8, 0u8, 66u8, 105u8, 114u8, 108u8, 101u8,
+                    197u8, 159u8, 105u8, 107u8, 32u8, 75u8, 114u8, 97u8, 108u8, 108u8, 196u8,
+                    177u8, 107u8, 32u8, 196u8, 176u8, 110u8, 103u8, 105u8, 108u8, 105u8, 122u8,
+                    99u8, 101u8, 115u8, 105u8, 65u8, 66u8, 68u8, 32u8, 196u8, 176u8, 110u8, 103u8,
+                    105u8, 108u8, 105u8, 122u8, 99u8, 101u8, 115u8, 105u8,
+                ])
+            },
+        )
+    },
+    long_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8,
+                    115u8, 122u8, 104u8, 45u8, 72u8, 97u8, 110u8, 116u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 36u8, 0u8, 66u8, 97u8, 115u8, 105u8, 116u8,
+                    108u8, 101u8, 197u8, 159u8, 116u8, 105u8, 114u8, 105u8, 108u8, 109u8, 105u8,
+                    197u8, 159u8, 32u8, 195u8, 135u8, 105u8, 110u8, 99u8, 101u8, 32u8, 40u8, 77u8,
+                    97u8, 110u8, 100u8, 97u8, 114u8, 105u8, 110u8, 41u8, 71u8, 101u8, 108u8, 101u8,
+                    110u8, 101u8, 107u8, 115u8, 101u8, 108u8, 32u8, 195u8, 135u8, 105u8, 110u8,
+                    99u8, 101u8, 32u8, 40u8, 77u8, 97u8, 110u8, 100u8, 97u8, 114u8, 105u8, 110u8,
+                    41u8,
+                ])
+            },
+        )
+    },
+};
+static UND: &DataStruct = &::icu_displaynames::provider::LocaleDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    long_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+};
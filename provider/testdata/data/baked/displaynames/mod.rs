@@ -1,2 +1,11 @@
 // @generated
+pub mod calendars_v1;
+pub mod currencies_v1;
+pub mod keys_v1;
+pub mod languages_v1;
+pub mod locales_v1;
+pub mod patterns_v1;
+pub mod scripts_v1;
 pub mod territories_v1;
+pub mod units_v1;
+pub mod variants_v1;
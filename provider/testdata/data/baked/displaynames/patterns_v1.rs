@@ -0,0 +1,46 @@
+// @generated
+#![cfg(feature = "icu_displaynames")]
+type DataStruct = < :: icu_displaynames :: provider :: LocaleDisplayPatternsV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[
+        ("ar", AR_AR_EG),
+        ("ar-EG", AR_AR_EG),
+        ("bn", BN_CCP_EN_EN_001_EN_ZA_ES_ES_AR_FIL),
+        ("ccp", BN_CCP_EN_EN_001_EN_ZA_ES_ES_AR_FIL),
+        ("en", BN_CCP_EN_EN_001_EN_ZA_ES_ES_AR_FIL),
+        ("en-001", BN_CCP_EN_EN_001_EN_ZA_ES_ES_AR_FIL),
+        ("en-ZA", BN_CCP_EN_EN_001_EN_ZA_ES_ES_AR_FIL),
+        ("es", BN_CCP_EN_EN_001_EN_ZA_ES_ES_AR_FIL),
+        ("es-AR", BN_CCP_EN_EN_001_EN_ZA_ES_ES_AR_FIL),
+        ("fil", BN_CCP_EN_EN_001_EN_ZA_ES_ES_AR_FIL),
+        ("fr", FR),
+        ("ja", JA),
+        ("ru", BN_CCP_EN_EN_001_EN_ZA_ES_ES_AR_FIL),
+        ("sr", BN_CCP_EN_EN_001_EN_ZA_ES_ES_AR_FIL),
+        ("sr-Cyrl", BN_CCP_EN_EN_001_EN_ZA_ES_ES_AR_FIL),
+        ("sr-Latn", BN_CCP_EN_EN_001_EN_ZA_ES_ES_AR_FIL),
+        ("th", BN_CCP_EN_EN_001_EN_ZA_ES_ES_AR_FIL),
+        ("tr", BN_CCP_EN_EN_001_EN_ZA_ES_ES_AR_FIL),
+        ("und", BN_CCP_EN_EN_001_EN_ZA_ES_ES_AR_FIL),
+    ]);
+static AR_AR_EG: &DataStruct = &::icu_displaynames::provider::LocaleDisplayPatternsV1 {
+    pattern: alloc::borrow::Cow::Borrowed("{0} ({1})"),
+    separator: alloc::borrow::Cow::Borrowed("{0}، {1}"),
+    key_type_pattern: alloc::borrow::Cow::Borrowed("{0}: {1}"),
+};
+static BN_CCP_EN_EN_001_EN_ZA_ES_ES_AR_FIL: &DataStruct =
+    &::icu_displaynames::provider::LocaleDisplayPatternsV1 {
+        pattern: alloc::borrow::Cow::Borrowed("{0} ({1})"),
+        separator: alloc::borrow::Cow::Borrowed("{0}, {1}"),
+        key_type_pattern: alloc::borrow::Cow::Borrowed("{0}: {1}"),
+    };
+static FR: &DataStruct = &::icu_displaynames::provider::LocaleDisplayPatternsV1 {
+    pattern: alloc::borrow::Cow::Borrowed("{0} ({1})"),
+    separator: alloc::borrow::Cow::Borrowed("{0}, {1}"),
+    key_type_pattern: alloc::borrow::Cow::Borrowed("{0}\u{202f}: {1}"),
+};
+static JA: &DataStruct = &::icu_displaynames::provider::LocaleDisplayPatternsV1 {
+    pattern: alloc::borrow::Cow::Borrowed("{0} ({1})"),
+    separator: alloc::borrow::Cow::Borrowed("{0}、{1}"),
+    key_type_pattern: alloc::borrow::Cow::Borrowed("{0}: {1}"),
+};
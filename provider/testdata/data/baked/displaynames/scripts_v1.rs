@@ -0,0 +1,4554 @@
+// @generated
+#![cfg(feature = "icu_displaynames")]
+type DataStruct = < :: icu_displaynames :: provider :: ScriptDisplayNamesV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[
+        ("ar", AR_AR_EG),
+        ("ar-EG", AR_AR_EG),
+        ("bn", BN),
+        ("ccp", CCP),
+        ("en", EN_EN_001_EN_ZA),
+        ("en-001", EN_EN_001_EN_ZA),
+        ("en-ZA", EN_EN_001_EN_ZA),
+        ("es", ES),
+        ("es-AR", ES_AR),
+        ("fil", FIL),
+        ("fr", FR),
+        ("ja", JA),
+        ("ru", RU),
+        ("sr", SR_SR_CYRL),
+        ("sr-Cyrl", SR_SR_CYRL),
+        ("sr-Latn", SR_LATN),
+        ("th", TH),
+        ("tr", TR),
+        ("und", UND),
+    ]);
+static AR_AR_EG: &DataStruct = &::icu_displaynames::provider::ScriptDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 100u8, 108u8, 109u8, 65u8, 114u8, 97u8, 98u8, 65u8, 114u8, 97u8, 110u8,
+                    65u8, 114u8, 109u8, 110u8, 66u8, 97u8, 108u8, 105u8, 66u8, 97u8, 116u8, 107u8,
+                    66u8, 101u8, 110u8, 103u8, 66u8, 108u8, 105u8, 115u8, 66u8, 111u8, 112u8,
+                    111u8, 66u8, 114u8, 97u8, 104u8, 66u8, 114u8, 97u8, 105u8, 66u8, 117u8, 103u8,
+                    105u8, 66u8, 117u8, 104u8, 100u8, 67u8, 97u8, 107u8, 109u8, 67u8, 97u8, 110u8,
+                    115u8, 67u8, 97u8, 114u8, 105u8, 67u8, 104u8, 97u8, 109u8, 67u8, 104u8, 101u8,
+                    114u8, 67u8, 105u8, 114u8, 116u8, 67u8, 111u8, 112u8, 116u8, 67u8, 112u8,
+                    114u8, 116u8, 67u8, 121u8, 114u8, 108u8, 67u8, 121u8, 114u8, 115u8, 68u8,
+                    101u8, 118u8, 97u8, 68u8, 115u8, 114u8, 116u8, 69u8, 103u8, 121u8, 100u8, 69u8,
+                    103u8, 121u8, 104u8, 69u8, 103u8, 121u8, 112u8, 69u8, 116u8, 104u8, 105u8,
+                    71u8, 101u8, 111u8, 107u8, 71u8, 101u8, 111u8, 114u8, 71u8, 108u8, 97u8, 103u8,
+                    71u8, 111u8, 116u8, 104u8, 71u8, 114u8, 101u8, 107u8, 71u8, 117u8, 106u8,
+                    114u8, 71u8, 117u8, 114u8, 117u8, 72u8, 97u8, 110u8, 98u8, 72u8, 97u8, 110u8,
+                    103u8, 72u8, 97u8, 110u8, 105u8, 72u8, 97u8, 110u8, 111u8, 72u8, 97u8, 110u8,
+                    115u8, 72u8, 97u8, 110u8, 116u8, 72u8, 101u8, 98u8, 114u8, 72u8, 105u8, 114u8,
+                    97u8, 72u8, 109u8, 110u8, 103u8, 72u8, 114u8, 107u8, 116u8, 72u8, 117u8, 110u8,
+                    103u8, 73u8, 110u8, 100u8, 115u8, 73u8, 116u8, 97u8, 108u8, 74u8, 97u8, 109u8,
+                    111u8, 74u8, 97u8, 118u8, 97u8, 74u8, 112u8, 97u8, 110u8, 75u8, 97u8, 108u8,
+                    105u8, 75u8, 97u8, 110u8, 97u8, 75u8, 104u8, 97u8, 114u8, 75u8, 104u8, 109u8,
+                    114u8, 75u8, 110u8, 100u8, 97u8, 75u8, 111u8, 114u8, 101u8, 76u8, 97u8, 110u8,
+                    97u8, 76u8, 97u8, 111u8, 111u8, 76u8, 97u8, 116u8, 102u8, 76u8, 97u8, 116u8,
+                    103u8, 76u8, 97u8, 116u8, 110u8, 76u8, 101u8, 112u8, 99u8, 76u8, 105u8, 109u8,
+                    98u8, 76u8, 105u8, 110u8, 97u8, 76u8, 105u8, 110u8, 98u8, 76u8, 121u8, 99u8,
+                    105u8, 76u8, 121u8, 100u8, 105u8, 77u8, 97u8, 110u8, 100u8, 77u8, 97u8, 121u8,
+                    97u8, 77u8, 101u8, 114u8, 111u8, 77u8, 108u8, 121u8, 109u8, 77u8, 111u8, 110u8,
+                    103u8, 77u8, 111u8, 111u8, 110u8, 77u8, 116u8, 101u8, 105u8, 77u8, 121u8,
+                    109u8, 114u8, 78u8, 97u8, 114u8, 98u8, 78u8, 107u8, 111u8, 111u8, 79u8, 103u8,
+                    97u8, 109u8, 79u8, 108u8, 99u8, 107u8, 79u8, 114u8, 107u8, 104u8, 79u8, 114u8,
+                    121u8, 97u8, 79u8, 115u8, 109u8, 97u8, 80u8, 101u8, 114u8, 109u8, 80u8, 104u8,
+                    97u8, 103u8, 80u8, 104u8, 110u8, 120u8, 80u8, 108u8, 114u8, 100u8, 81u8, 97u8,
+                    97u8, 103u8, 82u8, 111u8, 104u8, 103u8, 82u8, 111u8, 114u8, 111u8, 82u8, 117u8,
+                    110u8, 114u8, 83u8, 97u8, 114u8, 97u8, 83u8, 97u8, 114u8, 98u8, 83u8, 104u8,
+                    97u8, 119u8, 83u8, 105u8, 110u8, 104u8, 83u8, 117u8, 110u8, 100u8, 83u8, 121u8,
+                    108u8, 111u8, 83u8, 121u8, 114u8, 99u8, 83u8, 121u8, 114u8, 101u8, 83u8, 121u8,
+                    114u8, 106u8, 83u8, 121u8, 114u8, 110u8, 84u8, 97u8, 103u8, 98u8, 84u8, 97u8,
+                    108u8, 101u8, 84u8, 97u8, 108u8, 117u8, 84u8, 97u8, 109u8, 108u8, 84u8, 101u8,
+                    108u8, 117u8, 84u8, 101u8, 110u8, 103u8, 84u8, 102u8, 110u8, 103u8, 84u8,
+                    103u8, 108u8, 103u8, 84u8, 104u8, 97u8, 97u8, 84u8, 104u8, 97u8, 105u8, 84u8,
+                    105u8, 98u8, 116u8, 85u8, 103u8, 97u8, 114u8, 86u8, 97u8, 105u8, 105u8, 86u8,
+                    105u8, 115u8, 112u8, 88u8, 112u8, 101u8, 111u8, 88u8, 115u8, 117u8, 120u8,
+                    89u8, 105u8, 105u8, 105u8, 90u8, 105u8, 110u8, 104u8, 90u8, 109u8, 116u8,
+                    104u8, 90u8, 115u8, 121u8, 101u8, 90u8, 115u8, 121u8, 109u8, 90u8, 120u8,
+                    120u8, 120u8, 90u8, 121u8, 121u8, 121u8, 90u8, 122u8, 122u8, 122u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    126u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 22u8, 0u8, 36u8, 0u8, 54u8, 0u8,
+                    68u8, 0u8, 82u8, 0u8, 100u8, 0u8, 117u8, 0u8, 137u8, 0u8, 155u8, 0u8, 169u8,
+                    0u8, 185u8, 0u8, 201u8, 0u8, 211u8, 0u8, 254u8, 0u8, 12u8, 1u8, 28u8, 1u8,
+                    44u8, 1u8, 56u8, 1u8, 70u8, 1u8, 86u8, 1u8, 104u8, 1u8, 169u8, 1u8, 193u8, 1u8,
+                    211u8, 1u8, 233u8, 1u8, 255u8, 1u8, 23u8, 2u8, 41u8, 2u8, 111u8, 2u8, 127u8,
+                    2u8, 151u8, 2u8, 165u8, 2u8, 183u8, 2u8, 205u8, 2u8, 219u8, 2u8, 227u8, 2u8,
+                    243u8, 2u8, 253u8, 2u8, 13u8, 3u8, 27u8, 3u8, 45u8, 3u8, 59u8, 3u8, 79u8, 3u8,
+                    104u8, 3u8, 144u8, 3u8, 173u8, 3u8, 198u8, 3u8, 231u8, 3u8, 239u8, 3u8, 253u8,
+                    3u8, 15u8, 4u8, 32u8, 4u8, 48u8, 4u8, 66u8, 4u8, 82u8, 4u8, 98u8, 4u8, 112u8,
+                    4u8, 122u8, 4u8, 132u8, 4u8, 176u8, 4u8, 216u8, 4u8, 234u8, 4u8, 5u8, 5u8,
+                    19u8, 5u8, 34u8, 5u8, 49u8, 5u8, 63u8, 5u8, 77u8, 5u8, 99u8, 5u8, 136u8, 5u8,
+                    156u8, 5u8, 176u8, 5u8, 192u8, 5u8, 198u8, 5u8, 217u8, 5u8, 235u8, 5u8, 25u8,
+                    6u8, 33u8, 6u8, 49u8, 6u8, 66u8, 6u8, 82u8, 6u8, 96u8, 6u8, 116u8, 6u8, 151u8,
+                    6u8, 167u8, 6u8, 185u8, 6u8, 214u8, 6u8, 224u8, 6u8, 238u8, 6u8, 254u8, 6u8,
+                    10u8, 7u8, 26u8, 7u8, 72u8, 7u8, 86u8, 7u8, 104u8, 7u8, 124u8, 7u8, 151u8, 7u8,
+                    169u8, 7u8, 212u8, 7u8, 245u8, 7u8, 22u8, 8u8, 42u8, 8u8, 57u8, 8u8, 85u8, 8u8,
+                    103u8, 8u8, 117u8, 8u8, 135u8, 8u8, 153u8, 8u8, 173u8, 8u8, 185u8, 8u8, 207u8,
+                    8u8, 221u8, 8u8, 245u8, 8u8, 255u8, 8u8, 24u8, 9u8, 55u8, 9u8, 120u8, 9u8,
+                    128u8, 9u8, 142u8, 9u8, 163u8, 9u8, 175u8, 9u8, 183u8, 9u8, 200u8, 9u8, 206u8,
+                    9u8, 216u8, 163u8, 216u8, 175u8, 217u8, 132u8, 217u8, 133u8, 216u8, 167u8,
+                    217u8, 132u8, 216u8, 185u8, 216u8, 177u8, 216u8, 168u8, 217u8, 138u8, 216u8,
+                    169u8, 217u8, 134u8, 216u8, 179u8, 216u8, 170u8, 216u8, 185u8, 217u8, 132u8,
+                    217u8, 138u8, 217u8, 130u8, 216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 216u8,
+                    177u8, 217u8, 133u8, 217u8, 138u8, 217u8, 134u8, 217u8, 138u8, 216u8, 169u8,
+                    216u8, 167u8, 217u8, 132u8, 216u8, 168u8, 216u8, 167u8, 217u8, 132u8, 217u8,
+                    138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 168u8, 216u8, 167u8,
+                    216u8, 170u8, 216u8, 167u8, 217u8, 131u8, 216u8, 167u8, 217u8, 132u8, 216u8,
+                    168u8, 217u8, 134u8, 216u8, 186u8, 216u8, 167u8, 217u8, 132u8, 217u8, 138u8,
+                    216u8, 169u8, 216u8, 177u8, 217u8, 133u8, 217u8, 136u8, 216u8, 178u8, 32u8,
+                    216u8, 168u8, 217u8, 132u8, 217u8, 138u8, 216u8, 179u8, 216u8, 167u8, 217u8,
+                    132u8, 216u8, 168u8, 217u8, 136u8, 216u8, 168u8, 217u8, 136u8, 217u8, 133u8,
+                    217u8, 136u8, 217u8, 129u8, 217u8, 136u8, 216u8, 167u8, 217u8, 132u8, 217u8,
+                    135u8, 217u8, 134u8, 216u8, 175u8, 217u8, 136u8, 216u8, 179u8, 217u8, 138u8,
+                    216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 168u8, 216u8, 177u8, 216u8,
+                    167u8, 217u8, 138u8, 217u8, 132u8, 216u8, 167u8, 217u8, 132u8, 216u8, 168u8,
+                    216u8, 172u8, 217u8, 138u8, 217u8, 134u8, 217u8, 138u8, 216u8, 178u8, 216u8,
+                    167u8, 217u8, 132u8, 216u8, 168u8, 217u8, 135u8, 217u8, 138u8, 216u8, 175u8,
+                    217u8, 138u8, 216u8, 169u8, 216u8, 180u8, 216u8, 167u8, 217u8, 131u8, 217u8,
+                    133u8, 216u8, 167u8, 217u8, 133u8, 217u8, 130u8, 216u8, 167u8, 216u8, 183u8,
+                    216u8, 185u8, 32u8, 217u8, 131u8, 217u8, 134u8, 216u8, 175u8, 217u8, 138u8,
+                    216u8, 169u8, 32u8, 216u8, 163u8, 216u8, 181u8, 217u8, 132u8, 217u8, 138u8,
+                    216u8, 169u8, 32u8, 217u8, 133u8, 217u8, 136u8, 216u8, 173u8, 216u8, 175u8,
+                    216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 217u8, 131u8, 216u8, 167u8, 216u8,
+                    177u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 170u8,
+                    216u8, 180u8, 216u8, 167u8, 217u8, 133u8, 217u8, 138u8, 216u8, 169u8, 216u8,
+                    167u8, 217u8, 132u8, 216u8, 180u8, 217u8, 138u8, 216u8, 177u8, 217u8, 136u8,
+                    217u8, 131u8, 217u8, 138u8, 216u8, 167u8, 217u8, 132u8, 216u8, 179u8, 217u8,
+                    138u8, 216u8, 177u8, 216u8, 171u8, 216u8, 167u8, 217u8, 132u8, 217u8, 130u8,
+                    216u8, 168u8, 216u8, 183u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8,
+                    132u8, 217u8, 130u8, 216u8, 168u8, 216u8, 177u8, 216u8, 181u8, 217u8, 138u8,
+                    216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 179u8, 217u8, 138u8, 216u8,
+                    177u8, 217u8, 138u8, 217u8, 132u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8,
+                    217u8, 132u8, 216u8, 179u8, 217u8, 138u8, 216u8, 177u8, 217u8, 138u8, 217u8,
+                    132u8, 217u8, 138u8, 216u8, 169u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8,
+                    179u8, 217u8, 132u8, 216u8, 167u8, 217u8, 129u8, 217u8, 138u8, 216u8, 169u8,
+                    32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 131u8, 217u8, 134u8, 216u8, 179u8,
+                    217u8, 138u8, 216u8, 169u8, 32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 130u8,
+                    216u8, 175u8, 217u8, 138u8, 217u8, 133u8, 216u8, 169u8, 216u8, 167u8, 217u8,
+                    132u8, 216u8, 175u8, 217u8, 138u8, 217u8, 129u8, 216u8, 167u8, 217u8, 134u8,
+                    216u8, 167u8, 216u8, 172u8, 216u8, 167u8, 216u8, 177u8, 217u8, 138u8, 216u8,
+                    167u8, 217u8, 132u8, 216u8, 175u8, 217u8, 138u8, 216u8, 179u8, 217u8, 138u8,
+                    216u8, 177u8, 217u8, 138u8, 216u8, 170u8, 216u8, 167u8, 217u8, 132u8, 216u8,
+                    175u8, 217u8, 138u8, 217u8, 133u8, 217u8, 136u8, 216u8, 183u8, 217u8, 138u8,
+                    217u8, 130u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 217u8,
+                    135u8, 217u8, 138u8, 216u8, 177u8, 216u8, 167u8, 216u8, 183u8, 217u8, 138u8,
+                    217u8, 130u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 217u8,
+                    135u8, 217u8, 138u8, 216u8, 177u8, 217u8, 136u8, 216u8, 186u8, 217u8, 132u8,
+                    217u8, 138u8, 217u8, 129u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8,
+                    132u8, 216u8, 163u8, 216u8, 171u8, 217u8, 138u8, 217u8, 136u8, 216u8, 168u8,
+                    217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 216u8,
+                    168u8, 216u8, 172u8, 216u8, 175u8, 217u8, 138u8, 216u8, 169u8, 32u8, 216u8,
+                    167u8, 217u8, 132u8, 216u8, 172u8, 217u8, 136u8, 216u8, 177u8, 216u8, 172u8,
+                    217u8, 138u8, 216u8, 169u8, 32u8, 45u8, 32u8, 216u8, 163u8, 216u8, 179u8,
+                    217u8, 136u8, 217u8, 133u8, 216u8, 170u8, 216u8, 167u8, 217u8, 129u8, 216u8,
+                    177u8, 217u8, 132u8, 217u8, 138u8, 32u8, 217u8, 136u8, 32u8, 217u8, 134u8,
+                    216u8, 179u8, 216u8, 174u8, 216u8, 177u8, 217u8, 138u8, 216u8, 167u8, 217u8,
+                    132u8, 216u8, 172u8, 217u8, 136u8, 216u8, 177u8, 216u8, 172u8, 217u8, 138u8,
+                    216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 172u8, 217u8, 132u8, 216u8,
+                    167u8, 216u8, 172u8, 217u8, 136u8, 217u8, 132u8, 217u8, 138u8, 216u8, 170u8,
+                    217u8, 138u8, 217u8, 131u8, 216u8, 167u8, 217u8, 132u8, 217u8, 130u8, 217u8,
+                    136u8, 216u8, 183u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8,
+                    217u8, 138u8, 217u8, 136u8, 217u8, 134u8, 216u8, 167u8, 217u8, 134u8, 217u8,
+                    138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 170u8, 216u8, 167u8,
+                    216u8, 186u8, 216u8, 172u8, 216u8, 177u8, 216u8, 167u8, 216u8, 170u8, 217u8,
+                    138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 172u8, 216u8, 177u8,
+                    217u8, 133u8, 216u8, 174u8, 217u8, 138u8, 217u8, 135u8, 216u8, 167u8, 217u8,
+                    134u8, 216u8, 168u8, 216u8, 167u8, 217u8, 132u8, 217u8, 135u8, 216u8, 167u8,
+                    217u8, 134u8, 216u8, 186u8, 217u8, 136u8, 217u8, 132u8, 216u8, 167u8, 217u8,
+                    132u8, 217u8, 135u8, 216u8, 167u8, 217u8, 134u8, 216u8, 167u8, 217u8, 132u8,
+                    217u8, 135u8, 216u8, 167u8, 217u8, 134u8, 217u8, 136u8, 217u8, 134u8, 217u8,
+                    136u8, 216u8, 167u8, 217u8, 132u8, 217u8, 133u8, 216u8, 168u8, 216u8, 179u8,
+                    216u8, 183u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 170u8, 217u8,
+                    130u8, 217u8, 132u8, 217u8, 138u8, 216u8, 175u8, 217u8, 138u8, 216u8, 169u8,
+                    216u8, 167u8, 217u8, 132u8, 216u8, 185u8, 216u8, 168u8, 216u8, 177u8, 217u8,
+                    138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 217u8, 135u8, 217u8, 138u8,
+                    216u8, 177u8, 216u8, 167u8, 216u8, 172u8, 216u8, 167u8, 217u8, 134u8, 216u8,
+                    167u8, 216u8, 167u8, 217u8, 132u8, 216u8, 168u8, 216u8, 167u8, 217u8, 135u8,
+                    217u8, 136u8, 217u8, 135u8, 32u8, 217u8, 135u8, 217u8, 133u8, 217u8, 136u8,
+                    217u8, 134u8, 216u8, 172u8, 216u8, 163u8, 216u8, 168u8, 216u8, 172u8, 216u8,
+                    175u8, 217u8, 138u8, 216u8, 169u8, 32u8, 217u8, 133u8, 217u8, 130u8, 216u8,
+                    183u8, 216u8, 185u8, 217u8, 138u8, 216u8, 169u8, 32u8, 217u8, 138u8, 216u8,
+                    167u8, 216u8, 168u8, 216u8, 167u8, 217u8, 134u8, 217u8, 138u8, 216u8, 169u8,
+                    216u8, 167u8, 217u8, 132u8, 217u8, 133u8, 216u8, 172u8, 216u8, 177u8, 217u8,
+                    138u8, 216u8, 169u8, 32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 130u8, 216u8,
+                    175u8, 217u8, 138u8, 217u8, 133u8, 216u8, 169u8, 216u8, 167u8, 217u8, 134u8,
+                    216u8, 175u8, 216u8, 179u8, 32u8, 45u8, 32u8, 217u8, 135u8, 216u8, 167u8,
+                    216u8, 177u8, 216u8, 167u8, 216u8, 168u8, 216u8, 167u8, 217u8, 134u8, 216u8,
+                    167u8, 217u8, 132u8, 216u8, 165u8, 217u8, 138u8, 216u8, 183u8, 216u8, 167u8,
+                    217u8, 132u8, 217u8, 138u8, 216u8, 169u8, 32u8, 216u8, 167u8, 217u8, 132u8,
+                    217u8, 130u8, 216u8, 175u8, 217u8, 138u8, 217u8, 133u8, 216u8, 169u8, 216u8,
+                    172u8, 216u8, 167u8, 217u8, 133u8, 217u8, 136u8, 216u8, 167u8, 217u8, 132u8,
+                    216u8, 172u8, 216u8, 167u8, 217u8, 136u8, 217u8, 138u8, 216u8, 169u8, 216u8,
+                    167u8, 217u8, 132u8, 217u8, 138u8, 216u8, 167u8, 216u8, 168u8, 216u8, 167u8,
+                    217u8, 134u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 217u8,
+                    131u8, 217u8, 138u8, 216u8, 167u8, 217u8, 135u8, 32u8, 217u8, 132u8, 217u8,
+                    137u8, 216u8, 167u8, 217u8, 132u8, 217u8, 131u8, 216u8, 170u8, 217u8, 131u8,
+                    216u8, 167u8, 217u8, 134u8, 216u8, 167u8, 216u8, 167u8, 217u8, 132u8, 216u8,
+                    174u8, 216u8, 167u8, 216u8, 177u8, 217u8, 136u8, 216u8, 180u8, 216u8, 170u8,
+                    217u8, 137u8, 216u8, 167u8, 217u8, 132u8, 216u8, 174u8, 217u8, 133u8, 217u8,
+                    138u8, 216u8, 177u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8,
+                    217u8, 131u8, 216u8, 167u8, 217u8, 134u8, 216u8, 167u8, 216u8, 175u8, 216u8,
+                    167u8, 216u8, 167u8, 217u8, 132u8, 217u8, 131u8, 217u8, 136u8, 216u8, 177u8,
+                    217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 167u8, 217u8,
+                    134u8, 216u8, 167u8, 216u8, 167u8, 217u8, 132u8, 217u8, 132u8, 216u8, 167u8,
+                    217u8, 136u8, 216u8, 167u8, 217u8, 132u8, 217u8, 132u8, 216u8, 167u8, 216u8,
+                    170u8, 217u8, 138u8, 217u8, 134u8, 217u8, 138u8, 216u8, 169u8, 32u8, 45u8,
+                    32u8, 217u8, 133u8, 216u8, 170u8, 216u8, 186u8, 217u8, 138u8, 216u8, 177u8,
+                    32u8, 217u8, 129u8, 216u8, 177u8, 216u8, 167u8, 217u8, 131u8, 216u8, 170u8,
+                    216u8, 177u8, 216u8, 167u8, 217u8, 132u8, 217u8, 132u8, 216u8, 167u8, 216u8,
+                    170u8, 217u8, 138u8, 217u8, 134u8, 217u8, 138u8, 216u8, 169u8, 32u8, 45u8,
+                    32u8, 217u8, 133u8, 216u8, 170u8, 216u8, 186u8, 217u8, 138u8, 216u8, 177u8,
+                    32u8, 216u8, 186u8, 217u8, 138u8, 217u8, 132u8, 217u8, 137u8, 216u8, 167u8,
+                    217u8, 132u8, 217u8, 132u8, 216u8, 167u8, 216u8, 170u8, 217u8, 138u8, 217u8,
+                    134u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 217u8, 132u8,
+                    217u8, 138u8, 216u8, 168u8, 216u8, 170u8, 216u8, 180u8, 216u8, 167u8, 32u8,
+                    45u8, 32u8, 216u8, 177u8, 217u8, 136u8, 217u8, 134u8, 216u8, 172u8, 216u8,
+                    167u8, 217u8, 132u8, 217u8, 132u8, 217u8, 138u8, 217u8, 133u8, 216u8, 168u8,
+                    217u8, 136u8, 216u8, 167u8, 217u8, 132u8, 216u8, 174u8, 216u8, 183u8, 217u8,
+                    138u8, 216u8, 169u8, 32u8, 216u8, 163u8, 216u8, 167u8, 217u8, 132u8, 216u8,
+                    174u8, 216u8, 183u8, 217u8, 138u8, 216u8, 169u8, 32u8, 216u8, 168u8, 216u8,
+                    167u8, 217u8, 132u8, 217u8, 132u8, 217u8, 138u8, 216u8, 179u8, 217u8, 138u8,
+                    216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 217u8, 132u8, 217u8, 138u8, 216u8,
+                    175u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 217u8, 133u8,
+                    216u8, 167u8, 217u8, 134u8, 216u8, 175u8, 216u8, 167u8, 217u8, 138u8, 217u8,
+                    134u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 217u8, 133u8,
+                    216u8, 167u8, 217u8, 138u8, 216u8, 167u8, 32u8, 216u8, 167u8, 217u8, 132u8,
+                    217u8, 135u8, 217u8, 138u8, 216u8, 177u8, 217u8, 136u8, 216u8, 186u8, 217u8,
+                    132u8, 217u8, 138u8, 217u8, 129u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8,
+                    217u8, 132u8, 217u8, 133u8, 217u8, 138u8, 216u8, 177u8, 217u8, 136u8, 217u8,
+                    138u8, 216u8, 170u8, 217u8, 138u8, 217u8, 131u8, 216u8, 167u8, 217u8, 132u8,
+                    217u8, 133u8, 216u8, 167u8, 217u8, 132u8, 217u8, 138u8, 216u8, 167u8, 217u8,
+                    132u8, 216u8, 167u8, 217u8, 133u8, 216u8, 167u8, 217u8, 132u8, 217u8, 133u8,
+                    216u8, 186u8, 217u8, 136u8, 217u8, 132u8, 217u8, 138u8, 216u8, 169u8, 217u8,
+                    133u8, 217u8, 136u8, 217u8, 134u8, 217u8, 133u8, 217u8, 138u8, 216u8, 170u8,
+                    217u8, 138u8, 32u8, 217u8, 133u8, 216u8, 167u8, 217u8, 138u8, 217u8, 138u8,
+                    217u8, 131u8, 216u8, 167u8, 217u8, 132u8, 217u8, 133u8, 217u8, 138u8, 216u8,
+                    167u8, 217u8, 134u8, 217u8, 133u8, 216u8, 167u8, 216u8, 177u8, 216u8, 167u8,
+                    217u8, 132u8, 216u8, 185u8, 216u8, 177u8, 216u8, 168u8, 217u8, 138u8, 216u8,
+                    169u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 180u8, 217u8, 133u8, 216u8,
+                    167u8, 217u8, 132u8, 217u8, 138u8, 216u8, 169u8, 32u8, 216u8, 167u8, 217u8,
+                    132u8, 217u8, 130u8, 216u8, 175u8, 217u8, 138u8, 217u8, 133u8, 216u8, 169u8,
+                    216u8, 163u8, 217u8, 134u8, 217u8, 131u8, 217u8, 136u8, 216u8, 167u8, 217u8,
+                    132u8, 216u8, 163u8, 217u8, 136u8, 216u8, 172u8, 217u8, 135u8, 216u8, 167u8,
+                    217u8, 133u8, 216u8, 163u8, 217u8, 136u8, 217u8, 132u8, 32u8, 216u8, 170u8,
+                    216u8, 180u8, 217u8, 138u8, 217u8, 131u8, 217u8, 138u8, 216u8, 167u8, 217u8,
+                    132u8, 216u8, 163u8, 217u8, 136u8, 216u8, 177u8, 216u8, 174u8, 217u8, 136u8,
+                    217u8, 134u8, 216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 217u8, 136u8, 216u8,
+                    177u8, 217u8, 138u8, 216u8, 167u8, 216u8, 167u8, 217u8, 132u8, 216u8, 163u8,
+                    217u8, 136u8, 216u8, 179u8, 217u8, 133u8, 216u8, 167u8, 217u8, 134u8, 217u8,
+                    138u8, 216u8, 167u8, 216u8, 167u8, 217u8, 132u8, 216u8, 168u8, 217u8, 138u8,
+                    216u8, 177u8, 217u8, 133u8, 217u8, 138u8, 217u8, 131u8, 217u8, 138u8, 216u8,
+                    169u8, 32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 130u8, 216u8, 175u8, 217u8,
+                    138u8, 217u8, 133u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 217u8, 129u8,
+                    216u8, 167u8, 216u8, 172u8, 216u8, 179u8, 216u8, 168u8, 216u8, 167u8, 216u8,
+                    167u8, 217u8, 132u8, 217u8, 129u8, 217u8, 138u8, 217u8, 134u8, 217u8, 138u8,
+                    217u8, 130u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8,
+                    181u8, 217u8, 136u8, 216u8, 170u8, 217u8, 138u8, 216u8, 167u8, 216u8, 170u8,
+                    32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 172u8, 217u8, 133u8, 216u8, 167u8,
+                    216u8, 161u8, 216u8, 178u8, 217u8, 136u8, 216u8, 172u8, 217u8, 138u8, 217u8,
+                    138u8, 216u8, 167u8, 217u8, 132u8, 216u8, 173u8, 217u8, 134u8, 217u8, 138u8,
+                    217u8, 129u8, 217u8, 138u8, 216u8, 177u8, 217u8, 134u8, 216u8, 172u8, 217u8,
+                    136u8, 216u8, 177u8, 217u8, 134u8, 216u8, 172u8, 217u8, 136u8, 216u8, 167u8,
+                    217u8, 132u8, 216u8, 177u8, 217u8, 136u8, 217u8, 134u8, 217u8, 138u8, 216u8,
+                    167u8, 217u8, 132u8, 216u8, 179u8, 216u8, 167u8, 216u8, 177u8, 216u8, 167u8,
+                    216u8, 170u8, 217u8, 138u8, 216u8, 167u8, 217u8, 132u8, 216u8, 185u8, 216u8,
+                    177u8, 216u8, 168u8, 217u8, 138u8, 216u8, 169u8, 32u8, 216u8, 167u8, 217u8,
+                    132u8, 216u8, 172u8, 217u8, 134u8, 217u8, 136u8, 216u8, 168u8, 217u8, 138u8,
+                    216u8, 169u8, 32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 130u8, 216u8, 175u8,
+                    217u8, 138u8, 217u8, 133u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8,
+                    180u8, 217u8, 136u8, 216u8, 167u8, 217u8, 134u8, 217u8, 138u8, 216u8, 167u8,
+                    217u8, 132u8, 216u8, 179u8, 217u8, 138u8, 217u8, 134u8, 217u8, 135u8, 216u8,
+                    167u8, 217u8, 132u8, 216u8, 167u8, 216u8, 167u8, 217u8, 132u8, 216u8, 179u8,
+                    217u8, 136u8, 217u8, 134u8, 216u8, 175u8, 216u8, 167u8, 217u8, 134u8, 217u8,
+                    138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 179u8, 217u8, 138u8,
+                    217u8, 132u8, 217u8, 136u8, 216u8, 170u8, 217u8, 138u8, 32u8, 217u8, 134u8,
+                    216u8, 167u8, 216u8, 186u8, 216u8, 177u8, 217u8, 138u8, 216u8, 167u8, 217u8,
+                    132u8, 216u8, 179u8, 216u8, 177u8, 217u8, 138u8, 216u8, 167u8, 217u8, 134u8,
+                    217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 179u8, 216u8,
+                    177u8, 217u8, 138u8, 216u8, 167u8, 217u8, 134u8, 217u8, 138u8, 216u8, 169u8,
+                    32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 216u8, 179u8, 216u8, 170u8,
+                    216u8, 177u8, 217u8, 134u8, 216u8, 172u8, 217u8, 138u8, 217u8, 132u8, 217u8,
+                    138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 179u8, 216u8, 177u8,
+                    217u8, 138u8, 216u8, 167u8, 217u8, 134u8, 217u8, 138u8, 216u8, 169u8, 32u8,
+                    216u8, 167u8, 217u8, 132u8, 216u8, 186u8, 216u8, 177u8, 216u8, 168u8, 217u8,
+                    138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 179u8, 216u8, 177u8,
+                    217u8, 138u8, 216u8, 167u8, 217u8, 134u8, 217u8, 138u8, 216u8, 169u8, 32u8,
+                    216u8, 167u8, 217u8, 132u8, 216u8, 180u8, 216u8, 177u8, 217u8, 130u8, 217u8,
+                    138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 170u8, 216u8, 167u8,
+                    216u8, 172u8, 216u8, 168u8, 216u8, 167u8, 217u8, 134u8, 217u8, 136u8, 216u8,
+                    167u8, 216u8, 167u8, 217u8, 132u8, 216u8, 170u8, 216u8, 167u8, 217u8, 138u8,
+                    32u8, 217u8, 132u8, 217u8, 138u8, 216u8, 167u8, 217u8, 132u8, 216u8, 170u8,
+                    216u8, 167u8, 217u8, 137u8, 32u8, 217u8, 132u8, 217u8, 137u8, 32u8, 216u8,
+                    167u8, 217u8, 132u8, 216u8, 172u8, 216u8, 175u8, 217u8, 138u8, 216u8, 175u8,
+                    216u8, 167u8, 217u8, 132u8, 216u8, 170u8, 216u8, 167u8, 217u8, 133u8, 217u8,
+                    138u8, 217u8, 132u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8,
+                    216u8, 170u8, 217u8, 138u8, 217u8, 132u8, 216u8, 172u8, 217u8, 136u8, 216u8,
+                    167u8, 217u8, 132u8, 216u8, 170u8, 217u8, 138u8, 217u8, 134u8, 216u8, 172u8,
+                    217u8, 136u8, 216u8, 167u8, 216u8, 177u8, 216u8, 167u8, 217u8, 132u8, 216u8,
+                    170u8, 217u8, 138u8, 217u8, 129u8, 217u8, 138u8, 217u8, 134u8, 216u8, 167u8,
+                    216u8, 186u8, 216u8, 167u8, 217u8, 132u8, 216u8, 170u8, 216u8, 186u8, 216u8,
+                    167u8, 217u8, 132u8, 217u8, 136u8, 216u8, 186u8, 217u8, 138u8, 216u8, 169u8,
+                    216u8, 167u8, 217u8, 132u8, 216u8, 171u8, 216u8, 185u8, 217u8, 134u8, 216u8,
+                    169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 170u8, 216u8, 167u8, 217u8, 138u8,
+                    217u8, 132u8, 216u8, 167u8, 217u8, 134u8, 216u8, 175u8, 217u8, 138u8, 216u8,
+                    169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 170u8, 216u8, 168u8, 216u8, 170u8,
+                    217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 216u8,
+                    172u8, 216u8, 167u8, 216u8, 177u8, 217u8, 138u8, 216u8, 170u8, 217u8, 138u8,
+                    217u8, 131u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 217u8,
+                    129u8, 216u8, 167u8, 217u8, 138u8, 216u8, 167u8, 217u8, 132u8, 217u8, 131u8,
+                    217u8, 132u8, 216u8, 167u8, 217u8, 133u8, 32u8, 216u8, 167u8, 217u8, 132u8,
+                    217u8, 133u8, 216u8, 177u8, 216u8, 166u8, 217u8, 138u8, 216u8, 167u8, 217u8,
+                    132u8, 217u8, 129u8, 216u8, 167u8, 216u8, 177u8, 216u8, 179u8, 217u8, 138u8,
+                    216u8, 169u8, 32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 130u8, 216u8, 175u8,
+                    217u8, 138u8, 217u8, 133u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 217u8,
+                    131u8, 216u8, 170u8, 216u8, 167u8, 216u8, 168u8, 216u8, 169u8, 32u8, 216u8,
+                    167u8, 217u8, 132u8, 217u8, 133u8, 216u8, 179u8, 217u8, 133u8, 216u8, 167u8,
+                    216u8, 177u8, 217u8, 138u8, 216u8, 169u8, 32u8, 216u8, 167u8, 217u8, 132u8,
+                    216u8, 163u8, 217u8, 131u8, 216u8, 175u8, 217u8, 138u8, 216u8, 169u8, 32u8,
+                    216u8, 167u8, 217u8, 132u8, 216u8, 179u8, 217u8, 136u8, 217u8, 133u8, 216u8,
+                    177u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 217u8, 138u8,
+                    217u8, 138u8, 216u8, 167u8, 217u8, 132u8, 217u8, 133u8, 217u8, 136u8, 216u8,
+                    177u8, 217u8, 136u8, 216u8, 171u8, 216u8, 170u8, 216u8, 175u8, 217u8, 136u8,
+                    217u8, 138u8, 217u8, 134u8, 32u8, 216u8, 177u8, 217u8, 138u8, 216u8, 167u8,
+                    216u8, 182u8, 217u8, 138u8, 216u8, 165u8, 217u8, 138u8, 217u8, 133u8, 217u8,
+                    136u8, 216u8, 172u8, 217u8, 138u8, 216u8, 177u8, 217u8, 133u8, 217u8, 136u8,
+                    216u8, 178u8, 216u8, 186u8, 217u8, 138u8, 216u8, 177u8, 32u8, 217u8, 133u8,
+                    217u8, 131u8, 216u8, 170u8, 217u8, 136u8, 216u8, 168u8, 216u8, 185u8, 216u8,
+                    167u8, 217u8, 133u8, 217u8, 134u8, 216u8, 184u8, 216u8, 167u8, 217u8, 133u8,
+                    32u8, 217u8, 131u8, 216u8, 170u8, 216u8, 167u8, 216u8, 168u8, 216u8, 169u8,
+                    32u8, 216u8, 186u8, 217u8, 138u8, 216u8, 177u8, 32u8, 217u8, 133u8, 216u8,
+                    185u8, 216u8, 177u8, 217u8, 136u8, 217u8, 129u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+};
+static BN: &DataStruct = &::icu_displaynames::provider::ScriptDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 100u8, 108u8, 109u8, 65u8, 114u8, 97u8, 98u8, 65u8, 114u8, 97u8, 110u8,
+                    65u8, 114u8, 109u8, 105u8, 65u8, 114u8, 109u8, 110u8, 65u8, 118u8, 115u8,
+                    116u8, 66u8, 97u8, 108u8, 105u8, 66u8, 97u8, 116u8, 107u8, 66u8, 101u8, 110u8,
+                    103u8, 66u8, 108u8, 105u8, 115u8, 66u8, 111u8, 112u8, 111u8, 66u8, 114u8, 97u8,
+                    104u8, 66u8, 114u8, 97u8, 105u8, 66u8, 117u8, 103u8, 105u8, 66u8, 117u8, 104u8,
+                    100u8, 67u8, 97u8, 107u8, 109u8, 67u8, 97u8, 110u8, 115u8, 67u8, 97u8, 114u8,
+                    105u8, 67u8, 104u8, 97u8, 109u8, 67u8, 104u8, 101u8, 114u8, 67u8, 105u8, 114u8,
+                    116u8, 67u8, 111u8, 112u8, 116u8, 67u8, 112u8, 114u8, 116u8, 67u8, 121u8,
+                    114u8, 108u8, 67u8, 121u8, 114u8, 115u8, 68u8, 101u8, 118u8, 97u8, 68u8, 115u8,
+                    114u8, 116u8, 69u8, 103u8, 121u8, 100u8, 69u8, 103u8, 121u8, 104u8, 69u8,
+                    103u8, 121u8, 112u8, 69u8, 116u8, 104u8, 105u8, 71u8, 101u8, 111u8, 107u8,
+                    71u8, 101u8, 111u8, 114u8, 71u8, 108u8, 97u8, 103u8, 71u8, 111u8, 116u8, 104u8,
+                    71u8, 114u8, 101u8, 107u8, 71u8, 117u8, 106u8, 114u8, 71u8, 117u8, 114u8,
+                    117u8, 72u8, 97u8, 110u8, 98u8, 72u8, 97u8, 110u8, 103u8, 72u8, 97u8, 110u8,
+                    105u8, 72u8, 97u8, 110u8, 111u8, 72u8, 97u8, 110u8, 115u8, 72u8, 97u8, 110u8,
+                    116u8, 72u8, 101u8, 98u8, 114u8, 72u8, 105u8, 114u8, 97u8, 72u8, 109u8, 110u8,
+                    103u8, 72u8, 114u8, 107u8, 116u8, 72u8, 117u8, 110u8, 103u8, 73u8, 110u8,
+                    100u8, 115u8, 73u8, 116u8, 97u8, 108u8, 74u8, 97u8, 109u8, 111u8, 74u8, 97u8,
+                    118u8, 97u8, 74u8, 112u8, 97u8, 110u8, 75u8, 97u8, 108u8, 105u8, 75u8, 97u8,
+                    110u8, 97u8, 75u8, 104u8, 97u8, 114u8, 75u8, 104u8, 109u8, 114u8, 75u8, 110u8,
+                    100u8, 97u8, 75u8, 111u8, 114u8, 101u8, 75u8, 116u8, 104u8, 105u8, 76u8, 97u8,
+                    110u8, 97u8, 76u8, 97u8, 111u8, 111u8, 76u8, 97u8, 116u8, 102u8, 76u8, 97u8,
+                    116u8, 103u8, 76u8, 97u8, 116u8, 110u8, 76u8, 101u8, 112u8, 99u8, 76u8, 105u8,
+                    109u8, 98u8, 76u8, 105u8, 110u8, 97u8, 76u8, 105u8, 110u8, 98u8, 76u8, 121u8,
+                    99u8, 105u8, 76u8, 121u8, 100u8, 105u8, 77u8, 97u8, 110u8, 100u8, 77u8, 97u8,
+                    110u8, 105u8, 77u8, 97u8, 121u8, 97u8, 77u8, 101u8, 114u8, 111u8, 77u8, 108u8,
+                    121u8, 109u8, 77u8, 111u8, 110u8, 103u8, 77u8, 111u8, 111u8, 110u8, 77u8,
+                    116u8, 101u8, 105u8, 77u8, 121u8, 109u8, 114u8, 78u8, 107u8, 111u8, 111u8,
+                    79u8, 103u8, 97u8, 109u8, 79u8, 108u8, 99u8, 107u8, 79u8, 114u8, 107u8, 104u8,
+                    79u8, 114u8, 121u8, 97u8, 79u8, 115u8, 109u8, 97u8, 80u8, 101u8, 114u8, 109u8,
+                    80u8, 104u8, 97u8, 103u8, 80u8, 104u8, 108u8, 105u8, 80u8, 104u8, 108u8, 112u8,
+                    80u8, 104u8, 108u8, 118u8, 80u8, 104u8, 110u8, 120u8, 80u8, 108u8, 114u8,
+                    100u8, 80u8, 114u8, 116u8, 105u8, 82u8, 106u8, 110u8, 103u8, 82u8, 111u8,
+                    104u8, 103u8, 82u8, 111u8, 114u8, 111u8, 82u8, 117u8, 110u8, 114u8, 83u8, 97u8,
+                    109u8, 114u8, 83u8, 97u8, 114u8, 97u8, 83u8, 97u8, 117u8, 114u8, 83u8, 103u8,
+                    110u8, 119u8, 83u8, 104u8, 97u8, 119u8, 83u8, 105u8, 110u8, 104u8, 83u8, 117u8,
+                    110u8, 100u8, 83u8, 121u8, 108u8, 111u8, 83u8, 121u8, 114u8, 99u8, 83u8, 121u8,
+                    114u8, 101u8, 83u8, 121u8, 114u8, 106u8, 83u8, 121u8, 114u8, 110u8, 84u8, 97u8,
+                    103u8, 98u8, 84u8, 97u8, 108u8, 101u8, 84u8, 97u8, 108u8, 117u8, 84u8, 97u8,
+                    109u8, 108u8, 84u8, 97u8, 118u8, 116u8, 84u8, 101u8, 108u8, 117u8, 84u8, 101u8,
+                    110u8, 103u8, 84u8, 102u8, 110u8, 103u8, 84u8, 103u8, 108u8, 103u8, 84u8,
+                    104u8, 97u8, 97u8, 84u8, 104u8, 97u8, 105u8, 84u8, 105u8, 98u8, 116u8, 85u8,
+                    103u8, 97u8, 114u8, 86u8, 97u8, 105u8, 105u8, 86u8, 105u8, 115u8, 112u8, 88u8,
+                    112u8, 101u8, 111u8, 88u8, 115u8, 117u8, 120u8, 89u8, 105u8, 105u8, 105u8,
+                    90u8, 105u8, 110u8, 104u8, 90u8, 109u8, 116u8, 104u8, 90u8, 115u8, 121u8,
+                    101u8, 90u8, 115u8, 121u8, 109u8, 90u8, 120u8, 120u8, 120u8, 90u8, 121u8,
+                    121u8, 121u8, 90u8, 122u8, 122u8, 122u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    136u8, 0u8, 0u8, 0u8, 0u8, 0u8, 15u8, 0u8, 27u8, 0u8, 54u8, 0u8, 66u8, 0u8,
+                    93u8, 0u8, 114u8, 0u8, 132u8, 0u8, 147u8, 0u8, 162u8, 0u8, 195u8, 0u8, 219u8,
+                    0u8, 243u8, 0u8, 5u8, 1u8, 17u8, 1u8, 32u8, 1u8, 47u8, 1u8, 167u8, 1u8, 197u8,
+                    1u8, 212u8, 1u8, 230u8, 1u8, 245u8, 1u8, 10u8, 2u8, 43u8, 2u8, 64u8, 2u8,
+                    151u8, 2u8, 172u8, 2u8, 193u8, 2u8, 236u8, 2u8, 29u8, 3u8, 87u8, 3u8, 111u8,
+                    3u8, 154u8, 3u8, 181u8, 3u8, 214u8, 3u8, 229u8, 3u8, 244u8, 3u8, 9u8, 4u8,
+                    33u8, 4u8, 73u8, 4u8, 94u8, 4u8, 109u8, 4u8, 133u8, 4u8, 154u8, 4u8, 184u8,
+                    4u8, 202u8, 4u8, 226u8, 4u8, 248u8, 4u8, 38u8, 5u8, 87u8, 5u8, 105u8, 5u8,
+                    142u8, 5u8, 160u8, 5u8, 181u8, 5u8, 199u8, 5u8, 224u8, 5u8, 248u8, 5u8, 13u8,
+                    6u8, 25u8, 6u8, 46u8, 6u8, 70u8, 6u8, 85u8, 6u8, 103u8, 6u8, 112u8, 6u8, 161u8,
+                    6u8, 204u8, 6u8, 225u8, 6u8, 243u8, 6u8, 5u8, 7u8, 33u8, 7u8, 64u8, 7u8, 91u8,
+                    7u8, 118u8, 7u8, 154u8, 7u8, 184u8, 7u8, 239u8, 7u8, 7u8, 8u8, 37u8, 8u8, 67u8,
+                    8u8, 76u8, 8u8, 113u8, 8u8, 140u8, 8u8, 155u8, 8u8, 167u8, 8u8, 186u8, 8u8,
+                    204u8, 8u8, 225u8, 8u8, 249u8, 8u8, 36u8, 9u8, 58u8, 9u8, 89u8, 9u8, 126u8,
+                    9u8, 163u8, 9u8, 187u8, 9u8, 227u8, 9u8, 254u8, 9u8, 25u8, 10u8, 43u8, 10u8,
+                    79u8, 10u8, 94u8, 10u8, 115u8, 10u8, 133u8, 10u8, 160u8, 10u8, 188u8, 10u8,
+                    212u8, 10u8, 230u8, 10u8, 1u8, 11u8, 35u8, 11u8, 59u8, 11u8, 123u8, 11u8,
+                    190u8, 11u8, 254u8, 11u8, 28u8, 12u8, 43u8, 12u8, 72u8, 12u8, 87u8, 12u8,
+                    115u8, 12u8, 133u8, 12u8, 163u8, 12u8, 184u8, 12u8, 202u8, 12u8, 214u8, 12u8,
+                    223u8, 12u8, 244u8, 12u8, 12u8, 13u8, 21u8, 13u8, 58u8, 13u8, 98u8, 13u8,
+                    163u8, 13u8, 166u8, 13u8, 175u8, 13u8, 212u8, 13u8, 227u8, 13u8, 245u8, 13u8,
+                    7u8, 14u8, 25u8, 14u8, 224u8, 166u8, 134u8, 224u8, 166u8, 166u8, 224u8, 166u8,
+                    178u8, 224u8, 166u8, 190u8, 224u8, 166u8, 174u8, 224u8, 166u8, 134u8, 224u8,
+                    166u8, 176u8, 224u8, 166u8, 172u8, 224u8, 166u8, 191u8, 224u8, 166u8, 168u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 184u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                    164u8, 224u8, 166u8, 190u8, 224u8, 166u8, 178u8, 224u8, 166u8, 191u8, 224u8,
+                    166u8, 149u8, 224u8, 166u8, 134u8, 224u8, 166u8, 176u8, 224u8, 166u8, 174u8,
+                    224u8, 166u8, 191u8, 224u8, 166u8, 134u8, 224u8, 166u8, 176u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 174u8, 224u8, 167u8, 135u8, 224u8, 166u8, 168u8, 224u8,
+                    167u8, 128u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 224u8, 166u8, 134u8,
+                    224u8, 166u8, 173u8, 224u8, 167u8, 135u8, 224u8, 166u8, 184u8, 224u8, 166u8,
+                    164u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 224u8, 166u8, 172u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 178u8, 224u8, 167u8, 128u8, 224u8, 166u8, 175u8,
+                    224u8, 166u8, 188u8, 224u8, 166u8, 172u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    159u8, 224u8, 166u8, 190u8, 224u8, 166u8, 149u8, 224u8, 166u8, 172u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 130u8, 224u8, 166u8, 178u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 172u8, 224u8, 167u8, 141u8, 224u8, 166u8, 178u8, 224u8, 166u8,
+                    191u8, 224u8, 166u8, 184u8, 224u8, 166u8, 170u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 176u8, 224u8, 166u8, 164u8, 224u8, 167u8, 128u8, 224u8, 166u8, 149u8,
+                    224u8, 166u8, 172u8, 224u8, 167u8, 139u8, 224u8, 166u8, 170u8, 224u8, 167u8,
+                    139u8, 224u8, 166u8, 174u8, 224u8, 167u8, 139u8, 224u8, 166u8, 171u8, 224u8,
+                    167u8, 139u8, 224u8, 166u8, 172u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 185u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                    174u8, 224u8, 167u8, 128u8, 224u8, 166u8, 172u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 176u8, 224u8, 167u8, 135u8, 224u8, 166u8, 135u8, 224u8, 166u8, 178u8,
+                    224u8, 166u8, 172u8, 224u8, 167u8, 129u8, 224u8, 166u8, 151u8, 224u8, 166u8,
+                    191u8, 224u8, 166u8, 172u8, 224u8, 167u8, 129u8, 224u8, 166u8, 185u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 161u8, 224u8, 166u8, 154u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 149u8, 224u8, 166u8, 174u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    184u8, 224u8, 166u8, 130u8, 224u8, 166u8, 175u8, 224u8, 167u8, 129u8, 224u8,
+                    166u8, 149u8, 224u8, 167u8, 141u8, 224u8, 166u8, 164u8, 32u8, 224u8, 166u8,
+                    149u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 161u8, 224u8, 166u8, 191u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 32u8, 224u8, 166u8, 133u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 172u8,
+                    224u8, 167u8, 139u8, 224u8, 166u8, 176u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    156u8, 224u8, 166u8, 191u8, 224u8, 166u8, 168u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 178u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    178u8, 224u8, 167u8, 135u8, 224u8, 166u8, 172u8, 224u8, 166u8, 191u8, 224u8,
+                    166u8, 149u8, 224u8, 167u8, 141u8, 224u8, 166u8, 184u8, 224u8, 166u8, 149u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    176u8, 224u8, 166u8, 191u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 168u8, 224u8, 166u8, 154u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 174u8, 224u8, 166u8,
+                    154u8, 224u8, 167u8, 135u8, 224u8, 166u8, 176u8, 224u8, 167u8, 139u8, 224u8,
+                    166u8, 149u8, 224u8, 166u8, 191u8, 224u8, 166u8, 149u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 176u8, 224u8, 167u8, 141u8, 224u8, 166u8, 159u8, 224u8, 166u8,
+                    149u8, 224u8, 167u8, 139u8, 224u8, 166u8, 170u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 159u8, 224u8, 166u8, 191u8, 224u8, 166u8, 149u8, 224u8, 166u8, 184u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 135u8, 224u8, 166u8, 170u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 176u8, 224u8, 167u8, 139u8, 224u8, 166u8, 175u8, 224u8,
+                    166u8, 188u8, 224u8, 167u8, 135u8, 224u8, 166u8, 159u8, 224u8, 166u8, 184u8,
+                    224u8, 166u8, 191u8, 224u8, 166u8, 176u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    178u8, 224u8, 166u8, 191u8, 224u8, 166u8, 149u8, 224u8, 166u8, 170u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 190u8, 224u8, 166u8, 154u8,
+                    224u8, 167u8, 128u8, 224u8, 166u8, 168u8, 32u8, 224u8, 166u8, 154u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 167u8, 141u8, 224u8, 166u8, 154u8,
+                    32u8, 224u8, 166u8, 184u8, 224u8, 167u8, 141u8, 224u8, 166u8, 178u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 173u8, 224u8, 167u8, 139u8, 224u8, 166u8, 168u8,
+                    224u8, 166u8, 191u8, 224u8, 166u8, 149u8, 32u8, 224u8, 166u8, 184u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 176u8, 224u8, 166u8, 191u8, 224u8, 166u8, 178u8,
+                    224u8, 166u8, 191u8, 224u8, 166u8, 149u8, 224u8, 166u8, 166u8, 224u8, 167u8,
+                    135u8, 224u8, 166u8, 172u8, 224u8, 166u8, 168u8, 224u8, 166u8, 151u8, 224u8,
+                    166u8, 176u8, 224u8, 166u8, 191u8, 224u8, 166u8, 166u8, 224u8, 167u8, 135u8,
+                    224u8, 166u8, 184u8, 224u8, 167u8, 135u8, 224u8, 166u8, 176u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 164u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8, 224u8,
+                    166u8, 182u8, 224u8, 166u8, 176u8, 224u8, 167u8, 128u8, 224u8, 166u8, 175u8,
+                    224u8, 166u8, 188u8, 32u8, 224u8, 166u8, 161u8, 224u8, 167u8, 135u8, 224u8,
+                    166u8, 174u8, 224u8, 167u8, 139u8, 224u8, 166u8, 159u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 149u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    182u8, 224u8, 166u8, 176u8, 224u8, 167u8, 128u8, 224u8, 166u8, 175u8, 224u8,
+                    166u8, 188u8, 32u8, 224u8, 166u8, 185u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    175u8, 224u8, 166u8, 188u8, 224u8, 166u8, 176u8, 224u8, 167u8, 135u8, 224u8,
+                    166u8, 159u8, 224u8, 166u8, 191u8, 224u8, 166u8, 149u8, 224u8, 166u8, 174u8,
+                    224u8, 166u8, 191u8, 224u8, 166u8, 182u8, 224u8, 166u8, 176u8, 224u8, 167u8,
+                    128u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 32u8, 224u8, 166u8, 185u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 176u8, 224u8, 167u8, 139u8, 224u8, 166u8, 151u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 178u8, 224u8, 166u8, 191u8, 224u8, 166u8, 170u8,
+                    224u8, 166u8, 135u8, 224u8, 166u8, 165u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    147u8, 224u8, 166u8, 170u8, 224u8, 166u8, 191u8, 224u8, 166u8, 175u8, 224u8,
+                    166u8, 188u8, 224u8, 166u8, 156u8, 224u8, 166u8, 176u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 156u8, 224u8, 166u8, 191u8, 224u8, 166u8, 175u8, 224u8, 166u8,
+                    188u8, 32u8, 224u8, 166u8, 150u8, 224u8, 167u8, 129u8, 224u8, 167u8, 142u8,
+                    224u8, 166u8, 184u8, 224u8, 167u8, 129u8, 224u8, 166u8, 176u8, 224u8, 166u8,
+                    191u8, 224u8, 166u8, 156u8, 224u8, 166u8, 176u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 156u8, 224u8, 166u8, 191u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 224u8, 166u8, 151u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 178u8, 224u8, 166u8, 190u8, 224u8, 166u8, 151u8, 224u8,
+                    167u8, 139u8, 224u8, 166u8, 178u8, 224u8, 166u8, 191u8, 224u8, 166u8, 159u8,
+                    224u8, 166u8, 191u8, 224u8, 166u8, 149u8, 224u8, 166u8, 151u8, 224u8, 167u8,
+                    139u8, 224u8, 166u8, 165u8, 224u8, 166u8, 191u8, 224u8, 166u8, 149u8, 224u8,
+                    166u8, 151u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 149u8, 224u8, 166u8, 151u8, 224u8, 167u8, 129u8, 224u8, 166u8,
+                    156u8, 224u8, 166u8, 176u8, 224u8, 166u8, 190u8, 224u8, 166u8, 159u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 151u8, 224u8, 167u8, 129u8, 224u8, 166u8, 176u8,
+                    224u8, 167u8, 129u8, 224u8, 166u8, 174u8, 224u8, 167u8, 129u8, 224u8, 166u8,
+                    150u8, 224u8, 166u8, 191u8, 224u8, 166u8, 185u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 45u8, 224u8, 166u8,
+                    172u8, 224u8, 167u8, 139u8, 224u8, 166u8, 170u8, 224u8, 167u8, 139u8, 224u8,
+                    166u8, 174u8, 224u8, 167u8, 139u8, 224u8, 166u8, 171u8, 224u8, 167u8, 139u8,
+                    224u8, 166u8, 185u8, 224u8, 166u8, 190u8, 224u8, 166u8, 153u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 151u8, 224u8, 167u8, 129u8, 224u8, 166u8, 178u8, 224u8,
+                    166u8, 185u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 168u8, 224u8, 166u8, 185u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                    175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 224u8, 167u8, 129u8, 224u8,
+                    166u8, 168u8, 224u8, 167u8, 129u8, 224u8, 166u8, 184u8, 224u8, 166u8, 176u8,
+                    224u8, 166u8, 178u8, 224u8, 167u8, 128u8, 224u8, 166u8, 149u8, 224u8, 167u8,
+                    131u8, 224u8, 166u8, 164u8, 224u8, 166u8, 144u8, 224u8, 166u8, 164u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 185u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8,
+                    224u8, 166u8, 172u8, 224u8, 166u8, 190u8, 224u8, 166u8, 185u8, 224u8, 167u8,
+                    128u8, 224u8, 166u8, 185u8, 224u8, 166u8, 191u8, 224u8, 166u8, 172u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 167u8, 129u8, 224u8, 166u8, 185u8,
+                    224u8, 166u8, 191u8, 224u8, 166u8, 176u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    151u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 171u8, 224u8, 166u8, 190u8, 224u8, 166u8, 185u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 147u8, 32u8, 224u8, 166u8, 174u8, 224u8, 166u8, 153u8, 224u8,
+                    166u8, 156u8, 224u8, 166u8, 190u8, 224u8, 166u8, 170u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 168u8, 224u8, 166u8, 191u8, 32u8, 224u8, 166u8, 133u8, 224u8,
+                    166u8, 149u8, 224u8, 167u8, 141u8, 224u8, 166u8, 183u8, 224u8, 166u8, 176u8,
+                    224u8, 166u8, 174u8, 224u8, 166u8, 190u8, 224u8, 166u8, 178u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 170u8, 224u8, 167u8, 129u8, 224u8, 166u8, 176u8, 224u8,
+                    167u8, 139u8, 224u8, 166u8, 168u8, 224u8, 167u8, 139u8, 32u8, 224u8, 166u8,
+                    185u8, 224u8, 166u8, 190u8, 224u8, 166u8, 153u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 151u8, 224u8, 167u8, 135u8, 224u8, 166u8, 176u8, 224u8, 167u8, 128u8,
+                    224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 224u8, 166u8, 184u8, 224u8, 166u8,
+                    191u8, 224u8, 166u8, 168u8, 224u8, 167u8, 141u8, 224u8, 166u8, 167u8, 224u8,
+                    167u8, 129u8, 224u8, 166u8, 170u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 154u8, 224u8, 167u8, 128u8, 224u8, 166u8,
+                    168u8, 32u8, 224u8, 166u8, 135u8, 224u8, 166u8, 164u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 178u8, 224u8, 166u8, 191u8, 224u8, 166u8, 156u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 174u8, 224u8,
+                    167u8, 139u8, 224u8, 166u8, 156u8, 224u8, 166u8, 190u8, 224u8, 166u8, 173u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    156u8, 224u8, 166u8, 156u8, 224u8, 166u8, 190u8, 224u8, 166u8, 170u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 168u8, 224u8, 167u8, 128u8, 224u8, 166u8, 149u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 185u8, 32u8, 224u8, 166u8, 178u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 149u8, 224u8, 166u8, 190u8, 224u8, 166u8, 159u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 149u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 150u8, 224u8, 166u8, 176u8, 224u8, 167u8, 139u8,
+                    224u8, 166u8, 183u8, 224u8, 167u8, 141u8, 224u8, 166u8, 160u8, 224u8, 167u8,
+                    128u8, 224u8, 166u8, 150u8, 224u8, 166u8, 174u8, 224u8, 167u8, 135u8, 224u8,
+                    166u8, 176u8, 224u8, 166u8, 149u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 161u8, 224u8, 166u8, 188u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 149u8, 224u8, 167u8, 139u8, 224u8, 166u8, 176u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 168u8, 224u8, 166u8, 149u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    135u8, 224u8, 166u8, 165u8, 224u8, 166u8, 191u8, 224u8, 166u8, 178u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 168u8, 224u8, 167u8, 141u8, 224u8, 166u8, 168u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 178u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    147u8, 224u8, 166u8, 171u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 149u8, 224u8, 167u8, 141u8, 224u8, 166u8, 159u8,
+                    224u8, 167u8, 129u8, 224u8, 166u8, 176u8, 32u8, 224u8, 166u8, 178u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 159u8,
+                    224u8, 166u8, 191u8, 224u8, 166u8, 168u8, 224u8, 166u8, 151u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 178u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 149u8, 32u8, 224u8, 166u8, 178u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 159u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 168u8, 224u8, 166u8, 178u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 159u8, 224u8, 166u8,
+                    191u8, 224u8, 166u8, 168u8, 224u8, 166u8, 178u8, 224u8, 167u8, 135u8, 224u8,
+                    166u8, 170u8, 224u8, 167u8, 141u8, 224u8, 166u8, 154u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 178u8, 224u8, 166u8, 191u8, 224u8, 166u8, 174u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 172u8, 224u8, 167u8, 129u8, 224u8, 166u8, 178u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 168u8, 224u8, 166u8, 191u8, 224u8, 166u8, 175u8,
+                    224u8, 166u8, 188u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 32u8, 224u8,
+                    166u8, 143u8, 224u8, 166u8, 178u8, 224u8, 166u8, 191u8, 224u8, 166u8, 168u8,
+                    224u8, 166u8, 191u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 176u8, 32u8, 224u8, 166u8, 172u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 178u8, 224u8, 166u8, 190u8, 224u8, 166u8, 135u8, 224u8, 166u8,
+                    184u8, 224u8, 166u8, 191u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 168u8, 224u8, 166u8, 178u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 135u8, 224u8, 166u8, 161u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    175u8, 224u8, 166u8, 188u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 224u8,
+                    166u8, 174u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 168u8, 224u8, 167u8, 141u8, 224u8, 166u8, 161u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 224u8, 167u8, 128u8, 224u8,
+                    166u8, 168u8, 224u8, 166u8, 174u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    154u8, 224u8, 166u8, 190u8, 224u8, 166u8, 135u8, 224u8, 166u8, 168u8, 224u8,
+                    166u8, 174u8, 224u8, 166u8, 190u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 32u8, 224u8, 166u8, 185u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 176u8, 224u8, 167u8, 139u8, 224u8, 166u8, 151u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 178u8, 224u8, 166u8, 191u8, 224u8, 166u8, 170u8, 224u8,
+                    166u8, 174u8, 224u8, 167u8, 135u8, 224u8, 166u8, 176u8, 224u8, 167u8, 139u8,
+                    224u8, 166u8, 135u8, 224u8, 166u8, 159u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    149u8, 224u8, 166u8, 174u8, 224u8, 166u8, 190u8, 224u8, 166u8, 178u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 178u8, 224u8, 166u8, 190u8, 224u8, 166u8, 174u8, 224u8, 166u8,
+                    174u8, 224u8, 167u8, 139u8, 224u8, 166u8, 153u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 151u8, 224u8, 167u8, 139u8, 224u8, 166u8, 178u8, 224u8, 167u8, 128u8,
+                    224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 224u8, 166u8, 174u8, 224u8, 167u8,
+                    129u8, 224u8, 166u8, 168u8, 224u8, 166u8, 174u8, 224u8, 167u8, 135u8, 224u8,
+                    166u8, 135u8, 224u8, 166u8, 159u8, 224u8, 167u8, 135u8, 224u8, 166u8, 135u8,
+                    32u8, 224u8, 166u8, 174u8, 224u8, 166u8, 190u8, 224u8, 166u8, 175u8, 224u8,
+                    166u8, 188u8, 224u8, 167u8, 135u8, 224u8, 166u8, 149u8, 224u8, 166u8, 174u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 168u8, 224u8, 166u8, 174u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 176u8, 224u8, 166u8, 143u8, 224u8, 166u8, 168u8, 226u8, 128u8, 153u8,
+                    224u8, 166u8, 149u8, 224u8, 167u8, 139u8, 224u8, 166u8, 147u8, 224u8, 166u8,
+                    152u8, 224u8, 166u8, 190u8, 224u8, 166u8, 174u8, 224u8, 166u8, 147u8, 224u8,
+                    166u8, 178u8, 32u8, 224u8, 166u8, 154u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    149u8, 224u8, 166u8, 191u8, 224u8, 166u8, 133u8, 224u8, 166u8, 176u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 150u8, 224u8, 167u8, 139u8, 224u8, 166u8, 168u8,
+                    224u8, 166u8, 147u8, 224u8, 166u8, 161u8, 224u8, 166u8, 188u8, 224u8, 166u8,
+                    191u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 147u8, 224u8, 166u8, 184u8, 224u8, 166u8, 174u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 168u8, 224u8, 166u8, 191u8, 224u8, 166u8, 175u8, 224u8, 166u8,
+                    188u8, 224u8, 166u8, 170u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 154u8, 224u8, 167u8, 128u8, 224u8, 166u8, 168u8,
+                    32u8, 224u8, 166u8, 170u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8, 224u8, 166u8, 149u8,
+                    224u8, 166u8, 171u8, 224u8, 166u8, 190u8, 224u8, 166u8, 151u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 184u8, 45u8, 224u8, 166u8, 170u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 150u8, 224u8, 166u8, 166u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    164u8, 32u8, 224u8, 166u8, 170u8, 224u8, 166u8, 190u8, 224u8, 166u8, 185u8,
+                    224u8, 166u8, 178u8, 224u8, 166u8, 173u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    184u8, 224u8, 166u8, 178u8, 224u8, 167u8, 141u8, 224u8, 166u8, 159u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 176u8, 32u8, 224u8, 166u8, 170u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 185u8, 224u8, 166u8, 178u8, 224u8, 166u8, 173u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 170u8, 224u8, 167u8, 129u8, 224u8, 166u8, 184u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 164u8, 224u8, 166u8, 149u8, 32u8, 224u8,
+                    166u8, 170u8, 224u8, 166u8, 190u8, 224u8, 166u8, 185u8, 224u8, 166u8, 178u8,
+                    224u8, 166u8, 173u8, 224u8, 166u8, 191u8, 224u8, 166u8, 171u8, 224u8, 166u8,
+                    191u8, 224u8, 166u8, 168u8, 224u8, 166u8, 191u8, 224u8, 166u8, 182u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 224u8, 166u8, 170u8,
+                    224u8, 167u8, 139u8, 224u8, 166u8, 178u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    176u8, 224u8, 167u8, 141u8, 224u8, 166u8, 161u8, 32u8, 224u8, 166u8, 167u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 172u8, 224u8, 166u8, 168u8, 224u8, 166u8,
+                    191u8, 224u8, 166u8, 149u8, 224u8, 166u8, 170u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 176u8, 224u8, 167u8, 141u8, 224u8, 166u8, 165u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 224u8, 166u8, 168u8, 224u8, 166u8,
+                    176u8, 224u8, 167u8, 135u8, 224u8, 166u8, 156u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 153u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 151u8, 224u8, 166u8, 185u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    168u8, 224u8, 166u8, 191u8, 224u8, 166u8, 171u8, 224u8, 166u8, 191u8, 224u8,
+                    166u8, 176u8, 224u8, 167u8, 139u8, 224u8, 166u8, 153u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 151u8, 224u8, 167u8, 139u8, 224u8, 166u8, 176u8, 224u8, 167u8,
+                    139u8, 224u8, 166u8, 153u8, 224u8, 167u8, 141u8, 224u8, 166u8, 151u8, 224u8,
+                    167u8, 139u8, 224u8, 166u8, 176u8, 224u8, 167u8, 129u8, 224u8, 166u8, 168u8,
+                    224u8, 166u8, 191u8, 224u8, 166u8, 149u8, 224u8, 166u8, 184u8, 224u8, 166u8,
+                    174u8, 224u8, 167u8, 135u8, 224u8, 166u8, 176u8, 224u8, 166u8, 191u8, 224u8,
+                    166u8, 159u8, 224u8, 166u8, 168u8, 224u8, 166u8, 184u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 176u8, 224u8, 166u8, 190u8, 224u8, 166u8, 164u8, 224u8, 166u8,
+                    191u8, 224u8, 166u8, 184u8, 224u8, 167u8, 140u8, 224u8, 166u8, 176u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 183u8, 224u8, 167u8, 141u8, 224u8, 166u8, 159u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 154u8, 224u8, 166u8,
+                    191u8, 224u8, 166u8, 185u8, 224u8, 167u8, 141u8, 224u8, 166u8, 168u8, 32u8,
+                    224u8, 166u8, 178u8, 224u8, 166u8, 191u8, 224u8, 166u8, 150u8, 224u8, 166u8,
+                    168u8, 224u8, 166u8, 184u8, 224u8, 166u8, 190u8, 224u8, 166u8, 173u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 168u8, 224u8, 166u8, 184u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    130u8, 224u8, 166u8, 185u8, 224u8, 166u8, 178u8, 224u8, 166u8, 191u8, 224u8,
+                    166u8, 184u8, 224u8, 167u8, 129u8, 224u8, 166u8, 168u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 166u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 224u8, 166u8,
+                    191u8, 224u8, 166u8, 156u8, 224u8, 166u8, 184u8, 224u8, 166u8, 191u8, 224u8,
+                    166u8, 178u8, 224u8, 167u8, 135u8, 224u8, 166u8, 159u8, 224u8, 166u8, 191u8,
+                    32u8, 224u8, 166u8, 168u8, 224u8, 166u8, 190u8, 224u8, 166u8, 151u8, 224u8,
+                    166u8, 176u8, 224u8, 166u8, 191u8, 224u8, 166u8, 184u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 176u8, 224u8, 166u8, 191u8, 224u8, 166u8, 175u8, 224u8, 166u8,
+                    188u8, 224u8, 166u8, 190u8, 224u8, 166u8, 149u8, 224u8, 166u8, 143u8, 224u8,
+                    166u8, 184u8, 224u8, 167u8, 141u8, 224u8, 166u8, 159u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 176u8, 224u8, 167u8, 135u8, 224u8, 166u8, 153u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 151u8, 224u8, 167u8, 135u8, 224u8, 166u8, 178u8, 224u8,
+                    167u8, 139u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    176u8, 224u8, 166u8, 191u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 149u8, 224u8, 166u8, 170u8, 224u8, 166u8, 182u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 154u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    174u8, 224u8, 166u8, 190u8, 224u8, 166u8, 158u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 154u8, 224u8, 166u8, 178u8, 224u8, 167u8, 128u8, 224u8, 166u8, 175u8,
+                    224u8, 166u8, 188u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 191u8, 224u8,
+                    166u8, 176u8, 224u8, 166u8, 191u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 149u8, 224u8, 166u8, 170u8, 224u8, 167u8,
+                    130u8, 224u8, 166u8, 176u8, 224u8, 167u8, 141u8, 224u8, 166u8, 172u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 158u8, 224u8, 167u8, 141u8, 224u8, 166u8, 154u8,
+                    224u8, 166u8, 178u8, 224u8, 167u8, 128u8, 224u8, 166u8, 175u8, 224u8, 166u8,
+                    188u8, 32u8, 224u8, 166u8, 184u8, 224u8, 166u8, 191u8, 224u8, 166u8, 176u8,
+                    224u8, 166u8, 191u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 149u8, 224u8, 166u8, 159u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 151u8, 224u8, 167u8, 139u8, 224u8, 166u8, 147u8, 224u8, 166u8, 175u8,
+                    224u8, 166u8, 188u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 164u8, 224u8, 166u8, 190u8, 224u8, 166u8, 135u8, 224u8,
+                    166u8, 178u8, 224u8, 167u8, 135u8, 224u8, 166u8, 168u8, 224u8, 166u8, 164u8,
+                    224u8, 167u8, 129u8, 224u8, 166u8, 168u8, 32u8, 224u8, 166u8, 164u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 135u8, 32u8, 224u8, 166u8, 178u8, 224u8, 167u8,
+                    129u8, 224u8, 166u8, 164u8, 224u8, 166u8, 190u8, 224u8, 166u8, 174u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 178u8, 224u8, 166u8, 164u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 135u8, 32u8, 224u8, 166u8, 173u8, 224u8, 166u8, 191u8, 224u8,
+                    166u8, 175u8, 224u8, 166u8, 188u8, 224u8, 167u8, 135u8, 224u8, 167u8, 142u8,
+                    224u8, 166u8, 164u8, 224u8, 167u8, 135u8, 224u8, 166u8, 178u8, 224u8, 167u8,
+                    135u8, 224u8, 166u8, 151u8, 224u8, 167u8, 129u8, 224u8, 166u8, 164u8, 224u8,
+                    167u8, 135u8, 224u8, 166u8, 153u8, 224u8, 167u8, 141u8, 224u8, 166u8, 151u8,
+                    224u8, 167u8, 139u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 176u8, 224u8, 166u8, 164u8, 224u8, 166u8, 191u8, 224u8,
+                    166u8, 171u8, 224u8, 166u8, 191u8, 224u8, 166u8, 168u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 151u8, 224u8, 166u8, 159u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    151u8, 224u8, 166u8, 190u8, 224u8, 166u8, 178u8, 224u8, 166u8, 151u8, 224u8,
+                    166u8, 165u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 165u8, 224u8, 166u8, 190u8, 224u8, 166u8, 135u8, 224u8, 166u8,
+                    164u8, 224u8, 166u8, 191u8, 224u8, 166u8, 172u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 172u8, 224u8, 166u8, 164u8, 224u8, 166u8, 191u8, 224u8, 166u8, 137u8,
+                    224u8, 166u8, 151u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 166u8,
+                    191u8, 224u8, 166u8, 159u8, 224u8, 166u8, 191u8, 224u8, 166u8, 149u8, 224u8,
+                    166u8, 173u8, 224u8, 166u8, 190u8, 224u8, 166u8, 135u8, 224u8, 166u8, 166u8,
+                    224u8, 167u8, 131u8, 224u8, 166u8, 182u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                    175u8, 224u8, 166u8, 174u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 32u8,
+                    224u8, 166u8, 173u8, 224u8, 166u8, 190u8, 224u8, 166u8, 183u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 170u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 154u8, 224u8, 167u8, 128u8, 224u8, 166u8, 168u8,
+                    32u8, 224u8, 166u8, 171u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 184u8, 224u8, 166u8, 191u8, 224u8, 166u8, 184u8,
+                    224u8, 167u8, 129u8, 224u8, 166u8, 174u8, 224u8, 167u8, 135u8, 224u8, 166u8,
+                    176u8, 45u8, 224u8, 166u8, 134u8, 224u8, 166u8, 149u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 149u8, 224u8, 166u8, 190u8, 224u8, 166u8, 166u8, 224u8, 167u8,
+                    128u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 32u8, 224u8, 166u8, 149u8,
+                    224u8, 167u8, 128u8, 224u8, 166u8, 178u8, 224u8, 166u8, 149u8, 224u8, 166u8,
+                    176u8, 224u8, 167u8, 130u8, 224u8, 166u8, 170u8, 224u8, 166u8, 135u8, 224u8,
+                    166u8, 149u8, 224u8, 166u8, 190u8, 224u8, 166u8, 135u8, 224u8, 166u8, 151u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 163u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    164u8, 224u8, 166u8, 191u8, 224u8, 166u8, 149u8, 32u8, 224u8, 166u8, 154u8,
+                    224u8, 166u8, 191u8, 224u8, 166u8, 185u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                    168u8, 224u8, 166u8, 135u8, 224u8, 166u8, 174u8, 224u8, 167u8, 139u8, 224u8,
+                    166u8, 156u8, 224u8, 166u8, 191u8, 224u8, 166u8, 170u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 176u8, 224u8, 166u8, 164u8, 224u8, 167u8, 128u8, 224u8, 166u8,
+                    149u8, 224u8, 166u8, 133u8, 224u8, 166u8, 178u8, 224u8, 166u8, 191u8, 224u8,
+                    166u8, 150u8, 224u8, 166u8, 191u8, 224u8, 166u8, 164u8, 224u8, 166u8, 184u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 167u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    176u8, 224u8, 166u8, 168u8, 224u8, 166u8, 133u8, 224u8, 166u8, 156u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 168u8, 224u8, 166u8, 190u8, 32u8, 224u8, 166u8,
+                    178u8, 224u8, 166u8, 191u8, 224u8, 166u8, 170u8, 224u8, 166u8, 191u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+};
+static CCP: &DataStruct = &::icu_displaynames::provider::ScriptDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 114u8, 97u8, 98u8, 65u8, 114u8, 109u8, 105u8, 65u8, 114u8, 109u8, 110u8,
+                    65u8, 118u8, 115u8, 116u8, 66u8, 97u8, 108u8, 105u8, 66u8, 97u8, 116u8, 107u8,
+                    66u8, 101u8, 110u8, 103u8, 66u8, 108u8, 105u8, 115u8, 66u8, 111u8, 112u8,
+                    111u8, 66u8, 114u8, 97u8, 104u8, 66u8, 114u8, 97u8, 105u8, 66u8, 117u8, 103u8,
+                    105u8, 66u8, 117u8, 104u8, 100u8, 67u8, 97u8, 107u8, 109u8, 67u8, 97u8, 110u8,
+                    115u8, 67u8, 97u8, 114u8, 105u8, 67u8, 104u8, 97u8, 109u8, 67u8, 104u8, 101u8,
+                    114u8, 67u8, 105u8, 114u8, 116u8, 67u8, 111u8, 112u8, 116u8, 67u8, 112u8,
+                    114u8, 116u8, 67u8, 121u8, 114u8, 108u8, 67u8, 121u8, 114u8, 115u8, 68u8,
+                    101u8, 118u8, 97u8, 68u8, 115u8, 114u8, 116u8, 69u8, 103u8, 121u8, 100u8, 69u8,
+                    103u8, 121u8, 104u8, 69u8, 103u8, 121u8, 112u8, 69u8, 116u8, 104u8, 105u8,
+                    71u8, 101u8, 111u8, 107u8, 71u8, 101u8, 111u8, 114u8, 71u8, 108u8, 97u8, 103u8,
+                    71u8, 111u8, 116u8, 104u8, 71u8, 114u8, 101u8, 107u8, 71u8, 117u8, 106u8,
+                    114u8, 71u8, 117u8, 114u8, 117u8, 72u8, 97u8, 110u8, 98u8, 72u8, 97u8, 110u8,
+                    103u8, 72u8, 97u8, 110u8, 105u8, 72u8, 97u8, 110u8, 111u8, 72u8, 97u8, 110u8,
+                    115u8, 72u8, 97u8, 110u8, 116u8, 72u8, 101u8, 98u8, 114u8, 72u8, 105u8, 114u8,
+                    97u8, 72u8, 109u8, 110u8, 103u8, 72u8, 114u8, 107u8, 116u8, 72u8, 117u8, 110u8,
+                    103u8, 73u8, 110u8, 100u8, 115u8, 73u8, 116u8, 97u8, 108u8, 74u8, 97u8, 109u8,
+                    111u8, 74u8, 97u8, 118u8, 97u8, 74u8, 112u8, 97u8, 110u8, 75u8, 97u8, 108u8,
+                    105u8, 75u8, 97u8, 110u8, 97u8, 75u8, 104u8, 97u8, 114u8, 75u8, 104u8, 109u8,
+                    114u8, 75u8, 110u8, 100u8, 97u8, 75u8, 111u8, 114u8, 101u8, 75u8, 116u8, 104u8,
+                    105u8, 76u8, 97u8, 110u8, 97u8, 76u8, 97u8, 111u8, 111u8, 76u8, 97u8, 116u8,
+                    102u8, 76u8, 97u8, 116u8, 103u8, 76u8, 97u8, 116u8, 110u8, 76u8, 101u8, 112u8,
+                    99u8, 76u8, 105u8, 109u8, 98u8, 76u8, 105u8, 110u8, 97u8, 76u8, 105u8, 110u8,
+                    98u8, 76u8, 121u8, 99u8, 105u8, 76u8, 121u8, 100u8, 105u8, 77u8, 97u8, 110u8,
+                    100u8, 77u8, 97u8, 110u8, 105u8, 77u8, 97u8, 121u8, 97u8, 77u8, 101u8, 114u8,
+                    111u8, 77u8, 108u8, 121u8, 109u8, 77u8, 111u8, 110u8, 103u8, 77u8, 111u8,
+                    111u8, 110u8, 77u8, 116u8, 101u8, 105u8, 77u8, 121u8, 109u8, 114u8, 78u8,
+                    107u8, 111u8, 111u8, 79u8, 103u8, 97u8, 109u8, 79u8, 108u8, 99u8, 107u8, 79u8,
+                    114u8, 107u8, 104u8, 79u8, 114u8, 121u8, 97u8, 79u8, 115u8, 109u8, 97u8, 80u8,
+                    101u8, 114u8, 109u8, 80u8, 104u8, 97u8, 103u8, 80u8, 104u8, 108u8, 105u8, 80u8,
+                    104u8, 108u8, 112u8, 80u8, 104u8, 108u8, 118u8, 80u8, 104u8, 110u8, 120u8,
+                    80u8, 108u8, 114u8, 100u8, 80u8, 114u8, 116u8, 105u8, 82u8, 106u8, 110u8,
+                    103u8, 82u8, 111u8, 114u8, 111u8, 82u8, 117u8, 110u8, 114u8, 83u8, 97u8, 109u8,
+                    114u8, 83u8, 97u8, 114u8, 97u8, 83u8, 97u8, 117u8, 114u8, 83u8, 103u8, 110u8,
+                    119u8, 83u8, 104u8, 97u8, 119u8, 83u8, 105u8, 110u8, 104u8, 83u8, 117u8, 110u8,
+                    100u8, 83u8, 121u8, 108u8, 111u8, 83u8, 121u8, 114u8, 99u8, 83u8, 121u8, 114u8,
+                    101u8, 83u8, 121u8, 114u8, 106u8, 83u8, 121u8, 114u8, 110u8, 84u8, 97u8, 103u8,
+                    98u8, 84u8, 97u8, 108u8, 101u8, 84u8, 97u8, 108u8, 117u8, 84u8, 97u8, 109u8,
+                    108u8, 84u8, 97u8, 118u8, 116u8, 84u8, 101u8, 108u8, 117u8, 84u8, 101u8, 110u8,
+                    103u8, 84u8, 102u8, 110u8, 103u8, 84u8, 103u8, 108u8, 103u8, 84u8, 104u8, 97u8,
+                    97u8, 84u8, 104u8, 97u8, 105u8, 84u8, 105u8, 98u8, 116u8, 85u8, 103u8, 97u8,
+                    114u8, 86u8, 97u8, 105u8, 105u8, 86u8, 105u8, 115u8, 112u8, 88u8, 112u8, 101u8,
+                    111u8, 88u8, 115u8, 117u8, 120u8, 89u8, 105u8, 105u8, 105u8, 90u8, 105u8,
+                    110u8, 104u8, 90u8, 109u8, 116u8, 104u8, 90u8, 115u8, 121u8, 101u8, 90u8,
+                    115u8, 121u8, 109u8, 90u8, 120u8, 120u8, 120u8, 90u8, 121u8, 121u8, 121u8,
+                    90u8, 122u8, 122u8, 122u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    133u8, 0u8, 0u8, 0u8, 0u8, 0u8, 20u8, 0u8, 40u8, 0u8, 76u8, 0u8, 108u8, 0u8,
+                    128u8, 0u8, 144u8, 0u8, 156u8, 0u8, 212u8, 0u8, 244u8, 0u8, 24u8, 1u8, 56u8,
+                    1u8, 72u8, 1u8, 96u8, 1u8, 112u8, 1u8, 251u8, 1u8, 27u8, 2u8, 47u8, 2u8, 71u8,
+                    2u8, 95u8, 2u8, 127u8, 2u8, 167u8, 2u8, 199u8, 2u8, 58u8, 3u8, 94u8, 3u8,
+                    122u8, 3u8, 187u8, 3u8, 0u8, 4u8, 73u8, 4u8, 113u8, 4u8, 178u8, 4u8, 214u8,
+                    4u8, 2u8, 5u8, 26u8, 5u8, 50u8, 5u8, 78u8, 5u8, 110u8, 5u8, 138u8, 5u8, 166u8,
+                    5u8, 186u8, 5u8, 214u8, 5u8, 246u8, 5u8, 30u8, 6u8, 62u8, 6u8, 82u8, 6u8,
+                    114u8, 6u8, 155u8, 6u8, 216u8, 6u8, 240u8, 6u8, 29u8, 7u8, 49u8, 7u8, 73u8,
+                    7u8, 89u8, 7u8, 113u8, 7u8, 129u8, 7u8, 157u8, 7u8, 181u8, 7u8, 193u8, 7u8,
+                    221u8, 7u8, 237u8, 7u8, 253u8, 7u8, 9u8, 8u8, 74u8, 8u8, 131u8, 8u8, 159u8,
+                    8u8, 179u8, 8u8, 203u8, 8u8, 236u8, 8u8, 17u8, 9u8, 45u8, 9u8, 73u8, 9u8,
+                    113u8, 9u8, 149u8, 9u8, 206u8, 9u8, 246u8, 9u8, 14u8, 10u8, 54u8, 10u8, 70u8,
+                    10u8, 123u8, 10u8, 151u8, 10u8, 175u8, 10u8, 195u8, 10u8, 227u8, 10u8, 3u8,
+                    11u8, 23u8, 11u8, 55u8, 11u8, 108u8, 11u8, 137u8, 11u8, 190u8, 11u8, 247u8,
+                    11u8, 52u8, 12u8, 84u8, 12u8, 137u8, 12u8, 173u8, 12u8, 209u8, 12u8, 1u8, 13u8,
+                    25u8, 13u8, 65u8, 13u8, 81u8, 13u8, 117u8, 13u8, 166u8, 13u8, 190u8, 13u8,
+                    218u8, 13u8, 250u8, 13u8, 39u8, 14u8, 67u8, 14u8, 152u8, 14u8, 221u8, 14u8,
+                    30u8, 15u8, 50u8, 15u8, 66u8, 15u8, 92u8, 15u8, 112u8, 15u8, 145u8, 15u8,
+                    169u8, 15u8, 205u8, 15u8, 233u8, 15u8, 1u8, 16u8, 9u8, 16u8, 17u8, 16u8, 49u8,
+                    16u8, 85u8, 16u8, 93u8, 16u8, 146u8, 16u8, 191u8, 16u8, 33u8, 17u8, 49u8, 17u8,
+                    57u8, 17u8, 106u8, 17u8, 130u8, 17u8, 162u8, 17u8, 202u8, 17u8, 234u8, 17u8,
+                    240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8,
+                    167u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8,
+                    132u8, 131u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 167u8, 240u8,
+                    145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 131u8,
+                    240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8,
+                    159u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8,
+                    132u8, 169u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 167u8, 240u8,
+                    145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 158u8, 240u8, 145u8, 132u8, 172u8,
+                    240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8,
+                    150u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8,
+                    132u8, 157u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 169u8, 240u8,
+                    145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 150u8,
+                    240u8, 145u8, 132u8, 145u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8,
+                    180u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 129u8, 240u8, 145u8,
+                    132u8, 163u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 179u8, 240u8,
+                    145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 140u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8,
+                    179u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8,
+                    132u8, 150u8, 240u8, 145u8, 132u8, 169u8, 240u8, 145u8, 132u8, 135u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 174u8,
+                    240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8,
+                    159u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 156u8, 240u8, 145u8,
+                    132u8, 174u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 179u8, 240u8,
+                    145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 166u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8,
+                    159u8, 240u8, 145u8, 132u8, 169u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8,
+                    132u8, 179u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 179u8, 240u8,
+                    145u8, 132u8, 134u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 163u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8,
+                    170u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8,
+                    132u8, 157u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 166u8, 240u8,
+                    145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8,
+                    180u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 142u8, 240u8, 145u8,
+                    132u8, 167u8, 240u8, 145u8, 132u8, 153u8, 32u8, 240u8, 145u8, 132u8, 135u8,
+                    240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 147u8, 240u8, 145u8, 132u8,
+                    168u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8,
+                    132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 179u8,
+                    240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8,
+                    179u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8,
+                    132u8, 142u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 154u8, 240u8,
+                    145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 154u8,
+                    240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8,
+                    132u8, 168u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 172u8, 240u8,
+                    145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 135u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8,
+                    167u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8,
+                    132u8, 160u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                    145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8,
+                    160u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8,
+                    132u8, 140u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 135u8, 240u8,
+                    145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 168u8,
+                    240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8,
+                    132u8, 167u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 174u8, 240u8,
+                    145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 145u8,
+                    240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8,
+                    180u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 173u8, 240u8, 145u8,
+                    132u8, 155u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 162u8, 240u8,
+                    145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 172u8,
+                    240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8,
+                    165u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8,
+                    132u8, 168u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                    145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 155u8,
+                    240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8,
+                    174u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 168u8, 32u8, 240u8,
+                    145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 167u8, 32u8, 240u8, 145u8,
+                    132u8, 165u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 163u8, 240u8,
+                    145u8, 132u8, 158u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 154u8,
+                    240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8,
+                    180u8, 32u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                    145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 163u8,
+                    240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8,
+                    180u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8,
+                    132u8, 155u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 154u8, 240u8,
+                    145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8,
+                    240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8,
+                    172u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8,
+                    132u8, 162u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 165u8,
+                    240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8,
+                    169u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 167u8, 32u8, 240u8,
+                    145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 159u8,
+                    240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8, 132u8,
+                    168u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8,
+                    132u8, 159u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 165u8, 240u8,
+                    145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 169u8,
+                    240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 167u8, 32u8, 240u8, 145u8,
+                    132u8, 166u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 145u8,
+                    240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8,
+                    180u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8,
+                    132u8, 165u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8, 240u8,
+                    145u8, 132u8, 169u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 167u8,
+                    32u8, 240u8, 145u8, 132u8, 166u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8,
+                    132u8, 162u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 137u8, 240u8,
+                    145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 168u8,
+                    240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8,
+                    131u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 151u8, 240u8, 145u8,
+                    132u8, 168u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 174u8, 240u8,
+                    145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 160u8,
+                    240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 142u8, 240u8, 145u8, 132u8,
+                    167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8,
+                    132u8, 142u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 160u8, 240u8,
+                    145u8, 132u8, 167u8, 32u8, 240u8, 145u8, 132u8, 136u8, 240u8, 145u8, 132u8,
+                    170u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8,
+                    132u8, 165u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 162u8, 240u8,
+                    145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 142u8, 240u8, 145u8, 132u8, 167u8,
+                    240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8,
+                    142u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8,
+                    132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 137u8, 240u8,
+                    145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 137u8,
+                    240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8,
+                    168u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8,
+                    132u8, 135u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 137u8, 240u8,
+                    145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 151u8, 240u8, 145u8, 132u8, 168u8,
+                    240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8,
+                    137u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8,
+                    132u8, 168u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 142u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8,
+                    145u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8,
+                    132u8, 170u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 170u8, 240u8,
+                    145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 136u8,
+                    240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 166u8, 240u8, 145u8, 132u8,
+                    179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8,
+                    132u8, 180u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                    145u8, 132u8, 166u8, 240u8, 145u8, 132u8, 139u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8,
+                    163u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 166u8, 240u8, 145u8,
+                    132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 154u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 166u8, 240u8, 145u8, 132u8, 179u8,
+                    240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8,
+                    170u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8,
+                    132u8, 133u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 142u8, 240u8,
+                    145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 133u8, 240u8, 145u8, 132u8, 170u8,
+                    240u8, 145u8, 132u8, 143u8, 240u8, 145u8, 132u8, 171u8, 240u8, 145u8, 132u8,
+                    162u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8,
+                    132u8, 168u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 170u8, 240u8,
+                    145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 159u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 166u8, 240u8, 145u8, 132u8,
+                    168u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8,
+                    132u8, 157u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 162u8, 240u8,
+                    145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 166u8, 240u8, 145u8, 132u8, 168u8,
+                    240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8,
+                    154u8, 240u8, 145u8, 132u8, 156u8, 240u8, 145u8, 132u8, 166u8, 240u8, 145u8,
+                    132u8, 131u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 159u8, 240u8,
+                    145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 139u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 142u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8,
+                    154u8, 240u8, 145u8, 132u8, 168u8, 32u8, 240u8, 145u8, 132u8, 166u8, 240u8,
+                    145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 167u8,
+                    240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8,
+                    155u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8,
+                    132u8, 174u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 32u8,
+                    240u8, 145u8, 132u8, 166u8, 240u8, 145u8, 132u8, 139u8, 240u8, 145u8, 132u8,
+                    180u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8,
+                    132u8, 162u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 160u8, 240u8,
+                    145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 168u8,
+                    240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8,
+                    153u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8,
+                    132u8, 170u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 174u8, 240u8,
+                    145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 169u8, 32u8, 240u8, 145u8, 132u8,
+                    131u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8,
+                    132u8, 163u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 142u8, 240u8,
+                    145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 159u8,
+                    240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 142u8, 240u8, 145u8, 132u8,
+                    158u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8,
+                    132u8, 142u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 142u8, 240u8,
+                    145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 169u8,
+                    240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8,
+                    166u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8,
+                    132u8, 168u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 145u8, 240u8,
+                    145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 136u8,
+                    240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8,
+                    140u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 146u8, 240u8, 145u8,
+                    132u8, 169u8, 240u8, 145u8, 132u8, 136u8, 240u8, 145u8, 132u8, 172u8, 240u8,
+                    145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 162u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8,
+                    154u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8,
+                    132u8, 174u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                    145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 173u8, 240u8, 145u8, 132u8,
+                    151u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8,
+                    132u8, 154u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 166u8, 240u8,
+                    145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 174u8,
+                    240u8, 145u8, 132u8, 156u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8,
+                    162u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8,
+                    132u8, 145u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 162u8, 240u8,
+                    145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8,
+                    179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8,
+                    132u8, 168u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 160u8,
+                    240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    135u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 163u8, 240u8,
+                    145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 145u8,
+                    240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8,
+                    180u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8,
+                    132u8, 160u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                    145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 163u8,
+                    240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8,
+                    180u8, 240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8,
+                    132u8, 168u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 163u8,
+                    240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8,
+                    168u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8,
+                    132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 134u8, 240u8, 145u8, 132u8, 163u8,
+                    240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8,
+                    168u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8,
+                    132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 168u8,
+                    240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 173u8, 240u8, 145u8, 132u8,
+                    165u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8,
+                    132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 163u8, 240u8,
+                    145u8, 132u8, 173u8, 240u8, 145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 168u8,
+                    240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8,
+                    180u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8,
+                    132u8, 160u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 169u8,
+                    240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8,
+                    159u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8,
+                    132u8, 154u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 140u8, 240u8,
+                    145u8, 132u8, 173u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8,
+                    154u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 166u8, 240u8,
+                    145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 174u8,
+                    240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8,
+                    163u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8,
+                    132u8, 180u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 172u8, 240u8,
+                    145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 131u8,
+                    240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8, 132u8,
+                    168u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8,
+                    132u8, 159u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 160u8, 240u8,
+                    145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8,
+                    139u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8,
+                    132u8, 174u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 169u8, 240u8,
+                    145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 159u8,
+                    240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8,
+                    180u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8,
+                    132u8, 134u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 145u8, 240u8,
+                    145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 134u8, 240u8, 145u8, 132u8, 172u8,
+                    32u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8,
+                    132u8, 172u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 154u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8,
+                    162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8,
+                    132u8, 172u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 131u8,
+                    240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 138u8, 240u8, 145u8, 132u8,
+                    159u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8,
+                    132u8, 174u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 135u8,
+                    240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8,
+                    167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8,
+                    132u8, 136u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 154u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 174u8,
+                    240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    160u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8,
+                    132u8, 165u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 154u8, 240u8,
+                    145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 167u8,
+                    240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8,
+                    162u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8,
+                    132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 162u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8,
+                    168u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8,
+                    132u8, 156u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 137u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 167u8,
+                    45u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 136u8, 240u8, 145u8,
+                    132u8, 167u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                    145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 167u8, 32u8, 240u8, 145u8, 132u8,
+                    155u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 166u8, 240u8, 145u8,
+                    132u8, 163u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 158u8, 240u8,
+                    145u8, 132u8, 169u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 167u8,
+                    240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8,
+                    145u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8,
+                    145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 166u8,
+                    240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8,
+                    158u8, 240u8, 145u8, 132u8, 169u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8,
+                    132u8, 170u8, 240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 135u8,
+                    240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8,
+                    132u8, 179u8, 240u8, 145u8, 132u8, 166u8, 240u8, 145u8, 132u8, 163u8, 240u8,
+                    145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 158u8, 240u8, 145u8, 132u8, 169u8,
+                    240u8, 145u8, 132u8, 156u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    154u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8,
+                    132u8, 169u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 167u8, 240u8,
+                    145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 163u8,
+                    240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8,
+                    147u8, 240u8, 145u8, 132u8, 167u8, 32u8, 240u8, 145u8, 132u8, 153u8, 240u8,
+                    145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 168u8,
+                    240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8,
+                    155u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8,
+                    132u8, 151u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 160u8, 240u8,
+                    145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8,
+                    142u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8,
+                    132u8, 139u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 137u8, 240u8,
+                    145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 174u8,
+                    240u8, 145u8, 132u8, 139u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8,
+                    137u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8,
+                    132u8, 174u8, 240u8, 145u8, 132u8, 139u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 162u8,
+                    240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8,
+                    168u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8,
+                    132u8, 165u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 159u8, 240u8,
+                    145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 168u8,
+                    240u8, 145u8, 132u8, 145u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8,
+                    154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8,
+                    132u8, 162u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                    145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 175u8, 240u8, 145u8, 132u8, 162u8,
+                    240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8,
+                    145u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8,
+                    132u8, 167u8, 240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                    145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 166u8,
+                    240u8, 145u8, 132u8, 167u8, 32u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8,
+                    132u8, 168u8, 240u8, 145u8, 132u8, 136u8, 240u8, 145u8, 132u8, 167u8, 240u8,
+                    145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 165u8,
+                    240u8, 145u8, 132u8, 158u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    160u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8,
+                    132u8, 165u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 129u8, 240u8,
+                    145u8, 132u8, 166u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 163u8,
+                    240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8,
+                    154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8,
+                    132u8, 154u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 142u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 168u8,
+                    240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8,
+                    145u8, 240u8, 145u8, 132u8, 168u8, 32u8, 240u8, 145u8, 132u8, 154u8, 240u8,
+                    145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8,
+                    240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8,
+                    168u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8,
+                    132u8, 160u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 140u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8, 132u8,
+                    179u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8,
+                    132u8, 139u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 137u8, 240u8,
+                    145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 174u8,
+                    32u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8,
+                    132u8, 162u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 160u8, 240u8,
+                    145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 155u8,
+                    240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 143u8, 240u8, 145u8, 132u8,
+                    168u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8,
+                    132u8, 142u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 162u8, 240u8,
+                    145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8,
+                    168u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8,
+                    132u8, 160u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 135u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 142u8, 240u8, 145u8, 132u8,
+                    137u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8,
+                    132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 168u8,
+                    240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    160u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8,
+                    132u8, 145u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 174u8, 240u8,
+                    145u8, 132u8, 164u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 150u8,
+                    240u8, 145u8, 132u8, 173u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8,
+                    172u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 177u8, 32u8, 240u8,
+                    145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 173u8, 32u8, 240u8, 145u8, 132u8,
+                    163u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8,
+                    132u8, 159u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 163u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 173u8,
+                    32u8, 240u8, 145u8, 132u8, 158u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8,
+                    132u8, 160u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 150u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 172u8,
+                    240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8,
+                    137u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8,
+                    132u8, 172u8, 240u8, 145u8, 132u8, 139u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 160u8,
+                    240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8,
+                    150u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 156u8, 240u8, 145u8,
+                    132u8, 168u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 137u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8, 132u8, 137u8,
+                    240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8,
+                    137u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 151u8, 240u8, 145u8,
+                    132u8, 154u8, 240u8, 145u8, 132u8, 151u8, 240u8, 145u8, 132u8, 173u8, 240u8,
+                    145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 155u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8,
+                    167u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8,
+                    132u8, 133u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 137u8, 240u8,
+                    145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 145u8,
+                    240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8,
+                    180u8, 240u8, 145u8, 132u8, 158u8, 240u8, 145u8, 132u8, 173u8, 240u8, 145u8,
+                    132u8, 152u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 137u8, 240u8,
+                    145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 142u8, 240u8, 145u8, 132u8, 173u8,
+                    240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8,
+                    160u8, 240u8, 145u8, 132u8, 172u8, 32u8, 240u8, 145u8, 132u8, 158u8, 240u8,
+                    145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 155u8,
+                    240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8,
+                    174u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8,
+                    145u8, 132u8, 156u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    165u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8,
+                    132u8, 172u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 167u8, 45u8,
+                    240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8,
+                    179u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8,
+                    132u8, 169u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 167u8, 32u8,
+                    240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 169u8, 240u8, 145u8, 132u8,
+                    163u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8,
+                    132u8, 180u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 170u8, 240u8,
+                    145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 133u8,
+                    240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8,
+                    168u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 173u8, 240u8, 145u8,
+                    132u8, 154u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 158u8, 240u8,
+                    145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 180u8,
+                    32u8, 240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8,
+                    132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 166u8, 240u8,
+                    145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 168u8,
+                    240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8,
+                    142u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 141u8, 240u8, 145u8,
+                    132u8, 170u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                    145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 154u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8,
+                    167u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8,
+                    132u8, 135u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 136u8, 240u8,
+                    145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 172u8,
+                    240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8,
+                    142u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8,
+                    132u8, 167u8, 240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 166u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 157u8,
+                    240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8,
+                    180u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8,
+                    132u8, 155u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 160u8, 240u8,
+                    145u8, 132u8, 172u8, 32u8, 240u8, 145u8, 132u8, 166u8, 240u8, 145u8, 132u8,
+                    167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8,
+                    132u8, 135u8, 240u8, 145u8, 132u8, 180u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+};
+static EN_EN_001_EN_ZA: &DataStruct = &::icu_displaynames::provider::ScriptDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 100u8, 108u8, 109u8, 65u8, 102u8, 97u8, 107u8, 65u8, 103u8, 104u8, 98u8,
+                    65u8, 104u8, 111u8, 109u8, 65u8, 114u8, 97u8, 98u8, 65u8, 114u8, 97u8, 110u8,
+                    65u8, 114u8, 109u8, 105u8, 65u8, 114u8, 109u8, 110u8, 65u8, 118u8, 115u8,
+                    116u8, 66u8, 97u8, 108u8, 105u8, 66u8, 97u8, 109u8, 117u8, 66u8, 97u8, 115u8,
+                    115u8, 66u8, 97u8, 116u8, 107u8, 66u8, 101u8, 110u8, 103u8, 66u8, 104u8, 107u8,
+                    115u8, 66u8, 108u8, 105u8, 115u8, 66u8, 111u8, 112u8, 111u8, 66u8, 114u8, 97u8,
+                    104u8, 66u8, 114u8, 97u8, 105u8, 66u8, 117u8, 103u8, 105u8, 66u8, 117u8, 104u8,
+                    100u8, 67u8, 97u8, 107u8, 109u8, 67u8, 97u8, 110u8, 115u8, 67u8, 97u8, 114u8,
+                    105u8, 67u8, 104u8, 97u8, 109u8, 67u8, 104u8, 101u8, 114u8, 67u8, 104u8, 114u8,
+                    115u8, 67u8, 105u8, 114u8, 116u8, 67u8, 111u8, 112u8, 116u8, 67u8, 112u8,
+                    109u8, 110u8, 67u8, 112u8, 114u8, 116u8, 67u8, 121u8, 114u8, 108u8, 67u8,
+                    121u8, 114u8, 115u8, 68u8, 101u8, 118u8, 97u8, 68u8, 105u8, 97u8, 107u8, 68u8,
+                    111u8, 103u8, 114u8, 68u8, 115u8, 114u8, 116u8, 68u8, 117u8, 112u8, 108u8,
+                    69u8, 103u8, 121u8, 100u8, 69u8, 103u8, 121u8, 104u8, 69u8, 103u8, 121u8,
+                    112u8, 69u8, 108u8, 98u8, 97u8, 69u8, 108u8, 121u8, 109u8, 69u8, 116u8, 104u8,
+                    105u8, 71u8, 101u8, 111u8, 107u8, 71u8, 101u8, 111u8, 114u8, 71u8, 108u8, 97u8,
+                    103u8, 71u8, 111u8, 110u8, 103u8, 71u8, 111u8, 110u8, 109u8, 71u8, 111u8,
+                    116u8, 104u8, 71u8, 114u8, 97u8, 110u8, 71u8, 114u8, 101u8, 107u8, 71u8, 117u8,
+                    106u8, 114u8, 71u8, 117u8, 114u8, 117u8, 72u8, 97u8, 110u8, 98u8, 72u8, 97u8,
+                    110u8, 103u8, 72u8, 97u8, 110u8, 105u8, 72u8, 97u8, 110u8, 111u8, 72u8, 97u8,
+                    110u8, 115u8, 72u8, 97u8, 110u8, 116u8, 72u8, 97u8, 116u8, 114u8, 72u8, 101u8,
+                    98u8, 114u8, 72u8, 105u8, 114u8, 97u8, 72u8, 108u8, 117u8, 119u8, 72u8, 109u8,
+                    110u8, 103u8, 72u8, 109u8, 110u8, 112u8, 72u8, 114u8, 107u8, 116u8, 72u8,
+                    117u8, 110u8, 103u8, 73u8, 110u8, 100u8, 115u8, 73u8, 116u8, 97u8, 108u8, 74u8,
+                    97u8, 109u8, 111u8, 74u8, 97u8, 118u8, 97u8, 74u8, 112u8, 97u8, 110u8, 74u8,
+                    117u8, 114u8, 99u8, 75u8, 97u8, 108u8, 105u8, 75u8, 97u8, 110u8, 97u8, 75u8,
+                    97u8, 119u8, 105u8, 75u8, 104u8, 97u8, 114u8, 75u8, 104u8, 109u8, 114u8, 75u8,
+                    104u8, 111u8, 106u8, 75u8, 105u8, 116u8, 115u8, 75u8, 110u8, 100u8, 97u8, 75u8,
+                    111u8, 114u8, 101u8, 75u8, 112u8, 101u8, 108u8, 75u8, 116u8, 104u8, 105u8,
+                    76u8, 97u8, 110u8, 97u8, 76u8, 97u8, 111u8, 111u8, 76u8, 97u8, 116u8, 102u8,
+                    76u8, 97u8, 116u8, 103u8, 76u8, 97u8, 116u8, 110u8, 76u8, 101u8, 112u8, 99u8,
+                    76u8, 105u8, 109u8, 98u8, 76u8, 105u8, 110u8, 97u8, 76u8, 105u8, 110u8, 98u8,
+                    76u8, 105u8, 115u8, 117u8, 76u8, 111u8, 109u8, 97u8, 76u8, 121u8, 99u8, 105u8,
+                    76u8, 121u8, 100u8, 105u8, 77u8, 97u8, 104u8, 106u8, 77u8, 97u8, 107u8, 97u8,
+                    77u8, 97u8, 110u8, 100u8, 77u8, 97u8, 110u8, 105u8, 77u8, 97u8, 114u8, 99u8,
+                    77u8, 97u8, 121u8, 97u8, 77u8, 101u8, 100u8, 102u8, 77u8, 101u8, 110u8, 100u8,
+                    77u8, 101u8, 114u8, 99u8, 77u8, 101u8, 114u8, 111u8, 77u8, 108u8, 121u8, 109u8,
+                    77u8, 111u8, 100u8, 105u8, 77u8, 111u8, 110u8, 103u8, 77u8, 111u8, 111u8,
+                    110u8, 77u8, 114u8, 111u8, 111u8, 77u8, 116u8, 101u8, 105u8, 77u8, 117u8,
+                    108u8, 116u8, 77u8, 121u8, 109u8, 114u8, 78u8, 97u8, 103u8, 109u8, 78u8, 97u8,
+                    110u8, 100u8, 78u8, 97u8, 114u8, 98u8, 78u8, 98u8, 97u8, 116u8, 78u8, 101u8,
+                    119u8, 97u8, 78u8, 107u8, 103u8, 98u8, 78u8, 107u8, 111u8, 111u8, 78u8, 115u8,
+                    104u8, 117u8, 79u8, 103u8, 97u8, 109u8, 79u8, 108u8, 99u8, 107u8, 79u8, 114u8,
+                    107u8, 104u8, 79u8, 114u8, 121u8, 97u8, 79u8, 115u8, 103u8, 101u8, 79u8, 115u8,
+                    109u8, 97u8, 79u8, 117u8, 103u8, 114u8, 80u8, 97u8, 108u8, 109u8, 80u8, 97u8,
+                    117u8, 99u8, 80u8, 101u8, 114u8, 109u8, 80u8, 104u8, 97u8, 103u8, 80u8, 104u8,
+                    108u8, 105u8, 80u8, 104u8, 108u8, 112u8, 80u8, 104u8, 108u8, 118u8, 80u8,
+                    104u8, 110u8, 120u8, 80u8, 108u8, 114u8, 100u8, 80u8, 114u8, 116u8, 105u8,
+                    81u8, 97u8, 97u8, 103u8, 82u8, 106u8, 110u8, 103u8, 82u8, 111u8, 104u8, 103u8,
+                    82u8, 111u8, 114u8, 111u8, 82u8, 117u8, 110u8, 114u8, 83u8, 97u8, 109u8, 114u8,
+                    83u8, 97u8, 114u8, 97u8, 83u8, 97u8, 114u8, 98u8, 83u8, 97u8, 117u8, 114u8,
+                    83u8, 103u8, 110u8, 119u8, 83u8, 104u8, 97u8, 119u8, 83u8, 104u8, 114u8, 100u8,
+                    83u8, 105u8, 100u8, 100u8, 83u8, 105u8, 110u8, 100u8, 83u8, 105u8, 110u8,
+                    104u8, 83u8, 111u8, 103u8, 100u8, 83u8, 111u8, 103u8, 111u8, 83u8, 111u8,
+                    114u8, 97u8, 83u8, 111u8, 121u8, 111u8, 83u8, 117u8, 110u8, 100u8, 83u8, 121u8,
+                    108u8, 111u8, 83u8, 121u8, 114u8, 99u8, 83u8, 121u8, 114u8, 101u8, 83u8, 121u8,
+                    114u8, 106u8, 83u8, 121u8, 114u8, 110u8, 84u8, 97u8, 103u8, 98u8, 84u8, 97u8,
+                    107u8, 114u8, 84u8, 97u8, 108u8, 101u8, 84u8, 97u8, 108u8, 117u8, 84u8, 97u8,
+                    109u8, 108u8, 84u8, 97u8, 110u8, 103u8, 84u8, 97u8, 118u8, 116u8, 84u8, 101u8,
+                    108u8, 117u8, 84u8, 101u8, 110u8, 103u8, 84u8, 102u8, 110u8, 103u8, 84u8,
+                    103u8, 108u8, 103u8, 84u8, 104u8, 97u8, 97u8, 84u8, 104u8, 97u8, 105u8, 84u8,
+                    105u8, 98u8, 116u8, 84u8, 105u8, 114u8, 104u8, 84u8, 110u8, 115u8, 97u8, 84u8,
+                    111u8, 116u8, 111u8, 85u8, 103u8, 97u8, 114u8, 86u8, 97u8, 105u8, 105u8, 86u8,
+                    105u8, 115u8, 112u8, 86u8, 105u8, 116u8, 104u8, 87u8, 97u8, 114u8, 97u8, 87u8,
+                    99u8, 104u8, 111u8, 87u8, 111u8, 108u8, 101u8, 88u8, 112u8, 101u8, 111u8, 88u8,
+                    115u8, 117u8, 120u8, 89u8, 101u8, 122u8, 105u8, 89u8, 105u8, 105u8, 105u8,
+                    90u8, 97u8, 110u8, 98u8, 90u8, 105u8, 110u8, 104u8, 90u8, 109u8, 116u8, 104u8,
+                    90u8, 115u8, 121u8, 101u8, 90u8, 115u8, 121u8, 109u8, 90u8, 120u8, 120u8,
+                    120u8, 90u8, 121u8, 121u8, 121u8, 90u8, 122u8, 122u8, 122u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    202u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 10u8, 0u8, 28u8, 0u8, 32u8, 0u8,
+                    38u8, 0u8, 46u8, 0u8, 62u8, 0u8, 70u8, 0u8, 77u8, 0u8, 85u8, 0u8, 90u8, 0u8,
+                    99u8, 0u8, 104u8, 0u8, 110u8, 0u8, 119u8, 0u8, 130u8, 0u8, 138u8, 0u8, 144u8,
+                    0u8, 151u8, 0u8, 159u8, 0u8, 164u8, 0u8, 170u8, 0u8, 207u8, 0u8, 213u8, 0u8,
+                    217u8, 0u8, 225u8, 0u8, 235u8, 0u8, 240u8, 0u8, 246u8, 0u8, 2u8, 1u8, 9u8, 1u8,
+                    17u8, 1u8, 45u8, 1u8, 55u8, 1u8, 66u8, 1u8, 71u8, 1u8, 78u8, 1u8, 96u8, 1u8,
+                    112u8, 1u8, 129u8, 1u8, 149u8, 1u8, 156u8, 1u8, 163u8, 1u8, 171u8, 1u8, 188u8,
+                    1u8, 196u8, 1u8, 206u8, 1u8, 219u8, 1u8, 232u8, 1u8, 238u8, 1u8, 245u8, 1u8,
+                    250u8, 1u8, 2u8, 2u8, 10u8, 2u8, 27u8, 2u8, 33u8, 2u8, 36u8, 2u8, 43u8, 2u8,
+                    53u8, 2u8, 64u8, 2u8, 70u8, 2u8, 76u8, 2u8, 84u8, 2u8, 105u8, 2u8, 117u8, 2u8,
+                    139u8, 2u8, 159u8, 2u8, 172u8, 2u8, 177u8, 2u8, 187u8, 2u8, 191u8, 2u8, 199u8,
+                    2u8, 207u8, 2u8, 214u8, 2u8, 222u8, 2u8, 230u8, 2u8, 234u8, 2u8, 244u8, 2u8,
+                    249u8, 2u8, 255u8, 2u8, 18u8, 3u8, 25u8, 3u8, 31u8, 3u8, 37u8, 3u8, 43u8, 3u8,
+                    48u8, 3u8, 51u8, 3u8, 64u8, 3u8, 76u8, 3u8, 81u8, 3u8, 87u8, 3u8, 92u8, 3u8,
+                    100u8, 3u8, 108u8, 3u8, 114u8, 3u8, 118u8, 3u8, 124u8, 3u8, 130u8, 3u8, 138u8,
+                    3u8, 145u8, 3u8, 153u8, 3u8, 163u8, 3u8, 170u8, 3u8, 187u8, 3u8, 198u8, 3u8,
+                    203u8, 3u8, 219u8, 3u8, 227u8, 3u8, 236u8, 3u8, 240u8, 3u8, 249u8, 3u8, 253u8,
+                    3u8, 0u8, 4u8, 12u8, 4u8, 19u8, 4u8, 26u8, 4u8, 37u8, 4u8, 48u8, 4u8, 65u8,
+                    4u8, 74u8, 4u8, 78u8, 4u8, 87u8, 4u8, 93u8, 4u8, 99u8, 4u8, 104u8, 4u8, 112u8,
+                    4u8, 118u8, 4u8, 122u8, 4u8, 127u8, 4u8, 134u8, 4u8, 144u8, 4u8, 153u8, 4u8,
+                    164u8, 4u8, 174u8, 4u8, 182u8, 4u8, 203u8, 4u8, 218u8, 4u8, 230u8, 4u8, 240u8,
+                    4u8, 0u8, 5u8, 22u8, 5u8, 28u8, 5u8, 34u8, 5u8, 40u8, 5u8, 50u8, 5u8, 55u8,
+                    5u8, 64u8, 5u8, 70u8, 5u8, 87u8, 5u8, 97u8, 5u8, 108u8, 5u8, 115u8, 5u8, 122u8,
+                    5u8, 129u8, 5u8, 138u8, 5u8, 145u8, 5u8, 152u8, 5u8, 163u8, 5u8, 175u8, 5u8,
+                    182u8, 5u8, 191u8, 5u8, 203u8, 5u8, 209u8, 5u8, 226u8, 5u8, 240u8, 5u8, 254u8,
+                    5u8, 6u8, 6u8, 11u8, 6u8, 17u8, 6u8, 28u8, 6u8, 33u8, 6u8, 39u8, 6u8, 47u8,
+                    6u8, 53u8, 6u8, 60u8, 6u8, 68u8, 6u8, 75u8, 6u8, 81u8, 6u8, 85u8, 6u8, 92u8,
+                    6u8, 99u8, 6u8, 105u8, 6u8, 109u8, 6u8, 117u8, 6u8, 120u8, 6u8, 134u8, 6u8,
+                    142u8, 6u8, 155u8, 6u8, 161u8, 6u8, 167u8, 6u8, 178u8, 6u8, 203u8, 6u8, 209u8,
+                    6u8, 211u8, 6u8, 227u8, 6u8, 236u8, 6u8, 1u8, 7u8, 6u8, 7u8, 13u8, 7u8, 22u8,
+                    7u8, 28u8, 7u8, 65u8, 100u8, 108u8, 97u8, 109u8, 65u8, 102u8, 97u8, 107u8,
+                    97u8, 67u8, 97u8, 117u8, 99u8, 97u8, 115u8, 105u8, 97u8, 110u8, 32u8, 65u8,
+                    108u8, 98u8, 97u8, 110u8, 105u8, 97u8, 110u8, 65u8, 104u8, 111u8, 109u8, 65u8,
+                    114u8, 97u8, 98u8, 105u8, 99u8, 78u8, 97u8, 115u8, 116u8, 97u8, 108u8, 105u8,
+                    113u8, 73u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 32u8, 65u8, 114u8,
+                    97u8, 109u8, 97u8, 105u8, 99u8, 65u8, 114u8, 109u8, 101u8, 110u8, 105u8, 97u8,
+                    110u8, 65u8, 118u8, 101u8, 115u8, 116u8, 97u8, 110u8, 66u8, 97u8, 108u8, 105u8,
+                    110u8, 101u8, 115u8, 101u8, 66u8, 97u8, 109u8, 117u8, 109u8, 66u8, 97u8, 115u8,
+                    115u8, 97u8, 32u8, 86u8, 97u8, 104u8, 66u8, 97u8, 116u8, 97u8, 107u8, 66u8,
+                    97u8, 110u8, 103u8, 108u8, 97u8, 66u8, 104u8, 97u8, 105u8, 107u8, 115u8, 117u8,
+                    107u8, 105u8, 66u8, 108u8, 105u8, 115u8, 115u8, 121u8, 109u8, 98u8, 111u8,
+                    108u8, 115u8, 66u8, 111u8, 112u8, 111u8, 109u8, 111u8, 102u8, 111u8, 66u8,
+                    114u8, 97u8, 104u8, 109u8, 105u8, 66u8, 114u8, 97u8, 105u8, 108u8, 108u8,
+                    101u8, 66u8, 117u8, 103u8, 105u8, 110u8, 101u8, 115u8, 101u8, 66u8, 117u8,
+                    104u8, 105u8, 100u8, 67u8, 104u8, 97u8, 107u8, 109u8, 97u8, 85u8, 110u8, 105u8,
+                    102u8, 105u8, 101u8, 100u8, 32u8, 67u8, 97u8, 110u8, 97u8, 100u8, 105u8, 97u8,
+                    110u8, 32u8, 65u8, 98u8, 111u8, 114u8, 105u8, 103u8, 105u8, 110u8, 97u8, 108u8,
+                    32u8, 83u8, 121u8, 108u8, 108u8, 97u8, 98u8, 105u8, 99u8, 115u8, 67u8, 97u8,
+                    114u8, 105u8, 97u8, 110u8, 67u8, 104u8, 97u8, 109u8, 67u8, 104u8, 101u8, 114u8,
+                    111u8, 107u8, 101u8, 101u8, 67u8, 104u8, 111u8, 114u8, 97u8, 115u8, 109u8,
+                    105u8, 97u8, 110u8, 67u8, 105u8, 114u8, 116u8, 104u8, 67u8, 111u8, 112u8,
+                    116u8, 105u8, 99u8, 67u8, 121u8, 112u8, 114u8, 111u8, 45u8, 77u8, 105u8, 110u8,
+                    111u8, 97u8, 110u8, 67u8, 121u8, 112u8, 114u8, 105u8, 111u8, 116u8, 67u8,
+                    121u8, 114u8, 105u8, 108u8, 108u8, 105u8, 99u8, 79u8, 108u8, 100u8, 32u8, 67u8,
+                    104u8, 117u8, 114u8, 99u8, 104u8, 32u8, 83u8, 108u8, 97u8, 118u8, 111u8, 110u8,
+                    105u8, 99u8, 32u8, 67u8, 121u8, 114u8, 105u8, 108u8, 108u8, 105u8, 99u8, 68u8,
+                    101u8, 118u8, 97u8, 110u8, 97u8, 103u8, 97u8, 114u8, 105u8, 68u8, 105u8, 118u8,
+                    101u8, 115u8, 32u8, 65u8, 107u8, 117u8, 114u8, 117u8, 68u8, 111u8, 103u8,
+                    114u8, 97u8, 68u8, 101u8, 115u8, 101u8, 114u8, 101u8, 116u8, 68u8, 117u8,
+                    112u8, 108u8, 111u8, 121u8, 97u8, 110u8, 32u8, 115u8, 104u8, 111u8, 114u8,
+                    116u8, 104u8, 97u8, 110u8, 100u8, 69u8, 103u8, 121u8, 112u8, 116u8, 105u8,
+                    97u8, 110u8, 32u8, 100u8, 101u8, 109u8, 111u8, 116u8, 105u8, 99u8, 69u8, 103u8,
+                    121u8, 112u8, 116u8, 105u8, 97u8, 110u8, 32u8, 104u8, 105u8, 101u8, 114u8,
+                    97u8, 116u8, 105u8, 99u8, 69u8, 103u8, 121u8, 112u8, 116u8, 105u8, 97u8, 110u8,
+                    32u8, 104u8, 105u8, 101u8, 114u8, 111u8, 103u8, 108u8, 121u8, 112u8, 104u8,
+                    115u8, 69u8, 108u8, 98u8, 97u8, 115u8, 97u8, 110u8, 69u8, 108u8, 121u8, 109u8,
+                    97u8, 105u8, 99u8, 69u8, 116u8, 104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 71u8,
+                    101u8, 111u8, 114u8, 103u8, 105u8, 97u8, 110u8, 32u8, 75u8, 104u8, 117u8,
+                    116u8, 115u8, 117u8, 114u8, 105u8, 71u8, 101u8, 111u8, 114u8, 103u8, 105u8,
+                    97u8, 110u8, 71u8, 108u8, 97u8, 103u8, 111u8, 108u8, 105u8, 116u8, 105u8, 99u8,
+                    71u8, 117u8, 110u8, 106u8, 97u8, 108u8, 97u8, 32u8, 71u8, 111u8, 110u8, 100u8,
+                    105u8, 77u8, 97u8, 115u8, 97u8, 114u8, 97u8, 109u8, 32u8, 71u8, 111u8, 110u8,
+                    100u8, 105u8, 71u8, 111u8, 116u8, 104u8, 105u8, 99u8, 71u8, 114u8, 97u8, 110u8,
+                    116u8, 104u8, 97u8, 71u8, 114u8, 101u8, 101u8, 107u8, 71u8, 117u8, 106u8, 97u8,
+                    114u8, 97u8, 116u8, 105u8, 71u8, 117u8, 114u8, 109u8, 117u8, 107u8, 104u8,
+                    105u8, 72u8, 97u8, 110u8, 32u8, 119u8, 105u8, 116u8, 104u8, 32u8, 66u8, 111u8,
+                    112u8, 111u8, 109u8, 111u8, 102u8, 111u8, 72u8, 97u8, 110u8, 103u8, 117u8,
+                    108u8, 72u8, 97u8, 110u8, 72u8, 97u8, 110u8, 117u8, 110u8, 111u8, 111u8, 83u8,
+                    105u8, 109u8, 112u8, 108u8, 105u8, 102u8, 105u8, 101u8, 100u8, 84u8, 114u8,
+                    97u8, 100u8, 105u8, 116u8, 105u8, 111u8, 110u8, 97u8, 108u8, 72u8, 97u8, 116u8,
+                    114u8, 97u8, 110u8, 72u8, 101u8, 98u8, 114u8, 101u8, 119u8, 72u8, 105u8, 114u8,
+                    97u8, 103u8, 97u8, 110u8, 97u8, 65u8, 110u8, 97u8, 116u8, 111u8, 108u8, 105u8,
+                    97u8, 110u8, 32u8, 72u8, 105u8, 101u8, 114u8, 111u8, 103u8, 108u8, 121u8,
+                    112u8, 104u8, 115u8, 80u8, 97u8, 104u8, 97u8, 119u8, 104u8, 32u8, 72u8, 109u8,
+                    111u8, 110u8, 103u8, 78u8, 121u8, 105u8, 97u8, 107u8, 101u8, 110u8, 103u8,
+                    32u8, 80u8, 117u8, 97u8, 99u8, 104u8, 117u8, 101u8, 32u8, 72u8, 109u8, 111u8,
+                    110u8, 103u8, 74u8, 97u8, 112u8, 97u8, 110u8, 101u8, 115u8, 101u8, 32u8, 115u8,
+                    121u8, 108u8, 108u8, 97u8, 98u8, 97u8, 114u8, 105u8, 101u8, 115u8, 79u8, 108u8,
+                    100u8, 32u8, 72u8, 117u8, 110u8, 103u8, 97u8, 114u8, 105u8, 97u8, 110u8, 73u8,
+                    110u8, 100u8, 117u8, 115u8, 79u8, 108u8, 100u8, 32u8, 73u8, 116u8, 97u8, 108u8,
+                    105u8, 99u8, 74u8, 97u8, 109u8, 111u8, 74u8, 97u8, 118u8, 97u8, 110u8, 101u8,
+                    115u8, 101u8, 74u8, 97u8, 112u8, 97u8, 110u8, 101u8, 115u8, 101u8, 74u8, 117u8,
+                    114u8, 99u8, 104u8, 101u8, 110u8, 75u8, 97u8, 121u8, 97u8, 104u8, 32u8, 76u8,
+                    105u8, 75u8, 97u8, 116u8, 97u8, 107u8, 97u8, 110u8, 97u8, 75u8, 97u8, 119u8,
+                    105u8, 75u8, 104u8, 97u8, 114u8, 111u8, 115u8, 104u8, 116u8, 104u8, 105u8,
+                    75u8, 104u8, 109u8, 101u8, 114u8, 75u8, 104u8, 111u8, 106u8, 107u8, 105u8,
+                    75u8, 104u8, 105u8, 116u8, 97u8, 110u8, 32u8, 115u8, 109u8, 97u8, 108u8, 108u8,
+                    32u8, 115u8, 99u8, 114u8, 105u8, 112u8, 116u8, 75u8, 97u8, 110u8, 110u8, 97u8,
+                    100u8, 97u8, 75u8, 111u8, 114u8, 101u8, 97u8, 110u8, 75u8, 112u8, 101u8, 108u8,
+                    108u8, 101u8, 75u8, 97u8, 105u8, 116u8, 104u8, 105u8, 76u8, 97u8, 110u8, 110u8,
+                    97u8, 76u8, 97u8, 111u8, 70u8, 114u8, 97u8, 107u8, 116u8, 117u8, 114u8, 32u8,
+                    76u8, 97u8, 116u8, 105u8, 110u8, 71u8, 97u8, 101u8, 108u8, 105u8, 99u8, 32u8,
+                    76u8, 97u8, 116u8, 105u8, 110u8, 76u8, 97u8, 116u8, 105u8, 110u8, 76u8, 101u8,
+                    112u8, 99u8, 104u8, 97u8, 76u8, 105u8, 109u8, 98u8, 117u8, 76u8, 105u8, 110u8,
+                    101u8, 97u8, 114u8, 32u8, 65u8, 76u8, 105u8, 110u8, 101u8, 97u8, 114u8, 32u8,
+                    66u8, 70u8, 114u8, 97u8, 115u8, 101u8, 114u8, 76u8, 111u8, 109u8, 97u8, 76u8,
+                    121u8, 99u8, 105u8, 97u8, 110u8, 76u8, 121u8, 100u8, 105u8, 97u8, 110u8, 77u8,
+                    97u8, 104u8, 97u8, 106u8, 97u8, 110u8, 105u8, 77u8, 97u8, 107u8, 97u8, 115u8,
+                    97u8, 114u8, 77u8, 97u8, 110u8, 100u8, 97u8, 101u8, 97u8, 110u8, 77u8, 97u8,
+                    110u8, 105u8, 99u8, 104u8, 97u8, 101u8, 97u8, 110u8, 77u8, 97u8, 114u8, 99u8,
+                    104u8, 101u8, 110u8, 77u8, 97u8, 121u8, 97u8, 110u8, 32u8, 104u8, 105u8, 101u8,
+                    114u8, 111u8, 103u8, 108u8, 121u8, 112u8, 104u8, 115u8, 77u8, 101u8, 100u8,
+                    101u8, 102u8, 97u8, 105u8, 100u8, 114u8, 105u8, 110u8, 77u8, 101u8, 110u8,
+                    100u8, 101u8, 77u8, 101u8, 114u8, 111u8, 105u8, 116u8, 105u8, 99u8, 32u8, 67u8,
+                    117u8, 114u8, 115u8, 105u8, 118u8, 101u8, 77u8, 101u8, 114u8, 111u8, 105u8,
+                    116u8, 105u8, 99u8, 77u8, 97u8, 108u8, 97u8, 121u8, 97u8, 108u8, 97u8, 109u8,
+                    77u8, 111u8, 100u8, 105u8, 77u8, 111u8, 110u8, 103u8, 111u8, 108u8, 105u8,
+                    97u8, 110u8, 77u8, 111u8, 111u8, 110u8, 77u8, 114u8, 111u8, 77u8, 101u8, 105u8,
+                    116u8, 101u8, 105u8, 32u8, 77u8, 97u8, 121u8, 101u8, 107u8, 77u8, 117u8, 108u8,
+                    116u8, 97u8, 110u8, 105u8, 77u8, 121u8, 97u8, 110u8, 109u8, 97u8, 114u8, 78u8,
+                    97u8, 103u8, 32u8, 77u8, 117u8, 110u8, 100u8, 97u8, 114u8, 105u8, 78u8, 97u8,
+                    110u8, 100u8, 105u8, 110u8, 97u8, 103u8, 97u8, 114u8, 105u8, 79u8, 108u8,
+                    100u8, 32u8, 78u8, 111u8, 114u8, 116u8, 104u8, 32u8, 65u8, 114u8, 97u8, 98u8,
+                    105u8, 97u8, 110u8, 78u8, 97u8, 98u8, 97u8, 116u8, 97u8, 101u8, 97u8, 110u8,
+                    78u8, 101u8, 119u8, 97u8, 78u8, 97u8, 120u8, 105u8, 32u8, 71u8, 101u8, 98u8,
+                    97u8, 78u8, 226u8, 128u8, 153u8, 75u8, 111u8, 78u8, 195u8, 188u8, 115u8, 104u8,
+                    117u8, 79u8, 103u8, 104u8, 97u8, 109u8, 79u8, 108u8, 32u8, 67u8, 104u8, 105u8,
+                    107u8, 105u8, 79u8, 114u8, 107u8, 104u8, 111u8, 110u8, 79u8, 100u8, 105u8,
+                    97u8, 79u8, 115u8, 97u8, 103u8, 101u8, 79u8, 115u8, 109u8, 97u8, 110u8, 121u8,
+                    97u8, 79u8, 108u8, 100u8, 32u8, 85u8, 121u8, 103u8, 104u8, 117u8, 114u8, 80u8,
+                    97u8, 108u8, 109u8, 121u8, 114u8, 101u8, 110u8, 101u8, 80u8, 97u8, 117u8, 32u8,
+                    67u8, 105u8, 110u8, 32u8, 72u8, 97u8, 117u8, 79u8, 108u8, 100u8, 32u8, 80u8,
+                    101u8, 114u8, 109u8, 105u8, 99u8, 80u8, 104u8, 97u8, 103u8, 115u8, 45u8, 112u8,
+                    97u8, 73u8, 110u8, 115u8, 99u8, 114u8, 105u8, 112u8, 116u8, 105u8, 111u8,
+                    110u8, 97u8, 108u8, 32u8, 80u8, 97u8, 104u8, 108u8, 97u8, 118u8, 105u8, 80u8,
+                    115u8, 97u8, 108u8, 116u8, 101u8, 114u8, 32u8, 80u8, 97u8, 104u8, 108u8, 97u8,
+                    118u8, 105u8, 66u8, 111u8, 111u8, 107u8, 32u8, 80u8, 97u8, 104u8, 108u8, 97u8,
+                    118u8, 105u8, 80u8, 104u8, 111u8, 101u8, 110u8, 105u8, 99u8, 105u8, 97u8,
+                    110u8, 80u8, 111u8, 108u8, 108u8, 97u8, 114u8, 100u8, 32u8, 80u8, 104u8, 111u8,
+                    110u8, 101u8, 116u8, 105u8, 99u8, 73u8, 110u8, 115u8, 99u8, 114u8, 105u8,
+                    112u8, 116u8, 105u8, 111u8, 110u8, 97u8, 108u8, 32u8, 80u8, 97u8, 114u8, 116u8,
+                    104u8, 105u8, 97u8, 110u8, 90u8, 97u8, 119u8, 103u8, 121u8, 105u8, 82u8, 101u8,
+                    106u8, 97u8, 110u8, 103u8, 72u8, 97u8, 110u8, 105u8, 102u8, 105u8, 82u8, 111u8,
+                    110u8, 103u8, 111u8, 114u8, 111u8, 110u8, 103u8, 111u8, 82u8, 117u8, 110u8,
+                    105u8, 99u8, 83u8, 97u8, 109u8, 97u8, 114u8, 105u8, 116u8, 97u8, 110u8, 83u8,
+                    97u8, 114u8, 97u8, 116u8, 105u8, 79u8, 108u8, 100u8, 32u8, 83u8, 111u8, 117u8,
+                    116u8, 104u8, 32u8, 65u8, 114u8, 97u8, 98u8, 105u8, 97u8, 110u8, 83u8, 97u8,
+                    117u8, 114u8, 97u8, 115u8, 104u8, 116u8, 114u8, 97u8, 83u8, 105u8, 103u8,
+                    110u8, 87u8, 114u8, 105u8, 116u8, 105u8, 110u8, 103u8, 83u8, 104u8, 97u8,
+                    118u8, 105u8, 97u8, 110u8, 83u8, 104u8, 97u8, 114u8, 97u8, 100u8, 97u8, 83u8,
+                    105u8, 100u8, 100u8, 104u8, 97u8, 109u8, 75u8, 104u8, 117u8, 100u8, 97u8,
+                    119u8, 97u8, 100u8, 105u8, 83u8, 105u8, 110u8, 104u8, 97u8, 108u8, 97u8, 83u8,
+                    111u8, 103u8, 100u8, 105u8, 97u8, 110u8, 79u8, 108u8, 100u8, 32u8, 83u8, 111u8,
+                    103u8, 100u8, 105u8, 97u8, 110u8, 83u8, 111u8, 114u8, 97u8, 32u8, 83u8, 111u8,
+                    109u8, 112u8, 101u8, 110u8, 103u8, 83u8, 111u8, 121u8, 111u8, 109u8, 98u8,
+                    111u8, 83u8, 117u8, 110u8, 100u8, 97u8, 110u8, 101u8, 115u8, 101u8, 83u8,
+                    121u8, 108u8, 111u8, 116u8, 105u8, 32u8, 78u8, 97u8, 103u8, 114u8, 105u8, 83u8,
+                    121u8, 114u8, 105u8, 97u8, 99u8, 69u8, 115u8, 116u8, 114u8, 97u8, 110u8, 103u8,
+                    101u8, 108u8, 111u8, 32u8, 83u8, 121u8, 114u8, 105u8, 97u8, 99u8, 87u8, 101u8,
+                    115u8, 116u8, 101u8, 114u8, 110u8, 32u8, 83u8, 121u8, 114u8, 105u8, 97u8, 99u8,
+                    69u8, 97u8, 115u8, 116u8, 101u8, 114u8, 110u8, 32u8, 83u8, 121u8, 114u8, 105u8,
+                    97u8, 99u8, 84u8, 97u8, 103u8, 98u8, 97u8, 110u8, 119u8, 97u8, 84u8, 97u8,
+                    107u8, 114u8, 105u8, 84u8, 97u8, 105u8, 32u8, 76u8, 101u8, 78u8, 101u8, 119u8,
+                    32u8, 84u8, 97u8, 105u8, 32u8, 76u8, 117u8, 101u8, 84u8, 97u8, 109u8, 105u8,
+                    108u8, 84u8, 97u8, 110u8, 103u8, 117u8, 116u8, 84u8, 97u8, 105u8, 32u8, 86u8,
+                    105u8, 101u8, 116u8, 84u8, 101u8, 108u8, 117u8, 103u8, 117u8, 84u8, 101u8,
+                    110u8, 103u8, 119u8, 97u8, 114u8, 84u8, 105u8, 102u8, 105u8, 110u8, 97u8,
+                    103u8, 104u8, 84u8, 97u8, 103u8, 97u8, 108u8, 111u8, 103u8, 84u8, 104u8, 97u8,
+                    97u8, 110u8, 97u8, 84u8, 104u8, 97u8, 105u8, 84u8, 105u8, 98u8, 101u8, 116u8,
+                    97u8, 110u8, 84u8, 105u8, 114u8, 104u8, 117u8, 116u8, 97u8, 84u8, 97u8, 110u8,
+                    103u8, 115u8, 97u8, 84u8, 111u8, 116u8, 111u8, 85u8, 103u8, 97u8, 114u8, 105u8,
+                    116u8, 105u8, 99u8, 86u8, 97u8, 105u8, 86u8, 105u8, 115u8, 105u8, 98u8, 108u8,
+                    101u8, 32u8, 83u8, 112u8, 101u8, 101u8, 99u8, 104u8, 86u8, 105u8, 116u8, 104u8,
+                    107u8, 117u8, 113u8, 105u8, 86u8, 97u8, 114u8, 97u8, 110u8, 103u8, 32u8, 75u8,
+                    115u8, 104u8, 105u8, 116u8, 105u8, 87u8, 97u8, 110u8, 99u8, 104u8, 111u8, 87u8,
+                    111u8, 108u8, 101u8, 97u8, 105u8, 79u8, 108u8, 100u8, 32u8, 80u8, 101u8, 114u8,
+                    115u8, 105u8, 97u8, 110u8, 83u8, 117u8, 109u8, 101u8, 114u8, 111u8, 45u8, 65u8,
+                    107u8, 107u8, 97u8, 100u8, 105u8, 97u8, 110u8, 32u8, 67u8, 117u8, 110u8, 101u8,
+                    105u8, 102u8, 111u8, 114u8, 109u8, 89u8, 101u8, 122u8, 105u8, 100u8, 105u8,
+                    89u8, 105u8, 90u8, 97u8, 110u8, 97u8, 98u8, 97u8, 122u8, 97u8, 114u8, 32u8,
+                    83u8, 113u8, 117u8, 97u8, 114u8, 101u8, 73u8, 110u8, 104u8, 101u8, 114u8,
+                    105u8, 116u8, 101u8, 100u8, 77u8, 97u8, 116u8, 104u8, 101u8, 109u8, 97u8,
+                    116u8, 105u8, 99u8, 97u8, 108u8, 32u8, 78u8, 111u8, 116u8, 97u8, 116u8, 105u8,
+                    111u8, 110u8, 69u8, 109u8, 111u8, 106u8, 105u8, 83u8, 121u8, 109u8, 98u8,
+                    111u8, 108u8, 115u8, 85u8, 110u8, 119u8, 114u8, 105u8, 116u8, 116u8, 101u8,
+                    110u8, 67u8, 111u8, 109u8, 109u8, 111u8, 110u8, 85u8, 110u8, 107u8, 110u8,
+                    111u8, 119u8, 110u8, 32u8, 83u8, 99u8, 114u8, 105u8, 112u8, 116u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    67u8, 97u8, 110u8, 115u8, 88u8, 115u8, 117u8, 120u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 85u8, 67u8, 65u8, 83u8, 83u8, 45u8,
+                    65u8, 32u8, 67u8, 117u8, 110u8, 101u8, 105u8, 102u8, 111u8, 114u8, 109u8,
+                ])
+            },
+        )
+    },
+};
+static ES: &DataStruct = &::icu_displaynames::provider::ScriptDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 100u8, 108u8, 109u8, 65u8, 114u8, 97u8, 98u8, 65u8, 114u8, 97u8, 110u8,
+                    65u8, 114u8, 109u8, 110u8, 65u8, 118u8, 115u8, 116u8, 66u8, 97u8, 108u8, 105u8,
+                    66u8, 97u8, 116u8, 107u8, 66u8, 101u8, 110u8, 103u8, 66u8, 108u8, 105u8, 115u8,
+                    66u8, 111u8, 112u8, 111u8, 66u8, 114u8, 97u8, 104u8, 66u8, 114u8, 97u8, 105u8,
+                    66u8, 117u8, 103u8, 105u8, 66u8, 117u8, 104u8, 100u8, 67u8, 97u8, 107u8, 109u8,
+                    67u8, 97u8, 110u8, 115u8, 67u8, 97u8, 114u8, 105u8, 67u8, 104u8, 97u8, 109u8,
+                    67u8, 104u8, 101u8, 114u8, 67u8, 105u8, 114u8, 116u8, 67u8, 111u8, 112u8,
+                    116u8, 67u8, 112u8, 114u8, 116u8, 67u8, 121u8, 114u8, 108u8, 67u8, 121u8,
+                    114u8, 115u8, 68u8, 101u8, 118u8, 97u8, 68u8, 115u8, 114u8, 116u8, 69u8, 103u8,
+                    121u8, 100u8, 69u8, 103u8, 121u8, 104u8, 69u8, 103u8, 121u8, 112u8, 69u8,
+                    116u8, 104u8, 105u8, 71u8, 101u8, 111u8, 107u8, 71u8, 101u8, 111u8, 114u8,
+                    71u8, 108u8, 97u8, 103u8, 71u8, 111u8, 116u8, 104u8, 71u8, 114u8, 101u8, 107u8,
+                    71u8, 117u8, 106u8, 114u8, 71u8, 117u8, 114u8, 117u8, 72u8, 97u8, 110u8, 98u8,
+                    72u8, 97u8, 110u8, 103u8, 72u8, 97u8, 110u8, 105u8, 72u8, 97u8, 110u8, 111u8,
+                    72u8, 97u8, 110u8, 115u8, 72u8, 97u8, 110u8, 116u8, 72u8, 101u8, 98u8, 114u8,
+                    72u8, 105u8, 114u8, 97u8, 72u8, 109u8, 110u8, 103u8, 72u8, 114u8, 107u8, 116u8,
+                    72u8, 117u8, 110u8, 103u8, 73u8, 110u8, 100u8, 115u8, 73u8, 116u8, 97u8, 108u8,
+                    74u8, 97u8, 109u8, 111u8, 74u8, 97u8, 118u8, 97u8, 74u8, 112u8, 97u8, 110u8,
+                    75u8, 97u8, 108u8, 105u8, 75u8, 97u8, 110u8, 97u8, 75u8, 104u8, 97u8, 114u8,
+                    75u8, 104u8, 109u8, 114u8, 75u8, 110u8, 100u8, 97u8, 75u8, 111u8, 114u8, 101u8,
+                    76u8, 97u8, 110u8, 97u8, 76u8, 97u8, 111u8, 111u8, 76u8, 97u8, 116u8, 102u8,
+                    76u8, 97u8, 116u8, 103u8, 76u8, 97u8, 116u8, 110u8, 76u8, 101u8, 112u8, 99u8,
+                    76u8, 105u8, 109u8, 98u8, 76u8, 105u8, 110u8, 97u8, 76u8, 105u8, 110u8, 98u8,
+                    76u8, 121u8, 99u8, 105u8, 76u8, 121u8, 100u8, 105u8, 77u8, 97u8, 110u8, 100u8,
+                    77u8, 97u8, 121u8, 97u8, 77u8, 101u8, 114u8, 111u8, 77u8, 108u8, 121u8, 109u8,
+                    77u8, 111u8, 110u8, 103u8, 77u8, 111u8, 111u8, 110u8, 77u8, 116u8, 101u8,
+                    105u8, 77u8, 121u8, 109u8, 114u8, 78u8, 107u8, 111u8, 111u8, 79u8, 103u8, 97u8,
+                    109u8, 79u8, 108u8, 99u8, 107u8, 79u8, 114u8, 107u8, 104u8, 79u8, 114u8, 121u8,
+                    97u8, 79u8, 115u8, 109u8, 97u8, 80u8, 101u8, 114u8, 109u8, 80u8, 104u8, 97u8,
+                    103u8, 80u8, 104u8, 110u8, 120u8, 80u8, 108u8, 114u8, 100u8, 81u8, 97u8, 97u8,
+                    103u8, 82u8, 106u8, 110u8, 103u8, 82u8, 111u8, 104u8, 103u8, 82u8, 111u8,
+                    114u8, 111u8, 82u8, 117u8, 110u8, 114u8, 83u8, 97u8, 114u8, 97u8, 83u8, 97u8,
+                    117u8, 114u8, 83u8, 103u8, 110u8, 119u8, 83u8, 104u8, 97u8, 119u8, 83u8, 105u8,
+                    110u8, 104u8, 83u8, 117u8, 110u8, 100u8, 83u8, 121u8, 108u8, 111u8, 83u8,
+                    121u8, 114u8, 99u8, 83u8, 121u8, 114u8, 101u8, 83u8, 121u8, 114u8, 106u8, 83u8,
+                    121u8, 114u8, 110u8, 84u8, 97u8, 103u8, 98u8, 84u8, 97u8, 108u8, 101u8, 84u8,
+                    97u8, 108u8, 117u8, 84u8, 97u8, 109u8, 108u8, 84u8, 101u8, 108u8, 117u8, 84u8,
+                    101u8, 110u8, 103u8, 84u8, 102u8, 110u8, 103u8, 84u8, 103u8, 108u8, 103u8,
+                    84u8, 104u8, 97u8, 97u8, 84u8, 104u8, 97u8, 105u8, 84u8, 105u8, 98u8, 116u8,
+                    85u8, 103u8, 97u8, 114u8, 86u8, 97u8, 105u8, 105u8, 86u8, 105u8, 115u8, 112u8,
+                    88u8, 112u8, 101u8, 111u8, 88u8, 115u8, 117u8, 120u8, 89u8, 105u8, 105u8,
+                    105u8, 90u8, 105u8, 110u8, 104u8, 90u8, 109u8, 116u8, 104u8, 90u8, 115u8,
+                    121u8, 101u8, 90u8, 115u8, 121u8, 109u8, 90u8, 120u8, 120u8, 120u8, 90u8,
+                    121u8, 121u8, 121u8, 90u8, 122u8, 122u8, 122u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    128u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 12u8, 0u8, 20u8, 0u8, 27u8, 0u8,
+                    36u8, 0u8, 44u8, 0u8, 49u8, 0u8, 57u8, 0u8, 71u8, 0u8, 79u8, 0u8, 85u8, 0u8,
+                    92u8, 0u8, 100u8, 0u8, 105u8, 0u8, 111u8, 0u8, 156u8, 0u8, 161u8, 0u8, 165u8,
+                    0u8, 173u8, 0u8, 178u8, 0u8, 183u8, 0u8, 192u8, 0u8, 201u8, 0u8, 243u8, 0u8,
+                    253u8, 0u8, 4u8, 1u8, 21u8, 1u8, 39u8, 1u8, 61u8, 1u8, 70u8, 1u8, 93u8, 1u8,
+                    102u8, 1u8, 114u8, 1u8, 121u8, 1u8, 127u8, 1u8, 136u8, 1u8, 143u8, 1u8, 159u8,
+                    1u8, 165u8, 1u8, 168u8, 1u8, 175u8, 1u8, 187u8, 1u8, 198u8, 1u8, 204u8, 1u8,
+                    212u8, 1u8, 224u8, 1u8, 244u8, 1u8, 4u8, 2u8, 20u8, 2u8, 39u8, 2u8, 43u8, 2u8,
+                    51u8, 2u8, 59u8, 2u8, 67u8, 2u8, 75u8, 2u8, 84u8, 2u8, 89u8, 2u8, 97u8, 2u8,
+                    104u8, 2u8, 109u8, 2u8, 117u8, 2u8, 131u8, 2u8, 146u8, 2u8, 152u8, 2u8, 158u8,
+                    2u8, 163u8, 2u8, 171u8, 2u8, 179u8, 2u8, 184u8, 2u8, 189u8, 2u8, 195u8, 2u8,
+                    214u8, 2u8, 224u8, 2u8, 234u8, 2u8, 240u8, 2u8, 244u8, 2u8, 250u8, 2u8, 1u8,
+                    3u8, 7u8, 3u8, 12u8, 3u8, 20u8, 3u8, 26u8, 3u8, 31u8, 3u8, 39u8, 3u8, 55u8,
+                    3u8, 63u8, 3u8, 70u8, 3u8, 82u8, 3u8, 88u8, 3u8, 94u8, 3u8, 100u8, 3u8, 111u8,
+                    3u8, 118u8, 3u8, 124u8, 3u8, 134u8, 3u8, 145u8, 3u8, 153u8, 3u8, 162u8, 3u8,
+                    171u8, 3u8, 183u8, 3u8, 190u8, 3u8, 208u8, 3u8, 226u8, 3u8, 242u8, 3u8, 251u8,
+                    3u8, 1u8, 4u8, 14u8, 4u8, 19u8, 4u8, 25u8, 4u8, 32u8, 4u8, 40u8, 4u8, 46u8,
+                    4u8, 52u8, 4u8, 62u8, 4u8, 70u8, 4u8, 80u8, 4u8, 83u8, 4u8, 99u8, 4u8, 112u8,
+                    4u8, 137u8, 4u8, 139u8, 4u8, 147u8, 4u8, 168u8, 4u8, 174u8, 4u8, 183u8, 4u8,
+                    193u8, 4u8, 199u8, 4u8, 195u8, 161u8, 100u8, 108u8, 97u8, 109u8, 195u8, 161u8,
+                    114u8, 97u8, 98u8, 101u8, 110u8, 97u8, 115u8, 116u8, 97u8, 108u8, 105u8, 113u8,
+                    97u8, 114u8, 109u8, 101u8, 110u8, 105u8, 111u8, 97u8, 118u8, 195u8, 169u8,
+                    115u8, 116u8, 105u8, 99u8, 111u8, 98u8, 97u8, 108u8, 105u8, 110u8, 195u8,
+                    169u8, 115u8, 98u8, 97u8, 116u8, 97u8, 107u8, 98u8, 101u8, 110u8, 103u8, 97u8,
+                    108u8, 195u8, 173u8, 115u8, 195u8, 173u8, 109u8, 98u8, 111u8, 108u8, 111u8,
+                    115u8, 32u8, 98u8, 108u8, 105u8, 115u8, 98u8, 111u8, 112u8, 111u8, 109u8,
+                    111u8, 102u8, 111u8, 98u8, 114u8, 97u8, 104u8, 109u8, 105u8, 98u8, 114u8, 97u8,
+                    105u8, 108u8, 108u8, 101u8, 98u8, 117u8, 103u8, 105u8, 110u8, 195u8, 169u8,
+                    115u8, 98u8, 117u8, 104u8, 105u8, 100u8, 99u8, 104u8, 97u8, 107u8, 109u8, 97u8,
+                    115u8, 105u8, 108u8, 97u8, 98u8, 97u8, 114u8, 105u8, 111u8, 115u8, 32u8, 97u8,
+                    98u8, 111u8, 114u8, 195u8, 173u8, 103u8, 101u8, 110u8, 101u8, 115u8, 32u8,
+                    99u8, 97u8, 110u8, 97u8, 100u8, 105u8, 101u8, 110u8, 115u8, 101u8, 115u8, 32u8,
+                    117u8, 110u8, 105u8, 102u8, 105u8, 99u8, 97u8, 100u8, 111u8, 115u8, 99u8, 97u8,
+                    114u8, 105u8, 111u8, 99u8, 104u8, 97u8, 109u8, 99u8, 104u8, 101u8, 114u8,
+                    111u8, 113u8, 117u8, 105u8, 99u8, 105u8, 114u8, 116u8, 104u8, 99u8, 111u8,
+                    112u8, 116u8, 111u8, 99u8, 104u8, 105u8, 112u8, 114u8, 105u8, 111u8, 116u8,
+                    97u8, 99u8, 105u8, 114u8, 195u8, 173u8, 108u8, 105u8, 99u8, 111u8, 99u8, 105u8,
+                    114u8, 195u8, 173u8, 108u8, 105u8, 99u8, 111u8, 32u8, 100u8, 101u8, 108u8,
+                    32u8, 97u8, 110u8, 116u8, 105u8, 103u8, 117u8, 111u8, 32u8, 101u8, 115u8,
+                    108u8, 97u8, 118u8, 111u8, 32u8, 101u8, 99u8, 108u8, 101u8, 115u8, 105u8,
+                    195u8, 161u8, 115u8, 116u8, 105u8, 99u8, 111u8, 100u8, 101u8, 118u8, 97u8,
+                    110u8, 97u8, 103u8, 97u8, 114u8, 105u8, 100u8, 101u8, 115u8, 101u8, 114u8,
+                    101u8, 116u8, 101u8, 103u8, 105u8, 112u8, 99u8, 105u8, 111u8, 32u8, 100u8,
+                    101u8, 109u8, 195u8, 179u8, 116u8, 105u8, 99u8, 111u8, 101u8, 103u8, 105u8,
+                    112u8, 99u8, 105u8, 111u8, 32u8, 104u8, 105u8, 101u8, 114u8, 195u8, 161u8,
+                    116u8, 105u8, 99u8, 111u8, 106u8, 101u8, 114u8, 111u8, 103u8, 108u8, 195u8,
+                    173u8, 102u8, 105u8, 99u8, 111u8, 115u8, 32u8, 101u8, 103u8, 105u8, 112u8,
+                    99u8, 105u8, 111u8, 115u8, 101u8, 116u8, 105u8, 195u8, 179u8, 112u8, 105u8,
+                    99u8, 111u8, 103u8, 101u8, 111u8, 114u8, 103u8, 105u8, 97u8, 110u8, 111u8,
+                    32u8, 101u8, 99u8, 108u8, 101u8, 115u8, 105u8, 195u8, 161u8, 115u8, 116u8,
+                    105u8, 99u8, 111u8, 103u8, 101u8, 111u8, 114u8, 103u8, 105u8, 97u8, 110u8,
+                    111u8, 103u8, 108u8, 97u8, 103u8, 111u8, 108u8, 195u8, 173u8, 116u8, 105u8,
+                    99u8, 111u8, 103u8, 195u8, 179u8, 116u8, 105u8, 99u8, 111u8, 103u8, 114u8,
+                    105u8, 101u8, 103u8, 111u8, 103u8, 117u8, 121u8, 97u8, 114u8, 97u8, 116u8,
+                    195u8, 173u8, 103u8, 117u8, 114u8, 109u8, 117u8, 106u8, 105u8, 104u8, 97u8,
+                    110u8, 32u8, 99u8, 111u8, 110u8, 32u8, 98u8, 111u8, 112u8, 111u8, 109u8, 111u8,
+                    102u8, 111u8, 104u8, 97u8, 110u8, 103u8, 117u8, 108u8, 104u8, 97u8, 110u8,
+                    104u8, 97u8, 110u8, 117u8, 110u8, 111u8, 111u8, 115u8, 105u8, 109u8, 112u8,
+                    108u8, 105u8, 102u8, 105u8, 99u8, 97u8, 100u8, 111u8, 116u8, 114u8, 97u8,
+                    100u8, 105u8, 99u8, 105u8, 111u8, 110u8, 97u8, 108u8, 104u8, 101u8, 98u8,
+                    114u8, 101u8, 111u8, 104u8, 105u8, 114u8, 97u8, 103u8, 97u8, 110u8, 97u8,
+                    112u8, 97u8, 104u8, 97u8, 119u8, 104u8, 32u8, 104u8, 109u8, 111u8, 110u8,
+                    103u8, 115u8, 105u8, 108u8, 97u8, 98u8, 97u8, 114u8, 105u8, 111u8, 115u8, 32u8,
+                    106u8, 97u8, 112u8, 111u8, 110u8, 101u8, 115u8, 101u8, 115u8, 104u8, 195u8,
+                    186u8, 110u8, 103u8, 97u8, 114u8, 111u8, 32u8, 97u8, 110u8, 116u8, 105u8,
+                    103u8, 117u8, 111u8, 73u8, 110u8, 100u8, 105u8, 111u8, 32u8, 40u8, 104u8, 97u8,
+                    114u8, 97u8, 112u8, 112u8, 97u8, 110u8, 41u8, 97u8, 110u8, 116u8, 105u8, 103u8,
+                    117u8, 97u8, 32u8, 98u8, 97u8, 115u8, 116u8, 97u8, 114u8, 100u8, 105u8, 108u8,
+                    108u8, 97u8, 106u8, 97u8, 109u8, 111u8, 106u8, 97u8, 118u8, 97u8, 110u8, 195u8,
+                    169u8, 115u8, 106u8, 97u8, 112u8, 111u8, 110u8, 195u8, 169u8, 115u8, 107u8,
+                    97u8, 121u8, 97u8, 104u8, 32u8, 108u8, 105u8, 107u8, 97u8, 116u8, 97u8, 107u8,
+                    97u8, 110u8, 97u8, 107u8, 104u8, 97u8, 114u8, 111u8, 115u8, 116u8, 104u8,
+                    105u8, 106u8, 101u8, 109u8, 101u8, 114u8, 99u8, 97u8, 110u8, 97u8, 114u8,
+                    195u8, 169u8, 115u8, 99u8, 111u8, 114u8, 101u8, 97u8, 110u8, 111u8, 108u8,
+                    97u8, 110u8, 110u8, 97u8, 108u8, 97u8, 111u8, 115u8, 105u8, 97u8, 110u8, 111u8,
+                    108u8, 97u8, 116u8, 105u8, 110u8, 111u8, 32u8, 102u8, 114u8, 97u8, 107u8,
+                    116u8, 117u8, 114u8, 108u8, 97u8, 116u8, 105u8, 110u8, 111u8, 32u8, 103u8,
+                    97u8, 195u8, 169u8, 108u8, 105u8, 99u8, 111u8, 108u8, 97u8, 116u8, 105u8,
+                    110u8, 111u8, 108u8, 101u8, 112u8, 99u8, 104u8, 97u8, 108u8, 105u8, 109u8,
+                    98u8, 117u8, 108u8, 105u8, 110u8, 101u8, 97u8, 108u8, 32u8, 65u8, 108u8, 105u8,
+                    110u8, 101u8, 97u8, 108u8, 32u8, 66u8, 108u8, 105u8, 99u8, 105u8, 111u8, 108u8,
+                    105u8, 100u8, 105u8, 111u8, 109u8, 97u8, 110u8, 100u8, 101u8, 111u8, 106u8,
+                    101u8, 114u8, 111u8, 103u8, 108u8, 195u8, 173u8, 102u8, 105u8, 99u8, 111u8,
+                    115u8, 32u8, 109u8, 97u8, 121u8, 97u8, 115u8, 109u8, 101u8, 114u8, 111u8,
+                    195u8, 173u8, 116u8, 105u8, 99u8, 111u8, 109u8, 97u8, 108u8, 97u8, 121u8,
+                    195u8, 161u8, 108u8, 97u8, 109u8, 109u8, 111u8, 110u8, 103u8, 111u8, 108u8,
+                    109u8, 111u8, 111u8, 110u8, 109u8, 101u8, 105u8, 116u8, 101u8, 105u8, 98u8,
+                    105u8, 114u8, 109u8, 97u8, 110u8, 111u8, 110u8, 226u8, 128u8, 153u8, 107u8,
+                    111u8, 111u8, 103u8, 104u8, 97u8, 109u8, 111u8, 108u8, 32u8, 99u8, 104u8,
+                    105u8, 107u8, 105u8, 111u8, 114u8, 107u8, 104u8, 111u8, 110u8, 111u8, 114u8,
+                    105u8, 121u8, 97u8, 111u8, 115u8, 109u8, 97u8, 110u8, 105u8, 121u8, 97u8,
+                    112u8, 101u8, 114u8, 109u8, 105u8, 97u8, 110u8, 111u8, 32u8, 97u8, 110u8,
+                    116u8, 105u8, 103u8, 117u8, 111u8, 112u8, 104u8, 97u8, 103u8, 115u8, 45u8,
+                    112u8, 97u8, 102u8, 101u8, 110u8, 105u8, 99u8, 105u8, 111u8, 80u8, 111u8,
+                    108u8, 108u8, 97u8, 114u8, 100u8, 32u8, 77u8, 105u8, 97u8, 111u8, 122u8, 97u8,
+                    119u8, 103u8, 121u8, 105u8, 114u8, 101u8, 106u8, 97u8, 110u8, 103u8, 104u8,
+                    97u8, 110u8, 105u8, 102u8, 105u8, 114u8, 111u8, 110u8, 103u8, 111u8, 45u8,
+                    114u8, 111u8, 110u8, 103u8, 111u8, 114u8, 195u8, 186u8, 110u8, 105u8, 99u8,
+                    111u8, 115u8, 97u8, 114u8, 97u8, 116u8, 105u8, 115u8, 97u8, 117u8, 114u8, 97u8,
+                    115u8, 104u8, 116u8, 114u8, 97u8, 83u8, 105u8, 103u8, 110u8, 87u8, 114u8,
+                    105u8, 116u8, 105u8, 110u8, 103u8, 115u8, 104u8, 97u8, 118u8, 105u8, 97u8,
+                    110u8, 111u8, 99u8, 105u8, 110u8, 103u8, 97u8, 108u8, 195u8, 169u8, 115u8,
+                    115u8, 117u8, 110u8, 100u8, 97u8, 110u8, 195u8, 169u8, 115u8, 115u8, 121u8,
+                    108u8, 111u8, 116u8, 105u8, 32u8, 110u8, 97u8, 103u8, 114u8, 105u8, 115u8,
+                    105u8, 114u8, 105u8, 97u8, 99u8, 111u8, 115u8, 105u8, 114u8, 105u8, 97u8, 99u8,
+                    111u8, 32u8, 101u8, 115u8, 116u8, 114u8, 97u8, 110u8, 103u8, 101u8, 108u8,
+                    111u8, 115u8, 105u8, 114u8, 105u8, 97u8, 99u8, 111u8, 32u8, 111u8, 99u8, 99u8,
+                    105u8, 100u8, 101u8, 110u8, 116u8, 97u8, 108u8, 115u8, 105u8, 114u8, 105u8,
+                    97u8, 99u8, 111u8, 32u8, 111u8, 114u8, 105u8, 101u8, 110u8, 116u8, 97u8, 108u8,
+                    116u8, 97u8, 103u8, 98u8, 97u8, 110u8, 195u8, 186u8, 97u8, 116u8, 97u8, 105u8,
+                    32u8, 108u8, 101u8, 110u8, 117u8, 101u8, 118u8, 111u8, 32u8, 116u8, 97u8,
+                    105u8, 32u8, 108u8, 117u8, 101u8, 116u8, 97u8, 109u8, 105u8, 108u8, 116u8,
+                    101u8, 108u8, 117u8, 103u8, 117u8, 116u8, 101u8, 110u8, 103u8, 119u8, 97u8,
+                    114u8, 116u8, 105u8, 102u8, 105u8, 110u8, 97u8, 103u8, 104u8, 116u8, 97u8,
+                    103u8, 97u8, 108u8, 111u8, 116u8, 104u8, 97u8, 97u8, 110u8, 97u8, 116u8, 97u8,
+                    105u8, 108u8, 97u8, 110u8, 100u8, 195u8, 169u8, 115u8, 116u8, 105u8, 98u8,
+                    101u8, 116u8, 97u8, 110u8, 111u8, 117u8, 103u8, 97u8, 114u8, 195u8, 173u8,
+                    116u8, 105u8, 99u8, 111u8, 118u8, 97u8, 105u8, 108u8, 101u8, 110u8, 103u8,
+                    117u8, 97u8, 106u8, 101u8, 32u8, 118u8, 105u8, 115u8, 105u8, 98u8, 108u8,
+                    101u8, 112u8, 101u8, 114u8, 115u8, 97u8, 32u8, 97u8, 110u8, 116u8, 105u8,
+                    103u8, 117u8, 111u8, 99u8, 117u8, 110u8, 101u8, 105u8, 102u8, 111u8, 114u8,
+                    109u8, 101u8, 32u8, 115u8, 117u8, 109u8, 101u8, 114u8, 105u8, 111u8, 45u8,
+                    97u8, 99u8, 97u8, 100u8, 105u8, 111u8, 121u8, 105u8, 104u8, 101u8, 114u8,
+                    101u8, 100u8, 97u8, 100u8, 111u8, 110u8, 111u8, 116u8, 97u8, 99u8, 105u8,
+                    195u8, 179u8, 110u8, 32u8, 109u8, 97u8, 116u8, 101u8, 109u8, 195u8, 161u8,
+                    116u8, 105u8, 99u8, 97u8, 101u8, 109u8, 111u8, 106u8, 105u8, 115u8, 115u8,
+                    195u8, 173u8, 109u8, 98u8, 111u8, 108u8, 111u8, 115u8, 110u8, 111u8, 32u8,
+                    101u8, 115u8, 99u8, 114u8, 105u8, 116u8, 111u8, 99u8, 111u8, 109u8, 195u8,
+                    186u8, 110u8, 97u8, 108u8, 102u8, 97u8, 98u8, 101u8, 116u8, 111u8, 32u8, 100u8,
+                    101u8, 115u8, 99u8, 111u8, 110u8, 111u8, 99u8, 105u8, 100u8, 111u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+};
+static ES_AR: &DataStruct = &::icu_displaynames::provider::ScriptDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 100u8, 108u8, 109u8, 65u8, 114u8, 97u8, 98u8, 65u8, 114u8, 97u8, 110u8,
+                    65u8, 114u8, 109u8, 110u8, 65u8, 118u8, 115u8, 116u8, 66u8, 97u8, 108u8, 105u8,
+                    66u8, 97u8, 116u8, 107u8, 66u8, 101u8, 110u8, 103u8, 66u8, 108u8, 105u8, 115u8,
+                    66u8, 111u8, 112u8, 111u8, 66u8, 114u8, 97u8, 104u8, 66u8, 114u8, 97u8, 105u8,
+                    66u8, 117u8, 103u8, 105u8, 66u8, 117u8, 104u8, 100u8, 67u8, 97u8, 107u8, 109u8,
+                    67u8, 97u8, 110u8, 115u8, 67u8, 97u8, 114u8, 105u8, 67u8, 104u8, 97u8, 109u8,
+                    67u8, 104u8, 101u8, 114u8, 67u8, 105u8, 114u8, 116u8, 67u8, 111u8, 112u8,
+                    116u8, 67u8, 112u8, 114u8, 116u8, 67u8, 121u8, 114u8, 108u8, 67u8, 121u8,
+                    114u8, 115u8, 68u8, 101u8, 118u8, 97u8, 68u8, 115u8, 114u8, 116u8, 69u8, 103u8,
+                    121u8, 100u8, 69u8, 103u8, 121u8, 104u8, 69u8, 103u8, 121u8, 112u8, 69u8,
+                    116u8, 104u8, 105u8, 71u8, 101u8, 111u8, 107u8, 71u8, 101u8, 111u8, 114u8,
+                    71u8, 108u8, 97u8, 103u8, 71u8, 111u8, 116u8, 104u8, 71u8, 114u8, 101u8, 107u8,
+                    71u8, 117u8, 106u8, 114u8, 71u8, 117u8, 114u8, 117u8, 72u8, 97u8, 110u8, 98u8,
+                    72u8, 97u8, 110u8, 103u8, 72u8, 97u8, 110u8, 105u8, 72u8, 97u8, 110u8, 111u8,
+                    72u8, 97u8, 110u8, 115u8, 72u8, 97u8, 110u8, 116u8, 72u8, 101u8, 98u8, 114u8,
+                    72u8, 105u8, 114u8, 97u8, 72u8, 109u8, 110u8, 103u8, 72u8, 114u8, 107u8, 116u8,
+                    72u8, 117u8, 110u8, 103u8, 73u8, 110u8, 100u8, 115u8, 73u8, 116u8, 97u8, 108u8,
+                    74u8, 97u8, 109u8, 111u8, 74u8, 97u8, 118u8, 97u8, 74u8, 112u8, 97u8, 110u8,
+                    75u8, 97u8, 108u8, 105u8, 75u8, 97u8, 110u8, 97u8, 75u8, 104u8, 97u8, 114u8,
+                    75u8, 104u8, 109u8, 114u8, 75u8, 110u8, 100u8, 97u8, 75u8, 111u8, 114u8, 101u8,
+                    76u8, 97u8, 110u8, 97u8, 76u8, 97u8, 111u8, 111u8, 76u8, 97u8, 116u8, 102u8,
+                    76u8, 97u8, 116u8, 103u8, 76u8, 97u8, 116u8, 110u8, 76u8, 101u8, 112u8, 99u8,
+                    76u8, 105u8, 109u8, 98u8, 76u8, 105u8, 110u8, 97u8, 76u8, 105u8, 110u8, 98u8,
+                    76u8, 121u8, 99u8, 105u8, 76u8, 121u8, 100u8, 105u8, 77u8, 97u8, 110u8, 100u8,
+                    77u8, 97u8, 121u8, 97u8, 77u8, 101u8, 114u8, 111u8, 77u8, 108u8, 121u8, 109u8,
+                    77u8, 111u8, 110u8, 103u8, 77u8, 111u8, 111u8, 110u8, 77u8, 116u8, 101u8,
+                    105u8, 77u8, 121u8, 109u8, 114u8, 78u8, 107u8, 111u8, 111u8, 79u8, 103u8, 97u8,
+                    109u8, 79u8, 108u8, 99u8, 107u8, 79u8, 114u8, 107u8, 104u8, 79u8, 114u8, 121u8,
+                    97u8, 79u8, 115u8, 109u8, 97u8, 80u8, 101u8, 114u8, 109u8, 80u8, 104u8, 97u8,
+                    103u8, 80u8, 104u8, 110u8, 120u8, 80u8, 108u8, 114u8, 100u8, 81u8, 97u8, 97u8,
+                    103u8, 82u8, 106u8, 110u8, 103u8, 82u8, 111u8, 104u8, 103u8, 82u8, 111u8,
+                    114u8, 111u8, 82u8, 117u8, 110u8, 114u8, 83u8, 97u8, 114u8, 97u8, 83u8, 97u8,
+                    117u8, 114u8, 83u8, 103u8, 110u8, 119u8, 83u8, 104u8, 97u8, 119u8, 83u8, 105u8,
+                    110u8, 104u8, 83u8, 117u8, 110u8, 100u8, 83u8, 121u8, 108u8, 111u8, 83u8,
+                    121u8, 114u8, 99u8, 83u8, 121u8, 114u8, 101u8, 83u8, 121u8, 114u8, 106u8, 83u8,
+                    121u8, 114u8, 110u8, 84u8, 97u8, 103u8, 98u8, 84u8, 97u8, 108u8, 101u8, 84u8,
+                    97u8, 108u8, 117u8, 84u8, 97u8, 109u8, 108u8, 84u8, 101u8, 108u8, 117u8, 84u8,
+                    101u8, 110u8, 103u8, 84u8, 102u8, 110u8, 103u8, 84u8, 103u8, 108u8, 103u8,
+                    84u8, 104u8, 97u8, 97u8, 84u8, 104u8, 97u8, 105u8, 84u8, 105u8, 98u8, 116u8,
+                    85u8, 103u8, 97u8, 114u8, 86u8, 97u8, 105u8, 105u8, 86u8, 105u8, 115u8, 112u8,
+                    88u8, 112u8, 101u8, 111u8, 88u8, 115u8, 117u8, 120u8, 89u8, 105u8, 105u8,
+                    105u8, 90u8, 105u8, 110u8, 104u8, 90u8, 109u8, 116u8, 104u8, 90u8, 115u8,
+                    121u8, 101u8, 90u8, 115u8, 121u8, 109u8, 90u8, 120u8, 120u8, 120u8, 90u8,
+                    121u8, 121u8, 121u8, 90u8, 122u8, 122u8, 122u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    128u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 12u8, 0u8, 20u8, 0u8, 27u8, 0u8,
+                    36u8, 0u8, 44u8, 0u8, 49u8, 0u8, 57u8, 0u8, 71u8, 0u8, 79u8, 0u8, 85u8, 0u8,
+                    92u8, 0u8, 100u8, 0u8, 105u8, 0u8, 111u8, 0u8, 156u8, 0u8, 161u8, 0u8, 165u8,
+                    0u8, 173u8, 0u8, 178u8, 0u8, 183u8, 0u8, 192u8, 0u8, 201u8, 0u8, 243u8, 0u8,
+                    253u8, 0u8, 4u8, 1u8, 21u8, 1u8, 39u8, 1u8, 61u8, 1u8, 70u8, 1u8, 93u8, 1u8,
+                    102u8, 1u8, 114u8, 1u8, 121u8, 1u8, 127u8, 1u8, 135u8, 1u8, 142u8, 1u8, 158u8,
+                    1u8, 164u8, 1u8, 167u8, 1u8, 174u8, 1u8, 186u8, 1u8, 197u8, 1u8, 203u8, 1u8,
+                    211u8, 1u8, 223u8, 1u8, 242u8, 1u8, 2u8, 2u8, 18u8, 2u8, 37u8, 2u8, 41u8, 2u8,
+                    49u8, 2u8, 57u8, 2u8, 65u8, 2u8, 73u8, 2u8, 82u8, 2u8, 87u8, 2u8, 95u8, 2u8,
+                    102u8, 2u8, 107u8, 2u8, 110u8, 2u8, 124u8, 2u8, 139u8, 2u8, 145u8, 2u8, 151u8,
+                    2u8, 156u8, 2u8, 164u8, 2u8, 172u8, 2u8, 177u8, 2u8, 182u8, 2u8, 188u8, 2u8,
+                    207u8, 2u8, 217u8, 2u8, 224u8, 2u8, 230u8, 2u8, 234u8, 2u8, 242u8, 2u8, 249u8,
+                    2u8, 255u8, 2u8, 4u8, 3u8, 12u8, 3u8, 18u8, 3u8, 23u8, 3u8, 31u8, 3u8, 47u8,
+                    3u8, 55u8, 3u8, 62u8, 3u8, 74u8, 3u8, 80u8, 3u8, 86u8, 3u8, 92u8, 3u8, 103u8,
+                    3u8, 110u8, 3u8, 116u8, 3u8, 126u8, 3u8, 137u8, 3u8, 145u8, 3u8, 154u8, 3u8,
+                    163u8, 3u8, 175u8, 3u8, 183u8, 3u8, 201u8, 3u8, 219u8, 3u8, 235u8, 3u8, 244u8,
+                    3u8, 250u8, 3u8, 7u8, 4u8, 12u8, 4u8, 18u8, 4u8, 25u8, 4u8, 33u8, 4u8, 39u8,
+                    4u8, 45u8, 4u8, 55u8, 4u8, 63u8, 4u8, 73u8, 4u8, 76u8, 4u8, 92u8, 4u8, 105u8,
+                    4u8, 130u8, 4u8, 132u8, 4u8, 140u8, 4u8, 161u8, 4u8, 167u8, 4u8, 176u8, 4u8,
+                    186u8, 4u8, 192u8, 4u8, 195u8, 161u8, 100u8, 108u8, 97u8, 109u8, 195u8, 161u8,
+                    114u8, 97u8, 98u8, 101u8, 110u8, 97u8, 115u8, 116u8, 97u8, 108u8, 105u8, 113u8,
+                    97u8, 114u8, 109u8, 101u8, 110u8, 105u8, 111u8, 97u8, 118u8, 195u8, 169u8,
+                    115u8, 116u8, 105u8, 99u8, 111u8, 98u8, 97u8, 108u8, 105u8, 110u8, 195u8,
+                    169u8, 115u8, 98u8, 97u8, 116u8, 97u8, 107u8, 98u8, 101u8, 110u8, 103u8, 97u8,
+                    108u8, 195u8, 173u8, 115u8, 195u8, 173u8, 109u8, 98u8, 111u8, 108u8, 111u8,
+                    115u8, 32u8, 98u8, 108u8, 105u8, 115u8, 98u8, 111u8, 112u8, 111u8, 109u8,
+                    111u8, 102u8, 111u8, 98u8, 114u8, 97u8, 104u8, 109u8, 105u8, 98u8, 114u8, 97u8,
+                    105u8, 108u8, 108u8, 101u8, 98u8, 117u8, 103u8, 105u8, 110u8, 195u8, 169u8,
+                    115u8, 98u8, 117u8, 104u8, 105u8, 100u8, 99u8, 104u8, 97u8, 107u8, 109u8, 97u8,
+                    115u8, 105u8, 108u8, 97u8, 98u8, 97u8, 114u8, 105u8, 111u8, 115u8, 32u8, 97u8,
+                    98u8, 111u8, 114u8, 195u8, 173u8, 103u8, 101u8, 110u8, 101u8, 115u8, 32u8,
+                    99u8, 97u8, 110u8, 97u8, 100u8, 105u8, 101u8, 110u8, 115u8, 101u8, 115u8, 32u8,
+                    117u8, 110u8, 105u8, 102u8, 105u8, 99u8, 97u8, 100u8, 111u8, 115u8, 99u8, 97u8,
+                    114u8, 105u8, 111u8, 99u8, 104u8, 97u8, 109u8, 99u8, 104u8, 101u8, 114u8,
+                    111u8, 113u8, 117u8, 105u8, 99u8, 105u8, 114u8, 116u8, 104u8, 99u8, 111u8,
+                    112u8, 116u8, 111u8, 99u8, 104u8, 105u8, 112u8, 114u8, 105u8, 111u8, 116u8,
+                    97u8, 99u8, 105u8, 114u8, 195u8, 173u8, 108u8, 105u8, 99u8, 111u8, 99u8, 105u8,
+                    114u8, 195u8, 173u8, 108u8, 105u8, 99u8, 111u8, 32u8, 100u8, 101u8, 108u8,
+                    32u8, 97u8, 110u8, 116u8, 105u8, 103u8, 117u8, 111u8, 32u8, 101u8, 115u8,
+                    108u8, 97u8, 118u8, 111u8, 32u8, 101u8, 99u8, 108u8, 101u8, 115u8, 105u8,
+                    195u8, 161u8, 115u8, 116u8, 105u8, 99u8, 111u8, 100u8, 101u8, 118u8, 97u8,
+                    110u8, 97u8, 103u8, 97u8, 114u8, 105u8, 100u8, 101u8, 115u8, 101u8, 114u8,
+                    101u8, 116u8, 101u8, 103u8, 105u8, 112u8, 99u8, 105u8, 111u8, 32u8, 100u8,
+                    101u8, 109u8, 195u8, 179u8, 116u8, 105u8, 99u8, 111u8, 101u8, 103u8, 105u8,
+                    112u8, 99u8, 105u8, 111u8, 32u8, 104u8, 105u8, 101u8, 114u8, 195u8, 161u8,
+                    116u8, 105u8, 99u8, 111u8, 106u8, 101u8, 114u8, 111u8, 103u8, 108u8, 195u8,
+                    173u8, 102u8, 105u8, 99u8, 111u8, 115u8, 32u8, 101u8, 103u8, 105u8, 112u8,
+                    99u8, 105u8, 111u8, 115u8, 101u8, 116u8, 105u8, 195u8, 179u8, 112u8, 105u8,
+                    99u8, 111u8, 103u8, 101u8, 111u8, 114u8, 103u8, 105u8, 97u8, 110u8, 111u8,
+                    32u8, 101u8, 99u8, 108u8, 101u8, 115u8, 105u8, 195u8, 161u8, 115u8, 116u8,
+                    105u8, 99u8, 111u8, 103u8, 101u8, 111u8, 114u8, 103u8, 105u8, 97u8, 110u8,
+                    111u8, 103u8, 108u8, 97u8, 103u8, 111u8, 108u8, 195u8, 173u8, 116u8, 105u8,
+                    99u8, 111u8, 103u8, 195u8, 179u8, 116u8, 105u8, 99u8, 111u8, 103u8, 114u8,
+                    105u8, 101u8, 103u8, 111u8, 103u8, 117u8, 106u8, 97u8, 114u8, 97u8, 116u8,
+                    105u8, 103u8, 117u8, 114u8, 109u8, 117u8, 106u8, 105u8, 104u8, 97u8, 110u8,
+                    32u8, 99u8, 111u8, 110u8, 32u8, 98u8, 111u8, 112u8, 111u8, 109u8, 111u8, 102u8,
+                    111u8, 104u8, 97u8, 110u8, 103u8, 117u8, 108u8, 104u8, 97u8, 110u8, 104u8,
+                    97u8, 110u8, 117u8, 110u8, 111u8, 111u8, 115u8, 105u8, 109u8, 112u8, 108u8,
+                    105u8, 102u8, 105u8, 99u8, 97u8, 100u8, 111u8, 116u8, 114u8, 97u8, 100u8,
+                    105u8, 99u8, 105u8, 111u8, 110u8, 97u8, 108u8, 104u8, 101u8, 98u8, 114u8,
+                    101u8, 111u8, 104u8, 105u8, 114u8, 97u8, 103u8, 97u8, 110u8, 97u8, 112u8, 97u8,
+                    104u8, 97u8, 119u8, 104u8, 32u8, 104u8, 109u8, 111u8, 110u8, 103u8, 107u8,
+                    97u8, 116u8, 97u8, 107u8, 97u8, 110u8, 97u8, 32u8, 111u8, 32u8, 104u8, 105u8,
+                    114u8, 97u8, 103u8, 97u8, 110u8, 97u8, 104u8, 195u8, 186u8, 110u8, 103u8, 97u8,
+                    114u8, 111u8, 32u8, 97u8, 110u8, 116u8, 105u8, 103u8, 117u8, 111u8, 73u8,
+                    110u8, 100u8, 105u8, 111u8, 32u8, 40u8, 104u8, 97u8, 114u8, 97u8, 112u8, 112u8,
+                    97u8, 110u8, 41u8, 97u8, 110u8, 116u8, 105u8, 103u8, 117u8, 97u8, 32u8, 98u8,
+                    97u8, 115u8, 116u8, 97u8, 114u8, 100u8, 105u8, 108u8, 108u8, 97u8, 106u8, 97u8,
+                    109u8, 111u8, 106u8, 97u8, 118u8, 97u8, 110u8, 195u8, 169u8, 115u8, 106u8,
+                    97u8, 112u8, 111u8, 110u8, 195u8, 169u8, 115u8, 107u8, 97u8, 121u8, 97u8,
+                    104u8, 32u8, 108u8, 105u8, 107u8, 97u8, 116u8, 97u8, 107u8, 97u8, 110u8, 97u8,
+                    107u8, 104u8, 97u8, 114u8, 111u8, 115u8, 116u8, 104u8, 105u8, 106u8, 101u8,
+                    109u8, 101u8, 114u8, 99u8, 97u8, 110u8, 97u8, 114u8, 195u8, 169u8, 115u8, 99u8,
+                    111u8, 114u8, 101u8, 97u8, 110u8, 111u8, 108u8, 97u8, 110u8, 110u8, 97u8,
+                    108u8, 97u8, 111u8, 108u8, 97u8, 116u8, 105u8, 110u8, 111u8, 32u8, 102u8,
+                    114u8, 97u8, 107u8, 116u8, 117u8, 114u8, 108u8, 97u8, 116u8, 105u8, 110u8,
+                    111u8, 32u8, 103u8, 97u8, 195u8, 169u8, 108u8, 105u8, 99u8, 111u8, 108u8, 97u8,
+                    116u8, 195u8, 173u8, 110u8, 108u8, 101u8, 112u8, 99u8, 104u8, 97u8, 108u8,
+                    105u8, 109u8, 98u8, 117u8, 108u8, 105u8, 110u8, 101u8, 97u8, 108u8, 32u8, 65u8,
+                    108u8, 105u8, 110u8, 101u8, 97u8, 108u8, 32u8, 66u8, 108u8, 105u8, 99u8, 105u8,
+                    111u8, 108u8, 105u8, 100u8, 105u8, 111u8, 109u8, 97u8, 110u8, 100u8, 101u8,
+                    111u8, 106u8, 101u8, 114u8, 111u8, 103u8, 108u8, 195u8, 173u8, 102u8, 105u8,
+                    99u8, 111u8, 115u8, 32u8, 109u8, 97u8, 121u8, 97u8, 115u8, 109u8, 101u8, 114u8,
+                    111u8, 195u8, 173u8, 116u8, 105u8, 99u8, 111u8, 109u8, 97u8, 108u8, 97u8, 98u8,
+                    97u8, 114u8, 109u8, 111u8, 110u8, 103u8, 111u8, 108u8, 109u8, 111u8, 111u8,
+                    110u8, 109u8, 97u8, 110u8, 105u8, 112u8, 117u8, 114u8, 105u8, 98u8, 105u8,
+                    114u8, 109u8, 97u8, 110u8, 111u8, 110u8, 226u8, 128u8, 153u8, 107u8, 111u8,
+                    111u8, 103u8, 104u8, 97u8, 109u8, 111u8, 108u8, 32u8, 99u8, 104u8, 105u8,
+                    107u8, 105u8, 111u8, 114u8, 107u8, 104u8, 111u8, 110u8, 111u8, 114u8, 105u8,
+                    121u8, 97u8, 111u8, 115u8, 109u8, 97u8, 110u8, 105u8, 121u8, 97u8, 112u8,
+                    101u8, 114u8, 109u8, 105u8, 97u8, 110u8, 111u8, 32u8, 97u8, 110u8, 116u8,
+                    105u8, 103u8, 117u8, 111u8, 112u8, 104u8, 97u8, 103u8, 115u8, 45u8, 112u8,
+                    97u8, 102u8, 101u8, 110u8, 105u8, 99u8, 105u8, 111u8, 80u8, 111u8, 108u8,
+                    108u8, 97u8, 114u8, 100u8, 32u8, 77u8, 105u8, 97u8, 111u8, 122u8, 97u8, 119u8,
+                    103u8, 121u8, 105u8, 114u8, 101u8, 106u8, 97u8, 110u8, 103u8, 104u8, 97u8,
+                    110u8, 105u8, 102u8, 105u8, 114u8, 111u8, 110u8, 103u8, 111u8, 45u8, 114u8,
+                    111u8, 110u8, 103u8, 111u8, 114u8, 195u8, 186u8, 110u8, 105u8, 99u8, 111u8,
+                    115u8, 97u8, 114u8, 97u8, 116u8, 105u8, 115u8, 97u8, 117u8, 114u8, 97u8, 115u8,
+                    104u8, 116u8, 114u8, 97u8, 83u8, 105u8, 103u8, 110u8, 87u8, 114u8, 105u8,
+                    116u8, 105u8, 110u8, 103u8, 115u8, 104u8, 97u8, 118u8, 105u8, 97u8, 110u8,
+                    111u8, 99u8, 105u8, 110u8, 103u8, 97u8, 108u8, 195u8, 169u8, 115u8, 115u8,
+                    117u8, 110u8, 100u8, 97u8, 110u8, 195u8, 169u8, 115u8, 115u8, 121u8, 108u8,
+                    111u8, 116u8, 105u8, 32u8, 110u8, 97u8, 103u8, 114u8, 105u8, 115u8, 105u8,
+                    114u8, 195u8, 173u8, 97u8, 99u8, 111u8, 115u8, 105u8, 114u8, 105u8, 97u8, 99u8,
+                    111u8, 32u8, 101u8, 115u8, 116u8, 114u8, 97u8, 110u8, 103u8, 101u8, 108u8,
+                    111u8, 115u8, 105u8, 114u8, 105u8, 97u8, 99u8, 111u8, 32u8, 111u8, 99u8, 99u8,
+                    105u8, 100u8, 101u8, 110u8, 116u8, 97u8, 108u8, 115u8, 105u8, 114u8, 105u8,
+                    97u8, 99u8, 111u8, 32u8, 111u8, 114u8, 105u8, 101u8, 110u8, 116u8, 97u8, 108u8,
+                    116u8, 97u8, 103u8, 98u8, 97u8, 110u8, 195u8, 186u8, 97u8, 116u8, 97u8, 105u8,
+                    32u8, 108u8, 101u8, 110u8, 117u8, 101u8, 118u8, 111u8, 32u8, 116u8, 97u8,
+                    105u8, 32u8, 108u8, 117u8, 101u8, 116u8, 97u8, 109u8, 105u8, 108u8, 116u8,
+                    101u8, 108u8, 117u8, 103u8, 117u8, 116u8, 101u8, 110u8, 103u8, 119u8, 97u8,
+                    114u8, 116u8, 105u8, 102u8, 105u8, 110u8, 97u8, 103u8, 104u8, 116u8, 97u8,
+                    103u8, 97u8, 108u8, 111u8, 116u8, 104u8, 97u8, 97u8, 110u8, 97u8, 116u8, 97u8,
+                    105u8, 108u8, 97u8, 110u8, 100u8, 195u8, 169u8, 115u8, 116u8, 105u8, 98u8,
+                    101u8, 116u8, 97u8, 110u8, 111u8, 117u8, 103u8, 97u8, 114u8, 195u8, 173u8,
+                    116u8, 105u8, 99u8, 111u8, 118u8, 97u8, 105u8, 108u8, 101u8, 110u8, 103u8,
+                    117u8, 97u8, 106u8, 101u8, 32u8, 118u8, 105u8, 115u8, 105u8, 98u8, 108u8,
+                    101u8, 112u8, 101u8, 114u8, 115u8, 97u8, 32u8, 97u8, 110u8, 116u8, 105u8,
+                    103u8, 117u8, 111u8, 99u8, 117u8, 110u8, 101u8, 105u8, 102u8, 111u8, 114u8,
+                    109u8, 101u8, 32u8, 115u8, 117u8, 109u8, 101u8, 114u8, 105u8, 111u8, 45u8,
+                    97u8, 99u8, 97u8, 100u8, 105u8, 111u8, 121u8, 105u8, 104u8, 101u8, 114u8,
+                    101u8, 100u8, 97u8, 100u8, 111u8, 110u8, 111u8, 116u8, 97u8, 99u8, 105u8,
+                    195u8, 179u8, 110u8, 32u8, 109u8, 97u8, 116u8, 101u8, 109u8, 195u8, 161u8,
+                    116u8, 105u8, 99u8, 97u8, 101u8, 109u8, 111u8, 106u8, 105u8, 115u8, 115u8,
+                    195u8, 173u8, 109u8, 98u8, 111u8, 108u8, 111u8, 115u8, 110u8, 111u8, 32u8,
+                    101u8, 115u8, 99u8, 114u8, 105u8, 116u8, 111u8, 99u8, 111u8, 109u8, 195u8,
+                    186u8, 110u8, 97u8, 108u8, 102u8, 97u8, 98u8, 101u8, 116u8, 111u8, 32u8, 100u8,
+                    101u8, 115u8, 99u8, 111u8, 110u8, 111u8, 99u8, 105u8, 100u8, 111u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+};
+static FIL: &DataStruct = &::icu_displaynames::provider::ScriptDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 100u8, 108u8, 109u8, 65u8, 114u8, 97u8, 98u8, 65u8, 114u8, 97u8, 110u8,
+                    65u8, 114u8, 109u8, 110u8, 66u8, 101u8, 110u8, 103u8, 66u8, 111u8, 112u8,
+                    111u8, 66u8, 114u8, 97u8, 105u8, 67u8, 97u8, 107u8, 109u8, 67u8, 97u8, 110u8,
+                    115u8, 67u8, 104u8, 101u8, 114u8, 67u8, 121u8, 114u8, 108u8, 68u8, 101u8,
+                    118u8, 97u8, 69u8, 116u8, 104u8, 105u8, 71u8, 101u8, 111u8, 114u8, 71u8, 114u8,
+                    101u8, 107u8, 71u8, 117u8, 106u8, 114u8, 71u8, 117u8, 114u8, 117u8, 72u8, 97u8,
+                    110u8, 98u8, 72u8, 97u8, 110u8, 103u8, 72u8, 97u8, 110u8, 105u8, 72u8, 97u8,
+                    110u8, 115u8, 72u8, 97u8, 110u8, 116u8, 72u8, 101u8, 98u8, 114u8, 72u8, 105u8,
+                    114u8, 97u8, 72u8, 114u8, 107u8, 116u8, 74u8, 97u8, 109u8, 111u8, 74u8, 112u8,
+                    97u8, 110u8, 75u8, 97u8, 110u8, 97u8, 75u8, 104u8, 109u8, 114u8, 75u8, 110u8,
+                    100u8, 97u8, 75u8, 111u8, 114u8, 101u8, 76u8, 97u8, 111u8, 111u8, 76u8, 97u8,
+                    116u8, 110u8, 77u8, 108u8, 121u8, 109u8, 77u8, 111u8, 110u8, 103u8, 77u8,
+                    116u8, 101u8, 105u8, 77u8, 121u8, 109u8, 114u8, 78u8, 107u8, 111u8, 111u8,
+                    79u8, 108u8, 99u8, 107u8, 79u8, 114u8, 121u8, 97u8, 82u8, 111u8, 104u8, 103u8,
+                    83u8, 105u8, 110u8, 104u8, 83u8, 117u8, 110u8, 100u8, 83u8, 121u8, 114u8, 99u8,
+                    84u8, 97u8, 109u8, 108u8, 84u8, 101u8, 108u8, 117u8, 84u8, 102u8, 110u8, 103u8,
+                    84u8, 104u8, 97u8, 97u8, 84u8, 104u8, 97u8, 105u8, 84u8, 105u8, 98u8, 116u8,
+                    86u8, 97u8, 105u8, 105u8, 89u8, 105u8, 105u8, 105u8, 90u8, 109u8, 116u8, 104u8,
+                    90u8, 115u8, 121u8, 101u8, 90u8, 115u8, 121u8, 109u8, 90u8, 120u8, 120u8,
+                    120u8, 90u8, 121u8, 121u8, 121u8, 90u8, 122u8, 122u8, 122u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    58u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 11u8, 0u8, 19u8, 0u8, 27u8, 0u8, 33u8,
+                    0u8, 41u8, 0u8, 48u8, 0u8, 54u8, 0u8, 91u8, 0u8, 99u8, 0u8, 107u8, 0u8, 117u8,
+                    0u8, 125u8, 0u8, 133u8, 0u8, 138u8, 0u8, 146u8, 0u8, 154u8, 0u8, 173u8, 0u8,
+                    179u8, 0u8, 182u8, 0u8, 192u8, 0u8, 203u8, 0u8, 209u8, 0u8, 217u8, 0u8, 237u8,
+                    0u8, 241u8, 0u8, 249u8, 0u8, 1u8, 1u8, 6u8, 1u8, 13u8, 1u8, 19u8, 1u8, 22u8,
+                    1u8, 27u8, 1u8, 36u8, 1u8, 45u8, 1u8, 57u8, 1u8, 64u8, 1u8, 70u8, 1u8, 78u8,
+                    1u8, 82u8, 1u8, 88u8, 1u8, 95u8, 1u8, 104u8, 1u8, 110u8, 1u8, 115u8, 1u8,
+                    121u8, 1u8, 129u8, 1u8, 135u8, 1u8, 139u8, 1u8, 146u8, 1u8, 149u8, 1u8, 151u8,
+                    1u8, 172u8, 1u8, 177u8, 1u8, 188u8, 1u8, 203u8, 1u8, 212u8, 1u8, 65u8, 100u8,
+                    108u8, 97u8, 109u8, 65u8, 114u8, 97u8, 98u8, 105u8, 99u8, 78u8, 97u8, 115u8,
+                    116u8, 97u8, 108u8, 105u8, 113u8, 65u8, 114u8, 109u8, 101u8, 110u8, 105u8,
+                    97u8, 110u8, 66u8, 97u8, 110u8, 103u8, 108u8, 97u8, 66u8, 111u8, 112u8, 111u8,
+                    109u8, 111u8, 102u8, 111u8, 66u8, 114u8, 97u8, 105u8, 108u8, 108u8, 101u8,
+                    67u8, 104u8, 97u8, 107u8, 109u8, 97u8, 85u8, 110u8, 105u8, 102u8, 105u8, 101u8,
+                    100u8, 32u8, 67u8, 97u8, 110u8, 97u8, 100u8, 105u8, 97u8, 110u8, 32u8, 65u8,
+                    98u8, 111u8, 114u8, 105u8, 103u8, 105u8, 110u8, 97u8, 108u8, 32u8, 83u8, 121u8,
+                    108u8, 108u8, 97u8, 98u8, 105u8, 99u8, 115u8, 67u8, 104u8, 101u8, 114u8, 111u8,
+                    107u8, 101u8, 101u8, 67u8, 121u8, 114u8, 105u8, 108u8, 108u8, 105u8, 99u8,
+                    68u8, 101u8, 118u8, 97u8, 110u8, 97u8, 103u8, 97u8, 114u8, 105u8, 69u8, 116u8,
+                    104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 71u8, 101u8, 111u8, 114u8, 103u8,
+                    105u8, 97u8, 110u8, 71u8, 114u8, 101u8, 101u8, 107u8, 71u8, 117u8, 106u8, 97u8,
+                    114u8, 97u8, 116u8, 105u8, 71u8, 117u8, 114u8, 109u8, 117u8, 107u8, 104u8,
+                    105u8, 72u8, 97u8, 110u8, 32u8, 110u8, 97u8, 32u8, 109u8, 97u8, 121u8, 32u8,
+                    66u8, 111u8, 112u8, 111u8, 109u8, 111u8, 102u8, 111u8, 72u8, 97u8, 110u8,
+                    103u8, 117u8, 108u8, 72u8, 97u8, 110u8, 80u8, 105u8, 110u8, 97u8, 115u8, 105u8,
+                    109u8, 112u8, 108u8, 101u8, 84u8, 114u8, 97u8, 100u8, 105u8, 115u8, 121u8,
+                    111u8, 110u8, 97u8, 108u8, 72u8, 101u8, 98u8, 114u8, 101u8, 119u8, 72u8, 105u8,
+                    114u8, 97u8, 103u8, 97u8, 110u8, 97u8, 74u8, 97u8, 112u8, 97u8, 110u8, 101u8,
+                    115u8, 101u8, 32u8, 115u8, 121u8, 108u8, 108u8, 97u8, 98u8, 97u8, 114u8, 105u8,
+                    101u8, 115u8, 74u8, 97u8, 109u8, 111u8, 74u8, 97u8, 112u8, 97u8, 110u8, 101u8,
+                    115u8, 101u8, 75u8, 97u8, 116u8, 97u8, 107u8, 97u8, 110u8, 97u8, 75u8, 104u8,
+                    109u8, 101u8, 114u8, 75u8, 97u8, 110u8, 110u8, 97u8, 100u8, 97u8, 75u8, 111u8,
+                    114u8, 101u8, 97u8, 110u8, 76u8, 97u8, 111u8, 76u8, 97u8, 116u8, 105u8, 110u8,
+                    77u8, 97u8, 108u8, 97u8, 121u8, 97u8, 108u8, 97u8, 109u8, 77u8, 111u8, 110u8,
+                    103u8, 111u8, 108u8, 105u8, 97u8, 110u8, 77u8, 101u8, 105u8, 116u8, 101u8,
+                    105u8, 32u8, 77u8, 97u8, 121u8, 101u8, 107u8, 77u8, 121u8, 97u8, 110u8, 109u8,
+                    97u8, 114u8, 78u8, 226u8, 128u8, 153u8, 75u8, 111u8, 79u8, 108u8, 32u8, 67u8,
+                    104u8, 105u8, 107u8, 105u8, 79u8, 100u8, 105u8, 97u8, 72u8, 97u8, 110u8, 105u8,
+                    102u8, 105u8, 83u8, 105u8, 110u8, 104u8, 97u8, 108u8, 97u8, 83u8, 117u8, 110u8,
+                    100u8, 97u8, 110u8, 101u8, 115u8, 101u8, 83u8, 121u8, 114u8, 105u8, 97u8, 99u8,
+                    84u8, 97u8, 109u8, 105u8, 108u8, 84u8, 101u8, 108u8, 117u8, 103u8, 117u8, 84u8,
+                    105u8, 102u8, 105u8, 110u8, 97u8, 103u8, 104u8, 84u8, 104u8, 97u8, 97u8, 110u8,
+                    97u8, 84u8, 104u8, 97u8, 105u8, 84u8, 105u8, 98u8, 101u8, 116u8, 97u8, 110u8,
+                    86u8, 97u8, 105u8, 89u8, 105u8, 77u8, 97u8, 116u8, 104u8, 101u8, 109u8, 97u8,
+                    116u8, 105u8, 99u8, 97u8, 108u8, 32u8, 78u8, 111u8, 116u8, 97u8, 116u8, 105u8,
+                    111u8, 110u8, 69u8, 109u8, 111u8, 106u8, 105u8, 77u8, 103u8, 97u8, 32u8, 83u8,
+                    105u8, 109u8, 98u8, 111u8, 108u8, 111u8, 72u8, 105u8, 110u8, 100u8, 105u8,
+                    32u8, 78u8, 97u8, 107u8, 97u8, 115u8, 117u8, 108u8, 97u8, 116u8, 75u8, 97u8,
+                    114u8, 97u8, 110u8, 105u8, 119u8, 97u8, 110u8, 72u8, 105u8, 110u8, 100u8,
+                    105u8, 32u8, 75u8, 105u8, 108u8, 97u8, 108u8, 97u8, 110u8, 103u8, 32u8, 83u8,
+                    99u8, 114u8, 105u8, 112u8, 116u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+};
+static FR: &DataStruct = &::icu_displaynames::provider::ScriptDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 100u8, 108u8, 109u8, 65u8, 114u8, 97u8, 98u8, 65u8, 114u8, 97u8, 110u8,
+                    65u8, 114u8, 109u8, 105u8, 65u8, 114u8, 109u8, 110u8, 65u8, 118u8, 115u8,
+                    116u8, 66u8, 97u8, 108u8, 105u8, 66u8, 97u8, 116u8, 107u8, 66u8, 101u8, 110u8,
+                    103u8, 66u8, 108u8, 105u8, 115u8, 66u8, 111u8, 112u8, 111u8, 66u8, 114u8, 97u8,
+                    104u8, 66u8, 114u8, 97u8, 105u8, 66u8, 117u8, 103u8, 105u8, 66u8, 117u8, 104u8,
+                    100u8, 67u8, 97u8, 107u8, 109u8, 67u8, 97u8, 110u8, 115u8, 67u8, 97u8, 114u8,
+                    105u8, 67u8, 104u8, 97u8, 109u8, 67u8, 104u8, 101u8, 114u8, 67u8, 105u8, 114u8,
+                    116u8, 67u8, 111u8, 112u8, 116u8, 67u8, 112u8, 114u8, 116u8, 67u8, 121u8,
+                    114u8, 108u8, 67u8, 121u8, 114u8, 115u8, 68u8, 101u8, 118u8, 97u8, 68u8, 115u8,
+                    114u8, 116u8, 69u8, 103u8, 121u8, 100u8, 69u8, 103u8, 121u8, 104u8, 69u8,
+                    103u8, 121u8, 112u8, 69u8, 108u8, 121u8, 109u8, 69u8, 116u8, 104u8, 105u8,
+                    71u8, 101u8, 111u8, 107u8, 71u8, 101u8, 111u8, 114u8, 71u8, 108u8, 97u8, 103u8,
+                    71u8, 111u8, 116u8, 104u8, 71u8, 114u8, 101u8, 107u8, 71u8, 117u8, 106u8,
+                    114u8, 71u8, 117u8, 114u8, 117u8, 72u8, 97u8, 110u8, 98u8, 72u8, 97u8, 110u8,
+                    103u8, 72u8, 97u8, 110u8, 105u8, 72u8, 97u8, 110u8, 111u8, 72u8, 97u8, 110u8,
+                    115u8, 72u8, 97u8, 110u8, 116u8, 72u8, 101u8, 98u8, 114u8, 72u8, 105u8, 114u8,
+                    97u8, 72u8, 109u8, 110u8, 103u8, 72u8, 109u8, 110u8, 112u8, 72u8, 114u8, 107u8,
+                    116u8, 72u8, 117u8, 110u8, 103u8, 73u8, 110u8, 100u8, 115u8, 73u8, 116u8, 97u8,
+                    108u8, 74u8, 97u8, 109u8, 111u8, 74u8, 97u8, 118u8, 97u8, 74u8, 112u8, 97u8,
+                    110u8, 75u8, 97u8, 108u8, 105u8, 75u8, 97u8, 110u8, 97u8, 75u8, 104u8, 97u8,
+                    114u8, 75u8, 104u8, 109u8, 114u8, 75u8, 110u8, 100u8, 97u8, 75u8, 111u8, 114u8,
+                    101u8, 75u8, 116u8, 104u8, 105u8, 76u8, 97u8, 110u8, 97u8, 76u8, 97u8, 111u8,
+                    111u8, 76u8, 97u8, 116u8, 102u8, 76u8, 97u8, 116u8, 103u8, 76u8, 97u8, 116u8,
+                    110u8, 76u8, 101u8, 112u8, 99u8, 76u8, 105u8, 109u8, 98u8, 76u8, 105u8, 110u8,
+                    97u8, 76u8, 105u8, 110u8, 98u8, 76u8, 121u8, 99u8, 105u8, 76u8, 121u8, 100u8,
+                    105u8, 77u8, 97u8, 110u8, 100u8, 77u8, 97u8, 110u8, 105u8, 77u8, 97u8, 121u8,
+                    97u8, 77u8, 101u8, 114u8, 111u8, 77u8, 108u8, 121u8, 109u8, 77u8, 111u8, 110u8,
+                    103u8, 77u8, 111u8, 111u8, 110u8, 77u8, 116u8, 101u8, 105u8, 77u8, 121u8,
+                    109u8, 114u8, 78u8, 97u8, 110u8, 100u8, 78u8, 107u8, 111u8, 111u8, 79u8, 103u8,
+                    97u8, 109u8, 79u8, 108u8, 99u8, 107u8, 79u8, 114u8, 107u8, 104u8, 79u8, 114u8,
+                    121u8, 97u8, 79u8, 115u8, 109u8, 97u8, 80u8, 101u8, 114u8, 109u8, 80u8, 104u8,
+                    97u8, 103u8, 80u8, 104u8, 108u8, 105u8, 80u8, 104u8, 108u8, 112u8, 80u8, 104u8,
+                    108u8, 118u8, 80u8, 104u8, 110u8, 120u8, 80u8, 108u8, 114u8, 100u8, 80u8,
+                    114u8, 116u8, 105u8, 81u8, 97u8, 97u8, 103u8, 82u8, 106u8, 110u8, 103u8, 82u8,
+                    111u8, 104u8, 103u8, 82u8, 111u8, 114u8, 111u8, 82u8, 117u8, 110u8, 114u8,
+                    83u8, 97u8, 109u8, 114u8, 83u8, 97u8, 114u8, 97u8, 83u8, 97u8, 117u8, 114u8,
+                    83u8, 103u8, 110u8, 119u8, 83u8, 104u8, 97u8, 119u8, 83u8, 105u8, 110u8, 104u8,
+                    83u8, 117u8, 110u8, 100u8, 83u8, 121u8, 108u8, 111u8, 83u8, 121u8, 114u8, 99u8,
+                    83u8, 121u8, 114u8, 101u8, 83u8, 121u8, 114u8, 106u8, 83u8, 121u8, 114u8,
+                    110u8, 84u8, 97u8, 103u8, 98u8, 84u8, 97u8, 108u8, 101u8, 84u8, 97u8, 108u8,
+                    117u8, 84u8, 97u8, 109u8, 108u8, 84u8, 97u8, 118u8, 116u8, 84u8, 101u8, 108u8,
+                    117u8, 84u8, 101u8, 110u8, 103u8, 84u8, 102u8, 110u8, 103u8, 84u8, 103u8,
+                    108u8, 103u8, 84u8, 104u8, 97u8, 97u8, 84u8, 104u8, 97u8, 105u8, 84u8, 105u8,
+                    98u8, 116u8, 85u8, 103u8, 97u8, 114u8, 86u8, 97u8, 105u8, 105u8, 86u8, 105u8,
+                    115u8, 112u8, 87u8, 99u8, 104u8, 111u8, 88u8, 112u8, 101u8, 111u8, 88u8, 115u8,
+                    117u8, 120u8, 89u8, 105u8, 105u8, 105u8, 90u8, 105u8, 110u8, 104u8, 90u8,
+                    109u8, 116u8, 104u8, 90u8, 115u8, 121u8, 101u8, 90u8, 115u8, 121u8, 109u8,
+                    90u8, 120u8, 120u8, 120u8, 90u8, 121u8, 121u8, 121u8, 90u8, 122u8, 122u8,
+                    122u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    141u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 10u8, 0u8, 18u8, 0u8, 36u8, 0u8,
+                    45u8, 0u8, 54u8, 0u8, 62u8, 0u8, 67u8, 0u8, 74u8, 0u8, 88u8, 0u8, 96u8, 0u8,
+                    104u8, 0u8, 111u8, 0u8, 118u8, 0u8, 125u8, 0u8, 131u8, 0u8, 169u8, 0u8, 175u8,
+                    0u8, 179u8, 0u8, 187u8, 0u8, 192u8, 0u8, 197u8, 0u8, 217u8, 0u8, 227u8, 0u8,
+                    1u8, 1u8, 12u8, 1u8, 21u8, 1u8, 41u8, 1u8, 62u8, 1u8, 86u8, 1u8, 97u8, 1u8,
+                    108u8, 1u8, 128u8, 1u8, 137u8, 1u8, 149u8, 1u8, 156u8, 1u8, 160u8, 1u8, 172u8,
+                    1u8, 183u8, 1u8, 200u8, 1u8, 207u8, 1u8, 218u8, 1u8, 227u8, 1u8, 237u8, 1u8,
+                    249u8, 1u8, 0u8, 2u8, 8u8, 2u8, 20u8, 2u8, 42u8, 2u8, 62u8, 2u8, 77u8, 2u8,
+                    82u8, 2u8, 97u8, 2u8, 101u8, 2u8, 109u8, 2u8, 117u8, 2u8, 125u8, 2u8, 133u8,
+                    2u8, 144u8, 2u8, 149u8, 2u8, 156u8, 2u8, 163u8, 2u8, 170u8, 2u8, 175u8, 2u8,
+                    178u8, 2u8, 202u8, 2u8, 228u8, 2u8, 233u8, 2u8, 239u8, 2u8, 245u8, 2u8, 0u8,
+                    3u8, 11u8, 3u8, 17u8, 3u8, 23u8, 3u8, 31u8, 3u8, 41u8, 3u8, 60u8, 3u8, 72u8,
+                    3u8, 81u8, 3u8, 87u8, 3u8, 91u8, 3u8, 103u8, 3u8, 109u8, 3u8, 120u8, 3u8,
+                    126u8, 3u8, 130u8, 3u8, 138u8, 3u8, 144u8, 3u8, 148u8, 3u8, 156u8, 3u8, 170u8,
+                    3u8, 178u8, 3u8, 202u8, 3u8, 223u8, 3u8, 241u8, 3u8, 251u8, 3u8, 17u8, 4u8,
+                    40u8, 4u8, 46u8, 4u8, 52u8, 4u8, 58u8, 4u8, 68u8, 4u8, 75u8, 4u8, 85u8, 4u8,
+                    91u8, 4u8, 101u8, 4u8, 121u8, 4u8, 128u8, 4u8, 137u8, 4u8, 146u8, 4u8, 161u8,
+                    4u8, 169u8, 4u8, 190u8, 4u8, 209u8, 4u8, 226u8, 4u8, 235u8, 4u8, 242u8, 4u8,
+                    2u8, 5u8, 8u8, 5u8, 18u8, 5u8, 27u8, 5u8, 34u8, 5u8, 42u8, 5u8, 47u8, 5u8,
+                    53u8, 5u8, 58u8, 5u8, 67u8, 5u8, 78u8, 5u8, 82u8, 5u8, 96u8, 5u8, 103u8, 5u8,
+                    129u8, 5u8, 157u8, 5u8, 159u8, 5u8, 167u8, 5u8, 189u8, 5u8, 194u8, 5u8, 202u8,
+                    5u8, 212u8, 5u8, 218u8, 5u8, 97u8, 100u8, 108u8, 97u8, 109u8, 97u8, 114u8,
+                    97u8, 98u8, 101u8, 110u8, 97u8, 115u8, 116u8, 97u8, 108u8, 105u8, 113u8, 97u8,
+                    114u8, 97u8, 109u8, 195u8, 169u8, 101u8, 110u8, 32u8, 105u8, 109u8, 112u8,
+                    195u8, 169u8, 114u8, 105u8, 97u8, 108u8, 97u8, 114u8, 109u8, 195u8, 169u8,
+                    110u8, 105u8, 101u8, 110u8, 97u8, 118u8, 101u8, 115u8, 116u8, 105u8, 113u8,
+                    117u8, 101u8, 98u8, 97u8, 108u8, 105u8, 110u8, 97u8, 105u8, 115u8, 98u8, 97u8,
+                    116u8, 97u8, 107u8, 98u8, 101u8, 110u8, 103u8, 97u8, 108u8, 105u8, 115u8,
+                    121u8, 109u8, 98u8, 111u8, 108u8, 101u8, 115u8, 32u8, 66u8, 108u8, 105u8,
+                    115u8, 115u8, 98u8, 111u8, 112u8, 111u8, 109u8, 111u8, 102u8, 111u8, 98u8,
+                    114u8, 195u8, 162u8, 104u8, 109u8, 195u8, 174u8, 98u8, 114u8, 97u8, 105u8,
+                    108u8, 108u8, 101u8, 98u8, 111u8, 117u8, 103u8, 117u8, 105u8, 115u8, 98u8,
+                    111u8, 117u8, 104u8, 105u8, 100u8, 101u8, 99u8, 104u8, 97u8, 107u8, 109u8,
+                    97u8, 115u8, 121u8, 108u8, 108u8, 97u8, 98u8, 97u8, 105u8, 114u8, 101u8, 32u8,
+                    97u8, 117u8, 116u8, 111u8, 99u8, 104u8, 116u8, 111u8, 110u8, 101u8, 32u8, 99u8,
+                    97u8, 110u8, 97u8, 100u8, 105u8, 101u8, 110u8, 32u8, 117u8, 110u8, 105u8,
+                    102u8, 105u8, 195u8, 169u8, 99u8, 97u8, 114u8, 105u8, 101u8, 110u8, 99u8,
+                    104u8, 97u8, 109u8, 99u8, 104u8, 101u8, 114u8, 111u8, 107u8, 101u8, 101u8,
+                    99u8, 105u8, 114u8, 116u8, 104u8, 99u8, 111u8, 112u8, 116u8, 101u8, 115u8,
+                    121u8, 108u8, 108u8, 97u8, 98u8, 97u8, 105u8, 114u8, 101u8, 32u8, 99u8, 104u8,
+                    121u8, 112u8, 114u8, 105u8, 111u8, 116u8, 101u8, 99u8, 121u8, 114u8, 105u8,
+                    108u8, 108u8, 105u8, 113u8, 117u8, 101u8, 99u8, 121u8, 114u8, 105u8, 108u8,
+                    108u8, 105u8, 113u8, 117u8, 101u8, 32u8, 40u8, 118u8, 97u8, 114u8, 105u8, 97u8,
+                    110u8, 116u8, 101u8, 32u8, 115u8, 108u8, 97u8, 118u8, 111u8, 110u8, 110u8,
+                    101u8, 41u8, 100u8, 195u8, 169u8, 118u8, 97u8, 110u8, 97u8, 103u8, 97u8, 114u8,
+                    105u8, 100u8, 195u8, 169u8, 115u8, 195u8, 169u8, 114u8, 101u8, 116u8, 100u8,
+                    195u8, 169u8, 109u8, 111u8, 116u8, 105u8, 113u8, 117u8, 101u8, 32u8, 195u8,
+                    169u8, 103u8, 121u8, 112u8, 116u8, 105u8, 101u8, 110u8, 104u8, 105u8, 195u8,
+                    169u8, 114u8, 97u8, 116u8, 105u8, 113u8, 117u8, 101u8, 32u8, 195u8, 169u8,
+                    103u8, 121u8, 112u8, 116u8, 105u8, 101u8, 110u8, 104u8, 105u8, 195u8, 169u8,
+                    114u8, 111u8, 103u8, 108u8, 121u8, 112u8, 104u8, 101u8, 115u8, 32u8, 195u8,
+                    169u8, 103u8, 121u8, 112u8, 116u8, 105u8, 101u8, 110u8, 115u8, 195u8, 169u8,
+                    108u8, 121u8, 109u8, 97u8, 195u8, 175u8, 113u8, 117u8, 101u8, 195u8, 169u8,
+                    116u8, 104u8, 105u8, 111u8, 112u8, 105u8, 113u8, 117u8, 101u8, 103u8, 195u8,
+                    169u8, 111u8, 114u8, 103u8, 105u8, 101u8, 110u8, 32u8, 107u8, 104u8, 111u8,
+                    117u8, 116u8, 115u8, 111u8, 117u8, 114u8, 105u8, 103u8, 195u8, 169u8, 111u8,
+                    114u8, 103u8, 105u8, 101u8, 110u8, 103u8, 108u8, 97u8, 103u8, 111u8, 108u8,
+                    105u8, 116u8, 105u8, 113u8, 117u8, 101u8, 103u8, 111u8, 116u8, 105u8, 113u8,
+                    117u8, 101u8, 103u8, 114u8, 101u8, 99u8, 103u8, 111u8, 117u8, 100u8, 106u8,
+                    97u8, 114u8, 195u8, 162u8, 116u8, 195u8, 174u8, 103u8, 111u8, 117u8, 114u8,
+                    109u8, 111u8, 117u8, 107u8, 104u8, 195u8, 174u8, 104u8, 97u8, 110u8, 32u8,
+                    97u8, 118u8, 101u8, 99u8, 32u8, 98u8, 111u8, 112u8, 111u8, 109u8, 111u8, 102u8,
+                    111u8, 104u8, 97u8, 110u8, 103u8, 195u8, 187u8, 108u8, 115u8, 105u8, 110u8,
+                    111u8, 103u8, 114u8, 97u8, 109u8, 109u8, 101u8, 115u8, 104u8, 97u8, 110u8,
+                    111u8, 117u8, 110u8, 195u8, 179u8, 111u8, 115u8, 105u8, 109u8, 112u8, 108u8,
+                    105u8, 102u8, 105u8, 195u8, 169u8, 116u8, 114u8, 97u8, 100u8, 105u8, 116u8,
+                    105u8, 111u8, 110u8, 110u8, 101u8, 108u8, 104u8, 195u8, 169u8, 98u8, 114u8,
+                    101u8, 117u8, 104u8, 105u8, 114u8, 97u8, 103u8, 97u8, 110u8, 97u8, 112u8, 97u8,
+                    104u8, 97u8, 119u8, 104u8, 32u8, 104u8, 109u8, 111u8, 110u8, 103u8, 110u8,
+                    121u8, 105u8, 97u8, 107u8, 101u8, 110u8, 103u8, 32u8, 112u8, 117u8, 97u8, 99u8,
+                    104u8, 117u8, 101u8, 32u8, 104u8, 109u8, 111u8, 110u8, 103u8, 107u8, 97u8,
+                    116u8, 97u8, 107u8, 97u8, 110u8, 97u8, 32u8, 111u8, 117u8, 32u8, 104u8, 105u8,
+                    114u8, 97u8, 103u8, 97u8, 110u8, 97u8, 97u8, 110u8, 99u8, 105u8, 101u8, 110u8,
+                    32u8, 104u8, 111u8, 110u8, 103u8, 114u8, 111u8, 105u8, 115u8, 105u8, 110u8,
+                    100u8, 117u8, 115u8, 97u8, 110u8, 99u8, 105u8, 101u8, 110u8, 32u8, 105u8,
+                    116u8, 97u8, 108u8, 105u8, 113u8, 117u8, 101u8, 106u8, 97u8, 109u8, 111u8,
+                    106u8, 97u8, 118u8, 97u8, 110u8, 97u8, 105u8, 115u8, 106u8, 97u8, 112u8, 111u8,
+                    110u8, 97u8, 105u8, 115u8, 107u8, 97u8, 121u8, 97u8, 104u8, 32u8, 108u8, 105u8,
+                    107u8, 97u8, 116u8, 97u8, 107u8, 97u8, 110u8, 97u8, 107u8, 104u8, 97u8, 114u8,
+                    111u8, 99u8, 104u8, 116u8, 104u8, 195u8, 174u8, 107u8, 104u8, 109u8, 101u8,
+                    114u8, 107u8, 97u8, 110u8, 110u8, 97u8, 114u8, 97u8, 99u8, 111u8, 114u8, 195u8,
+                    169u8, 101u8, 110u8, 107u8, 97u8, 105u8, 116u8, 104u8, 195u8, 174u8, 108u8,
+                    97u8, 110u8, 110u8, 97u8, 108u8, 97u8, 111u8, 108u8, 97u8, 116u8, 105u8, 110u8,
+                    32u8, 40u8, 118u8, 97u8, 114u8, 105u8, 97u8, 110u8, 116u8, 101u8, 32u8, 98u8,
+                    114u8, 105u8, 115u8, 195u8, 169u8, 101u8, 41u8, 108u8, 97u8, 116u8, 105u8,
+                    110u8, 32u8, 40u8, 118u8, 97u8, 114u8, 105u8, 97u8, 110u8, 116u8, 101u8, 32u8,
+                    103u8, 97u8, 195u8, 169u8, 108u8, 105u8, 113u8, 117u8, 101u8, 41u8, 108u8,
+                    97u8, 116u8, 105u8, 110u8, 108u8, 101u8, 112u8, 99u8, 104u8, 97u8, 108u8,
+                    105u8, 109u8, 98u8, 111u8, 117u8, 108u8, 105u8, 110u8, 195u8, 169u8, 97u8,
+                    105u8, 114u8, 101u8, 32u8, 65u8, 108u8, 105u8, 110u8, 195u8, 169u8, 97u8,
+                    105u8, 114u8, 101u8, 32u8, 66u8, 108u8, 121u8, 99u8, 105u8, 101u8, 110u8,
+                    108u8, 121u8, 100u8, 105u8, 101u8, 110u8, 109u8, 97u8, 110u8, 100u8, 195u8,
+                    169u8, 101u8, 110u8, 109u8, 97u8, 110u8, 105u8, 99u8, 104u8, 195u8, 169u8,
+                    101u8, 110u8, 104u8, 105u8, 195u8, 169u8, 114u8, 111u8, 103u8, 108u8, 121u8,
+                    112u8, 104u8, 101u8, 115u8, 32u8, 109u8, 97u8, 121u8, 97u8, 115u8, 109u8,
+                    195u8, 169u8, 114u8, 111u8, 195u8, 175u8, 116u8, 105u8, 113u8, 117u8, 101u8,
+                    109u8, 97u8, 108u8, 97u8, 121u8, 97u8, 108u8, 97u8, 109u8, 109u8, 111u8, 110u8,
+                    103u8, 111u8, 108u8, 109u8, 111u8, 111u8, 110u8, 109u8, 101u8, 105u8, 116u8,
+                    101u8, 105u8, 32u8, 109u8, 97u8, 121u8, 101u8, 107u8, 98u8, 105u8, 114u8,
+                    109u8, 97u8, 110u8, 110u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8, 103u8, 97u8,
+                    114u8, 105u8, 110u8, 226u8, 128u8, 153u8, 107u8, 111u8, 111u8, 103u8, 97u8,
+                    109u8, 111u8, 108u8, 45u8, 99u8, 104u8, 105u8, 107u8, 105u8, 111u8, 114u8,
+                    107u8, 104u8, 111u8, 110u8, 111u8, 100u8, 105u8, 97u8, 111u8, 115u8, 109u8,
+                    97u8, 110u8, 97u8, 105u8, 115u8, 97u8, 110u8, 99u8, 105u8, 101u8, 110u8, 32u8,
+                    112u8, 101u8, 114u8, 109u8, 105u8, 101u8, 110u8, 112u8, 104u8, 97u8, 103u8,
+                    115u8, 32u8, 112u8, 97u8, 112u8, 101u8, 104u8, 108u8, 101u8, 118u8, 105u8,
+                    32u8, 100u8, 101u8, 115u8, 32u8, 105u8, 110u8, 115u8, 99u8, 114u8, 105u8,
+                    112u8, 116u8, 105u8, 111u8, 110u8, 115u8, 112u8, 101u8, 104u8, 108u8, 101u8,
+                    118u8, 105u8, 32u8, 100u8, 101u8, 115u8, 32u8, 112u8, 115u8, 97u8, 117u8,
+                    116u8, 105u8, 101u8, 114u8, 115u8, 112u8, 101u8, 104u8, 108u8, 101u8, 118u8,
+                    105u8, 32u8, 100u8, 101u8, 115u8, 32u8, 108u8, 105u8, 118u8, 114u8, 101u8,
+                    115u8, 112u8, 104u8, 195u8, 169u8, 110u8, 105u8, 99u8, 105u8, 101u8, 110u8,
+                    112u8, 104u8, 111u8, 110u8, 195u8, 169u8, 116u8, 105u8, 113u8, 117u8, 101u8,
+                    32u8, 100u8, 101u8, 32u8, 80u8, 111u8, 108u8, 108u8, 97u8, 114u8, 100u8, 112u8,
+                    97u8, 114u8, 116u8, 104u8, 101u8, 32u8, 100u8, 101u8, 115u8, 32u8, 105u8,
+                    110u8, 115u8, 99u8, 114u8, 105u8, 112u8, 116u8, 105u8, 111u8, 110u8, 115u8,
+                    122u8, 97u8, 119u8, 103u8, 121u8, 105u8, 114u8, 101u8, 106u8, 97u8, 110u8,
+                    103u8, 104u8, 97u8, 110u8, 105u8, 102u8, 105u8, 114u8, 111u8, 110u8, 103u8,
+                    111u8, 114u8, 111u8, 110u8, 103u8, 111u8, 114u8, 117u8, 110u8, 105u8, 113u8,
+                    117u8, 101u8, 115u8, 97u8, 109u8, 97u8, 114u8, 105u8, 116u8, 97u8, 105u8,
+                    110u8, 115u8, 97u8, 114u8, 97u8, 116u8, 105u8, 115u8, 97u8, 117u8, 114u8, 97u8,
+                    115u8, 104u8, 116u8, 114u8, 97u8, 195u8, 169u8, 99u8, 114u8, 105u8, 116u8,
+                    117u8, 114u8, 101u8, 32u8, 100u8, 101u8, 115u8, 32u8, 115u8, 105u8, 103u8,
+                    110u8, 101u8, 115u8, 115u8, 104u8, 97u8, 118u8, 105u8, 101u8, 110u8, 99u8,
+                    105u8, 110u8, 103u8, 97u8, 108u8, 97u8, 105u8, 115u8, 115u8, 117u8, 110u8,
+                    100u8, 97u8, 110u8, 97u8, 105u8, 115u8, 115u8, 121u8, 108u8, 111u8, 116u8,
+                    195u8, 174u8, 32u8, 110u8, 195u8, 162u8, 103u8, 114u8, 195u8, 174u8, 115u8,
+                    121u8, 114u8, 105u8, 97u8, 113u8, 117u8, 101u8, 115u8, 121u8, 114u8, 105u8,
+                    97u8, 113u8, 117u8, 101u8, 32u8, 101u8, 115u8, 116u8, 114u8, 97u8, 110u8,
+                    103u8, 104u8, 195u8, 169u8, 108u8, 111u8, 115u8, 121u8, 114u8, 105u8, 97u8,
+                    113u8, 117u8, 101u8, 32u8, 111u8, 99u8, 99u8, 105u8, 100u8, 101u8, 110u8,
+                    116u8, 97u8, 108u8, 115u8, 121u8, 114u8, 105u8, 97u8, 113u8, 117u8, 101u8,
+                    32u8, 111u8, 114u8, 105u8, 101u8, 110u8, 116u8, 97u8, 108u8, 116u8, 97u8,
+                    103u8, 98u8, 97u8, 110u8, 111u8, 117u8, 97u8, 116u8, 97u8, 195u8, 175u8, 45u8,
+                    108u8, 101u8, 110u8, 111u8, 117u8, 118u8, 101u8, 97u8, 117u8, 32u8, 116u8,
+                    97u8, 195u8, 175u8, 45u8, 108u8, 117u8, 101u8, 116u8, 97u8, 109u8, 111u8,
+                    117u8, 108u8, 116u8, 97u8, 195u8, 175u8, 32u8, 118u8, 105u8, 195u8, 170u8,
+                    116u8, 116u8, 195u8, 169u8, 108u8, 111u8, 117u8, 103u8, 111u8, 117u8, 116u8,
+                    101u8, 110u8, 103u8, 119u8, 97u8, 114u8, 116u8, 105u8, 102u8, 105u8, 110u8,
+                    97u8, 103u8, 104u8, 116u8, 97u8, 103u8, 97u8, 108u8, 116u8, 104u8, 195u8,
+                    162u8, 110u8, 97u8, 116u8, 104u8, 97u8, 195u8, 175u8, 116u8, 105u8, 98u8,
+                    195u8, 169u8, 116u8, 97u8, 105u8, 110u8, 111u8, 117u8, 103u8, 97u8, 114u8,
+                    105u8, 116u8, 105u8, 113u8, 117u8, 101u8, 118u8, 97u8, 195u8, 175u8, 112u8,
+                    97u8, 114u8, 111u8, 108u8, 101u8, 32u8, 118u8, 105u8, 115u8, 105u8, 98u8,
+                    108u8, 101u8, 119u8, 97u8, 110u8, 116u8, 99u8, 104u8, 111u8, 99u8, 117u8,
+                    110u8, 195u8, 169u8, 105u8, 102u8, 111u8, 114u8, 109u8, 101u8, 32u8, 112u8,
+                    101u8, 114u8, 115u8, 195u8, 169u8, 112u8, 111u8, 108u8, 105u8, 116u8, 97u8,
+                    105u8, 110u8, 99u8, 117u8, 110u8, 195u8, 169u8, 105u8, 102u8, 111u8, 114u8,
+                    109u8, 101u8, 32u8, 115u8, 117u8, 109u8, 195u8, 169u8, 114u8, 111u8, 45u8,
+                    97u8, 107u8, 107u8, 97u8, 100u8, 105u8, 101u8, 110u8, 121u8, 105u8, 104u8,
+                    195u8, 169u8, 114u8, 105u8, 116u8, 195u8, 169u8, 110u8, 111u8, 116u8, 97u8,
+                    116u8, 105u8, 111u8, 110u8, 32u8, 109u8, 97u8, 116u8, 104u8, 195u8, 169u8,
+                    109u8, 97u8, 116u8, 105u8, 113u8, 117u8, 101u8, 101u8, 109u8, 111u8, 106u8,
+                    105u8, 115u8, 121u8, 109u8, 98u8, 111u8, 108u8, 101u8, 115u8, 110u8, 111u8,
+                    110u8, 32u8, 195u8, 169u8, 99u8, 114u8, 105u8, 116u8, 99u8, 111u8, 109u8,
+                    109u8, 117u8, 110u8, 195u8, 169u8, 99u8, 114u8, 105u8, 116u8, 117u8, 114u8,
+                    101u8, 32u8, 105u8, 110u8, 99u8, 111u8, 110u8, 110u8, 117u8, 101u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+};
+static JA: &DataStruct = &::icu_displaynames::provider::ScriptDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 100u8, 108u8, 109u8, 65u8, 102u8, 97u8, 107u8, 65u8, 103u8, 104u8, 98u8,
+                    65u8, 114u8, 97u8, 98u8, 65u8, 114u8, 97u8, 110u8, 65u8, 114u8, 109u8, 105u8,
+                    65u8, 114u8, 109u8, 110u8, 65u8, 118u8, 115u8, 116u8, 66u8, 97u8, 108u8, 105u8,
+                    66u8, 97u8, 109u8, 117u8, 66u8, 97u8, 115u8, 115u8, 66u8, 97u8, 116u8, 107u8,
+                    66u8, 101u8, 110u8, 103u8, 66u8, 108u8, 105u8, 115u8, 66u8, 111u8, 112u8,
+                    111u8, 66u8, 114u8, 97u8, 104u8, 66u8, 114u8, 97u8, 105u8, 66u8, 117u8, 103u8,
+                    105u8, 66u8, 117u8, 104u8, 100u8, 67u8, 97u8, 107u8, 109u8, 67u8, 97u8, 110u8,
+                    115u8, 67u8, 97u8, 114u8, 105u8, 67u8, 104u8, 97u8, 109u8, 67u8, 104u8, 101u8,
+                    114u8, 67u8, 105u8, 114u8, 116u8, 67u8, 111u8, 112u8, 116u8, 67u8, 112u8,
+                    114u8, 116u8, 67u8, 121u8, 114u8, 108u8, 67u8, 121u8, 114u8, 115u8, 68u8,
+                    101u8, 118u8, 97u8, 68u8, 115u8, 114u8, 116u8, 68u8, 117u8, 112u8, 108u8, 69u8,
+                    103u8, 121u8, 100u8, 69u8, 103u8, 121u8, 104u8, 69u8, 103u8, 121u8, 112u8,
+                    69u8, 108u8, 98u8, 97u8, 69u8, 116u8, 104u8, 105u8, 71u8, 101u8, 111u8, 107u8,
+                    71u8, 101u8, 111u8, 114u8, 71u8, 108u8, 97u8, 103u8, 71u8, 111u8, 116u8, 104u8,
+                    71u8, 114u8, 97u8, 110u8, 71u8, 114u8, 101u8, 107u8, 71u8, 117u8, 106u8, 114u8,
+                    71u8, 117u8, 114u8, 117u8, 72u8, 97u8, 110u8, 98u8, 72u8, 97u8, 110u8, 103u8,
+                    72u8, 97u8, 110u8, 105u8, 72u8, 97u8, 110u8, 111u8, 72u8, 97u8, 110u8, 115u8,
+                    72u8, 97u8, 110u8, 116u8, 72u8, 101u8, 98u8, 114u8, 72u8, 105u8, 114u8, 97u8,
+                    72u8, 108u8, 117u8, 119u8, 72u8, 109u8, 110u8, 103u8, 72u8, 114u8, 107u8,
+                    116u8, 72u8, 117u8, 110u8, 103u8, 73u8, 110u8, 100u8, 115u8, 73u8, 116u8, 97u8,
+                    108u8, 74u8, 97u8, 109u8, 111u8, 74u8, 97u8, 118u8, 97u8, 74u8, 112u8, 97u8,
+                    110u8, 74u8, 117u8, 114u8, 99u8, 75u8, 97u8, 108u8, 105u8, 75u8, 97u8, 110u8,
+                    97u8, 75u8, 104u8, 97u8, 114u8, 75u8, 104u8, 109u8, 114u8, 75u8, 104u8, 111u8,
+                    106u8, 75u8, 110u8, 100u8, 97u8, 75u8, 111u8, 114u8, 101u8, 75u8, 112u8, 101u8,
+                    108u8, 75u8, 116u8, 104u8, 105u8, 76u8, 97u8, 110u8, 97u8, 76u8, 97u8, 111u8,
+                    111u8, 76u8, 97u8, 116u8, 102u8, 76u8, 97u8, 116u8, 103u8, 76u8, 97u8, 116u8,
+                    110u8, 76u8, 101u8, 112u8, 99u8, 76u8, 105u8, 109u8, 98u8, 76u8, 105u8, 110u8,
+                    97u8, 76u8, 105u8, 110u8, 98u8, 76u8, 105u8, 115u8, 117u8, 76u8, 111u8, 109u8,
+                    97u8, 76u8, 121u8, 99u8, 105u8, 76u8, 121u8, 100u8, 105u8, 77u8, 97u8, 104u8,
+                    106u8, 77u8, 97u8, 110u8, 100u8, 77u8, 97u8, 110u8, 105u8, 77u8, 97u8, 121u8,
+                    97u8, 77u8, 101u8, 110u8, 100u8, 77u8, 101u8, 114u8, 99u8, 77u8, 101u8, 114u8,
+                    111u8, 77u8, 108u8, 121u8, 109u8, 77u8, 111u8, 100u8, 105u8, 77u8, 111u8,
+                    110u8, 103u8, 77u8, 111u8, 111u8, 110u8, 77u8, 114u8, 111u8, 111u8, 77u8,
+                    116u8, 101u8, 105u8, 77u8, 121u8, 109u8, 114u8, 78u8, 97u8, 114u8, 98u8, 78u8,
+                    98u8, 97u8, 116u8, 78u8, 107u8, 103u8, 98u8, 78u8, 107u8, 111u8, 111u8, 78u8,
+                    115u8, 104u8, 117u8, 79u8, 103u8, 97u8, 109u8, 79u8, 108u8, 99u8, 107u8, 79u8,
+                    114u8, 107u8, 104u8, 79u8, 114u8, 121u8, 97u8, 79u8, 115u8, 109u8, 97u8, 80u8,
+                    97u8, 108u8, 109u8, 80u8, 97u8, 117u8, 99u8, 80u8, 101u8, 114u8, 109u8, 80u8,
+                    104u8, 97u8, 103u8, 80u8, 104u8, 108u8, 105u8, 80u8, 104u8, 108u8, 112u8, 80u8,
+                    104u8, 108u8, 118u8, 80u8, 104u8, 110u8, 120u8, 80u8, 108u8, 114u8, 100u8,
+                    80u8, 114u8, 116u8, 105u8, 82u8, 106u8, 110u8, 103u8, 82u8, 111u8, 104u8,
+                    103u8, 82u8, 111u8, 114u8, 111u8, 82u8, 117u8, 110u8, 114u8, 83u8, 97u8, 109u8,
+                    114u8, 83u8, 97u8, 114u8, 97u8, 83u8, 97u8, 114u8, 98u8, 83u8, 97u8, 117u8,
+                    114u8, 83u8, 103u8, 110u8, 119u8, 83u8, 104u8, 97u8, 119u8, 83u8, 104u8, 114u8,
+                    100u8, 83u8, 105u8, 100u8, 100u8, 83u8, 105u8, 110u8, 100u8, 83u8, 105u8,
+                    110u8, 104u8, 83u8, 111u8, 114u8, 97u8, 83u8, 117u8, 110u8, 100u8, 83u8, 121u8,
+                    108u8, 111u8, 83u8, 121u8, 114u8, 99u8, 83u8, 121u8, 114u8, 101u8, 83u8, 121u8,
+                    114u8, 106u8, 83u8, 121u8, 114u8, 110u8, 84u8, 97u8, 103u8, 98u8, 84u8, 97u8,
+                    107u8, 114u8, 84u8, 97u8, 108u8, 101u8, 84u8, 97u8, 108u8, 117u8, 84u8, 97u8,
+                    109u8, 108u8, 84u8, 97u8, 110u8, 103u8, 84u8, 97u8, 118u8, 116u8, 84u8, 101u8,
+                    108u8, 117u8, 84u8, 101u8, 110u8, 103u8, 84u8, 102u8, 110u8, 103u8, 84u8,
+                    103u8, 108u8, 103u8, 84u8, 104u8, 97u8, 97u8, 84u8, 104u8, 97u8, 105u8, 84u8,
+                    105u8, 98u8, 116u8, 84u8, 105u8, 114u8, 104u8, 85u8, 103u8, 97u8, 114u8, 86u8,
+                    97u8, 105u8, 105u8, 86u8, 105u8, 115u8, 112u8, 87u8, 97u8, 114u8, 97u8, 87u8,
+                    111u8, 108u8, 101u8, 88u8, 112u8, 101u8, 111u8, 88u8, 115u8, 117u8, 120u8,
+                    89u8, 105u8, 105u8, 105u8, 90u8, 105u8, 110u8, 104u8, 90u8, 109u8, 116u8,
+                    104u8, 90u8, 115u8, 121u8, 101u8, 90u8, 115u8, 121u8, 109u8, 90u8, 120u8,
+                    120u8, 120u8, 90u8, 121u8, 121u8, 121u8, 90u8, 122u8, 122u8, 122u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    170u8, 0u8, 0u8, 0u8, 0u8, 0u8, 18u8, 0u8, 36u8, 0u8, 72u8, 0u8, 90u8, 0u8,
+                    114u8, 0u8, 135u8, 0u8, 156u8, 0u8, 180u8, 0u8, 192u8, 0u8, 207u8, 0u8, 219u8,
+                    0u8, 234u8, 0u8, 252u8, 0u8, 17u8, 1u8, 29u8, 1u8, 53u8, 1u8, 71u8, 1u8, 86u8,
+                    1u8, 104u8, 1u8, 122u8, 1u8, 158u8, 1u8, 173u8, 1u8, 188u8, 1u8, 209u8, 1u8,
+                    224u8, 1u8, 239u8, 1u8, 1u8, 2u8, 16u8, 2u8, 55u8, 2u8, 85u8, 2u8, 106u8, 2u8,
+                    133u8, 2u8, 157u8, 2u8, 181u8, 2u8, 205u8, 2u8, 226u8, 2u8, 247u8, 2u8, 23u8,
+                    3u8, 44u8, 3u8, 62u8, 3u8, 77u8, 3u8, 95u8, 3u8, 113u8, 3u8, 137u8, 3u8, 158u8,
+                    3u8, 176u8, 3u8, 188u8, 3u8, 194u8, 3u8, 212u8, 3u8, 221u8, 3u8, 230u8, 3u8,
+                    248u8, 3u8, 4u8, 4u8, 31u8, 4u8, 58u8, 4u8, 64u8, 4u8, 91u8, 4u8, 109u8, 4u8,
+                    130u8, 4u8, 136u8, 4u8, 151u8, 4u8, 169u8, 4u8, 181u8, 4u8, 196u8, 4u8, 208u8,
+                    4u8, 238u8, 4u8, 0u8, 5u8, 15u8, 5u8, 33u8, 5u8, 51u8, 5u8, 66u8, 5u8, 84u8,
+                    5u8, 105u8, 5u8, 117u8, 5u8, 149u8, 5u8, 182u8, 5u8, 197u8, 5u8, 215u8, 5u8,
+                    230u8, 5u8, 240u8, 5u8, 250u8, 5u8, 15u8, 6u8, 27u8, 6u8, 42u8, 6u8, 60u8, 6u8,
+                    87u8, 6u8, 102u8, 6u8, 114u8, 6u8, 132u8, 6u8, 147u8, 6u8, 171u8, 6u8, 186u8,
+                    6u8, 210u8, 6u8, 231u8, 6u8, 249u8, 6u8, 8u8, 7u8, 20u8, 7u8, 38u8, 7u8, 59u8,
+                    7u8, 86u8, 7u8, 104u8, 7u8, 125u8, 7u8, 137u8, 7u8, 143u8, 7u8, 158u8, 7u8,
+                    176u8, 7u8, 194u8, 7u8, 212u8, 7u8, 233u8, 7u8, 251u8, 7u8, 25u8, 8u8, 43u8,
+                    8u8, 58u8, 8u8, 88u8, 8u8, 121u8, 8u8, 154u8, 8u8, 175u8, 8u8, 199u8, 8u8,
+                    226u8, 8u8, 244u8, 8u8, 9u8, 9u8, 33u8, 9u8, 48u8, 9u8, 66u8, 9u8, 84u8, 9u8,
+                    111u8, 9u8, 141u8, 9u8, 153u8, 9u8, 168u8, 9u8, 192u8, 9u8, 198u8, 9u8, 219u8,
+                    9u8, 237u8, 9u8, 17u8, 10u8, 32u8, 10u8, 62u8, 10u8, 77u8, 10u8, 121u8, 10u8,
+                    159u8, 10u8, 197u8, 10u8, 218u8, 10u8, 239u8, 10u8, 1u8, 11u8, 25u8, 11u8,
+                    40u8, 11u8, 52u8, 11u8, 76u8, 11u8, 91u8, 11u8, 115u8, 11u8, 136u8, 11u8,
+                    154u8, 11u8, 169u8, 11u8, 181u8, 11u8, 199u8, 11u8, 223u8, 11u8, 244u8, 11u8,
+                    3u8, 12u8, 12u8, 12u8, 42u8, 12u8, 63u8, 12u8, 87u8, 12u8, 132u8, 12u8, 141u8,
+                    12u8, 189u8, 12u8, 201u8, 12u8, 210u8, 12u8, 222u8, 12u8, 231u8, 12u8, 243u8,
+                    12u8, 227u8, 130u8, 162u8, 227u8, 131u8, 137u8, 227u8, 131u8, 169u8, 227u8,
+                    131u8, 160u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 130u8, 162u8,
+                    227u8, 131u8, 149u8, 227u8, 130u8, 161u8, 227u8, 130u8, 171u8, 230u8, 150u8,
+                    135u8, 229u8, 173u8, 151u8, 227u8, 130u8, 171u8, 227u8, 131u8, 149u8, 227u8,
+                    130u8, 171u8, 227u8, 130u8, 185u8, 227u8, 131u8, 187u8, 227u8, 130u8, 162u8,
+                    227u8, 131u8, 171u8, 227u8, 131u8, 144u8, 227u8, 131u8, 139u8, 227u8, 130u8,
+                    162u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 130u8, 162u8, 227u8,
+                    131u8, 169u8, 227u8, 131u8, 147u8, 227u8, 130u8, 162u8, 230u8, 150u8, 135u8,
+                    229u8, 173u8, 151u8, 227u8, 131u8, 138u8, 227u8, 130u8, 185u8, 227u8, 130u8,
+                    191u8, 227u8, 130u8, 162u8, 227u8, 131u8, 170u8, 227u8, 131u8, 188u8, 227u8,
+                    130u8, 175u8, 228u8, 189u8, 147u8, 229u8, 184u8, 157u8, 229u8, 155u8, 189u8,
+                    227u8, 130u8, 162u8, 227u8, 131u8, 169u8, 227u8, 131u8, 160u8, 230u8, 150u8,
+                    135u8, 229u8, 173u8, 151u8, 227u8, 130u8, 162u8, 227u8, 131u8, 171u8, 227u8,
+                    131u8, 161u8, 227u8, 131u8, 139u8, 227u8, 130u8, 162u8, 230u8, 150u8, 135u8,
+                    229u8, 173u8, 151u8, 227u8, 130u8, 162u8, 227u8, 131u8, 180u8, 227u8, 130u8,
+                    167u8, 227u8, 130u8, 185u8, 227u8, 130u8, 191u8, 227u8, 131u8, 188u8, 230u8,
+                    150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 131u8, 144u8, 227u8, 131u8, 170u8,
+                    230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 131u8, 144u8, 227u8, 131u8,
+                    160u8, 227u8, 131u8, 179u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8,
+                    131u8, 144u8, 227u8, 130u8, 181u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8,
+                    227u8, 131u8, 144u8, 227u8, 130u8, 191u8, 227u8, 130u8, 175u8, 230u8, 150u8,
+                    135u8, 229u8, 173u8, 151u8, 227u8, 131u8, 153u8, 227u8, 131u8, 179u8, 227u8,
+                    130u8, 172u8, 227u8, 131u8, 171u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8,
+                    227u8, 131u8, 150u8, 227u8, 131u8, 170u8, 227u8, 130u8, 185u8, 227u8, 130u8,
+                    183u8, 227u8, 131u8, 179u8, 227u8, 131u8, 156u8, 227u8, 131u8, 171u8, 230u8,
+                    179u8, 168u8, 233u8, 159u8, 179u8, 229u8, 173u8, 151u8, 230u8, 175u8, 141u8,
+                    227u8, 131u8, 150u8, 227u8, 131u8, 169u8, 227u8, 131u8, 188u8, 227u8, 131u8,
+                    149u8, 227u8, 131u8, 159u8, 227u8, 131u8, 188u8, 230u8, 150u8, 135u8, 229u8,
+                    173u8, 151u8, 227u8, 131u8, 150u8, 227u8, 131u8, 169u8, 227u8, 130u8, 164u8,
+                    227u8, 131u8, 166u8, 231u8, 130u8, 185u8, 229u8, 173u8, 151u8, 227u8, 131u8,
+                    150u8, 227u8, 130u8, 174u8, 227u8, 130u8, 185u8, 230u8, 150u8, 135u8, 229u8,
+                    173u8, 151u8, 227u8, 131u8, 150u8, 227u8, 131u8, 146u8, 227u8, 131u8, 131u8,
+                    227u8, 131u8, 137u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 131u8,
+                    129u8, 227u8, 131u8, 163u8, 227u8, 130u8, 175u8, 227u8, 131u8, 158u8, 230u8,
+                    150u8, 135u8, 229u8, 173u8, 151u8, 231u8, 181u8, 177u8, 229u8, 144u8, 136u8,
+                    227u8, 130u8, 171u8, 227u8, 131u8, 138u8, 227u8, 131u8, 128u8, 229u8, 133u8,
+                    136u8, 228u8, 189u8, 143u8, 230u8, 176u8, 145u8, 233u8, 159u8, 179u8, 231u8,
+                    175u8, 128u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 130u8, 171u8,
+                    227u8, 131u8, 170u8, 227u8, 130u8, 162u8, 230u8, 150u8, 135u8, 229u8, 173u8,
+                    151u8, 227u8, 131u8, 129u8, 227u8, 131u8, 163u8, 227u8, 131u8, 160u8, 230u8,
+                    150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 131u8, 129u8, 227u8, 130u8, 167u8,
+                    227u8, 131u8, 173u8, 227u8, 130u8, 173u8, 227u8, 131u8, 188u8, 230u8, 150u8,
+                    135u8, 229u8, 173u8, 151u8, 227u8, 130u8, 173u8, 227u8, 130u8, 162u8, 227u8,
+                    130u8, 185u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 130u8, 179u8,
+                    227u8, 131u8, 151u8, 227u8, 131u8, 136u8, 230u8, 150u8, 135u8, 229u8, 173u8,
+                    151u8, 227u8, 130u8, 173u8, 227u8, 131u8, 151u8, 227u8, 131u8, 173u8, 227u8,
+                    130u8, 185u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 130u8, 173u8,
+                    227u8, 131u8, 170u8, 227u8, 131u8, 171u8, 230u8, 150u8, 135u8, 229u8, 173u8,
+                    151u8, 229u8, 143u8, 164u8, 228u8, 187u8, 163u8, 230u8, 149u8, 153u8, 228u8,
+                    188u8, 154u8, 227u8, 130u8, 185u8, 227u8, 131u8, 169u8, 227u8, 131u8, 150u8,
+                    232u8, 170u8, 158u8, 227u8, 130u8, 173u8, 227u8, 131u8, 170u8, 227u8, 131u8,
+                    171u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 131u8, 135u8, 227u8,
+                    131u8, 188u8, 227u8, 131u8, 144u8, 227u8, 131u8, 138u8, 227u8, 131u8, 188u8,
+                    227u8, 130u8, 172u8, 227u8, 131u8, 170u8, 227u8, 131u8, 188u8, 230u8, 150u8,
+                    135u8, 229u8, 173u8, 151u8, 227u8, 131u8, 135u8, 227u8, 130u8, 187u8, 227u8,
+                    131u8, 172u8, 227u8, 131u8, 131u8, 227u8, 131u8, 136u8, 230u8, 150u8, 135u8,
+                    229u8, 173u8, 151u8, 227u8, 131u8, 135u8, 227u8, 131u8, 165u8, 227u8, 131u8,
+                    151u8, 227u8, 131u8, 173u8, 227u8, 131u8, 175u8, 227u8, 130u8, 168u8, 229u8,
+                    188u8, 143u8, 233u8, 128u8, 159u8, 232u8, 168u8, 152u8, 227u8, 130u8, 168u8,
+                    227u8, 130u8, 184u8, 227u8, 131u8, 151u8, 227u8, 131u8, 136u8, 230u8, 176u8,
+                    145u8, 232u8, 161u8, 134u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8,
+                    130u8, 168u8, 227u8, 130u8, 184u8, 227u8, 131u8, 151u8, 227u8, 131u8, 136u8,
+                    231u8, 165u8, 158u8, 229u8, 174u8, 152u8, 230u8, 150u8, 135u8, 229u8, 173u8,
+                    151u8, 227u8, 130u8, 168u8, 227u8, 130u8, 184u8, 227u8, 131u8, 151u8, 227u8,
+                    131u8, 136u8, 232u8, 129u8, 150u8, 229u8, 136u8, 187u8, 230u8, 150u8, 135u8,
+                    229u8, 173u8, 151u8, 227u8, 130u8, 168u8, 227u8, 131u8, 171u8, 227u8, 131u8,
+                    144u8, 227u8, 130u8, 181u8, 227u8, 131u8, 179u8, 230u8, 150u8, 135u8, 229u8,
+                    173u8, 151u8, 227u8, 130u8, 168u8, 227u8, 131u8, 129u8, 227u8, 130u8, 170u8,
+                    227u8, 131u8, 148u8, 227u8, 130u8, 162u8, 230u8, 150u8, 135u8, 229u8, 173u8,
+                    151u8, 227u8, 130u8, 184u8, 227u8, 131u8, 167u8, 227u8, 131u8, 188u8, 227u8,
+                    130u8, 184u8, 227u8, 130u8, 162u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8,
+                    40u8, 227u8, 131u8, 149u8, 227u8, 131u8, 132u8, 227u8, 131u8, 170u8, 41u8,
+                    227u8, 130u8, 184u8, 227u8, 131u8, 167u8, 227u8, 131u8, 188u8, 227u8, 130u8,
+                    184u8, 227u8, 130u8, 162u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8,
+                    130u8, 176u8, 227u8, 131u8, 169u8, 227u8, 130u8, 180u8, 227u8, 131u8, 171u8,
+                    230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 130u8, 180u8, 227u8, 131u8,
+                    188u8, 227u8, 131u8, 136u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8,
+                    130u8, 176u8, 227u8, 131u8, 169u8, 227u8, 131u8, 179u8, 227u8, 130u8, 191u8,
+                    230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 130u8, 174u8, 227u8, 131u8,
+                    170u8, 227u8, 130u8, 183u8, 227u8, 131u8, 163u8, 230u8, 150u8, 135u8, 229u8,
+                    173u8, 151u8, 227u8, 130u8, 176u8, 227u8, 130u8, 184u8, 227u8, 131u8, 163u8,
+                    227u8, 131u8, 169u8, 227u8, 131u8, 188u8, 227u8, 131u8, 136u8, 230u8, 150u8,
+                    135u8, 229u8, 173u8, 151u8, 227u8, 130u8, 176u8, 227u8, 131u8, 171u8, 227u8,
+                    131u8, 160u8, 227u8, 130u8, 173u8, 227u8, 131u8, 188u8, 230u8, 150u8, 135u8,
+                    229u8, 173u8, 151u8, 230u8, 188u8, 162u8, 232u8, 170u8, 158u8, 230u8, 179u8,
+                    168u8, 233u8, 159u8, 179u8, 229u8, 173u8, 151u8, 230u8, 175u8, 141u8, 227u8,
+                    131u8, 143u8, 227u8, 131u8, 179u8, 227u8, 130u8, 176u8, 227u8, 131u8, 171u8,
+                    230u8, 188u8, 162u8, 229u8, 173u8, 151u8, 227u8, 131u8, 143u8, 227u8, 131u8,
+                    140u8, 227u8, 131u8, 142u8, 227u8, 130u8, 170u8, 230u8, 150u8, 135u8, 229u8,
+                    173u8, 151u8, 231u8, 176u8, 161u8, 228u8, 189u8, 147u8, 229u8, 173u8, 151u8,
+                    231u8, 185u8, 129u8, 228u8, 189u8, 147u8, 229u8, 173u8, 151u8, 227u8, 131u8,
+                    152u8, 227u8, 131u8, 150u8, 227u8, 131u8, 169u8, 227u8, 130u8, 164u8, 230u8,
+                    150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 129u8, 178u8, 227u8, 130u8, 137u8,
+                    227u8, 129u8, 140u8, 227u8, 129u8, 170u8, 227u8, 130u8, 162u8, 227u8, 131u8,
+                    138u8, 227u8, 131u8, 136u8, 227u8, 131u8, 170u8, 227u8, 130u8, 162u8, 232u8,
+                    177u8, 161u8, 229u8, 189u8, 162u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8,
+                    227u8, 131u8, 145u8, 227u8, 131u8, 143u8, 227u8, 130u8, 166u8, 227u8, 131u8,
+                    187u8, 227u8, 131u8, 149u8, 227u8, 131u8, 162u8, 227u8, 131u8, 179u8, 230u8,
+                    150u8, 135u8, 229u8, 173u8, 151u8, 228u8, 187u8, 174u8, 229u8, 144u8, 141u8,
+                    229u8, 143u8, 164u8, 228u8, 187u8, 163u8, 227u8, 131u8, 143u8, 227u8, 131u8,
+                    179u8, 227u8, 130u8, 172u8, 227u8, 131u8, 170u8, 227u8, 131u8, 188u8, 230u8,
+                    150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 130u8, 164u8, 227u8, 131u8, 179u8,
+                    227u8, 131u8, 128u8, 227u8, 130u8, 185u8, 230u8, 150u8, 135u8, 229u8, 173u8,
+                    151u8, 229u8, 143u8, 164u8, 227u8, 130u8, 164u8, 227u8, 130u8, 191u8, 227u8,
+                    131u8, 170u8, 227u8, 130u8, 162u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8,
+                    229u8, 173u8, 151u8, 230u8, 175u8, 141u8, 227u8, 130u8, 184u8, 227u8, 131u8,
+                    163u8, 227u8, 131u8, 175u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 230u8,
+                    151u8, 165u8, 230u8, 156u8, 172u8, 232u8, 170u8, 158u8, 227u8, 129u8, 174u8,
+                    230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 229u8, 165u8, 179u8, 231u8, 156u8,
+                    159u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 130u8, 171u8, 227u8,
+                    131u8, 164u8, 227u8, 131u8, 188u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8,
+                    227u8, 130u8, 171u8, 227u8, 130u8, 191u8, 227u8, 130u8, 171u8, 227u8, 131u8,
+                    138u8, 227u8, 130u8, 171u8, 227u8, 131u8, 173u8, 227u8, 131u8, 188u8, 227u8,
+                    130u8, 183u8, 227u8, 131u8, 165u8, 227u8, 131u8, 134u8, 227u8, 130u8, 163u8,
+                    227u8, 131u8, 188u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 130u8,
+                    175u8, 227u8, 131u8, 161u8, 227u8, 131u8, 188u8, 227u8, 131u8, 171u8, 230u8,
+                    150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 131u8, 155u8, 227u8, 130u8, 184u8,
+                    227u8, 131u8, 163u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 130u8,
+                    171u8, 227u8, 131u8, 179u8, 227u8, 131u8, 138u8, 227u8, 131u8, 128u8, 230u8,
+                    150u8, 135u8, 229u8, 173u8, 151u8, 233u8, 159u8, 147u8, 229u8, 155u8, 189u8,
+                    232u8, 170u8, 158u8, 227u8, 129u8, 174u8, 230u8, 150u8, 135u8, 229u8, 173u8,
+                    151u8, 227u8, 130u8, 175u8, 227u8, 131u8, 154u8, 227u8, 131u8, 172u8, 230u8,
+                    150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 130u8, 171u8, 227u8, 130u8, 164u8,
+                    227u8, 131u8, 134u8, 227u8, 130u8, 163u8, 230u8, 150u8, 135u8, 229u8, 173u8,
+                    151u8, 227u8, 131u8, 169u8, 227u8, 131u8, 188u8, 227u8, 131u8, 179u8, 227u8,
+                    131u8, 138u8, 227u8, 131u8, 188u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8,
+                    227u8, 131u8, 169u8, 227u8, 130u8, 170u8, 230u8, 150u8, 135u8, 229u8, 173u8,
+                    151u8, 227u8, 131u8, 169u8, 227u8, 131u8, 134u8, 227u8, 131u8, 179u8, 230u8,
+                    150u8, 135u8, 229u8, 173u8, 151u8, 40u8, 227u8, 131u8, 137u8, 227u8, 130u8,
+                    164u8, 227u8, 131u8, 132u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 41u8,
+                    227u8, 131u8, 169u8, 227u8, 131u8, 134u8, 227u8, 131u8, 179u8, 230u8, 150u8,
+                    135u8, 229u8, 173u8, 151u8, 32u8, 40u8, 227u8, 130u8, 178u8, 227u8, 131u8,
+                    188u8, 227u8, 131u8, 171u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 41u8,
+                    227u8, 131u8, 169u8, 227u8, 131u8, 134u8, 227u8, 131u8, 179u8, 230u8, 150u8,
+                    135u8, 229u8, 173u8, 151u8, 227u8, 131u8, 172u8, 227u8, 131u8, 151u8, 227u8,
+                    131u8, 129u8, 227u8, 131u8, 163u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8,
+                    227u8, 131u8, 170u8, 227u8, 131u8, 179u8, 227u8, 131u8, 150u8, 230u8, 150u8,
+                    135u8, 229u8, 173u8, 151u8, 231u8, 183u8, 154u8, 230u8, 150u8, 135u8, 229u8,
+                    173u8, 151u8, 65u8, 231u8, 183u8, 154u8, 230u8, 150u8, 135u8, 229u8, 173u8,
+                    151u8, 66u8, 227u8, 131u8, 149u8, 227u8, 131u8, 172u8, 227u8, 130u8, 164u8,
+                    227u8, 130u8, 182u8, 227u8, 131u8, 188u8, 230u8, 150u8, 135u8, 229u8, 173u8,
+                    151u8, 227u8, 131u8, 173u8, 227u8, 131u8, 158u8, 230u8, 150u8, 135u8, 229u8,
+                    173u8, 151u8, 227u8, 131u8, 170u8, 227u8, 130u8, 173u8, 227u8, 130u8, 162u8,
+                    230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 131u8, 170u8, 227u8, 131u8,
+                    135u8, 227u8, 130u8, 163u8, 227u8, 130u8, 162u8, 230u8, 150u8, 135u8, 229u8,
+                    173u8, 151u8, 227u8, 131u8, 158u8, 227u8, 131u8, 143u8, 227u8, 131u8, 188u8,
+                    227u8, 130u8, 184u8, 227u8, 131u8, 163u8, 227u8, 131u8, 139u8, 227u8, 131u8,
+                    188u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 131u8, 158u8, 227u8,
+                    131u8, 179u8, 227u8, 131u8, 128u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8,
+                    227u8, 131u8, 158u8, 227u8, 131u8, 139u8, 230u8, 150u8, 135u8, 229u8, 173u8,
+                    151u8, 227u8, 131u8, 158u8, 227u8, 131u8, 164u8, 232u8, 177u8, 161u8, 229u8,
+                    189u8, 162u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 131u8, 161u8,
+                    227u8, 131u8, 179u8, 227u8, 131u8, 135u8, 230u8, 150u8, 135u8, 229u8, 173u8,
+                    151u8, 227u8, 131u8, 161u8, 227u8, 131u8, 173u8, 227u8, 130u8, 168u8, 230u8,
+                    150u8, 135u8, 229u8, 173u8, 151u8, 232u8, 141u8, 137u8, 230u8, 155u8, 184u8,
+                    228u8, 189u8, 147u8, 227u8, 131u8, 161u8, 227u8, 131u8, 173u8, 227u8, 130u8,
+                    168u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 131u8, 158u8, 227u8,
+                    131u8, 169u8, 227u8, 131u8, 164u8, 227u8, 131u8, 188u8, 227u8, 131u8, 169u8,
+                    227u8, 131u8, 160u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 131u8,
+                    162u8, 227u8, 131u8, 188u8, 227u8, 131u8, 135u8, 227u8, 130u8, 163u8, 227u8,
+                    131u8, 188u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 131u8, 162u8,
+                    227u8, 131u8, 179u8, 227u8, 130u8, 180u8, 227u8, 131u8, 171u8, 230u8, 150u8,
+                    135u8, 229u8, 173u8, 151u8, 227u8, 131u8, 160u8, 227u8, 131u8, 188u8, 227u8,
+                    131u8, 179u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 131u8, 160u8,
+                    227u8, 131u8, 173u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 131u8,
+                    161u8, 227u8, 130u8, 164u8, 227u8, 131u8, 134u8, 227u8, 130u8, 164u8, 230u8,
+                    150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 131u8, 159u8, 227u8, 131u8, 163u8,
+                    227u8, 131u8, 179u8, 227u8, 131u8, 158u8, 227u8, 131u8, 188u8, 230u8, 150u8,
+                    135u8, 229u8, 173u8, 151u8, 229u8, 143u8, 164u8, 228u8, 187u8, 163u8, 229u8,
+                    140u8, 151u8, 227u8, 130u8, 162u8, 227u8, 131u8, 169u8, 227u8, 131u8, 147u8,
+                    227u8, 130u8, 162u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 131u8,
+                    138u8, 227u8, 131u8, 144u8, 227u8, 131u8, 134u8, 227u8, 130u8, 162u8, 230u8,
+                    150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 131u8, 138u8, 227u8, 130u8, 183u8,
+                    230u8, 151u8, 143u8, 227u8, 130u8, 178u8, 227u8, 131u8, 144u8, 230u8, 150u8,
+                    135u8, 229u8, 173u8, 151u8, 227u8, 131u8, 179u8, 227u8, 130u8, 179u8, 230u8,
+                    150u8, 135u8, 229u8, 173u8, 151u8, 229u8, 165u8, 179u8, 230u8, 155u8, 184u8,
+                    227u8, 130u8, 170u8, 227u8, 130u8, 172u8, 227u8, 131u8, 160u8, 230u8, 150u8,
+                    135u8, 229u8, 173u8, 151u8, 227u8, 130u8, 170u8, 227u8, 131u8, 171u8, 227u8,
+                    131u8, 129u8, 227u8, 130u8, 173u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8,
+                    227u8, 130u8, 170u8, 227u8, 131u8, 171u8, 227u8, 131u8, 155u8, 227u8, 131u8,
+                    179u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 130u8, 170u8, 227u8,
+                    131u8, 135u8, 227u8, 130u8, 163u8, 227u8, 130u8, 162u8, 230u8, 150u8, 135u8,
+                    229u8, 173u8, 151u8, 227u8, 130u8, 170u8, 227u8, 130u8, 185u8, 227u8, 131u8,
+                    158u8, 227u8, 131u8, 139u8, 227u8, 130u8, 162u8, 230u8, 150u8, 135u8, 229u8,
+                    173u8, 151u8, 227u8, 131u8, 145u8, 227u8, 131u8, 171u8, 227u8, 131u8, 159u8,
+                    227u8, 131u8, 169u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 131u8,
+                    145u8, 227u8, 130u8, 166u8, 227u8, 131u8, 187u8, 227u8, 131u8, 129u8, 227u8,
+                    131u8, 179u8, 227u8, 131u8, 187u8, 227u8, 131u8, 143u8, 227u8, 130u8, 166u8,
+                    230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 229u8, 143u8, 164u8, 227u8, 129u8,
+                    186u8, 227u8, 131u8, 171u8, 227u8, 131u8, 160u8, 230u8, 150u8, 135u8, 229u8,
+                    173u8, 151u8, 227u8, 131u8, 145u8, 227u8, 130u8, 185u8, 227u8, 131u8, 145u8,
+                    230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 231u8, 162u8, 145u8, 230u8, 150u8,
+                    135u8, 227u8, 131u8, 145u8, 227u8, 131u8, 149u8, 227u8, 131u8, 169u8, 227u8,
+                    131u8, 180u8, 227u8, 130u8, 163u8, 227u8, 131u8, 188u8, 230u8, 150u8, 135u8,
+                    229u8, 173u8, 151u8, 232u8, 169u8, 169u8, 231u8, 183u8, 168u8, 231u8, 148u8,
+                    168u8, 227u8, 131u8, 145u8, 227u8, 131u8, 149u8, 227u8, 131u8, 169u8, 227u8,
+                    131u8, 180u8, 227u8, 130u8, 163u8, 227u8, 131u8, 188u8, 230u8, 150u8, 135u8,
+                    229u8, 173u8, 151u8, 230u8, 155u8, 184u8, 231u8, 137u8, 169u8, 231u8, 148u8,
+                    168u8, 227u8, 131u8, 145u8, 227u8, 131u8, 149u8, 227u8, 131u8, 169u8, 227u8,
+                    131u8, 180u8, 227u8, 130u8, 163u8, 227u8, 131u8, 188u8, 230u8, 150u8, 135u8,
+                    229u8, 173u8, 151u8, 227u8, 131u8, 149u8, 227u8, 130u8, 167u8, 227u8, 131u8,
+                    139u8, 227u8, 130u8, 173u8, 227u8, 130u8, 162u8, 230u8, 150u8, 135u8, 229u8,
+                    173u8, 151u8, 227u8, 131u8, 157u8, 227u8, 131u8, 169u8, 227u8, 131u8, 188u8,
+                    227u8, 131u8, 137u8, 233u8, 159u8, 179u8, 229u8, 163u8, 176u8, 232u8, 168u8,
+                    152u8, 229u8, 143u8, 183u8, 231u8, 162u8, 145u8, 230u8, 150u8, 135u8, 227u8,
+                    131u8, 145u8, 227u8, 131u8, 171u8, 227u8, 131u8, 134u8, 227u8, 130u8, 163u8,
+                    227u8, 130u8, 162u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 131u8,
+                    171u8, 227u8, 130u8, 184u8, 227u8, 131u8, 163u8, 227u8, 131u8, 179u8, 230u8,
+                    150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 131u8, 173u8, 227u8, 131u8, 146u8,
+                    227u8, 131u8, 179u8, 227u8, 130u8, 174u8, 227u8, 131u8, 163u8, 230u8, 150u8,
+                    135u8, 229u8, 173u8, 151u8, 227u8, 131u8, 173u8, 227u8, 131u8, 179u8, 227u8,
+                    130u8, 180u8, 227u8, 131u8, 173u8, 227u8, 131u8, 179u8, 227u8, 130u8, 180u8,
+                    230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 131u8, 171u8, 227u8, 131u8,
+                    188u8, 227u8, 131u8, 179u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8,
+                    130u8, 181u8, 227u8, 131u8, 158u8, 227u8, 131u8, 170u8, 227u8, 130u8, 162u8,
+                    230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 130u8, 181u8, 227u8, 131u8,
+                    169u8, 227u8, 131u8, 134u8, 227u8, 130u8, 163u8, 230u8, 150u8, 135u8, 229u8,
+                    173u8, 151u8, 229u8, 143u8, 164u8, 228u8, 187u8, 163u8, 229u8, 141u8, 151u8,
+                    227u8, 130u8, 162u8, 227u8, 131u8, 169u8, 227u8, 131u8, 147u8, 227u8, 130u8,
+                    162u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 130u8, 181u8, 227u8,
+                    130u8, 166u8, 227u8, 131u8, 169u8, 227u8, 131u8, 188u8, 227u8, 130u8, 183u8,
+                    227u8, 131u8, 165u8, 227u8, 131u8, 136u8, 227u8, 131u8, 169u8, 230u8, 150u8,
+                    135u8, 229u8, 173u8, 151u8, 230u8, 137u8, 139u8, 232u8, 169u8, 177u8, 230u8,
+                    150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 130u8, 183u8, 227u8, 131u8, 167u8,
+                    227u8, 131u8, 188u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 130u8,
+                    183u8, 227u8, 131u8, 163u8, 227u8, 131u8, 188u8, 227u8, 131u8, 169u8, 227u8,
+                    131u8, 128u8, 227u8, 131u8, 188u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8,
+                    230u8, 162u8, 181u8, 229u8, 173u8, 151u8, 227u8, 130u8, 175u8, 227u8, 131u8,
+                    128u8, 227u8, 131u8, 175u8, 227u8, 131u8, 135u8, 227u8, 130u8, 163u8, 230u8,
+                    150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 130u8, 183u8, 227u8, 131u8, 179u8,
+                    227u8, 131u8, 143u8, 227u8, 131u8, 169u8, 230u8, 150u8, 135u8, 229u8, 173u8,
+                    151u8, 227u8, 130u8, 189u8, 227u8, 131u8, 169u8, 227u8, 131u8, 179u8, 227u8,
+                    130u8, 176u8, 227u8, 131u8, 187u8, 227u8, 130u8, 189u8, 227u8, 131u8, 179u8,
+                    227u8, 131u8, 154u8, 227u8, 131u8, 179u8, 227u8, 130u8, 176u8, 230u8, 150u8,
+                    135u8, 229u8, 173u8, 151u8, 227u8, 130u8, 185u8, 227u8, 131u8, 179u8, 227u8,
+                    131u8, 128u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 130u8, 183u8,
+                    227u8, 131u8, 173u8, 227u8, 131u8, 134u8, 227u8, 130u8, 163u8, 227u8, 131u8,
+                    187u8, 227u8, 131u8, 138u8, 227u8, 130u8, 176u8, 227u8, 131u8, 170u8, 230u8,
+                    150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 130u8, 183u8, 227u8, 131u8, 170u8,
+                    227u8, 130u8, 162u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 130u8,
+                    183u8, 227u8, 131u8, 170u8, 227u8, 130u8, 162u8, 230u8, 150u8, 135u8, 229u8,
+                    173u8, 151u8, 40u8, 227u8, 130u8, 168u8, 227u8, 130u8, 185u8, 227u8, 131u8,
+                    136u8, 227u8, 131u8, 169u8, 227u8, 131u8, 179u8, 227u8, 130u8, 178u8, 227u8,
+                    131u8, 173u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 41u8, 227u8, 130u8,
+                    183u8, 227u8, 131u8, 170u8, 227u8, 130u8, 162u8, 230u8, 150u8, 135u8, 229u8,
+                    173u8, 151u8, 40u8, 232u8, 165u8, 191u8, 230u8, 150u8, 185u8, 227u8, 130u8,
+                    183u8, 227u8, 131u8, 170u8, 227u8, 130u8, 162u8, 230u8, 150u8, 135u8, 229u8,
+                    173u8, 151u8, 41u8, 227u8, 130u8, 183u8, 227u8, 131u8, 170u8, 227u8, 130u8,
+                    162u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 40u8, 230u8, 157u8, 177u8,
+                    230u8, 150u8, 185u8, 227u8, 130u8, 183u8, 227u8, 131u8, 170u8, 227u8, 130u8,
+                    162u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 41u8, 227u8, 130u8, 191u8,
+                    227u8, 130u8, 176u8, 227u8, 131u8, 144u8, 227u8, 131u8, 179u8, 227u8, 131u8,
+                    175u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 130u8, 191u8, 227u8,
+                    131u8, 188u8, 227u8, 130u8, 175u8, 227u8, 131u8, 170u8, 227u8, 131u8, 188u8,
+                    230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 130u8, 191u8, 227u8, 130u8,
+                    164u8, 227u8, 131u8, 187u8, 227u8, 131u8, 172u8, 230u8, 150u8, 135u8, 229u8,
+                    173u8, 151u8, 230u8, 150u8, 176u8, 227u8, 130u8, 191u8, 227u8, 130u8, 164u8,
+                    227u8, 131u8, 187u8, 227u8, 131u8, 171u8, 227u8, 131u8, 188u8, 230u8, 150u8,
+                    135u8, 229u8, 173u8, 151u8, 227u8, 130u8, 191u8, 227u8, 131u8, 159u8, 227u8,
+                    131u8, 171u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 232u8, 165u8, 191u8,
+                    229u8, 164u8, 143u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 130u8,
+                    191u8, 227u8, 130u8, 164u8, 227u8, 131u8, 187u8, 227u8, 131u8, 180u8, 227u8,
+                    130u8, 167u8, 227u8, 131u8, 136u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8,
+                    227u8, 131u8, 134u8, 227u8, 131u8, 171u8, 227u8, 130u8, 176u8, 230u8, 150u8,
+                    135u8, 229u8, 173u8, 151u8, 227u8, 131u8, 134u8, 227u8, 131u8, 179u8, 227u8,
+                    130u8, 176u8, 227u8, 131u8, 175u8, 227u8, 131u8, 188u8, 227u8, 131u8, 171u8,
+                    230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 131u8, 134u8, 227u8, 130u8,
+                    163u8, 227u8, 131u8, 149u8, 227u8, 131u8, 138u8, 227u8, 130u8, 176u8, 230u8,
+                    150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 130u8, 191u8, 227u8, 130u8, 172u8,
+                    227u8, 131u8, 173u8, 227u8, 130u8, 176u8, 230u8, 150u8, 135u8, 229u8, 173u8,
+                    151u8, 227u8, 130u8, 191u8, 227u8, 131u8, 188u8, 227u8, 131u8, 138u8, 230u8,
+                    150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 130u8, 191u8, 227u8, 130u8, 164u8,
+                    230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 227u8, 131u8, 129u8, 227u8, 131u8,
+                    153u8, 227u8, 131u8, 131u8, 227u8, 131u8, 136u8, 230u8, 150u8, 135u8, 229u8,
+                    173u8, 151u8, 227u8, 131u8, 134u8, 227u8, 130u8, 163u8, 227u8, 131u8, 171u8,
+                    227u8, 131u8, 149u8, 227u8, 131u8, 188u8, 227u8, 130u8, 191u8, 230u8, 150u8,
+                    135u8, 229u8, 173u8, 151u8, 227u8, 130u8, 166u8, 227u8, 130u8, 172u8, 227u8,
+                    131u8, 170u8, 227u8, 131u8, 131u8, 227u8, 131u8, 136u8, 230u8, 150u8, 135u8,
+                    229u8, 173u8, 151u8, 227u8, 131u8, 180u8, 227u8, 130u8, 161u8, 227u8, 130u8,
+                    164u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 232u8, 166u8, 150u8, 232u8,
+                    169u8, 177u8, 230u8, 179u8, 149u8, 227u8, 131u8, 144u8, 227u8, 131u8, 169u8,
+                    227u8, 131u8, 179u8, 227u8, 131u8, 187u8, 227u8, 130u8, 175u8, 227u8, 130u8,
+                    183u8, 227u8, 131u8, 134u8, 227u8, 130u8, 163u8, 230u8, 150u8, 135u8, 229u8,
+                    173u8, 151u8, 227u8, 130u8, 166u8, 227u8, 130u8, 169u8, 227u8, 131u8, 172u8,
+                    227u8, 130u8, 162u8, 227u8, 130u8, 164u8, 230u8, 150u8, 135u8, 229u8, 173u8,
+                    151u8, 229u8, 143u8, 164u8, 228u8, 187u8, 163u8, 227u8, 131u8, 154u8, 227u8,
+                    131u8, 171u8, 227u8, 130u8, 183u8, 227u8, 130u8, 162u8, 230u8, 150u8, 135u8,
+                    229u8, 173u8, 151u8, 227u8, 130u8, 183u8, 227u8, 131u8, 165u8, 227u8, 131u8,
+                    161u8, 227u8, 131u8, 188u8, 227u8, 131u8, 171u8, 239u8, 188u8, 157u8, 227u8,
+                    130u8, 162u8, 227u8, 131u8, 131u8, 227u8, 130u8, 171u8, 227u8, 131u8, 137u8,
+                    232u8, 170u8, 158u8, 230u8, 165u8, 148u8, 229u8, 189u8, 162u8, 230u8, 150u8,
+                    135u8, 229u8, 173u8, 151u8, 227u8, 130u8, 164u8, 230u8, 150u8, 135u8, 229u8,
+                    173u8, 151u8, 229u8, 159u8, 186u8, 229u8, 186u8, 149u8, 230u8, 150u8, 135u8,
+                    229u8, 173u8, 151u8, 227u8, 129u8, 174u8, 231u8, 168u8, 174u8, 229u8, 136u8,
+                    165u8, 227u8, 130u8, 146u8, 231u8, 182u8, 153u8, 230u8, 137u8, 191u8, 227u8,
+                    129u8, 153u8, 227u8, 130u8, 139u8, 231u8, 181u8, 144u8, 229u8, 144u8, 136u8,
+                    230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 230u8, 149u8, 176u8, 229u8, 173u8,
+                    166u8, 232u8, 168u8, 152u8, 229u8, 143u8, 183u8, 231u8, 181u8, 181u8, 230u8,
+                    150u8, 135u8, 229u8, 173u8, 151u8, 232u8, 168u8, 152u8, 229u8, 143u8, 183u8,
+                    230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 233u8, 157u8, 158u8, 232u8, 161u8,
+                    168u8, 232u8, 168u8, 152u8, 229u8, 133u8, 177u8, 233u8, 128u8, 154u8, 230u8,
+                    150u8, 135u8, 229u8, 173u8, 151u8, 228u8, 184u8, 141u8, 230u8, 152u8, 142u8,
+                    227u8, 129u8, 170u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+};
+static RU: &DataStruct = &::icu_displaynames::provider::ScriptDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 100u8, 108u8, 109u8, 65u8, 102u8, 97u8, 107u8, 65u8, 114u8, 97u8, 98u8,
+                    65u8, 114u8, 97u8, 110u8, 65u8, 114u8, 109u8, 105u8, 65u8, 114u8, 109u8, 110u8,
+                    65u8, 118u8, 115u8, 116u8, 66u8, 97u8, 108u8, 105u8, 66u8, 97u8, 109u8, 117u8,
+                    66u8, 97u8, 115u8, 115u8, 66u8, 97u8, 116u8, 107u8, 66u8, 101u8, 110u8, 103u8,
+                    66u8, 108u8, 105u8, 115u8, 66u8, 111u8, 112u8, 111u8, 66u8, 114u8, 97u8, 104u8,
+                    66u8, 114u8, 97u8, 105u8, 66u8, 117u8, 103u8, 105u8, 66u8, 117u8, 104u8, 100u8,
+                    67u8, 97u8, 107u8, 109u8, 67u8, 97u8, 110u8, 115u8, 67u8, 97u8, 114u8, 105u8,
+                    67u8, 104u8, 97u8, 109u8, 67u8, 104u8, 101u8, 114u8, 67u8, 105u8, 114u8, 116u8,
+                    67u8, 111u8, 112u8, 116u8, 67u8, 112u8, 114u8, 116u8, 67u8, 121u8, 114u8,
+                    108u8, 67u8, 121u8, 114u8, 115u8, 68u8, 101u8, 118u8, 97u8, 68u8, 115u8, 114u8,
+                    116u8, 68u8, 117u8, 112u8, 108u8, 69u8, 103u8, 121u8, 100u8, 69u8, 103u8,
+                    121u8, 104u8, 69u8, 103u8, 121u8, 112u8, 69u8, 116u8, 104u8, 105u8, 71u8,
+                    101u8, 111u8, 107u8, 71u8, 101u8, 111u8, 114u8, 71u8, 108u8, 97u8, 103u8, 71u8,
+                    111u8, 116u8, 104u8, 71u8, 114u8, 97u8, 110u8, 71u8, 114u8, 101u8, 107u8, 71u8,
+                    117u8, 106u8, 114u8, 71u8, 117u8, 114u8, 117u8, 72u8, 97u8, 110u8, 98u8, 72u8,
+                    97u8, 110u8, 103u8, 72u8, 97u8, 110u8, 105u8, 72u8, 97u8, 110u8, 111u8, 72u8,
+                    97u8, 110u8, 115u8, 72u8, 97u8, 110u8, 116u8, 72u8, 101u8, 98u8, 114u8, 72u8,
+                    105u8, 114u8, 97u8, 72u8, 108u8, 117u8, 119u8, 72u8, 109u8, 110u8, 103u8, 72u8,
+                    114u8, 107u8, 116u8, 72u8, 117u8, 110u8, 103u8, 73u8, 110u8, 100u8, 115u8,
+                    73u8, 116u8, 97u8, 108u8, 74u8, 97u8, 109u8, 111u8, 74u8, 97u8, 118u8, 97u8,
+                    74u8, 112u8, 97u8, 110u8, 74u8, 117u8, 114u8, 99u8, 75u8, 97u8, 108u8, 105u8,
+                    75u8, 97u8, 110u8, 97u8, 75u8, 104u8, 97u8, 114u8, 75u8, 104u8, 109u8, 114u8,
+                    75u8, 104u8, 111u8, 106u8, 75u8, 110u8, 100u8, 97u8, 75u8, 111u8, 114u8, 101u8,
+                    75u8, 112u8, 101u8, 108u8, 75u8, 116u8, 104u8, 105u8, 76u8, 97u8, 110u8, 97u8,
+                    76u8, 97u8, 111u8, 111u8, 76u8, 97u8, 116u8, 102u8, 76u8, 97u8, 116u8, 103u8,
+                    76u8, 97u8, 116u8, 110u8, 76u8, 101u8, 112u8, 99u8, 76u8, 105u8, 109u8, 98u8,
+                    76u8, 105u8, 110u8, 97u8, 76u8, 105u8, 110u8, 98u8, 76u8, 105u8, 115u8, 117u8,
+                    76u8, 111u8, 109u8, 97u8, 76u8, 121u8, 99u8, 105u8, 76u8, 121u8, 100u8, 105u8,
+                    77u8, 97u8, 110u8, 100u8, 77u8, 97u8, 110u8, 105u8, 77u8, 97u8, 121u8, 97u8,
+                    77u8, 101u8, 110u8, 100u8, 77u8, 101u8, 114u8, 99u8, 77u8, 101u8, 114u8, 111u8,
+                    77u8, 108u8, 121u8, 109u8, 77u8, 111u8, 110u8, 103u8, 77u8, 111u8, 111u8,
+                    110u8, 77u8, 114u8, 111u8, 111u8, 77u8, 116u8, 101u8, 105u8, 77u8, 121u8,
+                    109u8, 114u8, 78u8, 97u8, 114u8, 98u8, 78u8, 98u8, 97u8, 116u8, 78u8, 107u8,
+                    103u8, 98u8, 78u8, 107u8, 111u8, 111u8, 78u8, 115u8, 104u8, 117u8, 79u8, 103u8,
+                    97u8, 109u8, 79u8, 108u8, 99u8, 107u8, 79u8, 114u8, 107u8, 104u8, 79u8, 114u8,
+                    121u8, 97u8, 79u8, 115u8, 109u8, 97u8, 80u8, 97u8, 108u8, 109u8, 80u8, 101u8,
+                    114u8, 109u8, 80u8, 104u8, 97u8, 103u8, 80u8, 104u8, 108u8, 105u8, 80u8, 104u8,
+                    108u8, 112u8, 80u8, 104u8, 108u8, 118u8, 80u8, 104u8, 110u8, 120u8, 80u8,
+                    108u8, 114u8, 100u8, 80u8, 114u8, 116u8, 105u8, 81u8, 97u8, 97u8, 103u8, 82u8,
+                    106u8, 110u8, 103u8, 82u8, 111u8, 104u8, 103u8, 82u8, 111u8, 114u8, 111u8,
+                    82u8, 117u8, 110u8, 114u8, 83u8, 97u8, 109u8, 114u8, 83u8, 97u8, 114u8, 97u8,
+                    83u8, 97u8, 114u8, 98u8, 83u8, 97u8, 117u8, 114u8, 83u8, 103u8, 110u8, 119u8,
+                    83u8, 104u8, 97u8, 119u8, 83u8, 104u8, 114u8, 100u8, 83u8, 105u8, 110u8, 100u8,
+                    83u8, 105u8, 110u8, 104u8, 83u8, 111u8, 114u8, 97u8, 83u8, 117u8, 110u8, 100u8,
+                    83u8, 121u8, 108u8, 111u8, 83u8, 121u8, 114u8, 99u8, 83u8, 121u8, 114u8, 101u8,
+                    83u8, 121u8, 114u8, 106u8, 83u8, 121u8, 114u8, 110u8, 84u8, 97u8, 103u8, 98u8,
+                    84u8, 97u8, 107u8, 114u8, 84u8, 97u8, 108u8, 101u8, 84u8, 97u8, 108u8, 117u8,
+                    84u8, 97u8, 109u8, 108u8, 84u8, 97u8, 110u8, 103u8, 84u8, 97u8, 118u8, 116u8,
+                    84u8, 101u8, 108u8, 117u8, 84u8, 101u8, 110u8, 103u8, 84u8, 102u8, 110u8,
+                    103u8, 84u8, 103u8, 108u8, 103u8, 84u8, 104u8, 97u8, 97u8, 84u8, 104u8, 97u8,
+                    105u8, 84u8, 105u8, 98u8, 116u8, 84u8, 105u8, 114u8, 104u8, 85u8, 103u8, 97u8,
+                    114u8, 86u8, 97u8, 105u8, 105u8, 86u8, 105u8, 115u8, 112u8, 87u8, 97u8, 114u8,
+                    97u8, 87u8, 111u8, 108u8, 101u8, 88u8, 112u8, 101u8, 111u8, 88u8, 115u8, 117u8,
+                    120u8, 89u8, 105u8, 105u8, 105u8, 90u8, 105u8, 110u8, 104u8, 90u8, 109u8,
+                    116u8, 104u8, 90u8, 115u8, 121u8, 101u8, 90u8, 115u8, 121u8, 109u8, 90u8,
+                    120u8, 120u8, 120u8, 90u8, 121u8, 121u8, 121u8, 90u8, 122u8, 122u8, 122u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    165u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 20u8, 0u8, 34u8, 0u8, 50u8, 0u8,
+                    70u8, 0u8, 88u8, 0u8, 110u8, 0u8, 128u8, 0u8, 138u8, 0u8, 157u8, 0u8, 175u8,
+                    0u8, 197u8, 0u8, 223u8, 0u8, 239u8, 0u8, 251u8, 0u8, 18u8, 1u8, 44u8, 1u8,
+                    54u8, 1u8, 74u8, 1u8, 122u8, 1u8, 140u8, 1u8, 154u8, 1u8, 166u8, 1u8, 174u8,
+                    1u8, 190u8, 1u8, 206u8, 1u8, 224u8, 1u8, 254u8, 1u8, 18u8, 2u8, 32u8, 2u8,
+                    73u8, 2u8, 118u8, 2u8, 163u8, 2u8, 214u8, 2u8, 232u8, 2u8, 9u8, 3u8, 29u8, 3u8,
+                    47u8, 3u8, 61u8, 3u8, 75u8, 3u8, 93u8, 3u8, 111u8, 3u8, 127u8, 3u8, 137u8, 3u8,
+                    151u8, 3u8, 169u8, 3u8, 181u8, 3u8, 201u8, 3u8, 225u8, 3u8, 243u8, 3u8, 3u8,
+                    4u8, 40u8, 4u8, 61u8, 4u8, 97u8, 4u8, 127u8, 4u8, 196u8, 4u8, 228u8, 4u8,
+                    238u8, 4u8, 254u8, 4u8, 14u8, 5u8, 40u8, 5u8, 48u8, 5u8, 64u8, 5u8, 82u8, 5u8,
+                    100u8, 5u8, 114u8, 5u8, 128u8, 5u8, 146u8, 5u8, 158u8, 5u8, 170u8, 5u8, 180u8,
+                    5u8, 196u8, 5u8, 231u8, 5u8, 10u8, 6u8, 26u8, 6u8, 36u8, 6u8, 46u8, 6u8, 78u8,
+                    6u8, 110u8, 6u8, 118u8, 6u8, 126u8, 6u8, 138u8, 6u8, 156u8, 6u8, 176u8, 6u8,
+                    198u8, 6u8, 206u8, 6u8, 216u8, 6u8, 255u8, 6u8, 19u8, 7u8, 35u8, 7u8, 57u8,
+                    7u8, 78u8, 7u8, 84u8, 7u8, 100u8, 7u8, 122u8, 7u8, 156u8, 7u8, 178u8, 7u8,
+                    195u8, 7u8, 201u8, 7u8, 212u8, 7u8, 234u8, 7u8, 247u8, 7u8, 24u8, 8u8, 32u8,
+                    8u8, 50u8, 8u8, 66u8, 8u8, 94u8, 8u8, 106u8, 8u8, 130u8, 8u8, 165u8, 8u8,
+                    194u8, 8u8, 216u8, 8u8, 3u8, 9u8, 23u8, 9u8, 33u8, 9u8, 55u8, 9u8, 67u8, 9u8,
+                    88u8, 9u8, 108u8, 9u8, 134u8, 9u8, 146u8, 9u8, 180u8, 9u8, 198u8, 9u8, 219u8,
+                    9u8, 240u8, 9u8, 252u8, 9u8, 14u8, 10u8, 36u8, 10u8, 59u8, 10u8, 79u8, 10u8,
+                    102u8, 10u8, 120u8, 10u8, 159u8, 10u8, 191u8, 10u8, 226u8, 10u8, 242u8, 10u8,
+                    252u8, 10u8, 15u8, 11u8, 45u8, 11u8, 65u8, 11u8, 94u8, 11u8, 109u8, 11u8,
+                    121u8, 11u8, 143u8, 11u8, 173u8, 11u8, 187u8, 11u8, 195u8, 11u8, 209u8, 11u8,
+                    227u8, 11u8, 241u8, 11u8, 5u8, 12u8, 19u8, 12u8, 42u8, 12u8, 65u8, 12u8, 77u8,
+                    12u8, 107u8, 12u8, 157u8, 12u8, 159u8, 12u8, 187u8, 12u8, 238u8, 12u8, 250u8,
+                    12u8, 8u8, 13u8, 39u8, 13u8, 63u8, 13u8, 208u8, 176u8, 208u8, 180u8, 208u8,
+                    187u8, 208u8, 176u8, 208u8, 188u8, 208u8, 176u8, 209u8, 132u8, 208u8, 176u8,
+                    208u8, 186u8, 208u8, 176u8, 208u8, 176u8, 209u8, 128u8, 208u8, 176u8, 208u8,
+                    177u8, 208u8, 184u8, 209u8, 134u8, 208u8, 176u8, 208u8, 189u8, 208u8, 176u8,
+                    209u8, 129u8, 209u8, 130u8, 208u8, 176u8, 208u8, 187u8, 208u8, 184u8, 208u8,
+                    186u8, 208u8, 176u8, 209u8, 128u8, 208u8, 176u8, 208u8, 188u8, 208u8, 181u8,
+                    208u8, 185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 208u8,
+                    176u8, 209u8, 128u8, 208u8, 188u8, 209u8, 143u8, 208u8, 189u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 208u8, 176u8, 208u8, 178u8, 208u8,
+                    181u8, 209u8, 129u8, 209u8, 130u8, 208u8, 184u8, 208u8, 185u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 208u8, 177u8, 208u8, 176u8, 208u8,
+                    187u8, 208u8, 184u8, 208u8, 185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8,
+                    209u8, 143u8, 208u8, 177u8, 208u8, 176u8, 208u8, 188u8, 209u8, 131u8, 208u8,
+                    188u8, 208u8, 177u8, 208u8, 176u8, 209u8, 129u8, 209u8, 129u8, 208u8, 176u8,
+                    32u8, 40u8, 208u8, 178u8, 208u8, 176u8, 209u8, 133u8, 41u8, 208u8, 177u8,
+                    208u8, 176u8, 209u8, 130u8, 208u8, 176u8, 208u8, 186u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 176u8, 209u8, 143u8, 208u8, 177u8, 208u8, 181u8, 208u8, 189u8,
+                    208u8, 179u8, 208u8, 176u8, 208u8, 187u8, 209u8, 140u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 176u8, 209u8, 143u8, 208u8, 177u8, 208u8, 187u8, 208u8, 184u8,
+                    209u8, 129u8, 209u8, 129u8, 208u8, 184u8, 208u8, 188u8, 208u8, 178u8, 208u8,
+                    190u8, 208u8, 187u8, 208u8, 184u8, 208u8, 186u8, 208u8, 176u8, 208u8, 177u8,
+                    208u8, 190u8, 208u8, 191u8, 208u8, 190u8, 208u8, 188u8, 208u8, 190u8, 209u8,
+                    132u8, 208u8, 190u8, 208u8, 177u8, 209u8, 128u8, 208u8, 176u8, 209u8, 133u8,
+                    208u8, 188u8, 208u8, 184u8, 209u8, 136u8, 209u8, 128u8, 208u8, 184u8, 209u8,
+                    132u8, 209u8, 130u8, 32u8, 208u8, 145u8, 209u8, 128u8, 208u8, 176u8, 208u8,
+                    185u8, 208u8, 187u8, 209u8, 143u8, 208u8, 177u8, 209u8, 131u8, 208u8, 179u8,
+                    208u8, 184u8, 208u8, 189u8, 208u8, 184u8, 208u8, 183u8, 208u8, 184u8, 208u8,
+                    185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 208u8, 177u8,
+                    209u8, 131u8, 209u8, 133u8, 208u8, 184u8, 208u8, 180u8, 209u8, 135u8, 208u8,
+                    176u8, 208u8, 186u8, 208u8, 188u8, 208u8, 184u8, 208u8, 185u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 208u8, 186u8, 208u8, 176u8, 208u8,
+                    189u8, 208u8, 176u8, 208u8, 180u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8,
+                    208u8, 181u8, 32u8, 209u8, 129u8, 208u8, 187u8, 208u8, 190u8, 208u8, 179u8,
+                    208u8, 190u8, 208u8, 178u8, 208u8, 190u8, 208u8, 181u8, 32u8, 208u8, 191u8,
+                    208u8, 184u8, 209u8, 129u8, 209u8, 140u8, 208u8, 188u8, 208u8, 190u8, 208u8,
+                    186u8, 208u8, 176u8, 209u8, 128u8, 208u8, 184u8, 208u8, 185u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 209u8, 135u8, 208u8, 176u8, 208u8,
+                    188u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 209u8, 135u8,
+                    208u8, 181u8, 209u8, 128u8, 208u8, 190u8, 208u8, 186u8, 208u8, 184u8, 208u8,
+                    186u8, 208u8, 184u8, 209u8, 128u8, 209u8, 130u8, 208u8, 186u8, 208u8, 190u8,
+                    208u8, 191u8, 209u8, 130u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 209u8,
+                    143u8, 208u8, 186u8, 208u8, 184u8, 208u8, 191u8, 209u8, 128u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 208u8, 186u8, 208u8, 184u8, 209u8,
+                    128u8, 208u8, 184u8, 208u8, 187u8, 208u8, 187u8, 208u8, 184u8, 209u8, 134u8,
+                    208u8, 176u8, 209u8, 129u8, 209u8, 130u8, 208u8, 176u8, 209u8, 128u8, 208u8,
+                    190u8, 209u8, 129u8, 208u8, 187u8, 208u8, 176u8, 208u8, 178u8, 209u8, 143u8,
+                    208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 208u8,
+                    180u8, 208u8, 181u8, 208u8, 178u8, 208u8, 176u8, 208u8, 189u8, 208u8, 176u8,
+                    208u8, 179u8, 208u8, 176u8, 209u8, 128u8, 208u8, 184u8, 208u8, 180u8, 208u8,
+                    181u8, 208u8, 183u8, 208u8, 181u8, 209u8, 128u8, 208u8, 181u8, 209u8, 130u8,
+                    208u8, 180u8, 209u8, 131u8, 208u8, 191u8, 208u8, 187u8, 208u8, 190u8, 209u8,
+                    143u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 209u8, 143u8,
+                    32u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 209u8, 128u8, 208u8, 190u8,
+                    208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 209u8, 140u8, 208u8, 181u8, 208u8,
+                    179u8, 208u8, 184u8, 208u8, 191u8, 208u8, 181u8, 209u8, 130u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 32u8, 208u8, 180u8, 208u8, 181u8,
+                    208u8, 188u8, 208u8, 190u8, 209u8, 130u8, 208u8, 184u8, 209u8, 135u8, 208u8,
+                    181u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 208u8, 181u8,
+                    208u8, 179u8, 208u8, 184u8, 208u8, 191u8, 208u8, 181u8, 209u8, 130u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 32u8, 208u8, 184u8, 208u8,
+                    181u8, 209u8, 128u8, 208u8, 176u8, 209u8, 130u8, 208u8, 184u8, 209u8, 135u8,
+                    208u8, 181u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 208u8,
+                    181u8, 208u8, 179u8, 208u8, 184u8, 208u8, 191u8, 208u8, 181u8, 209u8, 130u8,
+                    209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 32u8, 208u8, 184u8,
+                    208u8, 181u8, 209u8, 128u8, 208u8, 190u8, 208u8, 179u8, 208u8, 187u8, 208u8,
+                    184u8, 209u8, 132u8, 208u8, 184u8, 209u8, 135u8, 208u8, 181u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 209u8, 141u8, 209u8, 132u8, 208u8,
+                    184u8, 208u8, 190u8, 208u8, 191u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8,
+                    209u8, 143u8, 208u8, 179u8, 209u8, 128u8, 209u8, 131u8, 208u8, 183u8, 208u8,
+                    184u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 209u8, 143u8,
+                    32u8, 209u8, 133u8, 209u8, 131u8, 209u8, 134u8, 209u8, 131u8, 209u8, 128u8,
+                    208u8, 184u8, 208u8, 179u8, 209u8, 128u8, 209u8, 131u8, 208u8, 183u8, 208u8,
+                    184u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 209u8, 143u8,
+                    208u8, 179u8, 208u8, 187u8, 208u8, 176u8, 208u8, 179u8, 208u8, 190u8, 208u8,
+                    187u8, 208u8, 184u8, 209u8, 134u8, 208u8, 176u8, 208u8, 179u8, 208u8, 190u8,
+                    209u8, 130u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 208u8,
+                    179u8, 209u8, 128u8, 208u8, 176u8, 208u8, 189u8, 209u8, 130u8, 209u8, 133u8,
+                    208u8, 176u8, 208u8, 179u8, 209u8, 128u8, 208u8, 181u8, 209u8, 135u8, 208u8,
+                    181u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 208u8, 179u8,
+                    209u8, 131u8, 208u8, 180u8, 208u8, 182u8, 208u8, 176u8, 209u8, 128u8, 208u8,
+                    176u8, 209u8, 130u8, 208u8, 184u8, 208u8, 179u8, 209u8, 131u8, 209u8, 128u8,
+                    208u8, 188u8, 209u8, 131u8, 208u8, 186u8, 209u8, 133u8, 208u8, 184u8, 209u8,
+                    133u8, 208u8, 176u8, 208u8, 189u8, 209u8, 140u8, 208u8, 177u8, 209u8, 133u8,
+                    208u8, 176u8, 208u8, 189u8, 208u8, 179u8, 209u8, 139u8, 208u8, 187u8, 209u8,
+                    140u8, 208u8, 186u8, 208u8, 184u8, 209u8, 130u8, 208u8, 176u8, 208u8, 185u8,
+                    209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 209u8, 133u8, 208u8,
+                    176u8, 208u8, 189u8, 209u8, 131u8, 208u8, 189u8, 209u8, 131u8, 209u8, 131u8,
+                    208u8, 191u8, 209u8, 128u8, 208u8, 190u8, 209u8, 137u8, 208u8, 181u8, 208u8,
+                    189u8, 208u8, 189u8, 208u8, 176u8, 209u8, 143u8, 209u8, 130u8, 209u8, 128u8,
+                    208u8, 176u8, 208u8, 180u8, 208u8, 184u8, 209u8, 134u8, 208u8, 184u8, 208u8,
+                    190u8, 208u8, 189u8, 208u8, 189u8, 208u8, 176u8, 209u8, 143u8, 208u8, 181u8,
+                    208u8, 178u8, 209u8, 128u8, 208u8, 181u8, 208u8, 185u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 176u8, 209u8, 143u8, 209u8, 133u8, 208u8, 184u8, 209u8, 128u8,
+                    208u8, 176u8, 208u8, 179u8, 208u8, 176u8, 208u8, 189u8, 208u8, 176u8, 208u8,
+                    187u8, 209u8, 131u8, 208u8, 178u8, 208u8, 184u8, 208u8, 185u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 184u8, 208u8, 181u8, 32u8, 208u8, 184u8, 208u8, 181u8,
+                    209u8, 128u8, 208u8, 190u8, 208u8, 179u8, 208u8, 187u8, 208u8, 184u8, 209u8,
+                    132u8, 209u8, 139u8, 208u8, 191u8, 208u8, 176u8, 209u8, 133u8, 208u8, 176u8,
+                    209u8, 131u8, 32u8, 209u8, 133u8, 208u8, 188u8, 208u8, 190u8, 208u8, 189u8,
+                    208u8, 179u8, 208u8, 186u8, 208u8, 176u8, 209u8, 130u8, 208u8, 176u8, 208u8,
+                    186u8, 208u8, 176u8, 208u8, 189u8, 208u8, 176u8, 32u8, 208u8, 184u8, 32u8,
+                    209u8, 133u8, 208u8, 184u8, 209u8, 128u8, 208u8, 176u8, 208u8, 179u8, 208u8,
+                    176u8, 208u8, 189u8, 208u8, 176u8, 209u8, 129u8, 209u8, 130u8, 208u8, 176u8,
+                    209u8, 128u8, 208u8, 190u8, 208u8, 178u8, 208u8, 181u8, 208u8, 189u8, 208u8,
+                    179u8, 208u8, 181u8, 209u8, 128u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8,
+                    209u8, 143u8, 209u8, 133u8, 208u8, 176u8, 209u8, 128u8, 208u8, 176u8, 208u8,
+                    191u8, 208u8, 191u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 209u8, 143u8,
+                    32u8, 40u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 209u8, 140u8, 208u8,
+                    188u8, 208u8, 181u8, 208u8, 189u8, 208u8, 189u8, 208u8, 190u8, 209u8, 129u8,
+                    209u8, 130u8, 209u8, 140u8, 32u8, 208u8, 180u8, 208u8, 190u8, 208u8, 187u8,
+                    208u8, 184u8, 208u8, 189u8, 209u8, 139u8, 32u8, 208u8, 152u8, 208u8, 189u8,
+                    208u8, 180u8, 208u8, 176u8, 41u8, 209u8, 129u8, 209u8, 130u8, 208u8, 176u8,
+                    209u8, 128u8, 208u8, 190u8, 208u8, 184u8, 209u8, 130u8, 208u8, 176u8, 208u8,
+                    187u8, 209u8, 140u8, 209u8, 143u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 176u8, 209u8, 143u8, 208u8, 180u8, 208u8, 182u8, 208u8, 176u8, 208u8,
+                    188u8, 208u8, 190u8, 209u8, 143u8, 208u8, 178u8, 208u8, 176u8, 208u8, 189u8,
+                    209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 209u8, 143u8, 208u8,
+                    191u8, 208u8, 190u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8,
+                    209u8, 143u8, 209u8, 135u8, 208u8, 182u8, 209u8, 131u8, 209u8, 128u8, 209u8,
+                    135u8, 208u8, 182u8, 209u8, 141u8, 208u8, 189u8, 209u8, 140u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 208u8, 186u8, 208u8, 176u8, 208u8,
+                    185u8, 208u8, 176u8, 208u8, 186u8, 208u8, 176u8, 209u8, 130u8, 208u8, 176u8,
+                    208u8, 186u8, 208u8, 176u8, 208u8, 189u8, 208u8, 176u8, 208u8, 186u8, 209u8,
+                    133u8, 208u8, 176u8, 209u8, 128u8, 208u8, 190u8, 209u8, 136u8, 209u8, 130u8,
+                    209u8, 133u8, 208u8, 184u8, 208u8, 186u8, 209u8, 133u8, 208u8, 188u8, 208u8,
+                    181u8, 209u8, 128u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 209u8, 143u8,
+                    209u8, 133u8, 208u8, 190u8, 208u8, 180u8, 208u8, 182u8, 208u8, 184u8, 208u8,
+                    186u8, 208u8, 184u8, 208u8, 186u8, 208u8, 176u8, 208u8, 189u8, 208u8, 189u8,
+                    208u8, 176u8, 208u8, 180u8, 208u8, 176u8, 208u8, 186u8, 208u8, 190u8, 209u8,
+                    128u8, 208u8, 181u8, 208u8, 185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8,
+                    209u8, 143u8, 208u8, 186u8, 208u8, 191u8, 208u8, 181u8, 208u8, 187u8, 208u8,
+                    187u8, 208u8, 181u8, 208u8, 186u8, 208u8, 176u8, 208u8, 185u8, 209u8, 130u8,
+                    209u8, 133u8, 208u8, 184u8, 208u8, 187u8, 208u8, 176u8, 208u8, 189u8, 208u8,
+                    189u8, 208u8, 176u8, 208u8, 187u8, 208u8, 176u8, 208u8, 190u8, 209u8, 129u8,
+                    209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 208u8, 187u8, 208u8,
+                    176u8, 209u8, 130u8, 208u8, 184u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 176u8, 209u8, 143u8, 32u8, 209u8, 132u8, 209u8, 128u8, 208u8, 176u8,
+                    208u8, 186u8, 209u8, 130u8, 209u8, 131u8, 209u8, 128u8, 208u8, 176u8, 208u8,
+                    179u8, 209u8, 141u8, 208u8, 187u8, 209u8, 140u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 176u8, 209u8, 143u8, 32u8, 208u8, 187u8, 208u8, 176u8, 209u8, 130u8,
+                    208u8, 184u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 209u8,
+                    143u8, 208u8, 187u8, 208u8, 176u8, 209u8, 130u8, 208u8, 184u8, 208u8, 189u8,
+                    208u8, 184u8, 209u8, 134u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8,
+                    191u8, 209u8, 133u8, 208u8, 176u8, 208u8, 187u8, 208u8, 184u8, 208u8, 188u8,
+                    208u8, 177u8, 209u8, 131u8, 208u8, 187u8, 208u8, 184u8, 208u8, 189u8, 208u8,
+                    181u8, 208u8, 185u8, 208u8, 189u8, 208u8, 190u8, 208u8, 181u8, 32u8, 208u8,
+                    191u8, 208u8, 184u8, 209u8, 129u8, 209u8, 140u8, 208u8, 188u8, 208u8, 190u8,
+                    32u8, 208u8, 144u8, 208u8, 187u8, 208u8, 184u8, 208u8, 189u8, 208u8, 181u8,
+                    208u8, 185u8, 208u8, 189u8, 208u8, 190u8, 208u8, 181u8, 32u8, 208u8, 191u8,
+                    208u8, 184u8, 209u8, 129u8, 209u8, 140u8, 208u8, 188u8, 208u8, 190u8, 32u8,
+                    208u8, 145u8, 208u8, 187u8, 208u8, 184u8, 209u8, 129u8, 209u8, 131u8, 208u8,
+                    187u8, 208u8, 190u8, 208u8, 188u8, 208u8, 176u8, 208u8, 187u8, 208u8, 184u8,
+                    209u8, 134u8, 208u8, 184u8, 208u8, 176u8, 208u8, 189u8, 208u8, 187u8, 208u8,
+                    184u8, 208u8, 180u8, 208u8, 184u8, 208u8, 185u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 176u8, 209u8, 143u8, 208u8, 188u8, 208u8, 176u8, 208u8, 189u8, 208u8,
+                    180u8, 208u8, 181u8, 208u8, 185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8,
+                    209u8, 143u8, 208u8, 188u8, 208u8, 176u8, 208u8, 189u8, 208u8, 184u8, 209u8,
+                    133u8, 208u8, 181u8, 208u8, 185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8,
+                    209u8, 143u8, 208u8, 188u8, 208u8, 176u8, 208u8, 185u8, 209u8, 143u8, 208u8,
+                    188u8, 208u8, 181u8, 208u8, 189u8, 208u8, 180u8, 208u8, 181u8, 208u8, 188u8,
+                    208u8, 181u8, 209u8, 128u8, 208u8, 190u8, 208u8, 184u8, 209u8, 130u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 32u8, 208u8, 186u8, 209u8,
+                    131u8, 209u8, 128u8, 209u8, 129u8, 208u8, 184u8, 208u8, 178u8, 208u8, 189u8,
+                    208u8, 176u8, 209u8, 143u8, 208u8, 188u8, 208u8, 181u8, 209u8, 128u8, 208u8,
+                    190u8, 208u8, 184u8, 209u8, 130u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8,
+                    209u8, 143u8, 208u8, 188u8, 208u8, 176u8, 208u8, 187u8, 208u8, 176u8, 209u8,
+                    143u8, 208u8, 187u8, 208u8, 176u8, 208u8, 188u8, 208u8, 188u8, 208u8, 190u8,
+                    208u8, 189u8, 208u8, 179u8, 208u8, 190u8, 208u8, 187u8, 209u8, 140u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 208u8, 176u8, 208u8, 183u8,
+                    208u8, 177u8, 209u8, 131u8, 208u8, 186u8, 208u8, 176u8, 32u8, 208u8, 188u8,
+                    209u8, 131u8, 208u8, 189u8, 208u8, 176u8, 208u8, 188u8, 209u8, 128u8, 208u8,
+                    190u8, 208u8, 188u8, 208u8, 176u8, 208u8, 189u8, 208u8, 184u8, 208u8, 191u8,
+                    209u8, 131u8, 209u8, 128u8, 208u8, 184u8, 208u8, 188u8, 209u8, 140u8, 209u8,
+                    143u8, 208u8, 189u8, 208u8, 188u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 209u8, 129u8, 208u8, 181u8, 208u8,
+                    178u8, 208u8, 181u8, 209u8, 128u8, 208u8, 189u8, 208u8, 190u8, 208u8, 176u8,
+                    209u8, 128u8, 208u8, 176u8, 208u8, 178u8, 208u8, 184u8, 208u8, 185u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 190u8, 208u8, 181u8, 208u8, 189u8, 208u8, 176u8,
+                    208u8, 177u8, 208u8, 176u8, 209u8, 130u8, 208u8, 181u8, 208u8, 185u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 208u8, 189u8, 208u8, 176u8,
+                    209u8, 129u8, 208u8, 184u8, 32u8, 208u8, 179u8, 208u8, 181u8, 208u8, 177u8,
+                    208u8, 176u8, 208u8, 189u8, 208u8, 186u8, 208u8, 190u8, 208u8, 189u8, 209u8,
+                    142u8, 208u8, 185u8, 45u8, 209u8, 136u8, 209u8, 131u8, 208u8, 190u8, 208u8,
+                    179u8, 208u8, 176u8, 208u8, 188u8, 208u8, 184u8, 209u8, 135u8, 208u8, 181u8,
+                    209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 208u8, 190u8, 208u8,
+                    187u8, 32u8, 209u8, 135u8, 208u8, 184u8, 208u8, 186u8, 208u8, 184u8, 208u8,
+                    190u8, 209u8, 128u8, 209u8, 133u8, 208u8, 190u8, 208u8, 189u8, 208u8, 190u8,
+                    45u8, 208u8, 181u8, 208u8, 189u8, 208u8, 184u8, 209u8, 129u8, 208u8, 181u8,
+                    208u8, 185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 208u8,
+                    190u8, 209u8, 128u8, 208u8, 184u8, 209u8, 143u8, 208u8, 190u8, 209u8, 129u8,
+                    208u8, 188u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                    176u8, 209u8, 143u8, 208u8, 191u8, 208u8, 176u8, 208u8, 187u8, 209u8, 140u8,
+                    208u8, 188u8, 208u8, 184u8, 209u8, 128u8, 209u8, 139u8, 208u8, 180u8, 209u8,
+                    128u8, 208u8, 181u8, 208u8, 178u8, 208u8, 189u8, 208u8, 181u8, 208u8, 191u8,
+                    208u8, 181u8, 209u8, 128u8, 208u8, 188u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                    176u8, 209u8, 143u8, 208u8, 191u8, 208u8, 176u8, 208u8, 179u8, 209u8, 129u8,
+                    208u8, 191u8, 208u8, 176u8, 208u8, 191u8, 208u8, 181u8, 209u8, 133u8, 208u8,
+                    187u8, 208u8, 181u8, 208u8, 178u8, 208u8, 184u8, 208u8, 185u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 208u8, 191u8, 208u8, 176u8, 209u8,
+                    133u8, 208u8, 187u8, 208u8, 176u8, 208u8, 178u8, 208u8, 184u8, 32u8, 208u8,
+                    191u8, 209u8, 129u8, 208u8, 176u8, 208u8, 187u8, 209u8, 130u8, 208u8, 184u8,
+                    209u8, 128u8, 208u8, 189u8, 208u8, 176u8, 209u8, 143u8, 208u8, 191u8, 208u8,
+                    176u8, 209u8, 133u8, 208u8, 187u8, 208u8, 176u8, 208u8, 178u8, 208u8, 184u8,
+                    32u8, 208u8, 186u8, 208u8, 189u8, 208u8, 184u8, 208u8, 182u8, 208u8, 189u8,
+                    208u8, 176u8, 209u8, 143u8, 209u8, 132u8, 208u8, 184u8, 208u8, 189u8, 208u8,
+                    184u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 176u8, 209u8, 143u8, 208u8, 191u8, 208u8, 190u8, 208u8, 187u8, 208u8,
+                    187u8, 208u8, 176u8, 209u8, 128u8, 208u8, 180u8, 208u8, 190u8, 208u8, 178u8,
+                    209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 32u8, 209u8, 132u8,
+                    208u8, 190u8, 208u8, 189u8, 208u8, 181u8, 209u8, 130u8, 208u8, 184u8, 208u8,
+                    186u8, 208u8, 176u8, 208u8, 191u8, 208u8, 176u8, 209u8, 128u8, 209u8, 132u8,
+                    209u8, 143u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 209u8,
+                    143u8, 208u8, 183u8, 208u8, 190u8, 208u8, 180u8, 208u8, 182u8, 208u8, 184u8,
+                    209u8, 128u8, 208u8, 181u8, 208u8, 180u8, 208u8, 182u8, 208u8, 176u8, 208u8,
+                    189u8, 208u8, 179u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 209u8, 143u8,
+                    209u8, 133u8, 208u8, 176u8, 208u8, 189u8, 208u8, 184u8, 209u8, 132u8, 208u8,
+                    184u8, 209u8, 128u8, 208u8, 190u8, 208u8, 189u8, 208u8, 179u8, 208u8, 190u8,
+                    45u8, 209u8, 128u8, 208u8, 190u8, 208u8, 189u8, 208u8, 179u8, 208u8, 190u8,
+                    209u8, 128u8, 209u8, 131u8, 208u8, 189u8, 208u8, 184u8, 209u8, 135u8, 208u8,
+                    181u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 209u8, 129u8,
+                    208u8, 176u8, 208u8, 188u8, 208u8, 176u8, 209u8, 128u8, 208u8, 184u8, 209u8,
+                    130u8, 209u8, 143u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8,
+                    209u8, 143u8, 209u8, 129u8, 208u8, 176u8, 209u8, 128u8, 208u8, 176u8, 209u8,
+                    130u8, 208u8, 184u8, 209u8, 129u8, 209u8, 130u8, 208u8, 176u8, 209u8, 128u8,
+                    208u8, 190u8, 209u8, 142u8, 208u8, 182u8, 208u8, 189u8, 208u8, 190u8, 208u8,
+                    176u8, 209u8, 128u8, 208u8, 176u8, 208u8, 177u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 176u8, 209u8, 143u8, 209u8, 129u8, 208u8, 176u8, 209u8, 131u8, 209u8,
+                    128u8, 208u8, 176u8, 209u8, 136u8, 209u8, 130u8, 209u8, 128u8, 208u8, 176u8,
+                    209u8, 143u8, 208u8, 183u8, 209u8, 139u8, 208u8, 186u8, 32u8, 208u8, 183u8,
+                    208u8, 189u8, 208u8, 176u8, 208u8, 186u8, 208u8, 190u8, 208u8, 178u8, 208u8,
+                    176u8, 208u8, 187u8, 209u8, 132u8, 208u8, 176u8, 208u8, 178u8, 208u8, 184u8,
+                    209u8, 130u8, 32u8, 208u8, 168u8, 208u8, 190u8, 209u8, 131u8, 209u8, 136u8,
+                    208u8, 176u8, 209u8, 128u8, 208u8, 176u8, 208u8, 180u8, 208u8, 176u8, 208u8,
+                    186u8, 209u8, 133u8, 209u8, 131u8, 208u8, 180u8, 208u8, 176u8, 208u8, 178u8,
+                    208u8, 176u8, 208u8, 180u8, 208u8, 184u8, 209u8, 129u8, 208u8, 184u8, 208u8,
+                    189u8, 208u8, 179u8, 208u8, 176u8, 208u8, 187u8, 209u8, 140u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 209u8, 129u8, 208u8, 190u8, 209u8,
+                    128u8, 208u8, 176u8, 45u8, 209u8, 129u8, 208u8, 190u8, 208u8, 189u8, 208u8,
+                    191u8, 208u8, 181u8, 208u8, 189u8, 208u8, 179u8, 209u8, 129u8, 209u8, 131u8,
+                    208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 176u8, 209u8, 143u8, 209u8, 129u8, 208u8, 184u8, 208u8, 187u8,
+                    208u8, 190u8, 209u8, 130u8, 208u8, 184u8, 32u8, 208u8, 189u8, 208u8, 176u8,
+                    208u8, 179u8, 209u8, 128u8, 208u8, 184u8, 209u8, 129u8, 208u8, 184u8, 209u8,
+                    128u8, 208u8, 184u8, 208u8, 185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8,
+                    209u8, 143u8, 209u8, 129u8, 208u8, 184u8, 209u8, 128u8, 208u8, 184u8, 208u8,
+                    185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 32u8, 209u8,
+                    141u8, 209u8, 129u8, 209u8, 130u8, 209u8, 128u8, 208u8, 176u8, 208u8, 189u8,
+                    208u8, 179u8, 208u8, 181u8, 208u8, 187u8, 208u8, 190u8, 208u8, 183u8, 208u8,
+                    176u8, 208u8, 191u8, 208u8, 176u8, 208u8, 180u8, 208u8, 189u8, 208u8, 190u8,
+                    209u8, 129u8, 208u8, 184u8, 209u8, 128u8, 208u8, 184u8, 208u8, 185u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 208u8, 178u8, 208u8, 190u8,
+                    209u8, 129u8, 209u8, 130u8, 208u8, 190u8, 209u8, 135u8, 208u8, 189u8, 208u8,
+                    190u8, 45u8, 209u8, 129u8, 208u8, 184u8, 209u8, 128u8, 208u8, 184u8, 208u8,
+                    185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 209u8, 130u8,
+                    208u8, 176u8, 208u8, 179u8, 208u8, 177u8, 208u8, 176u8, 208u8, 189u8, 208u8,
+                    178u8, 208u8, 176u8, 209u8, 130u8, 208u8, 176u8, 208u8, 186u8, 209u8, 128u8,
+                    208u8, 184u8, 209u8, 130u8, 208u8, 176u8, 208u8, 185u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 184u8, 208u8, 185u8, 32u8, 208u8, 187u8, 208u8, 181u8, 208u8,
+                    189u8, 208u8, 190u8, 208u8, 178u8, 209u8, 139u8, 208u8, 185u8, 32u8, 209u8,
+                    130u8, 208u8, 176u8, 208u8, 185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                    208u8, 185u8, 32u8, 208u8, 187u8, 208u8, 181u8, 209u8, 130u8, 208u8, 176u8,
+                    208u8, 188u8, 208u8, 184u8, 208u8, 187u8, 209u8, 140u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 176u8, 209u8, 143u8, 209u8, 130u8, 208u8, 176u8, 208u8, 189u8,
+                    208u8, 179u8, 209u8, 131u8, 209u8, 130u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                    190u8, 208u8, 181u8, 32u8, 208u8, 188u8, 208u8, 181u8, 208u8, 189u8, 209u8,
+                    143u8, 209u8, 130u8, 208u8, 176u8, 208u8, 185u8, 45u8, 208u8, 178u8, 209u8,
+                    140u8, 208u8, 181u8, 209u8, 130u8, 209u8, 130u8, 208u8, 181u8, 208u8, 187u8,
+                    209u8, 131u8, 208u8, 179u8, 209u8, 131u8, 209u8, 130u8, 208u8, 181u8, 208u8,
+                    189u8, 208u8, 179u8, 208u8, 178u8, 208u8, 176u8, 209u8, 128u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 208u8, 180u8, 209u8, 128u8, 208u8,
+                    181u8, 208u8, 178u8, 208u8, 189u8, 208u8, 181u8, 208u8, 187u8, 208u8, 184u8,
+                    208u8, 178u8, 208u8, 184u8, 208u8, 185u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                    176u8, 209u8, 143u8, 209u8, 130u8, 208u8, 176u8, 208u8, 179u8, 208u8, 176u8,
+                    208u8, 187u8, 208u8, 190u8, 208u8, 179u8, 209u8, 130u8, 208u8, 176u8, 208u8,
+                    189u8, 208u8, 176u8, 209u8, 130u8, 208u8, 176u8, 208u8, 185u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 209u8, 130u8, 208u8, 184u8, 208u8,
+                    177u8, 208u8, 181u8, 209u8, 130u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8,
+                    209u8, 143u8, 209u8, 130u8, 208u8, 184u8, 209u8, 128u8, 209u8, 133u8, 209u8,
+                    131u8, 209u8, 130u8, 208u8, 176u8, 209u8, 131u8, 208u8, 179u8, 208u8, 176u8,
+                    209u8, 128u8, 208u8, 184u8, 209u8, 130u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                    176u8, 209u8, 143u8, 208u8, 178u8, 208u8, 176u8, 208u8, 185u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 208u8, 178u8, 208u8, 184u8, 208u8,
+                    180u8, 208u8, 184u8, 208u8, 188u8, 208u8, 176u8, 209u8, 143u8, 32u8, 209u8,
+                    128u8, 208u8, 181u8, 209u8, 135u8, 209u8, 140u8, 208u8, 178u8, 208u8, 176u8,
+                    209u8, 128u8, 208u8, 176u8, 208u8, 189u8, 208u8, 179u8, 45u8, 208u8, 186u8,
+                    209u8, 136u8, 208u8, 184u8, 209u8, 130u8, 208u8, 184u8, 208u8, 178u8, 208u8,
+                    190u8, 208u8, 187u8, 208u8, 181u8, 208u8, 176u8, 208u8, 184u8, 209u8, 129u8,
+                    209u8, 130u8, 208u8, 176u8, 209u8, 128u8, 208u8, 190u8, 208u8, 191u8, 208u8,
+                    181u8, 209u8, 128u8, 209u8, 129u8, 208u8, 184u8, 208u8, 180u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 209u8, 136u8, 209u8, 131u8, 208u8,
+                    188u8, 208u8, 181u8, 209u8, 128u8, 208u8, 190u8, 45u8, 208u8, 176u8, 208u8,
+                    186u8, 208u8, 186u8, 208u8, 176u8, 208u8, 180u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 176u8, 209u8, 143u8, 32u8, 208u8, 186u8, 208u8, 187u8, 208u8, 184u8,
+                    208u8, 189u8, 208u8, 190u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 209u8,
+                    140u8, 208u8, 184u8, 209u8, 131u8, 208u8, 189u8, 208u8, 176u8, 209u8, 129u8,
+                    208u8, 187u8, 208u8, 181u8, 208u8, 180u8, 208u8, 190u8, 208u8, 178u8, 208u8,
+                    176u8, 208u8, 189u8, 208u8, 189u8, 208u8, 176u8, 209u8, 143u8, 208u8, 188u8,
+                    208u8, 176u8, 209u8, 130u8, 208u8, 181u8, 208u8, 188u8, 208u8, 176u8, 209u8,
+                    130u8, 208u8, 184u8, 209u8, 135u8, 208u8, 181u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 184u8, 208u8, 181u8, 32u8, 208u8, 190u8, 208u8, 177u8, 208u8, 190u8,
+                    208u8, 183u8, 208u8, 189u8, 208u8, 176u8, 209u8, 135u8, 208u8, 181u8, 208u8,
+                    189u8, 208u8, 184u8, 209u8, 143u8, 209u8, 141u8, 208u8, 188u8, 208u8, 190u8,
+                    208u8, 180u8, 208u8, 183u8, 208u8, 184u8, 209u8, 129u8, 208u8, 184u8, 208u8,
+                    188u8, 208u8, 178u8, 208u8, 190u8, 208u8, 187u8, 209u8, 139u8, 208u8, 189u8,
+                    208u8, 181u8, 209u8, 130u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8,
+                    209u8, 140u8, 208u8, 188u8, 208u8, 181u8, 208u8, 189u8, 208u8, 189u8, 208u8,
+                    190u8, 209u8, 129u8, 209u8, 130u8, 208u8, 184u8, 208u8, 190u8, 208u8, 177u8,
+                    209u8, 137u8, 208u8, 181u8, 208u8, 191u8, 209u8, 128u8, 208u8, 184u8, 208u8,
+                    189u8, 209u8, 143u8, 209u8, 130u8, 208u8, 176u8, 209u8, 143u8, 208u8, 189u8,
+                    208u8, 181u8, 208u8, 184u8, 208u8, 183u8, 208u8, 178u8, 208u8, 181u8, 209u8,
+                    129u8, 209u8, 130u8, 208u8, 189u8, 208u8, 176u8, 209u8, 143u8, 32u8, 208u8,
+                    191u8, 208u8, 184u8, 209u8, 129u8, 209u8, 140u8, 208u8, 188u8, 208u8, 181u8,
+                    208u8, 189u8, 208u8, 189u8, 208u8, 190u8, 209u8, 129u8, 209u8, 130u8, 209u8,
+                    140u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+};
+static SR_LATN: &DataStruct = &::icu_displaynames::provider::ScriptDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 100u8, 108u8, 109u8, 65u8, 114u8, 97u8, 98u8, 65u8, 114u8, 97u8, 110u8,
+                    65u8, 114u8, 109u8, 105u8, 65u8, 114u8, 109u8, 110u8, 65u8, 118u8, 115u8,
+                    116u8, 66u8, 97u8, 108u8, 105u8, 66u8, 97u8, 116u8, 107u8, 66u8, 101u8, 110u8,
+                    103u8, 66u8, 108u8, 105u8, 115u8, 66u8, 111u8, 112u8, 111u8, 66u8, 114u8, 97u8,
+                    104u8, 66u8, 114u8, 97u8, 105u8, 66u8, 117u8, 103u8, 105u8, 66u8, 117u8, 104u8,
+                    100u8, 67u8, 97u8, 107u8, 109u8, 67u8, 97u8, 110u8, 115u8, 67u8, 97u8, 114u8,
+                    105u8, 67u8, 104u8, 97u8, 109u8, 67u8, 104u8, 101u8, 114u8, 67u8, 105u8, 114u8,
+                    116u8, 67u8, 111u8, 112u8, 116u8, 67u8, 112u8, 114u8, 116u8, 67u8, 121u8,
+                    114u8, 108u8, 67u8, 121u8, 114u8, 115u8, 68u8, 101u8, 118u8, 97u8, 68u8, 115u8,
+                    114u8, 116u8, 69u8, 103u8, 121u8, 100u8, 69u8, 103u8, 121u8, 104u8, 69u8,
+                    103u8, 121u8, 112u8, 69u8, 116u8, 104u8, 105u8, 71u8, 101u8, 111u8, 107u8,
+                    71u8, 101u8, 111u8, 114u8, 71u8, 108u8, 97u8, 103u8, 71u8, 111u8, 116u8, 104u8,
+                    71u8, 114u8, 101u8, 107u8, 71u8, 117u8, 106u8, 114u8, 71u8, 117u8, 114u8,
+                    117u8, 72u8, 97u8, 110u8, 98u8, 72u8, 97u8, 110u8, 103u8, 72u8, 97u8, 110u8,
+                    105u8, 72u8, 97u8, 110u8, 111u8, 72u8, 97u8, 110u8, 115u8, 72u8, 97u8, 110u8,
+                    116u8, 72u8, 101u8, 98u8, 114u8, 72u8, 105u8, 114u8, 97u8, 72u8, 109u8, 110u8,
+                    103u8, 72u8, 114u8, 107u8, 116u8, 72u8, 117u8, 110u8, 103u8, 73u8, 110u8,
+                    100u8, 115u8, 73u8, 116u8, 97u8, 108u8, 74u8, 97u8, 109u8, 111u8, 74u8, 97u8,
+                    118u8, 97u8, 74u8, 112u8, 97u8, 110u8, 75u8, 97u8, 108u8, 105u8, 75u8, 97u8,
+                    110u8, 97u8, 75u8, 104u8, 97u8, 114u8, 75u8, 104u8, 109u8, 114u8, 75u8, 110u8,
+                    100u8, 97u8, 75u8, 111u8, 114u8, 101u8, 75u8, 116u8, 104u8, 105u8, 76u8, 97u8,
+                    110u8, 97u8, 76u8, 97u8, 111u8, 111u8, 76u8, 97u8, 116u8, 102u8, 76u8, 97u8,
+                    116u8, 103u8, 76u8, 97u8, 116u8, 110u8, 76u8, 101u8, 112u8, 99u8, 76u8, 105u8,
+                    109u8, 98u8, 76u8, 105u8, 110u8, 97u8, 76u8, 105u8, 110u8, 98u8, 76u8, 121u8,
+                    99u8, 105u8, 76u8, 121u8, 100u8, 105u8, 77u8, 97u8, 110u8, 100u8, 77u8, 97u8,
+                    110u8, 105u8, 77u8, 97u8, 121u8, 97u8, 77u8, 101u8, 114u8, 111u8, 77u8, 108u8,
+                    121u8, 109u8, 77u8, 111u8, 110u8, 103u8, 77u8, 111u8, 111u8, 110u8, 77u8,
+                    116u8, 101u8, 105u8, 77u8, 121u8, 109u8, 114u8, 78u8, 107u8, 111u8, 111u8,
+                    79u8, 103u8, 97u8, 109u8, 79u8, 108u8, 99u8, 107u8, 79u8, 114u8, 107u8, 104u8,
+                    79u8, 114u8, 121u8, 97u8, 79u8, 115u8, 109u8, 97u8, 80u8, 101u8, 114u8, 109u8,
+                    80u8, 104u8, 97u8, 103u8, 80u8, 104u8, 108u8, 105u8, 80u8, 104u8, 108u8, 112u8,
+                    80u8, 104u8, 108u8, 118u8, 80u8, 104u8, 110u8, 120u8, 80u8, 108u8, 114u8,
+                    100u8, 80u8, 114u8, 116u8, 105u8, 82u8, 106u8, 110u8, 103u8, 82u8, 111u8,
+                    104u8, 103u8, 82u8, 111u8, 114u8, 111u8, 82u8, 117u8, 110u8, 114u8, 83u8, 97u8,
+                    109u8, 114u8, 83u8, 97u8, 114u8, 97u8, 83u8, 97u8, 117u8, 114u8, 83u8, 103u8,
+                    110u8, 119u8, 83u8, 104u8, 97u8, 119u8, 83u8, 105u8, 110u8, 104u8, 83u8, 117u8,
+                    110u8, 100u8, 83u8, 121u8, 108u8, 111u8, 83u8, 121u8, 114u8, 99u8, 83u8, 121u8,
+                    114u8, 101u8, 83u8, 121u8, 114u8, 106u8, 83u8, 121u8, 114u8, 110u8, 84u8, 97u8,
+                    103u8, 98u8, 84u8, 97u8, 108u8, 101u8, 84u8, 97u8, 108u8, 117u8, 84u8, 97u8,
+                    109u8, 108u8, 84u8, 97u8, 118u8, 116u8, 84u8, 101u8, 108u8, 117u8, 84u8, 101u8,
+                    110u8, 103u8, 84u8, 102u8, 110u8, 103u8, 84u8, 103u8, 108u8, 103u8, 84u8,
+                    104u8, 97u8, 97u8, 84u8, 104u8, 97u8, 105u8, 84u8, 105u8, 98u8, 116u8, 85u8,
+                    103u8, 97u8, 114u8, 86u8, 97u8, 105u8, 105u8, 86u8, 105u8, 115u8, 112u8, 88u8,
+                    112u8, 101u8, 111u8, 88u8, 115u8, 117u8, 120u8, 89u8, 105u8, 105u8, 105u8,
+                    90u8, 105u8, 110u8, 104u8, 90u8, 109u8, 116u8, 104u8, 90u8, 115u8, 121u8,
+                    101u8, 90u8, 115u8, 121u8, 109u8, 90u8, 120u8, 120u8, 120u8, 90u8, 121u8,
+                    121u8, 121u8, 90u8, 122u8, 122u8, 122u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    136u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 18u8, 0u8, 26u8, 0u8, 52u8, 0u8,
+                    67u8, 0u8, 83u8, 0u8, 97u8, 0u8, 108u8, 0u8, 123u8, 0u8, 143u8, 0u8, 157u8,
+                    0u8, 172u8, 0u8, 185u8, 0u8, 199u8, 0u8, 213u8, 0u8, 219u8, 0u8, 5u8, 1u8,
+                    19u8, 1u8, 32u8, 1u8, 39u8, 1u8, 49u8, 1u8, 64u8, 1u8, 78u8, 1u8, 87u8, 1u8,
+                    119u8, 1u8, 129u8, 1u8, 136u8, 1u8, 159u8, 1u8, 185u8, 1u8, 206u8, 1u8, 220u8,
+                    1u8, 244u8, 1u8, 3u8, 2u8, 13u8, 2u8, 19u8, 2u8, 31u8, 2u8, 49u8, 2u8, 62u8,
+                    2u8, 66u8, 2u8, 72u8, 2u8, 75u8, 2u8, 81u8, 2u8, 111u8, 2u8, 138u8, 2u8, 153u8,
+                    2u8, 161u8, 2u8, 178u8, 2u8, 201u8, 2u8, 221u8, 2u8, 235u8, 2u8, 247u8, 2u8,
+                    253u8, 2u8, 11u8, 3u8, 25u8, 3u8, 39u8, 3u8, 47u8, 3u8, 61u8, 3u8, 74u8, 3u8,
+                    86u8, 3u8, 100u8, 3u8, 105u8, 3u8, 116u8, 3u8, 129u8, 3u8, 157u8, 3u8, 172u8,
+                    3u8, 180u8, 3u8, 192u8, 3u8, 203u8, 3u8, 219u8, 3u8, 235u8, 3u8, 249u8, 3u8,
+                    7u8, 4u8, 23u8, 4u8, 39u8, 4u8, 59u8, 4u8, 73u8, 4u8, 91u8, 4u8, 106u8, 4u8,
+                    121u8, 4u8, 133u8, 4u8, 150u8, 4u8, 153u8, 4u8, 166u8, 4u8, 174u8, 4u8, 188u8,
+                    4u8, 203u8, 4u8, 220u8, 4u8, 241u8, 4u8, 254u8, 4u8, 12u8, 5u8, 27u8, 5u8,
+                    40u8, 5u8, 57u8, 5u8, 78u8, 5u8, 92u8, 5u8, 104u8, 5u8, 110u8, 5u8, 126u8, 5u8,
+                    138u8, 5u8, 156u8, 5u8, 168u8, 5u8, 184u8, 5u8, 198u8, 5u8, 215u8, 5u8, 230u8,
+                    5u8, 245u8, 5u8, 7u8, 6u8, 21u8, 6u8, 46u8, 6u8, 67u8, 6u8, 88u8, 6u8, 102u8,
+                    6u8, 114u8, 6u8, 126u8, 6u8, 140u8, 6u8, 154u8, 6u8, 166u8, 6u8, 179u8, 6u8,
+                    186u8, 6u8, 193u8, 6u8, 203u8, 6u8, 219u8, 6u8, 235u8, 6u8, 250u8, 6u8, 253u8,
+                    6u8, 11u8, 7u8, 31u8, 7u8, 63u8, 7u8, 65u8, 7u8, 79u8, 7u8, 100u8, 7u8, 107u8,
+                    7u8, 114u8, 7u8, 128u8, 7u8, 145u8, 7u8, 97u8, 100u8, 108u8, 97u8, 109u8, 97u8,
+                    114u8, 97u8, 112u8, 115u8, 107u8, 111u8, 32u8, 112u8, 105u8, 115u8, 109u8,
+                    111u8, 110u8, 97u8, 115u8, 116u8, 97u8, 108u8, 105u8, 107u8, 105u8, 109u8,
+                    112u8, 101u8, 114u8, 105u8, 106u8, 115u8, 107u8, 111u8, 32u8, 97u8, 114u8,
+                    97u8, 109u8, 101u8, 106u8, 115u8, 107u8, 111u8, 32u8, 112u8, 105u8, 115u8,
+                    109u8, 111u8, 106u8, 101u8, 114u8, 109u8, 101u8, 110u8, 115u8, 107u8, 111u8,
+                    32u8, 112u8, 105u8, 115u8, 109u8, 111u8, 97u8, 118u8, 101u8, 115u8, 116u8,
+                    97u8, 110u8, 115u8, 107u8, 111u8, 32u8, 112u8, 105u8, 115u8, 109u8, 111u8,
+                    98u8, 97u8, 108u8, 105u8, 106u8, 115u8, 107u8, 111u8, 32u8, 112u8, 105u8,
+                    115u8, 109u8, 111u8, 98u8, 97u8, 116u8, 97u8, 107u8, 32u8, 112u8, 105u8, 115u8,
+                    109u8, 111u8, 98u8, 101u8, 110u8, 103u8, 97u8, 108u8, 115u8, 107u8, 111u8,
+                    32u8, 112u8, 105u8, 115u8, 109u8, 111u8, 98u8, 108u8, 105u8, 115u8, 105u8,
+                    109u8, 98u8, 111u8, 108u8, 105u8, 196u8, 141u8, 110u8, 111u8, 32u8, 112u8,
+                    105u8, 115u8, 109u8, 111u8, 98u8, 111u8, 112u8, 111u8, 109u8, 111u8, 102u8,
+                    111u8, 32u8, 112u8, 105u8, 115u8, 109u8, 111u8, 98u8, 114u8, 97u8, 109u8, 97u8,
+                    110u8, 115u8, 107u8, 111u8, 32u8, 112u8, 105u8, 115u8, 109u8, 111u8, 98u8,
+                    114u8, 97u8, 106u8, 101u8, 118u8, 111u8, 32u8, 112u8, 105u8, 115u8, 109u8,
+                    111u8, 98u8, 117u8, 103u8, 105u8, 110u8, 115u8, 107u8, 111u8, 32u8, 112u8,
+                    105u8, 115u8, 109u8, 111u8, 98u8, 117u8, 104u8, 105u8, 100u8, 115u8, 107u8,
+                    111u8, 32u8, 112u8, 105u8, 115u8, 109u8, 111u8, 196u8, 141u8, 97u8, 107u8,
+                    109u8, 97u8, 117u8, 106u8, 101u8, 100u8, 105u8, 110u8, 106u8, 101u8, 110u8,
+                    105u8, 32u8, 107u8, 97u8, 110u8, 97u8, 100u8, 115u8, 107u8, 105u8, 32u8, 97u8,
+                    98u8, 111u8, 114u8, 105u8, 100u8, 197u8, 190u8, 105u8, 110u8, 115u8, 107u8,
+                    105u8, 32u8, 115u8, 105u8, 108u8, 97u8, 98u8, 105u8, 99u8, 105u8, 107u8, 97u8,
+                    114u8, 105u8, 106u8, 115u8, 107u8, 111u8, 32u8, 112u8, 105u8, 115u8, 109u8,
+                    111u8, 196u8, 141u8, 97u8, 109u8, 115u8, 107u8, 111u8, 32u8, 112u8, 105u8,
+                    115u8, 109u8, 111u8, 196u8, 141u8, 101u8, 114u8, 111u8, 107u8, 105u8, 99u8,
+                    105u8, 114u8, 116u8, 32u8, 112u8, 105u8, 115u8, 109u8, 111u8, 107u8, 111u8,
+                    112u8, 116u8, 105u8, 196u8, 141u8, 107u8, 111u8, 32u8, 112u8, 105u8, 115u8,
+                    109u8, 111u8, 107u8, 105u8, 112u8, 97u8, 114u8, 115u8, 107u8, 111u8, 32u8,
+                    112u8, 105u8, 115u8, 109u8, 111u8, 196u8, 135u8, 105u8, 114u8, 105u8, 108u8,
+                    105u8, 99u8, 97u8, 83u8, 116u8, 97u8, 114u8, 111u8, 115u8, 108u8, 111u8, 118u8,
+                    101u8, 110u8, 115u8, 107u8, 97u8, 32u8, 99u8, 114u8, 107u8, 118u8, 101u8,
+                    110u8, 97u8, 32u8, 196u8, 135u8, 105u8, 114u8, 105u8, 108u8, 105u8, 99u8, 97u8,
+                    100u8, 101u8, 118u8, 97u8, 110u8, 97u8, 103u8, 97u8, 114u8, 105u8, 68u8, 101u8,
+                    122u8, 101u8, 114u8, 101u8, 116u8, 101u8, 103u8, 105u8, 112u8, 97u8, 116u8,
+                    115u8, 107u8, 111u8, 32u8, 110u8, 97u8, 114u8, 111u8, 100u8, 110u8, 111u8,
+                    32u8, 112u8, 105u8, 115u8, 109u8, 111u8, 101u8, 103u8, 105u8, 112u8, 97u8,
+                    116u8, 115u8, 107u8, 111u8, 32u8, 104u8, 105u8, 106u8, 101u8, 114u8, 97u8,
+                    116u8, 115u8, 107u8, 111u8, 32u8, 112u8, 105u8, 115u8, 109u8, 111u8, 101u8,
+                    103u8, 105u8, 112u8, 97u8, 116u8, 115u8, 107u8, 105u8, 32u8, 104u8, 105u8,
+                    106u8, 101u8, 114u8, 111u8, 103u8, 108u8, 105u8, 102u8, 105u8, 101u8, 116u8,
+                    105u8, 111u8, 112u8, 115u8, 107u8, 111u8, 32u8, 112u8, 105u8, 115u8, 109u8,
+                    111u8, 103u8, 114u8, 117u8, 122u8, 105u8, 106u8, 115u8, 107u8, 111u8, 32u8,
+                    107u8, 104u8, 117u8, 116u8, 115u8, 117u8, 114u8, 105u8, 32u8, 112u8, 105u8,
+                    115u8, 109u8, 111u8, 103u8, 114u8, 117u8, 122u8, 105u8, 106u8, 115u8, 107u8,
+                    111u8, 32u8, 112u8, 105u8, 115u8, 109u8, 111u8, 103u8, 108u8, 97u8, 103u8,
+                    111u8, 108u8, 106u8, 105u8, 99u8, 97u8, 71u8, 111u8, 116u8, 105u8, 107u8, 97u8,
+                    103u8, 114u8, 196u8, 141u8, 107u8, 111u8, 32u8, 112u8, 105u8, 115u8, 109u8,
+                    111u8, 103u8, 117u8, 100u8, 197u8, 190u8, 97u8, 114u8, 97u8, 116u8, 115u8,
+                    107u8, 111u8, 32u8, 112u8, 105u8, 115u8, 109u8, 111u8, 103u8, 117u8, 114u8,
+                    109u8, 117u8, 107u8, 105u8, 32u8, 112u8, 105u8, 115u8, 109u8, 111u8, 104u8,
+                    97u8, 110u8, 98u8, 104u8, 97u8, 110u8, 103u8, 117u8, 108u8, 104u8, 97u8, 110u8,
+                    104u8, 97u8, 110u8, 117u8, 110u8, 111u8, 112u8, 111u8, 106u8, 101u8, 100u8,
+                    110u8, 111u8, 115u8, 116u8, 97u8, 118u8, 108u8, 106u8, 101u8, 110u8, 111u8,
+                    32u8, 107u8, 105u8, 110u8, 101u8, 115u8, 107u8, 111u8, 32u8, 112u8, 105u8,
+                    115u8, 109u8, 111u8, 116u8, 114u8, 97u8, 100u8, 105u8, 99u8, 105u8, 111u8,
+                    110u8, 97u8, 108u8, 110u8, 111u8, 32u8, 107u8, 105u8, 110u8, 101u8, 115u8,
+                    107u8, 111u8, 32u8, 112u8, 105u8, 115u8, 109u8, 111u8, 104u8, 101u8, 98u8,
+                    114u8, 101u8, 106u8, 115u8, 107u8, 111u8, 32u8, 112u8, 105u8, 115u8, 109u8,
+                    111u8, 104u8, 105u8, 114u8, 97u8, 103u8, 97u8, 110u8, 97u8, 112u8, 97u8, 104u8,
+                    97u8, 118u8, 32u8, 104u8, 109u8, 111u8, 110u8, 103u8, 32u8, 112u8, 105u8,
+                    115u8, 109u8, 111u8, 106u8, 97u8, 112u8, 97u8, 110u8, 115u8, 107u8, 97u8, 32u8,
+                    115u8, 108u8, 111u8, 103u8, 111u8, 118u8, 110u8, 97u8, 32u8, 112u8, 105u8,
+                    115u8, 109u8, 97u8, 115u8, 116u8, 97u8, 114u8, 111u8, 109u8, 97u8, 196u8,
+                    145u8, 97u8, 114u8, 115u8, 107u8, 111u8, 32u8, 112u8, 105u8, 115u8, 109u8,
+                    111u8, 105u8, 110u8, 100u8, 117u8, 197u8, 161u8, 107u8, 111u8, 32u8, 112u8,
+                    105u8, 115u8, 109u8, 111u8, 115u8, 116u8, 97u8, 114u8, 105u8, 32u8, 105u8,
+                    116u8, 97u8, 108u8, 105u8, 107u8, 100u8, 197u8, 190u8, 97u8, 109u8, 111u8,
+                    106u8, 97u8, 118u8, 97u8, 110u8, 115u8, 107u8, 111u8, 32u8, 112u8, 105u8,
+                    115u8, 109u8, 111u8, 106u8, 97u8, 112u8, 97u8, 110u8, 115u8, 107u8, 111u8,
+                    32u8, 112u8, 105u8, 115u8, 109u8, 111u8, 107u8, 97u8, 106u8, 97u8, 104u8, 45u8,
+                    108u8, 105u8, 32u8, 112u8, 105u8, 115u8, 109u8, 111u8, 107u8, 97u8, 116u8,
+                    97u8, 107u8, 97u8, 110u8, 97u8, 107u8, 97u8, 114u8, 111u8, 197u8, 161u8, 116u8,
+                    105u8, 32u8, 112u8, 105u8, 115u8, 109u8, 111u8, 107u8, 109u8, 101u8, 114u8,
+                    115u8, 107u8, 111u8, 32u8, 112u8, 105u8, 115u8, 109u8, 111u8, 107u8, 97u8,
+                    110u8, 97u8, 100u8, 97u8, 32u8, 112u8, 105u8, 115u8, 109u8, 111u8, 107u8,
+                    111u8, 114u8, 101u8, 106u8, 115u8, 107u8, 111u8, 32u8, 112u8, 105u8, 115u8,
+                    109u8, 111u8, 107u8, 97u8, 105u8, 116u8, 105u8, 108u8, 97u8, 110u8, 110u8,
+                    97u8, 32u8, 112u8, 105u8, 115u8, 109u8, 111u8, 108u8, 97u8, 111u8, 197u8,
+                    161u8, 107u8, 111u8, 32u8, 112u8, 105u8, 115u8, 109u8, 111u8, 108u8, 97u8,
+                    116u8, 105u8, 110u8, 105u8, 99u8, 97u8, 32u8, 40u8, 102u8, 114u8, 97u8, 107u8,
+                    116u8, 117u8, 114u8, 32u8, 118u8, 97u8, 114u8, 105u8, 106u8, 97u8, 110u8,
+                    116u8, 97u8, 41u8, 103u8, 97u8, 108u8, 115u8, 107u8, 97u8, 32u8, 108u8, 97u8,
+                    116u8, 105u8, 110u8, 105u8, 99u8, 97u8, 108u8, 97u8, 116u8, 105u8, 110u8,
+                    105u8, 99u8, 97u8, 108u8, 101u8, 112u8, 196u8, 141u8, 97u8, 32u8, 112u8, 105u8,
+                    115u8, 109u8, 111u8, 108u8, 105u8, 109u8, 98u8, 117u8, 32u8, 112u8, 105u8,
+                    115u8, 109u8, 111u8, 108u8, 105u8, 110u8, 101u8, 97u8, 114u8, 110u8, 111u8,
+                    32u8, 65u8, 32u8, 112u8, 105u8, 115u8, 109u8, 111u8, 108u8, 105u8, 110u8,
+                    101u8, 97u8, 114u8, 110u8, 111u8, 32u8, 66u8, 32u8, 112u8, 105u8, 115u8, 109u8,
+                    111u8, 108u8, 105u8, 115u8, 105u8, 106u8, 115u8, 107u8, 111u8, 32u8, 112u8,
+                    105u8, 115u8, 109u8, 111u8, 108u8, 105u8, 100u8, 105u8, 106u8, 115u8, 107u8,
+                    111u8, 32u8, 112u8, 105u8, 115u8, 109u8, 111u8, 109u8, 97u8, 110u8, 100u8,
+                    101u8, 97u8, 110u8, 115u8, 107u8, 111u8, 32u8, 112u8, 105u8, 115u8, 109u8,
+                    111u8, 109u8, 97u8, 110u8, 105u8, 104u8, 101u8, 106u8, 115u8, 107u8, 111u8,
+                    32u8, 112u8, 105u8, 115u8, 109u8, 111u8, 109u8, 97u8, 106u8, 97u8, 110u8,
+                    115u8, 107u8, 105u8, 32u8, 104u8, 105u8, 106u8, 101u8, 114u8, 111u8, 103u8,
+                    108u8, 105u8, 102u8, 105u8, 109u8, 101u8, 114u8, 111u8, 105u8, 116u8, 105u8,
+                    107u8, 32u8, 112u8, 105u8, 115u8, 109u8, 111u8, 109u8, 97u8, 108u8, 97u8,
+                    106u8, 97u8, 108u8, 97u8, 109u8, 115u8, 107u8, 111u8, 32u8, 112u8, 105u8,
+                    115u8, 109u8, 111u8, 109u8, 111u8, 110u8, 103u8, 111u8, 108u8, 115u8, 107u8,
+                    111u8, 32u8, 112u8, 105u8, 115u8, 109u8, 111u8, 109u8, 101u8, 115u8, 101u8,
+                    196u8, 141u8, 101u8, 118u8, 111u8, 32u8, 112u8, 105u8, 115u8, 109u8, 111u8,
+                    109u8, 101u8, 105u8, 116u8, 101u8, 105u8, 32u8, 109u8, 97u8, 106u8, 101u8,
+                    107u8, 109u8, 105u8, 106u8, 97u8, 110u8, 109u8, 97u8, 114u8, 115u8, 107u8,
+                    111u8, 32u8, 112u8, 105u8, 115u8, 109u8, 111u8, 110u8, 107u8, 111u8, 111u8,
+                    103u8, 97u8, 109u8, 115u8, 107u8, 111u8, 32u8, 112u8, 105u8, 115u8, 109u8,
+                    111u8, 111u8, 108u8, 32u8, 196u8, 141u8, 105u8, 107u8, 105u8, 111u8, 114u8,
+                    107u8, 111u8, 110u8, 115u8, 107u8, 111u8, 32u8, 112u8, 105u8, 115u8, 109u8,
+                    111u8, 111u8, 114u8, 105u8, 106u8, 97u8, 110u8, 115u8, 107u8, 111u8, 32u8,
+                    112u8, 105u8, 115u8, 109u8, 111u8, 111u8, 115u8, 109u8, 97u8, 110u8, 106u8,
+                    97u8, 110u8, 115u8, 107u8, 111u8, 32u8, 112u8, 105u8, 115u8, 109u8, 111u8,
+                    115u8, 116u8, 97u8, 114u8, 111u8, 32u8, 112u8, 101u8, 114u8, 109u8, 105u8,
+                    107u8, 115u8, 107u8, 111u8, 32u8, 112u8, 105u8, 115u8, 109u8, 111u8, 112u8,
+                    97u8, 103u8, 115u8, 45u8, 112u8, 97u8, 32u8, 112u8, 105u8, 115u8, 109u8, 111u8,
+                    112u8, 105u8, 115u8, 97u8, 110u8, 105u8, 32u8, 112u8, 97u8, 104u8, 108u8, 97u8,
+                    118u8, 105u8, 112u8, 115u8, 97u8, 108u8, 116u8, 101u8, 114u8, 32u8, 112u8,
+                    97u8, 104u8, 108u8, 97u8, 118u8, 105u8, 112u8, 97u8, 104u8, 108u8, 97u8, 118u8,
+                    105u8, 32u8, 112u8, 105u8, 115u8, 109u8, 111u8, 70u8, 101u8, 110u8, 105u8,
+                    196u8, 141u8, 97u8, 110u8, 115u8, 107u8, 111u8, 32u8, 112u8, 105u8, 115u8,
+                    109u8, 111u8, 112u8, 111u8, 114u8, 97u8, 108u8, 100u8, 32u8, 102u8, 111u8,
+                    110u8, 101u8, 116u8, 115u8, 107u8, 111u8, 32u8, 112u8, 105u8, 115u8, 109u8,
+                    111u8, 112u8, 105u8, 115u8, 97u8, 110u8, 105u8, 32u8, 112u8, 97u8, 114u8,
+                    116u8, 105u8, 97u8, 110u8, 114u8, 101u8, 106u8, 97u8, 110u8, 103u8, 32u8,
+                    112u8, 105u8, 115u8, 109u8, 111u8, 104u8, 97u8, 110u8, 105u8, 102u8, 105u8,
+                    114u8, 111u8, 110u8, 103u8, 111u8, 114u8, 111u8, 110u8, 103u8, 111u8, 32u8,
+                    112u8, 105u8, 115u8, 109u8, 111u8, 114u8, 117u8, 110u8, 115u8, 107u8, 111u8,
+                    32u8, 112u8, 105u8, 115u8, 109u8, 111u8, 115u8, 97u8, 109u8, 97u8, 114u8,
+                    105u8, 116u8, 97u8, 110u8, 115u8, 107u8, 111u8, 32u8, 112u8, 105u8, 115u8,
+                    109u8, 111u8, 115u8, 97u8, 114u8, 97u8, 116u8, 105u8, 32u8, 112u8, 105u8,
+                    115u8, 109u8, 111u8, 115u8, 97u8, 117u8, 114u8, 97u8, 197u8, 161u8, 116u8,
+                    114u8, 97u8, 32u8, 112u8, 105u8, 115u8, 109u8, 111u8, 122u8, 110u8, 97u8,
+                    107u8, 111u8, 118u8, 110u8, 111u8, 32u8, 112u8, 105u8, 115u8, 109u8, 111u8,
+                    197u8, 161u8, 97u8, 118u8, 105u8, 106u8, 97u8, 110u8, 115u8, 107u8, 111u8,
+                    32u8, 112u8, 105u8, 115u8, 109u8, 111u8, 115u8, 105u8, 110u8, 104u8, 97u8,
+                    108u8, 115u8, 107u8, 111u8, 32u8, 112u8, 105u8, 115u8, 109u8, 111u8, 115u8,
+                    117u8, 110u8, 100u8, 97u8, 110u8, 115u8, 107u8, 111u8, 32u8, 112u8, 105u8,
+                    115u8, 109u8, 111u8, 115u8, 105u8, 108u8, 111u8, 116u8, 105u8, 32u8, 110u8,
+                    97u8, 103u8, 114u8, 105u8, 32u8, 112u8, 105u8, 115u8, 109u8, 111u8, 115u8,
+                    105u8, 114u8, 105u8, 106u8, 115u8, 107u8, 111u8, 32u8, 112u8, 105u8, 115u8,
+                    109u8, 111u8, 115u8, 105u8, 114u8, 105u8, 106u8, 115u8, 107u8, 111u8, 32u8,
+                    101u8, 115u8, 116u8, 114u8, 97u8, 110u8, 103u8, 101u8, 108u8, 111u8, 32u8,
+                    112u8, 105u8, 115u8, 109u8, 111u8, 122u8, 97u8, 112u8, 97u8, 100u8, 110u8,
+                    111u8, 115u8, 105u8, 114u8, 105u8, 106u8, 115u8, 107u8, 111u8, 32u8, 112u8,
+                    105u8, 115u8, 109u8, 111u8, 112u8, 105u8, 115u8, 109u8, 111u8, 32u8, 105u8,
+                    115u8, 116u8, 111u8, 196u8, 141u8, 110u8, 101u8, 32u8, 83u8, 105u8, 114u8,
+                    105u8, 106u8, 101u8, 116u8, 97u8, 103u8, 98u8, 97u8, 110u8, 118u8, 97u8, 32u8,
+                    112u8, 105u8, 115u8, 109u8, 111u8, 116u8, 97u8, 105u8, 32u8, 108u8, 101u8,
+                    32u8, 112u8, 105u8, 115u8, 109u8, 111u8, 110u8, 111u8, 118u8, 105u8, 32u8,
+                    116u8, 97u8, 105u8, 32u8, 108u8, 117u8, 101u8, 116u8, 97u8, 109u8, 105u8,
+                    108u8, 115u8, 107u8, 111u8, 32u8, 112u8, 105u8, 115u8, 109u8, 111u8, 116u8,
+                    97u8, 105u8, 32u8, 118u8, 105u8, 101u8, 116u8, 32u8, 112u8, 105u8, 115u8,
+                    109u8, 111u8, 116u8, 101u8, 108u8, 117u8, 103u8, 117u8, 32u8, 112u8, 105u8,
+                    115u8, 109u8, 111u8, 116u8, 101u8, 110u8, 103u8, 118u8, 97u8, 114u8, 32u8,
+                    112u8, 105u8, 115u8, 109u8, 111u8, 116u8, 105u8, 102u8, 105u8, 110u8, 97u8,
+                    103u8, 84u8, 97u8, 103u8, 97u8, 108u8, 111u8, 103u8, 116u8, 97u8, 110u8, 97u8,
+                    32u8, 112u8, 105u8, 115u8, 109u8, 111u8, 116u8, 97u8, 106u8, 108u8, 97u8,
+                    110u8, 100u8, 115u8, 107u8, 111u8, 32u8, 112u8, 105u8, 115u8, 109u8, 111u8,
+                    116u8, 105u8, 98u8, 101u8, 116u8, 97u8, 110u8, 115u8, 107u8, 111u8, 32u8,
+                    112u8, 105u8, 115u8, 109u8, 111u8, 117u8, 103u8, 97u8, 114u8, 105u8, 116u8,
+                    115u8, 107u8, 111u8, 32u8, 112u8, 105u8, 115u8, 109u8, 111u8, 118u8, 97u8,
+                    105u8, 118u8, 105u8, 100u8, 108u8, 106u8, 105u8, 118u8, 105u8, 32u8, 103u8,
+                    111u8, 118u8, 111u8, 114u8, 115u8, 116u8, 97u8, 114u8, 111u8, 112u8, 101u8,
+                    114u8, 115u8, 105u8, 106u8, 115u8, 107u8, 111u8, 32u8, 112u8, 105u8, 115u8,
+                    109u8, 111u8, 115u8, 117u8, 109u8, 101u8, 114u8, 115u8, 107u8, 111u8, 45u8,
+                    97u8, 107u8, 97u8, 100u8, 115u8, 107u8, 111u8, 32u8, 107u8, 117u8, 110u8,
+                    101u8, 105u8, 102u8, 111u8, 114u8, 109u8, 32u8, 112u8, 105u8, 115u8, 109u8,
+                    111u8, 106u8, 105u8, 110u8, 97u8, 115u8, 108u8, 101u8, 100u8, 110u8, 111u8,
+                    32u8, 112u8, 105u8, 115u8, 109u8, 111u8, 109u8, 97u8, 116u8, 101u8, 109u8,
+                    97u8, 116u8, 105u8, 196u8, 141u8, 107u8, 97u8, 32u8, 110u8, 111u8, 116u8, 97u8,
+                    99u8, 105u8, 106u8, 97u8, 101u8, 109u8, 111u8, 100u8, 197u8, 190u8, 105u8,
+                    115u8, 105u8, 109u8, 98u8, 111u8, 108u8, 105u8, 110u8, 101u8, 112u8, 105u8,
+                    115u8, 97u8, 110u8, 105u8, 32u8, 106u8, 101u8, 122u8, 105u8, 107u8, 122u8,
+                    97u8, 106u8, 101u8, 100u8, 110u8, 105u8, 196u8, 141u8, 107u8, 111u8, 32u8,
+                    112u8, 105u8, 115u8, 109u8, 111u8, 110u8, 101u8, 112u8, 111u8, 122u8, 110u8,
+                    97u8, 116u8, 111u8, 32u8, 112u8, 105u8, 115u8, 109u8, 111u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+};
+static SR_SR_CYRL: &DataStruct = &::icu_displaynames::provider::ScriptDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 100u8, 108u8, 109u8, 65u8, 114u8, 97u8, 98u8, 65u8, 114u8, 97u8, 110u8,
+                    65u8, 114u8, 109u8, 105u8, 65u8, 114u8, 109u8, 110u8, 65u8, 118u8, 115u8,
+                    116u8, 66u8, 97u8, 108u8, 105u8, 66u8, 97u8, 116u8, 107u8, 66u8, 101u8, 110u8,
+                    103u8, 66u8, 108u8, 105u8, 115u8, 66u8, 111u8, 112u8, 111u8, 66u8, 114u8, 97u8,
+                    104u8, 66u8, 114u8, 97u8, 105u8, 66u8, 117u8, 103u8, 105u8, 66u8, 117u8, 104u8,
+                    100u8, 67u8, 97u8, 107u8, 109u8, 67u8, 97u8, 110u8, 115u8, 67u8, 97u8, 114u8,
+                    105u8, 67u8, 104u8, 97u8, 109u8, 67u8, 104u8, 101u8, 114u8, 67u8, 105u8, 114u8,
+                    116u8, 67u8, 111u8, 112u8, 116u8, 67u8, 112u8, 114u8, 116u8, 67u8, 121u8,
+                    114u8, 108u8, 67u8, 121u8, 114u8, 115u8, 68u8, 101u8, 118u8, 97u8, 68u8, 115u8,
+                    114u8, 116u8, 69u8, 103u8, 121u8, 100u8, 69u8, 103u8, 121u8, 104u8, 69u8,
+                    103u8, 121u8, 112u8, 69u8, 116u8, 104u8, 105u8, 71u8, 101u8, 111u8, 107u8,
+                    71u8, 101u8, 111u8, 114u8, 71u8, 108u8, 97u8, 103u8, 71u8, 111u8, 116u8, 104u8,
+                    71u8, 114u8, 101u8, 107u8, 71u8, 117u8, 106u8, 114u8, 71u8, 117u8, 114u8,
+                    117u8, 72u8, 97u8, 110u8, 98u8, 72u8, 97u8, 110u8, 103u8, 72u8, 97u8, 110u8,
+                    105u8, 72u8, 97u8, 110u8, 111u8, 72u8, 97u8, 110u8, 115u8, 72u8, 97u8, 110u8,
+                    116u8, 72u8, 101u8, 98u8, 114u8, 72u8, 105u8, 114u8, 97u8, 72u8, 109u8, 110u8,
+                    103u8, 72u8, 114u8, 107u8, 116u8, 72u8, 117u8, 110u8, 103u8, 73u8, 110u8,
+                    100u8, 115u8, 73u8, 116u8, 97u8, 108u8, 74u8, 97u8, 109u8, 111u8, 74u8, 97u8,
+                    118u8, 97u8, 74u8, 112u8, 97u8, 110u8, 75u8, 97u8, 108u8, 105u8, 75u8, 97u8,
+                    110u8, 97u8, 75u8, 104u8, 97u8, 114u8, 75u8, 104u8, 109u8, 114u8, 75u8, 110u8,
+                    100u8, 97u8, 75u8, 111u8, 114u8, 101u8, 75u8, 116u8, 104u8, 105u8, 76u8, 97u8,
+                    110u8, 97u8, 76u8, 97u8, 111u8, 111u8, 76u8, 97u8, 116u8, 102u8, 76u8, 97u8,
+                    116u8, 103u8, 76u8, 97u8, 116u8, 110u8, 76u8, 101u8, 112u8, 99u8, 76u8, 105u8,
+                    109u8, 98u8, 76u8, 105u8, 110u8, 97u8, 76u8, 105u8, 110u8, 98u8, 76u8, 121u8,
+                    99u8, 105u8, 76u8, 121u8, 100u8, 105u8, 77u8, 97u8, 110u8, 100u8, 77u8, 97u8,
+                    110u8, 105u8, 77u8, 97u8, 121u8, 97u8, 77u8, 101u8, 114u8, 111u8, 77u8, 108u8,
+                    121u8, 109u8, 77u8, 111u8, 110u8, 103u8, 77u8, 111u8, 111u8, 110u8, 77u8,
+                    116u8, 101u8, 105u8, 77u8, 121u8, 109u8, 114u8, 78u8, 107u8, 111u8, 111u8,
+                    79u8, 103u8, 97u8, 109u8, 79u8, 108u8, 99u8, 107u8, 79u8, 114u8, 107u8, 104u8,
+                    79u8, 114u8, 121u8, 97u8, 79u8, 115u8, 109u8, 97u8, 80u8, 101u8, 114u8, 109u8,
+                    80u8, 104u8, 97u8, 103u8, 80u8, 104u8, 108u8, 105u8, 80u8, 104u8, 108u8, 112u8,
+                    80u8, 104u8, 108u8, 118u8, 80u8, 104u8, 110u8, 120u8, 80u8, 108u8, 114u8,
+                    100u8, 80u8, 114u8, 116u8, 105u8, 82u8, 106u8, 110u8, 103u8, 82u8, 111u8,
+                    104u8, 103u8, 82u8, 111u8, 114u8, 111u8, 82u8, 117u8, 110u8, 114u8, 83u8, 97u8,
+                    109u8, 114u8, 83u8, 97u8, 114u8, 97u8, 83u8, 97u8, 117u8, 114u8, 83u8, 103u8,
+                    110u8, 119u8, 83u8, 104u8, 97u8, 119u8, 83u8, 105u8, 110u8, 104u8, 83u8, 117u8,
+                    110u8, 100u8, 83u8, 121u8, 108u8, 111u8, 83u8, 121u8, 114u8, 99u8, 83u8, 121u8,
+                    114u8, 101u8, 83u8, 121u8, 114u8, 106u8, 83u8, 121u8, 114u8, 110u8, 84u8, 97u8,
+                    103u8, 98u8, 84u8, 97u8, 108u8, 101u8, 84u8, 97u8, 108u8, 117u8, 84u8, 97u8,
+                    109u8, 108u8, 84u8, 97u8, 118u8, 116u8, 84u8, 101u8, 108u8, 117u8, 84u8, 101u8,
+                    110u8, 103u8, 84u8, 102u8, 110u8, 103u8, 84u8, 103u8, 108u8, 103u8, 84u8,
+                    104u8, 97u8, 97u8, 84u8, 104u8, 97u8, 105u8, 84u8, 105u8, 98u8, 116u8, 85u8,
+                    103u8, 97u8, 114u8, 86u8, 97u8, 105u8, 105u8, 86u8, 105u8, 115u8, 112u8, 88u8,
+                    112u8, 101u8, 111u8, 88u8, 115u8, 117u8, 120u8, 89u8, 105u8, 105u8, 105u8,
+                    90u8, 105u8, 110u8, 104u8, 90u8, 109u8, 116u8, 104u8, 90u8, 115u8, 121u8,
+                    101u8, 90u8, 115u8, 121u8, 109u8, 90u8, 120u8, 120u8, 120u8, 90u8, 121u8,
+                    121u8, 121u8, 90u8, 122u8, 122u8, 122u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    136u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 35u8, 0u8, 51u8, 0u8, 101u8, 0u8,
+                    130u8, 0u8, 161u8, 0u8, 188u8, 0u8, 209u8, 0u8, 238u8, 0u8, 19u8, 1u8, 46u8,
+                    1u8, 75u8, 1u8, 100u8, 1u8, 127u8, 1u8, 154u8, 1u8, 164u8, 1u8, 239u8, 1u8,
+                    10u8, 2u8, 33u8, 2u8, 45u8, 2u8, 64u8, 2u8, 91u8, 2u8, 118u8, 2u8, 134u8, 2u8,
+                    194u8, 2u8, 214u8, 2u8, 228u8, 2u8, 16u8, 3u8, 66u8, 3u8, 107u8, 3u8, 134u8,
+                    3u8, 180u8, 3u8, 209u8, 3u8, 227u8, 3u8, 239u8, 3u8, 4u8, 4u8, 35u8, 4u8, 60u8,
+                    4u8, 68u8, 4u8, 80u8, 4u8, 86u8, 4u8, 98u8, 4u8, 154u8, 4u8, 206u8, 4u8, 235u8,
+                    4u8, 251u8, 4u8, 27u8, 5u8, 71u8, 5u8, 108u8, 5u8, 133u8, 5u8, 156u8, 5u8,
+                    164u8, 5u8, 191u8, 5u8, 218u8, 5u8, 244u8, 5u8, 4u8, 6u8, 29u8, 6u8, 54u8, 6u8,
+                    77u8, 6u8, 104u8, 6u8, 114u8, 6u8, 135u8, 6u8, 158u8, 6u8, 210u8, 6u8, 239u8,
+                    6u8, 255u8, 6u8, 20u8, 7u8, 41u8, 7u8, 71u8, 7u8, 101u8, 7u8, 128u8, 7u8,
+                    155u8, 7u8, 186u8, 7u8, 217u8, 7u8, 0u8, 8u8, 27u8, 8u8, 62u8, 8u8, 91u8, 8u8,
+                    118u8, 8u8, 141u8, 8u8, 174u8, 8u8, 180u8, 8u8, 205u8, 8u8, 218u8, 8u8, 245u8,
+                    8u8, 18u8, 9u8, 49u8, 9u8, 89u8, 9u8, 113u8, 9u8, 140u8, 9u8, 169u8, 9u8,
+                    194u8, 9u8, 225u8, 9u8, 9u8, 10u8, 36u8, 10u8, 59u8, 10u8, 71u8, 10u8, 102u8,
+                    10u8, 125u8, 10u8, 160u8, 10u8, 183u8, 10u8, 212u8, 10u8, 239u8, 10u8, 14u8,
+                    11u8, 43u8, 11u8, 72u8, 11u8, 106u8, 11u8, 133u8, 11u8, 181u8, 11u8, 222u8,
+                    11u8, 4u8, 12u8, 31u8, 12u8, 53u8, 12u8, 75u8, 12u8, 102u8, 12u8, 128u8, 12u8,
+                    151u8, 12u8, 176u8, 12u8, 190u8, 12u8, 204u8, 12u8, 223u8, 12u8, 254u8, 12u8,
+                    29u8, 13u8, 58u8, 13u8, 64u8, 13u8, 89u8, 13u8, 128u8, 13u8, 189u8, 13u8,
+                    193u8, 13u8, 220u8, 13u8, 3u8, 14u8, 13u8, 14u8, 27u8, 14u8, 54u8, 14u8, 85u8,
+                    14u8, 208u8, 176u8, 208u8, 180u8, 208u8, 187u8, 208u8, 176u8, 208u8, 188u8,
+                    208u8, 176u8, 209u8, 128u8, 208u8, 176u8, 208u8, 191u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8,
+                    188u8, 208u8, 190u8, 208u8, 189u8, 208u8, 176u8, 209u8, 129u8, 209u8, 130u8,
+                    208u8, 176u8, 208u8, 187u8, 208u8, 184u8, 208u8, 186u8, 208u8, 184u8, 208u8,
+                    188u8, 208u8, 191u8, 208u8, 181u8, 209u8, 128u8, 208u8, 184u8, 209u8, 152u8,
+                    209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 32u8, 208u8, 176u8, 209u8, 128u8,
+                    208u8, 176u8, 208u8, 188u8, 208u8, 181u8, 209u8, 152u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8,
+                    188u8, 208u8, 190u8, 209u8, 152u8, 208u8, 181u8, 209u8, 128u8, 208u8, 188u8,
+                    208u8, 181u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 32u8,
+                    208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 208u8,
+                    176u8, 208u8, 178u8, 208u8, 181u8, 209u8, 129u8, 209u8, 130u8, 208u8, 176u8,
+                    208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 32u8, 208u8, 191u8,
+                    208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 208u8, 177u8, 208u8,
+                    176u8, 208u8, 187u8, 208u8, 184u8, 209u8, 152u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 190u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8,
+                    208u8, 190u8, 208u8, 177u8, 208u8, 176u8, 209u8, 130u8, 208u8, 176u8, 208u8,
+                    186u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8,
+                    190u8, 208u8, 177u8, 208u8, 181u8, 208u8, 189u8, 208u8, 179u8, 208u8, 176u8,
+                    208u8, 187u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 32u8, 208u8, 191u8,
+                    208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 208u8, 177u8, 208u8,
+                    187u8, 208u8, 184u8, 209u8, 129u8, 208u8, 184u8, 208u8, 188u8, 208u8, 177u8,
+                    208u8, 190u8, 208u8, 187u8, 208u8, 184u8, 209u8, 135u8, 208u8, 189u8, 208u8,
+                    190u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8,
+                    190u8, 208u8, 177u8, 208u8, 190u8, 208u8, 191u8, 208u8, 190u8, 208u8, 188u8,
+                    208u8, 190u8, 209u8, 132u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8, 184u8,
+                    209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 208u8, 177u8, 209u8, 128u8, 208u8,
+                    176u8, 208u8, 188u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 190u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8,
+                    208u8, 190u8, 208u8, 177u8, 209u8, 128u8, 208u8, 176u8, 209u8, 152u8, 208u8,
+                    181u8, 208u8, 178u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8,
+                    129u8, 208u8, 188u8, 208u8, 190u8, 208u8, 177u8, 209u8, 131u8, 208u8, 179u8,
+                    208u8, 184u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 32u8,
+                    208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 208u8,
+                    177u8, 209u8, 131u8, 209u8, 133u8, 208u8, 184u8, 208u8, 180u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8,
+                    208u8, 188u8, 208u8, 190u8, 209u8, 135u8, 208u8, 176u8, 208u8, 186u8, 208u8,
+                    188u8, 208u8, 176u8, 209u8, 131u8, 209u8, 152u8, 208u8, 181u8, 208u8, 180u8,
+                    208u8, 184u8, 209u8, 154u8, 208u8, 181u8, 208u8, 189u8, 208u8, 184u8, 32u8,
+                    208u8, 186u8, 208u8, 176u8, 208u8, 189u8, 208u8, 176u8, 208u8, 180u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 208u8, 176u8, 208u8, 177u8, 208u8,
+                    190u8, 209u8, 128u8, 208u8, 184u8, 209u8, 159u8, 208u8, 184u8, 208u8, 189u8,
+                    209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 209u8, 129u8, 208u8, 184u8,
+                    208u8, 187u8, 208u8, 176u8, 208u8, 177u8, 208u8, 184u8, 209u8, 134u8, 208u8,
+                    184u8, 208u8, 186u8, 208u8, 176u8, 209u8, 128u8, 208u8, 184u8, 209u8, 152u8,
+                    209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8, 184u8,
+                    209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 209u8, 135u8, 208u8, 176u8, 208u8,
+                    188u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8,
+                    184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 209u8, 135u8, 208u8, 181u8,
+                    209u8, 128u8, 208u8, 190u8, 208u8, 186u8, 208u8, 184u8, 209u8, 134u8, 208u8,
+                    184u8, 209u8, 128u8, 209u8, 130u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8,
+                    129u8, 208u8, 188u8, 208u8, 190u8, 208u8, 186u8, 208u8, 190u8, 208u8, 191u8,
+                    209u8, 130u8, 208u8, 184u8, 209u8, 135u8, 208u8, 186u8, 208u8, 190u8, 32u8,
+                    208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 208u8,
+                    186u8, 208u8, 184u8, 208u8, 191u8, 208u8, 176u8, 209u8, 128u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8,
+                    208u8, 188u8, 208u8, 190u8, 209u8, 155u8, 208u8, 184u8, 209u8, 128u8, 208u8,
+                    184u8, 208u8, 187u8, 208u8, 184u8, 209u8, 134u8, 208u8, 176u8, 208u8, 161u8,
+                    209u8, 130u8, 208u8, 176u8, 209u8, 128u8, 208u8, 190u8, 209u8, 129u8, 208u8,
+                    187u8, 208u8, 190u8, 208u8, 178u8, 208u8, 181u8, 208u8, 189u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 176u8, 32u8, 209u8, 134u8, 209u8, 128u8, 208u8, 186u8,
+                    208u8, 178u8, 208u8, 181u8, 208u8, 189u8, 208u8, 176u8, 32u8, 209u8, 155u8,
+                    208u8, 184u8, 209u8, 128u8, 208u8, 184u8, 208u8, 187u8, 208u8, 184u8, 209u8,
+                    134u8, 208u8, 176u8, 208u8, 180u8, 208u8, 181u8, 208u8, 178u8, 208u8, 176u8,
+                    208u8, 189u8, 208u8, 176u8, 208u8, 179u8, 208u8, 176u8, 209u8, 128u8, 208u8,
+                    184u8, 208u8, 148u8, 208u8, 181u8, 208u8, 183u8, 208u8, 181u8, 209u8, 128u8,
+                    208u8, 181u8, 209u8, 130u8, 208u8, 181u8, 208u8, 179u8, 208u8, 184u8, 208u8,
+                    191u8, 208u8, 176u8, 209u8, 130u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8,
+                    32u8, 208u8, 189u8, 208u8, 176u8, 209u8, 128u8, 208u8, 190u8, 208u8, 180u8,
+                    208u8, 189u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8,
+                    208u8, 188u8, 208u8, 190u8, 208u8, 181u8, 208u8, 179u8, 208u8, 184u8, 208u8,
+                    191u8, 208u8, 176u8, 209u8, 130u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8,
+                    32u8, 209u8, 133u8, 208u8, 184u8, 209u8, 152u8, 208u8, 181u8, 209u8, 128u8,
+                    208u8, 176u8, 209u8, 130u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 32u8,
+                    208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 208u8,
+                    181u8, 208u8, 179u8, 208u8, 184u8, 208u8, 191u8, 208u8, 176u8, 209u8, 130u8,
+                    209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 209u8, 133u8, 208u8, 184u8,
+                    209u8, 152u8, 208u8, 181u8, 209u8, 128u8, 208u8, 190u8, 208u8, 179u8, 208u8,
+                    187u8, 208u8, 184u8, 209u8, 132u8, 208u8, 184u8, 208u8, 181u8, 209u8, 130u8,
+                    208u8, 184u8, 208u8, 190u8, 208u8, 191u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                    190u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8,
+                    190u8, 208u8, 179u8, 209u8, 128u8, 209u8, 131u8, 208u8, 183u8, 208u8, 184u8,
+                    209u8, 152u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 32u8, 208u8, 186u8,
+                    209u8, 133u8, 209u8, 131u8, 209u8, 130u8, 209u8, 129u8, 209u8, 131u8, 209u8,
+                    128u8, 208u8, 184u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8,
+                    188u8, 208u8, 190u8, 208u8, 179u8, 209u8, 128u8, 209u8, 131u8, 208u8, 183u8,
+                    208u8, 184u8, 209u8, 152u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 32u8,
+                    208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 208u8,
+                    179u8, 208u8, 187u8, 208u8, 176u8, 208u8, 179u8, 208u8, 190u8, 209u8, 153u8,
+                    208u8, 184u8, 209u8, 134u8, 208u8, 176u8, 208u8, 147u8, 208u8, 190u8, 209u8,
+                    130u8, 208u8, 184u8, 208u8, 186u8, 208u8, 176u8, 208u8, 179u8, 209u8, 128u8,
+                    209u8, 135u8, 208u8, 186u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8, 184u8,
+                    209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 208u8, 179u8, 209u8, 131u8, 209u8,
+                    159u8, 208u8, 176u8, 209u8, 128u8, 208u8, 176u8, 209u8, 130u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8,
+                    208u8, 188u8, 208u8, 190u8, 208u8, 179u8, 209u8, 131u8, 209u8, 128u8, 208u8,
+                    188u8, 209u8, 131u8, 208u8, 186u8, 208u8, 184u8, 32u8, 208u8, 191u8, 208u8,
+                    184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 209u8, 133u8, 208u8, 176u8,
+                    208u8, 189u8, 208u8, 177u8, 209u8, 133u8, 208u8, 176u8, 208u8, 189u8, 208u8,
+                    179u8, 209u8, 131u8, 208u8, 187u8, 209u8, 133u8, 208u8, 176u8, 208u8, 189u8,
+                    209u8, 133u8, 208u8, 176u8, 208u8, 189u8, 209u8, 131u8, 208u8, 189u8, 208u8,
+                    190u8, 208u8, 191u8, 208u8, 190u8, 209u8, 152u8, 208u8, 181u8, 208u8, 180u8,
+                    208u8, 189u8, 208u8, 190u8, 209u8, 129u8, 209u8, 130u8, 208u8, 176u8, 208u8,
+                    178u8, 209u8, 153u8, 208u8, 181u8, 208u8, 189u8, 208u8, 190u8, 32u8, 208u8,
+                    186u8, 208u8, 184u8, 208u8, 189u8, 208u8, 181u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 190u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8,
+                    208u8, 190u8, 209u8, 130u8, 209u8, 128u8, 208u8, 176u8, 208u8, 180u8, 208u8,
+                    184u8, 209u8, 134u8, 208u8, 184u8, 208u8, 190u8, 208u8, 189u8, 208u8, 176u8,
+                    208u8, 187u8, 208u8, 189u8, 208u8, 190u8, 32u8, 208u8, 186u8, 208u8, 184u8,
+                    208u8, 189u8, 208u8, 181u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 32u8,
+                    208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 209u8,
+                    133u8, 208u8, 181u8, 208u8, 177u8, 209u8, 128u8, 208u8, 181u8, 209u8, 152u8,
+                    209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8, 184u8,
+                    209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 209u8, 133u8, 208u8, 184u8, 209u8,
+                    128u8, 208u8, 176u8, 208u8, 179u8, 208u8, 176u8, 208u8, 189u8, 208u8, 176u8,
+                    208u8, 191u8, 208u8, 176u8, 209u8, 133u8, 208u8, 176u8, 208u8, 178u8, 32u8,
+                    209u8, 133u8, 208u8, 188u8, 208u8, 190u8, 208u8, 189u8, 208u8, 179u8, 32u8,
+                    208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 209u8,
+                    152u8, 208u8, 176u8, 208u8, 191u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 176u8, 32u8, 209u8, 129u8, 208u8, 187u8, 208u8, 190u8,
+                    208u8, 179u8, 208u8, 190u8, 208u8, 178u8, 208u8, 189u8, 208u8, 176u8, 32u8,
+                    208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 176u8, 209u8,
+                    129u8, 209u8, 130u8, 208u8, 176u8, 209u8, 128u8, 208u8, 190u8, 208u8, 188u8,
+                    208u8, 176u8, 209u8, 146u8, 208u8, 176u8, 209u8, 128u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8,
+                    188u8, 208u8, 190u8, 208u8, 184u8, 208u8, 189u8, 208u8, 180u8, 209u8, 131u8,
+                    209u8, 136u8, 208u8, 186u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8, 184u8,
+                    209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 209u8, 129u8, 209u8, 130u8, 208u8,
+                    176u8, 209u8, 128u8, 208u8, 184u8, 32u8, 208u8, 184u8, 209u8, 130u8, 208u8,
+                    176u8, 208u8, 187u8, 208u8, 184u8, 208u8, 186u8, 209u8, 159u8, 208u8, 176u8,
+                    208u8, 188u8, 208u8, 190u8, 209u8, 152u8, 208u8, 176u8, 208u8, 178u8, 208u8,
+                    176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 32u8, 208u8,
+                    191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 209u8, 152u8,
+                    208u8, 176u8, 208u8, 191u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8,
+                    188u8, 208u8, 190u8, 208u8, 186u8, 208u8, 176u8, 209u8, 152u8, 208u8, 176u8,
+                    209u8, 133u8, 45u8, 208u8, 187u8, 208u8, 184u8, 32u8, 208u8, 191u8, 208u8,
+                    184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 208u8, 186u8, 208u8, 176u8,
+                    209u8, 130u8, 208u8, 176u8, 208u8, 186u8, 208u8, 176u8, 208u8, 189u8, 208u8,
+                    176u8, 208u8, 186u8, 208u8, 176u8, 209u8, 128u8, 208u8, 190u8, 209u8, 136u8,
+                    209u8, 130u8, 208u8, 184u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8,
+                    208u8, 188u8, 208u8, 190u8, 208u8, 186u8, 208u8, 188u8, 208u8, 181u8, 209u8,
+                    128u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8,
+                    184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 208u8, 186u8, 208u8, 176u8,
+                    208u8, 189u8, 208u8, 176u8, 208u8, 180u8, 208u8, 176u8, 32u8, 208u8, 191u8,
+                    208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 208u8, 186u8, 208u8,
+                    190u8, 209u8, 128u8, 208u8, 181u8, 209u8, 152u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 190u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8,
+                    208u8, 190u8, 208u8, 186u8, 208u8, 176u8, 208u8, 184u8, 209u8, 130u8, 208u8,
+                    184u8, 208u8, 187u8, 208u8, 176u8, 208u8, 189u8, 208u8, 189u8, 208u8, 176u8,
+                    32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8,
+                    208u8, 187u8, 208u8, 176u8, 208u8, 190u8, 209u8, 136u8, 208u8, 186u8, 208u8,
+                    190u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8,
+                    190u8, 208u8, 187u8, 208u8, 176u8, 209u8, 130u8, 208u8, 184u8, 208u8, 189u8,
+                    208u8, 184u8, 209u8, 134u8, 208u8, 176u8, 32u8, 40u8, 209u8, 132u8, 209u8,
+                    128u8, 208u8, 176u8, 208u8, 186u8, 209u8, 130u8, 209u8, 131u8, 209u8, 128u8,
+                    32u8, 208u8, 178u8, 208u8, 176u8, 209u8, 128u8, 208u8, 184u8, 209u8, 152u8,
+                    208u8, 176u8, 208u8, 189u8, 209u8, 130u8, 208u8, 176u8, 41u8, 208u8, 179u8,
+                    208u8, 176u8, 208u8, 187u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 32u8,
+                    208u8, 187u8, 208u8, 176u8, 209u8, 130u8, 208u8, 184u8, 208u8, 189u8, 208u8,
+                    184u8, 209u8, 134u8, 208u8, 176u8, 208u8, 187u8, 208u8, 176u8, 209u8, 130u8,
+                    208u8, 184u8, 208u8, 189u8, 208u8, 184u8, 209u8, 134u8, 208u8, 176u8, 208u8,
+                    187u8, 208u8, 181u8, 208u8, 191u8, 209u8, 135u8, 208u8, 176u8, 32u8, 208u8,
+                    191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 208u8, 187u8,
+                    208u8, 184u8, 208u8, 188u8, 208u8, 177u8, 209u8, 131u8, 32u8, 208u8, 191u8,
+                    208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 208u8, 187u8, 208u8,
+                    184u8, 208u8, 189u8, 208u8, 181u8, 208u8, 176u8, 209u8, 128u8, 208u8, 189u8,
+                    208u8, 190u8, 32u8, 208u8, 144u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8,
+                    129u8, 208u8, 188u8, 208u8, 190u8, 208u8, 187u8, 208u8, 184u8, 208u8, 189u8,
+                    208u8, 181u8, 208u8, 176u8, 209u8, 128u8, 208u8, 189u8, 208u8, 190u8, 32u8,
+                    208u8, 145u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8,
+                    208u8, 190u8, 208u8, 187u8, 208u8, 184u8, 209u8, 129u8, 208u8, 184u8, 209u8,
+                    152u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8,
+                    184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 208u8, 187u8, 208u8, 184u8,
+                    208u8, 180u8, 208u8, 184u8, 209u8, 152u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                    190u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8,
+                    190u8, 208u8, 188u8, 208u8, 176u8, 208u8, 189u8, 208u8, 180u8, 208u8, 181u8,
+                    208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 32u8,
+                    208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 208u8,
+                    188u8, 208u8, 176u8, 208u8, 189u8, 208u8, 184u8, 209u8, 133u8, 208u8, 181u8,
+                    209u8, 152u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 32u8, 208u8, 191u8,
+                    208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 208u8, 188u8, 208u8,
+                    176u8, 209u8, 152u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 184u8, 32u8, 209u8, 133u8, 208u8, 184u8, 209u8, 152u8, 208u8, 181u8,
+                    209u8, 128u8, 208u8, 190u8, 208u8, 179u8, 208u8, 187u8, 208u8, 184u8, 209u8,
+                    132u8, 208u8, 184u8, 208u8, 188u8, 208u8, 181u8, 209u8, 128u8, 208u8, 190u8,
+                    208u8, 184u8, 209u8, 130u8, 208u8, 184u8, 208u8, 186u8, 32u8, 208u8, 191u8,
+                    208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 208u8, 188u8, 208u8,
+                    176u8, 208u8, 187u8, 208u8, 176u8, 209u8, 152u8, 208u8, 176u8, 208u8, 187u8,
+                    208u8, 176u8, 208u8, 188u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 32u8,
+                    208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 208u8,
+                    188u8, 208u8, 190u8, 208u8, 189u8, 208u8, 179u8, 208u8, 190u8, 208u8, 187u8,
+                    209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8, 184u8,
+                    209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 208u8, 188u8, 208u8, 181u8, 209u8,
+                    129u8, 208u8, 181u8, 209u8, 135u8, 208u8, 181u8, 208u8, 178u8, 208u8, 190u8,
+                    32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8,
+                    208u8, 188u8, 208u8, 181u8, 208u8, 184u8, 209u8, 130u8, 208u8, 181u8, 208u8,
+                    184u8, 32u8, 208u8, 188u8, 208u8, 176u8, 209u8, 152u8, 208u8, 181u8, 208u8,
+                    186u8, 208u8, 188u8, 208u8, 184u8, 209u8, 152u8, 208u8, 176u8, 208u8, 189u8,
+                    208u8, 188u8, 208u8, 176u8, 209u8, 128u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                    190u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8,
+                    190u8, 208u8, 189u8, 208u8, 186u8, 208u8, 190u8, 208u8, 190u8, 208u8, 179u8,
+                    208u8, 176u8, 208u8, 188u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 32u8,
+                    208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 208u8,
+                    190u8, 208u8, 187u8, 32u8, 209u8, 135u8, 208u8, 184u8, 208u8, 186u8, 208u8,
+                    184u8, 208u8, 190u8, 209u8, 128u8, 208u8, 186u8, 208u8, 190u8, 208u8, 189u8,
+                    209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8, 184u8,
+                    209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 208u8, 190u8, 209u8, 128u8, 208u8,
+                    184u8, 209u8, 152u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 190u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8,
+                    208u8, 190u8, 208u8, 190u8, 209u8, 129u8, 208u8, 188u8, 208u8, 176u8, 209u8,
+                    154u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8,
+                    32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8,
+                    209u8, 129u8, 209u8, 130u8, 208u8, 176u8, 209u8, 128u8, 208u8, 190u8, 32u8,
+                    208u8, 191u8, 208u8, 181u8, 209u8, 128u8, 208u8, 188u8, 208u8, 184u8, 208u8,
+                    186u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8,
+                    184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 208u8, 191u8, 208u8, 176u8,
+                    208u8, 179u8, 209u8, 129u8, 45u8, 208u8, 191u8, 208u8, 176u8, 32u8, 208u8,
+                    191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 208u8, 191u8,
+                    208u8, 184u8, 209u8, 129u8, 208u8, 176u8, 208u8, 189u8, 208u8, 184u8, 32u8,
+                    208u8, 191u8, 208u8, 176u8, 209u8, 133u8, 208u8, 187u8, 208u8, 176u8, 208u8,
+                    178u8, 208u8, 184u8, 208u8, 191u8, 209u8, 129u8, 208u8, 176u8, 208u8, 187u8,
+                    209u8, 130u8, 208u8, 181u8, 209u8, 128u8, 32u8, 208u8, 191u8, 208u8, 176u8,
+                    209u8, 133u8, 208u8, 187u8, 208u8, 176u8, 208u8, 178u8, 208u8, 184u8, 208u8,
+                    191u8, 208u8, 176u8, 209u8, 133u8, 208u8, 187u8, 208u8, 176u8, 208u8, 178u8,
+                    208u8, 184u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8,
+                    208u8, 190u8, 208u8, 164u8, 208u8, 181u8, 208u8, 189u8, 208u8, 184u8, 209u8,
+                    135u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8,
+                    32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8,
+                    208u8, 191u8, 208u8, 190u8, 209u8, 128u8, 208u8, 176u8, 208u8, 187u8, 208u8,
+                    180u8, 32u8, 209u8, 132u8, 208u8, 190u8, 208u8, 189u8, 208u8, 181u8, 209u8,
+                    130u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8,
+                    184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 208u8, 191u8, 208u8, 184u8,
+                    209u8, 129u8, 208u8, 176u8, 208u8, 189u8, 208u8, 184u8, 32u8, 208u8, 191u8,
+                    208u8, 176u8, 209u8, 128u8, 209u8, 130u8, 208u8, 184u8, 208u8, 176u8, 208u8,
+                    189u8, 209u8, 128u8, 208u8, 181u8, 209u8, 152u8, 208u8, 176u8, 208u8, 189u8,
+                    208u8, 179u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8,
+                    208u8, 190u8, 209u8, 133u8, 208u8, 176u8, 208u8, 189u8, 208u8, 184u8, 209u8,
+                    132u8, 208u8, 184u8, 209u8, 128u8, 208u8, 190u8, 208u8, 189u8, 208u8, 179u8,
+                    208u8, 190u8, 209u8, 128u8, 208u8, 190u8, 208u8, 189u8, 208u8, 179u8, 208u8,
+                    190u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8,
+                    190u8, 209u8, 128u8, 209u8, 131u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 190u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8,
+                    208u8, 190u8, 209u8, 129u8, 208u8, 176u8, 208u8, 188u8, 208u8, 176u8, 209u8,
+                    128u8, 208u8, 184u8, 209u8, 130u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8,
+                    208u8, 188u8, 208u8, 190u8, 209u8, 129u8, 208u8, 176u8, 209u8, 128u8, 208u8,
+                    176u8, 209u8, 130u8, 208u8, 184u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8,
+                    129u8, 208u8, 188u8, 208u8, 190u8, 209u8, 129u8, 208u8, 176u8, 209u8, 131u8,
+                    209u8, 128u8, 208u8, 176u8, 209u8, 136u8, 209u8, 130u8, 209u8, 128u8, 208u8,
+                    176u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8,
+                    190u8, 208u8, 183u8, 208u8, 189u8, 208u8, 176u8, 208u8, 186u8, 208u8, 190u8,
+                    208u8, 178u8, 208u8, 189u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8, 184u8,
+                    209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 209u8, 136u8, 208u8, 176u8, 208u8,
+                    178u8, 208u8, 184u8, 209u8, 152u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8,
+                    208u8, 186u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8,
+                    208u8, 188u8, 208u8, 190u8, 209u8, 129u8, 208u8, 184u8, 208u8, 189u8, 209u8,
+                    133u8, 208u8, 176u8, 208u8, 187u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8,
+                    32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8,
+                    209u8, 129u8, 209u8, 131u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 208u8,
+                    189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8,
+                    184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 209u8, 129u8, 208u8, 184u8,
+                    208u8, 187u8, 208u8, 190u8, 209u8, 130u8, 208u8, 184u8, 32u8, 208u8, 189u8,
+                    208u8, 176u8, 208u8, 179u8, 209u8, 128u8, 208u8, 184u8, 32u8, 208u8, 191u8,
+                    208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 209u8, 129u8, 208u8,
+                    184u8, 209u8, 128u8, 208u8, 184u8, 209u8, 152u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 190u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8,
+                    208u8, 190u8, 209u8, 129u8, 208u8, 184u8, 209u8, 128u8, 208u8, 184u8, 209u8,
+                    152u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 32u8, 208u8, 181u8, 209u8,
+                    129u8, 209u8, 130u8, 209u8, 128u8, 208u8, 176u8, 208u8, 189u8, 208u8, 179u8,
+                    208u8, 181u8, 208u8, 187u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8, 184u8,
+                    209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 208u8, 183u8, 208u8, 176u8, 208u8,
+                    191u8, 208u8, 176u8, 208u8, 180u8, 208u8, 189u8, 208u8, 190u8, 209u8, 129u8,
+                    208u8, 184u8, 209u8, 128u8, 208u8, 184u8, 209u8, 152u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8,
+                    188u8, 208u8, 190u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8,
+                    208u8, 190u8, 32u8, 208u8, 184u8, 209u8, 129u8, 209u8, 130u8, 208u8, 190u8,
+                    209u8, 135u8, 208u8, 189u8, 208u8, 181u8, 32u8, 208u8, 161u8, 208u8, 184u8,
+                    209u8, 128u8, 208u8, 184u8, 209u8, 152u8, 208u8, 181u8, 209u8, 130u8, 208u8,
+                    176u8, 208u8, 179u8, 208u8, 177u8, 208u8, 176u8, 208u8, 189u8, 208u8, 178u8,
+                    208u8, 176u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8,
+                    208u8, 190u8, 209u8, 130u8, 208u8, 176u8, 208u8, 184u8, 32u8, 208u8, 187u8,
+                    208u8, 181u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8,
+                    208u8, 190u8, 208u8, 189u8, 208u8, 190u8, 208u8, 178u8, 208u8, 184u8, 32u8,
+                    209u8, 130u8, 208u8, 176u8, 208u8, 184u8, 32u8, 208u8, 187u8, 209u8, 131u8,
+                    208u8, 181u8, 209u8, 130u8, 208u8, 176u8, 208u8, 188u8, 208u8, 184u8, 208u8,
+                    187u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8,
+                    184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 209u8, 130u8, 208u8, 176u8,
+                    208u8, 184u8, 32u8, 208u8, 178u8, 208u8, 184u8, 208u8, 181u8, 209u8, 130u8,
+                    32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8,
+                    209u8, 130u8, 208u8, 181u8, 208u8, 187u8, 209u8, 131u8, 208u8, 179u8, 209u8,
+                    131u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8,
+                    190u8, 209u8, 130u8, 208u8, 181u8, 208u8, 189u8, 208u8, 179u8, 208u8, 178u8,
+                    208u8, 176u8, 209u8, 128u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8,
+                    208u8, 188u8, 208u8, 190u8, 209u8, 130u8, 208u8, 184u8, 209u8, 132u8, 208u8,
+                    184u8, 208u8, 189u8, 208u8, 176u8, 208u8, 179u8, 208u8, 162u8, 208u8, 176u8,
+                    208u8, 179u8, 208u8, 176u8, 208u8, 187u8, 208u8, 190u8, 208u8, 179u8, 209u8,
+                    130u8, 208u8, 176u8, 208u8, 189u8, 208u8, 176u8, 32u8, 208u8, 191u8, 208u8,
+                    184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 209u8, 130u8, 208u8, 176u8,
+                    209u8, 152u8, 208u8, 187u8, 208u8, 176u8, 208u8, 189u8, 208u8, 180u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8,
+                    129u8, 208u8, 188u8, 208u8, 190u8, 209u8, 130u8, 208u8, 184u8, 208u8, 177u8,
+                    208u8, 181u8, 209u8, 130u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8,
+                    188u8, 208u8, 190u8, 209u8, 131u8, 208u8, 179u8, 208u8, 176u8, 209u8, 128u8,
+                    208u8, 184u8, 209u8, 130u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 32u8,
+                    208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 208u8,
+                    178u8, 208u8, 176u8, 208u8, 184u8, 208u8, 178u8, 208u8, 184u8, 208u8, 180u8,
+                    209u8, 153u8, 208u8, 184u8, 208u8, 178u8, 208u8, 184u8, 32u8, 208u8, 179u8,
+                    208u8, 190u8, 208u8, 178u8, 208u8, 190u8, 209u8, 128u8, 209u8, 129u8, 209u8,
+                    130u8, 208u8, 176u8, 209u8, 128u8, 208u8, 190u8, 208u8, 191u8, 208u8, 181u8,
+                    209u8, 128u8, 209u8, 129u8, 208u8, 184u8, 209u8, 152u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8,
+                    188u8, 208u8, 190u8, 209u8, 129u8, 209u8, 131u8, 208u8, 188u8, 208u8, 181u8,
+                    209u8, 128u8, 209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 45u8, 208u8, 176u8,
+                    208u8, 186u8, 208u8, 176u8, 208u8, 180u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                    190u8, 32u8, 208u8, 186u8, 209u8, 131u8, 208u8, 189u8, 208u8, 181u8, 208u8,
+                    184u8, 209u8, 132u8, 208u8, 190u8, 209u8, 128u8, 208u8, 188u8, 32u8, 208u8,
+                    191u8, 208u8, 184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 209u8, 152u8,
+                    208u8, 184u8, 208u8, 189u8, 208u8, 176u8, 209u8, 129u8, 208u8, 187u8, 208u8,
+                    181u8, 208u8, 180u8, 208u8, 189u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8,
+                    184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 208u8, 188u8, 208u8, 176u8,
+                    209u8, 130u8, 208u8, 181u8, 208u8, 188u8, 208u8, 176u8, 209u8, 130u8, 208u8,
+                    184u8, 209u8, 135u8, 208u8, 186u8, 208u8, 176u8, 32u8, 208u8, 189u8, 208u8,
+                    190u8, 209u8, 130u8, 208u8, 176u8, 209u8, 134u8, 208u8, 184u8, 209u8, 152u8,
+                    208u8, 176u8, 208u8, 181u8, 208u8, 188u8, 208u8, 190u8, 209u8, 159u8, 208u8,
+                    184u8, 209u8, 129u8, 208u8, 184u8, 208u8, 188u8, 208u8, 177u8, 208u8, 190u8,
+                    208u8, 187u8, 208u8, 184u8, 208u8, 189u8, 208u8, 181u8, 208u8, 191u8, 208u8,
+                    184u8, 209u8, 129u8, 208u8, 176u8, 208u8, 189u8, 208u8, 184u8, 32u8, 209u8,
+                    152u8, 208u8, 181u8, 208u8, 183u8, 208u8, 184u8, 208u8, 186u8, 208u8, 183u8,
+                    208u8, 176u8, 209u8, 152u8, 208u8, 181u8, 208u8, 180u8, 208u8, 189u8, 208u8,
+                    184u8, 209u8, 135u8, 208u8, 186u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8,
+                    184u8, 209u8, 129u8, 208u8, 188u8, 208u8, 190u8, 208u8, 189u8, 208u8, 181u8,
+                    208u8, 191u8, 208u8, 190u8, 208u8, 183u8, 208u8, 189u8, 208u8, 176u8, 209u8,
+                    130u8, 208u8, 190u8, 32u8, 208u8, 191u8, 208u8, 184u8, 209u8, 129u8, 208u8,
+                    188u8, 208u8, 190u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+};
+static TH: &DataStruct = &::icu_displaynames::provider::ScriptDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 100u8, 108u8, 109u8, 65u8, 102u8, 97u8, 107u8, 65u8, 103u8, 104u8, 98u8,
+                    65u8, 114u8, 97u8, 98u8, 65u8, 114u8, 97u8, 110u8, 65u8, 114u8, 109u8, 105u8,
+                    65u8, 114u8, 109u8, 110u8, 65u8, 118u8, 115u8, 116u8, 66u8, 97u8, 108u8, 105u8,
+                    66u8, 97u8, 109u8, 117u8, 66u8, 97u8, 115u8, 115u8, 66u8, 97u8, 116u8, 107u8,
+                    66u8, 101u8, 110u8, 103u8, 66u8, 108u8, 105u8, 115u8, 66u8, 111u8, 112u8,
+                    111u8, 66u8, 114u8, 97u8, 104u8, 66u8, 114u8, 97u8, 105u8, 66u8, 117u8, 103u8,
+                    105u8, 66u8, 117u8, 104u8, 100u8, 67u8, 97u8, 107u8, 109u8, 67u8, 97u8, 110u8,
+                    115u8, 67u8, 97u8, 114u8, 105u8, 67u8, 104u8, 97u8, 109u8, 67u8, 104u8, 101u8,
+                    114u8, 67u8, 105u8, 114u8, 116u8, 67u8, 111u8, 112u8, 116u8, 67u8, 112u8,
+                    114u8, 116u8, 67u8, 121u8, 114u8, 108u8, 67u8, 121u8, 114u8, 115u8, 68u8,
+                    101u8, 118u8, 97u8, 68u8, 115u8, 114u8, 116u8, 68u8, 117u8, 112u8, 108u8, 69u8,
+                    103u8, 121u8, 100u8, 69u8, 103u8, 121u8, 104u8, 69u8, 103u8, 121u8, 112u8,
+                    69u8, 108u8, 98u8, 97u8, 69u8, 116u8, 104u8, 105u8, 71u8, 101u8, 111u8, 107u8,
+                    71u8, 101u8, 111u8, 114u8, 71u8, 108u8, 97u8, 103u8, 71u8, 111u8, 116u8, 104u8,
+                    71u8, 114u8, 97u8, 110u8, 71u8, 114u8, 101u8, 107u8, 71u8, 117u8, 106u8, 114u8,
+                    71u8, 117u8, 114u8, 117u8, 72u8, 97u8, 110u8, 98u8, 72u8, 97u8, 110u8, 103u8,
+                    72u8, 97u8, 110u8, 105u8, 72u8, 97u8, 110u8, 111u8, 72u8, 97u8, 110u8, 115u8,
+                    72u8, 97u8, 110u8, 116u8, 72u8, 101u8, 98u8, 114u8, 72u8, 105u8, 114u8, 97u8,
+                    72u8, 108u8, 117u8, 119u8, 72u8, 109u8, 110u8, 103u8, 72u8, 114u8, 107u8,
+                    116u8, 72u8, 117u8, 110u8, 103u8, 73u8, 110u8, 100u8, 115u8, 73u8, 116u8, 97u8,
+                    108u8, 74u8, 97u8, 109u8, 111u8, 74u8, 97u8, 118u8, 97u8, 74u8, 112u8, 97u8,
+                    110u8, 74u8, 117u8, 114u8, 99u8, 75u8, 97u8, 108u8, 105u8, 75u8, 97u8, 110u8,
+                    97u8, 75u8, 104u8, 97u8, 114u8, 75u8, 104u8, 109u8, 114u8, 75u8, 104u8, 111u8,
+                    106u8, 75u8, 110u8, 100u8, 97u8, 75u8, 111u8, 114u8, 101u8, 75u8, 112u8, 101u8,
+                    108u8, 75u8, 116u8, 104u8, 105u8, 76u8, 97u8, 110u8, 97u8, 76u8, 97u8, 111u8,
+                    111u8, 76u8, 97u8, 116u8, 102u8, 76u8, 97u8, 116u8, 103u8, 76u8, 97u8, 116u8,
+                    110u8, 76u8, 101u8, 112u8, 99u8, 76u8, 105u8, 109u8, 98u8, 76u8, 105u8, 110u8,
+                    97u8, 76u8, 105u8, 110u8, 98u8, 76u8, 105u8, 115u8, 117u8, 76u8, 111u8, 109u8,
+                    97u8, 76u8, 121u8, 99u8, 105u8, 76u8, 121u8, 100u8, 105u8, 77u8, 97u8, 104u8,
+                    106u8, 77u8, 97u8, 110u8, 100u8, 77u8, 97u8, 110u8, 105u8, 77u8, 97u8, 121u8,
+                    97u8, 77u8, 101u8, 110u8, 100u8, 77u8, 101u8, 114u8, 99u8, 77u8, 101u8, 114u8,
+                    111u8, 77u8, 108u8, 121u8, 109u8, 77u8, 111u8, 100u8, 105u8, 77u8, 111u8,
+                    110u8, 103u8, 77u8, 111u8, 111u8, 110u8, 77u8, 114u8, 111u8, 111u8, 77u8,
+                    116u8, 101u8, 105u8, 77u8, 121u8, 109u8, 114u8, 78u8, 97u8, 114u8, 98u8, 78u8,
+                    98u8, 97u8, 116u8, 78u8, 107u8, 103u8, 98u8, 78u8, 107u8, 111u8, 111u8, 78u8,
+                    115u8, 104u8, 117u8, 79u8, 103u8, 97u8, 109u8, 79u8, 108u8, 99u8, 107u8, 79u8,
+                    114u8, 107u8, 104u8, 79u8, 114u8, 121u8, 97u8, 79u8, 115u8, 109u8, 97u8, 80u8,
+                    97u8, 108u8, 109u8, 80u8, 97u8, 117u8, 99u8, 80u8, 101u8, 114u8, 109u8, 80u8,
+                    104u8, 97u8, 103u8, 80u8, 104u8, 108u8, 105u8, 80u8, 104u8, 108u8, 112u8, 80u8,
+                    104u8, 108u8, 118u8, 80u8, 104u8, 110u8, 120u8, 80u8, 108u8, 114u8, 100u8,
+                    80u8, 114u8, 116u8, 105u8, 82u8, 106u8, 110u8, 103u8, 82u8, 111u8, 104u8,
+                    103u8, 82u8, 111u8, 114u8, 111u8, 82u8, 117u8, 110u8, 114u8, 83u8, 97u8, 109u8,
+                    114u8, 83u8, 97u8, 114u8, 97u8, 83u8, 97u8, 114u8, 98u8, 83u8, 97u8, 117u8,
+                    114u8, 83u8, 103u8, 110u8, 119u8, 83u8, 104u8, 97u8, 119u8, 83u8, 104u8, 114u8,
+                    100u8, 83u8, 105u8, 100u8, 100u8, 83u8, 105u8, 110u8, 100u8, 83u8, 105u8,
+                    110u8, 104u8, 83u8, 111u8, 114u8, 97u8, 83u8, 117u8, 110u8, 100u8, 83u8, 121u8,
+                    108u8, 111u8, 83u8, 121u8, 114u8, 99u8, 83u8, 121u8, 114u8, 101u8, 83u8, 121u8,
+                    114u8, 106u8, 83u8, 121u8, 114u8, 110u8, 84u8, 97u8, 103u8, 98u8, 84u8, 97u8,
+                    107u8, 114u8, 84u8, 97u8, 108u8, 101u8, 84u8, 97u8, 108u8, 117u8, 84u8, 97u8,
+                    109u8, 108u8, 84u8, 97u8, 110u8, 103u8, 84u8, 97u8, 118u8, 116u8, 84u8, 101u8,
+                    108u8, 117u8, 84u8, 101u8, 110u8, 103u8, 84u8, 102u8, 110u8, 103u8, 84u8,
+                    103u8, 108u8, 103u8, 84u8, 104u8, 97u8, 97u8, 84u8, 104u8, 97u8, 105u8, 84u8,
+                    105u8, 98u8, 116u8, 84u8, 105u8, 114u8, 104u8, 85u8, 103u8, 97u8, 114u8, 86u8,
+                    97u8, 105u8, 105u8, 86u8, 105u8, 115u8, 112u8, 87u8, 97u8, 114u8, 97u8, 87u8,
+                    111u8, 108u8, 101u8, 88u8, 112u8, 101u8, 111u8, 88u8, 115u8, 117u8, 120u8,
+                    89u8, 105u8, 105u8, 105u8, 90u8, 105u8, 110u8, 104u8, 90u8, 109u8, 116u8,
+                    104u8, 90u8, 115u8, 121u8, 101u8, 90u8, 115u8, 121u8, 109u8, 90u8, 120u8,
+                    120u8, 120u8, 90u8, 121u8, 121u8, 121u8, 90u8, 122u8, 122u8, 122u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    170u8, 0u8, 0u8, 0u8, 0u8, 0u8, 18u8, 0u8, 36u8, 0u8, 88u8, 0u8, 106u8, 0u8,
+                    130u8, 0u8, 185u8, 0u8, 215u8, 0u8, 233u8, 0u8, 248u8, 0u8, 7u8, 1u8, 22u8,
+                    1u8, 37u8, 1u8, 58u8, 1u8, 94u8, 1u8, 118u8, 1u8, 136u8, 1u8, 154u8, 1u8,
+                    169u8, 1u8, 184u8, 1u8, 202u8, 1u8, 48u8, 2u8, 66u8, 2u8, 75u8, 2u8, 96u8, 2u8,
+                    114u8, 2u8, 132u8, 2u8, 156u8, 2u8, 180u8, 2u8, 5u8, 3u8, 29u8, 3u8, 50u8, 3u8,
+                    89u8, 3u8, 131u8, 3u8, 179u8, 3u8, 236u8, 3u8, 4u8, 4u8, 31u8, 4u8, 76u8, 4u8,
+                    100u8, 4u8, 130u8, 4u8, 145u8, 4u8, 160u8, 4u8, 172u8, 4u8, 190u8, 4u8, 214u8,
+                    4u8, 235u8, 4u8, 253u8, 4u8, 9u8, 5u8, 33u8, 5u8, 51u8, 5u8, 72u8, 5u8, 87u8,
+                    5u8, 111u8, 5u8, 159u8, 5u8, 189u8, 5u8, 249u8, 5u8, 29u8, 6u8, 47u8, 6u8,
+                    80u8, 6u8, 92u8, 6u8, 101u8, 6u8, 122u8, 6u8, 143u8, 6u8, 158u8, 6u8, 182u8,
+                    6u8, 200u8, 6u8, 212u8, 6u8, 227u8, 6u8, 248u8, 6u8, 10u8, 7u8, 25u8, 7u8,
+                    40u8, 7u8, 58u8, 7u8, 67u8, 7u8, 112u8, 7u8, 145u8, 7u8, 160u8, 7u8, 175u8,
+                    7u8, 190u8, 7u8, 220u8, 7u8, 250u8, 7u8, 18u8, 8u8, 30u8, 8u8, 48u8, 8u8, 66u8,
+                    8u8, 84u8, 8u8, 108u8, 8u8, 129u8, 8u8, 171u8, 8u8, 186u8, 8u8, 238u8, 8u8,
+                    3u8, 9u8, 30u8, 9u8, 42u8, 9u8, 69u8, 9u8, 78u8, 9u8, 87u8, 9u8, 114u8, 9u8,
+                    126u8, 9u8, 180u8, 9u8, 213u8, 9u8, 238u8, 9u8, 0u8, 10u8, 12u8, 10u8, 27u8,
+                    10u8, 48u8, 10u8, 69u8, 10u8, 87u8, 10u8, 111u8, 10u8, 135u8, 10u8, 162u8,
+                    10u8, 201u8, 10u8, 223u8, 10u8, 33u8, 11u8, 81u8, 11u8, 117u8, 11u8, 141u8,
+                    11u8, 192u8, 11u8, 2u8, 12u8, 17u8, 12u8, 35u8, 12u8, 65u8, 12u8, 80u8, 12u8,
+                    104u8, 12u8, 122u8, 12u8, 170u8, 12u8, 197u8, 12u8, 227u8, 12u8, 248u8, 12u8,
+                    10u8, 13u8, 28u8, 13u8, 52u8, 13u8, 67u8, 13u8, 97u8, 13u8, 112u8, 13u8, 145u8,
+                    13u8, 163u8, 13u8, 214u8, 13u8, 253u8, 13u8, 39u8, 14u8, 63u8, 14u8, 78u8,
+                    14u8, 90u8, 14u8, 120u8, 14u8, 132u8, 14u8, 150u8, 14u8, 171u8, 14u8, 189u8,
+                    14u8, 213u8, 14u8, 234u8, 14u8, 2u8, 15u8, 14u8, 15u8, 23u8, 15u8, 38u8, 15u8,
+                    59u8, 15u8, 80u8, 15u8, 86u8, 15u8, 140u8, 15u8, 170u8, 15u8, 188u8, 15u8,
+                    230u8, 15u8, 62u8, 16u8, 68u8, 16u8, 95u8, 16u8, 167u8, 16u8, 185u8, 16u8,
+                    212u8, 16u8, 254u8, 16u8, 13u8, 17u8, 224u8, 184u8, 173u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 148u8, 224u8, 184u8, 165u8, 224u8, 184u8, 177u8, 224u8, 184u8,
+                    161u8, 224u8, 184u8, 173u8, 224u8, 184u8, 176u8, 224u8, 184u8, 159u8, 224u8,
+                    184u8, 178u8, 224u8, 184u8, 132u8, 224u8, 184u8, 178u8, 224u8, 185u8, 129u8,
+                    224u8, 184u8, 173u8, 224u8, 184u8, 165u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    154u8, 224u8, 185u8, 128u8, 224u8, 184u8, 153u8, 224u8, 184u8, 181u8, 224u8,
+                    184u8, 162u8, 32u8, 224u8, 184u8, 132u8, 224u8, 184u8, 173u8, 224u8, 185u8,
+                    128u8, 224u8, 184u8, 132u8, 224u8, 185u8, 128u8, 224u8, 184u8, 139u8, 224u8,
+                    184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 173u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 171u8, 224u8, 184u8, 163u8, 224u8, 184u8, 177u8, 224u8, 184u8,
+                    154u8, 224u8, 184u8, 153u8, 224u8, 184u8, 178u8, 224u8, 184u8, 170u8, 224u8,
+                    184u8, 149u8, 224u8, 184u8, 178u8, 224u8, 184u8, 165u8, 224u8, 184u8, 180u8,
+                    224u8, 184u8, 129u8, 224u8, 184u8, 173u8, 224u8, 184u8, 180u8, 224u8, 184u8,
+                    161u8, 224u8, 184u8, 158u8, 224u8, 184u8, 181u8, 224u8, 185u8, 128u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 165u8,
+                    32u8, 224u8, 184u8, 173u8, 224u8, 184u8, 163u8, 224u8, 184u8, 178u8, 224u8,
+                    185u8, 128u8, 224u8, 184u8, 161u8, 224u8, 184u8, 173u8, 224u8, 184u8, 180u8,
+                    224u8, 184u8, 129u8, 224u8, 184u8, 173u8, 224u8, 184u8, 178u8, 224u8, 184u8,
+                    163u8, 224u8, 185u8, 140u8, 224u8, 185u8, 128u8, 224u8, 184u8, 161u8, 224u8,
+                    185u8, 128u8, 224u8, 184u8, 153u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8,
+                    224u8, 184u8, 173u8, 224u8, 185u8, 128u8, 224u8, 184u8, 167u8, 224u8, 184u8,
+                    170u8, 224u8, 184u8, 149u8, 224u8, 184u8, 176u8, 224u8, 184u8, 154u8, 224u8,
+                    184u8, 178u8, 224u8, 184u8, 171u8, 224u8, 184u8, 165u8, 224u8, 184u8, 181u8,
+                    224u8, 184u8, 154u8, 224u8, 184u8, 178u8, 224u8, 184u8, 161u8, 224u8, 184u8,
+                    184u8, 224u8, 184u8, 161u8, 224u8, 184u8, 154u8, 224u8, 184u8, 177u8, 224u8,
+                    184u8, 170u8, 224u8, 184u8, 139u8, 224u8, 184u8, 178u8, 224u8, 184u8, 154u8,
+                    224u8, 184u8, 178u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8, 224u8, 184u8,
+                    129u8, 224u8, 185u8, 128u8, 224u8, 184u8, 154u8, 224u8, 184u8, 135u8, 224u8,
+                    184u8, 129u8, 224u8, 184u8, 178u8, 224u8, 184u8, 165u8, 224u8, 184u8, 181u8,
+                    224u8, 184u8, 154u8, 224u8, 184u8, 165u8, 224u8, 184u8, 180u8, 224u8, 184u8,
+                    170u8, 224u8, 184u8, 139u8, 224u8, 184u8, 180u8, 224u8, 184u8, 161u8, 224u8,
+                    185u8, 130u8, 224u8, 184u8, 154u8, 224u8, 184u8, 165u8, 224u8, 184u8, 170u8,
+                    224u8, 185u8, 140u8, 224u8, 184u8, 155u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                    158u8, 224u8, 184u8, 173u8, 224u8, 184u8, 161u8, 224u8, 184u8, 173u8, 224u8,
+                    184u8, 159u8, 224u8, 184u8, 173u8, 224u8, 184u8, 158u8, 224u8, 184u8, 163u8,
+                    224u8, 184u8, 178u8, 224u8, 184u8, 171u8, 224u8, 184u8, 161u8, 224u8, 184u8,
+                    181u8, 224u8, 185u8, 128u8, 224u8, 184u8, 154u8, 224u8, 184u8, 163u8, 224u8,
+                    184u8, 165u8, 224u8, 184u8, 165u8, 224u8, 185u8, 140u8, 224u8, 184u8, 154u8,
+                    224u8, 184u8, 185u8, 224u8, 184u8, 129u8, 224u8, 184u8, 180u8, 224u8, 184u8,
+                    170u8, 224u8, 184u8, 154u8, 224u8, 184u8, 185u8, 224u8, 184u8, 174u8, 224u8,
+                    184u8, 180u8, 224u8, 184u8, 148u8, 224u8, 184u8, 136u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 129u8, 224u8, 184u8, 161u8, 224u8, 185u8, 136u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 173u8, 224u8, 184u8, 177u8, 224u8, 184u8, 129u8, 224u8,
+                    184u8, 169u8, 224u8, 184u8, 163u8, 224u8, 184u8, 158u8, 224u8, 184u8, 162u8,
+                    224u8, 184u8, 178u8, 224u8, 184u8, 135u8, 224u8, 184u8, 132u8, 224u8, 185u8,
+                    140u8, 224u8, 184u8, 130u8, 224u8, 184u8, 173u8, 224u8, 184u8, 135u8, 224u8,
+                    184u8, 138u8, 224u8, 184u8, 178u8, 224u8, 184u8, 167u8, 224u8, 184u8, 173u8,
+                    224u8, 184u8, 176u8, 224u8, 184u8, 154u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                    163u8, 224u8, 184u8, 180u8, 224u8, 184u8, 136u8, 224u8, 184u8, 180u8, 224u8,
+                    184u8, 153u8, 224u8, 185u8, 131u8, 224u8, 184u8, 153u8, 224u8, 185u8, 129u8,
+                    224u8, 184u8, 132u8, 224u8, 184u8, 153u8, 224u8, 184u8, 178u8, 224u8, 184u8,
+                    148u8, 224u8, 184u8, 178u8, 224u8, 184u8, 132u8, 224u8, 184u8, 178u8, 224u8,
+                    185u8, 128u8, 224u8, 184u8, 163u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8,
+                    224u8, 184u8, 136u8, 224u8, 184u8, 178u8, 224u8, 184u8, 161u8, 224u8, 185u8,
+                    128u8, 224u8, 184u8, 138u8, 224u8, 184u8, 173u8, 224u8, 185u8, 130u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 129u8, 224u8, 184u8, 181u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 139u8, 224u8, 184u8, 180u8, 224u8, 184u8, 163u8, 224u8, 185u8,
+                    140u8, 224u8, 184u8, 151u8, 224u8, 184u8, 132u8, 224u8, 184u8, 173u8, 224u8,
+                    184u8, 155u8, 224u8, 184u8, 149u8, 224u8, 184u8, 180u8, 224u8, 184u8, 129u8,
+                    224u8, 185u8, 132u8, 224u8, 184u8, 139u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    155u8, 224u8, 184u8, 163u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8,
+                    184u8, 151u8, 224u8, 184u8, 139u8, 224u8, 184u8, 181u8, 224u8, 184u8, 163u8,
+                    224u8, 184u8, 180u8, 224u8, 184u8, 165u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    180u8, 224u8, 184u8, 129u8, 224u8, 185u8, 128u8, 224u8, 184u8, 138u8, 224u8,
+                    184u8, 173u8, 224u8, 184u8, 163u8, 224u8, 185u8, 140u8, 224u8, 184u8, 138u8,
+                    224u8, 184u8, 170u8, 224u8, 184u8, 165u8, 224u8, 184u8, 178u8, 224u8, 185u8,
+                    130u8, 224u8, 184u8, 167u8, 224u8, 184u8, 153u8, 224u8, 184u8, 180u8, 224u8,
+                    184u8, 129u8, 224u8, 184u8, 139u8, 224u8, 184u8, 181u8, 224u8, 184u8, 163u8,
+                    224u8, 184u8, 180u8, 224u8, 184u8, 165u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    180u8, 224u8, 184u8, 129u8, 224u8, 185u8, 130u8, 224u8, 184u8, 154u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 178u8, 224u8, 184u8, 147u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 151u8, 224u8, 184u8, 167u8, 224u8, 184u8, 153u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 132u8, 224u8, 184u8, 163u8, 224u8, 184u8, 181u8, 224u8,
+                    185u8, 128u8, 224u8, 184u8, 148u8, 224u8, 185u8, 128u8, 224u8, 184u8, 139u8,
+                    224u8, 185u8, 128u8, 224u8, 184u8, 163u8, 224u8, 184u8, 151u8, 224u8, 184u8,
+                    138u8, 224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8,
+                    184u8, 130u8, 224u8, 184u8, 148u8, 224u8, 184u8, 177u8, 224u8, 184u8, 155u8,
+                    224u8, 185u8, 130u8, 224u8, 184u8, 165u8, 224u8, 184u8, 162u8, 224u8, 184u8,
+                    177u8, 224u8, 184u8, 153u8, 224u8, 184u8, 148u8, 224u8, 184u8, 181u8, 224u8,
+                    185u8, 130u8, 224u8, 184u8, 161u8, 224u8, 184u8, 149u8, 224u8, 184u8, 180u8,
+                    224u8, 184u8, 129u8, 224u8, 184u8, 173u8, 224u8, 184u8, 181u8, 224u8, 184u8,
+                    162u8, 224u8, 184u8, 180u8, 224u8, 184u8, 155u8, 224u8, 184u8, 149u8, 224u8,
+                    185u8, 140u8, 224u8, 185u8, 128u8, 224u8, 184u8, 174u8, 224u8, 184u8, 181u8,
+                    224u8, 184u8, 162u8, 224u8, 184u8, 163u8, 224u8, 184u8, 178u8, 224u8, 184u8,
+                    149u8, 224u8, 184u8, 180u8, 224u8, 184u8, 129u8, 224u8, 184u8, 173u8, 224u8,
+                    184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 180u8, 224u8, 184u8, 155u8,
+                    224u8, 184u8, 149u8, 224u8, 185u8, 140u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    174u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 185u8, 130u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 129u8, 224u8, 184u8, 165u8, 224u8, 184u8, 180u8,
+                    224u8, 184u8, 159u8, 224u8, 184u8, 170u8, 224u8, 185u8, 140u8, 224u8, 184u8,
+                    173u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 180u8, 224u8,
+                    184u8, 155u8, 224u8, 184u8, 149u8, 224u8, 185u8, 140u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 173u8, 224u8, 184u8, 165u8, 224u8, 184u8, 154u8, 224u8, 185u8,
+                    140u8, 224u8, 184u8, 139u8, 224u8, 184u8, 178u8, 224u8, 184u8, 153u8, 224u8,
+                    185u8, 128u8, 224u8, 184u8, 173u8, 224u8, 184u8, 152u8, 224u8, 184u8, 180u8,
+                    224u8, 185u8, 130u8, 224u8, 184u8, 173u8, 224u8, 184u8, 155u8, 224u8, 184u8,
+                    180u8, 224u8, 184u8, 129u8, 224u8, 184u8, 132u8, 224u8, 184u8, 177u8, 224u8,
+                    184u8, 149u8, 224u8, 184u8, 139u8, 224u8, 184u8, 185u8, 224u8, 184u8, 163u8,
+                    224u8, 184u8, 181u8, 224u8, 184u8, 136u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                    163u8, 224u8, 185u8, 140u8, 224u8, 185u8, 128u8, 224u8, 184u8, 136u8, 224u8,
+                    184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 136u8, 224u8, 184u8, 173u8,
+                    224u8, 184u8, 163u8, 224u8, 185u8, 140u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    136u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 129u8, 224u8,
+                    184u8, 165u8, 224u8, 184u8, 178u8, 224u8, 185u8, 130u8, 224u8, 184u8, 129u8,
+                    224u8, 184u8, 165u8, 224u8, 184u8, 180u8, 224u8, 184u8, 149u8, 224u8, 184u8,
+                    180u8, 224u8, 184u8, 129u8, 224u8, 185u8, 130u8, 224u8, 184u8, 129u8, 224u8,
+                    184u8, 152u8, 224u8, 184u8, 180u8, 224u8, 184u8, 129u8, 224u8, 184u8, 132u8,
+                    224u8, 184u8, 164u8, 224u8, 184u8, 147u8, 224u8, 184u8, 171u8, 224u8, 185u8,
+                    140u8, 224u8, 184u8, 129u8, 224u8, 184u8, 163u8, 224u8, 184u8, 181u8, 224u8,
+                    184u8, 129u8, 224u8, 184u8, 132u8, 224u8, 184u8, 184u8, 224u8, 184u8, 138u8,
+                    224u8, 184u8, 163u8, 224u8, 184u8, 178u8, 224u8, 184u8, 149u8, 224u8, 184u8,
+                    129u8, 224u8, 184u8, 185u8, 224u8, 184u8, 163u8, 224u8, 185u8, 140u8, 224u8,
+                    184u8, 161u8, 224u8, 184u8, 185u8, 224u8, 184u8, 132u8, 224u8, 184u8, 181u8,
+                    224u8, 184u8, 136u8, 224u8, 184u8, 181u8, 224u8, 184u8, 153u8, 224u8, 184u8,
+                    129u8, 224u8, 184u8, 165u8, 224u8, 184u8, 178u8, 224u8, 184u8, 135u8, 224u8,
+                    184u8, 174u8, 224u8, 184u8, 177u8, 224u8, 184u8, 153u8, 224u8, 184u8, 129u8,
+                    224u8, 184u8, 182u8, 224u8, 184u8, 165u8, 224u8, 184u8, 174u8, 224u8, 184u8,
+                    177u8, 224u8, 185u8, 136u8, 224u8, 184u8, 153u8, 224u8, 184u8, 174u8, 224u8,
+                    184u8, 178u8, 224u8, 184u8, 153u8, 224u8, 184u8, 185u8, 224u8, 185u8, 130u8,
+                    224u8, 184u8, 153u8, 224u8, 185u8, 130u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                    149u8, 224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 184u8, 162u8, 224u8,
+                    185u8, 136u8, 224u8, 184u8, 173u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 167u8, 224u8, 185u8, 128u8, 224u8, 184u8, 149u8, 224u8, 185u8,
+                    135u8, 224u8, 184u8, 161u8, 224u8, 184u8, 174u8, 224u8, 184u8, 181u8, 224u8,
+                    184u8, 154u8, 224u8, 184u8, 163u8, 224u8, 184u8, 185u8, 224u8, 184u8, 174u8,
+                    224u8, 184u8, 180u8, 224u8, 184u8, 163u8, 224u8, 184u8, 176u8, 224u8, 184u8,
+                    135u8, 224u8, 184u8, 176u8, 224u8, 184u8, 153u8, 224u8, 184u8, 176u8, 224u8,
+                    184u8, 173u8, 224u8, 184u8, 177u8, 224u8, 184u8, 129u8, 224u8, 184u8, 130u8,
+                    224u8, 184u8, 163u8, 224u8, 184u8, 176u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 153u8, 224u8, 184u8, 178u8, 224u8, 185u8, 130u8, 224u8,
+                    184u8, 149u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8, 184u8, 181u8,
+                    224u8, 184u8, 162u8, 224u8, 184u8, 155u8, 224u8, 184u8, 178u8, 224u8, 185u8,
+                    128u8, 224u8, 184u8, 174u8, 224u8, 184u8, 178u8, 224u8, 184u8, 171u8, 224u8,
+                    185u8, 140u8, 224u8, 184u8, 161u8, 224u8, 185u8, 137u8, 224u8, 184u8, 135u8,
+                    224u8, 184u8, 132u8, 224u8, 184u8, 176u8, 224u8, 184u8, 149u8, 224u8, 184u8,
+                    176u8, 224u8, 184u8, 129u8, 224u8, 184u8, 176u8, 224u8, 184u8, 153u8, 224u8,
+                    184u8, 176u8, 224u8, 184u8, 171u8, 224u8, 184u8, 163u8, 224u8, 184u8, 183u8,
+                    224u8, 184u8, 173u8, 224u8, 184u8, 174u8, 224u8, 184u8, 180u8, 224u8, 184u8,
+                    163u8, 224u8, 184u8, 176u8, 224u8, 184u8, 135u8, 224u8, 184u8, 176u8, 224u8,
+                    184u8, 153u8, 224u8, 184u8, 176u8, 224u8, 184u8, 174u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 135u8, 224u8, 184u8, 129u8, 224u8, 184u8, 178u8, 224u8, 184u8,
+                    163u8, 224u8, 184u8, 181u8, 224u8, 185u8, 130u8, 224u8, 184u8, 154u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 178u8, 224u8, 184u8, 147u8, 224u8, 184u8, 173u8,
+                    224u8, 184u8, 180u8, 224u8, 184u8, 153u8, 224u8, 184u8, 148u8, 224u8, 184u8,
+                    177u8, 224u8, 184u8, 170u8, 224u8, 184u8, 173u8, 224u8, 184u8, 180u8, 224u8,
+                    184u8, 149u8, 224u8, 184u8, 178u8, 224u8, 184u8, 165u8, 224u8, 184u8, 181u8,
+                    224u8, 185u8, 130u8, 224u8, 184u8, 154u8, 224u8, 184u8, 163u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 147u8, 224u8, 184u8, 136u8, 224u8, 184u8, 178u8, 224u8,
+                    185u8, 130u8, 224u8, 184u8, 161u8, 224u8, 184u8, 138u8, 224u8, 184u8, 167u8,
+                    224u8, 184u8, 178u8, 224u8, 184u8, 141u8, 224u8, 184u8, 181u8, 224u8, 185u8,
+                    136u8, 224u8, 184u8, 155u8, 224u8, 184u8, 184u8, 224u8, 185u8, 136u8, 224u8,
+                    184u8, 153u8, 224u8, 184u8, 136u8, 224u8, 184u8, 185u8, 224u8, 184u8, 163u8,
+                    224u8, 185u8, 140u8, 224u8, 185u8, 128u8, 224u8, 184u8, 138u8, 224u8, 184u8,
+                    153u8, 224u8, 184u8, 132u8, 224u8, 184u8, 162u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 171u8, 224u8, 185u8, 140u8, 224u8, 184u8, 132u8, 224u8, 184u8, 176u8,
+                    224u8, 184u8, 149u8, 224u8, 184u8, 176u8, 224u8, 184u8, 129u8, 224u8, 184u8,
+                    176u8, 224u8, 184u8, 153u8, 224u8, 184u8, 176u8, 224u8, 184u8, 130u8, 224u8,
+                    185u8, 130u8, 224u8, 184u8, 163u8, 224u8, 184u8, 169u8, 224u8, 184u8, 144u8,
+                    224u8, 184u8, 181u8, 224u8, 185u8, 128u8, 224u8, 184u8, 130u8, 224u8, 184u8,
+                    161u8, 224u8, 184u8, 163u8, 224u8, 184u8, 132u8, 224u8, 184u8, 173u8, 224u8,
+                    184u8, 136u8, 224u8, 184u8, 132u8, 224u8, 184u8, 181u8, 224u8, 184u8, 129u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 153u8, 224u8, 184u8, 153u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 148u8, 224u8, 184u8, 178u8, 224u8, 185u8, 128u8, 224u8,
+                    184u8, 129u8, 224u8, 184u8, 178u8, 224u8, 184u8, 171u8, 224u8, 184u8, 165u8,
+                    224u8, 184u8, 181u8, 224u8, 185u8, 128u8, 224u8, 184u8, 155u8, 224u8, 184u8,
+                    165u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8, 184u8, 129u8, 224u8,
+                    184u8, 178u8, 224u8, 184u8, 162u8, 224u8, 184u8, 149u8, 224u8, 184u8, 180u8,
+                    224u8, 184u8, 165u8, 224u8, 185u8, 137u8, 224u8, 184u8, 178u8, 224u8, 184u8,
+                    153u8, 224u8, 184u8, 153u8, 224u8, 184u8, 178u8, 224u8, 184u8, 165u8, 224u8,
+                    184u8, 178u8, 224u8, 184u8, 167u8, 224u8, 184u8, 165u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 149u8, 224u8, 184u8, 180u8, 224u8, 184u8, 153u8, 32u8, 45u8,
+                    32u8, 224u8, 184u8, 159u8, 224u8, 184u8, 163u8, 224u8, 184u8, 177u8, 224u8,
+                    184u8, 135u8, 224u8, 185u8, 128u8, 224u8, 184u8, 149u8, 224u8, 184u8, 173u8,
+                    224u8, 184u8, 163u8, 224u8, 185u8, 140u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 149u8, 224u8, 184u8, 180u8, 224u8, 184u8, 153u8, 32u8,
+                    45u8, 32u8, 224u8, 185u8, 129u8, 224u8, 184u8, 129u8, 224u8, 184u8, 165u8,
+                    224u8, 184u8, 180u8, 224u8, 184u8, 129u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    176u8, 224u8, 184u8, 149u8, 224u8, 184u8, 180u8, 224u8, 184u8, 153u8, 224u8,
+                    185u8, 128u8, 224u8, 184u8, 165u8, 224u8, 184u8, 155u8, 224u8, 184u8, 138u8,
+                    224u8, 184u8, 178u8, 224u8, 184u8, 165u8, 224u8, 184u8, 180u8, 224u8, 184u8,
+                    161u8, 224u8, 184u8, 154u8, 224u8, 184u8, 185u8, 224u8, 184u8, 165u8, 224u8,
+                    184u8, 181u8, 224u8, 185u8, 128u8, 224u8, 184u8, 153u8, 224u8, 184u8, 181u8,
+                    224u8, 184u8, 162u8, 224u8, 184u8, 163u8, 224u8, 185u8, 140u8, 224u8, 185u8,
+                    128u8, 224u8, 184u8, 173u8, 224u8, 184u8, 165u8, 224u8, 184u8, 181u8, 224u8,
+                    185u8, 128u8, 224u8, 184u8, 153u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8,
+                    224u8, 184u8, 163u8, 224u8, 185u8, 140u8, 224u8, 184u8, 154u8, 224u8, 184u8,
+                    181u8, 224u8, 185u8, 128u8, 224u8, 184u8, 159u8, 224u8, 184u8, 163u8, 224u8,
+                    185u8, 128u8, 224u8, 184u8, 139u8, 224u8, 184u8, 173u8, 224u8, 184u8, 163u8,
+                    224u8, 185u8, 140u8, 224u8, 185u8, 130u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    161u8, 224u8, 184u8, 178u8, 224u8, 185u8, 132u8, 224u8, 184u8, 165u8, 224u8,
+                    185u8, 128u8, 224u8, 184u8, 139u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8,
+                    224u8, 184u8, 165u8, 224u8, 184u8, 181u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    148u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 161u8, 224u8,
+                    184u8, 171u8, 224u8, 184u8, 178u8, 224u8, 184u8, 138u8, 224u8, 184u8, 153u8,
+                    224u8, 184u8, 181u8, 224u8, 185u8, 129u8, 224u8, 184u8, 161u8, 224u8, 184u8,
+                    153u8, 224u8, 185u8, 128u8, 224u8, 184u8, 148u8, 224u8, 184u8, 181u8, 224u8,
+                    184u8, 162u8, 224u8, 184u8, 153u8, 224u8, 184u8, 161u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 153u8, 224u8, 184u8, 180u8, 224u8, 185u8, 129u8, 224u8, 184u8,
+                    138u8, 224u8, 184u8, 153u8, 224u8, 184u8, 161u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 162u8, 224u8, 184u8, 178u8, 224u8, 185u8, 132u8, 224u8, 184u8, 174u8,
+                    224u8, 185u8, 130u8, 224u8, 184u8, 163u8, 224u8, 184u8, 129u8, 224u8, 184u8,
+                    165u8, 224u8, 184u8, 180u8, 224u8, 184u8, 159u8, 224u8, 184u8, 170u8, 224u8,
+                    185u8, 140u8, 224u8, 185u8, 128u8, 224u8, 184u8, 161u8, 224u8, 184u8, 153u8,
+                    224u8, 185u8, 128u8, 224u8, 184u8, 148u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    132u8, 224u8, 184u8, 173u8, 224u8, 184u8, 163u8, 224u8, 185u8, 140u8, 224u8,
+                    184u8, 139u8, 224u8, 184u8, 181u8, 224u8, 184u8, 159u8, 45u8, 224u8, 185u8,
+                    128u8, 224u8, 184u8, 161u8, 224u8, 185u8, 130u8, 224u8, 184u8, 163u8, 224u8,
+                    184u8, 173u8, 224u8, 184u8, 180u8, 224u8, 184u8, 149u8, 224u8, 184u8, 180u8,
+                    224u8, 184u8, 129u8, 224u8, 185u8, 128u8, 224u8, 184u8, 161u8, 224u8, 185u8,
+                    130u8, 224u8, 184u8, 163u8, 224u8, 184u8, 149u8, 224u8, 184u8, 180u8, 224u8,
+                    184u8, 129u8, 224u8, 184u8, 161u8, 224u8, 184u8, 178u8, 224u8, 184u8, 165u8,
+                    224u8, 184u8, 178u8, 224u8, 184u8, 162u8, 224u8, 184u8, 178u8, 224u8, 184u8,
+                    165u8, 224u8, 184u8, 177u8, 224u8, 184u8, 161u8, 224u8, 185u8, 130u8, 224u8,
+                    184u8, 161u8, 224u8, 184u8, 145u8, 224u8, 184u8, 181u8, 224u8, 184u8, 161u8,
+                    224u8, 184u8, 173u8, 224u8, 184u8, 135u8, 224u8, 185u8, 130u8, 224u8, 184u8,
+                    129u8, 224u8, 185u8, 128u8, 224u8, 184u8, 165u8, 224u8, 184u8, 181u8, 224u8,
+                    184u8, 162u8, 224u8, 184u8, 161u8, 224u8, 184u8, 185u8, 224u8, 184u8, 153u8,
+                    224u8, 184u8, 161u8, 224u8, 185u8, 130u8, 224u8, 184u8, 163u8, 224u8, 185u8,
+                    128u8, 224u8, 184u8, 161u8, 224u8, 185u8, 128u8, 224u8, 184u8, 151u8, 224u8,
+                    184u8, 161u8, 224u8, 184u8, 178u8, 224u8, 185u8, 128u8, 224u8, 184u8, 162u8,
+                    224u8, 184u8, 129u8, 224u8, 184u8, 158u8, 224u8, 184u8, 161u8, 224u8, 185u8,
+                    136u8, 224u8, 184u8, 178u8, 224u8, 184u8, 173u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 176u8, 224u8, 185u8, 128u8, 224u8, 184u8, 154u8,
+                    224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    171u8, 224u8, 184u8, 153u8, 224u8, 184u8, 183u8, 224u8, 184u8, 173u8, 224u8,
+                    185u8, 130u8, 224u8, 184u8, 154u8, 224u8, 184u8, 163u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 147u8, 224u8, 184u8, 153u8, 224u8, 184u8, 178u8, 224u8, 184u8,
+                    154u8, 224u8, 184u8, 178u8, 224u8, 184u8, 151u8, 224u8, 184u8, 178u8, 224u8,
+                    185u8, 128u8, 224u8, 184u8, 173u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8,
+                    224u8, 184u8, 153u8, 224u8, 184u8, 129u8, 224u8, 184u8, 181u8, 224u8, 184u8,
+                    154u8, 224u8, 184u8, 178u8, 45u8, 224u8, 184u8, 153u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 139u8, 224u8, 184u8, 181u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    173u8, 224u8, 185u8, 135u8, 224u8, 184u8, 153u8, 224u8, 185u8, 130u8, 224u8,
+                    184u8, 129u8, 224u8, 184u8, 153u8, 224u8, 184u8, 184u8, 224u8, 184u8, 139u8,
+                    224u8, 184u8, 184u8, 224u8, 185u8, 130u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                    132u8, 224u8, 184u8, 177u8, 224u8, 184u8, 161u8, 224u8, 185u8, 130u8, 224u8,
+                    184u8, 173u8, 224u8, 184u8, 165u8, 224u8, 184u8, 138u8, 224u8, 184u8, 180u8,
+                    224u8, 184u8, 129u8, 224u8, 184u8, 180u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                    173u8, 224u8, 184u8, 163u8, 224u8, 185u8, 140u8, 224u8, 184u8, 132u8, 224u8,
+                    184u8, 173u8, 224u8, 184u8, 153u8, 224u8, 185u8, 130u8, 224u8, 184u8, 173u8,
+                    224u8, 184u8, 163u8, 224u8, 184u8, 180u8, 224u8, 184u8, 162u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 173u8, 224u8, 184u8, 173u8, 224u8, 184u8, 170u8, 224u8,
+                    184u8, 161u8, 224u8, 184u8, 177u8, 224u8, 184u8, 153u8, 224u8, 184u8, 162u8,
+                    224u8, 184u8, 178u8, 224u8, 184u8, 158u8, 224u8, 184u8, 178u8, 224u8, 184u8,
+                    165u8, 224u8, 185u8, 132u8, 224u8, 184u8, 161u8, 224u8, 184u8, 163u8, 224u8,
+                    184u8, 181u8, 224u8, 184u8, 153u8, 224u8, 184u8, 155u8, 224u8, 185u8, 136u8,
+                    224u8, 184u8, 173u8, 224u8, 184u8, 136u8, 224u8, 184u8, 180u8, 224u8, 185u8,
+                    137u8, 224u8, 184u8, 135u8, 224u8, 184u8, 174u8, 224u8, 184u8, 173u8, 224u8,
+                    185u8, 128u8, 224u8, 184u8, 155u8, 224u8, 184u8, 173u8, 224u8, 184u8, 163u8,
+                    224u8, 185u8, 140u8, 224u8, 184u8, 161u8, 224u8, 184u8, 180u8, 224u8, 184u8,
+                    129u8, 224u8, 185u8, 130u8, 224u8, 184u8, 154u8, 224u8, 184u8, 163u8, 224u8,
+                    184u8, 178u8, 224u8, 184u8, 147u8, 224u8, 184u8, 159u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 129u8, 224u8, 184u8, 170u8, 224u8, 185u8, 140u8, 45u8, 224u8,
+                    184u8, 155u8, 224u8, 184u8, 178u8, 224u8, 184u8, 155u8, 224u8, 184u8, 176u8,
+                    224u8, 184u8, 171u8, 224u8, 185u8, 140u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 167u8, 224u8, 184u8, 181u8, 224u8, 184u8, 173u8, 224u8,
+                    184u8, 180u8, 224u8, 184u8, 153u8, 224u8, 184u8, 170u8, 224u8, 184u8, 132u8,
+                    224u8, 184u8, 163u8, 224u8, 184u8, 180u8, 224u8, 184u8, 155u8, 224u8, 184u8,
+                    138u8, 224u8, 184u8, 177u8, 224u8, 184u8, 153u8, 224u8, 185u8, 129u8, 224u8,
+                    184u8, 153u8, 224u8, 184u8, 165u8, 224u8, 184u8, 155u8, 224u8, 184u8, 176u8,
+                    224u8, 184u8, 171u8, 224u8, 185u8, 140u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 167u8, 224u8, 184u8, 181u8, 224u8, 184u8, 139u8, 224u8,
+                    184u8, 173u8, 224u8, 184u8, 165u8, 224u8, 185u8, 128u8, 224u8, 184u8, 149u8,
+                    224u8, 184u8, 173u8, 224u8, 184u8, 163u8, 224u8, 185u8, 140u8, 224u8, 184u8,
+                    155u8, 224u8, 184u8, 176u8, 224u8, 184u8, 171u8, 224u8, 185u8, 140u8, 224u8,
+                    184u8, 165u8, 224u8, 184u8, 178u8, 224u8, 184u8, 167u8, 224u8, 184u8, 181u8,
+                    224u8, 184u8, 154u8, 224u8, 184u8, 184u8, 224u8, 185u8, 138u8, 224u8, 184u8,
+                    129u8, 224u8, 184u8, 159u8, 224u8, 184u8, 180u8, 224u8, 184u8, 153u8, 224u8,
+                    184u8, 180u8, 224u8, 185u8, 128u8, 224u8, 184u8, 138u8, 224u8, 184u8, 181u8,
+                    224u8, 184u8, 162u8, 224u8, 184u8, 170u8, 224u8, 184u8, 177u8, 224u8, 184u8,
+                    151u8, 224u8, 184u8, 168u8, 224u8, 184u8, 178u8, 224u8, 184u8, 170u8, 224u8,
+                    184u8, 149u8, 224u8, 184u8, 163u8, 224u8, 185u8, 140u8, 224u8, 184u8, 158u8,
+                    224u8, 184u8, 173u8, 224u8, 184u8, 165u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 163u8, 224u8, 185u8, 140u8, 224u8, 184u8, 148u8, 224u8,
+                    184u8, 158u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 185u8, 140u8,
+                    224u8, 185u8, 128u8, 224u8, 184u8, 151u8, 224u8, 184u8, 181u8, 224u8, 184u8,
+                    162u8, 224u8, 184u8, 173u8, 224u8, 184u8, 180u8, 224u8, 184u8, 153u8, 224u8,
+                    184u8, 170u8, 224u8, 184u8, 132u8, 224u8, 184u8, 163u8, 224u8, 184u8, 180u8,
+                    224u8, 184u8, 155u8, 224u8, 184u8, 138u8, 224u8, 184u8, 177u8, 224u8, 184u8,
+                    153u8, 224u8, 185u8, 129u8, 224u8, 184u8, 153u8, 224u8, 184u8, 165u8, 224u8,
+                    185u8, 128u8, 224u8, 184u8, 163u8, 224u8, 184u8, 136u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 135u8, 224u8, 184u8, 174u8, 224u8, 184u8, 176u8, 224u8, 184u8,
+                    153u8, 224u8, 184u8, 181u8, 224u8, 184u8, 159u8, 224u8, 184u8, 181u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 173u8, 224u8, 184u8, 135u8, 224u8, 185u8, 130u8,
+                    224u8, 184u8, 129u8, 224u8, 184u8, 163u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                    135u8, 224u8, 185u8, 130u8, 224u8, 184u8, 129u8, 224u8, 184u8, 163u8, 224u8,
+                    184u8, 185u8, 224u8, 184u8, 153u8, 224u8, 184u8, 180u8, 224u8, 184u8, 129u8,
+                    224u8, 184u8, 139u8, 224u8, 184u8, 178u8, 224u8, 184u8, 161u8, 224u8, 184u8,
+                    178u8, 224u8, 185u8, 128u8, 224u8, 184u8, 163u8, 224u8, 184u8, 181u8, 224u8,
+                    184u8, 162u8, 224u8, 184u8, 139u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8,
+                    224u8, 184u8, 178u8, 224u8, 184u8, 149u8, 224u8, 184u8, 180u8, 224u8, 184u8,
+                    173u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 184u8, 176u8, 224u8,
+                    185u8, 128u8, 224u8, 184u8, 154u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8,
+                    224u8, 185u8, 131u8, 224u8, 184u8, 149u8, 224u8, 185u8, 137u8, 224u8, 185u8,
+                    130u8, 224u8, 184u8, 154u8, 224u8, 184u8, 163u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 147u8, 224u8, 185u8, 130u8, 224u8, 184u8, 170u8, 224u8, 184u8, 167u8,
+                    224u8, 184u8, 163u8, 224u8, 184u8, 177u8, 224u8, 184u8, 170u8, 224u8, 184u8,
+                    149u8, 224u8, 184u8, 163u8, 224u8, 184u8, 176u8, 224u8, 185u8, 132u8, 224u8,
+                    184u8, 139u8, 224u8, 184u8, 153u8, 224u8, 185u8, 140u8, 224u8, 185u8, 132u8,
+                    224u8, 184u8, 163u8, 224u8, 184u8, 149u8, 224u8, 184u8, 180u8, 224u8, 185u8,
+                    137u8, 224u8, 184u8, 135u8, 224u8, 184u8, 139u8, 224u8, 184u8, 173u8, 224u8,
+                    185u8, 128u8, 224u8, 184u8, 167u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8,
+                    224u8, 184u8, 153u8, 224u8, 184u8, 138u8, 224u8, 184u8, 178u8, 224u8, 184u8,
+                    163u8, 224u8, 184u8, 178u8, 224u8, 184u8, 148u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 170u8, 224u8, 184u8, 180u8, 224u8, 184u8, 151u8, 224u8, 184u8, 152u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 161u8, 224u8, 184u8, 132u8, 224u8, 184u8,
+                    184u8, 224u8, 184u8, 148u8, 224u8, 184u8, 178u8, 224u8, 184u8, 167u8, 224u8,
+                    184u8, 178u8, 224u8, 184u8, 148u8, 224u8, 184u8, 181u8, 224u8, 184u8, 170u8,
+                    224u8, 184u8, 180u8, 224u8, 184u8, 135u8, 224u8, 184u8, 171u8, 224u8, 184u8,
+                    165u8, 224u8, 185u8, 130u8, 224u8, 184u8, 170u8, 224u8, 184u8, 163u8, 224u8,
+                    184u8, 178u8, 224u8, 184u8, 170u8, 224u8, 184u8, 161u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 155u8, 224u8, 185u8, 135u8, 224u8, 184u8, 135u8, 224u8, 184u8,
+                    139u8, 224u8, 184u8, 184u8, 224u8, 184u8, 153u8, 224u8, 184u8, 148u8, 224u8,
+                    184u8, 178u8, 224u8, 184u8, 139u8, 224u8, 184u8, 180u8, 224u8, 185u8, 130u8,
+                    224u8, 184u8, 165u8, 224u8, 184u8, 149u8, 224u8, 184u8, 180u8, 224u8, 184u8,
+                    153u8, 224u8, 184u8, 178u8, 224u8, 184u8, 129u8, 224u8, 184u8, 163u8, 224u8,
+                    184u8, 181u8, 224u8, 184u8, 139u8, 224u8, 184u8, 181u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 163u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8,
+                    139u8, 224u8, 184u8, 181u8, 224u8, 185u8, 128u8, 224u8, 184u8, 163u8, 224u8,
+                    184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 185u8, 128u8, 224u8, 184u8, 173u8,
+                    224u8, 184u8, 170u8, 224u8, 184u8, 151u8, 224u8, 184u8, 163u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 153u8, 224u8, 184u8, 136u8, 224u8, 184u8, 181u8, 224u8,
+                    185u8, 130u8, 224u8, 184u8, 165u8, 224u8, 184u8, 139u8, 224u8, 184u8, 181u8,
+                    224u8, 185u8, 128u8, 224u8, 184u8, 163u8, 224u8, 184u8, 181u8, 224u8, 184u8,
+                    162u8, 224u8, 184u8, 149u8, 224u8, 184u8, 176u8, 224u8, 184u8, 167u8, 224u8,
+                    184u8, 177u8, 224u8, 184u8, 153u8, 224u8, 184u8, 149u8, 224u8, 184u8, 129u8,
+                    224u8, 184u8, 139u8, 224u8, 184u8, 181u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    163u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 149u8, 224u8,
+                    184u8, 176u8, 224u8, 184u8, 167u8, 224u8, 184u8, 177u8, 224u8, 184u8, 153u8,
+                    224u8, 184u8, 173u8, 224u8, 184u8, 173u8, 224u8, 184u8, 129u8, 224u8, 184u8,
+                    149u8, 224u8, 184u8, 177u8, 224u8, 184u8, 129u8, 224u8, 184u8, 154u8, 224u8,
+                    184u8, 177u8, 224u8, 184u8, 153u8, 224u8, 184u8, 167u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 151u8, 224u8, 184u8, 178u8, 224u8, 184u8, 132u8, 224u8, 184u8,
+                    163u8, 224u8, 184u8, 181u8, 224u8, 185u8, 132u8, 224u8, 184u8, 151u8, 224u8,
+                    185u8, 128u8, 224u8, 184u8, 165u8, 224u8, 185u8, 132u8, 224u8, 184u8, 151u8,
+                    224u8, 184u8, 165u8, 224u8, 184u8, 183u8, 224u8, 185u8, 137u8, 224u8, 184u8,
+                    173u8, 224u8, 185u8, 131u8, 224u8, 184u8, 171u8, 224u8, 184u8, 161u8, 224u8,
+                    185u8, 136u8, 224u8, 184u8, 151u8, 224u8, 184u8, 161u8, 224u8, 184u8, 180u8,
+                    224u8, 184u8, 172u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8, 224u8, 184u8,
+                    153u8, 224u8, 184u8, 129u8, 224u8, 184u8, 177u8, 224u8, 184u8, 151u8, 224u8,
+                    185u8, 132u8, 224u8, 184u8, 151u8, 224u8, 185u8, 128u8, 224u8, 184u8, 167u8,
+                    224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 149u8, 224u8, 185u8,
+                    128u8, 224u8, 184u8, 149u8, 224u8, 184u8, 165u8, 224u8, 184u8, 185u8, 224u8,
+                    184u8, 129u8, 224u8, 184u8, 185u8, 224u8, 185u8, 128u8, 224u8, 184u8, 151u8,
+                    224u8, 184u8, 135u8, 224u8, 184u8, 129u8, 224u8, 184u8, 167u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 163u8, 224u8, 185u8, 140u8, 224u8, 184u8, 151u8, 224u8,
+                    184u8, 180u8, 224u8, 184u8, 159u8, 224u8, 184u8, 180u8, 224u8, 184u8, 153u8,
+                    224u8, 184u8, 178u8, 224u8, 184u8, 129u8, 224u8, 184u8, 149u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 129u8, 224u8, 184u8, 178u8, 224u8, 184u8, 165u8, 224u8,
+                    185u8, 135u8, 224u8, 184u8, 173u8, 224u8, 184u8, 129u8, 224u8, 184u8, 151u8,
+                    224u8, 184u8, 178u8, 224u8, 184u8, 153u8, 224u8, 184u8, 178u8, 224u8, 185u8,
+                    132u8, 224u8, 184u8, 151u8, 224u8, 184u8, 162u8, 224u8, 184u8, 151u8, 224u8,
+                    184u8, 180u8, 224u8, 185u8, 128u8, 224u8, 184u8, 154u8, 224u8, 184u8, 149u8,
+                    224u8, 185u8, 128u8, 224u8, 184u8, 151u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                    174u8, 224u8, 184u8, 184u8, 224u8, 184u8, 151u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 162u8, 224u8, 184u8, 185u8, 224u8, 184u8, 129u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 163u8, 224u8, 184u8, 180u8, 224u8, 184u8, 149u8, 224u8, 185u8,
+                    132u8, 224u8, 184u8, 167u8, 224u8, 184u8, 132u8, 224u8, 184u8, 179u8, 224u8,
+                    184u8, 158u8, 224u8, 184u8, 185u8, 224u8, 184u8, 148u8, 224u8, 184u8, 151u8,
+                    224u8, 184u8, 181u8, 224u8, 185u8, 136u8, 224u8, 184u8, 161u8, 224u8, 184u8,
+                    173u8, 224u8, 184u8, 135u8, 224u8, 185u8, 128u8, 224u8, 184u8, 171u8, 224u8,
+                    185u8, 135u8, 224u8, 184u8, 153u8, 224u8, 185u8, 132u8, 224u8, 184u8, 148u8,
+                    224u8, 185u8, 137u8, 224u8, 184u8, 167u8, 224u8, 184u8, 178u8, 224u8, 184u8,
+                    163u8, 224u8, 184u8, 177u8, 224u8, 184u8, 135u8, 224u8, 184u8, 129u8, 224u8,
+                    184u8, 170u8, 224u8, 184u8, 180u8, 224u8, 184u8, 149u8, 224u8, 184u8, 180u8,
+                    224u8, 185u8, 130u8, 224u8, 184u8, 173u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    181u8, 224u8, 185u8, 128u8, 224u8, 184u8, 173u8, 224u8, 185u8, 128u8, 224u8,
+                    184u8, 155u8, 224u8, 184u8, 173u8, 224u8, 184u8, 163u8, 224u8, 185u8, 140u8,
+                    224u8, 185u8, 128u8, 224u8, 184u8, 139u8, 224u8, 184u8, 181u8, 224u8, 184u8,
+                    162u8, 224u8, 185u8, 130u8, 224u8, 184u8, 154u8, 224u8, 184u8, 163u8, 224u8,
+                    184u8, 178u8, 224u8, 184u8, 147u8, 224u8, 184u8, 173u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 129u8, 224u8, 184u8, 169u8, 224u8, 184u8, 163u8, 224u8, 184u8,
+                    163u8, 224u8, 184u8, 185u8, 224u8, 184u8, 155u8, 224u8, 184u8, 165u8, 224u8,
+                    184u8, 180u8, 224u8, 185u8, 136u8, 224u8, 184u8, 161u8, 224u8, 184u8, 170u8,
+                    224u8, 184u8, 184u8, 224u8, 185u8, 128u8, 224u8, 184u8, 161u8, 224u8, 185u8,
+                    128u8, 224u8, 184u8, 163u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 45u8,
+                    224u8, 184u8, 173u8, 224u8, 184u8, 177u8, 224u8, 184u8, 132u8, 224u8, 184u8,
+                    132u8, 224u8, 184u8, 178u8, 224u8, 185u8, 128u8, 224u8, 184u8, 148u8, 224u8,
+                    184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 162u8, 224u8, 184u8, 180u8,
+                    224u8, 184u8, 173u8, 224u8, 184u8, 180u8, 224u8, 184u8, 153u8, 224u8, 185u8,
+                    128u8, 224u8, 184u8, 174u8, 224u8, 184u8, 173u8, 224u8, 184u8, 163u8, 224u8,
+                    184u8, 180u8, 224u8, 184u8, 149u8, 224u8, 185u8, 128u8, 224u8, 184u8, 132u8,
+                    224u8, 184u8, 163u8, 224u8, 184u8, 183u8, 224u8, 185u8, 136u8, 224u8, 184u8,
+                    173u8, 224u8, 184u8, 135u8, 224u8, 184u8, 171u8, 224u8, 184u8, 161u8, 224u8,
+                    184u8, 178u8, 224u8, 184u8, 162u8, 224u8, 184u8, 151u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 135u8, 224u8, 184u8, 132u8, 224u8, 184u8, 147u8, 224u8, 184u8,
+                    180u8, 224u8, 184u8, 149u8, 224u8, 184u8, 168u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 170u8, 224u8, 184u8, 149u8, 224u8, 184u8, 163u8, 224u8, 185u8, 140u8,
+                    224u8, 184u8, 173u8, 224u8, 184u8, 181u8, 224u8, 185u8, 130u8, 224u8, 184u8,
+                    161u8, 224u8, 184u8, 136u8, 224u8, 184u8, 180u8, 224u8, 184u8, 170u8, 224u8,
+                    184u8, 177u8, 224u8, 184u8, 141u8, 224u8, 184u8, 165u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 129u8, 224u8, 184u8, 169u8, 224u8, 184u8, 147u8, 224u8, 185u8,
+                    140u8, 224u8, 185u8, 132u8, 224u8, 184u8, 161u8, 224u8, 185u8, 136u8, 224u8,
+                    184u8, 161u8, 224u8, 184u8, 181u8, 224u8, 184u8, 160u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 169u8, 224u8, 184u8, 178u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    130u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 153u8, 224u8,
+                    184u8, 170u8, 224u8, 184u8, 178u8, 224u8, 184u8, 161u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 141u8, 224u8, 184u8, 149u8, 224u8, 184u8, 177u8, 224u8, 184u8,
+                    167u8, 224u8, 184u8, 173u8, 224u8, 184u8, 177u8, 224u8, 184u8, 129u8, 224u8,
+                    184u8, 169u8, 224u8, 184u8, 163u8, 224u8, 184u8, 151u8, 224u8, 184u8, 181u8,
+                    224u8, 185u8, 136u8, 224u8, 185u8, 132u8, 224u8, 184u8, 161u8, 224u8, 185u8,
+                    136u8, 224u8, 184u8, 163u8, 224u8, 184u8, 185u8, 224u8, 185u8, 137u8, 224u8,
+                    184u8, 136u8, 224u8, 184u8, 177u8, 224u8, 184u8, 129u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+};
+static TR: &DataStruct = &::icu_displaynames::provider::ScriptDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    65u8, 100u8, 108u8, 109u8, 65u8, 102u8, 97u8, 107u8, 65u8, 103u8, 104u8, 98u8,
+                    65u8, 114u8, 97u8, 98u8, 65u8, 114u8, 97u8, 110u8, 65u8, 114u8, 109u8, 105u8,
+                    65u8, 114u8, 109u8, 110u8, 65u8, 118u8, 115u8, 116u8, 66u8, 97u8, 108u8, 105u8,
+                    66u8, 97u8, 109u8, 117u8, 66u8, 97u8, 115u8, 115u8, 66u8, 97u8, 116u8, 107u8,
+                    66u8, 101u8, 110u8, 103u8, 66u8, 108u8, 105u8, 115u8, 66u8, 111u8, 112u8,
+                    111u8, 66u8, 114u8, 97u8, 104u8, 66u8, 114u8, 97u8, 105u8, 66u8, 117u8, 103u8,
+                    105u8, 66u8, 117u8, 104u8, 100u8, 67u8, 97u8, 107u8, 109u8, 67u8, 97u8, 110u8,
+                    115u8, 67u8, 97u8, 114u8, 105u8, 67u8, 104u8, 97u8, 109u8, 67u8, 104u8, 101u8,
+                    114u8, 67u8, 105u8, 114u8, 116u8, 67u8, 111u8, 112u8, 116u8, 67u8, 112u8,
+                    114u8, 116u8, 67u8, 121u8, 114u8, 108u8, 67u8, 121u8, 114u8, 115u8, 68u8,
+                    101u8, 118u8, 97u8, 68u8, 115u8, 114u8, 116u8, 68u8, 117u8, 112u8, 108u8, 69u8,
+                    103u8, 121u8, 100u8, 69u8, 103u8, 121u8, 104u8, 69u8, 103u8, 121u8, 112u8,
+                    69u8, 108u8, 98u8, 97u8, 69u8, 116u8, 104u8, 105u8, 71u8, 101u8, 111u8, 107u8,
+                    71u8, 101u8, 111u8, 114u8, 71u8, 108u8, 97u8, 103u8, 71u8, 111u8, 116u8, 104u8,
+                    71u8, 114u8, 97u8, 110u8, 71u8, 114u8, 101u8, 107u8, 71u8, 117u8, 106u8, 114u8,
+                    71u8, 117u8, 114u8, 117u8, 72u8, 97u8, 110u8, 98u8, 72u8, 97u8, 110u8, 103u8,
+                    72u8, 97u8, 110u8, 105u8, 72u8, 97u8, 110u8, 111u8, 72u8, 97u8, 110u8, 115u8,
+                    72u8, 97u8, 110u8, 116u8, 72u8, 101u8, 98u8, 114u8, 72u8, 105u8, 114u8, 97u8,
+                    72u8, 108u8, 117u8, 119u8, 72u8, 109u8, 110u8, 103u8, 72u8, 114u8, 107u8,
+                    116u8, 72u8, 117u8, 110u8, 103u8, 73u8, 110u8, 100u8, 115u8, 73u8, 116u8, 97u8,
+                    108u8, 74u8, 97u8, 109u8, 111u8, 74u8, 97u8, 118u8, 97u8, 74u8, 112u8, 97u8,
+                    110u8, 74u8, 117u8, 114u8, 99u8, 75u8, 97u8, 108u8, 105u8, 75u8, 97u8, 110u8,
+                    97u8, 75u8, 104u8, 97u8, 114u8, 75u8, 104u8, 109u8, 114u8, 75u8, 104u8, 111u8,
+                    106u8, 75u8, 110u8, 100u8, 97u8, 75u8, 111u8, 114u8, 101u8, 75u8, 112u8, 101u8,
+                    108u8, 75u8, 116u8, 104u8, 105u8, 76u8, 97u8, 110u8, 97u8, 76u8, 97u8, 111u8,
+                    111u8, 76u8, 97u8, 116u8, 102u8, 76u8, 97u8, 116u8, 103u8, 76u8, 97u8, 116u8,
+                    110u8, 76u8, 101u8, 112u8, 99u8, 76u8, 105u8, 109u8, 98u8, 76u8, 105u8, 110u8,
+                    97u8, 76u8, 105u8, 110u8, 98u8, 76u8, 105u8, 115u8, 117u8, 76u8, 111u8, 109u8,
+                    97u8, 76u8, 121u8, 99u8, 105u8, 76u8, 121u8, 100u8, 105u8, 77u8, 97u8, 104u8,
+                    106u8, 77u8, 97u8, 110u8, 100u8, 77u8, 97u8, 121u8, 97u8, 77u8, 101u8, 110u8,
+                    100u8, 77u8, 101u8, 114u8, 99u8, 77u8, 101u8, 114u8, 111u8, 77u8, 108u8, 121u8,
+                    109u8, 77u8, 111u8, 100u8, 105u8, 77u8, 111u8, 110u8, 103u8, 77u8, 111u8,
+                    111u8, 110u8, 77u8, 114u8, 111u8, 111u8, 77u8, 116u8, 101u8, 105u8, 77u8,
+                    121u8, 109u8, 114u8, 78u8, 97u8, 114u8, 98u8, 78u8, 98u8, 97u8, 116u8, 78u8,
+                    107u8, 103u8, 98u8, 78u8, 107u8, 111u8, 111u8, 78u8, 115u8, 104u8, 117u8, 79u8,
+                    103u8, 97u8, 109u8, 79u8, 108u8, 99u8, 107u8, 79u8, 114u8, 107u8, 104u8, 79u8,
+                    114u8, 121u8, 97u8, 79u8, 115u8, 109u8, 97u8, 80u8, 97u8, 108u8, 109u8, 80u8,
+                    97u8, 117u8, 99u8, 80u8, 101u8, 114u8, 109u8, 80u8, 104u8, 97u8, 103u8, 80u8,
+                    104u8, 108u8, 105u8, 80u8, 104u8, 108u8, 112u8, 80u8, 104u8, 108u8, 118u8,
+                    80u8, 104u8, 110u8, 120u8, 80u8, 108u8, 114u8, 100u8, 80u8, 114u8, 116u8,
+                    105u8, 81u8, 97u8, 97u8, 103u8, 82u8, 106u8, 110u8, 103u8, 82u8, 111u8, 104u8,
+                    103u8, 82u8, 111u8, 114u8, 111u8, 82u8, 117u8, 110u8, 114u8, 83u8, 97u8, 109u8,
+                    114u8, 83u8, 97u8, 114u8, 97u8, 83u8, 97u8, 114u8, 98u8, 83u8, 97u8, 117u8,
+                    114u8, 83u8, 103u8, 110u8, 119u8, 83u8, 104u8, 97u8, 119u8, 83u8, 104u8, 114u8,
+                    100u8, 83u8, 105u8, 100u8, 100u8, 83u8, 105u8, 110u8, 100u8, 83u8, 105u8,
+                    110u8, 104u8, 83u8, 111u8, 114u8, 97u8, 83u8, 117u8, 110u8, 100u8, 83u8, 121u8,
+                    108u8, 111u8, 83u8, 121u8, 114u8, 99u8, 83u8, 121u8, 114u8, 101u8, 83u8, 121u8,
+                    114u8, 106u8, 83u8, 121u8, 114u8, 110u8, 84u8, 97u8, 103u8, 98u8, 84u8, 97u8,
+                    107u8, 114u8, 84u8, 97u8, 108u8, 101u8, 84u8, 97u8, 108u8, 117u8, 84u8, 97u8,
+                    109u8, 108u8, 84u8, 97u8, 110u8, 103u8, 84u8, 97u8, 118u8, 116u8, 84u8, 101u8,
+                    108u8, 117u8, 84u8, 101u8, 110u8, 103u8, 84u8, 102u8, 110u8, 103u8, 84u8,
+                    103u8, 108u8, 103u8, 84u8, 104u8, 97u8, 97u8, 84u8, 104u8, 97u8, 105u8, 84u8,
+                    105u8, 98u8, 116u8, 84u8, 105u8, 114u8, 104u8, 85u8, 103u8, 97u8, 114u8, 86u8,
+                    97u8, 105u8, 105u8, 86u8, 105u8, 115u8, 112u8, 87u8, 97u8, 114u8, 97u8, 87u8,
+                    111u8, 108u8, 101u8, 88u8, 112u8, 101u8, 111u8, 88u8, 115u8, 117u8, 120u8,
+                    89u8, 105u8, 105u8, 105u8, 90u8, 105u8, 110u8, 104u8, 90u8, 109u8, 116u8,
+                    104u8, 90u8, 115u8, 121u8, 101u8, 90u8, 115u8, 121u8, 109u8, 90u8, 120u8,
+                    120u8, 120u8, 90u8, 121u8, 121u8, 121u8, 90u8, 122u8, 122u8, 122u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    170u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 10u8, 0u8, 27u8, 0u8, 31u8, 0u8,
+                    40u8, 0u8, 63u8, 0u8, 69u8, 0u8, 75u8, 0u8, 84u8, 0u8, 89u8, 0u8, 98u8, 0u8,
+                    103u8, 0u8, 109u8, 0u8, 124u8, 0u8, 132u8, 0u8, 138u8, 0u8, 145u8, 0u8, 150u8,
+                    0u8, 155u8, 0u8, 161u8, 0u8, 165u8, 0u8, 170u8, 0u8, 174u8, 0u8, 181u8, 0u8,
+                    186u8, 0u8, 192u8, 0u8, 200u8, 0u8, 205u8, 0u8, 232u8, 0u8, 242u8, 0u8, 249u8,
+                    0u8, 12u8, 1u8, 27u8, 1u8, 44u8, 1u8, 66u8, 1u8, 73u8, 1u8, 81u8, 1u8, 96u8,
+                    1u8, 103u8, 1u8, 111u8, 1u8, 116u8, 1u8, 123u8, 1u8, 128u8, 1u8, 136u8, 1u8,
+                    144u8, 1u8, 158u8, 1u8, 165u8, 1u8, 168u8, 1u8, 175u8, 1u8, 193u8, 1u8, 203u8,
+                    1u8, 210u8, 1u8, 218u8, 1u8, 240u8, 1u8, 252u8, 1u8, 17u8, 2u8, 27u8, 2u8,
+                    32u8, 2u8, 45u8, 2u8, 49u8, 2u8, 58u8, 2u8, 63u8, 2u8, 70u8, 2u8, 78u8, 2u8,
+                    86u8, 2u8, 96u8, 2u8, 100u8, 2u8, 106u8, 2u8, 113u8, 2u8, 119u8, 2u8, 125u8,
+                    2u8, 131u8, 2u8, 136u8, 2u8, 139u8, 2u8, 152u8, 2u8, 162u8, 2u8, 167u8, 2u8,
+                    173u8, 2u8, 178u8, 2u8, 186u8, 2u8, 194u8, 2u8, 200u8, 2u8, 204u8, 2u8, 209u8,
+                    2u8, 214u8, 2u8, 222u8, 2u8, 228u8, 2u8, 247u8, 2u8, 252u8, 2u8, 16u8, 3u8,
+                    24u8, 3u8, 33u8, 3u8, 37u8, 3u8, 43u8, 3u8, 47u8, 3u8, 50u8, 3u8, 62u8, 3u8,
+                    67u8, 3u8, 82u8, 3u8, 88u8, 3u8, 98u8, 3u8, 104u8, 3u8, 110u8, 3u8, 115u8, 3u8,
+                    123u8, 3u8, 128u8, 3u8, 133u8, 3u8, 140u8, 3u8, 147u8, 3u8, 158u8, 3u8, 169u8,
+                    3u8, 177u8, 3u8, 196u8, 3u8, 211u8, 3u8, 229u8, 3u8, 235u8, 3u8, 250u8, 3u8,
+                    13u8, 4u8, 19u8, 4u8, 25u8, 4u8, 31u8, 4u8, 41u8, 4u8, 46u8, 4u8, 53u8, 4u8,
+                    59u8, 4u8, 75u8, 4u8, 85u8, 4u8, 98u8, 4u8, 105u8, 4u8, 112u8, 4u8, 119u8, 4u8,
+                    128u8, 4u8, 134u8, 4u8, 146u8, 4u8, 151u8, 4u8, 163u8, 4u8, 171u8, 4u8, 190u8,
+                    4u8, 204u8, 4u8, 218u8, 4u8, 226u8, 4u8, 231u8, 4u8, 237u8, 4u8, 248u8, 4u8,
+                    253u8, 4u8, 3u8, 5u8, 11u8, 5u8, 17u8, 5u8, 24u8, 5u8, 31u8, 5u8, 38u8, 5u8,
+                    44u8, 5u8, 47u8, 5u8, 52u8, 5u8, 59u8, 5u8, 80u8, 5u8, 83u8, 5u8, 112u8, 5u8,
+                    125u8, 5u8, 131u8, 5u8, 140u8, 5u8, 166u8, 5u8, 168u8, 5u8, 177u8, 5u8, 199u8,
+                    5u8, 204u8, 5u8, 210u8, 5u8, 226u8, 5u8, 231u8, 5u8, 65u8, 100u8, 108u8, 97u8,
+                    109u8, 65u8, 102u8, 97u8, 107u8, 97u8, 75u8, 97u8, 102u8, 107u8, 97u8, 115u8,
+                    32u8, 65u8, 108u8, 98u8, 97u8, 110u8, 121u8, 97u8, 115u8, 196u8, 177u8, 65u8,
+                    114u8, 97u8, 112u8, 78u8, 101u8, 115u8, 116u8, 195u8, 162u8, 108u8, 105u8,
+                    107u8, 196u8, 176u8, 109u8, 112u8, 97u8, 114u8, 97u8, 116u8, 111u8, 114u8,
+                    108u8, 117u8, 107u8, 32u8, 65u8, 114u8, 97u8, 109u8, 105u8, 99u8, 101u8, 115u8,
+                    105u8, 69u8, 114u8, 109u8, 101u8, 110u8, 105u8, 65u8, 118u8, 101u8, 115u8,
+                    116u8, 97u8, 66u8, 97u8, 108u8, 105u8, 32u8, 68u8, 105u8, 108u8, 105u8, 66u8,
+                    97u8, 109u8, 117u8, 109u8, 66u8, 97u8, 115u8, 115u8, 97u8, 32u8, 86u8, 97u8,
+                    104u8, 66u8, 97u8, 116u8, 97u8, 107u8, 66u8, 101u8, 110u8, 103u8, 97u8, 108u8,
+                    66u8, 108u8, 105u8, 115u8, 32u8, 83u8, 101u8, 109u8, 98u8, 111u8, 108u8, 108u8,
+                    101u8, 114u8, 105u8, 66u8, 111u8, 112u8, 111u8, 109u8, 111u8, 102u8, 111u8,
+                    66u8, 114u8, 97u8, 104u8, 109u8, 105u8, 66u8, 114u8, 97u8, 105u8, 108u8, 108u8,
+                    101u8, 66u8, 117u8, 103u8, 105u8, 115u8, 66u8, 117u8, 104u8, 105u8, 100u8,
+                    67u8, 104u8, 97u8, 107u8, 109u8, 97u8, 85u8, 67u8, 65u8, 83u8, 75u8, 97u8,
+                    114u8, 121u8, 97u8, 67u8, 104u8, 97u8, 109u8, 195u8, 135u8, 101u8, 114u8,
+                    111u8, 107u8, 105u8, 67u8, 105u8, 114u8, 116u8, 104u8, 75u8, 196u8, 177u8,
+                    112u8, 116u8, 105u8, 75u8, 196u8, 177u8, 98u8, 114u8, 196u8, 177u8, 115u8,
+                    75u8, 105u8, 114u8, 105u8, 108u8, 69u8, 115u8, 107u8, 105u8, 32u8, 75u8, 105u8,
+                    108u8, 105u8, 115u8, 101u8, 32u8, 83u8, 108u8, 97u8, 118u8, 99u8, 97u8, 115u8,
+                    196u8, 177u8, 32u8, 75u8, 105u8, 114u8, 105u8, 108u8, 68u8, 101u8, 118u8, 97u8,
+                    110u8, 97u8, 103u8, 97u8, 114u8, 105u8, 68u8, 101u8, 115u8, 101u8, 114u8,
+                    101u8, 116u8, 68u8, 117u8, 112u8, 108u8, 111u8, 121u8, 195u8, 169u8, 32u8,
+                    83u8, 116u8, 101u8, 110u8, 111u8, 103u8, 114u8, 97u8, 102u8, 105u8, 68u8,
+                    101u8, 109u8, 111u8, 116u8, 105u8, 107u8, 32u8, 77u8, 196u8, 177u8, 115u8,
+                    196u8, 177u8, 114u8, 72u8, 105u8, 121u8, 101u8, 114u8, 97u8, 116u8, 105u8,
+                    107u8, 32u8, 77u8, 196u8, 177u8, 115u8, 196u8, 177u8, 114u8, 77u8, 196u8,
+                    177u8, 115u8, 196u8, 177u8, 114u8, 32u8, 72u8, 105u8, 121u8, 101u8, 114u8,
+                    111u8, 103u8, 108u8, 105u8, 102u8, 108u8, 101u8, 114u8, 105u8, 69u8, 108u8,
+                    98u8, 97u8, 115u8, 97u8, 110u8, 69u8, 116u8, 105u8, 121u8, 111u8, 112u8, 121u8,
+                    97u8, 72u8, 117u8, 116u8, 115u8, 117u8, 114u8, 105u8, 32u8, 71u8, 195u8, 188u8,
+                    114u8, 99u8, 195u8, 188u8, 71u8, 195u8, 188u8, 114u8, 99u8, 195u8, 188u8, 71u8,
+                    108u8, 97u8, 103u8, 111u8, 108u8, 105u8, 116u8, 71u8, 111u8, 116u8, 105u8,
+                    107u8, 71u8, 114u8, 97u8, 110u8, 116u8, 104u8, 97u8, 89u8, 117u8, 110u8, 97u8,
+                    110u8, 71u8, 195u8, 188u8, 99u8, 101u8, 114u8, 97u8, 116u8, 71u8, 117u8, 114u8,
+                    109u8, 117u8, 107u8, 104u8, 105u8, 72u8, 97u8, 110u8, 32u8, 45u8, 32u8, 66u8,
+                    111u8, 112u8, 111u8, 109u8, 111u8, 102u8, 111u8, 72u8, 97u8, 110u8, 103u8,
+                    196u8, 177u8, 108u8, 72u8, 97u8, 110u8, 72u8, 97u8, 110u8, 117u8, 110u8, 111u8,
+                    111u8, 66u8, 97u8, 115u8, 105u8, 116u8, 108u8, 101u8, 197u8, 159u8, 116u8,
+                    105u8, 114u8, 105u8, 108u8, 109u8, 105u8, 197u8, 159u8, 71u8, 101u8, 108u8,
+                    101u8, 110u8, 101u8, 107u8, 115u8, 101u8, 108u8, 196u8, 176u8, 98u8, 114u8,
+                    97u8, 110u8, 105u8, 72u8, 105u8, 114u8, 97u8, 103u8, 97u8, 110u8, 97u8, 65u8,
+                    110u8, 97u8, 100u8, 111u8, 108u8, 117u8, 32u8, 72u8, 105u8, 121u8, 101u8,
+                    114u8, 111u8, 103u8, 108u8, 105u8, 102u8, 108u8, 101u8, 114u8, 105u8, 80u8,
+                    97u8, 104u8, 97u8, 118u8, 104u8, 32u8, 72u8, 109u8, 111u8, 110u8, 103u8, 74u8,
+                    97u8, 112u8, 111u8, 110u8, 32u8, 104u8, 101u8, 99u8, 101u8, 32u8, 97u8, 108u8,
+                    102u8, 97u8, 98u8, 101u8, 108u8, 101u8, 114u8, 105u8, 69u8, 115u8, 107u8,
+                    105u8, 32u8, 77u8, 97u8, 99u8, 97u8, 114u8, 73u8, 110u8, 100u8, 117u8, 115u8,
+                    69u8, 115u8, 107u8, 105u8, 32u8, 196u8, 176u8, 116u8, 97u8, 108u8, 121u8, 97u8,
+                    110u8, 74u8, 97u8, 109u8, 111u8, 67u8, 97u8, 118u8, 97u8, 32u8, 68u8, 105u8,
+                    108u8, 105u8, 74u8, 97u8, 112u8, 111u8, 110u8, 74u8, 117u8, 114u8, 99u8, 104u8,
+                    101u8, 110u8, 75u8, 97u8, 121u8, 97u8, 104u8, 32u8, 76u8, 105u8, 75u8, 97u8,
+                    116u8, 97u8, 107u8, 97u8, 110u8, 97u8, 75u8, 104u8, 97u8, 114u8, 111u8, 115u8,
+                    104u8, 116u8, 104u8, 105u8, 75u8, 109u8, 101u8, 114u8, 75u8, 104u8, 111u8,
+                    106u8, 107u8, 105u8, 75u8, 97u8, 110u8, 110u8, 97u8, 100u8, 97u8, 75u8, 111u8,
+                    114u8, 101u8, 99u8, 101u8, 75u8, 112u8, 101u8, 108u8, 108u8, 101u8, 75u8, 97u8,
+                    105u8, 116u8, 104u8, 105u8, 76u8, 97u8, 110u8, 110u8, 97u8, 76u8, 97u8, 111u8,
+                    70u8, 114u8, 97u8, 107u8, 116u8, 117u8, 114u8, 32u8, 76u8, 97u8, 116u8, 105u8,
+                    110u8, 71u8, 97u8, 101u8, 108u8, 32u8, 76u8, 97u8, 116u8, 105u8, 110u8, 76u8,
+                    97u8, 116u8, 105u8, 110u8, 76u8, 101u8, 112u8, 99u8, 104u8, 97u8, 76u8, 105u8,
+                    109u8, 98u8, 117u8, 76u8, 105u8, 110u8, 101u8, 101u8, 114u8, 32u8, 65u8, 76u8,
+                    105u8, 110u8, 101u8, 101u8, 114u8, 32u8, 66u8, 70u8, 114u8, 97u8, 115u8, 101u8,
+                    114u8, 76u8, 111u8, 109u8, 97u8, 76u8, 105u8, 107u8, 121u8, 97u8, 76u8, 105u8,
+                    100u8, 121u8, 97u8, 77u8, 97u8, 104u8, 97u8, 106u8, 97u8, 110u8, 105u8, 77u8,
+                    97u8, 110u8, 100u8, 101u8, 110u8, 77u8, 97u8, 121u8, 97u8, 32u8, 72u8, 105u8,
+                    121u8, 101u8, 114u8, 111u8, 103u8, 108u8, 105u8, 102u8, 108u8, 101u8, 114u8,
+                    105u8, 77u8, 101u8, 110u8, 100u8, 101u8, 77u8, 101u8, 114u8, 111u8, 105u8,
+                    116u8, 105u8, 107u8, 32u8, 69u8, 108u8, 32u8, 89u8, 97u8, 122u8, 196u8, 177u8,
+                    115u8, 196u8, 177u8, 77u8, 101u8, 114u8, 111u8, 105u8, 116u8, 105u8, 107u8,
+                    77u8, 97u8, 108u8, 97u8, 121u8, 97u8, 108u8, 97u8, 109u8, 77u8, 111u8, 100u8,
+                    105u8, 77u8, 111u8, 196u8, 159u8, 111u8, 108u8, 77u8, 111u8, 111u8, 110u8,
+                    77u8, 114u8, 111u8, 77u8, 101u8, 105u8, 116u8, 101u8, 105u8, 32u8, 77u8, 97u8,
+                    121u8, 101u8, 107u8, 66u8, 117u8, 114u8, 109u8, 97u8, 69u8, 115u8, 107u8,
+                    105u8, 32u8, 75u8, 117u8, 122u8, 101u8, 121u8, 32u8, 65u8, 114u8, 97u8, 112u8,
+                    78u8, 101u8, 98u8, 97u8, 116u8, 105u8, 78u8, 97u8, 107u8, 115u8, 105u8, 32u8,
+                    71u8, 101u8, 98u8, 97u8, 78u8, 226u8, 128u8, 153u8, 75u8, 111u8, 78u8, 195u8,
+                    188u8, 115u8, 104u8, 117u8, 79u8, 103u8, 104u8, 97u8, 109u8, 79u8, 108u8, 32u8,
+                    67u8, 104u8, 105u8, 107u8, 105u8, 79u8, 114u8, 104u8, 117u8, 110u8, 79u8,
+                    114u8, 105u8, 121u8, 97u8, 79u8, 115u8, 109u8, 97u8, 110u8, 121u8, 97u8, 80u8,
+                    97u8, 108u8, 109u8, 105u8, 114u8, 97u8, 80u8, 97u8, 117u8, 32u8, 67u8, 105u8,
+                    110u8, 32u8, 72u8, 97u8, 117u8, 69u8, 115u8, 107u8, 105u8, 32u8, 80u8, 101u8,
+                    114u8, 109u8, 105u8, 107u8, 80u8, 104u8, 97u8, 103u8, 115u8, 45u8, 112u8, 97u8,
+                    80u8, 101u8, 104u8, 108u8, 101u8, 118u8, 105u8, 32u8, 75u8, 105u8, 116u8, 97u8,
+                    98u8, 101u8, 32u8, 68u8, 105u8, 108u8, 105u8, 80u8, 115u8, 97u8, 108u8, 116u8,
+                    101u8, 114u8, 32u8, 80u8, 101u8, 104u8, 108u8, 101u8, 118u8, 105u8, 75u8,
+                    105u8, 116u8, 97u8, 112u8, 32u8, 80u8, 101u8, 104u8, 108u8, 101u8, 118u8,
+                    105u8, 32u8, 68u8, 105u8, 108u8, 105u8, 70u8, 101u8, 110u8, 105u8, 107u8,
+                    101u8, 80u8, 111u8, 108u8, 108u8, 97u8, 114u8, 100u8, 32u8, 70u8, 111u8, 110u8,
+                    101u8, 116u8, 105u8, 107u8, 80u8, 97u8, 114u8, 116u8, 195u8, 167u8, 97u8, 32u8,
+                    75u8, 105u8, 116u8, 97u8, 98u8, 101u8, 32u8, 68u8, 105u8, 108u8, 105u8, 90u8,
+                    97u8, 119u8, 103u8, 121u8, 105u8, 82u8, 101u8, 106u8, 97u8, 110u8, 103u8, 72u8,
+                    97u8, 110u8, 105u8, 102u8, 105u8, 82u8, 111u8, 110u8, 103u8, 111u8, 114u8,
+                    111u8, 110u8, 103u8, 111u8, 82u8, 117u8, 110u8, 105u8, 107u8, 83u8, 97u8,
+                    109u8, 97u8, 114u8, 105u8, 116u8, 83u8, 97u8, 114u8, 97u8, 116u8, 105u8, 69u8,
+                    115u8, 107u8, 105u8, 32u8, 71u8, 195u8, 188u8, 110u8, 101u8, 121u8, 32u8, 65u8,
+                    114u8, 97u8, 112u8, 83u8, 97u8, 117u8, 114u8, 97u8, 115u8, 104u8, 116u8, 114u8,
+                    97u8, 196u8, 176u8, 197u8, 159u8, 97u8, 114u8, 101u8, 116u8, 32u8, 68u8, 105u8,
+                    108u8, 105u8, 83u8, 104u8, 97u8, 118u8, 105u8, 97u8, 110u8, 83u8, 104u8, 97u8,
+                    114u8, 97u8, 100u8, 97u8, 83u8, 105u8, 100u8, 100u8, 104u8, 97u8, 109u8, 75u8,
+                    104u8, 117u8, 100u8, 97u8, 98u8, 97u8, 100u8, 105u8, 83u8, 101u8, 121u8, 108u8,
+                    97u8, 110u8, 83u8, 111u8, 114u8, 97u8, 32u8, 83u8, 111u8, 109u8, 112u8, 101u8,
+                    110u8, 103u8, 83u8, 117u8, 110u8, 100u8, 97u8, 83u8, 121u8, 108u8, 111u8,
+                    116u8, 105u8, 32u8, 78u8, 97u8, 103u8, 114u8, 105u8, 83u8, 195u8, 188u8, 114u8,
+                    121u8, 97u8, 110u8, 105u8, 69u8, 115u8, 116u8, 114u8, 97u8, 110u8, 103u8,
+                    101u8, 108u8, 97u8, 32u8, 83u8, 195u8, 188u8, 114u8, 121u8, 97u8, 110u8, 105u8,
+                    66u8, 97u8, 116u8, 196u8, 177u8, 32u8, 83u8, 195u8, 188u8, 114u8, 121u8, 97u8,
+                    110u8, 105u8, 68u8, 111u8, 196u8, 159u8, 117u8, 32u8, 83u8, 195u8, 188u8,
+                    114u8, 121u8, 97u8, 110u8, 105u8, 84u8, 97u8, 103u8, 98u8, 97u8, 110u8, 118u8,
+                    97u8, 84u8, 97u8, 107u8, 114u8, 105u8, 84u8, 97u8, 105u8, 32u8, 76u8, 101u8,
+                    78u8, 101u8, 119u8, 32u8, 84u8, 97u8, 105u8, 32u8, 76u8, 117u8, 101u8, 84u8,
+                    97u8, 109u8, 105u8, 108u8, 84u8, 97u8, 110u8, 103u8, 117u8, 116u8, 84u8, 97u8,
+                    105u8, 32u8, 86u8, 105u8, 101u8, 116u8, 84u8, 101u8, 108u8, 117u8, 103u8,
+                    117u8, 84u8, 101u8, 110u8, 103u8, 118u8, 97u8, 114u8, 84u8, 105u8, 102u8,
+                    105u8, 110u8, 97u8, 103u8, 84u8, 97u8, 107u8, 97u8, 108u8, 111u8, 116u8, 84u8,
+                    104u8, 97u8, 97u8, 110u8, 97u8, 84u8, 97u8, 121u8, 84u8, 105u8, 98u8, 101u8,
+                    116u8, 84u8, 105u8, 114u8, 104u8, 117u8, 116u8, 97u8, 85u8, 103u8, 97u8, 114u8,
+                    105u8, 116u8, 32u8, 195u8, 135u8, 105u8, 118u8, 105u8, 32u8, 89u8, 97u8, 122u8,
+                    196u8, 177u8, 115u8, 196u8, 177u8, 86u8, 97u8, 105u8, 75u8, 111u8, 110u8,
+                    117u8, 197u8, 159u8, 109u8, 97u8, 32u8, 83u8, 101u8, 115u8, 108u8, 101u8,
+                    114u8, 105u8, 32u8, 195u8, 135u8, 105u8, 122u8, 105u8, 109u8, 108u8, 101u8,
+                    109u8, 101u8, 115u8, 105u8, 86u8, 97u8, 114u8, 97u8, 110u8, 103u8, 32u8, 75u8,
+                    115u8, 104u8, 105u8, 116u8, 105u8, 87u8, 111u8, 108u8, 101u8, 97u8, 105u8,
+                    69u8, 115u8, 107u8, 105u8, 32u8, 70u8, 97u8, 114u8, 115u8, 83u8, 195u8, 188u8,
+                    109u8, 101u8, 114u8, 45u8, 65u8, 107u8, 97u8, 100u8, 32u8, 195u8, 135u8, 105u8,
+                    118u8, 105u8, 32u8, 89u8, 97u8, 122u8, 196u8, 177u8, 115u8, 196u8, 177u8, 89u8,
+                    105u8, 75u8, 97u8, 108u8, 196u8, 177u8, 116u8, 115u8, 97u8, 108u8, 77u8, 97u8,
+                    116u8, 101u8, 109u8, 97u8, 116u8, 105u8, 107u8, 115u8, 101u8, 108u8, 32u8,
+                    71u8, 195u8, 182u8, 115u8, 116u8, 101u8, 114u8, 105u8, 109u8, 69u8, 109u8,
+                    111u8, 106u8, 105u8, 83u8, 101u8, 109u8, 98u8, 111u8, 108u8, 89u8, 97u8, 122u8,
+                    196u8, 177u8, 108u8, 196u8, 177u8, 32u8, 79u8, 108u8, 109u8, 97u8, 121u8, 97u8,
+                    110u8, 79u8, 114u8, 116u8, 97u8, 107u8, 66u8, 105u8, 108u8, 105u8, 110u8,
+                    109u8, 101u8, 121u8, 101u8, 110u8, 32u8, 65u8, 108u8, 102u8, 97u8, 98u8, 101u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+};
+static UND: &DataStruct = &::icu_displaynames::provider::ScriptDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+};
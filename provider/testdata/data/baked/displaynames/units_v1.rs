@@ -0,0 +1,4243 @@
+// @generated
+#![cfg(feature = "icu_displaynames")]
+type DataStruct = < :: icu_displaynames :: provider :: UnitDisplayNamesV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[
+        ("ar", AR_AR_EG),
+        ("ar-EG", AR_AR_EG),
+        ("bn", BN),
+        ("ccp", CCP),
+        ("en", EN),
+        ("en-001", EN_001_EN_ZA),
+        ("en-ZA", EN_001_EN_ZA),
+        ("es", ES),
+        ("es-AR", ES_AR),
+        ("fil", FIL),
+        ("fr", FR),
+        ("ja", JA),
+        ("ru", RU),
+        ("sr", SR_SR_CYRL),
+        ("sr-Cyrl", SR_SR_CYRL),
+        ("sr-Latn", SR_LATN),
+        ("th", TH),
+        ("tr", TR),
+        ("und", UND),
+    ]);
+static AR_AR_EG: &DataStruct = &::icu_displaynames::provider::UnitDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    65u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 7u8, 0u8, 11u8, 0u8, 18u8, 0u8, 28u8,
+                    0u8, 44u8, 0u8, 54u8, 0u8, 64u8, 0u8, 79u8, 0u8, 90u8, 0u8, 100u8, 0u8, 110u8,
+                    0u8, 113u8, 0u8, 119u8, 0u8, 129u8, 0u8, 140u8, 0u8, 144u8, 0u8, 150u8, 0u8,
+                    157u8, 0u8, 165u8, 0u8, 169u8, 0u8, 176u8, 0u8, 180u8, 0u8, 184u8, 0u8, 191u8,
+                    0u8, 199u8, 0u8, 207u8, 0u8, 216u8, 0u8, 234u8, 0u8, 239u8, 0u8, 2u8, 1u8, 9u8,
+                    1u8, 17u8, 1u8, 22u8, 1u8, 38u8, 1u8, 61u8, 1u8, 72u8, 1u8, 76u8, 1u8, 91u8,
+                    1u8, 104u8, 1u8, 121u8, 1u8, 131u8, 1u8, 141u8, 1u8, 152u8, 1u8, 158u8, 1u8,
+                    163u8, 1u8, 173u8, 1u8, 178u8, 1u8, 185u8, 1u8, 193u8, 1u8, 198u8, 1u8, 204u8,
+                    1u8, 221u8, 1u8, 232u8, 1u8, 243u8, 1u8, 3u8, 2u8, 15u8, 2u8, 26u8, 2u8, 37u8,
+                    2u8, 42u8, 2u8, 49u8, 2u8, 57u8, 2u8, 61u8, 2u8, 65u8, 2u8, 97u8, 99u8, 114u8,
+                    101u8, 98u8, 105u8, 116u8, 98u8, 121u8, 116u8, 101u8, 99u8, 101u8, 108u8,
+                    115u8, 105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8, 110u8,
+                    116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 102u8, 111u8, 111u8, 116u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8,
+                    105u8, 110u8, 99u8, 104u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8,
+                    45u8, 109u8, 105u8, 108u8, 101u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 121u8,
+                    97u8, 114u8, 100u8, 100u8, 97u8, 121u8, 100u8, 101u8, 103u8, 114u8, 101u8,
+                    101u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8,
+                    102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8,
+                    102u8, 111u8, 111u8, 116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8,
+                    105u8, 103u8, 97u8, 98u8, 105u8, 116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8,
+                    116u8, 101u8, 103u8, 114u8, 97u8, 109u8, 104u8, 101u8, 99u8, 116u8, 97u8,
+                    114u8, 101u8, 104u8, 111u8, 117u8, 114u8, 105u8, 110u8, 99u8, 104u8, 107u8,
+                    105u8, 108u8, 111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 98u8,
+                    121u8, 116u8, 101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 104u8, 111u8, 117u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8, 97u8,
+                    98u8, 105u8, 116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 108u8, 101u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8,
+                    101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8,
+                    105u8, 108u8, 101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8,
+                    118u8, 105u8, 97u8, 110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 108u8, 105u8,
+                    116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 110u8,
+                    116u8, 104u8, 110u8, 97u8, 110u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8, 101u8, 114u8, 99u8, 101u8,
+                    110u8, 116u8, 112u8, 101u8, 116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 112u8,
+                    111u8, 117u8, 110u8, 100u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8, 115u8,
+                    116u8, 111u8, 110u8, 101u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8,
+                    116u8, 101u8, 114u8, 97u8, 98u8, 121u8, 116u8, 101u8, 119u8, 101u8, 101u8,
+                    107u8, 121u8, 97u8, 114u8, 100u8, 121u8, 101u8, 97u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    65u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 12u8, 0u8, 20u8, 0u8, 39u8, 0u8, 53u8,
+                    0u8, 76u8, 0u8, 93u8, 0u8, 112u8, 0u8, 135u8, 0u8, 150u8, 0u8, 165u8, 0u8,
+                    186u8, 0u8, 194u8, 0u8, 202u8, 0u8, 227u8, 0u8, 248u8, 0u8, 254u8, 0u8, 8u8,
+                    1u8, 20u8, 1u8, 36u8, 1u8, 44u8, 1u8, 54u8, 1u8, 64u8, 1u8, 72u8, 1u8, 84u8,
+                    1u8, 100u8, 1u8, 116u8, 1u8, 130u8, 1u8, 162u8, 1u8, 168u8, 1u8, 196u8, 1u8,
+                    208u8, 1u8, 224u8, 1u8, 230u8, 1u8, 0u8, 2u8, 41u8, 2u8, 61u8, 2u8, 67u8, 2u8,
+                    91u8, 2u8, 115u8, 2u8, 140u8, 2u8, 152u8, 2u8, 164u8, 2u8, 181u8, 2u8, 191u8,
+                    2u8, 199u8, 2u8, 218u8, 2u8, 228u8, 2u8, 242u8, 2u8, 2u8, 3u8, 8u8, 3u8, 18u8,
+                    3u8, 41u8, 3u8, 58u8, 3u8, 77u8, 3u8, 100u8, 3u8, 115u8, 3u8, 130u8, 3u8,
+                    151u8, 3u8, 159u8, 3u8, 171u8, 3u8, 187u8, 3u8, 199u8, 3u8, 209u8, 3u8, 217u8,
+                    129u8, 216u8, 175u8, 216u8, 167u8, 217u8, 134u8, 216u8, 168u8, 216u8, 170u8,
+                    216u8, 168u8, 216u8, 167u8, 217u8, 138u8, 216u8, 170u8, 216u8, 175u8, 216u8,
+                    177u8, 216u8, 172u8, 216u8, 169u8, 32u8, 217u8, 133u8, 216u8, 166u8, 217u8,
+                    136u8, 217u8, 138u8, 216u8, 169u8, 216u8, 179u8, 217u8, 134u8, 216u8, 170u8,
+                    217u8, 138u8, 217u8, 133u8, 216u8, 170u8, 216u8, 177u8, 216u8, 179u8, 217u8,
+                    134u8, 216u8, 170u8, 217u8, 138u8, 217u8, 133u8, 216u8, 170u8, 216u8, 177u8,
+                    32u8, 217u8, 133u8, 217u8, 131u8, 216u8, 185u8, 216u8, 168u8, 217u8, 130u8,
+                    216u8, 175u8, 217u8, 133u8, 32u8, 217u8, 133u8, 217u8, 131u8, 216u8, 185u8,
+                    216u8, 168u8, 216u8, 169u8, 216u8, 168u8, 217u8, 136u8, 216u8, 181u8, 216u8,
+                    169u8, 32u8, 217u8, 133u8, 217u8, 131u8, 216u8, 185u8, 216u8, 168u8, 216u8,
+                    169u8, 217u8, 131u8, 217u8, 138u8, 217u8, 132u8, 217u8, 136u8, 217u8, 133u8,
+                    216u8, 170u8, 216u8, 177u8, 32u8, 217u8, 133u8, 217u8, 131u8, 216u8, 185u8,
+                    216u8, 168u8, 217u8, 133u8, 216u8, 170u8, 216u8, 177u8, 32u8, 217u8, 133u8,
+                    217u8, 131u8, 216u8, 185u8, 216u8, 168u8, 217u8, 133u8, 217u8, 138u8, 217u8,
+                    132u8, 32u8, 217u8, 133u8, 217u8, 131u8, 216u8, 185u8, 216u8, 168u8, 217u8,
+                    138u8, 216u8, 167u8, 216u8, 177u8, 216u8, 175u8, 216u8, 169u8, 32u8, 217u8,
+                    133u8, 217u8, 131u8, 216u8, 185u8, 216u8, 168u8, 216u8, 169u8, 216u8, 163u8,
+                    217u8, 138u8, 216u8, 167u8, 217u8, 133u8, 216u8, 175u8, 216u8, 177u8, 216u8,
+                    172u8, 216u8, 169u8, 216u8, 175u8, 216u8, 177u8, 216u8, 172u8, 216u8, 169u8,
+                    32u8, 217u8, 129u8, 217u8, 135u8, 216u8, 177u8, 217u8, 134u8, 217u8, 135u8,
+                    216u8, 167u8, 217u8, 138u8, 216u8, 170u8, 216u8, 163u8, 217u8, 136u8, 217u8,
+                    134u8, 216u8, 181u8, 216u8, 169u8, 32u8, 216u8, 179u8, 216u8, 167u8, 216u8,
+                    166u8, 217u8, 132u8, 216u8, 169u8, 217u8, 130u8, 216u8, 175u8, 217u8, 133u8,
+                    216u8, 186u8, 216u8, 167u8, 217u8, 132u8, 217u8, 136u8, 217u8, 134u8, 216u8,
+                    186u8, 217u8, 138u8, 216u8, 186u8, 216u8, 167u8, 216u8, 168u8, 216u8, 170u8,
+                    216u8, 186u8, 217u8, 138u8, 216u8, 186u8, 216u8, 167u8, 216u8, 168u8, 216u8,
+                    167u8, 217u8, 138u8, 216u8, 170u8, 216u8, 186u8, 216u8, 177u8, 216u8, 167u8,
+                    217u8, 133u8, 217u8, 135u8, 217u8, 131u8, 216u8, 170u8, 216u8, 167u8, 216u8,
+                    177u8, 216u8, 179u8, 216u8, 167u8, 216u8, 185u8, 216u8, 167u8, 216u8, 170u8,
+                    216u8, 168u8, 217u8, 136u8, 216u8, 181u8, 216u8, 169u8, 217u8, 131u8, 217u8,
+                    138u8, 217u8, 132u8, 217u8, 136u8, 216u8, 168u8, 216u8, 170u8, 217u8, 131u8,
+                    217u8, 138u8, 217u8, 132u8, 217u8, 136u8, 216u8, 168u8, 216u8, 167u8, 217u8,
+                    138u8, 216u8, 170u8, 217u8, 131u8, 217u8, 138u8, 217u8, 132u8, 217u8, 136u8,
+                    216u8, 186u8, 216u8, 177u8, 216u8, 167u8, 217u8, 133u8, 217u8, 131u8, 217u8,
+                    138u8, 217u8, 132u8, 217u8, 136u8, 217u8, 133u8, 216u8, 170u8, 216u8, 177u8,
+                    217u8, 131u8, 217u8, 138u8, 217u8, 132u8, 217u8, 136u8, 217u8, 133u8, 216u8,
+                    170u8, 216u8, 177u8, 32u8, 217u8, 129u8, 217u8, 138u8, 32u8, 216u8, 167u8,
+                    217u8, 132u8, 216u8, 179u8, 216u8, 167u8, 216u8, 185u8, 216u8, 169u8, 217u8,
+                    132u8, 216u8, 170u8, 216u8, 177u8, 217u8, 132u8, 216u8, 170u8, 216u8, 177u8,
+                    32u8, 217u8, 132u8, 217u8, 131u8, 217u8, 132u8, 32u8, 217u8, 131u8, 217u8,
+                    138u8, 217u8, 132u8, 217u8, 136u8, 217u8, 133u8, 216u8, 170u8, 216u8, 177u8,
+                    217u8, 133u8, 217u8, 138u8, 216u8, 186u8, 216u8, 167u8, 216u8, 168u8, 216u8,
+                    170u8, 217u8, 133u8, 217u8, 138u8, 216u8, 186u8, 216u8, 167u8, 216u8, 168u8,
+                    216u8, 167u8, 217u8, 138u8, 216u8, 170u8, 217u8, 133u8, 216u8, 170u8, 216u8,
+                    177u8, 217u8, 133u8, 216u8, 170u8, 216u8, 177u8, 32u8, 217u8, 129u8, 217u8,
+                    138u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 171u8, 216u8, 167u8, 217u8,
+                    134u8, 217u8, 138u8, 216u8, 169u8, 217u8, 133u8, 216u8, 170u8, 216u8, 177u8,
+                    32u8, 217u8, 129u8, 217u8, 138u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8,
+                    171u8, 216u8, 167u8, 217u8, 134u8, 217u8, 138u8, 216u8, 169u8, 32u8, 216u8,
+                    167u8, 217u8, 132u8, 217u8, 133u8, 216u8, 177u8, 216u8, 168u8, 216u8, 185u8,
+                    216u8, 169u8, 217u8, 133u8, 217u8, 138u8, 217u8, 131u8, 216u8, 177u8, 217u8,
+                    136u8, 216u8, 171u8, 216u8, 167u8, 217u8, 134u8, 217u8, 138u8, 216u8, 169u8,
+                    217u8, 133u8, 217u8, 138u8, 217u8, 132u8, 217u8, 133u8, 217u8, 138u8, 217u8,
+                    132u8, 32u8, 217u8, 132u8, 217u8, 131u8, 217u8, 132u8, 32u8, 216u8, 186u8,
+                    216u8, 167u8, 217u8, 132u8, 217u8, 136u8, 217u8, 134u8, 217u8, 133u8, 217u8,
+                    138u8, 217u8, 132u8, 32u8, 217u8, 129u8, 217u8, 138u8, 32u8, 216u8, 167u8,
+                    217u8, 132u8, 216u8, 179u8, 216u8, 167u8, 216u8, 185u8, 216u8, 169u8, 217u8,
+                    133u8, 217u8, 138u8, 217u8, 132u8, 32u8, 216u8, 167u8, 216u8, 179u8, 217u8,
+                    131u8, 217u8, 134u8, 216u8, 175u8, 217u8, 134u8, 216u8, 167u8, 217u8, 129u8,
+                    217u8, 138u8, 217u8, 133u8, 217u8, 132u8, 217u8, 138u8, 217u8, 132u8, 216u8,
+                    170u8, 216u8, 177u8, 217u8, 133u8, 217u8, 132u8, 217u8, 138u8, 217u8, 133u8,
+                    216u8, 170u8, 216u8, 177u8, 217u8, 133u8, 217u8, 132u8, 217u8, 138u8, 32u8,
+                    216u8, 171u8, 216u8, 167u8, 217u8, 134u8, 217u8, 138u8, 216u8, 169u8, 216u8,
+                    175u8, 217u8, 130u8, 217u8, 138u8, 217u8, 130u8, 216u8, 169u8, 216u8, 180u8,
+                    217u8, 135u8, 217u8, 136u8, 216u8, 177u8, 217u8, 134u8, 216u8, 167u8, 217u8,
+                    134u8, 217u8, 136u8, 32u8, 216u8, 171u8, 216u8, 167u8, 217u8, 134u8, 217u8,
+                    138u8, 216u8, 169u8, 216u8, 163u8, 217u8, 136u8, 217u8, 134u8, 216u8, 181u8,
+                    216u8, 169u8, 216u8, 168u8, 216u8, 167u8, 217u8, 132u8, 217u8, 133u8, 216u8,
+                    167u8, 216u8, 166u8, 216u8, 169u8, 216u8, 168u8, 217u8, 138u8, 216u8, 170u8,
+                    216u8, 167u8, 216u8, 168u8, 216u8, 167u8, 217u8, 138u8, 216u8, 170u8, 216u8,
+                    177u8, 216u8, 183u8, 217u8, 132u8, 216u8, 171u8, 216u8, 167u8, 217u8, 134u8,
+                    217u8, 138u8, 216u8, 169u8, 216u8, 179u8, 217u8, 134u8, 216u8, 170u8, 217u8,
+                    138u8, 217u8, 133u8, 216u8, 170u8, 216u8, 177u8, 32u8, 217u8, 133u8, 216u8,
+                    177u8, 216u8, 168u8, 216u8, 185u8, 217u8, 130u8, 216u8, 175u8, 217u8, 133u8,
+                    32u8, 217u8, 133u8, 216u8, 177u8, 216u8, 168u8, 216u8, 185u8, 216u8, 169u8,
+                    216u8, 168u8, 217u8, 136u8, 216u8, 181u8, 216u8, 169u8, 32u8, 217u8, 133u8,
+                    216u8, 177u8, 216u8, 168u8, 216u8, 185u8, 216u8, 169u8, 217u8, 131u8, 217u8,
+                    138u8, 217u8, 132u8, 217u8, 136u8, 217u8, 133u8, 216u8, 170u8, 216u8, 177u8,
+                    32u8, 217u8, 133u8, 216u8, 177u8, 216u8, 168u8, 216u8, 185u8, 217u8, 133u8,
+                    216u8, 170u8, 216u8, 177u8, 32u8, 217u8, 133u8, 216u8, 177u8, 216u8, 168u8,
+                    216u8, 185u8, 217u8, 133u8, 217u8, 138u8, 217u8, 132u8, 32u8, 217u8, 133u8,
+                    216u8, 177u8, 216u8, 168u8, 216u8, 185u8, 217u8, 138u8, 216u8, 167u8, 216u8,
+                    177u8, 216u8, 175u8, 216u8, 169u8, 32u8, 217u8, 133u8, 216u8, 177u8, 216u8,
+                    168u8, 216u8, 185u8, 216u8, 169u8, 216u8, 179u8, 216u8, 170u8, 217u8, 136u8,
+                    217u8, 134u8, 216u8, 170u8, 217u8, 138u8, 216u8, 177u8, 216u8, 167u8, 216u8,
+                    168u8, 216u8, 170u8, 216u8, 170u8, 217u8, 138u8, 216u8, 177u8, 216u8, 167u8,
+                    216u8, 168u8, 216u8, 167u8, 217u8, 138u8, 216u8, 170u8, 216u8, 163u8, 216u8,
+                    179u8, 216u8, 167u8, 216u8, 168u8, 217u8, 138u8, 216u8, 185u8, 217u8, 138u8,
+                    216u8, 167u8, 216u8, 177u8, 216u8, 175u8, 216u8, 169u8, 216u8, 179u8, 217u8,
+                    134u8, 217u8, 136u8, 216u8, 167u8, 216u8, 170u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    30u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 26u8, 0u8, 36u8, 0u8, 51u8, 0u8,
+                    62u8, 0u8, 72u8, 0u8, 82u8, 0u8, 90u8, 0u8, 94u8, 0u8, 102u8, 0u8, 111u8, 0u8,
+                    129u8, 0u8, 148u8, 0u8, 164u8, 0u8, 187u8, 0u8, 198u8, 0u8, 213u8, 0u8, 226u8,
+                    0u8, 236u8, 0u8, 242u8, 0u8, 252u8, 0u8, 13u8, 1u8, 24u8, 1u8, 35u8, 1u8, 51u8,
+                    1u8, 63u8, 1u8, 74u8, 1u8, 85u8, 1u8, 89u8, 1u8, 99u8, 101u8, 110u8, 116u8,
+                    105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8,
+                    99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8,
+                    117u8, 98u8, 105u8, 99u8, 45u8, 102u8, 111u8, 111u8, 116u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 105u8, 108u8, 101u8, 99u8, 117u8,
+                    98u8, 105u8, 99u8, 45u8, 121u8, 97u8, 114u8, 100u8, 103u8, 105u8, 103u8, 97u8,
+                    98u8, 121u8, 116u8, 101u8, 104u8, 111u8, 117u8, 114u8, 107u8, 105u8, 108u8,
+                    111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 109u8, 105u8, 99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8, 105u8, 108u8,
+                    108u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 110u8, 117u8,
+                    116u8, 101u8, 110u8, 97u8, 110u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8,
+                    116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8,
+                    108u8, 101u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8,
+                    114u8, 100u8, 119u8, 101u8, 101u8, 107u8, 121u8, 101u8, 97u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    30u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 10u8, 0u8, 18u8, 0u8, 24u8, 0u8, 28u8,
+                    0u8, 36u8, 0u8, 48u8, 0u8, 53u8, 0u8, 61u8, 0u8, 67u8, 0u8, 71u8, 0u8, 78u8,
+                    0u8, 89u8, 0u8, 94u8, 0u8, 101u8, 0u8, 107u8, 0u8, 124u8, 0u8, 133u8, 0u8,
+                    141u8, 0u8, 143u8, 0u8, 149u8, 0u8, 156u8, 0u8, 164u8, 0u8, 174u8, 0u8, 180u8,
+                    0u8, 184u8, 0u8, 192u8, 0u8, 204u8, 0u8, 214u8, 0u8, 216u8, 179u8, 217u8,
+                    133u8, 216u8, 179u8, 217u8, 133u8, 194u8, 179u8, 217u8, 130u8, 216u8, 175u8,
+                    217u8, 133u8, 194u8, 179u8, 217u8, 131u8, 217u8, 133u8, 194u8, 179u8, 217u8,
+                    133u8, 194u8, 179u8, 217u8, 133u8, 217u8, 138u8, 217u8, 132u8, 194u8, 179u8,
+                    217u8, 138u8, 216u8, 167u8, 216u8, 177u8, 216u8, 175u8, 216u8, 169u8, 194u8,
+                    179u8, 216u8, 186u8, 46u8, 216u8, 168u8, 216u8, 179u8, 216u8, 167u8, 216u8,
+                    185u8, 216u8, 169u8, 217u8, 131u8, 216u8, 186u8, 217u8, 133u8, 217u8, 131u8,
+                    217u8, 133u8, 217u8, 131u8, 217u8, 133u8, 47u8, 216u8, 179u8, 217u8, 132u8,
+                    216u8, 170u8, 216u8, 177u8, 47u8, 217u8, 131u8, 217u8, 133u8, 217u8, 133u8,
+                    47u8, 216u8, 171u8, 217u8, 133u8, 47u8, 216u8, 171u8, 194u8, 178u8, 217u8,
+                    133u8, 46u8, 216u8, 171u8, 46u8, 217u8, 133u8, 217u8, 138u8, 217u8, 132u8,
+                    47u8, 216u8, 186u8, 216u8, 167u8, 217u8, 132u8, 217u8, 136u8, 217u8, 134u8,
+                    217u8, 133u8, 217u8, 138u8, 217u8, 132u8, 47u8, 216u8, 179u8, 217u8, 133u8,
+                    217u8, 132u8, 216u8, 170u8, 216u8, 177u8, 216u8, 175u8, 217u8, 134u8, 46u8,
+                    216u8, 171u8, 46u8, 216u8, 179u8, 217u8, 133u8, 32u8, 194u8, 178u8, 217u8,
+                    130u8, 216u8, 175u8, 217u8, 133u8, 194u8, 178u8, 216u8, 168u8, 217u8, 136u8,
+                    216u8, 181u8, 216u8, 169u8, 194u8, 178u8, 217u8, 131u8, 217u8, 133u8, 194u8,
+                    178u8, 217u8, 133u8, 194u8, 178u8, 217u8, 133u8, 217u8, 138u8, 217u8, 132u8,
+                    194u8, 178u8, 217u8, 138u8, 216u8, 167u8, 216u8, 177u8, 216u8, 175u8, 216u8,
+                    169u8, 194u8, 178u8, 216u8, 163u8, 216u8, 179u8, 216u8, 168u8, 217u8, 136u8,
+                    216u8, 185u8, 216u8, 179u8, 217u8, 134u8, 216u8, 169u8,
+                ])
+            },
+        )
+    },
+};
+static BN: &DataStruct = &::icu_displaynames::provider::UnitDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    65u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 7u8, 0u8, 11u8, 0u8, 18u8, 0u8, 28u8,
+                    0u8, 44u8, 0u8, 54u8, 0u8, 64u8, 0u8, 79u8, 0u8, 90u8, 0u8, 100u8, 0u8, 110u8,
+                    0u8, 113u8, 0u8, 119u8, 0u8, 129u8, 0u8, 140u8, 0u8, 144u8, 0u8, 150u8, 0u8,
+                    157u8, 0u8, 165u8, 0u8, 169u8, 0u8, 176u8, 0u8, 180u8, 0u8, 184u8, 0u8, 191u8,
+                    0u8, 199u8, 0u8, 207u8, 0u8, 216u8, 0u8, 234u8, 0u8, 239u8, 0u8, 2u8, 1u8, 9u8,
+                    1u8, 17u8, 1u8, 22u8, 1u8, 38u8, 1u8, 61u8, 1u8, 72u8, 1u8, 76u8, 1u8, 91u8,
+                    1u8, 104u8, 1u8, 121u8, 1u8, 131u8, 1u8, 141u8, 1u8, 152u8, 1u8, 158u8, 1u8,
+                    163u8, 1u8, 173u8, 1u8, 178u8, 1u8, 185u8, 1u8, 193u8, 1u8, 198u8, 1u8, 204u8,
+                    1u8, 221u8, 1u8, 232u8, 1u8, 243u8, 1u8, 3u8, 2u8, 15u8, 2u8, 26u8, 2u8, 37u8,
+                    2u8, 42u8, 2u8, 49u8, 2u8, 57u8, 2u8, 61u8, 2u8, 65u8, 2u8, 97u8, 99u8, 114u8,
+                    101u8, 98u8, 105u8, 116u8, 98u8, 121u8, 116u8, 101u8, 99u8, 101u8, 108u8,
+                    115u8, 105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8, 110u8,
+                    116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 102u8, 111u8, 111u8, 116u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8,
+                    105u8, 110u8, 99u8, 104u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8,
+                    45u8, 109u8, 105u8, 108u8, 101u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 121u8,
+                    97u8, 114u8, 100u8, 100u8, 97u8, 121u8, 100u8, 101u8, 103u8, 114u8, 101u8,
+                    101u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8,
+                    102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8,
+                    102u8, 111u8, 111u8, 116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8,
+                    105u8, 103u8, 97u8, 98u8, 105u8, 116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8,
+                    116u8, 101u8, 103u8, 114u8, 97u8, 109u8, 104u8, 101u8, 99u8, 116u8, 97u8,
+                    114u8, 101u8, 104u8, 111u8, 117u8, 114u8, 105u8, 110u8, 99u8, 104u8, 107u8,
+                    105u8, 108u8, 111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 98u8,
+                    121u8, 116u8, 101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 104u8, 111u8, 117u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8, 97u8,
+                    98u8, 105u8, 116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 108u8, 101u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8,
+                    101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8,
+                    105u8, 108u8, 101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8,
+                    118u8, 105u8, 97u8, 110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 108u8, 105u8,
+                    116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 110u8,
+                    116u8, 104u8, 110u8, 97u8, 110u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8, 101u8, 114u8, 99u8, 101u8,
+                    110u8, 116u8, 112u8, 101u8, 116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 112u8,
+                    111u8, 117u8, 110u8, 100u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8, 115u8,
+                    116u8, 111u8, 110u8, 101u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8,
+                    116u8, 101u8, 114u8, 97u8, 98u8, 121u8, 116u8, 101u8, 119u8, 101u8, 101u8,
+                    107u8, 121u8, 97u8, 114u8, 100u8, 121u8, 101u8, 97u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    65u8, 0u8, 0u8, 0u8, 0u8, 0u8, 9u8, 0u8, 18u8, 0u8, 30u8, 0u8, 76u8, 0u8,
+                    109u8, 0u8, 161u8, 0u8, 180u8, 0u8, 214u8, 0u8, 4u8, 1u8, 38u8, 1u8, 69u8, 1u8,
+                    94u8, 1u8, 103u8, 1u8, 121u8, 1u8, 167u8, 1u8, 201u8, 1u8, 210u8, 1u8, 228u8,
+                    1u8, 249u8, 1u8, 17u8, 2u8, 32u8, 2u8, 50u8, 2u8, 65u8, 2u8, 80u8, 2u8, 101u8,
+                    2u8, 125u8, 2u8, 152u8, 2u8, 179u8, 2u8, 238u8, 2u8, 253u8, 2u8, 60u8, 3u8,
+                    81u8, 3u8, 105u8, 3u8, 120u8, 3u8, 173u8, 3u8, 242u8, 3u8, 28u8, 4u8, 40u8,
+                    4u8, 91u8, 4u8, 135u8, 4u8, 202u8, 4u8, 229u8, 4u8, 0u8, 5u8, 33u8, 5u8, 48u8,
+                    5u8, 57u8, 5u8, 96u8, 5u8, 111u8, 5u8, 126u8, 5u8, 153u8, 5u8, 171u8, 5u8,
+                    192u8, 5u8, 238u8, 5u8, 4u8, 6u8, 32u8, 6u8, 72u8, 6u8, 100u8, 6u8, 125u8, 6u8,
+                    129u8, 6u8, 131u8, 6u8, 152u8, 6u8, 176u8, 6u8, 194u8, 6u8, 200u8, 6u8, 224u8,
+                    166u8, 143u8, 224u8, 166u8, 149u8, 224u8, 166u8, 176u8, 224u8, 166u8, 172u8,
+                    224u8, 166u8, 191u8, 224u8, 166u8, 159u8, 224u8, 166u8, 172u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 135u8, 224u8, 166u8, 159u8, 224u8, 166u8, 161u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 151u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8,
+                    224u8, 167u8, 128u8, 32u8, 224u8, 166u8, 184u8, 224u8, 167u8, 135u8, 224u8,
+                    166u8, 178u8, 224u8, 166u8, 184u8, 224u8, 166u8, 191u8, 224u8, 166u8, 175u8,
+                    224u8, 166u8, 188u8, 224u8, 166u8, 190u8, 224u8, 166u8, 184u8, 224u8, 166u8,
+                    184u8, 224u8, 167u8, 135u8, 224u8, 166u8, 168u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 159u8, 224u8, 166u8, 191u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 159u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 166u8,
+                    149u8, 224u8, 166u8, 191u8, 224u8, 166u8, 137u8, 224u8, 166u8, 172u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 149u8, 32u8, 224u8, 166u8, 184u8, 224u8, 167u8,
+                    135u8, 224u8, 166u8, 168u8, 224u8, 167u8, 141u8, 224u8, 166u8, 159u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8, 224u8, 166u8, 159u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 166u8, 152u8, 224u8, 166u8,
+                    168u8, 224u8, 166u8, 149u8, 32u8, 224u8, 166u8, 171u8, 224u8, 167u8, 129u8,
+                    224u8, 166u8, 159u8, 224u8, 166u8, 149u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    137u8, 224u8, 166u8, 172u8, 224u8, 166u8, 191u8, 224u8, 166u8, 149u8, 32u8,
+                    224u8, 166u8, 135u8, 224u8, 166u8, 158u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                    154u8, 224u8, 166u8, 191u8, 224u8, 166u8, 149u8, 224u8, 166u8, 191u8, 224u8,
+                    166u8, 137u8, 224u8, 166u8, 172u8, 224u8, 166u8, 191u8, 224u8, 166u8, 149u8,
+                    32u8, 224u8, 166u8, 149u8, 224u8, 166u8, 191u8, 224u8, 166u8, 178u8, 224u8,
+                    167u8, 139u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8, 224u8, 166u8, 159u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 166u8, 149u8, 224u8, 166u8,
+                    191u8, 224u8, 166u8, 137u8, 224u8, 166u8, 172u8, 224u8, 166u8, 191u8, 224u8,
+                    166u8, 149u8, 32u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    159u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 166u8, 149u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 137u8, 224u8, 166u8, 172u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 149u8, 32u8, 224u8, 166u8, 174u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 135u8, 224u8, 166u8, 178u8, 224u8, 166u8, 149u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 137u8, 224u8, 166u8, 172u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    149u8, 32u8, 224u8, 166u8, 151u8, 224u8, 166u8, 156u8, 224u8, 166u8, 166u8,
+                    224u8, 166u8, 191u8, 224u8, 166u8, 168u8, 224u8, 166u8, 161u8, 224u8, 166u8,
+                    191u8, 224u8, 166u8, 151u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8,
+                    167u8, 128u8, 224u8, 166u8, 161u8, 224u8, 166u8, 191u8, 224u8, 166u8, 151u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 167u8, 128u8, 32u8, 224u8,
+                    166u8, 171u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 167u8, 135u8,
+                    224u8, 166u8, 168u8, 224u8, 166u8, 185u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    135u8, 224u8, 166u8, 159u8, 224u8, 166u8, 171u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 178u8, 224u8, 167u8, 129u8, 224u8, 166u8, 135u8, 224u8, 166u8, 161u8,
+                    32u8, 224u8, 166u8, 134u8, 224u8, 166u8, 137u8, 224u8, 166u8, 168u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 184u8, 224u8, 166u8, 171u8, 224u8, 167u8, 129u8,
+                    224u8, 166u8, 159u8, 224u8, 166u8, 151u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                    175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 178u8, 224u8, 166u8, 168u8, 224u8,
+                    166u8, 151u8, 224u8, 166u8, 191u8, 224u8, 166u8, 151u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 172u8, 224u8, 166u8, 191u8, 224u8, 166u8, 159u8, 224u8, 166u8,
+                    151u8, 224u8, 166u8, 191u8, 224u8, 166u8, 151u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 172u8, 224u8, 166u8, 190u8, 224u8, 166u8, 135u8, 224u8, 166u8, 159u8,
+                    224u8, 166u8, 151u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 174u8, 224u8, 166u8, 185u8, 224u8, 167u8, 135u8, 224u8,
+                    166u8, 149u8, 224u8, 167u8, 141u8, 224u8, 166u8, 159u8, 224u8, 166u8, 176u8,
+                    224u8, 166u8, 152u8, 224u8, 166u8, 168u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                    159u8, 224u8, 166u8, 190u8, 224u8, 166u8, 135u8, 224u8, 166u8, 158u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 154u8, 224u8, 166u8, 191u8, 224u8, 166u8, 149u8,
+                    224u8, 166u8, 191u8, 224u8, 166u8, 178u8, 224u8, 167u8, 139u8, 224u8, 166u8,
+                    172u8, 224u8, 166u8, 191u8, 224u8, 166u8, 159u8, 224u8, 166u8, 149u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 178u8, 224u8, 167u8, 139u8, 224u8, 166u8, 172u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 135u8, 224u8, 166u8, 159u8, 224u8, 166u8,
+                    149u8, 224u8, 166u8, 191u8, 224u8, 166u8, 178u8, 224u8, 167u8, 139u8, 224u8,
+                    166u8, 151u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 174u8, 224u8, 166u8, 149u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    178u8, 224u8, 167u8, 139u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8, 224u8,
+                    166u8, 159u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 166u8, 152u8,
+                    224u8, 166u8, 168u8, 224u8, 167u8, 141u8, 224u8, 166u8, 159u8, 224u8, 166u8,
+                    190u8, 32u8, 224u8, 166u8, 170u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8,
+                    224u8, 166u8, 164u8, 224u8, 166u8, 191u8, 32u8, 224u8, 166u8, 149u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 178u8, 224u8, 167u8, 139u8, 224u8, 166u8, 174u8,
+                    224u8, 166u8, 191u8, 224u8, 166u8, 159u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    176u8, 224u8, 166u8, 178u8, 224u8, 166u8, 191u8, 224u8, 166u8, 159u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 166u8, 178u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 159u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 44u8, 32u8,
+                    224u8, 166u8, 170u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8,
+                    164u8, 224u8, 166u8, 191u8, 32u8, 224u8, 166u8, 149u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 178u8, 224u8, 167u8, 139u8, 224u8, 166u8, 174u8, 224u8, 166u8,
+                    191u8, 224u8, 166u8, 159u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8,
+                    167u8, 135u8, 224u8, 166u8, 174u8, 224u8, 167u8, 135u8, 224u8, 166u8, 151u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 172u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    159u8, 224u8, 166u8, 174u8, 224u8, 167u8, 135u8, 224u8, 166u8, 151u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 172u8, 224u8, 166u8, 190u8, 224u8, 166u8, 135u8,
+                    224u8, 166u8, 159u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    159u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 166u8, 174u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 159u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8,
+                    32u8, 224u8, 166u8, 170u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8,
+                    166u8, 164u8, 224u8, 166u8, 191u8, 32u8, 224u8, 166u8, 184u8, 224u8, 167u8,
+                    135u8, 224u8, 166u8, 149u8, 224u8, 167u8, 135u8, 224u8, 166u8, 168u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 161u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 159u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 32u8, 224u8,
+                    166u8, 170u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 164u8,
+                    224u8, 166u8, 191u8, 32u8, 224u8, 166u8, 172u8, 224u8, 166u8, 176u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 151u8, 32u8, 224u8, 166u8, 184u8, 224u8, 167u8,
+                    135u8, 224u8, 166u8, 149u8, 224u8, 167u8, 135u8, 224u8, 166u8, 168u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 161u8, 224u8, 167u8, 135u8, 224u8, 166u8, 174u8,
+                    224u8, 166u8, 190u8, 224u8, 166u8, 135u8, 224u8, 166u8, 149u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 176u8, 224u8, 167u8, 139u8, 224u8, 166u8, 184u8, 224u8,
+                    167u8, 135u8, 224u8, 166u8, 149u8, 224u8, 167u8, 135u8, 224u8, 166u8, 168u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 161u8, 224u8, 166u8, 174u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 135u8, 224u8, 166u8, 178u8, 224u8, 166u8, 174u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 135u8, 224u8, 166u8, 178u8, 44u8, 32u8, 224u8,
+                    166u8, 170u8, 224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 164u8,
+                    224u8, 166u8, 191u8, 32u8, 224u8, 166u8, 151u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 178u8, 224u8, 166u8, 168u8,
+                    224u8, 167u8, 135u8, 224u8, 166u8, 152u8, 224u8, 166u8, 168u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 159u8, 224u8, 166u8, 190u8, 32u8, 224u8, 166u8, 170u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 164u8, 224u8, 166u8,
+                    191u8, 32u8, 224u8, 166u8, 174u8, 224u8, 166u8, 190u8, 224u8, 166u8, 135u8,
+                    224u8, 166u8, 178u8, 224u8, 166u8, 174u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    135u8, 224u8, 166u8, 178u8, 45u8, 224u8, 166u8, 184u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 149u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 168u8, 224u8, 167u8, 141u8, 224u8, 166u8, 161u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 168u8, 224u8, 167u8, 135u8, 224u8, 166u8, 173u8,
+                    224u8, 166u8, 191u8, 224u8, 166u8, 175u8, 224u8, 166u8, 188u8, 224u8, 166u8,
+                    190u8, 224u8, 166u8, 168u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8, 224u8,
+                    166u8, 178u8, 224u8, 166u8, 191u8, 224u8, 166u8, 178u8, 224u8, 166u8, 191u8,
+                    224u8, 166u8, 159u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 166u8,
+                    174u8, 224u8, 166u8, 191u8, 224u8, 166u8, 178u8, 224u8, 166u8, 191u8, 224u8,
+                    166u8, 174u8, 224u8, 166u8, 191u8, 224u8, 166u8, 159u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 176u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    178u8, 224u8, 166u8, 191u8, 224u8, 166u8, 184u8, 224u8, 167u8, 135u8, 224u8,
+                    166u8, 149u8, 224u8, 167u8, 135u8, 224u8, 166u8, 168u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 161u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    168u8, 224u8, 166u8, 191u8, 224u8, 166u8, 159u8, 224u8, 166u8, 174u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 184u8, 224u8, 166u8, 168u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8, 166u8, 168u8, 224u8, 167u8,
+                    139u8, 224u8, 166u8, 184u8, 224u8, 167u8, 135u8, 224u8, 166u8, 149u8, 224u8,
+                    167u8, 135u8, 224u8, 166u8, 168u8, 224u8, 167u8, 141u8, 224u8, 166u8, 161u8,
+                    224u8, 166u8, 134u8, 224u8, 166u8, 137u8, 224u8, 166u8, 168u8, 224u8, 167u8,
+                    141u8, 224u8, 166u8, 184u8, 224u8, 166u8, 182u8, 224u8, 166u8, 164u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 130u8, 224u8, 166u8, 182u8, 224u8, 166u8, 170u8,
+                    224u8, 167u8, 135u8, 224u8, 166u8, 159u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    172u8, 224u8, 166u8, 190u8, 224u8, 166u8, 135u8, 224u8, 166u8, 159u8, 224u8,
+                    166u8, 184u8, 224u8, 166u8, 170u8, 224u8, 166u8, 190u8, 224u8, 166u8, 137u8,
+                    224u8, 166u8, 168u8, 224u8, 167u8, 141u8, 224u8, 166u8, 161u8, 224u8, 166u8,
+                    184u8, 224u8, 167u8, 135u8, 224u8, 166u8, 149u8, 224u8, 167u8, 135u8, 224u8,
+                    166u8, 168u8, 224u8, 167u8, 141u8, 224u8, 166u8, 161u8, 224u8, 166u8, 172u8,
+                    224u8, 166u8, 176u8, 224u8, 167u8, 141u8, 224u8, 166u8, 151u8, 32u8, 224u8,
+                    166u8, 184u8, 224u8, 167u8, 135u8, 224u8, 166u8, 168u8, 224u8, 167u8, 141u8,
+                    224u8, 166u8, 159u8, 224u8, 166u8, 191u8, 224u8, 166u8, 174u8, 224u8, 166u8,
+                    191u8, 224u8, 166u8, 159u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8,
+                    166u8, 172u8, 224u8, 166u8, 176u8, 224u8, 167u8, 141u8, 224u8, 166u8, 151u8,
+                    32u8, 224u8, 166u8, 171u8, 224u8, 167u8, 129u8, 224u8, 166u8, 159u8, 224u8,
+                    166u8, 172u8, 224u8, 166u8, 176u8, 224u8, 167u8, 141u8, 224u8, 166u8, 151u8,
+                    32u8, 224u8, 166u8, 135u8, 224u8, 166u8, 158u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 154u8, 224u8, 166u8, 191u8, 224u8, 166u8, 172u8, 224u8, 166u8, 176u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 151u8, 32u8, 224u8, 166u8, 149u8, 224u8,
+                    166u8, 191u8, 224u8, 166u8, 178u8, 224u8, 167u8, 139u8, 224u8, 166u8, 174u8,
+                    224u8, 166u8, 191u8, 224u8, 166u8, 159u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                    176u8, 224u8, 166u8, 172u8, 224u8, 166u8, 176u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 151u8, 32u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    159u8, 224u8, 166u8, 190u8, 224u8, 166u8, 176u8, 224u8, 166u8, 172u8, 224u8,
+                    166u8, 176u8, 224u8, 167u8, 141u8, 224u8, 166u8, 151u8, 32u8, 224u8, 166u8,
+                    174u8, 224u8, 166u8, 190u8, 224u8, 166u8, 135u8, 224u8, 166u8, 178u8, 121u8,
+                    100u8, 194u8, 178u8, 115u8, 116u8, 224u8, 166u8, 159u8, 224u8, 167u8, 135u8,
+                    224u8, 166u8, 176u8, 224u8, 166u8, 190u8, 224u8, 166u8, 172u8, 224u8, 166u8,
+                    191u8, 224u8, 166u8, 159u8, 224u8, 166u8, 159u8, 224u8, 167u8, 135u8, 224u8,
+                    166u8, 176u8, 224u8, 166u8, 190u8, 224u8, 166u8, 172u8, 224u8, 166u8, 190u8,
+                    224u8, 166u8, 135u8, 224u8, 166u8, 159u8, 224u8, 166u8, 184u8, 224u8, 166u8,
+                    170u8, 224u8, 167u8, 141u8, 224u8, 166u8, 164u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 185u8, 224u8, 166u8, 151u8, 224u8, 166u8, 156u8, 224u8, 166u8, 172u8,
+                    224u8, 166u8, 155u8, 224u8, 166u8, 176u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    33u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 17u8, 0u8, 33u8, 0u8, 43u8, 0u8, 53u8,
+                    0u8, 64u8, 0u8, 74u8, 0u8, 84u8, 0u8, 94u8, 0u8, 105u8, 0u8, 111u8, 0u8, 118u8,
+                    0u8, 126u8, 0u8, 133u8, 0u8, 141u8, 0u8, 149u8, 0u8, 167u8, 0u8, 186u8, 0u8,
+                    193u8, 0u8, 201u8, 0u8, 224u8, 0u8, 235u8, 0u8, 250u8, 0u8, 11u8, 1u8, 21u8,
+                    1u8, 31u8, 1u8, 39u8, 1u8, 56u8, 1u8, 67u8, 1u8, 83u8, 1u8, 94u8, 1u8, 101u8,
+                    1u8, 99u8, 101u8, 108u8, 115u8, 105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8,
+                    105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8,
+                    99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8,
+                    117u8, 98u8, 105u8, 99u8, 45u8, 102u8, 111u8, 111u8, 116u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 45u8, 105u8, 110u8, 99u8, 104u8, 99u8, 117u8, 98u8, 105u8, 99u8,
+                    45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8,
+                    109u8, 105u8, 108u8, 101u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 121u8, 97u8,
+                    114u8, 100u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8,
+                    116u8, 102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8,
+                    101u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8, 105u8, 103u8, 97u8,
+                    98u8, 105u8, 116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8,
+                    107u8, 105u8, 108u8, 111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8,
+                    98u8, 121u8, 116u8, 101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8,
+                    109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 108u8, 105u8, 116u8,
+                    101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8, 108u8, 111u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8, 97u8, 98u8, 105u8,
+                    116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8,
+                    97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8,
+                    105u8, 99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8,
+                    105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 103u8, 97u8, 108u8,
+                    108u8, 111u8, 110u8, 109u8, 105u8, 108u8, 101u8, 45u8, 115u8, 99u8, 97u8,
+                    110u8, 100u8, 105u8, 110u8, 97u8, 118u8, 105u8, 97u8, 110u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8,
+                    108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 112u8, 101u8, 116u8, 97u8,
+                    98u8, 121u8, 116u8, 101u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8,
+                    101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8,
+                    97u8, 114u8, 100u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8, 116u8,
+                    101u8, 114u8, 97u8, 98u8, 121u8, 116u8, 101u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    33u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 18u8, 0u8, 22u8, 0u8, 26u8, 0u8, 30u8,
+                    0u8, 33u8, 0u8, 37u8, 0u8, 41u8, 0u8, 44u8, 0u8, 49u8, 0u8, 52u8, 0u8, 54u8,
+                    0u8, 56u8, 0u8, 58u8, 0u8, 60u8, 0u8, 72u8, 0u8, 76u8, 0u8, 104u8, 0u8, 106u8,
+                    0u8, 108u8, 0u8, 113u8, 0u8, 119u8, 0u8, 150u8, 0u8, 153u8, 0u8, 155u8, 0u8,
+                    167u8, 0u8, 172u8, 0u8, 197u8, 0u8, 201u8, 0u8, 226u8, 0u8, 245u8, 0u8, 249u8,
+                    0u8, 100u8, 101u8, 103u8, 46u8, 32u8, 67u8, 224u8, 166u8, 184u8, 224u8, 167u8,
+                    135u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8, 99u8, 109u8, 194u8, 179u8,
+                    102u8, 116u8, 194u8, 179u8, 105u8, 110u8, 194u8, 179u8, 109u8, 194u8, 179u8,
+                    109u8, 105u8, 194u8, 179u8, 121u8, 100u8, 194u8, 179u8, 194u8, 176u8, 70u8,
+                    102u8, 108u8, 32u8, 111u8, 122u8, 103u8, 97u8, 108u8, 71u8, 98u8, 71u8, 66u8,
+                    107u8, 98u8, 107u8, 66u8, 224u8, 166u8, 149u8, 224u8, 167u8, 135u8, 224u8,
+                    166u8, 156u8, 224u8, 166u8, 191u8, 107u8, 109u8, 47u8, 104u8, 224u8, 166u8,
+                    178u8, 224u8, 166u8, 191u8, 224u8, 166u8, 159u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 176u8, 47u8, 224u8, 166u8, 149u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    174u8, 224u8, 166u8, 191u8, 77u8, 98u8, 77u8, 66u8, 109u8, 47u8, 115u8, 194u8,
+                    178u8, 206u8, 188u8, 115u8, 101u8, 99u8, 115u8, 224u8, 166u8, 174u8, 224u8,
+                    166u8, 190u8, 224u8, 166u8, 135u8, 224u8, 166u8, 178u8, 47u8, 224u8, 166u8,
+                    151u8, 224u8, 167u8, 141u8, 224u8, 166u8, 175u8, 224u8, 166u8, 190u8, 224u8,
+                    166u8, 178u8, 224u8, 166u8, 168u8, 115u8, 109u8, 105u8, 109u8, 76u8, 224u8,
+                    166u8, 174u8, 224u8, 166u8, 191u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8,
+                    80u8, 66u8, 121u8, 116u8, 101u8, 224u8, 166u8, 172u8, 224u8, 166u8, 176u8,
+                    224u8, 167u8, 141u8, 224u8, 166u8, 151u8, 32u8, 224u8, 166u8, 184u8, 224u8,
+                    167u8, 135u8, 224u8, 166u8, 174u8, 224u8, 166u8, 191u8, 105u8, 110u8, 194u8,
+                    178u8, 224u8, 166u8, 172u8, 224u8, 166u8, 176u8, 224u8, 167u8, 141u8, 224u8,
+                    166u8, 151u8, 32u8, 224u8, 166u8, 149u8, 224u8, 166u8, 191u8, 224u8, 166u8,
+                    174u8, 224u8, 166u8, 191u8, 224u8, 166u8, 172u8, 224u8, 166u8, 176u8, 224u8,
+                    167u8, 141u8, 224u8, 166u8, 151u8, 32u8, 224u8, 166u8, 151u8, 224u8, 166u8,
+                    156u8, 84u8, 98u8, 105u8, 116u8, 84u8, 66u8, 121u8, 116u8, 101u8,
+                ])
+            },
+        )
+    },
+};
+static CCP: &DataStruct = &::icu_displaynames::provider::UnitDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    65u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 7u8, 0u8, 11u8, 0u8, 18u8, 0u8, 28u8,
+                    0u8, 44u8, 0u8, 54u8, 0u8, 64u8, 0u8, 79u8, 0u8, 90u8, 0u8, 100u8, 0u8, 110u8,
+                    0u8, 113u8, 0u8, 119u8, 0u8, 129u8, 0u8, 140u8, 0u8, 144u8, 0u8, 150u8, 0u8,
+                    157u8, 0u8, 165u8, 0u8, 169u8, 0u8, 176u8, 0u8, 180u8, 0u8, 184u8, 0u8, 191u8,
+                    0u8, 199u8, 0u8, 207u8, 0u8, 216u8, 0u8, 234u8, 0u8, 239u8, 0u8, 2u8, 1u8, 9u8,
+                    1u8, 17u8, 1u8, 22u8, 1u8, 38u8, 1u8, 61u8, 1u8, 72u8, 1u8, 76u8, 1u8, 91u8,
+                    1u8, 104u8, 1u8, 121u8, 1u8, 131u8, 1u8, 141u8, 1u8, 152u8, 1u8, 158u8, 1u8,
+                    163u8, 1u8, 173u8, 1u8, 178u8, 1u8, 185u8, 1u8, 193u8, 1u8, 198u8, 1u8, 204u8,
+                    1u8, 221u8, 1u8, 232u8, 1u8, 243u8, 1u8, 3u8, 2u8, 15u8, 2u8, 26u8, 2u8, 37u8,
+                    2u8, 42u8, 2u8, 49u8, 2u8, 57u8, 2u8, 61u8, 2u8, 65u8, 2u8, 97u8, 99u8, 114u8,
+                    101u8, 98u8, 105u8, 116u8, 98u8, 121u8, 116u8, 101u8, 99u8, 101u8, 108u8,
+                    115u8, 105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8, 110u8,
+                    116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 102u8, 111u8, 111u8, 116u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8,
+                    105u8, 110u8, 99u8, 104u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8,
+                    45u8, 109u8, 105u8, 108u8, 101u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 121u8,
+                    97u8, 114u8, 100u8, 100u8, 97u8, 121u8, 100u8, 101u8, 103u8, 114u8, 101u8,
+                    101u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8,
+                    102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8,
+                    102u8, 111u8, 111u8, 116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8,
+                    105u8, 103u8, 97u8, 98u8, 105u8, 116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8,
+                    116u8, 101u8, 103u8, 114u8, 97u8, 109u8, 104u8, 101u8, 99u8, 116u8, 97u8,
+                    114u8, 101u8, 104u8, 111u8, 117u8, 114u8, 105u8, 110u8, 99u8, 104u8, 107u8,
+                    105u8, 108u8, 111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 98u8,
+                    121u8, 116u8, 101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 104u8, 111u8, 117u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8, 97u8,
+                    98u8, 105u8, 116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 108u8, 101u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8,
+                    101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8,
+                    105u8, 108u8, 101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8,
+                    118u8, 105u8, 97u8, 110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 108u8, 105u8,
+                    116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 110u8,
+                    116u8, 104u8, 110u8, 97u8, 110u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8, 101u8, 114u8, 99u8, 101u8,
+                    110u8, 116u8, 112u8, 101u8, 116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 112u8,
+                    111u8, 117u8, 110u8, 100u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8, 115u8,
+                    116u8, 111u8, 110u8, 101u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8,
+                    116u8, 101u8, 114u8, 97u8, 98u8, 121u8, 116u8, 101u8, 119u8, 101u8, 101u8,
+                    107u8, 121u8, 97u8, 114u8, 100u8, 121u8, 101u8, 97u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    65u8, 0u8, 0u8, 0u8, 0u8, 0u8, 24u8, 0u8, 40u8, 0u8, 56u8, 0u8, 117u8, 0u8,
+                    161u8, 0u8, 238u8, 0u8, 31u8, 1u8, 88u8, 1u8, 157u8, 1u8, 210u8, 1u8, 3u8, 2u8,
+                    52u8, 2u8, 68u8, 2u8, 92u8, 2u8, 153u8, 2u8, 214u8, 2u8, 230u8, 2u8, 2u8, 3u8,
+                    30u8, 3u8, 58u8, 3u8, 78u8, 3u8, 110u8, 3u8, 130u8, 3u8, 154u8, 3u8, 186u8,
+                    3u8, 218u8, 3u8, 254u8, 3u8, 34u8, 4u8, 116u8, 4u8, 136u8, 4u8, 219u8, 4u8,
+                    247u8, 4u8, 19u8, 5u8, 39u8, 5u8, 117u8, 5u8, 220u8, 5u8, 20u8, 6u8, 36u8, 6u8,
+                    107u8, 6u8, 169u8, 6u8, 250u8, 6u8, 30u8, 7u8, 66u8, 7u8, 114u8, 7u8, 138u8,
+                    7u8, 150u8, 7u8, 202u8, 7u8, 230u8, 7u8, 231u8, 7u8, 233u8, 7u8, 5u8, 8u8,
+                    37u8, 8u8, 106u8, 8u8, 147u8, 8u8, 196u8, 8u8, 1u8, 9u8, 46u8, 9u8, 83u8, 9u8,
+                    87u8, 9u8, 89u8, 9u8, 117u8, 9u8, 141u8, 9u8, 157u8, 9u8, 173u8, 9u8, 240u8,
+                    145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 135u8,
+                    240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8,
+                    180u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8,
+                    132u8, 150u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 157u8, 240u8,
+                    145u8, 132u8, 173u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    137u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8,
+                    132u8, 169u8, 32u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 172u8,
+                    240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8,
+                    165u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8,
+                    132u8, 140u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 165u8, 240u8,
+                    145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 145u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    159u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8,
+                    132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 135u8, 240u8,
+                    145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 133u8, 240u8, 145u8, 132u8, 170u8,
+                    240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    135u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 165u8, 240u8,
+                    145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 145u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    159u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8,
+                    132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 135u8, 240u8,
+                    145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 133u8, 240u8, 145u8, 132u8, 170u8,
+                    240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    135u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 156u8, 240u8,
+                    145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    133u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8,
+                    132u8, 168u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 180u8, 32u8,
+                    240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 140u8, 240u8, 145u8,
+                    132u8, 168u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                    145u8, 132u8, 133u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 157u8,
+                    240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8,
+                    180u8, 32u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                    145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 159u8,
+                    240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8, 132u8,
+                    162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8,
+                    132u8, 168u8, 240u8, 145u8, 132u8, 133u8, 240u8, 145u8, 132u8, 170u8, 240u8,
+                    145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 135u8,
+                    240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8,
+                    132u8, 168u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8, 132u8, 162u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 168u8,
+                    240u8, 145u8, 132u8, 133u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8,
+                    157u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8,
+                    132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 172u8,
+                    240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8,
+                    135u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 133u8, 240u8, 145u8,
+                    132u8, 170u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                    145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8,
+                    137u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 140u8, 240u8, 145u8,
+                    132u8, 180u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                    145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 147u8,
+                    240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8,
+                    179u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 169u8, 240u8, 145u8,
+                    132u8, 147u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 137u8, 240u8,
+                    145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 169u8,
+                    32u8, 240u8, 145u8, 132u8, 156u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8,
+                    132u8, 172u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 166u8, 240u8, 145u8, 132u8, 173u8, 240u8, 145u8, 132u8, 150u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 156u8, 240u8, 145u8, 132u8,
+                    179u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8,
+                    132u8, 131u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 147u8, 240u8,
+                    145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8,
+                    133u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8,
+                    132u8, 179u8, 240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 156u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 150u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8,
+                    179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8,
+                    132u8, 167u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 137u8,
+                    240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    150u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8,
+                    132u8, 168u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 157u8, 240u8,
+                    145u8, 132u8, 173u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8,
+                    162u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8,
+                    132u8, 166u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 135u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8, 132u8, 167u8,
+                    240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8,
+                    138u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8,
+                    132u8, 180u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 131u8, 240u8,
+                    145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    135u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8,
+                    132u8, 174u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                    145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 135u8,
+                    240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8,
+                    174u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 173u8, 240u8, 145u8,
+                    132u8, 150u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 135u8, 240u8,
+                    145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 174u8,
+                    240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8,
+                    162u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8,
+                    132u8, 135u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 163u8, 240u8,
+                    145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 168u8,
+                    240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8,
+                    180u8, 240u8, 145u8, 132u8, 138u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8,
+                    132u8, 154u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 145u8, 32u8,
+                    240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8,
+                    162u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8,
+                    132u8, 168u8, 32u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 168u8,
+                    240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8,
+                    159u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8,
+                    132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 163u8, 240u8,
+                    145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8, 132u8, 162u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8,
+                    168u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8,
+                    132u8, 180u8, 44u8, 32u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8,
+                    179u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8,
+                    132u8, 150u8, 240u8, 145u8, 132u8, 168u8, 32u8, 240u8, 145u8, 132u8, 135u8,
+                    240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8,
+                    174u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8,
+                    132u8, 145u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 172u8, 240u8,
+                    145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 137u8,
+                    240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    150u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8,
+                    132u8, 172u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 157u8, 240u8,
+                    145u8, 132u8, 173u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    145u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8,
+                    132u8, 159u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 145u8, 240u8,
+                    145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8, 132u8,
+                    155u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8,
+                    132u8, 167u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 168u8, 32u8,
+                    240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8,
+                    135u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8,
+                    132u8, 179u8, 240u8, 145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 145u8,
+                    240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 32u8, 240u8, 145u8,
+                    132u8, 155u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 162u8, 240u8,
+                    145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 168u8,
+                    32u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8,
+                    132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 137u8, 240u8,
+                    145u8, 132u8, 167u8, 32u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8,
+                    172u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8,
+                    132u8, 154u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 147u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 173u8,
+                    240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8,
+                    162u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8,
+                    132u8, 172u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 172u8, 240u8,
+                    145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 147u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8,
+                    172u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8,
+                    132u8, 159u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 163u8, 240u8,
+                    145u8, 132u8, 180u8, 44u8, 32u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8,
+                    132u8, 179u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 167u8, 240u8,
+                    145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 168u8, 32u8, 240u8, 145u8, 132u8,
+                    137u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8,
+                    132u8, 163u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 154u8, 240u8,
+                    145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 138u8, 240u8, 145u8, 132u8, 167u8,
+                    240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8,
+                    145u8, 32u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 179u8, 240u8,
+                    145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 150u8,
+                    240u8, 145u8, 132u8, 168u8, 32u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8,
+                    132u8, 172u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 163u8,
+                    240u8, 145u8, 132u8, 180u8, 45u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8,
+                    132u8, 179u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 179u8, 240u8,
+                    145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 147u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    154u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 158u8, 240u8, 145u8,
+                    132u8, 168u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 154u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 168u8,
+                    240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    163u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8,
+                    132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 159u8, 240u8,
+                    145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 168u8,
+                    240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8,
+                    145u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8,
+                    132u8, 159u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 163u8, 240u8,
+                    145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 172u8,
+                    240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8,
+                    154u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 147u8, 240u8, 145u8,
+                    132u8, 180u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                    145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 150u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8,
+                    140u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8,
+                    132u8, 179u8, 240u8, 145u8, 132u8, 160u8, 240u8, 145u8, 132u8, 154u8, 240u8,
+                    145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 172u8,
+                    240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8,
+                    154u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 147u8, 240u8, 145u8,
+                    132u8, 180u8, 240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 133u8, 240u8,
+                    145u8, 132u8, 170u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 179u8,
+                    240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 180u8, 37u8, 80u8, 66u8,
+                    240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8, 133u8, 240u8, 145u8, 132u8,
+                    170u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8,
+                    132u8, 147u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 165u8, 240u8,
+                    145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 172u8,
+                    240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8,
+                    147u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8,
+                    132u8, 167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 167u8, 32u8, 240u8, 145u8, 132u8,
+                    165u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8,
+                    132u8, 180u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                    145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 145u8,
+                    240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8,
+                    157u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8,
+                    132u8, 180u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 167u8, 32u8,
+                    240u8, 145u8, 132u8, 156u8, 240u8, 145u8, 132u8, 170u8, 240u8, 145u8, 132u8,
+                    150u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8,
+                    132u8, 167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 167u8, 32u8, 240u8, 145u8, 132u8,
+                    131u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8,
+                    132u8, 180u8, 240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                    145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8,
+                    167u8, 32u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 168u8, 240u8,
+                    145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 159u8,
+                    240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8, 132u8,
+                    162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8,
+                    132u8, 167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 167u8, 32u8, 240u8, 145u8, 132u8,
+                    159u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8,
+                    132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 157u8, 240u8,
+                    145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 167u8, 32u8, 240u8, 145u8,
+                    132u8, 159u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 173u8, 121u8,
+                    100u8, 194u8, 178u8, 115u8, 116u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8,
+                    132u8, 172u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 157u8, 240u8,
+                    145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 180u8,
+                    240u8, 145u8, 132u8, 145u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8,
+                    157u8, 240u8, 145u8, 132u8, 173u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8,
+                    132u8, 180u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8, 132u8, 155u8, 240u8,
+                    145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 150u8, 240u8, 145u8, 132u8, 137u8,
+                    240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8,
+                    180u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8,
+                    132u8, 143u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8, 240u8,
+                    145u8, 132u8, 180u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    38u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 11u8, 0u8, 21u8, 0u8, 37u8, 0u8, 47u8,
+                    0u8, 57u8, 0u8, 68u8, 0u8, 78u8, 0u8, 88u8, 0u8, 98u8, 0u8, 109u8, 0u8, 115u8,
+                    0u8, 122u8, 0u8, 130u8, 0u8, 134u8, 0u8, 141u8, 0u8, 149u8, 0u8, 157u8, 0u8,
+                    166u8, 0u8, 184u8, 0u8, 203u8, 0u8, 210u8, 0u8, 218u8, 0u8, 241u8, 0u8, 252u8,
+                    0u8, 11u8, 1u8, 24u8, 1u8, 41u8, 1u8, 51u8, 1u8, 61u8, 1u8, 66u8, 1u8, 83u8,
+                    1u8, 94u8, 1u8, 110u8, 1u8, 121u8, 1u8, 132u8, 1u8, 139u8, 1u8, 97u8, 99u8,
+                    114u8, 101u8, 99u8, 101u8, 108u8, 115u8, 105u8, 117u8, 115u8, 99u8, 101u8,
+                    110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 102u8, 111u8, 111u8, 116u8,
+                    99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 105u8, 110u8, 99u8, 104u8, 99u8, 117u8,
+                    98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 45u8, 109u8, 105u8, 108u8, 101u8, 99u8, 117u8, 98u8, 105u8, 99u8,
+                    45u8, 121u8, 97u8, 114u8, 100u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8,
+                    104u8, 101u8, 105u8, 116u8, 102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8,
+                    117u8, 110u8, 99u8, 101u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8,
+                    105u8, 103u8, 97u8, 98u8, 105u8, 116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8,
+                    116u8, 101u8, 104u8, 111u8, 117u8, 114u8, 107u8, 105u8, 108u8, 111u8, 98u8,
+                    105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 98u8, 121u8, 116u8, 101u8, 107u8,
+                    105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8, 111u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8,
+                    114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8,
+                    103u8, 97u8, 98u8, 105u8, 116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8,
+                    101u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 109u8, 105u8, 99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8, 105u8, 108u8,
+                    101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8, 118u8, 105u8,
+                    97u8, 110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 108u8, 105u8, 116u8, 101u8,
+                    114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    111u8, 117u8, 110u8, 99u8, 101u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8,
+                    115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 107u8, 105u8, 108u8, 111u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 109u8, 105u8, 108u8, 101u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 121u8, 97u8, 114u8, 100u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8,
+                    116u8, 101u8, 114u8, 97u8, 98u8, 121u8, 116u8, 101u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    38u8, 0u8, 0u8, 0u8, 0u8, 0u8, 24u8, 0u8, 30u8, 0u8, 46u8, 0u8, 50u8, 0u8,
+                    54u8, 0u8, 58u8, 0u8, 61u8, 0u8, 65u8, 0u8, 69u8, 0u8, 72u8, 0u8, 77u8, 0u8,
+                    80u8, 0u8, 82u8, 0u8, 84u8, 0u8, 104u8, 0u8, 106u8, 0u8, 108u8, 0u8, 124u8,
+                    0u8, 160u8, 0u8, 164u8, 0u8, 201u8, 0u8, 203u8, 0u8, 205u8, 0u8, 210u8, 0u8,
+                    216u8, 0u8, 5u8, 1u8, 67u8, 1u8, 70u8, 1u8, 72u8, 1u8, 88u8, 1u8, 116u8, 1u8,
+                    120u8, 1u8, 124u8, 1u8, 128u8, 1u8, 169u8, 1u8, 210u8, 1u8, 214u8, 1u8, 240u8,
+                    145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 137u8,
+                    240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8,
+                    180u8, 100u8, 101u8, 103u8, 46u8, 32u8, 67u8, 240u8, 145u8, 132u8, 165u8,
+                    240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8,
+                    168u8, 99u8, 109u8, 194u8, 179u8, 102u8, 116u8, 194u8, 179u8, 105u8, 110u8,
+                    194u8, 179u8, 109u8, 194u8, 179u8, 109u8, 105u8, 194u8, 179u8, 121u8, 100u8,
+                    194u8, 179u8, 194u8, 176u8, 70u8, 102u8, 108u8, 32u8, 111u8, 122u8, 103u8,
+                    97u8, 108u8, 71u8, 98u8, 71u8, 66u8, 240u8, 145u8, 132u8, 138u8, 240u8, 145u8,
+                    132u8, 167u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 145u8, 107u8, 98u8, 107u8, 66u8, 240u8, 145u8, 132u8, 135u8,
+                    240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 142u8, 240u8, 145u8, 132u8,
+                    168u8, 240u8, 145u8, 132u8, 135u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8,
+                    132u8, 163u8, 240u8, 145u8, 132u8, 174u8, 240u8, 145u8, 132u8, 159u8, 240u8,
+                    145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8, 132u8, 162u8,
+                    240u8, 145u8, 132u8, 180u8, 107u8, 109u8, 47u8, 104u8, 240u8, 145u8, 132u8,
+                    163u8, 240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 145u8, 240u8, 145u8,
+                    132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 47u8, 240u8, 145u8, 132u8, 135u8,
+                    240u8, 145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8,
+                    168u8, 77u8, 98u8, 77u8, 66u8, 109u8, 47u8, 115u8, 194u8, 178u8, 206u8, 188u8,
+                    115u8, 101u8, 99u8, 115u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8,
+                    172u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 180u8, 47u8, 240u8,
+                    145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 160u8,
+                    240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8,
+                    154u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 138u8, 240u8, 145u8,
+                    132u8, 167u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 180u8, 240u8,
+                    145u8, 132u8, 145u8, 32u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8, 132u8,
+                    179u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 167u8, 240u8, 145u8,
+                    132u8, 150u8, 240u8, 145u8, 132u8, 168u8, 32u8, 240u8, 145u8, 132u8, 159u8,
+                    240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 163u8, 240u8, 145u8, 132u8,
+                    180u8, 115u8, 109u8, 105u8, 109u8, 76u8, 240u8, 145u8, 132u8, 159u8, 240u8,
+                    145u8, 132u8, 168u8, 240u8, 145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 168u8,
+                    240u8, 145u8, 132u8, 131u8, 240u8, 145u8, 132u8, 133u8, 240u8, 145u8, 132u8,
+                    170u8, 240u8, 145u8, 132u8, 154u8, 240u8, 145u8, 132u8, 179u8, 240u8, 145u8,
+                    132u8, 140u8, 240u8, 145u8, 132u8, 180u8, 99u8, 109u8, 194u8, 178u8, 105u8,
+                    110u8, 194u8, 178u8, 107u8, 109u8, 194u8, 178u8, 240u8, 145u8, 132u8, 157u8,
+                    240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8,
+                    180u8, 240u8, 145u8, 132u8, 137u8, 240u8, 145u8, 132u8, 167u8, 32u8, 240u8,
+                    145u8, 132u8, 159u8, 240u8, 145u8, 132u8, 172u8, 240u8, 145u8, 132u8, 163u8,
+                    240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8,
+                    167u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8,
+                    132u8, 137u8, 240u8, 145u8, 132u8, 167u8, 32u8, 240u8, 145u8, 132u8, 137u8,
+                    240u8, 145u8, 132u8, 167u8, 240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8,
+                    180u8, 84u8, 98u8, 105u8, 116u8, 84u8, 66u8, 121u8, 116u8, 101u8,
+                ])
+            },
+        )
+    },
+};
+static EN: &DataStruct = &::icu_displaynames::provider::UnitDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    65u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 7u8, 0u8, 11u8, 0u8, 18u8, 0u8, 28u8,
+                    0u8, 44u8, 0u8, 54u8, 0u8, 64u8, 0u8, 79u8, 0u8, 90u8, 0u8, 100u8, 0u8, 110u8,
+                    0u8, 113u8, 0u8, 119u8, 0u8, 129u8, 0u8, 140u8, 0u8, 144u8, 0u8, 150u8, 0u8,
+                    157u8, 0u8, 165u8, 0u8, 169u8, 0u8, 176u8, 0u8, 180u8, 0u8, 184u8, 0u8, 191u8,
+                    0u8, 199u8, 0u8, 207u8, 0u8, 216u8, 0u8, 234u8, 0u8, 239u8, 0u8, 2u8, 1u8, 9u8,
+                    1u8, 17u8, 1u8, 22u8, 1u8, 38u8, 1u8, 61u8, 1u8, 72u8, 1u8, 76u8, 1u8, 91u8,
+                    1u8, 104u8, 1u8, 121u8, 1u8, 131u8, 1u8, 141u8, 1u8, 152u8, 1u8, 158u8, 1u8,
+                    163u8, 1u8, 173u8, 1u8, 178u8, 1u8, 185u8, 1u8, 193u8, 1u8, 198u8, 1u8, 204u8,
+                    1u8, 221u8, 1u8, 232u8, 1u8, 243u8, 1u8, 3u8, 2u8, 15u8, 2u8, 26u8, 2u8, 37u8,
+                    2u8, 42u8, 2u8, 49u8, 2u8, 57u8, 2u8, 61u8, 2u8, 65u8, 2u8, 97u8, 99u8, 114u8,
+                    101u8, 98u8, 105u8, 116u8, 98u8, 121u8, 116u8, 101u8, 99u8, 101u8, 108u8,
+                    115u8, 105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8, 110u8,
+                    116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 102u8, 111u8, 111u8, 116u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8,
+                    105u8, 110u8, 99u8, 104u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8,
+                    45u8, 109u8, 105u8, 108u8, 101u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 121u8,
+                    97u8, 114u8, 100u8, 100u8, 97u8, 121u8, 100u8, 101u8, 103u8, 114u8, 101u8,
+                    101u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8,
+                    102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8,
+                    102u8, 111u8, 111u8, 116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8,
+                    105u8, 103u8, 97u8, 98u8, 105u8, 116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8,
+                    116u8, 101u8, 103u8, 114u8, 97u8, 109u8, 104u8, 101u8, 99u8, 116u8, 97u8,
+                    114u8, 101u8, 104u8, 111u8, 117u8, 114u8, 105u8, 110u8, 99u8, 104u8, 107u8,
+                    105u8, 108u8, 111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 98u8,
+                    121u8, 116u8, 101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 104u8, 111u8, 117u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8, 97u8,
+                    98u8, 105u8, 116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 108u8, 101u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8,
+                    101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8,
+                    105u8, 108u8, 101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8,
+                    118u8, 105u8, 97u8, 110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 108u8, 105u8,
+                    116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 110u8,
+                    116u8, 104u8, 110u8, 97u8, 110u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8, 101u8, 114u8, 99u8, 101u8,
+                    110u8, 116u8, 112u8, 101u8, 116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 112u8,
+                    111u8, 117u8, 110u8, 100u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8, 115u8,
+                    116u8, 111u8, 110u8, 101u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8,
+                    116u8, 101u8, 114u8, 97u8, 98u8, 121u8, 116u8, 101u8, 119u8, 101u8, 101u8,
+                    107u8, 121u8, 97u8, 114u8, 100u8, 121u8, 101u8, 97u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    65u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 9u8, 0u8, 14u8, 0u8, 29u8, 0u8, 40u8,
+                    0u8, 57u8, 0u8, 67u8, 0u8, 79u8, 0u8, 95u8, 0u8, 107u8, 0u8, 118u8, 0u8, 129u8,
+                    0u8, 133u8, 0u8, 140u8, 0u8, 158u8, 0u8, 170u8, 0u8, 174u8, 0u8, 181u8, 0u8,
+                    189u8, 0u8, 198u8, 0u8, 203u8, 0u8, 211u8, 0u8, 216u8, 0u8, 222u8, 0u8, 230u8,
+                    0u8, 239u8, 0u8, 248u8, 0u8, 2u8, 1u8, 21u8, 1u8, 27u8, 1u8, 47u8, 1u8, 55u8,
+                    1u8, 64u8, 1u8, 70u8, 1u8, 87u8, 1u8, 112u8, 1u8, 124u8, 1u8, 129u8, 1u8,
+                    145u8, 1u8, 159u8, 1u8, 177u8, 1u8, 188u8, 1u8, 199u8, 1u8, 211u8, 1u8, 218u8,
+                    1u8, 224u8, 1u8, 235u8, 1u8, 241u8, 1u8, 248u8, 1u8, 1u8, 2u8, 7u8, 2u8, 14u8,
+                    2u8, 32u8, 2u8, 43u8, 2u8, 56u8, 2u8, 73u8, 2u8, 86u8, 2u8, 98u8, 2u8, 110u8,
+                    2u8, 116u8, 2u8, 124u8, 2u8, 133u8, 2u8, 138u8, 2u8, 143u8, 2u8, 97u8, 99u8,
+                    114u8, 101u8, 115u8, 98u8, 105u8, 116u8, 115u8, 98u8, 121u8, 116u8, 101u8,
+                    115u8, 100u8, 101u8, 103u8, 114u8, 101u8, 101u8, 115u8, 32u8, 67u8, 101u8,
+                    108u8, 115u8, 105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 115u8, 99u8, 117u8, 98u8, 105u8, 99u8, 32u8, 99u8,
+                    101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 99u8,
+                    117u8, 98u8, 105u8, 99u8, 32u8, 102u8, 101u8, 101u8, 116u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 32u8, 105u8, 110u8, 99u8, 104u8, 101u8, 115u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 32u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 115u8, 99u8, 117u8, 98u8, 105u8, 99u8, 32u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 115u8, 99u8, 117u8, 98u8, 105u8, 99u8, 32u8, 109u8, 105u8, 108u8, 101u8,
+                    115u8, 99u8, 117u8, 98u8, 105u8, 99u8, 32u8, 121u8, 97u8, 114u8, 100u8, 115u8,
+                    100u8, 97u8, 121u8, 115u8, 100u8, 101u8, 103u8, 114u8, 101u8, 101u8, 115u8,
+                    100u8, 101u8, 103u8, 114u8, 101u8, 101u8, 115u8, 32u8, 70u8, 97u8, 104u8,
+                    114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8, 102u8, 108u8, 117u8, 105u8,
+                    100u8, 32u8, 111u8, 117u8, 110u8, 99u8, 101u8, 115u8, 102u8, 101u8, 101u8,
+                    116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 115u8, 103u8, 105u8, 103u8,
+                    97u8, 98u8, 105u8, 116u8, 115u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8, 116u8,
+                    101u8, 115u8, 103u8, 114u8, 97u8, 109u8, 115u8, 104u8, 101u8, 99u8, 116u8,
+                    97u8, 114u8, 101u8, 115u8, 104u8, 111u8, 117u8, 114u8, 115u8, 105u8, 110u8,
+                    99u8, 104u8, 101u8, 115u8, 107u8, 105u8, 108u8, 111u8, 98u8, 105u8, 116u8,
+                    115u8, 107u8, 105u8, 108u8, 111u8, 98u8, 121u8, 116u8, 101u8, 115u8, 107u8,
+                    105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 115u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 107u8, 105u8, 108u8, 111u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 32u8, 112u8, 101u8, 114u8, 32u8,
+                    104u8, 111u8, 117u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 115u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 115u8, 32u8, 112u8, 101u8, 114u8, 32u8, 107u8,
+                    105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8,
+                    97u8, 98u8, 105u8, 116u8, 115u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8,
+                    101u8, 115u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 115u8, 32u8, 112u8, 101u8, 114u8, 32u8, 115u8, 101u8, 99u8,
+                    111u8, 110u8, 100u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 32u8, 112u8,
+                    101u8, 114u8, 32u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 32u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 100u8, 109u8, 105u8, 99u8, 114u8, 111u8,
+                    115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 115u8, 109u8, 105u8, 108u8, 101u8,
+                    115u8, 109u8, 105u8, 108u8, 101u8, 115u8, 32u8, 112u8, 101u8, 114u8, 32u8,
+                    103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8, 101u8, 115u8,
+                    32u8, 112u8, 101u8, 114u8, 32u8, 104u8, 111u8, 117u8, 114u8, 109u8, 105u8,
+                    108u8, 101u8, 115u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8,
+                    118u8, 105u8, 97u8, 110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 108u8, 105u8,
+                    116u8, 101u8, 114u8, 115u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 115u8, 109u8, 105u8, 108u8, 108u8, 105u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 115u8, 109u8, 105u8, 110u8, 117u8, 116u8, 101u8,
+                    115u8, 109u8, 111u8, 110u8, 116u8, 104u8, 115u8, 110u8, 97u8, 110u8, 111u8,
+                    115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 115u8, 111u8, 117u8, 110u8, 99u8,
+                    101u8, 115u8, 112u8, 101u8, 114u8, 99u8, 101u8, 110u8, 116u8, 112u8, 101u8,
+                    116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 115u8, 112u8, 111u8, 117u8, 110u8,
+                    100u8, 115u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 115u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 32u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 115u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    32u8, 102u8, 101u8, 101u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    32u8, 105u8, 110u8, 99u8, 104u8, 101u8, 115u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 32u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 115u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 32u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 115u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 32u8,
+                    109u8, 105u8, 108u8, 101u8, 115u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    32u8, 121u8, 97u8, 114u8, 100u8, 115u8, 115u8, 116u8, 111u8, 110u8, 101u8,
+                    115u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8, 115u8, 116u8, 101u8,
+                    114u8, 97u8, 98u8, 121u8, 116u8, 101u8, 115u8, 119u8, 101u8, 101u8, 107u8,
+                    115u8, 121u8, 97u8, 114u8, 100u8, 115u8, 121u8, 101u8, 97u8, 114u8, 115u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    48u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 7u8, 0u8, 14u8, 0u8, 24u8, 0u8, 40u8,
+                    0u8, 50u8, 0u8, 60u8, 0u8, 75u8, 0u8, 86u8, 0u8, 96u8, 0u8, 106u8, 0u8, 116u8,
+                    0u8, 127u8, 0u8, 133u8, 0u8, 140u8, 0u8, 148u8, 0u8, 155u8, 0u8, 163u8, 0u8,
+                    171u8, 0u8, 180u8, 0u8, 198u8, 0u8, 217u8, 0u8, 224u8, 0u8, 232u8, 0u8, 237u8,
+                    0u8, 253u8, 0u8, 20u8, 1u8, 31u8, 1u8, 46u8, 1u8, 59u8, 1u8, 76u8, 1u8, 86u8,
+                    1u8, 96u8, 1u8, 107u8, 1u8, 113u8, 1u8, 123u8, 1u8, 128u8, 1u8, 136u8, 1u8,
+                    142u8, 1u8, 159u8, 1u8, 170u8, 1u8, 181u8, 1u8, 197u8, 1u8, 209u8, 1u8, 220u8,
+                    1u8, 231u8, 1u8, 238u8, 1u8, 98u8, 105u8, 116u8, 98u8, 121u8, 116u8, 101u8,
+                    99u8, 101u8, 108u8, 115u8, 105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8,
+                    105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8,
+                    99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8,
+                    117u8, 98u8, 105u8, 99u8, 45u8, 102u8, 111u8, 111u8, 116u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 45u8, 105u8, 110u8, 99u8, 104u8, 99u8, 117u8, 98u8, 105u8, 99u8,
+                    45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8,
+                    117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8,
+                    98u8, 105u8, 99u8, 45u8, 109u8, 105u8, 108u8, 101u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 121u8, 97u8, 114u8, 100u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8,
+                    104u8, 101u8, 105u8, 116u8, 102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8,
+                    117u8, 110u8, 99u8, 101u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8,
+                    105u8, 103u8, 97u8, 98u8, 105u8, 116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8,
+                    116u8, 101u8, 107u8, 105u8, 108u8, 111u8, 98u8, 105u8, 116u8, 107u8, 105u8,
+                    108u8, 111u8, 98u8, 121u8, 116u8, 101u8, 107u8, 105u8, 108u8, 111u8, 103u8,
+                    114u8, 97u8, 109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 108u8, 105u8, 116u8,
+                    101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8, 108u8, 111u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8, 97u8, 98u8, 105u8,
+                    116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 103u8, 97u8, 108u8, 108u8,
+                    111u8, 110u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    104u8, 111u8, 117u8, 114u8, 109u8, 105u8, 108u8, 101u8, 45u8, 115u8, 99u8,
+                    97u8, 110u8, 100u8, 105u8, 110u8, 97u8, 118u8, 105u8, 97u8, 110u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8,
+                    108u8, 105u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8, 110u8,
+                    117u8, 116u8, 101u8, 110u8, 97u8, 110u8, 111u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8, 101u8, 116u8, 97u8,
+                    98u8, 121u8, 116u8, 101u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8, 116u8,
+                    101u8, 114u8, 97u8, 98u8, 105u8, 116u8, 116u8, 101u8, 114u8, 97u8, 98u8, 121u8,
+                    116u8, 101u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    48u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 7u8, 0u8, 13u8, 0u8, 15u8, 0u8, 19u8,
+                    0u8, 25u8, 0u8, 33u8, 0u8, 37u8, 0u8, 40u8, 0u8, 44u8, 0u8, 51u8, 0u8, 57u8,
+                    0u8, 62u8, 0u8, 65u8, 0u8, 69u8, 0u8, 74u8, 0u8, 78u8, 0u8, 83u8, 0u8, 85u8,
+                    0u8, 87u8, 0u8, 94u8, 0u8, 103u8, 0u8, 107u8, 0u8, 112u8, 0u8, 113u8, 0u8,
+                    123u8, 0u8, 135u8, 0u8, 141u8, 0u8, 150u8, 0u8, 160u8, 0u8, 163u8, 0u8, 165u8,
+                    0u8, 167u8, 0u8, 176u8, 0u8, 180u8, 0u8, 188u8, 0u8, 190u8, 0u8, 195u8, 0u8,
+                    199u8, 0u8, 203u8, 0u8, 210u8, 0u8, 218u8, 0u8, 222u8, 0u8, 230u8, 0u8, 238u8,
+                    0u8, 245u8, 0u8, 249u8, 0u8, 98u8, 105u8, 116u8, 98u8, 121u8, 116u8, 101u8,
+                    100u8, 101u8, 103u8, 46u8, 32u8, 67u8, 99u8, 109u8, 99u8, 109u8, 194u8, 179u8,
+                    102u8, 101u8, 101u8, 116u8, 194u8, 179u8, 105u8, 110u8, 99u8, 104u8, 101u8,
+                    115u8, 194u8, 179u8, 107u8, 109u8, 194u8, 179u8, 109u8, 194u8, 179u8, 109u8,
+                    105u8, 194u8, 179u8, 121u8, 97u8, 114u8, 100u8, 115u8, 194u8, 179u8, 100u8,
+                    101u8, 103u8, 46u8, 32u8, 70u8, 102u8, 108u8, 32u8, 111u8, 122u8, 103u8, 97u8,
+                    108u8, 71u8, 98u8, 105u8, 116u8, 71u8, 66u8, 121u8, 116u8, 101u8, 107u8, 98u8,
+                    105u8, 116u8, 107u8, 66u8, 121u8, 116u8, 101u8, 107u8, 103u8, 107u8, 109u8,
+                    107u8, 109u8, 47u8, 104u8, 111u8, 117u8, 114u8, 108u8, 105u8, 116u8, 101u8,
+                    114u8, 115u8, 47u8, 107u8, 109u8, 77u8, 98u8, 105u8, 116u8, 77u8, 66u8, 121u8,
+                    116u8, 101u8, 109u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 47u8, 115u8,
+                    101u8, 99u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 47u8, 115u8, 101u8,
+                    99u8, 194u8, 178u8, 206u8, 188u8, 115u8, 101u8, 99u8, 115u8, 109u8, 105u8,
+                    108u8, 101u8, 115u8, 47u8, 103u8, 97u8, 108u8, 109u8, 105u8, 108u8, 101u8,
+                    115u8, 47u8, 104u8, 111u8, 117u8, 114u8, 115u8, 109u8, 105u8, 109u8, 76u8,
+                    109u8, 109u8, 109u8, 105u8, 108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 115u8,
+                    109u8, 105u8, 110u8, 115u8, 110u8, 97u8, 110u8, 111u8, 115u8, 101u8, 99u8,
+                    115u8, 111u8, 122u8, 80u8, 66u8, 121u8, 116u8, 101u8, 115u8, 101u8, 99u8,
+                    115u8, 99u8, 109u8, 194u8, 178u8, 115u8, 113u8, 32u8, 102u8, 101u8, 101u8,
+                    116u8, 105u8, 110u8, 99u8, 104u8, 101u8, 115u8, 194u8, 178u8, 107u8, 109u8,
+                    194u8, 178u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 194u8, 178u8, 115u8,
+                    113u8, 32u8, 109u8, 105u8, 108u8, 101u8, 115u8, 121u8, 97u8, 114u8, 100u8,
+                    115u8, 194u8, 178u8, 84u8, 98u8, 105u8, 116u8, 84u8, 66u8, 121u8, 116u8, 101u8,
+                ])
+            },
+        )
+    },
+};
+static EN_001_EN_ZA: &DataStruct = &::icu_displaynames::provider::UnitDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    65u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 7u8, 0u8, 11u8, 0u8, 18u8, 0u8, 28u8,
+                    0u8, 44u8, 0u8, 54u8, 0u8, 64u8, 0u8, 79u8, 0u8, 90u8, 0u8, 100u8, 0u8, 110u8,
+                    0u8, 113u8, 0u8, 119u8, 0u8, 129u8, 0u8, 140u8, 0u8, 144u8, 0u8, 150u8, 0u8,
+                    157u8, 0u8, 165u8, 0u8, 169u8, 0u8, 176u8, 0u8, 180u8, 0u8, 184u8, 0u8, 191u8,
+                    0u8, 199u8, 0u8, 207u8, 0u8, 216u8, 0u8, 234u8, 0u8, 239u8, 0u8, 2u8, 1u8, 9u8,
+                    1u8, 17u8, 1u8, 22u8, 1u8, 38u8, 1u8, 61u8, 1u8, 72u8, 1u8, 76u8, 1u8, 91u8,
+                    1u8, 104u8, 1u8, 121u8, 1u8, 131u8, 1u8, 141u8, 1u8, 152u8, 1u8, 158u8, 1u8,
+                    163u8, 1u8, 173u8, 1u8, 178u8, 1u8, 185u8, 1u8, 193u8, 1u8, 198u8, 1u8, 204u8,
+                    1u8, 221u8, 1u8, 232u8, 1u8, 243u8, 1u8, 3u8, 2u8, 15u8, 2u8, 26u8, 2u8, 37u8,
+                    2u8, 42u8, 2u8, 49u8, 2u8, 57u8, 2u8, 61u8, 2u8, 65u8, 2u8, 97u8, 99u8, 114u8,
+                    101u8, 98u8, 105u8, 116u8, 98u8, 121u8, 116u8, 101u8, 99u8, 101u8, 108u8,
+                    115u8, 105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8, 110u8,
+                    116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 102u8, 111u8, 111u8, 116u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8,
+                    105u8, 110u8, 99u8, 104u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8,
+                    45u8, 109u8, 105u8, 108u8, 101u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 121u8,
+                    97u8, 114u8, 100u8, 100u8, 97u8, 121u8, 100u8, 101u8, 103u8, 114u8, 101u8,
+                    101u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8,
+                    102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8,
+                    102u8, 111u8, 111u8, 116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8,
+                    105u8, 103u8, 97u8, 98u8, 105u8, 116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8,
+                    116u8, 101u8, 103u8, 114u8, 97u8, 109u8, 104u8, 101u8, 99u8, 116u8, 97u8,
+                    114u8, 101u8, 104u8, 111u8, 117u8, 114u8, 105u8, 110u8, 99u8, 104u8, 107u8,
+                    105u8, 108u8, 111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 98u8,
+                    121u8, 116u8, 101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 104u8, 111u8, 117u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8, 97u8,
+                    98u8, 105u8, 116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 108u8, 101u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8,
+                    101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8,
+                    105u8, 108u8, 101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8,
+                    118u8, 105u8, 97u8, 110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 108u8, 105u8,
+                    116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 110u8,
+                    116u8, 104u8, 110u8, 97u8, 110u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8, 101u8, 114u8, 99u8, 101u8,
+                    110u8, 116u8, 112u8, 101u8, 116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 112u8,
+                    111u8, 117u8, 110u8, 100u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8, 115u8,
+                    116u8, 111u8, 110u8, 101u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8,
+                    116u8, 101u8, 114u8, 97u8, 98u8, 121u8, 116u8, 101u8, 119u8, 101u8, 101u8,
+                    107u8, 121u8, 97u8, 114u8, 100u8, 121u8, 101u8, 97u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    65u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 9u8, 0u8, 14u8, 0u8, 29u8, 0u8, 40u8,
+                    0u8, 57u8, 0u8, 67u8, 0u8, 79u8, 0u8, 95u8, 0u8, 107u8, 0u8, 118u8, 0u8, 129u8,
+                    0u8, 133u8, 0u8, 140u8, 0u8, 158u8, 0u8, 173u8, 0u8, 177u8, 0u8, 187u8, 0u8,
+                    195u8, 0u8, 204u8, 0u8, 209u8, 0u8, 217u8, 0u8, 222u8, 0u8, 228u8, 0u8, 236u8,
+                    0u8, 245u8, 0u8, 254u8, 0u8, 8u8, 1u8, 27u8, 1u8, 33u8, 1u8, 53u8, 1u8, 61u8,
+                    1u8, 70u8, 1u8, 76u8, 1u8, 93u8, 1u8, 118u8, 1u8, 130u8, 1u8, 135u8, 1u8,
+                    154u8, 1u8, 168u8, 1u8, 185u8, 1u8, 196u8, 1u8, 207u8, 1u8, 219u8, 1u8, 226u8,
+                    1u8, 232u8, 1u8, 243u8, 1u8, 249u8, 1u8, 1u8, 2u8, 10u8, 2u8, 16u8, 2u8, 23u8,
+                    2u8, 41u8, 2u8, 52u8, 2u8, 65u8, 2u8, 82u8, 2u8, 95u8, 2u8, 107u8, 2u8, 119u8,
+                    2u8, 124u8, 2u8, 132u8, 2u8, 141u8, 2u8, 146u8, 2u8, 151u8, 2u8, 97u8, 99u8,
+                    114u8, 101u8, 115u8, 98u8, 105u8, 116u8, 115u8, 98u8, 121u8, 116u8, 101u8,
+                    115u8, 100u8, 101u8, 103u8, 114u8, 101u8, 101u8, 115u8, 32u8, 67u8, 101u8,
+                    108u8, 115u8, 105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8,
+                    101u8, 116u8, 114u8, 101u8, 115u8, 99u8, 117u8, 98u8, 105u8, 99u8, 32u8, 99u8,
+                    101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 114u8, 101u8, 115u8, 99u8,
+                    117u8, 98u8, 105u8, 99u8, 32u8, 102u8, 101u8, 101u8, 116u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 32u8, 105u8, 110u8, 99u8, 104u8, 101u8, 115u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 32u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 114u8,
+                    101u8, 115u8, 99u8, 117u8, 98u8, 105u8, 99u8, 32u8, 109u8, 101u8, 116u8, 114u8,
+                    101u8, 115u8, 99u8, 117u8, 98u8, 105u8, 99u8, 32u8, 109u8, 105u8, 108u8, 101u8,
+                    115u8, 99u8, 117u8, 98u8, 105u8, 99u8, 32u8, 121u8, 97u8, 114u8, 100u8, 115u8,
+                    100u8, 97u8, 121u8, 115u8, 100u8, 101u8, 103u8, 114u8, 101u8, 101u8, 115u8,
+                    100u8, 101u8, 103u8, 114u8, 101u8, 101u8, 115u8, 32u8, 70u8, 97u8, 104u8,
+                    114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8, 85u8, 83u8, 32u8, 102u8,
+                    108u8, 117u8, 105u8, 100u8, 32u8, 111u8, 117u8, 110u8, 99u8, 101u8, 115u8,
+                    102u8, 101u8, 101u8, 116u8, 85u8, 83u8, 32u8, 103u8, 97u8, 108u8, 108u8, 111u8,
+                    110u8, 115u8, 103u8, 105u8, 103u8, 97u8, 98u8, 105u8, 116u8, 115u8, 103u8,
+                    105u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8, 115u8, 103u8, 114u8, 97u8,
+                    109u8, 115u8, 104u8, 101u8, 99u8, 116u8, 97u8, 114u8, 101u8, 115u8, 104u8,
+                    111u8, 117u8, 114u8, 115u8, 105u8, 110u8, 99u8, 104u8, 101u8, 115u8, 107u8,
+                    105u8, 108u8, 111u8, 98u8, 105u8, 116u8, 115u8, 107u8, 105u8, 108u8, 111u8,
+                    98u8, 121u8, 116u8, 101u8, 115u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8,
+                    97u8, 109u8, 115u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 114u8,
+                    101u8, 115u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 114u8, 101u8,
+                    115u8, 32u8, 112u8, 101u8, 114u8, 32u8, 104u8, 111u8, 117u8, 114u8, 108u8,
+                    105u8, 116u8, 114u8, 101u8, 115u8, 108u8, 105u8, 116u8, 114u8, 101u8, 115u8,
+                    32u8, 112u8, 101u8, 114u8, 32u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 114u8, 101u8, 109u8, 101u8, 103u8, 97u8, 98u8, 105u8, 116u8, 115u8,
+                    109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8, 115u8, 109u8, 101u8,
+                    116u8, 114u8, 101u8, 115u8, 109u8, 101u8, 116u8, 114u8, 101u8, 115u8, 32u8,
+                    112u8, 101u8, 114u8, 32u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8,
+                    101u8, 116u8, 114u8, 101u8, 115u8, 32u8, 112u8, 101u8, 114u8, 32u8, 115u8,
+                    101u8, 99u8, 111u8, 110u8, 100u8, 32u8, 115u8, 113u8, 117u8, 97u8, 114u8,
+                    101u8, 100u8, 109u8, 105u8, 99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 115u8, 109u8, 105u8, 108u8, 101u8, 115u8, 109u8, 105u8, 108u8,
+                    101u8, 115u8, 32u8, 112u8, 101u8, 114u8, 32u8, 85u8, 83u8, 32u8, 103u8, 97u8,
+                    108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8, 101u8, 115u8, 32u8, 112u8,
+                    101u8, 114u8, 32u8, 104u8, 111u8, 117u8, 114u8, 83u8, 99u8, 97u8, 110u8, 100u8,
+                    105u8, 110u8, 97u8, 118u8, 105u8, 97u8, 110u8, 32u8, 109u8, 105u8, 108u8,
+                    101u8, 109u8, 105u8, 108u8, 108u8, 105u8, 108u8, 105u8, 116u8, 114u8, 101u8,
+                    115u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 101u8, 116u8, 114u8, 101u8,
+                    115u8, 109u8, 105u8, 108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 115u8, 109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 115u8, 109u8, 111u8,
+                    110u8, 116u8, 104u8, 115u8, 110u8, 97u8, 110u8, 111u8, 115u8, 101u8, 99u8,
+                    111u8, 110u8, 100u8, 115u8, 111u8, 117u8, 110u8, 99u8, 101u8, 115u8, 112u8,
+                    101u8, 114u8, 32u8, 99u8, 101u8, 110u8, 116u8, 112u8, 101u8, 116u8, 97u8, 98u8,
+                    121u8, 116u8, 101u8, 115u8, 112u8, 111u8, 117u8, 110u8, 100u8, 115u8, 115u8,
+                    101u8, 99u8, 111u8, 110u8, 100u8, 115u8, 115u8, 113u8, 117u8, 97u8, 114u8,
+                    101u8, 32u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 114u8,
+                    101u8, 115u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 32u8, 102u8, 101u8,
+                    101u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 32u8, 105u8, 110u8,
+                    99u8, 104u8, 101u8, 115u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 32u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 114u8, 101u8, 115u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 32u8, 109u8, 101u8, 116u8, 114u8, 101u8,
+                    115u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 32u8, 109u8, 105u8, 108u8,
+                    101u8, 115u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 32u8, 121u8, 97u8,
+                    114u8, 100u8, 115u8, 115u8, 116u8, 111u8, 110u8, 101u8, 116u8, 101u8, 114u8,
+                    97u8, 98u8, 105u8, 116u8, 115u8, 116u8, 101u8, 114u8, 97u8, 98u8, 121u8, 116u8,
+                    101u8, 115u8, 119u8, 101u8, 101u8, 107u8, 115u8, 121u8, 97u8, 114u8, 100u8,
+                    115u8, 121u8, 101u8, 97u8, 114u8, 115u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    47u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 7u8, 0u8, 14u8, 0u8, 24u8, 0u8, 40u8,
+                    0u8, 50u8, 0u8, 60u8, 0u8, 75u8, 0u8, 86u8, 0u8, 96u8, 0u8, 106u8, 0u8, 116u8,
+                    0u8, 127u8, 0u8, 133u8, 0u8, 140u8, 0u8, 148u8, 0u8, 155u8, 0u8, 163u8, 0u8,
+                    171u8, 0u8, 180u8, 0u8, 198u8, 0u8, 217u8, 0u8, 224u8, 0u8, 232u8, 0u8, 248u8,
+                    0u8, 15u8, 1u8, 26u8, 1u8, 41u8, 1u8, 54u8, 1u8, 71u8, 1u8, 81u8, 1u8, 91u8,
+                    1u8, 102u8, 1u8, 108u8, 1u8, 118u8, 1u8, 123u8, 1u8, 131u8, 1u8, 137u8, 1u8,
+                    154u8, 1u8, 165u8, 1u8, 176u8, 1u8, 192u8, 1u8, 204u8, 1u8, 215u8, 1u8, 226u8,
+                    1u8, 233u8, 1u8, 98u8, 105u8, 116u8, 98u8, 121u8, 116u8, 101u8, 99u8, 101u8,
+                    108u8, 115u8, 105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8,
+                    110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 45u8, 102u8, 111u8, 111u8, 116u8, 99u8, 117u8, 98u8, 105u8, 99u8,
+                    45u8, 105u8, 110u8, 99u8, 104u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 107u8,
+                    105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 109u8, 105u8, 108u8, 101u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8,
+                    121u8, 97u8, 114u8, 100u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8,
+                    101u8, 105u8, 116u8, 102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8,
+                    110u8, 99u8, 101u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8, 105u8,
+                    103u8, 97u8, 98u8, 105u8, 116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8, 116u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8,
+                    111u8, 98u8, 121u8, 116u8, 101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8,
+                    97u8, 109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 108u8, 105u8, 116u8, 101u8,
+                    114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8, 97u8, 98u8, 105u8, 116u8,
+                    109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 109u8, 105u8, 99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8, 105u8, 108u8,
+                    101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8, 118u8, 105u8,
+                    97u8, 110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 108u8, 105u8, 116u8, 101u8,
+                    114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    109u8, 105u8, 108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 110u8, 97u8, 110u8, 111u8, 115u8,
+                    101u8, 99u8, 111u8, 110u8, 100u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8,
+                    101u8, 116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8, 101u8,
+                    110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8,
+                    97u8, 114u8, 101u8, 45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8,
+                    97u8, 114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8,
+                    108u8, 101u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8,
+                    114u8, 100u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8, 116u8, 101u8,
+                    114u8, 97u8, 98u8, 121u8, 116u8, 101u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    47u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 7u8, 0u8, 13u8, 0u8, 15u8, 0u8, 19u8,
+                    0u8, 25u8, 0u8, 33u8, 0u8, 37u8, 0u8, 40u8, 0u8, 44u8, 0u8, 51u8, 0u8, 57u8,
+                    0u8, 65u8, 0u8, 71u8, 0u8, 75u8, 0u8, 80u8, 0u8, 84u8, 0u8, 89u8, 0u8, 91u8,
+                    0u8, 93u8, 0u8, 100u8, 0u8, 109u8, 0u8, 113u8, 0u8, 118u8, 0u8, 128u8, 0u8,
+                    140u8, 0u8, 146u8, 0u8, 158u8, 0u8, 168u8, 0u8, 171u8, 0u8, 173u8, 0u8, 175u8,
+                    0u8, 184u8, 0u8, 188u8, 0u8, 196u8, 0u8, 198u8, 0u8, 203u8, 0u8, 207u8, 0u8,
+                    211u8, 0u8, 218u8, 0u8, 226u8, 0u8, 230u8, 0u8, 238u8, 0u8, 246u8, 0u8, 253u8,
+                    0u8, 1u8, 1u8, 98u8, 105u8, 116u8, 98u8, 121u8, 116u8, 101u8, 100u8, 101u8,
+                    103u8, 46u8, 32u8, 67u8, 99u8, 109u8, 99u8, 109u8, 194u8, 179u8, 102u8, 101u8,
+                    101u8, 116u8, 194u8, 179u8, 105u8, 110u8, 99u8, 104u8, 101u8, 115u8, 194u8,
+                    179u8, 107u8, 109u8, 194u8, 179u8, 109u8, 194u8, 179u8, 109u8, 105u8, 194u8,
+                    179u8, 121u8, 97u8, 114u8, 100u8, 115u8, 194u8, 179u8, 100u8, 101u8, 103u8,
+                    46u8, 32u8, 70u8, 85u8, 83u8, 32u8, 102u8, 108u8, 32u8, 111u8, 122u8, 85u8,
+                    83u8, 32u8, 103u8, 97u8, 108u8, 71u8, 98u8, 105u8, 116u8, 71u8, 66u8, 121u8,
+                    116u8, 101u8, 107u8, 98u8, 105u8, 116u8, 107u8, 66u8, 121u8, 116u8, 101u8,
+                    107u8, 103u8, 107u8, 109u8, 107u8, 109u8, 47u8, 104u8, 111u8, 117u8, 114u8,
+                    108u8, 105u8, 116u8, 114u8, 101u8, 115u8, 47u8, 107u8, 109u8, 77u8, 98u8,
+                    105u8, 116u8, 77u8, 66u8, 121u8, 116u8, 101u8, 109u8, 101u8, 116u8, 114u8,
+                    101u8, 115u8, 47u8, 115u8, 101u8, 99u8, 109u8, 101u8, 116u8, 114u8, 101u8,
+                    115u8, 47u8, 115u8, 101u8, 99u8, 194u8, 178u8, 206u8, 188u8, 115u8, 101u8,
+                    99u8, 115u8, 109u8, 105u8, 108u8, 101u8, 115u8, 47u8, 85u8, 83u8, 32u8, 103u8,
+                    97u8, 108u8, 109u8, 105u8, 108u8, 101u8, 115u8, 47u8, 104u8, 111u8, 117u8,
+                    114u8, 115u8, 109u8, 105u8, 109u8, 108u8, 109u8, 109u8, 109u8, 105u8, 108u8,
+                    108u8, 105u8, 115u8, 101u8, 99u8, 115u8, 109u8, 105u8, 110u8, 115u8, 110u8,
+                    97u8, 110u8, 111u8, 115u8, 101u8, 99u8, 115u8, 111u8, 122u8, 80u8, 66u8, 121u8,
+                    116u8, 101u8, 115u8, 101u8, 99u8, 115u8, 99u8, 109u8, 194u8, 178u8, 115u8,
+                    113u8, 32u8, 102u8, 101u8, 101u8, 116u8, 105u8, 110u8, 99u8, 104u8, 101u8,
+                    115u8, 194u8, 178u8, 107u8, 109u8, 194u8, 178u8, 109u8, 101u8, 116u8, 114u8,
+                    101u8, 115u8, 194u8, 178u8, 115u8, 113u8, 32u8, 109u8, 105u8, 108u8, 101u8,
+                    115u8, 121u8, 97u8, 114u8, 100u8, 115u8, 194u8, 178u8, 84u8, 98u8, 105u8,
+                    116u8, 84u8, 66u8, 121u8, 116u8, 101u8,
+                ])
+            },
+        )
+    },
+};
+static ES: &DataStruct = &::icu_displaynames::provider::UnitDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    65u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 7u8, 0u8, 11u8, 0u8, 18u8, 0u8, 28u8,
+                    0u8, 44u8, 0u8, 54u8, 0u8, 64u8, 0u8, 79u8, 0u8, 90u8, 0u8, 100u8, 0u8, 110u8,
+                    0u8, 113u8, 0u8, 119u8, 0u8, 129u8, 0u8, 140u8, 0u8, 144u8, 0u8, 150u8, 0u8,
+                    157u8, 0u8, 165u8, 0u8, 169u8, 0u8, 176u8, 0u8, 180u8, 0u8, 184u8, 0u8, 191u8,
+                    0u8, 199u8, 0u8, 207u8, 0u8, 216u8, 0u8, 234u8, 0u8, 239u8, 0u8, 2u8, 1u8, 9u8,
+                    1u8, 17u8, 1u8, 22u8, 1u8, 38u8, 1u8, 61u8, 1u8, 72u8, 1u8, 76u8, 1u8, 91u8,
+                    1u8, 104u8, 1u8, 121u8, 1u8, 131u8, 1u8, 141u8, 1u8, 152u8, 1u8, 158u8, 1u8,
+                    163u8, 1u8, 173u8, 1u8, 178u8, 1u8, 185u8, 1u8, 193u8, 1u8, 198u8, 1u8, 204u8,
+                    1u8, 221u8, 1u8, 232u8, 1u8, 243u8, 1u8, 3u8, 2u8, 15u8, 2u8, 26u8, 2u8, 37u8,
+                    2u8, 42u8, 2u8, 49u8, 2u8, 57u8, 2u8, 61u8, 2u8, 65u8, 2u8, 97u8, 99u8, 114u8,
+                    101u8, 98u8, 105u8, 116u8, 98u8, 121u8, 116u8, 101u8, 99u8, 101u8, 108u8,
+                    115u8, 105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8, 110u8,
+                    116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 102u8, 111u8, 111u8, 116u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8,
+                    105u8, 110u8, 99u8, 104u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8,
+                    45u8, 109u8, 105u8, 108u8, 101u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 121u8,
+                    97u8, 114u8, 100u8, 100u8, 97u8, 121u8, 100u8, 101u8, 103u8, 114u8, 101u8,
+                    101u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8,
+                    102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8,
+                    102u8, 111u8, 111u8, 116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8,
+                    105u8, 103u8, 97u8, 98u8, 105u8, 116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8,
+                    116u8, 101u8, 103u8, 114u8, 97u8, 109u8, 104u8, 101u8, 99u8, 116u8, 97u8,
+                    114u8, 101u8, 104u8, 111u8, 117u8, 114u8, 105u8, 110u8, 99u8, 104u8, 107u8,
+                    105u8, 108u8, 111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 98u8,
+                    121u8, 116u8, 101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 104u8, 111u8, 117u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8, 97u8,
+                    98u8, 105u8, 116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 108u8, 101u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8,
+                    101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8,
+                    105u8, 108u8, 101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8,
+                    118u8, 105u8, 97u8, 110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 108u8, 105u8,
+                    116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 110u8,
+                    116u8, 104u8, 110u8, 97u8, 110u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8, 101u8, 114u8, 99u8, 101u8,
+                    110u8, 116u8, 112u8, 101u8, 116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 112u8,
+                    111u8, 117u8, 110u8, 100u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8, 115u8,
+                    116u8, 111u8, 110u8, 101u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8,
+                    116u8, 101u8, 114u8, 97u8, 98u8, 121u8, 116u8, 101u8, 119u8, 101u8, 101u8,
+                    107u8, 121u8, 97u8, 114u8, 100u8, 121u8, 101u8, 97u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    65u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 9u8, 0u8, 14u8, 0u8, 28u8, 0u8, 40u8,
+                    0u8, 61u8, 0u8, 74u8, 0u8, 91u8, 0u8, 111u8, 0u8, 126u8, 0u8, 141u8, 0u8,
+                    156u8, 0u8, 161u8, 0u8, 167u8, 0u8, 184u8, 0u8, 199u8, 0u8, 203u8, 0u8, 210u8,
+                    0u8, 218u8, 0u8, 227u8, 0u8, 233u8, 0u8, 243u8, 0u8, 248u8, 0u8, 0u8, 1u8, 8u8,
+                    1u8, 17u8, 1u8, 27u8, 1u8, 38u8, 1u8, 58u8, 1u8, 64u8, 1u8, 85u8, 1u8, 93u8,
+                    1u8, 102u8, 1u8, 108u8, 1u8, 126u8, 1u8, 156u8, 1u8, 169u8, 1u8, 175u8, 1u8,
+                    192u8, 1u8, 207u8, 1u8, 226u8, 1u8, 236u8, 1u8, 247u8, 1u8, 3u8, 2u8, 10u8,
+                    2u8, 15u8, 2u8, 27u8, 2u8, 32u8, 2u8, 42u8, 2u8, 51u8, 2u8, 57u8, 2u8, 65u8,
+                    2u8, 87u8, 2u8, 101u8, 2u8, 119u8, 2u8, 140u8, 2u8, 156u8, 2u8, 172u8, 2u8,
+                    188u8, 2u8, 194u8, 2u8, 202u8, 2u8, 211u8, 2u8, 218u8, 2u8, 224u8, 2u8, 97u8,
+                    99u8, 114u8, 101u8, 115u8, 98u8, 105u8, 116u8, 115u8, 98u8, 121u8, 116u8,
+                    101u8, 115u8, 103u8, 114u8, 97u8, 100u8, 111u8, 115u8, 32u8, 67u8, 101u8,
+                    108u8, 115u8, 105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 195u8, 173u8,
+                    109u8, 101u8, 116u8, 114u8, 111u8, 115u8, 99u8, 101u8, 110u8, 116u8, 195u8,
+                    173u8, 109u8, 101u8, 116u8, 114u8, 111u8, 115u8, 32u8, 99u8, 195u8, 186u8,
+                    98u8, 105u8, 99u8, 111u8, 115u8, 112u8, 105u8, 101u8, 115u8, 32u8, 99u8, 195u8,
+                    186u8, 98u8, 105u8, 99u8, 111u8, 115u8, 112u8, 117u8, 108u8, 103u8, 97u8,
+                    100u8, 97u8, 115u8, 32u8, 99u8, 195u8, 186u8, 98u8, 105u8, 99u8, 97u8, 115u8,
+                    107u8, 105u8, 108u8, 195u8, 179u8, 109u8, 101u8, 116u8, 114u8, 111u8, 115u8,
+                    32u8, 99u8, 195u8, 186u8, 98u8, 105u8, 99u8, 111u8, 115u8, 109u8, 101u8, 116u8,
+                    114u8, 111u8, 115u8, 32u8, 99u8, 195u8, 186u8, 98u8, 105u8, 99u8, 111u8, 115u8,
+                    109u8, 105u8, 108u8, 108u8, 97u8, 115u8, 32u8, 99u8, 195u8, 186u8, 98u8, 105u8,
+                    99u8, 97u8, 115u8, 121u8, 97u8, 114u8, 100u8, 97u8, 115u8, 32u8, 99u8, 195u8,
+                    186u8, 98u8, 105u8, 99u8, 97u8, 115u8, 100u8, 195u8, 173u8, 97u8, 115u8, 103u8,
+                    114u8, 97u8, 100u8, 111u8, 115u8, 103u8, 114u8, 97u8, 100u8, 111u8, 115u8,
+                    32u8, 70u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8,
+                    111u8, 110u8, 122u8, 97u8, 115u8, 32u8, 108u8, 195u8, 173u8, 113u8, 117u8,
+                    105u8, 100u8, 97u8, 115u8, 112u8, 105u8, 101u8, 115u8, 103u8, 97u8, 108u8,
+                    111u8, 110u8, 101u8, 115u8, 103u8, 105u8, 103u8, 97u8, 98u8, 105u8, 116u8,
+                    115u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8, 115u8, 103u8,
+                    114u8, 97u8, 109u8, 111u8, 115u8, 104u8, 101u8, 99u8, 116u8, 195u8, 161u8,
+                    114u8, 101u8, 97u8, 115u8, 104u8, 111u8, 114u8, 97u8, 115u8, 112u8, 117u8,
+                    108u8, 103u8, 97u8, 100u8, 97u8, 115u8, 107u8, 105u8, 108u8, 111u8, 98u8,
+                    105u8, 116u8, 115u8, 107u8, 105u8, 108u8, 111u8, 98u8, 121u8, 116u8, 101u8,
+                    115u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 111u8, 115u8,
+                    107u8, 105u8, 108u8, 195u8, 179u8, 109u8, 101u8, 116u8, 114u8, 111u8, 115u8,
+                    107u8, 105u8, 108u8, 195u8, 179u8, 109u8, 101u8, 116u8, 114u8, 111u8, 115u8,
+                    32u8, 112u8, 111u8, 114u8, 32u8, 104u8, 111u8, 114u8, 97u8, 108u8, 105u8,
+                    116u8, 114u8, 111u8, 115u8, 108u8, 105u8, 116u8, 114u8, 111u8, 115u8, 32u8,
+                    112u8, 111u8, 114u8, 32u8, 107u8, 105u8, 108u8, 195u8, 179u8, 109u8, 101u8,
+                    116u8, 114u8, 111u8, 109u8, 101u8, 103u8, 97u8, 98u8, 105u8, 116u8, 115u8,
+                    109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8, 115u8, 109u8, 101u8,
+                    116u8, 114u8, 111u8, 115u8, 109u8, 101u8, 116u8, 114u8, 111u8, 115u8, 32u8,
+                    112u8, 111u8, 114u8, 32u8, 115u8, 101u8, 103u8, 117u8, 110u8, 100u8, 111u8,
+                    109u8, 101u8, 116u8, 114u8, 111u8, 115u8, 32u8, 112u8, 111u8, 114u8, 32u8,
+                    115u8, 101u8, 103u8, 117u8, 110u8, 100u8, 111u8, 32u8, 97u8, 108u8, 32u8, 99u8,
+                    117u8, 97u8, 100u8, 114u8, 97u8, 100u8, 111u8, 109u8, 105u8, 99u8, 114u8,
+                    111u8, 115u8, 101u8, 103u8, 117u8, 110u8, 100u8, 111u8, 115u8, 109u8, 105u8,
+                    108u8, 108u8, 97u8, 115u8, 109u8, 105u8, 108u8, 108u8, 97u8, 115u8, 32u8,
+                    112u8, 111u8, 114u8, 32u8, 103u8, 97u8, 108u8, 195u8, 179u8, 110u8, 109u8,
+                    105u8, 108u8, 108u8, 97u8, 115u8, 32u8, 112u8, 111u8, 114u8, 32u8, 104u8,
+                    111u8, 114u8, 97u8, 109u8, 105u8, 108u8, 108u8, 97u8, 115u8, 32u8, 101u8,
+                    115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8, 118u8, 97u8, 115u8, 109u8,
+                    105u8, 108u8, 105u8, 108u8, 105u8, 116u8, 114u8, 111u8, 115u8, 109u8, 105u8,
+                    108u8, 195u8, 173u8, 109u8, 101u8, 116u8, 114u8, 111u8, 115u8, 109u8, 105u8,
+                    108u8, 105u8, 115u8, 101u8, 103u8, 117u8, 110u8, 100u8, 111u8, 115u8, 109u8,
+                    105u8, 110u8, 117u8, 116u8, 111u8, 115u8, 109u8, 101u8, 115u8, 101u8, 115u8,
+                    110u8, 97u8, 110u8, 111u8, 115u8, 101u8, 103u8, 117u8, 110u8, 100u8, 111u8,
+                    115u8, 111u8, 110u8, 122u8, 97u8, 115u8, 112u8, 111u8, 114u8, 32u8, 99u8,
+                    105u8, 101u8, 110u8, 116u8, 111u8, 112u8, 101u8, 116u8, 97u8, 98u8, 121u8,
+                    116u8, 101u8, 115u8, 108u8, 105u8, 98u8, 114u8, 97u8, 115u8, 115u8, 101u8,
+                    103u8, 117u8, 110u8, 100u8, 111u8, 115u8, 99u8, 101u8, 110u8, 116u8, 195u8,
+                    173u8, 109u8, 101u8, 116u8, 114u8, 111u8, 115u8, 32u8, 99u8, 117u8, 97u8,
+                    100u8, 114u8, 97u8, 100u8, 111u8, 115u8, 112u8, 105u8, 101u8, 115u8, 32u8,
+                    99u8, 117u8, 97u8, 100u8, 114u8, 97u8, 100u8, 111u8, 115u8, 112u8, 117u8,
+                    108u8, 103u8, 97u8, 100u8, 97u8, 115u8, 32u8, 99u8, 117u8, 97u8, 100u8, 114u8,
+                    97u8, 100u8, 97u8, 115u8, 107u8, 105u8, 108u8, 195u8, 179u8, 109u8, 101u8,
+                    116u8, 114u8, 111u8, 115u8, 32u8, 99u8, 117u8, 97u8, 100u8, 114u8, 97u8, 100u8,
+                    111u8, 115u8, 109u8, 101u8, 116u8, 114u8, 111u8, 115u8, 32u8, 99u8, 117u8,
+                    97u8, 100u8, 114u8, 97u8, 100u8, 111u8, 115u8, 109u8, 105u8, 108u8, 108u8,
+                    97u8, 115u8, 32u8, 99u8, 117u8, 97u8, 100u8, 114u8, 97u8, 100u8, 97u8, 115u8,
+                    121u8, 97u8, 114u8, 100u8, 97u8, 115u8, 32u8, 99u8, 117u8, 97u8, 100u8, 114u8,
+                    97u8, 100u8, 97u8, 115u8, 115u8, 116u8, 111u8, 110u8, 101u8, 115u8, 116u8,
+                    101u8, 114u8, 97u8, 98u8, 105u8, 116u8, 115u8, 116u8, 101u8, 114u8, 97u8, 98u8,
+                    121u8, 116u8, 101u8, 115u8, 115u8, 101u8, 109u8, 97u8, 110u8, 97u8, 115u8,
+                    121u8, 97u8, 114u8, 100u8, 97u8, 115u8, 97u8, 195u8, 177u8, 111u8, 115u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    63u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 7u8, 0u8, 11u8, 0u8, 18u8, 0u8, 28u8,
+                    0u8, 44u8, 0u8, 54u8, 0u8, 64u8, 0u8, 79u8, 0u8, 90u8, 0u8, 100u8, 0u8, 110u8,
+                    0u8, 113u8, 0u8, 119u8, 0u8, 129u8, 0u8, 140u8, 0u8, 144u8, 0u8, 150u8, 0u8,
+                    157u8, 0u8, 165u8, 0u8, 169u8, 0u8, 176u8, 0u8, 180u8, 0u8, 187u8, 0u8, 195u8,
+                    0u8, 203u8, 0u8, 212u8, 0u8, 230u8, 0u8, 235u8, 0u8, 254u8, 0u8, 5u8, 1u8,
+                    13u8, 1u8, 18u8, 1u8, 34u8, 1u8, 57u8, 1u8, 68u8, 1u8, 72u8, 1u8, 87u8, 1u8,
+                    100u8, 1u8, 117u8, 1u8, 127u8, 1u8, 137u8, 1u8, 148u8, 1u8, 154u8, 1u8, 159u8,
+                    1u8, 169u8, 1u8, 174u8, 1u8, 182u8, 1u8, 187u8, 1u8, 193u8, 1u8, 210u8, 1u8,
+                    221u8, 1u8, 232u8, 1u8, 248u8, 1u8, 4u8, 2u8, 15u8, 2u8, 26u8, 2u8, 31u8, 2u8,
+                    38u8, 2u8, 46u8, 2u8, 50u8, 2u8, 54u8, 2u8, 97u8, 99u8, 114u8, 101u8, 98u8,
+                    105u8, 116u8, 98u8, 121u8, 116u8, 101u8, 99u8, 101u8, 108u8, 115u8, 105u8,
+                    117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 102u8,
+                    111u8, 111u8, 116u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 105u8, 110u8, 99u8,
+                    104u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 105u8, 108u8,
+                    101u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 121u8, 97u8, 114u8, 100u8, 100u8,
+                    97u8, 121u8, 100u8, 101u8, 103u8, 114u8, 101u8, 101u8, 102u8, 97u8, 104u8,
+                    114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8, 102u8, 108u8, 117u8, 105u8,
+                    100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 102u8, 111u8, 111u8, 116u8,
+                    103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8, 105u8, 103u8, 97u8, 98u8,
+                    105u8, 116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8, 103u8,
+                    114u8, 97u8, 109u8, 104u8, 101u8, 99u8, 116u8, 97u8, 114u8, 101u8, 105u8,
+                    110u8, 99u8, 104u8, 107u8, 105u8, 108u8, 111u8, 98u8, 105u8, 116u8, 107u8,
+                    105u8, 108u8, 111u8, 98u8, 121u8, 116u8, 101u8, 107u8, 105u8, 108u8, 111u8,
+                    103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 108u8, 105u8,
+                    116u8, 101u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    109u8, 101u8, 103u8, 97u8, 98u8, 105u8, 116u8, 109u8, 101u8, 103u8, 97u8, 98u8,
+                    121u8, 116u8, 101u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 109u8, 105u8, 99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 109u8, 105u8, 108u8, 101u8, 109u8, 105u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8,
+                    105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8,
+                    114u8, 109u8, 105u8, 108u8, 101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8,
+                    105u8, 110u8, 97u8, 118u8, 105u8, 97u8, 110u8, 109u8, 105u8, 108u8, 108u8,
+                    105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 115u8,
+                    101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8, 110u8, 117u8, 116u8, 101u8,
+                    109u8, 111u8, 110u8, 116u8, 104u8, 110u8, 97u8, 110u8, 111u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8, 101u8,
+                    116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 112u8, 111u8, 117u8, 110u8, 100u8,
+                    115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 115u8, 113u8, 117u8, 97u8, 114u8,
+                    101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 102u8, 111u8, 111u8,
+                    116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 105u8, 110u8, 99u8,
+                    104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8,
+                    101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8, 115u8, 116u8, 111u8, 110u8,
+                    101u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8, 116u8, 101u8, 114u8,
+                    97u8, 98u8, 121u8, 116u8, 101u8, 119u8, 101u8, 101u8, 107u8, 121u8, 97u8,
+                    114u8, 100u8, 121u8, 101u8, 97u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    63u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 3u8, 0u8, 4u8, 0u8, 7u8, 0u8, 9u8,
+                    0u8, 13u8, 0u8, 17u8, 0u8, 21u8, 0u8, 25u8, 0u8, 28u8, 0u8, 32u8, 0u8, 36u8,
+                    0u8, 37u8, 0u8, 42u8, 0u8, 45u8, 0u8, 50u8, 0u8, 52u8, 0u8, 55u8, 0u8, 57u8,
+                    0u8, 59u8, 0u8, 60u8, 0u8, 62u8, 0u8, 64u8, 0u8, 66u8, 0u8, 68u8, 0u8, 70u8,
+                    0u8, 72u8, 0u8, 76u8, 0u8, 77u8, 0u8, 81u8, 0u8, 83u8, 0u8, 85u8, 0u8, 86u8,
+                    0u8, 89u8, 0u8, 94u8, 0u8, 97u8, 0u8, 99u8, 0u8, 105u8, 0u8, 109u8, 0u8, 116u8,
+                    0u8, 118u8, 0u8, 120u8, 0u8, 122u8, 0u8, 125u8, 0u8, 127u8, 0u8, 129u8, 0u8,
+                    131u8, 0u8, 133u8, 0u8, 135u8, 0u8, 136u8, 0u8, 140u8, 0u8, 144u8, 0u8, 148u8,
+                    0u8, 152u8, 0u8, 155u8, 0u8, 159u8, 0u8, 163u8, 0u8, 165u8, 0u8, 167u8, 0u8,
+                    169u8, 0u8, 173u8, 0u8, 175u8, 0u8, 97u8, 99u8, 98u8, 66u8, 194u8, 176u8, 67u8,
+                    99u8, 109u8, 99u8, 109u8, 194u8, 179u8, 102u8, 116u8, 194u8, 179u8, 105u8,
+                    110u8, 194u8, 179u8, 107u8, 109u8, 194u8, 179u8, 109u8, 194u8, 179u8, 109u8,
+                    105u8, 194u8, 179u8, 121u8, 100u8, 194u8, 179u8, 100u8, 103u8, 114u8, 97u8,
+                    100u8, 46u8, 194u8, 176u8, 70u8, 102u8, 108u8, 32u8, 111u8, 122u8, 102u8,
+                    116u8, 103u8, 97u8, 108u8, 71u8, 98u8, 71u8, 66u8, 103u8, 104u8, 97u8, 105u8,
+                    110u8, 107u8, 98u8, 107u8, 66u8, 107u8, 103u8, 107u8, 109u8, 107u8, 109u8,
+                    47u8, 104u8, 108u8, 108u8, 47u8, 107u8, 109u8, 77u8, 98u8, 77u8, 66u8, 109u8,
+                    109u8, 47u8, 115u8, 109u8, 47u8, 115u8, 194u8, 178u8, 206u8, 188u8, 115u8,
+                    109u8, 105u8, 109u8, 105u8, 47u8, 103u8, 97u8, 108u8, 109u8, 105u8, 47u8,
+                    104u8, 109u8, 105u8, 32u8, 101u8, 115u8, 99u8, 46u8, 109u8, 108u8, 109u8,
+                    109u8, 109u8, 115u8, 109u8, 105u8, 110u8, 109u8, 46u8, 110u8, 115u8, 111u8,
+                    122u8, 80u8, 66u8, 108u8, 98u8, 115u8, 99u8, 109u8, 194u8, 178u8, 102u8, 116u8,
+                    194u8, 178u8, 105u8, 110u8, 194u8, 178u8, 107u8, 109u8, 194u8, 178u8, 109u8,
+                    194u8, 178u8, 109u8, 105u8, 194u8, 178u8, 121u8, 100u8, 194u8, 178u8, 115u8,
+                    116u8, 84u8, 98u8, 84u8, 66u8, 115u8, 101u8, 109u8, 46u8, 121u8, 100u8, 97u8,
+                ])
+            },
+        )
+    },
+};
+static ES_AR: &DataStruct = &::icu_displaynames::provider::UnitDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    65u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 7u8, 0u8, 11u8, 0u8, 18u8, 0u8, 28u8,
+                    0u8, 44u8, 0u8, 54u8, 0u8, 64u8, 0u8, 79u8, 0u8, 90u8, 0u8, 100u8, 0u8, 110u8,
+                    0u8, 113u8, 0u8, 119u8, 0u8, 129u8, 0u8, 140u8, 0u8, 144u8, 0u8, 150u8, 0u8,
+                    157u8, 0u8, 165u8, 0u8, 169u8, 0u8, 176u8, 0u8, 180u8, 0u8, 184u8, 0u8, 191u8,
+                    0u8, 199u8, 0u8, 207u8, 0u8, 216u8, 0u8, 234u8, 0u8, 239u8, 0u8, 2u8, 1u8, 9u8,
+                    1u8, 17u8, 1u8, 22u8, 1u8, 38u8, 1u8, 61u8, 1u8, 72u8, 1u8, 76u8, 1u8, 91u8,
+                    1u8, 104u8, 1u8, 121u8, 1u8, 131u8, 1u8, 141u8, 1u8, 152u8, 1u8, 158u8, 1u8,
+                    163u8, 1u8, 173u8, 1u8, 178u8, 1u8, 185u8, 1u8, 193u8, 1u8, 198u8, 1u8, 204u8,
+                    1u8, 221u8, 1u8, 232u8, 1u8, 243u8, 1u8, 3u8, 2u8, 15u8, 2u8, 26u8, 2u8, 37u8,
+                    2u8, 42u8, 2u8, 49u8, 2u8, 57u8, 2u8, 61u8, 2u8, 65u8, 2u8, 97u8, 99u8, 114u8,
+                    101u8, 98u8, 105u8, 116u8, 98u8, 121u8, 116u8, 101u8, 99u8, 101u8, 108u8,
+                    115u8, 105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8, 110u8,
+                    116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 102u8, 111u8, 111u8, 116u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8,
+                    105u8, 110u8, 99u8, 104u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8,
+                    45u8, 109u8, 105u8, 108u8, 101u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 121u8,
+                    97u8, 114u8, 100u8, 100u8, 97u8, 121u8, 100u8, 101u8, 103u8, 114u8, 101u8,
+                    101u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8,
+                    102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8,
+                    102u8, 111u8, 111u8, 116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8,
+                    105u8, 103u8, 97u8, 98u8, 105u8, 116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8,
+                    116u8, 101u8, 103u8, 114u8, 97u8, 109u8, 104u8, 101u8, 99u8, 116u8, 97u8,
+                    114u8, 101u8, 104u8, 111u8, 117u8, 114u8, 105u8, 110u8, 99u8, 104u8, 107u8,
+                    105u8, 108u8, 111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 98u8,
+                    121u8, 116u8, 101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 104u8, 111u8, 117u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8, 97u8,
+                    98u8, 105u8, 116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 108u8, 101u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8,
+                    101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8,
+                    105u8, 108u8, 101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8,
+                    118u8, 105u8, 97u8, 110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 108u8, 105u8,
+                    116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 110u8,
+                    116u8, 104u8, 110u8, 97u8, 110u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8, 101u8, 114u8, 99u8, 101u8,
+                    110u8, 116u8, 112u8, 101u8, 116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 112u8,
+                    111u8, 117u8, 110u8, 100u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8, 115u8,
+                    116u8, 111u8, 110u8, 101u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8,
+                    116u8, 101u8, 114u8, 97u8, 98u8, 121u8, 116u8, 101u8, 119u8, 101u8, 101u8,
+                    107u8, 121u8, 97u8, 114u8, 100u8, 121u8, 101u8, 97u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    65u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 9u8, 0u8, 14u8, 0u8, 28u8, 0u8, 40u8,
+                    0u8, 61u8, 0u8, 74u8, 0u8, 91u8, 0u8, 111u8, 0u8, 126u8, 0u8, 141u8, 0u8,
+                    156u8, 0u8, 161u8, 0u8, 167u8, 0u8, 184u8, 0u8, 197u8, 0u8, 201u8, 0u8, 208u8,
+                    0u8, 216u8, 0u8, 225u8, 0u8, 231u8, 0u8, 241u8, 0u8, 246u8, 0u8, 254u8, 0u8,
+                    6u8, 1u8, 15u8, 1u8, 25u8, 1u8, 36u8, 1u8, 56u8, 1u8, 62u8, 1u8, 83u8, 1u8,
+                    91u8, 1u8, 100u8, 1u8, 106u8, 1u8, 124u8, 1u8, 154u8, 1u8, 167u8, 1u8, 173u8,
+                    1u8, 190u8, 1u8, 205u8, 1u8, 222u8, 1u8, 232u8, 1u8, 243u8, 1u8, 255u8, 1u8,
+                    6u8, 2u8, 11u8, 2u8, 23u8, 2u8, 28u8, 2u8, 38u8, 2u8, 47u8, 2u8, 53u8, 2u8,
+                    61u8, 2u8, 83u8, 2u8, 97u8, 2u8, 115u8, 2u8, 136u8, 2u8, 152u8, 2u8, 168u8,
+                    2u8, 184u8, 2u8, 190u8, 2u8, 198u8, 2u8, 207u8, 2u8, 214u8, 2u8, 220u8, 2u8,
+                    97u8, 99u8, 114u8, 101u8, 115u8, 98u8, 105u8, 116u8, 115u8, 98u8, 121u8, 116u8,
+                    101u8, 115u8, 103u8, 114u8, 97u8, 100u8, 111u8, 115u8, 32u8, 67u8, 101u8,
+                    108u8, 115u8, 105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 195u8, 173u8,
+                    109u8, 101u8, 116u8, 114u8, 111u8, 115u8, 99u8, 101u8, 110u8, 116u8, 195u8,
+                    173u8, 109u8, 101u8, 116u8, 114u8, 111u8, 115u8, 32u8, 99u8, 195u8, 186u8,
+                    98u8, 105u8, 99u8, 111u8, 115u8, 112u8, 105u8, 101u8, 115u8, 32u8, 99u8, 195u8,
+                    186u8, 98u8, 105u8, 99u8, 111u8, 115u8, 112u8, 117u8, 108u8, 103u8, 97u8,
+                    100u8, 97u8, 115u8, 32u8, 99u8, 195u8, 186u8, 98u8, 105u8, 99u8, 97u8, 115u8,
+                    107u8, 105u8, 108u8, 195u8, 179u8, 109u8, 101u8, 116u8, 114u8, 111u8, 115u8,
+                    32u8, 99u8, 195u8, 186u8, 98u8, 105u8, 99u8, 111u8, 115u8, 109u8, 101u8, 116u8,
+                    114u8, 111u8, 115u8, 32u8, 99u8, 195u8, 186u8, 98u8, 105u8, 99u8, 111u8, 115u8,
+                    109u8, 105u8, 108u8, 108u8, 97u8, 115u8, 32u8, 99u8, 195u8, 186u8, 98u8, 105u8,
+                    99u8, 97u8, 115u8, 121u8, 97u8, 114u8, 100u8, 97u8, 115u8, 32u8, 99u8, 195u8,
+                    186u8, 98u8, 105u8, 99u8, 97u8, 115u8, 100u8, 195u8, 173u8, 97u8, 115u8, 103u8,
+                    114u8, 97u8, 100u8, 111u8, 115u8, 103u8, 114u8, 97u8, 100u8, 111u8, 115u8,
+                    32u8, 70u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8,
+                    111u8, 110u8, 122u8, 97u8, 115u8, 32u8, 102u8, 108u8, 117u8, 105u8, 100u8,
+                    97u8, 115u8, 112u8, 105u8, 101u8, 115u8, 103u8, 97u8, 108u8, 111u8, 110u8,
+                    101u8, 115u8, 103u8, 105u8, 103u8, 97u8, 98u8, 105u8, 116u8, 115u8, 103u8,
+                    105u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8, 115u8, 103u8, 114u8, 97u8,
+                    109u8, 111u8, 115u8, 104u8, 101u8, 99u8, 116u8, 195u8, 161u8, 114u8, 101u8,
+                    97u8, 115u8, 104u8, 111u8, 114u8, 97u8, 115u8, 112u8, 117u8, 108u8, 103u8,
+                    97u8, 100u8, 97u8, 115u8, 107u8, 105u8, 108u8, 111u8, 98u8, 105u8, 116u8,
+                    115u8, 107u8, 105u8, 108u8, 111u8, 98u8, 121u8, 116u8, 101u8, 115u8, 107u8,
+                    105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 111u8, 115u8, 107u8, 105u8,
+                    108u8, 195u8, 179u8, 109u8, 101u8, 116u8, 114u8, 111u8, 115u8, 107u8, 105u8,
+                    108u8, 195u8, 179u8, 109u8, 101u8, 116u8, 114u8, 111u8, 115u8, 32u8, 112u8,
+                    111u8, 114u8, 32u8, 104u8, 111u8, 114u8, 97u8, 108u8, 105u8, 116u8, 114u8,
+                    111u8, 115u8, 108u8, 105u8, 116u8, 114u8, 111u8, 115u8, 32u8, 112u8, 111u8,
+                    114u8, 32u8, 107u8, 105u8, 108u8, 195u8, 179u8, 109u8, 101u8, 116u8, 114u8,
+                    111u8, 109u8, 101u8, 103u8, 97u8, 98u8, 105u8, 116u8, 115u8, 109u8, 101u8,
+                    103u8, 97u8, 98u8, 121u8, 116u8, 101u8, 115u8, 109u8, 101u8, 116u8, 114u8,
+                    111u8, 115u8, 109u8, 101u8, 116u8, 114u8, 111u8, 115u8, 32u8, 112u8, 111u8,
+                    114u8, 32u8, 115u8, 101u8, 103u8, 117u8, 110u8, 100u8, 111u8, 109u8, 101u8,
+                    116u8, 114u8, 111u8, 115u8, 32u8, 112u8, 111u8, 114u8, 32u8, 115u8, 101u8,
+                    103u8, 117u8, 110u8, 100u8, 111u8, 32u8, 97u8, 108u8, 32u8, 99u8, 117u8, 97u8,
+                    100u8, 114u8, 97u8, 100u8, 111u8, 109u8, 105u8, 99u8, 114u8, 111u8, 115u8,
+                    101u8, 103u8, 117u8, 110u8, 100u8, 111u8, 115u8, 109u8, 105u8, 108u8, 108u8,
+                    97u8, 115u8, 109u8, 105u8, 108u8, 108u8, 97u8, 115u8, 32u8, 112u8, 111u8,
+                    114u8, 32u8, 103u8, 97u8, 108u8, 195u8, 179u8, 110u8, 109u8, 105u8, 108u8,
+                    108u8, 97u8, 115u8, 32u8, 112u8, 111u8, 114u8, 32u8, 104u8, 111u8, 114u8, 97u8,
+                    109u8, 105u8, 108u8, 108u8, 97u8, 32u8, 101u8, 115u8, 99u8, 97u8, 110u8, 100u8,
+                    105u8, 110u8, 97u8, 118u8, 97u8, 109u8, 105u8, 108u8, 105u8, 108u8, 105u8,
+                    116u8, 114u8, 111u8, 115u8, 109u8, 105u8, 108u8, 195u8, 173u8, 109u8, 101u8,
+                    116u8, 114u8, 111u8, 115u8, 109u8, 105u8, 108u8, 105u8, 115u8, 101u8, 103u8,
+                    117u8, 110u8, 100u8, 111u8, 115u8, 109u8, 105u8, 110u8, 117u8, 116u8, 111u8,
+                    115u8, 109u8, 101u8, 115u8, 101u8, 115u8, 110u8, 97u8, 110u8, 111u8, 115u8,
+                    101u8, 103u8, 117u8, 110u8, 100u8, 111u8, 115u8, 111u8, 110u8, 122u8, 97u8,
+                    115u8, 112u8, 111u8, 114u8, 32u8, 99u8, 105u8, 101u8, 110u8, 116u8, 111u8,
+                    112u8, 101u8, 116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 115u8, 108u8, 105u8,
+                    98u8, 114u8, 97u8, 115u8, 115u8, 101u8, 103u8, 117u8, 110u8, 100u8, 111u8,
+                    115u8, 99u8, 101u8, 110u8, 116u8, 195u8, 173u8, 109u8, 101u8, 116u8, 114u8,
+                    111u8, 115u8, 32u8, 99u8, 117u8, 97u8, 100u8, 114u8, 97u8, 100u8, 111u8, 115u8,
+                    112u8, 105u8, 101u8, 115u8, 32u8, 99u8, 117u8, 97u8, 100u8, 114u8, 97u8, 100u8,
+                    111u8, 115u8, 112u8, 117u8, 108u8, 103u8, 97u8, 100u8, 97u8, 115u8, 32u8, 99u8,
+                    117u8, 97u8, 100u8, 114u8, 97u8, 100u8, 97u8, 115u8, 107u8, 105u8, 108u8,
+                    195u8, 179u8, 109u8, 101u8, 116u8, 114u8, 111u8, 115u8, 32u8, 99u8, 117u8,
+                    97u8, 100u8, 114u8, 97u8, 100u8, 111u8, 115u8, 109u8, 101u8, 116u8, 114u8,
+                    111u8, 115u8, 32u8, 99u8, 117u8, 97u8, 100u8, 114u8, 97u8, 100u8, 111u8, 115u8,
+                    109u8, 105u8, 108u8, 108u8, 97u8, 115u8, 32u8, 99u8, 117u8, 97u8, 100u8, 114u8,
+                    97u8, 100u8, 97u8, 115u8, 121u8, 97u8, 114u8, 100u8, 97u8, 115u8, 32u8, 99u8,
+                    117u8, 97u8, 100u8, 114u8, 97u8, 100u8, 97u8, 115u8, 115u8, 116u8, 111u8,
+                    110u8, 101u8, 115u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8, 115u8,
+                    116u8, 101u8, 114u8, 97u8, 98u8, 121u8, 116u8, 101u8, 115u8, 115u8, 101u8,
+                    109u8, 97u8, 110u8, 97u8, 115u8, 121u8, 97u8, 114u8, 100u8, 97u8, 115u8, 97u8,
+                    195u8, 177u8, 111u8, 115u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    60u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 7u8, 0u8, 11u8, 0u8, 18u8, 0u8, 28u8,
+                    0u8, 44u8, 0u8, 54u8, 0u8, 64u8, 0u8, 79u8, 0u8, 90u8, 0u8, 100u8, 0u8, 110u8,
+                    0u8, 113u8, 0u8, 123u8, 0u8, 134u8, 0u8, 138u8, 0u8, 144u8, 0u8, 151u8, 0u8,
+                    159u8, 0u8, 163u8, 0u8, 170u8, 0u8, 174u8, 0u8, 178u8, 0u8, 185u8, 0u8, 193u8,
+                    0u8, 201u8, 0u8, 210u8, 0u8, 228u8, 0u8, 233u8, 0u8, 252u8, 0u8, 3u8, 1u8,
+                    11u8, 1u8, 16u8, 1u8, 32u8, 1u8, 55u8, 1u8, 66u8, 1u8, 70u8, 1u8, 85u8, 1u8,
+                    98u8, 1u8, 115u8, 1u8, 125u8, 1u8, 135u8, 1u8, 146u8, 1u8, 152u8, 1u8, 157u8,
+                    1u8, 167u8, 1u8, 172u8, 1u8, 180u8, 1u8, 185u8, 1u8, 191u8, 1u8, 208u8, 1u8,
+                    219u8, 1u8, 230u8, 1u8, 246u8, 1u8, 2u8, 2u8, 13u8, 2u8, 24u8, 2u8, 31u8, 2u8,
+                    39u8, 2u8, 97u8, 99u8, 114u8, 101u8, 98u8, 105u8, 116u8, 98u8, 121u8, 116u8,
+                    101u8, 99u8, 101u8, 108u8, 115u8, 105u8, 117u8, 115u8, 99u8, 101u8, 110u8,
+                    116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 102u8, 111u8, 111u8, 116u8, 99u8,
+                    117u8, 98u8, 105u8, 99u8, 45u8, 105u8, 110u8, 99u8, 104u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 105u8, 108u8, 101u8, 99u8, 117u8,
+                    98u8, 105u8, 99u8, 45u8, 121u8, 97u8, 114u8, 100u8, 100u8, 97u8, 121u8, 102u8,
+                    97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8, 102u8, 108u8,
+                    117u8, 105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 102u8, 111u8,
+                    111u8, 116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8, 105u8, 103u8,
+                    97u8, 98u8, 105u8, 116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8,
+                    103u8, 114u8, 97u8, 109u8, 104u8, 101u8, 99u8, 116u8, 97u8, 114u8, 101u8,
+                    104u8, 111u8, 117u8, 114u8, 105u8, 110u8, 99u8, 104u8, 107u8, 105u8, 108u8,
+                    111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 98u8, 121u8, 116u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8,
+                    111u8, 117u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 108u8, 105u8, 116u8,
+                    101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8, 108u8, 111u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8, 97u8, 98u8, 105u8,
+                    116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    108u8, 101u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8, 105u8, 108u8,
+                    101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8, 118u8, 105u8,
+                    97u8, 110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 108u8, 105u8, 116u8, 101u8,
+                    114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    109u8, 105u8, 108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 110u8, 116u8, 104u8,
+                    110u8, 97u8, 110u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 111u8,
+                    117u8, 110u8, 99u8, 101u8, 112u8, 101u8, 116u8, 97u8, 98u8, 121u8, 116u8,
+                    101u8, 112u8, 111u8, 117u8, 110u8, 100u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8,
+                    116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8,
+                    108u8, 101u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8,
+                    114u8, 100u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8, 116u8, 101u8,
+                    114u8, 97u8, 98u8, 121u8, 116u8, 101u8, 119u8, 101u8, 101u8, 107u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    60u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 3u8, 0u8, 4u8, 0u8, 7u8, 0u8, 9u8,
+                    0u8, 13u8, 0u8, 17u8, 0u8, 21u8, 0u8, 25u8, 0u8, 28u8, 0u8, 32u8, 0u8, 36u8,
+                    0u8, 39u8, 0u8, 42u8, 0u8, 47u8, 0u8, 49u8, 0u8, 52u8, 0u8, 54u8, 0u8, 56u8,
+                    0u8, 57u8, 0u8, 59u8, 0u8, 62u8, 0u8, 64u8, 0u8, 66u8, 0u8, 68u8, 0u8, 70u8,
+                    0u8, 72u8, 0u8, 76u8, 0u8, 77u8, 0u8, 81u8, 0u8, 83u8, 0u8, 85u8, 0u8, 86u8,
+                    0u8, 89u8, 0u8, 94u8, 0u8, 97u8, 0u8, 99u8, 0u8, 105u8, 0u8, 109u8, 0u8, 116u8,
+                    0u8, 118u8, 0u8, 120u8, 0u8, 122u8, 0u8, 125u8, 0u8, 128u8, 0u8, 130u8, 0u8,
+                    132u8, 0u8, 134u8, 0u8, 136u8, 0u8, 140u8, 0u8, 144u8, 0u8, 148u8, 0u8, 152u8,
+                    0u8, 156u8, 0u8, 159u8, 0u8, 163u8, 0u8, 167u8, 0u8, 169u8, 0u8, 171u8, 0u8,
+                    97u8, 99u8, 98u8, 66u8, 194u8, 176u8, 67u8, 99u8, 109u8, 99u8, 109u8, 194u8,
+                    179u8, 102u8, 116u8, 194u8, 179u8, 105u8, 110u8, 194u8, 179u8, 107u8, 109u8,
+                    194u8, 179u8, 109u8, 194u8, 179u8, 109u8, 105u8, 194u8, 179u8, 121u8, 100u8,
+                    194u8, 179u8, 100u8, 100u8, 46u8, 194u8, 176u8, 70u8, 102u8, 108u8, 32u8,
+                    111u8, 122u8, 102u8, 116u8, 103u8, 97u8, 108u8, 71u8, 98u8, 71u8, 66u8, 103u8,
+                    104u8, 97u8, 104u8, 115u8, 46u8, 105u8, 110u8, 107u8, 98u8, 107u8, 66u8, 107u8,
+                    103u8, 107u8, 109u8, 107u8, 109u8, 47u8, 104u8, 108u8, 108u8, 47u8, 107u8,
+                    109u8, 77u8, 98u8, 77u8, 66u8, 109u8, 109u8, 47u8, 115u8, 109u8, 47u8, 115u8,
+                    194u8, 178u8, 206u8, 188u8, 115u8, 109u8, 105u8, 109u8, 105u8, 47u8, 103u8,
+                    97u8, 108u8, 109u8, 105u8, 47u8, 104u8, 109u8, 105u8, 32u8, 101u8, 115u8, 99u8,
+                    46u8, 109u8, 108u8, 109u8, 109u8, 109u8, 115u8, 109u8, 105u8, 110u8, 109u8,
+                    109u8, 46u8, 110u8, 115u8, 111u8, 122u8, 80u8, 66u8, 108u8, 98u8, 115u8, 101u8,
+                    103u8, 46u8, 99u8, 109u8, 194u8, 178u8, 102u8, 116u8, 194u8, 178u8, 105u8,
+                    110u8, 194u8, 178u8, 107u8, 109u8, 194u8, 178u8, 109u8, 194u8, 178u8, 109u8,
+                    105u8, 194u8, 178u8, 121u8, 100u8, 194u8, 178u8, 84u8, 98u8, 84u8, 66u8, 115u8,
+                    101u8, 109u8, 115u8, 46u8,
+                ])
+            },
+        )
+    },
+};
+static FIL: &DataStruct = &::icu_displaynames::provider::UnitDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    65u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 7u8, 0u8, 11u8, 0u8, 18u8, 0u8, 28u8,
+                    0u8, 44u8, 0u8, 54u8, 0u8, 64u8, 0u8, 79u8, 0u8, 90u8, 0u8, 100u8, 0u8, 110u8,
+                    0u8, 113u8, 0u8, 119u8, 0u8, 129u8, 0u8, 140u8, 0u8, 144u8, 0u8, 150u8, 0u8,
+                    157u8, 0u8, 165u8, 0u8, 169u8, 0u8, 176u8, 0u8, 180u8, 0u8, 184u8, 0u8, 191u8,
+                    0u8, 199u8, 0u8, 207u8, 0u8, 216u8, 0u8, 234u8, 0u8, 239u8, 0u8, 2u8, 1u8, 9u8,
+                    1u8, 17u8, 1u8, 22u8, 1u8, 38u8, 1u8, 61u8, 1u8, 72u8, 1u8, 76u8, 1u8, 91u8,
+                    1u8, 104u8, 1u8, 121u8, 1u8, 131u8, 1u8, 141u8, 1u8, 152u8, 1u8, 158u8, 1u8,
+                    163u8, 1u8, 173u8, 1u8, 178u8, 1u8, 185u8, 1u8, 193u8, 1u8, 198u8, 1u8, 204u8,
+                    1u8, 221u8, 1u8, 232u8, 1u8, 243u8, 1u8, 3u8, 2u8, 15u8, 2u8, 26u8, 2u8, 37u8,
+                    2u8, 42u8, 2u8, 49u8, 2u8, 57u8, 2u8, 61u8, 2u8, 65u8, 2u8, 97u8, 99u8, 114u8,
+                    101u8, 98u8, 105u8, 116u8, 98u8, 121u8, 116u8, 101u8, 99u8, 101u8, 108u8,
+                    115u8, 105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8, 110u8,
+                    116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 102u8, 111u8, 111u8, 116u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8,
+                    105u8, 110u8, 99u8, 104u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8,
+                    45u8, 109u8, 105u8, 108u8, 101u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 121u8,
+                    97u8, 114u8, 100u8, 100u8, 97u8, 121u8, 100u8, 101u8, 103u8, 114u8, 101u8,
+                    101u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8,
+                    102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8,
+                    102u8, 111u8, 111u8, 116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8,
+                    105u8, 103u8, 97u8, 98u8, 105u8, 116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8,
+                    116u8, 101u8, 103u8, 114u8, 97u8, 109u8, 104u8, 101u8, 99u8, 116u8, 97u8,
+                    114u8, 101u8, 104u8, 111u8, 117u8, 114u8, 105u8, 110u8, 99u8, 104u8, 107u8,
+                    105u8, 108u8, 111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 98u8,
+                    121u8, 116u8, 101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 104u8, 111u8, 117u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8, 97u8,
+                    98u8, 105u8, 116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 108u8, 101u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8,
+                    101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8,
+                    105u8, 108u8, 101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8,
+                    118u8, 105u8, 97u8, 110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 108u8, 105u8,
+                    116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 110u8,
+                    116u8, 104u8, 110u8, 97u8, 110u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8, 101u8, 114u8, 99u8, 101u8,
+                    110u8, 116u8, 112u8, 101u8, 116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 112u8,
+                    111u8, 117u8, 110u8, 100u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8, 115u8,
+                    116u8, 111u8, 110u8, 101u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8,
+                    116u8, 101u8, 114u8, 97u8, 98u8, 121u8, 116u8, 101u8, 119u8, 101u8, 101u8,
+                    107u8, 121u8, 97u8, 114u8, 100u8, 121u8, 101u8, 97u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    65u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 9u8, 0u8, 14u8, 0u8, 29u8, 0u8, 39u8,
+                    0u8, 56u8, 0u8, 73u8, 0u8, 87u8, 0u8, 103u8, 0u8, 115u8, 0u8, 127u8, 0u8,
+                    139u8, 0u8, 143u8, 0u8, 150u8, 0u8, 168u8, 0u8, 180u8, 0u8, 190u8, 0u8, 195u8,
+                    0u8, 203u8, 0u8, 212u8, 0u8, 217u8, 0u8, 224u8, 0u8, 232u8, 0u8, 239u8, 0u8,
+                    247u8, 0u8, 0u8, 1u8, 4u8, 1u8, 13u8, 1u8, 32u8, 1u8, 37u8, 1u8, 57u8, 1u8,
+                    65u8, 1u8, 74u8, 1u8, 79u8, 1u8, 97u8, 1u8, 124u8, 1u8, 136u8, 1u8, 141u8, 1u8,
+                    157u8, 1u8, 172u8, 1u8, 190u8, 1u8, 199u8, 1u8, 208u8, 1u8, 219u8, 1u8, 229u8,
+                    1u8, 238u8, 1u8, 249u8, 1u8, 253u8, 1u8, 6u8, 2u8, 15u8, 2u8, 20u8, 2u8, 31u8,
+                    2u8, 50u8, 2u8, 70u8, 2u8, 86u8, 2u8, 104u8, 2u8, 118u8, 2u8, 132u8, 2u8,
+                    149u8, 2u8, 155u8, 2u8, 163u8, 2u8, 172u8, 2u8, 182u8, 2u8, 187u8, 2u8, 97u8,
+                    99u8, 114u8, 101u8, 115u8, 98u8, 105u8, 116u8, 115u8, 98u8, 121u8, 116u8,
+                    101u8, 115u8, 100u8, 101u8, 103u8, 114u8, 101u8, 101u8, 115u8, 32u8, 67u8,
+                    101u8, 108u8, 115u8, 105u8, 117u8, 115u8, 115u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 114u8, 111u8, 107u8, 117u8, 98u8, 105u8, 107u8, 111u8,
+                    32u8, 115u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 114u8, 111u8,
+                    107u8, 117u8, 98u8, 105u8, 107u8, 111u8, 32u8, 116u8, 97u8, 108u8, 97u8, 109u8,
+                    112u8, 97u8, 107u8, 97u8, 110u8, 107u8, 117u8, 98u8, 105u8, 107u8, 111u8, 32u8,
+                    112u8, 117u8, 108u8, 103u8, 97u8, 100u8, 97u8, 107u8, 117u8, 98u8, 105u8,
+                    107u8, 111u8, 32u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 114u8,
+                    111u8, 107u8, 117u8, 98u8, 105u8, 107u8, 111u8, 32u8, 109u8, 101u8, 116u8,
+                    114u8, 111u8, 107u8, 117u8, 98u8, 105u8, 107u8, 111u8, 32u8, 109u8, 105u8,
+                    108u8, 121u8, 97u8, 107u8, 117u8, 98u8, 105u8, 107u8, 111u8, 32u8, 121u8, 97u8,
+                    114u8, 100u8, 97u8, 97u8, 114u8, 97u8, 119u8, 100u8, 101u8, 103u8, 114u8,
+                    101u8, 101u8, 115u8, 100u8, 101u8, 103u8, 114u8, 101u8, 101u8, 115u8, 32u8,
+                    70u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8, 102u8,
+                    108u8, 117u8, 105u8, 100u8, 32u8, 111u8, 117u8, 110u8, 99u8, 101u8, 115u8,
+                    116u8, 97u8, 108u8, 97u8, 109u8, 112u8, 97u8, 107u8, 97u8, 110u8, 103u8, 97u8,
+                    108u8, 111u8, 110u8, 103u8, 105u8, 103u8, 97u8, 98u8, 105u8, 116u8, 115u8,
+                    103u8, 105u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8, 115u8, 103u8, 114u8,
+                    97u8, 109u8, 111u8, 101u8, 107u8, 116u8, 97u8, 114u8, 121u8, 97u8, 109u8,
+                    103u8, 97u8, 32u8, 111u8, 114u8, 97u8, 115u8, 112u8, 117u8, 108u8, 103u8, 97u8,
+                    100u8, 97u8, 107u8, 105u8, 108u8, 111u8, 98u8, 105u8, 116u8, 115u8, 107u8,
+                    105u8, 108u8, 111u8, 98u8, 121u8, 116u8, 101u8, 115u8, 107u8, 105u8, 108u8,
+                    111u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 114u8, 111u8, 107u8,
+                    105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 114u8, 111u8, 32u8, 107u8, 97u8,
+                    100u8, 97u8, 32u8, 111u8, 114u8, 97u8, 115u8, 108u8, 105u8, 116u8, 114u8,
+                    111u8, 108u8, 105u8, 116u8, 114u8, 111u8, 32u8, 107u8, 97u8, 100u8, 97u8, 32u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 114u8, 111u8, 109u8, 101u8,
+                    103u8, 97u8, 98u8, 105u8, 116u8, 115u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8,
+                    116u8, 101u8, 115u8, 109u8, 101u8, 116u8, 114u8, 111u8, 109u8, 101u8, 116u8,
+                    114u8, 111u8, 32u8, 107u8, 97u8, 100u8, 97u8, 32u8, 115u8, 101u8, 103u8, 117u8,
+                    110u8, 100u8, 111u8, 109u8, 101u8, 116u8, 114u8, 111u8, 32u8, 107u8, 97u8,
+                    100u8, 97u8, 32u8, 115u8, 101u8, 103u8, 117u8, 110u8, 100u8, 111u8, 32u8,
+                    107u8, 119u8, 97u8, 100u8, 114u8, 97u8, 100u8, 111u8, 109u8, 105u8, 107u8,
+                    114u8, 111u8, 115u8, 101u8, 103u8, 117u8, 110u8, 100u8, 111u8, 109u8, 105u8,
+                    108u8, 121u8, 97u8, 109u8, 105u8, 108u8, 121u8, 97u8, 32u8, 107u8, 97u8, 100u8,
+                    97u8, 32u8, 103u8, 97u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8, 121u8, 97u8,
+                    32u8, 107u8, 97u8, 100u8, 97u8, 32u8, 111u8, 114u8, 97u8, 115u8, 109u8, 105u8,
+                    108u8, 121u8, 97u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8,
+                    118u8, 105u8, 97u8, 110u8, 109u8, 105u8, 108u8, 105u8, 108u8, 105u8, 116u8,
+                    114u8, 111u8, 109u8, 105u8, 108u8, 105u8, 109u8, 101u8, 116u8, 114u8, 111u8,
+                    109u8, 105u8, 108u8, 105u8, 115u8, 101u8, 103u8, 117u8, 110u8, 100u8, 111u8,
+                    109u8, 103u8, 97u8, 32u8, 109u8, 105u8, 110u8, 117u8, 116u8, 111u8, 109u8,
+                    103u8, 97u8, 32u8, 98u8, 117u8, 119u8, 97u8, 110u8, 110u8, 97u8, 110u8, 111u8,
+                    115u8, 101u8, 103u8, 117u8, 110u8, 100u8, 111u8, 111u8, 110u8, 115u8, 97u8,
+                    112u8, 111u8, 114u8, 115u8, 121u8, 101u8, 110u8, 116u8, 111u8, 112u8, 101u8,
+                    116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 115u8, 108u8, 105u8, 98u8, 114u8, 97u8,
+                    109u8, 103u8, 97u8, 32u8, 115u8, 101u8, 103u8, 117u8, 110u8, 100u8, 111u8,
+                    115u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 114u8, 111u8, 32u8,
+                    107u8, 119u8, 97u8, 100u8, 114u8, 97u8, 100u8, 111u8, 116u8, 97u8, 108u8, 97u8,
+                    109u8, 112u8, 97u8, 107u8, 97u8, 110u8, 32u8, 112u8, 97u8, 114u8, 105u8, 115u8,
+                    117u8, 107u8, 97u8, 116u8, 112u8, 117u8, 108u8, 103u8, 97u8, 100u8, 97u8, 32u8,
+                    107u8, 119u8, 97u8, 100u8, 114u8, 97u8, 100u8, 111u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 114u8, 111u8, 32u8, 107u8, 119u8, 97u8, 100u8,
+                    114u8, 97u8, 100u8, 111u8, 109u8, 101u8, 116u8, 114u8, 111u8, 32u8, 107u8,
+                    119u8, 97u8, 100u8, 114u8, 97u8, 100u8, 111u8, 109u8, 105u8, 108u8, 121u8,
+                    97u8, 32u8, 107u8, 119u8, 97u8, 100u8, 114u8, 97u8, 100u8, 111u8, 121u8, 97u8,
+                    114u8, 100u8, 97u8, 110u8, 103u8, 32u8, 112u8, 97u8, 114u8, 105u8, 115u8,
+                    117u8, 107u8, 97u8, 116u8, 115u8, 116u8, 111u8, 110u8, 101u8, 115u8, 116u8,
+                    101u8, 114u8, 97u8, 98u8, 105u8, 116u8, 115u8, 116u8, 101u8, 114u8, 97u8, 98u8,
+                    121u8, 116u8, 101u8, 115u8, 109u8, 103u8, 97u8, 32u8, 108u8, 105u8, 110u8,
+                    103u8, 103u8, 111u8, 121u8, 97u8, 114u8, 100u8, 97u8, 109u8, 103u8, 97u8, 32u8,
+                    116u8, 97u8, 111u8, 110u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    53u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 7u8, 0u8, 14u8, 0u8, 24u8, 0u8, 40u8,
+                    0u8, 50u8, 0u8, 60u8, 0u8, 75u8, 0u8, 86u8, 0u8, 96u8, 0u8, 106u8, 0u8, 116u8,
+                    0u8, 127u8, 0u8, 133u8, 0u8, 140u8, 0u8, 148u8, 0u8, 155u8, 0u8, 159u8, 0u8,
+                    166u8, 0u8, 174u8, 0u8, 182u8, 0u8, 191u8, 0u8, 209u8, 0u8, 228u8, 0u8, 235u8,
+                    0u8, 243u8, 0u8, 3u8, 1u8, 26u8, 1u8, 37u8, 1u8, 52u8, 1u8, 65u8, 1u8, 82u8,
+                    1u8, 92u8, 1u8, 102u8, 1u8, 113u8, 1u8, 119u8, 1u8, 124u8, 1u8, 134u8, 1u8,
+                    139u8, 1u8, 146u8, 1u8, 154u8, 1u8, 160u8, 1u8, 177u8, 1u8, 188u8, 1u8, 199u8,
+                    1u8, 215u8, 1u8, 227u8, 1u8, 238u8, 1u8, 249u8, 1u8, 0u8, 2u8, 8u8, 2u8, 12u8,
+                    2u8, 98u8, 105u8, 116u8, 98u8, 121u8, 116u8, 101u8, 99u8, 101u8, 108u8, 115u8,
+                    105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8, 110u8, 116u8,
+                    105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8,
+                    102u8, 111u8, 111u8, 116u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 105u8, 110u8,
+                    99u8, 104u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 107u8, 105u8, 108u8, 111u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 105u8,
+                    108u8, 101u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 121u8, 97u8, 114u8, 100u8,
+                    102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8, 102u8,
+                    108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 103u8,
+                    97u8, 108u8, 108u8, 111u8, 110u8, 103u8, 105u8, 103u8, 97u8, 98u8, 105u8,
+                    116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8, 104u8, 101u8,
+                    99u8, 116u8, 97u8, 114u8, 101u8, 104u8, 111u8, 117u8, 114u8, 107u8, 105u8,
+                    108u8, 111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 98u8, 121u8,
+                    116u8, 101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8,
+                    105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    104u8, 111u8, 117u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 109u8, 101u8, 103u8, 97u8, 98u8, 105u8, 116u8, 109u8, 101u8, 103u8,
+                    97u8, 98u8, 121u8, 116u8, 101u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 103u8, 97u8,
+                    108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8, 105u8, 108u8, 101u8, 45u8,
+                    115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8, 118u8, 105u8, 97u8, 110u8,
+                    109u8, 105u8, 108u8, 108u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 110u8, 116u8, 104u8, 110u8, 97u8,
+                    110u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 111u8, 117u8, 110u8,
+                    99u8, 101u8, 112u8, 101u8, 114u8, 99u8, 101u8, 110u8, 116u8, 112u8, 101u8,
+                    116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8,
+                    116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8,
+                    108u8, 101u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8,
+                    114u8, 100u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8, 116u8, 101u8,
+                    114u8, 97u8, 98u8, 121u8, 116u8, 101u8, 119u8, 101u8, 101u8, 107u8, 121u8,
+                    101u8, 97u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    53u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 7u8, 0u8, 13u8, 0u8, 15u8, 0u8, 19u8,
+                    0u8, 31u8, 0u8, 40u8, 0u8, 44u8, 0u8, 47u8, 0u8, 51u8, 0u8, 58u8, 0u8, 64u8,
+                    0u8, 69u8, 0u8, 72u8, 0u8, 76u8, 0u8, 81u8, 0u8, 89u8, 0u8, 93u8, 0u8, 97u8,
+                    0u8, 102u8, 0u8, 104u8, 0u8, 106u8, 0u8, 111u8, 0u8, 119u8, 0u8, 123u8, 0u8,
+                    128u8, 0u8, 137u8, 0u8, 152u8, 0u8, 157u8, 0u8, 166u8, 0u8, 176u8, 0u8, 179u8,
+                    0u8, 181u8, 0u8, 183u8, 0u8, 190u8, 0u8, 194u8, 0u8, 199u8, 0u8, 206u8, 0u8,
+                    208u8, 0u8, 209u8, 0u8, 214u8, 0u8, 218u8, 0u8, 222u8, 0u8, 229u8, 0u8, 238u8,
+                    0u8, 242u8, 0u8, 249u8, 0u8, 0u8, 1u8, 7u8, 1u8, 11u8, 1u8, 16u8, 1u8, 22u8,
+                    1u8, 98u8, 105u8, 116u8, 98u8, 121u8, 116u8, 101u8, 100u8, 101u8, 103u8, 46u8,
+                    32u8, 67u8, 99u8, 109u8, 99u8, 109u8, 194u8, 179u8, 116u8, 97u8, 108u8, 97u8,
+                    109u8, 112u8, 97u8, 107u8, 97u8, 110u8, 194u8, 179u8, 112u8, 117u8, 108u8,
+                    103u8, 97u8, 100u8, 97u8, 194u8, 179u8, 107u8, 109u8, 194u8, 179u8, 109u8,
+                    194u8, 179u8, 109u8, 105u8, 194u8, 179u8, 121u8, 97u8, 114u8, 100u8, 97u8,
+                    194u8, 179u8, 100u8, 101u8, 103u8, 46u8, 32u8, 70u8, 102u8, 108u8, 32u8, 111u8,
+                    122u8, 103u8, 97u8, 108u8, 71u8, 98u8, 105u8, 116u8, 71u8, 66u8, 121u8, 116u8,
+                    101u8, 104u8, 101u8, 107u8, 116u8, 97u8, 114u8, 121u8, 97u8, 111u8, 114u8,
+                    97u8, 115u8, 107u8, 98u8, 105u8, 116u8, 107u8, 66u8, 121u8, 116u8, 101u8,
+                    107u8, 103u8, 107u8, 109u8, 107u8, 109u8, 47u8, 104u8, 114u8, 108u8, 105u8,
+                    116u8, 114u8, 111u8, 47u8, 107u8, 109u8, 77u8, 98u8, 105u8, 116u8, 77u8, 66u8,
+                    121u8, 116u8, 101u8, 109u8, 101u8, 116u8, 114u8, 111u8, 47u8, 115u8, 101u8,
+                    103u8, 109u8, 101u8, 116u8, 114u8, 111u8, 47u8, 115u8, 101u8, 103u8, 117u8,
+                    110u8, 100u8, 111u8, 194u8, 178u8, 206u8, 188u8, 115u8, 101u8, 103u8, 109u8,
+                    105u8, 108u8, 121u8, 97u8, 47u8, 103u8, 97u8, 108u8, 109u8, 105u8, 108u8,
+                    121u8, 97u8, 47u8, 111u8, 114u8, 97u8, 115u8, 115u8, 109u8, 105u8, 109u8, 76u8,
+                    109u8, 109u8, 109u8, 105u8, 108u8, 105u8, 115u8, 101u8, 103u8, 109u8, 105u8,
+                    110u8, 46u8, 98u8, 117u8, 119u8, 97u8, 110u8, 110u8, 97u8, 110u8, 111u8, 115u8,
+                    101u8, 103u8, 111u8, 122u8, 37u8, 80u8, 66u8, 121u8, 116u8, 101u8, 115u8,
+                    101u8, 103u8, 46u8, 99u8, 109u8, 194u8, 178u8, 115u8, 113u8, 32u8, 102u8,
+                    101u8, 101u8, 116u8, 112u8, 117u8, 108u8, 103u8, 97u8, 100u8, 97u8, 194u8,
+                    178u8, 107u8, 109u8, 194u8, 178u8, 109u8, 101u8, 116u8, 114u8, 111u8, 194u8,
+                    178u8, 115u8, 113u8, 32u8, 109u8, 105u8, 108u8, 101u8, 121u8, 97u8, 114u8,
+                    100u8, 97u8, 194u8, 178u8, 84u8, 98u8, 105u8, 116u8, 84u8, 66u8, 121u8, 116u8,
+                    101u8, 108u8, 105u8, 110u8, 103u8, 103u8, 111u8, 116u8, 97u8, 111u8, 110u8,
+                ])
+            },
+        )
+    },
+};
+static FR: &DataStruct = &::icu_displaynames::provider::UnitDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    65u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 7u8, 0u8, 11u8, 0u8, 18u8, 0u8, 28u8,
+                    0u8, 44u8, 0u8, 54u8, 0u8, 64u8, 0u8, 79u8, 0u8, 90u8, 0u8, 100u8, 0u8, 110u8,
+                    0u8, 113u8, 0u8, 119u8, 0u8, 129u8, 0u8, 140u8, 0u8, 144u8, 0u8, 150u8, 0u8,
+                    157u8, 0u8, 165u8, 0u8, 169u8, 0u8, 176u8, 0u8, 180u8, 0u8, 184u8, 0u8, 191u8,
+                    0u8, 199u8, 0u8, 207u8, 0u8, 216u8, 0u8, 234u8, 0u8, 239u8, 0u8, 2u8, 1u8, 9u8,
+                    1u8, 17u8, 1u8, 22u8, 1u8, 38u8, 1u8, 61u8, 1u8, 72u8, 1u8, 76u8, 1u8, 91u8,
+                    1u8, 104u8, 1u8, 121u8, 1u8, 131u8, 1u8, 141u8, 1u8, 152u8, 1u8, 158u8, 1u8,
+                    163u8, 1u8, 173u8, 1u8, 178u8, 1u8, 185u8, 1u8, 193u8, 1u8, 198u8, 1u8, 204u8,
+                    1u8, 221u8, 1u8, 232u8, 1u8, 243u8, 1u8, 3u8, 2u8, 15u8, 2u8, 26u8, 2u8, 37u8,
+                    2u8, 42u8, 2u8, 49u8, 2u8, 57u8, 2u8, 61u8, 2u8, 65u8, 2u8, 97u8, 99u8, 114u8,
+                    101u8, 98u8, 105u8, 116u8, 98u8, 121u8, 116u8, 101u8, 99u8, 101u8, 108u8,
+                    115u8, 105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8, 110u8,
+                    116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 102u8, 111u8, 111u8, 116u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8,
+                    105u8, 110u8, 99u8, 104u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8,
+                    45u8, 109u8, 105u8, 108u8, 101u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 121u8,
+                    97u8, 114u8, 100u8, 100u8, 97u8, 121u8, 100u8, 101u8, 103u8, 114u8, 101u8,
+                    101u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8,
+                    102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8,
+                    102u8, 111u8, 111u8, 116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8,
+                    105u8, 103u8, 97u8, 98u8, 105u8, 116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8,
+                    116u8, 101u8, 103u8, 114u8, 97u8, 109u8, 104u8, 101u8, 99u8, 116u8, 97u8,
+                    114u8, 101u8, 104u8, 111u8, 117u8, 114u8, 105u8, 110u8, 99u8, 104u8, 107u8,
+                    105u8, 108u8, 111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 98u8,
+                    121u8, 116u8, 101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 104u8, 111u8, 117u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8, 97u8,
+                    98u8, 105u8, 116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 108u8, 101u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8,
+                    101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8,
+                    105u8, 108u8, 101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8,
+                    118u8, 105u8, 97u8, 110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 108u8, 105u8,
+                    116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 110u8,
+                    116u8, 104u8, 110u8, 97u8, 110u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8, 101u8, 114u8, 99u8, 101u8,
+                    110u8, 116u8, 112u8, 101u8, 116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 112u8,
+                    111u8, 117u8, 110u8, 100u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8, 115u8,
+                    116u8, 111u8, 110u8, 101u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8,
+                    116u8, 101u8, 114u8, 97u8, 98u8, 121u8, 116u8, 101u8, 119u8, 101u8, 101u8,
+                    107u8, 121u8, 97u8, 114u8, 100u8, 121u8, 101u8, 97u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    65u8, 0u8, 0u8, 0u8, 0u8, 0u8, 20u8, 0u8, 24u8, 0u8, 30u8, 0u8, 45u8, 0u8,
+                    57u8, 0u8, 75u8, 0u8, 86u8, 0u8, 98u8, 0u8, 115u8, 0u8, 128u8, 0u8, 140u8, 0u8,
+                    151u8, 0u8, 156u8, 0u8, 163u8, 0u8, 181u8, 0u8, 195u8, 0u8, 200u8, 0u8, 207u8,
+                    0u8, 215u8, 0u8, 225u8, 0u8, 232u8, 0u8, 240u8, 0u8, 246u8, 0u8, 252u8, 0u8,
+                    4u8, 1u8, 14u8, 1u8, 25u8, 1u8, 36u8, 1u8, 57u8, 1u8, 63u8, 1u8, 83u8, 1u8,
+                    92u8, 1u8, 103u8, 1u8, 110u8, 1u8, 129u8, 1u8, 156u8, 1u8, 169u8, 1u8, 174u8,
+                    1u8, 190u8, 1u8, 205u8, 1u8, 223u8, 1u8, 234u8, 1u8, 246u8, 1u8, 3u8, 2u8,
+                    10u8, 2u8, 14u8, 2u8, 26u8, 2u8, 31u8, 2u8, 40u8, 2u8, 51u8, 2u8, 57u8, 2u8,
+                    65u8, 2u8, 85u8, 2u8, 98u8, 2u8, 112u8, 2u8, 131u8, 2u8, 146u8, 2u8, 160u8,
+                    2u8, 173u8, 2u8, 179u8, 2u8, 188u8, 2u8, 199u8, 2u8, 207u8, 2u8, 212u8, 2u8,
+                    97u8, 99u8, 114u8, 101u8, 115u8, 32u8, 97u8, 110u8, 103u8, 108u8, 111u8, 45u8,
+                    115u8, 97u8, 120u8, 111u8, 110u8, 110u8, 101u8, 115u8, 98u8, 105u8, 116u8,
+                    115u8, 111u8, 99u8, 116u8, 101u8, 116u8, 115u8, 100u8, 101u8, 103u8, 114u8,
+                    195u8, 169u8, 115u8, 32u8, 67u8, 101u8, 108u8, 115u8, 105u8, 117u8, 115u8,
+                    99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 195u8, 168u8, 116u8, 114u8, 101u8,
+                    115u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 195u8, 168u8, 116u8, 114u8,
+                    101u8, 115u8, 32u8, 99u8, 117u8, 98u8, 101u8, 115u8, 112u8, 105u8, 101u8,
+                    100u8, 115u8, 32u8, 99u8, 117u8, 98u8, 101u8, 115u8, 112u8, 111u8, 117u8, 99u8,
+                    101u8, 115u8, 32u8, 99u8, 117u8, 98u8, 101u8, 115u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 195u8, 168u8, 116u8, 114u8, 101u8, 115u8, 32u8, 99u8, 117u8,
+                    98u8, 101u8, 115u8, 109u8, 195u8, 168u8, 116u8, 114u8, 101u8, 115u8, 32u8,
+                    99u8, 117u8, 98u8, 101u8, 115u8, 109u8, 105u8, 108u8, 108u8, 101u8, 115u8,
+                    32u8, 99u8, 117u8, 98u8, 101u8, 115u8, 121u8, 97u8, 114u8, 100u8, 115u8, 32u8,
+                    99u8, 117u8, 98u8, 101u8, 115u8, 106u8, 111u8, 117u8, 114u8, 115u8, 100u8,
+                    101u8, 103u8, 114u8, 195u8, 169u8, 115u8, 100u8, 101u8, 103u8, 114u8, 195u8,
+                    169u8, 115u8, 32u8, 70u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8,
+                    105u8, 116u8, 111u8, 110u8, 99u8, 101u8, 115u8, 32u8, 108u8, 105u8, 113u8,
+                    117u8, 105u8, 100u8, 101u8, 115u8, 112u8, 105u8, 101u8, 100u8, 115u8, 103u8,
+                    97u8, 108u8, 108u8, 111u8, 110u8, 115u8, 103u8, 105u8, 103u8, 97u8, 98u8,
+                    105u8, 116u8, 115u8, 103u8, 105u8, 103u8, 97u8, 111u8, 99u8, 116u8, 101u8,
+                    116u8, 115u8, 103u8, 114u8, 97u8, 109u8, 109u8, 101u8, 115u8, 104u8, 101u8,
+                    99u8, 116u8, 97u8, 114u8, 101u8, 115u8, 104u8, 101u8, 117u8, 114u8, 101u8,
+                    115u8, 112u8, 111u8, 117u8, 99u8, 101u8, 115u8, 107u8, 105u8, 108u8, 111u8,
+                    98u8, 105u8, 116u8, 115u8, 107u8, 105u8, 108u8, 111u8, 111u8, 99u8, 116u8,
+                    101u8, 116u8, 115u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8,
+                    109u8, 101u8, 115u8, 107u8, 105u8, 108u8, 111u8, 109u8, 195u8, 168u8, 116u8,
+                    114u8, 101u8, 115u8, 107u8, 105u8, 108u8, 111u8, 109u8, 195u8, 168u8, 116u8,
+                    114u8, 101u8, 115u8, 32u8, 112u8, 97u8, 114u8, 32u8, 104u8, 101u8, 117u8,
+                    114u8, 101u8, 108u8, 105u8, 116u8, 114u8, 101u8, 115u8, 108u8, 105u8, 116u8,
+                    114u8, 101u8, 115u8, 32u8, 97u8, 117u8, 32u8, 107u8, 105u8, 108u8, 111u8,
+                    109u8, 195u8, 168u8, 116u8, 114u8, 101u8, 109u8, 195u8, 169u8, 103u8, 97u8,
+                    98u8, 105u8, 116u8, 115u8, 109u8, 195u8, 169u8, 103u8, 97u8, 111u8, 99u8,
+                    116u8, 101u8, 116u8, 115u8, 109u8, 195u8, 168u8, 116u8, 114u8, 101u8, 115u8,
+                    109u8, 195u8, 168u8, 116u8, 114u8, 101u8, 115u8, 32u8, 112u8, 97u8, 114u8,
+                    32u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 101u8, 109u8, 195u8, 168u8,
+                    116u8, 114u8, 101u8, 115u8, 32u8, 112u8, 97u8, 114u8, 32u8, 115u8, 101u8, 99u8,
+                    111u8, 110u8, 100u8, 101u8, 32u8, 99u8, 97u8, 114u8, 114u8, 195u8, 169u8,
+                    101u8, 109u8, 105u8, 99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 101u8, 115u8, 109u8, 105u8, 108u8, 101u8, 115u8, 109u8, 105u8, 108u8,
+                    101u8, 115u8, 32u8, 112u8, 97u8, 114u8, 32u8, 103u8, 97u8, 108u8, 108u8, 111u8,
+                    110u8, 109u8, 105u8, 108u8, 101u8, 115u8, 32u8, 112u8, 97u8, 114u8, 32u8,
+                    104u8, 101u8, 117u8, 114u8, 101u8, 109u8, 105u8, 108u8, 108u8, 101u8, 115u8,
+                    32u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8, 118u8, 101u8, 115u8,
+                    109u8, 105u8, 108u8, 108u8, 105u8, 108u8, 105u8, 116u8, 114u8, 101u8, 115u8,
+                    109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 195u8, 168u8, 116u8, 114u8, 101u8,
+                    115u8, 109u8, 105u8, 108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 101u8, 115u8, 109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 115u8, 109u8,
+                    111u8, 105u8, 115u8, 110u8, 97u8, 110u8, 111u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 101u8, 115u8, 111u8, 110u8, 99u8, 101u8, 115u8, 112u8, 111u8,
+                    117u8, 114u8, 32u8, 99u8, 101u8, 110u8, 116u8, 112u8, 195u8, 169u8, 116u8,
+                    97u8, 111u8, 99u8, 116u8, 101u8, 116u8, 115u8, 108u8, 105u8, 118u8, 114u8,
+                    101u8, 115u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 101u8, 115u8, 99u8,
+                    101u8, 110u8, 116u8, 105u8, 109u8, 195u8, 168u8, 116u8, 114u8, 101u8, 115u8,
+                    32u8, 99u8, 97u8, 114u8, 114u8, 195u8, 169u8, 115u8, 112u8, 105u8, 101u8,
+                    100u8, 115u8, 32u8, 99u8, 97u8, 114u8, 114u8, 195u8, 169u8, 115u8, 112u8,
+                    111u8, 117u8, 99u8, 101u8, 115u8, 32u8, 99u8, 97u8, 114u8, 114u8, 195u8, 169u8,
+                    115u8, 107u8, 105u8, 108u8, 111u8, 109u8, 195u8, 168u8, 116u8, 114u8, 101u8,
+                    115u8, 32u8, 99u8, 97u8, 114u8, 114u8, 195u8, 169u8, 115u8, 109u8, 195u8,
+                    168u8, 116u8, 114u8, 101u8, 115u8, 32u8, 99u8, 97u8, 114u8, 114u8, 195u8,
+                    169u8, 115u8, 109u8, 105u8, 108u8, 108u8, 101u8, 115u8, 32u8, 99u8, 97u8,
+                    114u8, 114u8, 195u8, 169u8, 115u8, 121u8, 97u8, 114u8, 100u8, 115u8, 32u8,
+                    99u8, 97u8, 114u8, 114u8, 195u8, 169u8, 115u8, 115u8, 116u8, 111u8, 110u8,
+                    101u8, 115u8, 116u8, 195u8, 169u8, 114u8, 97u8, 98u8, 105u8, 116u8, 115u8,
+                    116u8, 195u8, 169u8, 114u8, 97u8, 111u8, 99u8, 116u8, 101u8, 116u8, 115u8,
+                    115u8, 101u8, 109u8, 97u8, 105u8, 110u8, 101u8, 115u8, 121u8, 97u8, 114u8,
+                    100u8, 115u8, 97u8, 110u8, 115u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    64u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 7u8, 0u8, 11u8, 0u8, 18u8, 0u8, 28u8,
+                    0u8, 44u8, 0u8, 54u8, 0u8, 64u8, 0u8, 79u8, 0u8, 90u8, 0u8, 100u8, 0u8, 110u8,
+                    0u8, 113u8, 0u8, 119u8, 0u8, 129u8, 0u8, 140u8, 0u8, 144u8, 0u8, 150u8, 0u8,
+                    157u8, 0u8, 165u8, 0u8, 169u8, 0u8, 176u8, 0u8, 180u8, 0u8, 184u8, 0u8, 191u8,
+                    0u8, 199u8, 0u8, 207u8, 0u8, 216u8, 0u8, 234u8, 0u8, 239u8, 0u8, 2u8, 1u8, 9u8,
+                    1u8, 17u8, 1u8, 22u8, 1u8, 38u8, 1u8, 61u8, 1u8, 72u8, 1u8, 76u8, 1u8, 91u8,
+                    1u8, 104u8, 1u8, 121u8, 1u8, 131u8, 1u8, 141u8, 1u8, 152u8, 1u8, 158u8, 1u8,
+                    163u8, 1u8, 173u8, 1u8, 178u8, 1u8, 185u8, 1u8, 193u8, 1u8, 198u8, 1u8, 204u8,
+                    1u8, 221u8, 1u8, 232u8, 1u8, 243u8, 1u8, 3u8, 2u8, 15u8, 2u8, 26u8, 2u8, 37u8,
+                    2u8, 42u8, 2u8, 49u8, 2u8, 57u8, 2u8, 61u8, 2u8, 97u8, 99u8, 114u8, 101u8,
+                    98u8, 105u8, 116u8, 98u8, 121u8, 116u8, 101u8, 99u8, 101u8, 108u8, 115u8,
+                    105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8, 110u8, 116u8,
+                    105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8,
+                    102u8, 111u8, 111u8, 116u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 105u8, 110u8,
+                    99u8, 104u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 107u8, 105u8, 108u8, 111u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 105u8,
+                    108u8, 101u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 121u8, 97u8, 114u8, 100u8,
+                    100u8, 97u8, 121u8, 100u8, 101u8, 103u8, 114u8, 101u8, 101u8, 102u8, 97u8,
+                    104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8, 102u8, 108u8, 117u8,
+                    105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 102u8, 111u8, 111u8,
+                    116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8, 105u8, 103u8, 97u8,
+                    98u8, 105u8, 116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8,
+                    103u8, 114u8, 97u8, 109u8, 104u8, 101u8, 99u8, 116u8, 97u8, 114u8, 101u8,
+                    104u8, 111u8, 117u8, 114u8, 105u8, 110u8, 99u8, 104u8, 107u8, 105u8, 108u8,
+                    111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 98u8, 121u8, 116u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8,
+                    111u8, 117u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 108u8, 105u8, 116u8,
+                    101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8, 108u8, 111u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8, 97u8, 98u8, 105u8,
+                    116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    108u8, 101u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8, 105u8, 108u8,
+                    101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8, 118u8, 105u8,
+                    97u8, 110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 108u8, 105u8, 116u8, 101u8,
+                    114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    109u8, 105u8, 108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 110u8, 116u8, 104u8,
+                    110u8, 97u8, 110u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 111u8,
+                    117u8, 110u8, 99u8, 101u8, 112u8, 101u8, 114u8, 99u8, 101u8, 110u8, 116u8,
+                    112u8, 101u8, 116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 112u8, 111u8, 117u8,
+                    110u8, 100u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 115u8, 113u8, 117u8,
+                    97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 102u8,
+                    111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 105u8,
+                    110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 107u8,
+                    105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8,
+                    97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8, 115u8, 116u8,
+                    111u8, 110u8, 101u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8, 116u8,
+                    101u8, 114u8, 97u8, 98u8, 121u8, 116u8, 101u8, 119u8, 101u8, 101u8, 107u8,
+                    121u8, 97u8, 114u8, 100u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    64u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 5u8, 0u8, 10u8, 0u8, 13u8, 0u8, 15u8,
+                    0u8, 19u8, 0u8, 23u8, 0u8, 27u8, 0u8, 31u8, 0u8, 34u8, 0u8, 38u8, 0u8, 42u8,
+                    0u8, 43u8, 0u8, 45u8, 0u8, 48u8, 0u8, 53u8, 0u8, 55u8, 0u8, 58u8, 0u8, 62u8,
+                    0u8, 64u8, 0u8, 65u8, 0u8, 67u8, 0u8, 68u8, 0u8, 70u8, 0u8, 74u8, 0u8, 76u8,
+                    0u8, 78u8, 0u8, 80u8, 0u8, 84u8, 0u8, 85u8, 0u8, 89u8, 0u8, 93u8, 0u8, 95u8,
+                    0u8, 96u8, 0u8, 99u8, 0u8, 104u8, 0u8, 107u8, 0u8, 109u8, 0u8, 115u8, 0u8,
+                    119u8, 0u8, 122u8, 0u8, 124u8, 0u8, 126u8, 0u8, 128u8, 0u8, 131u8, 0u8, 133u8,
+                    0u8, 135u8, 0u8, 137u8, 0u8, 138u8, 0u8, 140u8, 0u8, 142u8, 0u8, 143u8, 0u8,
+                    147u8, 0u8, 151u8, 0u8, 155u8, 0u8, 159u8, 0u8, 162u8, 0u8, 166u8, 0u8, 170u8,
+                    0u8, 172u8, 0u8, 176u8, 0u8, 178u8, 0u8, 182u8, 0u8, 97u8, 99u8, 98u8, 105u8,
+                    116u8, 111u8, 99u8, 116u8, 101u8, 116u8, 194u8, 176u8, 67u8, 99u8, 109u8, 99u8,
+                    109u8, 194u8, 179u8, 112u8, 105u8, 194u8, 179u8, 112u8, 111u8, 194u8, 179u8,
+                    107u8, 109u8, 194u8, 179u8, 109u8, 194u8, 179u8, 109u8, 105u8, 194u8, 179u8,
+                    121u8, 100u8, 194u8, 179u8, 106u8, 194u8, 176u8, 194u8, 176u8, 70u8, 102u8,
+                    108u8, 32u8, 111u8, 122u8, 112u8, 105u8, 103u8, 97u8, 108u8, 71u8, 98u8, 105u8,
+                    116u8, 71u8, 111u8, 103u8, 104u8, 97u8, 104u8, 112u8, 111u8, 107u8, 98u8,
+                    105u8, 116u8, 107u8, 111u8, 107u8, 103u8, 107u8, 109u8, 107u8, 109u8, 47u8,
+                    104u8, 108u8, 108u8, 47u8, 107u8, 109u8, 77u8, 98u8, 105u8, 116u8, 77u8, 111u8,
+                    109u8, 109u8, 47u8, 115u8, 109u8, 47u8, 115u8, 194u8, 178u8, 206u8, 188u8,
+                    115u8, 109u8, 105u8, 109u8, 105u8, 47u8, 103u8, 97u8, 108u8, 109u8, 105u8,
+                    47u8, 104u8, 115u8, 109u8, 105u8, 109u8, 108u8, 109u8, 109u8, 109u8, 115u8,
+                    109u8, 105u8, 110u8, 109u8, 46u8, 110u8, 115u8, 111u8, 122u8, 37u8, 80u8,
+                    111u8, 108u8, 98u8, 115u8, 99u8, 109u8, 194u8, 178u8, 112u8, 105u8, 194u8,
+                    178u8, 112u8, 111u8, 194u8, 178u8, 107u8, 109u8, 194u8, 178u8, 109u8, 194u8,
+                    178u8, 109u8, 105u8, 194u8, 178u8, 121u8, 100u8, 194u8, 178u8, 115u8, 116u8,
+                    84u8, 98u8, 105u8, 116u8, 84u8, 111u8, 115u8, 101u8, 109u8, 46u8, 121u8, 100u8,
+                ])
+            },
+        )
+    },
+};
+static JA: &DataStruct = &::icu_displaynames::provider::UnitDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    65u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 7u8, 0u8, 11u8, 0u8, 18u8, 0u8, 28u8,
+                    0u8, 44u8, 0u8, 54u8, 0u8, 64u8, 0u8, 79u8, 0u8, 90u8, 0u8, 100u8, 0u8, 110u8,
+                    0u8, 113u8, 0u8, 119u8, 0u8, 129u8, 0u8, 140u8, 0u8, 144u8, 0u8, 150u8, 0u8,
+                    157u8, 0u8, 165u8, 0u8, 169u8, 0u8, 176u8, 0u8, 180u8, 0u8, 184u8, 0u8, 191u8,
+                    0u8, 199u8, 0u8, 207u8, 0u8, 216u8, 0u8, 234u8, 0u8, 239u8, 0u8, 2u8, 1u8, 9u8,
+                    1u8, 17u8, 1u8, 22u8, 1u8, 38u8, 1u8, 61u8, 1u8, 72u8, 1u8, 76u8, 1u8, 91u8,
+                    1u8, 104u8, 1u8, 121u8, 1u8, 131u8, 1u8, 141u8, 1u8, 152u8, 1u8, 158u8, 1u8,
+                    163u8, 1u8, 173u8, 1u8, 178u8, 1u8, 185u8, 1u8, 193u8, 1u8, 198u8, 1u8, 204u8,
+                    1u8, 221u8, 1u8, 232u8, 1u8, 243u8, 1u8, 3u8, 2u8, 15u8, 2u8, 26u8, 2u8, 37u8,
+                    2u8, 42u8, 2u8, 49u8, 2u8, 57u8, 2u8, 61u8, 2u8, 65u8, 2u8, 97u8, 99u8, 114u8,
+                    101u8, 98u8, 105u8, 116u8, 98u8, 121u8, 116u8, 101u8, 99u8, 101u8, 108u8,
+                    115u8, 105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8, 110u8,
+                    116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 102u8, 111u8, 111u8, 116u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8,
+                    105u8, 110u8, 99u8, 104u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8,
+                    45u8, 109u8, 105u8, 108u8, 101u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 121u8,
+                    97u8, 114u8, 100u8, 100u8, 97u8, 121u8, 100u8, 101u8, 103u8, 114u8, 101u8,
+                    101u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8,
+                    102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8,
+                    102u8, 111u8, 111u8, 116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8,
+                    105u8, 103u8, 97u8, 98u8, 105u8, 116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8,
+                    116u8, 101u8, 103u8, 114u8, 97u8, 109u8, 104u8, 101u8, 99u8, 116u8, 97u8,
+                    114u8, 101u8, 104u8, 111u8, 117u8, 114u8, 105u8, 110u8, 99u8, 104u8, 107u8,
+                    105u8, 108u8, 111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 98u8,
+                    121u8, 116u8, 101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 104u8, 111u8, 117u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8, 97u8,
+                    98u8, 105u8, 116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 108u8, 101u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8,
+                    101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8,
+                    105u8, 108u8, 101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8,
+                    118u8, 105u8, 97u8, 110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 108u8, 105u8,
+                    116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 110u8,
+                    116u8, 104u8, 110u8, 97u8, 110u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8, 101u8, 114u8, 99u8, 101u8,
+                    110u8, 116u8, 112u8, 101u8, 116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 112u8,
+                    111u8, 117u8, 110u8, 100u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8, 115u8,
+                    116u8, 111u8, 110u8, 101u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8,
+                    116u8, 101u8, 114u8, 97u8, 98u8, 121u8, 116u8, 101u8, 119u8, 101u8, 101u8,
+                    107u8, 121u8, 97u8, 114u8, 100u8, 121u8, 101u8, 97u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    65u8, 0u8, 0u8, 0u8, 0u8, 0u8, 12u8, 0u8, 21u8, 0u8, 30u8, 0u8, 36u8, 0u8,
+                    57u8, 0u8, 84u8, 0u8, 102u8, 0u8, 117u8, 0u8, 141u8, 0u8, 159u8, 0u8, 174u8,
+                    0u8, 189u8, 0u8, 192u8, 0u8, 195u8, 0u8, 201u8, 0u8, 216u8, 0u8, 228u8, 0u8,
+                    237u8, 0u8, 252u8, 0u8, 11u8, 1u8, 20u8, 1u8, 35u8, 1u8, 41u8, 1u8, 50u8, 1u8,
+                    65u8, 1u8, 80u8, 1u8, 95u8, 1u8, 113u8, 1u8, 137u8, 1u8, 149u8, 1u8, 182u8,
+                    1u8, 197u8, 1u8, 212u8, 1u8, 224u8, 1u8, 242u8, 1u8, 10u8, 2u8, 25u8, 2u8,
+                    34u8, 2u8, 55u8, 2u8, 70u8, 2u8, 100u8, 2u8, 118u8, 2u8, 136u8, 2u8, 145u8,
+                    2u8, 148u8, 2u8, 154u8, 2u8, 163u8, 2u8, 172u8, 2u8, 187u8, 2u8, 202u8, 2u8,
+                    211u8, 2u8, 214u8, 2u8, 241u8, 2u8, 3u8, 3u8, 18u8, 3u8, 42u8, 3u8, 60u8, 3u8,
+                    75u8, 3u8, 90u8, 3u8, 102u8, 3u8, 117u8, 3u8, 132u8, 3u8, 138u8, 3u8, 147u8,
+                    3u8, 227u8, 130u8, 168u8, 227u8, 131u8, 188u8, 227u8, 130u8, 171u8, 227u8,
+                    131u8, 188u8, 227u8, 131u8, 147u8, 227u8, 131u8, 131u8, 227u8, 131u8, 136u8,
+                    227u8, 131u8, 144u8, 227u8, 130u8, 164u8, 227u8, 131u8, 136u8, 230u8, 145u8,
+                    130u8, 230u8, 176u8, 143u8, 227u8, 130u8, 187u8, 227u8, 131u8, 179u8, 227u8,
+                    131u8, 129u8, 227u8, 131u8, 161u8, 227u8, 131u8, 188u8, 227u8, 131u8, 136u8,
+                    227u8, 131u8, 171u8, 231u8, 171u8, 139u8, 230u8, 150u8, 185u8, 227u8, 130u8,
+                    187u8, 227u8, 131u8, 179u8, 227u8, 131u8, 129u8, 227u8, 131u8, 161u8, 227u8,
+                    131u8, 188u8, 227u8, 131u8, 136u8, 227u8, 131u8, 171u8, 231u8, 171u8, 139u8,
+                    230u8, 150u8, 185u8, 227u8, 131u8, 149u8, 227u8, 130u8, 163u8, 227u8, 131u8,
+                    188u8, 227u8, 131u8, 136u8, 231u8, 171u8, 139u8, 230u8, 150u8, 185u8, 227u8,
+                    130u8, 164u8, 227u8, 131u8, 179u8, 227u8, 131u8, 129u8, 231u8, 171u8, 139u8,
+                    230u8, 150u8, 185u8, 227u8, 130u8, 173u8, 227u8, 131u8, 173u8, 227u8, 131u8,
+                    161u8, 227u8, 131u8, 188u8, 227u8, 131u8, 136u8, 227u8, 131u8, 171u8, 231u8,
+                    171u8, 139u8, 230u8, 150u8, 185u8, 227u8, 131u8, 161u8, 227u8, 131u8, 188u8,
+                    227u8, 131u8, 136u8, 227u8, 131u8, 171u8, 231u8, 171u8, 139u8, 230u8, 150u8,
+                    185u8, 227u8, 131u8, 158u8, 227u8, 130u8, 164u8, 227u8, 131u8, 171u8, 231u8,
+                    171u8, 139u8, 230u8, 150u8, 185u8, 227u8, 131u8, 164u8, 227u8, 131u8, 188u8,
+                    227u8, 131u8, 137u8, 230u8, 151u8, 165u8, 229u8, 186u8, 166u8, 232u8, 143u8,
+                    175u8, 230u8, 176u8, 143u8, 230u8, 182u8, 178u8, 233u8, 135u8, 143u8, 227u8,
+                    130u8, 170u8, 227u8, 131u8, 179u8, 227u8, 130u8, 185u8, 227u8, 131u8, 149u8,
+                    227u8, 130u8, 163u8, 227u8, 131u8, 188u8, 227u8, 131u8, 136u8, 227u8, 130u8,
+                    172u8, 227u8, 131u8, 173u8, 227u8, 131u8, 179u8, 227u8, 130u8, 174u8, 227u8,
+                    130u8, 172u8, 227u8, 131u8, 147u8, 227u8, 131u8, 131u8, 227u8, 131u8, 136u8,
+                    227u8, 130u8, 174u8, 227u8, 130u8, 172u8, 227u8, 131u8, 144u8, 227u8, 130u8,
+                    164u8, 227u8, 131u8, 136u8, 227u8, 130u8, 176u8, 227u8, 131u8, 169u8, 227u8,
+                    131u8, 160u8, 227u8, 131u8, 152u8, 227u8, 130u8, 175u8, 227u8, 130u8, 191u8,
+                    227u8, 131u8, 188u8, 227u8, 131u8, 171u8, 230u8, 153u8, 130u8, 233u8, 150u8,
+                    147u8, 227u8, 130u8, 164u8, 227u8, 131u8, 179u8, 227u8, 131u8, 129u8, 227u8,
+                    130u8, 173u8, 227u8, 131u8, 173u8, 227u8, 131u8, 147u8, 227u8, 131u8, 131u8,
+                    227u8, 131u8, 136u8, 227u8, 130u8, 173u8, 227u8, 131u8, 173u8, 227u8, 131u8,
+                    144u8, 227u8, 130u8, 164u8, 227u8, 131u8, 136u8, 227u8, 130u8, 173u8, 227u8,
+                    131u8, 173u8, 227u8, 130u8, 176u8, 227u8, 131u8, 169u8, 227u8, 131u8, 160u8,
+                    227u8, 130u8, 173u8, 227u8, 131u8, 173u8, 227u8, 131u8, 161u8, 227u8, 131u8,
+                    188u8, 227u8, 131u8, 136u8, 227u8, 131u8, 171u8, 227u8, 130u8, 173u8, 227u8,
+                    131u8, 173u8, 227u8, 131u8, 161u8, 227u8, 131u8, 188u8, 227u8, 131u8, 136u8,
+                    227u8, 131u8, 171u8, 230u8, 175u8, 142u8, 230u8, 153u8, 130u8, 227u8, 131u8,
+                    170u8, 227u8, 131u8, 131u8, 227u8, 131u8, 136u8, 227u8, 131u8, 171u8, 227u8,
+                    131u8, 170u8, 227u8, 131u8, 131u8, 227u8, 131u8, 136u8, 227u8, 131u8, 171u8,
+                    230u8, 175u8, 142u8, 227u8, 130u8, 173u8, 227u8, 131u8, 173u8, 227u8, 131u8,
+                    161u8, 227u8, 131u8, 188u8, 227u8, 131u8, 136u8, 227u8, 131u8, 171u8, 227u8,
+                    131u8, 161u8, 227u8, 130u8, 172u8, 227u8, 131u8, 147u8, 227u8, 131u8, 131u8,
+                    227u8, 131u8, 136u8, 227u8, 131u8, 161u8, 227u8, 130u8, 172u8, 227u8, 131u8,
+                    144u8, 227u8, 130u8, 164u8, 227u8, 131u8, 136u8, 227u8, 131u8, 161u8, 227u8,
+                    131u8, 188u8, 227u8, 131u8, 136u8, 227u8, 131u8, 171u8, 227u8, 131u8, 161u8,
+                    227u8, 131u8, 188u8, 227u8, 131u8, 136u8, 227u8, 131u8, 171u8, 230u8, 175u8,
+                    142u8, 231u8, 167u8, 146u8, 227u8, 131u8, 161u8, 227u8, 131u8, 188u8, 227u8,
+                    131u8, 136u8, 227u8, 131u8, 171u8, 230u8, 175u8, 142u8, 231u8, 167u8, 146u8,
+                    230u8, 175u8, 142u8, 231u8, 167u8, 146u8, 227u8, 131u8, 158u8, 227u8, 130u8,
+                    164u8, 227u8, 130u8, 175u8, 227u8, 131u8, 173u8, 231u8, 167u8, 146u8, 227u8,
+                    131u8, 158u8, 227u8, 130u8, 164u8, 227u8, 131u8, 171u8, 227u8, 131u8, 158u8,
+                    227u8, 130u8, 164u8, 227u8, 131u8, 171u8, 230u8, 175u8, 142u8, 227u8, 130u8,
+                    172u8, 227u8, 131u8, 173u8, 227u8, 131u8, 179u8, 227u8, 131u8, 158u8, 227u8,
+                    130u8, 164u8, 227u8, 131u8, 171u8, 230u8, 175u8, 142u8, 230u8, 153u8, 130u8,
+                    227u8, 130u8, 185u8, 227u8, 130u8, 171u8, 227u8, 131u8, 179u8, 227u8, 130u8,
+                    184u8, 227u8, 131u8, 138u8, 227u8, 131u8, 147u8, 227u8, 130u8, 162u8, 227u8,
+                    131u8, 158u8, 227u8, 130u8, 164u8, 227u8, 131u8, 171u8, 227u8, 131u8, 159u8,
+                    227u8, 131u8, 170u8, 227u8, 131u8, 170u8, 227u8, 131u8, 131u8, 227u8, 131u8,
+                    136u8, 227u8, 131u8, 171u8, 227u8, 131u8, 159u8, 227u8, 131u8, 170u8, 227u8,
+                    131u8, 161u8, 227u8, 131u8, 188u8, 227u8, 131u8, 136u8, 227u8, 131u8, 171u8,
+                    227u8, 131u8, 159u8, 227u8, 131u8, 170u8, 231u8, 167u8, 146u8, 229u8, 136u8,
+                    134u8, 227u8, 129u8, 139u8, 230u8, 156u8, 136u8, 227u8, 131u8, 138u8, 227u8,
+                    131u8, 142u8, 231u8, 167u8, 146u8, 227u8, 130u8, 170u8, 227u8, 131u8, 179u8,
+                    227u8, 130u8, 185u8, 227u8, 131u8, 145u8, 227u8, 131u8, 188u8, 227u8, 130u8,
+                    187u8, 227u8, 131u8, 179u8, 227u8, 131u8, 136u8, 227u8, 131u8, 154u8, 227u8,
+                    130u8, 191u8, 227u8, 131u8, 144u8, 227u8, 130u8, 164u8, 227u8, 131u8, 136u8,
+                    227u8, 131u8, 157u8, 227u8, 131u8, 179u8, 227u8, 131u8, 137u8, 231u8, 167u8,
+                    146u8, 229u8, 185u8, 179u8, 230u8, 150u8, 185u8, 227u8, 130u8, 187u8, 227u8,
+                    131u8, 179u8, 227u8, 131u8, 129u8, 227u8, 131u8, 161u8, 227u8, 131u8, 188u8,
+                    227u8, 131u8, 136u8, 227u8, 131u8, 171u8, 229u8, 185u8, 179u8, 230u8, 150u8,
+                    185u8, 227u8, 131u8, 149u8, 227u8, 130u8, 163u8, 227u8, 131u8, 188u8, 227u8,
+                    131u8, 136u8, 229u8, 185u8, 179u8, 230u8, 150u8, 185u8, 227u8, 130u8, 164u8,
+                    227u8, 131u8, 179u8, 227u8, 131u8, 129u8, 229u8, 185u8, 179u8, 230u8, 150u8,
+                    185u8, 227u8, 130u8, 173u8, 227u8, 131u8, 173u8, 227u8, 131u8, 161u8, 227u8,
+                    131u8, 188u8, 227u8, 131u8, 136u8, 227u8, 131u8, 171u8, 229u8, 185u8, 179u8,
+                    230u8, 150u8, 185u8, 227u8, 131u8, 161u8, 227u8, 131u8, 188u8, 227u8, 131u8,
+                    136u8, 227u8, 131u8, 171u8, 229u8, 185u8, 179u8, 230u8, 150u8, 185u8, 227u8,
+                    131u8, 158u8, 227u8, 130u8, 164u8, 227u8, 131u8, 171u8, 229u8, 185u8, 179u8,
+                    230u8, 150u8, 185u8, 227u8, 131u8, 164u8, 227u8, 131u8, 188u8, 227u8, 131u8,
+                    137u8, 227u8, 130u8, 185u8, 227u8, 131u8, 136u8, 227u8, 131u8, 188u8, 227u8,
+                    131u8, 179u8, 227u8, 131u8, 134u8, 227u8, 131u8, 169u8, 227u8, 131u8, 147u8,
+                    227u8, 131u8, 131u8, 227u8, 131u8, 136u8, 227u8, 131u8, 134u8, 227u8, 131u8,
+                    169u8, 227u8, 131u8, 144u8, 227u8, 130u8, 164u8, 227u8, 131u8, 136u8, 233u8,
+                    128u8, 177u8, 233u8, 150u8, 147u8, 227u8, 131u8, 164u8, 227u8, 131u8, 188u8,
+                    227u8, 131u8, 137u8, 229u8, 185u8, 180u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    21u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 17u8, 0u8, 33u8, 0u8, 48u8, 0u8, 59u8,
+                    0u8, 69u8, 0u8, 77u8, 0u8, 85u8, 0u8, 93u8, 0u8, 102u8, 0u8, 120u8, 0u8, 139u8,
+                    0u8, 147u8, 0u8, 152u8, 0u8, 168u8, 0u8, 191u8, 0u8, 206u8, 0u8, 216u8, 0u8,
+                    233u8, 0u8, 249u8, 0u8, 99u8, 101u8, 108u8, 115u8, 105u8, 117u8, 115u8, 99u8,
+                    101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8,
+                    98u8, 105u8, 99u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 107u8, 105u8, 108u8, 111u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8,
+                    101u8, 105u8, 116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8,
+                    107u8, 105u8, 108u8, 111u8, 98u8, 121u8, 116u8, 101u8, 107u8, 105u8, 108u8,
+                    111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8, 97u8,
+                    98u8, 121u8, 116u8, 101u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8,
+                    111u8, 110u8, 100u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8,
+                    111u8, 110u8, 100u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8, 108u8,
+                    105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8,
+                    101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8,
+                    101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    21u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 5u8, 0u8, 9u8, 0u8, 13u8, 0u8, 16u8,
+                    0u8, 19u8, 0u8, 21u8, 0u8, 23u8, 0u8, 25u8, 0u8, 27u8, 0u8, 31u8, 0u8, 35u8,
+                    0u8, 37u8, 0u8, 38u8, 0u8, 41u8, 0u8, 46u8, 0u8, 65u8, 0u8, 67u8, 0u8, 71u8,
+                    0u8, 75u8, 0u8, 194u8, 176u8, 67u8, 99u8, 109u8, 99u8, 109u8, 194u8, 179u8,
+                    107u8, 109u8, 194u8, 179u8, 109u8, 194u8, 179u8, 194u8, 176u8, 70u8, 71u8,
+                    66u8, 75u8, 66u8, 107u8, 103u8, 107u8, 109u8, 107u8, 109u8, 47u8, 104u8, 76u8,
+                    47u8, 107u8, 109u8, 77u8, 66u8, 109u8, 109u8, 47u8, 115u8, 109u8, 47u8, 115u8,
+                    194u8, 178u8, 227u8, 131u8, 158u8, 227u8, 130u8, 164u8, 227u8, 131u8, 171u8,
+                    47u8, 227u8, 130u8, 172u8, 227u8, 131u8, 173u8, 227u8, 131u8, 179u8, 109u8,
+                    109u8, 99u8, 109u8, 194u8, 178u8, 107u8, 109u8, 194u8, 178u8, 109u8, 194u8,
+                    178u8,
+                ])
+            },
+        )
+    },
+};
+static RU: &DataStruct = &::icu_displaynames::provider::UnitDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    65u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 7u8, 0u8, 11u8, 0u8, 18u8, 0u8, 28u8,
+                    0u8, 44u8, 0u8, 54u8, 0u8, 64u8, 0u8, 79u8, 0u8, 90u8, 0u8, 100u8, 0u8, 110u8,
+                    0u8, 113u8, 0u8, 119u8, 0u8, 129u8, 0u8, 140u8, 0u8, 144u8, 0u8, 150u8, 0u8,
+                    157u8, 0u8, 165u8, 0u8, 169u8, 0u8, 176u8, 0u8, 180u8, 0u8, 184u8, 0u8, 191u8,
+                    0u8, 199u8, 0u8, 207u8, 0u8, 216u8, 0u8, 234u8, 0u8, 239u8, 0u8, 2u8, 1u8, 9u8,
+                    1u8, 17u8, 1u8, 22u8, 1u8, 38u8, 1u8, 61u8, 1u8, 72u8, 1u8, 76u8, 1u8, 91u8,
+                    1u8, 104u8, 1u8, 121u8, 1u8, 131u8, 1u8, 141u8, 1u8, 152u8, 1u8, 158u8, 1u8,
+                    163u8, 1u8, 173u8, 1u8, 178u8, 1u8, 185u8, 1u8, 193u8, 1u8, 198u8, 1u8, 204u8,
+                    1u8, 221u8, 1u8, 232u8, 1u8, 243u8, 1u8, 3u8, 2u8, 15u8, 2u8, 26u8, 2u8, 37u8,
+                    2u8, 42u8, 2u8, 49u8, 2u8, 57u8, 2u8, 61u8, 2u8, 65u8, 2u8, 97u8, 99u8, 114u8,
+                    101u8, 98u8, 105u8, 116u8, 98u8, 121u8, 116u8, 101u8, 99u8, 101u8, 108u8,
+                    115u8, 105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8, 110u8,
+                    116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 102u8, 111u8, 111u8, 116u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8,
+                    105u8, 110u8, 99u8, 104u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8,
+                    45u8, 109u8, 105u8, 108u8, 101u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 121u8,
+                    97u8, 114u8, 100u8, 100u8, 97u8, 121u8, 100u8, 101u8, 103u8, 114u8, 101u8,
+                    101u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8,
+                    102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8,
+                    102u8, 111u8, 111u8, 116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8,
+                    105u8, 103u8, 97u8, 98u8, 105u8, 116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8,
+                    116u8, 101u8, 103u8, 114u8, 97u8, 109u8, 104u8, 101u8, 99u8, 116u8, 97u8,
+                    114u8, 101u8, 104u8, 111u8, 117u8, 114u8, 105u8, 110u8, 99u8, 104u8, 107u8,
+                    105u8, 108u8, 111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 98u8,
+                    121u8, 116u8, 101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 104u8, 111u8, 117u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8, 97u8,
+                    98u8, 105u8, 116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 108u8, 101u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8,
+                    101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8,
+                    105u8, 108u8, 101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8,
+                    118u8, 105u8, 97u8, 110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 108u8, 105u8,
+                    116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 110u8,
+                    116u8, 104u8, 110u8, 97u8, 110u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8, 101u8, 114u8, 99u8, 101u8,
+                    110u8, 116u8, 112u8, 101u8, 116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 112u8,
+                    111u8, 117u8, 110u8, 100u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8, 115u8,
+                    116u8, 111u8, 110u8, 101u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8,
+                    116u8, 101u8, 114u8, 97u8, 98u8, 121u8, 116u8, 101u8, 119u8, 101u8, 101u8,
+                    107u8, 121u8, 97u8, 114u8, 100u8, 121u8, 101u8, 97u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    65u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 16u8, 0u8, 26u8, 0u8, 55u8, 0u8, 75u8,
+                    0u8, 116u8, 0u8, 145u8, 0u8, 176u8, 0u8, 215u8, 0u8, 246u8, 0u8, 19u8, 1u8,
+                    48u8, 1u8, 54u8, 1u8, 68u8, 1u8, 103u8, 1u8, 136u8, 1u8, 144u8, 1u8, 168u8,
+                    1u8, 184u8, 1u8, 202u8, 1u8, 214u8, 1u8, 228u8, 1u8, 236u8, 1u8, 246u8, 1u8,
+                    6u8, 2u8, 24u8, 2u8, 44u8, 2u8, 62u8, 2u8, 90u8, 2u8, 100u8, 2u8, 132u8, 2u8,
+                    148u8, 2u8, 166u8, 2u8, 176u8, 2u8, 204u8, 2u8, 254u8, 2u8, 22u8, 3u8, 30u8,
+                    3u8, 66u8, 3u8, 84u8, 3u8, 119u8, 3u8, 139u8, 3u8, 159u8, 3u8, 183u8, 3u8,
+                    195u8, 3u8, 207u8, 3u8, 229u8, 3u8, 239u8, 3u8, 255u8, 3u8, 17u8, 4u8, 27u8,
+                    4u8, 41u8, 4u8, 82u8, 4u8, 111u8, 4u8, 142u8, 4u8, 181u8, 4u8, 212u8, 4u8,
+                    241u8, 4u8, 14u8, 5u8, 26u8, 5u8, 42u8, 5u8, 60u8, 5u8, 72u8, 5u8, 80u8, 5u8,
+                    208u8, 176u8, 208u8, 186u8, 209u8, 128u8, 209u8, 139u8, 208u8, 177u8, 208u8,
+                    184u8, 209u8, 130u8, 209u8, 139u8, 208u8, 177u8, 208u8, 176u8, 208u8, 185u8,
+                    209u8, 130u8, 209u8, 139u8, 208u8, 179u8, 209u8, 128u8, 208u8, 176u8, 208u8,
+                    180u8, 209u8, 131u8, 209u8, 129u8, 209u8, 139u8, 32u8, 208u8, 166u8, 208u8,
+                    181u8, 208u8, 187u8, 209u8, 140u8, 209u8, 129u8, 208u8, 184u8, 209u8, 143u8,
+                    209u8, 129u8, 208u8, 176u8, 208u8, 189u8, 209u8, 130u8, 208u8, 184u8, 208u8,
+                    188u8, 208u8, 181u8, 209u8, 130u8, 209u8, 128u8, 209u8, 139u8, 208u8, 186u8,
+                    209u8, 131u8, 208u8, 177u8, 208u8, 184u8, 209u8, 135u8, 208u8, 181u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 181u8, 32u8, 209u8, 129u8, 208u8,
+                    176u8, 208u8, 189u8, 209u8, 130u8, 208u8, 184u8, 208u8, 188u8, 208u8, 181u8,
+                    209u8, 130u8, 209u8, 128u8, 209u8, 139u8, 208u8, 186u8, 209u8, 131u8, 208u8,
+                    177u8, 208u8, 184u8, 209u8, 135u8, 208u8, 181u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 184u8, 208u8, 181u8, 32u8, 209u8, 132u8, 209u8, 131u8, 209u8, 130u8,
+                    209u8, 139u8, 208u8, 186u8, 209u8, 131u8, 208u8, 177u8, 208u8, 184u8, 209u8,
+                    135u8, 208u8, 181u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 181u8,
+                    32u8, 208u8, 180u8, 209u8, 142u8, 208u8, 185u8, 208u8, 188u8, 209u8, 139u8,
+                    208u8, 186u8, 209u8, 131u8, 208u8, 177u8, 208u8, 184u8, 209u8, 135u8, 208u8,
+                    181u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 181u8, 32u8, 208u8,
+                    186u8, 208u8, 184u8, 208u8, 187u8, 208u8, 190u8, 208u8, 188u8, 208u8, 181u8,
+                    209u8, 130u8, 209u8, 128u8, 209u8, 139u8, 208u8, 186u8, 209u8, 131u8, 208u8,
+                    177u8, 208u8, 184u8, 209u8, 135u8, 208u8, 181u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 184u8, 208u8, 181u8, 32u8, 208u8, 188u8, 208u8, 181u8, 209u8, 130u8,
+                    209u8, 128u8, 209u8, 139u8, 208u8, 186u8, 209u8, 131u8, 208u8, 177u8, 208u8,
+                    184u8, 209u8, 135u8, 208u8, 181u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8,
+                    208u8, 181u8, 32u8, 208u8, 188u8, 208u8, 184u8, 208u8, 187u8, 208u8, 184u8,
+                    208u8, 186u8, 209u8, 131u8, 208u8, 177u8, 208u8, 184u8, 209u8, 135u8, 208u8,
+                    181u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 181u8, 32u8, 209u8,
+                    143u8, 209u8, 128u8, 208u8, 180u8, 209u8, 139u8, 208u8, 180u8, 208u8, 189u8,
+                    208u8, 184u8, 208u8, 179u8, 209u8, 128u8, 208u8, 176u8, 208u8, 180u8, 209u8,
+                    131u8, 209u8, 129u8, 209u8, 139u8, 208u8, 179u8, 209u8, 128u8, 208u8, 176u8,
+                    208u8, 180u8, 209u8, 131u8, 209u8, 129u8, 209u8, 139u8, 32u8, 208u8, 164u8,
+                    208u8, 176u8, 209u8, 128u8, 208u8, 181u8, 208u8, 189u8, 208u8, 179u8, 208u8,
+                    181u8, 208u8, 185u8, 209u8, 130u8, 208u8, 176u8, 208u8, 176u8, 208u8, 188u8,
+                    208u8, 181u8, 209u8, 128u8, 46u8, 32u8, 208u8, 182u8, 208u8, 184u8, 208u8,
+                    180u8, 208u8, 186u8, 208u8, 184u8, 208u8, 181u8, 32u8, 209u8, 131u8, 208u8,
+                    189u8, 209u8, 134u8, 208u8, 184u8, 208u8, 184u8, 209u8, 132u8, 209u8, 131u8,
+                    209u8, 130u8, 209u8, 139u8, 208u8, 176u8, 208u8, 188u8, 208u8, 181u8, 209u8,
+                    128u8, 46u8, 32u8, 208u8, 179u8, 208u8, 176u8, 208u8, 187u8, 208u8, 187u8,
+                    208u8, 190u8, 208u8, 189u8, 209u8, 139u8, 208u8, 179u8, 208u8, 184u8, 208u8,
+                    179u8, 208u8, 176u8, 208u8, 177u8, 208u8, 184u8, 209u8, 130u8, 209u8, 139u8,
+                    208u8, 179u8, 208u8, 184u8, 208u8, 179u8, 208u8, 176u8, 208u8, 177u8, 208u8,
+                    176u8, 208u8, 185u8, 209u8, 130u8, 209u8, 139u8, 208u8, 179u8, 209u8, 128u8,
+                    208u8, 176u8, 208u8, 188u8, 208u8, 188u8, 209u8, 139u8, 208u8, 179u8, 208u8,
+                    181u8, 208u8, 186u8, 209u8, 130u8, 208u8, 176u8, 209u8, 128u8, 209u8, 139u8,
+                    209u8, 135u8, 208u8, 176u8, 209u8, 129u8, 209u8, 139u8, 208u8, 180u8, 209u8,
+                    142u8, 208u8, 185u8, 208u8, 188u8, 209u8, 139u8, 208u8, 186u8, 208u8, 184u8,
+                    208u8, 187u8, 208u8, 190u8, 208u8, 177u8, 208u8, 184u8, 209u8, 130u8, 209u8,
+                    139u8, 208u8, 186u8, 208u8, 184u8, 208u8, 187u8, 208u8, 190u8, 208u8, 177u8,
+                    208u8, 176u8, 208u8, 185u8, 209u8, 130u8, 209u8, 139u8, 208u8, 186u8, 208u8,
+                    184u8, 208u8, 187u8, 208u8, 190u8, 208u8, 179u8, 209u8, 128u8, 208u8, 176u8,
+                    208u8, 188u8, 208u8, 188u8, 209u8, 139u8, 208u8, 186u8, 208u8, 184u8, 208u8,
+                    187u8, 208u8, 190u8, 208u8, 188u8, 208u8, 181u8, 209u8, 130u8, 209u8, 128u8,
+                    209u8, 139u8, 208u8, 186u8, 208u8, 184u8, 208u8, 187u8, 208u8, 190u8, 208u8,
+                    188u8, 208u8, 181u8, 209u8, 130u8, 209u8, 128u8, 209u8, 139u8, 32u8, 208u8,
+                    178u8, 32u8, 209u8, 135u8, 208u8, 176u8, 209u8, 129u8, 208u8, 187u8, 208u8,
+                    184u8, 209u8, 130u8, 209u8, 128u8, 209u8, 139u8, 208u8, 187u8, 208u8, 184u8,
+                    209u8, 130u8, 209u8, 128u8, 209u8, 139u8, 32u8, 208u8, 189u8, 208u8, 176u8,
+                    32u8, 208u8, 186u8, 208u8, 184u8, 208u8, 187u8, 208u8, 190u8, 208u8, 188u8,
+                    208u8, 181u8, 209u8, 130u8, 209u8, 128u8, 208u8, 188u8, 208u8, 181u8, 208u8,
+                    179u8, 208u8, 176u8, 208u8, 177u8, 208u8, 184u8, 209u8, 130u8, 209u8, 139u8,
+                    208u8, 188u8, 208u8, 181u8, 208u8, 179u8, 208u8, 176u8, 208u8, 177u8, 208u8,
+                    176u8, 208u8, 185u8, 209u8, 130u8, 209u8, 139u8, 208u8, 188u8, 208u8, 181u8,
+                    209u8, 130u8, 209u8, 128u8, 209u8, 139u8, 208u8, 188u8, 208u8, 181u8, 209u8,
+                    130u8, 209u8, 128u8, 209u8, 139u8, 32u8, 208u8, 178u8, 32u8, 209u8, 129u8,
+                    208u8, 181u8, 208u8, 186u8, 209u8, 131u8, 208u8, 189u8, 208u8, 180u8, 209u8,
+                    131u8, 208u8, 188u8, 208u8, 181u8, 209u8, 130u8, 209u8, 128u8, 209u8, 139u8,
+                    32u8, 208u8, 189u8, 208u8, 176u8, 32u8, 209u8, 129u8, 208u8, 181u8, 208u8,
+                    186u8, 209u8, 131u8, 208u8, 189u8, 208u8, 180u8, 209u8, 131u8, 32u8, 208u8,
+                    178u8, 32u8, 208u8, 186u8, 208u8, 178u8, 208u8, 176u8, 208u8, 180u8, 209u8,
+                    128u8, 208u8, 176u8, 209u8, 130u8, 208u8, 181u8, 208u8, 188u8, 208u8, 184u8,
+                    208u8, 186u8, 209u8, 128u8, 208u8, 190u8, 209u8, 129u8, 208u8, 181u8, 208u8,
+                    186u8, 209u8, 131u8, 208u8, 189u8, 208u8, 180u8, 209u8, 139u8, 208u8, 188u8,
+                    208u8, 184u8, 208u8, 187u8, 208u8, 184u8, 208u8, 188u8, 208u8, 184u8, 208u8,
+                    187u8, 208u8, 184u8, 32u8, 208u8, 189u8, 208u8, 176u8, 32u8, 208u8, 176u8,
+                    208u8, 188u8, 208u8, 181u8, 209u8, 128u8, 46u8, 32u8, 208u8, 179u8, 208u8,
+                    176u8, 208u8, 187u8, 208u8, 187u8, 208u8, 190u8, 208u8, 189u8, 208u8, 188u8,
+                    208u8, 184u8, 208u8, 187u8, 208u8, 184u8, 32u8, 208u8, 178u8, 32u8, 209u8,
+                    135u8, 208u8, 176u8, 209u8, 129u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8,
+                    208u8, 189u8, 208u8, 180u8, 208u8, 184u8, 208u8, 189u8, 208u8, 176u8, 208u8,
+                    178u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 181u8, 32u8, 208u8,
+                    188u8, 208u8, 184u8, 208u8, 187u8, 208u8, 184u8, 208u8, 188u8, 208u8, 184u8,
+                    208u8, 187u8, 208u8, 187u8, 208u8, 184u8, 208u8, 187u8, 208u8, 184u8, 209u8,
+                    130u8, 209u8, 128u8, 209u8, 139u8, 208u8, 188u8, 208u8, 184u8, 208u8, 187u8,
+                    208u8, 187u8, 208u8, 184u8, 208u8, 188u8, 208u8, 181u8, 209u8, 130u8, 209u8,
+                    128u8, 209u8, 139u8, 208u8, 188u8, 208u8, 184u8, 208u8, 187u8, 208u8, 187u8,
+                    208u8, 184u8, 209u8, 129u8, 208u8, 181u8, 208u8, 186u8, 209u8, 131u8, 208u8,
+                    189u8, 208u8, 180u8, 209u8, 139u8, 208u8, 188u8, 208u8, 184u8, 208u8, 189u8,
+                    209u8, 131u8, 209u8, 130u8, 209u8, 139u8, 208u8, 188u8, 208u8, 181u8, 209u8,
+                    129u8, 209u8, 143u8, 209u8, 134u8, 209u8, 139u8, 208u8, 189u8, 208u8, 176u8,
+                    208u8, 189u8, 208u8, 190u8, 209u8, 129u8, 208u8, 181u8, 208u8, 186u8, 209u8,
+                    131u8, 208u8, 189u8, 208u8, 180u8, 209u8, 139u8, 209u8, 131u8, 208u8, 189u8,
+                    209u8, 134u8, 208u8, 184u8, 208u8, 184u8, 208u8, 191u8, 209u8, 128u8, 208u8,
+                    190u8, 209u8, 134u8, 208u8, 181u8, 208u8, 189u8, 209u8, 130u8, 209u8, 139u8,
+                    208u8, 191u8, 208u8, 181u8, 209u8, 130u8, 208u8, 176u8, 208u8, 177u8, 208u8,
+                    176u8, 208u8, 185u8, 209u8, 130u8, 209u8, 139u8, 209u8, 132u8, 209u8, 131u8,
+                    208u8, 189u8, 209u8, 130u8, 209u8, 139u8, 209u8, 129u8, 208u8, 181u8, 208u8,
+                    186u8, 209u8, 131u8, 208u8, 189u8, 208u8, 180u8, 209u8, 139u8, 208u8, 186u8,
+                    208u8, 178u8, 208u8, 176u8, 208u8, 180u8, 209u8, 128u8, 208u8, 176u8, 209u8,
+                    130u8, 208u8, 189u8, 209u8, 139u8, 208u8, 181u8, 32u8, 209u8, 129u8, 208u8,
+                    176u8, 208u8, 189u8, 209u8, 130u8, 208u8, 184u8, 208u8, 188u8, 208u8, 181u8,
+                    209u8, 130u8, 209u8, 128u8, 209u8, 139u8, 208u8, 186u8, 208u8, 178u8, 208u8,
+                    176u8, 208u8, 180u8, 209u8, 128u8, 208u8, 176u8, 209u8, 130u8, 208u8, 189u8,
+                    209u8, 139u8, 208u8, 181u8, 32u8, 209u8, 132u8, 209u8, 131u8, 209u8, 130u8,
+                    209u8, 139u8, 208u8, 186u8, 208u8, 178u8, 208u8, 176u8, 208u8, 180u8, 209u8,
+                    128u8, 208u8, 176u8, 209u8, 130u8, 208u8, 189u8, 209u8, 139u8, 208u8, 181u8,
+                    32u8, 208u8, 180u8, 209u8, 142u8, 208u8, 185u8, 208u8, 188u8, 209u8, 139u8,
+                    208u8, 186u8, 208u8, 178u8, 208u8, 176u8, 208u8, 180u8, 209u8, 128u8, 208u8,
+                    176u8, 209u8, 130u8, 208u8, 189u8, 209u8, 139u8, 208u8, 181u8, 32u8, 208u8,
+                    186u8, 208u8, 184u8, 208u8, 187u8, 208u8, 190u8, 208u8, 188u8, 208u8, 181u8,
+                    209u8, 130u8, 209u8, 128u8, 209u8, 139u8, 208u8, 186u8, 208u8, 178u8, 208u8,
+                    176u8, 208u8, 180u8, 209u8, 128u8, 208u8, 176u8, 209u8, 130u8, 208u8, 189u8,
+                    209u8, 139u8, 208u8, 181u8, 32u8, 208u8, 188u8, 208u8, 181u8, 209u8, 130u8,
+                    209u8, 128u8, 209u8, 139u8, 208u8, 186u8, 208u8, 178u8, 208u8, 176u8, 208u8,
+                    180u8, 209u8, 128u8, 208u8, 176u8, 209u8, 130u8, 208u8, 189u8, 209u8, 139u8,
+                    208u8, 181u8, 32u8, 208u8, 188u8, 208u8, 184u8, 208u8, 187u8, 208u8, 184u8,
+                    208u8, 186u8, 208u8, 178u8, 208u8, 176u8, 208u8, 180u8, 209u8, 128u8, 208u8,
+                    176u8, 209u8, 130u8, 208u8, 189u8, 209u8, 139u8, 208u8, 181u8, 32u8, 209u8,
+                    143u8, 209u8, 128u8, 208u8, 180u8, 209u8, 139u8, 209u8, 129u8, 209u8, 130u8,
+                    208u8, 190u8, 209u8, 131u8, 208u8, 189u8, 209u8, 139u8, 209u8, 130u8, 208u8,
+                    181u8, 209u8, 128u8, 208u8, 176u8, 208u8, 177u8, 208u8, 184u8, 209u8, 130u8,
+                    209u8, 139u8, 209u8, 130u8, 208u8, 181u8, 209u8, 128u8, 208u8, 176u8, 208u8,
+                    177u8, 208u8, 176u8, 208u8, 185u8, 209u8, 130u8, 209u8, 139u8, 208u8, 189u8,
+                    208u8, 181u8, 208u8, 180u8, 208u8, 181u8, 208u8, 187u8, 208u8, 184u8, 209u8,
+                    143u8, 209u8, 128u8, 208u8, 180u8, 209u8, 139u8, 208u8, 179u8, 208u8, 190u8,
+                    208u8, 180u8, 209u8, 139u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    64u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 7u8, 0u8, 11u8, 0u8, 18u8, 0u8, 28u8,
+                    0u8, 44u8, 0u8, 54u8, 0u8, 64u8, 0u8, 79u8, 0u8, 90u8, 0u8, 100u8, 0u8, 110u8,
+                    0u8, 113u8, 0u8, 119u8, 0u8, 129u8, 0u8, 140u8, 0u8, 144u8, 0u8, 150u8, 0u8,
+                    157u8, 0u8, 165u8, 0u8, 169u8, 0u8, 176u8, 0u8, 180u8, 0u8, 184u8, 0u8, 191u8,
+                    0u8, 199u8, 0u8, 207u8, 0u8, 216u8, 0u8, 234u8, 0u8, 239u8, 0u8, 2u8, 1u8, 9u8,
+                    1u8, 17u8, 1u8, 22u8, 1u8, 38u8, 1u8, 61u8, 1u8, 72u8, 1u8, 76u8, 1u8, 91u8,
+                    1u8, 104u8, 1u8, 121u8, 1u8, 131u8, 1u8, 141u8, 1u8, 152u8, 1u8, 158u8, 1u8,
+                    163u8, 1u8, 173u8, 1u8, 178u8, 1u8, 185u8, 1u8, 193u8, 1u8, 198u8, 1u8, 204u8,
+                    1u8, 221u8, 1u8, 232u8, 1u8, 243u8, 1u8, 3u8, 2u8, 15u8, 2u8, 26u8, 2u8, 37u8,
+                    2u8, 44u8, 2u8, 52u8, 2u8, 56u8, 2u8, 60u8, 2u8, 97u8, 99u8, 114u8, 101u8,
+                    98u8, 105u8, 116u8, 98u8, 121u8, 116u8, 101u8, 99u8, 101u8, 108u8, 115u8,
+                    105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8, 110u8, 116u8,
+                    105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8,
+                    102u8, 111u8, 111u8, 116u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 105u8, 110u8,
+                    99u8, 104u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 107u8, 105u8, 108u8, 111u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 105u8,
+                    108u8, 101u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 121u8, 97u8, 114u8, 100u8,
+                    100u8, 97u8, 121u8, 100u8, 101u8, 103u8, 114u8, 101u8, 101u8, 102u8, 97u8,
+                    104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8, 102u8, 108u8, 117u8,
+                    105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 102u8, 111u8, 111u8,
+                    116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8, 105u8, 103u8, 97u8,
+                    98u8, 105u8, 116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8,
+                    103u8, 114u8, 97u8, 109u8, 104u8, 101u8, 99u8, 116u8, 97u8, 114u8, 101u8,
+                    104u8, 111u8, 117u8, 114u8, 105u8, 110u8, 99u8, 104u8, 107u8, 105u8, 108u8,
+                    111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 98u8, 121u8, 116u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8,
+                    111u8, 117u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 108u8, 105u8, 116u8,
+                    101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8, 108u8, 111u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8, 97u8, 98u8, 105u8,
+                    116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    108u8, 101u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8, 101u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8, 105u8, 108u8,
+                    101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8, 118u8, 105u8,
+                    97u8, 110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 108u8, 105u8, 116u8, 101u8,
+                    114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    109u8, 105u8, 108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 110u8, 116u8, 104u8,
+                    110u8, 97u8, 110u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 111u8,
+                    117u8, 110u8, 99u8, 101u8, 112u8, 101u8, 114u8, 99u8, 101u8, 110u8, 116u8,
+                    112u8, 101u8, 116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 112u8, 111u8, 117u8,
+                    110u8, 100u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 115u8, 113u8, 117u8,
+                    97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 102u8,
+                    111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 105u8,
+                    110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 107u8,
+                    105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8,
+                    97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8, 116u8, 101u8,
+                    114u8, 97u8, 98u8, 105u8, 116u8, 116u8, 101u8, 114u8, 97u8, 98u8, 121u8, 116u8,
+                    101u8, 119u8, 101u8, 101u8, 107u8, 121u8, 97u8, 114u8, 100u8, 121u8, 101u8,
+                    97u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    64u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 13u8, 0u8, 15u8, 0u8, 18u8, 0u8, 22u8,
+                    0u8, 28u8, 0u8, 34u8, 0u8, 44u8, 0u8, 50u8, 0u8, 54u8, 0u8, 60u8, 0u8, 68u8,
+                    0u8, 72u8, 0u8, 74u8, 0u8, 77u8, 0u8, 100u8, 0u8, 104u8, 0u8, 117u8, 0u8,
+                    125u8, 0u8, 129u8, 0u8, 131u8, 0u8, 135u8, 0u8, 137u8, 0u8, 146u8, 0u8, 154u8,
+                    0u8, 158u8, 0u8, 162u8, 0u8, 166u8, 0u8, 173u8, 0u8, 175u8, 0u8, 182u8, 0u8,
+                    190u8, 0u8, 194u8, 0u8, 196u8, 0u8, 201u8, 0u8, 208u8, 0u8, 214u8, 0u8, 218u8,
+                    0u8, 235u8, 0u8, 242u8, 0u8, 252u8, 0u8, 0u8, 1u8, 4u8, 1u8, 8u8, 1u8, 14u8,
+                    1u8, 21u8, 1u8, 25u8, 1u8, 32u8, 1u8, 33u8, 1u8, 37u8, 1u8, 43u8, 1u8, 45u8,
+                    1u8, 51u8, 1u8, 57u8, 1u8, 67u8, 1u8, 73u8, 1u8, 77u8, 1u8, 83u8, 1u8, 91u8,
+                    1u8, 99u8, 1u8, 103u8, 1u8, 110u8, 1u8, 117u8, 1u8, 208u8, 176u8, 208u8, 186u8,
+                    209u8, 128u8, 46u8, 208u8, 177u8, 208u8, 184u8, 209u8, 130u8, 208u8, 145u8,
+                    194u8, 176u8, 67u8, 209u8, 129u8, 208u8, 188u8, 209u8, 129u8, 208u8, 188u8,
+                    194u8, 179u8, 209u8, 132u8, 209u8, 130u8, 194u8, 179u8, 208u8, 180u8, 209u8,
+                    142u8, 208u8, 185u8, 208u8, 188u8, 194u8, 179u8, 208u8, 186u8, 208u8, 188u8,
+                    194u8, 179u8, 208u8, 188u8, 194u8, 179u8, 208u8, 188u8, 208u8, 184u8, 194u8,
+                    179u8, 209u8, 143u8, 209u8, 128u8, 208u8, 180u8, 194u8, 179u8, 208u8, 180u8,
+                    208u8, 189u8, 194u8, 176u8, 194u8, 176u8, 70u8, 208u8, 176u8, 208u8, 188u8,
+                    46u8, 32u8, 208u8, 182u8, 208u8, 184u8, 208u8, 180u8, 208u8, 186u8, 46u8, 32u8,
+                    209u8, 131u8, 208u8, 189u8, 209u8, 134u8, 46u8, 209u8, 132u8, 209u8, 130u8,
+                    208u8, 176u8, 208u8, 188u8, 46u8, 32u8, 208u8, 179u8, 208u8, 176u8, 208u8,
+                    187u8, 46u8, 208u8, 147u8, 208u8, 177u8, 208u8, 184u8, 209u8, 130u8, 208u8,
+                    147u8, 208u8, 145u8, 208u8, 179u8, 208u8, 179u8, 208u8, 176u8, 209u8, 135u8,
+                    208u8, 180u8, 209u8, 142u8, 208u8, 185u8, 208u8, 188u8, 46u8, 208u8, 186u8,
+                    208u8, 177u8, 208u8, 184u8, 209u8, 130u8, 208u8, 186u8, 208u8, 145u8, 208u8,
+                    186u8, 208u8, 179u8, 208u8, 186u8, 208u8, 188u8, 208u8, 186u8, 208u8, 188u8,
+                    47u8, 209u8, 135u8, 208u8, 187u8, 208u8, 187u8, 47u8, 208u8, 186u8, 208u8,
+                    188u8, 208u8, 156u8, 208u8, 177u8, 208u8, 184u8, 209u8, 130u8, 208u8, 156u8,
+                    208u8, 145u8, 208u8, 188u8, 208u8, 188u8, 47u8, 209u8, 129u8, 208u8, 188u8,
+                    47u8, 209u8, 129u8, 194u8, 178u8, 208u8, 188u8, 208u8, 186u8, 209u8, 129u8,
+                    208u8, 188u8, 208u8, 184u8, 208u8, 188u8, 208u8, 184u8, 47u8, 208u8, 176u8,
+                    208u8, 188u8, 46u8, 32u8, 208u8, 179u8, 208u8, 176u8, 208u8, 187u8, 208u8,
+                    188u8, 208u8, 184u8, 47u8, 209u8, 135u8, 209u8, 129u8, 208u8, 186u8, 46u8,
+                    32u8, 208u8, 188u8, 208u8, 184u8, 208u8, 188u8, 208u8, 187u8, 208u8, 188u8,
+                    208u8, 188u8, 208u8, 188u8, 209u8, 129u8, 208u8, 188u8, 208u8, 184u8, 208u8,
+                    189u8, 208u8, 188u8, 208u8, 181u8, 209u8, 129u8, 46u8, 208u8, 189u8, 209u8,
+                    129u8, 209u8, 131u8, 208u8, 189u8, 209u8, 134u8, 46u8, 37u8, 208u8, 159u8,
+                    208u8, 145u8, 209u8, 132u8, 208u8, 189u8, 209u8, 130u8, 209u8, 129u8, 209u8,
+                    129u8, 208u8, 188u8, 194u8, 178u8, 209u8, 132u8, 209u8, 130u8, 194u8, 178u8,
+                    208u8, 180u8, 209u8, 142u8, 208u8, 185u8, 208u8, 188u8, 194u8, 178u8, 208u8,
+                    186u8, 208u8, 188u8, 194u8, 178u8, 208u8, 188u8, 194u8, 178u8, 208u8, 188u8,
+                    208u8, 184u8, 194u8, 178u8, 209u8, 143u8, 209u8, 128u8, 208u8, 180u8, 194u8,
+                    178u8, 208u8, 162u8, 208u8, 177u8, 208u8, 184u8, 209u8, 130u8, 208u8, 162u8,
+                    208u8, 145u8, 208u8, 189u8, 208u8, 181u8, 208u8, 180u8, 46u8, 209u8, 143u8,
+                    209u8, 128u8, 208u8, 180u8, 46u8, 208u8, 179u8, 46u8,
+                ])
+            },
+        )
+    },
+};
+static SR_LATN: &DataStruct = &::icu_displaynames::provider::UnitDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    65u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 7u8, 0u8, 11u8, 0u8, 18u8, 0u8, 28u8,
+                    0u8, 44u8, 0u8, 54u8, 0u8, 64u8, 0u8, 79u8, 0u8, 90u8, 0u8, 100u8, 0u8, 110u8,
+                    0u8, 113u8, 0u8, 119u8, 0u8, 129u8, 0u8, 140u8, 0u8, 144u8, 0u8, 150u8, 0u8,
+                    157u8, 0u8, 165u8, 0u8, 169u8, 0u8, 176u8, 0u8, 180u8, 0u8, 184u8, 0u8, 191u8,
+                    0u8, 199u8, 0u8, 207u8, 0u8, 216u8, 0u8, 234u8, 0u8, 239u8, 0u8, 2u8, 1u8, 9u8,
+                    1u8, 17u8, 1u8, 22u8, 1u8, 38u8, 1u8, 61u8, 1u8, 72u8, 1u8, 76u8, 1u8, 91u8,
+                    1u8, 104u8, 1u8, 121u8, 1u8, 131u8, 1u8, 141u8, 1u8, 152u8, 1u8, 158u8, 1u8,
+                    163u8, 1u8, 173u8, 1u8, 178u8, 1u8, 185u8, 1u8, 193u8, 1u8, 198u8, 1u8, 204u8,
+                    1u8, 221u8, 1u8, 232u8, 1u8, 243u8, 1u8, 3u8, 2u8, 15u8, 2u8, 26u8, 2u8, 37u8,
+                    2u8, 42u8, 2u8, 49u8, 2u8, 57u8, 2u8, 61u8, 2u8, 65u8, 2u8, 97u8, 99u8, 114u8,
+                    101u8, 98u8, 105u8, 116u8, 98u8, 121u8, 116u8, 101u8, 99u8, 101u8, 108u8,
+                    115u8, 105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8, 110u8,
+                    116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 102u8, 111u8, 111u8, 116u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8,
+                    105u8, 110u8, 99u8, 104u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8,
+                    45u8, 109u8, 105u8, 108u8, 101u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 121u8,
+                    97u8, 114u8, 100u8, 100u8, 97u8, 121u8, 100u8, 101u8, 103u8, 114u8, 101u8,
+                    101u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8,
+                    102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8,
+                    102u8, 111u8, 111u8, 116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8,
+                    105u8, 103u8, 97u8, 98u8, 105u8, 116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8,
+                    116u8, 101u8, 103u8, 114u8, 97u8, 109u8, 104u8, 101u8, 99u8, 116u8, 97u8,
+                    114u8, 101u8, 104u8, 111u8, 117u8, 114u8, 105u8, 110u8, 99u8, 104u8, 107u8,
+                    105u8, 108u8, 111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 98u8,
+                    121u8, 116u8, 101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 104u8, 111u8, 117u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8, 97u8,
+                    98u8, 105u8, 116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 108u8, 101u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8,
+                    101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8,
+                    105u8, 108u8, 101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8,
+                    118u8, 105u8, 97u8, 110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 108u8, 105u8,
+                    116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 110u8,
+                    116u8, 104u8, 110u8, 97u8, 110u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8, 101u8, 114u8, 99u8, 101u8,
+                    110u8, 116u8, 112u8, 101u8, 116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 112u8,
+                    111u8, 117u8, 110u8, 100u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8, 115u8,
+                    116u8, 111u8, 110u8, 101u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8,
+                    116u8, 101u8, 114u8, 97u8, 98u8, 121u8, 116u8, 101u8, 119u8, 101u8, 101u8,
+                    107u8, 121u8, 97u8, 114u8, 100u8, 121u8, 101u8, 97u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    65u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 11u8, 0u8, 18u8, 0u8, 35u8, 0u8, 45u8,
+                    0u8, 61u8, 0u8, 72u8, 0u8, 83u8, 0u8, 98u8, 0u8, 109u8, 0u8, 120u8, 0u8, 131u8,
+                    0u8, 135u8, 0u8, 142u8, 0u8, 160u8, 0u8, 174u8, 0u8, 179u8, 0u8, 185u8, 0u8,
+                    195u8, 0u8, 204u8, 0u8, 209u8, 0u8, 216u8, 0u8, 220u8, 0u8, 225u8, 0u8, 235u8,
+                    0u8, 244u8, 0u8, 253u8, 0u8, 6u8, 1u8, 22u8, 1u8, 27u8, 1u8, 45u8, 1u8, 55u8,
+                    1u8, 64u8, 1u8, 69u8, 1u8, 84u8, 1u8, 110u8, 1u8, 122u8, 1u8, 127u8, 1u8,
+                    142u8, 1u8, 154u8, 1u8, 172u8, 1u8, 181u8, 1u8, 190u8, 1u8, 201u8, 1u8, 207u8,
+                    1u8, 213u8, 1u8, 224u8, 1u8, 228u8, 1u8, 236u8, 1u8, 245u8, 1u8, 250u8, 1u8,
+                    1u8, 2u8, 21u8, 2u8, 36u8, 2u8, 51u8, 2u8, 70u8, 2u8, 85u8, 2u8, 100u8, 2u8,
+                    115u8, 2u8, 129u8, 2u8, 139u8, 2u8, 148u8, 2u8, 155u8, 2u8, 160u8, 2u8, 97u8,
+                    107u8, 101u8, 114u8, 105u8, 98u8, 105u8, 116u8, 111u8, 118u8, 105u8, 98u8,
+                    97u8, 106u8, 116u8, 111u8, 118u8, 105u8, 115u8, 116u8, 101u8, 112u8, 101u8,
+                    110u8, 105u8, 32u8, 67u8, 101u8, 108u8, 122u8, 105u8, 106u8, 117u8, 115u8,
+                    97u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 114u8, 105u8,
+                    107u8, 117u8, 98u8, 110u8, 105u8, 32u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 114u8, 105u8, 107u8, 117u8, 98u8, 110u8, 101u8, 32u8,
+                    115u8, 116u8, 111u8, 112u8, 101u8, 107u8, 117u8, 98u8, 110u8, 105u8, 32u8,
+                    105u8, 110u8, 196u8, 141u8, 105u8, 107u8, 117u8, 98u8, 110u8, 105u8, 32u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 114u8, 105u8, 107u8, 117u8,
+                    98u8, 110u8, 105u8, 32u8, 109u8, 101u8, 116u8, 114u8, 105u8, 107u8, 117u8,
+                    98u8, 110u8, 101u8, 32u8, 109u8, 105u8, 108u8, 106u8, 101u8, 107u8, 117u8,
+                    98u8, 110u8, 105u8, 32u8, 106u8, 97u8, 114u8, 100u8, 105u8, 100u8, 97u8, 110u8,
+                    105u8, 115u8, 116u8, 101u8, 112u8, 101u8, 110u8, 105u8, 115u8, 116u8, 101u8,
+                    112u8, 101u8, 110u8, 105u8, 32u8, 70u8, 97u8, 114u8, 101u8, 110u8, 104u8, 97u8,
+                    106u8, 116u8, 97u8, 117u8, 110u8, 99u8, 101u8, 32u8, 116u8, 101u8, 196u8,
+                    141u8, 110u8, 111u8, 115u8, 116u8, 105u8, 115u8, 116u8, 111u8, 112u8, 101u8,
+                    103u8, 97u8, 108u8, 111u8, 110u8, 105u8, 103u8, 105u8, 103u8, 97u8, 98u8,
+                    105u8, 116u8, 111u8, 118u8, 105u8, 103u8, 105u8, 103u8, 97u8, 98u8, 97u8,
+                    106u8, 116u8, 105u8, 103u8, 114u8, 97u8, 109u8, 105u8, 104u8, 101u8, 107u8,
+                    116u8, 97u8, 114u8, 105u8, 115u8, 97u8, 116u8, 105u8, 105u8, 110u8, 196u8,
+                    141u8, 105u8, 107u8, 105u8, 108u8, 111u8, 98u8, 105u8, 116u8, 111u8, 118u8,
+                    105u8, 107u8, 105u8, 108u8, 111u8, 98u8, 97u8, 106u8, 116u8, 105u8, 107u8,
+                    105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 105u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 114u8, 105u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 114u8, 105u8, 32u8, 110u8, 97u8, 32u8, 115u8, 97u8, 116u8, 108u8,
+                    105u8, 116u8, 114u8, 105u8, 108u8, 105u8, 116u8, 114u8, 105u8, 32u8, 112u8,
+                    111u8, 32u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 114u8, 117u8,
+                    109u8, 101u8, 103u8, 97u8, 98u8, 105u8, 116u8, 111u8, 118u8, 105u8, 109u8,
+                    101u8, 103u8, 97u8, 98u8, 97u8, 106u8, 116u8, 105u8, 109u8, 101u8, 116u8,
+                    114u8, 105u8, 109u8, 101u8, 116u8, 114u8, 105u8, 32u8, 117u8, 32u8, 115u8,
+                    101u8, 107u8, 117u8, 110u8, 100u8, 105u8, 109u8, 101u8, 116u8, 114u8, 105u8,
+                    32u8, 117u8, 32u8, 115u8, 101u8, 107u8, 117u8, 110u8, 100u8, 105u8, 32u8,
+                    110u8, 97u8, 32u8, 107u8, 118u8, 97u8, 100u8, 114u8, 97u8, 116u8, 109u8, 105u8,
+                    107u8, 114u8, 111u8, 115u8, 101u8, 107u8, 117u8, 110u8, 100u8, 101u8, 109u8,
+                    105u8, 108u8, 106u8, 101u8, 109u8, 105u8, 108u8, 106u8, 97u8, 32u8, 112u8,
+                    111u8, 32u8, 103u8, 97u8, 108u8, 111u8, 110u8, 117u8, 109u8, 105u8, 108u8,
+                    106u8, 101u8, 32u8, 110u8, 97u8, 32u8, 115u8, 97u8, 116u8, 115u8, 107u8, 97u8,
+                    110u8, 100u8, 105u8, 110u8, 97u8, 118u8, 115u8, 107u8, 97u8, 32u8, 109u8,
+                    105u8, 108u8, 106u8, 97u8, 109u8, 105u8, 108u8, 105u8, 108u8, 105u8, 116u8,
+                    114u8, 105u8, 109u8, 105u8, 108u8, 105u8, 109u8, 101u8, 116u8, 114u8, 105u8,
+                    109u8, 105u8, 108u8, 105u8, 115u8, 101u8, 107u8, 117u8, 110u8, 100u8, 101u8,
+                    109u8, 105u8, 110u8, 117u8, 116u8, 105u8, 109u8, 101u8, 115u8, 101u8, 99u8,
+                    105u8, 110u8, 97u8, 110u8, 111u8, 115u8, 101u8, 107u8, 117u8, 110u8, 100u8,
+                    101u8, 117u8, 110u8, 99u8, 101u8, 112u8, 114u8, 111u8, 99u8, 101u8, 110u8,
+                    97u8, 116u8, 112u8, 101u8, 116u8, 97u8, 98u8, 97u8, 106u8, 116u8, 105u8, 102u8,
+                    117u8, 110u8, 116u8, 101u8, 115u8, 101u8, 107u8, 117u8, 110u8, 100u8, 101u8,
+                    107u8, 118u8, 97u8, 100u8, 114u8, 97u8, 116u8, 110u8, 105u8, 32u8, 99u8, 101u8,
+                    110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 114u8, 105u8, 107u8, 118u8, 97u8,
+                    100u8, 114u8, 97u8, 116u8, 110u8, 101u8, 32u8, 115u8, 116u8, 111u8, 112u8,
+                    101u8, 107u8, 118u8, 97u8, 100u8, 114u8, 97u8, 116u8, 110u8, 105u8, 32u8,
+                    105u8, 110u8, 196u8, 141u8, 105u8, 107u8, 118u8, 97u8, 100u8, 114u8, 97u8,
+                    116u8, 110u8, 105u8, 32u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8,
+                    114u8, 105u8, 107u8, 118u8, 97u8, 100u8, 114u8, 97u8, 116u8, 110u8, 105u8,
+                    32u8, 109u8, 101u8, 116u8, 114u8, 105u8, 107u8, 118u8, 97u8, 100u8, 114u8,
+                    97u8, 116u8, 110u8, 101u8, 32u8, 109u8, 105u8, 108u8, 106u8, 101u8, 107u8,
+                    118u8, 97u8, 100u8, 114u8, 97u8, 116u8, 110u8, 105u8, 32u8, 106u8, 97u8, 114u8,
+                    100u8, 105u8, 97u8, 109u8, 101u8, 114u8, 105u8, 196u8, 141u8, 107u8, 101u8,
+                    32u8, 116u8, 111u8, 110u8, 101u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8,
+                    116u8, 111u8, 118u8, 105u8, 116u8, 101u8, 114u8, 97u8, 98u8, 97u8, 106u8,
+                    116u8, 105u8, 110u8, 101u8, 100u8, 101u8, 108u8, 106u8, 101u8, 106u8, 97u8,
+                    114u8, 100u8, 105u8, 103u8, 111u8, 100u8, 105u8, 110u8, 101u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    48u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 7u8, 0u8, 14u8, 0u8, 24u8, 0u8, 40u8,
+                    0u8, 50u8, 0u8, 60u8, 0u8, 75u8, 0u8, 86u8, 0u8, 96u8, 0u8, 106u8, 0u8, 109u8,
+                    0u8, 120u8, 0u8, 124u8, 0u8, 130u8, 0u8, 137u8, 0u8, 145u8, 0u8, 152u8, 0u8,
+                    160u8, 0u8, 168u8, 0u8, 177u8, 0u8, 195u8, 0u8, 214u8, 0u8, 221u8, 0u8, 229u8,
+                    0u8, 234u8, 0u8, 1u8, 1u8, 12u8, 1u8, 27u8, 1u8, 44u8, 1u8, 54u8, 1u8, 64u8,
+                    1u8, 75u8, 1u8, 81u8, 1u8, 91u8, 1u8, 96u8, 1u8, 104u8, 1u8, 109u8, 1u8, 115u8,
+                    1u8, 132u8, 1u8, 143u8, 1u8, 154u8, 1u8, 165u8, 1u8, 170u8, 1u8, 177u8, 1u8,
+                    185u8, 1u8, 189u8, 1u8, 98u8, 105u8, 116u8, 98u8, 121u8, 116u8, 101u8, 99u8,
+                    101u8, 108u8, 115u8, 105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8,
+                    101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8,
+                    98u8, 105u8, 99u8, 45u8, 102u8, 111u8, 111u8, 116u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 105u8, 110u8, 99u8, 104u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8,
+                    98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 45u8, 109u8, 105u8, 108u8, 101u8, 99u8, 117u8, 98u8, 105u8, 99u8,
+                    45u8, 121u8, 97u8, 114u8, 100u8, 100u8, 97u8, 121u8, 102u8, 108u8, 117u8,
+                    105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 102u8, 111u8, 111u8,
+                    116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8, 105u8, 103u8, 97u8,
+                    98u8, 105u8, 116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8,
+                    107u8, 105u8, 108u8, 111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8,
+                    98u8, 121u8, 116u8, 101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8,
+                    109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8,
+                    105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 109u8, 101u8, 103u8, 97u8, 98u8, 105u8, 116u8, 109u8,
+                    101u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 109u8, 105u8, 99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8, 101u8, 45u8,
+                    115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8, 118u8, 105u8, 97u8, 110u8,
+                    109u8, 105u8, 108u8, 108u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    110u8, 117u8, 116u8, 101u8, 110u8, 97u8, 110u8, 111u8, 115u8, 101u8, 99u8,
+                    111u8, 110u8, 100u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8, 101u8, 116u8,
+                    97u8, 98u8, 121u8, 116u8, 101u8, 112u8, 111u8, 117u8, 110u8, 100u8, 115u8,
+                    101u8, 99u8, 111u8, 110u8, 100u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8,
+                    115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8,
+                    115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8,
+                    115u8, 116u8, 111u8, 110u8, 101u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8,
+                    116u8, 116u8, 101u8, 114u8, 97u8, 98u8, 121u8, 116u8, 101u8, 119u8, 101u8,
+                    101u8, 107u8, 121u8, 101u8, 97u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    48u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 7u8, 0u8, 10u8, 0u8, 12u8, 0u8, 16u8,
+                    0u8, 20u8, 0u8, 24u8, 0u8, 28u8, 0u8, 31u8, 0u8, 35u8, 0u8, 39u8, 0u8, 41u8,
+                    0u8, 46u8, 0u8, 48u8, 0u8, 51u8, 0u8, 53u8, 0u8, 55u8, 0u8, 57u8, 0u8, 59u8,
+                    0u8, 61u8, 0u8, 63u8, 0u8, 67u8, 0u8, 71u8, 0u8, 73u8, 0u8, 75u8, 0u8, 76u8,
+                    0u8, 81u8, 0u8, 84u8, 0u8, 87u8, 0u8, 90u8, 0u8, 92u8, 0u8, 94u8, 0u8, 96u8,
+                    0u8, 99u8, 0u8, 101u8, 0u8, 103u8, 0u8, 105u8, 0u8, 107u8, 0u8, 110u8, 0u8,
+                    114u8, 0u8, 118u8, 0u8, 122u8, 0u8, 126u8, 0u8, 128u8, 0u8, 130u8, 0u8, 132u8,
+                    0u8, 136u8, 0u8, 98u8, 105u8, 116u8, 98u8, 97u8, 106u8, 116u8, 194u8, 176u8,
+                    67u8, 99u8, 109u8, 99u8, 109u8, 194u8, 179u8, 102u8, 116u8, 194u8, 179u8,
+                    105u8, 110u8, 194u8, 179u8, 107u8, 109u8, 194u8, 179u8, 109u8, 194u8, 179u8,
+                    109u8, 105u8, 194u8, 179u8, 121u8, 100u8, 194u8, 179u8, 100u8, 46u8, 102u8,
+                    108u8, 32u8, 111u8, 122u8, 102u8, 116u8, 103u8, 97u8, 108u8, 71u8, 98u8, 71u8,
+                    66u8, 107u8, 98u8, 107u8, 66u8, 107u8, 103u8, 107u8, 109u8, 107u8, 109u8, 47u8,
+                    104u8, 76u8, 47u8, 107u8, 109u8, 77u8, 98u8, 77u8, 66u8, 109u8, 109u8, 47u8,
+                    115u8, 194u8, 178u8, 206u8, 188u8, 115u8, 109u8, 112u8, 103u8, 115u8, 109u8,
+                    105u8, 109u8, 76u8, 109u8, 109u8, 109u8, 115u8, 109u8, 105u8, 110u8, 110u8,
+                    115u8, 111u8, 122u8, 80u8, 66u8, 108u8, 98u8, 115u8, 101u8, 107u8, 99u8, 109u8,
+                    194u8, 178u8, 105u8, 110u8, 194u8, 178u8, 109u8, 105u8, 194u8, 178u8, 121u8,
+                    100u8, 194u8, 178u8, 115u8, 116u8, 84u8, 98u8, 84u8, 66u8, 110u8, 101u8, 100u8,
+                    46u8, 103u8, 111u8, 100u8, 46u8,
+                ])
+            },
+        )
+    },
+};
+static SR_SR_CYRL: &DataStruct = &::icu_displaynames::provider::UnitDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    65u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 7u8, 0u8, 11u8, 0u8, 18u8, 0u8, 28u8,
+                    0u8, 44u8, 0u8, 54u8, 0u8, 64u8, 0u8, 79u8, 0u8, 90u8, 0u8, 100u8, 0u8, 110u8,
+                    0u8, 113u8, 0u8, 119u8, 0u8, 129u8, 0u8, 140u8, 0u8, 144u8, 0u8, 150u8, 0u8,
+                    157u8, 0u8, 165u8, 0u8, 169u8, 0u8, 176u8, 0u8, 180u8, 0u8, 184u8, 0u8, 191u8,
+                    0u8, 199u8, 0u8, 207u8, 0u8, 216u8, 0u8, 234u8, 0u8, 239u8, 0u8, 2u8, 1u8, 9u8,
+                    1u8, 17u8, 1u8, 22u8, 1u8, 38u8, 1u8, 61u8, 1u8, 72u8, 1u8, 76u8, 1u8, 91u8,
+                    1u8, 104u8, 1u8, 121u8, 1u8, 131u8, 1u8, 141u8, 1u8, 152u8, 1u8, 158u8, 1u8,
+                    163u8, 1u8, 173u8, 1u8, 178u8, 1u8, 185u8, 1u8, 193u8, 1u8, 198u8, 1u8, 204u8,
+                    1u8, 221u8, 1u8, 232u8, 1u8, 243u8, 1u8, 3u8, 2u8, 15u8, 2u8, 26u8, 2u8, 37u8,
+                    2u8, 42u8, 2u8, 49u8, 2u8, 57u8, 2u8, 61u8, 2u8, 65u8, 2u8, 97u8, 99u8, 114u8,
+                    101u8, 98u8, 105u8, 116u8, 98u8, 121u8, 116u8, 101u8, 99u8, 101u8, 108u8,
+                    115u8, 105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8, 110u8,
+                    116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 102u8, 111u8, 111u8, 116u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8,
+                    105u8, 110u8, 99u8, 104u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8,
+                    45u8, 109u8, 105u8, 108u8, 101u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 121u8,
+                    97u8, 114u8, 100u8, 100u8, 97u8, 121u8, 100u8, 101u8, 103u8, 114u8, 101u8,
+                    101u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8,
+                    102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8,
+                    102u8, 111u8, 111u8, 116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8,
+                    105u8, 103u8, 97u8, 98u8, 105u8, 116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8,
+                    116u8, 101u8, 103u8, 114u8, 97u8, 109u8, 104u8, 101u8, 99u8, 116u8, 97u8,
+                    114u8, 101u8, 104u8, 111u8, 117u8, 114u8, 105u8, 110u8, 99u8, 104u8, 107u8,
+                    105u8, 108u8, 111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 98u8,
+                    121u8, 116u8, 101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 104u8, 111u8, 117u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8, 97u8,
+                    98u8, 105u8, 116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 108u8, 101u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8,
+                    101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8,
+                    105u8, 108u8, 101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8,
+                    118u8, 105u8, 97u8, 110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 108u8, 105u8,
+                    116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 110u8,
+                    116u8, 104u8, 110u8, 97u8, 110u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8, 101u8, 114u8, 99u8, 101u8,
+                    110u8, 116u8, 112u8, 101u8, 116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 112u8,
+                    111u8, 117u8, 110u8, 100u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8, 115u8,
+                    116u8, 111u8, 110u8, 101u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8,
+                    116u8, 101u8, 114u8, 97u8, 98u8, 121u8, 116u8, 101u8, 119u8, 101u8, 101u8,
+                    107u8, 121u8, 97u8, 114u8, 100u8, 121u8, 101u8, 97u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    65u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 22u8, 0u8, 36u8, 0u8, 69u8, 0u8,
+                    89u8, 0u8, 120u8, 0u8, 141u8, 0u8, 160u8, 0u8, 189u8, 0u8, 210u8, 0u8, 229u8,
+                    0u8, 250u8, 0u8, 2u8, 1u8, 16u8, 1u8, 51u8, 1u8, 76u8, 1u8, 86u8, 1u8, 98u8,
+                    1u8, 118u8, 1u8, 136u8, 1u8, 146u8, 1u8, 160u8, 1u8, 168u8, 1u8, 176u8, 1u8,
+                    196u8, 1u8, 214u8, 1u8, 232u8, 1u8, 250u8, 1u8, 24u8, 2u8, 34u8, 2u8, 68u8,
+                    2u8, 88u8, 2u8, 106u8, 2u8, 116u8, 2u8, 144u8, 2u8, 192u8, 2u8, 216u8, 2u8,
+                    224u8, 2u8, 250u8, 2u8, 14u8, 3u8, 47u8, 3u8, 65u8, 3u8, 83u8, 3u8, 105u8, 3u8,
+                    117u8, 3u8, 129u8, 3u8, 151u8, 3u8, 159u8, 3u8, 175u8, 3u8, 193u8, 3u8, 203u8,
+                    3u8, 217u8, 3u8, 0u8, 4u8, 29u8, 4u8, 56u8, 4u8, 93u8, 4u8, 122u8, 4u8, 149u8,
+                    4u8, 178u8, 4u8, 203u8, 4u8, 223u8, 4u8, 241u8, 4u8, 253u8, 4u8, 7u8, 5u8,
+                    208u8, 176u8, 208u8, 186u8, 208u8, 181u8, 209u8, 128u8, 208u8, 184u8, 208u8,
+                    177u8, 208u8, 184u8, 209u8, 130u8, 208u8, 190u8, 208u8, 178u8, 208u8, 184u8,
+                    208u8, 177u8, 208u8, 176u8, 209u8, 152u8, 209u8, 130u8, 208u8, 190u8, 208u8,
+                    178u8, 208u8, 184u8, 209u8, 129u8, 209u8, 130u8, 208u8, 181u8, 208u8, 191u8,
+                    208u8, 181u8, 208u8, 189u8, 208u8, 184u8, 32u8, 208u8, 166u8, 208u8, 181u8,
+                    208u8, 187u8, 208u8, 183u8, 208u8, 184u8, 209u8, 152u8, 209u8, 131u8, 209u8,
+                    129u8, 208u8, 176u8, 209u8, 134u8, 208u8, 181u8, 208u8, 189u8, 209u8, 130u8,
+                    208u8, 184u8, 208u8, 188u8, 208u8, 181u8, 209u8, 130u8, 209u8, 128u8, 208u8,
+                    184u8, 208u8, 186u8, 209u8, 131u8, 208u8, 177u8, 208u8, 189u8, 208u8, 184u8,
+                    32u8, 209u8, 134u8, 208u8, 181u8, 208u8, 189u8, 209u8, 130u8, 208u8, 184u8,
+                    208u8, 188u8, 208u8, 181u8, 209u8, 130u8, 209u8, 128u8, 208u8, 184u8, 208u8,
+                    186u8, 209u8, 131u8, 208u8, 177u8, 208u8, 189u8, 208u8, 181u8, 32u8, 209u8,
+                    129u8, 209u8, 130u8, 208u8, 190u8, 208u8, 191u8, 208u8, 181u8, 208u8, 186u8,
+                    209u8, 131u8, 208u8, 177u8, 208u8, 189u8, 208u8, 184u8, 32u8, 208u8, 184u8,
+                    208u8, 189u8, 209u8, 135u8, 208u8, 184u8, 208u8, 186u8, 209u8, 131u8, 208u8,
+                    177u8, 208u8, 189u8, 208u8, 184u8, 32u8, 208u8, 186u8, 208u8, 184u8, 208u8,
+                    187u8, 208u8, 190u8, 208u8, 188u8, 208u8, 181u8, 209u8, 130u8, 209u8, 128u8,
+                    208u8, 184u8, 208u8, 186u8, 209u8, 131u8, 208u8, 177u8, 208u8, 189u8, 208u8,
+                    184u8, 32u8, 208u8, 188u8, 208u8, 181u8, 209u8, 130u8, 209u8, 128u8, 208u8,
+                    184u8, 208u8, 186u8, 209u8, 131u8, 208u8, 177u8, 208u8, 189u8, 208u8, 181u8,
+                    32u8, 208u8, 188u8, 208u8, 184u8, 209u8, 153u8, 208u8, 181u8, 208u8, 186u8,
+                    209u8, 131u8, 208u8, 177u8, 208u8, 189u8, 208u8, 184u8, 32u8, 209u8, 152u8,
+                    208u8, 176u8, 209u8, 128u8, 208u8, 180u8, 208u8, 184u8, 208u8, 180u8, 208u8,
+                    176u8, 208u8, 189u8, 208u8, 184u8, 209u8, 129u8, 209u8, 130u8, 208u8, 181u8,
+                    208u8, 191u8, 208u8, 181u8, 208u8, 189u8, 208u8, 184u8, 209u8, 129u8, 209u8,
+                    130u8, 208u8, 181u8, 208u8, 191u8, 208u8, 181u8, 208u8, 189u8, 208u8, 184u8,
+                    32u8, 208u8, 164u8, 208u8, 176u8, 209u8, 128u8, 208u8, 181u8, 208u8, 189u8,
+                    209u8, 133u8, 208u8, 176u8, 209u8, 152u8, 209u8, 130u8, 208u8, 176u8, 209u8,
+                    131u8, 208u8, 189u8, 209u8, 134u8, 208u8, 181u8, 32u8, 209u8, 130u8, 208u8,
+                    181u8, 209u8, 135u8, 208u8, 189u8, 208u8, 190u8, 209u8, 129u8, 209u8, 130u8,
+                    208u8, 184u8, 209u8, 129u8, 209u8, 130u8, 208u8, 190u8, 208u8, 191u8, 208u8,
+                    181u8, 208u8, 179u8, 208u8, 176u8, 208u8, 187u8, 208u8, 190u8, 208u8, 189u8,
+                    208u8, 184u8, 208u8, 179u8, 208u8, 184u8, 208u8, 179u8, 208u8, 176u8, 208u8,
+                    177u8, 208u8, 184u8, 209u8, 130u8, 208u8, 190u8, 208u8, 178u8, 208u8, 184u8,
+                    208u8, 179u8, 208u8, 184u8, 208u8, 179u8, 208u8, 176u8, 208u8, 177u8, 208u8,
+                    176u8, 209u8, 152u8, 209u8, 130u8, 208u8, 184u8, 208u8, 179u8, 209u8, 128u8,
+                    208u8, 176u8, 208u8, 188u8, 208u8, 184u8, 209u8, 133u8, 208u8, 181u8, 208u8,
+                    186u8, 209u8, 130u8, 208u8, 176u8, 209u8, 128u8, 208u8, 184u8, 209u8, 129u8,
+                    208u8, 176u8, 209u8, 130u8, 208u8, 184u8, 208u8, 184u8, 208u8, 189u8, 209u8,
+                    135u8, 208u8, 184u8, 208u8, 186u8, 208u8, 184u8, 208u8, 187u8, 208u8, 190u8,
+                    208u8, 177u8, 208u8, 184u8, 209u8, 130u8, 208u8, 190u8, 208u8, 178u8, 208u8,
+                    184u8, 208u8, 186u8, 208u8, 184u8, 208u8, 187u8, 208u8, 190u8, 208u8, 177u8,
+                    208u8, 176u8, 209u8, 152u8, 209u8, 130u8, 208u8, 184u8, 208u8, 186u8, 208u8,
+                    184u8, 208u8, 187u8, 208u8, 190u8, 208u8, 179u8, 209u8, 128u8, 208u8, 176u8,
+                    208u8, 188u8, 208u8, 184u8, 208u8, 186u8, 208u8, 184u8, 208u8, 187u8, 208u8,
+                    190u8, 208u8, 188u8, 208u8, 181u8, 209u8, 130u8, 209u8, 128u8, 208u8, 184u8,
+                    208u8, 186u8, 208u8, 184u8, 208u8, 187u8, 208u8, 190u8, 208u8, 188u8, 208u8,
+                    181u8, 209u8, 130u8, 209u8, 128u8, 208u8, 184u8, 32u8, 208u8, 189u8, 208u8,
+                    176u8, 32u8, 209u8, 129u8, 208u8, 176u8, 209u8, 130u8, 208u8, 187u8, 208u8,
+                    184u8, 209u8, 130u8, 209u8, 128u8, 208u8, 184u8, 208u8, 187u8, 208u8, 184u8,
+                    209u8, 130u8, 209u8, 128u8, 208u8, 184u8, 32u8, 208u8, 191u8, 208u8, 190u8,
+                    32u8, 208u8, 186u8, 208u8, 184u8, 208u8, 187u8, 208u8, 190u8, 208u8, 188u8,
+                    208u8, 181u8, 209u8, 130u8, 209u8, 128u8, 209u8, 131u8, 208u8, 188u8, 208u8,
+                    181u8, 208u8, 179u8, 208u8, 176u8, 208u8, 177u8, 208u8, 184u8, 209u8, 130u8,
+                    208u8, 190u8, 208u8, 178u8, 208u8, 184u8, 208u8, 188u8, 208u8, 181u8, 208u8,
+                    179u8, 208u8, 176u8, 208u8, 177u8, 208u8, 176u8, 209u8, 152u8, 209u8, 130u8,
+                    208u8, 184u8, 208u8, 188u8, 208u8, 181u8, 209u8, 130u8, 209u8, 128u8, 208u8,
+                    184u8, 208u8, 188u8, 208u8, 181u8, 209u8, 130u8, 209u8, 128u8, 208u8, 184u8,
+                    32u8, 209u8, 131u8, 32u8, 209u8, 129u8, 208u8, 181u8, 208u8, 186u8, 209u8,
+                    131u8, 208u8, 189u8, 208u8, 180u8, 208u8, 184u8, 208u8, 188u8, 208u8, 181u8,
+                    209u8, 130u8, 209u8, 128u8, 208u8, 184u8, 32u8, 209u8, 131u8, 32u8, 209u8,
+                    129u8, 208u8, 181u8, 208u8, 186u8, 209u8, 131u8, 208u8, 189u8, 208u8, 180u8,
+                    208u8, 184u8, 32u8, 208u8, 189u8, 208u8, 176u8, 32u8, 208u8, 186u8, 208u8,
+                    178u8, 208u8, 176u8, 208u8, 180u8, 209u8, 128u8, 208u8, 176u8, 209u8, 130u8,
+                    208u8, 188u8, 208u8, 184u8, 208u8, 186u8, 209u8, 128u8, 208u8, 190u8, 209u8,
+                    129u8, 208u8, 181u8, 208u8, 186u8, 209u8, 131u8, 208u8, 189u8, 208u8, 180u8,
+                    208u8, 181u8, 208u8, 188u8, 208u8, 184u8, 209u8, 153u8, 208u8, 181u8, 208u8,
+                    188u8, 208u8, 184u8, 209u8, 153u8, 208u8, 176u8, 32u8, 208u8, 191u8, 208u8,
+                    190u8, 32u8, 208u8, 179u8, 208u8, 176u8, 208u8, 187u8, 208u8, 190u8, 208u8,
+                    189u8, 209u8, 131u8, 208u8, 188u8, 208u8, 184u8, 209u8, 153u8, 208u8, 181u8,
+                    32u8, 208u8, 189u8, 208u8, 176u8, 32u8, 209u8, 129u8, 208u8, 176u8, 209u8,
+                    130u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 208u8, 189u8, 208u8, 180u8,
+                    208u8, 184u8, 208u8, 189u8, 208u8, 176u8, 208u8, 178u8, 209u8, 129u8, 208u8,
+                    186u8, 208u8, 176u8, 32u8, 208u8, 188u8, 208u8, 184u8, 209u8, 153u8, 208u8,
+                    176u8, 208u8, 188u8, 208u8, 184u8, 208u8, 187u8, 208u8, 184u8, 208u8, 187u8,
+                    208u8, 184u8, 209u8, 130u8, 209u8, 128u8, 208u8, 184u8, 208u8, 188u8, 208u8,
+                    184u8, 208u8, 187u8, 208u8, 184u8, 208u8, 188u8, 208u8, 181u8, 209u8, 130u8,
+                    209u8, 128u8, 208u8, 184u8, 208u8, 188u8, 208u8, 184u8, 208u8, 187u8, 208u8,
+                    184u8, 209u8, 129u8, 208u8, 181u8, 208u8, 186u8, 209u8, 131u8, 208u8, 189u8,
+                    208u8, 180u8, 208u8, 181u8, 208u8, 188u8, 208u8, 184u8, 208u8, 189u8, 209u8,
+                    131u8, 209u8, 130u8, 208u8, 184u8, 208u8, 188u8, 208u8, 181u8, 209u8, 129u8,
+                    208u8, 181u8, 209u8, 134u8, 208u8, 184u8, 208u8, 189u8, 208u8, 176u8, 208u8,
+                    189u8, 208u8, 190u8, 209u8, 129u8, 208u8, 181u8, 208u8, 186u8, 209u8, 131u8,
+                    208u8, 189u8, 208u8, 180u8, 208u8, 181u8, 209u8, 131u8, 208u8, 189u8, 209u8,
+                    134u8, 208u8, 181u8, 208u8, 191u8, 209u8, 128u8, 208u8, 190u8, 209u8, 134u8,
+                    208u8, 181u8, 208u8, 189u8, 208u8, 176u8, 209u8, 130u8, 208u8, 191u8, 208u8,
+                    181u8, 209u8, 130u8, 208u8, 176u8, 208u8, 177u8, 208u8, 176u8, 209u8, 152u8,
+                    209u8, 130u8, 208u8, 184u8, 209u8, 132u8, 209u8, 131u8, 208u8, 189u8, 209u8,
+                    130u8, 208u8, 181u8, 209u8, 129u8, 208u8, 181u8, 208u8, 186u8, 209u8, 131u8,
+                    208u8, 189u8, 208u8, 180u8, 208u8, 181u8, 208u8, 186u8, 208u8, 178u8, 208u8,
+                    176u8, 208u8, 180u8, 209u8, 128u8, 208u8, 176u8, 209u8, 130u8, 208u8, 189u8,
+                    208u8, 184u8, 32u8, 209u8, 134u8, 208u8, 181u8, 208u8, 189u8, 209u8, 130u8,
+                    208u8, 184u8, 208u8, 188u8, 208u8, 181u8, 209u8, 130u8, 209u8, 128u8, 208u8,
+                    184u8, 208u8, 186u8, 208u8, 178u8, 208u8, 176u8, 208u8, 180u8, 209u8, 128u8,
+                    208u8, 176u8, 209u8, 130u8, 208u8, 189u8, 208u8, 181u8, 32u8, 209u8, 129u8,
+                    209u8, 130u8, 208u8, 190u8, 208u8, 191u8, 208u8, 181u8, 208u8, 186u8, 208u8,
+                    178u8, 208u8, 176u8, 208u8, 180u8, 209u8, 128u8, 208u8, 176u8, 209u8, 130u8,
+                    208u8, 189u8, 208u8, 184u8, 32u8, 208u8, 184u8, 208u8, 189u8, 209u8, 135u8,
+                    208u8, 184u8, 208u8, 186u8, 208u8, 178u8, 208u8, 176u8, 208u8, 180u8, 209u8,
+                    128u8, 208u8, 176u8, 209u8, 130u8, 208u8, 189u8, 208u8, 184u8, 32u8, 208u8,
+                    186u8, 208u8, 184u8, 208u8, 187u8, 208u8, 190u8, 208u8, 188u8, 208u8, 181u8,
+                    209u8, 130u8, 209u8, 128u8, 208u8, 184u8, 208u8, 186u8, 208u8, 178u8, 208u8,
+                    176u8, 208u8, 180u8, 209u8, 128u8, 208u8, 176u8, 209u8, 130u8, 208u8, 189u8,
+                    208u8, 184u8, 32u8, 208u8, 188u8, 208u8, 181u8, 209u8, 130u8, 209u8, 128u8,
+                    208u8, 184u8, 208u8, 186u8, 208u8, 178u8, 208u8, 176u8, 208u8, 180u8, 209u8,
+                    128u8, 208u8, 176u8, 209u8, 130u8, 208u8, 189u8, 208u8, 181u8, 32u8, 208u8,
+                    188u8, 208u8, 184u8, 209u8, 153u8, 208u8, 181u8, 208u8, 186u8, 208u8, 178u8,
+                    208u8, 176u8, 208u8, 180u8, 209u8, 128u8, 208u8, 176u8, 209u8, 130u8, 208u8,
+                    189u8, 208u8, 184u8, 32u8, 209u8, 152u8, 208u8, 176u8, 209u8, 128u8, 208u8,
+                    180u8, 208u8, 184u8, 208u8, 176u8, 208u8, 188u8, 208u8, 181u8, 209u8, 128u8,
+                    208u8, 184u8, 209u8, 135u8, 208u8, 186u8, 208u8, 181u8, 32u8, 209u8, 130u8,
+                    208u8, 190u8, 208u8, 189u8, 208u8, 181u8, 209u8, 130u8, 208u8, 181u8, 209u8,
+                    128u8, 208u8, 176u8, 208u8, 177u8, 208u8, 184u8, 209u8, 130u8, 208u8, 190u8,
+                    208u8, 178u8, 208u8, 184u8, 209u8, 130u8, 208u8, 181u8, 209u8, 128u8, 208u8,
+                    176u8, 208u8, 177u8, 208u8, 176u8, 209u8, 152u8, 209u8, 130u8, 208u8, 184u8,
+                    208u8, 189u8, 208u8, 181u8, 208u8, 180u8, 208u8, 181u8, 209u8, 153u8, 208u8,
+                    181u8, 209u8, 152u8, 208u8, 176u8, 209u8, 128u8, 208u8, 180u8, 208u8, 184u8,
+                    208u8, 179u8, 208u8, 190u8, 208u8, 180u8, 208u8, 184u8, 208u8, 189u8, 208u8,
+                    181u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    48u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 7u8, 0u8, 14u8, 0u8, 24u8, 0u8, 40u8,
+                    0u8, 50u8, 0u8, 60u8, 0u8, 75u8, 0u8, 86u8, 0u8, 96u8, 0u8, 106u8, 0u8, 109u8,
+                    0u8, 120u8, 0u8, 124u8, 0u8, 130u8, 0u8, 137u8, 0u8, 145u8, 0u8, 152u8, 0u8,
+                    160u8, 0u8, 168u8, 0u8, 177u8, 0u8, 195u8, 0u8, 214u8, 0u8, 221u8, 0u8, 229u8,
+                    0u8, 234u8, 0u8, 1u8, 1u8, 12u8, 1u8, 27u8, 1u8, 44u8, 1u8, 54u8, 1u8, 64u8,
+                    1u8, 75u8, 1u8, 81u8, 1u8, 91u8, 1u8, 96u8, 1u8, 104u8, 1u8, 109u8, 1u8, 115u8,
+                    1u8, 132u8, 1u8, 143u8, 1u8, 154u8, 1u8, 165u8, 1u8, 170u8, 1u8, 177u8, 1u8,
+                    185u8, 1u8, 189u8, 1u8, 98u8, 105u8, 116u8, 98u8, 121u8, 116u8, 101u8, 99u8,
+                    101u8, 108u8, 115u8, 105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8,
+                    101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8,
+                    98u8, 105u8, 99u8, 45u8, 102u8, 111u8, 111u8, 116u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 105u8, 110u8, 99u8, 104u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8,
+                    98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 45u8, 109u8, 105u8, 108u8, 101u8, 99u8, 117u8, 98u8, 105u8, 99u8,
+                    45u8, 121u8, 97u8, 114u8, 100u8, 100u8, 97u8, 121u8, 102u8, 108u8, 117u8,
+                    105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 102u8, 111u8, 111u8,
+                    116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8, 105u8, 103u8, 97u8,
+                    98u8, 105u8, 116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8,
+                    107u8, 105u8, 108u8, 111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8,
+                    98u8, 121u8, 116u8, 101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8,
+                    109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8,
+                    105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 109u8, 101u8, 103u8, 97u8, 98u8, 105u8, 116u8, 109u8,
+                    101u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 109u8, 105u8, 99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8, 101u8, 45u8,
+                    115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8, 118u8, 105u8, 97u8, 110u8,
+                    109u8, 105u8, 108u8, 108u8, 105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8,
+                    105u8, 108u8, 108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    110u8, 117u8, 116u8, 101u8, 110u8, 97u8, 110u8, 111u8, 115u8, 101u8, 99u8,
+                    111u8, 110u8, 100u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8, 101u8, 116u8,
+                    97u8, 98u8, 121u8, 116u8, 101u8, 112u8, 111u8, 117u8, 110u8, 100u8, 115u8,
+                    101u8, 99u8, 111u8, 110u8, 100u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8,
+                    115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8,
+                    115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8,
+                    115u8, 116u8, 111u8, 110u8, 101u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8,
+                    116u8, 116u8, 101u8, 114u8, 97u8, 98u8, 121u8, 116u8, 101u8, 119u8, 101u8,
+                    101u8, 107u8, 121u8, 101u8, 97u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    48u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 14u8, 0u8, 17u8, 0u8, 19u8, 0u8, 23u8,
+                    0u8, 27u8, 0u8, 31u8, 0u8, 35u8, 0u8, 38u8, 0u8, 42u8, 0u8, 46u8, 0u8, 49u8,
+                    0u8, 54u8, 0u8, 56u8, 0u8, 59u8, 0u8, 61u8, 0u8, 63u8, 0u8, 65u8, 0u8, 67u8,
+                    0u8, 69u8, 0u8, 71u8, 0u8, 75u8, 0u8, 79u8, 0u8, 81u8, 0u8, 83u8, 0u8, 84u8,
+                    0u8, 89u8, 0u8, 92u8, 0u8, 95u8, 0u8, 98u8, 0u8, 100u8, 0u8, 102u8, 0u8, 104u8,
+                    0u8, 110u8, 0u8, 112u8, 0u8, 114u8, 0u8, 116u8, 0u8, 118u8, 0u8, 124u8, 0u8,
+                    128u8, 0u8, 132u8, 0u8, 136u8, 0u8, 140u8, 0u8, 142u8, 0u8, 144u8, 0u8, 146u8,
+                    0u8, 153u8, 0u8, 208u8, 177u8, 208u8, 184u8, 209u8, 130u8, 208u8, 177u8, 208u8,
+                    176u8, 209u8, 152u8, 209u8, 130u8, 194u8, 176u8, 67u8, 99u8, 109u8, 99u8,
+                    109u8, 194u8, 179u8, 102u8, 116u8, 194u8, 179u8, 105u8, 110u8, 194u8, 179u8,
+                    107u8, 109u8, 194u8, 179u8, 109u8, 194u8, 179u8, 109u8, 105u8, 194u8, 179u8,
+                    121u8, 100u8, 194u8, 179u8, 208u8, 180u8, 46u8, 102u8, 108u8, 32u8, 111u8,
+                    122u8, 102u8, 116u8, 103u8, 97u8, 108u8, 71u8, 98u8, 71u8, 66u8, 107u8, 98u8,
+                    107u8, 66u8, 107u8, 103u8, 107u8, 109u8, 107u8, 109u8, 47u8, 104u8, 76u8, 47u8,
+                    107u8, 109u8, 77u8, 98u8, 77u8, 66u8, 109u8, 109u8, 47u8, 115u8, 194u8, 178u8,
+                    206u8, 188u8, 115u8, 109u8, 112u8, 103u8, 115u8, 109u8, 105u8, 109u8, 76u8,
+                    109u8, 109u8, 109u8, 115u8, 208u8, 188u8, 208u8, 184u8, 208u8, 189u8, 110u8,
+                    115u8, 111u8, 122u8, 80u8, 66u8, 108u8, 98u8, 209u8, 129u8, 208u8, 181u8,
+                    208u8, 186u8, 99u8, 109u8, 194u8, 178u8, 105u8, 110u8, 194u8, 178u8, 109u8,
+                    105u8, 194u8, 178u8, 121u8, 100u8, 194u8, 178u8, 115u8, 116u8, 84u8, 98u8,
+                    84u8, 66u8, 208u8, 189u8, 208u8, 181u8, 208u8, 180u8, 46u8, 208u8, 179u8,
+                    208u8, 190u8, 208u8, 180u8, 46u8,
+                ])
+            },
+        )
+    },
+};
+static TH: &DataStruct = &::icu_displaynames::provider::UnitDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    65u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 7u8, 0u8, 11u8, 0u8, 18u8, 0u8, 28u8,
+                    0u8, 44u8, 0u8, 54u8, 0u8, 64u8, 0u8, 79u8, 0u8, 90u8, 0u8, 100u8, 0u8, 110u8,
+                    0u8, 113u8, 0u8, 119u8, 0u8, 129u8, 0u8, 140u8, 0u8, 144u8, 0u8, 150u8, 0u8,
+                    157u8, 0u8, 165u8, 0u8, 169u8, 0u8, 176u8, 0u8, 180u8, 0u8, 184u8, 0u8, 191u8,
+                    0u8, 199u8, 0u8, 207u8, 0u8, 216u8, 0u8, 234u8, 0u8, 239u8, 0u8, 2u8, 1u8, 9u8,
+                    1u8, 17u8, 1u8, 22u8, 1u8, 38u8, 1u8, 61u8, 1u8, 72u8, 1u8, 76u8, 1u8, 91u8,
+                    1u8, 104u8, 1u8, 121u8, 1u8, 131u8, 1u8, 141u8, 1u8, 152u8, 1u8, 158u8, 1u8,
+                    163u8, 1u8, 173u8, 1u8, 178u8, 1u8, 185u8, 1u8, 193u8, 1u8, 198u8, 1u8, 204u8,
+                    1u8, 221u8, 1u8, 232u8, 1u8, 243u8, 1u8, 3u8, 2u8, 15u8, 2u8, 26u8, 2u8, 37u8,
+                    2u8, 42u8, 2u8, 49u8, 2u8, 57u8, 2u8, 61u8, 2u8, 65u8, 2u8, 97u8, 99u8, 114u8,
+                    101u8, 98u8, 105u8, 116u8, 98u8, 121u8, 116u8, 101u8, 99u8, 101u8, 108u8,
+                    115u8, 105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8, 110u8,
+                    116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 102u8, 111u8, 111u8, 116u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8,
+                    105u8, 110u8, 99u8, 104u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8,
+                    45u8, 109u8, 105u8, 108u8, 101u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 121u8,
+                    97u8, 114u8, 100u8, 100u8, 97u8, 121u8, 100u8, 101u8, 103u8, 114u8, 101u8,
+                    101u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8,
+                    102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8,
+                    102u8, 111u8, 111u8, 116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8,
+                    105u8, 103u8, 97u8, 98u8, 105u8, 116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8,
+                    116u8, 101u8, 103u8, 114u8, 97u8, 109u8, 104u8, 101u8, 99u8, 116u8, 97u8,
+                    114u8, 101u8, 104u8, 111u8, 117u8, 114u8, 105u8, 110u8, 99u8, 104u8, 107u8,
+                    105u8, 108u8, 111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 98u8,
+                    121u8, 116u8, 101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 104u8, 111u8, 117u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8, 97u8,
+                    98u8, 105u8, 116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 108u8, 101u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8,
+                    101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8,
+                    105u8, 108u8, 101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8,
+                    118u8, 105u8, 97u8, 110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 108u8, 105u8,
+                    116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 110u8,
+                    116u8, 104u8, 110u8, 97u8, 110u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8, 101u8, 114u8, 99u8, 101u8,
+                    110u8, 116u8, 112u8, 101u8, 116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 112u8,
+                    111u8, 117u8, 110u8, 100u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8, 115u8,
+                    116u8, 111u8, 110u8, 101u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8,
+                    116u8, 101u8, 114u8, 97u8, 98u8, 121u8, 116u8, 101u8, 119u8, 101u8, 101u8,
+                    107u8, 121u8, 97u8, 114u8, 100u8, 121u8, 101u8, 97u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    65u8, 0u8, 0u8, 0u8, 0u8, 0u8, 21u8, 0u8, 30u8, 0u8, 42u8, 0u8, 78u8, 0u8,
+                    105u8, 0u8, 156u8, 0u8, 189u8, 0u8, 225u8, 0u8, 17u8, 1u8, 53u8, 1u8, 89u8,
+                    1u8, 122u8, 1u8, 131u8, 1u8, 143u8, 1u8, 182u8, 1u8, 215u8, 1u8, 224u8, 1u8,
+                    242u8, 1u8, 7u8, 2u8, 31u8, 2u8, 43u8, 2u8, 64u8, 2u8, 85u8, 2u8, 97u8, 2u8,
+                    118u8, 2u8, 142u8, 2u8, 166u8, 2u8, 190u8, 2u8, 244u8, 2u8, 0u8, 3u8, 45u8,
+                    3u8, 66u8, 3u8, 90u8, 3u8, 102u8, 3u8, 141u8, 3u8, 204u8, 3u8, 237u8, 3u8,
+                    249u8, 3u8, 32u8, 4u8, 74u8, 4u8, 122u8, 4u8, 149u8, 4u8, 176u8, 4u8, 209u8,
+                    4u8, 221u8, 4u8, 236u8, 4u8, 10u8, 5u8, 25u8, 5u8, 58u8, 5u8, 82u8, 5u8, 97u8,
+                    5u8, 115u8, 5u8, 157u8, 5u8, 181u8, 5u8, 208u8, 5u8, 247u8, 5u8, 18u8, 6u8,
+                    45u8, 6u8, 69u8, 6u8, 81u8, 6u8, 102u8, 6u8, 126u8, 6u8, 147u8, 6u8, 156u8,
+                    6u8, 224u8, 185u8, 128u8, 224u8, 184u8, 173u8, 224u8, 185u8, 128u8, 224u8,
+                    184u8, 132u8, 224u8, 184u8, 173u8, 224u8, 184u8, 163u8, 224u8, 185u8, 140u8,
+                    224u8, 184u8, 154u8, 224u8, 184u8, 180u8, 224u8, 184u8, 149u8, 224u8, 185u8,
+                    132u8, 224u8, 184u8, 154u8, 224u8, 184u8, 149u8, 224u8, 185u8, 140u8, 224u8,
+                    184u8, 173u8, 224u8, 184u8, 135u8, 224u8, 184u8, 168u8, 224u8, 184u8, 178u8,
+                    224u8, 185u8, 128u8, 224u8, 184u8, 139u8, 224u8, 184u8, 165u8, 224u8, 185u8,
+                    128u8, 224u8, 184u8, 139u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8,
+                    184u8, 170u8, 224u8, 185u8, 128u8, 224u8, 184u8, 139u8, 224u8, 184u8, 153u8,
+                    224u8, 184u8, 149u8, 224u8, 184u8, 180u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    161u8, 224u8, 184u8, 149u8, 224u8, 184u8, 163u8, 224u8, 184u8, 165u8, 224u8,
+                    184u8, 185u8, 224u8, 184u8, 129u8, 224u8, 184u8, 154u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 168u8, 224u8, 184u8, 129u8, 224u8, 185u8, 140u8, 224u8, 185u8,
+                    128u8, 224u8, 184u8, 139u8, 224u8, 184u8, 153u8, 224u8, 184u8, 149u8, 224u8,
+                    184u8, 180u8, 224u8, 185u8, 128u8, 224u8, 184u8, 161u8, 224u8, 184u8, 149u8,
+                    224u8, 184u8, 163u8, 224u8, 184u8, 165u8, 224u8, 184u8, 185u8, 224u8, 184u8,
+                    129u8, 224u8, 184u8, 154u8, 224u8, 184u8, 178u8, 224u8, 184u8, 168u8, 224u8,
+                    184u8, 129u8, 224u8, 185u8, 140u8, 224u8, 184u8, 159u8, 224u8, 184u8, 184u8,
+                    224u8, 184u8, 149u8, 224u8, 184u8, 165u8, 224u8, 184u8, 185u8, 224u8, 184u8,
+                    129u8, 224u8, 184u8, 154u8, 224u8, 184u8, 178u8, 224u8, 184u8, 168u8, 224u8,
+                    184u8, 129u8, 224u8, 185u8, 140u8, 224u8, 184u8, 153u8, 224u8, 184u8, 180u8,
+                    224u8, 185u8, 137u8, 224u8, 184u8, 167u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    185u8, 224u8, 184u8, 129u8, 224u8, 184u8, 154u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 168u8, 224u8, 184u8, 129u8, 224u8, 185u8, 140u8, 224u8, 184u8, 129u8,
+                    224u8, 184u8, 180u8, 224u8, 185u8, 130u8, 224u8, 184u8, 165u8, 224u8, 185u8,
+                    128u8, 224u8, 184u8, 161u8, 224u8, 184u8, 149u8, 224u8, 184u8, 163u8, 224u8,
+                    184u8, 165u8, 224u8, 184u8, 185u8, 224u8, 184u8, 129u8, 224u8, 184u8, 154u8,
+                    224u8, 184u8, 178u8, 224u8, 184u8, 168u8, 224u8, 184u8, 129u8, 224u8, 185u8,
+                    140u8, 224u8, 185u8, 128u8, 224u8, 184u8, 161u8, 224u8, 184u8, 149u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 165u8, 224u8, 184u8, 185u8, 224u8, 184u8, 129u8,
+                    224u8, 184u8, 154u8, 224u8, 184u8, 178u8, 224u8, 184u8, 168u8, 224u8, 184u8,
+                    129u8, 224u8, 185u8, 140u8, 224u8, 185u8, 132u8, 224u8, 184u8, 161u8, 224u8,
+                    184u8, 165u8, 224u8, 185u8, 140u8, 224u8, 184u8, 165u8, 224u8, 184u8, 185u8,
+                    224u8, 184u8, 129u8, 224u8, 184u8, 154u8, 224u8, 184u8, 178u8, 224u8, 184u8,
+                    168u8, 224u8, 184u8, 129u8, 224u8, 185u8, 140u8, 224u8, 184u8, 171u8, 224u8,
+                    184u8, 165u8, 224u8, 184u8, 178u8, 224u8, 184u8, 167u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 153u8, 224u8, 184u8, 173u8, 224u8, 184u8, 135u8, 224u8, 184u8,
+                    168u8, 224u8, 184u8, 178u8, 224u8, 184u8, 173u8, 224u8, 184u8, 135u8, 224u8,
+                    184u8, 168u8, 224u8, 184u8, 178u8, 224u8, 184u8, 159u8, 224u8, 184u8, 178u8,
+                    224u8, 185u8, 128u8, 224u8, 184u8, 163u8, 224u8, 184u8, 153u8, 224u8, 185u8,
+                    132u8, 224u8, 184u8, 174u8, 224u8, 184u8, 149u8, 224u8, 185u8, 140u8, 224u8,
+                    184u8, 159u8, 224u8, 184u8, 165u8, 224u8, 184u8, 185u8, 224u8, 184u8, 173u8,
+                    224u8, 184u8, 180u8, 224u8, 184u8, 148u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                    173u8, 224u8, 184u8, 153u8, 224u8, 184u8, 139u8, 224u8, 185u8, 140u8, 224u8,
+                    184u8, 159u8, 224u8, 184u8, 184u8, 224u8, 184u8, 149u8, 224u8, 185u8, 129u8,
+                    224u8, 184u8, 129u8, 224u8, 184u8, 165u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    173u8, 224u8, 184u8, 153u8, 224u8, 184u8, 129u8, 224u8, 184u8, 180u8, 224u8,
+                    184u8, 129u8, 224u8, 184u8, 176u8, 224u8, 184u8, 154u8, 224u8, 184u8, 180u8,
+                    224u8, 184u8, 149u8, 224u8, 184u8, 129u8, 224u8, 184u8, 180u8, 224u8, 184u8,
+                    129u8, 224u8, 184u8, 176u8, 224u8, 185u8, 132u8, 224u8, 184u8, 154u8, 224u8,
+                    184u8, 149u8, 224u8, 185u8, 140u8, 224u8, 184u8, 129u8, 224u8, 184u8, 163u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 161u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    174u8, 224u8, 184u8, 129u8, 224u8, 184u8, 149u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 163u8, 224u8, 185u8, 140u8, 224u8, 184u8, 138u8, 224u8, 184u8, 177u8,
+                    224u8, 185u8, 136u8, 224u8, 184u8, 167u8, 224u8, 185u8, 130u8, 224u8, 184u8,
+                    161u8, 224u8, 184u8, 135u8, 224u8, 184u8, 153u8, 224u8, 184u8, 180u8, 224u8,
+                    185u8, 137u8, 224u8, 184u8, 167u8, 224u8, 184u8, 129u8, 224u8, 184u8, 180u8,
+                    224u8, 185u8, 130u8, 224u8, 184u8, 165u8, 224u8, 184u8, 154u8, 224u8, 184u8,
+                    180u8, 224u8, 184u8, 149u8, 224u8, 184u8, 129u8, 224u8, 184u8, 180u8, 224u8,
+                    185u8, 130u8, 224u8, 184u8, 165u8, 224u8, 185u8, 132u8, 224u8, 184u8, 154u8,
+                    224u8, 184u8, 149u8, 224u8, 185u8, 140u8, 224u8, 184u8, 129u8, 224u8, 184u8,
+                    180u8, 224u8, 185u8, 130u8, 224u8, 184u8, 165u8, 224u8, 184u8, 129u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 177u8, 224u8, 184u8, 161u8, 224u8, 184u8, 129u8,
+                    224u8, 184u8, 180u8, 224u8, 185u8, 130u8, 224u8, 184u8, 165u8, 224u8, 185u8,
+                    128u8, 224u8, 184u8, 161u8, 224u8, 184u8, 149u8, 224u8, 184u8, 163u8, 224u8,
+                    184u8, 129u8, 224u8, 184u8, 180u8, 224u8, 185u8, 130u8, 224u8, 184u8, 165u8,
+                    224u8, 185u8, 128u8, 224u8, 184u8, 161u8, 224u8, 184u8, 149u8, 224u8, 184u8,
+                    163u8, 224u8, 184u8, 149u8, 224u8, 185u8, 136u8, 224u8, 184u8, 173u8, 224u8,
+                    184u8, 138u8, 224u8, 184u8, 177u8, 224u8, 185u8, 136u8, 224u8, 184u8, 167u8,
+                    224u8, 185u8, 130u8, 224u8, 184u8, 161u8, 224u8, 184u8, 135u8, 224u8, 184u8,
+                    165u8, 224u8, 184u8, 180u8, 224u8, 184u8, 149u8, 224u8, 184u8, 163u8, 224u8,
+                    184u8, 165u8, 224u8, 184u8, 180u8, 224u8, 184u8, 149u8, 224u8, 184u8, 163u8,
+                    224u8, 184u8, 149u8, 224u8, 185u8, 136u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                    129u8, 224u8, 184u8, 180u8, 224u8, 185u8, 130u8, 224u8, 184u8, 165u8, 224u8,
+                    185u8, 128u8, 224u8, 184u8, 161u8, 224u8, 184u8, 149u8, 224u8, 184u8, 163u8,
+                    224u8, 185u8, 128u8, 224u8, 184u8, 161u8, 224u8, 184u8, 129u8, 224u8, 184u8,
+                    176u8, 224u8, 184u8, 154u8, 224u8, 184u8, 180u8, 224u8, 184u8, 149u8, 224u8,
+                    185u8, 128u8, 224u8, 184u8, 161u8, 224u8, 184u8, 129u8, 224u8, 184u8, 176u8,
+                    224u8, 185u8, 132u8, 224u8, 184u8, 154u8, 224u8, 184u8, 149u8, 224u8, 185u8,
+                    140u8, 224u8, 185u8, 128u8, 224u8, 184u8, 161u8, 224u8, 184u8, 149u8, 224u8,
+                    184u8, 163u8, 224u8, 185u8, 128u8, 224u8, 184u8, 161u8, 224u8, 184u8, 149u8,
+                    224u8, 184u8, 163u8, 224u8, 184u8, 149u8, 224u8, 185u8, 136u8, 224u8, 184u8,
+                    173u8, 224u8, 184u8, 167u8, 224u8, 184u8, 180u8, 224u8, 184u8, 153u8, 224u8,
+                    184u8, 178u8, 224u8, 184u8, 151u8, 224u8, 184u8, 181u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 161u8, 224u8, 184u8, 149u8, 224u8, 184u8, 163u8, 224u8, 184u8,
+                    149u8, 224u8, 185u8, 136u8, 224u8, 184u8, 173u8, 224u8, 184u8, 167u8, 224u8,
+                    184u8, 180u8, 224u8, 184u8, 153u8, 224u8, 184u8, 178u8, 224u8, 184u8, 151u8,
+                    224u8, 184u8, 181u8, 224u8, 184u8, 129u8, 224u8, 184u8, 179u8, 224u8, 184u8,
+                    165u8, 224u8, 184u8, 177u8, 224u8, 184u8, 135u8, 224u8, 184u8, 170u8, 224u8,
+                    184u8, 173u8, 224u8, 184u8, 135u8, 224u8, 185u8, 132u8, 224u8, 184u8, 161u8,
+                    224u8, 185u8, 130u8, 224u8, 184u8, 132u8, 224u8, 184u8, 163u8, 224u8, 184u8,
+                    167u8, 224u8, 184u8, 180u8, 224u8, 184u8, 153u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 151u8, 224u8, 184u8, 181u8, 224u8, 185u8, 132u8, 224u8, 184u8, 161u8,
+                    224u8, 184u8, 165u8, 224u8, 185u8, 140u8, 224u8, 185u8, 132u8, 224u8, 184u8,
+                    161u8, 224u8, 184u8, 165u8, 224u8, 185u8, 140u8, 224u8, 184u8, 149u8, 224u8,
+                    185u8, 136u8, 224u8, 184u8, 173u8, 224u8, 185u8, 129u8, 224u8, 184u8, 129u8,
+                    224u8, 184u8, 165u8, 224u8, 184u8, 165u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                    153u8, 224u8, 185u8, 132u8, 224u8, 184u8, 161u8, 224u8, 184u8, 165u8, 224u8,
+                    185u8, 140u8, 224u8, 184u8, 149u8, 224u8, 185u8, 136u8, 224u8, 184u8, 173u8,
+                    224u8, 184u8, 138u8, 224u8, 184u8, 177u8, 224u8, 185u8, 136u8, 224u8, 184u8,
+                    167u8, 224u8, 185u8, 130u8, 224u8, 184u8, 161u8, 224u8, 184u8, 135u8, 224u8,
+                    185u8, 132u8, 224u8, 184u8, 161u8, 224u8, 184u8, 165u8, 224u8, 185u8, 140u8,
+                    224u8, 184u8, 170u8, 224u8, 185u8, 129u8, 224u8, 184u8, 129u8, 224u8, 184u8,
+                    153u8, 224u8, 184u8, 148u8, 224u8, 184u8, 180u8, 224u8, 185u8, 128u8, 224u8,
+                    184u8, 153u8, 224u8, 185u8, 128u8, 224u8, 184u8, 167u8, 224u8, 184u8, 181u8,
+                    224u8, 184u8, 162u8, 224u8, 184u8, 161u8, 224u8, 184u8, 180u8, 224u8, 184u8,
+                    165u8, 224u8, 184u8, 165u8, 224u8, 184u8, 180u8, 224u8, 184u8, 165u8, 224u8,
+                    184u8, 180u8, 224u8, 184u8, 149u8, 224u8, 184u8, 163u8, 224u8, 184u8, 161u8,
+                    224u8, 184u8, 180u8, 224u8, 184u8, 165u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    180u8, 224u8, 185u8, 128u8, 224u8, 184u8, 161u8, 224u8, 184u8, 149u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 161u8, 224u8, 184u8, 180u8, 224u8, 184u8, 165u8,
+                    224u8, 184u8, 165u8, 224u8, 184u8, 180u8, 224u8, 184u8, 167u8, 224u8, 184u8,
+                    180u8, 224u8, 184u8, 153u8, 224u8, 184u8, 178u8, 224u8, 184u8, 151u8, 224u8,
+                    184u8, 181u8, 224u8, 184u8, 153u8, 224u8, 184u8, 178u8, 224u8, 184u8, 151u8,
+                    224u8, 184u8, 181u8, 224u8, 185u8, 128u8, 224u8, 184u8, 148u8, 224u8, 184u8,
+                    183u8, 224u8, 184u8, 173u8, 224u8, 184u8, 153u8, 224u8, 184u8, 153u8, 224u8,
+                    184u8, 178u8, 224u8, 185u8, 130u8, 224u8, 184u8, 153u8, 224u8, 184u8, 167u8,
+                    224u8, 184u8, 180u8, 224u8, 184u8, 153u8, 224u8, 184u8, 178u8, 224u8, 184u8,
+                    151u8, 224u8, 184u8, 181u8, 224u8, 184u8, 173u8, 224u8, 184u8, 173u8, 224u8,
+                    184u8, 153u8, 224u8, 184u8, 139u8, 224u8, 185u8, 140u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 155u8, 224u8, 184u8, 173u8, 224u8, 184u8, 163u8, 224u8, 185u8,
+                    140u8, 224u8, 185u8, 128u8, 224u8, 184u8, 139u8, 224u8, 185u8, 135u8, 224u8,
+                    184u8, 153u8, 224u8, 184u8, 149u8, 224u8, 185u8, 140u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 158u8, 224u8, 184u8, 149u8, 224u8, 184u8, 176u8, 224u8, 185u8,
+                    132u8, 224u8, 184u8, 154u8, 224u8, 184u8, 149u8, 224u8, 185u8, 140u8, 224u8,
+                    184u8, 155u8, 224u8, 184u8, 173u8, 224u8, 184u8, 153u8, 224u8, 184u8, 148u8,
+                    224u8, 185u8, 140u8, 224u8, 184u8, 167u8, 224u8, 184u8, 180u8, 224u8, 184u8,
+                    153u8, 224u8, 184u8, 178u8, 224u8, 184u8, 151u8, 224u8, 184u8, 181u8, 224u8,
+                    184u8, 149u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 135u8, 224u8, 185u8, 128u8, 224u8, 184u8, 139u8, 224u8, 184u8,
+                    153u8, 224u8, 184u8, 149u8, 224u8, 184u8, 180u8, 224u8, 185u8, 128u8, 224u8,
+                    184u8, 161u8, 224u8, 184u8, 149u8, 224u8, 184u8, 163u8, 224u8, 184u8, 149u8,
+                    224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 184u8, 178u8, 224u8, 184u8,
+                    135u8, 224u8, 184u8, 159u8, 224u8, 184u8, 184u8, 224u8, 184u8, 149u8, 224u8,
+                    184u8, 149u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 135u8, 224u8, 184u8, 153u8, 224u8, 184u8, 180u8, 224u8, 185u8,
+                    137u8, 224u8, 184u8, 167u8, 224u8, 184u8, 149u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 178u8, 224u8, 184u8, 135u8, 224u8, 184u8, 129u8,
+                    224u8, 184u8, 180u8, 224u8, 185u8, 130u8, 224u8, 184u8, 165u8, 224u8, 185u8,
+                    128u8, 224u8, 184u8, 161u8, 224u8, 184u8, 149u8, 224u8, 184u8, 163u8, 224u8,
+                    184u8, 149u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 135u8, 224u8, 185u8, 128u8, 224u8, 184u8, 161u8, 224u8, 184u8,
+                    149u8, 224u8, 184u8, 163u8, 224u8, 184u8, 149u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 178u8, 224u8, 184u8, 135u8, 224u8, 185u8, 132u8,
+                    224u8, 184u8, 161u8, 224u8, 184u8, 165u8, 224u8, 185u8, 140u8, 224u8, 184u8,
+                    149u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 135u8, 224u8, 184u8, 171u8, 224u8, 184u8, 165u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 170u8, 224u8, 185u8, 130u8, 224u8, 184u8, 149u8, 224u8, 184u8,
+                    153u8, 224u8, 185u8, 128u8, 224u8, 184u8, 151u8, 224u8, 184u8, 163u8, 224u8,
+                    184u8, 178u8, 224u8, 184u8, 154u8, 224u8, 184u8, 180u8, 224u8, 184u8, 149u8,
+                    224u8, 185u8, 128u8, 224u8, 184u8, 151u8, 224u8, 184u8, 163u8, 224u8, 184u8,
+                    178u8, 224u8, 185u8, 132u8, 224u8, 184u8, 154u8, 224u8, 184u8, 149u8, 224u8,
+                    185u8, 140u8, 224u8, 184u8, 170u8, 224u8, 184u8, 177u8, 224u8, 184u8, 155u8,
+                    224u8, 184u8, 148u8, 224u8, 184u8, 178u8, 224u8, 184u8, 171u8, 224u8, 185u8,
+                    140u8, 224u8, 184u8, 171u8, 224u8, 184u8, 165u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 155u8, 224u8, 184u8, 181u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    33u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 26u8, 0u8, 36u8, 0u8, 46u8, 0u8,
+                    61u8, 0u8, 72u8, 0u8, 82u8, 0u8, 92u8, 0u8, 99u8, 0u8, 107u8, 0u8, 114u8, 0u8,
+                    122u8, 0u8, 130u8, 0u8, 139u8, 0u8, 157u8, 0u8, 176u8, 0u8, 183u8, 0u8, 191u8,
+                    0u8, 214u8, 0u8, 229u8, 0u8, 242u8, 0u8, 252u8, 0u8, 6u8, 1u8, 14u8, 1u8, 31u8,
+                    1u8, 42u8, 1u8, 53u8, 1u8, 69u8, 1u8, 81u8, 1u8, 92u8, 1u8, 103u8, 1u8, 110u8,
+                    1u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8,
+                    117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 102u8, 111u8, 111u8,
+                    116u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 105u8, 110u8, 99u8, 104u8, 99u8,
+                    117u8, 98u8, 105u8, 99u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 105u8, 108u8, 101u8,
+                    99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 121u8, 97u8, 114u8, 100u8, 103u8, 105u8,
+                    103u8, 97u8, 98u8, 105u8, 116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8, 116u8,
+                    101u8, 107u8, 105u8, 108u8, 111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8,
+                    111u8, 98u8, 121u8, 116u8, 101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8,
+                    97u8, 109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 108u8, 105u8, 116u8, 101u8,
+                    114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8, 97u8, 98u8, 105u8, 116u8,
+                    109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8, 105u8,
+                    108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 103u8, 97u8, 108u8, 108u8,
+                    111u8, 110u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    104u8, 111u8, 117u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 108u8, 105u8,
+                    116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 112u8, 101u8, 116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8, 116u8,
+                    101u8, 114u8, 97u8, 98u8, 105u8, 116u8, 116u8, 101u8, 114u8, 97u8, 98u8, 121u8,
+                    116u8, 101u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    33u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 21u8, 0u8, 37u8, 0u8, 56u8, 0u8, 70u8,
+                    0u8, 81u8, 0u8, 100u8, 0u8, 116u8, 0u8, 118u8, 0u8, 120u8, 0u8, 122u8, 0u8,
+                    124u8, 0u8, 131u8, 0u8, 138u8, 0u8, 167u8, 0u8, 187u8, 0u8, 189u8, 0u8, 191u8,
+                    0u8, 224u8, 0u8, 255u8, 0u8, 33u8, 1u8, 40u8, 1u8, 47u8, 1u8, 49u8, 1u8, 63u8,
+                    1u8, 79u8, 1u8, 98u8, 1u8, 112u8, 1u8, 123u8, 1u8, 142u8, 1u8, 158u8, 1u8,
+                    160u8, 1u8, 224u8, 184u8, 139u8, 224u8, 184u8, 161u8, 46u8, 224u8, 184u8,
+                    165u8, 224u8, 184u8, 154u8, 46u8, 224u8, 184u8, 139u8, 224u8, 184u8, 161u8,
+                    46u8, 224u8, 184u8, 165u8, 224u8, 184u8, 154u8, 46u8, 224u8, 184u8, 159u8,
+                    224u8, 184u8, 184u8, 224u8, 184u8, 149u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    154u8, 46u8, 224u8, 184u8, 153u8, 224u8, 184u8, 180u8, 224u8, 185u8, 137u8,
+                    224u8, 184u8, 167u8, 224u8, 184u8, 165u8, 224u8, 184u8, 154u8, 46u8, 224u8,
+                    184u8, 129u8, 224u8, 184u8, 161u8, 46u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    154u8, 46u8, 224u8, 184u8, 161u8, 46u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    154u8, 46u8, 224u8, 185u8, 132u8, 224u8, 184u8, 161u8, 224u8, 184u8, 165u8,
+                    224u8, 185u8, 140u8, 224u8, 184u8, 165u8, 224u8, 184u8, 154u8, 46u8, 224u8,
+                    184u8, 171u8, 224u8, 184u8, 165u8, 224u8, 184u8, 178u8, 71u8, 98u8, 71u8, 66u8,
+                    107u8, 98u8, 107u8, 66u8, 224u8, 184u8, 129u8, 224u8, 184u8, 129u8, 46u8,
+                    224u8, 184u8, 129u8, 224u8, 184u8, 161u8, 46u8, 224u8, 184u8, 129u8, 224u8,
+                    184u8, 161u8, 46u8, 47u8, 224u8, 184u8, 138u8, 224u8, 184u8, 177u8, 224u8,
+                    185u8, 136u8, 224u8, 184u8, 167u8, 224u8, 185u8, 130u8, 224u8, 184u8, 161u8,
+                    224u8, 184u8, 135u8, 224u8, 184u8, 165u8, 224u8, 184u8, 180u8, 224u8, 184u8,
+                    149u8, 224u8, 184u8, 163u8, 47u8, 224u8, 184u8, 129u8, 224u8, 184u8, 161u8,
+                    46u8, 77u8, 98u8, 77u8, 66u8, 224u8, 185u8, 128u8, 224u8, 184u8, 161u8, 224u8,
+                    184u8, 149u8, 224u8, 184u8, 163u8, 47u8, 224u8, 184u8, 167u8, 224u8, 184u8,
+                    180u8, 224u8, 184u8, 153u8, 224u8, 184u8, 178u8, 224u8, 184u8, 151u8, 224u8,
+                    184u8, 181u8, 194u8, 178u8, 224u8, 185u8, 132u8, 224u8, 184u8, 161u8, 224u8,
+                    184u8, 165u8, 224u8, 185u8, 140u8, 47u8, 224u8, 185u8, 129u8, 224u8, 184u8,
+                    129u8, 224u8, 184u8, 165u8, 224u8, 184u8, 165u8, 224u8, 184u8, 173u8, 224u8,
+                    184u8, 153u8, 224u8, 185u8, 132u8, 224u8, 184u8, 161u8, 224u8, 184u8, 165u8,
+                    224u8, 185u8, 140u8, 47u8, 224u8, 184u8, 138u8, 224u8, 184u8, 177u8, 224u8,
+                    185u8, 136u8, 224u8, 184u8, 167u8, 224u8, 185u8, 130u8, 224u8, 184u8, 161u8,
+                    224u8, 184u8, 135u8, 224u8, 184u8, 161u8, 224u8, 184u8, 165u8, 46u8, 224u8,
+                    184u8, 161u8, 224u8, 184u8, 161u8, 46u8, 80u8, 66u8, 224u8, 184u8, 149u8,
+                    224u8, 184u8, 163u8, 46u8, 224u8, 184u8, 139u8, 224u8, 184u8, 161u8, 46u8,
+                    224u8, 184u8, 149u8, 224u8, 184u8, 163u8, 46u8, 224u8, 184u8, 159u8, 224u8,
+                    184u8, 184u8, 224u8, 184u8, 149u8, 224u8, 184u8, 149u8, 224u8, 184u8, 163u8,
+                    46u8, 224u8, 184u8, 153u8, 224u8, 184u8, 180u8, 224u8, 185u8, 137u8, 224u8,
+                    184u8, 167u8, 224u8, 184u8, 149u8, 224u8, 184u8, 163u8, 46u8, 224u8, 184u8,
+                    129u8, 224u8, 184u8, 161u8, 46u8, 224u8, 184u8, 149u8, 224u8, 184u8, 163u8,
+                    46u8, 224u8, 184u8, 161u8, 46u8, 224u8, 184u8, 149u8, 224u8, 184u8, 163u8,
+                    46u8, 224u8, 185u8, 132u8, 224u8, 184u8, 161u8, 224u8, 184u8, 165u8, 224u8,
+                    185u8, 140u8, 224u8, 184u8, 149u8, 224u8, 184u8, 163u8, 46u8, 224u8, 184u8,
+                    171u8, 224u8, 184u8, 165u8, 224u8, 184u8, 178u8, 84u8, 98u8, 84u8, 66u8,
+                ])
+            },
+        )
+    },
+};
+static TR: &DataStruct = &::icu_displaynames::provider::UnitDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    65u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 7u8, 0u8, 11u8, 0u8, 18u8, 0u8, 28u8,
+                    0u8, 44u8, 0u8, 54u8, 0u8, 64u8, 0u8, 79u8, 0u8, 90u8, 0u8, 100u8, 0u8, 110u8,
+                    0u8, 113u8, 0u8, 119u8, 0u8, 129u8, 0u8, 140u8, 0u8, 144u8, 0u8, 150u8, 0u8,
+                    157u8, 0u8, 165u8, 0u8, 169u8, 0u8, 176u8, 0u8, 180u8, 0u8, 184u8, 0u8, 191u8,
+                    0u8, 199u8, 0u8, 207u8, 0u8, 216u8, 0u8, 234u8, 0u8, 239u8, 0u8, 2u8, 1u8, 9u8,
+                    1u8, 17u8, 1u8, 22u8, 1u8, 38u8, 1u8, 61u8, 1u8, 72u8, 1u8, 76u8, 1u8, 91u8,
+                    1u8, 104u8, 1u8, 121u8, 1u8, 131u8, 1u8, 141u8, 1u8, 152u8, 1u8, 158u8, 1u8,
+                    163u8, 1u8, 173u8, 1u8, 178u8, 1u8, 185u8, 1u8, 193u8, 1u8, 198u8, 1u8, 204u8,
+                    1u8, 221u8, 1u8, 232u8, 1u8, 243u8, 1u8, 3u8, 2u8, 15u8, 2u8, 26u8, 2u8, 37u8,
+                    2u8, 42u8, 2u8, 49u8, 2u8, 57u8, 2u8, 61u8, 2u8, 65u8, 2u8, 97u8, 99u8, 114u8,
+                    101u8, 98u8, 105u8, 116u8, 98u8, 121u8, 116u8, 101u8, 99u8, 101u8, 108u8,
+                    115u8, 105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8, 110u8,
+                    116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 102u8, 111u8, 111u8, 116u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8,
+                    105u8, 110u8, 99u8, 104u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8,
+                    45u8, 109u8, 105u8, 108u8, 101u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 121u8,
+                    97u8, 114u8, 100u8, 100u8, 97u8, 121u8, 100u8, 101u8, 103u8, 114u8, 101u8,
+                    101u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8,
+                    102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8,
+                    102u8, 111u8, 111u8, 116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8,
+                    105u8, 103u8, 97u8, 98u8, 105u8, 116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8,
+                    116u8, 101u8, 103u8, 114u8, 97u8, 109u8, 104u8, 101u8, 99u8, 116u8, 97u8,
+                    114u8, 101u8, 104u8, 111u8, 117u8, 114u8, 105u8, 110u8, 99u8, 104u8, 107u8,
+                    105u8, 108u8, 111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 98u8,
+                    121u8, 116u8, 101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 104u8, 111u8, 117u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8, 97u8,
+                    98u8, 105u8, 116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 108u8, 101u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8,
+                    101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8,
+                    105u8, 108u8, 101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8,
+                    118u8, 105u8, 97u8, 110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 108u8, 105u8,
+                    116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 110u8,
+                    116u8, 104u8, 110u8, 97u8, 110u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8, 101u8, 114u8, 99u8, 101u8,
+                    110u8, 116u8, 112u8, 101u8, 116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 112u8,
+                    111u8, 117u8, 110u8, 100u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8, 115u8,
+                    116u8, 111u8, 110u8, 101u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8,
+                    116u8, 101u8, 114u8, 97u8, 98u8, 121u8, 116u8, 101u8, 119u8, 101u8, 101u8,
+                    107u8, 121u8, 97u8, 114u8, 100u8, 121u8, 101u8, 97u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    65u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 7u8, 0u8, 11u8, 0u8, 27u8, 0u8, 37u8,
+                    0u8, 51u8, 0u8, 59u8, 0u8, 68u8, 0u8, 81u8, 0u8, 90u8, 0u8, 98u8, 0u8, 108u8,
+                    0u8, 112u8, 0u8, 118u8, 0u8, 135u8, 0u8, 145u8, 0u8, 148u8, 0u8, 153u8, 0u8,
+                    160u8, 0u8, 168u8, 0u8, 172u8, 0u8, 178u8, 0u8, 182u8, 0u8, 186u8, 0u8, 193u8,
+                    0u8, 201u8, 0u8, 209u8, 0u8, 218u8, 0u8, 232u8, 0u8, 237u8, 0u8, 252u8, 0u8,
+                    3u8, 1u8, 11u8, 1u8, 16u8, 1u8, 28u8, 1u8, 42u8, 1u8, 53u8, 1u8, 56u8, 1u8,
+                    65u8, 1u8, 73u8, 1u8, 76u8, 1u8, 85u8, 1u8, 94u8, 1u8, 104u8, 1u8, 110u8, 1u8,
+                    112u8, 1u8, 122u8, 1u8, 125u8, 1u8, 131u8, 1u8, 139u8, 1u8, 144u8, 1u8, 150u8,
+                    1u8, 164u8, 1u8, 172u8, 1u8, 181u8, 1u8, 194u8, 1u8, 203u8, 1u8, 211u8, 1u8,
+                    221u8, 1u8, 226u8, 1u8, 233u8, 1u8, 241u8, 1u8, 246u8, 1u8, 251u8, 1u8, 97u8,
+                    107u8, 114u8, 101u8, 98u8, 105u8, 116u8, 98u8, 97u8, 121u8, 116u8, 115u8, 97u8,
+                    110u8, 116u8, 105u8, 103u8, 114u8, 97u8, 116u8, 32u8, 100u8, 101u8, 114u8,
+                    101u8, 99u8, 101u8, 115u8, 97u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8,
+                    114u8, 101u8, 115u8, 97u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 114u8,
+                    101u8, 107u8, 195u8, 188u8, 112u8, 102u8, 105u8, 116u8, 32u8, 107u8, 195u8,
+                    188u8, 112u8, 105u8, 110u8, 195u8, 167u8, 32u8, 107u8, 195u8, 188u8, 112u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 114u8, 101u8, 107u8, 195u8,
+                    188u8, 112u8, 109u8, 101u8, 116u8, 114u8, 101u8, 107u8, 195u8, 188u8, 112u8,
+                    109u8, 105u8, 108u8, 32u8, 107u8, 195u8, 188u8, 112u8, 121u8, 97u8, 114u8,
+                    100u8, 97u8, 32u8, 107u8, 195u8, 188u8, 112u8, 103u8, 195u8, 188u8, 110u8,
+                    100u8, 101u8, 114u8, 101u8, 99u8, 101u8, 102u8, 97u8, 104u8, 114u8, 101u8,
+                    110u8, 104u8, 97u8, 121u8, 116u8, 32u8, 100u8, 101u8, 114u8, 101u8, 99u8,
+                    101u8, 115u8, 196u8, 177u8, 118u8, 196u8, 177u8, 32u8, 111u8, 110u8, 115u8,
+                    102u8, 105u8, 116u8, 103u8, 97u8, 108u8, 111u8, 110u8, 103u8, 105u8, 103u8,
+                    97u8, 98u8, 105u8, 116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 97u8, 121u8, 116u8,
+                    103u8, 114u8, 97u8, 109u8, 104u8, 101u8, 107u8, 116u8, 97u8, 114u8, 115u8,
+                    97u8, 97u8, 116u8, 105u8, 110u8, 195u8, 167u8, 107u8, 105u8, 108u8, 111u8,
+                    98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 98u8, 97u8, 121u8, 116u8,
+                    107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8,
+                    111u8, 109u8, 101u8, 116u8, 114u8, 101u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+                    101u8, 116u8, 114u8, 101u8, 47u8, 115u8, 97u8, 97u8, 116u8, 108u8, 105u8,
+                    116u8, 114u8, 101u8, 108u8, 105u8, 116u8, 114u8, 101u8, 47u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 114u8, 101u8, 109u8, 101u8, 103u8, 97u8,
+                    98u8, 105u8, 116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 97u8, 121u8, 116u8, 109u8,
+                    101u8, 116u8, 114u8, 101u8, 109u8, 101u8, 116u8, 114u8, 101u8, 47u8, 115u8,
+                    97u8, 110u8, 105u8, 121u8, 101u8, 109u8, 101u8, 116u8, 114u8, 101u8, 47u8,
+                    115u8, 97u8, 110u8, 105u8, 121u8, 101u8, 194u8, 178u8, 109u8, 105u8, 107u8,
+                    114u8, 111u8, 115u8, 97u8, 110u8, 105u8, 121u8, 101u8, 109u8, 105u8, 108u8,
+                    109u8, 105u8, 108u8, 47u8, 103u8, 97u8, 108u8, 111u8, 110u8, 109u8, 105u8,
+                    108u8, 47u8, 115u8, 97u8, 97u8, 116u8, 115u8, 109u8, 105u8, 109u8, 105u8,
+                    108u8, 105u8, 108u8, 105u8, 116u8, 114u8, 101u8, 109u8, 105u8, 108u8, 105u8,
+                    109u8, 101u8, 116u8, 114u8, 101u8, 109u8, 105u8, 108u8, 105u8, 115u8, 97u8,
+                    110u8, 105u8, 121u8, 101u8, 100u8, 97u8, 107u8, 105u8, 107u8, 97u8, 97u8,
+                    121u8, 110u8, 97u8, 110u8, 111u8, 115u8, 97u8, 110u8, 105u8, 121u8, 101u8,
+                    111u8, 110u8, 115u8, 121u8, 195u8, 188u8, 122u8, 100u8, 101u8, 112u8, 101u8,
+                    116u8, 97u8, 98u8, 97u8, 121u8, 116u8, 108u8, 105u8, 98u8, 114u8, 101u8, 115u8,
+                    97u8, 110u8, 105u8, 121u8, 101u8, 115u8, 97u8, 110u8, 116u8, 105u8, 109u8,
+                    101u8, 116u8, 114u8, 101u8, 107u8, 97u8, 114u8, 101u8, 102u8, 105u8, 116u8,
+                    32u8, 107u8, 97u8, 114u8, 101u8, 105u8, 110u8, 195u8, 167u8, 32u8, 107u8, 97u8,
+                    114u8, 101u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 114u8, 101u8,
+                    107u8, 97u8, 114u8, 101u8, 109u8, 101u8, 116u8, 114u8, 101u8, 107u8, 97u8,
+                    114u8, 101u8, 109u8, 105u8, 108u8, 32u8, 107u8, 97u8, 114u8, 101u8, 121u8,
+                    97u8, 114u8, 100u8, 97u8, 32u8, 107u8, 97u8, 114u8, 101u8, 115u8, 116u8, 111u8,
+                    110u8, 101u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8, 116u8, 101u8,
+                    114u8, 97u8, 98u8, 97u8, 121u8, 116u8, 104u8, 97u8, 102u8, 116u8, 97u8, 121u8,
+                    97u8, 114u8, 100u8, 97u8, 121u8, 196u8, 177u8, 108u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 17u8, 0u8, 33u8, 0u8, 43u8, 0u8, 53u8,
+                    0u8, 68u8, 0u8, 79u8, 0u8, 89u8, 0u8, 99u8, 0u8, 109u8, 0u8, 115u8, 0u8, 122u8,
+                    0u8, 130u8, 0u8, 137u8, 0u8, 145u8, 0u8, 153u8, 0u8, 162u8, 0u8, 180u8, 0u8,
+                    199u8, 0u8, 206u8, 0u8, 214u8, 0u8, 230u8, 0u8, 253u8, 0u8, 8u8, 1u8, 23u8,
+                    1u8, 33u8, 1u8, 43u8, 1u8, 48u8, 1u8, 55u8, 1u8, 63u8, 1u8, 80u8, 1u8, 91u8,
+                    1u8, 102u8, 1u8, 118u8, 1u8, 130u8, 1u8, 141u8, 1u8, 152u8, 1u8, 159u8, 1u8,
+                    99u8, 101u8, 108u8, 115u8, 105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8,
+                    105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8,
+                    99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8,
+                    117u8, 98u8, 105u8, 99u8, 45u8, 102u8, 111u8, 111u8, 116u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 45u8, 105u8, 110u8, 99u8, 104u8, 99u8, 117u8, 98u8, 105u8, 99u8,
+                    45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8,
+                    117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8,
+                    98u8, 105u8, 99u8, 45u8, 109u8, 105u8, 108u8, 101u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 121u8, 97u8, 114u8, 100u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8,
+                    104u8, 101u8, 105u8, 116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8,
+                    105u8, 103u8, 97u8, 98u8, 105u8, 116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8,
+                    116u8, 101u8, 107u8, 105u8, 108u8, 111u8, 98u8, 105u8, 116u8, 107u8, 105u8,
+                    108u8, 111u8, 98u8, 121u8, 116u8, 101u8, 107u8, 105u8, 108u8, 111u8, 103u8,
+                    114u8, 97u8, 109u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 108u8, 105u8, 116u8,
+                    101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8, 108u8, 111u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8, 97u8, 98u8, 105u8,
+                    116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8,
+                    111u8, 110u8, 100u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8,
+                    111u8, 110u8, 100u8, 109u8, 105u8, 99u8, 114u8, 111u8, 115u8, 101u8, 99u8,
+                    111u8, 110u8, 100u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8, 108u8,
+                    105u8, 108u8, 105u8, 116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8,
+                    101u8, 114u8, 99u8, 101u8, 110u8, 116u8, 112u8, 101u8, 116u8, 97u8, 98u8,
+                    121u8, 116u8, 101u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8,
+                    101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    109u8, 105u8, 108u8, 101u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    121u8, 97u8, 114u8, 100u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8,
+                    116u8, 101u8, 114u8, 97u8, 98u8, 121u8, 116u8, 101u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 5u8, 0u8, 9u8, 0u8, 14u8, 0u8, 20u8,
+                    0u8, 24u8, 0u8, 27u8, 0u8, 32u8, 0u8, 39u8, 0u8, 42u8, 0u8, 45u8, 0u8, 49u8,
+                    0u8, 51u8, 0u8, 55u8, 0u8, 57u8, 0u8, 59u8, 0u8, 61u8, 0u8, 66u8, 0u8, 70u8,
+                    0u8, 74u8, 0u8, 76u8, 0u8, 80u8, 0u8, 86u8, 0u8, 90u8, 0u8, 97u8, 0u8, 99u8,
+                    0u8, 101u8, 0u8, 103u8, 0u8, 104u8, 0u8, 106u8, 0u8, 110u8, 0u8, 114u8, 0u8,
+                    118u8, 0u8, 122u8, 0u8, 125u8, 0u8, 129u8, 0u8, 133u8, 0u8, 137u8, 0u8, 194u8,
+                    176u8, 67u8, 99u8, 109u8, 99u8, 109u8, 194u8, 179u8, 102u8, 105u8, 116u8,
+                    194u8, 179u8, 105u8, 110u8, 195u8, 167u8, 194u8, 179u8, 107u8, 109u8, 194u8,
+                    179u8, 109u8, 194u8, 179u8, 109u8, 105u8, 108u8, 194u8, 179u8, 121u8, 97u8,
+                    114u8, 100u8, 97u8, 194u8, 179u8, 194u8, 176u8, 70u8, 103u8, 97u8, 108u8, 71u8,
+                    98u8, 105u8, 116u8, 71u8, 66u8, 107u8, 98u8, 105u8, 116u8, 107u8, 66u8, 107u8,
+                    103u8, 107u8, 109u8, 107u8, 109u8, 47u8, 115u8, 97u8, 108u8, 47u8, 107u8,
+                    109u8, 77u8, 98u8, 105u8, 116u8, 77u8, 66u8, 109u8, 47u8, 115u8, 110u8, 109u8,
+                    47u8, 115u8, 110u8, 194u8, 178u8, 206u8, 188u8, 115u8, 110u8, 109u8, 105u8,
+                    108u8, 47u8, 103u8, 97u8, 108u8, 109u8, 108u8, 109u8, 109u8, 111u8, 122u8,
+                    37u8, 80u8, 66u8, 99u8, 109u8, 194u8, 178u8, 102u8, 116u8, 194u8, 178u8, 105u8,
+                    110u8, 194u8, 178u8, 107u8, 109u8, 194u8, 178u8, 109u8, 194u8, 178u8, 109u8,
+                    105u8, 194u8, 178u8, 121u8, 100u8, 194u8, 178u8, 84u8, 98u8, 105u8, 116u8,
+                    84u8, 66u8,
+                ])
+            },
+        )
+    },
+};
+static UND: &DataStruct = &::icu_displaynames::provider::UnitDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    65u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 7u8, 0u8, 11u8, 0u8, 18u8, 0u8, 28u8,
+                    0u8, 44u8, 0u8, 54u8, 0u8, 64u8, 0u8, 79u8, 0u8, 90u8, 0u8, 100u8, 0u8, 110u8,
+                    0u8, 113u8, 0u8, 119u8, 0u8, 129u8, 0u8, 140u8, 0u8, 144u8, 0u8, 150u8, 0u8,
+                    157u8, 0u8, 165u8, 0u8, 169u8, 0u8, 176u8, 0u8, 180u8, 0u8, 184u8, 0u8, 191u8,
+                    0u8, 199u8, 0u8, 207u8, 0u8, 216u8, 0u8, 234u8, 0u8, 239u8, 0u8, 2u8, 1u8, 9u8,
+                    1u8, 17u8, 1u8, 22u8, 1u8, 38u8, 1u8, 61u8, 1u8, 72u8, 1u8, 76u8, 1u8, 91u8,
+                    1u8, 104u8, 1u8, 121u8, 1u8, 131u8, 1u8, 141u8, 1u8, 152u8, 1u8, 158u8, 1u8,
+                    163u8, 1u8, 173u8, 1u8, 178u8, 1u8, 185u8, 1u8, 193u8, 1u8, 198u8, 1u8, 204u8,
+                    1u8, 221u8, 1u8, 232u8, 1u8, 243u8, 1u8, 3u8, 2u8, 15u8, 2u8, 26u8, 2u8, 37u8,
+                    2u8, 42u8, 2u8, 49u8, 2u8, 57u8, 2u8, 61u8, 2u8, 65u8, 2u8, 97u8, 99u8, 114u8,
+                    101u8, 98u8, 105u8, 116u8, 98u8, 121u8, 116u8, 101u8, 99u8, 101u8, 108u8,
+                    115u8, 105u8, 117u8, 115u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8, 110u8,
+                    116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 102u8, 111u8, 111u8, 116u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8,
+                    105u8, 110u8, 99u8, 104u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8,
+                    45u8, 109u8, 105u8, 108u8, 101u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 121u8,
+                    97u8, 114u8, 100u8, 100u8, 97u8, 121u8, 100u8, 101u8, 103u8, 114u8, 101u8,
+                    101u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8,
+                    102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8,
+                    102u8, 111u8, 111u8, 116u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8,
+                    105u8, 103u8, 97u8, 98u8, 105u8, 116u8, 103u8, 105u8, 103u8, 97u8, 98u8, 121u8,
+                    116u8, 101u8, 103u8, 114u8, 97u8, 109u8, 104u8, 101u8, 99u8, 116u8, 97u8,
+                    114u8, 101u8, 104u8, 111u8, 117u8, 114u8, 105u8, 110u8, 99u8, 104u8, 107u8,
+                    105u8, 108u8, 111u8, 98u8, 105u8, 116u8, 107u8, 105u8, 108u8, 111u8, 98u8,
+                    121u8, 116u8, 101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 104u8, 111u8, 117u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 108u8,
+                    105u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 103u8, 97u8,
+                    98u8, 105u8, 116u8, 109u8, 101u8, 103u8, 97u8, 98u8, 121u8, 116u8, 101u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 99u8, 114u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 105u8, 108u8, 101u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 109u8, 105u8, 108u8,
+                    101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 109u8,
+                    105u8, 108u8, 101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8,
+                    118u8, 105u8, 97u8, 110u8, 109u8, 105u8, 108u8, 108u8, 105u8, 108u8, 105u8,
+                    116u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 109u8, 111u8, 110u8,
+                    116u8, 104u8, 110u8, 97u8, 110u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 111u8, 117u8, 110u8, 99u8, 101u8, 112u8, 101u8, 114u8, 99u8, 101u8,
+                    110u8, 116u8, 112u8, 101u8, 116u8, 97u8, 98u8, 121u8, 116u8, 101u8, 112u8,
+                    111u8, 117u8, 110u8, 100u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 102u8, 111u8, 111u8, 116u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 105u8, 110u8, 99u8, 104u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 121u8, 97u8, 114u8, 100u8, 115u8,
+                    116u8, 111u8, 110u8, 101u8, 116u8, 101u8, 114u8, 97u8, 98u8, 105u8, 116u8,
+                    116u8, 101u8, 114u8, 97u8, 98u8, 121u8, 116u8, 101u8, 119u8, 101u8, 101u8,
+                    107u8, 121u8, 97u8, 114u8, 100u8, 121u8, 101u8, 97u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    65u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 7u8, 0u8, 11u8, 0u8, 14u8, 0u8, 16u8,
+                    0u8, 20u8, 0u8, 24u8, 0u8, 28u8, 0u8, 32u8, 0u8, 35u8, 0u8, 39u8, 0u8, 43u8,
+                    0u8, 46u8, 0u8, 49u8, 0u8, 52u8, 0u8, 60u8, 0u8, 62u8, 0u8, 68u8, 0u8, 70u8,
+                    0u8, 72u8, 0u8, 76u8, 0u8, 83u8, 0u8, 85u8, 0u8, 87u8, 0u8, 89u8, 0u8, 91u8,
+                    0u8, 93u8, 0u8, 95u8, 0u8, 99u8, 0u8, 104u8, 0u8, 108u8, 0u8, 110u8, 0u8,
+                    112u8, 0u8, 117u8, 0u8, 120u8, 0u8, 125u8, 0u8, 128u8, 0u8, 130u8, 0u8, 136u8,
+                    0u8, 140u8, 0u8, 143u8, 0u8, 145u8, 0u8, 147u8, 0u8, 149u8, 0u8, 152u8, 0u8,
+                    155u8, 0u8, 157u8, 0u8, 159u8, 0u8, 160u8, 0u8, 162u8, 0u8, 164u8, 0u8, 167u8,
+                    0u8, 171u8, 0u8, 175u8, 0u8, 179u8, 0u8, 183u8, 0u8, 186u8, 0u8, 190u8, 0u8,
+                    194u8, 0u8, 196u8, 0u8, 198u8, 0u8, 200u8, 0u8, 202u8, 0u8, 204u8, 0u8, 97u8,
+                    99u8, 114u8, 101u8, 98u8, 105u8, 116u8, 98u8, 121u8, 116u8, 101u8, 194u8,
+                    176u8, 67u8, 99u8, 109u8, 99u8, 109u8, 194u8, 179u8, 102u8, 116u8, 194u8,
+                    179u8, 105u8, 110u8, 194u8, 179u8, 107u8, 109u8, 194u8, 179u8, 109u8, 194u8,
+                    179u8, 109u8, 105u8, 194u8, 179u8, 121u8, 100u8, 194u8, 179u8, 100u8, 97u8,
+                    121u8, 100u8, 101u8, 103u8, 194u8, 176u8, 70u8, 85u8, 83u8, 32u8, 102u8, 108u8,
+                    32u8, 111u8, 122u8, 102u8, 116u8, 85u8, 83u8, 32u8, 103u8, 97u8, 108u8, 71u8,
+                    98u8, 71u8, 66u8, 103u8, 114u8, 97u8, 109u8, 104u8, 101u8, 99u8, 116u8, 97u8,
+                    114u8, 101u8, 104u8, 114u8, 105u8, 110u8, 107u8, 98u8, 107u8, 66u8, 107u8,
+                    103u8, 107u8, 109u8, 107u8, 109u8, 47u8, 104u8, 108u8, 105u8, 116u8, 101u8,
+                    114u8, 76u8, 47u8, 107u8, 109u8, 77u8, 98u8, 77u8, 66u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 109u8, 47u8, 115u8, 109u8, 47u8, 115u8, 194u8, 178u8, 206u8,
+                    188u8, 115u8, 109u8, 105u8, 109u8, 112u8, 103u8, 32u8, 85u8, 83u8, 109u8,
+                    105u8, 47u8, 104u8, 115u8, 109u8, 105u8, 109u8, 76u8, 109u8, 109u8, 109u8,
+                    115u8, 109u8, 105u8, 110u8, 109u8, 111u8, 110u8, 110u8, 115u8, 111u8, 122u8,
+                    37u8, 80u8, 66u8, 108u8, 98u8, 115u8, 101u8, 99u8, 99u8, 109u8, 194u8, 178u8,
+                    102u8, 116u8, 194u8, 178u8, 105u8, 110u8, 194u8, 178u8, 107u8, 109u8, 194u8,
+                    178u8, 109u8, 194u8, 178u8, 109u8, 105u8, 194u8, 178u8, 121u8, 100u8, 194u8,
+                    178u8, 115u8, 116u8, 84u8, 98u8, 84u8, 66u8, 119u8, 107u8, 121u8, 100u8, 121u8,
+                    114u8,
+                ])
+            },
+        )
+    },
+    short_names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+};
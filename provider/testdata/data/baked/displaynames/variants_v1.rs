@@ -0,0 +1,1409 @@
+// @generated
+#![cfg(feature = "icu_displaynames")]
+type DataStruct = < :: icu_displaynames :: provider :: VariantDisplayNamesV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[
+        ("ar", AR_AR_EG),
+        ("ar-EG", AR_AR_EG),
+        ("bn", BN_CCP_UND),
+        ("ccp", BN_CCP_UND),
+        ("en", EN_EN_001_EN_ZA),
+        ("en-001", EN_EN_001_EN_ZA),
+        ("en-ZA", EN_EN_001_EN_ZA),
+        ("es", ES_ES_AR),
+        ("es-AR", ES_ES_AR),
+        ("fil", FIL),
+        ("fr", FR),
+        ("ja", JA),
+        ("ru", RU),
+        ("sr", SR_SR_CYRL),
+        ("sr-Cyrl", SR_SR_CYRL),
+        ("sr-Latn", SR_LATN),
+        ("th", TH),
+        ("tr", TR),
+        ("und", BN_CCP_UND),
+    ]);
+static AR_AR_EG: &DataStruct = &::icu_displaynames::provider::VariantDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    49u8, 57u8, 48u8, 49u8, 0u8, 0u8, 0u8, 0u8, 49u8, 57u8, 53u8, 57u8, 97u8, 99u8,
+                    97u8, 100u8, 49u8, 57u8, 57u8, 54u8, 0u8, 0u8, 0u8, 0u8, 97u8, 114u8, 101u8,
+                    118u8, 101u8, 108u8, 97u8, 0u8, 97u8, 114u8, 101u8, 118u8, 109u8, 100u8, 97u8,
+                    0u8, 98u8, 97u8, 107u8, 117u8, 49u8, 57u8, 50u8, 54u8, 107u8, 107u8, 99u8,
+                    111u8, 114u8, 0u8, 0u8, 0u8, 109u8, 111u8, 110u8, 111u8, 116u8, 111u8, 110u8,
+                    0u8, 110u8, 101u8, 100u8, 105u8, 115u8, 0u8, 0u8, 0u8, 112u8, 105u8, 110u8,
+                    121u8, 105u8, 110u8, 0u8, 0u8, 112u8, 111u8, 108u8, 121u8, 116u8, 111u8, 110u8,
+                    0u8, 112u8, 111u8, 115u8, 105u8, 120u8, 0u8, 0u8, 0u8, 114u8, 101u8, 118u8,
+                    105u8, 115u8, 101u8, 100u8, 0u8, 115u8, 99u8, 111u8, 116u8, 108u8, 97u8, 110u8,
+                    100u8, 117u8, 99u8, 99u8, 111u8, 114u8, 0u8, 0u8, 0u8, 117u8, 99u8, 114u8,
+                    99u8, 111u8, 114u8, 0u8, 0u8, 118u8, 97u8, 108u8, 101u8, 110u8, 99u8, 105u8,
+                    97u8, 119u8, 97u8, 100u8, 101u8, 103u8, 105u8, 108u8, 101u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    18u8, 0u8, 0u8, 0u8, 0u8, 0u8, 52u8, 0u8, 66u8, 0u8, 113u8, 0u8, 136u8, 0u8,
+                    159u8, 0u8, 224u8, 0u8, 251u8, 0u8, 18u8, 1u8, 41u8, 1u8, 74u8, 1u8, 99u8, 1u8,
+                    109u8, 1u8, 143u8, 1u8, 203u8, 1u8, 232u8, 1u8, 22u8, 2u8, 34u8, 2u8, 216u8,
+                    167u8, 217u8, 132u8, 216u8, 170u8, 217u8, 135u8, 216u8, 172u8, 216u8, 166u8,
+                    216u8, 169u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 217u8, 132u8,
+                    217u8, 133u8, 216u8, 167u8, 217u8, 134u8, 217u8, 138u8, 216u8, 169u8, 32u8,
+                    216u8, 167u8, 217u8, 132u8, 216u8, 170u8, 217u8, 130u8, 217u8, 132u8, 217u8,
+                    138u8, 216u8, 175u8, 217u8, 138u8, 216u8, 169u8, 216u8, 163u8, 217u8, 131u8,
+                    216u8, 167u8, 216u8, 175u8, 217u8, 138u8, 217u8, 133u8, 217u8, 138u8, 216u8,
+                    167u8, 217u8, 132u8, 216u8, 170u8, 217u8, 135u8, 216u8, 172u8, 216u8, 166u8,
+                    216u8, 169u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 217u8, 132u8,
+                    217u8, 133u8, 216u8, 167u8, 217u8, 134u8, 217u8, 138u8, 216u8, 169u8, 32u8,
+                    217u8, 132u8, 216u8, 185u8, 216u8, 167u8, 217u8, 133u8, 32u8, 49u8, 57u8, 57u8,
+                    54u8, 216u8, 163u8, 216u8, 177u8, 217u8, 133u8, 217u8, 134u8, 217u8, 138u8,
+                    216u8, 169u8, 32u8, 216u8, 180u8, 216u8, 177u8, 217u8, 130u8, 217u8, 138u8,
+                    216u8, 169u8, 216u8, 163u8, 216u8, 177u8, 217u8, 133u8, 217u8, 134u8, 217u8,
+                    138u8, 216u8, 169u8, 32u8, 216u8, 186u8, 216u8, 177u8, 216u8, 168u8, 217u8,
+                    138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 216u8, 168u8,
+                    216u8, 172u8, 216u8, 175u8, 217u8, 138u8, 216u8, 169u8, 32u8, 216u8, 167u8,
+                    217u8, 132u8, 216u8, 170u8, 216u8, 177u8, 217u8, 131u8, 217u8, 138u8, 216u8,
+                    169u8, 32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 132u8, 216u8, 167u8, 216u8,
+                    170u8, 217u8, 138u8, 217u8, 134u8, 217u8, 138u8, 216u8, 169u8, 32u8, 216u8,
+                    167u8, 217u8, 132u8, 217u8, 133u8, 217u8, 136u8, 216u8, 173u8, 216u8, 175u8,
+                    216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 170u8, 217u8, 135u8, 216u8,
+                    172u8, 216u8, 166u8, 216u8, 169u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8,
+                    185u8, 216u8, 167u8, 217u8, 133u8, 216u8, 169u8, 216u8, 163u8, 216u8, 173u8,
+                    216u8, 167u8, 216u8, 175u8, 217u8, 138u8, 32u8, 216u8, 167u8, 217u8, 132u8,
+                    217u8, 134u8, 216u8, 186u8, 217u8, 133u8, 216u8, 169u8, 217u8, 132u8, 217u8,
+                    135u8, 216u8, 172u8, 216u8, 169u8, 32u8, 217u8, 134u8, 216u8, 167u8, 216u8,
+                    170u8, 217u8, 138u8, 216u8, 179u8, 217u8, 136u8, 217u8, 134u8, 216u8, 168u8,
+                    217u8, 138u8, 217u8, 134u8, 217u8, 138u8, 217u8, 138u8, 217u8, 134u8, 32u8,
+                    216u8, 168u8, 216u8, 167u8, 217u8, 132u8, 217u8, 132u8, 216u8, 167u8, 216u8,
+                    170u8, 217u8, 138u8, 217u8, 134u8, 217u8, 138u8, 216u8, 169u8, 217u8, 133u8,
+                    216u8, 170u8, 216u8, 185u8, 216u8, 175u8, 216u8, 175u8, 32u8, 216u8, 167u8,
+                    217u8, 132u8, 217u8, 134u8, 216u8, 186u8, 217u8, 133u8, 216u8, 167u8, 216u8,
+                    170u8, 216u8, 173u8, 216u8, 167u8, 216u8, 179u8, 217u8, 136u8, 216u8, 168u8,
+                    216u8, 170u8, 217u8, 135u8, 216u8, 172u8, 216u8, 166u8, 216u8, 169u8, 32u8,
+                    216u8, 170u8, 217u8, 133u8, 216u8, 170u8, 32u8, 217u8, 133u8, 216u8, 177u8,
+                    216u8, 167u8, 216u8, 172u8, 216u8, 185u8, 216u8, 170u8, 217u8, 135u8, 216u8,
+                    167u8, 216u8, 167u8, 217u8, 132u8, 216u8, 165u8, 217u8, 134u8, 216u8, 172u8,
+                    217u8, 132u8, 217u8, 138u8, 216u8, 178u8, 217u8, 138u8, 216u8, 169u8, 32u8,
+                    216u8, 167u8, 217u8, 132u8, 216u8, 163u8, 216u8, 179u8, 217u8, 131u8, 216u8,
+                    170u8, 217u8, 132u8, 217u8, 134u8, 216u8, 175u8, 217u8, 134u8, 217u8, 138u8,
+                    216u8, 169u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 177u8, 216u8, 179u8,
+                    217u8, 133u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8,
+                    170u8, 217u8, 135u8, 216u8, 172u8, 216u8, 166u8, 216u8, 169u8, 32u8, 216u8,
+                    167u8, 217u8, 132u8, 217u8, 133u8, 217u8, 136u8, 216u8, 173u8, 216u8, 175u8,
+                    216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 216u8, 170u8, 217u8, 135u8, 216u8,
+                    172u8, 216u8, 166u8, 216u8, 169u8, 32u8, 216u8, 167u8, 217u8, 132u8, 217u8,
+                    133u8, 216u8, 177u8, 216u8, 167u8, 216u8, 172u8, 216u8, 185u8, 216u8, 169u8,
+                    32u8, 216u8, 167u8, 217u8, 132u8, 217u8, 133u8, 217u8, 136u8, 216u8, 173u8,
+                    216u8, 175u8, 216u8, 169u8, 216u8, 168u8, 217u8, 132u8, 217u8, 134u8, 216u8,
+                    179u8, 217u8, 138u8, 216u8, 169u8, 216u8, 167u8, 217u8, 132u8, 217u8, 133u8,
+                    217u8, 134u8, 216u8, 175u8, 216u8, 177u8, 217u8, 138u8, 217u8, 134u8, 32u8,
+                    216u8, 168u8, 216u8, 167u8, 217u8, 132u8, 217u8, 132u8, 216u8, 167u8, 216u8,
+                    170u8, 217u8, 138u8, 217u8, 134u8, 217u8, 138u8, 216u8, 169u8, 32u8, 45u8,
+                    32u8, 217u8, 136u8, 217u8, 138u8, 216u8, 175u8, 226u8, 128u8, 147u8, 216u8,
+                    172u8, 217u8, 138u8, 217u8, 132u8, 216u8, 178u8,
+                ])
+            },
+        )
+    },
+};
+static BN_CCP_UND: &DataStruct = &::icu_displaynames::provider::VariantDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[]) },
+            unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        )
+    },
+};
+static EN_EN_001_EN_ZA: &DataStruct = &::icu_displaynames::provider::VariantDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    49u8, 54u8, 48u8, 54u8, 110u8, 105u8, 99u8, 116u8, 49u8, 54u8, 57u8, 52u8,
+                    97u8, 99u8, 97u8, 100u8, 49u8, 57u8, 48u8, 49u8, 0u8, 0u8, 0u8, 0u8, 49u8,
+                    57u8, 53u8, 57u8, 97u8, 99u8, 97u8, 100u8, 49u8, 57u8, 57u8, 52u8, 0u8, 0u8,
+                    0u8, 0u8, 49u8, 57u8, 57u8, 54u8, 0u8, 0u8, 0u8, 0u8, 97u8, 98u8, 108u8, 49u8,
+                    57u8, 52u8, 51u8, 0u8, 97u8, 108u8, 97u8, 108u8, 99u8, 57u8, 55u8, 0u8, 97u8,
+                    108u8, 117u8, 107u8, 117u8, 0u8, 0u8, 0u8, 97u8, 111u8, 49u8, 57u8, 57u8, 48u8,
+                    0u8, 0u8, 97u8, 114u8, 101u8, 118u8, 101u8, 108u8, 97u8, 0u8, 97u8, 114u8,
+                    101u8, 118u8, 109u8, 100u8, 97u8, 0u8, 98u8, 97u8, 107u8, 117u8, 49u8, 57u8,
+                    50u8, 54u8, 98u8, 97u8, 108u8, 97u8, 110u8, 107u8, 97u8, 0u8, 98u8, 97u8,
+                    114u8, 108u8, 97u8, 0u8, 0u8, 0u8, 98u8, 105u8, 115u8, 107u8, 101u8, 0u8, 0u8,
+                    0u8, 98u8, 111u8, 104u8, 111u8, 114u8, 105u8, 99u8, 0u8, 98u8, 111u8, 111u8,
+                    110u8, 116u8, 0u8, 0u8, 0u8, 99u8, 111u8, 108u8, 98u8, 49u8, 57u8, 52u8, 53u8,
+                    100u8, 97u8, 106u8, 110u8, 107u8, 111u8, 0u8, 0u8, 101u8, 107u8, 97u8, 118u8,
+                    115u8, 107u8, 0u8, 0u8, 101u8, 109u8, 111u8, 100u8, 101u8, 110u8, 103u8, 0u8,
+                    102u8, 111u8, 110u8, 105u8, 112u8, 97u8, 0u8, 0u8, 102u8, 111u8, 110u8, 117u8,
+                    112u8, 97u8, 0u8, 0u8, 104u8, 101u8, 112u8, 98u8, 117u8, 114u8, 110u8, 0u8,
+                    105u8, 106u8, 101u8, 107u8, 97u8, 118u8, 115u8, 107u8, 107u8, 107u8, 99u8,
+                    111u8, 114u8, 0u8, 0u8, 0u8, 107u8, 115u8, 99u8, 111u8, 114u8, 0u8, 0u8, 0u8,
+                    108u8, 105u8, 112u8, 97u8, 119u8, 0u8, 0u8, 0u8, 109u8, 101u8, 116u8, 101u8,
+                    108u8, 107u8, 111u8, 0u8, 109u8, 111u8, 110u8, 111u8, 116u8, 111u8, 110u8, 0u8,
+                    110u8, 100u8, 121u8, 117u8, 107u8, 97u8, 0u8, 0u8, 110u8, 101u8, 100u8, 105u8,
+                    115u8, 0u8, 0u8, 0u8, 110u8, 106u8, 105u8, 118u8, 97u8, 0u8, 0u8, 0u8, 110u8,
+                    117u8, 108u8, 105u8, 107u8, 0u8, 0u8, 0u8, 111u8, 115u8, 111u8, 106u8, 115u8,
+                    0u8, 0u8, 0u8, 111u8, 120u8, 101u8, 110u8, 100u8, 105u8, 99u8, 116u8, 112u8,
+                    97u8, 109u8, 97u8, 107u8, 97u8, 0u8, 0u8, 112u8, 105u8, 110u8, 121u8, 105u8,
+                    110u8, 0u8, 0u8, 112u8, 111u8, 108u8, 121u8, 116u8, 111u8, 110u8, 0u8, 112u8,
+                    111u8, 115u8, 105u8, 120u8, 0u8, 0u8, 0u8, 114u8, 101u8, 118u8, 105u8, 115u8,
+                    101u8, 100u8, 0u8, 114u8, 105u8, 103u8, 105u8, 107u8, 0u8, 0u8, 0u8, 114u8,
+                    111u8, 122u8, 97u8, 106u8, 0u8, 0u8, 0u8, 115u8, 97u8, 97u8, 104u8, 111u8, 0u8,
+                    0u8, 0u8, 115u8, 99u8, 111u8, 116u8, 108u8, 97u8, 110u8, 100u8, 115u8, 99u8,
+                    111u8, 117u8, 115u8, 101u8, 0u8, 0u8, 115u8, 111u8, 108u8, 98u8, 97u8, 0u8,
+                    0u8, 0u8, 115u8, 111u8, 116u8, 97u8, 118u8, 0u8, 0u8, 0u8, 116u8, 97u8, 114u8,
+                    97u8, 115u8, 107u8, 0u8, 0u8, 117u8, 99u8, 99u8, 111u8, 114u8, 0u8, 0u8, 0u8,
+                    117u8, 99u8, 114u8, 99u8, 111u8, 114u8, 0u8, 0u8, 117u8, 110u8, 105u8, 102u8,
+                    111u8, 110u8, 0u8, 0u8, 118u8, 97u8, 108u8, 101u8, 110u8, 99u8, 105u8, 97u8,
+                    119u8, 97u8, 100u8, 101u8, 103u8, 105u8, 108u8, 101u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    55u8, 0u8, 0u8, 0u8, 0u8, 0u8, 26u8, 0u8, 45u8, 0u8, 75u8, 0u8, 83u8, 0u8,
+                    114u8, 0u8, 140u8, 0u8, 172u8, 0u8, 205u8, 0u8, 218u8, 0u8, 12u8, 1u8, 28u8,
+                    1u8, 44u8, 1u8, 73u8, 1u8, 96u8, 1u8, 136u8, 1u8, 160u8, 1u8, 177u8, 1u8,
+                    186u8, 1u8, 238u8, 1u8, 253u8, 1u8, 31u8, 2u8, 51u8, 2u8, 64u8, 2u8, 77u8, 2u8,
+                    97u8, 2u8, 133u8, 2u8, 151u8, 2u8, 171u8, 2u8, 200u8, 2u8, 216u8, 2u8, 225u8,
+                    2u8, 239u8, 2u8, 255u8, 2u8, 18u8, 3u8, 33u8, 3u8, 56u8, 3u8, 90u8, 3u8, 104u8,
+                    3u8, 123u8, 3u8, 132u8, 3u8, 140u8, 3u8, 159u8, 3u8, 175u8, 3u8, 181u8, 3u8,
+                    185u8, 3u8, 210u8, 3u8, 216u8, 3u8, 241u8, 3u8, 24u8, 4u8, 48u8, 4u8, 67u8,
+                    4u8, 94u8, 4u8, 118u8, 4u8, 127u8, 4u8, 76u8, 97u8, 116u8, 101u8, 32u8, 77u8,
+                    105u8, 100u8, 100u8, 108u8, 101u8, 32u8, 70u8, 114u8, 101u8, 110u8, 99u8,
+                    104u8, 32u8, 116u8, 111u8, 32u8, 49u8, 54u8, 48u8, 54u8, 69u8, 97u8, 114u8,
+                    108u8, 121u8, 32u8, 77u8, 111u8, 100u8, 101u8, 114u8, 110u8, 32u8, 70u8, 114u8,
+                    101u8, 110u8, 99u8, 104u8, 84u8, 114u8, 97u8, 100u8, 105u8, 116u8, 105u8,
+                    111u8, 110u8, 97u8, 108u8, 32u8, 71u8, 101u8, 114u8, 109u8, 97u8, 110u8, 32u8,
+                    111u8, 114u8, 116u8, 104u8, 111u8, 103u8, 114u8, 97u8, 112u8, 104u8, 121u8,
+                    65u8, 99u8, 97u8, 100u8, 101u8, 109u8, 105u8, 99u8, 83u8, 116u8, 97u8, 110u8,
+                    100u8, 97u8, 114u8, 100u8, 105u8, 122u8, 101u8, 100u8, 32u8, 82u8, 101u8,
+                    115u8, 105u8, 97u8, 110u8, 32u8, 111u8, 114u8, 116u8, 104u8, 111u8, 103u8,
+                    114u8, 97u8, 112u8, 104u8, 121u8, 71u8, 101u8, 114u8, 109u8, 97u8, 110u8, 32u8,
+                    111u8, 114u8, 116u8, 104u8, 111u8, 103u8, 114u8, 97u8, 112u8, 104u8, 121u8,
+                    32u8, 111u8, 102u8, 32u8, 49u8, 57u8, 57u8, 54u8, 79u8, 114u8, 116u8, 104u8,
+                    111u8, 103u8, 114u8, 97u8, 112u8, 104u8, 105u8, 99u8, 32u8, 102u8, 111u8,
+                    114u8, 109u8, 117u8, 108u8, 97u8, 116u8, 105u8, 111u8, 110u8, 32u8, 111u8,
+                    102u8, 32u8, 49u8, 57u8, 52u8, 51u8, 65u8, 76u8, 65u8, 45u8, 76u8, 67u8, 32u8,
+                    82u8, 111u8, 109u8, 97u8, 110u8, 105u8, 122u8, 97u8, 116u8, 105u8, 111u8,
+                    110u8, 44u8, 32u8, 49u8, 57u8, 57u8, 55u8, 32u8, 101u8, 100u8, 105u8, 116u8,
+                    105u8, 111u8, 110u8, 65u8, 108u8, 117u8, 107u8, 117u8, 32u8, 100u8, 105u8,
+                    97u8, 108u8, 101u8, 99u8, 116u8, 80u8, 111u8, 114u8, 116u8, 117u8, 103u8,
+                    117u8, 101u8, 115u8, 101u8, 32u8, 76u8, 97u8, 110u8, 103u8, 117u8, 97u8, 103u8,
+                    101u8, 32u8, 79u8, 114u8, 116u8, 104u8, 111u8, 103u8, 114u8, 97u8, 112u8,
+                    104u8, 105u8, 99u8, 32u8, 65u8, 103u8, 114u8, 101u8, 101u8, 109u8, 101u8,
+                    110u8, 116u8, 32u8, 111u8, 102u8, 32u8, 49u8, 57u8, 57u8, 48u8, 69u8, 97u8,
+                    115u8, 116u8, 101u8, 114u8, 110u8, 32u8, 65u8, 114u8, 109u8, 101u8, 110u8,
+                    105u8, 97u8, 110u8, 87u8, 101u8, 115u8, 116u8, 101u8, 114u8, 110u8, 32u8, 65u8,
+                    114u8, 109u8, 101u8, 110u8, 105u8, 97u8, 110u8, 85u8, 110u8, 105u8, 102u8,
+                    105u8, 101u8, 100u8, 32u8, 84u8, 117u8, 114u8, 107u8, 105u8, 99u8, 32u8, 76u8,
+                    97u8, 116u8, 105u8, 110u8, 32u8, 65u8, 108u8, 112u8, 104u8, 97u8, 98u8, 101u8,
+                    116u8, 66u8, 97u8, 108u8, 97u8, 110u8, 107u8, 97u8, 32u8, 100u8, 105u8, 97u8,
+                    108u8, 101u8, 99u8, 116u8, 32u8, 111u8, 102u8, 32u8, 65u8, 110u8, 105u8, 105u8,
+                    66u8, 97u8, 114u8, 108u8, 97u8, 118u8, 101u8, 110u8, 116u8, 111u8, 32u8, 100u8,
+                    105u8, 97u8, 108u8, 101u8, 99u8, 116u8, 32u8, 103u8, 114u8, 111u8, 117u8,
+                    112u8, 32u8, 111u8, 102u8, 32u8, 75u8, 97u8, 98u8, 117u8, 118u8, 101u8, 114u8,
+                    100u8, 105u8, 97u8, 110u8, 117u8, 83u8, 97u8, 110u8, 32u8, 71u8, 105u8, 111u8,
+                    114u8, 103u8, 105u8, 111u8, 47u8, 66u8, 105u8, 108u8, 97u8, 32u8, 100u8, 105u8,
+                    97u8, 108u8, 101u8, 99u8, 116u8, 66u8, 111u8, 104u8, 111u8, 114u8, 105u8,
+                    196u8, 141u8, 32u8, 97u8, 108u8, 112u8, 104u8, 97u8, 98u8, 101u8, 116u8, 66u8,
+                    111u8, 111u8, 110u8, 116u8, 108u8, 105u8, 110u8, 103u8, 80u8, 111u8, 114u8,
+                    116u8, 117u8, 103u8, 117u8, 101u8, 115u8, 101u8, 45u8, 66u8, 114u8, 97u8,
+                    122u8, 105u8, 108u8, 105u8, 97u8, 110u8, 32u8, 79u8, 114u8, 116u8, 104u8,
+                    111u8, 103u8, 114u8, 97u8, 112u8, 104u8, 105u8, 99u8, 32u8, 67u8, 111u8, 110u8,
+                    118u8, 101u8, 110u8, 116u8, 105u8, 111u8, 110u8, 32u8, 111u8, 102u8, 32u8,
+                    49u8, 57u8, 52u8, 53u8, 68u8, 97u8, 106u8, 110u8, 107u8, 111u8, 32u8, 97u8,
+                    108u8, 112u8, 104u8, 97u8, 98u8, 101u8, 116u8, 83u8, 101u8, 114u8, 98u8, 105u8,
+                    97u8, 110u8, 32u8, 119u8, 105u8, 116u8, 104u8, 32u8, 69u8, 107u8, 97u8, 118u8,
+                    105u8, 97u8, 110u8, 32u8, 112u8, 114u8, 111u8, 110u8, 117u8, 110u8, 99u8,
+                    105u8, 97u8, 116u8, 105u8, 111u8, 110u8, 69u8, 97u8, 114u8, 108u8, 121u8, 32u8,
+                    77u8, 111u8, 100u8, 101u8, 114u8, 110u8, 32u8, 69u8, 110u8, 103u8, 108u8,
+                    105u8, 115u8, 104u8, 73u8, 80u8, 65u8, 32u8, 80u8, 104u8, 111u8, 110u8, 101u8,
+                    116u8, 105u8, 99u8, 115u8, 85u8, 80u8, 65u8, 32u8, 80u8, 104u8, 111u8, 110u8,
+                    101u8, 116u8, 105u8, 99u8, 115u8, 72u8, 101u8, 112u8, 98u8, 117u8, 114u8,
+                    110u8, 32u8, 114u8, 111u8, 109u8, 97u8, 110u8, 105u8, 122u8, 97u8, 116u8,
+                    105u8, 111u8, 110u8, 83u8, 101u8, 114u8, 98u8, 105u8, 97u8, 110u8, 32u8, 119u8,
+                    105u8, 116u8, 104u8, 32u8, 73u8, 106u8, 101u8, 107u8, 97u8, 118u8, 105u8, 97u8,
+                    110u8, 32u8, 112u8, 114u8, 111u8, 110u8, 117u8, 110u8, 99u8, 105u8, 97u8,
+                    116u8, 105u8, 111u8, 110u8, 67u8, 111u8, 109u8, 109u8, 111u8, 110u8, 32u8,
+                    79u8, 114u8, 116u8, 104u8, 111u8, 103u8, 114u8, 97u8, 112u8, 104u8, 121u8,
+                    83u8, 116u8, 97u8, 110u8, 100u8, 97u8, 114u8, 100u8, 32u8, 79u8, 114u8, 116u8,
+                    104u8, 111u8, 103u8, 114u8, 97u8, 112u8, 104u8, 121u8, 84u8, 104u8, 101u8,
+                    32u8, 76u8, 105u8, 112u8, 111u8, 118u8, 97u8, 122u8, 32u8, 100u8, 105u8, 97u8,
+                    108u8, 101u8, 99u8, 116u8, 32u8, 111u8, 102u8, 32u8, 82u8, 101u8, 115u8, 105u8,
+                    97u8, 110u8, 77u8, 101u8, 116u8, 101u8, 108u8, 107u8, 111u8, 32u8, 97u8, 108u8,
+                    112u8, 104u8, 97u8, 98u8, 101u8, 116u8, 77u8, 111u8, 110u8, 111u8, 116u8,
+                    111u8, 110u8, 105u8, 99u8, 78u8, 100u8, 121u8, 117u8, 107u8, 97u8, 32u8, 100u8,
+                    105u8, 97u8, 108u8, 101u8, 99u8, 116u8, 78u8, 97u8, 116u8, 105u8, 115u8, 111u8,
+                    110u8, 101u8, 32u8, 100u8, 105u8, 97u8, 108u8, 101u8, 99u8, 116u8, 71u8, 110u8,
+                    105u8, 118u8, 97u8, 47u8, 78u8, 106u8, 105u8, 118u8, 97u8, 32u8, 100u8, 105u8,
+                    97u8, 108u8, 101u8, 99u8, 116u8, 77u8, 111u8, 100u8, 101u8, 114u8, 110u8, 32u8,
+                    86u8, 111u8, 108u8, 97u8, 112u8, 195u8, 188u8, 107u8, 79u8, 115u8, 101u8, 97u8,
+                    99u8, 99u8, 111u8, 47u8, 79u8, 115u8, 111u8, 106u8, 97u8, 110u8, 101u8, 32u8,
+                    100u8, 105u8, 97u8, 108u8, 101u8, 99u8, 116u8, 79u8, 120u8, 102u8, 111u8,
+                    114u8, 100u8, 32u8, 69u8, 110u8, 103u8, 108u8, 105u8, 115u8, 104u8, 32u8, 68u8,
+                    105u8, 99u8, 116u8, 105u8, 111u8, 110u8, 97u8, 114u8, 121u8, 32u8, 115u8,
+                    112u8, 101u8, 108u8, 108u8, 105u8, 110u8, 103u8, 80u8, 97u8, 109u8, 97u8,
+                    107u8, 97u8, 32u8, 100u8, 105u8, 97u8, 108u8, 101u8, 99u8, 116u8, 80u8, 105u8,
+                    110u8, 121u8, 105u8, 110u8, 32u8, 82u8, 111u8, 109u8, 97u8, 110u8, 105u8,
+                    122u8, 97u8, 116u8, 105u8, 111u8, 110u8, 80u8, 111u8, 108u8, 121u8, 116u8,
+                    111u8, 110u8, 105u8, 99u8, 67u8, 111u8, 109u8, 112u8, 117u8, 116u8, 101u8,
+                    114u8, 82u8, 101u8, 118u8, 105u8, 115u8, 101u8, 100u8, 32u8, 79u8, 114u8,
+                    116u8, 104u8, 111u8, 103u8, 114u8, 97u8, 112u8, 104u8, 121u8, 67u8, 108u8,
+                    97u8, 115u8, 115u8, 105u8, 99u8, 32u8, 86u8, 111u8, 108u8, 97u8, 112u8, 195u8,
+                    188u8, 107u8, 82u8, 101u8, 115u8, 105u8, 97u8, 110u8, 83u8, 97u8, 104u8, 111u8,
+                    83u8, 99u8, 111u8, 116u8, 116u8, 105u8, 115u8, 104u8, 32u8, 83u8, 116u8, 97u8,
+                    110u8, 100u8, 97u8, 114u8, 100u8, 32u8, 69u8, 110u8, 103u8, 108u8, 105u8,
+                    115u8, 104u8, 83u8, 99u8, 111u8, 117u8, 115u8, 101u8, 83u8, 116u8, 111u8,
+                    108u8, 118u8, 105u8, 122u8, 122u8, 97u8, 47u8, 83u8, 111u8, 108u8, 98u8, 105u8,
+                    99u8, 97u8, 32u8, 100u8, 105u8, 97u8, 108u8, 101u8, 99u8, 116u8, 83u8, 111u8,
+                    116u8, 97u8, 118u8, 101u8, 110u8, 116u8, 111u8, 32u8, 100u8, 105u8, 97u8,
+                    108u8, 101u8, 99u8, 116u8, 32u8, 103u8, 114u8, 111u8, 117u8, 112u8, 32u8,
+                    111u8, 102u8, 32u8, 75u8, 97u8, 98u8, 117u8, 118u8, 101u8, 114u8, 100u8, 105u8,
+                    97u8, 110u8, 117u8, 84u8, 97u8, 114u8, 97u8, 115u8, 107u8, 105u8, 101u8, 118u8,
+                    105u8, 99u8, 97u8, 32u8, 111u8, 114u8, 116u8, 104u8, 111u8, 103u8, 114u8, 97u8,
+                    112u8, 104u8, 121u8, 85u8, 110u8, 105u8, 102u8, 105u8, 101u8, 100u8, 32u8,
+                    79u8, 114u8, 116u8, 104u8, 111u8, 103u8, 114u8, 97u8, 112u8, 104u8, 121u8,
+                    85u8, 110u8, 105u8, 102u8, 105u8, 101u8, 100u8, 32u8, 82u8, 101u8, 118u8,
+                    105u8, 115u8, 101u8, 100u8, 32u8, 79u8, 114u8, 116u8, 104u8, 111u8, 103u8,
+                    114u8, 97u8, 112u8, 104u8, 121u8, 85u8, 110u8, 105u8, 102u8, 111u8, 110u8,
+                    32u8, 112u8, 104u8, 111u8, 110u8, 101u8, 116u8, 105u8, 99u8, 32u8, 97u8, 108u8,
+                    112u8, 104u8, 97u8, 98u8, 101u8, 116u8, 86u8, 97u8, 108u8, 101u8, 110u8, 99u8,
+                    105u8, 97u8, 110u8, 87u8, 97u8, 100u8, 101u8, 45u8, 71u8, 105u8, 108u8, 101u8,
+                    115u8, 32u8, 82u8, 111u8, 109u8, 97u8, 110u8, 105u8, 122u8, 97u8, 116u8, 105u8,
+                    111u8, 110u8,
+                ])
+            },
+        )
+    },
+};
+static ES_ES_AR: &DataStruct = &::icu_displaynames::provider::VariantDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    49u8, 57u8, 48u8, 49u8, 0u8, 0u8, 0u8, 0u8, 49u8, 57u8, 57u8, 54u8, 0u8, 0u8,
+                    0u8, 0u8, 97u8, 114u8, 101u8, 118u8, 101u8, 108u8, 97u8, 0u8, 102u8, 111u8,
+                    110u8, 105u8, 112u8, 97u8, 0u8, 0u8, 109u8, 111u8, 110u8, 111u8, 116u8, 111u8,
+                    110u8, 0u8, 112u8, 105u8, 110u8, 121u8, 105u8, 110u8, 0u8, 0u8, 112u8, 111u8,
+                    108u8, 121u8, 116u8, 111u8, 110u8, 0u8, 112u8, 111u8, 115u8, 105u8, 120u8, 0u8,
+                    0u8, 0u8, 114u8, 101u8, 118u8, 105u8, 115u8, 101u8, 100u8, 0u8, 115u8, 99u8,
+                    111u8, 116u8, 108u8, 97u8, 110u8, 100u8, 118u8, 97u8, 108u8, 101u8, 110u8,
+                    99u8, 105u8, 97u8, 119u8, 97u8, 100u8, 101u8, 103u8, 105u8, 108u8, 101u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    12u8, 0u8, 0u8, 0u8, 0u8, 0u8, 31u8, 0u8, 58u8, 0u8, 74u8, 0u8, 110u8, 0u8,
+                    121u8, 0u8, 141u8, 0u8, 152u8, 0u8, 161u8, 0u8, 181u8, 0u8, 207u8, 0u8, 217u8,
+                    0u8, 79u8, 114u8, 116u8, 111u8, 103u8, 114u8, 97u8, 102u8, 195u8, 173u8, 97u8,
+                    32u8, 97u8, 108u8, 101u8, 109u8, 97u8, 110u8, 97u8, 32u8, 116u8, 114u8, 97u8,
+                    100u8, 105u8, 99u8, 105u8, 111u8, 110u8, 97u8, 108u8, 79u8, 114u8, 116u8,
+                    111u8, 103u8, 114u8, 97u8, 102u8, 195u8, 173u8, 97u8, 32u8, 97u8, 108u8, 101u8,
+                    109u8, 97u8, 110u8, 97u8, 32u8, 100u8, 101u8, 32u8, 49u8, 57u8, 57u8, 54u8,
+                    65u8, 114u8, 109u8, 101u8, 110u8, 105u8, 111u8, 32u8, 111u8, 114u8, 105u8,
+                    101u8, 110u8, 116u8, 97u8, 108u8, 65u8, 108u8, 102u8, 97u8, 98u8, 101u8, 116u8,
+                    111u8, 32u8, 102u8, 111u8, 110u8, 195u8, 169u8, 116u8, 105u8, 99u8, 111u8,
+                    32u8, 105u8, 110u8, 116u8, 101u8, 114u8, 110u8, 97u8, 99u8, 105u8, 111u8,
+                    110u8, 97u8, 108u8, 32u8, 73u8, 80u8, 65u8, 77u8, 111u8, 110u8, 111u8, 116u8,
+                    195u8, 179u8, 110u8, 105u8, 99u8, 111u8, 82u8, 111u8, 109u8, 97u8, 110u8,
+                    105u8, 122u8, 97u8, 99u8, 105u8, 195u8, 179u8, 110u8, 32u8, 112u8, 105u8,
+                    110u8, 121u8, 105u8, 110u8, 80u8, 111u8, 108u8, 105u8, 116u8, 195u8, 179u8,
+                    110u8, 105u8, 99u8, 111u8, 79u8, 114u8, 100u8, 101u8, 110u8, 97u8, 100u8,
+                    111u8, 114u8, 79u8, 114u8, 116u8, 111u8, 103u8, 114u8, 97u8, 102u8, 195u8,
+                    173u8, 97u8, 32u8, 114u8, 101u8, 118u8, 105u8, 115u8, 97u8, 100u8, 97u8, 73u8,
+                    110u8, 103u8, 108u8, 195u8, 169u8, 115u8, 32u8, 101u8, 115u8, 99u8, 111u8,
+                    99u8, 195u8, 169u8, 115u8, 32u8, 101u8, 115u8, 116u8, 195u8, 161u8, 110u8,
+                    100u8, 97u8, 114u8, 86u8, 97u8, 108u8, 101u8, 110u8, 99u8, 105u8, 97u8, 110u8,
+                    111u8, 82u8, 111u8, 109u8, 97u8, 110u8, 105u8, 122u8, 97u8, 99u8, 105u8, 195u8,
+                    179u8, 110u8, 32u8, 87u8, 97u8, 100u8, 101u8, 45u8, 71u8, 105u8, 108u8, 101u8,
+                    115u8,
+                ])
+            },
+        )
+    },
+};
+static FIL: &DataStruct = &::icu_displaynames::provider::VariantDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    112u8, 105u8, 110u8, 121u8, 105u8, 110u8, 0u8, 0u8, 119u8, 97u8, 100u8, 101u8,
+                    103u8, 105u8, 108u8, 101u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 19u8, 0u8, 80u8, 105u8, 110u8, 121u8, 105u8,
+                    110u8, 32u8, 82u8, 111u8, 109u8, 97u8, 110u8, 105u8, 122u8, 97u8, 116u8, 105u8,
+                    111u8, 110u8, 87u8, 97u8, 100u8, 101u8, 45u8, 71u8, 105u8, 108u8, 101u8, 115u8,
+                    32u8, 82u8, 111u8, 109u8, 97u8, 110u8, 105u8, 122u8, 97u8, 116u8, 105u8, 111u8,
+                    110u8,
+                ])
+            },
+        )
+    },
+};
+static FR: &DataStruct = &::icu_displaynames::provider::VariantDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    49u8, 54u8, 48u8, 54u8, 110u8, 105u8, 99u8, 116u8, 49u8, 54u8, 57u8, 52u8,
+                    97u8, 99u8, 97u8, 100u8, 49u8, 57u8, 48u8, 49u8, 0u8, 0u8, 0u8, 0u8, 49u8,
+                    57u8, 57u8, 52u8, 0u8, 0u8, 0u8, 0u8, 49u8, 57u8, 57u8, 54u8, 0u8, 0u8, 0u8,
+                    0u8, 97u8, 114u8, 101u8, 118u8, 101u8, 108u8, 97u8, 0u8, 97u8, 114u8, 101u8,
+                    118u8, 109u8, 100u8, 97u8, 0u8, 98u8, 97u8, 107u8, 117u8, 49u8, 57u8, 50u8,
+                    54u8, 98u8, 105u8, 115u8, 107u8, 101u8, 0u8, 0u8, 0u8, 98u8, 111u8, 111u8,
+                    110u8, 116u8, 0u8, 0u8, 0u8, 102u8, 111u8, 110u8, 105u8, 112u8, 97u8, 0u8, 0u8,
+                    102u8, 111u8, 110u8, 117u8, 112u8, 97u8, 0u8, 0u8, 108u8, 105u8, 112u8, 97u8,
+                    119u8, 0u8, 0u8, 0u8, 109u8, 111u8, 110u8, 111u8, 116u8, 111u8, 110u8, 0u8,
+                    110u8, 101u8, 100u8, 105u8, 115u8, 0u8, 0u8, 0u8, 110u8, 106u8, 105u8, 118u8,
+                    97u8, 0u8, 0u8, 0u8, 111u8, 115u8, 111u8, 106u8, 115u8, 0u8, 0u8, 0u8, 112u8,
+                    105u8, 110u8, 121u8, 105u8, 110u8, 0u8, 0u8, 112u8, 111u8, 108u8, 121u8, 116u8,
+                    111u8, 110u8, 0u8, 112u8, 111u8, 115u8, 105u8, 120u8, 0u8, 0u8, 0u8, 114u8,
+                    101u8, 118u8, 105u8, 115u8, 101u8, 100u8, 0u8, 114u8, 111u8, 122u8, 97u8,
+                    106u8, 0u8, 0u8, 0u8, 115u8, 97u8, 97u8, 104u8, 111u8, 0u8, 0u8, 0u8, 115u8,
+                    99u8, 111u8, 116u8, 108u8, 97u8, 110u8, 100u8, 115u8, 99u8, 111u8, 117u8,
+                    115u8, 101u8, 0u8, 0u8, 115u8, 111u8, 108u8, 98u8, 97u8, 0u8, 0u8, 0u8, 116u8,
+                    97u8, 114u8, 97u8, 115u8, 107u8, 0u8, 0u8, 118u8, 97u8, 108u8, 101u8, 110u8,
+                    99u8, 105u8, 97u8, 119u8, 97u8, 100u8, 101u8, 103u8, 105u8, 108u8, 101u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    29u8, 0u8, 0u8, 0u8, 0u8, 0u8, 17u8, 0u8, 46u8, 0u8, 82u8, 0u8, 114u8, 0u8,
+                    143u8, 0u8, 161u8, 0u8, 181u8, 0u8, 213u8, 0u8, 243u8, 0u8, 5u8, 1u8, 39u8,
+                    1u8, 69u8, 1u8, 94u8, 1u8, 105u8, 1u8, 125u8, 1u8, 150u8, 1u8, 180u8, 1u8,
+                    186u8, 1u8, 197u8, 1u8, 209u8, 1u8, 230u8, 1u8, 247u8, 1u8, 4u8, 2u8, 30u8,
+                    2u8, 45u8, 2u8, 76u8, 2u8, 100u8, 2u8, 109u8, 2u8, 102u8, 114u8, 97u8, 110u8,
+                    195u8, 167u8, 111u8, 121u8, 115u8, 32u8, 100u8, 101u8, 32u8, 49u8, 54u8, 48u8,
+                    54u8, 102u8, 114u8, 97u8, 110u8, 195u8, 167u8, 111u8, 105u8, 115u8, 32u8, 97u8,
+                    99u8, 97u8, 100u8, 195u8, 169u8, 109u8, 105u8, 113u8, 117u8, 101u8, 32u8,
+                    100u8, 101u8, 32u8, 49u8, 54u8, 57u8, 52u8, 111u8, 114u8, 116u8, 104u8, 111u8,
+                    103u8, 114u8, 97u8, 112u8, 104u8, 101u8, 32u8, 97u8, 108u8, 108u8, 101u8,
+                    109u8, 97u8, 110u8, 100u8, 101u8, 32u8, 116u8, 114u8, 97u8, 100u8, 105u8,
+                    116u8, 105u8, 111u8, 110u8, 110u8, 101u8, 108u8, 108u8, 101u8, 111u8, 114u8,
+                    116u8, 104u8, 111u8, 103u8, 114u8, 97u8, 112u8, 104u8, 101u8, 32u8, 110u8,
+                    111u8, 114u8, 109u8, 97u8, 108u8, 105u8, 115u8, 195u8, 169u8, 101u8, 32u8,
+                    100u8, 101u8, 32u8, 82u8, 101u8, 115u8, 105u8, 97u8, 111u8, 114u8, 116u8,
+                    104u8, 111u8, 103u8, 114u8, 97u8, 112u8, 104u8, 101u8, 32u8, 97u8, 108u8,
+                    108u8, 101u8, 109u8, 97u8, 110u8, 100u8, 101u8, 32u8, 100u8, 101u8, 32u8, 49u8,
+                    57u8, 57u8, 54u8, 97u8, 114u8, 109u8, 195u8, 169u8, 110u8, 105u8, 101u8, 110u8,
+                    32u8, 111u8, 114u8, 105u8, 101u8, 110u8, 116u8, 97u8, 108u8, 97u8, 114u8,
+                    109u8, 195u8, 169u8, 110u8, 105u8, 101u8, 110u8, 32u8, 111u8, 99u8, 99u8,
+                    105u8, 100u8, 101u8, 110u8, 116u8, 97u8, 108u8, 97u8, 108u8, 112u8, 104u8,
+                    97u8, 98u8, 101u8, 116u8, 32u8, 108u8, 97u8, 116u8, 105u8, 110u8, 32u8, 97u8,
+                    108u8, 116u8, 97u8, 195u8, 175u8, 113u8, 117u8, 101u8, 32u8, 117u8, 110u8,
+                    105u8, 102u8, 105u8, 195u8, 169u8, 100u8, 105u8, 97u8, 108u8, 101u8, 99u8,
+                    116u8, 101u8, 32u8, 100u8, 101u8, 32u8, 83u8, 97u8, 110u8, 32u8, 71u8, 105u8,
+                    111u8, 114u8, 103u8, 105u8, 111u8, 32u8, 47u8, 32u8, 66u8, 105u8, 108u8, 97u8,
+                    100u8, 105u8, 97u8, 108u8, 101u8, 99u8, 116u8, 101u8, 32u8, 98u8, 111u8, 111u8,
+                    110u8, 116u8, 108u8, 105u8, 110u8, 103u8, 97u8, 108u8, 112u8, 104u8, 97u8,
+                    98u8, 101u8, 116u8, 32u8, 112u8, 104u8, 111u8, 110u8, 195u8, 169u8, 116u8,
+                    105u8, 113u8, 117u8, 101u8, 32u8, 105u8, 110u8, 116u8, 101u8, 114u8, 110u8,
+                    97u8, 116u8, 105u8, 111u8, 110u8, 97u8, 108u8, 97u8, 108u8, 112u8, 104u8, 97u8,
+                    98u8, 101u8, 116u8, 32u8, 112u8, 104u8, 111u8, 110u8, 195u8, 169u8, 116u8,
+                    105u8, 113u8, 117u8, 101u8, 32u8, 111u8, 117u8, 114u8, 97u8, 108u8, 105u8,
+                    113u8, 117u8, 101u8, 100u8, 105u8, 97u8, 108u8, 101u8, 99u8, 116u8, 101u8,
+                    32u8, 108u8, 105u8, 112u8, 111u8, 118u8, 97u8, 122u8, 32u8, 100u8, 101u8, 32u8,
+                    82u8, 101u8, 115u8, 105u8, 97u8, 109u8, 111u8, 110u8, 111u8, 116u8, 111u8,
+                    110u8, 105u8, 113u8, 117u8, 101u8, 100u8, 105u8, 97u8, 108u8, 101u8, 99u8,
+                    116u8, 101u8, 32u8, 100u8, 101u8, 32u8, 78u8, 97u8, 116u8, 105u8, 115u8, 111u8,
+                    110u8, 101u8, 100u8, 105u8, 97u8, 108u8, 101u8, 99u8, 116u8, 101u8, 32u8,
+                    100u8, 101u8, 32u8, 71u8, 110u8, 105u8, 118u8, 97u8, 32u8, 47u8, 32u8, 78u8,
+                    106u8, 105u8, 118u8, 97u8, 100u8, 105u8, 97u8, 108u8, 101u8, 99u8, 116u8,
+                    101u8, 32u8, 100u8, 226u8, 128u8, 153u8, 79u8, 115u8, 101u8, 97u8, 99u8, 99u8,
+                    111u8, 32u8, 47u8, 32u8, 79u8, 115u8, 111u8, 106u8, 97u8, 110u8, 101u8, 112u8,
+                    105u8, 110u8, 121u8, 105u8, 110u8, 112u8, 111u8, 108u8, 121u8, 116u8, 111u8,
+                    110u8, 105u8, 113u8, 117u8, 101u8, 105u8, 110u8, 102u8, 111u8, 114u8, 109u8,
+                    97u8, 116u8, 105u8, 113u8, 117u8, 101u8, 111u8, 114u8, 116u8, 104u8, 111u8,
+                    103u8, 114u8, 97u8, 112u8, 104u8, 101u8, 32u8, 114u8, 195u8, 169u8, 118u8,
+                    105u8, 115u8, 195u8, 169u8, 101u8, 100u8, 105u8, 97u8, 108u8, 101u8, 99u8,
+                    116u8, 101u8, 32u8, 100u8, 101u8, 32u8, 82u8, 101u8, 115u8, 105u8, 97u8, 100u8,
+                    105u8, 97u8, 108u8, 101u8, 99u8, 116u8, 101u8, 32u8, 115u8, 97u8, 104u8, 111u8,
+                    97u8, 110u8, 103u8, 108u8, 97u8, 105u8, 115u8, 32u8, 115u8, 116u8, 97u8, 110u8,
+                    100u8, 97u8, 114u8, 100u8, 32u8, 195u8, 169u8, 99u8, 111u8, 115u8, 115u8, 97u8,
+                    105u8, 115u8, 100u8, 105u8, 97u8, 108u8, 101u8, 99u8, 116u8, 101u8, 32u8,
+                    115u8, 99u8, 111u8, 117u8, 115u8, 101u8, 100u8, 105u8, 97u8, 108u8, 101u8,
+                    99u8, 116u8, 101u8, 32u8, 100u8, 101u8, 32u8, 83u8, 116u8, 111u8, 108u8, 118u8,
+                    105u8, 122u8, 122u8, 97u8, 32u8, 47u8, 32u8, 83u8, 111u8, 108u8, 98u8, 105u8,
+                    99u8, 97u8, 111u8, 114u8, 116u8, 104u8, 111u8, 103u8, 114u8, 97u8, 112u8,
+                    104u8, 101u8, 32u8, 116u8, 97u8, 114u8, 97u8, 115u8, 107u8, 105u8, 101u8,
+                    118u8, 105u8, 99u8, 97u8, 118u8, 97u8, 108u8, 101u8, 110u8, 99u8, 105u8, 101u8,
+                    110u8, 87u8, 97u8, 100u8, 101u8, 45u8, 71u8, 105u8, 108u8, 101u8, 115u8,
+                ])
+            },
+        )
+    },
+};
+static JA: &DataStruct = &::icu_displaynames::provider::VariantDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    49u8, 54u8, 48u8, 54u8, 110u8, 105u8, 99u8, 116u8, 49u8, 54u8, 57u8, 52u8,
+                    97u8, 99u8, 97u8, 100u8, 49u8, 57u8, 48u8, 49u8, 0u8, 0u8, 0u8, 0u8, 49u8,
+                    57u8, 53u8, 57u8, 97u8, 99u8, 97u8, 100u8, 49u8, 57u8, 57u8, 52u8, 0u8, 0u8,
+                    0u8, 0u8, 49u8, 57u8, 57u8, 54u8, 0u8, 0u8, 0u8, 0u8, 97u8, 108u8, 97u8, 108u8,
+                    99u8, 57u8, 55u8, 0u8, 97u8, 108u8, 117u8, 107u8, 117u8, 0u8, 0u8, 0u8, 97u8,
+                    114u8, 101u8, 118u8, 101u8, 108u8, 97u8, 0u8, 97u8, 114u8, 101u8, 118u8, 109u8,
+                    100u8, 97u8, 0u8, 98u8, 97u8, 107u8, 117u8, 49u8, 57u8, 50u8, 54u8, 98u8, 97u8,
+                    117u8, 100u8, 100u8, 104u8, 97u8, 0u8, 98u8, 105u8, 115u8, 99u8, 97u8, 121u8,
+                    97u8, 110u8, 98u8, 105u8, 115u8, 107u8, 101u8, 0u8, 0u8, 0u8, 98u8, 111u8,
+                    104u8, 111u8, 114u8, 105u8, 99u8, 0u8, 98u8, 111u8, 111u8, 110u8, 116u8, 0u8,
+                    0u8, 0u8, 100u8, 97u8, 106u8, 110u8, 107u8, 111u8, 0u8, 0u8, 101u8, 109u8,
+                    111u8, 100u8, 101u8, 110u8, 103u8, 0u8, 102u8, 111u8, 110u8, 105u8, 112u8,
+                    97u8, 0u8, 0u8, 102u8, 111u8, 110u8, 117u8, 112u8, 97u8, 0u8, 0u8, 102u8,
+                    111u8, 110u8, 120u8, 115u8, 97u8, 109u8, 112u8, 104u8, 101u8, 112u8, 98u8,
+                    117u8, 114u8, 110u8, 0u8, 104u8, 111u8, 103u8, 110u8, 111u8, 114u8, 115u8,
+                    107u8, 105u8, 116u8, 105u8, 104u8, 97u8, 115u8, 97u8, 0u8, 106u8, 97u8, 117u8,
+                    101u8, 114u8, 0u8, 0u8, 0u8, 106u8, 121u8, 117u8, 116u8, 112u8, 105u8, 110u8,
+                    103u8, 107u8, 107u8, 99u8, 111u8, 114u8, 0u8, 0u8, 0u8, 107u8, 115u8, 99u8,
+                    111u8, 114u8, 0u8, 0u8, 0u8, 108u8, 97u8, 117u8, 107u8, 105u8, 107u8, 97u8,
+                    0u8, 108u8, 105u8, 112u8, 97u8, 119u8, 0u8, 0u8, 0u8, 109u8, 101u8, 116u8,
+                    101u8, 108u8, 107u8, 111u8, 0u8, 109u8, 111u8, 110u8, 111u8, 116u8, 111u8,
+                    110u8, 0u8, 110u8, 100u8, 121u8, 117u8, 107u8, 97u8, 0u8, 0u8, 110u8, 101u8,
+                    100u8, 105u8, 115u8, 0u8, 0u8, 0u8, 110u8, 106u8, 105u8, 118u8, 97u8, 0u8, 0u8,
+                    0u8, 110u8, 117u8, 108u8, 105u8, 107u8, 0u8, 0u8, 0u8, 111u8, 115u8, 111u8,
+                    106u8, 115u8, 0u8, 0u8, 0u8, 112u8, 97u8, 109u8, 97u8, 107u8, 97u8, 0u8, 0u8,
+                    112u8, 105u8, 110u8, 121u8, 105u8, 110u8, 0u8, 0u8, 112u8, 111u8, 108u8, 121u8,
+                    116u8, 111u8, 110u8, 0u8, 112u8, 111u8, 115u8, 105u8, 120u8, 0u8, 0u8, 0u8,
+                    112u8, 117u8, 116u8, 101u8, 114u8, 0u8, 0u8, 0u8, 114u8, 101u8, 118u8, 105u8,
+                    115u8, 101u8, 100u8, 0u8, 114u8, 105u8, 103u8, 105u8, 107u8, 0u8, 0u8, 0u8,
+                    114u8, 111u8, 122u8, 97u8, 106u8, 0u8, 0u8, 0u8, 115u8, 97u8, 97u8, 104u8,
+                    111u8, 0u8, 0u8, 0u8, 115u8, 99u8, 111u8, 116u8, 108u8, 97u8, 110u8, 100u8,
+                    115u8, 99u8, 111u8, 117u8, 115u8, 101u8, 0u8, 0u8, 115u8, 111u8, 108u8, 98u8,
+                    97u8, 0u8, 0u8, 0u8, 115u8, 117u8, 114u8, 109u8, 105u8, 114u8, 97u8, 110u8,
+                    115u8, 117u8, 114u8, 115u8, 105u8, 108u8, 118u8, 0u8, 115u8, 117u8, 116u8,
+                    115u8, 105u8, 108u8, 118u8, 0u8, 116u8, 97u8, 114u8, 97u8, 115u8, 107u8, 0u8,
+                    0u8, 117u8, 99u8, 99u8, 111u8, 114u8, 0u8, 0u8, 0u8, 117u8, 99u8, 114u8, 99u8,
+                    111u8, 114u8, 0u8, 0u8, 117u8, 108u8, 115u8, 116u8, 101u8, 114u8, 0u8, 0u8,
+                    118u8, 97u8, 105u8, 100u8, 105u8, 107u8, 97u8, 0u8, 118u8, 97u8, 108u8, 101u8,
+                    110u8, 99u8, 105u8, 97u8, 118u8, 97u8, 108u8, 108u8, 97u8, 100u8, 101u8, 114u8,
+                    119u8, 97u8, 100u8, 101u8, 103u8, 105u8, 108u8, 101u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    60u8, 0u8, 0u8, 0u8, 0u8, 0u8, 36u8, 0u8, 63u8, 0u8, 87u8, 0u8, 118u8, 0u8,
+                    151u8, 0u8, 178u8, 0u8, 208u8, 0u8, 223u8, 0u8, 247u8, 0u8, 15u8, 1u8, 45u8,
+                    1u8, 57u8, 1u8, 72u8, 1u8, 112u8, 1u8, 151u8, 1u8, 172u8, 1u8, 193u8, 1u8,
+                    211u8, 1u8, 229u8, 1u8, 250u8, 1u8, 15u8, 2u8, 39u8, 2u8, 57u8, 2u8, 75u8, 2u8,
+                    87u8, 2u8, 99u8, 2u8, 138u8, 2u8, 177u8, 2u8, 189u8, 2u8, 229u8, 2u8, 250u8,
+                    2u8, 12u8, 3u8, 30u8, 3u8, 54u8, 3u8, 69u8, 3u8, 96u8, 3u8, 130u8, 3u8, 145u8,
+                    3u8, 177u8, 3u8, 195u8, 3u8, 213u8, 3u8, 225u8, 3u8, 234u8, 3u8, 5u8, 4u8,
+                    20u8, 4u8, 29u8, 4u8, 62u8, 4u8, 83u8, 4u8, 129u8, 4u8, 144u8, 4u8, 165u8, 4u8,
+                    186u8, 4u8, 219u8, 4u8, 2u8, 5u8, 47u8, 5u8, 62u8, 5u8, 80u8, 5u8, 101u8, 5u8,
+                    116u8, 5u8, 229u8, 190u8, 140u8, 230u8, 156u8, 159u8, 228u8, 184u8, 173u8,
+                    228u8, 184u8, 150u8, 227u8, 131u8, 149u8, 227u8, 131u8, 169u8, 227u8, 131u8,
+                    179u8, 227u8, 130u8, 185u8, 232u8, 170u8, 158u8, 40u8, 227u8, 128u8, 156u8,
+                    49u8, 54u8, 48u8, 54u8, 41u8, 229u8, 136u8, 157u8, 230u8, 156u8, 159u8, 231u8,
+                    143u8, 190u8, 228u8, 187u8, 163u8, 227u8, 131u8, 149u8, 227u8, 131u8, 169u8,
+                    227u8, 131u8, 179u8, 227u8, 130u8, 185u8, 232u8, 170u8, 158u8, 227u8, 131u8,
+                    137u8, 227u8, 130u8, 164u8, 227u8, 131u8, 132u8, 232u8, 170u8, 158u8, 230u8,
+                    151u8, 167u8, 230u8, 173u8, 163u8, 230u8, 155u8, 184u8, 230u8, 179u8, 149u8,
+                    230u8, 168u8, 153u8, 230u8, 186u8, 150u8, 227u8, 131u8, 153u8, 227u8, 131u8,
+                    169u8, 227u8, 131u8, 171u8, 227u8, 131u8, 188u8, 227u8, 130u8, 183u8, 232u8,
+                    170u8, 158u8, 32u8, 40u8, 49u8, 57u8, 53u8, 57u8, 41u8, 230u8, 168u8, 153u8,
+                    230u8, 186u8, 150u8, 227u8, 131u8, 172u8, 227u8, 131u8, 188u8, 227u8, 130u8,
+                    184u8, 227u8, 130u8, 162u8, 230u8, 150u8, 185u8, 232u8, 168u8, 128u8, 230u8,
+                    173u8, 163u8, 230u8, 155u8, 184u8, 230u8, 179u8, 149u8, 227u8, 131u8, 137u8,
+                    227u8, 130u8, 164u8, 227u8, 131u8, 132u8, 232u8, 170u8, 158u8, 230u8, 173u8,
+                    163u8, 230u8, 155u8, 184u8, 230u8, 179u8, 149u8, 40u8, 49u8, 57u8, 57u8, 54u8,
+                    41u8, 65u8, 76u8, 65u8, 45u8, 76u8, 67u8, 227u8, 131u8, 169u8, 227u8, 131u8,
+                    134u8, 227u8, 131u8, 179u8, 230u8, 150u8, 135u8, 229u8, 173u8, 151u8, 229u8,
+                    140u8, 150u8, 40u8, 49u8, 57u8, 57u8, 55u8, 41u8, 227u8, 130u8, 162u8, 227u8,
+                    131u8, 173u8, 227u8, 130u8, 179u8, 230u8, 150u8, 185u8, 232u8, 168u8, 128u8,
+                    230u8, 157u8, 177u8, 227u8, 130u8, 162u8, 227u8, 131u8, 171u8, 227u8, 131u8,
+                    161u8, 227u8, 131u8, 139u8, 227u8, 130u8, 162u8, 230u8, 150u8, 135u8, 232u8,
+                    170u8, 158u8, 232u8, 165u8, 191u8, 227u8, 130u8, 162u8, 227u8, 131u8, 171u8,
+                    227u8, 131u8, 161u8, 227u8, 131u8, 139u8, 227u8, 130u8, 162u8, 230u8, 150u8,
+                    135u8, 232u8, 170u8, 158u8, 231u8, 181u8, 177u8, 228u8, 184u8, 128u8, 227u8,
+                    131u8, 136u8, 227u8, 131u8, 171u8, 227u8, 130u8, 179u8, 227u8, 131u8, 169u8,
+                    227u8, 131u8, 134u8, 227u8, 131u8, 179u8, 230u8, 150u8, 135u8, 229u8, 173u8,
+                    151u8, 227u8, 131u8, 144u8, 227u8, 130u8, 166u8, 227u8, 131u8, 131u8, 227u8,
+                    131u8, 128u8, 227u8, 131u8, 147u8, 227u8, 130u8, 185u8, 227u8, 130u8, 171u8,
+                    227u8, 131u8, 164u8, 232u8, 170u8, 158u8, 227u8, 130u8, 181u8, 227u8, 131u8,
+                    179u8, 227u8, 131u8, 187u8, 227u8, 130u8, 184u8, 227u8, 131u8, 167u8, 227u8,
+                    131u8, 171u8, 227u8, 130u8, 184u8, 227u8, 131u8, 167u8, 47u8, 227u8, 131u8,
+                    147u8, 227u8, 131u8, 188u8, 227u8, 131u8, 169u8, 230u8, 150u8, 185u8, 232u8,
+                    168u8, 128u8, 227u8, 131u8, 156u8, 227u8, 131u8, 155u8, 227u8, 131u8, 170u8,
+                    227u8, 131u8, 131u8, 227u8, 131u8, 132u8, 227u8, 131u8, 187u8, 227u8, 130u8,
+                    162u8, 227u8, 131u8, 171u8, 227u8, 131u8, 149u8, 227u8, 130u8, 161u8, 227u8,
+                    131u8, 153u8, 227u8, 131u8, 131u8, 227u8, 131u8, 136u8, 227u8, 131u8, 150u8,
+                    227u8, 131u8, 188u8, 227u8, 131u8, 179u8, 227u8, 131u8, 136u8, 227u8, 131u8,
+                    170u8, 227u8, 131u8, 179u8, 227u8, 130u8, 176u8, 227u8, 131u8, 128u8, 227u8,
+                    130u8, 164u8, 227u8, 131u8, 179u8, 227u8, 131u8, 129u8, 227u8, 131u8, 131u8,
+                    227u8, 131u8, 132u8, 227u8, 130u8, 161u8, 229u8, 136u8, 157u8, 230u8, 156u8,
+                    159u8, 232u8, 191u8, 145u8, 228u8, 187u8, 163u8, 232u8, 139u8, 177u8, 232u8,
+                    170u8, 158u8, 229u8, 155u8, 189u8, 233u8, 154u8, 155u8, 233u8, 159u8, 179u8,
+                    229u8, 163u8, 176u8, 232u8, 168u8, 152u8, 229u8, 143u8, 183u8, 227u8, 130u8,
+                    166u8, 227u8, 131u8, 169u8, 227u8, 131u8, 171u8, 233u8, 159u8, 179u8, 229u8,
+                    163u8, 176u8, 232u8, 168u8, 152u8, 229u8, 143u8, 183u8, 227u8, 131u8, 149u8,
+                    227u8, 130u8, 169u8, 227u8, 131u8, 179u8, 227u8, 130u8, 175u8, 227u8, 130u8,
+                    181u8, 227u8, 131u8, 179u8, 227u8, 131u8, 151u8, 227u8, 131u8, 152u8, 227u8,
+                    131u8, 156u8, 227u8, 131u8, 179u8, 229u8, 188u8, 143u8, 227u8, 131u8, 173u8,
+                    227u8, 131u8, 188u8, 227u8, 131u8, 158u8, 229u8, 173u8, 151u8, 227u8, 131u8,
+                    152u8, 227u8, 130u8, 176u8, 227u8, 131u8, 142u8, 227u8, 130u8, 183u8, 227u8,
+                    131u8, 165u8, 227u8, 130u8, 175u8, 227u8, 130u8, 164u8, 227u8, 131u8, 134u8,
+                    227u8, 130u8, 163u8, 227u8, 131u8, 143u8, 227u8, 131u8, 188u8, 227u8, 130u8,
+                    181u8, 227u8, 131u8, 164u8, 227u8, 130u8, 166u8, 227u8, 130u8, 168u8, 227u8,
+                    131u8, 171u8, 231u8, 149u8, 165u8, 231u8, 167u8, 176u8, 231u8, 178u8, 164u8,
+                    230u8, 139u8, 188u8, 229u8, 133u8, 177u8, 233u8, 128u8, 154u8, 227u8, 130u8,
+                    179u8, 227u8, 131u8, 188u8, 227u8, 131u8, 179u8, 227u8, 130u8, 166u8, 227u8,
+                    130u8, 169u8, 227u8, 131u8, 188u8, 227u8, 131u8, 171u8, 232u8, 170u8, 158u8,
+                    230u8, 173u8, 163u8, 230u8, 155u8, 184u8, 230u8, 179u8, 149u8, 230u8, 168u8,
+                    153u8, 230u8, 186u8, 150u8, 227u8, 130u8, 179u8, 227u8, 131u8, 188u8, 227u8,
+                    131u8, 179u8, 227u8, 130u8, 166u8, 227u8, 130u8, 169u8, 227u8, 131u8, 188u8,
+                    227u8, 131u8, 171u8, 232u8, 170u8, 158u8, 230u8, 173u8, 163u8, 230u8, 155u8,
+                    184u8, 230u8, 179u8, 149u8, 227u8, 131u8, 169u8, 227u8, 130u8, 166u8, 227u8,
+                    130u8, 173u8, 227u8, 130u8, 171u8, 227u8, 131u8, 172u8, 227u8, 131u8, 188u8,
+                    227u8, 130u8, 184u8, 227u8, 130u8, 162u8, 230u8, 150u8, 185u8, 232u8, 168u8,
+                    128u8, 32u8, 227u8, 131u8, 170u8, 227u8, 131u8, 157u8, 227u8, 131u8, 180u8,
+                    227u8, 130u8, 161u8, 227u8, 131u8, 132u8, 230u8, 150u8, 185u8, 232u8, 168u8,
+                    128u8, 227u8, 131u8, 161u8, 227u8, 131u8, 134u8, 227u8, 131u8, 171u8, 227u8,
+                    131u8, 129u8, 227u8, 131u8, 131u8, 227u8, 131u8, 132u8, 227u8, 130u8, 161u8,
+                    227u8, 131u8, 162u8, 227u8, 131u8, 142u8, 227u8, 131u8, 136u8, 227u8, 131u8,
+                    139u8, 227u8, 131u8, 131u8, 227u8, 130u8, 175u8, 227u8, 131u8, 179u8, 227u8,
+                    130u8, 184u8, 227u8, 131u8, 165u8, 227u8, 130u8, 171u8, 230u8, 150u8, 185u8,
+                    232u8, 168u8, 128u8, 227u8, 131u8, 138u8, 227u8, 131u8, 134u8, 227u8, 130u8,
+                    163u8, 227u8, 130u8, 190u8, 227u8, 131u8, 188u8, 227u8, 131u8, 141u8, 230u8,
+                    150u8, 185u8, 232u8, 168u8, 128u8, 227u8, 131u8, 139u8, 227u8, 131u8, 180u8,
+                    227u8, 130u8, 161u8, 230u8, 150u8, 185u8, 232u8, 168u8, 128u8, 231u8, 143u8,
+                    190u8, 228u8, 187u8, 163u8, 227u8, 131u8, 180u8, 227u8, 130u8, 169u8, 227u8,
+                    131u8, 169u8, 227u8, 131u8, 148u8, 227u8, 131u8, 165u8, 227u8, 130u8, 175u8,
+                    232u8, 170u8, 158u8, 227u8, 130u8, 170u8, 227u8, 130u8, 188u8, 227u8, 130u8,
+                    162u8, 227u8, 131u8, 131u8, 227u8, 130u8, 179u8, 47u8, 227u8, 130u8, 170u8,
+                    227u8, 130u8, 189u8, 227u8, 131u8, 164u8, 227u8, 131u8, 141u8, 230u8, 150u8,
+                    185u8, 232u8, 168u8, 128u8, 227u8, 131u8, 145u8, 227u8, 131u8, 158u8, 227u8,
+                    130u8, 171u8, 230u8, 150u8, 185u8, 232u8, 168u8, 128u8, 227u8, 131u8, 148u8,
+                    227u8, 131u8, 179u8, 233u8, 159u8, 179u8, 40u8, 227u8, 131u8, 173u8, 227u8,
+                    131u8, 188u8, 227u8, 131u8, 158u8, 229u8, 173u8, 151u8, 232u8, 161u8, 168u8,
+                    232u8, 168u8, 152u8, 230u8, 179u8, 149u8, 41u8, 227u8, 131u8, 157u8, 227u8,
+                    131u8, 170u8, 227u8, 131u8, 136u8, 227u8, 131u8, 139u8, 227u8, 131u8, 131u8,
+                    227u8, 130u8, 175u8, 227u8, 130u8, 179u8, 227u8, 131u8, 179u8, 227u8, 131u8,
+                    148u8, 227u8, 131u8, 165u8, 227u8, 131u8, 188u8, 227u8, 130u8, 191u8, 227u8,
+                    131u8, 151u8, 227u8, 131u8, 188u8, 227u8, 130u8, 191u8, 227u8, 131u8, 188u8,
+                    230u8, 148u8, 185u8, 232u8, 168u8, 130u8, 231u8, 137u8, 136u8, 229u8, 143u8,
+                    164u8, 229u8, 133u8, 184u8, 227u8, 131u8, 180u8, 227u8, 130u8, 169u8, 227u8,
+                    131u8, 169u8, 227u8, 131u8, 148u8, 227u8, 131u8, 165u8, 227u8, 130u8, 175u8,
+                    232u8, 170u8, 158u8, 227u8, 131u8, 172u8, 227u8, 130u8, 183u8, 227u8, 130u8,
+                    162u8, 230u8, 150u8, 185u8, 232u8, 168u8, 128u8, 227u8, 130u8, 181u8, 227u8,
+                    131u8, 155u8, 232u8, 170u8, 158u8, 227u8, 130u8, 185u8, 227u8, 130u8, 179u8,
+                    227u8, 131u8, 131u8, 227u8, 131u8, 136u8, 227u8, 131u8, 169u8, 227u8, 131u8,
+                    179u8, 227u8, 131u8, 137u8, 230u8, 168u8, 153u8, 230u8, 186u8, 150u8, 232u8,
+                    139u8, 177u8, 232u8, 170u8, 158u8, 227u8, 131u8, 170u8, 227u8, 131u8, 144u8,
+                    227u8, 131u8, 151u8, 227u8, 131u8, 188u8, 227u8, 131u8, 171u8, 230u8, 150u8,
+                    185u8, 232u8, 168u8, 128u8, 227u8, 130u8, 185u8, 227u8, 131u8, 136u8, 227u8,
+                    131u8, 171u8, 227u8, 131u8, 180u8, 227u8, 130u8, 163u8, 227u8, 131u8, 131u8,
+                    227u8, 131u8, 132u8, 227u8, 130u8, 161u8, 47u8, 227u8, 130u8, 189u8, 227u8,
+                    131u8, 171u8, 227u8, 131u8, 147u8, 227u8, 131u8, 132u8, 227u8, 130u8, 161u8,
+                    230u8, 150u8, 185u8, 232u8, 168u8, 128u8, 227u8, 130u8, 185u8, 227u8, 131u8,
+                    171u8, 227u8, 131u8, 159u8, 227u8, 131u8, 169u8, 227u8, 131u8, 179u8, 227u8,
+                    130u8, 185u8, 227u8, 131u8, 171u8, 227u8, 130u8, 183u8, 227u8, 131u8, 171u8,
+                    227u8, 131u8, 180u8, 227u8, 130u8, 161u8, 227u8, 131u8, 179u8, 227u8, 130u8,
+                    185u8, 227u8, 131u8, 136u8, 227u8, 130u8, 183u8, 227u8, 131u8, 171u8, 227u8,
+                    131u8, 180u8, 227u8, 130u8, 161u8, 227u8, 131u8, 179u8, 227u8, 130u8, 191u8,
+                    227u8, 131u8, 169u8, 227u8, 130u8, 183u8, 227u8, 130u8, 177u8, 227u8, 131u8,
+                    180u8, 227u8, 130u8, 163u8, 227u8, 131u8, 132u8, 227u8, 130u8, 161u8, 230u8,
+                    173u8, 163u8, 230u8, 155u8, 184u8, 230u8, 179u8, 149u8, 231u8, 181u8, 177u8,
+                    228u8, 184u8, 128u8, 227u8, 130u8, 179u8, 227u8, 131u8, 188u8, 227u8, 131u8,
+                    179u8, 227u8, 130u8, 166u8, 227u8, 130u8, 169u8, 227u8, 131u8, 188u8, 227u8,
+                    131u8, 171u8, 232u8, 170u8, 158u8, 230u8, 173u8, 163u8, 230u8, 155u8, 184u8,
+                    230u8, 179u8, 149u8, 230u8, 148u8, 185u8, 232u8, 168u8, 130u8, 231u8, 181u8,
+                    177u8, 228u8, 184u8, 128u8, 227u8, 130u8, 179u8, 227u8, 131u8, 188u8, 227u8,
+                    131u8, 179u8, 227u8, 130u8, 166u8, 227u8, 130u8, 169u8, 227u8, 131u8, 188u8,
+                    227u8, 131u8, 171u8, 232u8, 170u8, 158u8, 230u8, 173u8, 163u8, 230u8, 155u8,
+                    184u8, 230u8, 179u8, 149u8, 227u8, 130u8, 162u8, 227u8, 131u8, 171u8, 227u8,
+                    130u8, 185u8, 227u8, 130u8, 191u8, 227u8, 131u8, 188u8, 227u8, 131u8, 180u8,
+                    227u8, 130u8, 161u8, 227u8, 130u8, 164u8, 227u8, 131u8, 135u8, 227u8, 130u8,
+                    163u8, 227u8, 130u8, 171u8, 227u8, 131u8, 144u8, 227u8, 131u8, 172u8, 227u8,
+                    131u8, 179u8, 227u8, 130u8, 183u8, 227u8, 130u8, 162u8, 230u8, 150u8, 185u8,
+                    232u8, 168u8, 128u8, 227u8, 131u8, 180u8, 227u8, 130u8, 161u8, 227u8, 131u8,
+                    169u8, 227u8, 131u8, 128u8, 227u8, 131u8, 188u8, 227u8, 130u8, 166u8, 227u8,
+                    130u8, 167u8, 227u8, 131u8, 188u8, 227u8, 131u8, 137u8, 229u8, 188u8, 143u8,
+                    227u8, 131u8, 173u8, 227u8, 131u8, 188u8, 227u8, 131u8, 158u8, 229u8, 173u8,
+                    151u8, 232u8, 161u8, 168u8, 232u8, 168u8, 152u8, 230u8, 179u8, 149u8,
+                ])
+            },
+        )
+    },
+};
+static RU: &DataStruct = &::icu_displaynames::provider::VariantDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    49u8, 54u8, 48u8, 54u8, 110u8, 105u8, 99u8, 116u8, 49u8, 57u8, 48u8, 49u8, 0u8,
+                    0u8, 0u8, 0u8, 49u8, 57u8, 57u8, 52u8, 0u8, 0u8, 0u8, 0u8, 49u8, 57u8, 57u8,
+                    54u8, 0u8, 0u8, 0u8, 0u8, 97u8, 114u8, 101u8, 118u8, 101u8, 108u8, 97u8, 0u8,
+                    97u8, 114u8, 101u8, 118u8, 109u8, 100u8, 97u8, 0u8, 98u8, 97u8, 107u8, 117u8,
+                    49u8, 57u8, 50u8, 54u8, 102u8, 111u8, 110u8, 105u8, 112u8, 97u8, 0u8, 0u8,
+                    107u8, 107u8, 99u8, 111u8, 114u8, 0u8, 0u8, 0u8, 109u8, 111u8, 110u8, 111u8,
+                    116u8, 111u8, 110u8, 0u8, 112u8, 105u8, 110u8, 121u8, 105u8, 110u8, 0u8, 0u8,
+                    112u8, 111u8, 108u8, 121u8, 116u8, 111u8, 110u8, 0u8, 112u8, 111u8, 115u8,
+                    105u8, 120u8, 0u8, 0u8, 0u8, 114u8, 111u8, 122u8, 97u8, 106u8, 0u8, 0u8, 0u8,
+                    115u8, 97u8, 97u8, 104u8, 111u8, 0u8, 0u8, 0u8, 115u8, 99u8, 111u8, 116u8,
+                    108u8, 97u8, 110u8, 100u8, 116u8, 97u8, 114u8, 97u8, 115u8, 107u8, 0u8, 0u8,
+                    118u8, 97u8, 108u8, 101u8, 110u8, 99u8, 105u8, 97u8, 119u8, 97u8, 100u8, 101u8,
+                    103u8, 105u8, 108u8, 101u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    19u8, 0u8, 0u8, 0u8, 0u8, 0u8, 76u8, 0u8, 138u8, 0u8, 218u8, 0u8, 58u8, 1u8,
+                    93u8, 1u8, 126u8, 1u8, 203u8, 1u8, 13u8, 2u8, 44u8, 2u8, 64u8, 2u8, 78u8, 2u8,
+                    106u8, 2u8, 130u8, 2u8, 150u8, 2u8, 158u8, 2u8, 191u8, 2u8, 213u8, 2u8, 237u8,
+                    2u8, 208u8, 159u8, 208u8, 190u8, 208u8, 183u8, 208u8, 180u8, 208u8, 189u8,
+                    208u8, 181u8, 45u8, 209u8, 129u8, 209u8, 128u8, 208u8, 181u8, 208u8, 180u8,
+                    208u8, 189u8, 208u8, 181u8, 208u8, 178u8, 208u8, 181u8, 208u8, 186u8, 208u8,
+                    190u8, 208u8, 178u8, 209u8, 139u8, 208u8, 185u8, 32u8, 209u8, 132u8, 209u8,
+                    128u8, 208u8, 176u8, 208u8, 189u8, 209u8, 134u8, 209u8, 131u8, 208u8, 183u8,
+                    209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 32u8, 208u8, 180u8,
+                    208u8, 190u8, 32u8, 49u8, 54u8, 48u8, 54u8, 32u8, 208u8, 179u8, 46u8, 208u8,
+                    162u8, 209u8, 128u8, 208u8, 176u8, 208u8, 180u8, 208u8, 184u8, 209u8, 134u8,
+                    208u8, 184u8, 208u8, 190u8, 208u8, 189u8, 208u8, 189u8, 208u8, 176u8, 209u8,
+                    143u8, 32u8, 208u8, 189u8, 208u8, 181u8, 208u8, 188u8, 208u8, 181u8, 209u8,
+                    134u8, 208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 32u8, 208u8, 190u8, 209u8,
+                    128u8, 209u8, 132u8, 208u8, 190u8, 208u8, 179u8, 209u8, 128u8, 208u8, 176u8,
+                    209u8, 132u8, 208u8, 184u8, 209u8, 143u8, 208u8, 161u8, 209u8, 130u8, 208u8,
+                    176u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 209u8, 130u8,
+                    208u8, 184u8, 208u8, 183u8, 208u8, 184u8, 209u8, 128u8, 208u8, 190u8, 208u8,
+                    178u8, 208u8, 176u8, 208u8, 189u8, 208u8, 189u8, 208u8, 176u8, 209u8, 143u8,
+                    32u8, 209u8, 128u8, 208u8, 181u8, 208u8, 183u8, 209u8, 140u8, 209u8, 143u8,
+                    208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 209u8, 143u8, 32u8,
+                    208u8, 190u8, 209u8, 128u8, 209u8, 132u8, 208u8, 190u8, 208u8, 179u8, 209u8,
+                    128u8, 208u8, 176u8, 209u8, 132u8, 208u8, 184u8, 209u8, 143u8, 208u8, 159u8,
+                    209u8, 128u8, 208u8, 176u8, 208u8, 178u8, 208u8, 184u8, 208u8, 187u8, 208u8,
+                    176u8, 32u8, 208u8, 189u8, 208u8, 181u8, 208u8, 188u8, 208u8, 181u8, 209u8,
+                    134u8, 208u8, 186u8, 208u8, 190u8, 208u8, 185u8, 32u8, 208u8, 190u8, 209u8,
+                    128u8, 209u8, 132u8, 208u8, 190u8, 208u8, 179u8, 209u8, 128u8, 208u8, 176u8,
+                    209u8, 132u8, 208u8, 184u8, 208u8, 184u8, 32u8, 209u8, 131u8, 209u8, 129u8,
+                    209u8, 130u8, 208u8, 176u8, 208u8, 189u8, 208u8, 190u8, 208u8, 178u8, 208u8,
+                    187u8, 208u8, 181u8, 208u8, 189u8, 208u8, 189u8, 209u8, 139u8, 208u8, 181u8,
+                    32u8, 209u8, 129u8, 32u8, 49u8, 57u8, 57u8, 54u8, 32u8, 208u8, 179u8, 208u8,
+                    190u8, 208u8, 180u8, 208u8, 176u8, 208u8, 146u8, 208u8, 190u8, 209u8, 129u8,
+                    209u8, 130u8, 208u8, 190u8, 209u8, 135u8, 208u8, 189u8, 208u8, 190u8, 45u8,
+                    208u8, 176u8, 209u8, 128u8, 208u8, 188u8, 209u8, 143u8, 208u8, 189u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 208u8, 151u8, 208u8, 176u8,
+                    208u8, 191u8, 208u8, 176u8, 208u8, 180u8, 208u8, 189u8, 208u8, 190u8, 45u8,
+                    208u8, 176u8, 209u8, 128u8, 208u8, 188u8, 209u8, 143u8, 208u8, 189u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 208u8, 163u8, 208u8, 189u8,
+                    208u8, 184u8, 209u8, 132u8, 208u8, 184u8, 209u8, 134u8, 208u8, 184u8, 209u8,
+                    128u8, 208u8, 190u8, 208u8, 178u8, 208u8, 176u8, 208u8, 189u8, 208u8, 189u8,
+                    209u8, 139u8, 208u8, 185u8, 32u8, 209u8, 130u8, 209u8, 142u8, 209u8, 128u8,
+                    209u8, 129u8, 208u8, 186u8, 208u8, 190u8, 45u8, 208u8, 187u8, 208u8, 176u8,
+                    209u8, 130u8, 208u8, 184u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                    184u8, 208u8, 185u8, 32u8, 208u8, 176u8, 208u8, 187u8, 209u8, 132u8, 208u8,
+                    176u8, 208u8, 178u8, 208u8, 184u8, 209u8, 130u8, 208u8, 156u8, 208u8, 181u8,
+                    208u8, 182u8, 208u8, 180u8, 209u8, 131u8, 208u8, 189u8, 208u8, 176u8, 209u8,
+                    128u8, 208u8, 190u8, 208u8, 180u8, 208u8, 189u8, 209u8, 139u8, 208u8, 185u8,
+                    32u8, 209u8, 132u8, 208u8, 190u8, 208u8, 189u8, 208u8, 181u8, 209u8, 130u8,
+                    208u8, 184u8, 209u8, 135u8, 208u8, 181u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                    184u8, 208u8, 185u8, 32u8, 208u8, 176u8, 208u8, 187u8, 209u8, 132u8, 208u8,
+                    176u8, 208u8, 178u8, 208u8, 184u8, 209u8, 130u8, 208u8, 158u8, 208u8, 177u8,
+                    209u8, 137u8, 208u8, 176u8, 209u8, 143u8, 32u8, 208u8, 190u8, 209u8, 128u8,
+                    209u8, 132u8, 208u8, 190u8, 208u8, 179u8, 209u8, 128u8, 208u8, 176u8, 209u8,
+                    132u8, 208u8, 184u8, 209u8, 143u8, 208u8, 156u8, 208u8, 190u8, 208u8, 189u8,
+                    208u8, 190u8, 209u8, 130u8, 208u8, 190u8, 208u8, 189u8, 208u8, 189u8, 209u8,
+                    139u8, 208u8, 185u8, 208u8, 159u8, 208u8, 184u8, 208u8, 189u8, 209u8, 140u8,
+                    208u8, 184u8, 208u8, 189u8, 209u8, 140u8, 208u8, 156u8, 208u8, 189u8, 208u8,
+                    190u8, 208u8, 179u8, 208u8, 190u8, 209u8, 130u8, 208u8, 190u8, 208u8, 189u8,
+                    208u8, 176u8, 208u8, 187u8, 209u8, 140u8, 208u8, 189u8, 209u8, 139u8, 208u8,
+                    185u8, 208u8, 154u8, 208u8, 190u8, 208u8, 188u8, 208u8, 191u8, 209u8, 140u8,
+                    209u8, 142u8, 209u8, 130u8, 208u8, 181u8, 209u8, 128u8, 208u8, 189u8, 209u8,
+                    139u8, 208u8, 185u8, 208u8, 160u8, 208u8, 181u8, 208u8, 183u8, 209u8, 140u8,
+                    209u8, 143u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8,
+                    185u8, 208u8, 161u8, 208u8, 176u8, 209u8, 133u8, 208u8, 190u8, 208u8, 144u8,
+                    208u8, 189u8, 208u8, 179u8, 208u8, 187u8, 208u8, 190u8, 45u8, 209u8, 136u8,
+                    208u8, 190u8, 209u8, 130u8, 208u8, 187u8, 208u8, 176u8, 208u8, 189u8, 208u8,
+                    180u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8, 208u8, 162u8,
+                    208u8, 176u8, 209u8, 128u8, 208u8, 176u8, 209u8, 136u8, 208u8, 186u8, 208u8,
+                    181u8, 208u8, 178u8, 208u8, 184u8, 209u8, 134u8, 208u8, 176u8, 208u8, 146u8,
+                    208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8, 189u8, 209u8, 129u8, 208u8,
+                    184u8, 208u8, 185u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 185u8,
+                    208u8, 161u8, 208u8, 184u8, 209u8, 129u8, 209u8, 130u8, 208u8, 181u8, 208u8,
+                    188u8, 208u8, 176u8, 32u8, 208u8, 163u8, 209u8, 141u8, 208u8, 185u8, 208u8,
+                    180u8, 208u8, 176u8, 32u8, 226u8, 128u8, 147u8, 32u8, 208u8, 148u8, 208u8,
+                    182u8, 208u8, 176u8, 208u8, 185u8, 208u8, 187u8, 208u8, 183u8, 208u8, 176u8,
+                ])
+            },
+        )
+    },
+};
+static SR_LATN: &DataStruct = &::icu_displaynames::provider::VariantDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    49u8, 54u8, 48u8, 54u8, 110u8, 105u8, 99u8, 116u8, 49u8, 54u8, 57u8, 52u8,
+                    97u8, 99u8, 97u8, 100u8, 49u8, 57u8, 48u8, 49u8, 0u8, 0u8, 0u8, 0u8, 49u8,
+                    57u8, 57u8, 52u8, 0u8, 0u8, 0u8, 0u8, 49u8, 57u8, 57u8, 54u8, 0u8, 0u8, 0u8,
+                    0u8, 97u8, 114u8, 101u8, 118u8, 101u8, 108u8, 97u8, 0u8, 97u8, 114u8, 101u8,
+                    118u8, 109u8, 100u8, 97u8, 0u8, 98u8, 97u8, 107u8, 117u8, 49u8, 57u8, 50u8,
+                    54u8, 98u8, 105u8, 115u8, 107u8, 101u8, 0u8, 0u8, 0u8, 98u8, 111u8, 111u8,
+                    110u8, 116u8, 0u8, 0u8, 0u8, 102u8, 111u8, 110u8, 105u8, 112u8, 97u8, 0u8, 0u8,
+                    102u8, 111u8, 110u8, 117u8, 112u8, 97u8, 0u8, 0u8, 108u8, 105u8, 112u8, 97u8,
+                    119u8, 0u8, 0u8, 0u8, 109u8, 111u8, 110u8, 111u8, 116u8, 111u8, 110u8, 0u8,
+                    110u8, 101u8, 100u8, 105u8, 115u8, 0u8, 0u8, 0u8, 110u8, 106u8, 105u8, 118u8,
+                    97u8, 0u8, 0u8, 0u8, 111u8, 115u8, 111u8, 106u8, 115u8, 0u8, 0u8, 0u8, 112u8,
+                    111u8, 108u8, 121u8, 116u8, 111u8, 110u8, 0u8, 112u8, 111u8, 115u8, 105u8,
+                    120u8, 0u8, 0u8, 0u8, 114u8, 101u8, 118u8, 105u8, 115u8, 101u8, 100u8, 0u8,
+                    114u8, 111u8, 122u8, 97u8, 106u8, 0u8, 0u8, 0u8, 115u8, 97u8, 97u8, 104u8,
+                    111u8, 0u8, 0u8, 0u8, 115u8, 99u8, 111u8, 116u8, 108u8, 97u8, 110u8, 100u8,
+                    115u8, 99u8, 111u8, 117u8, 115u8, 101u8, 0u8, 0u8, 115u8, 111u8, 108u8, 98u8,
+                    97u8, 0u8, 0u8, 0u8, 116u8, 97u8, 114u8, 97u8, 115u8, 107u8, 0u8, 0u8, 118u8,
+                    97u8, 108u8, 101u8, 110u8, 99u8, 105u8, 97u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    27u8, 0u8, 0u8, 0u8, 0u8, 0u8, 42u8, 0u8, 64u8, 0u8, 98u8, 0u8, 137u8, 0u8,
+                    165u8, 0u8, 184u8, 0u8, 201u8, 0u8, 236u8, 0u8, 6u8, 1u8, 14u8, 1u8, 26u8, 1u8,
+                    38u8, 1u8, 68u8, 1u8, 77u8, 1u8, 94u8, 1u8, 116u8, 1u8, 139u8, 1u8, 148u8, 1u8,
+                    157u8, 1u8, 179u8, 1u8, 186u8, 1u8, 190u8, 1u8, 218u8, 1u8, 223u8, 1u8, 248u8,
+                    1u8, 19u8, 2u8, 70u8, 114u8, 97u8, 110u8, 99u8, 117u8, 115u8, 107u8, 105u8,
+                    32u8, 105u8, 122u8, 32u8, 107u8, 97u8, 115u8, 110u8, 111u8, 103u8, 32u8, 115u8,
+                    114u8, 101u8, 100u8, 110u8, 106u8, 101u8, 103u8, 32u8, 118u8, 101u8, 107u8,
+                    97u8, 32u8, 100u8, 111u8, 32u8, 49u8, 54u8, 48u8, 54u8, 46u8, 82u8, 97u8,
+                    110u8, 105u8, 32u8, 109u8, 111u8, 100u8, 101u8, 114u8, 110u8, 105u8, 32u8,
+                    102u8, 114u8, 97u8, 110u8, 99u8, 117u8, 115u8, 107u8, 105u8, 84u8, 114u8, 97u8,
+                    100u8, 105u8, 99u8, 105u8, 111u8, 110u8, 97u8, 108u8, 110u8, 97u8, 32u8, 110u8,
+                    101u8, 109u8, 97u8, 196u8, 141u8, 107u8, 97u8, 32u8, 111u8, 114u8, 116u8,
+                    111u8, 103u8, 114u8, 97u8, 102u8, 105u8, 106u8, 97u8, 83u8, 116u8, 97u8, 110u8,
+                    100u8, 97u8, 114u8, 100u8, 110u8, 105u8, 122u8, 111u8, 118u8, 97u8, 110u8,
+                    97u8, 32u8, 114u8, 101u8, 115u8, 105u8, 106u8, 97u8, 110u8, 115u8, 107u8, 97u8,
+                    32u8, 111u8, 114u8, 116u8, 111u8, 103u8, 114u8, 97u8, 102u8, 105u8, 106u8,
+                    97u8, 78u8, 101u8, 109u8, 97u8, 196u8, 141u8, 107u8, 97u8, 32u8, 111u8, 114u8,
+                    116u8, 111u8, 103u8, 114u8, 97u8, 102u8, 105u8, 106u8, 97u8, 32u8, 105u8,
+                    122u8, 32u8, 49u8, 57u8, 57u8, 54u8, 73u8, 115u8, 116u8, 111u8, 196u8, 141u8,
+                    110u8, 105u8, 32u8, 97u8, 114u8, 109u8, 101u8, 110u8, 105u8, 106u8, 115u8,
+                    107u8, 105u8, 90u8, 97u8, 112u8, 97u8, 100u8, 110u8, 111u8, 32u8, 106u8, 101u8,
+                    114u8, 109u8, 101u8, 110u8, 115u8, 107u8, 97u8, 85u8, 106u8, 101u8, 100u8,
+                    105u8, 110u8, 106u8, 101u8, 110u8, 32u8, 116u8, 117u8, 114u8, 115u8, 107u8,
+                    105u8, 32u8, 108u8, 97u8, 116u8, 105u8, 110u8, 105u8, 196u8, 141u8, 110u8,
+                    105u8, 32u8, 97u8, 108u8, 102u8, 97u8, 98u8, 101u8, 116u8, 83u8, 97u8, 110u8,
+                    32u8, 196u8, 144u8, 111u8, 114u8, 196u8, 145u8, 105u8, 111u8, 47u8, 66u8,
+                    105u8, 108u8, 97u8, 32u8, 100u8, 105u8, 106u8, 97u8, 108u8, 101u8, 107u8,
+                    116u8, 66u8, 117u8, 110u8, 116u8, 108u8, 105u8, 110u8, 103u8, 73u8, 80u8, 65u8,
+                    32u8, 102u8, 111u8, 110u8, 101u8, 116u8, 105u8, 107u8, 97u8, 85u8, 80u8, 65u8,
+                    32u8, 102u8, 111u8, 110u8, 101u8, 116u8, 105u8, 107u8, 97u8, 76u8, 105u8,
+                    112u8, 111u8, 118u8, 105u8, 196u8, 141u8, 107u8, 105u8, 32u8, 100u8, 105u8,
+                    106u8, 97u8, 108u8, 101u8, 107u8, 116u8, 32u8, 114u8, 101u8, 115u8, 105u8,
+                    106u8, 97u8, 110u8, 115u8, 107u8, 105u8, 77u8, 111u8, 110u8, 111u8, 116u8,
+                    111u8, 110u8, 105u8, 107u8, 78u8, 97u8, 116u8, 105u8, 115u8, 111u8, 110u8,
+                    101u8, 32u8, 100u8, 105u8, 106u8, 97u8, 108u8, 101u8, 107u8, 116u8, 71u8,
+                    110u8, 106u8, 105u8, 118u8, 97u8, 47u8, 78u8, 106u8, 105u8, 118u8, 97u8, 32u8,
+                    100u8, 105u8, 106u8, 97u8, 108u8, 107u8, 101u8, 107u8, 116u8, 79u8, 115u8,
+                    101u8, 97u8, 107u8, 111u8, 47u8, 79u8, 115u8, 111u8, 106u8, 97u8, 110u8, 101u8,
+                    32u8, 100u8, 105u8, 106u8, 97u8, 108u8, 101u8, 107u8, 116u8, 80u8, 111u8,
+                    108u8, 105u8, 116u8, 111u8, 110u8, 105u8, 107u8, 75u8, 111u8, 109u8, 112u8,
+                    106u8, 117u8, 116u8, 101u8, 114u8, 82u8, 101u8, 118u8, 105u8, 100u8, 105u8,
+                    114u8, 97u8, 110u8, 97u8, 32u8, 111u8, 114u8, 116u8, 111u8, 103u8, 114u8, 97u8,
+                    102u8, 105u8, 106u8, 97u8, 82u8, 101u8, 115u8, 105u8, 106u8, 97u8, 110u8, 83u8,
+                    97u8, 104u8, 111u8, 197u8, 160u8, 107u8, 111u8, 116u8, 115u8, 107u8, 105u8,
+                    32u8, 115u8, 116u8, 97u8, 110u8, 100u8, 97u8, 114u8, 100u8, 110u8, 105u8, 32u8,
+                    69u8, 110u8, 103u8, 108u8, 101u8, 115u8, 107u8, 105u8, 83u8, 107u8, 97u8,
+                    117u8, 122u8, 83u8, 116u8, 111u8, 108u8, 118u8, 105u8, 99u8, 97u8, 47u8, 83u8,
+                    111u8, 108u8, 98u8, 105u8, 99u8, 97u8, 32u8, 100u8, 105u8, 106u8, 97u8, 108u8,
+                    101u8, 107u8, 116u8, 84u8, 97u8, 114u8, 97u8, 115u8, 107u8, 105u8, 106u8,
+                    101u8, 118u8, 105u8, 196u8, 141u8, 107u8, 97u8, 32u8, 111u8, 114u8, 116u8,
+                    111u8, 103u8, 114u8, 97u8, 102u8, 105u8, 106u8, 97u8, 86u8, 97u8, 108u8, 101u8,
+                    110u8, 99u8, 105u8, 106u8, 115u8, 107u8, 97u8,
+                ])
+            },
+        )
+    },
+};
+static SR_SR_CYRL: &DataStruct = &::icu_displaynames::provider::VariantDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    49u8, 54u8, 48u8, 54u8, 110u8, 105u8, 99u8, 116u8, 49u8, 54u8, 57u8, 52u8,
+                    97u8, 99u8, 97u8, 100u8, 49u8, 57u8, 48u8, 49u8, 0u8, 0u8, 0u8, 0u8, 49u8,
+                    57u8, 57u8, 52u8, 0u8, 0u8, 0u8, 0u8, 49u8, 57u8, 57u8, 54u8, 0u8, 0u8, 0u8,
+                    0u8, 97u8, 114u8, 101u8, 118u8, 101u8, 108u8, 97u8, 0u8, 97u8, 114u8, 101u8,
+                    118u8, 109u8, 100u8, 97u8, 0u8, 98u8, 97u8, 107u8, 117u8, 49u8, 57u8, 50u8,
+                    54u8, 98u8, 105u8, 115u8, 107u8, 101u8, 0u8, 0u8, 0u8, 98u8, 111u8, 111u8,
+                    110u8, 116u8, 0u8, 0u8, 0u8, 102u8, 111u8, 110u8, 105u8, 112u8, 97u8, 0u8, 0u8,
+                    102u8, 111u8, 110u8, 117u8, 112u8, 97u8, 0u8, 0u8, 108u8, 105u8, 112u8, 97u8,
+                    119u8, 0u8, 0u8, 0u8, 109u8, 111u8, 110u8, 111u8, 116u8, 111u8, 110u8, 0u8,
+                    110u8, 101u8, 100u8, 105u8, 115u8, 0u8, 0u8, 0u8, 110u8, 106u8, 105u8, 118u8,
+                    97u8, 0u8, 0u8, 0u8, 111u8, 115u8, 111u8, 106u8, 115u8, 0u8, 0u8, 0u8, 112u8,
+                    111u8, 108u8, 121u8, 116u8, 111u8, 110u8, 0u8, 112u8, 111u8, 115u8, 105u8,
+                    120u8, 0u8, 0u8, 0u8, 114u8, 101u8, 118u8, 105u8, 115u8, 101u8, 100u8, 0u8,
+                    114u8, 111u8, 122u8, 97u8, 106u8, 0u8, 0u8, 0u8, 115u8, 97u8, 97u8, 104u8,
+                    111u8, 0u8, 0u8, 0u8, 115u8, 99u8, 111u8, 116u8, 108u8, 97u8, 110u8, 100u8,
+                    115u8, 99u8, 111u8, 117u8, 115u8, 101u8, 0u8, 0u8, 115u8, 111u8, 108u8, 98u8,
+                    97u8, 0u8, 0u8, 0u8, 116u8, 97u8, 114u8, 97u8, 115u8, 107u8, 0u8, 0u8, 118u8,
+                    97u8, 108u8, 101u8, 110u8, 99u8, 105u8, 97u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    27u8, 0u8, 0u8, 0u8, 0u8, 0u8, 71u8, 0u8, 113u8, 0u8, 177u8, 0u8, 253u8, 0u8,
+                    44u8, 1u8, 79u8, 1u8, 112u8, 1u8, 175u8, 1u8, 220u8, 1u8, 236u8, 1u8, 3u8, 2u8,
+                    26u8, 2u8, 82u8, 2u8, 100u8, 2u8, 133u8, 2u8, 171u8, 2u8, 215u8, 2u8, 233u8,
+                    2u8, 251u8, 2u8, 38u8, 3u8, 52u8, 3u8, 60u8, 3u8, 112u8, 3u8, 122u8, 3u8,
+                    170u8, 3u8, 221u8, 3u8, 208u8, 164u8, 209u8, 128u8, 208u8, 176u8, 208u8, 189u8,
+                    209u8, 134u8, 209u8, 131u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 32u8,
+                    208u8, 184u8, 208u8, 183u8, 32u8, 208u8, 186u8, 208u8, 176u8, 209u8, 129u8,
+                    208u8, 189u8, 208u8, 190u8, 208u8, 179u8, 32u8, 209u8, 129u8, 209u8, 128u8,
+                    208u8, 181u8, 208u8, 180u8, 209u8, 154u8, 208u8, 181u8, 208u8, 179u8, 32u8,
+                    208u8, 178u8, 208u8, 181u8, 208u8, 186u8, 208u8, 176u8, 32u8, 208u8, 180u8,
+                    208u8, 190u8, 32u8, 49u8, 54u8, 48u8, 54u8, 46u8, 208u8, 160u8, 208u8, 176u8,
+                    208u8, 189u8, 208u8, 184u8, 32u8, 208u8, 188u8, 208u8, 190u8, 208u8, 180u8,
+                    208u8, 181u8, 209u8, 128u8, 208u8, 189u8, 208u8, 184u8, 32u8, 209u8, 132u8,
+                    209u8, 128u8, 208u8, 176u8, 208u8, 189u8, 209u8, 134u8, 209u8, 131u8, 209u8,
+                    129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 162u8, 209u8, 128u8, 208u8, 176u8,
+                    208u8, 180u8, 208u8, 184u8, 209u8, 134u8, 208u8, 184u8, 208u8, 190u8, 208u8,
+                    189u8, 208u8, 176u8, 208u8, 187u8, 208u8, 189u8, 208u8, 176u8, 32u8, 208u8,
+                    189u8, 208u8, 181u8, 208u8, 188u8, 208u8, 176u8, 209u8, 135u8, 208u8, 186u8,
+                    208u8, 176u8, 32u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 208u8, 190u8,
+                    208u8, 179u8, 209u8, 128u8, 208u8, 176u8, 209u8, 132u8, 208u8, 184u8, 209u8,
+                    152u8, 208u8, 176u8, 208u8, 161u8, 209u8, 130u8, 208u8, 176u8, 208u8, 189u8,
+                    208u8, 180u8, 208u8, 176u8, 209u8, 128u8, 208u8, 180u8, 208u8, 189u8, 208u8,
+                    184u8, 208u8, 183u8, 208u8, 190u8, 208u8, 178u8, 208u8, 176u8, 208u8, 189u8,
+                    208u8, 176u8, 32u8, 209u8, 128u8, 208u8, 181u8, 209u8, 129u8, 208u8, 184u8,
+                    209u8, 152u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                    176u8, 32u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 208u8, 190u8, 208u8,
+                    179u8, 209u8, 128u8, 208u8, 176u8, 209u8, 132u8, 208u8, 184u8, 209u8, 152u8,
+                    208u8, 176u8, 208u8, 157u8, 208u8, 181u8, 208u8, 188u8, 208u8, 176u8, 209u8,
+                    135u8, 208u8, 186u8, 208u8, 176u8, 32u8, 208u8, 190u8, 209u8, 128u8, 209u8,
+                    130u8, 208u8, 190u8, 208u8, 179u8, 209u8, 128u8, 208u8, 176u8, 209u8, 132u8,
+                    208u8, 184u8, 209u8, 152u8, 208u8, 176u8, 32u8, 208u8, 184u8, 208u8, 183u8,
+                    32u8, 49u8, 57u8, 57u8, 54u8, 208u8, 152u8, 209u8, 129u8, 209u8, 130u8, 208u8,
+                    190u8, 209u8, 135u8, 208u8, 189u8, 208u8, 184u8, 32u8, 208u8, 176u8, 209u8,
+                    128u8, 208u8, 188u8, 208u8, 181u8, 208u8, 189u8, 208u8, 184u8, 209u8, 152u8,
+                    209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 208u8, 151u8, 208u8, 176u8, 208u8,
+                    191u8, 208u8, 176u8, 208u8, 180u8, 208u8, 189u8, 208u8, 190u8, 32u8, 209u8,
+                    152u8, 208u8, 181u8, 209u8, 128u8, 208u8, 188u8, 208u8, 181u8, 208u8, 189u8,
+                    209u8, 129u8, 208u8, 186u8, 208u8, 176u8, 208u8, 163u8, 209u8, 152u8, 208u8,
+                    181u8, 208u8, 180u8, 208u8, 184u8, 209u8, 154u8, 208u8, 181u8, 208u8, 189u8,
+                    32u8, 209u8, 130u8, 209u8, 131u8, 209u8, 128u8, 209u8, 129u8, 208u8, 186u8,
+                    208u8, 184u8, 32u8, 208u8, 187u8, 208u8, 176u8, 209u8, 130u8, 208u8, 184u8,
+                    208u8, 189u8, 208u8, 184u8, 209u8, 135u8, 208u8, 189u8, 208u8, 184u8, 32u8,
+                    208u8, 176u8, 208u8, 187u8, 209u8, 132u8, 208u8, 176u8, 208u8, 177u8, 208u8,
+                    181u8, 209u8, 130u8, 208u8, 161u8, 208u8, 176u8, 208u8, 189u8, 32u8, 208u8,
+                    130u8, 208u8, 190u8, 209u8, 128u8, 209u8, 146u8, 208u8, 184u8, 208u8, 190u8,
+                    47u8, 208u8, 145u8, 208u8, 184u8, 208u8, 187u8, 208u8, 176u8, 32u8, 208u8,
+                    180u8, 208u8, 184u8, 209u8, 152u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8,
+                    208u8, 186u8, 209u8, 130u8, 208u8, 145u8, 209u8, 131u8, 208u8, 189u8, 209u8,
+                    130u8, 208u8, 187u8, 208u8, 184u8, 208u8, 189u8, 208u8, 179u8, 208u8, 152u8,
+                    208u8, 159u8, 208u8, 144u8, 32u8, 209u8, 132u8, 208u8, 190u8, 208u8, 189u8,
+                    208u8, 181u8, 209u8, 130u8, 208u8, 184u8, 208u8, 186u8, 208u8, 176u8, 208u8,
+                    163u8, 208u8, 159u8, 208u8, 144u8, 32u8, 209u8, 132u8, 208u8, 190u8, 208u8,
+                    189u8, 208u8, 181u8, 209u8, 130u8, 208u8, 184u8, 208u8, 186u8, 208u8, 176u8,
+                    208u8, 155u8, 208u8, 184u8, 208u8, 191u8, 208u8, 190u8, 208u8, 178u8, 208u8,
+                    184u8, 209u8, 135u8, 208u8, 186u8, 208u8, 184u8, 32u8, 208u8, 180u8, 208u8,
+                    184u8, 209u8, 152u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8, 186u8,
+                    209u8, 130u8, 32u8, 209u8, 128u8, 208u8, 181u8, 209u8, 129u8, 208u8, 184u8,
+                    209u8, 152u8, 208u8, 176u8, 208u8, 189u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                    184u8, 208u8, 156u8, 208u8, 190u8, 208u8, 189u8, 208u8, 190u8, 209u8, 130u8,
+                    208u8, 190u8, 208u8, 189u8, 208u8, 184u8, 208u8, 186u8, 208u8, 157u8, 208u8,
+                    176u8, 209u8, 130u8, 208u8, 184u8, 209u8, 129u8, 208u8, 190u8, 208u8, 189u8,
+                    208u8, 181u8, 32u8, 208u8, 180u8, 208u8, 184u8, 209u8, 152u8, 208u8, 176u8,
+                    208u8, 187u8, 208u8, 181u8, 208u8, 186u8, 209u8, 130u8, 208u8, 147u8, 209u8,
+                    154u8, 208u8, 184u8, 208u8, 178u8, 208u8, 176u8, 47u8, 208u8, 138u8, 208u8,
+                    184u8, 208u8, 178u8, 208u8, 176u8, 32u8, 208u8, 180u8, 208u8, 184u8, 209u8,
+                    152u8, 208u8, 176u8, 208u8, 187u8, 208u8, 186u8, 208u8, 181u8, 208u8, 186u8,
+                    209u8, 130u8, 208u8, 158u8, 209u8, 129u8, 208u8, 181u8, 208u8, 176u8, 208u8,
+                    186u8, 208u8, 190u8, 47u8, 208u8, 158u8, 209u8, 129u8, 208u8, 190u8, 209u8,
+                    152u8, 208u8, 176u8, 208u8, 189u8, 208u8, 181u8, 32u8, 208u8, 180u8, 208u8,
+                    184u8, 209u8, 152u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8, 186u8,
+                    209u8, 130u8, 208u8, 159u8, 208u8, 190u8, 208u8, 187u8, 208u8, 184u8, 209u8,
+                    130u8, 208u8, 190u8, 208u8, 189u8, 208u8, 184u8, 208u8, 186u8, 208u8, 154u8,
+                    208u8, 190u8, 208u8, 188u8, 208u8, 191u8, 209u8, 152u8, 209u8, 131u8, 209u8,
+                    130u8, 208u8, 181u8, 209u8, 128u8, 208u8, 160u8, 208u8, 181u8, 208u8, 178u8,
+                    208u8, 184u8, 208u8, 180u8, 208u8, 184u8, 209u8, 128u8, 208u8, 176u8, 208u8,
+                    189u8, 208u8, 176u8, 32u8, 208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 208u8,
+                    190u8, 208u8, 179u8, 209u8, 128u8, 208u8, 176u8, 209u8, 132u8, 208u8, 184u8,
+                    209u8, 152u8, 208u8, 176u8, 208u8, 160u8, 208u8, 181u8, 209u8, 129u8, 208u8,
+                    184u8, 209u8, 152u8, 208u8, 176u8, 208u8, 189u8, 208u8, 161u8, 208u8, 176u8,
+                    209u8, 133u8, 208u8, 190u8, 208u8, 168u8, 208u8, 186u8, 208u8, 190u8, 209u8,
+                    130u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 32u8, 209u8, 129u8, 209u8,
+                    130u8, 208u8, 176u8, 208u8, 189u8, 208u8, 180u8, 208u8, 176u8, 209u8, 128u8,
+                    208u8, 180u8, 208u8, 189u8, 208u8, 184u8, 32u8, 208u8, 149u8, 208u8, 189u8,
+                    208u8, 179u8, 208u8, 187u8, 208u8, 181u8, 209u8, 129u8, 208u8, 186u8, 208u8,
+                    184u8, 208u8, 161u8, 208u8, 186u8, 208u8, 176u8, 209u8, 131u8, 208u8, 183u8,
+                    208u8, 161u8, 209u8, 130u8, 208u8, 190u8, 208u8, 187u8, 208u8, 178u8, 208u8,
+                    184u8, 209u8, 134u8, 208u8, 176u8, 47u8, 208u8, 161u8, 208u8, 190u8, 208u8,
+                    187u8, 208u8, 177u8, 208u8, 184u8, 209u8, 134u8, 208u8, 176u8, 32u8, 208u8,
+                    180u8, 208u8, 184u8, 209u8, 152u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8,
+                    208u8, 186u8, 209u8, 130u8, 208u8, 162u8, 208u8, 176u8, 209u8, 128u8, 208u8,
+                    176u8, 209u8, 129u8, 208u8, 186u8, 208u8, 184u8, 209u8, 152u8, 208u8, 181u8,
+                    208u8, 178u8, 208u8, 184u8, 209u8, 135u8, 208u8, 186u8, 208u8, 176u8, 32u8,
+                    208u8, 190u8, 209u8, 128u8, 209u8, 130u8, 208u8, 190u8, 208u8, 179u8, 209u8,
+                    128u8, 208u8, 176u8, 209u8, 132u8, 208u8, 184u8, 209u8, 152u8, 208u8, 176u8,
+                    208u8, 146u8, 208u8, 176u8, 208u8, 187u8, 208u8, 181u8, 208u8, 189u8, 209u8,
+                    134u8, 208u8, 184u8, 209u8, 152u8, 209u8, 129u8, 208u8, 186u8, 208u8, 176u8,
+                ])
+            },
+        )
+    },
+};
+static TH: &DataStruct = &::icu_displaynames::provider::VariantDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    49u8, 54u8, 48u8, 54u8, 110u8, 105u8, 99u8, 116u8, 49u8, 54u8, 57u8, 52u8,
+                    97u8, 99u8, 97u8, 100u8, 49u8, 57u8, 48u8, 49u8, 0u8, 0u8, 0u8, 0u8, 49u8,
+                    57u8, 53u8, 57u8, 97u8, 99u8, 97u8, 100u8, 49u8, 57u8, 57u8, 52u8, 0u8, 0u8,
+                    0u8, 0u8, 49u8, 57u8, 57u8, 54u8, 0u8, 0u8, 0u8, 0u8, 97u8, 108u8, 97u8, 108u8,
+                    99u8, 57u8, 55u8, 0u8, 97u8, 108u8, 117u8, 107u8, 117u8, 0u8, 0u8, 0u8, 97u8,
+                    114u8, 101u8, 118u8, 101u8, 108u8, 97u8, 0u8, 97u8, 114u8, 101u8, 118u8, 109u8,
+                    100u8, 97u8, 0u8, 98u8, 97u8, 107u8, 117u8, 49u8, 57u8, 50u8, 54u8, 98u8, 97u8,
+                    117u8, 100u8, 100u8, 104u8, 97u8, 0u8, 98u8, 105u8, 115u8, 99u8, 97u8, 121u8,
+                    97u8, 110u8, 98u8, 105u8, 115u8, 107u8, 101u8, 0u8, 0u8, 0u8, 98u8, 111u8,
+                    104u8, 111u8, 114u8, 105u8, 99u8, 0u8, 98u8, 111u8, 111u8, 110u8, 116u8, 0u8,
+                    0u8, 0u8, 100u8, 97u8, 106u8, 110u8, 107u8, 111u8, 0u8, 0u8, 101u8, 109u8,
+                    111u8, 100u8, 101u8, 110u8, 103u8, 0u8, 102u8, 111u8, 110u8, 105u8, 112u8,
+                    97u8, 0u8, 0u8, 102u8, 111u8, 110u8, 117u8, 112u8, 97u8, 0u8, 0u8, 102u8,
+                    111u8, 110u8, 120u8, 115u8, 97u8, 109u8, 112u8, 104u8, 101u8, 112u8, 98u8,
+                    117u8, 114u8, 110u8, 0u8, 104u8, 111u8, 103u8, 110u8, 111u8, 114u8, 115u8,
+                    107u8, 105u8, 116u8, 105u8, 104u8, 97u8, 115u8, 97u8, 0u8, 106u8, 97u8, 117u8,
+                    101u8, 114u8, 0u8, 0u8, 0u8, 106u8, 121u8, 117u8, 116u8, 112u8, 105u8, 110u8,
+                    103u8, 107u8, 107u8, 99u8, 111u8, 114u8, 0u8, 0u8, 0u8, 107u8, 115u8, 99u8,
+                    111u8, 114u8, 0u8, 0u8, 0u8, 108u8, 97u8, 117u8, 107u8, 105u8, 107u8, 97u8,
+                    0u8, 108u8, 105u8, 112u8, 97u8, 119u8, 0u8, 0u8, 0u8, 108u8, 117u8, 110u8,
+                    97u8, 49u8, 57u8, 49u8, 56u8, 109u8, 101u8, 116u8, 101u8, 108u8, 107u8, 111u8,
+                    0u8, 109u8, 111u8, 110u8, 111u8, 116u8, 111u8, 110u8, 0u8, 110u8, 100u8, 121u8,
+                    117u8, 107u8, 97u8, 0u8, 0u8, 110u8, 101u8, 100u8, 105u8, 115u8, 0u8, 0u8, 0u8,
+                    110u8, 106u8, 105u8, 118u8, 97u8, 0u8, 0u8, 0u8, 110u8, 117u8, 108u8, 105u8,
+                    107u8, 0u8, 0u8, 0u8, 111u8, 115u8, 111u8, 106u8, 115u8, 0u8, 0u8, 0u8, 112u8,
+                    97u8, 109u8, 97u8, 107u8, 97u8, 0u8, 0u8, 112u8, 101u8, 116u8, 114u8, 49u8,
+                    55u8, 48u8, 56u8, 112u8, 105u8, 110u8, 121u8, 105u8, 110u8, 0u8, 0u8, 112u8,
+                    111u8, 108u8, 121u8, 116u8, 111u8, 110u8, 0u8, 112u8, 111u8, 115u8, 105u8,
+                    120u8, 0u8, 0u8, 0u8, 112u8, 117u8, 116u8, 101u8, 114u8, 0u8, 0u8, 0u8, 114u8,
+                    101u8, 118u8, 105u8, 115u8, 101u8, 100u8, 0u8, 114u8, 105u8, 103u8, 105u8,
+                    107u8, 0u8, 0u8, 0u8, 114u8, 111u8, 122u8, 97u8, 106u8, 0u8, 0u8, 0u8, 114u8,
+                    117u8, 109u8, 103u8, 114u8, 0u8, 0u8, 0u8, 115u8, 97u8, 97u8, 104u8, 111u8,
+                    0u8, 0u8, 0u8, 115u8, 99u8, 111u8, 116u8, 108u8, 97u8, 110u8, 100u8, 115u8,
+                    99u8, 111u8, 117u8, 115u8, 101u8, 0u8, 0u8, 115u8, 111u8, 108u8, 98u8, 97u8,
+                    0u8, 0u8, 0u8, 115u8, 117u8, 114u8, 109u8, 105u8, 114u8, 97u8, 110u8, 115u8,
+                    117u8, 114u8, 115u8, 105u8, 108u8, 118u8, 0u8, 115u8, 117u8, 116u8, 115u8,
+                    105u8, 108u8, 118u8, 0u8, 116u8, 97u8, 114u8, 97u8, 115u8, 107u8, 0u8, 0u8,
+                    117u8, 99u8, 99u8, 111u8, 114u8, 0u8, 0u8, 0u8, 117u8, 99u8, 114u8, 99u8,
+                    111u8, 114u8, 0u8, 0u8, 117u8, 108u8, 115u8, 116u8, 101u8, 114u8, 0u8, 0u8,
+                    118u8, 97u8, 105u8, 100u8, 105u8, 107u8, 97u8, 0u8, 118u8, 97u8, 108u8, 101u8,
+                    110u8, 99u8, 105u8, 97u8, 118u8, 97u8, 108u8, 108u8, 97u8, 100u8, 101u8, 114u8,
+                    119u8, 97u8, 100u8, 101u8, 103u8, 105u8, 108u8, 101u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    63u8, 0u8, 0u8, 0u8, 0u8, 0u8, 86u8, 0u8, 149u8, 0u8, 227u8, 0u8, 248u8, 0u8,
+                    55u8, 1u8, 120u8, 1u8, 125u8, 2u8, 179u8, 2u8, 233u8, 2u8, 28u8, 3u8, 121u8,
+                    3u8, 154u8, 3u8, 178u8, 3u8, 1u8, 4u8, 46u8, 4u8, 70u8, 4u8, 118u8, 4u8, 178u8,
+                    4u8, 214u8, 4u8, 242u8, 4u8, 16u8, 5u8, 85u8, 5u8, 139u8, 5u8, 163u8, 5u8,
+                    187u8, 5u8, 253u8, 5u8, 39u8, 6u8, 87u8, 6u8, 108u8, 6u8, 198u8, 6u8, 218u8,
+                    6u8, 13u8, 7u8, 40u8, 7u8, 55u8, 7u8, 118u8, 7u8, 188u8, 7u8, 233u8, 7u8, 68u8,
+                    8u8, 125u8, 8u8, 139u8, 8u8, 157u8, 8u8, 184u8, 8u8, 217u8, 8u8, 241u8, 8u8,
+                    42u8, 9u8, 87u8, 9u8, 108u8, 9u8, 126u8, 9u8, 138u8, 9u8, 207u8, 9u8, 225u8,
+                    9u8, 54u8, 10u8, 81u8, 10u8, 108u8, 10u8, 132u8, 10u8, 204u8, 10u8, 5u8, 11u8,
+                    86u8, 11u8, 113u8, 11u8, 131u8, 11u8, 158u8, 11u8, 188u8, 11u8, 224u8, 184u8,
+                    155u8, 224u8, 184u8, 165u8, 224u8, 184u8, 178u8, 224u8, 184u8, 162u8, 224u8,
+                    184u8, 162u8, 224u8, 184u8, 184u8, 224u8, 184u8, 132u8, 224u8, 184u8, 129u8,
+                    224u8, 184u8, 165u8, 224u8, 184u8, 178u8, 224u8, 184u8, 135u8, 224u8, 184u8,
+                    157u8, 224u8, 184u8, 163u8, 224u8, 184u8, 177u8, 224u8, 185u8, 136u8, 224u8,
+                    184u8, 135u8, 224u8, 185u8, 128u8, 224u8, 184u8, 168u8, 224u8, 184u8, 170u8,
+                    224u8, 184u8, 150u8, 224u8, 184u8, 182u8, 224u8, 184u8, 135u8, 224u8, 184u8,
+                    155u8, 224u8, 184u8, 181u8, 32u8, 224u8, 184u8, 132u8, 46u8, 224u8, 184u8,
+                    168u8, 46u8, 32u8, 49u8, 54u8, 48u8, 54u8, 224u8, 184u8, 149u8, 224u8, 184u8,
+                    173u8, 224u8, 184u8, 153u8, 224u8, 184u8, 149u8, 224u8, 185u8, 137u8, 224u8,
+                    184u8, 153u8, 224u8, 184u8, 162u8, 224u8, 184u8, 184u8, 224u8, 184u8, 132u8,
+                    224u8, 185u8, 131u8, 224u8, 184u8, 171u8, 224u8, 184u8, 161u8, 224u8, 185u8,
+                    136u8, 224u8, 184u8, 157u8, 224u8, 184u8, 163u8, 224u8, 184u8, 177u8, 224u8,
+                    185u8, 136u8, 224u8, 184u8, 135u8, 224u8, 185u8, 128u8, 224u8, 184u8, 168u8,
+                    224u8, 184u8, 170u8, 224u8, 185u8, 128u8, 224u8, 184u8, 162u8, 224u8, 184u8,
+                    173u8, 224u8, 184u8, 163u8, 224u8, 184u8, 161u8, 224u8, 184u8, 177u8, 224u8,
+                    184u8, 153u8, 224u8, 184u8, 173u8, 224u8, 184u8, 173u8, 224u8, 184u8, 163u8,
+                    224u8, 185u8, 140u8, 224u8, 185u8, 130u8, 224u8, 184u8, 152u8, 224u8, 184u8,
+                    129u8, 224u8, 184u8, 163u8, 224u8, 184u8, 178u8, 224u8, 184u8, 159u8, 224u8,
+                    184u8, 181u8, 224u8, 184u8, 148u8, 224u8, 184u8, 177u8, 224u8, 185u8, 137u8,
+                    224u8, 184u8, 135u8, 224u8, 185u8, 128u8, 224u8, 184u8, 148u8, 224u8, 184u8,
+                    180u8, 224u8, 184u8, 161u8, 224u8, 184u8, 167u8, 224u8, 184u8, 180u8, 224u8,
+                    184u8, 138u8, 224u8, 184u8, 178u8, 224u8, 184u8, 129u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 163u8, 224u8, 184u8, 173u8, 224u8, 184u8, 177u8, 224u8, 184u8,
+                    129u8, 224u8, 184u8, 130u8, 224u8, 184u8, 163u8, 224u8, 184u8, 176u8, 224u8,
+                    185u8, 128u8, 224u8, 184u8, 163u8, 224u8, 185u8, 128u8, 224u8, 184u8, 139u8,
+                    224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 153u8, 224u8, 184u8,
+                    161u8, 224u8, 184u8, 178u8, 224u8, 184u8, 149u8, 224u8, 184u8, 163u8, 224u8,
+                    184u8, 178u8, 224u8, 184u8, 144u8, 224u8, 184u8, 178u8, 224u8, 184u8, 153u8,
+                    224u8, 185u8, 128u8, 224u8, 184u8, 162u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                    163u8, 224u8, 184u8, 161u8, 224u8, 184u8, 177u8, 224u8, 184u8, 153u8, 224u8,
+                    184u8, 173u8, 224u8, 184u8, 173u8, 224u8, 184u8, 163u8, 224u8, 185u8, 140u8,
+                    224u8, 185u8, 130u8, 224u8, 184u8, 152u8, 224u8, 184u8, 129u8, 224u8, 184u8,
+                    163u8, 224u8, 184u8, 178u8, 224u8, 184u8, 159u8, 224u8, 184u8, 181u8, 224u8,
+                    184u8, 155u8, 224u8, 184u8, 181u8, 32u8, 49u8, 57u8, 57u8, 54u8, 224u8, 184u8,
+                    129u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 184u8, 150u8, 224u8,
+                    184u8, 173u8, 224u8, 184u8, 148u8, 224u8, 184u8, 173u8, 224u8, 184u8, 177u8,
+                    224u8, 184u8, 129u8, 224u8, 184u8, 169u8, 224u8, 184u8, 163u8, 224u8, 185u8,
+                    128u8, 224u8, 184u8, 155u8, 224u8, 185u8, 135u8, 224u8, 184u8, 153u8, 224u8,
+                    184u8, 173u8, 224u8, 184u8, 177u8, 224u8, 184u8, 129u8, 224u8, 184u8, 169u8,
+                    224u8, 184u8, 163u8, 224u8, 185u8, 130u8, 224u8, 184u8, 163u8, 224u8, 184u8,
+                    161u8, 224u8, 184u8, 177u8, 224u8, 184u8, 153u8, 224u8, 184u8, 149u8, 224u8,
+                    184u8, 178u8, 224u8, 184u8, 161u8, 224u8, 185u8, 128u8, 224u8, 184u8, 129u8,
+                    224u8, 184u8, 147u8, 224u8, 184u8, 145u8, 224u8, 185u8, 140u8, 224u8, 184u8,
+                    130u8, 224u8, 184u8, 173u8, 224u8, 184u8, 135u8, 224u8, 184u8, 170u8, 224u8,
+                    184u8, 161u8, 224u8, 184u8, 178u8, 224u8, 184u8, 132u8, 224u8, 184u8, 161u8,
+                    224u8, 184u8, 171u8, 224u8, 185u8, 137u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                    135u8, 224u8, 184u8, 170u8, 224u8, 184u8, 161u8, 224u8, 184u8, 184u8, 224u8,
+                    184u8, 148u8, 224u8, 184u8, 173u8, 224u8, 185u8, 128u8, 224u8, 184u8, 161u8,
+                    224u8, 184u8, 163u8, 224u8, 184u8, 180u8, 224u8, 184u8, 129u8, 224u8, 184u8,
+                    177u8, 224u8, 184u8, 153u8, 224u8, 185u8, 129u8, 224u8, 184u8, 165u8, 224u8,
+                    184u8, 176u8, 224u8, 184u8, 171u8, 224u8, 185u8, 137u8, 224u8, 184u8, 173u8,
+                    224u8, 184u8, 135u8, 224u8, 184u8, 170u8, 224u8, 184u8, 161u8, 224u8, 184u8,
+                    184u8, 224u8, 184u8, 148u8, 224u8, 184u8, 163u8, 224u8, 184u8, 177u8, 224u8,
+                    184u8, 144u8, 224u8, 184u8, 170u8, 224u8, 184u8, 160u8, 224u8, 184u8, 178u8,
+                    32u8, 224u8, 184u8, 137u8, 224u8, 184u8, 154u8, 224u8, 184u8, 177u8, 224u8,
+                    184u8, 154u8, 224u8, 184u8, 158u8, 224u8, 184u8, 180u8, 224u8, 184u8, 161u8,
+                    224u8, 184u8, 158u8, 224u8, 185u8, 140u8, 224u8, 184u8, 155u8, 224u8, 184u8,
+                    181u8, 32u8, 49u8, 57u8, 57u8, 55u8, 224u8, 184u8, 160u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 169u8, 224u8, 184u8, 178u8, 224u8, 184u8, 158u8, 224u8, 184u8,
+                    183u8, 224u8, 185u8, 137u8, 224u8, 184u8, 153u8, 224u8, 185u8, 128u8, 224u8,
+                    184u8, 161u8, 224u8, 184u8, 183u8, 224u8, 184u8, 173u8, 224u8, 184u8, 135u8,
+                    224u8, 184u8, 173u8, 224u8, 184u8, 165u8, 224u8, 184u8, 185u8, 224u8, 184u8,
+                    132u8, 224u8, 184u8, 185u8, 224u8, 184u8, 173u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 163u8, 224u8, 185u8, 140u8, 224u8, 185u8, 128u8, 224u8, 184u8, 161u8,
+                    224u8, 185u8, 128u8, 224u8, 184u8, 153u8, 224u8, 184u8, 181u8, 224u8, 184u8,
+                    162u8, 224u8, 184u8, 149u8, 224u8, 184u8, 176u8, 224u8, 184u8, 167u8, 224u8,
+                    184u8, 177u8, 224u8, 184u8, 153u8, 224u8, 184u8, 173u8, 224u8, 184u8, 173u8,
+                    224u8, 184u8, 129u8, 224u8, 184u8, 173u8, 224u8, 184u8, 178u8, 224u8, 184u8,
+                    163u8, 224u8, 185u8, 140u8, 224u8, 185u8, 128u8, 224u8, 184u8, 161u8, 224u8,
+                    185u8, 128u8, 224u8, 184u8, 153u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8,
+                    224u8, 184u8, 149u8, 224u8, 184u8, 176u8, 224u8, 184u8, 167u8, 224u8, 184u8,
+                    177u8, 224u8, 184u8, 153u8, 224u8, 184u8, 149u8, 224u8, 184u8, 129u8, 224u8,
+                    184u8, 149u8, 224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 184u8, 173u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 129u8, 224u8, 184u8, 169u8, 224u8, 184u8,
+                    163u8, 224u8, 184u8, 165u8, 224u8, 184u8, 176u8, 224u8, 184u8, 149u8, 224u8,
+                    184u8, 180u8, 224u8, 184u8, 153u8, 224u8, 185u8, 128u8, 224u8, 184u8, 149u8,
+                    224u8, 184u8, 173u8, 224u8, 184u8, 163u8, 224u8, 185u8, 140u8, 224u8, 184u8,
+                    129u8, 224u8, 184u8, 180u8, 224u8, 184u8, 129u8, 224u8, 185u8, 129u8, 224u8,
+                    184u8, 154u8, 224u8, 184u8, 154u8, 224u8, 184u8, 132u8, 224u8, 184u8, 163u8,
+                    224u8, 184u8, 154u8, 224u8, 184u8, 167u8, 224u8, 184u8, 135u8, 224u8, 184u8,
+                    136u8, 224u8, 184u8, 163u8, 224u8, 184u8, 158u8, 224u8, 184u8, 163u8, 224u8,
+                    184u8, 176u8, 224u8, 184u8, 158u8, 224u8, 184u8, 184u8, 224u8, 184u8, 151u8,
+                    224u8, 184u8, 152u8, 224u8, 185u8, 128u8, 224u8, 184u8, 136u8, 224u8, 185u8,
+                    137u8, 224u8, 184u8, 178u8, 224u8, 184u8, 154u8, 224u8, 184u8, 180u8, 224u8,
+                    184u8, 170u8, 224u8, 184u8, 132u8, 224u8, 184u8, 178u8, 224u8, 184u8, 162u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 153u8, 224u8, 184u8, 160u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 169u8, 224u8, 184u8, 178u8, 224u8, 184u8, 158u8, 224u8,
+                    184u8, 183u8, 224u8, 185u8, 137u8, 224u8, 184u8, 153u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 161u8, 224u8, 184u8, 183u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                    135u8, 224u8, 184u8, 139u8, 224u8, 184u8, 178u8, 224u8, 184u8, 153u8, 224u8,
+                    184u8, 136u8, 224u8, 184u8, 173u8, 224u8, 184u8, 163u8, 224u8, 185u8, 140u8,
+                    224u8, 185u8, 130u8, 224u8, 184u8, 136u8, 47u8, 224u8, 184u8, 154u8, 224u8,
+                    184u8, 180u8, 224u8, 184u8, 165u8, 224u8, 184u8, 178u8, 224u8, 184u8, 149u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                    177u8, 224u8, 184u8, 129u8, 224u8, 184u8, 169u8, 224u8, 184u8, 163u8, 224u8,
+                    185u8, 130u8, 224u8, 184u8, 154u8, 224u8, 185u8, 130u8, 224u8, 184u8, 174u8,
+                    224u8, 184u8, 163u8, 224u8, 184u8, 180u8, 224u8, 184u8, 138u8, 224u8, 184u8,
+                    154u8, 224u8, 184u8, 185u8, 224u8, 184u8, 153u8, 224u8, 184u8, 151u8, 224u8,
+                    184u8, 165u8, 224u8, 184u8, 180u8, 224u8, 185u8, 136u8, 224u8, 184u8, 135u8,
+                    224u8, 184u8, 149u8, 224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 184u8,
+                    173u8, 224u8, 184u8, 177u8, 224u8, 184u8, 129u8, 224u8, 184u8, 169u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 148u8, 224u8, 184u8, 178u8, 224u8, 184u8, 162u8,
+                    224u8, 184u8, 153u8, 224u8, 184u8, 138u8, 224u8, 184u8, 180u8, 224u8, 184u8,
+                    139u8, 224u8, 184u8, 178u8, 224u8, 184u8, 173u8, 224u8, 184u8, 177u8, 224u8,
+                    184u8, 135u8, 224u8, 184u8, 129u8, 224u8, 184u8, 164u8, 224u8, 184u8, 169u8,
+                    224u8, 184u8, 170u8, 224u8, 184u8, 161u8, 224u8, 184u8, 177u8, 224u8, 184u8,
+                    162u8, 224u8, 185u8, 131u8, 224u8, 184u8, 171u8, 224u8, 184u8, 161u8, 224u8,
+                    185u8, 136u8, 224u8, 184u8, 149u8, 224u8, 184u8, 173u8, 224u8, 184u8, 153u8,
+                    224u8, 184u8, 149u8, 224u8, 185u8, 137u8, 224u8, 184u8, 153u8, 224u8, 184u8,
+                    170u8, 224u8, 184u8, 177u8, 224u8, 184u8, 151u8, 224u8, 184u8, 173u8, 224u8,
+                    184u8, 177u8, 224u8, 184u8, 129u8, 224u8, 184u8, 169u8, 224u8, 184u8, 163u8,
+                    224u8, 184u8, 170u8, 224u8, 184u8, 178u8, 224u8, 184u8, 129u8, 224u8, 184u8,
+                    165u8, 224u8, 184u8, 170u8, 224u8, 184u8, 177u8, 224u8, 184u8, 151u8, 224u8,
+                    184u8, 173u8, 224u8, 184u8, 177u8, 224u8, 184u8, 129u8, 224u8, 184u8, 169u8,
+                    224u8, 184u8, 163u8, 32u8, 85u8, 80u8, 65u8, 224u8, 184u8, 159u8, 224u8, 184u8,
+                    173u8, 224u8, 184u8, 153u8, 224u8, 184u8, 139u8, 224u8, 185u8, 140u8, 224u8,
+                    185u8, 129u8, 224u8, 184u8, 139u8, 224u8, 184u8, 161u8, 224u8, 184u8, 158u8,
+                    224u8, 185u8, 140u8, 224u8, 184u8, 129u8, 224u8, 184u8, 178u8, 224u8, 184u8,
+                    163u8, 224u8, 184u8, 150u8, 224u8, 184u8, 173u8, 224u8, 184u8, 148u8, 224u8,
+                    184u8, 173u8, 224u8, 184u8, 177u8, 224u8, 184u8, 129u8, 224u8, 184u8, 169u8,
+                    224u8, 184u8, 163u8, 224u8, 185u8, 129u8, 224u8, 184u8, 154u8, 224u8, 184u8,
+                    154u8, 224u8, 185u8, 128u8, 224u8, 184u8, 174u8, 224u8, 184u8, 155u8, 224u8,
+                    185u8, 128u8, 224u8, 184u8, 154u8, 224u8, 184u8, 180u8, 224u8, 184u8, 163u8,
+                    224u8, 185u8, 140u8, 224u8, 184u8, 153u8, 224u8, 184u8, 160u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 169u8, 224u8, 184u8, 178u8, 224u8, 184u8, 153u8, 224u8,
+                    184u8, 173u8, 224u8, 184u8, 163u8, 224u8, 185u8, 140u8, 224u8, 184u8, 167u8,
+                    224u8, 184u8, 180u8, 224u8, 185u8, 128u8, 224u8, 184u8, 136u8, 224u8, 184u8,
+                    181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 153u8, 224u8, 184u8, 170u8, 224u8,
+                    184u8, 185u8, 224u8, 184u8, 135u8, 224u8, 184u8, 173u8, 224u8, 184u8, 180u8,
+                    224u8, 184u8, 149u8, 224u8, 184u8, 180u8, 224u8, 184u8, 171u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 170u8, 224u8, 184u8, 176u8, 224u8, 185u8, 128u8, 224u8,
+                    184u8, 162u8, 224u8, 184u8, 178u8, 224u8, 185u8, 128u8, 224u8, 184u8, 173u8,
+                    224u8, 184u8, 173u8, 224u8, 184u8, 163u8, 224u8, 185u8, 140u8, 224u8, 184u8,
+                    163u8, 224u8, 184u8, 176u8, 224u8, 184u8, 154u8, 224u8, 184u8, 154u8, 224u8,
+                    184u8, 150u8, 224u8, 184u8, 173u8, 224u8, 184u8, 148u8, 224u8, 184u8, 173u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 129u8, 224u8, 184u8, 169u8, 224u8, 184u8,
+                    163u8, 224u8, 185u8, 129u8, 224u8, 184u8, 154u8, 224u8, 184u8, 154u8, 224u8,
+                    184u8, 162u8, 224u8, 184u8, 167u8, 224u8, 184u8, 182u8, 224u8, 184u8, 148u8,
+                    224u8, 184u8, 158u8, 224u8, 184u8, 180u8, 224u8, 184u8, 135u8, 224u8, 184u8,
+                    173u8, 224u8, 184u8, 177u8, 224u8, 184u8, 129u8, 224u8, 184u8, 130u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 167u8, 224u8, 184u8, 180u8, 224u8, 184u8, 152u8,
+                    224u8, 184u8, 181u8, 224u8, 184u8, 170u8, 224u8, 184u8, 178u8, 224u8, 184u8,
+                    161u8, 224u8, 184u8, 177u8, 224u8, 184u8, 141u8, 224u8, 184u8, 173u8, 224u8,
+                    184u8, 177u8, 224u8, 184u8, 129u8, 224u8, 184u8, 130u8, 224u8, 184u8, 163u8,
+                    224u8, 184u8, 167u8, 224u8, 184u8, 180u8, 224u8, 184u8, 152u8, 224u8, 184u8,
+                    181u8, 224u8, 184u8, 161u8, 224u8, 184u8, 178u8, 224u8, 184u8, 149u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 144u8, 224u8, 184u8, 178u8, 224u8, 184u8, 153u8,
+                    224u8, 184u8, 165u8, 224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 184u8,
+                    129u8, 224u8, 184u8, 180u8, 224u8, 184u8, 129u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 160u8, 224u8, 184u8, 178u8, 224u8, 184u8, 169u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 158u8, 224u8, 184u8, 183u8, 224u8, 185u8, 137u8, 224u8, 184u8,
+                    153u8, 224u8, 185u8, 128u8, 224u8, 184u8, 161u8, 224u8, 184u8, 183u8, 224u8,
+                    184u8, 173u8, 224u8, 184u8, 135u8, 224u8, 184u8, 165u8, 224u8, 184u8, 180u8,
+                    224u8, 185u8, 130u8, 224u8, 184u8, 158u8, 224u8, 184u8, 167u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 139u8, 224u8, 184u8, 130u8, 224u8, 184u8, 173u8, 224u8,
+                    184u8, 135u8, 224u8, 185u8, 128u8, 224u8, 184u8, 163u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 139u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8,
+                    153u8, 224u8, 184u8, 165u8, 224u8, 184u8, 185u8, 224u8, 184u8, 153u8, 224u8,
+                    185u8, 136u8, 224u8, 184u8, 178u8, 32u8, 49u8, 57u8, 49u8, 56u8, 224u8, 184u8,
+                    149u8, 224u8, 184u8, 177u8, 224u8, 184u8, 167u8, 224u8, 184u8, 173u8, 224u8,
+                    184u8, 177u8, 224u8, 184u8, 129u8, 224u8, 184u8, 169u8, 224u8, 184u8, 163u8,
+                    224u8, 185u8, 128u8, 224u8, 184u8, 161u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    149u8, 224u8, 184u8, 167u8, 224u8, 184u8, 138u8, 224u8, 184u8, 180u8, 224u8,
+                    184u8, 139u8, 224u8, 184u8, 178u8, 224u8, 185u8, 130u8, 224u8, 184u8, 161u8,
+                    224u8, 185u8, 130u8, 224u8, 184u8, 153u8, 224u8, 185u8, 130u8, 224u8, 184u8,
+                    151u8, 224u8, 184u8, 153u8, 224u8, 184u8, 180u8, 224u8, 184u8, 129u8, 224u8,
+                    184u8, 148u8, 224u8, 184u8, 180u8, 224u8, 184u8, 167u8, 224u8, 184u8, 132u8,
+                    224u8, 184u8, 178u8, 224u8, 184u8, 160u8, 224u8, 184u8, 178u8, 224u8, 184u8,
+                    169u8, 224u8, 184u8, 178u8, 224u8, 184u8, 158u8, 224u8, 184u8, 183u8, 224u8,
+                    185u8, 137u8, 224u8, 184u8, 153u8, 224u8, 185u8, 128u8, 224u8, 184u8, 161u8,
+                    224u8, 184u8, 183u8, 224u8, 184u8, 173u8, 224u8, 184u8, 135u8, 224u8, 184u8,
+                    153u8, 224u8, 184u8, 178u8, 224u8, 184u8, 151u8, 224u8, 184u8, 180u8, 224u8,
+                    185u8, 130u8, 224u8, 184u8, 139u8, 224u8, 185u8, 128u8, 224u8, 184u8, 153u8,
+                    224u8, 184u8, 160u8, 224u8, 184u8, 178u8, 224u8, 184u8, 169u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 158u8, 224u8, 184u8, 183u8, 224u8, 185u8, 137u8, 224u8,
+                    184u8, 153u8, 224u8, 185u8, 128u8, 224u8, 184u8, 161u8, 224u8, 184u8, 183u8,
+                    224u8, 184u8, 173u8, 224u8, 184u8, 135u8, 224u8, 184u8, 136u8, 224u8, 184u8,
+                    153u8, 224u8, 184u8, 181u8, 224u8, 184u8, 167u8, 224u8, 184u8, 178u8, 47u8,
+                    224u8, 184u8, 153u8, 224u8, 184u8, 136u8, 224u8, 184u8, 181u8, 224u8, 184u8,
+                    167u8, 224u8, 184u8, 178u8, 224u8, 185u8, 130u8, 224u8, 184u8, 167u8, 224u8,
+                    184u8, 165u8, 224u8, 184u8, 178u8, 224u8, 184u8, 158u8, 224u8, 184u8, 182u8,
+                    224u8, 184u8, 132u8, 224u8, 184u8, 170u8, 224u8, 184u8, 161u8, 224u8, 184u8,
+                    177u8, 224u8, 184u8, 162u8, 224u8, 185u8, 131u8, 224u8, 184u8, 171u8, 224u8,
+                    184u8, 161u8, 224u8, 185u8, 136u8, 224u8, 184u8, 160u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 169u8, 224u8, 184u8, 178u8, 224u8, 184u8, 158u8, 224u8, 184u8,
+                    183u8, 224u8, 185u8, 137u8, 224u8, 184u8, 153u8, 224u8, 185u8, 128u8, 224u8,
+                    184u8, 161u8, 224u8, 184u8, 183u8, 224u8, 184u8, 173u8, 224u8, 184u8, 135u8,
+                    224u8, 185u8, 130u8, 224u8, 184u8, 173u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    139u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 132u8, 224u8,
+                    185u8, 130u8, 224u8, 184u8, 132u8, 47u8, 224u8, 185u8, 130u8, 224u8, 184u8,
+                    173u8, 224u8, 185u8, 130u8, 224u8, 184u8, 139u8, 224u8, 184u8, 162u8, 224u8,
+                    184u8, 178u8, 224u8, 185u8, 128u8, 224u8, 184u8, 153u8, 224u8, 184u8, 160u8,
+                    224u8, 184u8, 178u8, 224u8, 184u8, 169u8, 224u8, 184u8, 178u8, 224u8, 184u8,
+                    158u8, 224u8, 184u8, 183u8, 224u8, 185u8, 137u8, 224u8, 184u8, 153u8, 224u8,
+                    185u8, 128u8, 224u8, 184u8, 161u8, 224u8, 184u8, 183u8, 224u8, 184u8, 173u8,
+                    224u8, 184u8, 135u8, 224u8, 184u8, 158u8, 224u8, 184u8, 178u8, 224u8, 184u8,
+                    161u8, 224u8, 184u8, 178u8, 224u8, 184u8, 132u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 158u8, 224u8, 184u8, 181u8, 224u8, 184u8, 151u8, 32u8, 49u8, 55u8, 48u8,
+                    56u8, 224u8, 184u8, 158u8, 224u8, 184u8, 180u8, 224u8, 184u8, 153u8, 224u8,
+                    184u8, 173u8, 224u8, 184u8, 180u8, 224u8, 184u8, 153u8, 224u8, 185u8, 130u8,
+                    224u8, 184u8, 158u8, 224u8, 184u8, 165u8, 224u8, 184u8, 181u8, 224u8, 185u8,
+                    130u8, 224u8, 184u8, 151u8, 224u8, 184u8, 153u8, 224u8, 184u8, 180u8, 224u8,
+                    184u8, 129u8, 224u8, 184u8, 132u8, 224u8, 184u8, 173u8, 224u8, 184u8, 161u8,
+                    224u8, 184u8, 158u8, 224u8, 184u8, 180u8, 224u8, 184u8, 167u8, 224u8, 185u8,
+                    128u8, 224u8, 184u8, 149u8, 224u8, 184u8, 173u8, 224u8, 184u8, 163u8, 224u8,
+                    185u8, 140u8, 224u8, 184u8, 158u8, 224u8, 184u8, 180u8, 224u8, 184u8, 167u8,
+                    224u8, 185u8, 128u8, 224u8, 184u8, 149u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                    163u8, 224u8, 185u8, 140u8, 224u8, 184u8, 173u8, 224u8, 184u8, 173u8, 224u8,
+                    184u8, 163u8, 224u8, 185u8, 140u8, 224u8, 185u8, 130u8, 224u8, 184u8, 152u8,
+                    224u8, 184u8, 129u8, 224u8, 184u8, 163u8, 224u8, 184u8, 178u8, 224u8, 184u8,
+                    159u8, 224u8, 184u8, 180u8, 224u8, 184u8, 155u8, 224u8, 184u8, 163u8, 224u8,
+                    184u8, 177u8, 224u8, 184u8, 154u8, 224u8, 184u8, 155u8, 224u8, 184u8, 163u8,
+                    224u8, 184u8, 184u8, 224u8, 184u8, 135u8, 224u8, 185u8, 130u8, 224u8, 184u8,
+                    167u8, 224u8, 184u8, 165u8, 224u8, 184u8, 178u8, 224u8, 184u8, 158u8, 224u8,
+                    184u8, 182u8, 224u8, 184u8, 132u8, 224u8, 184u8, 148u8, 224u8, 184u8, 177u8,
+                    224u8, 185u8, 137u8, 224u8, 184u8, 135u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    148u8, 224u8, 184u8, 180u8, 224u8, 184u8, 161u8, 224u8, 185u8, 128u8, 224u8,
+                    184u8, 163u8, 224u8, 185u8, 128u8, 224u8, 184u8, 139u8, 224u8, 184u8, 181u8,
+                    224u8, 184u8, 162u8, 224u8, 184u8, 153u8, 224u8, 184u8, 163u8, 224u8, 184u8,
+                    177u8, 224u8, 184u8, 161u8, 224u8, 184u8, 129u8, 224u8, 184u8, 163u8, 224u8,
+                    185u8, 140u8, 224u8, 184u8, 139u8, 224u8, 184u8, 178u8, 224u8, 185u8, 130u8,
+                    224u8, 184u8, 174u8, 224u8, 184u8, 160u8, 224u8, 184u8, 178u8, 224u8, 184u8,
+                    169u8, 224u8, 184u8, 178u8, 224u8, 184u8, 173u8, 224u8, 184u8, 177u8, 224u8,
+                    184u8, 135u8, 224u8, 184u8, 129u8, 224u8, 184u8, 164u8, 224u8, 184u8, 169u8,
+                    224u8, 184u8, 161u8, 224u8, 184u8, 178u8, 224u8, 184u8, 149u8, 224u8, 184u8,
+                    163u8, 224u8, 184u8, 178u8, 224u8, 184u8, 144u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 153u8, 224u8, 184u8, 170u8, 224u8, 184u8, 129u8, 224u8, 185u8, 138u8,
+                    224u8, 184u8, 173u8, 224u8, 184u8, 149u8, 224u8, 184u8, 170u8, 224u8, 185u8,
+                    128u8, 224u8, 184u8, 129u8, 224u8, 184u8, 178u8, 224u8, 184u8, 170u8, 224u8,
+                    185u8, 140u8, 224u8, 184u8, 160u8, 224u8, 184u8, 178u8, 224u8, 184u8, 169u8,
+                    224u8, 184u8, 178u8, 224u8, 184u8, 158u8, 224u8, 184u8, 183u8, 224u8, 185u8,
+                    137u8, 224u8, 184u8, 153u8, 224u8, 185u8, 128u8, 224u8, 184u8, 161u8, 224u8,
+                    184u8, 183u8, 224u8, 184u8, 173u8, 224u8, 184u8, 135u8, 224u8, 184u8, 170u8,
+                    224u8, 185u8, 130u8, 224u8, 184u8, 149u8, 224u8, 184u8, 167u8, 224u8, 184u8,
+                    181u8, 224u8, 184u8, 139u8, 224u8, 184u8, 139u8, 224u8, 184u8, 178u8, 47u8,
+                    224u8, 185u8, 130u8, 224u8, 184u8, 139u8, 224u8, 184u8, 165u8, 224u8, 184u8,
+                    154u8, 224u8, 184u8, 181u8, 224u8, 184u8, 129u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 139u8, 224u8, 184u8, 185u8, 224u8, 184u8, 163u8, 224u8, 185u8, 140u8,
+                    224u8, 184u8, 161u8, 224u8, 184u8, 180u8, 224u8, 184u8, 163u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 153u8, 224u8, 184u8, 139u8, 224u8, 184u8, 185u8, 224u8,
+                    184u8, 163u8, 224u8, 185u8, 140u8, 224u8, 184u8, 139u8, 224u8, 184u8, 181u8,
+                    224u8, 184u8, 165u8, 224u8, 184u8, 159u8, 224u8, 185u8, 140u8, 224u8, 184u8,
+                    139u8, 224u8, 184u8, 184u8, 224u8, 184u8, 149u8, 224u8, 184u8, 139u8, 224u8,
+                    184u8, 181u8, 224u8, 184u8, 165u8, 224u8, 184u8, 159u8, 224u8, 185u8, 140u8,
+                    224u8, 184u8, 151u8, 224u8, 184u8, 178u8, 224u8, 184u8, 163u8, 224u8, 184u8,
+                    178u8, 224u8, 184u8, 139u8, 224u8, 185u8, 128u8, 224u8, 184u8, 132u8, 224u8,
+                    184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 167u8, 224u8, 184u8, 180u8,
+                    224u8, 184u8, 139u8, 224u8, 184u8, 178u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                    173u8, 224u8, 184u8, 163u8, 224u8, 185u8, 140u8, 224u8, 185u8, 130u8, 224u8,
+                    184u8, 152u8, 224u8, 184u8, 129u8, 224u8, 184u8, 163u8, 224u8, 184u8, 178u8,
+                    224u8, 184u8, 159u8, 224u8, 184u8, 181u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                    177u8, 224u8, 184u8, 129u8, 224u8, 184u8, 130u8, 224u8, 184u8, 163u8, 224u8,
+                    184u8, 167u8, 224u8, 184u8, 180u8, 224u8, 184u8, 152u8, 224u8, 184u8, 181u8,
+                    224u8, 185u8, 129u8, 224u8, 184u8, 154u8, 224u8, 184u8, 154u8, 224u8, 184u8,
+                    132u8, 224u8, 184u8, 163u8, 224u8, 184u8, 154u8, 224u8, 184u8, 167u8, 224u8,
+                    184u8, 135u8, 224u8, 184u8, 136u8, 224u8, 184u8, 163u8, 224u8, 184u8, 173u8,
+                    224u8, 184u8, 177u8, 224u8, 184u8, 129u8, 224u8, 184u8, 130u8, 224u8, 184u8,
+                    163u8, 224u8, 184u8, 167u8, 224u8, 184u8, 180u8, 224u8, 184u8, 152u8, 224u8,
+                    184u8, 181u8, 224u8, 185u8, 129u8, 224u8, 184u8, 154u8, 224u8, 184u8, 154u8,
+                    224u8, 184u8, 155u8, 224u8, 184u8, 163u8, 224u8, 184u8, 177u8, 224u8, 184u8,
+                    154u8, 224u8, 184u8, 155u8, 224u8, 184u8, 163u8, 224u8, 184u8, 184u8, 224u8,
+                    184u8, 135u8, 224u8, 184u8, 132u8, 224u8, 184u8, 163u8, 224u8, 184u8, 154u8,
+                    224u8, 184u8, 167u8, 224u8, 184u8, 135u8, 224u8, 184u8, 136u8, 224u8, 184u8,
+                    163u8, 224u8, 184u8, 173u8, 224u8, 184u8, 177u8, 224u8, 184u8, 165u8, 224u8,
+                    184u8, 170u8, 224u8, 185u8, 128u8, 224u8, 184u8, 149u8, 224u8, 184u8, 173u8,
+                    224u8, 184u8, 163u8, 224u8, 185u8, 140u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    167u8, 224u8, 184u8, 148u8, 224u8, 184u8, 180u8, 224u8, 184u8, 132u8, 224u8,
+                    184u8, 178u8, 224u8, 184u8, 167u8, 224u8, 184u8, 178u8, 224u8, 185u8, 128u8,
+                    224u8, 184u8, 165u8, 224u8, 184u8, 153u8, 224u8, 185u8, 128u8, 224u8, 184u8,
+                    139u8, 224u8, 184u8, 181u8, 224u8, 184u8, 162u8, 224u8, 184u8, 167u8, 224u8,
+                    184u8, 177u8, 224u8, 184u8, 165u8, 224u8, 184u8, 165u8, 224u8, 184u8, 178u8,
+                    224u8, 185u8, 128u8, 224u8, 184u8, 148u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                    163u8, 224u8, 185u8, 140u8, 224u8, 184u8, 129u8, 224u8, 184u8, 178u8, 224u8,
+                    184u8, 163u8, 224u8, 184u8, 150u8, 224u8, 184u8, 173u8, 224u8, 184u8, 148u8,
+                    224u8, 184u8, 173u8, 224u8, 184u8, 177u8, 224u8, 184u8, 129u8, 224u8, 184u8,
+                    169u8, 224u8, 184u8, 163u8, 224u8, 185u8, 129u8, 224u8, 184u8, 154u8, 224u8,
+                    184u8, 154u8, 224u8, 185u8, 128u8, 224u8, 184u8, 167u8, 224u8, 184u8, 148u8,
+                    45u8, 224u8, 185u8, 132u8, 224u8, 184u8, 136u8, 224u8, 184u8, 165u8, 224u8,
+                    184u8, 170u8, 224u8, 185u8, 140u8,
+                ])
+            },
+        )
+    },
+};
+static TR: &DataStruct = &::icu_displaynames::provider::VariantDisplayNamesV1 {
+    names: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    49u8, 54u8, 48u8, 54u8, 110u8, 105u8, 99u8, 116u8, 49u8, 54u8, 57u8, 52u8,
+                    97u8, 99u8, 97u8, 100u8, 49u8, 57u8, 48u8, 49u8, 0u8, 0u8, 0u8, 0u8, 49u8,
+                    57u8, 53u8, 57u8, 97u8, 99u8, 97u8, 100u8, 49u8, 57u8, 57u8, 52u8, 0u8, 0u8,
+                    0u8, 0u8, 49u8, 57u8, 57u8, 54u8, 0u8, 0u8, 0u8, 0u8, 97u8, 114u8, 101u8,
+                    118u8, 101u8, 108u8, 97u8, 0u8, 97u8, 114u8, 101u8, 118u8, 109u8, 100u8, 97u8,
+                    0u8, 98u8, 97u8, 107u8, 117u8, 49u8, 57u8, 50u8, 54u8, 98u8, 105u8, 115u8,
+                    107u8, 101u8, 0u8, 0u8, 0u8, 98u8, 111u8, 111u8, 110u8, 116u8, 0u8, 0u8, 0u8,
+                    102u8, 111u8, 110u8, 105u8, 112u8, 97u8, 0u8, 0u8, 102u8, 111u8, 110u8, 117u8,
+                    112u8, 97u8, 0u8, 0u8, 107u8, 107u8, 99u8, 111u8, 114u8, 0u8, 0u8, 0u8, 108u8,
+                    105u8, 112u8, 97u8, 119u8, 0u8, 0u8, 0u8, 109u8, 111u8, 110u8, 111u8, 116u8,
+                    111u8, 110u8, 0u8, 110u8, 101u8, 100u8, 105u8, 115u8, 0u8, 0u8, 0u8, 110u8,
+                    106u8, 105u8, 118u8, 97u8, 0u8, 0u8, 0u8, 111u8, 115u8, 111u8, 106u8, 115u8,
+                    0u8, 0u8, 0u8, 112u8, 105u8, 110u8, 121u8, 105u8, 110u8, 0u8, 0u8, 112u8,
+                    111u8, 108u8, 121u8, 116u8, 111u8, 110u8, 0u8, 112u8, 111u8, 115u8, 105u8,
+                    120u8, 0u8, 0u8, 0u8, 114u8, 101u8, 118u8, 105u8, 115u8, 101u8, 100u8, 0u8,
+                    114u8, 111u8, 122u8, 97u8, 106u8, 0u8, 0u8, 0u8, 115u8, 97u8, 97u8, 104u8,
+                    111u8, 0u8, 0u8, 0u8, 115u8, 99u8, 111u8, 116u8, 108u8, 97u8, 110u8, 100u8,
+                    115u8, 99u8, 111u8, 117u8, 115u8, 101u8, 0u8, 0u8, 115u8, 111u8, 108u8, 98u8,
+                    97u8, 0u8, 0u8, 0u8, 116u8, 97u8, 114u8, 97u8, 115u8, 107u8, 0u8, 0u8, 117u8,
+                    99u8, 99u8, 111u8, 114u8, 0u8, 0u8, 0u8, 117u8, 99u8, 114u8, 99u8, 111u8,
+                    114u8, 0u8, 0u8, 118u8, 97u8, 108u8, 101u8, 110u8, 99u8, 105u8, 97u8, 119u8,
+                    97u8, 100u8, 101u8, 103u8, 105u8, 108u8, 101u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    33u8, 0u8, 0u8, 0u8, 0u8, 0u8, 42u8, 0u8, 65u8, 0u8, 101u8, 0u8, 109u8, 0u8,
+                    141u8, 0u8, 171u8, 0u8, 187u8, 0u8, 203u8, 0u8, 239u8, 0u8, 8u8, 1u8, 17u8,
+                    1u8, 31u8, 1u8, 45u8, 1u8, 68u8, 1u8, 90u8, 1u8, 99u8, 1u8, 116u8, 1u8, 136u8,
+                    1u8, 160u8, 1u8, 195u8, 1u8, 204u8, 1u8, 214u8, 1u8, 252u8, 1u8, 10u8, 2u8,
+                    14u8, 2u8, 43u8, 2u8, 49u8, 2u8, 75u8, 2u8, 105u8, 2u8, 139u8, 2u8, 194u8, 2u8,
+                    205u8, 2u8, 49u8, 54u8, 48u8, 54u8, 226u8, 128u8, 152u8, 121u8, 97u8, 32u8,
+                    68u8, 101u8, 107u8, 32u8, 71u8, 101u8, 195u8, 167u8, 32u8, 79u8, 114u8, 116u8,
+                    97u8, 195u8, 167u8, 97u8, 196u8, 159u8, 32u8, 70u8, 114u8, 97u8, 110u8, 115u8,
+                    196u8, 177u8, 122u8, 99u8, 97u8, 115u8, 196u8, 177u8, 69u8, 114u8, 107u8,
+                    101u8, 110u8, 32u8, 77u8, 111u8, 100u8, 101u8, 114u8, 110u8, 32u8, 70u8, 114u8,
+                    97u8, 110u8, 115u8, 196u8, 177u8, 122u8, 99u8, 97u8, 71u8, 101u8, 108u8, 101u8,
+                    110u8, 101u8, 107u8, 115u8, 101u8, 108u8, 32u8, 65u8, 108u8, 109u8, 97u8,
+                    110u8, 99u8, 97u8, 32u8, 89u8, 97u8, 122u8, 196u8, 177u8, 109u8, 32u8, 75u8,
+                    117u8, 114u8, 97u8, 108u8, 108u8, 97u8, 114u8, 196u8, 177u8, 65u8, 107u8, 97u8,
+                    100u8, 101u8, 109u8, 105u8, 107u8, 83u8, 116u8, 97u8, 110u8, 100u8, 97u8,
+                    114u8, 116u8, 32u8, 82u8, 101u8, 115u8, 105u8, 97u8, 32u8, 89u8, 97u8, 122u8,
+                    196u8, 177u8, 109u8, 32u8, 75u8, 117u8, 114u8, 97u8, 108u8, 108u8, 97u8, 114u8,
+                    196u8, 177u8, 49u8, 57u8, 57u8, 54u8, 32u8, 65u8, 108u8, 109u8, 97u8, 110u8,
+                    99u8, 97u8, 32u8, 89u8, 97u8, 122u8, 196u8, 177u8, 109u8, 32u8, 75u8, 117u8,
+                    114u8, 97u8, 108u8, 108u8, 97u8, 114u8, 196u8, 177u8, 68u8, 111u8, 196u8,
+                    159u8, 117u8, 32u8, 69u8, 114u8, 109u8, 101u8, 110u8, 105u8, 99u8, 101u8,
+                    115u8, 105u8, 66u8, 97u8, 116u8, 196u8, 177u8, 32u8, 69u8, 114u8, 109u8, 101u8,
+                    110u8, 105u8, 99u8, 101u8, 115u8, 105u8, 66u8, 105u8, 114u8, 108u8, 101u8,
+                    197u8, 159u8, 116u8, 105u8, 114u8, 105u8, 108u8, 109u8, 105u8, 197u8, 159u8,
+                    32u8, 89u8, 101u8, 110u8, 105u8, 32u8, 84u8, 195u8, 188u8, 114u8, 107u8, 32u8,
+                    65u8, 108u8, 102u8, 97u8, 98u8, 101u8, 115u8, 105u8, 83u8, 97u8, 110u8, 32u8,
+                    71u8, 105u8, 111u8, 114u8, 103u8, 105u8, 111u8, 47u8, 66u8, 105u8, 108u8, 97u8,
+                    32u8, 76u8, 101u8, 104u8, 195u8, 167u8, 101u8, 115u8, 105u8, 66u8, 111u8,
+                    111u8, 110u8, 116u8, 108u8, 105u8, 110u8, 103u8, 73u8, 80u8, 65u8, 32u8, 83u8,
+                    101u8, 115u8, 32u8, 66u8, 105u8, 108u8, 105u8, 109u8, 105u8, 85u8, 80u8, 65u8,
+                    32u8, 83u8, 101u8, 115u8, 32u8, 66u8, 105u8, 108u8, 105u8, 109u8, 105u8, 79u8,
+                    114u8, 116u8, 97u8, 107u8, 32u8, 89u8, 97u8, 122u8, 196u8, 177u8, 109u8, 32u8,
+                    75u8, 117u8, 114u8, 97u8, 108u8, 108u8, 97u8, 114u8, 196u8, 177u8, 82u8, 101u8,
+                    115u8, 105u8, 97u8, 32u8, 76u8, 105u8, 112u8, 111u8, 118u8, 97u8, 122u8, 32u8,
+                    76u8, 101u8, 104u8, 195u8, 167u8, 101u8, 115u8, 105u8, 77u8, 111u8, 110u8,
+                    111u8, 116u8, 111u8, 110u8, 105u8, 107u8, 78u8, 97u8, 116u8, 105u8, 115u8,
+                    111u8, 110u8, 101u8, 32u8, 76u8, 101u8, 104u8, 195u8, 167u8, 101u8, 115u8,
+                    105u8, 71u8, 110u8, 105u8, 118u8, 97u8, 47u8, 78u8, 106u8, 105u8, 118u8, 97u8,
+                    32u8, 76u8, 101u8, 104u8, 195u8, 167u8, 101u8, 115u8, 105u8, 79u8, 115u8,
+                    101u8, 97u8, 99u8, 99u8, 111u8, 47u8, 79u8, 115u8, 111u8, 106u8, 97u8, 110u8,
+                    101u8, 32u8, 76u8, 101u8, 104u8, 195u8, 167u8, 101u8, 115u8, 105u8, 80u8,
+                    105u8, 110u8, 121u8, 105u8, 110u8, 32u8, 40u8, 76u8, 97u8, 116u8, 105u8, 110u8,
+                    32u8, 65u8, 108u8, 102u8, 97u8, 98u8, 101u8, 115u8, 105u8, 110u8, 100u8, 101u8,
+                    32u8, 89u8, 97u8, 122u8, 196u8, 177u8, 109u8, 196u8, 177u8, 41u8, 80u8, 111u8,
+                    108u8, 105u8, 116u8, 111u8, 110u8, 105u8, 107u8, 66u8, 105u8, 108u8, 103u8,
+                    105u8, 115u8, 97u8, 121u8, 97u8, 114u8, 71u8, 195u8, 182u8, 122u8, 100u8,
+                    101u8, 110u8, 32u8, 71u8, 101u8, 195u8, 167u8, 105u8, 114u8, 105u8, 108u8,
+                    109u8, 105u8, 197u8, 159u8, 32u8, 89u8, 97u8, 122u8, 196u8, 177u8, 109u8, 32u8,
+                    75u8, 117u8, 114u8, 97u8, 108u8, 108u8, 97u8, 114u8, 196u8, 177u8, 82u8, 101u8,
+                    115u8, 105u8, 97u8, 32u8, 76u8, 101u8, 104u8, 195u8, 167u8, 101u8, 115u8,
+                    105u8, 83u8, 97u8, 104u8, 111u8, 83u8, 116u8, 97u8, 110u8, 100u8, 97u8, 114u8,
+                    116u8, 32u8, 196u8, 176u8, 115u8, 107u8, 111u8, 195u8, 167u8, 32u8, 196u8,
+                    176u8, 110u8, 103u8, 105u8, 108u8, 105u8, 122u8, 99u8, 101u8, 115u8, 105u8,
+                    83u8, 99u8, 111u8, 117u8, 115u8, 101u8, 83u8, 116u8, 111u8, 108u8, 118u8,
+                    105u8, 122u8, 122u8, 97u8, 47u8, 83u8, 111u8, 108u8, 98u8, 105u8, 99u8, 97u8,
+                    32u8, 76u8, 101u8, 104u8, 195u8, 167u8, 101u8, 115u8, 105u8, 84u8, 97u8, 114u8,
+                    97u8, 115u8, 107u8, 105u8, 101u8, 118u8, 105u8, 99u8, 97u8, 32u8, 89u8, 97u8,
+                    122u8, 196u8, 177u8, 109u8, 32u8, 75u8, 117u8, 114u8, 97u8, 108u8, 108u8, 97u8,
+                    114u8, 196u8, 177u8, 66u8, 105u8, 114u8, 108u8, 101u8, 197u8, 159u8, 116u8,
+                    105u8, 114u8, 105u8, 108u8, 109u8, 105u8, 197u8, 159u8, 32u8, 89u8, 97u8,
+                    122u8, 196u8, 177u8, 109u8, 32u8, 75u8, 117u8, 114u8, 97u8, 108u8, 108u8, 97u8,
+                    114u8, 196u8, 177u8, 71u8, 195u8, 182u8, 122u8, 100u8, 101u8, 110u8, 32u8,
+                    71u8, 101u8, 195u8, 167u8, 105u8, 114u8, 105u8, 108u8, 109u8, 105u8, 197u8,
+                    159u8, 32u8, 66u8, 105u8, 114u8, 108u8, 101u8, 197u8, 159u8, 116u8, 105u8,
+                    114u8, 105u8, 108u8, 109u8, 105u8, 197u8, 159u8, 32u8, 89u8, 97u8, 122u8,
+                    196u8, 177u8, 109u8, 32u8, 75u8, 117u8, 114u8, 97u8, 108u8, 108u8, 97u8, 114u8,
+                    196u8, 177u8, 86u8, 97u8, 108u8, 101u8, 110u8, 115u8, 105u8, 121u8, 97u8, 99u8,
+                    97u8, 87u8, 97u8, 100u8, 101u8, 45u8, 71u8, 105u8, 108u8, 101u8, 115u8, 32u8,
+                    40u8, 76u8, 97u8, 116u8, 105u8, 110u8, 32u8, 65u8, 108u8, 102u8, 97u8, 98u8,
+                    101u8, 115u8, 105u8, 110u8, 100u8, 101u8, 32u8, 89u8, 97u8, 122u8, 196u8,
+                    177u8, 109u8, 196u8, 177u8, 41u8,
+                ])
+            },
+        )
+    },
+};
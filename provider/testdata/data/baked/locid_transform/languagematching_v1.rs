@@ -0,0 +1,624 @@
+// @generated
+#![cfg(feature = "icu_locid_transform")]
+type DataStruct = < :: icu_locid_transform :: provider :: LanguageMatchingV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_locid_transform::provider::LanguageMatchingV1 {
+    paradigm_locales: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 7u8, 0u8, 9u8, 0u8, 15u8, 0u8, 20u8, 0u8,
+            101u8, 110u8, 101u8, 110u8, 45u8, 71u8, 66u8, 101u8, 115u8, 101u8, 115u8, 45u8, 52u8,
+            49u8, 57u8, 112u8, 116u8, 45u8, 66u8, 82u8, 112u8, 116u8, 45u8, 80u8, 84u8,
+        ])
+    },
+    match_variables: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 13u8, 0u8, 17u8, 0u8, 97u8, 109u8,
+                    101u8, 114u8, 105u8, 99u8, 97u8, 115u8, 99u8, 110u8, 115u8, 97u8, 114u8, 101u8,
+                    110u8, 85u8, 83u8, 109u8, 97u8, 103u8, 104u8, 114u8, 101u8, 98u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 192u8, 0u8, 198u8, 0u8, 228u8, 0u8, 48u8, 48u8,
+                    51u8, 48u8, 48u8, 53u8, 48u8, 49u8, 51u8, 48u8, 49u8, 57u8, 48u8, 50u8, 49u8,
+                    48u8, 50u8, 57u8, 52u8, 49u8, 57u8, 65u8, 71u8, 0u8, 65u8, 73u8, 0u8, 65u8,
+                    82u8, 0u8, 65u8, 87u8, 0u8, 66u8, 66u8, 0u8, 66u8, 76u8, 0u8, 66u8, 77u8, 0u8,
+                    66u8, 79u8, 0u8, 66u8, 81u8, 0u8, 66u8, 82u8, 0u8, 66u8, 83u8, 0u8, 66u8, 86u8,
+                    0u8, 66u8, 90u8, 0u8, 67u8, 65u8, 0u8, 67u8, 76u8, 0u8, 67u8, 79u8, 0u8, 67u8,
+                    82u8, 0u8, 67u8, 85u8, 0u8, 67u8, 87u8, 0u8, 68u8, 77u8, 0u8, 68u8, 79u8, 0u8,
+                    69u8, 67u8, 0u8, 70u8, 75u8, 0u8, 71u8, 68u8, 0u8, 71u8, 70u8, 0u8, 71u8, 76u8,
+                    0u8, 71u8, 80u8, 0u8, 71u8, 83u8, 0u8, 71u8, 84u8, 0u8, 71u8, 89u8, 0u8, 72u8,
+                    78u8, 0u8, 72u8, 84u8, 0u8, 74u8, 77u8, 0u8, 75u8, 78u8, 0u8, 75u8, 89u8, 0u8,
+                    76u8, 67u8, 0u8, 77u8, 70u8, 0u8, 77u8, 81u8, 0u8, 77u8, 83u8, 0u8, 77u8, 88u8,
+                    0u8, 78u8, 73u8, 0u8, 80u8, 65u8, 0u8, 80u8, 69u8, 0u8, 80u8, 77u8, 0u8, 80u8,
+                    82u8, 0u8, 80u8, 89u8, 0u8, 83u8, 82u8, 0u8, 83u8, 86u8, 0u8, 83u8, 88u8, 0u8,
+                    84u8, 67u8, 0u8, 84u8, 84u8, 0u8, 85u8, 83u8, 0u8, 85u8, 89u8, 0u8, 86u8, 67u8,
+                    0u8, 86u8, 69u8, 0u8, 86u8, 71u8, 0u8, 86u8, 73u8, 0u8, 72u8, 75u8, 0u8, 77u8,
+                    79u8, 0u8, 65u8, 83u8, 0u8, 67u8, 65u8, 0u8, 71u8, 85u8, 0u8, 77u8, 72u8, 0u8,
+                    77u8, 80u8, 0u8, 80u8, 72u8, 0u8, 80u8, 82u8, 0u8, 85u8, 77u8, 0u8, 85u8, 83u8,
+                    0u8, 86u8, 73u8, 0u8, 68u8, 90u8, 0u8, 69u8, 72u8, 0u8, 76u8, 89u8, 0u8, 77u8,
+                    65u8, 0u8, 77u8, 82u8, 0u8, 84u8, 78u8, 0u8,
+                ])
+            },
+        )
+    },
+    language: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            51u8, 1u8, 0u8, 0u8, 0u8, 0u8, 18u8, 0u8, 36u8, 0u8, 54u8, 0u8, 72u8, 0u8, 90u8, 0u8,
+            109u8, 0u8, 128u8, 0u8, 146u8, 0u8, 164u8, 0u8, 182u8, 0u8, 200u8, 0u8, 219u8, 0u8,
+            237u8, 0u8, 255u8, 0u8, 17u8, 1u8, 35u8, 1u8, 53u8, 1u8, 72u8, 1u8, 90u8, 1u8, 109u8,
+            1u8, 127u8, 1u8, 145u8, 1u8, 163u8, 1u8, 181u8, 1u8, 199u8, 1u8, 219u8, 1u8, 238u8,
+            1u8, 1u8, 2u8, 19u8, 2u8, 38u8, 2u8, 56u8, 2u8, 74u8, 2u8, 92u8, 2u8, 110u8, 2u8,
+            128u8, 2u8, 146u8, 2u8, 164u8, 2u8, 182u8, 2u8, 201u8, 2u8, 219u8, 2u8, 237u8, 2u8,
+            255u8, 2u8, 17u8, 3u8, 35u8, 3u8, 54u8, 3u8, 72u8, 3u8, 90u8, 3u8, 108u8, 3u8, 126u8,
+            3u8, 144u8, 3u8, 162u8, 3u8, 180u8, 3u8, 198u8, 3u8, 216u8, 3u8, 234u8, 3u8, 252u8,
+            3u8, 15u8, 4u8, 33u8, 4u8, 51u8, 4u8, 69u8, 4u8, 87u8, 4u8, 105u8, 4u8, 123u8, 4u8,
+            142u8, 4u8, 161u8, 4u8, 180u8, 4u8, 199u8, 4u8, 217u8, 4u8, 235u8, 4u8, 253u8, 4u8,
+            15u8, 5u8, 33u8, 5u8, 51u8, 5u8, 69u8, 5u8, 87u8, 5u8, 105u8, 5u8, 123u8, 5u8, 141u8,
+            5u8, 160u8, 5u8, 178u8, 5u8, 197u8, 5u8, 215u8, 5u8, 233u8, 5u8, 251u8, 5u8, 13u8, 6u8,
+            32u8, 6u8, 50u8, 6u8, 68u8, 6u8, 86u8, 6u8, 104u8, 6u8, 122u8, 6u8, 140u8, 6u8, 158u8,
+            6u8, 176u8, 6u8, 194u8, 6u8, 212u8, 6u8, 230u8, 6u8, 248u8, 6u8, 10u8, 7u8, 28u8, 7u8,
+            46u8, 7u8, 64u8, 7u8, 82u8, 7u8, 100u8, 7u8, 118u8, 7u8, 137u8, 7u8, 155u8, 7u8, 173u8,
+            7u8, 191u8, 7u8, 210u8, 7u8, 228u8, 7u8, 246u8, 7u8, 8u8, 8u8, 26u8, 8u8, 44u8, 8u8,
+            62u8, 8u8, 80u8, 8u8, 98u8, 8u8, 116u8, 8u8, 134u8, 8u8, 153u8, 8u8, 172u8, 8u8, 191u8,
+            8u8, 210u8, 8u8, 229u8, 8u8, 248u8, 8u8, 11u8, 9u8, 30u8, 9u8, 49u8, 9u8, 68u8, 9u8,
+            87u8, 9u8, 106u8, 9u8, 125u8, 9u8, 144u8, 9u8, 163u8, 9u8, 182u8, 9u8, 201u8, 9u8,
+            220u8, 9u8, 239u8, 9u8, 2u8, 10u8, 21u8, 10u8, 40u8, 10u8, 59u8, 10u8, 78u8, 10u8,
+            97u8, 10u8, 116u8, 10u8, 135u8, 10u8, 154u8, 10u8, 173u8, 10u8, 192u8, 10u8, 211u8,
+            10u8, 230u8, 10u8, 249u8, 10u8, 12u8, 11u8, 31u8, 11u8, 50u8, 11u8, 69u8, 11u8, 88u8,
+            11u8, 107u8, 11u8, 126u8, 11u8, 145u8, 11u8, 164u8, 11u8, 183u8, 11u8, 202u8, 11u8,
+            222u8, 11u8, 242u8, 11u8, 6u8, 12u8, 26u8, 12u8, 46u8, 12u8, 66u8, 12u8, 86u8, 12u8,
+            106u8, 12u8, 126u8, 12u8, 146u8, 12u8, 166u8, 12u8, 186u8, 12u8, 206u8, 12u8, 226u8,
+            12u8, 246u8, 12u8, 10u8, 13u8, 30u8, 13u8, 50u8, 13u8, 70u8, 13u8, 90u8, 13u8, 110u8,
+            13u8, 130u8, 13u8, 150u8, 13u8, 169u8, 13u8, 188u8, 13u8, 207u8, 13u8, 226u8, 13u8,
+            245u8, 13u8, 8u8, 14u8, 27u8, 14u8, 46u8, 14u8, 65u8, 14u8, 84u8, 14u8, 103u8, 14u8,
+            122u8, 14u8, 141u8, 14u8, 160u8, 14u8, 179u8, 14u8, 198u8, 14u8, 217u8, 14u8, 236u8,
+            14u8, 255u8, 14u8, 18u8, 15u8, 36u8, 15u8, 55u8, 15u8, 74u8, 15u8, 93u8, 15u8, 112u8,
+            15u8, 131u8, 15u8, 150u8, 15u8, 169u8, 15u8, 188u8, 15u8, 207u8, 15u8, 226u8, 15u8,
+            245u8, 15u8, 8u8, 16u8, 27u8, 16u8, 46u8, 16u8, 65u8, 16u8, 84u8, 16u8, 103u8, 16u8,
+            122u8, 16u8, 141u8, 16u8, 160u8, 16u8, 179u8, 16u8, 198u8, 16u8, 217u8, 16u8, 236u8,
+            16u8, 255u8, 16u8, 18u8, 17u8, 37u8, 17u8, 56u8, 17u8, 75u8, 17u8, 94u8, 17u8, 113u8,
+            17u8, 132u8, 17u8, 151u8, 17u8, 170u8, 17u8, 189u8, 17u8, 208u8, 17u8, 227u8, 17u8,
+            246u8, 17u8, 9u8, 18u8, 28u8, 18u8, 47u8, 18u8, 66u8, 18u8, 85u8, 18u8, 104u8, 18u8,
+            123u8, 18u8, 142u8, 18u8, 161u8, 18u8, 180u8, 18u8, 199u8, 18u8, 218u8, 18u8, 237u8,
+            18u8, 0u8, 19u8, 19u8, 19u8, 38u8, 19u8, 57u8, 19u8, 76u8, 19u8, 95u8, 19u8, 114u8,
+            19u8, 133u8, 19u8, 152u8, 19u8, 171u8, 19u8, 190u8, 19u8, 209u8, 19u8, 228u8, 19u8,
+            247u8, 19u8, 10u8, 20u8, 29u8, 20u8, 48u8, 20u8, 67u8, 20u8, 86u8, 20u8, 105u8, 20u8,
+            124u8, 20u8, 143u8, 20u8, 162u8, 20u8, 181u8, 20u8, 200u8, 20u8, 219u8, 20u8, 238u8,
+            20u8, 1u8, 21u8, 20u8, 21u8, 39u8, 21u8, 58u8, 21u8, 78u8, 21u8, 97u8, 21u8, 116u8,
+            21u8, 135u8, 21u8, 154u8, 21u8, 173u8, 21u8, 192u8, 21u8, 211u8, 21u8, 230u8, 21u8,
+            249u8, 21u8, 12u8, 22u8, 31u8, 22u8, 50u8, 22u8, 69u8, 22u8, 88u8, 22u8, 107u8, 22u8,
+            1u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 110u8, 98u8,
+            110u8, 111u8, 4u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8,
+            104u8, 114u8, 98u8, 115u8, 4u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8,
+            0u8, 0u8, 115u8, 104u8, 98u8, 115u8, 4u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            2u8, 0u8, 0u8, 0u8, 115u8, 104u8, 104u8, 114u8, 4u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 115u8, 104u8, 115u8, 114u8, 4u8, 0u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 115u8, 115u8, 121u8, 97u8, 97u8, 4u8, 1u8, 2u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 103u8, 115u8, 119u8, 100u8,
+            101u8, 4u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 108u8,
+            98u8, 100u8, 101u8, 8u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8,
+            0u8, 100u8, 97u8, 110u8, 111u8, 8u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8,
+            0u8, 0u8, 0u8, 100u8, 97u8, 110u8, 98u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 2u8, 0u8, 0u8, 0u8, 97u8, 98u8, 114u8, 117u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 97u8, 99u8, 104u8, 101u8, 110u8, 20u8, 1u8, 2u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 97u8, 102u8, 110u8, 108u8, 30u8,
+            1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 97u8, 107u8, 101u8,
+            110u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 97u8,
+            109u8, 101u8, 110u8, 20u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8,
+            0u8, 97u8, 121u8, 101u8, 115u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8,
+            0u8, 0u8, 0u8, 97u8, 122u8, 114u8, 117u8, 20u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 3u8, 0u8, 0u8, 0u8, 98u8, 97u8, 108u8, 117u8, 114u8, 20u8, 1u8, 2u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 98u8, 101u8, 114u8, 117u8, 30u8, 1u8, 2u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 98u8, 101u8, 109u8, 101u8,
+            110u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 98u8,
+            104u8, 104u8, 105u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8,
+            0u8, 98u8, 110u8, 101u8, 110u8, 20u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8,
+            0u8, 0u8, 0u8, 98u8, 111u8, 122u8, 104u8, 20u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 2u8, 0u8, 0u8, 0u8, 98u8, 114u8, 102u8, 114u8, 20u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 99u8, 97u8, 101u8, 115u8, 30u8, 1u8, 2u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 99u8, 101u8, 98u8, 102u8, 105u8, 108u8,
+            20u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 99u8, 104u8,
+            114u8, 101u8, 110u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8,
+            0u8, 99u8, 107u8, 98u8, 97u8, 114u8, 20u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            2u8, 0u8, 0u8, 0u8, 99u8, 111u8, 102u8, 114u8, 20u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 99u8, 114u8, 115u8, 102u8, 114u8, 20u8, 0u8, 2u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 99u8, 115u8, 115u8, 107u8, 20u8, 1u8,
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 99u8, 121u8, 101u8, 110u8,
+            30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 101u8, 101u8,
+            101u8, 110u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8,
+            101u8, 111u8, 101u8, 110u8, 20u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8,
+            0u8, 0u8, 0u8, 101u8, 117u8, 101u8, 115u8, 20u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 102u8, 111u8, 100u8, 97u8, 20u8, 1u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 102u8, 121u8, 110u8, 108u8, 20u8, 1u8, 2u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 103u8, 97u8, 101u8, 110u8, 30u8,
+            1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 103u8, 97u8, 97u8,
+            101u8, 110u8, 20u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8,
+            103u8, 100u8, 101u8, 110u8, 20u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8,
+            0u8, 0u8, 0u8, 103u8, 108u8, 101u8, 115u8, 20u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 103u8, 110u8, 101u8, 115u8, 30u8, 1u8, 2u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 103u8, 117u8, 104u8, 105u8, 30u8, 1u8,
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 104u8, 97u8, 101u8, 110u8,
+            20u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 104u8, 97u8,
+            119u8, 101u8, 110u8, 20u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8,
+            0u8, 104u8, 116u8, 102u8, 114u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            2u8, 0u8, 0u8, 0u8, 104u8, 121u8, 114u8, 117u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 105u8, 97u8, 101u8, 110u8, 30u8, 1u8, 2u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 105u8, 103u8, 101u8, 110u8, 20u8, 1u8,
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 105u8, 115u8, 101u8, 110u8,
+            20u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 106u8, 118u8,
+            105u8, 100u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8,
+            107u8, 97u8, 101u8, 110u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8,
+            0u8, 0u8, 107u8, 103u8, 102u8, 114u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 2u8, 0u8, 0u8, 0u8, 107u8, 107u8, 114u8, 117u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 107u8, 109u8, 101u8, 110u8, 30u8, 1u8, 2u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 107u8, 110u8, 101u8, 110u8,
+            30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 107u8, 114u8,
+            105u8, 101u8, 110u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8,
+            0u8, 107u8, 117u8, 116u8, 114u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            2u8, 0u8, 0u8, 0u8, 107u8, 121u8, 114u8, 117u8, 20u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 108u8, 97u8, 105u8, 116u8, 30u8, 1u8, 2u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 108u8, 103u8, 101u8, 110u8, 30u8, 1u8,
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 108u8, 110u8, 102u8, 114u8,
+            30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 108u8, 111u8,
+            101u8, 110u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8,
+            108u8, 111u8, 122u8, 101u8, 110u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            3u8, 0u8, 0u8, 0u8, 108u8, 117u8, 97u8, 102u8, 114u8, 20u8, 1u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 109u8, 97u8, 105u8, 104u8, 105u8, 30u8, 1u8,
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 109u8, 102u8, 101u8, 101u8,
+            110u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 109u8,
+            103u8, 102u8, 114u8, 20u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8,
+            0u8, 109u8, 105u8, 101u8, 110u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            2u8, 0u8, 0u8, 0u8, 109u8, 108u8, 101u8, 110u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 109u8, 110u8, 114u8, 117u8, 30u8, 1u8, 2u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 109u8, 114u8, 104u8, 105u8, 30u8,
+            1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 109u8, 115u8, 105u8,
+            100u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 109u8,
+            116u8, 101u8, 110u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8,
+            0u8, 109u8, 121u8, 101u8, 110u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            2u8, 0u8, 0u8, 0u8, 110u8, 101u8, 101u8, 110u8, 20u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 110u8, 110u8, 110u8, 98u8, 20u8, 0u8, 2u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 110u8, 110u8, 110u8, 111u8, 30u8, 1u8,
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 110u8, 115u8, 111u8, 101u8,
+            110u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 110u8,
+            121u8, 101u8, 110u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8,
+            0u8, 110u8, 121u8, 110u8, 101u8, 110u8, 20u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 2u8, 0u8, 0u8, 0u8, 111u8, 99u8, 102u8, 114u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 111u8, 109u8, 101u8, 110u8, 30u8, 1u8, 2u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 111u8, 114u8, 101u8, 110u8, 30u8,
+            1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 112u8, 97u8, 101u8,
+            110u8, 20u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 112u8,
+            99u8, 109u8, 101u8, 110u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8,
+            0u8, 0u8, 112u8, 115u8, 101u8, 110u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 2u8, 0u8, 0u8, 0u8, 113u8, 117u8, 101u8, 115u8, 20u8, 1u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 114u8, 109u8, 100u8, 101u8, 30u8, 1u8, 2u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 114u8, 110u8, 101u8, 110u8,
+            30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 114u8, 119u8,
+            102u8, 114u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8,
+            115u8, 97u8, 104u8, 105u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8,
+            0u8, 0u8, 115u8, 100u8, 101u8, 110u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 2u8, 0u8, 0u8, 0u8, 115u8, 105u8, 101u8, 110u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 115u8, 110u8, 101u8, 110u8, 30u8, 1u8, 2u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 115u8, 111u8, 101u8, 110u8,
+            30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 115u8, 113u8,
+            101u8, 110u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8,
+            115u8, 116u8, 101u8, 110u8, 20u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8,
+            0u8, 0u8, 0u8, 115u8, 117u8, 105u8, 100u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 115u8, 119u8, 101u8, 110u8, 30u8, 1u8, 2u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 116u8, 97u8, 101u8, 110u8, 30u8, 1u8, 2u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 116u8, 101u8, 101u8, 110u8,
+            30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 116u8, 103u8,
+            114u8, 117u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8,
+            116u8, 105u8, 101u8, 110u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8,
+            0u8, 0u8, 0u8, 116u8, 107u8, 114u8, 117u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 116u8, 108u8, 104u8, 101u8, 110u8, 30u8, 1u8, 2u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 116u8, 110u8, 101u8, 110u8, 30u8,
+            1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 116u8, 111u8, 101u8,
+            110u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 116u8,
+            116u8, 114u8, 117u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8,
+            0u8, 116u8, 117u8, 109u8, 101u8, 110u8, 20u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 2u8, 0u8, 0u8, 0u8, 117u8, 103u8, 122u8, 104u8, 20u8, 1u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 117u8, 107u8, 114u8, 117u8, 30u8, 1u8, 2u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 117u8, 114u8, 101u8, 110u8,
+            30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 117u8, 122u8,
+            114u8, 117u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8,
+            119u8, 111u8, 102u8, 114u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8,
+            0u8, 0u8, 0u8, 120u8, 104u8, 101u8, 110u8, 30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 121u8, 105u8, 101u8, 110u8, 30u8, 1u8, 2u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 121u8, 111u8, 101u8, 110u8, 20u8, 1u8,
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 122u8, 97u8, 122u8, 104u8,
+            30u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 122u8, 117u8,
+            101u8, 110u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8,
+            97u8, 97u8, 111u8, 97u8, 114u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8,
+            0u8, 0u8, 0u8, 97u8, 98u8, 104u8, 97u8, 114u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 97u8, 98u8, 118u8, 97u8, 114u8, 10u8, 1u8, 2u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 97u8, 99u8, 109u8, 97u8, 114u8, 10u8, 1u8,
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 97u8, 99u8, 113u8, 97u8,
+            114u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 97u8,
+            99u8, 119u8, 97u8, 114u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8,
+            0u8, 0u8, 97u8, 99u8, 120u8, 97u8, 114u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 3u8, 0u8, 0u8, 0u8, 97u8, 99u8, 121u8, 97u8, 114u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 97u8, 100u8, 102u8, 97u8, 114u8, 10u8, 1u8,
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 97u8, 101u8, 98u8, 97u8,
+            114u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 97u8,
+            101u8, 99u8, 97u8, 114u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8,
+            0u8, 0u8, 97u8, 102u8, 98u8, 97u8, 114u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 3u8, 0u8, 0u8, 0u8, 97u8, 106u8, 112u8, 97u8, 114u8, 10u8, 1u8, 2u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 97u8, 112u8, 99u8, 97u8, 114u8, 10u8, 1u8,
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 97u8, 112u8, 100u8, 97u8,
+            114u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 97u8,
+            114u8, 113u8, 97u8, 114u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8,
+            0u8, 0u8, 97u8, 114u8, 115u8, 97u8, 114u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 97u8, 114u8, 121u8, 97u8, 114u8, 10u8, 1u8, 2u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 97u8, 114u8, 122u8, 97u8, 114u8,
+            10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 97u8, 117u8,
+            122u8, 97u8, 114u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8,
+            0u8, 97u8, 118u8, 108u8, 97u8, 114u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 3u8, 0u8, 0u8, 0u8, 97u8, 121u8, 104u8, 97u8, 114u8, 10u8, 1u8, 2u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 97u8, 121u8, 108u8, 97u8, 114u8, 10u8,
+            1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 97u8, 121u8, 110u8,
+            97u8, 114u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8,
+            97u8, 121u8, 112u8, 97u8, 114u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            3u8, 0u8, 0u8, 0u8, 98u8, 98u8, 122u8, 97u8, 114u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 112u8, 103u8, 97u8, 97u8, 114u8, 10u8, 1u8, 2u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 115u8, 104u8, 117u8, 97u8,
+            114u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 115u8,
+            115u8, 104u8, 97u8, 114u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8,
+            0u8, 0u8, 97u8, 122u8, 98u8, 97u8, 122u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 3u8, 0u8, 0u8, 0u8, 118u8, 114u8, 111u8, 101u8, 116u8, 10u8, 1u8, 2u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 102u8, 102u8, 109u8, 102u8, 102u8, 10u8,
+            1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 102u8, 117u8, 98u8,
+            102u8, 102u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8,
+            102u8, 117u8, 101u8, 102u8, 102u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            3u8, 0u8, 0u8, 0u8, 102u8, 117u8, 102u8, 102u8, 102u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 102u8, 117u8, 104u8, 102u8, 102u8, 10u8, 1u8,
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 102u8, 117u8, 105u8, 102u8,
+            102u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 102u8,
+            117u8, 113u8, 102u8, 102u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8,
+            0u8, 0u8, 0u8, 102u8, 117u8, 118u8, 102u8, 102u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 103u8, 110u8, 119u8, 103u8, 110u8, 10u8, 1u8, 2u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 103u8, 117u8, 105u8, 103u8,
+            110u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 103u8,
+            117u8, 110u8, 103u8, 110u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8,
+            0u8, 0u8, 0u8, 110u8, 104u8, 100u8, 103u8, 110u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 105u8, 107u8, 116u8, 105u8, 117u8, 10u8, 1u8, 2u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 101u8, 110u8, 98u8, 107u8,
+            108u8, 110u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8,
+            101u8, 121u8, 111u8, 107u8, 108u8, 110u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 3u8, 0u8, 0u8, 0u8, 110u8, 105u8, 113u8, 107u8, 108u8, 110u8, 10u8, 1u8, 2u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 111u8, 107u8, 105u8, 107u8, 108u8,
+            110u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 112u8,
+            107u8, 111u8, 107u8, 108u8, 110u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            3u8, 0u8, 0u8, 0u8, 115u8, 103u8, 99u8, 107u8, 108u8, 110u8, 10u8, 1u8, 2u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 116u8, 101u8, 99u8, 107u8, 108u8, 110u8,
+            10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 116u8, 117u8,
+            121u8, 107u8, 108u8, 110u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8,
+            0u8, 0u8, 0u8, 103u8, 111u8, 109u8, 107u8, 111u8, 107u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 103u8, 107u8, 112u8, 107u8, 112u8, 101u8, 10u8,
+            1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 105u8, 100u8, 97u8,
+            108u8, 117u8, 121u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8,
+            0u8, 108u8, 107u8, 98u8, 108u8, 117u8, 121u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 108u8, 107u8, 111u8, 108u8, 117u8, 121u8, 10u8, 1u8, 2u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 108u8, 107u8, 115u8, 108u8,
+            117u8, 121u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8,
+            108u8, 114u8, 105u8, 108u8, 117u8, 121u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 3u8, 0u8, 0u8, 0u8, 108u8, 114u8, 109u8, 108u8, 117u8, 121u8, 10u8, 1u8, 2u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 108u8, 115u8, 109u8, 108u8, 117u8,
+            121u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 108u8,
+            116u8, 111u8, 108u8, 117u8, 121u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            3u8, 0u8, 0u8, 0u8, 108u8, 116u8, 115u8, 108u8, 117u8, 121u8, 10u8, 1u8, 2u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 108u8, 119u8, 103u8, 108u8, 117u8, 121u8,
+            10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 110u8, 108u8,
+            101u8, 108u8, 117u8, 121u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8,
+            0u8, 0u8, 0u8, 110u8, 121u8, 100u8, 108u8, 117u8, 121u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 114u8, 97u8, 103u8, 108u8, 117u8, 121u8, 10u8,
+            1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 108u8, 116u8, 103u8,
+            108u8, 118u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8,
+            98u8, 104u8, 114u8, 109u8, 103u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            3u8, 0u8, 0u8, 0u8, 98u8, 106u8, 113u8, 109u8, 103u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 98u8, 109u8, 109u8, 109u8, 103u8, 10u8, 1u8,
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 98u8, 122u8, 99u8, 109u8,
+            103u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 109u8,
+            115u8, 104u8, 109u8, 103u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8,
+            0u8, 0u8, 0u8, 115u8, 107u8, 103u8, 109u8, 103u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 116u8, 100u8, 120u8, 109u8, 103u8, 10u8, 1u8, 2u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 116u8, 107u8, 103u8, 109u8,
+            103u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 116u8,
+            120u8, 121u8, 109u8, 103u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8,
+            0u8, 0u8, 0u8, 120u8, 109u8, 118u8, 109u8, 103u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 120u8, 109u8, 119u8, 109u8, 103u8, 10u8, 1u8, 2u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 109u8, 118u8, 102u8, 109u8,
+            110u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 98u8,
+            106u8, 110u8, 109u8, 115u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8,
+            0u8, 0u8, 0u8, 98u8, 116u8, 106u8, 109u8, 115u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 98u8, 118u8, 101u8, 109u8, 115u8, 10u8, 1u8, 2u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 98u8, 118u8, 117u8, 109u8,
+            115u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 99u8,
+            111u8, 97u8, 109u8, 115u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8,
+            0u8, 0u8, 100u8, 117u8, 112u8, 109u8, 115u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 104u8, 106u8, 105u8, 109u8, 115u8, 10u8, 1u8, 2u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 105u8, 100u8, 109u8, 115u8, 10u8,
+            1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 106u8, 97u8, 107u8,
+            109u8, 115u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8,
+            106u8, 97u8, 120u8, 109u8, 115u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            3u8, 0u8, 0u8, 0u8, 107u8, 118u8, 98u8, 109u8, 115u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 107u8, 118u8, 114u8, 109u8, 115u8, 10u8, 1u8,
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 107u8, 120u8, 100u8, 109u8,
+            115u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 108u8,
+            99u8, 101u8, 109u8, 115u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8,
+            0u8, 0u8, 108u8, 99u8, 102u8, 109u8, 115u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 108u8, 105u8, 119u8, 109u8, 115u8, 10u8, 1u8, 2u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 109u8, 97u8, 120u8, 109u8, 115u8,
+            10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 109u8, 101u8,
+            111u8, 109u8, 115u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8,
+            0u8, 109u8, 102u8, 97u8, 109u8, 115u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 3u8, 0u8, 0u8, 0u8, 109u8, 102u8, 98u8, 109u8, 115u8, 10u8, 1u8, 2u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 109u8, 105u8, 110u8, 109u8, 115u8, 10u8,
+            1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 109u8, 113u8, 103u8,
+            109u8, 115u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8,
+            109u8, 115u8, 105u8, 109u8, 115u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            3u8, 0u8, 0u8, 0u8, 109u8, 117u8, 105u8, 109u8, 115u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 111u8, 114u8, 110u8, 109u8, 115u8, 10u8, 1u8,
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 111u8, 114u8, 115u8, 109u8,
+            115u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 112u8,
+            101u8, 108u8, 109u8, 115u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8,
+            0u8, 0u8, 0u8, 112u8, 115u8, 101u8, 109u8, 115u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 116u8, 109u8, 119u8, 109u8, 115u8, 10u8, 1u8, 2u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 117u8, 114u8, 107u8, 109u8,
+            115u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 118u8,
+            107u8, 107u8, 109u8, 115u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8,
+            0u8, 0u8, 0u8, 118u8, 107u8, 116u8, 109u8, 115u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 120u8, 109u8, 109u8, 109u8, 115u8, 10u8, 1u8, 2u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 122u8, 108u8, 109u8, 109u8,
+            115u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 122u8,
+            109u8, 105u8, 109u8, 115u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8,
+            0u8, 0u8, 0u8, 100u8, 116u8, 121u8, 110u8, 101u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 103u8, 97u8, 120u8, 111u8, 109u8, 10u8, 1u8, 2u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 104u8, 97u8, 101u8, 111u8,
+            109u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 111u8,
+            114u8, 99u8, 111u8, 109u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8,
+            0u8, 0u8, 115u8, 112u8, 118u8, 111u8, 114u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 112u8, 98u8, 116u8, 112u8, 115u8, 10u8, 1u8, 2u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 112u8, 115u8, 116u8, 112u8, 115u8,
+            10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 113u8, 117u8,
+            98u8, 113u8, 117u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8,
+            0u8, 113u8, 117u8, 100u8, 113u8, 117u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 3u8, 0u8, 0u8, 0u8, 113u8, 117u8, 102u8, 113u8, 117u8, 10u8, 1u8, 2u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 113u8, 117u8, 103u8, 113u8, 117u8, 10u8,
+            1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 113u8, 117u8, 104u8,
+            113u8, 117u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8,
+            113u8, 117u8, 107u8, 113u8, 117u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            3u8, 0u8, 0u8, 0u8, 113u8, 117u8, 108u8, 113u8, 117u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 113u8, 117u8, 112u8, 113u8, 117u8, 10u8, 1u8,
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 113u8, 117u8, 114u8, 113u8,
+            117u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 113u8,
+            117u8, 115u8, 113u8, 117u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8,
+            0u8, 0u8, 0u8, 113u8, 117u8, 119u8, 113u8, 117u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 113u8, 117u8, 120u8, 113u8, 117u8, 10u8, 1u8, 2u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 113u8, 117u8, 121u8, 113u8,
+            117u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 113u8,
+            118u8, 97u8, 113u8, 117u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8,
+            0u8, 0u8, 113u8, 118u8, 99u8, 113u8, 117u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 113u8, 118u8, 101u8, 113u8, 117u8, 10u8, 1u8, 2u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 113u8, 118u8, 104u8, 113u8, 117u8,
+            10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 113u8, 118u8,
+            105u8, 113u8, 117u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8,
+            0u8, 113u8, 118u8, 106u8, 113u8, 117u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 3u8, 0u8, 0u8, 0u8, 113u8, 118u8, 108u8, 113u8, 117u8, 10u8, 1u8, 2u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 113u8, 118u8, 109u8, 113u8, 117u8, 10u8,
+            1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 113u8, 118u8, 110u8,
+            113u8, 117u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8,
+            113u8, 118u8, 111u8, 113u8, 117u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            3u8, 0u8, 0u8, 0u8, 113u8, 118u8, 112u8, 113u8, 117u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 113u8, 118u8, 115u8, 113u8, 117u8, 10u8, 1u8,
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 113u8, 118u8, 119u8, 113u8,
+            117u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 113u8,
+            118u8, 122u8, 113u8, 117u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8,
+            0u8, 0u8, 0u8, 113u8, 119u8, 97u8, 113u8, 117u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 113u8, 119u8, 99u8, 113u8, 117u8, 10u8, 1u8, 2u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 113u8, 119u8, 104u8, 113u8,
+            117u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 113u8,
+            119u8, 115u8, 113u8, 117u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8,
+            0u8, 0u8, 0u8, 113u8, 120u8, 97u8, 113u8, 117u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 113u8, 120u8, 99u8, 113u8, 117u8, 10u8, 1u8, 2u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 113u8, 120u8, 104u8, 113u8,
+            117u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 113u8,
+            120u8, 108u8, 113u8, 117u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8,
+            0u8, 0u8, 0u8, 113u8, 120u8, 110u8, 113u8, 117u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 113u8, 120u8, 111u8, 113u8, 117u8, 10u8, 1u8, 2u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 113u8, 120u8, 112u8, 113u8,
+            117u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 113u8,
+            120u8, 114u8, 113u8, 117u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8,
+            0u8, 0u8, 0u8, 113u8, 120u8, 116u8, 113u8, 117u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 113u8, 120u8, 117u8, 113u8, 117u8, 10u8, 1u8, 2u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 113u8, 120u8, 119u8, 113u8,
+            117u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 115u8,
+            100u8, 99u8, 115u8, 99u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8,
+            0u8, 0u8, 115u8, 100u8, 110u8, 115u8, 99u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 115u8, 114u8, 111u8, 115u8, 99u8, 10u8, 1u8, 2u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 97u8, 97u8, 101u8, 115u8, 113u8,
+            10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 97u8, 97u8,
+            116u8, 115u8, 113u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8,
+            0u8, 97u8, 108u8, 110u8, 115u8, 113u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 3u8, 0u8, 0u8, 0u8, 97u8, 105u8, 105u8, 115u8, 121u8, 114u8, 10u8, 1u8, 2u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 117u8, 122u8, 115u8, 117u8, 122u8,
+            10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 121u8, 105u8,
+            104u8, 121u8, 105u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8,
+            0u8, 99u8, 100u8, 111u8, 122u8, 104u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 3u8, 0u8, 0u8, 0u8, 99u8, 106u8, 121u8, 122u8, 104u8, 10u8, 1u8, 2u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 99u8, 112u8, 120u8, 122u8, 104u8, 10u8,
+            1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 99u8, 122u8, 104u8,
+            122u8, 104u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8,
+            99u8, 122u8, 111u8, 122u8, 104u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            3u8, 0u8, 0u8, 0u8, 103u8, 97u8, 110u8, 122u8, 104u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 104u8, 97u8, 107u8, 122u8, 104u8, 10u8, 1u8,
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 104u8, 115u8, 110u8, 122u8,
+            104u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 108u8,
+            122u8, 104u8, 122u8, 104u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8,
+            0u8, 0u8, 0u8, 109u8, 110u8, 112u8, 122u8, 104u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 110u8, 97u8, 110u8, 122u8, 104u8, 10u8, 1u8, 2u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 119u8, 117u8, 117u8, 122u8,
+            104u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 121u8,
+            117u8, 101u8, 122u8, 104u8, 80u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+            0u8, 0u8, 0u8, 42u8, 42u8,
+        ])
+    },
+    script: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            50u8, 0u8, 0u8, 0u8, 0u8, 0u8, 28u8, 0u8, 56u8, 0u8, 84u8, 0u8, 112u8, 0u8, 140u8, 0u8,
+            168u8, 0u8, 196u8, 0u8, 224u8, 0u8, 252u8, 0u8, 24u8, 1u8, 52u8, 1u8, 80u8, 1u8, 108u8,
+            1u8, 136u8, 1u8, 164u8, 1u8, 192u8, 1u8, 220u8, 1u8, 248u8, 1u8, 20u8, 2u8, 48u8, 2u8,
+            76u8, 2u8, 104u8, 2u8, 132u8, 2u8, 160u8, 2u8, 188u8, 2u8, 216u8, 2u8, 244u8, 2u8,
+            16u8, 3u8, 44u8, 3u8, 72u8, 3u8, 100u8, 3u8, 128u8, 3u8, 156u8, 3u8, 184u8, 3u8, 212u8,
+            3u8, 240u8, 3u8, 12u8, 4u8, 40u8, 4u8, 68u8, 4u8, 96u8, 4u8, 124u8, 4u8, 152u8, 4u8,
+            180u8, 4u8, 208u8, 4u8, 236u8, 4u8, 8u8, 5u8, 36u8, 5u8, 64u8, 5u8, 92u8, 5u8, 10u8,
+            1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 97u8, 109u8, 95u8,
+            69u8, 116u8, 104u8, 105u8, 101u8, 110u8, 95u8, 76u8, 97u8, 116u8, 110u8, 10u8, 1u8,
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 97u8, 122u8, 95u8, 76u8,
+            97u8, 116u8, 110u8, 114u8, 117u8, 95u8, 67u8, 121u8, 114u8, 108u8, 10u8, 1u8, 2u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 98u8, 110u8, 95u8, 66u8, 101u8,
+            110u8, 103u8, 101u8, 110u8, 95u8, 76u8, 97u8, 116u8, 110u8, 10u8, 1u8, 2u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 98u8, 111u8, 95u8, 84u8, 105u8, 98u8,
+            116u8, 122u8, 104u8, 95u8, 72u8, 97u8, 110u8, 115u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 104u8, 121u8, 95u8, 65u8, 114u8, 109u8, 110u8,
+            114u8, 117u8, 95u8, 67u8, 121u8, 114u8, 108u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 107u8, 97u8, 95u8, 71u8, 101u8, 111u8, 114u8, 101u8,
+            110u8, 95u8, 76u8, 97u8, 116u8, 110u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 7u8, 0u8, 0u8, 0u8, 107u8, 109u8, 95u8, 75u8, 104u8, 109u8, 114u8, 101u8, 110u8,
+            95u8, 76u8, 97u8, 116u8, 110u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8,
+            0u8, 0u8, 0u8, 107u8, 110u8, 95u8, 75u8, 110u8, 100u8, 97u8, 101u8, 110u8, 95u8, 76u8,
+            97u8, 116u8, 110u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8,
+            0u8, 108u8, 111u8, 95u8, 76u8, 97u8, 111u8, 111u8, 101u8, 110u8, 95u8, 76u8, 97u8,
+            116u8, 110u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8,
+            109u8, 108u8, 95u8, 77u8, 108u8, 121u8, 109u8, 101u8, 110u8, 95u8, 76u8, 97u8, 116u8,
+            110u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 109u8,
+            121u8, 95u8, 77u8, 121u8, 109u8, 114u8, 101u8, 110u8, 95u8, 76u8, 97u8, 116u8, 110u8,
+            10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 110u8, 101u8,
+            95u8, 68u8, 101u8, 118u8, 97u8, 101u8, 110u8, 95u8, 76u8, 97u8, 116u8, 110u8, 10u8,
+            1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 111u8, 114u8, 95u8,
+            79u8, 114u8, 121u8, 97u8, 101u8, 110u8, 95u8, 76u8, 97u8, 116u8, 110u8, 10u8, 1u8, 2u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 112u8, 97u8, 95u8, 71u8, 117u8,
+            114u8, 117u8, 101u8, 110u8, 95u8, 76u8, 97u8, 116u8, 110u8, 10u8, 1u8, 2u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 112u8, 115u8, 95u8, 65u8, 114u8, 97u8,
+            98u8, 101u8, 110u8, 95u8, 76u8, 97u8, 116u8, 110u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 115u8, 100u8, 95u8, 65u8, 114u8, 97u8, 98u8, 101u8,
+            110u8, 95u8, 76u8, 97u8, 116u8, 110u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 7u8, 0u8, 0u8, 0u8, 115u8, 105u8, 95u8, 83u8, 105u8, 110u8, 104u8, 101u8, 110u8,
+            95u8, 76u8, 97u8, 116u8, 110u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8,
+            0u8, 0u8, 0u8, 116u8, 97u8, 95u8, 84u8, 97u8, 109u8, 108u8, 101u8, 110u8, 95u8, 76u8,
+            97u8, 116u8, 110u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8,
+            0u8, 116u8, 101u8, 95u8, 84u8, 101u8, 108u8, 117u8, 101u8, 110u8, 95u8, 76u8, 97u8,
+            116u8, 110u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8,
+            116u8, 105u8, 95u8, 69u8, 116u8, 104u8, 105u8, 101u8, 110u8, 95u8, 76u8, 97u8, 116u8,
+            110u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 116u8,
+            107u8, 95u8, 76u8, 97u8, 116u8, 110u8, 114u8, 117u8, 95u8, 67u8, 121u8, 114u8, 108u8,
+            10u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 117u8, 114u8,
+            95u8, 65u8, 114u8, 97u8, 98u8, 101u8, 110u8, 95u8, 76u8, 97u8, 116u8, 110u8, 10u8, 1u8,
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 117u8, 122u8, 95u8, 76u8,
+            97u8, 116u8, 110u8, 114u8, 117u8, 95u8, 67u8, 121u8, 114u8, 108u8, 10u8, 1u8, 2u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 121u8, 105u8, 95u8, 72u8, 101u8,
+            98u8, 114u8, 101u8, 110u8, 95u8, 76u8, 97u8, 116u8, 110u8, 5u8, 0u8, 2u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 115u8, 114u8, 95u8, 76u8, 97u8, 116u8,
+            110u8, 115u8, 114u8, 95u8, 67u8, 121u8, 114u8, 108u8, 10u8, 1u8, 2u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 122u8, 97u8, 95u8, 76u8, 97u8, 116u8, 110u8,
+            122u8, 104u8, 95u8, 72u8, 97u8, 110u8, 115u8, 20u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 122u8, 104u8, 95u8, 72u8, 97u8, 110u8, 105u8, 122u8,
+            104u8, 95u8, 72u8, 97u8, 110u8, 115u8, 20u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 7u8, 0u8, 0u8, 0u8, 122u8, 104u8, 95u8, 72u8, 97u8, 110u8, 105u8, 122u8, 104u8,
+            95u8, 72u8, 97u8, 110u8, 116u8, 20u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8,
+            0u8, 0u8, 0u8, 97u8, 114u8, 95u8, 76u8, 97u8, 116u8, 110u8, 97u8, 114u8, 95u8, 65u8,
+            114u8, 97u8, 98u8, 20u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8,
+            0u8, 98u8, 110u8, 95u8, 76u8, 97u8, 116u8, 110u8, 98u8, 110u8, 95u8, 66u8, 101u8,
+            110u8, 103u8, 20u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8,
+            103u8, 117u8, 95u8, 76u8, 97u8, 116u8, 110u8, 103u8, 117u8, 95u8, 71u8, 117u8, 106u8,
+            114u8, 20u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 104u8,
+            105u8, 95u8, 76u8, 97u8, 116u8, 110u8, 104u8, 105u8, 95u8, 68u8, 101u8, 118u8, 97u8,
+            20u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 107u8, 110u8,
+            95u8, 76u8, 97u8, 116u8, 110u8, 107u8, 110u8, 95u8, 75u8, 110u8, 100u8, 97u8, 20u8,
+            1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 109u8, 108u8, 95u8,
+            76u8, 97u8, 116u8, 110u8, 109u8, 108u8, 95u8, 77u8, 108u8, 121u8, 109u8, 20u8, 1u8,
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 109u8, 114u8, 95u8, 76u8,
+            97u8, 116u8, 110u8, 109u8, 114u8, 95u8, 68u8, 101u8, 118u8, 97u8, 20u8, 1u8, 2u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 116u8, 97u8, 95u8, 76u8, 97u8, 116u8,
+            110u8, 116u8, 97u8, 95u8, 84u8, 97u8, 109u8, 108u8, 20u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 116u8, 101u8, 95u8, 76u8, 97u8, 116u8, 110u8, 116u8,
+            101u8, 95u8, 84u8, 101u8, 108u8, 117u8, 20u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 7u8, 0u8, 0u8, 0u8, 122u8, 104u8, 95u8, 76u8, 97u8, 116u8, 110u8, 122u8, 104u8,
+            95u8, 72u8, 97u8, 110u8, 115u8, 5u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8,
+            0u8, 0u8, 0u8, 106u8, 97u8, 95u8, 76u8, 97u8, 116u8, 110u8, 106u8, 97u8, 95u8, 74u8,
+            112u8, 97u8, 110u8, 5u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8,
+            0u8, 106u8, 97u8, 95u8, 72u8, 97u8, 110u8, 105u8, 106u8, 97u8, 95u8, 74u8, 112u8, 97u8,
+            110u8, 5u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 106u8,
+            97u8, 95u8, 72u8, 105u8, 114u8, 97u8, 106u8, 97u8, 95u8, 74u8, 112u8, 97u8, 110u8, 5u8,
+            1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 106u8, 97u8, 95u8,
+            75u8, 97u8, 110u8, 97u8, 106u8, 97u8, 95u8, 74u8, 112u8, 97u8, 110u8, 5u8, 1u8, 2u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 106u8, 97u8, 95u8, 72u8, 114u8,
+            107u8, 116u8, 106u8, 97u8, 95u8, 74u8, 112u8, 97u8, 110u8, 5u8, 1u8, 2u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 106u8, 97u8, 95u8, 72u8, 105u8, 114u8,
+            97u8, 106u8, 97u8, 95u8, 72u8, 114u8, 107u8, 116u8, 5u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 106u8, 97u8, 95u8, 75u8, 97u8, 110u8, 97u8, 106u8,
+            97u8, 95u8, 72u8, 114u8, 107u8, 116u8, 5u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 7u8, 0u8, 0u8, 0u8, 107u8, 111u8, 95u8, 72u8, 97u8, 110u8, 105u8, 107u8, 111u8,
+            95u8, 75u8, 111u8, 114u8, 101u8, 5u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8,
+            0u8, 0u8, 0u8, 107u8, 111u8, 95u8, 72u8, 97u8, 110u8, 103u8, 107u8, 111u8, 95u8, 75u8,
+            111u8, 114u8, 101u8, 5u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8,
+            0u8, 107u8, 111u8, 95u8, 74u8, 97u8, 109u8, 111u8, 107u8, 111u8, 95u8, 75u8, 111u8,
+            114u8, 101u8, 5u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8,
+            107u8, 111u8, 95u8, 74u8, 97u8, 109u8, 111u8, 107u8, 111u8, 95u8, 72u8, 97u8, 110u8,
+            103u8, 50u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 42u8,
+            95u8, 42u8, 42u8, 95u8, 42u8,
+        ])
+    },
+    region: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            17u8, 0u8, 0u8, 0u8, 0u8, 0u8, 40u8, 0u8, 82u8, 0u8, 108u8, 0u8, 142u8, 0u8, 174u8,
+            0u8, 210u8, 0u8, 236u8, 0u8, 22u8, 1u8, 66u8, 1u8, 92u8, 1u8, 134u8, 1u8, 178u8, 1u8,
+            204u8, 1u8, 246u8, 1u8, 34u8, 2u8, 66u8, 2u8, 4u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 97u8, 114u8, 95u8, 42u8, 95u8, 36u8, 109u8, 97u8, 103u8,
+            104u8, 114u8, 101u8, 98u8, 97u8, 114u8, 95u8, 42u8, 95u8, 36u8, 109u8, 97u8, 103u8,
+            104u8, 114u8, 101u8, 98u8, 4u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 14u8, 0u8,
+            0u8, 0u8, 97u8, 114u8, 95u8, 42u8, 95u8, 36u8, 33u8, 109u8, 97u8, 103u8, 104u8, 114u8,
+            101u8, 98u8, 97u8, 114u8, 95u8, 42u8, 95u8, 36u8, 33u8, 109u8, 97u8, 103u8, 104u8,
+            114u8, 101u8, 98u8, 5u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8,
+            0u8, 97u8, 114u8, 95u8, 42u8, 95u8, 42u8, 97u8, 114u8, 95u8, 42u8, 95u8, 42u8, 4u8,
+            0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 101u8, 110u8, 95u8,
+            42u8, 95u8, 36u8, 101u8, 110u8, 85u8, 83u8, 101u8, 110u8, 95u8, 42u8, 95u8, 36u8,
+            101u8, 110u8, 85u8, 83u8, 3u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8,
+            0u8, 0u8, 101u8, 110u8, 95u8, 42u8, 95u8, 36u8, 33u8, 101u8, 110u8, 85u8, 83u8, 101u8,
+            110u8, 95u8, 42u8, 95u8, 71u8, 66u8, 4u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            11u8, 0u8, 0u8, 0u8, 101u8, 110u8, 95u8, 42u8, 95u8, 36u8, 33u8, 101u8, 110u8, 85u8,
+            83u8, 101u8, 110u8, 95u8, 42u8, 95u8, 36u8, 33u8, 101u8, 110u8, 85u8, 83u8, 5u8, 0u8,
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 101u8, 110u8, 95u8, 42u8,
+            95u8, 42u8, 101u8, 110u8, 95u8, 42u8, 95u8, 42u8, 4u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 101u8, 115u8, 95u8, 42u8, 95u8, 36u8, 97u8, 109u8,
+            101u8, 114u8, 105u8, 99u8, 97u8, 115u8, 101u8, 115u8, 95u8, 42u8, 95u8, 36u8, 97u8,
+            109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 115u8, 4u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 101u8, 115u8, 95u8, 42u8, 95u8, 36u8, 33u8, 97u8, 109u8,
+            101u8, 114u8, 105u8, 99u8, 97u8, 115u8, 101u8, 115u8, 95u8, 42u8, 95u8, 36u8, 33u8,
+            97u8, 109u8, 101u8, 114u8, 105u8, 99u8, 97u8, 115u8, 5u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 101u8, 115u8, 95u8, 42u8, 95u8, 42u8, 101u8, 115u8,
+            95u8, 42u8, 95u8, 42u8, 4u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 14u8, 0u8,
+            0u8, 0u8, 112u8, 116u8, 95u8, 42u8, 95u8, 36u8, 97u8, 109u8, 101u8, 114u8, 105u8, 99u8,
+            97u8, 115u8, 112u8, 116u8, 95u8, 42u8, 95u8, 36u8, 97u8, 109u8, 101u8, 114u8, 105u8,
+            99u8, 97u8, 115u8, 4u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8,
+            0u8, 112u8, 116u8, 95u8, 42u8, 95u8, 36u8, 33u8, 97u8, 109u8, 101u8, 114u8, 105u8,
+            99u8, 97u8, 115u8, 112u8, 116u8, 95u8, 42u8, 95u8, 36u8, 33u8, 97u8, 109u8, 101u8,
+            114u8, 105u8, 99u8, 97u8, 115u8, 5u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8,
+            0u8, 0u8, 0u8, 112u8, 116u8, 95u8, 42u8, 95u8, 42u8, 112u8, 116u8, 95u8, 42u8, 95u8,
+            42u8, 4u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 122u8,
+            104u8, 95u8, 72u8, 97u8, 110u8, 116u8, 95u8, 36u8, 99u8, 110u8, 115u8, 97u8, 114u8,
+            122u8, 104u8, 95u8, 72u8, 97u8, 110u8, 116u8, 95u8, 36u8, 99u8, 110u8, 115u8, 97u8,
+            114u8, 4u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 122u8,
+            104u8, 95u8, 72u8, 97u8, 110u8, 116u8, 95u8, 36u8, 33u8, 99u8, 110u8, 115u8, 97u8,
+            114u8, 122u8, 104u8, 95u8, 72u8, 97u8, 110u8, 116u8, 95u8, 36u8, 33u8, 99u8, 110u8,
+            115u8, 97u8, 114u8, 5u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8,
+            0u8, 122u8, 104u8, 95u8, 72u8, 97u8, 110u8, 116u8, 95u8, 42u8, 122u8, 104u8, 95u8,
+            72u8, 97u8, 110u8, 116u8, 95u8, 42u8, 4u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            5u8, 0u8, 0u8, 0u8, 42u8, 95u8, 42u8, 95u8, 42u8, 42u8, 95u8, 42u8, 95u8, 42u8,
+        ])
+    },
+};
@@ -1,3 +1,4 @@
 // @generated
 pub mod aliases_v1;
+pub mod languagematching_v1;
 pub mod likelysubtags_v1;
@@ -1,7 +1,9 @@
 // @generated
 mod calendar;
 mod collator;
+mod compactdecimal;
 mod core;
+mod currency;
 mod datetime;
 mod decimal;
 mod displaynames;
@@ -11,14 +13,30 @@ mod locid_transform;
 mod normalizer;
 mod plurals;
 mod props;
+mod rbnf;
+mod relativetime;
 mod segmenter;
 mod time_zone;
+mod units;
 /// This data provider was programmatically generated by [`icu_datagen`](
 /// https://unicode-org.github.io/icu4x-docs/doc/icu_datagen/enum.Out.html#variant.Module).
 #[non_exhaustive]
 pub struct BakedDataProvider;
 use ::icu_provider::prelude::*;
 #[cfg(feature = "icu_calendar")]
+impl DataProvider<::icu_calendar::provider::IslamicUmmAlQuraCacheV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_calendar::provider::IslamicUmmAlQuraCacheV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *calendar::islamicumalquracache_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_calendar::provider::IslamicUmmAlQuraCacheV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_calendar")]
 impl DataProvider<::icu_calendar::provider::JapaneseErasV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_calendar::provider::JapaneseErasV1Marker>, DataError> {
         Ok(DataResponse {
@@ -148,6 +166,75 @@ impl DataProvider<::icu_collator::provider::CollationSpecialPrimariesV1Marker> f
         })
     }
 }
+#[cfg(feature = "icu_compactdecimal")]
+impl DataProvider<::icu_compactdecimal::provider::LongCompactDecimalFormatDataV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_compactdecimal::provider::LongCompactDecimalFormatDataV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *compactdecimal::long_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| {
+                        DataErrorKind::MissingLocale.with_req(::icu_compactdecimal::provider::LongCompactDecimalFormatDataV1Marker::KEY, req)
+                    })?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_compactdecimal")]
+impl DataProvider<::icu_compactdecimal::provider::ShortCompactDecimalFormatDataV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_compactdecimal::provider::ShortCompactDecimalFormatDataV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *compactdecimal::short_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| {
+                        DataErrorKind::MissingLocale.with_req(::icu_compactdecimal::provider::ShortCompactDecimalFormatDataV1Marker::KEY, req)
+                    })?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_currency")]
+impl DataProvider<::icu_currency::provider::CurrencyEssentialsV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_currency::provider::CurrencyEssentialsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *currency::essentials_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_currency::provider::CurrencyEssentialsV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_currency")]
+impl DataProvider<::icu_currency::provider::CurrencyFractionsV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_currency::provider::CurrencyFractionsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *currency::fractions_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_currency::provider::CurrencyFractionsV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_currency")]
+impl DataProvider<::icu_currency::provider::CurrencyNamesV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_currency::provider::CurrencyNamesV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *currency::names_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_currency::provider::CurrencyNamesV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
 #[cfg(feature = "icu_datetime")]
 impl DataProvider<::icu_datetime::provider::calendar::BuddhistDateLengthsV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::calendar::BuddhistDateLengthsV1Marker>, DataError> {
@@ -179,6 +266,32 @@ impl DataProvider<::icu_datetime::provider::calendar::BuddhistDateSymbolsV1Marke
     }
 }
 #[cfg(feature = "icu_datetime")]
+impl DataProvider<::icu_datetime::provider::calendar::ChineseDateLengthsV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::calendar::ChineseDateLengthsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *datetime::chinese::datelengths_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_datetime::provider::calendar::ChineseDateLengthsV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_datetime")]
+impl DataProvider<::icu_datetime::provider::calendar::ChineseDateSymbolsV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::calendar::ChineseDateSymbolsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *datetime::chinese::datesymbols_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_datetime::provider::calendar::ChineseDateSymbolsV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_datetime")]
 impl DataProvider<::icu_datetime::provider::calendar::CopticDateLengthsV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::calendar::CopticDateLengthsV1Marker>, DataError> {
         Ok(DataResponse {
@@ -204,6 +317,32 @@ impl DataProvider<::icu_datetime::provider::calendar::CopticDateSymbolsV1Marker>
         })
     }
 }
+#[cfg(feature = "icu_datetime")]
+impl DataProvider<::icu_datetime::provider::calendar::DangiDateLengthsV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::calendar::DangiDateLengthsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *datetime::dangi::datelengths_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_datetime::provider::calendar::DangiDateLengthsV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_datetime")]
+impl DataProvider<::icu_datetime::provider::calendar::DangiDateSymbolsV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::calendar::DangiDateSymbolsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *datetime::dangi::datesymbols_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_datetime::provider::calendar::DangiDateSymbolsV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
 #[cfg(feature = "icu_datetime_experimental")]
 impl DataProvider<::icu_datetime::provider::calendar::DateSkeletonPatternsV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::calendar::DateSkeletonPatternsV1Marker>, DataError> {
@@ -280,6 +419,32 @@ impl DataProvider<::icu_datetime::provider::calendar::GregorianDateSymbolsV1Mark
     }
 }
 #[cfg(feature = "icu_datetime")]
+impl DataProvider<::icu_datetime::provider::calendar::HebrewDateLengthsV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::calendar::HebrewDateLengthsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *datetime::hebrew::datelengths_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_datetime::provider::calendar::HebrewDateLengthsV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_datetime")]
+impl DataProvider<::icu_datetime::provider::calendar::HebrewDateSymbolsV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::calendar::HebrewDateSymbolsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *datetime::hebrew::datesymbols_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_datetime::provider::calendar::HebrewDateSymbolsV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_datetime")]
 impl DataProvider<::icu_datetime::provider::calendar::IndianDateLengthsV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::calendar::IndianDateLengthsV1Marker>, DataError> {
         Ok(DataResponse {
@@ -306,6 +471,32 @@ impl DataProvider<::icu_datetime::provider::calendar::IndianDateSymbolsV1Marker>
     }
 }
 #[cfg(feature = "icu_datetime")]
+impl DataProvider<::icu_datetime::provider::calendar::IslamicDateLengthsV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::calendar::IslamicDateLengthsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *datetime::islamic::datelengths_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_datetime::provider::calendar::IslamicDateLengthsV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_datetime")]
+impl DataProvider<::icu_datetime::provider::calendar::IslamicDateSymbolsV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::calendar::IslamicDateSymbolsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *datetime::islamic::datesymbols_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_datetime::provider::calendar::IslamicDateSymbolsV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_datetime")]
 impl DataProvider<::icu_datetime::provider::calendar::JapaneseDateLengthsV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::calendar::JapaneseDateLengthsV1Marker>, DataError> {
         Ok(DataResponse {
@@ -366,6 +557,58 @@ impl DataProvider<::icu_datetime::provider::calendar::JapaneseExtendedDateSymbol
     }
 }
 #[cfg(feature = "icu_datetime")]
+impl DataProvider<::icu_datetime::provider::calendar::PersianDateLengthsV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::calendar::PersianDateLengthsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *datetime::persian::datelengths_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_datetime::provider::calendar::PersianDateLengthsV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_datetime")]
+impl DataProvider<::icu_datetime::provider::calendar::PersianDateSymbolsV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::calendar::PersianDateSymbolsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *datetime::persian::datesymbols_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_datetime::provider::calendar::PersianDateSymbolsV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_datetime")]
+impl DataProvider<::icu_datetime::provider::calendar::RocDateLengthsV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::calendar::RocDateLengthsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *datetime::roc::datelengths_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_datetime::provider::calendar::RocDateLengthsV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_datetime")]
+impl DataProvider<::icu_datetime::provider::calendar::RocDateSymbolsV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::calendar::RocDateSymbolsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *datetime::roc::datesymbols_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_datetime::provider::calendar::RocDateSymbolsV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_datetime")]
 impl DataProvider<::icu_datetime::provider::calendar::TimeLengthsV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::calendar::TimeLengthsV1Marker>, DataError> {
         Ok(DataResponse {
@@ -478,6 +721,19 @@ impl DataProvider<::icu_datetime::provider::time_zones::TimeZoneFormatsV1Marker>
     }
 }
 #[cfg(feature = "icu_decimal")]
+impl DataProvider<::icu_decimal::provider::AlgorithmicSymbolsV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_decimal::provider::AlgorithmicSymbolsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *decimal::algorithmic_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_decimal::provider::AlgorithmicSymbolsV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_decimal")]
 impl DataProvider<::icu_decimal::provider::DecimalSymbolsV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_decimal::provider::DecimalSymbolsV1Marker>, DataError> {
         Ok(DataResponse {
@@ -490,6 +746,110 @@ impl DataProvider<::icu_decimal::provider::DecimalSymbolsV1Marker> for BakedData
         })
     }
 }
+#[cfg(feature = "icu_decimal")]
+impl DataProvider<::icu_decimal::provider::NotationSymbolsV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_decimal::provider::NotationSymbolsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *decimal::notation_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_decimal::provider::NotationSymbolsV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_displaynames")]
+impl DataProvider<::icu_displaynames::provider::CalendarDisplayNamesV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_displaynames::provider::CalendarDisplayNamesV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *displaynames::calendars_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_displaynames::provider::CalendarDisplayNamesV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_displaynames")]
+impl DataProvider<::icu_displaynames::provider::CurrencyDisplayNamesV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_displaynames::provider::CurrencyDisplayNamesV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *displaynames::currencies_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_displaynames::provider::CurrencyDisplayNamesV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_displaynames")]
+impl DataProvider<::icu_displaynames::provider::KeyDisplayNamesV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_displaynames::provider::KeyDisplayNamesV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *displaynames::keys_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_displaynames::provider::KeyDisplayNamesV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_displaynames")]
+impl DataProvider<::icu_displaynames::provider::LanguageDisplayNamesV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_displaynames::provider::LanguageDisplayNamesV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *displaynames::languages_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_displaynames::provider::LanguageDisplayNamesV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_displaynames")]
+impl DataProvider<::icu_displaynames::provider::LocaleDisplayNamesV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_displaynames::provider::LocaleDisplayNamesV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *displaynames::locales_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_displaynames::provider::LocaleDisplayNamesV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_displaynames")]
+impl DataProvider<::icu_displaynames::provider::LocaleDisplayPatternsV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_displaynames::provider::LocaleDisplayPatternsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *displaynames::patterns_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_displaynames::provider::LocaleDisplayPatternsV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_displaynames")]
+impl DataProvider<::icu_displaynames::provider::ScriptDisplayNamesV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_displaynames::provider::ScriptDisplayNamesV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *displaynames::scripts_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_displaynames::provider::ScriptDisplayNamesV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
 #[cfg(feature = "icu_displaynames")]
 impl DataProvider<::icu_displaynames::provider::TerritoryDisplayNamesV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_displaynames::provider::TerritoryDisplayNamesV1Marker>, DataError> {
@@ -503,6 +863,32 @@ impl DataProvider<::icu_displaynames::provider::TerritoryDisplayNamesV1Marker> f
         })
     }
 }
+#[cfg(feature = "icu_displaynames")]
+impl DataProvider<::icu_displaynames::provider::UnitDisplayNamesV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_displaynames::provider::UnitDisplayNamesV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *displaynames::units_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_displaynames::provider::UnitDisplayNamesV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_displaynames")]
+impl DataProvider<::icu_displaynames::provider::VariantDisplayNamesV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_displaynames::provider::VariantDisplayNamesV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *displaynames::variants_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_displaynames::provider::VariantDisplayNamesV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
 #[cfg(feature = "icu_list")]
 impl DataProvider<::icu_list::provider::AndListV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_list::provider::AndListV1Marker>, DataError> {
@@ -556,6 +942,19 @@ impl DataProvider<::icu_locid_transform::provider::AliasesV1Marker> for BakedDat
     }
 }
 #[cfg(feature = "icu_locid_transform")]
+impl DataProvider<::icu_locid_transform::provider::LanguageMatchingV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_locid_transform::provider::LanguageMatchingV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *locid_transform::languagematching_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_locid_transform::provider::LanguageMatchingV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_locid_transform")]
 impl DataProvider<::icu_locid_transform::provider::LikelySubtagsV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_locid_transform::provider::LikelySubtagsV1Marker>, DataError> {
         Ok(DataResponse {
@@ -639,6 +1038,54 @@ impl DataProvider<::icu_normalizer::provider::CompatibilityDecompositionTablesV1
         })
     }
 }
+#[cfg(feature = "icu_normalizer_experimental")]
+impl DataProvider<::icu_normalizer::provider::NfkcCasefoldDecompositionSupplementV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_normalizer::provider::NfkcCasefoldDecompositionSupplementV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *normalizer::nfkccfd_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| {
+                        DataErrorKind::MissingLocale.with_req(::icu_normalizer::provider::NfkcCasefoldDecompositionSupplementV1Marker::KEY, req)
+                    })?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_normalizer_experimental")]
+impl DataProvider<::icu_normalizer::provider::NfkcCasefoldDecompositionTablesV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_normalizer::provider::NfkcCasefoldDecompositionTablesV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *normalizer::nfkccfex_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| {
+                        DataErrorKind::MissingLocale.with_req(::icu_normalizer::provider::NfkcCasefoldDecompositionTablesV1Marker::KEY, req)
+                    })?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_normalizer_experimental")]
+impl DataProvider<::icu_normalizer::provider::NfkcSimpleCasefoldDecompositionSupplementV1Marker> for BakedDataProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<::icu_normalizer::provider::NfkcSimpleCasefoldDecompositionSupplementV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *normalizer::nfkcscfd_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| {
+                        DataErrorKind::MissingLocale.with_req(::icu_normalizer::provider::NfkcSimpleCasefoldDecompositionSupplementV1Marker::KEY, req)
+                    })?,
+            ))),
+        })
+    }
+}
 #[cfg(feature = "icu_normalizer")]
 impl DataProvider<::icu_normalizer::provider::NonRecursiveDecompositionSupplementV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_normalizer::provider::NonRecursiveDecompositionSupplementV1Marker>, DataError> {
@@ -1526,6 +1973,257 @@ impl DataProvider<::icu_provider_adapters::fallback::provider::LocaleFallbackPar
         })
     }
 }
+#[cfg(feature = "icu_rbnf")]
+impl DataProvider<::icu_rbnf::provider::OrdinalRulesV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_rbnf::provider::OrdinalRulesV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *rbnf::ordinal_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_rbnf::provider::OrdinalRulesV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_rbnf")]
+impl DataProvider<::icu_rbnf::provider::SpelloutRulesV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_rbnf::provider::SpelloutRulesV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *rbnf::spellout_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_rbnf::provider::SpelloutRulesV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_relativetime")]
+impl DataProvider<::icu_relativetime::provider::DayRelativeTimeFormatDataV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_relativetime::provider::DayRelativeTimeFormatDataV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *relativetime::day_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| {
+                        DataErrorKind::MissingLocale.with_req(::icu_relativetime::provider::DayRelativeTimeFormatDataV1Marker::KEY, req)
+                    })?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_relativetime")]
+impl DataProvider<::icu_relativetime::provider::FridayRelativeTimeFormatDataV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_relativetime::provider::FridayRelativeTimeFormatDataV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *relativetime::fri_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| {
+                        DataErrorKind::MissingLocale.with_req(::icu_relativetime::provider::FridayRelativeTimeFormatDataV1Marker::KEY, req)
+                    })?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_relativetime")]
+impl DataProvider<::icu_relativetime::provider::HourRelativeTimeFormatDataV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_relativetime::provider::HourRelativeTimeFormatDataV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *relativetime::hour_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| {
+                        DataErrorKind::MissingLocale.with_req(::icu_relativetime::provider::HourRelativeTimeFormatDataV1Marker::KEY, req)
+                    })?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_relativetime")]
+impl DataProvider<::icu_relativetime::provider::MinuteRelativeTimeFormatDataV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_relativetime::provider::MinuteRelativeTimeFormatDataV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *relativetime::minute_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| {
+                        DataErrorKind::MissingLocale.with_req(::icu_relativetime::provider::MinuteRelativeTimeFormatDataV1Marker::KEY, req)
+                    })?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_relativetime")]
+impl DataProvider<::icu_relativetime::provider::MondayRelativeTimeFormatDataV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_relativetime::provider::MondayRelativeTimeFormatDataV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *relativetime::mon_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| {
+                        DataErrorKind::MissingLocale.with_req(::icu_relativetime::provider::MondayRelativeTimeFormatDataV1Marker::KEY, req)
+                    })?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_relativetime")]
+impl DataProvider<::icu_relativetime::provider::MonthRelativeTimeFormatDataV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_relativetime::provider::MonthRelativeTimeFormatDataV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *relativetime::month_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| {
+                        DataErrorKind::MissingLocale.with_req(::icu_relativetime::provider::MonthRelativeTimeFormatDataV1Marker::KEY, req)
+                    })?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_relativetime")]
+impl DataProvider<::icu_relativetime::provider::QuarterRelativeTimeFormatDataV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_relativetime::provider::QuarterRelativeTimeFormatDataV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *relativetime::quarter_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| {
+                        DataErrorKind::MissingLocale.with_req(::icu_relativetime::provider::QuarterRelativeTimeFormatDataV1Marker::KEY, req)
+                    })?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_relativetime")]
+impl DataProvider<::icu_relativetime::provider::SaturdayRelativeTimeFormatDataV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_relativetime::provider::SaturdayRelativeTimeFormatDataV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *relativetime::sat_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| {
+                        DataErrorKind::MissingLocale.with_req(::icu_relativetime::provider::SaturdayRelativeTimeFormatDataV1Marker::KEY, req)
+                    })?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_relativetime")]
+impl DataProvider<::icu_relativetime::provider::SecondRelativeTimeFormatDataV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_relativetime::provider::SecondRelativeTimeFormatDataV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *relativetime::second_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| {
+                        DataErrorKind::MissingLocale.with_req(::icu_relativetime::provider::SecondRelativeTimeFormatDataV1Marker::KEY, req)
+                    })?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_relativetime")]
+impl DataProvider<::icu_relativetime::provider::SundayRelativeTimeFormatDataV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_relativetime::provider::SundayRelativeTimeFormatDataV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *relativetime::sun_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| {
+                        DataErrorKind::MissingLocale.with_req(::icu_relativetime::provider::SundayRelativeTimeFormatDataV1Marker::KEY, req)
+                    })?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_relativetime")]
+impl DataProvider<::icu_relativetime::provider::ThursdayRelativeTimeFormatDataV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_relativetime::provider::ThursdayRelativeTimeFormatDataV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *relativetime::thu_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| {
+                        DataErrorKind::MissingLocale.with_req(::icu_relativetime::provider::ThursdayRelativeTimeFormatDataV1Marker::KEY, req)
+                    })?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_relativetime")]
+impl DataProvider<::icu_relativetime::provider::TuesdayRelativeTimeFormatDataV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_relativetime::provider::TuesdayRelativeTimeFormatDataV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *relativetime::tue_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| {
+                        DataErrorKind::MissingLocale.with_req(::icu_relativetime::provider::TuesdayRelativeTimeFormatDataV1Marker::KEY, req)
+                    })?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_relativetime")]
+impl DataProvider<::icu_relativetime::provider::WednesdayRelativeTimeFormatDataV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_relativetime::provider::WednesdayRelativeTimeFormatDataV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *relativetime::wed_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| {
+                        DataErrorKind::MissingLocale.with_req(::icu_relativetime::provider::WednesdayRelativeTimeFormatDataV1Marker::KEY, req)
+                    })?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_relativetime")]
+impl DataProvider<::icu_relativetime::provider::WeekRelativeTimeFormatDataV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_relativetime::provider::WeekRelativeTimeFormatDataV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *relativetime::week_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| {
+                        DataErrorKind::MissingLocale.with_req(::icu_relativetime::provider::WeekRelativeTimeFormatDataV1Marker::KEY, req)
+                    })?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_relativetime")]
+impl DataProvider<::icu_relativetime::provider::YearRelativeTimeFormatDataV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_relativetime::provider::YearRelativeTimeFormatDataV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *relativetime::year_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| {
+                        DataErrorKind::MissingLocale.with_req(::icu_relativetime::provider::YearRelativeTimeFormatDataV1Marker::KEY, req)
+                    })?,
+            ))),
+        })
+    }
+}
 #[cfg(feature = "icu_segmenter")]
 impl DataProvider<::icu_segmenter::provider::GraphemeClusterBreakDataV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_segmenter::provider::GraphemeClusterBreakDataV1Marker>, DataError> {
@@ -1617,3 +2315,55 @@ impl DataProvider<::icu_timezone::provider::MetazonePeriodV1Marker> for BakedDat
         })
     }
 }
+#[cfg(feature = "icu_units")]
+impl DataProvider<::icu_units::provider::LongUnitsFormatDataV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_units::provider::LongUnitsFormatDataV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *units::long_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_units::provider::LongUnitsFormatDataV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_units")]
+impl DataProvider<::icu_units::provider::NarrowUnitsFormatDataV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_units::provider::NarrowUnitsFormatDataV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *units::narrow_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_units::provider::NarrowUnitsFormatDataV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_units")]
+impl DataProvider<::icu_units::provider::ShortUnitsFormatDataV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_units::provider::ShortUnitsFormatDataV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *units::short_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_units::provider::ShortUnitsFormatDataV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_units")]
+impl DataProvider<::icu_units::provider::UnitsInfoV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_units::provider::UnitsInfoV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *units::info_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_units::provider::UnitsInfoV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
@@ -3,6 +3,9 @@ pub mod comp_v1;
 pub mod decomp_v1;
 pub mod nfd_v1;
 pub mod nfdex_v1;
+pub mod nfkccfd_v1;
+pub mod nfkccfex_v1;
+pub mod nfkcscfd_v1;
 pub mod nfkd_v1;
 pub mod nfkdex_v1;
 pub mod uts46d_v1;
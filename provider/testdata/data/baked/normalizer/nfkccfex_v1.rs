@@ -0,0 +1,390 @@
+// @generated
+#![cfg(feature = "icu_normalizer_experimental")]
+type DataStruct = < :: icu_normalizer :: provider :: NfkcCasefoldDecompositionTablesV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_normalizer::provider::DecompositionTablesV1 {
+    scalars16: unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+            49u8, 0u8, 68u8, 32u8, 52u8, 0u8, 49u8, 0u8, 68u8, 32u8, 50u8, 0u8, 51u8, 0u8, 68u8,
+            32u8, 52u8, 0u8, 115u8, 0u8, 115u8, 0u8, 105u8, 0u8, 106u8, 0u8, 108u8, 0u8, 183u8,
+            0u8, 188u8, 2u8, 110u8, 0u8, 100u8, 0u8, 122u8, 0u8, 12u8, 3u8, 108u8, 0u8, 106u8, 0u8,
+            110u8, 0u8, 106u8, 0u8, 32u8, 0u8, 185u8, 3u8, 32u8, 0u8, 8u8, 3u8, 1u8, 3u8, 101u8,
+            5u8, 130u8, 5u8, 39u8, 6u8, 116u8, 6u8, 72u8, 6u8, 116u8, 6u8, 199u8, 6u8, 116u8, 6u8,
+            74u8, 6u8, 116u8, 6u8, 77u8, 14u8, 50u8, 14u8, 205u8, 14u8, 178u8, 14u8, 171u8, 14u8,
+            153u8, 14u8, 171u8, 14u8, 161u8, 14u8, 178u8, 15u8, 113u8, 15u8, 128u8, 15u8, 179u8,
+            15u8, 113u8, 15u8, 128u8, 15u8, 97u8, 0u8, 190u8, 2u8, 177u8, 3u8, 19u8, 3u8, 185u8,
+            3u8, 177u8, 3u8, 20u8, 3u8, 185u8, 3u8, 177u8, 3u8, 19u8, 3u8, 0u8, 3u8, 185u8, 3u8,
+            177u8, 3u8, 20u8, 3u8, 0u8, 3u8, 185u8, 3u8, 177u8, 3u8, 19u8, 3u8, 1u8, 3u8, 185u8,
+            3u8, 177u8, 3u8, 20u8, 3u8, 1u8, 3u8, 185u8, 3u8, 177u8, 3u8, 19u8, 3u8, 66u8, 3u8,
+            185u8, 3u8, 177u8, 3u8, 20u8, 3u8, 66u8, 3u8, 185u8, 3u8, 183u8, 3u8, 19u8, 3u8, 185u8,
+            3u8, 183u8, 3u8, 20u8, 3u8, 185u8, 3u8, 183u8, 3u8, 19u8, 3u8, 0u8, 3u8, 185u8, 3u8,
+            183u8, 3u8, 20u8, 3u8, 0u8, 3u8, 185u8, 3u8, 183u8, 3u8, 19u8, 3u8, 1u8, 3u8, 185u8,
+            3u8, 183u8, 3u8, 20u8, 3u8, 1u8, 3u8, 185u8, 3u8, 183u8, 3u8, 19u8, 3u8, 66u8, 3u8,
+            185u8, 3u8, 183u8, 3u8, 20u8, 3u8, 66u8, 3u8, 185u8, 3u8, 201u8, 3u8, 19u8, 3u8, 185u8,
+            3u8, 201u8, 3u8, 20u8, 3u8, 185u8, 3u8, 201u8, 3u8, 19u8, 3u8, 0u8, 3u8, 185u8, 3u8,
+            201u8, 3u8, 20u8, 3u8, 0u8, 3u8, 185u8, 3u8, 201u8, 3u8, 19u8, 3u8, 1u8, 3u8, 185u8,
+            3u8, 201u8, 3u8, 20u8, 3u8, 1u8, 3u8, 185u8, 3u8, 201u8, 3u8, 19u8, 3u8, 66u8, 3u8,
+            185u8, 3u8, 201u8, 3u8, 20u8, 3u8, 66u8, 3u8, 185u8, 3u8, 177u8, 3u8, 0u8, 3u8, 185u8,
+            3u8, 177u8, 3u8, 185u8, 3u8, 177u8, 3u8, 1u8, 3u8, 185u8, 3u8, 177u8, 3u8, 66u8, 3u8,
+            185u8, 3u8, 32u8, 0u8, 8u8, 3u8, 66u8, 3u8, 183u8, 3u8, 0u8, 3u8, 185u8, 3u8, 183u8,
+            3u8, 185u8, 3u8, 183u8, 3u8, 1u8, 3u8, 185u8, 3u8, 183u8, 3u8, 66u8, 3u8, 185u8, 3u8,
+            32u8, 0u8, 19u8, 3u8, 0u8, 3u8, 32u8, 0u8, 19u8, 3u8, 1u8, 3u8, 32u8, 0u8, 19u8, 3u8,
+            66u8, 3u8, 32u8, 0u8, 20u8, 3u8, 0u8, 3u8, 32u8, 0u8, 20u8, 3u8, 1u8, 3u8, 32u8, 0u8,
+            20u8, 3u8, 66u8, 3u8, 32u8, 0u8, 8u8, 3u8, 0u8, 3u8, 201u8, 3u8, 0u8, 3u8, 185u8, 3u8,
+            201u8, 3u8, 185u8, 3u8, 201u8, 3u8, 1u8, 3u8, 185u8, 3u8, 201u8, 3u8, 66u8, 3u8, 185u8,
+            3u8, 46u8, 0u8, 46u8, 0u8, 46u8, 0u8, 46u8, 0u8, 46u8, 0u8, 50u8, 32u8, 50u8, 32u8,
+            50u8, 32u8, 50u8, 32u8, 50u8, 32u8, 53u8, 32u8, 53u8, 32u8, 53u8, 32u8, 53u8, 32u8,
+            53u8, 32u8, 33u8, 0u8, 33u8, 0u8, 63u8, 0u8, 63u8, 0u8, 63u8, 0u8, 33u8, 0u8, 114u8,
+            0u8, 115u8, 0u8, 97u8, 0u8, 47u8, 0u8, 99u8, 0u8, 97u8, 0u8, 47u8, 0u8, 115u8, 0u8,
+            176u8, 0u8, 99u8, 0u8, 99u8, 0u8, 47u8, 0u8, 111u8, 0u8, 99u8, 0u8, 47u8, 0u8, 117u8,
+            0u8, 176u8, 0u8, 102u8, 0u8, 110u8, 0u8, 111u8, 0u8, 115u8, 0u8, 109u8, 0u8, 116u8,
+            0u8, 101u8, 0u8, 108u8, 0u8, 116u8, 0u8, 109u8, 0u8, 102u8, 0u8, 97u8, 0u8, 120u8, 0u8,
+            49u8, 0u8, 68u8, 32u8, 55u8, 0u8, 49u8, 0u8, 68u8, 32u8, 57u8, 0u8, 49u8, 0u8, 68u8,
+            32u8, 49u8, 0u8, 48u8, 0u8, 49u8, 0u8, 68u8, 32u8, 51u8, 0u8, 50u8, 0u8, 68u8, 32u8,
+            51u8, 0u8, 49u8, 0u8, 68u8, 32u8, 53u8, 0u8, 50u8, 0u8, 68u8, 32u8, 53u8, 0u8, 51u8,
+            0u8, 68u8, 32u8, 53u8, 0u8, 52u8, 0u8, 68u8, 32u8, 53u8, 0u8, 49u8, 0u8, 68u8, 32u8,
+            54u8, 0u8, 53u8, 0u8, 68u8, 32u8, 54u8, 0u8, 49u8, 0u8, 68u8, 32u8, 56u8, 0u8, 51u8,
+            0u8, 68u8, 32u8, 56u8, 0u8, 53u8, 0u8, 68u8, 32u8, 56u8, 0u8, 55u8, 0u8, 68u8, 32u8,
+            56u8, 0u8, 105u8, 0u8, 105u8, 0u8, 105u8, 0u8, 105u8, 0u8, 105u8, 0u8, 105u8, 0u8,
+            118u8, 0u8, 118u8, 0u8, 105u8, 0u8, 118u8, 0u8, 105u8, 0u8, 105u8, 0u8, 118u8, 0u8,
+            105u8, 0u8, 105u8, 0u8, 105u8, 0u8, 105u8, 0u8, 120u8, 0u8, 120u8, 0u8, 105u8, 0u8,
+            120u8, 0u8, 105u8, 0u8, 105u8, 0u8, 48u8, 0u8, 68u8, 32u8, 51u8, 0u8, 43u8, 34u8, 43u8,
+            34u8, 43u8, 34u8, 43u8, 34u8, 43u8, 34u8, 46u8, 34u8, 46u8, 34u8, 46u8, 34u8, 46u8,
+            34u8, 46u8, 34u8, 49u8, 0u8, 49u8, 0u8, 49u8, 0u8, 50u8, 0u8, 49u8, 0u8, 51u8, 0u8,
+            49u8, 0u8, 52u8, 0u8, 49u8, 0u8, 53u8, 0u8, 49u8, 0u8, 54u8, 0u8, 49u8, 0u8, 55u8, 0u8,
+            49u8, 0u8, 56u8, 0u8, 49u8, 0u8, 57u8, 0u8, 50u8, 0u8, 48u8, 0u8, 40u8, 0u8, 49u8, 0u8,
+            41u8, 0u8, 40u8, 0u8, 50u8, 0u8, 41u8, 0u8, 40u8, 0u8, 51u8, 0u8, 41u8, 0u8, 40u8, 0u8,
+            52u8, 0u8, 41u8, 0u8, 40u8, 0u8, 53u8, 0u8, 41u8, 0u8, 40u8, 0u8, 54u8, 0u8, 41u8, 0u8,
+            40u8, 0u8, 55u8, 0u8, 41u8, 0u8, 40u8, 0u8, 56u8, 0u8, 41u8, 0u8, 40u8, 0u8, 57u8, 0u8,
+            41u8, 0u8, 40u8, 0u8, 49u8, 0u8, 48u8, 0u8, 41u8, 0u8, 40u8, 0u8, 49u8, 0u8, 49u8, 0u8,
+            41u8, 0u8, 40u8, 0u8, 49u8, 0u8, 50u8, 0u8, 41u8, 0u8, 40u8, 0u8, 49u8, 0u8, 51u8, 0u8,
+            41u8, 0u8, 40u8, 0u8, 49u8, 0u8, 52u8, 0u8, 41u8, 0u8, 40u8, 0u8, 49u8, 0u8, 53u8, 0u8,
+            41u8, 0u8, 40u8, 0u8, 49u8, 0u8, 54u8, 0u8, 41u8, 0u8, 40u8, 0u8, 49u8, 0u8, 55u8, 0u8,
+            41u8, 0u8, 40u8, 0u8, 49u8, 0u8, 56u8, 0u8, 41u8, 0u8, 40u8, 0u8, 49u8, 0u8, 57u8, 0u8,
+            41u8, 0u8, 40u8, 0u8, 50u8, 0u8, 48u8, 0u8, 41u8, 0u8, 49u8, 0u8, 46u8, 0u8, 50u8, 0u8,
+            46u8, 0u8, 51u8, 0u8, 46u8, 0u8, 52u8, 0u8, 46u8, 0u8, 53u8, 0u8, 46u8, 0u8, 54u8, 0u8,
+            46u8, 0u8, 55u8, 0u8, 46u8, 0u8, 56u8, 0u8, 46u8, 0u8, 57u8, 0u8, 46u8, 0u8, 49u8, 0u8,
+            48u8, 0u8, 46u8, 0u8, 49u8, 0u8, 49u8, 0u8, 46u8, 0u8, 49u8, 0u8, 50u8, 0u8, 46u8, 0u8,
+            49u8, 0u8, 51u8, 0u8, 46u8, 0u8, 49u8, 0u8, 52u8, 0u8, 46u8, 0u8, 49u8, 0u8, 53u8, 0u8,
+            46u8, 0u8, 49u8, 0u8, 54u8, 0u8, 46u8, 0u8, 49u8, 0u8, 55u8, 0u8, 46u8, 0u8, 49u8, 0u8,
+            56u8, 0u8, 46u8, 0u8, 49u8, 0u8, 57u8, 0u8, 46u8, 0u8, 50u8, 0u8, 48u8, 0u8, 46u8, 0u8,
+            40u8, 0u8, 97u8, 0u8, 41u8, 0u8, 40u8, 0u8, 98u8, 0u8, 41u8, 0u8, 40u8, 0u8, 99u8, 0u8,
+            41u8, 0u8, 40u8, 0u8, 100u8, 0u8, 41u8, 0u8, 40u8, 0u8, 101u8, 0u8, 41u8, 0u8, 40u8,
+            0u8, 102u8, 0u8, 41u8, 0u8, 40u8, 0u8, 103u8, 0u8, 41u8, 0u8, 40u8, 0u8, 104u8, 0u8,
+            41u8, 0u8, 40u8, 0u8, 105u8, 0u8, 41u8, 0u8, 40u8, 0u8, 106u8, 0u8, 41u8, 0u8, 40u8,
+            0u8, 107u8, 0u8, 41u8, 0u8, 40u8, 0u8, 108u8, 0u8, 41u8, 0u8, 40u8, 0u8, 109u8, 0u8,
+            41u8, 0u8, 40u8, 0u8, 110u8, 0u8, 41u8, 0u8, 40u8, 0u8, 111u8, 0u8, 41u8, 0u8, 40u8,
+            0u8, 112u8, 0u8, 41u8, 0u8, 40u8, 0u8, 113u8, 0u8, 41u8, 0u8, 40u8, 0u8, 114u8, 0u8,
+            41u8, 0u8, 40u8, 0u8, 115u8, 0u8, 41u8, 0u8, 40u8, 0u8, 116u8, 0u8, 41u8, 0u8, 40u8,
+            0u8, 117u8, 0u8, 41u8, 0u8, 40u8, 0u8, 118u8, 0u8, 41u8, 0u8, 40u8, 0u8, 119u8, 0u8,
+            41u8, 0u8, 40u8, 0u8, 120u8, 0u8, 41u8, 0u8, 40u8, 0u8, 121u8, 0u8, 41u8, 0u8, 40u8,
+            0u8, 122u8, 0u8, 41u8, 0u8, 58u8, 0u8, 58u8, 0u8, 61u8, 0u8, 61u8, 0u8, 61u8, 0u8,
+            136u8, 48u8, 138u8, 48u8, 179u8, 48u8, 200u8, 48u8, 40u8, 0u8, 0u8, 17u8, 41u8, 0u8,
+            40u8, 0u8, 2u8, 17u8, 41u8, 0u8, 40u8, 0u8, 3u8, 17u8, 41u8, 0u8, 40u8, 0u8, 5u8, 17u8,
+            41u8, 0u8, 40u8, 0u8, 6u8, 17u8, 41u8, 0u8, 40u8, 0u8, 7u8, 17u8, 41u8, 0u8, 40u8, 0u8,
+            9u8, 17u8, 41u8, 0u8, 40u8, 0u8, 11u8, 17u8, 41u8, 0u8, 40u8, 0u8, 12u8, 17u8, 41u8,
+            0u8, 40u8, 0u8, 14u8, 17u8, 41u8, 0u8, 40u8, 0u8, 15u8, 17u8, 41u8, 0u8, 40u8, 0u8,
+            16u8, 17u8, 41u8, 0u8, 40u8, 0u8, 17u8, 17u8, 41u8, 0u8, 40u8, 0u8, 18u8, 17u8, 41u8,
+            0u8, 40u8, 0u8, 0u8, 17u8, 97u8, 17u8, 41u8, 0u8, 40u8, 0u8, 2u8, 17u8, 97u8, 17u8,
+            41u8, 0u8, 40u8, 0u8, 3u8, 17u8, 97u8, 17u8, 41u8, 0u8, 40u8, 0u8, 5u8, 17u8, 97u8,
+            17u8, 41u8, 0u8, 40u8, 0u8, 6u8, 17u8, 97u8, 17u8, 41u8, 0u8, 40u8, 0u8, 7u8, 17u8,
+            97u8, 17u8, 41u8, 0u8, 40u8, 0u8, 9u8, 17u8, 97u8, 17u8, 41u8, 0u8, 40u8, 0u8, 11u8,
+            17u8, 97u8, 17u8, 41u8, 0u8, 40u8, 0u8, 12u8, 17u8, 97u8, 17u8, 41u8, 0u8, 40u8, 0u8,
+            14u8, 17u8, 97u8, 17u8, 41u8, 0u8, 40u8, 0u8, 15u8, 17u8, 97u8, 17u8, 41u8, 0u8, 40u8,
+            0u8, 16u8, 17u8, 97u8, 17u8, 41u8, 0u8, 40u8, 0u8, 17u8, 17u8, 97u8, 17u8, 41u8, 0u8,
+            40u8, 0u8, 18u8, 17u8, 97u8, 17u8, 41u8, 0u8, 40u8, 0u8, 12u8, 17u8, 110u8, 17u8, 41u8,
+            0u8, 40u8, 0u8, 11u8, 17u8, 105u8, 17u8, 12u8, 17u8, 101u8, 17u8, 171u8, 17u8, 41u8,
+            0u8, 40u8, 0u8, 11u8, 17u8, 105u8, 17u8, 18u8, 17u8, 110u8, 17u8, 41u8, 0u8, 40u8, 0u8,
+            0u8, 78u8, 41u8, 0u8, 40u8, 0u8, 140u8, 78u8, 41u8, 0u8, 40u8, 0u8, 9u8, 78u8, 41u8,
+            0u8, 40u8, 0u8, 219u8, 86u8, 41u8, 0u8, 40u8, 0u8, 148u8, 78u8, 41u8, 0u8, 40u8, 0u8,
+            109u8, 81u8, 41u8, 0u8, 40u8, 0u8, 3u8, 78u8, 41u8, 0u8, 40u8, 0u8, 107u8, 81u8, 41u8,
+            0u8, 40u8, 0u8, 93u8, 78u8, 41u8, 0u8, 40u8, 0u8, 65u8, 83u8, 41u8, 0u8, 40u8, 0u8,
+            8u8, 103u8, 41u8, 0u8, 40u8, 0u8, 107u8, 112u8, 41u8, 0u8, 40u8, 0u8, 52u8, 108u8,
+            41u8, 0u8, 40u8, 0u8, 40u8, 103u8, 41u8, 0u8, 40u8, 0u8, 209u8, 145u8, 41u8, 0u8, 40u8,
+            0u8, 31u8, 87u8, 41u8, 0u8, 40u8, 0u8, 229u8, 101u8, 41u8, 0u8, 40u8, 0u8, 42u8, 104u8,
+            41u8, 0u8, 40u8, 0u8, 9u8, 103u8, 41u8, 0u8, 40u8, 0u8, 62u8, 121u8, 41u8, 0u8, 40u8,
+            0u8, 13u8, 84u8, 41u8, 0u8, 40u8, 0u8, 121u8, 114u8, 41u8, 0u8, 40u8, 0u8, 161u8,
+            140u8, 41u8, 0u8, 40u8, 0u8, 93u8, 121u8, 41u8, 0u8, 40u8, 0u8, 180u8, 82u8, 41u8, 0u8,
+            40u8, 0u8, 227u8, 78u8, 41u8, 0u8, 40u8, 0u8, 124u8, 84u8, 41u8, 0u8, 40u8, 0u8, 102u8,
+            91u8, 41u8, 0u8, 40u8, 0u8, 227u8, 118u8, 41u8, 0u8, 40u8, 0u8, 1u8, 79u8, 41u8, 0u8,
+            40u8, 0u8, 199u8, 140u8, 41u8, 0u8, 40u8, 0u8, 84u8, 83u8, 41u8, 0u8, 40u8, 0u8, 109u8,
+            121u8, 41u8, 0u8, 40u8, 0u8, 17u8, 79u8, 41u8, 0u8, 40u8, 0u8, 234u8, 129u8, 41u8, 0u8,
+            40u8, 0u8, 243u8, 129u8, 41u8, 0u8, 112u8, 0u8, 116u8, 0u8, 101u8, 0u8, 50u8, 0u8,
+            50u8, 0u8, 50u8, 0u8, 52u8, 0u8, 50u8, 0u8, 53u8, 0u8, 50u8, 0u8, 54u8, 0u8, 50u8, 0u8,
+            55u8, 0u8, 50u8, 0u8, 56u8, 0u8, 50u8, 0u8, 57u8, 0u8, 51u8, 0u8, 48u8, 0u8, 51u8, 0u8,
+            51u8, 0u8, 51u8, 0u8, 52u8, 0u8, 51u8, 0u8, 53u8, 0u8, 14u8, 17u8, 97u8, 17u8, 183u8,
+            17u8, 0u8, 17u8, 105u8, 17u8, 12u8, 17u8, 110u8, 17u8, 11u8, 17u8, 116u8, 17u8, 11u8,
+            17u8, 110u8, 17u8, 51u8, 0u8, 54u8, 0u8, 51u8, 0u8, 55u8, 0u8, 51u8, 0u8, 56u8, 0u8,
+            51u8, 0u8, 57u8, 0u8, 52u8, 0u8, 48u8, 0u8, 52u8, 0u8, 52u8, 0u8, 52u8, 0u8, 53u8, 0u8,
+            52u8, 0u8, 54u8, 0u8, 52u8, 0u8, 55u8, 0u8, 52u8, 0u8, 56u8, 0u8, 52u8, 0u8, 57u8, 0u8,
+            53u8, 0u8, 48u8, 0u8, 49u8, 0u8, 8u8, 103u8, 50u8, 0u8, 8u8, 103u8, 51u8, 0u8, 8u8,
+            103u8, 52u8, 0u8, 8u8, 103u8, 53u8, 0u8, 8u8, 103u8, 54u8, 0u8, 8u8, 103u8, 55u8, 0u8,
+            8u8, 103u8, 56u8, 0u8, 8u8, 103u8, 57u8, 0u8, 8u8, 103u8, 49u8, 0u8, 48u8, 0u8, 8u8,
+            103u8, 49u8, 0u8, 49u8, 0u8, 8u8, 103u8, 49u8, 0u8, 50u8, 0u8, 8u8, 103u8, 104u8, 0u8,
+            103u8, 0u8, 101u8, 0u8, 114u8, 0u8, 103u8, 0u8, 101u8, 0u8, 118u8, 0u8, 108u8, 0u8,
+            116u8, 0u8, 100u8, 0u8, 228u8, 78u8, 140u8, 84u8, 162u8, 48u8, 207u8, 48u8, 154u8,
+            48u8, 252u8, 48u8, 200u8, 48u8, 162u8, 48u8, 235u8, 48u8, 213u8, 48u8, 161u8, 48u8,
+            162u8, 48u8, 243u8, 48u8, 216u8, 48u8, 154u8, 48u8, 162u8, 48u8, 162u8, 48u8, 252u8,
+            48u8, 235u8, 48u8, 164u8, 48u8, 203u8, 48u8, 243u8, 48u8, 175u8, 48u8, 153u8, 48u8,
+            164u8, 48u8, 243u8, 48u8, 193u8, 48u8, 166u8, 48u8, 169u8, 48u8, 243u8, 48u8, 168u8,
+            48u8, 185u8, 48u8, 175u8, 48u8, 252u8, 48u8, 200u8, 48u8, 153u8, 48u8, 168u8, 48u8,
+            252u8, 48u8, 171u8, 48u8, 252u8, 48u8, 170u8, 48u8, 243u8, 48u8, 185u8, 48u8, 170u8,
+            48u8, 252u8, 48u8, 224u8, 48u8, 171u8, 48u8, 164u8, 48u8, 234u8, 48u8, 171u8, 48u8,
+            233u8, 48u8, 195u8, 48u8, 200u8, 48u8, 171u8, 48u8, 237u8, 48u8, 234u8, 48u8, 252u8,
+            48u8, 171u8, 48u8, 153u8, 48u8, 237u8, 48u8, 243u8, 48u8, 171u8, 48u8, 153u8, 48u8,
+            243u8, 48u8, 222u8, 48u8, 173u8, 48u8, 153u8, 48u8, 171u8, 48u8, 153u8, 48u8, 173u8,
+            48u8, 153u8, 48u8, 203u8, 48u8, 252u8, 48u8, 173u8, 48u8, 229u8, 48u8, 234u8, 48u8,
+            252u8, 48u8, 173u8, 48u8, 153u8, 48u8, 235u8, 48u8, 191u8, 48u8, 153u8, 48u8, 252u8,
+            48u8, 173u8, 48u8, 237u8, 48u8, 173u8, 48u8, 237u8, 48u8, 175u8, 48u8, 153u8, 48u8,
+            233u8, 48u8, 224u8, 48u8, 173u8, 48u8, 237u8, 48u8, 225u8, 48u8, 252u8, 48u8, 200u8,
+            48u8, 235u8, 48u8, 173u8, 48u8, 237u8, 48u8, 239u8, 48u8, 195u8, 48u8, 200u8, 48u8,
+            175u8, 48u8, 153u8, 48u8, 233u8, 48u8, 224u8, 48u8, 200u8, 48u8, 243u8, 48u8, 175u8,
+            48u8, 235u8, 48u8, 187u8, 48u8, 153u8, 48u8, 164u8, 48u8, 237u8, 48u8, 175u8, 48u8,
+            237u8, 48u8, 252u8, 48u8, 205u8, 48u8, 177u8, 48u8, 252u8, 48u8, 185u8, 48u8, 179u8,
+            48u8, 235u8, 48u8, 202u8, 48u8, 179u8, 48u8, 252u8, 48u8, 219u8, 48u8, 154u8, 48u8,
+            181u8, 48u8, 164u8, 48u8, 175u8, 48u8, 235u8, 48u8, 181u8, 48u8, 243u8, 48u8, 193u8,
+            48u8, 252u8, 48u8, 224u8, 48u8, 183u8, 48u8, 234u8, 48u8, 243u8, 48u8, 175u8, 48u8,
+            153u8, 48u8, 187u8, 48u8, 243u8, 48u8, 193u8, 48u8, 187u8, 48u8, 243u8, 48u8, 200u8,
+            48u8, 191u8, 48u8, 153u8, 48u8, 252u8, 48u8, 185u8, 48u8, 198u8, 48u8, 153u8, 48u8,
+            183u8, 48u8, 200u8, 48u8, 153u8, 48u8, 235u8, 48u8, 202u8, 48u8, 206u8, 48u8, 206u8,
+            48u8, 195u8, 48u8, 200u8, 48u8, 207u8, 48u8, 164u8, 48u8, 196u8, 48u8, 207u8, 48u8,
+            154u8, 48u8, 252u8, 48u8, 187u8, 48u8, 243u8, 48u8, 200u8, 48u8, 207u8, 48u8, 154u8,
+            48u8, 252u8, 48u8, 196u8, 48u8, 207u8, 48u8, 153u8, 48u8, 252u8, 48u8, 236u8, 48u8,
+            235u8, 48u8, 210u8, 48u8, 154u8, 48u8, 162u8, 48u8, 185u8, 48u8, 200u8, 48u8, 235u8,
+            48u8, 210u8, 48u8, 154u8, 48u8, 175u8, 48u8, 235u8, 48u8, 210u8, 48u8, 154u8, 48u8,
+            179u8, 48u8, 210u8, 48u8, 153u8, 48u8, 235u8, 48u8, 213u8, 48u8, 161u8, 48u8, 233u8,
+            48u8, 195u8, 48u8, 200u8, 48u8, 153u8, 48u8, 213u8, 48u8, 163u8, 48u8, 252u8, 48u8,
+            200u8, 48u8, 213u8, 48u8, 153u8, 48u8, 195u8, 48u8, 183u8, 48u8, 167u8, 48u8, 235u8,
+            48u8, 213u8, 48u8, 233u8, 48u8, 243u8, 48u8, 216u8, 48u8, 175u8, 48u8, 191u8, 48u8,
+            252u8, 48u8, 235u8, 48u8, 216u8, 48u8, 154u8, 48u8, 189u8, 48u8, 216u8, 48u8, 154u8,
+            48u8, 203u8, 48u8, 210u8, 48u8, 216u8, 48u8, 235u8, 48u8, 196u8, 48u8, 216u8, 48u8,
+            154u8, 48u8, 243u8, 48u8, 185u8, 48u8, 216u8, 48u8, 154u8, 48u8, 252u8, 48u8, 183u8,
+            48u8, 153u8, 48u8, 216u8, 48u8, 153u8, 48u8, 252u8, 48u8, 191u8, 48u8, 219u8, 48u8,
+            154u8, 48u8, 164u8, 48u8, 243u8, 48u8, 200u8, 48u8, 219u8, 48u8, 153u8, 48u8, 235u8,
+            48u8, 200u8, 48u8, 219u8, 48u8, 243u8, 48u8, 219u8, 48u8, 154u8, 48u8, 243u8, 48u8,
+            200u8, 48u8, 153u8, 48u8, 219u8, 48u8, 252u8, 48u8, 235u8, 48u8, 219u8, 48u8, 252u8,
+            48u8, 243u8, 48u8, 222u8, 48u8, 164u8, 48u8, 175u8, 48u8, 237u8, 48u8, 222u8, 48u8,
+            164u8, 48u8, 235u8, 48u8, 222u8, 48u8, 195u8, 48u8, 207u8, 48u8, 222u8, 48u8, 235u8,
+            48u8, 175u8, 48u8, 222u8, 48u8, 243u8, 48u8, 183u8, 48u8, 231u8, 48u8, 243u8, 48u8,
+            223u8, 48u8, 175u8, 48u8, 237u8, 48u8, 243u8, 48u8, 223u8, 48u8, 234u8, 48u8, 223u8,
+            48u8, 234u8, 48u8, 207u8, 48u8, 153u8, 48u8, 252u8, 48u8, 235u8, 48u8, 225u8, 48u8,
+            171u8, 48u8, 153u8, 48u8, 225u8, 48u8, 171u8, 48u8, 153u8, 48u8, 200u8, 48u8, 243u8,
+            48u8, 228u8, 48u8, 252u8, 48u8, 200u8, 48u8, 153u8, 48u8, 228u8, 48u8, 252u8, 48u8,
+            235u8, 48u8, 230u8, 48u8, 162u8, 48u8, 243u8, 48u8, 234u8, 48u8, 195u8, 48u8, 200u8,
+            48u8, 235u8, 48u8, 234u8, 48u8, 233u8, 48u8, 235u8, 48u8, 210u8, 48u8, 154u8, 48u8,
+            252u8, 48u8, 235u8, 48u8, 252u8, 48u8, 213u8, 48u8, 153u8, 48u8, 235u8, 48u8, 236u8,
+            48u8, 224u8, 48u8, 236u8, 48u8, 243u8, 48u8, 200u8, 48u8, 177u8, 48u8, 153u8, 48u8,
+            243u8, 48u8, 48u8, 0u8, 185u8, 112u8, 49u8, 0u8, 185u8, 112u8, 50u8, 0u8, 185u8, 112u8,
+            51u8, 0u8, 185u8, 112u8, 52u8, 0u8, 185u8, 112u8, 53u8, 0u8, 185u8, 112u8, 54u8, 0u8,
+            185u8, 112u8, 55u8, 0u8, 185u8, 112u8, 56u8, 0u8, 185u8, 112u8, 57u8, 0u8, 185u8,
+            112u8, 49u8, 0u8, 48u8, 0u8, 185u8, 112u8, 49u8, 0u8, 49u8, 0u8, 185u8, 112u8, 49u8,
+            0u8, 50u8, 0u8, 185u8, 112u8, 49u8, 0u8, 51u8, 0u8, 185u8, 112u8, 49u8, 0u8, 52u8, 0u8,
+            185u8, 112u8, 49u8, 0u8, 53u8, 0u8, 185u8, 112u8, 49u8, 0u8, 54u8, 0u8, 185u8, 112u8,
+            49u8, 0u8, 55u8, 0u8, 185u8, 112u8, 49u8, 0u8, 56u8, 0u8, 185u8, 112u8, 49u8, 0u8,
+            57u8, 0u8, 185u8, 112u8, 50u8, 0u8, 48u8, 0u8, 185u8, 112u8, 50u8, 0u8, 49u8, 0u8,
+            185u8, 112u8, 50u8, 0u8, 50u8, 0u8, 185u8, 112u8, 50u8, 0u8, 51u8, 0u8, 185u8, 112u8,
+            50u8, 0u8, 52u8, 0u8, 185u8, 112u8, 104u8, 0u8, 112u8, 0u8, 97u8, 0u8, 100u8, 0u8,
+            97u8, 0u8, 97u8, 0u8, 117u8, 0u8, 98u8, 0u8, 97u8, 0u8, 114u8, 0u8, 111u8, 0u8, 118u8,
+            0u8, 112u8, 0u8, 99u8, 0u8, 100u8, 0u8, 109u8, 0u8, 100u8, 0u8, 109u8, 0u8, 50u8, 0u8,
+            100u8, 0u8, 109u8, 0u8, 51u8, 0u8, 105u8, 0u8, 117u8, 0u8, 115u8, 94u8, 16u8, 98u8,
+            45u8, 102u8, 140u8, 84u8, 39u8, 89u8, 99u8, 107u8, 14u8, 102u8, 187u8, 108u8, 42u8,
+            104u8, 15u8, 95u8, 26u8, 79u8, 62u8, 121u8, 110u8, 0u8, 97u8, 0u8, 188u8, 3u8, 97u8,
+            0u8, 109u8, 0u8, 97u8, 0u8, 107u8, 0u8, 97u8, 0u8, 107u8, 0u8, 98u8, 0u8, 109u8, 0u8,
+            98u8, 0u8, 103u8, 0u8, 98u8, 0u8, 99u8, 0u8, 97u8, 0u8, 108u8, 0u8, 107u8, 0u8, 99u8,
+            0u8, 97u8, 0u8, 108u8, 0u8, 112u8, 0u8, 102u8, 0u8, 110u8, 0u8, 102u8, 0u8, 188u8, 3u8,
+            102u8, 0u8, 188u8, 3u8, 103u8, 0u8, 109u8, 0u8, 103u8, 0u8, 107u8, 0u8, 103u8, 0u8,
+            104u8, 0u8, 122u8, 0u8, 107u8, 0u8, 104u8, 0u8, 122u8, 0u8, 109u8, 0u8, 104u8, 0u8,
+            122u8, 0u8, 116u8, 0u8, 104u8, 0u8, 122u8, 0u8, 188u8, 3u8, 108u8, 0u8, 109u8, 0u8,
+            108u8, 0u8, 100u8, 0u8, 108u8, 0u8, 107u8, 0u8, 108u8, 0u8, 102u8, 0u8, 109u8, 0u8,
+            110u8, 0u8, 109u8, 0u8, 188u8, 3u8, 109u8, 0u8, 109u8, 0u8, 109u8, 0u8, 99u8, 0u8,
+            109u8, 0u8, 107u8, 0u8, 109u8, 0u8, 109u8, 0u8, 109u8, 0u8, 50u8, 0u8, 99u8, 0u8,
+            109u8, 0u8, 50u8, 0u8, 107u8, 0u8, 109u8, 0u8, 50u8, 0u8, 109u8, 0u8, 109u8, 0u8, 51u8,
+            0u8, 99u8, 0u8, 109u8, 0u8, 51u8, 0u8, 107u8, 0u8, 109u8, 0u8, 51u8, 0u8, 109u8, 0u8,
+            21u8, 34u8, 115u8, 0u8, 109u8, 0u8, 21u8, 34u8, 115u8, 0u8, 50u8, 0u8, 107u8, 0u8,
+            112u8, 0u8, 97u8, 0u8, 109u8, 0u8, 112u8, 0u8, 97u8, 0u8, 103u8, 0u8, 112u8, 0u8, 97u8,
+            0u8, 114u8, 0u8, 97u8, 0u8, 100u8, 0u8, 114u8, 0u8, 97u8, 0u8, 100u8, 0u8, 21u8, 34u8,
+            115u8, 0u8, 114u8, 0u8, 97u8, 0u8, 100u8, 0u8, 21u8, 34u8, 115u8, 0u8, 50u8, 0u8,
+            112u8, 0u8, 115u8, 0u8, 110u8, 0u8, 115u8, 0u8, 188u8, 3u8, 115u8, 0u8, 109u8, 0u8,
+            115u8, 0u8, 112u8, 0u8, 118u8, 0u8, 110u8, 0u8, 118u8, 0u8, 188u8, 3u8, 118u8, 0u8,
+            109u8, 0u8, 118u8, 0u8, 107u8, 0u8, 118u8, 0u8, 112u8, 0u8, 119u8, 0u8, 110u8, 0u8,
+            119u8, 0u8, 188u8, 3u8, 119u8, 0u8, 109u8, 0u8, 119u8, 0u8, 107u8, 0u8, 119u8, 0u8,
+            107u8, 0u8, 201u8, 3u8, 109u8, 0u8, 201u8, 3u8, 97u8, 0u8, 46u8, 0u8, 109u8, 0u8, 46u8,
+            0u8, 98u8, 0u8, 113u8, 0u8, 99u8, 0u8, 21u8, 34u8, 107u8, 0u8, 103u8, 0u8, 99u8, 0u8,
+            111u8, 0u8, 46u8, 0u8, 100u8, 0u8, 98u8, 0u8, 103u8, 0u8, 121u8, 0u8, 104u8, 0u8, 97u8,
+            0u8, 105u8, 0u8, 110u8, 0u8, 107u8, 0u8, 107u8, 0u8, 107u8, 0u8, 116u8, 0u8, 108u8,
+            0u8, 110u8, 0u8, 108u8, 0u8, 111u8, 0u8, 103u8, 0u8, 108u8, 0u8, 120u8, 0u8, 109u8,
+            0u8, 105u8, 0u8, 108u8, 0u8, 109u8, 0u8, 111u8, 0u8, 108u8, 0u8, 112u8, 0u8, 104u8,
+            0u8, 112u8, 0u8, 46u8, 0u8, 109u8, 0u8, 46u8, 0u8, 112u8, 0u8, 112u8, 0u8, 109u8, 0u8,
+            112u8, 0u8, 114u8, 0u8, 115u8, 0u8, 118u8, 0u8, 119u8, 0u8, 98u8, 0u8, 118u8, 0u8,
+            21u8, 34u8, 109u8, 0u8, 97u8, 0u8, 21u8, 34u8, 109u8, 0u8, 49u8, 0u8, 229u8, 101u8,
+            50u8, 0u8, 229u8, 101u8, 51u8, 0u8, 229u8, 101u8, 52u8, 0u8, 229u8, 101u8, 53u8, 0u8,
+            229u8, 101u8, 54u8, 0u8, 229u8, 101u8, 55u8, 0u8, 229u8, 101u8, 56u8, 0u8, 229u8,
+            101u8, 57u8, 0u8, 229u8, 101u8, 49u8, 0u8, 48u8, 0u8, 229u8, 101u8, 49u8, 0u8, 49u8,
+            0u8, 229u8, 101u8, 49u8, 0u8, 50u8, 0u8, 229u8, 101u8, 49u8, 0u8, 51u8, 0u8, 229u8,
+            101u8, 49u8, 0u8, 52u8, 0u8, 229u8, 101u8, 49u8, 0u8, 53u8, 0u8, 229u8, 101u8, 49u8,
+            0u8, 54u8, 0u8, 229u8, 101u8, 49u8, 0u8, 55u8, 0u8, 229u8, 101u8, 49u8, 0u8, 56u8, 0u8,
+            229u8, 101u8, 49u8, 0u8, 57u8, 0u8, 229u8, 101u8, 50u8, 0u8, 48u8, 0u8, 229u8, 101u8,
+            50u8, 0u8, 49u8, 0u8, 229u8, 101u8, 50u8, 0u8, 50u8, 0u8, 229u8, 101u8, 50u8, 0u8,
+            51u8, 0u8, 229u8, 101u8, 50u8, 0u8, 52u8, 0u8, 229u8, 101u8, 50u8, 0u8, 53u8, 0u8,
+            229u8, 101u8, 50u8, 0u8, 54u8, 0u8, 229u8, 101u8, 50u8, 0u8, 55u8, 0u8, 229u8, 101u8,
+            50u8, 0u8, 56u8, 0u8, 229u8, 101u8, 50u8, 0u8, 57u8, 0u8, 229u8, 101u8, 51u8, 0u8,
+            48u8, 0u8, 229u8, 101u8, 51u8, 0u8, 49u8, 0u8, 229u8, 101u8, 103u8, 0u8, 97u8, 0u8,
+            108u8, 0u8, 102u8, 0u8, 102u8, 0u8, 102u8, 0u8, 105u8, 0u8, 102u8, 0u8, 108u8, 0u8,
+            102u8, 0u8, 102u8, 0u8, 108u8, 0u8, 115u8, 0u8, 116u8, 0u8, 116u8, 5u8, 118u8, 5u8,
+            116u8, 5u8, 101u8, 5u8, 116u8, 5u8, 107u8, 5u8, 126u8, 5u8, 118u8, 5u8, 116u8, 5u8,
+            109u8, 5u8, 208u8, 5u8, 220u8, 5u8, 74u8, 6u8, 84u8, 6u8, 39u8, 6u8, 74u8, 6u8, 84u8,
+            6u8, 213u8, 6u8, 74u8, 6u8, 84u8, 6u8, 72u8, 6u8, 74u8, 6u8, 84u8, 6u8, 199u8, 6u8,
+            74u8, 6u8, 84u8, 6u8, 198u8, 6u8, 74u8, 6u8, 84u8, 6u8, 200u8, 6u8, 74u8, 6u8, 84u8,
+            6u8, 208u8, 6u8, 74u8, 6u8, 84u8, 6u8, 73u8, 6u8, 74u8, 6u8, 84u8, 6u8, 44u8, 6u8,
+            74u8, 6u8, 84u8, 6u8, 45u8, 6u8, 74u8, 6u8, 84u8, 6u8, 69u8, 6u8, 74u8, 6u8, 84u8, 6u8,
+            74u8, 6u8, 40u8, 6u8, 44u8, 6u8, 40u8, 6u8, 45u8, 6u8, 40u8, 6u8, 46u8, 6u8, 40u8, 6u8,
+            69u8, 6u8, 40u8, 6u8, 73u8, 6u8, 40u8, 6u8, 74u8, 6u8, 42u8, 6u8, 44u8, 6u8, 42u8, 6u8,
+            45u8, 6u8, 42u8, 6u8, 46u8, 6u8, 42u8, 6u8, 69u8, 6u8, 42u8, 6u8, 73u8, 6u8, 42u8, 6u8,
+            74u8, 6u8, 43u8, 6u8, 44u8, 6u8, 43u8, 6u8, 69u8, 6u8, 43u8, 6u8, 73u8, 6u8, 43u8, 6u8,
+            74u8, 6u8, 44u8, 6u8, 45u8, 6u8, 44u8, 6u8, 69u8, 6u8, 45u8, 6u8, 69u8, 6u8, 46u8, 6u8,
+            44u8, 6u8, 46u8, 6u8, 45u8, 6u8, 46u8, 6u8, 69u8, 6u8, 51u8, 6u8, 44u8, 6u8, 51u8, 6u8,
+            45u8, 6u8, 51u8, 6u8, 46u8, 6u8, 51u8, 6u8, 69u8, 6u8, 53u8, 6u8, 45u8, 6u8, 53u8, 6u8,
+            69u8, 6u8, 54u8, 6u8, 44u8, 6u8, 54u8, 6u8, 45u8, 6u8, 54u8, 6u8, 46u8, 6u8, 54u8, 6u8,
+            69u8, 6u8, 55u8, 6u8, 45u8, 6u8, 55u8, 6u8, 69u8, 6u8, 56u8, 6u8, 69u8, 6u8, 57u8, 6u8,
+            44u8, 6u8, 57u8, 6u8, 69u8, 6u8, 58u8, 6u8, 44u8, 6u8, 58u8, 6u8, 69u8, 6u8, 65u8, 6u8,
+            44u8, 6u8, 65u8, 6u8, 45u8, 6u8, 65u8, 6u8, 46u8, 6u8, 65u8, 6u8, 69u8, 6u8, 65u8, 6u8,
+            73u8, 6u8, 65u8, 6u8, 74u8, 6u8, 66u8, 6u8, 45u8, 6u8, 66u8, 6u8, 69u8, 6u8, 66u8, 6u8,
+            73u8, 6u8, 66u8, 6u8, 74u8, 6u8, 67u8, 6u8, 39u8, 6u8, 67u8, 6u8, 44u8, 6u8, 67u8, 6u8,
+            45u8, 6u8, 67u8, 6u8, 46u8, 6u8, 67u8, 6u8, 68u8, 6u8, 67u8, 6u8, 69u8, 6u8, 67u8, 6u8,
+            73u8, 6u8, 67u8, 6u8, 74u8, 6u8, 68u8, 6u8, 44u8, 6u8, 68u8, 6u8, 45u8, 6u8, 68u8, 6u8,
+            46u8, 6u8, 68u8, 6u8, 69u8, 6u8, 68u8, 6u8, 73u8, 6u8, 68u8, 6u8, 74u8, 6u8, 69u8, 6u8,
+            44u8, 6u8, 69u8, 6u8, 69u8, 6u8, 69u8, 6u8, 73u8, 6u8, 70u8, 6u8, 44u8, 6u8, 70u8, 6u8,
+            45u8, 6u8, 70u8, 6u8, 46u8, 6u8, 70u8, 6u8, 69u8, 6u8, 70u8, 6u8, 73u8, 6u8, 70u8, 6u8,
+            74u8, 6u8, 71u8, 6u8, 44u8, 6u8, 71u8, 6u8, 69u8, 6u8, 71u8, 6u8, 73u8, 6u8, 71u8, 6u8,
+            74u8, 6u8, 74u8, 6u8, 45u8, 6u8, 74u8, 6u8, 46u8, 6u8, 74u8, 6u8, 73u8, 6u8, 32u8, 0u8,
+            76u8, 6u8, 81u8, 6u8, 32u8, 0u8, 77u8, 6u8, 81u8, 6u8, 32u8, 0u8, 78u8, 6u8, 81u8, 6u8,
+            32u8, 0u8, 79u8, 6u8, 81u8, 6u8, 32u8, 0u8, 80u8, 6u8, 81u8, 6u8, 32u8, 0u8, 81u8, 6u8,
+            112u8, 6u8, 74u8, 6u8, 84u8, 6u8, 49u8, 6u8, 74u8, 6u8, 84u8, 6u8, 50u8, 6u8, 74u8,
+            6u8, 84u8, 6u8, 70u8, 6u8, 40u8, 6u8, 49u8, 6u8, 40u8, 6u8, 50u8, 6u8, 40u8, 6u8, 70u8,
+            6u8, 42u8, 6u8, 49u8, 6u8, 42u8, 6u8, 50u8, 6u8, 42u8, 6u8, 70u8, 6u8, 43u8, 6u8, 49u8,
+            6u8, 43u8, 6u8, 50u8, 6u8, 43u8, 6u8, 70u8, 6u8, 69u8, 6u8, 39u8, 6u8, 70u8, 6u8, 49u8,
+            6u8, 70u8, 6u8, 50u8, 6u8, 70u8, 6u8, 70u8, 6u8, 74u8, 6u8, 49u8, 6u8, 74u8, 6u8, 50u8,
+            6u8, 74u8, 6u8, 70u8, 6u8, 74u8, 6u8, 84u8, 6u8, 46u8, 6u8, 74u8, 6u8, 84u8, 6u8, 71u8,
+            6u8, 40u8, 6u8, 71u8, 6u8, 42u8, 6u8, 71u8, 6u8, 53u8, 6u8, 46u8, 6u8, 68u8, 6u8, 71u8,
+            6u8, 70u8, 6u8, 71u8, 6u8, 43u8, 6u8, 71u8, 6u8, 51u8, 6u8, 71u8, 6u8, 52u8, 6u8, 69u8,
+            6u8, 52u8, 6u8, 71u8, 6u8, 64u8, 6u8, 78u8, 6u8, 81u8, 6u8, 64u8, 6u8, 79u8, 6u8, 81u8,
+            6u8, 64u8, 6u8, 80u8, 6u8, 81u8, 6u8, 55u8, 6u8, 73u8, 6u8, 55u8, 6u8, 74u8, 6u8, 57u8,
+            6u8, 73u8, 6u8, 57u8, 6u8, 74u8, 6u8, 58u8, 6u8, 73u8, 6u8, 58u8, 6u8, 74u8, 6u8, 51u8,
+            6u8, 73u8, 6u8, 51u8, 6u8, 74u8, 6u8, 52u8, 6u8, 73u8, 6u8, 52u8, 6u8, 74u8, 6u8, 45u8,
+            6u8, 73u8, 6u8, 44u8, 6u8, 73u8, 6u8, 46u8, 6u8, 73u8, 6u8, 53u8, 6u8, 73u8, 6u8, 53u8,
+            6u8, 74u8, 6u8, 54u8, 6u8, 73u8, 6u8, 54u8, 6u8, 74u8, 6u8, 52u8, 6u8, 44u8, 6u8, 52u8,
+            6u8, 45u8, 6u8, 52u8, 6u8, 46u8, 6u8, 52u8, 6u8, 49u8, 6u8, 51u8, 6u8, 49u8, 6u8, 53u8,
+            6u8, 49u8, 6u8, 54u8, 6u8, 49u8, 6u8, 42u8, 6u8, 44u8, 6u8, 69u8, 6u8, 42u8, 6u8, 45u8,
+            6u8, 44u8, 6u8, 42u8, 6u8, 45u8, 6u8, 69u8, 6u8, 42u8, 6u8, 46u8, 6u8, 69u8, 6u8, 42u8,
+            6u8, 69u8, 6u8, 44u8, 6u8, 42u8, 6u8, 69u8, 6u8, 45u8, 6u8, 42u8, 6u8, 69u8, 6u8, 46u8,
+            6u8, 45u8, 6u8, 69u8, 6u8, 74u8, 6u8, 45u8, 6u8, 69u8, 6u8, 73u8, 6u8, 51u8, 6u8, 45u8,
+            6u8, 44u8, 6u8, 51u8, 6u8, 44u8, 6u8, 45u8, 6u8, 51u8, 6u8, 44u8, 6u8, 73u8, 6u8, 51u8,
+            6u8, 69u8, 6u8, 45u8, 6u8, 51u8, 6u8, 69u8, 6u8, 44u8, 6u8, 51u8, 6u8, 69u8, 6u8, 69u8,
+            6u8, 53u8, 6u8, 45u8, 6u8, 45u8, 6u8, 53u8, 6u8, 69u8, 6u8, 69u8, 6u8, 52u8, 6u8, 45u8,
+            6u8, 69u8, 6u8, 52u8, 6u8, 44u8, 6u8, 74u8, 6u8, 52u8, 6u8, 69u8, 6u8, 46u8, 6u8, 52u8,
+            6u8, 69u8, 6u8, 69u8, 6u8, 54u8, 6u8, 45u8, 6u8, 73u8, 6u8, 54u8, 6u8, 46u8, 6u8, 69u8,
+            6u8, 55u8, 6u8, 69u8, 6u8, 45u8, 6u8, 55u8, 6u8, 69u8, 6u8, 69u8, 6u8, 55u8, 6u8, 69u8,
+            6u8, 74u8, 6u8, 57u8, 6u8, 44u8, 6u8, 69u8, 6u8, 57u8, 6u8, 69u8, 6u8, 69u8, 6u8, 57u8,
+            6u8, 69u8, 6u8, 73u8, 6u8, 58u8, 6u8, 69u8, 6u8, 69u8, 6u8, 58u8, 6u8, 69u8, 6u8, 74u8,
+            6u8, 58u8, 6u8, 69u8, 6u8, 73u8, 6u8, 65u8, 6u8, 46u8, 6u8, 69u8, 6u8, 66u8, 6u8, 69u8,
+            6u8, 45u8, 6u8, 66u8, 6u8, 69u8, 6u8, 69u8, 6u8, 68u8, 6u8, 45u8, 6u8, 69u8, 6u8, 68u8,
+            6u8, 45u8, 6u8, 74u8, 6u8, 68u8, 6u8, 45u8, 6u8, 73u8, 6u8, 68u8, 6u8, 44u8, 6u8, 44u8,
+            6u8, 68u8, 6u8, 46u8, 6u8, 69u8, 6u8, 68u8, 6u8, 69u8, 6u8, 45u8, 6u8, 69u8, 6u8, 45u8,
+            6u8, 44u8, 6u8, 69u8, 6u8, 45u8, 6u8, 74u8, 6u8, 69u8, 6u8, 44u8, 6u8, 45u8, 6u8, 69u8,
+            6u8, 46u8, 6u8, 69u8, 6u8, 69u8, 6u8, 44u8, 6u8, 46u8, 6u8, 71u8, 6u8, 69u8, 6u8, 44u8,
+            6u8, 71u8, 6u8, 69u8, 6u8, 69u8, 6u8, 70u8, 6u8, 45u8, 6u8, 69u8, 6u8, 70u8, 6u8, 45u8,
+            6u8, 73u8, 6u8, 70u8, 6u8, 44u8, 6u8, 69u8, 6u8, 70u8, 6u8, 44u8, 6u8, 73u8, 6u8, 70u8,
+            6u8, 69u8, 6u8, 74u8, 6u8, 70u8, 6u8, 69u8, 6u8, 73u8, 6u8, 74u8, 6u8, 69u8, 6u8, 69u8,
+            6u8, 40u8, 6u8, 46u8, 6u8, 74u8, 6u8, 42u8, 6u8, 44u8, 6u8, 74u8, 6u8, 42u8, 6u8, 44u8,
+            6u8, 73u8, 6u8, 42u8, 6u8, 46u8, 6u8, 74u8, 6u8, 42u8, 6u8, 46u8, 6u8, 73u8, 6u8, 42u8,
+            6u8, 69u8, 6u8, 74u8, 6u8, 42u8, 6u8, 69u8, 6u8, 73u8, 6u8, 44u8, 6u8, 69u8, 6u8, 74u8,
+            6u8, 44u8, 6u8, 45u8, 6u8, 73u8, 6u8, 44u8, 6u8, 69u8, 6u8, 73u8, 6u8, 51u8, 6u8, 46u8,
+            6u8, 73u8, 6u8, 53u8, 6u8, 45u8, 6u8, 74u8, 6u8, 52u8, 6u8, 45u8, 6u8, 74u8, 6u8, 54u8,
+            6u8, 45u8, 6u8, 74u8, 6u8, 68u8, 6u8, 44u8, 6u8, 74u8, 6u8, 68u8, 6u8, 69u8, 6u8, 74u8,
+            6u8, 74u8, 6u8, 44u8, 6u8, 74u8, 6u8, 74u8, 6u8, 69u8, 6u8, 74u8, 6u8, 69u8, 6u8, 69u8,
+            6u8, 74u8, 6u8, 66u8, 6u8, 69u8, 6u8, 74u8, 6u8, 70u8, 6u8, 45u8, 6u8, 74u8, 6u8, 57u8,
+            6u8, 69u8, 6u8, 74u8, 6u8, 67u8, 6u8, 69u8, 6u8, 74u8, 6u8, 70u8, 6u8, 44u8, 6u8, 45u8,
+            6u8, 69u8, 6u8, 46u8, 6u8, 74u8, 6u8, 68u8, 6u8, 44u8, 6u8, 69u8, 6u8, 67u8, 6u8, 69u8,
+            6u8, 69u8, 6u8, 44u8, 6u8, 45u8, 6u8, 74u8, 6u8, 45u8, 6u8, 44u8, 6u8, 74u8, 6u8, 69u8,
+            6u8, 44u8, 6u8, 74u8, 6u8, 65u8, 6u8, 69u8, 6u8, 74u8, 6u8, 40u8, 6u8, 45u8, 6u8, 74u8,
+            6u8, 51u8, 6u8, 46u8, 6u8, 74u8, 6u8, 70u8, 6u8, 44u8, 6u8, 74u8, 6u8, 53u8, 6u8, 68u8,
+            6u8, 210u8, 6u8, 66u8, 6u8, 68u8, 6u8, 210u8, 6u8, 39u8, 6u8, 68u8, 6u8, 68u8, 6u8,
+            71u8, 6u8, 39u8, 6u8, 67u8, 6u8, 40u8, 6u8, 49u8, 6u8, 69u8, 6u8, 45u8, 6u8, 69u8, 6u8,
+            47u8, 6u8, 53u8, 6u8, 68u8, 6u8, 57u8, 6u8, 69u8, 6u8, 49u8, 6u8, 51u8, 6u8, 72u8, 6u8,
+            68u8, 6u8, 57u8, 6u8, 68u8, 6u8, 74u8, 6u8, 71u8, 6u8, 72u8, 6u8, 51u8, 6u8, 68u8, 6u8,
+            69u8, 6u8, 53u8, 6u8, 68u8, 6u8, 73u8, 6u8, 44u8, 6u8, 68u8, 6u8, 32u8, 0u8, 44u8, 6u8,
+            68u8, 6u8, 39u8, 6u8, 68u8, 6u8, 71u8, 6u8, 49u8, 6u8, 204u8, 6u8, 39u8, 6u8, 68u8,
+            6u8, 68u8, 6u8, 39u8, 6u8, 83u8, 6u8, 68u8, 6u8, 39u8, 6u8, 84u8, 6u8, 68u8, 6u8, 39u8,
+            6u8, 85u8, 6u8, 48u8, 0u8, 44u8, 0u8, 49u8, 0u8, 44u8, 0u8, 50u8, 0u8, 44u8, 0u8, 51u8,
+            0u8, 44u8, 0u8, 52u8, 0u8, 44u8, 0u8, 53u8, 0u8, 44u8, 0u8, 54u8, 0u8, 44u8, 0u8, 55u8,
+            0u8, 44u8, 0u8, 56u8, 0u8, 44u8, 0u8, 57u8, 0u8, 44u8, 0u8, 20u8, 48u8, 115u8, 0u8,
+            21u8, 48u8, 119u8, 0u8, 122u8, 0u8, 104u8, 0u8, 118u8, 0u8, 115u8, 0u8, 100u8, 0u8,
+            112u8, 0u8, 112u8, 0u8, 118u8, 0u8, 119u8, 0u8, 99u8, 0u8, 109u8, 0u8, 114u8, 0u8,
+            100u8, 0u8, 106u8, 0u8, 123u8, 48u8, 75u8, 48u8, 179u8, 48u8, 179u8, 48u8, 20u8, 48u8,
+            44u8, 103u8, 21u8, 48u8, 20u8, 48u8, 9u8, 78u8, 21u8, 48u8, 20u8, 48u8, 140u8, 78u8,
+            21u8, 48u8, 20u8, 48u8, 137u8, 91u8, 21u8, 48u8, 20u8, 48u8, 185u8, 112u8, 21u8, 48u8,
+            20u8, 48u8, 83u8, 98u8, 21u8, 48u8, 20u8, 48u8, 215u8, 118u8, 21u8, 48u8, 20u8, 48u8,
+            221u8, 82u8, 21u8, 48u8, 20u8, 48u8, 87u8, 101u8, 21u8, 48u8,
+        ])
+    },
+    scalars24: unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+            40u8, 4u8, 1u8, 41u8, 4u8, 1u8, 42u8, 4u8, 1u8, 43u8, 4u8, 1u8, 44u8, 4u8, 1u8, 45u8,
+            4u8, 1u8, 46u8, 4u8, 1u8, 47u8, 4u8, 1u8, 48u8, 4u8, 1u8, 49u8, 4u8, 1u8, 50u8, 4u8,
+            1u8, 51u8, 4u8, 1u8, 52u8, 4u8, 1u8, 53u8, 4u8, 1u8, 54u8, 4u8, 1u8, 55u8, 4u8, 1u8,
+            56u8, 4u8, 1u8, 57u8, 4u8, 1u8, 58u8, 4u8, 1u8, 59u8, 4u8, 1u8, 60u8, 4u8, 1u8, 61u8,
+            4u8, 1u8, 62u8, 4u8, 1u8, 63u8, 4u8, 1u8, 64u8, 4u8, 1u8, 65u8, 4u8, 1u8, 66u8, 4u8,
+            1u8, 67u8, 4u8, 1u8, 68u8, 4u8, 1u8, 69u8, 4u8, 1u8, 70u8, 4u8, 1u8, 71u8, 4u8, 1u8,
+            72u8, 4u8, 1u8, 73u8, 4u8, 1u8, 74u8, 4u8, 1u8, 75u8, 4u8, 1u8, 76u8, 4u8, 1u8, 77u8,
+            4u8, 1u8, 78u8, 4u8, 1u8, 79u8, 4u8, 1u8, 216u8, 4u8, 1u8, 217u8, 4u8, 1u8, 218u8, 4u8,
+            1u8, 219u8, 4u8, 1u8, 220u8, 4u8, 1u8, 221u8, 4u8, 1u8, 222u8, 4u8, 1u8, 223u8, 4u8,
+            1u8, 224u8, 4u8, 1u8, 225u8, 4u8, 1u8, 226u8, 4u8, 1u8, 227u8, 4u8, 1u8, 228u8, 4u8,
+            1u8, 229u8, 4u8, 1u8, 230u8, 4u8, 1u8, 231u8, 4u8, 1u8, 232u8, 4u8, 1u8, 233u8, 4u8,
+            1u8, 234u8, 4u8, 1u8, 235u8, 4u8, 1u8, 236u8, 4u8, 1u8, 237u8, 4u8, 1u8, 238u8, 4u8,
+            1u8, 239u8, 4u8, 1u8, 240u8, 4u8, 1u8, 241u8, 4u8, 1u8, 242u8, 4u8, 1u8, 243u8, 4u8,
+            1u8, 244u8, 4u8, 1u8, 245u8, 4u8, 1u8, 246u8, 4u8, 1u8, 247u8, 4u8, 1u8, 248u8, 4u8,
+            1u8, 249u8, 4u8, 1u8, 250u8, 4u8, 1u8, 251u8, 4u8, 1u8, 151u8, 5u8, 1u8, 152u8, 5u8,
+            1u8, 153u8, 5u8, 1u8, 154u8, 5u8, 1u8, 155u8, 5u8, 1u8, 156u8, 5u8, 1u8, 157u8, 5u8,
+            1u8, 158u8, 5u8, 1u8, 159u8, 5u8, 1u8, 160u8, 5u8, 1u8, 161u8, 5u8, 1u8, 163u8, 5u8,
+            1u8, 164u8, 5u8, 1u8, 165u8, 5u8, 1u8, 166u8, 5u8, 1u8, 167u8, 5u8, 1u8, 168u8, 5u8,
+            1u8, 169u8, 5u8, 1u8, 170u8, 5u8, 1u8, 171u8, 5u8, 1u8, 172u8, 5u8, 1u8, 173u8, 5u8,
+            1u8, 174u8, 5u8, 1u8, 175u8, 5u8, 1u8, 176u8, 5u8, 1u8, 177u8, 5u8, 1u8, 179u8, 5u8,
+            1u8, 180u8, 5u8, 1u8, 181u8, 5u8, 1u8, 182u8, 5u8, 1u8, 183u8, 5u8, 1u8, 184u8, 5u8,
+            1u8, 185u8, 5u8, 1u8, 187u8, 5u8, 1u8, 188u8, 5u8, 1u8, 4u8, 223u8, 1u8, 5u8, 223u8,
+            1u8, 6u8, 223u8, 1u8, 8u8, 223u8, 1u8, 10u8, 223u8, 1u8, 30u8, 223u8, 1u8, 192u8, 12u8,
+            1u8, 193u8, 12u8, 1u8, 194u8, 12u8, 1u8, 195u8, 12u8, 1u8, 196u8, 12u8, 1u8, 197u8,
+            12u8, 1u8, 198u8, 12u8, 1u8, 199u8, 12u8, 1u8, 200u8, 12u8, 1u8, 201u8, 12u8, 1u8,
+            202u8, 12u8, 1u8, 203u8, 12u8, 1u8, 204u8, 12u8, 1u8, 205u8, 12u8, 1u8, 206u8, 12u8,
+            1u8, 207u8, 12u8, 1u8, 208u8, 12u8, 1u8, 209u8, 12u8, 1u8, 210u8, 12u8, 1u8, 211u8,
+            12u8, 1u8, 212u8, 12u8, 1u8, 213u8, 12u8, 1u8, 214u8, 12u8, 1u8, 215u8, 12u8, 1u8,
+            216u8, 12u8, 1u8, 217u8, 12u8, 1u8, 218u8, 12u8, 1u8, 219u8, 12u8, 1u8, 220u8, 12u8,
+            1u8, 221u8, 12u8, 1u8, 222u8, 12u8, 1u8, 223u8, 12u8, 1u8, 224u8, 12u8, 1u8, 225u8,
+            12u8, 1u8, 226u8, 12u8, 1u8, 227u8, 12u8, 1u8, 228u8, 12u8, 1u8, 229u8, 12u8, 1u8,
+            230u8, 12u8, 1u8, 231u8, 12u8, 1u8, 232u8, 12u8, 1u8, 233u8, 12u8, 1u8, 234u8, 12u8,
+            1u8, 235u8, 12u8, 1u8, 236u8, 12u8, 1u8, 237u8, 12u8, 1u8, 238u8, 12u8, 1u8, 239u8,
+            12u8, 1u8, 240u8, 12u8, 1u8, 241u8, 12u8, 1u8, 242u8, 12u8, 1u8, 192u8, 24u8, 1u8,
+            193u8, 24u8, 1u8, 194u8, 24u8, 1u8, 195u8, 24u8, 1u8, 196u8, 24u8, 1u8, 197u8, 24u8,
+            1u8, 198u8, 24u8, 1u8, 199u8, 24u8, 1u8, 200u8, 24u8, 1u8, 201u8, 24u8, 1u8, 202u8,
+            24u8, 1u8, 203u8, 24u8, 1u8, 204u8, 24u8, 1u8, 205u8, 24u8, 1u8, 206u8, 24u8, 1u8,
+            207u8, 24u8, 1u8, 208u8, 24u8, 1u8, 209u8, 24u8, 1u8, 210u8, 24u8, 1u8, 211u8, 24u8,
+            1u8, 212u8, 24u8, 1u8, 213u8, 24u8, 1u8, 214u8, 24u8, 1u8, 215u8, 24u8, 1u8, 216u8,
+            24u8, 1u8, 217u8, 24u8, 1u8, 218u8, 24u8, 1u8, 219u8, 24u8, 1u8, 220u8, 24u8, 1u8,
+            221u8, 24u8, 1u8, 222u8, 24u8, 1u8, 223u8, 24u8, 1u8, 96u8, 110u8, 1u8, 97u8, 110u8,
+            1u8, 98u8, 110u8, 1u8, 99u8, 110u8, 1u8, 100u8, 110u8, 1u8, 101u8, 110u8, 1u8, 102u8,
+            110u8, 1u8, 103u8, 110u8, 1u8, 104u8, 110u8, 1u8, 105u8, 110u8, 1u8, 106u8, 110u8, 1u8,
+            107u8, 110u8, 1u8, 108u8, 110u8, 1u8, 109u8, 110u8, 1u8, 110u8, 110u8, 1u8, 111u8,
+            110u8, 1u8, 112u8, 110u8, 1u8, 113u8, 110u8, 1u8, 114u8, 110u8, 1u8, 115u8, 110u8, 1u8,
+            116u8, 110u8, 1u8, 117u8, 110u8, 1u8, 118u8, 110u8, 1u8, 119u8, 110u8, 1u8, 120u8,
+            110u8, 1u8, 121u8, 110u8, 1u8, 122u8, 110u8, 1u8, 123u8, 110u8, 1u8, 124u8, 110u8, 1u8,
+            125u8, 110u8, 1u8, 126u8, 110u8, 1u8, 127u8, 110u8, 1u8, 34u8, 233u8, 1u8, 35u8, 233u8,
+            1u8, 36u8, 233u8, 1u8, 37u8, 233u8, 1u8, 38u8, 233u8, 1u8, 39u8, 233u8, 1u8, 40u8,
+            233u8, 1u8, 41u8, 233u8, 1u8, 42u8, 233u8, 1u8, 43u8, 233u8, 1u8, 44u8, 233u8, 1u8,
+            45u8, 233u8, 1u8, 46u8, 233u8, 1u8, 47u8, 233u8, 1u8, 48u8, 233u8, 1u8, 49u8, 233u8,
+            1u8, 50u8, 233u8, 1u8, 51u8, 233u8, 1u8, 52u8, 233u8, 1u8, 53u8, 233u8, 1u8, 54u8,
+            233u8, 1u8, 55u8, 233u8, 1u8, 56u8, 233u8, 1u8, 57u8, 233u8, 1u8, 58u8, 233u8, 1u8,
+            59u8, 233u8, 1u8, 60u8, 233u8, 1u8, 61u8, 233u8, 1u8, 62u8, 233u8, 1u8, 63u8, 233u8,
+            1u8, 64u8, 233u8, 1u8, 65u8, 233u8, 1u8, 66u8, 233u8, 1u8, 67u8, 233u8, 1u8,
+        ])
+    },
+};
@@ -0,0 +1,1948 @@
+// @generated
+#![cfg(feature = "icu_normalizer_experimental")]
+type DataStruct = < :: icu_normalizer :: provider :: NfkcSimpleCasefoldDecompositionSupplementV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_normalizer::provider::DecompositionSupplementV1 {
+    trie: ::icu_collections::codepointtrie::CodePointTrie::from_parts(
+        ::icu_collections::codepointtrie::CodePointTrieHeader {
+            high_start: 921600u32,
+            shifted12_high_start: 225u16,
+            index3_null_offset: 344u16,
+            data_null_offset: 0u32,
+            null_value: 0u32,
+            trie_type: ::icu_collections::codepointtrie::TrieType::Small,
+        },
+        unsafe {
+            ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                0u8, 0u8, 64u8, 0u8, 96u8, 0u8, 160u8, 0u8, 224u8, 0u8, 31u8, 1u8, 95u8, 1u8,
+                156u8, 1u8, 220u8, 1u8, 27u8, 2u8, 43u8, 2u8, 100u8, 2u8, 0u8, 0u8, 159u8, 2u8,
+                223u8, 2u8, 29u8, 3u8, 93u8, 3u8, 141u8, 3u8, 205u8, 3u8, 13u8, 4u8, 77u8, 4u8,
+                141u8, 4u8, 198u8, 4u8, 0u8, 0u8, 234u8, 4u8, 7u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 64u8, 5u8, 0u8, 0u8, 116u8, 5u8, 168u8, 5u8,
+                220u8, 5u8, 233u8, 5u8, 0u8, 0u8, 0u8, 0u8, 223u8, 4u8, 249u8, 4u8, 6u8, 5u8, 28u8,
+                5u8, 60u8, 5u8, 73u8, 5u8, 97u8, 5u8, 128u8, 5u8, 249u8, 4u8, 249u8, 4u8, 249u8,
+                4u8, 249u8, 4u8, 249u8, 4u8, 249u8, 4u8, 249u8, 4u8, 249u8, 4u8, 249u8, 4u8, 249u8,
+                4u8, 249u8, 4u8, 249u8, 4u8, 249u8, 4u8, 249u8, 4u8, 249u8, 4u8, 249u8, 4u8, 249u8,
+                4u8, 249u8, 4u8, 249u8, 4u8, 249u8, 4u8, 249u8, 4u8, 249u8, 4u8, 249u8, 4u8, 249u8,
+                4u8, 249u8, 4u8, 249u8, 4u8, 249u8, 4u8, 249u8, 4u8, 249u8, 4u8, 249u8, 4u8, 249u8,
+                4u8, 249u8, 4u8, 249u8, 4u8, 249u8, 4u8, 249u8, 4u8, 249u8, 4u8, 249u8, 4u8, 249u8,
+                4u8, 249u8, 4u8, 249u8, 4u8, 249u8, 4u8, 249u8, 4u8, 249u8, 4u8, 249u8, 4u8, 249u8,
+                4u8, 249u8, 4u8, 249u8, 4u8, 249u8, 4u8, 160u8, 5u8, 0u8, 0u8, 16u8, 0u8, 32u8,
+                0u8, 48u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 96u8, 0u8, 112u8, 0u8,
+                128u8, 0u8, 144u8, 0u8, 160u8, 0u8, 176u8, 0u8, 192u8, 0u8, 208u8, 0u8, 224u8, 0u8,
+                240u8, 0u8, 0u8, 1u8, 16u8, 1u8, 31u8, 1u8, 47u8, 1u8, 63u8, 1u8, 79u8, 1u8, 95u8,
+                1u8, 111u8, 1u8, 127u8, 1u8, 143u8, 1u8, 156u8, 1u8, 172u8, 1u8, 188u8, 1u8, 204u8,
+                1u8, 220u8, 1u8, 236u8, 1u8, 252u8, 1u8, 12u8, 2u8, 27u8, 2u8, 43u8, 2u8, 59u8,
+                2u8, 75u8, 2u8, 43u8, 2u8, 59u8, 2u8, 75u8, 2u8, 91u8, 2u8, 100u8, 2u8, 116u8, 2u8,
+                132u8, 2u8, 148u8, 2u8, 0u8, 0u8, 16u8, 0u8, 32u8, 0u8, 48u8, 0u8, 159u8, 2u8,
+                175u8, 2u8, 191u8, 2u8, 207u8, 2u8, 223u8, 2u8, 239u8, 2u8, 255u8, 2u8, 15u8, 3u8,
+                29u8, 3u8, 45u8, 3u8, 61u8, 3u8, 77u8, 3u8, 93u8, 3u8, 109u8, 3u8, 125u8, 3u8,
+                141u8, 3u8, 141u8, 3u8, 157u8, 3u8, 173u8, 3u8, 189u8, 3u8, 205u8, 3u8, 221u8, 3u8,
+                237u8, 3u8, 253u8, 3u8, 13u8, 4u8, 29u8, 4u8, 45u8, 4u8, 61u8, 4u8, 77u8, 4u8,
+                93u8, 4u8, 109u8, 4u8, 125u8, 4u8, 141u8, 4u8, 157u8, 4u8, 173u8, 4u8, 189u8, 4u8,
+                198u8, 4u8, 214u8, 4u8, 230u8, 4u8, 246u8, 4u8, 0u8, 0u8, 16u8, 0u8, 32u8, 0u8,
+                48u8, 0u8, 234u8, 4u8, 250u8, 4u8, 10u8, 5u8, 26u8, 5u8, 7u8, 5u8, 23u8, 5u8, 39u8,
+                5u8, 55u8, 5u8, 0u8, 0u8, 16u8, 0u8, 32u8, 0u8, 48u8, 0u8, 0u8, 0u8, 16u8, 0u8,
+                32u8, 0u8, 48u8, 0u8, 0u8, 0u8, 16u8, 0u8, 32u8, 0u8, 48u8, 0u8, 0u8, 0u8, 16u8,
+                0u8, 32u8, 0u8, 48u8, 0u8, 0u8, 0u8, 16u8, 0u8, 32u8, 0u8, 48u8, 0u8, 0u8, 0u8,
+                16u8, 0u8, 32u8, 0u8, 48u8, 0u8, 0u8, 0u8, 16u8, 0u8, 32u8, 0u8, 48u8, 0u8, 0u8,
+                0u8, 16u8, 0u8, 32u8, 0u8, 48u8, 0u8, 64u8, 5u8, 80u8, 5u8, 96u8, 5u8, 112u8, 5u8,
+                0u8, 0u8, 16u8, 0u8, 32u8, 0u8, 48u8, 0u8, 116u8, 5u8, 132u8, 5u8, 148u8, 5u8,
+                164u8, 5u8, 168u8, 5u8, 184u8, 5u8, 200u8, 5u8, 216u8, 5u8, 220u8, 5u8, 236u8, 5u8,
+                252u8, 5u8, 12u8, 6u8, 233u8, 5u8, 249u8, 5u8, 9u8, 6u8, 25u8, 6u8, 0u8, 0u8, 16u8,
+                0u8, 32u8, 0u8, 48u8, 0u8, 0u8, 0u8, 16u8, 0u8, 32u8, 0u8, 48u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 41u8, 6u8, 57u8, 6u8, 73u8, 6u8, 0u8, 0u8, 0u8, 0u8, 87u8, 6u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 247u8, 4u8, 174u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 6u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 114u8, 6u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 120u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 136u8, 6u8, 152u8, 6u8, 168u8, 6u8, 184u8, 6u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 200u8, 6u8, 216u8, 6u8,
+                232u8, 6u8, 248u8, 6u8, 8u8, 7u8, 19u8, 7u8, 0u8, 0u8, 28u8, 7u8, 44u8, 7u8, 60u8,
+                7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 76u8, 7u8, 92u8, 7u8, 108u8, 7u8,
+                124u8, 7u8, 140u8, 7u8, 156u8, 7u8, 172u8, 7u8, 188u8, 7u8, 204u8, 7u8, 220u8, 7u8,
+                236u8, 7u8, 252u8, 7u8, 12u8, 8u8, 28u8, 8u8, 44u8, 8u8, 60u8, 8u8, 75u8, 8u8,
+                91u8, 8u8, 105u8, 8u8, 121u8, 8u8, 137u8, 8u8, 151u8, 8u8, 167u8, 8u8, 0u8, 0u8,
+                183u8, 8u8, 199u8, 8u8, 215u8, 8u8, 231u8, 8u8, 247u8, 8u8, 7u8, 9u8, 23u8, 9u8,
+                38u8, 9u8, 54u8, 9u8, 70u8, 9u8, 82u8, 9u8, 98u8, 9u8, 113u8, 9u8, 123u8, 9u8,
+                139u8, 9u8, 155u8, 9u8, 171u8, 9u8, 187u8, 9u8, 200u8, 9u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 216u8, 9u8, 232u8, 9u8, 248u8, 9u8, 7u8, 10u8, 23u8,
+                10u8, 39u8, 10u8, 55u8, 10u8, 55u8, 10u8, 71u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 81u8, 10u8, 97u8, 10u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 113u8, 10u8, 129u8, 10u8, 145u8, 10u8, 161u8, 10u8, 177u8,
+                10u8, 193u8, 10u8, 75u8, 0u8, 65u8, 0u8, 209u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 220u8, 10u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 233u8, 10u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 249u8, 10u8, 9u8, 11u8,
+                25u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 41u8, 11u8, 57u8, 11u8, 73u8, 11u8, 89u8,
+                11u8, 105u8, 11u8, 121u8, 11u8, 137u8, 11u8, 153u8, 11u8, 169u8, 11u8, 183u8, 11u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 186u8, 11u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                202u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 218u8, 11u8, 234u8,
+                11u8, 250u8, 11u8, 10u8, 12u8, 26u8, 12u8, 42u8, 12u8, 58u8, 12u8, 74u8, 12u8,
+                90u8, 12u8, 106u8, 12u8, 122u8, 12u8, 138u8, 12u8, 154u8, 12u8, 170u8, 12u8, 186u8,
+                12u8, 0u8, 0u8, 0u8, 0u8, 202u8, 12u8, 0u8, 0u8, 0u8, 0u8, 212u8, 12u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 223u8, 12u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 239u8, 12u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 255u8, 12u8, 15u8,
+                13u8, 31u8, 13u8, 47u8, 13u8, 63u8, 13u8, 79u8, 13u8, 94u8, 13u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 110u8, 13u8, 126u8, 13u8, 142u8, 13u8,
+                158u8, 13u8, 174u8, 13u8, 190u8, 13u8, 206u8, 13u8, 222u8, 13u8, 238u8, 13u8,
+                254u8, 13u8, 14u8, 14u8, 30u8, 14u8, 46u8, 14u8, 62u8, 14u8, 78u8, 14u8, 94u8,
+                14u8, 110u8, 14u8, 126u8, 14u8, 142u8, 14u8, 158u8, 14u8, 174u8, 14u8, 190u8, 14u8,
+                206u8, 14u8, 222u8, 14u8, 238u8, 14u8, 254u8, 14u8, 14u8, 15u8, 30u8, 15u8, 46u8,
+                15u8, 62u8, 15u8, 78u8, 15u8, 94u8, 15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                110u8, 15u8, 126u8, 15u8, 142u8, 15u8, 0u8, 0u8, 158u8, 15u8, 174u8, 15u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 188u8,
+                15u8, 203u8, 15u8, 219u8, 15u8, 235u8, 15u8, 251u8, 15u8, 9u8, 16u8, 25u8, 16u8,
+                41u8, 16u8, 57u8, 16u8, 73u8, 16u8, 89u8, 16u8, 105u8, 16u8, 0u8, 0u8, 119u8, 16u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 129u8, 16u8, 145u8, 16u8, 161u8,
+                16u8, 177u8, 16u8, 193u8, 16u8, 209u8, 16u8, 225u8, 16u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 241u8, 16u8, 254u8, 16u8,
+                14u8, 17u8, 0u8, 0u8, 24u8, 17u8, 40u8, 17u8, 54u8, 17u8, 68u8, 17u8, 82u8, 17u8,
+                96u8, 17u8, 112u8, 17u8, 128u8, 17u8, 0u8, 0u8, 141u8, 17u8, 157u8, 17u8, 173u8,
+                17u8, 189u8, 17u8, 205u8, 17u8, 221u8, 17u8, 237u8, 17u8, 253u8, 17u8, 13u8, 18u8,
+                29u8, 18u8, 45u8, 18u8, 61u8, 18u8, 77u8, 18u8, 93u8, 18u8, 109u8, 18u8, 125u8,
+                18u8, 141u8, 18u8, 157u8, 18u8, 173u8, 18u8, 189u8, 18u8, 205u8, 18u8, 221u8, 18u8,
+                237u8, 18u8, 0u8, 0u8, 253u8, 18u8, 12u8, 19u8, 27u8, 19u8, 43u8, 19u8, 59u8, 19u8,
+                75u8, 19u8, 91u8, 19u8, 107u8, 19u8, 0u8, 0u8, 0u8, 0u8, 123u8, 19u8, 139u8, 9u8,
+                139u8, 19u8, 0u8, 0u8, 155u8, 19u8, 171u8, 19u8, 187u8, 19u8, 203u8, 19u8, 219u8,
+                19u8, 235u8, 19u8, 250u8, 19u8, 9u8, 20u8, 24u8, 20u8, 39u8, 20u8, 54u8, 20u8,
+                69u8, 20u8, 84u8, 20u8, 100u8, 20u8, 116u8, 20u8, 132u8, 20u8, 148u8, 20u8, 164u8,
+                20u8, 180u8, 20u8, 196u8, 20u8, 212u8, 20u8, 77u8, 14u8, 228u8, 20u8, 244u8, 20u8,
+                4u8, 21u8, 19u8, 21u8, 35u8, 21u8, 51u8, 21u8, 67u8, 21u8, 83u8, 21u8, 99u8, 21u8,
+                115u8, 21u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 131u8, 21u8, 147u8, 21u8, 163u8, 21u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 179u8, 21u8, 195u8, 21u8,
+                211u8, 21u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 226u8, 21u8, 242u8, 21u8, 2u8, 22u8, 18u8, 22u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 34u8, 22u8, 50u8, 22u8,
+                66u8, 22u8, 82u8, 22u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 98u8, 22u8, 114u8,
+                22u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 130u8, 22u8, 146u8, 22u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 162u8, 22u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 175u8, 22u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 65u8, 0u8, 191u8, 22u8, 71u8, 0u8, 197u8, 22u8, 213u8, 22u8,
+                229u8, 22u8, 193u8, 22u8, 73u8, 0u8, 199u8, 22u8, 241u8, 22u8, 1u8, 23u8, 17u8,
+                23u8, 33u8, 23u8, 65u8, 0u8, 191u8, 22u8, 71u8, 0u8, 45u8, 23u8, 61u8, 23u8, 67u8,
+                0u8, 41u8, 23u8, 77u8, 23u8, 93u8, 23u8, 215u8, 22u8, 69u8, 0u8, 195u8, 22u8, 75u8,
+                0u8, 65u8, 0u8, 191u8, 22u8, 71u8, 0u8, 197u8, 22u8, 213u8, 22u8, 67u8, 0u8, 193u8,
+                22u8, 73u8, 0u8, 199u8, 22u8, 215u8, 22u8, 69u8, 0u8, 195u8, 22u8, 75u8, 0u8, 65u8,
+                0u8, 191u8, 22u8, 71u8, 0u8, 109u8, 23u8, 125u8, 23u8, 141u8, 23u8, 157u8, 23u8,
+                173u8, 23u8, 131u8, 23u8, 147u8, 23u8, 163u8, 23u8, 121u8, 23u8, 137u8, 23u8,
+                153u8, 23u8, 169u8, 23u8, 127u8, 23u8, 240u8, 2u8, 159u8, 23u8, 240u8, 2u8, 133u8,
+                23u8, 149u8, 23u8, 189u8, 23u8, 205u8, 23u8, 211u8, 23u8, 207u8, 23u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 227u8, 23u8,
+                243u8, 23u8, 3u8, 24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 19u8, 24u8,
+                35u8, 24u8, 51u8, 24u8, 67u8, 24u8, 81u8, 24u8, 97u8, 24u8, 113u8, 24u8, 129u8,
+                24u8, 145u8, 24u8, 161u8, 24u8, 176u8, 24u8, 161u8, 24u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 192u8, 24u8, 173u8, 10u8, 208u8, 24u8, 65u8, 0u8, 224u8, 24u8, 0u8,
+                0u8, 240u8, 24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 25u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 25u8, 32u8, 25u8, 48u8, 25u8, 64u8, 25u8, 80u8,
+                25u8, 96u8, 25u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 112u8, 25u8, 139u8, 9u8, 139u8, 9u8, 139u8, 9u8, 139u8, 9u8, 139u8, 9u8,
+                139u8, 9u8, 139u8, 9u8, 139u8, 9u8, 139u8, 9u8, 139u8, 9u8, 139u8, 9u8, 139u8, 9u8,
+                139u8, 9u8, 139u8, 9u8, 139u8, 9u8, 139u8, 9u8, 139u8, 9u8, 139u8, 9u8, 139u8, 9u8,
+                139u8, 9u8, 139u8, 9u8, 139u8, 9u8, 139u8, 9u8, 139u8, 9u8, 139u8, 9u8, 139u8, 9u8,
+                139u8, 9u8, 139u8, 9u8, 139u8, 9u8, 139u8, 9u8, 139u8, 9u8, 139u8, 9u8, 121u8, 0u8,
+                153u8, 0u8, 185u8, 0u8, 217u8, 0u8, 225u8, 0u8, 225u8, 0u8, 225u8, 0u8, 1u8, 1u8,
+                33u8, 1u8, 56u8, 1u8, 88u8, 1u8, 93u8, 1u8, 125u8, 1u8, 88u8, 1u8, 149u8, 1u8,
+                181u8, 1u8, 213u8, 1u8, 243u8, 1u8, 13u8, 2u8, 88u8, 1u8, 88u8, 1u8, 45u8, 2u8,
+                77u8, 2u8, 100u8, 2u8, 132u8, 2u8, 164u8, 2u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8,
+                88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8,
+                1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8,
+                88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8,
+                1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 196u8, 2u8,
+                88u8, 1u8, 228u8, 2u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8,
+                1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8,
+                88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8,
+                1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8,
+                88u8, 1u8, 0u8, 3u8, 32u8, 3u8, 64u8, 3u8, 88u8, 1u8, 88u8, 1u8, 96u8, 3u8, 122u8,
+                3u8, 88u8, 1u8, 88u8, 1u8, 150u8, 3u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8,
+                88u8, 1u8, 172u8, 3u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8,
+                1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8,
+                88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8,
+                1u8, 88u8, 1u8, 88u8, 1u8, 200u8, 3u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8,
+                88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8,
+                1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8,
+                88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8,
+                1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 222u8, 3u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8,
+                88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 233u8, 3u8, 88u8, 1u8, 9u8,
+                4u8, 41u8, 4u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8,
+                88u8, 1u8, 88u8, 1u8, 73u8, 4u8, 88u8, 1u8, 88u8, 1u8, 105u8, 4u8, 121u8, 4u8,
+                153u8, 4u8, 88u8, 1u8, 88u8, 1u8, 88u8, 1u8, 159u8, 4u8, 88u8, 1u8, 88u8, 1u8,
+                191u8, 4u8, 191u8, 4u8, 191u8, 4u8, 191u8, 4u8, 191u8, 4u8, 191u8, 4u8, 191u8, 4u8,
+                191u8, 4u8,
+            ])
+        },
+        unsafe {
+            ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 97u8, 0u8, 0u8, 0u8, 98u8, 0u8, 0u8, 0u8, 99u8, 0u8, 0u8, 0u8,
+                100u8, 0u8, 0u8, 0u8, 101u8, 0u8, 0u8, 0u8, 102u8, 0u8, 0u8, 0u8, 103u8, 0u8, 0u8,
+                0u8, 104u8, 0u8, 0u8, 0u8, 105u8, 0u8, 0u8, 0u8, 106u8, 0u8, 0u8, 0u8, 107u8, 0u8,
+                0u8, 0u8, 108u8, 0u8, 0u8, 0u8, 109u8, 0u8, 0u8, 0u8, 110u8, 0u8, 0u8, 0u8, 111u8,
+                0u8, 0u8, 0u8, 112u8, 0u8, 0u8, 0u8, 113u8, 0u8, 0u8, 0u8, 114u8, 0u8, 0u8, 0u8,
+                115u8, 0u8, 0u8, 0u8, 116u8, 0u8, 0u8, 0u8, 117u8, 0u8, 0u8, 0u8, 118u8, 0u8, 0u8,
+                0u8, 119u8, 0u8, 0u8, 0u8, 120u8, 0u8, 0u8, 0u8, 121u8, 0u8, 0u8, 0u8, 122u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 32u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 32u8, 0u8, 8u8, 3u8, 0u8, 0u8, 0u8, 0u8, 97u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 255u8, 255u8, 255u8, 255u8, 0u8,
+                0u8, 0u8, 0u8, 32u8, 0u8, 4u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 50u8,
+                0u8, 0u8, 0u8, 51u8, 0u8, 0u8, 0u8, 32u8, 0u8, 1u8, 3u8, 188u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 32u8, 0u8, 39u8, 3u8, 49u8, 0u8, 0u8, 0u8,
+                111u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 175u8, 35u8, 0u8, 0u8, 178u8,
+                35u8, 0u8, 0u8, 181u8, 35u8, 0u8, 0u8, 0u8, 0u8, 97u8, 0u8, 0u8, 3u8, 97u8, 0u8,
+                1u8, 3u8, 97u8, 0u8, 2u8, 3u8, 97u8, 0u8, 3u8, 3u8, 97u8, 0u8, 8u8, 3u8, 97u8, 0u8,
+                10u8, 3u8, 230u8, 0u8, 0u8, 0u8, 99u8, 0u8, 39u8, 3u8, 101u8, 0u8, 0u8, 3u8, 101u8,
+                0u8, 1u8, 3u8, 101u8, 0u8, 2u8, 3u8, 101u8, 0u8, 8u8, 3u8, 105u8, 0u8, 0u8, 3u8,
+                105u8, 0u8, 1u8, 3u8, 105u8, 0u8, 2u8, 3u8, 105u8, 0u8, 8u8, 3u8, 240u8, 0u8, 0u8,
+                0u8, 110u8, 0u8, 3u8, 3u8, 111u8, 0u8, 0u8, 3u8, 111u8, 0u8, 1u8, 3u8, 111u8, 0u8,
+                2u8, 3u8, 111u8, 0u8, 3u8, 3u8, 111u8, 0u8, 8u8, 3u8, 0u8, 0u8, 0u8, 0u8, 248u8,
+                0u8, 0u8, 0u8, 117u8, 0u8, 0u8, 3u8, 117u8, 0u8, 1u8, 3u8, 117u8, 0u8, 2u8, 3u8,
+                117u8, 0u8, 8u8, 3u8, 121u8, 0u8, 1u8, 3u8, 254u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 97u8, 0u8, 4u8, 3u8, 0u8, 0u8, 0u8, 0u8, 97u8, 0u8, 6u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 97u8, 0u8, 40u8, 3u8, 0u8, 0u8, 0u8, 0u8, 99u8, 0u8, 1u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 99u8, 0u8, 2u8, 3u8, 0u8, 0u8, 0u8, 0u8, 99u8, 0u8, 7u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 99u8, 0u8, 12u8, 3u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 12u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 17u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 101u8, 0u8, 4u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 101u8, 0u8, 6u8, 3u8, 0u8, 0u8, 0u8, 0u8, 101u8, 0u8, 7u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 101u8, 0u8, 40u8, 3u8, 0u8, 0u8, 0u8, 0u8, 101u8, 0u8, 12u8, 3u8, 0u8,
+                0u8, 0u8, 0u8, 103u8, 0u8, 2u8, 3u8, 0u8, 0u8, 0u8, 0u8, 103u8, 0u8, 6u8, 3u8, 0u8,
+                0u8, 0u8, 0u8, 103u8, 0u8, 7u8, 3u8, 0u8, 0u8, 0u8, 0u8, 103u8, 0u8, 39u8, 3u8,
+                0u8, 0u8, 0u8, 0u8, 104u8, 0u8, 2u8, 3u8, 0u8, 0u8, 0u8, 0u8, 39u8, 1u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 105u8, 0u8, 3u8, 3u8, 0u8, 0u8, 0u8, 0u8, 105u8, 0u8, 4u8, 3u8,
+                0u8, 0u8, 0u8, 0u8, 105u8, 0u8, 6u8, 3u8, 0u8, 0u8, 0u8, 0u8, 105u8, 0u8, 40u8,
+                3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 186u8,
+                3u8, 0u8, 0u8, 186u8, 3u8, 106u8, 0u8, 2u8, 3u8, 0u8, 0u8, 0u8, 0u8, 107u8, 0u8,
+                39u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 108u8, 0u8, 1u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 108u8, 0u8, 39u8, 3u8, 0u8, 0u8, 0u8, 0u8, 108u8, 0u8, 12u8, 3u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 188u8, 3u8, 66u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                110u8, 0u8, 1u8, 3u8, 0u8, 0u8, 0u8, 0u8, 110u8, 0u8, 39u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 110u8, 0u8, 12u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 190u8, 3u8, 75u8, 1u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 111u8, 0u8, 4u8, 3u8, 0u8, 0u8, 0u8, 0u8, 111u8, 0u8,
+                6u8, 3u8, 0u8, 0u8, 0u8, 0u8, 111u8, 0u8, 11u8, 3u8, 0u8, 0u8, 0u8, 0u8, 83u8, 1u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 114u8, 0u8, 1u8, 3u8, 0u8, 0u8, 0u8, 0u8, 114u8, 0u8,
+                39u8, 3u8, 0u8, 0u8, 0u8, 0u8, 114u8, 0u8, 12u8, 3u8, 0u8, 0u8, 0u8, 0u8, 115u8,
+                0u8, 1u8, 3u8, 0u8, 0u8, 0u8, 0u8, 115u8, 0u8, 2u8, 3u8, 0u8, 0u8, 0u8, 0u8, 115u8,
+                0u8, 39u8, 3u8, 0u8, 0u8, 0u8, 0u8, 115u8, 0u8, 12u8, 3u8, 0u8, 0u8, 0u8, 0u8,
+                116u8, 0u8, 39u8, 3u8, 0u8, 0u8, 0u8, 0u8, 116u8, 0u8, 12u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 103u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 117u8, 0u8, 3u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 117u8, 0u8, 4u8, 3u8, 0u8, 0u8, 0u8, 0u8, 117u8, 0u8, 6u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 117u8, 0u8, 10u8, 3u8, 0u8, 0u8, 0u8, 0u8, 117u8, 0u8, 11u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 117u8, 0u8, 40u8, 3u8, 0u8, 0u8, 0u8, 0u8, 119u8, 0u8, 2u8, 3u8, 0u8,
+                0u8, 0u8, 0u8, 121u8, 0u8, 2u8, 3u8, 0u8, 0u8, 0u8, 0u8, 121u8, 0u8, 8u8, 3u8,
+                122u8, 0u8, 1u8, 3u8, 0u8, 0u8, 0u8, 0u8, 122u8, 0u8, 7u8, 3u8, 0u8, 0u8, 0u8, 0u8,
+                122u8, 0u8, 12u8, 3u8, 0u8, 0u8, 0u8, 0u8, 115u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 83u8, 2u8, 0u8, 0u8, 131u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 133u8, 1u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 84u8, 2u8, 0u8, 0u8, 136u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 86u8, 2u8, 0u8, 0u8, 87u8, 2u8, 0u8, 0u8, 140u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 221u8, 1u8, 0u8, 0u8, 89u8, 2u8, 0u8, 0u8, 91u8, 2u8,
+                0u8, 0u8, 146u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 96u8, 2u8, 0u8, 0u8, 99u8, 2u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 105u8, 2u8, 0u8, 0u8, 104u8, 2u8, 0u8, 0u8, 153u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 111u8,
+                2u8, 0u8, 0u8, 114u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 117u8, 2u8, 0u8, 0u8,
+                111u8, 0u8, 27u8, 3u8, 0u8, 0u8, 0u8, 0u8, 163u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 165u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 128u8, 2u8, 0u8, 0u8, 168u8, 1u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 131u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 173u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 136u8, 2u8, 0u8, 0u8, 117u8,
+                0u8, 27u8, 3u8, 0u8, 0u8, 0u8, 0u8, 138u8, 2u8, 0u8, 0u8, 139u8, 2u8, 0u8, 0u8,
+                180u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 182u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                146u8, 2u8, 0u8, 0u8, 185u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 189u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 192u8, 35u8, 0u8, 0u8, 192u8,
+                35u8, 0u8, 0u8, 192u8, 35u8, 0u8, 0u8, 195u8, 3u8, 0u8, 0u8, 195u8, 3u8, 0u8, 0u8,
+                195u8, 3u8, 0u8, 0u8, 197u8, 3u8, 0u8, 0u8, 197u8, 3u8, 0u8, 0u8, 197u8, 3u8, 97u8,
+                0u8, 12u8, 3u8, 0u8, 0u8, 0u8, 0u8, 105u8, 0u8, 12u8, 3u8, 0u8, 0u8, 0u8, 0u8,
+                111u8, 0u8, 12u8, 3u8, 0u8, 0u8, 0u8, 0u8, 117u8, 0u8, 12u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 4u8, 51u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 254u8, 50u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 248u8, 50u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 242u8, 50u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 236u8, 50u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                230u8, 50u8, 0u8, 0u8, 0u8, 0u8, 230u8, 0u8, 4u8, 3u8, 0u8, 0u8, 0u8, 0u8, 229u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 103u8, 0u8, 12u8, 3u8, 0u8, 0u8, 0u8, 0u8,
+                107u8, 0u8, 12u8, 3u8, 0u8, 0u8, 0u8, 0u8, 111u8, 0u8, 40u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 224u8, 50u8, 0u8, 0u8, 0u8, 0u8, 146u8, 2u8, 12u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 192u8, 3u8, 0u8, 0u8, 192u8, 3u8, 0u8, 0u8,
+                192u8, 3u8, 103u8, 0u8, 1u8, 3u8, 0u8, 0u8, 0u8, 0u8, 149u8, 1u8, 0u8, 0u8, 191u8,
+                1u8, 0u8, 0u8, 110u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 218u8, 50u8,
+                0u8, 0u8, 0u8, 0u8, 230u8, 0u8, 1u8, 3u8, 0u8, 0u8, 0u8, 0u8, 248u8, 0u8, 1u8, 3u8,
+                0u8, 0u8, 0u8, 0u8, 97u8, 0u8, 15u8, 3u8, 0u8, 0u8, 0u8, 0u8, 97u8, 0u8, 17u8, 3u8,
+                0u8, 0u8, 0u8, 0u8, 101u8, 0u8, 15u8, 3u8, 0u8, 0u8, 0u8, 0u8, 101u8, 0u8, 17u8,
+                3u8, 0u8, 0u8, 0u8, 0u8, 105u8, 0u8, 15u8, 3u8, 0u8, 0u8, 0u8, 0u8, 105u8, 0u8,
+                17u8, 3u8, 0u8, 0u8, 0u8, 0u8, 111u8, 0u8, 15u8, 3u8, 0u8, 0u8, 0u8, 0u8, 111u8,
+                0u8, 17u8, 3u8, 0u8, 0u8, 0u8, 0u8, 114u8, 0u8, 15u8, 3u8, 0u8, 0u8, 0u8, 0u8,
+                114u8, 0u8, 17u8, 3u8, 0u8, 0u8, 0u8, 0u8, 117u8, 0u8, 15u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 117u8, 0u8, 17u8, 3u8, 0u8, 0u8, 0u8, 0u8, 115u8, 0u8, 38u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 116u8, 0u8, 38u8, 3u8, 0u8, 0u8, 0u8, 0u8, 29u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 104u8, 0u8, 12u8, 3u8, 0u8, 0u8, 0u8, 0u8, 158u8, 1u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 35u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 37u8, 2u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 97u8, 0u8, 7u8, 3u8, 0u8, 0u8, 0u8, 0u8, 101u8, 0u8, 39u8, 3u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 212u8, 50u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 206u8, 50u8,
+                0u8, 0u8, 0u8, 0u8, 111u8, 0u8, 7u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 200u8,
+                50u8, 0u8, 0u8, 0u8, 0u8, 121u8, 0u8, 4u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 101u8, 44u8, 0u8, 0u8, 60u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 154u8, 1u8, 0u8, 0u8, 102u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 66u8, 2u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 128u8, 1u8, 0u8, 0u8, 137u8, 2u8, 0u8, 0u8, 140u8,
+                2u8, 0u8, 0u8, 71u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 73u8, 2u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 75u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 77u8, 2u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 79u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 104u8, 0u8, 0u8, 0u8, 102u8,
+                2u8, 0u8, 0u8, 106u8, 0u8, 0u8, 0u8, 114u8, 0u8, 0u8, 0u8, 121u8, 2u8, 0u8, 0u8,
+                123u8, 2u8, 0u8, 0u8, 129u8, 2u8, 0u8, 0u8, 119u8, 0u8, 0u8, 0u8, 121u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 32u8, 0u8, 6u8, 3u8, 32u8, 0u8, 7u8, 3u8, 32u8, 0u8, 10u8, 3u8, 32u8, 0u8,
+                40u8, 3u8, 32u8, 0u8, 3u8, 3u8, 32u8, 0u8, 11u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 99u8, 2u8, 0u8, 0u8, 108u8, 0u8, 0u8, 0u8, 115u8, 0u8, 0u8, 0u8, 120u8,
+                0u8, 0u8, 0u8, 149u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 185u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 255u8, 255u8, 255u8, 255u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                113u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 115u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 119u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 199u8, 3u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 243u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                32u8, 0u8, 1u8, 3u8, 0u8, 0u8, 201u8, 51u8, 177u8, 3u8, 1u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 181u8, 3u8, 1u8, 3u8, 183u8, 3u8, 1u8, 3u8, 185u8, 3u8, 1u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 191u8, 3u8, 1u8, 3u8, 0u8, 0u8, 0u8, 0u8, 197u8, 3u8, 1u8, 3u8, 201u8,
+                3u8, 1u8, 3u8, 0u8, 0u8, 0u8, 0u8, 177u8, 3u8, 0u8, 0u8, 178u8, 3u8, 0u8, 0u8,
+                179u8, 3u8, 0u8, 0u8, 180u8, 3u8, 0u8, 0u8, 181u8, 3u8, 0u8, 0u8, 182u8, 3u8, 0u8,
+                0u8, 183u8, 3u8, 0u8, 0u8, 184u8, 3u8, 0u8, 0u8, 185u8, 3u8, 0u8, 0u8, 186u8, 3u8,
+                0u8, 0u8, 187u8, 3u8, 0u8, 0u8, 188u8, 3u8, 0u8, 0u8, 189u8, 3u8, 0u8, 0u8, 190u8,
+                3u8, 0u8, 0u8, 191u8, 3u8, 0u8, 0u8, 192u8, 3u8, 0u8, 0u8, 193u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 195u8, 3u8, 0u8, 0u8, 196u8, 3u8, 0u8, 0u8, 197u8, 3u8, 0u8,
+                0u8, 198u8, 3u8, 0u8, 0u8, 199u8, 3u8, 0u8, 0u8, 200u8, 3u8, 0u8, 0u8, 201u8, 3u8,
+                0u8, 0u8, 185u8, 3u8, 8u8, 3u8, 197u8, 3u8, 8u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 195u8, 3u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 3u8,
+                0u8, 0u8, 178u8, 3u8, 0u8, 0u8, 184u8, 3u8, 0u8, 0u8, 197u8, 3u8, 0u8, 0u8, 197u8,
+                3u8, 1u8, 3u8, 197u8, 3u8, 8u8, 3u8, 198u8, 3u8, 0u8, 0u8, 192u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 217u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 219u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 221u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 223u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 225u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 227u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 229u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 231u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 233u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 235u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 237u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 239u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 186u8, 3u8, 0u8, 0u8, 193u8, 3u8, 0u8, 0u8, 195u8, 3u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 184u8, 3u8, 0u8, 0u8, 181u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 248u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 195u8, 3u8, 0u8, 0u8, 251u8, 3u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 123u8, 3u8, 0u8, 0u8, 124u8, 3u8,
+                0u8, 0u8, 125u8, 3u8, 0u8, 0u8, 53u8, 4u8, 0u8, 3u8, 53u8, 4u8, 8u8, 3u8, 82u8,
+                4u8, 0u8, 0u8, 51u8, 4u8, 1u8, 3u8, 84u8, 4u8, 0u8, 0u8, 85u8, 4u8, 0u8, 0u8, 86u8,
+                4u8, 0u8, 0u8, 86u8, 4u8, 8u8, 3u8, 88u8, 4u8, 0u8, 0u8, 89u8, 4u8, 0u8, 0u8, 90u8,
+                4u8, 0u8, 0u8, 91u8, 4u8, 0u8, 0u8, 58u8, 4u8, 1u8, 3u8, 56u8, 4u8, 0u8, 3u8, 67u8,
+                4u8, 6u8, 3u8, 95u8, 4u8, 0u8, 0u8, 48u8, 4u8, 0u8, 0u8, 49u8, 4u8, 0u8, 0u8, 50u8,
+                4u8, 0u8, 0u8, 51u8, 4u8, 0u8, 0u8, 52u8, 4u8, 0u8, 0u8, 53u8, 4u8, 0u8, 0u8, 54u8,
+                4u8, 0u8, 0u8, 55u8, 4u8, 0u8, 0u8, 56u8, 4u8, 0u8, 0u8, 56u8, 4u8, 6u8, 3u8, 58u8,
+                4u8, 0u8, 0u8, 59u8, 4u8, 0u8, 0u8, 60u8, 4u8, 0u8, 0u8, 61u8, 4u8, 0u8, 0u8, 62u8,
+                4u8, 0u8, 0u8, 63u8, 4u8, 0u8, 0u8, 64u8, 4u8, 0u8, 0u8, 65u8, 4u8, 0u8, 0u8, 66u8,
+                4u8, 0u8, 0u8, 67u8, 4u8, 0u8, 0u8, 68u8, 4u8, 0u8, 0u8, 69u8, 4u8, 0u8, 0u8, 70u8,
+                4u8, 0u8, 0u8, 71u8, 4u8, 0u8, 0u8, 72u8, 4u8, 0u8, 0u8, 73u8, 4u8, 0u8, 0u8, 74u8,
+                4u8, 0u8, 0u8, 75u8, 4u8, 0u8, 0u8, 76u8, 4u8, 0u8, 0u8, 77u8, 4u8, 0u8, 0u8, 78u8,
+                4u8, 0u8, 0u8, 79u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 99u8,
+                4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 101u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 103u8,
+                4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 105u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 107u8,
+                4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 109u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 111u8,
+                4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 113u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 115u8,
+                4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 117u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 117u8,
+                4u8, 15u8, 3u8, 0u8, 0u8, 0u8, 0u8, 121u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                123u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 125u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                127u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 129u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                139u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 141u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                143u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 145u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                147u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 149u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                151u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 153u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                155u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 157u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                159u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 161u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                163u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 165u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                167u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 169u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                171u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 173u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                175u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 177u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                179u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 181u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                183u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 185u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                187u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 189u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                191u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 207u8, 4u8, 0u8, 0u8, 54u8, 4u8, 6u8,
+                3u8, 0u8, 0u8, 0u8, 0u8, 196u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 198u8, 4u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 200u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 202u8, 4u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 204u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 206u8, 4u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 48u8, 4u8, 6u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 48u8, 4u8, 8u8, 3u8, 0u8, 0u8, 0u8, 0u8, 213u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 53u8, 4u8, 6u8, 3u8, 0u8, 0u8, 0u8, 0u8, 217u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 217u8, 4u8, 8u8, 3u8, 0u8, 0u8, 0u8, 0u8, 54u8, 4u8, 8u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 55u8, 4u8, 8u8, 3u8, 0u8, 0u8, 0u8, 0u8, 225u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 56u8, 4u8, 4u8, 3u8, 0u8, 0u8, 0u8, 0u8, 56u8, 4u8, 8u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 62u8, 4u8, 8u8, 3u8, 0u8, 0u8, 0u8, 0u8, 233u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 233u8, 4u8, 8u8, 3u8, 0u8, 0u8, 0u8, 0u8, 77u8, 4u8, 8u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 67u8, 4u8, 4u8, 3u8, 0u8, 0u8, 0u8, 0u8, 67u8, 4u8, 8u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 67u8, 4u8, 11u8, 3u8, 0u8, 0u8, 0u8, 0u8, 71u8, 4u8, 8u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 247u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 75u8, 4u8, 8u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 251u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 253u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 255u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 3u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 7u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 9u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 11u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 15u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 17u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 19u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 21u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 23u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 25u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 27u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 29u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 31u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 33u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 35u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 37u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 39u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 41u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 43u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 45u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 47u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 5u8, 0u8,
+                0u8, 98u8, 5u8, 0u8, 0u8, 99u8, 5u8, 0u8, 0u8, 100u8, 5u8, 0u8, 0u8, 101u8, 5u8,
+                0u8, 0u8, 102u8, 5u8, 0u8, 0u8, 103u8, 5u8, 0u8, 0u8, 104u8, 5u8, 0u8, 0u8, 105u8,
+                5u8, 0u8, 0u8, 106u8, 5u8, 0u8, 0u8, 107u8, 5u8, 0u8, 0u8, 108u8, 5u8, 0u8, 0u8,
+                109u8, 5u8, 0u8, 0u8, 110u8, 5u8, 0u8, 0u8, 111u8, 5u8, 0u8, 0u8, 112u8, 5u8, 0u8,
+                0u8, 113u8, 5u8, 0u8, 0u8, 114u8, 5u8, 0u8, 0u8, 115u8, 5u8, 0u8, 0u8, 116u8, 5u8,
+                0u8, 0u8, 117u8, 5u8, 0u8, 0u8, 118u8, 5u8, 0u8, 0u8, 119u8, 5u8, 0u8, 0u8, 120u8,
+                5u8, 0u8, 0u8, 121u8, 5u8, 0u8, 0u8, 122u8, 5u8, 0u8, 0u8, 123u8, 5u8, 0u8, 0u8,
+                124u8, 5u8, 0u8, 0u8, 125u8, 5u8, 0u8, 0u8, 126u8, 5u8, 0u8, 0u8, 127u8, 5u8, 0u8,
+                0u8, 128u8, 5u8, 0u8, 0u8, 129u8, 5u8, 0u8, 0u8, 130u8, 5u8, 0u8, 0u8, 131u8, 5u8,
+                0u8, 0u8, 132u8, 5u8, 0u8, 0u8, 133u8, 5u8, 0u8, 0u8, 134u8, 5u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 204u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 255u8, 255u8, 255u8, 255u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 206u8, 3u8, 0u8, 0u8, 208u8, 3u8, 0u8, 0u8, 210u8, 3u8, 0u8, 0u8, 212u8,
+                3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 214u8,
+                3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 216u8,
+                3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 218u8, 3u8, 0u8, 0u8, 220u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 11u8, 15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 222u8, 51u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 51u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 45u8, 0u8, 0u8, 1u8, 45u8, 0u8, 0u8, 2u8, 45u8,
+                0u8, 0u8, 3u8, 45u8, 0u8, 0u8, 4u8, 45u8, 0u8, 0u8, 5u8, 45u8, 0u8, 0u8, 6u8, 45u8,
+                0u8, 0u8, 7u8, 45u8, 0u8, 0u8, 8u8, 45u8, 0u8, 0u8, 9u8, 45u8, 0u8, 0u8, 10u8,
+                45u8, 0u8, 0u8, 11u8, 45u8, 0u8, 0u8, 12u8, 45u8, 0u8, 0u8, 13u8, 45u8, 0u8, 0u8,
+                14u8, 45u8, 0u8, 0u8, 15u8, 45u8, 0u8, 0u8, 16u8, 45u8, 0u8, 0u8, 17u8, 45u8, 0u8,
+                0u8, 18u8, 45u8, 0u8, 0u8, 19u8, 45u8, 0u8, 0u8, 20u8, 45u8, 0u8, 0u8, 21u8, 45u8,
+                0u8, 0u8, 22u8, 45u8, 0u8, 0u8, 23u8, 45u8, 0u8, 0u8, 24u8, 45u8, 0u8, 0u8, 25u8,
+                45u8, 0u8, 0u8, 26u8, 45u8, 0u8, 0u8, 27u8, 45u8, 0u8, 0u8, 28u8, 45u8, 0u8, 0u8,
+                29u8, 45u8, 0u8, 0u8, 30u8, 45u8, 0u8, 0u8, 31u8, 45u8, 0u8, 0u8, 32u8, 45u8, 0u8,
+                0u8, 33u8, 45u8, 0u8, 0u8, 34u8, 45u8, 0u8, 0u8, 35u8, 45u8, 0u8, 0u8, 36u8, 45u8,
+                0u8, 0u8, 37u8, 45u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 39u8, 45u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 45u8, 45u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 220u8, 16u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 240u8, 19u8, 0u8, 0u8, 241u8,
+                19u8, 0u8, 0u8, 242u8, 19u8, 0u8, 0u8, 243u8, 19u8, 0u8, 0u8, 244u8, 19u8, 0u8,
+                0u8, 245u8, 19u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 255u8, 255u8, 255u8,
+                255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8,
+                255u8, 255u8, 255u8, 255u8, 255u8, 50u8, 4u8, 0u8, 0u8, 52u8, 4u8, 0u8, 0u8, 62u8,
+                4u8, 0u8, 0u8, 65u8, 4u8, 0u8, 0u8, 66u8, 4u8, 0u8, 0u8, 66u8, 4u8, 0u8, 0u8, 74u8,
+                4u8, 0u8, 0u8, 99u8, 4u8, 0u8, 0u8, 75u8, 166u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 16u8, 0u8, 0u8, 209u8, 16u8, 0u8, 0u8,
+                210u8, 16u8, 0u8, 0u8, 211u8, 16u8, 0u8, 0u8, 212u8, 16u8, 0u8, 0u8, 213u8, 16u8,
+                0u8, 0u8, 214u8, 16u8, 0u8, 0u8, 215u8, 16u8, 0u8, 0u8, 216u8, 16u8, 0u8, 0u8,
+                217u8, 16u8, 0u8, 0u8, 218u8, 16u8, 0u8, 0u8, 219u8, 16u8, 0u8, 0u8, 220u8, 16u8,
+                0u8, 0u8, 221u8, 16u8, 0u8, 0u8, 222u8, 16u8, 0u8, 0u8, 223u8, 16u8, 0u8, 0u8,
+                224u8, 16u8, 0u8, 0u8, 225u8, 16u8, 0u8, 0u8, 226u8, 16u8, 0u8, 0u8, 227u8, 16u8,
+                0u8, 0u8, 228u8, 16u8, 0u8, 0u8, 229u8, 16u8, 0u8, 0u8, 230u8, 16u8, 0u8, 0u8,
+                231u8, 16u8, 0u8, 0u8, 232u8, 16u8, 0u8, 0u8, 233u8, 16u8, 0u8, 0u8, 234u8, 16u8,
+                0u8, 0u8, 235u8, 16u8, 0u8, 0u8, 236u8, 16u8, 0u8, 0u8, 237u8, 16u8, 0u8, 0u8,
+                238u8, 16u8, 0u8, 0u8, 239u8, 16u8, 0u8, 0u8, 240u8, 16u8, 0u8, 0u8, 241u8, 16u8,
+                0u8, 0u8, 242u8, 16u8, 0u8, 0u8, 243u8, 16u8, 0u8, 0u8, 244u8, 16u8, 0u8, 0u8,
+                245u8, 16u8, 0u8, 0u8, 246u8, 16u8, 0u8, 0u8, 247u8, 16u8, 0u8, 0u8, 248u8, 16u8,
+                0u8, 0u8, 249u8, 16u8, 0u8, 0u8, 250u8, 16u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 253u8, 16u8, 0u8, 0u8, 254u8, 16u8, 0u8, 0u8, 255u8, 16u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                97u8, 0u8, 0u8, 0u8, 230u8, 0u8, 0u8, 0u8, 98u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                100u8, 0u8, 0u8, 0u8, 101u8, 0u8, 0u8, 0u8, 221u8, 1u8, 0u8, 0u8, 103u8, 0u8, 0u8,
+                0u8, 104u8, 0u8, 0u8, 0u8, 105u8, 0u8, 0u8, 0u8, 106u8, 0u8, 0u8, 0u8, 107u8, 0u8,
+                0u8, 0u8, 108u8, 0u8, 0u8, 0u8, 109u8, 0u8, 0u8, 0u8, 110u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 111u8, 0u8, 0u8, 0u8, 35u8, 2u8, 0u8, 0u8, 112u8, 0u8, 0u8, 0u8,
+                114u8, 0u8, 0u8, 0u8, 116u8, 0u8, 0u8, 0u8, 117u8, 0u8, 0u8, 0u8, 119u8, 0u8, 0u8,
+                0u8, 97u8, 0u8, 0u8, 0u8, 80u8, 2u8, 0u8, 0u8, 81u8, 2u8, 0u8, 0u8, 2u8, 29u8, 0u8,
+                0u8, 98u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 101u8, 0u8, 0u8, 0u8, 89u8, 2u8,
+                0u8, 0u8, 91u8, 2u8, 0u8, 0u8, 92u8, 2u8, 0u8, 0u8, 103u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 107u8, 0u8, 0u8, 0u8, 109u8, 0u8, 0u8, 0u8, 75u8, 1u8, 0u8, 0u8, 111u8,
+                0u8, 0u8, 0u8, 84u8, 2u8, 0u8, 0u8, 22u8, 29u8, 0u8, 0u8, 23u8, 29u8, 0u8, 0u8,
+                112u8, 0u8, 0u8, 0u8, 116u8, 0u8, 0u8, 0u8, 117u8, 0u8, 0u8, 0u8, 29u8, 29u8, 0u8,
+                0u8, 111u8, 2u8, 0u8, 0u8, 118u8, 0u8, 0u8, 0u8, 37u8, 29u8, 0u8, 0u8, 178u8, 3u8,
+                0u8, 0u8, 179u8, 3u8, 0u8, 0u8, 180u8, 3u8, 0u8, 0u8, 198u8, 3u8, 0u8, 0u8, 199u8,
+                3u8, 0u8, 0u8, 105u8, 0u8, 0u8, 0u8, 114u8, 0u8, 0u8, 0u8, 117u8, 0u8, 0u8, 0u8,
+                118u8, 0u8, 0u8, 0u8, 178u8, 3u8, 0u8, 0u8, 179u8, 3u8, 0u8, 0u8, 193u8, 3u8, 0u8,
+                0u8, 198u8, 3u8, 0u8, 0u8, 199u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 82u8, 2u8, 0u8, 0u8, 99u8, 0u8, 0u8,
+                0u8, 85u8, 2u8, 0u8, 0u8, 240u8, 0u8, 0u8, 0u8, 92u8, 2u8, 0u8, 0u8, 102u8, 0u8,
+                0u8, 0u8, 95u8, 2u8, 0u8, 0u8, 97u8, 2u8, 0u8, 0u8, 101u8, 2u8, 0u8, 0u8, 104u8,
+                2u8, 0u8, 0u8, 105u8, 2u8, 0u8, 0u8, 106u8, 2u8, 0u8, 0u8, 123u8, 29u8, 0u8, 0u8,
+                157u8, 2u8, 0u8, 0u8, 109u8, 2u8, 0u8, 0u8, 133u8, 29u8, 0u8, 0u8, 159u8, 2u8, 0u8,
+                0u8, 113u8, 2u8, 0u8, 0u8, 112u8, 2u8, 0u8, 0u8, 114u8, 2u8, 0u8, 0u8, 115u8, 2u8,
+                0u8, 0u8, 116u8, 2u8, 0u8, 0u8, 117u8, 2u8, 0u8, 0u8, 120u8, 2u8, 0u8, 0u8, 130u8,
+                2u8, 0u8, 0u8, 131u8, 2u8, 0u8, 0u8, 171u8, 1u8, 0u8, 0u8, 137u8, 2u8, 0u8, 0u8,
+                138u8, 2u8, 0u8, 0u8, 28u8, 29u8, 0u8, 0u8, 139u8, 2u8, 0u8, 0u8, 140u8, 2u8, 0u8,
+                0u8, 122u8, 0u8, 0u8, 0u8, 144u8, 2u8, 0u8, 0u8, 145u8, 2u8, 0u8, 0u8, 146u8, 2u8,
+                0u8, 0u8, 184u8, 3u8, 0u8, 0u8, 97u8, 0u8, 37u8, 3u8, 0u8, 0u8, 0u8, 0u8, 98u8,
+                0u8, 7u8, 3u8, 0u8, 0u8, 0u8, 0u8, 98u8, 0u8, 35u8, 3u8, 0u8, 0u8, 0u8, 0u8, 98u8,
+                0u8, 49u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 104u8, 50u8, 0u8, 0u8, 0u8, 0u8,
+                100u8, 0u8, 7u8, 3u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 35u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 100u8, 0u8, 49u8, 3u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 39u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 100u8, 0u8, 45u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 98u8, 50u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 92u8, 50u8, 0u8, 0u8, 0u8, 0u8, 101u8, 0u8, 45u8, 3u8,
+                0u8, 0u8, 0u8, 0u8, 101u8, 0u8, 48u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 86u8,
+                50u8, 0u8, 0u8, 0u8, 0u8, 102u8, 0u8, 7u8, 3u8, 0u8, 0u8, 0u8, 0u8, 103u8, 0u8,
+                4u8, 3u8, 0u8, 0u8, 0u8, 0u8, 104u8, 0u8, 7u8, 3u8, 0u8, 0u8, 0u8, 0u8, 104u8, 0u8,
+                35u8, 3u8, 0u8, 0u8, 0u8, 0u8, 104u8, 0u8, 8u8, 3u8, 0u8, 0u8, 0u8, 0u8, 104u8,
+                0u8, 39u8, 3u8, 0u8, 0u8, 0u8, 0u8, 104u8, 0u8, 46u8, 3u8, 0u8, 0u8, 0u8, 0u8,
+                105u8, 0u8, 48u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 80u8, 50u8, 0u8, 0u8, 0u8,
+                0u8, 107u8, 0u8, 1u8, 3u8, 0u8, 0u8, 0u8, 0u8, 107u8, 0u8, 35u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 107u8, 0u8, 49u8, 3u8, 0u8, 0u8, 0u8, 0u8, 108u8, 0u8, 35u8, 3u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 74u8, 50u8, 0u8, 0u8, 0u8, 0u8, 108u8, 0u8, 49u8, 3u8,
+                0u8, 0u8, 0u8, 0u8, 108u8, 0u8, 45u8, 3u8, 0u8, 0u8, 0u8, 0u8, 109u8, 0u8, 1u8,
+                3u8, 0u8, 0u8, 0u8, 0u8, 109u8, 0u8, 7u8, 3u8, 0u8, 0u8, 0u8, 0u8, 109u8, 0u8,
+                35u8, 3u8, 0u8, 0u8, 0u8, 0u8, 110u8, 0u8, 7u8, 3u8, 0u8, 0u8, 0u8, 0u8, 110u8,
+                0u8, 35u8, 3u8, 0u8, 0u8, 0u8, 0u8, 110u8, 0u8, 49u8, 3u8, 0u8, 0u8, 0u8, 0u8,
+                110u8, 0u8, 45u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 68u8, 50u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 62u8, 50u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 56u8, 50u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 50u8, 50u8, 0u8, 0u8, 0u8, 0u8, 112u8, 0u8, 1u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 112u8, 0u8, 7u8, 3u8, 0u8, 0u8, 0u8, 0u8, 114u8, 0u8, 7u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 114u8, 0u8, 35u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 44u8, 50u8, 0u8, 0u8,
+                0u8, 0u8, 114u8, 0u8, 49u8, 3u8, 0u8, 0u8, 0u8, 0u8, 115u8, 0u8, 7u8, 3u8, 0u8,
+                0u8, 0u8, 0u8, 115u8, 0u8, 35u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 38u8, 50u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 32u8, 50u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 26u8, 50u8,
+                0u8, 0u8, 0u8, 0u8, 116u8, 0u8, 7u8, 3u8, 0u8, 0u8, 0u8, 0u8, 116u8, 0u8, 35u8,
+                3u8, 0u8, 0u8, 0u8, 0u8, 116u8, 0u8, 49u8, 3u8, 0u8, 0u8, 0u8, 0u8, 116u8, 0u8,
+                45u8, 3u8, 0u8, 0u8, 0u8, 0u8, 117u8, 0u8, 36u8, 3u8, 0u8, 0u8, 0u8, 0u8, 117u8,
+                0u8, 48u8, 3u8, 0u8, 0u8, 0u8, 0u8, 117u8, 0u8, 45u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 20u8, 50u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 14u8, 50u8, 0u8, 0u8, 0u8, 0u8,
+                118u8, 0u8, 3u8, 3u8, 0u8, 0u8, 0u8, 0u8, 118u8, 0u8, 35u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 119u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 119u8, 0u8, 1u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 119u8, 0u8, 8u8, 3u8, 0u8, 0u8, 0u8, 0u8, 119u8, 0u8, 7u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 119u8, 0u8, 35u8, 3u8, 0u8, 0u8, 0u8, 0u8, 120u8, 0u8, 7u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 120u8, 0u8, 8u8, 3u8, 0u8, 0u8, 0u8, 0u8, 121u8, 0u8, 7u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 122u8, 0u8, 2u8, 3u8, 0u8, 0u8, 0u8, 0u8, 122u8, 0u8, 35u8, 3u8, 0u8,
+                0u8, 0u8, 0u8, 122u8, 0u8, 49u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 228u8, 3u8, 115u8,
+                0u8, 7u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 223u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 97u8, 0u8, 35u8, 3u8, 0u8, 0u8, 0u8, 0u8, 97u8, 0u8, 9u8, 3u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 50u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 50u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 252u8, 49u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 246u8, 49u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 240u8, 49u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 234u8,
+                49u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 228u8, 49u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                222u8, 49u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 216u8, 49u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 210u8, 49u8, 0u8, 0u8, 0u8, 0u8, 101u8, 0u8, 35u8, 3u8, 0u8, 0u8, 0u8, 0u8,
+                101u8, 0u8, 9u8, 3u8, 0u8, 0u8, 0u8, 0u8, 101u8, 0u8, 3u8, 3u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 204u8, 49u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 198u8, 49u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 192u8, 49u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 186u8, 49u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 180u8, 49u8, 0u8, 0u8, 0u8, 0u8, 105u8, 0u8, 9u8, 3u8, 0u8,
+                0u8, 0u8, 0u8, 105u8, 0u8, 35u8, 3u8, 0u8, 0u8, 0u8, 0u8, 111u8, 0u8, 35u8, 3u8,
+                0u8, 0u8, 0u8, 0u8, 111u8, 0u8, 9u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 174u8,
+                49u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 168u8, 49u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                162u8, 49u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 156u8, 49u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 150u8, 49u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 144u8, 49u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 138u8, 49u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 132u8, 49u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 126u8, 49u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 120u8, 49u8, 0u8, 0u8,
+                0u8, 0u8, 117u8, 0u8, 35u8, 3u8, 0u8, 0u8, 0u8, 0u8, 117u8, 0u8, 9u8, 3u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 114u8, 49u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 108u8, 49u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 102u8, 49u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 96u8,
+                49u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 90u8, 49u8, 0u8, 0u8, 0u8, 0u8, 121u8, 0u8,
+                0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 121u8, 0u8, 35u8, 3u8, 0u8, 0u8, 0u8, 0u8, 121u8,
+                0u8, 9u8, 3u8, 0u8, 0u8, 0u8, 0u8, 121u8, 0u8, 3u8, 3u8, 0u8, 0u8, 0u8, 0u8, 251u8,
+                30u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 253u8, 30u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                255u8, 30u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 177u8, 3u8, 19u8, 3u8, 177u8, 3u8, 20u8, 3u8, 0u8, 0u8, 225u8,
+                48u8, 0u8, 0u8, 221u8, 48u8, 0u8, 0u8, 217u8, 48u8, 0u8, 0u8, 213u8, 48u8, 0u8,
+                0u8, 209u8, 48u8, 0u8, 0u8, 205u8, 48u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 181u8, 3u8, 19u8, 3u8, 181u8, 3u8, 20u8,
+                3u8, 0u8, 0u8, 87u8, 49u8, 0u8, 0u8, 84u8, 49u8, 0u8, 0u8, 81u8, 49u8, 0u8, 0u8,
+                78u8, 49u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 183u8, 3u8, 19u8, 3u8, 183u8, 3u8, 20u8, 3u8, 0u8, 0u8, 165u8, 48u8, 0u8,
+                0u8, 161u8, 48u8, 0u8, 0u8, 157u8, 48u8, 0u8, 0u8, 153u8, 48u8, 0u8, 0u8, 149u8,
+                48u8, 0u8, 0u8, 145u8, 48u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 185u8, 3u8, 19u8, 3u8, 185u8, 3u8, 20u8, 3u8, 0u8, 0u8,
+                63u8, 49u8, 0u8, 0u8, 60u8, 49u8, 0u8, 0u8, 57u8, 49u8, 0u8, 0u8, 54u8, 49u8, 0u8,
+                0u8, 51u8, 49u8, 0u8, 0u8, 48u8, 49u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 191u8, 3u8, 19u8, 3u8, 191u8, 3u8, 20u8, 3u8,
+                0u8, 0u8, 27u8, 49u8, 0u8, 0u8, 24u8, 49u8, 0u8, 0u8, 21u8, 49u8, 0u8, 0u8, 18u8,
+                49u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 197u8, 3u8, 20u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                49u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 250u8, 48u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                244u8, 48u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 201u8, 3u8, 19u8, 3u8, 201u8, 3u8, 20u8, 3u8, 0u8, 0u8, 105u8, 48u8, 0u8,
+                0u8, 101u8, 48u8, 0u8, 0u8, 97u8, 48u8, 0u8, 0u8, 93u8, 48u8, 0u8, 0u8, 89u8, 48u8,
+                0u8, 0u8, 85u8, 48u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 48u8, 0u8, 0u8, 229u8, 48u8, 0u8, 0u8, 225u8,
+                80u8, 0u8, 0u8, 221u8, 80u8, 0u8, 0u8, 217u8, 80u8, 0u8, 0u8, 213u8, 80u8, 0u8,
+                0u8, 209u8, 80u8, 0u8, 0u8, 205u8, 80u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 172u8, 48u8, 0u8, 0u8, 169u8,
+                48u8, 0u8, 0u8, 165u8, 80u8, 0u8, 0u8, 161u8, 80u8, 0u8, 0u8, 157u8, 80u8, 0u8,
+                0u8, 153u8, 80u8, 0u8, 0u8, 149u8, 80u8, 0u8, 0u8, 145u8, 80u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 112u8, 48u8,
+                0u8, 0u8, 109u8, 48u8, 0u8, 0u8, 105u8, 80u8, 0u8, 0u8, 101u8, 80u8, 0u8, 0u8,
+                97u8, 80u8, 0u8, 0u8, 93u8, 80u8, 0u8, 0u8, 89u8, 80u8, 0u8, 0u8, 85u8, 80u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 177u8,
+                3u8, 6u8, 3u8, 177u8, 3u8, 4u8, 3u8, 177u8, 3u8, 0u8, 3u8, 177u8, 3u8, 1u8, 3u8,
+                177u8, 3u8, 69u8, 3u8, 32u8, 0u8, 19u8, 3u8, 0u8, 0u8, 0u8, 0u8, 32u8, 0u8, 19u8,
+                3u8, 32u8, 0u8, 66u8, 3u8, 0u8, 0u8, 75u8, 52u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 181u8, 3u8, 0u8, 3u8, 181u8, 3u8, 1u8, 3u8, 183u8, 3u8, 0u8, 3u8, 183u8, 3u8,
+                1u8, 3u8, 183u8, 3u8, 69u8, 3u8, 0u8, 0u8, 89u8, 52u8, 0u8, 0u8, 92u8, 52u8, 0u8,
+                0u8, 95u8, 52u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 185u8, 3u8, 6u8, 3u8, 185u8, 3u8, 4u8, 3u8, 185u8, 3u8, 0u8, 3u8,
+                185u8, 3u8, 1u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 98u8, 52u8, 0u8, 0u8, 101u8,
+                52u8, 0u8, 0u8, 104u8, 52u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 197u8, 3u8, 6u8, 3u8, 197u8, 3u8, 4u8, 3u8, 197u8, 3u8,
+                0u8, 3u8, 197u8, 3u8, 1u8, 3u8, 193u8, 3u8, 20u8, 3u8, 0u8, 0u8, 107u8, 52u8, 0u8,
+                0u8, 201u8, 51u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 191u8, 3u8, 0u8, 3u8, 191u8, 3u8, 1u8, 3u8, 201u8, 3u8, 0u8, 3u8,
+                201u8, 3u8, 1u8, 3u8, 201u8, 3u8, 69u8, 3u8, 32u8, 0u8, 1u8, 3u8, 32u8, 0u8, 20u8,
+                3u8, 0u8, 0u8, 0u8, 0u8, 32u8, 0u8, 0u8, 0u8, 32u8, 0u8, 0u8, 0u8, 32u8, 0u8, 0u8,
+                0u8, 32u8, 0u8, 0u8, 0u8, 32u8, 0u8, 0u8, 0u8, 32u8, 0u8, 0u8, 0u8, 32u8, 0u8, 0u8,
+                0u8, 32u8, 0u8, 0u8, 0u8, 32u8, 0u8, 0u8, 0u8, 32u8, 0u8, 0u8, 0u8, 32u8, 0u8, 0u8,
+                0u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8,
+                255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 0u8, 0u8, 0u8, 0u8,
+                16u8, 32u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 32u8, 0u8, 51u8, 3u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 46u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 121u8, 4u8, 0u8, 0u8, 121u8, 36u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8,
+                255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8,
+                32u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 126u8, 4u8, 0u8, 0u8, 126u8, 36u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 131u8,
+                4u8, 0u8, 0u8, 131u8, 36u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 136u8, 4u8, 0u8, 0u8, 0u8, 0u8, 32u8, 0u8, 5u8,
+                3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 138u8,
+                4u8, 0u8, 0u8, 140u8, 4u8, 0u8, 0u8, 137u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 126u8, 68u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 32u8, 0u8, 0u8, 0u8, 255u8, 255u8, 255u8, 255u8, 255u8,
+                255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8,
+                255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8,
+                255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8,
+                255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8,
+                255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 48u8,
+                0u8, 0u8, 0u8, 105u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 52u8,
+                0u8, 0u8, 0u8, 53u8, 0u8, 0u8, 0u8, 54u8, 0u8, 0u8, 0u8, 55u8, 0u8, 0u8, 0u8, 56u8,
+                0u8, 0u8, 0u8, 57u8, 0u8, 0u8, 0u8, 43u8, 0u8, 0u8, 0u8, 18u8, 34u8, 0u8, 0u8,
+                61u8, 0u8, 0u8, 0u8, 40u8, 0u8, 0u8, 0u8, 41u8, 0u8, 0u8, 0u8, 110u8, 0u8, 0u8,
+                0u8, 48u8, 0u8, 0u8, 0u8, 49u8, 0u8, 0u8, 0u8, 50u8, 0u8, 0u8, 0u8, 51u8, 0u8, 0u8,
+                0u8, 52u8, 0u8, 0u8, 0u8, 53u8, 0u8, 0u8, 0u8, 54u8, 0u8, 0u8, 0u8, 55u8, 0u8, 0u8,
+                0u8, 56u8, 0u8, 0u8, 0u8, 57u8, 0u8, 0u8, 0u8, 43u8, 0u8, 0u8, 0u8, 18u8, 34u8,
+                0u8, 0u8, 61u8, 0u8, 0u8, 0u8, 40u8, 0u8, 0u8, 0u8, 41u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 97u8, 0u8, 0u8, 0u8, 101u8, 0u8, 0u8, 0u8, 111u8, 0u8, 0u8, 0u8, 120u8,
+                0u8, 0u8, 0u8, 89u8, 2u8, 0u8, 0u8, 104u8, 0u8, 0u8, 0u8, 107u8, 0u8, 0u8, 0u8,
+                108u8, 0u8, 0u8, 0u8, 109u8, 0u8, 0u8, 0u8, 110u8, 0u8, 0u8, 0u8, 112u8, 0u8, 0u8,
+                0u8, 115u8, 0u8, 0u8, 0u8, 116u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 142u8, 4u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 144u8, 36u8, 0u8, 0u8,
+                147u8, 36u8, 99u8, 0u8, 0u8, 0u8, 0u8, 0u8, 150u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 152u8, 36u8, 0u8, 0u8, 155u8, 36u8, 91u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 158u8, 4u8, 103u8, 0u8, 0u8, 0u8, 104u8, 0u8, 0u8, 0u8, 104u8, 0u8, 0u8,
+                0u8, 104u8, 0u8, 0u8, 0u8, 104u8, 0u8, 0u8, 0u8, 39u8, 1u8, 0u8, 0u8, 105u8, 0u8,
+                0u8, 0u8, 105u8, 0u8, 0u8, 0u8, 108u8, 0u8, 0u8, 0u8, 108u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 110u8, 0u8, 0u8, 0u8, 0u8, 0u8, 160u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 112u8, 0u8, 0u8, 0u8, 113u8, 0u8, 0u8, 0u8, 114u8, 0u8, 0u8, 0u8,
+                114u8, 0u8, 0u8, 0u8, 114u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 162u8, 4u8, 0u8, 0u8, 164u8, 36u8, 0u8, 0u8, 167u8, 4u8, 0u8, 0u8, 0u8,
+                0u8, 122u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 201u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 122u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 107u8, 0u8, 0u8, 0u8, 97u8, 0u8,
+                10u8, 3u8, 98u8, 0u8, 0u8, 0u8, 99u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 101u8,
+                0u8, 0u8, 0u8, 102u8, 0u8, 0u8, 0u8, 78u8, 33u8, 0u8, 0u8, 109u8, 0u8, 0u8, 0u8,
+                111u8, 0u8, 0u8, 0u8, 208u8, 5u8, 0u8, 0u8, 209u8, 5u8, 0u8, 0u8, 210u8, 5u8, 0u8,
+                0u8, 211u8, 5u8, 0u8, 0u8, 105u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                169u8, 36u8, 192u8, 3u8, 0u8, 0u8, 179u8, 3u8, 0u8, 0u8, 179u8, 3u8, 0u8, 0u8,
+                192u8, 3u8, 0u8, 0u8, 17u8, 34u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8,
+                101u8, 0u8, 0u8, 0u8, 105u8, 0u8, 0u8, 0u8, 106u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 172u8, 36u8, 0u8, 0u8, 175u8, 36u8, 0u8, 0u8,
+                178u8, 68u8, 0u8, 0u8, 182u8, 36u8, 0u8, 0u8, 185u8, 36u8, 0u8, 0u8, 188u8, 36u8,
+                0u8, 0u8, 191u8, 36u8, 0u8, 0u8, 194u8, 36u8, 0u8, 0u8, 197u8, 36u8, 0u8, 0u8,
+                200u8, 36u8, 0u8, 0u8, 203u8, 36u8, 0u8, 0u8, 206u8, 36u8, 0u8, 0u8, 209u8, 36u8,
+                0u8, 0u8, 212u8, 36u8, 0u8, 0u8, 215u8, 36u8, 0u8, 0u8, 175u8, 3u8, 105u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 218u8, 4u8, 0u8, 0u8, 218u8, 36u8, 0u8, 0u8, 223u8, 4u8, 118u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 4u8, 0u8, 0u8, 227u8, 36u8, 0u8, 0u8, 230u8, 68u8,
+                0u8, 0u8, 234u8, 4u8, 120u8, 0u8, 0u8, 0u8, 0u8, 0u8, 236u8, 4u8, 0u8, 0u8, 238u8,
+                36u8, 108u8, 0u8, 0u8, 0u8, 99u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 109u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 132u8, 33u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 241u8, 36u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 244u8, 4u8, 0u8, 0u8,
+                244u8, 36u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 249u8, 4u8, 0u8, 0u8, 249u8, 36u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 49u8, 0u8, 0u8, 0u8, 50u8,
+                0u8, 0u8, 0u8, 51u8, 0u8, 0u8, 0u8, 52u8, 0u8, 0u8, 0u8, 53u8, 0u8, 0u8, 0u8, 54u8,
+                0u8, 0u8, 0u8, 55u8, 0u8, 0u8, 0u8, 56u8, 0u8, 0u8, 0u8, 57u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 180u8, 4u8, 0u8, 0u8, 254u8, 4u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 2u8, 5u8, 0u8,
+                0u8, 4u8, 5u8, 0u8, 0u8, 6u8, 5u8, 0u8, 0u8, 8u8, 5u8, 0u8, 0u8, 10u8, 5u8, 0u8,
+                0u8, 12u8, 5u8, 0u8, 0u8, 14u8, 5u8, 0u8, 0u8, 16u8, 5u8, 0u8, 0u8, 18u8, 37u8,
+                0u8, 0u8, 21u8, 37u8, 0u8, 0u8, 24u8, 37u8, 0u8, 0u8, 27u8, 37u8, 0u8, 0u8, 30u8,
+                37u8, 0u8, 0u8, 33u8, 37u8, 0u8, 0u8, 36u8, 37u8, 0u8, 0u8, 39u8, 37u8, 0u8, 0u8,
+                42u8, 37u8, 0u8, 0u8, 45u8, 69u8, 0u8, 0u8, 49u8, 69u8, 0u8, 0u8, 53u8, 69u8, 0u8,
+                0u8, 57u8, 69u8, 0u8, 0u8, 61u8, 69u8, 0u8, 0u8, 65u8, 69u8, 0u8, 0u8, 69u8, 69u8,
+                0u8, 0u8, 73u8, 69u8, 0u8, 0u8, 77u8, 69u8, 0u8, 0u8, 81u8, 69u8, 0u8, 0u8, 85u8,
+                69u8, 0u8, 0u8, 89u8, 5u8, 0u8, 0u8, 91u8, 5u8, 0u8, 0u8, 93u8, 5u8, 0u8, 0u8,
+                95u8, 5u8, 0u8, 0u8, 97u8, 5u8, 0u8, 0u8, 99u8, 5u8, 0u8, 0u8, 101u8, 5u8, 0u8,
+                0u8, 103u8, 5u8, 0u8, 0u8, 105u8, 5u8, 0u8, 0u8, 107u8, 37u8, 0u8, 0u8, 110u8,
+                37u8, 0u8, 0u8, 113u8, 37u8, 0u8, 0u8, 116u8, 37u8, 0u8, 0u8, 119u8, 37u8, 0u8,
+                0u8, 122u8, 37u8, 0u8, 0u8, 125u8, 37u8, 0u8, 0u8, 128u8, 37u8, 0u8, 0u8, 131u8,
+                37u8, 0u8, 0u8, 134u8, 37u8, 0u8, 0u8, 137u8, 37u8, 0u8, 0u8, 140u8, 37u8, 0u8,
+                0u8, 143u8, 37u8, 0u8, 0u8, 146u8, 37u8, 0u8, 0u8, 149u8, 37u8, 0u8, 0u8, 152u8,
+                37u8, 0u8, 0u8, 155u8, 37u8, 0u8, 0u8, 158u8, 37u8, 0u8, 0u8, 161u8, 37u8, 0u8,
+                0u8, 164u8, 37u8, 0u8, 0u8, 167u8, 37u8, 0u8, 0u8, 170u8, 37u8, 0u8, 0u8, 173u8,
+                37u8, 0u8, 0u8, 176u8, 37u8, 0u8, 0u8, 179u8, 37u8, 0u8, 0u8, 182u8, 37u8, 0u8,
+                0u8, 185u8, 37u8, 0u8, 0u8, 188u8, 37u8, 0u8, 0u8, 191u8, 37u8, 0u8, 0u8, 194u8,
+                37u8, 0u8, 0u8, 197u8, 37u8, 0u8, 0u8, 200u8, 37u8, 0u8, 0u8, 203u8, 37u8, 0u8,
+                0u8, 206u8, 37u8, 0u8, 0u8, 209u8, 37u8, 0u8, 0u8, 212u8, 37u8, 0u8, 0u8, 215u8,
+                37u8, 97u8, 0u8, 0u8, 0u8, 98u8, 0u8, 0u8, 0u8, 99u8, 0u8, 0u8, 0u8, 100u8, 0u8,
+                0u8, 0u8, 101u8, 0u8, 0u8, 0u8, 102u8, 0u8, 0u8, 0u8, 103u8, 0u8, 0u8, 0u8, 104u8,
+                0u8, 0u8, 0u8, 105u8, 0u8, 0u8, 0u8, 106u8, 0u8, 0u8, 0u8, 113u8, 0u8, 0u8, 0u8,
+                114u8, 0u8, 0u8, 0u8, 115u8, 0u8, 0u8, 0u8, 116u8, 0u8, 0u8, 0u8, 117u8, 0u8, 0u8,
+                0u8, 118u8, 0u8, 0u8, 0u8, 119u8, 0u8, 0u8, 0u8, 120u8, 0u8, 0u8, 0u8, 121u8, 0u8,
+                0u8, 0u8, 122u8, 0u8, 0u8, 0u8, 48u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 244u8, 68u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                218u8, 37u8, 0u8, 0u8, 220u8, 5u8, 0u8, 0u8, 220u8, 37u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 48u8,
+                44u8, 0u8, 0u8, 49u8, 44u8, 0u8, 0u8, 50u8, 44u8, 0u8, 0u8, 51u8, 44u8, 0u8, 0u8,
+                52u8, 44u8, 0u8, 0u8, 53u8, 44u8, 0u8, 0u8, 54u8, 44u8, 0u8, 0u8, 55u8, 44u8, 0u8,
+                0u8, 56u8, 44u8, 0u8, 0u8, 57u8, 44u8, 0u8, 0u8, 58u8, 44u8, 0u8, 0u8, 59u8, 44u8,
+                0u8, 0u8, 60u8, 44u8, 0u8, 0u8, 61u8, 44u8, 0u8, 0u8, 62u8, 44u8, 0u8, 0u8, 63u8,
+                44u8, 0u8, 0u8, 64u8, 44u8, 0u8, 0u8, 65u8, 44u8, 0u8, 0u8, 66u8, 44u8, 0u8, 0u8,
+                67u8, 44u8, 0u8, 0u8, 68u8, 44u8, 0u8, 0u8, 69u8, 44u8, 0u8, 0u8, 70u8, 44u8, 0u8,
+                0u8, 71u8, 44u8, 0u8, 0u8, 72u8, 44u8, 0u8, 0u8, 73u8, 44u8, 0u8, 0u8, 74u8, 44u8,
+                0u8, 0u8, 75u8, 44u8, 0u8, 0u8, 76u8, 44u8, 0u8, 0u8, 77u8, 44u8, 0u8, 0u8, 78u8,
+                44u8, 0u8, 0u8, 79u8, 44u8, 0u8, 0u8, 80u8, 44u8, 0u8, 0u8, 81u8, 44u8, 0u8, 0u8,
+                82u8, 44u8, 0u8, 0u8, 83u8, 44u8, 0u8, 0u8, 84u8, 44u8, 0u8, 0u8, 85u8, 44u8, 0u8,
+                0u8, 86u8, 44u8, 0u8, 0u8, 87u8, 44u8, 0u8, 0u8, 88u8, 44u8, 0u8, 0u8, 89u8, 44u8,
+                0u8, 0u8, 90u8, 44u8, 0u8, 0u8, 91u8, 44u8, 0u8, 0u8, 92u8, 44u8, 0u8, 0u8, 93u8,
+                44u8, 0u8, 0u8, 94u8, 44u8, 0u8, 0u8, 95u8, 44u8, 0u8, 0u8, 97u8, 44u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 107u8, 2u8, 0u8, 0u8, 125u8, 29u8, 0u8, 0u8, 125u8, 2u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 104u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 106u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 108u8, 44u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 81u8, 2u8, 0u8, 0u8, 113u8, 2u8, 0u8, 0u8, 80u8, 2u8, 0u8, 0u8, 82u8,
+                2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 115u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 118u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 106u8,
+                0u8, 0u8, 0u8, 118u8, 0u8, 0u8, 0u8, 63u8, 2u8, 0u8, 0u8, 64u8, 2u8, 0u8, 0u8,
+                129u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 131u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 133u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 135u8, 44u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 137u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 139u8, 44u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 141u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 143u8, 44u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 145u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 147u8, 44u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 149u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 151u8, 44u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 153u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 155u8,
+                44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 157u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                159u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 161u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 163u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 165u8, 44u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 167u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 169u8, 44u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 171u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 173u8, 44u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 175u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 177u8, 44u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 179u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 181u8, 44u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 183u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 185u8,
+                44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 187u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                189u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 191u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 193u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 195u8, 44u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 197u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 199u8, 44u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 201u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 203u8, 44u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 205u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 207u8, 44u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 209u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 211u8, 44u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8,
+                44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 217u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                219u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 221u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 223u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 44u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 227u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 236u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 238u8,
+                44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 243u8, 44u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 45u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 205u8, 107u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 159u8, 159u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                78u8, 0u8, 0u8, 40u8, 78u8, 0u8, 0u8, 54u8, 78u8, 0u8, 0u8, 63u8, 78u8, 0u8, 0u8,
+                89u8, 78u8, 0u8, 0u8, 133u8, 78u8, 0u8, 0u8, 140u8, 78u8, 0u8, 0u8, 160u8, 78u8,
+                0u8, 0u8, 186u8, 78u8, 0u8, 0u8, 63u8, 81u8, 0u8, 0u8, 101u8, 81u8, 0u8, 0u8,
+                107u8, 81u8, 0u8, 0u8, 130u8, 81u8, 0u8, 0u8, 150u8, 81u8, 0u8, 0u8, 171u8, 81u8,
+                0u8, 0u8, 224u8, 81u8, 0u8, 0u8, 245u8, 81u8, 0u8, 0u8, 0u8, 82u8, 0u8, 0u8, 155u8,
+                82u8, 0u8, 0u8, 249u8, 82u8, 0u8, 0u8, 21u8, 83u8, 0u8, 0u8, 26u8, 83u8, 0u8, 0u8,
+                56u8, 83u8, 0u8, 0u8, 65u8, 83u8, 0u8, 0u8, 92u8, 83u8, 0u8, 0u8, 105u8, 83u8, 0u8,
+                0u8, 130u8, 83u8, 0u8, 0u8, 182u8, 83u8, 0u8, 0u8, 200u8, 83u8, 0u8, 0u8, 227u8,
+                83u8, 0u8, 0u8, 215u8, 86u8, 0u8, 0u8, 31u8, 87u8, 0u8, 0u8, 235u8, 88u8, 0u8, 0u8,
+                2u8, 89u8, 0u8, 0u8, 10u8, 89u8, 0u8, 0u8, 21u8, 89u8, 0u8, 0u8, 39u8, 89u8, 0u8,
+                0u8, 115u8, 89u8, 0u8, 0u8, 80u8, 91u8, 0u8, 0u8, 128u8, 91u8, 0u8, 0u8, 248u8,
+                91u8, 0u8, 0u8, 15u8, 92u8, 0u8, 0u8, 34u8, 92u8, 0u8, 0u8, 56u8, 92u8, 0u8, 0u8,
+                110u8, 92u8, 0u8, 0u8, 113u8, 92u8, 0u8, 0u8, 219u8, 93u8, 0u8, 0u8, 229u8, 93u8,
+                0u8, 0u8, 241u8, 93u8, 0u8, 0u8, 254u8, 93u8, 0u8, 0u8, 114u8, 94u8, 0u8, 0u8,
+                122u8, 94u8, 0u8, 0u8, 127u8, 94u8, 0u8, 0u8, 244u8, 94u8, 0u8, 0u8, 254u8, 94u8,
+                0u8, 0u8, 11u8, 95u8, 0u8, 0u8, 19u8, 95u8, 0u8, 0u8, 80u8, 95u8, 0u8, 0u8, 97u8,
+                95u8, 0u8, 0u8, 115u8, 95u8, 0u8, 0u8, 195u8, 95u8, 0u8, 0u8, 8u8, 98u8, 0u8, 0u8,
+                54u8, 98u8, 0u8, 0u8, 75u8, 98u8, 0u8, 0u8, 47u8, 101u8, 0u8, 0u8, 52u8, 101u8,
+                0u8, 0u8, 135u8, 101u8, 0u8, 0u8, 151u8, 101u8, 0u8, 0u8, 164u8, 101u8, 0u8, 0u8,
+                185u8, 101u8, 0u8, 0u8, 224u8, 101u8, 0u8, 0u8, 229u8, 101u8, 0u8, 0u8, 240u8,
+                102u8, 0u8, 0u8, 8u8, 103u8, 0u8, 0u8, 40u8, 103u8, 0u8, 0u8, 32u8, 107u8, 0u8,
+                0u8, 98u8, 107u8, 0u8, 0u8, 121u8, 107u8, 0u8, 0u8, 179u8, 107u8, 0u8, 0u8, 203u8,
+                107u8, 0u8, 0u8, 212u8, 107u8, 0u8, 0u8, 219u8, 107u8, 0u8, 0u8, 15u8, 108u8, 0u8,
+                0u8, 20u8, 108u8, 0u8, 0u8, 52u8, 108u8, 0u8, 0u8, 107u8, 112u8, 0u8, 0u8, 42u8,
+                114u8, 0u8, 0u8, 54u8, 114u8, 0u8, 0u8, 59u8, 114u8, 0u8, 0u8, 63u8, 114u8, 0u8,
+                0u8, 71u8, 114u8, 0u8, 0u8, 89u8, 114u8, 0u8, 0u8, 91u8, 114u8, 0u8, 0u8, 172u8,
+                114u8, 0u8, 0u8, 132u8, 115u8, 0u8, 0u8, 137u8, 115u8, 0u8, 0u8, 220u8, 116u8, 0u8,
+                0u8, 230u8, 116u8, 0u8, 0u8, 24u8, 117u8, 0u8, 0u8, 31u8, 117u8, 0u8, 0u8, 40u8,
+                117u8, 0u8, 0u8, 48u8, 117u8, 0u8, 0u8, 139u8, 117u8, 0u8, 0u8, 146u8, 117u8, 0u8,
+                0u8, 118u8, 118u8, 0u8, 0u8, 125u8, 118u8, 0u8, 0u8, 174u8, 118u8, 0u8, 0u8, 191u8,
+                118u8, 0u8, 0u8, 238u8, 118u8, 0u8, 0u8, 219u8, 119u8, 0u8, 0u8, 226u8, 119u8, 0u8,
+                0u8, 243u8, 119u8, 0u8, 0u8, 58u8, 121u8, 0u8, 0u8, 184u8, 121u8, 0u8, 0u8, 190u8,
+                121u8, 0u8, 0u8, 116u8, 122u8, 0u8, 0u8, 203u8, 122u8, 0u8, 0u8, 249u8, 122u8, 0u8,
+                0u8, 115u8, 124u8, 0u8, 0u8, 248u8, 124u8, 0u8, 0u8, 54u8, 127u8, 0u8, 0u8, 81u8,
+                127u8, 0u8, 0u8, 138u8, 127u8, 0u8, 0u8, 189u8, 127u8, 0u8, 0u8, 1u8, 128u8, 0u8,
+                0u8, 12u8, 128u8, 0u8, 0u8, 18u8, 128u8, 0u8, 0u8, 51u8, 128u8, 0u8, 0u8, 127u8,
+                128u8, 0u8, 0u8, 137u8, 128u8, 0u8, 0u8, 227u8, 129u8, 0u8, 0u8, 234u8, 129u8, 0u8,
+                0u8, 243u8, 129u8, 0u8, 0u8, 252u8, 129u8, 0u8, 0u8, 12u8, 130u8, 0u8, 0u8, 27u8,
+                130u8, 0u8, 0u8, 31u8, 130u8, 0u8, 0u8, 110u8, 130u8, 0u8, 0u8, 114u8, 130u8, 0u8,
+                0u8, 120u8, 130u8, 0u8, 0u8, 77u8, 134u8, 0u8, 0u8, 107u8, 134u8, 0u8, 0u8, 64u8,
+                136u8, 0u8, 0u8, 76u8, 136u8, 0u8, 0u8, 99u8, 136u8, 0u8, 0u8, 126u8, 137u8, 0u8,
+                0u8, 139u8, 137u8, 0u8, 0u8, 210u8, 137u8, 0u8, 0u8, 0u8, 138u8, 0u8, 0u8, 55u8,
+                140u8, 0u8, 0u8, 70u8, 140u8, 0u8, 0u8, 85u8, 140u8, 0u8, 0u8, 120u8, 140u8, 0u8,
+                0u8, 157u8, 140u8, 0u8, 0u8, 100u8, 141u8, 0u8, 0u8, 112u8, 141u8, 0u8, 0u8, 179u8,
+                141u8, 0u8, 0u8, 171u8, 142u8, 0u8, 0u8, 202u8, 142u8, 0u8, 0u8, 155u8, 143u8, 0u8,
+                0u8, 176u8, 143u8, 0u8, 0u8, 181u8, 143u8, 0u8, 0u8, 145u8, 144u8, 0u8, 0u8, 73u8,
+                145u8, 0u8, 0u8, 198u8, 145u8, 0u8, 0u8, 204u8, 145u8, 0u8, 0u8, 209u8, 145u8, 0u8,
+                0u8, 119u8, 149u8, 0u8, 0u8, 128u8, 149u8, 0u8, 0u8, 28u8, 150u8, 0u8, 0u8, 182u8,
+                150u8, 0u8, 0u8, 185u8, 150u8, 0u8, 0u8, 232u8, 150u8, 0u8, 0u8, 81u8, 151u8, 0u8,
+                0u8, 94u8, 151u8, 0u8, 0u8, 98u8, 151u8, 0u8, 0u8, 105u8, 151u8, 0u8, 0u8, 203u8,
+                151u8, 0u8, 0u8, 237u8, 151u8, 0u8, 0u8, 243u8, 151u8, 0u8, 0u8, 1u8, 152u8, 0u8,
+                0u8, 168u8, 152u8, 0u8, 0u8, 219u8, 152u8, 0u8, 0u8, 223u8, 152u8, 0u8, 0u8, 150u8,
+                153u8, 0u8, 0u8, 153u8, 153u8, 0u8, 0u8, 172u8, 153u8, 0u8, 0u8, 168u8, 154u8, 0u8,
+                0u8, 216u8, 154u8, 0u8, 0u8, 223u8, 154u8, 0u8, 0u8, 37u8, 155u8, 0u8, 0u8, 47u8,
+                155u8, 0u8, 0u8, 50u8, 155u8, 0u8, 0u8, 60u8, 155u8, 0u8, 0u8, 90u8, 155u8, 0u8,
+                0u8, 229u8, 156u8, 0u8, 0u8, 117u8, 158u8, 0u8, 0u8, 127u8, 158u8, 0u8, 0u8, 165u8,
+                158u8, 0u8, 0u8, 187u8, 158u8, 0u8, 0u8, 195u8, 158u8, 0u8, 0u8, 205u8, 158u8, 0u8,
+                0u8, 209u8, 158u8, 0u8, 0u8, 249u8, 158u8, 0u8, 0u8, 253u8, 158u8, 0u8, 0u8, 14u8,
+                159u8, 0u8, 0u8, 19u8, 159u8, 0u8, 0u8, 32u8, 159u8, 0u8, 0u8, 59u8, 159u8, 0u8,
+                0u8, 74u8, 159u8, 0u8, 0u8, 82u8, 159u8, 0u8, 0u8, 141u8, 159u8, 0u8, 0u8, 156u8,
+                159u8, 0u8, 0u8, 160u8, 159u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                32u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                18u8, 48u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 83u8, 0u8, 0u8, 68u8, 83u8, 0u8,
+                0u8, 69u8, 83u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 32u8, 0u8, 153u8, 48u8, 32u8, 0u8, 154u8, 48u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 223u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 225u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 17u8, 0u8, 0u8, 1u8, 17u8,
+                0u8, 0u8, 170u8, 17u8, 0u8, 0u8, 2u8, 17u8, 0u8, 0u8, 172u8, 17u8, 0u8, 0u8, 173u8,
+                17u8, 0u8, 0u8, 3u8, 17u8, 0u8, 0u8, 4u8, 17u8, 0u8, 0u8, 5u8, 17u8, 0u8, 0u8,
+                176u8, 17u8, 0u8, 0u8, 177u8, 17u8, 0u8, 0u8, 178u8, 17u8, 0u8, 0u8, 179u8, 17u8,
+                0u8, 0u8, 180u8, 17u8, 0u8, 0u8, 181u8, 17u8, 0u8, 0u8, 26u8, 17u8, 0u8, 0u8, 6u8,
+                17u8, 0u8, 0u8, 7u8, 17u8, 0u8, 0u8, 8u8, 17u8, 0u8, 0u8, 33u8, 17u8, 0u8, 0u8,
+                9u8, 17u8, 0u8, 0u8, 10u8, 17u8, 0u8, 0u8, 11u8, 17u8, 0u8, 0u8, 12u8, 17u8, 0u8,
+                0u8, 13u8, 17u8, 0u8, 0u8, 14u8, 17u8, 0u8, 0u8, 15u8, 17u8, 0u8, 0u8, 16u8, 17u8,
+                0u8, 0u8, 17u8, 17u8, 0u8, 0u8, 18u8, 17u8, 0u8, 0u8, 97u8, 17u8, 0u8, 0u8, 98u8,
+                17u8, 0u8, 0u8, 99u8, 17u8, 0u8, 0u8, 100u8, 17u8, 0u8, 0u8, 101u8, 17u8, 0u8, 0u8,
+                102u8, 17u8, 0u8, 0u8, 103u8, 17u8, 0u8, 0u8, 104u8, 17u8, 0u8, 0u8, 105u8, 17u8,
+                0u8, 0u8, 106u8, 17u8, 0u8, 0u8, 107u8, 17u8, 0u8, 0u8, 108u8, 17u8, 0u8, 0u8,
+                109u8, 17u8, 0u8, 0u8, 110u8, 17u8, 0u8, 0u8, 111u8, 17u8, 0u8, 0u8, 112u8, 17u8,
+                0u8, 0u8, 113u8, 17u8, 0u8, 0u8, 114u8, 17u8, 0u8, 0u8, 115u8, 17u8, 0u8, 0u8,
+                116u8, 17u8, 0u8, 0u8, 117u8, 17u8, 0u8, 0u8, 255u8, 255u8, 255u8, 255u8, 20u8,
+                17u8, 0u8, 0u8, 21u8, 17u8, 0u8, 0u8, 199u8, 17u8, 0u8, 0u8, 200u8, 17u8, 0u8, 0u8,
+                204u8, 17u8, 0u8, 0u8, 206u8, 17u8, 0u8, 0u8, 211u8, 17u8, 0u8, 0u8, 215u8, 17u8,
+                0u8, 0u8, 217u8, 17u8, 0u8, 0u8, 28u8, 17u8, 0u8, 0u8, 221u8, 17u8, 0u8, 0u8,
+                223u8, 17u8, 0u8, 0u8, 29u8, 17u8, 0u8, 0u8, 30u8, 17u8, 0u8, 0u8, 32u8, 17u8, 0u8,
+                0u8, 34u8, 17u8, 0u8, 0u8, 35u8, 17u8, 0u8, 0u8, 39u8, 17u8, 0u8, 0u8, 41u8, 17u8,
+                0u8, 0u8, 43u8, 17u8, 0u8, 0u8, 44u8, 17u8, 0u8, 0u8, 45u8, 17u8, 0u8, 0u8, 46u8,
+                17u8, 0u8, 0u8, 47u8, 17u8, 0u8, 0u8, 50u8, 17u8, 0u8, 0u8, 54u8, 17u8, 0u8, 0u8,
+                64u8, 17u8, 0u8, 0u8, 71u8, 17u8, 0u8, 0u8, 76u8, 17u8, 0u8, 0u8, 241u8, 17u8, 0u8,
+                0u8, 242u8, 17u8, 0u8, 0u8, 87u8, 17u8, 0u8, 0u8, 88u8, 17u8, 0u8, 0u8, 89u8, 17u8,
+                0u8, 0u8, 132u8, 17u8, 0u8, 0u8, 133u8, 17u8, 0u8, 0u8, 136u8, 17u8, 0u8, 0u8,
+                145u8, 17u8, 0u8, 0u8, 146u8, 17u8, 0u8, 0u8, 148u8, 17u8, 0u8, 0u8, 158u8, 17u8,
+                0u8, 0u8, 161u8, 17u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 78u8,
+                0u8, 0u8, 140u8, 78u8, 0u8, 0u8, 9u8, 78u8, 0u8, 0u8, 219u8, 86u8, 0u8, 0u8, 10u8,
+                78u8, 0u8, 0u8, 45u8, 78u8, 0u8, 0u8, 11u8, 78u8, 0u8, 0u8, 50u8, 117u8, 0u8, 0u8,
+                89u8, 78u8, 0u8, 0u8, 25u8, 78u8, 0u8, 0u8, 1u8, 78u8, 0u8, 0u8, 41u8, 89u8, 0u8,
+                0u8, 48u8, 87u8, 0u8, 0u8, 186u8, 78u8, 0u8, 0u8, 0u8, 0u8, 227u8, 37u8, 0u8, 0u8,
+                230u8, 37u8, 0u8, 0u8, 233u8, 37u8, 0u8, 0u8, 236u8, 37u8, 0u8, 0u8, 239u8, 37u8,
+                0u8, 0u8, 242u8, 37u8, 0u8, 0u8, 245u8, 37u8, 0u8, 0u8, 248u8, 37u8, 0u8, 0u8,
+                251u8, 37u8, 0u8, 0u8, 254u8, 37u8, 0u8, 0u8, 1u8, 38u8, 0u8, 0u8, 4u8, 38u8, 0u8,
+                0u8, 7u8, 38u8, 0u8, 0u8, 10u8, 38u8, 0u8, 0u8, 13u8, 70u8, 0u8, 0u8, 17u8, 70u8,
+                0u8, 0u8, 21u8, 70u8, 0u8, 0u8, 25u8, 70u8, 0u8, 0u8, 29u8, 70u8, 0u8, 0u8, 33u8,
+                70u8, 0u8, 0u8, 37u8, 70u8, 0u8, 0u8, 41u8, 70u8, 0u8, 0u8, 45u8, 70u8, 0u8, 0u8,
+                49u8, 70u8, 0u8, 0u8, 53u8, 70u8, 0u8, 0u8, 57u8, 70u8, 0u8, 0u8, 61u8, 70u8, 0u8,
+                0u8, 65u8, 70u8, 0u8, 0u8, 69u8, 70u8, 0u8, 0u8, 73u8, 166u8, 0u8, 0u8, 80u8,
+                134u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 86u8, 38u8, 0u8, 0u8, 89u8, 38u8, 0u8, 0u8,
+                92u8, 38u8, 0u8, 0u8, 95u8, 38u8, 0u8, 0u8, 98u8, 38u8, 0u8, 0u8, 101u8, 38u8, 0u8,
+                0u8, 104u8, 38u8, 0u8, 0u8, 107u8, 38u8, 0u8, 0u8, 110u8, 38u8, 0u8, 0u8, 113u8,
+                38u8, 0u8, 0u8, 116u8, 38u8, 0u8, 0u8, 119u8, 38u8, 0u8, 0u8, 122u8, 38u8, 0u8,
+                0u8, 125u8, 38u8, 0u8, 0u8, 128u8, 38u8, 0u8, 0u8, 131u8, 38u8, 0u8, 0u8, 134u8,
+                38u8, 0u8, 0u8, 137u8, 38u8, 0u8, 0u8, 140u8, 38u8, 0u8, 0u8, 143u8, 38u8, 0u8,
+                0u8, 146u8, 38u8, 0u8, 0u8, 149u8, 38u8, 0u8, 0u8, 152u8, 38u8, 0u8, 0u8, 155u8,
+                38u8, 0u8, 0u8, 158u8, 38u8, 0u8, 0u8, 161u8, 38u8, 0u8, 0u8, 164u8, 38u8, 0u8,
+                0u8, 167u8, 38u8, 0u8, 0u8, 170u8, 38u8, 0u8, 0u8, 173u8, 38u8, 0u8, 0u8, 176u8,
+                38u8, 0u8, 0u8, 179u8, 38u8, 0u8, 0u8, 182u8, 38u8, 0u8, 0u8, 185u8, 38u8, 0u8,
+                0u8, 188u8, 38u8, 0u8, 0u8, 191u8, 38u8, 79u8, 85u8, 0u8, 0u8, 124u8, 94u8, 0u8,
+                0u8, 135u8, 101u8, 0u8, 0u8, 143u8, 123u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 194u8, 38u8, 0u8, 0u8,
+                1u8, 5u8, 0u8, 0u8, 197u8, 6u8, 0u8, 0u8, 180u8, 3u8, 0u8, 0u8, 199u8, 6u8, 0u8,
+                0u8, 201u8, 6u8, 0u8, 0u8, 203u8, 6u8, 0u8, 0u8, 205u8, 6u8, 0u8, 0u8, 207u8, 6u8,
+                0u8, 0u8, 209u8, 6u8, 0u8, 0u8, 211u8, 6u8, 0u8, 0u8, 187u8, 4u8, 0u8, 0u8, 184u8,
+                4u8, 0u8, 0u8, 213u8, 6u8, 0u8, 0u8, 215u8, 6u8, 0u8, 0u8, 217u8, 6u8, 0u8, 17u8,
+                0u8, 0u8, 2u8, 17u8, 0u8, 0u8, 3u8, 17u8, 0u8, 0u8, 5u8, 17u8, 0u8, 0u8, 6u8, 17u8,
+                0u8, 0u8, 7u8, 17u8, 0u8, 0u8, 9u8, 17u8, 0u8, 0u8, 11u8, 17u8, 0u8, 0u8, 12u8,
+                17u8, 0u8, 0u8, 14u8, 17u8, 0u8, 0u8, 15u8, 17u8, 0u8, 0u8, 16u8, 17u8, 0u8, 0u8,
+                17u8, 17u8, 0u8, 0u8, 18u8, 17u8, 0u8, 0u8, 0u8, 0u8, 14u8, 6u8, 0u8, 0u8, 18u8,
+                6u8, 0u8, 0u8, 22u8, 6u8, 0u8, 0u8, 26u8, 6u8, 0u8, 0u8, 30u8, 6u8, 0u8, 0u8, 34u8,
+                6u8, 0u8, 0u8, 38u8, 6u8, 0u8, 0u8, 42u8, 6u8, 0u8, 0u8, 46u8, 6u8, 0u8, 0u8, 50u8,
+                6u8, 0u8, 0u8, 54u8, 6u8, 0u8, 0u8, 58u8, 6u8, 0u8, 0u8, 62u8, 6u8, 0u8, 0u8, 66u8,
+                6u8, 0u8, 0u8, 219u8, 102u8, 0u8, 0u8, 224u8, 70u8, 0u8, 0u8, 228u8, 6u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 78u8, 0u8, 0u8, 140u8, 78u8, 0u8, 0u8, 9u8, 78u8, 0u8, 0u8, 219u8,
+                86u8, 0u8, 0u8, 148u8, 78u8, 0u8, 0u8, 109u8, 81u8, 0u8, 0u8, 3u8, 78u8, 0u8, 0u8,
+                107u8, 81u8, 0u8, 0u8, 93u8, 78u8, 0u8, 0u8, 65u8, 83u8, 0u8, 0u8, 8u8, 103u8, 0u8,
+                0u8, 107u8, 112u8, 0u8, 0u8, 52u8, 108u8, 0u8, 0u8, 40u8, 103u8, 0u8, 0u8, 209u8,
+                145u8, 0u8, 0u8, 31u8, 87u8, 0u8, 0u8, 229u8, 101u8, 0u8, 0u8, 42u8, 104u8, 0u8,
+                0u8, 9u8, 103u8, 0u8, 0u8, 62u8, 121u8, 0u8, 0u8, 13u8, 84u8, 0u8, 0u8, 121u8,
+                114u8, 0u8, 0u8, 161u8, 140u8, 0u8, 0u8, 93u8, 121u8, 0u8, 0u8, 180u8, 82u8, 0u8,
+                0u8, 216u8, 121u8, 0u8, 0u8, 55u8, 117u8, 0u8, 0u8, 115u8, 89u8, 0u8, 0u8, 105u8,
+                144u8, 0u8, 0u8, 42u8, 81u8, 0u8, 0u8, 112u8, 83u8, 0u8, 0u8, 232u8, 108u8, 0u8,
+                0u8, 5u8, 152u8, 0u8, 0u8, 17u8, 79u8, 0u8, 0u8, 153u8, 81u8, 0u8, 0u8, 99u8,
+                107u8, 0u8, 0u8, 10u8, 78u8, 0u8, 0u8, 45u8, 78u8, 0u8, 0u8, 11u8, 78u8, 0u8, 0u8,
+                230u8, 93u8, 0u8, 0u8, 243u8, 83u8, 0u8, 0u8, 59u8, 83u8, 0u8, 0u8, 151u8, 91u8,
+                0u8, 0u8, 102u8, 91u8, 0u8, 0u8, 227u8, 118u8, 0u8, 0u8, 1u8, 79u8, 0u8, 0u8,
+                199u8, 140u8, 0u8, 0u8, 84u8, 83u8, 0u8, 0u8, 28u8, 89u8, 0u8, 0u8, 0u8, 0u8,
+                230u8, 6u8, 0u8, 0u8, 232u8, 6u8, 0u8, 0u8, 234u8, 6u8, 0u8, 0u8, 236u8, 6u8, 0u8,
+                0u8, 238u8, 6u8, 0u8, 0u8, 177u8, 3u8, 0u8, 0u8, 200u8, 6u8, 0u8, 0u8, 216u8, 6u8,
+                0u8, 0u8, 240u8, 6u8, 0u8, 0u8, 242u8, 6u8, 0u8, 0u8, 244u8, 6u8, 0u8, 0u8, 246u8,
+                6u8, 0u8, 0u8, 248u8, 6u8, 0u8, 0u8, 250u8, 6u8, 0u8, 0u8, 252u8, 6u8, 0u8, 0u8,
+                254u8, 6u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 2u8, 7u8, 0u8, 0u8, 4u8, 7u8, 0u8, 0u8,
+                6u8, 7u8, 0u8, 0u8, 8u8, 7u8, 0u8, 0u8, 10u8, 7u8, 0u8, 0u8, 12u8, 7u8, 0u8, 0u8,
+                14u8, 7u8, 0u8, 0u8, 16u8, 39u8, 0u8, 0u8, 19u8, 39u8, 0u8, 0u8, 22u8, 39u8, 0u8,
+                0u8, 25u8, 7u8, 0u8, 0u8, 27u8, 39u8, 0u8, 0u8, 30u8, 7u8, 0u8, 0u8, 32u8, 39u8,
+                162u8, 48u8, 0u8, 0u8, 164u8, 48u8, 0u8, 0u8, 166u8, 48u8, 0u8, 0u8, 168u8, 48u8,
+                0u8, 0u8, 170u8, 48u8, 0u8, 0u8, 171u8, 48u8, 0u8, 0u8, 173u8, 48u8, 0u8, 0u8,
+                175u8, 48u8, 0u8, 0u8, 177u8, 48u8, 0u8, 0u8, 179u8, 48u8, 0u8, 0u8, 181u8, 48u8,
+                0u8, 0u8, 183u8, 48u8, 0u8, 0u8, 185u8, 48u8, 0u8, 0u8, 187u8, 48u8, 0u8, 0u8,
+                189u8, 48u8, 0u8, 0u8, 191u8, 48u8, 0u8, 0u8, 193u8, 48u8, 0u8, 0u8, 196u8, 48u8,
+                0u8, 0u8, 198u8, 48u8, 0u8, 0u8, 200u8, 48u8, 0u8, 0u8, 202u8, 48u8, 0u8, 0u8,
+                203u8, 48u8, 0u8, 0u8, 204u8, 48u8, 0u8, 0u8, 205u8, 48u8, 0u8, 0u8, 206u8, 48u8,
+                0u8, 0u8, 207u8, 48u8, 0u8, 0u8, 210u8, 48u8, 0u8, 0u8, 213u8, 48u8, 0u8, 0u8,
+                216u8, 48u8, 0u8, 0u8, 219u8, 48u8, 0u8, 0u8, 222u8, 48u8, 0u8, 0u8, 223u8, 48u8,
+                0u8, 0u8, 224u8, 48u8, 0u8, 0u8, 225u8, 48u8, 0u8, 0u8, 226u8, 48u8, 0u8, 0u8,
+                228u8, 48u8, 0u8, 0u8, 230u8, 48u8, 0u8, 0u8, 232u8, 48u8, 0u8, 0u8, 233u8, 48u8,
+                0u8, 0u8, 234u8, 48u8, 0u8, 0u8, 235u8, 48u8, 0u8, 0u8, 236u8, 48u8, 0u8, 0u8,
+                237u8, 48u8, 0u8, 0u8, 239u8, 48u8, 0u8, 0u8, 240u8, 48u8, 0u8, 0u8, 241u8, 48u8,
+                0u8, 0u8, 242u8, 48u8, 0u8, 0u8, 0u8, 0u8, 35u8, 7u8, 0u8, 0u8, 37u8, 103u8, 0u8,
+                0u8, 42u8, 71u8, 0u8, 0u8, 46u8, 103u8, 0u8, 0u8, 51u8, 39u8, 0u8, 0u8, 54u8,
+                103u8, 0u8, 0u8, 59u8, 39u8, 0u8, 0u8, 62u8, 39u8, 0u8, 0u8, 65u8, 135u8, 0u8, 0u8,
+                71u8, 71u8, 0u8, 0u8, 75u8, 39u8, 0u8, 0u8, 78u8, 39u8, 0u8, 0u8, 81u8, 39u8, 0u8,
+                0u8, 84u8, 71u8, 0u8, 0u8, 88u8, 71u8, 0u8, 0u8, 92u8, 71u8, 0u8, 0u8, 96u8, 71u8,
+                0u8, 0u8, 100u8, 71u8, 0u8, 0u8, 104u8, 71u8, 0u8, 0u8, 108u8, 71u8, 0u8, 0u8,
+                112u8, 135u8, 0u8, 0u8, 118u8, 7u8, 0u8, 0u8, 120u8, 135u8, 0u8, 0u8, 126u8, 135u8,
+                0u8, 0u8, 132u8, 103u8, 0u8, 0u8, 122u8, 71u8, 0u8, 0u8, 137u8, 135u8, 0u8, 0u8,
+                143u8, 135u8, 0u8, 0u8, 149u8, 71u8, 0u8, 0u8, 153u8, 39u8, 0u8, 0u8, 156u8, 39u8,
+                0u8, 0u8, 159u8, 71u8, 0u8, 0u8, 163u8, 71u8, 0u8, 0u8, 167u8, 103u8, 0u8, 0u8,
+                172u8, 103u8, 0u8, 0u8, 177u8, 39u8, 0u8, 0u8, 180u8, 39u8, 0u8, 0u8, 183u8, 71u8,
+                0u8, 0u8, 187u8, 39u8, 0u8, 0u8, 190u8, 39u8, 0u8, 0u8, 141u8, 7u8, 0u8, 0u8,
+                193u8, 7u8, 0u8, 0u8, 195u8, 39u8, 0u8, 0u8, 198u8, 39u8, 0u8, 0u8, 201u8, 135u8,
+                0u8, 0u8, 207u8, 71u8, 0u8, 0u8, 211u8, 103u8, 0u8, 0u8, 216u8, 135u8, 0u8, 0u8,
+                222u8, 71u8, 0u8, 0u8, 226u8, 39u8, 0u8, 0u8, 229u8, 39u8, 0u8, 0u8, 232u8, 135u8,
+                0u8, 0u8, 238u8, 71u8, 0u8, 0u8, 242u8, 135u8, 0u8, 0u8, 248u8, 39u8, 0u8, 0u8,
+                251u8, 103u8, 0u8, 0u8, 0u8, 40u8, 0u8, 0u8, 3u8, 72u8, 0u8, 0u8, 7u8, 40u8, 0u8,
+                0u8, 10u8, 72u8, 0u8, 0u8, 14u8, 104u8, 0u8, 0u8, 19u8, 72u8, 0u8, 0u8, 23u8,
+                104u8, 0u8, 0u8, 28u8, 72u8, 0u8, 0u8, 32u8, 8u8, 0u8, 0u8, 34u8, 104u8, 0u8, 0u8,
+                39u8, 40u8, 0u8, 0u8, 42u8, 40u8, 0u8, 0u8, 45u8, 72u8, 0u8, 0u8, 49u8, 40u8, 0u8,
+                0u8, 52u8, 40u8, 0u8, 0u8, 55u8, 40u8, 0u8, 0u8, 58u8, 104u8, 0u8, 0u8, 63u8, 72u8,
+                0u8, 0u8, 67u8, 8u8, 0u8, 0u8, 69u8, 136u8, 0u8, 0u8, 75u8, 40u8, 0u8, 0u8, 78u8,
+                104u8, 0u8, 0u8, 128u8, 71u8, 0u8, 0u8, 83u8, 72u8, 0u8, 0u8, 87u8, 40u8, 0u8, 0u8,
+                90u8, 40u8, 0u8, 0u8, 93u8, 72u8, 0u8, 0u8, 97u8, 8u8, 0u8, 0u8, 99u8, 72u8, 0u8,
+                0u8, 103u8, 104u8, 0u8, 0u8, 108u8, 8u8, 0u8, 0u8, 110u8, 136u8, 0u8, 0u8, 134u8,
+                39u8, 0u8, 0u8, 116u8, 8u8, 0u8, 0u8, 118u8, 8u8, 0u8, 0u8, 120u8, 8u8, 0u8, 0u8,
+                122u8, 8u8, 0u8, 0u8, 124u8, 8u8, 0u8, 0u8, 126u8, 8u8, 0u8, 0u8, 128u8, 8u8, 0u8,
+                0u8, 130u8, 8u8, 0u8, 0u8, 132u8, 8u8, 0u8, 0u8, 134u8, 8u8, 0u8, 0u8, 136u8, 40u8,
+                0u8, 0u8, 139u8, 40u8, 0u8, 0u8, 142u8, 40u8, 0u8, 0u8, 145u8, 40u8, 0u8, 0u8,
+                148u8, 40u8, 0u8, 0u8, 151u8, 40u8, 0u8, 0u8, 154u8, 40u8, 0u8, 0u8, 157u8, 40u8,
+                0u8, 0u8, 160u8, 40u8, 0u8, 0u8, 163u8, 40u8, 0u8, 0u8, 166u8, 40u8, 0u8, 0u8,
+                169u8, 40u8, 0u8, 0u8, 172u8, 40u8, 0u8, 0u8, 175u8, 40u8, 0u8, 0u8, 178u8, 40u8,
+                0u8, 0u8, 181u8, 40u8, 0u8, 0u8, 184u8, 8u8, 0u8, 0u8, 186u8, 8u8, 0u8, 0u8, 188u8,
+                40u8, 0u8, 0u8, 191u8, 8u8, 0u8, 0u8, 193u8, 8u8, 0u8, 0u8, 195u8, 8u8, 0u8, 0u8,
+                197u8, 40u8, 0u8, 0u8, 200u8, 40u8, 0u8, 0u8, 203u8, 8u8, 0u8, 0u8, 205u8, 8u8,
+                0u8, 0u8, 207u8, 8u8, 0u8, 0u8, 209u8, 8u8, 0u8, 0u8, 211u8, 8u8, 0u8, 0u8, 213u8,
+                72u8, 0u8, 0u8, 182u8, 8u8, 0u8, 0u8, 217u8, 8u8, 0u8, 0u8, 219u8, 8u8, 0u8, 0u8,
+                221u8, 8u8, 0u8, 0u8, 223u8, 8u8, 0u8, 0u8, 225u8, 8u8, 0u8, 0u8, 227u8, 8u8, 0u8,
+                0u8, 229u8, 8u8, 0u8, 0u8, 231u8, 40u8, 0u8, 0u8, 234u8, 72u8, 0u8, 0u8, 238u8,
+                8u8, 0u8, 0u8, 240u8, 8u8, 0u8, 0u8, 242u8, 8u8, 0u8, 0u8, 244u8, 8u8, 0u8, 0u8,
+                246u8, 8u8, 0u8, 0u8, 248u8, 8u8, 0u8, 0u8, 250u8, 8u8, 0u8, 0u8, 252u8, 40u8, 0u8,
+                0u8, 255u8, 40u8, 0u8, 0u8, 249u8, 40u8, 0u8, 0u8, 2u8, 41u8, 0u8, 0u8, 5u8, 9u8,
+                0u8, 0u8, 7u8, 9u8, 0u8, 0u8, 9u8, 9u8, 0u8, 0u8, 11u8, 9u8, 0u8, 0u8, 13u8, 9u8,
+                0u8, 0u8, 15u8, 9u8, 0u8, 0u8, 17u8, 9u8, 0u8, 0u8, 18u8, 9u8, 0u8, 0u8, 21u8, 9u8,
+                0u8, 0u8, 23u8, 9u8, 0u8, 0u8, 25u8, 41u8, 0u8, 0u8, 28u8, 41u8, 0u8, 0u8, 198u8,
+                8u8, 0u8, 0u8, 31u8, 41u8, 0u8, 0u8, 34u8, 41u8, 0u8, 0u8, 37u8, 41u8, 0u8, 0u8,
+                201u8, 8u8, 0u8, 0u8, 40u8, 41u8, 0u8, 0u8, 43u8, 41u8, 0u8, 0u8, 46u8, 73u8, 0u8,
+                0u8, 182u8, 8u8, 0u8, 0u8, 50u8, 41u8, 0u8, 0u8, 53u8, 41u8, 0u8, 0u8, 56u8, 41u8,
+                0u8, 0u8, 59u8, 41u8, 0u8, 0u8, 62u8, 105u8, 0u8, 0u8, 67u8, 137u8, 0u8, 0u8, 73u8,
+                9u8, 0u8, 0u8, 75u8, 9u8, 0u8, 0u8, 77u8, 9u8, 0u8, 0u8, 79u8, 9u8, 0u8, 0u8, 81u8,
+                9u8, 0u8, 0u8, 83u8, 9u8, 0u8, 0u8, 85u8, 9u8, 0u8, 0u8, 87u8, 9u8, 0u8, 0u8, 89u8,
+                9u8, 0u8, 0u8, 87u8, 9u8, 0u8, 0u8, 91u8, 9u8, 0u8, 0u8, 93u8, 9u8, 0u8, 0u8, 95u8,
+                9u8, 0u8, 0u8, 97u8, 9u8, 0u8, 0u8, 99u8, 9u8, 0u8, 0u8, 97u8, 9u8, 0u8, 0u8,
+                101u8, 9u8, 0u8, 0u8, 103u8, 9u8, 0u8, 0u8, 105u8, 73u8, 0u8, 0u8, 109u8, 9u8, 0u8,
+                0u8, 151u8, 4u8, 0u8, 0u8, 194u8, 8u8, 0u8, 0u8, 111u8, 73u8, 0u8, 0u8, 115u8,
+                41u8, 0u8, 0u8, 118u8, 9u8, 0u8, 0u8, 120u8, 9u8, 0u8, 0u8, 122u8, 9u8, 0u8, 0u8,
+                181u8, 8u8, 0u8, 0u8, 124u8, 9u8, 0u8, 0u8, 126u8, 9u8, 0u8, 0u8, 23u8, 9u8, 0u8,
+                0u8, 128u8, 9u8, 0u8, 0u8, 6u8, 9u8, 0u8, 0u8, 130u8, 9u8, 0u8, 0u8, 132u8, 41u8,
+                0u8, 0u8, 135u8, 9u8, 0u8, 0u8, 227u8, 8u8, 0u8, 0u8, 137u8, 41u8, 0u8, 0u8, 140u8,
+                41u8, 0u8, 0u8, 143u8, 9u8, 0u8, 0u8, 145u8, 73u8, 0u8, 0u8, 149u8, 41u8, 0u8, 0u8,
+                152u8, 9u8, 0u8, 0u8, 66u8, 9u8, 0u8, 0u8, 154u8, 9u8, 0u8, 0u8, 156u8, 9u8, 0u8,
+                0u8, 158u8, 41u8, 0u8, 0u8, 161u8, 41u8, 0u8, 0u8, 164u8, 9u8, 0u8, 0u8, 166u8,
+                9u8, 0u8, 0u8, 168u8, 9u8, 0u8, 0u8, 170u8, 9u8, 0u8, 0u8, 172u8, 9u8, 0u8, 0u8,
+                174u8, 9u8, 0u8, 0u8, 176u8, 9u8, 0u8, 0u8, 178u8, 9u8, 0u8, 0u8, 180u8, 9u8, 0u8,
+                0u8, 182u8, 41u8, 0u8, 0u8, 185u8, 41u8, 0u8, 0u8, 188u8, 41u8, 0u8, 0u8, 191u8,
+                41u8, 0u8, 0u8, 194u8, 41u8, 0u8, 0u8, 197u8, 41u8, 0u8, 0u8, 200u8, 41u8, 0u8,
+                0u8, 203u8, 41u8, 0u8, 0u8, 206u8, 41u8, 0u8, 0u8, 209u8, 41u8, 0u8, 0u8, 212u8,
+                41u8, 0u8, 0u8, 215u8, 41u8, 0u8, 0u8, 218u8, 41u8, 0u8, 0u8, 221u8, 41u8, 0u8,
+                0u8, 224u8, 41u8, 0u8, 0u8, 227u8, 41u8, 0u8, 0u8, 230u8, 41u8, 0u8, 0u8, 233u8,
+                41u8, 0u8, 0u8, 236u8, 41u8, 0u8, 0u8, 239u8, 41u8, 0u8, 0u8, 242u8, 41u8, 0u8,
+                0u8, 245u8, 41u8, 0u8, 0u8, 248u8, 41u8, 65u8, 166u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                67u8, 166u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 69u8, 166u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 71u8, 166u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 73u8, 166u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 75u8, 166u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 77u8, 166u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 79u8, 166u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 81u8, 166u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 83u8, 166u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 85u8, 166u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 87u8, 166u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 89u8, 166u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 91u8, 166u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 93u8,
+                166u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 95u8, 166u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                97u8, 166u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 99u8, 166u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 101u8, 166u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 103u8, 166u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 105u8, 166u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 107u8, 166u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 109u8, 166u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 129u8, 166u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 131u8, 166u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 133u8, 166u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 135u8, 166u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 137u8, 166u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 139u8, 166u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 141u8, 166u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 143u8,
+                166u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 145u8, 166u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                147u8, 166u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 149u8, 166u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 151u8, 166u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 153u8, 166u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 155u8, 166u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 74u8, 4u8, 0u8, 0u8, 76u8,
+                4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 35u8, 167u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 37u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 39u8, 167u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 41u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 43u8, 167u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 45u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 47u8, 167u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 51u8, 167u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 53u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 55u8, 167u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 57u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 59u8, 167u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 61u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 63u8, 167u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 67u8, 167u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 69u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 71u8,
+                167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 73u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                75u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 77u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 79u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 81u8, 167u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 83u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 85u8, 167u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 87u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 89u8, 167u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 91u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 93u8, 167u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 95u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 167u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 99u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 101u8,
+                167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 103u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                105u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 107u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 109u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 111u8, 167u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 122u8, 167u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 124u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 121u8,
+                29u8, 0u8, 0u8, 127u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 129u8, 167u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 131u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 133u8, 167u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 135u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 140u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 101u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 145u8, 167u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 147u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 151u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 153u8,
+                167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 155u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                157u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 159u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 161u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 163u8, 167u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 165u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 167u8, 167u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 169u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 102u8, 2u8, 0u8, 0u8,
+                92u8, 2u8, 0u8, 0u8, 97u8, 2u8, 0u8, 0u8, 108u8, 2u8, 0u8, 0u8, 106u8, 2u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 158u8, 2u8, 0u8, 0u8, 135u8, 2u8, 0u8, 0u8, 157u8, 2u8,
+                0u8, 0u8, 83u8, 171u8, 0u8, 0u8, 181u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 183u8,
+                167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 185u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                187u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 189u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 191u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 193u8, 167u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 195u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 148u8, 167u8, 0u8, 0u8,
+                130u8, 2u8, 0u8, 0u8, 142u8, 29u8, 0u8, 0u8, 200u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 202u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 209u8, 167u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 167u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 217u8, 167u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 99u8, 0u8,
+                0u8, 0u8, 102u8, 0u8, 0u8, 0u8, 113u8, 0u8, 0u8, 0u8, 246u8, 167u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 39u8, 1u8, 0u8, 0u8, 83u8, 1u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 39u8,
+                167u8, 0u8, 0u8, 55u8, 171u8, 0u8, 0u8, 107u8, 2u8, 0u8, 0u8, 82u8, 171u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 141u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 160u8, 19u8, 0u8, 0u8, 161u8, 19u8, 0u8, 0u8, 162u8, 19u8, 0u8, 0u8, 163u8,
+                19u8, 0u8, 0u8, 164u8, 19u8, 0u8, 0u8, 165u8, 19u8, 0u8, 0u8, 166u8, 19u8, 0u8,
+                0u8, 167u8, 19u8, 0u8, 0u8, 168u8, 19u8, 0u8, 0u8, 169u8, 19u8, 0u8, 0u8, 170u8,
+                19u8, 0u8, 0u8, 171u8, 19u8, 0u8, 0u8, 172u8, 19u8, 0u8, 0u8, 173u8, 19u8, 0u8,
+                0u8, 174u8, 19u8, 0u8, 0u8, 175u8, 19u8, 0u8, 0u8, 176u8, 19u8, 0u8, 0u8, 177u8,
+                19u8, 0u8, 0u8, 178u8, 19u8, 0u8, 0u8, 179u8, 19u8, 0u8, 0u8, 180u8, 19u8, 0u8,
+                0u8, 181u8, 19u8, 0u8, 0u8, 182u8, 19u8, 0u8, 0u8, 183u8, 19u8, 0u8, 0u8, 184u8,
+                19u8, 0u8, 0u8, 185u8, 19u8, 0u8, 0u8, 186u8, 19u8, 0u8, 0u8, 187u8, 19u8, 0u8,
+                0u8, 188u8, 19u8, 0u8, 0u8, 189u8, 19u8, 0u8, 0u8, 190u8, 19u8, 0u8, 0u8, 191u8,
+                19u8, 0u8, 0u8, 192u8, 19u8, 0u8, 0u8, 193u8, 19u8, 0u8, 0u8, 194u8, 19u8, 0u8,
+                0u8, 195u8, 19u8, 0u8, 0u8, 196u8, 19u8, 0u8, 0u8, 197u8, 19u8, 0u8, 0u8, 198u8,
+                19u8, 0u8, 0u8, 199u8, 19u8, 0u8, 0u8, 200u8, 19u8, 0u8, 0u8, 201u8, 19u8, 0u8,
+                0u8, 202u8, 19u8, 0u8, 0u8, 203u8, 19u8, 0u8, 0u8, 204u8, 19u8, 0u8, 0u8, 205u8,
+                19u8, 0u8, 0u8, 206u8, 19u8, 0u8, 0u8, 207u8, 19u8, 0u8, 0u8, 208u8, 19u8, 0u8,
+                0u8, 209u8, 19u8, 0u8, 0u8, 210u8, 19u8, 0u8, 0u8, 211u8, 19u8, 0u8, 0u8, 212u8,
+                19u8, 0u8, 0u8, 213u8, 19u8, 0u8, 0u8, 214u8, 19u8, 0u8, 0u8, 215u8, 19u8, 0u8,
+                0u8, 216u8, 19u8, 0u8, 0u8, 217u8, 19u8, 0u8, 0u8, 218u8, 19u8, 0u8, 0u8, 219u8,
+                19u8, 0u8, 0u8, 220u8, 19u8, 0u8, 0u8, 221u8, 19u8, 0u8, 0u8, 222u8, 19u8, 0u8,
+                0u8, 223u8, 19u8, 0u8, 0u8, 224u8, 19u8, 0u8, 0u8, 225u8, 19u8, 0u8, 0u8, 226u8,
+                19u8, 0u8, 0u8, 227u8, 19u8, 0u8, 0u8, 228u8, 19u8, 0u8, 0u8, 229u8, 19u8, 0u8,
+                0u8, 230u8, 19u8, 0u8, 0u8, 231u8, 19u8, 0u8, 0u8, 232u8, 19u8, 0u8, 0u8, 233u8,
+                19u8, 0u8, 0u8, 234u8, 19u8, 0u8, 0u8, 235u8, 19u8, 0u8, 0u8, 236u8, 19u8, 0u8,
+                0u8, 237u8, 19u8, 0u8, 0u8, 238u8, 19u8, 0u8, 0u8, 239u8, 19u8, 0u8, 0u8, 0u8, 0u8,
+                251u8, 9u8, 0u8, 0u8, 253u8, 9u8, 0u8, 0u8, 255u8, 9u8, 0u8, 0u8, 252u8, 41u8, 0u8,
+                0u8, 1u8, 42u8, 0u8, 0u8, 4u8, 10u8, 0u8, 0u8, 4u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 6u8, 10u8, 0u8, 0u8, 8u8, 10u8, 0u8, 0u8, 10u8, 10u8, 0u8, 0u8, 12u8, 10u8,
+                0u8, 0u8, 14u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 226u8, 5u8, 0u8, 0u8, 208u8, 5u8, 0u8, 0u8, 211u8, 5u8, 0u8,
+                0u8, 212u8, 5u8, 0u8, 0u8, 219u8, 5u8, 0u8, 0u8, 220u8, 5u8, 0u8, 0u8, 221u8, 5u8,
+                0u8, 0u8, 232u8, 5u8, 0u8, 0u8, 234u8, 5u8, 0u8, 0u8, 43u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 10u8, 113u8,
+                6u8, 0u8, 0u8, 113u8, 6u8, 0u8, 0u8, 123u8, 6u8, 0u8, 0u8, 123u8, 6u8, 0u8, 0u8,
+                123u8, 6u8, 0u8, 0u8, 123u8, 6u8, 0u8, 0u8, 126u8, 6u8, 0u8, 0u8, 126u8, 6u8, 0u8,
+                0u8, 126u8, 6u8, 0u8, 0u8, 126u8, 6u8, 0u8, 0u8, 128u8, 6u8, 0u8, 0u8, 128u8, 6u8,
+                0u8, 0u8, 128u8, 6u8, 0u8, 0u8, 128u8, 6u8, 0u8, 0u8, 122u8, 6u8, 0u8, 0u8, 122u8,
+                6u8, 0u8, 0u8, 127u8, 6u8, 0u8, 0u8, 127u8, 6u8, 0u8, 0u8, 127u8, 6u8, 0u8, 0u8,
+                127u8, 6u8, 0u8, 0u8, 121u8, 6u8, 0u8, 0u8, 121u8, 6u8, 0u8, 0u8, 121u8, 6u8, 0u8,
+                0u8, 121u8, 6u8, 0u8, 0u8, 164u8, 6u8, 0u8, 0u8, 164u8, 6u8, 0u8, 0u8, 164u8, 6u8,
+                0u8, 0u8, 164u8, 6u8, 0u8, 0u8, 166u8, 6u8, 0u8, 0u8, 166u8, 6u8, 0u8, 0u8, 132u8,
+                6u8, 0u8, 0u8, 132u8, 6u8, 0u8, 0u8, 132u8, 6u8, 0u8, 0u8, 132u8, 6u8, 0u8, 0u8,
+                131u8, 6u8, 0u8, 0u8, 131u8, 6u8, 0u8, 0u8, 131u8, 6u8, 0u8, 0u8, 131u8, 6u8, 0u8,
+                0u8, 134u8, 6u8, 0u8, 0u8, 134u8, 6u8, 0u8, 0u8, 134u8, 6u8, 0u8, 0u8, 134u8, 6u8,
+                0u8, 0u8, 135u8, 6u8, 0u8, 0u8, 135u8, 6u8, 0u8, 0u8, 141u8, 6u8, 0u8, 0u8, 141u8,
+                6u8, 0u8, 0u8, 140u8, 6u8, 0u8, 0u8, 140u8, 6u8, 0u8, 0u8, 142u8, 6u8, 0u8, 0u8,
+                142u8, 6u8, 0u8, 0u8, 136u8, 6u8, 0u8, 0u8, 136u8, 6u8, 0u8, 0u8, 152u8, 6u8, 0u8,
+                0u8, 152u8, 6u8, 0u8, 0u8, 145u8, 6u8, 0u8, 0u8, 145u8, 6u8, 0u8, 0u8, 169u8, 6u8,
+                0u8, 0u8, 169u8, 6u8, 0u8, 0u8, 175u8, 6u8, 0u8, 0u8, 175u8, 6u8, 0u8, 0u8, 175u8,
+                6u8, 0u8, 0u8, 175u8, 6u8, 0u8, 0u8, 179u8, 6u8, 0u8, 0u8, 179u8, 6u8, 0u8, 0u8,
+                179u8, 6u8, 0u8, 0u8, 179u8, 6u8, 0u8, 0u8, 177u8, 6u8, 0u8, 0u8, 177u8, 6u8, 0u8,
+                0u8, 177u8, 6u8, 0u8, 0u8, 177u8, 6u8, 0u8, 0u8, 186u8, 6u8, 0u8, 0u8, 186u8, 6u8,
+                0u8, 0u8, 187u8, 6u8, 0u8, 0u8, 187u8, 6u8, 0u8, 0u8, 187u8, 6u8, 0u8, 0u8, 187u8,
+                6u8, 0u8, 0u8, 213u8, 6u8, 84u8, 6u8, 213u8, 6u8, 84u8, 6u8, 193u8, 6u8, 0u8, 0u8,
+                193u8, 6u8, 0u8, 0u8, 193u8, 6u8, 0u8, 0u8, 193u8, 6u8, 0u8, 0u8, 190u8, 6u8, 0u8,
+                0u8, 190u8, 6u8, 0u8, 0u8, 190u8, 6u8, 0u8, 0u8, 190u8, 6u8, 0u8, 0u8, 210u8, 6u8,
+                0u8, 0u8, 210u8, 6u8, 0u8, 0u8, 210u8, 6u8, 84u8, 6u8, 210u8, 6u8, 84u8, 6u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 173u8, 6u8, 0u8, 0u8, 173u8, 6u8, 0u8, 0u8,
+                173u8, 6u8, 0u8, 0u8, 173u8, 6u8, 0u8, 0u8, 199u8, 6u8, 0u8, 0u8, 199u8, 6u8, 0u8,
+                0u8, 198u8, 6u8, 0u8, 0u8, 198u8, 6u8, 0u8, 0u8, 200u8, 6u8, 0u8, 0u8, 200u8, 6u8,
+                0u8, 0u8, 0u8, 0u8, 210u8, 3u8, 203u8, 6u8, 0u8, 0u8, 203u8, 6u8, 0u8, 0u8, 197u8,
+                6u8, 0u8, 0u8, 197u8, 6u8, 0u8, 0u8, 201u8, 6u8, 0u8, 0u8, 201u8, 6u8, 0u8, 0u8,
+                208u8, 6u8, 0u8, 0u8, 208u8, 6u8, 0u8, 0u8, 208u8, 6u8, 0u8, 0u8, 208u8, 6u8, 0u8,
+                0u8, 73u8, 6u8, 0u8, 0u8, 73u8, 6u8, 0u8, 0u8, 0u8, 0u8, 18u8, 42u8, 0u8, 0u8,
+                18u8, 42u8, 0u8, 0u8, 21u8, 42u8, 0u8, 0u8, 21u8, 42u8, 0u8, 0u8, 24u8, 42u8, 0u8,
+                0u8, 24u8, 42u8, 0u8, 0u8, 27u8, 42u8, 0u8, 0u8, 27u8, 42u8, 0u8, 0u8, 30u8, 42u8,
+                0u8, 0u8, 30u8, 42u8, 0u8, 0u8, 33u8, 42u8, 0u8, 0u8, 33u8, 42u8, 0u8, 0u8, 36u8,
+                42u8, 0u8, 0u8, 36u8, 42u8, 0u8, 0u8, 36u8, 42u8, 0u8, 0u8, 39u8, 42u8, 0u8, 0u8,
+                39u8, 42u8, 0u8, 0u8, 39u8, 42u8, 204u8, 6u8, 0u8, 0u8, 204u8, 6u8, 0u8, 0u8,
+                204u8, 6u8, 0u8, 0u8, 204u8, 6u8, 0u8, 0u8, 0u8, 0u8, 42u8, 42u8, 0u8, 0u8, 45u8,
+                42u8, 0u8, 0u8, 48u8, 42u8, 0u8, 0u8, 39u8, 42u8, 0u8, 0u8, 51u8, 42u8, 0u8, 0u8,
+                54u8, 10u8, 0u8, 0u8, 56u8, 10u8, 0u8, 0u8, 58u8, 10u8, 0u8, 0u8, 60u8, 10u8, 0u8,
+                0u8, 62u8, 10u8, 0u8, 0u8, 64u8, 10u8, 0u8, 0u8, 66u8, 10u8, 0u8, 0u8, 68u8, 10u8,
+                0u8, 0u8, 70u8, 10u8, 0u8, 0u8, 72u8, 10u8, 0u8, 0u8, 74u8, 10u8, 0u8, 0u8, 76u8,
+                10u8, 0u8, 0u8, 78u8, 10u8, 0u8, 0u8, 80u8, 10u8, 0u8, 0u8, 82u8, 10u8, 0u8, 0u8,
+                84u8, 10u8, 0u8, 0u8, 86u8, 10u8, 0u8, 0u8, 88u8, 10u8, 0u8, 0u8, 87u8, 10u8, 0u8,
+                0u8, 90u8, 10u8, 0u8, 0u8, 92u8, 10u8, 0u8, 0u8, 94u8, 10u8, 0u8, 0u8, 96u8, 10u8,
+                0u8, 0u8, 98u8, 10u8, 0u8, 0u8, 100u8, 10u8, 0u8, 0u8, 102u8, 10u8, 0u8, 0u8,
+                104u8, 10u8, 0u8, 0u8, 106u8, 10u8, 0u8, 0u8, 108u8, 10u8, 0u8, 0u8, 110u8, 10u8,
+                0u8, 0u8, 112u8, 10u8, 0u8, 0u8, 114u8, 10u8, 0u8, 0u8, 116u8, 10u8, 0u8, 0u8,
+                118u8, 10u8, 0u8, 0u8, 120u8, 10u8, 0u8, 0u8, 122u8, 10u8, 0u8, 0u8, 124u8, 10u8,
+                0u8, 0u8, 126u8, 10u8, 0u8, 0u8, 128u8, 10u8, 0u8, 0u8, 130u8, 10u8, 0u8, 0u8,
+                132u8, 10u8, 0u8, 0u8, 134u8, 10u8, 0u8, 0u8, 136u8, 10u8, 0u8, 0u8, 138u8, 10u8,
+                0u8, 0u8, 140u8, 10u8, 0u8, 0u8, 142u8, 10u8, 0u8, 0u8, 144u8, 10u8, 0u8, 0u8,
+                146u8, 10u8, 0u8, 0u8, 148u8, 10u8, 0u8, 0u8, 150u8, 10u8, 0u8, 0u8, 152u8, 10u8,
+                0u8, 0u8, 154u8, 10u8, 0u8, 0u8, 156u8, 10u8, 0u8, 0u8, 158u8, 10u8, 0u8, 0u8,
+                160u8, 10u8, 0u8, 0u8, 162u8, 10u8, 0u8, 0u8, 164u8, 10u8, 0u8, 0u8, 166u8, 10u8,
+                0u8, 0u8, 168u8, 10u8, 0u8, 0u8, 170u8, 10u8, 0u8, 0u8, 172u8, 10u8, 0u8, 0u8,
+                174u8, 10u8, 0u8, 0u8, 176u8, 10u8, 0u8, 0u8, 178u8, 10u8, 0u8, 0u8, 180u8, 10u8,
+                0u8, 0u8, 89u8, 10u8, 0u8, 0u8, 91u8, 10u8, 0u8, 0u8, 182u8, 10u8, 0u8, 0u8, 184u8,
+                10u8, 0u8, 0u8, 50u8, 10u8, 0u8, 0u8, 186u8, 10u8, 0u8, 0u8, 188u8, 10u8, 0u8, 0u8,
+                190u8, 10u8, 0u8, 0u8, 192u8, 10u8, 0u8, 0u8, 194u8, 10u8, 0u8, 0u8, 196u8, 10u8,
+                0u8, 0u8, 198u8, 10u8, 0u8, 0u8, 200u8, 10u8, 0u8, 0u8, 202u8, 10u8, 0u8, 0u8,
+                204u8, 10u8, 0u8, 0u8, 85u8, 10u8, 0u8, 0u8, 206u8, 10u8, 0u8, 0u8, 208u8, 10u8,
+                0u8, 0u8, 179u8, 10u8, 0u8, 0u8, 210u8, 10u8, 0u8, 0u8, 205u8, 10u8, 48u8, 6u8,
+                112u8, 6u8, 49u8, 6u8, 112u8, 6u8, 73u8, 6u8, 112u8, 6u8, 0u8, 0u8, 212u8, 58u8,
+                0u8, 0u8, 215u8, 58u8, 0u8, 0u8, 218u8, 58u8, 0u8, 0u8, 221u8, 58u8, 0u8, 0u8,
+                224u8, 58u8, 0u8, 0u8, 227u8, 58u8, 0u8, 0u8, 230u8, 42u8, 0u8, 0u8, 233u8, 42u8,
+                0u8, 0u8, 48u8, 42u8, 0u8, 0u8, 236u8, 42u8, 0u8, 0u8, 39u8, 42u8, 0u8, 0u8, 51u8,
+                42u8, 0u8, 0u8, 239u8, 10u8, 0u8, 0u8, 241u8, 10u8, 0u8, 0u8, 60u8, 10u8, 0u8, 0u8,
+                243u8, 10u8, 0u8, 0u8, 62u8, 10u8, 0u8, 0u8, 64u8, 10u8, 0u8, 0u8, 245u8, 10u8,
+                0u8, 0u8, 247u8, 10u8, 0u8, 0u8, 72u8, 10u8, 0u8, 0u8, 249u8, 10u8, 0u8, 0u8, 74u8,
+                10u8, 0u8, 0u8, 76u8, 10u8, 0u8, 0u8, 251u8, 10u8, 0u8, 0u8, 253u8, 10u8, 0u8, 0u8,
+                80u8, 10u8, 0u8, 0u8, 255u8, 10u8, 0u8, 0u8, 82u8, 10u8, 0u8, 0u8, 84u8, 10u8, 0u8,
+                0u8, 140u8, 10u8, 0u8, 0u8, 142u8, 10u8, 0u8, 0u8, 148u8, 10u8, 0u8, 0u8, 150u8,
+                10u8, 0u8, 0u8, 152u8, 10u8, 0u8, 0u8, 160u8, 10u8, 0u8, 0u8, 162u8, 10u8, 0u8,
+                0u8, 164u8, 10u8, 0u8, 0u8, 166u8, 10u8, 0u8, 0u8, 174u8, 10u8, 0u8, 0u8, 176u8,
+                10u8, 0u8, 0u8, 178u8, 10u8, 0u8, 0u8, 1u8, 11u8, 0u8, 0u8, 182u8, 10u8, 0u8, 0u8,
+                3u8, 11u8, 0u8, 0u8, 5u8, 11u8, 0u8, 0u8, 192u8, 10u8, 0u8, 0u8, 7u8, 11u8, 0u8,
+                0u8, 194u8, 10u8, 0u8, 0u8, 196u8, 10u8, 73u8, 6u8, 112u8, 6u8, 0u8, 0u8, 9u8,
+                11u8, 0u8, 0u8, 11u8, 11u8, 0u8, 0u8, 179u8, 10u8, 0u8, 0u8, 13u8, 11u8, 0u8, 0u8,
+                210u8, 10u8, 0u8, 0u8, 205u8, 10u8, 0u8, 0u8, 42u8, 42u8, 0u8, 0u8, 45u8, 42u8,
+                0u8, 0u8, 15u8, 43u8, 0u8, 0u8, 48u8, 42u8, 0u8, 0u8, 18u8, 43u8, 0u8, 0u8, 54u8,
+                10u8, 0u8, 0u8, 56u8, 10u8, 0u8, 0u8, 58u8, 10u8, 0u8, 0u8, 60u8, 10u8, 0u8, 0u8,
+                21u8, 11u8, 0u8, 0u8, 66u8, 10u8, 0u8, 0u8, 68u8, 10u8, 0u8, 0u8, 70u8, 10u8, 0u8,
+                0u8, 72u8, 10u8, 0u8, 0u8, 23u8, 11u8, 0u8, 0u8, 80u8, 10u8, 0u8, 0u8, 86u8, 10u8,
+                0u8, 0u8, 88u8, 10u8, 0u8, 0u8, 87u8, 10u8, 0u8, 0u8, 90u8, 10u8, 0u8, 0u8, 92u8,
+                10u8, 0u8, 0u8, 96u8, 10u8, 0u8, 0u8, 98u8, 10u8, 0u8, 0u8, 100u8, 10u8, 0u8, 0u8,
+                102u8, 10u8, 0u8, 0u8, 104u8, 10u8, 0u8, 0u8, 106u8, 10u8, 0u8, 0u8, 25u8, 11u8,
+                0u8, 0u8, 108u8, 10u8, 0u8, 0u8, 110u8, 10u8, 0u8, 0u8, 112u8, 10u8, 0u8, 0u8,
+                114u8, 10u8, 0u8, 0u8, 116u8, 10u8, 0u8, 0u8, 118u8, 10u8, 0u8, 0u8, 122u8, 10u8,
+                0u8, 0u8, 124u8, 10u8, 0u8, 0u8, 126u8, 10u8, 0u8, 0u8, 128u8, 10u8, 0u8, 0u8,
+                130u8, 10u8, 0u8, 0u8, 132u8, 10u8, 0u8, 0u8, 134u8, 10u8, 0u8, 0u8, 136u8, 10u8,
+                0u8, 0u8, 138u8, 10u8, 0u8, 0u8, 144u8, 10u8, 0u8, 0u8, 146u8, 10u8, 0u8, 0u8,
+                154u8, 10u8, 0u8, 0u8, 156u8, 10u8, 0u8, 0u8, 158u8, 10u8, 0u8, 0u8, 160u8, 10u8,
+                0u8, 0u8, 162u8, 10u8, 0u8, 0u8, 168u8, 10u8, 0u8, 0u8, 170u8, 10u8, 0u8, 0u8,
+                172u8, 10u8, 0u8, 0u8, 174u8, 10u8, 0u8, 0u8, 27u8, 11u8, 0u8, 0u8, 180u8, 10u8,
+                0u8, 0u8, 89u8, 10u8, 0u8, 0u8, 91u8, 10u8, 0u8, 0u8, 182u8, 10u8, 0u8, 0u8, 186u8,
+                10u8, 0u8, 0u8, 188u8, 10u8, 0u8, 0u8, 190u8, 10u8, 0u8, 0u8, 192u8, 10u8, 0u8,
+                0u8, 29u8, 11u8, 0u8, 0u8, 198u8, 10u8, 0u8, 0u8, 200u8, 10u8, 71u8, 6u8, 112u8,
+                6u8, 0u8, 0u8, 85u8, 10u8, 0u8, 0u8, 206u8, 10u8, 0u8, 0u8, 208u8, 10u8, 0u8, 0u8,
+                179u8, 10u8, 0u8, 0u8, 197u8, 10u8, 0u8, 0u8, 48u8, 42u8, 0u8, 0u8, 18u8, 43u8,
+                0u8, 0u8, 60u8, 10u8, 0u8, 0u8, 21u8, 11u8, 0u8, 0u8, 72u8, 10u8, 0u8, 0u8, 23u8,
+                11u8, 0u8, 0u8, 80u8, 10u8, 0u8, 0u8, 31u8, 11u8, 0u8, 0u8, 104u8, 10u8, 0u8, 0u8,
+                33u8, 11u8, 0u8, 0u8, 35u8, 11u8, 0u8, 0u8, 37u8, 11u8, 0u8, 0u8, 160u8, 10u8, 0u8,
+                0u8, 162u8, 10u8, 0u8, 0u8, 174u8, 10u8, 0u8, 0u8, 192u8, 10u8, 0u8, 0u8, 29u8,
+                11u8, 0u8, 0u8, 179u8, 10u8, 0u8, 0u8, 197u8, 10u8, 0u8, 0u8, 39u8, 59u8, 0u8, 0u8,
+                42u8, 59u8, 0u8, 0u8, 45u8, 59u8, 0u8, 0u8, 48u8, 11u8, 0u8, 0u8, 50u8, 11u8, 0u8,
+                0u8, 52u8, 11u8, 0u8, 0u8, 54u8, 11u8, 0u8, 0u8, 56u8, 11u8, 0u8, 0u8, 58u8, 11u8,
+                0u8, 0u8, 60u8, 11u8, 0u8, 0u8, 62u8, 11u8, 0u8, 0u8, 64u8, 11u8, 0u8, 0u8, 66u8,
+                11u8, 0u8, 0u8, 68u8, 11u8, 0u8, 0u8, 47u8, 10u8, 0u8, 0u8, 70u8, 11u8, 0u8, 0u8,
+                44u8, 10u8, 0u8, 0u8, 72u8, 11u8, 0u8, 0u8, 209u8, 10u8, 0u8, 0u8, 74u8, 11u8, 0u8,
+                0u8, 76u8, 11u8, 0u8, 0u8, 78u8, 11u8, 0u8, 0u8, 80u8, 11u8, 0u8, 0u8, 82u8, 11u8,
+                0u8, 0u8, 84u8, 11u8, 0u8, 0u8, 86u8, 11u8, 0u8, 0u8, 35u8, 11u8, 0u8, 0u8, 88u8,
+                11u8, 0u8, 0u8, 90u8, 11u8, 0u8, 0u8, 92u8, 11u8, 0u8, 0u8, 94u8, 11u8, 0u8, 0u8,
+                48u8, 11u8, 0u8, 0u8, 50u8, 11u8, 0u8, 0u8, 52u8, 11u8, 0u8, 0u8, 54u8, 11u8, 0u8,
+                0u8, 56u8, 11u8, 0u8, 0u8, 58u8, 11u8, 0u8, 0u8, 60u8, 11u8, 0u8, 0u8, 62u8, 11u8,
+                0u8, 0u8, 64u8, 11u8, 0u8, 0u8, 66u8, 11u8, 0u8, 0u8, 68u8, 11u8, 0u8, 0u8, 47u8,
+                10u8, 0u8, 0u8, 70u8, 11u8, 0u8, 0u8, 44u8, 10u8, 0u8, 0u8, 72u8, 11u8, 0u8, 0u8,
+                209u8, 10u8, 0u8, 0u8, 74u8, 11u8, 0u8, 0u8, 76u8, 11u8, 0u8, 0u8, 78u8, 11u8, 0u8,
+                0u8, 80u8, 11u8, 0u8, 0u8, 82u8, 11u8, 0u8, 0u8, 84u8, 11u8, 0u8, 0u8, 86u8, 11u8,
+                0u8, 0u8, 35u8, 11u8, 0u8, 0u8, 88u8, 11u8, 0u8, 0u8, 90u8, 11u8, 0u8, 0u8, 92u8,
+                11u8, 0u8, 0u8, 94u8, 11u8, 0u8, 0u8, 82u8, 11u8, 0u8, 0u8, 84u8, 11u8, 0u8, 0u8,
+                86u8, 11u8, 0u8, 0u8, 35u8, 11u8, 0u8, 0u8, 33u8, 11u8, 0u8, 0u8, 37u8, 11u8, 0u8,
+                0u8, 120u8, 10u8, 0u8, 0u8, 98u8, 10u8, 0u8, 0u8, 100u8, 10u8, 0u8, 0u8, 102u8,
+                10u8, 0u8, 0u8, 82u8, 11u8, 0u8, 0u8, 84u8, 11u8, 0u8, 0u8, 86u8, 11u8, 0u8, 0u8,
+                120u8, 10u8, 0u8, 0u8, 122u8, 10u8, 39u8, 6u8, 75u8, 6u8, 39u8, 6u8, 75u8, 6u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 96u8, 43u8, 0u8, 0u8, 99u8, 43u8,
+                0u8, 0u8, 99u8, 43u8, 0u8, 0u8, 102u8, 43u8, 0u8, 0u8, 105u8, 43u8, 0u8, 0u8,
+                108u8, 43u8, 0u8, 0u8, 111u8, 43u8, 0u8, 0u8, 114u8, 43u8, 0u8, 0u8, 88u8, 42u8,
+                0u8, 0u8, 88u8, 42u8, 0u8, 0u8, 117u8, 43u8, 0u8, 0u8, 120u8, 43u8, 0u8, 0u8,
+                123u8, 43u8, 0u8, 0u8, 126u8, 43u8, 0u8, 0u8, 129u8, 43u8, 0u8, 0u8, 132u8, 43u8,
+                0u8, 0u8, 135u8, 43u8, 0u8, 0u8, 138u8, 43u8, 0u8, 0u8, 138u8, 43u8, 0u8, 0u8,
+                141u8, 43u8, 0u8, 0u8, 141u8, 43u8, 0u8, 0u8, 144u8, 43u8, 0u8, 0u8, 147u8, 43u8,
+                0u8, 0u8, 147u8, 43u8, 0u8, 0u8, 150u8, 43u8, 0u8, 0u8, 153u8, 43u8, 0u8, 0u8,
+                153u8, 43u8, 0u8, 0u8, 156u8, 43u8, 0u8, 0u8, 156u8, 43u8, 0u8, 0u8, 159u8, 43u8,
+                0u8, 0u8, 162u8, 43u8, 0u8, 0u8, 165u8, 43u8, 0u8, 0u8, 165u8, 43u8, 0u8, 0u8,
+                168u8, 43u8, 0u8, 0u8, 171u8, 43u8, 0u8, 0u8, 174u8, 43u8, 0u8, 0u8, 177u8, 43u8,
+                0u8, 0u8, 177u8, 43u8, 0u8, 0u8, 180u8, 43u8, 0u8, 0u8, 183u8, 43u8, 0u8, 0u8,
+                186u8, 43u8, 0u8, 0u8, 189u8, 43u8, 0u8, 0u8, 192u8, 43u8, 0u8, 0u8, 192u8, 43u8,
+                0u8, 0u8, 195u8, 43u8, 0u8, 0u8, 198u8, 43u8, 0u8, 0u8, 201u8, 43u8, 0u8, 0u8,
+                204u8, 43u8, 0u8, 0u8, 207u8, 43u8, 0u8, 0u8, 210u8, 43u8, 0u8, 0u8, 210u8, 43u8,
+                0u8, 0u8, 213u8, 43u8, 0u8, 0u8, 213u8, 43u8, 0u8, 0u8, 216u8, 43u8, 0u8, 0u8,
+                216u8, 43u8, 0u8, 0u8, 219u8, 43u8, 0u8, 0u8, 89u8, 42u8, 0u8, 0u8, 222u8, 43u8,
+                0u8, 0u8, 225u8, 43u8, 0u8, 0u8, 180u8, 42u8, 0u8, 0u8, 91u8, 42u8, 0u8, 0u8,
+                228u8, 43u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 231u8, 43u8, 0u8,
+                0u8, 234u8, 43u8, 0u8, 0u8, 237u8, 43u8, 0u8, 0u8, 240u8, 43u8, 0u8, 0u8, 243u8,
+                43u8, 0u8, 0u8, 246u8, 43u8, 0u8, 0u8, 246u8, 43u8, 0u8, 0u8, 249u8, 43u8, 0u8,
+                0u8, 252u8, 43u8, 0u8, 0u8, 255u8, 43u8, 0u8, 0u8, 2u8, 44u8, 0u8, 0u8, 2u8, 44u8,
+                0u8, 0u8, 5u8, 44u8, 0u8, 0u8, 8u8, 44u8, 0u8, 0u8, 11u8, 44u8, 0u8, 0u8, 14u8,
+                44u8, 0u8, 0u8, 17u8, 44u8, 0u8, 0u8, 20u8, 44u8, 0u8, 0u8, 23u8, 44u8, 0u8, 0u8,
+                26u8, 44u8, 0u8, 0u8, 29u8, 44u8, 0u8, 0u8, 32u8, 44u8, 0u8, 0u8, 35u8, 44u8, 0u8,
+                0u8, 38u8, 44u8, 0u8, 0u8, 41u8, 44u8, 0u8, 0u8, 44u8, 44u8, 0u8, 0u8, 47u8, 44u8,
+                0u8, 0u8, 50u8, 44u8, 0u8, 0u8, 206u8, 42u8, 0u8, 0u8, 53u8, 44u8, 0u8, 0u8, 56u8,
+                44u8, 0u8, 0u8, 59u8, 44u8, 0u8, 0u8, 62u8, 44u8, 0u8, 0u8, 65u8, 44u8, 0u8, 0u8,
+                195u8, 43u8, 0u8, 0u8, 201u8, 43u8, 0u8, 0u8, 68u8, 44u8, 0u8, 0u8, 71u8, 44u8,
+                0u8, 0u8, 74u8, 44u8, 0u8, 0u8, 77u8, 44u8, 0u8, 0u8, 80u8, 44u8, 0u8, 0u8, 83u8,
+                44u8, 0u8, 0u8, 80u8, 44u8, 0u8, 0u8, 74u8, 44u8, 0u8, 0u8, 86u8, 44u8, 0u8, 0u8,
+                89u8, 44u8, 0u8, 0u8, 92u8, 44u8, 0u8, 0u8, 95u8, 44u8, 0u8, 0u8, 98u8, 44u8, 0u8,
+                0u8, 83u8, 44u8, 0u8, 0u8, 174u8, 43u8, 0u8, 0u8, 144u8, 43u8, 0u8, 0u8, 101u8,
+                44u8, 0u8, 0u8, 104u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 107u8, 44u8, 0u8, 0u8, 110u8, 44u8, 0u8, 0u8,
+                113u8, 76u8, 0u8, 0u8, 117u8, 76u8, 0u8, 0u8, 121u8, 76u8, 0u8, 0u8, 125u8, 76u8,
+                0u8, 0u8, 129u8, 76u8, 0u8, 0u8, 133u8, 76u8, 0u8, 0u8, 137u8, 76u8, 0u8, 0u8,
+                141u8, 44u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 144u8, 204u8, 0u8, 0u8, 152u8, 76u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 44u8, 0u8, 0u8, 0u8,
+                1u8, 48u8, 0u8, 0u8, 2u8, 48u8, 0u8, 0u8, 58u8, 0u8, 0u8, 0u8, 59u8, 0u8, 0u8, 0u8,
+                33u8, 0u8, 0u8, 0u8, 63u8, 0u8, 0u8, 0u8, 22u8, 48u8, 0u8, 0u8, 23u8, 48u8, 0u8,
+                0u8, 0u8, 0u8, 121u8, 36u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 121u8,
+                4u8, 20u8, 32u8, 0u8, 0u8, 19u8, 32u8, 0u8, 0u8, 95u8, 0u8, 0u8, 0u8, 95u8, 0u8,
+                0u8, 0u8, 40u8, 0u8, 0u8, 0u8, 41u8, 0u8, 0u8, 0u8, 123u8, 0u8, 0u8, 0u8, 125u8,
+                0u8, 0u8, 0u8, 20u8, 48u8, 0u8, 0u8, 21u8, 48u8, 0u8, 0u8, 16u8, 48u8, 0u8, 0u8,
+                17u8, 48u8, 0u8, 0u8, 10u8, 48u8, 0u8, 0u8, 11u8, 48u8, 0u8, 0u8, 8u8, 48u8, 0u8,
+                0u8, 9u8, 48u8, 0u8, 0u8, 12u8, 48u8, 0u8, 0u8, 13u8, 48u8, 0u8, 0u8, 14u8, 48u8,
+                0u8, 0u8, 15u8, 48u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 91u8, 0u8,
+                0u8, 0u8, 93u8, 0u8, 0u8, 0u8, 32u8, 0u8, 5u8, 3u8, 32u8, 0u8, 5u8, 3u8, 32u8, 0u8,
+                5u8, 3u8, 32u8, 0u8, 5u8, 3u8, 95u8, 0u8, 0u8, 0u8, 95u8, 0u8, 0u8, 0u8, 95u8, 0u8,
+                0u8, 0u8, 44u8, 0u8, 0u8, 0u8, 1u8, 48u8, 0u8, 0u8, 46u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 59u8, 0u8, 0u8, 0u8, 58u8, 0u8, 0u8, 0u8, 63u8, 0u8, 0u8, 0u8, 33u8, 0u8,
+                0u8, 0u8, 20u8, 32u8, 0u8, 0u8, 40u8, 0u8, 0u8, 0u8, 41u8, 0u8, 0u8, 0u8, 123u8,
+                0u8, 0u8, 0u8, 125u8, 0u8, 0u8, 0u8, 20u8, 48u8, 0u8, 0u8, 21u8, 48u8, 0u8, 0u8,
+                35u8, 0u8, 0u8, 0u8, 38u8, 0u8, 0u8, 0u8, 42u8, 0u8, 0u8, 0u8, 43u8, 0u8, 0u8, 0u8,
+                45u8, 0u8, 0u8, 0u8, 60u8, 0u8, 0u8, 0u8, 62u8, 0u8, 0u8, 0u8, 61u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 92u8, 0u8, 0u8, 0u8, 36u8, 0u8, 0u8, 0u8, 37u8, 0u8, 0u8, 0u8,
+                64u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 32u8, 0u8, 75u8, 6u8, 64u8, 6u8, 75u8, 6u8, 32u8, 0u8, 76u8,
+                6u8, 0u8, 0u8, 0u8, 0u8, 32u8, 0u8, 77u8, 6u8, 0u8, 0u8, 0u8, 0u8, 32u8, 0u8, 78u8,
+                6u8, 64u8, 6u8, 78u8, 6u8, 32u8, 0u8, 79u8, 6u8, 64u8, 6u8, 79u8, 6u8, 32u8, 0u8,
+                80u8, 6u8, 64u8, 6u8, 80u8, 6u8, 32u8, 0u8, 81u8, 6u8, 64u8, 6u8, 81u8, 6u8, 32u8,
+                0u8, 82u8, 6u8, 64u8, 6u8, 82u8, 6u8, 33u8, 6u8, 0u8, 0u8, 39u8, 6u8, 83u8, 6u8,
+                39u8, 6u8, 83u8, 6u8, 39u8, 6u8, 84u8, 6u8, 39u8, 6u8, 84u8, 6u8, 72u8, 6u8, 84u8,
+                6u8, 72u8, 6u8, 84u8, 6u8, 39u8, 6u8, 85u8, 6u8, 39u8, 6u8, 85u8, 6u8, 74u8, 6u8,
+                84u8, 6u8, 74u8, 6u8, 84u8, 6u8, 74u8, 6u8, 84u8, 6u8, 74u8, 6u8, 84u8, 6u8, 39u8,
+                6u8, 0u8, 0u8, 39u8, 6u8, 0u8, 0u8, 40u8, 6u8, 0u8, 0u8, 40u8, 6u8, 0u8, 0u8, 40u8,
+                6u8, 0u8, 0u8, 41u8, 6u8, 0u8, 0u8, 41u8, 6u8, 0u8, 0u8, 42u8, 6u8, 0u8, 0u8, 42u8,
+                6u8, 0u8, 0u8, 42u8, 6u8, 0u8, 0u8, 42u8, 6u8, 0u8, 0u8, 43u8, 6u8, 0u8, 0u8, 43u8,
+                6u8, 0u8, 0u8, 43u8, 6u8, 0u8, 0u8, 43u8, 6u8, 0u8, 0u8, 44u8, 6u8, 0u8, 0u8, 44u8,
+                6u8, 0u8, 0u8, 44u8, 6u8, 0u8, 0u8, 45u8, 6u8, 0u8, 0u8, 45u8, 6u8, 0u8, 0u8, 45u8,
+                6u8, 0u8, 0u8, 45u8, 6u8, 0u8, 0u8, 46u8, 6u8, 0u8, 0u8, 46u8, 6u8, 0u8, 0u8, 46u8,
+                6u8, 0u8, 0u8, 46u8, 6u8, 0u8, 0u8, 47u8, 6u8, 0u8, 0u8, 47u8, 6u8, 0u8, 0u8, 48u8,
+                6u8, 0u8, 0u8, 48u8, 6u8, 0u8, 0u8, 49u8, 6u8, 0u8, 0u8, 49u8, 6u8, 0u8, 0u8, 50u8,
+                6u8, 0u8, 0u8, 51u8, 6u8, 0u8, 0u8, 51u8, 6u8, 0u8, 0u8, 51u8, 6u8, 0u8, 0u8, 51u8,
+                6u8, 0u8, 0u8, 52u8, 6u8, 0u8, 0u8, 52u8, 6u8, 0u8, 0u8, 52u8, 6u8, 0u8, 0u8, 52u8,
+                6u8, 0u8, 0u8, 53u8, 6u8, 0u8, 0u8, 53u8, 6u8, 0u8, 0u8, 53u8, 6u8, 0u8, 0u8, 53u8,
+                6u8, 0u8, 0u8, 54u8, 6u8, 0u8, 0u8, 54u8, 6u8, 0u8, 0u8, 54u8, 6u8, 0u8, 0u8, 55u8,
+                6u8, 0u8, 0u8, 55u8, 6u8, 0u8, 0u8, 55u8, 6u8, 0u8, 0u8, 55u8, 6u8, 0u8, 0u8, 56u8,
+                6u8, 0u8, 0u8, 56u8, 6u8, 0u8, 0u8, 56u8, 6u8, 0u8, 0u8, 56u8, 6u8, 0u8, 0u8, 57u8,
+                6u8, 0u8, 0u8, 57u8, 6u8, 0u8, 0u8, 57u8, 6u8, 0u8, 0u8, 57u8, 6u8, 0u8, 0u8, 58u8,
+                6u8, 0u8, 0u8, 58u8, 6u8, 0u8, 0u8, 58u8, 6u8, 0u8, 0u8, 65u8, 6u8, 0u8, 0u8, 65u8,
+                6u8, 0u8, 0u8, 65u8, 6u8, 0u8, 0u8, 65u8, 6u8, 0u8, 0u8, 66u8, 6u8, 0u8, 0u8, 66u8,
+                6u8, 0u8, 0u8, 66u8, 6u8, 0u8, 0u8, 66u8, 6u8, 0u8, 0u8, 67u8, 6u8, 0u8, 0u8, 67u8,
+                6u8, 0u8, 0u8, 67u8, 6u8, 0u8, 0u8, 67u8, 6u8, 0u8, 0u8, 68u8, 6u8, 0u8, 0u8, 68u8,
+                6u8, 0u8, 0u8, 68u8, 6u8, 0u8, 0u8, 69u8, 6u8, 0u8, 0u8, 69u8, 6u8, 0u8, 0u8, 69u8,
+                6u8, 0u8, 0u8, 69u8, 6u8, 0u8, 0u8, 70u8, 6u8, 0u8, 0u8, 70u8, 6u8, 0u8, 0u8, 70u8,
+                6u8, 0u8, 0u8, 70u8, 6u8, 0u8, 0u8, 71u8, 6u8, 0u8, 0u8, 71u8, 6u8, 0u8, 0u8, 71u8,
+                6u8, 0u8, 0u8, 71u8, 6u8, 0u8, 0u8, 72u8, 6u8, 0u8, 0u8, 72u8, 6u8, 0u8, 0u8, 73u8,
+                6u8, 0u8, 0u8, 74u8, 6u8, 0u8, 0u8, 74u8, 6u8, 0u8, 0u8, 74u8, 6u8, 0u8, 0u8, 74u8,
+                6u8, 0u8, 0u8, 0u8, 0u8, 156u8, 44u8, 0u8, 0u8, 156u8, 44u8, 0u8, 0u8, 159u8, 44u8,
+                0u8, 0u8, 159u8, 44u8, 0u8, 0u8, 162u8, 44u8, 0u8, 0u8, 162u8, 44u8, 0u8, 0u8,
+                148u8, 12u8, 0u8, 0u8, 148u8, 12u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 255u8,
+                255u8, 255u8, 255u8, 0u8, 0u8, 0u8, 0u8, 33u8, 0u8, 0u8, 0u8, 34u8, 0u8, 0u8, 0u8,
+                35u8, 0u8, 0u8, 0u8, 36u8, 0u8, 0u8, 0u8, 37u8, 0u8, 0u8, 0u8, 38u8, 0u8, 0u8, 0u8,
+                39u8, 0u8, 0u8, 0u8, 40u8, 0u8, 0u8, 0u8, 41u8, 0u8, 0u8, 0u8, 42u8, 0u8, 0u8, 0u8,
+                43u8, 0u8, 0u8, 0u8, 44u8, 0u8, 0u8, 0u8, 45u8, 0u8, 0u8, 0u8, 46u8, 0u8, 0u8, 0u8,
+                47u8, 0u8, 0u8, 0u8, 48u8, 0u8, 0u8, 0u8, 49u8, 0u8, 0u8, 0u8, 50u8, 0u8, 0u8, 0u8,
+                51u8, 0u8, 0u8, 0u8, 52u8, 0u8, 0u8, 0u8, 53u8, 0u8, 0u8, 0u8, 54u8, 0u8, 0u8, 0u8,
+                55u8, 0u8, 0u8, 0u8, 56u8, 0u8, 0u8, 0u8, 57u8, 0u8, 0u8, 0u8, 58u8, 0u8, 0u8, 0u8,
+                59u8, 0u8, 0u8, 0u8, 60u8, 0u8, 0u8, 0u8, 61u8, 0u8, 0u8, 0u8, 62u8, 0u8, 0u8, 0u8,
+                63u8, 0u8, 0u8, 0u8, 64u8, 0u8, 0u8, 0u8, 97u8, 0u8, 0u8, 0u8, 98u8, 0u8, 0u8, 0u8,
+                99u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 101u8, 0u8, 0u8, 0u8, 102u8, 0u8, 0u8,
+                0u8, 103u8, 0u8, 0u8, 0u8, 104u8, 0u8, 0u8, 0u8, 105u8, 0u8, 0u8, 0u8, 106u8, 0u8,
+                0u8, 0u8, 107u8, 0u8, 0u8, 0u8, 108u8, 0u8, 0u8, 0u8, 109u8, 0u8, 0u8, 0u8, 110u8,
+                0u8, 0u8, 0u8, 111u8, 0u8, 0u8, 0u8, 112u8, 0u8, 0u8, 0u8, 113u8, 0u8, 0u8, 0u8,
+                114u8, 0u8, 0u8, 0u8, 115u8, 0u8, 0u8, 0u8, 116u8, 0u8, 0u8, 0u8, 117u8, 0u8, 0u8,
+                0u8, 118u8, 0u8, 0u8, 0u8, 119u8, 0u8, 0u8, 0u8, 120u8, 0u8, 0u8, 0u8, 121u8, 0u8,
+                0u8, 0u8, 122u8, 0u8, 0u8, 0u8, 91u8, 0u8, 0u8, 0u8, 92u8, 0u8, 0u8, 0u8, 93u8,
+                0u8, 0u8, 0u8, 94u8, 0u8, 0u8, 0u8, 95u8, 0u8, 0u8, 0u8, 96u8, 0u8, 0u8, 0u8, 97u8,
+                0u8, 0u8, 0u8, 98u8, 0u8, 0u8, 0u8, 99u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8,
+                101u8, 0u8, 0u8, 0u8, 102u8, 0u8, 0u8, 0u8, 103u8, 0u8, 0u8, 0u8, 104u8, 0u8, 0u8,
+                0u8, 105u8, 0u8, 0u8, 0u8, 106u8, 0u8, 0u8, 0u8, 107u8, 0u8, 0u8, 0u8, 108u8, 0u8,
+                0u8, 0u8, 109u8, 0u8, 0u8, 0u8, 110u8, 0u8, 0u8, 0u8, 111u8, 0u8, 0u8, 0u8, 112u8,
+                0u8, 0u8, 0u8, 113u8, 0u8, 0u8, 0u8, 114u8, 0u8, 0u8, 0u8, 115u8, 0u8, 0u8, 0u8,
+                116u8, 0u8, 0u8, 0u8, 117u8, 0u8, 0u8, 0u8, 118u8, 0u8, 0u8, 0u8, 119u8, 0u8, 0u8,
+                0u8, 120u8, 0u8, 0u8, 0u8, 121u8, 0u8, 0u8, 0u8, 122u8, 0u8, 0u8, 0u8, 123u8, 0u8,
+                0u8, 0u8, 124u8, 0u8, 0u8, 0u8, 125u8, 0u8, 0u8, 0u8, 126u8, 0u8, 0u8, 0u8, 133u8,
+                41u8, 0u8, 0u8, 134u8, 41u8, 0u8, 0u8, 2u8, 48u8, 0u8, 0u8, 12u8, 48u8, 0u8, 0u8,
+                13u8, 48u8, 0u8, 0u8, 1u8, 48u8, 0u8, 0u8, 251u8, 48u8, 0u8, 0u8, 242u8, 48u8, 0u8,
+                0u8, 161u8, 48u8, 0u8, 0u8, 163u8, 48u8, 0u8, 0u8, 165u8, 48u8, 0u8, 0u8, 167u8,
+                48u8, 0u8, 0u8, 169u8, 48u8, 0u8, 0u8, 227u8, 48u8, 0u8, 0u8, 229u8, 48u8, 0u8,
+                0u8, 231u8, 48u8, 0u8, 0u8, 195u8, 48u8, 0u8, 0u8, 252u8, 48u8, 0u8, 0u8, 162u8,
+                48u8, 0u8, 0u8, 164u8, 48u8, 0u8, 0u8, 166u8, 48u8, 0u8, 0u8, 168u8, 48u8, 0u8,
+                0u8, 170u8, 48u8, 0u8, 0u8, 171u8, 48u8, 0u8, 0u8, 173u8, 48u8, 0u8, 0u8, 175u8,
+                48u8, 0u8, 0u8, 177u8, 48u8, 0u8, 0u8, 179u8, 48u8, 0u8, 0u8, 181u8, 48u8, 0u8,
+                0u8, 183u8, 48u8, 0u8, 0u8, 185u8, 48u8, 0u8, 0u8, 187u8, 48u8, 0u8, 0u8, 189u8,
+                48u8, 0u8, 0u8, 223u8, 48u8, 0u8, 0u8, 224u8, 48u8, 0u8, 0u8, 225u8, 48u8, 0u8,
+                0u8, 226u8, 48u8, 0u8, 0u8, 228u8, 48u8, 0u8, 0u8, 230u8, 48u8, 0u8, 0u8, 232u8,
+                48u8, 0u8, 0u8, 233u8, 48u8, 0u8, 0u8, 234u8, 48u8, 0u8, 0u8, 235u8, 48u8, 0u8,
+                0u8, 236u8, 48u8, 0u8, 0u8, 237u8, 48u8, 0u8, 0u8, 239u8, 48u8, 0u8, 0u8, 243u8,
+                48u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 255u8, 255u8, 255u8, 255u8,
+                0u8, 17u8, 0u8, 0u8, 1u8, 17u8, 0u8, 0u8, 170u8, 17u8, 0u8, 0u8, 2u8, 17u8, 0u8,
+                0u8, 172u8, 17u8, 0u8, 0u8, 173u8, 17u8, 0u8, 0u8, 3u8, 17u8, 0u8, 0u8, 4u8, 17u8,
+                0u8, 0u8, 5u8, 17u8, 0u8, 0u8, 176u8, 17u8, 0u8, 0u8, 177u8, 17u8, 0u8, 0u8, 178u8,
+                17u8, 0u8, 0u8, 179u8, 17u8, 0u8, 0u8, 180u8, 17u8, 0u8, 0u8, 181u8, 17u8, 0u8,
+                0u8, 26u8, 17u8, 0u8, 0u8, 6u8, 17u8, 0u8, 0u8, 7u8, 17u8, 0u8, 0u8, 8u8, 17u8,
+                0u8, 0u8, 33u8, 17u8, 0u8, 0u8, 9u8, 17u8, 0u8, 0u8, 10u8, 17u8, 0u8, 0u8, 11u8,
+                17u8, 0u8, 0u8, 12u8, 17u8, 0u8, 0u8, 13u8, 17u8, 0u8, 0u8, 14u8, 17u8, 0u8, 0u8,
+                15u8, 17u8, 0u8, 0u8, 16u8, 17u8, 0u8, 0u8, 17u8, 17u8, 0u8, 0u8, 18u8, 17u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 17u8, 0u8, 0u8, 98u8, 17u8, 0u8,
+                0u8, 99u8, 17u8, 0u8, 0u8, 100u8, 17u8, 0u8, 0u8, 101u8, 17u8, 0u8, 0u8, 102u8,
+                17u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 103u8, 17u8, 0u8, 0u8,
+                104u8, 17u8, 0u8, 0u8, 105u8, 17u8, 0u8, 0u8, 106u8, 17u8, 0u8, 0u8, 107u8, 17u8,
+                0u8, 0u8, 108u8, 17u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 109u8,
+                17u8, 0u8, 0u8, 110u8, 17u8, 0u8, 0u8, 111u8, 17u8, 0u8, 0u8, 112u8, 17u8, 0u8,
+                0u8, 113u8, 17u8, 0u8, 0u8, 114u8, 17u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 115u8, 17u8, 0u8, 0u8, 116u8, 17u8, 0u8, 0u8, 117u8, 17u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 162u8, 0u8, 0u8, 0u8, 163u8,
+                0u8, 0u8, 0u8, 172u8, 0u8, 0u8, 0u8, 32u8, 0u8, 4u8, 3u8, 166u8, 0u8, 0u8, 0u8,
+                165u8, 0u8, 0u8, 0u8, 169u8, 32u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 37u8, 0u8,
+                0u8, 144u8, 33u8, 0u8, 0u8, 145u8, 33u8, 0u8, 0u8, 146u8, 33u8, 0u8, 0u8, 147u8,
+                33u8, 0u8, 0u8, 160u8, 37u8, 0u8, 0u8, 203u8, 37u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8,
+                255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8,
+                255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 234u8, 28u8,
+                0u8, 0u8, 235u8, 28u8, 0u8, 0u8, 236u8, 28u8, 0u8, 0u8, 237u8, 28u8, 0u8, 0u8,
+                238u8, 28u8, 0u8, 0u8, 239u8, 28u8, 0u8, 0u8, 240u8, 28u8, 0u8, 0u8, 241u8, 28u8,
+                0u8, 0u8, 242u8, 28u8, 0u8, 0u8, 243u8, 28u8, 0u8, 0u8, 244u8, 28u8, 0u8, 0u8,
+                245u8, 28u8, 0u8, 0u8, 246u8, 28u8, 0u8, 0u8, 247u8, 28u8, 0u8, 0u8, 248u8, 28u8,
+                0u8, 0u8, 249u8, 28u8, 0u8, 0u8, 250u8, 28u8, 0u8, 0u8, 251u8, 28u8, 0u8, 0u8,
+                252u8, 28u8, 0u8, 0u8, 253u8, 28u8, 0u8, 0u8, 254u8, 28u8, 0u8, 0u8, 255u8, 28u8,
+                0u8, 0u8, 0u8, 29u8, 0u8, 0u8, 1u8, 29u8, 0u8, 0u8, 2u8, 29u8, 0u8, 0u8, 3u8, 29u8,
+                0u8, 0u8, 4u8, 29u8, 0u8, 0u8, 5u8, 29u8, 0u8, 0u8, 6u8, 29u8, 0u8, 0u8, 7u8, 29u8,
+                0u8, 0u8, 8u8, 29u8, 0u8, 0u8, 9u8, 29u8, 0u8, 0u8, 10u8, 29u8, 0u8, 0u8, 11u8,
+                29u8, 0u8, 0u8, 12u8, 29u8, 0u8, 0u8, 13u8, 29u8, 0u8, 0u8, 14u8, 29u8, 0u8, 0u8,
+                15u8, 29u8, 0u8, 0u8, 16u8, 29u8, 0u8, 0u8, 17u8, 29u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 18u8, 29u8, 0u8,
+                0u8, 19u8, 29u8, 0u8, 0u8, 20u8, 29u8, 0u8, 0u8, 21u8, 29u8, 0u8, 0u8, 22u8, 29u8,
+                0u8, 0u8, 23u8, 29u8, 0u8, 0u8, 24u8, 29u8, 0u8, 0u8, 25u8, 29u8, 0u8, 0u8, 26u8,
+                29u8, 0u8, 0u8, 27u8, 29u8, 0u8, 0u8, 28u8, 29u8, 0u8, 0u8, 29u8, 29u8, 0u8, 0u8,
+                30u8, 29u8, 0u8, 0u8, 31u8, 29u8, 0u8, 0u8, 32u8, 29u8, 0u8, 0u8, 33u8, 29u8, 0u8,
+                0u8, 34u8, 29u8, 0u8, 0u8, 35u8, 29u8, 0u8, 0u8, 36u8, 29u8, 0u8, 0u8, 37u8, 29u8,
+                0u8, 0u8, 38u8, 29u8, 0u8, 0u8, 39u8, 29u8, 0u8, 0u8, 40u8, 29u8, 0u8, 0u8, 41u8,
+                29u8, 0u8, 0u8, 42u8, 29u8, 0u8, 0u8, 43u8, 29u8, 0u8, 0u8, 44u8, 29u8, 0u8, 0u8,
+                45u8, 29u8, 0u8, 0u8, 46u8, 29u8, 0u8, 0u8, 47u8, 29u8, 0u8, 0u8, 48u8, 29u8, 0u8,
+                0u8, 49u8, 29u8, 0u8, 0u8, 50u8, 29u8, 0u8, 0u8, 51u8, 29u8, 0u8, 0u8, 52u8, 29u8,
+                0u8, 0u8, 53u8, 29u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 54u8, 29u8, 0u8, 0u8, 55u8, 29u8, 0u8, 0u8, 56u8,
+                29u8, 0u8, 0u8, 57u8, 29u8, 0u8, 0u8, 58u8, 29u8, 0u8, 0u8, 59u8, 29u8, 0u8, 0u8,
+                60u8, 29u8, 0u8, 0u8, 61u8, 29u8, 0u8, 0u8, 62u8, 29u8, 0u8, 0u8, 63u8, 29u8, 0u8,
+                0u8, 64u8, 29u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 29u8, 0u8, 0u8, 66u8, 29u8,
+                0u8, 0u8, 67u8, 29u8, 0u8, 0u8, 68u8, 29u8, 0u8, 0u8, 69u8, 29u8, 0u8, 0u8, 70u8,
+                29u8, 0u8, 0u8, 71u8, 29u8, 0u8, 0u8, 72u8, 29u8, 0u8, 0u8, 73u8, 29u8, 0u8, 0u8,
+                74u8, 29u8, 0u8, 0u8, 75u8, 29u8, 0u8, 0u8, 76u8, 29u8, 0u8, 0u8, 77u8, 29u8, 0u8,
+                0u8, 78u8, 29u8, 0u8, 0u8, 79u8, 29u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 80u8, 29u8,
+                0u8, 0u8, 81u8, 29u8, 0u8, 0u8, 82u8, 29u8, 0u8, 0u8, 83u8, 29u8, 0u8, 0u8, 84u8,
+                29u8, 0u8, 0u8, 85u8, 29u8, 0u8, 0u8, 86u8, 29u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                87u8, 29u8, 0u8, 0u8, 88u8, 29u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 2u8,
+                0u8, 0u8, 209u8, 2u8, 0u8, 0u8, 230u8, 0u8, 0u8, 0u8, 153u8, 2u8, 0u8, 0u8, 83u8,
+                2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 163u8, 2u8, 0u8, 0u8, 102u8, 171u8, 0u8, 0u8,
+                165u8, 2u8, 0u8, 0u8, 164u8, 2u8, 0u8, 0u8, 86u8, 2u8, 0u8, 0u8, 87u8, 2u8, 0u8,
+                0u8, 145u8, 29u8, 0u8, 0u8, 88u8, 2u8, 0u8, 0u8, 94u8, 2u8, 0u8, 0u8, 169u8, 2u8,
+                0u8, 0u8, 100u8, 2u8, 0u8, 0u8, 98u8, 2u8, 0u8, 0u8, 96u8, 2u8, 0u8, 0u8, 155u8,
+                2u8, 0u8, 0u8, 39u8, 1u8, 0u8, 0u8, 156u8, 2u8, 0u8, 0u8, 103u8, 2u8, 0u8, 0u8,
+                132u8, 2u8, 0u8, 0u8, 170u8, 2u8, 0u8, 0u8, 171u8, 2u8, 0u8, 0u8, 108u8, 2u8, 0u8,
+                0u8, 0u8, 0u8, 89u8, 29u8, 142u8, 167u8, 0u8, 0u8, 110u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+                90u8, 29u8, 142u8, 2u8, 0u8, 0u8, 0u8, 0u8, 91u8, 29u8, 248u8, 0u8, 0u8, 0u8,
+                118u8, 2u8, 0u8, 0u8, 119u8, 2u8, 0u8, 0u8, 113u8, 0u8, 0u8, 0u8, 122u8, 2u8, 0u8,
+                0u8, 0u8, 0u8, 92u8, 29u8, 125u8, 2u8, 0u8, 0u8, 126u8, 2u8, 0u8, 0u8, 128u8, 2u8,
+                0u8, 0u8, 168u8, 2u8, 0u8, 0u8, 166u8, 2u8, 0u8, 0u8, 103u8, 171u8, 0u8, 0u8,
+                167u8, 2u8, 0u8, 0u8, 136u8, 2u8, 0u8, 0u8, 113u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 143u8, 2u8, 0u8, 0u8, 161u8, 2u8, 0u8, 0u8, 162u8, 2u8, 0u8, 0u8, 152u8, 2u8,
+                0u8, 0u8, 192u8, 1u8, 0u8, 0u8, 193u8, 1u8, 0u8, 0u8, 194u8, 1u8, 0u8, 0u8, 0u8,
+                0u8, 93u8, 29u8, 0u8, 0u8, 94u8, 29u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 95u8, 29u8, 0u8,
+                0u8, 96u8, 29u8, 0u8, 0u8, 97u8, 29u8, 0u8, 0u8, 98u8, 29u8, 0u8, 0u8, 99u8, 29u8,
+                0u8, 0u8, 100u8, 29u8, 0u8, 0u8, 101u8, 29u8, 0u8, 0u8, 102u8, 29u8, 0u8, 0u8,
+                103u8, 29u8, 0u8, 0u8, 104u8, 29u8, 0u8, 0u8, 105u8, 29u8, 0u8, 0u8, 106u8, 29u8,
+                0u8, 0u8, 107u8, 29u8, 0u8, 0u8, 108u8, 29u8, 0u8, 0u8, 109u8, 29u8, 0u8, 0u8,
+                110u8, 29u8, 0u8, 0u8, 111u8, 29u8, 0u8, 0u8, 112u8, 29u8, 0u8, 0u8, 113u8, 29u8,
+                0u8, 0u8, 114u8, 29u8, 0u8, 0u8, 115u8, 29u8, 0u8, 0u8, 116u8, 29u8, 0u8, 0u8,
+                117u8, 29u8, 0u8, 0u8, 118u8, 29u8, 0u8, 0u8, 119u8, 29u8, 0u8, 0u8, 120u8, 29u8,
+                0u8, 0u8, 121u8, 29u8, 0u8, 0u8, 122u8, 29u8, 0u8, 0u8, 123u8, 29u8, 0u8, 0u8,
+                124u8, 29u8, 0u8, 0u8, 125u8, 29u8, 0u8, 0u8, 126u8, 29u8, 0u8, 0u8, 127u8, 29u8,
+                0u8, 0u8, 128u8, 29u8, 0u8, 0u8, 129u8, 29u8, 0u8, 0u8, 130u8, 29u8, 0u8, 0u8,
+                131u8, 29u8, 0u8, 0u8, 132u8, 29u8, 0u8, 0u8, 133u8, 29u8, 0u8, 0u8, 134u8, 29u8,
+                0u8, 0u8, 135u8, 29u8, 0u8, 0u8, 136u8, 29u8, 0u8, 0u8, 137u8, 29u8, 0u8, 0u8,
+                138u8, 29u8, 0u8, 0u8, 139u8, 29u8, 0u8, 0u8, 140u8, 29u8, 0u8, 0u8, 141u8, 29u8,
+                0u8, 0u8, 142u8, 29u8, 0u8, 0u8, 143u8, 29u8, 0u8, 0u8, 144u8, 29u8, 0u8, 0u8,
+                145u8, 29u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 146u8, 29u8, 0u8, 0u8, 147u8, 29u8, 0u8,
+                0u8, 148u8, 29u8, 0u8, 0u8, 149u8, 29u8, 0u8, 0u8, 150u8, 29u8, 0u8, 0u8, 151u8,
+                29u8, 0u8, 0u8, 152u8, 29u8, 0u8, 0u8, 153u8, 29u8, 0u8, 0u8, 154u8, 29u8, 0u8,
+                0u8, 155u8, 29u8, 0u8, 0u8, 156u8, 29u8, 0u8, 0u8, 157u8, 29u8, 0u8, 0u8, 158u8,
+                29u8, 0u8, 0u8, 159u8, 29u8, 0u8, 0u8, 160u8, 29u8, 0u8, 0u8, 161u8, 29u8, 0u8,
+                0u8, 162u8, 29u8, 0u8, 0u8, 163u8, 29u8, 0u8, 0u8, 164u8, 29u8, 0u8, 0u8, 165u8,
+                29u8, 0u8, 0u8, 166u8, 29u8, 0u8, 0u8, 167u8, 29u8, 0u8, 0u8, 168u8, 29u8, 0u8,
+                0u8, 169u8, 29u8, 0u8, 0u8, 170u8, 29u8, 0u8, 0u8, 171u8, 29u8, 0u8, 0u8, 172u8,
+                29u8, 0u8, 0u8, 173u8, 29u8, 0u8, 0u8, 174u8, 29u8, 0u8, 0u8, 175u8, 29u8, 0u8,
+                0u8, 176u8, 29u8, 0u8, 0u8, 177u8, 29u8, 0u8, 0u8, 178u8, 29u8, 0u8, 0u8, 179u8,
+                29u8, 0u8, 0u8, 180u8, 29u8, 0u8, 0u8, 181u8, 29u8, 0u8, 0u8, 182u8, 29u8, 0u8,
+                0u8, 183u8, 29u8, 0u8, 0u8, 184u8, 29u8, 0u8, 0u8, 185u8, 29u8, 0u8, 0u8, 186u8,
+                29u8, 0u8, 0u8, 187u8, 29u8, 0u8, 0u8, 188u8, 29u8, 0u8, 0u8, 189u8, 29u8, 0u8,
+                0u8, 190u8, 29u8, 0u8, 0u8, 191u8, 29u8, 0u8, 0u8, 192u8, 29u8, 0u8, 0u8, 193u8,
+                29u8, 0u8, 0u8, 194u8, 29u8, 0u8, 0u8, 195u8, 29u8, 0u8, 0u8, 196u8, 29u8, 0u8,
+                0u8, 197u8, 29u8, 0u8, 0u8, 198u8, 29u8, 0u8, 0u8, 199u8, 29u8, 0u8, 0u8, 200u8,
+                29u8, 0u8, 0u8, 201u8, 29u8, 0u8, 0u8, 202u8, 29u8, 0u8, 0u8, 203u8, 29u8, 0u8,
+                0u8, 204u8, 29u8, 0u8, 0u8, 205u8, 29u8, 0u8, 0u8, 206u8, 29u8, 0u8, 0u8, 207u8,
+                29u8, 0u8, 0u8, 208u8, 29u8, 0u8, 0u8, 209u8, 29u8, 255u8, 255u8, 255u8, 255u8,
+                255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8,
+                255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8,
+                255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 255u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 113u8,
+                0u8, 0u8, 0u8, 114u8, 0u8, 0u8, 0u8, 115u8, 0u8, 0u8, 0u8, 116u8, 0u8, 0u8, 0u8,
+                117u8, 0u8, 0u8, 0u8, 118u8, 0u8, 0u8, 0u8, 119u8, 0u8, 0u8, 0u8, 120u8, 0u8, 0u8,
+                0u8, 121u8, 0u8, 0u8, 0u8, 122u8, 0u8, 0u8, 0u8, 97u8, 0u8, 0u8, 0u8, 98u8, 0u8,
+                0u8, 0u8, 99u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 101u8, 0u8, 0u8, 0u8, 102u8,
+                0u8, 0u8, 0u8, 103u8, 0u8, 0u8, 0u8, 104u8, 0u8, 0u8, 0u8, 105u8, 0u8, 0u8, 0u8,
+                106u8, 0u8, 0u8, 0u8, 107u8, 0u8, 0u8, 0u8, 108u8, 0u8, 0u8, 0u8, 109u8, 0u8, 0u8,
+                0u8, 110u8, 0u8, 0u8, 0u8, 111u8, 0u8, 0u8, 0u8, 112u8, 0u8, 0u8, 0u8, 113u8, 0u8,
+                0u8, 0u8, 114u8, 0u8, 0u8, 0u8, 115u8, 0u8, 0u8, 0u8, 116u8, 0u8, 0u8, 0u8, 117u8,
+                0u8, 0u8, 0u8, 118u8, 0u8, 0u8, 0u8, 119u8, 0u8, 0u8, 0u8, 120u8, 0u8, 0u8, 0u8,
+                121u8, 0u8, 0u8, 0u8, 122u8, 0u8, 0u8, 0u8, 97u8, 0u8, 0u8, 0u8, 98u8, 0u8, 0u8,
+                0u8, 99u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 101u8, 0u8, 0u8, 0u8, 102u8, 0u8,
+                0u8, 0u8, 103u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 105u8, 0u8, 0u8, 0u8, 106u8,
+                0u8, 0u8, 0u8, 107u8, 0u8, 0u8, 0u8, 108u8, 0u8, 0u8, 0u8, 109u8, 0u8, 0u8, 0u8,
+                110u8, 0u8, 0u8, 0u8, 111u8, 0u8, 0u8, 0u8, 112u8, 0u8, 0u8, 0u8, 113u8, 0u8, 0u8,
+                0u8, 114u8, 0u8, 0u8, 0u8, 115u8, 0u8, 0u8, 0u8, 116u8, 0u8, 0u8, 0u8, 117u8, 0u8,
+                0u8, 0u8, 118u8, 0u8, 0u8, 0u8, 119u8, 0u8, 0u8, 0u8, 120u8, 0u8, 0u8, 0u8, 121u8,
+                0u8, 0u8, 0u8, 122u8, 0u8, 0u8, 0u8, 97u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 99u8,
+                0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 103u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 106u8, 0u8, 0u8, 0u8, 107u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 110u8, 0u8, 0u8, 0u8, 111u8,
+                0u8, 0u8, 0u8, 112u8, 0u8, 0u8, 0u8, 113u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                115u8, 0u8, 0u8, 0u8, 116u8, 0u8, 0u8, 0u8, 117u8, 0u8, 0u8, 0u8, 118u8, 0u8, 0u8,
+                0u8, 119u8, 0u8, 0u8, 0u8, 120u8, 0u8, 0u8, 0u8, 121u8, 0u8, 0u8, 0u8, 122u8, 0u8,
+                0u8, 0u8, 97u8, 0u8, 0u8, 0u8, 98u8, 0u8, 0u8, 0u8, 99u8, 0u8, 0u8, 0u8, 100u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 102u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 104u8,
+                0u8, 0u8, 0u8, 105u8, 0u8, 0u8, 0u8, 106u8, 0u8, 0u8, 0u8, 107u8, 0u8, 0u8, 0u8,
+                108u8, 0u8, 0u8, 0u8, 109u8, 0u8, 0u8, 0u8, 110u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 112u8, 0u8, 0u8, 0u8, 113u8, 0u8, 0u8, 0u8, 114u8, 0u8, 0u8, 0u8, 115u8, 0u8,
+                0u8, 0u8, 116u8, 0u8, 0u8, 0u8, 117u8, 0u8, 0u8, 0u8, 118u8, 0u8, 0u8, 0u8, 119u8,
+                0u8, 0u8, 0u8, 120u8, 0u8, 0u8, 0u8, 121u8, 0u8, 0u8, 0u8, 122u8, 0u8, 0u8, 0u8,
+                97u8, 0u8, 0u8, 0u8, 98u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8,
+                101u8, 0u8, 0u8, 0u8, 102u8, 0u8, 0u8, 0u8, 103u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 106u8, 0u8, 0u8, 0u8, 107u8, 0u8, 0u8, 0u8, 108u8, 0u8,
+                0u8, 0u8, 109u8, 0u8, 0u8, 0u8, 110u8, 0u8, 0u8, 0u8, 111u8, 0u8, 0u8, 0u8, 112u8,
+                0u8, 0u8, 0u8, 113u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 115u8, 0u8, 0u8, 0u8,
+                116u8, 0u8, 0u8, 0u8, 117u8, 0u8, 0u8, 0u8, 118u8, 0u8, 0u8, 0u8, 119u8, 0u8, 0u8,
+                0u8, 120u8, 0u8, 0u8, 0u8, 121u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 0u8,
+                0u8, 0u8, 98u8, 0u8, 0u8, 0u8, 105u8, 0u8, 0u8, 0u8, 106u8, 0u8, 0u8, 0u8, 107u8,
+                0u8, 0u8, 0u8, 108u8, 0u8, 0u8, 0u8, 109u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                111u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                115u8, 0u8, 0u8, 0u8, 116u8, 0u8, 0u8, 0u8, 117u8, 0u8, 0u8, 0u8, 118u8, 0u8, 0u8,
+                0u8, 119u8, 0u8, 0u8, 0u8, 120u8, 0u8, 0u8, 0u8, 121u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 97u8, 0u8, 0u8, 0u8, 98u8, 0u8, 0u8, 0u8, 99u8, 0u8, 0u8, 0u8, 100u8,
+                0u8, 0u8, 0u8, 101u8, 0u8, 0u8, 0u8, 102u8, 0u8, 0u8, 0u8, 103u8, 0u8, 0u8, 0u8,
+                104u8, 0u8, 0u8, 0u8, 105u8, 0u8, 0u8, 0u8, 106u8, 0u8, 0u8, 0u8, 107u8, 0u8, 0u8,
+                0u8, 108u8, 0u8, 0u8, 0u8, 109u8, 0u8, 0u8, 0u8, 110u8, 0u8, 0u8, 0u8, 119u8, 0u8,
+                0u8, 0u8, 120u8, 0u8, 0u8, 0u8, 121u8, 0u8, 0u8, 0u8, 122u8, 0u8, 0u8, 0u8, 49u8,
+                1u8, 0u8, 0u8, 55u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 177u8,
+                3u8, 0u8, 0u8, 178u8, 3u8, 0u8, 0u8, 179u8, 3u8, 0u8, 0u8, 180u8, 3u8, 0u8, 0u8,
+                181u8, 3u8, 0u8, 0u8, 182u8, 3u8, 0u8, 0u8, 183u8, 3u8, 0u8, 0u8, 184u8, 3u8, 0u8,
+                0u8, 185u8, 3u8, 0u8, 0u8, 186u8, 3u8, 0u8, 0u8, 187u8, 3u8, 0u8, 0u8, 188u8, 3u8,
+                0u8, 0u8, 189u8, 3u8, 0u8, 0u8, 190u8, 3u8, 0u8, 0u8, 191u8, 3u8, 0u8, 0u8, 192u8,
+                3u8, 0u8, 0u8, 193u8, 3u8, 0u8, 0u8, 184u8, 3u8, 0u8, 0u8, 195u8, 3u8, 0u8, 0u8,
+                196u8, 3u8, 0u8, 0u8, 197u8, 3u8, 0u8, 0u8, 198u8, 3u8, 0u8, 0u8, 199u8, 3u8, 0u8,
+                0u8, 200u8, 3u8, 0u8, 0u8, 201u8, 3u8, 0u8, 0u8, 7u8, 34u8, 0u8, 0u8, 177u8, 3u8,
+                0u8, 0u8, 178u8, 3u8, 0u8, 0u8, 179u8, 3u8, 0u8, 0u8, 180u8, 3u8, 0u8, 0u8, 181u8,
+                3u8, 0u8, 0u8, 182u8, 3u8, 0u8, 0u8, 183u8, 3u8, 0u8, 0u8, 184u8, 3u8, 0u8, 0u8,
+                185u8, 3u8, 0u8, 0u8, 186u8, 3u8, 0u8, 0u8, 187u8, 3u8, 0u8, 0u8, 188u8, 3u8, 0u8,
+                0u8, 189u8, 3u8, 0u8, 0u8, 190u8, 3u8, 0u8, 0u8, 191u8, 3u8, 0u8, 0u8, 192u8, 3u8,
+                0u8, 0u8, 193u8, 3u8, 0u8, 0u8, 195u8, 3u8, 0u8, 0u8, 195u8, 3u8, 0u8, 0u8, 196u8,
+                3u8, 0u8, 0u8, 197u8, 3u8, 0u8, 0u8, 198u8, 3u8, 0u8, 0u8, 199u8, 3u8, 0u8, 0u8,
+                200u8, 3u8, 0u8, 0u8, 201u8, 3u8, 0u8, 0u8, 2u8, 34u8, 0u8, 0u8, 181u8, 3u8, 0u8,
+                0u8, 184u8, 3u8, 0u8, 0u8, 186u8, 3u8, 0u8, 0u8, 198u8, 3u8, 0u8, 0u8, 193u8, 3u8,
+                0u8, 0u8, 192u8, 3u8, 0u8, 0u8, 177u8, 3u8, 0u8, 0u8, 178u8, 3u8, 0u8, 0u8, 179u8,
+                3u8, 0u8, 0u8, 180u8, 3u8, 0u8, 0u8, 181u8, 3u8, 0u8, 0u8, 182u8, 3u8, 0u8, 0u8,
+                183u8, 3u8, 0u8, 0u8, 184u8, 3u8, 0u8, 0u8, 185u8, 3u8, 0u8, 0u8, 186u8, 3u8, 0u8,
+                0u8, 187u8, 3u8, 0u8, 0u8, 188u8, 3u8, 0u8, 0u8, 189u8, 3u8, 0u8, 0u8, 190u8, 3u8,
+                0u8, 0u8, 199u8, 3u8, 0u8, 0u8, 200u8, 3u8, 0u8, 0u8, 201u8, 3u8, 0u8, 0u8, 2u8,
+                34u8, 0u8, 0u8, 181u8, 3u8, 0u8, 0u8, 184u8, 3u8, 0u8, 0u8, 186u8, 3u8, 0u8, 0u8,
+                198u8, 3u8, 0u8, 0u8, 193u8, 3u8, 0u8, 0u8, 192u8, 3u8, 0u8, 0u8, 221u8, 3u8, 0u8,
+                0u8, 221u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 48u8, 0u8, 0u8,
+                0u8, 49u8, 0u8, 0u8, 0u8, 50u8, 0u8, 0u8, 0u8, 51u8, 0u8, 0u8, 0u8, 52u8, 0u8, 0u8,
+                0u8, 53u8, 0u8, 0u8, 0u8, 54u8, 0u8, 0u8, 0u8, 55u8, 0u8, 0u8, 0u8, 56u8, 0u8, 0u8,
+                0u8, 57u8, 0u8, 0u8, 0u8, 48u8, 0u8, 0u8, 0u8, 49u8, 0u8, 0u8, 0u8, 50u8, 0u8, 0u8,
+                0u8, 51u8, 0u8, 0u8, 0u8, 52u8, 0u8, 0u8, 0u8, 53u8, 0u8, 0u8, 0u8, 54u8, 0u8, 0u8,
+                0u8, 55u8, 0u8, 0u8, 0u8, 56u8, 0u8, 0u8, 0u8, 57u8, 0u8, 0u8, 0u8, 48u8, 0u8, 0u8,
+                0u8, 49u8, 0u8, 0u8, 0u8, 50u8, 0u8, 0u8, 0u8, 51u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                210u8, 29u8, 0u8, 0u8, 211u8, 29u8, 0u8, 0u8, 212u8, 29u8, 0u8, 0u8, 213u8, 29u8,
+                0u8, 0u8, 214u8, 29u8, 0u8, 0u8, 215u8, 29u8, 0u8, 0u8, 216u8, 29u8, 0u8, 0u8,
+                217u8, 29u8, 0u8, 0u8, 218u8, 29u8, 0u8, 0u8, 219u8, 29u8, 0u8, 0u8, 220u8, 29u8,
+                0u8, 0u8, 221u8, 29u8, 0u8, 0u8, 222u8, 29u8, 0u8, 0u8, 223u8, 29u8, 0u8, 0u8,
+                224u8, 29u8, 0u8, 0u8, 225u8, 29u8, 0u8, 0u8, 226u8, 29u8, 0u8, 0u8, 227u8, 29u8,
+                0u8, 0u8, 228u8, 29u8, 0u8, 0u8, 229u8, 29u8, 0u8, 0u8, 230u8, 29u8, 0u8, 0u8,
+                231u8, 29u8, 0u8, 0u8, 232u8, 29u8, 0u8, 0u8, 233u8, 29u8, 0u8, 0u8, 234u8, 29u8,
+                0u8, 0u8, 235u8, 29u8, 0u8, 0u8, 236u8, 29u8, 0u8, 0u8, 237u8, 29u8, 0u8, 0u8,
+                238u8, 29u8, 0u8, 0u8, 239u8, 29u8, 0u8, 0u8, 240u8, 29u8, 0u8, 0u8, 241u8, 29u8,
+                0u8, 0u8, 242u8, 29u8, 0u8, 0u8, 243u8, 29u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 39u8, 6u8, 0u8, 0u8, 40u8, 6u8, 0u8, 0u8, 44u8, 6u8, 0u8, 0u8, 47u8, 6u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 72u8, 6u8, 0u8, 0u8, 50u8, 6u8, 0u8, 0u8, 45u8, 6u8, 0u8,
+                0u8, 55u8, 6u8, 0u8, 0u8, 74u8, 6u8, 0u8, 0u8, 67u8, 6u8, 0u8, 0u8, 68u8, 6u8, 0u8,
+                0u8, 69u8, 6u8, 0u8, 0u8, 70u8, 6u8, 0u8, 0u8, 51u8, 6u8, 0u8, 0u8, 57u8, 6u8, 0u8,
+                0u8, 65u8, 6u8, 0u8, 0u8, 53u8, 6u8, 0u8, 0u8, 66u8, 6u8, 0u8, 0u8, 49u8, 6u8, 0u8,
+                0u8, 52u8, 6u8, 0u8, 0u8, 42u8, 6u8, 0u8, 0u8, 43u8, 6u8, 0u8, 0u8, 46u8, 6u8, 0u8,
+                0u8, 48u8, 6u8, 0u8, 0u8, 54u8, 6u8, 0u8, 0u8, 56u8, 6u8, 0u8, 0u8, 58u8, 6u8, 0u8,
+                0u8, 110u8, 6u8, 0u8, 0u8, 186u8, 6u8, 0u8, 0u8, 161u8, 6u8, 0u8, 0u8, 111u8, 6u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 40u8, 6u8, 0u8, 0u8, 44u8, 6u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 71u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 45u8, 6u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 74u8, 6u8, 0u8, 0u8, 67u8, 6u8, 0u8, 0u8, 68u8, 6u8,
+                0u8, 0u8, 69u8, 6u8, 0u8, 0u8, 70u8, 6u8, 0u8, 0u8, 51u8, 6u8, 0u8, 0u8, 57u8, 6u8,
+                0u8, 0u8, 65u8, 6u8, 0u8, 0u8, 53u8, 6u8, 0u8, 0u8, 66u8, 6u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 52u8, 6u8, 0u8, 0u8, 42u8, 6u8, 0u8, 0u8, 43u8, 6u8, 0u8, 0u8, 46u8, 6u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 54u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 58u8, 6u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 44u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 45u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 74u8, 6u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 68u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 70u8, 6u8,
+                0u8, 0u8, 51u8, 6u8, 0u8, 0u8, 57u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 53u8, 6u8,
+                0u8, 0u8, 66u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 52u8, 6u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 46u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 54u8, 6u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 58u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 186u8, 6u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 111u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 40u8, 6u8,
+                0u8, 0u8, 44u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 71u8, 6u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 45u8, 6u8, 0u8, 0u8, 55u8, 6u8, 0u8, 0u8, 74u8, 6u8,
+                0u8, 0u8, 67u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 69u8, 6u8, 0u8, 0u8, 70u8, 6u8,
+                0u8, 0u8, 51u8, 6u8, 0u8, 0u8, 57u8, 6u8, 0u8, 0u8, 65u8, 6u8, 0u8, 0u8, 53u8, 6u8,
+                0u8, 0u8, 66u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 52u8, 6u8, 0u8, 0u8, 42u8, 6u8,
+                0u8, 0u8, 43u8, 6u8, 0u8, 0u8, 46u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 54u8, 6u8,
+                0u8, 0u8, 56u8, 6u8, 0u8, 0u8, 58u8, 6u8, 0u8, 0u8, 110u8, 6u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 161u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 39u8, 6u8, 0u8, 0u8, 40u8, 6u8,
+                0u8, 0u8, 44u8, 6u8, 0u8, 0u8, 47u8, 6u8, 0u8, 0u8, 71u8, 6u8, 0u8, 0u8, 72u8, 6u8,
+                0u8, 0u8, 50u8, 6u8, 0u8, 0u8, 45u8, 6u8, 0u8, 0u8, 55u8, 6u8, 0u8, 0u8, 74u8, 6u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 68u8, 6u8, 0u8, 0u8, 69u8, 6u8, 0u8, 0u8, 70u8, 6u8,
+                0u8, 0u8, 51u8, 6u8, 0u8, 0u8, 57u8, 6u8, 0u8, 0u8, 65u8, 6u8, 0u8, 0u8, 53u8, 6u8,
+                0u8, 0u8, 66u8, 6u8, 0u8, 0u8, 49u8, 6u8, 0u8, 0u8, 52u8, 6u8, 0u8, 0u8, 42u8, 6u8,
+                0u8, 0u8, 43u8, 6u8, 0u8, 0u8, 46u8, 6u8, 0u8, 0u8, 48u8, 6u8, 0u8, 0u8, 54u8, 6u8,
+                0u8, 0u8, 56u8, 6u8, 0u8, 0u8, 58u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 40u8, 6u8, 0u8, 0u8, 44u8, 6u8,
+                0u8, 0u8, 47u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 72u8, 6u8, 0u8, 0u8, 50u8, 6u8,
+                0u8, 0u8, 45u8, 6u8, 0u8, 0u8, 55u8, 6u8, 0u8, 0u8, 74u8, 6u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 68u8, 6u8, 0u8, 0u8, 69u8, 6u8, 0u8, 0u8, 70u8, 6u8, 0u8, 0u8, 51u8, 6u8,
+                0u8, 0u8, 57u8, 6u8, 0u8, 0u8, 0u8, 0u8, 108u8, 5u8, 0u8, 0u8, 165u8, 12u8, 0u8,
+                0u8, 167u8, 12u8, 0u8, 0u8, 169u8, 12u8, 0u8, 0u8, 171u8, 12u8, 0u8, 0u8, 173u8,
+                12u8, 0u8, 0u8, 175u8, 12u8, 0u8, 0u8, 177u8, 12u8, 0u8, 0u8, 179u8, 12u8, 0u8,
+                0u8, 181u8, 12u8, 0u8, 0u8, 183u8, 12u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 188u8, 37u8,
+                0u8, 0u8, 191u8, 37u8, 0u8, 0u8, 194u8, 37u8, 0u8, 0u8, 197u8, 37u8, 0u8, 0u8,
+                200u8, 37u8, 0u8, 0u8, 203u8, 37u8, 0u8, 0u8, 206u8, 37u8, 0u8, 0u8, 209u8, 37u8,
+                0u8, 0u8, 212u8, 37u8, 0u8, 0u8, 215u8, 37u8, 0u8, 0u8, 185u8, 44u8, 99u8, 0u8,
+                0u8, 0u8, 114u8, 0u8, 0u8, 0u8, 0u8, 0u8, 194u8, 8u8, 0u8, 0u8, 188u8, 12u8, 0u8,
+                0u8, 0u8, 0u8, 113u8, 0u8, 0u8, 0u8, 114u8, 0u8, 0u8, 0u8, 115u8, 0u8, 0u8, 0u8,
+                116u8, 0u8, 0u8, 0u8, 117u8, 0u8, 0u8, 0u8, 118u8, 0u8, 0u8, 0u8, 119u8, 0u8, 0u8,
+                0u8, 120u8, 0u8, 0u8, 0u8, 121u8, 0u8, 0u8, 0u8, 122u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                190u8, 12u8, 0u8, 0u8, 87u8, 9u8, 0u8, 0u8, 192u8, 12u8, 0u8, 0u8, 184u8, 3u8, 0u8,
+                0u8, 194u8, 44u8, 0u8, 0u8, 197u8, 12u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 20u8, 9u8, 0u8, 0u8, 196u8, 8u8, 0u8, 0u8, 199u8, 12u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 201u8, 12u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 203u8, 12u8, 0u8, 0u8,
+                205u8, 12u8, 181u8, 48u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 75u8, 98u8, 0u8, 0u8, 87u8,
+                91u8, 0u8, 0u8, 204u8, 83u8, 0u8, 0u8, 198u8, 48u8, 153u8, 48u8, 140u8, 78u8, 0u8,
+                0u8, 26u8, 89u8, 0u8, 0u8, 227u8, 137u8, 0u8, 0u8, 41u8, 89u8, 0u8, 0u8, 164u8,
+                78u8, 0u8, 0u8, 32u8, 102u8, 0u8, 0u8, 33u8, 113u8, 0u8, 0u8, 153u8, 101u8, 0u8,
+                0u8, 77u8, 82u8, 0u8, 0u8, 140u8, 95u8, 0u8, 0u8, 141u8, 81u8, 0u8, 0u8, 176u8,
+                101u8, 0u8, 0u8, 29u8, 82u8, 0u8, 0u8, 66u8, 125u8, 0u8, 0u8, 31u8, 117u8, 0u8,
+                0u8, 169u8, 140u8, 0u8, 0u8, 240u8, 88u8, 0u8, 0u8, 57u8, 84u8, 0u8, 0u8, 20u8,
+                111u8, 0u8, 0u8, 149u8, 98u8, 0u8, 0u8, 85u8, 99u8, 0u8, 0u8, 0u8, 78u8, 0u8, 0u8,
+                9u8, 78u8, 0u8, 0u8, 74u8, 144u8, 0u8, 0u8, 230u8, 93u8, 0u8, 0u8, 45u8, 78u8, 0u8,
+                0u8, 243u8, 83u8, 0u8, 0u8, 7u8, 99u8, 0u8, 0u8, 112u8, 141u8, 0u8, 0u8, 83u8,
+                98u8, 0u8, 0u8, 129u8, 121u8, 0u8, 0u8, 122u8, 122u8, 0u8, 0u8, 8u8, 84u8, 0u8,
+                0u8, 128u8, 110u8, 0u8, 0u8, 9u8, 103u8, 0u8, 0u8, 8u8, 103u8, 0u8, 0u8, 51u8,
+                117u8, 0u8, 0u8, 114u8, 82u8, 0u8, 0u8, 182u8, 85u8, 0u8, 0u8, 77u8, 145u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 207u8, 44u8, 0u8, 0u8, 210u8, 44u8, 0u8, 0u8, 213u8, 44u8, 0u8, 0u8,
+                216u8, 44u8, 0u8, 0u8, 219u8, 44u8, 0u8, 0u8, 222u8, 44u8, 0u8, 0u8, 225u8, 44u8,
+                0u8, 0u8, 228u8, 44u8, 0u8, 0u8, 231u8, 44u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 151u8, 95u8, 0u8, 0u8, 239u8, 83u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 48u8, 0u8, 0u8, 0u8, 49u8, 0u8, 0u8, 0u8, 50u8, 0u8,
+                0u8, 0u8, 51u8, 0u8, 0u8, 0u8, 52u8, 0u8, 0u8, 0u8, 53u8, 0u8, 0u8, 0u8, 54u8, 0u8,
+                0u8, 0u8, 55u8, 0u8, 0u8, 0u8, 56u8, 0u8, 0u8, 0u8, 57u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            ])
+        },
+        0u32,
+    ),
+    flags: 3u8,
+    passthrough_cap: 65u16,
+};
@@ -0,0 +1,3 @@
+// @generated
+pub mod ordinal_v1;
+pub mod spellout_v1;
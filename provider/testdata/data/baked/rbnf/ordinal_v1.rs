@@ -0,0 +1,491 @@
+// @generated
+#![cfg(feature = "icu_rbnf")]
+type DataStruct =
+    <::icu_rbnf::provider::OrdinalRulesV1Marker as ::icu_provider::DataMarker>::Yokeable;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[
+        ("ar", AR),
+        ("en", EN),
+        ("es", ES),
+        ("fil", FIL),
+        ("fr", FR),
+        ("ja", JA),
+        ("ru", RU),
+        ("th", TH),
+        ("und", UND),
+    ]);
+static AR: &DataStruct = &::icu_decimal::provider::RbnfRulesV1 {
+    rule_sets: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 105u8, 103u8, 105u8, 116u8, 115u8, 45u8,
+                    111u8, 114u8, 100u8, 105u8, 110u8, 97u8, 108u8,
+                ])
+            },
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8, 2u8, 0u8, 0u8, 0u8]) },
+        )
+    },
+    rules: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 136u8, 146u8, 226u8,
+            134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 61u8,
+        ])
+    },
+};
+static EN: &DataStruct = &::icu_decimal::provider::RbnfRulesV1 {
+    rule_sets: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 105u8, 103u8, 105u8, 116u8, 115u8, 45u8,
+                    111u8, 114u8, 100u8, 105u8, 110u8, 97u8, 108u8,
+                ])
+            },
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8, 2u8, 0u8, 0u8, 0u8]) },
+        )
+    },
+    rules: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 136u8, 146u8, 226u8,
+            134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 61u8, 36u8,
+            40u8, 111u8, 114u8, 100u8, 105u8, 110u8, 97u8, 108u8, 44u8, 111u8, 110u8, 101u8, 123u8,
+            115u8, 116u8, 125u8, 116u8, 119u8, 111u8, 123u8, 110u8, 100u8, 125u8, 102u8, 101u8,
+            119u8, 123u8, 114u8, 100u8, 125u8, 111u8, 116u8, 104u8, 101u8, 114u8, 123u8, 116u8,
+            104u8, 125u8, 41u8, 36u8,
+        ])
+    },
+};
+static ES: &DataStruct = &::icu_decimal::provider::RbnfRulesV1 {
+    rule_sets: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 14u8, 0u8, 37u8, 0u8, 67u8, 0u8, 91u8, 0u8,
+                    125u8, 0u8, 156u8, 0u8, 100u8, 105u8, 103u8, 105u8, 116u8, 115u8, 45u8, 111u8,
+                    114u8, 100u8, 105u8, 110u8, 97u8, 108u8, 100u8, 105u8, 103u8, 105u8, 116u8,
+                    115u8, 45u8, 111u8, 114u8, 100u8, 105u8, 110u8, 97u8, 108u8, 45u8, 102u8,
+                    101u8, 109u8, 105u8, 110u8, 105u8, 110u8, 101u8, 100u8, 105u8, 103u8, 105u8,
+                    116u8, 115u8, 45u8, 111u8, 114u8, 100u8, 105u8, 110u8, 97u8, 108u8, 45u8,
+                    102u8, 101u8, 109u8, 105u8, 110u8, 105u8, 110u8, 101u8, 45u8, 112u8, 108u8,
+                    117u8, 114u8, 97u8, 108u8, 100u8, 105u8, 103u8, 105u8, 116u8, 115u8, 45u8,
+                    111u8, 114u8, 100u8, 105u8, 110u8, 97u8, 108u8, 45u8, 109u8, 97u8, 115u8, 99u8,
+                    117u8, 108u8, 105u8, 110u8, 101u8, 100u8, 105u8, 103u8, 105u8, 116u8, 115u8,
+                    45u8, 111u8, 114u8, 100u8, 105u8, 110u8, 97u8, 108u8, 45u8, 109u8, 97u8, 115u8,
+                    99u8, 117u8, 108u8, 105u8, 110u8, 101u8, 45u8, 97u8, 100u8, 106u8, 101u8, 99u8,
+                    116u8, 105u8, 118u8, 101u8, 100u8, 105u8, 103u8, 105u8, 116u8, 115u8, 45u8,
+                    111u8, 114u8, 100u8, 105u8, 110u8, 97u8, 108u8, 45u8, 109u8, 97u8, 115u8, 99u8,
+                    117u8, 108u8, 105u8, 110u8, 101u8, 45u8, 112u8, 108u8, 117u8, 114u8, 97u8,
+                    108u8, 100u8, 111u8, 114u8, 100u8, 45u8, 109u8, 97u8, 115u8, 99u8, 97u8, 98u8,
+                    98u8, 114u8, 101u8, 118u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    7u8, 0u8, 8u8, 0u8, 0u8, 0u8, 8u8, 0u8, 10u8, 0u8, 0u8, 0u8, 10u8, 0u8, 12u8,
+                    0u8, 0u8, 0u8, 12u8, 0u8, 14u8, 0u8, 0u8, 0u8, 14u8, 0u8, 16u8, 0u8, 0u8, 0u8,
+                    16u8, 0u8, 18u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 1u8, 0u8,
+                ])
+            },
+        )
+    },
+    rules: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            18u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 41u8, 0u8, 0u8, 0u8,
+            60u8, 0u8, 0u8, 0u8, 82u8, 0u8, 0u8, 0u8, 101u8, 0u8, 0u8, 0u8, 124u8, 0u8, 0u8, 0u8,
+            147u8, 0u8, 0u8, 0u8, 191u8, 0u8, 0u8, 0u8, 217u8, 0u8, 0u8, 0u8, 244u8, 0u8, 0u8, 0u8,
+            14u8, 1u8, 0u8, 0u8, 44u8, 1u8, 0u8, 0u8, 70u8, 1u8, 0u8, 0u8, 97u8, 1u8, 0u8, 0u8,
+            123u8, 1u8, 0u8, 0u8, 167u8, 1u8, 0u8, 0u8, 193u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 194u8, 186u8, 0u8,
+            1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8,
+            181u8, 137u8, 202u8, 179u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 194u8, 186u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 225u8, 181u8, 137u8, 202u8, 179u8, 0u8, 4u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 194u8,
+            186u8, 0u8, 20u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 100u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 134u8, 146u8, 226u8,
+            134u8, 146u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 61u8, 37u8, 100u8, 105u8, 103u8, 105u8, 116u8, 115u8, 45u8, 111u8,
+            114u8, 100u8, 105u8, 110u8, 97u8, 108u8, 45u8, 109u8, 97u8, 115u8, 99u8, 117u8, 108u8,
+            105u8, 110u8, 101u8, 61u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 136u8, 146u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 61u8, 46u8, 194u8, 170u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 136u8, 146u8, 226u8,
+            134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 61u8, 46u8,
+            225u8, 181u8, 131u8, 203u8, 162u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 136u8, 146u8, 226u8, 134u8, 146u8, 226u8,
+            134u8, 146u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 61u8, 46u8, 194u8, 186u8, 1u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8,
+            136u8, 146u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8,
+            48u8, 61u8, 46u8, 61u8, 37u8, 37u8, 100u8, 111u8, 114u8, 100u8, 45u8, 109u8, 97u8,
+            115u8, 99u8, 97u8, 98u8, 98u8, 114u8, 101u8, 118u8, 61u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 136u8, 146u8, 226u8,
+            134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 61u8, 46u8,
+            225u8, 181u8, 146u8, 203u8, 162u8,
+        ])
+    },
+};
+static FIL: &DataStruct = &::icu_decimal::provider::RbnfRulesV1 {
+    rule_sets: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 105u8, 103u8, 105u8, 116u8, 115u8, 45u8,
+                    111u8, 114u8, 100u8, 105u8, 110u8, 97u8, 108u8,
+                ])
+            },
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8, 2u8, 0u8, 0u8, 0u8]) },
+        )
+    },
+    rules: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 136u8, 146u8, 226u8,
+            134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 105u8, 107u8, 97u8, 61u8, 35u8, 44u8, 35u8, 35u8,
+            48u8, 61u8,
+        ])
+    },
+};
+static FR: &DataStruct = &::icu_decimal::provider::RbnfRulesV1 {
+    rule_sets: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 14u8, 0u8, 37u8, 0u8, 67u8, 0u8, 91u8, 0u8,
+                    100u8, 105u8, 103u8, 105u8, 116u8, 115u8, 45u8, 111u8, 114u8, 100u8, 105u8,
+                    110u8, 97u8, 108u8, 100u8, 105u8, 103u8, 105u8, 116u8, 115u8, 45u8, 111u8,
+                    114u8, 100u8, 105u8, 110u8, 97u8, 108u8, 45u8, 102u8, 101u8, 109u8, 105u8,
+                    110u8, 105u8, 110u8, 101u8, 100u8, 105u8, 103u8, 105u8, 116u8, 115u8, 45u8,
+                    111u8, 114u8, 100u8, 105u8, 110u8, 97u8, 108u8, 45u8, 102u8, 101u8, 109u8,
+                    105u8, 110u8, 105u8, 110u8, 101u8, 45u8, 112u8, 108u8, 117u8, 114u8, 97u8,
+                    108u8, 100u8, 105u8, 103u8, 105u8, 116u8, 115u8, 45u8, 111u8, 114u8, 100u8,
+                    105u8, 110u8, 97u8, 108u8, 45u8, 109u8, 97u8, 115u8, 99u8, 117u8, 108u8, 105u8,
+                    110u8, 101u8, 100u8, 105u8, 103u8, 105u8, 116u8, 115u8, 45u8, 111u8, 114u8,
+                    100u8, 105u8, 110u8, 97u8, 108u8, 45u8, 109u8, 97u8, 115u8, 99u8, 117u8, 108u8,
+                    105u8, 110u8, 101u8, 45u8, 112u8, 108u8, 117u8, 114u8, 97u8, 108u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 3u8, 0u8, 0u8, 0u8, 3u8, 0u8, 5u8, 0u8,
+                    0u8, 0u8, 5u8, 0u8, 7u8, 0u8, 0u8, 0u8, 7u8, 0u8, 9u8, 0u8, 0u8, 0u8,
+                ])
+            },
+        )
+    },
+    rules: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            9u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 44u8, 0u8, 0u8, 0u8, 70u8, 0u8, 0u8, 0u8,
+            121u8, 0u8, 0u8, 0u8, 147u8, 0u8, 0u8, 0u8, 200u8, 0u8, 0u8, 0u8, 226u8, 0u8, 0u8, 0u8,
+            21u8, 1u8, 0u8, 0u8, 47u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 37u8, 100u8, 105u8, 103u8, 105u8, 116u8,
+            115u8, 45u8, 111u8, 114u8, 100u8, 105u8, 110u8, 97u8, 108u8, 45u8, 109u8, 97u8, 115u8,
+            99u8, 117u8, 108u8, 105u8, 110u8, 101u8, 61u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 136u8, 146u8, 226u8, 134u8, 146u8,
+            226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 61u8, 36u8, 40u8, 111u8, 114u8,
+            100u8, 105u8, 110u8, 97u8, 108u8, 44u8, 111u8, 110u8, 101u8, 123u8, 114u8, 101u8,
+            125u8, 111u8, 116u8, 104u8, 101u8, 114u8, 123u8, 101u8, 125u8, 41u8, 36u8, 1u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8,
+            136u8, 146u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8,
+            48u8, 61u8, 36u8, 40u8, 111u8, 114u8, 100u8, 105u8, 110u8, 97u8, 108u8, 44u8, 111u8,
+            110u8, 101u8, 123u8, 114u8, 101u8, 115u8, 125u8, 111u8, 116u8, 104u8, 101u8, 114u8,
+            123u8, 101u8, 115u8, 125u8, 41u8, 36u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 136u8, 146u8, 226u8, 134u8, 146u8,
+            226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 61u8, 36u8, 40u8, 111u8, 114u8,
+            100u8, 105u8, 110u8, 97u8, 108u8, 44u8, 111u8, 110u8, 101u8, 123u8, 101u8, 114u8,
+            125u8, 111u8, 116u8, 104u8, 101u8, 114u8, 123u8, 101u8, 125u8, 41u8, 36u8, 1u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8,
+            136u8, 146u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8,
+            48u8, 61u8, 36u8, 40u8, 111u8, 114u8, 100u8, 105u8, 110u8, 97u8, 108u8, 44u8, 111u8,
+            110u8, 101u8, 123u8, 101u8, 114u8, 115u8, 125u8, 111u8, 116u8, 104u8, 101u8, 114u8,
+            123u8, 101u8, 115u8, 125u8, 41u8, 36u8,
+        ])
+    },
+};
+static JA: &DataStruct = &::icu_decimal::provider::RbnfRulesV1 {
+    rule_sets: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 105u8, 103u8, 105u8, 116u8, 115u8, 45u8,
+                    111u8, 114u8, 100u8, 105u8, 110u8, 97u8, 108u8,
+                ])
+            },
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8, 2u8, 0u8, 0u8, 0u8]) },
+        )
+    },
+    rules: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 34u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 231u8, 172u8, 172u8, 226u8,
+            136u8, 146u8, 226u8, 134u8, 146u8, 35u8, 44u8, 35u8, 35u8, 48u8, 226u8, 134u8, 146u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            231u8, 172u8, 172u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 61u8,
+        ])
+    },
+};
+static RU: &DataStruct = &::icu_decimal::provider::RbnfRulesV1 {
+    rule_sets: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    25u8, 0u8, 0u8, 0u8, 0u8, 0u8, 14u8, 0u8, 37u8, 0u8, 69u8, 0u8, 103u8, 0u8,
+                    133u8, 0u8, 165u8, 0u8, 197u8, 0u8, 221u8, 0u8, 254u8, 0u8, 33u8, 1u8, 64u8,
+                    1u8, 97u8, 1u8, 130u8, 1u8, 151u8, 1u8, 181u8, 1u8, 213u8, 1u8, 241u8, 1u8,
+                    15u8, 2u8, 45u8, 2u8, 66u8, 2u8, 96u8, 2u8, 128u8, 2u8, 156u8, 2u8, 186u8, 2u8,
+                    100u8, 105u8, 103u8, 105u8, 116u8, 115u8, 45u8, 111u8, 114u8, 100u8, 105u8,
+                    110u8, 97u8, 108u8, 100u8, 105u8, 103u8, 105u8, 116u8, 115u8, 45u8, 111u8,
+                    114u8, 100u8, 105u8, 110u8, 97u8, 108u8, 45u8, 102u8, 101u8, 109u8, 105u8,
+                    110u8, 105u8, 110u8, 101u8, 100u8, 105u8, 103u8, 105u8, 116u8, 115u8, 45u8,
+                    111u8, 114u8, 100u8, 105u8, 110u8, 97u8, 108u8, 45u8, 102u8, 101u8, 109u8,
+                    105u8, 110u8, 105u8, 110u8, 101u8, 45u8, 97u8, 98u8, 108u8, 97u8, 116u8, 105u8,
+                    118u8, 101u8, 100u8, 105u8, 103u8, 105u8, 116u8, 115u8, 45u8, 111u8, 114u8,
+                    100u8, 105u8, 110u8, 97u8, 108u8, 45u8, 102u8, 101u8, 109u8, 105u8, 110u8,
+                    105u8, 110u8, 101u8, 45u8, 97u8, 99u8, 99u8, 117u8, 115u8, 97u8, 116u8, 105u8,
+                    118u8, 101u8, 100u8, 105u8, 103u8, 105u8, 116u8, 115u8, 45u8, 111u8, 114u8,
+                    100u8, 105u8, 110u8, 97u8, 108u8, 45u8, 102u8, 101u8, 109u8, 105u8, 110u8,
+                    105u8, 110u8, 101u8, 45u8, 100u8, 97u8, 116u8, 105u8, 118u8, 101u8, 100u8,
+                    105u8, 103u8, 105u8, 116u8, 115u8, 45u8, 111u8, 114u8, 100u8, 105u8, 110u8,
+                    97u8, 108u8, 45u8, 102u8, 101u8, 109u8, 105u8, 110u8, 105u8, 110u8, 101u8,
+                    45u8, 103u8, 101u8, 110u8, 105u8, 116u8, 105u8, 118u8, 101u8, 100u8, 105u8,
+                    103u8, 105u8, 116u8, 115u8, 45u8, 111u8, 114u8, 100u8, 105u8, 110u8, 97u8,
+                    108u8, 45u8, 102u8, 101u8, 109u8, 105u8, 110u8, 105u8, 110u8, 101u8, 45u8,
+                    108u8, 111u8, 99u8, 97u8, 116u8, 105u8, 118u8, 101u8, 100u8, 105u8, 103u8,
+                    105u8, 116u8, 115u8, 45u8, 111u8, 114u8, 100u8, 105u8, 110u8, 97u8, 108u8,
+                    45u8, 109u8, 97u8, 115u8, 99u8, 117u8, 108u8, 105u8, 110u8, 101u8, 100u8,
+                    105u8, 103u8, 105u8, 116u8, 115u8, 45u8, 111u8, 114u8, 100u8, 105u8, 110u8,
+                    97u8, 108u8, 45u8, 109u8, 97u8, 115u8, 99u8, 117u8, 108u8, 105u8, 110u8, 101u8,
+                    45u8, 97u8, 98u8, 108u8, 97u8, 116u8, 105u8, 118u8, 101u8, 100u8, 105u8, 103u8,
+                    105u8, 116u8, 115u8, 45u8, 111u8, 114u8, 100u8, 105u8, 110u8, 97u8, 108u8,
+                    45u8, 109u8, 97u8, 115u8, 99u8, 117u8, 108u8, 105u8, 110u8, 101u8, 45u8, 97u8,
+                    99u8, 99u8, 117u8, 115u8, 97u8, 116u8, 105u8, 118u8, 101u8, 100u8, 105u8,
+                    103u8, 105u8, 116u8, 115u8, 45u8, 111u8, 114u8, 100u8, 105u8, 110u8, 97u8,
+                    108u8, 45u8, 109u8, 97u8, 115u8, 99u8, 117u8, 108u8, 105u8, 110u8, 101u8, 45u8,
+                    100u8, 97u8, 116u8, 105u8, 118u8, 101u8, 100u8, 105u8, 103u8, 105u8, 116u8,
+                    115u8, 45u8, 111u8, 114u8, 100u8, 105u8, 110u8, 97u8, 108u8, 45u8, 109u8, 97u8,
+                    115u8, 99u8, 117u8, 108u8, 105u8, 110u8, 101u8, 45u8, 103u8, 101u8, 110u8,
+                    105u8, 116u8, 105u8, 118u8, 101u8, 100u8, 105u8, 103u8, 105u8, 116u8, 115u8,
+                    45u8, 111u8, 114u8, 100u8, 105u8, 110u8, 97u8, 108u8, 45u8, 109u8, 97u8, 115u8,
+                    99u8, 117u8, 108u8, 105u8, 110u8, 101u8, 45u8, 108u8, 111u8, 99u8, 97u8, 116u8,
+                    105u8, 118u8, 101u8, 100u8, 105u8, 103u8, 105u8, 116u8, 115u8, 45u8, 111u8,
+                    114u8, 100u8, 105u8, 110u8, 97u8, 108u8, 45u8, 110u8, 101u8, 117u8, 116u8,
+                    101u8, 114u8, 100u8, 105u8, 103u8, 105u8, 116u8, 115u8, 45u8, 111u8, 114u8,
+                    100u8, 105u8, 110u8, 97u8, 108u8, 45u8, 110u8, 101u8, 117u8, 116u8, 101u8,
+                    114u8, 45u8, 97u8, 98u8, 108u8, 97u8, 116u8, 105u8, 118u8, 101u8, 100u8, 105u8,
+                    103u8, 105u8, 116u8, 115u8, 45u8, 111u8, 114u8, 100u8, 105u8, 110u8, 97u8,
+                    108u8, 45u8, 110u8, 101u8, 117u8, 116u8, 101u8, 114u8, 45u8, 97u8, 99u8, 99u8,
+                    117u8, 115u8, 97u8, 116u8, 105u8, 118u8, 101u8, 100u8, 105u8, 103u8, 105u8,
+                    116u8, 115u8, 45u8, 111u8, 114u8, 100u8, 105u8, 110u8, 97u8, 108u8, 45u8,
+                    110u8, 101u8, 117u8, 116u8, 101u8, 114u8, 45u8, 100u8, 97u8, 116u8, 105u8,
+                    118u8, 101u8, 100u8, 105u8, 103u8, 105u8, 116u8, 115u8, 45u8, 111u8, 114u8,
+                    100u8, 105u8, 110u8, 97u8, 108u8, 45u8, 110u8, 101u8, 117u8, 116u8, 101u8,
+                    114u8, 45u8, 103u8, 101u8, 110u8, 105u8, 116u8, 105u8, 118u8, 101u8, 100u8,
+                    105u8, 103u8, 105u8, 116u8, 115u8, 45u8, 111u8, 114u8, 100u8, 105u8, 110u8,
+                    97u8, 108u8, 45u8, 110u8, 101u8, 117u8, 116u8, 101u8, 114u8, 45u8, 108u8,
+                    111u8, 99u8, 97u8, 116u8, 105u8, 118u8, 101u8, 100u8, 105u8, 103u8, 105u8,
+                    116u8, 115u8, 45u8, 111u8, 114u8, 100u8, 105u8, 110u8, 97u8, 108u8, 45u8,
+                    112u8, 108u8, 117u8, 114u8, 97u8, 108u8, 100u8, 105u8, 103u8, 105u8, 116u8,
+                    115u8, 45u8, 111u8, 114u8, 100u8, 105u8, 110u8, 97u8, 108u8, 45u8, 112u8,
+                    108u8, 117u8, 114u8, 97u8, 108u8, 45u8, 97u8, 98u8, 108u8, 97u8, 116u8, 105u8,
+                    118u8, 101u8, 100u8, 105u8, 103u8, 105u8, 116u8, 115u8, 45u8, 111u8, 114u8,
+                    100u8, 105u8, 110u8, 97u8, 108u8, 45u8, 112u8, 108u8, 117u8, 114u8, 97u8,
+                    108u8, 45u8, 97u8, 99u8, 99u8, 117u8, 115u8, 97u8, 116u8, 105u8, 118u8, 101u8,
+                    100u8, 105u8, 103u8, 105u8, 116u8, 115u8, 45u8, 111u8, 114u8, 100u8, 105u8,
+                    110u8, 97u8, 108u8, 45u8, 112u8, 108u8, 117u8, 114u8, 97u8, 108u8, 45u8, 100u8,
+                    97u8, 116u8, 105u8, 118u8, 101u8, 100u8, 105u8, 103u8, 105u8, 116u8, 115u8,
+                    45u8, 111u8, 114u8, 100u8, 105u8, 110u8, 97u8, 108u8, 45u8, 112u8, 108u8,
+                    117u8, 114u8, 97u8, 108u8, 45u8, 103u8, 101u8, 110u8, 105u8, 116u8, 105u8,
+                    118u8, 101u8, 100u8, 105u8, 103u8, 105u8, 116u8, 115u8, 45u8, 111u8, 114u8,
+                    100u8, 105u8, 110u8, 97u8, 108u8, 45u8, 112u8, 108u8, 117u8, 114u8, 97u8,
+                    108u8, 45u8, 108u8, 111u8, 99u8, 97u8, 116u8, 105u8, 118u8, 101u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 2u8, 0u8, 4u8, 0u8, 0u8, 0u8, 4u8, 0u8, 6u8, 0u8,
+                    0u8, 0u8, 6u8, 0u8, 8u8, 0u8, 0u8, 0u8, 8u8, 0u8, 10u8, 0u8, 0u8, 0u8, 10u8,
+                    0u8, 12u8, 0u8, 0u8, 0u8, 12u8, 0u8, 14u8, 0u8, 0u8, 0u8, 14u8, 0u8, 16u8, 0u8,
+                    0u8, 0u8, 16u8, 0u8, 18u8, 0u8, 0u8, 0u8, 18u8, 0u8, 20u8, 0u8, 0u8, 0u8, 20u8,
+                    0u8, 22u8, 0u8, 0u8, 0u8, 22u8, 0u8, 24u8, 0u8, 0u8, 0u8, 24u8, 0u8, 26u8, 0u8,
+                    0u8, 0u8, 26u8, 0u8, 28u8, 0u8, 0u8, 0u8, 28u8, 0u8, 30u8, 0u8, 0u8, 0u8, 30u8,
+                    0u8, 32u8, 0u8, 0u8, 0u8, 32u8, 0u8, 34u8, 0u8, 0u8, 0u8, 34u8, 0u8, 36u8, 0u8,
+                    0u8, 0u8, 36u8, 0u8, 38u8, 0u8, 0u8, 0u8, 38u8, 0u8, 40u8, 0u8, 0u8, 0u8, 40u8,
+                    0u8, 42u8, 0u8, 0u8, 0u8, 42u8, 0u8, 44u8, 0u8, 0u8, 0u8, 44u8, 0u8, 46u8, 0u8,
+                    0u8, 0u8, 46u8, 0u8, 48u8, 0u8, 0u8, 0u8, 48u8, 0u8, 50u8, 0u8, 0u8, 0u8,
+                ])
+            },
+        )
+    },
+    rules: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            50u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 50u8, 0u8, 0u8, 0u8,
+            76u8, 0u8, 0u8, 0u8, 103u8, 0u8, 0u8, 0u8, 129u8, 0u8, 0u8, 0u8, 156u8, 0u8, 0u8, 0u8,
+            182u8, 0u8, 0u8, 0u8, 209u8, 0u8, 0u8, 0u8, 235u8, 0u8, 0u8, 0u8, 6u8, 1u8, 0u8, 0u8,
+            32u8, 1u8, 0u8, 0u8, 59u8, 1u8, 0u8, 0u8, 85u8, 1u8, 0u8, 0u8, 112u8, 1u8, 0u8, 0u8,
+            138u8, 1u8, 0u8, 0u8, 165u8, 1u8, 0u8, 0u8, 191u8, 1u8, 0u8, 0u8, 218u8, 1u8, 0u8, 0u8,
+            244u8, 1u8, 0u8, 0u8, 15u8, 2u8, 0u8, 0u8, 41u8, 2u8, 0u8, 0u8, 70u8, 2u8, 0u8, 0u8,
+            96u8, 2u8, 0u8, 0u8, 125u8, 2u8, 0u8, 0u8, 151u8, 2u8, 0u8, 0u8, 178u8, 2u8, 0u8, 0u8,
+            204u8, 2u8, 0u8, 0u8, 231u8, 2u8, 0u8, 0u8, 1u8, 3u8, 0u8, 0u8, 28u8, 3u8, 0u8, 0u8,
+            54u8, 3u8, 0u8, 0u8, 81u8, 3u8, 0u8, 0u8, 107u8, 3u8, 0u8, 0u8, 136u8, 3u8, 0u8, 0u8,
+            162u8, 3u8, 0u8, 0u8, 191u8, 3u8, 0u8, 0u8, 217u8, 3u8, 0u8, 0u8, 244u8, 3u8, 0u8, 0u8,
+            14u8, 4u8, 0u8, 0u8, 40u8, 4u8, 0u8, 0u8, 66u8, 4u8, 0u8, 0u8, 95u8, 4u8, 0u8, 0u8,
+            121u8, 4u8, 0u8, 0u8, 147u8, 4u8, 0u8, 0u8, 173u8, 4u8, 0u8, 0u8, 200u8, 4u8, 0u8, 0u8,
+            226u8, 4u8, 0u8, 0u8, 253u8, 4u8, 0u8, 0u8, 23u8, 5u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 136u8, 146u8,
+            226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 61u8, 1u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8,
+            136u8, 146u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8,
+            48u8, 61u8, 45u8, 209u8, 143u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 136u8, 146u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+            146u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 61u8, 45u8, 208u8, 185u8, 1u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 136u8, 146u8,
+            226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 61u8, 45u8,
+            209u8, 142u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 226u8, 136u8, 146u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8,
+            44u8, 35u8, 35u8, 48u8, 61u8, 45u8, 208u8, 185u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 136u8, 146u8, 226u8, 134u8,
+            146u8, 226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 61u8, 45u8, 208u8, 185u8,
+            1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            226u8, 136u8, 146u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8,
+            35u8, 48u8, 61u8, 45u8, 208u8, 185u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 136u8, 146u8, 226u8, 134u8, 146u8, 226u8,
+            134u8, 146u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 61u8, 45u8, 208u8, 185u8, 1u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8,
+            136u8, 146u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8,
+            48u8, 61u8, 45u8, 208u8, 188u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 136u8, 146u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+            146u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 61u8, 45u8, 208u8, 185u8, 1u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 136u8, 146u8,
+            226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 61u8, 45u8,
+            208u8, 188u8, 209u8, 131u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 136u8, 146u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 61u8, 45u8, 208u8, 179u8, 208u8, 190u8, 1u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8,
+            136u8, 146u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8,
+            48u8, 61u8, 45u8, 208u8, 188u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 136u8, 146u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+            146u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 61u8, 45u8, 208u8, 181u8, 1u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 136u8, 146u8,
+            226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 61u8, 45u8,
+            208u8, 188u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 226u8, 136u8, 146u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8,
+            44u8, 35u8, 35u8, 48u8, 61u8, 45u8, 208u8, 181u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 136u8, 146u8, 226u8, 134u8,
+            146u8, 226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 61u8, 45u8, 208u8, 188u8,
+            209u8, 131u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 226u8, 136u8, 146u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8,
+            44u8, 35u8, 35u8, 48u8, 61u8, 45u8, 208u8, 179u8, 208u8, 190u8, 1u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 136u8, 146u8,
+            226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 61u8, 45u8,
+            208u8, 188u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 226u8, 136u8, 146u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8,
+            44u8, 35u8, 35u8, 48u8, 61u8, 45u8, 101u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 136u8, 146u8, 226u8, 134u8, 146u8,
+            226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 61u8, 45u8, 208u8, 188u8,
+            208u8, 184u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 226u8, 136u8, 146u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8,
+            44u8, 35u8, 35u8, 48u8, 61u8, 45u8, 101u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 136u8, 146u8, 226u8, 134u8, 146u8,
+            226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 61u8, 45u8, 208u8, 188u8, 1u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8,
+            136u8, 146u8, 226u8, 134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8,
+            48u8, 61u8, 45u8, 209u8, 133u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 136u8, 146u8, 226u8, 134u8, 146u8, 226u8, 134u8,
+            146u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 61u8, 45u8, 209u8, 133u8,
+        ])
+    },
+};
+static TH: &DataStruct = &::icu_decimal::provider::RbnfRulesV1 {
+    rule_sets: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 105u8, 103u8, 105u8, 116u8, 115u8, 45u8,
+                    111u8, 114u8, 100u8, 105u8, 110u8, 97u8, 108u8,
+                ])
+            },
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8, 2u8, 0u8, 0u8, 0u8]) },
+        )
+    },
+    rules: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 42u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 224u8, 184u8, 151u8, 224u8,
+            184u8, 181u8, 224u8, 185u8, 136u8, 194u8, 160u8, 226u8, 136u8, 146u8, 226u8, 134u8,
+            146u8, 35u8, 44u8, 35u8, 35u8, 48u8, 226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 224u8, 184u8, 151u8, 224u8,
+            184u8, 181u8, 224u8, 185u8, 136u8, 194u8, 160u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8,
+            61u8,
+        ])
+    },
+};
+static UND: &DataStruct = &::icu_decimal::provider::RbnfRulesV1 {
+    rule_sets: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 100u8, 105u8, 103u8, 105u8, 116u8, 115u8, 45u8,
+                    111u8, 114u8, 100u8, 105u8, 110u8, 97u8, 108u8,
+                ])
+            },
+            unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[0u8, 0u8, 2u8, 0u8, 0u8, 0u8]) },
+        )
+    },
+    rules: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 226u8, 136u8, 146u8, 226u8,
+            134u8, 146u8, 226u8, 134u8, 146u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 35u8, 44u8, 35u8, 35u8, 48u8, 61u8, 46u8,
+        ])
+    },
+};
@@ -0,0 +1,2008 @@
+// @generated
+#![cfg(feature = "icu_relativetime")]
+type DataStruct = < :: icu_relativetime :: provider :: DayRelativeTimeFormatDataV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[
+        ("ar", AR_AR_EG),
+        ("ar-EG", AR_AR_EG),
+        ("bn", BN),
+        ("ccp", CCP_UND),
+        ("en", EN),
+        ("en-001", EN_001_EN_ZA),
+        ("en-ZA", EN_001_EN_ZA),
+        ("es", ES),
+        ("es-AR", ES_AR),
+        ("fil", FIL),
+        ("fr", FR),
+        ("ja", JA),
+        ("ru", RU),
+        ("sr", SR_SR_CYRL),
+        ("sr-Cyrl", SR_SR_CYRL),
+        ("sr-Latn", SR_LATN),
+        ("th", TH),
+        ("tr", TR),
+        ("und", CCP_UND),
+    ]);
+static AR_AR_EG: &DataStruct = &::icu_relativetime::provider::RelativeTimeFormatDataV1 {
+    long: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[254u8, 255u8, 0u8, 1u8, 2u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 19u8, 0u8, 29u8, 0u8, 37u8, 0u8,
+                        216u8, 163u8, 217u8, 136u8, 217u8, 132u8, 32u8, 216u8, 163u8, 217u8, 133u8,
+                        216u8, 179u8, 216u8, 163u8, 217u8, 133u8, 216u8, 179u8, 216u8, 167u8,
+                        217u8, 132u8, 217u8, 138u8, 217u8, 136u8, 217u8, 133u8, 216u8, 186u8,
+                        216u8, 175u8, 217u8, 139u8, 216u8, 167u8, 216u8, 168u8, 216u8, 185u8,
+                        216u8, 175u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 186u8, 216u8, 175u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("قبل يوم واحد"),
+                index: 255u8,
+            }),
+            two: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("قبل يومين"),
+                index: 255u8,
+            }),
+            few: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("قبل  أيام"),
+                index: 7u8,
+            }),
+            many: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("قبل  يوم\u{64b}ا"),
+                index: 7u8,
+            }),
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("قبل  يوم"),
+                index: 7u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("خلال يوم واحد"),
+                index: 255u8,
+            }),
+            two: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("خلال يومين"),
+                index: 255u8,
+            }),
+            few: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("خلال  أيام"),
+                index: 9u8,
+            }),
+            many: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("خلال  يوم\u{64b}ا"),
+                index: 9u8,
+            }),
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("خلال  يوم"),
+                index: 9u8,
+            },
+        },
+    },
+    short: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[254u8, 255u8, 0u8, 1u8, 2u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 19u8, 0u8, 29u8, 0u8, 37u8, 0u8,
+                        216u8, 163u8, 217u8, 136u8, 217u8, 132u8, 32u8, 216u8, 163u8, 217u8, 133u8,
+                        216u8, 179u8, 216u8, 163u8, 217u8, 133u8, 216u8, 179u8, 216u8, 167u8,
+                        217u8, 132u8, 217u8, 138u8, 217u8, 136u8, 217u8, 133u8, 216u8, 186u8,
+                        216u8, 175u8, 217u8, 139u8, 216u8, 167u8, 216u8, 168u8, 216u8, 185u8,
+                        216u8, 175u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 186u8, 216u8, 175u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("قبل يوم واحد"),
+                index: 255u8,
+            }),
+            two: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("قبل يومين"),
+                index: 255u8,
+            }),
+            few: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("قبل  أيام"),
+                index: 7u8,
+            }),
+            many: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("قبل  يوم\u{64b}ا"),
+                index: 7u8,
+            }),
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("قبل  يوم"),
+                index: 7u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("خلال يوم واحد"),
+                index: 255u8,
+            }),
+            two: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("خلال يومين"),
+                index: 255u8,
+            }),
+            few: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("خلال  أيام"),
+                index: 9u8,
+            }),
+            many: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("خلال  يوم\u{64b}ا"),
+                index: 9u8,
+            }),
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("خلال  يوم"),
+                index: 9u8,
+            },
+        },
+    },
+    narrow: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[254u8, 255u8, 0u8, 1u8, 2u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 19u8, 0u8, 29u8, 0u8, 37u8, 0u8,
+                        216u8, 163u8, 217u8, 136u8, 217u8, 132u8, 32u8, 216u8, 163u8, 217u8, 133u8,
+                        216u8, 179u8, 216u8, 163u8, 217u8, 133u8, 216u8, 179u8, 216u8, 167u8,
+                        217u8, 132u8, 217u8, 138u8, 217u8, 136u8, 217u8, 133u8, 216u8, 186u8,
+                        216u8, 175u8, 217u8, 139u8, 216u8, 167u8, 216u8, 168u8, 216u8, 185u8,
+                        216u8, 175u8, 32u8, 216u8, 167u8, 217u8, 132u8, 216u8, 186u8, 216u8, 175u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("قبل يوم واحد"),
+                index: 255u8,
+            }),
+            two: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("قبل يومين"),
+                index: 255u8,
+            }),
+            few: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("قبل  أيام"),
+                index: 7u8,
+            }),
+            many: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("قبل  يوم\u{64b}ا"),
+                index: 7u8,
+            }),
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("قبل  يوم"),
+                index: 7u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("خلال يوم واحد"),
+                index: 255u8,
+            }),
+            two: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("خلال يومين"),
+                index: 255u8,
+            }),
+            few: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("خلال  أيام"),
+                index: 9u8,
+            }),
+            many: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("خلال  يوم\u{64b}ا"),
+                index: 9u8,
+            }),
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("خلال  يوم"),
+                index: 9u8,
+            },
+        },
+    },
+};
+static BN: &DataStruct = &::icu_relativetime::provider::RelativeTimeFormatDataV1 {
+    long: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[254u8, 255u8, 0u8, 1u8, 2u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 19u8, 0u8, 34u8, 0u8, 40u8, 0u8, 64u8, 0u8,
+                        224u8, 166u8, 151u8, 224u8, 166u8, 164u8, 32u8, 224u8, 166u8, 170u8, 224u8,
+                        166u8, 176u8, 224u8, 166u8, 182u8, 224u8, 167u8, 129u8, 224u8, 166u8,
+                        151u8, 224u8, 166u8, 164u8, 224u8, 166u8, 149u8, 224u8, 166u8, 190u8,
+                        224u8, 166u8, 178u8, 224u8, 166u8, 134u8, 224u8, 166u8, 156u8, 224u8,
+                        166u8, 134u8, 224u8, 166u8, 151u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                        174u8, 224u8, 167u8, 128u8, 224u8, 166u8, 149u8, 224u8, 166u8, 190u8,
+                        224u8, 166u8, 178u8, 224u8, 166u8, 134u8, 224u8, 166u8, 151u8, 224u8,
+                        166u8, 190u8, 224u8, 166u8, 174u8, 224u8, 167u8, 128u8, 32u8, 224u8, 166u8,
+                        170u8, 224u8, 166u8, 176u8, 224u8, 166u8, 182u8, 224u8, 167u8, 129u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" দিন আগে"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" দিনের মধ\u{9cd}যে"),
+                index: 0u8,
+            },
+        },
+    },
+    short: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[254u8, 255u8, 0u8, 1u8, 2u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 19u8, 0u8, 34u8, 0u8, 40u8, 0u8, 64u8, 0u8,
+                        224u8, 166u8, 151u8, 224u8, 166u8, 164u8, 32u8, 224u8, 166u8, 170u8, 224u8,
+                        166u8, 176u8, 224u8, 166u8, 182u8, 224u8, 167u8, 129u8, 224u8, 166u8,
+                        151u8, 224u8, 166u8, 164u8, 224u8, 166u8, 149u8, 224u8, 166u8, 190u8,
+                        224u8, 166u8, 178u8, 224u8, 166u8, 134u8, 224u8, 166u8, 156u8, 224u8,
+                        166u8, 134u8, 224u8, 166u8, 151u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                        174u8, 224u8, 167u8, 128u8, 224u8, 166u8, 149u8, 224u8, 166u8, 190u8,
+                        224u8, 166u8, 178u8, 224u8, 166u8, 134u8, 224u8, 166u8, 151u8, 224u8,
+                        166u8, 190u8, 224u8, 166u8, 174u8, 224u8, 167u8, 128u8, 32u8, 224u8, 166u8,
+                        170u8, 224u8, 166u8, 176u8, 224u8, 166u8, 182u8, 224u8, 167u8, 129u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" দিন আগে"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" দিনের মধ\u{9cd}যে"),
+                index: 0u8,
+            },
+        },
+    },
+    narrow: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[254u8, 255u8, 0u8, 1u8, 2u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 19u8, 0u8, 34u8, 0u8, 40u8, 0u8, 64u8, 0u8,
+                        224u8, 166u8, 151u8, 224u8, 166u8, 164u8, 32u8, 224u8, 166u8, 170u8, 224u8,
+                        166u8, 176u8, 224u8, 166u8, 182u8, 224u8, 167u8, 129u8, 224u8, 166u8,
+                        151u8, 224u8, 166u8, 164u8, 224u8, 166u8, 149u8, 224u8, 166u8, 190u8,
+                        224u8, 166u8, 178u8, 224u8, 166u8, 134u8, 224u8, 166u8, 156u8, 224u8,
+                        166u8, 134u8, 224u8, 166u8, 151u8, 224u8, 166u8, 190u8, 224u8, 166u8,
+                        174u8, 224u8, 167u8, 128u8, 224u8, 166u8, 149u8, 224u8, 166u8, 190u8,
+                        224u8, 166u8, 178u8, 224u8, 166u8, 134u8, 224u8, 166u8, 151u8, 224u8,
+                        166u8, 190u8, 224u8, 166u8, 174u8, 224u8, 167u8, 128u8, 32u8, 224u8, 166u8,
+                        170u8, 224u8, 166u8, 176u8, 224u8, 166u8, 182u8, 224u8, 167u8, 129u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" দিন আগে"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" দিনের মধ\u{9cd}যে"),
+                index: 0u8,
+            },
+        },
+    },
+};
+static CCP_UND: &DataStruct = &::icu_relativetime::provider::RelativeTimeFormatDataV1 {
+    long: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[255u8, 0u8, 1u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 9u8, 0u8, 14u8, 0u8, 121u8, 101u8, 115u8,
+                        116u8, 101u8, 114u8, 100u8, 97u8, 121u8, 116u8, 111u8, 100u8, 97u8, 121u8,
+                        116u8, 111u8, 109u8, 111u8, 114u8, 114u8, 111u8, 119u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("- d"),
+                index: 1u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("+ d"),
+                index: 1u8,
+            },
+        },
+    },
+    short: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[255u8, 0u8, 1u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 9u8, 0u8, 14u8, 0u8, 121u8, 101u8, 115u8,
+                        116u8, 101u8, 114u8, 100u8, 97u8, 121u8, 116u8, 111u8, 100u8, 97u8, 121u8,
+                        116u8, 111u8, 109u8, 111u8, 114u8, 114u8, 111u8, 119u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("- d"),
+                index: 1u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("+ d"),
+                index: 1u8,
+            },
+        },
+    },
+    narrow: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[255u8, 0u8, 1u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 9u8, 0u8, 14u8, 0u8, 121u8, 101u8, 115u8,
+                        116u8, 101u8, 114u8, 100u8, 97u8, 121u8, 116u8, 111u8, 100u8, 97u8, 121u8,
+                        116u8, 111u8, 109u8, 111u8, 114u8, 114u8, 111u8, 119u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("- d"),
+                index: 1u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("+ d"),
+                index: 1u8,
+            },
+        },
+    },
+};
+static EN: &DataStruct = &::icu_relativetime::provider::RelativeTimeFormatDataV1 {
+    long: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[255u8, 0u8, 1u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 9u8, 0u8, 14u8, 0u8, 121u8, 101u8, 115u8,
+                        116u8, 101u8, 114u8, 100u8, 97u8, 121u8, 116u8, 111u8, 100u8, 97u8, 121u8,
+                        116u8, 111u8, 109u8, 111u8, 114u8, 114u8, 111u8, 119u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" day ago"),
+                index: 0u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" days ago"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("in  day"),
+                index: 3u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("in  days"),
+                index: 3u8,
+            },
+        },
+    },
+    short: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[255u8, 0u8, 1u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 9u8, 0u8, 14u8, 0u8, 121u8, 101u8, 115u8,
+                        116u8, 101u8, 114u8, 100u8, 97u8, 121u8, 116u8, 111u8, 100u8, 97u8, 121u8,
+                        116u8, 111u8, 109u8, 111u8, 114u8, 114u8, 111u8, 119u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" day ago"),
+                index: 0u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" days ago"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("in  day"),
+                index: 3u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("in  days"),
+                index: 3u8,
+            },
+        },
+    },
+    narrow: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[255u8, 0u8, 1u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 9u8, 0u8, 14u8, 0u8, 121u8, 101u8, 115u8,
+                        116u8, 101u8, 114u8, 100u8, 97u8, 121u8, 116u8, 111u8, 100u8, 97u8, 121u8,
+                        116u8, 111u8, 109u8, 111u8, 114u8, 114u8, 111u8, 119u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("d ago"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("in d"),
+                index: 3u8,
+            },
+        },
+    },
+};
+static EN_001_EN_ZA: &DataStruct = &::icu_relativetime::provider::RelativeTimeFormatDataV1 {
+    long: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[255u8, 0u8, 1u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 9u8, 0u8, 14u8, 0u8, 121u8, 101u8, 115u8,
+                        116u8, 101u8, 114u8, 100u8, 97u8, 121u8, 116u8, 111u8, 100u8, 97u8, 121u8,
+                        116u8, 111u8, 109u8, 111u8, 114u8, 114u8, 111u8, 119u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" day ago"),
+                index: 0u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" days ago"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("in  day"),
+                index: 3u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("in  days"),
+                index: 3u8,
+            },
+        },
+    },
+    short: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[255u8, 0u8, 1u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 9u8, 0u8, 14u8, 0u8, 121u8, 101u8, 115u8,
+                        116u8, 101u8, 114u8, 100u8, 97u8, 121u8, 116u8, 111u8, 100u8, 97u8, 121u8,
+                        116u8, 111u8, 109u8, 111u8, 114u8, 114u8, 111u8, 119u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" day ago"),
+                index: 0u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" days ago"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("in  day"),
+                index: 3u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("in  days"),
+                index: 3u8,
+            },
+        },
+    },
+    narrow: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[255u8, 0u8, 1u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 9u8, 0u8, 14u8, 0u8, 121u8, 101u8, 115u8,
+                        116u8, 101u8, 114u8, 100u8, 97u8, 121u8, 116u8, 111u8, 100u8, 97u8, 121u8,
+                        116u8, 111u8, 109u8, 111u8, 114u8, 114u8, 111u8, 119u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" day ago"),
+                index: 0u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" days ago"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("in  day"),
+                index: 3u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("in  days"),
+                index: 3u8,
+            },
+        },
+    },
+};
+static ES: &DataStruct = &::icu_relativetime::provider::RelativeTimeFormatDataV1 {
+    long: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[254u8, 255u8, 0u8, 1u8, 2u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 12u8, 0u8, 15u8, 0u8, 22u8, 0u8,
+                        97u8, 110u8, 116u8, 101u8, 97u8, 121u8, 101u8, 114u8, 97u8, 121u8, 101u8,
+                        114u8, 104u8, 111u8, 121u8, 109u8, 97u8, 195u8, 177u8, 97u8, 110u8, 97u8,
+                        112u8, 97u8, 115u8, 97u8, 100u8, 111u8, 32u8, 109u8, 97u8, 195u8, 177u8,
+                        97u8, 110u8, 97u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("hace  día"),
+                index: 5u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("hace  días"),
+                index: 5u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("dentro de  día"),
+                index: 10u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("dentro de  días"),
+                index: 10u8,
+            },
+        },
+    },
+    short: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[254u8, 255u8, 0u8, 1u8, 2u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 12u8, 0u8, 15u8, 0u8, 22u8, 0u8,
+                        97u8, 110u8, 116u8, 101u8, 97u8, 121u8, 101u8, 114u8, 97u8, 121u8, 101u8,
+                        114u8, 104u8, 111u8, 121u8, 109u8, 97u8, 195u8, 177u8, 97u8, 110u8, 97u8,
+                        112u8, 97u8, 115u8, 97u8, 100u8, 111u8, 32u8, 109u8, 97u8, 195u8, 177u8,
+                        97u8, 110u8, 97u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("hace  d"),
+                index: 5u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("dentro de  d"),
+                index: 10u8,
+            },
+        },
+    },
+    narrow: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[254u8, 255u8, 0u8, 1u8, 2u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 12u8, 0u8, 15u8, 0u8, 22u8, 0u8,
+                        97u8, 110u8, 116u8, 101u8, 97u8, 121u8, 101u8, 114u8, 97u8, 121u8, 101u8,
+                        114u8, 104u8, 111u8, 121u8, 109u8, 97u8, 195u8, 177u8, 97u8, 110u8, 97u8,
+                        112u8, 97u8, 115u8, 97u8, 100u8, 111u8, 32u8, 109u8, 97u8, 195u8, 177u8,
+                        97u8, 110u8, 97u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("hace  d"),
+                index: 5u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("dentro de  d"),
+                index: 10u8,
+            },
+        },
+    },
+};
+static ES_AR: &DataStruct = &::icu_relativetime::provider::RelativeTimeFormatDataV1 {
+    long: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[254u8, 255u8, 0u8, 1u8, 2u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 12u8, 0u8, 15u8, 0u8, 22u8, 0u8,
+                        97u8, 110u8, 116u8, 101u8, 97u8, 121u8, 101u8, 114u8, 97u8, 121u8, 101u8,
+                        114u8, 104u8, 111u8, 121u8, 109u8, 97u8, 195u8, 177u8, 97u8, 110u8, 97u8,
+                        112u8, 97u8, 115u8, 97u8, 100u8, 111u8, 32u8, 109u8, 97u8, 195u8, 177u8,
+                        97u8, 110u8, 97u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("hace  día"),
+                index: 5u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("hace  días"),
+                index: 5u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("dentro de  día"),
+                index: 10u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("dentro de  días"),
+                index: 10u8,
+            },
+        },
+    },
+    short: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[254u8, 255u8, 0u8, 1u8, 2u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 12u8, 0u8, 15u8, 0u8, 22u8, 0u8,
+                        97u8, 110u8, 116u8, 101u8, 97u8, 121u8, 101u8, 114u8, 97u8, 121u8, 101u8,
+                        114u8, 104u8, 111u8, 121u8, 109u8, 97u8, 195u8, 177u8, 97u8, 110u8, 97u8,
+                        112u8, 97u8, 115u8, 97u8, 100u8, 111u8, 32u8, 109u8, 97u8, 195u8, 177u8,
+                        97u8, 110u8, 97u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("hace  días"),
+                index: 5u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("dentro de  días"),
+                index: 10u8,
+            },
+        },
+    },
+    narrow: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[254u8, 255u8, 0u8, 1u8, 2u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 12u8, 0u8, 15u8, 0u8, 22u8, 0u8,
+                        97u8, 110u8, 116u8, 101u8, 97u8, 121u8, 101u8, 114u8, 97u8, 121u8, 101u8,
+                        114u8, 104u8, 111u8, 121u8, 109u8, 97u8, 195u8, 177u8, 97u8, 110u8, 97u8,
+                        112u8, 97u8, 115u8, 97u8, 100u8, 111u8, 32u8, 109u8, 97u8, 195u8, 177u8,
+                        97u8, 110u8, 97u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("hace  días"),
+                index: 5u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("dentro de  días"),
+                index: 10u8,
+            },
+        },
+    },
+};
+static FIL: &DataStruct = &::icu_relativetime::provider::RelativeTimeFormatDataV1 {
+    long: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[254u8, 255u8, 0u8, 1u8, 2u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 21u8, 0u8, 28u8, 0u8, 40u8, 0u8, 45u8, 0u8,
+                        65u8, 114u8, 97u8, 119u8, 32u8, 98u8, 97u8, 103u8, 111u8, 32u8, 97u8,
+                        110u8, 103u8, 32u8, 107u8, 97u8, 104u8, 97u8, 112u8, 111u8, 110u8, 107u8,
+                        97u8, 104u8, 97u8, 112u8, 111u8, 110u8, 110u8, 103u8, 97u8, 121u8, 111u8,
+                        110u8, 103u8, 32u8, 97u8, 114u8, 97u8, 119u8, 98u8, 117u8, 107u8, 97u8,
+                        115u8, 83u8, 97u8, 109u8, 97u8, 107u8, 97u8, 108u8, 97u8, 119u8, 97u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" araw ang nakalipas"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("sa  araw"),
+                index: 3u8,
+            },
+        },
+    },
+    short: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[254u8, 255u8, 0u8, 1u8, 2u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 21u8, 0u8, 28u8, 0u8, 40u8, 0u8, 45u8, 0u8,
+                        65u8, 114u8, 97u8, 119u8, 32u8, 98u8, 97u8, 103u8, 111u8, 32u8, 97u8,
+                        110u8, 103u8, 32u8, 107u8, 97u8, 104u8, 97u8, 112u8, 111u8, 110u8, 107u8,
+                        97u8, 104u8, 97u8, 112u8, 111u8, 110u8, 110u8, 103u8, 97u8, 121u8, 111u8,
+                        110u8, 103u8, 32u8, 97u8, 114u8, 97u8, 119u8, 98u8, 117u8, 107u8, 97u8,
+                        115u8, 83u8, 97u8, 109u8, 97u8, 107u8, 97u8, 108u8, 97u8, 119u8, 97u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" araw ang nakalipas"),
+                index: 0u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" (na) araw ang nakalipas"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("sa  (na) araw"),
+                index: 3u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("sa  araw"),
+                index: 3u8,
+            },
+        },
+    },
+    narrow: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[254u8, 255u8, 0u8, 1u8, 2u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 21u8, 0u8, 28u8, 0u8, 40u8, 0u8, 45u8, 0u8,
+                        65u8, 114u8, 97u8, 119u8, 32u8, 98u8, 97u8, 103u8, 111u8, 32u8, 97u8,
+                        110u8, 103u8, 32u8, 107u8, 97u8, 104u8, 97u8, 112u8, 111u8, 110u8, 107u8,
+                        97u8, 104u8, 97u8, 112u8, 111u8, 110u8, 110u8, 103u8, 97u8, 121u8, 111u8,
+                        110u8, 103u8, 32u8, 97u8, 114u8, 97u8, 119u8, 98u8, 117u8, 107u8, 97u8,
+                        115u8, 83u8, 97u8, 109u8, 97u8, 107u8, 97u8, 108u8, 97u8, 119u8, 97u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" araw ang nakalipas"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("sa  araw"),
+                index: 3u8,
+            },
+        },
+    },
+};
+static FR: &DataStruct = &::icu_relativetime::provider::RelativeTimeFormatDataV1 {
+    long: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[254u8, 255u8, 0u8, 1u8, 2u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 14u8, 0u8, 27u8, 0u8, 33u8, 0u8,
+                        97u8, 118u8, 97u8, 110u8, 116u8, 45u8, 104u8, 105u8, 101u8, 114u8, 104u8,
+                        105u8, 101u8, 114u8, 97u8, 117u8, 106u8, 111u8, 117u8, 114u8, 100u8, 226u8,
+                        128u8, 153u8, 104u8, 117u8, 105u8, 100u8, 101u8, 109u8, 97u8, 105u8, 110u8,
+                        97u8, 112u8, 114u8, 195u8, 168u8, 115u8, 45u8, 100u8, 101u8, 109u8, 97u8,
+                        105u8, 110u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("il y a  jour"),
+                index: 7u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("il y a  jours"),
+                index: 7u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("dans  jour"),
+                index: 5u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("dans  jours"),
+                index: 5u8,
+            },
+        },
+    },
+    short: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[254u8, 255u8, 0u8, 1u8, 2u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 14u8, 0u8, 27u8, 0u8, 33u8, 0u8,
+                        97u8, 118u8, 97u8, 110u8, 116u8, 45u8, 104u8, 105u8, 101u8, 114u8, 104u8,
+                        105u8, 101u8, 114u8, 97u8, 117u8, 106u8, 111u8, 117u8, 114u8, 100u8, 226u8,
+                        128u8, 153u8, 104u8, 117u8, 105u8, 100u8, 101u8, 109u8, 97u8, 105u8, 110u8,
+                        97u8, 112u8, 114u8, 195u8, 168u8, 115u8, 45u8, 100u8, 101u8, 109u8, 97u8,
+                        105u8, 110u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("il y a \u{a0}j"),
+                index: 7u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("dans \u{a0}j"),
+                index: 5u8,
+            },
+        },
+    },
+    narrow: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[254u8, 255u8, 0u8, 1u8, 2u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 14u8, 0u8, 27u8, 0u8, 33u8, 0u8,
+                        97u8, 118u8, 97u8, 110u8, 116u8, 45u8, 104u8, 105u8, 101u8, 114u8, 104u8,
+                        105u8, 101u8, 114u8, 97u8, 117u8, 106u8, 111u8, 117u8, 114u8, 100u8, 226u8,
+                        128u8, 153u8, 104u8, 117u8, 105u8, 100u8, 101u8, 109u8, 97u8, 105u8, 110u8,
+                        97u8, 112u8, 114u8, 195u8, 168u8, 115u8, 45u8, 100u8, 101u8, 109u8, 97u8,
+                        105u8, 110u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("- j"),
+                index: 1u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("+ j"),
+                index: 1u8,
+            },
+        },
+    },
+};
+static JA: &DataStruct = &::icu_relativetime::provider::RelativeTimeFormatDataV1 {
+    long: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[254u8, 255u8, 0u8, 1u8, 2u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 9u8, 0u8, 15u8, 0u8, 21u8, 0u8, 27u8, 0u8,
+                        228u8, 184u8, 128u8, 230u8, 152u8, 168u8, 230u8, 151u8, 165u8, 230u8,
+                        152u8, 168u8, 230u8, 151u8, 165u8, 228u8, 187u8, 138u8, 230u8, 151u8,
+                        165u8, 230u8, 152u8, 142u8, 230u8, 151u8, 165u8, 230u8, 152u8, 142u8,
+                        229u8, 190u8, 140u8, 230u8, 151u8, 165u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" 日前"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" 日後"),
+                index: 0u8,
+            },
+        },
+    },
+    short: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[254u8, 255u8, 0u8, 1u8, 2u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 9u8, 0u8, 15u8, 0u8, 21u8, 0u8, 27u8, 0u8,
+                        228u8, 184u8, 128u8, 230u8, 152u8, 168u8, 230u8, 151u8, 165u8, 230u8,
+                        152u8, 168u8, 230u8, 151u8, 165u8, 228u8, 187u8, 138u8, 230u8, 151u8,
+                        165u8, 230u8, 152u8, 142u8, 230u8, 151u8, 165u8, 230u8, 152u8, 142u8,
+                        229u8, 190u8, 140u8, 230u8, 151u8, 165u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" 日前"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" 日後"),
+                index: 0u8,
+            },
+        },
+    },
+    narrow: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[254u8, 255u8, 0u8, 1u8, 2u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 9u8, 0u8, 15u8, 0u8, 21u8, 0u8, 27u8, 0u8,
+                        228u8, 184u8, 128u8, 230u8, 152u8, 168u8, 230u8, 151u8, 165u8, 230u8,
+                        152u8, 168u8, 230u8, 151u8, 165u8, 228u8, 187u8, 138u8, 230u8, 151u8,
+                        165u8, 230u8, 152u8, 142u8, 230u8, 151u8, 165u8, 230u8, 152u8, 142u8,
+                        229u8, 190u8, 140u8, 230u8, 151u8, 165u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("日前"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("日後"),
+                index: 0u8,
+            },
+        },
+    },
+};
+static RU: &DataStruct = &::icu_relativetime::provider::RelativeTimeFormatDataV1 {
+    long: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[254u8, 255u8, 0u8, 1u8, 2u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 18u8, 0u8, 28u8, 0u8, 42u8, 0u8, 54u8, 0u8,
+                        208u8, 191u8, 208u8, 190u8, 208u8, 183u8, 208u8, 176u8, 208u8, 178u8,
+                        209u8, 135u8, 208u8, 181u8, 209u8, 128u8, 208u8, 176u8, 208u8, 178u8,
+                        209u8, 135u8, 208u8, 181u8, 209u8, 128u8, 208u8, 176u8, 209u8, 129u8,
+                        208u8, 181u8, 208u8, 179u8, 208u8, 190u8, 208u8, 180u8, 208u8, 189u8,
+                        209u8, 143u8, 208u8, 183u8, 208u8, 176u8, 208u8, 178u8, 209u8, 130u8,
+                        209u8, 128u8, 208u8, 176u8, 208u8, 191u8, 208u8, 190u8, 209u8, 129u8,
+                        208u8, 187u8, 208u8, 181u8, 208u8, 183u8, 208u8, 176u8, 208u8, 178u8,
+                        209u8, 130u8, 209u8, 128u8, 208u8, 176u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" день назад"),
+                index: 0u8,
+            }),
+            two: None,
+            few: None,
+            many: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" дней назад"),
+                index: 0u8,
+            }),
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" дня назад"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("через  день"),
+                index: 11u8,
+            }),
+            two: None,
+            few: None,
+            many: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("через  дней"),
+                index: 11u8,
+            }),
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("через  дня"),
+                index: 11u8,
+            },
+        },
+    },
+    short: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[254u8, 255u8, 0u8, 1u8, 2u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 18u8, 0u8, 28u8, 0u8, 42u8, 0u8, 54u8, 0u8,
+                        208u8, 191u8, 208u8, 190u8, 208u8, 183u8, 208u8, 176u8, 208u8, 178u8,
+                        209u8, 135u8, 208u8, 181u8, 209u8, 128u8, 208u8, 176u8, 208u8, 178u8,
+                        209u8, 135u8, 208u8, 181u8, 209u8, 128u8, 208u8, 176u8, 209u8, 129u8,
+                        208u8, 181u8, 208u8, 179u8, 208u8, 190u8, 208u8, 180u8, 208u8, 189u8,
+                        209u8, 143u8, 208u8, 183u8, 208u8, 176u8, 208u8, 178u8, 209u8, 130u8,
+                        209u8, 128u8, 208u8, 176u8, 208u8, 191u8, 208u8, 190u8, 209u8, 129u8,
+                        208u8, 187u8, 208u8, 181u8, 208u8, 183u8, 208u8, 176u8, 208u8, 178u8,
+                        209u8, 130u8, 209u8, 128u8, 208u8, 176u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" дн. назад"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("через  дн."),
+                index: 11u8,
+            },
+        },
+    },
+    narrow: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[254u8, 255u8, 0u8, 1u8, 2u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 18u8, 0u8, 28u8, 0u8, 42u8, 0u8, 54u8, 0u8,
+                        208u8, 191u8, 208u8, 190u8, 208u8, 183u8, 208u8, 176u8, 208u8, 178u8,
+                        209u8, 135u8, 208u8, 181u8, 209u8, 128u8, 208u8, 176u8, 208u8, 178u8,
+                        209u8, 135u8, 208u8, 181u8, 209u8, 128u8, 208u8, 176u8, 209u8, 129u8,
+                        208u8, 181u8, 208u8, 179u8, 208u8, 190u8, 208u8, 180u8, 208u8, 189u8,
+                        209u8, 143u8, 208u8, 183u8, 208u8, 176u8, 208u8, 178u8, 209u8, 130u8,
+                        209u8, 128u8, 208u8, 176u8, 208u8, 191u8, 208u8, 190u8, 209u8, 129u8,
+                        208u8, 187u8, 208u8, 181u8, 208u8, 183u8, 208u8, 176u8, 208u8, 178u8,
+                        209u8, 130u8, 209u8, 128u8, 208u8, 176u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("- дн."),
+                index: 1u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("+ дн."),
+                index: 1u8,
+            },
+        },
+    },
+};
+static SR_LATN: &DataStruct = &::icu_relativetime::provider::RelativeTimeFormatDataV1 {
+    long: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[254u8, 255u8, 0u8, 1u8, 2u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 9u8, 0u8, 14u8, 0u8, 19u8, 0u8, 24u8, 0u8,
+                        112u8, 114u8, 101u8, 107u8, 106u8, 117u8, 196u8, 141u8, 101u8, 106u8,
+                        117u8, 196u8, 141u8, 101u8, 100u8, 97u8, 110u8, 97u8, 115u8, 115u8, 117u8,
+                        116u8, 114u8, 97u8, 112u8, 114u8, 101u8, 107u8, 111u8, 115u8, 117u8, 116u8,
+                        114u8, 97u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("pre  dana"),
+                index: 4u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("za  dan"),
+                index: 3u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("za  dana"),
+                index: 3u8,
+            },
+        },
+    },
+    short: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[254u8, 255u8, 0u8, 1u8, 2u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 9u8, 0u8, 14u8, 0u8, 19u8, 0u8, 24u8, 0u8,
+                        112u8, 114u8, 101u8, 107u8, 106u8, 117u8, 196u8, 141u8, 101u8, 106u8,
+                        117u8, 196u8, 141u8, 101u8, 100u8, 97u8, 110u8, 97u8, 115u8, 115u8, 117u8,
+                        116u8, 114u8, 97u8, 112u8, 114u8, 101u8, 107u8, 111u8, 115u8, 117u8, 116u8,
+                        114u8, 97u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("pre  d."),
+                index: 4u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("za  d."),
+                index: 3u8,
+            },
+        },
+    },
+    narrow: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[254u8, 255u8, 0u8, 1u8, 2u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 9u8, 0u8, 14u8, 0u8, 19u8, 0u8, 24u8, 0u8,
+                        112u8, 114u8, 101u8, 107u8, 106u8, 117u8, 196u8, 141u8, 101u8, 106u8,
+                        117u8, 196u8, 141u8, 101u8, 100u8, 97u8, 110u8, 97u8, 115u8, 115u8, 117u8,
+                        116u8, 114u8, 97u8, 112u8, 114u8, 101u8, 107u8, 111u8, 115u8, 117u8, 116u8,
+                        114u8, 97u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("pre  d."),
+                index: 4u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("za  d."),
+                index: 3u8,
+            },
+        },
+    },
+};
+static SR_SR_CYRL: &DataStruct = &::icu_relativetime::provider::RelativeTimeFormatDataV1 {
+    long: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[254u8, 255u8, 0u8, 1u8, 2u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 0u8, 24u8, 0u8, 34u8, 0u8, 44u8, 0u8,
+                        208u8, 191u8, 209u8, 128u8, 208u8, 181u8, 208u8, 186u8, 209u8, 152u8,
+                        209u8, 131u8, 209u8, 135u8, 208u8, 181u8, 209u8, 152u8, 209u8, 131u8,
+                        209u8, 135u8, 208u8, 181u8, 208u8, 180u8, 208u8, 176u8, 208u8, 189u8,
+                        208u8, 176u8, 209u8, 129u8, 209u8, 129u8, 209u8, 131u8, 209u8, 130u8,
+                        209u8, 128u8, 208u8, 176u8, 208u8, 191u8, 209u8, 128u8, 208u8, 181u8,
+                        208u8, 186u8, 208u8, 190u8, 209u8, 129u8, 209u8, 131u8, 209u8, 130u8,
+                        209u8, 128u8, 208u8, 176u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("пре  дана"),
+                index: 7u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: Some(::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("за  дан"),
+                index: 5u8,
+            }),
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("за  дана"),
+                index: 5u8,
+            },
+        },
+    },
+    short: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[254u8, 255u8, 0u8, 1u8, 2u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 0u8, 24u8, 0u8, 34u8, 0u8, 44u8, 0u8,
+                        208u8, 191u8, 209u8, 128u8, 208u8, 181u8, 208u8, 186u8, 209u8, 152u8,
+                        209u8, 131u8, 209u8, 135u8, 208u8, 181u8, 209u8, 152u8, 209u8, 131u8,
+                        209u8, 135u8, 208u8, 181u8, 208u8, 180u8, 208u8, 176u8, 208u8, 189u8,
+                        208u8, 176u8, 209u8, 129u8, 209u8, 129u8, 209u8, 131u8, 209u8, 130u8,
+                        209u8, 128u8, 208u8, 176u8, 208u8, 191u8, 209u8, 128u8, 208u8, 181u8,
+                        208u8, 186u8, 208u8, 190u8, 209u8, 129u8, 209u8, 131u8, 209u8, 130u8,
+                        209u8, 128u8, 208u8, 176u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("пре  д."),
+                index: 7u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("за  д."),
+                index: 5u8,
+            },
+        },
+    },
+    narrow: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[254u8, 255u8, 0u8, 1u8, 2u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 0u8, 24u8, 0u8, 34u8, 0u8, 44u8, 0u8,
+                        208u8, 191u8, 209u8, 128u8, 208u8, 181u8, 208u8, 186u8, 209u8, 152u8,
+                        209u8, 131u8, 209u8, 135u8, 208u8, 181u8, 209u8, 152u8, 209u8, 131u8,
+                        209u8, 135u8, 208u8, 181u8, 208u8, 180u8, 208u8, 176u8, 208u8, 189u8,
+                        208u8, 176u8, 209u8, 129u8, 209u8, 129u8, 209u8, 131u8, 209u8, 130u8,
+                        209u8, 128u8, 208u8, 176u8, 208u8, 191u8, 209u8, 128u8, 208u8, 181u8,
+                        208u8, 186u8, 208u8, 190u8, 209u8, 129u8, 209u8, 131u8, 209u8, 130u8,
+                        209u8, 128u8, 208u8, 176u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("пре  д."),
+                index: 7u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("за  д."),
+                index: 5u8,
+            },
+        },
+    },
+};
+static TH: &DataStruct = &::icu_relativetime::provider::RelativeTimeFormatDataV1 {
+    long: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[254u8, 255u8, 0u8, 1u8, 2u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 33u8, 0u8, 57u8, 0u8, 75u8, 0u8, 99u8, 0u8,
+                        224u8, 185u8, 128u8, 224u8, 184u8, 161u8, 224u8, 184u8, 183u8, 224u8,
+                        185u8, 136u8, 224u8, 184u8, 173u8, 224u8, 184u8, 167u8, 224u8, 184u8,
+                        178u8, 224u8, 184u8, 153u8, 224u8, 184u8, 139u8, 224u8, 184u8, 183u8,
+                        224u8, 184u8, 153u8, 224u8, 185u8, 128u8, 224u8, 184u8, 161u8, 224u8,
+                        184u8, 183u8, 224u8, 185u8, 136u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                        167u8, 224u8, 184u8, 178u8, 224u8, 184u8, 153u8, 224u8, 184u8, 167u8,
+                        224u8, 184u8, 177u8, 224u8, 184u8, 153u8, 224u8, 184u8, 153u8, 224u8,
+                        184u8, 181u8, 224u8, 185u8, 137u8, 224u8, 184u8, 158u8, 224u8, 184u8,
+                        163u8, 224u8, 184u8, 184u8, 224u8, 185u8, 136u8, 224u8, 184u8, 135u8,
+                        224u8, 184u8, 153u8, 224u8, 184u8, 181u8, 224u8, 185u8, 137u8, 224u8,
+                        184u8, 161u8, 224u8, 184u8, 176u8, 224u8, 184u8, 163u8, 224u8, 184u8,
+                        183u8, 224u8, 184u8, 153u8, 224u8, 184u8, 153u8, 224u8, 184u8, 181u8,
+                        224u8, 185u8, 137u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" ว\u{e31}นท\u{e35}\u{e48}ผ\u{e48}านมา"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("ในอ\u{e35}ก  ว\u{e31}น"),
+                index: 16u8,
+            },
+        },
+    },
+    short: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[254u8, 255u8, 0u8, 1u8, 2u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 33u8, 0u8, 57u8, 0u8, 75u8, 0u8, 99u8, 0u8,
+                        224u8, 185u8, 128u8, 224u8, 184u8, 161u8, 224u8, 184u8, 183u8, 224u8,
+                        185u8, 136u8, 224u8, 184u8, 173u8, 224u8, 184u8, 167u8, 224u8, 184u8,
+                        178u8, 224u8, 184u8, 153u8, 224u8, 184u8, 139u8, 224u8, 184u8, 183u8,
+                        224u8, 184u8, 153u8, 224u8, 185u8, 128u8, 224u8, 184u8, 161u8, 224u8,
+                        184u8, 183u8, 224u8, 185u8, 136u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                        167u8, 224u8, 184u8, 178u8, 224u8, 184u8, 153u8, 224u8, 184u8, 167u8,
+                        224u8, 184u8, 177u8, 224u8, 184u8, 153u8, 224u8, 184u8, 153u8, 224u8,
+                        184u8, 181u8, 224u8, 185u8, 137u8, 224u8, 184u8, 158u8, 224u8, 184u8,
+                        163u8, 224u8, 184u8, 184u8, 224u8, 185u8, 136u8, 224u8, 184u8, 135u8,
+                        224u8, 184u8, 153u8, 224u8, 184u8, 181u8, 224u8, 185u8, 137u8, 224u8,
+                        184u8, 161u8, 224u8, 184u8, 176u8, 224u8, 184u8, 163u8, 224u8, 184u8,
+                        183u8, 224u8, 184u8, 153u8, 224u8, 184u8, 153u8, 224u8, 184u8, 181u8,
+                        224u8, 185u8, 137u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" ว\u{e31}นท\u{e35}\u{e48}แล\u{e49}ว"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("ใน  ว\u{e31}น"),
+                index: 7u8,
+            },
+        },
+    },
+    narrow: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[254u8, 255u8, 0u8, 1u8, 2u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 33u8, 0u8, 57u8, 0u8, 75u8, 0u8, 99u8, 0u8,
+                        224u8, 185u8, 128u8, 224u8, 184u8, 161u8, 224u8, 184u8, 183u8, 224u8,
+                        185u8, 136u8, 224u8, 184u8, 173u8, 224u8, 184u8, 167u8, 224u8, 184u8,
+                        178u8, 224u8, 184u8, 153u8, 224u8, 184u8, 139u8, 224u8, 184u8, 183u8,
+                        224u8, 184u8, 153u8, 224u8, 185u8, 128u8, 224u8, 184u8, 161u8, 224u8,
+                        184u8, 183u8, 224u8, 185u8, 136u8, 224u8, 184u8, 173u8, 224u8, 184u8,
+                        167u8, 224u8, 184u8, 178u8, 224u8, 184u8, 153u8, 224u8, 184u8, 167u8,
+                        224u8, 184u8, 177u8, 224u8, 184u8, 153u8, 224u8, 184u8, 153u8, 224u8,
+                        184u8, 181u8, 224u8, 185u8, 137u8, 224u8, 184u8, 158u8, 224u8, 184u8,
+                        163u8, 224u8, 184u8, 184u8, 224u8, 185u8, 136u8, 224u8, 184u8, 135u8,
+                        224u8, 184u8, 153u8, 224u8, 184u8, 181u8, 224u8, 185u8, 137u8, 224u8,
+                        184u8, 161u8, 224u8, 184u8, 176u8, 224u8, 184u8, 163u8, 224u8, 184u8,
+                        183u8, 224u8, 184u8, 153u8, 224u8, 184u8, 153u8, 224u8, 184u8, 181u8,
+                        224u8, 185u8, 137u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" ว\u{e31}นท\u{e35}\u{e48}แล\u{e49}ว"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed("ใน  ว\u{e31}น"),
+                index: 7u8,
+            },
+        },
+    },
+};
+static TR: &DataStruct = &::icu_relativetime::provider::RelativeTimeFormatDataV1 {
+    long: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[254u8, 255u8, 0u8, 1u8, 2u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 12u8, 0u8, 16u8, 0u8, 22u8, 0u8, 28u8, 0u8,
+                        101u8, 118u8, 118u8, 101u8, 108u8, 115u8, 105u8, 32u8, 103u8, 195u8, 188u8,
+                        110u8, 100u8, 195u8, 188u8, 110u8, 98u8, 117u8, 103u8, 195u8, 188u8, 110u8,
+                        121u8, 97u8, 114u8, 196u8, 177u8, 110u8, 195u8, 182u8, 98u8, 195u8, 188u8,
+                        114u8, 32u8, 103u8, 195u8, 188u8, 110u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" gün önce"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" gün sonra"),
+                index: 0u8,
+            },
+        },
+    },
+    short: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[254u8, 255u8, 0u8, 1u8, 2u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 12u8, 0u8, 16u8, 0u8, 22u8, 0u8, 28u8, 0u8,
+                        101u8, 118u8, 118u8, 101u8, 108u8, 115u8, 105u8, 32u8, 103u8, 195u8, 188u8,
+                        110u8, 100u8, 195u8, 188u8, 110u8, 98u8, 117u8, 103u8, 195u8, 188u8, 110u8,
+                        121u8, 97u8, 114u8, 196u8, 177u8, 110u8, 195u8, 182u8, 98u8, 195u8, 188u8,
+                        114u8, 32u8, 103u8, 195u8, 188u8, 110u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" gün önce"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" gün sonra"),
+                index: 0u8,
+            },
+        },
+    },
+    narrow: ::icu_relativetime::provider::RelativeTimePatternDataV1 {
+        relatives: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::ZeroVec::from_bytes_unchecked(&[254u8, 255u8, 0u8, 1u8, 2u8]) },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 12u8, 0u8, 16u8, 0u8, 22u8, 0u8, 28u8, 0u8,
+                        101u8, 118u8, 118u8, 101u8, 108u8, 115u8, 105u8, 32u8, 103u8, 195u8, 188u8,
+                        110u8, 100u8, 195u8, 188u8, 110u8, 98u8, 117u8, 103u8, 195u8, 188u8, 110u8,
+                        121u8, 97u8, 114u8, 196u8, 177u8, 110u8, 195u8, 182u8, 98u8, 195u8, 188u8,
+                        114u8, 32u8, 103u8, 195u8, 188u8, 110u8,
+                    ])
+                },
+            )
+        },
+        past: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" gün önce"),
+                index: 0u8,
+            },
+        },
+        future: ::icu_relativetime::provider::PluralPatterns {
+            zero: None,
+            one: None,
+            two: None,
+            few: None,
+            many: None,
+            other: ::icu_relativetime::provider::SingularSubPattern {
+                pattern: alloc::borrow::Cow::Borrowed(" gün sonra"),
+                index: 0u8,
+            },
+        },
+    },
+};
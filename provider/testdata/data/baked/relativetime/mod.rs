@@ -0,0 +1,16 @@
+// @generated
+pub mod day_v1;
+pub mod fri_v1;
+pub mod hour_v1;
+pub mod minute_v1;
+pub mod mon_v1;
+pub mod month_v1;
+pub mod quarter_v1;
+pub mod sat_v1;
+pub mod second_v1;
+pub mod sun_v1;
+pub mod thu_v1;
+pub mod tue_v1;
+pub mod wed_v1;
+pub mod week_v1;
+pub mod year_v1;
@@ -0,0 +1,1972 @@
+// @generated
+#![cfg(feature = "icu_units")]
+type DataStruct =
+    <::icu_units::provider::UnitsInfoV1Marker as ::icu_provider::DataMarker>::Yokeable;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_units::provider::UnitsInfoV1 {
+    conversions: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    113u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 17u8, 0u8, 23u8, 0u8, 33u8, 0u8,
+                    43u8, 0u8, 60u8, 0u8, 70u8, 0u8, 73u8, 0u8, 79u8, 0u8, 82u8, 0u8, 102u8, 0u8,
+                    108u8, 0u8, 112u8, 0u8, 119u8, 0u8, 126u8, 0u8, 131u8, 0u8, 138u8, 0u8, 145u8,
+                    0u8, 148u8, 0u8, 158u8, 0u8, 164u8, 0u8, 167u8, 0u8, 177u8, 0u8, 183u8, 0u8,
+                    189u8, 0u8, 202u8, 0u8, 224u8, 0u8, 227u8, 0u8, 231u8, 0u8, 235u8, 0u8, 240u8,
+                    0u8, 250u8, 0u8, 6u8, 1u8, 18u8, 1u8, 20u8, 1u8, 30u8, 1u8, 36u8, 1u8, 47u8,
+                    1u8, 67u8, 1u8, 78u8, 1u8, 82u8, 1u8, 89u8, 1u8, 96u8, 1u8, 102u8, 1u8, 117u8,
+                    1u8, 122u8, 1u8, 126u8, 1u8, 133u8, 1u8, 138u8, 1u8, 148u8, 1u8, 152u8, 1u8,
+                    156u8, 1u8, 160u8, 1u8, 166u8, 1u8, 171u8, 1u8, 176u8, 1u8, 182u8, 1u8, 190u8,
+                    1u8, 194u8, 1u8, 204u8, 1u8, 209u8, 1u8, 214u8, 1u8, 217u8, 1u8, 222u8, 1u8,
+                    226u8, 1u8, 243u8, 1u8, 249u8, 1u8, 253u8, 1u8, 2u8, 2u8, 14u8, 2u8, 27u8, 2u8,
+                    33u8, 2u8, 42u8, 2u8, 46u8, 2u8, 49u8, 2u8, 54u8, 2u8, 64u8, 2u8, 70u8, 2u8,
+                    76u8, 2u8, 83u8, 2u8, 91u8, 2u8, 101u8, 2u8, 110u8, 2u8, 115u8, 2u8, 119u8,
+                    2u8, 130u8, 2u8, 135u8, 2u8, 140u8, 2u8, 147u8, 2u8, 152u8, 2u8, 163u8, 2u8,
+                    168u8, 2u8, 182u8, 2u8, 189u8, 2u8, 195u8, 2u8, 205u8, 2u8, 211u8, 2u8, 227u8,
+                    2u8, 237u8, 2u8, 249u8, 2u8, 254u8, 2u8, 8u8, 3u8, 16u8, 3u8, 24u8, 3u8, 27u8,
+                    3u8, 32u8, 3u8, 36u8, 3u8, 40u8, 3u8, 44u8, 3u8, 55u8, 3u8, 59u8, 3u8, 63u8,
+                    3u8, 49u8, 48u8, 48u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 97u8, 99u8, 114u8, 101u8, 97u8, 109u8, 112u8, 101u8, 114u8,
+                    101u8, 97u8, 114u8, 99u8, 45u8, 109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 97u8,
+                    114u8, 99u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 97u8, 115u8, 116u8,
+                    114u8, 111u8, 110u8, 111u8, 109u8, 105u8, 99u8, 97u8, 108u8, 45u8, 117u8,
+                    110u8, 105u8, 116u8, 97u8, 116u8, 109u8, 111u8, 115u8, 112u8, 104u8, 101u8,
+                    114u8, 101u8, 98u8, 97u8, 114u8, 98u8, 97u8, 114u8, 114u8, 101u8, 108u8, 98u8,
+                    105u8, 116u8, 98u8, 114u8, 105u8, 116u8, 105u8, 115u8, 104u8, 45u8, 116u8,
+                    104u8, 101u8, 114u8, 109u8, 97u8, 108u8, 45u8, 117u8, 110u8, 105u8, 116u8,
+                    98u8, 117u8, 115u8, 104u8, 101u8, 108u8, 98u8, 121u8, 116u8, 101u8, 99u8, 97u8,
+                    108u8, 111u8, 114u8, 105u8, 101u8, 99u8, 97u8, 110u8, 100u8, 101u8, 108u8,
+                    97u8, 99u8, 97u8, 114u8, 97u8, 116u8, 99u8, 101u8, 108u8, 115u8, 105u8, 117u8,
+                    115u8, 99u8, 101u8, 110u8, 116u8, 117u8, 114u8, 121u8, 99u8, 117u8, 112u8,
+                    99u8, 117u8, 112u8, 45u8, 109u8, 101u8, 116u8, 114u8, 105u8, 99u8, 100u8, 97u8,
+                    108u8, 116u8, 111u8, 110u8, 100u8, 97u8, 121u8, 100u8, 97u8, 121u8, 45u8,
+                    112u8, 101u8, 114u8, 115u8, 111u8, 110u8, 100u8, 101u8, 99u8, 97u8, 100u8,
+                    101u8, 100u8, 101u8, 103u8, 114u8, 101u8, 101u8, 100u8, 101u8, 115u8, 115u8,
+                    101u8, 114u8, 116u8, 45u8, 115u8, 112u8, 111u8, 111u8, 110u8, 100u8, 101u8,
+                    115u8, 115u8, 101u8, 114u8, 116u8, 45u8, 115u8, 112u8, 111u8, 111u8, 110u8,
+                    45u8, 105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 100u8, 111u8,
+                    116u8, 100u8, 114u8, 97u8, 109u8, 100u8, 114u8, 111u8, 112u8, 100u8, 117u8,
+                    110u8, 97u8, 109u8, 101u8, 97u8, 114u8, 116u8, 104u8, 45u8, 109u8, 97u8, 115u8,
+                    115u8, 101u8, 97u8, 114u8, 116u8, 104u8, 45u8, 114u8, 97u8, 100u8, 105u8,
+                    117u8, 115u8, 101u8, 108u8, 101u8, 99u8, 116u8, 114u8, 111u8, 110u8, 118u8,
+                    111u8, 108u8, 116u8, 101u8, 109u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8,
+                    104u8, 101u8, 105u8, 116u8, 102u8, 97u8, 116u8, 104u8, 111u8, 109u8, 102u8,
+                    108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 102u8,
+                    108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 45u8,
+                    105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 102u8, 111u8, 111u8,
+                    100u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8, 101u8, 102u8, 111u8, 111u8,
+                    116u8, 102u8, 117u8, 114u8, 108u8, 111u8, 110u8, 103u8, 103u8, 45u8, 102u8,
+                    111u8, 114u8, 99u8, 101u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 103u8,
+                    97u8, 108u8, 108u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 103u8, 114u8, 97u8, 105u8, 110u8, 103u8, 114u8, 97u8,
+                    109u8, 104u8, 101u8, 99u8, 116u8, 97u8, 114u8, 101u8, 104u8, 101u8, 114u8,
+                    116u8, 122u8, 104u8, 111u8, 114u8, 115u8, 101u8, 112u8, 111u8, 119u8, 101u8,
+                    114u8, 104u8, 111u8, 117u8, 114u8, 105u8, 110u8, 99u8, 104u8, 105u8, 116u8,
+                    101u8, 109u8, 106u8, 105u8, 103u8, 103u8, 101u8, 114u8, 106u8, 111u8, 117u8,
+                    108u8, 101u8, 107u8, 97u8, 114u8, 97u8, 116u8, 107u8, 101u8, 108u8, 118u8,
+                    105u8, 110u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8,
+                    110u8, 111u8, 116u8, 108u8, 105u8, 103u8, 104u8, 116u8, 45u8, 121u8, 101u8,
+                    97u8, 114u8, 108u8, 105u8, 116u8, 101u8, 114u8, 108u8, 117u8, 109u8, 101u8,
+                    110u8, 108u8, 117u8, 120u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 105u8,
+                    108u8, 101u8, 109u8, 105u8, 108u8, 101u8, 45u8, 115u8, 99u8, 97u8, 110u8,
+                    100u8, 105u8, 110u8, 97u8, 118u8, 105u8, 97u8, 110u8, 109u8, 105u8, 110u8,
+                    117u8, 116u8, 101u8, 109u8, 111u8, 108u8, 101u8, 109u8, 111u8, 110u8, 116u8,
+                    104u8, 109u8, 111u8, 110u8, 116u8, 104u8, 45u8, 112u8, 101u8, 114u8, 115u8,
+                    111u8, 110u8, 110u8, 97u8, 117u8, 116u8, 105u8, 99u8, 97u8, 108u8, 45u8, 109u8,
+                    105u8, 108u8, 101u8, 110u8, 101u8, 119u8, 116u8, 111u8, 110u8, 111u8, 102u8,
+                    103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 111u8, 102u8, 104u8, 103u8,
+                    111u8, 104u8, 109u8, 111u8, 117u8, 110u8, 99u8, 101u8, 111u8, 117u8, 110u8,
+                    99u8, 101u8, 45u8, 116u8, 114u8, 111u8, 121u8, 112u8, 97u8, 114u8, 115u8,
+                    101u8, 99u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8, 112u8, 101u8, 114u8, 99u8,
+                    101u8, 110u8, 116u8, 112u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 101u8,
+                    112u8, 101u8, 114u8, 109u8, 105u8, 108u8, 108u8, 105u8, 111u8, 110u8, 112u8,
+                    101u8, 114u8, 109u8, 121u8, 114u8, 105u8, 97u8, 100u8, 112u8, 105u8, 110u8,
+                    99u8, 104u8, 112u8, 105u8, 110u8, 116u8, 112u8, 105u8, 110u8, 116u8, 45u8,
+                    109u8, 101u8, 116u8, 114u8, 105u8, 99u8, 112u8, 105u8, 120u8, 101u8, 108u8,
+                    112u8, 111u8, 105u8, 110u8, 116u8, 112u8, 111u8, 114u8, 116u8, 105u8, 111u8,
+                    110u8, 112u8, 111u8, 117u8, 110u8, 100u8, 112u8, 111u8, 117u8, 110u8, 100u8,
+                    45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 113u8, 117u8, 97u8, 114u8, 116u8,
+                    113u8, 117u8, 97u8, 114u8, 116u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8,
+                    105u8, 97u8, 108u8, 113u8, 117u8, 97u8, 114u8, 116u8, 101u8, 114u8, 114u8,
+                    97u8, 100u8, 105u8, 97u8, 110u8, 114u8, 101u8, 118u8, 111u8, 108u8, 117u8,
+                    116u8, 105u8, 111u8, 110u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 115u8,
+                    111u8, 108u8, 97u8, 114u8, 45u8, 108u8, 117u8, 109u8, 105u8, 110u8, 111u8,
+                    115u8, 105u8, 116u8, 121u8, 115u8, 111u8, 108u8, 97u8, 114u8, 45u8, 109u8,
+                    97u8, 115u8, 115u8, 115u8, 111u8, 108u8, 97u8, 114u8, 45u8, 114u8, 97u8, 100u8,
+                    105u8, 117u8, 115u8, 115u8, 116u8, 111u8, 110u8, 101u8, 116u8, 97u8, 98u8,
+                    108u8, 101u8, 115u8, 112u8, 111u8, 111u8, 110u8, 116u8, 101u8, 97u8, 115u8,
+                    112u8, 111u8, 111u8, 110u8, 116u8, 104u8, 101u8, 114u8, 109u8, 45u8, 117u8,
+                    115u8, 116u8, 111u8, 110u8, 116u8, 111u8, 110u8, 110u8, 101u8, 118u8, 111u8,
+                    108u8, 116u8, 119u8, 97u8, 116u8, 116u8, 119u8, 101u8, 101u8, 107u8, 119u8,
+                    101u8, 101u8, 107u8, 45u8, 112u8, 101u8, 114u8, 115u8, 111u8, 110u8, 121u8,
+                    97u8, 114u8, 100u8, 121u8, 101u8, 97u8, 114u8, 121u8, 101u8, 97u8, 114u8, 45u8,
+                    112u8, 101u8, 114u8, 115u8, 111u8, 110u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    113u8, 0u8, 0u8, 0u8, 0u8, 0u8, 33u8, 0u8, 76u8, 0u8, 108u8, 0u8, 145u8, 0u8,
+                    183u8, 0u8, 218u8, 0u8, 22u8, 1u8, 82u8, 1u8, 127u8, 1u8, 156u8, 1u8, 228u8,
+                    1u8, 18u8, 2u8, 47u8, 2u8, 113u8, 2u8, 146u8, 2u8, 181u8, 2u8, 216u8, 2u8,
+                    246u8, 2u8, 35u8, 3u8, 73u8, 3u8, 148u8, 3u8, 182u8, 3u8, 216u8, 3u8, 246u8,
+                    3u8, 27u8, 4u8, 72u8, 4u8, 115u8, 4u8, 146u8, 4u8, 191u8, 4u8, 237u8, 4u8,
+                    20u8, 5u8, 64u8, 5u8, 97u8, 5u8, 174u8, 5u8, 202u8, 5u8, 237u8, 5u8, 14u8, 6u8,
+                    59u8, 6u8, 102u8, 6u8, 168u8, 6u8, 201u8, 6u8, 233u8, 6u8, 29u8, 7u8, 73u8,
+                    7u8, 115u8, 7u8, 155u8, 7u8, 190u8, 7u8, 229u8, 7u8, 20u8, 8u8, 95u8, 8u8,
+                    128u8, 8u8, 160u8, 8u8, 190u8, 8u8, 235u8, 8u8, 44u8, 9u8, 77u8, 9u8, 109u8,
+                    9u8, 143u8, 9u8, 187u8, 9u8, 224u8, 9u8, 6u8, 10u8, 69u8, 10u8, 119u8, 10u8,
+                    150u8, 10u8, 183u8, 10u8, 215u8, 10u8, 247u8, 10u8, 30u8, 11u8, 60u8, 11u8,
+                    90u8, 11u8, 122u8, 11u8, 180u8, 11u8, 237u8, 11u8, 52u8, 12u8, 130u8, 12u8,
+                    170u8, 12u8, 209u8, 12u8, 253u8, 12u8, 55u8, 13u8, 88u8, 13u8, 122u8, 13u8,
+                    157u8, 13u8, 191u8, 13u8, 237u8, 13u8, 25u8, 14u8, 63u8, 14u8, 94u8, 14u8,
+                    127u8, 14u8, 160u8, 14u8, 200u8, 14u8, 12u8, 15u8, 56u8, 15u8, 98u8, 15u8,
+                    128u8, 15u8, 170u8, 15u8, 206u8, 15u8, 238u8, 15u8, 57u8, 16u8, 103u8, 16u8,
+                    137u8, 16u8, 177u8, 16u8, 222u8, 16u8, 11u8, 17u8, 79u8, 17u8, 117u8, 17u8,
+                    152u8, 17u8, 223u8, 17u8, 31u8, 18u8, 65u8, 18u8, 99u8, 18u8, 132u8, 18u8,
+                    162u8, 18u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 8u8,
+                    0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 1u8, 134u8, 160u8, 1u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    12u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8,
+                    0u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 18u8, 216u8, 58u8, 146u8, 1u8, 49u8, 45u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 8u8, 0u8,
+                    0u8, 0u8, 97u8, 109u8, 112u8, 101u8, 114u8, 101u8, 1u8, 1u8, 5u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 13u8, 0u8,
+                    0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 114u8, 101u8, 118u8, 111u8, 108u8, 117u8, 116u8,
+                    105u8, 111u8, 110u8, 1u8, 84u8, 96u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    10u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8,
+                    0u8, 114u8, 101u8, 118u8, 111u8, 108u8, 117u8, 116u8, 105u8, 111u8, 110u8, 1u8,
+                    19u8, 198u8, 128u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8,
+                    10u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 34u8, 212u8, 186u8, 90u8, 108u8, 1u8, 5u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 32u8, 0u8, 0u8, 0u8, 35u8, 0u8, 0u8, 0u8, 36u8, 0u8, 0u8,
+                    0u8, 36u8, 0u8, 0u8, 0u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8,
+                    109u8, 45u8, 112u8, 101u8, 114u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 1u8, 139u8, 205u8, 1u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    32u8, 0u8, 0u8, 0u8, 35u8, 0u8, 0u8, 0u8, 36u8, 0u8, 0u8, 0u8, 36u8, 0u8, 0u8,
+                    0u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8,
+                    97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 1u8, 134u8,
+                    160u8, 1u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 16u8,
+                    0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 2u8, 80u8, 70u8, 25u8,
+                    141u8, 14u8, 141u8, 74u8, 81u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    3u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8,
+                    98u8, 105u8, 116u8, 1u8, 1u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 39u8,
+                    0u8, 0u8, 0u8, 44u8, 0u8, 0u8, 0u8, 48u8, 0u8, 0u8, 0u8, 48u8, 0u8, 0u8, 0u8,
+                    107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 5u8, 133u8, 254u8, 111u8, 183u8, 1u8,
+                    87u8, 82u8, 160u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8,
+                    16u8, 0u8, 0u8, 0u8, 22u8, 0u8, 0u8, 0u8, 22u8, 0u8, 0u8, 0u8, 99u8, 117u8,
+                    98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 51u8, 71u8, 147u8,
+                    157u8, 127u8, 5u8, 175u8, 49u8, 7u8, 164u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 5u8, 0u8,
+                    0u8, 0u8, 98u8, 105u8, 116u8, 8u8, 1u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    39u8, 0u8, 0u8, 0u8, 41u8, 0u8, 0u8, 0u8, 42u8, 0u8, 0u8, 0u8, 42u8, 0u8, 0u8,
+                    0u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 2u8, 11u8, 125u8, 5u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8,
+                    9u8, 0u8, 0u8, 0u8, 99u8, 97u8, 110u8, 100u8, 101u8, 108u8, 97u8, 1u8, 1u8,
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8,
+                    11u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 107u8, 105u8, 108u8, 111u8, 103u8,
+                    114u8, 97u8, 109u8, 1u8, 19u8, 136u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    6u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8,
+                    0u8, 107u8, 101u8, 108u8, 118u8, 105u8, 110u8, 1u8, 1u8, 21u8, 87u8, 20u8, 5u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 6u8,
+                    0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 121u8, 101u8, 97u8, 114u8, 100u8, 1u8, 5u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8,
+                    21u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 28u8, 52u8, 25u8, 153u8, 1u8, 209u8, 169u8,
+                    74u8, 32u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8,
+                    12u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 99u8, 117u8,
+                    98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 1u8, 15u8, 160u8,
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 39u8, 0u8, 0u8, 0u8, 43u8, 0u8, 0u8,
+                    0u8, 51u8, 0u8, 0u8, 0u8, 51u8, 0u8, 0u8, 0u8, 107u8, 105u8, 108u8, 111u8,
+                    103u8, 114u8, 97u8, 109u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 111u8, 49u8, 157u8, 175u8, 173u8, 120u8, 235u8, 197u8, 172u8, 98u8, 0u8,
+                    0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8,
+                    0u8, 10u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 1u8, 81u8, 128u8, 1u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    6u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8,
+                    0u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 1u8, 81u8, 128u8, 1u8, 5u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 6u8, 0u8,
+                    0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 121u8, 101u8, 97u8, 114u8, 10u8, 1u8, 5u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 13u8,
+                    0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 114u8, 101u8, 118u8, 111u8, 108u8, 117u8,
+                    116u8, 105u8, 111u8, 110u8, 1u8, 1u8, 104u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 11u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 21u8, 0u8,
+                    0u8, 0u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 28u8, 52u8, 25u8, 153u8, 232u8, 212u8, 165u8, 16u8, 0u8, 0u8, 5u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 19u8,
+                    0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 6u8, 239u8, 209u8, 47u8, 175u8, 8u8, 0u8,
+                    0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8,
+                    0u8, 7u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 112u8, 105u8, 120u8, 101u8, 108u8,
+                    1u8, 1u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 15u8,
+                    0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 99u8, 117u8, 98u8,
+                    105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 28u8, 52u8, 25u8, 153u8,
+                    116u8, 106u8, 82u8, 136u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    11u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 22u8, 0u8, 0u8, 0u8, 22u8, 0u8, 0u8,
+                    0u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    9u8, 102u8, 179u8, 51u8, 10u8, 233u8, 247u8, 188u8, 192u8, 0u8, 0u8, 5u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 15u8,
+                    0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 3u8, 232u8, 1u8, 5u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8,
+                    0u8, 20u8, 0u8, 0u8, 0u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8,
+                    109u8, 4u8, 240u8, 169u8, 156u8, 88u8, 136u8, 72u8, 50u8, 160u8, 0u8, 0u8, 1u8,
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8,
+                    9u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    97u8, 82u8, 116u8, 1u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 39u8, 0u8, 0u8,
+                    0u8, 42u8, 0u8, 0u8, 0u8, 53u8, 0u8, 0u8, 0u8, 53u8, 0u8, 0u8, 0u8, 107u8,
+                    105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 115u8, 113u8, 117u8,
+                    97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8,
+                    101u8, 99u8, 111u8, 110u8, 100u8, 24u8, 114u8, 129u8, 8u8, 69u8, 149u8, 22u8,
+                    20u8, 1u8, 72u8, 74u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    2u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8,
+                    101u8, 109u8, 1u8, 1u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8,
+                    0u8, 7u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 107u8, 101u8,
+                    108u8, 118u8, 105u8, 110u8, 5u8, 9u8, 179u8, 143u8, 180u8, 5u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8,
+                    9u8, 0u8, 0u8, 0u8, 109u8, 101u8, 116u8, 101u8, 114u8, 4u8, 119u8, 2u8, 113u8,
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8,
+                    0u8, 21u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 99u8, 117u8, 98u8, 105u8, 99u8,
+                    45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 28u8, 52u8, 25u8, 153u8, 14u8, 141u8,
+                    74u8, 81u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8,
+                    0u8, 14u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 99u8,
+                    117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 6u8, 239u8,
+                    209u8, 3u8, 185u8, 172u8, 160u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    39u8, 0u8, 0u8, 0u8, 41u8, 0u8, 0u8, 0u8, 42u8, 0u8, 0u8, 0u8, 42u8, 0u8, 0u8,
+                    0u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 16u8, 88u8, 1u8, 5u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8,
+                    9u8, 0u8, 0u8, 0u8, 109u8, 101u8, 116u8, 101u8, 114u8, 1u8, 125u8, 4u8, 226u8,
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8,
+                    8u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    98u8, 58u8, 125u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 23u8, 0u8, 0u8, 0u8,
+                    26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8,
+                    97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 2u8, 254u8,
+                    37u8, 78u8, 32u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8,
+                    15u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8, 0u8, 99u8, 117u8,
+                    98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 28u8, 52u8, 25u8,
+                    153u8, 29u8, 26u8, 148u8, 162u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    11u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8,
+                    0u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    6u8, 239u8, 209u8, 5u8, 245u8, 225u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 8u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 16u8, 0u8,
+                    0u8, 0u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 98u8, 224u8,
+                    19u8, 23u8, 72u8, 118u8, 232u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    8u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8,
+                    0u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 1u8, 3u8, 232u8,
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8,
+                    0u8, 15u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 39u8, 16u8, 1u8, 5u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 22u8, 0u8, 0u8, 0u8,
+                    23u8, 0u8, 0u8, 0u8, 23u8, 0u8, 0u8, 0u8, 114u8, 101u8, 118u8, 111u8, 108u8,
+                    117u8, 116u8, 105u8, 111u8, 110u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8,
+                    101u8, 99u8, 111u8, 110u8, 100u8, 1u8, 1u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 38u8, 0u8, 0u8, 0u8, 45u8, 0u8, 0u8, 0u8, 51u8, 0u8, 0u8, 0u8, 51u8, 0u8,
+                    0u8, 0u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 115u8,
+                    101u8, 99u8, 111u8, 110u8, 100u8, 132u8, 118u8, 129u8, 13u8, 188u8, 92u8, 39u8,
+                    45u8, 121u8, 136u8, 61u8, 32u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    6u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8,
+                    115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 14u8, 16u8, 1u8, 5u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8,
+                    8u8, 0u8, 0u8, 0u8, 109u8, 101u8, 116u8, 101u8, 114u8, 127u8, 19u8, 136u8, 5u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 6u8,
+                    0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 105u8, 116u8, 101u8, 109u8, 1u8, 1u8, 5u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8,
+                    21u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 84u8, 156u8, 76u8, 203u8, 29u8, 26u8, 148u8,
+                    162u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 39u8, 0u8, 0u8, 0u8,
+                    40u8, 0u8, 0u8, 0u8, 41u8, 0u8, 0u8, 0u8, 41u8, 0u8, 0u8, 0u8, 107u8, 105u8,
+                    108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8,
+                    111u8, 110u8, 100u8, 1u8, 1u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8,
+                    0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8,
+                    112u8, 111u8, 114u8, 116u8, 105u8, 111u8, 110u8, 1u8, 24u8, 5u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8,
+                    8u8, 0u8, 0u8, 0u8, 107u8, 101u8, 108u8, 118u8, 105u8, 110u8, 1u8, 1u8, 5u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8,
+                    10u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 107u8, 105u8, 108u8, 111u8, 103u8,
+                    114u8, 97u8, 109u8, 1u8, 1u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8,
+                    0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8, 0u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8,
+                    101u8, 99u8, 111u8, 110u8, 100u8, 1u8, 207u8, 3u8, 132u8, 5u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8,
+                    0u8, 13u8, 0u8, 0u8, 0u8, 109u8, 101u8, 116u8, 101u8, 114u8, 33u8, 156u8,
+                    123u8, 247u8, 34u8, 70u8, 192u8, 1u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    11u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8,
+                    0u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    1u8, 3u8, 232u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 37u8, 0u8, 0u8, 0u8,
+                    38u8, 0u8, 0u8, 0u8, 39u8, 0u8, 0u8, 0u8, 39u8, 0u8, 0u8, 0u8, 99u8, 97u8,
+                    110u8, 100u8, 101u8, 108u8, 97u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8,
+                    101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 1u8, 1u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 24u8, 0u8, 0u8,
+                    0u8, 25u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 99u8, 97u8,
+                    110u8, 100u8, 101u8, 108u8, 97u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 1u8,
+                    1u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8,
+                    0u8, 7u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    1u8, 1u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 8u8, 0u8,
+                    0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 3u8, 17u8, 208u8, 125u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8,
+                    0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 39u8, 16u8, 1u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 8u8,
+                    0u8, 0u8, 0u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 60u8, 1u8, 5u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 15u8,
+                    0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 105u8, 116u8, 101u8, 109u8, 127u8, 134u8,
+                    23u8, 41u8, 95u8, 20u8, 92u8, 198u8, 0u8, 0u8, 1u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 6u8,
+                    0u8, 0u8, 0u8, 121u8, 101u8, 97u8, 114u8, 1u8, 12u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 6u8,
+                    0u8, 0u8, 0u8, 121u8, 101u8, 97u8, 114u8, 1u8, 12u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 8u8,
+                    0u8, 0u8, 0u8, 109u8, 101u8, 116u8, 101u8, 114u8, 7u8, 60u8, 1u8, 5u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 32u8, 0u8, 0u8, 0u8, 33u8, 0u8, 0u8, 0u8, 34u8,
+                    0u8, 0u8, 0u8, 34u8, 0u8, 0u8, 0u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8,
+                    97u8, 109u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8,
+                    111u8, 110u8, 100u8, 1u8, 1u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 17u8,
+                    0u8, 0u8, 0u8, 30u8, 0u8, 0u8, 0u8, 33u8, 0u8, 0u8, 0u8, 33u8, 0u8, 0u8, 0u8,
+                    105u8, 116u8, 101u8, 109u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 76u8, 2u8, 150u8, 21u8, 46u8, 70u8,
+                    124u8, 142u8, 26u8, 103u8, 0u8, 0u8, 0u8, 27u8, 125u8, 85u8, 5u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 39u8, 0u8, 0u8, 0u8, 44u8, 0u8, 0u8, 0u8, 47u8, 0u8,
+                    0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8,
+                    109u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8,
+                    101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8,
+                    97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 6u8, 53u8,
+                    83u8, 163u8, 43u8, 3u8, 13u8, 64u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    52u8, 0u8, 0u8, 0u8, 53u8, 0u8, 0u8, 0u8, 54u8, 0u8, 0u8, 0u8, 54u8, 0u8, 0u8,
+                    0u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    97u8, 109u8, 112u8, 101u8, 114u8, 101u8, 1u8, 1u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8,
+                    16u8, 0u8, 0u8, 0u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8,
+                    2u8, 180u8, 32u8, 133u8, 95u8, 94u8, 16u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 15u8,
+                    0u8, 0u8, 0u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 11u8,
+                    221u8, 115u8, 1u8, 125u8, 120u8, 64u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    5u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8,
+                    0u8, 109u8, 101u8, 116u8, 101u8, 114u8, 10u8, 129u8, 49u8, 71u8, 215u8, 176u8,
+                    59u8, 225u8, 241u8, 160u8, 0u8, 24u8, 135u8, 224u8, 99u8, 5u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 32u8, 0u8, 0u8, 0u8, 33u8, 0u8, 0u8, 0u8, 34u8, 0u8, 0u8,
+                    0u8, 34u8, 0u8, 0u8, 0u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8,
+                    109u8, 45u8, 112u8, 101u8, 114u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 1u8, 1u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8,
+                    0u8, 8u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 112u8, 111u8,
+                    114u8, 116u8, 105u8, 111u8, 110u8, 1u8, 100u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 10u8,
+                    0u8, 0u8, 0u8, 112u8, 111u8, 114u8, 116u8, 105u8, 111u8, 110u8, 1u8, 3u8,
+                    232u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 8u8, 0u8,
+                    0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 112u8, 111u8, 114u8, 116u8,
+                    105u8, 111u8, 110u8, 1u8, 15u8, 66u8, 64u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 7u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 10u8, 0u8,
+                    0u8, 0u8, 112u8, 111u8, 114u8, 116u8, 105u8, 111u8, 110u8, 1u8, 39u8, 16u8,
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8,
+                    0u8, 22u8, 0u8, 0u8, 0u8, 22u8, 0u8, 0u8, 0u8, 99u8, 117u8, 98u8, 105u8, 99u8,
+                    45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 28u8, 52u8, 25u8, 153u8, 7u8, 70u8,
+                    165u8, 40u8, 128u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8,
+                    0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8, 0u8,
+                    99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 28u8,
+                    52u8, 25u8, 153u8, 232u8, 212u8, 165u8, 16u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8,
+                    14u8, 0u8, 0u8, 0u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 1u8, 7u8, 208u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8,
+                    0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8,
+                    112u8, 105u8, 120u8, 101u8, 108u8, 1u8, 1u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 5u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8,
+                    0u8, 109u8, 101u8, 116u8, 101u8, 114u8, 127u8, 5u8, 126u8, 64u8, 5u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8,
+                    0u8, 9u8, 0u8, 0u8, 0u8, 112u8, 111u8, 114u8, 116u8, 105u8, 111u8, 110u8, 1u8,
+                    1u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 12u8, 0u8,
+                    0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 107u8, 105u8, 108u8, 111u8,
+                    103u8, 114u8, 97u8, 109u8, 2u8, 180u8, 32u8, 133u8, 5u8, 245u8, 225u8, 0u8,
+                    5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 32u8, 0u8, 0u8, 0u8, 38u8, 0u8, 0u8,
+                    0u8, 44u8, 0u8, 0u8, 0u8, 44u8, 0u8, 0u8, 0u8, 107u8, 105u8, 108u8, 111u8,
+                    103u8, 114u8, 97u8, 109u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+                    112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 8u8, 23u8, 93u8, 86u8, 169u8, 57u8,
+                    1u8, 209u8, 169u8, 74u8, 32u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    11u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8,
+                    0u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    28u8, 52u8, 25u8, 153u8, 116u8, 106u8, 82u8, 136u8, 0u8, 5u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8,
+                    0u8, 18u8, 0u8, 0u8, 0u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 6u8, 239u8, 209u8, 23u8, 215u8, 132u8, 0u8, 5u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8,
+                    0u8, 6u8, 0u8, 0u8, 0u8, 121u8, 101u8, 97u8, 114u8, 1u8, 4u8, 5u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 18u8, 0u8,
+                    0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 114u8, 101u8, 118u8, 111u8, 108u8, 117u8, 116u8,
+                    105u8, 111u8, 110u8, 3u8, 231u8, 121u8, 48u8, 24u8, 135u8, 224u8, 99u8, 5u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8,
+                    12u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 114u8, 101u8, 118u8, 111u8, 108u8,
+                    117u8, 116u8, 105u8, 111u8, 110u8, 1u8, 1u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 6u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8,
+                    0u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 1u8, 1u8, 5u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 38u8, 0u8, 0u8, 0u8, 50u8, 0u8, 0u8, 0u8, 51u8, 0u8, 0u8,
+                    0u8, 51u8, 0u8, 0u8, 0u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8,
+                    109u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 1u8, 60u8, 165u8, 13u8,
+                    148u8, 212u8, 212u8, 237u8, 242u8, 0u8, 0u8, 0u8, 1u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 22u8, 0u8, 0u8, 0u8,
+                    22u8, 0u8, 0u8, 0u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8,
+                    25u8, 25u8, 23u8, 213u8, 40u8, 28u8, 208u8, 21u8, 63u8, 54u8, 0u8, 0u8, 0u8,
+                    1u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8,
+                    0u8, 10u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 41u8, 119u8, 138u8, 32u8, 1u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    8u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8,
+                    0u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 18u8, 236u8, 227u8,
+                    163u8, 2u8, 250u8, 240u8, 128u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8,
+                    0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8,
+                    99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 28u8,
+                    52u8, 25u8, 153u8, 29u8, 26u8, 148u8, 162u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8,
+                    21u8, 0u8, 0u8, 0u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 9u8, 102u8, 179u8, 51u8, 29u8, 26u8, 148u8, 162u8, 0u8, 0u8, 5u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 39u8, 0u8, 0u8, 0u8, 43u8, 0u8, 0u8, 0u8,
+                    44u8, 0u8, 0u8, 0u8, 44u8, 0u8, 0u8, 0u8, 107u8, 105u8, 108u8, 111u8, 103u8,
+                    114u8, 97u8, 109u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8,
+                    101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8,
+                    117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 6u8,
+                    73u8, 128u8, 208u8, 1u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8,
+                    0u8, 12u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 107u8,
+                    105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 2u8, 180u8, 32u8, 133u8, 195u8,
+                    80u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 10u8, 0u8,
+                    0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 107u8, 105u8, 108u8, 111u8,
+                    103u8, 114u8, 97u8, 109u8, 3u8, 232u8, 1u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 45u8, 0u8, 0u8, 0u8, 46u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 47u8, 0u8,
+                    0u8, 0u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 115u8,
+                    101u8, 99u8, 111u8, 110u8, 100u8, 45u8, 97u8, 109u8, 112u8, 101u8, 114u8,
+                    101u8, 1u8, 1u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 38u8, 0u8, 0u8, 0u8,
+                    39u8, 0u8, 0u8, 0u8, 40u8, 0u8, 0u8, 0u8, 40u8, 0u8, 0u8, 0u8, 107u8, 105u8,
+                    108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 1u8, 1u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8,
+                    0u8, 9u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 115u8,
+                    101u8, 99u8, 111u8, 110u8, 100u8, 9u8, 58u8, 128u8, 1u8, 5u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8,
+                    0u8, 10u8, 0u8, 0u8, 0u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 9u8, 58u8,
+                    128u8, 1u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 7u8,
+                    0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 4u8, 119u8, 4u8, 226u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    4u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8,
+                    121u8, 101u8, 97u8, 114u8, 1u8, 1u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    4u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8,
+                    121u8, 101u8, 97u8, 114u8, 1u8, 1u8,
+                ])
+            },
+        )
+    },
+    quantities: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    45u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 22u8, 0u8, 45u8, 0u8, 48u8, 0u8, 55u8,
+                    0u8, 79u8, 0u8, 116u8, 0u8, 127u8, 0u8, 151u8, 0u8, 172u8, 0u8, 224u8, 0u8,
+                    226u8, 0u8, 230u8, 0u8, 250u8, 0u8, 11u8, 1u8, 17u8, 1u8, 25u8, 1u8, 57u8, 1u8,
+                    81u8, 1u8, 102u8, 1u8, 134u8, 1u8, 173u8, 1u8, 206u8, 1u8, 244u8, 1u8, 33u8,
+                    2u8, 85u8, 2u8, 124u8, 2u8, 170u8, 2u8, 223u8, 2u8, 228u8, 2u8, 244u8, 2u8,
+                    11u8, 3u8, 16u8, 3u8, 31u8, 3u8, 38u8, 3u8, 89u8, 3u8, 99u8, 3u8, 119u8, 3u8,
+                    140u8, 3u8, 146u8, 3u8, 159u8, 3u8, 171u8, 3u8, 201u8, 3u8, 218u8, 3u8, 97u8,
+                    109u8, 112u8, 101u8, 114u8, 101u8, 97u8, 109u8, 112u8, 101u8, 114u8, 101u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 97u8,
+                    109u8, 112u8, 101u8, 114u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    98u8, 105u8, 116u8, 99u8, 97u8, 110u8, 100u8, 101u8, 108u8, 97u8, 99u8, 97u8,
+                    110u8, 100u8, 101u8, 108u8, 97u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    99u8, 97u8, 110u8, 100u8, 101u8, 108u8, 97u8, 45u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8, 108u8, 111u8, 103u8,
+                    114u8, 97u8, 109u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 97u8, 109u8, 112u8,
+                    101u8, 114u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8, 108u8,
+                    111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8,
+                    101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 101u8, 109u8, 105u8, 116u8,
+                    101u8, 109u8, 105u8, 116u8, 101u8, 109u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+                    99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 105u8,
+                    116u8, 101u8, 109u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8, 108u8,
+                    111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 101u8, 108u8, 118u8, 105u8, 110u8,
+                    107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8,
+                    111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+                    115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 107u8, 105u8, 108u8, 111u8, 103u8,
+                    114u8, 97u8, 109u8, 45u8, 112u8, 101u8, 114u8, 45u8, 99u8, 117u8, 98u8, 105u8,
+                    99u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 107u8, 105u8, 108u8, 111u8,
+                    103u8, 114u8, 97u8, 109u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8,
+                    108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 107u8, 105u8, 108u8, 111u8, 103u8,
+                    114u8, 97u8, 109u8, 45u8, 112u8, 101u8, 114u8, 45u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8,
+                    101u8, 99u8, 111u8, 110u8, 100u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8,
+                    97u8, 109u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8,
+                    101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8,
+                    97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 107u8,
+                    105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 45u8, 97u8, 109u8, 112u8, 101u8, 114u8, 101u8, 107u8, 105u8,
+                    108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8,
+                    115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8,
+                    115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 45u8, 97u8, 109u8, 112u8, 101u8,
+                    114u8, 101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8,
+                    115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8,
+                    114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8,
+                    115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 45u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 97u8, 109u8, 112u8, 101u8, 114u8, 101u8, 107u8, 105u8,
+                    108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 45u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8,
+                    114u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8,
+                    111u8, 110u8, 100u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8,
+                    45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 45u8, 97u8, 109u8,
+                    112u8, 101u8, 114u8, 101u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8,
+                    109u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8,
+                    116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8,
+                    97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 45u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 97u8, 109u8, 112u8, 101u8, 114u8,
+                    101u8, 109u8, 101u8, 116u8, 101u8, 114u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8,
+                    109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8,
+                    113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+                    100u8, 112u8, 105u8, 120u8, 101u8, 108u8, 112u8, 105u8, 120u8, 101u8, 108u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 112u8,
+                    111u8, 114u8, 116u8, 105u8, 111u8, 110u8, 112u8, 111u8, 119u8, 52u8, 45u8,
+                    115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 45u8, 115u8, 113u8, 117u8, 97u8,
+                    114u8, 101u8, 45u8, 97u8, 109u8, 112u8, 101u8, 114u8, 101u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8,
+                    45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8,
+                    101u8, 114u8, 114u8, 101u8, 118u8, 111u8, 108u8, 117u8, 116u8, 105u8, 111u8,
+                    110u8, 114u8, 101u8, 118u8, 111u8, 108u8, 117u8, 116u8, 105u8, 111u8, 110u8,
+                    45u8, 112u8, 101u8, 114u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 114u8,
+                    101u8, 118u8, 111u8, 108u8, 117u8, 116u8, 105u8, 111u8, 110u8, 45u8, 112u8,
+                    101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 115u8, 101u8,
+                    99u8, 111u8, 110u8, 100u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 45u8, 97u8,
+                    109u8, 112u8, 101u8, 114u8, 101u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+                    45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8,
+                    101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8,
+                    45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 115u8, 101u8, 99u8, 111u8,
+                    110u8, 100u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 114u8, 101u8,
+                    118u8, 111u8, 108u8, 117u8, 116u8, 105u8, 111u8, 110u8, 121u8, 101u8, 97u8,
+                    114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    45u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 0u8, 39u8, 0u8, 54u8, 0u8, 61u8, 0u8,
+                    79u8, 0u8, 90u8, 0u8, 103u8, 0u8, 109u8, 0u8, 124u8, 0u8, 135u8, 0u8, 155u8,
+                    0u8, 164u8, 0u8, 180u8, 0u8, 193u8, 0u8, 211u8, 0u8, 222u8, 0u8, 226u8, 0u8,
+                    231u8, 0u8, 243u8, 0u8, 0u8, 1u8, 8u8, 1u8, 27u8, 1u8, 45u8, 1u8, 50u8, 1u8,
+                    57u8, 1u8, 76u8, 1u8, 82u8, 1u8, 95u8, 1u8, 114u8, 1u8, 120u8, 1u8, 125u8, 1u8,
+                    137u8, 1u8, 145u8, 1u8, 155u8, 1u8, 162u8, 1u8, 182u8, 1u8, 187u8, 1u8, 198u8,
+                    1u8, 207u8, 1u8, 215u8, 1u8, 230u8, 1u8, 234u8, 1u8, 238u8, 1u8, 249u8, 1u8,
+                    101u8, 108u8, 101u8, 99u8, 116u8, 114u8, 105u8, 99u8, 45u8, 99u8, 117u8, 114u8,
+                    114u8, 101u8, 110u8, 116u8, 109u8, 97u8, 103u8, 110u8, 101u8, 116u8, 105u8,
+                    99u8, 45u8, 102u8, 105u8, 101u8, 108u8, 100u8, 45u8, 115u8, 116u8, 114u8,
+                    101u8, 110u8, 103u8, 116u8, 104u8, 99u8, 117u8, 114u8, 114u8, 101u8, 110u8,
+                    116u8, 45u8, 100u8, 101u8, 110u8, 115u8, 105u8, 116u8, 121u8, 100u8, 105u8,
+                    103u8, 105u8, 116u8, 97u8, 108u8, 108u8, 117u8, 109u8, 105u8, 110u8, 111u8,
+                    117u8, 115u8, 45u8, 105u8, 110u8, 116u8, 101u8, 110u8, 115u8, 105u8, 116u8,
+                    121u8, 105u8, 108u8, 108u8, 117u8, 109u8, 105u8, 110u8, 97u8, 110u8, 99u8,
+                    101u8, 108u8, 117u8, 109u8, 105u8, 110u8, 111u8, 117u8, 115u8, 45u8, 102u8,
+                    108u8, 117u8, 120u8, 118u8, 111u8, 108u8, 117u8, 109u8, 101u8, 115u8, 112u8,
+                    101u8, 99u8, 105u8, 102u8, 105u8, 99u8, 45u8, 118u8, 111u8, 108u8, 117u8,
+                    109u8, 101u8, 99u8, 111u8, 110u8, 115u8, 117u8, 109u8, 112u8, 116u8, 105u8,
+                    111u8, 110u8, 101u8, 108u8, 101u8, 99u8, 116u8, 114u8, 105u8, 99u8, 45u8, 99u8,
+                    111u8, 110u8, 100u8, 117u8, 99u8, 116u8, 97u8, 110u8, 99u8, 101u8, 116u8,
+                    121u8, 112u8, 101u8, 119u8, 105u8, 100u8, 116u8, 104u8, 115u8, 117u8, 98u8,
+                    115u8, 116u8, 97u8, 110u8, 99u8, 101u8, 45u8, 97u8, 109u8, 111u8, 117u8, 110u8,
+                    116u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8,
+                    105u8, 111u8, 110u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8,
+                    97u8, 116u8, 105u8, 111u8, 110u8, 45u8, 109u8, 97u8, 115u8, 115u8, 116u8,
+                    101u8, 109u8, 112u8, 101u8, 114u8, 97u8, 116u8, 117u8, 114u8, 101u8, 109u8,
+                    97u8, 115u8, 115u8, 102u8, 111u8, 114u8, 99u8, 101u8, 109u8, 97u8, 115u8,
+                    115u8, 45u8, 100u8, 101u8, 110u8, 115u8, 105u8, 116u8, 121u8, 109u8, 97u8,
+                    115u8, 115u8, 45u8, 102u8, 114u8, 97u8, 99u8, 116u8, 105u8, 111u8, 110u8,
+                    112u8, 114u8, 101u8, 115u8, 115u8, 117u8, 114u8, 101u8, 112u8, 114u8, 101u8,
+                    115u8, 115u8, 117u8, 114u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8,
+                    101u8, 110u8, 103u8, 116u8, 104u8, 109u8, 97u8, 103u8, 110u8, 101u8, 116u8,
+                    105u8, 99u8, 45u8, 105u8, 110u8, 100u8, 117u8, 99u8, 116u8, 105u8, 111u8,
+                    110u8, 112u8, 111u8, 119u8, 101u8, 114u8, 118u8, 111u8, 108u8, 116u8, 97u8,
+                    103u8, 101u8, 101u8, 108u8, 101u8, 99u8, 116u8, 114u8, 105u8, 99u8, 45u8,
+                    114u8, 101u8, 115u8, 105u8, 115u8, 116u8, 97u8, 110u8, 99u8, 101u8, 101u8,
+                    110u8, 101u8, 114u8, 103u8, 121u8, 109u8, 97u8, 103u8, 110u8, 101u8, 116u8,
+                    105u8, 99u8, 45u8, 102u8, 108u8, 117u8, 120u8, 101u8, 108u8, 101u8, 99u8,
+                    116u8, 114u8, 105u8, 99u8, 45u8, 105u8, 110u8, 100u8, 117u8, 99u8, 116u8, 97u8,
+                    110u8, 99u8, 101u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 115u8, 112u8,
+                    101u8, 101u8, 100u8, 97u8, 99u8, 99u8, 101u8, 108u8, 101u8, 114u8, 97u8, 116u8,
+                    105u8, 111u8, 110u8, 103u8, 114u8, 97u8, 112u8, 104u8, 105u8, 99u8, 115u8,
+                    114u8, 101u8, 115u8, 111u8, 108u8, 117u8, 116u8, 105u8, 111u8, 110u8, 112u8,
+                    111u8, 114u8, 116u8, 105u8, 111u8, 110u8, 101u8, 108u8, 101u8, 99u8, 116u8,
+                    114u8, 105u8, 99u8, 45u8, 99u8, 97u8, 112u8, 97u8, 99u8, 105u8, 116u8, 97u8,
+                    110u8, 99u8, 101u8, 97u8, 110u8, 103u8, 108u8, 101u8, 119u8, 97u8, 118u8,
+                    101u8, 45u8, 110u8, 117u8, 109u8, 98u8, 101u8, 114u8, 102u8, 114u8, 101u8,
+                    113u8, 117u8, 101u8, 110u8, 99u8, 121u8, 100u8, 117u8, 114u8, 97u8, 116u8,
+                    105u8, 111u8, 110u8, 101u8, 108u8, 101u8, 99u8, 116u8, 114u8, 105u8, 99u8,
+                    45u8, 99u8, 104u8, 97u8, 114u8, 103u8, 101u8, 97u8, 114u8, 101u8, 97u8, 100u8,
+                    111u8, 115u8, 101u8, 115u8, 111u8, 108u8, 105u8, 100u8, 45u8, 97u8, 110u8,
+                    103u8, 108u8, 101u8, 121u8, 101u8, 97u8, 114u8, 45u8, 100u8, 117u8, 114u8,
+                    97u8, 116u8, 105u8, 111u8, 110u8,
+                ])
+            },
+        )
+    },
+    preferences: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            27u8, 1u8, 0u8, 0u8, 0u8, 0u8, 62u8, 0u8, 115u8, 0u8, 173u8, 0u8, 236u8, 0u8, 36u8,
+            1u8, 85u8, 1u8, 141u8, 1u8, 197u8, 1u8, 253u8, 1u8, 46u8, 2u8, 102u8, 2u8, 158u8, 2u8,
+            214u8, 2u8, 12u8, 3u8, 66u8, 3u8, 120u8, 3u8, 174u8, 3u8, 237u8, 3u8, 38u8, 4u8, 95u8,
+            4u8, 145u8, 4u8, 191u8, 4u8, 237u8, 4u8, 75u8, 5u8, 154u8, 5u8, 233u8, 5u8, 56u8, 6u8,
+            135u8, 6u8, 214u8, 6u8, 37u8, 7u8, 116u8, 7u8, 195u8, 7u8, 18u8, 8u8, 97u8, 8u8, 176u8,
+            8u8, 255u8, 8u8, 78u8, 9u8, 157u8, 9u8, 236u8, 9u8, 59u8, 10u8, 138u8, 10u8, 217u8,
+            10u8, 40u8, 11u8, 119u8, 11u8, 198u8, 11u8, 21u8, 12u8, 100u8, 12u8, 179u8, 12u8, 2u8,
+            13u8, 81u8, 13u8, 160u8, 13u8, 239u8, 13u8, 62u8, 14u8, 141u8, 14u8, 220u8, 14u8, 43u8,
+            15u8, 122u8, 15u8, 201u8, 15u8, 24u8, 16u8, 103u8, 16u8, 182u8, 16u8, 5u8, 17u8, 84u8,
+            17u8, 163u8, 17u8, 242u8, 17u8, 65u8, 18u8, 144u8, 18u8, 223u8, 18u8, 46u8, 19u8,
+            125u8, 19u8, 204u8, 19u8, 27u8, 20u8, 106u8, 20u8, 185u8, 20u8, 8u8, 21u8, 87u8, 21u8,
+            166u8, 21u8, 245u8, 21u8, 68u8, 22u8, 147u8, 22u8, 226u8, 22u8, 49u8, 23u8, 128u8,
+            23u8, 207u8, 23u8, 30u8, 24u8, 109u8, 24u8, 188u8, 24u8, 11u8, 25u8, 90u8, 25u8, 169u8,
+            25u8, 248u8, 25u8, 71u8, 26u8, 146u8, 26u8, 222u8, 26u8, 47u8, 27u8, 123u8, 27u8,
+            204u8, 27u8, 29u8, 28u8, 105u8, 28u8, 181u8, 28u8, 1u8, 29u8, 77u8, 29u8, 153u8, 29u8,
+            229u8, 29u8, 49u8, 30u8, 125u8, 30u8, 197u8, 30u8, 250u8, 30u8, 48u8, 31u8, 104u8,
+            31u8, 160u8, 31u8, 221u8, 31u8, 26u8, 32u8, 86u8, 32u8, 151u8, 32u8, 205u8, 32u8, 10u8,
+            33u8, 66u8, 33u8, 121u8, 33u8, 178u8, 33u8, 231u8, 33u8, 33u8, 34u8, 84u8, 34u8, 135u8,
+            34u8, 186u8, 34u8, 237u8, 34u8, 32u8, 35u8, 83u8, 35u8, 146u8, 35u8, 203u8, 35u8,
+            253u8, 35u8, 47u8, 36u8, 97u8, 36u8, 147u8, 36u8, 211u8, 36u8, 28u8, 37u8, 101u8, 37u8,
+            167u8, 37u8, 224u8, 37u8, 41u8, 38u8, 114u8, 38u8, 187u8, 38u8, 4u8, 39u8, 70u8, 39u8,
+            127u8, 39u8, 200u8, 39u8, 17u8, 40u8, 90u8, 40u8, 156u8, 40u8, 213u8, 40u8, 30u8, 41u8,
+            103u8, 41u8, 176u8, 41u8, 249u8, 41u8, 66u8, 42u8, 139u8, 42u8, 205u8, 42u8, 6u8, 43u8,
+            79u8, 43u8, 138u8, 43u8, 196u8, 43u8, 248u8, 43u8, 46u8, 44u8, 118u8, 44u8, 190u8,
+            44u8, 5u8, 45u8, 53u8, 45u8, 123u8, 45u8, 193u8, 45u8, 6u8, 46u8, 67u8, 46u8, 120u8,
+            46u8, 191u8, 46u8, 6u8, 47u8, 76u8, 47u8, 124u8, 47u8, 194u8, 47u8, 8u8, 48u8, 77u8,
+            48u8, 136u8, 48u8, 188u8, 48u8, 241u8, 48u8, 45u8, 49u8, 105u8, 49u8, 163u8, 49u8,
+            217u8, 49u8, 14u8, 50u8, 66u8, 50u8, 118u8, 50u8, 171u8, 50u8, 223u8, 50u8, 19u8, 51u8,
+            70u8, 51u8, 124u8, 51u8, 174u8, 51u8, 229u8, 51u8, 28u8, 52u8, 76u8, 52u8, 126u8, 52u8,
+            176u8, 52u8, 224u8, 52u8, 18u8, 53u8, 68u8, 53u8, 121u8, 53u8, 170u8, 53u8, 229u8,
+            53u8, 32u8, 54u8, 91u8, 54u8, 140u8, 54u8, 199u8, 54u8, 21u8, 55u8, 76u8, 55u8, 131u8,
+            55u8, 186u8, 55u8, 237u8, 55u8, 37u8, 56u8, 91u8, 56u8, 146u8, 56u8, 201u8, 56u8, 7u8,
+            57u8, 65u8, 57u8, 123u8, 57u8, 181u8, 57u8, 239u8, 57u8, 42u8, 58u8, 107u8, 58u8,
+            172u8, 58u8, 230u8, 58u8, 33u8, 59u8, 93u8, 59u8, 149u8, 59u8, 225u8, 59u8, 45u8, 60u8,
+            110u8, 60u8, 169u8, 60u8, 228u8, 60u8, 39u8, 61u8, 105u8, 61u8, 165u8, 61u8, 232u8,
+            61u8, 36u8, 62u8, 98u8, 62u8, 157u8, 62u8, 216u8, 62u8, 19u8, 63u8, 78u8, 63u8, 137u8,
+            63u8, 196u8, 63u8, 252u8, 63u8, 56u8, 64u8, 118u8, 64u8, 178u8, 64u8, 240u8, 64u8,
+            46u8, 65u8, 108u8, 65u8, 170u8, 65u8, 232u8, 65u8, 38u8, 66u8, 97u8, 66u8, 161u8, 66u8,
+            218u8, 66u8, 19u8, 67u8, 76u8, 67u8, 133u8, 67u8, 184u8, 67u8, 240u8, 67u8, 44u8, 68u8,
+            109u8, 68u8, 160u8, 68u8, 210u8, 68u8, 3u8, 69u8, 51u8, 69u8, 107u8, 69u8, 162u8, 69u8,
+            215u8, 69u8, 9u8, 70u8, 62u8, 70u8, 115u8, 70u8, 174u8, 70u8, 234u8, 70u8, 47u8, 71u8,
+            133u8, 71u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 4u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 30u8, 0u8, 0u8, 0u8,
+            97u8, 114u8, 101u8, 97u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 48u8, 48u8,
+            49u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+            101u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 21u8,
+            0u8, 0u8, 0u8, 97u8, 114u8, 101u8, 97u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8,
+            116u8, 48u8, 48u8, 49u8, 104u8, 101u8, 99u8, 116u8, 97u8, 114u8, 101u8, 1u8, 0u8, 0u8,
+            0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8,
+            11u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 97u8, 114u8, 101u8,
+            97u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 48u8, 48u8, 49u8, 115u8, 113u8,
+            117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8,
+            1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 11u8,
+            0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 31u8, 0u8, 0u8, 0u8, 97u8, 114u8, 101u8, 97u8,
+            100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 48u8, 48u8, 49u8, 115u8, 113u8, 117u8,
+            97u8, 114u8, 101u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8,
+            114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            4u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 24u8, 0u8, 0u8, 0u8,
+            97u8, 114u8, 101u8, 97u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 71u8, 66u8,
+            115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 1u8, 0u8,
+            0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8,
+            0u8, 11u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 17u8, 0u8, 0u8, 0u8, 97u8, 114u8, 101u8,
+            97u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 71u8, 66u8, 97u8, 99u8, 114u8,
+            101u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            4u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 24u8, 0u8, 0u8, 0u8,
+            97u8, 114u8, 101u8, 97u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 71u8, 66u8,
+            115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 102u8, 111u8, 111u8, 116u8, 1u8, 0u8,
+            0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8,
+            0u8, 11u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 24u8, 0u8, 0u8, 0u8, 97u8, 114u8, 101u8,
+            97u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 71u8, 66u8, 115u8, 113u8, 117u8,
+            97u8, 114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+            0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8,
+            13u8, 0u8, 0u8, 0u8, 24u8, 0u8, 0u8, 0u8, 97u8, 114u8, 101u8, 97u8, 100u8, 101u8,
+            102u8, 97u8, 117u8, 108u8, 116u8, 85u8, 83u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+            45u8, 109u8, 105u8, 108u8, 101u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8,
+            0u8, 17u8, 0u8, 0u8, 0u8, 97u8, 114u8, 101u8, 97u8, 100u8, 101u8, 102u8, 97u8, 117u8,
+            108u8, 116u8, 85u8, 83u8, 97u8, 99u8, 114u8, 101u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+            0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8,
+            13u8, 0u8, 0u8, 0u8, 24u8, 0u8, 0u8, 0u8, 97u8, 114u8, 101u8, 97u8, 100u8, 101u8,
+            102u8, 97u8, 117u8, 108u8, 116u8, 85u8, 83u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+            45u8, 102u8, 111u8, 111u8, 116u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8,
+            0u8, 24u8, 0u8, 0u8, 0u8, 97u8, 114u8, 101u8, 97u8, 100u8, 101u8, 102u8, 97u8, 117u8,
+            108u8, 116u8, 85u8, 83u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 105u8, 110u8,
+            99u8, 104u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 4u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 24u8, 0u8, 0u8, 0u8,
+            97u8, 114u8, 101u8, 97u8, 102u8, 108u8, 111u8, 111u8, 114u8, 48u8, 48u8, 49u8, 115u8,
+            113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 1u8, 0u8,
+            0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8,
+            0u8, 9u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 22u8, 0u8, 0u8, 0u8, 97u8, 114u8, 101u8,
+            97u8, 102u8, 108u8, 111u8, 111u8, 114u8, 67u8, 65u8, 115u8, 113u8, 117u8, 97u8, 114u8,
+            101u8, 45u8, 102u8, 111u8, 111u8, 116u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 11u8, 0u8,
+            0u8, 0u8, 22u8, 0u8, 0u8, 0u8, 97u8, 114u8, 101u8, 97u8, 102u8, 108u8, 111u8, 111u8,
+            114u8, 71u8, 66u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 102u8, 111u8, 111u8,
+            116u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            4u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 22u8, 0u8, 0u8, 0u8, 97u8,
+            114u8, 101u8, 97u8, 102u8, 108u8, 111u8, 111u8, 114u8, 77u8, 77u8, 115u8, 113u8, 117u8,
+            97u8, 114u8, 101u8, 45u8, 102u8, 111u8, 111u8, 116u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+            0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8,
+            0u8, 11u8, 0u8, 0u8, 0u8, 22u8, 0u8, 0u8, 0u8, 97u8, 114u8, 101u8, 97u8, 102u8, 108u8,
+            111u8, 111u8, 114u8, 85u8, 83u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 102u8,
+            111u8, 111u8, 116u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 31u8, 0u8,
+            0u8, 0u8, 97u8, 114u8, 101u8, 97u8, 103u8, 101u8, 111u8, 103u8, 114u8, 97u8, 112u8,
+            104u8, 48u8, 48u8, 49u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8, 107u8, 105u8,
+            108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+            0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8,
+            14u8, 0u8, 0u8, 0u8, 25u8, 0u8, 0u8, 0u8, 97u8, 114u8, 101u8, 97u8, 103u8, 101u8,
+            111u8, 103u8, 114u8, 97u8, 112u8, 104u8, 71u8, 66u8, 115u8, 113u8, 117u8, 97u8, 114u8,
+            101u8, 45u8, 109u8, 105u8, 108u8, 101u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 14u8, 0u8,
+            0u8, 0u8, 25u8, 0u8, 0u8, 0u8, 97u8, 114u8, 101u8, 97u8, 103u8, 101u8, 111u8, 103u8,
+            114u8, 97u8, 112u8, 104u8, 85u8, 83u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 45u8,
+            109u8, 105u8, 108u8, 101u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 18u8,
+            0u8, 0u8, 0u8, 97u8, 114u8, 101u8, 97u8, 108u8, 97u8, 110u8, 100u8, 48u8, 48u8, 49u8,
+            104u8, 101u8, 99u8, 116u8, 97u8, 114u8, 101u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+            5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 10u8,
+            0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 97u8, 114u8, 101u8, 97u8, 108u8, 97u8, 110u8,
+            100u8, 71u8, 66u8, 97u8, 99u8, 114u8, 101u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+            5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 10u8,
+            0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 97u8, 114u8, 101u8, 97u8, 108u8, 97u8, 110u8,
+            100u8, 85u8, 83u8, 97u8, 99u8, 114u8, 101u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+            5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 29u8,
+            0u8, 0u8, 0u8, 62u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8,
+            114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8,
+            108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 48u8, 48u8, 49u8, 109u8, 105u8, 108u8, 108u8,
+            105u8, 103u8, 114u8, 97u8, 109u8, 45u8, 111u8, 102u8, 103u8, 108u8, 117u8, 99u8, 111u8,
+            115u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 100u8, 101u8, 99u8, 105u8, 108u8, 105u8,
+            116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8,
+            0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8,
+            105u8, 111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8,
+            111u8, 115u8, 101u8, 65u8, 71u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8,
+            108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8,
+            0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8,
+            0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8,
+            110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8,
+            111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 65u8, 73u8,
+            109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8,
+            114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+            5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8,
+            0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8,
+            114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8,
+            108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 65u8, 79u8, 109u8, 105u8, 108u8, 108u8, 105u8,
+            109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8,
+            101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8,
+            0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8,
+            111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8,
+            115u8, 101u8, 65u8, 85u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8,
+            101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8, 0u8,
+            0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8,
+            0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8,
+            99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8, 111u8,
+            111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 66u8, 65u8, 109u8,
+            105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8,
+            45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8,
+            0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8,
+            97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8,
+            117u8, 99u8, 111u8, 115u8, 101u8, 66u8, 71u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8,
+            111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8,
+            114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8,
+            99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8,
+            98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8,
+            66u8, 72u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8,
+            101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+            0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8,
+            0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8,
+            116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8,
+            103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 66u8, 77u8, 109u8, 105u8, 108u8, 108u8,
+            105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8,
+            116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8,
+            0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8,
+            105u8, 111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8,
+            111u8, 115u8, 101u8, 66u8, 78u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8,
+            108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8,
+            0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8,
+            0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8,
+            110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8,
+            111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 66u8, 87u8,
+            109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8,
+            114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+            5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8,
+            0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8,
+            114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8,
+            108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 66u8, 89u8, 109u8, 105u8, 108u8, 108u8, 105u8,
+            109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8,
+            101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8,
+            0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8,
+            111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8,
+            115u8, 101u8, 67u8, 65u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8,
+            101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8, 0u8,
+            0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8,
+            0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8,
+            99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8, 111u8,
+            111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 67u8, 72u8, 109u8,
+            105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8,
+            45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8,
+            0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8,
+            97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8,
+            117u8, 99u8, 111u8, 115u8, 101u8, 67u8, 77u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8,
+            111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8,
+            114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8,
+            99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8,
+            98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8,
+            67u8, 78u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8,
+            101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+            0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8,
+            0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8,
+            116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8,
+            103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 67u8, 90u8, 109u8, 105u8, 108u8, 108u8,
+            105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8,
+            116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8,
+            0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8,
+            105u8, 111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8,
+            111u8, 115u8, 101u8, 68u8, 75u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8,
+            108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8,
+            0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8,
+            0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8,
+            110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8,
+            111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 68u8, 77u8,
+            109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8,
+            114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+            5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8,
+            0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8,
+            114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8,
+            108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 69u8, 69u8, 109u8, 105u8, 108u8, 108u8, 105u8,
+            109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8,
+            101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8,
+            0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8,
+            111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8,
+            115u8, 101u8, 70u8, 73u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8,
+            101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8, 0u8,
+            0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8,
+            0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8,
+            99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8, 111u8,
+            111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 70u8, 74u8, 109u8,
+            105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8,
+            45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8,
+            0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8,
+            97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8,
+            117u8, 99u8, 111u8, 115u8, 101u8, 71u8, 66u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8,
+            111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8,
+            114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8,
+            99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8,
+            98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8,
+            71u8, 68u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8,
+            101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+            0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8,
+            0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8,
+            116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8,
+            103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 72u8, 75u8, 109u8, 105u8, 108u8, 108u8,
+            105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8,
+            116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8,
+            0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8,
+            105u8, 111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8,
+            111u8, 115u8, 101u8, 72u8, 82u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8,
+            108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8,
+            0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8,
+            0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8,
+            110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8,
+            111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 72u8, 85u8,
+            109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8,
+            114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+            5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8,
+            0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8,
+            114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8,
+            108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 73u8, 69u8, 109u8, 105u8, 108u8, 108u8, 105u8,
+            109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8,
+            101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8,
+            0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8,
+            111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8,
+            115u8, 101u8, 73u8, 77u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8,
+            101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8, 0u8,
+            0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8,
+            0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8,
+            99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8, 111u8,
+            111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 73u8, 83u8, 109u8,
+            105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8,
+            45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8,
+            0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8,
+            97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8,
+            117u8, 99u8, 111u8, 115u8, 101u8, 75u8, 69u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8,
+            111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8,
+            114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8,
+            99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8,
+            98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8,
+            75u8, 78u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8,
+            101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+            0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8,
+            0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8,
+            116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8,
+            103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 75u8, 87u8, 109u8, 105u8, 108u8, 108u8,
+            105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8,
+            116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8,
+            0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8,
+            105u8, 111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8,
+            111u8, 115u8, 101u8, 75u8, 90u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8,
+            108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8,
+            0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8,
+            0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8,
+            110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8,
+            111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 76u8, 67u8,
+            109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8,
+            114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+            5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8,
+            0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8,
+            114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8,
+            108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 76u8, 73u8, 109u8, 105u8, 108u8, 108u8, 105u8,
+            109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8,
+            101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8,
+            0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8,
+            111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8,
+            115u8, 101u8, 76u8, 84u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8,
+            101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8, 0u8,
+            0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8,
+            0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8,
+            99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8, 111u8,
+            111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 76u8, 85u8, 109u8,
+            105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8,
+            45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8,
+            0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8,
+            97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8,
+            117u8, 99u8, 111u8, 115u8, 101u8, 76u8, 86u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8,
+            111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8,
+            114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8,
+            99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8,
+            98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8,
+            77u8, 69u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8,
+            101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+            0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8,
+            0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8,
+            116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8,
+            103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 77u8, 71u8, 109u8, 105u8, 108u8, 108u8,
+            105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8,
+            116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8,
+            0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8,
+            105u8, 111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8,
+            111u8, 115u8, 101u8, 77u8, 75u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8,
+            108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8,
+            0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8,
+            0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8,
+            110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8,
+            111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 77u8, 79u8,
+            109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8,
+            114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+            5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8,
+            0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8,
+            114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8,
+            108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 77u8, 83u8, 109u8, 105u8, 108u8, 108u8, 105u8,
+            109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8,
+            101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8,
+            0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8,
+            111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8,
+            115u8, 101u8, 77u8, 84u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8,
+            101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8, 0u8,
+            0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8,
+            0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8,
+            99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8, 111u8,
+            111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 77u8, 85u8, 109u8,
+            105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8,
+            45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8,
+            0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8,
+            97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8,
+            117u8, 99u8, 111u8, 115u8, 101u8, 77u8, 89u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8,
+            111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8,
+            114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8,
+            99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8,
+            98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8,
+            77u8, 90u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8,
+            101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+            0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8,
+            0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8,
+            116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8,
+            103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 78u8, 65u8, 109u8, 105u8, 108u8, 108u8,
+            105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8,
+            116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8,
+            0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8,
+            105u8, 111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8,
+            111u8, 115u8, 101u8, 78u8, 76u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8,
+            108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8,
+            0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8,
+            0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8,
+            110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8,
+            111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 78u8, 79u8,
+            109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8,
+            114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+            5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8,
+            0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8,
+            114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8,
+            108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 78u8, 90u8, 109u8, 105u8, 108u8, 108u8, 105u8,
+            109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8,
+            101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8,
+            0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8,
+            111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8,
+            115u8, 101u8, 79u8, 77u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8,
+            101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8, 0u8,
+            0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8,
+            0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8,
+            99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8, 111u8,
+            111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 80u8, 71u8, 109u8,
+            105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8,
+            45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8,
+            0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8,
+            97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8,
+            117u8, 99u8, 111u8, 115u8, 101u8, 82u8, 83u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8,
+            111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8,
+            114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8,
+            99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8,
+            98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8,
+            82u8, 85u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8,
+            101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+            0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8,
+            0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8,
+            116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8,
+            103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 83u8, 69u8, 109u8, 105u8, 108u8, 108u8,
+            105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8,
+            116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8,
+            0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8,
+            105u8, 111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8,
+            111u8, 115u8, 101u8, 83u8, 71u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8,
+            108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8,
+            0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8,
+            0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8,
+            110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8,
+            111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 83u8, 73u8,
+            109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8,
+            114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+            5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8,
+            0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8,
+            114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8,
+            108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 83u8, 75u8, 109u8, 105u8, 108u8, 108u8, 105u8,
+            109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8,
+            101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8,
+            0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8,
+            111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8,
+            115u8, 101u8, 84u8, 67u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8,
+            101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8, 0u8,
+            0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8,
+            0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8,
+            99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8, 111u8,
+            111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 84u8, 79u8, 109u8,
+            105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8,
+            45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8,
+            0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8,
+            97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8,
+            117u8, 99u8, 111u8, 115u8, 101u8, 85u8, 65u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8,
+            111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8,
+            114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8,
+            99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8,
+            98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8,
+            85u8, 71u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8,
+            101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+            0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8,
+            0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8,
+            116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8,
+            103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 86u8, 67u8, 109u8, 105u8, 108u8, 108u8,
+            105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8,
+            116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8,
+            0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8,
+            105u8, 111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8,
+            111u8, 115u8, 101u8, 86u8, 71u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8,
+            108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8,
+            0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8,
+            0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8,
+            110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8,
+            111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 86u8, 78u8,
+            109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8,
+            114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+            5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8,
+            0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8,
+            114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8,
+            108u8, 117u8, 99u8, 111u8, 115u8, 101u8, 86u8, 85u8, 109u8, 105u8, 108u8, 108u8, 105u8,
+            109u8, 111u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8,
+            101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 47u8, 0u8,
+            0u8, 0u8, 99u8, 111u8, 110u8, 99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8,
+            111u8, 110u8, 98u8, 108u8, 111u8, 111u8, 100u8, 45u8, 103u8, 108u8, 117u8, 99u8, 111u8,
+            115u8, 101u8, 90u8, 65u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 111u8, 108u8,
+            101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8, 0u8,
+            0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8,
+            0u8, 20u8, 0u8, 0u8, 0u8, 23u8, 0u8, 0u8, 0u8, 43u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8,
+            99u8, 101u8, 110u8, 116u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 100u8, 101u8,
+            102u8, 97u8, 117u8, 108u8, 116u8, 48u8, 48u8, 49u8, 105u8, 116u8, 101u8, 109u8, 45u8,
+            112u8, 101u8, 114u8, 45u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8,
+            101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 44u8, 0u8,
+            0u8, 0u8, 99u8, 111u8, 110u8, 115u8, 117u8, 109u8, 112u8, 116u8, 105u8, 111u8, 110u8,
+            100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 48u8, 48u8, 49u8, 108u8, 105u8, 116u8,
+            101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 49u8, 48u8, 48u8, 45u8, 107u8, 105u8,
+            108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+            0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 23u8, 0u8, 0u8, 0u8,
+            26u8, 0u8, 0u8, 0u8, 49u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 115u8, 117u8, 109u8,
+            112u8, 116u8, 105u8, 111u8, 110u8, 118u8, 101u8, 104u8, 105u8, 99u8, 108u8, 101u8,
+            45u8, 102u8, 117u8, 101u8, 108u8, 48u8, 48u8, 49u8, 108u8, 105u8, 116u8, 101u8, 114u8,
+            45u8, 112u8, 101u8, 114u8, 45u8, 49u8, 48u8, 48u8, 45u8, 107u8, 105u8, 108u8, 111u8,
+            109u8, 101u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 23u8, 0u8, 0u8, 0u8, 25u8, 0u8, 0u8,
+            0u8, 44u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 115u8, 117u8, 109u8, 112u8, 116u8, 105u8,
+            111u8, 110u8, 118u8, 101u8, 104u8, 105u8, 99u8, 108u8, 101u8, 45u8, 102u8, 117u8,
+            101u8, 108u8, 66u8, 82u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8,
+            45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8,
+            0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8,
+            23u8, 0u8, 0u8, 0u8, 25u8, 0u8, 0u8, 0u8, 49u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8,
+            115u8, 117u8, 109u8, 112u8, 116u8, 105u8, 111u8, 110u8, 118u8, 101u8, 104u8, 105u8,
+            99u8, 108u8, 101u8, 45u8, 102u8, 117u8, 101u8, 108u8, 67u8, 65u8, 109u8, 105u8, 108u8,
+            101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8,
+            105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+            0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 23u8, 0u8, 0u8,
+            0u8, 25u8, 0u8, 0u8, 0u8, 49u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 115u8, 117u8, 109u8,
+            112u8, 116u8, 105u8, 111u8, 110u8, 118u8, 101u8, 104u8, 105u8, 99u8, 108u8, 101u8,
+            45u8, 102u8, 117u8, 101u8, 108u8, 71u8, 66u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8,
+            101u8, 114u8, 45u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8,
+            101u8, 114u8, 105u8, 97u8, 108u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 23u8, 0u8, 0u8, 0u8, 25u8, 0u8, 0u8,
+            0u8, 44u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 115u8, 117u8, 109u8, 112u8, 116u8, 105u8,
+            111u8, 110u8, 118u8, 101u8, 104u8, 105u8, 99u8, 108u8, 101u8, 45u8, 102u8, 117u8,
+            101u8, 108u8, 73u8, 84u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8,
+            45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8,
+            0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8,
+            23u8, 0u8, 0u8, 0u8, 25u8, 0u8, 0u8, 0u8, 44u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8,
+            115u8, 117u8, 109u8, 112u8, 116u8, 105u8, 111u8, 110u8, 118u8, 101u8, 104u8, 105u8,
+            99u8, 108u8, 101u8, 45u8, 102u8, 117u8, 101u8, 108u8, 74u8, 80u8, 108u8, 105u8, 116u8,
+            101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+            101u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 23u8, 0u8, 0u8, 0u8, 25u8, 0u8, 0u8, 0u8,
+            44u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 115u8, 117u8, 109u8, 112u8, 116u8, 105u8,
+            111u8, 110u8, 118u8, 101u8, 104u8, 105u8, 99u8, 108u8, 101u8, 45u8, 102u8, 117u8,
+            101u8, 108u8, 75u8, 82u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8,
+            45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8,
+            0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8,
+            23u8, 0u8, 0u8, 0u8, 25u8, 0u8, 0u8, 0u8, 44u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8,
+            115u8, 117u8, 109u8, 112u8, 116u8, 105u8, 111u8, 110u8, 118u8, 101u8, 104u8, 105u8,
+            99u8, 108u8, 101u8, 45u8, 102u8, 117u8, 101u8, 108u8, 77u8, 88u8, 108u8, 105u8, 116u8,
+            101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+            101u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 23u8, 0u8, 0u8, 0u8, 25u8, 0u8, 0u8, 0u8,
+            44u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 115u8, 117u8, 109u8, 112u8, 116u8, 105u8,
+            111u8, 110u8, 118u8, 101u8, 104u8, 105u8, 99u8, 108u8, 101u8, 45u8, 102u8, 117u8,
+            101u8, 108u8, 77u8, 89u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8,
+            45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8,
+            0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8,
+            23u8, 0u8, 0u8, 0u8, 25u8, 0u8, 0u8, 0u8, 44u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8,
+            115u8, 117u8, 109u8, 112u8, 116u8, 105u8, 111u8, 110u8, 118u8, 101u8, 104u8, 105u8,
+            99u8, 108u8, 101u8, 45u8, 102u8, 117u8, 101u8, 108u8, 78u8, 76u8, 108u8, 105u8, 116u8,
+            101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+            101u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 23u8, 0u8, 0u8, 0u8, 25u8, 0u8, 0u8, 0u8,
+            44u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 115u8, 117u8, 109u8, 112u8, 116u8, 105u8,
+            111u8, 110u8, 118u8, 101u8, 104u8, 105u8, 99u8, 108u8, 101u8, 45u8, 102u8, 117u8,
+            101u8, 108u8, 84u8, 72u8, 108u8, 105u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8,
+            45u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8,
+            0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8,
+            23u8, 0u8, 0u8, 0u8, 25u8, 0u8, 0u8, 0u8, 44u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8,
+            115u8, 117u8, 109u8, 112u8, 116u8, 105u8, 111u8, 110u8, 118u8, 101u8, 104u8, 105u8,
+            99u8, 108u8, 101u8, 45u8, 102u8, 117u8, 101u8, 108u8, 84u8, 82u8, 108u8, 105u8, 116u8,
+            101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+            101u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 23u8, 0u8, 0u8, 0u8, 25u8, 0u8, 0u8, 0u8,
+            40u8, 0u8, 0u8, 0u8, 99u8, 111u8, 110u8, 115u8, 117u8, 109u8, 112u8, 116u8, 105u8,
+            111u8, 110u8, 118u8, 101u8, 104u8, 105u8, 99u8, 108u8, 101u8, 45u8, 102u8, 117u8,
+            101u8, 108u8, 85u8, 83u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8,
+            103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 18u8, 0u8,
+            0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 100u8, 117u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8,
+            100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 48u8, 48u8, 49u8, 100u8, 97u8, 121u8,
+            1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8,
+            0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 22u8, 0u8, 0u8, 0u8, 100u8,
+            117u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 100u8, 101u8, 102u8, 97u8, 117u8,
+            108u8, 116u8, 48u8, 48u8, 49u8, 104u8, 111u8, 117u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8,
+            0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 15u8, 0u8,
+            0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 24u8, 0u8, 0u8, 0u8, 100u8, 117u8, 114u8, 97u8, 116u8,
+            105u8, 111u8, 110u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 48u8, 48u8, 49u8,
+            109u8, 105u8, 110u8, 117u8, 116u8, 101u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 18u8, 0u8,
+            0u8, 0u8, 24u8, 0u8, 0u8, 0u8, 100u8, 117u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8,
+            100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 48u8, 48u8, 49u8, 115u8, 101u8, 99u8,
+            111u8, 110u8, 100u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 29u8, 0u8,
+            0u8, 0u8, 100u8, 117u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 100u8, 101u8, 102u8,
+            97u8, 117u8, 108u8, 116u8, 48u8, 48u8, 49u8, 109u8, 105u8, 108u8, 108u8, 105u8, 115u8,
+            101u8, 99u8, 111u8, 110u8, 100u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8,
+            0u8, 29u8, 0u8, 0u8, 0u8, 100u8, 117u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 100u8,
+            101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 48u8, 48u8, 49u8, 109u8, 105u8, 99u8, 114u8,
+            111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+            5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 18u8,
+            0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 100u8, 117u8, 114u8, 97u8, 116u8, 105u8, 111u8,
+            110u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 48u8, 48u8, 49u8, 110u8, 97u8,
+            110u8, 111u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+            0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8,
+            0u8, 16u8, 0u8, 0u8, 0u8, 33u8, 0u8, 0u8, 0u8, 100u8, 117u8, 114u8, 97u8, 116u8, 105u8,
+            111u8, 110u8, 109u8, 101u8, 100u8, 105u8, 97u8, 48u8, 48u8, 49u8, 109u8, 105u8, 110u8,
+            117u8, 116u8, 101u8, 45u8, 97u8, 110u8, 100u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8,
+            100u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            8u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 22u8, 0u8, 0u8, 0u8,
+            100u8, 117u8, 114u8, 97u8, 116u8, 105u8, 111u8, 110u8, 109u8, 101u8, 100u8, 105u8,
+            97u8, 48u8, 48u8, 49u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 1u8, 0u8, 0u8, 0u8,
+            1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 13u8,
+            0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 29u8, 0u8, 0u8, 0u8, 101u8, 110u8, 101u8, 114u8,
+            103u8, 121u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 48u8, 48u8, 49u8, 107u8,
+            105u8, 108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 45u8, 104u8, 111u8, 117u8, 114u8, 1u8,
+            0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8,
+            0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 24u8, 0u8, 0u8, 0u8, 101u8, 110u8,
+            101u8, 114u8, 103u8, 121u8, 102u8, 111u8, 111u8, 100u8, 48u8, 48u8, 49u8, 107u8, 105u8,
+            108u8, 111u8, 99u8, 97u8, 108u8, 111u8, 114u8, 105u8, 101u8, 1u8, 0u8, 0u8, 0u8, 1u8,
+            0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 10u8, 0u8,
+            0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 23u8, 0u8, 0u8, 0u8, 101u8, 110u8, 101u8, 114u8, 103u8,
+            121u8, 102u8, 111u8, 111u8, 100u8, 85u8, 83u8, 102u8, 111u8, 111u8, 100u8, 99u8, 97u8,
+            108u8, 111u8, 114u8, 105u8, 101u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8,
+            0u8, 25u8, 0u8, 0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 100u8, 101u8,
+            102u8, 97u8, 117u8, 108u8, 116u8, 48u8, 48u8, 49u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+            101u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 21u8,
+            0u8, 0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 100u8, 101u8, 102u8, 97u8,
+            117u8, 108u8, 116u8, 48u8, 48u8, 49u8, 109u8, 101u8, 116u8, 101u8, 114u8, 1u8, 0u8,
+            0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8,
+            0u8, 13u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 108u8, 101u8,
+            110u8, 103u8, 116u8, 104u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 48u8, 48u8,
+            49u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 1u8, 0u8,
+            0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8,
+            0u8, 13u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 108u8, 101u8,
+            110u8, 103u8, 116u8, 104u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 71u8, 66u8,
+            109u8, 105u8, 108u8, 101u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 19u8,
+            0u8, 0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 100u8, 101u8, 102u8, 97u8,
+            117u8, 108u8, 116u8, 71u8, 66u8, 102u8, 111u8, 111u8, 116u8, 1u8, 0u8, 0u8, 0u8, 1u8,
+            0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 13u8, 0u8,
+            0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8,
+            104u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 71u8, 66u8, 105u8, 110u8, 99u8,
+            104u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            6u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8,
+            108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8,
+            116u8, 85u8, 83u8, 109u8, 105u8, 108u8, 101u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+            5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 15u8,
+            0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 100u8,
+            101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 85u8, 83u8, 102u8, 111u8, 111u8, 116u8, 1u8,
+            0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8,
+            0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 108u8, 101u8,
+            110u8, 103u8, 116u8, 104u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 85u8, 83u8,
+            105u8, 110u8, 99u8, 104u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 31u8,
+            0u8, 0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 102u8, 111u8, 99u8, 97u8,
+            108u8, 45u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 48u8, 48u8, 49u8, 109u8, 105u8,
+            108u8, 108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+            0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8,
+            0u8, 15u8, 0u8, 0u8, 0u8, 25u8, 0u8, 0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8,
+            104u8, 112u8, 101u8, 114u8, 115u8, 111u8, 110u8, 48u8, 48u8, 49u8, 99u8, 101u8, 110u8,
+            116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+            0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8,
+            14u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8,
+            112u8, 101u8, 114u8, 115u8, 111u8, 110u8, 67u8, 65u8, 105u8, 110u8, 99u8, 104u8, 1u8,
+            0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8,
+            0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 108u8, 101u8,
+            110u8, 103u8, 116u8, 104u8, 112u8, 101u8, 114u8, 115u8, 111u8, 110u8, 71u8, 66u8,
+            105u8, 110u8, 99u8, 104u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 18u8,
+            0u8, 0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 112u8, 101u8, 114u8, 115u8,
+            111u8, 110u8, 73u8, 78u8, 105u8, 110u8, 99u8, 104u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+            0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8,
+            14u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8,
+            112u8, 101u8, 114u8, 115u8, 111u8, 110u8, 85u8, 83u8, 105u8, 110u8, 99u8, 104u8, 1u8,
+            0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8,
+            0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 22u8, 0u8, 0u8, 0u8, 32u8, 0u8, 0u8, 0u8, 108u8, 101u8,
+            110u8, 103u8, 116u8, 104u8, 112u8, 101u8, 114u8, 115u8, 111u8, 110u8, 45u8, 104u8,
+            101u8, 105u8, 103u8, 104u8, 116u8, 48u8, 48u8, 49u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+            109u8, 101u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8,
+            0u8, 41u8, 0u8, 0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 112u8, 101u8,
+            114u8, 115u8, 111u8, 110u8, 45u8, 104u8, 101u8, 105u8, 103u8, 104u8, 116u8, 65u8, 84u8,
+            109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 97u8, 110u8, 100u8, 45u8, 99u8, 101u8, 110u8,
+            116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+            0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8,
+            21u8, 0u8, 0u8, 0u8, 41u8, 0u8, 0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8,
+            112u8, 101u8, 114u8, 115u8, 111u8, 110u8, 45u8, 104u8, 101u8, 105u8, 103u8, 104u8,
+            116u8, 66u8, 69u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 97u8, 110u8, 100u8, 45u8,
+            99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 3u8, 0u8, 0u8,
+            0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8,
+            19u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 34u8, 0u8, 0u8, 0u8, 108u8, 101u8, 110u8,
+            103u8, 116u8, 104u8, 112u8, 101u8, 114u8, 115u8, 111u8, 110u8, 45u8, 104u8, 101u8,
+            105u8, 103u8, 104u8, 116u8, 67u8, 65u8, 102u8, 111u8, 111u8, 116u8, 45u8, 97u8, 110u8,
+            100u8, 45u8, 105u8, 110u8, 99u8, 104u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 21u8, 0u8,
+            0u8, 0u8, 25u8, 0u8, 0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 112u8, 101u8,
+            114u8, 115u8, 111u8, 110u8, 45u8, 104u8, 101u8, 105u8, 103u8, 104u8, 116u8, 67u8, 65u8,
+            105u8, 110u8, 99u8, 104u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 41u8,
+            0u8, 0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 112u8, 101u8, 114u8, 115u8,
+            111u8, 110u8, 45u8, 104u8, 101u8, 105u8, 103u8, 104u8, 116u8, 68u8, 90u8, 109u8, 101u8,
+            116u8, 101u8, 114u8, 45u8, 97u8, 110u8, 100u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+            109u8, 101u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8,
+            0u8, 41u8, 0u8, 0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 112u8, 101u8,
+            114u8, 115u8, 111u8, 110u8, 45u8, 104u8, 101u8, 105u8, 103u8, 104u8, 116u8, 69u8, 71u8,
+            109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 97u8, 110u8, 100u8, 45u8, 99u8, 101u8, 110u8,
+            116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+            0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8,
+            21u8, 0u8, 0u8, 0u8, 41u8, 0u8, 0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8,
+            112u8, 101u8, 114u8, 115u8, 111u8, 110u8, 45u8, 104u8, 101u8, 105u8, 103u8, 104u8,
+            116u8, 69u8, 83u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 97u8, 110u8, 100u8, 45u8,
+            99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8,
+            0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8,
+            19u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 41u8, 0u8, 0u8, 0u8, 108u8, 101u8, 110u8,
+            103u8, 116u8, 104u8, 112u8, 101u8, 114u8, 115u8, 111u8, 110u8, 45u8, 104u8, 101u8,
+            105u8, 103u8, 104u8, 116u8, 70u8, 82u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 97u8,
+            110u8, 100u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8,
+            114u8, 3u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            6u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 34u8, 0u8, 0u8, 0u8,
+            108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 112u8, 101u8, 114u8, 115u8, 111u8, 110u8,
+            45u8, 104u8, 101u8, 105u8, 103u8, 104u8, 116u8, 71u8, 66u8, 102u8, 111u8, 111u8, 116u8,
+            45u8, 97u8, 110u8, 100u8, 45u8, 105u8, 110u8, 99u8, 104u8, 1u8, 0u8, 0u8, 0u8, 1u8,
+            0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 19u8, 0u8,
+            0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 25u8, 0u8, 0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8,
+            104u8, 112u8, 101u8, 114u8, 115u8, 111u8, 110u8, 45u8, 104u8, 101u8, 105u8, 103u8,
+            104u8, 116u8, 71u8, 66u8, 105u8, 110u8, 99u8, 104u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+            0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8,
+            21u8, 0u8, 0u8, 0u8, 41u8, 0u8, 0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8,
+            112u8, 101u8, 114u8, 115u8, 111u8, 110u8, 45u8, 104u8, 101u8, 105u8, 103u8, 104u8,
+            116u8, 72u8, 75u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 97u8, 110u8, 100u8, 45u8,
+            99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8,
+            0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8,
+            19u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 41u8, 0u8, 0u8, 0u8, 108u8, 101u8, 110u8,
+            103u8, 116u8, 104u8, 112u8, 101u8, 114u8, 115u8, 111u8, 110u8, 45u8, 104u8, 101u8,
+            105u8, 103u8, 104u8, 116u8, 73u8, 68u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 97u8,
+            110u8, 100u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8,
+            114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            6u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 41u8, 0u8, 0u8, 0u8,
+            108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 112u8, 101u8, 114u8, 115u8, 111u8, 110u8,
+            45u8, 104u8, 101u8, 105u8, 103u8, 104u8, 116u8, 73u8, 76u8, 109u8, 101u8, 116u8, 101u8,
+            114u8, 45u8, 97u8, 110u8, 100u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8,
+            116u8, 101u8, 114u8, 3u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 34u8, 0u8,
+            0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 112u8, 101u8, 114u8, 115u8, 111u8,
+            110u8, 45u8, 104u8, 101u8, 105u8, 103u8, 104u8, 116u8, 73u8, 78u8, 102u8, 111u8, 111u8,
+            116u8, 45u8, 97u8, 110u8, 100u8, 45u8, 105u8, 110u8, 99u8, 104u8, 1u8, 0u8, 0u8, 0u8,
+            1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 19u8,
+            0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 25u8, 0u8, 0u8, 0u8, 108u8, 101u8, 110u8, 103u8,
+            116u8, 104u8, 112u8, 101u8, 114u8, 115u8, 111u8, 110u8, 45u8, 104u8, 101u8, 105u8,
+            103u8, 104u8, 116u8, 73u8, 78u8, 105u8, 110u8, 99u8, 104u8, 1u8, 0u8, 0u8, 0u8, 1u8,
+            0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 19u8, 0u8,
+            0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 41u8, 0u8, 0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8,
+            104u8, 112u8, 101u8, 114u8, 115u8, 111u8, 110u8, 45u8, 104u8, 101u8, 105u8, 103u8,
+            104u8, 116u8, 73u8, 84u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 97u8, 110u8, 100u8,
+            45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 1u8, 0u8,
+            0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8,
+            0u8, 19u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 41u8, 0u8, 0u8, 0u8, 108u8, 101u8,
+            110u8, 103u8, 116u8, 104u8, 112u8, 101u8, 114u8, 115u8, 111u8, 110u8, 45u8, 104u8,
+            101u8, 105u8, 103u8, 104u8, 116u8, 74u8, 79u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+            97u8, 110u8, 100u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8,
+            114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            6u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 41u8, 0u8, 0u8, 0u8,
+            108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 112u8, 101u8, 114u8, 115u8, 111u8, 110u8,
+            45u8, 104u8, 101u8, 105u8, 103u8, 104u8, 116u8, 77u8, 89u8, 109u8, 101u8, 116u8, 101u8,
+            114u8, 45u8, 97u8, 110u8, 100u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8,
+            116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 41u8, 0u8,
+            0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 112u8, 101u8, 114u8, 115u8, 111u8,
+            110u8, 45u8, 104u8, 101u8, 105u8, 103u8, 104u8, 116u8, 83u8, 65u8, 109u8, 101u8, 116u8,
+            101u8, 114u8, 45u8, 97u8, 110u8, 100u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8,
+            101u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 41u8,
+            0u8, 0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 112u8, 101u8, 114u8, 115u8,
+            111u8, 110u8, 45u8, 104u8, 101u8, 105u8, 103u8, 104u8, 116u8, 83u8, 69u8, 109u8, 101u8,
+            116u8, 101u8, 114u8, 45u8, 97u8, 110u8, 100u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8,
+            109u8, 101u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8,
+            0u8, 41u8, 0u8, 0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 112u8, 101u8,
+            114u8, 115u8, 111u8, 110u8, 45u8, 104u8, 101u8, 105u8, 103u8, 104u8, 116u8, 84u8, 82u8,
+            109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 97u8, 110u8, 100u8, 45u8, 99u8, 101u8, 110u8,
+            116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 3u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+            0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8,
+            21u8, 0u8, 0u8, 0u8, 34u8, 0u8, 0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8,
+            112u8, 101u8, 114u8, 115u8, 111u8, 110u8, 45u8, 104u8, 101u8, 105u8, 103u8, 104u8,
+            116u8, 85u8, 83u8, 102u8, 111u8, 111u8, 116u8, 45u8, 97u8, 110u8, 100u8, 45u8, 105u8,
+            110u8, 99u8, 104u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 25u8, 0u8,
+            0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 112u8, 101u8, 114u8, 115u8, 111u8,
+            110u8, 45u8, 104u8, 101u8, 105u8, 103u8, 104u8, 116u8, 85u8, 83u8, 105u8, 110u8, 99u8,
+            104u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            6u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 41u8, 0u8, 0u8, 0u8,
+            108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 112u8, 101u8, 114u8, 115u8, 111u8, 110u8,
+            45u8, 104u8, 101u8, 105u8, 103u8, 104u8, 116u8, 86u8, 78u8, 109u8, 101u8, 116u8, 101u8,
+            114u8, 45u8, 97u8, 110u8, 100u8, 45u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8,
+            116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 17u8, 0u8, 0u8, 0u8, 27u8, 0u8,
+            0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 114u8, 97u8, 105u8, 110u8, 102u8,
+            97u8, 108u8, 108u8, 48u8, 48u8, 49u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 101u8,
+            116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 26u8, 0u8,
+            0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 114u8, 97u8, 105u8, 110u8, 102u8,
+            97u8, 108u8, 108u8, 66u8, 82u8, 99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8,
+            101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8,
+            0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 114u8, 97u8, 105u8, 110u8, 102u8, 97u8,
+            108u8, 108u8, 85u8, 83u8, 105u8, 110u8, 99u8, 104u8, 9u8, 0u8, 0u8, 0u8, 10u8, 0u8,
+            0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8,
+            0u8, 13u8, 0u8, 0u8, 0u8, 22u8, 0u8, 0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8,
+            104u8, 114u8, 111u8, 97u8, 100u8, 48u8, 48u8, 49u8, 107u8, 105u8, 108u8, 111u8, 109u8,
+            101u8, 116u8, 101u8, 114u8, 44u8, 1u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8,
+            18u8, 0u8, 0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 114u8, 111u8, 97u8,
+            100u8, 48u8, 48u8, 49u8, 109u8, 101u8, 116u8, 101u8, 114u8, 112u8, 114u8, 101u8, 99u8,
+            105u8, 115u8, 105u8, 111u8, 110u8, 45u8, 105u8, 110u8, 99u8, 114u8, 101u8, 109u8,
+            101u8, 110u8, 116u8, 47u8, 53u8, 48u8, 10u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 13u8, 0u8,
+            0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 114u8, 111u8,
+            97u8, 100u8, 48u8, 48u8, 49u8, 109u8, 101u8, 116u8, 101u8, 114u8, 112u8, 114u8, 101u8,
+            99u8, 105u8, 115u8, 105u8, 111u8, 110u8, 45u8, 105u8, 110u8, 99u8, 114u8, 101u8, 109u8,
+            101u8, 110u8, 116u8, 47u8, 49u8, 48u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 13u8, 0u8,
+            0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 114u8, 111u8,
+            97u8, 100u8, 48u8, 48u8, 49u8, 109u8, 101u8, 116u8, 101u8, 114u8, 112u8, 114u8, 101u8,
+            99u8, 105u8, 115u8, 105u8, 111u8, 110u8, 45u8, 105u8, 110u8, 99u8, 114u8, 101u8, 109u8,
+            101u8, 110u8, 116u8, 47u8, 49u8, 1u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8,
+            16u8, 0u8, 0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 114u8, 111u8, 97u8,
+            100u8, 71u8, 66u8, 109u8, 105u8, 108u8, 101u8, 100u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+            0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8,
+            12u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8,
+            114u8, 111u8, 97u8, 100u8, 71u8, 66u8, 121u8, 97u8, 114u8, 100u8, 112u8, 114u8, 101u8,
+            99u8, 105u8, 115u8, 105u8, 111u8, 110u8, 45u8, 105u8, 110u8, 99u8, 114u8, 101u8, 109u8,
+            101u8, 110u8, 116u8, 47u8, 53u8, 48u8, 10u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 12u8, 0u8,
+            0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 114u8, 111u8,
+            97u8, 100u8, 71u8, 66u8, 121u8, 97u8, 114u8, 100u8, 112u8, 114u8, 101u8, 99u8, 105u8,
+            115u8, 105u8, 111u8, 110u8, 45u8, 105u8, 110u8, 99u8, 114u8, 101u8, 109u8, 101u8,
+            110u8, 116u8, 47u8, 49u8, 48u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8,
+            16u8, 0u8, 0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 114u8, 111u8, 97u8,
+            100u8, 71u8, 66u8, 121u8, 97u8, 114u8, 100u8, 112u8, 114u8, 101u8, 99u8, 105u8, 115u8,
+            105u8, 111u8, 110u8, 45u8, 105u8, 110u8, 99u8, 114u8, 101u8, 109u8, 101u8, 110u8,
+            116u8, 47u8, 49u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 29u8, 0u8,
+            0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 114u8, 111u8, 97u8, 100u8, 83u8,
+            69u8, 109u8, 105u8, 108u8, 101u8, 45u8, 115u8, 99u8, 97u8, 110u8, 100u8, 105u8, 110u8,
+            97u8, 118u8, 105u8, 97u8, 110u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8,
+            21u8, 0u8, 0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 114u8, 111u8, 97u8,
+            100u8, 83u8, 69u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 44u8,
+            1u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8,
+            0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 17u8, 0u8, 0u8, 0u8, 108u8, 101u8,
+            110u8, 103u8, 116u8, 104u8, 114u8, 111u8, 97u8, 100u8, 83u8, 69u8, 109u8, 101u8, 116u8,
+            101u8, 114u8, 112u8, 114u8, 101u8, 99u8, 105u8, 115u8, 105u8, 111u8, 110u8, 45u8,
+            105u8, 110u8, 99u8, 114u8, 101u8, 109u8, 101u8, 110u8, 116u8, 47u8, 53u8, 48u8, 10u8,
+            0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8,
+            0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 17u8, 0u8, 0u8, 0u8, 108u8, 101u8,
+            110u8, 103u8, 116u8, 104u8, 114u8, 111u8, 97u8, 100u8, 83u8, 69u8, 109u8, 101u8, 116u8,
+            101u8, 114u8, 112u8, 114u8, 101u8, 99u8, 105u8, 115u8, 105u8, 111u8, 110u8, 45u8,
+            105u8, 110u8, 99u8, 114u8, 101u8, 109u8, 101u8, 110u8, 116u8, 47u8, 49u8, 48u8, 1u8,
+            0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8,
+            0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 17u8, 0u8, 0u8, 0u8, 108u8, 101u8,
+            110u8, 103u8, 116u8, 104u8, 114u8, 111u8, 97u8, 100u8, 83u8, 69u8, 109u8, 101u8, 116u8,
+            101u8, 114u8, 112u8, 114u8, 101u8, 99u8, 105u8, 115u8, 105u8, 111u8, 110u8, 45u8,
+            105u8, 110u8, 99u8, 114u8, 101u8, 109u8, 101u8, 110u8, 116u8, 47u8, 49u8, 1u8, 0u8,
+            0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8,
+            0u8, 10u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 108u8, 101u8,
+            110u8, 103u8, 116u8, 104u8, 114u8, 111u8, 97u8, 100u8, 85u8, 83u8, 109u8, 105u8, 108u8,
+            101u8, 100u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 6u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8,
+            108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 114u8, 111u8, 97u8, 100u8, 85u8, 83u8, 102u8,
+            111u8, 111u8, 116u8, 112u8, 114u8, 101u8, 99u8, 105u8, 115u8, 105u8, 111u8, 110u8,
+            45u8, 105u8, 110u8, 99u8, 114u8, 101u8, 109u8, 101u8, 110u8, 116u8, 47u8, 53u8, 48u8,
+            10u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8,
+            0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 108u8,
+            101u8, 110u8, 103u8, 116u8, 104u8, 114u8, 111u8, 97u8, 100u8, 85u8, 83u8, 102u8, 111u8,
+            111u8, 116u8, 112u8, 114u8, 101u8, 99u8, 105u8, 115u8, 105u8, 111u8, 110u8, 45u8,
+            105u8, 110u8, 99u8, 114u8, 101u8, 109u8, 101u8, 110u8, 116u8, 47u8, 49u8, 48u8, 1u8,
+            0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8,
+            0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 108u8, 101u8,
+            110u8, 103u8, 116u8, 104u8, 114u8, 111u8, 97u8, 100u8, 85u8, 83u8, 102u8, 111u8, 111u8,
+            116u8, 112u8, 114u8, 101u8, 99u8, 105u8, 115u8, 105u8, 111u8, 110u8, 45u8, 105u8,
+            110u8, 99u8, 114u8, 101u8, 109u8, 101u8, 110u8, 116u8, 47u8, 49u8, 1u8, 0u8, 0u8, 0u8,
+            1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 14u8,
+            0u8, 0u8, 0u8, 17u8, 0u8, 0u8, 0u8, 27u8, 0u8, 0u8, 0u8, 108u8, 101u8, 110u8, 103u8,
+            116u8, 104u8, 115u8, 110u8, 111u8, 119u8, 102u8, 97u8, 108u8, 108u8, 48u8, 48u8, 49u8,
+            99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8,
+            0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8,
+            14u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8, 0u8, 108u8, 101u8, 110u8,
+            103u8, 116u8, 104u8, 115u8, 110u8, 111u8, 119u8, 102u8, 97u8, 108u8, 108u8, 85u8, 83u8,
+            105u8, 110u8, 99u8, 104u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 21u8,
+            0u8, 0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 118u8, 101u8, 104u8, 105u8,
+            99u8, 108u8, 101u8, 48u8, 48u8, 49u8, 109u8, 101u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8,
+            0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8,
+            13u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 108u8, 101u8, 110u8,
+            103u8, 116u8, 104u8, 118u8, 101u8, 104u8, 105u8, 99u8, 108u8, 101u8, 71u8, 66u8, 102u8,
+            111u8, 111u8, 116u8, 45u8, 97u8, 110u8, 100u8, 45u8, 105u8, 110u8, 99u8, 104u8, 1u8,
+            0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8,
+            0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 108u8, 101u8,
+            110u8, 103u8, 116u8, 104u8, 118u8, 101u8, 104u8, 105u8, 99u8, 108u8, 101u8, 85u8, 83u8,
+            102u8, 111u8, 111u8, 116u8, 45u8, 97u8, 110u8, 100u8, 45u8, 105u8, 110u8, 99u8, 104u8,
+            1u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8,
+            0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 17u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 108u8,
+            101u8, 110u8, 103u8, 116u8, 104u8, 118u8, 105u8, 115u8, 105u8, 98u8, 108u8, 116u8,
+            121u8, 48u8, 48u8, 49u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8, 114u8,
+            1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8,
+            0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 17u8, 0u8, 0u8, 0u8, 22u8, 0u8, 0u8, 0u8, 108u8,
+            101u8, 110u8, 103u8, 116u8, 104u8, 118u8, 105u8, 115u8, 105u8, 98u8, 108u8, 116u8,
+            121u8, 48u8, 48u8, 49u8, 109u8, 101u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8,
+            0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 14u8, 0u8,
+            0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8,
+            104u8, 118u8, 105u8, 115u8, 105u8, 98u8, 108u8, 116u8, 121u8, 68u8, 69u8, 109u8, 101u8,
+            116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 20u8, 0u8,
+            0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 118u8, 105u8, 115u8, 105u8, 98u8,
+            108u8, 116u8, 121u8, 71u8, 66u8, 109u8, 105u8, 108u8, 101u8, 1u8, 0u8, 0u8, 0u8, 1u8,
+            0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 14u8, 0u8,
+            0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8,
+            104u8, 118u8, 105u8, 115u8, 105u8, 98u8, 108u8, 116u8, 121u8, 71u8, 66u8, 102u8, 111u8,
+            111u8, 116u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8,
+            0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 118u8, 105u8, 115u8, 105u8, 98u8, 108u8,
+            116u8, 121u8, 78u8, 76u8, 109u8, 101u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8,
+            0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 14u8, 0u8,
+            0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8, 0u8, 108u8, 101u8, 110u8, 103u8, 116u8,
+            104u8, 118u8, 105u8, 115u8, 105u8, 98u8, 108u8, 116u8, 121u8, 85u8, 83u8, 109u8, 105u8,
+            108u8, 101u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8,
+            0u8, 108u8, 101u8, 110u8, 103u8, 116u8, 104u8, 118u8, 105u8, 115u8, 105u8, 98u8, 108u8,
+            116u8, 121u8, 85u8, 83u8, 102u8, 111u8, 111u8, 116u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+            0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8,
+            0u8, 14u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 109u8, 97u8, 115u8, 115u8, 100u8, 101u8,
+            102u8, 97u8, 117u8, 108u8, 116u8, 48u8, 48u8, 49u8, 116u8, 111u8, 110u8, 110u8, 101u8,
+            1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8,
+            0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 22u8, 0u8, 0u8, 0u8, 109u8,
+            97u8, 115u8, 115u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 48u8, 48u8, 49u8,
+            107u8, 105u8, 108u8, 111u8, 103u8, 114u8, 97u8, 109u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+            0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8,
+            0u8, 14u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 109u8, 97u8, 115u8, 115u8, 100u8, 101u8,
+            102u8, 97u8, 117u8, 108u8, 116u8, 48u8, 48u8, 49u8, 103u8, 114u8, 97u8, 109u8, 1u8,
+            0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8,
+            0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 23u8, 0u8, 0u8, 0u8, 109u8, 97u8,
+            115u8, 115u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 48u8, 48u8, 49u8, 109u8,
+            105u8, 108u8, 108u8, 105u8, 103u8, 114u8, 97u8, 109u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+            0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8,
+            0u8, 14u8, 0u8, 0u8, 0u8, 23u8, 0u8, 0u8, 0u8, 109u8, 97u8, 115u8, 115u8, 100u8, 101u8,
+            102u8, 97u8, 117u8, 108u8, 116u8, 48u8, 48u8, 49u8, 109u8, 105u8, 99u8, 114u8, 111u8,
+            103u8, 114u8, 97u8, 109u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 16u8,
+            0u8, 0u8, 0u8, 109u8, 97u8, 115u8, 115u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8,
+            116u8, 71u8, 66u8, 116u8, 111u8, 110u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 13u8, 0u8,
+            0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 109u8, 97u8, 115u8, 115u8, 100u8, 101u8, 102u8, 97u8,
+            117u8, 108u8, 116u8, 71u8, 66u8, 112u8, 111u8, 117u8, 110u8, 100u8, 1u8, 0u8, 0u8, 0u8,
+            1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 11u8,
+            0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 109u8, 97u8, 115u8, 115u8,
+            100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 71u8, 66u8, 111u8, 117u8, 110u8, 99u8,
+            101u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            4u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8,
+            109u8, 97u8, 115u8, 115u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 85u8, 83u8,
+            116u8, 111u8, 110u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 18u8, 0u8,
+            0u8, 0u8, 109u8, 97u8, 115u8, 115u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8,
+            85u8, 83u8, 112u8, 111u8, 117u8, 110u8, 100u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+            5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 13u8,
+            0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 109u8, 97u8, 115u8, 115u8, 100u8, 101u8, 102u8,
+            97u8, 117u8, 108u8, 116u8, 85u8, 83u8, 111u8, 117u8, 110u8, 99u8, 101u8, 1u8, 0u8, 0u8,
+            0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8,
+            10u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 109u8, 97u8, 115u8,
+            115u8, 112u8, 101u8, 114u8, 115u8, 111u8, 110u8, 48u8, 48u8, 49u8, 107u8, 105u8, 108u8,
+            111u8, 103u8, 114u8, 97u8, 109u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8,
+            0u8, 17u8, 0u8, 0u8, 0u8, 109u8, 97u8, 115u8, 115u8, 112u8, 101u8, 114u8, 115u8, 111u8,
+            110u8, 48u8, 48u8, 49u8, 103u8, 114u8, 97u8, 109u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+            0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8,
+            12u8, 0u8, 0u8, 0u8, 27u8, 0u8, 0u8, 0u8, 109u8, 97u8, 115u8, 115u8, 112u8, 101u8,
+            114u8, 115u8, 111u8, 110u8, 71u8, 66u8, 115u8, 116u8, 111u8, 110u8, 101u8, 45u8, 97u8,
+            110u8, 100u8, 45u8, 112u8, 111u8, 117u8, 110u8, 100u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+            0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8,
+            0u8, 12u8, 0u8, 0u8, 0u8, 27u8, 0u8, 0u8, 0u8, 109u8, 97u8, 115u8, 115u8, 112u8, 101u8,
+            114u8, 115u8, 111u8, 110u8, 71u8, 66u8, 112u8, 111u8, 117u8, 110u8, 100u8, 45u8, 97u8,
+            110u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+            0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8,
+            0u8, 12u8, 0u8, 0u8, 0u8, 27u8, 0u8, 0u8, 0u8, 109u8, 97u8, 115u8, 115u8, 112u8, 101u8,
+            114u8, 115u8, 111u8, 110u8, 72u8, 75u8, 112u8, 111u8, 117u8, 110u8, 100u8, 45u8, 97u8,
+            110u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+            0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8,
+            0u8, 12u8, 0u8, 0u8, 0u8, 17u8, 0u8, 0u8, 0u8, 109u8, 97u8, 115u8, 115u8, 112u8, 101u8,
+            114u8, 115u8, 111u8, 110u8, 85u8, 83u8, 112u8, 111u8, 117u8, 110u8, 100u8, 1u8, 0u8,
+            0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8,
+            0u8, 10u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 27u8, 0u8, 0u8, 0u8, 109u8, 97u8, 115u8,
+            115u8, 112u8, 101u8, 114u8, 115u8, 111u8, 110u8, 85u8, 83u8, 112u8, 111u8, 117u8,
+            110u8, 100u8, 45u8, 97u8, 110u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 1u8,
+            0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 12u8, 0u8,
+            0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 22u8, 0u8, 0u8, 0u8, 46u8, 0u8, 0u8, 0u8, 109u8, 97u8,
+            115u8, 115u8, 45u8, 100u8, 101u8, 110u8, 115u8, 105u8, 116u8, 121u8, 100u8, 101u8,
+            102u8, 97u8, 117u8, 108u8, 116u8, 48u8, 48u8, 49u8, 107u8, 105u8, 108u8, 111u8, 103u8,
+            114u8, 97u8, 109u8, 45u8, 112u8, 101u8, 114u8, 45u8, 99u8, 117u8, 98u8, 105u8, 99u8,
+            45u8, 109u8, 101u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 15u8, 0u8,
+            0u8, 0u8, 23u8, 0u8, 0u8, 0u8, 112u8, 111u8, 119u8, 101u8, 114u8, 100u8, 101u8, 102u8,
+            97u8, 117u8, 108u8, 116u8, 48u8, 48u8, 49u8, 103u8, 105u8, 103u8, 97u8, 119u8, 97u8,
+            116u8, 116u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 23u8, 0u8, 0u8,
+            0u8, 112u8, 111u8, 119u8, 101u8, 114u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8,
+            48u8, 48u8, 49u8, 109u8, 101u8, 103u8, 97u8, 119u8, 97u8, 116u8, 116u8, 1u8, 0u8, 0u8,
+            0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8,
+            12u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 23u8, 0u8, 0u8, 0u8, 112u8, 111u8, 119u8,
+            101u8, 114u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 48u8, 48u8, 49u8, 107u8,
+            105u8, 108u8, 111u8, 119u8, 97u8, 116u8, 116u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+            5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 15u8,
+            0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 112u8, 111u8, 119u8, 101u8, 114u8, 100u8, 101u8,
+            102u8, 97u8, 117u8, 108u8, 116u8, 48u8, 48u8, 49u8, 119u8, 97u8, 116u8, 116u8, 1u8,
+            0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8,
+            0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 24u8, 0u8, 0u8, 0u8, 112u8, 111u8,
+            119u8, 101u8, 114u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 48u8, 48u8, 49u8,
+            109u8, 105u8, 108u8, 108u8, 105u8, 119u8, 97u8, 116u8, 116u8, 1u8, 0u8, 0u8, 0u8, 1u8,
+            0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 11u8, 0u8,
+            0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 22u8, 0u8, 0u8, 0u8, 112u8, 111u8, 119u8, 101u8, 114u8,
+            101u8, 110u8, 103u8, 105u8, 110u8, 101u8, 48u8, 48u8, 49u8, 107u8, 105u8, 108u8, 111u8,
+            119u8, 97u8, 116u8, 116u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 23u8,
+            0u8, 0u8, 0u8, 112u8, 111u8, 119u8, 101u8, 114u8, 101u8, 110u8, 103u8, 105u8, 110u8,
+            101u8, 71u8, 66u8, 104u8, 111u8, 114u8, 115u8, 101u8, 112u8, 111u8, 119u8, 101u8,
+            114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            5u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 23u8, 0u8, 0u8, 0u8,
+            112u8, 111u8, 119u8, 101u8, 114u8, 101u8, 110u8, 103u8, 105u8, 110u8, 101u8, 85u8,
+            83u8, 104u8, 111u8, 114u8, 115u8, 101u8, 112u8, 111u8, 119u8, 101u8, 114u8, 1u8, 0u8,
+            0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8,
+            0u8, 16u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 30u8, 0u8, 0u8, 0u8, 112u8, 114u8,
+            101u8, 115u8, 115u8, 117u8, 114u8, 101u8, 98u8, 97u8, 114u8, 111u8, 109u8, 116u8,
+            114u8, 99u8, 48u8, 48u8, 49u8, 104u8, 101u8, 99u8, 116u8, 111u8, 112u8, 97u8, 115u8,
+            99u8, 97u8, 108u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 26u8, 0u8,
+            0u8, 0u8, 112u8, 114u8, 101u8, 115u8, 115u8, 117u8, 114u8, 101u8, 98u8, 97u8, 114u8,
+            111u8, 109u8, 116u8, 114u8, 99u8, 66u8, 82u8, 109u8, 105u8, 108u8, 108u8, 105u8, 98u8,
+            97u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 8u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8,
+            112u8, 114u8, 101u8, 115u8, 115u8, 117u8, 114u8, 101u8, 98u8, 97u8, 114u8, 111u8,
+            109u8, 116u8, 114u8, 99u8, 69u8, 71u8, 109u8, 105u8, 108u8, 108u8, 105u8, 98u8, 97u8,
+            114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            8u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8,
+            112u8, 114u8, 101u8, 115u8, 115u8, 117u8, 114u8, 101u8, 98u8, 97u8, 114u8, 111u8,
+            109u8, 116u8, 114u8, 99u8, 71u8, 66u8, 109u8, 105u8, 108u8, 108u8, 105u8, 98u8, 97u8,
+            114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            8u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8,
+            112u8, 114u8, 101u8, 115u8, 115u8, 117u8, 114u8, 101u8, 98u8, 97u8, 114u8, 111u8,
+            109u8, 116u8, 114u8, 99u8, 73u8, 76u8, 109u8, 105u8, 108u8, 108u8, 105u8, 98u8, 97u8,
+            114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            8u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 27u8, 0u8, 0u8, 0u8,
+            112u8, 114u8, 101u8, 115u8, 115u8, 117u8, 114u8, 101u8, 98u8, 97u8, 114u8, 111u8,
+            109u8, 116u8, 114u8, 99u8, 73u8, 78u8, 105u8, 110u8, 99u8, 104u8, 45u8, 111u8, 102u8,
+            104u8, 103u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 33u8, 0u8, 0u8,
+            0u8, 112u8, 114u8, 101u8, 115u8, 115u8, 117u8, 114u8, 101u8, 98u8, 97u8, 114u8, 111u8,
+            109u8, 116u8, 114u8, 99u8, 77u8, 88u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 101u8,
+            116u8, 101u8, 114u8, 45u8, 111u8, 102u8, 104u8, 103u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+            0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8,
+            0u8, 18u8, 0u8, 0u8, 0u8, 33u8, 0u8, 0u8, 0u8, 112u8, 114u8, 101u8, 115u8, 115u8,
+            117u8, 114u8, 101u8, 98u8, 97u8, 114u8, 111u8, 109u8, 116u8, 114u8, 99u8, 82u8, 85u8,
+            109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 111u8,
+            102u8, 104u8, 103u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 26u8, 0u8,
+            0u8, 0u8, 112u8, 114u8, 101u8, 115u8, 115u8, 117u8, 114u8, 101u8, 98u8, 97u8, 114u8,
+            111u8, 109u8, 116u8, 114u8, 99u8, 84u8, 72u8, 109u8, 105u8, 108u8, 108u8, 105u8, 98u8,
+            97u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 8u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 27u8, 0u8, 0u8, 0u8,
+            112u8, 114u8, 101u8, 115u8, 115u8, 117u8, 114u8, 101u8, 98u8, 97u8, 114u8, 111u8,
+            109u8, 116u8, 114u8, 99u8, 85u8, 83u8, 105u8, 110u8, 99u8, 104u8, 45u8, 111u8, 102u8,
+            104u8, 103u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8,
+            0u8, 112u8, 114u8, 101u8, 115u8, 115u8, 117u8, 114u8, 101u8, 100u8, 101u8, 102u8, 97u8,
+            117u8, 108u8, 116u8, 48u8, 48u8, 49u8, 109u8, 101u8, 103u8, 97u8, 112u8, 97u8, 115u8,
+            99u8, 97u8, 108u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 24u8, 0u8,
+            0u8, 0u8, 112u8, 114u8, 101u8, 115u8, 115u8, 117u8, 114u8, 101u8, 100u8, 101u8, 102u8,
+            97u8, 117u8, 108u8, 116u8, 48u8, 48u8, 49u8, 112u8, 97u8, 115u8, 99u8, 97u8, 108u8,
+            1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8,
+            0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 17u8, 0u8, 0u8, 0u8, 44u8, 0u8, 0u8, 0u8, 112u8,
+            114u8, 101u8, 115u8, 115u8, 117u8, 114u8, 101u8, 100u8, 101u8, 102u8, 97u8, 117u8,
+            108u8, 116u8, 71u8, 66u8, 112u8, 111u8, 117u8, 110u8, 100u8, 45u8, 102u8, 111u8, 114u8,
+            99u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8,
+            45u8, 105u8, 110u8, 99u8, 104u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 17u8, 0u8, 0u8, 0u8,
+            44u8, 0u8, 0u8, 0u8, 112u8, 114u8, 101u8, 115u8, 115u8, 117u8, 114u8, 101u8, 100u8,
+            101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 85u8, 83u8, 112u8, 111u8, 117u8, 110u8, 100u8,
+            45u8, 102u8, 111u8, 114u8, 99u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 115u8, 113u8,
+            117u8, 97u8, 114u8, 101u8, 45u8, 105u8, 110u8, 99u8, 104u8, 1u8, 0u8, 0u8, 0u8, 1u8,
+            0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 12u8, 0u8,
+            0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 33u8, 0u8, 0u8, 0u8, 115u8, 112u8, 101u8, 101u8, 100u8,
+            100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 48u8, 48u8, 49u8, 107u8, 105u8, 108u8,
+            111u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8,
+            111u8, 117u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 27u8, 0u8,
+            0u8, 0u8, 115u8, 112u8, 101u8, 101u8, 100u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8,
+            116u8, 71u8, 66u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8,
+            111u8, 117u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 27u8, 0u8,
+            0u8, 0u8, 115u8, 112u8, 101u8, 101u8, 100u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8,
+            116u8, 85u8, 83u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8,
+            111u8, 117u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 35u8, 0u8,
+            0u8, 0u8, 115u8, 112u8, 101u8, 101u8, 100u8, 114u8, 97u8, 105u8, 110u8, 102u8, 97u8,
+            108u8, 108u8, 48u8, 48u8, 49u8, 109u8, 105u8, 108u8, 108u8, 105u8, 109u8, 101u8, 116u8,
+            101u8, 114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 1u8, 0u8,
+            0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8,
+            0u8, 13u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 34u8, 0u8, 0u8, 0u8, 115u8, 112u8,
+            101u8, 101u8, 100u8, 114u8, 97u8, 105u8, 110u8, 102u8, 97u8, 108u8, 108u8, 66u8, 82u8,
+            99u8, 101u8, 110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8,
+            101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+            5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 15u8,
+            0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 115u8, 112u8, 101u8, 101u8, 100u8, 114u8, 97u8,
+            105u8, 110u8, 102u8, 97u8, 108u8, 108u8, 85u8, 83u8, 105u8, 110u8, 99u8, 104u8, 45u8,
+            112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+            0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8,
+            0u8, 16u8, 0u8, 0u8, 0u8, 35u8, 0u8, 0u8, 0u8, 115u8, 112u8, 101u8, 101u8, 100u8,
+            115u8, 110u8, 111u8, 119u8, 102u8, 97u8, 108u8, 108u8, 48u8, 48u8, 49u8, 99u8, 101u8,
+            110u8, 116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8, 112u8, 101u8, 114u8,
+            45u8, 104u8, 111u8, 117u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8,
+            0u8, 28u8, 0u8, 0u8, 0u8, 115u8, 112u8, 101u8, 101u8, 100u8, 115u8, 110u8, 111u8,
+            119u8, 102u8, 97u8, 108u8, 108u8, 85u8, 83u8, 105u8, 110u8, 99u8, 104u8, 45u8, 112u8,
+            101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+            5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 12u8,
+            0u8, 0u8, 0u8, 30u8, 0u8, 0u8, 0u8, 115u8, 112u8, 101u8, 101u8, 100u8, 119u8, 105u8,
+            110u8, 100u8, 48u8, 48u8, 49u8, 107u8, 105u8, 108u8, 111u8, 109u8, 101u8, 116u8, 101u8,
+            114u8, 45u8, 112u8, 101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 1u8, 0u8, 0u8, 0u8,
+            1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 9u8,
+            0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 27u8, 0u8, 0u8, 0u8, 115u8, 112u8, 101u8, 101u8,
+            100u8, 119u8, 105u8, 110u8, 100u8, 70u8, 73u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+            112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 1u8, 0u8, 0u8, 0u8,
+            1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 9u8,
+            0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 27u8, 0u8, 0u8, 0u8, 115u8, 112u8, 101u8, 101u8,
+            100u8, 119u8, 105u8, 110u8, 100u8, 75u8, 82u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+            112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 1u8, 0u8, 0u8, 0u8,
+            1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 9u8,
+            0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 27u8, 0u8, 0u8, 0u8, 115u8, 112u8, 101u8, 101u8,
+            100u8, 119u8, 105u8, 110u8, 100u8, 78u8, 79u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+            112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 1u8, 0u8, 0u8, 0u8,
+            1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 9u8,
+            0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 27u8, 0u8, 0u8, 0u8, 115u8, 112u8, 101u8, 101u8,
+            100u8, 119u8, 105u8, 110u8, 100u8, 80u8, 76u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+            112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 1u8, 0u8, 0u8, 0u8,
+            1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 9u8,
+            0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 27u8, 0u8, 0u8, 0u8, 115u8, 112u8, 101u8, 101u8,
+            100u8, 119u8, 105u8, 110u8, 100u8, 82u8, 85u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+            112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 1u8, 0u8, 0u8, 0u8,
+            1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 9u8,
+            0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 27u8, 0u8, 0u8, 0u8, 115u8, 112u8, 101u8, 101u8,
+            100u8, 119u8, 105u8, 110u8, 100u8, 83u8, 69u8, 109u8, 101u8, 116u8, 101u8, 114u8, 45u8,
+            112u8, 101u8, 114u8, 45u8, 115u8, 101u8, 99u8, 111u8, 110u8, 100u8, 1u8, 0u8, 0u8, 0u8,
+            1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 9u8,
+            0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 24u8, 0u8, 0u8, 0u8, 115u8, 112u8, 101u8, 101u8,
+            100u8, 119u8, 105u8, 110u8, 100u8, 85u8, 83u8, 109u8, 105u8, 108u8, 101u8, 45u8, 112u8,
+            101u8, 114u8, 45u8, 104u8, 111u8, 117u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+            5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 21u8,
+            0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 116u8, 101u8, 109u8, 112u8, 101u8, 114u8, 97u8,
+            116u8, 117u8, 114u8, 101u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 48u8, 48u8,
+            49u8, 99u8, 101u8, 108u8, 115u8, 105u8, 117u8, 115u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+            0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8,
+            0u8, 20u8, 0u8, 0u8, 0u8, 30u8, 0u8, 0u8, 0u8, 116u8, 101u8, 109u8, 112u8, 101u8,
+            114u8, 97u8, 116u8, 117u8, 114u8, 101u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8,
+            116u8, 85u8, 83u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8,
+            1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8,
+            0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 116u8,
+            101u8, 109u8, 112u8, 101u8, 114u8, 97u8, 116u8, 117u8, 114u8, 101u8, 119u8, 101u8,
+            97u8, 116u8, 104u8, 101u8, 114u8, 48u8, 48u8, 49u8, 99u8, 101u8, 108u8, 115u8, 105u8,
+            117u8, 115u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8, 0u8, 30u8, 0u8,
+            0u8, 0u8, 116u8, 101u8, 109u8, 112u8, 101u8, 114u8, 97u8, 116u8, 117u8, 114u8, 101u8,
+            119u8, 101u8, 97u8, 116u8, 104u8, 101u8, 114u8, 66u8, 83u8, 102u8, 97u8, 104u8, 114u8,
+            101u8, 110u8, 104u8, 101u8, 105u8, 116u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 20u8, 0u8,
+            0u8, 0u8, 30u8, 0u8, 0u8, 0u8, 116u8, 101u8, 109u8, 112u8, 101u8, 114u8, 97u8, 116u8,
+            117u8, 114u8, 101u8, 119u8, 101u8, 97u8, 116u8, 104u8, 101u8, 114u8, 66u8, 90u8, 102u8,
+            97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8, 1u8, 0u8, 0u8, 0u8, 1u8,
+            0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 18u8, 0u8,
+            0u8, 0u8, 20u8, 0u8, 0u8, 0u8, 30u8, 0u8, 0u8, 0u8, 116u8, 101u8, 109u8, 112u8, 101u8,
+            114u8, 97u8, 116u8, 117u8, 114u8, 101u8, 119u8, 101u8, 97u8, 116u8, 104u8, 101u8,
+            114u8, 75u8, 89u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8,
+            1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8,
+            0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8, 0u8, 30u8, 0u8, 0u8, 0u8, 116u8,
+            101u8, 109u8, 112u8, 101u8, 114u8, 97u8, 116u8, 117u8, 114u8, 101u8, 119u8, 101u8,
+            97u8, 116u8, 104u8, 101u8, 114u8, 80u8, 82u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8,
+            104u8, 101u8, 105u8, 116u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8, 0u8,
+            30u8, 0u8, 0u8, 0u8, 116u8, 101u8, 109u8, 112u8, 101u8, 114u8, 97u8, 116u8, 117u8,
+            114u8, 101u8, 119u8, 101u8, 97u8, 116u8, 104u8, 101u8, 114u8, 80u8, 87u8, 102u8, 97u8,
+            104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+            0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8,
+            0u8, 20u8, 0u8, 0u8, 0u8, 30u8, 0u8, 0u8, 0u8, 116u8, 101u8, 109u8, 112u8, 101u8,
+            114u8, 97u8, 116u8, 117u8, 114u8, 101u8, 119u8, 101u8, 97u8, 116u8, 104u8, 101u8,
+            114u8, 85u8, 83u8, 102u8, 97u8, 104u8, 114u8, 101u8, 110u8, 104u8, 101u8, 105u8, 116u8,
+            1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8,
+            0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 27u8, 0u8, 0u8, 0u8, 118u8,
+            111u8, 108u8, 117u8, 109u8, 101u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8,
+            48u8, 48u8, 49u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 109u8, 101u8, 116u8, 101u8,
+            114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            6u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 32u8, 0u8, 0u8, 0u8,
+            118u8, 111u8, 108u8, 117u8, 109u8, 101u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8,
+            116u8, 48u8, 48u8, 49u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 99u8, 101u8, 110u8,
+            116u8, 105u8, 109u8, 101u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+            0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8,
+            15u8, 0u8, 0u8, 0u8, 25u8, 0u8, 0u8, 0u8, 118u8, 111u8, 108u8, 117u8, 109u8, 101u8,
+            100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 71u8, 66u8, 99u8, 117u8, 98u8, 105u8,
+            99u8, 45u8, 102u8, 111u8, 111u8, 116u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 15u8, 0u8,
+            0u8, 0u8, 25u8, 0u8, 0u8, 0u8, 118u8, 111u8, 108u8, 117u8, 109u8, 101u8, 100u8, 101u8,
+            102u8, 97u8, 117u8, 108u8, 116u8, 71u8, 66u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8,
+            105u8, 110u8, 99u8, 104u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 25u8,
+            0u8, 0u8, 0u8, 118u8, 111u8, 108u8, 117u8, 109u8, 101u8, 100u8, 101u8, 102u8, 97u8,
+            117u8, 108u8, 116u8, 85u8, 83u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 102u8, 111u8,
+            111u8, 116u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 25u8, 0u8, 0u8,
+            0u8, 118u8, 111u8, 108u8, 117u8, 109u8, 101u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8,
+            116u8, 85u8, 83u8, 99u8, 117u8, 98u8, 105u8, 99u8, 45u8, 105u8, 110u8, 99u8, 104u8,
+            1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8,
+            0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 118u8,
+            111u8, 108u8, 117u8, 109u8, 101u8, 102u8, 108u8, 117u8, 105u8, 100u8, 48u8, 48u8, 49u8,
+            108u8, 105u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8,
+            0u8, 24u8, 0u8, 0u8, 0u8, 118u8, 111u8, 108u8, 117u8, 109u8, 101u8, 102u8, 108u8,
+            117u8, 105u8, 100u8, 48u8, 48u8, 49u8, 109u8, 105u8, 108u8, 108u8, 105u8, 108u8, 105u8,
+            116u8, 101u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 28u8, 0u8,
+            0u8, 0u8, 118u8, 111u8, 108u8, 117u8, 109u8, 101u8, 102u8, 108u8, 117u8, 105u8, 100u8,
+            71u8, 66u8, 103u8, 97u8, 108u8, 108u8, 111u8, 110u8, 45u8, 105u8, 109u8, 112u8, 101u8,
+            114u8, 105u8, 97u8, 108u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 33u8,
+            0u8, 0u8, 0u8, 118u8, 111u8, 108u8, 117u8, 109u8, 101u8, 102u8, 108u8, 117u8, 105u8,
+            100u8, 71u8, 66u8, 102u8, 108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8,
+            101u8, 45u8, 105u8, 109u8, 112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 1u8, 0u8, 0u8, 0u8,
+            1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 11u8,
+            0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 118u8, 111u8, 108u8, 117u8,
+            109u8, 101u8, 102u8, 108u8, 117u8, 105u8, 100u8, 85u8, 83u8, 103u8, 97u8, 108u8, 108u8,
+            111u8, 110u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8,
+            0u8, 118u8, 111u8, 108u8, 117u8, 109u8, 101u8, 102u8, 108u8, 117u8, 105u8, 100u8, 85u8,
+            83u8, 113u8, 117u8, 97u8, 114u8, 116u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 13u8, 0u8,
+            0u8, 0u8, 17u8, 0u8, 0u8, 0u8, 118u8, 111u8, 108u8, 117u8, 109u8, 101u8, 102u8, 108u8,
+            117u8, 105u8, 100u8, 85u8, 83u8, 112u8, 105u8, 110u8, 116u8, 1u8, 0u8, 0u8, 0u8, 1u8,
+            0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 11u8, 0u8,
+            0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 118u8, 111u8, 108u8, 117u8, 109u8,
+            101u8, 102u8, 108u8, 117u8, 105u8, 100u8, 85u8, 83u8, 99u8, 117u8, 112u8, 1u8, 0u8,
+            0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8,
+            0u8, 11u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 24u8, 0u8, 0u8, 0u8, 118u8, 111u8,
+            108u8, 117u8, 109u8, 101u8, 102u8, 108u8, 117u8, 105u8, 100u8, 85u8, 83u8, 102u8,
+            108u8, 117u8, 105u8, 100u8, 45u8, 111u8, 117u8, 110u8, 99u8, 101u8, 1u8, 0u8, 0u8, 0u8,
+            1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 11u8,
+            0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 23u8, 0u8, 0u8, 0u8, 118u8, 111u8, 108u8, 117u8,
+            109u8, 101u8, 102u8, 108u8, 117u8, 105u8, 100u8, 85u8, 83u8, 116u8, 97u8, 98u8, 108u8,
+            101u8, 115u8, 112u8, 111u8, 111u8, 110u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 13u8, 0u8,
+            0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 118u8, 111u8, 108u8, 117u8, 109u8, 101u8, 102u8, 108u8,
+            117u8, 105u8, 100u8, 85u8, 83u8, 116u8, 101u8, 97u8, 115u8, 112u8, 111u8, 111u8, 110u8,
+            1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8,
+            0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 118u8,
+            111u8, 108u8, 117u8, 109u8, 101u8, 111u8, 105u8, 108u8, 48u8, 48u8, 49u8, 98u8, 97u8,
+            114u8, 114u8, 101u8, 108u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 21u8,
+            0u8, 0u8, 0u8, 118u8, 111u8, 108u8, 117u8, 109u8, 101u8, 118u8, 101u8, 104u8, 105u8,
+            99u8, 108u8, 101u8, 48u8, 48u8, 49u8, 108u8, 105u8, 116u8, 101u8, 114u8, 1u8, 0u8, 0u8,
+            0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8,
+            13u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 118u8, 111u8, 108u8,
+            117u8, 109u8, 101u8, 118u8, 101u8, 104u8, 105u8, 99u8, 108u8, 101u8, 85u8, 83u8, 103u8,
+            97u8, 108u8, 108u8, 111u8, 110u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8, 0u8, 23u8, 0u8, 0u8,
+            0u8, 27u8, 0u8, 0u8, 0u8, 121u8, 101u8, 97u8, 114u8, 45u8, 100u8, 117u8, 114u8, 97u8,
+            116u8, 105u8, 111u8, 110u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 48u8, 48u8,
+            49u8, 121u8, 101u8, 97u8, 114u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8, 0u8, 23u8, 0u8, 0u8, 0u8,
+            28u8, 0u8, 0u8, 0u8, 121u8, 101u8, 97u8, 114u8, 45u8, 100u8, 117u8, 114u8, 97u8, 116u8,
+            105u8, 111u8, 110u8, 100u8, 101u8, 102u8, 97u8, 117u8, 108u8, 116u8, 48u8, 48u8, 49u8,
+            109u8, 111u8, 110u8, 116u8, 104u8, 5u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 5u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 23u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8,
+            0u8, 37u8, 0u8, 0u8, 0u8, 121u8, 101u8, 97u8, 114u8, 45u8, 100u8, 117u8, 114u8, 97u8,
+            116u8, 105u8, 111u8, 110u8, 112u8, 101u8, 114u8, 115u8, 111u8, 110u8, 45u8, 97u8,
+            103u8, 101u8, 48u8, 48u8, 49u8, 121u8, 101u8, 97u8, 114u8, 45u8, 112u8, 101u8, 114u8,
+            115u8, 111u8, 110u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 23u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 54u8,
+            0u8, 0u8, 0u8, 121u8, 101u8, 97u8, 114u8, 45u8, 100u8, 117u8, 114u8, 97u8, 116u8,
+            105u8, 111u8, 110u8, 112u8, 101u8, 114u8, 115u8, 111u8, 110u8, 45u8, 97u8, 103u8,
+            101u8, 48u8, 48u8, 49u8, 121u8, 101u8, 97u8, 114u8, 45u8, 112u8, 101u8, 114u8, 115u8,
+            111u8, 110u8, 45u8, 97u8, 110u8, 100u8, 45u8, 109u8, 111u8, 110u8, 116u8, 104u8, 45u8,
+            112u8, 101u8, 114u8, 115u8, 111u8, 110u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 5u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 23u8, 0u8, 0u8, 0u8, 26u8, 0u8,
+            0u8, 0u8, 38u8, 0u8, 0u8, 0u8, 121u8, 101u8, 97u8, 114u8, 45u8, 100u8, 117u8, 114u8,
+            97u8, 116u8, 105u8, 111u8, 110u8, 112u8, 101u8, 114u8, 115u8, 111u8, 110u8, 45u8, 97u8,
+            103u8, 101u8, 48u8, 48u8, 49u8, 109u8, 111u8, 110u8, 116u8, 104u8, 45u8, 112u8, 101u8,
+            114u8, 115u8, 111u8, 110u8,
+        ])
+    },
+};
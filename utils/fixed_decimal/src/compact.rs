@@ -0,0 +1,110 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use core::fmt;
+
+use crate::FixedDecimal;
+
+/// A struct containing a [`FixedDecimal`] significand together with an exponent, representing a
+/// number written in compact notation (such as 1.2M).
+/// This represents a _source number_, as defined
+/// [in UTS #35](https://www.unicode.org/reports/tr35/tr35-numbers.html#Plural_rules_syntax).
+/// The value exponent=0 represents a number in non-compact
+/// notation (such as 1 200 000).
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompactDecimal {
+    significand: FixedDecimal,
+    exponent: u8,
+}
+
+impl CompactDecimal {
+    /// Constructs a [`CompactDecimal`] from its significand and exponent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{CompactDecimal, FixedDecimal};
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let compact = CompactDecimal::from_significand_and_exponent(
+    ///     FixedDecimal::from(12).multiplied_pow10(-1),
+    ///     6,
+    /// );
+    /// assert_writeable_eq!(compact, "1.2c6");
+    /// ```
+    pub fn from_significand_and_exponent(significand: FixedDecimal, exponent: u8) -> Self {
+        Self {
+            significand,
+            exponent,
+        }
+    }
+
+    /// Returns a reference to the significand of `self`.
+    ///
+    /// ```
+    /// use fixed_decimal::{CompactDecimal, FixedDecimal};
+    ///
+    /// let compact = CompactDecimal::from_significand_and_exponent(FixedDecimal::from(2), 3);
+    /// assert_eq!(compact.significand(), &FixedDecimal::from(2));
+    /// ```
+    pub fn significand(&self) -> &FixedDecimal {
+        &self.significand
+    }
+
+    /// Returns the significand of `self`.
+    pub fn into_significand(self) -> FixedDecimal {
+        self.significand
+    }
+
+    /// Returns the exponent of `self`.
+    ///
+    /// ```
+    /// use fixed_decimal::{CompactDecimal, FixedDecimal};
+    ///
+    /// let compact = CompactDecimal::from_significand_and_exponent(FixedDecimal::from(2), 3);
+    /// assert_eq!(compact.exponent(), 3);
+    /// ```
+    pub const fn exponent(&self) -> u8 {
+        self.exponent
+    }
+}
+
+/// Render the [`CompactDecimal`] in sampleValue syntax.
+/// The letter c is used, rather than the deprecated e.
+///
+/// # Examples
+///
+/// ```
+/// # use fixed_decimal::{CompactDecimal, FixedDecimal};
+/// # use writeable::assert_writeable_eq;
+/// #
+/// assert_writeable_eq!(
+///     CompactDecimal::from_significand_and_exponent(FixedDecimal::from(42), 0),
+///     "42"
+/// );
+/// assert_writeable_eq!(
+///     CompactDecimal::from_significand_and_exponent(FixedDecimal::from(4), 3),
+///     "4c3"
+/// );
+/// ```
+impl writeable::Writeable for CompactDecimal {
+    fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        self.significand.write_to(sink)?;
+        if self.exponent != 0 {
+            sink.write_char('c')?;
+            self.exponent.write_to(sink)?;
+        }
+        Ok(())
+    }
+
+    fn writeable_length_hint(&self) -> writeable::LengthHint {
+        let mut result = self.significand.writeable_length_hint();
+        if self.exponent != 0 {
+            result += self.exponent.writeable_length_hint() + 1;
+        }
+        result
+    }
+}
+
+writeable::impl_display_with_writeable!(CompactDecimal);
@@ -1177,6 +1177,10 @@ impl FixedDecimal {
         }
 
         if position <= before_truncate_magnitude {
+            // Restore the zeros between the last non-zero digit and `position`, which
+            // `trunc` removed, so that the increment happens at `position`.
+            self.digits
+                .resize((self.magnitude - position + 1) as usize, 0);
             let result = self.increment_abs_by_one();
             if result.is_err() {
                 // Do nothing for now.
@@ -2974,6 +2978,26 @@ fn test_rounding() {
     dec.ceil(-1);
     assert_eq!("-0.0", dec.to_string());
 
+    let mut dec = FixedDecimal::from_str("1.08").unwrap();
+    dec.ceil(-1);
+    assert_eq!("1.1", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("10.08").unwrap();
+    dec.ceil(-1);
+    assert_eq!("10.1", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("-1.08").unwrap();
+    dec.floor(-1);
+    assert_eq!("-1.1", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("100.5").unwrap();
+    dec.ceil(1);
+    assert_eq!("110", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("1.08").unwrap();
+    dec.half_even(-1);
+    assert_eq!("1.1", dec.to_string());
+
     // Test Half Ceil
     let mut dec = FixedDecimal::from_str("3.234").unwrap();
     dec.half_ceil(0);
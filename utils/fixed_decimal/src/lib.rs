@@ -51,6 +51,7 @@
     )
 )]
 
+mod compact;
 mod decimal;
 mod ops;
 mod uint_iterator;
@@ -58,6 +59,7 @@ mod uint_iterator;
 #[cfg(feature = "ryu")]
 pub use decimal::DoublePrecision;
 
+pub use compact::CompactDecimal;
 pub use decimal::FixedDecimal;
 pub use decimal::Sign;
 pub use decimal::SignDisplay;
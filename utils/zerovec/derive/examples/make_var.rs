@@ -70,6 +70,18 @@ struct MultiFieldTuple<'a>(
     #[serde(borrow)] Cow<'a, str>,
 );
 
+#[make_varule(FlagsStructULE)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, serde::Serialize, serde::Deserialize)]
+#[zerovec::derive(Serialize, Deserialize, Debug)]
+struct FlagsStruct<'a> {
+    a: u8,
+    b: bool,
+    #[serde(borrow)]
+    c: Cow<'a, str>,
+    #[serde(borrow)]
+    d: Cow<'a, str>,
+}
+
 /// The `assert` function should have the body `|(stack, zero)| assert_eq!(stack, &U::zero_from(&zero))`
 ///
 /// We cannot do this internally because we technically need a different `U` with a shorter lifetime here
@@ -129,6 +141,10 @@ fn main() {
         assert_eq!(stack, &MultiFieldStruct::zero_from(zero))
     });
 
+    assert_zerovec::<FlagsStructULE, FlagsStruct, _>(TEST_FLAGS, |stack, zero| {
+        assert_eq!(stack, &FlagsStruct::zero_from(zero))
+    });
+
     let vartuples = &[
         VarTupleStruct(101, 'ø', TEST_STRINGS1.into()),
         VarTupleStruct(9499, '⸘', TEST_STRINGS2.into()),
@@ -187,3 +203,18 @@ const TEST_MULTIFIELD: &[MultiFieldStruct<'static>] = &[
         f: 'ə',
     },
 ];
+
+const TEST_FLAGS: &[FlagsStruct<'static>] = &[
+    FlagsStruct {
+        a: 1,
+        b: false,
+        c: Cow::Borrowed("nb"),
+        d: Cow::Borrowed("no"),
+    },
+    FlagsStruct {
+        a: 4,
+        b: true,
+        c: Cow::Borrowed("hr"),
+        d: Cow::Borrowed("bs"),
+    },
+];
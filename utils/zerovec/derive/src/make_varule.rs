@@ -100,12 +100,27 @@ pub fn make_varule_impl(attr: AttributeArgs, mut input: DeriveInput) -> TokenStr
 
     let unsized_field_info = UnsizedFields::new(unsized_fields);
 
-    let mut field_inits = crate::ule::make_ule_fields(&sized_fields);
+    let mut field_inits: Vec<_> = sized_fields
+        .iter()
+        .zip(crate::ule::make_ule_fields(&sized_fields))
+        .map(|(f, init)| {
+            let doc = format!("The `{}` field of {name}", f.accessor);
+            quote!(#[doc = #doc] #init)
+        })
+        .collect();
     let last_field_ule = unsized_field_info.varule_ty();
 
     let setter = unsized_field_info.varule_setter();
     let vis = &unsized_field_info.varule_vis();
-    field_inits.push(quote!(#vis #setter #last_field_ule));
+    let last_field_doc = if unsized_field_info.fields.len() == 1 {
+        format!(
+            "The `{}` field of {name}",
+            unsized_field_info.fields[0].field.accessor
+        )
+    } else {
+        format!("The unsized fields of {name}")
+    };
+    field_inits.push(quote!(#[doc = #last_field_doc] #vis #setter #last_field_ule));
 
     let semi = utils::semi_for(fields);
     let repr_attr = utils::repr_for(fields);
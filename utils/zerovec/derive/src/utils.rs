@@ -65,7 +65,9 @@ pub fn repr_for(f: &Fields) -> TokenStream2 {
     if f.len() == 1 {
         quote!(transparent)
     } else {
-        quote!(packed)
+        // `C` keeps the fields in declaration order, which is the order
+        // in which they are encoded
+        quote!(C, packed)
     }
 }
 
@@ -44,7 +44,7 @@ impl MultiFieldsULE {
                 lengths, output,
             );
             debug_assert!(
-                <VarZeroSlice<[u8]>>::validate_byte_slice(output).is_ok(),
+                <VarZeroSlice<[u8], Index32>>::validate_byte_slice(output).is_ok(),
                 "Encoded slice must be valid VarZeroSlice"
             );
             // Safe since write_serializable_bytes produces a valid VarZeroSlice buffer
@@ -95,11 +95,13 @@ impl MultiFieldsULE {
     /// Construct from a byte slice
     ///
     /// # Safety
-    /// - byte slice must be a valid VarZeroSlice<[u8]>
+    /// - byte slice must be a valid VarZeroSlice<[u8], Index32>
     #[inline]
     pub unsafe fn from_byte_slice_unchecked(bytes: &[u8]) -> &Self {
         // &Self is transparent over &VZS<..>
-        mem::transmute(<VarZeroSlice<[u8]>>::from_byte_slice_unchecked(bytes))
+        mem::transmute(<VarZeroSlice<[u8], Index32>>::from_byte_slice_unchecked(
+            bytes,
+        ))
     }
 }
 
@@ -141,12 +143,42 @@ unsafe impl VarULE for MultiFieldsULE {
     /// This impl exists so that EncodeAsVarULE can work.
     #[inline]
     fn validate_byte_slice(slice: &[u8]) -> Result<(), ZeroVecError> {
-        <VarZeroSlice<[u8]>>::validate_byte_slice(slice)
+        <VarZeroSlice<[u8], Index32>>::validate_byte_slice(slice)
     }
 
     #[inline]
     unsafe fn from_byte_slice_unchecked(bytes: &[u8]) -> &Self {
         // &Self is transparent over &VZS<..>
-        mem::transmute(<VarZeroSlice<[u8]>>::from_byte_slice_unchecked(bytes))
+        mem::transmute(<VarZeroSlice<[u8], Index32>>::from_byte_slice_unchecked(
+            bytes,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_multi_fields_roundtrip() {
+        let fields: [&[u8]; 4] = [b"abc", b"", b"defgh", b"ij"];
+        let lengths = fields.map(<[u8]>::len);
+
+        let mut bytes = vec![0; MultiFieldsULE::compute_encoded_len_for(&lengths)];
+        let multi = MultiFieldsULE::new_from_lengths_partially_initialized(&lengths, &mut bytes);
+        for (i, field) in fields.iter().enumerate() {
+            unsafe { multi.set_field_at::<[u8], [u8]>(i, field) };
+        }
+
+        MultiFieldsULE::validate_byte_slice(&bytes).expect("Encoded bytes must validate");
+        let multi = MultiFieldsULE::parse_byte_slice(&bytes).unwrap();
+        for (i, field) in fields.iter().enumerate() {
+            unsafe {
+                multi.validate_field::<[u8]>(i).unwrap();
+                assert_eq!(multi.get_field::<[u8]>(i), *field);
+            }
+        }
+        let multi = unsafe { MultiFieldsULE::from_byte_slice_unchecked(&bytes) };
+        assert_eq!(unsafe { multi.get_field::<[u8]>(2) }, b"defgh");
     }
 }